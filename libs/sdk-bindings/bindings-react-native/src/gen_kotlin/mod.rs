@@ -182,8 +182,8 @@ pub mod filters {
             Type::External { .. } => {
                 unimplemented!("render_to_map: External is not implemented")
             }
-            Type::Custom { .. } => {
-                unimplemented!("render_to_map: Custom is not implemented")
+            Type::Custom { builtin, .. } => {
+                render_to_map(builtin.as_ref(), ci, obj_name, field_name, optional)
             }
             Type::Unresolved { .. } => {
                 unimplemented!("render_to_map: Unresolved is not implemented")
@@ -250,7 +250,9 @@ pub mod filters {
             }
             Type::Map(_, _) => "".into(),
             Type::External { .. } => "".into(),
-            Type::Custom { .. } => "".into(),
+            Type::Custom { builtin, .. } => {
+                render_from_map(builtin.as_ref(), ci, name, field_name, optional)?
+            }
             Type::Unresolved { .. } => "".into(),
         };
         Ok(res.to_string())
@@ -278,8 +280,12 @@ pub mod filters {
             Type::Int64 => Ok(".toLong()".to_string()),
             Type::UInt64 => Ok(".toULong()".to_string()),
             Type::Float32 | Type::Float64 => Ok(".toFloat()".to_string()),
+            Type::Custom { builtin, .. } => rn_convert_type(builtin.as_ref(), _ci),
             Type::Optional(inner) => {
-                let unboxed = inner.as_ref();
+                let unboxed = match inner.as_ref() {
+                    Type::Custom { builtin, .. } => builtin.as_ref(),
+                    unboxed => unboxed,
+                };
                 let conversion = rn_convert_type(unboxed, _ci).unwrap();
                 let optional = match *unboxed {
                     Type::Int8
@@ -323,6 +329,7 @@ pub mod filters {
                 rn_type_name(unboxed, ci)
             }
             Type::Sequence(_) => Ok("ReadableArray".to_string()),
+            Type::Custom { builtin, .. } => rn_type_name(builtin.as_ref(), ci),
             _ => Ok("".to_string()),
         }
     }
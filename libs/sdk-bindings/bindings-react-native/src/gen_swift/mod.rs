@@ -154,8 +154,8 @@ pub mod filters {
             Type::External { .. } => {
                 unimplemented!("render_to_map: External is not implemented")
             }
-            Type::Custom { .. } => {
-                unimplemented!("render_to_map: Custom is not implemented")
+            Type::Custom { builtin, .. } => {
+                render_to_map(builtin.as_ref(), ci, obj_name, field_name, optional)
             }
             Type::Unresolved { .. } => {
                 unimplemented!("render_to_map: Unresolved is not implemented")
@@ -170,7 +170,10 @@ pub mod filters {
     ) -> Result<String, askama::Error> {
         match t {
             Type::Optional(inner) => {
-                let unboxed = inner.as_ref();
+                let unboxed = match inner.as_ref() {
+                    Type::Custom { builtin, .. } => builtin.as_ref(),
+                    unboxed => unboxed,
+                };
                 let optional = match *unboxed {
                     Type::Int8
                     | Type::UInt8
@@ -283,6 +286,7 @@ pub mod filters {
                 extern_type_name(unboxed, ci)
             }
             Type::Sequence(_) => Ok("NSArray*".to_string()),
+            Type::Custom { builtin, .. } => extern_type_name(builtin.as_ref(), ci),
             _ => Ok("".to_string()),
         }
     }
@@ -359,7 +363,7 @@ pub mod filters {
             }
             Type::Map(_, _) => "".into(),
             Type::External { .. } => "".into(),
-            Type::Custom { .. } => "".into(),
+            Type::Custom { builtin, .. } => render_from_map(builtin.as_ref(), ci, map_var_name)?,
             Type::Unresolved { .. } => "".into(),
         };
         Ok(res.to_string())
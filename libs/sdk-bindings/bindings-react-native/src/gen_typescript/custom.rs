@@ -1,29 +0,0 @@
-/* This Source Code Form is subject to the terms of the Mozilla Public
- * License, v. 2.0. If a copy of the MPL was not distributed with this
- * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
-
-use uniffi_bindgen::backend::{CodeOracle, CodeType};
-
-pub struct CustomCodeType {
-    name: String,
-}
-
-impl CustomCodeType {
-    pub fn new(name: String) -> Self {
-        Self { name }
-    }
-}
-
-impl CodeType for CustomCodeType {
-    fn type_label(&self, _oracle: &dyn CodeOracle) -> String {
-        self.name.clone()
-    }
-
-    fn canonical_name(&self, _oracle: &dyn CodeOracle) -> String {
-        format!("Type{}", self.name)
-    }
-
-    fn coerce(&self, _oracle: &dyn CodeOracle, nm: &str) -> String {
-        nm.to_string()
-    }
-}
@@ -10,7 +10,6 @@ use crate::generator::RNConfig;
 
 mod callback_interface;
 mod compounds;
-mod custom;
 mod enum_;
 mod error;
 mod external;
@@ -94,7 +93,7 @@ impl TypescriptCodeOracle {
             Type::Sequence(inner) => Box::new(compounds::SequenceCodeType::new(*inner)),
             Type::Map(key, value) => Box::new(compounds::MapCodeType::new(*key, *value)),
             Type::External { name, .. } => Box::new(external::ExternalCodeType::new(name)),
-            Type::Custom { name, .. } => Box::new(custom::CustomCodeType::new(name)),
+            Type::Custom { builtin, .. } => self.create_code_type(*builtin),
 
             Type::Unresolved { name } => {
                 unreachable!("Type `{name}` must be resolved before calling create_code_type")
@@ -207,7 +206,10 @@ pub mod filters {
     pub fn default_value(t: &TypeIdentifier) -> Result<String, askama::Error> {
         let res: Result<String, askama::Error> = match t {
             Type::Optional(inner) => {
-                let unboxed = inner.as_ref();
+                let unboxed = match inner.as_ref() {
+                    Type::Custom { builtin, .. } => builtin.as_ref(),
+                    unboxed => unboxed,
+                };
                 match unboxed {
                     Type::UInt8
                     | Type::Int8
@@ -7,34 +7,63 @@ extern crate uniffi_28 as uniffi;
 use std::sync::Arc;
 
 use anyhow::Result;
-use breez_sdk_core::lnurl::pay::{LnUrlPayResult, LnUrlPaySuccessData};
+use breez_sdk_core::lnurl::pay::{LnUrlPayPendingData, LnUrlPayResult, LnUrlPaySuccessData};
 use breez_sdk_core::{
-    error::*, mnemonic_to_seed as sdk_mnemonic_to_seed, parse as sdk_parse_input,
-    parse_invoice as sdk_parse_invoice, AesSuccessActionDataDecrypted, AesSuccessActionDataResult,
-    BackupFailedData, BackupStatus, BitcoinAddressData, BreezEvent, BreezServices,
-    BuyBitcoinProvider, BuyBitcoinRequest, BuyBitcoinResponse, ChannelState, CheckMessageRequest,
-    CheckMessageResponse, ClosedChannelPaymentDetails, Config, ConfigureNodeRequest,
-    ConnectRequest, CurrencyInfo, EnvironmentType, EventListener, FeeratePreset, FiatCurrency,
+    diff_snapshots as sdk_diff_snapshots, error::*, log_filter_enabled,
+    mnemonic_to_seed as sdk_mnemonic_to_seed, parse as sdk_parse_input,
+    parse_invoice as sdk_parse_invoice, AddFiatRateAlertRequest, AesSuccessActionDataDecrypted,
+    AesSuccessActionDataResult, AmendInvoiceRequest, Amount, AutoRefundConfig, BackupFailedData,
+    BackupStatus, BackupTransportConfig, BalanceBreakdown, Balances, BatchPaymentProgressDetails,
+    BatchPaymentResult, BitcoinAddressData, BreezEvent, BreezServices, BumpFeeRequest,
+    BumpFeeResponse, BuyBitcoinProvider, BuyBitcoinQuote, BuyBitcoinRequest, BuyBitcoinResponse,
+    ChainServiceConfig, ChannelDetails, ChannelHygieneSuggestionDetails, ChannelOpeningFee,
+    ChannelState, CheckMessageRequest, CheckMessageResponse, CloseChannelRequest,
+    CloseChannelResponse, ClosedChannelPaymentDetails, Config, ConfigureNodeRequest,
+    ConnectPeerRequest, ConnectProgressDetails, ConnectRequest, ConnectStage, ConnectionState,
+    ConnectionStatus, CreateLnurlWithdrawVoucherRequest, CreateStaticLnurlPayRequest, CurrencyInfo,
+    DecryptRecoveryBundleRequest, Denomination, DeriveEncryptionKeyRequest,
+    DeriveEncryptionKeyResponse, EncryptedPaymentRequestData, EnvironmentType, EventListener,
+    ExportFormat, ExportPaymentsRequest, ExportRecoveryBundleRequest, ExportRecoveryBundleResponse,
+    ExportSwapRefundKeysRequest, FeatureSupport, FeeratePreset, FetchBuyBitcoinQuoteRequest,
+    FiatAmount, FiatCurrency, FiatRateAlert, FiatRateAlertDirection, FiatRateAlertTriggeredDetails,
     GreenlightCredentials, GreenlightDeviceCredentials, GreenlightNodeConfig, HealthCheckStatus,
-    InputType, InvoicePaidDetails, LNInvoice, ListPaymentsRequest, ListSwapsRequest,
-    LnPaymentDetails, LnUrlAuthError, LnUrlAuthRequestData, LnUrlCallbackStatus, LnUrlErrorData,
-    LnUrlPayError, LnUrlPayErrorData, LnUrlPayRequest, LnUrlPayRequestData, LnUrlWithdrawError,
-    LnUrlWithdrawRequest, LnUrlWithdrawRequestData, LnUrlWithdrawResult, LnUrlWithdrawSuccessData,
-    LocaleOverrides, LocalizedName, LogEntry, LogStream, LspInformation, MessageSuccessActionData,
-    MetadataFilter, MetadataItem, Network, NodeConfig, NodeCredentials, NodeState,
-    OnchainPaymentLimitsResponse, OpenChannelFeeRequest, OpenChannelFeeResponse, OpeningFeeParams,
-    OpeningFeeParamsMenu, PayOnchainRequest, PayOnchainResponse, Payment, PaymentDetails,
-    PaymentFailedData, PaymentStatus, PaymentType, PaymentTypeFilter, PrepareOnchainPaymentRequest,
-    PrepareOnchainPaymentResponse, PrepareRedeemOnchainFundsRequest,
-    PrepareRedeemOnchainFundsResponse, PrepareRefundRequest, PrepareRefundResponse, Rate,
-    ReceiveOnchainRequest, ReceivePaymentRequest, ReceivePaymentResponse, RecommendedFees,
-    RedeemOnchainFundsRequest, RedeemOnchainFundsResponse, RefundRequest, RefundResponse,
+    HistoricalRate, HtlcExposureLimits, ImportPaymentsRequest, ImportPaymentsResponse, InputType,
+    InvoiceFeatures, InvoicePaidDetails, InvoicePayability, InvoiceVerificationResult, LNInvoice,
+    LNOffer, ListPaymentGroupsRequest, ListPaymentsRequest, ListSwapsRequest, LnOfferBlindedPath,
+    LnPaymentDetails, LnUrlAuthDomain, LnUrlAuthError, LnUrlAuthExport, LnUrlAuthRequestData,
+    LnUrlCallbackStatus, LnUrlErrorData, LnUrlPayError, LnUrlPayErrorData, LnUrlPayFinishedDetails,
+    LnUrlPayRequest, LnUrlPayRequestData, LnUrlWithdrawError, LnUrlWithdrawRequest,
+    LnUrlWithdrawRequestData, LnUrlWithdrawResult, LnUrlWithdrawSuccessData, LnurlPayInfo,
+    LnurlWithdrawVoucher, LocaleOverrides, LocalizedName, LogEntry, LogStream, LowLiquidityDetails,
+    LspInformation, LspPolicy, MemoPrivacy, MessageSuccessActionData, MetadataFilter, MetadataItem,
+    MetricBucket, MetricCounter, MetricHistogram, MetricsSnapshot, MigrationChannel, MilliSat,
+    MockNodeConfig, Network, NodeConfig, NodeCredentials, NodeMigrationRequest, NodeMigrationState,
+    NodeState, OnchainPaymentLimitsResponse, OpenChannelFeeRequest, OpenChannelFeeResponse,
+    OpenChannelReceiveDetails, OpenChannelReceiveStage, OpenChannelRequest, OpenChannelResponse,
+    OpeningFeeParams, OpeningFeeParamsMenu, OutboxPayment, OutboxPaymentDetails,
+    OutboxPaymentStatus, PartnerFeeConfig, PartnerFeeDestination, PartnerFeeDetails,
+    PayOfferRequest, PayOnchainAddressRequest, PayOnchainAddressResponse, PayOnchainRequest,
+    PayOnchainResponse, Payment, PaymentDetails, PaymentFailedData, PaymentGroup, PaymentGroupBy,
+    PaymentProbe, PaymentRequestBundle, PaymentStatus, PaymentType, PaymentTypeFilter,
+    PeerConnectivity, PendingChannelOpen, PrepareOnchainPaymentRequest,
+    PrepareOnchainPaymentResponse, PrepareReceivePaymentRequest, PrepareReceivePaymentResponse,
+    PrepareRedeemOnchainFundsRequest, PrepareRedeemOnchainFundsResponse, PrepareRefundRequest,
+    PrepareRefundResponse, PrepareSendPaymentRequest, PrepareSendPaymentResponse,
+    ProbePaymentRequest, ProveAddressOwnershipRequest, ProveAddressOwnershipResponse, ProxyConfig,
+    QueuePaymentRequest, Rate, ReceiveLimitReason, ReceiveOnchainRequest, ReceivePaymentRequest,
+    ReceivePaymentResponse, ReceiveUnifiedRequest, ReceiveUnifiedResponse, RecommendedFees,
+    RecoveryBundle, RedeemLnurlWithdrawVoucherRequest, RedeemOnchainFundsRequest,
+    RedeemOnchainFundsResponse, RefundFeePreview, RefundRequest, RefundResponse,
     ReportIssueRequest, ReportPaymentFailureDetails, ReverseSwapFeesRequest, ReverseSwapInfo,
-    ReverseSwapPairInfo, ReverseSwapStatus, RouteHint, RouteHintHop, SendPaymentRequest,
-    SendPaymentResponse, SendSpontaneousPaymentRequest, ServiceHealthCheckResponse,
-    SignMessageRequest, SignMessageResponse, StaticBackupRequest, StaticBackupResponse,
-    SuccessActionProcessed, SwapAmountType, SwapInfo, SwapStatus, Symbol, TlvEntry,
-    UnspentTransactionOutput, UrlSuccessActionData,
+    ReverseSwapPairInfo, ReverseSwapProtocol, ReverseSwapStatus, RouteHint, RouteHintHop, Sat,
+    SdkInfo, SendPaymentRequest, SendPaymentResponse, SendPaymentsRequest, SendPaymentsResponse,
+    SendSpontaneousPaymentRequest, ServiceComponent, ServiceHealthCheckResponse, ServiceStatus,
+    SignMessageRequest, SignMessageResponse, SnapshotChange, SortOrder, SpendPolicy,
+    SpontaneousPaymentMetadata, StaticBackupRequest, StaticBackupResponse, StaticLnurlPay,
+    StaticLnurlPayCallbackRequest, SuccessActionProcessed, SwapAmountType, SwapInfo, SwapRefundKey,
+    SwapRefundedDetails, SwapStatus, Symbol, TaskState, TaskStatus, TlvEntry, TlvRecord,
+    UnredeemedFundsDetails, UnspentTransactionOutput, UrlSuccessActionData, UserSettings,
+    ValidatePayabilityRequest,
 };
 use log::{Level, LevelFilter, Metadata, Record};
 use once_cell::sync::{Lazy, OnceCell};
@@ -57,14 +86,19 @@ impl BindingLogger {
 impl log::Log for BindingLogger {
     fn enabled(&self, m: &Metadata) -> bool {
         // ignore the internal uniffi log to prevent infinite loop.
-        m.level() <= Level::Trace && *m.target() != *"breez_sdk_bindings::uniffi_binding"
+        m.level() <= Level::Trace
+            && *m.target() != *"breez_sdk_bindings::uniffi_binding"
+            && log_filter_enabled(m).unwrap_or(true)
     }
 
     fn log(&self, record: &Record) {
-        self.log_stream.log(LogEntry {
-            line: record.args().to_string(),
-            level: record.level().as_str().to_string(),
-        });
+        if self.enabled(record.metadata()) {
+            self.log_stream.log(LogEntry {
+                line: record.args().to_string(),
+                level: record.level().as_str().to_string(),
+                module: record.module_path().unwrap_or(record.target()).to_string(),
+            });
+        }
     }
     fn flush(&self) {}
 }
@@ -78,6 +112,11 @@ pub fn default_config(
     BreezServices::default_config(env_type, api_key, node_config)
 }
 
+/// Reports the version of the SDK and the protocols it supports
+pub fn sdk_info() -> SdkInfo {
+    BreezServices::sdk_info()
+}
+
 /// Get the static backup data from the peristent storage.
 /// This data enables the user to recover the node in an external core ligntning node.
 /// See here for instructions on how to recover using this data: https://docs.corelightning.org/docs/backup-and-recovery#backing-up-using-static-channel-backup
@@ -85,11 +124,21 @@ pub fn static_backup(req: StaticBackupRequest) -> SdkResult<StaticBackupResponse
     BreezServices::static_backup(req)
 }
 
+/// Decrypts a recovery bundle with the private key of its recipient.
+pub fn decrypt_recovery_bundle(req: DecryptRecoveryBundleRequest) -> SdkResult<RecoveryBundle> {
+    BreezServices::decrypt_recovery_bundle(req)
+}
+
 /// Fetches the service health check from the support API.
 pub fn service_health_check(api_key: String) -> SdkResult<ServiceHealthCheckResponse> {
     rt().block_on(BreezServices::service_health_check(api_key))
 }
 
+/// Lists the differences between two snapshots of [BlockingBreezServices::diagnostic_snapshot].
+pub fn diff_snapshots(a: String, b: String) -> SdkResult<Vec<SnapshotChange>> {
+    sdk_diff_snapshots(a, b)
+}
+
 /// connect initializes the SDK services, schedule the node to run in the cloud and
 /// run the signer. This must be called in order to start communicating with the node.
 ///
@@ -113,6 +162,23 @@ pub fn connect(
     })
 }
 
+/// See [BreezServices::connect_ephemeral]
+pub fn connect_ephemeral(
+    config: Config,
+    event_listener: Box<dyn EventListener>,
+) -> Result<Arc<BlockingBreezServices>, ConnectError> {
+    rt().block_on(async move {
+        let breez_services = BreezServices::connect_ephemeral(config, event_listener).await?;
+
+        Ok(Arc::new(BlockingBreezServices { breez_services }))
+    })
+}
+
+/// See [BreezServices::set_log_filter]
+pub fn set_log_filter(filter: String) -> SdkResult<()> {
+    BreezServices::set_log_filter(&filter)
+}
+
 /// If used, this must be called before `connect`
 pub fn set_log_stream(log_stream: Box<dyn LogStream>) -> SdkResult<()> {
     LOG_INIT.set(true).map_err(|_| SdkError::Generic {
@@ -142,6 +208,29 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.send_payment(req))
     }
 
+    pub fn send_payments(&self, req: SendPaymentsRequest) -> SdkResult<SendPaymentsResponse> {
+        rt().block_on(self.breez_services.send_payments(req))
+    }
+
+    pub fn verify_invoices(&self, invoices: Vec<String>) -> Vec<InvoiceVerificationResult> {
+        rt().block_on(self.breez_services.verify_invoices(invoices))
+    }
+
+    pub fn queue_payment(
+        &self,
+        req: QueuePaymentRequest,
+    ) -> Result<OutboxPayment, SendPaymentError> {
+        rt().block_on(self.breez_services.queue_payment(req))
+    }
+
+    pub fn list_pending_outbox(&self) -> SdkResult<Vec<OutboxPayment>> {
+        rt().block_on(self.breez_services.list_pending_outbox())
+    }
+
+    pub fn cancel_outbox_payment(&self, id: i64) -> SdkResult<()> {
+        rt().block_on(self.breez_services.cancel_outbox_payment(id))
+    }
+
     pub fn send_spontaneous_payment(
         &self,
         req: SendSpontaneousPaymentRequest,
@@ -149,6 +238,43 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.send_spontaneous_payment(req))
     }
 
+    pub fn cancel(&self, cancel_token: String) -> SdkResult<bool> {
+        rt().block_on(self.breez_services.cancel(cancel_token))
+    }
+
+    pub fn probe_payment(&self, req: ProbePaymentRequest) -> SdkResult<PaymentProbe> {
+        rt().block_on(self.breez_services.probe_payment(req))
+    }
+
+    pub fn validate_payability(
+        &self,
+        req: ValidatePayabilityRequest,
+    ) -> SdkResult<InvoicePayability> {
+        rt().block_on(self.breez_services.validate_payability(req))
+    }
+
+    pub fn list_payment_probes(&self) -> SdkResult<Vec<PaymentProbe>> {
+        rt().block_on(self.breez_services.list_payment_probes())
+    }
+
+    pub fn prepare_send_payment(
+        &self,
+        req: PrepareSendPaymentRequest,
+    ) -> Result<PrepareSendPaymentResponse, SendPaymentError> {
+        rt().block_on(self.breez_services.prepare_send_payment(req))
+    }
+
+    pub fn pay_offer(&self, req: PayOfferRequest) -> Result<SendPaymentResponse, SendPaymentError> {
+        rt().block_on(self.breez_services.pay_offer(req))
+    }
+
+    pub fn prepare_receive_payment(
+        &self,
+        req: PrepareReceivePaymentRequest,
+    ) -> Result<PrepareReceivePaymentResponse, ReceivePaymentError> {
+        rt().block_on(self.breez_services.prepare_receive_payment(req))
+    }
+
     pub fn receive_payment(
         &self,
         req: ReceivePaymentRequest,
@@ -156,6 +282,17 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.receive_payment(req))
     }
 
+    pub fn receive_unified(
+        &self,
+        req: ReceiveUnifiedRequest,
+    ) -> Result<ReceiveUnifiedResponse, ReceivePaymentError> {
+        rt().block_on(self.breez_services.receive_unified(req))
+    }
+
+    pub fn amend_invoice(&self, req: AmendInvoiceRequest) -> SdkResult<LNInvoice> {
+        rt().block_on(self.breez_services.amend_invoice(req))
+    }
+
     pub fn node_credentials(&self) -> SdkResult<Option<NodeCredentials>> {
         rt().block_on(self.breez_services.node_credentials())
     }
@@ -164,6 +301,39 @@ impl BlockingBreezServices {
         self.breez_services.node_info()
     }
 
+    pub fn balances(&self) -> SdkResult<Balances> {
+        self.breez_services.balances()
+    }
+
+    pub fn get_balance_breakdown(&self) -> SdkResult<BalanceBreakdown> {
+        rt().block_on(self.breez_services.get_balance_breakdown())
+    }
+
+    pub fn derive_encryption_key(
+        &self,
+        req: DeriveEncryptionKeyRequest,
+    ) -> SdkResult<DeriveEncryptionKeyResponse> {
+        rt().block_on(self.breez_services.derive_encryption_key(req))
+    }
+
+    pub fn prove_address_ownership(
+        &self,
+        req: ProveAddressOwnershipRequest,
+    ) -> SdkResult<ProveAddressOwnershipResponse> {
+        rt().block_on(self.breez_services.prove_address_ownership(req))
+    }
+
+    pub fn encrypt_payment_request(&self, bundle: PaymentRequestBundle) -> SdkResult<String> {
+        rt().block_on(self.breez_services.encrypt_payment_request(bundle))
+    }
+
+    pub fn decrypt_payment_request(
+        &self,
+        data: EncryptedPaymentRequestData,
+    ) -> SdkResult<PaymentRequestBundle> {
+        rt().block_on(self.breez_services.decrypt_payment_request(data))
+    }
+
     pub fn sign_message(&self, req: SignMessageRequest) -> SdkResult<SignMessageResponse> {
         rt().block_on(self.breez_services.sign_message(req))
     }
@@ -180,14 +350,45 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.backup())
     }
 
+    pub fn rotate_backup_key(&self) -> SdkResult<()> {
+        rt().block_on(self.breez_services.rotate_backup_key())
+    }
+
+    pub fn list_backup_versions(&self) -> SdkResult<Vec<u64>> {
+        rt().block_on(self.breez_services.list_backup_versions())
+    }
+
+    pub fn restore_backup(&self, version: u64) -> SdkResult<()> {
+        rt().block_on(self.breez_services.restore_backup(version))
+    }
+
     pub fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
         rt().block_on(self.breez_services.list_payments(req))
     }
 
+    pub fn list_payment_groups(
+        &self,
+        req: ListPaymentGroupsRequest,
+    ) -> SdkResult<Vec<PaymentGroup>> {
+        rt().block_on(self.breez_services.list_payment_groups(req))
+    }
+
+    pub fn export_payments(&self, req: ExportPaymentsRequest) -> SdkResult<String> {
+        rt().block_on(self.breez_services.export_payments(req))
+    }
+
+    pub fn import_payments(&self, req: ImportPaymentsRequest) -> SdkResult<ImportPaymentsResponse> {
+        rt().block_on(self.breez_services.import_payments(req))
+    }
+
     pub fn payment_by_hash(&self, hash: String) -> SdkResult<Option<Payment>> {
         rt().block_on(self.breez_services.payment_by_hash(hash))
     }
 
+    pub fn list_channel_fees_paid(&self) -> SdkResult<Vec<ChannelOpeningFee>> {
+        rt().block_on(self.breez_services.list_channel_fees_paid())
+    }
+
     pub fn set_payment_metadata(&self, hash: String, metadata: String) -> SdkResult<()> {
         rt().block_on(self.breez_services.set_payment_metadata(hash, metadata))
     }
@@ -210,10 +411,38 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.lnurl_auth(req_data))
     }
 
+    pub fn list_lnurl_auth_domains(&self) -> SdkResult<Vec<LnUrlAuthDomain>> {
+        self.breez_services.list_lnurl_auth_domains()
+    }
+
+    pub fn lnurl_auth_linking_key(&self, domain: String) -> SdkResult<String> {
+        rt().block_on(self.breez_services.lnurl_auth_linking_key(domain))
+    }
+
+    pub fn export_lnurl_auth(&self) -> SdkResult<LnUrlAuthExport> {
+        rt().block_on(self.breez_services.export_lnurl_auth())
+    }
+
+    pub fn import_lnurl_auth(&self, export: LnUrlAuthExport) -> SdkResult<()> {
+        rt().block_on(self.breez_services.import_lnurl_auth(export))
+    }
+
     pub fn report_issue(&self, req: ReportIssueRequest) -> SdkResult<()> {
         rt().block_on(self.breez_services.report_issue(req))
     }
 
+    pub fn peer_connectivity(&self) -> SdkResult<PeerConnectivity> {
+        rt().block_on(self.breez_services.peer_connectivity())
+    }
+
+    pub fn connection_status(&self) -> SdkResult<ConnectionStatus> {
+        rt().block_on(self.breez_services.connection_status())
+    }
+
+    pub fn task_status(&self) -> SdkResult<Vec<TaskStatus>> {
+        self.breez_services.task_status()
+    }
+
     pub fn redeem_onchain_funds(
         &self,
         req: RedeemOnchainFundsRequest,
@@ -221,10 +450,38 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.redeem_onchain_funds(req))
     }
 
+    pub fn bump_fee(&self, req: BumpFeeRequest) -> SdkResult<BumpFeeResponse> {
+        rt().block_on(self.breez_services.bump_fee(req))
+    }
+
     pub fn fetch_fiat_rates(&self) -> SdkResult<Vec<Rate>> {
         rt().block_on(self.breez_services.fetch_fiat_rates())
     }
 
+    pub fn fiat_rate_history(
+        &self,
+        currency: String,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> SdkResult<Vec<HistoricalRate>> {
+        rt().block_on(
+            self.breez_services
+                .fiat_rate_history(currency, from_timestamp, to_timestamp),
+        )
+    }
+
+    pub fn add_fiat_rate_alert(&self, req: AddFiatRateAlertRequest) -> SdkResult<FiatRateAlert> {
+        rt().block_on(self.breez_services.add_fiat_rate_alert(req))
+    }
+
+    pub fn list_fiat_rate_alerts(&self) -> SdkResult<Vec<FiatRateAlert>> {
+        rt().block_on(self.breez_services.list_fiat_rate_alerts())
+    }
+
+    pub fn remove_fiat_rate_alert(&self, id: i64) -> SdkResult<()> {
+        rt().block_on(self.breez_services.remove_fiat_rate_alert(id))
+    }
+
     pub fn list_fiat_currencies(&self) -> SdkResult<Vec<FiatCurrency>> {
         rt().block_on(self.breez_services.list_fiat_currencies())
     }
@@ -245,6 +502,14 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.lsp_id())
     }
 
+    pub fn user_settings(&self) -> SdkResult<UserSettings> {
+        self.breez_services.user_settings()
+    }
+
+    pub fn set_user_settings(&self, settings: UserSettings) -> SdkResult<()> {
+        self.breez_services.set_user_settings(settings)
+    }
+
     pub fn lsp_info(&self) -> SdkResult<LspInformation> {
         rt().block_on(self.breez_services.lsp_info())
     }
@@ -263,6 +528,40 @@ impl BlockingBreezServices {
         })
     }
 
+    pub fn list_channels(&self) -> SdkResult<Vec<ChannelDetails>> {
+        rt().block_on(self.breez_services.list_channels())
+    }
+
+    pub fn close_channel(&self, req: CloseChannelRequest) -> SdkResult<CloseChannelResponse> {
+        rt().block_on(self.breez_services.close_channel(req))
+    }
+
+    pub fn connect_peer(&self, req: ConnectPeerRequest) -> SdkResult<()> {
+        rt().block_on(self.breez_services.connect_peer(req))
+    }
+
+    pub fn open_channel(&self, req: OpenChannelRequest) -> SdkResult<OpenChannelResponse> {
+        rt().block_on(self.breez_services.open_channel(req))
+    }
+
+    pub fn export_node_migration(
+        &self,
+        req: NodeMigrationRequest,
+    ) -> SdkResult<NodeMigrationState> {
+        rt().block_on(self.breez_services.export_node_migration(req))
+    }
+
+    pub fn export_recovery_bundle(
+        &self,
+        req: ExportRecoveryBundleRequest,
+    ) -> SdkResult<ExportRecoveryBundleResponse> {
+        rt().block_on(self.breez_services.export_recovery_bundle(req))
+    }
+
+    pub fn stop_recovery_bundle_refresh(&self) -> SdkResult<()> {
+        self.breez_services.stop_recovery_bundle_refresh()
+    }
+
     pub fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
         rt().block_on(async { self.breez_services.register_webhook(webhook_url).await })
     }
@@ -271,6 +570,46 @@ impl BlockingBreezServices {
         rt().block_on(async { self.breez_services.unregister_webhook(webhook_url).await })
     }
 
+    pub fn register_lightning_address(&self, username: String) -> SdkResult<LnurlPayInfo> {
+        rt().block_on(self.breez_services.register_lightning_address(username))
+    }
+
+    pub fn lnurl_pay_info(&self) -> SdkResult<Option<LnurlPayInfo>> {
+        self.breez_services.lnurl_pay_info()
+    }
+
+    pub fn create_static_lnurl_pay(
+        &self,
+        req: CreateStaticLnurlPayRequest,
+    ) -> SdkResult<StaticLnurlPay> {
+        rt().block_on(self.breez_services.create_static_lnurl_pay(req))
+    }
+
+    pub fn static_lnurl_pay_callback(
+        &self,
+        req: StaticLnurlPayCallbackRequest,
+    ) -> SdkResult<String> {
+        rt().block_on(self.breez_services.static_lnurl_pay_callback(req))
+    }
+
+    pub fn create_lnurl_withdraw_voucher(
+        &self,
+        req: CreateLnurlWithdrawVoucherRequest,
+    ) -> SdkResult<LnurlWithdrawVoucher> {
+        rt().block_on(self.breez_services.create_lnurl_withdraw_voucher(req))
+    }
+
+    pub fn redeem_lnurl_withdraw_voucher(
+        &self,
+        req: RedeemLnurlWithdrawVoucherRequest,
+    ) -> Result<SendPaymentResponse, SendPaymentError> {
+        rt().block_on(self.breez_services.redeem_lnurl_withdraw_voucher(req))
+    }
+
+    pub fn list_lnurl_withdraw_vouchers(&self) -> SdkResult<Vec<LnurlWithdrawVoucher>> {
+        self.breez_services.list_lnurl_withdraw_vouchers()
+    }
+
     /// Onchain receive swap API
     pub fn receive_onchain(
         &self,
@@ -294,11 +633,22 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.redeem_swap(swap_address))
     }
 
+    pub fn verify_swap_address(&self, swap_address: String) -> SdkResult<bool> {
+        rt().block_on(self.breez_services.verify_swap_address(swap_address))
+    }
+
     /// list non-completed expired swaps that should be refunded by calling [BreezServices::refund]
     pub fn list_refundables(&self) -> SdkResult<Vec<SwapInfo>> {
         rt().block_on(self.breez_services.list_refundables())
     }
 
+    pub fn export_swap_refund_keys(
+        &self,
+        req: ExportSwapRefundKeysRequest,
+    ) -> SdkResult<Vec<SwapRefundKey>> {
+        rt().block_on(self.breez_services.export_swap_refund_keys(req))
+    }
+
     // prepare a refund transaction for a failed/expired swap
     // optionally used to know fees before calling `refund()`
     pub fn prepare_refund(&self, req: PrepareRefundRequest) -> SdkResult<PrepareRefundResponse> {
@@ -348,6 +698,13 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.pay_onchain(req))
     }
 
+    pub fn pay_onchain_address(
+        &self,
+        req: PayOnchainAddressRequest,
+    ) -> Result<PayOnchainAddressResponse, SendOnchainError> {
+        rt().block_on(self.breez_services.pay_onchain_address(req))
+    }
+
     pub fn execute_dev_command(&self, command: String) -> SdkResult<String> {
         rt().block_on(self.breez_services.execute_dev_command(command))
     }
@@ -356,10 +713,26 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.generate_diagnostic_data())
     }
 
+    pub fn diagnostic_snapshot(&self) -> SdkResult<String> {
+        rt().block_on(self.breez_services.diagnostic_snapshot())
+    }
+
     pub fn sync(&self) -> SdkResult<()> {
         rt().block_on(self.breez_services.sync())
     }
 
+    pub fn cancel_sync(&self) -> SdkResult<bool> {
+        rt().block_on(self.breez_services.cancel_sync())
+    }
+
+    pub fn metrics(&self) -> SdkResult<MetricsSnapshot> {
+        rt().block_on(self.breez_services.metrics())
+    }
+
+    pub fn sync_partial(&self) -> SdkResult<()> {
+        rt().block_on(self.breez_services.sync_partial())
+    }
+
     pub fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
         rt().block_on(self.breez_services.recommended_fees())
     }
@@ -371,6 +744,13 @@ impl BlockingBreezServices {
         rt().block_on(self.breez_services.buy_bitcoin(req))
     }
 
+    pub fn fetch_buy_bitcoin_quote(
+        &self,
+        req: FetchBuyBitcoinQuoteRequest,
+    ) -> SdkResult<BuyBitcoinQuote> {
+        rt().block_on(self.breez_services.fetch_buy_bitcoin_quote(req))
+    }
+
     pub fn prepare_redeem_onchain_funds(
         &self,
         req: PrepareRedeemOnchainFundsRequest,
@@ -395,4 +775,28 @@ fn rt() -> &'static tokio::runtime::Runtime {
     &RT
 }
 
+impl UniffiCustomTypeConverter for MilliSat {
+    type Builtin = u64;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(MilliSat(val))
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.msat()
+    }
+}
+
+impl UniffiCustomTypeConverter for Sat {
+    type Builtin = u64;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(Sat(val))
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.sat()
+    }
+}
+
 uniffi::include_scaffolding!("breez_sdk");
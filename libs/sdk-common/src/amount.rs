@@ -0,0 +1,197 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Sub};
+
+use serde::{Deserialize, Serialize};
+
+/// An amount in millisatoshis.
+///
+/// Serialized as a plain number, so it can replace an `amount_msat: u64` field without changing
+/// the serialized form. The conversions to and from [Sat] are explicit, to avoid unit mistakes.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct MilliSat(pub u64);
+
+/// An amount in satoshis.
+///
+/// Serialized as a plain number, so it can replace an `amount_sat: u64` field without changing
+/// the serialized form.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Sat(pub u64);
+
+impl MilliSat {
+    pub const ZERO: MilliSat = MilliSat(0);
+
+    pub fn msat(self) -> u64 {
+        self.0
+    }
+
+    /// The whole sats in this amount, dropping the millisatoshis that don't add up to a sat
+    pub fn to_sat_floor(self) -> Sat {
+        Sat(self.0 / 1000)
+    }
+
+    /// The sats needed to cover this amount, rounding up a partial sat
+    pub fn to_sat_ceil(self) -> Sat {
+        Sat(self.0.div_ceil(1000))
+    }
+
+    /// Whether this amount is a whole number of sats
+    pub fn is_whole_sat(self) -> bool {
+        self.0 % 1000 == 0
+    }
+
+    pub fn checked_add(self, rhs: MilliSat) -> Option<MilliSat> {
+        self.0.checked_add(rhs.0).map(MilliSat)
+    }
+
+    pub fn checked_sub(self, rhs: MilliSat) -> Option<MilliSat> {
+        self.0.checked_sub(rhs.0).map(MilliSat)
+    }
+
+    pub fn saturating_sub(self, rhs: MilliSat) -> MilliSat {
+        MilliSat(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Sat {
+    pub const ZERO: Sat = Sat(0);
+
+    pub fn sat(self) -> u64 {
+        self.0
+    }
+
+    /// This amount in millisatoshis. Panics on overflow, like the arithmetic on [u64].
+    pub fn to_msat(self) -> MilliSat {
+        MilliSat(self.0 * 1000)
+    }
+
+    pub fn checked_to_msat(self) -> Option<MilliSat> {
+        self.0.checked_mul(1000).map(MilliSat)
+    }
+
+    pub fn checked_add(self, rhs: Sat) -> Option<Sat> {
+        self.0.checked_add(rhs.0).map(Sat)
+    }
+
+    pub fn checked_sub(self, rhs: Sat) -> Option<Sat> {
+        self.0.checked_sub(rhs.0).map(Sat)
+    }
+
+    pub fn saturating_sub(self, rhs: Sat) -> Sat {
+        Sat(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl From<Sat> for MilliSat {
+    fn from(sat: Sat) -> Self {
+        sat.to_msat()
+    }
+}
+
+impl From<u64> for MilliSat {
+    fn from(msat: u64) -> Self {
+        MilliSat(msat)
+    }
+}
+
+impl From<MilliSat> for u64 {
+    fn from(msat: MilliSat) -> Self {
+        msat.0
+    }
+}
+
+impl From<u64> for Sat {
+    fn from(sat: u64) -> Self {
+        Sat(sat)
+    }
+}
+
+impl From<Sat> for u64 {
+    fn from(sat: Sat) -> Self {
+        sat.0
+    }
+}
+
+impl fmt::Display for MilliSat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} msat", self.0)
+    }
+}
+
+impl fmt::Display for Sat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sat", self.0)
+    }
+}
+
+macro_rules! impl_amount_ops {
+    ($t:ident) => {
+        impl Add for $t {
+            type Output = $t;
+
+            fn add(self, rhs: $t) -> $t {
+                $t(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $t {
+            fn add_assign(&mut self, rhs: $t) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+
+            fn sub(self, rhs: $t) -> $t {
+                $t(self.0 - rhs.0)
+            }
+        }
+
+        impl Sum for $t {
+            fn sum<I: Iterator<Item = $t>>(iter: I) -> $t {
+                iter.fold($t(0), Add::add)
+            }
+        }
+    };
+}
+
+impl_amount_ops!(MilliSat);
+impl_amount_ops!(Sat);
+
+#[cfg(test)]
+mod tests {
+    use super::{MilliSat, Sat};
+
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_amount_conversions() {
+        assert_eq!(MilliSat::from(Sat(21)), MilliSat(21_000));
+        assert_eq!(MilliSat(21_999).to_sat_floor(), Sat(21));
+        assert_eq!(MilliSat(21_001).to_sat_ceil(), Sat(22));
+        assert_eq!(MilliSat(21_000).to_sat_ceil(), Sat(21));
+        assert!(MilliSat(21_000).is_whole_sat());
+        assert!(!MilliSat(21_001).is_whole_sat());
+        assert_eq!(Sat(u64::MAX).checked_to_msat(), None);
+        assert_eq!(
+            [Sat(1), Sat(2)].into_iter().sum::<Sat>().to_msat(),
+            MilliSat(3_000)
+        );
+        assert_eq!(MilliSat(1).saturating_sub(MilliSat(2)), MilliSat::ZERO);
+    }
+
+    #[sdk_macros::test_all]
+    fn test_amount_serde() {
+        // Serialized as the bare number, like the u64 fields they replace
+        assert_eq!(serde_json::to_string(&MilliSat(1500)).unwrap(), "1500");
+        assert_eq!(serde_json::from_str::<Sat>("42").unwrap(), Sat(42));
+    }
+}
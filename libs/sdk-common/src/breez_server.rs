@@ -15,7 +15,7 @@ use crate::grpc::swapper_client::SwapperClient;
 use crate::grpc::taproot_swapper_client::TaprootSwapperClient;
 use crate::grpc::transport::{GrpcClient, Transport};
 use crate::grpc::{ChainApiServersRequest, PingRequest};
-use crate::prelude::{ServiceConnectivityError, ServiceConnectivityErrorKind};
+use crate::prelude::{ProxyConfig, ServiceConnectivityError, ServiceConnectivityErrorKind};
 use crate::with_connection_retry;
 
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
@@ -31,8 +31,17 @@ pub struct BreezServer {
 
 impl BreezServer {
     pub fn new(server_url: String, api_key: Option<String>) -> anyhow::Result<Self> {
+        Self::new_with_proxy(server_url, api_key, None)
+    }
+
+    /// Like [BreezServer::new], with the gRPC connections going through the given SOCKS5 proxy
+    pub fn new_with_proxy(
+        server_url: String,
+        api_key: Option<String>,
+        proxy: Option<ProxyConfig>,
+    ) -> anyhow::Result<Self> {
         Ok(Self {
-            grpc_client: Mutex::new(GrpcClient::new(server_url)?),
+            grpc_client: Mutex::new(GrpcClient::new_with_proxy(server_url, proxy)?),
             api_key,
         })
     }
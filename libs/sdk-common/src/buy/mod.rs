@@ -1,7 +1,24 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use maybe_sync::{MaybeSend, MaybeSync};
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::RestClient;
 
 pub mod moonpay;
+pub mod transak;
+
+/// What buying Bitcoin with a fiat amount costs at a provider, see
+/// [BuyBitcoinProviderApi::fetch_quote]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BuyBitcoinQuote {
+    pub fiat_currency: String,
+    /// The fiat amount paid, fees included
+    pub fiat_amount: f64,
+    /// The fees of the provider and the network fee, in the fiat currency
+    pub fees_fiat: f64,
+    /// The amount expected to be sent to the address, after fees
+    pub amount_sat: u64,
+}
 
 #[sdk_macros::async_trait]
 pub trait BuyBitcoinProviderApi: MaybeSend + MaybeSync {
@@ -13,4 +30,15 @@ pub trait BuyBitcoinProviderApi: MaybeSend + MaybeSync {
         max_amount_sat: Option<u64>,
         redirect_url: Option<String>,
     ) -> Result<String>;
+
+    /// Fetch what buying Bitcoin for `fiat_amount`, fees included, costs at the provider, so
+    /// providers can be compared before calling [Self::buy_bitcoin]
+    async fn fetch_quote(
+        &self,
+        _rest_client: &dyn RestClient,
+        _fiat_currency: &str,
+        _fiat_amount: f64,
+    ) -> Result<BuyBitcoinQuote> {
+        Err(anyhow!("The provider doesn't support quotes"))
+    }
 }
@@ -1,9 +1,14 @@
 use anyhow::Result;
+use serde::Deserialize;
 use url::Url;
 
-use crate::{grpc::SignUrlRequest, prelude::BreezServer, utils::Arc};
+use crate::{
+    grpc::SignUrlRequest,
+    prelude::{get_and_check_success, parse_json, BreezServer, RestClient},
+    utils::Arc,
+};
 
-use super::BuyBitcoinProviderApi;
+use super::{BuyBitcoinProviderApi, BuyBitcoinQuote};
 
 #[derive(Clone)]
 struct MoonPayConfig {
@@ -14,6 +19,21 @@ struct MoonPayConfig {
     pub color_code: String,
     pub redirect_url: String,
     pub enabled_payment_methods: String,
+    pub quote_url: String,
+}
+
+/// The response of the Moonpay buy quote API, with the amounts in the fiat currency unless noted
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MoonpayQuote {
+    /// The amount of BTC bought
+    quote_currency_amount: f64,
+    fee_amount: f64,
+    #[serde(default)]
+    extra_fee_amount: f64,
+    #[serde(default)]
+    network_fee_amount: f64,
+    total_amount: f64,
 }
 
 fn moonpay_config() -> MoonPayConfig {
@@ -27,9 +47,21 @@ fn moonpay_config() -> MoonPayConfig {
         enabled_payment_methods: String::from(
             "credit_debit_card,sepa_bank_transfer,gbp_bank_transfer",
         ),
+        quote_url: String::from("https://api.moonpay.com/v3/currencies/btc/buy_quote"),
     }
 }
 
+fn create_moonpay_quote_url(fiat_currency: &str, fiat_amount: f64) -> Result<Url> {
+    let config = moonpay_config();
+    let params = vec![
+        ("apiKey", config.api_key),
+        ("baseCurrencyCode", fiat_currency.to_lowercase()),
+        ("baseCurrencyAmount", fiat_amount.to_string()),
+        ("areFeesIncluded", String::from("true")),
+    ];
+    Ok(Url::parse_with_params(&config.quote_url, params)?)
+}
+
 fn create_moonpay_url(
     wallet_address: String,
     quote_currency_amount: Option<String>,
@@ -98,13 +130,36 @@ impl BuyBitcoinProviderApi for MoonpayProvider {
             .full_url;
         Ok(signed_url)
     }
+
+    async fn fetch_quote(
+        &self,
+        rest_client: &dyn RestClient,
+        fiat_currency: &str,
+        fiat_amount: f64,
+    ) -> Result<BuyBitcoinQuote> {
+        let url = create_moonpay_quote_url(fiat_currency, fiat_amount)?;
+        let (response, _) = get_and_check_success(rest_client, url.as_str()).await?;
+        let quote: MoonpayQuote = parse_json(&response)?;
+        Ok(BuyBitcoinQuote {
+            fiat_currency: fiat_currency.to_uppercase(),
+            fiat_amount: quote.total_amount,
+            fees_fiat: quote.fee_amount + quote.extra_fee_amount + quote.network_fee_amount,
+            amount_sat: (quote.quote_currency_amount * 100_000_000.0).round() as u64,
+        })
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use std::collections::HashMap;
 
-    use crate::prelude::moonpay::{create_moonpay_url, moonpay_config};
+    use crate::prelude::moonpay::{create_moonpay_quote_url, create_moonpay_url, moonpay_config};
+    use crate::prelude::{
+        BreezServer, BuyBitcoinProviderApi, BuyBitcoinQuote, MoonpayProvider,
+        PRODUCTION_BREEZSERVER_URL,
+    };
+    use crate::test_utils::mock_rest_client::{MockResponse, MockRestClient};
+    use crate::utils::Arc;
 
     #[sdk_macros::async_test_all]
     async fn test_sign_moonpay_url() -> Result<(), Box<dyn std::error::Error>> {
@@ -166,4 +221,46 @@ pub(crate) mod tests {
         assert_eq!(query_pairs.get("quoteCurrencyAmount"), Some(&quote_amount),);
         Ok(())
     }
+
+    #[sdk_macros::async_test_all]
+    async fn test_fetch_moonpay_quote() -> Result<(), Box<dyn std::error::Error>> {
+        let url = create_moonpay_quote_url("USD", 100.0)?;
+        let query_pairs = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+        assert_eq!(url.host_str(), Some("api.moonpay.com"));
+        assert_eq!(
+            query_pairs.get("baseCurrencyCode"),
+            Some(&"usd".to_string())
+        );
+        assert_eq!(
+            query_pairs.get("baseCurrencyAmount"),
+            Some(&"100".to_string())
+        );
+        assert_eq!(
+            query_pairs.get("areFeesIncluded"),
+            Some(&"true".to_string())
+        );
+
+        let rest_client = MockRestClient::new();
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"baseCurrencyAmount": 95.25, "quoteCurrencyAmount": 0.00145, "feeAmount": 3.5,
+                "extraFeeAmount": 1, "networkFeeAmount": 0.25, "totalAmount": 100}"#
+                .to_string(),
+        ));
+        let provider = MoonpayProvider::new(Arc::new(BreezServer::new(
+            PRODUCTION_BREEZSERVER_URL.to_string(),
+            None,
+        )?));
+        let quote = provider.fetch_quote(&rest_client, "usd", 100.0).await?;
+        assert_eq!(
+            quote,
+            BuyBitcoinQuote {
+                fiat_currency: "USD".to_string(),
+                fiat_amount: 100.0,
+                fees_fiat: 4.75,
+                amount_sat: 145_000,
+            }
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,178 @@
+use anyhow::Result;
+use serde::Deserialize;
+use url::Url;
+
+use crate::prelude::{get_and_check_success, parse_json, RestClient};
+
+use super::{BuyBitcoinProviderApi, BuyBitcoinQuote};
+
+const TRANSAK_BASE_URL: &str = "https://global.transak.com";
+const TRANSAK_QUOTE_URL: &str = "https://api.transak.com/api/v1/pricing/public/quotes";
+
+/// The response of the Transak pricing API
+#[derive(Deserialize)]
+struct TransakQuoteResponse {
+    response: TransakQuote,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransakQuote {
+    fiat_amount: f64,
+    /// The amount of BTC bought
+    crypto_amount: f64,
+    total_fee: f64,
+}
+
+fn create_transak_url(
+    api_key: &str,
+    wallet_address: String,
+    crypto_amount: Option<String>,
+    redirect_url: Option<String>,
+) -> Result<Url> {
+    let mut params = vec![
+        ("apiKey", api_key.to_string()),
+        ("productsAvailed", String::from("BUY")),
+        ("cryptoCurrencyCode", String::from("BTC")),
+        ("network", String::from("mainnet")),
+        ("walletAddress", wallet_address),
+        ("disableWalletAddressForm", String::from("true")),
+    ];
+    if let Some(crypto_amount) = crypto_amount {
+        params.push(("cryptoAmount", crypto_amount));
+    }
+    if let Some(redirect_url) = redirect_url {
+        params.push(("redirectURL", redirect_url));
+    }
+    Ok(Url::parse_with_params(TRANSAK_BASE_URL, params)?)
+}
+
+fn create_transak_quote_url(api_key: &str, fiat_currency: &str, fiat_amount: f64) -> Result<Url> {
+    let params = vec![
+        ("partnerApiKey", api_key.to_string()),
+        ("fiatCurrency", fiat_currency.to_uppercase()),
+        ("cryptoCurrency", String::from("BTC")),
+        ("network", String::from("mainnet")),
+        ("isBuyOrSell", String::from("BUY")),
+        ("paymentMethod", String::from("credit_debit_card")),
+        ("fiatAmount", fiat_amount.to_string()),
+    ];
+    Ok(Url::parse_with_params(TRANSAK_QUOTE_URL, params)?)
+}
+
+/// Buys Bitcoin through Transak with the API key of a Transak partner account.
+///
+/// Transak has no maximum amount parameter, so the `max_amount_sat` of
+/// [BuyBitcoinProviderApi::buy_bitcoin] is not enforced by the provider.
+pub struct TransakProvider {
+    api_key: String,
+}
+
+impl TransakProvider {
+    pub fn new(api_key: String) -> Self {
+        Self { api_key }
+    }
+}
+
+#[sdk_macros::async_trait]
+impl BuyBitcoinProviderApi for TransakProvider {
+    async fn buy_bitcoin(
+        &self,
+        address: String,
+        locked_amount_sat: Option<u64>,
+        _max_amount_sat: Option<u64>,
+        redirect_url: Option<String>,
+    ) -> Result<String> {
+        let url = create_transak_url(
+            &self.api_key,
+            address,
+            locked_amount_sat.map(|amount| format!("{:.8}", amount as f64 / 100_000_000.0)),
+            redirect_url,
+        )?;
+        Ok(url.to_string())
+    }
+
+    async fn fetch_quote(
+        &self,
+        rest_client: &dyn RestClient,
+        fiat_currency: &str,
+        fiat_amount: f64,
+    ) -> Result<BuyBitcoinQuote> {
+        let url = create_transak_quote_url(&self.api_key, fiat_currency, fiat_amount)?;
+        let (response, _) = get_and_check_success(rest_client, url.as_str()).await?;
+        let quote = parse_json::<TransakQuoteResponse>(&response)?.response;
+        Ok(BuyBitcoinQuote {
+            fiat_currency: fiat_currency.to_uppercase(),
+            fiat_amount: quote.fiat_amount,
+            fees_fiat: quote.total_fee,
+            amount_sat: (quote.crypto_amount * 100_000_000.0).round() as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashMap;
+
+    use crate::prelude::transak::{create_transak_quote_url, create_transak_url};
+    use crate::prelude::{BuyBitcoinProviderApi, BuyBitcoinQuote, TransakProvider};
+    use crate::test_utils::mock_rest_client::{MockResponse, MockRestClient};
+
+    #[sdk_macros::async_test_all]
+    async fn test_transak_url() -> Result<(), Box<dyn std::error::Error>> {
+        let url = create_transak_url(
+            "key",
+            "an address".to_string(),
+            Some("0.00100000".to_string()),
+            None,
+        )?;
+        let query_pairs = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+        assert_eq!(url.host_str(), Some("global.transak.com"));
+        assert_eq!(query_pairs.get("apiKey"), Some(&"key".to_string()));
+        assert_eq!(
+            query_pairs.get("walletAddress"),
+            Some(&"an address".to_string())
+        );
+        assert_eq!(
+            query_pairs.get("cryptoAmount"),
+            Some(&"0.00100000".to_string())
+        );
+        assert_eq!(query_pairs.get("redirectURL"), None);
+
+        let url = create_transak_quote_url("key", "eur", 50.5)?;
+        let query_pairs = url.query_pairs().into_owned().collect::<HashMap<_, _>>();
+        assert_eq!(query_pairs.get("partnerApiKey"), Some(&"key".to_string()));
+        assert_eq!(query_pairs.get("fiatCurrency"), Some(&"EUR".to_string()));
+        assert_eq!(query_pairs.get("fiatAmount"), Some(&"50.5".to_string()));
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_fetch_transak_quote() -> Result<(), Box<dyn std::error::Error>> {
+        let rest_client = MockRestClient::new();
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"response": {"fiatCurrency": "EUR", "fiatAmount": 50, "cryptoAmount": 0.0007,
+                "totalFee": 2.25, "conversionPrice": 0.0000145}}"#
+                .to_string(),
+        ));
+        let provider = TransakProvider::new("key".to_string());
+        let quote = provider.fetch_quote(&rest_client, "eur", 50.0).await?;
+        assert_eq!(
+            quote,
+            BuyBitcoinQuote {
+                fiat_currency: "EUR".to_string(),
+                fiat_amount: 50.0,
+                fees_fiat: 2.25,
+                amount_sat: 70_000,
+            }
+        );
+
+        rest_client.add_response(MockResponse::new(400, "{}".to_string()));
+        assert!(provider
+            .fetch_quote(&rest_client, "eur", 1.0)
+            .await
+            .is_err());
+        Ok(())
+    }
+}
@@ -1,6 +1,14 @@
 use anyhow::Result;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 use std::time::Duration;
 
+use tonic::codegen::Service;
+use tonic::transport::Uri;
+
+use crate::utils::proxy::ProxyConfig;
+
 pub type Transport = tonic::transport::Channel;
 
 #[derive(Clone)]
@@ -10,9 +18,17 @@ pub struct GrpcClient {
 
 impl GrpcClient {
     pub fn new(url: String) -> Result<Self> {
-        Ok(Self {
-            inner: Self::create_endpoint(&url)?.connect_lazy(),
-        })
+        Self::new_with_proxy(url, None)
+    }
+
+    /// Creates a client whose connections are opened through the given SOCKS5 proxy, if any
+    pub fn new_with_proxy(url: String, proxy: Option<ProxyConfig>) -> Result<Self> {
+        let endpoint = Self::create_endpoint(&url)?;
+        let inner = match proxy {
+            Some(proxy) => endpoint.connect_with_connector_lazy(Socks5Connector { proxy }),
+            None => endpoint.connect_lazy(),
+        };
+        Ok(Self { inner })
     }
 
     pub fn into_inner(self) -> Transport {
@@ -29,3 +45,36 @@ impl GrpcClient {
         )
     }
 }
+
+/// Opens the gRPC connections through a SOCKS5 proxy. TLS is still applied on top by tonic.
+#[derive(Clone)]
+struct Socks5Connector {
+    proxy: ProxyConfig,
+}
+
+impl Service<Uri> for Socks5Connector {
+    type Response = tokio::net::TcpStream;
+    type Error = std::io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let proxy = self.proxy.clone();
+        Box::pin(async move {
+            let host = uri.host().ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("Missing host in {uri}"),
+                )
+            })?;
+            let port = uri.port_u16().unwrap_or(match uri.scheme_str() {
+                Some("https") => 443,
+                _ => 80,
+            });
+            proxy.connect(host, port).await
+        })
+    }
+}
@@ -1,4 +1,6 @@
-use anyhow::Result;
+use anyhow::{ensure, Result};
+
+use crate::utils::proxy::ProxyConfig;
 
 pub type Transport = tonic_web_wasm_client::Client;
 
@@ -14,6 +16,14 @@ impl GrpcClient {
         })
     }
 
+    pub fn new_with_proxy(url: String, proxy: Option<ProxyConfig>) -> Result<Self> {
+        ensure!(
+            proxy.is_none(),
+            "Proxies are not supported in the browser, configure one in the browser instead"
+        );
+        Self::new(url)
+    }
+
     pub fn into_inner(self) -> Transport {
         self.inner
     }
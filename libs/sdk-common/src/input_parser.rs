@@ -3,7 +3,7 @@ use std::ops::Not;
 use std::str::FromStr;
 
 use ::bip21::Uri;
-use anyhow::{anyhow, bail, Context, Result};
+use anyhow::{anyhow, bail, ensure, Context, Result};
 use bitcoin::bech32;
 use bitcoin::bech32::FromBase32;
 use log::{debug, error};
@@ -19,6 +19,12 @@ const USER_BITCOIN_PAYMENT_PREFIX: &str = "user._bitcoin-payment";
 const BOLT12_PREFIX: &str = "lno";
 const LNURL_PAY_PREFIX: &str = "lnurl";
 const BIP353_PREFIX: &str = "bitcoin:";
+/// Prefix of the payment requests encrypted for the other apps of the same wallet
+pub const ENCRYPTED_PAYMENT_REQUEST_PREFIX: &str = "breezpr:";
+/// Uppercased bech32 prefixes of the payloads that may be scanned from QR codes encoded in
+/// alphanumeric mode, which only supports uppercase characters.
+const UPPERCASE_BECH32_PREFIXES: [&str; 8] =
+    ["LN", "BC1", "TB1", "BCRT1", "LQ1", "TLQ1", "EX1", "TEX1"];
 
 /// Parses generic user input, typically pasted from clipboard or scanned from a QR.
 ///
@@ -197,7 +203,8 @@ pub async fn parse_with_rest_client<C: RestClient + ?Sized>(
     input: &str,
     external_input_parsers: Option<&[ExternalInputParser]>,
 ) -> Result<InputType> {
-    let input = input.trim();
+    let input = normalize_input(input);
+    let input = input.as_str();
 
     // Try to parse the destination as a bip353 address.
     let (bip353_parsed_input, is_bip353) = match bip353_parse(input).await {
@@ -208,14 +215,21 @@ pub async fn parse_with_rest_client<C: RestClient + ?Sized>(
     if let Ok(input_type) = parse_core(rest_client, &bip353_parsed_input).await {
         let input_type = if is_bip353 {
             match input_type {
-                #[cfg(feature = "liquid")]
+                #[cfg(feature = "bolt12")]
                 InputType::Bolt12Offer { offer, .. } => InputType::Bolt12Offer {
                     offer,
                     bip353_address: Some(input.to_string()),
                 },
-                InputType::LnUrlPay { data, .. } => InputType::LnUrlPay {
+                InputType::LnUrlPay {
+                    data,
+                    amount_msat,
+                    comment,
+                    ..
+                } => InputType::LnUrlPay {
                     data,
                     bip353_address: Some(input.to_string()),
+                    amount_msat,
+                    comment,
                 },
                 i => bail!("Unexpected input type was resolved from a BIP353 address: {i:?}"),
             }
@@ -233,6 +247,41 @@ pub async fn parse_with_rest_client<C: RestClient + ?Sized>(
     Err(anyhow!("Unrecognized input type"))
 }
 
+/// Normalizes user input before it is parsed, so that [parse] and [parse_invoice] handle it
+/// consistently:
+///
+/// - Surrounding whitespace is trimmed.
+/// - URI-encoded input, recognized by an encoded scheme separator (`%3A`) and no plain one, is decoded.
+/// - Uppercase input with a bech32 payload, as scanned from QR codes in alphanumeric mode
+///   (e.g. `LIGHTNING:LNBC1...`), is lowercased. Any query parameters are kept as they are.
+pub fn normalize_input(input: &str) -> String {
+    let mut input = input.trim().to_string();
+
+    if !input.contains(':') && input.to_lowercase().contains("%3a") {
+        if let Ok(decoded) = percent_encoding::percent_decode_str(&input).decode_utf8() {
+            input = decoded.trim().to_string();
+        }
+    }
+
+    let (payload, query) = match input.split_once('?') {
+        Some((payload, query)) => (payload, Some(query)),
+        None => (input.as_str(), None),
+    };
+    let bech32_payload = payload
+        .split_once(':')
+        .map(|(_, rest)| rest)
+        .unwrap_or(payload);
+    let is_uppercase_bech32 = !payload.chars().any(char::is_lowercase)
+        && UPPERCASE_BECH32_PREFIXES
+            .iter()
+            .any(|prefix| bech32_payload.starts_with(prefix));
+    match (is_uppercase_bech32, query) {
+        (true, Some(query)) => format!("{}?{query}", payload.to_lowercase()),
+        (true, None) => payload.to_lowercase(),
+        (false, _) => input,
+    }
+}
+
 fn get_by_key(tuple_vector: &[(&str, &str)], key: &str) -> Option<String> {
     tuple_vector
         .iter()
@@ -303,6 +352,15 @@ async fn bip353_parse(input: &str) -> Option<String> {
 
 /// Core parse implementation
 async fn parse_core<C: RestClient + ?Sized>(rest_client: &C, input: &str) -> Result<InputType> {
+    if let Some(blob) = input.strip_prefix(ENCRYPTED_PAYMENT_REQUEST_PREFIX) {
+        base64::decode_config(blob, base64::URL_SAFE_NO_PAD)?;
+        return Ok(InputType::EncryptedPaymentRequest {
+            data: EncryptedPaymentRequestData {
+                blob: blob.to_string(),
+            },
+        });
+    }
+
     // Covers BIP 21 URIs and simple onchain BTC addresses (which are valid BIP 21 with the 'bitcoin:' prefix)
     if let Ok(bip21_uri) = prepend_if_missing("bitcoin:", input).parse::<Uri<'_>>() {
         let bitcoin_addr_data = bip21_uri.into();
@@ -331,7 +389,7 @@ async fn parse_core<C: RestClient + ?Sized>(rest_client: &C, input: &str) -> Res
         return Ok(InputType::LiquidAddress { address });
     }
 
-    #[cfg(feature = "liquid")]
+    #[cfg(feature = "bolt12")]
     if let Ok(offer) = parse_bolt12_offer(input) {
         return Ok(InputType::Bolt12Offer {
             offer,
@@ -378,8 +436,21 @@ async fn parse_core<C: RestClient + ?Sized>(rest_client: &C, input: &str) -> Res
         .strip_prefix("lightning:")
         .or(input.strip_prefix("LIGHTNING:"))
         .unwrap_or(input);
+    let (input, deep_link_amount_msat, deep_link_comment) = split_deep_link_params(input)?;
     if let Ok((domain, lnurl_endpoint, ln_address)) = lnurl_decode(input) {
-        return resolve_lnurl(rest_client, domain, lnurl_endpoint, ln_address).await;
+        return match resolve_lnurl(rest_client, domain, lnurl_endpoint, ln_address).await? {
+            InputType::LnUrlPay {
+                data,
+                bip353_address,
+                ..
+            } => Ok(InputType::LnUrlPay {
+                data,
+                bip353_address,
+                amount_msat: deep_link_amount_msat,
+                comment: deep_link_comment,
+            }),
+            input_type => Ok(input_type),
+        };
     }
 
     Err(anyhow!("Unrecognized input type"))
@@ -427,6 +498,8 @@ async fn parse_external<C: RestClient + ?Sized>(
                 InputType::LnUrlPay { data, .. } => InputType::LnUrlPay {
                     data: LnUrlPayRequestData { domain, ..data },
                     bip353_address: None,
+                    amount_msat: None,
+                    comment: None,
                 },
                 _ => input_type,
             };
@@ -442,6 +515,35 @@ async fn parse_external<C: RestClient + ?Sized>(
     Err(anyhow!("Unrecognized input type"))
 }
 
+/// Splits the query parameters off deep links like `lightning:lnurl1...?amount=1000&comment=hi`,
+/// returning the LNURL, the `amount` converted from satoshis to millisatoshis and the `comment`.
+///
+/// Only bech32 LNURLs and LN addresses are split: the query parameters of prefix-based LNURLs
+/// (LUD-17) belong to the endpoint URL.
+fn split_deep_link_params(input: &str) -> Result<(&str, Option<u64>, Option<String>)> {
+    let (lnurl, query) = match input.split_once('?') {
+        Some((lnurl, query)) if !lnurl.contains(':') => (lnurl, query),
+        _ => return Ok((input, None, None)),
+    };
+
+    let mut amount_msat = None;
+    let mut comment = None;
+    for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+        match key.as_ref() {
+            "amount" => {
+                amount_msat = value
+                    .parse::<u64>()
+                    .ok()
+                    .and_then(|amount_sat| amount_sat.checked_mul(1000));
+                ensure!(amount_msat.is_some(), "Invalid deep link amount: {value}");
+            }
+            "comment" => comment = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+    Ok((lnurl, amount_msat, comment))
+}
+
 /// Prepends the given prefix to the input, if the input doesn't already start with it
 fn prepend_if_missing(prefix: &str, input: &str) -> String {
     match input.to_lowercase().starts_with(prefix) {
@@ -603,6 +705,8 @@ async fn resolve_lnurl<C: RestClient + ?Sized>(
                 ..data
             },
             bip353_address: None,
+            amount_msat: None,
+            comment: None,
         },
         _ => temp,
     };
@@ -634,7 +738,7 @@ pub enum InputType {
     Bolt11 {
         invoice: LNInvoice,
     },
-    #[cfg(feature = "liquid")]
+    #[cfg(feature = "bolt12")]
     Bolt12Offer {
         offer: LNOffer,
         /// The BIP353 address from which this InputType was resolved
@@ -657,6 +761,12 @@ pub enum InputType {
         data: LnUrlPayRequestData,
         /// The BIP353 address from which this InputType was resolved
         bip353_address: Option<String>,
+        /// The amount set by the `amount` query parameter, in satoshis, of a deep link like
+        /// `lightning:lnurl1...?amount=1000`, converted to millisatoshis. It is not checked
+        /// against the `min_sendable` and `max_sendable` of the endpoint.
+        amount_msat: Option<u64>,
+        /// The comment set by the `comment` query parameter of a deep link
+        comment: Option<String>,
     },
 
     /// # Supported standards
@@ -686,6 +796,12 @@ pub enum InputType {
     LnUrlError {
         data: LnUrlErrorData,
     },
+
+    /// A payment request encrypted by another app of the same wallet, which only the wallet
+    /// can decrypt
+    EncryptedPaymentRequest {
+        data: EncryptedPaymentRequestData,
+    },
 }
 
 /// Generic struct containing the possible LNURL payloads returned when contacting a LNURL endpoint
@@ -721,6 +837,8 @@ impl From<LnUrlRequestData> for InputType {
             PayRequest { data } => Self::LnUrlPay {
                 data,
                 bip353_address: None,
+                amount_msat: None,
+                comment: None,
             },
             WithdrawRequest { data } => Self::LnUrlWithdraw { data },
             AuthRequest { data } => Self::LnUrlAuth { data },
@@ -780,12 +898,12 @@ pub struct LnUrlPayRequestData {
 impl LnUrlPayRequestData {
     /// The minimum amount, in sats, accepted by this LNURL-pay endpoint
     pub fn min_sendable_sats(&self) -> u64 {
-        self.min_sendable / 1000
+        MilliSat(self.min_sendable).to_sat_floor().sat()
     }
 
     /// The maximum amount, in sats, accepted by this LNURL-pay endpoint
     pub fn max_sendable_sats(&self) -> u64 {
-        self.max_sendable / 1000
+        MilliSat(self.max_sendable).to_sat_floor().sat()
     }
 
     /// Parsed metadata items. Use `metadata_str` to get the raw metadata string, as received from
@@ -800,12 +918,12 @@ impl LnUrlPayRequestData {
 impl LnUrlWithdrawRequestData {
     /// The minimum amount, in sats, accepted by this LNURL-withdraw endpoint
     pub fn min_withdrawable_sats(&self) -> u64 {
-        self.min_withdrawable / 1000
+        MilliSat(self.min_withdrawable).to_sat_floor().sat()
     }
 
     /// The maximum amount, in sats, accepted by this LNURL-withdraw endpoint
     pub fn max_withdrawable_sats(&self) -> u64 {
-        self.max_withdrawable / 1000
+        MilliSat(self.max_withdrawable).to_sat_floor().sat()
     }
 }
 
@@ -820,12 +938,23 @@ pub struct MetadataItem {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BitcoinAddressData {
     pub address: String,
+    /// The network of the address. Testnet and signet addresses are encoded the same way, so
+    /// signet addresses are reported as [Network::Testnet]. Use
+    /// [BitcoinAddressData::is_valid_for_network] to check an address against a network.
     pub network: super::prelude::Network,
     pub amount_sat: Option<u64>,
     pub label: Option<String>,
     pub message: Option<String>,
 }
 
+/// Wrapped in a [EncryptedPaymentRequest], this is the result of [parse] when given a blob
+/// starting with [ENCRYPTED_PAYMENT_REQUEST_PREFIX].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EncryptedPaymentRequestData {
+    /// The URL-safe base64 encrypted payment request, without the prefix
+    pub blob: String,
+}
+
 #[derive(Debug)]
 pub enum URISerializationError {
     UnsupportedNetwork,
@@ -834,6 +963,14 @@ pub enum URISerializationError {
 }
 
 impl BitcoinAddressData {
+    /// Whether the address can be used on the `network`, which is the case of testnet addresses
+    /// on signet and the other way around
+    pub fn is_valid_for_network(&self, network: super::prelude::Network) -> bool {
+        self.address
+            .parse::<bitcoin::Address>()
+            .is_ok_and(|address| address.is_valid_for_network(network.into()))
+    }
+
     /// Converts the structure to a BIP21 URI while also
     /// ensuring that all the fields are valid
     pub fn to_uri(&self) -> Result<String, URISerializationError> {
@@ -923,6 +1060,38 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[sdk_macros::test_all]
+    fn test_normalize_input() {
+        let bolt11 = "lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz";
+
+        // Whitespace
+        assert_eq!(normalize_input(&format!(" {bolt11}\n")), bolt11);
+        // Uppercase QR payloads
+        assert_eq!(normalize_input(&bolt11.to_uppercase()), bolt11);
+        assert_eq!(
+            normalize_input(&format!("lightning:{bolt11}").to_uppercase()),
+            format!("lightning:{bolt11}")
+        );
+        assert_eq!(
+            normalize_input("BITCOIN:BC1QXHMDUFSVNUAAAER4YNZ88FSPDSXQ2H9E9CETDJ?label=Hello"),
+            "bitcoin:bc1qxhmdufsvnuaaaer4ynz88fspdsxq2h9e9cetdj?label=Hello"
+        );
+        // URI-encoded input
+        assert_eq!(
+            normalize_input(&format!("lightning%3A{bolt11}")),
+            format!("lightning:{bolt11}")
+        );
+        assert_eq!(
+            normalize_input("bitcoin:1andreas3batLhQa2FawWjeyjCqyBzypd?label=Hello%20World"),
+            "bitcoin:1andreas3batLhQa2FawWjeyjCqyBzypd?label=Hello%20World"
+        );
+        // Mixed case input is not lowercased
+        assert_eq!(
+            normalize_input("1andreas3batLhQa2FawWjeyjCqyBzypd"),
+            "1andreas3batLhQa2FawWjeyjCqyBzypd"
+        );
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_trim_input() -> Result<()> {
         let mock_rest_client = MockRestClient::new();
@@ -964,6 +1133,25 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[sdk_macros::async_test_all]
+    async fn test_bitcoin_address_signet() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+
+        let addr = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+        match parse_with_rest_client(rest_client.as_ref(), addr, None).await? {
+            InputType::BitcoinAddress { address } => {
+                // Signet addresses can't be told apart from testnet ones
+                assert_eq!(address.network, Network::Testnet);
+                assert!(address.is_valid_for_network(Network::Signet));
+                assert!(address.is_valid_for_network(Network::Testnet));
+                assert!(!address.is_valid_for_network(Network::Bitcoin));
+            }
+            _ => return Err(anyhow!("Invalid type parsed")),
+        }
+        Ok(())
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_bitcoin_address_bip21() -> Result<()> {
         let mock_rest_client = MockRestClient::new();
@@ -1235,6 +1423,23 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[sdk_macros::async_test_all]
+    async fn test_encrypted_payment_request() -> Result<()> {
+        let rest_client: Arc<dyn RestClient> = Arc::new(MockRestClient::new());
+
+        match parse_with_rest_client(rest_client.as_ref(), "breezpr:AQIDBA", None).await? {
+            InputType::EncryptedPaymentRequest { data } => assert_eq!(data.blob, "AQIDBA"),
+            _ => return Err(anyhow!("Unexpected type")),
+        }
+        assert!(
+            parse_with_rest_client(rest_client.as_ref(), "breezpr:AQ+D", None)
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_node_id() -> Result<()> {
         let mock_rest_client = MockRestClient::new();
@@ -1667,6 +1872,47 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[sdk_macros::async_test_all]
+    async fn test_lnurl_pay_deep_link_params() -> Result<(), Box<dyn std::error::Error>> {
+        let mock_rest_client = MockRestClient::new();
+        let lnurl_pay_encoded = "lnurl1dp68gurn8ghj7mr0vdskc6r0wd6z7mrww4excttsv9un7um9wdekjmmw84jxywf5x43rvv35xgmr2enrxanr2cfcvsmnwe3jxcukvde48qukgdec89snwde3vfjxvepjxpjnjvtpxd3kvdnxx5crxwpjvyunsephsz36jf";
+        mock_lnurl_pay_endpoint(&mock_rest_client, None);
+        mock_lnurl_pay_endpoint(&mock_rest_client, None);
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+
+        let deep_link = format!("lightning:{lnurl_pay_encoded}?amount=12&comment=thank%20you");
+        match parse_with_rest_client(rest_client.as_ref(), &deep_link, None).await? {
+            InputType::LnUrlPay {
+                amount_msat,
+                comment,
+                ..
+            } => {
+                assert_eq!(amount_msat, Some(12_000));
+                assert_eq!(comment, Some("thank you".to_string()));
+            }
+            input_type => panic!("Unexpected input type: {input_type:?}"),
+        }
+
+        // Without deep link params
+        assert!(matches!(
+            parse_with_rest_client(rest_client.as_ref(), lnurl_pay_encoded, None).await?,
+            InputType::LnUrlPay {
+                amount_msat: None,
+                comment: None,
+                ..
+            }
+        ));
+
+        // The amount must be a number of satoshis
+        let deep_link = format!("lightning:{lnurl_pay_encoded}?amount=1.5");
+        assert!(
+            parse_with_rest_client(rest_client.as_ref(), &deep_link, None)
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_lnurl_pay_lud_16_ln_address() -> Result<(), Box<dyn std::error::Error>> {
         let mock_rest_client = MockRestClient::new();
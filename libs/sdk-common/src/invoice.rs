@@ -1,16 +1,21 @@
 use std::num::ParseIntError;
 use std::str::FromStr;
-use std::time::{SystemTimeError, UNIX_EPOCH};
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use std::time::SystemTime;
+use std::time::{Duration, SystemTimeError, UNIX_EPOCH};
 
 use anyhow::anyhow;
+use bitcoin::bech32::{u5, ToBase32};
+use bitcoin::secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
 use bitcoin::secp256k1::{self, PublicKey};
 use hex::ToHex;
+use lightning::ln::features::Bolt11InvoiceFeatures;
 use lightning::routing::gossip::RoutingFees;
 use lightning::routing::*;
 use lightning_invoice::*;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "liquid")]
+#[cfg(feature = "bolt12")]
 use {
     bitcoin::hashes::hex::ToHex as BitcoinHashToHex, lightning_with_bolt12::ln::msgs::DecodeError,
     lightning_with_bolt12::offers::offer::Offer,
@@ -176,9 +181,17 @@ pub struct LNInvoice {
     pub amount_msat: Option<u64>,
     pub timestamp: u64,
     pub expiry: u64,
+    /// When the invoice expires, in seconds since the epoch: `timestamp + expiry`
+    #[serde(default)]
+    pub expires_at: u64,
+    /// Whether the invoice was expired when it was parsed
+    #[serde(default)]
+    pub is_expired: bool,
     pub routing_hints: Vec<RouteHint>,
     pub payment_secret: Vec<u8>,
     pub min_final_cltv_expiry_delta: u64,
+    #[serde(default)]
+    pub features: InvoiceFeatures,
 }
 
 impl LNInvoice {
@@ -187,6 +200,104 @@ impl LNInvoice {
             .iter()
             .any(|hint| hint.hops.iter().any(|hop| hop.src_node_id == pubkey))
     }
+
+    /// The route hint whose last hop is the channel from `pubkey`, for example the LSP of the
+    /// payee
+    pub fn hint_from_node(&self, pubkey: &str) -> Option<&RouteHint> {
+        self.routing_hints.iter().find(|hint| {
+            hint.hops
+                .last()
+                .map(|hop| hop.src_node_id == pubkey)
+                .unwrap_or(false)
+        })
+    }
+}
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Date, js_name = now)]
+    fn date_now() -> f64;
+}
+
+/// The current time, in seconds since the epoch
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// The current time, in seconds since the epoch. `SystemTime` is not available in the browser.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+fn now_secs() -> u64 {
+    (date_now() / 1000.0) as u64
+}
+
+/// How a feature is signalled by the feature bits of an invoice
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeatureSupport {
+    #[default]
+    NotSupported,
+    Optional,
+    Required,
+}
+
+impl FeatureSupport {
+    fn from_bits(flags: &[u8], required_bit: usize) -> Self {
+        let is_set = |bit: usize| {
+            flags
+                .get(bit / 8)
+                .map(|byte| byte & (1 << (bit % 8)) != 0)
+                .unwrap_or(false)
+        };
+        match (is_set(required_bit), is_set(required_bit + 1)) {
+            (true, _) => FeatureSupport::Required,
+            (false, true) => FeatureSupport::Optional,
+            (false, false) => FeatureSupport::NotSupported,
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        *self != FeatureSupport::NotSupported
+    }
+}
+
+/// The payment capabilities of an invoice, as signalled by its feature bits
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvoiceFeatures {
+    /// Whether the payee accepts the payment split in multiple parts
+    pub basic_mpp: FeatureSupport,
+    /// Whether the payee expects the payment secret in the onion
+    pub payment_secret: FeatureSupport,
+    /// Whether the payee can be paid through trampoline nodes
+    pub trampoline: FeatureSupport,
+    /// Whether the invoice requires features this SDK does not know about
+    pub requires_unknown_features: bool,
+}
+
+impl InvoiceFeatures {
+    /// Feature bits of `basic_mpp`, as per BOLT 9
+    const BASIC_MPP_BIT: usize = 16;
+    /// Feature bits of `payment_secret`, as per BOLT 9
+    const PAYMENT_SECRET_BIT: usize = 14;
+    /// Feature bits of `trampoline_routing`, as proposed for BOLT 9
+    const TRAMPOLINE_BIT: usize = 56;
+
+    fn from_ldk_features(features: Option<&Bolt11InvoiceFeatures>) -> Self {
+        let features = match features {
+            Some(features) => features,
+            None => return InvoiceFeatures::default(),
+        };
+        let flags = features.le_flags();
+        InvoiceFeatures {
+            basic_mpp: FeatureSupport::from_bits(flags, Self::BASIC_MPP_BIT),
+            payment_secret: FeatureSupport::from_bits(flags, Self::PAYMENT_SECRET_BIT),
+            trampoline: FeatureSupport::from_bits(flags, Self::TRAMPOLINE_BIT),
+            requires_unknown_features: features.requires_unknown_bits(),
+        }
+    }
 }
 
 /// Details of a specific hop in a larger route hint
@@ -256,6 +367,33 @@ impl RouteHint {
     }
 }
 
+/// The changes applied to an invoice by [amend_invoice]
+#[derive(Clone, Debug, Default)]
+pub struct InvoiceAmendment {
+    /// The new amount. If not set, the invoice amount is kept.
+    pub amount_msat: Option<u64>,
+    /// The new expiry, in seconds from the invoice timestamp. If not set, the invoice expiry is kept.
+    pub expiry: Option<u64>,
+    /// The route hints to set. If not set or empty, the invoice route hints are kept.
+    pub route_hints: Vec<RouteHint>,
+    /// If true, the `route_hints` are merged with the invoice route hints instead of replacing them.
+    pub merge_route_hints: bool,
+}
+
+/// Rebuilds an unexpired invoice with the [InvoiceAmendment] applied, preserving its payment hash
+/// and payment secret. The resulting invoice has to be signed by the payee.
+pub fn amend_invoice(
+    invoice: &str,
+    amendment: InvoiceAmendment,
+) -> InvoiceResult<RawBolt11Invoice> {
+    let signed = invoice.parse::<SignedRawBolt11Invoice>()?;
+    let invoice = Bolt11Invoice::from_signed(signed)?;
+    if invoice.is_expired() {
+        return Err(InvoiceError::validation("Cannot amend an expired invoice"));
+    }
+    build_amended_invoice(&invoice, amendment)
+}
+
 pub fn add_routing_hints(
     invoice: &str,
     merge_with_existing: bool,
@@ -264,7 +402,22 @@ pub fn add_routing_hints(
 ) -> InvoiceResult<RawBolt11Invoice> {
     let signed = invoice.parse::<SignedRawBolt11Invoice>()?;
     let invoice = Bolt11Invoice::from_signed(signed)?;
+    build_amended_invoice(
+        &invoice,
+        InvoiceAmendment {
+            amount_msat: new_amount_msats,
+            expiry: None,
+            route_hints: route_hints.clone(),
+            merge_route_hints: merge_with_existing,
+        },
+    )
+}
 
+fn build_amended_invoice(
+    invoice: &Bolt11Invoice,
+    amendment: InvoiceAmendment,
+) -> InvoiceResult<RawBolt11Invoice> {
+    let route_hints = &amendment.route_hints;
     let mut ldk_hints: Vec<router::RouteHint> = vec![];
     for h in route_hints {
         ldk_hints.push(h.to_ldk_hint()?);
@@ -274,19 +427,27 @@ pub fn add_routing_hints(
         .invoice_description(invoice.description())
         .payment_hash(*invoice.payment_hash())
         .timestamp(invoice.timestamp())
-        .expiry_time(invoice.expiry_time())
+        .expiry_time(
+            amendment
+                .expiry
+                .map(Duration::from_secs)
+                .unwrap_or(invoice.expiry_time()),
+        )
         .payment_secret(*invoice.payment_secret())
         .min_final_cltv_expiry_delta(invoice.min_final_cltv_expiry_delta())
         .basic_mpp();
-    if let Some(new_amount_msat) = new_amount_msats {
-        invoice_builder = invoice_builder.amount_milli_satoshis(new_amount_msat)
+    if let Some(amount_msat) = amendment.amount_msat.or(invoice.amount_milli_satoshis()) {
+        invoice_builder = invoice_builder.amount_milli_satoshis(amount_msat)
+    }
+    for fallback in invoice.fallbacks() {
+        invoice_builder = invoice_builder.fallback(fallback.clone());
     }
 
     // When merging route hints, only route hints are added that go through different nodes than ones in the invoice route hints.
     // Otherwise when not merging route hints, the invoice route hints are replaced by the provided route hints.
     let unique_hop_hints: Vec<router::RouteHint> = match route_hints.len() {
         0 => invoice.route_hints(),
-        _ => match merge_with_existing {
+        _ => match amendment.merge_route_hints {
             true => {
                 let invoice_hints_hop_src_node_ids: Vec<String> = invoice
                     .route_hints()
@@ -346,11 +507,12 @@ pub fn invoice_pubkey(invoice: &Bolt11Invoice) -> String {
 
 /// Parse a BOLT11 payment request and return a structure contains the parsed fields.
 pub fn parse_invoice(bolt11: &str) -> InvoiceResult<LNInvoice> {
-    if bolt11.trim().is_empty() {
+    let bolt11 = normalize_input(bolt11);
+    if bolt11.is_empty() {
         return Err(InvoiceError::validation("Bolt11 is an empty string"));
     }
     let re = Regex::new(r"(?i)^lightning:")?;
-    let bolt11 = re.replace_all(bolt11, "");
+    let bolt11 = re.replace_all(&bolt11, "");
     let signed = bolt11.parse::<SignedRawBolt11Invoice>()?;
     let invoice = Bolt11Invoice::from_signed(signed)?;
     let since_the_epoch = invoice.timestamp().duration_since(UNIX_EPOCH)?;
@@ -364,14 +526,19 @@ pub fn parse_invoice(bolt11: &str) -> InvoiceResult<LNInvoice> {
     // convert hints to bridge interface
     let invoice_hints = invoice.route_hints();
     let converted_hints = invoice_hints.iter().map(RouteHint::from_ldk_hint).collect();
+    let timestamp = since_the_epoch.as_secs();
+    let expiry = invoice.expiry_time().as_secs();
+    let expires_at = timestamp.saturating_add(expiry);
     // return the parsed invoice
     let ln_invoice = LNInvoice {
         bolt11: bolt11.to_string(),
         network: invoice.network().into(),
         payee_pubkey,
-        expiry: invoice.expiry_time().as_secs(),
+        expiry,
+        expires_at,
+        is_expired: expires_at < now_secs(),
         amount_msat: invoice.amount_milli_satoshis(),
-        timestamp: since_the_epoch.as_secs(),
+        timestamp,
         routing_hints: converted_hints,
         payment_hash: invoice.payment_hash().encode_hex::<String>(),
         payment_secret: invoice.payment_secret().0.to_vec(),
@@ -384,17 +551,124 @@ pub fn parse_invoice(bolt11: &str) -> InvoiceResult<LNInvoice> {
             Bolt11InvoiceDescription::Hash(h) => Some(h.0.to_string()),
         },
         min_final_cltv_expiry_delta: invoice.min_final_cltv_expiry_delta(),
+        features: InvoiceFeatures::from_ldk_features(invoice.features()),
     };
     Ok(ln_invoice)
 }
 
+/// A tagged field of a BOLT11 invoice, as encoded in the invoice
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RawInvoiceTaggedField {
+    /// The field type, for example 1 for the payment hash (`p`)
+    pub tag: u8,
+    /// The field data, as 5-bit words
+    pub data: Vec<u8>,
+}
+
+/// The parts of a signed BOLT11 invoice, see [decode_raw_invoice]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RawInvoiceParts {
+    /// The human readable part, with the network and amount, for example `lnbc110n`
+    pub hrp: String,
+    pub timestamp: u64,
+    /// The tagged fields, in the order they're encoded in, including the ones of unknown types
+    pub tagged_fields: Vec<RawInvoiceTaggedField>,
+    /// The hex encoded 64 byte compact signature followed by the recovery id
+    pub signature: String,
+}
+
+/// Splits a BOLT11 invoice into its raw parts, without validating its semantics or signature.
+/// The invoice can be encoded back with [encode_raw_invoice].
+pub fn decode_raw_invoice(bolt11: &str) -> InvoiceResult<RawInvoiceParts> {
+    let bolt11 = normalize_input(bolt11);
+    let re = Regex::new(r"(?i)^lightning:")?;
+    let signed = re
+        .replace_all(&bolt11, "")
+        .parse::<SignedRawBolt11Invoice>()?;
+    let raw_invoice = signed.raw_invoice();
+
+    // Each encoded field starts with its type and two words of data length
+    let tagged_fields = raw_invoice
+        .data
+        .tagged_fields
+        .iter()
+        .map(|field| {
+            let words = field.to_base32();
+            RawInvoiceTaggedField {
+                tag: words[0].to_u8(),
+                data: words[3..].iter().map(|word| word.to_u8()).collect(),
+            }
+        })
+        .collect();
+    let (recovery_id, compact) = signed.signature().0.serialize_compact();
+    let mut signature = compact.to_vec();
+    signature.push(recovery_id.to_i32() as u8);
+
+    Ok(RawInvoiceParts {
+        hrp: raw_invoice.hrp.to_string(),
+        timestamp: raw_invoice.data.timestamp.as_unix_timestamp(),
+        tagged_fields,
+        signature: signature.encode_hex(),
+    })
+}
+
+/// Encodes the parts of a BOLT11 invoice, see [decode_raw_invoice]. The signature is kept as is,
+/// so an invoice whose fields were changed has to be signed again by the payee to be valid.
+pub fn encode_raw_invoice(parts: &RawInvoiceParts) -> InvoiceResult<String> {
+    let to_words = |values: &[u8]| -> InvoiceResult<Vec<u5>> {
+        values
+            .iter()
+            .map(|value| {
+                u5::try_from_u8(*value)
+                    .map_err(|_| InvoiceError::validation("Invalid 5-bit word in tagged field"))
+            })
+            .collect()
+    };
+    let mut tagged_fields = vec![];
+    for field in &parts.tagged_fields {
+        let len = field.data.len();
+        if len >= 1024 {
+            return Err(InvoiceError::validation("Tagged field is too long"));
+        }
+        let mut words = to_words(&[field.tag, (len / 32) as u8, (len % 32) as u8])?;
+        words.extend(to_words(&field.data)?);
+        tagged_fields.push(RawTaggedField::UnknownSemantics(words));
+    }
+    let raw_invoice = RawBolt11Invoice {
+        hrp: parts.hrp.to_lowercase().parse::<RawHrp>()?,
+        data: RawDataPart {
+            timestamp: PositiveTimestamp::from_unix_timestamp(parts.timestamp)?,
+            tagged_fields,
+        },
+    };
+
+    let signature = hex::decode(&parts.signature)
+        .map_err(|_| InvoiceError::validation("Invalid signature encoding"))?;
+    if signature.len() != 65 {
+        return Err(InvoiceError::validation("Invalid signature length"));
+    }
+    let signature = RecoverableSignature::from_compact(
+        &signature[..64],
+        RecoveryId::from_i32(signature[64] as i32)?,
+    )?;
+    Ok(raw_invoice
+        .sign::<_, InvoiceError>(|_| Ok(signature))?
+        .to_string())
+}
+
+/// Serializes the invoice back to its lowercase bech32 encoding, without the `lightning:` prefix
+/// the invoice may have been parsed with.
+pub fn invoice_to_bech32(invoice: &LNInvoice) -> InvoiceResult<String> {
+    encode_raw_invoice(&decode_raw_invoice(&invoice.bolt11)?)
+}
+
 #[cfg(feature = "liquid")]
 // Covers BIP 21 URIs and simple onchain Liquid addresses (which are valid BIP 21 with the 'liquidnetwork:' prefix)
 pub fn parse_liquid_address(input: &str) -> Result<LiquidAddressData, DeserializeError> {
     LiquidAddressData::from_addr(input).or_else(|_| input.parse::<LiquidAddressData>())
 }
 
-#[cfg(feature = "liquid")]
+#[cfg(feature = "bolt12")]
 pub fn parse_bolt12_offer(input: &str) -> Result<LNOffer, Bolt12ParseError> {
     let offer = input.parse::<Offer>()?;
     // TODO This conversion (between lightning-v0.0.125 to -v0.0.118 Amount types)
@@ -445,6 +719,33 @@ pub fn parse_bolt12_offer(input: &str) -> Result<LNOffer, Bolt12ParseError> {
     })
 }
 
+/// Checks that the offer can be paid on `network`. Offers that don't list their chains are
+/// for Bitcoin, which [parse_bolt12_offer] already includes in [LNOffer::chains].
+#[cfg(feature = "bolt12")]
+pub fn validate_offer_network(offer: &LNOffer, network: Network) -> InvoiceResult<()> {
+    match offer.chains.contains(&offer_chain(network)) {
+        true => Ok(()),
+        false => Err(InvoiceError::invalid_network(
+            "Offer chains do not include the configured network",
+        )),
+    }
+}
+
+/// The chain hash identifying `network` in [LNOffer::chains]
+#[cfg(feature = "bolt12")]
+pub fn offer_chain(network: Network) -> String {
+    use lightning_with_bolt12::bitcoin::constants::ChainHash;
+    use lightning_with_bolt12::bitcoin::Network as OfferNetwork;
+
+    let network = match network {
+        Network::Bitcoin => OfferNetwork::Bitcoin,
+        Network::Testnet => OfferNetwork::Testnet,
+        Network::Signet => OfferNetwork::Signet,
+        Network::Regtest => OfferNetwork::Regtest,
+    };
+    ChainHash::using_genesis_block_const(network).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::invoice::*;
@@ -456,6 +757,8 @@ mod tests {
     fn test_parse_invoice() {
         let payreq = String::from("lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz");
         let res = parse_invoice(&payreq).unwrap();
+        assert_eq!(res.expires_at, res.timestamp + res.expiry);
+        assert!(res.is_expired);
 
         let private_key_vec =
             hex::decode("3e171115f50b2c355836dc026a6d54d525cf0d796eb50b3460a205d25c9d38fd")
@@ -505,6 +808,98 @@ mod tests {
         print!("{encoded:?}");
     }
 
+    #[sdk_macros::test_all]
+    fn test_amend_invoice() {
+        use bitcoin::hashes::{sha256, Hash};
+        use bitcoin::secp256k1::{Secp256k1, SecretKey};
+
+        let secp = Secp256k1::new();
+        let private_key = SecretKey::from_slice(&[42; 32]).unwrap();
+        let payreq = InvoiceBuilder::new(Currency::Bitcoin)
+            .description("test".into())
+            .payment_hash(sha256::Hash::hash(&[1; 32]))
+            .payment_secret(PaymentSecret([2; 32]))
+            .current_timestamp()
+            .min_final_cltv_expiry_delta(144)
+            .amount_milli_satoshis(1000)
+            .build_signed(|hash| secp.sign_ecdsa_recoverable(hash, &private_key))
+            .unwrap()
+            .to_string();
+        let original = parse_invoice(&payreq).unwrap();
+
+        let raw_invoice = amend_invoice(
+            &payreq,
+            InvoiceAmendment {
+                amount_msat: Some(2000),
+                expiry: Some(60),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let signed = raw_invoice
+            .sign::<_, ()>(|hash| Ok(secp.sign_ecdsa_recoverable(hash, &private_key)))
+            .unwrap();
+        let amended = parse_invoice(&signed.to_string()).unwrap();
+
+        assert_eq!(amended.payment_hash, original.payment_hash);
+        assert_eq!(amended.payment_secret, original.payment_secret);
+        assert_eq!(amended.description, original.description);
+        assert_eq!(amended.amount_msat, Some(2000));
+        assert_eq!(amended.expiry, 60);
+    }
+
+    #[sdk_macros::test_all]
+    fn test_raw_invoice_round_trip() {
+        let payreq = "lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz";
+        let parts = decode_raw_invoice(&format!("lightning:{}", payreq.to_uppercase())).unwrap();
+        assert_eq!(parts.hrp, "lnbc110n");
+        // The payment hash field has 52 words of data
+        let payment_hash = &parts.tagged_fields[0];
+        assert_eq!(payment_hash.tag, 1);
+        assert_eq!(payment_hash.data.len(), 52);
+        assert_eq!(parts.signature.len(), 130);
+        assert_eq!(encode_raw_invoice(&parts).unwrap(), payreq);
+
+        let invoice = parse_invoice(payreq).unwrap();
+        assert_eq!(invoice_to_bech32(&invoice).unwrap(), payreq);
+
+        // Changed fields are encoded, but invalidate the signature
+        let mut changed = parts.clone();
+        changed.tagged_fields.remove(1);
+        let changed = encode_raw_invoice(&changed).unwrap();
+        assert_ne!(changed, payreq);
+        assert!(decode_raw_invoice(&changed).is_ok());
+        assert!(parse_invoice(&changed).is_err());
+
+        let mut invalid = parts;
+        invalid.tagged_fields[0].data[0] = 32;
+        assert!(encode_raw_invoice(&invalid).is_err());
+    }
+
+    #[sdk_macros::test_all]
+    fn test_parse_invoice_normalized() {
+        let payreq = String::from("lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz");
+
+        for input in [
+            format!(" {payreq} "),
+            format!("lightning:{payreq}").to_uppercase(),
+            format!("lightning%3A{payreq}"),
+        ] {
+            let res = parse_invoice(&input).unwrap();
+            assert_eq!(res.bolt11, payreq);
+        }
+    }
+
+    #[sdk_macros::test_all]
+    fn test_parse_invoice_features() {
+        let payreq = String::from("lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz");
+        let res = parse_invoice(&payreq).unwrap();
+
+        assert!(res.features.basic_mpp.is_supported());
+        assert!(res.features.payment_secret.is_supported());
+        assert!(!res.features.requires_unknown_features);
+    }
+
     #[sdk_macros::test_all]
     fn test_parse_invoice_invalid_bitcoin_network() {
         let payreq = String::from("lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz");
@@ -523,6 +918,31 @@ mod tests {
         assert!(validate_network(res.unwrap(), Network::Bitcoin).is_err());
     }
 
+    #[sdk_macros::test_all]
+    fn test_parse_invoice_signet_network() {
+        use bitcoin::hashes::{sha256, Hash};
+        use bitcoin::secp256k1::{Secp256k1, SecretKey};
+
+        let secp = Secp256k1::new();
+        let private_key = SecretKey::from_slice(&[42; 32]).unwrap();
+        let payreq = InvoiceBuilder::new(Currency::Signet)
+            .description("test".into())
+            .payment_hash(sha256::Hash::hash(&[1; 32]))
+            .payment_secret(PaymentSecret([2; 32]))
+            .current_timestamp()
+            .min_final_cltv_expiry_delta(144)
+            .amount_milli_satoshis(1000)
+            .build_signed(|hash| secp.sign_ecdsa_recoverable(hash, &private_key))
+            .unwrap()
+            .to_string();
+        assert!(payreq.starts_with("lntbs"));
+
+        let res = parse_invoice(&payreq).unwrap();
+        assert_eq!(res.network, Network::Signet);
+        assert!(validate_network(res.clone(), Network::Signet).is_ok());
+        assert!(validate_network(res, Network::Testnet).is_err());
+    }
+
     #[sdk_macros::test_all]
     fn test_format_short_channel_id() {
         let valid_short_channel_ids = vec![
@@ -562,4 +982,32 @@ mod tests {
             assert!(res.is_err());
         }
     }
+
+    #[cfg(feature = "bolt12")]
+    #[sdk_macros::test_all]
+    fn test_validate_offer_network() {
+        let offer = LNOffer {
+            offer: "lno1".to_string(),
+            chains: vec![offer_chain(Network::Bitcoin)],
+            min_amount: None,
+            description: None,
+            absolute_expiry: None,
+            issuer: None,
+            signing_pubkey: None,
+            paths: vec![],
+        };
+        assert!(validate_offer_network(&offer, Network::Bitcoin).is_ok());
+        assert!(matches!(
+            validate_offer_network(&offer, Network::Testnet),
+            Err(InvoiceError::InvalidNetwork(_))
+        ));
+
+        let offer = LNOffer {
+            chains: vec![offer_chain(Network::Signet), offer_chain(Network::Regtest)],
+            ..offer
+        };
+        assert!(validate_offer_network(&offer, Network::Regtest).is_ok());
+        assert!(validate_offer_network(&offer, Network::Bitcoin).is_err());
+        assert_ne!(offer_chain(Network::Signet), offer_chain(Network::Regtest));
+    }
 }
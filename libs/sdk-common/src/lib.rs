@@ -1,3 +1,4 @@
+mod amount;
 mod breez_server;
 mod buy;
 mod dns_resolver;
@@ -22,18 +23,20 @@ pub mod utils;
 pub use bitcoin;
 pub use lightning;
 pub use lightning_invoice;
-#[cfg(feature = "liquid")]
+#[cfg(feature = "bolt12")]
 pub use lightning_with_bolt12;
 
 // We don't include grpc::* in the prelude exports, to force callers to use the grpc path prefix.
 #[rustfmt::skip]
 pub mod prelude {
     pub use crate::*;
+    pub use crate::amount::*;
     pub use crate::breez_server::*;
     pub use crate::error::*;
     pub use crate::fiat::*;
     pub use crate::buy::*;
     pub use crate::buy::moonpay::*;
+    pub use crate::buy::transak::*;
     pub use crate::input_parser::*;
     pub use crate::invoice::*;
     #[cfg(feature = "liquid")]
@@ -50,5 +53,6 @@ pub mod prelude {
     pub use crate::model::*;
     #[cfg(feature = "test-utils")]
     pub use crate::test_utils::mock_rest_client::*;
+    pub use crate::utils::proxy::*;
     pub use crate::utils::rest_client::*;
 }
@@ -31,12 +31,14 @@ impl LnUrlError {
     }
 }
 
+#[cfg(feature = "aes-success-action")]
 impl From<aes::cipher::InvalidLength> for LnUrlError {
     fn from(err: aes::cipher::InvalidLength) -> Self {
         Self::Generic(err.to_string())
     }
 }
 
+#[cfg(feature = "aes-success-action")]
 impl From<aes::cipher::block_padding::UnpadError> for LnUrlError {
     fn from(err: aes::cipher::block_padding::UnpadError) -> Self {
         Self::Generic(err.to_string())
@@ -2,6 +2,12 @@ pub mod error;
 pub mod model;
 pub mod specs;
 
+/// The LNURL specs, or LUDs, supported when parsing and handling LNURLs
+pub const SUPPORTED_LUDS: &[&str] = &[
+    "LUD-01", "LUD-03", "LUD-04", "LUD-05", "LUD-06", "LUD-09", "LUD-10", "LUD-12", "LUD-16",
+    "LUD-17",
+];
+
 #[cfg(test)]
 mod tests {
     use bitcoin::secp256k1::rand;
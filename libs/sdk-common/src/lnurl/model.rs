@@ -24,7 +24,7 @@ pub enum LnUrlCallbackStatus {
 }
 
 /// Wrapped in a [LnUrlError], this represents a LNURL-endpoint error.
-#[derive(Clone, Deserialize, Debug, Serialize)]
+#[derive(Clone, Deserialize, Debug, PartialEq, Serialize)]
 pub struct LnUrlErrorData {
     pub reason: String,
 }
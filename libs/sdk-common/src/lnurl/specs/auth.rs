@@ -169,6 +169,20 @@ pub mod model {
         #[error("Invalid uri: {err}")]
         InvalidUri { err: String },
 
+        /// This error is raised when the `k1` challenge was already signed before. Services
+        /// issue a fresh challenge for each login, so a reused one may be a replayed request.
+        #[error("K1 reused: {err}")]
+        K1Reused { err: String },
+
+        /// This error is raised when the callback URL doesn't belong to the domain shown to the
+        /// user, so the signature would be sent to another service.
+        #[error("Origin mismatch: {err}")]
+        OriginMismatch { err: String },
+
+        /// This error is raised when too many auth attempts were made to the domain recently.
+        #[error("Rate limited: {err}")]
+        RateLimited { err: String },
+
         /// This error is raised when a connection to an external service fails.
         #[error("Service connectivity: {err}")]
         ServiceConnectivity { err: String },
@@ -2,7 +2,9 @@ use std::str::FromStr;
 
 use crate::prelude::*;
 
+#[cfg(feature = "aes-success-action")]
 pub type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+#[cfg(feature = "aes-success-action")]
 pub type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
 
 /// Validates invoice and performs the second and last step of LNURL-pay, as per
@@ -115,12 +117,15 @@ pub fn validate_invoice(user_amount_msat: u64, bolt11: &str, network: Network) -
 }
 
 pub mod model {
+    #[cfg(feature = "aes-success-action")]
     use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+    #[cfg(feature = "aes-success-action")]
     use anyhow::Result;
     use serde::{Deserialize, Serialize};
     use thiserror::Error;
     use utils::default_true;
 
+    #[cfg(feature = "aes-success-action")]
     use crate::prelude::specs::pay::{Aes256CbcDec, Aes256CbcEnc};
     use crate::prelude::*;
 
@@ -143,6 +148,17 @@ pub mod model {
         /// Validates that, if there is a URL success action, the URL domain matches
         /// the LNURL callback domain. Defaults to `true`
         pub validate_success_action_url: Option<bool>,
+        #[cfg(not(feature = "liquid"))] // Only available for the Greenlight SDK
+        /// If set and the payment is not complete after this many seconds, the call returns a
+        /// pending result and the payment continues in the background
+        pub pending_timeout_sec: Option<u32>,
+        #[cfg(not(feature = "liquid"))] // Only available for the Greenlight SDK
+        /// A token chosen by the caller to cancel the call with, unique among the calls in
+        /// progress
+        pub cancel_token: Option<String>,
+        #[cfg(not(feature = "liquid"))] // Only available for the Greenlight SDK
+        /// If true, the payment is made even if the destination is in the denylist of the SDK
+        pub skip_denylist: Option<bool>,
     }
 
     pub enum ValidatedCallbackResponse {
@@ -150,7 +166,7 @@ pub mod model {
         EndpointError { data: LnUrlErrorData },
     }
 
-    #[derive(Clone, Serialize, Deserialize, Debug)]
+    #[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
     pub struct LnUrlPayErrorData {
         pub payment_hash: String,
         pub reason: String,
@@ -304,6 +320,7 @@ pub mod model {
         }
 
         /// Decrypts the ciphertext as a UTF-8 string, given the key (invoice preimage) parameter.
+        #[cfg(feature = "aes-success-action")]
         pub fn decrypt(&self, key: &[u8; 32]) -> Result<String> {
             let plaintext_bytes =
                 Aes256CbcDec::new_from_slices(key, &base64::decode(&self.iv)?)?
@@ -313,6 +330,7 @@ pub mod model {
         }
 
         /// Helper method that encrypts a given plaintext, with a given key and IV.
+        #[cfg(feature = "aes-success-action")]
         pub fn encrypt(key: &[u8; 32], iv: &[u8; 16], plaintext: String) -> Result<String> {
             let ciphertext_bytes = Aes256CbcEnc::new_from_slices(key, iv)?
                 .encrypt_padded_vec_mut::<Pkcs7>(plaintext.as_bytes());
@@ -321,6 +339,7 @@ pub mod model {
         }
     }
 
+    #[cfg(feature = "aes-success-action")]
     impl TryFrom<(AesSuccessActionData, &[u8; 32])> for AesSuccessActionDataDecrypted {
         type Error = anyhow::Error;
 
@@ -393,6 +412,12 @@ pub mod model {
         #[error("Invoice already paid")]
         AlreadyPaid,
 
+        /// This error is raised when the LNURL domain, the lightning address or the invoice
+        /// destination is in the denylist of the SDK. It can be paid anyway with
+        /// [LnUrlPayRequest::skip_denylist].
+        #[error("Denied destination: {err}")]
+        DeniedDestination { err: String },
+
         /// This error is raised when a general error occurs not specific to other error variants
         /// in this enum.
         #[error("Generic: {err}")]
@@ -443,6 +468,21 @@ pub mod model {
         /// This error is raised when the node does not have enough funds to make the payment.
         #[error("Insufficient balance: {err}")]
         InsufficientBalance { err: String },
+
+        /// This error is raised when the call is cancelled with its
+        /// [LnUrlPayRequest::cancel_token]. If the payment was already handed to the node, it
+        /// may still complete.
+        #[error("Payment cancelled")]
+        Cancelled,
+
+        /// This error is raised when the payment exceeds the limits of the spend policy of the
+        /// SDK, or its destination is not allowed by it.
+        #[error("Spend policy violation: {err}")]
+        SpendPolicyViolation { err: String },
+
+        /// This error is raised when the payment exceeds the HTLC exposure limits of the SDK.
+        #[error("HTLC exposure exceeded: {err}")]
+        HtlcExposureExceeded { err: String },
     }
 
     impl From<anyhow::Error> for LnUrlPayError {
@@ -487,6 +527,7 @@ pub mod model {
 
 #[cfg(test)]
 pub(crate) mod tests {
+    #[cfg(feature = "aes-success-action")]
     use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
     use anyhow::Result;
     use bitcoin::hashes::{sha256, Hash};
@@ -547,6 +588,7 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "aes-success-action")]
     #[sdk_macros::test_all]
     fn test_lnurl_pay_validate_success_action_encrypt_decrypt() -> Result<()> {
         // Simulate a preimage, which will be the AES key
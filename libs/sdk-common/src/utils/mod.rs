@@ -1,3 +1,4 @@
+pub(crate) mod proxy;
 pub(crate) mod rest_client;
 
 #[macro_export]
@@ -1,4 +1,6 @@
 use crate::error::{ServiceConnectivityError, ServiceConnectivityErrorKind};
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use crate::utils::proxy::ProxyConfig;
 use log::*;
 use maybe_sync::{MaybeSend, MaybeSync};
 use reqwest::Client;
@@ -36,6 +38,22 @@ impl ReqwestRestClient {
             .map_err(Into::<ServiceConnectivityError>::into)?;
         Ok(ReqwestRestClient { client })
     }
+
+    /// Creates a client whose requests are sent through the given SOCKS5 proxy
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn with_proxy(proxy: &ProxyConfig) -> Result<Self, ServiceConnectivityError> {
+        let proxy_url = proxy.url().map_err(|e| {
+            ServiceConnectivityError::new(
+                ServiceConnectivityErrorKind::Other,
+                format!("Invalid proxy address {}: {e}", proxy.address),
+            )
+        })?;
+        let client = Client::builder()
+            .proxy(reqwest::Proxy::all(proxy_url)?)
+            .build()
+            .map_err(Into::<ServiceConnectivityError>::into)?;
+        Ok(ReqwestRestClient { client })
+    }
 }
 
 #[sdk_macros::async_trait]
@@ -1,13 +1,17 @@
 use crate::{
+    bitcoin::util::bip32::ChildNumber,
     breez_services::BackupFailedData,
-    error::SdkResult,
+    error::{SdkError, SdkResult},
+    node_api::NodeAPI,
     persist::db::{HookEvent, SqliteStorage},
-    BreezEvent, Config,
+    supervisor::TaskSupervisor,
+    BackupTransportConfig, BreezEvent, Config,
 };
 
 use anyhow::{anyhow, Result};
 use ecies::symmetric::{sym_decrypt, sym_encrypt};
 use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec_with_limit};
+use sdk_common::prelude::ProxyConfig;
 use std::{
     fs::{self, File},
     io::{Read, Write},
@@ -25,9 +29,18 @@ use tokio::{
     },
 };
 
+mod object_store;
+mod s3;
+mod webdav;
+
+pub use s3::S3BackupTransport;
+pub use webdav::WebDavBackupTransport;
+
 #[derive(Clone)]
 pub(crate) struct BackupRequest {
     force: bool,
+    /// The snapshot to restore before syncing
+    restore_version: Option<u64>,
     on_complete: Option<mpsc::Sender<Result<()>>>,
 }
 
@@ -38,6 +51,7 @@ impl BackupRequest {
     pub(crate) fn new(force: bool) -> Self {
         Self {
             force,
+            restore_version: None,
             on_complete: None,
         }
     }
@@ -45,11 +59,55 @@ impl BackupRequest {
     pub(crate) fn with(on_complete: Sender<Result<()>>, force: bool) -> Self {
         Self {
             force,
+            restore_version: None,
+            on_complete: Some(on_complete),
+        }
+    }
+
+    pub(crate) fn restore(on_complete: Sender<Result<()>>, version: u64) -> Self {
+        Self {
+            force: true,
+            restore_version: Some(version),
             on_complete: Some(on_complete),
         }
     }
 }
 
+/// The number of snapshots of previous backups kept by the transports supporting them
+pub(crate) const MAX_BACKUP_SNAPSHOTS: usize = 10;
+
+/// The backup encryption keys are derived from the seed at m/139'/epoch, the epoch starting at 0
+/// and increasing with each [crate::BreezServices::rotate_backup_key]
+const BACKUP_KEY_PATH_INDEX: u32 = 139;
+/// Prefix of the backups encrypted with a rotated key, followed by the key epoch as a big endian
+/// u32, in plaintext so any wallet with the seed can derive the key. The backups of epoch 0 have
+/// no prefix, as they had before the key could be rotated.
+const BACKUP_EPOCH_MAGIC: &[u8; 4] = b"BKEP";
+
+/// Splits an encrypted backup into the epoch of its key and the ciphertext
+fn split_backup_epoch(data: &[u8]) -> (u32, &[u8]) {
+    match data.strip_prefix(BACKUP_EPOCH_MAGIC.as_slice()) {
+        Some(rest) if rest.len() >= 4 => {
+            let (epoch, ciphertext) = rest.split_at(4);
+            (u32::from_be_bytes(epoch.try_into().unwrap()), ciphertext)
+        }
+        _ => (0, data),
+    }
+}
+
+/// Prefixes the ciphertext with the epoch of its key, see [BACKUP_EPOCH_MAGIC]
+fn with_backup_epoch(epoch: u32, ciphertext: Vec<u8>) -> Vec<u8> {
+    match epoch {
+        0 => ciphertext,
+        _ => [
+            BACKUP_EPOCH_MAGIC.as_slice(),
+            &epoch.to_be_bytes(),
+            ciphertext.as_slice(),
+        ]
+        .concat(),
+    }
+}
+
 // BackupState is the sdk state that requiers syncing between multiple apps.
 /// It is just a blob of data marked with a specific version (generation).
 /// The generation signals for the local state if the remote state is newer,
@@ -62,10 +120,85 @@ pub struct BackupState {
 }
 
 /// BackupTransport is the interface for syncing the sdk state between multiple apps.
+///
+/// Transports can also keep snapshots of the previous backups, so a corrupted backup can be
+/// recovered from. The snapshot methods do nothing by default.
 #[tonic::async_trait]
 pub trait BackupTransport: Send + Sync {
     async fn pull(&self) -> SdkResult<Option<BackupState>>;
     async fn push(&self, version: Option<u64>, data: Vec<u8>) -> SdkResult<u64>;
+
+    /// Stores a copy of the pushed backup, keeping at most [MAX_BACKUP_SNAPSHOTS] of them
+    async fn push_snapshot(&self, _state: BackupState) -> SdkResult<()> {
+        Ok(())
+    }
+
+    /// Lists the generations of the stored snapshots
+    async fn list_snapshots(&self) -> SdkResult<Vec<u64>> {
+        Ok(vec![])
+    }
+
+    async fn pull_snapshot(&self, _generation: u64) -> SdkResult<Option<BackupState>> {
+        Ok(None)
+    }
+}
+
+/// Keeps the backup in memory, for the wallets of [crate::BreezServices::connect_ephemeral]
+/// which are not backed up remotely
+#[derive(Default)]
+pub(crate) struct MemoryBackupTransport {
+    state: std::sync::Mutex<Option<BackupState>>,
+}
+
+#[tonic::async_trait]
+impl BackupTransport for MemoryBackupTransport {
+    async fn pull(&self) -> SdkResult<Option<BackupState>> {
+        Ok(self.state.lock().unwrap().clone())
+    }
+
+    async fn push(&self, version: Option<u64>, data: Vec<u8>) -> SdkResult<u64> {
+        let mut state = self.state.lock().unwrap();
+        let remote_version = state.as_ref().map(|s| s.generation);
+        if remote_version.is_some() && remote_version != version {
+            return Err(SdkError::generic("Backup version mismatch"));
+        }
+        let generation = version.map_or(1, |v| v + 1);
+        *state = Some(BackupState { generation, data });
+        Ok(generation)
+    }
+}
+
+/// Creates the transport selected by [Config::backup_transport]
+pub(crate) fn transport_from_config(
+    config: &BackupTransportConfig,
+    proxy: Option<&ProxyConfig>,
+) -> SdkResult<Arc<dyn BackupTransport>> {
+    match config {
+        BackupTransportConfig::S3 {
+            endpoint,
+            region,
+            bucket,
+            access_key_id,
+            secret_access_key,
+        } => Ok(Arc::new(S3BackupTransport::new(
+            endpoint,
+            region,
+            bucket,
+            access_key_id,
+            secret_access_key,
+            proxy,
+        )?)),
+        BackupTransportConfig::WebDav {
+            url,
+            username,
+            password,
+        } => Ok(Arc::new(WebDavBackupTransport::new(
+            url,
+            username.clone(),
+            password.clone(),
+            proxy,
+        )?)),
+    }
 }
 
 pub(crate) struct BackupWatcher {
@@ -73,8 +206,7 @@ pub(crate) struct BackupWatcher {
     backup_request_sender: Mutex<Option<mpsc::Sender<BackupRequest>>>,
     inner: Arc<dyn BackupTransport>,
     persister: Arc<SqliteStorage>,
-    encryption_key: Vec<u8>,
-    legacy_encryption_key: Vec<u8>,
+    node_api: Arc<dyn NodeAPI>,
     events_notifier: broadcast::Sender<BreezEvent>,
 }
 
@@ -84,8 +216,7 @@ impl BackupWatcher {
         config: Config,
         inner: Arc<dyn BackupTransport>,
         persister: Arc<SqliteStorage>,
-        encryption_key: Vec<u8>,
-        legacy_encryption_key: Vec<u8>,
+        node_api: Arc<dyn NodeAPI>,
     ) -> Self {
         let (events_notifier, _) = broadcast::channel::<BreezEvent>(100);
 
@@ -94,8 +225,7 @@ impl BackupWatcher {
             backup_request_sender: Mutex::new(None),
             inner,
             persister,
-            encryption_key,
-            legacy_encryption_key,
+            node_api,
             events_notifier,
         }
     }
@@ -105,13 +235,17 @@ impl BackupWatcher {
         *backup_request_sender = Some(sender);
     }
 
-    pub(crate) async fn start(&self, mut quit_receiver: watch::Receiver<()>) -> Result<()> {
+    /// Starts the backup worker on its own thread, restarted by the `supervisor` if it panics
+    pub(crate) async fn start(
+        &self,
+        quit_receiver: watch::Receiver<()>,
+        supervisor: Arc<TaskSupervisor>,
+    ) -> Result<()> {
         let worker = BackupWorker::new(
             self.config.working_dir.clone(),
             self.inner.clone(),
             self.persister.clone(),
-            self.encryption_key.clone(),
-            self.legacy_encryption_key.clone(),
+            self.node_api.clone(),
             self.events_notifier.clone(),
         );
 
@@ -120,67 +254,77 @@ impl BackupWatcher {
             mpsc::channel::<BackupRequest>(100);
         self.set_request_sender(backup_request_sender.clone()).await;
 
+        // The receivers are kept across the restarts of the worker task
+        let receivers = Arc::new(Mutex::new((backup_request_receiver, hooks_subscription)));
         let rt = Builder::new_current_thread().enable_all().build()?;
         std::thread::spawn(move || {
-            rt.block_on(async move {
-                loop {
-                    tokio::select! {
-
-                     // We listen to manual backup requests from the user
-                     req = backup_request_receiver.recv() => {
-                      match req {
-                       Some(req) => {
-                        match worker.sync(req.force).await {
-                         Ok(_) => {
-                          if let Some(callback) = req.on_complete {
-                           _ = callback.send(Ok(())).await;
-                          }
-                         }
-                         Err(e) => {
-                          error!("Sync worker returned with error {e}");
-                          if let Some(callback) = req.on_complete {
-                           _ = callback.send(Err(e)).await;
-                          }
-                         }
-                        };
-                       }
-                       None => {
-                        return
-                       }
-                      }
-                     }
-
-                      // We spin the backup worker on every new entry to the sync_requests table.
-                      event = hooks_subscription.recv() => {
-                        match event {
-                            Ok(HookEvent::Insert{table}) => {
-                             if table == "sync_requests"{
-                              // we do want to wait a bit to allow for multiple sync requests to be inserted
-                              tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-                              if let Err(e) = worker.sync(false).await {
-                               error!("Sync worker returned with error {e}");
+            let task_quit_receiver = quit_receiver.clone();
+            rt.block_on(supervisor.run("backup_worker", quit_receiver, move || {
+                let worker = worker.clone();
+                let receivers = receivers.clone();
+                let mut quit_receiver = task_quit_receiver.clone();
+                async move {
+                    let mut receivers = receivers.lock().await;
+                    let (backup_request_receiver, hooks_subscription) = &mut *receivers;
+                    loop {
+                        tokio::select! {
+
+                         // We listen to manual backup requests from the user
+                         req = backup_request_receiver.recv() => {
+                          match req {
+                           Some(req) => {
+                            match worker.sync(req.force, req.restore_version).await {
+                             Ok(_) => {
+                              if let Some(callback) = req.on_complete {
+                               _ = callback.send(Ok(())).await;
                               }
                              }
-                            }
-                            // If we are lagging we want to trigger sync
-                            Err(RecvError::Lagged(_)) => {
-                             if let Err(e) = worker.sync(false).await {
+                             Err(e) => {
                               error!("Sync worker returned with error {e}");
+                              if let Some(callback) = req.on_complete {
+                               _ = callback.send(Err(e)).await;
+                              }
                              }
+                            };
+                           }
+                           None => {
+                            return
+                           }
+                          }
+                         }
+
+                          // We spin the backup worker on every new entry to the sync_requests table.
+                          event = hooks_subscription.recv() => {
+                            match event {
+                                Ok(HookEvent::Insert{table}) => {
+                                 if table == "sync_requests"{
+                                  // we do want to wait a bit to allow for multiple sync requests to be inserted
+                                  tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                                  if let Err(e) = worker.sync(false, None).await {
+                                   error!("Sync worker returned with error {e}");
+                                  }
+                                 }
+                                }
+                                // If we are lagging we want to trigger sync
+                                Err(RecvError::Lagged(_)) => {
+                                 if let Err(e) = worker.sync(false, None).await {
+                                  error!("Sync worker returned with error {e}");
+                                 }
+                                }
+                                // If the channel is closed we exit
+                                Err(_) => {
+                                 return
+                                }
                             }
-                            // If the channel is closed we exit
-                            Err(_) => {
-                             return
-                            }
+                          },
+                          // We also want to exit if we receive a quit signal
+                          _ = quit_receiver.changed() => {
+                            return
+                          }
                         }
-                      },
-                      // We also want to exit if we receive a quit signal
-                      _ = quit_receiver.changed() => {
-                        return
-                      }
                     }
                 }
-            });
+            }));
         });
 
         Ok(())
@@ -198,6 +342,13 @@ impl BackupWatcher {
             .await
             .map_err(|_| anyhow!("Failed to send backup request, the channel is likely closed"))
     }
+
+    /// The versions of the stored backup snapshots, newest first
+    pub(crate) async fn list_versions(&self) -> SdkResult<Vec<u64>> {
+        let mut versions = self.inner.list_snapshots().await?;
+        versions.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(versions)
+    }
 }
 
 /// BackupWorker is a worker that bidirectionally syncs the sdk state.
@@ -206,8 +357,7 @@ struct BackupWorker {
     working_dir_path: String,
     inner: Arc<dyn BackupTransport>,
     persister: Arc<SqliteStorage>,
-    encryption_key: Vec<u8>,
-    legacy_encryption_key: Vec<u8>,
+    node_api: Arc<dyn NodeAPI>,
     events_notifier: broadcast::Sender<BreezEvent>,
 }
 
@@ -216,16 +366,14 @@ impl BackupWorker {
         working_dir_path: String,
         inner: Arc<dyn BackupTransport>,
         persister: Arc<SqliteStorage>,
-        encryption_key: Vec<u8>,
-        legacy_encryption_key: Vec<u8>,
+        node_api: Arc<dyn NodeAPI>,
         events_notifier: broadcast::Sender<BreezEvent>,
     ) -> Self {
         Self {
             working_dir_path,
             inner,
             persister,
-            encryption_key,
-            legacy_encryption_key,
+            node_api,
             events_notifier,
         }
     }
@@ -237,17 +385,31 @@ impl BackupWorker {
         Ok(())
     }
 
-    async fn sync(&self, force: bool) -> Result<()> {
+    async fn sync(&self, force: bool, restore_version: Option<u64>) -> Result<()> {
         let last_sync_request_id = self.persister.get_last_sync_request()?.unwrap_or_default();
         // In case we don't  have any sync requests the worker can exit
         if !force && last_sync_request_id == 0 {
             return Ok(());
         }
         let sync_dir = self.sync_dir()?;
-        let notify_res = match self
-            .sync_internal(sync_dir.clone(), last_sync_request_id)
-            .await
-        {
+        let sync_res = match restore_version {
+            Some(version) => match self.restore(sync_dir.clone(), version).await {
+                Ok(remote_version) => {
+                    // Include the sync requests of the restored changes
+                    let last_sync_request_id =
+                        self.persister.get_last_sync_request()?.unwrap_or_default();
+                    self.sync_internal(sync_dir.clone(), last_sync_request_id, remote_version)
+                        .await
+                }
+                Err(e) => Err(e),
+            },
+            None => {
+                let last_version = self.persister.get_last_sync_version()?;
+                self.sync_internal(sync_dir.clone(), last_sync_request_id, last_version)
+                    .await
+            }
+        };
+        let notify_res = match &sync_res {
             Ok(_) => {
                 info!("backup sync completed successfully");
                 self.notify(BreezEvent::BackupSucceeded).await
@@ -265,6 +427,8 @@ impl BackupWorker {
         fs::remove_dir_all(Path::new(sync_dir.as_str()))?;
 
         match notify_res {
+            // A failed restore is also reported to the caller
+            Ok(_) if restore_version.is_some() => sync_res,
             Ok(r) => Ok(r),
             Err(e) => {
                 error!("failed to notify backup event {}", e);
@@ -278,10 +442,12 @@ impl BackupWorker {
     /// 1. Try to push the local state to the remote state using the current version (optimistic).
     /// 2. If the push fails, sync the remote changes into the local changes including the remote newer version.
     /// 3. Try to push the local state again with the new version.
-    async fn sync_internal(&self, sync_dir: String, mut last_sync_request_id: u64) -> Result<()> {
-        // get the last local sync version and the last sync request id
-        let last_version = self.persister.get_last_sync_version()?;
-
+    async fn sync_internal(
+        &self,
+        sync_dir: String,
+        mut last_sync_request_id: u64,
+        last_version: Option<u64>,
+    ) -> Result<()> {
         self.notify(BreezEvent::BackupStarted).await?;
 
         // Backup the local sdk state
@@ -327,6 +493,13 @@ impl BackupWorker {
                 self.persister
                     .set_last_backup_time(now.duration_since(UNIX_EPOCH).unwrap().as_secs())?;
                 info!("Sync succeeded");
+                let snapshot = BackupState {
+                    generation: new_version,
+                    data: new_data,
+                };
+                if let Err(e) = self.inner.push_snapshot(snapshot).await {
+                    warn!("Failed to push backup snapshot: {e}");
+                }
                 Ok(())
             }
             Err(e) => {
@@ -385,18 +558,78 @@ impl BackupWorker {
         }
     }
 
+    /// Merges the snapshot of the given version into the local state, returning the version of
+    /// the current remote backup, which the next push replaces even if it can't be decrypted.
+    async fn restore(&self, sync_dir: String, version: u64) -> Result<Option<u64>> {
+        let snapshot = self
+            .inner
+            .pull_snapshot(version)
+            .await?
+            .ok_or(anyhow!("Backup version {version} not found"))?;
+        let data = decompress_to_vec_with_limit(&self.decrypt(&snapshot.data).await?, 4000000)
+            .map_err(|e| anyhow!("Failed to decompress backup version {version}: {e}"))?;
+
+        let tmp_dir = tempdir_in(sync_dir)?;
+        let mut snapshot_file = File::create(tmp_dir.path().join("sync_storage.sql"))?;
+        snapshot_file.write_all(&data)?;
+        snapshot_file.flush()?;
+        let snapshot_storage = SqliteStorage::new(
+            tmp_dir
+                .path()
+                .to_str()
+                .ok_or(anyhow!("Invalid sync directory"))?
+                .to_string(),
+        );
+        self.persister
+            .import_remote_changes(&snapshot_storage, true)?;
+        info!("Restored backup version {version}");
+
+        Ok(self.inner.pull().await?.map(|state| state.generation))
+    }
+
+    /// The key the backups of `epoch` are encrypted with
+    async fn encryption_key(&self, epoch: u32) -> Result<Vec<u8>> {
+        let path = vec![
+            ChildNumber::from_hardened_idx(BACKUP_KEY_PATH_INDEX)?,
+            ChildNumber::from_normal_idx(epoch)?,
+        ];
+        Ok(self.node_api.derive_symmetric_key(path).await?.to_vec())
+    }
+
+    /// Decrypts with the key of the backup epoch. A newer epoch than the local one, rotated by
+    /// another wallet with the same seed, is adopted for the next backups.
+    async fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let (epoch, ciphertext) = split_backup_epoch(data);
+        let mut keys = vec![self.encryption_key(epoch).await?];
+        if epoch == 0 {
+            // The backups may still be encrypted with the key of the legacy derivation
+            let path = vec![
+                ChildNumber::from_hardened_idx(BACKUP_KEY_PATH_INDEX)?,
+                ChildNumber::from(0),
+            ];
+            let legacy_key = self.node_api.legacy_derive_symmetric_key(path).await?;
+            keys.push(legacy_key.to_vec());
+        }
+        let decrypted = keys
+            .iter()
+            .find_map(|key| sym_decrypt(key.as_slice(), ciphertext))
+            // A backup we can't decrypt was pushed by another wallet, for example one
+            // sharing the same store. Never overwrite it.
+            .ok_or(SdkError::generic(
+                "Backup conflict: the remote backup was not created by this wallet",
+            ))?;
+        if epoch > self.persister.get_backup_key_epoch()? {
+            info!("Adopting the backup key epoch {epoch}");
+            self.persister.set_backup_key_epoch(epoch)?;
+        }
+        Ok(decrypted)
+    }
+
     async fn pull(&self) -> Result<Option<BackupState>> {
         let state = self.inner.pull().await?;
         match state {
             Some(state) => {
-                let mut decrypted =
-                    sym_decrypt(self.encryption_key.as_slice(), state.data.as_slice());
-                if decrypted.is_none() {
-                    warn!("Failed to decrypt backup with new key, trying legacy key");
-                    decrypted =
-                        sym_decrypt(self.legacy_encryption_key.as_slice(), state.data.as_slice());
-                }
-                let decrypted_data = decrypted.ok_or(anyhow!("Failed to decrypt backup"))?;
+                let decrypted_data = self.decrypt(&state.data).await?;
                 match decompress_to_vec_with_limit(&decrypted_data, 4000000) {
                     Ok(decompressed) => Ok(Some(BackupState {
                         generation: state.generation,
@@ -418,9 +651,11 @@ impl BackupWorker {
             "Pushing compressed data with size = {}",
             compressed_data.len()
         );
-        let encrypted_data =
-            sym_encrypt(self.encryption_key.as_slice(), compressed_data.as_slice())
-                .ok_or(anyhow!("Failed to encrypt backup"))?;
+        let epoch = self.persister.get_backup_key_epoch()?;
+        let encryption_key = self.encryption_key(epoch).await?;
+        let encrypted_data = sym_encrypt(encryption_key.as_slice(), compressed_data.as_slice())
+            .ok_or(anyhow!("Failed to encrypt backup"))?;
+        let encrypted_data = with_backup_epoch(epoch, encrypted_data);
         let version = self.inner.push(version, encrypted_data.clone()).await?;
         Ok((version, encrypted_data))
     }
@@ -444,39 +679,50 @@ impl BackupWorker {
 
 #[cfg(test)]
 mod tests {
+    use crate::bitcoin::util::bip32::ChildNumber;
+    use crate::breez_services::tests::get_dummy_node_state;
     use crate::persist::swap::SwapStorage;
     use crate::test_utils::get_test_ofp_48h;
     use crate::ListSwapsRequest;
     use crate::{
         backup::BackupRequest,
         persist::db::SqliteStorage,
-        test_utils::{create_test_config, create_test_persister, MockBackupTransport},
+        supervisor::TaskSupervisor,
+        test_utils::{
+            create_test_config, create_test_persister, test_master_key, MockBackupTransport,
+            MockNodeAPI,
+        },
         BreezEvent, SwapInfo,
     };
+    use ecies::symmetric::sym_decrypt;
     use std::{sync::Arc, vec};
-    use tokio::sync::{broadcast::Receiver, watch};
+    use tokio::sync::{broadcast::Receiver, mpsc, watch};
     use tokio::{
         spawn,
         time::{Duration, Instant},
     };
 
-    use super::BackupWatcher;
+    use super::{split_backup_epoch, BackupState, BackupWatcher};
 
     async fn create_test_backup_watcher(
+    ) -> (watch::Sender<()>, BackupWatcher, Arc<MockBackupTransport>) {
+        create_test_backup_watcher_with(Arc::new(MockBackupTransport::new())).await
+    }
+
+    /// A watcher with a fresh local database, backing up to `transport`
+    async fn create_test_backup_watcher_with(
+        transport: Arc<MockBackupTransport>,
     ) -> (watch::Sender<()>, BackupWatcher, Arc<MockBackupTransport>) {
         let config = create_test_config();
         let persister = Arc::new(create_test_persister(config.clone()));
         persister.init().unwrap();
-        let transport = Arc::new(MockBackupTransport::new());
-        let watcher = BackupWatcher::new(
-            config,
-            transport.clone(),
-            persister,
-            vec![0; 32],
-            vec![0; 32],
-        );
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let watcher = BackupWatcher::new(config, transport.clone(), persister, node_api);
         let (quit_sender, receiver) = watch::channel(());
-        watcher.start(receiver).await.unwrap();
+        watcher
+            .start(receiver, Arc::new(TaskSupervisor::default()))
+            .await
+            .unwrap();
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
         (quit_sender, watcher, transport)
     }
@@ -762,6 +1008,147 @@ mod tests {
         quit_sender.closed().await;
     }
 
+    /// The backup key of `epoch` derived by [MockNodeAPI]
+    fn test_backup_key(epoch: u32) -> Vec<u8> {
+        let path = [
+            ChildNumber::from_hardened_idx(139).unwrap(),
+            ChildNumber::from(epoch),
+        ];
+        test_master_key()
+            .derive_symmetric_key(&path)
+            .unwrap()
+            .to_vec()
+    }
+
+    // Test restoring a snapshot over a corrupted backup, after rotating the key.
+    #[tokio::test]
+    async fn test_rotate_key_and_restore() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        let persister = watcher.persister.clone();
+
+        // Back up a swap
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(persister.clone());
+        wait_for_backup_success(subscription).await;
+
+        let state = transport.state.lock().unwrap().clone().unwrap();
+        assert_eq!(split_backup_epoch(&state.data), (0, state.data.as_slice()));
+
+        // Rotate the key and back up again
+        persister.set_backup_key_epoch(1).unwrap();
+        let subscription = watcher.subscribe_events();
+        watcher
+            .request_backup(BackupRequest::new(true))
+            .await
+            .unwrap();
+        wait_for_backup_success(subscription).await;
+        let state = transport.state.lock().unwrap().clone().unwrap();
+        let (epoch, ciphertext) = split_backup_epoch(&state.data);
+        assert_eq!(epoch, 1);
+        assert!(sym_decrypt(&test_backup_key(1), ciphertext).is_some());
+        assert!(sym_decrypt(&test_backup_key(0), ciphertext).is_none());
+        assert_eq!(watcher.list_versions().await.unwrap(), vec![2, 1]);
+
+        // Corrupt the latest backup and lose the local swap
+        transport.state.lock().unwrap().as_mut().unwrap().data = vec![1, 2, 3];
+        persister
+            .get_connection()
+            .unwrap()
+            .execute("delete from sync.swaps", [])
+            .unwrap();
+
+        // Restore the first snapshot, encrypted with the previous key
+        let (on_complete, mut on_complete_receiver) = mpsc::channel(1);
+        watcher
+            .request_backup(BackupRequest::restore(on_complete, 1))
+            .await
+            .unwrap();
+        on_complete_receiver.recv().await.unwrap().unwrap();
+        let swaps = persister.list_swaps(ListSwapsRequest::default()).unwrap();
+        assert_eq!(swaps.len(), 1);
+        let state = transport.state.lock().unwrap().clone().unwrap();
+        assert_eq!(state.generation, 3);
+        let (epoch, ciphertext) = split_backup_epoch(&state.data);
+        assert_eq!(epoch, 1);
+        assert!(sym_decrypt(&test_backup_key(1), ciphertext).is_some());
+
+        let (on_complete, mut on_complete_receiver) = mpsc::channel(1);
+        watcher
+            .request_backup(BackupRequest::restore(on_complete, 10))
+            .await
+            .unwrap();
+        assert!(on_complete_receiver.recv().await.unwrap().is_err());
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+    }
+
+    // Test that a wallet restored from the same seed decrypts a backup of a rotated key and
+    // keeps backing up with it.
+    #[tokio::test]
+    async fn test_restore_rotated_key_from_seed() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        let subscription = watcher.subscribe_events();
+        populate_sync_table(watcher.persister.clone());
+        wait_for_backup_success(subscription).await;
+        watcher.persister.set_backup_key_epoch(2).unwrap();
+        let subscription = watcher.subscribe_events();
+        watcher
+            .request_backup(BackupRequest::new(true))
+            .await
+            .unwrap();
+        wait_for_backup_success(subscription).await;
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+
+        let (quit_sender, restored, transport) = create_test_backup_watcher_with(transport).await;
+        let subscription = restored.subscribe_events();
+        restored
+            .request_backup(BackupRequest::new(true))
+            .await
+            .unwrap();
+        wait_for_backup_success(subscription).await;
+        let persister = restored.persister.clone();
+        assert_eq!(persister.get_backup_key_epoch().unwrap(), 2);
+        let swaps = persister.list_swaps(ListSwapsRequest::default()).unwrap();
+        assert_eq!(swaps.len(), 1);
+        let state = transport.state.lock().unwrap().clone().unwrap();
+        assert_eq!(split_backup_epoch(&state.data).0, 2);
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+    }
+
+    // Test that a remote backup of another wallet is reported as a conflict and kept.
+    #[tokio::test]
+    async fn test_foreign_backup_conflict() {
+        let (quit_sender, watcher, transport) = create_test_backup_watcher().await;
+        let foreign_state = BackupState {
+            generation: 5,
+            data: vec![1, 2, 3],
+        };
+        *transport.remote_version.lock().unwrap() = Some(5);
+        *transport.state.lock().unwrap() = Some(foreign_state.clone());
+
+        let mut subscription = watcher.subscribe_events();
+        watcher
+            .request_backup(BackupRequest::new(true))
+            .await
+            .unwrap();
+        assert_eq!(
+            subscription.recv().await.unwrap(),
+            BreezEvent::BackupStarted
+        );
+        match subscription.recv().await.unwrap() {
+            BreezEvent::BackupFailed { details } => {
+                assert!(details.error.contains("Backup conflict"))
+            }
+            event => panic!("Unexpected event {event:?}"),
+        }
+        assert_eq!(transport.pulled(), 1);
+        assert_eq!(*transport.state.lock().unwrap(), Some(foreign_state));
+        _ = quit_sender.send(());
+        quit_sender.closed().await;
+    }
+
     fn populate_sync_table(persister: Arc<SqliteStorage>) {
         let tested_swap_info = SwapInfo {
             bitcoin_address: String::from("1"),
@@ -789,7 +1176,7 @@ mod tests {
             channel_opening_fees: Some(get_test_ofp_48h(1, 1).into()),
             confirmed_at: Some(555),
         };
-        persister.insert_swap(&tested_swap_info).unwrap();
+        persister.insert_swap(&tested_swap_info, None).unwrap();
     }
 
     async fn wait_for_backup_success(mut subscription: Receiver<BreezEvent>) {
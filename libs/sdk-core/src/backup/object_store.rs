@@ -0,0 +1,268 @@
+use reqwest::{header::ETAG, Response, StatusCode};
+use sdk_common::prelude::ProxyConfig;
+
+use super::{BackupState, MAX_BACKUP_SNAPSHOTS};
+use crate::error::{SdkError, SdkResult};
+
+/// Size of the generation prefix of the stored object
+const GENERATION_PREFIX_LEN: usize = 8;
+
+/// Remote objects supporting conditional writes, holding the backup and its snapshots.
+///
+/// Plain object stores have no notion of the backup generation, so it is stored as a big-endian
+/// prefix of the object content, and the object ETag is used to detect concurrent writes.
+///
+/// The objects are named by appending a suffix to the backup location: the backup itself has
+/// no suffix, and the snapshots are stored in [MAX_BACKUP_SNAPSHOTS] slots, reused in turn.
+#[tonic::async_trait]
+pub(super) trait ObjectStore: Send + Sync {
+    /// Gets the object content and its ETag, or `None` if the object doesn't exist
+    async fn get(&self, suffix: &str) -> SdkResult<Option<(Vec<u8>, String)>>;
+
+    /// Writes the object if its ETag still matches `etag`, or if it doesn't exist yet when
+    /// `etag` is `None`. Returns false if the precondition failed.
+    async fn put(&self, suffix: &str, content: Vec<u8>, etag: Option<String>) -> SdkResult<bool>;
+}
+
+pub(super) async fn pull(store: &dyn ObjectStore) -> SdkResult<Option<BackupState>> {
+    match store.get("").await? {
+        Some((content, _)) => Ok(Some(decode(&content)?)),
+        None => Ok(None),
+    }
+}
+
+/// Writes the next generation of the backup, if the remote backup is still at `version`
+pub(super) async fn push(
+    store: &dyn ObjectStore,
+    version: Option<u64>,
+    data: Vec<u8>,
+) -> SdkResult<u64> {
+    let (generation, etag) = match (version, store.get("").await?) {
+        (None, None) => (1, None),
+        (Some(version), Some((content, etag))) if decode(&content)?.generation == version => {
+            (version + 1, Some(etag))
+        }
+        _ => return Err(version_mismatch(version)),
+    };
+
+    match store.put("", encode(generation, data), etag).await? {
+        true => Ok(generation),
+        // Someone else pushed between our read and write
+        false => Err(version_mismatch(version)),
+    }
+}
+
+/// The suffix of the snapshot slot of `generation`
+fn snapshot_suffix(generation: u64) -> String {
+    format!(".snapshot-{}", generation % MAX_BACKUP_SNAPSHOTS as u64)
+}
+
+async fn get_snapshot(store: &dyn ObjectStore, slot: u64) -> SdkResult<Option<BackupState>> {
+    match store.get(&snapshot_suffix(slot)).await? {
+        Some((content, _)) => Ok(Some(decode(&content)?)),
+        None => Ok(None),
+    }
+}
+
+pub(super) async fn push_snapshot(store: &dyn ObjectStore, state: BackupState) -> SdkResult<()> {
+    let suffix = snapshot_suffix(state.generation);
+    let etag = store.get(&suffix).await?.map(|(_, etag)| etag);
+    match store
+        .put(&suffix, encode(state.generation, state.data), etag)
+        .await?
+    {
+        true => Ok(()),
+        false => Err(SdkError::generic(
+            "The backup snapshot was written concurrently",
+        )),
+    }
+}
+
+pub(super) async fn list_snapshots(store: &dyn ObjectStore) -> SdkResult<Vec<u64>> {
+    let mut generations = vec![];
+    for slot in 0..MAX_BACKUP_SNAPSHOTS as u64 {
+        if let Some(snapshot) = get_snapshot(store, slot).await? {
+            generations.push(snapshot.generation);
+        }
+    }
+    Ok(generations)
+}
+
+pub(super) async fn pull_snapshot(
+    store: &dyn ObjectStore,
+    generation: u64,
+) -> SdkResult<Option<BackupState>> {
+    Ok(get_snapshot(store, generation)
+        .await?
+        // The slot may hold another generation
+        .filter(|snapshot| snapshot.generation == generation))
+}
+
+fn encode(generation: u64, data: Vec<u8>) -> Vec<u8> {
+    let mut content = generation.to_be_bytes().to_vec();
+    content.extend(data);
+    content
+}
+
+fn decode(content: &[u8]) -> SdkResult<BackupState> {
+    if content.len() < GENERATION_PREFIX_LEN {
+        return Err(SdkError::generic("Invalid remote backup"));
+    }
+    let (prefix, data) = content.split_at(GENERATION_PREFIX_LEN);
+    let mut generation = [0; GENERATION_PREFIX_LEN];
+    generation.copy_from_slice(prefix);
+    Ok(BackupState {
+        generation: u64::from_be_bytes(generation),
+        data: data.to_vec(),
+    })
+}
+
+fn version_mismatch(version: Option<u64>) -> SdkError {
+    SdkError::Generic {
+        err: format!("Backup version mismatch, the remote backup is not at version {version:?}"),
+    }
+}
+
+pub(super) fn http_client(proxy: Option<&ProxyConfig>) -> SdkResult<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(proxy) = proxy {
+        let url = proxy
+            .url()
+            .map_err(|e| SdkError::generic(&format!("Invalid proxy: {e}")))?;
+        builder = builder.proxy(
+            reqwest::Proxy::all(url)
+                .map_err(|e| SdkError::generic(&format!("Invalid proxy: {e}")))?,
+        );
+    }
+    builder
+        .build()
+        .map_err(|e| SdkError::generic(&format!("Failed to create the HTTP client: {e}")))
+}
+
+pub(super) fn request_failed(e: reqwest::Error) -> SdkError {
+    SdkError::service_connectivity(&format!("Backup store request failed: {e}"))
+}
+
+/// Reads the object from a GET response
+pub(super) async fn read_object(response: Response) -> SdkResult<Option<(Vec<u8>, String)>> {
+    match response.status() {
+        StatusCode::NOT_FOUND => Ok(None),
+        status if status.is_success() => {
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .ok_or(SdkError::generic("The backup store returned no ETag"))?
+                .to_string();
+            let content = response.bytes().await.map_err(request_failed)?;
+            Ok(Some((content.to_vec(), etag)))
+        }
+        status => Err(SdkError::Generic {
+            err: format!("Failed to get the remote backup: {status}"),
+        }),
+    }
+}
+
+/// Checks the result of a conditional PUT
+pub(super) fn write_succeeded(response: &Response) -> SdkResult<bool> {
+    match response.status() {
+        StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT => Ok(false),
+        status if status.is_success() => Ok(true),
+        status => Err(SdkError::Generic {
+            err: format!("Failed to write the remote backup: {status}"),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use anyhow::Result;
+
+    use super::{list_snapshots, pull, pull_snapshot, push, push_snapshot, ObjectStore};
+    use crate::backup::{BackupState, MAX_BACKUP_SNAPSHOTS};
+    use crate::error::SdkResult;
+
+    /// Objects by suffix, with their ETag
+    #[derive(Default)]
+    struct MemoryObjectStore {
+        objects: Mutex<HashMap<String, (Vec<u8>, u32)>>,
+    }
+
+    #[tonic::async_trait]
+    impl ObjectStore for MemoryObjectStore {
+        async fn get(&self, suffix: &str) -> SdkResult<Option<(Vec<u8>, String)>> {
+            Ok(self
+                .objects
+                .lock()
+                .unwrap()
+                .get(suffix)
+                .map(|(content, etag)| (content.clone(), etag.to_string())))
+        }
+
+        async fn put(
+            &self,
+            suffix: &str,
+            content: Vec<u8>,
+            etag: Option<String>,
+        ) -> SdkResult<bool> {
+            let mut objects = self.objects.lock().unwrap();
+            let current_etag = objects.get(suffix).map(|(_, etag)| *etag);
+            if current_etag.map(|etag| etag.to_string()) != etag {
+                return Ok(false);
+            }
+            objects.insert(
+                suffix.to_string(),
+                (content, current_etag.unwrap_or_default() + 1),
+            );
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_push_and_pull() -> Result<()> {
+        let store = MemoryObjectStore::default();
+        assert!(pull(&store).await?.is_none());
+
+        assert_eq!(push(&store, None, vec![1, 2]).await?, 1);
+        assert_eq!(push(&store, Some(1), vec![3]).await?, 2);
+        let state = pull(&store).await?.unwrap();
+        assert_eq!(state.generation, 2);
+        assert_eq!(state.data, vec![3]);
+
+        // Pushing over an unknown or outdated backup is rejected
+        assert!(push(&store, None, vec![4]).await.is_err());
+        assert!(push(&store, Some(1), vec![4]).await.is_err());
+        assert_eq!(pull(&store).await?.unwrap().data, vec![3]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_snapshots() -> Result<()> {
+        let store = MemoryObjectStore::default();
+        let snapshots = MAX_BACKUP_SNAPSHOTS as u64 + 2;
+        for generation in 1..=snapshots {
+            push_snapshot(
+                &store,
+                BackupState {
+                    generation,
+                    data: vec![generation as u8],
+                },
+            )
+            .await?;
+        }
+
+        // Only the last snapshots are kept
+        let mut generations = list_snapshots(&store).await?;
+        generations.sort();
+        assert_eq!(generations, (3..=snapshots).collect::<Vec<u64>>());
+        assert!(pull_snapshot(&store, 1).await?.is_none());
+        assert_eq!(
+            pull_snapshot(&store, snapshots).await?.unwrap().data,
+            vec![snapshots as u8]
+        );
+        Ok(())
+    }
+}
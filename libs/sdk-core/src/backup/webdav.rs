@@ -0,0 +1,94 @@
+use reqwest::{
+    header::{IF_MATCH, IF_NONE_MATCH},
+    Method, RequestBuilder, Url,
+};
+use sdk_common::prelude::ProxyConfig;
+
+use super::object_store::{self, http_client, read_object, request_failed, write_succeeded};
+use super::{BackupState, BackupTransport};
+use crate::error::{SdkError, SdkResult};
+
+/// Stores the backup in a file on a WebDAV server, and its snapshots next to it.
+///
+/// The server must support conditional writes (`If-Match` and `If-None-Match`), which is used
+/// to detect other apps pushing a backup at the same time.
+pub struct WebDavBackupTransport {
+    client: reqwest::Client,
+    url: Url,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl WebDavBackupTransport {
+    /// Creates a transport for the file at `url`, for example
+    /// `https://dav.example.com/breez/backup`. The parent collection must exist.
+    pub fn new(
+        url: &str,
+        username: Option<String>,
+        password: Option<String>,
+        proxy: Option<&ProxyConfig>,
+    ) -> SdkResult<Self> {
+        Ok(Self {
+            client: http_client(proxy)?,
+            url: Url::parse(url)
+                .map_err(|e| SdkError::generic(&format!("Invalid WebDAV URL: {e}")))?,
+            username,
+            password,
+        })
+    }
+
+    fn request(&self, method: Method, suffix: &str) -> SdkResult<RequestBuilder> {
+        let url = Url::parse(&format!("{}{suffix}", self.url))
+            .map_err(|e| SdkError::generic(&format!("Invalid WebDAV URL: {e}")))?;
+        let request = self.client.request(method, url);
+        Ok(match &self.username {
+            Some(username) => request.basic_auth(username, self.password.as_ref()),
+            None => request,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl object_store::ObjectStore for WebDavBackupTransport {
+    async fn get(&self, suffix: &str) -> SdkResult<Option<(Vec<u8>, String)>> {
+        let response = self
+            .request(Method::GET, suffix)?
+            .send()
+            .await
+            .map_err(request_failed)?;
+        read_object(response).await
+    }
+
+    async fn put(&self, suffix: &str, content: Vec<u8>, etag: Option<String>) -> SdkResult<bool> {
+        let request = self.request(Method::PUT, suffix)?;
+        let request = match etag {
+            Some(etag) => request.header(IF_MATCH, etag),
+            None => request.header(IF_NONE_MATCH, "*"),
+        };
+        let response = request.body(content).send().await.map_err(request_failed)?;
+        write_succeeded(&response)
+    }
+}
+
+#[tonic::async_trait]
+impl BackupTransport for WebDavBackupTransport {
+    async fn pull(&self) -> SdkResult<Option<BackupState>> {
+        object_store::pull(self).await
+    }
+
+    async fn push(&self, version: Option<u64>, data: Vec<u8>) -> SdkResult<u64> {
+        object_store::push(self, version, data).await
+    }
+
+    async fn push_snapshot(&self, state: BackupState) -> SdkResult<()> {
+        object_store::push_snapshot(self, state).await
+    }
+
+    async fn list_snapshots(&self) -> SdkResult<Vec<u64>> {
+        object_store::list_snapshots(self).await
+    }
+
+    async fn pull_snapshot(&self, generation: u64) -> SdkResult<Option<BackupState>> {
+        object_store::pull_snapshot(self, generation).await
+    }
+}
@@ -19,36 +19,57 @@ use log::{Level, LevelFilter, Metadata, Record};
 use once_cell::sync::{Lazy, OnceCell};
 use sdk_common::invoice;
 pub use sdk_common::prelude::{
-    parse, AesSuccessActionDataDecrypted, AesSuccessActionDataResult, BitcoinAddressData,
-    CurrencyInfo, FiatCurrency, InputType, LNInvoice, LnUrlAuthRequestData, LnUrlCallbackStatus,
-    LnUrlError, LnUrlErrorData, LnUrlPayErrorData, LnUrlPayRequest, LnUrlPayRequestData,
-    LnUrlWithdrawRequest, LnUrlWithdrawRequestData, LnUrlWithdrawResult, LnUrlWithdrawSuccessData,
-    LocaleOverrides, LocalizedName, MessageSuccessActionData, Network, Rate, RouteHint,
-    RouteHintHop, SuccessActionProcessed, Symbol, UrlSuccessActionData,
+    parse, AesSuccessActionDataDecrypted, AesSuccessActionDataResult, Amount, BitcoinAddressData,
+    BuyBitcoinQuote, CurrencyInfo, EncryptedPaymentRequestData, FeatureSupport, FiatCurrency,
+    InputType, InvoiceFeatures, LNInvoice, LNOffer, LnOfferBlindedPath, LnUrlAuthRequestData,
+    LnUrlCallbackStatus, LnUrlError, LnUrlErrorData, LnUrlPayErrorData, LnUrlPayRequest,
+    LnUrlPayRequestData, LnUrlWithdrawRequest, LnUrlWithdrawRequestData, LnUrlWithdrawResult,
+    LnUrlWithdrawSuccessData, LocaleOverrides, LocalizedName, MessageSuccessActionData, MilliSat,
+    Network, ProxyConfig, Rate, RouteHint, RouteHintHop, Sat, SuccessActionProcessed, Symbol,
+    UrlSuccessActionData,
 };
 use tokio::sync::Mutex;
 
-use crate::breez_services::{self, BreezEvent, BreezServices, EventListener};
+use crate::breez_services::{self, log_filter_enabled, BreezEvent, BreezServices, EventListener};
 use crate::chain::RecommendedFees;
 use crate::error::{
     ConnectError, ReceiveOnchainError, ReceivePaymentError, RedeemOnchainError, SdkError,
     SendOnchainError, SendPaymentError,
 };
 use crate::lsp::LspInformation;
-use crate::models::{Config, LogEntry, NodeState, Payment, SwapInfo};
+use crate::models::{
+    AddFiatRateAlertRequest, Config, ConnectionStatus, FiatRateAlert, HistoricalRate, LogEntry,
+    MetricsSnapshot, NodeState, Payment, SwapInfo, SwapRefundKey, TaskStatus,
+};
 use crate::{
-    BackupStatus, BuyBitcoinRequest, BuyBitcoinResponse, CheckMessageRequest, CheckMessageResponse,
-    ConfigureNodeRequest, ConnectRequest, EnvironmentType, ListPaymentsRequest, ListSwapsRequest,
-    LnUrlAuthError, NodeConfig, NodeCredentials, OnchainPaymentLimitsResponse,
-    OpenChannelFeeRequest, OpenChannelFeeResponse, PayOnchainRequest, PayOnchainResponse,
-    PrepareOnchainPaymentRequest, PrepareOnchainPaymentResponse, PrepareRedeemOnchainFundsRequest,
+    AmendInvoiceRequest, BackupStatus, BalanceBreakdown, Balances, BumpFeeRequest, BumpFeeResponse,
+    BuyBitcoinRequest, BuyBitcoinResponse, ChannelDetails, ChannelOpeningFee, CheckMessageRequest,
+    CheckMessageResponse, CloseChannelRequest, CloseChannelResponse, ConfigureNodeRequest,
+    ConnectPeerRequest, ConnectRequest, CreateLnurlWithdrawVoucherRequest,
+    CreateStaticLnurlPayRequest, DecryptRecoveryBundleRequest, DeriveEncryptionKeyRequest,
+    DeriveEncryptionKeyResponse, EnvironmentType, ExportPaymentsRequest,
+    ExportRecoveryBundleRequest, ExportRecoveryBundleResponse, ExportSwapRefundKeysRequest,
+    FetchBuyBitcoinQuoteRequest, ImportPaymentsRequest, ImportPaymentsResponse, InvoicePayability,
+    InvoiceVerificationResult, ListPaymentGroupsRequest, ListPaymentsRequest, ListSwapsRequest,
+    LnUrlAuthDomain, LnUrlAuthError, LnUrlAuthExport, LnurlPayInfo, LnurlWithdrawVoucher,
+    NodeConfig, NodeCredentials, NodeMigrationRequest, NodeMigrationState,
+    OnchainPaymentLimitsResponse, OpenChannelFeeRequest, OpenChannelFeeResponse,
+    OpenChannelRequest, OpenChannelResponse, OutboxPayment, PayOfferRequest,
+    PayOnchainAddressRequest, PayOnchainAddressResponse, PayOnchainRequest, PayOnchainResponse,
+    PaymentGroup, PaymentProbe, PaymentRequestBundle, PeerConnectivity,
+    PrepareOnchainPaymentRequest, PrepareOnchainPaymentResponse, PrepareReceivePaymentRequest,
+    PrepareReceivePaymentResponse, PrepareRedeemOnchainFundsRequest,
     PrepareRedeemOnchainFundsResponse, PrepareRefundRequest, PrepareRefundResponse,
-    ReceiveOnchainRequest, ReceivePaymentRequest, ReceivePaymentResponse,
+    PrepareSendPaymentRequest, PrepareSendPaymentResponse, ProbePaymentRequest,
+    ProveAddressOwnershipRequest, ProveAddressOwnershipResponse, QueuePaymentRequest,
+    ReceiveOnchainRequest, ReceivePaymentRequest, ReceivePaymentResponse, ReceiveUnifiedRequest,
+    ReceiveUnifiedResponse, RecoveryBundle, RedeemLnurlWithdrawVoucherRequest,
     RedeemOnchainFundsRequest, RedeemOnchainFundsResponse, RefundRequest, RefundResponse,
-    ReportIssueRequest, ReverseSwapFeesRequest, ReverseSwapInfo, ReverseSwapPairInfo,
-    SendPaymentRequest, SendPaymentResponse, SendSpontaneousPaymentRequest,
-    ServiceHealthCheckResponse, SignMessageRequest, SignMessageResponse, StaticBackupRequest,
-    StaticBackupResponse,
+    ReportIssueRequest, ReverseSwapFeesRequest, ReverseSwapInfo, ReverseSwapPairInfo, SdkInfo,
+    SendPaymentRequest, SendPaymentResponse, SendPaymentsRequest, SendPaymentsResponse,
+    SendSpontaneousPaymentRequest, ServiceHealthCheckResponse, SignMessageRequest,
+    SignMessageResponse, SnapshotChange, StaticBackupRequest, StaticBackupResponse, StaticLnurlPay,
+    StaticLnurlPayCallbackRequest, UserSettings, ValidatePayabilityRequest,
 };
 
 // === FRB mirroring
@@ -87,6 +108,19 @@ pub enum _Network {
     Regtest,
 }
 
+#[frb(mirror(MilliSat))]
+pub struct _MilliSat(pub u64);
+
+#[frb(mirror(Sat))]
+pub struct _Sat(pub u64);
+
+#[frb(mirror(ProxyConfig))]
+pub struct _ProxyConfig {
+    pub address: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
 #[frb(mirror(LNInvoice))]
 pub struct _LNInvoice {
     pub bolt11: String,
@@ -98,9 +132,27 @@ pub struct _LNInvoice {
     pub amount_msat: Option<u64>,
     pub timestamp: u64,
     pub expiry: u64,
+    pub expires_at: u64,
+    pub is_expired: bool,
     pub routing_hints: Vec<RouteHint>,
     pub payment_secret: Vec<u8>,
     pub min_final_cltv_expiry_delta: u64,
+    pub features: InvoiceFeatures,
+}
+
+#[frb(mirror(InvoiceFeatures))]
+pub struct _InvoiceFeatures {
+    pub basic_mpp: FeatureSupport,
+    pub payment_secret: FeatureSupport,
+    pub trampoline: FeatureSupport,
+    pub requires_unknown_features: bool,
+}
+
+#[frb(mirror(FeatureSupport))]
+pub enum _FeatureSupport {
+    NotSupported,
+    Optional,
+    Required,
 }
 
 #[frb(mirror(RouteHint))]
@@ -127,6 +179,9 @@ pub struct _LnUrlPayRequest {
     pub comment: Option<String>,
     pub payment_label: Option<String>,
     pub validate_success_action_url: Option<bool>,
+    pub pending_timeout_sec: Option<u32>,
+    pub cancel_token: Option<String>,
+    pub skip_denylist: Option<bool>,
 }
 
 #[frb(mirror(LnUrlPayRequestData))]
@@ -166,6 +221,10 @@ pub enum _InputType {
     Bolt11 {
         invoice: LNInvoice,
     },
+    Bolt12Offer {
+        offer: LNOffer,
+        bip353_address: Option<String>,
+    },
     NodeId {
         node_id: String,
     },
@@ -175,6 +234,8 @@ pub enum _InputType {
     LnUrlPay {
         data: LnUrlPayRequestData,
         bip353_address: Option<String>,
+        amount_msat: Option<u64>,
+        comment: Option<String>,
     },
     LnUrlWithdraw {
         data: LnUrlWithdrawRequestData,
@@ -185,6 +246,42 @@ pub enum _InputType {
     LnUrlError {
         data: LnUrlErrorData,
     },
+    EncryptedPaymentRequest {
+        data: EncryptedPaymentRequestData,
+    },
+}
+
+#[frb(mirror(EncryptedPaymentRequestData))]
+pub struct _EncryptedPaymentRequestData {
+    pub blob: String,
+}
+
+#[frb(mirror(LNOffer))]
+pub struct _LNOffer {
+    pub offer: String,
+    pub chains: Vec<String>,
+    pub min_amount: Option<Amount>,
+    pub description: Option<String>,
+    pub absolute_expiry: Option<u64>,
+    pub issuer: Option<String>,
+    pub signing_pubkey: Option<String>,
+    pub paths: Vec<LnOfferBlindedPath>,
+}
+
+#[frb(mirror(Amount))]
+pub enum _Amount {
+    Bitcoin {
+        amount_msat: u64,
+    },
+    Currency {
+        iso4217_code: String,
+        fractional_amount: u64,
+    },
+}
+
+#[frb(mirror(LnOfferBlindedPath))]
+pub struct _LnOfferBlindedPath {
+    pub blinded_hops: Vec<String>,
 }
 
 #[frb(mirror(BitcoinAddressData))]
@@ -235,6 +332,7 @@ pub struct _LnUrlPayErrorData {
 #[frb(mirror(LnUrlPayError))]
 pub enum _LnUrlPayError {
     AlreadyPaid,
+    DeniedDestination { err: String },
     Generic { err: String },
     InvalidAmount { err: String },
     InvalidInvoice { err: String },
@@ -246,6 +344,8 @@ pub enum _LnUrlPayError {
     RouteNotFound { err: String },
     RouteTooExpensive { err: String },
     ServiceConnectivity { err: String },
+    SpendPolicyViolation { err: String },
+    HtlcExposureExceeded { err: String },
 }
 
 #[frb(mirror(LnUrlWithdrawResult))]
@@ -304,6 +404,14 @@ pub struct _Symbol {
     pub position: Option<u32>,
 }
 
+#[frb(mirror(BuyBitcoinQuote))]
+pub struct _BuyBitcoinQuote {
+    pub fiat_currency: String,
+    pub fiat_amount: f64,
+    pub fees_fiat: f64,
+    pub amount_sat: u64,
+}
+
 /*
 The format Lazy<Mutex<Option<...>>> for the following variables allows them to be instance-global,
 meaning they can be set only once per instance, but calling disconnect() will unset them.
@@ -336,6 +444,27 @@ pub fn connect(req: ConnectRequest) -> Result<()> {
     .map_err(anyhow::Error::new::<ConnectError>)
 }
 
+/// See [BreezServices::connect_ephemeral]
+pub fn connect_ephemeral(config: Config) -> Result<()> {
+    block_on(async move {
+        let mut locked = BREEZ_SERVICES_INSTANCE.lock().await;
+        match *locked {
+            None => {
+                let breez_services =
+                    BreezServices::connect_ephemeral(config, Box::new(BindingEventListener {}))
+                        .await?;
+
+                *locked = Some(breez_services);
+                Ok(())
+            }
+            Some(_) => Err(ConnectError::Generic {
+                err: "Static node services already set, please call disconnect() first".into(),
+            }),
+        }
+    })
+    .map_err(anyhow::Error::new::<ConnectError>)
+}
+
 /// Check whether node service is initialized or not
 pub fn is_initialized() -> bool {
     block_on(async { get_breez_services().await.is_ok() })
@@ -347,6 +476,24 @@ pub fn sync() -> Result<()> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::cancel_sync]
+pub fn cancel_sync() -> Result<bool> {
+    block_on(async { get_breez_services().await?.cancel_sync().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::metrics]
+pub fn metrics() -> Result<MetricsSnapshot> {
+    block_on(async { get_breez_services().await?.metrics().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::sync_partial]
+pub fn sync_partial() -> Result<()> {
+    block_on(async { get_breez_services().await?.sync_partial().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::node_credentials]
 pub fn node_credentials() -> Result<Option<NodeCredentials>> {
     block_on(async { get_breez_services().await?.node_credentials().await })
@@ -363,6 +510,18 @@ pub fn node_info() -> Result<NodeState> {
     })
 }
 
+/// See [BreezServices::balances]
+pub fn balances() -> Result<Balances> {
+    block_on(async { get_breez_services().await?.balances() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::get_balance_breakdown]
+pub fn get_balance_breakdown() -> Result<BalanceBreakdown> {
+    block_on(async { get_breez_services().await?.get_balance_breakdown().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::configure_node]
 pub fn configure_node(req: ConfigureNodeRequest) -> Result<()> {
     block_on(async { get_breez_services().await?.configure_node(req).await })
@@ -382,6 +541,49 @@ pub fn disconnect() -> Result<()> {
     .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::derive_encryption_key]
+pub fn derive_encryption_key(
+    req: DeriveEncryptionKeyRequest,
+) -> Result<DeriveEncryptionKeyResponse> {
+    block_on(async { get_breez_services().await?.derive_encryption_key(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::prove_address_ownership]
+pub fn prove_address_ownership(
+    req: ProveAddressOwnershipRequest,
+) -> Result<ProveAddressOwnershipResponse> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .prove_address_ownership(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::encrypt_payment_request]
+pub fn encrypt_payment_request(bundle: PaymentRequestBundle) -> Result<String> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .encrypt_payment_request(bundle)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::decrypt_payment_request]
+pub fn decrypt_payment_request(data: EncryptedPaymentRequestData) -> Result<PaymentRequestBundle> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .decrypt_payment_request(data)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::sign_message]
 pub fn sign_message(req: SignMessageRequest) -> Result<SignMessageResponse> {
     block_on(async { get_breez_services().await?.sign_message(req).await })
@@ -410,6 +612,35 @@ pub fn default_config(
     BreezServices::default_config(env_type, api_key, node_config)
 }
 
+/// See [BreezServices::sdk_info]
+pub fn sdk_info() -> SdkInfo {
+    BreezServices::sdk_info()
+}
+
+/// See [BreezServices::decrypt_recovery_bundle]
+pub fn decrypt_recovery_bundle(req: DecryptRecoveryBundleRequest) -> Result<RecoveryBundle> {
+    BreezServices::decrypt_recovery_bundle(req).map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::export_recovery_bundle]
+pub fn export_recovery_bundle(
+    req: ExportRecoveryBundleRequest,
+) -> Result<ExportRecoveryBundleResponse> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .export_recovery_bundle(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::stop_recovery_bundle_refresh]
+pub fn stop_recovery_bundle_refresh() -> Result<()> {
+    block_on(async { get_breez_services().await?.stop_recovery_bundle_refresh() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::static_backup]
 pub fn static_backup(req: StaticBackupRequest) -> Result<StaticBackupResponse> {
     BreezServices::static_backup(req).map_err(anyhow::Error::new::<SdkError>)
@@ -440,6 +671,11 @@ pub fn breez_log_stream(s: StreamSink<LogEntry>) -> Result<()> {
     Ok(())
 }
 
+/// See [BreezServices::set_log_filter]
+pub fn set_log_filter(filter: String) -> Result<()> {
+    BreezServices::set_log_filter(&filter).map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  LSP API's */
 
 /// See [BreezServices::list_lsps]
@@ -460,6 +696,18 @@ pub fn lsp_id() -> Result<Option<String>> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::user_settings]
+pub fn user_settings() -> Result<UserSettings> {
+    block_on(async { get_breez_services().await?.user_settings() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::set_user_settings]
+pub fn set_user_settings(settings: UserSettings) -> Result<()> {
+    block_on(async { get_breez_services().await?.set_user_settings(settings) })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::fetch_lsp_info]
 pub fn fetch_lsp_info(id: String) -> Result<Option<LspInformation>> {
     block_on(async { get_breez_services().await?.fetch_lsp_info(id).await })
@@ -480,6 +728,36 @@ pub fn close_lsp_channels() -> Result<()> {
     })
 }
 
+/// See [BreezServices::list_channels]
+pub fn list_channels() -> Result<Vec<ChannelDetails>> {
+    block_on(async { get_breez_services().await?.list_channels().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::close_channel]
+pub fn close_channel(req: CloseChannelRequest) -> Result<CloseChannelResponse> {
+    block_on(async { get_breez_services().await?.close_channel(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::connect_peer]
+pub fn connect_peer(req: ConnectPeerRequest) -> Result<()> {
+    block_on(async { get_breez_services().await?.connect_peer(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::open_channel]
+pub fn open_channel(req: OpenChannelRequest) -> Result<OpenChannelResponse> {
+    block_on(async { get_breez_services().await?.open_channel(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::export_node_migration]
+pub fn export_node_migration(req: NodeMigrationRequest) -> Result<NodeMigrationState> {
+    block_on(async { get_breez_services().await?.export_node_migration(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 pub fn register_webhook(webhook_url: String) -> Result<()> {
     block_on(async {
         get_breez_services()
@@ -500,6 +778,77 @@ pub fn unregister_webhook(webhook_url: String) -> Result<()> {
     .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::register_lightning_address]
+pub fn register_lightning_address(username: String) -> Result<LnurlPayInfo> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .register_lightning_address(username)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::lnurl_pay_info]
+pub fn lnurl_pay_info() -> Result<Option<LnurlPayInfo>> {
+    block_on(async { get_breez_services().await?.lnurl_pay_info() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::create_static_lnurl_pay]
+pub fn create_static_lnurl_pay(req: CreateStaticLnurlPayRequest) -> Result<StaticLnurlPay> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .create_static_lnurl_pay(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::static_lnurl_pay_callback]
+pub fn static_lnurl_pay_callback(req: StaticLnurlPayCallbackRequest) -> Result<String> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .static_lnurl_pay_callback(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::create_lnurl_withdraw_voucher]
+pub fn create_lnurl_withdraw_voucher(
+    req: CreateLnurlWithdrawVoucherRequest,
+) -> Result<LnurlWithdrawVoucher> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .create_lnurl_withdraw_voucher(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::redeem_lnurl_withdraw_voucher]
+pub fn redeem_lnurl_withdraw_voucher(
+    req: RedeemLnurlWithdrawVoucherRequest,
+) -> Result<SendPaymentResponse> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .redeem_lnurl_withdraw_voucher(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SendPaymentError>)
+}
+
+/// See [BreezServices::list_lnurl_withdraw_vouchers]
+pub fn list_lnurl_withdraw_vouchers() -> Result<Vec<LnurlWithdrawVoucher>> {
+    block_on(async { get_breez_services().await?.list_lnurl_withdraw_vouchers() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Backup API's */
 
 /// See [BreezServices::backup]
@@ -514,6 +863,24 @@ pub fn backup_status() -> Result<BackupStatus> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::rotate_backup_key]
+pub fn rotate_backup_key() -> Result<()> {
+    block_on(async { get_breez_services().await?.rotate_backup_key().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::list_backup_versions]
+pub fn list_backup_versions() -> Result<Vec<u64>> {
+    block_on(async { get_breez_services().await?.list_backup_versions().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::restore_backup]
+pub fn restore_backup(version: u64) -> Result<()> {
+    block_on(async { get_breez_services().await?.restore_backup(version).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Parse API's */
 
 pub fn parse_invoice(invoice: String) -> Result<LNInvoice> {
@@ -532,12 +899,36 @@ pub fn list_payments(req: ListPaymentsRequest) -> Result<Vec<Payment>> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::list_payment_groups]
+pub fn list_payment_groups(req: ListPaymentGroupsRequest) -> Result<Vec<PaymentGroup>> {
+    block_on(async { get_breez_services().await?.list_payment_groups(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::export_payments]
+pub fn export_payments(req: ExportPaymentsRequest) -> Result<String> {
+    block_on(async { get_breez_services().await?.export_payments(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::import_payments]
+pub fn import_payments(req: ImportPaymentsRequest) -> Result<ImportPaymentsResponse> {
+    block_on(async { get_breez_services().await?.import_payments(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::list_payments]
 pub fn payment_by_hash(hash: String) -> Result<Option<Payment>> {
     block_on(async { get_breez_services().await?.payment_by_hash(hash).await })
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::list_channel_fees_paid]
+pub fn list_channel_fees_paid() -> Result<Vec<ChannelOpeningFee>> {
+    block_on(async { get_breez_services().await?.list_channel_fees_paid().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::set_payment_metadata]
 pub fn set_payment_metadata(hash: String, metadata: String) -> Result<()> {
     block_on(async {
@@ -557,6 +948,42 @@ pub fn send_payment(req: SendPaymentRequest) -> Result<SendPaymentResponse> {
         .map_err(anyhow::Error::new::<SendPaymentError>)
 }
 
+/// See [BreezServices::send_payments]
+pub fn send_payments(req: SendPaymentsRequest) -> Result<SendPaymentsResponse> {
+    block_on(async { get_breez_services().await?.send_payments(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::verify_invoices]
+pub fn verify_invoices(invoices: Vec<String>) -> Result<Vec<InvoiceVerificationResult>> {
+    block_on(async { Ok(get_breez_services().await?.verify_invoices(invoices).await) })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::queue_payment]
+pub fn queue_payment(req: QueuePaymentRequest) -> Result<OutboxPayment> {
+    block_on(async { get_breez_services().await?.queue_payment(req).await })
+        .map_err(anyhow::Error::new::<SendPaymentError>)
+}
+
+/// See [BreezServices::list_pending_outbox]
+pub fn list_pending_outbox() -> Result<Vec<OutboxPayment>> {
+    block_on(async { get_breez_services().await?.list_pending_outbox().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::cancel_outbox_payment]
+pub fn cancel_outbox_payment(id: i64) -> Result<()> {
+    block_on(async { get_breez_services().await?.cancel_outbox_payment(id).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::prepare_send_payment]
+pub fn prepare_send_payment(req: PrepareSendPaymentRequest) -> Result<PrepareSendPaymentResponse> {
+    block_on(async { get_breez_services().await?.prepare_send_payment(req).await })
+        .map_err(anyhow::Error::new::<SendPaymentError>)
+}
+
 /// See [BreezServices::send_spontaneous_payment]
 pub fn send_spontaneous_payment(req: SendSpontaneousPaymentRequest) -> Result<SendPaymentResponse> {
     block_on(async {
@@ -568,12 +995,67 @@ pub fn send_spontaneous_payment(req: SendSpontaneousPaymentRequest) -> Result<Se
     .map_err(anyhow::Error::new::<SendPaymentError>)
 }
 
+/// See [BreezServices::cancel]
+pub fn cancel(cancel_token: String) -> Result<bool> {
+    block_on(async { get_breez_services().await?.cancel(cancel_token).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::probe_payment]
+pub fn probe_payment(req: ProbePaymentRequest) -> Result<PaymentProbe> {
+    block_on(async { get_breez_services().await?.probe_payment(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::validate_payability]
+pub fn validate_payability(req: ValidatePayabilityRequest) -> Result<InvoicePayability> {
+    block_on(async { get_breez_services().await?.validate_payability(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::list_payment_probes]
+pub fn list_payment_probes() -> Result<Vec<PaymentProbe>> {
+    block_on(async { get_breez_services().await?.list_payment_probes().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::pay_offer]
+pub fn pay_offer(req: PayOfferRequest) -> Result<SendPaymentResponse> {
+    block_on(async { get_breez_services().await?.pay_offer(req).await })
+        .map_err(anyhow::Error::new::<SendPaymentError>)
+}
+
+/// See [BreezServices::prepare_receive_payment]
+pub fn prepare_receive_payment(
+    req: PrepareReceivePaymentRequest,
+) -> Result<PrepareReceivePaymentResponse> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .prepare_receive_payment(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<ReceivePaymentError>)
+}
+
 /// See [BreezServices::receive_payment]
 pub fn receive_payment(req: ReceivePaymentRequest) -> Result<ReceivePaymentResponse> {
     block_on(async { get_breez_services().await?.receive_payment(req).await })
         .map_err(anyhow::Error::new::<ReceivePaymentError>)
 }
 
+/// See [BreezServices::receive_unified]
+pub fn receive_unified(req: ReceiveUnifiedRequest) -> Result<ReceiveUnifiedResponse> {
+    block_on(async { get_breez_services().await?.receive_unified(req).await })
+        .map_err(anyhow::Error::new::<ReceivePaymentError>)
+}
+
+/// See [BreezServices::amend_invoice]
+pub fn amend_invoice(req: AmendInvoiceRequest) -> Result<LNInvoice> {
+    block_on(async { get_breez_services().await?.amend_invoice(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  LNURL API's */
 
 /// See [BreezServices::lnurl_pay]
@@ -594,6 +1076,35 @@ pub fn lnurl_auth(req_data: crate::LnUrlAuthRequestData) -> Result<LnUrlCallback
         .map_err(anyhow::Error::new::<LnUrlAuthError>)
 }
 
+/// See [BreezServices::list_lnurl_auth_domains]
+pub fn list_lnurl_auth_domains() -> Result<Vec<LnUrlAuthDomain>> {
+    block_on(async { get_breez_services().await?.list_lnurl_auth_domains() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::lnurl_auth_linking_key]
+pub fn lnurl_auth_linking_key(domain: String) -> Result<String> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .lnurl_auth_linking_key(domain)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::export_lnurl_auth]
+pub fn export_lnurl_auth() -> Result<LnUrlAuthExport> {
+    block_on(async { get_breez_services().await?.export_lnurl_auth().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::import_lnurl_auth]
+pub fn import_lnurl_auth(export: LnUrlAuthExport) -> Result<()> {
+    block_on(async { get_breez_services().await?.import_lnurl_auth(export).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Support API */
 
 /// See [BreezServices::report_issue]
@@ -602,6 +1113,24 @@ pub fn report_issue(req: ReportIssueRequest) -> Result<()> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::connection_status]
+pub fn connection_status() -> Result<ConnectionStatus> {
+    block_on(async { get_breez_services().await?.connection_status().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::task_status]
+pub fn task_status() -> Result<Vec<TaskStatus>> {
+    block_on(async { get_breez_services().await?.task_status() })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::peer_connectivity]
+pub fn peer_connectivity() -> Result<PeerConnectivity> {
+    block_on(async { get_breez_services().await?.peer_connectivity().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Fiat Currency API's */
 
 /// See [BreezServices::fetch_fiat_rates]
@@ -610,6 +1139,39 @@ pub fn fetch_fiat_rates() -> Result<Vec<Rate>> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::fiat_rate_history]
+pub fn fiat_rate_history(
+    currency: String,
+    from_timestamp: Option<i64>,
+    to_timestamp: Option<i64>,
+) -> Result<Vec<HistoricalRate>> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .fiat_rate_history(currency, from_timestamp, to_timestamp)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::add_fiat_rate_alert]
+pub fn add_fiat_rate_alert(req: AddFiatRateAlertRequest) -> Result<FiatRateAlert> {
+    block_on(async { get_breez_services().await?.add_fiat_rate_alert(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::list_fiat_rate_alerts]
+pub fn list_fiat_rate_alerts() -> Result<Vec<FiatRateAlert>> {
+    block_on(async { get_breez_services().await?.list_fiat_rate_alerts().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [BreezServices::remove_fiat_rate_alert]
+pub fn remove_fiat_rate_alert(id: i64) -> Result<()> {
+    block_on(async { get_breez_services().await?.remove_fiat_rate_alert(id).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::list_fiat_currencies]
 pub fn list_fiat_currencies() -> Result<Vec<FiatCurrency>> {
     block_on(async { get_breez_services().await?.list_fiat_currencies().await })
@@ -624,6 +1186,12 @@ pub fn pay_onchain(req: PayOnchainRequest) -> Result<PayOnchainResponse> {
         .map_err(anyhow::Error::new::<SendOnchainError>)
 }
 
+/// See [BreezServices::pay_onchain_address]
+pub fn pay_onchain_address(req: PayOnchainAddressRequest) -> Result<PayOnchainAddressResponse> {
+    block_on(async { get_breez_services().await?.pay_onchain_address(req).await })
+        .map_err(anyhow::Error::new::<SendOnchainError>)
+}
+
 /// See [BreezServices::receive_onchain]
 pub fn receive_onchain(req: ReceiveOnchainRequest) -> Result<SwapInfo> {
     block_on(async { get_breez_services().await?.receive_onchain(req).await })
@@ -636,12 +1204,29 @@ pub fn buy_bitcoin(req: BuyBitcoinRequest) -> Result<BuyBitcoinResponse> {
         .map_err(anyhow::Error::new::<ReceiveOnchainError>)
 }
 
+/// See [BreezServices::fetch_buy_bitcoin_quote]
+pub fn fetch_buy_bitcoin_quote(req: FetchBuyBitcoinQuoteRequest) -> Result<BuyBitcoinQuote> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .fetch_buy_bitcoin_quote(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::redeem_onchain_funds]
 pub fn redeem_onchain_funds(req: RedeemOnchainFundsRequest) -> Result<RedeemOnchainFundsResponse> {
     block_on(async { get_breez_services().await?.redeem_onchain_funds(req).await })
         .map_err(anyhow::Error::new::<RedeemOnchainError>)
 }
 
+/// See [BreezServices::bump_fee]
+pub fn bump_fee(req: BumpFeeRequest) -> Result<BumpFeeResponse> {
+    block_on(async { get_breez_services().await?.bump_fee(req).await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::prepare_redeem_onchain_funds]
 pub fn prepare_redeem_onchain_funds(
     req: PrepareRedeemOnchainFundsRequest,
@@ -663,6 +1248,17 @@ pub fn list_refundables() -> Result<Vec<SwapInfo>> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::export_swap_refund_keys]
+pub fn export_swap_refund_keys(req: ExportSwapRefundKeysRequest) -> Result<Vec<SwapRefundKey>> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .export_swap_refund_keys(req)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /// See [BreezServices::prepare_refund]
 pub fn prepare_refund(req: PrepareRefundRequest) -> Result<PrepareRefundResponse> {
     block_on(async { get_breez_services().await?.prepare_refund(req).await })
@@ -687,6 +1283,17 @@ pub fn redeem_swap(swap_address: String) -> Result<()> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::verify_swap_address]
+pub fn verify_swap_address(swap_address: String) -> Result<bool> {
+    block_on(async {
+        get_breez_services()
+            .await?
+            .verify_swap_address(swap_address)
+            .await
+    })
+    .map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  In Progress Swap API's */
 
 /// See [BreezServices::in_progress_swap]
@@ -786,6 +1393,17 @@ pub fn generate_diagnostic_data() -> Result<String> {
         .map_err(anyhow::Error::new::<SdkError>)
 }
 
+/// See [BreezServices::diagnostic_snapshot]
+pub fn diagnostic_snapshot() -> Result<String> {
+    block_on(async { get_breez_services().await?.diagnostic_snapshot().await })
+        .map_err(anyhow::Error::new::<SdkError>)
+}
+
+/// See [crate::diff_snapshots]
+pub fn diff_snapshots(a: String, b: String) -> Result<Vec<SnapshotChange>> {
+    crate::diff_snapshots(a, b).map_err(anyhow::Error::new::<SdkError>)
+}
+
 /*  Binding Related Logic */
 
 struct BindingEventListener;
@@ -812,7 +1430,7 @@ impl BindingLogger {
 
 impl log::Log for BindingLogger {
     fn enabled(&self, m: &Metadata) -> bool {
-        m.level() <= Level::Trace
+        m.level() <= Level::Trace && log_filter_enabled(m).unwrap_or(true)
     }
 
     fn log(&self, record: &Record) {
@@ -820,6 +1438,7 @@ impl log::Log for BindingLogger {
             self.log_stream.add(LogEntry {
                 line: record.args().to_string(),
                 level: record.level().as_str().to_string(),
+                module: record.module_path().unwrap_or(record.target()).to_string(),
             });
         }
     }
@@ -0,0 +1,216 @@
+//! Signatures of messages by the keys of onchain addresses, following the "simple" format of
+//! [BIP-322](https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki).
+//!
+//! The message is committed to by a virtual `to_spend` transaction paying to the address, and
+//! the signature is the witness of a virtual `to_sign` transaction spending it. Only single key
+//! P2WPKH and P2TR (key path, without script tree) addresses are supported.
+
+use anyhow::{anyhow, Result};
+
+use crate::bitcoin::blockdata::opcodes::all::OP_RETURN;
+use crate::bitcoin::blockdata::script::Builder;
+use crate::bitcoin::consensus::serialize;
+use crate::bitcoin::hashes::{sha256, Hash, HashEngine};
+use crate::bitcoin::secp256k1::{ecdsa, schnorr, Message};
+use crate::bitcoin::util::sighash::{Prevouts, SighashCache};
+use crate::bitcoin::{
+    EcdsaSighashType, OutPoint, PackedLockTime, PubkeyHash, PublicKey, SchnorrSighashType, Script,
+    Sequence, Transaction, TxIn, TxOut, Witness,
+};
+
+const MESSAGE_TAG: &[u8] = b"BIP0322-signed-message";
+
+/// The tagged hash of the message
+fn message_hash(message: &str) -> sha256::Hash {
+    let tag = sha256::Hash::hash(MESSAGE_TAG);
+    let mut engine = sha256::Hash::engine();
+    engine.input(&tag[..]);
+    engine.input(&tag[..]);
+    engine.input(message.as_bytes());
+    sha256::Hash::from_engine(engine)
+}
+
+fn to_spend(script_pubkey: &Script, message: &str) -> Transaction {
+    Transaction {
+        version: 0,
+        lock_time: PackedLockTime(0),
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Builder::new()
+                .push_int(0)
+                .push_slice(&message_hash(message)[..])
+                .into_script(),
+            sequence: Sequence(0),
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: 0,
+            script_pubkey: script_pubkey.clone(),
+        }],
+    }
+}
+
+fn to_sign(to_spend: &Transaction) -> Transaction {
+    Transaction {
+        version: 0,
+        lock_time: PackedLockTime(0),
+        input: vec![TxIn {
+            previous_output: OutPoint::new(to_spend.txid(), 0),
+            script_sig: Script::new(),
+            sequence: Sequence(0),
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: 0,
+            script_pubkey: Builder::new().push_opcode(OP_RETURN).into_script(),
+        }],
+    }
+}
+
+/// The hash to sign to prove the ownership of the address of `script_pubkey`: with ECDSA for a
+/// P2WPKH address, with the tweaked key of a P2TR address.
+pub(crate) fn signature_hash(script_pubkey: &Script, message: &str) -> Result<Message> {
+    let to_spend = to_spend(script_pubkey, message);
+    let to_sign = to_sign(&to_spend);
+    let mut sighasher = SighashCache::new(&to_sign);
+
+    if script_pubkey.is_v0_p2wpkh() {
+        let pubkey_hash = PubkeyHash::from_slice(&script_pubkey.as_bytes()[2..])?;
+        let script_code = Script::new_p2pkh(&pubkey_hash);
+        let sighash = sighasher.segwit_signature_hash(0, &script_code, 0, EcdsaSighashType::All)?;
+        Ok(Message::from_slice(&sighash[..])?)
+    } else if script_pubkey.is_v1_p2tr() {
+        let prevouts = [to_spend.output[0].clone()];
+        let sighash = sighasher.taproot_key_spend_signature_hash(
+            0,
+            &Prevouts::All(&prevouts),
+            SchnorrSighashType::Default,
+        )?;
+        Ok(Message::from(sighash))
+    } else {
+        Err(anyhow!("Only P2WPKH and P2TR addresses are supported"))
+    }
+}
+
+/// The base64 encoded witness of the `to_sign` transaction of a P2WPKH address, from the
+/// signature of its [signature_hash]
+pub(crate) fn p2wpkh_signature(signature: &ecdsa::Signature, pubkey: &PublicKey) -> String {
+    let mut signature = signature.serialize_der().to_vec();
+    signature.push(EcdsaSighashType::All as u8);
+    base64::encode(serialize(&Witness::from_vec(vec![
+        signature,
+        pubkey.to_bytes(),
+    ])))
+}
+
+/// The base64 encoded witness of the `to_sign` transaction of a P2TR address, from the
+/// signature of its [signature_hash]
+pub(crate) fn p2tr_signature(signature: &schnorr::Signature) -> String {
+    base64::encode(serialize(&Witness::from_vec(vec![signature
+        .as_ref()
+        .to_vec()])))
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{
+        message_hash, p2tr_signature, p2wpkh_signature, signature_hash, to_sign, to_spend,
+    };
+    use crate::bitcoin::consensus::deserialize;
+    use crate::bitcoin::hashes::hex::ToHex;
+    use crate::bitcoin::secp256k1::{ecdsa, schnorr, KeyPair, Message, Secp256k1, SecretKey};
+    use crate::bitcoin::util::schnorr::TapTweak;
+    use crate::bitcoin::util::sighash::{Prevouts, SighashCache};
+    use crate::bitcoin::{Address, EcdsaSighashType, Network, PublicKey, SchnorrSighashType};
+    use crate::bitcoin::{Script, Witness, XOnlyPublicKey};
+
+    #[test]
+    fn test_message_hash() {
+        // Test vectors of BIP-322
+        assert_eq!(
+            message_hash("").to_hex(),
+            "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+        );
+        assert_eq!(
+            message_hash("Hello World").to_hex(),
+            "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+        );
+    }
+
+    #[test]
+    fn test_to_spend_txid() {
+        // Test vectors of BIP-322
+        let address: Address = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l"
+            .parse()
+            .unwrap();
+        let to_spend = to_spend(&address.script_pubkey(), "Hello World");
+        assert_eq!(
+            to_spend.txid().to_hex(),
+            "b79d196740ad5217771c1098fc4a4b51e0535c32236c71f1ea4d61a2d603352b"
+        );
+        assert_eq!(
+            to_sign(&to_spend).txid().to_hex(),
+            "88737ae86f2077145f93cc4b153ae9a1cb8d56afa511988c149c5c8c9d93bddf"
+        );
+    }
+
+    #[test]
+    fn test_sign_simple() -> Result<()> {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[3; 32])?;
+        let pubkey = PublicKey::new(secret_key.public_key(&secp));
+        let message = "I own this address";
+
+        let p2wpkh = Address::p2wpkh(&pubkey, Network::Bitcoin)?.script_pubkey();
+        let signature = secp.sign_ecdsa(&signature_hash(&p2wpkh, message)?, &secret_key);
+        let witness = decode(&p2wpkh_signature(&signature, &pubkey))?;
+        let to_spend = to_spend(&p2wpkh, message);
+        let to_sign = to_sign(&to_spend);
+        let sighash = SighashCache::new(&to_sign).segwit_signature_hash(
+            0,
+            &Script::new_p2pkh(&pubkey.pubkey_hash()),
+            0,
+            EcdsaSighashType::All,
+        )?;
+        let signature = witness.to_vec()[0].clone();
+        assert_eq!(witness.to_vec()[1], pubkey.to_bytes());
+        secp.verify_ecdsa(
+            &Message::from_slice(&sighash[..])?,
+            &ecdsa::Signature::from_der(&signature[..signature.len() - 1])?,
+            &pubkey.inner,
+        )?;
+
+        let (internal_key, _) =
+            XOnlyPublicKey::from_keypair(&KeyPair::from_secret_key(&secp, &secret_key));
+        let p2tr = Address::p2tr(&secp, internal_key, None, Network::Bitcoin);
+        let p2tr = p2tr.script_pubkey();
+        let keypair = KeyPair::from_secret_key(&secp, &secret_key)
+            .tap_tweak(&secp, None)
+            .to_inner();
+        let signature = secp.sign_schnorr(&signature_hash(&p2tr, message)?, &keypair);
+        let witness = decode(&p2tr_signature(&signature))?;
+        let to_spend = to_spend(&p2tr, message);
+        let to_sign = to_sign(&to_spend);
+        let sighash = SighashCache::new(&to_sign).taproot_key_spend_signature_hash(
+            0,
+            &Prevouts::All(&[to_spend.output[0].clone()]),
+            SchnorrSighashType::Default,
+        )?;
+        let output_key = XOnlyPublicKey::from_slice(&p2tr.as_bytes()[2..])?;
+        secp.verify_schnorr(
+            &schnorr::Signature::from_slice(&witness.to_vec()[0])?,
+            &Message::from(sighash),
+            &output_key,
+        )?;
+
+        // Other scripts aren't supported
+        assert!(signature_hash(&Script::new_p2pkh(&pubkey.pubkey_hash()), message).is_err());
+        Ok(())
+    }
+
+    fn decode(signature: &str) -> Result<Witness> {
+        Ok(deserialize(&base64::decode(signature)?)?)
+    }
+}
@@ -1,58 +1,176 @@
+use std::cmp::Reverse;
 use std::collections::HashMap;
-use std::fs::OpenOptions;
+use std::future::Future;
 use std::io::Write;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{anyhow, Result};
 use bip39::*;
 use bitcoin::hashes::hex::ToHex;
-use bitcoin::hashes::{sha256, Hash};
+use bitcoin::hashes::{sha256, Hash, HashEngine, Hmac, HmacEngine};
+use bitcoin::secp256k1::{PublicKey, Secp256k1};
 use bitcoin::util::bip32::ChildNumber;
 use chrono::Local;
-use futures::TryFutureExt;
+use futures::{stream, Stream, StreamExt, TryFutureExt};
+use gl_client::credentials::Device;
 use gl_client::pb::incoming_payment;
 use log::{LevelFilter, Metadata, Record};
+use rand::Rng;
 use sdk_common::grpc;
 use sdk_common::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use strum_macros::EnumString;
-use tokio::sync::{mpsc, watch, Mutex};
-use tokio::time::{sleep, MissedTickBehavior};
+use tokio::sync::{broadcast, mpsc, oneshot, watch, Mutex};
+use tokio::time::{sleep, timeout, MissedTickBehavior};
 
-use crate::backup::{BackupRequest, BackupTransport, BackupWatcher};
+use crate::backup::{self, BackupRequest, BackupTransport, BackupWatcher, MemoryBackupTransport};
+#[cfg(feature = "buy-bitcoin")]
 use crate::buy::{BuyBitcoinApi, BuyBitcoinService};
+use crate::cancellation::{Cancellation, Cancellations};
 use crate::chain::{
-    ChainService, Outspend, RecommendedFees, RedundantChainService, RedundantChainServiceTrait,
-    DEFAULT_MEMPOOL_SPACE_URL,
+    test_network_mempool_space_url, ChainService, ElectrumChainService, Esplora, Outspend,
+    RecommendedFees, RedundantChainService, RedundantChainServiceTrait, DEFAULT_MEMPOOL_SPACE_URL,
 };
+use crate::denylist::Denylist;
 use crate::error::{
     ConnectError, ReceiveOnchainError, ReceiveOnchainResult, ReceivePaymentError,
-    RedeemOnchainResult, SdkError, SdkResult, SendOnchainError, SendPaymentError,
+    RedeemOnchainError, RedeemOnchainResult, SdkError, SdkResult, SendOnchainError,
+    SendPaymentError,
 };
-use crate::greenlight::{GLBackupTransport, Greenlight};
+use crate::greenlight::{GLBackupTransport, Greenlight, SeedSigner};
+use crate::htlc_exposure::{HtlcExposureError, InflightReservation, InflightTracker};
+use crate::lightning_invoice::RawBolt11Invoice;
+#[cfg(feature = "lnurl")]
 use crate::lnurl::auth::SdkLnurlAuthSigner;
+#[cfg(feature = "lnurl")]
 use crate::lnurl::pay::*;
-use crate::lsp::LspInformation;
+use crate::log_file::RotatingLogFile;
+use crate::lsp::{select_lsp, LspInformation};
+use crate::metrics::{MeteredLspAPI, Metrics};
+#[cfg(feature = "mock-node")]
+use crate::mock_node::MockNode;
 use crate::models::{
     sanitize::*, ChannelState, ClosedChannelPaymentDetails, Config, EnvironmentType, LspAPI,
-    NodeState, Payment, PaymentDetails, PaymentType, ReverseSwapPairInfo, ReverseSwapServiceAPI,
-    SwapInfo, SwapperAPI, INVOICE_PAYMENT_FEE_EXPIRY_SECONDS,
+    NodeState, Payment, PaymentDetails, PaymentType, SwapInfo, SwapperAPI, DEFAULT_MAX_ROUTE_HINTS,
+    DEFAULT_SEND_PAYMENTS_CONCURRENCY, INVOICE_PAYMENT_FEE_EXPIRY_SECONDS,
 };
-use crate::node_api::{CreateInvoiceRequest, NodeAPI};
+#[cfg(feature = "reverse-swaps")]
+use crate::models::{ReverseSwapPairInfo, ReverseSwapServiceAPI};
+use crate::node_api::{CreateInvoiceRequest, NodeAPI, NodeError, PaymentLimits};
+use crate::payment_request::{decrypt_bundle, encrypt_bundle, PAYMENT_REQUEST_KEY_PURPOSE};
 use crate::persist::cache::NodeStateStorage;
+use crate::persist::channels::ChannelActivity;
 use crate::persist::db::SqliteStorage;
+#[cfg(feature = "lnurl")]
+use crate::persist::lnurl_pay::PendingLnurlPay;
+use crate::persist::migrations::{current_sync_migrations, MIN_BACKUP_SCHEMA_VERSION};
+use crate::persist::onchain_txs::RedeemTx;
 use crate::persist::swap::SwapStorage;
 use crate::persist::transactions::PaymentStorage;
-use crate::swap_in::{BTCReceiveSwap, BTCReceiveSwapParameters, TaprootSwapperAPI};
+use crate::persist::writer::PaymentsWriter;
+use crate::send_queue::SendQueue;
+use crate::signer::Signer;
+use crate::snapshot::{keyed_by, redact};
+use crate::spend_policy::{SpendPolicyError, SpendReservation, SPEND_POLICY_WINDOW_SECS};
+#[cfg(feature = "lnurl")]
+use crate::static_lnurl_pay::{self, encode_lnurl};
+use crate::supervisor::{TaskResult, TaskSupervisor};
+use crate::swap_in::{
+    needs_new_opening_fee, BTCReceiveSwap, BTCReceiveSwapParameters, TaprootSwapperAPI,
+};
+#[cfg(feature = "reverse-swaps")]
 use crate::swap_out::boltzswap::BoltzApi;
+#[cfg(feature = "reverse-swaps")]
+use crate::swap_out::error::ReverseSwapError;
+#[cfg(feature = "reverse-swaps")]
 use crate::swap_out::reverseswap::BTCSendSwap;
 use crate::*;
 
 pub type BreezServicesResult<T, E = ConnectError> = Result<T, E>;
 
+/// The max number of hops of the routes probed to estimate how much can be sent to a node
+const ROUTE_PROBE_MAX_HOPS: u32 = 6;
+/// The number of events buffered for each [BreezServices::subscribe] stream. Slower subscribers
+/// miss the older events.
+const EVENT_STREAM_CAPACITY: usize = 100;
+/// The label of the payments of the [Config::partner_fee]
+const PARTNER_FEE_PAYMENT_LABEL: &str = "partner-fee";
+/// The basis points of an amount
+const BASIS_POINTS: u64 = 10_000;
+/// How often the swap monitor processes the pending swaps, between the blocks
+const SWAP_MONITOR_INTERVAL_SECS: u64 = 60;
+/// How often the outbox monitor looks for queued payments due for an attempt
+const OUTBOX_MONITOR_INTERVAL_SECS: u64 = 10;
+/// The delay before the first retry of a queued payment, doubled after each failed attempt
+const OUTBOX_MIN_RETRY_DELAY_SECS: u64 = 15;
+/// The maximum delay between two attempts of a queued payment
+const OUTBOX_MAX_RETRY_DELAY_SECS: u64 = 60 * 60;
+/// The number of failed attempts after which a queued payment is abandoned
+const OUTBOX_MAX_ATTEMPTS: u32 = 20;
+/// How often the connectivity monitor checks that the node and the LSP peer are reachable
+const CONNECTION_CHECK_INTERVAL_SECS: u64 = 30;
+/// The delay before the first reconnection attempt, doubled after each failed attempt
+const RECONNECT_MIN_DELAY_MS: u64 = 1_000;
+/// The maximum delay between two reconnection attempts
+const RECONNECT_MAX_DELAY_MS: u64 = 5 * 60 * 1_000;
+/// How long the LSP peer is pinged before a payment or an invoice, before reconnecting it
+const LSP_PEER_PING_TIMEOUT_SECS: u64 = 3;
+/// How long the reconnection of the LSP peer can delay a payment or an invoice
+const LSP_PEER_RECONNECT_TIMEOUT_SECS: u64 = 5;
+/// The estimated time for the funding tx of a channel to confirm, 3 blocks
+const CHANNEL_OPEN_EXPECTED_CONFIRMATION_SECS: u64 = 30 * 60;
+/// How long the denylist of [Config::denylist_url] is used before it's fetched again
+const DENYLIST_MAX_AGE_SECS: u64 = 24 * 60 * 60;
+/// How often the denylist monitor checks whether the denylist is due for a refresh
+const DENYLIST_CHECK_INTERVAL_SECS: u64 = 60 * 60;
+/// The timeout of each service status check of the connectivity monitor, see
+/// [Config::service_status_check_interval_secs]
+const SERVICE_STATUS_CHECK_TIMEOUT_SECS: u64 = 10;
+/// How long an LNURL-pay is kept pending while its payment is unknown to the node
+#[cfg(feature = "lnurl")]
+const PENDING_LNURL_PAY_EXPIRY_SECS: i64 = 24 * 60 * 60;
+
+/// The hardened derivation path index under which the app encryption keys are derived, see
+/// [BreezServices::derive_encryption_key]. 139 and 140 are used for the backup and credentials
+/// encryption keys.
+const APP_ENCRYPTION_KEY_PATH_INDEX: u32 = 141;
+/// The setting holding the [ExportRecoveryBundleRequest] of the recovery bundle file refreshed
+/// after channel changes
+const RECOVERY_BUNDLE_SETTING: &str = "recovery-bundle";
+/// The generic instructions of a [RecoveryBundle]
+const RECOVERY_INSTRUCTIONS: &str = "This bundle helps recover the funds of a Lightning wallet. \
+It doesn't contain the seed of the wallet (its 12 or 24 recovery words), which is needed as well.
+
+1. Restore the wallet from its seed in an app built on the Breez SDK, or in a Core Lightning \
+node. The onchain funds and the funds of the channels that are still open are recovered with the \
+seed alone.
+2. If the channels can't be recovered that way, for example because the service hosting the node \
+is gone, pass the static_backup of this bundle to the `recoverchannel` command of a Core \
+Lightning node restored from the seed. The peers of the channels then close them, and the funds \
+are sent to the onchain wallet of the node.";
+/// How many keys of the onchain wallet, derived at `m/0/0/index` like the node does, are searched
+/// for the key of an address, see [BreezServices::prove_address_ownership]
+const ONCHAIN_WALLET_KEY_SCAN_LIMIT: u32 = 1_000;
+/// The window over which the LNURL-auth attempts of a domain are rate-limited
+#[cfg(feature = "lnurl")]
+const LNURL_AUTH_RATE_LIMIT_WINDOW_SECS: i64 = 60;
+/// How many LNURL-auth challenges of a domain can be signed within the rate limit window
+#[cfg(feature = "lnurl")]
+const LNURL_AUTH_MAX_ATTEMPTS_PER_WINDOW: u32 = 5;
+/// How many of the most recent payments are included in [BreezServices::diagnostic_snapshot]
+const DIAGNOSTIC_SNAPSHOT_PAYMENTS: u32 = 100;
+/// The estimated size of a cooperative channel close tx, spending the 2-of-2 funding output to
+/// an output for each side, used to project the fees of sweeping a stale channel
+const CHANNEL_CLOSE_TX_VSIZE: u64 = 170;
+/// The final CLTV delta of the probes sent to a node id, the BOLT11 default
+const PROBE_FINAL_CLTV_DELTA: u64 = 18;
+/// The token the [BreezServices::sync] in progress is registered with
+const SYNC_CANCEL_TOKEN: &str = "sync";
+
 /// Trait that can be used to react to various [BreezEvent]s emitted by the SDK.
 pub trait EventListener: Send + Sync {
     fn on_event(&self, e: BreezEvent);
@@ -60,7 +178,8 @@ pub trait EventListener: Send + Sync {
 
 /// Event emitted by the SDK. To listen for and react to these events, use an [EventListener] when
 /// initializing the [BreezServices].
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
 #[allow(clippy::large_enum_variant)]
 pub enum BreezEvent {
     /// Indicates that a new block has just been found
@@ -85,14 +204,93 @@ pub enum BreezEvent {
     /// Indicates that a swap has been updated which may also
     /// include a status change
     SwapUpdated { details: SwapInfo },
+    /// Indicates the progress of [BreezServices::connect], emitted once per [ConnectStage] and
+    /// again each time a step of the [ConnectStage::InitialSync] completes
+    ConnectProgress { details: ConnectProgressDetails },
+    /// Emitted on [BreezServices::connect] when funds are found that need an action from the
+    /// user to be recovered
+    UnredeemedFundsDetected { details: UnredeemedFundsDetails },
+    /// Indicates that an LNURL payment for which [LnUrlPayResult::Pending] was returned has
+    /// finished
+    #[cfg(feature = "lnurl")]
+    LnUrlPayFinished { details: LnUrlPayFinishedDetails },
+    /// Indicates the progress of a payment received through a channel opened by the LSP, for
+    /// example the first payment received by a node
+    OpenChannelReceiveUpdated { details: OpenChannelReceiveDetails },
+    /// Emitted after a sync when the amount that can be sent drops below
+    /// [Config::low_outbound_liquidity_threshold_msat], to prompt the user to top up
+    LowOutboundLiquidity { details: LowLiquidityDetails },
+    /// Emitted after a sync when the amount that can be received through the existing channels
+    /// drops below [Config::low_inbound_liquidity_threshold_msat], to prompt the user to get
+    /// more inbound capacity
+    LowInboundLiquidity { details: LowLiquidityDetails },
+    /// Indicates that the LSP opened a channel to forward a payment, and that `fee_msat`,
+    /// calculated from the `proportional` rate in ppm with a minimum of `min_fee_msat`, is
+    /// deducted from it for the channel opening
+    ChannelOpening {
+        fee_msat: u64,
+        min_fee_msat: u64,
+        proportional: u32,
+    },
+    /// Indicates that the SDK selected another LSP, for example according to the
+    /// [Config::lsp_policy] or because the previous LSP is no longer available
+    LspChanged {
+        previous_lsp_id: Option<String>,
+        lsp_id: String,
+    },
+    /// Indicates that a refundable swap was refunded automatically, see [Config::swap_auto_refund]
+    SwapRefunded { details: SwapRefundedDetails },
+    /// Emitted each time a payment of [BreezServices::send_payments] finishes
+    BatchPaymentProgress {
+        details: BatchPaymentProgressDetails,
+    },
+    /// Indicates that a payment was queued in the outbox, or that one of its attempts finished
+    OutboxPaymentUpdated { details: OutboxPaymentDetails },
+    /// Indicates that a service accepted the signature of [BreezServices::lnurl_auth]
+    #[cfg(feature = "lnurl")]
+    LnUrlAuthCompleted { details: LnUrlAuthDomain },
+    /// Emitted after a sync for each channel that was not used and whose peer was not connected
+    /// during [Config::channel_hygiene_window_secs], suggesting to close it with
+    /// [BreezServices::close_channel] before onchain fees rise
+    ChannelHygieneSuggestion {
+        details: ChannelHygieneSuggestionDetails,
+    },
+    /// Indicates that a fetched exchange rate crossed the threshold of a price alert, see
+    /// [BreezServices::add_fiat_rate_alert]
+    #[cfg(feature = "fiat")]
+    FiatRateAlertTriggered {
+        details: FiatRateAlertTriggeredDetails,
+    },
+    /// Indicates that the node and the LSP peer are reachable again after a
+    /// [BreezEvent::Disconnected]
+    Connected,
+    /// Indicates that the node or the LSP peer can't be reached anymore. The SDK reconnects
+    /// automatically, see [BreezServices::connection_status].
+    Disconnected { error: String },
+    /// Indicates that a reconnection is attempted in `delay_ms`, the delay growing exponentially
+    /// with the attempts
+    Reconnecting { attempt: u32, delay_ms: u64 },
+    /// Indicates that the LSP peer couldn't be reached before a payment was sent or an invoice
+    /// was created, for the `reason` given, and that it was reconnected
+    LspPeerReconnected { lsp_pubkey: String, reason: String },
+    /// Indicates that a keysend payment was received, with the sender metadata in
+    /// [LnPaymentDetails::spontaneous_metadata]. It follows the [BreezEvent::InvoicePaid] of the
+    /// invoice the node created for it.
+    SpontaneousPaymentReceived { details: Payment },
+    /// Indicates that a service the SDK depends on degraded or recovered, see
+    /// [Config::service_status_check_interval_secs]
+    ServiceStatusChanged {
+        component: ServiceComponent,
+        status: ServiceStatus,
+    },
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct BackupFailedData {
     pub error: String,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct PaymentFailedData {
     pub error: String,
     pub node_id: String,
@@ -100,14 +298,181 @@ pub struct PaymentFailedData {
     pub label: Option<String>,
 }
 
+/// Result of an LNURL payment that continued in the background, included as payload in
+/// [BreezEvent::LnUrlPayFinished]
+#[cfg(feature = "lnurl")]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LnUrlPayFinishedDetails {
+    pub payment_hash: String,
+    /// The final result. Errors that occurred while paying are reported as
+    /// [LnUrlPayResult::PayError].
+    pub result: LnUrlPayResult,
+}
+
 /// Details of an invoice that has been paid, included as payload in an emitted [BreezEvent]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct InvoicePaidDetails {
     pub payment_hash: String,
     pub bolt11: String,
     pub payment: Option<Payment>,
 }
 
+/// The progress of a payment received through a new channel, included as payload in
+/// [BreezEvent::OpenChannelReceiveUpdated]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OpenChannelReceiveDetails {
+    pub payment_hash: String,
+    pub stage: OpenChannelReceiveStage,
+}
+
+/// The stages of a payment received through a channel opened by the LSP
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum OpenChannelReceiveStage {
+    /// The invoice was created and the channel opening was negotiated with the LSP, which
+    /// deducts `opening_fee_msat` from the received amount
+    Registered { opening_fee_msat: u64 },
+    /// The LSP opened the channel and forwards the payment through it
+    ChannelPending { funding_txid: String },
+    /// The payment was received and `opening_fee_msat` was charged for the channel
+    Settled { opening_fee_msat: u64 },
+    /// The invoice was registered with the LSP again, for example after the channel opening
+    /// timed out, so the payer can retry the payment with the same invoice
+    Reregistered,
+}
+
+/// The liquidity that dropped below its configured threshold, included as payload in
+/// [BreezEvent::LowOutboundLiquidity] and [BreezEvent::LowInboundLiquidity]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LowLiquidityDetails {
+    pub liquidity_msat: u64,
+    pub threshold_msat: u64,
+}
+
+/// A stale channel suggested to be closed, included as payload in
+/// [BreezEvent::ChannelHygieneSuggestion]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChannelHygieneSuggestionDetails {
+    pub channel_id: String,
+    pub peer_id: String,
+    pub funding_txid: String,
+    pub local_balance_msat: u64,
+    /// The seconds since the channel was last used
+    pub idle_secs: u64,
+    /// The seconds since the peer was last seen connected
+    pub peer_offline_secs: u64,
+    /// The current `hourFee` rate the sweep fee is projected with
+    pub sat_per_vbyte: u64,
+    /// The projected fee of a cooperative close of the channel
+    pub projected_sweep_fee_sat: u64,
+    /// The projected amount returned onchain, the local balance minus the sweep fee
+    pub projected_sweep_amount_sat: u64,
+}
+
+/// A price alert and the exchange rate that triggered it, included as payload in
+/// [BreezEvent::FiatRateAlertTriggered]
+#[cfg(feature = "fiat")]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FiatRateAlertTriggeredDetails {
+    pub alert: FiatRateAlert,
+    pub rate: f64,
+}
+
+/// A swap refunded automatically, included as payload in [BreezEvent::SwapRefunded]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SwapRefundedDetails {
+    pub swap_address: String,
+    pub refund_tx_id: String,
+    pub to_address: String,
+    pub sat_per_vbyte: u32,
+}
+
+/// The progress of a batch of payments, included as payload in [BreezEvent::BatchPaymentProgress]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BatchPaymentProgressDetails {
+    /// The payment that just finished
+    pub result: BatchPaymentResult,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub total: u32,
+}
+
+/// A payment of the outbox and its new state, included as payload in
+/// [BreezEvent::OutboxPaymentUpdated]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OutboxPaymentDetails {
+    pub outbox_payment: OutboxPayment,
+    pub status: OutboxPaymentStatus,
+}
+
+/// Funds that are not yet in the user's balance but can be recovered, included as payload in
+/// [BreezEvent::UnredeemedFundsDetected]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct UnredeemedFundsDetails {
+    /// Swaps that failed or expired and can be refunded with [BreezServices::refund]
+    pub refundable_swaps: Vec<SwapInfo>,
+    /// Swaps with confirmed funds whose invoice was not paid yet. They can be retried with
+    /// [BreezServices::redeem_swap]
+    pub redeemable_swaps: Vec<SwapInfo>,
+    /// Swaps with confirmed funds whose invoice expired unpaid after their channel opening fee
+    /// params expired too. [BreezServices::redeem_swap] needs a new opening fee for them, which
+    /// fails if it's higher than the accepted one, so they may have to be refunded instead
+    pub expired_invoice_swaps: Vec<SwapInfo>,
+    /// Reverse swaps whose lockup tx was broadcast but that are not claimed yet
+    pub unclaimed_reverse_swaps: Vec<ReverseSwapInfo>,
+    /// The total amount of the above, in satoshis
+    pub total_amount_sat: u64,
+}
+
+/// The stages [BreezServices::connect] goes through, in the order they are reported
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ConnectStage {
+    /// No stored credentials were found, trying to recover an existing node from the seed
+    NodeRecovery,
+    /// No existing node was found for the seed, registering a new one
+    NodeRegistration,
+    /// Authenticating the node credentials with the scheduler
+    SchedulerHandshake,
+    /// Starting the signer and the node background tasks
+    NodeStartup,
+    /// Syncing the node state for the first time
+    InitialSync,
+    /// The SDK is connected and ready to use
+    Completed,
+}
+
+impl ConnectStage {
+    /// The overall connect progress, from 0 to 100, once this stage is reached
+    pub fn progress_percent(&self) -> u8 {
+        match self {
+            ConnectStage::NodeRecovery => 10,
+            ConnectStage::NodeRegistration => 20,
+            ConnectStage::SchedulerHandshake => 30,
+            ConnectStage::NodeStartup => 50,
+            ConnectStage::InitialSync => 70,
+            ConnectStage::Completed => 100,
+        }
+    }
+
+    /// The overall connect progress once `done` of the `total` steps of the initial sync are
+    /// completed, from the [ConnectStage::InitialSync] progress up to the completed one
+    fn initial_sync_percent(done: u32, total: u32) -> u8 {
+        let start = ConnectStage::InitialSync.progress_percent() as u32;
+        let end = ConnectStage::Completed.progress_percent() as u32;
+        (start + (end - start) * done.min(total) / total.max(1)) as u8
+    }
+}
+
+/// Details of the connect progress, included as payload in an emitted [BreezEvent]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ConnectProgressDetails {
+    pub stage: ConnectStage,
+    /// The overall connect progress, from 0 to 100
+    pub progress_percent: u8,
+    /// Whether a new node was registered (`true`) or an existing node was restored or
+    /// reconnected (`false`). It is `None` while this is not yet known.
+    pub is_new_node: Option<bool>,
+}
+
 pub trait LogStream: Send + Sync {
     fn log(&self, l: LogEntry);
 }
@@ -119,6 +484,42 @@ pub struct SignMessageRequest {
     pub message: String,
 }
 
+/// Request to derive a key for the app to encrypt its own data, see
+/// [BreezServices::derive_encryption_key]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeriveEncryptionKeyRequest {
+    /// What the key is used for, for example `contacts`. Keys for different purposes are
+    /// unrelated.
+    pub purpose: String,
+    /// Allows to derive multiple keys for the same purpose, for example to rotate them. Must be
+    /// lower than 2^31.
+    pub index: u32,
+}
+
+/// Response to a [DeriveEncryptionKeyRequest]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeriveEncryptionKeyResponse {
+    /// The 32 byte key, for example for AES-256
+    pub key: Vec<u8>,
+}
+
+/// Request to prove the ownership of an onchain address, see
+/// [BreezServices::prove_address_ownership]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProveAddressOwnershipRequest {
+    /// An address of the onchain wallet of the node
+    pub address: String,
+    /// The message to sign, for example the challenge of an exchange
+    pub message: String,
+}
+
+/// Response to a [ProveAddressOwnershipRequest]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProveAddressOwnershipResponse {
+    /// The BIP-322 "simple" signature of the message, base64 encoded
+    pub signature: String,
+}
+
 /// Response to a [SignMessageRequest].
 #[derive(Clone, Debug, PartialEq)]
 pub struct SignMessageResponse {
@@ -159,7 +560,9 @@ pub struct BreezServices {
     started: Mutex<bool>,
     node_api: Arc<dyn NodeAPI>,
     lsp_api: Arc<dyn LspAPI>,
+    #[cfg(feature = "fiat")]
     fiat_api: Arc<dyn FiatAPI>,
+    #[cfg(feature = "buy-bitcoin")]
     buy_bitcoin_api: Arc<dyn BuyBitcoinApi>,
     support_api: Arc<dyn SupportAPI>,
     chain_service: Arc<dyn ChainService>,
@@ -167,10 +570,50 @@ pub struct BreezServices {
     rest_client: Arc<dyn RestClient>,
     payment_receiver: Arc<PaymentReceiver>,
     btc_receive_swapper: Arc<BTCReceiveSwap>,
+    #[cfg(feature = "reverse-swaps")]
     btc_send_swapper: Arc<BTCSendSwap>,
     event_listener: Option<Box<dyn EventListener>>,
+    event_sender: broadcast::Sender<BreezEvent>,
     backup_watcher: Arc<BackupWatcher>,
+    /// Runs the long-lived background tasks, see [BreezServices::task_status]
+    task_supervisor: Arc<TaskSupervisor>,
+    send_queue: Arc<SendQueue>,
+    /// Persists the payments being sent, see [PaymentsWriter]
+    payments_writer: Arc<PaymentsWriter>,
+    /// The payments being sent which are not persisted as pending yet
+    htlc_inflight: InflightTracker,
+    /// The calls started with a cancel token
+    cancellations: Cancellations,
+    /// The [BreezServices::sync] in progress, registered with [SYNC_CANCEL_TOKEN]
+    sync_cancellations: Cancellations,
     shutdown_sender: watch::Sender<()>,
+    /// Whether connecting registered a new node, if known
+    is_new_node: Option<bool>,
+    /// Whether the first sync of the node runs while connecting, see
+    /// [BreezServices::initial_sync]
+    initial_sync_in_progress: AtomicBool,
+    /// The result of the last [BreezServices::sync], locked while a sync is in flight
+    last_sync_result: Mutex<Option<SdkResult<()>>>,
+    lsp_reconnections: Mutex<LspReconnections>,
+    connection_status: Mutex<ConnectionStatus>,
+    metrics: Arc<Metrics>,
+    /// Whether the wallet was connected with [BreezServices::connect_ephemeral]
+    ephemeral: bool,
+}
+
+/// The response of the LNURL server to the registration of a voucher
+#[derive(Deserialize)]
+struct LnurlWithdrawRegistration {
+    lnurl: String,
+}
+
+/// The reconnections to the LSP peer, reported by [BreezServices::peer_connectivity]
+#[derive(Default)]
+struct LspReconnections {
+    attempts: u32,
+    failed_attempts: u32,
+    last_reconnected_at: Option<i64>,
+    last_error: Option<String>,
 }
 
 impl BreezServices {
@@ -188,8 +631,10 @@ impl BreezServices {
     ///   private key, typically derived from the mnemonic. When using a new `invite_code`,
     ///   the seed should be derived from a new random mnemonic. When re-using an `invite_code`,
     ///   the same mnemonic should be used as when the `invite_code` was first used.
-    /// * `event_listener` - Listener to SDK events
+    /// * `event_listener` - Listener to SDK events. While connecting, it receives a
+    ///   [BreezEvent::ConnectProgress] for each [ConnectStage] reached.
     ///
+    #[tracing::instrument(skip_all, fields(network = ?req.config.network))]
     pub async fn connect(
         req: ConnectRequest,
         event_listener: Box<dyn EventListener>,
@@ -197,16 +642,96 @@ impl BreezServices {
         let (sdk_version, sdk_git_hash) = Self::get_sdk_version();
         info!("SDK v{sdk_version} ({sdk_git_hash})");
         let start = Instant::now();
+        let restore_only = req.is_restore_only();
         let services = BreezServicesBuilder::new(req.config)
-            .seed(req.seed)
-            .build(req.restore_only, Some(event_listener))
+            .seed(req.seed)?
+            .build(Some(restore_only), Some(event_listener))
             .await?;
         services.start().await?;
+        services.notify_connect_progress(ConnectStage::Completed);
+        if let Err(e) = services.check_unredeemed_funds().await {
+            warn!("Failed to check for unredeemed funds: {e}");
+        }
         let connect_duration = start.elapsed();
         info!("SDK connected in: {connect_duration:?}");
         Ok(services)
     }
 
+    /// Connects a throwaway wallet for docs, demos and automated UI tests. Its seed is random and
+    /// its state is kept in a temporary directory, deleted on [BreezServices::disconnect]. It is
+    /// not backed up remotely.
+    ///
+    /// Only the test networks are supported: the `config.network` can't be [Network::Bitcoin].
+    /// The `config.working_dir` is not used.
+    #[tracing::instrument(skip_all, fields(network = ?config.network))]
+    pub async fn connect_ephemeral(
+        mut config: Config,
+        event_listener: Box<dyn EventListener>,
+    ) -> BreezServicesResult<Arc<BreezServices>> {
+        ensure_sdk!(
+            config.network != Network::Bitcoin,
+            ConnectError::Generic {
+                err: "Ephemeral wallets are only supported on the test networks".into()
+            }
+        );
+        let working_dir = std::env::temp_dir().join(format!(
+            "breez-sdk-ephemeral-{}",
+            hex::encode(rand::random::<[u8; 8]>())
+        ));
+        std::fs::create_dir_all(&working_dir).map_err(|e| ConnectError::Generic {
+            err: format!("Failed to create the working dir: {e}"),
+        })?;
+        config.working_dir = working_dir.to_string_lossy().into_owned();
+
+        let mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
+        let seed = Seed::new(&mnemonic, "").as_bytes().to_vec();
+        let res = async {
+            let services = BreezServicesBuilder::new(config)
+                .seed(seed)?
+                .backup_transport(Arc::new(MemoryBackupTransport::default()))
+                .ephemeral()
+                .build(Some(false), Some(event_listener))
+                .await?;
+            services.start().await?;
+            services.notify_connect_progress(ConnectStage::Completed);
+            Ok::<_, ConnectError>(services)
+        }
+        .await;
+        if res.is_err() {
+            _ = std::fs::remove_dir_all(&working_dir);
+        }
+        res
+    }
+
+    /// Same as [BreezServices::connect], with the keys of the node held by `signer` instead of
+    /// passing the seed to the SDK. The signer also drives the signer loop of the node.
+    ///
+    /// See [ConnectWithSignerRequest::node_credentials] for when the credentials of the node are
+    /// needed.
+    #[tracing::instrument(skip_all, fields(network = ?req.config.network))]
+    pub async fn connect_with_signer(
+        req: ConnectWithSignerRequest,
+        signer: Arc<dyn Signer>,
+        event_listener: Box<dyn EventListener>,
+    ) -> BreezServicesResult<Arc<BreezServices>> {
+        let (sdk_version, sdk_git_hash) = Self::get_sdk_version();
+        info!("SDK v{sdk_version} ({sdk_git_hash})");
+        let mut builder = BreezServicesBuilder::new(req.config);
+        builder.signer(signer);
+        if let Some(node_credentials) = req.node_credentials {
+            builder.node_credentials(node_credentials);
+        }
+        let services = builder
+            .build(req.restore_only, Some(event_listener))
+            .await?;
+        services.start().await?;
+        services.notify_connect_progress(ConnectStage::Completed);
+        if let Err(e) = services.check_unredeemed_funds().await {
+            warn!("Failed to check for unredeemed funds: {e}");
+        }
+        Ok(services)
+    }
+
     fn get_sdk_version() -> (&'static str, &'static str) {
         let sdk_version = option_env!("CARGO_PKG_VERSION").unwrap_or_default();
         let sdk_git_hash = option_env!("SDK_GIT_HASH").unwrap_or_default();
@@ -253,9 +778,49 @@ impl BreezServices {
             })?;
         self.shutdown_sender.closed().await;
         *started = false;
+
+        if self.ephemeral {
+            info!(
+                "Deleting the ephemeral wallet in {}",
+                self.config.working_dir
+            );
+            if let Err(e) = std::fs::remove_dir_all(&self.config.working_dir) {
+                warn!("Failed to delete the ephemeral wallet: {e}");
+            }
+        }
         Ok(())
     }
 
+    /// Returns a stream of the [BreezEvent]s emitted from now on, as an alternative to the
+    /// [EventListener]. Any number of streams can be open at the same time.
+    ///
+    /// The stream ends when the [BreezServices] is dropped. If it's not polled, older events are
+    /// dropped once 100 events are buffered.
+    pub fn subscribe(&self) -> impl Stream<Item = BreezEvent> {
+        self.subscribe_filtered(|_| true)
+    }
+
+    /// Like [BreezServices::subscribe], only returning the events accepted by `filter`, for
+    /// example `|e| matches!(e, BreezEvent::PaymentSucceed { .. })`
+    pub fn subscribe_filtered<F>(&self, filter: F) -> impl Stream<Item = BreezEvent>
+    where
+        F: Fn(&BreezEvent) -> bool + Send + 'static,
+    {
+        let receiver = self.event_sender.subscribe();
+        futures::stream::unfold((receiver, filter), |(mut receiver, filter)| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(e) if filter(&e) => return Some((e, (receiver, filter))),
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Event stream lagged behind, skipped {skipped} events")
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
     /// Configure the node
     ///
     /// This calls [NodeAPI::configure_node] to make changes to the active node's configuration.
@@ -268,41 +833,219 @@ impl BreezServices {
 
     /// Pay a bolt11 invoice
     ///
-    /// Calling `send_payment` ensures that the payment is not already completed, if so it will result in an error.
-    /// If the invoice doesn't specify an amount, the amount is taken from the `amount_msat` arg.
+    /// Calling `send_payment` ensures that the payment is not already completed, if so it will
+    /// result in an error. If the invoice doesn't specify an amount, the amount is taken from the
+    /// `amount_msat` arg.
+    ///
+    /// Up to [Config::max_concurrent_payments] payments are sent at the same time, the others
+    /// wait for a slot, and the payments to the same payee are sent one after the other. Paying
+    /// an invoice that is already being paid fails with [SendPaymentError::AlreadyInProgress].
+    ///
+    /// If a [Config::partner_fee] is set, it is paid once the payment succeeds.
+    ///
+    /// The call can be cancelled with [BreezServices::cancel] if a
+    /// [SendPaymentRequest::cancel_token] is set.
+    #[tracing::instrument(
+        skip_all,
+        fields(use_trampoline = ?req.use_trampoline, payment_hash = tracing::field::Empty)
+    )]
     pub async fn send_payment(
         &self,
         req: SendPaymentRequest,
     ) -> Result<SendPaymentResponse, SendPaymentError> {
-        let parsed_invoice = parse_invoice(req.bolt11.as_str())?;
-        let invoice_expiration = parsed_invoice.timestamp + parsed_invoice.expiry;
-        let current_time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
-        if invoice_expiration < current_time {
-            return Err(SendPaymentError::InvoiceExpired {
-                err: format!("Invoice expired at {}", invoice_expiration),
-            });
-        }
-        let invoice_amount_msat = parsed_invoice.amount_msat.unwrap_or_default();
-        let provided_amount_msat = req.amount_msat.unwrap_or_default();
+        let cancellation = self.cancellations.register(req.cancel_token.as_deref())?;
+        self.send_payment_cancellable(req, &cancellation, &[]).await
+    }
 
-        // Valid the invoice network against the config network
-        validate_network(parsed_invoice.clone(), self.config.network)?;
+    /// [BreezServices::send_payment] as part of a call that can be cancelled.
+    ///
+    /// The `payee_destinations` identify the payee besides the invoice payee pubkey, for the
+    /// allowed destinations of the [Config::spend_policy].
+    async fn send_payment_cancellable(
+        &self,
+        req: SendPaymentRequest,
+        cancellation: &Cancellation,
+        payee_destinations: &[&str],
+    ) -> Result<SendPaymentResponse, SendPaymentError> {
+        let payment = self
+            .pay_invoice(req, cancellation, Some(payee_destinations))
+            .await?;
+        let partner_fee = self.pay_partner_fee(payment.amount_msat).await;
+        Ok(SendPaymentResponse {
+            payment,
+            partner_fee,
+        })
+    }
 
-        let amount_msat = match (provided_amount_msat, invoice_amount_msat) {
-            (0, 0) => {
-                return Err(SendPaymentError::InvalidAmount {
-                    err: "Amount must be provided when paying a zero invoice".into(),
-                })
+    /// Pays a batch of bolt11 invoices, see [BreezServices::send_payment]
+    ///
+    /// Up to [SendPaymentsRequest::max_concurrency] payments are sent at the same time. A failed
+    /// payment doesn't stop the batch: the outcome of each payment is returned, and a
+    /// [BreezEvent::BatchPaymentProgress] is emitted each time one finishes.
+    #[tracing::instrument(skip_all, fields(payments = req.payments.len()))]
+    pub async fn send_payments(&self, req: SendPaymentsRequest) -> SdkResult<SendPaymentsResponse> {
+        let max_concurrency = req
+            .max_concurrency
+            .unwrap_or(DEFAULT_SEND_PAYMENTS_CONCURRENCY);
+        ensure_sdk!(
+            max_concurrency > 0,
+            SdkError::generic("The max concurrency must be at least 1")
+        );
+
+        let total = req.payments.len() as u32;
+        let mut payments = stream::iter(req.payments.into_iter().enumerate())
+            .map(|(index, payment_req)| async move {
+                let bolt11 = payment_req.bolt11.clone();
+                let (payment, error) = match self.send_payment(payment_req).await {
+                    Ok(res) => (Some(res.payment), None),
+                    Err(e) => (None, Some(e.to_string())),
+                };
+                BatchPaymentResult {
+                    index: index as u32,
+                    bolt11,
+                    payment,
+                    error,
+                }
+            })
+            .buffer_unordered(max_concurrency as usize);
+
+        let mut results = vec![];
+        let (mut succeeded, mut failed) = (0, 0);
+        while let Some(result) = payments.next().await {
+            match result.error {
+                None => succeeded += 1,
+                Some(_) => failed += 1,
             }
-            (0, amount_msat) => amount_msat,
-            (amount_msat, 0) => amount_msat,
-            (_amount_1, _amount_2) => {
-                return Err(SendPaymentError::InvalidAmount {
-                    err: "Amount should not be provided when paying a non zero invoice".into(),
-                })
+            let event = BreezEvent::BatchPaymentProgress {
+                details: BatchPaymentProgressDetails {
+                    result: result.clone(),
+                    succeeded,
+                    failed,
+                    total,
+                },
+            };
+            if let Err(e) = self.notify_event_listeners(event).await {
+                warn!("Failed to notify the batch payment progress: {e}");
+            }
+            results.push(result);
+        }
+        results.sort_by_key(|result| result.index);
+
+        Ok(SendPaymentsResponse {
+            results,
+            succeeded,
+            failed,
+        })
+    }
+
+    /// Queues a payment in the outbox, for example when [BreezServices::send_payment] failed
+    /// for lack of connectivity.
+    ///
+    /// The queued payments are attempted in the background, and retried with an increasing delay
+    /// until they succeed, fail with an error retrying won't fix, or expire. They are also retried
+    /// right away each time [BreezServices::connect] starts a new node session. A
+    /// [BreezEvent::OutboxPaymentUpdated] is emitted when the payment is queued and after each
+    /// attempt.
+    pub async fn queue_payment(
+        &self,
+        req: QueuePaymentRequest,
+    ) -> Result<OutboxPayment, SendPaymentError> {
+        let (invoice, _) = self.validate_payable_invoice(&req.req.bolt11, req.req.amount_msat)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let expiry_secs = req.expiry_secs.unwrap_or(DEFAULT_OUTBOX_EXPIRY_SECS);
+        let expires_at =
+            (now + expiry_secs as i64).min((invoice.timestamp + invoice.expiry) as i64);
+
+        let id = self
+            .persister
+            .insert_outbox_payment(&req.req, now, expires_at)?;
+        let outbox_payment =
+            self.persister
+                .get_outbox_payment(id)?
+                .ok_or(SendPaymentError::Generic {
+                    err: "Failed to queue the payment".into(),
+                })?;
+        self.notify_event_listeners(BreezEvent::OutboxPaymentUpdated {
+            details: OutboxPaymentDetails {
+                outbox_payment: outbox_payment.clone(),
+                status: OutboxPaymentStatus::Queued,
+            },
+        })
+        .await?;
+        Ok(outbox_payment)
+    }
+
+    /// Lists the payments queued in the outbox by [BreezServices::queue_payment], the oldest first
+    pub async fn list_pending_outbox(&self) -> SdkResult<Vec<OutboxPayment>> {
+        Ok(self.persister.list_outbox_payments()?)
+    }
+
+    /// Removes a payment from the outbox, so it isn't attempted anymore. An attempt already in
+    /// flight isn't stopped.
+    pub async fn cancel_outbox_payment(&self, id: i64) -> SdkResult<()> {
+        let outbox_payment = self
+            .persister
+            .get_outbox_payment(id)?
+            .ok_or(SdkError::generic("No queued payment with this id"))?;
+        self.persister.delete_outbox_payment(id)?;
+        Ok(self
+            .notify_event_listeners(BreezEvent::OutboxPaymentUpdated {
+                details: OutboxPaymentDetails {
+                    outbox_payment,
+                    status: OutboxPaymentStatus::Cancelled,
+                },
+            })
+            .await?)
+    }
+
+    /// Pays a bolt11 invoice, see [BreezServices::send_payment]
+    ///
+    /// If the call is cancelled while waiting for the destination, the payment is not sent. If
+    /// it's cancelled once handed to the node, [SendPaymentError::Cancelled] is returned right
+    /// away and the payment stays pending until the node completes or fails it. It keeps its slot
+    /// in the send queue meanwhile, and a failure is recorded on the payment.
+    ///
+    /// The payment is checked against the [Config::spend_policy], with the `payee_destinations`
+    /// identifying the payee besides the invoice payee pubkey. The destinations are not checked
+    /// if `None`, for the partner fee payments, but the payment still counts towards the limits.
+    async fn pay_invoice(
+        &self,
+        req: SendPaymentRequest,
+        cancellation: &Cancellation,
+        payee_destinations: Option<&[&str]>,
+    ) -> Result<Payment, SendPaymentError> {
+        let limits = self.payment_limits(&req)?;
+        let (parsed_invoice, amount_msat) =
+            self.validate_payable_invoice(&req.bolt11, req.amount_msat)?;
+        if !req.skip_denylist.unwrap_or_default() {
+            if let Some(denied) = self.denied_destination(&[&parsed_invoice.payee_pubkey]) {
+                return Err(SendPaymentError::DeniedDestination {
+                    err: format!("{denied} is in the denylist"),
+                });
             }
+        }
+        let destinations = payee_destinations.map(|payee_destinations| {
+            let mut destinations = vec![parsed_invoice.payee_pubkey.as_str()];
+            destinations.extend_from_slice(payee_destinations);
+            destinations
+        });
+        let spend = self.reserve_spend(
+            amount_msat,
+            limits.max_fee_msat(amount_msat),
+            destinations.as_deref(),
+        )?;
+        let exclude = match req.route_hint_index {
+            Some(index) => excluded_route_hint_channels(&parsed_invoice, index)?,
+            None => vec![],
         };
 
+        let permit = tokio::select! {
+            permit = self.send_queue.acquire(
+                Some(&parsed_invoice.payee_pubkey),
+                Some(&parsed_invoice.payment_hash),
+            ) => permit?,
+            _ = cancellation.cancelled() => return Err(SendPaymentError::Cancelled),
+        };
         if self
             .persister
             .get_completed_payment_by_hash(&parsed_invoice.payment_hash)?
@@ -310,3066 +1053,10490 @@ impl BreezServices {
         {
             return Err(SendPaymentError::AlreadyPaid);
         }
+        let htlc_exposure = self.reserve_htlc_exposure(amount_msat)?;
 
         // If there is an lsp, the invoice route hint does not contain the
         // lsp in the hint, and trampoline payments are requested, attempt a
         // trampoline payment.
         let maybe_trampoline_id = self.get_trampoline_id(&req, &parsed_invoice)?;
 
-        self.persist_pending_payment(&parsed_invoice, amount_msat, req.label.clone())?;
+        self.ensure_lsp_peer_connected().await;
+        ensure_sdk!(!cancellation.is_cancelled(), SendPaymentError::Cancelled);
+        self.persist_pending_payment(&parsed_invoice, amount_msat, req.label.clone())
+            .await?;
+        // The pending payment is counted in flight from now on
+        drop(htlc_exposure);
 
-        // If trampoline is an option, try trampoline first.
-        let trampoline_result = if let Some(trampoline_id) = maybe_trampoline_id {
-            debug!("attempting trampoline payment");
-            match self
-                .node_api
-                .send_trampoline_payment(
-                    parsed_invoice.bolt11.clone(),
-                    amount_msat,
-                    req.label.clone(),
-                    trampoline_id,
-                )
-                .await
-            {
-                Ok(res) => Some(res),
-                Err(e) => {
-                    if e.to_string().contains("missing balance") {
-                        debug!(
-                            "trampoline payment failed due to insufficient balance: {:?}",
-                            e
-                        );
-                        return Err(SendPaymentError::InsufficientBalance {
-                            err: "Trampoline payment failed".into(),
-                        });
+        let start = Instant::now();
+        let mut dispatch = tokio::spawn(dispatch_payment(
+            self.node_api.clone(),
+            req.clone(),
+            parsed_invoice.bolt11.clone(),
+            amount_msat,
+            maybe_trampoline_id,
+            exclude,
+            limits,
+        ));
+        let payment_res = tokio::select! {
+            dispatch_res = &mut dispatch => dispatch_res.map_err(|e| SendPaymentError::Generic {
+                err: format!("The payment task failed: {e}"),
+            })??,
+            _ = cancellation.cancelled() => {
+                // The payment was handed to the node and may still complete. It keeps its slot
+                // in the send queue until it does, and a failure is persisted on the pending
+                // payment, which would otherwise stay pending.
+                let payments_writer = self.payments_writer.clone();
+                let payment_hash = parsed_invoice.payment_hash.clone();
+                tokio::spawn(async move {
+                    let _permit = permit;
+                    let payment_res = match dispatch.await {
+                        Ok(dispatch_res) => dispatch_res.and_then(|payment_res| payment_res),
+                        Err(e) => Err(SendPaymentError::Generic {
+                            err: format!("The payment task failed: {e}"),
+                        }),
+                    };
+                    if let Some(spend) = spend {
+                        spend.settle(&payment_res);
                     }
-
-                    warn!("trampoline payment failed: {:?}", e);
-                    None
-                }
-            }
-        } else {
-            debug!("not attempting trampoline payment");
-            None
-        };
-
-        // If trampoline failed or didn't happen, fall back to regular payment.
-        let payment_res = match trampoline_result {
-            Some(res) => Ok(res),
-            None => {
-                debug!("attempting normal payment");
-                self.node_api
-                    .send_payment(
-                        parsed_invoice.bolt11.clone(),
-                        req.amount_msat,
-                        req.label.clone(),
-                    )
-                    .map_err(Into::into)
-                    .await
+                    if let Err(e) = payment_res {
+                        debug!("Cancelled payment {payment_hash} failed: {e}");
+                        let hash = payment_hash.clone();
+                        if let Err(err) = payments_writer
+                            .write(move |persister| {
+                                persister.update_payment_attempted_error(&hash, Some(e.to_string()))
+                            })
+                            .await
+                        {
+                            warn!("Failed to persist the failure of payment {payment_hash}: {err}");
+                        }
+                    }
+                });
+                return Err(SendPaymentError::Cancelled);
             }
         };
+        self.metrics
+            .record_payment(start.elapsed(), payment_res.is_ok());
+        if let Some(spend) = spend {
+            spend.settle(&payment_res);
+        }
 
         debug!("payment returned {:?}", payment_res);
-        let payment = self
-            .on_payment_completed(
-                parsed_invoice.payee_pubkey.clone(),
-                Some(parsed_invoice),
-                req.label,
-                payment_res,
-            )
-            .await?;
-        Ok(SendPaymentResponse { payment })
+        self.on_payment_completed(
+            parsed_invoice.payee_pubkey.clone(),
+            Some(parsed_invoice),
+            req.label,
+            payment_res,
+        )
+        .await
     }
 
-    fn get_trampoline_id(
-        &self,
-        req: &SendPaymentRequest,
-        invoice: &LNInvoice,
-    ) -> Result<Option<Vec<u8>>, SendPaymentError> {
-        // If trampoline is turned off, return immediately
-        if !req.use_trampoline {
-            return Ok(None);
+    /// Pays the [Config::partner_fee] of a payment of `amount_msat`, if set. A failure is
+    /// reported in the returned details, as the payment the fee applies to already succeeded.
+    async fn pay_partner_fee(&self, amount_msat: u64) -> Option<PartnerFeeDetails> {
+        let partner_fee = self.config.partner_fee.as_ref()?;
+        let amount_msat = amount_msat * partner_fee.fee_bps as u64 / BASIS_POINTS;
+        if amount_msat == 0 {
+            return None;
         }
 
-        // Get the persisted LSP id. If no LSP, return early.
-        let lsp_pubkey = match self.persister.get_lsp_pubkey()? {
-            Some(lsp_pubkey) => lsp_pubkey,
-            None => return Ok(None),
+        let label = Some(PARTNER_FEE_PAYMENT_LABEL.to_string());
+        let payment_res = match &partner_fee.destination {
+            PartnerFeeDestination::NodeId { node_id } => {
+                self.pay_partner_node(node_id, amount_msat, label).await
+            }
+            PartnerFeeDestination::LightningAddress { address } => {
+                self.pay_lightning_address(address, amount_msat, label)
+                    .await
+            }
         };
-
-        // If the LSP is in the routing hint, don't use trampoline, but rather
-        // pay directly to the destination.
-        if invoice.routing_hints.iter().any(|hint| {
-            hint.hops
-                .last()
-                .map(|hop| hop.src_node_id == lsp_pubkey)
-                .unwrap_or(false)
-        }) {
-            return Ok(None);
-        }
-
-        // If ended up here, this payment will attempt trampoline.
-        Ok(Some(hex::decode(lsp_pubkey).map_err(|_| {
-            SendPaymentError::Generic {
-                err: "failed to decode lsp pubkey".to_string(),
+        Some(match payment_res {
+            Ok(payment) => PartnerFeeDetails {
+                amount_msat,
+                payment_id: Some(payment.id),
+                error: None,
+            },
+            Err(e) => {
+                warn!("Failed to pay the partner fee of {amount_msat} msat: {e}");
+                PartnerFeeDetails {
+                    amount_msat,
+                    payment_id: None,
+                    error: Some(e.to_string()),
+                }
             }
-        })?))
+        })
     }
 
-    /// Pay directly to a node id using keysend
-    pub async fn send_spontaneous_payment(
+    /// Pays the partner fee to a node id with keysend. The payment counts towards the
+    /// [Config::spend_policy] limits, but the node id is not checked against the allowed
+    /// destinations.
+    async fn pay_partner_node(
         &self,
-        req: SendSpontaneousPaymentRequest,
-    ) -> Result<SendPaymentResponse, SendPaymentError> {
+        node_id: &str,
+        amount_msat: u64,
+        label: Option<String>,
+    ) -> Result<Payment, SendPaymentError> {
+        let spend =
+            self.reserve_spend(amount_msat, self.max_routing_fee_msat(amount_msat), None)?;
         let payment_res = self
             .node_api
-            .send_spontaneous_payment(
-                req.node_id.clone(),
-                req.amount_msat,
-                req.extra_tlvs,
-                req.label.clone(),
-            )
+            .send_spontaneous_payment(node_id.to_string(), amount_msat, None, label.clone())
             .map_err(Into::into)
             .await;
-        let payment = self
-            .on_payment_completed(req.node_id, None, req.label, payment_res)
-            .await?;
-        Ok(SendPaymentResponse { payment })
+        if let Some(spend) = spend {
+            spend.settle(&payment_res);
+        }
+        self.on_payment_completed(node_id.to_string(), None, label, payment_res)
+            .await
     }
 
-    /// Second step of LNURL-pay. The first step is `parse()`, which also validates the LNURL destination
-    /// and generates the `LnUrlPayRequest` payload needed here.
-    ///
-    /// This call will validate the `amount_msat` and `comment` parameters of `req` against the parameters
-    /// of the LNURL endpoint (`req_data`). If they match the endpoint requirements, the LNURL payment
-    /// is made.
-    ///
-    /// This method will return an [anyhow::Error] when any validation check fails.
-    pub async fn lnurl_pay(&self, req: LnUrlPayRequest) -> Result<LnUrlPayResult, LnUrlPayError> {
-        match validate_lnurl_pay(
+    /// Pays the invoice requested from the lightning address, without success action
+    #[cfg(feature = "lnurl")]
+    async fn pay_lightning_address(
+        &self,
+        address: &str,
+        amount_msat: u64,
+        label: Option<String>,
+    ) -> Result<Payment, SendPaymentError> {
+        let data = match parse_with_rest_client(self.rest_client.as_ref(), address, None).await {
+            Ok(InputType::LnUrlPay { data, .. }) => data,
+            _ => {
+                return Err(SendPaymentError::Generic {
+                    err: format!("Invalid lightning address: {address}"),
+                })
+            }
+        };
+        let cb = match validate_lnurl_pay(
             self.rest_client.as_ref(),
-            req.amount_msat,
-            &req.comment,
-            &req.data,
+            amount_msat,
+            &None,
+            &data,
             self.config.network,
-            req.validate_success_action_url,
+            None,
         )
-        .await?
+        .await
+        .map_err(|e| SendPaymentError::Generic { err: e.to_string() })?
         {
+            ValidatedCallbackResponse::EndpointSuccess { data: cb } => cb,
             ValidatedCallbackResponse::EndpointError { data: e } => {
-                Ok(LnUrlPayResult::EndpointError { data: e })
+                return Err(SendPaymentError::Generic { err: e.reason })
             }
-            ValidatedCallbackResponse::EndpointSuccess { data: cb } => {
-                let pay_req = SendPaymentRequest {
-                    bolt11: cb.pr.clone(),
-                    amount_msat: None,
-                    use_trampoline: req.use_trampoline,
-                    label: req.payment_label,
-                };
-                let invoice = parse_invoice(cb.pr.as_str())?;
-
-                let payment = match self.send_payment(pay_req).await {
-                    Ok(p) => Ok(p),
-                    e @ Err(
-                        SendPaymentError::InvalidInvoice { .. }
-                        | SendPaymentError::ServiceConnectivity { .. },
-                    ) => e,
-                    Err(e) => {
-                        return Ok(LnUrlPayResult::PayError {
-                            data: LnUrlPayErrorData {
-                                payment_hash: invoice.payment_hash,
-                                reason: e.to_string(),
-                            },
-                        })
-                    }
-                }?
-                .payment;
-                let details = match &payment.details {
-                    PaymentDetails::ClosedChannel { .. } => {
-                        return Err(LnUrlPayError::Generic {
-                            err: "Payment lookup found unexpected payment type".into(),
-                        });
-                    }
-                    PaymentDetails::Ln { data } => data,
-                };
+        };
+        self.pay_invoice(
+            SendPaymentRequest {
+                bolt11: cb.pr,
+                use_trampoline: None,
+                amount_msat: None,
+                label,
+                route_hint_index: None,
+                payment_timeout_sec: None,
+                maxfee_percent: None,
+                exemptfee_msat: None,
+                cancel_token: None,
+                skip_denylist: None,
+            },
+            &Cancellation::none(),
+            None,
+        )
+        .await
+    }
 
-                let maybe_sa_processed: Option<SuccessActionProcessed> = match cb.success_action {
-                    Some(sa) => {
-                        let processed_sa = match sa {
-                            // For AES, we decrypt the contents on the fly
-                            SuccessAction::Aes { data } => {
-                                let preimage = sha256::Hash::from_str(&details.payment_preimage)?;
-                                let preimage_arr: [u8; 32] = preimage.into_inner();
-                                let result = match (data, &preimage_arr).try_into() {
-                                    Ok(data) => AesSuccessActionDataResult::Decrypted { data },
-                                    Err(e) => AesSuccessActionDataResult::ErrorStatus {
-                                        reason: e.to_string(),
-                                    },
-                                };
-                                SuccessActionProcessed::Aes { result }
-                            }
-                            SuccessAction::Message { data } => {
-                                SuccessActionProcessed::Message { data }
-                            }
-                            SuccessAction::Url { data } => SuccessActionProcessed::Url { data },
-                        };
-                        Some(processed_sa)
+    #[cfg(not(feature = "lnurl"))]
+    async fn pay_lightning_address(
+        &self,
+        _address: &str,
+        _amount_msat: u64,
+        _label: Option<String>,
+    ) -> Result<Payment, SendPaymentError> {
+        Err(SendPaymentError::Generic {
+            err: "Paying a lightning address requires the lnurl feature".into(),
+        })
+    }
+
+    /// Estimates the fee of paying a bolt11 invoice, without sending anything.
+    ///
+    /// The routes are probed the same way as for a regular [BreezServices::send_payment], so
+    /// the estimate doesn't apply to trampoline payments. If the amount has to be split over
+    /// several channels, the fee covers all the parts. Whether the remote hops have the liquidity
+    /// to forward the payment is unknown here, use [BreezServices::probe_payment] to check it.
+    pub async fn prepare_send_payment(
+        &self,
+        req: PrepareSendPaymentRequest,
+    ) -> Result<PrepareSendPaymentResponse, SendPaymentError> {
+        let (invoice, amount_msat) = self.validate_payable_invoice(&req.bolt11, req.amount_msat)?;
+        if self
+            .persister
+            .get_completed_payment_by_hash(&invoice.payment_hash)?
+            .is_some()
+        {
+            return Err(SendPaymentError::AlreadyPaid);
+        }
+
+        let last_hop = invoice.routing_hints.first().and_then(|rh| rh.hops.first());
+        let mut max_amount_per_channel = self
+            .node_api
+            .max_sendable_amount(
+                Some(hex::decode(&invoice.payee_pubkey).map_err(|e| {
+                    SendPaymentError::InvalidInvoice {
+                        err: format!("Invalid payee pubkey: {e}"),
                     }
-                    None => None,
-                };
+                })?),
+                ROUTE_PROBE_MAX_HOPS,
+                last_hop,
+            )
+            .await?;
+        let max_sendable_msat: u64 = max_amount_per_channel.iter().map(|m| m.amount_msat).sum();
+        ensure_sdk!(
+            amount_msat <= max_sendable_msat,
+            SendPaymentError::RouteNotFound {
+                err: format!("Amount too high, max amount is {max_sendable_msat} msat")
+            }
+        );
 
-                let lnurl_pay_domain = match req.data.ln_address {
-                    Some(_) => None,
-                    None => Some(req.data.domain),
-                };
-                // Store SA (if available) + LN Address in separate table, associated to payment_hash
-                self.persister.insert_payment_external_info(
-                    &details.payment_hash,
-                    PaymentExternalInfo {
-                        lnurl_pay_success_action: maybe_sa_processed.clone(),
-                        lnurl_pay_domain,
-                        lnurl_pay_comment: req.comment,
-                        lnurl_metadata: Some(req.data.metadata_str),
-                        ln_address: req.data.ln_address,
-                        lnurl_withdraw_endpoint: None,
-                        attempted_amount_msat: invoice.amount_msat,
-                        attempted_error: None,
-                    },
-                )?;
+        // Split the amount over the channels the same way payments drain the largest channels first
+        max_amount_per_channel.sort_by_key(|m| Reverse(m.amount_msat));
+        let mut fee_msat = 0;
+        let mut route_hops = 0;
+        let mut parts = 0;
+        let mut left_to_pay_msat = amount_msat;
+        for max in max_amount_per_channel {
+            if left_to_pay_msat == 0 {
+                break;
+            }
+            let to_pay_msat = std::cmp::min(left_to_pay_msat, max.amount_msat);
+            if to_pay_msat == 0 {
+                continue;
+            }
+            fee_msat += max.path.first_hop_amount(to_pay_msat) - to_pay_msat;
+            let hops = max.path.edges.len() as u32;
+            route_hops = std::cmp::max(route_hops, hops);
+            parts += 1;
+            left_to_pay_msat -= to_pay_msat;
+        }
 
-                Ok(LnUrlPayResult::EndpointSuccess {
-                    data: lnurl::pay::LnUrlPaySuccessData {
-                        payment,
-                        success_action: maybe_sa_processed,
-                    },
+        Ok(PrepareSendPaymentResponse {
+            bolt11: req.bolt11,
+            amount_msat: MilliSat(amount_msat),
+            fee_msat: MilliSat(fee_msat),
+            route_hops,
+            parts,
+        })
+    }
+
+    /// Checks the invoice can be paid and returns it parsed, together with the amount to pay
+    fn validate_payable_invoice(
+        &self,
+        bolt11: &str,
+        amount_msat: Option<MilliSat>,
+    ) -> Result<(LNInvoice, u64), SendPaymentError> {
+        let parsed_invoice = parse_invoice(bolt11)?;
+        tracing::Span::current().record("payment_hash", parsed_invoice.payment_hash.as_str());
+        if parsed_invoice.is_expired {
+            return Err(SendPaymentError::InvoiceExpired {
+                err: format!("Invoice expired at {}", parsed_invoice.expires_at),
+            });
+        }
+        let invoice_amount_msat = parsed_invoice.amount_msat.unwrap_or_default();
+        let provided_amount_msat = amount_msat.unwrap_or_default().msat();
+
+        // Valid the invoice network against the config network
+        validate_network(parsed_invoice.clone(), self.config.network)?;
+        ensure_sdk!(
+            !parsed_invoice.features.requires_unknown_features,
+            SendPaymentError::InvalidInvoice {
+                err: "Invoice requires features that are not supported by the node".into()
+            }
+        );
+
+        let amount_msat = match (provided_amount_msat, invoice_amount_msat) {
+            (0, 0) => {
+                return Err(SendPaymentError::InvalidAmount {
+                    err: "Amount must be provided when paying a zero invoice".into(),
+                })
+            }
+            (0, amount_msat) => amount_msat,
+            (amount_msat, 0) => amount_msat,
+            (_amount_1, _amount_2) => {
+                return Err(SendPaymentError::InvalidAmount {
+                    err: "Amount should not be provided when paying a non zero invoice".into(),
                 })
             }
+        };
+        Ok((parsed_invoice, amount_msat))
+    }
+
+    /// The limits of the payment, the [Config] ones unless overridden by the request
+    /// The max routing fee of a payment of `amount_msat`, with the fee limits of the [Config]
+    fn max_routing_fee_msat(&self, amount_msat: u64) -> u64 {
+        PaymentLimits {
+            timeout_sec: self.config.payment_timeout_sec,
+            maxfee_percent: self.config.maxfee_percent,
+            exemptfee_msat: self.config.exemptfee_msat,
         }
+        .max_fee_msat(amount_msat)
     }
 
-    /// Second step of LNURL-withdraw. The first step is `parse()`, which also validates the LNURL destination
-    /// and generates the `LnUrlWithdrawRequest` payload needed here.
-    ///
-    /// This call will validate the given `amount_msat` against the parameters
-    /// of the LNURL endpoint (`data`). If they match the endpoint requirements, the LNURL withdraw
-    /// request is made. A successful result here means the endpoint started the payment.
-    pub async fn lnurl_withdraw(
+    fn payment_limits(&self, req: &SendPaymentRequest) -> Result<PaymentLimits, SendPaymentError> {
+        let limits = PaymentLimits {
+            timeout_sec: req
+                .payment_timeout_sec
+                .unwrap_or(self.config.payment_timeout_sec),
+            maxfee_percent: req.maxfee_percent.unwrap_or(self.config.maxfee_percent),
+            exemptfee_msat: req
+                .exemptfee_msat
+                .map(MilliSat::msat)
+                .unwrap_or(self.config.exemptfee_msat),
+        };
+        ensure_sdk!(
+            limits.timeout_sec > 0,
+            SendPaymentError::Generic {
+                err: "The payment timeout must be at least 1 second".into()
+            }
+        );
+        ensure_sdk!(
+            limits.maxfee_percent.is_finite() && limits.maxfee_percent >= 0.0,
+            SendPaymentError::Generic {
+                err: "The max fee percent must be a positive number".into()
+            }
+        );
+        Ok(limits)
+    }
+
+    fn get_trampoline_id(
         &self,
-        req: LnUrlWithdrawRequest,
-    ) -> Result<LnUrlWithdrawResult, LnUrlWithdrawError> {
-        let invoice = self
-            .receive_payment(ReceivePaymentRequest {
-                amount_msat: req.amount_msat,
-                description: req.description.unwrap_or_default(),
-                use_description_hash: Some(false),
-                ..Default::default()
-            })
-            .await?
-            .ln_invoice;
+        req: &SendPaymentRequest,
+        invoice: &LNInvoice,
+    ) -> Result<Option<Vec<u8>>, SendPaymentError> {
+        // If trampoline is turned off, or the route hint to use is given, return immediately
+        let use_trampoline = req.use_trampoline.unwrap_or(self.config.use_trampoline);
+        if !use_trampoline || req.route_hint_index.is_some() {
+            return Ok(None);
+        }
 
-        let lnurl_w_endpoint = req.data.callback.clone();
-        let res = validate_lnurl_withdraw(self.rest_client.as_ref(), req.data, invoice).await?;
+        // Get the persisted LSP id. If no LSP, return early.
+        let lsp_pubkey = match self.persister.get_lsp_pubkey()? {
+            Some(lsp_pubkey) => lsp_pubkey,
+            None => return Ok(None),
+        };
 
-        if let LnUrlWithdrawResult::Ok { ref data } = res {
-            // If endpoint was successfully called, store the LNURL-withdraw endpoint URL as metadata linked to the invoice
-            self.persister.insert_payment_external_info(
-                &data.invoice.payment_hash,
-                PaymentExternalInfo {
-                    lnurl_pay_success_action: None,
-                    lnurl_pay_domain: None,
-                    lnurl_pay_comment: None,
-                    lnurl_metadata: None,
-                    ln_address: None,
-                    lnurl_withdraw_endpoint: Some(lnurl_w_endpoint),
-                    attempted_amount_msat: None,
-                    attempted_error: None,
-                },
-            )?;
+        // If the LSP is in the routing hint, don't use trampoline, but rather
+        // pay directly to the destination.
+        if invoice.hint_from_node(&lsp_pubkey).is_some() {
+            return Ok(None);
         }
 
-        Ok(res)
+        // If ended up here, this payment will attempt trampoline.
+        Ok(Some(hex::decode(lsp_pubkey).map_err(|_| {
+            SendPaymentError::Generic {
+                err: "failed to decode lsp pubkey".to_string(),
+            }
+        })?))
     }
 
-    /// Third and last step of LNURL-auth. The first step is `parse()`, which also validates the LNURL destination
-    /// and generates the `LnUrlAuthRequestData` payload needed here. The second step is user approval of auth action.
+    /// Checks whether a bolt11 invoice can be paid by the node, without probing the routes: it
+    /// has to be unexpired, on the network of the node, not paid yet, its amount has to be
+    /// within what the node can send and, if its route hints tell, what the payee can receive.
     ///
-    /// This call will sign `k1` of the LNURL endpoint (`req_data`) on `secp256k1` using `linkingPrivKey` and DER-encodes the signature.
-    /// If they match the endpoint requirements, the LNURL auth request is made. A successful result here means the client signature is verified.
-    pub async fn lnurl_auth(
+    /// Fails only if the invoice can't be parsed. The route hint through the LSP of this node is
+    /// surfaced, if the invoice has one.
+    pub async fn validate_payability(
         &self,
-        req_data: LnUrlAuthRequestData,
-    ) -> Result<LnUrlCallbackStatus, LnUrlAuthError> {
-        Ok(perform_lnurl_auth(
-            self.rest_client.as_ref(),
-            &req_data,
-            &SdkLnurlAuthSigner::new(self.node_api.clone()),
-        )
-        .await?)
+        req: ValidatePayabilityRequest,
+    ) -> SdkResult<InvoicePayability> {
+        let invoice = parse_invoice(&req.bolt11)?;
+        let node_state = self.node_info()?;
+        let lsp_route_hint = match self.persister.get_lsp_pubkey()? {
+            Some(lsp_pubkey) => invoice.hint_from_node(&lsp_pubkey).cloned(),
+            None => None,
+        };
+        // The max HTLC amounts of the last hops are a hint of the inbound liquidity of the payee
+        let payee_inbound_liquidity_msat = match invoice.routing_hints.is_empty() {
+            true => None,
+            false => invoice
+                .routing_hints
+                .iter()
+                .map(|hint| hint.hops.last().and_then(|hop| hop.htlc_maximum_msat))
+                .sum::<Option<u64>>(),
+        };
+        let amount_msat = invoice
+            .amount_msat
+            .filter(|amount_msat| *amount_msat > 0)
+            .or(req.amount_msat.map(MilliSat::msat));
+
+        let error = match self.validate_payable_invoice(&req.bolt11, req.amount_msat) {
+            Err(e) => Some(e.to_string()),
+            Ok((_, amount_msat)) => {
+                if invoice.payee_pubkey == node_state.id {
+                    Some("The invoice was created by this node".to_string())
+                } else if self
+                    .persister
+                    .get_completed_payment_by_hash(&invoice.payment_hash)?
+                    .is_some()
+                {
+                    Some(SendPaymentError::AlreadyPaid.to_string())
+                } else if amount_msat > node_state.max_payable_msat {
+                    Some(format!(
+                        "Amount too high, max sendable is {} msat",
+                        node_state.max_payable_msat
+                    ))
+                } else {
+                    payee_inbound_liquidity_msat
+                        .filter(|max| amount_msat > *max)
+                        .map(|max| format!("Amount too high, the payee can receive {max} msat"))
+                }
+            }
+        };
+
+        Ok(InvoicePayability {
+            invoice,
+            amount_msat,
+            is_payable: error.is_none(),
+            error,
+            max_payable_msat: node_state.max_payable_msat,
+            payee_inbound_liquidity_msat,
+            lsp_route_hint,
+        })
     }
 
-    /// Creates an bolt11 payment request.
-    /// This also works when the node doesn't have any channels and need inbound liquidity.
-    /// In such case when the invoice is paid a new zero-conf channel will be open by the LSP,
-    /// providing inbound liquidity and the payment will be routed via this new channel.
-    pub async fn receive_payment(
+    /// Pay directly to a node id using keysend
+    ///
+    /// Fails with [SendPaymentError::InsufficientBalance] when the amount is higher than what can
+    /// be sent to the node, taking the channel reserves and the pending HTLCs into account.
+    #[tracing::instrument(skip_all, fields(node_id = %req.node_id))]
+    pub async fn send_spontaneous_payment(
         &self,
-        req: ReceivePaymentRequest,
-    ) -> Result<ReceivePaymentResponse, ReceivePaymentError> {
-        self.payment_receiver.receive_payment(req).await
+        req: SendSpontaneousPaymentRequest,
+    ) -> Result<SendPaymentResponse, SendPaymentError> {
+        let amount_msat = req.amount_msat.msat();
+        let permit = self.send_queue.acquire(Some(&req.node_id), None).await?;
+        let spend = self.reserve_spend(
+            amount_msat,
+            self.max_routing_fee_msat(amount_msat),
+            Some(&[req.node_id.as_str()][..]),
+        )?;
+        // Keysend payments are only persisted once completed, so they're tracked in flight until
+        // the node returns
+        let _htlc_exposure = self.reserve_htlc_exposure(amount_msat)?;
+        if let Some(max_sendable_msat) = self.max_spontaneous_sendable_msat(&req.node_id).await? {
+            ensure_sdk!(
+                amount_msat <= max_sendable_msat,
+                SendPaymentError::InsufficientBalance {
+                    err: format!("Amount too high, max sendable is {max_sendable_msat} msat"),
+                    max_sendable_msat: Some(max_sendable_msat),
+                }
+            );
+        }
+        self.ensure_lsp_peer_connected().await;
+        let start = Instant::now();
+        let payment_res = self
+            .node_api
+            .send_spontaneous_payment(
+                req.node_id.clone(),
+                amount_msat,
+                req.extra_tlvs,
+                req.label.clone(),
+            )
+            .map_err(Into::into)
+            .await;
+        self.metrics
+            .record_payment(start.elapsed(), payment_res.is_ok());
+        if let Some(spend) = spend {
+            spend.settle(&payment_res);
+        }
+        let payment = self
+            .on_payment_completed(req.node_id, None, req.label, payment_res)
+            .await?;
+        // The partner may be the destination of the payment
+        drop(permit);
+        let partner_fee = self.pay_partner_fee(amount_msat).await;
+        Ok(SendPaymentResponse {
+            payment,
+            partner_fee,
+        })
     }
 
-    /// Report an issue.
+    /// Cancels the call started with the `cancel_token`, see [SendPaymentRequest::cancel_token],
+    /// [LnUrlPayRequest::cancel_token] and [PayOnchainRequest::cancel_token]. Returns whether
+    /// a call in progress was cancelled.
     ///
-    /// Calling `report_issue` with a [ReportIssueRequest] enum param sends an issue report using the Support API.
-    /// - [ReportIssueRequest::PaymentFailure] sends a payment failure report to the Support API
-    ///   using the provided `payment_hash` to lookup the failed payment and the current [NodeState].
-    pub async fn report_issue(&self, req: ReportIssueRequest) -> SdkResult<()> {
-        match self.persister.get_node_state()? {
-            Some(node_state) => match req {
-                ReportIssueRequest::PaymentFailure { data } => {
-                    let payment = self
-                        .persister
-                        .get_payment_by_hash(&data.payment_hash)?
-                        .ok_or(SdkError::Generic {
-                            err: "Payment not found".into(),
-                        })?;
-                    let lsp_id = self.persister.get_lsp_id()?;
+    /// Calls are only aborted where it's safe. A payment still waiting for its destination is
+    /// not sent, and a reverse swap whose HODL invoice is not paid yet is cancelled. A payment
+    /// already handed to the node can't be recalled: the call returns right away but the
+    /// payment stays pending until the node completes or fails it.
+    pub async fn cancel(&self, cancel_token: String) -> SdkResult<bool> {
+        Ok(self.cancellations.cancel(&cancel_token))
+    }
 
-                    self.support_api
-                        .report_payment_failure(node_state, payment, lsp_id, data.comment)
-                        .await
+    /// Checks whether a payment to a BOLT11 invoice or a node id is likely to succeed, and at
+    /// what fee, without settling it: an HTLC with a random payment hash is sent along the route,
+    /// and reaches the destination if it fails it as an unknown payment.
+    ///
+    /// The amount is required when probing a node id or an invoice without an amount. The probe
+    /// is recorded, see [BreezServices::list_payment_probes].
+    pub async fn probe_payment(&self, req: ProbePaymentRequest) -> SdkResult<PaymentProbe> {
+        let (node_id, bolt11, amount_msat, route_hint, final_cltv_delta) =
+            match parse_invoice(&req.destination) {
+                Ok(invoice) => {
+                    validate_network(invoice.clone(), self.config.network)?;
+                    let route_hint = invoice
+                        .routing_hints
+                        .first()
+                        .and_then(|rh| rh.hops.first())
+                        .cloned();
+                    (
+                        invoice.payee_pubkey,
+                        Some(invoice.bolt11),
+                        invoice.amount_msat.or(req.amount_msat.map(MilliSat::msat)),
+                        route_hint,
+                        invoice.min_final_cltv_expiry_delta,
+                    )
                 }
-            },
-            None => Err(SdkError::Generic {
-                err: "Node state not found".into(),
-            }),
+                Err(_) => {
+                    let is_node_id = hex::decode(&req.destination)
+                        .is_ok_and(|id| PublicKey::from_slice(&id).is_ok());
+                    ensure_sdk!(
+                        is_node_id,
+                        SdkError::generic("The destination is neither an invoice nor a node id")
+                    );
+                    (
+                        req.destination,
+                        None,
+                        req.amount_msat.map(MilliSat::msat),
+                        None,
+                        PROBE_FINAL_CLTV_DELTA,
+                    )
+                }
+            };
+        let amount_msat = match amount_msat {
+            Some(amount_msat) if amount_msat > 0 => amount_msat,
+            _ => return Err(SdkError::generic("An amount of more than 0 must be set")),
+        };
+
+        let mut probe = PaymentProbe {
+            id: 0,
+            node_id: node_id.clone(),
+            bolt11,
+            amount_msat,
+            route_found: false,
+            reachable: false,
+            estimated_fee_msat: None,
+            hops: None,
+            error: None,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+        };
+        match self
+            .node_api
+            .probe_payment(
+                hex::decode(&node_id).map_err(|_| SdkError::generic("Invalid node id"))?,
+                amount_msat,
+                route_hint,
+                final_cltv_delta,
+                self.config.payment_timeout_sec,
+            )
+            .await
+        {
+            Ok(result) => {
+                probe.route_found = true;
+                probe.reachable = result.reachable;
+                probe.estimated_fee_msat = Some(result.fee_msat);
+                probe.hops = Some(result.hops);
+                probe.error = result.failure;
+            }
+            Err(NodeError::RouteNotFound(err)) => probe.error = Some(err),
+            Err(e) => return Err(e.into()),
         }
+        probe.id = self.persister.insert_payment_probe(&probe)?;
+        Ok(probe)
     }
 
-    /// Retrieve the decrypted credentials from the node.
-    pub async fn node_credentials(&self) -> SdkResult<Option<NodeCredentials>> {
-        Ok(self.node_api.node_credentials().await?)
+    /// Lists the probes sent with [BreezServices::probe_payment], the most recent first
+    pub async fn list_payment_probes(&self) -> SdkResult<Vec<PaymentProbe>> {
+        Ok(self.persister.list_payment_probes()?)
     }
 
-    /// Retrieve the node state from the persistent storage.
-    ///
-    /// Fail if it could not be retrieved or if `None` was found.
-    pub fn node_info(&self) -> SdkResult<NodeState> {
-        self.persister.get_node_state()?.ok_or(SdkError::Generic {
-            err: "Node info not found".into(),
-        })
-    }
-
-    /// Sign given message with the private key of the node id. Returns a zbase
-    /// encoded signature.
-    pub async fn sign_message(&self, req: SignMessageRequest) -> SdkResult<SignMessageResponse> {
-        let signature = self.node_api.sign_message(&req.message).await?;
-        Ok(SignMessageResponse { signature })
+    /// The max amount that can be sent to the node in a single part, as keysend payments are not
+    /// split. Returns `None` when no route to the node is known, in which case the node reports
+    /// the failure itself.
+    async fn max_spontaneous_sendable_msat(
+        &self,
+        node_id: &str,
+    ) -> Result<Option<u64>, SendPaymentError> {
+        let node_id = hex::decode(node_id).map_err(|e| SendPaymentError::Generic {
+            err: format!("Invalid node id: {e}"),
+        })?;
+        let max_amount_per_channel = match self
+            .node_api
+            .max_sendable_amount(Some(node_id), ROUTE_PROBE_MAX_HOPS, None)
+            .await
+        {
+            Ok(max_amount_per_channel) => max_amount_per_channel,
+            Err(e) => {
+                warn!("Failed to compute the max sendable amount: {e}");
+                return Ok(None);
+            }
+        };
+        Ok(max_amount_per_channel.iter().map(|m| m.amount_msat).max())
     }
 
-    /// Check whether given message was signed by the private key or the given
-    /// pubkey and the signature (zbase encoded) is valid.
-    pub async fn check_message(&self, req: CheckMessageRequest) -> SdkResult<CheckMessageResponse> {
-        let is_valid = self
+    /// Pay a BOLT12 offer
+    ///
+    /// The node requests an invoice from the offer issuer over the Lightning network and pays it.
+    /// The `amount_msat` of the [PayOfferRequest] is required when the offer doesn't specify an
+    /// amount in bitcoin.
+    #[tracing::instrument(skip_all, fields(offer = %req.offer))]
+    pub async fn pay_offer(
+        &self,
+        req: PayOfferRequest,
+    ) -> Result<SendPaymentResponse, SendPaymentError> {
+        let offer =
+            parse_bolt12_offer(&req.offer).map_err(|e| SendPaymentError::InvalidInvoice {
+                err: format!("Invalid offer: {e:?}"),
+            })?;
+        let amount_msat = req.amount_msat.map(MilliSat::msat);
+        let offer_amount_msat = self.validate_offer(&offer, amount_msat)?;
+
+        let destination = offer.signing_pubkey.as_deref().unwrap_or(&req.offer);
+        let permit = self.send_queue.acquire(Some(destination), None).await?;
+        let spend = self.reserve_spend(
+            offer_amount_msat,
+            self.max_routing_fee_msat(offer_amount_msat),
+            Some(&[destination][..]),
+        )?;
+        let payment_res = self
             .node_api
-            .check_message(&req.message, &req.pubkey, &req.signature)
+            .pay_offer(req.offer, amount_msat, req.payer_note, req.label.clone())
+            .map_err(Into::into)
+            .await;
+        if let Some(spend) = spend {
+            spend.settle(&payment_res);
+        }
+        let payment = self
+            .on_payment_completed(
+                offer.signing_pubkey.unwrap_or_default(),
+                None,
+                req.label,
+                payment_res,
+            )
             .await?;
-        Ok(CheckMessageResponse { is_valid })
-    }
-
-    /// Retrieve the node up to date BackupStatus
-    pub fn backup_status(&self) -> SdkResult<BackupStatus> {
-        let backup_time = self.persister.get_last_backup_time()?;
-        let sync_request = self.persister.get_last_sync_request()?;
-        Ok(BackupStatus {
-            last_backup_time: backup_time,
-            backed_up: sync_request.is_none(),
+        drop(permit);
+        let partner_fee = self.pay_partner_fee(payment.amount_msat).await;
+        Ok(SendPaymentResponse {
+            payment,
+            partner_fee,
         })
     }
 
-    /// Force running backup
-    pub async fn backup(&self) -> SdkResult<()> {
-        let (on_complete, mut on_complete_receiver) = mpsc::channel::<Result<()>>(1);
-        let req = BackupRequest::with(on_complete, true);
-        self.backup_watcher.request_backup(req).await?;
-
-        match on_complete_receiver.recv().await {
-            Some(res) => res.map_err(|e| SdkError::Generic {
-                err: format!("Backup failed: {e}"),
-            }),
-            None => Err(SdkError::Generic {
-                err: "Backup process failed to complete".into(),
+    /// Checks that the offer can be paid on the configured network, with `amount_msat`. Returns
+    /// the amount paid, which is the min amount of the offer if `amount_msat` is not set.
+    fn validate_offer(
+        &self,
+        offer: &LNOffer,
+        amount_msat: Option<u64>,
+    ) -> Result<u64, SendPaymentError> {
+        validate_offer_network(offer, self.config.network)?;
+        if let Some(absolute_expiry) = offer.absolute_expiry {
+            let current_time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            if absolute_expiry < current_time {
+                return Err(SendPaymentError::InvoiceExpired {
+                    err: format!("Offer expired at {absolute_expiry}"),
+                });
+            }
+        }
+        match (amount_msat, &offer.min_amount) {
+            (Some(amount_msat), Some(Amount::Bitcoin { amount_msat: min })) => {
+                ensure_sdk!(
+                    amount_msat >= *min,
+                    SendPaymentError::InvalidAmount {
+                        err: format!("Amount must be at least {min} msat")
+                    }
+                );
+                Ok(amount_msat)
+            }
+            (None, Some(Amount::Bitcoin { amount_msat })) | (Some(amount_msat), _) => {
+                Ok(*amount_msat)
+            }
+            (None, _) => Err(SendPaymentError::InvalidAmount {
+                err: "Amount must be provided when paying this offer".into(),
             }),
         }
     }
 
-    /// List payments matching the given filters, as retrieved from persistent storage
-    pub async fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
-        Ok(self.persister.list_payments(req)?)
-    }
+    /// Second step of LNURL-pay. The first step is `parse()`, which also validates the LNURL destination
+    /// and generates the `LnUrlPayRequest` payload needed here.
+    ///
+    /// This call will validate the `amount_msat` and `comment` parameters of `req` against the parameters
+    /// of the LNURL endpoint (`req_data`). If they match the endpoint requirements, the LNURL payment
+    /// is made.
+    ///
+    /// If `req.pending_timeout_sec` is set and the payment is not complete after that many seconds,
+    /// [LnUrlPayResult::Pending] is returned. The payment continues in the background and its
+    /// result is emitted with [BreezEvent::LnUrlPayFinished].
+    ///
+    /// The success action is stored with the payment, an AES one decrypted with the preimage, and
+    /// returned by [BreezServices::list_payments]. If the payment only completes after this call
+    /// returned an error, or after the app restarted, it is stored by the next sync.
+    ///
+    /// The call can be cancelled with [BreezServices::cancel] if a
+    /// [LnUrlPayRequest::cancel_token] is set, including while the payment continues in the
+    /// background.
+    ///
+    /// This method will return an [anyhow::Error] when any validation check fails.
+    #[tracing::instrument(
+        skip_all,
+        fields(domain = %req.data.domain, payment_hash = tracing::field::Empty)
+    )]
+    #[cfg(feature = "lnurl")]
+    pub async fn lnurl_pay(
+        self: &Arc<BreezServices>,
+        req: LnUrlPayRequest,
+    ) -> Result<LnUrlPayResult, LnUrlPayError> {
+        if !req.skip_denylist.unwrap_or_default() {
+            let mut destinations = vec![req.data.domain.as_str()];
+            destinations.extend(req.data.ln_address.as_deref());
+            if let Some(denied) = self.denied_destination(&destinations) {
+                return Err(LnUrlPayError::DeniedDestination {
+                    err: format!("{denied} is in the denylist"),
+                });
+            }
+        }
+        // Fails before calling the LNURL endpoint, the spend is counted once the invoice is paid
+        if let Some(policy) = &self.config.spend_policy {
+            let mut destinations = vec![req.data.domain.as_str()];
+            destinations.extend(req.data.ln_address.as_deref());
+            policy.check(req.amount_msat, &destinations)?;
+        }
 
-    /// Fetch a specific payment by its hash.
-    pub async fn payment_by_hash(&self, hash: String) -> SdkResult<Option<Payment>> {
-        Ok(self.persister.get_payment_by_hash(&hash)?)
-    }
+        let cancellation = self.cancellations.register(req.cancel_token.as_deref())?;
+        let validated = tokio::select! {
+            validated = validate_lnurl_pay(
+                self.rest_client.as_ref(),
+                req.amount_msat,
+                &req.comment,
+                &req.data,
+                self.config.network,
+                req.validate_success_action_url,
+            ) => validated?,
+            _ = cancellation.cancelled() => return Err(LnUrlPayError::Cancelled),
+        };
+        match validated {
+            ValidatedCallbackResponse::EndpointError { data: e } => {
+                Ok(LnUrlPayResult::EndpointError { data: e })
+            }
+            ValidatedCallbackResponse::EndpointSuccess { data: cb } => {
+                let timeout_sec = match req.pending_timeout_sec {
+                    Some(timeout_sec) => timeout_sec,
+                    None => {
+                        return self
+                            .pay_lnurl_callback_invoice(req, cb, &cancellation)
+                            .await
+                    }
+                };
+                let payment_hash = parse_invoice(cb.pr.as_str())?.payment_hash;
+                tracing::Span::current().record("payment_hash", payment_hash.as_str());
+
+                // The payment outlives the call, but it's not restarted like a background task
+                // if it fails, as the failure is its result
+                let (result_tx, mut result_rx) = oneshot::channel();
+                let cloned = self.clone();
+                let cloned_payment_hash = payment_hash.clone();
+                tokio::spawn(async move {
+                    let result = cloned
+                        .pay_lnurl_callback_invoice(req, cb, &cancellation)
+                        .await;
+                    // If the caller stopped waiting, the result is delivered as an event
+                    if let Err(result) = result_tx.send(result) {
+                        let result = result.unwrap_or_else(|e| LnUrlPayResult::PayError {
+                            data: LnUrlPayErrorData {
+                                payment_hash: cloned_payment_hash.clone(),
+                                reason: e.to_string(),
+                            },
+                        });
+                        let event = BreezEvent::LnUrlPayFinished {
+                            details: LnUrlPayFinishedDetails {
+                                payment_hash: cloned_payment_hash,
+                                result,
+                            },
+                        };
+                        if let Err(e) = cloned.notify_event_listeners(event).await {
+                            error!("Failed to notify the LNURL-pay result: {e}");
+                        }
+                    }
+                });
 
-    /// Set the external metadata of a payment as a valid JSON string
-    pub async fn set_payment_metadata(&self, hash: String, metadata: String) -> SdkResult<()> {
-        Ok(self
-            .persister
-            .set_payment_external_metadata(hash, metadata)?)
+                match tokio::time::timeout(Duration::from_secs(timeout_sec.into()), &mut result_rx)
+                    .await
+                {
+                    Ok(result) => result.map_err(|e| LnUrlPayError::Generic {
+                        err: format!("LNURL-pay task failed: {e}"),
+                    })?,
+                    Err(_) => {
+                        // Closing makes the task emit the event, unless it finished in the meantime
+                        result_rx.close();
+                        match result_rx.try_recv() {
+                            Ok(result) => result,
+                            Err(_) => Ok(LnUrlPayResult::Pending {
+                                data: LnUrlPayPendingData { payment_hash },
+                            }),
+                        }
+                    }
+                }
+            }
+        }
     }
 
-    /// Redeem on-chain funds from closed channels to the specified on-chain address, with the given feerate
-    pub async fn redeem_onchain_funds(
+    /// Pays the invoice returned by the LNURL-pay callback, then processes and stores the
+    /// success action.
+    ///
+    /// The success action and the LNURL details are kept until the payment completes. If the
+    /// app stops before that, they are stored by the next sync, see
+    /// [BreezServices::process_pending_lnurl_pays].
+    #[cfg(feature = "lnurl")]
+    async fn pay_lnurl_callback_invoice(
         &self,
-        req: RedeemOnchainFundsRequest,
-    ) -> RedeemOnchainResult<RedeemOnchainFundsResponse> {
-        let txid = self
-            .node_api
-            .redeem_onchain_funds(req.to_address, req.sat_per_vbyte)
-            .await?;
-        self.sync().await?;
-        Ok(RedeemOnchainFundsResponse { txid })
+        req: LnUrlPayRequest,
+        cb: CallbackResponse,
+        cancellation: &Cancellation,
+    ) -> Result<LnUrlPayResult, LnUrlPayError> {
+        let lnurl_destinations: Vec<String> = std::iter::once(req.data.domain.clone())
+            .chain(req.data.ln_address.clone())
+            .collect();
+        let payee_destinations: Vec<&str> = lnurl_destinations.iter().map(String::as_str).collect();
+        let pay_req = SendPaymentRequest {
+            bolt11: cb.pr.clone(),
+            amount_msat: None,
+            // Not requesting trampoline falls back to the configured default
+            use_trampoline: req.use_trampoline.then_some(true),
+            label: req.payment_label,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: None,
+            skip_denylist: req.skip_denylist,
+        };
+        let invoice = parse_invoice(cb.pr.as_str())?;
+
+        let lnurl_pay_domain = match req.data.ln_address {
+            Some(_) => None,
+            None => Some(req.data.domain),
+        };
+        let external_info = PaymentExternalInfo {
+            lnurl_pay_success_action: None,
+            lnurl_pay_domain,
+            lnurl_pay_comment: req.comment,
+            lnurl_metadata: Some(req.data.metadata_str),
+            ln_address: req.data.ln_address,
+            lnurl_withdraw_endpoint: None,
+            attempted_amount_msat: invoice.amount_msat,
+            attempted_error: None,
+        };
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?
+            .as_secs() as i64;
+        self.persister.insert_pending_lnurl_pay(&PendingLnurlPay {
+            payment_hash: invoice.payment_hash.clone(),
+            success_action: cb.success_action.clone(),
+            external_info: external_info.clone(),
+            created_at,
+        })?;
+
+        let SendPaymentResponse {
+            payment,
+            partner_fee,
+        } = match self
+            .send_payment_cancellable(pay_req, cancellation, &payee_destinations)
+            .await
+        {
+            Ok(p) => Ok(p),
+            // The payment may still complete, it's kept pending for the next sync
+            Err(SendPaymentError::Cancelled) => return Err(LnUrlPayError::Cancelled),
+            Err(
+                e @ (SendPaymentError::DeniedDestination { .. }
+                | SendPaymentError::SpendPolicyViolation { .. }
+                | SendPaymentError::HtlcExposureExceeded { .. }
+                | SendPaymentError::InvalidInvoice { .. }
+                | SendPaymentError::ServiceConnectivity { .. }),
+            ) => {
+                // The payment wasn't attempted, there is no outcome to wait for
+                self.persister
+                    .delete_pending_lnurl_pay(&invoice.payment_hash)?;
+                Err(e)
+            }
+            Err(e) => {
+                // The payment may still complete, it's kept pending for the next sync
+                return Ok(LnUrlPayResult::PayError {
+                    data: LnUrlPayErrorData {
+                        payment_hash: invoice.payment_hash,
+                        reason: e.to_string(),
+                    },
+                });
+            }
+        }?;
+        let details = match &payment.details {
+            PaymentDetails::ClosedChannel { .. } => {
+                return Err(LnUrlPayError::Generic {
+                    err: "Payment lookup found unexpected payment type".into(),
+                });
+            }
+            PaymentDetails::Ln { data } => data,
+        };
+
+        let maybe_sa_processed: Option<SuccessActionProcessed> = cb
+            .success_action
+            .map(|sa| process_success_action(sa, &details.payment_preimage))
+            .transpose()?;
+
+        // Store SA (if available) + LN Address in separate table, associated to payment_hash
+        self.persister.insert_payment_external_info(
+            &details.payment_hash,
+            PaymentExternalInfo {
+                lnurl_pay_success_action: maybe_sa_processed.clone(),
+                ..external_info
+            },
+        )?;
+        self.persister
+            .delete_pending_lnurl_pay(&details.payment_hash)?;
+
+        Ok(LnUrlPayResult::EndpointSuccess {
+            data: lnurl::pay::LnUrlPaySuccessData {
+                payment,
+                success_action: maybe_sa_processed,
+                partner_fee,
+            },
+        })
     }
 
-    pub async fn prepare_redeem_onchain_funds(
+    /// Second step of LNURL-withdraw. The first step is `parse()`, which also validates the LNURL destination
+    /// and generates the `LnUrlWithdrawRequest` payload needed here.
+    ///
+    /// This call will validate the given `amount_msat` against the parameters
+    /// of the LNURL endpoint (`data`). If they match the endpoint requirements, the LNURL withdraw
+    /// request is made. A successful result here means the endpoint started the payment.
+    #[tracing::instrument(
+        skip_all,
+        fields(callback = %req.data.callback, payment_hash = tracing::field::Empty)
+    )]
+    #[cfg(feature = "lnurl")]
+    pub async fn lnurl_withdraw(
         &self,
-        req: PrepareRedeemOnchainFundsRequest,
-    ) -> RedeemOnchainResult<PrepareRedeemOnchainFundsResponse> {
-        let response = self.node_api.prepare_redeem_onchain_funds(req).await?;
-        Ok(response)
-    }
+        req: LnUrlWithdrawRequest,
+    ) -> Result<LnUrlWithdrawResult, LnUrlWithdrawError> {
+        let invoice = self
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: MilliSat(req.amount_msat),
+                description: req.description.unwrap_or_default(),
+                use_description_hash: Some(false),
+                ..Default::default()
+            })
+            .await?
+            .ln_invoice;
+        tracing::Span::current().record("payment_hash", invoice.payment_hash.as_str());
 
-    /// Fetch live rates of fiat currencies, sorted by name
-    pub async fn fetch_fiat_rates(&self) -> SdkResult<Vec<Rate>> {
-        self.fiat_api.fetch_fiat_rates().await.map_err(Into::into)
-    }
+        let lnurl_w_endpoint = req.data.callback.clone();
+        let res = validate_lnurl_withdraw(self.rest_client.as_ref(), req.data, invoice).await?;
 
-    /// List all supported fiat currencies for which there is a known exchange rate.
-    /// List is sorted by the canonical name of the currency
-    pub async fn list_fiat_currencies(&self) -> SdkResult<Vec<FiatCurrency>> {
-        self.fiat_api
-            .list_fiat_currencies()
-            .await
-            .map_err(Into::into)
-    }
+        if let LnUrlWithdrawResult::Ok { ref data } = res {
+            // If endpoint was successfully called, store the LNURL-withdraw endpoint URL as metadata linked to the invoice
+            self.persister.insert_payment_external_info(
+                &data.invoice.payment_hash,
+                PaymentExternalInfo {
+                    lnurl_pay_success_action: None,
+                    lnurl_pay_domain: None,
+                    lnurl_pay_comment: None,
+                    lnurl_metadata: None,
+                    ln_address: None,
+                    lnurl_withdraw_endpoint: Some(lnurl_w_endpoint),
+                    attempted_amount_msat: None,
+                    attempted_error: None,
+                },
+            )?;
+        }
 
-    /// List available LSPs that can be selected by the user
-    pub async fn list_lsps(&self) -> SdkResult<Vec<LspInformation>> {
-        self.lsp_api.list_lsps(self.node_info()?.id).await
+        Ok(res)
     }
 
-    /// Select the LSP to be used and provide inbound liquidity
-    pub async fn connect_lsp(&self, lsp_id: String) -> SdkResult<()> {
-        let lsp_pubkey = match self.list_lsps().await?.iter().find(|lsp| lsp.id == lsp_id) {
-            Some(lsp) => lsp.pubkey.clone(),
-            None => {
-                return Err(SdkError::Generic {
-                    err: format!("Unknown LSP: {lsp_id}"),
-                })
+    /// Third and last step of LNURL-auth. The first step is `parse()`, which also validates the LNURL destination
+    /// and generates the `LnUrlAuthRequestData` payload needed here. The second step is user approval of auth action.
+    ///
+    /// This call will sign `k1` of the LNURL endpoint (`req_data`) on `secp256k1` using `linkingPrivKey` and DER-encodes the signature.
+    /// If they match the endpoint requirements, the LNURL auth request is made. A successful result here means the client signature is verified.
+    ///
+    /// Before signing, the request is checked for signs of a phishing or replay attempt, which
+    /// wallets can warn the user about:
+    /// * [LnUrlAuthError::OriginMismatch] if the callback URL isn't on the `domain` shown to the user
+    /// * [LnUrlAuthError::K1Reused] if the `k1` challenge was already signed
+    /// * [LnUrlAuthError::RateLimited] if too many challenges of the domain were signed recently
+    ///
+    /// When the service accepts the signature, the domain is added to the history listed by
+    /// [BreezServices::list_lnurl_auth_domains] and [BreezEvent::LnUrlAuthCompleted] is emitted.
+    #[cfg(feature = "lnurl")]
+    pub async fn lnurl_auth(
+        &self,
+        req_data: LnUrlAuthRequestData,
+    ) -> Result<LnUrlCallbackStatus, LnUrlAuthError> {
+        let domain = verify_lnurl_auth_origin(&req_data)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(SdkError::from)?
+            .as_secs() as i64;
+        let recent_attempts = self
+            .persister
+            .count_lnurl_auth_attempts(&domain, now - LNURL_AUTH_RATE_LIMIT_WINDOW_SECS)?;
+        if recent_attempts >= LNURL_AUTH_MAX_ATTEMPTS_PER_WINDOW {
+            return Err(LnUrlAuthError::RateLimited {
+                err: format!("Too many auth attempts to {domain}, try again later"),
+            });
+        }
+        // The challenge is recorded before signing, so it can't be replayed even if the
+        // callback fails after the signature was sent
+        if !self
+            .persister
+            .insert_lnurl_auth_attempt(&req_data.k1.to_lowercase(), &domain, now)?
+        {
+            return Err(LnUrlAuthError::K1Reused {
+                err: format!("The challenge of {domain} was already signed"),
+            });
+        }
+
+        let signer = SdkLnurlAuthSigner::new(self.node_api.clone());
+        let status = perform_lnurl_auth(self.rest_client.as_ref(), &req_data, &signer).await?;
+        if matches!(status, LnUrlCallbackStatus::Ok) {
+            // The service already accepted the signature, so failing to record it isn't an error
+            if let Err(e) = self.on_lnurl_auth_completed(&signer, &domain, now).await {
+                warn!("Failed to record the LNURL-auth to {domain}: {e}");
             }
-        };
+        }
+        Ok(status)
+    }
 
-        self.persister.set_lsp(lsp_id, Some(lsp_pubkey))?;
-        self.sync().await?;
-        if let Some(webhook_url) = self.persister.get_webhook_url()? {
-            self.register_payment_notifications(webhook_url).await?
+    #[cfg(feature = "lnurl")]
+    async fn on_lnurl_auth_completed(
+        &self,
+        signer: &SdkLnurlAuthSigner,
+        domain: &str,
+        authenticated_at: i64,
+    ) -> SdkResult<()> {
+        let linking_key = signer.linking_key(domain).await?;
+        self.persister
+            .record_lnurl_auth_domain(domain, &linking_key, authenticated_at)?;
+        let recorded = self
+            .persister
+            .list_lnurl_auth_domains()?
+            .into_iter()
+            .find(|d| d.domain == domain);
+        if let Some(details) = recorded {
+            self.notify_event_listeners(BreezEvent::LnUrlAuthCompleted { details })
+                .await?;
         }
         Ok(())
     }
 
-    /// Get the current LSP's ID
-    pub async fn lsp_id(&self) -> SdkResult<Option<String>> {
-        Ok(self.persister.get_lsp_id()?)
+    /// Lists the domains authenticated to with [BreezServices::lnurl_auth], the most recently
+    /// used first
+    #[cfg(feature = "lnurl")]
+    pub fn list_lnurl_auth_domains(&self) -> SdkResult<Vec<LnUrlAuthDomain>> {
+        Ok(self.persister.list_lnurl_auth_domains()?)
     }
 
-    /// Convenience method to look up [LspInformation] for a given LSP ID
-    pub async fn fetch_lsp_info(&self, id: String) -> SdkResult<Option<LspInformation>> {
-        get_lsp_by_id(self.persister.clone(), self.lsp_api.clone(), id.as_str()).await
+    /// The hex encoded LUD-05 linking key the wallet identifies with on the domain. It is derived
+    /// from the seed, so it is the same on any device restored from it.
+    #[cfg(feature = "lnurl")]
+    pub async fn lnurl_auth_linking_key(&self, domain: String) -> SdkResult<String> {
+        let signer = SdkLnurlAuthSigner::new(self.node_api.clone());
+        Ok(signer.linking_key(&domain.to_lowercase()).await?)
     }
 
-    /// Gets the fees required to open a channel for a given amount.
-    /// If no channel is needed, returns 0. If a channel is needed, returns the required opening fees.
-    pub async fn open_channel_fee(
-        &self,
-        req: OpenChannelFeeRequest,
-    ) -> SdkResult<OpenChannelFeeResponse> {
-        let lsp_info = self.lsp_info().await?;
-        let fee_params = lsp_info
-            .cheapest_open_channel_fee(req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS))?
-            .clone();
-
-        let node_state = self.node_info()?;
-        let fee_msat = req.amount_msat.map(|req_amount_msat| {
-            match node_state.max_receivable_single_payment_amount_msat >= req_amount_msat {
-                // In case we have enough inbound liquidity we return zero fee.
-                true => 0,
-                // Otherwise we need to calculate the fee for opening a new channel.
-                false => fee_params.get_channel_fees_msat_for(req_amount_msat),
-            }
-        });
-
-        Ok(OpenChannelFeeResponse {
-            fee_msat,
-            fee_params,
+    /// Exports the LNURL-auth identity and domain history, to be imported with
+    /// [BreezServices::import_lnurl_auth] on another device restored from the same seed
+    #[cfg(feature = "lnurl")]
+    pub async fn export_lnurl_auth(&self) -> SdkResult<LnUrlAuthExport> {
+        let signer = SdkLnurlAuthSigner::new(self.node_api.clone());
+        Ok(LnUrlAuthExport {
+            hashing_key: signer.hashing_key().await?,
+            domains: self.persister.list_lnurl_auth_domains()?,
         })
     }
 
-    /// Close all channels with the current LSP.
+    /// Imports the domain history exported with [BreezServices::export_lnurl_auth], merging it
+    /// with the local one.
     ///
-    /// Should be called  when the user wants to close all the channels.
-    pub async fn close_lsp_channels(&self) -> SdkResult<Vec<String>> {
-        let lsp = self.lsp_info().await?;
-        let tx_ids = self.node_api.close_peer_channels(lsp.pubkey).await?;
-        self.sync().await?;
-        Ok(tx_ids)
+    /// The export has to come from the same identity: its hashing key and the linking key of
+    /// each domain are checked against the ones derived from the seed of this node.
+    #[cfg(feature = "lnurl")]
+    pub async fn import_lnurl_auth(&self, export: LnUrlAuthExport) -> SdkResult<()> {
+        let signer = SdkLnurlAuthSigner::new(self.node_api.clone());
+        ensure_sdk!(
+            export.hashing_key == signer.hashing_key().await?,
+            SdkError::generic("The export belongs to another LNURL-auth identity")
+        );
+        for domain in &export.domains {
+            let linking_key = signer.linking_key(&domain.domain).await?;
+            ensure_sdk!(
+                domain.linking_key == linking_key,
+                SdkError::Generic {
+                    err: format!("The linking key of {} doesn't match", domain.domain),
+                }
+            );
+        }
+        for domain in &export.domains {
+            self.persister.import_lnurl_auth_domain(domain)?;
+        }
+        Ok(())
     }
 
-    /// Onchain receive swap API
-    ///
-    /// Create and start a new swap. A user-selected [OpeningFeeParams] can be optionally set in the argument.
-    /// If set, and the operation requires a new channel, the SDK will use the given fee params.
-    /// The provided [OpeningFeeParams] need to be valid at the time of swap redeeming.
-    ///
-    /// Since we only allow one in-progress swap this method will return error if there is currently
-    /// a swap waiting for confirmation to be redeemed and by that complete the swap.
-    /// In such case the [BreezServices::in_progress_swap] can be used to query the live swap status.
+    /// Creates an bolt11 payment request.
+    /// This also works when the node doesn't have any channels and need inbound liquidity.
+    /// In such case when the invoice is paid a new zero-conf channel will be open by the LSP,
+    /// providing inbound liquidity and the payment will be routed via this new channel.
     ///
-    /// The returned [SwapInfo] contains the created swap details. The channel opening fees are
-    /// available at [SwapInfo::channel_opening_fees].
-    pub async fn receive_onchain(
+    /// If [ReceivePaymentRequest::include_fallback_address] is set, the address of a new swap is
+    /// included in the invoice as fallback address, as returned by [BreezServices::receive_onchain].
+    /// Funds sent to it are received through the swap, and like with
+    /// [BreezServices::receive_onchain] this fails if a swap is in progress.
+    #[tracing::instrument(skip_all, fields(payment_hash = tracing::field::Empty))]
+    pub async fn receive_payment(
         &self,
-        req: ReceiveOnchainRequest,
-    ) -> ReceiveOnchainResult<SwapInfo> {
-        if let Some(in_progress) = self.in_progress_swap().await? {
-            return Err(ReceiveOnchainError::SwapInProgress{ err:format!(
-                    "A swap was detected for address {}. Use in_progress_swap method to get the current swap state",
-                    in_progress.bitcoin_address
-                )});
+        req: ReceivePaymentRequest,
+    ) -> Result<ReceivePaymentResponse, ReceivePaymentError> {
+        let fallback_address = match req.include_fallback_address.unwrap_or(false) {
+            true => {
+                let swap_info = self
+                    .receive_onchain(ReceiveOnchainRequest {
+                        opening_fee_params: req.opening_fee_params.clone(),
+                    })
+                    .await?;
+                Some(swap_info.bitcoin_address)
+            }
+            false => None,
+        };
+        let (req, fiat_rate) = self.resolve_fiat_amount(req).await?;
+        let fiat_amount = req.fiat_amount.clone();
+        self.ensure_lsp_peer_connected().await;
+        let mut res = self
+            .payment_receiver
+            .receive_payment_with_fallback(req, fallback_address)
+            .await?;
+        tracing::Span::current().record("payment_hash", res.ln_invoice.payment_hash.as_str());
+        if let Some(rate) = &fiat_rate {
+            if let Err(e) = self
+                .persister
+                .set_payment_fiat_rate(&res.ln_invoice.payment_hash, rate)
+            {
+                warn!("Failed to store the fiat rate of the invoice: {e}");
+            }
+            res.fiat_amount = fiat_amount;
+            res.fiat_rate = fiat_rate;
         }
-        let channel_opening_fees = req.opening_fee_params.unwrap_or(
-            self.lsp_info()
-                .await?
-                .cheapest_open_channel_fee(SWAP_PAYMENT_FEE_EXPIRY_SECONDS)?
-                .clone(),
+        if let Some(opening_fee_msat) = res.opening_fee_msat {
+            self.notify_open_channel_receive(
+                res.ln_invoice.payment_hash.clone(),
+                OpenChannelReceiveStage::Registered {
+                    opening_fee_msat: opening_fee_msat.msat(),
+                },
+            )
+            .await;
+        }
+        Ok(res)
+    }
+
+    /// Sets the amount of the request from its [ReceivePaymentRequest::fiat_amount], if any,
+    /// converted at the current exchange rate, which is returned as well
+    #[cfg(feature = "fiat")]
+    async fn resolve_fiat_amount(
+        &self,
+        mut req: ReceivePaymentRequest,
+    ) -> Result<(ReceivePaymentRequest, Option<Rate>), ReceivePaymentError> {
+        let Some(fiat_amount) = &req.fiat_amount else {
+            return Ok((req, None));
+        };
+        let rate = self
+            .fetch_fiat_rates()
+            .await?
+            .into_iter()
+            .find(|r| r.coin == fiat_amount.currency)
+            .ok_or(ReceivePaymentError::Generic {
+                err: format!("No exchange rate found for {}", fiat_amount.currency),
+            })?;
+        req.amount_msat = fiat_to_msat(fiat_amount.amount, rate.value)
+            .map(MilliSat)
+            .ok_or(ReceivePaymentError::InvalidAmount {
+                err: format!("Invalid fiat amount {}", fiat_amount.amount),
+            })?;
+        Ok((req, Some(rate)))
+    }
+
+    #[cfg(not(feature = "fiat"))]
+    async fn resolve_fiat_amount(
+        &self,
+        req: ReceivePaymentRequest,
+    ) -> Result<(ReceivePaymentRequest, Option<Rate>), ReceivePaymentError> {
+        ensure_sdk!(
+            req.fiat_amount.is_none(),
+            ReceivePaymentError::Generic {
+                err: "Fiat amounts require the fiat feature".into()
+            }
         );
+        Ok((req, None))
+    }
 
-        let swap_info = self
-            .btc_receive_swapper
-            .create_swap(channel_opening_fees)
-            .await?;
-        if let Some(webhook_url) = self.persister.get_webhook_url()? {
-            let address = &swap_info.bitcoin_address;
-            info!("Registering for onchain tx notification for address {address}");
-            self.register_onchain_tx_notification(address, &webhook_url)
-                .await?;
-        }
-        Ok(swap_info)
+    /// Calculates the channel opening fee that [BreezServices::receive_payment] will deduct
+    /// from the received amount, before creating the invoice.
+    ///
+    /// Passing the returned [PrepareReceivePaymentResponse::opening_fee_params] to
+    /// [ReceivePaymentRequest::opening_fee_params] ensures the invoice is created with the
+    /// previewed fee.
+    pub async fn prepare_receive_payment(
+        &self,
+        req: PrepareReceivePaymentRequest,
+    ) -> Result<PrepareReceivePaymentResponse, ReceivePaymentError> {
+        let lsp_info = self.lsp_info().await?;
+        self.payment_receiver
+            .prepare_receive_payment(req, &lsp_info)
     }
 
-    /// Returns an optional in-progress [SwapInfo].
-    /// A [SwapInfo] is in-progress if it is waiting for confirmation to be redeemed and complete the swap.
-    pub async fn in_progress_swap(&self) -> SdkResult<Option<SwapInfo>> {
-        let tip = self.chain_service.current_tip().await?;
-        self.btc_receive_swapper.rescan_monitored_swaps(tip).await?;
-        let in_progress = self.btc_receive_swapper.list_in_progress_swaps()?;
-        if !in_progress.is_empty() {
-            return Ok(Some(in_progress[0].clone()));
+    async fn notify_open_channel_receive(
+        &self,
+        payment_hash: String,
+        stage: OpenChannelReceiveStage,
+    ) {
+        let event = BreezEvent::OpenChannelReceiveUpdated {
+            details: OpenChannelReceiveDetails {
+                payment_hash,
+                stage,
+            },
+        };
+        if let Err(e) = self.notify_event_listeners(event).await {
+            warn!("Failed to notify the open channel receive progress: {e}");
         }
-        Ok(None)
     }
 
-    /// Iterate all historical swap addresses and fetch their current status from the blockchain.
-    /// The status is then updated in the persistent storage.
-    pub async fn rescan_swaps(&self) -> SdkResult<()> {
-        let tip = self.chain_service.current_tip().await?;
-        self.btc_receive_swapper.rescan_swaps(tip).await?;
-        Ok(())
+    /// Amends an unexpired, unpaid invoice of this node and signs it again.
+    ///
+    /// See [AmendInvoiceRequest] for the changes that are allowed. Invoices that were registered
+    /// with the LSP for a channel opening cannot be amended, as the LSP only forwards the payment
+    /// of the registered invoice.
+    pub async fn amend_invoice(&self, req: AmendInvoiceRequest) -> SdkResult<LNInvoice> {
+        let invoice = parse_invoice(&req.bolt11)?;
+        ensure_sdk!(
+            invoice.payee_pubkey == self.node_info()?.id,
+            SdkError::generic("Only invoices of this node can be amended")
+        );
+        if let Some(payment) = self.persister.get_payment_by_hash(&invoice.payment_hash)? {
+            ensure_sdk!(
+                payment.status != PaymentStatus::Complete,
+                SdkError::generic("Invoice already paid")
+            );
+        }
+        ensure_sdk!(
+            self.persister
+                .get_open_channel_bolt11_by_hash(&invoice.payment_hash)?
+                .is_none(),
+            SdkError::generic("Invoice was registered with the LSP and cannot be amended")
+        );
+        if let (Some(amount_msat), Some(invoice_amount_msat)) =
+            (req.amount_msat, invoice.amount_msat)
+        {
+            ensure_sdk!(
+                amount_msat.msat() >= invoice_amount_msat,
+                SdkError::generic("Invoice amount can only be raised")
+            );
+        }
+        if let Some(expiry) = req.expiry {
+            ensure_sdk!(
+                expiry as u64 <= invoice.expiry,
+                SdkError::generic("Invoice expiry can only be shortened")
+            );
+        }
+
+        let raw_invoice = amend_invoice(
+            &req.bolt11,
+            InvoiceAmendment {
+                amount_msat: req.amount_msat.map(MilliSat::msat),
+                expiry: req.expiry.map(|e| e as u64),
+                route_hints: req.route_hints.unwrap_or_default(),
+                merge_route_hints: false,
+            },
+        )?;
+        let bolt11 = self.node_api.sign_invoice(raw_invoice).await?;
+        info!("Amended invoice {}", invoice.payment_hash);
+        Ok(parse_invoice(&bolt11)?)
     }
 
-    /// Redeems an individual swap.
+    /// Stores the details of the LNURL-pays whose payment completed or failed after
+    /// [BreezServices::lnurl_pay] returned, for example because the app was stopped while paying.
+    /// The success action of a completed payment is processed with its preimage.
     ///
-    /// To be used only in the context of mobile notifications, where the notification triggers
-    /// an individual redeem.
-    ///
-    /// This is taken care of automatically in the context of typical SDK usage.
-    pub async fn redeem_swap(&self, swap_address: String) -> SdkResult<()> {
-        let tip = self.chain_service.current_tip().await?;
-        self.btc_receive_swapper
-            .rescan_swap(&swap_address, tip)
-            .await?;
-        self.btc_receive_swapper.redeem_swap(swap_address).await?;
+    /// The LNURL-pays still unknown to the node after [PENDING_LNURL_PAY_EXPIRY_SECS] are
+    /// dropped.
+    #[cfg(feature = "lnurl")]
+    fn process_pending_lnurl_pays(&self) -> SdkResult<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        for pending in self.persister.list_pending_lnurl_pays()? {
+            let payment = self.persister.get_payment_by_hash(&pending.payment_hash)?;
+            let external_info = match payment {
+                Some(Payment {
+                    status: PaymentStatus::Complete,
+                    details: PaymentDetails::Ln { data },
+                    ..
+                }) => PaymentExternalInfo {
+                    lnurl_pay_success_action: pending
+                        .success_action
+                        .map(|sa| process_success_action(sa, &data.payment_preimage))
+                        .transpose()
+                        .map_err(|e| SdkError::Generic { err: e.to_string() })?,
+                    ..pending.external_info
+                },
+                Some(Payment {
+                    status: PaymentStatus::Failed,
+                    error,
+                    ..
+                }) => PaymentExternalInfo {
+                    attempted_error: error,
+                    ..pending.external_info
+                },
+                None if now - pending.created_at > PENDING_LNURL_PAY_EXPIRY_SECS => {
+                    self.persister
+                        .delete_pending_lnurl_pay(&pending.payment_hash)?;
+                    continue;
+                }
+                _ => continue,
+            };
+            self.persister
+                .insert_payment_external_info(&pending.payment_hash, external_info)?;
+            self.persister
+                .delete_pending_lnurl_pay(&pending.payment_hash)?;
+        }
         Ok(())
     }
 
-    /// Lists current and historical swaps.
+    /// Signs an externally constructed invoice with the node key and returns the BOLT11.
     ///
-    /// Swaps can be filtered based on creation time and status.
-    pub async fn list_swaps(&self, req: ListSwapsRequest) -> SdkResult<Vec<SwapInfo>> {
-        Ok(self.persister.list_swaps(req)?)
+    /// This is meant for integrators who build the [RawBolt11Invoice] themselves, for example to
+    /// set custom fields, instead of creating it with [BreezServices::receive_payment]. The
+    /// invoice currency has to match the configured network.
+    pub async fn sign_invoice(&self, raw_invoice: RawBolt11Invoice) -> SdkResult<String> {
+        let bolt11 = self.node_api.sign_invoice(raw_invoice).await?;
+        validate_network(parse_invoice(&bolt11)?, self.config.network)?;
+        Ok(bolt11)
     }
 
-    /// Claims an individual reverse swap.
+    /// Parses a batch of bolt11 invoices, checking their signature and that they are for the
+    /// configured network, for example to reconcile the invoices of a merchant back-office.
     ///
-    /// To be used only in the context of mobile notifications, where the notification triggers
-    /// an individual reverse swap to be claimed.
-    ///
-    /// This is taken care of automatically in the context of typical SDK usage.
-    pub async fn claim_reverse_swap(&self, lockup_address: String) -> SdkResult<()> {
-        Ok(self
-            .btc_send_swapper
-            .claim_reverse_swap(lockup_address)
-            .await?)
+    /// The invoices are verified concurrently. An invalid invoice doesn't stop the batch: the
+    /// result of each invoice is returned, in the order of `invoices`.
+    pub async fn verify_invoices(&self, invoices: Vec<String>) -> Vec<InvoiceVerificationResult> {
+        let network = self.config.network;
+        let concurrency = std::thread::available_parallelism()
+            .map(|parallelism| parallelism.get())
+            .unwrap_or(1);
+        stream::iter(invoices)
+            .map(|bolt11| async move {
+                let verification = tokio::task::spawn_blocking({
+                    let bolt11 = bolt11.clone();
+                    move || verify_invoice(&bolt11, network)
+                })
+                .await;
+                let (invoice, error) = match verification {
+                    Ok(Ok(invoice)) => (Some(invoice), None),
+                    Ok(Err(e)) => (None, Some(e.to_string())),
+                    Err(e) => (None, Some(format!("Failed to verify the invoice: {e}"))),
+                };
+                InvoiceVerificationResult {
+                    bolt11,
+                    invoice,
+                    error,
+                }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await
     }
 
-    /// Lookup the reverse swap fees (see [ReverseSwapServiceAPI::fetch_reverse_swap_fees]).
-    ///
-    /// If the request has the `send_amount_sat` set, the returned [ReverseSwapPairInfo] will have
-    /// the total estimated fees for the reverse swap in its `total_estimated_fees`.
-    ///
-    /// If, in addition to that, the request has the `claim_tx_feerate` set as well, then
-    /// - `fees_claim` will have the actual claim transaction fees, instead of an estimate, and
-    /// - `total_estimated_fees` will have the actual total fees for the given parameters
-    ///
-    /// ### Errors
+    /// Report an issue.
     ///
-    /// If a `send_amount_sat` is specified in the `req`, but is outside the `min` and `max`,
-    /// this will result in an error. If you are not sure what are the `min` and `max`, please call
-    /// this with `send_amount_sat` as `None` first, then repeat the call with the desired amount.
-    pub async fn fetch_reverse_swap_fees(
-        &self,
-        req: ReverseSwapFeesRequest,
-    ) -> SdkResult<ReverseSwapPairInfo> {
-        let mut res = self.btc_send_swapper.fetch_reverse_swap_fees().await?;
+    /// Calling `report_issue` with a [ReportIssueRequest] enum param sends an issue report using the Support API.
+    /// - [ReportIssueRequest::PaymentFailure] sends a payment failure report to the Support API
+    ///   using the provided `payment_hash` to lookup the failed payment and the current [NodeState].
+    pub async fn report_issue(&self, req: ReportIssueRequest) -> SdkResult<()> {
+        match self.persister.get_node_state()? {
+            Some(node_state) => match req {
+                ReportIssueRequest::PaymentFailure { data } => {
+                    let payment = self
+                        .persister
+                        .get_payment_by_hash(&data.payment_hash)?
+                        .ok_or(SdkError::Generic {
+                            err: "Payment not found".into(),
+                        })?;
+                    let lsp_id = self.persister.get_lsp_id()?;
 
-        if let Some(amt) = req.send_amount_sat {
-            ensure_sdk!(amt <= res.max, SdkError::generic("Send amount is too high"));
-            ensure_sdk!(amt >= res.min, SdkError::generic("Send amount is too low"));
+                    self.support_api
+                        .report_payment_failure(node_state, payment, lsp_id, data.comment)
+                        .await
+                }
+            },
+            None => Err(SdkError::Generic {
+                err: "Node state not found".into(),
+            }),
+        }
+    }
 
-            if let Some(claim_tx_feerate) = req.claim_tx_feerate {
-                res.fees_claim = BTCSendSwap::calculate_claim_tx_fee(claim_tx_feerate)?;
+    /// Reports the connectivity to the LSP peer, pinging it now, and the reconnections to it
+    /// since connect.
+    ///
+    /// Support cases about failing payments can use it to tell connectivity issues apart from
+    /// missing liquidity.
+    pub async fn peer_connectivity(&self) -> SdkResult<PeerConnectivity> {
+        let lsp_pubkey = self.persister.get_lsp_pubkey()?;
+        let mut connectivity = PeerConnectivity {
+            lsp_pubkey: lsp_pubkey.clone(),
+            ..Default::default()
+        };
+        if let Some(lsp_pubkey) = lsp_pubkey {
+            let start = Instant::now();
+            match self.node_api.ping_peer(lsp_pubkey).await {
+                Ok(()) => {
+                    connectivity.lsp_connected = true;
+                    connectivity.ping_rtt_ms = Some(start.elapsed().as_millis() as u64);
+                }
+                Err(e) => connectivity.ping_error = Some(e.to_string()),
             }
-
-            let service_fee_sat = swap_out::get_service_fee_sat(amt, res.fees_percentage);
-            res.total_fees = Some(service_fee_sat + res.fees_lockup + res.fees_claim);
         }
 
-        Ok(res)
+        let reconnections = self.lsp_reconnections.lock().await;
+        connectivity.reconnection_attempts = reconnections.attempts;
+        connectivity.failed_reconnection_attempts = reconnections.failed_attempts;
+        connectivity.last_reconnected_at = reconnections.last_reconnected_at;
+        connectivity.last_reconnection_error = reconnections.last_error.clone();
+        Ok(connectivity)
     }
-    /// Returns the max amount that can be sent on-chain using the send_onchain method.
-    /// The returned amount is the sum of the max amount that can be sent on each channel
-    /// minus the expected fees.
-    /// This is possible since the route to the swapper node is known in advance and is expected
-    /// to consist of maximum 3 hops.
-    async fn max_reverse_swap_amount(&self) -> SdkResult<u64> {
-        // fetch the last hop hints from the swapper
-        let last_hop = self.btc_send_swapper.last_hop_for_payment().await?;
-        info!("max_reverse_swap_amount last_hop={:?}", last_hop);
-        // calculate the largest payment we can send over this route using maximum 3 hops
-        // as follows:
-        // User Node -> LSP Node -> Routing Node -> Swapper Node
-        let max_to_pay = self
-            .node_api
-            .max_sendable_amount(
-                Some(
-                    hex::decode(&last_hop.src_node_id).map_err(|e| SdkError::Generic {
-                        err: format!("Failed to decode hex node_id: {e}"),
-                    })?,
-                ),
-                swap_out::reverseswap::MAX_PAYMENT_PATH_HOPS,
-                Some(&last_hop),
-            )
-            .await?;
 
-        // Sum the max amount per channel and return the result
-        let total_msat: u64 = max_to_pay.into_iter().map(|m| m.amount_msat).sum();
-        let total_sat = total_msat / 1000;
-        Ok(total_sat)
+    /// Whether the node and the LSP peer are reachable, as last checked by the connection
+    /// monitor, for apps to show the online state.
+    ///
+    /// The SDK reconnects automatically when the connection is lost, see
+    /// [BreezEvent::Disconnected], [BreezEvent::Reconnecting] and [BreezEvent::Connected].
+    pub async fn connection_status(&self) -> SdkResult<ConnectionStatus> {
+        Ok(self.connection_status.lock().await.clone())
     }
 
-    /// list non-completed expired swaps that should be refunded by calling [BreezServices::refund]
-    pub async fn list_refundables(&self) -> SdkResult<Vec<SwapInfo>> {
-        Ok(self.btc_receive_swapper.list_refundables()?)
+    /// Returns the counters and histograms of the SDK operations since connect: the outgoing
+    /// payments and their duration, the syncs and their duration, the failed LSP API calls,
+    /// the created swaps and the reconnections to the LSP peer.
+    pub async fn metrics(&self) -> SdkResult<MetricsSnapshot> {
+        let reconnections = self.lsp_reconnections.lock().await;
+        Ok(self.metrics.snapshot(
+            reconnections.attempts as u64,
+            reconnections.failed_attempts as u64,
+        ))
     }
 
-    /// Prepares a refund transaction for a failed/expired swap.
-    ///
-    /// Can optionally be used before [BreezServices::refund] to know how much fees will be paid
-    /// to perform the refund.
-    pub async fn prepare_refund(
-        &self,
-        req: PrepareRefundRequest,
-    ) -> SdkResult<PrepareRefundResponse> {
-        Ok(self.btc_receive_swapper.prepare_refund(req).await?)
+    /// Retrieve the decrypted credentials from the node.
+    pub async fn node_credentials(&self) -> SdkResult<Option<NodeCredentials>> {
+        Ok(self.node_api.node_credentials().await?)
     }
 
-    /// Construct and broadcast a refund transaction for a failed/expired swap
+    /// Retrieve the node state from the persistent storage.
     ///
-    /// Returns the txid of the refund transaction.
-    pub async fn refund(&self, req: RefundRequest) -> SdkResult<RefundResponse> {
-        Ok(self.btc_receive_swapper.refund(req).await?)
+    /// Fail if it could not be retrieved or if `None` was found.
+    pub fn node_info(&self) -> SdkResult<NodeState> {
+        self.persister.get_node_state()?.ok_or(SdkError::Generic {
+            err: "Node info not found".into(),
+        })
     }
 
-    pub async fn onchain_payment_limits(&self) -> SdkResult<OnchainPaymentLimitsResponse> {
-        let fee_info = self.btc_send_swapper.fetch_reverse_swap_fees().await?;
-        debug!("Reverse swap pair info: {fee_info:?}");
-        let max_amt_current_channels = self.max_reverse_swap_amount().await?;
-        debug!("Max send amount possible with current channels: {max_amt_current_channels:?}");
-
-        Ok(OnchainPaymentLimitsResponse {
-            min_sat: fee_info.min,
-            max_sat: fee_info.max,
-            max_payable_sat: max_amt_current_channels,
-        })
+    /// Returns the health of the background tasks started on connect, like the sync loop, the
+    /// swap monitor and the backup worker. A task that fails or panics is restarted after a
+    /// delay, and its restarts and last error are reported here. Restarting after a panic needs
+    /// panics to unwind: with `panic = "abort"`, as in the release builds of the bindings, a
+    /// panic aborts the app.
+    pub fn task_status(&self) -> SdkResult<Vec<TaskStatus>> {
+        Ok(self.task_supervisor.status())
     }
 
-    /// Supersedes [BreezServices::fetch_reverse_swap_fees]
-    ///
-    /// ### Errors
-    ///
-    /// - `OutOfRange`: This indicates the send amount is outside the range of minimum and maximum
-    ///   values returned by [BreezServices::onchain_payment_limits]. When you get this error, please first call
-    ///   [BreezServices::onchain_payment_limits] to get the new limits, before calling this method again.
-    pub async fn prepare_onchain_payment(
-        &self,
-        req: PrepareOnchainPaymentRequest,
-    ) -> Result<PrepareOnchainPaymentResponse, SendOnchainError> {
-        let fees_claim = BTCSendSwap::calculate_claim_tx_fee(req.claim_tx_feerate)?;
-        BTCSendSwap::validate_claim_tx_fee(fees_claim)?;
+    /// Retrieve the funds of the node split by state from the persistent storage, as the
+    /// [NodeState] balances don't show the funds in flight or locked in swaps.
+    pub fn balances(&self) -> SdkResult<Balances> {
+        let node_state = self.node_info()?;
 
-        let fee_info = self.btc_send_swapper.fetch_reverse_swap_fees().await?;
+        let mut reverse_swaps_locked_msat = 0;
+        let mut reverse_swap_hashes = vec![];
+        for rs in self.persister.list_reverse_swaps()? {
+            if rs.cache.status.is_blocking_state() {
+                let invoice = parse_invoice(&rs.invoice)?;
+                reverse_swaps_locked_msat += invoice.amount_msat.unwrap_or_default();
+                reverse_swap_hashes.push(invoice.payment_hash);
+            }
+        }
 
-        // Calculate (send_amt, recv_amt) from the inputs and fees
-        let fees_lockup = fee_info.fees_lockup;
-        let p = fee_info.fees_percentage;
-        let (send_amt, recv_amt) = match req.amount_type {
-            SwapAmountType::Send => {
-                let temp_send_amt = req.amount_sat;
-                let service_fees = swap_out::get_service_fee_sat(temp_send_amt, p);
-                let total_fees = service_fees + fees_lockup + fees_claim;
-                ensure_sdk!(
-                    temp_send_amt > total_fees,
-                    SendOnchainError::generic(
-                        "Send amount is not high enough to account for all fees"
-                    )
-                );
+        let lightning_pending_msat = self
+            .persister
+            .list_payments(ListPaymentsRequest {
+                filters: Some(vec![PaymentTypeFilter::Sent]),
+                ..Default::default()
+            })?
+            .into_iter()
+            .filter(|p| p.status == PaymentStatus::Pending && !reverse_swap_hashes.contains(&p.id))
+            .map(|p| p.amount_msat + p.fee_msat)
+            .sum();
 
-                (temp_send_amt, temp_send_amt - total_fees)
-            }
-            SwapAmountType::Receive => {
-                let temp_recv_amt = req.amount_sat;
-                let send_amt_minus_service_fee = temp_recv_amt + fees_lockup + fees_claim;
-                let temp_send_amt = swap_out::get_invoice_amount_sat(send_amt_minus_service_fee, p);
+        let swaps_locked_msat = self
+            .persister
+            .list_swaps(ListSwapsRequest {
+                status: Some(vec![
+                    SwapStatus::Initial,
+                    SwapStatus::WaitingConfirmation,
+                    SwapStatus::Redeemable,
+                    SwapStatus::Refundable,
+                ]),
+                ..Default::default()
+            })?
+            .into_iter()
+            // Refunded funds are sent to an address given by the user
+            .filter(|s| s.refund_tx_ids.is_empty())
+            .map(|s| (s.confirmed_sats + s.unconfirmed_sats) * 1_000)
+            .sum();
+
+        Ok(Balances {
+            lightning_spendable_msat: node_state.max_payable_msat,
+            lightning_pending_msat,
+            onchain_confirmed_msat: node_state.onchain_balance_msat,
+            onchain_unconfirmed_msat: node_state.pending_onchain_balance_msat,
+            swaps_locked_msat,
+            reverse_swaps_locked_msat,
+        })
+    }
 
-                (temp_send_amt, temp_recv_amt)
-            }
-        };
+    /// Explains the funds of the node: what can be sent over each channel, what is locked in the
+    /// channel reserves and in the HTLCs in flight, and what is pending onchain or in swaps.
+    ///
+    /// Unlike [BreezServices::balances], the channels are fetched from the node.
+    pub async fn get_balance_breakdown(&self) -> SdkResult<BalanceBreakdown> {
+        let balances = self.balances()?;
+        let pending_channel_opens_msat = self.node_info()?.pending_channel_opens_msat;
+        let channels = self.node_api.list_channels().await?;
+        Ok(BalanceBreakdown::new(
+            channels,
+            pending_channel_opens_msat,
+            balances,
+        ))
+    }
 
-        let is_send_in_range = send_amt >= fee_info.min && send_amt <= fee_info.max;
-        ensure_sdk!(is_send_in_range, SendOnchainError::OutOfRange);
+    /// Sign given message with the private key of the node id. Returns a zbase
+    /// encoded signature.
+    pub async fn sign_message(&self, req: SignMessageRequest) -> SdkResult<SignMessageResponse> {
+        let signature = self.node_api.sign_message(&req.message).await?;
+        Ok(SignMessageResponse { signature })
+    }
 
-        Ok(PrepareOnchainPaymentResponse {
-            fees_hash: fee_info.fees_hash.clone(),
-            fees_percentage: p,
-            fees_lockup,
-            fees_claim,
-            sender_amount_sat: send_amt,
-            recipient_amount_sat: recv_amt,
-            total_fees: send_amt - recv_amt,
-        })
+    /// Check whether given message was signed by the private key or the given
+    /// pubkey and the signature (zbase encoded) is valid.
+    pub async fn check_message(&self, req: CheckMessageRequest) -> SdkResult<CheckMessageResponse> {
+        let is_valid = self
+            .node_api
+            .check_message(&req.message, &req.pubkey, &req.signature)
+            .await?;
+        Ok(CheckMessageResponse { is_valid })
     }
 
-    /// Creates a reverse swap and attempts to pay the HODL invoice
-    pub async fn pay_onchain(
+    /// Signs a message with the key of an address of the node onchain wallet, as a
+    /// [BIP-322](https://github.com/bitcoin/bips/blob/master/bip-0322.mediawiki) "simple"
+    /// signature, for example to whitelist the address on an exchange or for a travel rule
+    /// attestation.
+    ///
+    /// Only the P2WPKH and P2TR addresses of the wallet are supported.
+    pub async fn prove_address_ownership(
         &self,
-        req: PayOnchainRequest,
-    ) -> Result<PayOnchainResponse, SendOnchainError> {
+        req: ProveAddressOwnershipRequest,
+    ) -> SdkResult<ProveAddressOwnershipResponse> {
+        let address = bitcoin::Address::from_str(&req.address)
+            .map_err(|e| SdkError::generic(&format!("Invalid address: {e}")))?;
         ensure_sdk!(
-            req.prepare_res.sender_amount_sat > req.prepare_res.recipient_amount_sat,
-            SendOnchainError::generic("Send amount must be bigger than receive amount")
+            address.is_valid_for_network(self.config.network.into()),
+            SdkError::generic("The address is for another network")
+        );
+        let script_pubkey = address.script_pubkey();
+        ensure_sdk!(
+            script_pubkey.is_v0_p2wpkh() || script_pubkey.is_v1_p2tr(),
+            SdkError::generic("Only P2WPKH and P2TR addresses are supported")
         );
 
-        ensure_sdk!(self.in_progress_onchain_payments().await?.is_empty(), SendOnchainError::Generic { err:
-            "You can only start a new one after after the ongoing ones finish. \
-            Use the in_progress_onchain_payments method to get an overview of currently ongoing reverse swaps".into(),
-        });
-
-        let full_rsi = self.btc_send_swapper.create_reverse_swap(req).await?;
-        let reverse_swap_info = self
-            .btc_send_swapper
-            .convert_reverse_swap_info(full_rsi.clone())
+        let secp = Secp256k1::new();
+        let wallet_xpub = self
+            .node_api
+            .derive_xpub(vec![ChildNumber::from(0), ChildNumber::from(0)])
             .await?;
-        self.do_sync(false).await?;
-
-        if let Some(webhook_url) = self.persister.get_webhook_url()? {
-            let address = &full_rsi
-                .get_lockup_address(self.config.network)?
-                .to_string();
-            info!("Registering for onchain tx notification for address {address}");
-            self.register_onchain_tx_notification(address, &webhook_url)
-                .await?;
+        let signature_hash = crate::bip322::signature_hash(&script_pubkey, &req.message)?;
+        for index in 0..ONCHAIN_WALLET_KEY_SCAN_LIMIT {
+            let pubkey = wallet_xpub
+                .ckd_pub(&secp, ChildNumber::from(index))
+                .map_err(|e| SdkError::generic(&e.to_string()))?
+                .public_key;
+            if !onchain_wallet_scripts(&secp, &pubkey, self.config.network)?
+                .contains(&script_pubkey)
+            {
+                continue;
+            }
+            let path = vec![
+                ChildNumber::from(0),
+                ChildNumber::from(0),
+                ChildNumber::from(index),
+            ];
+            let signature = match script_pubkey.is_v0_p2wpkh() {
+                true => crate::bip322::p2wpkh_signature(
+                    &self.node_api.sign_ecdsa(path, signature_hash).await?,
+                    &bitcoin::PublicKey::new(pubkey),
+                ),
+                false => crate::bip322::p2tr_signature(
+                    &self.node_api.sign_taproot(path, signature_hash).await?,
+                ),
+            };
+            return Ok(ProveAddressOwnershipResponse { signature });
         }
-        Ok(PayOnchainResponse { reverse_swap_info })
+        Err(SdkError::generic(
+            "The address doesn't belong to the onchain wallet",
+        ))
     }
 
-    /// Returns the blocking [ReverseSwapInfo]s that are in progress.
-    pub async fn in_progress_onchain_payments(&self) -> SdkResult<Vec<ReverseSwapInfo>> {
-        let full_rsis = self.btc_send_swapper.list_blocking().await?;
+    /// Derives a symmetric key from the node seed, so the app can encrypt its own data with a
+    /// key that is recovered with the wallet instead of managing a second key hierarchy.
+    ///
+    /// The same purpose and index always result in the same key. The key is an HMAC-SHA256 of the
+    /// purpose, keyed with the private key at the hardened path `m/141'/index'`, so it reveals
+    /// nothing about the node keys or the keys of other purposes.
+    pub async fn derive_encryption_key(
+        &self,
+        req: DeriveEncryptionKeyRequest,
+    ) -> SdkResult<DeriveEncryptionKeyResponse> {
+        let path = vec![
+            ChildNumber::Hardened {
+                index: APP_ENCRYPTION_KEY_PATH_INDEX,
+            },
+            ChildNumber::from_hardened_idx(req.index).map_err(|_| SdkError::Generic {
+                err: format!("Invalid key index {}, must be lower than 2^31", req.index),
+            })?,
+        ];
+        let key = self.node_api.derive_symmetric_key(path).await?;
+        let mut engine = HmacEngine::<sha256::Hash>::new(&key);
+        engine.input(req.purpose.as_bytes());
+        Ok(DeriveEncryptionKeyResponse {
+            key: Hmac::<sha256::Hash>::from_engine(engine)
+                .as_inner()
+                .to_vec(),
+        })
+    }
 
-        let mut rsis = vec![];
-        for full_rsi in full_rsis {
-            let rsi = self
-                .btc_send_swapper
-                .convert_reverse_swap_info(full_rsi)
-                .await?;
-            rsis.push(rsi);
-        }
+    /// Encrypts the payment request for the other apps of the same wallet, for example to hand
+    /// it off from a watch to the phone through the clipboard or a QR code.
+    ///
+    /// The result is recognized by `parse` as [InputType::EncryptedPaymentRequest], and only
+    /// apps using the same seed can decrypt it with [BreezServices::decrypt_payment_request].
+    pub async fn encrypt_payment_request(&self, bundle: PaymentRequestBundle) -> SdkResult<String> {
+        encrypt_bundle(&self.payment_request_key().await?, &bundle)
+    }
 
-        Ok(rsis)
+    /// Decrypts a payment request encrypted by [BreezServices::encrypt_payment_request]
+    pub async fn decrypt_payment_request(
+        &self,
+        data: EncryptedPaymentRequestData,
+    ) -> SdkResult<PaymentRequestBundle> {
+        decrypt_bundle(&self.payment_request_key().await?, &data.blob)
     }
 
-    /// Execute a command directly on the NodeAPI interface.
-    /// Mainly used to debugging.
-    pub async fn execute_dev_command(&self, command: String) -> SdkResult<String> {
-        let dev_cmd_res = DevCommand::from_str(&command);
+    async fn payment_request_key(&self) -> SdkResult<Vec<u8>> {
+        Ok(self
+            .derive_encryption_key(DeriveEncryptionKeyRequest {
+                purpose: PAYMENT_REQUEST_KEY_PURPOSE.to_string(),
+                index: 0,
+            })
+            .await?
+            .key)
+    }
 
-        match dev_cmd_res {
-            Ok(dev_cmd) => match dev_cmd {
-                DevCommand::GenerateDiagnosticData => self.generate_diagnostic_data().await,
-            },
-            Err(_) => Ok(crate::serializer::to_string_pretty(
-                &self.node_api.execute_command(command).await?,
-            )?),
+    /// Retrieve the node up to date BackupStatus
+    pub fn backup_status(&self) -> SdkResult<BackupStatus> {
+        let backup_time = self.persister.get_last_backup_time()?;
+        let sync_request = self.persister.get_last_sync_request()?;
+        Ok(BackupStatus {
+            last_backup_time: backup_time,
+            backed_up: sync_request.is_none(),
+        })
+    }
+
+    /// Force running backup
+    pub async fn backup(&self) -> SdkResult<()> {
+        let (on_complete, mut on_complete_receiver) = mpsc::channel::<Result<()>>(1);
+        let req = BackupRequest::with(on_complete, true);
+        self.backup_watcher.request_backup(req).await?;
+
+        match on_complete_receiver.recv().await {
+            Some(res) => res.map_err(|e| SdkError::Generic {
+                err: format!("Backup failed: {e}"),
+            }),
+            None => Err(SdkError::Generic {
+                err: "Backup process failed to complete".into(),
+            }),
         }
     }
 
-    // Collects various user data from the node and the sdk storage.
-    // This is used for debugging and support purposes only.
-    pub async fn generate_diagnostic_data(&self) -> SdkResult<String> {
-        let now_sec = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or_default();
-        let node_data = self
-            .node_api
-            .generate_diagnostic_data()
-            .await
-            .unwrap_or_else(|e| json!({"error": e.to_string()}));
-        let sdk_data = self
-            .generate_sdk_diagnostic_data()
-            .await
-            .unwrap_or_else(|e| json!({"error": e.to_string()}));
-        let result = json!({
-            "timestamp": now_sec,
-            "node": node_data,
-            "sdk": sdk_data
-        });
-        Ok(crate::serializer::to_string_pretty(&result)?)
+    /// Encrypts the backup with the next key derived from the seed from now on, and pushes a
+    /// backup with it.
+    ///
+    /// The backup keys are derived at m/139'/epoch and the epoch is stored in plaintext next to
+    /// each backup, so any app with the same seed, including a fresh restore, derives the key
+    /// of the backup and keeps using it for its next backups.
+    pub async fn rotate_backup_key(&self) -> SdkResult<()> {
+        let epoch = self.persister.get_backup_key_epoch()?;
+        self.persister.set_backup_key_epoch(epoch + 1)?;
+        self.backup().await
     }
 
-    /// This method sync the local state with the remote node state.
-    /// The synced items are as follows:
-    /// * node state - General information about the node and its liquidity status
-    /// * channels - The list of channels and their status
-    /// * payments - The incoming/outgoing payments
-    pub async fn sync(&self) -> SdkResult<()> {
-        Ok(self.do_sync(false).await?)
+    /// Lists the versions of the backup snapshots stored by the backup transport, newest first.
+    ///
+    /// Any of them can be restored with [BreezServices::restore_backup].
+    pub async fn list_backup_versions(&self) -> SdkResult<Vec<u64>> {
+        self.backup_watcher.list_versions().await
     }
 
-    async fn do_sync(&self, match_local_balance: bool) -> Result<()> {
-        let start = Instant::now();
-        let node_pubkey = self.node_api.node_id().await?;
-        self.connect_lsp_peer(node_pubkey).await?;
+    /// Merges a backup snapshot into the local state, and pushes the result as the latest backup.
+    ///
+    /// This is the way to recover from a latest backup which can't be read anymore.
+    pub async fn restore_backup(&self, version: u64) -> SdkResult<()> {
+        let (on_complete, mut on_complete_receiver) = mpsc::channel::<Result<()>>(1);
+        let req = BackupRequest::restore(on_complete, version);
+        self.backup_watcher.request_backup(req).await?;
 
-        // First query the changes since last sync state.
-        let sync_state = self.persister.get_sync_state()?;
-        let new_data = &self
-            .node_api
-            .pull_changed(sync_state.clone(), match_local_balance)
-            .await?;
+        match on_complete_receiver.recv().await {
+            Some(res) => res.map_err(|e| SdkError::Generic {
+                err: format!("Restoring the backup failed: {e}"),
+            }),
+            None => Err(SdkError::Generic {
+                err: "Backup process failed to complete".into(),
+            }),
+        }
+    }
 
-        debug!(
-            "pull changed old state={:?} new state={:?}",
-            sync_state, new_data.sync_state
-        );
+    /// List payments matching the given filters, as retrieved from persistent storage
+    pub async fn list_payments(&self, req: ListPaymentsRequest) -> SdkResult<Vec<Payment>> {
+        Ok(self.persister.list_payments(req)?)
+    }
 
-        // update node state and channels state
-        self.persister.set_node_state(&new_data.node_state)?;
+    /// Aggregates the completed sent payments matching the given filters by counterparty, with
+    /// their totals, the largest total amount first.
+    ///
+    /// This backs views like the top recipients, or the payments of a subscription to a lightning
+    /// address, without going over the whole history in the app.
+    pub async fn list_payment_groups(
+        &self,
+        req: ListPaymentGroupsRequest,
+    ) -> SdkResult<Vec<PaymentGroup>> {
+        let payments = self.persister.list_payments(req.payments)?;
+        Ok(crate::payment_groups::group_payments(
+            payments,
+            req.group_by,
+            req.limit,
+        ))
+    }
 
-        let channels_before_update = self.persister.list_channels()?;
-        self.persister.update_channels(&new_data.channels)?;
-        let channels_after_update = self.persister.list_channels()?;
+    /// Exports the completed payments in the given time range, for example for accounting.
+    ///
+    /// Each payment includes its date, amount, fee and description. If
+    /// [ExportPaymentsRequest::include_fiat] is set, the amounts are also converted to that fiat
+    /// currency, using the rate recorded when the payment settled if it is the
+    /// [Config::fiat_currency], or the current rate otherwise.
+    pub async fn export_payments(&self, req: ExportPaymentsRequest) -> SdkResult<String> {
+        let fiat_rate = match req.include_fiat {
+            Some(currency) => Some(self.export_fiat_rate(currency).await?),
+            None => None,
+        };
+        let payments = self.persister.list_payments(ListPaymentsRequest {
+            from_timestamp: req.from_timestamp,
+            to_timestamp: req.to_timestamp,
+            include_failures: Some(false),
+            ..Default::default()
+        })?;
+        let payments = payments
+            .into_iter()
+            .filter(|p| p.status == PaymentStatus::Complete)
+            .collect();
+        crate::export::export_payments(payments, req.format, fiat_rate)
+    }
 
-        // Fetch the static backup if needed and persist it
-        if channels_before_update.len() != channels_after_update.len() {
-            info!("fetching static backup file from node");
-            let backup = self.node_api.static_backup().await?;
-            self.persister.set_static_backup(backup)?;
-        }
+    /// Imports payments made outside of this node, for example exported from another wallet,
+    /// so they show up in the payment history. The imported payments have [Payment::imported]
+    /// set and are not synced with the node.
+    ///
+    /// See [ImportPaymentsRequest] for the supported format. Payments with the id of an
+    /// existing payment are skipped, so importing the same data again has no effect.
+    pub async fn import_payments(
+        &self,
+        req: ImportPaymentsRequest,
+    ) -> SdkResult<ImportPaymentsResponse> {
+        let payments = crate::export::import_payments(&req.data, req.format)?;
+        let imported_count = self.persister.insert_imported_payments(&payments)?;
+        Ok(ImportPaymentsResponse {
+            imported_count: imported_count as u32,
+            skipped_count: (payments.len() - imported_count) as u32,
+        })
+    }
 
-        //fetch closed_channel and convert them to Payment items.
-        let mut closed_channel_payments: Vec<Payment> = vec![];
-        for closed_channel in
-            self.persister.list_channels()?.into_iter().filter(|c| {
-                c.state == ChannelState::Closed || c.state == ChannelState::PendingClose
-            })
-        {
-            let closed_channel_tx = self.closed_channel_to_transaction(closed_channel).await?;
-            closed_channel_payments.push(closed_channel_tx);
-        }
+    #[cfg(feature = "fiat")]
+    async fn export_fiat_rate(&self, currency: String) -> SdkResult<crate::export::FiatRate> {
+        let rate = self
+            .fiat_api
+            .fetch_fiat_rates()
+            .await?
+            .into_iter()
+            .find(|r| r.coin == currency)
+            .ok_or(SdkError::Generic {
+                err: format!("No exchange rate found for {currency}"),
+            })?;
+        Ok(crate::export::FiatRate {
+            currency,
+            value: rate.value,
+        })
+    }
 
-        // update both closed channels and lightning transaction payments
-        let mut payments = closed_channel_payments;
-        payments.extend(new_data.payments.clone());
-        self.persister.delete_pseudo_payments()?;
-        self.persister.insert_or_update_payments(&payments, false)?;
-        let duration = start.elapsed();
-        info!("Sync duration: {:?}", duration);
+    #[cfg(not(feature = "fiat"))]
+    async fn export_fiat_rate(&self, _currency: String) -> SdkResult<crate::export::FiatRate> {
+        Err(SdkError::generic("Fiat values require the fiat feature"))
+    }
 
-        // update the cached sync state
-        self.persister.set_sync_state(&new_data.sync_state)?;
-        self.notify_event_listeners(BreezEvent::Synced).await?;
-        Ok(())
+    /// Lists the fees charged by the LSP to open the channels the received payments required,
+    /// the most recent first.
+    ///
+    /// The fee of each payment is also available in [LnPaymentDetails::channel_opening_fee_msat].
+    pub async fn list_channel_fees_paid(&self) -> SdkResult<Vec<ChannelOpeningFee>> {
+        Ok(self.persister.list_channel_opening_fees()?)
     }
 
-    /// Connects to the selected LSP peer.
-    /// This validates if the selected LSP is still in [`list_lsps`].
-    /// If not or no LSP is selected, it selects the first LSP in [`list_lsps`].
-    async fn connect_lsp_peer(&self, node_pubkey: String) -> SdkResult<()> {
-        let lsps = self.lsp_api.list_lsps(node_pubkey).await?;
-        let lsp = match self
+    /// Fetch a specific payment by its hash.
+    pub async fn payment_by_hash(&self, hash: String) -> SdkResult<Option<Payment>> {
+        Ok(self.persister.get_payment_by_hash(&hash)?)
+    }
+
+    /// Set the external metadata of a payment as a valid JSON string
+    pub async fn set_payment_metadata(&self, hash: String, metadata: String) -> SdkResult<()> {
+        Ok(self
             .persister
-            .get_lsp_id()?
-            .and_then(|lsp_id| lsps.iter().find(|lsp| lsp.id == lsp_id))
-            .or_else(|| lsps.first())
-        {
-            Some(lsp) => lsp.clone(),
-            None => return Ok(()),
-        };
+            .set_payment_external_metadata(hash, metadata)?)
+    }
 
-        self.persister.set_lsp(lsp.id, Some(lsp.pubkey.clone()))?;
-        let node_state = match self.node_info() {
-            Ok(node_state) => node_state,
-            Err(_) => return Ok(()),
+    /// Redeem on-chain funds from closed channels to the specified on-chain address, with the given feerate
+    ///
+    /// If [RedeemOnchainFundsRequest::amount_sat] is set, only this amount is sent and the change
+    /// goes back to the wallet. Otherwise all the on-chain funds are sent.
+    pub async fn redeem_onchain_funds(
+        &self,
+        req: RedeemOnchainFundsRequest,
+    ) -> RedeemOnchainResult<RedeemOnchainFundsResponse> {
+        Self::validate_redeem_amount(req.amount_sat)?;
+        let amount_sat = req.amount_sat.map(Sat::sat);
+        let txid = self
+            .node_api
+            .redeem_onchain_funds(req.to_address.clone(), req.sat_per_vbyte, amount_sat)
+            .await?;
+        // Recorded for [BreezServices::bump_fee]
+        let redeem_tx = RedeemTx {
+            txid: hex::encode(&txid),
+            to_address: req.to_address,
+            amount_sat,
+            sat_per_vbyte: req.sat_per_vbyte,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs() as i64,
         };
-
-        let node_id = lsp.pubkey;
-        let address = lsp.host;
-        let lsp_connected = node_state
-            .connected_peers
-            .iter()
-            .any(|e| e == node_id.as_str());
-        if !lsp_connected {
-            debug!("connecting to lsp {}@{}", node_id.clone(), address.clone());
-            self.node_api
-                .connect_peer(node_id.clone(), address.clone())
-                .await
-                .map_err(|e| SdkError::ServiceConnectivity {
-                    err: format!("(LSP: {node_id}) Failed to connect: {e}"),
-                })?;
-            debug!("connected to lsp {node_id}@{address}");
+        if let Err(e) = self.persister.insert_redeem_tx(&redeem_tx) {
+            warn!("Failed to record the redeem transaction: {e}");
         }
-
-        Ok(())
+        self.do_sync(false).await?;
+        Ok(RedeemOnchainFundsResponse { txid })
     }
 
-    fn persist_pending_payment(
-        &self,
-        invoice: &LNInvoice,
-        amount_msat: u64,
-        label: Option<String>,
-    ) -> Result<(), SendPaymentError> {
-        self.persister.insert_or_update_payments(
-            &[Payment {
-                id: invoice.payment_hash.clone(),
-                payment_type: PaymentType::Sent,
-                payment_time: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
-                amount_msat,
-                fee_msat: 0,
-                status: PaymentStatus::Pending,
-                error: None,
-                description: invoice.description.clone(),
-                details: PaymentDetails::Ln {
-                    data: LnPaymentDetails {
-                        payment_hash: invoice.payment_hash.clone(),
-                        label: label.unwrap_or_default(),
-                        destination_pubkey: invoice.payee_pubkey.clone(),
-                        payment_preimage: String::new(),
-                        keysend: false,
-                        bolt11: invoice.bolt11.clone(),
-                        lnurl_success_action: None,
-                        lnurl_pay_domain: None,
-                        lnurl_pay_comment: None,
-                        ln_address: None,
-                        lnurl_metadata: None,
-                        lnurl_withdraw_endpoint: None,
-                        swap_info: None,
-                        reverse_swap_info: None,
-                        pending_expiration_block: None,
-                        open_channel_bolt11: None,
-                    },
-                },
-                metadata: None,
-            }],
-            true,
-        )?;
+    /// Replaces an unconfirmed transaction by one paying a higher fee rate (RBF), when it is stuck
+    /// in the mempool
+    ///
+    /// Supported are the transactions of [BreezServices::redeem_onchain_funds] and
+    /// [BreezServices::refund]. The replacement pays the same destination, and can be bumped
+    /// again, while the replaced transaction can't.
+    pub async fn bump_fee(&self, req: BumpFeeRequest) -> SdkResult<BumpFeeResponse> {
+        if let Some(txid) = self.persister.get_tx_replacement(&req.txid)? {
+            return Err(SdkError::Generic {
+                err: format!("The transaction was already replaced by {txid}"),
+            });
+        }
 
-        self.persister.insert_payment_external_info(
-            &invoice.payment_hash,
-            PaymentExternalInfo {
-                lnurl_pay_success_action: None,
-                lnurl_pay_domain: None,
-                lnurl_pay_comment: None,
-                lnurl_metadata: None,
-                ln_address: None,
-                lnurl_withdraw_endpoint: None,
-                attempted_amount_msat: invoice.amount_msat.map_or(Some(amount_msat), |_| None),
-                attempted_error: None,
-            },
+        let txid = match self.persister.get_redeem_tx(&req.txid)? {
+            Some(redeem_tx) => {
+                ensure_sdk!(
+                    req.sat_per_vbyte > redeem_tx.sat_per_vbyte,
+                    SdkError::Generic {
+                        err: format!(
+                            "The fee rate must be higher than the current {} sat/vbyte",
+                            redeem_tx.sat_per_vbyte
+                        )
+                    }
+                );
+                let txid = self
+                    .node_api
+                    .bump_redeem_onchain_funds(
+                        hex::decode(&req.txid).map_err(|_| SdkError::generic("Invalid txid"))?,
+                        redeem_tx.to_address.clone(),
+                        req.sat_per_vbyte,
+                        redeem_tx.amount_sat,
+                    )
+                    .await?;
+                let txid = hex::encode(txid);
+                self.persister.insert_redeem_tx(&RedeemTx {
+                    txid: txid.clone(),
+                    sat_per_vbyte: req.sat_per_vbyte,
+                    created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+                    ..redeem_tx
+                })?;
+                self.do_sync(false).await?;
+                txid
+            }
+            None => {
+                self.btc_receive_swapper
+                    .bump_refund_fee(&req.txid, req.sat_per_vbyte)
+                    .await?
+                    .refund_tx_id
+            }
+        };
+
+        self.persister.insert_tx_replacement(
+            &req.txid,
+            &txid,
+            req.sat_per_vbyte,
+            SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
         )?;
-        Ok(())
+        Ok(BumpFeeResponse { txid })
     }
 
-    async fn on_payment_completed(
+    /// Estimate the fee, the weight and the change of a [BreezServices::redeem_onchain_funds] transaction
+    pub async fn prepare_redeem_onchain_funds(
         &self,
-        node_id: String,
-        invoice: Option<LNInvoice>,
-        label: Option<String>,
-        payment_res: Result<Payment, SendPaymentError>,
-    ) -> Result<Payment, SendPaymentError> {
-        self.do_sync(false).await?;
-        match payment_res {
-            Ok(payment) => {
-                self.notify_event_listeners(BreezEvent::PaymentSucceed {
-                    details: payment.clone(),
-                })
-                .await?;
-                Ok(payment)
-            }
-            Err(e) => {
-                if let Some(invoice) = invoice.clone() {
-                    self.persister.update_payment_attempted_error(
-                        &invoice.payment_hash,
-                        Some(e.to_string()),
-                    )?;
-                }
-                self.notify_event_listeners(BreezEvent::PaymentFailed {
-                    details: PaymentFailedData {
-                        error: e.to_string(),
-                        node_id,
-                        invoice,
-                        label,
-                    },
-                })
-                .await?;
-                Err(e)
-            }
-        }
+        req: PrepareRedeemOnchainFundsRequest,
+    ) -> RedeemOnchainResult<PrepareRedeemOnchainFundsResponse> {
+        Self::validate_redeem_amount(req.amount_sat)?;
+        let response = self.node_api.prepare_redeem_onchain_funds(req).await?;
+        Ok(response)
     }
 
-    async fn on_event(&self, e: BreezEvent) -> Result<()> {
-        debug!("breez services got event {:?}", e);
-        self.notify_event_listeners(e.clone()).await
+    fn validate_redeem_amount(amount_sat: Option<Sat>) -> RedeemOnchainResult<()> {
+        match amount_sat {
+            Some(Sat::ZERO) => Err(RedeemOnchainError::Generic {
+                err: "The amount to redeem must be more than 0".to_string(),
+            }),
+            _ => Ok(()),
+        }
     }
 
-    async fn notify_event_listeners(&self, e: BreezEvent) -> Result<()> {
-        if let Err(err) = self.btc_receive_swapper.on_event(e.clone()).await {
-            debug!(
-                "btc_receive_swapper failed to process event {:?}: {:?}",
-                e, err
-            )
-        };
-        if let Err(err) = self.btc_send_swapper.on_event(e.clone()).await {
-            debug!(
-                "btc_send_swapper failed to process event {:?}: {:?}",
-                e, err
-            )
-        };
+    /// Fetch live rates of fiat currencies, sorted by name
+    ///
+    /// The fetched rates are recorded, see [BreezServices::fiat_rate_history], and checked
+    /// against the price alerts, see [BreezServices::add_fiat_rate_alert].
+    #[cfg(feature = "fiat")]
+    pub async fn fetch_fiat_rates(&self) -> SdkResult<Vec<Rate>> {
+        let rates = self.fiat_api.fetch_fiat_rates().await?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        if let Err(e) = self.persister.insert_fiat_rates(&rates, now) {
+            warn!("Failed to record the fiat rates: {e}");
+        }
+        if let Err(e) = self.check_fiat_rate_alerts(&rates, now).await {
+            warn!("Failed to check the fiat rate alerts: {e}");
+        }
+        Ok(rates)
+    }
 
-        if self.event_listener.is_some() {
-            self.event_listener.as_ref().unwrap().on_event(e.clone())
+    /// Registers a price alert. [BreezEvent::FiatRateAlertTriggered] is emitted when the
+    /// exchange rate of the currency, checked every time the rates are fetched with
+    /// [BreezServices::fetch_fiat_rates], crosses the threshold in the given direction. The alert
+    /// triggers again once the rate moved back and crossed the threshold another time.
+    #[cfg(feature = "fiat")]
+    pub async fn add_fiat_rate_alert(
+        &self,
+        req: AddFiatRateAlertRequest,
+    ) -> SdkResult<FiatRateAlert> {
+        if req.currency.is_empty() {
+            return Err(SdkError::generic("The currency must be set"));
         }
-        Ok(())
+        if !req.threshold.is_finite() || req.threshold <= 0.0 {
+            return Err(SdkError::generic("The threshold must be more than 0"));
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let id = self.persister.insert_fiat_rate_alert(&req, now)?;
+        Ok(FiatRateAlert {
+            id,
+            currency: req.currency,
+            direction: req.direction,
+            threshold: req.threshold,
+            created_at: now,
+            triggered: false,
+            last_triggered_at: None,
+        })
     }
 
-    /// Convenience method to look up LSP info based on current LSP ID
-    pub async fn lsp_info(&self) -> SdkResult<LspInformation> {
-        get_lsp(self.persister.clone(), self.lsp_api.clone()).await
+    /// Lists the price alerts registered with [BreezServices::add_fiat_rate_alert]
+    #[cfg(feature = "fiat")]
+    pub async fn list_fiat_rate_alerts(&self) -> SdkResult<Vec<FiatRateAlert>> {
+        Ok(self.persister.list_fiat_rate_alerts()?)
     }
 
-    /// Get the recommended fees for onchain transactions
-    pub async fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
-        self.chain_service.recommended_fees().await
+    /// Removes a price alert registered with [BreezServices::add_fiat_rate_alert]
+    #[cfg(feature = "fiat")]
+    pub async fn remove_fiat_rate_alert(&self, id: i64) -> SdkResult<()> {
+        match self.persister.delete_fiat_rate_alert(id)? {
+            true => Ok(()),
+            false => Err(SdkError::generic(&format!(
+                "Fiat rate alert {id} not found"
+            ))),
+        }
     }
 
-    /// Get the full default config for a specific environment type
-    pub fn default_config(
-        env_type: EnvironmentType,
-        api_key: String,
-        node_config: NodeConfig,
-    ) -> Config {
-        match env_type {
-            EnvironmentType::Production => Config::production(api_key, node_config),
-            EnvironmentType::Staging => Config::staging(api_key, node_config),
+    #[cfg(feature = "fiat")]
+    async fn check_fiat_rate_alerts(&self, rates: &[Rate], now: i64) -> Result<()> {
+        let alerts = self.persister.list_fiat_rate_alerts()?;
+        for (alert, rate) in fiat_rate_alert_changes(&alerts, rates) {
+            self.persister
+                .set_fiat_rate_alert_triggered(alert.id, alert.triggered, now)?;
+            if alert.triggered {
+                let alert = FiatRateAlert {
+                    last_triggered_at: Some(now),
+                    ..alert
+                };
+                self.notify_event_listeners(BreezEvent::FiatRateAlertTriggered {
+                    details: FiatRateAlertTriggeredDetails { alert, rate },
+                })
+                .await?;
+            }
         }
+        Ok(())
     }
 
-    /// Get the static backup data from the persistent storage.
-    /// This data enables the user to recover the node in an external core ligntning node.
-    /// See here for instructions on how to recover using this data: <https://docs.corelightning.org/docs/backup-and-recovery#backing-up-using-static-channel-backup>
-    pub fn static_backup(req: StaticBackupRequest) -> SdkResult<StaticBackupResponse> {
-        let storage = SqliteStorage::new(req.working_dir);
-        Ok(StaticBackupResponse {
-            backup: storage.get_static_backup()?,
-        })
+    /// Lists the recorded exchange rates of the fiat currency in the given time range, oldest
+    /// first. The rates are recorded every time they are fetched, including when a payment
+    /// settles if [Config::fiat_currency] is set.
+    #[cfg(feature = "fiat")]
+    pub async fn fiat_rate_history(
+        &self,
+        currency: String,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> SdkResult<Vec<HistoricalRate>> {
+        Ok(self
+            .persister
+            .list_fiat_rates(&currency, from_timestamp, to_timestamp)?)
     }
 
-    /// Fetches the service health check from the support API.
-    pub async fn service_health_check(api_key: String) -> SdkResult<ServiceHealthCheckResponse> {
-        let support_api: Arc<dyn SupportAPI> = Arc::new(BreezServer::new(
-            PRODUCTION_BREEZSERVER_URL.to_string(),
-            Some(api_key),
-        )?);
+    /// List all supported fiat currencies for which there is a known exchange rate.
+    /// List is sorted by the canonical name of the currency
+    #[cfg(feature = "fiat")]
+    pub async fn list_fiat_currencies(&self) -> SdkResult<Vec<FiatCurrency>> {
+        self.fiat_api
+            .list_fiat_currencies()
+            .await
+            .map_err(Into::into)
+    }
 
-        support_api.service_health_check().await
+    /// List available LSPs that can be selected by the user
+    pub async fn list_lsps(&self) -> SdkResult<Vec<LspInformation>> {
+        self.lsp_api.list_lsps(self.node_info()?.id).await
     }
 
-    /// Generates an url that can be used by a third part provider to buy Bitcoin with fiat currency.
-    ///
-    /// A user-selected [OpeningFeeParams] can be optionally set in the argument. If set, and the
-    /// operation requires a new channel, the SDK will try to use the given fee params.
-    pub async fn buy_bitcoin(
-        &self,
-        req: BuyBitcoinRequest,
-    ) -> Result<BuyBitcoinResponse, ReceiveOnchainError> {
-        let swap_info = self
-            .receive_onchain(ReceiveOnchainRequest {
-                opening_fee_params: req.opening_fee_params,
-            })
-            .await?;
-        let url = self
-            .buy_bitcoin_api
-            .buy_bitcoin(req.provider, &swap_info, req.redirect_url)
-            .await?;
-
-        Ok(BuyBitcoinResponse {
-            url,
-            opening_fee_params: swap_info.channel_opening_fees,
-        })
-    }
-
-    /// Starts the BreezServices background threads.
-    ///
-    /// Internal method. Should only be used as part of [BreezServices::start]
-    async fn start_background_tasks(self: &Arc<BreezServices>) -> SdkResult<()> {
-        // start the signer
-        let (shutdown_signer_sender, signer_signer_receiver) = watch::channel(());
-        self.start_signer(signer_signer_receiver).await;
-        self.start_node_keep_alive(self.shutdown_sender.subscribe())
-            .await;
-
-        // Sync node state
-        match self.persister.get_node_state()? {
-            Some(node) => {
-                info!("Starting existing node {}", node.id);
-                self.connect_lsp_peer(node.id).await?;
-            }
+    /// Select the LSP to be used and provide inbound liquidity
+    pub async fn connect_lsp(&self, lsp_id: String) -> SdkResult<()> {
+        let lsp_pubkey = match self.list_lsps().await?.iter().find(|lsp| lsp.id == lsp_id) {
+            Some(lsp) => lsp.pubkey.clone(),
             None => {
-                // In case it is a first run we sync in foreground to get the node state.
-                info!("First run, syncing in foreground");
-                self.sync().await?;
-                info!("First run, finished running syncing in foreground");
+                return Err(SdkError::Generic {
+                    err: format!("Unknown LSP: {lsp_id}"),
+                })
             }
-        }
+        };
 
-        // start backup watcher
-        self.start_backup_watcher().await?;
+        self.persister.set_lsp(lsp_id, Some(lsp_pubkey))?;
+        self.do_sync(false).await?;
+        if let Some(webhook_url) = self.persister.get_webhook_url()? {
+            self.register_payment_notifications(webhook_url).await?
+        }
+        Ok(())
+    }
 
-        //track backup events
-        self.track_backup_events().await;
+    /// Get the current LSP's ID
+    pub async fn lsp_id(&self) -> SdkResult<Option<String>> {
+        Ok(self.persister.get_lsp_id()?)
+    }
 
-        //track swap events
-        self.track_swap_events().await;
+    /// Get the [UserSettings]
+    pub fn user_settings(&self) -> SdkResult<UserSettings> {
+        Ok(self.persister.get_user_settings()?)
+    }
 
-        // track paid invoices
-        self.track_invoices().await;
+    /// Replace the [UserSettings]. They are included in the next backup, and so synced to the
+    /// other devices using this wallet.
+    pub fn set_user_settings(&self, settings: UserSettings) -> SdkResult<()> {
+        Ok(self.persister.set_user_settings(&settings)?)
+    }
 
-        // track new blocks
-        self.track_new_blocks().await;
+    /// Convenience method to look up [LspInformation] for a given LSP ID
+    pub async fn fetch_lsp_info(&self, id: String) -> SdkResult<Option<LspInformation>> {
+        get_lsp_by_id(self.persister.clone(), self.lsp_api.clone(), id.as_str()).await
+    }
 
-        // track logs
-        self.track_logs().await;
+    /// Gets the fees required to open a channel for a given amount.
+    /// If no channel is needed, returns 0. If a channel is needed, returns the required opening fees.
+    pub async fn open_channel_fee(
+        &self,
+        req: OpenChannelFeeRequest,
+    ) -> SdkResult<OpenChannelFeeResponse> {
+        let lsp_info = self.lsp_info().await?;
+        let fee_params = lsp_info
+            .cheapest_open_channel_fee(req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS))?
+            .clone();
 
-        // Stop signer on shutdown
-        let mut shutdown_receiver = self.shutdown_sender.subscribe();
-        tokio::spawn(async move {
-            _ = shutdown_receiver.changed().await;
-            _ = shutdown_signer_sender.send(());
-            debug!("Received the signal to exit signer");
+        let node_state = self.node_info()?;
+        let fee_msat = req.amount_msat.map(|MilliSat(req_amount_msat)| {
+            MilliSat(
+                match node_state.max_receivable_single_payment_amount_msat >= req_amount_msat {
+                    // In case we have enough inbound liquidity we return zero fee.
+                    true => 0,
+                    // Otherwise we need to calculate the fee for opening a new channel.
+                    false => fee_params.get_channel_fees_msat_for(req_amount_msat),
+                },
+            )
         });
 
-        self.init_chainservice_urls().await?;
+        Ok(OpenChannelFeeResponse {
+            fee_msat,
+            fee_params,
+        })
+    }
 
-        Ok(())
+    /// Close all channels with the current LSP.
+    ///
+    /// Should be called  when the user wants to close all the channels.
+    pub async fn close_lsp_channels(&self) -> SdkResult<Vec<String>> {
+        let lsp = self.lsp_info().await?;
+        let tx_ids = self.node_api.close_peer_channels(lsp.pubkey).await?;
+        self.do_sync(false).await?;
+        Ok(tx_ids)
     }
 
-    async fn start_signer(self: &Arc<BreezServices>, mut shutdown_receiver: watch::Receiver<()>) {
-        let node_api = self.node_api.clone();
+    /// Lists the channels of the node that are not yet closed, with their balances and peer.
+    ///
+    /// Closed channels are reported as [PaymentType::ClosedChannel] payments instead.
+    pub async fn list_channels(&self) -> SdkResult<Vec<ChannelDetails>> {
+        Ok(self.node_api.list_channels().await?)
+    }
 
-        tokio::spawn(async move {
-            loop {
-                let (tx, rx) = mpsc::channel(1);
-                let is_shutdown = tokio::select! {
-                    _ = node_api.start_signer(rx) => {
-                        tokio::time::sleep(Duration::from_secs(1)).await;
-                        false
-                    }
+    /// Closes a single channel, unlike [BreezServices::close_lsp_channels] which closes all the
+    /// channels with the LSP.
+    pub async fn close_channel(&self, req: CloseChannelRequest) -> SdkResult<CloseChannelResponse> {
+        ensure_sdk!(
+            self.node_api
+                .list_channels()
+                .await?
+                .iter()
+                .any(|c| c.channel_id == req.channel_id),
+            SdkError::generic("Channel not found")
+        );
+        let closing_txid = self
+            .node_api
+            .close_channel(req.channel_id, req.force)
+            .await?;
+        self.do_sync(false).await?;
+        Ok(CloseChannelResponse { closing_txid })
+    }
 
-                    _ = shutdown_receiver.changed() => {
-                        true
-                    }
-                };
+    /// Connects to a peer other than the LSP, for example to then open a channel to it with
+    /// [BreezServices::open_channel].
+    pub async fn connect_peer(&self, req: ConnectPeerRequest) -> SdkResult<()> {
+        let (node_id, address) = parse_node_uri(&req.node_uri)?;
+        self.node_api.connect_peer(node_id, address).await?;
+        Ok(())
+    }
 
-                debug!("shutting down signer");
-                drop(tx); // Dropping the sender explicitly to notify the receiver.
+    /// Opens a channel to a peer connected with [BreezServices::connect_peer], funded from the
+    /// onchain balance. Unlike the channels opened by the LSP, the channel starts with all the
+    /// liquidity on the local side, so it can only be used to send at first.
+    ///
+    /// The channel is part of [BreezServices::list_channels] and [BreezServices::node_info] right
+    /// away, and can be used once the funding tx confirms.
+    pub async fn open_channel(&self, req: OpenChannelRequest) -> SdkResult<OpenChannelResponse> {
+        ensure_sdk!(
+            PublicKey::from_str(&req.node_id).is_ok(),
+            SdkError::generic("Invalid node id")
+        );
+        ensure_sdk!(
+            req.amount_sat > Sat::ZERO,
+            SdkError::generic("Channel amount must be greater than 0")
+        );
+        ensure_sdk!(
+            req.amount_sat.to_msat().msat() <= self.node_info()?.onchain_balance_msat,
+            SdkError::generic("Insufficient onchain balance")
+        );
+        let res = self
+            .node_api
+            .open_channel(
+                req.node_id,
+                req.amount_sat.sat(),
+                req.announce,
+                req.sat_per_vbyte,
+            )
+            .await?;
+        self.do_sync(false).await?;
+        Ok(res)
+    }
 
-                if is_shutdown {
-                    return;
+    /// Exports the node state needed to move the wallet from Greenlight to a self-hosted CLN
+    /// node. Only this direction is supported: a Greenlight node can't be created from the
+    /// state of another node, so moving back means sending the funds to a new wallet.
+    ///
+    /// A migration is done in these steps:
+    /// 1. Call this method with [NodeMigrationRequest::close_channels] set, which cooperatively
+    ///    closes the LSP channels and returns the resulting [NodeMigrationState].
+    /// 2. Once the closing transactions confirm, move the onchain funds to the target node with
+    ///    [BreezServices::redeem_onchain_funds].
+    /// 3. Reopen the channels from the target node: connect to
+    ///    [NodeMigrationState::lsp_node_uri] and open a channel of
+    ///    [MigrationChannel::capacity_msat] to each [MigrationChannel::peer_id], for example with
+    ///    the CLN `connect` and `fundchannel` commands. Until then, the target node can only
+    ///    receive through channels the LSP opens to it.
+    ///
+    /// The static backup in the [NodeMigrationState] can be used to recover any channel that
+    /// could not be closed cooperatively, for example with the CLN `recoverchannel` command.
+    pub async fn export_node_migration(
+        &self,
+        req: NodeMigrationRequest,
+    ) -> SdkResult<NodeMigrationState> {
+        self.sync().await?;
+        let node_state = self.node_info()?;
+        let node_channels = self.node_api.list_channels().await?;
+        let channels: Vec<MigrationChannel> = self
+            .persister
+            .list_channels()?
+            .into_iter()
+            .filter(|c| c.state == ChannelState::Opened || c.state == ChannelState::PendingOpen)
+            .map(|c| {
+                let details = node_channels
+                    .iter()
+                    .find(|d| d.funding_txid == c.funding_txid);
+                MigrationChannel {
+                    peer_id: details.map(|d| d.peer_id.clone()),
+                    capacity_msat: details.map(|d| d.capacity_msat),
+                    ..MigrationChannel::from(c)
                 }
+            })
+            .collect();
+        let lsp_node_uri = match self.lsp_info().await {
+            Ok(lsp) => Some(format!("{}@{}", lsp.pubkey, lsp.host)),
+            Err(e) => {
+                warn!("Failed to get the LSP for the node migration: {e}");
+                None
             }
-        });
-    }
+        };
 
-    async fn start_node_keep_alive(
-        self: &Arc<BreezServices>,
-        shutdown_receiver: watch::Receiver<()>,
-    ) {
-        let cloned = self.clone();
-        tokio::spawn(async move {
-            cloned.node_api.start_keep_alive(shutdown_receiver).await;
-        });
+        let static_backup = self.node_api.static_backup().await?;
+        self.persister.set_static_backup(static_backup.clone())?;
+
+        let closing_txids = match req.close_channels && !channels.is_empty() {
+            true => self.close_lsp_channels().await?,
+            false => vec![],
+        };
+        info!(
+            "Exported node migration state with {} channels, closing txids: {:?}",
+            channels.len(),
+            closing_txids
+        );
+
+        Ok(NodeMigrationState {
+            node_id: node_state.id,
+            network: self.config.network,
+            lsp_node_uri,
+            static_backup,
+            channels,
+            closing_txids,
+            channels_balance_msat: node_state.channels_balance_msat,
+            onchain_balance_msat: node_state.onchain_balance_msat,
+            exported_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| SdkError::generic(&e.to_string()))?
+                .as_secs(),
+        })
     }
 
-    async fn start_backup_watcher(self: &Arc<BreezServices>) -> Result<()> {
-        self.backup_watcher
-            .start(self.shutdown_sender.subscribe())
-            .await
-            .map_err(|e| anyhow!("Failed to start backup watcher: {e}"))?;
+    /// Exports a [RecoveryBundle] encrypted to the public key of someone else, for example an
+    /// heir, so they can recover the funds along with the seed of the wallet, handed over
+    /// separately.
+    ///
+    /// The bundle isn't time-locked: the recipient can decrypt it with
+    /// [BreezServices::decrypt_recovery_bundle] as soon as they get it, so when they get it is
+    /// left to the inheritance arrangement.
+    ///
+    /// If [ExportRecoveryBundleRequest::file_path] is set, the file is written again with the new
+    /// static backup each time the channels change, until
+    /// [BreezServices::stop_recovery_bundle_refresh] is called.
+    pub async fn export_recovery_bundle(
+        &self,
+        req: ExportRecoveryBundleRequest,
+    ) -> SdkResult<ExportRecoveryBundleResponse> {
+        let static_backup = self.node_api.static_backup().await?;
+        self.persister.set_static_backup(static_backup.clone())?;
+        let bundle = self.recovery_bundle(&req, static_backup)?;
+        if let Some(file_path) = &req.file_path {
+            write_recovery_bundle(file_path, &bundle)?;
+            self.persister.update_setting(
+                RECOVERY_BUNDLE_SETTING.to_string(),
+                serde_json::to_string(&req)?,
+            )?;
+        }
+        Ok(ExportRecoveryBundleResponse { bundle })
+    }
 
-        // Restore backup state and request backup on start if needed
-        let force_backup = self
+    /// Stops refreshing the file of the last [BreezServices::export_recovery_bundle]. The file
+    /// is left as is.
+    pub fn stop_recovery_bundle_refresh(&self) -> SdkResult<()> {
+        Ok(self
             .persister
-            .get_last_sync_version()
-            .map_err(|e| anyhow!("Failed to read last sync version: {e}"))?
-            .is_none();
-        self.backup_watcher
-            .request_backup(BackupRequest::new(force_backup))
-            .await
-            .map_err(|e| anyhow!("Failed to request backup: {e}"))
+            .delete_setting(RECOVERY_BUNDLE_SETTING.to_string())?)
     }
 
-    async fn track_backup_events(self: &Arc<BreezServices>) {
-        let cloned = self.clone();
-        tokio::spawn(async move {
-            let mut events_stream = cloned.backup_watcher.subscribe_events();
-            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
-            loop {
-                tokio::select! {
-                    backup_event = events_stream.recv() => {
-                        if let Ok(e) = backup_event {
-                            if let Err(err) = cloned.notify_event_listeners(e).await {
-                                error!("error handling backup event: {:?}", err);
-                            }
-                        }
-                        let backup_status = cloned.backup_status();
-                        info!("backup status: {:?}", backup_status);
-                    },
-                    _ = shutdown_receiver.changed() => {
-                        debug!("Backup watcher task completed");
-                        break;
-                    }
-                }
-            }
-        });
+    /// Decrypts a bundle of [BreezServices::export_recovery_bundle] with the private key of the
+    /// recipient. It doesn't need a wallet.
+    pub fn decrypt_recovery_bundle(req: DecryptRecoveryBundleRequest) -> SdkResult<RecoveryBundle> {
+        let decrypted = crate::crypt::decrypt(req.private_key, req.bundle)
+            .map_err(|e| SdkError::generic(&format!("Failed to decrypt the bundle: {e}")))?;
+        Ok(serde_json::from_slice(&decrypted)?)
     }
 
-    async fn track_swap_events(self: &Arc<BreezServices>) {
-        let cloned = self.clone();
-        tokio::spawn(async move {
-            let mut swap_events_stream = cloned.btc_receive_swapper.subscribe_status_changes();
-            let mut rev_swap_events_stream = cloned.btc_send_swapper.subscribe_status_changes();
-            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
-            loop {
-                tokio::select! {
-                    swap_event = swap_events_stream.recv() => {
-                        if let Ok(e) = swap_event {
-                            if let Err(err) = cloned.notify_event_listeners(e).await {
-                                error!("error handling swap event: {:?}", err);
-                            }
-                        }
-                    },
-                    rev_swap_event = rev_swap_events_stream.recv() => {
-                        if let Ok(e) = rev_swap_event {
-                            if let Err(err) = cloned.notify_event_listeners(e).await {
-                                error!("error handling reverse swap event: {:?}", err);
-                            }
-                        }
-                    },
-                    _ = shutdown_receiver.changed() => {
-                        debug!("Swap events handling task completed");
-                        break;
-                    }
-                }
-            }
-        });
+    /// The [RecoveryBundle] of the request, encrypted to its recipient
+    fn recovery_bundle(
+        &self,
+        req: &ExportRecoveryBundleRequest,
+        static_backup: Vec<String>,
+    ) -> SdkResult<Vec<u8>> {
+        let recipient_pubkey = PublicKey::from_str(&req.recipient_pubkey)
+            .map_err(|e| SdkError::generic(&format!("Invalid recipient public key: {e}")))?;
+        let node_state = self.node_info()?;
+        let channels = self
+            .persister
+            .list_channels()?
+            .into_iter()
+            .filter(|c| c.state == ChannelState::Opened || c.state == ChannelState::PendingOpen)
+            .map(Into::into)
+            .collect();
+        let instructions = match &req.instructions {
+            Some(instructions) => format!("{RECOVERY_INSTRUCTIONS}\n\n{instructions}"),
+            None => RECOVERY_INSTRUCTIONS.to_string(),
+        };
+        let bundle = RecoveryBundle {
+            node_id: node_state.id,
+            network: self.config.network,
+            instructions,
+            static_backup,
+            channels,
+            channels_balance_msat: node_state.channels_balance_msat,
+            onchain_balance_msat: node_state.onchain_balance_msat,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        };
+        Ok(crate::crypt::encrypt(
+            recipient_pubkey.serialize().to_vec(),
+            serde_json::to_vec(&bundle)?,
+        )?)
     }
 
-    async fn track_invoices(self: &Arc<BreezServices>) {
-        let cloned = self.clone();
-        tokio::spawn(async move {
-            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
-            loop {
-                if shutdown_receiver.has_changed().unwrap_or(true) {
-                    return;
-                }
-                let mut invoice_stream = match cloned.node_api.stream_incoming_payments().await {
-                    Ok(invoice_stream) => invoice_stream,
-                    Err(e) => {
-                        warn!("stream incoming payments returned error: {:?}", e);
-                        tokio::select! {
-                            _ = sleep(Duration::from_secs(1)) => {
-                                continue
-                            }
-                            _ = shutdown_receiver.changed() => {
-                                debug!("Invoice tracking task has completed");
-                                return;
-                            }
-                        };
-                    }
-                };
-
-                loop {
-                    let paid_invoice_res = tokio::select! {
-                        paid_invoice_res = invoice_stream.message() => {
-                            paid_invoice_res
-                        }
+    /// Writes the recovery bundle file again, if it is refreshed
+    fn refresh_recovery_bundle(&self, static_backup: Vec<String>) -> SdkResult<()> {
+        let req: ExportRecoveryBundleRequest = match self
+            .persister
+            .get_setting(RECOVERY_BUNDLE_SETTING.to_string())?
+        {
+            Some(req) => serde_json::from_str(&req)?,
+            None => return Ok(()),
+        };
+        if let Some(file_path) = &req.file_path {
+            write_recovery_bundle(file_path, &self.recovery_bundle(&req, static_backup)?)?;
+            info!("Refreshed the recovery bundle at {file_path}");
+        }
+        Ok(())
+    }
 
-                        _ = shutdown_receiver.changed() => {
-                            debug!("Invoice tracking task has completed");
-                            return;
-                        }
-                    };
+    /// Onchain receive swap API
+    ///
+    /// Create and start a new swap. A user-selected [OpeningFeeParams] can be optionally set in the argument.
+    /// If set, and the operation requires a new channel, the SDK will use the given fee params.
+    /// The provided [OpeningFeeParams] need to be valid at the time of swap redeeming.
+    ///
+    /// Since we only allow one in-progress swap this method will return error if there is currently
+    /// a swap waiting for confirmation to be redeemed and by that complete the swap.
+    /// In such case the [BreezServices::in_progress_swap] can be used to query the live swap status.
+    ///
+    /// The returned [SwapInfo] contains the created swap details. The channel opening fees are
+    /// available at [SwapInfo::channel_opening_fees].
+    #[tracing::instrument(skip_all, fields(swap_address = tracing::field::Empty))]
+    pub async fn receive_onchain(
+        &self,
+        req: ReceiveOnchainRequest,
+    ) -> ReceiveOnchainResult<SwapInfo> {
+        if let Some(in_progress) = self.in_progress_swap().await? {
+            return Err(ReceiveOnchainError::SwapInProgress{ err:format!(
+                    "A swap was detected for address {}. Use in_progress_swap method to get the current swap state",
+                    in_progress.bitcoin_address
+                )});
+        }
+        let channel_opening_fees = req.opening_fee_params.unwrap_or(
+            self.lsp_info()
+                .await?
+                .cheapest_open_channel_fee(SWAP_PAYMENT_FEE_EXPIRY_SECONDS)?
+                .clone(),
+        );
 
-                    let i = match paid_invoice_res {
-                        Ok(Some(i)) => i,
-                        Ok(None) => {
-                            debug!("invoice stream got None");
-                            break;
-                        }
-                        Err(err) => {
-                            debug!("invoice stream got error: {:?}", err);
-                            break;
-                        }
-                    };
+        let swap_info = self
+            .btc_receive_swapper
+            .create_swap(channel_opening_fees)
+            .await?;
+        tracing::Span::current().record("swap_address", swap_info.bitcoin_address.as_str());
+        self.metrics.swaps_created.inc();
+        if let Some(webhook_url) = self.persister.get_webhook_url()? {
+            let address = &swap_info.bitcoin_address;
+            info!("Registering for onchain tx notification for address {address}");
+            self.register_onchain_tx_notification(address, &webhook_url)
+                .await?;
+        }
+        Ok(swap_info)
+    }
 
-                    debug!("invoice stream got new invoice");
-                    let p = match i.details {
-                        Some(incoming_payment::Details::Offchain(p)) => p,
-                        _ => continue,
-                    };
+    /// Creates a BIP21 URI that can be paid either onchain, to a new swap address, or over
+    /// lightning, to a new invoice in its `lightning` parameter. Wallets that support such URIs
+    /// pay the invoice, while others pay to the address. Both receive the same amount.
+    ///
+    /// The amount must be within the limits of the swap, see [SwapInfo::min_allowed_deposit]
+    /// and [SwapInfo::max_allowed_deposit]. Above the maximum, the error is a
+    /// [ReceivePaymentError::AmountExceedsMaximum] with [ReceiveLimitReason::MaxSwapDeposit]. As
+    /// with [BreezServices::receive_onchain], this fails if a swap is in progress.
+    pub async fn receive_unified(
+        &self,
+        req: ReceiveUnifiedRequest,
+    ) -> Result<ReceiveUnifiedResponse, ReceivePaymentError> {
+        let swap_info = self
+            .receive_onchain(ReceiveOnchainRequest {
+                opening_fee_params: req.opening_fee_params,
+            })
+            .await?;
+        ensure_sdk!(
+            req.amount_sat.sat() as i64 >= swap_info.min_allowed_deposit,
+            ReceivePaymentError::InvalidAmount {
+                err: format!(
+                    "Amount must be at least {} sat to be received onchain",
+                    swap_info.min_allowed_deposit
+                )
+            }
+        );
+        ensure_sdk!(
+            req.amount_sat.sat() as i64 <= swap_info.max_allowed_deposit,
+            ReceivePaymentError::AmountExceedsMaximum {
+                max_amount_msat: Sat(swap_info.max_allowed_deposit as u64).to_msat().msat(),
+                reason: ReceiveLimitReason::MaxSwapDeposit,
+            }
+        );
 
-                    let mut payment: Option<crate::models::Payment> = p.clone().try_into().ok();
-                    if let Some(ref p) = payment {
-                        let res = cloned
-                            .persister
-                            .insert_or_update_payments(&vec![p.clone()], false);
-                        debug!("paid invoice was added to payments list {res:?}");
-                        if let Ok(Some(mut node_info)) = cloned.persister.get_node_state() {
-                            node_info.channels_balance_msat += p.amount_msat;
-                            let res = cloned.persister.set_node_state(&node_info);
-                            debug!("channel balance was updated {res:?}");
-                        }
-                        payment = cloned
-                            .persister
-                            .get_payment_by_hash(&p.id)
-                            .unwrap_or(payment);
-                    }
-                    _ = cloned
-                        .on_event(BreezEvent::InvoicePaid {
-                            details: InvoicePaidDetails {
-                                payment_hash: hex::encode(p.payment_hash),
-                                bolt11: p.bolt11,
-                                payment,
-                            },
-                        })
-                        .await;
-                    if let Err(e) = cloned.do_sync(true).await {
-                        error!("failed to sync after paid invoice: {:?}", e);
-                    }
-                }
+        let res = self
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: req.amount_sat.to_msat(),
+                description: req.description.clone(),
+                opening_fee_params: swap_info.channel_opening_fees.clone(),
+                ..Default::default()
+            })
+            .await?;
+        Ok(ReceiveUnifiedResponse {
+            bip21: unified_bip21_uri(
+                &swap_info.bitcoin_address,
+                req.amount_sat.sat(),
+                &req.description,
+                &res.ln_invoice.bolt11,
+            ),
+            swap_info,
+            ln_invoice: res.ln_invoice,
+            opening_fee_params: res.opening_fee_params,
+            opening_fee_msat: res.opening_fee_msat,
+        })
+    }
 
-                tokio::select! {
-                    _ = sleep(Duration::from_secs(1)) => {
-                        continue
-                    }
-                    _ = shutdown_receiver.changed() => {
-                        debug!("Invoice tracking task has completed");
-                        return;
-                    }
-                };
-            }
-        });
+    /// Returns an optional in-progress [SwapInfo].
+    /// A [SwapInfo] is in-progress if it is waiting for confirmation to be redeemed and complete the swap.
+    pub async fn in_progress_swap(&self) -> SdkResult<Option<SwapInfo>> {
+        let tip = self.chain_service.current_tip().await?;
+        self.btc_receive_swapper.rescan_monitored_swaps(tip).await?;
+        let in_progress = self.btc_receive_swapper.list_in_progress_swaps()?;
+        if !in_progress.is_empty() {
+            return Ok(Some(in_progress[0].clone()));
+        }
+        Ok(None)
     }
 
-    async fn track_logs(self: &Arc<BreezServices>) {
-        let cloned = self.clone();
-        tokio::spawn(async move {
-            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
-            loop {
-                if shutdown_receiver.has_changed().unwrap_or(true) {
-                    return;
-                }
-                let mut log_stream = match cloned.node_api.stream_log_messages().await {
-                    Ok(log_stream) => log_stream,
-                    Err(e) => {
-                        warn!("stream log messages returned error: {:?}", e);
-                        tokio::select! {
-                            _ = sleep(Duration::from_secs(1)) => {
-                                continue
-                            }
-                            _ = shutdown_receiver.changed() => {
-                                debug!("Invoice tracking task has completed");
-                                return;
-                            }
-                        };
-                    }
-                };
+    /// Iterate all historical swap addresses and fetch their current status from the blockchain.
+    /// The status is then updated in the persistent storage.
+    pub async fn rescan_swaps(&self) -> SdkResult<()> {
+        let tip = self.chain_service.current_tip().await?;
+        self.btc_receive_swapper.rescan_swaps(tip).await?;
+        Ok(())
+    }
 
-                loop {
-                    let log_message_res = tokio::select! {
-                        log_message_res = log_stream.message() => {
-                            log_message_res
-                        }
+    /// Checks that a swap address was derived from the stored swap keys and payment hash, so
+    /// the address shown to the user wasn't substituted. Should be used before sending large
+    /// onchain deposits.
+    ///
+    /// Returns an error if no swap with the given address exists.
+    pub async fn verify_swap_address(&self, swap_address: String) -> SdkResult<bool> {
+        Ok(self
+            .btc_receive_swapper
+            .verify_swap_address(&swap_address)?)
+    }
 
-                        _ = shutdown_receiver.changed() => {
-                            debug!("Track logs task has completed");
-                            return;
-                        }
-                    };
+    /// Redeems an individual swap.
+    ///
+    /// To be used only in the context of mobile notifications, where the notification triggers
+    /// an individual redeem.
+    ///
+    /// This is taken care of automatically in the context of typical SDK usage.
+    #[tracing::instrument(skip_all, fields(swap_address = %swap_address))]
+    pub async fn redeem_swap(&self, swap_address: String) -> SdkResult<()> {
+        let tip = self.chain_service.current_tip().await?;
+        self.btc_receive_swapper
+            .rescan_swap(&swap_address, tip)
+            .await?;
+        self.btc_receive_swapper.redeem_swap(swap_address).await?;
+        Ok(())
+    }
 
-                    match log_message_res {
-                        Ok(Some(l)) => info!("node-logs: {}", l.line),
-                        Ok(None) => {
-                            // stream is closed, renew it
-                            break;
-                        }
-                        Err(err) => {
-                            debug!("failed to process log entry {:?}", err);
-                            break;
-                        }
-                    };
-                }
+    /// Lists current and historical swaps.
+    ///
+    /// Swaps can be filtered based on creation time and status.
+    pub async fn list_swaps(&self, req: ListSwapsRequest) -> SdkResult<Vec<SwapInfo>> {
+        Ok(self.persister.list_swaps(req)?)
+    }
 
-                tokio::select! {
-                    _ = sleep(Duration::from_secs(1)) => {
-                        continue
-                    }
-                    _ = shutdown_receiver.changed() => {
-                        debug!("Invoice tracking task has completed");
-                        return;
-                    }
-                };
-            }
-        });
+    /// Claims an individual reverse swap.
+    ///
+    /// To be used only in the context of mobile notifications, where the notification triggers
+    /// an individual reverse swap to be claimed.
+    ///
+    /// This is taken care of automatically in the context of typical SDK usage.
+    #[tracing::instrument(skip_all, fields(lockup_address = %lockup_address))]
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn claim_reverse_swap(&self, lockup_address: String) -> SdkResult<()> {
+        Ok(self
+            .btc_send_swapper
+            .claim_reverse_swap(lockup_address)
+            .await?)
     }
 
-    async fn track_new_blocks(self: &Arc<BreezServices>) {
-        let cloned = self.clone();
-        tokio::spawn(async move {
-            let mut current_block: u32 = 0;
-            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
-            let mut interval = tokio::time::interval(Duration::from_secs(30));
-            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
-            loop {
-                tokio::select! {
-                    _ = interval.tick() => { }
-
-                    _ = shutdown_receiver.changed() => {
-                        debug!("New blocks task has completed");
-                        return;
-                    }
-                }
+    /// Lookup the reverse swap fees (see [ReverseSwapServiceAPI::fetch_reverse_swap_fees]).
+    ///
+    /// If the request has the `send_amount_sat` set, the returned [ReverseSwapPairInfo] will have
+    /// the total estimated fees for the reverse swap in its `total_estimated_fees`.
+    ///
+    /// If, in addition to that, the request has the `claim_tx_feerate` set as well, then
+    /// - `fees_claim` will have the actual claim transaction fees, instead of an estimate, and
+    /// - `total_estimated_fees` will have the actual total fees for the given parameters
+    ///
+    /// ### Errors
+    ///
+    /// If a `send_amount_sat` is specified in the `req`, but is outside the `min` and `max`,
+    /// this will result in an error. If you are not sure what are the `min` and `max`, please call
+    /// this with `send_amount_sat` as `None` first, then repeat the call with the desired amount.
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn fetch_reverse_swap_fees(
+        &self,
+        req: ReverseSwapFeesRequest,
+    ) -> SdkResult<ReverseSwapPairInfo> {
+        let mut res = self.btc_send_swapper.fetch_reverse_swap_fees().await?;
 
-                let next_block = match cloned.chain_service.current_tip().await {
-                    Ok(next_block) => next_block,
-                    Err(e) => {
-                        error!("failed to fetch next block {}", e);
-                        continue;
-                    }
-                };
+        if let Some(Sat(amt)) = req.send_amount_sat {
+            ensure_sdk!(amt <= res.max, SdkError::generic("Send amount is too high"));
+            ensure_sdk!(amt >= res.min, SdkError::generic("Send amount is too low"));
 
-                debug!("got tip {:?}", next_block);
-                if next_block > current_block {
-                    _ = cloned.sync().await;
-                    _ = cloned
-                        .on_event(BreezEvent::NewBlock { block: next_block })
-                        .await;
-                }
-                current_block = next_block
+            if let Some(claim_tx_feerate) = req.claim_tx_feerate {
+                res.fees_claim = BTCSendSwap::calculate_claim_tx_fee(claim_tx_feerate)?;
             }
-        });
-    }
 
-    async fn init_chainservice_urls(&self) -> Result<()> {
-        let breez_server = Arc::new(BreezServer::new(
-            PRODUCTION_BREEZSERVER_URL.to_string(),
-            None,
-        )?);
-        let persister = &self.persister;
+            let service_fee_sat = swap_out::get_service_fee_sat(amt, res.fees_percentage);
+            res.total_fees = Some(service_fee_sat + res.fees_lockup + res.fees_claim);
+        }
 
-        let cloned_breez_server = breez_server.clone();
-        let cloned_persister = persister.clone();
-        tokio::spawn(async move {
-            match cloned_breez_server.fetch_mempoolspace_urls().await {
-                Ok(fresh_urls) => {
-                    if let Err(e) = cloned_persister.set_mempoolspace_base_urls(fresh_urls) {
-                        error!("Failed to cache mempool.space URLs: {e}");
-                    }
-                }
-                Err(e) => error!("Failed to fetch mempool.space URLs: {e}"),
-            }
-        });
+        Ok(res)
+    }
+    /// Returns the max amount that can be sent on-chain using the send_onchain method.
+    /// The returned amount is the sum of the max amount that can be sent on each channel
+    /// minus the expected fees.
+    /// This is possible since the route to the swapper node is known in advance and is expected
+    /// to consist of maximum 3 hops.
+    #[cfg(feature = "reverse-swaps")]
+    async fn max_reverse_swap_amount(&self) -> SdkResult<u64> {
+        // fetch the last hop hints from the swapper
+        let last_hop = self.btc_send_swapper.last_hop_for_payment().await?;
+        info!("max_reverse_swap_amount last_hop={:?}", last_hop);
+        // calculate the largest payment we can send over this route using maximum 3 hops
+        // as follows:
+        // User Node -> LSP Node -> Routing Node -> Swapper Node
+        let max_to_pay = self
+            .node_api
+            .max_sendable_amount(
+                Some(
+                    hex::decode(&last_hop.src_node_id).map_err(|e| SdkError::Generic {
+                        err: format!("Failed to decode hex node_id: {e}"),
+                    })?,
+                ),
+                swap_out::reverseswap::MAX_PAYMENT_PATH_HOPS,
+                Some(&last_hop),
+            )
+            .await?;
 
-        Ok(())
+        // Sum the max amount per channel and return the result
+        let total: MilliSat = max_to_pay
+            .into_iter()
+            .map(|m| MilliSat(m.amount_msat))
+            .sum();
+        Ok(total.to_sat_floor().sat())
     }
 
-    /// Configures a global SDK logger that will log to file and will forward log events to
-    /// an optional application-specific logger.
-    ///
-    /// If called, it should be called before any SDK methods (for example, before `connect`).
-    ///
-    /// It must be called only once in the application lifecycle. Alternatively, If the application
-    /// already uses a globally-registered logger, this method shouldn't be called at all.
-    ///
-    /// ### Arguments
-    ///
-    /// - `log_dir`: Location where the the SDK log file will be created. The directory must already exist.
-    ///
-    /// - `app_logger`: Optional application logger.
-    ///
-    /// If the application is to use it's own logger, but would also like the SDK to log SDK-specific
-    /// log output to a file in the configured `log_dir`, then do not register the
-    /// app-specific logger as a global logger and instead call this method with the app logger as an arg.
-    ///
-    /// ### Logging Configuration
-    ///
-    /// Setting `breez_sdk_core::input_parser=debug` will include in the logs the raw payloads received
-    /// when interacting with JSON endpoints, for example those used during all LNURL workflows.
-    ///
-    /// ### Errors
+    /// list non-completed expired swaps that should be refunded by calling [BreezServices::refund]
+    pub async fn list_refundables(&self) -> SdkResult<Vec<SwapInfo>> {
+        Ok(self.btc_receive_swapper.list_refundables()?)
+    }
+
+    /// Exports the refund keys of the swaps, so third-party recovery tools can refund expired
+    /// swaps without the SDK.
     ///
-    /// An error is thrown if the log file cannot be created in the working directory.
+    /// The refund key of the n-th swap is derived from the seed at the hardened path
+    /// `m/145'/n'`, so the keys can be exported again when only the seed remains. Only the keys
+    /// of the swaps in the database are exported, unless
+    /// [ExportSwapRefundKeysRequest::include_gap_keys] is set: then all the keys up to the last
+    /// used one are exported, followed by 20 unused ones to cover the swaps missing from it.
+    /// Swaps created before the keys were derived from the seed have random keys, only found in
+    /// their [SwapInfo::private_key].
+    pub async fn export_swap_refund_keys(
+        &self,
+        req: ExportSwapRefundKeysRequest,
+    ) -> SdkResult<Vec<SwapRefundKey>> {
+        Ok(self
+            .btc_receive_swapper
+            .export_refund_keys(req.include_gap_keys.unwrap_or_default())
+            .await?)
+    }
+
+    /// Prepares a refund transaction for a failed/expired swap.
     ///
-    /// An error is thrown if a global logger is already configured.
-    pub fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>) -> Result<()> {
-        let target_log_file = Box::new(
-            OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(format!("{log_dir}/sdk.log"))
-                .map_err(|e| anyhow!("Can't create log file: {e}"))?,
-        );
-        let logger = env_logger::Builder::new()
-            .target(env_logger::Target::Pipe(target_log_file))
-            .parse_filters(
-                r#"
-                info,
-                breez_sdk_core=debug,
-                sdk_common=debug,
-                gl_client=debug,
-                h2=warn,
-                hyper=warn,
-                lightning_signer=warn,
-                reqwest=warn,
-                rustls=warn,
-                rustyline=warn,
-                vls_protocol_signer=warn
-            "#,
-            )
-            .format(|buf, record| {
-                writeln!(
-                    buf,
-                    "[{} {} {}:{}] {}",
-                    Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
-                    record.level(),
-                    record.module_path().unwrap_or("unknown"),
-                    record.line().unwrap_or(0),
-                    record.args()
-                )
-            })
-            .build();
+    /// Can optionally be used before [BreezServices::refund] to know how much fees will be paid
+    /// to perform the refund.
+    pub async fn prepare_refund(
+        &self,
+        req: PrepareRefundRequest,
+    ) -> SdkResult<PrepareRefundResponse> {
+        Ok(self.btc_receive_swapper.prepare_refund(req).await?)
+    }
 
-        let global_logger = GlobalSdkLogger {
-            logger,
-            log_listener: app_logger,
-        };
+    /// Construct and broadcast a refund transaction for a failed/expired swap
+    ///
+    /// Returns the txid of the refund transaction.
+    #[tracing::instrument(skip_all, fields(swap_address = %req.swap_address))]
+    pub async fn refund(&self, req: RefundRequest) -> SdkResult<RefundResponse> {
+        Ok(self.btc_receive_swapper.refund(req).await?)
+    }
 
-        log::set_boxed_logger(Box::new(global_logger))
-            .map_err(|e| anyhow!("Failed to set global logger: {e}"))?;
-        log::set_max_level(LevelFilter::Trace);
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn onchain_payment_limits(&self) -> SdkResult<OnchainPaymentLimitsResponse> {
+        let fee_info = self.btc_send_swapper.fetch_reverse_swap_fees().await?;
+        debug!("Reverse swap pair info: {fee_info:?}");
+        let max_amt_current_channels = self.max_reverse_swap_amount().await?;
+        debug!("Max send amount possible with current channels: {max_amt_current_channels:?}");
 
-        Ok(())
+        Ok(OnchainPaymentLimitsResponse {
+            min_sat: Sat(fee_info.min),
+            max_sat: Sat(fee_info.max),
+            max_payable_sat: Sat(max_amt_current_channels),
+        })
     }
 
-    async fn lookup_chain_service_closing_outspend(
+    /// Supersedes [BreezServices::fetch_reverse_swap_fees]
+    ///
+    /// ### Errors
+    ///
+    /// - `OutOfRange`: This indicates the send amount is outside the range of minimum and maximum
+    ///   values returned by [BreezServices::onchain_payment_limits]. When you get this error, please first call
+    ///   [BreezServices::onchain_payment_limits] to get the new limits, before calling this method again.
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn prepare_onchain_payment(
         &self,
-        channel: crate::models::Channel,
-    ) -> Result<Option<Outspend>> {
-        match channel.funding_outnum {
-            None => Ok(None),
-            Some(outnum) => {
-                // Find the output tx that was used to fund the channel
-                let outspends = self
-                    .chain_service
-                    .transaction_outspends(channel.funding_txid.clone())
-                    .await?;
+        req: PrepareOnchainPaymentRequest,
+    ) -> Result<PrepareOnchainPaymentResponse, SendOnchainError> {
+        let fees_claim = BTCSendSwap::calculate_claim_tx_fee(req.claim_tx_feerate)?;
+        BTCSendSwap::validate_claim_tx_fee(fees_claim)?;
 
-                Ok(outspends.get(outnum as usize).cloned())
+        let fee_info = self.btc_send_swapper.fetch_reverse_swap_fees().await?;
+
+        // Calculate (send_amt, recv_amt) from the inputs and fees
+        let fees_lockup = fee_info.fees_lockup;
+        let p = fee_info.fees_percentage;
+        let (send_amt, recv_amt) = match req.amount_type {
+            SwapAmountType::Send => {
+                let temp_send_amt = req.amount_sat.sat();
+                let service_fees = swap_out::get_service_fee_sat(temp_send_amt, p);
+                let total_fees = service_fees + fees_lockup + fees_claim;
+                ensure_sdk!(
+                    temp_send_amt > total_fees,
+                    SendOnchainError::generic(
+                        "Send amount is not high enough to account for all fees"
+                    )
+                );
+
+                (temp_send_amt, temp_send_amt - total_fees)
             }
-        }
-    }
+            SwapAmountType::Receive => {
+                let temp_recv_amt = req.amount_sat.sat();
+                let send_amt_minus_service_fee = temp_recv_amt + fees_lockup + fees_claim;
+                let temp_send_amt = swap_out::get_invoice_amount_sat(send_amt_minus_service_fee, p);
 
-    /// Chain service lookup of relevant channel closing fields (closed_at, closing_txid).
-    ///
-    /// Should be used sparingly because it involves a network lookup.
-    async fn lookup_channel_closing_data(
-        &self,
-        channel: &crate::models::Channel,
-    ) -> Result<(Option<u64>, Option<String>)> {
-        let maybe_outspend_res = self
-            .lookup_chain_service_closing_outspend(channel.clone())
-            .await;
-        let maybe_outspend: Option<Outspend> = match maybe_outspend_res {
-            Ok(s) => s,
-            Err(e) => {
-                error!("Failed to lookup channel closing data: {:?}", e);
-                None
+                (temp_send_amt, temp_recv_amt)
             }
         };
 
-        let maybe_closed_at = maybe_outspend
-            .clone()
-            .and_then(|outspend| outspend.status)
-            .and_then(|s| s.block_time);
-        let maybe_closing_txid = maybe_outspend.and_then(|outspend| outspend.txid);
+        let is_send_in_range = send_amt >= fee_info.min && send_amt <= fee_info.max;
+        ensure_sdk!(is_send_in_range, SendOnchainError::OutOfRange);
 
-        Ok((maybe_closed_at, maybe_closing_txid))
+        Ok(PrepareOnchainPaymentResponse {
+            fees_hash: fee_info.fees_hash.clone(),
+            fees_percentage: p,
+            fees_lockup,
+            fees_claim,
+            sender_amount_sat: Sat(send_amt),
+            recipient_amount_sat: Sat(recv_amt),
+            total_fees: send_amt - recv_amt,
+        })
     }
 
-    async fn closed_channel_to_transaction(
-        &self,
-        channel: crate::models::Channel,
-    ) -> Result<Payment> {
-        let (payment_time, closing_txid) = match (channel.closed_at, channel.closing_txid.clone()) {
-            (Some(closed_at), Some(closing_txid)) => (closed_at as i64, Some(closing_txid)),
-            (_, _) => {
-                // If any of the two closing-related fields are empty, we look them up and persist them
-                let (maybe_closed_at, maybe_closing_txid) =
-                    self.lookup_channel_closing_data(&channel).await?;
-
-                let processed_closed_at = match maybe_closed_at {
-                    None => {
-                        warn!("Blocktime could not be determined for from closing outspend, defaulting closed_at to epoch time");
-                        SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()
-                    }
-                    Some(block_time) => block_time,
-                };
+    /// Creates a reverse swap and attempts to pay the HODL invoice
+    ///
+    /// The call can be cancelled with [BreezServices::cancel] if a
+    /// [PayOnchainRequest::cancel_token] is set, until the HODL invoice payment starts.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            recipient_address = %req.recipient_address,
+            reverse_swap_id = tracing::field::Empty
+        )
+    )]
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn pay_onchain(
+        &self,
+        req: PayOnchainRequest,
+    ) -> Result<PayOnchainResponse, SendOnchainError> {
+        let cancellation = self.cancellations.register(req.cancel_token.as_deref())?;
+        ensure_sdk!(
+            req.prepare_res.sender_amount_sat > req.prepare_res.recipient_amount_sat,
+            SendOnchainError::generic("Send amount must be bigger than receive amount")
+        );
+        self.btc_send_swapper
+            .validate_recipient_address(&req.recipient_address)?;
 
-                let mut updated_channel = channel.clone();
-                updated_channel.closed_at = Some(processed_closed_at);
-                // If no closing txid found, we persist it as None, so it will be looked-up next time
-                updated_channel.closing_txid.clone_from(&maybe_closing_txid);
-                self.persister.insert_or_update_channel(updated_channel)?;
+        ensure_sdk!(self.in_progress_onchain_payments().await?.is_empty(), SendOnchainError::Generic { err:
+            "You can only start a new one after after the ongoing ones finish. \
+            Use the in_progress_onchain_payments method to get an overview of currently ongoing reverse swaps".into(),
+        });
+        // The HODL invoice of the sender amount is paid, covering the swap fees
+        let amount_msat = req.prepare_res.sender_amount_sat.to_msat().msat();
+        let spend = self.reserve_spend(
+            amount_msat,
+            self.max_routing_fee_msat(amount_msat),
+            Some(&[req.recipient_address.as_str()][..]),
+        )?;
 
-                (processed_closed_at as i64, maybe_closing_txid)
+        let full_rsi = match self
+            .btc_send_swapper
+            .create_reverse_swap(req, &cancellation)
+            .await
+        {
+            Ok(full_rsi) => full_rsi,
+            Err(e @ ReverseSwapError::PaymentPending { .. }) => {
+                if let Some(spend) = spend {
+                    spend.keep();
+                }
+                return Err(e.into());
             }
+            Err(e) => return Err(e.into()),
         };
+        tracing::Span::current().record("reverse_swap_id", full_rsi.id.as_str());
+        if let Some(spend) = spend {
+            spend.keep();
+        }
+        self.metrics.reverse_swaps_created.inc();
+        let reverse_swap_info = self
+            .btc_send_swapper
+            .convert_reverse_swap_info(full_rsi.clone())
+            .await?;
+        self.do_sync(false).await?;
 
-        Ok(Payment {
-            id: channel.funding_txid.clone(),
-            payment_type: PaymentType::ClosedChannel,
-            payment_time,
-            amount_msat: channel.local_balance_msat,
-            fee_msat: 0,
-            status: match channel.state {
-                ChannelState::PendingClose => PaymentStatus::Pending,
-                _ => PaymentStatus::Complete,
-            },
-            description: Some("Closed Channel".to_string()),
-            details: PaymentDetails::ClosedChannel {
-                data: ClosedChannelPaymentDetails {
-                    short_channel_id: channel.short_channel_id,
-                    state: channel.state,
-                    funding_txid: channel.funding_txid,
-                    closing_txid,
-                },
-            },
-            error: None,
-            metadata: None,
-        })
+        if let Some(webhook_url) = self.persister.get_webhook_url()? {
+            let address = &full_rsi
+                .get_lockup_address(self.config.network)?
+                .to_string();
+            info!("Registering for onchain tx notification for address {address}");
+            self.register_onchain_tx_notification(address, &webhook_url)
+                .await?;
+        }
+        Ok(PayOnchainResponse { reverse_swap_info })
     }
 
-    /// Register for webhook callbacks at the given `webhook_url`.
+    /// Pays an on-chain address or BIP21 URI in one step.
     ///
-    /// More specifically, it registers for the following types of callbacks:
-    /// - a payment is received
-    /// - a swap tx is confirmed
+    /// Picks the claim transaction feerate from the `fee_preference`, validates the amount
+    /// against [BreezServices::onchain_payment_limits], then calls
+    /// [BreezServices::prepare_onchain_payment] and [BreezServices::pay_onchain].
     ///
-    /// This method should be called every time the application is started and when the `webhook_url` changes.
-    /// For example, if the `webhook_url` contains a push notification token and the token changes after
-    /// the application was started, then this method should be called to register for callbacks at
-    /// the new correct `webhook_url`. To unregister a webhook call [BreezServices::unregister_webhook].
-    pub async fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
-        info!("Registering for webhook notifications");
-        let is_new_webhook_url = match self.persister.get_webhook_url()? {
-            None => true,
-            Some(cached_webhook_url) => cached_webhook_url != webhook_url,
-        };
-        match is_new_webhook_url {
-            false => debug!("Webhook URL not changed, no need to (re-)register for monitored swap tx notifications"),
-            true => {
-                for swap in self.btc_receive_swapper.list_swaps(ListSwapsRequest {
-                    status: Some(SwapStatus::unexpired()),
-                    ..Default::default()
-                })?
-                {
-                    let swap_address = &swap.bitcoin_address;
-                    info!("Found non-refundable monitored swap with address {swap_address}, registering for onchain tx notifications");
-                    self.register_onchain_tx_notification(swap_address, &webhook_url)
-                        .await?;
+    /// When draining, the send amount is the max amount payable with the current channels,
+    /// capped to the max accepted by the reverse swap service.
+    #[tracing::instrument(skip_all, fields(address = %req.address))]
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn pay_onchain_address(
+        &self,
+        req: PayOnchainAddressRequest,
+    ) -> Result<PayOnchainAddressResponse, SendOnchainError> {
+        let address_data =
+            match parse_with_rest_client(self.rest_client.as_ref(), &req.address, None).await {
+                Ok(InputType::BitcoinAddress { address }) => address,
+                _ => {
+                    return Err(SendOnchainError::InvalidDestinationAddress {
+                        err: format!("Not an on-chain address: {}", req.address),
+                    })
                 }
+            };
 
-                for rev_swap in self
-                    .btc_send_swapper
-                    .list_monitored()
-                    .await?
-                    .iter()
-                {
-                    let lockup_address = &rev_swap.get_lockup_address(self.config.network)?.to_string();
-                    info!("Found monitored reverse swap with address {lockup_address}, registering for onchain tx notifications");
-                    self.register_onchain_tx_notification(lockup_address, &webhook_url)
-                        .await?;
-                }
-            }
-        }
+        let preset = match req.fee_preference {
+            Some(preset) => preset,
+            None => self
+                .persister
+                .get_user_settings()?
+                .default_feerate_preset
+                .unwrap_or(FeeratePreset::Regular),
+        };
+        let claim_tx_feerate = self.recommended_fees().await?.feerate(preset) as u32;
 
-        // Register for LN payment notifications on every call, since these webhook registrations
-        // timeout after 14 days of not being used
-        self.register_payment_notifications(webhook_url.clone())
-            .await?;
+        let limits = self.onchain_payment_limits().await?;
+        let (amount_sat, amount_type) = onchain_address_amount(
+            req.drain,
+            req.amount_sat.or(address_data.amount_sat.map(Sat)),
+            &limits,
+        )?;
 
-        // Only cache the webhook URL if callbacks were successfully registered for it.
-        // If any step above failed, not caching it allows the caller to re-trigger the registrations
-        // by calling the method again
-        self.persister.set_webhook_url(webhook_url)?;
-        Ok(())
-    }
+        let prepare_res = self
+            .prepare_onchain_payment(PrepareOnchainPaymentRequest {
+                amount_sat,
+                amount_type,
+                claim_tx_feerate,
+            })
+            .await?;
+        ensure_sdk!(
+            prepare_res.sender_amount_sat <= limits.max_payable_sat,
+            SendOnchainError::generic("Not enough outbound liquidity to pay the amount and fees")
+        );
 
-    /// Unregister webhook callbacks for the given `webhook_url`.
-    ///
-    /// When called, it unregisters for the following types of callbacks:
-    /// - a payment is received
-    /// - a swap tx is confirmed
-    ///
-    /// This can be called when callbacks are no longer needed or the `webhook_url`
-    /// has changed such that it needs unregistering. For example, the token is valid but the locale changes.
-    /// To register a webhook call [BreezServices::register_webhook].
-    pub async fn unregister_webhook(&self, webhook_url: String) -> SdkResult<()> {
-        info!("Unregistering for webhook notifications");
-        self.unregister_onchain_tx_notifications(&webhook_url)
+        let res = self
+            .pay_onchain(PayOnchainRequest {
+                recipient_address: address_data.address,
+                prepare_res: prepare_res.clone(),
+                cancel_token: None,
+            })
             .await?;
-        self.unregister_payment_notifications(webhook_url).await?;
-        self.persister.remove_webhook_url()?;
-        Ok(())
+        Ok(PayOnchainAddressResponse {
+            prepare_res,
+            claim_tx_feerate,
+            reverse_swap_info: res.reverse_swap_info,
+        })
     }
 
-    /// Registers for lightning payment notifications. When a payment is intercepted by the LSP
-    /// to this node, a callback will be triggered to the `webhook_url`.
-    ///
-    /// Note: these notifications are registered for all LSPs (active and historical) with whom
-    /// we have a channel.
-    async fn register_payment_notifications(&self, webhook_url: String) -> SdkResult<()> {
-        let message = webhook_url.clone();
-        let sign_request = SignMessageRequest { message };
-        let sign_response = self.sign_message(sign_request).await?;
+    /// Returns the blocking [ReverseSwapInfo]s that are in progress.
+    #[cfg(feature = "reverse-swaps")]
+    pub async fn in_progress_onchain_payments(&self) -> SdkResult<Vec<ReverseSwapInfo>> {
+        let full_rsis = self.btc_send_swapper.list_blocking().await?;
 
-        // Attempt register call for all relevant LSPs
-        let mut error_found = false;
-        for lsp_info in get_notification_lsps(
-            self.persister.clone(),
-            self.lsp_api.clone(),
-            self.node_api.clone(),
-        )
-        .await?
-        {
-            let lsp_id = lsp_info.id;
-            let res = self
-                .lsp_api
-                .register_payment_notifications(
-                    lsp_id.clone(),
-                    lsp_info.lsp_pubkey,
-                    webhook_url.clone(),
-                    sign_response.signature.clone(),
-                )
-                .await;
-            if res.is_err() {
-                error_found = true;
-                warn!("Failed to register notifications for LSP {lsp_id}: {res:?}");
-            }
+        let mut rsis = vec![];
+        for full_rsi in full_rsis {
+            let rsi = self
+                .btc_send_swapper
+                .convert_reverse_swap_info(full_rsi)
+                .await?;
+            rsis.push(rsi);
         }
 
-        match error_found {
-            true => Err(SdkError::generic(
-                "Failed to register notifications for at least one LSP, see logs for details",
-            )),
-            false => Ok(()),
-        }
+        Ok(rsis)
     }
 
-    /// Unregisters lightning payment notifications with the current LSP for the `webhook_url`.
-    ///
-    /// Note: these notifications are unregistered for all LSPs (active and historical) with whom
-    /// we have a channel.
-    async fn unregister_payment_notifications(&self, webhook_url: String) -> SdkResult<()> {
-        let message = webhook_url.clone();
-        let sign_request = SignMessageRequest { message };
-        let sign_response = self.sign_message(sign_request).await?;
-
-        // Attempt register call for all relevant LSPs
-        let mut error_found = false;
-        for lsp_info in get_notification_lsps(
-            self.persister.clone(),
-            self.lsp_api.clone(),
-            self.node_api.clone(),
-        )
-        .await?
-        {
-            let lsp_id = lsp_info.id;
-            let res = self
-                .lsp_api
-                .unregister_payment_notifications(
-                    lsp_id.clone(),
-                    lsp_info.lsp_pubkey,
-                    webhook_url.clone(),
-                    sign_response.signature.clone(),
-                )
-                .await;
-            if res.is_err() {
-                error_found = true;
-                warn!("Failed to un-register notifications for LSP {lsp_id}: {res:?}");
-            }
-        }
+    /// Execute a command directly on the NodeAPI interface.
+    /// Mainly used to debugging.
+    pub async fn execute_dev_command(&self, command: String) -> SdkResult<String> {
+        let dev_cmd_res = DevCommand::from_str(&command);
 
-        match error_found {
-            true => Err(SdkError::generic(
-                "Failed to un-register notifications for at least one LSP, see logs for details",
-            )),
-            false => Ok(()),
+        match dev_cmd_res {
+            Ok(dev_cmd) => match dev_cmd {
+                DevCommand::GenerateDiagnosticData => self.generate_diagnostic_data().await,
+            },
+            Err(_) => Ok(crate::serializer::to_string_pretty(
+                &self.node_api.execute_command(command).await?,
+            )?),
         }
     }
 
-    /// Registers for a onchain tx notification. When a new transaction to the specified `address`
-    /// is confirmed, a callback will be triggered to the `webhook_url`.
-    async fn register_onchain_tx_notification(
-        &self,
-        address: &str,
-        webhook_url: &str,
-    ) -> SdkResult<()> {
-        let url = format!("{}/api/v1/register", self.config.chainnotifier_url);
-        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
-        let body = json!({
-            "address": address,
-            "webhook": webhook_url
-        })
-        .to_string();
-        self.rest_client
-            .post(&url, Some(headers), Some(body))
+    // Collects various user data from the node and the sdk storage.
+    // This is used for debugging and support purposes only.
+    pub async fn generate_diagnostic_data(&self) -> SdkResult<String> {
+        let now_sec = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let node_data = self
+            .node_api
+            .generate_diagnostic_data()
             .await
-            .map(|_| ())
-            .map_err(|e| SdkError::ServiceConnectivity {
-                err: format!("Failed to register for tx confirmation notifications: {e}"),
-            })
+            .unwrap_or_else(|e| json!({"error": e.to_string()}));
+        let sdk_data = self
+            .generate_sdk_diagnostic_data()
+            .await
+            .unwrap_or_else(|e| json!({"error": e.to_string()}));
+        let result = json!({
+            "timestamp": now_sec,
+            "node": node_data,
+            "sdk": sdk_data
+        });
+        Ok(crate::serializer::to_string_pretty(&result)?)
     }
 
-    /// Unregisters all onchain tx notifications for the `webhook_url`.
-    async fn unregister_onchain_tx_notifications(&self, webhook_url: &str) -> SdkResult<()> {
-        let url = format!("{}/api/v1/unregister", self.config.chainnotifier_url);
-        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
-        let body = json!({
-            "webhook": webhook_url
-        })
-        .to_string();
-        self.rest_client
-            .post(&url, Some(headers), Some(body))
-            .await
-            .map(|_| ())
-            .map_err(|e| SdkError::ServiceConnectivity {
-                err: format!("Failed to unregister for tx confirmation notifications: {e}"),
-            })
+    /// This method sync the local state with the remote node state.
+    /// The synced items are as follows:
+    /// * node state - General information about the node and its liquidity status
+    /// * channels - The list of channels and their status
+    /// * payments - The incoming/outgoing payments
+    ///
+    /// Calls made while a sync is in progress don't start another one, but wait for it to
+    /// finish and return its result.
+    ///
+    /// The sync can be cancelled with [BreezServices::cancel_sync].
+    pub async fn sync(&self) -> SdkResult<()> {
+        let mut last_sync_result = match self.last_sync_result.try_lock() {
+            Ok(last_sync_result) => last_sync_result,
+            Err(_) => {
+                let last_sync_result = self.last_sync_result.lock().await;
+                if let Some(result) = last_sync_result.clone() {
+                    return result;
+                }
+                // The sync in progress was cancelled before finishing
+                last_sync_result
+            }
+        };
+        *last_sync_result = None;
+        let cancellation = self.sync_cancellations.register(Some(SYNC_CANCEL_TOKEN))?;
+        let start = Instant::now();
+        let result: SdkResult<()> = tokio::select! {
+            result = self.do_sync(false) => result.map_err(Into::into),
+            _ = cancellation.cancelled() => Err(SdkError::generic("Sync cancelled")),
+        };
+        self.metrics.record_sync(start.elapsed(), result.is_ok());
+        *last_sync_result = Some(result.clone());
+        result
     }
 
-    async fn generate_sdk_diagnostic_data(&self) -> SdkResult<Value> {
-        let (sdk_version, sdk_git_hash) = Self::get_sdk_version();
-        let version = format!("SDK v{sdk_version} ({sdk_git_hash})");
-        let state = crate::serializer::value::to_value(&self.persister.get_node_state()?)?;
-        let payments = crate::serializer::value::to_value(
-            &self
-                .persister
-                .list_payments(ListPaymentsRequest::default())?,
-        )?;
-        let channels = crate::serializer::value::to_value(&self.persister.list_channels()?)?;
-        let settings = crate::serializer::value::to_value(&self.persister.list_settings()?)?;
-        let reverse_swaps = crate::serializer::value::to_value(
-            self.persister.list_reverse_swaps().map(sanitize_vec)?,
-        )?;
-        let swaps = crate::serializer::value::to_value(
-            self.btc_receive_swapper
-                .list_swaps(ListSwapsRequest::default())
-                .map(sanitize_vec)?,
-        )?;
-        let lsp_id = crate::serializer::value::to_value(&self.persister.get_lsp_id()?)?;
+    /// Cancels the [BreezServices::sync] in progress, which then fails. The changes it already
+    /// applied are kept and the next sync catches up with the rest. Returns whether a sync was
+    /// in progress.
+    pub async fn cancel_sync(&self) -> SdkResult<bool> {
+        Ok(self.sync_cancellations.cancel(SYNC_CANCEL_TOKEN))
+    }
 
-        let res = json!({
-            "version": version,
-            "node_state": state,
-            "payments": payments,
-            "channels": channels,
-            "settings": settings,
-            "reverse_swaps": reverse_swaps,
-            "swaps": swaps,
-            "lsp_id": lsp_id,
-        });
-        Ok(res)
+    /// A faster [BreezServices::sync], refreshing only the node state, including the balance,
+    /// and the payments that changed since the last sync.
+    ///
+    /// The channels, closed channel payments and static backup are left to the next full sync.
+    /// It emits [BreezEvent::Synced] once the changes are applied.
+    pub async fn sync_partial(&self) -> SdkResult<()> {
+        // Wait for a full sync in progress, and have the callers waiting on this one run their
+        // own full sync
+        let mut last_sync_result = self.last_sync_result.lock().await;
+        *last_sync_result = None;
+        self.do_partial_sync().await.map_err(Into::into)
     }
-}
 
-struct GlobalSdkLogger {
-    /// SDK internal logger, which logs to file
-    logger: env_logger::Logger,
-    /// Optional external log listener, that can receive a stream of log statements
-    log_listener: Option<Box<dyn log::Log>>,
-}
-impl log::Log for GlobalSdkLogger {
-    fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= log::Level::Trace
+    #[tracing::instrument(skip(self))]
+    async fn do_partial_sync(&self) -> Result<()> {
+        let start = Instant::now();
+        let sync_state = self.persister.get_sync_state()?;
+        let mut new_data = self.node_api.pull_changed(sync_state, false).await?;
+
+        let node_state_before_update = self.persister.get_node_state()?;
+        // The channels are not pulled, the pending ones are kept until the next full sync
+        if let Some(before) = &node_state_before_update {
+            new_data.node_state.pending_channel_opens = before.pending_channel_opens.clone();
+            new_data.node_state.pending_channel_opens_msat = before.pending_channel_opens_msat;
+        }
+        self.persister.set_node_state(&new_data.node_state)?;
+        self.persister.apply_synced_payments(
+            &self.private_payments(new_data.payments),
+            false,
+            &new_data.sync_state,
+        )?;
+        info!("Partial sync duration: {:?}", start.elapsed());
+
+        self.notify_event_listeners(BreezEvent::Synced).await?;
+        for event in low_liquidity_events(
+            &self.config,
+            node_state_before_update.as_ref(),
+            &new_data.node_state,
+        ) {
+            self.notify_event_listeners(event).await?;
+        }
+        Ok(())
     }
 
-    fn log(&self, record: &Record) {
-        if self.enabled(record.metadata()) {
-            self.logger.log(record);
+    #[tracing::instrument(skip(self))]
+    async fn do_sync(&self, match_local_balance: bool) -> Result<()> {
+        let start = Instant::now();
+        let node_pubkey = self.node_api.node_id().await?;
+        self.connect_lsp_peer(node_pubkey).await?;
 
-            if let Some(s) = &self.log_listener.as_ref() {
-                if s.enabled(record.metadata()) {
-                    s.log(record);
+        // First query the changes since last sync state.
+        let sync_state = self.persister.get_sync_state()?;
+        let mut new_data = self
+            .node_api
+            .pull_changed(sync_state.clone(), match_local_balance)
+            .await?;
+
+        debug!(
+            "pull changed old state={:?} new state={:?}",
+            sync_state, new_data.sync_state
+        );
+
+        // update node state and channels state
+        let node_state_before_update = self.persister.get_node_state()?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        new_data.node_state.pending_channel_opens = pending_channel_opens(
+            &new_data.channels,
+            node_state_before_update
+                .as_ref()
+                .map(|s| s.pending_channel_opens.as_slice())
+                .unwrap_or_default(),
+            now,
+        );
+        new_data.node_state.pending_channel_opens_msat = new_data
+            .node_state
+            .pending_channel_opens
+            .iter()
+            .map(|p| p.amount_msat)
+            .sum();
+        self.persister.set_node_state(&new_data.node_state)?;
+
+        let channels_before_update = self.persister.list_channels()?;
+        self.persister.update_channels(&new_data.channels)?;
+        let channels_after_update = self.persister.list_channels()?;
+
+        // The closed channels whose payment is already complete don't change anymore and are
+        // skipped
+        let completed_closed_channel_ids = self.persister.list_completed_closed_channel_ids()?;
+        let closed_channels: Vec<crate::models::Channel> = channels_after_update
+            .iter()
+            .filter(|c| {
+                (c.state == ChannelState::Closed || c.state == ChannelState::PendingClose)
+                    && !completed_closed_channel_ids.contains(&c.funding_txid)
+            })
+            .cloned()
+            .collect();
+
+        // The sync steps are the node state and channels, the static backup, each closed channel
+        // and the payments
+        let total_steps = 3 + closed_channels.len() as u32;
+        let mut done_steps = 1;
+        self.notify_sync_progress(done_steps, total_steps);
+
+        // New channels forwarding a payment to an invoice registered with the LSP were opened
+        // to receive it
+        for channel in channels_after_update.iter().filter(|c| {
+            !channels_before_update
+                .iter()
+                .any(|b| b.funding_txid == c.funding_txid)
+        }) {
+            for htlc in &channel.htlcs {
+                let payment_hash = hex::encode(&htlc.payment_hash);
+                if self
+                    .persister
+                    .get_open_channel_bolt11_by_hash(&payment_hash)?
+                    .is_none()
+                {
+                    continue;
+                }
+                if let Some((payer_amount_msat, ofp)) = self
+                    .persister
+                    .get_open_channel_registration(&payment_hash)?
+                {
+                    let event = BreezEvent::ChannelOpening {
+                        fee_msat: ofp.get_channel_fees_msat_for(payer_amount_msat),
+                        min_fee_msat: ofp.min_msat,
+                        proportional: ofp.proportional,
+                    };
+                    if let Err(e) = self.notify_event_listeners(event).await {
+                        warn!("Failed to notify the channel opening: {e}");
+                    }
                 }
+                self.notify_open_channel_receive(
+                    payment_hash,
+                    OpenChannelReceiveStage::ChannelPending {
+                        funding_txid: channel.funding_txid.clone(),
+                    },
+                )
+                .await;
             }
         }
-    }
 
-    fn flush(&self) {}
-}
+        // Fetch the static backup if needed and persist it
+        if channels_before_update.len() != channels_after_update.len() {
+            info!("fetching static backup file from node");
+            let backup = self.node_api.static_backup().await?;
+            self.persister.set_static_backup(backup.clone())?;
+            if let Err(e) = self.refresh_recovery_bundle(backup) {
+                warn!("Failed to refresh the recovery bundle: {e}");
+            }
+        }
+        done_steps += 1;
+        self.notify_sync_progress(done_steps, total_steps);
 
-/// A helper struct to configure and build BreezServices
-struct BreezServicesBuilder {
-    config: Config,
-    node_api: Option<Arc<dyn NodeAPI>>,
-    backup_transport: Option<Arc<dyn BackupTransport>>,
-    seed: Option<Vec<u8>>,
-    lsp_api: Option<Arc<dyn LspAPI>>,
-    fiat_api: Option<Arc<dyn FiatAPI>>,
-    persister: Option<Arc<SqliteStorage>>,
-    rest_client: Option<Arc<dyn RestClient>>,
-    support_api: Option<Arc<dyn SupportAPI>>,
-    swapper_api: Option<Arc<dyn SwapperAPI>>,
-    taproot_swapper_api: Option<Arc<dyn TaprootSwapperAPI>>,
-    /// Reverse swap functionality on the Breez Server
-    reverse_swapper_api: Option<Arc<dyn ReverseSwapperRoutingAPI>>,
-    /// Reverse swap functionality on the 3rd party reverse swap service
-    reverse_swap_service_api: Option<Arc<dyn ReverseSwapServiceAPI>>,
-    buy_bitcoin_api: Option<Arc<dyn BuyBitcoinApi>>,
-}
+        // Fetch the closed channels and convert them to Payment items
+        let mut closed_channel_payments: Vec<Payment> = vec![];
+        for closed_channel in closed_channels {
+            let closed_channel_tx = self.closed_channel_to_transaction(closed_channel).await?;
+            closed_channel_payments.push(closed_channel_tx);
+            done_steps += 1;
+            self.notify_sync_progress(done_steps, total_steps);
+        }
 
-#[allow(dead_code)]
-impl BreezServicesBuilder {
-    pub fn new(config: Config) -> BreezServicesBuilder {
-        BreezServicesBuilder {
-            config,
-            node_api: None,
-            seed: None,
-            lsp_api: None,
-            fiat_api: None,
-            persister: None,
-            rest_client: None,
-            support_api: None,
-            swapper_api: None,
-            taproot_swapper_api: None,
-            reverse_swapper_api: None,
-            reverse_swap_service_api: None,
-            buy_bitcoin_api: None,
-            backup_transport: None,
+        // Update both closed channels and lightning transaction payments, along with the sync state
+        let mut payments = closed_channel_payments;
+        payments.extend(new_data.payments.clone());
+        self.persister.apply_synced_payments(
+            &self.private_payments(payments),
+            true,
+            &new_data.sync_state,
+        )?;
+        self.notify_sync_progress(total_steps, total_steps);
+        #[cfg(feature = "lnurl")]
+        if let Err(e) = self.process_pending_lnurl_pays() {
+            warn!("Failed to process pending LNURL-pays: {e}");
         }
-    }
 
-    pub fn node_api(&mut self, node_api: Arc<dyn NodeAPI>) -> &mut Self {
-        self.node_api = Some(node_api);
-        self
-    }
+        // The HTLCs that are gone without the payment being received were failed, for example
+        // because the channel opening timed out
+        let failed_htlc_hashes: Vec<String> = channels_before_update
+            .iter()
+            .flat_map(|c| c.htlcs.iter())
+            .filter(|h| {
+                !channels_after_update
+                    .iter()
+                    .flat_map(|c| c.htlcs.iter())
+                    .any(|a| a.payment_hash == h.payment_hash)
+            })
+            .map(|h| hex::encode(&h.payment_hash))
+            .collect();
+        if !failed_htlc_hashes.is_empty() {
+            if let Err(e) = self
+                .renew_open_channel_registrations(Some(&failed_htlc_hashes))
+                .await
+            {
+                warn!("Failed to renew the open channel registrations: {e}");
+            }
+        }
+        let duration = start.elapsed();
+        info!("Sync duration: {:?}", duration);
 
-    pub fn lsp_api(&mut self, lsp_api: Arc<dyn LspAPI>) -> &mut Self {
-        self.lsp_api = Some(lsp_api.clone());
-        self
-    }
+        self.notify_event_listeners(BreezEvent::Synced).await?;
 
-    pub fn fiat_api(&mut self, fiat_api: Arc<dyn FiatAPI>) -> &mut Self {
-        self.fiat_api = Some(fiat_api.clone());
-        self
+        for event in low_liquidity_events(
+            &self.config,
+            node_state_before_update.as_ref(),
+            &new_data.node_state,
+        ) {
+            self.notify_event_listeners(event).await?;
+        }
+        if let Err(e) = self.check_channel_hygiene().await {
+            warn!("Failed to check the channel hygiene: {e}");
+        }
+        Ok(())
     }
 
-    pub fn buy_bitcoin_api(&mut self, buy_bitcoin_api: Arc<dyn BuyBitcoinApi>) -> &mut Self {
-        self.buy_bitcoin_api = Some(buy_bitcoin_api.clone());
-        self
-    }
+    /// Tracks the activity of the open channels and emits a [BreezEvent::ChannelHygieneSuggestion]
+    /// for each stale one, if [Config::channel_hygiene_window_secs] is set.
+    ///
+    /// The activity is tracked from the first check, so a channel is only suggested once the
+    /// window passed since it was first seen, and again each time the window passes after that.
+    async fn check_channel_hygiene(&self) -> Result<()> {
+        let Some(window_secs) = self.config.channel_hygiene_window_secs else {
+            return Ok(());
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let channels = self.node_api.list_channels().await?;
+        let known = self.persister.list_channel_activity()?;
+        let (activity, stale) = channel_hygiene_update(window_secs as i64, now, &channels, &known);
+        self.persister.replace_channel_activity(&activity)?;
+        if stale.is_empty() {
+            return Ok(());
+        }
 
-    pub fn persister(&mut self, persister: Arc<SqliteStorage>) -> &mut Self {
-        self.persister = Some(persister);
-        self
+        let sat_per_vbyte = self.chain_service.recommended_fees().await?.hour_fee;
+        let projected_sweep_fee_sat = CHANNEL_CLOSE_TX_VSIZE * sat_per_vbyte;
+        for (channel, activity) in stale {
+            let details = ChannelHygieneSuggestionDetails {
+                channel_id: channel.channel_id.clone(),
+                peer_id: channel.peer_id.clone(),
+                funding_txid: channel.funding_txid.clone(),
+                local_balance_msat: channel.local_balance_msat,
+                idle_secs: (now - activity.last_activity_at) as u64,
+                peer_offline_secs: (now - activity.peer_connected_at) as u64,
+                sat_per_vbyte,
+                projected_sweep_fee_sat,
+                projected_sweep_amount_sat: MilliSat(channel.local_balance_msat)
+                    .to_sat_floor()
+                    .saturating_sub(Sat(projected_sweep_fee_sat))
+                    .sat(),
+            };
+            self.notify_event_listeners(BreezEvent::ChannelHygieneSuggestion { details })
+                .await?;
+        }
+        Ok(())
     }
 
-    pub fn support_api(&mut self, support_api: Arc<dyn SupportAPI>) -> &mut Self {
-        self.support_api = Some(support_api.clone());
-        self
-    }
+    /// Renews the LSP registration of the unpaid open channel invoices, either the ones with the
+    /// given payment hashes or all of them. The registrations of paid or expired invoices are
+    /// removed instead.
+    async fn renew_open_channel_registrations(
+        &self,
+        payment_hashes: Option<&[String]>,
+    ) -> Result<()> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        for (bolt11, opening_fee_params) in self.persister.list_open_channel_registrations()? {
+            let invoice = parse_invoice(&bolt11)?;
+            if payment_hashes.is_some_and(|hashes| !hashes.contains(&invoice.payment_hash)) {
+                continue;
+            }
+            let expired = invoice.timestamp + invoice.expiry < now
+                || !opening_fee_params.valid_for(0).unwrap_or(false);
+            let paid = self
+                .persister
+                .get_completed_payment_by_hash(&invoice.payment_hash)?
+                .is_some();
+            if expired || paid {
+                self.persister
+                    .delete_open_channel_registration(&invoice.payment_hash)?;
+                continue;
+            }
 
-    pub fn rest_client(&mut self, rest_client: Arc<dyn RestClient>) -> &mut Self {
-        self.rest_client = Some(rest_client.clone());
-        self
+            match self
+                .payment_receiver
+                .renew_open_channel_registration(&bolt11, opening_fee_params)
+                .await
+            {
+                Ok(()) => {
+                    info!(
+                        "Renewed the open channel registration of {}",
+                        invoice.payment_hash
+                    );
+                    self.notify_open_channel_receive(
+                        invoice.payment_hash,
+                        OpenChannelReceiveStage::Reregistered,
+                    )
+                    .await;
+                }
+                Err(e) => warn!(
+                    "Failed to renew the open channel registration of {}: {e}",
+                    invoice.payment_hash
+                ),
+            }
+        }
+        Ok(())
     }
 
-    pub fn swapper_api(&mut self, swapper_api: Arc<dyn SwapperAPI>) -> &mut Self {
-        self.swapper_api = Some(swapper_api.clone());
-        self
-    }
+    /// Connects to the selected LSP peer.
+    /// If set, the LSP is first selected according to the [Config::lsp_policy].
+    /// This validates if the selected LSP is still in [`list_lsps`].
+    /// If not or no LSP is selected, it selects the first LSP in [`list_lsps`].
+    async fn connect_lsp_peer(&self, node_pubkey: String) -> SdkResult<()> {
+        let lsps = self.lsp_api.list_lsps(node_pubkey).await?;
+        let current_lsp_id = self.persister.get_lsp_id()?;
+        let policy_lsp = match &self.config.lsp_policy {
+            Some(policy) => {
+                let lsp = select_lsp(&lsps, policy, current_lsp_id.as_deref());
+                if lsp.is_none() {
+                    warn!("No LSP matches the policy {policy:?}");
+                }
+                lsp
+            }
+            None => None,
+        };
+        let lsp = match policy_lsp
+            .or_else(|| {
+                current_lsp_id
+                    .as_ref()
+                    .and_then(|lsp_id| lsps.iter().find(|lsp| &lsp.id == lsp_id))
+            })
+            .or_else(|| lsps.first())
+        {
+            Some(lsp) => lsp.clone(),
+            None => return Ok(()),
+        };
 
-    pub fn taproot_swapper_api(&mut self, swapper_api: Arc<dyn TaprootSwapperAPI>) -> &mut Self {
-        self.taproot_swapper_api = Some(swapper_api.clone());
-        self
-    }
+        self.persister
+            .set_lsp(lsp.id.clone(), Some(lsp.pubkey.clone()))?;
+        if current_lsp_id.as_ref() != Some(&lsp.id) {
+            self.on_lsp_changed(current_lsp_id, lsp.id.clone()).await;
+        }
+        let node_state = match self.node_info() {
+            Ok(node_state) => node_state,
+            Err(_) => return Ok(()),
+        };
 
-    pub fn reverse_swapper_api(
-        &mut self,
-        reverse_swapper_api: Arc<dyn ReverseSwapperRoutingAPI>,
-    ) -> &mut Self {
-        self.reverse_swapper_api = Some(reverse_swapper_api.clone());
-        self
+        let node_id = lsp.pubkey;
+        let address = lsp.host;
+        let lsp_connected = node_state
+            .connected_peers
+            .iter()
+            .any(|e| e == node_id.as_str());
+        if !lsp_connected {
+            debug!("connecting to lsp {}@{}", node_id.clone(), address.clone());
+            let res = self
+                .node_api
+                .connect_peer(node_id.clone(), address.clone())
+                .await;
+            let mut reconnections = self.lsp_reconnections.lock().await;
+            reconnections.attempts += 1;
+            if let Err(e) = res {
+                let err = format!("(LSP: {node_id}) Failed to connect: {e}");
+                reconnections.failed_attempts += 1;
+                reconnections.last_error = Some(err.clone());
+                return Err(SdkError::ServiceConnectivity { err });
+            }
+            reconnections.last_reconnected_at =
+                Some(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64);
+            debug!("connected to lsp {node_id}@{address}");
+        }
+
+        Ok(())
     }
 
-    pub fn reverse_swap_service_api(
-        &mut self,
-        reverse_swap_service_api: Arc<dyn ReverseSwapServiceAPI>,
-    ) -> &mut Self {
-        self.reverse_swap_service_api = Some(reverse_swap_service_api.clone());
-        self
+    /// Registers the payment notifications with the newly selected LSP and notifies the change
+    async fn on_lsp_changed(&self, previous_lsp_id: Option<String>, lsp_id: String) {
+        info!("Selected LSP {lsp_id}, previously {previous_lsp_id:?}");
+        match self.persister.get_webhook_url() {
+            Ok(Some(webhook_url)) => {
+                if let Err(e) = self.register_payment_notifications(webhook_url).await {
+                    warn!("Failed to register notifications with LSP {lsp_id}: {e}");
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Failed to read the webhook URL: {e}"),
+        }
+        let event = BreezEvent::LspChanged {
+            previous_lsp_id,
+            lsp_id,
+        };
+        if let Err(e) = self.notify_event_listeners(event).await {
+            warn!("Failed to notify the LSP change: {e}");
+        }
     }
 
-    pub fn backup_transport(&mut self, backup_transport: Arc<dyn BackupTransport>) -> &mut Self {
-        self.backup_transport = Some(backup_transport.clone());
-        self
+    /// The payments as persisted, according to [Config::memo_privacy]
+    fn private_payments(&self, payments: Vec<Payment>) -> Vec<Payment> {
+        payments
+            .into_iter()
+            .map(|p| self.config.memo_privacy.apply_to_payment(p))
+            .collect()
     }
 
-    pub fn seed(&mut self, seed: Vec<u8>) -> &mut Self {
-        self.seed = Some(seed);
-        self
+    /// Persists the payment as pending with the [PaymentsWriter]
+    async fn persist_pending_payment(
+        &self,
+        invoice: &LNInvoice,
+        amount_msat: u64,
+        label: Option<String>,
+    ) -> Result<(), SendPaymentError> {
+        let payment = Payment {
+            id: invoice.payment_hash.clone(),
+            payment_type: PaymentType::Sent,
+            payment_time: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+            amount_msat,
+            fee_msat: 0,
+            status: PaymentStatus::Pending,
+            error: None,
+            description: self.config.memo_privacy.apply(invoice.description.clone()),
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: invoice.payment_hash.clone(),
+                    label: label.unwrap_or_default(),
+                    destination_pubkey: invoice.payee_pubkey.clone(),
+                    payment_preimage: String::new(),
+                    keysend: false,
+                    bolt11: invoice.bolt11.clone(),
+                    lnurl_success_action: None,
+                    lnurl_pay_domain: None,
+                    lnurl_pay_comment: None,
+                    ln_address: None,
+                    lnurl_metadata: None,
+                    lnurl_withdraw_endpoint: None,
+                    swap_info: None,
+                    reverse_swap_info: None,
+                    pending_expiration_block: None,
+                    open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
+                },
+            },
+            metadata: None,
+            fiat_rate: None,
+            imported: false,
+        };
+        let external_info = PaymentExternalInfo {
+            lnurl_pay_success_action: None,
+            lnurl_pay_domain: None,
+            lnurl_pay_comment: None,
+            lnurl_metadata: None,
+            ln_address: None,
+            lnurl_withdraw_endpoint: None,
+            attempted_amount_msat: invoice.amount_msat.map_or(Some(amount_msat), |_| None),
+            attempted_error: None,
+        };
+        self.payments_writer
+            .write(move |persister| {
+                let payment_hash = payment.id.clone();
+                persister.insert_or_update_payments(&[payment], true)?;
+                persister.insert_payment_external_info(&payment_hash, external_info)
+            })
+            .await?;
+        Ok(())
     }
 
-    pub async fn build(
+    async fn on_payment_completed(
         &self,
-        restore_only: Option<bool>,
-        event_listener: Option<Box<dyn EventListener>>,
-    ) -> BreezServicesResult<Arc<BreezServices>> {
-        if self.node_api.is_none() && self.seed.is_none() {
-            return Err(ConnectError::Generic {
-                err: "Either node_api or both credentials and seed should be provided".into(),
-            });
+        node_id: String,
+        invoice: Option<LNInvoice>,
+        label: Option<String>,
+        payment_res: Result<Payment, SendPaymentError>,
+    ) -> Result<Payment, SendPaymentError> {
+        self.do_sync(false).await?;
+        match payment_res {
+            Ok(payment) => {
+                self.notify_event_listeners(BreezEvent::PaymentSucceed {
+                    details: payment.clone(),
+                })
+                .await?;
+                Ok(payment)
+            }
+            Err(e) => {
+                if let Some(invoice) = invoice.clone() {
+                    let error = e.to_string();
+                    self.payments_writer
+                        .write(move |persister| {
+                            persister
+                                .update_payment_attempted_error(&invoice.payment_hash, Some(error))
+                        })
+                        .await?;
+                }
+                self.notify_event_listeners(BreezEvent::PaymentFailed {
+                    details: PaymentFailedData {
+                        error: e.to_string(),
+                        node_id,
+                        invoice,
+                        label,
+                    },
+                })
+                .await?;
+                Err(e)
+            }
         }
+    }
 
-        // The storage is implemented via sqlite.
-        let persister = self
-            .persister
-            .clone()
-            .unwrap_or_else(|| Arc::new(SqliteStorage::new(self.config.working_dir.clone())));
-        persister.init()?;
+    async fn on_event(&self, e: BreezEvent) -> Result<()> {
+        debug!("breez services got event {:?}", e);
+        self.notify_event_listeners(e.clone()).await
+    }
 
-        let mut node_api = self.node_api.clone();
-        let mut backup_transport = self.backup_transport.clone();
-        if node_api.is_none() {
-            let greenlight = Greenlight::connect(
-                self.config.clone(),
-                self.seed.clone().unwrap(),
-                restore_only,
-                persister.clone(),
+    async fn notify_event_listeners(&self, e: BreezEvent) -> Result<()> {
+        if let Err(err) = self.btc_receive_swapper.on_event(e.clone()).await {
+            debug!(
+                "btc_receive_swapper failed to process event {:?}: {:?}",
+                e, err
             )
-            .await?;
-            let gl_arc = Arc::new(greenlight);
-            node_api = Some(gl_arc.clone());
-            if backup_transport.is_none() {
-                backup_transport = Some(Arc::new(GLBackupTransport { inner: gl_arc }));
+        };
+        #[cfg(feature = "reverse-swaps")]
+        if let Err(err) = self.btc_send_swapper.on_event(e.clone()).await {
+            debug!(
+                "btc_send_swapper failed to process event {:?}: {:?}",
+                e, err
+            )
+        };
+
+        if self.event_listener.is_some() {
+            self.event_listener.as_ref().unwrap().on_event(e.clone())
+        }
+        // Fails only when there are no subscribers
+        _ = self.event_sender.send(e);
+        Ok(())
+    }
+
+    /// Emits a [BreezEvent::UnredeemedFundsDetected] if any funds need an action to be recovered
+    async fn check_unredeemed_funds(&self) -> SdkResult<()> {
+        let refundable_swaps = self.btc_receive_swapper.list_refundables()?;
+        let (expired_invoice_swaps, redeemable_swaps): (Vec<_>, Vec<_>) = self
+            .btc_receive_swapper
+            .list_redeemables()?
+            .into_iter()
+            .partition(needs_new_opening_fee);
+        #[allow(unused_mut)]
+        let mut unclaimed_reverse_swaps: Vec<ReverseSwapInfo> = vec![];
+        #[cfg(feature = "reverse-swaps")]
+        for full_rsi in self.btc_send_swapper.list_blocking().await? {
+            if full_rsi.cache.status == ReverseSwapStatus::InProgress {
+                unclaimed_reverse_swaps.push(
+                    self.btc_send_swapper
+                        .convert_reverse_swap_info(full_rsi)
+                        .await?,
+                );
             }
         }
+        if refundable_swaps.is_empty()
+            && redeemable_swaps.is_empty()
+            && expired_invoice_swaps.is_empty()
+            && unclaimed_reverse_swaps.is_empty()
+        {
+            return Ok(());
+        }
 
-        if backup_transport.is_none() {
-            return Err(ConnectError::Generic {
-                err: "State synchronizer should be provided".into(),
+        let total_amount_sat = refundable_swaps
+            .iter()
+            .chain(redeemable_swaps.iter())
+            .chain(expired_invoice_swaps.iter())
+            .map(|s| s.confirmed_sats)
+            .sum::<u64>()
+            + unclaimed_reverse_swaps
+                .iter()
+                .map(|rs| rs.onchain_amount_sat)
+                .sum::<u64>();
+        info!("Found {total_amount_sat} sats of unredeemed funds");
+        self.notify_event_listeners(BreezEvent::UnredeemedFundsDetected {
+            details: UnredeemedFundsDetails {
+                refundable_swaps,
+                redeemable_swaps,
+                expired_invoice_swaps,
+                unclaimed_reverse_swaps,
+                total_amount_sat,
+            },
+        })
+        .await?;
+        Ok(())
+    }
+
+    fn notify_connect_progress(&self, stage: ConnectStage) {
+        self.notify_connect_progress_percent(stage, stage.progress_percent());
+    }
+
+    fn notify_connect_progress_percent(&self, stage: ConnectStage, progress_percent: u8) {
+        if let Some(listener) = self.event_listener.as_ref() {
+            listener.on_event(BreezEvent::ConnectProgress {
+                details: ConnectProgressDetails {
+                    stage,
+                    progress_percent,
+                    is_new_node: self.is_new_node,
+                },
             });
         }
+    }
 
-        let unwrapped_node_api = node_api.unwrap();
-        let unwrapped_backup_transport = backup_transport.unwrap();
-
-        // create the backup encryption key and then the backup watcher
-        let backup_encryption_key = unwrapped_node_api
-            .derive_bip32_key(vec![
-                ChildNumber::from_hardened_idx(139)?,
-                ChildNumber::from(0),
-            ])
-            .await?;
+    /// Runs the first sync of the node, reporting its progress as [ConnectStage::InitialSync]
+    /// events
+    async fn initial_sync(&self) -> SdkResult<()> {
+        self.notify_connect_progress(ConnectStage::InitialSync);
+        self.initial_sync_in_progress.store(true, Ordering::Relaxed);
+        let result = self.sync().await;
+        self.initial_sync_in_progress
+            .store(false, Ordering::Relaxed);
+        result
+    }
 
-        // We calculate the legacy key as a fallback for the case where the backup is still
-        // encrypted with the old key.
-        let legacy_backup_encryption_key = unwrapped_node_api
-            .legacy_derive_bip32_key(vec![
-                ChildNumber::from_hardened_idx(139)?,
-                ChildNumber::from(0),
-            ])
-            .await?;
-        let backup_watcher = BackupWatcher::new(
-            self.config.clone(),
-            unwrapped_backup_transport.clone(),
-            persister.clone(),
-            backup_encryption_key.to_priv().to_bytes(),
-            legacy_backup_encryption_key.to_priv().to_bytes(),
-        );
+    /// Reports that `done` of the `total` steps of the sync are completed, if it is the initial
+    /// sync
+    fn notify_sync_progress(&self, done: u32, total: u32) {
+        if self.initial_sync_in_progress.load(Ordering::Relaxed) {
+            self.notify_connect_progress_percent(
+                ConnectStage::InitialSync,
+                ConnectStage::initial_sync_percent(done, total),
+            );
+        }
+    }
 
-        // breez_server provides both FiatAPI & LspAPI implementations
-        let breez_server = Arc::new(
-            BreezServer::new(self.config.breezserver.clone(), self.config.api_key.clone())
-                .map_err(|e| ConnectError::ServiceConnectivity {
-                    err: format!("Failed to create BreezServer: {e}"),
-                })?,
-        );
+    /// Convenience method to look up LSP info based on current LSP ID
+    pub async fn lsp_info(&self) -> SdkResult<LspInformation> {
+        get_lsp(self.persister.clone(), self.lsp_api.clone()).await
+    }
 
-        // Ensure breez server connection is established in the background
-        let cloned_breez_server = breez_server.clone();
-        tokio::spawn(async move {
-            if let Err(e) = cloned_breez_server.ping().await {
-                error!("Failed to ping breez server: {e}");
-            }
-        });
+    /// Get the recommended fees for onchain transactions
+    pub async fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
+        self.chain_service.recommended_fees().await
+    }
 
-        let current_lsp_id = persister.get_lsp_id()?;
-        if current_lsp_id.is_none() && self.config.default_lsp_id.is_some() {
-            persister.set_lsp(self.config.default_lsp_id.clone().unwrap(), None)?;
+    /// Get the full default config for a specific environment type
+    pub fn default_config(
+        env_type: EnvironmentType,
+        api_key: String,
+        node_config: NodeConfig,
+    ) -> Config {
+        match env_type {
+            EnvironmentType::Production => Config::production(api_key, node_config),
+            EnvironmentType::Staging => Config::staging(api_key, node_config),
+            EnvironmentType::Regtest => Config::regtest(api_key, node_config),
         }
+    }
 
-        let payment_receiver = Arc::new(PaymentReceiver {
-            config: self.config.clone(),
-            node_api: unwrapped_node_api.clone(),
-            lsp: breez_server.clone(),
-            persister: persister.clone(),
-        });
+    /// Get the static backup data from the persistent storage.
+    /// This data enables the user to recover the node in an external core ligntning node.
+    /// See here for instructions on how to recover using this data: <https://docs.corelightning.org/docs/backup-and-recovery#backing-up-using-static-channel-backup>
+    pub fn static_backup(req: StaticBackupRequest) -> SdkResult<StaticBackupResponse> {
+        let storage = SqliteStorage::new(req.working_dir);
+        Ok(StaticBackupResponse {
+            backup: storage.get_static_backup()?,
+        })
+    }
 
-        let rest_client: Arc<dyn RestClient> = match self.rest_client.clone() {
-            Some(rest_client) => rest_client,
-            None => Arc::new(ReqwestRestClient::new()?),
-        };
+    /// Reports the version of the SDK and the protocols it supports, so apps and support tools
+    /// can tell which features and backups are compatible across installs of different versions.
+    pub fn sdk_info() -> SdkInfo {
+        SdkInfo {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            supported_luds: SUPPORTED_LUDS.iter().map(|lud| lud.to_string()).collect(),
+            swap_in_script_versions: vec!["segwit".to_string(), "taproot".to_string()],
+            reverse_swap_protocols: vec![ReverseSwapProtocol::Legacy, ReverseSwapProtocol::Taproot],
+            lsp_protocols: vec!["breez-grpc".to_string()],
+            backup_schema_version: current_sync_migrations().len() as u32,
+            min_backup_schema_version: MIN_BACKUP_SCHEMA_VERSION,
+        }
+    }
 
-        // mempool space is used to monitor the chain
-        let mempoolspace_urls = match self.config.mempoolspace_url.clone() {
-            None => {
-                let cached = persister.get_mempoolspace_base_urls()?;
-                match cached.len() {
-                    // If we have no cached values, or we cached an empty list, fetch new ones
-                    0 => {
-                        let fresh_urls = breez_server
-                            .fetch_mempoolspace_urls()
-                            .await
-                            .unwrap_or(vec![DEFAULT_MEMPOOL_SPACE_URL.into()]);
-                        persister.set_mempoolspace_base_urls(fresh_urls.clone())?;
-                        fresh_urls
-                    }
-                    // If we already have cached values, return those
-                    _ => cached,
-                }
-            }
-            Some(mempoolspace_url_from_config) => vec![mempoolspace_url_from_config],
-        };
-        let chain_service = Arc::new(RedundantChainService::from_base_urls(
-            rest_client.clone(),
-            mempoolspace_urls,
-        ));
+    /// Fetches the service health check from the support API.
+    pub async fn service_health_check(api_key: String) -> SdkResult<ServiceHealthCheckResponse> {
+        let support_api: Arc<dyn SupportAPI> = Arc::new(BreezServer::new(
+            PRODUCTION_BREEZSERVER_URL.to_string(),
+            Some(api_key),
+        )?);
 
-        let btc_receive_swapper = Arc::new(BTCReceiveSwap::new(BTCReceiveSwapParameters {
-            chain_service: chain_service.clone(),
-            payment_storage: persister.clone(),
-            network: self.config.network.into(),
-            node_api: unwrapped_node_api.clone(),
-            node_state_storage: persister.clone(),
-            payment_receiver: payment_receiver.clone(),
-            segwit_swapper_api: self
-                .swapper_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
-            swap_storage: persister.clone(),
-            taproot_swapper_api: self
-                .taproot_swapper_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
-        }));
+        support_api.service_health_check().await
+    }
 
-        let btc_send_swapper = Arc::new(BTCSendSwap::new(
-            self.config.clone(),
-            self.reverse_swapper_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
-            self.reverse_swap_service_api
-                .clone()
-                .unwrap_or_else(|| Arc::new(BoltzApi::new(rest_client.clone()))),
-            persister.clone(),
-            chain_service.clone(),
-            unwrapped_node_api.clone(),
-        ));
+    /// Generates an url that can be used by a third part provider to buy Bitcoin with fiat currency.
+    ///
+    /// A user-selected [OpeningFeeParams] can be optionally set in the argument. If set, and the
+    /// operation requires a new channel, the SDK will try to use the given fee params.
+    #[cfg(feature = "buy-bitcoin")]
+    pub async fn buy_bitcoin(
+        &self,
+        req: BuyBitcoinRequest,
+    ) -> Result<BuyBitcoinResponse, ReceiveOnchainError> {
+        let swap_info = self
+            .receive_onchain(ReceiveOnchainRequest {
+                opening_fee_params: req.opening_fee_params,
+            })
+            .await?;
+        let url = self
+            .buy_bitcoin_api
+            .buy_bitcoin(req.provider, &swap_info, req.redirect_url)
+            .await?;
 
-        // create a shutdown channel (sender and receiver)
-        let (shutdown_sender, _shutdown_receiver) = watch::channel::<()>(());
+        Ok(BuyBitcoinResponse {
+            url,
+            opening_fee_params: swap_info.channel_opening_fees,
+        })
+    }
 
-        let buy_bitcoin_api = self
+    /// Fetches a quote of how many sats the given fiat amount buys at a third party provider,
+    /// including its fees. Fetching quotes from several providers lets the user compare them
+    /// before calling [BreezServices::buy_bitcoin].
+    #[cfg(feature = "buy-bitcoin")]
+    pub async fn fetch_buy_bitcoin_quote(
+        &self,
+        req: FetchBuyBitcoinQuoteRequest,
+    ) -> SdkResult<BuyBitcoinQuote> {
+        if req.fiat_amount.amount <= 0.0 {
+            return Err(SdkError::Generic {
+                err: "The fiat amount must be positive".into(),
+            });
+        }
+        Ok(self
             .buy_bitcoin_api
-            .clone()
-            .unwrap_or_else(|| Arc::new(BuyBitcoinService::new(breez_server.clone())));
+            .fetch_quote(req.provider, &req.fiat_amount)
+            .await?)
+    }
 
-        // Create the node services and it them statically
-        let breez_services = Arc::new(BreezServices {
-            config: self.config.clone(),
-            started: Mutex::new(false),
-            node_api: unwrapped_node_api.clone(),
-            lsp_api: self.lsp_api.clone().unwrap_or_else(|| breez_server.clone()),
-            fiat_api: self
-                .fiat_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
-            support_api: self
-                .support_api
-                .clone()
-                .unwrap_or_else(|| breez_server.clone()),
-            buy_bitcoin_api,
-            chain_service,
-            persister: persister.clone(),
-            rest_client,
-            btc_receive_swapper,
-            btc_send_swapper,
-            payment_receiver,
-            event_listener,
-            backup_watcher: Arc::new(backup_watcher),
-            shutdown_sender,
-        });
+    /// Starts the BreezServices background threads.
+    ///
+    /// Internal method. Should only be used as part of [BreezServices::start]
+    async fn start_background_tasks(self: &Arc<BreezServices>) -> SdkResult<()> {
+        self.notify_connect_progress(ConnectStage::NodeStartup);
 
-        Ok(breez_services)
-    }
-}
+        // start the signer
+        let (shutdown_signer_sender, signer_signer_receiver) = watch::channel(());
+        self.start_signer(signer_signer_receiver).await;
+        self.start_node_keep_alive(self.shutdown_sender.subscribe())
+            .await;
 
-/// Attempts to convert the phrase to a mnemonic, then to a seed.
-///
-/// If the phrase is not a valid mnemonic, an error is returned.
-pub fn mnemonic_to_seed(phrase: String) -> Result<Vec<u8>> {
-    let mnemonic = Mnemonic::from_phrase(&phrase, Language::English)?;
-    let seed = Seed::new(&mnemonic, "");
-    Ok(seed.as_bytes().to_vec())
-}
+        // Sync node state
+        match self.persister.get_node_state()? {
+            Some(node) => {
+                info!("Starting existing node {}", node.id);
+                self.connect_lsp_peer(node.id).await?;
+            }
+            None => {
+                // In case it is a first run we sync in foreground to get the node state.
+                info!("First run, syncing in foreground");
+                self.initial_sync().await?;
+                info!("First run, finished running syncing in foreground");
+            }
+        }
 
-pub struct OpenChannelParams {
-    pub payer_amount_msat: u64,
-    pub opening_fee_params: models::OpeningFeeParams,
-}
+        // start backup watcher
+        self.start_backup_watcher().await?;
 
-#[tonic::async_trait]
-pub trait Receiver: Send + Sync {
-    fn open_channel_needed(&self, amount_msat: u64) -> Result<bool, ReceivePaymentError>;
-    async fn receive_payment(
-        &self,
-        req: ReceivePaymentRequest,
-    ) -> Result<ReceivePaymentResponse, ReceivePaymentError>;
-    async fn wrap_node_invoice(
-        &self,
-        invoice: &str,
-        params: Option<OpenChannelParams>,
-        lsp_info: Option<LspInformation>,
-    ) -> Result<String, ReceivePaymentError>;
-}
+        //track backup events
+        self.track_backup_events().await;
 
-pub(crate) struct PaymentReceiver {
-    config: Config,
-    node_api: Arc<dyn NodeAPI>,
-    lsp: Arc<dyn LspAPI>,
-    persister: Arc<SqliteStorage>,
-}
+        //track swap events
+        self.track_swap_events().await;
 
-#[tonic::async_trait]
-impl Receiver for PaymentReceiver {
-    fn open_channel_needed(&self, amount_msat: u64) -> Result<bool, ReceivePaymentError> {
-        let node_state = self
-            .persister
-            .get_node_state()?
-            .ok_or(ReceivePaymentError::Generic {
-                err: "Node info not found".into(),
-            })?;
-        Ok(node_state.max_receivable_single_payment_amount_msat < amount_msat)
-    }
+        // retry the failed swap redeems
+        self.start_swap_monitor().await;
 
-    async fn receive_payment(
-        &self,
-        req: ReceivePaymentRequest,
-    ) -> Result<ReceivePaymentResponse, ReceivePaymentError> {
-        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
-        let expiry = req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS);
+        // send the payments queued in the outbox
+        self.start_outbox_monitor().await;
 
-        ensure_sdk!(
-            req.amount_msat > 0,
-            ReceivePaymentError::InvalidAmount {
-                err: "Receive amount must be more than 0".into()
-            }
-        );
+        // reconnect when the node or the LSP peer can't be reached, report the services status
+        self.start_connectivity_monitor().await;
 
-        let mut destination_invoice_amount_msat = req.amount_msat;
-        let mut channel_opening_fee_params = None;
-        let mut channel_fees_msat = None;
+        // keep the denylist of payment destinations up to date
+        self.start_denylist_monitor().await;
 
-        // check if we need to open channel
-        let open_channel_needed = self.open_channel_needed(req.amount_msat)?;
-        if open_channel_needed {
-            info!("We need to open a channel");
-
-            // we need to open channel so we are calculating the fees for the LSP (coming either from the user, or from the LSP)
-            let ofp = match req.opening_fee_params {
-                Some(fee_params) => fee_params,
-                None => lsp_info.cheapest_open_channel_fee(expiry)?.clone(),
-            };
+        // track paid invoices
+        self.track_invoices().await;
 
-            channel_opening_fee_params = Some(ofp.clone());
-            channel_fees_msat = Some(ofp.get_channel_fees_msat_for(req.amount_msat));
-            if let Some(channel_fees_msat) = channel_fees_msat {
-                info!("zero-conf fee calculation option: lsp fee rate (proportional): {}:  (minimum {}), total fees for channel: {}",
-                    ofp.proportional, ofp.min_msat, channel_fees_msat);
-
-                if req.amount_msat < channel_fees_msat + 1000 {
-                    return Err(
-                        ReceivePaymentError::InvalidAmount{err: format!(
-                           "Amount should be more than the minimum fees {channel_fees_msat} msat, but is {} msat",
-                            req.amount_msat
-                        )}
-                    );
-                }
-                // remove the fees from the amount to get the small amount on the current node invoice.
-                destination_invoice_amount_msat = req.amount_msat - channel_fees_msat;
-            }
-        }
+        // track new blocks
+        self.track_new_blocks().await;
 
-        info!("Creating invoice on NodeAPI");
-        let invoice = self
-            .node_api
-            .create_invoice(CreateInvoiceRequest {
-                amount_msat: destination_invoice_amount_msat,
-                description: req.description,
-                payer_amount_msat: match open_channel_needed {
-                    true => Some(req.amount_msat),
-                    false => None,
-                },
-                preimage: req.preimage,
-                use_description_hash: req.use_description_hash,
-                expiry: Some(expiry),
-                cltv: Some(req.cltv.unwrap_or(144)),
-            })
-            .await?;
-        info!("Invoice created {}", invoice);
+        // track logs
+        self.track_logs().await;
 
-        let open_channel_params = match open_channel_needed {
-            true => Some(OpenChannelParams {
-                payer_amount_msat: req.amount_msat,
-                opening_fee_params: channel_opening_fee_params.clone().ok_or(
-                    ReceivePaymentError::Generic {
-                        err: "We need to open a channel, but no channel opening fee params found"
-                            .into(),
-                    },
-                )?,
-            }),
-            false => None,
-        };
+        // record the fiat rates of the settled payments
+        #[cfg(feature = "fiat")]
+        self.track_payment_fiat_rates().await;
 
-        let invoice = self
-            .wrap_node_invoice(&invoice, open_channel_params, Some(lsp_info))
-            .await?;
-        let parsed_invoice = parse_invoice(&invoice)?;
+        // refresh the registered webhook
+        self.refresh_webhook().await;
 
-        // return the signed, converted invoice with hints
-        Ok(ReceivePaymentResponse {
-            ln_invoice: parsed_invoice,
-            opening_fee_params: channel_opening_fee_params,
-            opening_fee_msat: channel_fees_msat,
-        })
-    }
+        // renew the registrations of the unpaid open channel invoices, whose channel opening may
+        // have timed out while the app was offline, retried until they are renewed
+        self.spawn_supervised("open_channel_registrations", |cloned| async move {
+            cloned.renew_open_channel_registrations(None).await
+        });
 
-    async fn wrap_node_invoice(
-        &self,
-        invoice: &str,
-        params: Option<OpenChannelParams>,
-        lsp_info: Option<LspInformation>,
-    ) -> Result<String, ReceivePaymentError> {
-        let lsp_info = match lsp_info {
-            Some(lsp_info) => lsp_info,
-            None => get_lsp(self.persister.clone(), self.lsp.clone()).await?,
-        };
+        // Stop signer on shutdown. Not supervised, as forwarding the signal can't fail.
+        let mut shutdown_receiver = self.shutdown_sender.subscribe();
+        tokio::spawn(async move {
+            _ = shutdown_receiver.changed().await;
+            _ = shutdown_signer_sender.send(());
+            debug!("Received the signal to exit signer");
+        });
 
-        match params {
-            Some(params) => {
-                self.wrap_open_channel_invoice(invoice, params, &lsp_info)
-                    .await
-            }
-            None => self.ensure_hint(invoice, &lsp_info).await,
+        // The mempool.space URLs are only used when no other chain service is configured, and
+        // the test networks have their own
+        if self.config.chain_service.is_none()
+            && test_network_mempool_space_url(self.config.network).is_none()
+        {
+            self.init_chainservice_urls().await?;
         }
+
+        Ok(())
     }
-}
 
-impl PaymentReceiver {
-    async fn ensure_hint(
-        &self,
-        invoice: &str,
-        lsp_info: &LspInformation,
-    ) -> Result<String, ReceivePaymentError> {
-        info!("Getting routing hints from node");
-        let (mut hints, has_public_channel) = self.node_api.get_routing_hints(lsp_info).await?;
-        if !has_public_channel && hints.is_empty() {
-            return Err(ReceivePaymentError::InvoiceNoRoutingHints {
-                err: "Must have at least one active channel".into(),
-            });
-        }
-
-        let parsed_invoice = parse_invoice(invoice)?;
-
-        // check if the lsp hint already exists
-        info!("Existing routing hints {:?}", parsed_invoice.routing_hints);
-
-        // limit the hints to max 3 and extract the lsp one.
-        if let Some(lsp_hint) = Self::limit_and_extract_lsp_hint(&mut hints, lsp_info) {
-            if parsed_invoice.contains_hint_for_node(lsp_info.pubkey.as_str()) {
-                return Ok(String::from(invoice));
-            }
-
-            info!("Adding lsp hint: {:?}", lsp_hint);
-            let modified =
-                add_routing_hints(invoice, true, &vec![lsp_hint], parsed_invoice.amount_msat)?;
+    async fn start_signer(self: &Arc<BreezServices>, shutdown_receiver: watch::Receiver<()>) {
+        let node_api = self.node_api.clone();
+        let task_shutdown_receiver = shutdown_receiver.clone();
+        self.task_supervisor
+            .spawn("signer", shutdown_receiver, move || {
+                let node_api = node_api.clone();
+                let mut shutdown_receiver = task_shutdown_receiver.clone();
+                async move {
+                    loop {
+                        let (tx, rx) = mpsc::channel(1);
+                        let is_shutdown = tokio::select! {
+                            _ = node_api.start_signer(rx) => {
+                                tokio::time::sleep(Duration::from_secs(1)).await;
+                                false
+                            }
 
-            let invoice = self.node_api.sign_invoice(modified).await?;
-            info!("Signed invoice with hint = {}", invoice);
-            return Ok(invoice);
-        }
+                            _ = shutdown_receiver.changed() => {
+                                true
+                            }
+                        };
 
-        if parsed_invoice.routing_hints.is_empty() {
-            info!("Adding custom hints: {:?}", hints);
-            let modified = add_routing_hints(invoice, false, &hints, parsed_invoice.amount_msat)?;
-            let invoice = self.node_api.sign_invoice(modified).await?;
-            info!("Signed invoice with hints = {}", invoice);
-            return Ok(invoice);
-        }
+                        debug!("shutting down signer");
+                        drop(tx); // Dropping the sender explicitly to notify the receiver.
 
-        Ok(String::from(invoice))
+                        if is_shutdown {
+                            return;
+                        }
+                    }
+                }
+            });
     }
 
-    async fn wrap_open_channel_invoice(
-        &self,
-        invoice: &str,
-        params: OpenChannelParams,
-        lsp_info: &LspInformation,
-    ) -> Result<String, ReceivePaymentError> {
-        let parsed_invoice = parse_invoice(invoice)?;
-        let open_channel_hint = RouteHint {
-            hops: vec![RouteHintHop {
-                src_node_id: lsp_info.pubkey.clone(),
-                short_channel_id: "1x0x0".to_string(),
-                fees_base_msat: lsp_info.base_fee_msat as u32,
-                fees_proportional_millionths: (lsp_info.fee_rate * 1000000.0) as u32,
-                cltv_expiry_delta: lsp_info.time_lock_delta as u64,
-                htlc_minimum_msat: Some(lsp_info.min_htlc_msat as u64),
-                htlc_maximum_msat: None,
-            }],
-        };
-        info!("Adding open channel hint: {:?}", open_channel_hint);
-        let invoice_with_hint = add_routing_hints(
-            invoice,
-            false,
-            &vec![open_channel_hint],
-            Some(params.payer_amount_msat),
-        )?;
-        let signed_invoice = self.node_api.sign_invoice(invoice_with_hint).await?;
+    async fn start_node_keep_alive(
+        self: &Arc<BreezServices>,
+        shutdown_receiver: watch::Receiver<()>,
+    ) {
+        let node_api = self.node_api.clone();
+        let task_shutdown_receiver = shutdown_receiver.clone();
+        self.task_supervisor
+            .spawn("node_keep_alive", shutdown_receiver, move || {
+                let node_api = node_api.clone();
+                let shutdown_receiver = task_shutdown_receiver.clone();
+                async move { node_api.start_keep_alive(shutdown_receiver).await }
+            });
+    }
 
-        info!("Registering payment with LSP");
-        let api_key = self.config.api_key.clone().unwrap_or_default();
-        let api_key_hash = sha256::Hash::hash(api_key.as_bytes()).to_hex();
+    /// Spawns a background task which is restarted if it returns an error or panics, see
+    /// [TaskSupervisor]
+    fn spawn_supervised<F, Fut, R>(self: &Arc<BreezServices>, name: &'static str, task: F)
+    where
+        F: Fn(Arc<BreezServices>) -> Fut + Send + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+        R: TaskResult + Send + 'static,
+    {
+        let cloned = self.clone();
+        self.task_supervisor
+            .spawn(name, self.shutdown_sender.subscribe(), move || {
+                task(cloned.clone())
+            });
+    }
 
-        self.lsp
-            .register_payment(
-                lsp_info.id.clone(),
-                lsp_info.lsp_pubkey.clone(),
-                grpc::PaymentInformation {
-                    payment_hash: hex::decode(parsed_invoice.payment_hash.clone())
-                        .map_err(|e| anyhow!("Failed to decode hex payment hash: {e}"))?,
-                    payment_secret: parsed_invoice.payment_secret.clone(),
-                    destination: hex::decode(parsed_invoice.payee_pubkey.clone())
-                        .map_err(|e| anyhow!("Failed to decode hex payee pubkey: {e}"))?,
-                    incoming_amount_msat: params.payer_amount_msat as i64,
-                    outgoing_amount_msat: parsed_invoice
-                        .amount_msat
-                        .ok_or(anyhow!("Open channel invoice must have an amount"))?
-                        as i64,
-                    tag: json!({ "apiKeyHash": api_key_hash }).to_string(),
-                    opening_fee_params: Some(params.opening_fee_params.into()),
-                },
+    async fn start_backup_watcher(self: &Arc<BreezServices>) -> Result<()> {
+        self.backup_watcher
+            .start(
+                self.shutdown_sender.subscribe(),
+                self.task_supervisor.clone(),
             )
-            .await?;
-        // Make sure we save the large amount so we can deduce the fees later.
-        self.persister.insert_open_channel_payment_info(
-            &parsed_invoice.payment_hash,
-            params.payer_amount_msat,
-            &signed_invoice,
-        )?;
+            .await
+            .map_err(|e| anyhow!("Failed to start backup watcher: {e}"))?;
 
-        Ok(signed_invoice)
+        // Restore backup state and request backup on start if needed
+        let force_backup = self
+            .persister
+            .get_last_sync_version()
+            .map_err(|e| anyhow!("Failed to read last sync version: {e}"))?
+            .is_none();
+        self.backup_watcher
+            .request_backup(BackupRequest::new(force_backup))
+            .await
+            .map_err(|e| anyhow!("Failed to request backup: {e}"))
     }
 
-    fn limit_and_extract_lsp_hint(
-        routing_hints: &mut Vec<RouteHint>,
-        lsp_info: &LspInformation,
-    ) -> Option<RouteHint> {
-        let mut lsp_hint: Option<RouteHint> = None;
-        if let Some(lsp_index) = routing_hints.iter().position(|r| {
-            r.hops
-                .iter()
-                .any(|h| h.src_node_id == lsp_info.pubkey.clone())
-        }) {
-            lsp_hint = Some(routing_hints.remove(lsp_index));
-        }
-        if routing_hints.len() > 3 {
-            routing_hints.drain(3..);
-        }
-        lsp_hint
+    /// Re-registers the persisted webhook URL for payment notifications, so the registration
+    /// with the LSP doesn't expire while the app is restarted but doesn't call
+    /// [BreezServices::register_webhook] again. It's retried until it succeeds.
+    async fn refresh_webhook(self: &Arc<BreezServices>) {
+        self.spawn_supervised("webhook_refresh", |cloned| async move {
+            cloned.refresh_webhook_registration().await
+        });
     }
-}
-
-/// Convenience method to look up LSP info based on current LSP ID
-async fn get_lsp(
-    persister: Arc<SqliteStorage>,
-    lsp_api: Arc<dyn LspAPI>,
-) -> SdkResult<LspInformation> {
-    let lsp_id = persister
-        .get_lsp_id()?
-        .ok_or(SdkError::generic("No LSP ID found"))?;
-
-    get_lsp_by_id(persister, lsp_api, lsp_id.as_str())
-        .await?
-        .ok_or_else(|| SdkError::Generic {
-            err: format!("No LSP found for id {lsp_id}"),
-        })
-}
-
-async fn get_lsp_by_id(
-    persister: Arc<SqliteStorage>,
-    lsp_api: Arc<dyn LspAPI>,
-    lsp_id: &str,
-) -> SdkResult<Option<LspInformation>> {
-    let node_pubkey = persister
-        .get_node_state()?
-        .ok_or(SdkError::generic("Node info not found"))?
-        .id;
-
-    Ok(lsp_api
-        .list_lsps(node_pubkey)
-        .await?
-        .iter()
-        .find(|&lsp| lsp.id.as_str() == lsp_id)
-        .cloned())
-}
 
-/// Convenience method to get all LSPs (active and historical) relevant for registering or
-/// unregistering webhook notifications
-async fn get_notification_lsps(
-    persister: Arc<SqliteStorage>,
-    lsp_api: Arc<dyn LspAPI>,
-    node_api: Arc<dyn NodeAPI>,
-) -> SdkResult<Vec<LspInformation>> {
-    let node_pubkey = persister
-        .get_node_state()?
-        .ok_or(SdkError::generic("Node info not found"))?
-        .id;
-    let mut open_peers = None;
+    /// Registers the persisted webhook URL again, if any
+    async fn refresh_webhook_registration(&self) -> SdkResult<()> {
+        let Some(webhook_url) = self.persister.get_webhook_url()? else {
+            return Ok(());
+        };
+        info!("Refreshing the registration of the persisted webhook");
+        self.register_payment_notifications(webhook_url).await
+    }
 
-    let mut notification_lsps = vec![];
-    for lsp in lsp_api.list_used_lsps(node_pubkey).await? {
-        match !lsp.opening_fee_params_list.values.is_empty() {
-            true => {
-                // Non-empty fee params list = this is the active LSP
-                // Always consider the active LSP for notifications
-                notification_lsps.push(lsp);
-            }
-            false => {
-                // Consider only historical LSPs with whom we have an active channel
-                let lsp_pubkey = hex::decode(&lsp.pubkey)
-                    .map_err(|e| anyhow!("Failed decode lsp pubkey: {e}"))?;
-                let open_peers = match &open_peers {
-                    Some(open_peers) => open_peers,
-                    None => {
-                        open_peers = Some(node_api.get_open_peers().await?);
-                        open_peers.as_ref().unwrap()
+    /// Records the exchange rate of the [Config::fiat_currency], or else of the
+    /// [UserSettings::preferred_fiat_currency], when payments settle
+    #[cfg(feature = "fiat")]
+    async fn track_payment_fiat_rates(self: &Arc<BreezServices>) {
+        self.spawn_supervised("payment_fiat_rates", |cloned| async move {
+            let mut events = cloned.event_sender.subscribe();
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            loop {
+                let event = tokio::select! {
+                    event = events.recv() => event,
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Payment fiat rates task completed");
+                        return;
                     }
                 };
-                let has_active_channel_to_lsp = open_peers.contains(&lsp_pubkey);
-                if has_active_channel_to_lsp {
-                    notification_lsps.push(lsp);
+                let payment_id = match event {
+                    Ok(BreezEvent::PaymentSucceed { details }) => details.id,
+                    // The invoices created from a fiat amount already have the rate they were
+                    // converted with
+                    Ok(BreezEvent::InvoicePaid { details }) => match details.payment {
+                        Some(payment) if payment.fiat_rate.is_some() => continue,
+                        _ => details.payment_hash,
+                    },
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return,
+                };
+                let currency = match cloned.config.fiat_currency.clone() {
+                    Some(currency) => currency,
+                    None => match cloned.persister.get_user_settings() {
+                        Ok(settings) => match settings.preferred_fiat_currency {
+                            Some(currency) => currency,
+                            None => continue,
+                        },
+                        Err(e) => {
+                            warn!("Failed to read the user settings: {e}");
+                            continue;
+                        }
+                    },
+                };
+                let rate = match cloned.fetch_fiat_rates().await {
+                    Ok(rates) => rates.into_iter().find(|r| r.coin == currency),
+                    Err(e) => {
+                        warn!("Failed to fetch the fiat rate of payment {payment_id}: {e}");
+                        continue;
+                    }
+                };
+                if let Some(rate) = rate {
+                    if let Err(e) = cloned.persister.set_payment_fiat_rate(&payment_id, &rate) {
+                        warn!("Failed to store the fiat rate of payment {payment_id}: {e}");
+                    }
                 }
             }
-        }
+        });
     }
-    Ok(notification_lsps)
-}
-
-#[cfg(test)]
-pub(crate) mod tests {
-    use std::collections::HashMap;
-    use std::sync::Arc;
-
-    use anyhow::{anyhow, Result};
-    use regex::Regex;
-    use reqwest::Url;
-    use sdk_common::prelude::Rate;
 
-    use crate::breez_services::{BreezServices, BreezServicesBuilder};
-    use crate::models::{LnPaymentDetails, NodeState, Payment, PaymentDetails, PaymentTypeFilter};
-    use crate::node_api::NodeAPI;
-    use crate::persist::cache::NodeStateStorage;
-    use crate::persist::swap::SwapStorage;
-    use crate::test_utils::*;
-    use crate::*;
+    async fn track_backup_events(self: &Arc<BreezServices>) {
+        self.spawn_supervised("backup_events", |cloned| async move {
+            let mut events_stream = cloned.backup_watcher.subscribe_events();
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            loop {
+                tokio::select! {
+                    backup_event = events_stream.recv() => {
+                        if let Ok(e) = backup_event {
+                            if let Err(err) = cloned.notify_event_listeners(e).await {
+                                error!("error handling backup event: {:?}", err);
+                            }
+                        }
+                        let backup_status = cloned.backup_status();
+                        info!("backup status: {:?}", backup_status);
+                    },
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Backup watcher task completed");
+                        break;
+                    }
+                }
+            }
+        });
+    }
 
-    use super::{PaymentReceiver, Receiver};
+    /// Tracks the swaps between blocks: their deposits and expiry, redeeming them again if they
+    /// failed to be redeemed, for example because the LSP was unavailable, and refunding the
+    /// expired ones if [Config::swap_auto_refund] is set.
+    async fn start_swap_monitor(self: &Arc<BreezServices>) {
+        self.spawn_supervised("swap_monitor", |cloned| async move {
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(SWAP_MONITOR_INTERVAL_SECS));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = cloned.btc_receive_swapper.monitor_swaps().await {
+                            warn!("Failed to monitor the swaps: {e}");
+                        }
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Swap monitor task completed");
+                        break;
+                    }
+                }
+            }
+        });
+    }
 
-    #[tokio::test]
-    async fn test_node_state() -> Result<()> {
-        // let storage_path = format!("{}/storage.sql", get_test_working_dir());
-        // std::fs::remove_file(storage_path).ok();
+    async fn start_outbox_monitor(self: &Arc<BreezServices>) {
+        self.spawn_supervised("outbox_monitor", |cloned| async move {
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(OUTBOX_MONITOR_INTERVAL_SECS));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            // A new node session is a good time to retry, whatever the backoff
+            let mut ignore_backoff = true;
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = cloned.process_outbox(ignore_backoff).await {
+                            warn!("Failed to process the payment outbox: {e}");
+                        }
+                        ignore_backoff = false;
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Outbox monitor task completed");
+                        break;
+                    }
+                }
+            }
+        });
+    }
 
-        let dummy_node_state = get_dummy_node_state();
+    async fn start_denylist_monitor(self: &Arc<BreezServices>) {
+        if self.config.denylist_url.is_none() {
+            return;
+        }
+        self.spawn_supervised("denylist_monitor", |cloned| async move {
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(DENYLIST_CHECK_INTERVAL_SECS));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = cloned.refresh_denylist().await {
+                            warn!("Failed to refresh the denylist: {e}");
+                        }
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Denylist monitor task completed");
+                        break;
+                    }
+                }
+            }
+        });
+    }
 
-        let lnurl_metadata = "{'key': 'sample-metadata-val'}";
-        let test_ln_address = "test@ln-address.com";
-        let test_lnurl_withdraw_endpoint = "https://test.endpoint.lnurl-w";
-        let sa = SuccessActionProcessed::Message {
-            data: MessageSuccessActionData {
-                message: "test message".into(),
-            },
+    /// Checks whether the LSP peer, the Greenlight node and the swapper respond in time
+    async fn check_service_status(&self) -> Vec<(ServiceComponent, ServiceStatus)> {
+        let lsp = async {
+            if let Some(lsp_pubkey) = self.persister.get_lsp_pubkey()? {
+                self.node_api.ping_peer(lsp_pubkey).await?;
+            }
+            Ok::<_, SdkError>(())
+        };
+        let greenlight = async {
+            self.node_api.get_open_peers().await?;
+            Ok::<_, SdkError>(())
+        };
+        let swapper = async {
+            self.btc_receive_swapper
+                .check_swapper()
+                .await
+                .map_err(SdkError::from)
         };
+        let (lsp, greenlight, swapper) = tokio::join!(
+            service_status(ServiceComponent::Lsp, lsp),
+            service_status(ServiceComponent::Greenlight, greenlight),
+            service_status(ServiceComponent::Swapper, swapper),
+        );
+        vec![
+            (ServiceComponent::Lsp, lsp),
+            (ServiceComponent::Greenlight, greenlight),
+            (ServiceComponent::Swapper, swapper),
+        ]
+    }
 
-        let payment_hash_lnurl_withdraw = "2222";
-        let payment_hash_with_lnurl_success_action = "3333";
-        let payment_hash_swap: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8];
-        let swap_info = SwapInfo {
-            bitcoin_address: "123".to_string(),
-            created_at: 12345678,
-            lock_height: 654321,
-            payment_hash: payment_hash_swap.clone(),
-            preimage: vec![],
-            private_key: vec![],
-            public_key: vec![],
-            swapper_public_key: vec![],
-            script: vec![],
-            bolt11: Some("312".into()),
-            paid_msat: 1000,
-            confirmed_sats: 1,
-            unconfirmed_sats: 0,
-            total_incoming_txs: 1,
-            status: SwapStatus::Refundable,
-            refund_tx_ids: vec![],
-            unconfirmed_tx_ids: vec![],
-            confirmed_tx_ids: vec![],
-            min_allowed_deposit: 5_000,
-            max_allowed_deposit: 1_000_000,
-            max_swapper_payable: 2_000_000,
-            last_redeem_error: None,
-            channel_opening_fees: Some(OpeningFeeParams {
-                min_msat: 5_000_000,
-                proportional: 50,
-                valid_until: "date".to_string(),
-                max_idle_time: 12345,
-                max_client_to_self_delay: 234,
-                promise: "promise".to_string(),
+    /// Fetches the denylist of the [Config::denylist_url], unless the cached one is recent
+    async fn refresh_denylist(&self) -> SdkResult<()> {
+        let Some(url) = &self.config.denylist_url else {
+            return Ok(());
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if let Some(denylist) = self.persister.get_denylist()? {
+            if denylist.url == *url && !denylist.is_stale(now, DENYLIST_MAX_AGE_SECS) {
+                return Ok(());
+            }
+        }
+
+        let (response, _) = get_and_check_success(self.rest_client.as_ref(), url)
+            .await
+            .map_err(|e| SdkError::ServiceConnectivity {
+                err: format!("Failed to fetch the denylist: {e}"),
+            })?;
+        let denylist = Denylist::parse(&response, url, now).map_err(|e| SdkError::Generic {
+            err: format!("Failed to parse the denylist: {e}"),
+        })?;
+        info!("Fetched a denylist of {} entries", denylist.entries.len());
+        self.persister.set_denylist(&denylist)?;
+        Ok(())
+    }
+
+    /// The first of the payment destinations that is in the cached denylist, if any.
+    ///
+    /// Payments are not blocked when the denylist can't be read or wasn't fetched yet.
+    fn denied_destination<'a>(&self, destinations: &[&'a str]) -> Option<&'a str> {
+        let url = self.config.denylist_url.as_ref()?;
+        match self.persister.get_denylist() {
+            Ok(Some(denylist)) if denylist.url == *url => denylist.find_denied(destinations),
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Failed to read the denylist: {e}");
+                None
+            }
+        }
+    }
+
+    /// Checks a payment of `amount_msat` to the payee identified by `destinations` against the
+    /// [Config::spend_policy], and counts it towards the daily limit with up to `max_fee_msat`
+    /// of routing fees. The destinations are not checked if `None`, for the partner fee payments.
+    ///
+    /// The amount stops being counted when the returned reservation is dropped, unless it's kept
+    /// once the payment is sent. Returns no reservation if there is no spend policy.
+    fn reserve_spend(
+        &self,
+        amount_msat: u64,
+        max_fee_msat: u64,
+        destinations: Option<&[&str]>,
+    ) -> Result<Option<SpendReservation>, SpendPolicyError> {
+        let Some(policy) = &self.config.spend_policy else {
+            return Ok(None);
+        };
+        policy.check(amount_msat, destinations)?;
+        let outflow_msat = amount_msat.saturating_add(max_fee_msat);
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        let since = now - SPEND_POLICY_WINDOW_SECS;
+        match self.persister.insert_spend_within_limit(
+            outflow_msat,
+            now,
+            since,
+            policy.daily_limit_msat,
+        )? {
+            Some(id) => Ok(Some(SpendReservation::new(
+                self.persister.clone(),
+                id,
+                amount_msat,
+            ))),
+            None => Err(SpendPolicyError::DailyLimitExceeded {
+                amount_msat: outflow_msat,
+                limit_msat: policy.daily_limit_msat.unwrap_or_default(),
+                spent_msat: self.persister.get_spent_since(since)?,
             }),
-            confirmed_at: Some(555),
+        }
+    }
+
+    /// Checks a payment of `amount_msat` against the [Config::htlc_exposure_limits], and tracks
+    /// it in flight until the returned reservation is dropped.
+    ///
+    /// The pending outgoing payments are counted in flight, including their fees.
+    fn reserve_htlc_exposure(
+        &self,
+        amount_msat: u64,
+    ) -> Result<Option<InflightReservation>, HtlcExposureError> {
+        let Some(limits) = &self.config.htlc_exposure_limits else {
+            return Ok(None);
         };
-        let payment_hash_rev_swap: Vec<u8> = vec![8, 7, 6, 5, 4, 3, 2, 1];
-        let preimage_rev_swap: Vec<u8> = vec![6, 6, 6, 6];
-        let full_ref_swap_info = FullReverseSwapInfo {
-            id: "rev_swap_id".to_string(),
-            created_at_block_height: 0,
-            preimage: preimage_rev_swap.clone(),
-            private_key: vec![],
-            claim_pubkey: "claim_pubkey".to_string(),
-            timeout_block_height: 600_000,
-            invoice: "645".to_string(),
-            redeem_script: "redeem_script".to_string(),
-            onchain_amount_sat: 250,
-            sat_per_vbyte: Some(50),
-            receive_amount_sat: None,
-            cache: ReverseSwapInfoCached {
-                status: ReverseSwapStatus::CompletedConfirmed,
-                lockup_txid: Some("lockup_txid".to_string()),
-                claim_txid: Some("claim_txid".to_string()),
-            },
+        let channels_spendable_msat: Vec<u64> = self
+            .persister
+            .list_channels()?
+            .into_iter()
+            .filter(|c| c.state == ChannelState::Opened)
+            .map(|c| c.spendable_msat)
+            .collect();
+        let reservation = self.htlc_inflight.reserve(
+            amount_msat,
+            || Ok(self.persister.get_pending_sent_msat()?),
+            |inflight_msat| limits.check(amount_msat, inflight_msat, &channels_spendable_msat),
+        )?;
+        Ok(Some(reservation))
+    }
+
+    /// Watches the connectivity in a single task. The connection to the node and the LSP peer is
+    /// checked regularly and restored when lost, see [BreezServices::restore_connection]. If
+    /// [Config::service_status_check_interval_secs] is set, the status of the services is
+    /// checked too, emitting a [BreezEvent::ServiceStatusChanged] when one degrades or recovers.
+    async fn start_connectivity_monitor(self: &Arc<BreezServices>) {
+        let service_status_interval_secs = self.config.service_status_check_interval_secs;
+        self.spawn_supervised("connectivity_monitor", move |cloned| async move {
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            let mut connection_interval =
+                tokio::time::interval(Duration::from_secs(CONNECTION_CHECK_INTERVAL_SECS));
+            connection_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            // The first tick completes immediately, while connect just reached the node
+            connection_interval.tick().await;
+            let mut service_status_interval = service_status_interval_secs.map(|secs| {
+                let mut interval = tokio::time::interval(Duration::from_secs(secs.max(1)));
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                interval
+            });
+            let mut statuses = HashMap::new();
+            loop {
+                tokio::select! {
+                    _ = connection_interval.tick() => {
+                        let Err(e) = cloned.check_connection().await else {
+                            continue;
+                        };
+                        if !cloned.restore_connection(e.to_string(), &mut shutdown_receiver).await {
+                            break;
+                        }
+                    }
+                    _ = tick_if_set(&mut service_status_interval) => {
+                        let checked = cloned.check_service_status().await;
+                        for event in service_status_events(&mut statuses, checked) {
+                            _ = cloned.on_event(event).await;
+                        }
+                    }
+                    _ = shutdown_receiver.changed() => break,
+                }
+            }
+            debug!("Connectivity monitor task completed");
+        });
+    }
+
+    /// Reconnects after the connection was lost with `error`, with an exponential backoff.
+    /// Emits a [BreezEvent::Disconnected], then a [BreezEvent::Reconnecting] before each attempt
+    /// and a [BreezEvent::Connected] once reconnected.
+    ///
+    /// Returns false if the SDK was shut down before it reconnected.
+    async fn restore_connection(
+        &self,
+        error: String,
+        shutdown_receiver: &mut watch::Receiver<()>,
+    ) -> bool {
+        warn!("Lost the connection: {error}");
+        self.set_connection_status(ConnectionState::Disconnected, 0, Some(error.clone()))
+            .await;
+        _ = self.on_event(BreezEvent::Disconnected { error }).await;
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let delay_ms = reconnect_delay_ms(attempt);
+            self.set_connection_status(ConnectionState::Reconnecting, attempt, None)
+                .await;
+            _ = self
+                .on_event(BreezEvent::Reconnecting { attempt, delay_ms })
+                .await;
+            tokio::select! {
+                _ = sleep(Duration::from_millis(delay_ms)) => {}
+                _ = shutdown_receiver.changed() => return false,
+            }
+
+            match self.reconnect().await {
+                Ok(()) => {
+                    info!("Reconnected after {attempt} attempts");
+                    self.set_connection_status(ConnectionState::Connected, 0, None)
+                        .await;
+                    _ = self.on_event(BreezEvent::Connected).await;
+                    return true;
+                }
+                Err(e) => {
+                    warn!("Reconnection attempt {attempt} failed: {e}");
+                    self.connection_status.lock().await.last_error = Some(e.to_string());
+                }
+            }
+        }
+    }
+
+    /// Pings the LSP peer through the node, or only reaches the node when no LSP is selected
+    async fn check_connection(&self) -> SdkResult<()> {
+        match self.persister.get_lsp_pubkey()? {
+            Some(lsp_pubkey) => self.node_api.ping_peer(lsp_pubkey).await?,
+            None => _ = self.node_api.get_open_peers().await?,
+        }
+        Ok(())
+    }
+
+    /// Pings the LSP peer before a payment is sent or an invoice is created, and reconnects it
+    /// if it can't be reached, as first attempts often fail only because the connection went
+    /// idle. Emits a [BreezEvent::LspPeerReconnected] when it was reconnected.
+    ///
+    /// This is best effort and bounded by short timeouts: a failed reconnection is logged and
+    /// recorded for [BreezServices::peer_connectivity], and the operation is attempted anyway.
+    async fn ensure_lsp_peer_connected(&self) {
+        let lsp_pubkey = match self.persister.get_lsp_pubkey() {
+            Ok(Some(lsp_pubkey)) => lsp_pubkey,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to read the LSP pubkey: {e}");
+                return;
+            }
         };
-        let rev_swap_info = ReverseSwapInfo {
-            id: "rev_swap_id".to_string(),
-            claim_pubkey: "claim_pubkey".to_string(),
-            lockup_txid: Some("lockup_txid".to_string()),
-            claim_txid: Some("claim_txid".to_string()),
-            onchain_amount_sat: 250,
-            status: ReverseSwapStatus::CompletedConfirmed,
+        let ping = timeout(
+            Duration::from_secs(LSP_PEER_PING_TIMEOUT_SECS),
+            self.node_api.ping_peer(lsp_pubkey.clone()),
+        )
+        .await;
+        let reason = match ping {
+            Ok(Ok(())) => return,
+            Ok(Err(e)) => e.to_string(),
+            Err(_) => "Ping timed out".to_string(),
         };
-        let dummy_transactions = vec![
-            Payment {
-                id: "1111".to_string(),
-                payment_type: PaymentType::Received,
-                payment_time: 100000,
-                amount_msat: 10,
-                fee_msat: 0,
-                status: PaymentStatus::Complete,
-                error: None,
-                description: Some("test receive".to_string()),
-                details: PaymentDetails::Ln {
-                    data: LnPaymentDetails {
-                        payment_hash: "1111".to_string(),
-                        label: "".to_string(),
-                        destination_pubkey: "1111".to_string(),
-                        payment_preimage: "2222".to_string(),
-                        keysend: false,
-                        bolt11: "1111".to_string(),
-                        lnurl_success_action: None,
-                        lnurl_pay_domain: None,
-                        lnurl_pay_comment: None,
-                        lnurl_metadata: None,
-                        ln_address: None,
-                        lnurl_withdraw_endpoint: None,
-                        swap_info: None,
-                        reverse_swap_info: None,
-                        pending_expiration_block: None,
-                        open_channel_bolt11: None,
-                    },
-                },
-                metadata: None,
-            },
-            Payment {
-                id: payment_hash_lnurl_withdraw.to_string(),
-                payment_type: PaymentType::Received,
-                payment_time: 150000,
-                amount_msat: 10,
-                fee_msat: 0,
-                status: PaymentStatus::Complete,
-                error: None,
-                description: Some("test lnurl-withdraw receive".to_string()),
-                details: PaymentDetails::Ln {
-                    data: LnPaymentDetails {
-                        payment_hash: payment_hash_lnurl_withdraw.to_string(),
-                        label: "".to_string(),
-                        destination_pubkey: "1111".to_string(),
-                        payment_preimage: "3333".to_string(),
-                        keysend: false,
-                        bolt11: "1111".to_string(),
-                        lnurl_success_action: None,
-                        lnurl_pay_domain: None,
-                        lnurl_pay_comment: None,
-                        lnurl_metadata: None,
-                        ln_address: None,
-                        lnurl_withdraw_endpoint: Some(test_lnurl_withdraw_endpoint.to_string()),
-                        swap_info: None,
-                        reverse_swap_info: None,
-                        pending_expiration_block: None,
-                        open_channel_bolt11: None,
-                    },
-                },
+
+        info!("LSP peer {lsp_pubkey} can't be reached ({reason}), reconnecting");
+        if self.reconnect_lsp_peer(&lsp_pubkey).await.is_err() {
+            return;
+        }
+        let event = BreezEvent::LspPeerReconnected { lsp_pubkey, reason };
+        if let Err(e) = self.notify_event_listeners(event).await {
+            warn!("Failed to notify the LSP peer reconnection: {e}");
+        }
+    }
+
+    /// Connects the node to the LSP peer again, within a short timeout. The attempt is recorded
+    /// for [BreezServices::peer_connectivity].
+    async fn reconnect_lsp_peer(&self, lsp_pubkey: &str) -> SdkResult<()> {
+        let reconnect = async {
+            let lsp = self.lsp_info().await?;
+            self.node_api.connect_peer(lsp.pubkey, lsp.host).await?;
+            Ok::<(), SdkError>(())
+        };
+        let res = match timeout(
+            Duration::from_secs(LSP_PEER_RECONNECT_TIMEOUT_SECS),
+            reconnect,
+        )
+        .await
+        {
+            Ok(res) => res,
+            Err(_) => Err(SdkError::ServiceConnectivity {
+                err: "Reconnection timed out".into(),
+            }),
+        };
+
+        let mut reconnections = self.lsp_reconnections.lock().await;
+        reconnections.attempts += 1;
+        if let Err(e) = &res {
+            warn!("Failed to reconnect the LSP peer {lsp_pubkey}: {e}");
+            reconnections.failed_attempts += 1;
+            reconnections.last_error = Some(format!("(LSP: {lsp_pubkey}) {e}"));
+            return res;
+        }
+        reconnections.last_reconnected_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.as_secs() as i64)
+            .ok();
+        info!("Reconnected the LSP peer {lsp_pubkey}");
+        Ok(())
+    }
+
+    /// Connects to the LSP peer again if one is selected, syncs and checks the connection again
+    async fn reconnect(&self) -> SdkResult<()> {
+        if let Some(lsp_pubkey) = self.persister.get_lsp_pubkey()? {
+            self.reconnect_lsp_peer(&lsp_pubkey).await?;
+        }
+        self.sync().await?;
+        self.check_connection().await
+    }
+
+    /// Updates the [ConnectionStatus], keeping its `since` while the state doesn't change and
+    /// its `last_error` until connected again
+    async fn set_connection_status(
+        &self,
+        state: ConnectionState,
+        reconnect_attempts: u32,
+        last_error: Option<String>,
+    ) {
+        let mut status = self.connection_status.lock().await;
+        if status.state != state {
+            status.state = state;
+            status.since = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default();
+        }
+        status.reconnect_attempts = reconnect_attempts;
+        if last_error.is_some() || state == ConnectionState::Connected {
+            status.last_error = last_error;
+        }
+    }
+
+    /// Attempts the queued payments that are due, one after the other
+    async fn process_outbox(&self, ignore_backoff: bool) -> SdkResult<()> {
+        for outbox_payment in self.persister.list_outbox_payments()? {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+            if ignore_backoff || outbox_payment.next_attempt_at <= now {
+                self.attempt_outbox_payment(outbox_payment, now).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn attempt_outbox_payment(
+        &self,
+        mut outbox_payment: OutboxPayment,
+        now: i64,
+    ) -> SdkResult<()> {
+        let status = match now >= outbox_payment.expires_at {
+            true => OutboxPaymentStatus::Abandoned,
+            false => {
+                outbox_payment.attempts += 1;
+                match self.send_payment(outbox_payment.req.clone()).await {
+                    Ok(res) => OutboxPaymentStatus::Sent {
+                        payment: res.payment,
+                    },
+                    Err(e) => {
+                        let permanent = matches!(
+                            e,
+                            SendPaymentError::AlreadyPaid
+                                | SendPaymentError::DeniedDestination { .. }
+                                | SendPaymentError::InvalidAmount { .. }
+                                | SendPaymentError::InvalidInvoice { .. }
+                                | SendPaymentError::InvalidNetwork { .. }
+                                | SendPaymentError::InvoiceExpired { .. }
+                        );
+                        outbox_payment.last_error = Some(e.to_string());
+                        match permanent || outbox_payment.attempts >= OUTBOX_MAX_ATTEMPTS {
+                            true => OutboxPaymentStatus::Abandoned,
+                            false => OutboxPaymentStatus::Queued,
+                        }
+                    }
+                }
+            }
+        };
+        info!(
+            "Outbox payment {} attempt {}: {status:?}",
+            outbox_payment.id, outbox_payment.attempts
+        );
+
+        match status {
+            OutboxPaymentStatus::Queued => {
+                outbox_payment.next_attempt_at =
+                    now + outbox_retry_delay_secs(outbox_payment.attempts) as i64;
+                self.persister
+                    .update_outbox_payment_attempt(&outbox_payment)?;
+            }
+            _ => {
+                self.persister.delete_outbox_payment(outbox_payment.id)?;
+            }
+        }
+        Ok(self
+            .notify_event_listeners(BreezEvent::OutboxPaymentUpdated {
+                details: OutboxPaymentDetails {
+                    outbox_payment,
+                    status,
+                },
+            })
+            .await?)
+    }
+
+    async fn track_swap_events(self: &Arc<BreezServices>) {
+        self.spawn_supervised("swap_events", |cloned| async move {
+            let mut swap_events_stream = cloned.btc_receive_swapper.subscribe_status_changes();
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            loop {
+                tokio::select! {
+                    swap_event = swap_events_stream.recv() => {
+                        if let Ok(e) = swap_event {
+                            if let Err(err) = cloned.notify_event_listeners(e).await {
+                                error!("error handling swap event: {:?}", err);
+                            }
+                        }
+                    },
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Swap events handling task completed");
+                        break;
+                    }
+                }
+            }
+        });
+
+        #[cfg(feature = "reverse-swaps")]
+        {
+            self.spawn_supervised("reverse_swap_events", |cloned| async move {
+                let mut rev_swap_events_stream = cloned.btc_send_swapper.subscribe_status_changes();
+                let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+                loop {
+                    tokio::select! {
+                        rev_swap_event = rev_swap_events_stream.recv() => {
+                            if let Ok(e) = rev_swap_event {
+                                if let Err(err) = cloned.notify_event_listeners(e).await {
+                                    error!("error handling reverse swap event: {:?}", err);
+                                }
+                            }
+                        },
+                        _ = shutdown_receiver.changed() => {
+                            debug!("Reverse swap events handling task completed");
+                            break;
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    async fn track_invoices(self: &Arc<BreezServices>) {
+        self.spawn_supervised("invoices", |cloned| async move {
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            loop {
+                if shutdown_receiver.has_changed().unwrap_or(true) {
+                    return;
+                }
+                let mut invoice_stream = match cloned.node_api.stream_incoming_payments().await {
+                    Ok(invoice_stream) => invoice_stream,
+                    Err(e) => {
+                        warn!("stream incoming payments returned error: {:?}", e);
+                        tokio::select! {
+                            _ = sleep(Duration::from_secs(1)) => {
+                                continue
+                            }
+                            _ = shutdown_receiver.changed() => {
+                                debug!("Invoice tracking task has completed");
+                                return;
+                            }
+                        };
+                    }
+                };
+
+                loop {
+                    let paid_invoice_res = tokio::select! {
+                        paid_invoice_res = invoice_stream.message() => {
+                            paid_invoice_res
+                        }
+
+                        _ = shutdown_receiver.changed() => {
+                            debug!("Invoice tracking task has completed");
+                            return;
+                        }
+                    };
+
+                    let i = match paid_invoice_res {
+                        Ok(Some(i)) => i,
+                        Ok(None) => {
+                            debug!("invoice stream got None");
+                            break;
+                        }
+                        Err(err) => {
+                            debug!("invoice stream got error: {:?}", err);
+                            break;
+                        }
+                    };
+
+                    debug!("invoice stream got new invoice");
+                    let p = match i.details {
+                        Some(incoming_payment::Details::Offchain(p)) => p,
+                        _ => continue,
+                    };
+
+                    // The custom TLV records are only reported here, so they are stored
+                    // before the payment is loaded with them
+                    if !p.extratlvs.is_empty() {
+                        let tlvs: Vec<TlvEntry> = p
+                            .extratlvs
+                            .iter()
+                            .map(|tlv| TlvEntry {
+                                field_number: tlv.r#type,
+                                value: tlv.value.clone(),
+                            })
+                            .collect();
+                        let res = cloned
+                            .persister
+                            .insert_payment_tlvs(&hex::encode(&p.payment_hash), &tlvs);
+                        debug!("received tlvs were stored {res:?}");
+                    }
+
+                    let mut payment: Option<crate::models::Payment> = p.clone().try_into().ok();
+                    if let Some(ref p) = payment {
+                        let res = cloned.persister.insert_or_update_payments(
+                            &[cloned.config.memo_privacy.apply_to_payment(p.clone())],
+                            false,
+                        );
+                        debug!("paid invoice was added to payments list {res:?}");
+                        if let Ok(Some(mut node_info)) = cloned.persister.get_node_state() {
+                            node_info.channels_balance_msat += p.amount_msat;
+                            let res = cloned.persister.set_node_state(&node_info);
+                            debug!("channel balance was updated {res:?}");
+                        }
+                        payment = cloned
+                            .persister
+                            .get_payment_by_hash(&p.id)
+                            .unwrap_or(payment);
+                    }
+                    let spontaneous_payment = payment.clone().filter(
+                        |p| matches!(&p.details, PaymentDetails::Ln { data } if data.keysend),
+                    );
+                    let payment_hash = hex::encode(p.payment_hash);
+                    let open_channel_fee_msat = payment.as_ref().and_then(|p| match &p.details {
+                        PaymentDetails::Ln { data } if data.open_channel_bolt11.is_some() => {
+                            Some(p.fee_msat)
+                        }
+                        _ => None,
+                    });
+                    _ = cloned
+                        .on_event(BreezEvent::InvoicePaid {
+                            details: InvoicePaidDetails {
+                                payment_hash: payment_hash.clone(),
+                                bolt11: p.bolt11,
+                                payment,
+                            },
+                        })
+                        .await;
+                    if let Some(details) = spontaneous_payment {
+                        _ = cloned
+                            .on_event(BreezEvent::SpontaneousPaymentReceived { details })
+                            .await;
+                    }
+                    if let Some(opening_fee_msat) = open_channel_fee_msat {
+                        cloned
+                            .notify_open_channel_receive(
+                                payment_hash,
+                                OpenChannelReceiveStage::Settled { opening_fee_msat },
+                            )
+                            .await;
+                    }
+                    if let Err(e) = cloned.do_sync(true).await {
+                        error!("failed to sync after paid invoice: {:?}", e);
+                    }
+                }
+
+                tokio::select! {
+                    _ = sleep(Duration::from_secs(1)) => {
+                        continue
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Invoice tracking task has completed");
+                        return;
+                    }
+                };
+            }
+        });
+    }
+
+    async fn track_logs(self: &Arc<BreezServices>) {
+        self.spawn_supervised("logs", |cloned| async move {
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            loop {
+                if shutdown_receiver.has_changed().unwrap_or(true) {
+                    return;
+                }
+                let mut log_stream = match cloned.node_api.stream_log_messages().await {
+                    Ok(log_stream) => log_stream,
+                    Err(e) => {
+                        warn!("stream log messages returned error: {:?}", e);
+                        tokio::select! {
+                            _ = sleep(Duration::from_secs(1)) => {
+                                continue
+                            }
+                            _ = shutdown_receiver.changed() => {
+                                debug!("Invoice tracking task has completed");
+                                return;
+                            }
+                        };
+                    }
+                };
+
+                loop {
+                    let log_message_res = tokio::select! {
+                        log_message_res = log_stream.message() => {
+                            log_message_res
+                        }
+
+                        _ = shutdown_receiver.changed() => {
+                            debug!("Track logs task has completed");
+                            return;
+                        }
+                    };
+
+                    match log_message_res {
+                        Ok(Some(l)) => info!("node-logs: {}", l.line),
+                        Ok(None) => {
+                            // stream is closed, renew it
+                            break;
+                        }
+                        Err(err) => {
+                            debug!("failed to process log entry {:?}", err);
+                            break;
+                        }
+                    };
+                }
+
+                tokio::select! {
+                    _ = sleep(Duration::from_secs(1)) => {
+                        continue
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        debug!("Invoice tracking task has completed");
+                        return;
+                    }
+                };
+            }
+        });
+    }
+
+    async fn track_new_blocks(self: &Arc<BreezServices>) {
+        self.spawn_supervised("new_blocks", |cloned| async move {
+            let mut current_block: u32 = 0;
+            let mut shutdown_receiver = cloned.shutdown_sender.subscribe();
+            let mut interval = tokio::time::interval(Duration::from_secs(30));
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => { }
+
+                    _ = shutdown_receiver.changed() => {
+                        debug!("New blocks task has completed");
+                        return;
+                    }
+                }
+
+                let next_block = match cloned.chain_service.current_tip().await {
+                    Ok(next_block) => next_block,
+                    Err(e) => {
+                        error!("failed to fetch next block {}", e);
+                        continue;
+                    }
+                };
+
+                debug!("got tip {:?}", next_block);
+                if next_block > current_block {
+                    _ = cloned.sync().await;
+                    _ = cloned
+                        .on_event(BreezEvent::NewBlock { block: next_block })
+                        .await;
+                }
+                current_block = next_block
+            }
+        });
+    }
+
+    /// Fetches the mempool.space URLs in the background, retried until they are cached
+    async fn init_chainservice_urls(self: &Arc<BreezServices>) -> Result<()> {
+        let breez_server = Arc::new(BreezServer::new_with_proxy(
+            PRODUCTION_BREEZSERVER_URL.to_string(),
+            None,
+            self.config.proxy.clone(),
+        )?);
+        self.spawn_supervised("mempool_space_urls", move |cloned| {
+            let breez_server = breez_server.clone();
+            async move {
+                let fresh_urls = breez_server
+                    .fetch_mempoolspace_urls()
+                    .await
+                    .map_err(|e| anyhow!("Failed to fetch mempool.space URLs: {e}"))?;
+                cloned
+                    .persister
+                    .set_mempoolspace_base_urls(fresh_urls)
+                    .map_err(|e| anyhow!("Failed to cache mempool.space URLs: {e}"))
+            }
+        });
+        Ok(())
+    }
+
+    /// Configures a global SDK logger that will log to file and will forward log events to
+    /// an optional application-specific logger.
+    ///
+    /// If called, it should be called before any SDK methods (for example, before `connect`).
+    ///
+    /// It must be called only once in the application lifecycle. Alternatively, If the application
+    /// already uses a globally-registered logger, this method shouldn't be called at all.
+    ///
+    /// ### Arguments
+    ///
+    /// - `log_dir`: Location where the the SDK log file will be created. The directory must already exist.
+    ///
+    /// - `app_logger`: Optional application logger.
+    ///
+    /// If the application is to use it's own logger, but would also like the SDK to log SDK-specific
+    /// log output to a file in the configured `log_dir`, then do not register the
+    /// app-specific logger as a global logger and instead call this method with the app logger as an arg.
+    ///
+    /// ### Logging Configuration
+    ///
+    /// Setting `breez_sdk_core::input_parser=debug` will include in the logs the raw payloads received
+    /// when interacting with JSON endpoints, for example those used during all LNURL workflows.
+    ///
+    /// The log filter can be set with [Config::log_filter] and changed at any time with
+    /// [BreezServices::set_log_filter].
+    ///
+    /// ### Errors
+    ///
+    /// An error is thrown if the log file cannot be created in the working directory.
+    ///
+    /// An error is thrown if a global logger is already configured.
+    pub fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>) -> Result<()> {
+        Self::init_logging_with_options(
+            LogFileOptions {
+                log_dir: log_dir.to_string(),
+                max_log_size_bytes: None,
+                max_rotated_files: None,
+            },
+            app_logger,
+        )
+    }
+
+    /// Same as [BreezServices::init_logging], but additionally allows to limit the size of the
+    /// log file. See [LogFileOptions].
+    pub fn init_logging_with_options(
+        options: LogFileOptions,
+        app_logger: Option<Box<dyn log::Log>>,
+    ) -> Result<()> {
+        let target_log_file = Box::new(
+            RotatingLogFile::open(&options).map_err(|e| anyhow!("Can't create log file: {e}"))?,
+        );
+        // The records are filtered by the GlobalSdkLogger, so the filter can be changed at runtime
+        let logger = env_logger::Builder::new()
+            .target(env_logger::Target::Pipe(target_log_file))
+            .filter_level(LevelFilter::Trace)
+            .format(|buf, record| {
+                writeln!(
+                    buf,
+                    "[{} {} {}:{}] {}",
+                    Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+                    record.level(),
+                    record.module_path().unwrap_or("unknown"),
+                    record.line().unwrap_or(0),
+                    record.args()
+                )
+            })
+            .build();
+
+        let global_logger = GlobalSdkLogger {
+            logger,
+            default_filter: env_logger::filter::Builder::new()
+                .parse(DEFAULT_LOG_FILTER)
+                .build(),
+            log_listener: app_logger,
+        };
+
+        log::set_boxed_logger(Box::new(global_logger))
+            .map_err(|e| anyhow!("Failed to set global logger: {e}"))?;
+        log::set_max_level(LevelFilter::Trace);
+
+        Ok(())
+    }
+
+    /// Replaces the log filter of the SDK loggers at runtime, for example to turn on verbose
+    /// logging with `breez_sdk_core=trace,gl_client=debug` while investigating an issue.
+    ///
+    /// The filter uses the `RUST_LOG` syntax: comma-separated directives, each either a level
+    /// or a `module=level` pair. It applies to the log file of [BreezServices::init_logging] and
+    /// to the log streams. Passing an empty filter restores the default filters.
+    pub fn set_log_filter(filter: &str) -> SdkResult<()> {
+        let log_filter = match filter.trim().is_empty() {
+            true => None,
+            false => Some(parse_log_filter(filter)?),
+        };
+        *LOG_FILTER
+            .write()
+            .map_err(|e| SdkError::generic(&e.to_string()))? = log_filter;
+        Ok(())
+    }
+
+    async fn lookup_chain_service_closing_outspend(
+        &self,
+        channel: crate::models::Channel,
+    ) -> Result<Option<Outspend>> {
+        match channel.funding_outnum {
+            None => Ok(None),
+            Some(outnum) => {
+                // Find the output tx that was used to fund the channel
+                let outspends = self
+                    .chain_service
+                    .transaction_outspends(channel.funding_txid.clone())
+                    .await?;
+
+                Ok(outspends.get(outnum as usize).cloned())
+            }
+        }
+    }
+
+    /// Chain service lookup of relevant channel closing fields (closed_at, closing_txid).
+    ///
+    /// Should be used sparingly because it involves a network lookup.
+    async fn lookup_channel_closing_data(
+        &self,
+        channel: &crate::models::Channel,
+    ) -> Result<(Option<u64>, Option<String>)> {
+        let maybe_outspend_res = self
+            .lookup_chain_service_closing_outspend(channel.clone())
+            .await;
+        let maybe_outspend: Option<Outspend> = match maybe_outspend_res {
+            Ok(s) => s,
+            Err(e) => {
+                error!("Failed to lookup channel closing data: {:?}", e);
+                None
+            }
+        };
+
+        let maybe_closed_at = maybe_outspend
+            .clone()
+            .and_then(|outspend| outspend.status)
+            .and_then(|s| s.block_time);
+        let maybe_closing_txid = maybe_outspend.and_then(|outspend| outspend.txid);
+
+        Ok((maybe_closed_at, maybe_closing_txid))
+    }
+
+    async fn closed_channel_to_transaction(
+        &self,
+        channel: crate::models::Channel,
+    ) -> Result<Payment> {
+        let (payment_time, closing_txid) = match (channel.closed_at, channel.closing_txid.clone()) {
+            (Some(closed_at), Some(closing_txid)) => (closed_at as i64, Some(closing_txid)),
+            (_, _) => {
+                // If any of the two closing-related fields are empty, we look them up and persist them
+                let (maybe_closed_at, maybe_closing_txid) =
+                    self.lookup_channel_closing_data(&channel).await?;
+
+                let processed_closed_at = match maybe_closed_at {
+                    None => {
+                        warn!("Blocktime could not be determined for from closing outspend, defaulting closed_at to epoch time");
+                        SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()
+                    }
+                    Some(block_time) => block_time,
+                };
+
+                let mut updated_channel = channel.clone();
+                updated_channel.closed_at = Some(processed_closed_at);
+                // If no closing txid found, we persist it as None, so it will be looked-up next time
+                updated_channel.closing_txid.clone_from(&maybe_closing_txid);
+                self.persister.insert_or_update_channel(updated_channel)?;
+
+                (processed_closed_at as i64, maybe_closing_txid)
+            }
+        };
+
+        Ok(Payment {
+            id: channel.funding_txid.clone(),
+            payment_type: PaymentType::ClosedChannel,
+            payment_time,
+            amount_msat: channel.local_balance_msat,
+            fee_msat: 0,
+            status: match channel.state {
+                ChannelState::PendingClose => PaymentStatus::Pending,
+                _ => PaymentStatus::Complete,
+            },
+            description: Some("Closed Channel".to_string()),
+            details: PaymentDetails::ClosedChannel {
+                data: ClosedChannelPaymentDetails {
+                    short_channel_id: channel.short_channel_id,
+                    state: channel.state,
+                    funding_txid: channel.funding_txid,
+                    closing_txid,
+                },
+            },
+            error: None,
+            metadata: None,
+            fiat_rate: None,
+            imported: false,
+        })
+    }
+
+    /// Register for webhook callbacks at the given `webhook_url`.
+    ///
+    /// More specifically, it registers for the following types of callbacks:
+    /// - a payment is received
+    /// - a swap tx is confirmed
+    ///
+    /// The `webhook_url` is persisted and its payment notifications registration is refreshed every
+    /// time the SDK is started, so the callbacks keep working across restarts. The method should
+    /// still be called when the `webhook_url` changes. For example, if the `webhook_url` contains a push notification token and the token changes after
+    /// the application was started, then this method should be called to register for callbacks at
+    /// the new correct `webhook_url`. To unregister a webhook call [BreezServices::unregister_webhook].
+    pub async fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
+        info!("Registering for webhook notifications");
+        let is_new_webhook_url = match self.persister.get_webhook_url()? {
+            None => true,
+            Some(cached_webhook_url) => cached_webhook_url != webhook_url,
+        };
+        match is_new_webhook_url {
+            false => debug!("Webhook URL not changed, no need to (re-)register for monitored swap tx notifications"),
+            true => {
+                for swap in self.btc_receive_swapper.list_swaps(ListSwapsRequest {
+                    status: Some(SwapStatus::unexpired()),
+                    ..Default::default()
+                })?
+                {
+                    let swap_address = &swap.bitcoin_address;
+                    info!("Found non-refundable monitored swap with address {swap_address}, registering for onchain tx notifications");
+                    self.register_onchain_tx_notification(swap_address, &webhook_url)
+                        .await?;
+                }
+
+                #[cfg(feature = "reverse-swaps")]
+                for rev_swap in self
+                    .btc_send_swapper
+                    .list_monitored()
+                    .await?
+                    .iter()
+                {
+                    let lockup_address = &rev_swap.get_lockup_address(self.config.network)?.to_string();
+                    info!("Found monitored reverse swap with address {lockup_address}, registering for onchain tx notifications");
+                    self.register_onchain_tx_notification(lockup_address, &webhook_url)
+                        .await?;
+                }
+            }
+        }
+
+        // Register for LN payment notifications on every call, since these webhook registrations
+        // timeout after 14 days of not being used
+        self.register_payment_notifications(webhook_url.clone())
+            .await?;
+
+        // Only cache the webhook URL if callbacks were successfully registered for it.
+        // If any step above failed, not caching it allows the caller to re-trigger the registrations
+        // by calling the method again
+        self.persister.set_webhook_url(webhook_url.clone())?;
+
+        // The lightning address is served through the webhook, so it has to follow it
+        if is_new_webhook_url {
+            if let Some(info) = self.persister.get_lnurl_pay_info()? {
+                let username = info.lightning_address.split('@').next().unwrap_or_default();
+                if let Err(e) = self.register_lnurl_pay(&webhook_url, username).await {
+                    warn!("Failed to move the lightning address to the new webhook: {e}");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers the lightning address `username@domain` of the wallet with the LNURL-pay server
+    /// at [Config::lnurl_server_url], and returns it along with the reusable LNURL-pay code.
+    ///
+    /// The server asks for the invoices through the webhook registered with
+    /// [BreezServices::register_webhook], which has to be called first, so they are created by
+    /// the same handler as for the other notifications. Registering another `username` replaces
+    /// the lightning address.
+    pub async fn register_lightning_address(&self, username: String) -> SdkResult<LnurlPayInfo> {
+        let webhook_url = self.persister.get_webhook_url()?.ok_or(SdkError::generic(
+            "No webhook registered, call register_webhook first",
+        ))?;
+        let info = self.register_lnurl_pay(&webhook_url, &username).await?;
+        self.persister.set_lnurl_pay_info(&info)?;
+        Ok(info)
+    }
+
+    /// Returns the lightning address and LNURL-pay code registered with
+    /// [BreezServices::register_lightning_address], if any
+    pub fn lnurl_pay_info(&self) -> SdkResult<Option<LnurlPayInfo>> {
+        Ok(self.persister.get_lnurl_pay_info()?)
+    }
+
+    /// Sets up a static LNURL-pay endpoint backed by the wallet, for example for donations
+    /// through a printed QR code which always works, without a server of the Breez SDK.
+    ///
+    /// The endpoint is hosted at [CreateStaticLnurlPayRequest::url], which serves the returned
+    /// [StaticLnurlPay::pay_request]. The requests of the payers to the
+    /// [CreateStaticLnurlPayRequest::callback_url] are forwarded to the wallet, for example
+    /// through the webhook registered with [BreezServices::register_webhook], and answered with
+    /// [BreezServices::static_lnurl_pay_callback]. Setting up another endpoint replaces it.
+    #[cfg(feature = "lnurl")]
+    pub async fn create_static_lnurl_pay(
+        &self,
+        req: CreateStaticLnurlPayRequest,
+    ) -> SdkResult<StaticLnurlPay> {
+        req.validate()?;
+        let static_lnurl_pay = StaticLnurlPay {
+            lnurl: encode_lnurl(&req.url)?,
+            metadata: req.metadata(),
+            pay_request: req.pay_request(),
+        };
+        self.persister.set_static_lnurl_pay(&req)?;
+        Ok(static_lnurl_pay)
+    }
+
+    /// Answers a request of a payer to the callback URL of the endpoint set up with
+    /// [BreezServices::create_static_lnurl_pay], with the JSON response to return to the payer.
+    ///
+    /// The amount and the comment are checked against the endpoint, and an invoice committing to
+    /// its metadata is created. A request the endpoint doesn't accept is answered with a LUD-06
+    /// error response. The comment of the payer, if any, is kept in the details of the payment.
+    #[cfg(feature = "lnurl")]
+    pub async fn static_lnurl_pay_callback(
+        &self,
+        req: StaticLnurlPayCallbackRequest,
+    ) -> SdkResult<String> {
+        let endpoint = self
+            .persister
+            .get_static_lnurl_pay()?
+            .ok_or(SdkError::generic(
+                "No static LNURL-pay endpoint, call create_static_lnurl_pay first",
+            ))?;
+        let comment = req.comment.filter(|c| !c.is_empty());
+        if let Err(reason) = endpoint.check_callback(req.amount_msat, comment.as_deref()) {
+            return Ok(static_lnurl_pay::error_response(&reason));
+        }
+
+        let res = self
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: req.amount_msat,
+                description: endpoint.metadata(),
+                use_description_hash: Some(true),
+                ..Default::default()
+            })
+            .await;
+        let invoice = match res {
+            Ok(res) => res.ln_invoice,
+            Err(e) => {
+                warn!("Failed to create the static LNURL-pay invoice: {e}");
+                return Ok(static_lnurl_pay::error_response(
+                    "The invoice could not be created",
+                ));
+            }
+        };
+        self.persister.insert_payment_external_info(
+            &invoice.payment_hash,
+            PaymentExternalInfo {
+                lnurl_pay_comment: comment,
+                lnurl_metadata: Some(endpoint.metadata()),
+                ..Default::default()
+            },
+        )?;
+        Ok(json!({ "pr": invoice.bolt11, "routes": [] }).to_string())
+    }
+
+    /// Creates an LNURL-withdraw voucher paid from the balance of the node, for example to hand
+    /// out as a gift card, and registers it with the LNURL server at [Config::lnurl_server_url].
+    ///
+    /// When the voucher is redeemed, the server sends the invoice of the claimant to the webhook
+    /// registered with [BreezServices::register_webhook], which has to be called first. The
+    /// webhook handler then pays it with [BreezServices::redeem_lnurl_withdraw_voucher].
+    pub async fn create_lnurl_withdraw_voucher(
+        &self,
+        req: CreateLnurlWithdrawVoucherRequest,
+    ) -> SdkResult<LnurlWithdrawVoucher> {
+        let amount_msat = req.amount_msat.msat();
+        ensure_sdk!(
+            amount_msat > 0 && req.uses > 0,
+            SdkError::generic("The amount and the uses of the voucher must be positive")
+        );
+        let node_state = self.node_info()?;
+        let total_msat = amount_msat.saturating_mul(req.uses as u64);
+        ensure_sdk!(
+            total_msat <= node_state.max_payable_msat,
+            SdkError::Generic {
+                err: format!(
+                    "The voucher can withdraw {total_msat} msat, more than the {} msat that can be sent",
+                    node_state.max_payable_msat
+                ),
+            }
+        );
+        let webhook_url = self.persister.get_webhook_url()?.ok_or(SdkError::generic(
+            "No webhook registered, call register_webhook first",
+        ))?;
+
+        let id = hex::encode(rand::random::<[u8; 32]>());
+        let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        let expires_at = created_at + req.expiry_secs as i64;
+        let message = format!(
+            "{created_at}-{webhook_url}-{id}-{}-{}-{expires_at}",
+            amount_msat, req.uses
+        );
+        let signature = self
+            .sign_message(SignMessageRequest { message })
+            .await?
+            .signature;
+
+        let url = format!(
+            "{}/lnurlwithdraw/{}",
+            self.config.lnurl_server_url, node_state.id
+        );
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = json!({
+            "time": created_at,
+            "webhook_url": webhook_url,
+            "k1": id,
+            "amount_msat": amount_msat,
+            "uses": req.uses,
+            "expires_at": expires_at,
+            "description": req.description,
+            "signature": signature,
+        })
+        .to_string();
+        let (response, status) = self
+            .rest_client
+            .post(&url, Some(headers), Some(body))
+            .await
+            .map_err(|e| SdkError::ServiceConnectivity {
+                err: format!("Failed to register the voucher: {e}"),
+            })?;
+        ensure_sdk!(
+            (200..300).contains(&status),
+            SdkError::ServiceConnectivity {
+                err: format!("Failed to register the voucher, status {status}: {response}"),
+            }
+        );
+        let registration: LnurlWithdrawRegistration =
+            serde_json::from_str(&response).map_err(|e| SdkError::Generic {
+                err: format!("Failed to parse the voucher registration: {e}"),
+            })?;
+
+        let voucher = LnurlWithdrawVoucher {
+            id,
+            lnurl: registration.lnurl,
+            amount_msat,
+            uses: req.uses,
+            redeemed_uses: 0,
+            description: req.description,
+            created_at,
+            expires_at,
+        };
+        self.persister.insert_lnurl_withdraw_voucher(&voucher)?;
+        Ok(voucher)
+    }
+
+    /// Pays the invoice of the claimant of a voucher created with
+    /// [BreezServices::create_lnurl_withdraw_voucher], taking one of its uses.
+    ///
+    /// The invoice can't be for more than the amount of the voucher. The use is given back if the
+    /// payment fails, unless it may still complete.
+    pub async fn redeem_lnurl_withdraw_voucher(
+        &self,
+        req: RedeemLnurlWithdrawVoucherRequest,
+    ) -> Result<SendPaymentResponse, SendPaymentError> {
+        let voucher = self.persister.get_lnurl_withdraw_voucher(&req.id)?.ok_or(
+            SendPaymentError::Generic {
+                err: "Voucher not found".into(),
+            },
+        )?;
+        let invoice = parse_invoice(&req.bolt11)?;
+        let amount_msat = invoice.amount_msat.ok_or(SendPaymentError::InvalidAmount {
+            err: "The invoice must have an amount".into(),
+        })?;
+        ensure_sdk!(
+            amount_msat <= voucher.amount_msat,
+            SendPaymentError::InvalidAmount {
+                err: format!(
+                    "The invoice is for {amount_msat} msat, more than the {} msat of the voucher",
+                    voucher.amount_msat
+                ),
+            }
+        );
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        ensure_sdk!(
+            self.persister
+                .claim_lnurl_withdraw_voucher_use(&req.id, now)?,
+            SendPaymentError::Generic {
+                err: "The voucher has expired or has no uses left".into(),
+            }
+        );
+        let res = self
+            .send_payment(SendPaymentRequest {
+                bolt11: req.bolt11,
+                use_trampoline: None,
+                amount_msat: None,
+                label: Some(format!("lnurl-withdraw-voucher:{}", req.id)),
+                route_hint_index: None,
+                payment_timeout_sec: None,
+                maxfee_percent: None,
+                exemptfee_msat: None,
+                cancel_token: None,
+                skip_denylist: None,
+            })
+            .await;
+        if let Err(e) = &res {
+            let may_complete = matches!(
+                e,
+                SendPaymentError::PaymentTimeout { .. } | SendPaymentError::AlreadyInProgress
+            );
+            if !may_complete {
+                if let Err(e) = self.persister.release_lnurl_withdraw_voucher_use(&req.id) {
+                    warn!("Failed to give back the use of voucher {}: {e}", req.id);
+                }
+            }
+        }
+        res
+    }
+
+    /// Lists the vouchers created with [BreezServices::create_lnurl_withdraw_voucher], the newest
+    /// first
+    pub fn list_lnurl_withdraw_vouchers(&self) -> SdkResult<Vec<LnurlWithdrawVoucher>> {
+        Ok(self.persister.list_lnurl_withdraw_vouchers()?)
+    }
+
+    /// Registers the `username` for the `webhook_url` with the LNURL-pay server. The request is
+    /// signed by the node, so only the node can claim or move its address.
+    async fn register_lnurl_pay(
+        &self,
+        webhook_url: &str,
+        username: &str,
+    ) -> SdkResult<LnurlPayInfo> {
+        validate_lightning_address_username(username)?;
+        let node_id = self.node_info()?.id;
+        let time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let message = format!("{time}-{webhook_url}-{username}");
+        let signature = self
+            .sign_message(SignMessageRequest { message })
+            .await?
+            .signature;
+
+        let url = format!("{}/lnurlpay/{node_id}", self.config.lnurl_server_url);
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = json!({
+            "time": time,
+            "webhook_url": webhook_url,
+            "username": username,
+            "signature": signature,
+        })
+        .to_string();
+        let (response, status) = self
+            .rest_client
+            .post(&url, Some(headers), Some(body))
+            .await
+            .map_err(|e| SdkError::ServiceConnectivity {
+                err: format!("Failed to register the lightning address: {e}"),
+            })?;
+        match status {
+            200..=299 => serde_json::from_str(&response).map_err(|e| SdkError::Generic {
+                err: format!("Failed to parse the lightning address registration: {e}"),
+            }),
+            409 => Err(SdkError::Generic {
+                err: format!("The username {username} is already taken"),
+            }),
+            _ => Err(SdkError::ServiceConnectivity {
+                err: format!(
+                    "Failed to register the lightning address, status {status}: {response}"
+                ),
+            }),
+        }
+    }
+
+    /// Unregister webhook callbacks for the given `webhook_url`.
+    ///
+    /// When called, it unregisters for the following types of callbacks:
+    /// - a payment is received
+    /// - a swap tx is confirmed
+    ///
+    /// This can be called when callbacks are no longer needed or the `webhook_url`
+    /// has changed such that it needs unregistering. For example, the token is valid but the locale changes.
+    /// To register a webhook call [BreezServices::register_webhook].
+    pub async fn unregister_webhook(&self, webhook_url: String) -> SdkResult<()> {
+        info!("Unregistering for webhook notifications");
+        self.unregister_onchain_tx_notifications(&webhook_url)
+            .await?;
+        self.unregister_payment_notifications(webhook_url).await?;
+        self.persister.remove_webhook_url()?;
+        Ok(())
+    }
+
+    /// Registers for lightning payment notifications. When a payment is intercepted by the LSP
+    /// to this node, a callback will be triggered to the `webhook_url`.
+    ///
+    /// Note: these notifications are registered for all LSPs (active and historical) with whom
+    /// we have a channel.
+    async fn register_payment_notifications(&self, webhook_url: String) -> SdkResult<()> {
+        let message = webhook_url.clone();
+        let sign_request = SignMessageRequest { message };
+        let sign_response = self.sign_message(sign_request).await?;
+
+        // Attempt register call for all relevant LSPs
+        let mut error_found = false;
+        for lsp_info in get_notification_lsps(
+            self.persister.clone(),
+            self.lsp_api.clone(),
+            self.node_api.clone(),
+        )
+        .await?
+        {
+            let lsp_id = lsp_info.id;
+            let res = self
+                .lsp_api
+                .register_payment_notifications(
+                    lsp_id.clone(),
+                    lsp_info.lsp_pubkey,
+                    webhook_url.clone(),
+                    sign_response.signature.clone(),
+                )
+                .await;
+            if res.is_err() {
+                error_found = true;
+                warn!("Failed to register notifications for LSP {lsp_id}: {res:?}");
+            }
+        }
+
+        match error_found {
+            true => Err(SdkError::generic(
+                "Failed to register notifications for at least one LSP, see logs for details",
+            )),
+            false => Ok(()),
+        }
+    }
+
+    /// Unregisters lightning payment notifications with the current LSP for the `webhook_url`.
+    ///
+    /// Note: these notifications are unregistered for all LSPs (active and historical) with whom
+    /// we have a channel.
+    async fn unregister_payment_notifications(&self, webhook_url: String) -> SdkResult<()> {
+        let message = webhook_url.clone();
+        let sign_request = SignMessageRequest { message };
+        let sign_response = self.sign_message(sign_request).await?;
+
+        // Attempt register call for all relevant LSPs
+        let mut error_found = false;
+        for lsp_info in get_notification_lsps(
+            self.persister.clone(),
+            self.lsp_api.clone(),
+            self.node_api.clone(),
+        )
+        .await?
+        {
+            let lsp_id = lsp_info.id;
+            let res = self
+                .lsp_api
+                .unregister_payment_notifications(
+                    lsp_id.clone(),
+                    lsp_info.lsp_pubkey,
+                    webhook_url.clone(),
+                    sign_response.signature.clone(),
+                )
+                .await;
+            if res.is_err() {
+                error_found = true;
+                warn!("Failed to un-register notifications for LSP {lsp_id}: {res:?}");
+            }
+        }
+
+        match error_found {
+            true => Err(SdkError::generic(
+                "Failed to un-register notifications for at least one LSP, see logs for details",
+            )),
+            false => Ok(()),
+        }
+    }
+
+    /// Registers for a onchain tx notification. When a new transaction to the specified `address`
+    /// is confirmed, a callback will be triggered to the `webhook_url`.
+    async fn register_onchain_tx_notification(
+        &self,
+        address: &str,
+        webhook_url: &str,
+    ) -> SdkResult<()> {
+        let url = format!("{}/api/v1/register", self.config.chainnotifier_url);
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = json!({
+            "address": address,
+            "webhook": webhook_url
+        })
+        .to_string();
+        self.rest_client
+            .post(&url, Some(headers), Some(body))
+            .await
+            .map(|_| ())
+            .map_err(|e| SdkError::ServiceConnectivity {
+                err: format!("Failed to register for tx confirmation notifications: {e}"),
+            })
+    }
+
+    /// Unregisters all onchain tx notifications for the `webhook_url`.
+    async fn unregister_onchain_tx_notifications(&self, webhook_url: &str) -> SdkResult<()> {
+        let url = format!("{}/api/v1/unregister", self.config.chainnotifier_url);
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = json!({
+            "webhook": webhook_url
+        })
+        .to_string();
+        self.rest_client
+            .post(&url, Some(headers), Some(body))
+            .await
+            .map(|_| ())
+            .map_err(|e| SdkError::ServiceConnectivity {
+                err: format!("Failed to unregister for tx confirmation notifications: {e}"),
+            })
+    }
+
+    /// Takes a snapshot of the node state, balances, channels, swaps and most recent payments,
+    /// as pretty printed JSON. It is redacted to be shared with support: the secrets and the
+    /// descriptions of the payments are blanked.
+    ///
+    /// Two snapshots can be compared with [crate::diff_snapshots], for example to investigate a
+    /// balance discrepancy.
+    pub async fn diagnostic_snapshot(&self) -> SdkResult<String> {
+        use crate::serializer::value::to_value;
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let (sdk_version, sdk_git_hash) = Self::get_sdk_version();
+        let payments = self.persister.list_payments(ListPaymentsRequest {
+            include_failures: Some(true),
+            limit: Some(DIAGNOSTIC_SNAPSHOT_PAYMENTS),
+            ..Default::default()
+        })?;
+        let swaps = self
+            .btc_receive_swapper
+            .list_swaps(ListSwapsRequest::default())
+            .map(sanitize_vec)?;
+
+        let mut snapshot = json!({
+            "timestamp": now,
+            "version": format!("SDK v{sdk_version} ({sdk_git_hash})"),
+            "node_state": to_value(self.persister.get_node_state()?)?,
+            "balances": to_value(self.balances()?)?,
+            "lsp_id": to_value(self.persister.get_lsp_id()?)?,
+            "channels": keyed_by(to_value(self.persister.list_channels()?)?, "funding_txid"),
+            "swaps": keyed_by(to_value(swaps)?, "bitcoin_address"),
+            "reverse_swaps": keyed_by(
+                to_value(self.persister.list_reverse_swaps().map(sanitize_vec)?)?,
+                "id",
+            ),
+            "payments": keyed_by(to_value(payments)?, "id"),
+        });
+        redact(&mut snapshot);
+        Ok(crate::serializer::to_string_pretty(&snapshot)?)
+    }
+
+    async fn generate_sdk_diagnostic_data(&self) -> SdkResult<Value> {
+        let (sdk_version, sdk_git_hash) = Self::get_sdk_version();
+        let version = format!("SDK v{sdk_version} ({sdk_git_hash})");
+        let state = crate::serializer::value::to_value(&self.persister.get_node_state()?)?;
+        let payments = crate::serializer::value::to_value(
+            &self
+                .persister
+                .list_payments(ListPaymentsRequest::default())?,
+        )?;
+        let channels = crate::serializer::value::to_value(&self.persister.list_channels()?)?;
+        let settings = crate::serializer::value::to_value(&self.persister.list_settings()?)?;
+        let reverse_swaps = crate::serializer::value::to_value(
+            self.persister.list_reverse_swaps().map(sanitize_vec)?,
+        )?;
+        let swaps = crate::serializer::value::to_value(
+            self.btc_receive_swapper
+                .list_swaps(ListSwapsRequest::default())
+                .map(sanitize_vec)?,
+        )?;
+        let lsp_id = crate::serializer::value::to_value(&self.persister.get_lsp_id()?)?;
+
+        let res = json!({
+            "version": version,
+            "node_state": state,
+            "payments": payments,
+            "channels": channels,
+            "settings": settings,
+            "reverse_swaps": reverse_swaps,
+            "swaps": swaps,
+            "lsp_id": lsp_id,
+        });
+        Ok(res)
+    }
+}
+
+/// The filters of the SDK log file, unless replaced with [BreezServices::set_log_filter]
+const DEFAULT_LOG_FILTER: &str = r#"
+    info,
+    breez_sdk_core=debug,
+    sdk_common=debug,
+    gl_client=debug,
+    h2=warn,
+    hyper=warn,
+    lightning_signer=warn,
+    reqwest=warn,
+    rustls=warn,
+    rustyline=warn,
+    vls_protocol_signer=warn
+"#;
+
+/// The filter set with [BreezServices::set_log_filter]
+static LOG_FILTER: RwLock<Option<env_logger::filter::Filter>> = RwLock::new(None);
+
+/// Parses a `RUST_LOG` style filter, rejecting directives with an invalid level that
+/// env_logger would otherwise silently ignore
+fn parse_log_filter(filter: &str) -> SdkResult<env_logger::filter::Filter> {
+    for directive in filter.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+        if let Some((_, level)) = directive.split_once('=') {
+            ensure_sdk!(
+                level.trim().parse::<LevelFilter>().is_ok(),
+                SdkError::generic(&format!("Invalid log level in directive: {directive}"))
+            );
+        }
+    }
+    Ok(env_logger::filter::Builder::new().parse(filter).build())
+}
+
+/// Whether the filter set with [BreezServices::set_log_filter] enables the log statement, or
+/// `None` if no filter was set. Used by the log stream implementations.
+pub fn log_filter_enabled(metadata: &Metadata) -> Option<bool> {
+    LOG_FILTER
+        .read()
+        .ok()?
+        .as_ref()
+        .map(|filter| filter.enabled(metadata))
+}
+
+struct GlobalSdkLogger {
+    /// SDK internal logger, which logs to file
+    logger: env_logger::Logger,
+    /// The filter of the log file if none was set with [BreezServices::set_log_filter]
+    default_filter: env_logger::filter::Filter,
+    /// Optional external log listener, that can receive a stream of log statements
+    log_listener: Option<Box<dyn log::Log>>,
+}
+impl log::Log for GlobalSdkLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::Level::Trace
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let filter_enabled = log_filter_enabled(record.metadata());
+            if filter_enabled.unwrap_or_else(|| self.default_filter.enabled(record.metadata())) {
+                self.logger.log(record);
+            }
+
+            if let Some(s) = &self.log_listener.as_ref() {
+                if s.enabled(record.metadata()) && filter_enabled.unwrap_or(true) {
+                    s.log(record);
+                }
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A helper struct to configure and build BreezServices
+struct BreezServicesBuilder {
+    config: Config,
+    node_api: Option<Arc<dyn NodeAPI>>,
+    backup_transport: Option<Arc<dyn BackupTransport>>,
+    signer: Option<Arc<dyn Signer>>,
+    node_credentials: Option<NodeCredentials>,
+    lsp_api: Option<Arc<dyn LspAPI>>,
+    #[cfg(feature = "fiat")]
+    fiat_api: Option<Arc<dyn FiatAPI>>,
+    persister: Option<Arc<SqliteStorage>>,
+    rest_client: Option<Arc<dyn RestClient>>,
+    chain_service: Option<Arc<dyn ChainService>>,
+    support_api: Option<Arc<dyn SupportAPI>>,
+    swapper_api: Option<Arc<dyn SwapperAPI>>,
+    taproot_swapper_api: Option<Arc<dyn TaprootSwapperAPI>>,
+    /// Reverse swap functionality on the Breez Server
+    #[cfg(feature = "reverse-swaps")]
+    reverse_swapper_api: Option<Arc<dyn ReverseSwapperRoutingAPI>>,
+    /// Reverse swap functionality on the 3rd party reverse swap service
+    #[cfg(feature = "reverse-swaps")]
+    reverse_swap_service_api: Option<Arc<dyn ReverseSwapServiceAPI>>,
+    #[cfg(feature = "buy-bitcoin")]
+    buy_bitcoin_api: Option<Arc<dyn BuyBitcoinApi>>,
+    ephemeral: bool,
+}
+
+#[allow(dead_code)]
+impl BreezServicesBuilder {
+    pub fn new(config: Config) -> BreezServicesBuilder {
+        BreezServicesBuilder {
+            config,
+            node_api: None,
+            signer: None,
+            node_credentials: None,
+            lsp_api: None,
+            #[cfg(feature = "fiat")]
+            fiat_api: None,
+            persister: None,
+            rest_client: None,
+            chain_service: None,
+            support_api: None,
+            swapper_api: None,
+            taproot_swapper_api: None,
+            #[cfg(feature = "reverse-swaps")]
+            reverse_swapper_api: None,
+            #[cfg(feature = "reverse-swaps")]
+            reverse_swap_service_api: None,
+            #[cfg(feature = "buy-bitcoin")]
+            buy_bitcoin_api: None,
+            backup_transport: None,
+            ephemeral: false,
+        }
+    }
+
+    pub fn node_api(&mut self, node_api: Arc<dyn NodeAPI>) -> &mut Self {
+        self.node_api = Some(node_api);
+        self
+    }
+
+    pub fn lsp_api(&mut self, lsp_api: Arc<dyn LspAPI>) -> &mut Self {
+        self.lsp_api = Some(lsp_api.clone());
+        self
+    }
+
+    #[cfg(feature = "fiat")]
+    pub fn fiat_api(&mut self, fiat_api: Arc<dyn FiatAPI>) -> &mut Self {
+        self.fiat_api = Some(fiat_api.clone());
+        self
+    }
+
+    #[cfg(feature = "buy-bitcoin")]
+    pub fn buy_bitcoin_api(&mut self, buy_bitcoin_api: Arc<dyn BuyBitcoinApi>) -> &mut Self {
+        self.buy_bitcoin_api = Some(buy_bitcoin_api.clone());
+        self
+    }
+
+    pub fn persister(&mut self, persister: Arc<SqliteStorage>) -> &mut Self {
+        self.persister = Some(persister);
+        self
+    }
+
+    pub fn support_api(&mut self, support_api: Arc<dyn SupportAPI>) -> &mut Self {
+        self.support_api = Some(support_api.clone());
+        self
+    }
+
+    pub fn rest_client(&mut self, rest_client: Arc<dyn RestClient>) -> &mut Self {
+        self.rest_client = Some(rest_client.clone());
+        self
+    }
+
+    pub fn swapper_api(&mut self, swapper_api: Arc<dyn SwapperAPI>) -> &mut Self {
+        self.swapper_api = Some(swapper_api.clone());
+        self
+    }
+
+    pub fn chain_service(&mut self, chain_service: Arc<dyn ChainService>) -> &mut Self {
+        self.chain_service = Some(chain_service.clone());
+        self
+    }
+
+    pub fn taproot_swapper_api(&mut self, swapper_api: Arc<dyn TaprootSwapperAPI>) -> &mut Self {
+        self.taproot_swapper_api = Some(swapper_api.clone());
+        self
+    }
+
+    #[cfg(feature = "reverse-swaps")]
+    pub fn reverse_swapper_api(
+        &mut self,
+        reverse_swapper_api: Arc<dyn ReverseSwapperRoutingAPI>,
+    ) -> &mut Self {
+        self.reverse_swapper_api = Some(reverse_swapper_api.clone());
+        self
+    }
+
+    #[cfg(feature = "reverse-swaps")]
+    pub fn reverse_swap_service_api(
+        &mut self,
+        reverse_swap_service_api: Arc<dyn ReverseSwapServiceAPI>,
+    ) -> &mut Self {
+        self.reverse_swap_service_api = Some(reverse_swap_service_api.clone());
+        self
+    }
+
+    pub fn backup_transport(&mut self, backup_transport: Arc<dyn BackupTransport>) -> &mut Self {
+        self.backup_transport = Some(backup_transport.clone());
+        self
+    }
+
+    /// Deletes the working dir on disconnect, see [BreezServices::connect_ephemeral]
+    fn ephemeral(&mut self) -> &mut Self {
+        self.ephemeral = true;
+        self
+    }
+
+    pub fn seed(&mut self, seed: Vec<u8>) -> BreezServicesResult<&mut Self> {
+        let signer =
+            SeedSigner::new(seed, self.config.network).map_err(|e| ConnectError::Generic {
+                err: format!("Failed to create the signer: {e}"),
+            })?;
+        Ok(self.signer(Arc::new(signer)))
+    }
+
+    pub fn signer(&mut self, signer: Arc<dyn Signer>) -> &mut Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    pub fn node_credentials(&mut self, node_credentials: NodeCredentials) -> &mut Self {
+        self.node_credentials = Some(node_credentials);
+        self
+    }
+
+    pub async fn build(
+        &self,
+        restore_only: Option<bool>,
+        event_listener: Option<Box<dyn EventListener>>,
+    ) -> BreezServicesResult<Arc<BreezServices>> {
+        if self.node_api.is_none() && self.signer.is_none() {
+            return Err(ConnectError::Generic {
+                err: "Either node_api or a signer should be provided".into(),
+            });
+        }
+        if let Some(log_filter) = &self.config.log_filter {
+            BreezServices::set_log_filter(log_filter)?;
+        }
+
+        // The storage is implemented via sqlite.
+        let persister = self.persister.clone().unwrap_or_else(|| {
+            Arc::new(
+                SqliteStorage::new(self.config.db_dir())
+                    .with_credentials_dir(self.config.credentials_dir.clone()),
+            )
+        });
+        persister.init()?;
+
+        let mut node_api = self.node_api.clone();
+        let mut backup_transport = match (
+            self.backup_transport.clone(),
+            self.config.backup_transport.as_ref(),
+        ) {
+            (Some(backup_transport), _) => Some(backup_transport),
+            (None, Some(config)) => Some(backup::transport_from_config(
+                config,
+                self.config.proxy.as_ref(),
+            )?),
+            (None, None) => None,
+        };
+        let mut is_new_node = None;
+        #[cfg(feature = "mock-node")]
+        if let (None, NodeConfig::Mock { config }) = (&node_api, &self.config.node_config) {
+            let mock_node = Arc::new(MockNode::new(
+                config.clone(),
+                self.config.network,
+                self.signer.clone().unwrap(),
+            )?);
+            node_api = Some(mock_node.clone());
+            if backup_transport.is_none() {
+                backup_transport = Some(mock_node);
+            }
+        }
+        #[cfg(not(feature = "mock-node"))]
+        if let (None, NodeConfig::Mock { .. }) = (&node_api, &self.config.node_config) {
+            return Err(ConnectError::Generic {
+                err: "The mock node requires the mock-node feature".into(),
+            });
+        }
+        if node_api.is_none() {
+            let registered = AtomicBool::new(false);
+            let on_progress = |stage: ConnectStage| {
+                if stage == ConnectStage::NodeRegistration {
+                    registered.store(true, Ordering::Relaxed);
+                }
+                if let Some(listener) = event_listener.as_ref() {
+                    listener.on_event(BreezEvent::ConnectProgress {
+                        details: ConnectProgressDetails {
+                            stage,
+                            progress_percent: stage.progress_percent(),
+                            is_new_node: match stage {
+                                ConnectStage::NodeRecovery => None,
+                                _ => Some(registered.load(Ordering::Relaxed)),
+                            },
+                        },
+                    });
+                }
+            };
+            let node_credentials = self.node_credentials.clone().map(|c| match c {
+                NodeCredentials::Greenlight { credentials } => {
+                    Device::from_bytes(credentials.device)
+                }
+            });
+            let greenlight = Greenlight::connect(
+                self.config.clone(),
+                self.signer.clone().unwrap(),
+                node_credentials,
+                restore_only,
+                persister.clone(),
+                &on_progress,
+            )
+            .await?;
+            is_new_node = Some(registered.load(Ordering::Relaxed));
+            let gl_arc = Arc::new(greenlight);
+            node_api = Some(gl_arc.clone());
+            if backup_transport.is_none() {
+                backup_transport = Some(Arc::new(GLBackupTransport { inner: gl_arc }));
+            }
+        }
+
+        if backup_transport.is_none() {
+            return Err(ConnectError::Generic {
+                err: "State synchronizer should be provided".into(),
+            });
+        }
+
+        let unwrapped_node_api = node_api.unwrap();
+        let unwrapped_backup_transport = backup_transport.unwrap();
+
+        // The backup watcher derives the backup encryption keys through the node
+        let backup_watcher = BackupWatcher::new(
+            self.config.clone(),
+            unwrapped_backup_transport.clone(),
+            persister.clone(),
+            unwrapped_node_api.clone(),
+        );
+
+        // breez_server provides both FiatAPI & LspAPI implementations
+        let breez_server = Arc::new(
+            BreezServer::new_with_proxy(
+                self.config.breezserver.clone(),
+                self.config.api_key.clone(),
+                self.config.proxy.clone(),
+            )
+            .map_err(|e| ConnectError::ServiceConnectivity {
+                err: format!("Failed to create BreezServer: {e}"),
+            })?,
+        );
+
+        // Ensure breez server connection is established in the background. Not supervised, as
+        // the services and their supervisor don't exist yet, and the ping is only a warm-up.
+        let cloned_breez_server = breez_server.clone();
+        tokio::spawn(async move {
+            if let Err(e) = cloned_breez_server.ping().await {
+                error!("Failed to ping breez server: {e}");
+            }
+        });
+
+        let current_lsp_id = persister.get_lsp_id()?;
+        if current_lsp_id.is_none() {
+            let preferred_lsp_id = persister
+                .get_user_settings()?
+                .preferred_lsp_id
+                .or(self.config.default_lsp_id.clone());
+            if let Some(lsp_id) = preferred_lsp_id {
+                persister.set_lsp(lsp_id, None)?;
+            }
+        }
+
+        let metrics = Arc::new(Metrics::default());
+        let payment_receiver = Arc::new(PaymentReceiver {
+            config: self.config.clone(),
+            node_api: unwrapped_node_api.clone(),
+            lsp: Arc::new(MeteredLspAPI {
+                inner: breez_server.clone(),
+                metrics: metrics.clone(),
+            }),
+            persister: persister.clone(),
+        });
+
+        let rest_client: Arc<dyn RestClient> = match self.rest_client.clone() {
+            Some(rest_client) => rest_client,
+            None => match &self.config.proxy {
+                Some(proxy) => Arc::new(ReqwestRestClient::with_proxy(proxy)?),
+                None => Arc::new(ReqwestRestClient::new()?),
+            },
+        };
+
+        let chain_service: Arc<dyn ChainService> = match (
+            self.chain_service.clone(),
+            self.config.chain_service.clone(),
+        ) {
+            (Some(chain_service), _) => chain_service,
+            (None, Some(ChainServiceConfig::Esplora { url })) => {
+                Arc::new(Esplora::from_base_url(rest_client.clone(), &url))
+            }
+            (None, Some(ChainServiceConfig::Electrum { url })) => {
+                Arc::new(ElectrumChainService::new(
+                    &url,
+                    self.config.network.into(),
+                    self.config.proxy.clone(),
+                )?)
+            }
+            (None, None) => {
+                // mempool space is used to monitor the chain
+                let mempoolspace_urls = match (
+                    self.config.mempoolspace_url.clone(),
+                    test_network_mempool_space_url(self.config.network),
+                ) {
+                    (None, Some(test_network_url)) => vec![test_network_url.to_string()],
+                    (None, None) => {
+                        let cached = persister.get_mempoolspace_base_urls()?;
+                        match cached.len() {
+                            // If we have no cached values, or we cached an empty list, fetch new ones
+                            0 => {
+                                let fresh_urls = breez_server
+                                    .fetch_mempoolspace_urls()
+                                    .await
+                                    .unwrap_or(vec![DEFAULT_MEMPOOL_SPACE_URL.into()]);
+                                persister.set_mempoolspace_base_urls(fresh_urls.clone())?;
+                                fresh_urls
+                            }
+                            // If we already have cached values, return those
+                            _ => cached,
+                        }
+                    }
+                    (Some(mempoolspace_url_from_config), _) => vec![mempoolspace_url_from_config],
+                };
+                Arc::new(RedundantChainService::from_base_urls(
+                    rest_client.clone(),
+                    mempoolspace_urls,
+                ))
+            }
+        };
+
+        let btc_receive_swapper = Arc::new(BTCReceiveSwap::new(BTCReceiveSwapParameters {
+            chain_service: chain_service.clone(),
+            payment_storage: persister.clone(),
+            network: self.config.network.into(),
+            node_api: unwrapped_node_api.clone(),
+            node_state_storage: persister.clone(),
+            payment_receiver: payment_receiver.clone(),
+            segwit_swapper_api: self
+                .swapper_api
+                .clone()
+                .unwrap_or_else(|| breez_server.clone()),
+            swap_storage: persister.clone(),
+            taproot_swapper_api: self
+                .taproot_swapper_api
+                .clone()
+                .unwrap_or_else(|| breez_server.clone()),
+            auto_refund: self.config.swap_auto_refund.clone(),
+        }));
+
+        #[cfg(feature = "reverse-swaps")]
+        let btc_send_swapper = Arc::new(BTCSendSwap::new(
+            self.config.clone(),
+            self.reverse_swapper_api
+                .clone()
+                .unwrap_or_else(|| breez_server.clone()),
+            self.reverse_swap_service_api
+                .clone()
+                .unwrap_or_else(|| Arc::new(BoltzApi::new(rest_client.clone()))),
+            persister.clone(),
+            chain_service.clone(),
+            unwrapped_node_api.clone(),
+        ));
+
+        // create a shutdown channel (sender and receiver)
+        let (shutdown_sender, _shutdown_receiver) = watch::channel::<()>(());
+
+        #[cfg(feature = "buy-bitcoin")]
+        let buy_bitcoin_api = self.buy_bitcoin_api.clone().unwrap_or_else(|| {
+            Arc::new(BuyBitcoinService::new(
+                breez_server.clone(),
+                self.config.transak_api_key.clone(),
+                rest_client.clone(),
+            ))
+        });
+
+        // Create the node services and it them statically
+        let breez_services = Arc::new(BreezServices {
+            config: self.config.clone(),
+            started: Mutex::new(false),
+            last_sync_result: Mutex::new(None),
+            lsp_reconnections: Mutex::new(LspReconnections::default()),
+            connection_status: Mutex::new(ConnectionStatus {
+                since: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .unwrap_or_default(),
+                ..Default::default()
+            }),
+            node_api: unwrapped_node_api.clone(),
+            lsp_api: Arc::new(MeteredLspAPI {
+                inner: self.lsp_api.clone().unwrap_or_else(|| breez_server.clone()),
+                metrics: metrics.clone(),
+            }),
+            #[cfg(feature = "fiat")]
+            fiat_api: self
+                .fiat_api
+                .clone()
+                .unwrap_or_else(|| breez_server.clone()),
+            support_api: self
+                .support_api
+                .clone()
+                .unwrap_or_else(|| breez_server.clone()),
+            #[cfg(feature = "buy-bitcoin")]
+            buy_bitcoin_api,
+            chain_service,
+            persister: persister.clone(),
+            rest_client,
+            btc_receive_swapper,
+            #[cfg(feature = "reverse-swaps")]
+            btc_send_swapper,
+            payment_receiver,
+            event_listener,
+            event_sender: broadcast::channel(EVENT_STREAM_CAPACITY).0,
+            backup_watcher: Arc::new(backup_watcher),
+            task_supervisor: Arc::new(TaskSupervisor::default()),
+            send_queue: Arc::new(SendQueue::new(self.config.max_concurrent_payments)),
+            payments_writer: Arc::new(PaymentsWriter::start(persister.clone())),
+            htlc_inflight: InflightTracker::default(),
+            cancellations: Cancellations::default(),
+            sync_cancellations: Cancellations::default(),
+            shutdown_sender,
+            is_new_node,
+            initial_sync_in_progress: AtomicBool::new(false),
+            metrics,
+            ephemeral: self.ephemeral,
+        });
+
+        Ok(breez_services)
+    }
+}
+
+fn write_recovery_bundle(file_path: &str, bundle: &[u8]) -> SdkResult<()> {
+    std::fs::write(file_path, bundle).map_err(|e| SdkError::Generic {
+        err: format!("Failed to write the recovery bundle to {file_path}: {e}"),
+    })
+}
+
+/// Parses the invoice, which checks its signature, and checks its network
+fn verify_invoice(bolt11: &str, network: Network) -> InvoiceResult<LNInvoice> {
+    let invoice = parse_invoice(bolt11)?;
+    validate_network(invoice.clone(), network)?;
+    Ok(invoice)
+}
+
+/// Checks that the LNURL-auth callback is on the domain shown to the user, over a scheme allowed
+/// for it, and returns the domain
+#[cfg(feature = "lnurl")]
+fn verify_lnurl_auth_origin(req_data: &LnUrlAuthRequestData) -> Result<String, LnUrlAuthError> {
+    let url = reqwest::Url::parse(&req_data.url)
+        .map_err(|e| LnUrlAuthError::InvalidUri { err: e.to_string() })?;
+    let domain = url
+        .domain()
+        .ok_or(LnUrlAuthError::InvalidUri {
+            err: "Could not determine domain".to_string(),
+        })?
+        .to_lowercase();
+    if domain != req_data.domain.to_lowercase() {
+        return Err(LnUrlAuthError::OriginMismatch {
+            err: format!("The callback is on {domain}, not on {}", req_data.domain),
+        });
+    }
+    let scheme_allowed = match url.scheme() {
+        "https" => !domain.ends_with(".onion"),
+        "http" => domain.ends_with(".onion"),
+        _ => false,
+    };
+    if !scheme_allowed {
+        return Err(LnUrlAuthError::OriginMismatch {
+            err: format!("The {} scheme is not allowed for {domain}", url.scheme()),
+        });
+    }
+    Ok(domain)
+}
+
+/// Processes the success action of a completed LNURL-pay, decrypting the contents of an AES
+/// action with the payment preimage
+#[cfg(feature = "lnurl")]
+fn process_success_action(
+    sa: SuccessAction,
+    payment_preimage: &str,
+) -> Result<SuccessActionProcessed, LnUrlPayError> {
+    Ok(match sa {
+        SuccessAction::Aes { data } => {
+            let preimage = sha256::Hash::from_str(payment_preimage)?;
+            let preimage_arr: [u8; 32] = preimage.into_inner();
+            let result = match (data, &preimage_arr).try_into() {
+                Ok(data) => AesSuccessActionDataResult::Decrypted { data },
+                Err(e) => AesSuccessActionDataResult::ErrorStatus {
+                    reason: e.to_string(),
+                },
+            };
+            SuccessActionProcessed::Aes { result }
+        }
+        SuccessAction::Message { data } => SuccessActionProcessed::Message { data },
+        SuccessAction::Url { data } => SuccessActionProcessed::Url { data },
+    })
+}
+
+/// The amount of a [BreezServices::pay_onchain_address] payment, and whether the recipient
+/// receives it or it is sent, fees included, when draining
+#[cfg(feature = "reverse-swaps")]
+fn onchain_address_amount(
+    drain: bool,
+    amount_sat: Option<Sat>,
+    limits: &OnchainPaymentLimitsResponse,
+) -> Result<(Sat, SwapAmountType), SendOnchainError> {
+    match (drain, amount_sat) {
+        (true, None) => {
+            let send_amount_sat = limits.max_payable_sat.min(limits.max_sat);
+            ensure_sdk!(
+                send_amount_sat >= limits.min_sat,
+                SendOnchainError::OutOfRange
+            );
+            Ok((send_amount_sat, SwapAmountType::Send))
+        }
+        (true, Some(_)) => Err(SendOnchainError::generic(
+            "An amount can't be set when draining",
+        )),
+        (false, Some(amount_sat)) => Ok((amount_sat, SwapAmountType::Receive)),
+        (false, None) => Err(SendOnchainError::generic(
+            "Either an amount or drain must be set",
+        )),
+    }
+}
+
+/// Checks that the username only has the characters allowed in a lightning address by LUD-16
+fn validate_lightning_address_username(username: &str) -> SdkResult<()> {
+    let valid = !username.is_empty()
+        && username
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-_.".contains(c));
+    ensure_sdk!(
+        valid,
+        SdkError::Generic {
+            err: format!(
+                "Invalid username {username}, only a-z, 0-9, '-', '_' and '.' are allowed"
+            ),
+        }
+    );
+    Ok(())
+}
+
+/// Splits a node URI `<node id>@<host>:<port>` into the node id and the address
+fn parse_node_uri(node_uri: &str) -> SdkResult<(String, String)> {
+    let invalid = || SdkError::Generic {
+        err: format!("Invalid node URI {node_uri}, expected <node id>@<host>:<port>"),
+    };
+    let (node_id, address) = node_uri.trim().split_once('@').ok_or_else(invalid)?;
+    PublicKey::from_str(node_id).map_err(|_| invalid())?;
+    let (host, port) = address.rsplit_once(':').ok_or_else(invalid)?;
+    ensure_sdk!(!host.is_empty() && port.parse::<u16>().is_ok(), invalid());
+    Ok((node_id.to_string(), address.to_string()))
+}
+
+/// The P2WPKH and P2TR scripts of a key of the onchain wallet
+fn onchain_wallet_scripts(
+    secp: &Secp256k1<bitcoin::secp256k1::All>,
+    pubkey: &PublicKey,
+    network: Network,
+) -> SdkResult<Vec<bitcoin::Script>> {
+    let p2wpkh = bitcoin::Address::p2wpkh(&bitcoin::PublicKey::new(*pubkey), network.into())
+        .map_err(|e| SdkError::generic(&e.to_string()))?;
+    let (internal_key, _) = pubkey.x_only_public_key();
+    let p2tr = bitcoin::Address::p2tr(secp, internal_key, None, network.into());
+    Ok(vec![p2wpkh.script_pubkey(), p2tr.script_pubkey()])
+}
+
+/// Converts a fiat amount to msat at `rate`, in fiat units per bitcoin. Returns `None` if the
+/// amount isn't positive or is too small to be paid.
+#[cfg_attr(not(feature = "fiat"), allow(dead_code))]
+fn fiat_to_msat(amount: f64, rate: f64) -> Option<u64> {
+    if !amount.is_finite() || amount <= 0.0 || !rate.is_finite() || rate <= 0.0 {
+        return None;
+    }
+    let amount_msat = (amount / rate * 100_000_000_000.0).round();
+    (amount_msat >= 1.0 && amount_msat < u64::MAX as f64).then_some(amount_msat as u64)
+}
+
+/// The delay before the next attempt of a queued payment that failed `attempts` times
+fn outbox_retry_delay_secs(attempts: u32) -> u64 {
+    OUTBOX_MIN_RETRY_DELAY_SECS
+        .saturating_mul(2u64.saturating_pow(attempts.saturating_sub(1)))
+        .min(OUTBOX_MAX_RETRY_DELAY_SECS)
+}
+
+/// The delay before the reconnection `attempt`, randomized between half and all of the
+/// exponential backoff so that many clients don't reconnect at once after an outage
+fn reconnect_delay_ms(attempt: u32) -> u64 {
+    let backoff_ms = RECONNECT_MIN_DELAY_MS
+        .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
+        .min(RECONNECT_MAX_DELAY_MS);
+    rand::thread_rng().gen_range(backoff_ms / 2..=backoff_ms)
+}
+
+/// Waits for the next tick of the `interval`, or forever if there's none
+async fn tick_if_set(interval: &mut Option<tokio::time::Interval>) {
+    match interval {
+        Some(interval) => _ = interval.tick().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Hands the payment to the node, first with trampoline if it's an option. Returns the result of
+/// the payment, or an error if it's not worth reporting as a failed payment.
+///
+/// It's spawned by [BreezServices::pay_invoice], so the payment is followed to completion even
+/// if the call is cancelled.
+async fn dispatch_payment(
+    node_api: Arc<dyn NodeAPI>,
+    req: SendPaymentRequest,
+    bolt11: String,
+    amount_msat: u64,
+    maybe_trampoline_id: Option<Vec<u8>>,
+    exclude: Vec<String>,
+    limits: PaymentLimits,
+) -> Result<Result<Payment, SendPaymentError>, SendPaymentError> {
+    // If trampoline is an option, try trampoline first.
+    let trampoline_result = if let Some(trampoline_id) = maybe_trampoline_id {
+        debug!("attempting trampoline payment");
+        match node_api
+            .send_trampoline_payment(
+                bolt11.clone(),
+                amount_msat,
+                req.label.clone(),
+                trampoline_id,
+                limits,
+            )
+            .await
+        {
+            Ok(res) => Some(res),
+            Err(e) => {
+                if e.to_string().contains("missing balance") {
+                    debug!(
+                        "trampoline payment failed due to insufficient balance: {:?}",
+                        e
+                    );
+                    return Err(SendPaymentError::InsufficientBalance {
+                        err: "Trampoline payment failed".into(),
+                        max_sendable_msat: None,
+                    });
+                }
+
+                warn!("trampoline payment failed: {:?}", e);
+                None
+            }
+        }
+    } else {
+        debug!("not attempting trampoline payment");
+        None
+    };
+
+    // If trampoline failed or didn't happen, fall back to regular payment.
+    Ok(match trampoline_result {
+        Some(res) => Ok(res),
+        None => {
+            debug!("attempting normal payment");
+            node_api
+                .send_payment(
+                    bolt11,
+                    req.amount_msat.map(MilliSat::msat),
+                    req.label,
+                    exclude,
+                    limits,
+                )
+                .map_err(Into::into)
+                .await
+        }
+    })
+}
+
+/// The last hop channels of all the route hints of the invoice but the one at `index`, in the
+/// `short_channel_id/direction` format. Excluding them forces the payment through that hint.
+fn excluded_route_hint_channels(
+    invoice: &LNInvoice,
+    index: u32,
+) -> Result<Vec<String>, SendPaymentError> {
+    let route_hint =
+        invoice
+            .routing_hints
+            .get(index as usize)
+            .ok_or(SendPaymentError::InvalidInvoice {
+                err: format!(
+                    "Route hint index {index} is out of range, the invoice has {} route hints",
+                    invoice.routing_hints.len()
+                ),
+            })?;
+    let excluded = invoice
+        .routing_hints
+        .iter()
+        .filter_map(|hint| hint.hops.last())
+        .filter(|hop| {
+            !route_hint
+                .hops
+                .iter()
+                .any(|h| h.short_channel_id == hop.short_channel_id)
+        })
+        .map(|hop| {
+            // The direction is 0 when the channel is used from the node with the lower node id
+            let direction = match hop.src_node_id < invoice.payee_pubkey {
+                true => 0,
+                false => 1,
+            };
+            format!("{}/{direction}", hop.short_channel_id)
+        })
+        .collect();
+    Ok(excluded)
+}
+
+/// A BIP21 URI paying `amount_sat` to the address, or to the bolt11 invoice for wallets that
+/// support the `lightning` parameter
+fn unified_bip21_uri(address: &str, amount_sat: u64, label: &str, bolt11: &str) -> String {
+    let amount_btc = format!(
+        "{}.{:08}",
+        amount_sat / 100_000_000,
+        amount_sat % 100_000_000
+    );
+    let amount_btc = amount_btc.trim_end_matches('0').trim_end_matches('.');
+    let mut uri = format!("bitcoin:{address}?amount={amount_btc}");
+    if !label.is_empty() {
+        uri.push_str(&format!("&label={}", percent_encode(label)));
+    }
+    uri.push_str(&format!("&lightning={bolt11}"));
+    uri
+}
+
+/// Percent-encodes all but the unreserved characters of RFC 3986, as BIP21 requires
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(
+            |b| match b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                true => (b as char).to_string(),
+                false => format!("%{b:02X}"),
+            },
+        )
+        .collect()
+}
+
+/// The status of the component from its check, degraded if it fails or doesn't complete within
+/// [SERVICE_STATUS_CHECK_TIMEOUT_SECS]
+async fn service_status(
+    component: ServiceComponent,
+    check: impl std::future::Future<Output = SdkResult<()>>,
+) -> ServiceStatus {
+    match timeout(
+        Duration::from_secs(SERVICE_STATUS_CHECK_TIMEOUT_SECS),
+        check,
+    )
+    .await
+    {
+        Ok(Ok(())) => ServiceStatus::Operational,
+        Ok(Err(e)) => {
+            warn!("Service status check of {component:?} failed: {e}");
+            ServiceStatus::Degraded
+        }
+        Err(_) => {
+            warn!("Service status check of {component:?} timed out");
+            ServiceStatus::Degraded
+        }
+    }
+}
+
+/// The [BreezEvent::ServiceStatusChanged] events of the components whose status changed since
+/// the previous check, updating the known `statuses`. Components are assumed operational until
+/// checked, so a first check only reports the degraded ones.
+fn service_status_events(
+    statuses: &mut HashMap<ServiceComponent, ServiceStatus>,
+    checked: Vec<(ServiceComponent, ServiceStatus)>,
+) -> Vec<BreezEvent> {
+    checked
+        .into_iter()
+        .filter(|(component, status)| {
+            let previous = statuses
+                .insert(*component, *status)
+                .unwrap_or(ServiceStatus::Operational);
+            previous != *status
+        })
+        .map(|(component, status)| BreezEvent::ServiceStatusChanged { component, status })
+        .collect()
+}
+
+/// The low liquidity events for the configured thresholds that the node state crossed since the
+/// previous sync, so each drop below a threshold is only reported once
+fn low_liquidity_events(
+    config: &Config,
+    before: Option<&NodeState>,
+    after: &NodeState,
+) -> Vec<BreezEvent> {
+    let dropped_below = |threshold: Option<u64>, liquidity: fn(&NodeState) -> u64| {
+        threshold
+            .filter(|t| {
+                let was_above = match before {
+                    Some(before) => liquidity(before) >= *t,
+                    None => true,
+                };
+                liquidity(after) < *t && was_above
+            })
+            .map(|threshold_msat| LowLiquidityDetails {
+                liquidity_msat: liquidity(after),
+                threshold_msat,
+            })
+    };
+
+    let mut events = vec![];
+    if let Some(details) = dropped_below(config.low_outbound_liquidity_threshold_msat, |s| {
+        s.max_payable_msat
+    }) {
+        events.push(BreezEvent::LowOutboundLiquidity { details });
+    }
+    if let Some(details) = dropped_below(config.low_inbound_liquidity_threshold_msat, |s| {
+        s.total_inbound_liquidity_msats
+    }) {
+        events.push(BreezEvent::LowInboundLiquidity { details });
+    }
+    events
+}
+
+/// Returns the alerts whose rate moved to the other side of their threshold, with their
+/// `triggered` flag updated, and the rate of their currency
+#[cfg(feature = "fiat")]
+fn fiat_rate_alert_changes(alerts: &[FiatRateAlert], rates: &[Rate]) -> Vec<(FiatRateAlert, f64)> {
+    alerts
+        .iter()
+        .filter_map(|alert| {
+            let rate = rates.iter().find(|r| r.coin == alert.currency)?.value;
+            let crossed = alert.is_crossed(rate);
+            (crossed != alert.triggered).then(|| {
+                let alert = FiatRateAlert {
+                    triggered: crossed,
+                    ..alert.clone()
+                };
+                (alert, rate)
+            })
+        })
+        .collect()
+}
+
+/// Updates the tracked activity of the open channels, a change of the local balance being a use
+/// of the channel, and returns it along with the channels that were not used and whose peer was
+/// not connected during the window. The returned stale channels are marked as suggested.
+fn channel_hygiene_update<'a>(
+    window_secs: i64,
+    now: i64,
+    channels: &'a [ChannelDetails],
+    known: &HashMap<String, ChannelActivity>,
+) -> (
+    Vec<ChannelActivity>,
+    Vec<(&'a ChannelDetails, ChannelActivity)>,
+) {
+    let mut activity = vec![];
+    let mut stale = vec![];
+    for channel in channels.iter().filter(|c| c.state == ChannelState::Opened) {
+        let mut a = known
+            .get(&channel.funding_txid)
+            .cloned()
+            .unwrap_or_else(|| ChannelActivity {
+                funding_txid: channel.funding_txid.clone(),
+                local_balance_msat: channel.local_balance_msat,
+                last_activity_at: now,
+                peer_connected_at: now,
+                suggested_at: None,
+            });
+        if a.local_balance_msat != channel.local_balance_msat {
+            a.local_balance_msat = channel.local_balance_msat;
+            a.last_activity_at = now;
+        }
+        if channel.peer_connected {
+            a.peer_connected_at = now;
+        }
+        let elapsed = |since: i64| now - since >= window_secs;
+        if elapsed(a.last_activity_at)
+            && elapsed(a.peer_connected_at)
+            && a.suggested_at.map_or(true, elapsed)
+        {
+            a.suggested_at = Some(now);
+            stale.push((channel, a.clone()));
+        }
+        activity.push(a);
+    }
+    (activity, stale)
+}
+
+/// The channels being opened, keeping when they were first seen from the `known` ones
+fn pending_channel_opens(
+    channels: &[Channel],
+    known: &[PendingChannelOpen],
+    now: u64,
+) -> Vec<PendingChannelOpen> {
+    channels
+        .iter()
+        .filter(|c| c.state == ChannelState::PendingOpen)
+        .map(|c| {
+            let seen_at = known
+                .iter()
+                .find(|k| k.funding_txid == c.funding_txid)
+                .map(|k| k.seen_at)
+                .unwrap_or(now);
+            PendingChannelOpen {
+                funding_txid: c.funding_txid.clone(),
+                amount_msat: c.local_balance_msat,
+                seen_at,
+                expected_confirmation_at: seen_at + CHANNEL_OPEN_EXPECTED_CONFIRMATION_SECS,
+            }
+        })
+        .collect()
+}
+
+/// Attempts to convert the phrase to a mnemonic, then to a seed.
+///
+/// If the phrase is not a valid mnemonic, an error is returned.
+pub fn mnemonic_to_seed(phrase: String) -> Result<Vec<u8>> {
+    let mnemonic = Mnemonic::from_phrase(&phrase, Language::English)?;
+    let seed = Seed::new(&mnemonic, "");
+    Ok(seed.as_bytes().to_vec())
+}
+
+pub struct OpenChannelParams {
+    pub payer_amount_msat: u64,
+    pub opening_fee_params: models::OpeningFeeParams,
+}
+
+#[tonic::async_trait]
+pub trait Receiver: Send + Sync {
+    fn open_channel_needed(&self, amount_msat: u64) -> Result<bool, ReceivePaymentError>;
+    async fn receive_payment(
+        &self,
+        req: ReceivePaymentRequest,
+    ) -> Result<ReceivePaymentResponse, ReceivePaymentError>;
+    async fn wrap_node_invoice(
+        &self,
+        invoice: &str,
+        params: Option<OpenChannelParams>,
+        lsp_info: Option<LspInformation>,
+    ) -> Result<String, ReceivePaymentError>;
+}
+
+pub(crate) struct PaymentReceiver {
+    config: Config,
+    node_api: Arc<dyn NodeAPI>,
+    lsp: Arc<dyn LspAPI>,
+    persister: Arc<SqliteStorage>,
+}
+
+#[tonic::async_trait]
+impl Receiver for PaymentReceiver {
+    fn open_channel_needed(&self, amount_msat: u64) -> Result<bool, ReceivePaymentError> {
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or(ReceivePaymentError::Generic {
+                err: "Node info not found".into(),
+            })?;
+        Ok(node_state.max_receivable_single_payment_amount_msat < amount_msat)
+    }
+
+    async fn receive_payment(
+        &self,
+        req: ReceivePaymentRequest,
+    ) -> Result<ReceivePaymentResponse, ReceivePaymentError> {
+        self.receive_payment_with_fallback(req, None).await
+    }
+
+    async fn wrap_node_invoice(
+        &self,
+        invoice: &str,
+        params: Option<OpenChannelParams>,
+        lsp_info: Option<LspInformation>,
+    ) -> Result<String, ReceivePaymentError> {
+        self.wrap_invoice(invoice, params, lsp_info, DEFAULT_MAX_ROUTE_HINTS as usize)
+            .await
+    }
+}
+
+impl PaymentReceiver {
+    /// Makes sure the amount can be received, or returns the maximum that can be received instead
+    fn ensure_receivable(&self, amount_msat: u64) -> Result<(), ReceivePaymentError> {
+        let node_state = self
+            .persister
+            .get_node_state()?
+            .ok_or(ReceivePaymentError::Generic {
+                err: "Node info not found".into(),
+            })?;
+        if amount_msat > MAX_PAYMENT_AMOUNT_MSAT {
+            return Err(ReceivePaymentError::AmountExceedsMaximum {
+                max_amount_msat: MAX_PAYMENT_AMOUNT_MSAT.min(node_state.max_receivable_msat),
+                reason: ReceiveLimitReason::MaxPaymentAmount,
+            });
+        }
+        if amount_msat > node_state.max_receivable_msat {
+            return Err(ReceivePaymentError::AmountExceedsMaximum {
+                max_amount_msat: node_state.max_receivable_msat,
+                reason: ReceiveLimitReason::MaxInboundLiquidity,
+            });
+        }
+        Ok(())
+    }
+
+    async fn wrap_invoice(
+        &self,
+        invoice: &str,
+        params: Option<OpenChannelParams>,
+        lsp_info: Option<LspInformation>,
+        max_route_hints: usize,
+    ) -> Result<String, ReceivePaymentError> {
+        let lsp_info = match lsp_info {
+            Some(lsp_info) => lsp_info,
+            None => get_lsp(self.persister.clone(), self.lsp.clone()).await?,
+        };
+
+        match params {
+            Some(params) => {
+                self.wrap_open_channel_invoice(invoice, params, &lsp_info)
+                    .await
+            }
+            None => self.ensure_hint(invoice, &lsp_info, max_route_hints).await,
+        }
+    }
+
+    /// Creates the invoice for a [ReceivePaymentRequest], with the optional onchain
+    /// `fallback_address` included in it.
+    async fn receive_payment_with_fallback(
+        &self,
+        req: ReceivePaymentRequest,
+        fallback_address: Option<String>,
+    ) -> Result<ReceivePaymentResponse, ReceivePaymentError> {
+        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
+        let expiry = req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS);
+        let max_route_hints = req.max_route_hints.unwrap_or(DEFAULT_MAX_ROUTE_HINTS) as usize;
+
+        let prepared = self.prepare_receive_payment(
+            PrepareReceivePaymentRequest {
+                amount_msat: req.amount_msat,
+                opening_fee_params: req.opening_fee_params,
+                expiry: Some(expiry),
+            },
+            &lsp_info,
+        )?;
+        let open_channel_needed = prepared.open_channel_needed;
+        let channel_opening_fee_params = prepared.opening_fee_params;
+        let channel_fees_msat = match open_channel_needed {
+            true => Some(prepared.opening_fee_msat),
+            false => None,
+        };
+
+        // A description committed to by its hash has to be kept as it is
+        let description = match req.use_description_hash {
+            Some(true) => req.description,
+            _ => self
+                .config
+                .memo_privacy
+                .apply(Some(req.description))
+                .unwrap_or_default(),
+        };
+        info!("Creating invoice on NodeAPI");
+        let invoice = self
+            .node_api
+            .create_invoice(CreateInvoiceRequest {
+                amount_msat: prepared.received_amount_msat.msat(),
+                description,
+                payer_amount_msat: match open_channel_needed {
+                    true => Some(req.amount_msat.msat()),
+                    false => None,
+                },
+                preimage: req.preimage,
+                use_description_hash: req.use_description_hash,
+                expiry: Some(expiry),
+                cltv: Some(req.cltv.unwrap_or(144)),
+                fallback_address,
+            })
+            .await?;
+        info!("Invoice created {}", invoice);
+
+        let open_channel_params = match open_channel_needed {
+            true => Some(OpenChannelParams {
+                payer_amount_msat: req.amount_msat.msat(),
+                opening_fee_params: channel_opening_fee_params.clone().ok_or(
+                    ReceivePaymentError::Generic {
+                        err: "We need to open a channel, but no channel opening fee params found"
+                            .into(),
+                    },
+                )?,
+            }),
+            false => None,
+        };
+
+        let invoice = self
+            .wrap_invoice(
+                &invoice,
+                open_channel_params,
+                Some(lsp_info),
+                max_route_hints,
+            )
+            .await?;
+        let parsed_invoice = parse_invoice(&invoice)?;
+        info!(
+            "Invoice has {} routing hints and a length of {}",
+            parsed_invoice.routing_hints.len(),
+            invoice.len()
+        );
+
+        // return the signed, converted invoice with hints
+        Ok(ReceivePaymentResponse {
+            ln_invoice: parsed_invoice,
+            opening_fee_params: channel_opening_fee_params,
+            opening_fee_msat: channel_fees_msat,
+            bolt11_length: invoice.len() as u32,
+            fiat_amount: None,
+            fiat_rate: None,
+        })
+    }
+
+    /// Calculates the channel opening fee that will be deducted from the received amount,
+    /// using the requested fee params or else the cheapest ones of the LSP
+    pub(crate) fn prepare_receive_payment(
+        &self,
+        req: PrepareReceivePaymentRequest,
+        lsp_info: &LspInformation,
+    ) -> Result<PrepareReceivePaymentResponse, ReceivePaymentError> {
+        let amount_msat = req.amount_msat.msat();
+        ensure_sdk!(
+            amount_msat > 0,
+            ReceivePaymentError::InvalidAmount {
+                err: "Receive amount must be more than 0".into()
+            }
+        );
+        self.ensure_receivable(amount_msat)?;
+
+        // check if we need to open channel
+        let open_channel_needed = self.open_channel_needed(amount_msat)?;
+        if !open_channel_needed {
+            return Ok(PrepareReceivePaymentResponse {
+                amount_msat: req.amount_msat,
+                open_channel_needed,
+                opening_fee_msat: MilliSat::ZERO,
+                opening_fee_params: None,
+                received_amount_msat: req.amount_msat,
+            });
+        }
+        info!("We need to open a channel");
+
+        // we need to open channel so we are calculating the fees for the LSP (coming either from the user, or from the LSP)
+        let ofp = match req.opening_fee_params {
+            Some(fee_params) => fee_params,
+            None => lsp_info
+                .cheapest_open_channel_fee(
+                    req.expiry.unwrap_or(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS),
+                )?
+                .clone(),
+        };
+        let channel_fees_msat = ofp.get_channel_fees_msat_for(amount_msat);
+        info!("zero-conf fee calculation option: lsp fee rate (proportional): {}:  (minimum {}), total fees for channel: {}",
+            ofp.proportional, ofp.min_msat, channel_fees_msat);
+
+        if amount_msat < channel_fees_msat + 1000 {
+            return Err(ReceivePaymentError::InvalidAmount {
+                err: format!(
+                    "Amount should be more than the minimum fees {channel_fees_msat} msat, but is {amount_msat} msat"
+                ),
+            });
+        }
+
+        Ok(PrepareReceivePaymentResponse {
+            amount_msat: req.amount_msat,
+            open_channel_needed,
+            opening_fee_msat: MilliSat(channel_fees_msat),
+            opening_fee_params: Some(ofp),
+            // remove the fees from the amount to get the small amount on the current node invoice.
+            received_amount_msat: MilliSat(amount_msat - channel_fees_msat),
+        })
+    }
+
+    async fn ensure_hint(
+        &self,
+        invoice: &str,
+        lsp_info: &LspInformation,
+        max_route_hints: usize,
+    ) -> Result<String, ReceivePaymentError> {
+        info!("Getting routing hints from node");
+        let (mut hints, has_public_channel) = self.node_api.get_routing_hints(lsp_info).await?;
+        if !has_public_channel && hints.is_empty() {
+            return Err(ReceivePaymentError::InvoiceNoRoutingHints {
+                err: "Must have at least one active channel".into(),
+            });
+        }
+
+        let parsed_invoice = parse_invoice(invoice)?;
+
+        // check if the lsp hint already exists
+        info!("Existing routing hints {:?}", parsed_invoice.routing_hints);
+
+        // limit the hints to the highest capacity ones and extract the lsp one.
+        if let Some(lsp_hint) =
+            Self::limit_and_extract_lsp_hint(&mut hints, lsp_info, max_route_hints)
+        {
+            if parsed_invoice.contains_hint_for_node(lsp_info.pubkey.as_str()) {
+                return Ok(String::from(invoice));
+            }
+
+            info!("Adding lsp hint: {:?}", lsp_hint);
+            let modified =
+                add_routing_hints(invoice, true, &vec![lsp_hint], parsed_invoice.amount_msat)?;
+
+            let invoice = self.node_api.sign_invoice(modified).await?;
+            info!("Signed invoice with hint = {}", invoice);
+            return Ok(invoice);
+        }
+
+        if parsed_invoice.routing_hints.is_empty() {
+            info!("Adding custom hints: {:?}", hints);
+            let modified = add_routing_hints(invoice, false, &hints, parsed_invoice.amount_msat)?;
+            let invoice = self.node_api.sign_invoice(modified).await?;
+            info!("Signed invoice with hints = {}", invoice);
+            return Ok(invoice);
+        }
+
+        Ok(String::from(invoice))
+    }
+
+    async fn wrap_open_channel_invoice(
+        &self,
+        invoice: &str,
+        params: OpenChannelParams,
+        lsp_info: &LspInformation,
+    ) -> Result<String, ReceivePaymentError> {
+        let parsed_invoice = parse_invoice(invoice)?;
+        let open_channel_hint = RouteHint {
+            hops: vec![RouteHintHop {
+                src_node_id: lsp_info.pubkey.clone(),
+                short_channel_id: "1x0x0".to_string(),
+                fees_base_msat: lsp_info.base_fee_msat as u32,
+                fees_proportional_millionths: (lsp_info.fee_rate * 1000000.0) as u32,
+                cltv_expiry_delta: lsp_info.time_lock_delta as u64,
+                htlc_minimum_msat: Some(lsp_info.min_htlc_msat as u64),
+                htlc_maximum_msat: None,
+            }],
+        };
+        info!("Adding open channel hint: {:?}", open_channel_hint);
+        let invoice_with_hint = add_routing_hints(
+            invoice,
+            false,
+            &vec![open_channel_hint],
+            Some(params.payer_amount_msat),
+        )?;
+        let signed_invoice = self.node_api.sign_invoice(invoice_with_hint).await?;
+
+        info!("Registering payment with LSP");
+        self.register_open_channel_payment(
+            lsp_info,
+            &parsed_invoice,
+            params.payer_amount_msat,
+            parsed_invoice
+                .amount_msat
+                .ok_or(anyhow!("Open channel invoice must have an amount"))?,
+            params.opening_fee_params.clone(),
+        )
+        .await?;
+        // Make sure we save the large amount so we can deduce the fees later.
+        self.persister.insert_open_channel_payment_info(
+            &parsed_invoice.payment_hash,
+            params.payer_amount_msat,
+            &signed_invoice,
+            Some(&params.opening_fee_params),
+        )?;
+        self.persister.insert_open_channel_registration(
+            &parsed_invoice.payment_hash,
+            &params.opening_fee_params,
+        )?;
+
+        Ok(signed_invoice)
+    }
+
+    /// Registers the open channel invoice with the LSP again, so a payment retried after the
+    /// channel opening timed out can still open the channel
+    pub(crate) async fn renew_open_channel_registration(
+        &self,
+        open_channel_bolt11: &str,
+        opening_fee_params: OpeningFeeParams,
+    ) -> Result<(), ReceivePaymentError> {
+        let lsp_info = get_lsp(self.persister.clone(), self.lsp.clone()).await?;
+        let invoice = parse_invoice(open_channel_bolt11)?;
+        let payer_amount_msat = invoice
+            .amount_msat
+            .ok_or(anyhow!("Open channel invoice must have an amount"))?;
+        let channel_fees_msat = opening_fee_params.get_channel_fees_msat_for(payer_amount_msat);
+        self.register_open_channel_payment(
+            &lsp_info,
+            &invoice,
+            payer_amount_msat,
+            payer_amount_msat.saturating_sub(channel_fees_msat),
+            opening_fee_params,
+        )
+        .await
+    }
+
+    async fn register_open_channel_payment(
+        &self,
+        lsp_info: &LspInformation,
+        invoice: &LNInvoice,
+        incoming_amount_msat: u64,
+        outgoing_amount_msat: u64,
+        opening_fee_params: OpeningFeeParams,
+    ) -> Result<(), ReceivePaymentError> {
+        let api_key = self.config.api_key.clone().unwrap_or_default();
+        let api_key_hash = sha256::Hash::hash(api_key.as_bytes()).to_hex();
+
+        self.lsp
+            .register_payment(
+                lsp_info.id.clone(),
+                lsp_info.lsp_pubkey.clone(),
+                grpc::PaymentInformation {
+                    payment_hash: hex::decode(invoice.payment_hash.clone())
+                        .map_err(|e| anyhow!("Failed to decode hex payment hash: {e}"))?,
+                    payment_secret: invoice.payment_secret.clone(),
+                    destination: hex::decode(invoice.payee_pubkey.clone())
+                        .map_err(|e| anyhow!("Failed to decode hex payee pubkey: {e}"))?,
+                    incoming_amount_msat: incoming_amount_msat as i64,
+                    outgoing_amount_msat: outgoing_amount_msat as i64,
+                    tag: json!({ "apiKeyHash": api_key_hash }).to_string(),
+                    opening_fee_params: Some(opening_fee_params.into()),
+                },
+            )
+            .await?;
+        Ok(())
+    }
+
+    fn limit_and_extract_lsp_hint(
+        routing_hints: &mut Vec<RouteHint>,
+        lsp_info: &LspInformation,
+        max_route_hints: usize,
+    ) -> Option<RouteHint> {
+        let mut lsp_hint: Option<RouteHint> = None;
+        if let Some(lsp_index) = routing_hints.iter().position(|r| {
+            r.hops
+                .iter()
+                .any(|h| h.src_node_id == lsp_info.pubkey.clone())
+        }) {
+            lsp_hint = Some(routing_hints.remove(lsp_index));
+        }
+        // Prefer the hints with the highest capacity, which is the smallest maximum HTLC
+        // along the hint hops.
+        routing_hints.sort_by_cached_key(|r| {
+            Reverse(
+                r.hops
+                    .iter()
+                    .map(|h| h.htlc_maximum_msat.unwrap_or_default())
+                    .min()
+                    .unwrap_or_default(),
+            )
+        });
+        routing_hints.truncate(max_route_hints);
+        lsp_hint
+    }
+}
+
+/// Convenience method to look up LSP info based on current LSP ID
+async fn get_lsp(
+    persister: Arc<SqliteStorage>,
+    lsp_api: Arc<dyn LspAPI>,
+) -> SdkResult<LspInformation> {
+    let lsp_id = persister
+        .get_lsp_id()?
+        .ok_or(SdkError::generic("No LSP ID found"))?;
+
+    get_lsp_by_id(persister, lsp_api, lsp_id.as_str())
+        .await?
+        .ok_or_else(|| SdkError::Generic {
+            err: format!("No LSP found for id {lsp_id}"),
+        })
+}
+
+async fn get_lsp_by_id(
+    persister: Arc<SqliteStorage>,
+    lsp_api: Arc<dyn LspAPI>,
+    lsp_id: &str,
+) -> SdkResult<Option<LspInformation>> {
+    let node_pubkey = persister
+        .get_node_state()?
+        .ok_or(SdkError::generic("Node info not found"))?
+        .id;
+
+    Ok(lsp_api
+        .list_lsps(node_pubkey)
+        .await?
+        .iter()
+        .find(|&lsp| lsp.id.as_str() == lsp_id)
+        .cloned())
+}
+
+/// Convenience method to get all LSPs (active and historical) relevant for registering or
+/// unregistering webhook notifications
+async fn get_notification_lsps(
+    persister: Arc<SqliteStorage>,
+    lsp_api: Arc<dyn LspAPI>,
+    node_api: Arc<dyn NodeAPI>,
+) -> SdkResult<Vec<LspInformation>> {
+    let node_pubkey = persister
+        .get_node_state()?
+        .ok_or(SdkError::generic("Node info not found"))?
+        .id;
+    let mut open_peers = None;
+
+    let mut notification_lsps = vec![];
+    for lsp in lsp_api.list_used_lsps(node_pubkey).await? {
+        match !lsp.opening_fee_params_list.values.is_empty() {
+            true => {
+                // Non-empty fee params list = this is the active LSP
+                // Always consider the active LSP for notifications
+                notification_lsps.push(lsp);
+            }
+            false => {
+                // Consider only historical LSPs with whom we have an active channel
+                let lsp_pubkey = hex::decode(&lsp.pubkey)
+                    .map_err(|e| anyhow!("Failed decode lsp pubkey: {e}"))?;
+                let open_peers = match &open_peers {
+                    Some(open_peers) => open_peers,
+                    None => {
+                        open_peers = Some(node_api.get_open_peers().await?);
+                        open_peers.as_ref().unwrap()
+                    }
+                };
+                let has_active_channel_to_lsp = open_peers.contains(&lsp_pubkey);
+                if has_active_channel_to_lsp {
+                    notification_lsps.push(lsp);
+                }
+            }
+        }
+    }
+    Ok(notification_lsps)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use anyhow::{anyhow, Result};
+    use futures::StreamExt;
+    #[cfg(feature = "buy-bitcoin")]
+    use regex::Regex;
+    #[cfg(feature = "buy-bitcoin")]
+    use reqwest::Url;
+    #[cfg(feature = "fiat")]
+    use sdk_common::prelude::Rate;
+    use serde_json::{json, Value};
+
+    use crate::bitcoin::hashes::hex::ToHex;
+    use crate::bitcoin::hashes::{sha256, Hash};
+    use crate::breez_services::{BreezServices, BreezServicesBuilder};
+    #[cfg(feature = "reverse-swaps")]
+    use crate::error::SendOnchainError;
+    use crate::error::{ReceivePaymentError, SendPaymentError};
+    use crate::lightning::ln::PaymentSecret;
+    use crate::lightning_invoice::{Currency, InvoiceBuilder};
+    use crate::models::{LnPaymentDetails, NodeState, Payment, PaymentDetails, PaymentTypeFilter};
+    use crate::node_api::{NodeAPI, PaymentLimits};
+    use crate::persist::cache::NodeStateStorage;
+    use crate::persist::channels::ChannelActivity;
+    #[cfg(feature = "lnurl")]
+    use crate::persist::lnurl_pay::PendingLnurlPay;
+    use crate::persist::swap::SwapStorage;
+    use crate::swap_in::SWAP_REFUND_KEY_GAP_LIMIT;
+    use crate::test_utils::*;
+    use crate::*;
+
+    #[cfg(feature = "fiat")]
+    use super::fiat_rate_alert_changes;
+    #[cfg(feature = "reverse-swaps")]
+    use super::onchain_address_amount;
+    #[cfg(feature = "lnurl")]
+    use super::LNURL_AUTH_MAX_ATTEMPTS_PER_WINDOW;
+    use super::{
+        breez_services_with_config, channel_hygiene_update, excluded_route_hint_channels,
+        fiat_to_msat, get_lsp, low_liquidity_events, onchain_wallet_scripts,
+        outbox_retry_delay_secs, parse_log_filter, parse_node_uri, reconnect_delay_ms,
+        service_status_events, unified_bip21_uri, ChildNumber, PaymentReceiver, PublicKey,
+        Receiver, Secp256k1, OUTBOX_MAX_RETRY_DELAY_SECS, OUTBOX_MIN_RETRY_DELAY_SECS,
+        RECONNECT_MAX_DELAY_MS, RECONNECT_MIN_DELAY_MS,
+    };
+    use crate::bitcoin::secp256k1::SecretKey;
+
+    #[tokio::test]
+    async fn test_node_state() -> Result<()> {
+        // let storage_path = format!("{}/storage.sql", get_test_working_dir());
+        // std::fs::remove_file(storage_path).ok();
+
+        let dummy_node_state = get_dummy_node_state();
+
+        let lnurl_metadata = "{'key': 'sample-metadata-val'}";
+        let test_ln_address = "test@ln-address.com";
+        let test_lnurl_withdraw_endpoint = "https://test.endpoint.lnurl-w";
+        let sa = SuccessActionProcessed::Message {
+            data: MessageSuccessActionData {
+                message: "test message".into(),
+            },
+        };
+
+        let payment_hash_lnurl_withdraw = "2222";
+        let payment_hash_with_lnurl_success_action = "3333";
+        let payment_hash_swap: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let swap_info = SwapInfo {
+            bitcoin_address: "123".to_string(),
+            created_at: 12345678,
+            lock_height: 654321,
+            payment_hash: payment_hash_swap.clone(),
+            preimage: vec![],
+            private_key: vec![],
+            public_key: vec![],
+            swapper_public_key: vec![],
+            script: vec![],
+            bolt11: Some("312".into()),
+            paid_msat: 1000,
+            confirmed_sats: 1,
+            unconfirmed_sats: 0,
+            total_incoming_txs: 1,
+            status: SwapStatus::Refundable,
+            refund_tx_ids: vec![],
+            unconfirmed_tx_ids: vec![],
+            confirmed_tx_ids: vec![],
+            min_allowed_deposit: 5_000,
+            max_allowed_deposit: 1_000_000,
+            max_swapper_payable: 2_000_000,
+            last_redeem_error: None,
+            channel_opening_fees: Some(OpeningFeeParams {
+                min_msat: 5_000_000,
+                proportional: 50,
+                valid_until: "date".to_string(),
+                max_idle_time: 12345,
+                max_client_to_self_delay: 234,
+                promise: "promise".to_string(),
+            }),
+            confirmed_at: Some(555),
+        };
+        let payment_hash_rev_swap: Vec<u8> = vec![8, 7, 6, 5, 4, 3, 2, 1];
+        let preimage_rev_swap: Vec<u8> = vec![6, 6, 6, 6];
+        let full_ref_swap_info = FullReverseSwapInfo {
+            id: "rev_swap_id".to_string(),
+            created_at_block_height: 0,
+            preimage: preimage_rev_swap.clone(),
+            private_key: vec![],
+            claim_pubkey: "claim_pubkey".to_string(),
+            timeout_block_height: 600_000,
+            invoice: "645".to_string(),
+            redeem_script: "redeem_script".to_string(),
+            onchain_amount_sat: 250,
+            sat_per_vbyte: Some(50),
+            receive_amount_sat: None,
+            protocol: ReverseSwapProtocol::Legacy,
+            refund_pubkey: None,
+            cache: ReverseSwapInfoCached {
+                status: ReverseSwapStatus::CompletedConfirmed,
+                lockup_txid: Some("lockup_txid".to_string()),
+                claim_txid: Some("claim_txid".to_string()),
+            },
+        };
+        let rev_swap_info = ReverseSwapInfo {
+            id: "rev_swap_id".to_string(),
+            claim_pubkey: "claim_pubkey".to_string(),
+            lockup_txid: Some("lockup_txid".to_string()),
+            claim_txid: Some("claim_txid".to_string()),
+            onchain_amount_sat: 250,
+            status: ReverseSwapStatus::CompletedConfirmed,
+            protocol: ReverseSwapProtocol::Legacy,
+        };
+        let dummy_transactions = vec![
+            Payment {
+                id: "1111".to_string(),
+                payment_type: PaymentType::Received,
+                payment_time: 100000,
+                amount_msat: 10,
+                fee_msat: 0,
+                status: PaymentStatus::Complete,
+                error: None,
+                description: Some("test receive".to_string()),
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: "1111".to_string(),
+                        label: "".to_string(),
+                        destination_pubkey: "1111".to_string(),
+                        payment_preimage: "2222".to_string(),
+                        keysend: false,
+                        bolt11: "1111".to_string(),
+                        lnurl_success_action: None,
+                        lnurl_pay_domain: None,
+                        lnurl_pay_comment: None,
+                        lnurl_metadata: None,
+                        ln_address: None,
+                        lnurl_withdraw_endpoint: None,
+                        swap_info: None,
+                        reverse_swap_info: None,
+                        pending_expiration_block: None,
+                        open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
+                    },
+                },
+                metadata: None,
+                fiat_rate: None,
+                imported: false,
+            },
+            Payment {
+                id: payment_hash_lnurl_withdraw.to_string(),
+                payment_type: PaymentType::Received,
+                payment_time: 150000,
+                amount_msat: 10,
+                fee_msat: 0,
+                status: PaymentStatus::Complete,
+                error: None,
+                description: Some("test lnurl-withdraw receive".to_string()),
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: payment_hash_lnurl_withdraw.to_string(),
+                        label: "".to_string(),
+                        destination_pubkey: "1111".to_string(),
+                        payment_preimage: "3333".to_string(),
+                        keysend: false,
+                        bolt11: "1111".to_string(),
+                        lnurl_success_action: None,
+                        lnurl_pay_domain: None,
+                        lnurl_pay_comment: None,
+                        lnurl_metadata: None,
+                        ln_address: None,
+                        lnurl_withdraw_endpoint: Some(test_lnurl_withdraw_endpoint.to_string()),
+                        swap_info: None,
+                        reverse_swap_info: None,
+                        pending_expiration_block: None,
+                        open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
+                    },
+                },
+                metadata: None,
+                fiat_rate: None,
+                imported: false,
+            },
+            Payment {
+                id: payment_hash_with_lnurl_success_action.to_string(),
+                payment_type: PaymentType::Sent,
+                payment_time: 200000,
+                amount_msat: 8,
+                fee_msat: 2,
+                status: PaymentStatus::Complete,
+                error: None,
+                description: Some("test payment".to_string()),
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: payment_hash_with_lnurl_success_action.to_string(),
+                        label: "".to_string(),
+                        destination_pubkey: "123".to_string(),
+                        payment_preimage: "4444".to_string(),
+                        keysend: false,
+                        bolt11: "123".to_string(),
+                        lnurl_success_action: Some(sa.clone()),
+                        lnurl_pay_domain: None,
+                        lnurl_pay_comment: None,
+                        lnurl_metadata: Some(lnurl_metadata.to_string()),
+                        ln_address: Some(test_ln_address.to_string()),
+                        lnurl_withdraw_endpoint: None,
+                        swap_info: None,
+                        reverse_swap_info: None,
+                        pending_expiration_block: None,
+                        open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
+                    },
+                },
+                metadata: None,
+                fiat_rate: None,
+                imported: false,
+            },
+            Payment {
+                id: hex::encode(payment_hash_swap.clone()),
+                payment_type: PaymentType::Received,
+                payment_time: 250000,
+                amount_msat: 1_000,
+                fee_msat: 0,
+                status: PaymentStatus::Complete,
+                error: None,
+                description: Some("test receive".to_string()),
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: hex::encode(payment_hash_swap),
+                        label: "".to_string(),
+                        destination_pubkey: "321".to_string(),
+                        payment_preimage: "5555".to_string(),
+                        keysend: false,
+                        bolt11: "312".to_string(),
+                        lnurl_success_action: None,
+                        lnurl_pay_domain: None,
+                        lnurl_pay_comment: None,
+                        lnurl_metadata: None,
+                        ln_address: None,
+                        lnurl_withdraw_endpoint: None,
+                        swap_info: Some(swap_info.clone()),
+                        reverse_swap_info: None,
+                        pending_expiration_block: None,
+                        open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
+                    },
+                },
+                metadata: None,
+                fiat_rate: None,
+                imported: false,
+            },
+            Payment {
+                id: hex::encode(payment_hash_rev_swap.clone()),
+                payment_type: PaymentType::Sent,
+                payment_time: 300000,
+                amount_msat: 50_000_000,
+                fee_msat: 2_000,
+                status: PaymentStatus::Complete,
+                error: None,
+                description: Some("test send onchain".to_string()),
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: hex::encode(payment_hash_rev_swap),
+                        label: "".to_string(),
+                        destination_pubkey: "321".to_string(),
+                        payment_preimage: hex::encode(preimage_rev_swap),
+                        keysend: false,
+                        bolt11: "312".to_string(),
+                        lnurl_success_action: None,
+                        lnurl_metadata: None,
+                        lnurl_pay_domain: None,
+                        lnurl_pay_comment: None,
+                        ln_address: None,
+                        lnurl_withdraw_endpoint: None,
+                        swap_info: None,
+                        reverse_swap_info: Some(rev_swap_info.clone()),
+                        pending_expiration_block: None,
+                        open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
+                    },
+                },
                 metadata: None,
+                fiat_rate: None,
+                imported: false,
+            },
+        ];
+        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+
+        let test_config = create_test_config();
+        let persister = Arc::new(create_test_persister(test_config.clone()));
+        persister.init()?;
+        persister.insert_or_update_payments(&dummy_transactions, false)?;
+        persister.insert_payment_external_info(
+            payment_hash_with_lnurl_success_action,
+            PaymentExternalInfo {
+                lnurl_pay_success_action: Some(sa.clone()),
+                lnurl_pay_domain: None,
+                lnurl_pay_comment: None,
+                lnurl_metadata: Some(lnurl_metadata.to_string()),
+                ln_address: Some(test_ln_address.to_string()),
+                lnurl_withdraw_endpoint: None,
+                attempted_amount_msat: None,
+                attempted_error: None,
+            },
+        )?;
+        persister.insert_payment_external_info(
+            payment_hash_lnurl_withdraw,
+            PaymentExternalInfo {
+                lnurl_pay_success_action: None,
+                lnurl_pay_domain: None,
+                lnurl_pay_comment: None,
+                lnurl_metadata: None,
+                ln_address: None,
+                lnurl_withdraw_endpoint: Some(test_lnurl_withdraw_endpoint.to_string()),
+                attempted_amount_msat: None,
+                attempted_error: None,
+            },
+        )?;
+        persister.insert_swap(&swap_info, None)?;
+        persister.update_swap_bolt11(
+            swap_info.bitcoin_address.clone(),
+            swap_info.bolt11.clone().unwrap(),
+        )?;
+        persister.insert_reverse_swap(&full_ref_swap_info)?;
+        persister
+            .update_reverse_swap_status("rev_swap_id", &ReverseSwapStatus::CompletedConfirmed)?;
+        persister
+            .update_reverse_swap_lockup_txid("rev_swap_id", Some("lockup_txid".to_string()))?;
+        persister.update_reverse_swap_claim_txid("rev_swap_id", Some("claim_txid".to_string()))?;
+
+        let mut builder = BreezServicesBuilder::new(test_config.clone());
+        builder
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .node_api(node_api)
+            .persister(persister)
+            .backup_transport(Arc::new(MockBackupTransport::new()));
+        #[cfg(feature = "fiat")]
+        builder.fiat_api(Arc::new(MockBreezServer {}));
+        let breez_services = builder.build(None, None).await?;
+
+        breez_services.sync().await?;
+        let fetched_state = breez_services.node_info()?;
+        assert_eq!(fetched_state, dummy_node_state);
+
+        let all = breez_services
+            .list_payments(ListPaymentsRequest::default())
+            .await?;
+        let mut cloned = all.clone();
+
+        // test the right order
+        cloned.reverse();
+        assert_eq!(dummy_transactions, cloned);
+
+        let received = breez_services
+            .list_payments(ListPaymentsRequest {
+                filters: Some(vec![PaymentTypeFilter::Received]),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(
+            received,
+            vec![cloned[3].clone(), cloned[1].clone(), cloned[0].clone()]
+        );
+
+        let sent = breez_services
+            .list_payments(ListPaymentsRequest {
+                filters: Some(vec![
+                    PaymentTypeFilter::Sent,
+                    PaymentTypeFilter::ClosedChannel,
+                ]),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(sent, vec![cloned[4].clone(), cloned[2].clone()]);
+        assert!(matches!(
+                &sent[1].details,
+                PaymentDetails::Ln {data: LnPaymentDetails {lnurl_success_action, ..}}
+                if lnurl_success_action == &Some(sa)));
+        assert!(matches!(
+                &sent[1].details,
+                PaymentDetails::Ln {data: LnPaymentDetails {lnurl_pay_domain, ln_address, ..}}
+                if lnurl_pay_domain.is_none() && ln_address == &Some(test_ln_address.to_string())));
+        assert!(matches!(
+                &received[1].details,
+                PaymentDetails::Ln {data: LnPaymentDetails {lnurl_withdraw_endpoint, ..}}
+                if lnurl_withdraw_endpoint == &Some(test_lnurl_withdraw_endpoint.to_string())));
+        assert!(matches!(
+                &received[0].details,
+                PaymentDetails::Ln {data: LnPaymentDetails {swap_info: swap, ..}}
+                if swap == &Some(swap_info)));
+        assert!(matches!(
+                &sent[0].details,
+                PaymentDetails::Ln {data: LnPaymentDetails {reverse_swap_info: rev_swap, ..}}
+                if rev_swap == &Some(rev_swap_info)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_with_open_channel() -> Result<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init().unwrap();
+
+        let dummy_node_state = get_dummy_node_state();
+
+        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+
+        let breez_server = Arc::new(MockBreezServer {});
+        persister.set_lsp(breez_server.lsp_id(), None).unwrap();
+        persister.set_node_state(&dummy_node_state).unwrap();
+
+        let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
+            config,
+            node_api,
+            persister,
+            lsp: breez_server.clone(),
+        });
+        let ln_invoice = receiver
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: MilliSat(3_000_000),
+                description: "should populate lsp hints".to_string(),
+                use_description_hash: Some(false),
+                ..Default::default()
+            })
+            .await?
+            .ln_invoice;
+        assert_eq!(ln_invoice.routing_hints[0].hops.len(), 1);
+        let lsp_hop = &ln_invoice.routing_hints[0].hops[0];
+        assert_eq!(lsp_hop.src_node_id, breez_server.clone().lsp_pub_key());
+        assert_eq!(lsp_hop.short_channel_id, "1x0x0");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_with_memo_privacy() -> Result<()> {
+        let receive = |memo_privacy: MemoPrivacy, use_description_hash: bool| async move {
+            let config = Config {
+                memo_privacy,
+                ..create_test_config()
+            };
+            let persister = Arc::new(create_test_persister(config.clone()));
+            persister.init()?;
+            let dummy_node_state = get_dummy_node_state();
+            let breez_server = Arc::new(MockBreezServer {});
+            persister.set_lsp(breez_server.lsp_id(), None)?;
+            persister.set_node_state(&dummy_node_state)?;
+            let receiver = PaymentReceiver {
+                config,
+                node_api: Arc::new(MockNodeAPI::new(dummy_node_state)),
+                persister,
+                lsp: breez_server,
+            };
+            let res = receiver
+                .receive_payment(ReceivePaymentRequest {
+                    amount_msat: MilliSat(3_000_000),
+                    description: "Coffee".to_string(),
+                    use_description_hash: Some(use_description_hash),
+                    ..Default::default()
+                })
+                .await?;
+            Ok::<_, anyhow::Error>(res.ln_invoice.description)
+        };
+
+        assert_eq!(
+            receive(MemoPrivacy::Plain, false).await?,
+            Some("Coffee".into())
+        );
+        assert_eq!(
+            receive(MemoPrivacy::Hash, false).await?,
+            Some(format!(
+                "sha256:{}",
+                sha256::Hash::hash("Coffee".as_bytes()).to_hex()
+            ))
+        );
+        assert_eq!(receive(MemoPrivacy::Strip, false).await?, Some("".into()));
+        // The description committed to by its hash is kept
+        assert_eq!(
+            receive(MemoPrivacy::Strip, true).await?,
+            Some("Coffee".into())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare_receive_payment() -> Result<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init().unwrap();
+
+        let dummy_node_state = get_dummy_node_state();
+        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+        let breez_server = Arc::new(MockBreezServer {});
+        persister.set_lsp(breez_server.lsp_id(), None).unwrap();
+        persister.set_node_state(&dummy_node_state).unwrap();
+
+        let receiver = PaymentReceiver {
+            config,
+            node_api,
+            persister: persister.clone(),
+            lsp: breez_server.clone(),
+        };
+        let lsp_info = get_lsp(persister, breez_server).await?;
+        let prepare = |amount_msat| {
+            receiver.prepare_receive_payment(
+                PrepareReceivePaymentRequest {
+                    amount_msat: MilliSat(amount_msat),
+                    ..Default::default()
+                },
+                &lsp_info,
+            )
+        };
+
+        // Enough inbound liquidity, no channel is needed
+        let res = prepare(dummy_node_state.max_receivable_single_payment_amount_msat)?;
+        assert!(!res.open_channel_needed);
+        assert_eq!(res.opening_fee_msat, MilliSat::ZERO);
+        assert!(res.opening_fee_params.is_none());
+        assert_eq!(res.received_amount_msat, res.amount_msat);
+
+        let res = prepare(3_000_000)?;
+        let ofp = lsp_info
+            .cheapest_open_channel_fee(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS)?
+            .clone();
+        assert!(res.open_channel_needed);
+        assert_eq!(
+            res.opening_fee_msat,
+            MilliSat(ofp.get_channel_fees_msat_for(3_000_000))
+        );
+        assert_eq!(res.opening_fee_params, Some(ofp));
+        assert_eq!(
+            res.received_amount_msat,
+            MilliSat(3_000_000) - res.opening_fee_msat
+        );
+
+        // The amount must cover the opening fee
+        assert!(matches!(
+            prepare(res.opening_fee_msat.msat()),
+            Err(ReceivePaymentError::InvalidAmount { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_coalesces_concurrent_calls() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
+
+        let (first, second) = tokio::join!(breez_services.sync(), breez_services.sync());
+        first?;
+        second?;
+        assert_eq!(*node_api.num_pull_changed.lock().unwrap(), 1);
+
+        // Once finished, the next call syncs again
+        breez_services.sync().await?;
+        assert_eq!(*node_api.num_pull_changed.lock().unwrap(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_partial() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
+        let mut synced_events =
+            Box::pin(breez_services.subscribe_filtered(|e| matches!(e, BreezEvent::Synced)));
+
+        breez_services.sync_partial().await?;
+        assert_eq!(*node_api.num_pull_changed.lock().unwrap(), 1);
+        assert_eq!(synced_events.next().await, Some(BreezEvent::Synced));
+        assert_eq!(breez_services.node_info()?.id, get_dummy_node_state().id);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_amount_exceeds_maximum() -> Result<()> {
+        let config = create_test_config();
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init().unwrap();
+
+        let dummy_node_state = get_dummy_node_state();
+        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+        let breez_server = Arc::new(MockBreezServer {});
+        persister.set_lsp(breez_server.lsp_id(), None).unwrap();
+        persister.set_node_state(&dummy_node_state).unwrap();
+
+        let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
+            config,
+            node_api,
+            persister,
+            lsp: breez_server,
+        });
+        let receive = |amount_msat| {
+            receiver.receive_payment(ReceivePaymentRequest {
+                amount_msat: MilliSat(amount_msat),
+                description: "too much".to_string(),
+                ..Default::default()
+            })
+        };
+
+        let res = receive(dummy_node_state.max_receivable_msat + 1).await;
+        assert!(matches!(
+            res,
+            Err(ReceivePaymentError::AmountExceedsMaximum {
+                max_amount_msat,
+                reason: ReceiveLimitReason::MaxInboundLiquidity,
+            }) if max_amount_msat == dummy_node_state.max_receivable_msat
+        ));
+
+        let res = receive(MAX_PAYMENT_AMOUNT_MSAT + 1).await;
+        assert!(matches!(
+            res,
+            Err(ReceivePaymentError::AmountExceedsMaximum {
+                reason: ReceiveLimitReason::MaxPaymentAmount,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_unified_limits() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let receive = |amount_sat| {
+            breez_services.receive_unified(ReceiveUnifiedRequest {
+                amount_sat: Sat(amount_sat),
+                description: "unified".to_string(),
+                opening_fee_params: None,
+            })
+        };
+
+        // The mock swapper takes deposits between 1,000 and 1,000,000 sat
+        assert!(matches!(
+            receive(999).await,
+            Err(ReceivePaymentError::InvalidAmount { .. })
+        ));
+        assert!(matches!(
+            receive(1_000_001).await,
+            Err(ReceivePaymentError::AmountExceedsMaximum {
+                max_amount_msat: 1_000_000_000,
+                reason: ReceiveLimitReason::MaxSwapDeposit,
+            })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prepare_send_payment() -> Result<()> {
+        let path = |base_fee_msat| PaymentPath {
+            edges: vec![
+                PaymentPathEdge {
+                    node_id: vec![],
+                    short_channel_id: "1x1x1".to_string(),
+                    channel_delay: 0,
+                    base_fee_msat: 0,
+                    fee_per_millionth: 0,
+                },
+                PaymentPathEdge {
+                    node_id: vec![],
+                    short_channel_id: "2x2x2".to_string(),
+                    channel_delay: 40,
+                    base_fee_msat,
+                    fee_per_millionth: 0,
+                },
+            ],
+        };
+        let max_amounts = vec![
+            MaxChannelAmount {
+                channel_id: "chan1".to_string(),
+                amount_msat: 30_000,
+                path: path(1_000),
+            },
+            MaxChannelAmount {
+                channel_id: "chan2".to_string(),
+                amount_msat: 40_000,
+                path: path(2_000),
+            },
+        ];
+        let mut node_api = crate::node_api::MockNodeAPI::new();
+        node_api
+            .expect_derive_symmetric_key()
+            .returning(|_| Ok([0; 32]));
+        node_api
+            .expect_legacy_derive_symmetric_key()
+            .returning(|_| Ok([0; 32]));
+        node_api
+            .expect_max_sendable_amount()
+            .returning(move |_, _, _| Ok(max_amounts.clone()));
+        let breez_services = breez_services_with(Some(Arc::new(node_api)), None, vec![]).await?;
+
+        // The largest channel is used first, then the rest is sent through the other one
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        let res = breez_services
+            .prepare_send_payment(PrepareSendPaymentRequest {
+                bolt11: invoice.bolt11,
+                amount_msat: None,
+            })
+            .await?;
+        assert_eq!(res.amount_msat, MilliSat(50_000));
+        assert_eq!(res.fee_msat, MilliSat(3_000));
+        assert_eq!(res.parts, 2);
+        assert_eq!(res.route_hops, 2);
+
+        let invoice = create_invoice("test".to_string(), 80_000, vec![], None);
+        let res = breez_services
+            .prepare_send_payment(PrepareSendPaymentRequest {
+                bolt11: invoice.bolt11,
+                amount_msat: None,
+            })
+            .await;
+        assert!(matches!(res, Err(SendPaymentError::RouteNotFound { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_node_uri() -> Result<()> {
+        let node_id = "02eadbd9e7557375161df8b646776a547c5cbc2e95b3071ec81553f8ec2cea3b8c";
+        assert_eq!(
+            parse_node_uri(&format!("{node_id}@1.2.3.4:9735"))?,
+            (node_id.to_string(), "1.2.3.4:9735".to_string())
+        );
+        assert_eq!(
+            parse_node_uri(&format!("{node_id}@[::1]:9735"))?.1,
+            "[::1]:9735"
+        );
+
+        for node_uri in [
+            node_id.to_string(),
+            format!("{node_id}@1.2.3.4"),
+            format!("{node_id}@:9735"),
+            format!("{node_id}@1.2.3.4:port"),
+            "02eadbd9@1.2.3.4:9735".to_string(),
+        ] {
+            assert!(parse_node_uri(&node_uri).is_err(), "{node_uri}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_excluded_route_hint_channels() -> Result<()> {
+        let hint = |src_node_id: &str, short_channel_id: &str| RouteHint {
+            hops: vec![RouteHintHop {
+                src_node_id: src_node_id.to_string(),
+                short_channel_id: short_channel_id.to_string(),
+                fees_base_msat: 0,
+                fees_proportional_millionths: 0,
+                cltv_expiry_delta: 40,
+                htlc_minimum_msat: None,
+                htlc_maximum_msat: None,
+            }],
+        };
+        let mut invoice = create_invoice("test".to_string(), 1_000, vec![], None);
+        invoice.payee_pubkey = format!("03{}", "11".repeat(32));
+        invoice.routing_hints = vec![
+            hint(&format!("02{}", "22".repeat(32)), "1x1x1"),
+            hint(&format!("03{}", "33".repeat(32)), "2x2x2"),
+            hint(&format!("03{}", "44".repeat(32)), "3x3x3"),
+        ];
+
+        assert_eq!(
+            excluded_route_hint_channels(&invoice, 2)?,
+            vec!["1x1x1/0".to_string(), "2x2x2/1".to_string()]
+        );
+        assert!(matches!(
+            excluded_route_hint_channels(&invoice, 3),
+            Err(SendPaymentError::InvalidInvoice { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "reverse-swaps")]
+    fn test_onchain_address_amount() {
+        let limits = |max_payable_sat| OnchainPaymentLimitsResponse {
+            min_sat: Sat(1_000),
+            max_sat: Sat(100_000),
+            max_payable_sat: Sat(max_payable_sat),
+        };
+
+        // Draining sends what the channels allow, capped to the reverse swap max
+        assert!(matches!(
+            onchain_address_amount(true, None, &limits(50_000)),
+            Ok((Sat(50_000), SwapAmountType::Send))
+        ));
+        assert!(matches!(
+            onchain_address_amount(true, None, &limits(200_000)),
+            Ok((Sat(100_000), SwapAmountType::Send))
+        ));
+        assert!(matches!(
+            onchain_address_amount(true, None, &limits(500)),
+            Err(SendOnchainError::OutOfRange)
+        ));
+        assert!(onchain_address_amount(true, Some(Sat(10_000)), &limits(50_000)).is_err());
+
+        // Otherwise the amount is the one the recipient receives
+        assert!(matches!(
+            onchain_address_amount(false, Some(Sat(10_000)), &limits(50_000)),
+            Ok((Sat(10_000), SwapAmountType::Receive))
+        ));
+        assert!(onchain_address_amount(false, None, &limits(50_000)).is_err());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "reverse-swaps")]
+    async fn test_pay_onchain_address_invalid_address() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        for address in ["not an address", invoice.bolt11.as_str()] {
+            let res = breez_services
+                .pay_onchain_address(PayOnchainAddressRequest {
+                    address: address.to_string(),
+                    amount_sat: Some(Sat(10_000)),
+                    drain: false,
+                    fee_preference: None,
+                })
+                .await;
+            assert!(matches!(
+                res,
+                Err(SendOnchainError::InvalidDestinationAddress { .. })
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unified_bip21_uri() {
+        assert_eq!(
+            unified_bip21_uri("bc1qaddress", 150_000, "Coffee & cake", "lnbc1500u1invoice"),
+            "bitcoin:bc1qaddress?amount=0.0015&label=Coffee%20%26%20cake&lightning=lnbc1500u1invoice"
+        );
+        assert_eq!(
+            unified_bip21_uri("bc1qaddress", 200_000_000, "", "lnbc2invoice"),
+            "bitcoin:bc1qaddress?amount=2&lightning=lnbc2invoice"
+        );
+    }
+
+    #[test]
+    fn test_parse_log_filter() -> Result<()> {
+        let filter = parse_log_filter("warn, breez_sdk_core=trace")?;
+        let metadata = |level, target| log::Metadata::builder().level(level).target(target).build();
+        assert!(filter.enabled(&metadata(
+            log::Level::Trace,
+            "breez_sdk_core::breez_services"
+        )));
+        assert!(!filter.enabled(&metadata(log::Level::Info, "gl_client")));
+        assert!(filter.enabled(&metadata(log::Level::Warn, "gl_client")));
+
+        assert!(parse_log_filter("breez_sdk_core=verbose").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_config_log_filter() {
+        let config = Config {
+            log_filter: Some("breez_sdk_core=verbose".to_string()),
+            ..create_test_config()
+        };
+        assert!(breez_services_with_config(config, None, None, vec![])
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_low_liquidity_events() {
+        let mut config = create_test_config();
+        config.low_outbound_liquidity_threshold_msat = Some(10_000);
+        config.low_inbound_liquidity_threshold_msat = Some(20_000);
+        let state = |max_payable_msat, total_inbound_liquidity_msats| NodeState {
+            max_payable_msat,
+            total_inbound_liquidity_msats,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            low_liquidity_events(&config, None, &state(5_000, 50_000)),
+            vec![BreezEvent::LowOutboundLiquidity {
+                details: LowLiquidityDetails {
+                    liquidity_msat: 5_000,
+                    threshold_msat: 10_000,
+                }
+            }]
+        );
+        assert_eq!(
+            low_liquidity_events(&config, Some(&state(15_000, 50_000)), &state(5_000, 15_000)),
+            vec![
+                BreezEvent::LowOutboundLiquidity {
+                    details: LowLiquidityDetails {
+                        liquidity_msat: 5_000,
+                        threshold_msat: 10_000,
+                    }
+                },
+                BreezEvent::LowInboundLiquidity {
+                    details: LowLiquidityDetails {
+                        liquidity_msat: 15_000,
+                        threshold_msat: 20_000,
+                    }
+                }
+            ]
+        );
+        // Already below the thresholds on the previous sync
+        assert!(
+            low_liquidity_events(&config, Some(&state(5_000, 15_000)), &state(4_000, 10_000))
+                .is_empty()
+        );
+
+        config.low_outbound_liquidity_threshold_msat = None;
+        config.low_inbound_liquidity_threshold_msat = None;
+        assert!(low_liquidity_events(&config, None, &state(0, 0)).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_payment_limits() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let req = SendPaymentRequest {
+            bolt11: "bolt11".to_string(),
+            use_trampoline: None,
+            amount_msat: None,
+            label: None,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: None,
+            skip_denylist: None,
+        };
+
+        // The config limits apply unless overridden by the request
+        let config = create_test_config();
+        assert_eq!(
+            breez_services.payment_limits(&req)?,
+            PaymentLimits {
+                timeout_sec: config.payment_timeout_sec,
+                maxfee_percent: config.maxfee_percent,
+                exemptfee_msat: config.exemptfee_msat,
+            }
+        );
+        let limits = breez_services.payment_limits(&SendPaymentRequest {
+            payment_timeout_sec: Some(10),
+            maxfee_percent: Some(0.1),
+            ..req.clone()
+        })?;
+        assert_eq!(
+            limits,
+            PaymentLimits {
+                timeout_sec: 10,
+                maxfee_percent: 0.1,
+                exemptfee_msat: config.exemptfee_msat,
+            }
+        );
+        assert!(limits.allows_fee(1_000, 100_000_000));
+        assert!(!limits.allows_fee(200_000, 100_000_000));
+        // Small fees are exempted from the percentage
+        assert!(limits.allows_fee(config.exemptfee_msat, 1_000));
+
+        assert!(breez_services
+            .payment_limits(&SendPaymentRequest {
+                payment_timeout_sec: Some(0),
+                ..req.clone()
+            })
+            .is_err());
+        assert!(breez_services
+            .payment_limits(&SendPaymentRequest {
+                maxfee_percent: Some(-1.0),
+                ..req
+            })
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_trampoline_id() -> Result<()> {
+        let lsp_pubkey = MockBreezServer {}.lsp_pub_key();
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        let req = SendPaymentRequest {
+            bolt11: invoice.bolt11.clone(),
+            use_trampoline: None,
+            amount_msat: None,
+            label: None,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: None,
+            skip_denylist: None,
+        };
+
+        for config_default in [false, true] {
+            let config = Config {
+                use_trampoline: config_default,
+                ..create_test_config()
+            };
+            let breez_services = breez_services_with_config(config, None, None, vec![]).await?;
+            let trampoline_id = |req: &SendPaymentRequest| {
+                breez_services
+                    .get_trampoline_id(req, &invoice)
+                    .map(|id| id.is_some())
+            };
+
+            // Without a persisted LSP pubkey there is no trampoline node
+            assert!(!trampoline_id(&SendPaymentRequest {
+                use_trampoline: Some(true),
+                ..req.clone()
+            })?);
+
+            breez_services
+                .persister
+                .set_lsp(MockBreezServer {}.lsp_id(), Some(lsp_pubkey.clone()))?;
+
+            // The config decides by default, the request overrides it
+            assert_eq!(trampoline_id(&req)?, config_default);
+            assert!(trampoline_id(&SendPaymentRequest {
+                use_trampoline: Some(true),
+                ..req.clone()
+            })?);
+            assert!(!trampoline_id(&SendPaymentRequest {
+                use_trampoline: Some(false),
+                ..req.clone()
+            })?);
+
+            // A chosen route hint disables trampoline
+            assert!(!trampoline_id(&SendPaymentRequest {
+                use_trampoline: Some(true),
+                route_hint_index: Some(0),
+                ..req.clone()
+            })?);
+        }
+
+        // Invoices routed through the LSP are paid directly
+        let config = Config {
+            use_trampoline: true,
+            ..create_test_config()
+        };
+        let breez_services = breez_services_with_config(config, None, None, vec![]).await?;
+        breez_services
+            .persister
+            .set_lsp(MockBreezServer {}.lsp_id(), Some(lsp_pubkey.clone()))?;
+        let lsp_hint = RouteHint {
+            hops: vec![RouteHintHop {
+                src_node_id: lsp_pubkey.clone(),
+                short_channel_id: "1x1x1".to_string(),
+                fees_base_msat: 1_000,
+                fees_proportional_millionths: 100,
+                cltv_expiry_delta: 144,
+                htlc_minimum_msat: None,
+                htlc_maximum_msat: None,
+            }],
+        };
+        let hinted = create_invoice("test".to_string(), 50_000, vec![lsp_hint], None);
+        assert_eq!(breez_services.get_trampoline_id(&req, &hinted)?, None);
+        assert_eq!(
+            breez_services.get_trampoline_id(&req, &invoice)?,
+            Some(hex::decode(lsp_pubkey)?)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_probe_payment() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let invoice = create_invoice("test".to_string(), 50_000_000, vec![], None);
+
+        let probe = breez_services
+            .probe_payment(ProbePaymentRequest {
+                destination: invoice.bolt11.clone(),
+                amount_msat: None,
+            })
+            .await?;
+        assert_eq!(probe.node_id, invoice.payee_pubkey);
+        assert_eq!(probe.bolt11, Some(invoice.bolt11));
+        assert_eq!(probe.amount_msat, 50_000_000);
+        assert!(probe.route_found && probe.reachable);
+        assert_eq!(probe.estimated_fee_msat, Some(50_000));
+
+        // Node ids are probed with the given amount
+        let node_id = invoice.payee_pubkey;
+        let req = ProbePaymentRequest {
+            destination: node_id.clone(),
+            amount_msat: None,
+        };
+        assert!(breez_services.probe_payment(req.clone()).await.is_err());
+        let probe = breez_services
+            .probe_payment(ProbePaymentRequest {
+                amount_msat: Some(MilliSat(1_000_000)),
+                ..req
+            })
+            .await?;
+        assert_eq!(probe.node_id, node_id);
+        assert_eq!(probe.bolt11, None);
+
+        assert!(breez_services
+            .probe_payment(ProbePaymentRequest {
+                destination: "not a destination".to_string(),
+                amount_msat: Some(MilliSat(1_000_000)),
+            })
+            .await
+            .is_err());
+
+        let probes = breez_services.list_payment_probes().await?;
+        assert_eq!(probes.len(), 2);
+        assert_eq!(probes[0], probe);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_queued_payment() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        let req = SendPaymentRequest {
+            bolt11: invoice.bolt11.clone(),
+            use_trampoline: None,
+            amount_msat: None,
+            label: None,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: Some("payment1".to_string()),
+            skip_denylist: None,
+        };
+        assert!(!breez_services.cancel("payment1".to_string()).await?);
+
+        // Keep the payment queued behind other ones using all the slots
+        let mut permits = vec![];
+        for _ in 0..breez_services.config.max_concurrent_payments {
+            permits.push(breez_services.send_queue.acquire(None, None).await?);
+        }
+        let (res, cancelled) = tokio::join!(breez_services.send_payment(req.clone()), async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            breez_services.cancel("payment1".to_string()).await
+        });
+        assert!(cancelled?);
+        assert!(matches!(res, Err(SendPaymentError::Cancelled)));
+        // Nothing was sent or persisted, and the token can be used again
+        assert!(breez_services
+            .persister
+            .get_payment_by_hash(&invoice.payment_hash)?
+            .is_none());
+        drop(permits);
+        breez_services.send_payment(req).await?;
+
+        assert!(!breez_services.cancel_sync().await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cancel_dispatched_payment() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        *node_api.send_payment_delay.lock().unwrap() = Duration::from_millis(200);
+        let breez_services = breez_services_with(Some(node_api), None, vec![]).await?;
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        let req = SendPaymentRequest {
+            bolt11: invoice.bolt11.clone(),
+            use_trampoline: None,
+            amount_msat: None,
+            label: None,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: Some("payment1".to_string()),
+            skip_denylist: None,
+        };
+
+        let (res, cancelled) = tokio::join!(breez_services.send_payment(req), async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            breez_services.cancel("payment1".to_string()).await
+        });
+        assert!(cancelled?);
+        assert!(matches!(res, Err(SendPaymentError::Cancelled)));
+        assert!(breez_services
+            .persister
+            .get_payment_by_hash(&invoice.payment_hash)?
+            .is_some());
+        // The payment keeps its slot until the node completes it
+        assert!(matches!(
+            breez_services
+                .send_queue
+                .acquire(Some(&invoice.payee_pubkey), Some(&invoice.payment_hash))
+                .await,
+            Err(SendPaymentError::AlreadyInProgress)
+        ));
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(breez_services
+            .send_queue
+            .acquire(Some(&invoice.payee_pubkey), Some(&invoice.payment_hash))
+            .await
+            .is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_payability() -> Result<()> {
+        let breez_services = breez_services().await?;
+        breez_services.sync().await?;
+        let lsp_pubkey = MockBreezServer {}.lsp_pub_key();
+        breez_services
+            .persister
+            .set_lsp(MockBreezServer {}.lsp_id(), Some(lsp_pubkey.clone()))?;
+        let max_payable_msat = get_dummy_node_state().max_payable_msat;
+        let validate = |bolt11: &str, amount_msat: Option<u64>| {
+            breez_services.validate_payability(ValidatePayabilityRequest {
+                bolt11: bolt11.to_string(),
+                amount_msat: amount_msat.map(MilliSat),
+            })
+        };
+
+        let invoice = create_invoice("test".to_string(), max_payable_msat, vec![], None);
+        let payability = validate(&invoice.bolt11, None).await?;
+        assert!(payability.is_payable);
+        assert!(!payability.invoice.is_expired);
+        assert_eq!(
+            payability.invoice.expires_at,
+            invoice.timestamp + invoice.expiry
+        );
+        assert_eq!(payability.amount_msat, Some(max_payable_msat));
+        assert_eq!(payability.payee_inbound_liquidity_msat, None);
+        assert_eq!(payability.lsp_route_hint, None);
+
+        let invoice = create_invoice("test".to_string(), max_payable_msat + 1, vec![], None);
+        let payability = validate(&invoice.bolt11, None).await?;
+        assert!(!payability.is_payable);
+        assert!(payability.error.is_some());
+
+        // The amount can only be set for invoices without an amount
+        assert!(!validate(&invoice.bolt11, Some(10)).await?.is_payable);
+
+        // The payee can't receive more than the max HTLC amount of the LSP channel
+        let lsp_hint = RouteHint {
+            hops: vec![RouteHintHop {
+                src_node_id: lsp_pubkey,
+                short_channel_id: "1x1x1".to_string(),
+                fees_base_msat: 1_000,
+                fees_proportional_millionths: 100,
+                cltv_expiry_delta: 144,
+                htlc_minimum_msat: None,
+                htlc_maximum_msat: Some(50),
+            }],
+        };
+        let invoice = create_invoice("test".to_string(), 60, vec![lsp_hint.clone()], None);
+        let payability = validate(&invoice.bolt11, None).await?;
+        assert!(!payability.is_payable);
+        assert_eq!(payability.payee_inbound_liquidity_msat, Some(50));
+        assert_eq!(payability.lsp_route_hint, Some(lsp_hint));
+
+        assert!(validate("not an invoice", None).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_denylist() -> Result<()> {
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        let mock_rest_client = MockRestClient::new();
+        mock_rest_client.add_response(MockResponse::new(
+            200,
+            format!(r#"["{}", "scam.example.com"]"#, invoice.payee_pubkey),
+        ));
+        let config = Config {
+            denylist_url: Some("https://denylist.example.com".to_string()),
+            ..create_test_config()
+        };
+        let breez_services =
+            breez_services_with_config(config, None, Some(Arc::new(mock_rest_client)), vec![])
+                .await?;
+        assert_eq!(
+            breez_services.denied_destination(&[&invoice.payee_pubkey]),
+            None
+        );
+
+        breez_services.refresh_denylist().await?;
+        assert_eq!(
+            breez_services.denied_destination(&["pay.scam.example.com"]),
+            Some("pay.scam.example.com")
+        );
+        // A recent denylist is not fetched again
+        breez_services.refresh_denylist().await?;
+
+        let req = SendPaymentRequest {
+            bolt11: invoice.bolt11.clone(),
+            use_trampoline: None,
+            amount_msat: None,
+            label: None,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: None,
+            skip_denylist: None,
+        };
+        let res = breez_services.send_payment(req.clone()).await;
+        assert!(matches!(
+            res,
+            Err(SendPaymentError::DeniedDestination { .. })
+        ));
+        breez_services
+            .send_payment(SendPaymentRequest {
+                skip_denylist: Some(true),
+                ..req
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spend_policy() -> Result<()> {
+        let config = Config {
+            spend_policy: Some(SpendPolicy {
+                daily_limit_msat: Some(80_000),
+                per_payment_limit_msat: Some(60_000),
+                allowed_destinations: None,
+            }),
+            maxfee_percent: 1.0,
+            exemptfee_msat: 0,
+            ..create_test_config()
+        };
+        let breez_services = breez_services_with_config(config, None, None, vec![]).await?;
+        let pay = |amount_msat| {
+            let breez_services = breez_services.clone();
+            async move {
+                let invoice = create_invoice("test".to_string(), amount_msat, vec![], None);
+                breez_services
+                    .send_payment(SendPaymentRequest {
+                        bolt11: invoice.bolt11,
+                        use_trampoline: None,
+                        amount_msat: None,
+                        label: None,
+                        route_hint_index: None,
+                        payment_timeout_sec: None,
+                        maxfee_percent: None,
+                        exemptfee_msat: None,
+                        cancel_token: None,
+                        skip_denylist: None,
+                    })
+                    .await
+            }
+        };
+
+        pay(50_000).await?;
+        assert!(matches!(
+            pay(70_000).await,
+            Err(SendPaymentError::SpendPolicyViolation { .. })
+        ));
+        assert!(matches!(
+            pay(40_000).await,
+            Err(SendPaymentError::SpendPolicyViolation { .. })
+        ));
+        // The refused payments are not counted
+        let since = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64
+            - SPEND_POLICY_WINDOW_SECS;
+        assert_eq!(breez_services.persister.get_spent_since(since)?, 50_000);
+        // The max routing fee of 300 msat is over the limit
+        assert!(matches!(
+            pay(30_000).await,
+            Err(SendPaymentError::SpendPolicyViolation { .. })
+        ));
+        pay(29_000).await?;
+
+        let config = Config {
+            spend_policy: Some(SpendPolicy {
+                allowed_destinations: Some(vec!["shop.example.com".to_string()]),
+                ..Default::default()
+            }),
+            ..create_test_config()
+        };
+        let breez_services = breez_services_with_config(config, None, None, vec![]).await?;
+        let res = breez_services
+            .send_spontaneous_payment(SendSpontaneousPaymentRequest {
+                node_id: "02".repeat(33),
+                amount_msat: MilliSat(1_000),
+                extra_tlvs: None,
+                label: None,
+            })
+            .await;
+        assert!(matches!(
+            res,
+            Err(SendPaymentError::SpendPolicyViolation { .. })
+        ));
+        assert!(breez_services
+            .reserve_spend(1_000, 0, Some(&["02", "pay.shop.example.com"][..]))?
+            .is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spend_policy_fees() -> Result<()> {
+        let config = Config {
+            spend_policy: Some(SpendPolicy {
+                daily_limit_msat: Some(100_000),
+                ..Default::default()
+            }),
+            maxfee_percent: 1.0,
+            exemptfee_msat: 0,
+            partner_fee: Some(PartnerFeeConfig {
+                fee_bps: 100,
+                destination: PartnerFeeDestination::NodeId {
+                    node_id: "03".repeat(33),
+                },
+            }),
+            ..create_test_config()
+        };
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        *node_api.send_payment_fee_msat.lock().unwrap() = 100;
+        let breez_services =
+            breez_services_with_config(config, Some(node_api), None, vec![]).await?;
+        let pay = |amount_msat| {
+            let breez_services = breez_services.clone();
+            async move {
+                let invoice = create_invoice("test".to_string(), amount_msat, vec![], None);
+                breez_services
+                    .send_payment(SendPaymentRequest {
+                        bolt11: invoice.bolt11,
+                        use_trampoline: None,
+                        amount_msat: None,
+                        label: None,
+                        route_hint_index: None,
+                        payment_timeout_sec: None,
+                        maxfee_percent: None,
+                        exemptfee_msat: None,
+                        cancel_token: None,
+                        skip_denylist: None,
+                    })
+                    .await
+            }
+        };
+        let spent = || {
+            let since = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64
+                - SPEND_POLICY_WINDOW_SECS;
+            Ok::<_, anyhow::Error>(breez_services.persister.get_spent_since(since)?)
+        };
+
+        // The payment is counted with its routing fee, and the partner fee is counted too
+        let res = pay(50_000).await?;
+        assert!(res.partner_fee.unwrap().error.is_none());
+        assert_eq!(spent()?, 50_000 + 100 + 500);
+
+        // The amount fits in the limit, but not with its max routing fee of 490 msat
+        assert!(matches!(
+            pay(49_000).await,
+            Err(SendPaymentError::SpendPolicyViolation { .. })
+        ));
+        assert_eq!(spent()?, 50_600);
+
+        // The partner fee doesn't fit in the limit left by the payment and its fee
+        let res = pay(48_900).await?;
+        assert!(res.partner_fee.unwrap().error.is_some());
+        assert_eq!(spent()?, 50_600 + 48_900 + 100);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_htlc_exposure_limits() -> Result<()> {
+        let config = Config {
+            htlc_exposure_limits: Some(HtlcExposureLimits {
+                max_inflight_msat: Some(60_000),
+                max_htlcs_per_payment: None,
+            }),
+            ..create_test_config()
+        };
+        let breez_services = breez_services_with_config(config, None, None, vec![]).await?;
+        let pay = |amount_msat| {
+            let breez_services = breez_services.clone();
+            async move {
+                let invoice = create_invoice("test".to_string(), amount_msat, vec![], None);
+                breez_services
+                    .send_payment(SendPaymentRequest {
+                        bolt11: invoice.bolt11,
+                        use_trampoline: None,
+                        amount_msat: None,
+                        label: None,
+                        route_hint_index: None,
+                        payment_timeout_sec: None,
+                        maxfee_percent: None,
+                        exemptfee_msat: None,
+                        cancel_token: None,
+                        skip_denylist: None,
+                    })
+                    .await
+            }
+        };
+
+        // A stuck payment counts in flight
+        let stuck = create_invoice("stuck".to_string(), 30_000, vec![], None);
+        breez_services
+            .persist_pending_payment(&stuck, 30_000, None)
+            .await?;
+        assert!(matches!(
+            pay(40_000).await,
+            Err(SendPaymentError::HtlcExposureExceeded { .. })
+        ));
+        pay(20_000).await?;
+
+        // So does a payment being sent
+        let reservation = breez_services.reserve_htlc_exposure(20_000)?;
+        assert!(reservation.is_some());
+        assert!(matches!(
+            pay(20_000).await,
+            Err(SendPaymentError::HtlcExposureExceeded { .. })
+        ));
+        drop(reservation);
+        pay(20_000).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_metrics() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let invoice = create_invoice("test".to_string(), 50_000, vec![], None);
+        breez_services
+            .send_payment(SendPaymentRequest {
+                bolt11: invoice.bolt11,
+                use_trampoline: None,
+                amount_msat: None,
+                label: None,
+                route_hint_index: None,
+                payment_timeout_sec: None,
+                maxfee_percent: None,
+                exemptfee_msat: None,
+                cancel_token: None,
+                skip_denylist: None,
+            })
+            .await?;
+
+        let metrics = breez_services.metrics().await?;
+        let counter = |name: &str| {
+            metrics
+                .counters
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .value
+        };
+        assert_eq!(counter("breez_payments_succeeded_total"), 1);
+        assert_eq!(counter("breez_payments_failed_total"), 0);
+        assert_eq!(metrics.histograms[0].count, 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "mock-node")]
+    #[tokio::test]
+    async fn test_mock_node_config() -> Result<()> {
+        let config = Config {
+            node_config: NodeConfig::Mock {
+                config: MockNodeConfig {
+                    channels_balance_msat: 50_000_000,
+                    onchain_balance_msat: 0,
+                    inbound_liquidity_msat: 1_000_000_000,
+                },
+            },
+            ..create_test_config()
+        };
+        let persister = Arc::new(create_test_persister(config.clone()));
+        persister.init()?;
+        persister.set_lsp(MockBreezServer {}.lsp_id(), None)?;
+
+        // No node API nor backup transport, the mock node provides both
+        let mut builder = BreezServicesBuilder::new(config);
+        builder
+            .seed(vec![7; 64])?
+            .lsp_api(Arc::new(MockBreezServer {}))
+            .taproot_swapper_api(Arc::new(MockBreezServer {}))
+            .persister(persister)
+            .rest_client(Arc::new(MockRestClient::new()));
+        let breez_services = builder.build(None, None).await?;
+        breez_services.sync().await?;
+
+        let node_info = breez_services.node_info()?;
+        assert_eq!(node_info.channels_balance_msat, 50_000_000);
+        assert_eq!(node_info.max_receivable_msat, 1_000_000_000);
+        Ok(())
+    }
+
+    #[cfg(feature = "fiat")]
+    #[test]
+    fn test_fiat_rate_alert_changes() {
+        let alert = |id: i64, direction: FiatRateAlertDirection, triggered: bool| FiatRateAlert {
+            id,
+            currency: "USD".to_string(),
+            direction,
+            threshold: 30_000.0,
+            created_at: 0,
+            triggered,
+            last_triggered_at: None,
+        };
+        let rates = |value: f64| {
+            vec![Rate {
+                coin: "USD".to_string(),
+                value,
+            }]
+        };
+        let alerts = vec![
+            alert(1, FiatRateAlertDirection::Above, false),
+            alert(2, FiatRateAlertDirection::Above, true),
+            alert(3, FiatRateAlertDirection::Below, false),
+            alert(4, FiatRateAlertDirection::Below, true),
+        ];
+
+        // Above the threshold, the untriggered "above" alert triggers and the "below" one re-arms
+        let changes = fiat_rate_alert_changes(&alerts, &rates(31_000.0));
+        assert_eq!(
+            changes
+                .iter()
+                .map(|(a, rate)| (a.id, a.triggered, *rate))
+                .collect::<Vec<_>>(),
+            vec![(1, true, 31_000.0), (4, false, 31_000.0)]
+        );
+
+        let changes = fiat_rate_alert_changes(&alerts, &rates(29_000.0));
+        assert_eq!(
+            changes
+                .iter()
+                .map(|(a, _)| (a.id, a.triggered))
+                .collect::<Vec<_>>(),
+            vec![(2, false), (3, true)]
+        );
+
+        // Alerts of currencies without a fetched rate are left unchanged
+        let eur = vec![Rate {
+            coin: "EUR".to_string(),
+            value: 31_000.0,
+        }];
+        assert!(fiat_rate_alert_changes(&alerts, &eur).is_empty());
+    }
+
+    #[test]
+    fn test_pending_channel_opens() {
+        let channel = |funding_txid: &str, state| Channel {
+            funding_txid: funding_txid.to_string(),
+            short_channel_id: None,
+            state,
+            spendable_msat: 0,
+            local_balance_msat: 5_000,
+            receivable_msat: 0,
+            closed_at: None,
+            funding_outnum: None,
+            alias_local: None,
+            alias_remote: None,
+            closing_txid: None,
+            htlcs: vec![],
+        };
+        let channels = vec![
+            channel("opened", ChannelState::Opened),
+            channel("pending", ChannelState::PendingOpen),
+        ];
+        let pending = pending_channel_opens(&channels, &[], 100);
+        assert_eq!(
+            pending,
+            vec![PendingChannelOpen {
+                funding_txid: "pending".to_string(),
+                amount_msat: 5_000,
+                seen_at: 100,
+                expected_confirmation_at: 100 + CHANNEL_OPEN_EXPECTED_CONFIRMATION_SECS,
+            }]
+        );
+
+        // The channels keep the time they were first seen, and are gone once opened
+        let channels = vec![
+            channel("pending", ChannelState::PendingOpen),
+            channel("new", ChannelState::PendingOpen),
+        ];
+        let pending = pending_channel_opens(&channels, &pending, 200);
+        assert_eq!(pending[0].seen_at, 100);
+        assert_eq!(pending[1].seen_at, 200);
+        let channels = vec![channel("pending", ChannelState::Opened)];
+        assert!(pending_channel_opens(&channels, &pending, 300).is_empty());
+    }
+
+    #[test]
+    fn test_channel_hygiene_update() {
+        let window = 100;
+        let channel = |funding_txid: &str, local_balance_msat, peer_connected| ChannelDetails {
+            channel_id: format!("id-{funding_txid}"),
+            peer_id: "peer".to_string(),
+            peer_connected,
+            short_channel_id: None,
+            funding_txid: funding_txid.to_string(),
+            state: ChannelState::Opened,
+            capacity_msat: 100_000,
+            local_balance_msat,
+            remote_balance_msat: 100_000 - local_balance_msat,
+            spendable_msat: local_balance_msat,
+            receivable_msat: 100_000 - local_balance_msat,
+            reserve_msat: 1_000,
+            htlcs_out_msat: 0,
+            htlcs_in_msat: 0,
+            dust_htlcs_msat: 0,
+        };
+        let stale_txids = |stale: Vec<(&ChannelDetails, ChannelActivity)>| -> Vec<String> {
+            stale
+                .into_iter()
+                .map(|(c, _)| c.funding_txid.clone())
+                .collect()
+        };
+
+        // The channels are tracked from the first check
+        let channels = vec![
+            channel("used", 1_000, false),
+            channel("idle", 1_000, false),
+            channel("connected", 1_000, true),
+        ];
+        let (activity, stale) = channel_hygiene_update(window, 0, &channels, &HashMap::new());
+        assert!(stale.is_empty());
+        let known: HashMap<_, _> = activity
+            .into_iter()
+            .map(|a| (a.funding_txid.clone(), a))
+            .collect();
+
+        // Only the channel neither used nor connected during the window is suggested
+        let channels = vec![
+            channel("used", 2_000, false),
+            channel("idle", 1_000, false),
+            channel("connected", 1_000, true),
+        ];
+        let (activity, stale) = channel_hygiene_update(window, 100, &channels, &known);
+        assert_eq!(stale_txids(stale), vec!["idle".to_string()]);
+        let known: HashMap<_, _> = activity
+            .into_iter()
+            .map(|a| (a.funding_txid.clone(), a))
+            .collect();
+        assert_eq!(known["idle"].suggested_at, Some(100));
+        assert_eq!(known["used"].last_activity_at, 100);
+
+        // A suggested channel is only suggested again after another window, by then the used
+        // channel is idle as well
+        let (_, stale) = channel_hygiene_update(window, 150, &channels, &known);
+        assert!(stale.is_empty());
+        let (_, stale) = channel_hygiene_update(window, 200, &channels, &known);
+        assert_eq!(
+            stale_txids(stale),
+            vec!["used".to_string(), "idle".to_string()]
+        );
+
+        // The channels that are not open are not tracked
+        let pending = ChannelDetails {
+            state: ChannelState::PendingClose,
+            ..channel("closing", 1_000, false)
+        };
+        let (activity, _) = channel_hygiene_update(window, 200, &[pending], &known);
+        assert!(activity.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_balances() -> Result<()> {
+        let payment = |id: &str, status: PaymentStatus| Payment {
+            id: id.to_string(),
+            payment_type: PaymentType::Sent,
+            payment_time: 1_700_000_000,
+            amount_msat: 5_000,
+            fee_msat: 10,
+            status,
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: id.to_string(),
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        };
+        let breez_services = breez_services_with(
+            None,
+            None,
+            vec![
+                payment("1111", PaymentStatus::Pending),
+                payment("2222", PaymentStatus::Complete),
+            ],
+        )
+        .await?;
+        let node_state = get_dummy_node_state();
+        breez_services.persister.set_node_state(&node_state)?;
+
+        assert_eq!(
+            breez_services.balances()?,
+            Balances {
+                lightning_spendable_msat: node_state.max_payable_msat,
+                lightning_pending_msat: 5_010,
+                onchain_confirmed_msat: node_state.onchain_balance_msat,
+                onchain_unconfirmed_msat: node_state.pending_onchain_balance_msat,
+                swaps_locked_msat: 0,
+                reverse_swaps_locked_msat: 0,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_balance_breakdown() {
+        let channel = |funding_txid: &str, state| ChannelDetails {
+            channel_id: format!("id-{funding_txid}"),
+            peer_id: "peer".to_string(),
+            peer_connected: true,
+            short_channel_id: None,
+            funding_txid: funding_txid.to_string(),
+            state,
+            capacity_msat: 100_000,
+            local_balance_msat: 50_000,
+            remote_balance_msat: 50_000,
+            spendable_msat: 40_000,
+            receivable_msat: 45_000,
+            reserve_msat: 1_000,
+            htlcs_out_msat: 8_000,
+            htlcs_in_msat: 2_000,
+            dust_htlcs_msat: 500,
+        };
+        let balances = Balances {
+            lightning_spendable_msat: 80_000,
+            onchain_confirmed_msat: 7_000,
+            onchain_unconfirmed_msat: 3_000,
+            swaps_locked_msat: 20_000,
+            ..Default::default()
+        };
+        let breakdown = BalanceBreakdown::new(
+            vec![
+                channel("a", ChannelState::Opened),
+                channel("b", ChannelState::Opened),
+                channel("c", ChannelState::PendingClose),
+            ],
+            6_000,
+            balances,
+        );
+
+        // Only the opened channels can be spent from, but the HTLCs of all of them are in flight
+        assert_eq!(breakdown.spendable_msat, 80_000);
+        assert_eq!(breakdown.channel_reserves_msat, 2_000);
+        assert_eq!(breakdown.htlcs_out_msat, 24_000);
+        assert_eq!(breakdown.htlcs_in_msat, 6_000);
+        assert_eq!(breakdown.dust_htlcs_msat, 1_500);
+        assert_eq!(breakdown.pending_channel_opens_msat, 6_000);
+        assert_eq!(breakdown.onchain_confirmed_msat, 7_000);
+        assert_eq!(breakdown.onchain_unconfirmed_msat, 3_000);
+        assert_eq!(breakdown.swaps_locked_msat, 20_000);
+        assert_eq!(breakdown.channels.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_bump_fee() -> Result<()> {
+        let breez_services = breez_services_with(None, None, vec![]).await?;
+        let res = breez_services
+            .redeem_onchain_funds(RedeemOnchainFundsRequest {
+                to_address: "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_string(),
+                sat_per_vbyte: 2,
+                amount_sat: Some(Sat(10_000)),
+            })
+            .await?;
+        let txid = hex::encode(res.txid);
+
+        // The fee rate must increase
+        let bump = |txid: &str, sat_per_vbyte| {
+            breez_services.bump_fee(BumpFeeRequest {
+                txid: txid.to_string(),
+                sat_per_vbyte,
+            })
+        };
+        assert!(bump(&txid, 2).await.is_err());
+        let replacement_txid = bump(&txid, 5).await?.txid;
+        assert_ne!(replacement_txid, txid);
+
+        // Only the replacement can be bumped again
+        assert!(bump(&txid, 10).await.is_err());
+        let redeem_tx = breez_services
+            .persister
+            .get_redeem_tx(&replacement_txid)?
+            .unwrap();
+        assert_eq!(redeem_tx.amount_sat, Some(10_000));
+        assert_eq!(redeem_tx.sat_per_vbyte, 5);
+        bump(&replacement_txid, 10).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_partner_fee() -> Result<()> {
+        let mut config = create_test_config();
+        config.partner_fee = Some(PartnerFeeConfig {
+            fee_bps: 50,
+            destination: PartnerFeeDestination::NodeId {
+                node_id: "03".repeat(33),
             },
-            Payment {
-                id: payment_hash_with_lnurl_success_action.to_string(),
-                payment_type: PaymentType::Sent,
-                payment_time: 200000,
-                amount_msat: 8,
-                fee_msat: 2,
-                status: PaymentStatus::Complete,
-                error: None,
-                description: Some("test payment".to_string()),
-                details: PaymentDetails::Ln {
-                    data: LnPaymentDetails {
-                        payment_hash: payment_hash_with_lnurl_success_action.to_string(),
-                        label: "".to_string(),
-                        destination_pubkey: "123".to_string(),
-                        payment_preimage: "4444".to_string(),
-                        keysend: false,
-                        bolt11: "123".to_string(),
-                        lnurl_success_action: Some(sa.clone()),
-                        lnurl_pay_domain: None,
-                        lnurl_pay_comment: None,
-                        lnurl_metadata: Some(lnurl_metadata.to_string()),
-                        ln_address: Some(test_ln_address.to_string()),
-                        lnurl_withdraw_endpoint: None,
-                        swap_info: None,
-                        reverse_swap_info: None,
-                        pending_expiration_block: None,
-                        open_channel_bolt11: None,
-                    },
+        });
+        let breez_services = breez_services_with_config(config, None, None, vec![]).await?;
+
+        let res = breez_services
+            .send_spontaneous_payment(SendSpontaneousPaymentRequest {
+                node_id: "02".repeat(33),
+                amount_msat: MilliSat(1_000_000),
+                extra_tlvs: None,
+                label: None,
+            })
+            .await?;
+        let partner_fee = res.partner_fee.unwrap();
+        assert_eq!(partner_fee.amount_msat, 5_000);
+        assert!(partner_fee.error.is_none());
+        let fee_payment_id = partner_fee.payment_id.unwrap();
+        assert_ne!(fee_payment_id, res.payment.id);
+        assert!(breez_services
+            .persister
+            .get_payment_by_hash(&fee_payment_id)?
+            .is_some());
+
+        // No fee is paid when it rounds down to zero
+        let res = breez_services
+            .send_spontaneous_payment(SendSpontaneousPaymentRequest {
+                node_id: "02".repeat(33),
+                amount_msat: MilliSat(100),
+                extra_tlvs: None,
+                label: None,
+            })
+            .await?;
+        assert!(res.partner_fee.is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_send_payments() -> Result<()> {
+        let breez_services = breez_services_with(None, None, vec![]).await?;
+        let mut progress_events = Box::pin(
+            breez_services
+                .subscribe_filtered(|e| matches!(e, BreezEvent::BatchPaymentProgress { .. })),
+        );
+        let payment_req = |bolt11: String| SendPaymentRequest {
+            bolt11,
+            use_trampoline: None,
+            amount_msat: None,
+            label: None,
+            route_hint_index: None,
+            payment_timeout_sec: None,
+            maxfee_percent: None,
+            exemptfee_msat: None,
+            cancel_token: None,
+            skip_denylist: None,
+        };
+
+        let res = breez_services
+            .send_payments(SendPaymentsRequest {
+                payments: vec![
+                    payment_req(create_invoice("1".into(), 10_000, vec![], None).bolt11),
+                    payment_req("invalid".into()),
+                    payment_req(create_invoice("2".into(), 20_000, vec![], None).bolt11),
+                ],
+                max_concurrency: Some(2),
+            })
+            .await?;
+        assert_eq!((res.succeeded, res.failed), (2, 1));
+        let indexes: Vec<u32> = res.results.iter().map(|r| r.index).collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+        assert!(res.results[0].payment.is_some());
+        assert!(res.results[1].error.is_some());
+        assert_eq!(res.results[2].payment.as_ref().unwrap().amount_msat, 20_000);
+
+        for finished in 1..=3 {
+            match progress_events.next().await {
+                Some(BreezEvent::BatchPaymentProgress { details }) => {
+                    assert_eq!(details.succeeded + details.failed, finished);
+                    assert_eq!(details.total, 3);
+                }
+                e => panic!("Unexpected event: {e:?}"),
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_queue_payment() -> Result<()> {
+        let breez_services = breez_services_with(None, None, vec![]).await?;
+        let invoice = create_invoice("test".into(), 10_000, vec![], None);
+        let outbox_payment = breez_services
+            .queue_payment(QueuePaymentRequest {
+                req: SendPaymentRequest {
+                    bolt11: invoice.bolt11,
+                    use_trampoline: None,
+                    amount_msat: None,
+                    label: None,
+                    route_hint_index: None,
+                    payment_timeout_sec: None,
+                    maxfee_percent: None,
+                    exemptfee_msat: None,
+                    cancel_token: None,
+                    skip_denylist: None,
                 },
-                metadata: None,
+                expiry_secs: Some(60),
+            })
+            .await?;
+        assert_eq!(outbox_payment.expires_at, outbox_payment.created_at + 60);
+        assert_eq!(breez_services.list_pending_outbox().await?.len(), 1);
+
+        // An invalid invoice is not queued
+        assert!(breez_services
+            .queue_payment(QueuePaymentRequest {
+                req: SendPaymentRequest {
+                    bolt11: "invalid".into(),
+                    use_trampoline: None,
+                    amount_msat: None,
+                    label: None,
+                    route_hint_index: None,
+                    payment_timeout_sec: None,
+                    maxfee_percent: None,
+                    exemptfee_msat: None,
+                    cancel_token: None,
+                    skip_denylist: None,
+                },
+                expiry_secs: None,
+            })
+            .await
+            .is_err());
+
+        // The payment succeeds on the first attempt and leaves the outbox
+        breez_services.process_outbox(false).await?;
+        assert!(breez_services.list_pending_outbox().await?.is_empty());
+        assert!(breez_services
+            .cancel_outbox_payment(outbox_payment.id)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_outbox_retry_delay() {
+        assert_eq!(outbox_retry_delay_secs(1), OUTBOX_MIN_RETRY_DELAY_SECS);
+        assert_eq!(outbox_retry_delay_secs(3), 4 * OUTBOX_MIN_RETRY_DELAY_SECS);
+        assert_eq!(outbox_retry_delay_secs(100), OUTBOX_MAX_RETRY_DELAY_SECS);
+    }
+
+    #[test]
+    fn test_fiat_to_msat() {
+        assert_eq!(fiat_to_msat(20.0, 20_000.0), Some(100_000_000));
+        assert_eq!(fiat_to_msat(0.01, 40_000.0), Some(25_000));
+        assert_eq!(fiat_to_msat(0.0, 20_000.0), None);
+        assert_eq!(fiat_to_msat(-1.0, 20_000.0), None);
+        assert_eq!(fiat_to_msat(f64::NAN, 20_000.0), None);
+        assert_eq!(fiat_to_msat(1.0, 0.0), None);
+    }
+
+    #[cfg(feature = "fiat")]
+    #[tokio::test]
+    async fn test_resolve_fiat_amount() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let (req, rate) = breez_services
+            .resolve_fiat_amount(ReceivePaymentRequest {
+                amount_msat: MilliSat(1_000),
+                fiat_amount: Some(FiatAmount {
+                    currency: "USD".into(),
+                    amount: 5.0,
+                }),
+                ..Default::default()
+            })
+            .await?;
+        assert_eq!(req.amount_msat, MilliSat(25_000_000));
+        assert_eq!(rate.map(|r| r.value), Some(20_000.0));
+
+        let res = breez_services
+            .resolve_fiat_amount(ReceivePaymentRequest {
+                fiat_amount: Some(FiatAmount {
+                    currency: "EUR".into(),
+                    amount: 5.0,
+                }),
+                ..Default::default()
+            })
+            .await;
+        assert!(matches!(res, Err(ReceivePaymentError::Generic { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        for _ in 0..100 {
+            let delay_ms = reconnect_delay_ms(1);
+            assert!((RECONNECT_MIN_DELAY_MS / 2..=RECONNECT_MIN_DELAY_MS).contains(&delay_ms));
+            let delay_ms = reconnect_delay_ms(3);
+            assert!((2 * RECONNECT_MIN_DELAY_MS..=4 * RECONNECT_MIN_DELAY_MS).contains(&delay_ms));
+            let delay_ms = reconnect_delay_ms(100);
+            assert!((RECONNECT_MAX_DELAY_MS / 2..=RECONNECT_MAX_DELAY_MS).contains(&delay_ms));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_ephemeral_mainnet() {
+        struct NoopEventListener {}
+        impl EventListener for NoopEventListener {
+            fn on_event(&self, _e: BreezEvent) {}
+        }
+
+        let config = create_test_config();
+        assert_eq!(config.network, Network::Bitcoin);
+        let res = BreezServices::connect_ephemeral(config, Box::new(NoopEventListener {})).await;
+        assert!(matches!(res, Err(ConnectError::Generic { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_connection_status() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let status = breez_services.connection_status().await?;
+        assert_eq!(status.state, ConnectionState::Connected);
+        assert!(status.since > 0);
+
+        breez_services
+            .set_connection_status(ConnectionState::Reconnecting, 2, Some("timeout".into()))
+            .await;
+        let status = breez_services.connection_status().await?;
+        assert_eq!(status.state, ConnectionState::Reconnecting);
+        assert_eq!(status.reconnect_attempts, 2);
+        assert_eq!(status.last_error, Some("timeout".into()));
+
+        breez_services.check_connection().await?;
+        breez_services
+            .set_connection_status(ConnectionState::Connected, 0, None)
+            .await;
+        let status = breez_services.connection_status().await?;
+        assert_eq!(status.state, ConnectionState::Connected);
+        assert_eq!(status.last_error, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_restore_connection() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
+        breez_services.sync().await?;
+        let attempts = breez_services.lsp_reconnections.lock().await.attempts;
+
+        // The LSP peer can't be reached until it's connected again
+        *node_api.peers_offline.lock().unwrap() = true;
+        let Err(e) = breez_services.check_connection().await else {
+            return Err(anyhow!("The connection check should fail"));
+        };
+
+        let mut events = breez_services.event_sender.subscribe();
+        let mut shutdown_receiver = breez_services.shutdown_sender.subscribe();
+        assert!(
+            breez_services
+                .restore_connection(e.to_string(), &mut shutdown_receiver)
+                .await
+        );
+
+        let mut connection_events = vec![];
+        while let Ok(event) = events.try_recv() {
+            if matches!(
+                event,
+                BreezEvent::Disconnected { .. }
+                    | BreezEvent::Reconnecting { .. }
+                    | BreezEvent::Connected
+            ) {
+                connection_events.push(event);
+            }
+        }
+        assert_eq!(connection_events.len(), 3);
+        assert!(matches!(
+            &connection_events[0],
+            BreezEvent::Disconnected { error } if error == &e.to_string()
+        ));
+        assert!(matches!(
+            connection_events[1],
+            BreezEvent::Reconnecting { attempt: 1, .. }
+        ));
+        assert!(matches!(connection_events[2], BreezEvent::Connected));
+
+        let status = breez_services.connection_status().await?;
+        assert_eq!(status.state, ConnectionState::Connected);
+        assert_eq!(status.reconnect_attempts, 0);
+        assert!(!*node_api.peers_offline.lock().unwrap());
+        assert_eq!(
+            breez_services.lsp_reconnections.lock().await.attempts,
+            attempts + 1
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_payment_metadata_filters() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let order = node_api.add_dummy_payment_rand().await?;
+        let tagged = node_api.add_dummy_payment_rand().await?;
+        let breez_services = breez_services_with(Some(node_api), None, vec![]).await?;
+        breez_services.sync().await?;
+
+        breez_services
+            .set_payment_metadata(order.id.clone(), r#"{"orderId":"42"}"#.to_string())
+            .await?;
+        breez_services
+            .set_payment_metadata(tagged.id.clone(), r#"{"customer":"bob"}"#.to_string())
+            .await?;
+        assert!(breez_services
+            .set_payment_metadata(order.id.clone(), "not json".to_string())
+            .await
+            .is_err());
+
+        let filtered = |json_path: &str, json_value: Option<&str>| {
+            breez_services.list_payments(ListPaymentsRequest {
+                metadata_filters: Some(vec![MetadataFilter {
+                    json_path: json_path.to_string(),
+                    json_value: json_value.map(str::to_string),
+                }]),
+                ..Default::default()
+            })
+        };
+        let ids = |payments: Vec<Payment>| payments.into_iter().map(|p| p.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(filtered("orderId", None).await?),
+            vec![order.id.clone()]
+        );
+        assert_eq!(
+            ids(filtered("orderId", Some(r#""42""#)).await?),
+            vec![order.id.clone()]
+        );
+        assert!(filtered("orderId", Some(r#""43""#)).await?.is_empty());
+        assert_eq!(ids(filtered("customer", None).await?), vec![tagged.id]);
+        assert!(filtered("label", None).await?.is_empty());
+
+        let payment = breez_services
+            .payment_by_hash(order.id)
+            .await?
+            .ok_or(anyhow!("Payment not found"))?;
+        assert_eq!(payment.metadata, Some(r#"{"orderId":"42"}"#.to_string()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_webhook_registration() -> Result<()> {
+        let lsp_api = Arc::new(MockNotificationLspAPI::default());
+        let breez_services = breez_services_with_mocks(
+            create_test_config(),
+            TestMocks {
+                lsp_api: Some(lsp_api.clone()),
+                ..Default::default()
             },
-            Payment {
-                id: hex::encode(payment_hash_swap.clone()),
-                payment_type: PaymentType::Received,
-                payment_time: 250000,
-                amount_msat: 1_000,
-                fee_msat: 0,
-                status: PaymentStatus::Complete,
-                error: None,
-                description: Some("test receive".to_string()),
-                details: PaymentDetails::Ln {
-                    data: LnPaymentDetails {
-                        payment_hash: hex::encode(payment_hash_swap),
-                        label: "".to_string(),
-                        destination_pubkey: "321".to_string(),
-                        payment_preimage: "5555".to_string(),
-                        keysend: false,
-                        bolt11: "312".to_string(),
-                        lnurl_success_action: None,
-                        lnurl_pay_domain: None,
-                        lnurl_pay_comment: None,
-                        lnurl_metadata: None,
-                        ln_address: None,
-                        lnurl_withdraw_endpoint: None,
-                        swap_info: Some(swap_info.clone()),
-                        reverse_swap_info: None,
-                        pending_expiration_block: None,
-                        open_channel_bolt11: None,
-                    },
+        )
+        .await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        // Nothing is registered without a persisted webhook
+        breez_services.refresh_webhook_registration().await?;
+        assert!(lsp_api.registered_webhooks.lock().unwrap().is_empty());
+
+        let webhook_url = "https://example.com/webhook".to_string();
+        breez_services.register_webhook(webhook_url.clone()).await?;
+        lsp_api.registered_webhooks.lock().unwrap().clear();
+        breez_services.refresh_webhook_registration().await?;
+        assert_eq!(
+            *lsp_api.registered_webhooks.lock().unwrap(),
+            vec![webhook_url]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_initial_sync_progress() -> Result<()> {
+        struct RecordingEventListener {
+            events: Arc<std::sync::Mutex<Vec<BreezEvent>>>,
+        }
+        impl EventListener for RecordingEventListener {
+            fn on_event(&self, e: BreezEvent) {
+                self.events.lock().unwrap().push(e);
+            }
+        }
+
+        let events = Arc::new(std::sync::Mutex::new(vec![]));
+        let listener = RecordingEventListener {
+            events: events.clone(),
+        };
+        let breez_services = breez_services_with_mocks(
+            create_test_config(),
+            TestMocks {
+                event_listener: Some(Box::new(listener)),
+                ..Default::default()
+            },
+        )
+        .await?;
+        let progress = |events: &Arc<std::sync::Mutex<Vec<BreezEvent>>>| -> Vec<u8> {
+            events
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(|e| match e {
+                    BreezEvent::ConnectProgress { details } => {
+                        assert_eq!(details.stage, ConnectStage::InitialSync);
+                        Some(details.progress_percent)
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        breez_services.initial_sync().await?;
+        let percents = progress(&events);
+        assert!(percents.len() > 2);
+        assert_eq!(percents.first(), Some(&70));
+        assert!(percents.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(percents.last(), Some(&100));
+
+        // A regular sync does not report any connect progress
+        events.lock().unwrap().clear();
+        breez_services.sync().await?;
+        assert!(progress(&events).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_initial_sync_percent() {
+        assert_eq!(ConnectStage::initial_sync_percent(0, 4), 70);
+        assert_eq!(ConnectStage::initial_sync_percent(2, 4), 85);
+        assert_eq!(ConnectStage::initial_sync_percent(4, 4), 100);
+        assert_eq!(ConnectStage::initial_sync_percent(5, 4), 100);
+        assert_eq!(ConnectStage::initial_sync_percent(0, 0), 70);
+    }
+
+    #[tokio::test]
+    async fn test_send_spontaneous_payment_insufficient_balance() -> Result<()> {
+        let max_amounts: Vec<MaxChannelAmount> = [30_000, 40_000]
+            .into_iter()
+            .enumerate()
+            .map(|(i, amount_msat)| MaxChannelAmount {
+                channel_id: format!("chan{i}"),
+                amount_msat,
+                path: PaymentPath { edges: vec![] },
+            })
+            .collect();
+        let mut node_api = crate::node_api::MockNodeAPI::new();
+        node_api
+            .expect_derive_symmetric_key()
+            .returning(|_| Ok([0; 32]));
+        node_api
+            .expect_legacy_derive_symmetric_key()
+            .returning(|_| Ok([0; 32]));
+        node_api
+            .expect_max_sendable_amount()
+            .returning(move |_, _, _| Ok(max_amounts.clone()));
+        node_api.expect_send_spontaneous_payment().never();
+        let breez_services = breez_services_with(Some(Arc::new(node_api)), None, vec![]).await?;
+
+        // Keysend payments are not split, so only the largest channel counts
+        let res = breez_services
+            .send_spontaneous_payment(SendSpontaneousPaymentRequest {
+                node_id: "02".repeat(33),
+                amount_msat: MilliSat(50_000),
+                extra_tlvs: None,
+                label: None,
+            })
+            .await;
+        assert!(matches!(
+            res,
+            Err(SendPaymentError::InsufficientBalance {
+                max_sendable_msat: Some(40_000),
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_derive_encryption_key() -> Result<()> {
+        let breez_services = breez_services_with(None, None, vec![]).await?;
+        let derive = |purpose: &str, index| {
+            breez_services.derive_encryption_key(DeriveEncryptionKeyRequest {
+                purpose: purpose.to_string(),
+                index,
+            })
+        };
+
+        let key = derive("contacts", 0).await?.key;
+        assert_eq!(key.len(), 32);
+        assert_eq!(derive("contacts", 0).await?.key, key);
+        assert_ne!(derive("notes", 0).await?.key, key);
+        assert!(derive("contacts", 1 << 31).await.is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "lnurl")]
+    #[tokio::test]
+    async fn test_lnurl_auth_checks() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+        for _ in 0..LNURL_AUTH_MAX_ATTEMPTS_PER_WINDOW {
+            mock_rest_client.add_response(MockResponse::new(200, r#"{"status":"OK"}"#.to_string()));
+        }
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+        let breez_services = breez_services_with(None, Some(rest_client), vec![]).await?;
+
+        let auth_request = |domain: &str, url: &str, k1: u8| LnUrlAuthRequestData {
+            k1: hex::encode([k1; 32]),
+            action: Some("login".to_string()),
+            domain: domain.to_string(),
+            url: format!("{url}?tag=login&k1={}", hex::encode([k1; 32])),
+        };
+
+        // The callback must be on the domain shown to the user, over https
+        let res = breez_services
+            .lnurl_auth(auth_request("service.com", "https://other.com/auth", 0))
+            .await;
+        assert!(matches!(res, Err(LnUrlAuthError::OriginMismatch { .. })));
+        let res = breez_services
+            .lnurl_auth(auth_request("service.com", "http://service.com/auth", 0))
+            .await;
+        assert!(matches!(res, Err(LnUrlAuthError::OriginMismatch { .. })));
+
+        let res = breez_services
+            .lnurl_auth(auth_request("service.com", "https://service.com/auth", 1))
+            .await?;
+        assert!(matches!(res, LnUrlCallbackStatus::Ok));
+        let res = breez_services
+            .lnurl_auth(auth_request("service.com", "https://service.com/auth", 1))
+            .await;
+        assert!(matches!(res, Err(LnUrlAuthError::K1Reused { .. })));
+
+        for k1 in 2..=LNURL_AUTH_MAX_ATTEMPTS_PER_WINDOW as u8 {
+            breez_services
+                .lnurl_auth(auth_request("service.com", "https://service.com/auth", k1))
+                .await?;
+        }
+        let res = breez_services
+            .lnurl_auth(auth_request("service.com", "https://service.com/auth", 10))
+            .await;
+        assert!(matches!(res, Err(LnUrlAuthError::RateLimited { .. })));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "lnurl")]
+    #[tokio::test]
+    async fn test_lnurl_auth_domains() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+        mock_rest_client.add_response(MockResponse::new(200, r#"{"status":"OK"}"#.to_string()));
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+        let breez_services = breez_services_with(None, Some(rest_client), vec![]).await?;
+
+        breez_services
+            .lnurl_auth(LnUrlAuthRequestData {
+                k1: hex::encode([1; 32]),
+                action: Some("login".to_string()),
+                domain: "Service.com".to_string(),
+                url: format!(
+                    "https://service.com/auth?tag=login&k1={}",
+                    hex::encode([1; 32])
+                ),
+            })
+            .await?;
+        let domains = breez_services.list_lnurl_auth_domains()?;
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[0].domain, "service.com");
+        assert_eq!(domains[0].auth_count, 1);
+        assert_eq!(
+            domains[0].linking_key,
+            breez_services
+                .lnurl_auth_linking_key("service.com".into())
+                .await?
+        );
+
+        // The history can only be imported by the same identity
+        let export = breez_services.export_lnurl_auth().await?;
+        assert_eq!(export.domains, domains);
+        breez_services.import_lnurl_auth(export.clone()).await?;
+        assert_eq!(breez_services.list_lnurl_auth_domains()?, domains);
+        let mut other_identity = export.clone();
+        other_identity.hashing_key = hex::encode([2; 33]);
+        assert!(breez_services
+            .import_lnurl_auth(other_identity)
+            .await
+            .is_err());
+        let mut tampered = export;
+        tampered.domains[0].linking_key = hex::encode([2; 33]);
+        assert!(breez_services.import_lnurl_auth(tampered).await.is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "lnurl")]
+    #[tokio::test]
+    async fn test_process_pending_lnurl_pays() -> Result<()> {
+        let preimage = [7; 32];
+        let iv = [1; 16];
+        let payment = |hash: &str, status: PaymentStatus| Payment {
+            id: hash.to_string(),
+            payment_type: PaymentType::Sent,
+            payment_time: 100000,
+            amount_msat: 1000,
+            fee_msat: 0,
+            status,
+            error: None,
+            description: None,
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: hash.to_string(),
+                    payment_preimage: hex::encode(preimage),
+                    ..Default::default()
                 },
-                metadata: None,
             },
-            Payment {
-                id: hex::encode(payment_hash_rev_swap.clone()),
-                payment_type: PaymentType::Sent,
-                payment_time: 300000,
-                amount_msat: 50_000_000,
-                fee_msat: 2_000,
-                status: PaymentStatus::Complete,
-                error: None,
-                description: Some("test send onchain".to_string()),
-                details: PaymentDetails::Ln {
-                    data: LnPaymentDetails {
-                        payment_hash: hex::encode(payment_hash_rev_swap),
-                        label: "".to_string(),
-                        destination_pubkey: "321".to_string(),
-                        payment_preimage: hex::encode(preimage_rev_swap),
-                        keysend: false,
-                        bolt11: "312".to_string(),
-                        lnurl_success_action: None,
-                        lnurl_metadata: None,
-                        lnurl_pay_domain: None,
-                        lnurl_pay_comment: None,
-                        ln_address: None,
-                        lnurl_withdraw_endpoint: None,
-                        swap_info: None,
-                        reverse_swap_info: Some(rev_swap_info.clone()),
-                        pending_expiration_block: None,
-                        open_channel_bolt11: None,
-                    },
-                },
-                metadata: None,
+            metadata: None,
+            fiat_rate: None,
+            imported: false,
+        };
+        let breez_services = breez_services_with(
+            None,
+            None,
+            vec![
+                payment("1111", PaymentStatus::Complete),
+                payment("2222", PaymentStatus::Failed),
+                payment("3333", PaymentStatus::Pending),
+            ],
+        )
+        .await?;
+
+        // The app was stopped while paying, the LNURL-pays were kept pending
+        let success_action = SuccessAction::Aes {
+            data: AesSuccessActionData {
+                description: "Voucher".to_string(),
+                ciphertext: AesSuccessActionData::encrypt(&preimage, &iv, "code-123".into())?,
+                iv: base64::encode(iv),
             },
+        };
+        for hash in ["1111", "2222", "3333", "4444"] {
+            breez_services
+                .persister
+                .insert_pending_lnurl_pay(&PendingLnurlPay {
+                    payment_hash: hash.to_string(),
+                    success_action: Some(success_action.clone()),
+                    external_info: PaymentExternalInfo {
+                        lnurl_pay_domain: Some("service.com".to_string()),
+                        ..Default::default()
+                    },
+                    created_at: 0,
+                })?;
+        }
+        breez_services.process_pending_lnurl_pays()?;
+
+        let ln_details = |payment: Option<Payment>| match payment {
+            Some(Payment {
+                details: PaymentDetails::Ln { data },
+                ..
+            }) => data,
+            _ => panic!("The payment was not found"),
+        };
+        let data = ln_details(breez_services.payment_by_hash("1111".into()).await?);
+        assert_eq!(data.lnurl_pay_domain, Some("service.com".to_string()));
+        assert_eq!(
+            data.lnurl_success_action,
+            Some(SuccessActionProcessed::Aes {
+                result: AesSuccessActionDataResult::Decrypted {
+                    data: AesSuccessActionDataDecrypted {
+                        description: "Voucher".to_string(),
+                        plaintext: "code-123".to_string(),
+                    }
+                }
+            })
+        );
+
+        // The details of the failed payment are kept, without a success action
+        let data = ln_details(breez_services.payment_by_hash("2222".into()).await?);
+        assert_eq!(data.lnurl_pay_domain, Some("service.com".to_string()));
+        assert_eq!(data.lnurl_success_action, None);
+
+        // Only the pending payment still waits, the expired unknown payment is dropped
+        let pending: Vec<String> = breez_services
+            .persister
+            .list_pending_lnurl_pays()?
+            .into_iter()
+            .map(|p| p.payment_hash)
+            .collect();
+        assert_eq!(pending, vec!["3333".to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prove_address_ownership() -> Result<()> {
+        let breez_services = breez_services_with(None, None, vec![]).await?;
+        let secp = Secp256k1::new();
+        let pubkey = test_master_key()
+            .derive_xpub(&[
+                ChildNumber::from(0),
+                ChildNumber::from(0),
+                ChildNumber::from(3),
+            ])?
+            .public_key;
+        let prove = |address: String| {
+            breez_services.prove_address_ownership(ProveAddressOwnershipRequest {
+                address,
+                message: "I own this address".into(),
+            })
+        };
+
+        for script in onchain_wallet_scripts(&secp, &pubkey, Network::Bitcoin)? {
+            let address =
+                crate::bitcoin::Address::from_script(&script, crate::bitcoin::Network::Bitcoin)
+                    .unwrap();
+            assert!(!prove(address.to_string()).await?.signature.is_empty());
+        }
+
+        // Addresses of other wallets, networks or script types
+        assert!(prove("bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".into())
+            .await
+            .is_err());
+        assert!(prove("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".into())
+            .await
+            .is_err());
+        assert!(prove("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa".into())
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_offer() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let offer = LNOffer {
+            offer: "lno1".to_string(),
+            chains: vec![offer_chain(Network::Bitcoin)],
+            min_amount: Some(Amount::Bitcoin { amount_msat: 1_000 }),
+            description: None,
+            absolute_expiry: None,
+            issuer: None,
+            signing_pubkey: None,
+            paths: vec![],
+        };
+        assert!(breez_services.validate_offer(&offer, None).is_ok());
+        assert!(matches!(
+            breez_services.validate_offer(&offer, Some(999)),
+            Err(SendPaymentError::InvalidAmount { .. })
+        ));
+
+        // An offer for another network is rejected before asking its issuer for an invoice
+        let testnet_offer = LNOffer {
+            chains: vec![offer_chain(Network::Testnet)],
+            ..offer.clone()
+        };
+        assert!(matches!(
+            breez_services.validate_offer(&testnet_offer, None),
+            Err(SendPaymentError::InvalidNetwork { .. })
+        ));
+
+        let expired_offer = LNOffer {
+            absolute_expiry: Some(1),
+            ..offer
+        };
+        assert!(matches!(
+            breez_services.validate_offer(&expired_offer, None),
+            Err(SendPaymentError::InvoiceExpired { .. })
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recovery_bundle() -> Result<()> {
+        let breez_services = breez_services_with(None, None, vec![]).await?;
+        breez_services.sync().await?;
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[7; 32])?;
+        let file_path = format!("{}/recovery_bundle", get_test_working_dir());
+        let decrypt = |bundle| {
+            BreezServices::decrypt_recovery_bundle(DecryptRecoveryBundleRequest {
+                bundle,
+                private_key: secret_key.secret_bytes().to_vec(),
+            })
+        };
+
+        let res = breez_services
+            .export_recovery_bundle(ExportRecoveryBundleRequest {
+                recipient_pubkey: secret_key.public_key(&secp).to_string(),
+                instructions: Some("Ask Bob for the seed".into()),
+                file_path: Some(file_path.clone()),
+            })
+            .await?;
+        let bundle = decrypt(res.bundle.clone())?;
+        assert_eq!(bundle.node_id, breez_services.node_info()?.id);
+        assert!(bundle.instructions.ends_with("Ask Bob for the seed"));
+        assert_eq!(std::fs::read(&file_path)?, res.bundle);
+
+        // Only the recipient can decrypt the bundle
+        assert!(
+            BreezServices::decrypt_recovery_bundle(DecryptRecoveryBundleRequest {
+                bundle: res.bundle,
+                private_key: vec![8; 32],
+            })
+            .is_err()
+        );
+
+        // The file is refreshed with the new static backup
+        breez_services.refresh_recovery_bundle(vec!["backup".into()])?;
+        let bundle = decrypt(std::fs::read(&file_path)?)?;
+        assert_eq!(bundle.static_backup, vec!["backup".to_string()]);
+
+        breez_services.stop_recovery_bundle_refresh()?;
+        std::fs::remove_file(&file_path)?;
+        breez_services.refresh_recovery_bundle(vec![])?;
+        assert!(!std::path::Path::new(&file_path).exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_close_channel() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let channel = mock_channel(&MockBreezServer {}.lsp_pub_key(), 1_000_000);
+        node_api.channels.lock().unwrap().push(channel.clone());
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
+
+        assert!(breez_services
+            .close_channel(CloseChannelRequest {
+                channel_id: "unknown".to_string(),
+                force: false,
+            })
+            .await
+            .is_err());
+        assert!(node_api.closed_channels.lock().unwrap().is_empty());
+
+        for force in [false, true] {
+            let res = breez_services
+                .close_channel(CloseChannelRequest {
+                    channel_id: channel.channel_id.clone(),
+                    force,
+                })
+                .await?;
+            assert!(res.closing_txid.is_some());
+            assert_eq!(
+                node_api.closed_channels.lock().unwrap().last(),
+                Some(&(channel.channel_id.clone(), force))
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_export_node_migration() -> Result<()> {
+        let lsp_pubkey = MockBreezServer {}.lsp_pub_key();
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        node_api
+            .channels
+            .lock()
+            .unwrap()
+            .push(mock_channel(&lsp_pubkey, 1_000_000));
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
+
+        // Without closing the channels
+        let state = breez_services
+            .export_node_migration(NodeMigrationRequest {
+                close_channels: false,
+            })
+            .await?;
+        assert_eq!(state.node_id, breez_services.node_info()?.id);
+        assert_eq!(state.lsp_node_uri, Some(format!("{lsp_pubkey}@localhost")));
+        assert_eq!(state.channels.len(), 1);
+        assert_eq!(state.channels[0].peer_id, Some(lsp_pubkey.clone()));
+        assert_eq!(state.channels[0].capacity_msat, Some(1_000_000));
+        assert!(state.closing_txids.is_empty());
+
+        // Closing the LSP channels
+        let state = breez_services
+            .export_node_migration(NodeMigrationRequest {
+                close_channels: true,
+            })
+            .await?;
+        assert_eq!(state.channels.len(), 1);
+        assert_eq!(state.closing_txids.len(), 1);
+
+        // Nothing to close without channels
+        node_api.channels.lock().unwrap().clear();
+        let state = breez_services
+            .export_node_migration(NodeMigrationRequest {
+                close_channels: true,
+            })
+            .await?;
+        assert!(state.channels.is_empty());
+        assert!(state.closing_txids.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_limit_and_extract_lsp_hint() -> Result<()> {
+        let breez_server = MockBreezServer {};
+        let lsp_info = breez_server.list_lsps("".into()).await?.remove(0);
+        let hint = |src_node_id: &str, htlc_maximum_msat: Option<u64>| RouteHint {
+            hops: vec![RouteHintHop {
+                src_node_id: src_node_id.into(),
+                htlc_maximum_msat,
+                ..Default::default()
+            }],
+        };
+
+        let mut hints = vec![
+            hint("small", Some(1_000)),
+            hint(&lsp_info.pubkey, Some(10)),
+            hint("unknown", None),
+            hint("large", Some(100_000)),
+            hint("medium", Some(50_000)),
         ];
-        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+        let lsp_hint = PaymentReceiver::limit_and_extract_lsp_hint(&mut hints, &lsp_info, 2);
+        assert_eq!(lsp_hint, Some(hint(&lsp_info.pubkey, Some(10))));
+        assert_eq!(
+            hints,
+            vec![hint("large", Some(100_000)), hint("medium", Some(50_000))]
+        );
+        Ok(())
+    }
 
-        let test_config = create_test_config();
-        let persister = Arc::new(create_test_persister(test_config.clone()));
-        persister.init()?;
-        persister.insert_or_update_payments(&dummy_transactions, false)?;
-        persister.insert_payment_external_info(
-            payment_hash_with_lnurl_success_action,
-            PaymentExternalInfo {
-                lnurl_pay_success_action: Some(sa.clone()),
-                lnurl_pay_domain: None,
-                lnurl_pay_comment: None,
-                lnurl_metadata: Some(lnurl_metadata.to_string()),
-                ln_address: Some(test_ln_address.to_string()),
-                lnurl_withdraw_endpoint: None,
-                attempted_amount_msat: None,
-                attempted_error: None,
-            },
-        )?;
-        persister.insert_payment_external_info(
-            payment_hash_lnurl_withdraw,
-            PaymentExternalInfo {
-                lnurl_pay_success_action: None,
-                lnurl_pay_domain: None,
-                lnurl_pay_comment: None,
-                lnurl_metadata: None,
-                ln_address: None,
-                lnurl_withdraw_endpoint: Some(test_lnurl_withdraw_endpoint.to_string()),
-                attempted_amount_msat: None,
-                attempted_error: None,
-            },
-        )?;
-        persister.insert_swap(&swap_info)?;
-        persister.update_swap_bolt11(
-            swap_info.bitcoin_address.clone(),
-            swap_info.bolt11.clone().unwrap(),
-        )?;
-        persister.insert_reverse_swap(&full_ref_swap_info)?;
-        persister
-            .update_reverse_swap_status("rev_swap_id", &ReverseSwapStatus::CompletedConfirmed)?;
-        persister
-            .update_reverse_swap_lockup_txid("rev_swap_id", Some("lockup_txid".to_string()))?;
-        persister.update_reverse_swap_claim_txid("rev_swap_id", Some("claim_txid".to_string()))?;
+    #[tokio::test]
+    async fn test_sign_invoice() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let build_raw_invoice = |currency: Currency| {
+            InvoiceBuilder::new(currency)
+                .description("externally constructed".into())
+                .payment_hash(sha256::Hash::hash(&[1; 32]))
+                .current_timestamp()
+                .amount_milli_satoshis(1_000)
+                .payment_secret(PaymentSecret([2; 32]))
+                .min_final_cltv_expiry_delta(144)
+                .build_raw()
+        };
 
-        let mut builder = BreezServicesBuilder::new(test_config.clone());
-        let breez_services = builder
-            .lsp_api(Arc::new(MockBreezServer {}))
-            .fiat_api(Arc::new(MockBreezServer {}))
-            .node_api(node_api)
-            .persister(persister)
-            .backup_transport(Arc::new(MockBackupTransport::new()))
-            .build(None, None)
+        let bolt11 = breez_services
+            .sign_invoice(build_raw_invoice(Currency::Bitcoin)?)
             .await?;
+        let invoice = parse_invoice(&bolt11)?;
+        assert_eq!(invoice.amount_msat, Some(1_000));
+        assert_eq!(invoice.description, Some("externally constructed".into()));
+
+        let res = breez_services
+            .sign_invoice(build_raw_invoice(Currency::Regtest)?)
+            .await;
+        assert!(res.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_verify_invoices() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let sign = |raw_invoice| breez_services.node_api.sign_invoice(raw_invoice);
+        let builder = |currency: Currency| {
+            InvoiceBuilder::new(currency)
+                .description("reconciled".into())
+                .payment_hash(sha256::Hash::hash(&[1; 32]))
+                .current_timestamp()
+                .amount_milli_satoshis(1_000)
+                .payment_secret(PaymentSecret([2; 32]))
+                .min_final_cltv_expiry_delta(144)
+        };
+
+        let valid = sign(builder(Currency::Bitcoin).build_raw()?).await?;
+        let regtest = sign(builder(Currency::Regtest).build_raw()?).await?;
+        // The signature doesn't match the payee key of the invoice
+        let payee =
+            PublicKey::from_secret_key(&Secp256k1::new(), &SecretKey::from_slice(&[3; 32])?);
+        let forged = sign(
+            builder(Currency::Bitcoin)
+                .payee_pub_key(payee)
+                .build_raw()?,
+        )
+        .await?;
+
+        let results = breez_services
+            .verify_invoices(vec![
+                valid.clone(),
+                regtest.clone(),
+                "not an invoice".to_string(),
+                forged.clone(),
+            ])
+            .await;
+        let bolt11s: Vec<&str> = results.iter().map(|r| r.bolt11.as_str()).collect();
+        assert_eq!(bolt11s, vec![&valid, &regtest, "not an invoice", &forged]);
+
+        assert_eq!(
+            results[0].invoice.as_ref().map(|i| i.amount_msat),
+            Some(Some(1_000))
+        );
+        assert!(results[0].error.is_none());
+        for result in &results[1..] {
+            assert!(result.invoice.is_none());
+            assert!(result.error.is_some());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_lsps() -> Result<()> {
+        let storage_path = format!("{}/storage.sql", get_test_working_dir());
+        std::fs::remove_file(storage_path).ok();
 
+        let breez_services = breez_services()
+            .await
+            .map_err(|e| anyhow!("Failed to get the BreezServices: {e}"))?;
         breez_services.sync().await?;
-        let fetched_state = breez_services.node_info()?;
-        assert_eq!(fetched_state, dummy_node_state);
 
-        let all = breez_services
-            .list_payments(ListPaymentsRequest::default())
+        let node_pubkey = breez_services.node_info()?.id;
+        let lsps = breez_services.lsp_api.list_lsps(node_pubkey).await?;
+        assert_eq!(lsps.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_subscribe() -> Result<()> {
+        let breez_services = breez_services()
+            .await
+            .map_err(|e| anyhow!("Failed to get the BreezServices: {e}"))?;
+        let mut all_events = Box::pin(breez_services.subscribe());
+        let mut block_events = Box::pin(
+            breez_services.subscribe_filtered(|e| matches!(e, BreezEvent::NewBlock { .. })),
+        );
+
+        breez_services
+            .notify_event_listeners(BreezEvent::Synced)
+            .await?;
+        breez_services
+            .notify_event_listeners(BreezEvent::NewBlock { block: 1 })
+            .await?;
+
+        assert_eq!(all_events.next().await, Some(BreezEvent::Synced));
+        assert_eq!(
+            all_events.next().await,
+            Some(BreezEvent::NewBlock { block: 1 })
+        );
+        assert_eq!(
+            block_events.next().await,
+            Some(BreezEvent::NewBlock { block: 1 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_serialize_event() -> Result<()> {
+        assert_eq!(
+            serde_json::to_value(BreezEvent::NewBlock { block: 1 })?,
+            json!({"type": "NewBlock", "block": 1})
+        );
+        assert_eq!(
+            serde_json::to_value(BreezEvent::Synced)?,
+            json!({"type": "Synced"})
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "lnurl")]
+    #[tokio::test]
+    async fn test_static_lnurl_pay() -> Result<()> {
+        let breez_services = breez_services().await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        let callback = |amount_msat, comment: Option<&str>| {
+            breez_services.static_lnurl_pay_callback(StaticLnurlPayCallbackRequest {
+                amount_msat: MilliSat(amount_msat),
+                comment: comment.map(str::to_string),
+            })
+        };
+        assert!(callback(10_000, None).await.is_err());
+
+        let static_lnurl_pay = breez_services
+            .create_static_lnurl_pay(CreateStaticLnurlPayRequest {
+                url: "https://alice.example.com/lnurlp".to_string(),
+                callback_url: "https://alice.example.com/lnurlp/callback".to_string(),
+                description: "Donations to Alice".to_string(),
+                min_sendable_msat: MilliSat(1_000),
+                max_sendable_msat: MilliSat(5_000_000),
+                comment_allowed: Some(20),
+            })
+            .await?;
+        assert!(static_lnurl_pay.lnurl.starts_with("LNURL1"));
+
+        let response: Value = serde_json::from_str(&callback(1, None).await?)?;
+        assert_eq!(response["status"], "ERROR");
+        let response: Value = serde_json::from_str(&callback(3_000_000, Some("Thanks!")).await?)?;
+        let invoice = parse_invoice(response["pr"].as_str().unwrap_or_default())?;
+        // The mock node doesn't hash the description
+        assert_eq!(invoice.description, Some(static_lnurl_pay.metadata));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_receive_payment_fallback_address() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        let receive = |include_fallback_address| {
+            breez_services.receive_payment(ReceivePaymentRequest {
+                amount_msat: MilliSat(3_000_000),
+                description: "fallback".to_string(),
+                include_fallback_address,
+                ..Default::default()
+            })
+        };
+
+        receive(None).await?;
+        receive(Some(true)).await?;
+        let swap_info = breez_services
+            .btc_receive_swapper
+            .list_unused()?
+            .pop()
+            .ok_or(anyhow!("Expected a new swap"))?;
+        assert_eq!(
+            *node_api.invoice_fallback_addresses.lock().unwrap(),
+            vec![None, Some(swap_info.bitcoin_address)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_channel_fees_paid() -> Result<()> {
+        let breez_services = breez_services().await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        assert!(breez_services.list_channel_fees_paid().await?.is_empty());
+
+        let resp = breez_services
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: MilliSat(3_000_000),
+                description: "channel fee".to_string(),
+                ..Default::default()
+            })
             .await?;
-        let mut cloned = all.clone();
+        let opening_fee_msat = resp
+            .opening_fee_msat
+            .ok_or(anyhow!("Expected a channel opening fee"))?;
+        let payment_hash = resp.ln_invoice.payment_hash.clone();
+        // The fee is only listed once the payment is received
+        assert!(breez_services.list_channel_fees_paid().await?.is_empty());
+
+        let received_amount_msat = 3_000_000 - opening_fee_msat;
+        breez_services.persister.insert_or_update_payments(
+            &[Payment {
+                id: payment_hash.clone(),
+                payment_type: PaymentType::Received,
+                payment_time: 1,
+                amount_msat: received_amount_msat,
+                status: PaymentStatus::Complete,
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails {
+                        payment_hash: payment_hash.clone(),
+                        ..Default::default()
+                    },
+                },
+                ..Default::default()
+            }],
+            false,
+        )?;
 
-        // test the right order
-        cloned.reverse();
-        assert_eq!(dummy_transactions, cloned);
+        let fees = breez_services.list_channel_fees_paid().await?;
+        assert_eq!(fees.len(), 1);
+        assert_eq!(fees[0].payment_hash, payment_hash);
+        assert_eq!(fees[0].payer_amount_msat, 3_000_000);
+        assert_eq!(fees[0].received_amount_msat, received_amount_msat);
+        assert_eq!(fees[0].fee_msat, opening_fee_msat);
+        assert_eq!(fees[0].opening_fee_params, resp.opening_fee_params);
+
+        // The received payment shows the fee it included
+        let payment = breez_services
+            .payment_by_hash(payment_hash)
+            .await?
+            .ok_or(anyhow!("Expected the received payment"))?;
+        assert_eq!(payment.fee_msat, opening_fee_msat);
+        assert!(matches!(
+            payment.details,
+            PaymentDetails::Ln { data } if data.channel_opening_fee_msat == Some(opening_fee_msat)
+        ));
+        Ok(())
+    }
 
-        let received = breez_services
-            .list_payments(ListPaymentsRequest {
-                filters: Some(vec![PaymentTypeFilter::Received]),
+    #[tokio::test]
+    async fn test_open_channel_receive_registered_event() -> Result<()> {
+        let breez_services = breez_services()
+            .await
+            .map_err(|e| anyhow!("Failed to get the BreezServices: {e}"))?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        let mut events = Box::pin(breez_services.subscribe());
+
+        let res = breez_services
+            .receive_payment(ReceivePaymentRequest {
+                amount_msat: MilliSat(3_000_000),
+                description: "first receive".to_string(),
                 ..Default::default()
             })
             .await?;
+        let opening_fee_msat = res
+            .opening_fee_msat
+            .ok_or(anyhow!("Expected a channel opening"))?;
         assert_eq!(
-            received,
-            vec![cloned[3].clone(), cloned[1].clone(), cloned[0].clone()]
+            events.next().await,
+            Some(BreezEvent::OpenChannelReceiveUpdated {
+                details: OpenChannelReceiveDetails {
+                    payment_hash: res.ln_invoice.payment_hash,
+                    stage: OpenChannelReceiveStage::Registered {
+                        opening_fee_msat: opening_fee_msat.msat()
+                    },
+                },
+            })
         );
+        Ok(())
+    }
 
-        let sent = breez_services
-            .list_payments(ListPaymentsRequest {
-                filters: Some(vec![
-                    PaymentTypeFilter::Sent,
-                    PaymentTypeFilter::ClosedChannel,
-                ]),
-                ..Default::default()
-            })
+    #[tokio::test]
+    async fn test_register_lightning_address() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+        mock_rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"lnurl":"lnurl1dp68gurn8ghj7","lightning_address":"alice@breez.fun"}"#.to_string(),
+        ));
+        mock_rest_client.add_response(MockResponse::new(409, "".to_string()));
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+        let breez_services = breez_services_with(None, Some(rest_client), vec![]).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+
+        // The invoices are served through the webhook, so one has to be registered first
+        assert!(breez_services
+            .register_lightning_address("alice".into())
+            .await
+            .is_err());
+        breez_services
+            .persister
+            .set_webhook_url("https://example.com/webhook".into())?;
+        assert!(breez_services
+            .register_lightning_address("Alice!".into())
+            .await
+            .is_err());
+
+        let info = breez_services
+            .register_lightning_address("alice".into())
             .await?;
-        assert_eq!(sent, vec![cloned[4].clone(), cloned[2].clone()]);
-        assert!(matches!(
-                &sent[1].details,
-                PaymentDetails::Ln {data: LnPaymentDetails {lnurl_success_action, ..}}
-                if lnurl_success_action == &Some(sa)));
-        assert!(matches!(
-                &sent[1].details,
-                PaymentDetails::Ln {data: LnPaymentDetails {lnurl_pay_domain, ln_address, ..}}
-                if lnurl_pay_domain.is_none() && ln_address == &Some(test_ln_address.to_string())));
-        assert!(matches!(
-                &received[1].details,
-                PaymentDetails::Ln {data: LnPaymentDetails {lnurl_withdraw_endpoint, ..}}
-                if lnurl_withdraw_endpoint == &Some(test_lnurl_withdraw_endpoint.to_string())));
-        assert!(matches!(
-                &received[0].details,
-                PaymentDetails::Ln {data: LnPaymentDetails {swap_info: swap, ..}}
-                if swap == &Some(swap_info)));
-        assert!(matches!(
-                &sent[0].details,
-                PaymentDetails::Ln {data: LnPaymentDetails {reverse_swap_info: rev_swap, ..}}
-                if rev_swap == &Some(rev_swap_info)));
+        assert_eq!(info.lightning_address, "alice@breez.fun");
+        assert_eq!(breez_services.lnurl_pay_info()?, Some(info.clone()));
 
+        // Failing to register a taken username keeps the registered address
+        assert!(breez_services
+            .register_lightning_address("bob".into())
+            .await
+            .is_err());
+        assert_eq!(breez_services.lnurl_pay_info()?, Some(info));
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_receive_with_open_channel() -> Result<()> {
-        let config = create_test_config();
-        let persister = Arc::new(create_test_persister(config.clone()));
-        persister.init().unwrap();
-
-        let dummy_node_state = get_dummy_node_state();
+    async fn test_lnurl_withdraw_vouchers() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+        mock_rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"lnurl":"lnurl1dp68gurn8ghj7"}"#.to_string(),
+        ));
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+        let breez_services = breez_services_with(None, Some(rest_client), vec![]).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
+        breez_services
+            .persister
+            .set_webhook_url("https://example.com/webhook".into())?;
+        let create_req = |amount_msat: u64, uses: u32| CreateLnurlWithdrawVoucherRequest {
+            amount_msat: MilliSat(amount_msat),
+            uses,
+            expiry_secs: 3600,
+            description: Some("Gift".into()),
+        };
 
-        let node_api = Arc::new(MockNodeAPI::new(dummy_node_state.clone()));
+        // All the uses have to be payable from the balance
+        assert!(breez_services
+            .create_lnurl_withdraw_voucher(create_req(50, 2))
+            .await
+            .is_err());
+        assert!(breez_services
+            .create_lnurl_withdraw_voucher(create_req(0, 2))
+            .await
+            .is_err());
 
-        let breez_server = Arc::new(MockBreezServer {});
-        persister.set_lsp(breez_server.lsp_id(), None).unwrap();
-        persister.set_node_state(&dummy_node_state).unwrap();
+        let voucher = breez_services
+            .create_lnurl_withdraw_voucher(create_req(40, 2))
+            .await?;
+        assert_eq!(voucher.lnurl, "lnurl1dp68gurn8ghj7");
+        assert_eq!(voucher.expires_at, voucher.created_at + 3600);
+        assert_eq!(
+            breez_services.list_lnurl_withdraw_vouchers()?,
+            vec![voucher.clone()]
+        );
 
-        let receiver: Arc<dyn Receiver> = Arc::new(PaymentReceiver {
-            config,
-            node_api,
-            persister,
-            lsp: breez_server.clone(),
-        });
-        let ln_invoice = receiver
-            .receive_payment(ReceivePaymentRequest {
-                amount_msat: 3_000_000,
-                description: "should populate lsp hints".to_string(),
-                use_description_hash: Some(false),
-                ..Default::default()
+        // The invoice of the claimant can't be for more than the voucher
+        let invoice = create_invoice("claim".into(), 41, vec![], None);
+        let res = breez_services
+            .redeem_lnurl_withdraw_voucher(RedeemLnurlWithdrawVoucherRequest {
+                id: voucher.id.clone(),
+                bolt11: invoice.bolt11.clone(),
             })
-            .await?
-            .ln_invoice;
-        assert_eq!(ln_invoice.routing_hints[0].hops.len(), 1);
-        let lsp_hop = &ln_invoice.routing_hints[0].hops[0];
-        assert_eq!(lsp_hop.src_node_id, breez_server.clone().lsp_pub_key());
-        assert_eq!(lsp_hop.short_channel_id, "1x0x0");
+            .await;
+        assert!(matches!(res, Err(SendPaymentError::InvalidAmount { .. })));
+        let res = breez_services
+            .redeem_lnurl_withdraw_voucher(RedeemLnurlWithdrawVoucherRequest {
+                id: "unknown".into(),
+                bolt11: invoice.bolt11,
+            })
+            .await;
+        assert!(matches!(res, Err(SendPaymentError::Generic { .. })));
+        assert_eq!(
+            breez_services.list_lnurl_withdraw_vouchers()?[0].redeemed_uses,
+            0
+        );
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_list_lsps() -> Result<()> {
-        let storage_path = format!("{}/storage.sql", get_test_working_dir());
-        std::fs::remove_file(storage_path).ok();
+    async fn test_diagnostic_snapshot() -> Result<()> {
+        let payment = Payment {
+            id: "1111".to_string(),
+            payment_type: PaymentType::Received,
+            payment_time: 100000,
+            amount_msat: 10,
+            fee_msat: 0,
+            status: PaymentStatus::Complete,
+            error: None,
+            description: Some("Coffee".to_string()),
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    payment_hash: "1111".to_string(),
+                    label: "".to_string(),
+                    destination_pubkey: "1111".to_string(),
+                    payment_preimage: "2222".to_string(),
+                    keysend: false,
+                    bolt11: "1111".to_string(),
+                    lnurl_success_action: None,
+                    lnurl_pay_domain: None,
+                    lnurl_pay_comment: None,
+                    lnurl_metadata: None,
+                    ln_address: None,
+                    lnurl_withdraw_endpoint: None,
+                    swap_info: None,
+                    reverse_swap_info: None,
+                    pending_expiration_block: None,
+                    open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
+                },
+            },
+            metadata: None,
+            fiat_rate: None,
+            imported: false,
+        };
+        let breez_services = breez_services_with(None, None, vec![payment]).await?;
+        breez_services
+            .persister
+            .set_node_state(&get_dummy_node_state())?;
 
-        let breez_services = breez_services()
-            .await
-            .map_err(|e| anyhow!("Failed to get the BreezServices: {e}"))?;
+        let snapshot = breez_services.diagnostic_snapshot().await?;
+        let value: Value = serde_json::from_str(&snapshot)?;
+        let payment = &value["payments"]["1111"];
+        assert_eq!(payment["amount_msat"], json!(10));
+        assert_eq!(payment["description"], json!("<redacted>"));
+        assert_eq!(
+            payment["details"]["data"]["payment_preimage"],
+            json!("<redacted>")
+        );
+        assert_eq!(
+            value["node_state"]["max_payable_msat"],
+            json!(get_dummy_node_state().max_payable_msat)
+        );
+
+        assert!(diff_snapshots(snapshot.clone(), snapshot)?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_peer_connectivity() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let connectivity = breez_services.peer_connectivity().await?;
+        assert_eq!(connectivity.lsp_pubkey, None);
+        assert!(!connectivity.lsp_connected);
+
+        // The LSP is not among the connected peers of the dummy node state, so it's reconnected
+        breez_services.sync().await?;
+        breez_services.connect_lsp_peer("tx1".into()).await?;
+        let connectivity = breez_services.peer_connectivity().await?;
+        assert_eq!(
+            connectivity.lsp_pubkey,
+            Some(MockBreezServer {}.lsp_pub_key())
+        );
+        assert!(connectivity.lsp_connected);
+        assert!(connectivity.ping_rtt_ms.is_some());
+        assert_eq!(connectivity.ping_error, None);
+        assert!(connectivity.reconnection_attempts >= 1);
+        assert_eq!(connectivity.failed_reconnection_attempts, 0);
+        assert!(connectivity.last_reconnected_at.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ensure_lsp_peer_connected() -> Result<()> {
+        let node_api = Arc::new(MockNodeAPI::new(get_dummy_node_state()));
+        let breez_services = breez_services_with(Some(node_api.clone()), None, vec![]).await?;
         breez_services.sync().await?;
+        let attempts = breez_services.lsp_reconnections.lock().await.attempts;
+        let mut events = breez_services.event_sender.subscribe();
 
-        let node_pubkey = breez_services.node_info()?.id;
-        let lsps = breez_services.lsp_api.list_lsps(node_pubkey).await?;
-        assert_eq!(lsps.len(), 1);
+        // A reachable LSP peer is left as is
+        breez_services.ensure_lsp_peer_connected().await;
+        assert_eq!(
+            breez_services.lsp_reconnections.lock().await.attempts,
+            attempts
+        );
+        assert!(events.try_recv().is_err());
+
+        *node_api.peers_offline.lock().unwrap() = true;
+        breez_services.ensure_lsp_peer_connected().await;
+        assert!(!*node_api.peers_offline.lock().unwrap());
+        let reconnections = breez_services.lsp_reconnections.lock().await;
+        assert_eq!(reconnections.attempts, attempts + 1);
+        assert_eq!(reconnections.failed_attempts, 0);
+        assert!(reconnections.last_reconnected_at.is_some());
+        assert!(matches!(
+            events.try_recv()?,
+            BreezEvent::LspPeerReconnected { lsp_pubkey, reason }
+                if lsp_pubkey == MockBreezServer {}.lsp_pub_key() && reason.contains("Peer not connected")
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_unredeemed_funds() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let mut events = breez_services.event_sender.subscribe();
+        let insert_swap = |address: &str, fee_params_valid: bool, bolt11: Option<String>| {
+            breez_services.persister.insert_swap(
+                &SwapInfo {
+                    bitcoin_address: address.to_string(),
+                    payment_hash: rand_vec_u8(32),
+                    confirmed_sats: 50_000,
+                    status: SwapStatus::Redeemable,
+                    channel_opening_fees: Some(get_test_ofp(1, 1, fee_params_valid).into()),
+                    ..Default::default()
+                },
+                None,
+            )?;
+            if let Some(bolt11) = bolt11 {
+                breez_services
+                    .persister
+                    .update_swap_bolt11(address.to_string(), bolt11)?;
+            }
+            anyhow::Ok(())
+        };
 
+        breez_services.check_unredeemed_funds().await?;
+        assert!(events.try_recv().is_err());
+
+        // Only the swap whose invoice and fee params both expired needs a new opening fee
+        insert_swap("pending", true, None)?;
+        insert_swap("valid_fee", true, Some(create_expired_invoice(50_000_000)))?;
+        insert_swap("expired", false, Some(create_expired_invoice(50_000_000)))?;
+        breez_services.check_unredeemed_funds().await?;
+        let BreezEvent::UnredeemedFundsDetected { details } = events.try_recv()? else {
+            panic!("Expected an UnredeemedFundsDetected event");
+        };
+        let addresses = |swaps: Vec<SwapInfo>| {
+            let mut addresses: Vec<_> = swaps.into_iter().map(|s| s.bitcoin_address).collect();
+            addresses.sort();
+            addresses
+        };
+        assert_eq!(
+            addresses(details.redeemable_swaps),
+            vec!["pending", "valid_fee"]
+        );
+        assert_eq!(addresses(details.expired_invoice_swaps), vec!["expired"]);
+        assert_eq!(details.total_amount_sat, 150_000);
         Ok(())
     }
 
+    #[cfg(feature = "fiat")]
     #[tokio::test]
     async fn test_fetch_rates() -> Result<(), Box<dyn std::error::Error>> {
         let breez_services = breez_services().await?;
@@ -3388,6 +11555,7 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "buy-bitcoin")]
     #[tokio::test]
     async fn test_buy_bitcoin_with_moonpay() -> Result<(), Box<dyn std::error::Error>> {
         let mock_rest_client = MockRestClient::new();
@@ -3422,6 +11590,145 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "buy-bitcoin")]
+    #[tokio::test]
+    async fn test_fetch_buy_bitcoin_quote() -> Result<(), Box<dyn std::error::Error>> {
+        let breez_services = breez_services().await?;
+
+        let quote = breez_services
+            .fetch_buy_bitcoin_quote(FetchBuyBitcoinQuoteRequest {
+                provider: BuyBitcoinProvider::Moonpay,
+                fiat_amount: FiatAmount {
+                    currency: "USD".to_string(),
+                    amount: 100.0,
+                },
+            })
+            .await?;
+        assert_eq!(quote.fiat_currency, "USD");
+        assert_eq!(quote.fiat_amount, 100.0);
+        assert!(quote.fees_fiat < quote.fiat_amount);
+
+        let invalid_amount = breez_services
+            .fetch_buy_bitcoin_quote(FetchBuyBitcoinQuoteRequest {
+                provider: BuyBitcoinProvider::Moonpay,
+                fiat_amount: FiatAmount {
+                    currency: "USD".to_string(),
+                    amount: 0.0,
+                },
+            })
+            .await;
+        assert!(invalid_amount.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_export_swap_refund_keys() -> Result<()> {
+        let breez_services = breez_services().await?;
+        let gap_keys = ExportSwapRefundKeysRequest {
+            include_gap_keys: Some(true),
+        };
+        let keys = breez_services
+            .export_swap_refund_keys(ExportSwapRefundKeysRequest::default())
+            .await?;
+        assert!(keys.is_empty());
+        let keys = breez_services
+            .export_swap_refund_keys(gap_keys.clone())
+            .await?;
+        assert_eq!(keys.len() as u32, SWAP_REFUND_KEY_GAP_LIMIT);
+        assert_eq!(keys[0].derivation_path, "m/145'/0'");
+        let private_key = SecretKey::from_slice(&keys[0].private_key)?;
+        assert_eq!(
+            PublicKey::from_secret_key(&Secp256k1::new(), &private_key)
+                .serialize()
+                .to_vec(),
+            keys[0].public_key
+        );
+
+        breez_services.persister.insert_swap(
+            &SwapInfo {
+                bitcoin_address: "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq".into(),
+                channel_opening_fees: Some(get_test_ofp_48h(1, 1).into()),
+                ..Default::default()
+            },
+            Some(0),
+        )?;
+        let keys = breez_services
+            .export_swap_refund_keys(ExportSwapRefundKeysRequest::default())
+            .await?;
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].derivation_path, "m/145'/0'");
+        let keys = breez_services.export_swap_refund_keys(gap_keys).await?;
+        assert_eq!(keys.len() as u32, SWAP_REFUND_KEY_GAP_LIMIT + 1);
+        assert_eq!(
+            keys.last().unwrap().derivation_path,
+            format!("m/145'/{SWAP_REFUND_KEY_GAP_LIMIT}'")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_service_status_events() {
+        let mut statuses = HashMap::new();
+        let changed = |component, status| BreezEvent::ServiceStatusChanged { component, status };
+
+        let events = service_status_events(
+            &mut statuses,
+            vec![
+                (ServiceComponent::Lsp, ServiceStatus::Operational),
+                (ServiceComponent::Swapper, ServiceStatus::Degraded),
+            ],
+        );
+        assert_eq!(
+            events,
+            vec![changed(ServiceComponent::Swapper, ServiceStatus::Degraded)]
+        );
+
+        let events = service_status_events(
+            &mut statuses,
+            vec![
+                (ServiceComponent::Lsp, ServiceStatus::Degraded),
+                (ServiceComponent::Swapper, ServiceStatus::Degraded),
+            ],
+        );
+        assert_eq!(
+            events,
+            vec![changed(ServiceComponent::Lsp, ServiceStatus::Degraded)]
+        );
+
+        let events = service_status_events(
+            &mut statuses,
+            vec![
+                (ServiceComponent::Lsp, ServiceStatus::Operational),
+                (ServiceComponent::Swapper, ServiceStatus::Operational),
+            ],
+        );
+        assert_eq!(
+            events,
+            vec![
+                changed(ServiceComponent::Lsp, ServiceStatus::Operational),
+                changed(ServiceComponent::Swapper, ServiceStatus::Operational),
+            ]
+        );
+        assert!(service_status_events(
+            &mut statuses,
+            vec![(ServiceComponent::Lsp, ServiceStatus::Operational)]
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_sdk_info() {
+        let info = BreezServices::sdk_info();
+
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+        assert!(info.supported_luds.contains(&"LUD-06".to_string()));
+        assert!(info
+            .reverse_swap_protocols
+            .contains(&ReverseSwapProtocol::Taproot));
+        assert!(info.backup_schema_version >= info.min_backup_schema_version);
+    }
+
     /// Build node service for tests
     pub(crate) async fn breez_services() -> Result<Arc<BreezServices>> {
         breez_services_with(None, None, vec![]).await
@@ -3433,30 +11740,80 @@ pub(crate) mod tests {
         rest_client: Option<Arc<dyn RestClient>>,
         known_payments: Vec<Payment>,
     ) -> Result<Arc<BreezServices>> {
-        let node_api =
-            node_api.unwrap_or_else(|| Arc::new(MockNodeAPI::new(get_dummy_node_state())));
-        let rest_client: Arc<dyn RestClient> =
-            rest_client.unwrap_or_else(|| Arc::new(MockRestClient::new()));
+        breez_services_with_config(create_test_config(), node_api, rest_client, known_payments)
+            .await
+    }
+
+    /// Build node service for tests with the given config and a list of known payments
+    pub(crate) async fn breez_services_with_config(
+        test_config: Config,
+        node_api: Option<Arc<dyn NodeAPI>>,
+        rest_client: Option<Arc<dyn RestClient>>,
+        known_payments: Vec<Payment>,
+    ) -> Result<Arc<BreezServices>> {
+        breez_services_with_mocks(
+            test_config,
+            TestMocks {
+                node_api,
+                rest_client,
+                known_payments,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// The mocks [breez_services_with_mocks] builds the node service with. The unset ones
+    /// default to the standard mocks.
+    #[derive(Default)]
+    pub(crate) struct TestMocks {
+        pub(crate) node_api: Option<Arc<dyn NodeAPI>>,
+        pub(crate) rest_client: Option<Arc<dyn RestClient>>,
+        pub(crate) lsp_api: Option<Arc<dyn LspAPI>>,
+        pub(crate) chain_service: Option<Arc<dyn ChainService>>,
+        pub(crate) known_payments: Vec<Payment>,
+        pub(crate) event_listener: Option<Box<dyn EventListener>>,
+    }
+
+    /// Build node service for tests with the given config and mocks
+    pub(crate) async fn breez_services_with_mocks(
+        test_config: Config,
+        mocks: TestMocks,
+    ) -> Result<Arc<BreezServices>> {
+        let node_api = mocks
+            .node_api
+            .unwrap_or_else(|| Arc::new(MockNodeAPI::new(get_dummy_node_state())));
+        let rest_client: Arc<dyn RestClient> = mocks
+            .rest_client
+            .unwrap_or_else(|| Arc::new(MockRestClient::new()));
+        let lsp_api: Arc<dyn LspAPI> = mocks
+            .lsp_api
+            .unwrap_or_else(|| Arc::new(MockBreezServer {}));
+        let chain_service: Arc<dyn ChainService> = mocks
+            .chain_service
+            .unwrap_or_else(|| Arc::new(MockChainService::default()));
 
-        let test_config = create_test_config();
         let persister = Arc::new(create_test_persister(test_config.clone()));
         persister.init()?;
-        persister.insert_or_update_payments(&known_payments, false)?;
+        persister.insert_or_update_payments(&mocks.known_payments, false)?;
         persister.set_lsp(MockBreezServer {}.lsp_id(), None)?;
 
         let mut builder = BreezServicesBuilder::new(test_config.clone());
-        let breez_services = builder
-            .lsp_api(Arc::new(MockBreezServer {}))
-            .fiat_api(Arc::new(MockBreezServer {}))
+        builder
+            .lsp_api(lsp_api)
             .taproot_swapper_api(Arc::new(MockBreezServer {}))
-            .reverse_swap_service_api(Arc::new(MockReverseSwapperAPI {}))
-            .buy_bitcoin_api(Arc::new(MockBuyBitcoinService {}))
             .persister(persister)
             .node_api(node_api)
             .rest_client(rest_client)
-            .backup_transport(Arc::new(MockBackupTransport::new()))
-            .build(None, None)
-            .await?;
+            .chain_service(chain_service)
+            .backup_transport(Arc::new(MockBackupTransport::new()));
+        #[cfg(feature = "fiat")]
+        builder.fiat_api(Arc::new(MockBreezServer {}));
+        #[cfg(feature = "reverse-swaps")]
+        builder.reverse_swap_service_api(Arc::new(MockReverseSwapperAPI {}));
+        #[cfg(feature = "buy-bitcoin")]
+        builder.buy_bitcoin_api(Arc::new(MockBuyBitcoinService {}));
+        let breez_services = builder.build(None, mocks.event_listener).await?;
 
         Ok(breez_services)
     }
@@ -3477,6 +11834,8 @@ pub(crate) mod tests {
             connected_peers: vec!["1111".to_string()],
             max_receivable_single_payment_amount_msat: 2_000,
             total_inbound_liquidity_msats: 10_000,
+            pending_channel_opens_msat: 0,
+            pending_channel_opens: vec![],
         }
     }
 }
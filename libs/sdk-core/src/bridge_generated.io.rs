@@ -6,6 +6,11 @@ pub extern "C" fn wire_connect(port_: i64, req: *mut wire_ConnectRequest) {
     wire_connect_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_connect_ephemeral(port_: i64, config: *mut wire_Config) {
+    wire_connect_ephemeral_impl(port_, config)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_is_initialized(port_: i64) {
     wire_is_initialized_impl(port_)
@@ -16,6 +21,21 @@ pub extern "C" fn wire_sync(port_: i64) {
     wire_sync_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_cancel_sync(port_: i64) {
+    wire_cancel_sync_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_metrics(port_: i64) {
+    wire_metrics_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sync_partial(port_: i64) {
+    wire_sync_partial_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_node_credentials(port_: i64) {
     wire_node_credentials_impl(port_)
@@ -26,6 +46,16 @@ pub extern "C" fn wire_node_info(port_: i64) {
     wire_node_info_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_balances(port_: i64) {
+    wire_balances_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_get_balance_breakdown(port_: i64) {
+    wire_get_balance_breakdown_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_configure_node(port_: i64, req: *mut wire_ConfigureNodeRequest) {
     wire_configure_node_impl(port_, req)
@@ -36,6 +66,35 @@ pub extern "C" fn wire_disconnect(port_: i64) {
     wire_disconnect_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_derive_encryption_key(
+    port_: i64,
+    req: *mut wire_DeriveEncryptionKeyRequest,
+) {
+    wire_derive_encryption_key_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_prove_address_ownership(
+    port_: i64,
+    req: *mut wire_ProveAddressOwnershipRequest,
+) {
+    wire_prove_address_ownership_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_encrypt_payment_request(port_: i64, bundle: *mut wire_PaymentRequestBundle) {
+    wire_encrypt_payment_request_impl(port_, bundle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_decrypt_payment_request(
+    port_: i64,
+    data: *mut wire_EncryptedPaymentRequestData,
+) {
+    wire_decrypt_payment_request_impl(port_, data)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_sign_message(port_: i64, req: *mut wire_SignMessageRequest) {
     wire_sign_message_impl(port_, req)
@@ -61,6 +120,32 @@ pub extern "C" fn wire_default_config(
     wire_default_config_impl(port_, env_type, api_key, node_config)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_sdk_info(port_: i64) {
+    wire_sdk_info_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_decrypt_recovery_bundle(
+    port_: i64,
+    req: *mut wire_DecryptRecoveryBundleRequest,
+) {
+    wire_decrypt_recovery_bundle_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_export_recovery_bundle(
+    port_: i64,
+    req: *mut wire_ExportRecoveryBundleRequest,
+) {
+    wire_export_recovery_bundle_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_stop_recovery_bundle_refresh(port_: i64) {
+    wire_stop_recovery_bundle_refresh_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_static_backup(port_: i64, req: *mut wire_StaticBackupRequest) {
     wire_static_backup_impl(port_, req)
@@ -81,6 +166,11 @@ pub extern "C" fn wire_breez_log_stream(port_: i64) {
     wire_breez_log_stream_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_set_log_filter(port_: i64, filter: *mut wire_uint_8_list) {
+    wire_set_log_filter_impl(port_, filter)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_lsps(port_: i64) {
     wire_list_lsps_impl(port_)
@@ -96,6 +186,16 @@ pub extern "C" fn wire_lsp_id(port_: i64) {
     wire_lsp_id_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_user_settings(port_: i64) {
+    wire_user_settings_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_set_user_settings(port_: i64, settings: *mut wire_UserSettings) {
+    wire_set_user_settings_impl(port_, settings)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_fetch_lsp_info(port_: i64, id: *mut wire_uint_8_list) {
     wire_fetch_lsp_info_impl(port_, id)
@@ -111,6 +211,31 @@ pub extern "C" fn wire_close_lsp_channels(port_: i64) {
     wire_close_lsp_channels_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_channels(port_: i64) {
+    wire_list_channels_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_close_channel(port_: i64, req: *mut wire_CloseChannelRequest) {
+    wire_close_channel_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_connect_peer(port_: i64, req: *mut wire_ConnectPeerRequest) {
+    wire_connect_peer_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_open_channel(port_: i64, req: *mut wire_OpenChannelRequest) {
+    wire_open_channel_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_export_node_migration(port_: i64, req: *mut wire_NodeMigrationRequest) {
+    wire_export_node_migration_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_register_webhook(port_: i64, webhook_url: *mut wire_uint_8_list) {
     wire_register_webhook_impl(port_, webhook_url)
@@ -121,6 +246,53 @@ pub extern "C" fn wire_unregister_webhook(port_: i64, webhook_url: *mut wire_uin
     wire_unregister_webhook_impl(port_, webhook_url)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_register_lightning_address(port_: i64, username: *mut wire_uint_8_list) {
+    wire_register_lightning_address_impl(port_, username)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_lnurl_pay_info(port_: i64) {
+    wire_lnurl_pay_info_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_create_static_lnurl_pay(
+    port_: i64,
+    req: *mut wire_CreateStaticLnurlPayRequest,
+) {
+    wire_create_static_lnurl_pay_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_static_lnurl_pay_callback(
+    port_: i64,
+    req: *mut wire_StaticLnurlPayCallbackRequest,
+) {
+    wire_static_lnurl_pay_callback_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_create_lnurl_withdraw_voucher(
+    port_: i64,
+    req: *mut wire_CreateLnurlWithdrawVoucherRequest,
+) {
+    wire_create_lnurl_withdraw_voucher_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_redeem_lnurl_withdraw_voucher(
+    port_: i64,
+    req: *mut wire_RedeemLnurlWithdrawVoucherRequest,
+) {
+    wire_redeem_lnurl_withdraw_voucher_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_lnurl_withdraw_vouchers(port_: i64) {
+    wire_list_lnurl_withdraw_vouchers_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_backup(port_: i64) {
     wire_backup_impl(port_)
@@ -131,6 +303,21 @@ pub extern "C" fn wire_backup_status(port_: i64) {
     wire_backup_status_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_rotate_backup_key(port_: i64) {
+    wire_rotate_backup_key_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_backup_versions(port_: i64) {
+    wire_list_backup_versions_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_restore_backup(port_: i64, version: u64) {
+    wire_restore_backup_impl(port_, version)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_parse_invoice(port_: i64, invoice: *mut wire_uint_8_list) {
     wire_parse_invoice_impl(port_, invoice)
@@ -146,11 +333,31 @@ pub extern "C" fn wire_list_payments(port_: i64, req: *mut wire_ListPaymentsRequ
     wire_list_payments_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_payment_groups(port_: i64, req: *mut wire_ListPaymentGroupsRequest) {
+    wire_list_payment_groups_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_export_payments(port_: i64, req: *mut wire_ExportPaymentsRequest) {
+    wire_export_payments_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_import_payments(port_: i64, req: *mut wire_ImportPaymentsRequest) {
+    wire_import_payments_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_payment_by_hash(port_: i64, hash: *mut wire_uint_8_list) {
     wire_payment_by_hash_impl(port_, hash)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_channel_fees_paid(port_: i64) {
+    wire_list_channel_fees_paid_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_set_payment_metadata(
     port_: i64,
@@ -165,6 +372,36 @@ pub extern "C" fn wire_send_payment(port_: i64, req: *mut wire_SendPaymentReques
     wire_send_payment_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_send_payments(port_: i64, req: *mut wire_SendPaymentsRequest) {
+    wire_send_payments_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_verify_invoices(port_: i64, invoices: *mut wire_StringList) {
+    wire_verify_invoices_impl(port_, invoices)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_queue_payment(port_: i64, req: *mut wire_QueuePaymentRequest) {
+    wire_queue_payment_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_pending_outbox(port_: i64) {
+    wire_list_pending_outbox_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cancel_outbox_payment(port_: i64, id: i64) {
+    wire_cancel_outbox_payment_impl(port_, id)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_prepare_send_payment(port_: i64, req: *mut wire_PrepareSendPaymentRequest) {
+    wire_prepare_send_payment_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_send_spontaneous_payment(
     port_: i64,
@@ -173,11 +410,54 @@ pub extern "C" fn wire_send_spontaneous_payment(
     wire_send_spontaneous_payment_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_cancel(port_: i64, cancel_token: *mut wire_uint_8_list) {
+    wire_cancel_impl(port_, cancel_token)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_probe_payment(port_: i64, req: *mut wire_ProbePaymentRequest) {
+    wire_probe_payment_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_validate_payability(port_: i64, req: *mut wire_ValidatePayabilityRequest) {
+    wire_validate_payability_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_payment_probes(port_: i64) {
+    wire_list_payment_probes_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_pay_offer(port_: i64, req: *mut wire_PayOfferRequest) {
+    wire_pay_offer_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_prepare_receive_payment(
+    port_: i64,
+    req: *mut wire_PrepareReceivePaymentRequest,
+) {
+    wire_prepare_receive_payment_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_receive_payment(port_: i64, req: *mut wire_ReceivePaymentRequest) {
     wire_receive_payment_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_receive_unified(port_: i64, req: *mut wire_ReceiveUnifiedRequest) {
+    wire_receive_unified_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_amend_invoice(port_: i64, req: *mut wire_AmendInvoiceRequest) {
+    wire_amend_invoice_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_lnurl_pay(port_: i64, req: *mut wire_LnUrlPayRequest) {
     wire_lnurl_pay_impl(port_, req)
@@ -193,16 +473,76 @@ pub extern "C" fn wire_lnurl_auth(port_: i64, req_data: *mut wire_LnUrlAuthReque
     wire_lnurl_auth_impl(port_, req_data)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_list_lnurl_auth_domains(port_: i64) {
+    wire_list_lnurl_auth_domains_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_lnurl_auth_linking_key(port_: i64, domain: *mut wire_uint_8_list) {
+    wire_lnurl_auth_linking_key_impl(port_, domain)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_export_lnurl_auth(port_: i64) {
+    wire_export_lnurl_auth_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_import_lnurl_auth(port_: i64, export: *mut wire_LnUrlAuthExport) {
+    wire_import_lnurl_auth_impl(port_, export)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_report_issue(port_: i64, req: *mut wire_ReportIssueRequest) {
     wire_report_issue_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_connection_status(port_: i64) {
+    wire_connection_status_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_task_status(port_: i64) {
+    wire_task_status_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_peer_connectivity(port_: i64) {
+    wire_peer_connectivity_impl(port_)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_fetch_fiat_rates(port_: i64) {
     wire_fetch_fiat_rates_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_fiat_rate_history(
+    port_: i64,
+    currency: *mut wire_uint_8_list,
+    from_timestamp: *mut i64,
+    to_timestamp: *mut i64,
+) {
+    wire_fiat_rate_history_impl(port_, currency, from_timestamp, to_timestamp)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_add_fiat_rate_alert(port_: i64, req: *mut wire_AddFiatRateAlertRequest) {
+    wire_add_fiat_rate_alert_impl(port_, req)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_list_fiat_rate_alerts(port_: i64) {
+    wire_list_fiat_rate_alerts_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_remove_fiat_rate_alert(port_: i64, id: i64) {
+    wire_remove_fiat_rate_alert_impl(port_, id)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_list_fiat_currencies(port_: i64) {
     wire_list_fiat_currencies_impl(port_)
@@ -213,6 +553,11 @@ pub extern "C" fn wire_pay_onchain(port_: i64, req: *mut wire_PayOnchainRequest)
     wire_pay_onchain_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_pay_onchain_address(port_: i64, req: *mut wire_PayOnchainAddressRequest) {
+    wire_pay_onchain_address_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_receive_onchain(port_: i64, req: *mut wire_ReceiveOnchainRequest) {
     wire_receive_onchain_impl(port_, req)
@@ -223,11 +568,24 @@ pub extern "C" fn wire_buy_bitcoin(port_: i64, req: *mut wire_BuyBitcoinRequest)
     wire_buy_bitcoin_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_fetch_buy_bitcoin_quote(
+    port_: i64,
+    req: *mut wire_FetchBuyBitcoinQuoteRequest,
+) {
+    wire_fetch_buy_bitcoin_quote_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_redeem_onchain_funds(port_: i64, req: *mut wire_RedeemOnchainFundsRequest) {
     wire_redeem_onchain_funds_impl(port_, req)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_bump_fee(port_: i64, req: *mut wire_BumpFeeRequest) {
+    wire_bump_fee_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_prepare_redeem_onchain_funds(
     port_: i64,
@@ -241,6 +599,14 @@ pub extern "C" fn wire_list_refundables(port_: i64) {
     wire_list_refundables_impl(port_)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_export_swap_refund_keys(
+    port_: i64,
+    req: *mut wire_ExportSwapRefundKeysRequest,
+) {
+    wire_export_swap_refund_keys_impl(port_, req)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_prepare_refund(port_: i64, req: *mut wire_PrepareRefundRequest) {
     wire_prepare_refund_impl(port_, req)
@@ -261,6 +627,11 @@ pub extern "C" fn wire_redeem_swap(port_: i64, swap_address: *mut wire_uint_8_li
     wire_redeem_swap_impl(port_, swap_address)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_verify_swap_address(port_: i64, swap_address: *mut wire_uint_8_list) {
+    wire_verify_swap_address_impl(port_, swap_address)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_in_progress_swap(port_: i64) {
     wire_in_progress_swap_impl(port_)
@@ -319,57 +690,220 @@ pub extern "C" fn wire_generate_diagnostic_data(port_: i64) {
     wire_generate_diagnostic_data_impl(port_)
 }
 
-// Section: allocate functions
-
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_bool_0(value: bool) -> *mut bool {
-    support::new_leak_box_ptr(value)
+pub extern "C" fn wire_diagnostic_snapshot(port_: i64) {
+    wire_diagnostic_snapshot_impl(port_)
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_buy_bitcoin_request_0() -> *mut wire_BuyBitcoinRequest {
-    support::new_leak_box_ptr(wire_BuyBitcoinRequest::new_with_null_ptr())
+pub extern "C" fn wire_diff_snapshots(
+    port_: i64,
+    a: *mut wire_uint_8_list,
+    b: *mut wire_uint_8_list,
+) {
+    wire_diff_snapshots_impl(port_, a, b)
 }
 
+// Section: allocate functions
+
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_check_message_request_0() -> *mut wire_CheckMessageRequest {
-    support::new_leak_box_ptr(wire_CheckMessageRequest::new_with_null_ptr())
+pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
+    let wrap = wire_StringList {
+        ptr: support::new_leak_vec_ptr(<*mut wire_uint_8_list>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_configure_node_request_0() -> *mut wire_ConfigureNodeRequest {
-    support::new_leak_box_ptr(wire_ConfigureNodeRequest::new_with_null_ptr())
+pub extern "C" fn new_box_autoadd_add_fiat_rate_alert_request_0(
+) -> *mut wire_AddFiatRateAlertRequest {
+    support::new_leak_box_ptr(wire_AddFiatRateAlertRequest::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_connect_request_0() -> *mut wire_ConnectRequest {
-    support::new_leak_box_ptr(wire_ConnectRequest::new_with_null_ptr())
+pub extern "C" fn new_box_autoadd_amend_invoice_request_0() -> *mut wire_AmendInvoiceRequest {
+    support::new_leak_box_ptr(wire_AmendInvoiceRequest::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_greenlight_credentials_0() -> *mut wire_GreenlightCredentials {
-    support::new_leak_box_ptr(wire_GreenlightCredentials::new_with_null_ptr())
+pub extern "C" fn new_box_autoadd_auto_refund_config_0() -> *mut wire_AutoRefundConfig {
+    support::new_leak_box_ptr(wire_AutoRefundConfig::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_greenlight_node_config_0() -> *mut wire_GreenlightNodeConfig {
-    support::new_leak_box_ptr(wire_GreenlightNodeConfig::new_with_null_ptr())
+pub extern "C" fn new_box_autoadd_backup_transport_config_0() -> *mut wire_BackupTransportConfig {
+    support::new_leak_box_ptr(wire_BackupTransportConfig::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_i64_0(value: i64) -> *mut i64 {
+pub extern "C" fn new_box_autoadd_bool_0(value: bool) -> *mut bool {
     support::new_leak_box_ptr(value)
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_list_payments_request_0() -> *mut wire_ListPaymentsRequest {
-    support::new_leak_box_ptr(wire_ListPaymentsRequest::new_with_null_ptr())
+pub extern "C" fn new_box_autoadd_bump_fee_request_0() -> *mut wire_BumpFeeRequest {
+    support::new_leak_box_ptr(wire_BumpFeeRequest::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn new_box_autoadd_list_swaps_request_0() -> *mut wire_ListSwapsRequest {
-    support::new_leak_box_ptr(wire_ListSwapsRequest::new_with_null_ptr())
-}
+pub extern "C" fn new_box_autoadd_buy_bitcoin_request_0() -> *mut wire_BuyBitcoinRequest {
+    support::new_leak_box_ptr(wire_BuyBitcoinRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_chain_service_config_0() -> *mut wire_ChainServiceConfig {
+    support::new_leak_box_ptr(wire_ChainServiceConfig::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_check_message_request_0() -> *mut wire_CheckMessageRequest {
+    support::new_leak_box_ptr(wire_CheckMessageRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_close_channel_request_0() -> *mut wire_CloseChannelRequest {
+    support::new_leak_box_ptr(wire_CloseChannelRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_config_0() -> *mut wire_Config {
+    support::new_leak_box_ptr(wire_Config::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_configure_node_request_0() -> *mut wire_ConfigureNodeRequest {
+    support::new_leak_box_ptr(wire_ConfigureNodeRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_connect_peer_request_0() -> *mut wire_ConnectPeerRequest {
+    support::new_leak_box_ptr(wire_ConnectPeerRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_connect_request_0() -> *mut wire_ConnectRequest {
+    support::new_leak_box_ptr(wire_ConnectRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_create_lnurl_withdraw_voucher_request_0(
+) -> *mut wire_CreateLnurlWithdrawVoucherRequest {
+    support::new_leak_box_ptr(wire_CreateLnurlWithdrawVoucherRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_create_static_lnurl_pay_request_0(
+) -> *mut wire_CreateStaticLnurlPayRequest {
+    support::new_leak_box_ptr(wire_CreateStaticLnurlPayRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_decrypt_recovery_bundle_request_0(
+) -> *mut wire_DecryptRecoveryBundleRequest {
+    support::new_leak_box_ptr(wire_DecryptRecoveryBundleRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_denomination_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_derive_encryption_key_request_0(
+) -> *mut wire_DeriveEncryptionKeyRequest {
+    support::new_leak_box_ptr(wire_DeriveEncryptionKeyRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_encrypted_payment_request_data_0(
+) -> *mut wire_EncryptedPaymentRequestData {
+    support::new_leak_box_ptr(wire_EncryptedPaymentRequestData::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_export_payments_request_0() -> *mut wire_ExportPaymentsRequest {
+    support::new_leak_box_ptr(wire_ExportPaymentsRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_export_recovery_bundle_request_0(
+) -> *mut wire_ExportRecoveryBundleRequest {
+    support::new_leak_box_ptr(wire_ExportRecoveryBundleRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_export_swap_refund_keys_request_0(
+) -> *mut wire_ExportSwapRefundKeysRequest {
+    support::new_leak_box_ptr(wire_ExportSwapRefundKeysRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_f64_0(value: f64) -> *mut f64 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_feerate_preset_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_fetch_buy_bitcoin_quote_request_0(
+) -> *mut wire_FetchBuyBitcoinQuoteRequest {
+    support::new_leak_box_ptr(wire_FetchBuyBitcoinQuoteRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_fiat_amount_0() -> *mut wire_FiatAmount {
+    support::new_leak_box_ptr(wire_FiatAmount::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_greenlight_credentials_0() -> *mut wire_GreenlightCredentials {
+    support::new_leak_box_ptr(wire_GreenlightCredentials::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_greenlight_node_config_0() -> *mut wire_GreenlightNodeConfig {
+    support::new_leak_box_ptr(wire_GreenlightNodeConfig::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_htlc_exposure_limits_0() -> *mut wire_HtlcExposureLimits {
+    support::new_leak_box_ptr(wire_HtlcExposureLimits::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_i64_0(value: i64) -> *mut i64 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_import_payments_request_0() -> *mut wire_ImportPaymentsRequest {
+    support::new_leak_box_ptr(wire_ImportPaymentsRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_list_payment_groups_request_0(
+) -> *mut wire_ListPaymentGroupsRequest {
+    support::new_leak_box_ptr(wire_ListPaymentGroupsRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_list_payments_request_0() -> *mut wire_ListPaymentsRequest {
+    support::new_leak_box_ptr(wire_ListPaymentsRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_list_swaps_request_0() -> *mut wire_ListSwapsRequest {
+    support::new_leak_box_ptr(wire_ListSwapsRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_ln_url_auth_export_0() -> *mut wire_LnUrlAuthExport {
+    support::new_leak_box_ptr(wire_LnUrlAuthExport::new_with_null_ptr())
+}
 
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_ln_url_auth_request_data_0() -> *mut wire_LnUrlAuthRequestData {
@@ -386,32 +920,84 @@ pub extern "C" fn new_box_autoadd_ln_url_withdraw_request_0() -> *mut wire_LnUrl
     support::new_leak_box_ptr(wire_LnUrlWithdrawRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_lsp_policy_0() -> *mut wire_LspPolicy {
+    support::new_leak_box_ptr(wire_LspPolicy::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_milli_sat_0() -> *mut wire_MilliSat {
+    support::new_leak_box_ptr(wire_MilliSat::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_mock_node_config_0() -> *mut wire_MockNodeConfig {
+    support::new_leak_box_ptr(wire_MockNodeConfig::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_node_config_0() -> *mut wire_NodeConfig {
     support::new_leak_box_ptr(wire_NodeConfig::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_node_migration_request_0() -> *mut wire_NodeMigrationRequest {
+    support::new_leak_box_ptr(wire_NodeMigrationRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_open_channel_fee_request_0() -> *mut wire_OpenChannelFeeRequest {
     support::new_leak_box_ptr(wire_OpenChannelFeeRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_open_channel_request_0() -> *mut wire_OpenChannelRequest {
+    support::new_leak_box_ptr(wire_OpenChannelRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_opening_fee_params_0() -> *mut wire_OpeningFeeParams {
     support::new_leak_box_ptr(wire_OpeningFeeParams::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_partner_fee_config_0() -> *mut wire_PartnerFeeConfig {
+    support::new_leak_box_ptr(wire_PartnerFeeConfig::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_pay_offer_request_0() -> *mut wire_PayOfferRequest {
+    support::new_leak_box_ptr(wire_PayOfferRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_pay_onchain_address_request_0(
+) -> *mut wire_PayOnchainAddressRequest {
+    support::new_leak_box_ptr(wire_PayOnchainAddressRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_pay_onchain_request_0() -> *mut wire_PayOnchainRequest {
     support::new_leak_box_ptr(wire_PayOnchainRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_payment_request_bundle_0() -> *mut wire_PaymentRequestBundle {
+    support::new_leak_box_ptr(wire_PaymentRequestBundle::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_prepare_onchain_payment_request_0(
 ) -> *mut wire_PrepareOnchainPaymentRequest {
     support::new_leak_box_ptr(wire_PrepareOnchainPaymentRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_prepare_receive_payment_request_0(
+) -> *mut wire_PrepareReceivePaymentRequest {
+    support::new_leak_box_ptr(wire_PrepareReceivePaymentRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_prepare_redeem_onchain_funds_request_0(
 ) -> *mut wire_PrepareRedeemOnchainFundsRequest {
@@ -423,6 +1009,33 @@ pub extern "C" fn new_box_autoadd_prepare_refund_request_0() -> *mut wire_Prepar
     support::new_leak_box_ptr(wire_PrepareRefundRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_prepare_send_payment_request_0(
+) -> *mut wire_PrepareSendPaymentRequest {
+    support::new_leak_box_ptr(wire_PrepareSendPaymentRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_probe_payment_request_0() -> *mut wire_ProbePaymentRequest {
+    support::new_leak_box_ptr(wire_ProbePaymentRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_prove_address_ownership_request_0(
+) -> *mut wire_ProveAddressOwnershipRequest {
+    support::new_leak_box_ptr(wire_ProveAddressOwnershipRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_proxy_config_0() -> *mut wire_ProxyConfig {
+    support::new_leak_box_ptr(wire_ProxyConfig::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_queue_payment_request_0() -> *mut wire_QueuePaymentRequest {
+    support::new_leak_box_ptr(wire_QueuePaymentRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_receive_onchain_request_0() -> *mut wire_ReceiveOnchainRequest {
     support::new_leak_box_ptr(wire_ReceiveOnchainRequest::new_with_null_ptr())
@@ -433,6 +1046,17 @@ pub extern "C" fn new_box_autoadd_receive_payment_request_0() -> *mut wire_Recei
     support::new_leak_box_ptr(wire_ReceivePaymentRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_receive_unified_request_0() -> *mut wire_ReceiveUnifiedRequest {
+    support::new_leak_box_ptr(wire_ReceiveUnifiedRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0(
+) -> *mut wire_RedeemLnurlWithdrawVoucherRequest {
+    support::new_leak_box_ptr(wire_RedeemLnurlWithdrawVoucherRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_redeem_onchain_funds_request_0(
 ) -> *mut wire_RedeemOnchainFundsRequest {
@@ -461,11 +1085,21 @@ pub extern "C" fn new_box_autoadd_reverse_swap_fees_request_0() -> *mut wire_Rev
     support::new_leak_box_ptr(wire_ReverseSwapFeesRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_sat_0() -> *mut wire_Sat {
+    support::new_leak_box_ptr(wire_Sat::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_send_payment_request_0() -> *mut wire_SendPaymentRequest {
     support::new_leak_box_ptr(wire_SendPaymentRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_send_payments_request_0() -> *mut wire_SendPaymentsRequest {
+    support::new_leak_box_ptr(wire_SendPaymentsRequest::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_send_spontaneous_payment_request_0(
 ) -> *mut wire_SendSpontaneousPaymentRequest {
@@ -477,11 +1111,32 @@ pub extern "C" fn new_box_autoadd_sign_message_request_0() -> *mut wire_SignMess
     support::new_leak_box_ptr(wire_SignMessageRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_sort_order_0(value: i32) -> *mut i32 {
+    support::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_spend_policy_0() -> *mut wire_SpendPolicy {
+    support::new_leak_box_ptr(wire_SpendPolicy::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_static_backup_request_0() -> *mut wire_StaticBackupRequest {
     support::new_leak_box_ptr(wire_StaticBackupRequest::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_static_lnurl_pay_callback_request_0(
+) -> *mut wire_StaticLnurlPayCallbackRequest {
+    support::new_leak_box_ptr(wire_StaticLnurlPayCallbackRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_u16_0(value: u16) -> *mut u16 {
+    support::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_u32_0(value: u32) -> *mut u32 {
     support::new_leak_box_ptr(value)
@@ -492,6 +1147,26 @@ pub extern "C" fn new_box_autoadd_u64_0(value: u64) -> *mut u64 {
     support::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_user_settings_0() -> *mut wire_UserSettings {
+    support::new_leak_box_ptr(wire_UserSettings::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_validate_payability_request_0(
+) -> *mut wire_ValidatePayabilityRequest {
+    support::new_leak_box_ptr(wire_ValidatePayabilityRequest::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_ln_url_auth_domain_0(len: i32) -> *mut wire_list_ln_url_auth_domain {
+    let wrap = wire_list_ln_url_auth_domain {
+        ptr: support::new_leak_vec_ptr(<wire_LnUrlAuthDomain>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_metadata_filter_0(len: i32) -> *mut wire_list_metadata_filter {
     let wrap = wire_list_metadata_filter {
@@ -510,6 +1185,33 @@ pub extern "C" fn new_list_payment_type_filter_0(len: i32) -> *mut wire_list_pay
     support::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn new_list_route_hint_0(len: i32) -> *mut wire_list_route_hint {
+    let wrap = wire_list_route_hint {
+        ptr: support::new_leak_vec_ptr(<wire_RouteHint>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_route_hint_hop_0(len: i32) -> *mut wire_list_route_hint_hop {
+    let wrap = wire_list_route_hint_hop {
+        ptr: support::new_leak_vec_ptr(<wire_RouteHintHop>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn new_list_send_payment_request_0(len: i32) -> *mut wire_list_send_payment_request {
+    let wrap = wire_list_send_payment_request {
+        ptr: support::new_leak_vec_ptr(<wire_SendPaymentRequest>::new_with_null_ptr(), len),
+        len,
+    };
+    support::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn new_list_swap_status_0(len: i32) -> *mut wire_list_swap_status {
     let wrap = wire_list_swap_status {
@@ -547,34 +1249,228 @@ impl Wire2Api<String> for *mut wire_uint_8_list {
         String::from_utf8_lossy(&vec).into_owned()
     }
 }
-
-impl Wire2Api<bool> for *mut bool {
-    fn wire2api(self) -> bool {
-        unsafe { *support::box_from_leak_ptr(self) }
+impl Wire2Api<Vec<String>> for *mut wire_StringList {
+    fn wire2api(self) -> Vec<String> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
-impl Wire2Api<BuyBitcoinRequest> for *mut wire_BuyBitcoinRequest {
-    fn wire2api(self) -> BuyBitcoinRequest {
-        let wrap = unsafe { support::box_from_leak_ptr(self) };
-        Wire2Api::<BuyBitcoinRequest>::wire2api(*wrap).into()
+impl Wire2Api<AddFiatRateAlertRequest> for wire_AddFiatRateAlertRequest {
+    fn wire2api(self) -> AddFiatRateAlertRequest {
+        AddFiatRateAlertRequest {
+            currency: self.currency.wire2api(),
+            direction: self.direction.wire2api(),
+            threshold: self.threshold.wire2api(),
+        }
     }
 }
-impl Wire2Api<CheckMessageRequest> for *mut wire_CheckMessageRequest {
-    fn wire2api(self) -> CheckMessageRequest {
-        let wrap = unsafe { support::box_from_leak_ptr(self) };
-        Wire2Api::<CheckMessageRequest>::wire2api(*wrap).into()
+impl Wire2Api<AmendInvoiceRequest> for wire_AmendInvoiceRequest {
+    fn wire2api(self) -> AmendInvoiceRequest {
+        AmendInvoiceRequest {
+            bolt11: self.bolt11.wire2api(),
+            amount_msat: self.amount_msat.wire2api(),
+            expiry: self.expiry.wire2api(),
+            route_hints: self.route_hints.wire2api(),
+        }
     }
 }
-impl Wire2Api<ConfigureNodeRequest> for *mut wire_ConfigureNodeRequest {
-    fn wire2api(self) -> ConfigureNodeRequest {
+impl Wire2Api<AutoRefundConfig> for wire_AutoRefundConfig {
+    fn wire2api(self) -> AutoRefundConfig {
+        AutoRefundConfig {
+            to_address: self.to_address.wire2api(),
+            sat_per_vbyte: self.sat_per_vbyte.wire2api(),
+        }
+    }
+}
+impl Wire2Api<BackupTransportConfig> for wire_BackupTransportConfig {
+    fn wire2api(self) -> BackupTransportConfig {
+        match self.tag {
+            0 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.S3);
+                BackupTransportConfig::S3 {
+                    endpoint: ans.endpoint.wire2api(),
+                    region: ans.region.wire2api(),
+                    bucket: ans.bucket.wire2api(),
+                    access_key_id: ans.access_key_id.wire2api(),
+                    secret_access_key: ans.secret_access_key.wire2api(),
+                }
+            },
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.WebDav);
+                BackupTransportConfig::WebDav {
+                    url: ans.url.wire2api(),
+                    username: ans.username.wire2api(),
+                    password: ans.password.wire2api(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Wire2Api<AddFiatRateAlertRequest> for *mut wire_AddFiatRateAlertRequest {
+    fn wire2api(self) -> AddFiatRateAlertRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        Wire2Api::<ConfigureNodeRequest>::wire2api(*wrap).into()
+        Wire2Api::<AddFiatRateAlertRequest>::wire2api(*wrap).into()
     }
 }
-impl Wire2Api<ConnectRequest> for *mut wire_ConnectRequest {
-    fn wire2api(self) -> ConnectRequest {
+impl Wire2Api<AmendInvoiceRequest> for *mut wire_AmendInvoiceRequest {
+    fn wire2api(self) -> AmendInvoiceRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
-        Wire2Api::<ConnectRequest>::wire2api(*wrap).into()
+        Wire2Api::<AmendInvoiceRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<AutoRefundConfig> for *mut wire_AutoRefundConfig {
+    fn wire2api(self) -> AutoRefundConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<AutoRefundConfig>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<BackupTransportConfig> for *mut wire_BackupTransportConfig {
+    fn wire2api(self) -> BackupTransportConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<BackupTransportConfig>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<bool> for *mut bool {
+    fn wire2api(self) -> bool {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
+impl Wire2Api<BumpFeeRequest> for *mut wire_BumpFeeRequest {
+    fn wire2api(self) -> BumpFeeRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<BumpFeeRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<BuyBitcoinRequest> for *mut wire_BuyBitcoinRequest {
+    fn wire2api(self) -> BuyBitcoinRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<BuyBitcoinRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ChainServiceConfig> for *mut wire_ChainServiceConfig {
+    fn wire2api(self) -> ChainServiceConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ChainServiceConfig>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<CheckMessageRequest> for *mut wire_CheckMessageRequest {
+    fn wire2api(self) -> CheckMessageRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<CheckMessageRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<CloseChannelRequest> for *mut wire_CloseChannelRequest {
+    fn wire2api(self) -> CloseChannelRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<CloseChannelRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<Config> for *mut wire_Config {
+    fn wire2api(self) -> Config {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Config>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ConfigureNodeRequest> for *mut wire_ConfigureNodeRequest {
+    fn wire2api(self) -> ConfigureNodeRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ConfigureNodeRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ConnectPeerRequest> for *mut wire_ConnectPeerRequest {
+    fn wire2api(self) -> ConnectPeerRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ConnectPeerRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ConnectRequest> for *mut wire_ConnectRequest {
+    fn wire2api(self) -> ConnectRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ConnectRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<CreateLnurlWithdrawVoucherRequest> for *mut wire_CreateLnurlWithdrawVoucherRequest {
+    fn wire2api(self) -> CreateLnurlWithdrawVoucherRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<CreateLnurlWithdrawVoucherRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<CreateStaticLnurlPayRequest> for *mut wire_CreateStaticLnurlPayRequest {
+    fn wire2api(self) -> CreateStaticLnurlPayRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<CreateStaticLnurlPayRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<DecryptRecoveryBundleRequest> for *mut wire_DecryptRecoveryBundleRequest {
+    fn wire2api(self) -> DecryptRecoveryBundleRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<DecryptRecoveryBundleRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<Denomination> for *mut i32 {
+    fn wire2api(self) -> Denomination {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Denomination>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<DeriveEncryptionKeyRequest> for *mut wire_DeriveEncryptionKeyRequest {
+    fn wire2api(self) -> DeriveEncryptionKeyRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<DeriveEncryptionKeyRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<EncryptedPaymentRequestData> for *mut wire_EncryptedPaymentRequestData {
+    fn wire2api(self) -> EncryptedPaymentRequestData {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<EncryptedPaymentRequestData>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ExportPaymentsRequest> for *mut wire_ExportPaymentsRequest {
+    fn wire2api(self) -> ExportPaymentsRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ExportPaymentsRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ExportRecoveryBundleRequest> for *mut wire_ExportRecoveryBundleRequest {
+    fn wire2api(self) -> ExportRecoveryBundleRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ExportRecoveryBundleRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ExportSwapRefundKeysRequest> for *mut wire_ExportSwapRefundKeysRequest {
+    fn wire2api(self) -> ExportSwapRefundKeysRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ExportSwapRefundKeysRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<f64> for *mut f64 {
+    fn wire2api(self) -> f64 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
+impl Wire2Api<FeeratePreset> for *mut i32 {
+    fn wire2api(self) -> FeeratePreset {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<FeeratePreset>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<FetchBuyBitcoinQuoteRequest> for *mut wire_FetchBuyBitcoinQuoteRequest {
+    fn wire2api(self) -> FetchBuyBitcoinQuoteRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<FetchBuyBitcoinQuoteRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<FiatAmount> for *mut wire_FiatAmount {
+    fn wire2api(self) -> FiatAmount {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<FiatAmount>::wire2api(*wrap).into()
     }
 }
 impl Wire2Api<GreenlightCredentials> for *mut wire_GreenlightCredentials {
@@ -589,11 +1485,29 @@ impl Wire2Api<GreenlightNodeConfig> for *mut wire_GreenlightNodeConfig {
         Wire2Api::<GreenlightNodeConfig>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<HtlcExposureLimits> for *mut wire_HtlcExposureLimits {
+    fn wire2api(self) -> HtlcExposureLimits {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<HtlcExposureLimits>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<i64> for *mut i64 {
     fn wire2api(self) -> i64 {
         unsafe { *support::box_from_leak_ptr(self) }
     }
 }
+impl Wire2Api<ImportPaymentsRequest> for *mut wire_ImportPaymentsRequest {
+    fn wire2api(self) -> ImportPaymentsRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ImportPaymentsRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ListPaymentGroupsRequest> for *mut wire_ListPaymentGroupsRequest {
+    fn wire2api(self) -> ListPaymentGroupsRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ListPaymentGroupsRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<ListPaymentsRequest> for *mut wire_ListPaymentsRequest {
     fn wire2api(self) -> ListPaymentsRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -606,6 +1520,12 @@ impl Wire2Api<ListSwapsRequest> for *mut wire_ListSwapsRequest {
         Wire2Api::<ListSwapsRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<LnUrlAuthExport> for *mut wire_LnUrlAuthExport {
+    fn wire2api(self) -> LnUrlAuthExport {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<LnUrlAuthExport>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<LnUrlAuthRequestData> for *mut wire_LnUrlAuthRequestData {
     fn wire2api(self) -> LnUrlAuthRequestData {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -624,36 +1544,96 @@ impl Wire2Api<LnUrlWithdrawRequest> for *mut wire_LnUrlWithdrawRequest {
         Wire2Api::<LnUrlWithdrawRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<LspPolicy> for *mut wire_LspPolicy {
+    fn wire2api(self) -> LspPolicy {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<LspPolicy>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<MilliSat> for *mut wire_MilliSat {
+    fn wire2api(self) -> MilliSat {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<MilliSat>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<MockNodeConfig> for *mut wire_MockNodeConfig {
+    fn wire2api(self) -> MockNodeConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<MockNodeConfig>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<NodeConfig> for *mut wire_NodeConfig {
     fn wire2api(self) -> NodeConfig {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<NodeConfig>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<NodeMigrationRequest> for *mut wire_NodeMigrationRequest {
+    fn wire2api(self) -> NodeMigrationRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<NodeMigrationRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<OpenChannelFeeRequest> for *mut wire_OpenChannelFeeRequest {
     fn wire2api(self) -> OpenChannelFeeRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<OpenChannelFeeRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<OpenChannelRequest> for *mut wire_OpenChannelRequest {
+    fn wire2api(self) -> OpenChannelRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<OpenChannelRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<OpeningFeeParams> for *mut wire_OpeningFeeParams {
     fn wire2api(self) -> OpeningFeeParams {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<OpeningFeeParams>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PartnerFeeConfig> for *mut wire_PartnerFeeConfig {
+    fn wire2api(self) -> PartnerFeeConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PartnerFeeConfig>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<PayOfferRequest> for *mut wire_PayOfferRequest {
+    fn wire2api(self) -> PayOfferRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PayOfferRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<PayOnchainAddressRequest> for *mut wire_PayOnchainAddressRequest {
+    fn wire2api(self) -> PayOnchainAddressRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PayOnchainAddressRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<PayOnchainRequest> for *mut wire_PayOnchainRequest {
     fn wire2api(self) -> PayOnchainRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<PayOnchainRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PaymentRequestBundle> for *mut wire_PaymentRequestBundle {
+    fn wire2api(self) -> PaymentRequestBundle {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PaymentRequestBundle>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<PrepareOnchainPaymentRequest> for *mut wire_PrepareOnchainPaymentRequest {
     fn wire2api(self) -> PrepareOnchainPaymentRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<PrepareOnchainPaymentRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PrepareReceivePaymentRequest> for *mut wire_PrepareReceivePaymentRequest {
+    fn wire2api(self) -> PrepareReceivePaymentRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PrepareReceivePaymentRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<PrepareRedeemOnchainFundsRequest> for *mut wire_PrepareRedeemOnchainFundsRequest {
     fn wire2api(self) -> PrepareRedeemOnchainFundsRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -666,6 +1646,36 @@ impl Wire2Api<PrepareRefundRequest> for *mut wire_PrepareRefundRequest {
         Wire2Api::<PrepareRefundRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<PrepareSendPaymentRequest> for *mut wire_PrepareSendPaymentRequest {
+    fn wire2api(self) -> PrepareSendPaymentRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<PrepareSendPaymentRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ProbePaymentRequest> for *mut wire_ProbePaymentRequest {
+    fn wire2api(self) -> ProbePaymentRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ProbePaymentRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ProveAddressOwnershipRequest> for *mut wire_ProveAddressOwnershipRequest {
+    fn wire2api(self) -> ProveAddressOwnershipRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ProveAddressOwnershipRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ProxyConfig> for *mut wire_ProxyConfig {
+    fn wire2api(self) -> ProxyConfig {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ProxyConfig>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<QueuePaymentRequest> for *mut wire_QueuePaymentRequest {
+    fn wire2api(self) -> QueuePaymentRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<QueuePaymentRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<ReceiveOnchainRequest> for *mut wire_ReceiveOnchainRequest {
     fn wire2api(self) -> ReceiveOnchainRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -678,6 +1688,18 @@ impl Wire2Api<ReceivePaymentRequest> for *mut wire_ReceivePaymentRequest {
         Wire2Api::<ReceivePaymentRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<ReceiveUnifiedRequest> for *mut wire_ReceiveUnifiedRequest {
+    fn wire2api(self) -> ReceiveUnifiedRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ReceiveUnifiedRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<RedeemLnurlWithdrawVoucherRequest> for *mut wire_RedeemLnurlWithdrawVoucherRequest {
+    fn wire2api(self) -> RedeemLnurlWithdrawVoucherRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<RedeemLnurlWithdrawVoucherRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<RedeemOnchainFundsRequest> for *mut wire_RedeemOnchainFundsRequest {
     fn wire2api(self) -> RedeemOnchainFundsRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -708,12 +1730,24 @@ impl Wire2Api<ReverseSwapFeesRequest> for *mut wire_ReverseSwapFeesRequest {
         Wire2Api::<ReverseSwapFeesRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<Sat> for *mut wire_Sat {
+    fn wire2api(self) -> Sat {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<Sat>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<SendPaymentRequest> for *mut wire_SendPaymentRequest {
     fn wire2api(self) -> SendPaymentRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<SendPaymentRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<SendPaymentsRequest> for *mut wire_SendPaymentsRequest {
+    fn wire2api(self) -> SendPaymentsRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<SendPaymentsRequest>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<SendSpontaneousPaymentRequest> for *mut wire_SendSpontaneousPaymentRequest {
     fn wire2api(self) -> SendSpontaneousPaymentRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -726,12 +1760,35 @@ impl Wire2Api<SignMessageRequest> for *mut wire_SignMessageRequest {
         Wire2Api::<SignMessageRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<SortOrder> for *mut i32 {
+    fn wire2api(self) -> SortOrder {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<SortOrder>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<SpendPolicy> for *mut wire_SpendPolicy {
+    fn wire2api(self) -> SpendPolicy {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<SpendPolicy>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<StaticBackupRequest> for *mut wire_StaticBackupRequest {
     fn wire2api(self) -> StaticBackupRequest {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
         Wire2Api::<StaticBackupRequest>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<StaticLnurlPayCallbackRequest> for *mut wire_StaticLnurlPayCallbackRequest {
+    fn wire2api(self) -> StaticLnurlPayCallbackRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<StaticLnurlPayCallbackRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<u16> for *mut u16 {
+    fn wire2api(self) -> u16 {
+        unsafe { *support::box_from_leak_ptr(self) }
+    }
+}
 impl Wire2Api<u32> for *mut u32 {
     fn wire2api(self) -> u32 {
         unsafe { *support::box_from_leak_ptr(self) }
@@ -742,6 +1799,26 @@ impl Wire2Api<u64> for *mut u64 {
         unsafe { *support::box_from_leak_ptr(self) }
     }
 }
+impl Wire2Api<UserSettings> for *mut wire_UserSettings {
+    fn wire2api(self) -> UserSettings {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<UserSettings>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<ValidatePayabilityRequest> for *mut wire_ValidatePayabilityRequest {
+    fn wire2api(self) -> ValidatePayabilityRequest {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<ValidatePayabilityRequest>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<BumpFeeRequest> for wire_BumpFeeRequest {
+    fn wire2api(self) -> BumpFeeRequest {
+        BumpFeeRequest {
+            txid: self.txid.wire2api(),
+            sat_per_vbyte: self.sat_per_vbyte.wire2api(),
+        }
+    }
+}
 
 impl Wire2Api<BuyBitcoinRequest> for wire_BuyBitcoinRequest {
     fn wire2api(self) -> BuyBitcoinRequest {
@@ -752,6 +1829,27 @@ impl Wire2Api<BuyBitcoinRequest> for wire_BuyBitcoinRequest {
         }
     }
 }
+impl Wire2Api<ChainServiceConfig> for wire_ChainServiceConfig {
+    fn wire2api(self) -> ChainServiceConfig {
+        match self.tag {
+            0 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Esplora);
+                ChainServiceConfig::Esplora {
+                    url: ans.url.wire2api(),
+                }
+            },
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Electrum);
+                ChainServiceConfig::Electrum {
+                    url: ans.url.wire2api(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
 impl Wire2Api<CheckMessageRequest> for wire_CheckMessageRequest {
     fn wire2api(self) -> CheckMessageRequest {
         CheckMessageRequest {
@@ -761,19 +1859,53 @@ impl Wire2Api<CheckMessageRequest> for wire_CheckMessageRequest {
         }
     }
 }
+impl Wire2Api<CloseChannelRequest> for wire_CloseChannelRequest {
+    fn wire2api(self) -> CloseChannelRequest {
+        CloseChannelRequest {
+            channel_id: self.channel_id.wire2api(),
+            force: self.force.wire2api(),
+        }
+    }
+}
 impl Wire2Api<Config> for wire_Config {
     fn wire2api(self) -> Config {
         Config {
             breezserver: self.breezserver.wire2api(),
             chainnotifier_url: self.chainnotifier_url.wire2api(),
+            lnurl_server_url: self.lnurl_server_url.wire2api(),
             mempoolspace_url: self.mempoolspace_url.wire2api(),
+            chain_service: self.chain_service.wire2api(),
+            backup_transport: self.backup_transport.wire2api(),
+            proxy: self.proxy.wire2api(),
             working_dir: self.working_dir.wire2api(),
+            db_dir: self.db_dir.wire2api(),
+            credentials_dir: self.credentials_dir.wire2api(),
             network: self.network.wire2api(),
             payment_timeout_sec: self.payment_timeout_sec.wire2api(),
+            max_concurrent_payments: self.max_concurrent_payments.wire2api(),
             default_lsp_id: self.default_lsp_id.wire2api(),
+            lsp_policy: self.lsp_policy.wire2api(),
             api_key: self.api_key.wire2api(),
             maxfee_percent: self.maxfee_percent.wire2api(),
             exemptfee_msat: self.exemptfee_msat.wire2api(),
+            fiat_currency: self.fiat_currency.wire2api(),
+            low_outbound_liquidity_threshold_msat: self
+                .low_outbound_liquidity_threshold_msat
+                .wire2api(),
+            low_inbound_liquidity_threshold_msat: self
+                .low_inbound_liquidity_threshold_msat
+                .wire2api(),
+            use_trampoline: self.use_trampoline.wire2api(),
+            partner_fee: self.partner_fee.wire2api(),
+            swap_auto_refund: self.swap_auto_refund.wire2api(),
+            memo_privacy: self.memo_privacy.wire2api(),
+            channel_hygiene_window_secs: self.channel_hygiene_window_secs.wire2api(),
+            log_filter: self.log_filter.wire2api(),
+            denylist_url: self.denylist_url.wire2api(),
+            transak_api_key: self.transak_api_key.wire2api(),
+            spend_policy: self.spend_policy.wire2api(),
+            htlc_exposure_limits: self.htlc_exposure_limits.wire2api(),
+            service_status_check_interval_secs: self.service_status_check_interval_secs.wire2api(),
             node_config: self.node_config.wire2api(),
         }
     }
@@ -785,12 +1917,110 @@ impl Wire2Api<ConfigureNodeRequest> for wire_ConfigureNodeRequest {
         }
     }
 }
+impl Wire2Api<ConnectPeerRequest> for wire_ConnectPeerRequest {
+    fn wire2api(self) -> ConnectPeerRequest {
+        ConnectPeerRequest {
+            node_uri: self.node_uri.wire2api(),
+        }
+    }
+}
 impl Wire2Api<ConnectRequest> for wire_ConnectRequest {
     fn wire2api(self) -> ConnectRequest {
         ConnectRequest {
             config: self.config.wire2api(),
             seed: self.seed.wire2api(),
             restore_only: self.restore_only.wire2api(),
+            register_if_missing: self.register_if_missing.wire2api(),
+        }
+    }
+}
+impl Wire2Api<CreateLnurlWithdrawVoucherRequest> for wire_CreateLnurlWithdrawVoucherRequest {
+    fn wire2api(self) -> CreateLnurlWithdrawVoucherRequest {
+        CreateLnurlWithdrawVoucherRequest {
+            amount_msat: self.amount_msat.wire2api(),
+            uses: self.uses.wire2api(),
+            expiry_secs: self.expiry_secs.wire2api(),
+            description: self.description.wire2api(),
+        }
+    }
+}
+impl Wire2Api<CreateStaticLnurlPayRequest> for wire_CreateStaticLnurlPayRequest {
+    fn wire2api(self) -> CreateStaticLnurlPayRequest {
+        CreateStaticLnurlPayRequest {
+            url: self.url.wire2api(),
+            callback_url: self.callback_url.wire2api(),
+            description: self.description.wire2api(),
+            min_sendable_msat: self.min_sendable_msat.wire2api(),
+            max_sendable_msat: self.max_sendable_msat.wire2api(),
+            comment_allowed: self.comment_allowed.wire2api(),
+        }
+    }
+}
+impl Wire2Api<DecryptRecoveryBundleRequest> for wire_DecryptRecoveryBundleRequest {
+    fn wire2api(self) -> DecryptRecoveryBundleRequest {
+        DecryptRecoveryBundleRequest {
+            bundle: self.bundle.wire2api(),
+            private_key: self.private_key.wire2api(),
+        }
+    }
+}
+
+impl Wire2Api<DeriveEncryptionKeyRequest> for wire_DeriveEncryptionKeyRequest {
+    fn wire2api(self) -> DeriveEncryptionKeyRequest {
+        DeriveEncryptionKeyRequest {
+            purpose: self.purpose.wire2api(),
+            index: self.index.wire2api(),
+        }
+    }
+}
+impl Wire2Api<EncryptedPaymentRequestData> for wire_EncryptedPaymentRequestData {
+    fn wire2api(self) -> EncryptedPaymentRequestData {
+        EncryptedPaymentRequestData {
+            blob: self.blob.wire2api(),
+        }
+    }
+}
+
+impl Wire2Api<ExportPaymentsRequest> for wire_ExportPaymentsRequest {
+    fn wire2api(self) -> ExportPaymentsRequest {
+        ExportPaymentsRequest {
+            format: self.format.wire2api(),
+            from_timestamp: self.from_timestamp.wire2api(),
+            to_timestamp: self.to_timestamp.wire2api(),
+            include_fiat: self.include_fiat.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ExportRecoveryBundleRequest> for wire_ExportRecoveryBundleRequest {
+    fn wire2api(self) -> ExportRecoveryBundleRequest {
+        ExportRecoveryBundleRequest {
+            recipient_pubkey: self.recipient_pubkey.wire2api(),
+            instructions: self.instructions.wire2api(),
+            file_path: self.file_path.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ExportSwapRefundKeysRequest> for wire_ExportSwapRefundKeysRequest {
+    fn wire2api(self) -> ExportSwapRefundKeysRequest {
+        ExportSwapRefundKeysRequest {
+            include_gap_keys: self.include_gap_keys.wire2api(),
+        }
+    }
+}
+
+impl Wire2Api<FetchBuyBitcoinQuoteRequest> for wire_FetchBuyBitcoinQuoteRequest {
+    fn wire2api(self) -> FetchBuyBitcoinQuoteRequest {
+        FetchBuyBitcoinQuoteRequest {
+            provider: self.provider.wire2api(),
+            fiat_amount: self.fiat_amount.wire2api(),
+        }
+    }
+}
+impl Wire2Api<FiatAmount> for wire_FiatAmount {
+    fn wire2api(self) -> FiatAmount {
+        FiatAmount {
+            currency: self.currency.wire2api(),
+            amount: self.amount.wire2api(),
         }
     }
 }
@@ -811,9 +2041,25 @@ impl Wire2Api<GreenlightNodeConfig> for wire_GreenlightNodeConfig {
         }
     }
 }
+impl Wire2Api<HtlcExposureLimits> for wire_HtlcExposureLimits {
+    fn wire2api(self) -> HtlcExposureLimits {
+        HtlcExposureLimits {
+            max_inflight_msat: self.max_inflight_msat.wire2api(),
+            max_htlcs_per_payment: self.max_htlcs_per_payment.wire2api(),
+        }
+    }
+}
 
-impl Wire2Api<Vec<MetadataFilter>> for *mut wire_list_metadata_filter {
-    fn wire2api(self) -> Vec<MetadataFilter> {
+impl Wire2Api<ImportPaymentsRequest> for wire_ImportPaymentsRequest {
+    fn wire2api(self) -> ImportPaymentsRequest {
+        ImportPaymentsRequest {
+            format: self.format.wire2api(),
+            data: self.data.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Vec<LnUrlAuthDomain>> for *mut wire_list_ln_url_auth_domain {
+    fn wire2api(self) -> Vec<LnUrlAuthDomain> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -821,8 +2067,8 @@ impl Wire2Api<Vec<MetadataFilter>> for *mut wire_list_metadata_filter {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
-impl Wire2Api<Vec<PaymentTypeFilter>> for *mut wire_list_payment_type_filter {
-    fn wire2api(self) -> Vec<PaymentTypeFilter> {
+impl Wire2Api<Vec<MetadataFilter>> for *mut wire_list_metadata_filter {
+    fn wire2api(self) -> Vec<MetadataFilter> {
         let vec = unsafe {
             let wrap = support::box_from_leak_ptr(self);
             support::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -830,8 +2076,26 @@ impl Wire2Api<Vec<PaymentTypeFilter>> for *mut wire_list_payment_type_filter {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
-impl Wire2Api<ListPaymentsRequest> for wire_ListPaymentsRequest {
-    fn wire2api(self) -> ListPaymentsRequest {
+impl Wire2Api<ListPaymentGroupsRequest> for wire_ListPaymentGroupsRequest {
+    fn wire2api(self) -> ListPaymentGroupsRequest {
+        ListPaymentGroupsRequest {
+            group_by: self.group_by.wire2api(),
+            payments: self.payments.wire2api(),
+            limit: self.limit.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Vec<PaymentTypeFilter>> for *mut wire_list_payment_type_filter {
+    fn wire2api(self) -> Vec<PaymentTypeFilter> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<ListPaymentsRequest> for wire_ListPaymentsRequest {
+    fn wire2api(self) -> ListPaymentsRequest {
         ListPaymentsRequest {
             filters: self.filters.wire2api(),
             metadata_filters: self.metadata_filters.wire2api(),
@@ -840,9 +2104,38 @@ impl Wire2Api<ListPaymentsRequest> for wire_ListPaymentsRequest {
             include_failures: self.include_failures.wire2api(),
             offset: self.offset.wire2api(),
             limit: self.limit.wire2api(),
+            include_tlv_hex: self.include_tlv_hex.wire2api(),
+            sort_order: self.sort_order.wire2api(),
         }
     }
 }
+impl Wire2Api<Vec<RouteHint>> for *mut wire_list_route_hint {
+    fn wire2api(self) -> Vec<RouteHint> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<Vec<RouteHintHop>> for *mut wire_list_route_hint_hop {
+    fn wire2api(self) -> Vec<RouteHintHop> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
+impl Wire2Api<Vec<SendPaymentRequest>> for *mut wire_list_send_payment_request {
+    fn wire2api(self) -> Vec<SendPaymentRequest> {
+        let vec = unsafe {
+            let wrap = support::box_from_leak_ptr(self);
+            support::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(Wire2Api::wire2api).collect()
+    }
+}
 impl Wire2Api<Vec<SwapStatus>> for *mut wire_list_swap_status {
     fn wire2api(self) -> Vec<SwapStatus> {
         let vec = unsafe {
@@ -872,6 +2165,25 @@ impl Wire2Api<Vec<TlvEntry>> for *mut wire_list_tlv_entry {
         vec.into_iter().map(Wire2Api::wire2api).collect()
     }
 }
+impl Wire2Api<LnUrlAuthDomain> for wire_LnUrlAuthDomain {
+    fn wire2api(self) -> LnUrlAuthDomain {
+        LnUrlAuthDomain {
+            domain: self.domain.wire2api(),
+            linking_key: self.linking_key.wire2api(),
+            first_authenticated_at: self.first_authenticated_at.wire2api(),
+            last_authenticated_at: self.last_authenticated_at.wire2api(),
+            auth_count: self.auth_count.wire2api(),
+        }
+    }
+}
+impl Wire2Api<LnUrlAuthExport> for wire_LnUrlAuthExport {
+    fn wire2api(self) -> LnUrlAuthExport {
+        LnUrlAuthExport {
+            hashing_key: self.hashing_key.wire2api(),
+            domains: self.domains.wire2api(),
+        }
+    }
+}
 impl Wire2Api<LnUrlAuthRequestData> for wire_LnUrlAuthRequestData {
     fn wire2api(self) -> LnUrlAuthRequestData {
         LnUrlAuthRequestData {
@@ -891,6 +2203,9 @@ impl Wire2Api<LnUrlPayRequest> for wire_LnUrlPayRequest {
             comment: self.comment.wire2api(),
             payment_label: self.payment_label.wire2api(),
             validate_success_action_url: self.validate_success_action_url.wire2api(),
+            pending_timeout_sec: self.pending_timeout_sec.wire2api(),
+            cancel_token: self.cancel_token.wire2api(),
+            skip_denylist: self.skip_denylist.wire2api(),
         }
     }
 }
@@ -929,6 +2244,23 @@ impl Wire2Api<LnUrlWithdrawRequestData> for wire_LnUrlWithdrawRequestData {
         }
     }
 }
+impl Wire2Api<LspPolicy> for wire_LspPolicy {
+    fn wire2api(self) -> LspPolicy {
+        match self.tag {
+            0 => LspPolicy::LowestOpeningFee,
+            1 => LspPolicy::LowestProportionalFee,
+            2 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Pinned);
+                LspPolicy::Pinned {
+                    lsp_id: ans.lsp_id.wire2api(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl Wire2Api<MetadataFilter> for wire_MetadataFilter {
     fn wire2api(self) -> MetadataFilter {
         MetadataFilter {
@@ -937,6 +2269,20 @@ impl Wire2Api<MetadataFilter> for wire_MetadataFilter {
         }
     }
 }
+impl Wire2Api<MilliSat> for wire_MilliSat {
+    fn wire2api(self) -> MilliSat {
+        MilliSat(self.field0.wire2api())
+    }
+}
+impl Wire2Api<MockNodeConfig> for wire_MockNodeConfig {
+    fn wire2api(self) -> MockNodeConfig {
+        MockNodeConfig {
+            channels_balance_msat: self.channels_balance_msat.wire2api(),
+            onchain_balance_msat: self.onchain_balance_msat.wire2api(),
+            inbound_liquidity_msat: self.inbound_liquidity_msat.wire2api(),
+        }
+    }
+}
 
 impl Wire2Api<NodeConfig> for wire_NodeConfig {
     fn wire2api(self) -> NodeConfig {
@@ -948,10 +2294,24 @@ impl Wire2Api<NodeConfig> for wire_NodeConfig {
                     config: ans.config.wire2api(),
                 }
             },
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.Mock);
+                NodeConfig::Mock {
+                    config: ans.config.wire2api(),
+                }
+            },
             _ => unreachable!(),
         }
     }
 }
+impl Wire2Api<NodeMigrationRequest> for wire_NodeMigrationRequest {
+    fn wire2api(self) -> NodeMigrationRequest {
+        NodeMigrationRequest {
+            close_channels: self.close_channels.wire2api(),
+        }
+    }
+}
 impl Wire2Api<OpenChannelFeeRequest> for wire_OpenChannelFeeRequest {
     fn wire2api(self) -> OpenChannelFeeRequest {
         OpenChannelFeeRequest {
@@ -960,6 +2320,16 @@ impl Wire2Api<OpenChannelFeeRequest> for wire_OpenChannelFeeRequest {
         }
     }
 }
+impl Wire2Api<OpenChannelRequest> for wire_OpenChannelRequest {
+    fn wire2api(self) -> OpenChannelRequest {
+        OpenChannelRequest {
+            node_id: self.node_id.wire2api(),
+            amount_sat: self.amount_sat.wire2api(),
+            announce: self.announce.wire2api(),
+            sat_per_vbyte: self.sat_per_vbyte.wire2api(),
+        }
+    }
+}
 impl Wire2Api<OpeningFeeParams> for wire_OpeningFeeParams {
     fn wire2api(self) -> OpeningFeeParams {
         OpeningFeeParams {
@@ -973,11 +2343,71 @@ impl Wire2Api<OpeningFeeParams> for wire_OpeningFeeParams {
     }
 }
 
+impl Wire2Api<PartnerFeeConfig> for wire_PartnerFeeConfig {
+    fn wire2api(self) -> PartnerFeeConfig {
+        PartnerFeeConfig {
+            fee_bps: self.fee_bps.wire2api(),
+            destination: self.destination.wire2api(),
+        }
+    }
+}
+impl Wire2Api<PartnerFeeDestination> for wire_PartnerFeeDestination {
+    fn wire2api(self) -> PartnerFeeDestination {
+        match self.tag {
+            0 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.NodeId);
+                PartnerFeeDestination::NodeId {
+                    node_id: ans.node_id.wire2api(),
+                }
+            },
+            1 => unsafe {
+                let ans = support::box_from_leak_ptr(self.kind);
+                let ans = support::box_from_leak_ptr(ans.LightningAddress);
+                PartnerFeeDestination::LightningAddress {
+                    address: ans.address.wire2api(),
+                }
+            },
+            _ => unreachable!(),
+        }
+    }
+}
+impl Wire2Api<PayOfferRequest> for wire_PayOfferRequest {
+    fn wire2api(self) -> PayOfferRequest {
+        PayOfferRequest {
+            offer: self.offer.wire2api(),
+            amount_msat: self.amount_msat.wire2api(),
+            payer_note: self.payer_note.wire2api(),
+            label: self.label.wire2api(),
+        }
+    }
+}
+impl Wire2Api<PayOnchainAddressRequest> for wire_PayOnchainAddressRequest {
+    fn wire2api(self) -> PayOnchainAddressRequest {
+        PayOnchainAddressRequest {
+            address: self.address.wire2api(),
+            amount_sat: self.amount_sat.wire2api(),
+            drain: self.drain.wire2api(),
+            fee_preference: self.fee_preference.wire2api(),
+        }
+    }
+}
 impl Wire2Api<PayOnchainRequest> for wire_PayOnchainRequest {
     fn wire2api(self) -> PayOnchainRequest {
         PayOnchainRequest {
             recipient_address: self.recipient_address.wire2api(),
             prepare_res: self.prepare_res.wire2api(),
+            cancel_token: self.cancel_token.wire2api(),
+        }
+    }
+}
+
+impl Wire2Api<PaymentRequestBundle> for wire_PaymentRequestBundle {
+    fn wire2api(self) -> PaymentRequestBundle {
+        PaymentRequestBundle {
+            invoice: self.invoice.wire2api(),
+            fallback: self.fallback.wire2api(),
+            metadata: self.metadata.wire2api(),
         }
     }
 }
@@ -1004,11 +2434,21 @@ impl Wire2Api<PrepareOnchainPaymentResponse> for wire_PrepareOnchainPaymentRespo
         }
     }
 }
+impl Wire2Api<PrepareReceivePaymentRequest> for wire_PrepareReceivePaymentRequest {
+    fn wire2api(self) -> PrepareReceivePaymentRequest {
+        PrepareReceivePaymentRequest {
+            amount_msat: self.amount_msat.wire2api(),
+            opening_fee_params: self.opening_fee_params.wire2api(),
+            expiry: self.expiry.wire2api(),
+        }
+    }
+}
 impl Wire2Api<PrepareRedeemOnchainFundsRequest> for wire_PrepareRedeemOnchainFundsRequest {
     fn wire2api(self) -> PrepareRedeemOnchainFundsRequest {
         PrepareRedeemOnchainFundsRequest {
             to_address: self.to_address.wire2api(),
             sat_per_vbyte: self.sat_per_vbyte.wire2api(),
+            amount_sat: self.amount_sat.wire2api(),
         }
     }
 }
@@ -1019,6 +2459,48 @@ impl Wire2Api<PrepareRefundRequest> for wire_PrepareRefundRequest {
             to_address: self.to_address.wire2api(),
             sat_per_vbyte: self.sat_per_vbyte.wire2api(),
             unilateral: self.unilateral.wire2api(),
+            include_fee_previews: self.include_fee_previews.wire2api(),
+        }
+    }
+}
+impl Wire2Api<PrepareSendPaymentRequest> for wire_PrepareSendPaymentRequest {
+    fn wire2api(self) -> PrepareSendPaymentRequest {
+        PrepareSendPaymentRequest {
+            bolt11: self.bolt11.wire2api(),
+            amount_msat: self.amount_msat.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ProbePaymentRequest> for wire_ProbePaymentRequest {
+    fn wire2api(self) -> ProbePaymentRequest {
+        ProbePaymentRequest {
+            destination: self.destination.wire2api(),
+            amount_msat: self.amount_msat.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ProveAddressOwnershipRequest> for wire_ProveAddressOwnershipRequest {
+    fn wire2api(self) -> ProveAddressOwnershipRequest {
+        ProveAddressOwnershipRequest {
+            address: self.address.wire2api(),
+            message: self.message.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ProxyConfig> for wire_ProxyConfig {
+    fn wire2api(self) -> ProxyConfig {
+        ProxyConfig {
+            address: self.address.wire2api(),
+            username: self.username.wire2api(),
+            password: self.password.wire2api(),
+        }
+    }
+}
+impl Wire2Api<QueuePaymentRequest> for wire_QueuePaymentRequest {
+    fn wire2api(self) -> QueuePaymentRequest {
+        QueuePaymentRequest {
+            req: self.req.wire2api(),
+            expiry_secs: self.expiry_secs.wire2api(),
         }
     }
 }
@@ -1039,6 +2521,26 @@ impl Wire2Api<ReceivePaymentRequest> for wire_ReceivePaymentRequest {
             use_description_hash: self.use_description_hash.wire2api(),
             expiry: self.expiry.wire2api(),
             cltv: self.cltv.wire2api(),
+            include_fallback_address: self.include_fallback_address.wire2api(),
+            max_route_hints: self.max_route_hints.wire2api(),
+            fiat_amount: self.fiat_amount.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ReceiveUnifiedRequest> for wire_ReceiveUnifiedRequest {
+    fn wire2api(self) -> ReceiveUnifiedRequest {
+        ReceiveUnifiedRequest {
+            amount_sat: self.amount_sat.wire2api(),
+            description: self.description.wire2api(),
+            opening_fee_params: self.opening_fee_params.wire2api(),
+        }
+    }
+}
+impl Wire2Api<RedeemLnurlWithdrawVoucherRequest> for wire_RedeemLnurlWithdrawVoucherRequest {
+    fn wire2api(self) -> RedeemLnurlWithdrawVoucherRequest {
+        RedeemLnurlWithdrawVoucherRequest {
+            id: self.id.wire2api(),
+            bolt11: self.bolt11.wire2api(),
         }
     }
 }
@@ -1047,6 +2549,7 @@ impl Wire2Api<RedeemOnchainFundsRequest> for wire_RedeemOnchainFundsRequest {
         RedeemOnchainFundsRequest {
             to_address: self.to_address.wire2api(),
             sat_per_vbyte: self.sat_per_vbyte.wire2api(),
+            amount_sat: self.amount_sat.wire2api(),
         }
     }
 }
@@ -1090,6 +2593,31 @@ impl Wire2Api<ReverseSwapFeesRequest> for wire_ReverseSwapFeesRequest {
         }
     }
 }
+impl Wire2Api<RouteHint> for wire_RouteHint {
+    fn wire2api(self) -> RouteHint {
+        RouteHint {
+            hops: self.hops.wire2api(),
+        }
+    }
+}
+impl Wire2Api<RouteHintHop> for wire_RouteHintHop {
+    fn wire2api(self) -> RouteHintHop {
+        RouteHintHop {
+            src_node_id: self.src_node_id.wire2api(),
+            short_channel_id: self.short_channel_id.wire2api(),
+            fees_base_msat: self.fees_base_msat.wire2api(),
+            fees_proportional_millionths: self.fees_proportional_millionths.wire2api(),
+            cltv_expiry_delta: self.cltv_expiry_delta.wire2api(),
+            htlc_minimum_msat: self.htlc_minimum_msat.wire2api(),
+            htlc_maximum_msat: self.htlc_maximum_msat.wire2api(),
+        }
+    }
+}
+impl Wire2Api<Sat> for wire_Sat {
+    fn wire2api(self) -> Sat {
+        Sat(self.field0.wire2api())
+    }
+}
 impl Wire2Api<SendPaymentRequest> for wire_SendPaymentRequest {
     fn wire2api(self) -> SendPaymentRequest {
         SendPaymentRequest {
@@ -1097,6 +2625,20 @@ impl Wire2Api<SendPaymentRequest> for wire_SendPaymentRequest {
             use_trampoline: self.use_trampoline.wire2api(),
             amount_msat: self.amount_msat.wire2api(),
             label: self.label.wire2api(),
+            route_hint_index: self.route_hint_index.wire2api(),
+            payment_timeout_sec: self.payment_timeout_sec.wire2api(),
+            maxfee_percent: self.maxfee_percent.wire2api(),
+            exemptfee_msat: self.exemptfee_msat.wire2api(),
+            cancel_token: self.cancel_token.wire2api(),
+            skip_denylist: self.skip_denylist.wire2api(),
+        }
+    }
+}
+impl Wire2Api<SendPaymentsRequest> for wire_SendPaymentsRequest {
+    fn wire2api(self) -> SendPaymentsRequest {
+        SendPaymentsRequest {
+            payments: self.payments.wire2api(),
+            max_concurrency: self.max_concurrency.wire2api(),
         }
     }
 }
@@ -1117,6 +2659,16 @@ impl Wire2Api<SignMessageRequest> for wire_SignMessageRequest {
         }
     }
 }
+
+impl Wire2Api<SpendPolicy> for wire_SpendPolicy {
+    fn wire2api(self) -> SpendPolicy {
+        SpendPolicy {
+            daily_limit_msat: self.daily_limit_msat.wire2api(),
+            per_payment_limit_msat: self.per_payment_limit_msat.wire2api(),
+            allowed_destinations: self.allowed_destinations.wire2api(),
+        }
+    }
+}
 impl Wire2Api<StaticBackupRequest> for wire_StaticBackupRequest {
     fn wire2api(self) -> StaticBackupRequest {
         StaticBackupRequest {
@@ -1124,6 +2676,14 @@ impl Wire2Api<StaticBackupRequest> for wire_StaticBackupRequest {
         }
     }
 }
+impl Wire2Api<StaticLnurlPayCallbackRequest> for wire_StaticLnurlPayCallbackRequest {
+    fn wire2api(self) -> StaticLnurlPayCallbackRequest {
+        StaticLnurlPayCallbackRequest {
+            amount_msat: self.amount_msat.wire2api(),
+            comment: self.comment.wire2api(),
+        }
+    }
+}
 
 impl Wire2Api<TlvEntry> for wire_TlvEntry {
     fn wire2api(self) -> TlvEntry {
@@ -1142,8 +2702,64 @@ impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
         }
     }
 }
+impl Wire2Api<UserSettings> for wire_UserSettings {
+    fn wire2api(self) -> UserSettings {
+        UserSettings {
+            preferred_fiat_currency: self.preferred_fiat_currency.wire2api(),
+            denomination: self.denomination.wire2api(),
+            default_feerate_preset: self.default_feerate_preset.wire2api(),
+            preferred_lsp_id: self.preferred_lsp_id.wire2api(),
+        }
+    }
+}
+impl Wire2Api<ValidatePayabilityRequest> for wire_ValidatePayabilityRequest {
+    fn wire2api(self) -> ValidatePayabilityRequest {
+        ValidatePayabilityRequest {
+            bolt11: self.bolt11.wire2api(),
+            amount_msat: self.amount_msat.wire2api(),
+        }
+    }
+}
 // Section: wire structs
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_StringList {
+    ptr: *mut *mut wire_uint_8_list,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_AddFiatRateAlertRequest {
+    currency: *mut wire_uint_8_list,
+    direction: i32,
+    threshold: f64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_AmendInvoiceRequest {
+    bolt11: *mut wire_uint_8_list,
+    amount_msat: *mut wire_MilliSat,
+    expiry: *mut u32,
+    route_hints: *mut wire_list_route_hint,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_AutoRefundConfig {
+    to_address: *mut wire_uint_8_list,
+    sat_per_vbyte: *mut u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_BumpFeeRequest {
+    txid: *mut wire_uint_8_list,
+    sat_per_vbyte: u32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_BuyBitcoinRequest {
@@ -1160,19 +2776,48 @@ pub struct wire_CheckMessageRequest {
     signature: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_CloseChannelRequest {
+    channel_id: *mut wire_uint_8_list,
+    force: bool,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_Config {
     breezserver: *mut wire_uint_8_list,
     chainnotifier_url: *mut wire_uint_8_list,
+    lnurl_server_url: *mut wire_uint_8_list,
     mempoolspace_url: *mut wire_uint_8_list,
+    chain_service: *mut wire_ChainServiceConfig,
+    backup_transport: *mut wire_BackupTransportConfig,
+    proxy: *mut wire_ProxyConfig,
     working_dir: *mut wire_uint_8_list,
+    db_dir: *mut wire_uint_8_list,
+    credentials_dir: *mut wire_uint_8_list,
     network: i32,
     payment_timeout_sec: u32,
+    max_concurrent_payments: u32,
     default_lsp_id: *mut wire_uint_8_list,
+    lsp_policy: *mut wire_LspPolicy,
     api_key: *mut wire_uint_8_list,
     maxfee_percent: f64,
     exemptfee_msat: u64,
+    fiat_currency: *mut wire_uint_8_list,
+    low_outbound_liquidity_threshold_msat: *mut u64,
+    low_inbound_liquidity_threshold_msat: *mut u64,
+    use_trampoline: bool,
+    partner_fee: *mut wire_PartnerFeeConfig,
+    swap_auto_refund: *mut wire_AutoRefundConfig,
+    memo_privacy: i32,
+    channel_hygiene_window_secs: *mut u64,
+    log_filter: *mut wire_uint_8_list,
+    denylist_url: *mut wire_uint_8_list,
+    transak_api_key: *mut wire_uint_8_list,
+    spend_policy: *mut wire_SpendPolicy,
+    htlc_exposure_limits: *mut wire_HtlcExposureLimits,
+    service_status_check_interval_secs: *mut u64,
     node_config: wire_NodeConfig,
 }
 
@@ -1182,103 +2827,259 @@ pub struct wire_ConfigureNodeRequest {
     close_to_address: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ConnectPeerRequest {
+    node_uri: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ConnectRequest {
     config: wire_Config,
     seed: *mut wire_uint_8_list,
     restore_only: *mut bool,
+    register_if_missing: *mut bool,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_GreenlightCredentials {
-    developer_key: *mut wire_uint_8_list,
-    developer_cert: *mut wire_uint_8_list,
+pub struct wire_CreateLnurlWithdrawVoucherRequest {
+    amount_msat: wire_MilliSat,
+    uses: u32,
+    expiry_secs: u32,
+    description: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_GreenlightNodeConfig {
-    partner_credentials: *mut wire_GreenlightCredentials,
-    invite_code: *mut wire_uint_8_list,
+pub struct wire_CreateStaticLnurlPayRequest {
+    url: *mut wire_uint_8_list,
+    callback_url: *mut wire_uint_8_list,
+    description: *mut wire_uint_8_list,
+    min_sendable_msat: wire_MilliSat,
+    max_sendable_msat: wire_MilliSat,
+    comment_allowed: *mut u16,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_metadata_filter {
-    ptr: *mut wire_MetadataFilter,
-    len: i32,
+pub struct wire_DecryptRecoveryBundleRequest {
+    bundle: *mut wire_uint_8_list,
+    private_key: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_payment_type_filter {
-    ptr: *mut i32,
-    len: i32,
+pub struct wire_DeriveEncryptionKeyRequest {
+    purpose: *mut wire_uint_8_list,
+    index: u32,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_ListPaymentsRequest {
-    filters: *mut wire_list_payment_type_filter,
-    metadata_filters: *mut wire_list_metadata_filter,
+pub struct wire_EncryptedPaymentRequestData {
+    blob: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ExportPaymentsRequest {
+    format: i32,
     from_timestamp: *mut i64,
     to_timestamp: *mut i64,
-    include_failures: *mut bool,
-    offset: *mut u32,
-    limit: *mut u32,
+    include_fiat: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_swap_status {
-    ptr: *mut i32,
-    len: i32,
+pub struct wire_ExportRecoveryBundleRequest {
+    recipient_pubkey: *mut wire_uint_8_list,
+    instructions: *mut wire_uint_8_list,
+    file_path: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_ListSwapsRequest {
-    status: *mut wire_list_swap_status,
-    from_timestamp: *mut i64,
-    to_timestamp: *mut i64,
-    offset: *mut u32,
-    limit: *mut u32,
+pub struct wire_ExportSwapRefundKeysRequest {
+    include_gap_keys: *mut bool,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_list_tlv_entry {
-    ptr: *mut wire_TlvEntry,
-    len: i32,
+pub struct wire_FetchBuyBitcoinQuoteRequest {
+    provider: i32,
+    fiat_amount: wire_FiatAmount,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_LnUrlAuthRequestData {
-    k1: *mut wire_uint_8_list,
-    action: *mut wire_uint_8_list,
-    domain: *mut wire_uint_8_list,
-    url: *mut wire_uint_8_list,
+pub struct wire_FiatAmount {
+    currency: *mut wire_uint_8_list,
+    amount: f64,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_LnUrlPayRequest {
-    data: wire_LnUrlPayRequestData,
-    amount_msat: u64,
-    use_trampoline: bool,
-    comment: *mut wire_uint_8_list,
-    payment_label: *mut wire_uint_8_list,
-    validate_success_action_url: *mut bool,
+pub struct wire_GreenlightCredentials {
+    developer_key: *mut wire_uint_8_list,
+    developer_cert: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_LnUrlPayRequestData {
-    callback: *mut wire_uint_8_list,
-    min_sendable: u64,
+pub struct wire_GreenlightNodeConfig {
+    partner_credentials: *mut wire_GreenlightCredentials,
+    invite_code: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_HtlcExposureLimits {
+    max_inflight_msat: *mut u64,
+    max_htlcs_per_payment: *mut u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ImportPaymentsRequest {
+    format: i32,
+    data: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_ln_url_auth_domain {
+    ptr: *mut wire_LnUrlAuthDomain,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_metadata_filter {
+    ptr: *mut wire_MetadataFilter,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ListPaymentGroupsRequest {
+    group_by: i32,
+    payments: wire_ListPaymentsRequest,
+    limit: *mut u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_payment_type_filter {
+    ptr: *mut i32,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ListPaymentsRequest {
+    filters: *mut wire_list_payment_type_filter,
+    metadata_filters: *mut wire_list_metadata_filter,
+    from_timestamp: *mut i64,
+    to_timestamp: *mut i64,
+    include_failures: *mut bool,
+    offset: *mut u32,
+    limit: *mut u32,
+    include_tlv_hex: *mut bool,
+    sort_order: *mut i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_route_hint {
+    ptr: *mut wire_RouteHint,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_route_hint_hop {
+    ptr: *mut wire_RouteHintHop,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_send_payment_request {
+    ptr: *mut wire_SendPaymentRequest,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_swap_status {
+    ptr: *mut i32,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ListSwapsRequest {
+    status: *mut wire_list_swap_status,
+    from_timestamp: *mut i64,
+    to_timestamp: *mut i64,
+    offset: *mut u32,
+    limit: *mut u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_list_tlv_entry {
+    ptr: *mut wire_TlvEntry,
+    len: i32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LnUrlAuthDomain {
+    domain: *mut wire_uint_8_list,
+    linking_key: *mut wire_uint_8_list,
+    first_authenticated_at: i64,
+    last_authenticated_at: i64,
+    auth_count: u32,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LnUrlAuthExport {
+    hashing_key: *mut wire_uint_8_list,
+    domains: *mut wire_list_ln_url_auth_domain,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LnUrlAuthRequestData {
+    k1: *mut wire_uint_8_list,
+    action: *mut wire_uint_8_list,
+    domain: *mut wire_uint_8_list,
+    url: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LnUrlPayRequest {
+    data: wire_LnUrlPayRequestData,
+    amount_msat: u64,
+    use_trampoline: bool,
+    comment: *mut wire_uint_8_list,
+    payment_label: *mut wire_uint_8_list,
+    validate_success_action_url: *mut bool,
+    pending_timeout_sec: *mut u32,
+    cancel_token: *mut wire_uint_8_list,
+    skip_denylist: *mut bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LnUrlPayRequestData {
+    callback: *mut wire_uint_8_list,
+    min_sendable: u64,
     max_sendable: u64,
     metadata_str: *mut wire_uint_8_list,
     comment_allowed: u16,
@@ -1313,13 +3114,42 @@ pub struct wire_MetadataFilter {
     json_value: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_MilliSat {
+    field0: u64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_MockNodeConfig {
+    channels_balance_msat: u64,
+    onchain_balance_msat: u64,
+    inbound_liquidity_msat: u64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NodeMigrationRequest {
+    close_channels: bool,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_OpenChannelFeeRequest {
-    amount_msat: *mut u64,
+    amount_msat: *mut wire_MilliSat,
     expiry: *mut u32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_OpenChannelRequest {
+    node_id: *mut wire_uint_8_list,
+    amount_sat: wire_Sat,
+    announce: bool,
+    sat_per_vbyte: *mut u32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_OpeningFeeParams {
@@ -1331,17 +3161,51 @@ pub struct wire_OpeningFeeParams {
     promise: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PartnerFeeConfig {
+    fee_bps: u32,
+    destination: wire_PartnerFeeDestination,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PayOfferRequest {
+    offer: *mut wire_uint_8_list,
+    amount_msat: *mut wire_MilliSat,
+    payer_note: *mut wire_uint_8_list,
+    label: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PayOnchainAddressRequest {
+    address: *mut wire_uint_8_list,
+    amount_sat: *mut wire_Sat,
+    drain: bool,
+    fee_preference: *mut i32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_PayOnchainRequest {
     recipient_address: *mut wire_uint_8_list,
     prepare_res: wire_PrepareOnchainPaymentResponse,
+    cancel_token: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PaymentRequestBundle {
+    invoice: *mut wire_uint_8_list,
+    fallback: *mut wire_uint_8_list,
+    metadata: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_PrepareOnchainPaymentRequest {
-    amount_sat: u64,
+    amount_sat: wire_Sat,
     amount_type: i32,
     claim_tx_feerate: u32,
 }
@@ -1353,16 +3217,25 @@ pub struct wire_PrepareOnchainPaymentResponse {
     fees_percentage: f64,
     fees_lockup: u64,
     fees_claim: u64,
-    sender_amount_sat: u64,
-    recipient_amount_sat: u64,
+    sender_amount_sat: wire_Sat,
+    recipient_amount_sat: wire_Sat,
     total_fees: u64,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PrepareReceivePaymentRequest {
+    amount_msat: wire_MilliSat,
+    opening_fee_params: *mut wire_OpeningFeeParams,
+    expiry: *mut u32,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_PrepareRedeemOnchainFundsRequest {
     to_address: *mut wire_uint_8_list,
     sat_per_vbyte: u32,
+    amount_sat: *mut wire_Sat,
 }
 
 #[repr(C)]
@@ -1372,6 +3245,43 @@ pub struct wire_PrepareRefundRequest {
     to_address: *mut wire_uint_8_list,
     sat_per_vbyte: u32,
     unilateral: *mut bool,
+    include_fee_previews: *mut bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PrepareSendPaymentRequest {
+    bolt11: *mut wire_uint_8_list,
+    amount_msat: *mut wire_MilliSat,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ProbePaymentRequest {
+    destination: *mut wire_uint_8_list,
+    amount_msat: *mut wire_MilliSat,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ProveAddressOwnershipRequest {
+    address: *mut wire_uint_8_list,
+    message: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ProxyConfig {
+    address: *mut wire_uint_8_list,
+    username: *mut wire_uint_8_list,
+    password: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_QueuePaymentRequest {
+    req: wire_SendPaymentRequest,
+    expiry_secs: *mut u64,
 }
 
 #[repr(C)]
@@ -1383,13 +3293,31 @@ pub struct wire_ReceiveOnchainRequest {
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ReceivePaymentRequest {
-    amount_msat: u64,
+    amount_msat: wire_MilliSat,
     description: *mut wire_uint_8_list,
     preimage: *mut wire_uint_8_list,
     opening_fee_params: *mut wire_OpeningFeeParams,
     use_description_hash: *mut bool,
     expiry: *mut u32,
     cltv: *mut u32,
+    include_fallback_address: *mut bool,
+    max_route_hints: *mut u32,
+    fiat_amount: *mut wire_FiatAmount,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ReceiveUnifiedRequest {
+    amount_sat: wire_Sat,
+    description: *mut wire_uint_8_list,
+    opening_fee_params: *mut wire_OpeningFeeParams,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RedeemLnurlWithdrawVoucherRequest {
+    id: *mut wire_uint_8_list,
+    bolt11: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
@@ -1397,6 +3325,7 @@ pub struct wire_ReceivePaymentRequest {
 pub struct wire_RedeemOnchainFundsRequest {
     to_address: *mut wire_uint_8_list,
     sat_per_vbyte: u32,
+    amount_sat: *mut wire_Sat,
 }
 
 #[repr(C)]
@@ -1418,24 +3347,61 @@ pub struct wire_ReportPaymentFailureDetails {
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_ReverseSwapFeesRequest {
-    send_amount_sat: *mut u64,
+    send_amount_sat: *mut wire_Sat,
     claim_tx_feerate: *mut u32,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RouteHint {
+    hops: *mut wire_list_route_hint_hop,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_RouteHintHop {
+    src_node_id: *mut wire_uint_8_list,
+    short_channel_id: *mut wire_uint_8_list,
+    fees_base_msat: u32,
+    fees_proportional_millionths: u32,
+    cltv_expiry_delta: u64,
+    htlc_minimum_msat: *mut u64,
+    htlc_maximum_msat: *mut u64,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_Sat {
+    field0: u64,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_SendPaymentRequest {
     bolt11: *mut wire_uint_8_list,
-    use_trampoline: bool,
-    amount_msat: *mut u64,
+    use_trampoline: *mut bool,
+    amount_msat: *mut wire_MilliSat,
     label: *mut wire_uint_8_list,
+    route_hint_index: *mut u32,
+    payment_timeout_sec: *mut u32,
+    maxfee_percent: *mut f64,
+    exemptfee_msat: *mut wire_MilliSat,
+    cancel_token: *mut wire_uint_8_list,
+    skip_denylist: *mut bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_SendPaymentsRequest {
+    payments: *mut wire_list_send_payment_request,
+    max_concurrency: *mut u32,
 }
 
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_SendSpontaneousPaymentRequest {
     node_id: *mut wire_uint_8_list,
-    amount_msat: u64,
+    amount_msat: wire_MilliSat,
     extra_tlvs: *mut wire_list_tlv_entry,
     label: *mut wire_uint_8_list,
 }
@@ -1446,12 +3412,27 @@ pub struct wire_SignMessageRequest {
     message: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_SpendPolicy {
+    daily_limit_msat: *mut u64,
+    per_payment_limit_msat: *mut u64,
+    allowed_destinations: *mut wire_StringList,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_StaticBackupRequest {
     working_dir: *mut wire_uint_8_list,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_StaticLnurlPayCallbackRequest {
+    amount_msat: wire_MilliSat,
+    comment: *mut wire_uint_8_list,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_TlvEntry {
@@ -1468,133 +3449,609 @@ pub struct wire_uint_8_list {
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_NodeConfig {
-    tag: i32,
-    kind: *mut NodeConfigKind,
-}
-
-#[repr(C)]
-pub union NodeConfigKind {
-    Greenlight: *mut wire_NodeConfig_Greenlight,
+pub struct wire_UserSettings {
+    preferred_fiat_currency: *mut wire_uint_8_list,
+    denomination: *mut i32,
+    default_feerate_preset: *mut i32,
+    preferred_lsp_id: *mut wire_uint_8_list,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_NodeConfig_Greenlight {
-    config: *mut wire_GreenlightNodeConfig,
+pub struct wire_ValidatePayabilityRequest {
+    bolt11: *mut wire_uint_8_list,
+    amount_msat: *mut wire_MilliSat,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_ReportIssueRequest {
+pub struct wire_BackupTransportConfig {
     tag: i32,
-    kind: *mut ReportIssueRequestKind,
+    kind: *mut BackupTransportConfigKind,
 }
 
 #[repr(C)]
-pub union ReportIssueRequestKind {
-    PaymentFailure: *mut wire_ReportIssueRequest_PaymentFailure,
+pub union BackupTransportConfigKind {
+    S3: *mut wire_BackupTransportConfig_S3,
+    WebDav: *mut wire_BackupTransportConfig_WebDav,
 }
 
 #[repr(C)]
 #[derive(Clone)]
-pub struct wire_ReportIssueRequest_PaymentFailure {
-    data: *mut wire_ReportPaymentFailureDetails,
+pub struct wire_BackupTransportConfig_S3 {
+    endpoint: *mut wire_uint_8_list,
+    region: *mut wire_uint_8_list,
+    bucket: *mut wire_uint_8_list,
+    access_key_id: *mut wire_uint_8_list,
+    secret_access_key: *mut wire_uint_8_list,
 }
 
-// Section: impl NewWithNullPtr
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_BackupTransportConfig_WebDav {
+    url: *mut wire_uint_8_list,
+    username: *mut wire_uint_8_list,
+    password: *mut wire_uint_8_list,
+}
 
-pub trait NewWithNullPtr {
-    fn new_with_null_ptr() -> Self;
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChainServiceConfig {
+    tag: i32,
+    kind: *mut ChainServiceConfigKind,
 }
 
-impl<T> NewWithNullPtr for *mut T {
-    fn new_with_null_ptr() -> Self {
-        std::ptr::null_mut()
-    }
+#[repr(C)]
+pub union ChainServiceConfigKind {
+    Esplora: *mut wire_ChainServiceConfig_Esplora,
+    Electrum: *mut wire_ChainServiceConfig_Electrum,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChainServiceConfig_Esplora {
+    url: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ChainServiceConfig_Electrum {
+    url: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LspPolicy {
+    tag: i32,
+    kind: *mut LspPolicyKind,
+}
+
+#[repr(C)]
+pub union LspPolicyKind {
+    LowestOpeningFee: *mut wire_LspPolicy_LowestOpeningFee,
+    LowestProportionalFee: *mut wire_LspPolicy_LowestProportionalFee,
+    Pinned: *mut wire_LspPolicy_Pinned,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LspPolicy_LowestOpeningFee {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LspPolicy_LowestProportionalFee {}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_LspPolicy_Pinned {
+    lsp_id: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NodeConfig {
+    tag: i32,
+    kind: *mut NodeConfigKind,
+}
+
+#[repr(C)]
+pub union NodeConfigKind {
+    Greenlight: *mut wire_NodeConfig_Greenlight,
+    Mock: *mut wire_NodeConfig_Mock,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NodeConfig_Greenlight {
+    config: *mut wire_GreenlightNodeConfig,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_NodeConfig_Mock {
+    config: *mut wire_MockNodeConfig,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PartnerFeeDestination {
+    tag: i32,
+    kind: *mut PartnerFeeDestinationKind,
+}
+
+#[repr(C)]
+pub union PartnerFeeDestinationKind {
+    NodeId: *mut wire_PartnerFeeDestination_NodeId,
+    LightningAddress: *mut wire_PartnerFeeDestination_LightningAddress,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PartnerFeeDestination_NodeId {
+    node_id: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_PartnerFeeDestination_LightningAddress {
+    address: *mut wire_uint_8_list,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ReportIssueRequest {
+    tag: i32,
+    kind: *mut ReportIssueRequestKind,
+}
+
+#[repr(C)]
+pub union ReportIssueRequestKind {
+    PaymentFailure: *mut wire_ReportIssueRequest_PaymentFailure,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_ReportIssueRequest_PaymentFailure {
+    data: *mut wire_ReportPaymentFailureDetails,
+}
+
+// Section: impl NewWithNullPtr
+
+pub trait NewWithNullPtr {
+    fn new_with_null_ptr() -> Self;
+}
+
+impl<T> NewWithNullPtr for *mut T {
+    fn new_with_null_ptr() -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl NewWithNullPtr for wire_AddFiatRateAlertRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            currency: core::ptr::null_mut(),
+            direction: Default::default(),
+            threshold: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_AddFiatRateAlertRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_AmendInvoiceRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            bolt11: core::ptr::null_mut(),
+            amount_msat: core::ptr::null_mut(),
+            expiry: core::ptr::null_mut(),
+            route_hints: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_AmendInvoiceRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_AutoRefundConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            to_address: core::ptr::null_mut(),
+            sat_per_vbyte: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_AutoRefundConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl Default for wire_BackupTransportConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_BackupTransportConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_BackupTransportConfig_S3() -> *mut BackupTransportConfigKind {
+    support::new_leak_box_ptr(BackupTransportConfigKind {
+        S3: support::new_leak_box_ptr(wire_BackupTransportConfig_S3 {
+            endpoint: core::ptr::null_mut(),
+            region: core::ptr::null_mut(),
+            bucket: core::ptr::null_mut(),
+            access_key_id: core::ptr::null_mut(),
+            secret_access_key: core::ptr::null_mut(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_BackupTransportConfig_WebDav() -> *mut BackupTransportConfigKind {
+    support::new_leak_box_ptr(BackupTransportConfigKind {
+        WebDav: support::new_leak_box_ptr(wire_BackupTransportConfig_WebDav {
+            url: core::ptr::null_mut(),
+            username: core::ptr::null_mut(),
+            password: core::ptr::null_mut(),
+        }),
+    })
+}
+
+impl NewWithNullPtr for wire_BumpFeeRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            txid: core::ptr::null_mut(),
+            sat_per_vbyte: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_BumpFeeRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
 }
 
 impl NewWithNullPtr for wire_BuyBitcoinRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            provider: Default::default(),
-            opening_fee_params: core::ptr::null_mut(),
-            redirect_url: core::ptr::null_mut(),
+            provider: Default::default(),
+            opening_fee_params: core::ptr::null_mut(),
+            redirect_url: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_BuyBitcoinRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl Default for wire_ChainServiceConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ChainServiceConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_ChainServiceConfig_Esplora() -> *mut ChainServiceConfigKind {
+    support::new_leak_box_ptr(ChainServiceConfigKind {
+        Esplora: support::new_leak_box_ptr(wire_ChainServiceConfig_Esplora {
+            url: core::ptr::null_mut(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_ChainServiceConfig_Electrum() -> *mut ChainServiceConfigKind {
+    support::new_leak_box_ptr(ChainServiceConfigKind {
+        Electrum: support::new_leak_box_ptr(wire_ChainServiceConfig_Electrum {
+            url: core::ptr::null_mut(),
+        }),
+    })
+}
+
+impl NewWithNullPtr for wire_CheckMessageRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            message: core::ptr::null_mut(),
+            pubkey: core::ptr::null_mut(),
+            signature: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_CheckMessageRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_CloseChannelRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            channel_id: core::ptr::null_mut(),
+            force: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_CloseChannelRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Config {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            breezserver: core::ptr::null_mut(),
+            chainnotifier_url: core::ptr::null_mut(),
+            lnurl_server_url: core::ptr::null_mut(),
+            mempoolspace_url: core::ptr::null_mut(),
+            chain_service: core::ptr::null_mut(),
+            backup_transport: core::ptr::null_mut(),
+            proxy: core::ptr::null_mut(),
+            working_dir: core::ptr::null_mut(),
+            db_dir: core::ptr::null_mut(),
+            credentials_dir: core::ptr::null_mut(),
+            network: Default::default(),
+            payment_timeout_sec: Default::default(),
+            max_concurrent_payments: Default::default(),
+            default_lsp_id: core::ptr::null_mut(),
+            lsp_policy: core::ptr::null_mut(),
+            api_key: core::ptr::null_mut(),
+            maxfee_percent: Default::default(),
+            exemptfee_msat: Default::default(),
+            fiat_currency: core::ptr::null_mut(),
+            low_outbound_liquidity_threshold_msat: core::ptr::null_mut(),
+            low_inbound_liquidity_threshold_msat: core::ptr::null_mut(),
+            use_trampoline: Default::default(),
+            partner_fee: core::ptr::null_mut(),
+            swap_auto_refund: core::ptr::null_mut(),
+            memo_privacy: Default::default(),
+            channel_hygiene_window_secs: core::ptr::null_mut(),
+            log_filter: core::ptr::null_mut(),
+            denylist_url: core::ptr::null_mut(),
+            transak_api_key: core::ptr::null_mut(),
+            spend_policy: core::ptr::null_mut(),
+            htlc_exposure_limits: core::ptr::null_mut(),
+            service_status_check_interval_secs: core::ptr::null_mut(),
+            node_config: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_Config {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ConfigureNodeRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            close_to_address: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ConfigureNodeRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ConnectPeerRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            node_uri: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ConnectPeerRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ConnectRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            config: Default::default(),
+            seed: core::ptr::null_mut(),
+            restore_only: core::ptr::null_mut(),
+            register_if_missing: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ConnectRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_CreateLnurlWithdrawVoucherRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_msat: Default::default(),
+            uses: Default::default(),
+            expiry_secs: Default::default(),
+            description: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_CreateLnurlWithdrawVoucherRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_CreateStaticLnurlPayRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            url: core::ptr::null_mut(),
+            callback_url: core::ptr::null_mut(),
+            description: core::ptr::null_mut(),
+            min_sendable_msat: Default::default(),
+            max_sendable_msat: Default::default(),
+            comment_allowed: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_CreateStaticLnurlPayRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_DecryptRecoveryBundleRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            bundle: core::ptr::null_mut(),
+            private_key: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_DecryptRecoveryBundleRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_DeriveEncryptionKeyRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            purpose: core::ptr::null_mut(),
+            index: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_DeriveEncryptionKeyRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_EncryptedPaymentRequestData {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            blob: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_BuyBitcoinRequest {
+impl Default for wire_EncryptedPaymentRequestData {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_CheckMessageRequest {
+impl NewWithNullPtr for wire_ExportPaymentsRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            message: core::ptr::null_mut(),
-            pubkey: core::ptr::null_mut(),
-            signature: core::ptr::null_mut(),
+            format: Default::default(),
+            from_timestamp: core::ptr::null_mut(),
+            to_timestamp: core::ptr::null_mut(),
+            include_fiat: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_CheckMessageRequest {
+impl Default for wire_ExportPaymentsRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_Config {
+impl NewWithNullPtr for wire_ExportRecoveryBundleRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            breezserver: core::ptr::null_mut(),
-            chainnotifier_url: core::ptr::null_mut(),
-            mempoolspace_url: core::ptr::null_mut(),
-            working_dir: core::ptr::null_mut(),
-            network: Default::default(),
-            payment_timeout_sec: Default::default(),
-            default_lsp_id: core::ptr::null_mut(),
-            api_key: core::ptr::null_mut(),
-            maxfee_percent: Default::default(),
-            exemptfee_msat: Default::default(),
-            node_config: Default::default(),
+            recipient_pubkey: core::ptr::null_mut(),
+            instructions: core::ptr::null_mut(),
+            file_path: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_Config {
+impl Default for wire_ExportRecoveryBundleRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_ConfigureNodeRequest {
+impl NewWithNullPtr for wire_ExportSwapRefundKeysRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            close_to_address: core::ptr::null_mut(),
+            include_gap_keys: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_ConfigureNodeRequest {
+impl Default for wire_ExportSwapRefundKeysRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_ConnectRequest {
+impl NewWithNullPtr for wire_FetchBuyBitcoinQuoteRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            config: Default::default(),
-            seed: core::ptr::null_mut(),
-            restore_only: core::ptr::null_mut(),
+            provider: Default::default(),
+            fiat_amount: Default::default(),
         }
     }
 }
 
-impl Default for wire_ConnectRequest {
+impl Default for wire_FetchBuyBitcoinQuoteRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_FiatAmount {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            currency: core::ptr::null_mut(),
+            amount: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_FiatAmount {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
@@ -1630,6 +4087,52 @@ impl Default for wire_GreenlightNodeConfig {
     }
 }
 
+impl NewWithNullPtr for wire_HtlcExposureLimits {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            max_inflight_msat: core::ptr::null_mut(),
+            max_htlcs_per_payment: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_HtlcExposureLimits {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ImportPaymentsRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            format: Default::default(),
+            data: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ImportPaymentsRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ListPaymentGroupsRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            group_by: Default::default(),
+            payments: Default::default(),
+            limit: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ListPaymentGroupsRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_ListPaymentsRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1640,6 +4143,8 @@ impl NewWithNullPtr for wire_ListPaymentsRequest {
             include_failures: core::ptr::null_mut(),
             offset: core::ptr::null_mut(),
             limit: core::ptr::null_mut(),
+            include_tlv_hex: core::ptr::null_mut(),
+            sort_order: core::ptr::null_mut(),
         }
     }
 }
@@ -1668,6 +4173,39 @@ impl Default for wire_ListSwapsRequest {
     }
 }
 
+impl NewWithNullPtr for wire_LnUrlAuthDomain {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            domain: core::ptr::null_mut(),
+            linking_key: core::ptr::null_mut(),
+            first_authenticated_at: Default::default(),
+            last_authenticated_at: Default::default(),
+            auth_count: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_LnUrlAuthDomain {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_LnUrlAuthExport {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            hashing_key: core::ptr::null_mut(),
+            domains: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_LnUrlAuthExport {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_LnUrlAuthRequestData {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1694,6 +4232,9 @@ impl NewWithNullPtr for wire_LnUrlPayRequest {
             comment: core::ptr::null_mut(),
             payment_label: core::ptr::null_mut(),
             validate_success_action_url: core::ptr::null_mut(),
+            pending_timeout_sec: core::ptr::null_mut(),
+            cancel_token: core::ptr::null_mut(),
+            skip_denylist: core::ptr::null_mut(),
         }
     }
 }
@@ -1720,68 +4261,235 @@ impl NewWithNullPtr for wire_LnUrlPayRequestData {
     }
 }
 
-impl Default for wire_LnUrlPayRequestData {
+impl Default for wire_LnUrlPayRequestData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_LnUrlWithdrawRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            data: Default::default(),
+            amount_msat: Default::default(),
+            description: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_LnUrlWithdrawRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_LnUrlWithdrawRequestData {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            callback: core::ptr::null_mut(),
+            k1: core::ptr::null_mut(),
+            default_description: core::ptr::null_mut(),
+            min_withdrawable: Default::default(),
+            max_withdrawable: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_LnUrlWithdrawRequestData {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl Default for wire_LspPolicy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_LspPolicy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_LspPolicy_Pinned() -> *mut LspPolicyKind {
+    support::new_leak_box_ptr(LspPolicyKind {
+        Pinned: support::new_leak_box_ptr(wire_LspPolicy_Pinned {
+            lsp_id: core::ptr::null_mut(),
+        }),
+    })
+}
+
+impl NewWithNullPtr for wire_MetadataFilter {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            json_path: core::ptr::null_mut(),
+            json_value: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_MetadataFilter {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_MilliSat {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_MilliSat {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_MockNodeConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            channels_balance_msat: Default::default(),
+            onchain_balance_msat: Default::default(),
+            inbound_liquidity_msat: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_MockNodeConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl Default for wire_NodeConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_NodeConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: core::ptr::null_mut(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_NodeConfig_Greenlight() -> *mut NodeConfigKind {
+    support::new_leak_box_ptr(NodeConfigKind {
+        Greenlight: support::new_leak_box_ptr(wire_NodeConfig_Greenlight {
+            config: core::ptr::null_mut(),
+        }),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn inflate_NodeConfig_Mock() -> *mut NodeConfigKind {
+    support::new_leak_box_ptr(NodeConfigKind {
+        Mock: support::new_leak_box_ptr(wire_NodeConfig_Mock {
+            config: core::ptr::null_mut(),
+        }),
+    })
+}
+
+impl NewWithNullPtr for wire_NodeMigrationRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            close_channels: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_NodeMigrationRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_OpenChannelFeeRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_msat: core::ptr::null_mut(),
+            expiry: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_OpenChannelFeeRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_LnUrlWithdrawRequest {
+impl NewWithNullPtr for wire_OpenChannelRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            data: Default::default(),
-            amount_msat: Default::default(),
-            description: core::ptr::null_mut(),
+            node_id: core::ptr::null_mut(),
+            amount_sat: Default::default(),
+            announce: Default::default(),
+            sat_per_vbyte: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_LnUrlWithdrawRequest {
+impl Default for wire_OpenChannelRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_LnUrlWithdrawRequestData {
+impl NewWithNullPtr for wire_OpeningFeeParams {
     fn new_with_null_ptr() -> Self {
         Self {
-            callback: core::ptr::null_mut(),
-            k1: core::ptr::null_mut(),
-            default_description: core::ptr::null_mut(),
-            min_withdrawable: Default::default(),
-            max_withdrawable: Default::default(),
+            min_msat: Default::default(),
+            proportional: Default::default(),
+            valid_until: core::ptr::null_mut(),
+            max_idle_time: Default::default(),
+            max_client_to_self_delay: Default::default(),
+            promise: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_LnUrlWithdrawRequestData {
+impl Default for wire_OpeningFeeParams {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_MetadataFilter {
+impl NewWithNullPtr for wire_PartnerFeeConfig {
     fn new_with_null_ptr() -> Self {
         Self {
-            json_path: core::ptr::null_mut(),
-            json_value: core::ptr::null_mut(),
+            fee_bps: Default::default(),
+            destination: Default::default(),
         }
     }
 }
 
-impl Default for wire_MetadataFilter {
+impl Default for wire_PartnerFeeConfig {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl Default for wire_NodeConfig {
+impl Default for wire_PartnerFeeDestination {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_NodeConfig {
+impl NewWithNullPtr for wire_PartnerFeeDestination {
     fn new_with_null_ptr() -> Self {
         Self {
             tag: -1,
@@ -1791,43 +4499,53 @@ impl NewWithNullPtr for wire_NodeConfig {
 }
 
 #[no_mangle]
-pub extern "C" fn inflate_NodeConfig_Greenlight() -> *mut NodeConfigKind {
-    support::new_leak_box_ptr(NodeConfigKind {
-        Greenlight: support::new_leak_box_ptr(wire_NodeConfig_Greenlight {
-            config: core::ptr::null_mut(),
+pub extern "C" fn inflate_PartnerFeeDestination_NodeId() -> *mut PartnerFeeDestinationKind {
+    support::new_leak_box_ptr(PartnerFeeDestinationKind {
+        NodeId: support::new_leak_box_ptr(wire_PartnerFeeDestination_NodeId {
+            node_id: core::ptr::null_mut(),
         }),
     })
 }
 
-impl NewWithNullPtr for wire_OpenChannelFeeRequest {
+#[no_mangle]
+pub extern "C" fn inflate_PartnerFeeDestination_LightningAddress() -> *mut PartnerFeeDestinationKind
+{
+    support::new_leak_box_ptr(PartnerFeeDestinationKind {
+        LightningAddress: support::new_leak_box_ptr(wire_PartnerFeeDestination_LightningAddress {
+            address: core::ptr::null_mut(),
+        }),
+    })
+}
+
+impl NewWithNullPtr for wire_PayOfferRequest {
     fn new_with_null_ptr() -> Self {
         Self {
+            offer: core::ptr::null_mut(),
             amount_msat: core::ptr::null_mut(),
-            expiry: core::ptr::null_mut(),
+            payer_note: core::ptr::null_mut(),
+            label: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_OpenChannelFeeRequest {
+impl Default for wire_PayOfferRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
 
-impl NewWithNullPtr for wire_OpeningFeeParams {
+impl NewWithNullPtr for wire_PayOnchainAddressRequest {
     fn new_with_null_ptr() -> Self {
         Self {
-            min_msat: Default::default(),
-            proportional: Default::default(),
-            valid_until: core::ptr::null_mut(),
-            max_idle_time: Default::default(),
-            max_client_to_self_delay: Default::default(),
-            promise: core::ptr::null_mut(),
+            address: core::ptr::null_mut(),
+            amount_sat: core::ptr::null_mut(),
+            drain: Default::default(),
+            fee_preference: core::ptr::null_mut(),
         }
     }
 }
 
-impl Default for wire_OpeningFeeParams {
+impl Default for wire_PayOnchainAddressRequest {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
@@ -1838,6 +4556,7 @@ impl NewWithNullPtr for wire_PayOnchainRequest {
         Self {
             recipient_address: core::ptr::null_mut(),
             prepare_res: Default::default(),
+            cancel_token: core::ptr::null_mut(),
         }
     }
 }
@@ -1848,6 +4567,22 @@ impl Default for wire_PayOnchainRequest {
     }
 }
 
+impl NewWithNullPtr for wire_PaymentRequestBundle {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            invoice: core::ptr::null_mut(),
+            fallback: core::ptr::null_mut(),
+            metadata: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_PaymentRequestBundle {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_PrepareOnchainPaymentRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1884,11 +4619,28 @@ impl Default for wire_PrepareOnchainPaymentResponse {
     }
 }
 
+impl NewWithNullPtr for wire_PrepareReceivePaymentRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_msat: Default::default(),
+            opening_fee_params: core::ptr::null_mut(),
+            expiry: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_PrepareReceivePaymentRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_PrepareRedeemOnchainFundsRequest {
     fn new_with_null_ptr() -> Self {
         Self {
             to_address: core::ptr::null_mut(),
             sat_per_vbyte: Default::default(),
+            amount_sat: core::ptr::null_mut(),
         }
     }
 }
@@ -1906,6 +4658,7 @@ impl NewWithNullPtr for wire_PrepareRefundRequest {
             to_address: core::ptr::null_mut(),
             sat_per_vbyte: Default::default(),
             unilateral: core::ptr::null_mut(),
+            include_fee_previews: core::ptr::null_mut(),
         }
     }
 }
@@ -1916,6 +4669,82 @@ impl Default for wire_PrepareRefundRequest {
     }
 }
 
+impl NewWithNullPtr for wire_PrepareSendPaymentRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            bolt11: core::ptr::null_mut(),
+            amount_msat: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_PrepareSendPaymentRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ProbePaymentRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            destination: core::ptr::null_mut(),
+            amount_msat: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ProbePaymentRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ProveAddressOwnershipRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            message: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ProveAddressOwnershipRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ProxyConfig {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            username: core::ptr::null_mut(),
+            password: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ProxyConfig {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_QueuePaymentRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            req: Default::default(),
+            expiry_secs: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_QueuePaymentRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_ReceiveOnchainRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -1940,6 +4769,9 @@ impl NewWithNullPtr for wire_ReceivePaymentRequest {
             use_description_hash: core::ptr::null_mut(),
             expiry: core::ptr::null_mut(),
             cltv: core::ptr::null_mut(),
+            include_fallback_address: core::ptr::null_mut(),
+            max_route_hints: core::ptr::null_mut(),
+            fiat_amount: core::ptr::null_mut(),
         }
     }
 }
@@ -1950,11 +4782,43 @@ impl Default for wire_ReceivePaymentRequest {
     }
 }
 
+impl NewWithNullPtr for wire_ReceiveUnifiedRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_sat: Default::default(),
+            description: core::ptr::null_mut(),
+            opening_fee_params: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ReceiveUnifiedRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_RedeemLnurlWithdrawVoucherRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            id: core::ptr::null_mut(),
+            bolt11: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_RedeemLnurlWithdrawVoucherRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_RedeemOnchainFundsRequest {
     fn new_with_null_ptr() -> Self {
         Self {
             to_address: core::ptr::null_mut(),
             sat_per_vbyte: Default::default(),
+            amount_sat: core::ptr::null_mut(),
         }
     }
 }
@@ -2036,13 +4900,67 @@ impl Default for wire_ReverseSwapFeesRequest {
     }
 }
 
+impl NewWithNullPtr for wire_RouteHint {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            hops: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_RouteHint {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_RouteHintHop {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            src_node_id: core::ptr::null_mut(),
+            short_channel_id: core::ptr::null_mut(),
+            fees_base_msat: Default::default(),
+            fees_proportional_millionths: Default::default(),
+            cltv_expiry_delta: Default::default(),
+            htlc_minimum_msat: core::ptr::null_mut(),
+            htlc_maximum_msat: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_RouteHintHop {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_Sat {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: Default::default(),
+        }
+    }
+}
+
+impl Default for wire_Sat {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_SendPaymentRequest {
     fn new_with_null_ptr() -> Self {
         Self {
             bolt11: core::ptr::null_mut(),
-            use_trampoline: Default::default(),
+            use_trampoline: core::ptr::null_mut(),
             amount_msat: core::ptr::null_mut(),
             label: core::ptr::null_mut(),
+            route_hint_index: core::ptr::null_mut(),
+            payment_timeout_sec: core::ptr::null_mut(),
+            maxfee_percent: core::ptr::null_mut(),
+            exemptfee_msat: core::ptr::null_mut(),
+            cancel_token: core::ptr::null_mut(),
+            skip_denylist: core::ptr::null_mut(),
         }
     }
 }
@@ -2053,6 +4971,21 @@ impl Default for wire_SendPaymentRequest {
     }
 }
 
+impl NewWithNullPtr for wire_SendPaymentsRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payments: core::ptr::null_mut(),
+            max_concurrency: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_SendPaymentsRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_SendSpontaneousPaymentRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2084,6 +5017,22 @@ impl Default for wire_SignMessageRequest {
     }
 }
 
+impl NewWithNullPtr for wire_SpendPolicy {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            daily_limit_msat: core::ptr::null_mut(),
+            per_payment_limit_msat: core::ptr::null_mut(),
+            allowed_destinations: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_SpendPolicy {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_StaticBackupRequest {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2098,6 +5047,21 @@ impl Default for wire_StaticBackupRequest {
     }
 }
 
+impl NewWithNullPtr for wire_StaticLnurlPayCallbackRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_msat: Default::default(),
+            comment: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_StaticLnurlPayCallbackRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_TlvEntry {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -2113,6 +5077,38 @@ impl Default for wire_TlvEntry {
     }
 }
 
+impl NewWithNullPtr for wire_UserSettings {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            preferred_fiat_currency: core::ptr::null_mut(),
+            denomination: core::ptr::null_mut(),
+            default_feerate_preset: core::ptr::null_mut(),
+            preferred_lsp_id: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_UserSettings {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_ValidatePayabilityRequest {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            bolt11: core::ptr::null_mut(),
+            amount_msat: core::ptr::null_mut(),
+        }
+    }
+}
+
+impl Default for wire_ValidatePayabilityRequest {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
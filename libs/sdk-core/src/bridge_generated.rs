@@ -21,62 +21,158 @@ use std::sync::Arc;
 // Section: imports
 
 use crate::breez_services::BackupFailedData;
+use crate::breez_services::BatchPaymentProgressDetails;
 use crate::breez_services::BreezEvent;
+use crate::breez_services::ChannelHygieneSuggestionDetails;
 use crate::breez_services::CheckMessageRequest;
 use crate::breez_services::CheckMessageResponse;
+use crate::breez_services::ConnectProgressDetails;
+use crate::breez_services::ConnectStage;
+use crate::breez_services::DeriveEncryptionKeyRequest;
+use crate::breez_services::DeriveEncryptionKeyResponse;
+use crate::breez_services::FiatRateAlertTriggeredDetails;
 use crate::breez_services::InvoicePaidDetails;
+use crate::breez_services::LnUrlPayFinishedDetails;
+use crate::breez_services::LowLiquidityDetails;
+use crate::breez_services::OpenChannelReceiveDetails;
+use crate::breez_services::OpenChannelReceiveStage;
+use crate::breez_services::OutboxPaymentDetails;
 use crate::breez_services::PaymentFailedData;
+use crate::breez_services::ProveAddressOwnershipRequest;
+use crate::breez_services::ProveAddressOwnershipResponse;
 use crate::breez_services::SignMessageRequest;
 use crate::breez_services::SignMessageResponse;
+use crate::breez_services::SwapRefundedDetails;
+use crate::breez_services::UnredeemedFundsDetails;
 use crate::chain::RecommendedFees;
+use crate::lnurl::pay::LnUrlPayPendingData;
 use crate::lnurl::pay::LnUrlPayResult;
 use crate::lnurl::pay::LnUrlPaySuccessData;
 use crate::lsp::LspInformation;
+use crate::models::AddFiatRateAlertRequest;
+use crate::models::AmendInvoiceRequest;
+use crate::models::AutoRefundConfig;
 use crate::models::BackupStatus;
+use crate::models::BackupTransportConfig;
+use crate::models::BalanceBreakdown;
+use crate::models::Balances;
+use crate::models::BatchPaymentResult;
+use crate::models::BumpFeeRequest;
+use crate::models::BumpFeeResponse;
 use crate::models::BuyBitcoinProvider;
 use crate::models::BuyBitcoinRequest;
 use crate::models::BuyBitcoinResponse;
+use crate::models::ChainServiceConfig;
+use crate::models::ChannelDetails;
+use crate::models::ChannelOpeningFee;
 use crate::models::ChannelState;
+use crate::models::CloseChannelRequest;
+use crate::models::CloseChannelResponse;
 use crate::models::ClosedChannelPaymentDetails;
 use crate::models::Config;
 use crate::models::ConfigureNodeRequest;
+use crate::models::ConnectPeerRequest;
 use crate::models::ConnectRequest;
+use crate::models::ConnectionState;
+use crate::models::ConnectionStatus;
+use crate::models::CreateLnurlWithdrawVoucherRequest;
+use crate::models::CreateStaticLnurlPayRequest;
+use crate::models::DecryptRecoveryBundleRequest;
+use crate::models::Denomination;
 use crate::models::EnvironmentType;
+use crate::models::ExportFormat;
+use crate::models::ExportPaymentsRequest;
+use crate::models::ExportRecoveryBundleRequest;
+use crate::models::ExportRecoveryBundleResponse;
+use crate::models::ExportSwapRefundKeysRequest;
+use crate::models::FeeratePreset;
+use crate::models::FetchBuyBitcoinQuoteRequest;
+use crate::models::FiatAmount;
+use crate::models::FiatRateAlert;
+use crate::models::FiatRateAlertDirection;
 use crate::models::GreenlightCredentials;
 use crate::models::GreenlightDeviceCredentials;
 use crate::models::GreenlightNodeConfig;
 use crate::models::HealthCheckStatus;
+use crate::models::HistoricalRate;
+use crate::models::HtlcExposureLimits;
+use crate::models::ImportPaymentsRequest;
+use crate::models::ImportPaymentsResponse;
+use crate::models::InvoicePayability;
+use crate::models::InvoiceVerificationResult;
+use crate::models::ListPaymentGroupsRequest;
 use crate::models::ListPaymentsRequest;
 use crate::models::ListSwapsRequest;
 use crate::models::LnPaymentDetails;
+use crate::models::LnUrlAuthDomain;
+use crate::models::LnUrlAuthExport;
+use crate::models::LnurlPayInfo;
+use crate::models::LnurlWithdrawVoucher;
 use crate::models::LogEntry;
+use crate::models::LspPolicy;
+use crate::models::MemoPrivacy;
 use crate::models::MetadataFilter;
+use crate::models::MetricBucket;
+use crate::models::MetricCounter;
+use crate::models::MetricHistogram;
+use crate::models::MetricsSnapshot;
+use crate::models::MigrationChannel;
+use crate::models::MockNodeConfig;
 use crate::models::NodeConfig;
 use crate::models::NodeCredentials;
+use crate::models::NodeMigrationRequest;
+use crate::models::NodeMigrationState;
 use crate::models::NodeState;
 use crate::models::OnchainPaymentLimitsResponse;
 use crate::models::OpenChannelFeeRequest;
 use crate::models::OpenChannelFeeResponse;
+use crate::models::OpenChannelRequest;
+use crate::models::OpenChannelResponse;
 use crate::models::OpeningFeeParams;
 use crate::models::OpeningFeeParamsMenu;
+use crate::models::OutboxPayment;
+use crate::models::OutboxPaymentStatus;
+use crate::models::PartnerFeeConfig;
+use crate::models::PartnerFeeDestination;
+use crate::models::PartnerFeeDetails;
+use crate::models::PayOfferRequest;
+use crate::models::PayOnchainAddressRequest;
+use crate::models::PayOnchainAddressResponse;
 use crate::models::PayOnchainRequest;
 use crate::models::PayOnchainResponse;
 use crate::models::Payment;
 use crate::models::PaymentDetails;
+use crate::models::PaymentGroup;
+use crate::models::PaymentGroupBy;
+use crate::models::PaymentProbe;
+use crate::models::PaymentRequestBundle;
 use crate::models::PaymentStatus;
 use crate::models::PaymentType;
 use crate::models::PaymentTypeFilter;
+use crate::models::PeerConnectivity;
+use crate::models::PendingChannelOpen;
 use crate::models::PrepareOnchainPaymentRequest;
 use crate::models::PrepareOnchainPaymentResponse;
+use crate::models::PrepareReceivePaymentRequest;
+use crate::models::PrepareReceivePaymentResponse;
 use crate::models::PrepareRedeemOnchainFundsRequest;
 use crate::models::PrepareRedeemOnchainFundsResponse;
 use crate::models::PrepareRefundRequest;
 use crate::models::PrepareRefundResponse;
+use crate::models::PrepareSendPaymentRequest;
+use crate::models::PrepareSendPaymentResponse;
+use crate::models::ProbePaymentRequest;
+use crate::models::QueuePaymentRequest;
 use crate::models::ReceiveOnchainRequest;
 use crate::models::ReceivePaymentRequest;
 use crate::models::ReceivePaymentResponse;
+use crate::models::ReceiveUnifiedRequest;
+use crate::models::ReceiveUnifiedResponse;
+use crate::models::RecoveryBundle;
+use crate::models::RedeemLnurlWithdrawVoucherRequest;
 use crate::models::RedeemOnchainFundsRequest;
 use crate::models::RedeemOnchainFundsResponse;
+use crate::models::RefundFeePreview;
 use crate::models::RefundRequest;
 use crate::models::RefundResponse;
 use crate::models::ReportIssueRequest;
@@ -84,18 +180,36 @@ use crate::models::ReportPaymentFailureDetails;
 use crate::models::ReverseSwapFeesRequest;
 use crate::models::ReverseSwapInfo;
 use crate::models::ReverseSwapPairInfo;
+use crate::models::ReverseSwapProtocol;
 use crate::models::ReverseSwapStatus;
+use crate::models::SdkInfo;
 use crate::models::SendPaymentRequest;
 use crate::models::SendPaymentResponse;
+use crate::models::SendPaymentsRequest;
+use crate::models::SendPaymentsResponse;
 use crate::models::SendSpontaneousPaymentRequest;
+use crate::models::ServiceComponent;
 use crate::models::ServiceHealthCheckResponse;
+use crate::models::ServiceStatus;
+use crate::models::SnapshotChange;
+use crate::models::SortOrder;
+use crate::models::SpendPolicy;
+use crate::models::SpontaneousPaymentMetadata;
 use crate::models::StaticBackupRequest;
 use crate::models::StaticBackupResponse;
+use crate::models::StaticLnurlPay;
+use crate::models::StaticLnurlPayCallbackRequest;
 use crate::models::SwapAmountType;
 use crate::models::SwapInfo;
+use crate::models::SwapRefundKey;
 use crate::models::SwapStatus;
+use crate::models::TaskState;
+use crate::models::TaskStatus;
 use crate::models::TlvEntry;
+use crate::models::TlvRecord;
 use crate::models::UnspentTransactionOutput;
+use crate::models::UserSettings;
+use crate::models::ValidatePayabilityRequest;
 
 // Section: wire functions
 
@@ -112,6 +226,19 @@ fn wire_connect_impl(port_: MessagePort, req: impl Wire2Api<ConnectRequest> + Un
         },
     )
 }
+fn wire_connect_ephemeral_impl(port_: MessagePort, config: impl Wire2Api<Config> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "connect_ephemeral",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_config = config.wire2api();
+            move |task_callback| connect_ephemeral(api_config)
+        },
+    )
+}
 fn wire_is_initialized_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
@@ -132,6 +259,36 @@ fn wire_sync_impl(port_: MessagePort) {
         move || move |task_callback| sync(),
     )
 }
+fn wire_cancel_sync_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "cancel_sync",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| cancel_sync(),
+    )
+}
+fn wire_metrics_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, MetricsSnapshot, _>(
+        WrapInfo {
+            debug_name: "metrics",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| metrics(),
+    )
+}
+fn wire_sync_partial_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "sync_partial",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| sync_partial(),
+    )
+}
 fn wire_node_credentials_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<NodeCredentials>, _>(
         WrapInfo {
@@ -152,6 +309,26 @@ fn wire_node_info_impl(port_: MessagePort) {
         move || move |task_callback| node_info(),
     )
 }
+fn wire_balances_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Balances, _>(
+        WrapInfo {
+            debug_name: "balances",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| balances(),
+    )
+}
+fn wire_get_balance_breakdown_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BalanceBreakdown, _>(
+        WrapInfo {
+            debug_name: "get_balance_breakdown",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| get_balance_breakdown(),
+    )
+}
 fn wire_configure_node_impl(
     port_: MessagePort,
     req: impl Wire2Api<ConfigureNodeRequest> + UnwindSafe,
@@ -178,6 +355,70 @@ fn wire_disconnect_impl(port_: MessagePort) {
         move || move |task_callback| disconnect(),
     )
 }
+fn wire_derive_encryption_key_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<DeriveEncryptionKeyRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, DeriveEncryptionKeyResponse, _>(
+        WrapInfo {
+            debug_name: "derive_encryption_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| derive_encryption_key(api_req)
+        },
+    )
+}
+fn wire_prove_address_ownership_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ProveAddressOwnershipRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ProveAddressOwnershipResponse, _>(
+        WrapInfo {
+            debug_name: "prove_address_ownership",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| prove_address_ownership(api_req)
+        },
+    )
+}
+fn wire_encrypt_payment_request_impl(
+    port_: MessagePort,
+    bundle: impl Wire2Api<PaymentRequestBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "encrypt_payment_request",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_bundle = bundle.wire2api();
+            move |task_callback| encrypt_payment_request(api_bundle)
+        },
+    )
+}
+fn wire_decrypt_payment_request_impl(
+    port_: MessagePort,
+    data: impl Wire2Api<EncryptedPaymentRequestData> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PaymentRequestBundle, _>(
+        WrapInfo {
+            debug_name: "decrypt_payment_request",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_data = data.wire2api();
+            move |task_callback| decrypt_payment_request(api_data)
+        },
+    )
+}
 fn wire_sign_message_impl(port_: MessagePort, req: impl Wire2Api<SignMessageRequest> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SignMessageResponse, _>(
         WrapInfo {
@@ -242,6 +483,58 @@ fn wire_default_config_impl(
         },
     )
 }
+fn wire_sdk_info_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SdkInfo, _>(
+        WrapInfo {
+            debug_name: "sdk_info",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| Result::<_, ()>::Ok(sdk_info()),
+    )
+}
+fn wire_decrypt_recovery_bundle_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<DecryptRecoveryBundleRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RecoveryBundle, _>(
+        WrapInfo {
+            debug_name: "decrypt_recovery_bundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| decrypt_recovery_bundle(api_req)
+        },
+    )
+}
+fn wire_export_recovery_bundle_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ExportRecoveryBundleRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ExportRecoveryBundleResponse, _>(
+        WrapInfo {
+            debug_name: "export_recovery_bundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| export_recovery_bundle(api_req)
+        },
+    )
+}
+fn wire_stop_recovery_bundle_refresh_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "stop_recovery_bundle_refresh",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| stop_recovery_bundle_refresh(),
+    )
+}
 fn wire_static_backup_impl(
     port_: MessagePort,
     req: impl Wire2Api<StaticBackupRequest> + UnwindSafe,
@@ -293,6 +586,19 @@ fn wire_breez_log_stream_impl(port_: MessagePort) {
         move || move |task_callback| breez_log_stream(task_callback.stream_sink::<_, LogEntry>()),
     )
 }
+fn wire_set_log_filter_impl(port_: MessagePort, filter: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_log_filter",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_filter = filter.wire2api();
+            move |task_callback| set_log_filter(api_filter)
+        },
+    )
+}
 fn wire_list_lsps_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<LspInformation>, _>(
         WrapInfo {
@@ -326,6 +632,32 @@ fn wire_lsp_id_impl(port_: MessagePort) {
         move || move |task_callback| lsp_id(),
     )
 }
+fn wire_user_settings_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, UserSettings, _>(
+        WrapInfo {
+            debug_name: "user_settings",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| user_settings(),
+    )
+}
+fn wire_set_user_settings_impl(
+    port_: MessagePort,
+    settings: impl Wire2Api<UserSettings> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_user_settings",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_settings = settings.wire2api();
+            move |task_callback| set_user_settings(api_settings)
+        },
+    )
+}
 fn wire_fetch_lsp_info_impl(port_: MessagePort, id: impl Wire2Api<String> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<LspInformation>, _>(
         WrapInfo {
@@ -359,558 +691,1289 @@ fn wire_close_lsp_channels_impl(port_: MessagePort) {
         move || move |task_callback| close_lsp_channels(),
     )
 }
-fn wire_register_webhook_impl(port_: MessagePort, webhook_url: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+fn wire_list_channels_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<ChannelDetails>, _>(
         WrapInfo {
-            debug_name: "register_webhook",
+            debug_name: "list_channels",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || {
-            let api_webhook_url = webhook_url.wire2api();
-            move |task_callback| register_webhook(api_webhook_url)
-        },
+        move || move |task_callback| list_channels(),
     )
 }
-fn wire_unregister_webhook_impl(
+fn wire_close_channel_impl(
     port_: MessagePort,
-    webhook_url: impl Wire2Api<String> + UnwindSafe,
+    req: impl Wire2Api<CloseChannelRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, CloseChannelResponse, _>(
         WrapInfo {
-            debug_name: "unregister_webhook",
+            debug_name: "close_channel",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_webhook_url = webhook_url.wire2api();
-            move |task_callback| unregister_webhook(api_webhook_url)
+            let api_req = req.wire2api();
+            move |task_callback| close_channel(api_req)
         },
     )
 }
-fn wire_backup_impl(port_: MessagePort) {
+fn wire_connect_peer_impl(port_: MessagePort, req: impl Wire2Api<ConnectPeerRequest> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "backup",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
-        },
-        move || move |task_callback| backup(),
-    )
-}
-fn wire_backup_status_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BackupStatus, _>(
-        WrapInfo {
-            debug_name: "backup_status",
-            port: Some(port_),
-            mode: FfiCallMode::Normal,
-        },
-        move || move |task_callback| backup_status(),
-    )
-}
-fn wire_parse_invoice_impl(port_: MessagePort, invoice: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LNInvoice, _>(
-        WrapInfo {
-            debug_name: "parse_invoice",
+            debug_name: "connect_peer",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_invoice = invoice.wire2api();
-            move |task_callback| parse_invoice(api_invoice)
+            let api_req = req.wire2api();
+            move |task_callback| connect_peer(api_req)
         },
     )
 }
-fn wire_parse_input_impl(port_: MessagePort, input: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_InputType, _>(
+fn wire_open_channel_impl(port_: MessagePort, req: impl Wire2Api<OpenChannelRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OpenChannelResponse, _>(
         WrapInfo {
-            debug_name: "parse_input",
+            debug_name: "open_channel",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.wire2api();
-            move |task_callback| parse_input(api_input)
+            let api_req = req.wire2api();
+            move |task_callback| open_channel(api_req)
         },
     )
 }
-fn wire_list_payments_impl(
+fn wire_export_node_migration_impl(
     port_: MessagePort,
-    req: impl Wire2Api<ListPaymentsRequest> + UnwindSafe,
+    req: impl Wire2Api<NodeMigrationRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<Payment>, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, NodeMigrationState, _>(
         WrapInfo {
-            debug_name: "list_payments",
+            debug_name: "export_node_migration",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| list_payments(api_req)
+            move |task_callback| export_node_migration(api_req)
         },
     )
 }
-fn wire_payment_by_hash_impl(port_: MessagePort, hash: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<Payment>, _>(
+fn wire_register_webhook_impl(port_: MessagePort, webhook_url: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "payment_by_hash",
+            debug_name: "register_webhook",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_hash = hash.wire2api();
-            move |task_callback| payment_by_hash(api_hash)
+            let api_webhook_url = webhook_url.wire2api();
+            move |task_callback| register_webhook(api_webhook_url)
         },
     )
 }
-fn wire_set_payment_metadata_impl(
+fn wire_unregister_webhook_impl(
     port_: MessagePort,
-    hash: impl Wire2Api<String> + UnwindSafe,
-    metadata: impl Wire2Api<String> + UnwindSafe,
+    webhook_url: impl Wire2Api<String> + UnwindSafe,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "set_payment_metadata",
+            debug_name: "unregister_webhook",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_hash = hash.wire2api();
-            let api_metadata = metadata.wire2api();
-            move |task_callback| set_payment_metadata(api_hash, api_metadata)
+            let api_webhook_url = webhook_url.wire2api();
+            move |task_callback| unregister_webhook(api_webhook_url)
         },
     )
 }
-fn wire_send_payment_impl(port_: MessagePort, req: impl Wire2Api<SendPaymentRequest> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentResponse, _>(
+fn wire_register_lightning_address_impl(
+    port_: MessagePort,
+    username: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, LnurlPayInfo, _>(
         WrapInfo {
-            debug_name: "send_payment",
+            debug_name: "register_lightning_address",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_req = req.wire2api();
-            move |task_callback| send_payment(api_req)
+            let api_username = username.wire2api();
+            move |task_callback| register_lightning_address(api_username)
         },
     )
 }
-fn wire_send_spontaneous_payment_impl(
-    port_: MessagePort,
-    req: impl Wire2Api<SendSpontaneousPaymentRequest> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentResponse, _>(
+fn wire_lnurl_pay_info_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<LnurlPayInfo>, _>(
         WrapInfo {
-            debug_name: "send_spontaneous_payment",
+            debug_name: "lnurl_pay_info",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || {
-            let api_req = req.wire2api();
-            move |task_callback| send_spontaneous_payment(api_req)
-        },
+        move || move |task_callback| lnurl_pay_info(),
     )
 }
-fn wire_receive_payment_impl(
+fn wire_create_static_lnurl_pay_impl(
     port_: MessagePort,
-    req: impl Wire2Api<ReceivePaymentRequest> + UnwindSafe,
+    req: impl Wire2Api<CreateStaticLnurlPayRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ReceivePaymentResponse, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, StaticLnurlPay, _>(
         WrapInfo {
-            debug_name: "receive_payment",
+            debug_name: "create_static_lnurl_pay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| receive_payment(api_req)
+            move |task_callback| create_static_lnurl_pay(api_req)
         },
     )
 }
-fn wire_lnurl_pay_impl(port_: MessagePort, req: impl Wire2Api<LnUrlPayRequest> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, LnUrlPayResult, _>(
+fn wire_static_lnurl_pay_callback_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<StaticLnurlPayCallbackRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "lnurl_pay",
+            debug_name: "static_lnurl_pay_callback",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| lnurl_pay(api_req)
+            move |task_callback| static_lnurl_pay_callback(api_req)
         },
     )
 }
-fn wire_lnurl_withdraw_impl(
+fn wire_create_lnurl_withdraw_voucher_impl(
     port_: MessagePort,
-    req: impl Wire2Api<LnUrlWithdrawRequest> + UnwindSafe,
+    req: impl Wire2Api<CreateLnurlWithdrawVoucherRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LnUrlWithdrawResult, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, LnurlWithdrawVoucher, _>(
         WrapInfo {
-            debug_name: "lnurl_withdraw",
+            debug_name: "create_lnurl_withdraw_voucher",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| lnurl_withdraw(api_req)
+            move |task_callback| create_lnurl_withdraw_voucher(api_req)
         },
     )
 }
-fn wire_lnurl_auth_impl(
+fn wire_redeem_lnurl_withdraw_voucher_impl(
     port_: MessagePort,
-    req_data: impl Wire2Api<LnUrlAuthRequestData> + UnwindSafe,
+    req: impl Wire2Api<RedeemLnurlWithdrawVoucherRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LnUrlCallbackStatus, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentResponse, _>(
         WrapInfo {
-            debug_name: "lnurl_auth",
+            debug_name: "redeem_lnurl_withdraw_voucher",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_req_data = req_data.wire2api();
-            move |task_callback| lnurl_auth(api_req_data)
+            let api_req = req.wire2api();
+            move |task_callback| redeem_lnurl_withdraw_voucher(api_req)
         },
     )
 }
-fn wire_report_issue_impl(port_: MessagePort, req: impl Wire2Api<ReportIssueRequest> + UnwindSafe) {
+fn wire_list_lnurl_withdraw_vouchers_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<LnurlWithdrawVoucher>, _>(
+        WrapInfo {
+            debug_name: "list_lnurl_withdraw_vouchers",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_lnurl_withdraw_vouchers(),
+    )
+}
+fn wire_backup_impl(port_: MessagePort) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "report_issue",
+            debug_name: "backup",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || {
-            let api_req = req.wire2api();
-            move |task_callback| report_issue(api_req)
+        move || move |task_callback| backup(),
+    )
+}
+fn wire_backup_status_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BackupStatus, _>(
+        WrapInfo {
+            debug_name: "backup_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
         },
+        move || move |task_callback| backup_status(),
     )
 }
-fn wire_fetch_fiat_rates_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<mirror_Rate>, _>(
+fn wire_rotate_backup_key_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "fetch_fiat_rates",
+            debug_name: "rotate_backup_key",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| fetch_fiat_rates(),
+        move || move |task_callback| rotate_backup_key(),
     )
 }
-fn wire_list_fiat_currencies_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<mirror_FiatCurrency>, _>(
+fn wire_list_backup_versions_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<u64>, _>(
         WrapInfo {
-            debug_name: "list_fiat_currencies",
+            debug_name: "list_backup_versions",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| list_fiat_currencies(),
+        move || move |task_callback| list_backup_versions(),
     )
 }
-fn wire_pay_onchain_impl(port_: MessagePort, req: impl Wire2Api<PayOnchainRequest> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PayOnchainResponse, _>(
+fn wire_restore_backup_impl(port_: MessagePort, version: impl Wire2Api<u64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "pay_onchain",
+            debug_name: "restore_backup",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_req = req.wire2api();
-            move |task_callback| pay_onchain(api_req)
+            let api_version = version.wire2api();
+            move |task_callback| restore_backup(api_version)
         },
     )
 }
-fn wire_receive_onchain_impl(
-    port_: MessagePort,
-    req: impl Wire2Api<ReceiveOnchainRequest> + UnwindSafe,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SwapInfo, _>(
+fn wire_parse_invoice_impl(port_: MessagePort, invoice: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LNInvoice, _>(
         WrapInfo {
-            debug_name: "receive_onchain",
+            debug_name: "parse_invoice",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_req = req.wire2api();
-            move |task_callback| receive_onchain(api_req)
+            let api_invoice = invoice.wire2api();
+            move |task_callback| parse_invoice(api_invoice)
         },
     )
 }
-fn wire_buy_bitcoin_impl(port_: MessagePort, req: impl Wire2Api<BuyBitcoinRequest> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BuyBitcoinResponse, _>(
+fn wire_parse_input_impl(port_: MessagePort, input: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_InputType, _>(
         WrapInfo {
-            debug_name: "buy_bitcoin",
+            debug_name: "parse_input",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_req = req.wire2api();
-            move |task_callback| buy_bitcoin(api_req)
+            let api_input = input.wire2api();
+            move |task_callback| parse_input(api_input)
         },
     )
 }
-fn wire_redeem_onchain_funds_impl(
+fn wire_list_payments_impl(
     port_: MessagePort,
-    req: impl Wire2Api<RedeemOnchainFundsRequest> + UnwindSafe,
+    req: impl Wire2Api<ListPaymentsRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RedeemOnchainFundsResponse, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<Payment>, _>(
         WrapInfo {
-            debug_name: "redeem_onchain_funds",
+            debug_name: "list_payments",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| redeem_onchain_funds(api_req)
+            move |task_callback| list_payments(api_req)
         },
     )
 }
-fn wire_prepare_redeem_onchain_funds_impl(
+fn wire_list_payment_groups_impl(
     port_: MessagePort,
-    req: impl Wire2Api<PrepareRedeemOnchainFundsRequest> + UnwindSafe,
+    req: impl Wire2Api<ListPaymentGroupsRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareRedeemOnchainFundsResponse, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PaymentGroup>, _>(
         WrapInfo {
-            debug_name: "prepare_redeem_onchain_funds",
+            debug_name: "list_payment_groups",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| prepare_redeem_onchain_funds(api_req)
+            move |task_callback| list_payment_groups(api_req)
         },
     )
 }
-fn wire_list_refundables_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SwapInfo>, _>(
+fn wire_export_payments_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ExportPaymentsRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "list_refundables",
+            debug_name: "export_payments",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| list_refundables(),
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| export_payments(api_req)
+        },
     )
 }
-fn wire_prepare_refund_impl(
+fn wire_import_payments_impl(
     port_: MessagePort,
-    req: impl Wire2Api<PrepareRefundRequest> + UnwindSafe,
+    req: impl Wire2Api<ImportPaymentsRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareRefundResponse, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ImportPaymentsResponse, _>(
         WrapInfo {
-            debug_name: "prepare_refund",
+            debug_name: "import_payments",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| prepare_refund(api_req)
+            move |task_callback| import_payments(api_req)
         },
     )
 }
-fn wire_refund_impl(port_: MessagePort, req: impl Wire2Api<RefundRequest> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RefundResponse, _>(
+fn wire_payment_by_hash_impl(port_: MessagePort, hash: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<Payment>, _>(
         WrapInfo {
-            debug_name: "refund",
+            debug_name: "payment_by_hash",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_req = req.wire2api();
-            move |task_callback| refund(api_req)
+            let api_hash = hash.wire2api();
+            move |task_callback| payment_by_hash(api_hash)
         },
     )
 }
-fn wire_rescan_swaps_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+fn wire_list_channel_fees_paid_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<ChannelOpeningFee>, _>(
         WrapInfo {
-            debug_name: "rescan_swaps",
+            debug_name: "list_channel_fees_paid",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| rescan_swaps(),
+        move || move |task_callback| list_channel_fees_paid(),
     )
 }
-fn wire_redeem_swap_impl(port_: MessagePort, swap_address: impl Wire2Api<String> + UnwindSafe) {
+fn wire_set_payment_metadata_impl(
+    port_: MessagePort,
+    hash: impl Wire2Api<String> + UnwindSafe,
+    metadata: impl Wire2Api<String> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "redeem_swap",
+            debug_name: "set_payment_metadata",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_swap_address = swap_address.wire2api();
-            move |task_callback| redeem_swap(api_swap_address)
+            let api_hash = hash.wire2api();
+            let api_metadata = metadata.wire2api();
+            move |task_callback| set_payment_metadata(api_hash, api_metadata)
         },
     )
 }
-fn wire_in_progress_swap_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<SwapInfo>, _>(
+fn wire_send_payment_impl(port_: MessagePort, req: impl Wire2Api<SendPaymentRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentResponse, _>(
         WrapInfo {
-            debug_name: "in_progress_swap",
+            debug_name: "send_payment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| in_progress_swap(),
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| send_payment(api_req)
+        },
     )
 }
-fn wire_list_swaps_impl(port_: MessagePort, req: impl Wire2Api<ListSwapsRequest> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SwapInfo>, _>(
+fn wire_send_payments_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<SendPaymentsRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentsResponse, _>(
         WrapInfo {
-            debug_name: "list_swaps",
+            debug_name: "send_payments",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| list_swaps(api_req)
+            move |task_callback| send_payments(api_req)
         },
     )
 }
-fn wire_claim_reverse_swap_impl(
+fn wire_verify_invoices_impl(
     port_: MessagePort,
-    lockup_address: impl Wire2Api<String> + UnwindSafe,
+    invoices: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<InvoiceVerificationResult>, _>(
+        WrapInfo {
+            debug_name: "verify_invoices",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_invoices = invoices.wire2api();
+            move |task_callback| verify_invoices(api_invoices)
+        },
+    )
+}
+fn wire_queue_payment_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<QueuePaymentRequest> + UnwindSafe,
 ) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OutboxPayment, _>(
+        WrapInfo {
+            debug_name: "queue_payment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| queue_payment(api_req)
+        },
+    )
+}
+fn wire_list_pending_outbox_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OutboxPayment>, _>(
+        WrapInfo {
+            debug_name: "list_pending_outbox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_pending_outbox(),
+    )
+}
+fn wire_cancel_outbox_payment_impl(port_: MessagePort, id: impl Wire2Api<i64> + UnwindSafe) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
         WrapInfo {
-            debug_name: "claim_reverse_swap",
+            debug_name: "cancel_outbox_payment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_lockup_address = lockup_address.wire2api();
-            move |task_callback| claim_reverse_swap(api_lockup_address)
+            let api_id = id.wire2api();
+            move |task_callback| cancel_outbox_payment(api_id)
         },
     )
 }
-fn wire_open_channel_fee_impl(
+fn wire_prepare_send_payment_impl(
     port_: MessagePort,
-    req: impl Wire2Api<OpenChannelFeeRequest> + UnwindSafe,
+    req: impl Wire2Api<PrepareSendPaymentRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OpenChannelFeeResponse, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareSendPaymentResponse, _>(
         WrapInfo {
-            debug_name: "open_channel_fee",
+            debug_name: "prepare_send_payment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| open_channel_fee(api_req)
+            move |task_callback| prepare_send_payment(api_req)
         },
     )
 }
-fn wire_fetch_reverse_swap_fees_impl(
+fn wire_send_spontaneous_payment_impl(
     port_: MessagePort,
-    req: impl Wire2Api<ReverseSwapFeesRequest> + UnwindSafe,
+    req: impl Wire2Api<SendSpontaneousPaymentRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ReverseSwapPairInfo, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentResponse, _>(
         WrapInfo {
-            debug_name: "fetch_reverse_swap_fees",
+            debug_name: "send_spontaneous_payment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| fetch_reverse_swap_fees(api_req)
+            move |task_callback| send_spontaneous_payment(api_req)
         },
     )
 }
-fn wire_onchain_payment_limits_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OnchainPaymentLimitsResponse, _>(
+fn wire_cancel_impl(port_: MessagePort, cancel_token: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
-            debug_name: "onchain_payment_limits",
+            debug_name: "cancel",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| onchain_payment_limits(),
+        move || {
+            let api_cancel_token = cancel_token.wire2api();
+            move |task_callback| cancel(api_cancel_token)
+        },
     )
 }
-fn wire_prepare_onchain_payment_impl(
+fn wire_probe_payment_impl(
     port_: MessagePort,
-    req: impl Wire2Api<PrepareOnchainPaymentRequest> + UnwindSafe,
+    req: impl Wire2Api<ProbePaymentRequest> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareOnchainPaymentResponse, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PaymentProbe, _>(
         WrapInfo {
-            debug_name: "prepare_onchain_payment",
+            debug_name: "probe_payment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_req = req.wire2api();
-            move |task_callback| prepare_onchain_payment(api_req)
+            move |task_callback| probe_payment(api_req)
         },
     )
 }
-fn wire_in_progress_onchain_payments_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<ReverseSwapInfo>, _>(
+fn wire_validate_payability_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ValidatePayabilityRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, InvoicePayability, _>(
         WrapInfo {
-            debug_name: "in_progress_onchain_payments",
+            debug_name: "validate_payability",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| in_progress_onchain_payments(),
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| validate_payability(api_req)
+        },
     )
 }
-fn wire_recommended_fees_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RecommendedFees, _>(
+fn wire_list_payment_probes_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<PaymentProbe>, _>(
         WrapInfo {
-            debug_name: "recommended_fees",
+            debug_name: "list_payment_probes",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| recommended_fees(),
+        move || move |task_callback| list_payment_probes(),
     )
 }
-fn wire_execute_command_impl(port_: MessagePort, command: impl Wire2Api<String> + UnwindSafe) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+fn wire_pay_offer_impl(port_: MessagePort, req: impl Wire2Api<PayOfferRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SendPaymentResponse, _>(
         WrapInfo {
-            debug_name: "execute_command",
+            debug_name: "pay_offer",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_command = command.wire2api();
-            move |task_callback| execute_command(api_command)
+            let api_req = req.wire2api();
+            move |task_callback| pay_offer(api_req)
         },
     )
 }
-fn wire_generate_diagnostic_data_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+fn wire_prepare_receive_payment_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<PrepareReceivePaymentRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareReceivePaymentResponse, _>(
         WrapInfo {
-            debug_name: "generate_diagnostic_data",
+            debug_name: "prepare_receive_payment",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| generate_diagnostic_data(),
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| prepare_receive_payment(api_req)
+        },
     )
 }
-// Section: wrapper structs
-
-#[derive(Clone)]
-pub struct mirror_AesSuccessActionDataDecrypted(AesSuccessActionDataDecrypted);
-
-#[derive(Clone)]
-pub struct mirror_AesSuccessActionDataResult(AesSuccessActionDataResult);
-
-#[derive(Clone)]
-pub struct mirror_BitcoinAddressData(BitcoinAddressData);
-
-#[derive(Clone)]
-pub struct mirror_CurrencyInfo(CurrencyInfo);
-
-#[derive(Clone)]
-pub struct mirror_FiatCurrency(FiatCurrency);
-
-#[derive(Clone)]
-pub struct mirror_InputType(InputType);
-
-#[derive(Clone)]
-pub struct mirror_LNInvoice(LNInvoice);
-
-#[derive(Clone)]
-pub struct mirror_LnUrlAuthRequestData(LnUrlAuthRequestData);
-
-#[derive(Clone)]
-pub struct mirror_LnUrlCallbackStatus(LnUrlCallbackStatus);
-
-#[derive(Clone)]
-pub struct mirror_LnUrlErrorData(LnUrlErrorData);
+fn wire_receive_payment_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ReceivePaymentRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ReceivePaymentResponse, _>(
+        WrapInfo {
+            debug_name: "receive_payment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| receive_payment(api_req)
+        },
+    )
+}
+fn wire_receive_unified_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ReceiveUnifiedRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ReceiveUnifiedResponse, _>(
+        WrapInfo {
+            debug_name: "receive_unified",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| receive_unified(api_req)
+        },
+    )
+}
+fn wire_amend_invoice_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<AmendInvoiceRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LNInvoice, _>(
+        WrapInfo {
+            debug_name: "amend_invoice",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| amend_invoice(api_req)
+        },
+    )
+}
+fn wire_lnurl_pay_impl(port_: MessagePort, req: impl Wire2Api<LnUrlPayRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, LnUrlPayResult, _>(
+        WrapInfo {
+            debug_name: "lnurl_pay",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| lnurl_pay(api_req)
+        },
+    )
+}
+fn wire_lnurl_withdraw_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<LnUrlWithdrawRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LnUrlWithdrawResult, _>(
+        WrapInfo {
+            debug_name: "lnurl_withdraw",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| lnurl_withdraw(api_req)
+        },
+    )
+}
+fn wire_lnurl_auth_impl(
+    port_: MessagePort,
+    req_data: impl Wire2Api<LnUrlAuthRequestData> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_LnUrlCallbackStatus, _>(
+        WrapInfo {
+            debug_name: "lnurl_auth",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req_data = req_data.wire2api();
+            move |task_callback| lnurl_auth(api_req_data)
+        },
+    )
+}
+fn wire_list_lnurl_auth_domains_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<LnUrlAuthDomain>, _>(
+        WrapInfo {
+            debug_name: "list_lnurl_auth_domains",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_lnurl_auth_domains(),
+    )
+}
+fn wire_lnurl_auth_linking_key_impl(
+    port_: MessagePort,
+    domain: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "lnurl_auth_linking_key",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_domain = domain.wire2api();
+            move |task_callback| lnurl_auth_linking_key(api_domain)
+        },
+    )
+}
+fn wire_export_lnurl_auth_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, LnUrlAuthExport, _>(
+        WrapInfo {
+            debug_name: "export_lnurl_auth",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| export_lnurl_auth(),
+    )
+}
+fn wire_import_lnurl_auth_impl(
+    port_: MessagePort,
+    export: impl Wire2Api<LnUrlAuthExport> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "import_lnurl_auth",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_export = export.wire2api();
+            move |task_callback| import_lnurl_auth(api_export)
+        },
+    )
+}
+fn wire_report_issue_impl(port_: MessagePort, req: impl Wire2Api<ReportIssueRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "report_issue",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| report_issue(api_req)
+        },
+    )
+}
+fn wire_connection_status_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ConnectionStatus, _>(
+        WrapInfo {
+            debug_name: "connection_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| connection_status(),
+    )
+}
+fn wire_task_status_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<TaskStatus>, _>(
+        WrapInfo {
+            debug_name: "task_status",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| task_status(),
+    )
+}
+fn wire_peer_connectivity_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PeerConnectivity, _>(
+        WrapInfo {
+            debug_name: "peer_connectivity",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| peer_connectivity(),
+    )
+}
+fn wire_fetch_fiat_rates_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<mirror_Rate>, _>(
+        WrapInfo {
+            debug_name: "fetch_fiat_rates",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| fetch_fiat_rates(),
+    )
+}
+fn wire_fiat_rate_history_impl(
+    port_: MessagePort,
+    currency: impl Wire2Api<String> + UnwindSafe,
+    from_timestamp: impl Wire2Api<Option<i64>> + UnwindSafe,
+    to_timestamp: impl Wire2Api<Option<i64>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<HistoricalRate>, _>(
+        WrapInfo {
+            debug_name: "fiat_rate_history",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_currency = currency.wire2api();
+            let api_from_timestamp = from_timestamp.wire2api();
+            let api_to_timestamp = to_timestamp.wire2api();
+            move |task_callback| {
+                fiat_rate_history(api_currency, api_from_timestamp, api_to_timestamp)
+            }
+        },
+    )
+}
+fn wire_add_fiat_rate_alert_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<AddFiatRateAlertRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, FiatRateAlert, _>(
+        WrapInfo {
+            debug_name: "add_fiat_rate_alert",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| add_fiat_rate_alert(api_req)
+        },
+    )
+}
+fn wire_list_fiat_rate_alerts_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<FiatRateAlert>, _>(
+        WrapInfo {
+            debug_name: "list_fiat_rate_alerts",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_fiat_rate_alerts(),
+    )
+}
+fn wire_remove_fiat_rate_alert_impl(port_: MessagePort, id: impl Wire2Api<i64> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "remove_fiat_rate_alert",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.wire2api();
+            move |task_callback| remove_fiat_rate_alert(api_id)
+        },
+    )
+}
+fn wire_list_fiat_currencies_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<mirror_FiatCurrency>, _>(
+        WrapInfo {
+            debug_name: "list_fiat_currencies",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_fiat_currencies(),
+    )
+}
+fn wire_pay_onchain_impl(port_: MessagePort, req: impl Wire2Api<PayOnchainRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PayOnchainResponse, _>(
+        WrapInfo {
+            debug_name: "pay_onchain",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| pay_onchain(api_req)
+        },
+    )
+}
+fn wire_pay_onchain_address_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<PayOnchainAddressRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PayOnchainAddressResponse, _>(
+        WrapInfo {
+            debug_name: "pay_onchain_address",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| pay_onchain_address(api_req)
+        },
+    )
+}
+fn wire_receive_onchain_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ReceiveOnchainRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, SwapInfo, _>(
+        WrapInfo {
+            debug_name: "receive_onchain",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| receive_onchain(api_req)
+        },
+    )
+}
+fn wire_buy_bitcoin_impl(port_: MessagePort, req: impl Wire2Api<BuyBitcoinRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BuyBitcoinResponse, _>(
+        WrapInfo {
+            debug_name: "buy_bitcoin",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| buy_bitcoin(api_req)
+        },
+    )
+}
+fn wire_fetch_buy_bitcoin_quote_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<FetchBuyBitcoinQuoteRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, mirror_BuyBitcoinQuote, _>(
+        WrapInfo {
+            debug_name: "fetch_buy_bitcoin_quote",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| fetch_buy_bitcoin_quote(api_req)
+        },
+    )
+}
+fn wire_redeem_onchain_funds_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<RedeemOnchainFundsRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RedeemOnchainFundsResponse, _>(
+        WrapInfo {
+            debug_name: "redeem_onchain_funds",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| redeem_onchain_funds(api_req)
+        },
+    )
+}
+fn wire_bump_fee_impl(port_: MessagePort, req: impl Wire2Api<BumpFeeRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, BumpFeeResponse, _>(
+        WrapInfo {
+            debug_name: "bump_fee",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| bump_fee(api_req)
+        },
+    )
+}
+fn wire_prepare_redeem_onchain_funds_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<PrepareRedeemOnchainFundsRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareRedeemOnchainFundsResponse, _>(
+        WrapInfo {
+            debug_name: "prepare_redeem_onchain_funds",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| prepare_redeem_onchain_funds(api_req)
+        },
+    )
+}
+fn wire_list_refundables_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SwapInfo>, _>(
+        WrapInfo {
+            debug_name: "list_refundables",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| list_refundables(),
+    )
+}
+fn wire_export_swap_refund_keys_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ExportSwapRefundKeysRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SwapRefundKey>, _>(
+        WrapInfo {
+            debug_name: "export_swap_refund_keys",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| export_swap_refund_keys(api_req)
+        },
+    )
+}
+fn wire_prepare_refund_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<PrepareRefundRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareRefundResponse, _>(
+        WrapInfo {
+            debug_name: "prepare_refund",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| prepare_refund(api_req)
+        },
+    )
+}
+fn wire_refund_impl(port_: MessagePort, req: impl Wire2Api<RefundRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RefundResponse, _>(
+        WrapInfo {
+            debug_name: "refund",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| refund(api_req)
+        },
+    )
+}
+fn wire_rescan_swaps_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "rescan_swaps",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| rescan_swaps(),
+    )
+}
+fn wire_redeem_swap_impl(port_: MessagePort, swap_address: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "redeem_swap",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_swap_address = swap_address.wire2api();
+            move |task_callback| redeem_swap(api_swap_address)
+        },
+    )
+}
+fn wire_verify_swap_address_impl(
+    port_: MessagePort,
+    swap_address: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "verify_swap_address",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_swap_address = swap_address.wire2api();
+            move |task_callback| verify_swap_address(api_swap_address)
+        },
+    )
+}
+fn wire_in_progress_swap_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<SwapInfo>, _>(
+        WrapInfo {
+            debug_name: "in_progress_swap",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| in_progress_swap(),
+    )
+}
+fn wire_list_swaps_impl(port_: MessagePort, req: impl Wire2Api<ListSwapsRequest> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SwapInfo>, _>(
+        WrapInfo {
+            debug_name: "list_swaps",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| list_swaps(api_req)
+        },
+    )
+}
+fn wire_claim_reverse_swap_impl(
+    port_: MessagePort,
+    lockup_address: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "claim_reverse_swap",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_lockup_address = lockup_address.wire2api();
+            move |task_callback| claim_reverse_swap(api_lockup_address)
+        },
+    )
+}
+fn wire_open_channel_fee_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<OpenChannelFeeRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OpenChannelFeeResponse, _>(
+        WrapInfo {
+            debug_name: "open_channel_fee",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| open_channel_fee(api_req)
+        },
+    )
+}
+fn wire_fetch_reverse_swap_fees_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<ReverseSwapFeesRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, ReverseSwapPairInfo, _>(
+        WrapInfo {
+            debug_name: "fetch_reverse_swap_fees",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| fetch_reverse_swap_fees(api_req)
+        },
+    )
+}
+fn wire_onchain_payment_limits_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OnchainPaymentLimitsResponse, _>(
+        WrapInfo {
+            debug_name: "onchain_payment_limits",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| onchain_payment_limits(),
+    )
+}
+fn wire_prepare_onchain_payment_impl(
+    port_: MessagePort,
+    req: impl Wire2Api<PrepareOnchainPaymentRequest> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, PrepareOnchainPaymentResponse, _>(
+        WrapInfo {
+            debug_name: "prepare_onchain_payment",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.wire2api();
+            move |task_callback| prepare_onchain_payment(api_req)
+        },
+    )
+}
+fn wire_in_progress_onchain_payments_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<ReverseSwapInfo>, _>(
+        WrapInfo {
+            debug_name: "in_progress_onchain_payments",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| in_progress_onchain_payments(),
+    )
+}
+fn wire_recommended_fees_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, RecommendedFees, _>(
+        WrapInfo {
+            debug_name: "recommended_fees",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| recommended_fees(),
+    )
+}
+fn wire_execute_command_impl(port_: MessagePort, command: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "execute_command",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_command = command.wire2api();
+            move |task_callback| execute_command(api_command)
+        },
+    )
+}
+fn wire_generate_diagnostic_data_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "generate_diagnostic_data",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| generate_diagnostic_data(),
+    )
+}
+fn wire_diagnostic_snapshot_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "diagnostic_snapshot",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| diagnostic_snapshot(),
+    )
+}
+fn wire_diff_snapshots_impl(
+    port_: MessagePort,
+    a: impl Wire2Api<String> + UnwindSafe,
+    b: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<SnapshotChange>, _>(
+        WrapInfo {
+            debug_name: "diff_snapshots",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_a = a.wire2api();
+            let api_b = b.wire2api();
+            move |task_callback| diff_snapshots(api_a, api_b)
+        },
+    )
+}
+// Section: wrapper structs
+
+#[derive(Clone)]
+pub struct mirror_AesSuccessActionDataDecrypted(AesSuccessActionDataDecrypted);
+
+#[derive(Clone)]
+pub struct mirror_AesSuccessActionDataResult(AesSuccessActionDataResult);
+
+#[derive(Clone)]
+pub struct mirror_Amount(Amount);
+
+#[derive(Clone)]
+pub struct mirror_BitcoinAddressData(BitcoinAddressData);
+
+#[derive(Clone)]
+pub struct mirror_BuyBitcoinQuote(BuyBitcoinQuote);
+
+#[derive(Clone)]
+pub struct mirror_CurrencyInfo(CurrencyInfo);
+
+#[derive(Clone)]
+pub struct mirror_EncryptedPaymentRequestData(EncryptedPaymentRequestData);
+
+#[derive(Clone)]
+pub struct mirror_FeatureSupport(FeatureSupport);
+
+#[derive(Clone)]
+pub struct mirror_FiatCurrency(FiatCurrency);
+
+#[derive(Clone)]
+pub struct mirror_InputType(InputType);
+
+#[derive(Clone)]
+pub struct mirror_InvoiceFeatures(InvoiceFeatures);
+
+#[derive(Clone)]
+pub struct mirror_LNInvoice(LNInvoice);
+
+#[derive(Clone)]
+pub struct mirror_LNOffer(LNOffer);
+
+#[derive(Clone)]
+pub struct mirror_LnOfferBlindedPath(LnOfferBlindedPath);
+
+#[derive(Clone)]
+pub struct mirror_LnUrlAuthRequestData(LnUrlAuthRequestData);
+
+#[derive(Clone)]
+pub struct mirror_LnUrlCallbackStatus(LnUrlCallbackStatus);
+
+#[derive(Clone)]
+pub struct mirror_LnUrlErrorData(LnUrlErrorData);
 
 #[derive(Clone)]
 pub struct mirror_LnUrlPayErrorData(LnUrlPayErrorData);
@@ -936,9 +1999,15 @@ pub struct mirror_LocalizedName(LocalizedName);
 #[derive(Clone)]
 pub struct mirror_MessageSuccessActionData(MessageSuccessActionData);
 
+#[derive(Clone)]
+pub struct mirror_MilliSat(MilliSat);
+
 #[derive(Clone)]
 pub struct mirror_Network(Network);
 
+#[derive(Clone)]
+pub struct mirror_ProxyConfig(ProxyConfig);
+
 #[derive(Clone)]
 pub struct mirror_Rate(Rate);
 
@@ -948,6 +2017,9 @@ pub struct mirror_RouteHint(RouteHint);
 #[derive(Clone)]
 pub struct mirror_RouteHintHop(RouteHintHop);
 
+#[derive(Clone)]
+pub struct mirror_Sat(Sat);
+
 #[derive(Clone)]
 pub struct mirror_SuccessActionProcessed(SuccessActionProcessed);
 
@@ -973,6 +2045,18 @@ const _: fn() = || {
             let _: String = reason;
         }
     }
+    match None::<Amount>.unwrap() {
+        Amount::Bitcoin { amount_msat } => {
+            let _: u64 = amount_msat;
+        }
+        Amount::Currency {
+            iso4217_code,
+            fractional_amount,
+        } => {
+            let _: String = iso4217_code;
+            let _: u64 = fractional_amount;
+        }
+    }
     {
         let BitcoinAddressData = None::<BitcoinAddressData>.unwrap();
         let _: String = BitcoinAddressData.address;
@@ -981,6 +2065,13 @@ const _: fn() = || {
         let _: Option<String> = BitcoinAddressData.label;
         let _: Option<String> = BitcoinAddressData.message;
     }
+    {
+        let BuyBitcoinQuote = None::<BuyBitcoinQuote>.unwrap();
+        let _: String = BuyBitcoinQuote.fiat_currency;
+        let _: f64 = BuyBitcoinQuote.fiat_amount;
+        let _: f64 = BuyBitcoinQuote.fees_fiat;
+        let _: u64 = BuyBitcoinQuote.amount_sat;
+    }
     {
         let CurrencyInfo = None::<CurrencyInfo>.unwrap();
         let _: String = CurrencyInfo.name;
@@ -991,6 +2082,15 @@ const _: fn() = || {
         let _: Vec<LocalizedName> = CurrencyInfo.localized_name;
         let _: Vec<LocaleOverrides> = CurrencyInfo.locale_overrides;
     }
+    {
+        let EncryptedPaymentRequestData = None::<EncryptedPaymentRequestData>.unwrap();
+        let _: String = EncryptedPaymentRequestData.blob;
+    }
+    match None::<FeatureSupport>.unwrap() {
+        FeatureSupport::NotSupported => {}
+        FeatureSupport::Optional => {}
+        FeatureSupport::Required => {}
+    }
     {
         let FiatCurrency = None::<FiatCurrency>.unwrap();
         let _: String = FiatCurrency.id;
@@ -1003,6 +2103,13 @@ const _: fn() = || {
         InputType::Bolt11 { invoice } => {
             let _: LNInvoice = invoice;
         }
+        InputType::Bolt12Offer {
+            offer,
+            bip353_address,
+        } => {
+            let _: LNOffer = offer;
+            let _: Option<String> = bip353_address;
+        }
         InputType::NodeId { node_id } => {
             let _: String = node_id;
         }
@@ -1012,9 +2119,13 @@ const _: fn() = || {
         InputType::LnUrlPay {
             data,
             bip353_address,
+            amount_msat,
+            comment,
         } => {
             let _: LnUrlPayRequestData = data;
             let _: Option<String> = bip353_address;
+            let _: Option<u64> = amount_msat;
+            let _: Option<String> = comment;
         }
         InputType::LnUrlWithdraw { data } => {
             let _: LnUrlWithdrawRequestData = data;
@@ -1025,6 +2136,16 @@ const _: fn() = || {
         InputType::LnUrlError { data } => {
             let _: LnUrlErrorData = data;
         }
+        InputType::EncryptedPaymentRequest { data } => {
+            let _: EncryptedPaymentRequestData = data;
+        }
+    }
+    {
+        let InvoiceFeatures = None::<InvoiceFeatures>.unwrap();
+        let _: FeatureSupport = InvoiceFeatures.basic_mpp;
+        let _: FeatureSupport = InvoiceFeatures.payment_secret;
+        let _: FeatureSupport = InvoiceFeatures.trampoline;
+        let _: bool = InvoiceFeatures.requires_unknown_features;
     }
     {
         let LNInvoice = None::<LNInvoice>.unwrap();
@@ -1037,9 +2158,27 @@ const _: fn() = || {
         let _: Option<u64> = LNInvoice.amount_msat;
         let _: u64 = LNInvoice.timestamp;
         let _: u64 = LNInvoice.expiry;
+        let _: u64 = LNInvoice.expires_at;
+        let _: bool = LNInvoice.is_expired;
         let _: Vec<RouteHint> = LNInvoice.routing_hints;
         let _: Vec<u8> = LNInvoice.payment_secret;
         let _: u64 = LNInvoice.min_final_cltv_expiry_delta;
+        let _: InvoiceFeatures = LNInvoice.features;
+    }
+    {
+        let LNOffer = None::<LNOffer>.unwrap();
+        let _: String = LNOffer.offer;
+        let _: Vec<String> = LNOffer.chains;
+        let _: Option<Amount> = LNOffer.min_amount;
+        let _: Option<String> = LNOffer.description;
+        let _: Option<u64> = LNOffer.absolute_expiry;
+        let _: Option<String> = LNOffer.issuer;
+        let _: Option<String> = LNOffer.signing_pubkey;
+        let _: Vec<LnOfferBlindedPath> = LNOffer.paths;
+    }
+    {
+        let LnOfferBlindedPath = None::<LnOfferBlindedPath>.unwrap();
+        let _: Vec<String> = LnOfferBlindedPath.blinded_hops;
     }
     {
         let LnUrlAuthRequestData = None::<LnUrlAuthRequestData>.unwrap();
@@ -1113,12 +2252,22 @@ const _: fn() = || {
         let MessageSuccessActionData = None::<MessageSuccessActionData>.unwrap();
         let _: String = MessageSuccessActionData.message;
     }
+    {
+        let MilliSat = None::<MilliSat>.unwrap();
+        let _: u64 = MilliSat.0;
+    }
     match None::<Network>.unwrap() {
         Network::Bitcoin => {}
         Network::Testnet => {}
         Network::Signet => {}
         Network::Regtest => {}
     }
+    {
+        let ProxyConfig = None::<ProxyConfig>.unwrap();
+        let _: String = ProxyConfig.address;
+        let _: Option<String> = ProxyConfig.username;
+        let _: Option<String> = ProxyConfig.password;
+    }
     {
         let Rate = None::<Rate>.unwrap();
         let _: String = Rate.coin;
@@ -1138,6 +2287,10 @@ const _: fn() = || {
         let _: Option<u64> = RouteHintHop.htlc_minimum_msat;
         let _: Option<u64> = RouteHintHop.htlc_maximum_msat;
     }
+    {
+        let Sat = None::<Sat>.unwrap();
+        let _: u64 = Sat.0;
+    }
     match None::<SuccessActionProcessed>.unwrap() {
         SuccessActionProcessed::Aes { result } => {
             let _: AesSuccessActionDataResult = result;
@@ -1191,25 +2344,67 @@ impl Wire2Api<BuyBitcoinProvider> for i32 {
     fn wire2api(self) -> BuyBitcoinProvider {
         match self {
             0 => BuyBitcoinProvider::Moonpay,
+            1 => BuyBitcoinProvider::Transak,
             _ => unreachable!("Invalid variant for BuyBitcoinProvider: {}", self),
         }
     }
 }
 
+impl Wire2Api<Denomination> for i32 {
+    fn wire2api(self) -> Denomination {
+        match self {
+            0 => Denomination::Btc,
+            1 => Denomination::Sat,
+            _ => unreachable!("Invalid variant for Denomination: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<EnvironmentType> for i32 {
     fn wire2api(self) -> EnvironmentType {
         match self {
             0 => EnvironmentType::Production,
             1 => EnvironmentType::Staging,
+            2 => EnvironmentType::Regtest,
             _ => unreachable!("Invalid variant for EnvironmentType: {}", self),
         }
     }
 }
+impl Wire2Api<ExportFormat> for i32 {
+    fn wire2api(self) -> ExportFormat {
+        match self {
+            0 => ExportFormat::Csv,
+            1 => ExportFormat::Json,
+            _ => unreachable!("Invalid variant for ExportFormat: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<f64> for f64 {
     fn wire2api(self) -> f64 {
         self
     }
 }
+impl Wire2Api<FeeratePreset> for i32 {
+    fn wire2api(self) -> FeeratePreset {
+        match self {
+            0 => FeeratePreset::Regular,
+            1 => FeeratePreset::Economy,
+            2 => FeeratePreset::Priority,
+            _ => unreachable!("Invalid variant for FeeratePreset: {}", self),
+        }
+    }
+}
+
+impl Wire2Api<FiatRateAlertDirection> for i32 {
+    fn wire2api(self) -> FiatRateAlertDirection {
+        match self {
+            0 => FiatRateAlertDirection::Above,
+            1 => FiatRateAlertDirection::Below,
+            _ => unreachable!("Invalid variant for FiatRateAlertDirection: {}", self),
+        }
+    }
+}
 
 impl Wire2Api<i32> for i32 {
     fn wire2api(self) -> i32 {
@@ -1222,6 +2417,17 @@ impl Wire2Api<i64> for i64 {
     }
 }
 
+impl Wire2Api<MemoPrivacy> for i32 {
+    fn wire2api(self) -> MemoPrivacy {
+        match self {
+            0 => MemoPrivacy::Plain,
+            1 => MemoPrivacy::Hash,
+            2 => MemoPrivacy::Strip,
+            _ => unreachable!("Invalid variant for MemoPrivacy: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<Network> for i32 {
     fn wire2api(self) -> Network {
         match self {
@@ -1234,17 +2440,38 @@ impl Wire2Api<Network> for i32 {
     }
 }
 
+impl Wire2Api<PaymentGroupBy> for i32 {
+    fn wire2api(self) -> PaymentGroupBy {
+        match self {
+            0 => PaymentGroupBy::DestinationPubkey,
+            1 => PaymentGroupBy::LnAddress,
+            _ => unreachable!("Invalid variant for PaymentGroupBy: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<PaymentTypeFilter> for i32 {
     fn wire2api(self) -> PaymentTypeFilter {
         match self {
             0 => PaymentTypeFilter::Sent,
             1 => PaymentTypeFilter::Received,
             2 => PaymentTypeFilter::ClosedChannel,
+            3 => PaymentTypeFilter::SpontaneousReceived,
             _ => unreachable!("Invalid variant for PaymentTypeFilter: {}", self),
         }
     }
 }
 
+impl Wire2Api<SortOrder> for i32 {
+    fn wire2api(self) -> SortOrder {
+        match self {
+            0 => SortOrder::Descending,
+            1 => SortOrder::Ascending,
+            _ => unreachable!("Invalid variant for SortOrder: {}", self),
+        }
+    }
+}
+
 impl Wire2Api<SwapAmountType> for i32 {
     fn wire2api(self) -> SwapAmountType {
         match self {
@@ -1329,6 +2556,43 @@ impl rust2dart::IntoIntoDart<mirror_AesSuccessActionDataResult> for AesSuccessAc
     }
 }
 
+impl support::IntoDart for mirror_Amount {
+    fn into_dart(self) -> support::DartAbi {
+        match self.0 {
+            Amount::Bitcoin { amount_msat } => {
+                vec![0.into_dart(), amount_msat.into_into_dart().into_dart()]
+            }
+            Amount::Currency {
+                iso4217_code,
+                fractional_amount,
+            } => vec![
+                1.into_dart(),
+                iso4217_code.into_into_dart().into_dart(),
+                fractional_amount.into_into_dart().into_dart(),
+            ],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_Amount {}
+impl rust2dart::IntoIntoDart<mirror_Amount> for Amount {
+    fn into_into_dart(self) -> mirror_Amount {
+        mirror_Amount(self)
+    }
+}
+
+impl support::IntoDart for AutoRefundConfig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.to_address.into_dart(), self.sat_per_vbyte.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for AutoRefundConfig {}
+impl rust2dart::IntoIntoDart<AutoRefundConfig> for AutoRefundConfig {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for BackupFailedData {
     fn into_dart(self) -> support::DartAbi {
         vec![self.error.into_into_dart().into_dart()].into_dart()
@@ -1350,79 +2614,392 @@ impl support::IntoDart for BackupStatus {
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for BackupStatus {}
-impl rust2dart::IntoIntoDart<BackupStatus> for BackupStatus {
+impl support::IntoDartExceptPrimitive for BackupStatus {}
+impl rust2dart::IntoIntoDart<BackupStatus> for BackupStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for BackupTransportConfig {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::S3 {
+                endpoint,
+                region,
+                bucket,
+                access_key_id,
+                secret_access_key,
+            } => vec![
+                0.into_dart(),
+                endpoint.into_into_dart().into_dart(),
+                region.into_into_dart().into_dart(),
+                bucket.into_into_dart().into_dart(),
+                access_key_id.into_into_dart().into_dart(),
+                secret_access_key.into_into_dart().into_dart(),
+            ],
+            Self::WebDav {
+                url,
+                username,
+                password,
+            } => vec![
+                1.into_dart(),
+                url.into_into_dart().into_dart(),
+                username.into_dart(),
+                password.into_dart(),
+            ],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BackupTransportConfig {}
+impl rust2dart::IntoIntoDart<BackupTransportConfig> for BackupTransportConfig {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for BalanceBreakdown {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.spendable_msat.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.channel_reserves_msat.into_into_dart().into_dart(),
+            self.htlcs_out_msat.into_into_dart().into_dart(),
+            self.htlcs_in_msat.into_into_dart().into_dart(),
+            self.dust_htlcs_msat.into_into_dart().into_dart(),
+            self.pending_channel_opens_msat.into_into_dart().into_dart(),
+            self.onchain_confirmed_msat.into_into_dart().into_dart(),
+            self.onchain_unconfirmed_msat.into_into_dart().into_dart(),
+            self.swaps_locked_msat.into_into_dart().into_dart(),
+            self.reverse_swaps_locked_msat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BalanceBreakdown {}
+impl rust2dart::IntoIntoDart<BalanceBreakdown> for BalanceBreakdown {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for Balances {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.lightning_spendable_msat.into_into_dart().into_dart(),
+            self.lightning_pending_msat.into_into_dart().into_dart(),
+            self.onchain_confirmed_msat.into_into_dart().into_dart(),
+            self.onchain_unconfirmed_msat.into_into_dart().into_dart(),
+            self.swaps_locked_msat.into_into_dart().into_dart(),
+            self.reverse_swaps_locked_msat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Balances {}
+impl rust2dart::IntoIntoDart<Balances> for Balances {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for BatchPaymentProgressDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.result.into_into_dart().into_dart(),
+            self.succeeded.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BatchPaymentProgressDetails {}
+impl rust2dart::IntoIntoDart<BatchPaymentProgressDetails> for BatchPaymentProgressDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for BatchPaymentResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_into_dart().into_dart(),
+            self.bolt11.into_into_dart().into_dart(),
+            self.payment.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BatchPaymentResult {}
+impl rust2dart::IntoIntoDart<BatchPaymentResult> for BatchPaymentResult {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for mirror_BitcoinAddressData {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.0.address.into_into_dart().into_dart(),
+            self.0.network.into_into_dart().into_dart(),
+            self.0.amount_sat.into_dart(),
+            self.0.label.into_dart(),
+            self.0.message.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_BitcoinAddressData {}
+impl rust2dart::IntoIntoDart<mirror_BitcoinAddressData> for BitcoinAddressData {
+    fn into_into_dart(self) -> mirror_BitcoinAddressData {
+        mirror_BitcoinAddressData(self)
+    }
+}
+
+impl support::IntoDart for BreezEvent {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::NewBlock { block } => vec![0.into_dart(), block.into_into_dart().into_dart()],
+            Self::InvoicePaid { details } => {
+                vec![1.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::Synced => vec![2.into_dart()],
+            Self::PaymentSucceed { details } => {
+                vec![3.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::PaymentFailed { details } => {
+                vec![4.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::BackupStarted => vec![5.into_dart()],
+            Self::BackupSucceeded => vec![6.into_dart()],
+            Self::BackupFailed { details } => {
+                vec![7.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::ReverseSwapUpdated { details } => {
+                vec![8.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::SwapUpdated { details } => {
+                vec![9.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::ConnectProgress { details } => {
+                vec![10.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::UnredeemedFundsDetected { details } => {
+                vec![11.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::LnUrlPayFinished { details } => {
+                vec![12.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::OpenChannelReceiveUpdated { details } => {
+                vec![13.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::LowOutboundLiquidity { details } => {
+                vec![14.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::LowInboundLiquidity { details } => {
+                vec![15.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::ChannelOpening {
+                fee_msat,
+                min_fee_msat,
+                proportional,
+            } => vec![
+                16.into_dart(),
+                fee_msat.into_into_dart().into_dart(),
+                min_fee_msat.into_into_dart().into_dart(),
+                proportional.into_into_dart().into_dart(),
+            ],
+            Self::LspChanged {
+                previous_lsp_id,
+                lsp_id,
+            } => vec![
+                17.into_dart(),
+                previous_lsp_id.into_dart(),
+                lsp_id.into_into_dart().into_dart(),
+            ],
+            Self::SwapRefunded { details } => {
+                vec![18.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::BatchPaymentProgress { details } => {
+                vec![19.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::OutboxPaymentUpdated { details } => {
+                vec![20.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::LnUrlAuthCompleted { details } => {
+                vec![21.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::ChannelHygieneSuggestion { details } => {
+                vec![22.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::FiatRateAlertTriggered { details } => {
+                vec![23.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::Connected => vec![24.into_dart()],
+            Self::Disconnected { error } => {
+                vec![25.into_dart(), error.into_into_dart().into_dart()]
+            }
+            Self::Reconnecting { attempt, delay_ms } => vec![
+                26.into_dart(),
+                attempt.into_into_dart().into_dart(),
+                delay_ms.into_into_dart().into_dart(),
+            ],
+            Self::LspPeerReconnected { lsp_pubkey, reason } => vec![
+                27.into_dart(),
+                lsp_pubkey.into_into_dart().into_dart(),
+                reason.into_into_dart().into_dart(),
+            ],
+            Self::SpontaneousPaymentReceived { details } => {
+                vec![28.into_dart(), details.into_into_dart().into_dart()]
+            }
+            Self::ServiceStatusChanged { component, status } => vec![
+                29.into_dart(),
+                component.into_into_dart().into_dart(),
+                status.into_into_dart().into_dart(),
+            ],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BreezEvent {}
+impl rust2dart::IntoIntoDart<BreezEvent> for BreezEvent {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for BumpFeeResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.txid.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BumpFeeResponse {}
+impl rust2dart::IntoIntoDart<BumpFeeResponse> for BumpFeeResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for mirror_BuyBitcoinQuote {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.0.fiat_currency.into_into_dart().into_dart(),
+            self.0.fiat_amount.into_into_dart().into_dart(),
+            self.0.fees_fiat.into_into_dart().into_dart(),
+            self.0.amount_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_BuyBitcoinQuote {}
+impl rust2dart::IntoIntoDart<mirror_BuyBitcoinQuote> for BuyBitcoinQuote {
+    fn into_into_dart(self) -> mirror_BuyBitcoinQuote {
+        mirror_BuyBitcoinQuote(self)
+    }
+}
+
+impl support::IntoDart for BuyBitcoinResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.url.into_into_dart().into_dart(),
+            self.opening_fee_params.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for BuyBitcoinResponse {}
+impl rust2dart::IntoIntoDart<BuyBitcoinResponse> for BuyBitcoinResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ChainServiceConfig {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Esplora { url } => vec![0.into_dart(), url.into_into_dart().into_dart()],
+            Self::Electrum { url } => vec![1.into_dart(), url.into_into_dart().into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ChainServiceConfig {}
+impl rust2dart::IntoIntoDart<ChainServiceConfig> for ChainServiceConfig {
     fn into_into_dart(self) -> Self {
         self
     }
 }
 
-impl support::IntoDart for mirror_BitcoinAddressData {
+impl support::IntoDart for ChannelDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
-            self.0.address.into_into_dart().into_dart(),
-            self.0.network.into_into_dart().into_dart(),
-            self.0.amount_sat.into_dart(),
-            self.0.label.into_dart(),
-            self.0.message.into_dart(),
+            self.channel_id.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.peer_connected.into_into_dart().into_dart(),
+            self.short_channel_id.into_dart(),
+            self.funding_txid.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.capacity_msat.into_into_dart().into_dart(),
+            self.local_balance_msat.into_into_dart().into_dart(),
+            self.remote_balance_msat.into_into_dart().into_dart(),
+            self.spendable_msat.into_into_dart().into_dart(),
+            self.receivable_msat.into_into_dart().into_dart(),
+            self.reserve_msat.into_into_dart().into_dart(),
+            self.htlcs_out_msat.into_into_dart().into_dart(),
+            self.htlcs_in_msat.into_into_dart().into_dart(),
+            self.dust_htlcs_msat.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for mirror_BitcoinAddressData {}
-impl rust2dart::IntoIntoDart<mirror_BitcoinAddressData> for BitcoinAddressData {
-    fn into_into_dart(self) -> mirror_BitcoinAddressData {
-        mirror_BitcoinAddressData(self)
+impl support::IntoDartExceptPrimitive for ChannelDetails {}
+impl rust2dart::IntoIntoDart<ChannelDetails> for ChannelDetails {
+    fn into_into_dart(self) -> Self {
+        self
     }
 }
 
-impl support::IntoDart for BreezEvent {
+impl support::IntoDart for ChannelHygieneSuggestionDetails {
     fn into_dart(self) -> support::DartAbi {
-        match self {
-            Self::NewBlock { block } => vec![0.into_dart(), block.into_into_dart().into_dart()],
-            Self::InvoicePaid { details } => {
-                vec![1.into_dart(), details.into_into_dart().into_dart()]
-            }
-            Self::Synced => vec![2.into_dart()],
-            Self::PaymentSucceed { details } => {
-                vec![3.into_dart(), details.into_into_dart().into_dart()]
-            }
-            Self::PaymentFailed { details } => {
-                vec![4.into_dart(), details.into_into_dart().into_dart()]
-            }
-            Self::BackupStarted => vec![5.into_dart()],
-            Self::BackupSucceeded => vec![6.into_dart()],
-            Self::BackupFailed { details } => {
-                vec![7.into_dart(), details.into_into_dart().into_dart()]
-            }
-            Self::ReverseSwapUpdated { details } => {
-                vec![8.into_dart(), details.into_into_dart().into_dart()]
-            }
-            Self::SwapUpdated { details } => {
-                vec![9.into_dart(), details.into_into_dart().into_dart()]
-            }
-        }
+        vec![
+            self.channel_id.into_into_dart().into_dart(),
+            self.peer_id.into_into_dart().into_dart(),
+            self.funding_txid.into_into_dart().into_dart(),
+            self.local_balance_msat.into_into_dart().into_dart(),
+            self.idle_secs.into_into_dart().into_dart(),
+            self.peer_offline_secs.into_into_dart().into_dart(),
+            self.sat_per_vbyte.into_into_dart().into_dart(),
+            self.projected_sweep_fee_sat.into_into_dart().into_dart(),
+            self.projected_sweep_amount_sat.into_into_dart().into_dart(),
+        ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for BreezEvent {}
-impl rust2dart::IntoIntoDart<BreezEvent> for BreezEvent {
+impl support::IntoDartExceptPrimitive for ChannelHygieneSuggestionDetails {}
+impl rust2dart::IntoIntoDart<ChannelHygieneSuggestionDetails> for ChannelHygieneSuggestionDetails {
     fn into_into_dart(self) -> Self {
         self
     }
 }
 
-impl support::IntoDart for BuyBitcoinResponse {
+impl support::IntoDart for ChannelOpeningFee {
     fn into_dart(self) -> support::DartAbi {
         vec![
-            self.url.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.payment_time.into_into_dart().into_dart(),
+            self.payer_amount_msat.into_into_dart().into_dart(),
+            self.received_amount_msat.into_into_dart().into_dart(),
+            self.fee_msat.into_into_dart().into_dart(),
             self.opening_fee_params.into_dart(),
         ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for BuyBitcoinResponse {}
-impl rust2dart::IntoIntoDart<BuyBitcoinResponse> for BuyBitcoinResponse {
+impl support::IntoDartExceptPrimitive for ChannelOpeningFee {}
+impl rust2dart::IntoIntoDart<ChannelOpeningFee> for ChannelOpeningFee {
     fn into_into_dart(self) -> Self {
         self
     }
@@ -1458,6 +3035,18 @@ impl rust2dart::IntoIntoDart<CheckMessageResponse> for CheckMessageResponse {
     }
 }
 
+impl support::IntoDart for CloseChannelResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.closing_txid.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for CloseChannelResponse {}
+impl rust2dart::IntoIntoDart<CloseChannelResponse> for CloseChannelResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for ClosedChannelPaymentDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1481,14 +3070,36 @@ impl support::IntoDart for Config {
         vec![
             self.breezserver.into_into_dart().into_dart(),
             self.chainnotifier_url.into_into_dart().into_dart(),
+            self.lnurl_server_url.into_into_dart().into_dart(),
             self.mempoolspace_url.into_dart(),
+            self.chain_service.into_dart(),
+            self.backup_transport.into_dart(),
+            self.proxy.map(|v| mirror_ProxyConfig(v)).into_dart(),
             self.working_dir.into_into_dart().into_dart(),
+            self.db_dir.into_dart(),
+            self.credentials_dir.into_dart(),
             self.network.into_into_dart().into_dart(),
             self.payment_timeout_sec.into_into_dart().into_dart(),
+            self.max_concurrent_payments.into_into_dart().into_dart(),
             self.default_lsp_id.into_dart(),
+            self.lsp_policy.into_dart(),
             self.api_key.into_dart(),
             self.maxfee_percent.into_into_dart().into_dart(),
             self.exemptfee_msat.into_into_dart().into_dart(),
+            self.fiat_currency.into_dart(),
+            self.low_outbound_liquidity_threshold_msat.into_dart(),
+            self.low_inbound_liquidity_threshold_msat.into_dart(),
+            self.use_trampoline.into_into_dart().into_dart(),
+            self.partner_fee.into_dart(),
+            self.swap_auto_refund.into_dart(),
+            self.memo_privacy.into_into_dart().into_dart(),
+            self.channel_hygiene_window_secs.into_dart(),
+            self.log_filter.into_dart(),
+            self.denylist_url.into_dart(),
+            self.transak_api_key.into_dart(),
+            self.spend_policy.into_dart(),
+            self.htlc_exposure_limits.into_dart(),
+            self.service_status_check_interval_secs.into_dart(),
             self.node_config.into_into_dart().into_dart(),
         ]
         .into_dart()
@@ -1501,6 +3112,78 @@ impl rust2dart::IntoIntoDart<Config> for Config {
     }
 }
 
+impl support::IntoDart for ConnectProgressDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.stage.into_into_dart().into_dart(),
+            self.progress_percent.into_into_dart().into_dart(),
+            self.is_new_node.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ConnectProgressDetails {}
+impl rust2dart::IntoIntoDart<ConnectProgressDetails> for ConnectProgressDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ConnectStage {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::NodeRecovery => 0,
+            Self::NodeRegistration => 1,
+            Self::SchedulerHandshake => 2,
+            Self::NodeStartup => 3,
+            Self::InitialSync => 4,
+            Self::Completed => 5,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ConnectStage {}
+impl rust2dart::IntoIntoDart<ConnectStage> for ConnectStage {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ConnectionState {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Connected => 0,
+            Self::Disconnected => 1,
+            Self::Reconnecting => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ConnectionState {}
+impl rust2dart::IntoIntoDart<ConnectionState> for ConnectionState {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ConnectionStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.state.into_into_dart().into_dart(),
+            self.since.into_into_dart().into_dart(),
+            self.reconnect_attempts.into_into_dart().into_dart(),
+            self.last_error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ConnectionStatus {}
+impl rust2dart::IntoIntoDart<ConnectionStatus> for ConnectionStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_CurrencyInfo {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1522,6 +3205,108 @@ impl rust2dart::IntoIntoDart<mirror_CurrencyInfo> for CurrencyInfo {
     }
 }
 
+impl support::IntoDart for Denomination {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Btc => 0,
+            Self::Sat => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for Denomination {}
+impl rust2dart::IntoIntoDart<Denomination> for Denomination {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for DeriveEncryptionKeyResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.key.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for DeriveEncryptionKeyResponse {}
+impl rust2dart::IntoIntoDart<DeriveEncryptionKeyResponse> for DeriveEncryptionKeyResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for mirror_EncryptedPaymentRequestData {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.blob.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_EncryptedPaymentRequestData {}
+impl rust2dart::IntoIntoDart<mirror_EncryptedPaymentRequestData> for EncryptedPaymentRequestData {
+    fn into_into_dart(self) -> mirror_EncryptedPaymentRequestData {
+        mirror_EncryptedPaymentRequestData(self)
+    }
+}
+
+impl support::IntoDart for ExportRecoveryBundleResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.bundle.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ExportRecoveryBundleResponse {}
+impl rust2dart::IntoIntoDart<ExportRecoveryBundleResponse> for ExportRecoveryBundleResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for mirror_FeatureSupport {
+    fn into_dart(self) -> support::DartAbi {
+        match self.0 {
+            FeatureSupport::NotSupported => 0,
+            FeatureSupport::Optional => 1,
+            FeatureSupport::Required => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_FeatureSupport {}
+impl rust2dart::IntoIntoDart<mirror_FeatureSupport> for FeatureSupport {
+    fn into_into_dart(self) -> mirror_FeatureSupport {
+        mirror_FeatureSupport(self)
+    }
+}
+
+impl support::IntoDart for FeeratePreset {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Regular => 0,
+            Self::Economy => 1,
+            Self::Priority => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FeeratePreset {}
+impl rust2dart::IntoIntoDart<FeeratePreset> for FeeratePreset {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for FiatAmount {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.currency.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FiatAmount {}
+impl rust2dart::IntoIntoDart<FiatAmount> for FiatAmount {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_FiatCurrency {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1538,6 +3323,59 @@ impl rust2dart::IntoIntoDart<mirror_FiatCurrency> for FiatCurrency {
     }
 }
 
+impl support::IntoDart for FiatRateAlert {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.currency.into_into_dart().into_dart(),
+            self.direction.into_into_dart().into_dart(),
+            self.threshold.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.triggered.into_into_dart().into_dart(),
+            self.last_triggered_at.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FiatRateAlert {}
+impl rust2dart::IntoIntoDart<FiatRateAlert> for FiatRateAlert {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for FiatRateAlertDirection {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Above => 0,
+            Self::Below => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FiatRateAlertDirection {}
+impl rust2dart::IntoIntoDart<FiatRateAlertDirection> for FiatRateAlertDirection {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for FiatRateAlertTriggeredDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.alert.into_into_dart().into_dart(),
+            self.rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for FiatRateAlertTriggeredDetails {}
+impl rust2dart::IntoIntoDart<FiatRateAlertTriggeredDetails> for FiatRateAlertTriggeredDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for GreenlightCredentials {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1559,41 +3397,90 @@ impl support::IntoDart for GreenlightDeviceCredentials {
         vec![self.device.into_into_dart().into_dart()].into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for GreenlightDeviceCredentials {}
-impl rust2dart::IntoIntoDart<GreenlightDeviceCredentials> for GreenlightDeviceCredentials {
+impl support::IntoDartExceptPrimitive for GreenlightDeviceCredentials {}
+impl rust2dart::IntoIntoDart<GreenlightDeviceCredentials> for GreenlightDeviceCredentials {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for GreenlightNodeConfig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.partner_credentials.into_dart(),
+            self.invite_code.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for GreenlightNodeConfig {}
+impl rust2dart::IntoIntoDart<GreenlightNodeConfig> for GreenlightNodeConfig {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for HealthCheckStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Operational => 0,
+            Self::Maintenance => 1,
+            Self::ServiceDisruption => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for HealthCheckStatus {}
+impl rust2dart::IntoIntoDart<HealthCheckStatus> for HealthCheckStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for HistoricalRate {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.coin.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for HistoricalRate {}
+impl rust2dart::IntoIntoDart<HistoricalRate> for HistoricalRate {
     fn into_into_dart(self) -> Self {
         self
     }
 }
 
-impl support::IntoDart for GreenlightNodeConfig {
+impl support::IntoDart for HtlcExposureLimits {
     fn into_dart(self) -> support::DartAbi {
         vec![
-            self.partner_credentials.into_dart(),
-            self.invite_code.into_dart(),
+            self.max_inflight_msat.into_dart(),
+            self.max_htlcs_per_payment.into_dart(),
         ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for GreenlightNodeConfig {}
-impl rust2dart::IntoIntoDart<GreenlightNodeConfig> for GreenlightNodeConfig {
+impl support::IntoDartExceptPrimitive for HtlcExposureLimits {}
+impl rust2dart::IntoIntoDart<HtlcExposureLimits> for HtlcExposureLimits {
     fn into_into_dart(self) -> Self {
         self
     }
 }
 
-impl support::IntoDart for HealthCheckStatus {
+impl support::IntoDart for ImportPaymentsResponse {
     fn into_dart(self) -> support::DartAbi {
-        match self {
-            Self::Operational => 0,
-            Self::Maintenance => 1,
-            Self::ServiceDisruption => 2,
-        }
+        vec![
+            self.imported_count.into_into_dart().into_dart(),
+            self.skipped_count.into_into_dart().into_dart(),
+        ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for HealthCheckStatus {}
-impl rust2dart::IntoIntoDart<HealthCheckStatus> for HealthCheckStatus {
+impl support::IntoDartExceptPrimitive for ImportPaymentsResponse {}
+impl rust2dart::IntoIntoDart<ImportPaymentsResponse> for ImportPaymentsResponse {
     fn into_into_dart(self) -> Self {
         self
     }
@@ -1608,24 +3495,39 @@ impl support::IntoDart for mirror_InputType {
             InputType::Bolt11 { invoice } => {
                 vec![1.into_dart(), invoice.into_into_dart().into_dart()]
             }
+            InputType::Bolt12Offer {
+                offer,
+                bip353_address,
+            } => vec![
+                2.into_dart(),
+                offer.into_into_dart().into_dart(),
+                bip353_address.into_dart(),
+            ],
             InputType::NodeId { node_id } => {
-                vec![2.into_dart(), node_id.into_into_dart().into_dart()]
+                vec![3.into_dart(), node_id.into_into_dart().into_dart()]
             }
-            InputType::Url { url } => vec![3.into_dart(), url.into_into_dart().into_dart()],
+            InputType::Url { url } => vec![4.into_dart(), url.into_into_dart().into_dart()],
             InputType::LnUrlPay {
                 data,
                 bip353_address,
+                amount_msat,
+                comment,
             } => vec![
-                4.into_dart(),
+                5.into_dart(),
                 data.into_into_dart().into_dart(),
                 bip353_address.into_dart(),
+                amount_msat.into_dart(),
+                comment.into_dart(),
             ],
             InputType::LnUrlWithdraw { data } => {
-                vec![5.into_dart(), data.into_into_dart().into_dart()]
+                vec![6.into_dart(), data.into_into_dart().into_dart()]
             }
-            InputType::LnUrlAuth { data } => vec![6.into_dart(), data.into_into_dart().into_dart()],
+            InputType::LnUrlAuth { data } => vec![7.into_dart(), data.into_into_dart().into_dart()],
             InputType::LnUrlError { data } => {
-                vec![7.into_dart(), data.into_into_dart().into_dart()]
+                vec![8.into_dart(), data.into_into_dart().into_dart()]
+            }
+            InputType::EncryptedPaymentRequest { data } => {
+                vec![9.into_dart(), data.into_into_dart().into_dart()]
             }
         }
         .into_dart()
@@ -1638,6 +3540,27 @@ impl rust2dart::IntoIntoDart<mirror_InputType> for InputType {
     }
 }
 
+impl support::IntoDart for mirror_InvoiceFeatures {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.0.basic_mpp.into_into_dart().into_dart(),
+            self.0.payment_secret.into_into_dart().into_dart(),
+            self.0.trampoline.into_into_dart().into_dart(),
+            self.0
+                .requires_unknown_features
+                .into_into_dart()
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_InvoiceFeatures {}
+impl rust2dart::IntoIntoDart<mirror_InvoiceFeatures> for InvoiceFeatures {
+    fn into_into_dart(self) -> mirror_InvoiceFeatures {
+        mirror_InvoiceFeatures(self)
+    }
+}
+
 impl support::IntoDart for InvoicePaidDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1655,6 +3578,44 @@ impl rust2dart::IntoIntoDart<InvoicePaidDetails> for InvoicePaidDetails {
     }
 }
 
+impl support::IntoDart for InvoicePayability {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.invoice.into_into_dart().into_dart(),
+            self.amount_msat.into_dart(),
+            self.is_payable.into_into_dart().into_dart(),
+            self.error.into_dart(),
+            self.max_payable_msat.into_into_dart().into_dart(),
+            self.payee_inbound_liquidity_msat.into_dart(),
+            self.lsp_route_hint.map(|v| mirror_RouteHint(v)).into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for InvoicePayability {}
+impl rust2dart::IntoIntoDart<InvoicePayability> for InvoicePayability {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for InvoiceVerificationResult {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.bolt11.into_into_dart().into_dart(),
+            self.invoice.map(|v| mirror_LNInvoice(v)).into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for InvoiceVerificationResult {}
+impl rust2dart::IntoIntoDart<InvoiceVerificationResult> for InvoiceVerificationResult {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_LNInvoice {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1667,12 +3628,15 @@ impl support::IntoDart for mirror_LNInvoice {
             self.0.amount_msat.into_dart(),
             self.0.timestamp.into_into_dart().into_dart(),
             self.0.expiry.into_into_dart().into_dart(),
+            self.0.expires_at.into_into_dart().into_dart(),
+            self.0.is_expired.into_into_dart().into_dart(),
             self.0.routing_hints.into_into_dart().into_dart(),
             self.0.payment_secret.into_into_dart().into_dart(),
             self.0
                 .min_final_cltv_expiry_delta
                 .into_into_dart()
                 .into_dart(),
+            self.0.features.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1684,6 +3648,40 @@ impl rust2dart::IntoIntoDart<mirror_LNInvoice> for LNInvoice {
     }
 }
 
+impl support::IntoDart for mirror_LNOffer {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.0.offer.into_into_dart().into_dart(),
+            self.0.chains.into_into_dart().into_dart(),
+            self.0.min_amount.map(|v| mirror_Amount(v)).into_dart(),
+            self.0.description.into_dart(),
+            self.0.absolute_expiry.into_dart(),
+            self.0.issuer.into_dart(),
+            self.0.signing_pubkey.into_dart(),
+            self.0.paths.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_LNOffer {}
+impl rust2dart::IntoIntoDart<mirror_LNOffer> for LNOffer {
+    fn into_into_dart(self) -> mirror_LNOffer {
+        mirror_LNOffer(self)
+    }
+}
+
+impl support::IntoDart for mirror_LnOfferBlindedPath {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.blinded_hops.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_LnOfferBlindedPath {}
+impl rust2dart::IntoIntoDart<mirror_LnOfferBlindedPath> for LnOfferBlindedPath {
+    fn into_into_dart(self) -> mirror_LnOfferBlindedPath {
+        mirror_LnOfferBlindedPath(self)
+    }
+}
+
 impl support::IntoDart for LnPaymentDetails {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1694,6 +3692,7 @@ impl support::IntoDart for LnPaymentDetails {
             self.keysend.into_into_dart().into_dart(),
             self.bolt11.into_into_dart().into_dart(),
             self.open_channel_bolt11.into_dart(),
+            self.channel_opening_fee_msat.into_dart(),
             self.lnurl_success_action
                 .map(|v| mirror_SuccessActionProcessed(v))
                 .into_dart(),
@@ -1705,6 +3704,8 @@ impl support::IntoDart for LnPaymentDetails {
             self.swap_info.into_dart(),
             self.reverse_swap_info.into_dart(),
             self.pending_expiration_block.into_dart(),
+            self.tlvs.into_dart(),
+            self.spontaneous_metadata.into_dart(),
         ]
         .into_dart()
     }
@@ -1716,6 +3717,41 @@ impl rust2dart::IntoIntoDart<LnPaymentDetails> for LnPaymentDetails {
     }
 }
 
+impl support::IntoDart for LnUrlAuthDomain {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.domain.into_into_dart().into_dart(),
+            self.linking_key.into_into_dart().into_dart(),
+            self.first_authenticated_at.into_into_dart().into_dart(),
+            self.last_authenticated_at.into_into_dart().into_dart(),
+            self.auth_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnUrlAuthDomain {}
+impl rust2dart::IntoIntoDart<LnUrlAuthDomain> for LnUrlAuthDomain {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for LnUrlAuthExport {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.hashing_key.into_into_dart().into_dart(),
+            self.domains.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnUrlAuthExport {}
+impl rust2dart::IntoIntoDart<LnUrlAuthExport> for LnUrlAuthExport {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_LnUrlAuthRequestData {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1780,6 +3816,34 @@ impl rust2dart::IntoIntoDart<mirror_LnUrlPayErrorData> for LnUrlPayErrorData {
     }
 }
 
+impl support::IntoDart for LnUrlPayFinishedDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.payment_hash.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnUrlPayFinishedDetails {}
+impl rust2dart::IntoIntoDart<LnUrlPayFinishedDetails> for LnUrlPayFinishedDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for LnUrlPayPendingData {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.payment_hash.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnUrlPayPendingData {}
+impl rust2dart::IntoIntoDart<LnUrlPayPendingData> for LnUrlPayPendingData {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_LnUrlPayRequestData {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1811,6 +3875,7 @@ impl support::IntoDart for LnUrlPayResult {
             }
             Self::EndpointError { data } => vec![1.into_dart(), data.into_into_dart().into_dart()],
             Self::PayError { data } => vec![2.into_dart(), data.into_into_dart().into_dart()],
+            Self::Pending { data } => vec![3.into_dart(), data.into_into_dart().into_dart()],
         }
         .into_dart()
     }
@@ -1829,6 +3894,7 @@ impl support::IntoDart for LnUrlPaySuccessData {
             self.success_action
                 .map(|v| mirror_SuccessActionProcessed(v))
                 .into_dart(),
+            self.partner_fee.into_dart(),
         ]
         .into_dart()
     }
@@ -1894,6 +3960,44 @@ impl rust2dart::IntoIntoDart<mirror_LnUrlWithdrawSuccessData> for LnUrlWithdrawS
     }
 }
 
+impl support::IntoDart for LnurlPayInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.lnurl.into_into_dart().into_dart(),
+            self.lightning_address.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnurlPayInfo {}
+impl rust2dart::IntoIntoDart<LnurlPayInfo> for LnurlPayInfo {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for LnurlWithdrawVoucher {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.lnurl.into_into_dart().into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.uses.into_into_dart().into_dart(),
+            self.redeemed_uses.into_into_dart().into_dart(),
+            self.description.into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LnurlWithdrawVoucher {}
+impl rust2dart::IntoIntoDart<LnurlWithdrawVoucher> for LnurlWithdrawVoucher {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_LocaleOverrides {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1932,6 +4036,7 @@ impl support::IntoDart for LogEntry {
         vec![
             self.line.into_into_dart().into_dart(),
             self.level.into_into_dart().into_dart(),
+            self.module.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -1943,6 +4048,22 @@ impl rust2dart::IntoIntoDart<LogEntry> for LogEntry {
     }
 }
 
+impl support::IntoDart for LowLiquidityDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.liquidity_msat.into_into_dart().into_dart(),
+            self.threshold_msat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LowLiquidityDetails {}
+impl rust2dart::IntoIntoDart<LowLiquidityDetails> for LowLiquidityDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for LspInformation {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -1968,6 +4089,40 @@ impl rust2dart::IntoIntoDart<LspInformation> for LspInformation {
     }
 }
 
+impl support::IntoDart for LspPolicy {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::LowestOpeningFee => vec![0.into_dart()],
+            Self::LowestProportionalFee => vec![1.into_dart()],
+            Self::Pinned { lsp_id } => vec![2.into_dart(), lsp_id.into_into_dart().into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for LspPolicy {}
+impl rust2dart::IntoIntoDart<LspPolicy> for LspPolicy {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for MemoPrivacy {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Plain => 0,
+            Self::Hash => 1,
+            Self::Strip => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MemoPrivacy {}
+impl rust2dart::IntoIntoDart<MemoPrivacy> for MemoPrivacy {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_MessageSuccessActionData {
     fn into_dart(self) -> support::DartAbi {
         vec![self.0.message.into_into_dart().into_dart()].into_dart()
@@ -1980,6 +4135,124 @@ impl rust2dart::IntoIntoDart<mirror_MessageSuccessActionData> for MessageSuccess
     }
 }
 
+impl support::IntoDart for MetricBucket {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.upper_bound.into_into_dart().into_dart(),
+            self.cumulative_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MetricBucket {}
+impl rust2dart::IntoIntoDart<MetricBucket> for MetricBucket {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for MetricCounter {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.name.into_into_dart().into_dart(),
+            self.help.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MetricCounter {}
+impl rust2dart::IntoIntoDart<MetricCounter> for MetricCounter {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for MetricHistogram {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.name.into_into_dart().into_dart(),
+            self.help.into_into_dart().into_dart(),
+            self.buckets.into_into_dart().into_dart(),
+            self.sum.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MetricHistogram {}
+impl rust2dart::IntoIntoDart<MetricHistogram> for MetricHistogram {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for MetricsSnapshot {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.counters.into_into_dart().into_dart(),
+            self.histograms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MetricsSnapshot {}
+impl rust2dart::IntoIntoDart<MetricsSnapshot> for MetricsSnapshot {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for MigrationChannel {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.funding_txid.into_into_dart().into_dart(),
+            self.funding_outnum.into_dart(),
+            self.short_channel_id.into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.local_balance_msat.into_into_dart().into_dart(),
+            self.peer_id.into_dart(),
+            self.capacity_msat.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MigrationChannel {}
+impl rust2dart::IntoIntoDart<MigrationChannel> for MigrationChannel {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for mirror_MilliSat {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0 .0.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_MilliSat {}
+impl rust2dart::IntoIntoDart<mirror_MilliSat> for MilliSat {
+    fn into_into_dart(self) -> mirror_MilliSat {
+        mirror_MilliSat(self)
+    }
+}
+
+impl support::IntoDart for MockNodeConfig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.channels_balance_msat.into_into_dart().into_dart(),
+            self.onchain_balance_msat.into_into_dart().into_dart(),
+            self.inbound_liquidity_msat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for MockNodeConfig {}
+impl rust2dart::IntoIntoDart<MockNodeConfig> for MockNodeConfig {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_Network {
     fn into_dart(self) -> support::DartAbi {
         match self.0 {
@@ -2002,6 +4275,7 @@ impl support::IntoDart for NodeConfig {
     fn into_dart(self) -> support::DartAbi {
         match self {
             Self::Greenlight { config } => vec![0.into_dart(), config.into_into_dart().into_dart()],
+            Self::Mock { config } => vec![1.into_dart(), config.into_into_dart().into_dart()],
         }
         .into_dart()
     }
@@ -2030,6 +4304,29 @@ impl rust2dart::IntoIntoDart<NodeCredentials> for NodeCredentials {
     }
 }
 
+impl support::IntoDart for NodeMigrationState {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.node_id.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.lsp_node_uri.into_dart(),
+            self.static_backup.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.closing_txids.into_into_dart().into_dart(),
+            self.channels_balance_msat.into_into_dart().into_dart(),
+            self.onchain_balance_msat.into_into_dart().into_dart(),
+            self.exported_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for NodeMigrationState {}
+impl rust2dart::IntoIntoDart<NodeMigrationState> for NodeMigrationState {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for NodeState {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -2054,6 +4351,8 @@ impl support::IntoDart for NodeState {
             self.total_inbound_liquidity_msats
                 .into_into_dart()
                 .into_dart(),
+            self.pending_channel_opens_msat.into_into_dart().into_dart(),
+            self.pending_channel_opens.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2085,7 +4384,7 @@ impl rust2dart::IntoIntoDart<OnchainPaymentLimitsResponse> for OnchainPaymentLim
 impl support::IntoDart for OpenChannelFeeResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![
-            self.fee_msat.into_dart(),
+            self.fee_msat.map(|v| mirror_MilliSat(v)).into_dart(),
             self.fee_params.into_into_dart().into_dart(),
         ]
         .into_dart()
@@ -2098,6 +4397,62 @@ impl rust2dart::IntoIntoDart<OpenChannelFeeResponse> for OpenChannelFeeResponse
     }
 }
 
+impl support::IntoDart for OpenChannelReceiveDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.payment_hash.into_into_dart().into_dart(),
+            self.stage.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OpenChannelReceiveDetails {}
+impl rust2dart::IntoIntoDart<OpenChannelReceiveDetails> for OpenChannelReceiveDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OpenChannelReceiveStage {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Registered { opening_fee_msat } => {
+                vec![0.into_dart(), opening_fee_msat.into_into_dart().into_dart()]
+            }
+            Self::ChannelPending { funding_txid } => {
+                vec![1.into_dart(), funding_txid.into_into_dart().into_dart()]
+            }
+            Self::Settled { opening_fee_msat } => {
+                vec![2.into_dart(), opening_fee_msat.into_into_dart().into_dart()]
+            }
+            Self::Reregistered => vec![3.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OpenChannelReceiveStage {}
+impl rust2dart::IntoIntoDart<OpenChannelReceiveStage> for OpenChannelReceiveStage {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OpenChannelResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.channel_id.into_into_dart().into_dart(),
+            self.funding_txid.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OpenChannelResponse {}
+impl rust2dart::IntoIntoDart<OpenChannelResponse> for OpenChannelResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for OpeningFeeParams {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -2130,6 +4485,129 @@ impl rust2dart::IntoIntoDart<OpeningFeeParamsMenu> for OpeningFeeParamsMenu {
     }
 }
 
+impl support::IntoDart for OutboxPayment {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.req.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+            self.next_attempt_at.into_into_dart().into_dart(),
+            self.last_error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OutboxPayment {}
+impl rust2dart::IntoIntoDart<OutboxPayment> for OutboxPayment {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OutboxPaymentDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.outbox_payment.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OutboxPaymentDetails {}
+impl rust2dart::IntoIntoDart<OutboxPaymentDetails> for OutboxPaymentDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OutboxPaymentStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Queued => vec![0.into_dart()],
+            Self::Sent { payment } => vec![1.into_dart(), payment.into_into_dart().into_dart()],
+            Self::Abandoned => vec![2.into_dart()],
+            Self::Cancelled => vec![3.into_dart()],
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OutboxPaymentStatus {}
+impl rust2dart::IntoIntoDart<OutboxPaymentStatus> for OutboxPaymentStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PartnerFeeConfig {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.fee_bps.into_into_dart().into_dart(),
+            self.destination.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PartnerFeeConfig {}
+impl rust2dart::IntoIntoDart<PartnerFeeConfig> for PartnerFeeConfig {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PartnerFeeDestination {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::NodeId { node_id } => vec![0.into_dart(), node_id.into_into_dart().into_dart()],
+            Self::LightningAddress { address } => {
+                vec![1.into_dart(), address.into_into_dart().into_dart()]
+            }
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PartnerFeeDestination {}
+impl rust2dart::IntoIntoDart<PartnerFeeDestination> for PartnerFeeDestination {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PartnerFeeDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.amount_msat.into_into_dart().into_dart(),
+            self.payment_id.into_dart(),
+            self.error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PartnerFeeDetails {}
+impl rust2dart::IntoIntoDart<PartnerFeeDetails> for PartnerFeeDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PayOnchainAddressResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.prepare_res.into_into_dart().into_dart(),
+            self.claim_tx_feerate.into_into_dart().into_dart(),
+            self.reverse_swap_info.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PayOnchainAddressResponse {}
+impl rust2dart::IntoIntoDart<PayOnchainAddressResponse> for PayOnchainAddressResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for PayOnchainResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.reverse_swap_info.into_into_dart().into_dart()].into_dart()
@@ -2155,6 +4633,8 @@ impl support::IntoDart for Payment {
             self.description.into_dart(),
             self.details.into_into_dart().into_dart(),
             self.metadata.into_dart(),
+            self.fiat_rate.map(|v| mirror_Rate(v)).into_dart(),
+            self.imported.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2200,6 +4680,67 @@ impl rust2dart::IntoIntoDart<PaymentFailedData> for PaymentFailedData {
     }
 }
 
+impl support::IntoDart for PaymentGroup {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.key.into_into_dart().into_dart(),
+            self.payments_count.into_into_dart().into_dart(),
+            self.total_amount_msat.into_into_dart().into_dart(),
+            self.total_fee_msat.into_into_dart().into_dart(),
+            self.first_payment_time.into_into_dart().into_dart(),
+            self.last_payment_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentGroup {}
+impl rust2dart::IntoIntoDart<PaymentGroup> for PaymentGroup {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PaymentProbe {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.id.into_into_dart().into_dart(),
+            self.node_id.into_into_dart().into_dart(),
+            self.bolt11.into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.route_found.into_into_dart().into_dart(),
+            self.reachable.into_into_dart().into_dart(),
+            self.estimated_fee_msat.into_dart(),
+            self.hops.into_dart(),
+            self.error.into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentProbe {}
+impl rust2dart::IntoIntoDart<PaymentProbe> for PaymentProbe {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PaymentRequestBundle {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.invoice.into_into_dart().into_dart(),
+            self.fallback.into_dart(),
+            self.metadata.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PaymentRequestBundle {}
+impl rust2dart::IntoIntoDart<PaymentRequestBundle> for PaymentRequestBundle {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for PaymentStatus {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -2234,6 +4775,48 @@ impl rust2dart::IntoIntoDart<PaymentType> for PaymentType {
     }
 }
 
+impl support::IntoDart for PeerConnectivity {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.lsp_pubkey.into_dart(),
+            self.lsp_connected.into_into_dart().into_dart(),
+            self.ping_rtt_ms.into_dart(),
+            self.ping_error.into_dart(),
+            self.reconnection_attempts.into_into_dart().into_dart(),
+            self.failed_reconnection_attempts
+                .into_into_dart()
+                .into_dart(),
+            self.last_reconnected_at.into_dart(),
+            self.last_reconnection_error.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PeerConnectivity {}
+impl rust2dart::IntoIntoDart<PeerConnectivity> for PeerConnectivity {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PendingChannelOpen {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.funding_txid.into_into_dart().into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.seen_at.into_into_dart().into_dart(),
+            self.expected_confirmation_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PendingChannelOpen {}
+impl rust2dart::IntoIntoDart<PendingChannelOpen> for PendingChannelOpen {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for PrepareOnchainPaymentResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -2255,11 +4838,32 @@ impl rust2dart::IntoIntoDart<PrepareOnchainPaymentResponse> for PrepareOnchainPa
     }
 }
 
+impl support::IntoDart for PrepareReceivePaymentResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.amount_msat.into_into_dart().into_dart(),
+            self.open_channel_needed.into_into_dart().into_dart(),
+            self.opening_fee_msat.into_into_dart().into_dart(),
+            self.opening_fee_params.into_dart(),
+            self.received_amount_msat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PrepareReceivePaymentResponse {}
+impl rust2dart::IntoIntoDart<PrepareReceivePaymentResponse> for PrepareReceivePaymentResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for PrepareRedeemOnchainFundsResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![
             self.tx_weight.into_into_dart().into_dart(),
             self.tx_fee_sat.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.change_sat.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2273,19 +4877,68 @@ impl rust2dart::IntoIntoDart<PrepareRedeemOnchainFundsResponse>
     }
 }
 
-impl support::IntoDart for PrepareRefundResponse {
+impl support::IntoDart for PrepareRefundResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.refund_tx_weight.into_into_dart().into_dart(),
+            self.refund_tx_fee_sat.into_into_dart().into_dart(),
+            self.fee_previews.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PrepareRefundResponse {}
+impl rust2dart::IntoIntoDart<PrepareRefundResponse> for PrepareRefundResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for PrepareSendPaymentResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.bolt11.into_into_dart().into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.fee_msat.into_into_dart().into_dart(),
+            self.route_hops.into_into_dart().into_dart(),
+            self.parts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for PrepareSendPaymentResponse {}
+impl rust2dart::IntoIntoDart<PrepareSendPaymentResponse> for PrepareSendPaymentResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ProveAddressOwnershipResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.signature.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ProveAddressOwnershipResponse {}
+impl rust2dart::IntoIntoDart<ProveAddressOwnershipResponse> for ProveAddressOwnershipResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for mirror_ProxyConfig {
     fn into_dart(self) -> support::DartAbi {
         vec![
-            self.refund_tx_weight.into_into_dart().into_dart(),
-            self.refund_tx_fee_sat.into_into_dart().into_dart(),
+            self.0.address.into_into_dart().into_dart(),
+            self.0.username.into_dart(),
+            self.0.password.into_dart(),
         ]
         .into_dart()
     }
 }
-impl support::IntoDartExceptPrimitive for PrepareRefundResponse {}
-impl rust2dart::IntoIntoDart<PrepareRefundResponse> for PrepareRefundResponse {
-    fn into_into_dart(self) -> Self {
-        self
+impl support::IntoDartExceptPrimitive for mirror_ProxyConfig {}
+impl rust2dart::IntoIntoDart<mirror_ProxyConfig> for ProxyConfig {
+    fn into_into_dart(self) -> mirror_ProxyConfig {
+        mirror_ProxyConfig(self)
     }
 }
 
@@ -2310,7 +4963,12 @@ impl support::IntoDart for ReceivePaymentResponse {
         vec![
             self.ln_invoice.into_into_dart().into_dart(),
             self.opening_fee_params.into_dart(),
-            self.opening_fee_msat.into_dart(),
+            self.opening_fee_msat
+                .map(|v| mirror_MilliSat(v))
+                .into_dart(),
+            self.bolt11_length.into_into_dart().into_dart(),
+            self.fiat_amount.into_dart(),
+            self.fiat_rate.map(|v| mirror_Rate(v)).into_dart(),
         ]
         .into_dart()
     }
@@ -2322,6 +4980,27 @@ impl rust2dart::IntoIntoDart<ReceivePaymentResponse> for ReceivePaymentResponse
     }
 }
 
+impl support::IntoDart for ReceiveUnifiedResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.bip21.into_into_dart().into_dart(),
+            self.swap_info.into_into_dart().into_dart(),
+            self.ln_invoice.into_into_dart().into_dart(),
+            self.opening_fee_params.into_dart(),
+            self.opening_fee_msat
+                .map(|v| mirror_MilliSat(v))
+                .into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ReceiveUnifiedResponse {}
+impl rust2dart::IntoIntoDart<ReceiveUnifiedResponse> for ReceiveUnifiedResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for RecommendedFees {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -2341,6 +5020,28 @@ impl rust2dart::IntoIntoDart<RecommendedFees> for RecommendedFees {
     }
 }
 
+impl support::IntoDart for RecoveryBundle {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.node_id.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.instructions.into_into_dart().into_dart(),
+            self.static_backup.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.channels_balance_msat.into_into_dart().into_dart(),
+            self.onchain_balance_msat.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RecoveryBundle {}
+impl rust2dart::IntoIntoDart<RecoveryBundle> for RecoveryBundle {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for RedeemOnchainFundsResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.txid.into_into_dart().into_dart()].into_dart()
@@ -2353,6 +5054,23 @@ impl rust2dart::IntoIntoDart<RedeemOnchainFundsResponse> for RedeemOnchainFundsR
     }
 }
 
+impl support::IntoDart for RefundFeePreview {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.preset.into_into_dart().into_dart(),
+            self.sat_per_vbyte.into_into_dart().into_dart(),
+            self.refund_tx_fee_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for RefundFeePreview {}
+impl rust2dart::IntoIntoDart<RefundFeePreview> for RefundFeePreview {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for RefundResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.refund_tx_id.into_into_dart().into_dart()].into_dart()
@@ -2374,6 +5092,7 @@ impl support::IntoDart for ReverseSwapInfo {
             self.claim_txid.into_dart(),
             self.onchain_amount_sat.into_into_dart().into_dart(),
             self.status.into_into_dart().into_dart(),
+            self.protocol.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2406,6 +5125,22 @@ impl rust2dart::IntoIntoDart<ReverseSwapPairInfo> for ReverseSwapPairInfo {
     }
 }
 
+impl support::IntoDart for ReverseSwapProtocol {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Legacy => 0,
+            Self::Taproot => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ReverseSwapProtocol {}
+impl rust2dart::IntoIntoDart<ReverseSwapProtocol> for ReverseSwapProtocol {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for ReverseSwapStatus {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -2461,9 +5196,70 @@ impl rust2dart::IntoIntoDart<mirror_RouteHintHop> for RouteHintHop {
     }
 }
 
+impl support::IntoDart for mirror_Sat {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0 .0.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for mirror_Sat {}
+impl rust2dart::IntoIntoDart<mirror_Sat> for Sat {
+    fn into_into_dart(self) -> mirror_Sat {
+        mirror_Sat(self)
+    }
+}
+
+impl support::IntoDart for SdkInfo {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.version.into_into_dart().into_dart(),
+            self.supported_luds.into_into_dart().into_dart(),
+            self.swap_in_script_versions.into_into_dart().into_dart(),
+            self.reverse_swap_protocols.into_into_dart().into_dart(),
+            self.lsp_protocols.into_into_dart().into_dart(),
+            self.backup_schema_version.into_into_dart().into_dart(),
+            self.min_backup_schema_version.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SdkInfo {}
+impl rust2dart::IntoIntoDart<SdkInfo> for SdkInfo {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for SendPaymentRequest {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.bolt11.into_into_dart().into_dart(),
+            self.use_trampoline.into_dart(),
+            self.amount_msat.map(|v| mirror_MilliSat(v)).into_dart(),
+            self.label.into_dart(),
+            self.route_hint_index.into_dart(),
+            self.payment_timeout_sec.into_dart(),
+            self.maxfee_percent.into_dart(),
+            self.exemptfee_msat.map(|v| mirror_MilliSat(v)).into_dart(),
+            self.cancel_token.into_dart(),
+            self.skip_denylist.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SendPaymentRequest {}
+impl rust2dart::IntoIntoDart<SendPaymentRequest> for SendPaymentRequest {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for SendPaymentResponse {
     fn into_dart(self) -> support::DartAbi {
-        vec![self.payment.into_into_dart().into_dart()].into_dart()
+        vec![
+            self.payment.into_into_dart().into_dart(),
+            self.partner_fee.into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl support::IntoDartExceptPrimitive for SendPaymentResponse {}
@@ -2473,6 +5269,40 @@ impl rust2dart::IntoIntoDart<SendPaymentResponse> for SendPaymentResponse {
     }
 }
 
+impl support::IntoDart for SendPaymentsResponse {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.results.into_into_dart().into_dart(),
+            self.succeeded.into_into_dart().into_dart(),
+            self.failed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SendPaymentsResponse {}
+impl rust2dart::IntoIntoDart<SendPaymentsResponse> for SendPaymentsResponse {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for ServiceComponent {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Lsp => 0,
+            Self::Greenlight => 1,
+            Self::Swapper => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ServiceComponent {}
+impl rust2dart::IntoIntoDart<ServiceComponent> for ServiceComponent {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for ServiceHealthCheckResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.status.into_into_dart().into_dart()].into_dart()
@@ -2485,6 +5315,22 @@ impl rust2dart::IntoIntoDart<ServiceHealthCheckResponse> for ServiceHealthCheckR
     }
 }
 
+impl support::IntoDart for ServiceStatus {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Operational => 0,
+            Self::Degraded => 1,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for ServiceStatus {}
+impl rust2dart::IntoIntoDart<ServiceStatus> for ServiceStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for SignMessageResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.signature.into_into_dart().into_dart()].into_dart()
@@ -2497,6 +5343,58 @@ impl rust2dart::IntoIntoDart<SignMessageResponse> for SignMessageResponse {
     }
 }
 
+impl support::IntoDart for SnapshotChange {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.path.into_into_dart().into_dart(),
+            self.before.into_dart(),
+            self.after.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SnapshotChange {}
+impl rust2dart::IntoIntoDart<SnapshotChange> for SnapshotChange {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for SpendPolicy {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.daily_limit_msat.into_dart(),
+            self.per_payment_limit_msat.into_dart(),
+            self.allowed_destinations.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SpendPolicy {}
+impl rust2dart::IntoIntoDart<SpendPolicy> for SpendPolicy {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for SpontaneousPaymentMetadata {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.message.into_dart(),
+            self.sender_pubkey.into_dart(),
+            self.sender_name.into_dart(),
+            self.boostagram.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SpontaneousPaymentMetadata {}
+impl rust2dart::IntoIntoDart<SpontaneousPaymentMetadata> for SpontaneousPaymentMetadata {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for StaticBackupResponse {
     fn into_dart(self) -> support::DartAbi {
         vec![self.backup.into_dart()].into_dart()
@@ -2509,6 +5407,23 @@ impl rust2dart::IntoIntoDart<StaticBackupResponse> for StaticBackupResponse {
     }
 }
 
+impl support::IntoDart for StaticLnurlPay {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.lnurl.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.pay_request.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for StaticLnurlPay {}
+impl rust2dart::IntoIntoDart<StaticLnurlPay> for StaticLnurlPay {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for mirror_SuccessActionProcessed {
     fn into_dart(self) -> support::DartAbi {
         match self.0 {
@@ -2570,6 +5485,42 @@ impl rust2dart::IntoIntoDart<SwapInfo> for SwapInfo {
     }
 }
 
+impl support::IntoDart for SwapRefundKey {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.index.into_into_dart().into_dart(),
+            self.derivation_path.into_into_dart().into_dart(),
+            self.private_key.into_into_dart().into_dart(),
+            self.public_key.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SwapRefundKey {}
+impl rust2dart::IntoIntoDart<SwapRefundKey> for SwapRefundKey {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for SwapRefundedDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.swap_address.into_into_dart().into_dart(),
+            self.refund_tx_id.into_into_dart().into_dart(),
+            self.to_address.into_into_dart().into_dart(),
+            self.sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for SwapRefundedDetails {}
+impl rust2dart::IntoIntoDart<SwapRefundedDetails> for SwapRefundedDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for SwapStatus {
     fn into_dart(self) -> support::DartAbi {
         match self {
@@ -2608,6 +5559,78 @@ impl rust2dart::IntoIntoDart<mirror_Symbol> for Symbol {
     }
 }
 
+impl support::IntoDart for TaskState {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Running => 0,
+            Self::Restarting => 1,
+            Self::Stopped => 2,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TaskState {}
+impl rust2dart::IntoIntoDart<TaskState> for TaskState {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for TaskStatus {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.name.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+            self.restarts.into_into_dart().into_dart(),
+            self.last_error.into_dart(),
+            self.last_error_at.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TaskStatus {}
+impl rust2dart::IntoIntoDart<TaskStatus> for TaskStatus {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for TlvRecord {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.field_number.into_into_dart().into_dart(),
+            self.value_text.into_dart(),
+            self.value_hex.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for TlvRecord {}
+impl rust2dart::IntoIntoDart<TlvRecord> for TlvRecord {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for UnredeemedFundsDetails {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.refundable_swaps.into_into_dart().into_dart(),
+            self.redeemable_swaps.into_into_dart().into_dart(),
+            self.expired_invoice_swaps.into_into_dart().into_dart(),
+            self.unclaimed_reverse_swaps.into_into_dart().into_dart(),
+            self.total_amount_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UnredeemedFundsDetails {}
+impl rust2dart::IntoIntoDart<UnredeemedFundsDetails> for UnredeemedFundsDetails {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for UnspentTransactionOutput {
     fn into_dart(self) -> support::DartAbi {
         vec![
@@ -2643,6 +5666,24 @@ impl rust2dart::IntoIntoDart<mirror_UrlSuccessActionData> for UrlSuccessActionDa
     }
 }
 
+impl support::IntoDart for UserSettings {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.preferred_fiat_currency.into_dart(),
+            self.denomination.into_dart(),
+            self.default_feerate_preset.into_dart(),
+            self.preferred_lsp_id.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for UserSettings {}
+impl rust2dart::IntoIntoDart<UserSettings> for UserSettings {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 // Section: executor
 
 support::lazy_static! {
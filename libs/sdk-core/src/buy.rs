@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
-use anyhow::Result;
-use sdk_common::prelude::{BreezServer, BuyBitcoinProviderApi, MoonpayProvider};
+use anyhow::{anyhow, Result};
+use sdk_common::prelude::{
+    BreezServer, BuyBitcoinProviderApi, BuyBitcoinQuote, MoonpayProvider, RestClient,
+    TransakProvider,
+};
 
-use crate::{BuyBitcoinProvider, SwapInfo};
+use crate::{BuyBitcoinProvider, FiatAmount, SwapInfo};
 
 #[tonic::async_trait]
 pub(crate) trait BuyBitcoinApi: Send + Sync {
@@ -14,16 +17,47 @@ pub(crate) trait BuyBitcoinApi: Send + Sync {
         swap_info: &SwapInfo,
         redirect_url: Option<String>,
     ) -> Result<String>;
+
+    /// Fetch what buying Bitcoin for the fiat amount costs at the provider
+    async fn fetch_quote(
+        &self,
+        provider: BuyBitcoinProvider,
+        fiat_amount: &FiatAmount,
+    ) -> Result<BuyBitcoinQuote>;
 }
 
 pub(crate) struct BuyBitcoinService {
     moonpay_provider: Arc<dyn BuyBitcoinProviderApi>,
+    /// Only set if [crate::Config::transak_api_key] is set
+    transak_provider: Option<Arc<dyn BuyBitcoinProviderApi>>,
+    rest_client: Arc<dyn RestClient>,
 }
 
 impl BuyBitcoinService {
-    pub fn new(breez_server: Arc<BreezServer>) -> Self {
+    pub fn new(
+        breez_server: Arc<BreezServer>,
+        transak_api_key: Option<String>,
+        rest_client: Arc<dyn RestClient>,
+    ) -> Self {
         let moonpay_provider = Arc::new(MoonpayProvider::new(breez_server));
-        Self { moonpay_provider }
+        let transak_provider = transak_api_key.map(|api_key| {
+            Arc::new(TransakProvider::new(api_key)) as Arc<dyn BuyBitcoinProviderApi>
+        });
+        Self {
+            moonpay_provider,
+            transak_provider,
+            rest_client,
+        }
+    }
+
+    fn provider(&self, provider: BuyBitcoinProvider) -> Result<Arc<dyn BuyBitcoinProviderApi>> {
+        match provider {
+            BuyBitcoinProvider::Moonpay => Ok(self.moonpay_provider.clone()),
+            BuyBitcoinProvider::Transak => self
+                .transak_provider
+                .clone()
+                .ok_or_else(|| anyhow!("Transak requires the Transak API key to be configured")),
+        }
     }
 }
 
@@ -35,17 +69,27 @@ impl BuyBitcoinApi for BuyBitcoinService {
         swap_info: &SwapInfo,
         redirect_url: Option<String>,
     ) -> Result<String> {
-        match provider {
-            BuyBitcoinProvider::Moonpay => {
-                self.moonpay_provider
-                    .buy_bitcoin(
-                        swap_info.bitcoin_address.clone(),
-                        None,
-                        Some(swap_info.max_allowed_deposit as u64),
-                        redirect_url,
-                    )
-                    .await
-            }
-        }
+        self.provider(provider)?
+            .buy_bitcoin(
+                swap_info.bitcoin_address.clone(),
+                None,
+                Some(swap_info.max_allowed_deposit as u64),
+                redirect_url,
+            )
+            .await
+    }
+
+    async fn fetch_quote(
+        &self,
+        provider: BuyBitcoinProvider,
+        fiat_amount: &FiatAmount,
+    ) -> Result<BuyBitcoinQuote> {
+        self.provider(provider)?
+            .fetch_quote(
+                self.rest_client.as_ref(),
+                &fiat_amount.currency,
+                fiat_amount.amount,
+            )
+            .await
     }
 }
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::sync::watch;
+
+use crate::error::{SdkError, SdkResult};
+
+/// The calls in progress that can be cancelled, by the token their caller chose.
+#[derive(Default)]
+pub(crate) struct Cancellations {
+    tokens: Arc<StdMutex<HashMap<String, watch::Sender<bool>>>>,
+}
+
+impl Cancellations {
+    /// Registers the token of a call and returns the [Cancellation] it checks, which keeps the
+    /// token registered until dropped. Without a token, the call can't be cancelled.
+    ///
+    /// Fails if the token is already used by another call in progress.
+    pub(crate) fn register(&self, token: Option<&str>) -> SdkResult<Cancellation> {
+        let Some(token) = token else {
+            return Ok(Cancellation::none());
+        };
+        let mut tokens = self.tokens.lock().unwrap();
+        if tokens.contains_key(token) {
+            return Err(SdkError::generic(&format!(
+                "Cancel token {token} is already used by another call"
+            )));
+        }
+        let (sender, receiver) = watch::channel(false);
+        tokens.insert(token.to_string(), sender);
+        Ok(Cancellation {
+            registration: Some((self.tokens.clone(), token.to_string())),
+            receiver: Some(receiver),
+        })
+    }
+
+    /// Cancels the call registered with the token, returning whether there was one
+    pub(crate) fn cancel(&self, token: &str) -> bool {
+        match self.tokens.lock().unwrap().get(token) {
+            Some(sender) => {
+                sender.send_replace(true);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The cancellation state of a call, see [Cancellations::register]
+pub(crate) struct Cancellation {
+    registration: Option<(Arc<StdMutex<HashMap<String, watch::Sender<bool>>>>, String)>,
+    receiver: Option<watch::Receiver<bool>>,
+}
+
+impl Cancellation {
+    /// A call that can't be cancelled
+    pub(crate) fn none() -> Self {
+        Self {
+            registration: None,
+            receiver: None,
+        }
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.receiver.as_ref().is_some_and(|r| *r.borrow())
+    }
+
+    /// Resolves once the call is cancelled, never if it can't be
+    pub(crate) async fn cancelled(&self) {
+        if let Some(receiver) = &self.receiver {
+            let mut receiver = receiver.clone();
+            if receiver.wait_for(|cancelled| *cancelled).await.is_ok() {
+                return;
+            }
+        }
+        std::future::pending().await
+    }
+}
+
+impl Drop for Cancellation {
+    fn drop(&mut self) {
+        if let Some((tokens, token)) = &self.registration {
+            tokens.lock().unwrap().remove(token);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use super::{Cancellation, Cancellations};
+
+    #[tokio::test]
+    async fn test_cancel() -> Result<()> {
+        let cancellations = Cancellations::default();
+        assert!(!cancellations.cancel("token1"));
+
+        let cancellation = cancellations.register(Some("token1"))?;
+        // A token can only be used by one call at a time
+        assert!(cancellations.register(Some("token1")).is_err());
+        assert!(!cancellation.is_cancelled());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), cancellation.cancelled())
+                .await
+                .is_err()
+        );
+
+        assert!(cancellations.cancel("token1"));
+        assert!(cancellation.is_cancelled());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), cancellation.cancelled())
+                .await
+                .is_ok()
+        );
+
+        drop(cancellation);
+        assert!(!cancellations.cancel("token1"));
+        assert!(cancellations.register(Some("token1")).is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_without_token() -> Result<()> {
+        let cancellation = Cancellations::default().register(None)?;
+        assert!(!cancellation.is_cancelled());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), Cancellation::none().cancelled())
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -7,9 +8,29 @@ use serde::{Deserialize, Serialize};
 use crate::bitcoin::hashes::hex::FromHex;
 use crate::bitcoin::{OutPoint, Txid};
 use crate::error::{SdkError, SdkResult};
+use crate::models::FeeratePreset;
+
+mod electrum;
+
+pub(crate) use electrum::ElectrumChainService;
 
 pub const DEFAULT_MEMPOOL_SPACE_URL: &str = "https://mempool.space/api";
+pub const SIGNET_MEMPOOL_SPACE_URL: &str = "https://mempool.space/signet/api";
+pub const TESTNET_MEMPOOL_SPACE_URL: &str = "https://mempool.space/testnet/api";
+
+/// The public mempool.space API of the test networks, for which the Breez server provides no URLs
+pub(crate) fn test_network_mempool_space_url(network: Network) -> Option<&'static str> {
+    match network {
+        Network::Signet => Some(SIGNET_MEMPOOL_SPACE_URL),
+        Network::Testnet => Some(TESTNET_MEMPOOL_SPACE_URL),
+        Network::Bitcoin | Network::Regtest => None,
+    }
+}
 
+/// Source of onchain data, used for swap monitoring, fee estimation, sweeps and refunds.
+///
+/// The SDK uses the implementation matching [crate::Config::chain_service], or mempool.space
+/// if none is set.
 #[tonic::async_trait]
 pub trait ChainService: Send + Sync {
     async fn recommended_fees(&self) -> SdkResult<RecommendedFees>;
@@ -119,6 +140,7 @@ impl ChainService for RedundantChainService {
     }
 }
 
+#[cfg_attr(not(feature = "reverse-swaps"), allow(dead_code))]
 #[derive(Clone)]
 pub struct Utxo {
     pub out: OutPoint,
@@ -126,6 +148,7 @@ pub struct Utxo {
     pub block_height: Option<u32>,
 }
 
+#[cfg_attr(not(feature = "reverse-swaps"), allow(dead_code))]
 #[derive(Clone)]
 pub struct AddressUtxos {
     pub confirmed: Vec<Utxo>,
@@ -148,6 +171,7 @@ impl AddressUtxos {
 /// Gets unspent tx outputs. Specifically filters out inbound utxos that have been spent.
 /// If include_unconfirmed_spends is true, then the result won't include utxos that were spent
 /// in unconfirmed transactions.
+#[cfg_attr(not(feature = "reverse-swaps"), allow(dead_code))]
 pub(crate) fn get_utxos(
     address: String,
     transactions: Vec<OnchainTx>,
@@ -220,6 +244,17 @@ pub struct RecommendedFees {
     pub minimum_fee: u64,
 }
 
+impl RecommendedFees {
+    /// The feerate (sat/vByte) matching the `preset`
+    pub(crate) fn feerate(&self, preset: FeeratePreset) -> u64 {
+        match preset {
+            FeeratePreset::Priority => self.fastest_fee,
+            FeeratePreset::Regular => self.half_hour_fee,
+            FeeratePreset::Economy => self.economy_fee,
+        }
+    }
+}
+
 #[derive(Default, Deserialize, Serialize, Clone, Debug)]
 pub struct OnchainTx {
     pub txid: String,
@@ -291,6 +326,63 @@ impl MempoolSpace {
     }
 }
 
+/// [ChainService] backed by an Esplora server.
+///
+/// Esplora exposes the same API as mempool.space, except for the fee estimates.
+pub(crate) struct Esplora {
+    inner: MempoolSpace,
+}
+
+impl Esplora {
+    pub(crate) fn from_base_url(rest_client: Arc<dyn RestClient>, base_url: &str) -> Self {
+        Esplora {
+            inner: MempoolSpace::from_base_url(rest_client, base_url.trim_end_matches('/')),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ChainService for Esplora {
+    async fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
+        let (response, _) = get_and_check_success(
+            self.inner.rest_client.as_ref(),
+            &format!("{}/fee-estimates", self.inner.base_url),
+        )
+        .await?;
+        // Maps the confirmation target, in blocks, to the fee rate in sat/vB
+        let estimates: HashMap<String, f64> = parse_json(&response)?;
+        let fee_for_target = |target: &str| {
+            estimates
+                .get(target)
+                .map(|fee| (fee.ceil() as u64).max(1))
+                .unwrap_or(1)
+        };
+        Ok(RecommendedFees {
+            fastest_fee: fee_for_target("1"),
+            half_hour_fee: fee_for_target("3"),
+            hour_fee: fee_for_target("6"),
+            economy_fee: fee_for_target("144"),
+            minimum_fee: fee_for_target("1008"),
+        })
+    }
+
+    async fn address_transactions(&self, address: String) -> SdkResult<Vec<OnchainTx>> {
+        self.inner.address_transactions(address).await
+    }
+
+    async fn current_tip(&self) -> SdkResult<u32> {
+        self.inner.current_tip().await
+    }
+
+    async fn transaction_outspends(&self, txid: String) -> SdkResult<Vec<Outspend>> {
+        self.inner.transaction_outspends(txid).await
+    }
+
+    async fn broadcast_transaction(&self, tx: Vec<u8>) -> SdkResult<String> {
+        self.inner.broadcast_transaction(tx).await
+    }
+}
+
 #[tonic::async_trait]
 impl ChainService for MempoolSpace {
     async fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
@@ -351,7 +443,9 @@ mod tests {
     use std::sync::Arc;
 
     use crate::{
-        chain::{MempoolSpace, OnchainTx, RedundantChainService, RedundantChainServiceTrait},
+        chain::{
+            Esplora, MempoolSpace, OnchainTx, RedundantChainService, RedundantChainServiceTrait,
+        },
         error::SdkError,
     };
     use anyhow::Result;
@@ -467,6 +561,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn test_esplora_recommended_fees() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+
+        let response_body = json!({
+            "1": 20.5,
+            "2": 15.0,
+            "3": 12.1,
+            "6": 8.0,
+            "144": 2.0,
+            "1008": 0.5,
+        })
+        .to_string();
+
+        mock_rest_client.add_response(MockResponse::new(200, response_body));
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+
+        let esplora = Esplora::from_base_url(rest_client, "https://blockstream.info/api/");
+        let fees = esplora.recommended_fees().await?;
+        assert_eq!(fees.fastest_fee, 21);
+        assert_eq!(fees.half_hour_fee, 13);
+        assert_eq!(fees.hour_fee, 8);
+        assert_eq!(fees.economy_fee, 2);
+        assert_eq!(fees.minimum_fee, 1);
+
+        Ok(())
+    }
+
     #[test]
     async fn test_address_transactions() -> Result<()> {
         let mock_rest_client = MockRestClient::new();
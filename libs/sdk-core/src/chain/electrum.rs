@@ -0,0 +1,1082 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use sdk_common::prelude::ProxyConfig;
+use serde_json::{json, Value};
+use tokio::io::{
+    AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, ReadHalf, WriteHalf,
+};
+use tokio::net::TcpStream;
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
+use tokio::time::{timeout_at, Instant};
+use tokio_native_tls::{native_tls, TlsConnector};
+
+use super::{ChainService, OnchainTx, Outspend, RecommendedFees, TxStatus, Vin, Vout};
+use crate::bitcoin::consensus::deserialize;
+use crate::bitcoin::hashes::hex::ToHex;
+use crate::bitcoin::hashes::{sha256, Hash};
+use crate::bitcoin::{Address, BlockHeader, Network, Script, Transaction, Txid};
+use crate::error::{SdkError, SdkResult};
+
+const ELECTRUM_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fee targets, in blocks, used to fill in the [RecommendedFees]
+const FASTEST_FEE_TARGET: u32 = 1;
+const HALF_HOUR_FEE_TARGET: u32 = 3;
+const HOUR_FEE_TARGET: u32 = 6;
+const ECONOMY_FEE_TARGET: u32 = 144;
+
+/// [ChainService] backed by an Electrum server.
+///
+/// The server URL has the format `ssl://host:port` or `tcp://host:port`. If a proxy is given, the
+/// connection to the server is opened through it.
+///
+/// The requests needed to answer a call are sent in JSON-RPC batches, one per step: for example
+/// the transactions of an address take a request for the history, one for the transactions and
+/// one for their previous transactions and block headers, however many transactions there are.
+pub(crate) struct ElectrumChainService {
+    client: Arc<ElectrumClient>,
+    network: Network,
+}
+
+impl ElectrumChainService {
+    pub(crate) fn new(url: &str, network: Network, proxy: Option<ProxyConfig>) -> SdkResult<Self> {
+        Ok(Self {
+            client: Arc::new(ElectrumClient::new(url, proxy)?),
+            network,
+        })
+    }
+
+    /// Fetches the transactions missing from the cache, in a single batch
+    async fn fetch_transactions(
+        &self,
+        txids: impl IntoIterator<Item = String>,
+        cache: &mut HashMap<String, Transaction>,
+    ) -> SdkResult<()> {
+        let mut missing: Vec<String> = txids
+            .into_iter()
+            .filter(|txid| !cache.contains_key(txid))
+            .collect();
+        missing.sort();
+        missing.dedup();
+        let calls = missing
+            .iter()
+            .map(|txid| ("blockchain.transaction.get", vec![json!(txid)]))
+            .collect();
+        for (txid, raw_tx) in missing
+            .into_iter()
+            .zip(self.client.batch_call(calls).await?)
+        {
+            let tx: Transaction = deserialize(&decode_hex(&raw_tx)?)
+                .map_err(|e| SdkError::generic(&format!("Invalid transaction {txid}: {e}")))?;
+            cache.insert(txid, tx);
+        }
+        Ok(())
+    }
+
+    /// Fetches the block headers missing from the cache, in a single batch. Mempool heights are
+    /// skipped.
+    async fn fetch_headers(
+        &self,
+        heights: impl IntoIterator<Item = i32>,
+        headers: &mut HashMap<u32, BlockHeader>,
+    ) -> SdkResult<()> {
+        // Mempool transactions have a height of 0 or -1
+        let mut missing: Vec<u32> = heights
+            .into_iter()
+            .filter(|height| *height > 0)
+            .map(|height| height as u32)
+            .filter(|height| !headers.contains_key(height))
+            .collect();
+        missing.sort();
+        missing.dedup();
+        let calls = missing
+            .iter()
+            .map(|height| ("blockchain.block.header", vec![json!(height)]))
+            .collect();
+        for (height, raw_header) in missing
+            .into_iter()
+            .zip(self.client.batch_call(calls).await?)
+        {
+            let header: BlockHeader = deserialize(&decode_hex(&raw_header)?).map_err(|e| {
+                SdkError::generic(&format!("Invalid block header at {height}: {e}"))
+            })?;
+            headers.insert(height, header);
+        }
+        Ok(())
+    }
+
+    /// Fetches the histories of the scripts, in a single batch
+    async fn get_histories(&self, scripts: &[&Script]) -> SdkResult<Vec<Vec<HistoryItem>>> {
+        let calls = scripts
+            .iter()
+            .map(|script| {
+                (
+                    "blockchain.scripthash.get_history",
+                    vec![json!(script_hash(script))],
+                )
+            })
+            .collect();
+        self.client
+            .batch_call(calls)
+            .await?
+            .into_iter()
+            .map(|history| Ok(serde_json::from_value(history)?))
+            .collect()
+    }
+
+    fn to_vout(&self, script: &Script, value: u64) -> Vout {
+        Vout {
+            scriptpubkey: script.to_hex(),
+            scriptpubkey_asm: script.asm(),
+            scriptpubkey_type: script_type(script).to_string(),
+            scriptpubkey_address: Address::from_script(script, self.network)
+                .map(|a| a.to_string())
+                .unwrap_or_default(),
+            value,
+        }
+    }
+
+    /// Converts the transaction, whose previous transactions must be in the cache
+    fn to_onchain_tx(
+        &self,
+        tx: &Transaction,
+        status: TxStatus,
+        cache: &HashMap<String, Transaction>,
+    ) -> SdkResult<OnchainTx> {
+        let is_coinbase = tx.is_coin_base();
+        let mut vin = Vec::new();
+        let mut input_value = 0;
+        for input in &tx.input {
+            let prevout = match is_coinbase {
+                true => Vout::default(),
+                false => {
+                    let prev_out = cache
+                        .get(&input.previous_output.txid.to_hex())
+                        .and_then(|prev_tx| prev_tx.output.get(input.previous_output.vout as usize))
+                        .ok_or_else(|| {
+                            SdkError::generic(&format!(
+                                "Missing output {} of {}",
+                                input.previous_output.vout, input.previous_output.txid
+                            ))
+                        })?;
+                    input_value += prev_out.value;
+                    self.to_vout(&prev_out.script_pubkey, prev_out.value)
+                }
+            };
+            vin.push(Vin {
+                txid: input.previous_output.txid.to_hex(),
+                vout: input.previous_output.vout,
+                prevout,
+                scriptsig: input.script_sig.to_hex(),
+                scriptsig_asm: input.script_sig.asm(),
+                witness: match input.witness.is_empty() {
+                    true => None,
+                    false => Some(input.witness.to_vec().iter().map(hex::encode).collect()),
+                },
+                is_coinbase,
+                sequence: input.sequence.0,
+            });
+        }
+        let vout: Vec<Vout> = tx
+            .output
+            .iter()
+            .map(|o| self.to_vout(&o.script_pubkey, o.value))
+            .collect();
+        let output_value: u64 = tx.output.iter().map(|o| o.value).sum();
+
+        Ok(OnchainTx {
+            txid: tx.txid().to_hex(),
+            version: tx.version as u32,
+            locktime: tx.lock_time.0,
+            vin,
+            vout,
+            size: tx.size() as u32,
+            weight: tx.weight() as u32,
+            fee: input_value.saturating_sub(output_value) as u32,
+            status,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl ChainService for ElectrumChainService {
+    async fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
+        let targets = [
+            ECONOMY_FEE_TARGET,
+            HOUR_FEE_TARGET,
+            HALF_HOUR_FEE_TARGET,
+            FASTEST_FEE_TARGET,
+        ];
+        let mut calls = vec![("blockchain.relayfee", vec![])];
+        calls.extend(
+            targets
+                .iter()
+                .map(|target| ("blockchain.estimatefee", vec![json!(target)])),
+        );
+        let fees: Vec<Option<u64>> = self
+            .client
+            .batch_call(calls)
+            .await?
+            .iter()
+            .map(btc_per_kvb_to_sat_per_vbyte)
+            .collect();
+        let minimum_fee = fees[0].unwrap_or(1);
+
+        // The server returns no estimate if it doesn't have enough data, in which case we fall
+        // back to the estimate for the next longer target
+        let economy_fee = fees[1].unwrap_or(minimum_fee);
+        let hour_fee = fees[2].unwrap_or(economy_fee);
+        let half_hour_fee = fees[3].unwrap_or(hour_fee);
+        let fastest_fee = fees[4].unwrap_or(half_hour_fee);
+
+        Ok(RecommendedFees {
+            fastest_fee,
+            half_hour_fee,
+            hour_fee,
+            economy_fee,
+            minimum_fee,
+        })
+    }
+
+    async fn address_transactions(&self, address: String) -> SdkResult<Vec<OnchainTx>> {
+        let script = Address::from_str(&address)
+            .map_err(|e| SdkError::generic(&format!("Invalid address {address}: {e}")))?
+            .script_pubkey();
+        let history = self.get_histories(&[&script]).await?.remove(0);
+        let mut cache = HashMap::new();
+        self.fetch_transactions(history.iter().map(|item| item.tx_hash.clone()), &mut cache)
+            .await?;
+
+        // The previous transactions and the block headers don't depend on each other, so both
+        // batches are sent at once on the connection
+        let prev_txids: Vec<String> = history
+            .iter()
+            .filter_map(|item| cache.get(&item.tx_hash))
+            .filter(|tx| !tx.is_coin_base())
+            .flat_map(|tx| tx.input.iter())
+            .map(|input| input.previous_output.txid.to_hex())
+            .collect();
+        let mut headers = HashMap::new();
+        futures::try_join!(
+            self.fetch_transactions(prev_txids, &mut cache),
+            self.fetch_headers(history.iter().map(|item| item.height), &mut headers),
+        )?;
+
+        // Electrum returns the history oldest first, while the other chain services return
+        // the newest transactions first
+        history
+            .iter()
+            .rev()
+            .map(|item| {
+                let status = tx_status(item.height, &headers)?;
+                self.to_onchain_tx(&cache[&item.tx_hash], status, &cache)
+            })
+            .collect()
+    }
+
+    async fn current_tip(&self) -> SdkResult<u32> {
+        let tip: HeaderNotification = serde_json::from_value(
+            self.client
+                .call("blockchain.headers.subscribe", vec![])
+                .await?,
+        )?;
+        Ok(tip.height)
+    }
+
+    async fn transaction_outspends(&self, txid: String) -> SdkResult<Vec<Outspend>> {
+        let txid = Txid::from_str(&txid)
+            .map_err(|e| SdkError::generic(&format!("Invalid txid {txid}: {e}")))?;
+        let mut cache = HashMap::new();
+        self.fetch_transactions([txid.to_hex()], &mut cache).await?;
+        let tx = cache[&txid.to_hex()].clone();
+
+        // Electrum has no index of spent outputs, so look for the spending txs in the histories
+        // of the output scripts
+        let scripts: Vec<&Script> = tx.output.iter().map(|o| &o.script_pubkey).collect();
+        let histories = self.get_histories(&scripts).await?;
+        let candidates: Vec<String> = histories
+            .iter()
+            .flatten()
+            .filter(|item| item.tx_hash != txid.to_hex())
+            .map(|item| item.tx_hash.clone())
+            .collect();
+        self.fetch_transactions(candidates, &mut cache).await?;
+
+        let spends: Vec<Option<(&HistoryItem, usize)>> = histories
+            .iter()
+            .enumerate()
+            .map(|(index, history)| {
+                history.iter().find_map(|item| {
+                    let vin = cache.get(&item.tx_hash)?.input.iter().position(|input| {
+                        input.previous_output.txid == txid
+                            && input.previous_output.vout == index as u32
+                    })?;
+                    Some((item, vin))
+                })
+            })
+            .collect();
+        let mut headers = HashMap::new();
+        self.fetch_headers(
+            spends.iter().flatten().map(|(item, _)| item.height),
+            &mut headers,
+        )
+        .await?;
+
+        spends
+            .into_iter()
+            .map(|spend| {
+                Ok(match spend {
+                    Some((item, vin)) => Outspend {
+                        spent: true,
+                        txid: Some(item.tx_hash.clone()),
+                        vin: Some(vin as u32),
+                        status: Some(tx_status(item.height, &headers)?),
+                    },
+                    None => Outspend {
+                        spent: false,
+                        txid: None,
+                        vin: None,
+                        status: None,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    async fn broadcast_transaction(&self, tx: Vec<u8>) -> SdkResult<String> {
+        let txid = self
+            .client
+            .call(
+                "blockchain.transaction.broadcast",
+                vec![json!(hex::encode(tx))],
+            )
+            .await?;
+        txid.as_str()
+            .map(|txid| txid.to_string())
+            .ok_or_else(|| SdkError::generic(&format!("Unexpected broadcast response: {txid}")))
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct HistoryItem {
+    tx_hash: String,
+    height: i32,
+}
+
+#[derive(serde::Deserialize)]
+struct HeaderNotification {
+    height: u32,
+}
+
+/// The status of a transaction at the given height, whose block header must be in `headers`
+/// unless it's in the mempool
+fn tx_status(height: i32, headers: &HashMap<u32, BlockHeader>) -> SdkResult<TxStatus> {
+    // Mempool transactions have a height of 0 or -1
+    if height <= 0 {
+        return Ok(TxStatus::default());
+    }
+    let height = height as u32;
+    let header = headers
+        .get(&height)
+        .ok_or_else(|| SdkError::generic(&format!("Missing block header at {height}")))?;
+    Ok(TxStatus {
+        confirmed: true,
+        block_height: Some(height),
+        block_hash: Some(header.block_hash().to_hex()),
+        block_time: Some(header.time as u64),
+    })
+}
+
+/// Electrum JSON-RPC client, keeping a single connection open to the server.
+///
+/// The requests are pipelined: callers write their requests on the shared connection without
+/// waiting for the previous responses, and a reader task routes each response to its caller by id.
+struct ElectrumClient {
+    host: String,
+    port: u16,
+    use_ssl: bool,
+    proxy: Option<ProxyConfig>,
+    next_id: AtomicU64,
+    connection: Mutex<Option<Connection>>,
+}
+
+impl ElectrumClient {
+    fn new(url: &str, proxy: Option<ProxyConfig>) -> SdkResult<Self> {
+        let invalid_url = || SdkError::generic(&format!("Invalid Electrum URL: {url}"));
+        let (use_ssl, host_port) = match url.split_once("://") {
+            Some(("ssl", host_port)) => (true, host_port),
+            Some(("tcp", host_port)) => (false, host_port),
+            _ => return Err(invalid_url()),
+        };
+        let (host, port) = host_port.rsplit_once(':').ok_or_else(invalid_url)?;
+        Ok(Self {
+            host: host.to_string(),
+            port: port
+                .trim_end_matches('/')
+                .parse()
+                .map_err(|_| invalid_url())?,
+            use_ssl,
+            proxy,
+            next_id: AtomicU64::new(0),
+            connection: Mutex::new(None),
+        })
+    }
+
+    async fn connect(&self) -> std::io::Result<Box<dyn ElectrumStream>> {
+        let stream = match &self.proxy {
+            Some(proxy) => proxy.connect(&self.host, self.port).await?,
+            None => TcpStream::connect((self.host.as_str(), self.port)).await?,
+        };
+        match self.use_ssl {
+            true => {
+                let connector = native_tls::TlsConnector::new()
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                let tls_stream = TlsConnector::from(connector)
+                    .connect(&self.host, stream)
+                    .await
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+                Ok(Box::new(tls_stream))
+            }
+            false => Ok(Box::new(stream)),
+        }
+    }
+
+    async fn call(&self, method: &'static str, params: Vec<Value>) -> SdkResult<Value> {
+        Ok(self.batch_call(vec![(method, params)]).await?.remove(0))
+    }
+
+    /// Sends the calls in a single JSON-RPC batch and returns their results, in the same order.
+    /// Fails if any of the calls fails.
+    async fn batch_call(&self, calls: Vec<(&'static str, Vec<Value>)>) -> SdkResult<Vec<Value>> {
+        if calls.is_empty() {
+            return Ok(vec![]);
+        }
+        let requests: Vec<Value> = calls
+            .iter()
+            .map(|(method, params)| {
+                let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+                json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params})
+            })
+            .collect();
+        let method = calls[0].0;
+        let responses = match self.send(&requests).await {
+            Ok(responses) => responses,
+            Err(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                return Err(SdkError::service_connectivity(&format!(
+                    "Electrum call {method} failed: {e}"
+                )))
+            }
+            Err(e) => {
+                // The server may have closed the connection, retry once on a fresh one
+                debug!("Electrum connection failed, reconnecting: {e}");
+                self.send(&requests).await.map_err(|e| {
+                    SdkError::service_connectivity(&format!("Electrum call {method} failed: {e}"))
+                })?
+            }
+        };
+
+        calls
+            .iter()
+            .zip(responses)
+            .map(|((method, _), response)| match response.get("error") {
+                Some(error) if !error.is_null() => Err(SdkError::generic(&format!(
+                    "Electrum call {method} failed: {error}"
+                ))),
+                _ => Ok(response.get("result").cloned().unwrap_or(Value::Null)),
+            })
+            .collect()
+    }
+
+    /// Writes the requests on the connection, opening it if needed, and waits for their responses
+    async fn send(&self, requests: &[Value]) -> std::io::Result<Vec<Value>> {
+        let deadline = Instant::now() + ELECTRUM_TIMEOUT;
+        let timed_out = |_| std::io::Error::from(std::io::ErrorKind::TimedOut);
+        let mut line = match requests {
+            [request] => request.to_string(),
+            requests => Value::from(requests.to_vec()).to_string(),
+        };
+        line.push('\n');
+        let ids: Vec<u64> = requests
+            .iter()
+            .filter_map(|request| request["id"].as_u64())
+            .collect();
+
+        // The connection is only locked while writing, so the next requests can be written
+        // before the responses to these ones arrive
+        let (pending, receivers) = {
+            let mut connection = self.connection.lock().await;
+            let is_open = connection.as_ref().is_some_and(|c| !c.is_closed());
+            if !is_open {
+                let stream = timeout_at(deadline, self.connect())
+                    .await
+                    .map_err(timed_out)??;
+                *connection = Some(Connection::new(stream));
+            }
+            let open_connection = connection.as_mut().unwrap();
+            let receivers = open_connection.register(&ids)?;
+            let written = timeout_at(deadline, async {
+                open_connection.writer.write_all(line.as_bytes()).await?;
+                open_connection.writer.flush().await
+            })
+            .await
+            .map_err(timed_out)
+            .and_then(|res| res);
+            if let Err(e) = written {
+                *connection = None;
+                return Err(e);
+            }
+            (open_connection.pending.clone(), receivers)
+        };
+
+        let mut responses = Vec::new();
+        for receiver in receivers {
+            match timeout_at(deadline, receiver).await {
+                Ok(Ok(response)) => responses.push(response),
+                Ok(Err(_)) => return Err(std::io::ErrorKind::ConnectionAborted.into()),
+                Err(e) => {
+                    if let Some(pending) = pending.lock().unwrap().as_mut() {
+                        for id in &ids {
+                            pending.remove(id);
+                        }
+                    }
+                    return Err(timed_out(e));
+                }
+            }
+        }
+        Ok(responses)
+    }
+}
+
+trait ElectrumStream: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> ElectrumStream for T {}
+
+/// The requests waiting for a response, by id. `None` once the connection is closed.
+type PendingRequests = Arc<StdMutex<Option<HashMap<u64, oneshot::Sender<Value>>>>>;
+
+/// An open connection to the server, whose reader task routes the responses to the pending
+/// requests until the connection is closed
+struct Connection {
+    writer: WriteHalf<Box<dyn ElectrumStream>>,
+    pending: PendingRequests,
+    reader: JoinHandle<()>,
+}
+
+impl Connection {
+    fn new(stream: Box<dyn ElectrumStream>) -> Self {
+        let (reader, writer) = tokio::io::split(stream);
+        let pending: PendingRequests = Arc::new(StdMutex::new(Some(HashMap::new())));
+        let reader = tokio::spawn(Self::read_responses(
+            BufReader::new(reader),
+            pending.clone(),
+        ));
+        Self {
+            writer,
+            pending,
+            reader,
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.pending.lock().unwrap().is_none()
+    }
+
+    /// Registers the requests, returning the receivers of their responses
+    fn register(&self, ids: &[u64]) -> std::io::Result<Vec<oneshot::Receiver<Value>>> {
+        let mut pending = self.pending.lock().unwrap();
+        let pending = pending
+            .as_mut()
+            .ok_or(std::io::ErrorKind::ConnectionAborted)?;
+        Ok(ids
+            .iter()
+            .map(|id| {
+                let (sender, receiver) = oneshot::channel();
+                pending.insert(*id, sender);
+                receiver
+            })
+            .collect())
+    }
+
+    async fn read_responses(
+        mut reader: BufReader<ReadHalf<Box<dyn ElectrumStream>>>,
+        pending: PendingRequests,
+    ) {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line).await {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) => {
+                    debug!("Electrum connection failed: {e}");
+                    break;
+                }
+            }
+            // Batch responses come as an array, in any order
+            let responses = match serde_json::from_str(&line) {
+                Ok(Value::Array(responses)) => responses,
+                Ok(response) => vec![response],
+                Err(e) => {
+                    debug!("Invalid Electrum response: {e}");
+                    break;
+                }
+            };
+            let mut pending_requests = pending.lock().unwrap();
+            for response in responses {
+                // Subscription notifications have no id and are skipped
+                let sender = response
+                    .get("id")
+                    .and_then(Value::as_u64)
+                    .and_then(|id| pending_requests.as_mut()?.remove(&id));
+                if let Some(sender) = sender {
+                    _ = sender.send(response);
+                }
+            }
+        }
+        // Dropping the senders fails the requests still waiting for a response
+        *pending.lock().unwrap() = None;
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.reader.abort();
+        *self.pending.lock().unwrap() = None;
+    }
+}
+
+/// The Electrum script hash: the reversed SHA256 of the script, hex-encoded
+fn script_hash(script: &Script) -> String {
+    let mut hash = sha256::Hash::hash(script.as_bytes()).into_inner();
+    hash.reverse();
+    hex::encode(hash)
+}
+
+fn script_type(script: &Script) -> &'static str {
+    if script.is_p2pkh() {
+        "p2pkh"
+    } else if script.is_p2sh() {
+        "p2sh"
+    } else if script.is_v0_p2wpkh() {
+        "v0_p2wpkh"
+    } else if script.is_v0_p2wsh() {
+        "v0_p2wsh"
+    } else if script.is_v1_p2tr() {
+        "v1_p2tr"
+    } else if script.is_op_return() {
+        "op_return"
+    } else {
+        "unknown"
+    }
+}
+
+fn decode_hex(value: &Value) -> SdkResult<Vec<u8>> {
+    let hex_str = value
+        .as_str()
+        .ok_or_else(|| SdkError::generic(&format!("Expected a hex string, got {value}")))?;
+    hex::decode(hex_str).map_err(|e| SdkError::generic(&e.to_string()))
+}
+
+/// Converts an Electrum fee rate, in BTC/kvB, to sat/vB. Returns `None` if the server had no
+/// estimate, which it signals with a negative rate.
+fn btc_per_kvb_to_sat_per_vbyte(fee: &Value) -> Option<u64> {
+    fee.as_f64()
+        .filter(|fee| *fee > 0.0)
+        .map(|fee| ((fee * 100_000.0).ceil() as u64).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use serde_json::{json, Value};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream};
+
+    use super::{
+        btc_per_kvb_to_sat_per_vbyte, script_hash, Connection, ElectrumChainService, ElectrumClient,
+    };
+    use crate::bitcoin::consensus::encode::serialize_hex;
+    use crate::bitcoin::hashes::hex::ToHex;
+    use crate::bitcoin::hashes::Hash;
+    use crate::bitcoin::{
+        Address, BlockHash, BlockHeader, Network, OutPoint, PackedLockTime, Script, Sequence,
+        Transaction, TxIn, TxMerkleNode, TxOut, Witness,
+    };
+    use crate::chain::ChainService;
+
+    /// A client already connected to the given end of a duplex stream
+    fn connected_client(stream: DuplexStream) -> ElectrumClient {
+        // Reconnecting to this address fails right away
+        let client = ElectrumClient::new("tcp://127.0.0.1:1", None).unwrap();
+        *client.connection.try_lock().unwrap() = Some(Connection::new(Box::new(stream)));
+        client
+    }
+
+    fn key(method: &str, params: Value) -> String {
+        format!("{method} {params}")
+    }
+
+    /// A client connected to a scripted server, answering the calls from `results` keyed with
+    /// [key]. Also returns the number of request lines received by the server.
+    fn mock_client(results: HashMap<String, Value>) -> (ElectrumClient, Arc<AtomicUsize>) {
+        let (client_stream, server_stream) = tokio::io::duplex(1 << 16);
+        let request_lines = Arc::new(AtomicUsize::new(0));
+        let received = request_lines.clone();
+        tokio::spawn(async move {
+            let respond = |request: &Value| {
+                let key = key(
+                    request["method"].as_str().unwrap(),
+                    request["params"].clone(),
+                );
+                match results.get(&key) {
+                    Some(result) => {
+                        json!({"jsonrpc": "2.0", "id": request["id"], "result": result})
+                    }
+                    None => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": {"code": -32601, "message": format!("Unexpected {key}")}
+                    }),
+                }
+            };
+            let (reader, mut writer) = tokio::io::split(server_stream);
+            let mut lines = BufReader::new(reader).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                received.fetch_add(1, Ordering::SeqCst);
+                let response = match serde_json::from_str(&line).unwrap() {
+                    // Batches are answered in reverse order, the client has to match the ids
+                    Value::Array(requests) => {
+                        Value::from(requests.iter().rev().map(&respond).collect::<Vec<_>>())
+                    }
+                    request => respond(&request),
+                };
+                // Notifications can come before any response and are skipped by the client
+                let notification = json!({
+                    "jsonrpc": "2.0",
+                    "method": "blockchain.headers.subscribe",
+                    "params": [{"height": 1, "hex": ""}]
+                });
+                writer
+                    .write_all(format!("{notification}\n{response}\n").as_bytes())
+                    .await
+                    .unwrap();
+            }
+        });
+        (connected_client(client_stream), request_lines)
+    }
+
+    fn funding_tx(outputs: Vec<TxOut>) -> Transaction {
+        Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::null(),
+                script_sig: Script::new(),
+                sequence: Sequence::MAX,
+                witness: Witness::new(),
+            }],
+            output: outputs,
+        }
+    }
+
+    fn spending_tx(funding: &Transaction, vout: u32, output: TxOut) -> Transaction {
+        Transaction {
+            input: vec![TxIn {
+                previous_output: OutPoint::new(funding.txid(), vout),
+                ..funding.input[0].clone()
+            }],
+            output: vec![output],
+            ..funding.clone()
+        }
+    }
+
+    fn block_header(time: u32) -> BlockHeader {
+        BlockHeader {
+            version: 1,
+            prev_blockhash: BlockHash::all_zeros(),
+            merkle_root: TxMerkleNode::all_zeros(),
+            time,
+            bits: 0x1d00ffff,
+            nonce: 0,
+        }
+    }
+
+    #[test]
+    fn test_script_hash() {
+        // Example from the Electrum protocol docs
+        let script = Address::from_str("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa")
+            .unwrap()
+            .script_pubkey();
+        assert_eq!(
+            script_hash(&script),
+            "8b01df4e368ea28f8dc0423bcf7a4923e3a12d307c875e47a0cfbf90b5c39161"
+        );
+        assert_eq!(script_hash(&Script::new()).len(), 64);
+    }
+
+    #[test]
+    fn test_fee_conversion() {
+        assert_eq!(btc_per_kvb_to_sat_per_vbyte(&json!(0.00012)), Some(12));
+        assert_eq!(btc_per_kvb_to_sat_per_vbyte(&json!(0.000001)), Some(1));
+        assert_eq!(btc_per_kvb_to_sat_per_vbyte(&json!(-1)), None);
+    }
+
+    #[test]
+    fn test_parse_url() {
+        let client = ElectrumClient::new("ssl://electrum.blockstream.info:50002", None).unwrap();
+        assert_eq!(client.host, "electrum.blockstream.info");
+        assert_eq!(client.port, 50002);
+        assert!(client.use_ssl);
+
+        let client = ElectrumClient::new("tcp://abcdef.onion:50001", None).unwrap();
+        assert_eq!(client.host, "abcdef.onion");
+        assert!(!client.use_ssl);
+
+        assert!(ElectrumClient::new("electrum.blockstream.info:50002", None).is_err());
+        assert!(ElectrumClient::new("ssl://electrum.blockstream.info", None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pipelined_calls() {
+        let (client_stream, server_stream) = tokio::io::duplex(1 << 16);
+        let client = connected_client(client_stream);
+        let server = tokio::spawn(async move {
+            let (reader, mut writer) = tokio::io::split(server_stream);
+            let mut lines = BufReader::new(reader).lines();
+            // Both requests arrive before any response is sent, which then come in reverse order
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let line = lines.next_line().await.unwrap().unwrap();
+                requests.push(serde_json::from_str::<Value>(&line).unwrap());
+            }
+            for request in requests.iter().rev() {
+                let response =
+                    json!({"jsonrpc": "2.0", "id": request["id"], "result": request["method"]});
+                writer
+                    .write_all(format!("{response}\n").as_bytes())
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let (version, banner) = tokio::join!(
+            client.call("server.version", vec![]),
+            client.call("server.banner", vec![])
+        );
+        assert_eq!(version.unwrap(), json!("server.version"));
+        assert_eq!(banner.unwrap(), json!("server.banner"));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_batch_call() {
+        let (client, request_lines) = mock_client(HashMap::from([
+            (key("blockchain.block.header", json!([1])), json!("01")),
+            (key("blockchain.block.header", json!([2])), json!("02")),
+        ]));
+
+        let results = client
+            .batch_call(vec![
+                ("blockchain.block.header", vec![json!(1)]),
+                ("blockchain.block.header", vec![json!(2)]),
+            ])
+            .await
+            .unwrap();
+        assert_eq!(results, vec![json!("01"), json!("02")]);
+        assert_eq!(request_lines.load(Ordering::SeqCst), 1);
+
+        // A failed call fails the batch
+        let res = client
+            .batch_call(vec![
+                ("blockchain.block.header", vec![json!(1)]),
+                ("blockchain.block.header", vec![json!(3)]),
+            ])
+            .await;
+        assert!(res.is_err());
+
+        // An empty batch isn't sent
+        assert!(client.batch_call(vec![]).await.unwrap().is_empty());
+        assert_eq!(request_lines.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_connection_closed() {
+        let (client_stream, server_stream) = tokio::io::duplex(1 << 16);
+        let client = connected_client(client_stream);
+        tokio::spawn(async move {
+            // Close the connection once the request is received, without answering it
+            let mut lines = BufReader::new(server_stream).lines();
+            lines.next_line().await.unwrap();
+        });
+
+        // The pending call fails instead of waiting for the timeout, as does the reconnection
+        assert!(client.call("server.version", vec![]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_address_transactions() {
+        let address = Address::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq").unwrap();
+        let script = address.script_pubkey();
+        let funding = funding_tx(vec![TxOut {
+            value: 100_000,
+            script_pubkey: script.clone(),
+        }]);
+        let spending = spending_tx(
+            &funding,
+            0,
+            TxOut {
+                value: 90_000,
+                script_pubkey: script.clone(),
+            },
+        );
+        let header = block_header(1_700_000_000);
+        let (client, request_lines) = mock_client(HashMap::from([
+            (
+                key(
+                    "blockchain.scripthash.get_history",
+                    json!([script_hash(&script)]),
+                ),
+                json!([
+                    {"tx_hash": funding.txid().to_hex(), "height": 100},
+                    {"tx_hash": spending.txid().to_hex(), "height": 0},
+                ]),
+            ),
+            (
+                key(
+                    "blockchain.transaction.get",
+                    json!([funding.txid().to_hex()]),
+                ),
+                json!(serialize_hex(&funding)),
+            ),
+            (
+                key(
+                    "blockchain.transaction.get",
+                    json!([spending.txid().to_hex()]),
+                ),
+                json!(serialize_hex(&spending)),
+            ),
+            (
+                key("blockchain.block.header", json!([100])),
+                json!(serialize_hex(&header)),
+            ),
+        ]));
+        let service = ElectrumChainService {
+            client: Arc::new(client),
+            network: Network::Bitcoin,
+        };
+
+        let txs = service
+            .address_transactions(address.to_string())
+            .await
+            .unwrap();
+        // The history, the transactions and the block header, whatever the number of transactions
+        assert_eq!(request_lines.load(Ordering::SeqCst), 3);
+        assert_eq!(txs.len(), 2);
+
+        assert_eq!(txs[0].txid, spending.txid().to_hex());
+        assert!(!txs[0].status.confirmed);
+        assert_eq!(txs[0].vin[0].prevout.value, 100_000);
+        assert_eq!(txs[0].fee, 10_000);
+
+        assert_eq!(txs[1].txid, funding.txid().to_hex());
+        assert!(txs[1].vin[0].is_coinbase);
+        assert_eq!(txs[1].status.block_height, Some(100));
+        assert_eq!(txs[1].status.block_time, Some(1_700_000_000));
+        assert_eq!(txs[1].status.block_hash, Some(header.block_hash().to_hex()));
+    }
+
+    #[tokio::test]
+    async fn test_transaction_outspends() {
+        let spent_script = Address::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+            .unwrap()
+            .script_pubkey();
+        let unspent_script = Address::from_str("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa")
+            .unwrap()
+            .script_pubkey();
+        let funding = funding_tx(vec![
+            TxOut {
+                value: 100_000,
+                script_pubkey: spent_script.clone(),
+            },
+            TxOut {
+                value: 50_000,
+                script_pubkey: unspent_script.clone(),
+            },
+        ]);
+        let spending = spending_tx(
+            &funding,
+            0,
+            TxOut {
+                value: 90_000,
+                script_pubkey: unspent_script.clone(),
+            },
+        );
+        let (client, request_lines) = mock_client(HashMap::from([
+            (
+                key(
+                    "blockchain.transaction.get",
+                    json!([funding.txid().to_hex()]),
+                ),
+                json!(serialize_hex(&funding)),
+            ),
+            (
+                key(
+                    "blockchain.scripthash.get_history",
+                    json!([script_hash(&spent_script)]),
+                ),
+                json!([
+                    {"tx_hash": funding.txid().to_hex(), "height": 100},
+                    {"tx_hash": spending.txid().to_hex(), "height": 101},
+                ]),
+            ),
+            (
+                key(
+                    "blockchain.scripthash.get_history",
+                    json!([script_hash(&unspent_script)]),
+                ),
+                json!([{"tx_hash": funding.txid().to_hex(), "height": 100}]),
+            ),
+            (
+                key(
+                    "blockchain.transaction.get",
+                    json!([spending.txid().to_hex()]),
+                ),
+                json!(serialize_hex(&spending)),
+            ),
+            (
+                key("blockchain.block.header", json!([101])),
+                json!(serialize_hex(&block_header(1_700_000_600))),
+            ),
+        ]));
+        let service = ElectrumChainService {
+            client: Arc::new(client),
+            network: Network::Bitcoin,
+        };
+
+        let outspends = service
+            .transaction_outspends(funding.txid().to_hex())
+            .await
+            .unwrap();
+        // The transaction, the histories, the spending transactions and the block headers
+        assert_eq!(request_lines.load(Ordering::SeqCst), 4);
+        assert_eq!(outspends.len(), 2);
+
+        assert!(outspends[0].spent);
+        assert_eq!(outspends[0].txid, Some(spending.txid().to_hex()));
+        assert_eq!(outspends[0].vin, Some(0));
+        let status = outspends[0].status.as_ref().unwrap();
+        assert_eq!(status.block_height, Some(101));
+        assert_eq!(status.block_time, Some(1_700_000_600));
+
+        assert!(!outspends[1].spent);
+        assert!(outspends[1].txid.is_none());
+    }
+}
@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+/// The payment destinations fetched from the [crate::Config::denylist_url], as cached in the DB
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Denylist {
+    /// The URL the list was fetched from
+    pub url: String,
+    /// Node pubkeys, lightning addresses and LNURL domains, lowercased
+    pub entries: Vec<String>,
+    pub updated_at: u64,
+}
+
+impl Denylist {
+    /// Parses the list served at the denylist URL: a JSON array of node pubkeys, lightning
+    /// addresses and LNURL domains
+    pub(crate) fn parse(raw: &str, url: &str, updated_at: u64) -> Result<Self, serde_json::Error> {
+        let entries: Vec<String> = serde_json::from_str(raw)?;
+        Ok(Self {
+            url: url.to_string(),
+            entries: entries
+                .into_iter()
+                .map(|e| e.trim().to_lowercase())
+                .filter(|e| !e.is_empty())
+                .collect(),
+            updated_at,
+        })
+    }
+
+    pub(crate) fn is_stale(&self, now: u64, max_age_secs: u64) -> bool {
+        self.updated_at + max_age_secs <= now
+    }
+
+    /// The first of the destinations that is denied, if any.
+    ///
+    /// A denied domain also denies its subdomains.
+    pub(crate) fn find_denied<'a>(&self, destinations: &[&'a str]) -> Option<&'a str> {
+        destinations.iter().copied().find(|destination| {
+            let destination = destination.trim().to_lowercase();
+            !destination.is_empty()
+                && self.entries.iter().any(|entry| {
+                    destination == *entry || destination.ends_with(&format!(".{entry}"))
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Denylist;
+
+    #[test]
+    fn test_find_denied() {
+        let pubkey = "02eadbd9e7557375161df8b646776a547c5cbc2e95b3071ec81553f8ec2cea3b8c";
+        let denylist = Denylist::parse(
+            &format!(
+                r#"["{}", "Scam@Example.com", "evil.com", " "]"#,
+                pubkey.to_uppercase()
+            ),
+            "https://denylist.example.com",
+            100,
+        )
+        .unwrap();
+        assert_eq!(denylist.entries.len(), 3);
+
+        assert_eq!(denylist.find_denied(&[pubkey]), Some(pubkey));
+        assert_eq!(
+            denylist.find_denied(&["example.com", "scam@example.com"]),
+            Some("scam@example.com")
+        );
+        assert_eq!(
+            denylist.find_denied(&["pay.evil.com"]),
+            Some("pay.evil.com")
+        );
+        assert_eq!(denylist.find_denied(&["notevil.com"]), None);
+        assert_eq!(denylist.find_denied(&["example.com", ""]), None);
+
+        assert!(!denylist.is_stale(150, 100));
+        assert!(denylist.is_stale(200, 100));
+        assert!(
+            Denylist::parse(r#"{"entries": []}"#, "https://denylist.example.com", 100).is_err()
+        );
+    }
+}
@@ -5,8 +5,9 @@ use sdk_common::prelude::*;
 use thiserror::Error;
 
 use crate::{
-    bitcoin::util::bip32, node_api::NodeError, persist::error::PersistError,
-    swap_in::ReceiveSwapError, swap_out::error::ReverseSwapError,
+    bitcoin::util::bip32, htlc_exposure::HtlcExposureError, node_api::NodeError,
+    persist::error::PersistError, spend_policy::SpendPolicyError, swap_in::ReceiveSwapError,
+    swap_out::error::ReverseSwapError, ReceiveLimitReason,
 };
 
 pub type SdkResult<T, E = SdkError> = Result<T, E>;
@@ -19,11 +20,23 @@ pub enum ConnectError {
     #[error("Generic: {err}")]
     Generic { err: String },
 
-    /// This error is raised when [crate::models::ConnectRequest::restore_only] is set to true
+    /// This error is raised when [crate::models::ConnectRequest::register_if_missing] is set to
+    /// false, or it is not set and [crate::models::ConnectRequest::restore_only] is set to true,
     /// and no node exists for [crate::models::ConnectRequest::seed].
     #[error("Restore only: {err}")]
     RestoreOnly { err: String },
 
+    /// This error is raised when registering a new node fails because the registration quota of
+    /// the partner credentials in [crate::models::GreenlightNodeConfig::partner_credentials] is
+    /// exhausted.
+    #[error("Registration quota exceeded: {err}")]
+    RegistrationQuotaExceeded { err: String },
+
+    /// This error is raised when registering a new node is refused, for example because neither
+    /// partner credentials nor an invite code are set, or because they are invalid.
+    #[error("Registration rejected: {err}")]
+    RegistrationRejected { err: String },
+
     /// This error is raised when a connection to an external service fails.
     #[error("Service connectivity: {err}")]
     ServiceConnectivity { err: String },
@@ -41,6 +54,8 @@ impl From<NodeError> for ConnectError {
     fn from(value: NodeError) -> Self {
         match value {
             NodeError::RestoreOnly(err) => Self::RestoreOnly { err },
+            NodeError::RegistrationQuotaExceeded(err) => Self::RegistrationQuotaExceeded { err },
+            NodeError::RegistrationRejected(err) => Self::RegistrationRejected { err },
             NodeError::ServiceConnectivity(err) => Self::ServiceConnectivity { err },
             _ => Self::Generic {
                 err: value.to_string(),
@@ -72,6 +87,14 @@ impl From<SdkError> for ConnectError {
     }
 }
 
+impl From<PersistError> for LnUrlAuthError {
+    fn from(err: PersistError) -> Self {
+        Self::Generic {
+            err: err.to_string(),
+        }
+    }
+}
+
 impl From<SdkError> for LnUrlAuthError {
     fn from(value: SdkError) -> Self {
         match value {
@@ -102,6 +125,11 @@ impl From<SendPaymentError> for LnUrlPayError {
     fn from(value: SendPaymentError) -> Self {
         match value {
             SendPaymentError::AlreadyPaid => Self::AlreadyPaid,
+            SendPaymentError::Cancelled => Self::Cancelled,
+            SendPaymentError::AlreadyInProgress => Self::Generic {
+                err: value.to_string(),
+            },
+            SendPaymentError::DeniedDestination { err } => Self::DeniedDestination { err },
             SendPaymentError::Generic { err } => Self::Generic { err },
             SendPaymentError::InvalidAmount { err } => Self::InvalidAmount { err },
             SendPaymentError::InvalidInvoice { err } => Self::InvalidInvoice { err },
@@ -112,7 +140,20 @@ impl From<SendPaymentError> for LnUrlPayError {
             SendPaymentError::RouteNotFound { err } => Self::RouteNotFound { err },
             SendPaymentError::RouteTooExpensive { err } => Self::RouteTooExpensive { err },
             SendPaymentError::ServiceConnectivity { err } => Self::ServiceConnectivity { err },
-            SendPaymentError::InsufficientBalance { err } => Self::InsufficientBalance { err },
+            SendPaymentError::InsufficientBalance { err, .. } => Self::InsufficientBalance { err },
+            SendPaymentError::SpendPolicyViolation { err } => Self::SpendPolicyViolation { err },
+            SendPaymentError::HtlcExposureExceeded { err } => Self::HtlcExposureExceeded { err },
+        }
+    }
+}
+
+impl From<SpendPolicyError> for LnUrlPayError {
+    fn from(value: SpendPolicyError) -> Self {
+        match value {
+            SpendPolicyError::Persist(err) => err.into(),
+            _ => Self::SpendPolicyViolation {
+                err: value.to_string(),
+            },
         }
     }
 }
@@ -133,6 +174,9 @@ impl From<ReceivePaymentError> for LnUrlWithdrawError {
             | ReceivePaymentError::InvoiceNoDescription { err }
             | ReceivePaymentError::InvoicePreimageAlreadyExists { err } => Self::Generic { err },
             ReceivePaymentError::InvalidAmount { err } => Self::InvalidAmount { err },
+            ReceivePaymentError::AmountExceedsMaximum { .. } => Self::InvalidAmount {
+                err: value.to_string(),
+            },
             ReceivePaymentError::InvalidInvoice { err } => Self::InvalidInvoice { err },
             ReceivePaymentError::ServiceConnectivity { err } => Self::ServiceConnectivity { err },
             ReceivePaymentError::InvoiceNoRoutingHints { err } => {
@@ -268,6 +312,14 @@ pub enum ReceivePaymentError {
     #[error("Invalid amount: {err}")]
     InvalidAmount { err: String },
 
+    /// This error is raised when the amount exceeds the maximum that can currently be received.
+    /// The `max_amount_msat` can be suggested instead.
+    #[error("Amount exceeds maximum ({reason}): at most {max_amount_msat} msat can be received")]
+    AmountExceedsMaximum {
+        max_amount_msat: u64,
+        reason: ReceiveLimitReason,
+    },
+
     /// This error is raised when the lightning invoice cannot be parsed.
     #[error("Invalid invoice: {err}")]
     InvalidInvoice { err: String },
@@ -303,6 +355,17 @@ impl From<anyhow::Error> for ReceivePaymentError {
     }
 }
 
+impl From<ReceiveOnchainError> for ReceivePaymentError {
+    fn from(value: ReceiveOnchainError) -> Self {
+        match value {
+            ReceiveOnchainError::ServiceConnectivity { err } => Self::ServiceConnectivity { err },
+            _ => Self::Generic {
+                err: value.to_string(),
+            },
+        }
+    }
+}
+
 impl From<InvoiceError> for ReceivePaymentError {
     fn from(value: InvoiceError) -> Self {
         match value {
@@ -348,7 +411,7 @@ impl From<SdkError> for ReceivePaymentError {
 }
 
 /// General error returned by the SDK
-#[derive(Debug, Error)]
+#[derive(Clone, Debug, Error)]
 pub enum SdkError {
     /// This error is raised when a general error occurs not specific to other error variants
     /// in this enum.
@@ -450,6 +513,14 @@ impl From<serde_json::Error> for SdkError {
     }
 }
 
+impl From<SystemTimeError> for SdkError {
+    fn from(err: SystemTimeError) -> Self {
+        Self::Generic {
+            err: err.to_string(),
+        }
+    }
+}
+
 impl From<tonic::transport::Error> for SdkError {
     fn from(err: tonic::transport::Error) -> Self {
         Self::ServiceConnectivity {
@@ -469,10 +540,13 @@ impl From<tonic::Status> for SdkError {
 impl From<SendPaymentError> for SdkError {
     fn from(value: SendPaymentError) -> Self {
         match value {
-            SendPaymentError::AlreadyPaid => Self::Generic {
+            SendPaymentError::AlreadyPaid
+            | SendPaymentError::AlreadyInProgress
+            | SendPaymentError::Cancelled => Self::Generic {
                 err: value.to_string(),
             },
-            SendPaymentError::Generic { err }
+            SendPaymentError::DeniedDestination { err }
+            | SendPaymentError::Generic { err }
             | SendPaymentError::InvalidAmount { err }
             | SendPaymentError::InvalidInvoice { err }
             | SendPaymentError::InvalidNetwork { err }
@@ -481,7 +555,9 @@ impl From<SendPaymentError> for SdkError {
             | SendPaymentError::PaymentTimeout { err }
             | SendPaymentError::RouteNotFound { err }
             | SendPaymentError::RouteTooExpensive { err }
-            | SendPaymentError::InsufficientBalance { err } => Self::Generic { err },
+            | SendPaymentError::SpendPolicyViolation { err }
+            | SendPaymentError::HtlcExposureExceeded { err }
+            | SendPaymentError::InsufficientBalance { err, .. } => Self::Generic { err },
             SendPaymentError::ServiceConnectivity { err } => Self::ServiceConnectivity { err },
         }
     }
@@ -506,27 +582,50 @@ pub enum SendOnchainError {
     #[error("Generic: {err}")]
     Generic { err: String },
 
-    /// This error is raised when the [crate::models::SendOnchainRequest::onchain_recipient_address]
+    /// This error is raised when the [crate::models::PayOnchainRequest::recipient_address]
     /// is invalid.
     #[error("Invalid destination address: {err}")]
     InvalidDestinationAddress { err: String },
 
+    /// This error is raised when the [crate::models::PayOnchainRequest::recipient_address]
+    /// belongs to another network than [crate::Config::network].
+    #[error("Destination address network mismatch: {err}")]
+    DestinationAddressNetworkMismatch { err: String },
+
+    /// This error is raised when the [crate::models::PayOnchainRequest::recipient_address]
+    /// is of a script type the claim transaction can't pay to, like a future segwit version.
+    #[error("Unsupported destination address type: {err}")]
+    UnsupportedDestinationAddressType { err: String },
+
     /// This error is raised when a reverse swap is attempted with a send amount that is not
     /// in the [crate::BreezServices::onchain_payment_limits] range.
     #[error("Send amount is out of range")]
     OutOfRange,
 
     /// This error is raised when attempting to make a pay the HODL invoice by the node fails.
+    /// The reverse swap is cancelled and no funds left the wallet.
     #[error("Payment failed: {err}")]
     PaymentFailed { err: String },
 
-    /// This error is raised when attempting to pay the HODL invoice takes too long.
+    /// This error is raised when attempting to pay the HODL invoice takes too long. The payment
+    /// may still complete: the reverse swap stays in progress until the sync finds it either
+    /// locked up by the swapper or cancelled.
     #[error("Payment timeout: {err}")]
     PaymentTimeout { err: String },
 
     /// This error is raised when a connection to an external service fails.
     #[error("Service connectivity: {err}")]
     ServiceConnectivity { err: String },
+
+    /// This error is raised when the call is cancelled with [crate::BreezServices::cancel]
+    /// before the HODL invoice is paid.
+    #[error("Payment cancelled")]
+    Cancelled,
+
+    /// This error is raised when the payment exceeds the limits of the
+    /// [crate::Config::spend_policy], or its destination is not allowed by it.
+    #[error("Spend policy violation: {err}")]
+    SpendPolicyViolation { err: String },
 }
 impl SendOnchainError {
     pub(crate) fn generic(err: &str) -> Self {
@@ -572,8 +671,21 @@ impl From<ReverseSwapError> for SendOnchainError {
             ReverseSwapError::InvalidDestinationAddress(err) => {
                 Self::InvalidDestinationAddress { err }
             }
+            ReverseSwapError::DestinationAddressNetworkMismatch(err) => {
+                Self::DestinationAddressNetworkMismatch { err }
+            }
+            ReverseSwapError::UnsupportedDestinationAddressType(err) => {
+                Self::UnsupportedDestinationAddressType { err }
+            }
             ReverseSwapError::ServiceConnectivity(err) => Self::ServiceConnectivity { err },
             ReverseSwapError::Node(err) => err.into(),
+            ReverseSwapError::Cancelled => Self::Cancelled,
+            ReverseSwapError::PaymentFailed { .. } => Self::PaymentFailed {
+                err: value.to_string(),
+            },
+            ReverseSwapError::PaymentPending { .. } => Self::PaymentTimeout {
+                err: value.to_string(),
+            },
             _ => Self::Generic {
                 err: value.to_string(),
             },
@@ -589,6 +701,17 @@ impl From<PersistError> for SendOnchainError {
     }
 }
 
+impl From<SpendPolicyError> for SendOnchainError {
+    fn from(value: SpendPolicyError) -> Self {
+        match value {
+            SpendPolicyError::Persist(err) => err.into(),
+            _ => Self::SpendPolicyViolation {
+                err: value.to_string(),
+            },
+        }
+    }
+}
+
 /// Error returned by [crate::breez_services::BreezServices::send_payment] and [crate::breez_services::BreezServices::send_spontaneous_payment]
 #[derive(Clone, Debug, Error)]
 pub enum SendPaymentError {
@@ -596,6 +719,22 @@ pub enum SendPaymentError {
     #[error("Invoice already paid")]
     AlreadyPaid,
 
+    /// This error is raised when attempting to pay an invoice that is already being paid by
+    /// another concurrent call.
+    #[error("Invoice payment already in progress")]
+    AlreadyInProgress,
+
+    /// This error is raised when the call is cancelled with [crate::BreezServices::cancel]. If
+    /// the payment was already handed to the node, it may still complete.
+    #[error("Payment cancelled")]
+    Cancelled,
+
+    /// This error is raised when the destination of the payment is in the denylist, see
+    /// [crate::Config::denylist_url]. It can be paid anyway with
+    /// [crate::models::SendPaymentRequest::skip_denylist].
+    #[error("Denied destination: {err}")]
+    DeniedDestination { err: String },
+
     /// This error is raised when a general error occurs not specific to other error variants
     /// in this enum.
     #[error("Generic: {err}")]
@@ -641,8 +780,45 @@ pub enum SendPaymentError {
     ServiceConnectivity { err: String },
 
     /// This error is raised when the node does not have enough funds to make the payment.
+    ///
+    /// `max_sendable_msat` is set when the amount that can be sent is known.
     #[error("Insufficient balance: {err}")]
-    InsufficientBalance { err: String },
+    InsufficientBalance {
+        err: String,
+        max_sendable_msat: Option<u64>,
+    },
+
+    /// This error is raised when the payment exceeds the limits of the
+    /// [crate::Config::spend_policy], or its destination is not allowed by it.
+    #[error("Spend policy violation: {err}")]
+    SpendPolicyViolation { err: String },
+
+    /// This error is raised when the payment exceeds the
+    /// [crate::Config::htlc_exposure_limits].
+    #[error("HTLC exposure exceeded: {err}")]
+    HtlcExposureExceeded { err: String },
+}
+
+impl From<SpendPolicyError> for SendPaymentError {
+    fn from(value: SpendPolicyError) -> Self {
+        match value {
+            SpendPolicyError::Persist(err) => err.into(),
+            _ => Self::SpendPolicyViolation {
+                err: value.to_string(),
+            },
+        }
+    }
+}
+
+impl From<HtlcExposureError> for SendPaymentError {
+    fn from(value: HtlcExposureError) -> Self {
+        match value {
+            HtlcExposureError::Persist(err) => err.into(),
+            _ => Self::HtlcExposureExceeded {
+                err: value.to_string(),
+            },
+        }
+    }
 }
 
 impl From<anyhow::Error> for SendPaymentError {
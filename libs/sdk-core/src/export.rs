@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{SdkError, SdkResult};
+use crate::models::{
+    ExportFormat, LnPaymentDetails, Payment, PaymentDetails, PaymentStatus, PaymentType,
+};
+
+/// The columns of an exported payment
+#[derive(Serialize, Deserialize)]
+struct PaymentRecord {
+    id: String,
+    /// The payment time in RFC 3339 format, in UTC
+    #[serde(default)]
+    date: String,
+    /// Epoch time, in seconds
+    timestamp: i64,
+    payment_type: String,
+    status: String,
+    amount_msat: u64,
+    fee_msat: u64,
+    fiat_currency: Option<String>,
+    fiat_amount: Option<f64>,
+    fiat_fee: Option<f64>,
+    description: Option<String>,
+}
+
+/// The exchange rate of a fiat currency, in fiat units per bitcoin
+#[derive(Clone)]
+pub(crate) struct FiatRate {
+    pub(crate) currency: String,
+    pub(crate) value: f64,
+}
+
+impl FiatRate {
+    fn convert(&self, amount_msat: u64) -> f64 {
+        amount_msat as f64 * self.value / 100_000_000_000.0
+    }
+}
+
+/// Renders the payments in the given format, with their fiat values if a rate is given
+pub(crate) fn export_payments(
+    payments: Vec<Payment>,
+    format: ExportFormat,
+    fiat_rate: Option<FiatRate>,
+) -> SdkResult<String> {
+    let records = payments
+        .into_iter()
+        .map(|p| {
+            // Prefer the rate recorded when the payment settled
+            let fiat_rate = match (&p.fiat_rate, &fiat_rate) {
+                (Some(rate), Some(requested)) if rate.coin == requested.currency => {
+                    Some(FiatRate {
+                        currency: rate.coin.clone(),
+                        value: rate.value,
+                    })
+                }
+                (_, requested) => requested.clone(),
+            };
+            PaymentRecord {
+                date: DateTime::from_timestamp(p.payment_time, 0)
+                    .map(|d| d.to_rfc3339_opts(SecondsFormat::Secs, true))
+                    .unwrap_or_default(),
+                timestamp: p.payment_time,
+                payment_type: p.payment_type.to_string(),
+                status: format!("{:?}", p.status),
+                fiat_currency: fiat_rate.as_ref().map(|r| r.currency.clone()),
+                fiat_amount: fiat_rate.as_ref().map(|r| r.convert(p.amount_msat)),
+                fiat_fee: fiat_rate.as_ref().map(|r| r.convert(p.fee_msat)),
+                amount_msat: p.amount_msat,
+                fee_msat: p.fee_msat,
+                description: p.description,
+                id: p.id,
+            }
+        })
+        .collect::<Vec<PaymentRecord>>();
+
+    match format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(&records)?),
+        ExportFormat::Csv => {
+            let mut csv = String::from(
+                "id,date,timestamp,payment_type,status,amount_msat,fee_msat,fiat_currency,fiat_amount,fiat_fee,description\n",
+            );
+            for r in records {
+                let row = [
+                    r.id,
+                    r.date,
+                    r.timestamp.to_string(),
+                    r.payment_type,
+                    r.status,
+                    r.amount_msat.to_string(),
+                    r.fee_msat.to_string(),
+                    r.fiat_currency.unwrap_or_default(),
+                    r.fiat_amount.map(|a| format!("{a:.2}")).unwrap_or_default(),
+                    r.fiat_fee.map(|a| format!("{a:.2}")).unwrap_or_default(),
+                    r.description.unwrap_or_default(),
+                ];
+                let row: Vec<String> = row.iter().map(|field| escape_csv_field(field)).collect();
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+            Ok(csv)
+        }
+    }
+}
+
+/// Quotes the field if it contains a separator, a quote or a line break
+fn escape_csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// Parses payments in the format of [export_payments]. Only the `id`, `timestamp`,
+/// `payment_type` and `amount_msat` columns are required, so payments exported by other
+/// wallets can be imported after renaming their columns.
+pub(crate) fn import_payments(data: &str, format: ExportFormat) -> SdkResult<Vec<Payment>> {
+    let records: Vec<PaymentRecord> = match format {
+        ExportFormat::Json => serde_json::from_str(data)?,
+        ExportFormat::Csv => parse_csv_records(data)?,
+    };
+    records.into_iter().map(record_to_payment).collect()
+}
+
+fn parse_csv_records(data: &str) -> SdkResult<Vec<PaymentRecord>> {
+    let mut rows = parse_csv(data).into_iter();
+    let header: HashMap<String, usize> = match rows.next() {
+        Some(header) => header
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| (name.trim().to_string(), i))
+            .collect(),
+        None => return Ok(vec![]),
+    };
+
+    rows.enumerate()
+        .map(|(i, row)| {
+            let field = |name: &str| {
+                header
+                    .get(name)
+                    .and_then(|&col| row.get(col))
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+            let required = |name: &str| {
+                field(name).ok_or(SdkError::Generic {
+                    err: format!("Missing {name} in row {}", i + 1),
+                })
+            };
+            let number = |name: &str, value: String| {
+                value.parse::<u64>().map_err(|_| SdkError::Generic {
+                    err: format!("Invalid {name} in row {}: {value}", i + 1),
+                })
+            };
+            Ok(PaymentRecord {
+                id: required("id")?,
+                date: field("date").unwrap_or_default(),
+                timestamp: required("timestamp")?
+                    .parse()
+                    .map_err(|_| SdkError::Generic {
+                        err: format!("Invalid timestamp in row {}", i + 1),
+                    })?,
+                payment_type: required("payment_type")?,
+                status: field("status").unwrap_or(format!("{:?}", PaymentStatus::Complete)),
+                amount_msat: number("amount_msat", required("amount_msat")?)?,
+                fee_msat: match field("fee_msat") {
+                    Some(fee_msat) => number("fee_msat", fee_msat)?,
+                    None => 0,
+                },
+                fiat_currency: None,
+                fiat_amount: None,
+                fiat_fee: None,
+                description: field("description"),
+            })
+        })
+        .collect()
+}
+
+/// Splits the CSV data in rows of fields, unescaping the quoted fields. Empty lines are skipped.
+fn parse_csv(data: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = data.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|row| row.len() > 1 || row.first().is_some_and(|f| !f.is_empty()));
+    rows
+}
+
+fn record_to_payment(record: PaymentRecord) -> SdkResult<Payment> {
+    let payment_type = PaymentType::from_str(&record.payment_type).map_err(|_| {
+        SdkError::generic(&format!(
+            "Invalid payment type of payment {}: {}",
+            record.id, record.payment_type
+        ))
+    })?;
+    let status = match record.status.as_str() {
+        "Pending" => PaymentStatus::Pending,
+        "Complete" => PaymentStatus::Complete,
+        "Failed" => PaymentStatus::Failed,
+        status => {
+            return Err(SdkError::generic(&format!(
+                "Invalid status of payment {}: {status}",
+                record.id
+            )))
+        }
+    };
+    Ok(Payment {
+        details: PaymentDetails::Ln {
+            data: LnPaymentDetails {
+                payment_hash: record.id.clone(),
+                ..Default::default()
+            },
+        },
+        id: record.id,
+        payment_type,
+        payment_time: record.timestamp,
+        amount_msat: record.amount_msat,
+        fee_msat: record.fee_msat,
+        status,
+        description: record.description,
+        imported: true,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use sdk_common::prelude::Rate;
+
+    use super::{export_payments, import_payments, FiatRate};
+    use crate::models::{ExportFormat, Payment, PaymentStatus, PaymentType};
+
+    fn payment(id: &str, description: &str) -> Payment {
+        Payment {
+            id: id.to_string(),
+            payment_type: PaymentType::Received,
+            payment_time: 1_700_000_000,
+            amount_msat: 50_000_000,
+            fee_msat: 1_000_000,
+            status: PaymentStatus::Complete,
+            description: Some(description.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_export_payments_csv() -> Result<()> {
+        let csv = export_payments(
+            vec![payment("1", "coffee"), payment("2", "tea, \"green\"")],
+            ExportFormat::Csv,
+            Some(FiatRate {
+                currency: "USD".to_string(),
+                value: 40_000.0,
+            }),
+        )?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "1,2023-11-14T22:13:20Z,1700000000,Received,Complete,50000000,1000000,USD,20.00,0.40,coffee"
+        );
+        assert!(lines[2].ends_with(",\"tea, \"\"green\"\"\""));
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_payments_recorded_rate() -> Result<()> {
+        let mut recorded = payment("1", "coffee");
+        recorded.fiat_rate = Some(Rate {
+            coin: "USD".to_string(),
+            value: 30_000.0,
+        });
+        let json = export_payments(
+            vec![recorded, payment("2", "tea")],
+            ExportFormat::Json,
+            Some(FiatRate {
+                currency: "USD".to_string(),
+                value: 40_000.0,
+            }),
+        )?;
+        let records: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(records[0]["fiat_amount"], 15.0);
+        assert_eq!(records[1]["fiat_amount"], 20.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_payments() -> Result<()> {
+        let payments = vec![
+            payment("1", "coffee"),
+            payment("2", "tea, \"green\"\nand milk"),
+        ];
+        for format in [ExportFormat::Csv, ExportFormat::Json] {
+            let imported =
+                import_payments(&export_payments(payments.clone(), format, None)?, format)?;
+            assert_eq!(imported.len(), 2);
+            assert_eq!(imported[1].id, "2");
+            assert_eq!(imported[1].payment_time, 1_700_000_000);
+            assert_eq!(imported[1].amount_msat, 50_000_000);
+            assert_eq!(imported[1].fee_msat, 1_000_000);
+            assert_eq!(imported[1].status, PaymentStatus::Complete);
+            assert_eq!(
+                imported[1].description.as_deref(),
+                Some("tea, \"green\"\nand milk")
+            );
+            assert!(imported[1].imported);
+        }
+
+        // Only some columns, in another order
+        let imported = import_payments(
+            "payment_type,amount_msat,timestamp,id\r\nSent,1000,1700000000,abc\r\n",
+            ExportFormat::Csv,
+        )?;
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].payment_type, PaymentType::Sent);
+        assert_eq!(imported[0].fee_msat, 0);
+
+        assert!(import_payments("id,timestamp\nabc,1700000000\n", ExportFormat::Csv).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_payments_json() -> Result<()> {
+        let json = export_payments(vec![payment("1", "coffee")], ExportFormat::Json, None)?;
+        let records: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(records[0]["id"], "1");
+        assert_eq!(records[0]["amount_msat"], 50_000_000);
+        assert!(records[0]["fiat_amount"].is_null());
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    backup::{BackupState, BackupTransport},
+    backup::{BackupState, BackupTransport, MAX_BACKUP_SNAPSHOTS},
     error::{SdkError, SdkResult},
 };
 
@@ -9,6 +9,7 @@ use sdk_common::with_connection_retry;
 use std::sync::Arc;
 
 const BREEZ_SDK_DATASTORE_PATH: [&str; 2] = ["breez-sdk", "backup"];
+const BREEZ_SDK_SNAPSHOTS_DATASTORE_PATH: [&str; 2] = ["breez-sdk", "snapshots"];
 
 pub(crate) struct GLBackupTransport {
     pub(crate) inner: Arc<Greenlight>,
@@ -18,6 +19,28 @@ impl GLBackupTransport {
     fn gl_key(&self) -> Vec<String> {
         BREEZ_SDK_DATASTORE_PATH.map(|s| s.into()).to_vec()
     }
+
+    fn gl_snapshot_key(&self, generation: Option<u64>) -> Vec<String> {
+        let mut key: Vec<String> = BREEZ_SDK_SNAPSHOTS_DATASTORE_PATH
+            .map(|s| s.into())
+            .to_vec();
+        if let Some(generation) = generation {
+            key.push(generation.to_string());
+        }
+        key
+    }
+
+    async fn list_datastore(
+        &self,
+        key: Vec<String>,
+    ) -> SdkResult<Vec<cln::ListdatastoreDatastore>> {
+        let mut client = self.inner.get_node_client().await?;
+        let req = cln::ListdatastoreRequest { key };
+        Ok(with_connection_retry!(client.list_datastore(req.clone()))
+            .await?
+            .into_inner()
+            .datastore)
+    }
 }
 
 #[tonic::async_trait]
@@ -65,4 +88,49 @@ impl BackupTransport for GLBackupTransport {
             .into_inner();
         Ok(response.generation.unwrap())
     }
+
+    async fn push_snapshot(&self, state: BackupState) -> SdkResult<()> {
+        let mut client = self.inner.get_node_client().await?;
+        let req = cln::DatastoreRequest {
+            key: self.gl_snapshot_key(Some(state.generation)),
+            string: None,
+            hex: Some(state.data),
+            generation: None,
+            mode: Some(cln::datastore_request::DatastoreMode::CreateOrReplace.into()),
+        };
+        with_connection_retry!(client.datastore(req.clone())).await?;
+
+        // Delete the oldest snapshots
+        let mut generations = self.list_snapshots().await?;
+        generations.sort_unstable_by(|a, b| b.cmp(a));
+        for generation in generations.into_iter().skip(MAX_BACKUP_SNAPSHOTS) {
+            let req = cln::DeldatastoreRequest {
+                key: self.gl_snapshot_key(Some(generation)),
+                generation: None,
+            };
+            with_connection_retry!(client.del_datastore(req.clone())).await?;
+        }
+        Ok(())
+    }
+
+    async fn list_snapshots(&self) -> SdkResult<Vec<u64>> {
+        let prefix_len = BREEZ_SDK_SNAPSHOTS_DATASTORE_PATH.len();
+        Ok(self
+            .list_datastore(self.gl_snapshot_key(None))
+            .await?
+            .into_iter()
+            .filter(|entry| entry.key.len() == prefix_len + 1)
+            .filter_map(|entry| entry.key[prefix_len].parse().ok())
+            .collect())
+    }
+
+    async fn pull_snapshot(&self, generation: u64) -> SdkResult<Option<BackupState>> {
+        let key = self.gl_snapshot_key(Some(generation));
+        let store = self.list_datastore(key.clone()).await?;
+        Ok(store
+            .into_iter()
+            .find(|entry| entry.key == key)
+            .and_then(|entry| entry.hex)
+            .map(|data| BackupState { generation, data }))
+    }
 }
@@ -1,5 +1,7 @@
 mod backup_transport;
 pub(crate) mod error;
 mod node_api;
+mod signer;
 pub(crate) use backup_transport::GLBackupTransport;
 pub(crate) use node_api::Greenlight;
+pub use signer::SeedSigner;
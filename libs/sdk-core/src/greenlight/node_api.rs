@@ -18,6 +18,7 @@ use gl_client::pb::cln::listinvoices_invoices::ListinvoicesInvoicesStatus;
 use gl_client::pb::cln::listinvoices_request::ListinvoicesIndex;
 use gl_client::pb::cln::listpays_pays::ListpaysPaysStatus;
 use gl_client::pb::cln::listpeerchannels_channels::ListpeerchannelsChannelsState::*;
+use gl_client::pb::cln::listpeerchannels_channels_htlcs::ListpeerchannelsChannelsHtlcsDirection;
 use gl_client::pb::cln::listsendpays_request::ListsendpaysIndex;
 use gl_client::pb::cln::{
     self, Amount, DelinvoiceRequest, GetrouteRequest, GetrouteRoute, ListchannelsRequest,
@@ -27,7 +28,7 @@ use gl_client::pb::cln::{
 use gl_client::pb::{OffChainPayment, TrampolinePayRequest};
 use gl_client::scheduler::Scheduler;
 use gl_client::signer::model::greenlight::{amount, scheduler};
-use gl_client::signer::Signer;
+use gl_client::signer::Signer as GlSigner;
 use sdk_common::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
@@ -36,38 +37,47 @@ use tokio::join;
 use tokio::sync::{mpsc, watch, Mutex};
 use tokio::time::{sleep, Instant, MissedTickBehavior};
 use tokio_stream::StreamExt;
-use tonic::Streaming;
+use tonic::{Code, Streaming};
 
 use crate::bitcoin::bech32::{u5, ToBase32};
 use crate::bitcoin::blockdata::constants::WITNESS_SCALE_FACTOR;
-use crate::bitcoin::hashes::Hash;
-use crate::bitcoin::secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
-use crate::bitcoin::secp256k1::PublicKey;
-use crate::bitcoin::secp256k1::Secp256k1;
-use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
-use crate::bitcoin::{
-    Address, OutPoint, Script, Sequence, Transaction, TxIn, TxOut, Txid, Witness,
-};
+use crate::bitcoin::consensus::encode::{deserialize, serialize};
+use crate::bitcoin::secp256k1::ecdsa::{self, RecoverableSignature, RecoveryId};
+use crate::bitcoin::secp256k1::{schnorr, Message, PublicKey};
+use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPubKey};
+use crate::bitcoin::util::psbt::PartiallySignedTransaction;
+use crate::bitcoin::{Address, OutPoint, Script, Sequence, Transaction, TxIn, TxOut, Witness};
 use crate::lightning::util::message_signing::verify;
 use crate::lightning_invoice::{RawBolt11Invoice, SignedRawBolt11Invoice};
-use crate::node_api::{CreateInvoiceRequest, FetchBolt11Result, NodeAPI, NodeError, NodeResult};
+use crate::node_api::{
+    CreateInvoiceRequest, FetchBolt11Result, NodeAPI, NodeError, NodeResult, PaymentLimits,
+    ProbeResult,
+};
 use crate::persist::cache::NodeStateStorage;
 use crate::persist::db::SqliteStorage;
 use crate::persist::send_pays::{SendPay, SendPayStatus};
-use crate::{models::*, LspInformation};
+use crate::signer::Signer;
+use crate::{models::*, ConnectStage, LspInformation};
 use crate::{NodeConfig, PrepareRedeemOnchainFundsRequest, PrepareRedeemOnchainFundsResponse};
 
-const MAX_PAYMENT_AMOUNT_MSAT: u64 = 4294967000;
 const MAX_INBOUND_LIQUIDITY_MSAT: u64 = 4000000000;
 const TRAMPOLINE_BASE_FEE_MSAT: u64 = 4000;
 const TRAMPOLINE_FEE_PPM: u64 = 5000;
 const PAYMENT_STATE_PENDING: u8 = 1;
 const PAYMENT_STATE_COMPLETE: u8 = 2;
 const PAYMENT_STATE_FAILED: u8 = 4;
+/// The label prefix of the invoices CLN creates for received keysend payments
+const KEYSEND_INVOICE_LABEL_PREFIX: &str = "keysend-";
+/// Outputs below this value are not relayed, so smaller change is left to the miners instead
+const DUST_LIMIT_SAT: u64 = 546;
+/// The estimated weight of the witness of a P2WPKH input
+const WITNESS_INPUT_WEIGHT: u64 = 110;
+/// The weight of the version, locktime, input and output counts and segwit marker of a transaction
+const TX_CORE_WEIGHT: u32 = 42;
 
 pub(crate) struct Greenlight {
     sdk_config: Config,
-    signer: Mutex<Arc<Signer>>,
+    signer: Arc<dyn Signer>,
     device: Device,
     gl_client: Mutex<Option<node::Client>>,
     node_client: Mutex<Option<ClnClient>>,
@@ -90,43 +100,49 @@ struct PaymentLabel {
 }
 
 impl Greenlight {
-    /// Connects to a live node using the provided seed and config.
-    /// If the node is not registered, it will try to recover it using the seed.
+    /// Connects to a live node using the provided signer and config.
+    /// If `node_credentials` are provided, they are used instead of the stored ones.
+    /// If the node is not registered, it will try to recover it using the seed of the signer.
     /// If the node is not created, it will register it using the provided partner credentials
     /// or invite code
     /// If the node is already registered and an existing credentials were found, it will try to
     /// connect to the node using these credentials.
+    /// The `on_progress` callback is invoked as each [ConnectStage] is reached.
     pub async fn connect(
         config: Config,
-        seed: Vec<u8>,
+        signer: Arc<dyn Signer>,
+        node_credentials: Option<Device>,
         restore_only: Option<bool>,
         persister: Arc<SqliteStorage>,
+        on_progress: &(dyn Fn(ConnectStage) + Send + Sync),
     ) -> NodeResult<Self> {
         // Derive the encryption key from the seed
-        let temp_signer = Arc::new(Signer::new(
-            seed.clone(),
-            config.network.into(),
-            Nobody::new(),
-        )?);
-        let encryption_key = Self::derive_bip32_key(
-            config.network,
-            &temp_signer,
-            vec![ChildNumber::from_hardened_idx(140)?, ChildNumber::from(0)],
-        )?
-        .to_priv()
-        .to_bytes();
+        let encryption_key = signer.derive_symmetric_key(vec![
+            ChildNumber::from_hardened_idx(140)?,
+            ChildNumber::from(0),
+        ])?;
         let encryption_key_slice = encryption_key.as_slice();
 
         let register_credentials = match config.node_config.clone() {
             NodeConfig::Greenlight { config } => config,
+            NodeConfig::Mock { .. } => {
+                return Err(NodeError::generic("Not a Greenlight node config"))
+            }
         };
 
         // Query for the existing credentials
-        let mut parsed_credentials =
-            Self::get_node_credentials(config.network, &temp_signer, persister.clone())?
-                .ok_or(NodeError::credentials("No credentials found"));
+        let mut parsed_credentials = match node_credentials {
+            Some(credentials) => Ok(credentials),
+            None => Self::get_node_credentials(&signer, persister.clone())?
+                .ok_or(NodeError::credentials("No credentials found")),
+        };
+        let seed = signer.seed();
         if parsed_credentials.is_err() {
+            let seed = seed.clone().ok_or(NodeError::credentials(
+                "No credentials found, and the node can't be recovered without the seed",
+            ))?;
             info!("No credentials found, trying to recover existing node");
+            on_progress(ConnectStage::NodeRecovery);
             parsed_credentials = match Self::recover(config.network, seed.clone()).await {
                 Ok(creds) => Ok(creds),
                 Err(_) => {
@@ -134,6 +150,7 @@ impl Greenlight {
                         false => {
                             // If we got here it means we failed to recover so we need to register a new node
                             info!("Failed to recover node, registering new one");
+                            on_progress(ConnectStage::NodeRegistration);
                             let credentials = Self::register(
                                 config.clone().network,
                                 seed.clone(),
@@ -144,7 +161,9 @@ impl Greenlight {
                             Ok(credentials)
                         }
                         true => {
-                            return Err(NodeError::RestoreOnly("Node does not exist".to_string()));
+                            return Err(NodeError::RestoreOnly(
+                                "No node exists for the provided seed".to_string(),
+                            ));
                         }
                     }
                 }
@@ -154,15 +173,27 @@ impl Greenlight {
         // Persist the connection credentials for future use and return the node instance
         match parsed_credentials {
             Ok(creds) => {
-                let temp_scheduler = Scheduler::new(config.network.into(), creds.clone()).await?;
-                debug!("upgrading credentials");
-                let creds = creds.upgrade(&temp_scheduler, &temp_signer).await?;
-                debug!("upgrading credentials succeeded");
+                on_progress(ConnectStage::SchedulerHandshake);
+                // Upgrading the credentials needs the seed, signers without it are
+                // expected to provide up to date credentials
+                let creds = match seed {
+                    Some(seed) => {
+                        let temp_signer =
+                            Arc::new(GlSigner::new(seed, config.network.into(), Nobody::new())?);
+                        let temp_scheduler =
+                            Scheduler::new(config.network.into(), creds.clone()).await?;
+                        debug!("upgrading credentials");
+                        let creds = creds.upgrade(&temp_scheduler, &temp_signer).await?;
+                        debug!("upgrading credentials succeeded");
+                        creds
+                    }
+                    None => creds,
+                };
                 let encrypted_creds = sym_encrypt(encryption_key_slice, &creds.to_bytes());
                 match encrypted_creds {
                     Some(c) => {
                         persister.set_gl_credentials(c)?;
-                        Greenlight::new(config, seed, creds.clone(), persister)
+                        Ok(Greenlight::new(config, signer, creds.clone(), persister))
                     }
                     None => Err(NodeError::generic("Failed to encrypt credentials")),
                 }
@@ -173,61 +204,37 @@ impl Greenlight {
 
     fn new(
         sdk_config: Config,
-        seed: Vec<u8>,
+        signer: Arc<dyn Signer>,
         device: Device,
         persister: Arc<SqliteStorage>,
-    ) -> NodeResult<Greenlight> {
-        let greenlight_network = sdk_config.network.into();
-        let signer = Signer::new(seed.clone(), greenlight_network, device.clone())?;
-        Ok(Greenlight {
+    ) -> Greenlight {
+        Greenlight {
             sdk_config,
-            signer: Mutex::new(Arc::new(signer)),
+            signer,
             device,
             gl_client: Mutex::new(None),
             node_client: Mutex::new(None),
             persister,
             inprogress_payments: AtomicU16::new(0),
-        })
-    }
-
-    async fn get_signer(&self) -> Arc<Signer> {
-        Arc::clone(&*self.signer.lock().await)
-    }
-
-    fn derive_bip32_key(
-        network: Network,
-        signer: &Arc<Signer>,
-        path: Vec<ChildNumber>,
-    ) -> NodeResult<ExtendedPrivKey> {
-        Ok(
-            ExtendedPrivKey::new_master(network.into(), &signer.bip32_ext_key())?
-                .derive_priv(&Secp256k1::new(), &path)?,
-        )
-    }
-
-    fn legacy_derive_bip32_key(
-        network: Network,
-        signer: &Arc<Signer>,
-        path: Vec<ChildNumber>,
-    ) -> NodeResult<ExtendedPrivKey> {
-        Ok(
-            ExtendedPrivKey::new_master(network.into(), &signer.legacy_bip32_ext_key())?
-                .derive_priv(&Secp256k1::new(), &path)?,
-        )
+        }
     }
 
+    /// Registers a new node, either with the partner credentials, which don't need an invite
+    /// code, or with an invite code
     async fn register(
         network: Network,
         seed: Vec<u8>,
         register_credentials: Option<GreenlightCredentials>,
         invite_code: Option<String>,
-    ) -> Result<Device> {
-        if invite_code.is_some() && register_credentials.is_some() {
-            return Err(anyhow!("Cannot specify both invite code and credentials"));
-        }
+    ) -> NodeResult<Device> {
         let greenlight_network = network.into();
-        let creds = match register_credentials {
-            Some(creds) => {
+        let creds = match (register_credentials, &invite_code) {
+            (Some(_), Some(_)) => {
+                return Err(NodeError::RegistrationRejected(
+                    "Cannot specify both invite code and credentials".to_string(),
+                ))
+            }
+            (Some(creds), None) => {
                 debug!("registering with credentials");
                 Nobody {
                     cert: creds.developer_cert,
@@ -235,14 +242,21 @@ impl Greenlight {
                     ..Default::default()
                 }
             }
-            None => Nobody::new(),
+            (None, Some(_)) => Nobody::new(),
+            (None, None) => {
+                return Err(NodeError::RegistrationRejected(
+                    "Registering a node requires partner credentials or an invite code".to_string(),
+                ))
+            }
         };
 
-        let signer = Signer::new(seed, greenlight_network, creds.clone())?;
+        let signer = GlSigner::new(seed, greenlight_network, creds.clone())?;
         let scheduler = Scheduler::new(greenlight_network, creds).await?;
 
-        let register_res: scheduler::RegistrationResponse =
-            scheduler.register(&signer, invite_code).await?;
+        let register_res: scheduler::RegistrationResponse = scheduler
+            .register(&signer, invite_code)
+            .await
+            .map_err(registration_error)?;
 
         Ok(Device::from_bytes(register_res.creds))
     }
@@ -250,7 +264,7 @@ impl Greenlight {
     async fn recover(network: Network, seed: Vec<u8>) -> Result<Device> {
         let greenlight_network = network.into();
         let credentials = Nobody::new();
-        let signer = Signer::new(seed, greenlight_network, credentials.clone())?;
+        let signer = GlSigner::new(seed, greenlight_network, credentials.clone())?;
         let scheduler = Scheduler::new(greenlight_network, credentials).await?;
         let recover_res: scheduler::RecoveryResponse = scheduler.recover(&signer).await?;
 
@@ -280,27 +294,20 @@ impl Greenlight {
     }
 
     fn get_node_credentials(
-        network: Network,
-        signer: &Arc<Signer>,
+        signer: &Arc<dyn Signer>,
         persister: Arc<SqliteStorage>,
     ) -> NodeResult<Option<Device>> {
         // Derive the encryption key from the seed
-        let encryption_key = Self::derive_bip32_key(
-            network,
-            signer,
-            vec![ChildNumber::from_hardened_idx(140)?, ChildNumber::from(0)],
-        )?
-        .to_priv()
-        .to_bytes();
+        let encryption_key = signer.derive_symmetric_key(vec![
+            ChildNumber::from_hardened_idx(140)?,
+            ChildNumber::from(0),
+        ])?;
         let encryption_key_slice = encryption_key.as_slice();
 
-        let legacy_encryption_key = Self::legacy_derive_bip32_key(
-            network,
-            signer,
-            vec![ChildNumber::from_hardened_idx(140)?, ChildNumber::from(0)],
-        )?
-        .to_priv()
-        .to_bytes();
+        let legacy_encryption_key = signer.legacy_derive_symmetric_key(vec![
+            ChildNumber::from_hardened_idx(140)?,
+            ChildNumber::from(0),
+        ])?;
         let legacy_encryption_key_slice = legacy_encryption_key.as_slice();
 
         match persister.get_gl_credentials()? {
@@ -1022,16 +1029,15 @@ struct SyncState {
 #[tonic::async_trait]
 impl NodeAPI for Greenlight {
     async fn node_credentials(&self) -> NodeResult<Option<NodeCredentials>> {
-        Ok(Self::get_node_credentials(
-            self.sdk_config.network,
-            &self.get_signer().await,
-            self.persister.clone(),
-        )?
-        .map(|credentials| NodeCredentials::Greenlight {
-            credentials: GreenlightDeviceCredentials {
-                device: credentials.to_bytes(),
-            },
-        }))
+        Ok(
+            Self::get_node_credentials(&self.signer, self.persister.clone())?.map(|credentials| {
+                NodeCredentials::Greenlight {
+                    credentials: GreenlightDeviceCredentials {
+                        device: credentials.to_bytes(),
+                    },
+                }
+            }),
+        )
     }
 
     async fn configure_node(&self, close_to_address: Option<String>) -> NodeResult<()> {
@@ -1076,7 +1082,7 @@ impl NodeAPI for Greenlight {
             preimage: request.preimage,
             deschashonly: request.use_description_hash,
             expiry: request.expiry.map(|e| e as u64),
-            fallbacks: vec![],
+            fallbacks: request.fallback_address.into_iter().collect(),
             cltv: request.cltv,
         };
 
@@ -1247,6 +1253,9 @@ impl NodeAPI for Greenlight {
             connected_peers,
             max_receivable_single_payment_amount_msat: max_receivable_single_channel,
             total_inbound_liquidity_msats,
+            // Set from the channels by the SDK on sync
+            pending_channel_opens_msat: 0,
+            pending_channel_opens: vec![],
         };
         let mut htlc_list: Vec<Htlc> = Vec::new();
         for channel in all_channel_models.clone() {
@@ -1377,11 +1386,119 @@ impl NodeAPI for Greenlight {
         })
     }
 
+    async fn probe_payment(
+        &self,
+        node_id: Vec<u8>,
+        amount_msat: u64,
+        route_hint: Option<RouteHintHop>,
+        final_cltv_delta: u64,
+        timeout_sec: u32,
+    ) -> NodeResult<ProbeResult> {
+        let mut client = self.get_node_client().await?;
+
+        // With a route hint, route to its source node what it forwards to the destination
+        let (route_to, route_amount_msat, route_cltv) = match &route_hint {
+            Some(hint) => (
+                hex::decode(&hint.src_node_id)?,
+                amount_msat
+                    + hint.fees_base_msat as u64
+                    + amount_msat * hint.fees_proportional_millionths as u64 / 1_000_000,
+                final_cltv_delta + hint.cltv_expiry_delta,
+            ),
+            None => (node_id.clone(), amount_msat, final_cltv_delta),
+        };
+        let req = GetrouteRequest {
+            id: route_to,
+            amount_msat: Some(Amount {
+                msat: route_amount_msat,
+            }),
+            riskfactor: 0,
+            cltv: Some(route_cltv as u32),
+            fromid: None,
+            fuzzpercent: Some(0),
+            exclude: vec![],
+            maxhops: None,
+        };
+        let mut route: Vec<SendpayRoute> = with_connection_retry!(client.get_route(req.clone()))
+            .await
+            .map_err(|e| NodeError::RouteNotFound(e.message().to_string()))?
+            .into_inner()
+            .route
+            .into_iter()
+            .map(|hop| SendpayRoute {
+                amount_msat: hop.amount_msat,
+                id: hop.id,
+                delay: hop.delay,
+                channel: hop.channel,
+            })
+            .collect();
+        if let Some(hint) = route_hint {
+            route.push(SendpayRoute {
+                amount_msat: Some(Amount { msat: amount_msat }),
+                id: node_id,
+                delay: final_cltv_delta as u32,
+                channel: hint.short_channel_id,
+            });
+        }
+        let sent_msat = route
+            .first()
+            .and_then(|hop| hop.amount_msat.as_ref())
+            .map_or(amount_msat, |a| a.msat);
+        let result = ProbeResult {
+            fee_msat: sent_msat.saturating_sub(amount_msat),
+            hops: route.len() as u32,
+            reachable: false,
+            failure: None,
+        };
+
+        let payment_hash = rand::random::<[u8; 32]>().to_vec();
+        let req = SendpayRequest {
+            route,
+            payment_hash: payment_hash.clone(),
+            label: None,
+            amount_msat: Some(Amount { msat: amount_msat }),
+            bolt11: None,
+            payment_secret: None,
+            partid: None,
+            localinvreqid: None,
+            groupid: None,
+        };
+        if let Err(e) = with_connection_retry!(client.send_pay(req.clone())).await {
+            return Ok(ProbeResult {
+                failure: Some(e.message().to_string()),
+                ..result
+            });
+        }
+        let req = WaitsendpayRequest {
+            payment_hash,
+            partid: None,
+            timeout: Some(timeout_sec),
+            groupid: None,
+        };
+        let failure = match self
+            .with_keep_alive(with_connection_retry!(client.wait_send_pay(req.clone())))
+            .await
+        {
+            Ok(_) => return Err(NodeError::generic("The probe was unexpectedly settled")),
+            Err(e) => e.message().to_string(),
+        };
+        // The destination doesn't know the random payment hash, so failing it as an unknown
+        // payment means the probe reached it
+        let reachable = failure.contains("WIRE_INCORRECT_OR_UNKNOWN_PAYMENT_DETAILS");
+        Ok(ProbeResult {
+            reachable,
+            failure: (!reachable).then_some(failure),
+            ..result
+        })
+    }
+
     async fn send_payment(
         &self,
         bolt11: String,
         amount_msat: Option<u64>,
         label: Option<String>,
+        exclude: Vec<String>,
+        limits: PaymentLimits,
     ) -> NodeResult<Payment> {
         let mut description = None;
         if !bolt11.is_empty() {
@@ -1394,17 +1511,17 @@ impl NodeAPI for Greenlight {
         let request = cln::PayRequest {
             bolt11,
             amount_msat: amount_msat.map(|amt| cln::Amount { msat: amt }),
-            maxfeepercent: Some(self.sdk_config.maxfee_percent),
-            retry_for: Some(self.sdk_config.payment_timeout_sec),
+            maxfeepercent: Some(limits.maxfee_percent),
+            retry_for: Some(limits.timeout_sec),
             label,
             maxdelay: None,
             riskfactor: None,
             localinvreqid: None,
-            exclude: vec![],
+            exclude,
             maxfee: None,
             description,
             exemptfee: Some(cln::Amount {
-                msat: self.sdk_config.exemptfee_msat,
+                msat: limits.exemptfee_msat,
             }),
         };
         let result: cln::PayResponse = self
@@ -1424,6 +1541,7 @@ impl NodeAPI for Greenlight {
         amount_msat: u64,
         label: Option<String>,
         trampoline_node_id: Vec<u8>,
+        limits: PaymentLimits,
     ) -> NodeResult<Payment> {
         let invoice = parse_invoice(&bolt11)?;
         validate_network(invoice.clone(), self.sdk_config.network)?;
@@ -1437,6 +1555,11 @@ impl NodeAPI for Greenlight {
             (amount_msat.saturating_mul(TRAMPOLINE_FEE_PPM) / 1_000_000) + TRAMPOLINE_BASE_FEE_MSAT;
         let fee_percent = ((fee_msat as f64 / amount_msat as f64) * 100.) as f32;
         debug!("using fee msat {} fee percent {}", fee_msat, fee_percent);
+        if !limits.allows_fee(fee_msat, amount_msat) {
+            return Err(NodeError::RouteTooExpensive(format!(
+                "The trampoline fee of {fee_msat} msat exceeds the max fee"
+            )));
+        }
         let mut client = self.get_client().await?;
         let request = TrampolinePayRequest {
             bolt11,
@@ -1508,14 +1631,66 @@ impl NodeAPI for Greenlight {
         payment.try_into()
     }
 
+    async fn pay_offer(
+        &self,
+        offer: String,
+        amount_msat: Option<u64>,
+        payer_note: Option<String>,
+        label: Option<String>,
+    ) -> NodeResult<Payment> {
+        let mut client = self.get_node_client().await?;
+        let fetch_request = cln::FetchinvoiceRequest {
+            offer,
+            amount_msat: amount_msat.map(|msat| cln::Amount { msat }),
+            payer_note,
+            timeout: Some(self.sdk_config.payment_timeout_sec as f64),
+            ..Default::default()
+        };
+        let invoice = self
+            .with_keep_alive(with_connection_retry!(
+                client.fetch_invoice(fetch_request.clone())
+            ))
+            .await?
+            .into_inner()
+            .invoice;
+
+        // The pay command also accepts BOLT12 invoices in the bolt11 field
+        let request = cln::PayRequest {
+            bolt11: invoice,
+            amount_msat: None,
+            maxfeepercent: Some(self.sdk_config.maxfee_percent),
+            retry_for: Some(self.sdk_config.payment_timeout_sec),
+            label,
+            maxdelay: None,
+            riskfactor: None,
+            localinvreqid: None,
+            exclude: vec![],
+            maxfee: None,
+            description: None,
+            exemptfee: Some(cln::Amount {
+                msat: self.sdk_config.exemptfee_msat,
+            }),
+        };
+        let result: cln::PayResponse = self
+            .with_keep_alive(with_connection_retry!(client.pay(request.clone())))
+            .await?
+            .into_inner();
+
+        // Before returning we need to make sure the payment is persisted in the backend node.
+        // We do so by polling for the payment.
+        let payment = Self::fetch_outgoing_payment_with_retry(client, result.payment_hash).await?;
+        payment.try_into()
+    }
+
     async fn node_id(&self) -> NodeResult<String> {
-        Ok(hex::encode(self.get_signer().await.node_id()))
+        Ok(hex::encode(self.signer.node_id()?))
     }
 
     async fn redeem_onchain_funds(
         &self,
         to_address: String,
         sat_per_vbyte: u32,
+        amount_sat: Option<u64>,
     ) -> NodeResult<Vec<u8>> {
         let mut client = self.get_node_client().await?;
 
@@ -1523,9 +1698,7 @@ impl NodeAPI for Greenlight {
             feerate: Some(cln::Feerate {
                 style: Some(cln::feerate::Style::Perkw(sat_per_vbyte * 250)),
             }),
-            satoshi: Some(cln::AmountOrAll {
-                value: Some(cln::amount_or_all::Value::All(true)),
-            }),
+            satoshi: Some(withdraw_amount(amount_sat)),
             destination: to_address,
             minconf: None,
             utxos: vec![],
@@ -1543,56 +1716,117 @@ impl NodeAPI for Greenlight {
     ) -> NodeResult<PrepareRedeemOnchainFundsResponse> {
         let funds = self.list_funds().await?;
         let utxos = self.utxos(funds).await?;
-
-        let mut amount_msat: u64 = 0;
-        let txins: Vec<TxIn> = utxos
+        let utxo_amounts_sat: Vec<u64> = utxos
             .iter()
-            .map(|utxo| {
-                amount_msat += utxo.amount_millisatoshi;
-                TxIn {
-                    previous_output: OutPoint {
-                        txid: Txid::from_slice(&utxo.txid).unwrap(),
-                        vout: 0,
-                    },
-                    script_sig: Script::new(),
-                    sequence: Sequence(0),
-                    witness: Witness::default(),
-                }
-            })
+            .map(|utxo| utxo.amount_millisatoshi / 1_000)
             .collect();
-
-        let amount_sat = amount_msat / 1_000;
         let btc_address = Address::from_str(&req.to_address)?;
-        let tx_out: Vec<TxOut> = vec![TxOut {
-            value: amount_sat,
-            script_pubkey: btc_address.payload.script_pubkey(),
-        }];
-        let tx = Transaction {
-            version: 2,
-            lock_time: crate::bitcoin::PackedLockTime(0),
-            input: txins.clone(),
-            output: tx_out,
+
+        estimate_redeem_tx(
+            utxo_amounts_sat,
+            btc_address.payload.script_pubkey(),
+            req.amount_sat.map(Sat::sat),
+            req.sat_per_vbyte,
+        )
+    }
+
+    async fn bump_redeem_onchain_funds(
+        &self,
+        txid: Vec<u8>,
+        to_address: String,
+        sat_per_vbyte: u32,
+        amount_sat: Option<u64>,
+    ) -> NodeResult<Vec<u8>> {
+        let mut client = self.get_node_client().await?;
+        let tx = with_connection_retry!(client.list_transactions(cln::ListtransactionsRequest {}))
+            .await?
+            .into_inner()
+            .transactions
+            .into_iter()
+            .find(|tx| tx.hash == txid)
+            .ok_or(NodeError::generic("Transaction not found"))?;
+        if tx.blockheight != 0 {
+            return Err(NodeError::generic("The transaction is already confirmed"));
+        }
+
+        // The replacement spends the same inputs, which are still reserved by the original
+        let script_pubkey = Address::from_str(&to_address)?.script_pubkey();
+        let output_weight = (8 + 1 + script_pubkey.len() as u32) * WITNESS_SCALE_FACTOR as u32;
+        let request = cln::UtxopsbtRequest {
+            satoshi: Some(withdraw_amount(amount_sat)),
+            feerate: Some(cln::Feerate {
+                style: Some(cln::feerate::Style::Perkw(sat_per_vbyte * 250)),
+            }),
+            startweight: TX_CORE_WEIGHT + output_weight,
+            utxos: tx
+                .inputs
+                .iter()
+                .map(|input| cln::Outpoint {
+                    txid: input.txid.clone(),
+                    outnum: input.index,
+                })
+                .collect(),
+            reservedok: Some(true),
+            excess_as_change: Some(amount_sat.is_some()),
+            ..Default::default()
         };
+        let funded = with_connection_retry!(client.utxo_psbt(request.clone()))
+            .await?
+            .into_inner();
 
-        let witness_input_size: u64 = 110;
-        let tx_weight = tx.strippedsize() as u64 * WITNESS_SCALE_FACTOR as u64
-            + witness_input_size * txins.len() as u64;
-        let fee: u64 = tx_weight * req.sat_per_vbyte as u64 / WITNESS_SCALE_FACTOR as u64;
-        if fee >= amount_sat {
+        // The funded PSBT only has the inputs and the change, if any
+        let psbt_bytes = base64::decode(&funded.psbt)
+            .map_err(|e| NodeError::Generic(format!("Invalid PSBT: {e}")))?;
+        let mut psbt: PartiallySignedTransaction = deserialize(&psbt_bytes)
+            .map_err(|e| NodeError::Generic(format!("Invalid PSBT: {e}")))?;
+        let value = match amount_sat {
+            Some(amount_sat) => amount_sat,
+            None => funded.excess_msat.unwrap_or_default().msat / 1_000,
+        };
+        if value < DUST_LIMIT_SAT {
             return Err(NodeError::InsufficientFunds(
                 "Insufficient funds to pay fees".to_string(),
             ));
         }
-
-        return Ok(PrepareRedeemOnchainFundsResponse {
-            tx_weight,
-            tx_fee_sat: fee,
+        psbt.unsigned_tx.output.push(TxOut {
+            value,
+            script_pubkey,
         });
+        psbt.outputs.push(Default::default());
+
+        let request = cln::SignpsbtRequest {
+            psbt: base64::encode(serialize(&psbt)),
+            signonly: vec![],
+        };
+        let signed_psbt = with_connection_retry!(client.sign_psbt(request.clone()))
+            .await?
+            .into_inner()
+            .signed_psbt;
+        let request = cln::SendpsbtRequest {
+            psbt: signed_psbt,
+            reserve: None,
+        };
+        Ok(with_connection_retry!(client.send_psbt(request.clone()))
+            .await?
+            .into_inner()
+            .txid)
+    }
+
+    async fn new_onchain_address(&self) -> NodeResult<String> {
+        let mut client = self.get_node_client().await?;
+        let request = cln::NewaddrRequest {
+            addresstype: Some(cln::newaddr_request::NewaddrAddresstype::Bech32.into()),
+        };
+        with_connection_retry!(client.new_addr(request.clone()))
+            .await?
+            .into_inner()
+            .bech32
+            .ok_or(NodeError::generic("The node returned no address"))
     }
 
     /// Starts the signer that listens in a loop until the shutdown signal is received
     async fn start_signer(&self, shutdown: mpsc::Receiver<()>) {
-        match self.get_signer().await.run_forever(shutdown).await {
+        match self.signer.run(self.device.to_bytes(), shutdown).await {
             Ok(_) => info!("signer exited gracefully"),
             Err(e) => error!("signer exited with error: {e}"),
         }
@@ -1646,11 +1880,18 @@ impl NodeAPI for Greenlight {
         Ok(())
     }
 
+    async fn ping_peer(&self, node_id: String) -> NodeResult<()> {
+        let mut client = self.get_node_client().await?;
+        let ping_req = cln::PingRequest {
+            id: hex::decode(node_id)?,
+            ..Default::default()
+        };
+        client.ping(ping_req).await?;
+        Ok(())
+    }
+
     async fn sign_message(&self, message: &str) -> NodeResult<String> {
-        let (sig, recovery_id) = self
-            .get_signer()
-            .await
-            .sign_message(message.as_bytes().to_vec())?;
+        let (sig, recovery_id) = self.signer.sign_message(message.as_bytes().to_vec())?;
         let mut complete_signature = vec![31 + recovery_id];
         complete_signature.extend_from_slice(&sig);
         Ok(zbase32::encode_full_bytes(&complete_signature))
@@ -1686,7 +1927,7 @@ impl NodeAPI for Greenlight {
         buf.append(&mut hrp_len_bytes);
         buf.append(&mut hrp_buf);
         // Sign the invoice using the signer
-        let raw_result = self.get_signer().await.sign_invoice(buf)?;
+        let raw_result = self.signer.sign_invoice(buf)?;
         info!(
             "recover id: {:?} raw = {:?}",
             raw_result, raw_result[64] as i32
@@ -1752,6 +1993,62 @@ impl NodeAPI for Greenlight {
         Ok(tx_ids)
     }
 
+    async fn list_channels(&self) -> NodeResult<Vec<ChannelDetails>> {
+        let mut client = self.get_node_client().await?;
+        let req = cln::ListpeerchannelsRequest::default();
+        let peer_channels = with_connection_retry!(client.list_peer_channels(req.clone()))
+            .await?
+            .into_inner();
+        Ok(peer_channels
+            .channels
+            .into_iter()
+            .map(ChannelDetails::from)
+            .collect())
+    }
+
+    async fn close_channel(&self, channel_id: String, force: bool) -> NodeResult<Option<String>> {
+        let mut client = self.get_node_client().await?;
+        let req = cln::CloseRequest {
+            id: channel_id,
+            unilateraltimeout: close_unilateral_timeout(force),
+            destination: None,
+            fee_negotiation_step: None,
+            wrong_funding: None,
+            force_lease_closed: None,
+            feerange: vec![],
+        };
+        let res = with_connection_retry!(client.close(req.clone()))
+            .await?
+            .into_inner();
+        Ok(res.txid.map(hex::encode))
+    }
+
+    async fn open_channel(
+        &self,
+        node_id: String,
+        amount_sat: u64,
+        announce: bool,
+        sat_per_vbyte: Option<u32>,
+    ) -> NodeResult<OpenChannelResponse> {
+        let mut client = self.get_node_client().await?;
+        let req = cln::FundchannelRequest {
+            id: hex::decode(node_id)?,
+            amount: Some(withdraw_amount(Some(amount_sat))),
+            feerate: sat_per_vbyte.map(|sat_per_vbyte| cln::Feerate {
+                style: Some(cln::feerate::Style::Perkw(sat_per_vbyte * 250)),
+            }),
+            announce: Some(announce),
+            ..Default::default()
+        };
+        let res = with_connection_retry!(client.fund_channel(req.clone()))
+            .await?
+            .into_inner();
+        Ok(OpenChannelResponse {
+            channel_id: hex::encode(res.channel_id),
+            funding_txid: hex::encode(res.txid),
+        })
+    }
+
     async fn stream_incoming_payments(
         &self,
     ) -> NodeResult<Streaming<gl_client::signer::model::greenlight::IncomingPayment>> {
@@ -1914,12 +2211,40 @@ impl NodeAPI for Greenlight {
         Ok(max_channel_amounts)
     }
 
-    async fn derive_bip32_key(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPrivKey> {
-        Self::derive_bip32_key(self.sdk_config.network, &self.get_signer().await, path)
+    async fn derive_xpub(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPubKey> {
+        Ok(self.signer.derive_xpub(path)?)
+    }
+
+    async fn sign_ecdsa(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<ecdsa::Signature> {
+        Ok(self.signer.sign_ecdsa(path, message)?)
+    }
+
+    async fn sign_taproot(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<schnorr::Signature> {
+        Ok(self.signer.sign_taproot(path, message)?)
     }
 
-    async fn legacy_derive_bip32_key(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPrivKey> {
-        Self::legacy_derive_bip32_key(self.sdk_config.network, &self.get_signer().await, path)
+    async fn ecdh(&self, path: Vec<ChildNumber>, public_key: PublicKey) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.ecdh(path, public_key)?)
+    }
+
+    async fn hmac_sha256(&self, path: Vec<ChildNumber>, input: Vec<u8>) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.hmac_sha256(path, input)?)
+    }
+
+    async fn derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.derive_symmetric_key(path)?)
+    }
+
+    async fn legacy_derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.legacy_derive_symmetric_key(path)?)
     }
 
     async fn stream_custom_messages(
@@ -2058,7 +2383,12 @@ impl NodeAPI for Greenlight {
                                         .unwrap_or_default()
                                         .msat,
                                 ),
-                                htlc_maximum_msat: None,
+                                // Not encoded in the invoice, but used to prefer the hints
+                                // with the highest capacity
+                                htlc_maximum_msat: peer_channel
+                                    .receivable_msat
+                                    .clone()
+                                    .map(|a| a.msat),
                             }],
                         };
                         info!("Generating hint hop as routing hint: {:?}", hint);
@@ -2258,6 +2588,9 @@ impl TryFrom<SendPayAgg> for Payment {
                     keysend: value.bolt11.is_none(),
                     bolt11: value.bolt11.unwrap_or_default(),
                     open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
                     lnurl_success_action: None,
                     lnurl_pay_domain: None,
                     lnurl_pay_comment: None,
@@ -2270,6 +2603,8 @@ impl TryFrom<SendPayAgg> for Payment {
                 },
             },
             metadata: None,
+            fiat_rate: None,
+            imported: false,
         })
     }
 }
@@ -2292,10 +2627,10 @@ impl TryFrom<OffChainPayment> for Payment {
             details: PaymentDetails::Ln {
                 data: LnPaymentDetails {
                     payment_hash: hex::encode(p.payment_hash),
+                    keysend: p.label.starts_with(KEYSEND_INVOICE_LABEL_PREFIX),
                     label: p.label,
                     destination_pubkey: ln_invoice.payee_pubkey,
                     payment_preimage: hex::encode(p.preimage),
-                    keysend: false,
                     bolt11: p.bolt11,
                     lnurl_success_action: None, // For received payments, this is None
                     lnurl_pay_domain: None,     // For received payments, this is None
@@ -2307,9 +2642,14 @@ impl TryFrom<OffChainPayment> for Payment {
                     reverse_swap_info: None,
                     pending_expiration_block: None,
                     open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
                 },
             },
             metadata: None,
+            fiat_rate: None,
+            imported: false,
         })
     }
 }
@@ -2340,13 +2680,13 @@ impl TryFrom<cln::ListinvoicesInvoices> for Payment {
             details: PaymentDetails::Ln {
                 data: LnPaymentDetails {
                     payment_hash: hex::encode(invoice.payment_hash),
+                    keysend: invoice.label.starts_with(KEYSEND_INVOICE_LABEL_PREFIX),
                     label: invoice.label,
                     destination_pubkey: ln_invoice.payee_pubkey,
                     payment_preimage: invoice
                         .payment_preimage
                         .map(hex::encode)
                         .unwrap_or_default(),
-                    keysend: false,
                     bolt11: invoice.bolt11.unwrap_or_default(),
                     lnurl_success_action: None, // For received payments, this is None
                     lnurl_pay_domain: None,     // For received payments, this is None
@@ -2358,9 +2698,14 @@ impl TryFrom<cln::ListinvoicesInvoices> for Payment {
                     reverse_swap_info: None,
                     pending_expiration_block: None,
                     open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
                 },
             },
             metadata: None,
+            fiat_rate: None,
+            imported: false,
         })
     }
 }
@@ -2447,9 +2792,14 @@ impl TryFrom<cln::ListpaysPays> for Payment {
                     reverse_swap_info: None,
                     pending_expiration_block: None,
                     open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
                 },
             },
             metadata: None,
+            fiat_rate: None,
+            imported: false,
         })
     }
 }
@@ -2488,10 +2838,138 @@ impl TryFrom<cln::KeysendResponse> for PaymentResponse {
     }
 }
 
+/// The amount of a withdrawal, all the on-chain funds if `amount_sat` is not set
+/// Tells the registrations refused by the scheduler apart, so an exhausted quota of the partner
+/// credentials can be reported as such
+fn registration_error(err: anyhow::Error) -> NodeError {
+    let Some(status) = err.downcast_ref::<tonic::Status>() else {
+        return NodeError::Generic(err.to_string());
+    };
+    let message = status.message().to_string();
+    match status.code() {
+        Code::ResourceExhausted => NodeError::RegistrationQuotaExceeded(message),
+        _ if message.to_lowercase().contains("quota") => {
+            NodeError::RegistrationQuotaExceeded(message)
+        }
+        Code::InvalidArgument | Code::PermissionDenied | Code::Unauthenticated => {
+            NodeError::RegistrationRejected(message)
+        }
+        Code::Unavailable | Code::DeadlineExceeded => NodeError::ServiceConnectivity(message),
+        _ => NodeError::Generic(err.to_string()),
+    }
+}
+
+fn withdraw_amount(amount_sat: Option<u64>) -> cln::AmountOrAll {
+    let value = match amount_sat {
+        Some(amount_sat) => cln::amount_or_all::Value::Amount(Amount {
+            msat: amount_sat * 1_000,
+        }),
+        None => cln::amount_or_all::Value::All(true),
+    };
+    cln::AmountOrAll { value: Some(value) }
+}
+
+/// Estimates the weight and the fee of a transaction spending the given UTXOs to `script_pubkey`.
+///
+/// If `amount_sat` is set, the largest UTXOs are selected until they cover the amount and the
+/// fee, adding a change output unless it would be dust. Otherwise all the UTXOs are spent.
+fn estimate_redeem_tx(
+    mut utxo_amounts_sat: Vec<u64>,
+    script_pubkey: Script,
+    amount_sat: Option<u64>,
+    sat_per_vbyte: u32,
+) -> NodeResult<PrepareRedeemOnchainFundsResponse> {
+    let estimate = |inputs: usize, outputs: Vec<TxOut>| {
+        let tx = Transaction {
+            version: 2,
+            lock_time: crate::bitcoin::PackedLockTime(0),
+            input: vec![
+                TxIn {
+                    previous_output: OutPoint::null(),
+                    script_sig: Script::new(),
+                    sequence: Sequence(0),
+                    witness: Witness::default(),
+                };
+                inputs
+            ],
+            output: outputs,
+        };
+        let tx_weight = tx.strippedsize() as u64 * WITNESS_SCALE_FACTOR as u64
+            + WITNESS_INPUT_WEIGHT * inputs as u64;
+        let fee = tx_weight * sat_per_vbyte as u64 / WITNESS_SCALE_FACTOR as u64;
+        (tx_weight, fee)
+    };
+    let output = |value: u64| TxOut {
+        value,
+        script_pubkey: script_pubkey.clone(),
+    };
+
+    let amount_sat = match amount_sat {
+        Some(amount_sat) => amount_sat,
+        None => {
+            let total_sat: u64 = utxo_amounts_sat.iter().sum();
+            let (tx_weight, fee) = estimate(utxo_amounts_sat.len(), vec![output(total_sat)]);
+            if fee >= total_sat {
+                return Err(NodeError::InsufficientFunds(
+                    "Insufficient funds to pay fees".to_string(),
+                ));
+            }
+            return Ok(PrepareRedeemOnchainFundsResponse {
+                tx_weight,
+                tx_fee_sat: Sat(fee),
+                amount_sat: Sat(total_sat - fee),
+                change_sat: Sat::ZERO,
+            });
+        }
+    };
+    if amount_sat < DUST_LIMIT_SAT {
+        return Err(NodeError::Generic(format!(
+            "The amount must be at least {DUST_LIMIT_SAT} sats"
+        )));
+    }
+
+    // The change output is P2WPKH, and only its size matters here
+    let change_output = TxOut {
+        value: 0,
+        script_pubkey: Script::from(vec![0; 22]),
+    };
+    utxo_amounts_sat.sort_by_key(|amount| Reverse(*amount));
+    let mut selected_sat = 0;
+    for (i, utxo_amount_sat) in utxo_amounts_sat.iter().enumerate() {
+        selected_sat += utxo_amount_sat;
+        let inputs = i + 1;
+
+        let (tx_weight, fee) = estimate(inputs, vec![output(amount_sat), change_output.clone()]);
+        if selected_sat >= amount_sat + fee + DUST_LIMIT_SAT {
+            return Ok(PrepareRedeemOnchainFundsResponse {
+                tx_weight,
+                tx_fee_sat: Sat(fee),
+                amount_sat: Sat(amount_sat),
+                change_sat: Sat(selected_sat - amount_sat - fee),
+            });
+        }
+
+        // Without change, what is left over the amount goes to the fee
+        let (tx_weight, fee) = estimate(inputs, vec![output(amount_sat)]);
+        if selected_sat >= amount_sat + fee {
+            return Ok(PrepareRedeemOnchainFundsResponse {
+                tx_weight,
+                tx_fee_sat: Sat(selected_sat - amount_sat),
+                amount_sat: Sat(amount_sat),
+                change_sat: Sat::ZERO,
+            });
+        }
+    }
+
+    Err(NodeError::InsufficientFunds(
+        "Insufficient funds to pay the amount and the fees".to_string(),
+    ))
+}
+
 fn amount_to_msat(amount: &gl_client::pb::greenlight::Amount) -> u64 {
     match amount.unit {
         Some(amount::Unit::Millisatoshi(val)) => val,
-        Some(amount::Unit::Satoshi(val)) => val * 1000,
+        Some(amount::Unit::Satoshi(val)) => Sat(val).to_msat().msat(),
         Some(amount::Unit::Bitcoin(val)) => val * 100000000,
         Some(_) => 0,
         None => 0,
@@ -2535,6 +3013,47 @@ impl From<cln::ListpeerchannelsChannels> for Channel {
     }
 }
 
+impl From<cln::ListpeerchannelsChannels> for ChannelDetails {
+    fn from(c: cln::ListpeerchannelsChannels) -> Self {
+        let capacity_msat = c.total_msat.clone().unwrap_or_default().msat;
+        let peer_connected = c.peer_connected();
+        let channel = Channel::from(c.clone());
+        let (mut htlcs_out_msat, mut htlcs_in_msat, mut dust_htlcs_msat) = (0, 0, 0);
+        for htlc in &c.htlcs {
+            let amount_msat = htlc
+                .amount_msat
+                .as_ref()
+                .map(|a| a.msat)
+                .unwrap_or_default();
+            if htlc.direction == Some(ListpeerchannelsChannelsHtlcsDirection::Out as i32) {
+                htlcs_out_msat += amount_msat;
+            } else {
+                htlcs_in_msat += amount_msat;
+            }
+            if htlc.local_trimmed == Some(true) {
+                dust_htlcs_msat += amount_msat;
+            }
+        }
+        ChannelDetails {
+            channel_id: c.channel_id.map(hex::encode).unwrap_or_default(),
+            peer_id: c.peer_id.map(hex::encode).unwrap_or_default(),
+            peer_connected,
+            short_channel_id: channel.short_channel_id,
+            funding_txid: channel.funding_txid,
+            state: channel.state,
+            capacity_msat,
+            local_balance_msat: channel.local_balance_msat,
+            remote_balance_msat: capacity_msat.saturating_sub(channel.local_balance_msat),
+            spendable_msat: channel.spendable_msat,
+            receivable_msat: channel.receivable_msat,
+            reserve_msat: c.our_reserve_msat.map(|a| a.msat).unwrap_or_default(),
+            htlcs_out_msat,
+            htlcs_in_msat,
+            dust_htlcs_msat,
+        }
+    }
+}
+
 fn convert_to_send_pay_route(
     route: PaymentPath,
     to_pay_msat: u64,
@@ -2610,6 +3129,13 @@ impl TryFrom<ListclosedchannelsClosedchannels> for Channel {
     }
 }
 
+/// The `unilateraltimeout` of a [cln::CloseRequest], in seconds. A mutual close keeps the CLN
+/// default of 48 hours before closing unilaterally, while a forced one only waits a second for
+/// the peer. A timeout of 0 would wait for the peer forever.
+fn close_unilateral_timeout(force: bool) -> Option<u32> {
+    force.then_some(1)
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
@@ -2619,8 +3145,18 @@ mod tests {
     use gl_client::pb::cln::Amount;
     use gl_client::pb::{self, cln};
 
-    use crate::greenlight::node_api::convert_to_send_pay_route;
-    use crate::{models, PaymentPath, PaymentPathEdge};
+    use crate::bitcoin::Script;
+    use crate::greenlight::node_api::{
+        close_unilateral_timeout, convert_to_send_pay_route, estimate_redeem_tx, registration_error,
+    };
+    use crate::node_api::NodeError;
+    use crate::{models, PaymentPath, PaymentPathEdge, Sat};
+
+    #[test]
+    fn test_close_unilateral_timeout() {
+        assert_eq!(close_unilateral_timeout(false), None);
+        assert_eq!(close_unilateral_timeout(true), Some(1));
+    }
 
     #[test]
     fn test_convert_route() -> Result<()> {
@@ -2816,4 +3352,71 @@ mod tests {
             last_stable_connection: None,
         }
     }
+
+    #[test]
+    fn test_estimate_redeem_tx() -> Result<()> {
+        let script = Script::from(vec![0; 22]);
+        let utxos = vec![10_000, 50_000];
+
+        // The largest UTXO is enough, with change
+        let res = estimate_redeem_tx(utxos.clone(), script.clone(), Some(20_000), 2)?;
+        assert_eq!(res.tx_weight, 562);
+        assert_eq!(res.tx_fee_sat, Sat(281));
+        assert_eq!(res.amount_sat, Sat(20_000));
+        assert_eq!(res.change_sat, Sat(29_719));
+
+        // The change would be dust, so it goes to the fee
+        let res = estimate_redeem_tx(utxos.clone(), script.clone(), Some(49_500), 2)?;
+        assert_eq!(res.tx_weight, 438);
+        assert_eq!(res.tx_fee_sat, Sat(500));
+        assert_eq!(res.change_sat, Sat(0));
+
+        // Both UTXOs are needed
+        let res = estimate_redeem_tx(utxos.clone(), script.clone(), Some(59_000), 2)?;
+        assert_eq!(res.tx_weight, 836);
+        assert_eq!(res.tx_fee_sat, Sat(418));
+        assert_eq!(res.change_sat, Sat(582));
+
+        // Without amount, everything is sent
+        let res = estimate_redeem_tx(utxos.clone(), script.clone(), None, 2)?;
+        assert_eq!(res.tx_weight, 712);
+        assert_eq!(res.tx_fee_sat, Sat(356));
+        assert_eq!(res.amount_sat, Sat(59_644));
+        assert_eq!(res.change_sat, Sat(0));
+
+        assert!(matches!(
+            estimate_redeem_tx(utxos.clone(), script.clone(), Some(60_000), 2),
+            Err(NodeError::InsufficientFunds(_))
+        ));
+        assert!(matches!(
+            estimate_redeem_tx(utxos, script, Some(100), 2),
+            Err(NodeError::Generic(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_registration_error() {
+        let error = |code, message| registration_error(tonic::Status::new(code, message).into());
+        assert!(matches!(
+            error(tonic::Code::ResourceExhausted, "limit reached"),
+            NodeError::RegistrationQuotaExceeded(_)
+        ));
+        assert!(matches!(
+            error(tonic::Code::Unknown, "Partner quota exhausted"),
+            NodeError::RegistrationQuotaExceeded(_)
+        ));
+        assert!(matches!(
+            error(tonic::Code::PermissionDenied, "invalid invite code"),
+            NodeError::RegistrationRejected(_)
+        ));
+        assert!(matches!(
+            error(tonic::Code::Unavailable, "scheduler unavailable"),
+            NodeError::ServiceConnectivity(_)
+        ));
+        assert!(matches!(
+            registration_error(anyhow::anyhow!("other")),
+            NodeError::Generic(_)
+        ));
+    }
 }
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use gl_client::credentials::{Device, Nobody};
+use gl_client::signer::Signer as GlSigner;
+use sdk_common::prelude::Network;
+use tokio::sync::mpsc;
+
+use crate::bitcoin::secp256k1::{ecdsa, schnorr, Message, PublicKey};
+use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPubKey};
+use crate::signer::{MasterKey, Signer};
+
+/// The default [Signer], holding the seed in process and running the Greenlight signer loop
+pub struct SeedSigner {
+    seed: Vec<u8>,
+    network: Network,
+    signer: GlSigner,
+    master_key: MasterKey,
+    legacy_master_key: MasterKey,
+}
+
+impl SeedSigner {
+    pub fn new(seed: Vec<u8>, network: Network) -> Result<Self> {
+        let signer = GlSigner::new(seed.clone(), network.into(), Nobody::new())?;
+        let master_key = MasterKey::new(network.into(), &signer.bip32_ext_key())?;
+        let legacy_master_key = MasterKey::new(network.into(), &signer.legacy_bip32_ext_key())?;
+        Ok(Self {
+            seed,
+            network,
+            signer,
+            master_key,
+            legacy_master_key,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl Signer for SeedSigner {
+    fn node_id(&self) -> Result<Vec<u8>> {
+        Ok(self.signer.node_id())
+    }
+
+    fn derive_xpub(&self, path: Vec<ChildNumber>) -> Result<ExtendedPubKey> {
+        self.master_key.derive_xpub(&path)
+    }
+
+    fn sign_ecdsa(&self, path: Vec<ChildNumber>, message: Message) -> Result<ecdsa::Signature> {
+        self.master_key.sign_ecdsa(&path, &message)
+    }
+
+    fn sign_taproot(&self, path: Vec<ChildNumber>, message: Message) -> Result<schnorr::Signature> {
+        self.master_key.sign_taproot(&path, &message)
+    }
+
+    fn ecdh(&self, path: Vec<ChildNumber>, public_key: PublicKey) -> Result<[u8; 32]> {
+        self.master_key.ecdh(&path, &public_key)
+    }
+
+    fn hmac_sha256(&self, path: Vec<ChildNumber>, input: Vec<u8>) -> Result<[u8; 32]> {
+        self.master_key.hmac_sha256(&path, &input)
+    }
+
+    fn derive_symmetric_key(&self, path: Vec<ChildNumber>) -> Result<[u8; 32]> {
+        self.master_key.derive_symmetric_key(&path)
+    }
+
+    fn legacy_derive_symmetric_key(&self, path: Vec<ChildNumber>) -> Result<[u8; 32]> {
+        self.legacy_master_key.derive_symmetric_key(&path)
+    }
+
+    fn sign_message(&self, message: Vec<u8>) -> Result<(Vec<u8>, u8)> {
+        let (sig, recovery_id) = self.signer.sign_message(message)?;
+        Ok((sig.to_vec(), recovery_id))
+    }
+
+    fn sign_invoice(&self, msg: Vec<u8>) -> Result<Vec<u8>> {
+        self.signer.sign_invoice(msg)
+    }
+
+    async fn run(&self, device_credentials: Vec<u8>, shutdown: mpsc::Receiver<()>) -> Result<()> {
+        // The loop authenticates against the scheduler, so it needs the signer of the device
+        let signer = GlSigner::new(
+            self.seed.clone(),
+            self.network.into(),
+            Device::from_bytes(device_credentials),
+        )?;
+        signer.run_forever(shutdown).await
+    }
+
+    fn seed(&self) -> Option<Vec<u8>> {
+        Some(self.seed.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sdk_common::prelude::Network;
+
+    use super::SeedSigner;
+    use crate::bitcoin::secp256k1::{Message, PublicKey, Secp256k1};
+    use crate::bitcoin::util::bip32::ChildNumber;
+    use crate::lightning::util::message_signing::verify;
+    use crate::signer::Signer;
+
+    #[test]
+    fn test_seed_signer() {
+        let signer = SeedSigner::new(vec![7; 64], Network::Bitcoin).unwrap();
+        assert_eq!(signer.seed(), Some(vec![7; 64]));
+
+        let node_id = PublicKey::from_slice(&signer.node_id().unwrap()).unwrap();
+        let (sig, recovery_id) = signer.sign_message(b"hello".to_vec()).unwrap();
+        let mut complete_signature = vec![31 + recovery_id];
+        complete_signature.extend_from_slice(&sig);
+        let signature = zbase32::encode_full_bytes(&complete_signature);
+        assert!(verify(b"hello", &signature, &node_id));
+
+        let path = vec![ChildNumber::from_hardened_idx(140).unwrap(), 0.into()];
+        assert_eq!(
+            signer.derive_symmetric_key(path.clone()).unwrap(),
+            signer.derive_symmetric_key(path.clone()).unwrap()
+        );
+        assert_ne!(
+            signer.derive_symmetric_key(path.clone()).unwrap(),
+            signer.legacy_derive_symmetric_key(path.clone()).unwrap()
+        );
+
+        // The signatures verify with the public key of the same path
+        let secp = Secp256k1::new();
+        let message = Message::from_slice(&[1; 32]).unwrap();
+        let public_key = signer.derive_xpub(path.clone()).unwrap().public_key;
+        let signature = signer.sign_ecdsa(path.clone(), message).unwrap();
+        assert!(secp.verify_ecdsa(&message, &signature, &public_key).is_ok());
+
+        // Both sides of an ECDH exchange get the same secret
+        let other_path = vec![ChildNumber::from_hardened_idx(141).unwrap()];
+        let other_public_key = signer.derive_xpub(other_path.clone()).unwrap().public_key;
+        assert_eq!(
+            signer.ecdh(path, other_public_key).unwrap(),
+            signer.ecdh(other_path, public_key).unwrap()
+        );
+    }
+}
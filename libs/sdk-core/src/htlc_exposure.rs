@@ -0,0 +1,259 @@
+use std::sync::{Arc, Mutex};
+
+use thiserror::Error;
+
+use crate::persist::error::PersistError;
+use crate::HtlcExposureLimits;
+
+/// Why a payment is refused by the [crate::Config::htlc_exposure_limits]
+#[derive(Debug, Error)]
+pub(crate) enum HtlcExposureError {
+    #[error("The amount of {amount_msat} msat would bring the in-flight HTLCs to {exposure_msat} msat, over the limit of {limit_msat} msat")]
+    InflightLimitExceeded {
+        amount_msat: u64,
+        exposure_msat: u64,
+        limit_msat: u64,
+    },
+
+    #[error("The amount of {amount_msat} msat needs at least {htlcs} HTLCs, over the limit of {limit} per payment")]
+    HtlcCountExceeded {
+        amount_msat: u64,
+        htlcs: u32,
+        limit: u32,
+    },
+
+    #[error(transparent)]
+    Persist(#[from] PersistError),
+}
+
+impl HtlcExposureLimits {
+    /// Checks a payment of `amount_msat` against the limits, given the amount already in flight
+    /// and the spendable amounts of the opened channels.
+    ///
+    /// A payment is split in at least one HTLC per channel it's sent through, so the fewest
+    /// HTLCs it needs is the number of channels, the most liquid first, covering its amount.
+    pub(crate) fn check(
+        &self,
+        amount_msat: u64,
+        inflight_msat: u64,
+        channels_spendable_msat: &[u64],
+    ) -> Result<(), HtlcExposureError> {
+        if let Some(limit_msat) = self.max_inflight_msat {
+            let exposure_msat = inflight_msat.saturating_add(amount_msat);
+            if exposure_msat > limit_msat {
+                return Err(HtlcExposureError::InflightLimitExceeded {
+                    amount_msat,
+                    exposure_msat,
+                    limit_msat,
+                });
+            }
+        }
+        if let Some(limit) = self.max_htlcs_per_payment {
+            let htlcs = min_htlcs(amount_msat, channels_spendable_msat);
+            if htlcs > limit {
+                return Err(HtlcExposureError::HtlcCountExceeded {
+                    amount_msat,
+                    htlcs,
+                    limit,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The fewest channels covering the amount. If the channels can't cover it, all of them are
+/// counted: the payment then fails for lack of balance anyway.
+fn min_htlcs(amount_msat: u64, channels_spendable_msat: &[u64]) -> u32 {
+    let mut spendable_msat: Vec<u64> = channels_spendable_msat
+        .iter()
+        .copied()
+        .filter(|s| *s > 0)
+        .collect();
+    spendable_msat.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut htlcs = 0;
+    let mut covered_msat = 0u64;
+    for spendable in spendable_msat {
+        if covered_msat >= amount_msat {
+            break;
+        }
+        covered_msat = covered_msat.saturating_add(spendable);
+        htlcs += 1;
+    }
+    htlcs.max(1)
+}
+
+/// The amounts of the payments being sent by the SDK which are not persisted as pending yet,
+/// counted towards the [HtlcExposureLimits::max_inflight_msat].
+#[derive(Clone, Default)]
+pub(crate) struct InflightTracker {
+    inflight: Arc<Mutex<Inflight>>,
+}
+
+#[derive(Default)]
+struct Inflight {
+    msat: u64,
+    /// The number of tracked amounts released so far
+    releases: u64,
+}
+
+impl InflightTracker {
+    /// Checks the payment with `check`, given the amount in flight: the `pending_msat` of the
+    /// payments persisted as pending and the amount tracked here. The payment amount is tracked
+    /// if it passes, and no other payment is checked in the meantime.
+    ///
+    /// The pending amount is read without holding the lock. It's read again if a tracked amount
+    /// was released meanwhile, as its payment may have been persisted as pending after the read.
+    pub(crate) fn reserve<P, F>(
+        &self,
+        amount_msat: u64,
+        pending_msat: P,
+        check: F,
+    ) -> Result<InflightReservation, HtlcExposureError>
+    where
+        P: Fn() -> Result<u64, HtlcExposureError>,
+        F: FnOnce(u64) -> Result<(), HtlcExposureError>,
+    {
+        loop {
+            let releases = self.inflight.lock().unwrap().releases;
+            let pending_msat = pending_msat()?;
+            let mut inflight = self.inflight.lock().unwrap();
+            if inflight.releases != releases {
+                continue;
+            }
+            check(pending_msat + inflight.msat)?;
+            inflight.msat += amount_msat;
+            return Ok(InflightReservation {
+                inflight: self.inflight.clone(),
+                amount_msat,
+            });
+        }
+    }
+}
+
+/// A payment amount tracked by the [InflightTracker]. It stops being tracked when dropped, once
+/// the payment is persisted as pending or completed.
+pub(crate) struct InflightReservation {
+    inflight: Arc<Mutex<Inflight>>,
+    amount_msat: u64,
+}
+
+impl Drop for InflightReservation {
+    fn drop(&mut self) {
+        let mut inflight = self.inflight.lock().unwrap();
+        inflight.msat = inflight.msat.saturating_sub(self.amount_msat);
+        inflight.releases += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use crate::htlc_exposure::{min_htlcs, HtlcExposureError, InflightTracker};
+    use crate::HtlcExposureLimits;
+
+    #[test]
+    fn test_check() {
+        assert!(HtlcExposureLimits::default()
+            .check(u64::MAX, u64::MAX, &[])
+            .is_ok());
+
+        let limits = HtlcExposureLimits {
+            max_inflight_msat: Some(10_000),
+            max_htlcs_per_payment: Some(2),
+        };
+        let channels = [3_000, 0, 6_000, 4_000];
+        assert!(limits.check(4_000, 6_000, &channels).is_ok());
+        assert!(matches!(
+            limits.check(4_001, 6_000, &channels),
+            Err(HtlcExposureError::InflightLimitExceeded {
+                exposure_msat: 10_001,
+                ..
+            })
+        ));
+        assert!(limits.check(10_000, 0, &channels).is_ok());
+        assert!(matches!(
+            limits.check(10_001, 0, &[]),
+            Err(HtlcExposureError::InflightLimitExceeded { .. })
+        ));
+        let limits = HtlcExposureLimits {
+            max_htlcs_per_payment: Some(2),
+            ..Default::default()
+        };
+        assert!(matches!(
+            limits.check(10_001, 0, &channels),
+            Err(HtlcExposureError::HtlcCountExceeded { htlcs: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn test_min_htlcs() {
+        assert_eq!(min_htlcs(1_000, &[]), 1);
+        assert_eq!(min_htlcs(1_000, &[500, 2_000]), 1);
+        assert_eq!(min_htlcs(2_500, &[500, 2_000, 100]), 2);
+        assert_eq!(min_htlcs(10_000, &[500, 2_000, 100]), 3);
+    }
+
+    #[test]
+    fn test_inflight_tracker() {
+        let tracker = InflightTracker::default();
+        let first = tracker.reserve(
+            1_000,
+            || Ok(0),
+            |inflight| {
+                assert_eq!(inflight, 0);
+                Ok(())
+            },
+        );
+        assert!(first.is_ok());
+        {
+            let _second = tracker.reserve(
+                500,
+                || Ok(200),
+                |inflight| {
+                    assert_eq!(inflight, 1_200);
+                    Ok(())
+                },
+            );
+        }
+        drop(first);
+        let _third = tracker.reserve(
+            0,
+            || Ok(0),
+            |inflight| {
+                assert_eq!(inflight, 0);
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn test_inflight_tracker_release_while_reading() {
+        let tracker = InflightTracker::default();
+        let first = tracker.reserve(1_000, || Ok(0), |_| Ok(()));
+        let first = RefCell::new(first.ok());
+
+        // The payment of the first reservation is persisted as pending while the pending
+        // amount is read, so it's read again
+        let reads = Cell::new(0);
+        let second = tracker.reserve(
+            500,
+            || {
+                reads.set(reads.get() + 1);
+                if let Some(first) = first.borrow_mut().take() {
+                    drop(first);
+                    return Ok(0);
+                }
+                Ok(1_000)
+            },
+            |inflight| {
+                assert_eq!(inflight, 1_000);
+                Ok(())
+            },
+        );
+        assert!(second.is_ok());
+        assert_eq!(reads.get(), 2);
+    }
+}
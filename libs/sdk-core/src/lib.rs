@@ -83,7 +83,8 @@
 //! your own config from scratch it is recommended to use the [BreezServices::default_config] method and
 //! customize it according to your needs.
 //! Once the [NodeConfig] is created it is passed to the [BreezServices::connect] method along with the seed and and implementation of [EventListener] which is used to
-//! notify the caller of SDK events.
+//! notify the caller of SDK events. Async Rust callers can also consume the events as a stream with
+//! [BreezServices::subscribe].
 //!
 //! Now your SDK is ready to be used.
 //!
@@ -134,6 +135,23 @@
 //! The resulting [InputType] will tell you what the input is and how to treat it, as well as present relevant payload data
 //! in a structured form.
 //!
+//! ### I. Tracing
+//!
+//! The main flows (connect, sync, send, receive and swaps) are instrumented with [tracing](https://docs.rs/tracing)
+//! spans. Install a `tracing` subscriber, for example one exporting to OTLP, to see how long each step of a user
+//! action takes. The spans record what identifies the action, like the `payment_hash` of a payment, the
+//! `swap_address` of a swap or the `request_id` of an LSP request.
+//!
+//! ### J. Cargo features
+//!
+//! The `buy-bitcoin`, `fiat`, `lnurl`, `mock-node` and `reverse-swaps` features are enabled by default.
+//! Integrations that don't need some of these subsystems can disable the default features and only enable the
+//! ones they use, which reduces the binary size. The Flutter bindings require all of them but `mock-node`,
+//! without which connecting a [NodeConfig::Mock] node fails.
+//!
+//! The `test_vectors` feature exposes the [test_vectors](crate::test_vectors) module, with
+//! canonical swap scripts, invoices and LNURL payloads and their expected outputs.
+//!
 //!
 //! ## Bindings
 //!
@@ -156,6 +174,12 @@
 //!
 //! Join this [telegram group](https://t.me/breezsdk).
 
+#[cfg(all(
+    feature = "buy-bitcoin",
+    feature = "fiat",
+    feature = "lnurl",
+    feature = "reverse-swaps"
+))]
 #[allow(clippy::all)]
 mod bridge_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
 
@@ -163,38 +187,80 @@ mod bridge_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not
 extern crate log;
 
 mod backup;
+#[cfg(all(
+    feature = "buy-bitcoin",
+    feature = "fiat",
+    feature = "lnurl",
+    feature = "reverse-swaps"
+))]
 pub mod binding;
+mod bip322;
 mod breez_services;
+mod cancellation;
 mod chain;
 mod crypt;
+mod denylist;
 pub mod error;
+mod export;
 #[rustfmt::skip]
 mod node_api; // flutter_rust_bridge_codegen: has to be defined before greenlight; greenlight::node_api
 mod greenlight;
+mod htlc_exposure;
+#[cfg(feature = "lnurl")]
 #[rustfmt::skip]
 pub mod lnurl;
+#[cfg(feature = "buy-bitcoin")]
 mod buy;
+mod log_file;
 mod lsp;
 mod lsps0;
 mod lsps2;
+mod metrics;
+#[cfg(feature = "mock-node")]
+mod mock_node;
 mod models;
+mod payment_groups;
+mod payment_request;
 mod persist;
+mod send_queue;
 mod serializer;
+mod signer;
+mod snapshot;
+mod spend_policy;
+#[cfg(feature = "lnurl")]
+mod static_lnurl_pay;
+mod supervisor;
 mod support;
 mod swap_in;
+// The reverse swap types are part of the models and persistence, even if the feature is disabled
+#[cfg_attr(not(feature = "reverse-swaps"), allow(dead_code))]
 mod swap_out;
 #[allow(clippy::all)]
 #[allow(unused_mut)]
 #[allow(dead_code)]
 mod test_utils;
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
 
+pub use backup::{BackupState, BackupTransport, S3BackupTransport, WebDavBackupTransport};
+#[cfg(feature = "fiat")]
+pub use breez_services::FiatRateAlertTriggeredDetails;
+#[cfg(feature = "lnurl")]
+pub use breez_services::LnUrlPayFinishedDetails;
 pub use breez_services::{
-    mnemonic_to_seed, BackupFailedData, BreezEvent, BreezServices, CheckMessageRequest,
-    CheckMessageResponse, EventListener, InvoicePaidDetails, LogStream, PaymentFailedData,
-    SignMessageRequest, SignMessageResponse,
+    log_filter_enabled, mnemonic_to_seed, BackupFailedData, BatchPaymentProgressDetails,
+    BreezEvent, BreezServices, ChannelHygieneSuggestionDetails, CheckMessageRequest,
+    CheckMessageResponse, ConnectProgressDetails, ConnectStage, DeriveEncryptionKeyRequest,
+    DeriveEncryptionKeyResponse, EventListener, InvoicePaidDetails, LogStream, LowLiquidityDetails,
+    OpenChannelReceiveDetails, OpenChannelReceiveStage, OutboxPaymentDetails, PaymentFailedData,
+    ProveAddressOwnershipRequest, ProveAddressOwnershipResponse, SignMessageRequest,
+    SignMessageResponse, SwapRefundedDetails, UnredeemedFundsDetails,
 };
-pub use chain::RecommendedFees;
+pub use chain::{ChainService, OnchainTx, Outspend, RecommendedFees, TxStatus, Vin, Vout};
+pub use greenlight::SeedSigner;
 pub use lsp::LspInformation;
 pub use models::*;
 pub use sdk_common::prelude::*;
+pub use signer::Signer;
+pub use snapshot::diff_snapshots;
 pub use swap_out::reverseswap::{ESTIMATED_CLAIM_TX_VSIZE, ESTIMATED_LOCKUP_TX_VSIZE};
@@ -1,11 +1,11 @@
 use std::sync::Arc;
 
 use gl_client::bitcoin::{
-    hashes::{sha256, Hash, HashEngine, Hmac, HmacEngine},
-    secp256k1::{Message, Secp256k1},
+    secp256k1::Message,
     util::bip32::{ChildNumber, ExtendedPubKey},
 };
-use sdk_common::prelude::{LnUrlError, LnUrlResult, LnurlAuthSigner};
+use reqwest::Url;
+use sdk_common::prelude::{get_derivation_path, LnUrlError, LnUrlResult, LnurlAuthSigner};
 
 use crate::node_api::NodeAPI;
 
@@ -22,18 +22,16 @@ impl SdkLnurlAuthSigner {
 #[tonic::async_trait]
 impl LnurlAuthSigner for SdkLnurlAuthSigner {
     async fn derive_bip32_pub_key(&self, derivation_path: &[ChildNumber]) -> LnUrlResult<Vec<u8>> {
-        let xpriv = self.node_api.derive_bip32_key(derivation_path.to_vec()).await?;
-        Ok(ExtendedPubKey::from_priv(&Secp256k1::new(), &xpriv)
-            .encode()
-            .to_vec())
+        let xpub = self.node_api.derive_xpub(derivation_path.to_vec()).await?;
+        Ok(xpub.encode().to_vec())
     }
 
     async fn sign_ecdsa(&self, msg: &[u8], derivation_path: &[ChildNumber]) -> LnUrlResult<Vec<u8>> {
-        let xpriv = self.node_api.derive_bip32_key(derivation_path.to_vec()).await?;
-        let sig = Secp256k1::new().sign_ecdsa(
-            &Message::from_slice(msg).map_err(|_| LnUrlError::generic("Failed to sign"))?,
-            &xpriv.private_key,
-        );
+        let message = Message::from_slice(msg).map_err(|_| LnUrlError::generic("Failed to sign"))?;
+        let sig = self
+            .node_api
+            .sign_ecdsa(derivation_path.to_vec(), message)
+            .await?;
         Ok(sig.serialize_der().to_vec())
     }
 
@@ -42,13 +40,31 @@ impl LnurlAuthSigner for SdkLnurlAuthSigner {
         key_derivation_path: &[ChildNumber],
         input: &[u8],
     ) -> LnUrlResult<Vec<u8>> {
-        let priv_key = self
+        let hmac = self
             .node_api
-            .derive_bip32_key(key_derivation_path.to_vec()).await?;
-        let mut engine = HmacEngine::<sha256::Hash>::new(priv_key.encode().as_slice());
-        engine.input(input);
-        Ok(Hmac::<sha256::Hash>::from_engine(engine)
-            .as_inner()
-            .to_vec())
+            .hmac_sha256(key_derivation_path.to_vec(), input.to_vec())
+            .await?;
+        Ok(hmac.to_vec())
+    }
+}
+
+impl SdkLnurlAuthSigner {
+    /// The hex encoded public key of the LUD-05 hashing key `m/138'/0`
+    pub(crate) async fn hashing_key(&self) -> LnUrlResult<String> {
+        let path = [ChildNumber::from_hardened_idx(138)?, ChildNumber::from(0)];
+        self.public_key_hex(&path).await
+    }
+
+    /// The hex encoded LUD-05 linking public key of the domain, as sent to it on authentication
+    pub(crate) async fn linking_key(&self, domain: &str) -> LnUrlResult<String> {
+        let url = Url::parse(&format!("https://{domain}"))
+            .map_err(|e| LnUrlError::InvalidUri(e.to_string()))?;
+        let path = get_derivation_path(self, url).await?;
+        self.public_key_hex(&path).await
+    }
+
+    async fn public_key_hex(&self, path: &[ChildNumber]) -> LnUrlResult<String> {
+        let xpub = ExtendedPubKey::decode(&self.derive_bip32_pub_key(path).await?)?;
+        Ok(hex::encode(xpub.public_key.serialize()))
     }
 }
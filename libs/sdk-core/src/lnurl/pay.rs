@@ -1,7 +1,7 @@
 use sdk_common::prelude::*;
 use serde::Serialize;
 
-use crate::Payment;
+use crate::{PartnerFeeDetails, Payment};
 
 /// Contains the result of the entire LNURL-pay interaction, as reported by the LNURL endpoint.
 ///
@@ -14,18 +14,30 @@ use crate::Payment;
 ///
 /// * `PayError` indicates that an error occurred while trying to pay the invoice from the LNURL endpoint.
 ///   This includes the payment hash of the failed invoice and the failure reason.
-#[derive(Serialize)]
+///
+/// * `Pending` indicates the payment did not complete within the requested
+///   [LnUrlPayRequest::pending_timeout_sec]. The final result is emitted with
+///   [crate::BreezEvent::LnUrlPayFinished].
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum LnUrlPayResult {
     EndpointSuccess { data: LnUrlPaySuccessData },
     EndpointError { data: LnUrlErrorData },
     PayError { data: LnUrlPayErrorData },
+    Pending { data: LnUrlPayPendingData },
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct LnUrlPaySuccessData {
     pub payment: Payment,
     pub success_action: Option<SuccessActionProcessed>,
+    /// The partner fee paid after the payment, if a [crate::Config::partner_fee] is set
+    pub partner_fee: Option<PartnerFeeDetails>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LnUrlPayPendingData {
+    pub payment_hash: String,
 }
 
 #[cfg(test)]
@@ -309,6 +321,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await?
         {
@@ -330,6 +345,43 @@ pub(crate) mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_lnurl_pay_completed_before_pending_timeout() -> Result<()> {
+        let mock_rest_client = MockRestClient::new();
+        let pay_req = get_test_pay_req_data(0, 100_000, 0);
+        let inv = rand_invoice_with_description_hash(pay_req.metadata_str.clone())?;
+        let user_amount_msat = inv.amount_milli_satoshis().unwrap();
+
+        mock_lnurl_pay_callback_endpoint_no_success_action(
+            &mock_rest_client,
+            LnurlPayCallbackParams {
+                error: None,
+                pr: Some(inv.to_string()),
+            },
+        );
+
+        let rest_client: Arc<dyn RestClient> = Arc::new(mock_rest_client);
+        let mock_breez_services = breez_services_with(None, Some(rest_client), vec![]).await?;
+        // The payment completes right away, so its result is returned instead of a pending one
+        match mock_breez_services
+            .lnurl_pay(LnUrlPayRequest {
+                data: pay_req,
+                amount_msat: user_amount_msat,
+                use_trampoline: false,
+                comment: None,
+                payment_label: None,
+                validate_success_action_url: None,
+                pending_timeout_sec: Some(30),
+                cancel_token: None,
+                skip_denylist: None,
+            })
+            .await?
+        {
+            LnUrlPayResult::EndpointSuccess { .. } => Ok(()),
+            res => Err(anyhow!("Unexpected result {res:?}")),
+        }
+    }
+
     static COMMENT_LENGTH: u16 = 10;
 
     #[tokio::test]
@@ -354,6 +406,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await;
         // An unsupported Success Action results in an error
@@ -389,6 +444,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await?
         {
@@ -427,6 +485,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await?
         {
@@ -480,6 +541,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await
             .is_err());
@@ -515,6 +579,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await;
         assert!(matches!(res, Ok(LnUrlPayResult::EndpointError { data: _ })));
@@ -558,6 +625,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await?
         {
@@ -616,6 +686,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: Some(true),
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
                 use_trampoline: false,
             })
             .await;
@@ -652,6 +725,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: Some(false),
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
                 use_trampoline: false,
             })
             .await?
@@ -745,6 +821,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await?
         {
@@ -835,6 +914,9 @@ pub(crate) mod tests {
                 comment: Some(comment),
                 payment_label: None,
                 validate_success_action_url: None,
+                pending_timeout_sec: None,
+                cancel_token: None,
+                skip_denylist: None,
             })
             .await?
         {
@@ -0,0 +1,116 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::LogFileOptions;
+
+const LOG_FILE_NAME: &str = "sdk.log";
+const DEFAULT_MAX_ROTATED_FILES: u32 = 1;
+
+/// The SDK log file, rotated once it grows over [LogFileOptions::max_log_size_bytes].
+///
+/// Rotated files are named `sdk.log.1`, `sdk.log.2` and so on, `sdk.log.1` being the most recent.
+pub(crate) struct RotatingLogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_size: Option<u64>,
+    max_rotated_files: u32,
+}
+
+impl RotatingLogFile {
+    pub(crate) fn open(options: &LogFileOptions) -> io::Result<Self> {
+        let path = Path::new(&options.log_dir).join(LOG_FILE_NAME);
+        let file = Self::open_file(&path)?;
+        let size = file.metadata()?.len();
+        Ok(RotatingLogFile {
+            path,
+            file,
+            size,
+            max_size: options.max_log_size_bytes,
+            max_rotated_files: options
+                .max_rotated_files
+                .unwrap_or(DEFAULT_MAX_ROTATED_FILES),
+        })
+    }
+
+    fn open_file(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_rotated_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            // The oldest file is overwritten by the rename
+            for index in (1..self.max_rotated_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+        self.file = Self::open_file(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(max_size) = self.max_size {
+            if self.size > 0 && self.size + buf.len() as u64 > max_size {
+                self.rotate()?;
+            }
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use super::RotatingLogFile;
+    use crate::test_utils::get_test_working_dir;
+    use crate::LogFileOptions;
+
+    #[test]
+    fn test_log_rotation() -> Result<()> {
+        let log_dir = get_test_working_dir();
+        let mut log_file = RotatingLogFile::open(&LogFileOptions {
+            log_dir: log_dir.clone(),
+            max_log_size_bytes: Some(10),
+            max_rotated_files: Some(2),
+        })?;
+
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            log_file.write_all(line.as_bytes())?;
+        }
+        log_file.flush()?;
+
+        let read = |name: &str| std::fs::read_to_string(Path::new(&log_dir).join(name));
+        assert_eq!(read("sdk.log")?, "fourth\n");
+        assert_eq!(read("sdk.log.1")?, "third\n");
+        assert_eq!(read("sdk.log.2")?, "second\n");
+        assert!(read("sdk.log.3").is_err());
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use crate::crypt::encrypt;
 use crate::error::{SdkError, SdkResult};
-use crate::models::{LspAPI, OpeningFeeParams, OpeningFeeParamsMenu};
+use crate::models::{
+    LspAPI, LspPolicy, OpeningFeeParams, OpeningFeeParamsMenu, INVOICE_PAYMENT_FEE_EXPIRY_SECONDS,
+};
 
 use anyhow::{anyhow, Result};
 use prost::Message;
@@ -90,6 +92,39 @@ impl LspInformation {
     }
 }
 
+/// Selects the LSP from `lsps` according to the `policy`, or `None` if no LSP matches it.
+///
+/// The fees compared are those used to open a channel when receiving a payment. The current
+/// LSP is kept when no other LSP is strictly cheaper, so the selection doesn't alternate between
+/// LSPs with the same fees.
+pub(crate) fn select_lsp<'a>(
+    lsps: &'a [LspInformation],
+    policy: &LspPolicy,
+    current_lsp_id: Option<&str>,
+) -> Option<&'a LspInformation> {
+    if let LspPolicy::Pinned { lsp_id } = policy {
+        return lsps.iter().find(|lsp| &lsp.id == lsp_id);
+    }
+
+    let is_current = |lsp: &&LspInformation| Some(lsp.id.as_str()) == current_lsp_id;
+    lsps.iter()
+        .filter(is_current)
+        .chain(lsps.iter().filter(|lsp| !is_current(lsp)))
+        .filter_map(|lsp| {
+            let fee = lsp
+                .cheapest_open_channel_fee(INVOICE_PAYMENT_FEE_EXPIRY_SECONDS)
+                .ok()?;
+            let key = match policy {
+                LspPolicy::LowestProportionalFee => (fee.proportional as u64, fee.min_msat),
+                _ => (fee.min_msat, fee.proportional as u64),
+            };
+            Some((key, lsp))
+        })
+        // The first of the cheapest LSPs is returned
+        .min_by_key(|(key, _)| *key)
+        .map(|(_, lsp)| lsp)
+}
+
 #[tonic::async_trait]
 impl LspAPI for BreezServer {
     async fn list_lsps(&self, pubkey: String) -> SdkResult<Vec<LspInformation>> {
@@ -214,9 +249,9 @@ impl LspAPI for BreezServer {
 
 #[cfg(test)]
 mod tests {
-    use crate::{LspInformation, OpeningFeeParams};
+    use crate::{LspInformation, LspPolicy, OpeningFeeParams};
 
-    use super::OpeningFeeParamsMenu;
+    use super::{select_lsp, OpeningFeeParamsMenu};
     use anyhow::Result;
     use chrono::{Duration, Utc};
 
@@ -269,4 +304,83 @@ mod tests {
 
         Ok(())
     }
+
+    fn lsp_with_fees(id: &str, fees: Option<(u64, u32)>) -> LspInformation {
+        LspInformation {
+            id: id.to_string(),
+            name: id.to_string(),
+            widget_url: "".to_string(),
+            pubkey: "pubkey".to_string(),
+            host: "localhost".to_string(),
+            base_fee_msat: 1,
+            fee_rate: 1.0,
+            time_lock_delta: 32,
+            min_htlc_msat: 1000,
+            lsp_pubkey: hex::decode("A0").unwrap(),
+            opening_fee_params_list: OpeningFeeParamsMenu {
+                values: fees
+                    .map(|(min_msat, proportional)| OpeningFeeParams {
+                        min_msat,
+                        proportional,
+                        valid_until: (Utc::now() + Duration::days(1)).to_rfc3339(),
+                        max_idle_time: 1,
+                        max_client_to_self_delay: 1,
+                        promise: "promise".to_string(),
+                    })
+                    .into_iter()
+                    .collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_select_lsp() {
+        let lsps = vec![
+            lsp_with_fees("a", Some((2_000_000, 4_000))),
+            lsp_with_fees("b", Some((3_000_000, 1_000))),
+            lsp_with_fees("c", Some((2_000_000, 4_000))),
+            lsp_with_fees("d", None),
+        ];
+        let selected = |policy: LspPolicy, current_lsp_id: Option<&str>| {
+            select_lsp(&lsps, &policy, current_lsp_id).map(|lsp| lsp.id.clone())
+        };
+
+        assert_eq!(
+            selected(LspPolicy::LowestOpeningFee, None),
+            Some("a".to_string())
+        );
+        assert_eq!(
+            selected(LspPolicy::LowestProportionalFee, Some("a")),
+            Some("b".to_string())
+        );
+        // The current LSP is kept if it is as cheap as the others
+        assert_eq!(
+            selected(LspPolicy::LowestOpeningFee, Some("c")),
+            Some("c".to_string())
+        );
+        // LSPs without fees can't be compared
+        assert_eq!(
+            selected(LspPolicy::LowestOpeningFee, Some("d")),
+            Some("a".to_string())
+        );
+        assert_eq!(
+            selected(
+                LspPolicy::Pinned {
+                    lsp_id: "d".to_string()
+                },
+                Some("a")
+            ),
+            Some("d".to_string())
+        );
+        assert_eq!(
+            selected(
+                LspPolicy::Pinned {
+                    lsp_id: "e".to_string()
+                },
+                Some("a")
+            ),
+            None
+        );
+        assert!(select_lsp(&[], &LspPolicy::LowestOpeningFee, None).is_none());
+    }
 }
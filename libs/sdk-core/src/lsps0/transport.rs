@@ -227,6 +227,7 @@ impl Transport {
         }
     }
 
+    #[tracing::instrument(skip(self, peer_id, req, timeout), fields(request_id = tracing::field::Empty))]
     pub async fn request_response<TRequest, TResponse>(
         &self,
         method: String,
@@ -239,6 +240,7 @@ impl Transport {
         TResponse: serde::de::DeserializeOwned,
     {
         let request_id = generate_request_id();
+        tracing::Span::current().record("request_id", request_id.as_str());
         let wrapped_req = RpcRequest {
             id: request_id.clone(),
             jsonrpc: String::from(JSONRPC_VERSION),
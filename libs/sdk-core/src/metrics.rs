@@ -0,0 +1,355 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use sdk_common::grpc;
+
+use crate::error::SdkResult;
+use crate::lsp::LspInformation;
+use crate::models::{LspAPI, MetricBucket, MetricCounter, MetricHistogram, MetricsSnapshot};
+
+/// The upper bounds, in seconds, of the buckets of the duration histograms
+const DURATION_BUCKETS_SECS: [f64; 10] = [0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0];
+
+/// The counters and histograms of the SDK operations, see [crate::BreezServices::metrics]
+#[derive(Default)]
+pub(crate) struct Metrics {
+    payments_succeeded: Counter,
+    payments_failed: Counter,
+    payment_duration: Histogram,
+    syncs_succeeded: Counter,
+    syncs_failed: Counter,
+    sync_duration: Histogram,
+    lsp_rpc_failures: Counter,
+    pub(crate) swaps_created: Counter,
+    pub(crate) reverse_swaps_created: Counter,
+}
+
+impl Metrics {
+    /// Records an outgoing Lightning payment, from the moment it was handed to the node
+    pub(crate) fn record_payment(&self, duration: Duration, succeeded: bool) {
+        self.payment_duration.observe(duration);
+        match succeeded {
+            true => self.payments_succeeded.inc(),
+            false => self.payments_failed.inc(),
+        }
+    }
+
+    pub(crate) fn record_sync(&self, duration: Duration, succeeded: bool) {
+        self.sync_duration.observe(duration);
+        match succeeded {
+            true => self.syncs_succeeded.inc(),
+            false => self.syncs_failed.inc(),
+        }
+    }
+
+    /// The metrics so far, along with the reconnections to the LSP peer which are tracked
+    /// separately
+    pub(crate) fn snapshot(
+        &self,
+        lsp_reconnections: u64,
+        failed_lsp_reconnections: u64,
+    ) -> MetricsSnapshot {
+        let counter = |name: &str, help: &str, value: u64| MetricCounter {
+            name: name.to_string(),
+            help: help.to_string(),
+            value,
+        };
+        MetricsSnapshot {
+            counters: vec![
+                counter(
+                    "breez_payments_succeeded_total",
+                    "Outgoing Lightning payments that succeeded",
+                    self.payments_succeeded.get(),
+                ),
+                counter(
+                    "breez_payments_failed_total",
+                    "Outgoing Lightning payments that failed",
+                    self.payments_failed.get(),
+                ),
+                counter(
+                    "breez_syncs_succeeded_total",
+                    "Syncs with the node that succeeded",
+                    self.syncs_succeeded.get(),
+                ),
+                counter(
+                    "breez_syncs_failed_total",
+                    "Syncs with the node that failed",
+                    self.syncs_failed.get(),
+                ),
+                counter(
+                    "breez_lsp_rpc_failures_total",
+                    "Failed calls to the LSP API",
+                    self.lsp_rpc_failures.get(),
+                ),
+                counter(
+                    "breez_swaps_created_total",
+                    "Swap-in addresses created",
+                    self.swaps_created.get(),
+                ),
+                counter(
+                    "breez_reverse_swaps_created_total",
+                    "Reverse swaps created to pay onchain",
+                    self.reverse_swaps_created.get(),
+                ),
+                counter(
+                    "breez_lsp_reconnections_total",
+                    "Attempts to reconnect to the LSP peer",
+                    lsp_reconnections,
+                ),
+                counter(
+                    "breez_lsp_reconnections_failed_total",
+                    "Attempts to reconnect to the LSP peer that failed",
+                    failed_lsp_reconnections,
+                ),
+            ],
+            histograms: vec![
+                self.payment_duration.snapshot(
+                    "breez_payment_duration_seconds",
+                    "Duration of the outgoing Lightning payments, whether they succeeded or not",
+                ),
+                self.sync_duration
+                    .snapshot("breez_sync_duration_seconds", "Duration of the syncs"),
+            ],
+        }
+    }
+}
+
+/// Counts the failed calls to the LSP API it wraps
+pub(crate) struct MeteredLspAPI {
+    pub(crate) inner: Arc<dyn LspAPI>,
+    pub(crate) metrics: Arc<Metrics>,
+}
+
+impl MeteredLspAPI {
+    fn record<T>(&self, res: SdkResult<T>) -> SdkResult<T> {
+        if res.is_err() {
+            self.metrics.lsp_rpc_failures.inc();
+        }
+        res
+    }
+}
+
+#[tonic::async_trait]
+impl LspAPI for MeteredLspAPI {
+    async fn list_lsps(&self, node_pubkey: String) -> SdkResult<Vec<LspInformation>> {
+        self.record(self.inner.list_lsps(node_pubkey).await)
+    }
+
+    async fn list_used_lsps(&self, node_pubkey: String) -> SdkResult<Vec<LspInformation>> {
+        self.record(self.inner.list_used_lsps(node_pubkey).await)
+    }
+
+    async fn register_payment_notifications(
+        &self,
+        lsp_id: String,
+        lsp_pubkey: Vec<u8>,
+        webhook_url: String,
+        webhook_url_signature: String,
+    ) -> SdkResult<grpc::RegisterPaymentNotificationResponse> {
+        self.record(
+            self.inner
+                .register_payment_notifications(
+                    lsp_id,
+                    lsp_pubkey,
+                    webhook_url,
+                    webhook_url_signature,
+                )
+                .await,
+        )
+    }
+
+    async fn unregister_payment_notifications(
+        &self,
+        lsp_id: String,
+        lsp_pubkey: Vec<u8>,
+        webhook_url: String,
+        webhook_url_signature: String,
+    ) -> SdkResult<grpc::RemovePaymentNotificationResponse> {
+        self.record(
+            self.inner
+                .unregister_payment_notifications(
+                    lsp_id,
+                    lsp_pubkey,
+                    webhook_url,
+                    webhook_url_signature,
+                )
+                .await,
+        )
+    }
+
+    async fn register_payment(
+        &self,
+        lsp_id: String,
+        lsp_pubkey: Vec<u8>,
+        payment_info: grpc::PaymentInformation,
+    ) -> SdkResult<grpc::RegisterPaymentReply> {
+        self.record(
+            self.inner
+                .register_payment(lsp_id, lsp_pubkey, payment_info)
+                .await,
+        )
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Counter(AtomicU64);
+
+impl Counter {
+    pub(crate) fn inc(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A histogram of durations, with the buckets of [DURATION_BUCKETS_SECS]
+#[derive(Default)]
+pub(crate) struct Histogram(StdMutex<HistogramData>);
+
+#[derive(Default)]
+struct HistogramData {
+    /// The number of observations of each bucket, not cumulative
+    bucket_counts: [u64; DURATION_BUCKETS_SECS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    pub(crate) fn observe(&self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        let mut data = self.0.lock().unwrap();
+        if let Some(bucket) = DURATION_BUCKETS_SECS
+            .iter()
+            .position(|bound| secs <= *bound)
+        {
+            data.bucket_counts[bucket] += 1;
+        }
+        data.sum += secs;
+        data.count += 1;
+    }
+
+    fn snapshot(&self, name: &str, help: &str) -> MetricHistogram {
+        let data = self.0.lock().unwrap();
+        let mut cumulative_count = 0;
+        let buckets = DURATION_BUCKETS_SECS
+            .iter()
+            .zip(data.bucket_counts)
+            .map(|(upper_bound, count)| {
+                cumulative_count += count;
+                MetricBucket {
+                    upper_bound: *upper_bound,
+                    cumulative_count,
+                }
+            })
+            .collect();
+        MetricHistogram {
+            name: name.to_string(),
+            help: help.to_string(),
+            buckets,
+            sum: data.sum,
+            count: data.count,
+        }
+    }
+}
+
+impl MetricsSnapshot {
+    /// Renders the metrics in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        for counter in &self.counters {
+            let _ = writeln!(out, "# HELP {} {}", counter.name, counter.help);
+            let _ = writeln!(out, "# TYPE {} counter", counter.name);
+            let _ = writeln!(out, "{} {}", counter.name, counter.value);
+        }
+        for histogram in &self.histograms {
+            let name = &histogram.name;
+            let _ = writeln!(out, "# HELP {name} {}", histogram.help);
+            let _ = writeln!(out, "# TYPE {name} histogram");
+            for bucket in &histogram.buckets {
+                let _ = writeln!(
+                    out,
+                    "{name}_bucket{{le=\"{}\"}} {}",
+                    bucket.upper_bound, bucket.cumulative_count
+                );
+            }
+            let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", histogram.count);
+            let _ = writeln!(out, "{name}_sum {}", histogram.sum);
+            let _ = writeln!(out, "{name}_count {}", histogram.count);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use super::{MeteredLspAPI, Metrics};
+    use crate::models::LspAPI;
+    use crate::test_utils::MockBreezServer;
+
+    #[test]
+    fn test_metrics_snapshot() {
+        let metrics = Metrics::default();
+        metrics.record_payment(Duration::from_millis(300), true);
+        metrics.record_payment(Duration::from_secs(3), true);
+        metrics.record_payment(Duration::from_secs(600), false);
+
+        let snapshot = metrics.snapshot(4, 1);
+        let counter = |name: &str| {
+            snapshot
+                .counters
+                .iter()
+                .find(|c| c.name == name)
+                .unwrap()
+                .value
+        };
+        assert_eq!(counter("breez_payments_succeeded_total"), 2);
+        assert_eq!(counter("breez_payments_failed_total"), 1);
+        assert_eq!(counter("breez_syncs_succeeded_total"), 0);
+        assert_eq!(counter("breez_lsp_reconnections_total"), 4);
+
+        let payment_duration = &snapshot.histograms[0];
+        assert_eq!(payment_duration.count, 3);
+        assert!((payment_duration.sum - 603.3).abs() < 1e-9);
+        let cumulative = |upper_bound: f64| {
+            payment_duration
+                .buckets
+                .iter()
+                .find(|b| b.upper_bound == upper_bound)
+                .unwrap()
+                .cumulative_count
+        };
+        assert_eq!(cumulative(0.25), 0);
+        assert_eq!(cumulative(0.5), 1);
+        assert_eq!(cumulative(5.0), 2);
+        // Observations above the last bucket are only part of the count
+        assert_eq!(cumulative(120.0), 2);
+
+        let text = snapshot.to_prometheus();
+        assert!(text.contains("# TYPE breez_payments_succeeded_total counter\n"));
+        assert!(text.contains("breez_payments_succeeded_total 2\n"));
+        assert!(text.contains("breez_payment_duration_seconds_bucket{le=\"0.5\"} 1\n"));
+        assert!(text.contains("breez_payment_duration_seconds_bucket{le=\"+Inf\"} 3\n"));
+        assert!(text.contains("breez_payment_duration_seconds_count 3\n"));
+    }
+
+    #[tokio::test]
+    async fn test_metered_lsp_api() -> Result<()> {
+        let metrics = Arc::new(Metrics::default());
+        let lsp_api = MeteredLspAPI {
+            inner: Arc::new(MockBreezServer {}),
+            metrics: metrics.clone(),
+        };
+        lsp_api.list_lsps("node".to_string()).await?;
+        assert_eq!(metrics.lsp_rpc_failures.get(), 0);
+        Ok(())
+    }
+}
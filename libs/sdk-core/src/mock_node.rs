@@ -0,0 +1,827 @@
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use rand::Rng;
+use sdk_common::prelude::*;
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, watch};
+use tokio_stream::Stream;
+use tonic::Streaming;
+
+use crate::backup::{BackupState, BackupTransport};
+use crate::bitcoin::hashes::{sha256, Hash};
+use crate::bitcoin::secp256k1::{ecdsa, schnorr, Message, PublicKey, Secp256k1, SecretKey};
+use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPubKey};
+use crate::bitcoin::Address;
+use crate::error::{SdkError, SdkResult};
+use crate::lightning::ln::PaymentSecret;
+use crate::lightning::util::message_signing;
+use crate::lightning_invoice::{Currency, InvoiceBuilder, RawBolt11Invoice};
+use crate::lsp::LspInformation;
+use crate::models::{
+    ChannelDetails, LnPaymentDetails, MaxChannelAmount, MockNodeConfig, NodeCredentials, NodeState,
+    OpenChannelResponse, Payment, PaymentDetails, PaymentPath, PaymentResponse, PaymentStatus,
+    PaymentType, PrepareRedeemOnchainFundsRequest, PrepareRedeemOnchainFundsResponse, SyncResponse,
+    TlvEntry,
+};
+use crate::node_api::{
+    CreateInvoiceRequest, FetchBolt11Result, NodeAPI, NodeError, NodeResult, PaymentLimits,
+    ProbeResult,
+};
+use crate::signer::Signer;
+use crate::CustomMessage;
+
+/// The weight of the transactions of [MockNode::redeem_onchain_funds], a one input, one output
+/// P2WPKH transaction
+const MOCK_REDEEM_TX_WEIGHT: u64 = 437;
+
+/// The state of the mock nodes created in this process, by node id. It outlives the SDK
+/// instances, so a node reconnected in the same process finds its payments and balances, and
+/// mock nodes can pay each other's invoices.
+static MOCK_NODES: Lazy<StdMutex<HashMap<String, Arc<StdMutex<MockNodeState>>>>> =
+    Lazy::new(|| StdMutex::new(HashMap::new()));
+
+/// An in-memory Lightning node, selected with [crate::NodeConfig::Mock], to exercise the
+/// payment, swap and LNURL flows of the SDK without a Greenlight node.
+///
+/// It has no channels: the balances start from the [MockNodeConfig] and change with the
+/// payments. Invoices of other mock nodes of the process are settled on them, payments to any
+/// other destination succeed right away without a preimage. Its state is lost when the process
+/// exits.
+pub(crate) struct MockNode {
+    signer: Arc<dyn Signer>,
+    network: Network,
+    node_id: String,
+    secret_key: SecretKey,
+    state: Arc<StdMutex<MockNodeState>>,
+}
+
+struct MockNodeState {
+    channels_balance_msat: u64,
+    onchain_balance_msat: u64,
+    inbound_liquidity_msat: u64,
+    connected_peers: HashSet<String>,
+    /// The invoices created by the node, by payment hash
+    invoices: HashMap<String, MockInvoice>,
+    payments: Vec<Payment>,
+    backup: Option<BackupState>,
+}
+
+struct MockInvoice {
+    bolt11: String,
+    preimage: Vec<u8>,
+    paid: bool,
+}
+
+impl MockNode {
+    pub(crate) fn new(
+        config: MockNodeConfig,
+        network: Network,
+        signer: Arc<dyn Signer>,
+    ) -> NodeResult<Self> {
+        // A key of its own, so the node can sign without going through the signer protocol
+        let secret_key = SecretKey::from_slice(
+            &signer.derive_symmetric_key(vec![ChildNumber::from_hardened_idx(1017)?])?,
+        )?;
+        let node_id =
+            hex::encode(PublicKey::from_secret_key(&Secp256k1::new(), &secret_key).serialize());
+        let state = MOCK_NODES
+            .lock()
+            .unwrap()
+            .entry(node_id.clone())
+            .or_insert_with(|| {
+                Arc::new(StdMutex::new(MockNodeState {
+                    channels_balance_msat: config.channels_balance_msat,
+                    onchain_balance_msat: config.onchain_balance_msat,
+                    inbound_liquidity_msat: config.inbound_liquidity_msat,
+                    connected_peers: HashSet::new(),
+                    invoices: HashMap::new(),
+                    payments: Vec::new(),
+                    backup: None,
+                }))
+            })
+            .clone();
+        Ok(Self {
+            signer,
+            network,
+            node_id,
+            secret_key,
+            state,
+        })
+    }
+
+    fn currency(&self) -> Currency {
+        crate::bitcoin::Network::from(self.network).into()
+    }
+
+    /// Moves `amount_msat` out of the channels balance, failing if it's not available
+    fn debit(&self, amount_msat: u64) -> NodeResult<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.channels_balance_msat < amount_msat {
+            return Err(NodeError::InsufficientFunds(format!(
+                "The channels balance is {} msat",
+                state.channels_balance_msat
+            )));
+        }
+        state.channels_balance_msat -= amount_msat;
+        state.inbound_liquidity_msat += amount_msat;
+        Ok(())
+    }
+
+    /// Reverts a [MockNode::debit] for a payment that failed
+    fn refund(&self, amount_msat: u64) {
+        let mut state = self.state.lock().unwrap();
+        state.channels_balance_msat += amount_msat;
+        state.inbound_liquidity_msat = state.inbound_liquidity_msat.saturating_sub(amount_msat);
+    }
+
+    /// Pays the invoice, settling it on the payee if it's a mock node of this process
+    async fn pay_invoice(
+        &self,
+        bolt11: String,
+        amount_msat: Option<u64>,
+        label: Option<String>,
+    ) -> NodeResult<Payment> {
+        let invoice = parse_invoice(&bolt11)?;
+        validate_network(invoice.clone(), self.network)?;
+        let amount_msat = invoice
+            .amount_msat
+            .or(amount_msat)
+            .ok_or(NodeError::generic(
+                "An amount is required to pay an invoice without one",
+            ))?;
+        if invoice.payee_pubkey == self.node_id {
+            return Err(NodeError::PaymentFailed(
+                "The node can't pay its own invoices".into(),
+            ));
+        }
+        if self.state.lock().unwrap().payments.iter().any(|p| {
+            p.id == invoice.payment_hash
+                && p.payment_type == PaymentType::Sent
+                && p.status == PaymentStatus::Complete
+        }) {
+            return Err(NodeError::InvoiceAlreadyPaid);
+        }
+
+        self.debit(amount_msat)?;
+        let preimage = match settle_on_payee(
+            &invoice.payee_pubkey,
+            &invoice.payment_hash,
+            amount_msat,
+            invoice.description.clone(),
+        ) {
+            Ok(preimage) => preimage,
+            Err(e) => {
+                self.refund(amount_msat);
+                return Err(e);
+            }
+        };
+        let payment = mock_payment(
+            PaymentType::Sent,
+            amount_msat,
+            invoice.description,
+            LnPaymentDetails {
+                payment_hash: invoice.payment_hash,
+                label: label.unwrap_or_default(),
+                destination_pubkey: invoice.payee_pubkey,
+                payment_preimage: preimage.map(hex::encode).unwrap_or_default(),
+                bolt11,
+                ..Default::default()
+            },
+        );
+        self.state.lock().unwrap().payments.push(payment.clone());
+        Ok(payment)
+    }
+
+    fn node_state(&self, state: &MockNodeState) -> NodeState {
+        NodeState {
+            id: self.node_id.clone(),
+            block_height: 0,
+            channels_balance_msat: state.channels_balance_msat,
+            onchain_balance_msat: state.onchain_balance_msat,
+            pending_onchain_balance_msat: 0,
+            utxos: vec![],
+            max_payable_msat: state.channels_balance_msat,
+            max_receivable_msat: state.inbound_liquidity_msat,
+            max_single_payment_amount_msat: state.channels_balance_msat,
+            max_chan_reserve_msats: 0,
+            connected_peers: state.connected_peers.iter().cloned().collect(),
+            max_receivable_single_payment_amount_msat: state.inbound_liquidity_msat,
+            total_inbound_liquidity_msats: state.inbound_liquidity_msat,
+            pending_channel_opens_msat: 0,
+            pending_channel_opens: vec![],
+        }
+    }
+}
+
+/// Settles the invoice on the payee if it's a mock node of this process, returning the
+/// preimage. Payments to other destinations succeed without one.
+fn settle_on_payee(
+    payee_pubkey: &str,
+    payment_hash: &str,
+    amount_msat: u64,
+    description: Option<String>,
+) -> NodeResult<Option<Vec<u8>>> {
+    let Some(payee) = MOCK_NODES.lock().unwrap().get(payee_pubkey).cloned() else {
+        return Ok(None);
+    };
+    let mut payee = payee.lock().unwrap();
+    let Some(invoice) = payee.invoices.get_mut(payment_hash) else {
+        return Err(NodeError::PaymentFailed(
+            "The destination has no invoice with this payment hash".into(),
+        ));
+    };
+    if invoice.paid {
+        return Err(NodeError::InvoiceAlreadyPaid);
+    }
+    invoice.paid = true;
+    let preimage = invoice.preimage.clone();
+    let bolt11 = invoice.bolt11.clone();
+    payee.channels_balance_msat += amount_msat;
+    payee.inbound_liquidity_msat = payee.inbound_liquidity_msat.saturating_sub(amount_msat);
+    payee.payments.push(mock_payment(
+        PaymentType::Received,
+        amount_msat,
+        description,
+        LnPaymentDetails {
+            payment_hash: payment_hash.to_string(),
+            destination_pubkey: payee_pubkey.to_string(),
+            payment_preimage: hex::encode(&preimage),
+            bolt11,
+            ..Default::default()
+        },
+    ));
+    Ok(Some(preimage))
+}
+
+fn mock_payment(
+    payment_type: PaymentType,
+    amount_msat: u64,
+    description: Option<String>,
+    data: LnPaymentDetails,
+) -> Payment {
+    Payment {
+        id: data.payment_hash.clone(),
+        payment_type,
+        payment_time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64,
+        amount_msat,
+        fee_msat: 0,
+        status: PaymentStatus::Complete,
+        description,
+        details: PaymentDetails::Ln { data },
+        ..Default::default()
+    }
+}
+
+fn not_supported(operation: &str) -> NodeError {
+    NodeError::Generic(format!("{operation} is not supported by the mock node"))
+}
+
+#[tonic::async_trait]
+impl NodeAPI for MockNode {
+    async fn node_credentials(&self) -> NodeResult<Option<NodeCredentials>> {
+        Ok(None)
+    }
+
+    async fn configure_node(&self, _close_to_address: Option<String>) -> NodeResult<()> {
+        Ok(())
+    }
+
+    async fn create_invoice(&self, request: CreateInvoiceRequest) -> NodeResult<String> {
+        let preimage = request
+            .preimage
+            .unwrap_or_else(|| rand::thread_rng().gen::<[u8; 32]>().to_vec());
+        let payment_hash = sha256::Hash::hash(&preimage);
+        let mut state = self.state.lock().unwrap();
+        if state.invoices.contains_key(&payment_hash.to_string()) {
+            return Err(NodeError::InvoicePreimageAlreadyExists(
+                "An invoice with this preimage already exists".into(),
+            ));
+        }
+
+        let builder = InvoiceBuilder::new(self.currency());
+        let builder = match request.use_description_hash.unwrap_or_default() {
+            true => builder.description_hash(sha256::Hash::hash(request.description.as_bytes())),
+            false => builder.description(request.description),
+        };
+        let mut builder = builder
+            .payment_hash(payment_hash)
+            .payment_secret(PaymentSecret(rand::thread_rng().gen()))
+            .current_timestamp()
+            .expiry_time(Duration::from_secs(request.expiry.unwrap_or(3600) as u64))
+            .min_final_cltv_expiry_delta(request.cltv.unwrap_or(144) as u64);
+        if request.amount_msat > 0 {
+            builder = builder.amount_milli_satoshis(request.amount_msat);
+        }
+        let bolt11 = builder
+            .build_signed(|hash| Secp256k1::new().sign_ecdsa_recoverable(hash, &self.secret_key))
+            .map_err(|e| NodeError::Generic(e.to_string()))?
+            .to_string();
+        state.invoices.insert(
+            payment_hash.to_string(),
+            MockInvoice {
+                bolt11: bolt11.clone(),
+                preimage,
+                paid: false,
+            },
+        );
+        Ok(bolt11)
+    }
+
+    async fn delete_invoice(&self, bolt11: String) -> NodeResult<()> {
+        let payment_hash = parse_invoice(&bolt11)?.payment_hash;
+        self.state.lock().unwrap().invoices.remove(&payment_hash);
+        Ok(())
+    }
+
+    async fn fetch_bolt11(&self, payment_hash: Vec<u8>) -> NodeResult<Option<FetchBolt11Result>> {
+        Ok(self
+            .state
+            .lock()
+            .unwrap()
+            .invoices
+            .get(&hex::encode(payment_hash))
+            .map(|invoice| FetchBolt11Result {
+                bolt11: invoice.bolt11.clone(),
+                payer_amount_msat: None,
+            }))
+    }
+
+    async fn pull_changed(
+        &self,
+        _sync_state: Option<Value>,
+        _match_local_balance: bool,
+    ) -> NodeResult<SyncResponse> {
+        let state = self.state.lock().unwrap();
+        Ok(SyncResponse {
+            sync_state: Value::Null,
+            node_state: self.node_state(&state),
+            payments: state.payments.clone(),
+            channels: vec![],
+        })
+    }
+
+    async fn send_payment(
+        &self,
+        bolt11: String,
+        amount_msat: Option<u64>,
+        label: Option<String>,
+        _exclude: Vec<String>,
+        _limits: PaymentLimits,
+    ) -> NodeResult<Payment> {
+        self.pay_invoice(bolt11, amount_msat, label).await
+    }
+
+    async fn send_spontaneous_payment(
+        &self,
+        node_id: String,
+        amount_msat: u64,
+        _extra_tlvs: Option<Vec<TlvEntry>>,
+        label: Option<String>,
+    ) -> NodeResult<Payment> {
+        let preimage: [u8; 32] = rand::thread_rng().gen();
+        let payment_hash = sha256::Hash::hash(&preimage).to_string();
+        self.debit(amount_msat)?;
+        let payee = MOCK_NODES.lock().unwrap().get(&node_id).cloned();
+        if let Some(payee) = payee {
+            let mut payee = payee.lock().unwrap();
+            payee.channels_balance_msat += amount_msat;
+            payee.inbound_liquidity_msat = payee.inbound_liquidity_msat.saturating_sub(amount_msat);
+            payee.payments.push(mock_payment(
+                PaymentType::Received,
+                amount_msat,
+                None,
+                LnPaymentDetails {
+                    payment_hash: payment_hash.clone(),
+                    destination_pubkey: node_id.clone(),
+                    payment_preimage: hex::encode(preimage),
+                    keysend: true,
+                    ..Default::default()
+                },
+            ));
+        }
+        let payment = mock_payment(
+            PaymentType::Sent,
+            amount_msat,
+            None,
+            LnPaymentDetails {
+                payment_hash,
+                label: label.unwrap_or_default(),
+                destination_pubkey: node_id,
+                payment_preimage: hex::encode(preimage),
+                keysend: true,
+                ..Default::default()
+            },
+        );
+        self.state.lock().unwrap().payments.push(payment.clone());
+        Ok(payment)
+    }
+
+    async fn send_trampoline_payment(
+        &self,
+        bolt11: String,
+        amount_msat: u64,
+        label: Option<String>,
+        _trampoline_node_id: Vec<u8>,
+        _limits: PaymentLimits,
+    ) -> NodeResult<Payment> {
+        self.pay_invoice(bolt11, Some(amount_msat), label).await
+    }
+
+    async fn pay_offer(
+        &self,
+        _offer: String,
+        _amount_msat: Option<u64>,
+        _payer_note: Option<String>,
+        _label: Option<String>,
+    ) -> NodeResult<Payment> {
+        Err(not_supported("Paying BOLT12 offers"))
+    }
+
+    async fn node_id(&self) -> NodeResult<String> {
+        Ok(self.node_id.clone())
+    }
+
+    async fn send_pay(&self, bolt11: String, _max_hops: u32) -> NodeResult<PaymentResponse> {
+        let payment = self.pay_invoice(bolt11, None, None).await?;
+        let PaymentDetails::Ln { data } = payment.details else {
+            return Err(NodeError::generic("Unexpected payment details"));
+        };
+        Ok(PaymentResponse {
+            payment_time: payment.payment_time,
+            amount_msat: payment.amount_msat,
+            fee_msat: payment.fee_msat,
+            payment_hash: data.payment_hash,
+            payment_preimage: data.payment_preimage,
+        })
+    }
+
+    async fn probe_payment(
+        &self,
+        _node_id: Vec<u8>,
+        amount_msat: u64,
+        _route_hint: Option<RouteHintHop>,
+        _final_cltv_delta: u64,
+        _timeout_sec: u32,
+    ) -> NodeResult<ProbeResult> {
+        let channels_balance_msat = self.state.lock().unwrap().channels_balance_msat;
+        let reachable = amount_msat <= channels_balance_msat;
+        Ok(ProbeResult {
+            fee_msat: 0,
+            hops: 1,
+            reachable,
+            failure: (!reachable).then(|| "Insufficient channels balance".to_string()),
+        })
+    }
+
+    async fn max_sendable_amount<'a>(
+        &self,
+        _payee_node_id: Option<Vec<u8>>,
+        _max_hops: u32,
+        _last_hop: Option<&'a RouteHintHop>,
+    ) -> NodeResult<Vec<MaxChannelAmount>> {
+        Ok(vec![MaxChannelAmount {
+            channel_id: "mock".to_string(),
+            amount_msat: self.state.lock().unwrap().channels_balance_msat,
+            path: PaymentPath { edges: vec![] },
+        }])
+    }
+
+    async fn redeem_onchain_funds(
+        &self,
+        to_address: String,
+        sat_per_vbyte: u32,
+        amount_sat: Option<u64>,
+    ) -> NodeResult<Vec<u8>> {
+        let prepared = self
+            .prepare_redeem_onchain_funds(PrepareRedeemOnchainFundsRequest {
+                to_address,
+                sat_per_vbyte,
+                amount_sat: amount_sat.map(Sat),
+            })
+            .await?;
+        let mut state = self.state.lock().unwrap();
+        state.onchain_balance_msat -= (prepared.amount_sat + prepared.tx_fee_sat).to_msat().msat();
+        Ok(rand::thread_rng().gen::<[u8; 32]>().to_vec())
+    }
+
+    async fn prepare_redeem_onchain_funds(
+        &self,
+        req: PrepareRedeemOnchainFundsRequest,
+    ) -> NodeResult<PrepareRedeemOnchainFundsResponse> {
+        let tx_fee_sat = MOCK_REDEEM_TX_WEIGHT * req.sat_per_vbyte as u64 / 4;
+        let onchain_balance_sat = self.state.lock().unwrap().onchain_balance_msat / 1000;
+        let amount_sat = req
+            .amount_sat
+            .map(Sat::sat)
+            .unwrap_or(onchain_balance_sat.saturating_sub(tx_fee_sat));
+        if amount_sat == 0 || amount_sat + tx_fee_sat > onchain_balance_sat {
+            return Err(NodeError::InsufficientFunds(format!(
+                "The onchain balance is {onchain_balance_sat} sat"
+            )));
+        }
+        Ok(PrepareRedeemOnchainFundsResponse {
+            tx_weight: MOCK_REDEEM_TX_WEIGHT,
+            tx_fee_sat: Sat(tx_fee_sat),
+            amount_sat: Sat(amount_sat),
+            change_sat: Sat(onchain_balance_sat - amount_sat - tx_fee_sat),
+        })
+    }
+
+    async fn bump_redeem_onchain_funds(
+        &self,
+        _txid: Vec<u8>,
+        _to_address: String,
+        _sat_per_vbyte: u32,
+        _amount_sat: Option<u64>,
+    ) -> NodeResult<Vec<u8>> {
+        Err(not_supported("Bumping transactions"))
+    }
+
+    async fn new_onchain_address(&self) -> NodeResult<String> {
+        let public_key = crate::bitcoin::PublicKey::new(PublicKey::from_secret_key(
+            &Secp256k1::new(),
+            &self.secret_key,
+        ));
+        Ok(Address::p2wpkh(&public_key, self.network.into())
+            .map_err(|e| NodeError::Generic(e.to_string()))?
+            .to_string())
+    }
+
+    async fn start_signer(&self, mut shutdown: mpsc::Receiver<()>) {
+        // The node signs by itself, there are no signing requests to serve
+        _ = shutdown.recv().await;
+    }
+
+    async fn start_keep_alive(&self, mut shutdown: watch::Receiver<()>) {
+        _ = shutdown.changed().await;
+    }
+
+    async fn connect_peer(&self, node_id: String, _addr: String) -> NodeResult<()> {
+        self.state.lock().unwrap().connected_peers.insert(node_id);
+        Ok(())
+    }
+
+    async fn ping_peer(&self, node_id: String) -> NodeResult<()> {
+        match self
+            .state
+            .lock()
+            .unwrap()
+            .connected_peers
+            .contains(&node_id)
+        {
+            true => Ok(()),
+            false => Err(NodeError::ServiceConnectivity(format!(
+                "Peer {node_id} is not connected"
+            ))),
+        }
+    }
+
+    async fn sign_invoice(&self, invoice: RawBolt11Invoice) -> NodeResult<String> {
+        let secp = Secp256k1::new();
+        Ok(invoice
+            .sign::<_, ()>(|hash| Ok(secp.sign_ecdsa_recoverable(hash, &self.secret_key)))
+            .map_err(|_| NodeError::generic("Failed to sign the invoice"))?
+            .to_string())
+    }
+
+    async fn close_peer_channels(&self, _node_id: String) -> NodeResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn list_channels(&self) -> NodeResult<Vec<ChannelDetails>> {
+        Ok(vec![])
+    }
+
+    async fn close_channel(&self, _channel_id: String, _force: bool) -> NodeResult<Option<String>> {
+        Err(not_supported("Closing channels"))
+    }
+
+    async fn open_channel(
+        &self,
+        _node_id: String,
+        amount_sat: u64,
+        _announce: bool,
+        _sat_per_vbyte: Option<u32>,
+    ) -> NodeResult<OpenChannelResponse> {
+        let mut state = self.state.lock().unwrap();
+        if state.onchain_balance_msat < amount_sat * 1000 {
+            return Err(NodeError::InsufficientFunds(format!(
+                "The onchain balance is {} sat",
+                state.onchain_balance_msat / 1000
+            )));
+        }
+        // The funds are moved to the channels balance right away
+        state.onchain_balance_msat -= amount_sat * 1000;
+        state.channels_balance_msat += amount_sat * 1000;
+        Ok(OpenChannelResponse {
+            channel_id: hex::encode(rand::thread_rng().gen::<[u8; 32]>()),
+            funding_txid: hex::encode(rand::thread_rng().gen::<[u8; 32]>()),
+        })
+    }
+
+    async fn stream_incoming_payments(
+        &self,
+    ) -> NodeResult<Streaming<gl_client::signer::model::greenlight::IncomingPayment>> {
+        Err(not_supported("Streaming the incoming payments"))
+    }
+
+    async fn stream_log_messages(
+        &self,
+    ) -> NodeResult<Streaming<gl_client::signer::model::greenlight::LogEntry>> {
+        Err(not_supported("Streaming the node logs"))
+    }
+
+    async fn static_backup(&self) -> NodeResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn execute_command(&self, _command: String) -> NodeResult<Value> {
+        Err(not_supported("Executing node commands"))
+    }
+
+    async fn generate_diagnostic_data(&self) -> NodeResult<Value> {
+        let state = self.state.lock().unwrap();
+        Ok(json!({
+            "node_state": self.node_state(&state),
+            "invoices": state.invoices.len(),
+            "payments": state.payments.len(),
+        }))
+    }
+
+    async fn sign_message(&self, message: &str) -> NodeResult<String> {
+        message_signing::sign(message.as_bytes(), &self.secret_key)
+            .map_err(|e| NodeError::Generic(e.to_string()))
+    }
+
+    async fn check_message(
+        &self,
+        message: &str,
+        pubkey: &str,
+        signature: &str,
+    ) -> NodeResult<bool> {
+        let pk = pubkey
+            .parse::<PublicKey>()
+            .map_err(|e| NodeError::Generic(e.to_string()))?;
+        Ok(message_signing::verify(message.as_bytes(), signature, &pk))
+    }
+
+    async fn send_custom_message(&self, _message: CustomMessage) -> NodeResult<()> {
+        Ok(())
+    }
+
+    async fn stream_custom_messages(
+        &self,
+    ) -> NodeResult<Pin<Box<dyn Stream<Item = Result<CustomMessage>> + Send>>> {
+        Ok(Box::pin(tokio_stream::pending()))
+    }
+
+    async fn derive_xpub(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPubKey> {
+        Ok(self.signer.derive_xpub(path)?)
+    }
+
+    async fn sign_ecdsa(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<ecdsa::Signature> {
+        Ok(self.signer.sign_ecdsa(path, message)?)
+    }
+
+    async fn sign_taproot(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<schnorr::Signature> {
+        Ok(self.signer.sign_taproot(path, message)?)
+    }
+
+    async fn ecdh(&self, path: Vec<ChildNumber>, public_key: PublicKey) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.ecdh(path, public_key)?)
+    }
+
+    async fn hmac_sha256(&self, path: Vec<ChildNumber>, input: Vec<u8>) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.hmac_sha256(path, input)?)
+    }
+
+    async fn derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.derive_symmetric_key(path)?)
+    }
+
+    async fn legacy_derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]> {
+        Ok(self.signer.legacy_derive_symmetric_key(path)?)
+    }
+
+    async fn get_routing_hints(
+        &self,
+        _lsp_info: &LspInformation,
+    ) -> NodeResult<(Vec<RouteHint>, bool)> {
+        Ok((vec![], false))
+    }
+
+    async fn get_open_peers(&self) -> NodeResult<HashSet<Vec<u8>>> {
+        // The node acts as if it had a channel with each connected peer
+        Ok(self
+            .state
+            .lock()
+            .unwrap()
+            .connected_peers
+            .iter()
+            .filter_map(|peer| hex::decode(peer).ok())
+            .collect())
+    }
+}
+
+/// Keeps the backups of the SDK state along with the mock node state
+#[tonic::async_trait]
+impl BackupTransport for MockNode {
+    async fn pull(&self) -> SdkResult<Option<BackupState>> {
+        Ok(self.state.lock().unwrap().backup.clone())
+    }
+
+    async fn push(&self, version: Option<u64>, data: Vec<u8>) -> SdkResult<u64> {
+        let mut state = self.state.lock().unwrap();
+        let remote_version = state.backup.as_ref().map(|b| b.generation);
+        if remote_version.is_some() && remote_version != version {
+            return Err(SdkError::generic("Backup version mismatch"));
+        }
+        let generation = version.map_or(1, |v| v + 1);
+        state.backup = Some(BackupState { generation, data });
+        Ok(generation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+    use sdk_common::prelude::*;
+
+    use super::MockNode;
+    use crate::greenlight::SeedSigner;
+    use crate::models::{MockNodeConfig, PaymentStatus, PaymentType};
+    use crate::node_api::{CreateInvoiceRequest, NodeAPI, NodeError, PaymentLimits};
+
+    fn mock_node(seed: u8, channels_balance_msat: u64) -> Result<MockNode> {
+        let signer = SeedSigner::new(vec![seed; 64], Network::Regtest)?;
+        Ok(MockNode::new(
+            MockNodeConfig {
+                channels_balance_msat,
+                onchain_balance_msat: 0,
+                inbound_liquidity_msat: 1_000_000_000,
+            },
+            Network::Regtest,
+            Arc::new(signer),
+        )?)
+    }
+
+    #[tokio::test]
+    async fn test_mock_nodes_pay_each_other() -> Result<()> {
+        let payer = mock_node(1, 100_000_000)?;
+        let payee = mock_node(2, 0)?;
+        let bolt11 = payee
+            .create_invoice(CreateInvoiceRequest {
+                amount_msat: 30_000_000,
+                description: "test".to_string(),
+                payer_amount_msat: None,
+                preimage: None,
+                use_description_hash: None,
+                expiry: None,
+                cltv: None,
+                fallback_address: None,
+            })
+            .await?;
+        assert!(bolt11.starts_with("lnbcrt"));
+
+        let limits = PaymentLimits {
+            timeout_sec: 60,
+            maxfee_percent: 1.0,
+            exemptfee_msat: 20_000,
+        };
+        let sent = payer
+            .send_payment(bolt11.clone(), None, None, vec![], limits)
+            .await?;
+        assert_eq!(sent.status, PaymentStatus::Complete);
+        assert!(matches!(
+            payer.send_payment(bolt11, None, None, vec![], limits).await,
+            Err(NodeError::InvoiceAlreadyPaid)
+        ));
+
+        let payer_sync = payer.pull_changed(None, false).await?;
+        assert_eq!(payer_sync.node_state.channels_balance_msat, 70_000_000);
+        let payee_sync = payee.pull_changed(None, false).await?;
+        assert_eq!(payee_sync.node_state.channels_balance_msat, 30_000_000);
+        let received = &payee_sync.payments[0];
+        assert_eq!(received.payment_type, PaymentType::Received);
+        assert_eq!(received.id, sent.id);
+        Ok(())
+    }
+}
@@ -23,11 +23,23 @@ use crate::bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
 use crate::bitcoin::{Address, Script};
 use crate::error::SdkResult;
 use crate::lsp::LspInformation;
-use crate::swap_out::boltzswap::{BoltzApiCreateReverseSwapResponse, BoltzApiReverseSwapStatus};
+use crate::swap_out::boltzswap::{
+    BoltzApiCreateReverseSwapResponse, BoltzApiCreateTaprootReverseSwapResponse,
+    BoltzApiReverseSwapStatus,
+};
 use crate::swap_out::error::{ReverseSwapError, ReverseSwapResult};
+use crate::swap_out::taproot::{BoltzApiPartialSignature, TaprootReverseSwap};
 
 pub const SWAP_PAYMENT_FEE_EXPIRY_SECONDS: u32 = 60 * 60 * 24 * 2; // 2 days
 pub const INVOICE_PAYMENT_FEE_EXPIRY_SECONDS: u32 = 60 * 60; // 60 minutes
+/// The default maximum number of route hints, besides the LSP one, added to received invoices
+pub const DEFAULT_MAX_ROUTE_HINTS: u32 = 3;
+/// How long a queued payment is retried by default, see [QueuePaymentRequest::expiry_secs]
+pub const DEFAULT_OUTBOX_EXPIRY_SECS: u64 = 60 * 60 * 24; // 24 hours
+/// The default number of payments of a [SendPaymentsRequest] sent at the same time
+pub const DEFAULT_SEND_PAYMENTS_CONCURRENCY: u32 = 4;
+/// The maximum amount of a single payment, as channels are not wumbo
+pub const MAX_PAYMENT_AMOUNT_MSAT: u64 = 4294967000;
 
 /// Different types of supported payments
 #[derive(
@@ -146,6 +158,9 @@ pub struct FullReverseSwapInfo {
 
     /// The HODL invoice
     pub invoice: String,
+
+    /// The redeem script of a [ReverseSwapProtocol::Legacy] reverse swap, or the claim leaf of a
+    /// [ReverseSwapProtocol::Taproot] one
     pub redeem_script: String,
 
     /// Amount of sats that will be locked.
@@ -161,9 +176,28 @@ pub struct FullReverseSwapInfo {
     /// Amount that will be received onchain in the destination address, at the end of the reverse swap.
     pub receive_amount_sat: Option<u64>,
 
+    /// The protocol with which the funds are locked
+    pub protocol: ReverseSwapProtocol,
+
+    /// The Boltz key of a [ReverseSwapProtocol::Taproot] reverse swap, aggregated with ours
+    pub refund_pubkey: Option<String>,
+
     pub cache: ReverseSwapInfoCached,
 }
 
+/// The protocol with which the funds of a reverse swap are locked onchain
+#[derive(
+    Clone, Copy, Debug, Default, Display, EnumString, Eq, PartialEq, Serialize, Deserialize,
+)]
+pub enum ReverseSwapProtocol {
+    /// The funds are locked in a P2WSH HTLC, claimed by revealing the preimage
+    #[default]
+    Legacy,
+    /// The funds are locked in a taproot output, claimed together with Boltz with MuSig2. The
+    /// script path revealing the preimage is kept as fallback.
+    Taproot,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ReverseSwapInfoCached {
     pub status: ReverseSwapStatus,
@@ -171,6 +205,7 @@ pub struct ReverseSwapInfoCached {
     pub claim_txid: Option<String>,
 }
 
+#[cfg_attr(not(feature = "reverse-swaps"), allow(dead_code))]
 impl FullReverseSwapInfo {
     /// Builds the expected redeem script
     pub(crate) fn build_expected_reverse_swap_script(
@@ -290,10 +325,18 @@ impl FullReverseSwapInfo {
         }
     }
 
-    /// Derives the lockup address from the redeem script
+    /// Derives the lockup address from the redeem script, or from the script tree of a taproot
+    /// reverse swap
     pub(crate) fn get_lockup_address(&self, network: Network) -> ReverseSwapResult<Address> {
-        let redeem_script = Script::from_hex(&self.redeem_script)?;
-        Ok(Address::p2wsh(&redeem_script, network.into()))
+        match self.protocol {
+            ReverseSwapProtocol::Legacy => {
+                let redeem_script = Script::from_hex(&self.redeem_script)?;
+                Ok(Address::p2wsh(&redeem_script, network.into()))
+            }
+            ReverseSwapProtocol::Taproot => {
+                TaprootReverseSwap::from_reverse_swap(self)?.lockup_address(network.into())
+            }
+        }
     }
 
     /// Get the preimage hash sent in the create request
@@ -310,6 +353,7 @@ impl FullReverseSwapInfo {
             claim_txid: self.cache.claim_txid.clone(),
             onchain_amount_sat: self.onchain_amount_sat,
             status: self.cache.status,
+            protocol: self.protocol,
         }
     }
 }
@@ -325,6 +369,7 @@ pub struct ReverseSwapInfo {
     pub claim_txid: Option<String>,
     pub onchain_amount_sat: u64,
     pub status: ReverseSwapStatus,
+    pub protocol: ReverseSwapProtocol,
 }
 
 /// The possible statuses of a reverse swap, from the Breez SDK perspective.
@@ -429,6 +474,32 @@ pub(crate) trait ReverseSwapServiceAPI: Send + Sync {
         routing_node: String,
     ) -> ReverseSwapResult<BoltzApiCreateReverseSwapResponse>;
 
+    /// Creates a reverse swap locking the funds in a taproot output, with the v2 API of the
+    /// remote service (Boltz).
+    ///
+    /// # Arguments
+    ///
+    /// * `send_amount_sat` - Amount that is to be swapped
+    /// * `preimage_hash_hex` - Hex of preimage hash
+    /// * `claim_pubkey` - Pubkey of a keypair that can allow the SDK to claim the locked funds
+    async fn create_taproot_reverse_swap_on_remote(
+        &self,
+        send_amount_sat: u64,
+        preimage_hash_hex: String,
+        claim_pubkey: String,
+    ) -> ReverseSwapResult<BoltzApiCreateTaprootReverseSwapResponse>;
+
+    /// Gets the nonce and partial signature of Boltz for the input at `index` of the claim tx of
+    /// a taproot reverse swap, so it can be claimed with a key path spend
+    async fn get_claim_partial_signature(
+        &self,
+        id: String,
+        index: u32,
+        claim_tx_hex: String,
+        preimage_hex: String,
+        pub_nonce_hex: String,
+    ) -> ReverseSwapResult<BoltzApiPartialSignature>;
+
     /// Performs a live lookup of the reverse swap's status on the Boltz API
     async fn get_boltz_status(&self, id: String) -> ReverseSwapResult<BoltzApiReverseSwapStatus>;
 
@@ -441,6 +512,8 @@ pub(crate) trait ReverseSwapServiceAPI: Send + Sync {
 pub struct LogEntry {
     pub line: String,
     pub level: String,
+    /// The module the entry was logged from, for example `breez_sdk_core::breez_services`
+    pub module: String,
 }
 
 /// Configuration for the Breez Services
@@ -451,6 +524,9 @@ pub struct LogEntry {
 pub struct Config {
     pub breezserver: String,
     pub chainnotifier_url: String,
+    /// The LNURL-pay server the lightning address is registered with, see
+    /// [crate::BreezServices::register_lightning_address]
+    pub lnurl_server_url: String,
     /// If set, this is the mempool.space URL that will be used.
     ///
     /// If not set, a list of mempool.space URLs will be used to provide fault-tolerance. If calls
@@ -458,17 +534,96 @@ pub struct Config {
     ///
     /// Note that, if specified, the URL has to be in the format: `https://mempool.space/api`
     pub mempoolspace_url: Option<String>,
+    /// If set, this chain service is used to monitor the chain instead of mempool.space, and
+    /// `mempoolspace_url` is ignored.
+    pub chain_service: Option<ChainServiceConfig>,
+    /// If set, the SDK state is backed up to this store instead of the Greenlight node.
+    ///
+    /// Ignored if a transport is set with [crate::BreezServicesBuilder::backup_transport].
+    pub backup_transport: Option<BackupTransportConfig>,
+    /// If set, the connections to the Breez server and LSP, the swap service, the fiat rates,
+    /// LNURL and the chain service are made through this SOCKS5 proxy.
+    ///
+    /// The connection to the Greenlight node is not affected.
+    pub proxy: Option<ProxyConfig>,
     /// Directory in which all SDK files (DB, log) are stored. Defaults to ".", otherwise if it's customized,
     /// the folder should exist before starting the SDK.
     pub working_dir: String,
+    /// If set, the SQLite DBs are stored in this directory instead of the `working_dir`. The
+    /// folder should exist before starting the SDK.
+    pub db_dir: Option<String>,
+    /// If set, the encrypted node credentials are stored in a file in this directory instead of
+    /// in the DB, for example to keep them out of the app backups. The folder should exist
+    /// before starting the SDK.
+    pub credentials_dir: Option<String>,
     pub network: Network,
+    /// How long a payment is attempted for, retrying other routes until it expires. It can be
+    /// overridden per payment with [SendPaymentRequest::payment_timeout_sec].
     pub payment_timeout_sec: u32,
+    /// How many outgoing payments can be in flight at the same time. The payments over the limit
+    /// wait for one of them to complete. The payments to the same destination are always sent
+    /// one after the other.
+    pub max_concurrent_payments: u32,
     pub default_lsp_id: Option<String>,
+    /// If set, the LSP is selected by the SDK according to this policy on startup and on each
+    /// sync, so the fees of the LSPs are re-evaluated. A [crate::BreezEvent::LspChanged] is
+    /// emitted when another LSP is selected.
+    ///
+    /// This overrides the LSP selected with [crate::BreezServices::connect_lsp].
+    pub lsp_policy: Option<LspPolicy>,
     pub api_key: Option<String>,
-    /// Maps to the CLN `maxfeepercent` config when paying invoices (`lightning-pay`)
+    /// Maps to the CLN `maxfeepercent` config when paying invoices (`lightning-pay`). It can be
+    /// overridden per payment with [SendPaymentRequest::maxfee_percent].
     pub maxfee_percent: f64,
-    /// Maps to the CLN `exemptfee` config when paying invoices (`lightning-pay`)
+    /// Maps to the CLN `exemptfee` config when paying invoices (`lightning-pay`). It can be
+    /// overridden per payment with [SendPaymentRequest::exemptfee_msat].
     pub exemptfee_msat: u64,
+    /// If set, the exchange rate of this fiat currency, for example `USD`, is recorded in
+    /// [Payment::fiat_rate] when a payment settles
+    pub fiat_currency: Option<String>,
+    /// If set, a [crate::BreezEvent::LowOutboundLiquidity] is emitted when the amount that can be
+    /// sent drops below this threshold
+    pub low_outbound_liquidity_threshold_msat: Option<u64>,
+    /// If set, a [crate::BreezEvent::LowInboundLiquidity] is emitted when the amount that can be
+    /// received without a new channel drops below this threshold
+    pub low_inbound_liquidity_threshold_msat: Option<u64>,
+    /// If set, [crate::BreezServices::send_payment] attempts trampoline payments by default,
+    /// unless overridden by [SendPaymentRequest::use_trampoline]
+    pub use_trampoline: bool,
+    /// If set, a partner fee is paid after each outgoing Lightning payment
+    pub partner_fee: Option<PartnerFeeConfig>,
+    /// If set, the refundable swaps are refunded automatically, so the funds are not left in the
+    /// swap addresses when nobody is around to call [crate::BreezServices::refund]. A
+    /// [crate::BreezEvent::SwapRefunded] is emitted for each refund.
+    pub swap_auto_refund: Option<AutoRefundConfig>,
+    /// How the descriptions of the invoices are kept private, see [MemoPrivacy]
+    pub memo_privacy: MemoPrivacy,
+    /// If set, a [crate::BreezEvent::ChannelHygieneSuggestion] is emitted for the channels that
+    /// were not used and whose peer was not connected during this many seconds, so small or
+    /// unreachable channels can be closed before onchain fees rise
+    pub channel_hygiene_window_secs: Option<u64>,
+    /// If set, the log filter applied when connecting, in the `RUST_LOG` syntax, for example
+    /// `info,breez_sdk_core=debug`. See [crate::BreezServices::set_log_filter].
+    pub log_filter: Option<String>,
+    /// If set, a denylist of payment destinations is fetched from this URL and cached, and
+    /// [crate::BreezServices::send_payment] and [crate::BreezServices::lnurl_pay] refuse to pay
+    /// the destinations in it, unless [SendPaymentRequest::skip_denylist] is set.
+    ///
+    /// The URL serves a JSON array of node pubkeys, lightning addresses and LNURL domains. A
+    /// denied domain also denies its subdomains. The list is refreshed daily, and payments are
+    /// not blocked while no list could be fetched yet.
+    pub denylist_url: Option<String>,
+    /// The API key of a Transak partner account, required to buy Bitcoin with
+    /// [BuyBitcoinProvider::Transak]
+    pub transak_api_key: Option<String>,
+    /// If set, the limits enforced on the outgoing payments, see [SpendPolicy]
+    pub spend_policy: Option<SpendPolicy>,
+    /// If set, the bounds on the HTLCs of the outgoing payments, see [HtlcExposureLimits]
+    pub htlc_exposure_limits: Option<HtlcExposureLimits>,
+    /// If set, the LSP, the Greenlight node and the swapper are checked in the background every
+    /// this many seconds, and a [crate::BreezEvent::ServiceStatusChanged] is emitted when one of
+    /// them degrades or recovers
+    pub service_status_check_interval_secs: Option<u64>,
     pub node_config: NodeConfig,
 }
 
@@ -477,14 +632,36 @@ impl Config {
         Config {
             breezserver: PRODUCTION_BREEZSERVER_URL.to_string(),
             chainnotifier_url: "https://chainnotifier.breez.technology".to_string(),
+            lnurl_server_url: "https://breez.fun".to_string(),
             mempoolspace_url: None,
+            chain_service: None,
+            backup_transport: None,
+            proxy: None,
             working_dir: ".".to_string(),
+            db_dir: None,
+            credentials_dir: None,
             network: Bitcoin,
             payment_timeout_sec: 60,
+            max_concurrent_payments: 10,
             default_lsp_id: None,
+            lsp_policy: None,
             api_key: Some(api_key),
             maxfee_percent: 1.0,
             exemptfee_msat: 20000,
+            fiat_currency: None,
+            low_outbound_liquidity_threshold_msat: None,
+            low_inbound_liquidity_threshold_msat: None,
+            use_trampoline: false,
+            partner_fee: None,
+            swap_auto_refund: None,
+            memo_privacy: MemoPrivacy::Plain,
+            channel_hygiene_window_secs: None,
+            log_filter: None,
+            denylist_url: None,
+            transak_api_key: None,
+            spend_policy: None,
+            htlc_exposure_limits: None,
+            service_status_check_interval_secs: None,
             node_config,
         }
     }
@@ -493,22 +670,256 @@ impl Config {
         Config {
             breezserver: STAGING_BREEZSERVER_URL.to_string(),
             chainnotifier_url: "https://chainnotifier.breez.technology".to_string(),
+            lnurl_server_url: "https://breez.fun".to_string(),
             mempoolspace_url: None,
+            chain_service: None,
+            backup_transport: None,
+            proxy: None,
             working_dir: ".".to_string(),
+            db_dir: None,
+            credentials_dir: None,
             network: Bitcoin,
             payment_timeout_sec: 60,
+            max_concurrent_payments: 10,
             default_lsp_id: None,
+            lsp_policy: None,
             api_key: Some(api_key),
             maxfee_percent: 0.5,
             exemptfee_msat: 20000,
+            fiat_currency: None,
+            low_outbound_liquidity_threshold_msat: None,
+            low_inbound_liquidity_threshold_msat: None,
+            use_trampoline: false,
+            partner_fee: None,
+            swap_auto_refund: None,
+            memo_privacy: MemoPrivacy::Plain,
+            channel_hygiene_window_secs: None,
+            log_filter: None,
+            denylist_url: None,
+            transak_api_key: None,
+            spend_policy: None,
+            htlc_exposure_limits: None,
+            service_status_check_interval_secs: None,
             node_config,
         }
     }
+
+    /// A config for a local regtest stack, with the services at their
+    /// [REGTEST_BREEZSERVER_URL], [REGTEST_CHAINNOTIFIER_URL], [REGTEST_LNURL_SERVER_URL] and
+    /// [REGTEST_ESPLORA_URL] defaults. Override the URLs to match the stack, and use
+    /// [NodeConfig::Mock] to run without a Greenlight node.
+    pub fn regtest(api_key: String, node_config: NodeConfig) -> Self {
+        Config {
+            breezserver: REGTEST_BREEZSERVER_URL.to_string(),
+            chainnotifier_url: REGTEST_CHAINNOTIFIER_URL.to_string(),
+            lnurl_server_url: REGTEST_LNURL_SERVER_URL.to_string(),
+            mempoolspace_url: None,
+            chain_service: Some(ChainServiceConfig::Esplora {
+                url: REGTEST_ESPLORA_URL.to_string(),
+            }),
+            network: Regtest,
+            maxfee_percent: 1.0,
+            ..Config::staging(api_key, node_config)
+        }
+    }
+
+    /// The directory the SQLite DBs are stored in
+    pub(crate) fn db_dir(&self) -> String {
+        self.db_dir
+            .clone()
+            .unwrap_or_else(|| self.working_dir.clone())
+    }
+}
+
+/// How the descriptions (memos) of the invoices are kept off the node and the local payments
+/// DB, for users who don't want them stored outside their device, see [Config::memo_privacy]
+///
+/// It applies to the invoices created with [crate::BreezServices::receive_payment], whose
+/// description is given to the Greenlight node, and to the descriptions of the payments
+/// persisted locally, which are also part of the backups. The descriptions are
+/// still in the `bolt11` of the payments, and the invoices with
+/// [ReceivePaymentRequest::use_description_hash] keep theirs, as the hash has to match it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemoPrivacy {
+    /// The descriptions are kept as they are
+    #[default]
+    Plain,
+    /// The descriptions are replaced with `sha256:<hex>` of their SHA-256, so a known memo can
+    /// still be matched. The payer sees the hash as the description of the invoice.
+    Hash,
+    /// The descriptions are removed
+    Strip,
+}
+
+/// The prefix of the descriptions hashed by [MemoPrivacy::Hash]
+const HASHED_MEMO_PREFIX: &str = "sha256:";
+
+impl MemoPrivacy {
+    /// Applies the privacy mode to a description. Descriptions that are already hashed are kept,
+    /// so the invoices created with [MemoPrivacy::Hash] are not hashed again when synced.
+    pub(crate) fn apply(&self, description: Option<String>) -> Option<String> {
+        match self {
+            MemoPrivacy::Plain => description,
+            MemoPrivacy::Hash => description.map(|d| match d.starts_with(HASHED_MEMO_PREFIX) {
+                true => d,
+                false => format!(
+                    "{HASHED_MEMO_PREFIX}{}",
+                    sha256::Hash::hash(d.as_bytes()).to_hex()
+                ),
+            }),
+            MemoPrivacy::Strip => None,
+        }
+    }
+
+    /// Applies the privacy mode to the description of a payment before it is persisted
+    pub(crate) fn apply_to_payment(&self, payment: Payment) -> Payment {
+        Payment {
+            description: self.apply(payment.description.clone()),
+            ..payment
+        }
+    }
+}
+
+/// How the SDK selects the LSP, see [Config::lsp_policy]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LspPolicy {
+    /// The LSP with the lowest minimum channel opening fee, best for small payments
+    LowestOpeningFee,
+    /// The LSP with the lowest proportional channel opening fee, best for large payments
+    LowestProportionalFee,
+    /// The given LSP. If it isn't available, the selected LSP is kept.
+    Pinned { lsp_id: String },
+}
+
+/// The fee of a partner, for example the provider of a white-label wallet, see
+/// [Config::partner_fee]
+///
+/// The fee is paid as a separate payment to the `destination` once a payment succeeds, so it
+/// isn't included in the amount or in the routing fees of the payment. The fee payments have the
+/// `partner-fee` label.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartnerFeeConfig {
+    /// The fee in basis points of the payment amount, for example 50 for 0.5%
+    pub fee_bps: u32,
+    pub destination: PartnerFeeDestination,
+}
+
+/// Where the [PartnerFeeConfig] is paid to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartnerFeeDestination {
+    /// Paid with keysend to the node
+    NodeId { node_id: String },
+    /// Paid with LNURL-pay to the lightning address, requires the `lnurl` feature
+    LightningAddress { address: String },
+}
+
+/// The partner fee paid after a payment, see [Config::partner_fee]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartnerFeeDetails {
+    pub amount_msat: u64,
+    /// The id of the partner fee [Payment], if it succeeded
+    pub payment_id: Option<String>,
+    /// Why the partner fee payment failed. The payment the fee applies to is not affected.
+    pub error: Option<String>,
+}
+
+/// How the refundable swaps are refunded, see [Config::swap_auto_refund]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AutoRefundConfig {
+    /// The address the swaps are refunded to, on [Config::network]. If not set, they are refunded
+    /// to the on-chain wallet of the node.
+    pub to_address: Option<String>,
+    /// The fee rate of the refund transactions. If not set, the recommended fee rate for a
+    /// confirmation within an hour is used.
+    pub sat_per_vbyte: Option<u32>,
+}
+
+/// Limits on the outgoing payments, see [Config::spend_policy]
+///
+/// They are enforced by [crate::BreezServices::send_payment],
+/// [crate::BreezServices::send_spontaneous_payment], [crate::BreezServices::pay_offer],
+/// [crate::BreezServices::lnurl_pay] and [crate::BreezServices::pay_onchain], which fail with a
+/// `SpendPolicyViolation` error for a payment exceeding them.
+///
+/// The routing fees and the [Config::partner_fee] payments count towards the daily limit: a
+/// payment is checked with its max routing fee, then counted with the fee it was sent with. The
+/// partner fee payments are not checked against the allowed destinations.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpendPolicy {
+    /// The max amount sent over the last 24 hours, across restarts
+    pub daily_limit_msat: Option<u64>,
+    /// The max amount of a single payment, without the fees
+    pub per_payment_limit_msat: Option<u64>,
+    /// If set, only these destinations can be paid: node pubkeys, lightning addresses, LNURL
+    /// domains and on-chain addresses. An allowed domain also allows its subdomains.
+    pub allowed_destinations: Option<Vec<String>>,
+}
+
+/// Bounds on the HTLCs of the outgoing payments, see [Config::htlc_exposure_limits], to limit
+/// what can be lost to HTLCs stuck along a route.
+///
+/// They are enforced by [crate::BreezServices::send_payment],
+/// [crate::BreezServices::send_spontaneous_payment] and [crate::BreezServices::lnurl_pay],
+/// which fail with an `HtlcExposureExceeded` error for a payment exceeding them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HtlcExposureLimits {
+    /// The max amount of the outgoing payments in flight, including the payment being sent and
+    /// the fees of the pending ones
+    pub max_inflight_msat: Option<u64>,
+    /// The max number of HTLCs of a single payment. A payment needs at least one HTLC per
+    /// channel it's sent through, so a payment only the sum of several channels can cover is
+    /// refused if it needs more channels than this.
+    pub max_htlcs_per_payment: Option<u32>,
+}
+
+pub const REGTEST_BREEZSERVER_URL: &str = "http://localhost:8088";
+pub const REGTEST_CHAINNOTIFIER_URL: &str = "http://localhost:8089";
+pub const REGTEST_LNURL_SERVER_URL: &str = "http://localhost:8090";
+pub const REGTEST_ESPLORA_URL: &str = "http://localhost:3000";
+
+/// A self-hosted chain service to use instead of mempool.space
+#[derive(Clone, Debug)]
+pub enum ChainServiceConfig {
+    /// An Esplora REST API, for example `https://blockstream.info/api`
+    Esplora { url: String },
+    /// An Electrum server, in the format `ssl://host:port` or `tcp://host:port`
+    Electrum { url: String },
+}
+
+/// A self-hosted store for the SDK state backup, to use instead of the Greenlight node
+#[derive(Clone, Debug)]
+pub enum BackupTransportConfig {
+    /// An S3 compatible bucket supporting conditional writes. The backup is stored under the
+    /// `breez-sdk/backup` key.
+    S3 {
+        /// For example `https://s3.eu-west-1.amazonaws.com`
+        endpoint: String,
+        region: String,
+        bucket: String,
+        access_key_id: String,
+        secret_access_key: String,
+    },
+    /// A file on a WebDAV server, for example `https://dav.example.com/breez/backup`
+    WebDav {
+        url: String,
+        username: Option<String>,
+        password: Option<String>,
+    },
 }
 
 #[derive(Clone)]
 pub enum NodeConfig {
-    Greenlight { config: GreenlightNodeConfig },
+    Greenlight {
+        config: GreenlightNodeConfig,
+    },
+    /// An in-memory node for tests and development, whose balances only change with the
+    /// payments to and from it. Invoices of the other mock nodes of the process are settled on
+    /// them, and payments to any other destination succeed right away.
+    ///
+    /// Requires the `mock-node` feature.
+    Mock {
+        config: MockNodeConfig,
+    },
 }
 
 #[derive(Clone, Serialize)]
@@ -518,12 +929,26 @@ pub enum NodeCredentials {
     },
 }
 
+/// How a new Greenlight node is registered when connecting with a seed that has none. Exactly
+/// one of the fields has to be set to register a node.
 #[derive(Clone)]
 pub struct GreenlightNodeConfig {
+    /// The partner credentials, which register nodes without an invite code within the quota
+    /// of the partner. See [crate::error::ConnectError::RegistrationQuotaExceeded].
     pub partner_credentials: Option<GreenlightCredentials>,
     pub invite_code: Option<String>,
 }
 
+/// The starting state of a [NodeConfig::Mock] node. A node reconnected in the same process
+/// keeps its state instead.
+#[derive(Clone, Debug, Default)]
+pub struct MockNodeConfig {
+    pub channels_balance_msat: u64,
+    pub onchain_balance_msat: u64,
+    /// How much the node can receive over Lightning
+    pub inbound_liquidity_msat: u64,
+}
+
 /// Indicates the different kinds of supported environments for [crate::BreezServices].
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, EnumString)]
 pub enum EnvironmentType {
@@ -531,6 +956,9 @@ pub enum EnvironmentType {
     Production,
     #[strum(serialize = "staging")]
     Staging,
+    /// A local regtest stack, see [Config::regtest]
+    #[strum(serialize = "regtest")]
+    Regtest,
 }
 
 /// Client-specific credentials to connect to and manage a Greenlight node in the cloud
@@ -552,10 +980,52 @@ pub struct ConfigureNodeRequest {
     pub close_to_address: Option<String>,
 }
 
+/// Options for the SDK log file, see [crate::BreezServices::init_logging_with_options]
+#[derive(Clone, Debug)]
+pub struct LogFileOptions {
+    /// Location where the SDK log file will be created. The directory must already exist.
+    pub log_dir: String,
+    /// If set, the log file is rotated once it grows over this size
+    pub max_log_size_bytes: Option<u64>,
+    /// The number of rotated log files to keep besides the current one. Defaults to 1.
+    ///
+    /// The log files take at most `max_log_size_bytes * (max_rotated_files + 1)` of storage.
+    pub max_rotated_files: Option<u32>,
+}
+
 /// Represents a connect request.
 pub struct ConnectRequest {
     pub config: Config,
     pub seed: Vec<u8>,
+    /// Deprecated, use `register_if_missing` instead, which wins when both are set.
+    ///
+    /// If true, only restores an existing node and otherwise result in an error
+    pub restore_only: Option<bool>,
+    /// Whether a new node should be registered when no node exists for the `seed`. Defaults to
+    /// true. Restore wallet flows should set it to false, so that connecting results in a
+    /// [crate::error::ConnectError::RestoreOnly] error instead of registering a new empty node.
+    pub register_if_missing: Option<bool>,
+}
+
+impl ConnectRequest {
+    /// Whether only an existing node may be restored. `register_if_missing` takes precedence
+    /// over the deprecated `restore_only` when it is set.
+    pub(crate) fn is_restore_only(&self) -> bool {
+        match self.register_if_missing {
+            Some(register_if_missing) => !register_if_missing,
+            None => self.restore_only.unwrap_or(false),
+        }
+    }
+}
+
+/// Represents a connect request for a node whose keys are held by a [crate::Signer].
+pub struct ConnectWithSignerRequest {
+    pub config: Config,
+    /// The credentials of the node, as returned by [crate::BreezServices::node_credentials].
+    /// If not set, the credentials stored on a previous connect are used. A node can only be
+    /// registered or recovered by a signer holding the seed, so other signers need them on the
+    /// first connect.
+    pub node_credentials: Option<NodeCredentials>,
     /// If true, only restores an existing node and otherwise result in an error
     pub restore_only: Option<bool>,
 }
@@ -566,6 +1036,9 @@ pub enum PaymentTypeFilter {
     Sent,
     Received,
     ClosedChannel,
+    /// The received keysend payments, which carry no invoice, see
+    /// [LnPaymentDetails::spontaneous_metadata]
+    SpontaneousReceived,
 }
 
 /// A metadata filter which can be applied when retrieving the transaction list
@@ -573,11 +1046,13 @@ pub struct MetadataFilter {
     /// Specifies which field to apply the filter on, using the JSON path format
     pub json_path: String,
     /// Specifies which JSON value to filter for.
-    /// As such, strings must be wrapped with quotes ("") in order to be properly filtered
-    pub json_value: String,
+    /// As such, strings must be wrapped with quotes ("") in order to be properly filtered.
+    /// When not set, all the payments whose metadata contains the field are returned.
+    pub json_value: Option<String>,
 }
 
 /// Different types of supported feerates
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FeeratePreset {
     Regular,
     Economy,
@@ -597,6 +1072,30 @@ impl TryFrom<i32> for FeeratePreset {
     }
 }
 
+/// The unit in which amounts are shown to the user
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Denomination {
+    Btc,
+    Sat,
+}
+
+/// User preferences persisted by the SDK. They are included in the backup, so they roam
+/// across devices with the wallet.
+///
+/// The SDK doesn't interpret them beyond:
+/// - `preferred_fiat_currency` is used to record the fiat rate of payments when
+///   [Config::fiat_currency] is not set
+/// - `preferred_lsp_id` is used to select the LSP on startup when none was selected yet, instead
+///   of [Config::default_lsp_id]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserSettings {
+    pub preferred_fiat_currency: Option<String>,
+    pub denomination: Option<Denomination>,
+    pub default_feerate_preset: Option<FeeratePreset>,
+    pub preferred_lsp_id: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct BackupStatus {
     pub backed_up: bool,
@@ -631,6 +1130,323 @@ pub struct NodeState {
 
     /// Total receivable on all available channels
     pub total_inbound_liquidity_msats: u64,
+
+    /// The local balance of the channels being opened, not included in `channels_balance_msat`
+    /// until their funding tx confirms. For example, a payment received with a JIT channel is
+    /// only spendable once its channel is opened.
+    #[serde(default)]
+    pub pending_channel_opens_msat: u64,
+
+    /// The channels being opened, see `pending_channel_opens_msat`. Set on each full sync.
+    #[serde(default)]
+    pub pending_channel_opens: Vec<PendingChannelOpen>,
+}
+
+/// A channel whose funding tx is not confirmed yet, see [NodeState::pending_channel_opens]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PendingChannelOpen {
+    pub funding_txid: String,
+    /// The local balance of the channel, available once it's opened
+    pub amount_msat: u64,
+    /// Epoch time, in seconds, when the channel was first seen pending
+    pub seen_at: u64,
+    /// Estimated epoch time, in seconds, when the funding tx confirms
+    pub expected_confirmation_at: u64,
+}
+
+/// The funds of the node, split by state, see [crate::BreezServices::balances]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Balances {
+    /// What can be sent over Lightning now, taking the channel reserves into account
+    pub lightning_spendable_msat: u64,
+    /// Outgoing Lightning payments in flight, including their fees. The payments of the reverse
+    /// swaps are counted in `reverse_swaps_locked_msat` instead.
+    pub lightning_pending_msat: u64,
+    pub onchain_confirmed_msat: u64,
+    pub onchain_unconfirmed_msat: u64,
+    /// Deposited to swap addresses and not yet paid out over Lightning, or waiting to be
+    /// refunded
+    pub swaps_locked_msat: u64,
+    /// Paid to the reverse swaps in progress and not yet claimed onchain
+    pub reverse_swaps_locked_msat: u64,
+}
+
+/// The funds of the node with the channel reserves and HTLCs that explain the difference between
+/// the balances and what can be sent, see [crate::BreezServices::get_balance_breakdown]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BalanceBreakdown {
+    /// What can be sent over Lightning now, the sum of the spendable amounts of the opened
+    /// channels
+    pub spendable_msat: u64,
+    /// The channels not yet closed, with their spendable amounts, reserves and HTLCs
+    pub channels: Vec<ChannelDetails>,
+    /// The reserves of the opened channels, not spendable until they're closed
+    pub channel_reserves_msat: u64,
+    pub htlcs_out_msat: u64,
+    pub htlcs_in_msat: u64,
+    /// The part of the HTLCs below the dust limits of their channels
+    pub dust_htlcs_msat: u64,
+    /// The local balance of the channels being opened, see
+    /// [NodeState::pending_channel_opens_msat]
+    pub pending_channel_opens_msat: u64,
+    pub onchain_confirmed_msat: u64,
+    pub onchain_unconfirmed_msat: u64,
+    /// See [Balances::swaps_locked_msat]
+    pub swaps_locked_msat: u64,
+    /// See [Balances::reverse_swaps_locked_msat]
+    pub reverse_swaps_locked_msat: u64,
+}
+
+impl BalanceBreakdown {
+    pub(crate) fn new(
+        channels: Vec<ChannelDetails>,
+        pending_channel_opens_msat: u64,
+        balances: Balances,
+    ) -> Self {
+        let opened = || channels.iter().filter(|c| c.state == ChannelState::Opened);
+        Self {
+            spendable_msat: opened().map(|c| c.spendable_msat).sum(),
+            channel_reserves_msat: opened().map(|c| c.reserve_msat).sum(),
+            htlcs_out_msat: channels.iter().map(|c| c.htlcs_out_msat).sum(),
+            htlcs_in_msat: channels.iter().map(|c| c.htlcs_in_msat).sum(),
+            dust_htlcs_msat: channels.iter().map(|c| c.dust_htlcs_msat).sum(),
+            pending_channel_opens_msat,
+            onchain_confirmed_msat: balances.onchain_confirmed_msat,
+            onchain_unconfirmed_msat: balances.onchain_unconfirmed_msat,
+            swaps_locked_msat: balances.swaps_locked_msat,
+            reverse_swaps_locked_msat: balances.reverse_swaps_locked_msat,
+            channels,
+        }
+    }
+}
+
+/// A value that differs between two snapshots, see [crate::diff_snapshots]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SnapshotChange {
+    /// The dot separated path of the value, like `node_state.channels_balance_msat`
+    pub path: String,
+    /// The JSON encoded value in the first snapshot, if it is there
+    pub before: Option<String>,
+    /// The JSON encoded value in the second snapshot, if it is there
+    pub after: Option<String>,
+}
+
+/// The connectivity to the LSP peer, returned by [crate::BreezServices::peer_connectivity]
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct PeerConnectivity {
+    /// The node id of the selected LSP, if any
+    pub lsp_pubkey: Option<String>,
+    /// Whether the LSP answered the ping
+    pub lsp_connected: bool,
+    /// The round trip time of the ping, if it was answered
+    pub ping_rtt_ms: Option<u64>,
+    /// Why the ping failed, if it did
+    pub ping_error: Option<String>,
+    /// How many times the LSP peer was found disconnected and reconnected since connect
+    pub reconnection_attempts: u32,
+    /// How many of the `reconnection_attempts` failed
+    pub failed_reconnection_attempts: u32,
+    /// Epoch time, in seconds, of the last successful reconnection
+    pub last_reconnected_at: Option<i64>,
+    /// The error of the last failed reconnection
+    pub last_reconnection_error: Option<String>,
+}
+
+/// Whether the node and the LSP peer are reachable, see [ConnectionStatus]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub enum ConnectionState {
+    #[default]
+    Connected,
+    /// The connection was lost, the first reconnection attempt is not scheduled yet
+    Disconnected,
+    /// Waiting for, or running, a reconnection attempt
+    Reconnecting,
+}
+
+/// The connection to the node and the LSP peer, returned by
+/// [crate::BreezServices::connection_status]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ConnectionStatus {
+    pub state: ConnectionState,
+    /// Epoch time, in seconds, since which the connection is in this state
+    pub since: i64,
+    /// The reconnection attempts since the connection was lost
+    pub reconnect_attempts: u32,
+    /// Why the connection was lost, or why the last reconnection attempt failed
+    pub last_error: Option<String>,
+}
+
+/// Whether a background task is running, see [TaskStatus]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum TaskState {
+    Running,
+    /// Waiting to be restarted after it failed
+    Restarting,
+    /// Completed, on disconnect or once its work is done
+    Stopped,
+}
+
+/// The health of a background task of the SDK, like the sync loop or the swap monitor, returned
+/// by [crate::BreezServices::task_status]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TaskStatus {
+    pub name: String,
+    pub state: TaskState,
+    /// How many times the task was restarted after it failed since connect
+    pub restarts: u32,
+    /// The error of the last failure, or the message of the panic, if any
+    pub last_error: Option<String>,
+    /// Epoch time, in seconds, of the last failure
+    pub last_error_at: Option<i64>,
+}
+
+/// The counters and histograms of the SDK operations since connect, returned by
+/// [crate::BreezServices::metrics]. See [MetricsSnapshot::to_prometheus] to expose them to
+/// Prometheus.
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricsSnapshot {
+    pub counters: Vec<MetricCounter>,
+    pub histograms: Vec<MetricHistogram>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricCounter {
+    /// The Prometheus metric name, for example `breez_payments_succeeded_total`
+    pub name: String,
+    pub help: String,
+    pub value: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricHistogram {
+    /// The Prometheus metric name, for example `breez_payment_duration_seconds`
+    pub name: String,
+    pub help: String,
+    pub buckets: Vec<MetricBucket>,
+    /// The sum of the observed values
+    pub sum: f64,
+    /// The number of observed values, including those above the last bucket
+    pub count: u64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricBucket {
+    pub upper_bound: f64,
+    /// The number of observed values up to `upper_bound`
+    pub cumulative_count: u64,
+}
+
+/// The reusable LNURL-pay code and lightning address of the wallet, see
+/// [crate::BreezServices::register_lightning_address]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LnurlPayInfo {
+    /// The bech32 encoded LNURL-pay code, to be shown as a QR code
+    pub lnurl: String,
+    /// The lightning address, for example `alice@breez.fun`
+    pub lightning_address: String,
+}
+
+/// Request to [crate::BreezServices::create_static_lnurl_pay]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateStaticLnurlPayRequest {
+    /// The URL the endpoint is hosted at, which serves [StaticLnurlPay::pay_request]
+    pub url: String,
+    /// The URL the payers request the invoices from, whose requests are answered with
+    /// [crate::BreezServices::static_lnurl_pay_callback]
+    pub callback_url: String,
+    /// The description shown to the payers, for example `Donations to Alice`
+    pub description: String,
+    pub min_sendable_msat: MilliSat,
+    pub max_sendable_msat: MilliSat,
+    /// The max length of the comment the payers can add, or no comments if not set
+    pub comment_allowed: Option<u16>,
+}
+
+/// A static LNURL-pay endpoint backed by the wallet, see
+/// [crate::BreezServices::create_static_lnurl_pay]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StaticLnurlPay {
+    /// The bech32 encoded LNURL of [CreateStaticLnurlPayRequest::url], to be printed as a QR code
+    pub lnurl: String,
+    /// The LUD-06 metadata, whose hash is the description hash of the invoices
+    pub metadata: String,
+    /// The JSON response to serve at [CreateStaticLnurlPayRequest::url]
+    pub pay_request: String,
+}
+
+/// Request to [crate::BreezServices::static_lnurl_pay_callback], with the query parameters of
+/// the request to the callback URL
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaticLnurlPayCallbackRequest {
+    /// The `amount` parameter
+    pub amount_msat: MilliSat,
+    /// The `comment` parameter, if any
+    pub comment: Option<String>,
+}
+
+/// A domain the wallet authenticated to with LNURL-auth, see
+/// [crate::BreezServices::list_lnurl_auth_domains]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LnUrlAuthDomain {
+    pub domain: String,
+    /// The hex encoded LUD-05 linking public key, the identity of the wallet on the domain
+    pub linking_key: String,
+    pub first_authenticated_at: i64,
+    pub last_authenticated_at: i64,
+    pub auth_count: u32,
+}
+
+/// The LNURL-auth identity of the wallet and its domain history, exported with
+/// [crate::BreezServices::export_lnurl_auth] to be imported on another device restored from the
+/// same seed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LnUrlAuthExport {
+    /// The hex encoded public key of the LUD-05 hashing key, from which the linking key of each
+    /// domain is derived. It identifies the seed without revealing it.
+    pub hashing_key: String,
+    pub domains: Vec<LnUrlAuthDomain>,
+}
+
+/// Represents a request to create an LNURL-withdraw voucher paid from the balance of the node,
+/// see [crate::BreezServices::create_lnurl_withdraw_voucher]
+#[derive(Clone, Debug)]
+pub struct CreateLnurlWithdrawVoucherRequest {
+    /// The amount the claimant can withdraw on each use
+    pub amount_msat: MilliSat,
+    /// How many times the voucher can be redeemed
+    pub uses: u32,
+    /// How long the voucher can be redeemed for, in seconds
+    pub expiry_secs: u32,
+    /// The description shown to the claimant
+    pub description: Option<String>,
+}
+
+/// An LNURL-withdraw voucher hosted by the node, see
+/// [crate::BreezServices::create_lnurl_withdraw_voucher]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LnurlWithdrawVoucher {
+    /// The `k1` of the voucher, identifying it when it's redeemed
+    pub id: String,
+    /// The bech32 encoded LNURL-withdraw code, to be handed to the claimant
+    pub lnurl: String,
+    pub amount_msat: u64,
+    pub uses: u32,
+    pub redeemed_uses: u32,
+    pub description: Option<String>,
+    /// Epoch time, in seconds
+    pub created_at: i64,
+    /// Epoch time, in seconds, after which the voucher can't be redeemed
+    pub expires_at: i64,
+}
+
+/// Represents a request to pay the invoice of the claimant of a voucher, see
+/// [crate::BreezServices::redeem_lnurl_withdraw_voucher]
+#[derive(Clone, Debug)]
+pub struct RedeemLnurlWithdrawVoucherRequest {
+    /// The [LnurlWithdrawVoucher::id] of the voucher
+    pub id: String,
+    /// The invoice of the claimant
+    pub bolt11: String,
 }
 
 /// Internal response to a [crate::node_api::NodeAPI::pull_changed] call
@@ -651,7 +1467,7 @@ pub enum PaymentStatus {
 }
 
 /// Represents a payment, including its [PaymentType] and [PaymentDetails]
-#[derive(Default, PartialEq, Eq, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, PartialEq, Debug, Clone, Serialize, Deserialize)]
 pub struct Payment {
     pub id: String,
     pub payment_type: PaymentType,
@@ -664,10 +1480,80 @@ pub struct Payment {
     pub description: Option<String>,
     pub details: PaymentDetails,
     pub metadata: Option<String>,
+    /// The exchange rate of the [Config::fiat_currency] when the payment settled, if recorded
+    pub fiat_rate: Option<Rate>,
+    /// Whether the payment was made outside of this node and added with
+    /// [crate::BreezServices::import_payments]
+    pub imported: bool,
+}
+
+/// An exchange rate recorded at `timestamp`, as returned by
+/// [crate::BreezServices::fiat_rate_history]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HistoricalRate {
+    pub coin: String,
+    pub value: f64,
+    /// Epoch time, in seconds
+    pub timestamp: i64,
+}
+
+/// The side of its threshold the exchange rate has to cross for a [FiatRateAlert] to trigger
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FiatRateAlertDirection {
+    Above = 0,
+    Below = 1,
+}
+
+impl TryFrom<i32> for FiatRateAlertDirection {
+    type Error = anyhow::Error;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(FiatRateAlertDirection::Above),
+            1 => Ok(FiatRateAlertDirection::Below),
+            _ => Err(anyhow!("illegal value")),
+        }
+    }
+}
+
+/// Represents a [crate::BreezServices::add_fiat_rate_alert] request.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AddFiatRateAlertRequest {
+    /// The fiat currency code, for example `USD`
+    pub currency: String,
+    pub direction: FiatRateAlertDirection,
+    /// The exchange rate of one BTC in the fiat currency
+    pub threshold: f64,
+}
+
+/// A price alert checked every time the exchange rates are fetched, see
+/// [crate::BreezServices::add_fiat_rate_alert]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FiatRateAlert {
+    pub id: i64,
+    pub currency: String,
+    pub direction: FiatRateAlertDirection,
+    pub threshold: f64,
+    /// Epoch time, in seconds
+    pub created_at: i64,
+    /// Whether the rate is past the threshold since the alert last triggered. The alert
+    /// triggers again only after the rate moved back to the other side of the threshold.
+    pub triggered: bool,
+    /// Epoch time, in seconds
+    pub last_triggered_at: Option<i64>,
+}
+
+impl FiatRateAlert {
+    pub(crate) fn is_crossed(&self, rate: f64) -> bool {
+        match self.direction {
+            FiatRateAlertDirection::Above => rate > self.threshold,
+            FiatRateAlertDirection::Below => rate < self.threshold,
+        }
+    }
 }
 
 /// Represents a payments external information.
-#[derive(Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct PaymentExternalInfo {
     pub lnurl_pay_success_action: Option<SuccessActionProcessed>,
     pub lnurl_pay_domain: Option<String>,
@@ -691,6 +1577,92 @@ pub struct ListPaymentsRequest {
     pub include_failures: Option<bool>,
     pub offset: Option<u32>,
     pub limit: Option<u32>,
+    /// If set, the raw values of the custom TLV records of received payments are included as
+    /// [TlvRecord::value_hex]
+    pub include_tlv_hex: Option<bool>,
+    /// The order of the payments by time, the newest first by default
+    pub sort_order: Option<SortOrder>,
+}
+
+/// The counterparty by which [crate::BreezServices::list_payment_groups] groups the payments
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, EnumString)]
+pub enum PaymentGroupBy {
+    /// The [LnPaymentDetails::destination_pubkey] of sent payments
+    #[strum(serialize = "destination_pubkey")]
+    DestinationPubkey,
+    /// The [LnPaymentDetails::ln_address] of payments to a lightning address
+    #[strum(serialize = "ln_address")]
+    LnAddress,
+}
+
+/// Represents a list payment groups request.
+pub struct ListPaymentGroupsRequest {
+    pub group_by: PaymentGroupBy,
+    /// The payments to aggregate. Its `offset` and `limit` apply to the payments, before grouping.
+    pub payments: ListPaymentsRequest,
+    /// The maximum number of groups returned, all by default
+    pub limit: Option<u32>,
+}
+
+/// The completed sent payments to a counterparty, as returned by
+/// [crate::BreezServices::list_payment_groups]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PaymentGroup {
+    /// The destination pubkey or lightning address, depending on [PaymentGroupBy]
+    pub key: String,
+    pub payments_count: u32,
+    pub total_amount_msat: u64,
+    pub total_fee_msat: u64,
+    /// Epoch time, in seconds
+    pub first_payment_time: i64,
+    /// Epoch time, in seconds
+    pub last_payment_time: i64,
+}
+
+/// The order of a list
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    #[default]
+    Descending,
+    Ascending,
+}
+
+/// The file formats supported by [crate::BreezServices::export_payments]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumString)]
+pub enum ExportFormat {
+    #[strum(serialize = "csv")]
+    Csv,
+    #[strum(serialize = "json")]
+    Json,
+}
+
+/// Represents an export payments request.
+#[derive(Clone, Debug)]
+pub struct ExportPaymentsRequest {
+    pub format: ExportFormat,
+    /// Epoch time, in seconds
+    pub from_timestamp: Option<i64>,
+    /// Epoch time, in seconds
+    pub to_timestamp: Option<i64>,
+    /// The id of the fiat currency, for example `USD`, in which the payment amounts are also
+    /// exported. If not set, no fiat values are exported.
+    pub include_fiat: Option<String>,
+}
+
+/// Represents an import payments request.
+#[derive(Clone, Debug)]
+pub struct ImportPaymentsRequest {
+    pub format: ExportFormat,
+    /// The payments, in the format of [crate::BreezServices::export_payments]
+    pub data: String,
+}
+
+/// Represents an import payments response.
+#[derive(Clone, Debug, Serialize)]
+pub struct ImportPaymentsResponse {
+    pub imported_count: u32,
+    /// The payments skipped because a payment with the same id already exists
+    pub skipped_count: u32,
 }
 
 /// Represents a payment response.
@@ -749,6 +1721,10 @@ pub struct LnPaymentDetails {
     /// Represents the actual invoice paid by the sender
     pub open_channel_bolt11: Option<String>,
 
+    /// Only set for [PaymentType::Received] payments which required to open a channel.
+    /// The fee charged by the LSP for opening the channel, included in the payment `fee_msat`
+    pub channel_opening_fee_msat: Option<u64>,
+
     /// Only set for [PaymentType::Sent] payments that are part of a LNURL-pay workflow where
     /// the endpoint returns a success action
     pub lnurl_success_action: Option<SuccessActionProcessed>,
@@ -776,6 +1752,32 @@ pub struct LnPaymentDetails {
 
     /// Only set for [PaymentStatus::Pending] payments that are inflight.
     pub pending_expiration_block: Option<u32>,
+
+    /// Only set for [PaymentType::Received] payments that carried custom TLV records, like
+    /// keysend messages or podcast boostagrams
+    pub tlvs: Option<Vec<TlvRecord>>,
+
+    /// Only set for [PaymentType::Received] keysend payments, with the sender metadata found in
+    /// their custom TLV records
+    pub spontaneous_metadata: Option<SpontaneousPaymentMetadata>,
+}
+
+/// The fee charged by the LSP to open a channel for a received payment, see
+/// [crate::BreezServices::list_channel_fees_paid]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelOpeningFee {
+    /// The hash of the payment received through the opened channel
+    pub payment_hash: String,
+    /// Epoch time, in seconds
+    pub payment_time: i64,
+    /// The amount paid by the sender
+    pub payer_amount_msat: u64,
+    /// The amount received, after the fee was deducted
+    pub received_amount_msat: u64,
+    pub fee_msat: u64,
+    /// The fee params the channel was opened with. Not set for payments received before they
+    /// were recorded.
+    pub opening_fee_params: Option<OpeningFeeParams>,
 }
 
 /// Represents the funds that were on the user side of the channel at the time it was closed.
@@ -791,7 +1793,7 @@ pub struct ClosedChannelPaymentDetails {
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ReverseSwapFeesRequest {
     /// Amount to be sent
-    pub send_amount_sat: Option<u64>,
+    pub send_amount_sat: Option<Sat>,
     /// Feerate (sat / vByte) for the claim transaction
     pub claim_tx_feerate: Option<u32>,
 }
@@ -810,7 +1812,7 @@ pub struct MaxChannelAmount {
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ReceivePaymentRequest {
     /// The amount in satoshis for this payment request
-    pub amount_msat: u64,
+    pub amount_msat: MilliSat,
     /// The description for this payment request.
     pub description: String,
     /// Optional preimage for this payment request.
@@ -825,6 +1827,53 @@ pub struct ReceivePaymentRequest {
     pub expiry: Option<u32>,
     /// if specified, sets the min_final_cltv_expiry for the invoice
     pub cltv: Option<u32>,
+    /// If set to true, the address of a new swap is included in the invoice as fallback address,
+    /// for payers that prefer to pay onchain.
+    pub include_fallback_address: Option<bool>,
+    /// If specified, sets the maximum number of route hints included in the invoice besides the
+    /// LSP one. The hints of the highest capacity channels are preferred. Fewer hints result in a
+    /// shorter invoice, which is easier to scan as QR code. Defaults to [DEFAULT_MAX_ROUTE_HINTS].
+    pub max_route_hints: Option<u32>,
+    /// If set, the invoice amount is converted from this fiat amount at the current exchange
+    /// rate, instead of using `amount_msat`. The rate is recorded as the [Payment::fiat_rate].
+    pub fiat_amount: Option<FiatAmount>,
+}
+
+/// An amount in a fiat currency, for example 4.5 `USD`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FiatAmount {
+    /// The id of the currency, as in [crate::BreezServices::list_fiat_currencies]
+    pub currency: String,
+    pub amount: f64,
+}
+
+/// Represents a request to preview the fees of receiving a payment, see
+/// [crate::BreezServices::prepare_receive_payment]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PrepareReceivePaymentRequest {
+    /// The amount the payer will pay
+    pub amount_msat: MilliSat,
+    /// If set and valid, these fee options are used when a new channel is needed.
+    /// Otherwise the cheapest fee options valid for `expiry` will be used.
+    pub opening_fee_params: Option<OpeningFeeParams>,
+    /// The time the invoice will be valid for, in seconds
+    pub expiry: Option<u32>,
+}
+
+/// The fees of receiving a payment, returned by [crate::BreezServices::prepare_receive_payment]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrepareReceivePaymentResponse {
+    /// The amount the payer will pay
+    pub amount_msat: MilliSat,
+    /// Whether the LSP has to open a new channel to receive the payment
+    pub open_channel_needed: bool,
+    /// The channel opening fee that will be deducted from the amount, 0 if no channel is needed
+    pub opening_fee_msat: MilliSat,
+    /// If a channel is needed, the [OpeningFeeParams] the fee was calculated with. Pass them to
+    /// [ReceivePaymentRequest::opening_fee_params] to create the invoice with the same fee.
+    pub opening_fee_params: Option<OpeningFeeParams>,
+    /// The amount that will be received, after deducting the opening fee
+    pub received_amount_msat: MilliSat,
 }
 
 /// Represents a receive payment response.
@@ -839,22 +1888,127 @@ pub struct ReceivePaymentResponse {
     /// If set, these are the [OpeningFeeParams] used to calculate the channel opening fees.
     pub opening_fee_params: Option<OpeningFeeParams>,
     /// If set, this is the channel opening fee that will be deduced from the invoice amount.
-    pub opening_fee_msat: Option<u64>,
+    pub opening_fee_msat: Option<MilliSat>,
+    /// The length of the bolt11, which determines how dense its QR code is.
+    pub bolt11_length: u32,
+    /// The [ReceivePaymentRequest::fiat_amount] the invoice amount was converted from, if set
+    pub fiat_amount: Option<FiatAmount>,
+    /// The exchange rate the `fiat_amount` was converted with, if set
+    pub fiat_rate: Option<Rate>,
 }
 
-/// Represents a send payment request.
+/// Represents a request to receive a payment either onchain or over lightning, see
+/// [crate::BreezServices::receive_unified]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ReceiveUnifiedRequest {
+    pub amount_sat: Sat,
+    /// The invoice description, also used as BIP21 label
+    pub description: String,
+    /// If set and valid, these fee options are used when a new channel is needed, whichever way
+    /// the payment is received. Otherwise the default fee options will be used.
+    pub opening_fee_params: Option<OpeningFeeParams>,
+}
+
+/// Represents a unified receive response
 #[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReceiveUnifiedResponse {
+    /// The BIP21 URI with the swap address and the invoice in its `lightning` parameter
+    pub bip21: String,
+    /// The swap receiving the payment if it is paid onchain
+    pub swap_info: SwapInfo,
+    /// The invoice receiving the payment if it is paid over lightning
+    pub ln_invoice: LNInvoice,
+    /// If set, these are the [OpeningFeeParams] used to calculate the channel opening fees.
+    pub opening_fee_params: Option<OpeningFeeParams>,
+    /// If set, this is the channel opening fee that will be deduced from the invoice amount.
+    pub opening_fee_msat: Option<MilliSat>,
+}
+
+/// The limit a receive amount exceeds
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReceiveLimitReason {
+    /// The amount would take the channels balance above the maximum the node accepts to hold,
+    /// `MAX_INBOUND_LIQUIDITY_MSAT` (4,000,000 sat) on Greenlight. This is a policy cap: the LSP
+    /// opens channels with enough inbound liquidity below it.
+    MaxInboundLiquidity,
+    /// The amount is above [crate::MAX_PAYMENT_AMOUNT_MSAT], the maximum a single payment is
+    /// allowed to have
+    MaxPaymentAmount,
+    /// The amount is above [SwapInfo::max_allowed_deposit], the maximum the swap can take in
+    MaxSwapDeposit,
+}
+
+/// Represents a request to amend an unexpired, unpaid invoice of this node.
+///
+/// The payment hash and payment secret of the invoice are preserved, so the node still settles
+/// the amended invoice. Since the node keeps tracking the original invoice, only changes it accepts
+/// are allowed: the amount can only be raised and the expiry can only be shortened.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AmendInvoiceRequest {
+    /// The bolt11 invoice to amend
+    pub bolt11: String,
+    /// If specified, sets the new amount of the invoice
+    pub amount_msat: Option<MilliSat>,
+    /// If specified, sets the new time the invoice is valid for, in seconds from its creation
+    pub expiry: Option<u32>,
+    /// If specified, replaces the route hints of the invoice
+    pub route_hints: Option<Vec<RouteHint>>,
+}
+
+/// Represents a send payment request.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct SendPaymentRequest {
     /// The bolt11 invoice
     pub bolt11: String,
     /// Trampoline payments outsource pathfinding to the LSP. Trampoline payments can improve
     /// payment performance, but are generally more expensive in terms of fees and they
     /// compromise on privacy.
-    pub use_trampoline: bool,
+    ///
+    /// If not set, [Config::use_trampoline] is used.
+    pub use_trampoline: Option<bool>,
     /// The amount to pay in millisatoshis. Should only be set when `bolt11` is a zero-amount invoice.
-    pub amount_msat: Option<u64>,
+    pub amount_msat: Option<MilliSat>,
     /// The external label or identifier of the [Payment]
     pub label: Option<String>,
+    /// If set, the payment is only routed through the route hint at this index of the invoice
+    /// [LNInvoice::routing_hints], for example when another hint points to an unreachable node.
+    /// Trampoline is not used in that case.
+    pub route_hint_index: Option<u32>,
+    /// If set, overrides [Config::payment_timeout_sec] for this payment
+    pub payment_timeout_sec: Option<u32>,
+    /// If set, overrides [Config::maxfee_percent] for this payment
+    pub maxfee_percent: Option<f64>,
+    /// If set, overrides [Config::exemptfee_msat] for this payment
+    pub exemptfee_msat: Option<MilliSat>,
+    /// A token chosen by the caller to cancel the call with [crate::BreezServices::cancel],
+    /// unique among the calls in progress. Not used by [crate::BreezServices::queue_payment].
+    pub cancel_token: Option<String>,
+    /// If true, the payment is made even if the destination is in the denylist, see
+    /// [Config::denylist_url]
+    pub skip_denylist: Option<bool>,
+}
+
+/// Represents a prepare send payment request.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PrepareSendPaymentRequest {
+    /// The bolt11 invoice
+    pub bolt11: String,
+    /// The amount to pay in millisatoshis. Should only be set when `bolt11` is a zero-amount invoice.
+    pub amount_msat: Option<MilliSat>,
+}
+
+/// Represents a prepare send payment response.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrepareSendPaymentResponse {
+    pub bolt11: String,
+    /// The amount the recipient receives, in millisatoshis
+    pub amount_msat: MilliSat,
+    /// The expected routing fee, in millisatoshis
+    pub fee_msat: MilliSat,
+    /// The number of hops of the longest route
+    pub route_hops: u32,
+    /// The number of parts the payment is expected to be split in
+    pub parts: u32,
 }
 
 /// Represents a TLV entry for a keysend payment.
@@ -866,23 +2020,246 @@ pub struct TlvEntry {
     pub value: Vec<u8>,
 }
 
+/// A custom TLV record of a received payment, see [LnPaymentDetails::tlvs]
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
+pub struct TlvRecord {
+    /// The type field of the TLV
+    pub field_number: u64,
+    /// The value decoded as UTF-8 text, if it is valid text
+    pub value_text: Option<String>,
+    /// The hex encoded value, only set if [ListPaymentsRequest::include_tlv_hex] is set
+    pub value_hex: Option<String>,
+}
+
+impl From<&TlvEntry> for TlvRecord {
+    fn from(tlv: &TlvEntry) -> Self {
+        TlvRecord {
+            field_number: tlv.field_number,
+            value_text: String::from_utf8(tlv.value.clone()).ok(),
+            value_hex: Some(hex::encode(&tlv.value)),
+        }
+    }
+}
+
+/// The metadata a sender attached to a keysend payment, see
+/// [LnPaymentDetails::spontaneous_metadata]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SpontaneousPaymentMetadata {
+    /// The text message of TLV record 34349334, or the message of the boostagram
+    pub message: Option<String>,
+    /// The sender node id of TLV record 34349339, if the sender disclosed it
+    pub sender_pubkey: Option<String>,
+    /// The sender name of the boostagram
+    pub sender_name: Option<String>,
+    /// The podcasting 2.0 boostagram JSON of TLV record 7629169, with the podcast, the episode
+    /// and the app it was sent from
+    pub boostagram: Option<String>,
+}
+
+impl SpontaneousPaymentMetadata {
+    const MESSAGE_TLV: u64 = 34349334;
+    const SENDER_PUBKEY_TLV: u64 = 34349339;
+    const BOOSTAGRAM_TLV: u64 = 7629169;
+
+    /// Extracts the sender metadata from the TLV records of a received keysend payment
+    pub(crate) fn from_tlvs(tlvs: &[TlvRecord]) -> Self {
+        let find = |field_number| tlvs.iter().find(|tlv| tlv.field_number == field_number);
+        let boostagram = find(Self::BOOSTAGRAM_TLV).and_then(|tlv| tlv.value_text.clone());
+        let boostagram_field = |field: &str| {
+            boostagram
+                .as_deref()
+                .and_then(|b| serde_json::from_str::<Value>(b).ok())
+                .and_then(|b| b.get(field).and_then(Value::as_str).map(str::to_string))
+                .filter(|value| !value.is_empty())
+        };
+        SpontaneousPaymentMetadata {
+            message: find(Self::MESSAGE_TLV)
+                .and_then(|tlv| tlv.value_text.clone())
+                .or_else(|| boostagram_field("message")),
+            sender_pubkey: find(Self::SENDER_PUBKEY_TLV).and_then(|tlv| tlv.value_hex.clone()),
+            sender_name: boostagram_field("sender_name"),
+            boostagram,
+        }
+    }
+}
+
 /// Represents a send spontaneous payment request.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SendSpontaneousPaymentRequest {
     /// The node id to send this payment is
     pub node_id: String,
     /// The amount in millisatoshis for this payment
-    pub amount_msat: u64,
+    pub amount_msat: MilliSat,
     // Optional extra TLVs
     pub extra_tlvs: Option<Vec<TlvEntry>>,
     /// The external label or identifier of the [Payment]
     pub label: Option<String>,
 }
 
+/// Represents a request to pay a BOLT12 offer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayOfferRequest {
+    /// The BOLT12 offer
+    pub offer: String,
+    /// The amount in millisatoshis to pay. Required when the offer doesn't specify an amount, or
+    /// specifies it in a fiat currency.
+    pub amount_msat: Option<MilliSat>,
+    /// An optional note for the payee, included in the invoice request
+    pub payer_note: Option<String>,
+    /// The external label or identifier of the [Payment]
+    pub label: Option<String>,
+}
+
 /// Represents a send payment response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SendPaymentResponse {
     pub payment: Payment,
+    /// The partner fee paid after the payment, if a [Config::partner_fee] is set
+    pub partner_fee: Option<PartnerFeeDetails>,
+}
+
+/// Represents a [crate::BreezServices::probe_payment] request.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ProbePaymentRequest {
+    /// A BOLT11 invoice or the id of the destination node
+    pub destination: String,
+    /// Required when probing a node id or an invoice without an amount
+    pub amount_msat: Option<MilliSat>,
+}
+
+/// The outcome of a [crate::BreezServices::probe_payment]. Probes don't move funds and are not
+/// listed as payments, but with [crate::BreezServices::list_payment_probes].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentProbe {
+    pub id: i64,
+    pub node_id: String,
+    pub bolt11: Option<String>,
+    pub amount_msat: u64,
+    /// Whether a route to the destination was found
+    pub route_found: bool,
+    /// Whether the probe reached the destination, in which case a payment of the amount is
+    /// likely to succeed
+    pub reachable: bool,
+    /// The fee of the found route
+    pub estimated_fee_msat: Option<u64>,
+    pub hops: Option<u32>,
+    /// Why no route was found or the probe did not reach the destination
+    pub error: Option<String>,
+    /// Epoch time, in seconds
+    pub created_at: i64,
+}
+
+/// Represents a [crate::BreezServices::validate_payability] request.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidatePayabilityRequest {
+    pub bolt11: String,
+    /// The amount to pay, only for invoices without an amount
+    pub amount_msat: Option<MilliSat>,
+}
+
+/// Whether an invoice can be paid by the node, see [crate::BreezServices::validate_payability]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InvoicePayability {
+    pub invoice: LNInvoice,
+    /// The amount that would be paid: the invoice amount, or else the requested one
+    pub amount_msat: Option<u64>,
+    /// Whether the invoice passed all the checks. The payment can still fail, for example when
+    /// no route is found, see [crate::BreezServices::prepare_send_payment].
+    pub is_payable: bool,
+    /// Why the invoice can't be paid, if it can't
+    pub error: Option<String>,
+    /// The amount the node can send now, see [NodeState::max_payable_msat]
+    pub max_payable_msat: u64,
+    /// The amount the payee can receive through the route hints of the invoice, if all of them
+    /// set their max HTLC amount
+    pub payee_inbound_liquidity_msat: Option<u64>,
+    /// The route hint through the LSP of this node, if the invoice has one. Such invoices are
+    /// paid directly instead of with trampoline.
+    pub lsp_route_hint: Option<RouteHint>,
+}
+
+/// Represents a request to queue a payment in the outbox, see [crate::BreezServices::queue_payment]
+#[derive(Clone, Debug)]
+pub struct QueuePaymentRequest {
+    pub req: SendPaymentRequest,
+    /// How long the payment is retried before it is abandoned. Defaults to
+    /// [DEFAULT_OUTBOX_EXPIRY_SECS]. The payment is abandoned anyway once the invoice expires.
+    pub expiry_secs: Option<u64>,
+}
+
+/// A payment queued with [crate::BreezServices::queue_payment], retried until it succeeds or
+/// is abandoned
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct OutboxPayment {
+    pub id: i64,
+    pub req: SendPaymentRequest,
+    /// Epoch time, in seconds
+    pub created_at: i64,
+    /// When the payment is abandoned if it didn't succeed yet, epoch time in seconds
+    pub expires_at: i64,
+    /// The number of attempts so far
+    pub attempts: u32,
+    /// When the payment is attempted next, epoch time in seconds
+    pub next_attempt_at: i64,
+    /// The error of the last attempt
+    pub last_error: Option<String>,
+}
+
+/// The state of an [OutboxPayment], see [crate::BreezEvent::OutboxPaymentUpdated]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum OutboxPaymentStatus {
+    /// The payment is waiting for its next attempt
+    Queued,
+    /// The payment succeeded and was removed from the outbox
+    Sent { payment: Payment },
+    /// The payment failed with an error that retrying won't fix, expired or failed too many
+    /// times, and was removed from the outbox
+    Abandoned,
+    /// The payment was removed from the outbox by
+    /// [crate::BreezServices::cancel_outbox_payment]
+    Cancelled,
+}
+
+/// Represents a batch of payments for [crate::BreezServices::send_payments]
+#[derive(Clone, Debug)]
+pub struct SendPaymentsRequest {
+    pub payments: Vec<SendPaymentRequest>,
+    /// How many payments are sent at the same time. Defaults to
+    /// [DEFAULT_SEND_PAYMENTS_CONCURRENCY].
+    pub max_concurrency: Option<u32>,
+}
+
+/// The outcome of one payment of a [SendPaymentsRequest]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BatchPaymentResult {
+    /// The index of the payment in [SendPaymentsRequest::payments]
+    pub index: u32,
+    pub bolt11: String,
+    /// Set if the payment succeeded
+    pub payment: Option<Payment>,
+    /// Set if the payment failed
+    pub error: Option<String>,
+}
+
+/// The result of the verification of one of the invoices of
+/// [crate::BreezServices::verify_invoices]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct InvoiceVerificationResult {
+    pub bolt11: String,
+    /// Set if the invoice is valid
+    pub invoice: Option<LNInvoice>,
+    /// Why the invoice is invalid, if it isn't
+    pub error: Option<String>,
+}
+
+/// Returned by [crate::BreezServices::send_payments]
+#[derive(Clone, Debug, Serialize)]
+pub struct SendPaymentsResponse {
+    /// The outcome of each payment, in the order of [SendPaymentsRequest::payments]
+    pub results: Vec<BatchPaymentResult>,
+    pub succeeded: u32,
+    pub failed: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -907,6 +2284,26 @@ pub enum HealthCheckStatus {
     ServiceDisruption,
 }
 
+/// The services the SDK depends on, whose status is monitored when
+/// [Config::service_status_check_interval_secs] is set
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ServiceComponent {
+    /// The LSP peer of the node
+    Lsp,
+    /// The Greenlight node
+    Greenlight,
+    /// The swap service, used to receive onchain
+    Swapper,
+}
+
+/// The status of a [ServiceComponent], as seen by the SDK
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ServiceStatus {
+    Operational,
+    /// The component can't be reached or doesn't respond in time
+    Degraded,
+}
+
 /// Represents a service health check response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServiceHealthCheckResponse {
@@ -937,9 +2334,178 @@ pub struct StaticBackupResponse {
     pub backup: Option<Vec<String>>,
 }
 
+/// The version of the SDK and the protocols it supports, see [crate::BreezServices::sdk_info]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SdkInfo {
+    /// The version of the `breez-sdk-core` crate
+    pub version: String,
+    /// The LNURL specs supported, for example `LUD-06`
+    pub supported_luds: Vec<String>,
+    /// The script versions of the swap-in addresses that can be used or refunded. New swaps
+    /// always use the latest one.
+    pub swap_in_script_versions: Vec<String>,
+    /// The protocols of the reverse swaps that can be created or claimed
+    pub reverse_swap_protocols: Vec<ReverseSwapProtocol>,
+    /// The protocols spoken with the LSP
+    pub lsp_protocols: Vec<String>,
+    /// The schema of the backups this SDK version writes. Older SDK versions may not be able to
+    /// restore a backup with a newer schema than theirs.
+    pub backup_schema_version: u32,
+    /// The oldest backup schema this SDK version can restore
+    pub min_backup_schema_version: u32,
+}
+
+/// Represents a close channel request.
+#[derive(Clone, Debug)]
+pub struct CloseChannelRequest {
+    /// The hex encoded id of the channel to close, see [ChannelDetails::channel_id]
+    pub channel_id: String,
+    /// If set, the channel is closed unilaterally when the peer doesn't agree to a mutual close
+    /// right away. Otherwise the node waits up to 48 hours for the peer before closing it
+    /// unilaterally.
+    pub force: bool,
+}
+
+/// Represents a close channel response.
+#[derive(Clone, Debug, Serialize)]
+pub struct CloseChannelResponse {
+    /// The closing tx id. Not set for channels that were closed before their funding tx was broadcast
+    pub closing_txid: Option<String>,
+}
+
+/// Represents a connect peer request.
+#[derive(Clone, Debug)]
+pub struct ConnectPeerRequest {
+    /// The peer to connect to, as `<node id>@<host>:<port>`
+    pub node_uri: String,
+}
+
+/// Represents an open channel request.
+#[derive(Clone, Debug)]
+pub struct OpenChannelRequest {
+    /// The node id of the peer, which must be connected with [crate::BreezServices::connect_peer]
+    pub node_id: String,
+    /// The channel capacity, funded from the onchain balance
+    pub amount_sat: Sat,
+    /// Whether the channel is announced to the network, to route payments of other nodes
+    pub announce: bool,
+    /// The feerate of the funding tx. If not set, the node estimates one
+    pub sat_per_vbyte: Option<u32>,
+}
+
+/// Represents an open channel response.
+#[derive(Clone, Debug, Serialize)]
+pub struct OpenChannelResponse {
+    /// The hex encoded id of the new channel, see [ChannelDetails::channel_id]
+    pub channel_id: String,
+    pub funding_txid: String,
+}
+
+/// Represents a node migration request.
+#[derive(Clone, Debug, Default)]
+pub struct NodeMigrationRequest {
+    /// If true, the channels with the LSP are cooperatively closed as part of the export, so that
+    /// the funds can be moved onchain to the target node.
+    pub close_channels: bool,
+}
+
+/// The exported node state, needed to move the wallet from Greenlight to a self-hosted CLN node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NodeMigrationState {
+    pub node_id: String,
+    pub network: Network,
+    /// The URI of the LSP node, `pubkey@host`, for the target node to connect to it and reopen
+    /// the [NodeMigrationState::channels]
+    pub lsp_node_uri: Option<String>,
+    /// The static channel backup of the node, which can be used to recover the channels on the
+    /// target node as a fallback for channels that could not be closed cooperatively
+    pub static_backup: Vec<String>,
+    /// The channels that were open or pending open at the time of the export
+    pub channels: Vec<MigrationChannel>,
+    /// The txids of the closing transactions, if [NodeMigrationRequest::close_channels] was set
+    pub closing_txids: Vec<String>,
+    pub channels_balance_msat: u64,
+    pub onchain_balance_msat: u64,
+    /// Epoch time, in seconds
+    pub exported_at: u64,
+}
+
+/// A channel included in a [NodeMigrationState].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MigrationChannel {
+    pub funding_txid: String,
+    pub funding_outnum: Option<u32>,
+    pub short_channel_id: Option<String>,
+    pub state: ChannelState,
+    pub local_balance_msat: u64,
+    /// The node id of the channel peer, if the node still knows the channel
+    pub peer_id: Option<String>,
+    /// The capacity of the channel, to reopen one of the same size from the target node
+    pub capacity_msat: Option<u64>,
+}
+
+impl From<Channel> for MigrationChannel {
+    fn from(channel: Channel) -> Self {
+        MigrationChannel {
+            funding_txid: channel.funding_txid,
+            funding_outnum: channel.funding_outnum,
+            short_channel_id: channel.short_channel_id,
+            state: channel.state,
+            local_balance_msat: channel.local_balance_msat,
+            peer_id: None,
+            capacity_msat: None,
+        }
+    }
+}
+
+/// Request to export a [RecoveryBundle], see [crate::BreezServices::export_recovery_bundle]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExportRecoveryBundleRequest {
+    /// The hex encoded public key the bundle is encrypted to, for example the heir's
+    pub recipient_pubkey: String,
+    /// Instructions for the recipient, added to the generic recovery instructions
+    pub instructions: Option<String>,
+    /// If set, the bundle is written to this file, and written again each time the channels
+    /// change, until [crate::BreezServices::stop_recovery_bundle_refresh] is called
+    pub file_path: Option<String>,
+}
+
+/// Response to an [ExportRecoveryBundleRequest]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportRecoveryBundleResponse {
+    /// The [RecoveryBundle] as JSON, encrypted to the recipient public key with ECIES
+    pub bundle: Vec<u8>,
+}
+
+/// Request to decrypt a bundle of [ExportRecoveryBundleResponse]
+#[derive(Clone, Debug)]
+pub struct DecryptRecoveryBundleRequest {
+    pub bundle: Vec<u8>,
+    /// The private key of [ExportRecoveryBundleRequest::recipient_pubkey]
+    pub private_key: Vec<u8>,
+}
+
+/// What someone else needs to recover the funds of the wallet, along with its seed, for example
+/// in an inheritance setup. It doesn't contain the seed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryBundle {
+    pub node_id: String,
+    pub network: Network,
+    /// How to recover the funds, readable without the SDK
+    pub instructions: String,
+    /// The static channel backup of the node, to recover the funds of the channels
+    pub static_backup: Vec<String>,
+    /// The channels that were open or pending open when the bundle was created
+    pub channels: Vec<MigrationChannel>,
+    pub channels_balance_msat: u64,
+    pub onchain_balance_msat: u64,
+    /// Epoch time, in seconds
+    pub created_at: u64,
+}
+
 #[derive(Default)]
 pub struct OpenChannelFeeRequest {
-    pub amount_msat: Option<u64>,
+    pub amount_msat: Option<MilliSat>,
     pub expiry: Option<u32>,
 }
 
@@ -947,7 +2513,7 @@ pub struct OpenChannelFeeRequest {
 pub struct OpenChannelFeeResponse {
     /// Opening fee for receiving the amount set in the [OpenChannelFeeRequest], in case it was set.
     /// It may be zero if no new channel needs to be opened.
-    pub fee_msat: Option<u64>,
+    pub fee_msat: Option<MilliSat>,
     /// The fee params for receiving more than the current inbound liquidity.
     pub fee_params: OpeningFeeParams,
 }
@@ -984,10 +2550,23 @@ pub struct BuyBitcoinResponse {
     pub opening_fee_params: Option<OpeningFeeParams>,
 }
 
+/// Represents a request to compare what buying Bitcoin costs at a provider, see
+/// [crate::BreezServices::fetch_buy_bitcoin_quote]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FetchBuyBitcoinQuoteRequest {
+    pub provider: BuyBitcoinProvider,
+    /// The fiat amount to pay, fees included
+    pub fiat_amount: FiatAmount,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RedeemOnchainFundsRequest {
     pub to_address: String,
     pub sat_per_vbyte: u32,
+    /// The amount to send, the change going back to the wallet. If not set, all the on-chain
+    /// funds are sent.
+    #[serde(default)]
+    pub amount_sat: Option<Sat>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -995,6 +2574,34 @@ pub struct RedeemOnchainFundsResponse {
     pub txid: Vec<u8>,
 }
 
+/// Represents a request to replace an unconfirmed transaction by one paying a higher fee rate,
+/// see [crate::BreezServices::bump_fee]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BumpFeeRequest {
+    /// The txid of the transaction to replace, in hex
+    pub txid: String,
+    /// The new fee rate, which must be higher than the one of the replaced transaction
+    pub sat_per_vbyte: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BumpFeeResponse {
+    /// The txid of the replacement transaction
+    pub txid: String,
+}
+
+/// A payment request handed off to another app of the same wallet, for example from a watch to
+/// the phone. See [crate::BreezServices::encrypt_payment_request].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentRequestBundle {
+    /// The invoice or other input accepted by [parse]
+    pub invoice: String,
+    /// An on-chain address to pay instead, if the invoice can't be paid
+    pub fallback: Option<String>,
+    /// App specific data, for example the item being paid
+    pub metadata: Option<String>,
+}
+
 pub enum SwapAmountType {
     Send,
     Receive,
@@ -1003,7 +2610,7 @@ pub enum SwapAmountType {
 /// See [ReverseSwapFeesRequest]
 pub struct PrepareOnchainPaymentRequest {
     /// Depending on `amount_type`, this may be the desired send amount or the desired receive amount.
-    pub amount_sat: u64,
+    pub amount_sat: Sat,
     pub amount_type: SwapAmountType,
 
     /// Feerate (sat / vByte) for the claim transaction
@@ -1013,11 +2620,11 @@ pub struct PrepareOnchainPaymentRequest {
 #[derive(Serialize)]
 pub struct OnchainPaymentLimitsResponse {
     /// Minimum amount the reverse swap service accepts as a send amount
-    pub min_sat: u64,
+    pub min_sat: Sat,
     /// Maximum amount the reverse swap service accepts as a send amount
-    pub max_sat: u64,
+    pub max_sat: Sat,
     /// Maximum amount this node can send with the current channels and the current local balance
-    pub max_payable_sat: u64,
+    pub max_payable_sat: Sat,
 }
 
 /// Contains fields describing the reverse swap parameters (see [ReverseSwapPairInfo]), as well as
@@ -1029,8 +2636,8 @@ pub struct PrepareOnchainPaymentResponse {
     pub fees_lockup: u64,
     pub fees_claim: u64,
 
-    pub sender_amount_sat: u64,
-    pub recipient_amount_sat: u64,
+    pub sender_amount_sat: Sat,
+    pub recipient_amount_sat: Sat,
     pub total_fees: u64,
 }
 
@@ -1038,6 +2645,9 @@ pub struct PrepareOnchainPaymentResponse {
 pub struct PayOnchainRequest {
     pub recipient_address: String,
     pub prepare_res: PrepareOnchainPaymentResponse,
+    /// A token chosen by the caller to cancel the call with [crate::BreezServices::cancel],
+    /// unique among the calls in progress
+    pub cancel_token: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -1045,11 +2655,43 @@ pub struct PayOnchainResponse {
     pub reverse_swap_info: ReverseSwapInfo,
 }
 
+/// Represents a request to pay an on-chain address in one step, with
+/// [crate::BreezServices::pay_onchain_address].
+///
+/// Exactly one of `amount_sat` and `drain` must be set, unless `address` is a BIP21 URI with an
+/// amount, which is then used when `amount_sat` is not set.
+#[derive(Clone, Debug)]
+pub struct PayOnchainAddressRequest {
+    /// The on-chain address or BIP21 URI to pay
+    pub address: String,
+    /// The amount the recipient receives, fees excluded
+    pub amount_sat: Option<Sat>,
+    /// Whether to send as much as the channels allow, the recipient receiving what is left
+    /// after the fees
+    pub drain: bool,
+    /// The claim transaction feerate. Defaults to [UserSettings::default_feerate_preset], or to
+    /// [FeeratePreset::Regular] if it is not set.
+    pub fee_preference: Option<FeeratePreset>,
+}
+
+/// Returned by [crate::BreezServices::pay_onchain_address]
+#[derive(Serialize)]
+pub struct PayOnchainAddressResponse {
+    /// The amounts and fees of the payment
+    pub prepare_res: PrepareOnchainPaymentResponse,
+    /// The claim transaction feerate (sat/vByte) that was used
+    pub claim_tx_feerate: u32,
+    pub reverse_swap_info: ReverseSwapInfo,
+}
+
 pub struct PrepareRefundRequest {
     pub swap_address: String,
     pub to_address: String,
     pub sat_per_vbyte: u32,
     pub unilateral: Option<bool>,
+    /// If set to true, the response also previews the fees of the refund at each
+    /// [FeeratePreset], using the recommended fees
+    pub include_fee_previews: Option<bool>,
 }
 
 pub struct RefundRequest {
@@ -1061,6 +2703,17 @@ pub struct RefundRequest {
 
 pub struct PrepareRefundResponse {
     pub refund_tx_weight: u32,
+    pub refund_tx_fee_sat: Sat,
+    /// The fees of the refund at each [FeeratePreset], the cheapest first, if
+    /// [PrepareRefundRequest::include_fee_previews] was set
+    pub fee_previews: Vec<RefundFeePreview>,
+}
+
+/// The fee of a refund at a [FeeratePreset], see [PrepareRefundResponse::fee_previews]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RefundFeePreview {
+    pub preset: FeeratePreset,
+    pub sat_per_vbyte: u32,
     pub refund_tx_fee_sat: u64,
 }
 
@@ -1251,6 +2904,34 @@ pub struct Channel {
     pub htlcs: Vec<Htlc>,
 }
 
+/// The details of a channel of the node, as reported by [crate::BreezServices::list_channels]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct ChannelDetails {
+    /// The hex encoded channel id, used to close the channel
+    pub channel_id: String,
+    /// The node id of the channel peer
+    pub peer_id: String,
+    pub peer_connected: bool,
+    pub short_channel_id: Option<String>,
+    pub funding_txid: String,
+    pub state: ChannelState,
+    pub capacity_msat: u64,
+    pub local_balance_msat: u64,
+    pub remote_balance_msat: u64,
+    pub spendable_msat: u64,
+    pub receivable_msat: u64,
+    /// The reserve the peer requires us to keep in the channel. It can't be spent and is only
+    /// returned when the channel is closed.
+    pub reserve_msat: u64,
+    /// The outgoing HTLCs, not yet settled or failed
+    pub htlcs_out_msat: u64,
+    /// The incoming HTLCs, not yet settled or failed
+    pub htlcs_in_msat: u64,
+    /// The part of the HTLCs below the dust limit of the channel. They are not enforceable
+    /// onchain and go to the miners if the channel is force closed.
+    pub dust_htlcs_msat: u64,
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Serialize)]
 pub struct Htlc {
     pub expiry: u32,
@@ -1416,6 +3097,25 @@ pub struct SwapInfo {
     pub confirmed_at: Option<u32>,
 }
 
+/// Request to export the swap refund keys, see [crate::BreezServices::export_swap_refund_keys]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExportSwapRefundKeysRequest {
+    /// Also export the unused keys following the last used one, to cover the swaps missing from
+    /// the database. Defaults to false: only the keys of the known swaps are exported.
+    pub include_gap_keys: Option<bool>,
+}
+
+/// A key refunding the swaps created with it, see [crate::BreezServices::export_swap_refund_keys]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct SwapRefundKey {
+    pub index: u32,
+    /// The hardened path `m/145'/index'` the key is derived at, from the master key of the node
+    pub derivation_path: String,
+    pub private_key: Vec<u8>,
+    /// Matches the [SwapInfo::public_key] of the swaps that can be refunded with the key
+    pub public_key: Vec<u8>,
+}
+
 /// UTXO known to the LN node
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct UnspentTransactionOutput {
@@ -1432,6 +3132,8 @@ pub struct UnspentTransactionOutput {
 #[serde(tag = "buy_bitcoin_provider")]
 pub enum BuyBitcoinProvider {
     Moonpay,
+    /// Requires [Config::transak_api_key]
+    Transak,
 }
 
 /// We need to prepare a redeem_onchain_funds transaction to know what fee will be charged in satoshis.
@@ -1441,6 +3143,9 @@ pub enum BuyBitcoinProvider {
 pub struct PrepareRedeemOnchainFundsRequest {
     pub to_address: String,
     pub sat_per_vbyte: u32,
+    /// The amount to send, see [RedeemOnchainFundsRequest::amount_sat]
+    #[serde(default)]
+    pub amount_sat: Option<Sat>,
 }
 
 /// We need to prepare a redeem_onchain_funds transaction to know what a fee it will be charged in satoshis
@@ -1448,7 +3153,11 @@ pub struct PrepareRedeemOnchainFundsRequest {
 #[derive(PartialEq, Eq, Debug, Clone, Deserialize, Serialize)]
 pub struct PrepareRedeemOnchainFundsResponse {
     pub tx_weight: u64,
-    pub tx_fee_sat: u64,
+    pub tx_fee_sat: Sat,
+    /// The amount received at the destination address
+    pub amount_sat: Sat,
+    /// The amount going back to the wallet, 0 if there is no change output
+    pub change_sat: Sat,
 }
 
 impl FromStr for BuyBitcoinProvider {
@@ -1457,6 +3166,7 @@ impl FromStr for BuyBitcoinProvider {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "moonpay" => Ok(BuyBitcoinProvider::Moonpay),
+            "transak" => Ok(BuyBitcoinProvider::Transak),
             _ => Err(anyhow!("unknown buy bitcoin provider")),
         }
     }
@@ -1563,13 +3273,51 @@ mod tests {
     use rand::random;
     use sdk_common::grpc;
 
+    use crate::bitcoin::hashes::hex::ToHex;
+    use crate::bitcoin::hashes::{sha256, Hash};
     use crate::models::sanitize::Sanitize;
-    use crate::test_utils::{get_test_ofp, get_test_ofp_48h, rand_string, rand_vec_u8};
+    use crate::test_utils::{
+        create_test_config, get_test_ofp, get_test_ofp_48h, rand_string, rand_vec_u8,
+    };
     use crate::{
-        FullReverseSwapInfo, OpeningFeeParams, PaymentPath, PaymentPathEdge, ReverseSwapInfoCached,
-        ReverseSwapStatus, SwapInfo,
+        ConnectRequest, FullReverseSwapInfo, MemoPrivacy, OpeningFeeParams, PaymentPath,
+        PaymentPathEdge, ReverseSwapInfoCached, ReverseSwapProtocol, ReverseSwapStatus, SwapInfo,
     };
 
+    #[test]
+    fn test_connect_request_restore_only() {
+        let req = |restore_only, register_if_missing| ConnectRequest {
+            config: create_test_config(),
+            seed: vec![],
+            restore_only,
+            register_if_missing,
+        };
+        assert!(!req(None, None).is_restore_only());
+        assert!(req(Some(true), None).is_restore_only());
+        assert!(!req(Some(false), None).is_restore_only());
+        assert!(req(None, Some(false)).is_restore_only());
+        assert!(!req(None, Some(true)).is_restore_only());
+        // The register_if_missing option wins over the deprecated restore_only
+        assert!(req(Some(false), Some(false)).is_restore_only());
+        assert!(!req(Some(true), Some(true)).is_restore_only());
+    }
+
+    #[test]
+    fn test_memo_privacy() {
+        let memo = || Some("Coffee".to_string());
+        assert_eq!(MemoPrivacy::Plain.apply(memo()), memo());
+        assert_eq!(MemoPrivacy::Strip.apply(memo()), None);
+        assert_eq!(MemoPrivacy::Hash.apply(None), None);
+
+        let hashed = MemoPrivacy::Hash.apply(memo()).unwrap();
+        assert_eq!(
+            hashed,
+            format!("sha256:{}", sha256::Hash::hash(b"Coffee").to_hex())
+        );
+        // The description of an invoice created with the hash isn't hashed again on sync
+        assert_eq!(MemoPrivacy::Hash.apply(Some(hashed.clone())), Some(hashed));
+    }
+
     #[test]
     fn test_route_fees() -> Result<()> {
         let route = PaymentPath {
@@ -1744,6 +3492,8 @@ mod tests {
             onchain_amount_sat: 250,
             sat_per_vbyte: Some(50),
             receive_amount_sat: None,
+            protocol: ReverseSwapProtocol::Legacy,
+            refund_pubkey: None,
             cache: ReverseSwapInfoCached {
                 status: ReverseSwapStatus::CompletedConfirmed,
                 lockup_txid: Some("lockup_txid".to_string()),
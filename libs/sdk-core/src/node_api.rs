@@ -10,12 +10,13 @@ use tonic::Streaming;
 use sdk_common::prelude::*;
 
 use crate::{
-    bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey},
+    bitcoin::secp256k1::{ecdsa, schnorr, Message, PublicKey},
+    bitcoin::util::bip32::{ChildNumber, ExtendedPubKey},
     lightning_invoice::RawBolt11Invoice,
     persist::error::PersistError,
-    CustomMessage, LnUrlAuthError, LspInformation, MaxChannelAmount, NodeCredentials, Payment,
-    PaymentResponse, PrepareRedeemOnchainFundsRequest, PrepareRedeemOnchainFundsResponse,
-    RouteHint, RouteHintHop, SyncResponse, TlvEntry,
+    ChannelDetails, CustomMessage, LnUrlAuthError, LspInformation, MaxChannelAmount,
+    NodeCredentials, OpenChannelResponse, Payment, PaymentResponse, PrepareRedeemOnchainFundsRequest,
+    PrepareRedeemOnchainFundsResponse, RouteHint, RouteHintHop, SyncResponse, TlvEntry,
 };
 
 pub type NodeResult<T, E = NodeError> = Result<T, E>;
@@ -49,6 +50,12 @@ pub enum NodeError {
     #[error(transparent)]
     Persistance(#[from] PersistError),
 
+    #[error("{0}")]
+    RegistrationQuotaExceeded(String),
+
+    #[error("{0}")]
+    RegistrationRejected(String),
+
     #[error("{0}")]
     RestoreOnly(String),
 
@@ -107,6 +114,45 @@ pub struct CreateInvoiceRequest {
     pub use_description_hash: Option<bool>,
     pub expiry: Option<u32>,
     pub cltv: Option<u32>,
+    /// Onchain address to include as fallback in the invoice
+    pub fallback_address: Option<String>,
+}
+
+/// How long and how expensive a payment attempt can get, from the [crate::Config] or
+/// overridden by the [crate::SendPaymentRequest]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaymentLimits {
+    /// How long the routes are retried for
+    pub timeout_sec: u32,
+    pub maxfee_percent: f64,
+    /// The fee below which `maxfee_percent` is not enforced
+    pub exemptfee_msat: u64,
+}
+
+impl PaymentLimits {
+    /// Whether the fee is within these limits, for a payment of `amount_msat`
+    pub fn allows_fee(&self, fee_msat: u64, amount_msat: u64) -> bool {
+        fee_msat <= self.exemptfee_msat
+            || fee_msat as f64 <= amount_msat as f64 * self.maxfee_percent / 100.0
+    }
+
+    /// The max fee within these limits, for a payment of `amount_msat`
+    pub fn max_fee_msat(&self, amount_msat: u64) -> u64 {
+        let max_fee_msat = (amount_msat as f64 * self.maxfee_percent / 100.0) as u64;
+        max_fee_msat.max(self.exemptfee_msat)
+    }
+}
+
+/// The outcome of [NodeAPI::probe_payment]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbeResult {
+    /// The fee of the probed route
+    pub fee_msat: u64,
+    pub hops: u32,
+    /// Whether the probe HTLC reached the destination
+    pub reachable: bool,
+    /// Why the probe HTLC did not reach the destination
+    pub failure: Option<String>,
 }
 
 pub struct FetchBolt11Result {
@@ -115,6 +161,9 @@ pub struct FetchBolt11Result {
 }
 
 /// Trait covering functions affecting the LN node
+///
+/// Greenlight is the only implementation. An embedded LDK node backend is out of scope: it needs
+/// its own chain sync, persistence and LSP integration, which makes it a project of its own.
 #[cfg_attr(test, mockall::automock)]
 #[tonic::async_trait]
 pub trait NodeAPI: Send + Sync {
@@ -129,12 +178,15 @@ pub trait NodeAPI: Send + Sync {
         sync_state: Option<Value>,
         match_local_balance: bool,
     ) -> NodeResult<SyncResponse>;
-    /// As per the `pb::PayRequest` docs, `amount_msat` is only needed when the invoice doesn't specify an amount
+    /// As per the `pb::PayRequest` docs, `amount_msat` is only needed when the invoice doesn't specify an amount.
+    /// The channels in `exclude`, in the `short_channel_id/direction` format, are not used to route the payment.
     async fn send_payment(
         &self,
         bolt11: String,
         amount_msat: Option<u64>,
         label: Option<String>,
+        exclude: Vec<String>,
+        limits: PaymentLimits,
     ) -> NodeResult<Payment>;
     async fn send_spontaneous_payment(
         &self,
@@ -143,12 +195,22 @@ pub trait NodeAPI: Send + Sync {
         extra_tlvs: Option<Vec<TlvEntry>>,
         label: Option<String>,
     ) -> NodeResult<Payment>;
+    /// Fails with [NodeError::RouteTooExpensive] if the trampoline fee exceeds the `limits`
     async fn send_trampoline_payment(
         &self,
         bolt11: String,
         amount_msat: u64,
         label: Option<String>,
         trampoline_node_id: Vec<u8>,
+        limits: PaymentLimits,
+    ) -> NodeResult<Payment>;
+    /// Fetches an invoice for the BOLT12 offer from its issuer and pays it
+    async fn pay_offer(
+        &self,
+        offer: String,
+        amount_msat: Option<u64>,
+        payer_note: Option<String>,
+        label: Option<String>,
     ) -> NodeResult<Payment>;
     async fn node_id(&self) -> NodeResult<String>;
 
@@ -157,6 +219,18 @@ pub trait NodeAPI: Send + Sync {
     /// This is useful function to send the largest amount possible to a node.
     async fn send_pay(&self, bolt11: String, max_hops: u32) -> NodeResult<PaymentResponse>;
 
+    /// Probes a route to the node by sending an HTLC with a random payment hash, which the
+    /// destination can only fail. If a `route_hint` is given, the route ends with its hop.
+    /// Fails with [NodeError::RouteNotFound] if there is no route.
+    async fn probe_payment(
+        &self,
+        node_id: Vec<u8>,
+        amount_msat: u64,
+        route_hint: Option<RouteHintHop>,
+        final_cltv_delta: u64,
+        timeout_sec: u32,
+    ) -> NodeResult<ProbeResult>;
+
     /// Calculates the maximum amount that can be sent to a node.
     async fn max_sendable_amount<'a>(
         &self,
@@ -168,16 +242,44 @@ pub trait NodeAPI: Send + Sync {
         &self,
         to_address: String,
         sat_per_vbyte: u32,
+        amount_sat: Option<u64>,
     ) -> NodeResult<Vec<u8>>;
     async fn prepare_redeem_onchain_funds(
         &self,
         req: PrepareRedeemOnchainFundsRequest,
     ) -> NodeResult<PrepareRedeemOnchainFundsResponse>;
+    /// Replaces an unconfirmed [NodeAPI::redeem_onchain_funds] transaction by one spending the
+    /// same inputs at a higher fee rate
+    async fn bump_redeem_onchain_funds(
+        &self,
+        txid: Vec<u8>,
+        to_address: String,
+        sat_per_vbyte: u32,
+        amount_sat: Option<u64>,
+    ) -> NodeResult<Vec<u8>>;
+    /// Returns a new address of the on-chain wallet of the node
+    async fn new_onchain_address(&self) -> NodeResult<String>;
     async fn start_signer(&self, shutdown: mpsc::Receiver<()>);
     async fn start_keep_alive(&self, shutdown: watch::Receiver<()>);
     async fn connect_peer(&self, node_id: String, addr: String) -> NodeResult<()>;
+    /// Pings the connected peer, failing if it's not connected or doesn't answer
+    async fn ping_peer(&self, node_id: String) -> NodeResult<()>;
     async fn sign_invoice(&self, invoice: RawBolt11Invoice) -> NodeResult<String>;
     async fn close_peer_channels(&self, node_id: String) -> NodeResult<Vec<String>>;
+    /// Lists the channels of the node that are not yet closed
+    async fn list_channels(&self) -> NodeResult<Vec<ChannelDetails>>;
+    /// Closes the channel, returning the closing txid if one was broadcast. If `force` is set,
+    /// the channel is closed unilaterally when a mutual close isn't possible right away.
+    async fn close_channel(&self, channel_id: String, force: bool) -> NodeResult<Option<String>>;
+    /// Opens a channel to a connected peer, funded from the onchain balance. If `sat_per_vbyte`
+    /// is not set, the node estimates the feerate of the funding tx.
+    async fn open_channel(
+        &self,
+        node_id: String,
+        amount_sat: u64,
+        announce: bool,
+        sat_per_vbyte: Option<u32>,
+    ) -> NodeResult<OpenChannelResponse>;
     async fn stream_incoming_payments(
         &self,
     ) -> NodeResult<Streaming<gl_client::signer::model::greenlight::IncomingPayment>>;
@@ -195,9 +297,22 @@ pub trait NodeAPI: Send + Sync {
         &self,
     ) -> NodeResult<Pin<Box<dyn Stream<Item = Result<CustomMessage>> + Send>>>;
 
-    /// Gets the private key at the path specified
-    async fn derive_bip32_key(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPrivKey>;
-    async fn legacy_derive_bip32_key(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPrivKey>;
+    /// The key operations of the [crate::Signer] of the node, on the key at the path specified
+    async fn derive_xpub(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPubKey>;
+    async fn sign_ecdsa(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<ecdsa::Signature>;
+    async fn sign_taproot(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<schnorr::Signature>;
+    async fn ecdh(&self, path: Vec<ChildNumber>, public_key: PublicKey) -> NodeResult<[u8; 32]>;
+    async fn hmac_sha256(&self, path: Vec<ChildNumber>, input: Vec<u8>) -> NodeResult<[u8; 32]>;
+    async fn derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]>;
+    async fn legacy_derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]>;
 
     /// Gets the routing hints related to all private channels that the node has.
     /// Also returns a boolean indicating if the node has a public channel or not.
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::models::{
+    Payment, PaymentDetails, PaymentGroup, PaymentGroupBy, PaymentStatus, PaymentType,
+};
+
+/// Aggregates the completed sent payments by counterparty, the largest total amount first.
+///
+/// Payments without the counterparty, for example received payments, are skipped.
+pub(crate) fn group_payments(
+    payments: Vec<Payment>,
+    group_by: PaymentGroupBy,
+    limit: Option<u32>,
+) -> Vec<PaymentGroup> {
+    let mut groups: HashMap<String, PaymentGroup> = HashMap::new();
+    for payment in payments {
+        if payment.payment_type != PaymentType::Sent || payment.status != PaymentStatus::Complete {
+            continue;
+        }
+        let PaymentDetails::Ln { data } = &payment.details else {
+            continue;
+        };
+        let key = match group_by {
+            PaymentGroupBy::DestinationPubkey => Some(data.destination_pubkey.clone()),
+            PaymentGroupBy::LnAddress => data.ln_address.clone(),
+        };
+        let Some(key) = key.filter(|k| !k.is_empty()) else {
+            continue;
+        };
+
+        let group = groups.entry(key.clone()).or_insert_with(|| PaymentGroup {
+            key,
+            payments_count: 0,
+            total_amount_msat: 0,
+            total_fee_msat: 0,
+            first_payment_time: payment.payment_time,
+            last_payment_time: payment.payment_time,
+        });
+        group.payments_count += 1;
+        group.total_amount_msat += payment.amount_msat;
+        group.total_fee_msat += payment.fee_msat;
+        group.first_payment_time = group.first_payment_time.min(payment.payment_time);
+        group.last_payment_time = group.last_payment_time.max(payment.payment_time);
+    }
+
+    let mut groups: Vec<PaymentGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| {
+        b.total_amount_msat
+            .cmp(&a.total_amount_msat)
+            .then_with(|| a.key.cmp(&b.key))
+    });
+    if let Some(limit) = limit {
+        groups.truncate(limit as usize);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::group_payments;
+    use crate::models::{
+        LnPaymentDetails, Payment, PaymentDetails, PaymentGroupBy, PaymentStatus, PaymentType,
+    };
+
+    fn payment(
+        payment_type: PaymentType,
+        status: PaymentStatus,
+        destination_pubkey: &str,
+        ln_address: Option<&str>,
+        amount_msat: u64,
+        payment_time: i64,
+    ) -> Payment {
+        Payment {
+            payment_type,
+            status,
+            payment_time,
+            amount_msat,
+            fee_msat: amount_msat / 100,
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    destination_pubkey: destination_pubkey.to_string(),
+                    ln_address: ln_address.map(str::to_string),
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        }
+    }
+
+    fn sent(
+        destination_pubkey: &str,
+        ln_address: Option<&str>,
+        amount_msat: u64,
+        time: i64,
+    ) -> Payment {
+        payment(
+            PaymentType::Sent,
+            PaymentStatus::Complete,
+            destination_pubkey,
+            ln_address,
+            amount_msat,
+            time,
+        )
+    }
+
+    #[test]
+    fn test_group_payments_by_destination_pubkey() {
+        let payments = vec![
+            sent("a", None, 1_000, 10),
+            sent("b", None, 5_000, 20),
+            sent("a", None, 2_000, 30),
+            payment(
+                PaymentType::Sent,
+                PaymentStatus::Failed,
+                "a",
+                None,
+                9_000,
+                40,
+            ),
+            payment(
+                PaymentType::Received,
+                PaymentStatus::Complete,
+                "c",
+                None,
+                9_000,
+                50,
+            ),
+        ];
+
+        let groups = group_payments(payments, PaymentGroupBy::DestinationPubkey, None);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].key, "b");
+        assert_eq!(groups[1].key, "a");
+        assert_eq!(groups[1].payments_count, 2);
+        assert_eq!(groups[1].total_amount_msat, 3_000);
+        assert_eq!(groups[1].total_fee_msat, 30);
+        assert_eq!(groups[1].first_payment_time, 10);
+        assert_eq!(groups[1].last_payment_time, 30);
+    }
+
+    #[test]
+    fn test_group_payments_by_ln_address() {
+        let payments = vec![
+            sent("a", Some("alice@example.com"), 1_000, 10),
+            sent("a", Some("bob@example.com"), 2_000, 20),
+            sent("a", None, 3_000, 30),
+            sent("b", Some("alice@example.com"), 4_000, 40),
+        ];
+
+        let groups = group_payments(payments, PaymentGroupBy::LnAddress, Some(1));
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].key, "alice@example.com");
+        assert_eq!(groups[0].payments_count, 2);
+        assert_eq!(groups[0].total_amount_msat, 5_000);
+    }
+}
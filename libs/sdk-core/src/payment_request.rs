@@ -0,0 +1,67 @@
+use ecies::symmetric::{sym_decrypt, sym_encrypt};
+use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec_with_limit};
+use sdk_common::prelude::ENCRYPTED_PAYMENT_REQUEST_PREFIX;
+
+use crate::error::{SdkError, SdkResult};
+use crate::models::PaymentRequestBundle;
+
+/// The purpose of the key encrypting the payment requests, see
+/// [crate::BreezServices::derive_encryption_key]
+pub(crate) const PAYMENT_REQUEST_KEY_PURPOSE: &str = "payment-request";
+
+/// Larger payment requests are rejected when decrypting
+const MAX_PAYMENT_REQUEST_SIZE: usize = 100_000;
+
+/// Encodes the bundle as a compressed and encrypted blob, with the prefix recognized by `parse`
+pub(crate) fn encrypt_bundle(key: &[u8], bundle: &PaymentRequestBundle) -> SdkResult<String> {
+    let compressed = compress_to_vec(&serde_json::to_vec(bundle)?, 10);
+    let encrypted = sym_encrypt(key, &compressed)
+        .ok_or(SdkError::generic("Failed to encrypt the payment request"))?;
+    Ok(format!(
+        "{ENCRYPTED_PAYMENT_REQUEST_PREFIX}{}",
+        base64::encode_config(encrypted, base64::URL_SAFE_NO_PAD)
+    ))
+}
+
+/// Decodes a blob returned by [encrypt_bundle], without its prefix
+pub(crate) fn decrypt_bundle(key: &[u8], blob: &str) -> SdkResult<PaymentRequestBundle> {
+    let encrypted = base64::decode_config(blob, base64::URL_SAFE_NO_PAD)
+        .map_err(|_| SdkError::generic("Invalid payment request"))?;
+    // Blobs of another wallet fail to decrypt
+    let compressed = sym_decrypt(key, &encrypted).ok_or(SdkError::generic(
+        "The payment request was not created by this wallet",
+    ))?;
+    let data = decompress_to_vec_with_limit(&compressed, MAX_PAYMENT_REQUEST_SIZE)
+        .map_err(|_| SdkError::generic("Invalid payment request"))?;
+    Ok(serde_json::from_slice(&data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::ENCRYPTED_PAYMENT_REQUEST_PREFIX;
+
+    use super::{decrypt_bundle, encrypt_bundle};
+    use crate::models::PaymentRequestBundle;
+
+    #[test]
+    fn test_encrypt_bundle() -> Result<()> {
+        let key = [1; 32];
+        let bundle = PaymentRequestBundle {
+            invoice: "lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz".to_string(),
+            fallback: Some("bc1qxhmdufsvnuaaaer4ynz88fspdsxq2h9e9cetdj".to_string()),
+            metadata: Some("{\"item\":\"coffee\"}".to_string()),
+        };
+
+        let encrypted = encrypt_bundle(&key, &bundle)?;
+        let blob = encrypted
+            .strip_prefix(ENCRYPTED_PAYMENT_REQUEST_PREFIX)
+            .unwrap();
+        assert_eq!(decrypt_bundle(&key, blob)?, bundle);
+
+        // Only the same key decrypts it
+        assert!(decrypt_bundle(&[2; 32], blob).is_err());
+        assert!(decrypt_bundle(&key, "not a blob").is_err());
+        Ok(())
+    }
+}
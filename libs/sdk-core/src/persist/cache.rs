@@ -1,16 +1,30 @@
+use std::fs;
+use std::path::Path;
+
 use serde_json::Value;
 
-use crate::models::NodeState;
+use crate::denylist::Denylist;
+#[cfg(feature = "lnurl")]
+use crate::models::CreateStaticLnurlPayRequest;
+use crate::models::{LnurlPayInfo, NodeState};
 
-use super::{db::SqliteStorage, error::PersistResult};
+use super::{
+    db::SqliteStorage,
+    error::{PersistError, PersistResult},
+};
 
 const KEY_GL_CREDENTIALS: &str = "gl_credentials";
 const KEY_LAST_BACKUP_TIME: &str = "last_backup_time";
-const KEY_SYNC_STATE: &str = "sync_state";
+pub(super) const KEY_SYNC_STATE: &str = "sync_state";
 const KEY_NODE_STATE: &str = "node_state";
 const KEY_STATIC_BACKUP: &str = "static_backup";
 const KEY_WEBHOOK_URL: &str = "webhook_url";
 const KEY_MEMPOOLSPACE_BASE_URLS: &str = "mempoolspace_base_urls";
+const KEY_BACKUP_KEY_EPOCH: &str = "backup_key_epoch";
+const KEY_LNURL_PAY_INFO: &str = "lnurl_pay_info";
+#[cfg(feature = "lnurl")]
+const KEY_STATIC_LNURL_PAY: &str = "static_lnurl_pay";
+const KEY_DENYLIST: &str = "denylist";
 
 #[cfg_attr(test, mockall::automock)]
 pub(crate) trait NodeStateStorage: Send + Sync {
@@ -70,10 +84,6 @@ impl SqliteStorage {
         })
     }
 
-    pub fn set_sync_state(&self, t: &Value) -> PersistResult<()> {
-        self.update_cached_item(KEY_SYNC_STATE, t.to_string())
-    }
-
     pub fn get_sync_state(&self) -> PersistResult<Option<Value>> {
         let state_str = self.get_cached_item(KEY_SYNC_STATE)?;
         Ok(match state_str {
@@ -83,12 +93,33 @@ impl SqliteStorage {
     }
 
     pub fn set_gl_credentials(&self, creds: Vec<u8>) -> PersistResult<()> {
-        self.update_cached_item(KEY_GL_CREDENTIALS, hex::encode(creds))
+        match &self.credentials_file {
+            Some(file) => fs::write(file, hex::encode(creds))
+                .map_err(|e| PersistError::Generic(format!("Failed to write credentials: {e}"))),
+            None => self.update_cached_item(KEY_GL_CREDENTIALS, hex::encode(creds)),
+        }
     }
 
     pub fn get_gl_credentials(&self) -> PersistResult<Option<Vec<u8>>> {
+        if let Some(file) = &self.credentials_file {
+            if Path::new(file).exists() {
+                let str = fs::read_to_string(file).map_err(|e| {
+                    PersistError::Generic(format!("Failed to read credentials: {e}"))
+                })?;
+                return Ok(Some(hex::decode(str)?));
+            }
+        }
+
         match self.get_cached_item(KEY_GL_CREDENTIALS)? {
-            Some(str) => Ok(Some(hex::decode(str)?)),
+            Some(str) => {
+                let creds = hex::decode(str)?;
+                // Move credentials stored in the DB before a credentials dir was configured
+                if self.credentials_file.is_some() {
+                    self.set_gl_credentials(creds.clone())?;
+                    self.delete_cached_item(KEY_GL_CREDENTIALS)?;
+                }
+                Ok(Some(creds))
+            }
             None => Ok(None),
         }
     }
@@ -120,6 +151,49 @@ impl SqliteStorage {
         self.get_cached_item(KEY_WEBHOOK_URL)
     }
 
+    pub fn set_lnurl_pay_info(&self, info: &LnurlPayInfo) -> PersistResult<()> {
+        let serialized = serde_json::to_string(info)?;
+        self.update_cached_item(KEY_LNURL_PAY_INFO, serialized)
+    }
+
+    pub fn get_lnurl_pay_info(&self) -> PersistResult<Option<LnurlPayInfo>> {
+        Ok(match self.get_cached_item(KEY_LNURL_PAY_INFO)? {
+            Some(str) => serde_json::from_str(str.as_str())?,
+            None => None,
+        })
+    }
+
+    #[cfg(feature = "lnurl")]
+    pub(crate) fn set_static_lnurl_pay(
+        &self,
+        req: &CreateStaticLnurlPayRequest,
+    ) -> PersistResult<()> {
+        let serialized = serde_json::to_string(req)?;
+        self.update_cached_item(KEY_STATIC_LNURL_PAY, serialized)
+    }
+
+    #[cfg(feature = "lnurl")]
+    pub(crate) fn get_static_lnurl_pay(
+        &self,
+    ) -> PersistResult<Option<CreateStaticLnurlPayRequest>> {
+        Ok(match self.get_cached_item(KEY_STATIC_LNURL_PAY)? {
+            Some(str) => serde_json::from_str(str.as_str())?,
+            None => None,
+        })
+    }
+
+    pub(crate) fn set_denylist(&self, denylist: &Denylist) -> PersistResult<()> {
+        let serialized = serde_json::to_string(denylist)?;
+        self.update_cached_item(KEY_DENYLIST, serialized)
+    }
+
+    pub(crate) fn get_denylist(&self) -> PersistResult<Option<Denylist>> {
+        Ok(match self.get_cached_item(KEY_DENYLIST)? {
+            Some(str) => serde_json::from_str(str.as_str())?,
+            None => None,
+        })
+    }
+
     pub fn set_mempoolspace_base_urls(
         &self,
         mempool_space_endpoints: Vec<String>,
@@ -136,6 +210,20 @@ impl SqliteStorage {
 
         Ok(res)
     }
+
+    /// Sets the epoch of the key the backups are encrypted with
+    pub fn set_backup_key_epoch(&self, epoch: u32) -> PersistResult<()> {
+        self.update_cached_item(KEY_BACKUP_KEY_EPOCH, serde_json::to_string(&epoch)?)
+    }
+
+    /// The epoch set with [SqliteStorage::set_backup_key_epoch], 0 until the key is rotated
+    pub fn get_backup_key_epoch(&self) -> PersistResult<u32> {
+        let res = match self.get_cached_item(KEY_BACKUP_KEY_EPOCH)? {
+            Some(str) => serde_json::from_str(str.as_str())?,
+            None => 0,
+        };
+        Ok(res)
+    }
 }
 
 #[test]
@@ -155,3 +243,36 @@ fn test_cached_items() {
     let item_value = storage.get_cached_item("key1").unwrap();
     assert_eq!(item_value, None);
 }
+
+#[test]
+fn test_gl_credentials_in_credentials_dir() {
+    use crate::persist::test_utils;
+
+    let db_dir = test_utils::create_test_sql_dir();
+    let credentials_dir = test_utils::create_test_sql_dir();
+
+    // Credentials stored in the DB are moved to the credentials dir once it's configured
+    let storage = SqliteStorage::new(db_dir.clone());
+    storage.init().unwrap();
+    storage.set_gl_credentials(vec![1, 2, 3]).unwrap();
+
+    let storage = SqliteStorage::new(db_dir).with_credentials_dir(Some(credentials_dir.clone()));
+    assert_eq!(storage.get_gl_credentials().unwrap(), Some(vec![1, 2, 3]));
+    assert_eq!(storage.get_cached_item(KEY_GL_CREDENTIALS).unwrap(), None);
+    assert!(Path::new(&format!("{credentials_dir}/credentials")).exists());
+
+    storage.set_gl_credentials(vec![4, 5]).unwrap();
+    assert_eq!(storage.get_gl_credentials().unwrap(), Some(vec![4, 5]));
+}
+
+#[test]
+fn test_backup_key_epoch() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert_eq!(storage.get_backup_key_epoch().unwrap(), 0);
+
+    storage.set_backup_key_epoch(2).unwrap();
+    assert_eq!(storage.get_backup_key_epoch().unwrap(), 2);
+}
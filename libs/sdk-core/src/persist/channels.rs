@@ -1,4 +1,5 @@
 use crate::models::*;
+use rusqlite::named_params;
 use std::collections::HashMap;
 
 use super::{db::SqliteStorage, error::PersistResult};
@@ -144,6 +145,96 @@ impl SqliteStorage {
         )?;
         Ok(())
     }
+
+    /// The tracked activity of the open channels, by funding txid
+    pub(crate) fn list_channel_activity(&self) -> PersistResult<HashMap<String, ChannelActivity>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT funding_txid, local_balance_msat, last_activity_at, peer_connected_at, suggested_at
+             FROM channel_activity",
+        )?;
+        let activity = stmt
+            .query_map([], |row| {
+                Ok(ChannelActivity {
+                    funding_txid: row.get("funding_txid")?,
+                    local_balance_msat: row.get("local_balance_msat")?,
+                    last_activity_at: row.get("last_activity_at")?,
+                    peer_connected_at: row.get("peer_connected_at")?,
+                    suggested_at: row.get("suggested_at")?,
+                })
+            })?
+            .map(|a| a.map(|a| (a.funding_txid.clone(), a)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(activity)
+    }
+
+    /// Replaces the tracked activity, dropping the channels that are no longer open
+    pub(crate) fn replace_channel_activity(
+        &self,
+        activity: &[ChannelActivity],
+    ) -> PersistResult<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        tx.execute("DELETE FROM channel_activity", [])?;
+        for a in activity {
+            tx.execute(
+                "INSERT INTO channel_activity (funding_txid, local_balance_msat, last_activity_at, peer_connected_at, suggested_at)
+                 VALUES (:funding_txid, :local_balance_msat, :last_activity_at, :peer_connected_at, :suggested_at)",
+                named_params! {
+                    ":funding_txid": a.funding_txid,
+                    ":local_balance_msat": a.local_balance_msat,
+                    ":last_activity_at": a.last_activity_at,
+                    ":peer_connected_at": a.peer_connected_at,
+                    ":suggested_at": a.suggested_at,
+                },
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+/// When an open channel was last used and its peer last seen connected, to find the stale
+/// channels worth closing
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ChannelActivity {
+    pub(crate) funding_txid: String,
+    /// The local balance when the channel was last checked, a change means it was used
+    pub(crate) local_balance_msat: u64,
+    pub(crate) last_activity_at: i64,
+    pub(crate) peer_connected_at: i64,
+    /// When the channel was last suggested to be closed
+    pub(crate) suggested_at: Option<i64>,
+}
+
+#[test]
+fn test_channel_activity() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    let activity = |funding_txid: &str, suggested_at| ChannelActivity {
+        funding_txid: funding_txid.to_string(),
+        local_balance_msat: 1000,
+        last_activity_at: 10,
+        peer_connected_at: 20,
+        suggested_at,
+    };
+    storage
+        .replace_channel_activity(&[activity("123", None), activity("456", Some(30))])
+        .unwrap();
+    let stored = storage.list_channel_activity().unwrap();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(stored["456"], activity("456", Some(30)));
+
+    // The channels no longer open are dropped
+    storage
+        .replace_channel_activity(&[activity("456", None)])
+        .unwrap();
+    let stored = storage.list_channel_activity().unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored["456"], activity("456", None));
 }
 
 #[test]
@@ -2,15 +2,26 @@ use super::{
     error::PersistResult,
     migrations::{current_migrations, current_sync_migrations},
 };
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+use std::time::Duration;
+
 use anyhow::Result;
 use rusqlite::{
     hooks::Action,
     types::{FromSql, FromSqlError, ToSqlOutput},
-    Connection, ToSql,
+    Connection, DatabaseName, ToSql,
 };
 use rusqlite_migration::{Migrations, M};
 use tokio::sync::broadcast;
 
+/// How long a statement waits for another connection to release its lock before failing
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+/// The number of idle connections kept open for reuse
+const MAX_IDLE_CONNECTIONS: usize = 4;
+/// The number of prepared statements cached by each connection
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+
 /// HookEvent is used to notify listeners about DB changes.
 /// A listener can register to be notified about specific events that occurs as part of
 /// modifications in the persistent storage.
@@ -24,8 +35,50 @@ pub(crate) struct SqliteStorage {
     main_db_file: String,
     /// Sync DB. Gets synchronized across the different instances that connect to the same wallet.
     sync_db_file: String,
+    /// If set, the node credentials are stored in this file instead of the main DB.
+    pub(super) credentials_file: Option<String>,
     /// Dispatch DB hook events.
     events_publisher: broadcast::Sender<HookEvent>,
+    /// Connections returned by [SqliteStorage::get_connection] once dropped. Reusing them saves
+    /// opening and attaching the DBs, and keeps their prepared statement cache.
+    idle_connections: Mutex<Vec<Connection>>,
+}
+
+/// A connection to the DBs, returned to the idle connections of the storage when dropped
+pub(crate) struct PooledConnection<'a> {
+    con: Option<Connection>,
+    idle_connections: &'a Mutex<Vec<Connection>>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.con
+            .as_ref()
+            .expect("The connection is only taken when dropped")
+    }
+}
+
+impl DerefMut for PooledConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.con
+            .as_mut()
+            .expect("The connection is only taken when dropped")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        // A connection left in a transaction is closed instead
+        if let Some(con) = self.con.take().filter(|con| con.is_autocommit()) {
+            if let Ok(mut idle_connections) = self.idle_connections.lock() {
+                if idle_connections.len() < MAX_IDLE_CONNECTIONS {
+                    idle_connections.push(con);
+                }
+            }
+        }
+    }
 }
 
 impl SqliteStorage {
@@ -37,7 +90,17 @@ impl SqliteStorage {
         SqliteStorage {
             main_db_file,
             sync_db_file,
+            credentials_file: None,
             events_publisher,
+            idle_connections: Mutex::new(vec![]),
+        }
+    }
+
+    /// Stores the node credentials in a file in `credentials_dir`, if set, instead of the main DB
+    pub fn with_credentials_dir(self, credentials_dir: Option<String>) -> SqliteStorage {
+        SqliteStorage {
+            credentials_file: credentials_dir.map(|dir| format!("{}/credentials", dir)),
+            ..self
         }
     }
 
@@ -46,6 +109,14 @@ impl SqliteStorage {
     }
 
     pub(crate) fn init(&self) -> PersistResult<()> {
+        // Readers don't block the writer and the other way around. The sync DB keeps the rollback
+        // journal, so its file always holds the committed changes when it is backed up.
+        self.get_connection()?.pragma_update_and_check(
+            Some(DatabaseName::Main),
+            "journal_mode",
+            "WAL",
+            |row| row.get::<_, String>(0),
+        )?;
         self.migrate_main_db()?;
         Self::migrate_sync_db(self.sync_db_file.clone())?;
         Ok(())
@@ -66,8 +137,27 @@ impl SqliteStorage {
         Ok(())
     }
 
-    pub(crate) fn get_connection(&self) -> PersistResult<Connection> {
+    /// Returns an idle connection, or opens a new one
+    pub(crate) fn get_connection(&self) -> PersistResult<PooledConnection> {
+        let idle_connection = match self.idle_connections.lock() {
+            Ok(mut idle_connections) => idle_connections.pop(),
+            Err(_) => None,
+        };
+        let con = match idle_connection {
+            Some(con) => con,
+            None => self.open_connection()?,
+        };
+        Ok(PooledConnection {
+            con: Some(con),
+            idle_connections: &self.idle_connections,
+        })
+    }
+
+    /// Opens a connection that isn't reused, for example to attach other DBs to it
+    pub(crate) fn open_connection(&self) -> PersistResult<Connection> {
         let con = Connection::open(self.main_db_file.clone())?;
+        con.busy_timeout(BUSY_TIMEOUT)?;
+        con.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
         let sql = "ATTACH DATABASE ? AS sync;";
         con.execute(sql, [self.sync_db_file.clone()])?;
         // We want to notify any subscribers with hook events.
@@ -0,0 +1,220 @@
+use rusqlite::{named_params, Row};
+use sdk_common::prelude::Rate;
+
+use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::{AddFiatRateAlertRequest, FiatRateAlert, HistoricalRate};
+
+impl SqliteStorage {
+    /// Records the exchange rates fetched at `timestamp`
+    pub(crate) fn insert_fiat_rates(&self, rates: &[Rate], timestamp: i64) -> PersistResult<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for rate in rates {
+            tx.execute(
+                "INSERT OR REPLACE INTO fiat_rates (currency, timestamp, value)
+                 VALUES (:currency, :timestamp, :value)",
+                named_params! {
+                    ":currency": rate.coin,
+                    ":timestamp": timestamp,
+                    ":value": rate.value,
+                },
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Lists the recorded exchange rates of the currency, oldest first
+    pub(crate) fn list_fiat_rates(
+        &self,
+        currency: &str,
+        from_timestamp: Option<i64>,
+        to_timestamp: Option<i64>,
+    ) -> PersistResult<Vec<HistoricalRate>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT currency, timestamp, value FROM fiat_rates
+             WHERE currency = :currency
+              AND (:from IS NULL OR timestamp >= :from)
+              AND (:to IS NULL OR timestamp < :to)
+             ORDER BY timestamp",
+        )?;
+        let rates = stmt
+            .query_map(
+                named_params! {
+                    ":currency": currency,
+                    ":from": from_timestamp,
+                    ":to": to_timestamp,
+                },
+                |row| {
+                    Ok(HistoricalRate {
+                        coin: row.get(0)?,
+                        timestamp: row.get(1)?,
+                        value: row.get(2)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<HistoricalRate>, _>>()?;
+        Ok(rates)
+    }
+
+    /// Stores the exchange rate at the time the payment settled
+    pub(crate) fn set_payment_fiat_rate(&self, payment_id: &str, rate: &Rate) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO payment_fiat_rates (payment_id, currency, value)
+             VALUES (:payment_id, :currency, :value)",
+            named_params! {
+                ":payment_id": payment_id,
+                ":currency": rate.coin,
+                ":value": rate.value,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Stores the price alert and returns its id
+    pub(crate) fn insert_fiat_rate_alert(
+        &self,
+        req: &AddFiatRateAlertRequest,
+        created_at: i64,
+    ) -> PersistResult<i64> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO fiat_rate_alerts (currency, direction, threshold, created_at)
+             VALUES (:currency, :direction, :threshold, :created_at)",
+            named_params! {
+                ":currency": req.currency,
+                ":direction": req.direction as i32,
+                ":threshold": req.threshold,
+                ":created_at": created_at,
+            },
+        )?;
+        Ok(con.last_insert_rowid())
+    }
+
+    /// The price alerts, the oldest first
+    pub(crate) fn list_fiat_rate_alerts(&self) -> PersistResult<Vec<FiatRateAlert>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT * FROM fiat_rate_alerts ORDER BY id")?;
+        let alerts = stmt
+            .query_map([], |row| self.sql_row_to_fiat_rate_alert(row))?
+            .collect::<Result<Vec<FiatRateAlert>, _>>()?;
+        Ok(alerts)
+    }
+
+    /// Deletes the price alert, returning whether it existed
+    pub(crate) fn delete_fiat_rate_alert(&self, id: i64) -> PersistResult<bool> {
+        let deleted = self
+            .get_connection()?
+            .execute("DELETE FROM fiat_rate_alerts WHERE id = ?1", [id])?;
+        Ok(deleted > 0)
+    }
+
+    /// Sets whether the rate is past the threshold of the alert, recording the time it triggered
+    /// if it is
+    pub(crate) fn set_fiat_rate_alert_triggered(
+        &self,
+        id: i64,
+        triggered: bool,
+        timestamp: i64,
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "UPDATE fiat_rate_alerts
+             SET triggered = :triggered,
+              last_triggered_at = CASE WHEN :triggered THEN :timestamp ELSE last_triggered_at END
+             WHERE id = :id",
+            named_params! {
+                ":id": id,
+                ":triggered": triggered,
+                ":timestamp": timestamp,
+            },
+        )?;
+        Ok(())
+    }
+
+    fn sql_row_to_fiat_rate_alert(
+        &self,
+        row: &Row,
+    ) -> PersistResult<FiatRateAlert, rusqlite::Error> {
+        let direction: i32 = row.get("direction")?;
+        Ok(FiatRateAlert {
+            id: row.get("id")?,
+            currency: row.get("currency")?,
+            direction: direction
+                .try_into()
+                .map_err(|_| rusqlite::Error::IntegralValueOutOfRange(2, direction as i64))?,
+            threshold: row.get("threshold")?,
+            created_at: row.get("created_at")?,
+            triggered: row.get("triggered")?,
+            last_triggered_at: row.get("last_triggered_at")?,
+        })
+    }
+}
+
+#[test]
+fn test_fiat_rates() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    let rate = |coin: &str, value: f64| Rate {
+        coin: coin.to_string(),
+        value,
+    };
+    storage
+        .insert_fiat_rates(&[rate("USD", 40_000.0), rate("EUR", 37_000.0)], 100)
+        .unwrap();
+    storage
+        .insert_fiat_rates(&[rate("USD", 41_000.0)], 200)
+        .unwrap();
+
+    let history = storage.list_fiat_rates("USD", None, None).unwrap();
+    assert_eq!(
+        history.iter().map(|r| r.value).collect::<Vec<f64>>(),
+        vec![40_000.0, 41_000.0]
+    );
+    let history = storage.list_fiat_rates("USD", Some(150), None).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].timestamp, 200);
+    assert!(storage
+        .list_fiat_rates("USD", None, Some(100))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_fiat_rate_alerts() {
+    use crate::models::FiatRateAlertDirection;
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    let req = AddFiatRateAlertRequest {
+        currency: "USD".to_string(),
+        direction: FiatRateAlertDirection::Below,
+        threshold: 30_000.0,
+    };
+    let id = storage.insert_fiat_rate_alert(&req, 100).unwrap();
+    storage.insert_fiat_rate_alert(&req, 110).unwrap();
+
+    let alerts = storage.list_fiat_rate_alerts().unwrap();
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].id, id);
+    assert_eq!(alerts[0].direction, FiatRateAlertDirection::Below);
+    assert!(!alerts[0].triggered);
+    assert_eq!(alerts[0].last_triggered_at, None);
+
+    storage
+        .set_fiat_rate_alert_triggered(id, true, 200)
+        .unwrap();
+    storage
+        .set_fiat_rate_alert_triggered(id, false, 300)
+        .unwrap();
+    let alert = &storage.list_fiat_rate_alerts().unwrap()[0];
+    assert!(!alert.triggered);
+    assert_eq!(alert.last_triggered_at, Some(200));
+
+    assert!(storage.delete_fiat_rate_alert(id).unwrap());
+    assert!(!storage.delete_fiat_rate_alert(id).unwrap());
+    assert_eq!(storage.list_fiat_rate_alerts().unwrap().len(), 1);
+}
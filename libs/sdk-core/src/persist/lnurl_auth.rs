@@ -0,0 +1,198 @@
+use rusqlite::{named_params, Row};
+
+use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::LnUrlAuthDomain;
+
+impl SqliteStorage {
+    /// Records the signed LNURL-auth challenge. Returns false if `k1` was already signed.
+    pub(crate) fn insert_lnurl_auth_attempt(
+        &self,
+        k1: &str,
+        domain: &str,
+        created_at: i64,
+    ) -> PersistResult<bool> {
+        let inserted = self.get_connection()?.execute(
+            "INSERT OR IGNORE INTO lnurl_auth_attempts (k1, domain, created_at)
+             VALUES (:k1, :domain, :created_at)",
+            named_params! {
+                ":k1": k1,
+                ":domain": domain,
+                ":created_at": created_at,
+            },
+        )?;
+        Ok(inserted > 0)
+    }
+
+    /// The number of challenges of the domain signed since `since`
+    pub(crate) fn count_lnurl_auth_attempts(&self, domain: &str, since: i64) -> PersistResult<u32> {
+        Ok(self.get_connection()?.query_row(
+            "SELECT COUNT(*) FROM lnurl_auth_attempts WHERE domain = ?1 AND created_at >= ?2",
+            (domain, since),
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Records a successful authentication to the domain
+    pub(crate) fn record_lnurl_auth_domain(
+        &self,
+        domain: &str,
+        linking_key: &str,
+        authenticated_at: i64,
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT INTO lnurl_auth_domains (domain, linking_key, first_authenticated_at, last_authenticated_at, auth_count)
+             VALUES (:domain, :linking_key, :authenticated_at, :authenticated_at, 1)
+             ON CONFLICT(domain) DO UPDATE SET
+              linking_key = excluded.linking_key,
+              last_authenticated_at = excluded.last_authenticated_at,
+              auth_count = auth_count + 1",
+            named_params! {
+                ":domain": domain,
+                ":linking_key": linking_key,
+                ":authenticated_at": authenticated_at,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Merges a domain of an imported history with the local one
+    pub(crate) fn import_lnurl_auth_domain(&self, domain: &LnUrlAuthDomain) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT INTO lnurl_auth_domains (domain, linking_key, first_authenticated_at, last_authenticated_at, auth_count)
+             VALUES (:domain, :linking_key, :first_authenticated_at, :last_authenticated_at, :auth_count)
+             ON CONFLICT(domain) DO UPDATE SET
+              first_authenticated_at = MIN(first_authenticated_at, excluded.first_authenticated_at),
+              last_authenticated_at = MAX(last_authenticated_at, excluded.last_authenticated_at),
+              auth_count = MAX(auth_count, excluded.auth_count)",
+            named_params! {
+                ":domain": domain.domain,
+                ":linking_key": domain.linking_key,
+                ":first_authenticated_at": domain.first_authenticated_at,
+                ":last_authenticated_at": domain.last_authenticated_at,
+                ":auth_count": domain.auth_count,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// The authenticated domains, the most recently used first
+    pub(crate) fn list_lnurl_auth_domains(&self) -> PersistResult<Vec<LnUrlAuthDomain>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT domain, linking_key, first_authenticated_at, last_authenticated_at, auth_count
+             FROM lnurl_auth_domains
+             ORDER BY last_authenticated_at DESC",
+        )?;
+        let domains = stmt
+            .query_map([], |row| self.sql_row_to_lnurl_auth_domain(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(domains)
+    }
+
+    fn sql_row_to_lnurl_auth_domain(
+        &self,
+        row: &Row,
+    ) -> PersistResult<LnUrlAuthDomain, rusqlite::Error> {
+        Ok(LnUrlAuthDomain {
+            domain: row.get("domain")?,
+            linking_key: row.get("linking_key")?,
+            first_authenticated_at: row.get("first_authenticated_at")?,
+            last_authenticated_at: row.get("last_authenticated_at")?,
+            auth_count: row.get("auth_count")?,
+        })
+    }
+}
+
+#[test]
+fn test_lnurl_auth_attempts() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    assert!(storage
+        .insert_lnurl_auth_attempt("k1", "domain.com", 10)
+        .unwrap());
+    assert!(storage
+        .insert_lnurl_auth_attempt("k2", "domain.com", 20)
+        .unwrap());
+    assert!(storage
+        .insert_lnurl_auth_attempt("k3", "other.com", 20)
+        .unwrap());
+
+    // A challenge can only be recorded once, whatever the domain
+    assert!(!storage
+        .insert_lnurl_auth_attempt("k1", "other.com", 30)
+        .unwrap());
+
+    assert_eq!(
+        storage.count_lnurl_auth_attempts("domain.com", 0).unwrap(),
+        2
+    );
+    assert_eq!(
+        storage.count_lnurl_auth_attempts("domain.com", 15).unwrap(),
+        1
+    );
+    assert_eq!(
+        storage.count_lnurl_auth_attempts("other.com", 0).unwrap(),
+        1
+    );
+    assert_eq!(
+        storage.count_lnurl_auth_attempts("unknown.com", 0).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_lnurl_auth_domains() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    storage
+        .record_lnurl_auth_domain("domain.com", "key1", 10)
+        .unwrap();
+    storage
+        .record_lnurl_auth_domain("other.com", "key2", 15)
+        .unwrap();
+    storage
+        .record_lnurl_auth_domain("domain.com", "key1", 20)
+        .unwrap();
+
+    let domains = storage.list_lnurl_auth_domains().unwrap();
+    assert_eq!(
+        domains,
+        vec![
+            LnUrlAuthDomain {
+                domain: "domain.com".into(),
+                linking_key: "key1".into(),
+                first_authenticated_at: 10,
+                last_authenticated_at: 20,
+                auth_count: 2,
+            },
+            LnUrlAuthDomain {
+                domain: "other.com".into(),
+                linking_key: "key2".into(),
+                first_authenticated_at: 15,
+                last_authenticated_at: 15,
+                auth_count: 1,
+            },
+        ]
+    );
+
+    // An imported history is merged with the local one
+    storage
+        .import_lnurl_auth_domain(&LnUrlAuthDomain {
+            domain: "domain.com".into(),
+            linking_key: "key1".into(),
+            first_authenticated_at: 5,
+            last_authenticated_at: 8,
+            auth_count: 3,
+        })
+        .unwrap();
+    let domain = storage.list_lnurl_auth_domains().unwrap().remove(0);
+    assert_eq!(domain.first_authenticated_at, 5);
+    assert_eq!(domain.last_authenticated_at, 20);
+    assert_eq!(domain.auth_count, 3);
+}
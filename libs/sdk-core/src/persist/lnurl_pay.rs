@@ -0,0 +1,119 @@
+use rusqlite::types::Type;
+use rusqlite::{named_params, Row};
+use sdk_common::prelude::*;
+
+use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::PaymentExternalInfo;
+
+/// An LNURL-pay whose payment didn't complete yet. Its success action is processed with the
+/// preimage and its details are stored with the payment once the outcome is known.
+#[derive(Debug, PartialEq)]
+pub(crate) struct PendingLnurlPay {
+    pub(crate) payment_hash: String,
+    pub(crate) success_action: Option<SuccessAction>,
+    pub(crate) external_info: PaymentExternalInfo,
+    pub(crate) created_at: i64,
+}
+
+impl SqliteStorage {
+    pub(crate) fn insert_pending_lnurl_pay(&self, pending: &PendingLnurlPay) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO pending_lnurl_pays (payment_hash, success_action, external_info, created_at)
+             VALUES (:payment_hash, :success_action, :external_info, :created_at)",
+            named_params! {
+                ":payment_hash": pending.payment_hash,
+                ":success_action": pending
+                    .success_action
+                    .as_ref()
+                    .map(serde_json::to_string)
+                    .transpose()?,
+                ":external_info": serde_json::to_string(&pending.external_info)?,
+                ":created_at": pending.created_at,
+            },
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn list_pending_lnurl_pays(&self) -> PersistResult<Vec<PendingLnurlPay>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT payment_hash, success_action, external_info, created_at
+             FROM pending_lnurl_pays
+             ORDER BY created_at",
+        )?;
+        let pays = stmt
+            .query_map([], |row| self.sql_row_to_pending_lnurl_pay(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(pays)
+    }
+
+    pub(crate) fn delete_pending_lnurl_pay(&self, payment_hash: &str) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "DELETE FROM pending_lnurl_pays WHERE payment_hash = ?1",
+            [payment_hash],
+        )?;
+        Ok(())
+    }
+
+    fn sql_row_to_pending_lnurl_pay(
+        &self,
+        row: &Row,
+    ) -> PersistResult<PendingLnurlPay, rusqlite::Error> {
+        let from_json_err =
+            |idx, e| rusqlite::Error::FromSqlConversionFailure(idx, Type::Text, Box::new(e));
+        let success_action: Option<String> = row.get("success_action")?;
+        let external_info: String = row.get("external_info")?;
+        Ok(PendingLnurlPay {
+            payment_hash: row.get("payment_hash")?,
+            success_action: success_action
+                .map(|sa| serde_json::from_str(&sa))
+                .transpose()
+                .map_err(|e| from_json_err(1, e))?,
+            external_info: serde_json::from_str(&external_info).map_err(|e| from_json_err(2, e))?,
+            created_at: row.get("created_at")?,
+        })
+    }
+}
+
+#[test]
+fn test_pending_lnurl_pays() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    let first = PendingLnurlPay {
+        payment_hash: "hash1".into(),
+        success_action: Some(SuccessAction::Message {
+            data: MessageSuccessActionData {
+                message: "Thanks".into(),
+            },
+        }),
+        external_info: PaymentExternalInfo {
+            lnurl_pay_domain: Some("domain.com".into()),
+            lnurl_pay_comment: Some("comment".into()),
+            attempted_amount_msat: Some(1000),
+            ..Default::default()
+        },
+        created_at: 10,
+    };
+    let second = PendingLnurlPay {
+        payment_hash: "hash2".into(),
+        success_action: None,
+        external_info: PaymentExternalInfo {
+            ln_address: Some("user@domain.com".into()),
+            ..Default::default()
+        },
+        created_at: 20,
+    };
+    storage.insert_pending_lnurl_pay(&second).unwrap();
+    storage.insert_pending_lnurl_pay(&first).unwrap();
+
+    let pending = storage.list_pending_lnurl_pays().unwrap();
+    assert_eq!(pending, vec![first, second]);
+
+    storage.delete_pending_lnurl_pay("hash1").unwrap();
+    let pending = storage.list_pending_lnurl_pays().unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].payment_hash, "hash2");
+}
@@ -0,0 +1,147 @@
+use rusqlite::{named_params, OptionalExtension, Row};
+
+use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::LnurlWithdrawVoucher;
+
+const SELECT_LNURL_WITHDRAW_VOUCHERS: &str = "
+    SELECT id, lnurl, amount_msat, uses, redeemed_uses, description, created_at, expires_at
+    FROM lnurl_withdraw_vouchers";
+
+impl SqliteStorage {
+    pub(crate) fn insert_lnurl_withdraw_voucher(
+        &self,
+        voucher: &LnurlWithdrawVoucher,
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT INTO lnurl_withdraw_vouchers (id, lnurl, amount_msat, uses, redeemed_uses, description, created_at, expires_at)
+             VALUES (:id, :lnurl, :amount_msat, :uses, :redeemed_uses, :description, :created_at, :expires_at)",
+            named_params! {
+                ":id": voucher.id,
+                ":lnurl": voucher.lnurl,
+                ":amount_msat": voucher.amount_msat,
+                ":uses": voucher.uses,
+                ":redeemed_uses": voucher.redeemed_uses,
+                ":description": voucher.description,
+                ":created_at": voucher.created_at,
+                ":expires_at": voucher.expires_at,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// The vouchers, the newest first
+    pub(crate) fn list_lnurl_withdraw_vouchers(&self) -> PersistResult<Vec<LnurlWithdrawVoucher>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(&format!(
+            "{SELECT_LNURL_WITHDRAW_VOUCHERS} ORDER BY created_at DESC"
+        ))?;
+        let vouchers = stmt
+            .query_map([], |row| self.sql_row_to_lnurl_withdraw_voucher(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(vouchers)
+    }
+
+    pub(crate) fn get_lnurl_withdraw_voucher(
+        &self,
+        id: &str,
+    ) -> PersistResult<Option<LnurlWithdrawVoucher>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                &format!("{SELECT_LNURL_WITHDRAW_VOUCHERS} WHERE id = ?1"),
+                [id],
+                |row| self.sql_row_to_lnurl_withdraw_voucher(row),
+            )
+            .optional()?)
+    }
+
+    /// Takes a use of the voucher, if it has uses left and hasn't expired at `now`. Returns false
+    /// otherwise.
+    pub(crate) fn claim_lnurl_withdraw_voucher_use(
+        &self,
+        id: &str,
+        now: i64,
+    ) -> PersistResult<bool> {
+        let updated = self.get_connection()?.execute(
+            "UPDATE lnurl_withdraw_vouchers
+             SET redeemed_uses = redeemed_uses + 1
+             WHERE id = ?1 AND redeemed_uses < uses AND expires_at > ?2",
+            (id, now),
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Gives back a use taken by [SqliteStorage::claim_lnurl_withdraw_voucher_use], when paying
+    /// the claimant failed
+    pub(crate) fn release_lnurl_withdraw_voucher_use(&self, id: &str) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "UPDATE lnurl_withdraw_vouchers
+             SET redeemed_uses = redeemed_uses - 1
+             WHERE id = ?1 AND redeemed_uses > 0",
+            [id],
+        )?;
+        Ok(())
+    }
+
+    fn sql_row_to_lnurl_withdraw_voucher(
+        &self,
+        row: &Row,
+    ) -> PersistResult<LnurlWithdrawVoucher, rusqlite::Error> {
+        Ok(LnurlWithdrawVoucher {
+            id: row.get("id")?,
+            lnurl: row.get("lnurl")?,
+            amount_msat: row.get("amount_msat")?,
+            uses: row.get("uses")?,
+            redeemed_uses: row.get("redeemed_uses")?,
+            description: row.get("description")?,
+            created_at: row.get("created_at")?,
+            expires_at: row.get("expires_at")?,
+        })
+    }
+}
+
+#[test]
+fn test_lnurl_withdraw_vouchers() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    let voucher = LnurlWithdrawVoucher {
+        id: "k1".to_string(),
+        lnurl: "lnurl1".to_string(),
+        amount_msat: 10_000,
+        uses: 2,
+        redeemed_uses: 0,
+        description: Some("Gift".to_string()),
+        created_at: 100,
+        expires_at: 200,
+    };
+    storage.insert_lnurl_withdraw_voucher(&voucher).unwrap();
+    assert_eq!(
+        storage.get_lnurl_withdraw_voucher("k1").unwrap(),
+        Some(voucher.clone())
+    );
+    assert_eq!(storage.get_lnurl_withdraw_voucher("k2").unwrap(), None);
+
+    // An expired voucher can't be redeemed
+    assert!(!storage.claim_lnurl_withdraw_voucher_use("k1", 200).unwrap());
+
+    assert!(storage.claim_lnurl_withdraw_voucher_use("k1", 150).unwrap());
+    assert!(storage.claim_lnurl_withdraw_voucher_use("k1", 150).unwrap());
+    assert!(!storage.claim_lnurl_withdraw_voucher_use("k1", 150).unwrap());
+
+    // A released use can be taken again
+    storage.release_lnurl_withdraw_voucher_use("k1").unwrap();
+    assert_eq!(
+        storage
+            .get_lnurl_withdraw_voucher("k1")
+            .unwrap()
+            .unwrap()
+            .redeemed_uses,
+        1
+    );
+    assert!(storage.claim_lnurl_withdraw_voucher_use("k1", 150).unwrap());
+
+    assert_eq!(storage.list_lnurl_withdraw_vouchers().unwrap().len(), 1);
+}
@@ -1,3 +1,8 @@
+/// The oldest schema of the synced DB, as counted by [current_sync_migrations], a backup can have
+/// to be restored. Older backups are migrated to the current schema when restored, so this only
+/// has to be raised if old sync migrations are ever removed.
+pub(crate) const MIN_BACKUP_SCHEMA_VERSION: u32 = 1;
+
 pub(crate) fn current_migrations() -> Vec<&'static str> {
     vec![
         "
@@ -486,7 +491,160 @@ pub(crate) fn current_migrations() -> Vec<&'static str> {
         DELETE FROM payments;
         DELETE FROM cached_items WHERE key = 'sync_state';
        ",
-       "ALTER TABLE swaps_info ADD COLUMN chain_data TEXT;"
+       "ALTER TABLE swaps_info ADD COLUMN chain_data TEXT;",
+       "
+       CREATE TABLE IF NOT EXISTS open_channel_registrations (
+        payment_hash TEXT PRIMARY KEY NOT NULL,
+        opening_fee_params TEXT NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS fiat_rates (
+        currency TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        value REAL NOT NULL,
+        PRIMARY KEY (currency, timestamp)
+       ) STRICT;
+
+       CREATE TABLE IF NOT EXISTS payment_fiat_rates (
+        payment_id TEXT PRIMARY KEY NOT NULL,
+        currency TEXT NOT NULL,
+        value REAL NOT NULL
+       ) STRICT;
+       ",
+       "ALTER TABLE payments ADD COLUMN is_imported INTEGER DEFAULT 0 NOT NULL;",
+       "
+       CREATE TABLE IF NOT EXISTS payment_tlvs (
+        payment_id TEXT PRIMARY KEY NOT NULL,
+        tlvs TEXT NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS redeem_txs (
+        txid TEXT PRIMARY KEY NOT NULL,
+        to_address TEXT NOT NULL,
+        amount_sat INTEGER,
+        sat_per_vbyte INTEGER NOT NULL,
+        created_at INTEGER NOT NULL
+       ) STRICT;
+
+       CREATE TABLE IF NOT EXISTS tx_replacements (
+        replaced_txid TEXT PRIMARY KEY NOT NULL,
+        txid TEXT NOT NULL,
+        sat_per_vbyte INTEGER NOT NULL,
+        created_at INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS payment_outbox (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        bolt11 TEXT NOT NULL,
+        amount_msat INTEGER,
+        label TEXT,
+        use_trampoline INTEGER,
+        route_hint_index INTEGER,
+        created_at INTEGER NOT NULL,
+        expires_at INTEGER NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        next_attempt_at INTEGER NOT NULL,
+        last_error TEXT
+       ) STRICT;
+       ",
+       "
+       CREATE INDEX IF NOT EXISTS payments_payment_time ON payments(payment_time);
+       CREATE INDEX IF NOT EXISTS payments_payment_type ON payments(payment_type, payment_time);
+       CREATE INDEX IF NOT EXISTS payments_status ON payments(status, payment_time);
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS lnurl_auth_attempts (
+        k1 TEXT NOT NULL PRIMARY KEY,
+        domain TEXT NOT NULL,
+        created_at INTEGER NOT NULL
+       ) STRICT;
+
+       CREATE INDEX IF NOT EXISTS lnurl_auth_attempts_domain ON lnurl_auth_attempts(domain, created_at);
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS lnurl_withdraw_vouchers (
+        id TEXT NOT NULL PRIMARY KEY,
+        lnurl TEXT NOT NULL,
+        amount_msat INTEGER NOT NULL,
+        uses INTEGER NOT NULL,
+        redeemed_uses INTEGER NOT NULL DEFAULT 0,
+        description TEXT,
+        created_at INTEGER NOT NULL,
+        expires_at INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS lnurl_auth_domains (
+        domain TEXT NOT NULL PRIMARY KEY,
+        linking_key TEXT NOT NULL,
+        first_authenticated_at INTEGER NOT NULL,
+        last_authenticated_at INTEGER NOT NULL,
+        auth_count INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS pending_lnurl_pays (
+        payment_hash TEXT NOT NULL PRIMARY KEY,
+        success_action TEXT,
+        external_info TEXT NOT NULL,
+        created_at INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS channel_activity (
+        funding_txid TEXT NOT NULL PRIMARY KEY,
+        local_balance_msat INTEGER NOT NULL,
+        last_activity_at INTEGER NOT NULL,
+        peer_connected_at INTEGER NOT NULL,
+        suggested_at INTEGER
+       ) STRICT;
+       ",
+       "
+       ALTER TABLE payment_outbox ADD COLUMN payment_timeout_sec INTEGER;
+       ALTER TABLE payment_outbox ADD COLUMN maxfee_percent REAL;
+       ALTER TABLE payment_outbox ADD COLUMN exemptfee_msat INTEGER;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS fiat_rate_alerts (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        currency TEXT NOT NULL,
+        direction INTEGER NOT NULL,
+        threshold REAL NOT NULL,
+        created_at INTEGER NOT NULL,
+        triggered INTEGER NOT NULL DEFAULT 0,
+        last_triggered_at INTEGER
+       ) STRICT;
+       ",
+       "
+       CREATE TABLE IF NOT EXISTS payment_probes (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        node_id TEXT NOT NULL,
+        bolt11 TEXT,
+        amount_msat INTEGER NOT NULL,
+        route_found INTEGER NOT NULL,
+        reachable INTEGER NOT NULL,
+        estimated_fee_msat INTEGER,
+        hops INTEGER,
+        error TEXT,
+        created_at INTEGER NOT NULL
+       ) STRICT;
+       ",
+       "ALTER TABLE payment_outbox ADD COLUMN skip_denylist INTEGER;",
+       "
+       UPDATE payments
+       SET details = json_set(details, '$.keysend', json('true'))
+       WHERE payment_type = 'Received' AND json_extract(details, '$.label') LIKE 'keysend-%';
+       ",
+       "
+       CREATE TABLE spends (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        amount_msat INTEGER NOT NULL,
+        spent_at INTEGER NOT NULL
+       ) STRICT;
+       ",
     ]
 }
 
@@ -673,5 +831,34 @@ pub(crate) fn current_sync_migrations() -> Vec<&'static str> {
         ",
         "ALTER TABLE payments_external_info ADD COLUMN lnurl_pay_comment TEXT;",
         "DELETE FROM open_channel_payment_info;",
+        "
+        CREATE TABLE IF NOT EXISTS user_settings (
+         key TEXT NOT NULL PRIMARY KEY,
+         value TEXT NOT NULL,
+         updated_at TEXT DEFAULT CURRENT_TIMESTAMP NOT NULL
+        ) STRICT;
+
+        CREATE TRIGGER IF NOT EXISTS sync_requests_user_settings
+         AFTER INSERT ON user_settings
+        BEGIN
+         INSERT INTO sync_requests(changed_table) VALUES('user_settings');
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS sync_requests_user_settings_update
+         AFTER UPDATE ON user_settings
+        BEGIN
+         INSERT INTO sync_requests(changed_table) VALUES('user_settings');
+        END;
+        ",
+        "
+        ALTER TABLE reverse_swaps ADD COLUMN protocol TEXT NOT NULL DEFAULT 'Legacy';
+        ALTER TABLE reverse_swaps ADD COLUMN refund_pubkey TEXT;
+        ",
+        "ALTER TABLE open_channel_payment_info ADD COLUMN opening_fee_params TEXT;",
+        // Add the index of the swap refund key. Existing swaps derived it from the number of swaps created before them.
+        "
+        ALTER TABLE swaps ADD COLUMN refund_key_index INTEGER;
+        UPDATE swaps SET refund_key_index = (SELECT COUNT(*) FROM swaps AS earlier WHERE earlier.rowid < swaps.rowid);
+        ",
 	]
 }
@@ -2,13 +2,24 @@ pub(crate) mod cache;
 pub(crate) mod channels;
 pub(crate) mod db;
 pub(crate) mod error;
+pub(crate) mod fiat_rates;
+#[cfg(feature = "lnurl")]
+pub(crate) mod lnurl_auth;
+#[cfg(feature = "lnurl")]
+pub(crate) mod lnurl_pay;
+pub(crate) mod lnurl_withdraw_vouchers;
 pub(crate) mod migrations;
+pub(crate) mod onchain_txs;
+pub(crate) mod outbox;
+pub(crate) mod probes;
 pub(crate) mod reverseswap;
 pub(crate) mod send_pays;
 pub(crate) mod settings;
+pub(crate) mod spends;
 pub(crate) mod swap;
 pub(crate) mod sync;
 pub(crate) mod transactions;
+pub(crate) mod writer;
 
 #[cfg(test)]
 mod test_utils {
@@ -0,0 +1,111 @@
+use rusqlite::{named_params, OptionalExtension, Row};
+
+use super::{db::SqliteStorage, error::PersistResult};
+
+/// A transaction sent by [crate::BreezServices::redeem_onchain_funds]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RedeemTx {
+    pub(crate) txid: String,
+    pub(crate) to_address: String,
+    /// The amount sent, or `None` if all the on-chain funds were sent
+    pub(crate) amount_sat: Option<u64>,
+    pub(crate) sat_per_vbyte: u32,
+    pub(crate) created_at: i64,
+}
+
+impl SqliteStorage {
+    pub(crate) fn insert_redeem_tx(&self, redeem_tx: &RedeemTx) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO redeem_txs (txid, to_address, amount_sat, sat_per_vbyte, created_at)
+             VALUES (:txid, :to_address, :amount_sat, :sat_per_vbyte, :created_at)",
+            named_params! {
+                ":txid": redeem_tx.txid,
+                ":to_address": redeem_tx.to_address,
+                ":amount_sat": redeem_tx.amount_sat,
+                ":sat_per_vbyte": redeem_tx.sat_per_vbyte,
+                ":created_at": redeem_tx.created_at,
+            },
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn get_redeem_tx(&self, txid: &str) -> PersistResult<Option<RedeemTx>> {
+        Ok(self
+            .get_connection()?
+            .query_row("SELECT * FROM redeem_txs WHERE txid = ?1", [txid], |row| {
+                self.sql_row_to_redeem_tx(row)
+            })
+            .optional()?)
+    }
+
+    /// Records that `replaced_txid` was replaced by `txid`, paying `sat_per_vbyte`
+    pub(crate) fn insert_tx_replacement(
+        &self,
+        replaced_txid: &str,
+        txid: &str,
+        sat_per_vbyte: u32,
+        created_at: i64,
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO tx_replacements (replaced_txid, txid, sat_per_vbyte, created_at)
+             VALUES (:replaced_txid, :txid, :sat_per_vbyte, :created_at)",
+            named_params! {
+                ":replaced_txid": replaced_txid,
+                ":txid": txid,
+                ":sat_per_vbyte": sat_per_vbyte,
+                ":created_at": created_at,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// The txid of the transaction that replaced `txid`, if any
+    pub(crate) fn get_tx_replacement(&self, txid: &str) -> PersistResult<Option<String>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                "SELECT txid FROM tx_replacements WHERE replaced_txid = ?1",
+                [txid],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    fn sql_row_to_redeem_tx(&self, row: &Row) -> PersistResult<RedeemTx, rusqlite::Error> {
+        Ok(RedeemTx {
+            txid: row.get("txid")?,
+            to_address: row.get("to_address")?,
+            amount_sat: row.get("amount_sat")?,
+            sat_per_vbyte: row.get("sat_per_vbyte")?,
+            created_at: row.get("created_at")?,
+        })
+    }
+}
+
+#[test]
+fn test_redeem_txs() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    let redeem_tx = RedeemTx {
+        txid: "txid1".to_string(),
+        to_address: "address".to_string(),
+        amount_sat: Some(10_000),
+        sat_per_vbyte: 2,
+        created_at: 1,
+    };
+    storage.insert_redeem_tx(&redeem_tx).unwrap();
+    assert_eq!(storage.get_redeem_tx("txid1").unwrap(), Some(redeem_tx));
+    assert!(storage.get_redeem_tx("txid2").unwrap().is_none());
+
+    assert!(storage.get_tx_replacement("txid1").unwrap().is_none());
+    storage
+        .insert_tx_replacement("txid1", "txid2", 5, 2)
+        .unwrap();
+    assert_eq!(
+        storage.get_tx_replacement("txid1").unwrap().as_deref(),
+        Some("txid2")
+    );
+}
@@ -0,0 +1,168 @@
+use rusqlite::{named_params, OptionalExtension, Row};
+use sdk_common::prelude::MilliSat;
+
+use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::{OutboxPayment, SendPaymentRequest};
+
+const SELECT_OUTBOX_PAYMENTS: &str = "
+    SELECT id, bolt11, amount_msat, label, use_trampoline, route_hint_index, payment_timeout_sec,
+           maxfee_percent, exemptfee_msat, skip_denylist, created_at, expires_at, attempts,
+           next_attempt_at, last_error
+    FROM payment_outbox";
+
+impl SqliteStorage {
+    /// Queues the payment and returns its id
+    pub(crate) fn insert_outbox_payment(
+        &self,
+        req: &SendPaymentRequest,
+        created_at: i64,
+        expires_at: i64,
+    ) -> PersistResult<i64> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO payment_outbox (bolt11, amount_msat, label, use_trampoline, route_hint_index, payment_timeout_sec, maxfee_percent, exemptfee_msat, skip_denylist, created_at, expires_at, next_attempt_at)
+             VALUES (:bolt11, :amount_msat, :label, :use_trampoline, :route_hint_index, :payment_timeout_sec, :maxfee_percent, :exemptfee_msat, :skip_denylist, :created_at, :expires_at, :created_at)",
+            named_params! {
+                ":bolt11": req.bolt11,
+                ":amount_msat": req.amount_msat.map(MilliSat::msat),
+                ":label": req.label,
+                ":use_trampoline": req.use_trampoline,
+                ":route_hint_index": req.route_hint_index,
+                ":payment_timeout_sec": req.payment_timeout_sec,
+                ":maxfee_percent": req.maxfee_percent,
+                ":exemptfee_msat": req.exemptfee_msat.map(MilliSat::msat),
+                ":skip_denylist": req.skip_denylist,
+                ":created_at": created_at,
+                ":expires_at": expires_at,
+            },
+        )?;
+        Ok(con.last_insert_rowid())
+    }
+
+    /// The queued payments, the oldest first
+    pub(crate) fn list_outbox_payments(&self) -> PersistResult<Vec<OutboxPayment>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(&format!("{SELECT_OUTBOX_PAYMENTS} ORDER BY id"))?;
+        let outbox_payments = stmt
+            .query_map([], |row| self.sql_row_to_outbox_payment(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(outbox_payments)
+    }
+
+    pub(crate) fn get_outbox_payment(&self, id: i64) -> PersistResult<Option<OutboxPayment>> {
+        Ok(self
+            .get_connection()?
+            .query_row(
+                &format!("{SELECT_OUTBOX_PAYMENTS} WHERE id = ?1"),
+                [id],
+                |row| self.sql_row_to_outbox_payment(row),
+            )
+            .optional()?)
+    }
+
+    /// Records a failed attempt of the queued payment
+    pub(crate) fn update_outbox_payment_attempt(
+        &self,
+        outbox_payment: &OutboxPayment,
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "UPDATE payment_outbox
+             SET attempts = :attempts, next_attempt_at = :next_attempt_at, last_error = :last_error
+             WHERE id = :id",
+            named_params! {
+                ":id": outbox_payment.id,
+                ":attempts": outbox_payment.attempts,
+                ":next_attempt_at": outbox_payment.next_attempt_at,
+                ":last_error": outbox_payment.last_error,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Removes the payment from the queue. Returns false if it wasn't queued.
+    pub(crate) fn delete_outbox_payment(&self, id: i64) -> PersistResult<bool> {
+        let deleted = self
+            .get_connection()?
+            .execute("DELETE FROM payment_outbox WHERE id = ?1", [id])?;
+        Ok(deleted > 0)
+    }
+
+    fn sql_row_to_outbox_payment(
+        &self,
+        row: &Row,
+    ) -> PersistResult<OutboxPayment, rusqlite::Error> {
+        Ok(OutboxPayment {
+            id: row.get("id")?,
+            req: SendPaymentRequest {
+                bolt11: row.get("bolt11")?,
+                use_trampoline: row.get("use_trampoline")?,
+                amount_msat: row.get::<_, Option<u64>>("amount_msat")?.map(MilliSat),
+                label: row.get("label")?,
+                route_hint_index: row.get("route_hint_index")?,
+                payment_timeout_sec: row.get("payment_timeout_sec")?,
+                maxfee_percent: row.get("maxfee_percent")?,
+                exemptfee_msat: row.get::<_, Option<u64>>("exemptfee_msat")?.map(MilliSat),
+                cancel_token: None,
+                skip_denylist: row.get("skip_denylist")?,
+            },
+            created_at: row.get("created_at")?,
+            expires_at: row.get("expires_at")?,
+            attempts: row.get("attempts")?,
+            next_attempt_at: row.get("next_attempt_at")?,
+            last_error: row.get("last_error")?,
+        })
+    }
+}
+
+#[test]
+fn test_outbox_payments() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    let req = SendPaymentRequest {
+        bolt11: "bolt11".to_string(),
+        use_trampoline: Some(true),
+        amount_msat: Some(MilliSat(1_000)),
+        label: None,
+        route_hint_index: None,
+        payment_timeout_sec: Some(30),
+        maxfee_percent: Some(0.5),
+        exemptfee_msat: None,
+        cancel_token: None,
+        skip_denylist: None,
+    };
+    let id = storage.insert_outbox_payment(&req, 10, 100).unwrap();
+    let other_id = storage.insert_outbox_payment(&req, 20, 100).unwrap();
+    assert_ne!(id, other_id);
+
+    let mut outbox_payment = storage.get_outbox_payment(id).unwrap().unwrap();
+    assert_eq!(outbox_payment.req.use_trampoline, Some(true));
+    assert_eq!(outbox_payment.req.amount_msat, Some(MilliSat(1_000)));
+    assert_eq!(outbox_payment.req.payment_timeout_sec, Some(30));
+    assert_eq!(outbox_payment.req.maxfee_percent, Some(0.5));
+    assert_eq!(outbox_payment.attempts, 0);
+    assert_eq!(outbox_payment.next_attempt_at, 10);
+
+    outbox_payment.attempts = 1;
+    outbox_payment.next_attempt_at = 40;
+    outbox_payment.last_error = Some("offline".to_string());
+    storage
+        .update_outbox_payment_attempt(&outbox_payment)
+        .unwrap();
+    let outbox_payment = storage.get_outbox_payment(id).unwrap().unwrap();
+    assert_eq!(outbox_payment.attempts, 1);
+    assert_eq!(outbox_payment.next_attempt_at, 40);
+    assert_eq!(outbox_payment.last_error.as_deref(), Some("offline"));
+
+    assert!(storage.delete_outbox_payment(id).unwrap());
+    assert!(!storage.delete_outbox_payment(id).unwrap());
+    let ids: Vec<i64> = storage
+        .list_outbox_payments()
+        .unwrap()
+        .iter()
+        .map(|p| p.id)
+        .collect();
+    assert_eq!(ids, vec![other_id]);
+}
@@ -0,0 +1,98 @@
+use rusqlite::{named_params, Row};
+
+use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::PaymentProbe;
+
+impl SqliteStorage {
+    /// Stores the probe and returns its id
+    pub(crate) fn insert_payment_probe(&self, probe: &PaymentProbe) -> PersistResult<i64> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO payment_probes (node_id, bolt11, amount_msat, route_found, reachable, estimated_fee_msat, hops, error, created_at)
+             VALUES (:node_id, :bolt11, :amount_msat, :route_found, :reachable, :estimated_fee_msat, :hops, :error, :created_at)",
+            named_params! {
+                ":node_id": probe.node_id,
+                ":bolt11": probe.bolt11,
+                ":amount_msat": probe.amount_msat,
+                ":route_found": probe.route_found,
+                ":reachable": probe.reachable,
+                ":estimated_fee_msat": probe.estimated_fee_msat,
+                ":hops": probe.hops,
+                ":error": probe.error,
+                ":created_at": probe.created_at,
+            },
+        )?;
+        Ok(con.last_insert_rowid())
+    }
+
+    /// The probes, the most recent first
+    pub(crate) fn list_payment_probes(&self) -> PersistResult<Vec<PaymentProbe>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT * FROM payment_probes ORDER BY id DESC")?;
+        let probes = stmt
+            .query_map([], |row| self.sql_row_to_payment_probe(row))?
+            .collect::<Result<Vec<PaymentProbe>, _>>()?;
+        Ok(probes)
+    }
+
+    fn sql_row_to_payment_probe(&self, row: &Row) -> PersistResult<PaymentProbe, rusqlite::Error> {
+        Ok(PaymentProbe {
+            id: row.get("id")?,
+            node_id: row.get("node_id")?,
+            bolt11: row.get("bolt11")?,
+            amount_msat: row.get("amount_msat")?,
+            route_found: row.get("route_found")?,
+            reachable: row.get("reachable")?,
+            estimated_fee_msat: row.get("estimated_fee_msat")?,
+            hops: row.get("hops")?,
+            error: row.get("error")?,
+            created_at: row.get("created_at")?,
+        })
+    }
+}
+
+#[test]
+fn test_payment_probes() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    let probe = PaymentProbe {
+        id: 0,
+        node_id: "node".to_string(),
+        bolt11: None,
+        amount_msat: 1_000_000,
+        route_found: true,
+        reachable: true,
+        estimated_fee_msat: Some(1_000),
+        hops: Some(3),
+        error: None,
+        created_at: 100,
+    };
+    let first_id = storage.insert_payment_probe(&probe).unwrap();
+    let failed = PaymentProbe {
+        route_found: false,
+        reachable: false,
+        estimated_fee_msat: None,
+        hops: None,
+        error: Some("No route".to_string()),
+        created_at: 200,
+        ..probe.clone()
+    };
+    let second_id = storage.insert_payment_probe(&failed).unwrap();
+
+    let probes = storage.list_payment_probes().unwrap();
+    assert_eq!(
+        probes,
+        vec![
+            PaymentProbe {
+                id: second_id,
+                ..failed
+            },
+            PaymentProbe {
+                id: first_id,
+                ..probe
+            },
+        ]
+    );
+}
@@ -1,5 +1,9 @@
+#![cfg_attr(not(feature = "reverse-swaps"), allow(dead_code))]
+
 use super::{db::SqliteStorage, error::PersistResult};
-use crate::{FullReverseSwapInfo, ReverseSwapInfoCached, ReverseSwapStatus};
+use std::str::FromStr;
+
+use crate::{FullReverseSwapInfo, ReverseSwapInfoCached, ReverseSwapProtocol, ReverseSwapStatus};
 use rusqlite::{named_params, OptionalExtension, Row, TransactionBehavior};
 
 impl SqliteStorage {
@@ -8,8 +12,8 @@ impl SqliteStorage {
         let tx = con.transaction_with_behavior(TransactionBehavior::Immediate)?;
 
         tx.execute(
-            "INSERT INTO sync.reverse_swaps (id, created_at_block_height, preimage, private_key, claim_pubkey, timeout_block_height, invoice, onchain_amount_sat, sat_per_vbyte, receive_amount_sat, redeem_script)\
-            VALUES (:id, :created_at_block_height, :preimage, :private_key, :claim_pubkey, :timeout_block_height, :invoice, :onchain_amount_sat, :sat_per_vbyte, :receive_amount_sat, :redeem_script)",
+            "INSERT INTO sync.reverse_swaps (id, created_at_block_height, preimage, private_key, claim_pubkey, timeout_block_height, invoice, onchain_amount_sat, sat_per_vbyte, receive_amount_sat, redeem_script, protocol, refund_pubkey)\
+            VALUES (:id, :created_at_block_height, :preimage, :private_key, :claim_pubkey, :timeout_block_height, :invoice, :onchain_amount_sat, :sat_per_vbyte, :receive_amount_sat, :redeem_script, :protocol, :refund_pubkey)",
             named_params! {
                 ":id": rsi.id,
                 ":created_at_block_height": rsi.created_at_block_height,
@@ -21,7 +25,9 @@ impl SqliteStorage {
                 ":onchain_amount_sat": rsi.onchain_amount_sat,
                 ":sat_per_vbyte": rsi.sat_per_vbyte,
                 ":receive_amount_sat": rsi.receive_amount_sat,
-                ":redeem_script": rsi.redeem_script
+                ":redeem_script": rsi.redeem_script,
+                ":protocol": rsi.protocol.to_string(),
+                ":refund_pubkey": rsi.refund_pubkey
             },
         )?;
 
@@ -129,6 +135,8 @@ impl SqliteStorage {
         {prefix}sat_per_vbyte,
         {prefix}receive_amount_sat,
         {prefix}redeem_script,
+        {prefix}protocol,
+        {prefix}refund_pubkey,
         {prefix}status,
         {prefix}lockup_txid,
         {prefix}claim_txid           
@@ -154,6 +162,10 @@ impl SqliteStorage {
             sat_per_vbyte: row.get(format!("{prefix}sat_per_vbyte").as_str())?,
             receive_amount_sat: row.get(format!("{prefix}receive_amount_sat").as_str())?,
             redeem_script: row.get(format!("{prefix}redeem_script").as_str())?,
+            protocol: row
+                .get::<_, String>(format!("{prefix}protocol").as_str())
+                .map(|protocol| ReverseSwapProtocol::from_str(&protocol).unwrap_or_default())?,
+            refund_pubkey: row.get(format!("{prefix}refund_pubkey").as_str())?,
             cache: ReverseSwapInfoCached {
                 // The status is stored in the main DB, which is empty when the node is restored.
                 // We therefore default to the Initial state. This will be updated at the end of sync().
@@ -179,6 +191,8 @@ impl SqliteStorage {
             sat_per_vbyte as {prefix}sat_per_vbyte,
             receive_amount_sat as {prefix}receive_amount_sat,
             redeem_script as {prefix}redeem_script,
+            protocol as {prefix}protocol,
+            refund_pubkey as {prefix}refund_pubkey,
             status as {prefix}status,
             lockup_txid as {prefix}lockup_txid,
             claim_txid as {prefix}claim_txid         
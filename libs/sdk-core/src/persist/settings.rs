@@ -1,6 +1,8 @@
 use serde::Serialize;
+use serde_json::{Map, Value};
 
 use super::{db::SqliteStorage, error::PersistResult};
+use crate::models::UserSettings;
 
 #[allow(dead_code)]
 #[derive(Serialize)]
@@ -27,7 +29,6 @@ impl SqliteStorage {
         Ok(res.ok())
     }
 
-    #[allow(dead_code)]
     pub fn delete_setting(&self, key: String) -> PersistResult<()> {
         self.get_connection()?
             .execute("DELETE FROM settings WHERE key = ?1", [key])?;
@@ -81,6 +82,47 @@ impl SqliteStorage {
     pub fn get_lsp_pubkey(&self) -> PersistResult<Option<String>> {
         self.get_setting("lsp-pubkey".to_string())
     }
+
+    /// Reads the [UserSettings], stored in the synced `user_settings` table with one row per
+    /// field, holding its JSON value
+    pub fn get_user_settings(&self) -> PersistResult<UserSettings> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare("SELECT key, value FROM sync.user_settings")?;
+        let settings = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .map(|row| -> PersistResult<(String, Value)> {
+                let (key, value) = row?;
+                Ok((key, serde_json::from_str(&value)?))
+            })
+            .collect::<PersistResult<Map<String, Value>>>()?;
+        Ok(serde_json::from_value(Value::Object(settings))?)
+    }
+
+    /// Stores the [UserSettings]. Only the fields that changed are written, so that concurrent
+    /// changes of other fields on another device are kept when the backups are merged.
+    pub fn set_user_settings(&self, settings: &UserSettings) -> PersistResult<()> {
+        let settings = match serde_json::to_value(settings)? {
+            Value::Object(settings) => settings,
+            _ => return Ok(()),
+        };
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for (key, value) in settings {
+            tx.execute(
+                "
+                 INSERT INTO sync.user_settings (key, value) VALUES (?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET
+                  value = excluded.value,
+                  updated_at = CURRENT_TIMESTAMP
+                 WHERE value != excluded.value",
+                (key, value.to_string()),
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
 }
 
 #[test]
@@ -112,3 +154,44 @@ fn test_settings() {
     assert_eq!(settings[1].key, "key2");
     assert_eq!(settings[1].value, "val3");
 }
+
+#[test]
+fn test_user_settings() {
+    use crate::models::{Denomination, FeeratePreset};
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+    assert_eq!(
+        storage.get_user_settings().unwrap(),
+        UserSettings::default()
+    );
+
+    let settings = UserSettings {
+        preferred_fiat_currency: Some("EUR".to_string()),
+        denomination: Some(Denomination::Sat),
+        default_feerate_preset: Some(FeeratePreset::Economy),
+        preferred_lsp_id: None,
+    };
+    storage.set_user_settings(&settings).unwrap();
+    assert_eq!(storage.get_user_settings().unwrap(), settings);
+
+    // Clearing a field is stored, so that it roams as well
+    let settings = UserSettings {
+        denomination: None,
+        ..settings
+    };
+    storage.set_user_settings(&settings).unwrap();
+    assert_eq!(storage.get_user_settings().unwrap(), settings);
+
+    // Settings written by newer versions are ignored
+    storage
+        .get_connection()
+        .unwrap()
+        .execute(
+            "INSERT INTO sync.user_settings (key, value) VALUES ('unknown', 'true')",
+            [],
+        )
+        .unwrap();
+    assert_eq!(storage.get_user_settings().unwrap(), settings);
+}
@@ -0,0 +1,109 @@
+use rusqlite::{named_params, TransactionBehavior};
+
+use super::{db::SqliteStorage, error::PersistResult};
+
+impl SqliteStorage {
+    /// Counts a spend of `amount_msat` towards the daily limit of the spend policy, unless it
+    /// would bring the spends since `since` over `limit_msat`. Spends older than `since` are
+    /// pruned.
+    ///
+    /// Returns the id of the counted spend, or `None` if the limit would be exceeded.
+    pub(crate) fn insert_spend_within_limit(
+        &self,
+        amount_msat: u64,
+        spent_at: i64,
+        since: i64,
+        limit_msat: Option<u64>,
+    ) -> PersistResult<Option<i64>> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        tx.execute("DELETE FROM spends WHERE spent_at < ?1", [since])?;
+        let spent_msat: u64 = tx.query_row(
+            "SELECT COALESCE(SUM(amount_msat), 0) FROM spends",
+            [],
+            |row| row.get(0),
+        )?;
+        if limit_msat.is_some_and(|limit_msat| spent_msat + amount_msat > limit_msat) {
+            return Ok(None);
+        }
+        tx.execute(
+            "INSERT INTO spends (amount_msat, spent_at) VALUES (:amount_msat, :spent_at)",
+            named_params! {
+                ":amount_msat": amount_msat,
+                ":spent_at": spent_at,
+            },
+        )?;
+        let id = tx.last_insert_rowid();
+        tx.commit()?;
+        Ok(Some(id))
+    }
+
+    /// The sum of the spends counted since `since`
+    pub(crate) fn get_spent_since(&self, since: i64) -> PersistResult<u64> {
+        Ok(self.get_connection()?.query_row(
+            "SELECT COALESCE(SUM(amount_msat), 0) FROM spends WHERE spent_at >= ?1",
+            [since],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Updates the amount of a spend, once the routing fee of the payment is known
+    pub(crate) fn update_spend_amount(&self, id: i64, amount_msat: u64) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "UPDATE spends SET amount_msat = :amount_msat WHERE id = :id",
+            named_params! {
+                ":id": id,
+                ":amount_msat": amount_msat,
+            },
+        )?;
+        Ok(())
+    }
+
+    /// Stops counting a spend, when the payment wasn't sent after all
+    pub(crate) fn delete_spend(&self, id: i64) -> PersistResult<()> {
+        self.get_connection()?
+            .execute("DELETE FROM spends WHERE id = ?1", [id])?;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_spends() {
+    use crate::persist::test_utils;
+
+    let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+    storage.init().unwrap();
+
+    let first = storage
+        .insert_spend_within_limit(600, 10, 0, Some(1_000))
+        .unwrap();
+    assert!(first.is_some());
+    assert_eq!(
+        storage
+            .insert_spend_within_limit(500, 20, 0, Some(1_000))
+            .unwrap(),
+        None
+    );
+    let second = storage
+        .insert_spend_within_limit(400, 20, 0, Some(1_000))
+        .unwrap();
+    assert!(second.is_some());
+    assert_eq!(storage.get_spent_since(0).unwrap(), 1_000);
+
+    // Releasing a spend makes room for another one
+    storage.delete_spend(second.unwrap()).unwrap();
+    assert_eq!(storage.get_spent_since(0).unwrap(), 600);
+    storage.update_spend_amount(first.unwrap(), 650).unwrap();
+    assert_eq!(storage.get_spent_since(0).unwrap(), 650);
+
+    // Spends before the window don't count anymore and are pruned
+    assert!(storage
+        .insert_spend_within_limit(1_000, 30, 15, Some(1_000))
+        .unwrap()
+        .is_some());
+    assert_eq!(storage.get_spent_since(0).unwrap(), 1_000);
+    assert!(storage
+        .insert_spend_within_limit(5_000, 40, 15, None)
+        .unwrap()
+        .is_some());
+}
@@ -21,7 +21,8 @@ pub(crate) trait SwapStorage: Send + Sync {
         chain_info: &SwapChainInfo,
     ) -> PersistResult<()>;
     fn set_swap_status(&self, address: &str, status: &SwapStatus) -> PersistResult<()>;
-    fn insert_swap(&self, swap_info: &SwapInfo) -> PersistResult<()>;
+    fn insert_swap(&self, swap_info: &SwapInfo, refund_key_index: Option<u32>)
+        -> PersistResult<()>;
     fn update_swap_paid_amount(&self, bitcoin_address: &str, paid_msat: u64) -> PersistResult<()>;
     fn update_swap_max_allowed_deposit(
         &self,
@@ -47,6 +48,11 @@ pub(crate) trait SwapStorage: Send + Sync {
     fn get_swap_info_by_hash(&self, hash: &[u8]) -> PersistResult<Option<SwapInfo>>;
     fn get_swap_info_by_address(&self, address: &str) -> PersistResult<Option<SwapInfo>>;
     fn list_swaps(&self, req: ListSwapsRequest) -> PersistResult<Vec<SwapInfo>>;
+    /// The index of the refund key of the next swap: one after the highest index stored with the
+    /// swaps, which are part of the backup.
+    fn next_swap_refund_key_index(&self) -> PersistResult<u32>;
+    /// The indexes of the refund keys used by the swaps, in ascending order.
+    fn list_swap_refund_key_indexes(&self) -> PersistResult<Vec<u32>>;
 }
 
 impl SwapStorage for SqliteStorage {
@@ -120,7 +126,11 @@ impl SwapStorage for SqliteStorage {
         Ok(())
     }
 
-    fn insert_swap(&self, swap_info: &SwapInfo) -> PersistResult<()> {
+    fn insert_swap(
+        &self,
+        swap_info: &SwapInfo,
+        refund_key_index: Option<u32>,
+    ) -> PersistResult<()> {
         let mut con = self.get_connection()?;
         let tx = con.transaction_with_behavior(TransactionBehavior::Immediate)?;
 
@@ -137,9 +147,10 @@ impl SwapStorage for SqliteStorage {
            script,
            min_allowed_deposit, 
            max_allowed_deposit,
-           max_swapper_payable
+           max_swapper_payable,
+           refund_key_index
          )
-         VALUES (:bitcoin_address, :created_at, :lock_height, :payment_hash, :preimage, :private_key, :public_key, :swapper_public_key, :script, :min_allowed_deposit, :max_allowed_deposit, :max_swapper_payable)",
+         VALUES (:bitcoin_address, :created_at, :lock_height, :payment_hash, :preimage, :private_key, :public_key, :swapper_public_key, :script, :min_allowed_deposit, :max_allowed_deposit, :max_swapper_payable, :refund_key_index)",
          named_params! {
              ":bitcoin_address": swap_info.bitcoin_address,
              ":created_at": swap_info.created_at,
@@ -153,6 +164,7 @@ impl SwapStorage for SqliteStorage {
              ":min_allowed_deposit": swap_info.min_allowed_deposit,
              ":max_allowed_deposit": swap_info.max_allowed_deposit,
              ":max_swapper_payable": swap_info.max_swapper_payable,
+             ":refund_key_index": refund_key_index,
          },
         )?;
 
@@ -341,6 +353,28 @@ impl SwapStorage for SqliteStorage {
 
         Ok(vec)
     }
+
+    fn next_swap_refund_key_index(&self) -> PersistResult<u32> {
+        Ok(self.get_connection()?.query_row(
+            "SELECT COALESCE(MAX(refund_key_index) + 1, 0) FROM sync.swaps",
+            [],
+            |row| row.get(0),
+        )?)
+    }
+
+    fn list_swap_refund_key_indexes(&self) -> PersistResult<Vec<u32>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT refund_key_index FROM sync.swaps
+            WHERE refund_key_index IS NOT NULL
+            ORDER BY refund_key_index",
+        )?;
+        let indexes = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<u32>, _>>()?;
+        Ok(indexes)
+    }
 }
 
 impl SqliteStorage {
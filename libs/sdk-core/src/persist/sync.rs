@@ -100,7 +100,8 @@ impl SqliteStorage {
             }
         }
 
-        let mut con = self.get_connection()?;
+        // The remote DB stays attached if the import fails, so the connection isn't reused
+        let mut con = self.open_connection()?;
         let tx = con.transaction_with_behavior(TransactionBehavior::Immediate)?;
         tx.execute("ATTACH DATABASE ? AS remote_sync;", [sync_data_file])?;
 
@@ -129,7 +130,21 @@ impl SqliteStorage {
         // sync remote swaps table
         tx.execute(
             "
-        INSERT INTO sync.swaps 
+        INSERT INTO sync.swaps (
+           bitcoin_address,
+           created_at,
+           lock_height,
+           payment_hash,
+           preimage,
+           private_key,
+           public_key,
+           swapper_public_key,
+           script,
+           min_allowed_deposit,
+           max_allowed_deposit,
+           max_swapper_payable,
+           refund_key_index
+         )
           SELECT
            bitcoin_address,
            created_at,
@@ -142,7 +157,8 @@ impl SqliteStorage {
            script,
            min_allowed_deposit,
            max_allowed_deposit,
-           max_swapper_payable
+           max_swapper_payable,
+           refund_key_index
           FROM remote_sync.swaps
           WHERE bitcoin_address NOT IN (SELECT bitcoin_address FROM sync.swaps);",
             [],
@@ -194,6 +210,23 @@ impl SqliteStorage {
             [],
         )?;
 
+        // sync remote user_settings table, the latest change of each setting wins
+        tx.execute(
+            "
+             INSERT OR REPLACE INTO sync.user_settings
+             SELECT
+              remote_sync.user_settings.key,
+              remote_sync.user_settings.value,
+              remote_sync.user_settings.updated_at
+             FROM remote_sync.user_settings
+             LEFT JOIN sync.user_settings
+             ON sync.user_settings.key = remote_sync.user_settings.key
+             WHERE
+              sync.user_settings.updated_at IS NULL
+              OR remote_sync.user_settings.updated_at > sync.user_settings.updated_at;",
+            [],
+        )?;
+
         // sync remote reverse_swaps table
         tx.execute(
             "
@@ -209,7 +242,9 @@ impl SqliteStorage {
          onchain_amount_sat,
          sat_per_vbyte,
          receive_amount_sat,
-         redeem_script
+         redeem_script,
+         protocol,
+         refund_pubkey
         FROM remote_sync.reverse_swaps
         WHERE id NOT IN (SELECT id FROM sync.reverse_swaps);",
             [],
@@ -222,7 +257,8 @@ impl SqliteStorage {
          SELECT
           payment_hash,
           payer_amount_msat,
-          open_channel_bolt11
+          open_channel_bolt11,
+          opening_fee_params
          FROM remote_sync.open_channel_payment_info
          WHERE payment_hash NOT IN (SELECT payment_hash FROM sync.open_channel_payment_info);",
             [],
@@ -275,7 +311,7 @@ mod tests {
     use crate::persist::swap::SwapStorage;
     use crate::persist::test_utils;
     use crate::test_utils::{get_test_ofp_48h, rand_string, rand_vec_u8};
-    use crate::{ListSwapsRequest, SwapInfo};
+    use crate::{Denomination, ListSwapsRequest, SwapInfo, UserSettings};
 
     #[test]
     fn test_sync() -> PersistResult<()> {
@@ -298,7 +334,7 @@ mod tests {
         remote_storage.init()?;
         remote_storage.insert_swap(&remote_swap_info)?;
 
-        remote_storage.insert_open_channel_payment_info("123", 100000, "")?;
+        remote_storage.insert_open_channel_payment_info("123", 100000, "", None)?;
 
         remote_storage.import_remote_changes(&local_storage, false)?;
         local_storage.import_remote_changes(&remote_storage, true)?;
@@ -449,6 +485,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_sync_user_settings() -> PersistResult<()> {
+        let local_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        local_storage.init()?;
+
+        let remote_storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        remote_storage.init()?;
+
+        local_storage.set_user_settings(&UserSettings {
+            preferred_fiat_currency: Some("EUR".to_string()),
+            preferred_lsp_id: Some("lsp".to_string()),
+            ..Default::default()
+        })?;
+        // The settings rows include a local timestamp on update
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        remote_storage.set_user_settings(&UserSettings {
+            preferred_fiat_currency: Some("USD".to_string()),
+            denomination: Some(Denomination::Sat),
+            ..Default::default()
+        })?;
+        // The remote cleared the LSP, but it is changed again locally afterwards
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        local_storage.set_user_settings(&UserSettings {
+            preferred_fiat_currency: Some("EUR".to_string()),
+            preferred_lsp_id: Some("other-lsp".to_string()),
+            ..Default::default()
+        })?;
+
+        local_storage.import_remote_changes(&remote_storage, true)?;
+
+        // The latest change of each setting wins
+        assert_eq!(
+            local_storage.get_user_settings()?,
+            UserSettings {
+                preferred_fiat_currency: Some("USD".to_string()),
+                denomination: Some(Denomination::Sat),
+                default_feerate_preset: None,
+                preferred_lsp_id: Some("other-lsp".to_string()),
+            }
+        );
+        Ok(())
+    }
+
     fn create_test_swap_info() -> SwapInfo {
         SwapInfo {
             bitcoin_address: rand_string(10),
@@ -1,12 +1,13 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::str::FromStr;
 
 use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, Type, ValueRef};
-use rusqlite::Row;
 use rusqlite::{named_params, params, OptionalExtension};
+use rusqlite::{Connection, Row};
 use sdk_common::prelude::*;
 use serde_json::{Map, Value};
 
+use super::cache::KEY_SYNC_STATE;
 use super::db::SqliteStorage;
 use super::error::{PersistError, PersistResult};
 use crate::models::*;
@@ -59,49 +60,107 @@ impl SqliteStorage {
         transactions: &[Payment],
         is_pseudo: bool,
     ) -> PersistResult<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        upsert_payments(&tx, transactions, is_pseudo)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Applies the changes pulled from the node in a single transaction: upserts the payments,
+    /// optionally replacing the pseudo-payments, and stores the new sync state, so an interrupted
+    /// sync doesn't advance the sync state past payments that weren't persisted.
+    pub(crate) fn apply_synced_payments(
+        &self,
+        payments: &[Payment],
+        replace_pseudo_payments: bool,
+        sync_state: &Value,
+    ) -> PersistResult<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        if replace_pseudo_payments {
+            let deleted = tx.execute("DELETE FROM payments WHERE is_pseudo = 1", [])?;
+            if deleted > 0 {
+                debug!("deleted {} pseudo-payments", deleted);
+            }
+        }
+        upsert_payments(&tx, payments, false)?;
+        tx.execute(
+            "INSERT OR REPLACE INTO cached_items (key, value) VALUES (?1,?2)",
+            (KEY_SYNC_STATE, sync_state.to_string()),
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// The funding txids of the closed channels whose payment is complete, which don't change
+    /// anymore
+    pub(crate) fn list_completed_closed_channel_ids(&self) -> PersistResult<HashSet<String>> {
+        let con = self.get_connection()?;
+        let mut stmt =
+            con.prepare("SELECT id FROM payments WHERE payment_type = ?1 AND status = ?2")?;
+        let ids = stmt
+            .query_map(
+                params![
+                    PaymentType::ClosedChannel.to_string(),
+                    PaymentStatus::Complete
+                ],
+                |row| row.get(0),
+            )?
+            .collect::<Result<HashSet<String>, _>>()?;
+        Ok(ids)
+    }
+
+    /// The sum of the amounts and fees of the pending sent payments, counted in flight for the
+    /// HTLC exposure limits
+    pub(crate) fn get_pending_sent_msat(&self) -> PersistResult<u64> {
+        Ok(self.get_connection()?.query_row(
+            "
+          SELECT COALESCE(SUM(COALESCE(e.attempted_amount_msat, p.amount_msat) + p.fee_msat), 0)
+          FROM payments p
+          LEFT JOIN sync.payments_external_info e ON p.id = e.payment_id
+          WHERE p.payment_type = ?1 AND p.status = ?2",
+            params![PaymentType::Sent.to_string(), PaymentStatus::Pending],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Inserts payments made outside of this node, flagged as imported. Payments with the id of
+    /// an existing payment are skipped. Returns the number of inserted payments.
+    pub fn insert_imported_payments(&self, payments: &[Payment]) -> PersistResult<usize> {
         let con = self.get_connection()?;
         let mut prep_statement = con.prepare(
             "
-         INSERT OR REPLACE INTO payments (
+         INSERT OR IGNORE INTO payments (
            id,
-           payment_type,                 
-           payment_time,                                  
-           amount_msat, 
-           fee_msat,                 
+           payment_type,
+           payment_time,
+           amount_msat,
+           fee_msat,
            status,
            description,
            details,
-           is_pseudo
+           is_pseudo,
+           is_imported
         )
-         VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9)
+         VALUES (?1,?2,?3,?4,?5,?6,?7,?8,0,1)
         ",
         )?;
 
-        for ln_tx in transactions {
-            _ = prep_statement.execute((
-                &ln_tx.id,
-                &ln_tx.payment_type.to_string(),
-                &ln_tx.payment_time,
-                &ln_tx.amount_msat,
-                &ln_tx.fee_msat,
-                &ln_tx.status,
-                &ln_tx.description,
-                &ln_tx.details,
-                &is_pseudo,
+        let mut inserted = 0;
+        for payment in payments {
+            inserted += prep_statement.execute((
+                &payment.id,
+                &payment.payment_type.to_string(),
+                &payment.payment_time,
+                &payment.amount_msat,
+                &payment.fee_msat,
+                &payment.status,
+                &payment.description,
+                &payment.details,
             ))?;
         }
-        Ok(())
-    }
-
-    pub fn delete_pseudo_payments(&self) -> PersistResult<()> {
-        let con = self.get_connection()?;
-        let mut stmt = con.prepare("DELETE FROM payments where is_pseudo=1")?;
-        let res = stmt.execute([])?;
-        if res > 0 {
-            debug!("deleted {} pseudo-payments", res);
-        }
-
-        Ok(())
+        Ok(inserted)
     }
 
     /// Inserts metadata associated with this payment
@@ -204,12 +263,13 @@ impl SqliteStorage {
         Ok(())
     }
 
-    /// Inserts payer amount for invoices that require opening a channel.
+    /// Inserts payer amount and the fee params for invoices that require opening a channel.
     pub fn insert_open_channel_payment_info(
         &self,
         payment_hash: &str,
         payer_amount_msat: u64,
         open_channel_bolt11: &str,
+        opening_fee_params: Option<&OpeningFeeParams>,
     ) -> PersistResult<()> {
         let con = self.get_connection()?;
         let mut prep_statement = con.prepare(
@@ -217,17 +277,143 @@ impl SqliteStorage {
         INSERT OR IGNORE INTO sync.open_channel_payment_info (
           payment_hash,
           payer_amount_msat,
-          open_channel_bolt11
+          open_channel_bolt11,
+          opening_fee_params
         )
-        VALUES (?1,?2,?3)
+        VALUES (?1,?2,?3,?4)
        ",
         )?;
 
-        _ = prep_statement.execute((payment_hash, payer_amount_msat, open_channel_bolt11))?;
+        _ = prep_statement.execute((
+            payment_hash,
+            payer_amount_msat,
+            open_channel_bolt11,
+            opening_fee_params,
+        ))?;
+
+        Ok(())
+    }
+
+    /// Lists the channel opening fees charged for the completed received payments, the most
+    /// recent first
+    pub(crate) fn list_channel_opening_fees(&self) -> PersistResult<Vec<ChannelOpeningFee>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+          SELECT p.id, p.payment_time, o.payer_amount_msat, p.amount_msat, o.opening_fee_params
+          FROM payments p
+          JOIN sync.open_channel_payment_info o ON p.id = o.payment_hash
+          WHERE p.payment_type = ?1 AND p.status = ?2
+          ORDER BY p.payment_time DESC",
+        )?;
+        let fees = stmt
+            .query_map(
+                params![PaymentType::Received.to_string(), PaymentStatus::Complete],
+                |row| {
+                    let payer_amount_msat: u64 = row.get(2)?;
+                    let received_amount_msat: u64 = row.get(3)?;
+                    Ok(ChannelOpeningFee {
+                        payment_hash: row.get(0)?,
+                        payment_time: row.get(1)?,
+                        payer_amount_msat,
+                        received_amount_msat,
+                        fee_msat: payer_amount_msat.saturating_sub(received_amount_msat),
+                        opening_fee_params: row.get(4)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(fees)
+    }
+
+    /// Stores the custom TLV records a payment was received with
+    pub(crate) fn insert_payment_tlvs(
+        &self,
+        payment_id: &str,
+        tlvs: &[TlvEntry],
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO payment_tlvs (payment_id, tlvs) VALUES (?1, ?2)",
+            (payment_id, serde_json::to_string(tlvs)?),
+        )?;
+        Ok(())
+    }
 
+    /// Stores the fee params an open channel invoice was registered with at the LSP, so the
+    /// registration can be renewed
+    pub(crate) fn insert_open_channel_registration(
+        &self,
+        payment_hash: &str,
+        opening_fee_params: &OpeningFeeParams,
+    ) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "INSERT OR REPLACE INTO open_channel_registrations (payment_hash, opening_fee_params)
+             VALUES (?1, ?2)",
+            (payment_hash, serde_json::to_string(opening_fee_params)?),
+        )?;
         Ok(())
     }
 
+    pub(crate) fn delete_open_channel_registration(&self, payment_hash: &str) -> PersistResult<()> {
+        self.get_connection()?.execute(
+            "DELETE FROM open_channel_registrations WHERE payment_hash = ?1",
+            [payment_hash],
+        )?;
+        Ok(())
+    }
+
+    /// Gets the payer amount and fee params of an open channel invoice registered at the LSP
+    pub(crate) fn get_open_channel_registration(
+        &self,
+        payment_hash: &str,
+    ) -> PersistResult<Option<(u64, OpeningFeeParams)>> {
+        let registration: Option<(u64, String)> = self
+            .get_connection()?
+            .query_row(
+                "
+          SELECT o.payer_amount_msat, r.opening_fee_params
+          FROM open_channel_registrations r
+          JOIN sync.open_channel_payment_info o ON o.payment_hash = r.payment_hash
+          WHERE r.payment_hash = ?1",
+                [payment_hash],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        match registration {
+            Some((payer_amount_msat, opening_fee_params)) => Ok(Some((
+                payer_amount_msat,
+                serde_json::from_str(&opening_fee_params)?,
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Lists the open channel invoices registered at the LSP, with their fee params
+    pub(crate) fn list_open_channel_registrations(
+        &self,
+    ) -> PersistResult<Vec<(String, OpeningFeeParams)>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+          SELECT o.open_channel_bolt11, r.opening_fee_params
+          FROM open_channel_registrations r
+          JOIN sync.open_channel_payment_info o ON o.payment_hash = r.payment_hash
+          WHERE o.open_channel_bolt11 IS NOT NULL",
+        )?;
+        let registrations = stmt
+            .query_map([], |row| {
+                let opening_fee_params: String = row.get(1)?;
+                Ok((
+                    row.get(0)?,
+                    serde_json::from_str(&opening_fee_params).map_err(|e| {
+                        rusqlite::Error::FromSqlConversionFailure(1, Type::Text, Box::new(e))
+                    })?,
+                ))
+            })?
+            .collect::<Result<Vec<(String, OpeningFeeParams)>, _>>()?;
+        Ok(registrations)
+    }
+
     /// Constructs [Payment] by joining data in the `payment` and `payments_external_info` tables
     ///
     /// This queries all payments. To query a single payment, see [Self::get_payment_by_hash]
@@ -243,8 +429,13 @@ impl SqliteStorage {
         let offset = req.offset.unwrap_or(0u32);
         let limit = req.limit.unwrap_or(u32::MAX);
         let con = self.get_connection()?;
-        let query = self.select_payments_query(where_clause.as_str(), offset, limit)?;
-        let mut stmt = con.prepare(query.as_str())?;
+        let query = self.select_payments_query(
+            where_clause.as_str(),
+            req.sort_order.unwrap_or_default(),
+            offset,
+            limit,
+        )?;
+        let mut stmt = con.prepare_cached(query.as_str())?;
 
         let mut params: HashMap<String, String> = HashMap::new();
 
@@ -258,7 +449,9 @@ impl SqliteStorage {
                     },
                 )| {
                     params.insert(format!(":json_path_{i}"), format!("$.{json_path}"));
-                    params.insert(format!(":json_value_{i}"), json_value.clone());
+                    if let Some(json_value) = json_value {
+                        params.insert(format!(":json_value_{i}"), json_value.clone());
+                    }
                 },
             )
         }
@@ -274,12 +467,16 @@ impl SqliteStorage {
             )?
             .map(|i| i.unwrap())
             .collect();
+        if !req.include_tlv_hex.unwrap_or(false) {
+            return Ok(vec.into_iter().map(without_tlv_hex).collect());
+        }
         Ok(vec)
     }
 
     pub fn select_payments_query(
         &self,
         where_clause: &str,
+        sort_order: SortOrder,
         offset: u32,
         limit: u32,
     ) -> PersistResult<String> {
@@ -287,6 +484,10 @@ impl SqliteStorage {
         let swap_query = self.select_swap_query("true", "swaps_");
         let rev_swap_fields = self.select_reverse_swap_fields("revswaps_");
         let rev_swap_query = self.select_reverse_swap_query("true", "revswaps_");
+        let order = match sort_order {
+            SortOrder::Descending => "DESC",
+            SortOrder::Ascending => "ASC",
+        };
         let query = format!(
             "
           SELECT 
@@ -309,6 +510,11 @@ impl SqliteStorage {
            m.metadata,
            e.lnurl_pay_domain,
            e.lnurl_pay_comment,
+           f.currency,
+           f.value,
+           p.is_imported,
+           t.tlvs,
+           o.opening_fee_params,
            {swap_fields},
            {rev_swap_fields}
           FROM payments p
@@ -321,6 +527,12 @@ impl SqliteStorage {
           LEFT JOIN sync.open_channel_payment_info o
            ON
             p.id = o.payment_hash
+          LEFT JOIN payment_fiat_rates f
+           ON
+            p.id = f.payment_id
+          LEFT JOIN payment_tlvs t
+           ON
+            p.id = t.payment_id
           LEFT JOIN ({swap_query}) as swaps
            ON
             p.id = hex(swaps_payment_hash) COLLATE NOCASE
@@ -328,7 +540,7 @@ impl SqliteStorage {
            ON
             json_extract(p.details, '$.payment_preimage') = hex(revswaps_preimage) COLLATE NOCASE
           {where_clause}
-          ORDER BY payment_time DESC
+          ORDER BY payment_time {order}
           LIMIT {limit}
           OFFSET {offset}
         "
@@ -343,7 +555,7 @@ impl SqliteStorage {
     ///
     /// To query all payments, see [Self::list_payments]
     pub(crate) fn get_payment_by_hash(&self, hash: &str) -> PersistResult<Option<Payment>> {
-        let query = self.select_payments_query("where id = ?1", 0, 1)?;
+        let query = self.select_payments_query("where id = ?1", SortOrder::default(), 0, 1)?;
         Ok(self
             .get_connection()?
             .query_row(query.as_str(), [hash], |row| self.sql_row_to_payment(row))
@@ -351,7 +563,6 @@ impl SqliteStorage {
     }
 
     /// Look up a modified open channel bolt11 by hash.
-    #[allow(unused)]
     pub(crate) fn get_open_channel_bolt11_by_hash(
         &self,
         hash: &str,
@@ -390,6 +601,11 @@ impl SqliteStorage {
             details: row.get(7)?,
             error: row.get(13)?,
             metadata: row.get(16)?,
+            fiat_rate: match (row.get(19)?, row.get(20)?) {
+                (Some(coin), Some(value)) => Some(Rate { coin, value }),
+                _ => None,
+            },
+            imported: row.get(21)?,
         };
 
         if let PaymentDetails::Ln { ref mut data } = payment.details {
@@ -406,6 +622,21 @@ impl SqliteStorage {
             data.lnurl_metadata = row.get(9)?;
             data.ln_address = row.get(10)?;
             data.lnurl_withdraw_endpoint = row.get(11)?;
+            let tlvs: Option<String> = row.get(22)?;
+            data.tlvs = match tlvs {
+                None => None,
+                Some(s) => {
+                    let tlvs: Vec<TlvEntry> = serde_json::from_str(&s).map_err(|e| {
+                        rusqlite::Error::FromSqlConversionFailure(22, Type::Text, Box::new(e))
+                    })?;
+                    Some(tlvs.iter().map(TlvRecord::from).collect())
+                }
+            };
+            if data.keysend && payment.payment_type == PaymentType::Received {
+                data.spontaneous_metadata = Some(SpontaneousPaymentMetadata::from_tlvs(
+                    data.tlvs.as_deref().unwrap_or_default(),
+                ));
+            }
             data.swap_info = self.sql_row_to_swap(row, "swaps_").ok();
             if let Ok(fr) = self.sql_row_to_reverse_swap(row, "revswaps_") {
                 data.reverse_swap_info = Some(fr.get_reverse_swap_info_using_cached_values());
@@ -416,6 +647,9 @@ impl SqliteStorage {
         let payer_amount_msat: Option<u64> = row.get(14)?;
         if let Some(payer_amount) = payer_amount_msat {
             payment.fee_msat = payer_amount - amount_msat;
+            if let PaymentDetails::Ln { data } = &mut payment.details {
+                data.channel_opening_fee_msat = Some(payment.fee_msat);
+            }
         }
 
         // Add the payer invoice if it exists, in case of a received payment
@@ -429,6 +663,16 @@ impl SqliteStorage {
     }
 }
 
+/// Removes the raw values of the TLV records, see [ListPaymentsRequest::include_tlv_hex]
+fn without_tlv_hex(mut payment: Payment) -> Payment {
+    if let PaymentDetails::Ln { ref mut data } = payment.details {
+        for tlv in data.tlvs.iter_mut().flatten() {
+            tlv.value_hex = None;
+        }
+    }
+    payment
+}
+
 fn filter_to_where_clause(
     type_filters: Option<Vec<PaymentTypeFilter>>,
     metadata_filters: &Option<Vec<MetadataFilter>>,
@@ -451,35 +695,47 @@ fn filter_to_where_clause(
 
     if let Some(filters) = type_filters {
         if !filters.is_empty() {
-            let mut type_filter_clause: HashSet<PaymentType> = HashSet::new();
+            let mut type_filter_clause: BTreeSet<String> = BTreeSet::new();
+            let mut spontaneous_received = false;
             for type_filter in filters {
                 match type_filter {
                     PaymentTypeFilter::Sent => {
-                        type_filter_clause.insert(PaymentType::Sent);
+                        type_filter_clause.insert(PaymentType::Sent.to_string());
                     }
                     PaymentTypeFilter::Received => {
-                        type_filter_clause.insert(PaymentType::Received);
+                        type_filter_clause.insert(PaymentType::Received.to_string());
                     }
                     PaymentTypeFilter::ClosedChannel => {
-                        type_filter_clause.insert(PaymentType::ClosedChannel);
+                        type_filter_clause.insert(PaymentType::ClosedChannel.to_string());
                     }
+                    PaymentTypeFilter::SpontaneousReceived => spontaneous_received = true,
                 }
             }
 
-            where_clause.push(format!(
+            let mut type_clauses = vec![format!(
                 "payment_type in ({})",
                 type_filter_clause
                     .iter()
                     .map(|t| format!("'{}'", t))
                     .collect::<Vec<_>>()
                     .join(", ")
-            ));
+            )];
+            if spontaneous_received {
+                type_clauses.push(format!(
+                    "(payment_type = '{}' AND json_extract(p.details, '$.keysend') = 1)",
+                    PaymentType::Received
+                ));
+            }
+            where_clause.push(format!("({})", type_clauses.join(" OR ")));
         }
     }
 
     if let Some(filters) = metadata_filters {
-        filters.iter().enumerate().for_each(|(i, _)| {
-            where_clause.push(format!("metadata->:json_path_{i} = :json_value_{i}"));
+        filters.iter().enumerate().for_each(|(i, filter)| {
+            where_clause.push(match filter.json_value {
+                Some(_) => format!("metadata->:json_path_{i} = :json_value_{i}"),
+                None => format!("metadata->:json_path_{i} IS NOT NULL"),
+            });
         });
     }
 
@@ -525,13 +781,48 @@ impl ToSql for PaymentStatus {
     }
 }
 
+/// Upserts the payments with the statement of [SqliteStorage::insert_or_update_payments]
+fn upsert_payments(con: &Connection, payments: &[Payment], is_pseudo: bool) -> PersistResult<()> {
+    let mut prep_statement = con.prepare_cached(
+        "
+     INSERT OR REPLACE INTO payments (
+       id,
+       payment_type,                 
+       payment_time,                                  
+       amount_msat, 
+       fee_msat,                 
+       status,
+       description,
+       details,
+       is_pseudo
+    )
+     VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9)
+    ",
+    )?;
+
+    for ln_tx in payments {
+        _ = prep_statement.execute((
+            &ln_tx.id,
+            &ln_tx.payment_type.to_string(),
+            &ln_tx.payment_time,
+            &ln_tx.amount_msat,
+            &ln_tx.fee_msat,
+            &ln_tx.status,
+            &ln_tx.description,
+            &ln_tx.details,
+            &is_pseudo,
+        ))?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
         persist::{db::SqliteStorage, error::PersistResult, swap::SwapStorage},
         FullReverseSwapInfo, ListPaymentsRequest, MetadataFilter, OpeningFeeParams,
         PaymentExternalInfo, PaymentStatus, PaymentType, PaymentTypeFilter, ReverseSwapInfo,
-        ReverseSwapInfoCached, ReverseSwapStatus, SwapInfo, SwapStatus,
+        ReverseSwapInfoCached, ReverseSwapProtocol, ReverseSwapStatus, SwapInfo, SwapStatus,
     };
 
     #[test]
@@ -603,6 +894,8 @@ mod test {
             onchain_amount_sat: 250,
             sat_per_vbyte: Some(50),
             receive_amount_sat: None,
+            protocol: ReverseSwapProtocol::Legacy,
+            refund_pubkey: None,
             cache: ReverseSwapInfoCached {
                 status: ReverseSwapStatus::CompletedConfirmed,
                 lockup_txid: Some("lockup_txid".to_string()),
@@ -616,6 +909,7 @@ mod test {
             claim_txid: Some("claim_txid".to_string()),
             onchain_amount_sat: 250,
             status: ReverseSwapStatus::CompletedConfirmed,
+            protocol: ReverseSwapProtocol::Legacy,
         };
         let txs = [
             Payment {
@@ -645,9 +939,14 @@ mod test {
                         reverse_swap_info: None,
                         pending_expiration_block: None,
                         open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
                     },
                 },
                 metadata: None,
+                fiat_rate: None,
+                imported: false,
             },
             Payment {
                 id: payment_hash_with_lnurl_withdraw.to_string(),
@@ -676,9 +975,14 @@ mod test {
                         reverse_swap_info: None,
                         pending_expiration_block: None,
                         open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
                     },
                 },
                 metadata: None,
+                fiat_rate: None,
+                imported: false,
             },
             Payment {
                 id: hex::encode(payment_hash_with_swap_info.clone()),
@@ -707,9 +1011,14 @@ mod test {
                         reverse_swap_info: None,
                         pending_expiration_block: None,
                         open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
                     },
                 },
                 metadata: None,
+                fiat_rate: None,
+                imported: false,
             },
             Payment {
                 id: hex::encode(payment_hash_with_rev_swap_info.clone()),
@@ -738,9 +1047,14 @@ mod test {
                         reverse_swap_info: Some(rev_swap_info.clone()),
                         pending_expiration_block: None,
                         open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
                     },
                 },
                 metadata: None,
+                fiat_rate: None,
+                imported: false,
             },
             Payment {
                 id: payment_hash_with_lnurl_domain.to_string(),
@@ -769,9 +1083,14 @@ mod test {
                         reverse_swap_info: None,
                         pending_expiration_block: None,
                         open_channel_bolt11: None,
+                        channel_opening_fee_msat: None,
+                        tlvs: None,
+                        spontaneous_metadata: None,
                     },
                 },
                 metadata: None,
+                fiat_rate: None,
+                imported: false,
             },
         ];
         let failed_txs = [Payment {
@@ -801,9 +1120,14 @@ mod test {
                     reverse_swap_info: None,
                     pending_expiration_block: None,
                     open_channel_bolt11: None,
+                    channel_opening_fee_msat: None,
+                    tlvs: None,
+                    spontaneous_metadata: None,
                 },
             },
             metadata: None,
+            fiat_rate: None,
+            imported: false,
         }];
         let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
         storage.init()?;
@@ -835,7 +1159,7 @@ mod test {
                 attempted_error: None,
             },
         )?;
-        storage.insert_swap(&swap_info)?;
+        storage.insert_swap(&swap_info, None)?;
         storage.update_swap_bolt11(
             swap_info.bitcoin_address.clone(),
             swap_info.bolt11.clone().unwrap(),
@@ -905,9 +1229,13 @@ mod test {
         assert_eq!(retrieve_txs.len(), 5);
         assert_eq!(retrieve_txs, txs);
 
-        storage.insert_open_channel_payment_info("123", 150, "")?;
+        storage.insert_open_channel_payment_info("123", 150, "", None)?;
         let retrieve_txs = storage.list_payments(ListPaymentsRequest::default())?;
         assert_eq!(retrieve_txs[0].fee_msat, 50);
+        assert!(matches!(
+            &retrieve_txs[0].details,
+            PaymentDetails::Ln { data } if data.channel_opening_fee_msat == Some(50)
+        ));
 
         // test all with failures
         let retrieve_txs = storage.list_payments(ListPaymentsRequest {
@@ -958,19 +1286,19 @@ mod test {
         let test_json_filters = Some(vec![
             MetadataFilter {
                 json_path: "supportsBoolean".to_string(),
-                json_value: "true".to_string(),
+                json_value: Some("true".to_string()),
             },
             MetadataFilter {
                 json_path: "supportsInt".to_string(),
-                json_value: "10".to_string(),
+                json_value: Some("10".to_string()),
             },
             MetadataFilter {
                 json_path: "supportsString".to_string(),
-                json_value: r#""supports string""#.to_string(),
+                json_value: Some(r#""supports string""#.to_string()),
             },
             MetadataFilter {
                 json_path: "supportsNested.value".to_string(),
-                json_value: "[1,2]".to_string(),
+                json_value: Some("[1,2]".to_string()),
             },
         ]);
 
@@ -987,13 +1315,53 @@ mod test {
         assert_eq!(retrieve_txs[0].id, payment_hash_with_lnurl_withdraw);
         assert_eq!(retrieve_txs[0].metadata, Some(test_json.to_string()),);
 
+        // test filtering by the presence of a metadata field
+        let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+            metadata_filters: Some(vec![MetadataFilter {
+                json_path: "supportsNested".to_string(),
+                json_value: None,
+            }]),
+            ..Default::default()
+        })?;
+        assert_eq!(retrieve_txs.len(), 1);
+        assert_eq!(retrieve_txs[0].id, payment_hash_with_lnurl_withdraw);
+
+        let retrieve_txs = storage.list_payments(ListPaymentsRequest {
+            metadata_filters: Some(vec![MetadataFilter {
+                json_path: "orderId".to_string(),
+                json_value: None,
+            }]),
+            ..Default::default()
+        })?;
+        assert!(retrieve_txs.is_empty());
+
         // test open_channel_bolt11
+        let opening_fee_params = OpeningFeeParams {
+            min_msat: 2_000,
+            proportional: 4_000,
+            ..Default::default()
+        };
         storage.insert_open_channel_payment_info(
             payment_hash_with_lnurl_withdraw,
             150,
             "original_invoice",
+            Some(&opening_fee_params),
         )?;
 
+        // Only the received payments are listed
+        let channel_opening_fees = storage.list_channel_opening_fees()?;
+        assert_eq!(channel_opening_fees.len(), 1);
+        assert_eq!(
+            channel_opening_fees[0].payment_hash,
+            payment_hash_with_lnurl_withdraw
+        );
+        assert_eq!(channel_opening_fees[0].payer_amount_msat, 150);
+        assert_eq!(channel_opening_fees[0].fee_msat, 50);
+        assert_eq!(
+            channel_opening_fees[0].opening_fee_params,
+            Some(opening_fee_params)
+        );
+
         let open_channel_bolt11 = storage
             .get_open_channel_bolt11_by_hash(payment_hash_with_lnurl_withdraw)?
             .unwrap();
@@ -1029,4 +1397,358 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_open_channel_registrations() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let opening_fee_params = OpeningFeeParams {
+            min_msat: 2_000,
+            proportional: 4_000,
+            ..Default::default()
+        };
+
+        // Registrations are only listed once the open channel invoice is stored
+        storage.insert_open_channel_registration("123", &opening_fee_params)?;
+        assert!(storage.list_open_channel_registrations()?.is_empty());
+
+        storage.insert_open_channel_payment_info("123", 10_000, "lnbc1", None)?;
+        assert_eq!(
+            storage.list_open_channel_registrations()?,
+            vec![("lnbc1".to_string(), opening_fee_params.clone())]
+        );
+        assert_eq!(
+            storage.get_open_channel_registration("123")?,
+            Some((10_000, opening_fee_params))
+        );
+
+        storage.delete_open_channel_registration("123")?;
+        assert!(storage.list_open_channel_registrations()?.is_empty());
+        assert!(storage.get_open_channel_registration("123")?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_imported_payments() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::models::Payment;
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let payment = |id: &str, payment_time| Payment {
+            id: id.to_string(),
+            payment_type: PaymentType::Received,
+            payment_time,
+            amount_msat: 1_000,
+            status: PaymentStatus::Complete,
+            ..Default::default()
+        };
+        storage.insert_or_update_payments(&[payment("1", 1)], false)?;
+
+        // The existing payment is not replaced
+        let imported = [payment("1", 2), payment("2", 3)];
+        assert_eq!(storage.insert_imported_payments(&imported)?, 1);
+        assert_eq!(storage.insert_imported_payments(&imported)?, 0);
+
+        let payments = storage.list_payments(ListPaymentsRequest::default())?;
+        assert_eq!(payments.len(), 2);
+        assert_eq!(payments[0].id, "2");
+        assert!(payments[0].imported);
+        assert_eq!(payments[1].id, "1");
+        assert_eq!(payments[1].payment_time, 1);
+        assert!(!payments[1].imported);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pending_sent_msat() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::models::Payment;
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        assert_eq!(storage.get_pending_sent_msat()?, 0);
+
+        let payment = |id: &str, payment_type, status| Payment {
+            id: id.to_string(),
+            payment_type,
+            amount_msat: 1_000,
+            fee_msat: 10,
+            status,
+            ..Default::default()
+        };
+        storage.insert_or_update_payments(
+            &[
+                payment("1", PaymentType::Sent, PaymentStatus::Pending),
+                payment("2", PaymentType::Sent, PaymentStatus::Pending),
+                payment("3", PaymentType::Sent, PaymentStatus::Complete),
+                payment("4", PaymentType::Sent, PaymentStatus::Failed),
+                payment("5", PaymentType::Received, PaymentStatus::Pending),
+            ],
+            false,
+        )?;
+        // The attempted amount of a pending payment is counted
+        storage.insert_payment_external_info(
+            "2",
+            PaymentExternalInfo {
+                attempted_amount_msat: Some(5_000),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(storage.get_pending_sent_msat()?, 1_010 + 5_010);
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_payments_sort_order() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::models::{Payment, SortOrder};
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let payment = |id: &str, payment_time| Payment {
+            id: id.to_string(),
+            payment_type: PaymentType::Received,
+            payment_time,
+            status: PaymentStatus::Complete,
+            ..Default::default()
+        };
+        storage.insert_or_update_payments(
+            &[payment("1", 1), payment("3", 3), payment("2", 2)],
+            false,
+        )?;
+
+        let list = |sort_order| -> PersistResult<Vec<String>> {
+            Ok(storage
+                .list_payments(ListPaymentsRequest {
+                    sort_order,
+                    limit: Some(2),
+                    ..Default::default()
+                })?
+                .into_iter()
+                .map(|p| p.id)
+                .collect())
+        };
+        assert_eq!(list(None)?, vec!["3", "2"]);
+        assert_eq!(list(Some(SortOrder::Descending))?, vec!["3", "2"]);
+        assert_eq!(list(Some(SortOrder::Ascending))?, vec!["1", "2"]);
+        // The cached statement is reused
+        assert_eq!(list(Some(SortOrder::Ascending))?, vec!["1", "2"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_synced_payments() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::models::Payment;
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let payment = |id: &str, payment_type, status| Payment {
+            id: id.to_string(),
+            payment_type,
+            amount_msat: 1_000,
+            status,
+            ..Default::default()
+        };
+        storage.insert_or_update_payments(
+            &[payment("pseudo", PaymentType::Sent, PaymentStatus::Pending)],
+            true,
+        )?;
+
+        // The pseudo-payments are kept unless replaced
+        let sync_state = serde_json::json!({"index": 1});
+        storage.apply_synced_payments(
+            &[payment("1", PaymentType::Received, PaymentStatus::Complete)],
+            false,
+            &sync_state,
+        )?;
+        assert_eq!(storage.get_sync_state()?, Some(sync_state));
+        assert_eq!(
+            storage.list_payments(ListPaymentsRequest::default())?.len(),
+            2
+        );
+
+        let sync_state = serde_json::json!({"index": 2});
+        storage.apply_synced_payments(
+            &[
+                payment(
+                    "closed",
+                    PaymentType::ClosedChannel,
+                    PaymentStatus::Complete,
+                ),
+                payment(
+                    "closing",
+                    PaymentType::ClosedChannel,
+                    PaymentStatus::Pending,
+                ),
+            ],
+            true,
+            &sync_state,
+        )?;
+        assert_eq!(storage.get_sync_state()?, Some(sync_state));
+        let ids: Vec<String> = storage
+            .list_payments(ListPaymentsRequest::default())?
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        assert!(!ids.contains(&"pseudo".to_string()));
+        assert_eq!(ids.len(), 3);
+        assert_eq!(
+            storage.list_completed_closed_channel_ids()?,
+            ["closed".to_string()].into()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_payment_tlvs() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::models::{LnPaymentDetails, Payment, PaymentDetails, TlvEntry, TlvRecord};
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        storage.insert_or_update_payments(
+            &[Payment {
+                id: "1".to_string(),
+                payment_type: PaymentType::Received,
+                status: PaymentStatus::Complete,
+                details: PaymentDetails::Ln {
+                    data: LnPaymentDetails::default(),
+                },
+                ..Default::default()
+            }],
+            false,
+        )?;
+        storage.insert_payment_tlvs(
+            "1",
+            &[
+                TlvEntry {
+                    field_number: 34349334,
+                    value: b"hello".to_vec(),
+                },
+                TlvEntry {
+                    field_number: 5482373484,
+                    value: vec![0xff, 0x00],
+                },
+            ],
+        )?;
+
+        let tlvs = |include_tlv_hex| -> PersistResult<Option<Vec<TlvRecord>>> {
+            let payments = storage.list_payments(ListPaymentsRequest {
+                include_tlv_hex,
+                ..Default::default()
+            })?;
+            Ok(match &payments[0].details {
+                PaymentDetails::Ln { data } => data.tlvs.clone(),
+                _ => None,
+            })
+        };
+        assert_eq!(
+            tlvs(Some(true))?,
+            Some(vec![
+                TlvRecord {
+                    field_number: 34349334,
+                    value_text: Some("hello".to_string()),
+                    value_hex: Some("68656c6c6f".to_string()),
+                },
+                TlvRecord {
+                    field_number: 5482373484,
+                    value_text: None,
+                    value_hex: Some("ff00".to_string()),
+                },
+            ])
+        );
+        let tlvs = tlvs(None)?.unwrap();
+        assert_eq!(tlvs[0].value_text.as_deref(), Some("hello"));
+        assert!(tlvs.iter().all(|tlv| tlv.value_hex.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_spontaneous_receives() -> PersistResult<(), Box<dyn std::error::Error>> {
+        use crate::models::{
+            LnPaymentDetails, Payment, PaymentDetails, SpontaneousPaymentMetadata, TlvEntry,
+        };
+        use crate::persist::test_utils;
+
+        let storage = SqliteStorage::new(test_utils::create_test_sql_dir());
+        storage.init()?;
+        let payment = |id: &str, payment_type, keysend| Payment {
+            id: id.to_string(),
+            payment_type,
+            status: PaymentStatus::Complete,
+            details: PaymentDetails::Ln {
+                data: LnPaymentDetails {
+                    keysend,
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        };
+        storage.insert_or_update_payments(
+            &[
+                payment("invoice", PaymentType::Received, false),
+                payment("keysend", PaymentType::Received, true),
+                payment("sent", PaymentType::Sent, true),
+            ],
+            false,
+        )?;
+        storage.insert_payment_tlvs(
+            "keysend",
+            &[
+                TlvEntry {
+                    field_number: 34349339,
+                    value: vec![0x02, 0xab],
+                },
+                TlvEntry {
+                    field_number: 7629169,
+                    value: br#"{"podcast": "Show", "sender_name": "Alice", "message": "Great"}"#
+                        .to_vec(),
+                },
+            ],
+        )?;
+
+        let payments = storage.list_payments(ListPaymentsRequest {
+            filters: Some(vec![PaymentTypeFilter::SpontaneousReceived]),
+            ..Default::default()
+        })?;
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].id, "keysend");
+        let metadata = |payment: &Payment| match &payment.details {
+            PaymentDetails::Ln { data } => data.spontaneous_metadata.clone(),
+            _ => None,
+        };
+        assert_eq!(
+            metadata(&payments[0]),
+            Some(SpontaneousPaymentMetadata {
+                message: Some("Great".to_string()),
+                sender_pubkey: Some("02ab".to_string()),
+                sender_name: Some("Alice".to_string()),
+                boostagram: Some(
+                    r#"{"podcast": "Show", "sender_name": "Alice", "message": "Great"}"#
+                        .to_string()
+                ),
+            })
+        );
+
+        let mut ids: Vec<String> = storage
+            .list_payments(ListPaymentsRequest {
+                filters: Some(vec![
+                    PaymentTypeFilter::Sent,
+                    PaymentTypeFilter::SpontaneousReceived,
+                ]),
+                ..Default::default()
+            })?
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["keysend", "sent"]);
+        let sent = storage.get_payment_by_hash("sent")?.unwrap();
+        assert_eq!(metadata(&sent), None);
+        Ok(())
+    }
 }
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use tokio::sync::{mpsc, oneshot};
+
+use super::db::SqliteStorage;
+use super::error::{PersistError, PersistResult};
+
+type WriteJob = Box<dyn FnOnce(&SqliteStorage) + Send>;
+
+/// Persists the payments being sent from a dedicated task.
+///
+/// The writes of the concurrent payments are queued and run one after the other, in the order
+/// they were requested, instead of contending for the database from each payment. The task runs
+/// on a blocking thread and stops once the writer is dropped.
+pub(crate) struct PaymentsWriter {
+    jobs: mpsc::UnboundedSender<WriteJob>,
+}
+
+impl PaymentsWriter {
+    pub(crate) fn start(persister: Arc<SqliteStorage>) -> Self {
+        let (jobs, mut receiver) = mpsc::unbounded_channel::<WriteJob>();
+        tokio::task::spawn_blocking(move || {
+            while let Some(job) = receiver.blocking_recv() {
+                job(&persister);
+            }
+            debug!("Payments writer stopped");
+        });
+        Self { jobs }
+    }
+
+    /// Queues the `write` and waits for its result
+    pub(crate) async fn write<T, F>(&self, write: F) -> PersistResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&SqliteStorage) -> PersistResult<T> + Send + 'static,
+    {
+        let (result_tx, result_rx) = oneshot::channel();
+        self.jobs
+            .send(Box::new(move |persister| {
+                let _ = result_tx.send(write(persister));
+            }))
+            .map_err(|_| PersistError::generic("The payments writer is stopped"))?;
+        result_rx
+            .await
+            .map_err(|_| PersistError::generic("The payments writer dropped the write"))?
+    }
+}
+
+#[tokio::test]
+async fn test_payments_writer() {
+    use crate::persist::test_utils;
+
+    let storage = Arc::new(SqliteStorage::new(test_utils::create_test_sql_dir()));
+    storage.init().unwrap();
+    let writer = Arc::new(PaymentsWriter::start(storage.clone()));
+
+    // The writes are run in the order they were queued
+    let writes = (0..10).map(|i| {
+        let writer = writer.clone();
+        async move {
+            writer
+                .write(move |persister| persister.update_cached_item("writer_test", i.to_string()))
+                .await
+        }
+    });
+    for res in futures::future::join_all(writes).await {
+        res.unwrap();
+    }
+    assert_eq!(
+        storage.get_cached_item("writer_test").unwrap(),
+        Some("9".to_string())
+    );
+    assert_eq!(writer.write(|_| Ok(42)).await.unwrap(), 42);
+}
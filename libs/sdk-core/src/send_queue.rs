@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::sync::{Mutex, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore};
+
+use crate::error::SendPaymentError;
+
+/// Coordinates concurrent outgoing payments.
+///
+/// Payments to the same destination are sent one after the other, in the order they were
+/// requested, so that each of them sees the channel balances left by the previous one. Up to
+/// [crate::Config::max_concurrent_payments] payments to different destinations are in flight at
+/// the same time, and the others wait for one of them to complete. Paying an invoice that is
+/// already being paid fails right away.
+///
+/// The permit of a payment is kept until its [crate::BreezEvent::PaymentSucceed] or
+/// [crate::BreezEvent::PaymentFailed] is emitted, so the events of a payment hash are emitted in
+/// the order its payments were sent. The permit is owned, so a payment that is no longer awaited
+/// can keep it until the node completes it.
+pub(crate) struct SendQueue {
+    slots: Arc<Semaphore>,
+    destinations: StdMutex<HashMap<String, Arc<Mutex<()>>>>,
+    in_flight: StdMutex<HashSet<String>>,
+}
+
+impl SendQueue {
+    pub(crate) fn new(max_concurrent_payments: u32) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(max_concurrent_payments.max(1) as usize)),
+            destinations: StdMutex::new(HashMap::new()),
+            in_flight: StdMutex::new(HashSet::new()),
+        }
+    }
+
+    /// Waits for the previous payments to the `destination` to complete, then for a free slot,
+    /// and returns a [SendPermit] that keeps both until dropped. Payments without a known
+    /// destination only wait for a slot.
+    ///
+    /// If a `payment_hash` is given and a payment with the same hash is already queued or in
+    /// flight, [SendPaymentError::AlreadyInProgress] is returned.
+    pub(crate) async fn acquire(
+        self: &Arc<Self>,
+        destination: Option<&str>,
+        payment_hash: Option<&str>,
+    ) -> Result<SendPermit, SendPaymentError> {
+        if let Some(payment_hash) = payment_hash {
+            if !self
+                .in_flight
+                .lock()
+                .unwrap()
+                .insert(payment_hash.to_string())
+            {
+                return Err(SendPaymentError::AlreadyInProgress);
+            }
+        }
+
+        let destination_lock = destination.map(|destination| {
+            self.destinations
+                .lock()
+                .unwrap()
+                .entry(destination.to_string())
+                .or_default()
+                .clone()
+        });
+        // Create the permit before waiting, so the payment hash and the destination are
+        // released if the caller gives up while queued
+        let mut permit = SendPermit {
+            queue: self.clone(),
+            destination: destination.map(|d| d.to_string()),
+            payment_hash: payment_hash.map(|h| h.to_string()),
+            destination_guard: None,
+            slot: None,
+        };
+        // The tokio mutex is fair, so the payments to a destination keep their order
+        if let Some(destination_lock) = destination_lock {
+            permit.destination_guard = Some(destination_lock.lock_owned().await);
+        }
+        permit.slot = Some(
+            self.slots
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|e| SendPaymentError::Generic { err: e.to_string() })?,
+        );
+        Ok(permit)
+    }
+}
+
+/// A slot of the [SendQueue], reserving the destination and the payment hash if any
+pub(crate) struct SendPermit {
+    queue: Arc<SendQueue>,
+    destination: Option<String>,
+    payment_hash: Option<String>,
+    destination_guard: Option<OwnedMutexGuard<()>>,
+    slot: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for SendPermit {
+    fn drop(&mut self) {
+        self.slot.take();
+        self.destination_guard.take();
+        if let Some(payment_hash) = &self.payment_hash {
+            self.queue.in_flight.lock().unwrap().remove(payment_hash);
+        }
+
+        // Forget the destination lock once nobody else is waiting on it
+        if let Some(destination) = &self.destination {
+            let mut destinations = self.queue.destinations.lock().unwrap();
+            if let Some(lock) = destinations.get(destination) {
+                if Arc::strong_count(lock) == 1 {
+                    destinations.remove(destination);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use anyhow::Result;
+
+    use super::SendQueue;
+    use crate::error::SendPaymentError;
+
+    #[tokio::test]
+    async fn test_duplicate_payment_hash() -> Result<()> {
+        let queue = Arc::new(SendQueue::new(10));
+        let permit = queue.acquire(Some("node1"), Some("hash1")).await?;
+        assert!(matches!(
+            queue.acquire(Some("node1"), Some("hash1")).await,
+            Err(SendPaymentError::AlreadyInProgress)
+        ));
+        drop(permit);
+        assert!(queue.acquire(Some("node1"), Some("hash1")).await.is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_payments() -> Result<()> {
+        let queue = Arc::new(SendQueue::new(2));
+        let first = queue.acquire(Some("node1"), Some("hash1")).await?;
+        let _second = queue.acquire(Some("node2"), None).await?;
+
+        // The third payment waits for a slot
+        assert!(tokio::time::timeout(
+            Duration::from_millis(50),
+            queue.acquire(Some("node3"), Some("hash3"))
+        )
+        .await
+        .is_err());
+        // Giving up while queued releases the payment hash
+        assert_eq!(queue.in_flight.lock().unwrap().len(), 1);
+        drop(first);
+        assert!(tokio::time::timeout(
+            Duration::from_millis(50),
+            queue.acquire(Some("node3"), Some("hash3"))
+        )
+        .await
+        .is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_zero_max_concurrent_payments() -> Result<()> {
+        // At least one payment can be sent
+        let queue = Arc::new(SendQueue::new(0));
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), queue.acquire(None, None))
+                .await
+                .is_ok()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_same_destination_is_serialized() -> Result<()> {
+        let queue = Arc::new(SendQueue::new(10));
+        let permit = queue.acquire(Some("node1"), Some("hash1")).await?;
+
+        // A different destination is not blocked
+        assert!(tokio::time::timeout(
+            Duration::from_millis(50),
+            queue.acquire(Some("node2"), None)
+        )
+        .await
+        .is_ok());
+        // The same destination waits for the first permit to be dropped
+        assert!(tokio::time::timeout(
+            Duration::from_millis(50),
+            queue.acquire(Some("node1"), Some("hash2"))
+        )
+        .await
+        .is_err());
+        drop(permit);
+        assert!(tokio::time::timeout(
+            Duration::from_millis(50),
+            queue.acquire(Some("node1"), Some("hash2"))
+        )
+        .await
+        .is_ok());
+        assert!(queue.destinations.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_same_destination_keeps_order() -> Result<()> {
+        let queue = Arc::new(SendQueue::new(10));
+        let permit = queue.acquire(Some("node1"), None).await?;
+
+        let order = Arc::new(std::sync::Mutex::new(vec![]));
+        let mut waiting = vec![];
+        for i in 0..5 {
+            let (queue, order) = (queue.clone(), order.clone());
+            waiting.push(tokio::spawn(async move {
+                let _permit = queue.acquire(Some("node1"), None).await.unwrap();
+                order.lock().unwrap().push(i);
+            }));
+            // Let the payment start waiting before queuing the next one
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        drop(permit);
+        for handle in waiting {
+            handle.await?;
+        }
+        assert_eq!(*order.lock().unwrap(), vec![0, 1, 2, 3, 4]);
+        Ok(())
+    }
+}
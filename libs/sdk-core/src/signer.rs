@@ -0,0 +1,126 @@
+use anyhow::Result;
+use tokio::sync::mpsc;
+
+use crate::bitcoin::hashes::{sha256, Hash, HashEngine, Hmac, HmacEngine};
+use crate::bitcoin::secp256k1::ecdh::SharedSecret;
+use crate::bitcoin::secp256k1::{ecdsa, schnorr, KeyPair, Message, PublicKey, Secp256k1};
+use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey, ExtendedPubKey};
+use crate::bitcoin::util::schnorr::TapTweak;
+use crate::bitcoin::Network;
+
+/// Signer holds the keys of the node and signs on its behalf, so the seed doesn't have to be
+/// handed to the SDK.
+///
+/// The keys never leave the signer: the SDK only asks for public keys, signatures, shared
+/// secrets and symmetric keys derived at a path from the master key of the node.
+///
+/// [crate::SeedSigner] is the default implementation, keeping the seed in process. Integrators
+/// can implement it on top of a hardware wallet or an HSM and connect with
+/// [crate::BreezServices::connect_with_signer].
+#[tonic::async_trait]
+pub trait Signer: Send + Sync {
+    /// The public key of the node
+    fn node_id(&self) -> Result<Vec<u8>>;
+
+    /// The extended public key at `path`
+    fn derive_xpub(&self, path: Vec<ChildNumber>) -> Result<ExtendedPubKey>;
+
+    /// Signs the message with the key at `path`
+    fn sign_ecdsa(&self, path: Vec<ChildNumber>, message: Message) -> Result<ecdsa::Signature>;
+
+    /// Signs the message with the key at `path`, tweaked as the output key of a P2TR address
+    /// without script tree
+    fn sign_taproot(&self, path: Vec<ChildNumber>, message: Message) -> Result<schnorr::Signature>;
+
+    /// The ECDH shared secret of the key at `path` and `public_key`
+    fn ecdh(&self, path: Vec<ChildNumber>, public_key: PublicKey) -> Result<[u8; 32]>;
+
+    /// The HMAC-SHA256 of `input`, keyed with the serialized extended private key at `path`, as
+    /// LNURL-auth derives the linking keys
+    fn hmac_sha256(&self, path: Vec<ChildNumber>, input: Vec<u8>) -> Result<[u8; 32]>;
+
+    /// A 32 byte secret bound to `path`. The SDK encrypts its backups and stored credentials
+    /// and derives the swap refund keys with them.
+    fn derive_symmetric_key(&self, path: Vec<ChildNumber>) -> Result<[u8; 32]>;
+
+    /// Same as [Signer::derive_symmetric_key], from the master key used by earlier SDK versions
+    fn legacy_derive_symmetric_key(&self, path: Vec<ChildNumber>) -> Result<[u8; 32]>;
+
+    /// Signs the message, returning the compact signature and its recovery id
+    fn sign_message(&self, message: Vec<u8>) -> Result<(Vec<u8>, u8)>;
+
+    /// Signs the serialized invoice, returning the compact signature followed by the recovery id
+    fn sign_invoice(&self, msg: Vec<u8>) -> Result<Vec<u8>>;
+
+    /// Serves the signing requests of the node until `shutdown` receives a message.
+    ///
+    /// `device_credentials` are the credentials the node was registered with. Signers whose
+    /// requests reach them over another channel can just wait for the shutdown.
+    async fn run(&self, device_credentials: Vec<u8>, shutdown: mpsc::Receiver<()>) -> Result<()>;
+
+    /// The seed of the node, if the signer has access to it.
+    ///
+    /// A node can only be registered or recovered with the seed, so without it the node
+    /// credentials have to be provided when connecting.
+    fn seed(&self) -> Option<Vec<u8>> {
+        None
+    }
+}
+
+/// The [Signer] operations on the keys derived from a master key held in process
+pub(crate) struct MasterKey {
+    master: ExtendedPrivKey,
+    secp: Secp256k1<crate::bitcoin::secp256k1::All>,
+}
+
+impl MasterKey {
+    pub(crate) fn new(network: Network, seed: &[u8]) -> Result<Self> {
+        Ok(Self {
+            master: ExtendedPrivKey::new_master(network, seed)?,
+            secp: Secp256k1::new(),
+        })
+    }
+
+    fn derive(&self, path: &[ChildNumber]) -> Result<ExtendedPrivKey> {
+        Ok(self.master.derive_priv(&self.secp, &path)?)
+    }
+
+    pub(crate) fn derive_xpub(&self, path: &[ChildNumber]) -> Result<ExtendedPubKey> {
+        Ok(ExtendedPubKey::from_priv(&self.secp, &self.derive(path)?))
+    }
+
+    pub(crate) fn sign_ecdsa(
+        &self,
+        path: &[ChildNumber],
+        message: &Message,
+    ) -> Result<ecdsa::Signature> {
+        Ok(self
+            .secp
+            .sign_ecdsa(message, &self.derive(path)?.private_key))
+    }
+
+    pub(crate) fn sign_taproot(
+        &self,
+        path: &[ChildNumber],
+        message: &Message,
+    ) -> Result<schnorr::Signature> {
+        let keypair = KeyPair::from_secret_key(&self.secp, &self.derive(path)?.private_key)
+            .tap_tweak(&self.secp, None)
+            .to_inner();
+        Ok(self.secp.sign_schnorr(message, &keypair))
+    }
+
+    pub(crate) fn ecdh(&self, path: &[ChildNumber], public_key: &PublicKey) -> Result<[u8; 32]> {
+        Ok(SharedSecret::new(public_key, &self.derive(path)?.private_key).secret_bytes())
+    }
+
+    pub(crate) fn hmac_sha256(&self, path: &[ChildNumber], input: &[u8]) -> Result<[u8; 32]> {
+        let mut engine = HmacEngine::<sha256::Hash>::new(&self.derive(path)?.encode());
+        engine.input(input);
+        Ok(Hmac::<sha256::Hash>::from_engine(engine).into_inner())
+    }
+
+    pub(crate) fn derive_symmetric_key(&self, path: &[ChildNumber]) -> Result<[u8; 32]> {
+        Ok(self.derive(path)?.private_key.secret_bytes())
+    }
+}
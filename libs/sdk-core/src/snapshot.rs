@@ -0,0 +1,178 @@
+use serde_json::{Map, Value};
+
+use crate::error::{SdkError, SdkResult};
+use crate::models::SnapshotChange;
+
+/// The value of the redacted fields of a [crate::BreezServices::diagnostic_snapshot]
+const REDACTED: &str = "<redacted>";
+
+/// The fields blanked in a snapshot, at any depth: the secrets, and what the user wrote or read
+/// about their payments. Amounts, statuses and ids are kept, support needs them.
+const REDACTED_FIELDS: &[&str] = &[
+    "bolt11",
+    "description",
+    "label",
+    "ln_address",
+    "lnurl_metadata",
+    "lnurl_pay_comment",
+    "lnurl_success_action",
+    "metadata",
+    "open_channel_bolt11",
+    "payment_preimage",
+    "preimage",
+    "private_key",
+    "tlvs",
+];
+
+/// Blanks the [REDACTED_FIELDS] of the value
+pub(crate) fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, field) in map.iter_mut() {
+                if REDACTED_FIELDS.contains(&key.as_str()) {
+                    if !field.is_null() {
+                        *field = Value::String(REDACTED.to_string());
+                    }
+                } else {
+                    redact(field);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// Turns an array of objects into an object keyed by their `key` field, so two snapshots can be
+/// compared item by item even when items were added in between
+pub(crate) fn keyed_by(values: Value, key: &str) -> Value {
+    match values {
+        Value::Array(values) => Value::Object(
+            values
+                .into_iter()
+                .map(|value| {
+                    let id = match value.get(key) {
+                        Some(Value::String(id)) => id.clone(),
+                        Some(id) => id.to_string(),
+                        None => String::new(),
+                    };
+                    (id, value)
+                })
+                .collect::<Map<_, _>>(),
+        ),
+        other => other,
+    }
+}
+
+/// Lists the differences between two snapshots of [crate::BreezServices::diagnostic_snapshot],
+/// for example taken before and after a balance discrepancy.
+///
+/// Objects are compared field by field, and other values, including arrays, as a whole. Each
+/// change has the dot separated path of the value, like `payments.<id>.status`.
+pub fn diff_snapshots(a: String, b: String) -> SdkResult<Vec<SnapshotChange>> {
+    let parse = |snapshot: &str| {
+        serde_json::from_str::<Value>(snapshot).map_err(|e| SdkError::Generic {
+            err: format!("Invalid snapshot: {e}"),
+        })
+    };
+    let mut changes = Vec::new();
+    diff_values("", Some(&parse(&a)?), Some(&parse(&b)?), &mut changes);
+    Ok(changes)
+}
+
+fn diff_values(
+    path: &str,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<SnapshotChange>,
+) {
+    if let (Some(Value::Object(before)), Some(Value::Object(after))) = (before, after) {
+        let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        for key in keys {
+            let path = match path {
+                "" => key.clone(),
+                _ => format!("{path}.{key}"),
+            };
+            diff_values(&path, before.get(key), after.get(key), changes);
+        }
+    } else if before != after {
+        changes.push(SnapshotChange {
+            path: path.to_string(),
+            before: before.map(Value::to_string),
+            after: after.map(Value::to_string),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{diff_snapshots, keyed_by, redact, REDACTED};
+    use crate::models::SnapshotChange;
+
+    #[test]
+    fn test_redact() {
+        let mut value = json!({
+            "payments": [{
+                "amount_msat": 1000,
+                "description": "Coffee",
+                "details": {"data": {"payment_preimage": "00", "label": null}},
+            }],
+        });
+        redact(&mut value);
+        assert_eq!(
+            value,
+            json!({
+                "payments": [{
+                    "amount_msat": 1000,
+                    "description": REDACTED,
+                    "details": {"data": {"payment_preimage": REDACTED, "label": null}},
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let before = json!({
+            "node_state": {"channels_balance_msat": 5000, "connected_peers": ["a"]},
+            "payments": keyed_by(json!([{"id": "p1", "status": "pending"}]), "id"),
+        });
+        let after = json!({
+            "node_state": {"channels_balance_msat": 4000, "connected_peers": ["a"]},
+            "payments": keyed_by(json!([
+                {"id": "p2", "status": "complete"},
+                {"id": "p1", "status": "failed"},
+            ]), "id"),
+        });
+        let changes = diff_snapshots(before.to_string(), after.to_string()).unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                SnapshotChange {
+                    path: "node_state.channels_balance_msat".into(),
+                    before: Some("5000".into()),
+                    after: Some("4000".into()),
+                },
+                SnapshotChange {
+                    path: "payments.p1.status".into(),
+                    before: Some(r#""pending""#.into()),
+                    after: Some(r#""failed""#.into()),
+                },
+                SnapshotChange {
+                    path: "payments.p2".into(),
+                    before: None,
+                    after: Some(r#"{"id":"p2","status":"complete"}"#.into()),
+                },
+            ]
+        );
+
+        assert!(diff_snapshots(before.to_string(), before.to_string())
+            .unwrap()
+            .is_empty());
+        assert!(diff_snapshots("not json".into(), before.to_string()).is_err());
+    }
+}
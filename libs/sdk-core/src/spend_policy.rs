@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::error::SendPaymentError;
+use crate::persist::db::SqliteStorage;
+use crate::persist::error::PersistError;
+use crate::{Payment, SpendPolicy};
+
+/// The window of the [SpendPolicy::daily_limit_msat]
+pub(crate) const SPEND_POLICY_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// Why a payment is refused by the [crate::Config::spend_policy]
+#[derive(Debug, Error)]
+pub(crate) enum SpendPolicyError {
+    #[error("The amount of {amount_msat} msat is over the per-payment limit of {limit_msat} msat")]
+    PerPaymentLimitExceeded { amount_msat: u64, limit_msat: u64 },
+
+    #[error("The amount of {amount_msat} msat is over the daily limit of {limit_msat} msat, of which {spent_msat} msat were spent over the last 24 hours")]
+    DailyLimitExceeded {
+        amount_msat: u64,
+        limit_msat: u64,
+        spent_msat: u64,
+    },
+
+    #[error("{destination} is not in the allowed destinations")]
+    DestinationNotAllowed { destination: String },
+
+    #[error(transparent)]
+    Persist(#[from] PersistError),
+}
+
+impl SpendPolicy {
+    /// Checks the payment against the allowed destinations and the per-payment limit.
+    ///
+    /// The payment is allowed if one of the `destinations` identifying the payee is allowed. An
+    /// allowed domain also allows its subdomains. The destinations are not checked if `None`,
+    /// for the partner fee payments.
+    pub(crate) fn check(
+        &self,
+        amount_msat: u64,
+        destinations: Option<&[&str]>,
+    ) -> Result<(), SpendPolicyError> {
+        if let (Some(allowed), Some(destinations)) = (&self.allowed_destinations, destinations) {
+            let is_allowed = destinations.iter().any(|destination| {
+                let destination = destination.trim().to_lowercase();
+                !destination.is_empty()
+                    && allowed.iter().map(|a| a.trim().to_lowercase()).any(|a| {
+                        !a.is_empty()
+                            && (destination == a || destination.ends_with(&format!(".{a}")))
+                    })
+            });
+            if !is_allowed {
+                return Err(SpendPolicyError::DestinationNotAllowed {
+                    destination: destinations.first().unwrap_or(&"").to_string(),
+                });
+            }
+        }
+        if let Some(limit_msat) = self.per_payment_limit_msat {
+            if amount_msat > limit_msat {
+                return Err(SpendPolicyError::PerPaymentLimitExceeded {
+                    amount_msat,
+                    limit_msat,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A payment amount and its max routing fee, counted towards the [SpendPolicy::daily_limit_msat].
+/// It stops being counted when dropped, unless it's kept because the payment was sent.
+pub(crate) struct SpendReservation {
+    persister: Arc<SqliteStorage>,
+    id: i64,
+    amount_msat: u64,
+    kept: bool,
+}
+
+impl SpendReservation {
+    pub(crate) fn new(persister: Arc<SqliteStorage>, id: i64, amount_msat: u64) -> Self {
+        Self {
+            persister,
+            id,
+            amount_msat,
+            kept: false,
+        }
+    }
+
+    /// Keeps counting the amount and the max routing fee, once the payment was sent
+    pub(crate) fn keep(mut self) {
+        self.kept = true;
+    }
+
+    /// Keeps counting the amount if the payment may have been sent: if it succeeded, with the
+    /// routing fee it was sent with, or if it timed out and may still complete, with the max
+    /// routing fee.
+    pub(crate) fn settle(self, payment_res: &Result<Payment, SendPaymentError>) {
+        match payment_res {
+            Ok(payment) => {
+                let spent_msat = self.amount_msat + payment.fee_msat;
+                if let Err(e) = self.persister.update_spend_amount(self.id, spent_msat) {
+                    warn!("Failed to update the spend {}: {e}", self.id);
+                }
+                self.keep();
+            }
+            Err(SendPaymentError::PaymentTimeout { .. }) => self.keep(),
+            Err(_) => {}
+        }
+    }
+}
+
+impl Drop for SpendReservation {
+    fn drop(&mut self) {
+        if !self.kept {
+            if let Err(e) = self.persister.delete_spend(self.id) {
+                warn!("Failed to release the spend {}: {e}", self.id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::spend_policy::SpendPolicyError;
+    use crate::SpendPolicy;
+
+    #[test]
+    fn test_check() {
+        let pubkey = "02eadbd9e7557375161df8b646776a547c5cbc2e95b3071ec81553f8ec2cea3b8c";
+        assert!(SpendPolicy::default()
+            .check(u64::MAX, Some(&[pubkey][..]))
+            .is_ok());
+
+        let policy = SpendPolicy {
+            per_payment_limit_msat: Some(1_000),
+            allowed_destinations: Some(vec![
+                pubkey.to_uppercase(),
+                "Shop@Example.com".to_string(),
+                "school.org".to_string(),
+                " ".to_string(),
+            ]),
+            ..Default::default()
+        };
+        assert!(policy.check(1_000, Some(&[pubkey][..])).is_ok());
+        assert!(policy
+            .check(1_000, Some(&["example.com", "shop@example.com"][..]))
+            .is_ok());
+        assert!(policy.check(1_000, Some(&["pay.school.org"][..])).is_ok());
+        assert!(matches!(
+            policy.check(1_001, Some(&[pubkey][..])),
+            Err(SpendPolicyError::PerPaymentLimitExceeded { .. })
+        ));
+        for destinations in [&["example.com"][..], &["myschool.org"], &[""], &[]] {
+            assert!(matches!(
+                policy.check(1_000, Some(destinations)),
+                Err(SpendPolicyError::DestinationNotAllowed { .. })
+            ));
+        }
+
+        // The partner fee payments are not checked against the allowed destinations
+        assert!(policy.check(1_000, None).is_ok());
+        assert!(matches!(
+            policy.check(1_001, None),
+            Err(SpendPolicyError::PerPaymentLimitExceeded { .. })
+        ));
+    }
+}
@@ -0,0 +1,206 @@
+use reqwest::Url;
+use sdk_common::ensure_sdk;
+use sdk_common::prelude::MilliSat;
+use serde_json::json;
+
+use crate::bitcoin::bech32::{self, ToBase32, Variant};
+use crate::error::{SdkError, SdkResult};
+use crate::CreateStaticLnurlPayRequest;
+
+/// The min amount of a static LNURL-pay endpoint, the smallest invoice amount
+const MIN_SENDABLE_MSAT: MilliSat = MilliSat(1_000);
+
+impl CreateStaticLnurlPayRequest {
+    pub(crate) fn validate(&self) -> SdkResult<()> {
+        for url in [&self.url, &self.callback_url] {
+            let parsed = Url::parse(url).map_err(|e| SdkError::Generic {
+                err: format!("Invalid URL {url}: {e}"),
+            })?;
+            let is_onion = parsed
+                .host_str()
+                .is_some_and(|host| host.ends_with(".onion"));
+            ensure_sdk!(
+                parsed.scheme() == "https" || (parsed.scheme() == "http" && is_onion),
+                SdkError::Generic {
+                    err: format!("The URL {url} has to be HTTPS, or HTTP for an onion service"),
+                }
+            );
+        }
+        ensure_sdk!(
+            !self.description.trim().is_empty(),
+            SdkError::generic("The description can't be empty")
+        );
+        ensure_sdk!(
+            MIN_SENDABLE_MSAT <= self.min_sendable_msat
+                && self.min_sendable_msat <= self.max_sendable_msat,
+            SdkError::Generic {
+                err: format!(
+                    "The min sendable amount has to be at least {MIN_SENDABLE_MSAT} and at most the max sendable amount"
+                ),
+            }
+        );
+        Ok(())
+    }
+
+    /// The LUD-06 metadata of the endpoint
+    pub(crate) fn metadata(&self) -> String {
+        json!([["text/plain", self.description]]).to_string()
+    }
+
+    /// The LUD-06 response served at the URL of the endpoint
+    pub(crate) fn pay_request(&self) -> String {
+        let mut pay_request = json!({
+            "tag": "payRequest",
+            "callback": self.callback_url,
+            "minSendable": self.min_sendable_msat,
+            "maxSendable": self.max_sendable_msat,
+            "metadata": self.metadata(),
+        });
+        if let Some(comment_allowed) = self.comment_allowed.filter(|c| *c > 0) {
+            pay_request["commentAllowed"] = json!(comment_allowed);
+        }
+        pay_request.to_string()
+    }
+
+    /// Checks the parameters of a request to the callback URL. Returns the reason to give the
+    /// payer if they are invalid.
+    pub(crate) fn check_callback(
+        &self,
+        amount_msat: MilliSat,
+        comment: Option<&str>,
+    ) -> Result<(), String> {
+        if amount_msat < self.min_sendable_msat || amount_msat > self.max_sendable_msat {
+            return Err(format!(
+                "The amount has to be between {} and {}",
+                self.min_sendable_msat, self.max_sendable_msat
+            ));
+        }
+        let comment_len = comment.map(|c| c.chars().count()).unwrap_or_default();
+        let comment_allowed = self.comment_allowed.unwrap_or_default() as usize;
+        if comment_len > comment_allowed {
+            return Err(format!(
+                "The comment can't be longer than {comment_allowed} characters"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Encodes the URL as a bech32 LNURL, in upper case for a more compact QR code
+pub(crate) fn encode_lnurl(url: &str) -> SdkResult<String> {
+    bech32::encode("lnurl", url.to_base32(), Variant::Bech32)
+        .map(|lnurl| lnurl.to_uppercase())
+        .map_err(|e| SdkError::Generic {
+            err: format!("Failed to encode the LNURL: {e}"),
+        })
+}
+
+/// The LUD-06 response of the callback URL refusing the request
+pub(crate) fn error_response(reason: &str) -> String {
+    json!({ "status": "ERROR", "reason": reason }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use sdk_common::prelude::MilliSat;
+    use serde_json::Value;
+
+    use crate::bitcoin::bech32::{self, FromBase32};
+    use crate::static_lnurl_pay::encode_lnurl;
+    use crate::CreateStaticLnurlPayRequest;
+
+    fn request() -> CreateStaticLnurlPayRequest {
+        CreateStaticLnurlPayRequest {
+            url: "https://alice.example.com/lnurlp".to_string(),
+            callback_url: "https://alice.example.com/lnurlp/callback".to_string(),
+            description: "Donations to Alice".to_string(),
+            min_sendable_msat: MilliSat(1_000),
+            max_sendable_msat: MilliSat(1_000_000),
+            comment_allowed: Some(10),
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(request().validate().is_ok());
+        let onion = CreateStaticLnurlPayRequest {
+            callback_url: "http://alice.onion/callback".to_string(),
+            ..request()
+        };
+        assert!(onion.validate().is_ok());
+        for invalid in [
+            CreateStaticLnurlPayRequest {
+                url: "http://alice.example.com/lnurlp".to_string(),
+                ..request()
+            },
+            CreateStaticLnurlPayRequest {
+                callback_url: "not a url".to_string(),
+                ..request()
+            },
+            CreateStaticLnurlPayRequest {
+                description: " ".to_string(),
+                ..request()
+            },
+            CreateStaticLnurlPayRequest {
+                min_sendable_msat: MilliSat(999),
+                ..request()
+            },
+            CreateStaticLnurlPayRequest {
+                min_sendable_msat: MilliSat(2_000_000),
+                ..request()
+            },
+        ] {
+            assert!(invalid.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_pay_request() -> anyhow::Result<()> {
+        let req = request();
+        let pay_request: Value = serde_json::from_str(&req.pay_request())?;
+        assert_eq!(pay_request["tag"], "payRequest");
+        assert_eq!(pay_request["callback"], req.callback_url);
+        assert_eq!(pay_request["minSendable"], 1_000);
+        assert_eq!(pay_request["maxSendable"], 1_000_000);
+        assert_eq!(pay_request["commentAllowed"], 10);
+        assert_eq!(
+            pay_request["metadata"],
+            r#"[["text/plain","Donations to Alice"]]"#
+        );
+
+        let no_comments = CreateStaticLnurlPayRequest {
+            comment_allowed: None,
+            ..request()
+        };
+        let pay_request: Value = serde_json::from_str(&no_comments.pay_request())?;
+        assert!(pay_request.get("commentAllowed").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_callback() {
+        let req = request();
+        assert!(req.check_callback(MilliSat(1_000), None).is_ok());
+        assert!(req
+            .check_callback(MilliSat(1_000_000), Some("thank you!"))
+            .is_ok());
+        assert!(req.check_callback(MilliSat(999), None).is_err());
+        assert!(req.check_callback(MilliSat(1_000_001), None).is_err());
+        assert!(req
+            .check_callback(MilliSat(1_000), Some("thank you!!"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_encode_lnurl() -> anyhow::Result<()> {
+        let lnurl = encode_lnurl("https://alice.example.com/lnurlp")?;
+        assert!(lnurl.starts_with("LNURL1"));
+        let (hrp, data, _) = bech32::decode(&lnurl)?;
+        assert_eq!(hrp, "lnurl");
+        assert_eq!(
+            String::from_utf8(Vec::<u8>::from_base32(&data)?)?,
+            "https://alice.example.com/lnurlp"
+        );
+        Ok(())
+    }
+}
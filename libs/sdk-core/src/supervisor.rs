@@ -0,0 +1,276 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::watch;
+use tokio::time::sleep;
+
+use crate::{TaskState, TaskStatus};
+
+/// The delay before restarting a task after it failed, doubled on each failure in a row
+const RESTART_MIN_DELAY_SECS: u64 = 1;
+const RESTART_MAX_DELAY_SECS: u64 = 60;
+/// A task running this long before failing is restarted after the min delay again
+const HEALTHY_RUN_SECS: u64 = 300;
+
+/// The outcome of a run of a supervised task: a task returning `()` can only fail by panicking,
+/// a task returning a `Result` also fails with an error.
+pub(crate) trait TaskResult {
+    fn into_result(self) -> Result<(), String>;
+}
+
+impl TaskResult for () {
+    fn into_result(self) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+impl<E: Display> TaskResult for Result<(), E> {
+    fn into_result(self) -> Result<(), String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// Runs the long-lived background tasks of the SDK, restarting the ones that fail, so a failure
+/// doesn't silently disable a feature until the app restarts. The health of the tasks is
+/// returned by [crate::BreezServices::task_status].
+///
+/// A task fails when it returns an error, or when it panics. Catching a panic relies on
+/// unwinding: in a build with `panic = "abort"`, like the release profile of the bindings, a
+/// panic aborts the process, so only the tasks returning an error are restarted.
+#[derive(Default)]
+pub(crate) struct TaskSupervisor {
+    tasks: StdMutex<HashMap<&'static str, TaskStatus>>,
+}
+
+impl TaskSupervisor {
+    /// Spawns the task on the tokio runtime, see [TaskSupervisor::run]
+    pub(crate) fn spawn<F, Fut, R>(
+        self: &Arc<Self>,
+        name: &'static str,
+        shutdown_receiver: watch::Receiver<()>,
+        task: F,
+    ) where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = R> + Send + 'static,
+        R: TaskResult + Send + 'static,
+    {
+        let supervisor = self.clone();
+        tokio::spawn(async move { supervisor.run(name, shutdown_receiver, task).await });
+    }
+
+    /// Runs the future created by `task` until it completes, creating and running it again
+    /// whenever it fails. Each run is spawned as a tokio task, which catches its panic.
+    ///
+    /// The task is expected to complete on shutdown. It's not restarted once the shutdown is
+    /// signaled, and the `shutdown_receiver` is dropped when it completes, so waiting for the
+    /// shutdown receivers to be dropped waits for the task.
+    pub(crate) async fn run<F, Fut, R>(
+        &self,
+        name: &'static str,
+        mut shutdown_receiver: watch::Receiver<()>,
+        mut task: F,
+    ) where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = R> + Send + 'static,
+        R: TaskResult + Send + 'static,
+    {
+        self.tasks
+            .lock()
+            .unwrap()
+            .insert(name, TaskStatus::new(name));
+        let mut failures_in_a_row = 0;
+        loop {
+            let started_at = Instant::now();
+            let error = match tokio::spawn(task()).await.map(TaskResult::into_result) {
+                Ok(Ok(())) => {
+                    debug!("Task {name} completed");
+                    self.set_state(name, TaskState::Stopped);
+                    return;
+                }
+                Ok(Err(e)) => {
+                    error!("Task {name} failed: {e}");
+                    e
+                }
+                Err(e) if e.is_panic() => {
+                    let message = panic_message(e.into_panic().as_ref());
+                    error!("Task {name} panicked: {message}");
+                    message
+                }
+                Err(e) => {
+                    // The runtime is shutting down
+                    debug!("Task {name} was cancelled: {e}");
+                    self.set_state(name, TaskState::Stopped);
+                    return;
+                }
+            };
+            if started_at.elapsed() >= Duration::from_secs(HEALTHY_RUN_SECS) {
+                failures_in_a_row = 0;
+            }
+            failures_in_a_row += 1;
+            let delay = restart_delay(failures_in_a_row);
+            info!("Restarting task {name} in {delay:?}");
+            if let Some(status) = self.tasks.lock().unwrap().get_mut(name) {
+                status.state = TaskState::Restarting;
+                status.restarts += 1;
+                status.last_error = Some(error);
+                status.last_error_at = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs() as i64)
+                    .ok();
+            }
+
+            let is_shutdown = tokio::select! {
+                _ = sleep(delay) => shutdown_receiver.has_changed().unwrap_or(true),
+                _ = shutdown_receiver.changed() => true,
+            };
+            if is_shutdown {
+                debug!("Task {name} not restarted on shutdown");
+                self.set_state(name, TaskState::Stopped);
+                return;
+            }
+            self.set_state(name, TaskState::Running);
+        }
+    }
+
+    /// The status of the tasks started since connect, by name
+    pub(crate) fn status(&self) -> Vec<TaskStatus> {
+        let mut status: Vec<TaskStatus> = self.tasks.lock().unwrap().values().cloned().collect();
+        status.sort_by(|a, b| a.name.cmp(&b.name));
+        status
+    }
+
+    fn set_state(&self, name: &str, state: TaskState) {
+        if let Some(status) = self.tasks.lock().unwrap().get_mut(name) {
+            status.state = state;
+        }
+    }
+}
+
+impl TaskStatus {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            state: TaskState::Running,
+            restarts: 0,
+            last_error: None,
+            last_error_at: None,
+        }
+    }
+}
+
+fn restart_delay(failures_in_a_row: u32) -> Duration {
+    let delay_secs = RESTART_MIN_DELAY_SECS
+        .saturating_mul(1 << failures_in_a_row.saturating_sub(1).min(16))
+        .min(RESTART_MAX_DELAY_SECS);
+    Duration::from_secs(delay_secs)
+}
+
+/// The message given to `panic!`, which is either a `&str` or a `String`
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use tokio::sync::watch;
+
+    use crate::supervisor::{restart_delay, TaskSupervisor};
+    use crate::TaskState;
+
+    #[test]
+    fn test_restart_delay() {
+        assert_eq!(restart_delay(1), Duration::from_secs(1));
+        assert_eq!(restart_delay(2), Duration::from_secs(2));
+        assert_eq!(restart_delay(4), Duration::from_secs(8));
+        assert_eq!(restart_delay(7), Duration::from_secs(60));
+        assert_eq!(restart_delay(u32::MAX), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_restart_on_panic() {
+        let supervisor = Arc::new(TaskSupervisor::default());
+        let (shutdown_sender, shutdown_receiver) = watch::channel(());
+        let runs = Arc::new(AtomicU32::new(0));
+
+        // The task panics on its first two runs, then runs until the shutdown
+        let task_runs = runs.clone();
+        let task_shutdown_receiver = shutdown_receiver.clone();
+        supervisor.spawn("flaky", shutdown_receiver, move || {
+            let runs = task_runs.clone();
+            let mut shutdown_receiver = task_shutdown_receiver.clone();
+            async move {
+                if runs.fetch_add(1, Ordering::SeqCst) < 2 {
+                    panic!("flaky task failed");
+                }
+                _ = shutdown_receiver.changed().await;
+            }
+        });
+
+        // Restarted after 1 and 2 seconds
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while runs.load(Ordering::SeqCst) < 3 {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .unwrap();
+        let status = supervisor.status();
+        assert_eq!(status.len(), 1);
+        assert_eq!(status[0].name, "flaky");
+        assert_eq!(status[0].state, TaskState::Running);
+        assert_eq!(status[0].restarts, 2);
+        assert_eq!(status[0].last_error.as_deref(), Some("flaky task failed"));
+        assert!(status[0].last_error_at.is_some());
+
+        // Shutting down waits for the task to complete
+        shutdown_sender.send(()).unwrap();
+        shutdown_sender.closed().await;
+        assert_eq!(supervisor.status()[0].state, TaskState::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_restart_on_error() {
+        let supervisor = Arc::new(TaskSupervisor::default());
+        let (_shutdown_sender, shutdown_receiver) = watch::channel(());
+        let runs = Arc::new(AtomicU32::new(0));
+
+        // The task fails on its first run, then completes
+        let task_runs = runs.clone();
+        supervisor.spawn("fallible", shutdown_receiver, move || {
+            let runs = task_runs.clone();
+            async move {
+                match runs.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err("not reachable"),
+                    _ => Ok(()),
+                }
+            }
+        });
+
+        // Restarted after 1 second
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while supervisor.status().first().map(|s| s.state) != Some(TaskState::Stopped) {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        let status = supervisor.status();
+        assert_eq!(status[0].restarts, 1);
+        assert_eq!(status[0].last_error.as_deref(), Some("not reachable"));
+    }
+}
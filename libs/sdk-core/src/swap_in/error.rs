@@ -70,6 +70,15 @@ impl From<PersistError> for ReceiveSwapError {
     }
 }
 
+impl From<NodeError> for ReceiveSwapError {
+    fn from(e: NodeError) -> Self {
+        match e {
+            NodeError::ServiceConnectivity(err) => ReceiveSwapError::ServiceConnectivity(err),
+            _ => ReceiveSwapError::Generic(e.to_string()),
+        }
+    }
+}
+
 impl From<SdkError> for ReceiveSwapError {
     fn from(e: SdkError) -> Self {
         match e {
@@ -6,7 +6,10 @@ mod taproot;
 mod taproot_server;
 
 pub(crate) use error::ReceiveSwapError;
+#[cfg(feature = "test_vectors")]
+pub(crate) use segwit::create_submarine_swap_script;
 pub(crate) use swap::{
-    create_swap_keys, BTCReceiveSwap, BTCReceiveSwapParameters, SwapChainData, SwapChainInfo,
+    create_swap_keys, needs_new_opening_fee, BTCReceiveSwap, BTCReceiveSwapParameters,
+    SwapChainData, SwapChainInfo, SWAP_REFUND_KEY_GAP_LIMIT,
 };
 pub(crate) use taproot_server::TaprootSwapperAPI;
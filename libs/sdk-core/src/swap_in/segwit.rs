@@ -4,7 +4,8 @@ use gl_client::bitcoin::{
     blockdata::{opcodes, script::Builder},
     secp256k1::{Message, Secp256k1, SecretKey},
     util::sighash::SighashCache,
-    Address, EcdsaSighashType, PackedLockTime, Script, Sequence, Transaction, TxIn, TxOut, Witness,
+    Address, EcdsaSighashType, Network, PackedLockTime, Script, Sequence, Transaction, TxIn, TxOut,
+    Witness,
 };
 use ripemd::{Digest, Ripemd160};
 
@@ -19,12 +20,27 @@ const MAX_ECDSA_SIGNATURE_SIZE: usize = 73;
 const SEGWIT_SWAP_SCRIPT_SIZE: usize = 101;
 
 pub(super) struct SegwitReceiveSwap {
+    network: Network,
     swapper_api: Arc<dyn SwapperAPI>,
 }
 
 impl SegwitReceiveSwap {
-    pub fn new(swapper_api: Arc<dyn SwapperAPI>) -> Self {
-        Self { swapper_api }
+    pub fn new(network: Network, swapper_api: Arc<dyn SwapperAPI>) -> Self {
+        Self {
+            network,
+            swapper_api,
+        }
+    }
+
+    /// Computes the swap address from the keys, payment hash and lock height of the swap
+    pub fn swap_address(&self, swap_info: &SwapInfo) -> ReceiveSwapResult<String> {
+        let script = create_submarine_swap_script(
+            &swap_info.payment_hash,
+            &swap_info.swapper_public_key,
+            &swap_info.public_key,
+            swap_info.lock_height,
+        )?;
+        Ok(Address::p2wsh(&script, self.network).to_string())
     }
 
     pub fn payout_blocks_left(
@@ -153,7 +169,7 @@ impl SegwitReceiveSwap {
     }
 }
 
-fn create_submarine_swap_script(
+pub(crate) fn create_submarine_swap_script(
     payment_hash: &[u8],
     swapper_pub_key: &[u8],
     payer_pub_key: &[u8],
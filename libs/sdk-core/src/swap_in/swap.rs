@@ -10,6 +10,7 @@ use gl_client::{
         consensus::encode,
         hashes::sha256,
         secp256k1::{Message, PublicKey, Secp256k1, SecretKey},
+        util::bip32::ChildNumber,
         Address, AddressType, Network, OutPoint, Script, Sequence, TxIn, Witness,
     },
     lightning_invoice::Bolt11Invoice,
@@ -20,16 +21,17 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::{broadcast, Mutex};
 
 use crate::{
-    breez_services::{OpenChannelParams, Receiver},
-    chain::ChainService,
+    breez_services::{OpenChannelParams, Receiver, SwapRefundedDetails},
+    chain::{ChainService, RecommendedFees},
     error::ReceivePaymentError,
     node_api::{FetchBolt11Result, NodeAPI},
     persist::{
         cache::NodeStateStorage, error::PersistResult, swap::SwapStorage,
         transactions::PaymentStorage,
     },
-    BreezEvent, ListSwapsRequest, OpeningFeeParams, PrepareRefundRequest, PrepareRefundResponse,
-    ReceivePaymentRequest, RefundRequest, RefundResponse, SwapInfo, SwapStatus, SwapperAPI,
+    AutoRefundConfig, BreezEvent, FeeratePreset, ListSwapsRequest, MilliSat, OpeningFeeParams,
+    PrepareRefundRequest, PrepareRefundResponse, ReceivePaymentRequest, RefundFeePreview,
+    RefundRequest, RefundResponse, Sat, SwapInfo, SwapRefundKey, SwapStatus, SwapperAPI,
 };
 
 use super::{
@@ -44,12 +46,39 @@ const MIN_INVOICE_EXPIRY_SECONDS: u64 = 1800;
 const MIN_OPENING_FEE_PARAMS_VALIDITY_SECONDS: u32 = 1800;
 const MONITOR_EXPIRED_SWAP_BLOCKS: u32 = 144 * 28;
 
+/// The hardened derivation path index under which the swap refund keys are derived, see
+/// [derive_swap_refund_key]
+pub(crate) const SWAP_REFUND_KEY_PATH_INDEX: u32 = 145;
+/// How many refund keys are exported after the ones already used by swaps, to cover the swaps
+/// missing from the database, for example when it's lost and only the seed remains
+pub(crate) const SWAP_REFUND_KEY_GAP_LIMIT: u32 = 20;
+
 pub(crate) fn create_swap_keys() -> anyhow::Result<SwapKeys> {
     let priv_key = rand::thread_rng().gen::<[u8; 32]>().to_vec();
     let preimage = rand::thread_rng().gen::<[u8; 32]>().to_vec();
     Ok(SwapKeys { priv_key, preimage })
 }
 
+/// Whether the invoice of a redeemable swap expired unpaid after the opening fee params the swap
+/// was created with expired too. Redeeming it needs a new invoice and a new channel opening fee,
+/// which may be higher than the one accepted when the swap was created.
+pub(crate) fn needs_new_opening_fee(swap: &SwapInfo) -> bool {
+    let invoice_expired = swap
+        .bolt11
+        .as_ref()
+        .and_then(|bolt11| bolt11.parse::<Bolt11Invoice>().ok())
+        .is_some_and(|invoice| invoice.is_expired());
+    let fee_params_expired = swap
+        .channel_opening_fees
+        .as_ref()
+        .is_some_and(|fee_params| {
+            !fee_params
+                .valid_for(MIN_OPENING_FEE_PARAMS_VALIDITY_SECONDS)
+                .unwrap_or(false)
+        });
+    invoice_expired && fee_params_expired
+}
+
 pub(crate) struct SwapKeys {
     pub(crate) priv_key: Vec<u8>,
     pub(crate) preimage: Vec<u8>,
@@ -74,6 +103,29 @@ impl SwapKeys {
     }
 }
 
+/// The derivation path of the swap refund key at `index`, relative to the master key of the node
+pub(crate) fn swap_refund_key_path(index: u32) -> String {
+    format!("m/{SWAP_REFUND_KEY_PATH_INDEX}'/{index}'")
+}
+
+/// Derives the swap refund key at `index`, as the symmetric key of the hardened path
+/// `m/145'/index'`, so the swaps can be refunded with the seed alone
+pub(crate) async fn derive_swap_refund_key(
+    node_api: &dyn NodeAPI,
+    index: u32,
+) -> ReceiveSwapResult<SecretKey> {
+    let path = vec![
+        ChildNumber::Hardened {
+            index: SWAP_REFUND_KEY_PATH_INDEX,
+        },
+        ChildNumber::from_hardened_idx(index)
+            .map_err(|_| ReceiveSwapError::generic(format!("Invalid refund key index {index}")))?,
+    ];
+    Ok(SecretKey::from_slice(
+        &node_api.derive_symmetric_key(path).await?,
+    )?)
+}
+
 #[derive(Eq, PartialEq)]
 enum SwapAddressType {
     Segwit,
@@ -130,7 +182,8 @@ impl TryInto<TxIn> for &SwapOutput {
                 vout: self.output_index,
             },
             script_sig: Script::default(),
-            sequence: Sequence::default(),
+            // Signal RBF, so the refund fee can be bumped
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
             witness: Witness::default(),
         })
     }
@@ -194,12 +247,17 @@ impl From<SwapChainData> for SwapChainInfo {
 }
 
 pub(crate) struct BTCReceiveSwap {
+    auto_refund: Option<AutoRefundConfig>,
     chain_service: Arc<dyn ChainService>,
+    network: Network,
     payment_storage: Arc<dyn PaymentStorage>,
     current_tip: Mutex<u32>,
     node_api: Arc<dyn NodeAPI>,
     node_state_storage: Arc<dyn NodeStateStorage>,
     payment_receiver: Arc<dyn Receiver>,
+    /// Held while the pending swaps are processed, so a block and the swap monitor don't redeem
+    /// or refund the same swap twice
+    pending_swaps_lock: Mutex<()>,
     segwit: SegwitReceiveSwap,
     status_changes_notifier: broadcast::Sender<BreezEvent>,
     swap_storage: Arc<dyn SwapStorage>,
@@ -216,18 +274,22 @@ pub(crate) struct BTCReceiveSwapParameters {
     pub segwit_swapper_api: Arc<dyn SwapperAPI>,
     pub swap_storage: Arc<dyn SwapStorage>,
     pub taproot_swapper_api: Arc<dyn TaprootSwapperAPI>,
+    pub auto_refund: Option<AutoRefundConfig>,
 }
 
 impl BTCReceiveSwap {
     pub(crate) fn new(params: BTCReceiveSwapParameters) -> Self {
         BTCReceiveSwap {
+            auto_refund: params.auto_refund,
             chain_service: params.chain_service,
+            network: params.network,
             payment_storage: params.payment_storage,
             current_tip: Mutex::new(0),
             node_api: params.node_api,
             node_state_storage: params.node_state_storage,
             payment_receiver: params.payment_receiver,
-            segwit: SegwitReceiveSwap::new(params.segwit_swapper_api),
+            pending_swaps_lock: Mutex::new(()),
+            segwit: SegwitReceiveSwap::new(params.network, params.segwit_swapper_api),
             status_changes_notifier: broadcast::channel(100).0,
             swap_storage: params.swap_storage,
             taproot: TaprootReceiveSwap::new(params.network, params.taproot_swapper_api),
@@ -236,6 +298,7 @@ impl BTCReceiveSwap {
 }
 
 impl BTCReceiveSwap {
+    #[tracing::instrument(skip_all, fields(swap_address = tracing::field::Empty))]
     pub(crate) async fn create_swap(
         &self,
         opening_fee_params: OpeningFeeParams,
@@ -247,7 +310,9 @@ impl BTCReceiveSwap {
         // Calculate max_allowed_deposit based on absolute max and current node state
         let fn_max_allowed_deposit = |max_allowed_deposit_abs: i64| {
             std::cmp::min(
-                (node_state.max_receivable_msat / 1000) as i64,
+                MilliSat(node_state.max_receivable_msat)
+                    .to_sat_floor()
+                    .sat() as i64,
                 max_allowed_deposit_abs,
             )
         };
@@ -277,17 +342,62 @@ impl BTCReceiveSwap {
             self.swap_storage
                 .update_swap_fees(&unused_swap.bitcoin_address, &opening_fee_params)?;
 
+            tracing::Span::current().record("swap_address", unused_swap.bitcoin_address.as_str());
             return Ok(unused_swap);
         }
 
+        let refund_key_index = self.swap_storage.next_swap_refund_key_index()?;
+        let keys = SwapKeys {
+            priv_key: derive_swap_refund_key(self.node_api.as_ref(), refund_key_index)
+                .await?
+                .secret_bytes()
+                .to_vec(),
+            preimage: rand::thread_rng().gen::<[u8; 32]>().to_vec(),
+        };
         let swap_info = self
             .taproot
-            .create_swap(&node_state, opening_fee_params)
+            .create_swap(&node_state, opening_fee_params, keys)
             .await?;
-        self.swap_storage.insert_swap(&swap_info)?;
+        self.swap_storage
+            .insert_swap(&swap_info, Some(refund_key_index))?;
+        tracing::Span::current().record("swap_address", swap_info.bitcoin_address.as_str());
         Ok(swap_info)
     }
 
+    /// Checks that the swapper can be reached
+    pub(crate) async fn check_swapper(&self) -> ReceiveSwapResult<()> {
+        self.taproot.check_swapper().await
+    }
+
+    /// Derives the refund keys of the swaps, see [crate::BreezServices::export_swap_refund_keys]
+    pub(crate) async fn export_refund_keys(
+        &self,
+        include_gap_keys: bool,
+    ) -> ReceiveSwapResult<Vec<SwapRefundKey>> {
+        let secp = Secp256k1::new();
+        let indexes = match include_gap_keys {
+            true => {
+                let end =
+                    self.swap_storage.next_swap_refund_key_index()? + SWAP_REFUND_KEY_GAP_LIMIT;
+                (0..end).collect()
+            }
+            false => self.swap_storage.list_swap_refund_key_indexes()?,
+        };
+        let mut keys = vec![];
+        for index in indexes {
+            let private_key = derive_swap_refund_key(self.node_api.as_ref(), index).await?;
+            keys.push(SwapRefundKey {
+                index,
+                derivation_path: swap_refund_key_path(index),
+                private_key: private_key.secret_bytes().to_vec(),
+                public_key: PublicKey::from_secret_key(&secp, &private_key)
+                    .serialize()
+                    .to_vec(),
+            });
+        }
+        Ok(keys)
+    }
+
     pub(crate) fn list_swaps(&self, req: ListSwapsRequest) -> ReceiveSwapResult<Vec<SwapInfo>> {
         Ok(self.swap_storage.list_swaps(req)?)
     }
@@ -330,7 +440,6 @@ impl BTCReceiveSwap {
         Ok(result)
     }
 
-    #[allow(dead_code)]
     pub(crate) fn list_redeemables(&self) -> ReceiveSwapResult<Vec<SwapInfo>> {
         Ok(self.swap_storage.list_swaps(ListSwapsRequest {
             status: Some(SwapStatus::redeemable()),
@@ -456,12 +565,18 @@ impl BTCReceiveSwap {
 
         let weight = tx.weight();
         let fee = compute_tx_fee(weight, req.sat_per_vbyte);
+        let fee_previews = match req.include_fee_previews.unwrap_or(false) {
+            true => refund_fee_previews(weight, &self.chain_service.recommended_fees().await?),
+            false => vec![],
+        };
         Ok(PrepareRefundResponse {
             refund_tx_weight: weight as u32,
-            refund_tx_fee_sat: fee,
+            refund_tx_fee_sat: Sat(fee),
+            fee_previews,
         })
     }
 
+    #[tracing::instrument(skip_all, fields(swap_address = %req.swap_address))]
     pub(crate) async fn refund(&self, req: RefundRequest) -> ReceiveSwapResult<RefundResponse> {
         let current_tip = self.chain_service.current_tip().await?;
         let address_type = parse_address(&req.swap_address)?;
@@ -553,6 +668,64 @@ impl BTCReceiveSwap {
         })
     }
 
+    /// Replaces the unconfirmed refund transaction `txid` by one paying `sat_per_vbyte` to the
+    /// same address
+    pub(crate) async fn bump_refund_fee(
+        &self,
+        txid: &str,
+        sat_per_vbyte: u32,
+    ) -> ReceiveSwapResult<RefundResponse> {
+        let swap_info = self
+            .list_swaps(ListSwapsRequest::default())?
+            .into_iter()
+            .find(|s| s.refund_tx_ids.iter().any(|id| id == txid))
+            .ok_or(ReceiveSwapError::Generic(format!(
+                "No sweep or refund transaction {txid} found"
+            )))?;
+        let tx = self
+            .chain_service
+            .address_transactions(swap_info.bitcoin_address.clone())
+            .await?
+            .into_iter()
+            .find(|tx| tx.txid == txid)
+            .ok_or(ReceiveSwapError::generic(
+                "The refund transaction was not found on-chain",
+            ))?;
+        ensure_sdk!(
+            !tx.status.confirmed,
+            ReceiveSwapError::generic("The refund transaction is already confirmed")
+        );
+        let current_sat_per_vbyte =
+            tx.fee as u64 * WITNESS_SCALE_FACTOR as u64 / (tx.weight as u64).max(1);
+        ensure_sdk!(
+            sat_per_vbyte as u64 > current_sat_per_vbyte,
+            ReceiveSwapError::Generic(format!(
+                "The fee rate must be higher than the current {current_sat_per_vbyte} sat/vbyte"
+            ))
+        );
+
+        // The refund pays a single output, and only a unilateral refund has a script path witness
+        let to_address = tx
+            .vout
+            .first()
+            .map(|vout| vout.scriptpubkey_address.clone())
+            .ok_or(ReceiveSwapError::generic(
+                "The refund transaction has no output",
+            ))?;
+        let unilateral = tx
+            .vin
+            .iter()
+            .any(|vin| vin.witness.as_ref().is_some_and(|w| w.len() > 1));
+        self.refund(RefundRequest {
+            swap_address: swap_info.bitcoin_address,
+            to_address,
+            sat_per_vbyte,
+            unilateral: Some(unilateral),
+        })
+        .await
+    }
+
+    #[tracing::instrument(skip(self))]
     pub(crate) async fn redeem_swap(&self, address: String) -> ReceiveSwapResult<()> {
         let swap_info = self
             .swap_storage
@@ -594,6 +767,31 @@ impl BTCReceiveSwap {
         Err(ReceiveSwapError::PaymentError(message))
     }
 
+    /// Recomputes the swap address from the stored keys and payment hash, and checks whether it
+    /// matches the given address. The stored public key and payment hash are checked against the
+    /// stored private key and preimage as well.
+    pub(crate) fn verify_swap_address(&self, address: &str) -> ReceiveSwapResult<bool> {
+        let swap_info = self
+            .swap_storage
+            .get_swap_info_by_address(address)?
+            .ok_or(ReceiveSwapError::SwapNotFound(address.to_string()))?;
+        let keys = SwapKeys {
+            priv_key: swap_info.private_key.clone(),
+            preimage: swap_info.preimage.clone(),
+        };
+        if keys.public_key()?.serialize().to_vec() != swap_info.public_key
+            || keys.preimage_hash_bytes() != swap_info.payment_hash
+        {
+            return Ok(false);
+        }
+
+        let expected_address = match parse_address(address)? {
+            SwapAddressType::Segwit => self.segwit.swap_address(&swap_info)?,
+            SwapAddressType::Taproot => self.taproot.swap_address(&swap_info)?,
+        };
+        Ok(expected_address == address)
+    }
+
     pub(crate) async fn rescan_monitored_swaps(&self, tip: u32) -> ReceiveSwapResult<()> {
         self.refresh_swaps(
             self.swap_storage.list_swaps(ListSwapsRequest {
@@ -613,6 +811,7 @@ impl BTCReceiveSwap {
         self.refresh_swaps(vec![swap], tip).await
     }
 
+    #[tracing::instrument(skip(self))]
     pub(crate) async fn rescan_swaps(&self, tip: u32) -> ReceiveSwapResult<()> {
         self.refresh_swaps(
             self.swap_storage.list_swaps(ListSwapsRequest::default())?,
@@ -621,6 +820,18 @@ impl BTCReceiveSwap {
         .await
     }
 
+    /// Processes the pending swaps at the current chain tip, without waiting for the next block.
+    ///
+    /// The deposits are tracked from the mempool to their confirmation, with a
+    /// [BreezEvent::SwapUpdated] on each status change. The redeemable swaps are redeemed, again
+    /// if their last attempt failed, and the expired swaps are refunded if
+    /// [crate::Config::swap_auto_refund] is set.
+    pub(crate) async fn monitor_swaps(&self) -> ReceiveSwapResult<()> {
+        let tip = self.chain_service.current_tip().await?;
+        self.set_tip(tip).await;
+        self.execute_pending_swaps(tip).await
+    }
+
     pub(crate) fn subscribe_status_changes(&self) -> broadcast::Receiver<BreezEvent> {
         self.status_changes_notifier.subscribe()
     }
@@ -801,7 +1012,7 @@ impl BTCReceiveSwap {
         let amount_msat = bolt11_result
             .payer_amount_msat
             .unwrap_or(invoice_amount_msat);
-        if amount_msat != swap_info.confirmed_sats * 1000 {
+        if amount_msat != Sat(swap_info.confirmed_sats).to_msat().msat() {
             debug!("Existing swap payment request amount is no longer correct. Recreating payment request.");
             self.node_api.delete_invoice(bolt11_result.bolt11).await?;
             return Ok(None);
@@ -864,6 +1075,7 @@ impl BTCReceiveSwap {
     }
 
     async fn execute_pending_swaps(&self, tip: u32) -> ReceiveSwapResult<()> {
+        let _pending_swaps_guard = self.pending_swaps_lock.lock().await;
         let monitored_swaps = self.list_monitored()?;
         debug!("Refreshing {} monitored swaps", monitored_swaps.len());
 
@@ -883,6 +1095,86 @@ impl BTCReceiveSwap {
             }
         }
 
+        if let Some(auto_refund) = &self.auto_refund {
+            self.refund_swaps(auto_refund).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Refunds the refundable swaps that have no refund transaction yet. A swap that fails to be
+    /// refunded doesn't prevent the others from being refunded.
+    async fn refund_swaps(&self, config: &AutoRefundConfig) -> ReceiveSwapResult<()> {
+        if let Some(to_address) = &config.to_address {
+            let address = to_address
+                .parse::<Address>()
+                .map_err(|e| ReceiveSwapError::Generic(format!("Invalid refund address: {e}")))?;
+            ensure_sdk!(
+                address.is_valid_for_network(self.network),
+                ReceiveSwapError::Generic(format!(
+                    "Refund address is for {}, but {} is used",
+                    address.network, self.network
+                ))
+            );
+        }
+
+        let refundable_swaps: Vec<SwapInfo> = self
+            .list_refundables()?
+            .into_iter()
+            .filter(|s| s.refund_tx_ids.is_empty())
+            .collect();
+        if refundable_swaps.is_empty() {
+            return Ok(());
+        }
+
+        let sat_per_vbyte = match config.sat_per_vbyte {
+            Some(sat_per_vbyte) => sat_per_vbyte,
+            None => self.chain_service.recommended_fees().await?.hour_fee as u32,
+        };
+        debug!("Refunding {} swaps", refundable_swaps.len());
+        for s in refundable_swaps {
+            let to_address = match &config.to_address {
+                Some(to_address) => to_address.clone(),
+                None => match self.node_api.new_onchain_address().await {
+                    Ok(address) => address,
+                    Err(err) => {
+                        error!(
+                            "failed to get an onchain address to refund swap {}: {err:?}",
+                            s.bitcoin_address
+                        );
+                        continue;
+                    }
+                },
+            };
+            let req = RefundRequest {
+                swap_address: s.bitcoin_address.clone(),
+                to_address: to_address.clone(),
+                sat_per_vbyte,
+                unilateral: None,
+            };
+            match self.refund(req).await {
+                Ok(resp) => {
+                    info!(
+                        "refunded swap {} in tx {}",
+                        s.bitcoin_address, resp.refund_tx_id
+                    );
+                    let details = SwapRefundedDetails {
+                        swap_address: s.bitcoin_address,
+                        refund_tx_id: resp.refund_tx_id,
+                        to_address,
+                        sat_per_vbyte,
+                    };
+                    if let Err(e) = self
+                        .status_changes_notifier
+                        .send(BreezEvent::SwapRefunded { details })
+                    {
+                        warn!("Failed to emit the swap refunded event: {e}");
+                    }
+                }
+                Err(err) => error!("failed to refund swap {}: {err:?}", s.bitcoin_address),
+            }
+        }
+
         Ok(())
     }
 
@@ -995,7 +1287,7 @@ impl BTCReceiveSwap {
             }
         };
 
-        let amount_msat = swap_info.confirmed_sats * 1000;
+        let amount_msat = Sat(swap_info.confirmed_sats).to_msat();
         // Note that if the accepted opening fee params is no longer valid, a new one will be issued by the
         // receive_payment function. It is checked in the response.
         let receive_resp = self
@@ -1009,13 +1301,16 @@ impl BTCReceiveSwap {
                 opening_fee_params,
                 preimage: Some(swap_info.preimage.clone()),
                 use_description_hash: None,
+                include_fallback_address: None,
+                max_route_hints: None,
+                fiat_amount: None,
             })
             .await;
         match receive_resp {
             Ok(resp) => {
                 if let Some(opening_fee_params) = resp.opening_fee_params {
-                    if opening_fee_params.get_channel_fees_msat_for(amount_msat)
-                        > accepted_opening_fee_params.get_channel_fees_msat_for(amount_msat)
+                    if opening_fee_params.get_channel_fees_msat_for(amount_msat.msat())
+                        > accepted_opening_fee_params.get_channel_fees_msat_for(amount_msat.msat())
                     {
                         return Err(GetPaymentRequestError::NeedsNewFeeParams);
                     }
@@ -1177,6 +1472,25 @@ pub(super) fn compute_tx_fee(tx_weight: usize, sat_per_vbyte: u32) -> u64 {
     (tx_weight as u64 * sat_per_vbyte as u64).div_ceil(WITNESS_SCALE_FACTOR as u64)
 }
 
+/// The fees of a refund transaction of `tx_weight` at each [FeeratePreset], the cheapest first
+fn refund_fee_previews(tx_weight: usize, fees: &RecommendedFees) -> Vec<RefundFeePreview> {
+    [
+        FeeratePreset::Economy,
+        FeeratePreset::Regular,
+        FeeratePreset::Priority,
+    ]
+    .into_iter()
+    .map(|preset| {
+        let sat_per_vbyte = fees.feerate(preset) as u32;
+        RefundFeePreview {
+            preset,
+            sat_per_vbyte,
+            refund_tx_fee_sat: compute_tx_fee(tx_weight, sat_per_vbyte),
+        }
+    })
+    .collect()
+}
+
 fn refundable_utxos(
     swap_info: &SwapInfo,
     chain_data: &SwapChainData,
@@ -1214,7 +1528,7 @@ fn unpaid_utxos(swap_info: &SwapInfo, chain_data: &SwapChainData) -> Vec<SwapOut
                 return false;
             }
 
-            sum += o.amount_sat * 1000;
+            sum += Sat(o.amount_sat).to_msat().msat();
             true
         })
         .collect();
@@ -1241,31 +1555,66 @@ fn validate_swap_limits(swap_info: &SwapInfo) -> ReceiveSwapResult<()> {
 #[cfg(test)]
 mod tests {
 
-    use std::sync::Arc;
+    use std::{
+        collections::HashMap,
+        sync::Arc,
+        time::{SystemTime, UNIX_EPOCH},
+    };
 
-    use gl_client::bitcoin::Network;
+    use gl_client::bitcoin::{Address, Network};
     use mockall::predicate;
+    use tokio::sync::broadcast;
 
     use crate::{
-        chain::{OnchainTx, TxStatus, Vin, Vout},
+        breez_services::SwapRefundedDetails,
+        chain::{OnchainTx, RecommendedFees, TxStatus, Vin, Vout},
         persist::{
             cache::MockNodeStateStorage, swap::MockSwapStorage, transactions::MockPaymentStorage,
         },
         swap_in::{
-            swap::{compute_tx_fee, SwapOutput, SwapSpend},
+            segwit::create_submarine_swap_script,
+            swap::{compute_tx_fee, create_swap_keys, refund_fee_previews, SwapOutput, SwapSpend},
             taproot_server::MockTaprootSwapperAPI,
             BTCReceiveSwap, BTCReceiveSwapParameters,
         },
         test_utils::{
-            MockBreezServer, MockChainService, MockNodeAPI, MockReceiver, MockSwapperAPI,
+            create_test_config, create_test_persister, get_test_ofp_48h, MockBreezServer,
+            MockChainService, MockNodeAPI, MockReceiver, MockSwapperAPI,
         },
-        ListSwapsRequest, NodeState, OpeningFeeParams, Payment, SwapInfo, SwapStatus,
+        AutoRefundConfig, BreezEvent, FeeratePreset, ListSwapsRequest, NodeState, OpeningFeeParams,
+        Payment, SwapInfo, SwapStatus, SwapperAPI,
     };
 
     use super::SwapChainData;
     const TAPROOT_ADDRESS: &str = "bc1puvuup6jctnk0v3e3qhvr69c73e8a90vytw06hfyn8ahhvf0l63hqd30tzd";
     const SEGWIT_ADDRESS: &str = "bc1qzz9e7e9xukxf4e3v22h3q59penyqj8lzslg5xm8txx3k2r6rpffsg25nny";
 
+    #[test]
+    fn test_refund_fee_previews() {
+        let fees = RecommendedFees {
+            fastest_fee: 30,
+            half_hour_fee: 20,
+            hour_fee: 15,
+            economy_fee: 10,
+            minimum_fee: 1,
+        };
+        let previews = refund_fee_previews(1000, &fees);
+        let presets: Vec<FeeratePreset> = previews.iter().map(|p| p.preset).collect();
+        assert_eq!(
+            presets,
+            vec![
+                FeeratePreset::Economy,
+                FeeratePreset::Regular,
+                FeeratePreset::Priority
+            ]
+        );
+        let fees: Vec<(u32, u64)> = previews
+            .iter()
+            .map(|p| (p.sat_per_vbyte, p.refund_tx_fee_sat))
+            .collect();
+        assert_eq!(fees, vec![(10, 2500), (20, 5000), (30, 7500)]);
+    }
+
     #[test]
     fn test_compute_tx_fee() {
         let tx_weight = 1000;
@@ -1281,6 +1630,82 @@ mod tests {
         assert_eq!(fee, expected_fee);
     }
 
+    #[test]
+    fn test_verify_swap_address() -> anyhow::Result<()> {
+        let keys = create_swap_keys()?;
+        let swapper_keys = create_swap_keys()?;
+        let mut swap_info = SwapInfo {
+            payment_hash: keys.preimage_hash_bytes(),
+            preimage: keys.preimage.clone(),
+            private_key: keys.priv_key.clone(),
+            public_key: keys.public_key()?.serialize().to_vec(),
+            swapper_public_key: swapper_keys.public_key()?.serialize().to_vec(),
+            lock_height: 144,
+            ..Default::default()
+        };
+        let script = create_submarine_swap_script(
+            &swap_info.payment_hash,
+            &swap_info.swapper_public_key,
+            &swap_info.public_key,
+            swap_info.lock_height,
+        )?;
+        let address = Address::p2wsh(&script, Network::Bitcoin).to_string();
+        swap_info.bitcoin_address = address.clone();
+
+        let verify_on = |network: Network, swap_info: SwapInfo, address: &str| {
+            let mut swap_storage = MockSwapStorage::new();
+            swap_storage
+                .expect_get_swap_info_by_address()
+                .return_once(move |_| Ok(Some(swap_info)));
+            let swap = BTCReceiveSwap::new(BTCReceiveSwapParameters {
+                chain_service: Arc::new(MockChainService::default()),
+                payment_storage: Arc::new(MockPaymentStorage::new()),
+                network,
+                node_api: Arc::new(MockNodeAPI::new(NodeState::default())),
+                node_state_storage: Arc::new(MockNodeStateStorage::new()),
+                payment_receiver: Arc::new(MockReceiver::default()),
+                segwit_swapper_api: Arc::new(MockSwapperAPI {}),
+                swap_storage: Arc::new(swap_storage),
+                taproot_swapper_api: Arc::new(MockTaprootSwapperAPI::new()),
+                auto_refund: None,
+            });
+            swap.verify_swap_address(address)
+        };
+        let verify =
+            |swap_info: SwapInfo, address: &str| verify_on(Network::Bitcoin, swap_info, address);
+
+        assert!(verify(swap_info.clone(), &address)?);
+
+        // Signet swap addresses use the testnet prefix
+        let signet_address = Address::p2wsh(&script, Network::Signet).to_string();
+        assert!(signet_address.starts_with("tb1"));
+        let mut signet_swap_info = swap_info.clone();
+        signet_swap_info.bitcoin_address = signet_address.clone();
+        assert!(verify_on(
+            Network::Signet,
+            signet_swap_info.clone(),
+            &signet_address
+        )?);
+        assert!(!verify_on(
+            Network::Bitcoin,
+            signet_swap_info,
+            &signet_address
+        )?);
+        // An address of the same type that wasn't derived from the swap keys
+        assert!(!verify(swap_info.clone(), SEGWIT_ADDRESS)?);
+
+        // The stored swapper key was substituted
+        let mut tampered = swap_info.clone();
+        tampered.swapper_public_key = keys.public_key()?.serialize().to_vec();
+        assert!(!verify(tampered, &address)?);
+
+        // The stored public key doesn't belong to the private key
+        let mut tampered = swap_info;
+        tampered.public_key = swapper_keys.public_key()?.serialize().to_vec();
+        assert!(!verify(tampered, &address)?);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_create_swap_uses_unused_taproot_swap() {
         let mut swap_storage = MockSwapStorage::new();
@@ -1326,6 +1751,7 @@ mod tests {
             segwit_swapper_api: Arc::new(MockSwapperAPI {}),
             swap_storage: Arc::new(swap_storage),
             taproot_swapper_api: Arc::new(MockTaprootSwapperAPI::new()),
+            auto_refund: None,
         });
 
         let opening_fee_params = OpeningFeeParams {
@@ -1387,6 +1813,7 @@ mod tests {
             segwit_swapper_api: Arc::new(MockSwapperAPI {}),
             swap_storage: Arc::new(swap_storage),
             taproot_swapper_api: Arc::new(MockTaprootSwapperAPI::new()),
+            auto_refund: None,
         });
 
         let opening_fee_params = OpeningFeeParams {
@@ -1420,9 +1847,13 @@ mod tests {
             .expect_list_swaps()
             .return_once(move |_| Ok(vec![]));
 
+        swap_storage
+            .expect_next_swap_refund_key_index()
+            .return_once(|| Ok(0));
+
         swap_storage
             .expect_insert_swap()
-            .return_once(move |_| Ok(()));
+            .return_once(move |_, _| Ok(()));
 
         // Setup server
 
@@ -1436,6 +1867,7 @@ mod tests {
             segwit_swapper_api: Arc::new(MockSwapperAPI {}),
             swap_storage: Arc::new(swap_storage),
             taproot_swapper_api: Arc::new(MockBreezServer {}),
+            auto_refund: None,
         });
 
         let opening_fee_params = OpeningFeeParams {
@@ -1448,6 +1880,337 @@ mod tests {
         assert_eq!(result.max_allowed_deposit, 100_000);
     }
 
+    const WALLET_ADDRESS: &str = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh";
+
+    /// A segwit swap whose single deposit passed its timelock
+    fn refundable_swap() -> anyhow::Result<(SwapInfo, SwapChainData)> {
+        let keys = create_swap_keys()?;
+        let swapper_keys = create_swap_keys()?;
+        let mut swap_info = SwapInfo {
+            payment_hash: keys.preimage_hash_bytes(),
+            preimage: keys.preimage.clone(),
+            private_key: keys.priv_key.clone(),
+            public_key: keys.public_key()?.serialize().to_vec(),
+            swapper_public_key: swapper_keys.public_key()?.serialize().to_vec(),
+            lock_height: 144,
+            status: SwapStatus::Refundable,
+            ..Default::default()
+        };
+        let script = create_submarine_swap_script(
+            &swap_info.payment_hash,
+            &swap_info.swapper_public_key,
+            &swap_info.public_key,
+            swap_info.lock_height,
+        )?;
+        swap_info.bitcoin_address = Address::p2wsh(&script, Network::Bitcoin).to_string();
+        let chain_data = SwapChainData {
+            outputs: vec![SwapOutput {
+                address: swap_info.bitcoin_address.clone(),
+                tx_id: "a418e856bb22b6345868dc0b1ac1dd7a6b7fae1d231b275b74172f9584fa0bdf"
+                    .to_string(),
+                amount_sat: 100_000,
+                confirmed_at_height: Some(1),
+                ..Default::default()
+            }],
+        };
+        Ok((swap_info, chain_data))
+    }
+
+    fn auto_refund_swapper(
+        swaps: Vec<(SwapInfo, SwapChainData)>,
+        chain_service: MockChainService,
+        node_api: MockNodeAPI,
+    ) -> BTCReceiveSwap {
+        let swap_infos: Vec<SwapInfo> = swaps.iter().map(|(s, _)| s.clone()).collect();
+        let find = move |address: &str| {
+            swaps
+                .iter()
+                .find(|(s, _)| s.bitcoin_address == address)
+                .cloned()
+        };
+        let find_chain_data = find.clone();
+        let mut swap_storage = MockSwapStorage::new();
+        swap_storage
+            .expect_list_swaps()
+            .returning(move |_| Ok(swap_infos.clone()));
+        swap_storage
+            .expect_get_swap_info_by_address()
+            .returning(move |address| Ok(find(address).map(|(s, _)| s)));
+        swap_storage
+            .expect_get_swap_chain_data()
+            .returning(move |address| Ok(find_chain_data(address).map(|(_, c)| c)));
+        swap_storage
+            .expect_insert_swap_refund_tx_ids()
+            .returning(|_, _| Ok(()));
+
+        BTCReceiveSwap::new(BTCReceiveSwapParameters {
+            chain_service: Arc::new(chain_service),
+            payment_storage: Arc::new(MockPaymentStorage::new()),
+            network: Network::Bitcoin,
+            node_api: Arc::new(node_api),
+            node_state_storage: Arc::new(MockNodeStateStorage::new()),
+            payment_receiver: Arc::new(MockReceiver::default()),
+            segwit_swapper_api: Arc::new(MockSwapperAPI {}),
+            swap_storage: Arc::new(swap_storage),
+            taproot_swapper_api: Arc::new(MockBreezServer {}),
+            auto_refund: None,
+        })
+    }
+
+    fn refunded_swaps(receiver: &mut broadcast::Receiver<BreezEvent>) -> Vec<SwapRefundedDetails> {
+        let mut refunded = vec![];
+        while let Ok(event) = receiver.try_recv() {
+            if let BreezEvent::SwapRefunded { details } = event {
+                refunded.push(details);
+            }
+        }
+        refunded
+    }
+
+    #[tokio::test]
+    async fn test_refund_swaps() -> anyhow::Result<()> {
+        let (swap, chain_data) = refundable_swap()?;
+        // A swap that was already refunded is left alone
+        let (refunded_swap, refunded_chain_data) = refundable_swap()?;
+        let refunded_swap = SwapInfo {
+            refund_tx_ids: vec!["tx".to_string()],
+            ..refunded_swap
+        };
+        let swapper = auto_refund_swapper(
+            vec![
+                (swap.clone(), chain_data),
+                (refunded_swap, refunded_chain_data),
+            ],
+            MockChainService::default(),
+            MockNodeAPI::new(NodeState::default()),
+        );
+        let mut receiver = swapper.subscribe_status_changes();
+
+        swapper
+            .refund_swaps(&AutoRefundConfig {
+                to_address: Some(SEGWIT_ADDRESS.to_string()),
+                sat_per_vbyte: Some(5),
+            })
+            .await?;
+        let refunded = refunded_swaps(&mut receiver);
+        assert_eq!(refunded.len(), 1);
+        assert_eq!(refunded[0].swap_address, swap.bitcoin_address);
+        assert_eq!(refunded[0].to_address, SEGWIT_ADDRESS);
+        assert_eq!(refunded[0].sat_per_vbyte, 5);
+        assert!(!refunded[0].refund_tx_id.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refund_swaps_recommended_fee() -> anyhow::Result<()> {
+        let mut chain_service = MockChainService::default();
+        chain_service.recommended_fees.hour_fee = 7;
+        let swapper = auto_refund_swapper(
+            vec![refundable_swap()?],
+            chain_service,
+            MockNodeAPI::new(NodeState::default()),
+        );
+        let mut receiver = swapper.subscribe_status_changes();
+
+        // Without an address nor a fee rate, the swap is refunded to the wallet at the hour fee
+        swapper
+            .refund_swaps(&AutoRefundConfig {
+                to_address: None,
+                sat_per_vbyte: None,
+            })
+            .await?;
+        let refunded = refunded_swaps(&mut receiver);
+        assert_eq!(refunded.len(), 1);
+        assert_eq!(refunded[0].to_address, WALLET_ADDRESS);
+        assert_eq!(refunded[0].sat_per_vbyte, 7);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refund_swaps_address_failures() -> anyhow::Result<()> {
+        let node_api = MockNodeAPI::new(NodeState::default());
+        *node_api.onchain_address_unavailable.lock().unwrap() = true;
+        let swapper = auto_refund_swapper(
+            vec![refundable_swap()?, refundable_swap()?],
+            MockChainService::default(),
+            node_api,
+        );
+        let mut receiver = swapper.subscribe_status_changes();
+
+        // The wallet address can't be derived: each swap is skipped, without failing
+        swapper
+            .refund_swaps(&AutoRefundConfig {
+                to_address: None,
+                sat_per_vbyte: Some(5),
+            })
+            .await?;
+        assert!(refunded_swaps(&mut receiver).is_empty());
+
+        // An address of another network is rejected before refunding anything
+        assert!(swapper
+            .refund_swaps(&AutoRefundConfig {
+                to_address: Some("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string()),
+                sat_per_vbyte: Some(5),
+            })
+            .await
+            .is_err());
+        assert!(swapper
+            .refund_swaps(&AutoRefundConfig {
+                to_address: Some("not an address".to_string()),
+                sat_per_vbyte: Some(5),
+            })
+            .await
+            .is_err());
+        assert!(refunded_swaps(&mut receiver).is_empty());
+
+        // A configured address doesn't need the wallet
+        swapper
+            .refund_swaps(&AutoRefundConfig {
+                to_address: Some(SEGWIT_ADDRESS.to_string()),
+                sat_per_vbyte: Some(5),
+            })
+            .await?;
+        assert_eq!(refunded_swaps(&mut receiver).len(), 2);
+        Ok(())
+    }
+
+    /// A swapper failing to complete the first `failures` swaps
+    struct FlakySwapperAPI {
+        failures: std::sync::Mutex<u32>,
+        calls: std::sync::Mutex<u32>,
+    }
+
+    #[tonic::async_trait]
+    impl SwapperAPI for FlakySwapperAPI {
+        async fn complete_swap(&self, _bolt11: String) -> anyhow::Result<()> {
+            *self.calls.lock().unwrap() += 1;
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(anyhow::anyhow!("LSP unavailable"));
+            }
+            Ok(())
+        }
+    }
+
+    fn swap_updates(receiver: &mut broadcast::Receiver<BreezEvent>) -> Vec<SwapStatus> {
+        let mut statuses = vec![];
+        while let Ok(event) = receiver.try_recv() {
+            if let BreezEvent::SwapUpdated { details } = event {
+                statuses.push(details.status);
+            }
+        }
+        statuses
+    }
+
+    #[tokio::test]
+    async fn test_monitor_swaps() -> anyhow::Result<()> {
+        let chain_service = Arc::new(std::sync::Mutex::new(MockChainService {
+            tip: 1_000,
+            address_to_transactions: HashMap::new(),
+            ..Default::default()
+        }));
+        let persister = Arc::new(create_test_persister(create_test_config()));
+        persister.init()?;
+        let swapper_api = Arc::new(FlakySwapperAPI {
+            failures: std::sync::Mutex::new(1),
+            calls: std::sync::Mutex::new(0),
+        });
+        let swapper = BTCReceiveSwap::new(BTCReceiveSwapParameters {
+            chain_service: chain_service.clone(),
+            payment_storage: persister.clone(),
+            network: Network::Bitcoin,
+            node_api: Arc::new(MockNodeAPI::new(NodeState::default())),
+            node_state_storage: persister.clone(),
+            payment_receiver: Arc::new(MockReceiver::default()),
+            segwit_swapper_api: swapper_api.clone(),
+            swap_storage: persister.clone(),
+            taproot_swapper_api: Arc::new(MockBreezServer {}),
+            auto_refund: Some(AutoRefundConfig {
+                to_address: Some(SEGWIT_ADDRESS.to_string()),
+                sat_per_vbyte: Some(5),
+            }),
+        });
+        let mut receiver = swapper.subscribe_status_changes();
+
+        let (swap, _) = refundable_swap()?;
+        let swap = SwapInfo {
+            status: SwapStatus::Initial,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64,
+            min_allowed_deposit: 1_000,
+            max_allowed_deposit: 1_000_000,
+            channel_opening_fees: Some(get_test_ofp_48h(1, 1).into()),
+            ..swap
+        };
+        persister.insert_swap(&swap, None)?;
+        let address = swap.bitcoin_address.clone();
+        let swap_info = || -> anyhow::Result<SwapInfo> {
+            Ok(persister.get_swap_info_by_address(&address)?.unwrap())
+        };
+        let set_deposit = |confirmed_at_height: Option<u32>| {
+            let deposit = OnchainTx {
+                vout: vec![Vout {
+                    scriptpubkey_address: address.clone(),
+                    value: 100_000,
+                    ..Default::default()
+                }],
+                status: TxStatus {
+                    confirmed: confirmed_at_height.is_some(),
+                    block_height: confirmed_at_height,
+                    block_hash: confirmed_at_height.map(|_| "hash".to_string()),
+                    ..Default::default()
+                },
+                txid: "a418e856bb22b6345868dc0b1ac1dd7a6b7fae1d231b275b74172f9584fa0bdf"
+                    .to_string(),
+                ..Default::default()
+            };
+            chain_service
+                .lock()
+                .unwrap()
+                .address_to_transactions
+                .insert(address.clone(), vec![deposit]);
+        };
+
+        swapper.monitor_swaps().await?;
+        assert_eq!(swap_info()?.status, SwapStatus::Initial);
+        swap_updates(&mut receiver);
+
+        // The deposit is tracked from the mempool, without waiting for a block
+        set_deposit(None);
+        swapper.monitor_swaps().await?;
+        assert_eq!(swap_info()?.status, SwapStatus::WaitingConfirmation);
+        assert_eq!(
+            swap_updates(&mut receiver),
+            vec![SwapStatus::WaitingConfirmation]
+        );
+
+        // Once confirmed, the swap is redeemed, which fails the first time
+        set_deposit(Some(1_000));
+        swapper.monitor_swaps().await?;
+        let info = swap_info()?;
+        assert_eq!(info.status, SwapStatus::Redeemable);
+        assert_eq!(info.confirmed_sats, 100_000);
+        assert!(info.last_redeem_error.is_some());
+        assert!(swap_updates(&mut receiver).contains(&SwapStatus::Redeemable));
+        assert_eq!(*swapper_api.calls.lock().unwrap(), 1);
+
+        // The failed redeem is retried on the next run
+        swapper.monitor_swaps().await?;
+        assert_eq!(*swapper_api.calls.lock().unwrap(), 2);
+
+        // Once expired, the swap is refunded, once
+        chain_service.lock().unwrap().tip = 1_144;
+        swapper.monitor_swaps().await?;
+        let info = swap_info()?;
+        assert_eq!(info.status, SwapStatus::Refundable);
+        assert_eq!(info.refund_tx_ids.len(), 1);
+        assert!(swap_updates(&mut receiver).contains(&SwapStatus::Refundable));
+        swapper.monitor_swaps().await?;
+        assert_eq!(swap_info()?.refund_tx_ids.len(), 1);
+        assert_eq!(*swapper_api.calls.lock().unwrap(), 2);
+        Ok(())
+    }
+
     async fn test_swap_state_transition(
         swap_info: &SwapInfo,
         chain_data: &SwapChainData,
@@ -1546,6 +2309,7 @@ mod tests {
             segwit_swapper_api: Arc::new(MockSwapperAPI {}),
             swap_storage: Arc::new(swap_storage),
             taproot_swapper_api: Arc::new(MockBreezServer {}),
+            auto_refund: None,
         });
         let _receiver = swapper.subscribe_status_changes();
 
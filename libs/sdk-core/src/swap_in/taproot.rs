@@ -26,11 +26,11 @@ use secp256k1::musig::{
     MusigSession,
 };
 
-use crate::{NodeState, OpeningFeeParams, SwapInfo, SwapStatus};
+use crate::{MilliSat, NodeState, OpeningFeeParams, SwapInfo, SwapStatus};
 
 use super::{
     error::{ReceiveSwapError, ReceiveSwapResult},
-    swap::{compute_tx_fee, create_swap_keys, SwapOutput},
+    swap::{compute_tx_fee, SwapKeys, SwapOutput},
     taproot_server::TaprootSwapperAPI,
 };
 
@@ -60,8 +60,8 @@ impl TaprootReceiveSwap {
         &self,
         node_state: &NodeState,
         opening_fee_params: OpeningFeeParams,
+        keys: SwapKeys,
     ) -> ReceiveSwapResult<SwapInfo> {
-        let keys = create_swap_keys()?;
         let refund_pubkey = keys.public_key()?;
         let payment_hash = keys.preimage_hash_bytes();
         let resp = self
@@ -71,19 +71,11 @@ impl TaprootReceiveSwap {
 
         let claim_pubkey = PublicKey::from_slice(&resp.claim_pubkey)
             .map_err(|_| ReceiveSwapError::generic("Received invalid claim pubkey from server"))?;
-        let (x_only_claim_pubkey, _) = claim_pubkey.x_only_public_key();
         let (x_only_refund_pubkey, _) = refund_pubkey.x_only_public_key();
-        let claim_script = claim_script(&x_only_claim_pubkey, &payment_hash);
         let refund_script = refund_script(&x_only_refund_pubkey, resp.lock_time);
 
-        let taproot_spend_info = self.taproot_spend_info(
-            &claim_pubkey.serialize(),
-            &refund_pubkey.serialize(),
-            claim_script,
-            refund_script.clone(),
-        )?;
         let expected_address =
-            Address::p2tr_tweaked(taproot_spend_info.output_key(), self.network).to_string();
+            self.address(&claim_pubkey, &refund_pubkey, &payment_hash, resp.lock_time)?;
         if resp.address != expected_address {
             return Err(ReceiveSwapError::generic(
                 "Received invalid taproot swap address from server",
@@ -110,7 +102,9 @@ impl TaprootReceiveSwap {
             last_redeem_error: None,
             lock_height: resp.lock_time as i64,
             max_allowed_deposit: std::cmp::min(
-                node_state.max_receivable_msat / 1000,
+                MilliSat(node_state.max_receivable_msat)
+                    .to_sat_floor()
+                    .sat(),
                 parameters.max_swap_amount_sat,
             ) as i64,
             max_swapper_payable: parameters.max_swap_amount_sat as i64,
@@ -132,6 +126,22 @@ impl TaprootReceiveSwap {
         Ok(swap_info)
     }
 
+    /// Checks that the swapper can be reached, by fetching its swap parameters
+    pub async fn check_swapper(&self) -> ReceiveSwapResult<()> {
+        self.swapper_api.swap_parameters().await?;
+        Ok(())
+    }
+
+    /// Computes the swap address from the keys, payment hash and lock time of the swap
+    pub fn swap_address(&self, swap_info: &SwapInfo) -> ReceiveSwapResult<String> {
+        self.address(
+            &PublicKey::from_slice(&swap_info.swapper_public_key)?,
+            &PublicKey::from_slice(&swap_info.public_key)?,
+            &swap_info.payment_hash,
+            swap_info.lock_height as u32,
+        )
+    }
+
     pub fn payout_blocks_left(
         &self,
         swap_info: &SwapInfo,
@@ -464,6 +474,24 @@ impl TaprootReceiveSwap {
         Ok(MusigKeyAggCache::new(&self.musig_secp, &[&cp, &rp]))
     }
 
+    fn address(
+        &self,
+        claim_pubkey: &PublicKey,
+        refund_pubkey: &PublicKey,
+        payment_hash: &[u8],
+        lock_time: u32,
+    ) -> ReceiveSwapResult<String> {
+        let (x_only_claim_pubkey, _) = claim_pubkey.x_only_public_key();
+        let (x_only_refund_pubkey, _) = refund_pubkey.x_only_public_key();
+        let taproot_spend_info = self.taproot_spend_info(
+            &claim_pubkey.serialize(),
+            &refund_pubkey.serialize(),
+            claim_script(&x_only_claim_pubkey, payment_hash),
+            refund_script(&x_only_refund_pubkey, lock_time),
+        )?;
+        Ok(Address::p2tr_tweaked(taproot_spend_info.output_key(), self.network).to_string())
+    }
+
     fn taproot_spend_info(
         &self,
         claim_pubkey: &[u8],
@@ -1,25 +1,44 @@
 use std::collections::HashMap;
+#[cfg(feature = "reverse-swaps")]
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "reverse-swaps")]
 use serde_json::to_string_pretty;
 
+#[cfg(feature = "reverse-swaps")]
 use const_format::concatcp;
+#[cfg(feature = "reverse-swaps")]
 use sdk_common::prelude::*;
+#[cfg(feature = "reverse-swaps")]
 use serde_json::json;
 
 use crate::bitcoin::Txid;
+#[cfg(feature = "reverse-swaps")]
 use crate::models::ReverseSwapPairInfo;
 use crate::swap_out::reverseswap::CreateReverseSwapResponse;
-use crate::{ReverseSwapServiceAPI, RouteHint, RouteHintHop};
-
+#[cfg(feature = "reverse-swaps")]
+use crate::swap_out::taproot::BoltzApiPartialSignature;
+use crate::swap_out::taproot::CreateTaprootReverseSwapResponse;
+#[cfg(feature = "reverse-swaps")]
+use crate::ReverseSwapServiceAPI;
+use crate::{RouteHint, RouteHintHop};
+
+#[cfg(feature = "reverse-swaps")]
 use super::error::{ReverseSwapError, ReverseSwapResult};
 
+#[cfg(feature = "reverse-swaps")]
 const BOLTZ_API_URL: &str = "https://api.boltz.exchange/";
+#[cfg(feature = "reverse-swaps")]
 const GET_PAIRS_ENDPOINT: &str = concatcp!(BOLTZ_API_URL, "getpairs");
+#[cfg(feature = "reverse-swaps")]
 const GET_SWAP_STATUS_ENDPOINT: &str = concatcp!(BOLTZ_API_URL, "swapstatus");
+#[cfg(feature = "reverse-swaps")]
 const GET_ROUTE_HINTS_ENDPOINT: &str = concatcp!(BOLTZ_API_URL, "routinghints");
+#[cfg(feature = "reverse-swaps")]
 pub(crate) const CREATE_REVERSE_SWAP_ENDPOINT: &str = concatcp!(BOLTZ_API_URL, "createswap");
+#[cfg(feature = "reverse-swaps")]
+const CREATE_TAPROOT_REVERSE_SWAP_ENDPOINT: &str = concatcp!(BOLTZ_API_URL, "v2/swap/reverse");
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -154,6 +173,16 @@ pub(crate) enum BoltzApiCreateReverseSwapResponse {
     BoltzApiError { error: String },
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub(crate) enum BoltzApiCreateTaprootReverseSwapResponse {
+    /// Success response by the v2 Boltz API, indicating reverse swap was created successfully
+    BoltzApiSuccess(CreateTaprootReverseSwapResponse),
+
+    /// Error response by the v2 Boltz API, indicating there was an issue with creating the reverse swap
+    BoltzApiError { error: String },
+}
+
 /// Details of the lock tx, as reported by the Boltz endpoint
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LockTxData {
@@ -216,10 +245,12 @@ pub enum BoltzApiReverseSwapStatus {
     InvoiceExpired,
 }
 
+#[cfg(feature = "reverse-swaps")]
 pub struct BoltzApi {
     rest_client: Arc<dyn RestClient>,
 }
 
+#[cfg(feature = "reverse-swaps")]
 impl BoltzApi {
     pub fn new(rest_client: Arc<dyn RestClient>) -> Self {
         BoltzApi { rest_client }
@@ -251,6 +282,7 @@ impl BoltzApi {
     }
 }
 
+#[cfg(feature = "reverse-swaps")]
 #[tonic::async_trait]
 impl ReverseSwapServiceAPI for BoltzApi {
     async fn fetch_reverse_swap_fees(&self) -> ReverseSwapResult<ReverseSwapPairInfo> {
@@ -326,6 +358,77 @@ impl ReverseSwapServiceAPI for BoltzApi {
             })
     }
 
+    /// Call the v2 Boltz API and parse response as per https://docs.boltz.exchange/api/v2#reverse-swaps
+    ///
+    /// #### Errors
+    ///
+    /// As for [BoltzApi::create_reverse_swap_on_remote], Boltz API errors are returned as a
+    /// successful response of type [BoltzApiCreateTaprootReverseSwapResponse::BoltzApiError]
+    async fn create_taproot_reverse_swap_on_remote(
+        &self,
+        amount_sat: u64,
+        preimage_hash_hex: String,
+        claim_pubkey: String,
+    ) -> ReverseSwapResult<BoltzApiCreateTaprootReverseSwapResponse> {
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = json!({
+            "from": "BTC",
+            "to": "BTC",
+            "invoiceAmount": amount_sat,
+            "preimageHash": preimage_hash_hex,
+            "claimPublicKey": claim_pubkey
+        })
+        .to_string();
+        self.rest_client
+            .post(CREATE_TAPROOT_REVERSE_SWAP_ENDPOINT, Some(headers), Some(body)).await.map_err(|e| {
+                ReverseSwapError::ServiceConnectivity(format!(
+                    "(Boltz {CREATE_TAPROOT_REVERSE_SWAP_ENDPOINT}) Failed to request creation of reverse swap: {e}"
+                ))
+            })
+            .and_then(|(response, _)| {
+                trace!("Boltz API create taproot raw response {}", to_string_pretty(&response)?);
+                serde_json::from_str::<BoltzApiCreateTaprootReverseSwapResponse>(&response).map_err(|e| {
+                    ReverseSwapError::ServiceConnectivity(format!(
+                        "(Boltz {CREATE_TAPROOT_REVERSE_SWAP_ENDPOINT}) Failed to parse create swap response: {e}"
+                    ))
+                })
+            })
+    }
+
+    async fn get_claim_partial_signature(
+        &self,
+        id: String,
+        index: u32,
+        claim_tx_hex: String,
+        preimage_hex: String,
+        pub_nonce_hex: String,
+    ) -> ReverseSwapResult<BoltzApiPartialSignature> {
+        let url = format!("{CREATE_TAPROOT_REVERSE_SWAP_ENDPOINT}/{id}/claim");
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = json!({
+            "index": index,
+            "transaction": claim_tx_hex,
+            "preimage": preimage_hex,
+            "pubNonce": pub_nonce_hex
+        })
+        .to_string();
+        self.rest_client
+            .post(&url, Some(headers), Some(body))
+            .await
+            .map_err(|e| {
+                ReverseSwapError::ServiceConnectivity(format!(
+                    "(Boltz {url}) Failed to request the claim signature: {e}"
+                ))
+            })
+            .and_then(|(response, _)| {
+                serde_json::from_str::<BoltzApiPartialSignature>(&response).map_err(|e| {
+                    ReverseSwapError::ServiceConnectivity(format!(
+                        "(Boltz {url}) Failed to parse claim signature response: {e}"
+                    ))
+                })
+            })
+    }
+
     async fn get_route_hints(&self, routing_node_id: String) -> ReverseSwapResult<Vec<RouteHint>> {
         let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
         let body = json!({ "routingNode": routing_node_id, "symbol": "BTC" }).to_string();
@@ -352,6 +455,7 @@ impl ReverseSwapServiceAPI for BoltzApi {
     }
 }
 
+#[cfg(feature = "reverse-swaps")]
 fn build_boltz_reverse_swap_args(
     amount_sat: u64,
     preimage_hash_hex: String,
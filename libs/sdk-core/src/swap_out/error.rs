@@ -17,9 +17,28 @@ pub enum ReverseSwapError {
     #[error("Claim tx feerate is too low")]
     ClaimFeerateTooLow,
 
+    #[error("Cancelled")]
+    Cancelled,
+
+    /// Paying the HODL invoice failed, so the reverse swap was cancelled and no funds left the
+    /// wallet
+    #[error("Reverse swap {id} was cancelled, no funds left the wallet: {err}")]
+    PaymentFailed { id: String, err: String },
+
+    /// Paying the HODL invoice didn't complete in time and may still succeed, so the reverse swap
+    /// is still monitored
+    #[error("Reverse swap {id} is pending, the funds are either sent on-chain or returned once the payment settles: {err}")]
+    PaymentPending { id: String, err: String },
+
     #[error("{0}")]
     InvalidDestinationAddress(String),
 
+    #[error("{0}")]
+    DestinationAddressNetworkMismatch(String),
+
+    #[error("{0}")]
+    UnsupportedDestinationAddressType(String),
+
     #[error(transparent)]
     Node(#[from] NodeError),
 
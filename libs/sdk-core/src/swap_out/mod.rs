@@ -1,6 +1,7 @@
 pub(crate) mod boltzswap;
 pub(crate) mod error;
 pub(crate) mod reverseswap;
+pub(crate) mod taproot;
 
 /// Calculate the service fee from the `invoice_amount_sat`.
 ///
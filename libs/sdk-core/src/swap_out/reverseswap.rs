@@ -7,8 +7,12 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 use tokio::time::{sleep, Duration};
 
-use super::boltzswap::{BoltzApiCreateReverseSwapResponse, BoltzApiReverseSwapStatus::*};
+use super::boltzswap::{
+    BoltzApiCreateReverseSwapResponse, BoltzApiCreateTaprootReverseSwapResponse,
+    BoltzApiReverseSwapStatus::*,
+};
 use super::error::{ReverseSwapError, ReverseSwapResult};
+use super::taproot::TaprootReverseSwap;
 use crate::bitcoin::blockdata::constants::WITNESS_SCALE_FACTOR;
 use crate::bitcoin::consensus::serialize;
 use crate::bitcoin::hashes::hex::{FromHex, ToHex};
@@ -17,20 +21,41 @@ use crate::bitcoin::psbt::serialize::Serialize as PsbtSerialize;
 use crate::bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
 use crate::bitcoin::util::sighash::SighashCache;
 use crate::bitcoin::{
-    Address, AddressType, EcdsaSighashType, KeyPair, Network, OutPoint, Script, Sequence,
-    Transaction, TxIn, TxOut, Txid, Witness,
+    Address, AddressType, EcdsaSighashType, KeyPair, Network, OutPoint, PackedLockTime, Script,
+    Sequence, Transaction, TxIn, TxOut, Txid, Witness,
 };
+use crate::cancellation::Cancellation;
 use crate::chain::{get_utxos, AddressUtxos, ChainService, OnchainTx, Utxo};
 use crate::error::SdkResult;
 use crate::models::{ReverseSwapServiceAPI, ReverseSwapperRoutingAPI};
-use crate::node_api::{NodeAPI, NodeError};
+use crate::node_api::NodeAPI;
 use crate::swap_in::create_swap_keys;
 use crate::{
     ensure_sdk, BreezEvent, Config, FullReverseSwapInfo, PayOnchainRequest, PaymentStatus,
-    ReverseSwapInfo, ReverseSwapInfoCached, ReverseSwapPairInfo, ReverseSwapStatus,
-    ReverseSwapStatus::*, RouteHintHop,
+    ReverseSwapInfo, ReverseSwapInfoCached, ReverseSwapPairInfo, ReverseSwapProtocol,
+    ReverseSwapStatus, ReverseSwapStatus::*, RouteHintHop,
 };
 
+/// Checks the checksum and network of the address, and that the claim tx can pay to its script type
+fn validate_recipient_address(address: &str, network: Network) -> ReverseSwapResult<()> {
+    let address = Address::from_str(address)
+        .map_err(|e| ReverseSwapError::InvalidDestinationAddress(e.to_string()))?;
+    ensure_sdk!(
+        address.is_valid_for_network(network),
+        ReverseSwapError::DestinationAddressNetworkMismatch(format!(
+            "Address is for {}, but {network} is used",
+            address.network
+        ))
+    );
+    ensure_sdk!(
+        address.address_type().is_some(),
+        ReverseSwapError::UnsupportedDestinationAddressType(format!(
+            "Address {address} has a non-standard script type"
+        ))
+    );
+    Ok(())
+}
+
 // Estimates based on https://github.com/BoltzExchange/boltz-backend/blob/master/lib/rates/FeeProvider.ts#L31-L42
 pub const ESTIMATED_CLAIM_TX_VSIZE: u64 = 138;
 pub const ESTIMATED_LOCKUP_TX_VSIZE: u64 = 153;
@@ -139,11 +164,9 @@ impl BTCSendSwap {
         }
     }
 
-    /// Validates the reverse swap arguments given by the user
-    fn validate_recipient_address(claim_pubkey: &str) -> ReverseSwapResult<()> {
-        Address::from_str(claim_pubkey)
-            .map(|_| ())
-            .map_err(|e| ReverseSwapError::InvalidDestinationAddress(e.to_string()))
+    /// Validates the recipient address given by the user, before any reverse swap is created
+    pub(crate) fn validate_recipient_address(&self, address: &str) -> ReverseSwapResult<()> {
+        validate_recipient_address(address, self.config.network.into())
     }
 
     pub(crate) fn validate_claim_tx_fee(claim_fee: u64) -> ReverseSwapResult<()> {
@@ -183,100 +206,109 @@ impl BTCSendSwap {
 
     /// Creates and persists a reverse swap. If the initial payment fails, the reverse swap has the new
     /// status persisted.
+    ///
+    /// The reverse swap can be cancelled until the HODL invoice payment starts. The payment attempt
+    /// is bounded by [Config::payment_timeout_sec]:
+    /// - if the payment fails, the swapper never locks up funds and releases the HODL invoice, so
+    ///   the reverse swap is cancelled and [ReverseSwapError::PaymentFailed] is returned
+    /// - if the payment is still in flight when the timeout is reached, the reverse swap is kept
+    ///   monitored and [ReverseSwapError::PaymentPending] is returned. It either advances when the
+    ///   swapper locks up the funds, or is cancelled once the HTLC fails back to the node.
+    #[tracing::instrument(skip_all, fields(reverse_swap_id = tracing::field::Empty))]
     pub(crate) async fn create_reverse_swap(
         &self,
         req: PayOnchainRequest,
+        cancellation: &Cancellation,
     ) -> ReverseSwapResult<FullReverseSwapInfo> {
-        Self::validate_recipient_address(&req.recipient_address)?;
-
-        let routing_node = self
-            .reverse_swapper_api
-            .fetch_reverse_routing_node()
-            .await
-            .map(hex::encode)?;
-        let created_rsi = self
-            .create_and_validate_rev_swap_on_remote(req.clone(), routing_node)
-            .await?;
-
-        // Perform validation on the created swap
-        trace!("create_rev_swap v2 request: {req:?}");
-        trace!("create_rev_swap v2 created_rsi: {created_rsi:?}");
-
-        // Validate send_amount
-        let request_send_amount_sat = req.prepare_res.sender_amount_sat;
-        let request_send_amount_msat = request_send_amount_sat * 1_000;
-        created_rsi.validate_invoice_amount(request_send_amount_msat)?;
-
-        // Validate onchain_amount
-        let lockup_fee_sat = req.prepare_res.fees_lockup;
-        let service_fee_sat = super::get_service_fee_sat(
-            req.prepare_res.sender_amount_sat,
-            req.prepare_res.fees_percentage,
-        );
-        trace!("create_rev_swap v2 service_fee_sat: {service_fee_sat} sat");
-        let expected_onchain_amount = request_send_amount_sat - service_fee_sat - lockup_fee_sat;
-        ensure_sdk!(
-            created_rsi.onchain_amount_sat == expected_onchain_amount,
-            ReverseSwapError::generic("Unexpected onchain amount (lockup fee or service fee)")
-        );
+        self.validate_recipient_address(&req.recipient_address)?;
+
+        let create = async {
+            let routing_node = self
+                .reverse_swapper_api
+                .fetch_reverse_routing_node()
+                .await
+                .map(hex::encode)?;
+            self.create_and_validate_rev_swap_on_remote(req, routing_node)
+                .await
+        };
+        let created_rsi = tokio::select! {
+            created_rsi = create => created_rsi?,
+            _ = cancellation.cancelled() => return Err(ReverseSwapError::Cancelled),
+        };
 
-        // Validate claim_fee. If onchain_amount and claim_fee are both valid, receive_amount is also valid.
-        ensure_sdk!(
-            created_rsi.onchain_amount_sat > req.prepare_res.recipient_amount_sat,
-            ReverseSwapError::generic("Unexpected receive amount")
+        self.persister.insert_reverse_swap(&created_rsi)?;
+        tracing::Span::current().record("reverse_swap_id", created_rsi.id.as_str());
+        info!(
+            "Created and persisted {} reverse swap {}",
+            created_rsi.protocol, created_rsi.id
         );
-        let claim_fee = created_rsi.onchain_amount_sat - req.prepare_res.recipient_amount_sat;
-        Self::validate_claim_tx_fee(claim_fee)?;
 
-        self.persister.insert_reverse_swap(&created_rsi)?;
-        info!("Created and persisted reverse swap {}", created_rsi.id);
+        // The swap was created remotely but nothing is locked until the HODL invoice is paid
+        if cancellation.is_cancelled() {
+            self.persister
+                .update_reverse_swap_status(&created_rsi.id, &Cancelled)?;
+            self.emit_reverse_swap_updated(&created_rsi.id).await?;
+            return Err(ReverseSwapError::Cancelled);
+        }
 
         // Wait until one of the following happens:
         // - trying to pay the HODL invoice explicitly fails from Greenlight
         // - the regular poll of the Breez API detects the status of this reverse swap advanced to LockTxMempool
         //   (meaning Boltz detected that we paid the HODL invoice)
         // - the max allowed duration of a payment is reached
-        let res = tokio::select! {
-            pay_thread_res = tokio::time::timeout(
-                Duration::from_secs(self.config.payment_timeout_sec as u64),
-                self.node_api.send_pay(created_rsi.invoice.clone(), MAX_PAYMENT_PATH_HOPS)
-            ) => {
+        let payment_timeout = Duration::from_secs(self.config.payment_timeout_sec as u64);
+        let res = tokio::time::timeout(payment_timeout, async {
+            tokio::select! {
                 // TODO It doesn't fail when trying to pay more sats than max_payable?
-                match pay_thread_res {
+                pay_res = self.node_api.send_pay(created_rsi.invoice.clone(), MAX_PAYMENT_PATH_HOPS) => match pay_res {
                     // Paying a HODL invoice does not typically return, so if send_payment() returned, it's an abnormal situation
-                    Ok(Ok(res)) => Err(NodeError::PaymentFailed(format!("Payment of HODL invoice unexpectedly returned: {res:?}"))),
+                    Ok(res) => Err(format!("Payment of HODL invoice unexpectedly returned: {res:?}")),
 
                     // send_payment() returned an error, so we know paying the HODL invoice failed
-                    Ok(Err(e)) => Err(NodeError::PaymentFailed(format!("Failed to pay HODL invoice: {e}"))),
-
-                    // send_payment() has been trying to pay for longer than the payment timeout
-                    Err(e) => Err(NodeError::PaymentTimeout(format!("Trying to pay the HODL invoice timed out: {e}")))
-                }
-            },
-            paid_invoice_res = self.poll_initial_boltz_status_transition(&created_rsi.id) => {
-                paid_invoice_res.map(|_| created_rsi.clone()).map_err(|e| NodeError::Generic(e.to_string()))
+                    Err(e) => Err(format!("Failed to pay HODL invoice: {e}")),
+                },
+                _ = self.poll_initial_boltz_status_transition(&created_rsi.id) => Ok(()),
             }
-        };
+        })
+        .await;
 
-        // The result of the creation call can succeed or fail
         // We update the rev swap status accordingly, which would otherwise have needed a fully fledged sync() call
         match res {
-            Ok(_) => {
+            Ok(Ok(())) => {
                 let lockup_txid = self.get_lockup_tx(&created_rsi).await?.map(|tx| tx.txid);
                 self.persister
                     .update_reverse_swap_status(&created_rsi.id, &InProgress)?;
                 self.persister
                     .update_reverse_swap_lockup_txid(&created_rsi.id, lockup_txid)?;
-                self.emit_reverse_swap_updated(&created_rsi.id).await?
+                self.emit_reverse_swap_updated(&created_rsi.id).await?;
+                Ok(created_rsi)
             }
-            Err(_) => {
+            Ok(Err(err)) => {
+                warn!("Reverse swap {} failed: {err}", created_rsi.id);
                 self.persister
                     .update_reverse_swap_status(&created_rsi.id, &Cancelled)?;
-                self.emit_reverse_swap_updated(&created_rsi.id).await?
+                self.emit_reverse_swap_updated(&created_rsi.id).await?;
+                Err(ReverseSwapError::PaymentFailed {
+                    id: created_rsi.id,
+                    err,
+                })
+            }
+            // The HTLC may still be held by the swapper, so the funds may still leave the wallet.
+            // The reverse swap stays in its initial status, for the sync to settle it either way.
+            Err(_) => {
+                warn!(
+                    "Paying the HODL invoice of reverse swap {} timed out",
+                    created_rsi.id
+                );
+                Err(ReverseSwapError::PaymentPending {
+                    id: created_rsi.id,
+                    err: format!(
+                        "Trying to pay the HODL invoice timed out after {}s",
+                        payment_timeout.as_secs()
+                    ),
+                })
             }
         }
-
-        Ok(res?)
     }
 
     /// Endless loop that periodically polls whether the reverse swap transitioned away from the
@@ -285,16 +317,26 @@ impl BTCSendSwap {
     /// The loop returns as soon as the lock tx is seen by Boltz. In other words, it returns as soon as
     /// the reverse swap status, as reported by Boltz, is [BoltzApiReverseSwapStatus::LockTxMempool]
     /// or [BoltzApiReverseSwapStatus::LockTxConfirmed]
-    async fn poll_initial_boltz_status_transition(&self, id: &str) -> Result<()> {
+    async fn poll_initial_boltz_status_transition(&self, id: &str) {
         let mut i = 0;
         loop {
             sleep(Duration::from_secs(5)).await;
 
             info!("Checking Boltz status for reverse swap {id}, attempt {i}");
-            let reverse_swap_boltz_status = self
+            i += 1;
+            // A failing status check says nothing about the payment, so it's retried until the
+            // payment attempt times out
+            let reverse_swap_boltz_status = match self
                 .reverse_swap_service_api
                 .get_boltz_status(id.into())
-                .await?;
+                .await
+            {
+                Ok(status) => status,
+                Err(e) => {
+                    warn!("Failed to get the Boltz status of reverse swap {id}: {e}");
+                    continue;
+                }
+            };
             info!("Got Boltz status {reverse_swap_boltz_status:?}");
 
             // Return when lock tx is seen in the mempool or onchain
@@ -302,25 +344,141 @@ impl BTCSendSwap {
             // However, if the tx is broadcast and the block is mined between the iterations of this loop,
             // we might not see the LockTxMempool state and instead directly get the LockTxConfirmed
             if let LockTxMempool { .. } | LockTxConfirmed { .. } = reverse_swap_boltz_status {
-                return Ok(());
+                return;
             }
-            i += 1;
         }
     }
 
-    /// Create a new reverse swap on the remote service provider (Boltz), then validates its redeem script
-    /// before returning it
+    /// Validates the amounts of the created reverse swap against the prepared ones
+    fn validate_created_amounts(
+        req: &PayOnchainRequest,
+        created_rsi: &FullReverseSwapInfo,
+    ) -> ReverseSwapResult<()> {
+        trace!("create_rev_swap v2 request: {req:?}");
+        trace!("create_rev_swap v2 created_rsi: {created_rsi:?}");
+
+        // Validate send_amount
+        let request_send_amount_sat = req.prepare_res.sender_amount_sat.sat();
+        let request_send_amount_msat = req.prepare_res.sender_amount_sat.to_msat().msat();
+        created_rsi.validate_invoice_amount(request_send_amount_msat)?;
+
+        // Validate onchain_amount
+        let lockup_fee_sat = req.prepare_res.fees_lockup;
+        let service_fee_sat =
+            super::get_service_fee_sat(request_send_amount_sat, req.prepare_res.fees_percentage);
+        trace!("create_rev_swap v2 service_fee_sat: {service_fee_sat} sat");
+        let expected_onchain_amount = request_send_amount_sat - service_fee_sat - lockup_fee_sat;
+        ensure_sdk!(
+            created_rsi.onchain_amount_sat == expected_onchain_amount,
+            ReverseSwapError::generic("Unexpected onchain amount (lockup fee or service fee)")
+        );
+
+        // Validate claim_fee. If onchain_amount and claim_fee are both valid, receive_amount is also valid.
+        let recipient_amount_sat = req.prepare_res.recipient_amount_sat.sat();
+        ensure_sdk!(
+            created_rsi.onchain_amount_sat > recipient_amount_sat,
+            ReverseSwapError::generic("Unexpected receive amount")
+        );
+        let claim_fee = created_rsi.onchain_amount_sat - recipient_amount_sat;
+        Self::validate_claim_tx_fee(claim_fee)
+    }
+
+    /// Create a new reverse swap on the remote service provider (Boltz), then validates it before
+    /// returning it.
+    ///
+    /// A [ReverseSwapProtocol::Taproot] reverse swap is created if possible, falling back to a
+    /// [ReverseSwapProtocol::Legacy] one.
     async fn create_and_validate_rev_swap_on_remote(
         &self,
         req: PayOnchainRequest,
         routing_node: String,
+    ) -> ReverseSwapResult<FullReverseSwapInfo> {
+        match self
+            .create_and_validate_taproot_rev_swap_on_remote(&req)
+            .await
+        {
+            Ok(created_rsi) => Ok(created_rsi),
+            Err(e) => {
+                warn!("Failed to create a taproot reverse swap, using the legacy protocol: {e}");
+                self.create_and_validate_legacy_rev_swap_on_remote(req, routing_node)
+                    .await
+            }
+        }
+    }
+
+    async fn create_and_validate_taproot_rev_swap_on_remote(
+        &self,
+        req: &PayOnchainRequest,
+    ) -> ReverseSwapResult<FullReverseSwapInfo> {
+        // Boltz rejects preimage hashes it has already seen, so each attempt uses its own keys
+        let reverse_swap_keys = create_swap_keys()?;
+
+        let boltz_response = self
+            .reverse_swap_service_api
+            .create_taproot_reverse_swap_on_remote(
+                req.prepare_res.sender_amount_sat.sat(),
+                reverse_swap_keys.preimage_hash_bytes().to_hex(),
+                reverse_swap_keys.public_key()?.to_hex(),
+            )
+            .await?;
+        match boltz_response {
+            BoltzApiCreateTaprootReverseSwapResponse::BoltzApiSuccess(response) => {
+                let taproot_swap = TaprootReverseSwap::new(
+                    &reverse_swap_keys.priv_key,
+                    &response.refund_public_key,
+                    &reverse_swap_keys.preimage_hash_bytes(),
+                    response.timeout_block_height,
+                )?;
+                taproot_swap.validate(
+                    &response.swap_tree,
+                    &response.lockup_address,
+                    self.config.network.into(),
+                )?;
+
+                let res = FullReverseSwapInfo {
+                    created_at_block_height: self.chain_service.current_tip().await?,
+                    claim_pubkey: req.recipient_address.clone(),
+                    invoice: response.invoice,
+                    preimage: reverse_swap_keys.preimage,
+                    private_key: reverse_swap_keys.priv_key,
+                    timeout_block_height: response.timeout_block_height,
+                    id: response.id,
+                    onchain_amount_sat: response.onchain_amount,
+                    sat_per_vbyte: None,
+                    receive_amount_sat: Some(req.prepare_res.recipient_amount_sat.sat()),
+                    redeem_script: taproot_swap.claim_leaf().to_hex(),
+                    protocol: ReverseSwapProtocol::Taproot,
+                    refund_pubkey: Some(response.refund_public_key),
+                    cache: ReverseSwapInfoCached {
+                        status: Initial,
+                        lockup_txid: None,
+                        claim_txid: None,
+                    },
+                };
+
+                res.validate_invoice(req.prepare_res.sender_amount_sat.to_msat().msat())?;
+                Self::validate_created_amounts(req, &res)?;
+                Ok(res)
+            }
+            BoltzApiCreateTaprootReverseSwapResponse::BoltzApiError { error } => {
+                Err(ReverseSwapError::ServiceConnectivity(format!(
+                    "(Boltz) Failed to create taproot reverse swap: {error}"
+                )))
+            }
+        }
+    }
+
+    async fn create_and_validate_legacy_rev_swap_on_remote(
+        &self,
+        req: PayOnchainRequest,
+        routing_node: String,
     ) -> ReverseSwapResult<FullReverseSwapInfo> {
         let reverse_swap_keys = create_swap_keys()?;
 
         let boltz_response = self
             .reverse_swap_service_api
             .create_reverse_swap_on_remote(
-                req.prepare_res.sender_amount_sat,
+                req.prepare_res.sender_amount_sat.sat(),
                 reverse_swap_keys.preimage_hash_bytes().to_hex(),
                 reverse_swap_keys.public_key()?.to_hex(),
                 req.prepare_res.fees_hash,
@@ -331,7 +489,7 @@ impl BTCSendSwap {
             BoltzApiCreateReverseSwapResponse::BoltzApiSuccess(response) => {
                 let res = FullReverseSwapInfo {
                     created_at_block_height: self.chain_service.current_tip().await?,
-                    claim_pubkey: req.recipient_address,
+                    claim_pubkey: req.recipient_address.clone(),
                     invoice: response.invoice,
                     preimage: reverse_swap_keys.preimage,
                     private_key: reverse_swap_keys.priv_key,
@@ -339,8 +497,10 @@ impl BTCSendSwap {
                     id: response.id,
                     onchain_amount_sat: response.onchain_amount,
                     sat_per_vbyte: None,
-                    receive_amount_sat: Some(req.prepare_res.recipient_amount_sat),
+                    receive_amount_sat: Some(req.prepare_res.recipient_amount_sat.sat()),
                     redeem_script: response.redeem_script,
+                    protocol: ReverseSwapProtocol::Legacy,
+                    refund_pubkey: None,
                     cache: ReverseSwapInfoCached {
                         status: Initial,
                         lockup_txid: None,
@@ -348,8 +508,9 @@ impl BTCSendSwap {
                     },
                 };
 
-                res.validate_invoice(req.prepare_res.sender_amount_sat * 1_000)?;
+                res.validate_invoice(req.prepare_res.sender_amount_sat.to_msat().msat())?;
                 res.validate_redeem_script(response.lockup_address, self.config.network)?;
+                Self::validate_created_amounts(&req, &res)?;
                 Ok(res)
             }
             BoltzApiCreateReverseSwapResponse::BoltzApiError { error } => {
@@ -360,14 +521,21 @@ impl BTCSendSwap {
         }
     }
 
-    /// Builds and signs claim tx
-    async fn create_claim_tx(&self, rs: &FullReverseSwapInfo) -> Result<Transaction> {
+    /// Builds and signs claim tx.
+    ///
+    /// A [ReverseSwapProtocol::Taproot] reverse swap is claimed together with Boltz if
+    /// `cooperative` is set. Otherwise, or if Boltz doesn't cooperate, it is claimed with the
+    /// claim leaf. The witness doesn't change the txid of the claim tx.
+    async fn create_claim_tx(
+        &self,
+        rs: &FullReverseSwapInfo,
+        cooperative: bool,
+    ) -> Result<Transaction> {
         let lockup_addr = rs.get_lockup_address(self.config.network)?;
         let claim_addr = Address::from_str(&rs.claim_pubkey)?;
-        let redeem_script = Script::from_hex(&rs.redeem_script)?;
 
         match lockup_addr.address_type() {
-            Some(AddressType::P2wsh) => {
+            Some(AddressType::P2wsh | AddressType::P2tr) => {
                 // We explicitly only get the confirmed onchain transactions
                 //
                 // Otherwise, if we had gotten all txs, we risk a race condition when we try
@@ -405,14 +573,27 @@ impl BTCSendSwap {
                 };
                 debug!("Tx out amount: {tx_out_value} sat");
 
-                Self::build_claim_tx_inner(
-                    SecretKey::from_slice(rs.private_key.as_slice())?,
-                    rs.preimage.clone(),
-                    utxos,
-                    claim_addr,
-                    redeem_script,
-                    tx_out_value,
-                )
+                match rs.protocol {
+                    ReverseSwapProtocol::Legacy => Self::build_claim_tx_inner(
+                        SecretKey::from_slice(rs.private_key.as_slice())?,
+                        rs.preimage.clone(),
+                        utxos,
+                        claim_addr,
+                        Script::from_hex(&rs.redeem_script)?,
+                        tx_out_value,
+                    ),
+                    ReverseSwapProtocol::Taproot => {
+                        self.build_taproot_claim_tx(
+                            rs,
+                            utxos,
+                            &lockup_addr,
+                            claim_addr,
+                            tx_out_value,
+                            cooperative,
+                        )
+                        .await
+                    }
+                }
             }
             Some(addr_type) => Err(anyhow!("Unexpected lock address type: {addr_type:?}")),
             None => Err(anyhow!("Could not determine lock address type")),
@@ -482,6 +663,66 @@ impl BTCSendSwap {
         Ok(tx)
     }
 
+    async fn build_taproot_claim_tx(
+        &self,
+        rs: &FullReverseSwapInfo,
+        utxos: AddressUtxos,
+        lockup_addr: &Address,
+        claim_addr: Address,
+        tx_out_value: u64,
+        cooperative: bool,
+    ) -> Result<Transaction> {
+        let taproot_swap = TaprootReverseSwap::from_reverse_swap(rs)?;
+        let prevouts: Vec<TxOut> = utxos
+            .confirmed
+            .iter()
+            .map(|utxo| TxOut {
+                value: utxo.value,
+                script_pubkey: lockup_addr.script_pubkey(),
+            })
+            .collect();
+        let mut tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime(0),
+            input: utxos
+                .confirmed
+                .iter()
+                .map(|utxo| TxIn {
+                    previous_output: utxo.out,
+                    script_sig: Script::new(),
+                    sequence: Sequence(0),
+                    witness: Witness::default(),
+                })
+                .collect(),
+            output: vec![TxOut {
+                value: tx_out_value,
+                script_pubkey: claim_addr.script_pubkey(),
+            }],
+        };
+
+        if cooperative {
+            let mut cooperative_tx = tx.clone();
+            match taproot_swap
+                .sign_cooperatively(
+                    &mut cooperative_tx,
+                    &prevouts,
+                    &rs.preimage,
+                    &rs.id,
+                    self.reverse_swap_service_api.as_ref(),
+                )
+                .await
+            {
+                Ok(()) => return Ok(cooperative_tx),
+                Err(e) => warn!(
+                    "Failed to claim reverse swap {} cooperatively, using the claim leaf: {e}",
+                    rs.id
+                ),
+            }
+        }
+        taproot_swap.sign_with_claim_leaf(&mut tx, &prevouts, &rs.preimage)?;
+        Ok(tx)
+    }
+
     pub(crate) fn calculate_claim_tx_fee(claim_tx_feerate: u32) -> SdkResult<u64> {
         let tx = build_fake_claim_tx()?;
 
@@ -599,6 +840,7 @@ impl BTCSendSwap {
     /// Updates the cached values of monitored reverse swaps in the cache table and executes the
     /// corresponding next steps for the pending reverse swaps. This includes the blocking
     /// reverse swaps as well, since the blocking statuses are a subset of the monitored statuses.
+    #[tracing::instrument(skip_all)]
     async fn process_monitored_reverse_swaps(&self) -> Result<()> {
         let monitored = self.list_monitored().await?;
         debug!("Found {} monitored reverse swaps", monitored.len());
@@ -634,7 +876,7 @@ impl BTCSendSwap {
             // (Re-)Broadcast the claim tx for monitored reverse swaps that have a confirmed lockup tx
             let broadcasted_claim_tx = if matches!(lock_tx_status, TxStatus::Confirmed) {
                 info!("Lock tx is confirmed, preparing claim tx");
-                let claim_tx = self.create_claim_tx(&rsi).await?;
+                let claim_tx = self.create_claim_tx(&rsi, true).await?;
                 let claim_tx_broadcast_res = self
                     .chain_service
                     .broadcast_transaction(serialize(&claim_tx))
@@ -735,7 +977,7 @@ impl BTCSendSwap {
                 .map(|lockup_tx| lockup_tx.txid),
             claim_txid: match full_rsi.cache.status {
                 CompletedSeen | CompletedConfirmed => self
-                    .create_claim_tx(&full_rsi)
+                    .create_claim_tx(&full_rsi, false)
                     .await
                     .ok()
                     .map(|claim_tx| claim_tx.txid().to_hex()),
@@ -743,6 +985,7 @@ impl BTCSendSwap {
             },
             onchain_amount_sat: full_rsi.onchain_amount_sat,
             status: full_rsi.cache.status,
+            protocol: full_rsi.protocol,
         })
     }
 }
@@ -793,13 +1036,70 @@ fn build_fake_claim_tx() -> Result<Transaction> {
     )
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "reverse-swaps"))]
 mod tests {
     use anyhow::Result;
 
+    use super::validate_recipient_address;
+    use crate::bitcoin::Network;
+    use crate::error::SendOnchainError;
+    use crate::swap_out::error::ReverseSwapError;
     use crate::swap_out::get_service_fee_sat;
     use crate::test_utils::{MOCK_REVERSE_SWAP_MAX, MOCK_REVERSE_SWAP_MIN};
-    use crate::{PrepareOnchainPaymentRequest, PrepareOnchainPaymentResponse, SwapAmountType};
+    use crate::{PrepareOnchainPaymentRequest, PrepareOnchainPaymentResponse, Sat, SwapAmountType};
+
+    #[test]
+    fn test_validate_recipient_address() {
+        let mainnet = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        assert!(validate_recipient_address(mainnet, Network::Bitcoin).is_ok());
+        assert!(validate_recipient_address(
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+            Network::Signet
+        )
+        .is_ok());
+
+        // Wrong checksum
+        assert!(matches!(
+            validate_recipient_address(
+                "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdp",
+                Network::Bitcoin
+            ),
+            Err(ReverseSwapError::InvalidDestinationAddress(_))
+        ));
+        assert!(matches!(
+            validate_recipient_address(mainnet, Network::Testnet),
+            Err(ReverseSwapError::DestinationAddressNetworkMismatch(_))
+        ));
+        // A segwit v1 program that is not taproot
+        assert!(matches!(
+            validate_recipient_address(
+                "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+                Network::Bitcoin
+            ),
+            Err(ReverseSwapError::UnsupportedDestinationAddressType(_))
+        ));
+    }
+
+    #[test]
+    fn test_payment_outcome_errors() {
+        let failed = ReverseSwapError::PaymentFailed {
+            id: "swap".into(),
+            err: "no route".into(),
+        };
+        assert!(matches!(
+            SendOnchainError::from(failed),
+            SendOnchainError::PaymentFailed { err } if err.contains("swap") && err.contains("no route")
+        ));
+
+        let pending = ReverseSwapError::PaymentPending {
+            id: "swap".into(),
+            err: "timed out".into(),
+        };
+        assert!(matches!(
+            SendOnchainError::from(pending),
+            SendOnchainError::PaymentTimeout { err } if err.contains("swap") && err.contains("timed out")
+        ));
+    }
 
     #[tokio::test]
     async fn test_prepare_onchain_payment_in_range() -> Result<()> {
@@ -808,7 +1108,7 @@ mod tests {
         // User-specified send amount is within range
         assert_in_range_prep_payment_response(
             sdk.prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: MOCK_REVERSE_SWAP_MIN,
+                amount_sat: Sat(MOCK_REVERSE_SWAP_MIN),
                 amount_type: SwapAmountType::Receive,
                 claim_tx_feerate: 1,
             })
@@ -818,7 +1118,7 @@ mod tests {
         // Derived send amount is within range
         assert_in_range_prep_payment_response(
             sdk.prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: MOCK_REVERSE_SWAP_MIN,
+                amount_sat: Sat(MOCK_REVERSE_SWAP_MIN),
                 amount_type: SwapAmountType::Receive,
                 claim_tx_feerate: 1,
             })
@@ -835,7 +1135,7 @@ mod tests {
         // User-specified send amount is out of range (below min)
         assert!(sdk
             .prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: MOCK_REVERSE_SWAP_MIN - 1,
+                amount_sat: Sat(MOCK_REVERSE_SWAP_MIN - 1),
                 amount_type: SwapAmountType::Send,
                 claim_tx_feerate: 1,
             })
@@ -845,7 +1145,7 @@ mod tests {
         // User-specified send amount is out of range (above max)
         assert!(sdk
             .prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: MOCK_REVERSE_SWAP_MAX + 1,
+                amount_sat: Sat(MOCK_REVERSE_SWAP_MAX + 1),
                 amount_type: SwapAmountType::Send,
                 claim_tx_feerate: 1,
             })
@@ -855,7 +1155,7 @@ mod tests {
         // Derived send amount is out of range (below min: specified receive amount is 0)
         assert!(sdk
             .prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: 0,
+                amount_sat: Sat(0),
                 amount_type: SwapAmountType::Receive,
                 claim_tx_feerate: 1,
             })
@@ -865,7 +1165,7 @@ mod tests {
         // Derived send amount is out of range (above max)
         assert!(sdk
             .prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: MOCK_REVERSE_SWAP_MAX,
+                amount_sat: Sat(MOCK_REVERSE_SWAP_MAX),
                 amount_type: SwapAmountType::Receive,
                 claim_tx_feerate: 1,
             })
@@ -875,7 +1175,7 @@ mod tests {
         // Derived send amount is out of range (above max because the chosen claim tx feerate pushes the send above max)
         assert!(sdk
             .prepare_onchain_payment(PrepareOnchainPaymentRequest {
-                amount_sat: MOCK_REVERSE_SWAP_MIN,
+                amount_sat: Sat(MOCK_REVERSE_SWAP_MIN),
                 amount_type: SwapAmountType::Receive,
                 claim_tx_feerate: 1_000_000,
             })
@@ -891,8 +1191,8 @@ mod tests {
     fn assert_in_range_prep_payment_response(res: PrepareOnchainPaymentResponse) -> Result<()> {
         dbg!(&res);
 
-        let send_amount_sat = res.sender_amount_sat;
-        let receive_amount_sat = res.recipient_amount_sat;
+        let send_amount_sat = res.sender_amount_sat.sat();
+        let receive_amount_sat = res.recipient_amount_sat.sat();
         let total_fees = res.total_fees;
         assert_eq!(send_amount_sat - total_fees, receive_amount_sat);
 
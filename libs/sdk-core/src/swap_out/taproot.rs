@@ -0,0 +1,415 @@
+//! Reverse swaps locking the funds in a taproot output, as created by the v2 Boltz API.
+//!
+//! The internal key of the output aggregates the Boltz refund key and our claim key with MuSig2,
+//! so the funds are normally claimed with a key path spend signed together with Boltz. If Boltz
+//! doesn't cooperate, the funds are claimed with the claim leaf of the script tree, revealing
+//! the preimage. The other leaf lets Boltz refund itself after the timeout.
+
+use std::str::FromStr;
+
+use rand::Rng;
+use secp256k1::musig::{
+    MusigAggNonce, MusigKeyAggCache, MusigPartialSignature, MusigPubNonce, MusigSecRand,
+    MusigSession,
+};
+use serde::{Deserialize, Serialize};
+
+use super::error::{ReverseSwapError, ReverseSwapResult};
+use crate::bitcoin::blockdata::opcodes::all::{
+    OP_CHECKSIG, OP_CHECKSIGVERIFY, OP_CLTV, OP_EQUALVERIFY, OP_HASH160, OP_SIZE,
+};
+use crate::bitcoin::blockdata::script::Builder;
+use crate::bitcoin::consensus::serialize;
+use crate::bitcoin::hashes::hex::{FromHex, ToHex};
+use crate::bitcoin::hashes::{ripemd160, Hash};
+use crate::bitcoin::secp256k1::{self as bitcoin_secp, Message, PublicKey, Secp256k1, SecretKey};
+use crate::bitcoin::util::sighash::{Prevouts, SighashCache};
+use crate::bitcoin::util::taproot::{LeafVersion, TapLeafHash, TaprootBuilder, TaprootSpendInfo};
+use crate::bitcoin::{
+    Address, Network, SchnorrSighashType, Script, Transaction, TxOut, Witness, XOnlyPublicKey,
+};
+use crate::models::ReverseSwapServiceAPI;
+use crate::FullReverseSwapInfo;
+
+/// Response of the v2 Boltz API to the creation of a reverse swap
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTaprootReverseSwapResponse {
+    pub(crate) id: String,
+
+    /// HODL invoice that has to be paid, for the Boltz service to lock up the funds
+    pub(crate) invoice: String,
+
+    /// Leaves of the script tree of the lockup output
+    pub(crate) swap_tree: SwapTree,
+
+    /// Address to which the funds will be locked
+    pub(crate) lockup_address: String,
+
+    /// Key used by Boltz in the refund leaf and in the MuSig2 aggregated key
+    pub(crate) refund_public_key: String,
+
+    /// Block height after which Boltz can refund the locked funds
+    pub(crate) timeout_block_height: u32,
+
+    /// Amount of sats which will be locked
+    pub(crate) onchain_amount: u64,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapTree {
+    pub(crate) claim_leaf: SwapTreeLeaf,
+    pub(crate) refund_leaf: SwapTreeLeaf,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SwapTreeLeaf {
+    pub(crate) version: u8,
+    /// The leaf script, hex encoded
+    pub(crate) output: String,
+}
+
+/// The nonce and partial signature of Boltz for a cooperative claim
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BoltzApiPartialSignature {
+    pub(crate) pub_nonce: String,
+    pub(crate) partial_signature: String,
+}
+
+/// The keys and script tree of a taproot reverse swap
+pub(crate) struct TaprootReverseSwap {
+    secp: Secp256k1<bitcoin_secp::All>,
+    musig_secp: secp256k1::Secp256k1<secp256k1::All>,
+    claim_private_key: SecretKey,
+    refund_pubkey: PublicKey,
+    claim_leaf: Script,
+    refund_leaf: Script,
+}
+
+impl TaprootReverseSwap {
+    pub(crate) fn new(
+        claim_private_key: &[u8],
+        refund_pubkey: &str,
+        preimage_hash: &[u8],
+        timeout_block_height: u32,
+    ) -> ReverseSwapResult<Self> {
+        let secp = Secp256k1::new();
+        let claim_private_key = SecretKey::from_slice(claim_private_key)?;
+        let refund_pubkey = PublicKey::from_str(refund_pubkey)?;
+        let (x_only_claim_pubkey, _) = claim_private_key.public_key(&secp).x_only_public_key();
+        let (x_only_refund_pubkey, _) = refund_pubkey.x_only_public_key();
+        Ok(Self {
+            claim_leaf: claim_leaf(&x_only_claim_pubkey, preimage_hash),
+            refund_leaf: refund_leaf(&x_only_refund_pubkey, timeout_block_height),
+            secp,
+            musig_secp: secp256k1::Secp256k1::new(),
+            claim_private_key,
+            refund_pubkey,
+        })
+    }
+
+    pub(crate) fn from_reverse_swap(rsi: &FullReverseSwapInfo) -> ReverseSwapResult<Self> {
+        let refund_pubkey = rsi.refund_pubkey.as_ref().ok_or(ReverseSwapError::generic(
+            "Taproot reverse swap has no refund pubkey",
+        ))?;
+        Self::new(
+            &rsi.private_key,
+            refund_pubkey,
+            &rsi.get_preimage_hash(),
+            rsi.timeout_block_height,
+        )
+    }
+
+    pub(crate) fn claim_leaf(&self) -> &Script {
+        &self.claim_leaf
+    }
+
+    pub(crate) fn lockup_address(&self, network: Network) -> ReverseSwapResult<Address> {
+        Ok(Address::p2tr_tweaked(
+            self.spend_info()?.output_key(),
+            network,
+        ))
+    }
+
+    /// Checks the script tree and the lockup address received from Boltz match the expected ones
+    pub(crate) fn validate(
+        &self,
+        swap_tree: &SwapTree,
+        received_lockup_address: &str,
+        network: Network,
+    ) -> ReverseSwapResult<()> {
+        let tapscript_version = LeafVersion::TapScript.to_consensus();
+        for (leaf, expected) in [
+            (&swap_tree.claim_leaf, &self.claim_leaf),
+            (&swap_tree.refund_leaf, &self.refund_leaf),
+        ] {
+            if leaf.version != tapscript_version || Script::from_hex(&leaf.output)? != *expected {
+                return Err(ReverseSwapError::UnexpectedRedeemScript);
+            }
+        }
+        match self.lockup_address(network)?.to_string() == received_lockup_address {
+            true => Ok(()),
+            false => Err(ReverseSwapError::UnexpectedLockupAddress),
+        }
+    }
+
+    /// Signs the inputs of the claim tx with the claim leaf, revealing the preimage
+    pub(crate) fn sign_with_claim_leaf(
+        &self,
+        tx: &mut Transaction,
+        prevouts: &[TxOut],
+        preimage: &[u8],
+    ) -> ReverseSwapResult<()> {
+        let control_block = self
+            .spend_info()?
+            .control_block(&(self.claim_leaf.clone(), LeafVersion::TapScript))
+            .ok_or(ReverseSwapError::generic("Missing control block"))?;
+        let leaf_hash = TapLeafHash::from_script(&self.claim_leaf, LeafVersion::TapScript);
+        let keypair = self.claim_private_key.keypair(&self.secp);
+
+        let unsigned_tx = tx.clone();
+        let mut sighasher = SighashCache::new(&unsigned_tx);
+        for (index, input) in tx.input.iter_mut().enumerate() {
+            let sighash = sighasher
+                .taproot_script_spend_signature_hash(
+                    index,
+                    &Prevouts::All(prevouts),
+                    leaf_hash,
+                    SchnorrSighashType::Default,
+                )
+                .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+            let signature = self.secp.sign_schnorr_with_aux_rand(
+                &Message::from(sighash),
+                &keypair,
+                &rand::thread_rng().gen(),
+            );
+            input.witness = Witness::from_vec(vec![
+                signature.as_ref().to_vec(),
+                preimage.to_vec(),
+                self.claim_leaf.to_bytes(),
+                control_block.serialize(),
+            ]);
+        }
+        Ok(())
+    }
+
+    /// Signs the inputs of the claim tx with a key path spend, together with Boltz.
+    ///
+    /// The aggregated signatures are verified, so the tx is valid if this succeeds.
+    pub(crate) async fn sign_cooperatively(
+        &self,
+        tx: &mut Transaction,
+        prevouts: &[TxOut],
+        preimage: &[u8],
+        id: &str,
+        reverse_swap_service_api: &dyn ReverseSwapServiceAPI,
+    ) -> ReverseSwapResult<()> {
+        let spend_info = self.spend_info()?;
+        let tweak =
+            secp256k1::Scalar::from_be_bytes(spend_info.tap_tweak().to_scalar().to_be_bytes())
+                .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+        let mut key_agg_cache = self.key_agg_cache()?;
+        key_agg_cache
+            .pubkey_xonly_tweak_add(&self.musig_secp, &tweak)
+            .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+        let output_key = spend_info.output_key().to_inner();
+
+        let musig_claim_private_key =
+            secp256k1::SecretKey::from_slice(&self.claim_private_key.secret_bytes())
+                .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+        let musig_claim_pubkey = musig_claim_private_key.public_key(&self.musig_secp);
+
+        let unsigned_tx = tx.clone();
+        let serialized_tx = serialize(&unsigned_tx).to_hex();
+        let mut sighasher = SighashCache::new(&unsigned_tx);
+        for (index, input) in tx.input.iter_mut().enumerate() {
+            let sighash = sighasher
+                .taproot_key_spend_signature_hash(
+                    index,
+                    &Prevouts::All(prevouts),
+                    SchnorrSighashType::Default,
+                )
+                .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+            let msg = secp256k1::Message::from_digest(sighash.into_inner());
+
+            let session_id = MusigSecRand::assume_unique_per_nonce_gen(rand::thread_rng().gen());
+            let (our_sec_nonce, our_pub_nonce) = key_agg_cache
+                .nonce_gen(
+                    &self.musig_secp,
+                    session_id,
+                    musig_claim_pubkey,
+                    msg,
+                    Some(rand::thread_rng().gen()),
+                )
+                .map_err(|_| ReverseSwapError::generic("Failed to generate the MuSig2 nonce"))?;
+
+            let boltz_signature = reverse_swap_service_api
+                .get_claim_partial_signature(
+                    id.to_string(),
+                    index as u32,
+                    serialized_tx.clone(),
+                    preimage.to_hex(),
+                    our_pub_nonce.serialize().to_hex(),
+                )
+                .await?;
+            let their_pub_nonce =
+                MusigPubNonce::from_slice(&hex::decode(boltz_signature.pub_nonce)?)
+                    .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+            let their_partial_sig =
+                MusigPartialSignature::from_slice(&hex::decode(boltz_signature.partial_signature)?)
+                    .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+
+            let agg_nonce =
+                MusigAggNonce::new(&self.musig_secp, &[&their_pub_nonce, &our_pub_nonce]);
+            let musig_session = MusigSession::new(&self.musig_secp, &key_agg_cache, agg_nonce, msg);
+            let our_partial_sig = musig_session
+                .partial_sign(
+                    &self.musig_secp,
+                    our_sec_nonce,
+                    &musig_claim_private_key.keypair(&self.musig_secp),
+                    &key_agg_cache,
+                )
+                .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+            let signature = musig_session
+                .partial_sig_agg(&[&their_partial_sig, &our_partial_sig])
+                .as_byte_array();
+
+            // Boltz may have sent an invalid partial signature
+            let schnorr_signature = bitcoin_secp::schnorr::Signature::from_slice(&signature)?;
+            self.secp
+                .verify_schnorr(&schnorr_signature, &Message::from(sighash), &output_key)
+                .map_err(|_| {
+                    ReverseSwapError::generic("Invalid aggregated signature for the claim tx")
+                })?;
+
+            input.witness = Witness::from_vec(vec![signature.to_vec()]);
+        }
+        Ok(())
+    }
+
+    /// The MuSig2 aggregation of the Boltz refund key and our claim key, in the order Boltz uses
+    fn key_agg_cache(&self) -> ReverseSwapResult<MusigKeyAggCache> {
+        let refund_pubkey = secp256k1::PublicKey::from_slice(&self.refund_pubkey.serialize())
+            .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+        let claim_pubkey = secp256k1::PublicKey::from_slice(
+            &self.claim_private_key.public_key(&self.secp).serialize(),
+        )
+        .map_err(|e| ReverseSwapError::Generic(e.to_string()))?;
+        Ok(MusigKeyAggCache::new(
+            &self.musig_secp,
+            &[&refund_pubkey, &claim_pubkey],
+        ))
+    }
+
+    fn spend_info(&self) -> ReverseSwapResult<TaprootSpendInfo> {
+        // Convert from one secp256k1 crate to the other
+        let internal_key = XOnlyPublicKey::from_slice(&self.key_agg_cache()?.agg_pk().serialize())?;
+
+        TaprootBuilder::new()
+            .add_leaf(1, self.claim_leaf.clone())
+            .and_then(|builder| builder.add_leaf(1, self.refund_leaf.clone()))
+            .map_err(|e| ReverseSwapError::Generic(e.to_string()))?
+            .finalize(&self.secp, internal_key)
+            .map_err(|_| ReverseSwapError::generic("Could not finalize taproot spend info"))
+    }
+}
+
+/// The leaf with which we can claim the funds by revealing the preimage
+fn claim_leaf(x_only_claim_pubkey: &XOnlyPublicKey, preimage_hash: &[u8]) -> Script {
+    Builder::new()
+        .push_opcode(OP_SIZE)
+        .push_slice(&[0x20])
+        .push_opcode(OP_EQUALVERIFY)
+        .push_opcode(OP_HASH160)
+        .push_slice(&ripemd160::Hash::hash(preimage_hash))
+        .push_opcode(OP_EQUALVERIFY)
+        .push_x_only_key(x_only_claim_pubkey)
+        .push_opcode(OP_CHECKSIG)
+        .into_script()
+}
+
+/// The leaf with which Boltz can refund itself after the timeout
+fn refund_leaf(x_only_refund_pubkey: &XOnlyPublicKey, timeout_block_height: u32) -> Script {
+    Builder::new()
+        .push_x_only_key(x_only_refund_pubkey)
+        .push_opcode(OP_CHECKSIGVERIFY)
+        .push_int(timeout_block_height as i64)
+        .push_opcode(OP_CLTV)
+        .into_script()
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use rand::thread_rng;
+
+    use super::TaprootReverseSwap;
+    use crate::bitcoin::hashes::hex::ToHex;
+    use crate::bitcoin::hashes::{sha256, Hash};
+    use crate::bitcoin::secp256k1::{KeyPair, Secp256k1};
+    use crate::bitcoin::{
+        AddressType, Network, OutPoint, PackedLockTime, Script, Sequence, Transaction, TxIn, TxOut,
+        Txid, Witness,
+    };
+
+    #[test]
+    fn test_claim_with_claim_leaf() -> Result<()> {
+        let secp = Secp256k1::new();
+        let claim_keys = KeyPair::new(&secp, &mut thread_rng());
+        let refund_keys = KeyPair::new(&secp, &mut thread_rng());
+        let preimage = sha256::Hash::hash("preimage".as_bytes()).to_vec();
+
+        let swap = TaprootReverseSwap::new(
+            &claim_keys.secret_key().secret_bytes(),
+            &refund_keys.public_key().to_hex(),
+            &sha256::Hash::hash(&preimage),
+            840_000,
+        )?;
+        let lockup_address = swap.lockup_address(Network::Bitcoin)?;
+        assert_eq!(lockup_address.address_type(), Some(AddressType::P2tr));
+
+        let mut tx = Transaction {
+            version: 2,
+            lock_time: PackedLockTime(0),
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: Txid::all_zeros(),
+                    vout: 0,
+                },
+                script_sig: Script::new(),
+                sequence: Sequence(0),
+                witness: Witness::default(),
+            }],
+            output: vec![TxOut {
+                value: 9_000,
+                script_pubkey: lockup_address.script_pubkey(),
+            }],
+        };
+        let unsigned_txid = tx.txid();
+        let prevouts = vec![TxOut {
+            value: 10_000,
+            script_pubkey: lockup_address.script_pubkey(),
+        }];
+        swap.sign_with_claim_leaf(&mut tx, &prevouts, &preimage)?;
+
+        // The witness doesn't change the txid
+        assert_eq!(tx.txid(), unsigned_txid);
+        let witness = tx.input[0].witness.to_vec();
+        assert_eq!(witness.len(), 4);
+        assert_eq!(witness[0].len(), 64);
+        assert_eq!(witness[1], preimage);
+        assert_eq!(witness[2], swap.claim_leaf().to_bytes());
+
+        // Another refund key results in another lockup address
+        let other_swap = TaprootReverseSwap::new(
+            &claim_keys.secret_key().secret_bytes(),
+            &claim_keys.public_key().to_hex(),
+            &sha256::Hash::hash(&preimage),
+            840_000,
+        )?;
+        assert_ne!(other_swap.lockup_address(Network::Bitcoin)?, lockup_address);
+        Ok(())
+    }
+}
@@ -34,10 +34,11 @@ use crate::backup::{BackupState, BackupTransport};
 use crate::bitcoin::hashes::hex::ToHex;
 use crate::bitcoin::hashes::{sha256, Hash};
 use crate::bitcoin::secp256k1::ecdsa::RecoverableSignature;
-use crate::bitcoin::secp256k1::{KeyPair, Message, PublicKey, Secp256k1};
-use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPrivKey};
+use crate::bitcoin::secp256k1::{ecdsa, schnorr, KeyPair, Message, PublicKey, Secp256k1};
+use crate::bitcoin::util::bip32::{ChildNumber, ExtendedPubKey};
 use crate::bitcoin::Network;
 use crate::breez_services::{OpenChannelParams, Receiver};
+#[cfg(feature = "buy-bitcoin")]
 use crate::buy::BuyBitcoinApi;
 use crate::chain::{ChainService, OnchainTx, Outspend, RecommendedFees, TxStatus};
 use crate::error::{ReceivePaymentError, SdkError, SdkResult};
@@ -46,15 +47,24 @@ use crate::lightning::ln::PaymentSecret;
 use crate::lightning_invoice::{Currency, InvoiceBuilder, RawBolt11Invoice};
 use crate::lsp::LspInformation;
 use crate::models::{
-    LspAPI, NodeState, Payment, ReverseSwapServiceAPI, SwapperAPI, SyncResponse, TlvEntry,
+    Channel, ChannelDetails, ChannelState, LspAPI, NodeState, Payment, ReverseSwapServiceAPI,
+    SwapperAPI, SyncResponse, TlvEntry,
 };
-use crate::node_api::{CreateInvoiceRequest, FetchBolt11Result, NodeAPI, NodeError, NodeResult};
+use crate::node_api::{
+    CreateInvoiceRequest, FetchBolt11Result, NodeAPI, NodeError, NodeResult, PaymentLimits,
+    ProbeResult,
+};
+use crate::signer::MasterKey;
 use crate::swap_in::TaprootSwapperAPI;
-use crate::swap_out::boltzswap::{BoltzApiCreateReverseSwapResponse, BoltzApiReverseSwapStatus};
+use crate::swap_out::boltzswap::{
+    BoltzApiCreateReverseSwapResponse, BoltzApiCreateTaprootReverseSwapResponse,
+    BoltzApiReverseSwapStatus,
+};
 use crate::swap_out::error::{ReverseSwapError, ReverseSwapResult};
+use crate::swap_out::taproot::BoltzApiPartialSignature;
 use crate::{
-    parse_invoice, BuyBitcoinProvider, Config, CustomMessage, LNInvoice, MaxChannelAmount,
-    NodeCredentials, OpeningFeeParamsMenu, PaymentResponse, PrepareRedeemOnchainFundsRequest,
+    parse_invoice, Config, CustomMessage, LNInvoice, MaxChannelAmount, NodeCredentials,
+    OpenChannelResponse, OpeningFeeParamsMenu, PaymentResponse, PrepareRedeemOnchainFundsRequest,
     PrepareRedeemOnchainFundsResponse, ReceivePaymentRequest, ReverseSwapPairInfo, RouteHint,
     RouteHintHop, SwapInfo,
 };
@@ -67,6 +77,7 @@ pub struct MockBackupTransport {
     pub num_pulled: std::sync::Mutex<u32>,
     pub remote_version: std::sync::Mutex<Option<u64>>,
     pub state: std::sync::Mutex<Option<BackupState>>,
+    pub snapshots: std::sync::Mutex<Vec<BackupState>>,
 }
 
 impl MockBackupTransport {
@@ -76,6 +87,7 @@ impl MockBackupTransport {
             num_pulled: std::sync::Mutex::new(0),
             remote_version: std::sync::Mutex::new(None),
             state: std::sync::Mutex::new(None),
+            snapshots: std::sync::Mutex::new(vec![]),
         }
     }
     pub fn pushed(&self) -> u32 {
@@ -120,6 +132,28 @@ impl BackupTransport for MockBackupTransport {
         });
         Ok(next_version)
     }
+    async fn push_snapshot(&self, state: BackupState) -> SdkResult<()> {
+        self.snapshots.lock().unwrap().push(state);
+        Ok(())
+    }
+    async fn list_snapshots(&self) -> SdkResult<Vec<u64>> {
+        Ok(self
+            .snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|s| s.generation)
+            .collect())
+    }
+    async fn pull_snapshot(&self, generation: u64) -> SdkResult<Option<BackupState>> {
+        Ok(self
+            .snapshots
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.generation == generation)
+            .cloned())
+    }
 }
 
 pub struct MockSwapperAPI {}
@@ -158,6 +192,26 @@ impl ReverseSwapServiceAPI for MockReverseSwapperAPI {
         Err(ReverseSwapError::generic("Not implemented"))
     }
 
+    async fn create_taproot_reverse_swap_on_remote(
+        &self,
+        _amount_sat: u64,
+        _preimage_hash_hex: String,
+        _claim_pubkey: String,
+    ) -> ReverseSwapResult<BoltzApiCreateTaprootReverseSwapResponse> {
+        Err(ReverseSwapError::generic("Not implemented"))
+    }
+
+    async fn get_claim_partial_signature(
+        &self,
+        _id: String,
+        _index: u32,
+        _claim_tx_hex: String,
+        _preimage_hex: String,
+        _pub_nonce_hex: String,
+    ) -> ReverseSwapResult<BoltzApiPartialSignature> {
+        Err(ReverseSwapError::generic("Not implemented"))
+    }
+
     async fn get_boltz_status(&self, _id: String) -> ReverseSwapResult<BoltzApiReverseSwapStatus> {
         Err(ReverseSwapError::generic("Not implemented"))
     }
@@ -240,6 +294,35 @@ impl ChainService for MockChainService {
     }
 }
 
+/// Lets a test change the chain while the SDK uses it
+#[tonic::async_trait]
+impl ChainService for std::sync::Mutex<MockChainService> {
+    async fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
+        let chain_service = self.lock().unwrap().clone();
+        chain_service.recommended_fees().await
+    }
+
+    async fn address_transactions(&self, address: String) -> SdkResult<Vec<OnchainTx>> {
+        let chain_service = self.lock().unwrap().clone();
+        chain_service.address_transactions(address).await
+    }
+
+    async fn current_tip(&self) -> SdkResult<u32> {
+        let chain_service = self.lock().unwrap().clone();
+        chain_service.current_tip().await
+    }
+
+    async fn transaction_outspends(&self, txid: String) -> SdkResult<Vec<Outspend>> {
+        let chain_service = self.lock().unwrap().clone();
+        chain_service.transaction_outspends(txid).await
+    }
+
+    async fn broadcast_transaction(&self, tx: Vec<u8>) -> SdkResult<String> {
+        let chain_service = self.lock().unwrap().clone();
+        chain_service.broadcast_transaction(tx).await
+    }
+}
+
 impl TryFrom<Payment> for crate::models::PaymentResponse {
     type Error = anyhow::Error;
 
@@ -285,6 +368,9 @@ impl Receiver for MockReceiver {
             ln_invoice: parse_invoice(&self.bolt11)?,
             opening_fee_params: _request.opening_fee_params,
             opening_fee_msat: None,
+            bolt11_length: self.bolt11.len() as u32,
+            fiat_amount: None,
+            fiat_rate: None,
         })
     }
     async fn wrap_node_invoice(
@@ -307,6 +393,21 @@ pub struct MockNodeAPI {
     node_state: NodeState,
     on_send_custom_message: Box<dyn Fn(CustomMessage) -> NodeResult<()> + Sync + Send>,
     on_stream_custom_messages: Mutex<mpsc::Receiver<CustomMessage>>,
+    pub num_pull_changed: std::sync::Mutex<u32>,
+    /// Whether the peers can't be pinged, until [NodeAPI::connect_peer] is called
+    pub peers_offline: std::sync::Mutex<bool>,
+    /// The channels of the node, see [mock_channel]
+    pub channels: std::sync::Mutex<Vec<ChannelDetails>>,
+    /// The channel id and `force` flag of each [NodeAPI::close_channel] call
+    pub closed_channels: std::sync::Mutex<Vec<(String, bool)>>,
+    /// The fallback address of each [NodeAPI::create_invoice] call
+    pub invoice_fallback_addresses: std::sync::Mutex<Vec<Option<String>>>,
+    /// Whether [NodeAPI::new_onchain_address] fails
+    pub onchain_address_unavailable: std::sync::Mutex<bool>,
+    /// How long [NodeAPI::send_payment] takes to complete
+    pub send_payment_delay: std::sync::Mutex<Duration>,
+    /// The routing fee of the payments sent with [NodeAPI::send_payment]
+    pub send_payment_fee_msat: std::sync::Mutex<u64>,
 }
 
 #[tonic::async_trait]
@@ -320,6 +421,10 @@ impl NodeAPI for MockNodeAPI {
     }
 
     async fn create_invoice(&self, request: CreateInvoiceRequest) -> NodeResult<String> {
+        self.invoice_fallback_addresses
+            .lock()
+            .unwrap()
+            .push(request.fallback_address);
         let invoice = create_invoice(
             request.description,
             request.amount_msat,
@@ -338,6 +443,9 @@ impl NodeAPI for MockNodeAPI {
         _sync_state: Option<Value>,
         _match_local_balance: bool,
     ) -> NodeResult<SyncResponse> {
+        *self.num_pull_changed.lock().unwrap() += 1;
+        // Let concurrent calls run, as a real node would
+        tokio::task::yield_now().await;
         Ok(SyncResponse {
             sync_state: Value::Null,
             node_state: self.node_state.clone(),
@@ -349,7 +457,26 @@ impl NodeAPI for MockNodeAPI {
                 .cloned()
                 .flat_map(TryInto::try_into)
                 .collect(),
-            channels: Vec::new(),
+            channels: self
+                .channels
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|c| Channel {
+                    funding_txid: c.funding_txid.clone(),
+                    short_channel_id: c.short_channel_id.clone(),
+                    state: c.state.clone(),
+                    spendable_msat: c.spendable_msat,
+                    local_balance_msat: c.local_balance_msat,
+                    receivable_msat: c.receivable_msat,
+                    closed_at: None,
+                    funding_outnum: Some(0),
+                    alias_local: None,
+                    alias_remote: None,
+                    closing_txid: None,
+                    htlcs: vec![],
+                })
+                .collect(),
         })
     }
 
@@ -357,14 +484,38 @@ impl NodeAPI for MockNodeAPI {
         Err(NodeError::Generic("Not implemented".to_string()))
     }
 
+    async fn probe_payment(
+        &self,
+        _node_id: Vec<u8>,
+        amount_msat: u64,
+        _route_hint: Option<RouteHintHop>,
+        _final_cltv_delta: u64,
+        _timeout_sec: u32,
+    ) -> NodeResult<ProbeResult> {
+        Ok(ProbeResult {
+            fee_msat: amount_msat / 1000,
+            hops: 2,
+            reachable: true,
+            failure: None,
+        })
+    }
+
     async fn send_payment(
         &self,
         bolt11: String,
         _amount_msat: Option<u64>,
         _label: Option<String>,
+        _exclude: Vec<String>,
+        _limits: PaymentLimits,
     ) -> NodeResult<Payment> {
-        let payment = self.add_dummy_payment_for(bolt11, None, None).await?;
-        Ok(payment)
+        let delay = *self.send_payment_delay.lock().unwrap();
+        sleep(delay).await;
+        let inv = bolt11
+            .parse::<crate::lightning_invoice::Bolt11Invoice>()
+            .map_err(|e| NodeError::Generic(e.to_string()))?;
+        let fee_msat = *self.send_payment_fee_msat.lock().unwrap();
+        self.add_dummy_payment_with_fee(inv, None, None, fee_msat)
+            .await
     }
 
     async fn send_trampoline_payment(
@@ -373,6 +524,7 @@ impl NodeAPI for MockNodeAPI {
         _amount_msat: u64,
         _label: Option<String>,
         _trampoline_id: Vec<u8>,
+        _limits: PaymentLimits,
     ) -> NodeResult<Payment> {
         let payment = self.add_dummy_payment_for(bolt11, None, None).await?;
         Ok(payment)
@@ -389,6 +541,17 @@ impl NodeAPI for MockNodeAPI {
         Ok(payment)
     }
 
+    async fn pay_offer(
+        &self,
+        _offer: String,
+        _amount_msat: Option<u64>,
+        _payer_note: Option<String>,
+        _label: Option<String>,
+    ) -> NodeResult<Payment> {
+        let payment = self.add_dummy_payment_rand().await?;
+        Ok(payment)
+    }
+
     async fn node_id(&self) -> NodeResult<String> {
         Ok("".to_string())
     }
@@ -397,6 +560,7 @@ impl NodeAPI for MockNodeAPI {
         &self,
         _to_address: String,
         _sat_per_vbyte: u32,
+        _amount_sat: Option<u64>,
     ) -> NodeResult<Vec<u8>> {
         Ok(rand_vec_u8(32))
     }
@@ -408,14 +572,39 @@ impl NodeAPI for MockNodeAPI {
         Err(NodeError::Generic("Not implemented".to_string()))
     }
 
+    async fn bump_redeem_onchain_funds(
+        &self,
+        _txid: Vec<u8>,
+        _to_address: String,
+        _sat_per_vbyte: u32,
+        _amount_sat: Option<u64>,
+    ) -> NodeResult<Vec<u8>> {
+        Ok(rand_vec_u8(32))
+    }
+
+    async fn new_onchain_address(&self) -> NodeResult<String> {
+        if *self.onchain_address_unavailable.lock().unwrap() {
+            return Err(NodeError::Generic("No onchain address".to_string()));
+        }
+        Ok("bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".to_string())
+    }
+
     async fn start_signer(&self, _shutdown: mpsc::Receiver<()>) {}
 
     async fn start_keep_alive(&self, _shutdown: watch::Receiver<()>) {}
 
     async fn connect_peer(&self, _node_id: String, _addr: String) -> NodeResult<()> {
+        *self.peers_offline.lock().unwrap() = false;
         Ok(())
     }
 
+    async fn ping_peer(&self, _node_id: String) -> NodeResult<()> {
+        match *self.peers_offline.lock().unwrap() {
+            true => Err(NodeError::generic("Peer not connected")),
+            false => Ok(()),
+        }
+    }
+
     async fn sign_message(&self, _message: &str) -> NodeResult<String> {
         Ok("".to_string())
     }
@@ -433,8 +622,40 @@ impl NodeAPI for MockNodeAPI {
         Ok(sign_invoice(invoice))
     }
 
-    async fn close_peer_channels(&self, _node_id: String) -> NodeResult<Vec<String>> {
-        Ok(vec![])
+    async fn close_peer_channels(&self, node_id: String) -> NodeResult<Vec<String>> {
+        Ok(self
+            .channels
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.peer_id == node_id)
+            .map(|_| rand_string(64))
+            .collect())
+    }
+
+    async fn list_channels(&self) -> NodeResult<Vec<ChannelDetails>> {
+        Ok(self.channels.lock().unwrap().clone())
+    }
+
+    async fn close_channel(&self, channel_id: String, force: bool) -> NodeResult<Option<String>> {
+        self.closed_channels
+            .lock()
+            .unwrap()
+            .push((channel_id, force));
+        Ok(Some(rand_string(64)))
+    }
+
+    async fn open_channel(
+        &self,
+        _node_id: String,
+        _amount_sat: u64,
+        _announce: bool,
+        _sat_per_vbyte: Option<u32>,
+    ) -> NodeResult<OpenChannelResponse> {
+        Ok(OpenChannelResponse {
+            channel_id: rand_string(64),
+            funding_txid: rand_string(64),
+        })
     }
     async fn stream_incoming_payments(
         &self,
@@ -469,15 +690,41 @@ impl NodeAPI for MockNodeAPI {
         Err(NodeError::Generic("Not implemented".to_string()))
     }
 
-    async fn derive_bip32_key(&self, _path: Vec<ChildNumber>) -> NodeResult<ExtendedPrivKey> {
-        Ok(ExtendedPrivKey::new_master(Network::Bitcoin, &[])?)
+    async fn derive_xpub(&self, path: Vec<ChildNumber>) -> NodeResult<ExtendedPubKey> {
+        Ok(test_master_key().derive_xpub(&path)?)
     }
 
-    async fn legacy_derive_bip32_key(
+    async fn sign_ecdsa(
         &self,
-        _path: Vec<ChildNumber>,
-    ) -> NodeResult<ExtendedPrivKey> {
-        Ok(ExtendedPrivKey::new_master(Network::Bitcoin, &[])?)
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<ecdsa::Signature> {
+        Ok(test_master_key().sign_ecdsa(&path, &message)?)
+    }
+
+    async fn sign_taproot(
+        &self,
+        path: Vec<ChildNumber>,
+        message: Message,
+    ) -> NodeResult<schnorr::Signature> {
+        Ok(test_master_key().sign_taproot(&path, &message)?)
+    }
+
+    async fn ecdh(&self, path: Vec<ChildNumber>, public_key: PublicKey) -> NodeResult<[u8; 32]> {
+        Ok(test_master_key().ecdh(&path, &public_key)?)
+    }
+
+    async fn hmac_sha256(&self, path: Vec<ChildNumber>, input: Vec<u8>) -> NodeResult<[u8; 32]> {
+        Ok(test_master_key().hmac_sha256(&path, &input)?)
+    }
+
+    async fn derive_symmetric_key(&self, path: Vec<ChildNumber>) -> NodeResult<[u8; 32]> {
+        Ok(test_master_key().derive_symmetric_key(&path)?)
+    }
+
+    async fn legacy_derive_symmetric_key(&self, _path: Vec<ChildNumber>) -> NodeResult<[u8; 32]> {
+        // No backup or credentials of an earlier SDK version to decrypt in the tests
+        Ok([0; 32])
     }
 
     async fn send_custom_message(&self, message: CustomMessage) -> NodeResult<()> {
@@ -512,6 +759,11 @@ impl NodeAPI for MockNodeAPI {
     }
 }
 
+/// The master key of the keys derived by [MockNodeAPI]
+pub(crate) fn test_master_key() -> MasterKey {
+    MasterKey::new(Network::Bitcoin, &[]).unwrap()
+}
+
 impl MockNodeAPI {
     pub fn new(node_state: NodeState) -> Self {
         Self {
@@ -522,6 +774,14 @@ impl MockNodeAPI {
                 let (_, rx) = mpsc::channel(1);
                 Mutex::new(rx)
             },
+            num_pull_changed: std::sync::Mutex::new(0),
+            peers_offline: std::sync::Mutex::new(false),
+            channels: std::sync::Mutex::new(vec![]),
+            closed_channels: std::sync::Mutex::new(vec![]),
+            invoice_fallback_addresses: std::sync::Mutex::new(vec![]),
+            onchain_address_unavailable: std::sync::Mutex::new(false),
+            send_payment_delay: std::sync::Mutex::new(Duration::ZERO),
+            send_payment_fee_msat: std::sync::Mutex::new(0),
         }
     }
     /// Creates a (simulated) payment for the specified BOLT11 and adds it to a test-specific
@@ -556,12 +816,26 @@ impl MockNodeAPI {
         inv: crate::lightning_invoice::Bolt11Invoice,
         preimage: Option<sha256::Hash>,
         status: Option<PayStatus>,
+    ) -> NodeResult<Payment> {
+        self.add_dummy_payment_with_fee(inv, preimage, status, 0)
+            .await
+    }
+
+    /// Adds a dummy payment, sent with a routing fee of `fee_msat`.
+    pub(crate) async fn add_dummy_payment_with_fee(
+        &self,
+        inv: crate::lightning_invoice::Bolt11Invoice,
+        preimage: Option<sha256::Hash>,
+        status: Option<PayStatus>,
+        fee_msat: u64,
     ) -> NodeResult<Payment> {
         let gl_payment = gl_client::pb::cln::ListpaysPays {
             payment_hash: hex::decode(inv.payment_hash().to_hex())?,
             bolt11: Some(inv.to_string()),
             amount_msat: inv.amount_milli_satoshis().map(|msat| Amount { msat }),
-            amount_sent_msat: inv.amount_milli_satoshis().map(|msat| Amount { msat }),
+            amount_sent_msat: inv.amount_milli_satoshis().map(|msat| Amount {
+                msat: msat + fee_msat,
+            }),
             preimage: match preimage {
                 Some(preimage) => Some(hex::decode(preimage.to_hex())?),
                 None => Some(rand_vec_u8(32)),
@@ -692,6 +966,60 @@ impl LspAPI for MockBreezServer {
     }
 }
 
+/// An [LspAPI] which is the used LSP of the node and records the webhook URLs registered for
+/// payment notifications
+#[derive(Default)]
+pub(crate) struct MockNotificationLspAPI {
+    pub registered_webhooks: std::sync::Mutex<Vec<String>>,
+}
+
+#[tonic::async_trait]
+impl LspAPI for MockNotificationLspAPI {
+    async fn list_lsps(&self, node_pubkey: String) -> SdkResult<Vec<LspInformation>> {
+        MockBreezServer {}.list_lsps(node_pubkey).await
+    }
+
+    async fn list_used_lsps(&self, node_pubkey: String) -> SdkResult<Vec<LspInformation>> {
+        MockBreezServer {}.list_lsps(node_pubkey).await
+    }
+
+    async fn register_payment_notifications(
+        &self,
+        _lsp_id: String,
+        _lsp_pubkey: Vec<u8>,
+        webhook_url: String,
+        _webhook_url_signature: String,
+    ) -> SdkResult<grpc::RegisterPaymentNotificationResponse> {
+        self.registered_webhooks.lock().unwrap().push(webhook_url);
+        Ok(grpc::RegisterPaymentNotificationResponse {})
+    }
+
+    async fn unregister_payment_notifications(
+        &self,
+        _lsp_id: String,
+        _lsp_pubkey: Vec<u8>,
+        webhook_url: String,
+        _webhook_url_signature: String,
+    ) -> SdkResult<grpc::RemovePaymentNotificationResponse> {
+        self.registered_webhooks
+            .lock()
+            .unwrap()
+            .retain(|url| url != &webhook_url);
+        Ok(grpc::RemovePaymentNotificationResponse {})
+    }
+
+    async fn register_payment(
+        &self,
+        lsp_id: String,
+        lsp_pubkey: Vec<u8>,
+        payment_info: grpc::PaymentInformation,
+    ) -> SdkResult<grpc::RegisterPaymentReply> {
+        MockBreezServer {}
+            .register_payment(lsp_id, lsp_pubkey, payment_info)
+            .await
+    }
+}
+
 #[tonic::async_trait]
 impl FiatAPI for MockBreezServer {
     async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>> {
@@ -706,13 +1034,15 @@ impl FiatAPI for MockBreezServer {
     }
 }
 
+#[cfg(feature = "buy-bitcoin")]
 pub struct MockBuyBitcoinService {}
 
+#[cfg(feature = "buy-bitcoin")]
 #[tonic::async_trait]
 impl BuyBitcoinApi for MockBuyBitcoinService {
     async fn buy_bitcoin(
         &self,
-        _provider: BuyBitcoinProvider,
+        _provider: crate::BuyBitcoinProvider,
         swap_info: &SwapInfo,
         _redirect_url: Option<String>,
     ) -> Result<String> {
@@ -722,6 +1052,19 @@ impl BuyBitcoinApi for MockBuyBitcoinService {
             format!("{:.8}", swap_info.max_allowed_deposit as f64 / 100000000.0).as_str(),
         ))
     }
+
+    async fn fetch_quote(
+        &self,
+        _provider: crate::BuyBitcoinProvider,
+        fiat_amount: &crate::FiatAmount,
+    ) -> Result<crate::BuyBitcoinQuote> {
+        Ok(crate::BuyBitcoinQuote {
+            fiat_currency: fiat_amount.currency.clone(),
+            fiat_amount: fiat_amount.amount,
+            fees_fiat: fiat_amount.amount / 100.0,
+            amount_sat: (fiat_amount.amount * 1_000.0) as u64,
+        })
+    }
 }
 
 pub(crate) fn rand_invoice_with_description_hash(
@@ -759,6 +1102,27 @@ pub(crate) fn rand_invoice_with_description_hash_and_preimage(
         .build_signed(|hash| Secp256k1::new().sign_ecdsa_recoverable(hash, &private_key))?)
 }
 
+/// An open channel with `peer_id`, to add to [MockNodeAPI::channels]
+pub(crate) fn mock_channel(peer_id: &str, capacity_msat: u64) -> ChannelDetails {
+    ChannelDetails {
+        channel_id: rand_string(64),
+        peer_id: peer_id.to_string(),
+        peer_connected: true,
+        short_channel_id: Some("1x0x0".to_string()),
+        funding_txid: rand_string(64),
+        state: ChannelState::Opened,
+        capacity_msat,
+        local_balance_msat: capacity_msat / 2,
+        remote_balance_msat: capacity_msat / 2,
+        spendable_msat: capacity_msat / 2,
+        receivable_msat: capacity_msat / 2,
+        reserve_msat: 0,
+        htlcs_out_msat: 0,
+        htlcs_in_msat: 0,
+        dust_htlcs_msat: 0,
+    }
+}
+
 pub fn rand_string(len: usize) -> String {
     Alphanumeric.sample_string(&mut rand::thread_rng(), len)
 }
@@ -779,6 +1143,7 @@ pub fn create_test_config() -> crate::models::Config {
     let mut conf = Config {
         default_lsp_id: Some(String::from("03cea51f-b654-4fb0-8e82-eca137f236a0")),
         chainnotifier_url: "http://test-chainnotifier.local".to_string(),
+        lnurl_server_url: "http://test-lnurl-server.local".to_string(),
         ..Config::production(
             "".into(),
             crate::NodeConfig::Greenlight {
@@ -835,6 +1200,21 @@ pub fn create_invoice(
     parse_invoice(&sign_invoice(raw_invoice)).unwrap()
 }
 
+/// An invoice that expired an hour ago
+pub(crate) fn create_expired_invoice(amount_msat: u64) -> String {
+    let raw_invoice = InvoiceBuilder::new(Currency::Bitcoin)
+        .description("expired".to_string())
+        .payment_hash(sha256::Hash::hash(&rand::thread_rng().gen::<[u8; 32]>()))
+        .timestamp(SystemTime::now() - Duration::from_secs(7200))
+        .amount_milli_satoshis(amount_msat)
+        .expiry_time(Duration::from_secs(3600))
+        .payment_secret(PaymentSecret(rand::thread_rng().gen::<[u8; 32]>()))
+        .min_final_cltv_expiry_delta(32)
+        .build_raw()
+        .unwrap();
+    sign_invoice(raw_invoice)
+}
+
 fn sign_invoice(invoice: RawBolt11Invoice) -> String {
     let secp = Secp256k1::new();
     let (secret_key, _) = secp.generate_keypair(&mut OsRng);
@@ -0,0 +1,461 @@
+//! Canonical inputs with the outputs the SDK is expected to derive from them.
+//!
+//! Binding maintainers can serialize the vectors with [TestVectors::to_json], feed the inputs to
+//! the SDK through their bindings and compare the results with the expected values. Calling
+//! [verify_test_vectors] checks the vectors against this crate.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, ensure, Result};
+use sdk_common::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::bitcoin::hashes::hex::{FromHex, ToHex};
+use crate::bitcoin::hashes::{sha256, Hash};
+use crate::bitcoin::Address;
+use crate::models::FullReverseSwapInfo;
+use crate::swap_in::create_submarine_swap_script;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TestVectors {
+    pub swap_scripts: Vec<SwapScriptVector>,
+    pub reverse_swap_scripts: Vec<ReverseSwapScriptVector>,
+    pub invoices: Vec<InvoiceVector>,
+    pub lnurls: Vec<LnUrlVector>,
+}
+
+impl TestVectors {
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+/// A swap-in script, with the P2WSH address the user sends the funds to.
+///
+/// All byte values are hex encoded.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SwapScriptVector {
+    pub preimage: String,
+    /// SHA256 of the preimage
+    pub expected_payment_hash: String,
+    pub swapper_pubkey: String,
+    pub payer_pubkey: String,
+    pub lock_height: i64,
+    pub network: Network,
+    pub expected_script: String,
+    pub expected_address: String,
+}
+
+/// A reverse swap redeem script, with its P2WSH lockup address.
+///
+/// All byte values are hex encoded.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReverseSwapScriptVector {
+    pub preimage: String,
+    /// SHA256 of the preimage
+    pub expected_preimage_hash: String,
+    pub claim_pubkey: String,
+    pub refund_pubkey: String,
+    pub timeout_block_height: u32,
+    pub network: Network,
+    pub expected_redeem_script: String,
+    pub expected_lockup_address: String,
+}
+
+/// A bolt11 invoice, with the fields expected in the [LNInvoice] it's parsed into
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InvoiceVector {
+    pub bolt11: String,
+    pub expected_network: Network,
+    pub expected_payee_pubkey: String,
+    pub expected_payment_hash: String,
+    pub expected_description: Option<String>,
+    pub expected_amount_msat: Option<u64>,
+    pub expected_timestamp: u64,
+    pub expected_expiry: u64,
+    pub expected_min_final_cltv_expiry_delta: u64,
+}
+
+/// An LNURL, with the response of its endpoint (if it's queried while parsing) and the expected
+/// parse result
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LnUrlVector {
+    pub input: String,
+    pub endpoint_response: Option<String>,
+    pub expected: ExpectedLnUrl,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ExpectedLnUrl {
+    Pay {
+        endpoint: String,
+        domain: String,
+        callback: String,
+        min_sendable: u64,
+        max_sendable: u64,
+        comment_allowed: u16,
+        metadata_str: String,
+    },
+    Auth {
+        domain: String,
+        url: String,
+        k1: String,
+        action: Option<String>,
+    },
+}
+
+pub fn test_vectors() -> TestVectors {
+    TestVectors {
+        swap_scripts: vec![
+            SwapScriptVector {
+                preimage: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f".into(),
+                expected_payment_hash: "630dcd2966c4336691125448bbb25b4ff412a49c732db2c8abc1b8581bd710dd".into(),
+                swapper_pubkey: "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".into(),
+                payer_pubkey: "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5".into(),
+                lock_height: 288,
+                network: Network::Bitcoin,
+                expected_script: "a914ea4beb47def8492389a1e16634795441e1b872458763210279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f8179867022001b2752102c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee568ac".into(),
+                expected_address: "bc1q8365us8k9rduyg3t0vjuvp7v8qycxre5er2kcv0hktx9knrmhvusunmjey".into(),
+            },
+            SwapScriptVector {
+                preimage: "1111111111111111111111111111111111111111111111111111111111111111".into(),
+                expected_payment_hash: "02d449a31fbb267c8f352e9968a79e3e5fc95c1bbeaa502fd6454ebde5a4bedc".into(),
+                swapper_pubkey: "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9".into(),
+                payer_pubkey: "02e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd13".into(),
+                lock_height: 144,
+                network: Network::Testnet,
+                expected_script: "a9144d5209774e22ea63e2987579562d9a4ea3dab62387632102f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f967029000b2752102e493dbf1c10d80f3581e4904930b1404cc6c13900ee0758474fa94abe8c4cd1368ac".into(),
+                expected_address: "tb1qy0y9kkvgftz4pd7g33l3kydahgwutdy2fxu8d393dq8rh5z5e66s5fy5vz".into(),
+            },
+        ],
+        reverse_swap_scripts: vec![
+            ReverseSwapScriptVector {
+                preimage: "2222222222222222222222222222222222222222222222222222222222222222".into(),
+                expected_preimage_hash: "9f72ea0cf49536e3c66c787f705186df9a4378083753ae9536d65b3ad7fcddc4".into(),
+                claim_pubkey: "022f8bde4d1a07209355b4a7250a5c5128e88b84bddc619ab7cba8d569b240efe4".into(),
+                refund_pubkey: "03fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556".into(),
+                timeout_block_height: 800_000,
+                network: Network::Bitcoin,
+                expected_redeem_script: "8201208763a91402c19a42cf7a2af1683ebea406c7eb464e6f4d748821022f8bde4d1a07209355b4a7250a5c5128e88b84bddc619ab7cba8d569b240efe467750300350cb1752103fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a146029755668ac".into(),
+                expected_lockup_address: "bc1qm4p2xze6c4s3ma8dqey2sanez406x7plkgn2rhsqqlmzt2rvmccsxyh2ph".into(),
+            },
+            ReverseSwapScriptVector {
+                preimage: "202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f".into(),
+                expected_preimage_hash: "72dbb7336c76780023f83da4c355f2eeea85733b13d3477697917790c1229084".into(),
+                claim_pubkey: "025cbdf0646e5db4eaa398f365f2ea7a0e3d419b7e0330e39ce92bddedcac4f9bc".into(),
+                refund_pubkey: "022f01e5e15cca351daff3843fb70f3c2f0a1bdd05e5af888a67784ef3e10a2a01".into(),
+                timeout_block_height: 2_500_000,
+                network: Network::Testnet,
+                expected_redeem_script: "8201208763a914d23eb1fb0718e3b57150946736247dfd8559cd7c8821025cbdf0646e5db4eaa398f365f2ea7a0e3d419b7e0330e39ce92bddedcac4f9bc677503a02526b17521022f01e5e15cca351daff3843fb70f3c2f0a1bdd05e5af888a67784ef3e10a2a0168ac".into(),
+                expected_lockup_address: "tb1q840udl6ydlt8ru6jpa7jxhq8q4gv7lu5q077g6pws23chqxhnu5sl904v0".into(),
+            },
+        ],
+        invoices: vec![
+            InvoiceVector {
+                bolt11: "lnbc110n1p38q3gtpp5ypz09jrd8p993snjwnm68cph4ftwp22le34xd4r8ftspwshxhmnsdqqxqyjw5qcqpxsp5htlg8ydpywvsa7h3u4hdn77ehs4z4e844em0apjyvmqfkzqhhd2q9qgsqqqyssqszpxzxt9uuqzymr7zxcdccj5g69s8q7zzjs7sgxn9ejhnvdh6gqjcy22mss2yexunagm5r2gqczh8k24cwrqml3njskm548aruhpwssq9nvrvz".into(),
+                expected_network: Network::Bitcoin,
+                expected_payee_pubkey: "03e06bf1443655b9649999ea913402ea5998034055a717c7a41eb2c1cd16475f9e".into(),
+                expected_payment_hash: "2044f2c86d384a58c27274f7a3e037aa56e0a95fcc6a66d4674ae01742e6bee7".into(),
+                expected_description: Some("".into()),
+                expected_amount_msat: Some(11_000),
+                expected_timestamp: 1_651_524_875,
+                expected_expiry: 604_800,
+                expected_min_final_cltv_expiry_delta: 6,
+            },
+            InvoiceVector {
+                bolt11: "lntb15u1pj53l9tpp5p7kjsjcv3eqa39upytmj6k7ac8rqvdffyqr4um98pq5n4ppwxvnsdpzxysy2umswfjhxum0yppk76twypgxzmnwvyxqrrsscqp79qy9qsqsp53xw4x5ezpzvnheff9mrt0ju72u5a5dnxyh4rq6gtweufv9650d4qwqj3ds5xfg4pxc9h7a2g43fmntr4tt322jzujsycvuvury50u994kzr8539qf658hrp07hyz634qpvkeh378wnvf7lddp2x7yfgyk9cp7f7937".into(),
+                expected_network: Network::Testnet,
+                expected_payee_pubkey: "0348cc1a9479697cd52db445ea74149ad40bb01bb2045a3e8acba21b70f94ab7cf".into(),
+                expected_payment_hash: "0fad284b0c8e41d8978122f72d5bddc1c606352920075e6ca708293a842e3327".into(),
+                expected_description: Some("1 Espresso Coin Panna".into()),
+                expected_amount_msat: Some(1_500_000),
+                expected_timestamp: 1_699_282_091,
+                expected_expiry: 3600,
+                expected_min_final_cltv_expiry_delta: 30,
+            },
+            // BOLT11 spec example: no amount, default expiry and CLTV delta
+            InvoiceVector {
+                bolt11: "lnbc1pvjluezsp5zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygspp5qqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqqqsyqcyq5rqwzqfqypqdpl2pkx2ctnv5sxxmmwwd5kgetjypeh2ursdae8g6twvus8g6rfwvs8qun0dfjkxaq9qrsgq357wnc5r2ueh7ck6q93dj32dlqnls087fxdwk8qakdyafkq3yap9us6v52vjjsrvywa6rt52cm9r9zqt8r2t7mlcwspyetp5h2tztugp9lfyql".into(),
+                expected_network: Network::Bitcoin,
+                expected_payee_pubkey: "03e7156ae33b0a208d0744199163177e909e80176e55d97a2f221ede0f934dd9ad".into(),
+                expected_payment_hash: "0001020304050607080900010203040506070809000102030405060708090102".into(),
+                expected_description: Some("Please consider supporting this project".into()),
+                expected_amount_msat: None,
+                expected_timestamp: 1_496_314_658,
+                expected_expiry: 3600,
+                expected_min_final_cltv_expiry_delta: 18,
+            },
+        ],
+        lnurls: vec![
+            LnUrlVector {
+                input: "lnurl1dp68gurn8ghj7mr0vdskc6r0wd6z7mrww4excttsv9un7um9wdekjmmw84jxywf5x43rvv35xgmr2enrxanr2cfcvsmnwe3jxcukvde48qukgdec89snwde3vfjxvepjxpjnjvtpxd3kvdnxx5crxwpjvyunsephsz36jf".into(),
+                endpoint_response: Some(r#"{"callback":"https://localhost/lnurl-pay/callback/db945b624265fc7f5a8d77f269f7589d789a771bdfd20e91a3cf6f50382a98d7","tag":"payRequest","maxSendable":16000,"minSendable":4000,"metadata":"[[\"text/plain\",\"Coffee\"]]","commentAllowed":144}"#.into()),
+                expected: ExpectedLnUrl::Pay {
+                    endpoint: "https://localhost/lnurl-pay?session=db945b624265fc7f5a8d77f269f7589d789a771bdfd20e91a3cf6f50382a98d7".into(),
+                    domain: "localhost".into(),
+                    callback: "https://localhost/lnurl-pay/callback/db945b624265fc7f5a8d77f269f7589d789a771bdfd20e91a3cf6f50382a98d7".into(),
+                    min_sendable: 4000,
+                    max_sendable: 16000,
+                    comment_allowed: 144,
+                    metadata_str: r#"[["text/plain","Coffee"]]"#.into(),
+                },
+            },
+            LnUrlVector {
+                input: "lnurl1dp68gurn8ghj7mr0vdskc6r0wd6z7mrww4excttvdankjm3lw3skw0tvdankjm3xdvcn6vtp8q6n2dfsx5mrjwtrxdjnqvtzv56rzcnyv3jrxv3sxqmkyenrvv6kve3exv6nqdtyv43nqcmzvdsnvdrzx33rsenxx5unqc3cxgeqgntfgu".into(),
+                endpoint_response: None,
+                expected: ExpectedLnUrl::Auth {
+                    domain: "localhost".into(),
+                    url: "https://localhost/lnurl-login?tag=login&k1=1a855505699c3e01be41bddd32007bfcc5ff93505dec0cbca64b4b8ff590b822".into(),
+                    k1: "1a855505699c3e01be41bddd32007bfcc5ff93505dec0cbca64b4b8ff590b822".into(),
+                    action: None,
+                },
+            },
+            LnUrlVector {
+                input: "lnurl1dp68gurn8ghj7mr0vdskc6r0wd6z7mrww4excttvdankjm3lw3skw0tvdankjm3xdvcn6vtp8q6n2dfsx5mrjwtrxdjnqvtzv56rzcnyv3jrxv3sxqmkyenrvv6kve3exv6nqdtyv43nqcmzvdsnvdrzx33rsenxx5unqc3cxgezvctrw35k7m3awfjkw6tnw3jhys2umys".into(),
+                endpoint_response: None,
+                expected: ExpectedLnUrl::Auth {
+                    domain: "localhost".into(),
+                    url: "https://localhost/lnurl-login?tag=login&k1=1a855505699c3e01be41bddd32007bfcc5ff93505dec0cbca64b4b8ff590b822&action=register".into(),
+                    k1: "1a855505699c3e01be41bddd32007bfcc5ff93505dec0cbca64b4b8ff590b822".into(),
+                    action: Some("register".into()),
+                },
+            },
+        ],
+    }
+}
+
+/// Checks that this crate derives the expected outputs from all the [test_vectors]
+pub async fn verify_test_vectors() -> Result<()> {
+    let vectors = test_vectors();
+    for v in vectors.swap_scripts {
+        verify_swap_script(&v).map_err(|e| anyhow!("Swap script {}: {e}", v.expected_address))?;
+    }
+    for v in vectors.reverse_swap_scripts {
+        verify_reverse_swap_script(&v)
+            .map_err(|e| anyhow!("Reverse swap script {}: {e}", v.expected_lockup_address))?;
+    }
+    for v in vectors.invoices {
+        verify_invoice(&v).map_err(|e| anyhow!("Invoice {}: {e}", v.bolt11))?;
+    }
+    for v in vectors.lnurls {
+        verify_lnurl(&v)
+            .await
+            .map_err(|e| anyhow!("LNURL {}: {e}", v.input))?;
+    }
+    Ok(())
+}
+
+fn verify_swap_script(v: &SwapScriptVector) -> Result<()> {
+    let payment_hash = sha256::Hash::hash(&Vec::<u8>::from_hex(&v.preimage)?);
+    ensure!(
+        payment_hash[..].to_hex() == v.expected_payment_hash,
+        "unexpected payment hash {}",
+        payment_hash[..].to_hex()
+    );
+    let script = create_submarine_swap_script(
+        &payment_hash[..],
+        &Vec::<u8>::from_hex(&v.swapper_pubkey)?,
+        &Vec::<u8>::from_hex(&v.payer_pubkey)?,
+        v.lock_height,
+    )?;
+    ensure!(
+        script.to_hex() == v.expected_script,
+        "unexpected script {}",
+        script.to_hex()
+    );
+    let address = Address::p2wsh(&script, v.network.into()).to_string();
+    ensure!(
+        address == v.expected_address,
+        "unexpected address {address}"
+    );
+    Ok(())
+}
+
+fn verify_reverse_swap_script(v: &ReverseSwapScriptVector) -> Result<()> {
+    let preimage_hash = sha256::Hash::hash(&Vec::<u8>::from_hex(&v.preimage)?);
+    ensure!(
+        preimage_hash[..].to_hex() == v.expected_preimage_hash,
+        "unexpected preimage hash {}",
+        preimage_hash[..].to_hex()
+    );
+    let script = FullReverseSwapInfo::build_expected_reverse_swap_script(
+        preimage_hash[..].to_vec(),
+        Vec::<u8>::from_hex(&v.claim_pubkey)?,
+        Vec::<u8>::from_hex(&v.refund_pubkey)?,
+        v.timeout_block_height,
+    )?;
+    ensure!(
+        script.to_hex() == v.expected_redeem_script,
+        "unexpected redeem script {}",
+        script.to_hex()
+    );
+    let address = Address::p2wsh(&script, v.network.into()).to_string();
+    ensure!(
+        address == v.expected_lockup_address,
+        "unexpected lockup address {address}"
+    );
+    Ok(())
+}
+
+fn verify_invoice(v: &InvoiceVector) -> Result<()> {
+    let invoice = parse_invoice(&v.bolt11)?;
+    ensure!(invoice.network == v.expected_network, "unexpected network");
+    ensure!(
+        invoice.payee_pubkey == v.expected_payee_pubkey,
+        "unexpected payee pubkey {}",
+        invoice.payee_pubkey
+    );
+    ensure!(
+        invoice.payment_hash == v.expected_payment_hash,
+        "unexpected payment hash {}",
+        invoice.payment_hash
+    );
+    ensure!(
+        invoice.description == v.expected_description,
+        "unexpected description {:?}",
+        invoice.description
+    );
+    ensure!(
+        invoice.amount_msat == v.expected_amount_msat,
+        "unexpected amount {:?}",
+        invoice.amount_msat
+    );
+    ensure!(
+        invoice.timestamp == v.expected_timestamp,
+        "unexpected timestamp {}",
+        invoice.timestamp
+    );
+    ensure!(
+        invoice.expiry == v.expected_expiry,
+        "unexpected expiry {}",
+        invoice.expiry
+    );
+    ensure!(
+        invoice.min_final_cltv_expiry_delta == v.expected_min_final_cltv_expiry_delta,
+        "unexpected min final CLTV expiry delta {}",
+        invoice.min_final_cltv_expiry_delta
+    );
+    Ok(())
+}
+
+async fn verify_lnurl(v: &LnUrlVector) -> Result<()> {
+    let mut responses = HashMap::new();
+    if let (ExpectedLnUrl::Pay { endpoint, .. }, Some(response)) =
+        (&v.expected, &v.endpoint_response)
+    {
+        responses.insert(endpoint.clone(), response.clone());
+    }
+    let rest_client = FixedRestClient { responses };
+
+    match (
+        parse_with_rest_client(&rest_client, &v.input, None).await?,
+        &v.expected,
+    ) {
+        (
+            InputType::LnUrlPay { data, .. },
+            ExpectedLnUrl::Pay {
+                domain,
+                callback,
+                min_sendable,
+                max_sendable,
+                comment_allowed,
+                metadata_str,
+                ..
+            },
+        ) => {
+            ensure!(&data.domain == domain, "unexpected domain {}", data.domain);
+            ensure!(
+                &data.callback == callback,
+                "unexpected callback {}",
+                data.callback
+            );
+            ensure!(
+                data.min_sendable == *min_sendable && data.max_sendable == *max_sendable,
+                "unexpected sendable range {}-{}",
+                data.min_sendable,
+                data.max_sendable
+            );
+            ensure!(
+                data.comment_allowed == *comment_allowed,
+                "unexpected comment length {}",
+                data.comment_allowed
+            );
+            ensure!(
+                &data.metadata_str == metadata_str,
+                "unexpected metadata {}",
+                data.metadata_str
+            );
+        }
+        (
+            InputType::LnUrlAuth { data },
+            ExpectedLnUrl::Auth {
+                domain,
+                url,
+                k1,
+                action,
+            },
+        ) => {
+            ensure!(&data.domain == domain, "unexpected domain {}", data.domain);
+            ensure!(&data.url == url, "unexpected url {}", data.url);
+            ensure!(&data.k1 == k1, "unexpected k1 {}", data.k1);
+            ensure!(
+                &data.action == action,
+                "unexpected action {:?}",
+                data.action
+            );
+        }
+        (input_type, _) => return Err(anyhow!("unexpected input type {input_type:?}")),
+    }
+    Ok(())
+}
+
+/// Answers the GET requests with the endpoint responses of the vectors, so no network is needed
+struct FixedRestClient {
+    responses: HashMap<String, String>,
+}
+
+#[tonic::async_trait]
+impl RestClient for FixedRestClient {
+    async fn get(&self, url: &str) -> Result<(String, u16), ServiceConnectivityError> {
+        match self.responses.get(url) {
+            Some(response) => Ok((response.clone(), 200)),
+            None => Err(ServiceConnectivityError::new(
+                ServiceConnectivityErrorKind::Other,
+                format!("No response for {url}"),
+            )),
+        }
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        _headers: Option<HashMap<String, String>>,
+        _body: Option<String>,
+    ) -> Result<(String, u16), ServiceConnectivityError> {
+        Err(ServiceConnectivityError::new(
+            ServiceConnectivityErrorKind::Other,
+            format!("No response for {url}"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{test_vectors, verify_test_vectors, TestVectors};
+
+    #[tokio::test]
+    async fn test_verify_test_vectors() -> Result<()> {
+        verify_test_vectors().await
+    }
+
+    #[test]
+    fn test_vectors_json_round_trip() -> Result<()> {
+        let json = test_vectors().to_json()?;
+        let vectors: TestVectors = serde_json::from_str(&json)?;
+        assert_eq!(vectors.to_json()?, json);
+        Ok(())
+    }
+}
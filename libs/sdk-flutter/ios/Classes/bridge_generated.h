@@ -7,6 +7,14 @@ typedef struct _Dart_Handle* Dart_Handle;
 
 #define INVOICE_PAYMENT_FEE_EXPIRY_SECONDS (60 * 60)
 
+#define DEFAULT_MAX_ROUTE_HINTS 3
+
+#define DEFAULT_OUTBOX_EXPIRY_SECS ((60 * 60) * 24)
+
+#define DEFAULT_SEND_PAYMENTS_CONCURRENCY 4
+
+#define MAX_PAYMENT_AMOUNT_MSAT 4294967000
+
 #define ESTIMATED_CLAIM_TX_VSIZE 138
 
 #define ESTIMATED_LOCKUP_TX_VSIZE 153
@@ -26,6 +34,119 @@ typedef struct wire_uint_8_list {
   int32_t len;
 } wire_uint_8_list;
 
+typedef struct wire_ChainServiceConfig_Esplora {
+  struct wire_uint_8_list *url;
+} wire_ChainServiceConfig_Esplora;
+
+typedef struct wire_ChainServiceConfig_Electrum {
+  struct wire_uint_8_list *url;
+} wire_ChainServiceConfig_Electrum;
+
+typedef union ChainServiceConfigKind {
+  struct wire_ChainServiceConfig_Esplora *Esplora;
+  struct wire_ChainServiceConfig_Electrum *Electrum;
+} ChainServiceConfigKind;
+
+typedef struct wire_ChainServiceConfig {
+  int32_t tag;
+  union ChainServiceConfigKind *kind;
+} wire_ChainServiceConfig;
+
+typedef struct wire_BackupTransportConfig_S3 {
+  struct wire_uint_8_list *endpoint;
+  struct wire_uint_8_list *region;
+  struct wire_uint_8_list *bucket;
+  struct wire_uint_8_list *access_key_id;
+  struct wire_uint_8_list *secret_access_key;
+} wire_BackupTransportConfig_S3;
+
+typedef struct wire_BackupTransportConfig_WebDav {
+  struct wire_uint_8_list *url;
+  struct wire_uint_8_list *username;
+  struct wire_uint_8_list *password;
+} wire_BackupTransportConfig_WebDav;
+
+typedef union BackupTransportConfigKind {
+  struct wire_BackupTransportConfig_S3 *S3;
+  struct wire_BackupTransportConfig_WebDav *WebDav;
+} BackupTransportConfigKind;
+
+typedef struct wire_BackupTransportConfig {
+  int32_t tag;
+  union BackupTransportConfigKind *kind;
+} wire_BackupTransportConfig;
+
+typedef struct wire_ProxyConfig {
+  struct wire_uint_8_list *address;
+  struct wire_uint_8_list *username;
+  struct wire_uint_8_list *password;
+} wire_ProxyConfig;
+
+typedef struct wire_LspPolicy_LowestOpeningFee {
+} wire_LspPolicy_LowestOpeningFee;
+
+typedef struct wire_LspPolicy_LowestProportionalFee {
+} wire_LspPolicy_LowestProportionalFee;
+
+typedef struct wire_LspPolicy_Pinned {
+  struct wire_uint_8_list *lsp_id;
+} wire_LspPolicy_Pinned;
+
+typedef union LspPolicyKind {
+  struct wire_LspPolicy_LowestOpeningFee *LowestOpeningFee;
+  struct wire_LspPolicy_LowestProportionalFee *LowestProportionalFee;
+  struct wire_LspPolicy_Pinned *Pinned;
+} LspPolicyKind;
+
+typedef struct wire_LspPolicy {
+  int32_t tag;
+  union LspPolicyKind *kind;
+} wire_LspPolicy;
+
+typedef struct wire_PartnerFeeDestination_NodeId {
+  struct wire_uint_8_list *node_id;
+} wire_PartnerFeeDestination_NodeId;
+
+typedef struct wire_PartnerFeeDestination_LightningAddress {
+  struct wire_uint_8_list *address;
+} wire_PartnerFeeDestination_LightningAddress;
+
+typedef union PartnerFeeDestinationKind {
+  struct wire_PartnerFeeDestination_NodeId *NodeId;
+  struct wire_PartnerFeeDestination_LightningAddress *LightningAddress;
+} PartnerFeeDestinationKind;
+
+typedef struct wire_PartnerFeeDestination {
+  int32_t tag;
+  union PartnerFeeDestinationKind *kind;
+} wire_PartnerFeeDestination;
+
+typedef struct wire_PartnerFeeConfig {
+  uint32_t fee_bps;
+  struct wire_PartnerFeeDestination destination;
+} wire_PartnerFeeConfig;
+
+typedef struct wire_AutoRefundConfig {
+  struct wire_uint_8_list *to_address;
+  uint32_t *sat_per_vbyte;
+} wire_AutoRefundConfig;
+
+typedef struct wire_StringList {
+  struct wire_uint_8_list **ptr;
+  int32_t len;
+} wire_StringList;
+
+typedef struct wire_SpendPolicy {
+  uint64_t *daily_limit_msat;
+  uint64_t *per_payment_limit_msat;
+  struct wire_StringList *allowed_destinations;
+} wire_SpendPolicy;
+
+typedef struct wire_HtlcExposureLimits {
+  uint64_t *max_inflight_msat;
+  uint32_t *max_htlcs_per_payment;
+} wire_HtlcExposureLimits;
+
 typedef struct wire_GreenlightCredentials {
   struct wire_uint_8_list *developer_key;
   struct wire_uint_8_list *developer_cert;
@@ -40,8 +161,19 @@ typedef struct wire_NodeConfig_Greenlight {
   struct wire_GreenlightNodeConfig *config;
 } wire_NodeConfig_Greenlight;
 
+typedef struct wire_MockNodeConfig {
+  uint64_t channels_balance_msat;
+  uint64_t onchain_balance_msat;
+  uint64_t inbound_liquidity_msat;
+} wire_MockNodeConfig;
+
+typedef struct wire_NodeConfig_Mock {
+  struct wire_MockNodeConfig *config;
+} wire_NodeConfig_Mock;
+
 typedef union NodeConfigKind {
   struct wire_NodeConfig_Greenlight *Greenlight;
+  struct wire_NodeConfig_Mock *Mock;
 } NodeConfigKind;
 
 typedef struct wire_NodeConfig {
@@ -52,14 +184,36 @@ typedef struct wire_NodeConfig {
 typedef struct wire_Config {
   struct wire_uint_8_list *breezserver;
   struct wire_uint_8_list *chainnotifier_url;
+  struct wire_uint_8_list *lnurl_server_url;
   struct wire_uint_8_list *mempoolspace_url;
+  struct wire_ChainServiceConfig *chain_service;
+  struct wire_BackupTransportConfig *backup_transport;
+  struct wire_ProxyConfig *proxy;
   struct wire_uint_8_list *working_dir;
+  struct wire_uint_8_list *db_dir;
+  struct wire_uint_8_list *credentials_dir;
   int32_t network;
   uint32_t payment_timeout_sec;
+  uint32_t max_concurrent_payments;
   struct wire_uint_8_list *default_lsp_id;
+  struct wire_LspPolicy *lsp_policy;
   struct wire_uint_8_list *api_key;
   double maxfee_percent;
   uint64_t exemptfee_msat;
+  struct wire_uint_8_list *fiat_currency;
+  uint64_t *low_outbound_liquidity_threshold_msat;
+  uint64_t *low_inbound_liquidity_threshold_msat;
+  bool use_trampoline;
+  struct wire_PartnerFeeConfig *partner_fee;
+  struct wire_AutoRefundConfig *swap_auto_refund;
+  int32_t memo_privacy;
+  uint64_t *channel_hygiene_window_secs;
+  struct wire_uint_8_list *log_filter;
+  struct wire_uint_8_list *denylist_url;
+  struct wire_uint_8_list *transak_api_key;
+  struct wire_SpendPolicy *spend_policy;
+  struct wire_HtlcExposureLimits *htlc_exposure_limits;
+  uint64_t *service_status_check_interval_secs;
   struct wire_NodeConfig node_config;
 } wire_Config;
 
@@ -67,12 +221,33 @@ typedef struct wire_ConnectRequest {
   struct wire_Config config;
   struct wire_uint_8_list *seed;
   bool *restore_only;
+  bool *register_if_missing;
 } wire_ConnectRequest;
 
 typedef struct wire_ConfigureNodeRequest {
   struct wire_uint_8_list *close_to_address;
 } wire_ConfigureNodeRequest;
 
+typedef struct wire_DeriveEncryptionKeyRequest {
+  struct wire_uint_8_list *purpose;
+  uint32_t index;
+} wire_DeriveEncryptionKeyRequest;
+
+typedef struct wire_ProveAddressOwnershipRequest {
+  struct wire_uint_8_list *address;
+  struct wire_uint_8_list *message;
+} wire_ProveAddressOwnershipRequest;
+
+typedef struct wire_PaymentRequestBundle {
+  struct wire_uint_8_list *invoice;
+  struct wire_uint_8_list *fallback;
+  struct wire_uint_8_list *metadata;
+} wire_PaymentRequestBundle;
+
+typedef struct wire_EncryptedPaymentRequestData {
+  struct wire_uint_8_list *blob;
+} wire_EncryptedPaymentRequestData;
+
 typedef struct wire_SignMessageRequest {
   struct wire_uint_8_list *message;
 } wire_SignMessageRequest;
@@ -83,10 +258,82 @@ typedef struct wire_CheckMessageRequest {
   struct wire_uint_8_list *signature;
 } wire_CheckMessageRequest;
 
+typedef struct wire_DecryptRecoveryBundleRequest {
+  struct wire_uint_8_list *bundle;
+  struct wire_uint_8_list *private_key;
+} wire_DecryptRecoveryBundleRequest;
+
+typedef struct wire_ExportRecoveryBundleRequest {
+  struct wire_uint_8_list *recipient_pubkey;
+  struct wire_uint_8_list *instructions;
+  struct wire_uint_8_list *file_path;
+} wire_ExportRecoveryBundleRequest;
+
 typedef struct wire_StaticBackupRequest {
   struct wire_uint_8_list *working_dir;
 } wire_StaticBackupRequest;
 
+typedef struct wire_UserSettings {
+  struct wire_uint_8_list *preferred_fiat_currency;
+  int32_t *denomination;
+  int32_t *default_feerate_preset;
+  struct wire_uint_8_list *preferred_lsp_id;
+} wire_UserSettings;
+
+typedef struct wire_CloseChannelRequest {
+  struct wire_uint_8_list *channel_id;
+  bool force;
+} wire_CloseChannelRequest;
+
+typedef struct wire_ConnectPeerRequest {
+  struct wire_uint_8_list *node_uri;
+} wire_ConnectPeerRequest;
+
+typedef struct wire_Sat {
+  uint64_t field0;
+} wire_Sat;
+
+typedef struct wire_OpenChannelRequest {
+  struct wire_uint_8_list *node_id;
+  struct wire_Sat amount_sat;
+  bool announce;
+  uint32_t *sat_per_vbyte;
+} wire_OpenChannelRequest;
+
+typedef struct wire_NodeMigrationRequest {
+  bool close_channels;
+} wire_NodeMigrationRequest;
+
+typedef struct wire_MilliSat {
+  uint64_t field0;
+} wire_MilliSat;
+
+typedef struct wire_CreateStaticLnurlPayRequest {
+  struct wire_uint_8_list *url;
+  struct wire_uint_8_list *callback_url;
+  struct wire_uint_8_list *description;
+  struct wire_MilliSat min_sendable_msat;
+  struct wire_MilliSat max_sendable_msat;
+  uint16_t *comment_allowed;
+} wire_CreateStaticLnurlPayRequest;
+
+typedef struct wire_StaticLnurlPayCallbackRequest {
+  struct wire_MilliSat amount_msat;
+  struct wire_uint_8_list *comment;
+} wire_StaticLnurlPayCallbackRequest;
+
+typedef struct wire_CreateLnurlWithdrawVoucherRequest {
+  struct wire_MilliSat amount_msat;
+  uint32_t uses;
+  uint32_t expiry_secs;
+  struct wire_uint_8_list *description;
+} wire_CreateLnurlWithdrawVoucherRequest;
+
+typedef struct wire_RedeemLnurlWithdrawVoucherRequest {
+  struct wire_uint_8_list *id;
+  struct wire_uint_8_list *bolt11;
+} wire_RedeemLnurlWithdrawVoucherRequest;
+
 typedef struct wire_list_payment_type_filter {
   int32_t *ptr;
   int32_t len;
@@ -110,15 +357,61 @@ typedef struct wire_ListPaymentsRequest {
   bool *include_failures;
   uint32_t *offset;
   uint32_t *limit;
+  bool *include_tlv_hex;
+  int32_t *sort_order;
 } wire_ListPaymentsRequest;
 
+typedef struct wire_ListPaymentGroupsRequest {
+  int32_t group_by;
+  struct wire_ListPaymentsRequest payments;
+  uint32_t *limit;
+} wire_ListPaymentGroupsRequest;
+
+typedef struct wire_ExportPaymentsRequest {
+  int32_t format;
+  int64_t *from_timestamp;
+  int64_t *to_timestamp;
+  struct wire_uint_8_list *include_fiat;
+} wire_ExportPaymentsRequest;
+
+typedef struct wire_ImportPaymentsRequest {
+  int32_t format;
+  struct wire_uint_8_list *data;
+} wire_ImportPaymentsRequest;
+
 typedef struct wire_SendPaymentRequest {
   struct wire_uint_8_list *bolt11;
-  bool use_trampoline;
-  uint64_t *amount_msat;
+  bool *use_trampoline;
+  struct wire_MilliSat *amount_msat;
   struct wire_uint_8_list *label;
+  uint32_t *route_hint_index;
+  uint32_t *payment_timeout_sec;
+  double *maxfee_percent;
+  struct wire_MilliSat *exemptfee_msat;
+  struct wire_uint_8_list *cancel_token;
+  bool *skip_denylist;
 } wire_SendPaymentRequest;
 
+typedef struct wire_list_send_payment_request {
+  struct wire_SendPaymentRequest *ptr;
+  int32_t len;
+} wire_list_send_payment_request;
+
+typedef struct wire_SendPaymentsRequest {
+  struct wire_list_send_payment_request *payments;
+  uint32_t *max_concurrency;
+} wire_SendPaymentsRequest;
+
+typedef struct wire_QueuePaymentRequest {
+  struct wire_SendPaymentRequest req;
+  uint64_t *expiry_secs;
+} wire_QueuePaymentRequest;
+
+typedef struct wire_PrepareSendPaymentRequest {
+  struct wire_uint_8_list *bolt11;
+  struct wire_MilliSat *amount_msat;
+} wire_PrepareSendPaymentRequest;
+
 typedef struct wire_TlvEntry {
   uint64_t field_number;
   struct wire_uint_8_list *value;
@@ -131,11 +424,28 @@ typedef struct wire_list_tlv_entry {
 
 typedef struct wire_SendSpontaneousPaymentRequest {
   struct wire_uint_8_list *node_id;
-  uint64_t amount_msat;
+  struct wire_MilliSat amount_msat;
   struct wire_list_tlv_entry *extra_tlvs;
   struct wire_uint_8_list *label;
 } wire_SendSpontaneousPaymentRequest;
 
+typedef struct wire_ProbePaymentRequest {
+  struct wire_uint_8_list *destination;
+  struct wire_MilliSat *amount_msat;
+} wire_ProbePaymentRequest;
+
+typedef struct wire_ValidatePayabilityRequest {
+  struct wire_uint_8_list *bolt11;
+  struct wire_MilliSat *amount_msat;
+} wire_ValidatePayabilityRequest;
+
+typedef struct wire_PayOfferRequest {
+  struct wire_uint_8_list *offer;
+  struct wire_MilliSat *amount_msat;
+  struct wire_uint_8_list *payer_note;
+  struct wire_uint_8_list *label;
+} wire_PayOfferRequest;
+
 typedef struct wire_OpeningFeeParams {
   uint64_t min_msat;
   uint32_t proportional;
@@ -145,16 +455,67 @@ typedef struct wire_OpeningFeeParams {
   struct wire_uint_8_list *promise;
 } wire_OpeningFeeParams;
 
+typedef struct wire_PrepareReceivePaymentRequest {
+  struct wire_MilliSat amount_msat;
+  struct wire_OpeningFeeParams *opening_fee_params;
+  uint32_t *expiry;
+} wire_PrepareReceivePaymentRequest;
+
+typedef struct wire_FiatAmount {
+  struct wire_uint_8_list *currency;
+  double amount;
+} wire_FiatAmount;
+
 typedef struct wire_ReceivePaymentRequest {
-  uint64_t amount_msat;
+  struct wire_MilliSat amount_msat;
   struct wire_uint_8_list *description;
   struct wire_uint_8_list *preimage;
   struct wire_OpeningFeeParams *opening_fee_params;
   bool *use_description_hash;
   uint32_t *expiry;
   uint32_t *cltv;
+  bool *include_fallback_address;
+  uint32_t *max_route_hints;
+  struct wire_FiatAmount *fiat_amount;
 } wire_ReceivePaymentRequest;
 
+typedef struct wire_ReceiveUnifiedRequest {
+  struct wire_Sat amount_sat;
+  struct wire_uint_8_list *description;
+  struct wire_OpeningFeeParams *opening_fee_params;
+} wire_ReceiveUnifiedRequest;
+
+typedef struct wire_RouteHintHop {
+  struct wire_uint_8_list *src_node_id;
+  struct wire_uint_8_list *short_channel_id;
+  uint32_t fees_base_msat;
+  uint32_t fees_proportional_millionths;
+  uint64_t cltv_expiry_delta;
+  uint64_t *htlc_minimum_msat;
+  uint64_t *htlc_maximum_msat;
+} wire_RouteHintHop;
+
+typedef struct wire_list_route_hint_hop {
+  struct wire_RouteHintHop *ptr;
+  int32_t len;
+} wire_list_route_hint_hop;
+
+typedef struct wire_RouteHint {
+  struct wire_list_route_hint_hop *hops;
+} wire_RouteHint;
+
+typedef struct wire_list_route_hint {
+  struct wire_RouteHint *ptr;
+  int32_t len;
+} wire_list_route_hint;
+
+typedef struct wire_AmendInvoiceRequest {
+  struct wire_uint_8_list *bolt11;
+  struct wire_MilliSat *amount_msat;
+  uint32_t *expiry;
+  struct wire_list_route_hint *route_hints;
+} wire_AmendInvoiceRequest;
+
 typedef struct wire_LnUrlPayRequestData {
   struct wire_uint_8_list *callback;
   uint64_t min_sendable;
@@ -174,6 +535,9 @@ typedef struct wire_LnUrlPayRequest {
   struct wire_uint_8_list *comment;
   struct wire_uint_8_list *payment_label;
   bool *validate_success_action_url;
+  uint32_t *pending_timeout_sec;
+  struct wire_uint_8_list *cancel_token;
+  bool *skip_denylist;
 } wire_LnUrlPayRequest;
 
 typedef struct wire_LnUrlWithdrawRequestData {
@@ -197,6 +561,24 @@ typedef struct wire_LnUrlAuthRequestData {
   struct wire_uint_8_list *url;
 } wire_LnUrlAuthRequestData;
 
+typedef struct wire_LnUrlAuthDomain {
+  struct wire_uint_8_list *domain;
+  struct wire_uint_8_list *linking_key;
+  int64_t first_authenticated_at;
+  int64_t last_authenticated_at;
+  uint32_t auth_count;
+} wire_LnUrlAuthDomain;
+
+typedef struct wire_list_ln_url_auth_domain {
+  struct wire_LnUrlAuthDomain *ptr;
+  int32_t len;
+} wire_list_ln_url_auth_domain;
+
+typedef struct wire_LnUrlAuthExport {
+  struct wire_uint_8_list *hashing_key;
+  struct wire_list_ln_url_auth_domain *domains;
+} wire_LnUrlAuthExport;
+
 typedef struct wire_ReportPaymentFailureDetails {
   struct wire_uint_8_list *payment_hash;
   struct wire_uint_8_list *comment;
@@ -215,21 +597,35 @@ typedef struct wire_ReportIssueRequest {
   union ReportIssueRequestKind *kind;
 } wire_ReportIssueRequest;
 
+typedef struct wire_AddFiatRateAlertRequest {
+  struct wire_uint_8_list *currency;
+  int32_t direction;
+  double threshold;
+} wire_AddFiatRateAlertRequest;
+
 typedef struct wire_PrepareOnchainPaymentResponse {
   struct wire_uint_8_list *fees_hash;
   double fees_percentage;
   uint64_t fees_lockup;
   uint64_t fees_claim;
-  uint64_t sender_amount_sat;
-  uint64_t recipient_amount_sat;
+  struct wire_Sat sender_amount_sat;
+  struct wire_Sat recipient_amount_sat;
   uint64_t total_fees;
 } wire_PrepareOnchainPaymentResponse;
 
 typedef struct wire_PayOnchainRequest {
   struct wire_uint_8_list *recipient_address;
   struct wire_PrepareOnchainPaymentResponse prepare_res;
+  struct wire_uint_8_list *cancel_token;
 } wire_PayOnchainRequest;
 
+typedef struct wire_PayOnchainAddressRequest {
+  struct wire_uint_8_list *address;
+  struct wire_Sat *amount_sat;
+  bool drain;
+  int32_t *fee_preference;
+} wire_PayOnchainAddressRequest;
+
 typedef struct wire_ReceiveOnchainRequest {
   struct wire_OpeningFeeParams *opening_fee_params;
 } wire_ReceiveOnchainRequest;
@@ -240,21 +636,38 @@ typedef struct wire_BuyBitcoinRequest {
   struct wire_uint_8_list *redirect_url;
 } wire_BuyBitcoinRequest;
 
+typedef struct wire_FetchBuyBitcoinQuoteRequest {
+  int32_t provider;
+  struct wire_FiatAmount fiat_amount;
+} wire_FetchBuyBitcoinQuoteRequest;
+
 typedef struct wire_RedeemOnchainFundsRequest {
   struct wire_uint_8_list *to_address;
   uint32_t sat_per_vbyte;
+  struct wire_Sat *amount_sat;
 } wire_RedeemOnchainFundsRequest;
 
+typedef struct wire_BumpFeeRequest {
+  struct wire_uint_8_list *txid;
+  uint32_t sat_per_vbyte;
+} wire_BumpFeeRequest;
+
 typedef struct wire_PrepareRedeemOnchainFundsRequest {
   struct wire_uint_8_list *to_address;
   uint32_t sat_per_vbyte;
+  struct wire_Sat *amount_sat;
 } wire_PrepareRedeemOnchainFundsRequest;
 
+typedef struct wire_ExportSwapRefundKeysRequest {
+  bool *include_gap_keys;
+} wire_ExportSwapRefundKeysRequest;
+
 typedef struct wire_PrepareRefundRequest {
   struct wire_uint_8_list *swap_address;
   struct wire_uint_8_list *to_address;
   uint32_t sat_per_vbyte;
   bool *unilateral;
+  bool *include_fee_previews;
 } wire_PrepareRefundRequest;
 
 typedef struct wire_RefundRequest {
@@ -278,17 +691,17 @@ typedef struct wire_ListSwapsRequest {
 } wire_ListSwapsRequest;
 
 typedef struct wire_OpenChannelFeeRequest {
-  uint64_t *amount_msat;
+  struct wire_MilliSat *amount_msat;
   uint32_t *expiry;
 } wire_OpenChannelFeeRequest;
 
 typedef struct wire_ReverseSwapFeesRequest {
-  uint64_t *send_amount_sat;
+  struct wire_Sat *send_amount_sat;
   uint32_t *claim_tx_feerate;
 } wire_ReverseSwapFeesRequest;
 
 typedef struct wire_PrepareOnchainPaymentRequest {
-  uint64_t amount_sat;
+  struct wire_Sat amount_sat;
   int32_t amount_type;
   uint32_t claim_tx_feerate;
 } wire_PrepareOnchainPaymentRequest;
@@ -307,18 +720,38 @@ intptr_t init_frb_dart_api_dl(void *obj);
 
 void wire_connect(int64_t port_, struct wire_ConnectRequest *req);
 
+void wire_connect_ephemeral(int64_t port_, struct wire_Config *config);
+
 void wire_is_initialized(int64_t port_);
 
 void wire_sync(int64_t port_);
 
+void wire_cancel_sync(int64_t port_);
+
+void wire_metrics(int64_t port_);
+
+void wire_sync_partial(int64_t port_);
+
 void wire_node_credentials(int64_t port_);
 
 void wire_node_info(int64_t port_);
 
+void wire_balances(int64_t port_);
+
+void wire_get_balance_breakdown(int64_t port_);
+
 void wire_configure_node(int64_t port_, struct wire_ConfigureNodeRequest *req);
 
 void wire_disconnect(int64_t port_);
 
+void wire_derive_encryption_key(int64_t port_, struct wire_DeriveEncryptionKeyRequest *req);
+
+void wire_prove_address_ownership(int64_t port_, struct wire_ProveAddressOwnershipRequest *req);
+
+void wire_encrypt_payment_request(int64_t port_, struct wire_PaymentRequestBundle *bundle);
+
+void wire_decrypt_payment_request(int64_t port_, struct wire_EncryptedPaymentRequestData *data);
+
 void wire_sign_message(int64_t port_, struct wire_SignMessageRequest *req);
 
 void wire_check_message(int64_t port_, struct wire_CheckMessageRequest *req);
@@ -330,6 +763,14 @@ void wire_default_config(int64_t port_,
                          struct wire_uint_8_list *api_key,
                          struct wire_NodeConfig *node_config);
 
+void wire_sdk_info(int64_t port_);
+
+void wire_decrypt_recovery_bundle(int64_t port_, struct wire_DecryptRecoveryBundleRequest *req);
+
+void wire_export_recovery_bundle(int64_t port_, struct wire_ExportRecoveryBundleRequest *req);
+
+void wire_stop_recovery_bundle_refresh(int64_t port_);
+
 void wire_static_backup(int64_t port_, struct wire_StaticBackupRequest *req);
 
 void wire_service_health_check(int64_t port_, struct wire_uint_8_list *api_key);
@@ -338,69 +779,176 @@ void wire_breez_events_stream(int64_t port_);
 
 void wire_breez_log_stream(int64_t port_);
 
+void wire_set_log_filter(int64_t port_, struct wire_uint_8_list *filter);
+
 void wire_list_lsps(int64_t port_);
 
 void wire_connect_lsp(int64_t port_, struct wire_uint_8_list *lsp_id);
 
 void wire_lsp_id(int64_t port_);
 
+void wire_user_settings(int64_t port_);
+
+void wire_set_user_settings(int64_t port_, struct wire_UserSettings *settings);
+
 void wire_fetch_lsp_info(int64_t port_, struct wire_uint_8_list *id);
 
 void wire_lsp_info(int64_t port_);
 
 void wire_close_lsp_channels(int64_t port_);
 
+void wire_list_channels(int64_t port_);
+
+void wire_close_channel(int64_t port_, struct wire_CloseChannelRequest *req);
+
+void wire_connect_peer(int64_t port_, struct wire_ConnectPeerRequest *req);
+
+void wire_open_channel(int64_t port_, struct wire_OpenChannelRequest *req);
+
+void wire_export_node_migration(int64_t port_, struct wire_NodeMigrationRequest *req);
+
 void wire_register_webhook(int64_t port_, struct wire_uint_8_list *webhook_url);
 
 void wire_unregister_webhook(int64_t port_, struct wire_uint_8_list *webhook_url);
 
+void wire_register_lightning_address(int64_t port_, struct wire_uint_8_list *username);
+
+void wire_lnurl_pay_info(int64_t port_);
+
+void wire_create_static_lnurl_pay(int64_t port_, struct wire_CreateStaticLnurlPayRequest *req);
+
+void wire_static_lnurl_pay_callback(int64_t port_, struct wire_StaticLnurlPayCallbackRequest *req);
+
+void wire_create_lnurl_withdraw_voucher(int64_t port_,
+                                        struct wire_CreateLnurlWithdrawVoucherRequest *req);
+
+void wire_redeem_lnurl_withdraw_voucher(int64_t port_,
+                                        struct wire_RedeemLnurlWithdrawVoucherRequest *req);
+
+void wire_list_lnurl_withdraw_vouchers(int64_t port_);
+
 void wire_backup(int64_t port_);
 
 void wire_backup_status(int64_t port_);
 
+void wire_rotate_backup_key(int64_t port_);
+
+void wire_list_backup_versions(int64_t port_);
+
+void wire_restore_backup(int64_t port_, uint64_t version);
+
 void wire_parse_invoice(int64_t port_, struct wire_uint_8_list *invoice);
 
 void wire_parse_input(int64_t port_, struct wire_uint_8_list *input);
 
 void wire_list_payments(int64_t port_, struct wire_ListPaymentsRequest *req);
 
+void wire_list_payment_groups(int64_t port_, struct wire_ListPaymentGroupsRequest *req);
+
+void wire_export_payments(int64_t port_, struct wire_ExportPaymentsRequest *req);
+
+void wire_import_payments(int64_t port_, struct wire_ImportPaymentsRequest *req);
+
 void wire_payment_by_hash(int64_t port_, struct wire_uint_8_list *hash);
 
+void wire_list_channel_fees_paid(int64_t port_);
+
 void wire_set_payment_metadata(int64_t port_,
                                struct wire_uint_8_list *hash,
                                struct wire_uint_8_list *metadata);
 
 void wire_send_payment(int64_t port_, struct wire_SendPaymentRequest *req);
 
+void wire_send_payments(int64_t port_, struct wire_SendPaymentsRequest *req);
+
+void wire_verify_invoices(int64_t port_, struct wire_StringList *invoices);
+
+void wire_queue_payment(int64_t port_, struct wire_QueuePaymentRequest *req);
+
+void wire_list_pending_outbox(int64_t port_);
+
+void wire_cancel_outbox_payment(int64_t port_, int64_t id);
+
+void wire_prepare_send_payment(int64_t port_, struct wire_PrepareSendPaymentRequest *req);
+
 void wire_send_spontaneous_payment(int64_t port_, struct wire_SendSpontaneousPaymentRequest *req);
 
+void wire_cancel(int64_t port_, struct wire_uint_8_list *cancel_token);
+
+void wire_probe_payment(int64_t port_, struct wire_ProbePaymentRequest *req);
+
+void wire_validate_payability(int64_t port_, struct wire_ValidatePayabilityRequest *req);
+
+void wire_list_payment_probes(int64_t port_);
+
+void wire_pay_offer(int64_t port_, struct wire_PayOfferRequest *req);
+
+void wire_prepare_receive_payment(int64_t port_, struct wire_PrepareReceivePaymentRequest *req);
+
 void wire_receive_payment(int64_t port_, struct wire_ReceivePaymentRequest *req);
 
+void wire_receive_unified(int64_t port_, struct wire_ReceiveUnifiedRequest *req);
+
+void wire_amend_invoice(int64_t port_, struct wire_AmendInvoiceRequest *req);
+
 void wire_lnurl_pay(int64_t port_, struct wire_LnUrlPayRequest *req);
 
 void wire_lnurl_withdraw(int64_t port_, struct wire_LnUrlWithdrawRequest *req);
 
 void wire_lnurl_auth(int64_t port_, struct wire_LnUrlAuthRequestData *req_data);
 
+void wire_list_lnurl_auth_domains(int64_t port_);
+
+void wire_lnurl_auth_linking_key(int64_t port_, struct wire_uint_8_list *domain);
+
+void wire_export_lnurl_auth(int64_t port_);
+
+void wire_import_lnurl_auth(int64_t port_, struct wire_LnUrlAuthExport *export);
+
 void wire_report_issue(int64_t port_, struct wire_ReportIssueRequest *req);
 
+void wire_connection_status(int64_t port_);
+
+void wire_task_status(int64_t port_);
+
+void wire_peer_connectivity(int64_t port_);
+
 void wire_fetch_fiat_rates(int64_t port_);
 
+void wire_fiat_rate_history(int64_t port_,
+                            struct wire_uint_8_list *currency,
+                            int64_t *from_timestamp,
+                            int64_t *to_timestamp);
+
+void wire_add_fiat_rate_alert(int64_t port_, struct wire_AddFiatRateAlertRequest *req);
+
+void wire_list_fiat_rate_alerts(int64_t port_);
+
+void wire_remove_fiat_rate_alert(int64_t port_, int64_t id);
+
 void wire_list_fiat_currencies(int64_t port_);
 
 void wire_pay_onchain(int64_t port_, struct wire_PayOnchainRequest *req);
 
+void wire_pay_onchain_address(int64_t port_, struct wire_PayOnchainAddressRequest *req);
+
 void wire_receive_onchain(int64_t port_, struct wire_ReceiveOnchainRequest *req);
 
 void wire_buy_bitcoin(int64_t port_, struct wire_BuyBitcoinRequest *req);
 
+void wire_fetch_buy_bitcoin_quote(int64_t port_, struct wire_FetchBuyBitcoinQuoteRequest *req);
+
 void wire_redeem_onchain_funds(int64_t port_, struct wire_RedeemOnchainFundsRequest *req);
 
+void wire_bump_fee(int64_t port_, struct wire_BumpFeeRequest *req);
+
 void wire_prepare_redeem_onchain_funds(int64_t port_,
                                        struct wire_PrepareRedeemOnchainFundsRequest *req);
 
 void wire_list_refundables(int64_t port_);
 
+void wire_export_swap_refund_keys(int64_t port_, struct wire_ExportSwapRefundKeysRequest *req);
+
 void wire_prepare_refund(int64_t port_, struct wire_PrepareRefundRequest *req);
 
 void wire_refund(int64_t port_, struct wire_RefundRequest *req);
@@ -409,6 +957,8 @@ void wire_rescan_swaps(int64_t port_);
 
 void wire_redeem_swap(int64_t port_, struct wire_uint_8_list *swap_address);
 
+void wire_verify_swap_address(int64_t port_, struct wire_uint_8_list *swap_address);
+
 void wire_in_progress_swap(int64_t port_);
 
 void wire_list_swaps(int64_t port_, struct wire_ListSwapsRequest *req);
@@ -431,50 +981,142 @@ void wire_execute_command(int64_t port_, struct wire_uint_8_list *command);
 
 void wire_generate_diagnostic_data(int64_t port_);
 
+void wire_diagnostic_snapshot(int64_t port_);
+
+void wire_diff_snapshots(int64_t port_, struct wire_uint_8_list *a, struct wire_uint_8_list *b);
+
+struct wire_StringList *new_StringList_0(int32_t len);
+
+struct wire_AddFiatRateAlertRequest *new_box_autoadd_add_fiat_rate_alert_request_0(void);
+
+struct wire_AmendInvoiceRequest *new_box_autoadd_amend_invoice_request_0(void);
+
+struct wire_AutoRefundConfig *new_box_autoadd_auto_refund_config_0(void);
+
+struct wire_BackupTransportConfig *new_box_autoadd_backup_transport_config_0(void);
+
 bool *new_box_autoadd_bool_0(bool value);
 
+struct wire_BumpFeeRequest *new_box_autoadd_bump_fee_request_0(void);
+
 struct wire_BuyBitcoinRequest *new_box_autoadd_buy_bitcoin_request_0(void);
 
+struct wire_ChainServiceConfig *new_box_autoadd_chain_service_config_0(void);
+
 struct wire_CheckMessageRequest *new_box_autoadd_check_message_request_0(void);
 
+struct wire_CloseChannelRequest *new_box_autoadd_close_channel_request_0(void);
+
+struct wire_Config *new_box_autoadd_config_0(void);
+
 struct wire_ConfigureNodeRequest *new_box_autoadd_configure_node_request_0(void);
 
+struct wire_ConnectPeerRequest *new_box_autoadd_connect_peer_request_0(void);
+
 struct wire_ConnectRequest *new_box_autoadd_connect_request_0(void);
 
+struct wire_CreateLnurlWithdrawVoucherRequest *new_box_autoadd_create_lnurl_withdraw_voucher_request_0(void);
+
+struct wire_CreateStaticLnurlPayRequest *new_box_autoadd_create_static_lnurl_pay_request_0(void);
+
+struct wire_DecryptRecoveryBundleRequest *new_box_autoadd_decrypt_recovery_bundle_request_0(void);
+
+int32_t *new_box_autoadd_denomination_0(int32_t value);
+
+struct wire_DeriveEncryptionKeyRequest *new_box_autoadd_derive_encryption_key_request_0(void);
+
+struct wire_EncryptedPaymentRequestData *new_box_autoadd_encrypted_payment_request_data_0(void);
+
+struct wire_ExportPaymentsRequest *new_box_autoadd_export_payments_request_0(void);
+
+struct wire_ExportRecoveryBundleRequest *new_box_autoadd_export_recovery_bundle_request_0(void);
+
+struct wire_ExportSwapRefundKeysRequest *new_box_autoadd_export_swap_refund_keys_request_0(void);
+
+double *new_box_autoadd_f64_0(double value);
+
+int32_t *new_box_autoadd_feerate_preset_0(int32_t value);
+
+struct wire_FetchBuyBitcoinQuoteRequest *new_box_autoadd_fetch_buy_bitcoin_quote_request_0(void);
+
+struct wire_FiatAmount *new_box_autoadd_fiat_amount_0(void);
+
 struct wire_GreenlightCredentials *new_box_autoadd_greenlight_credentials_0(void);
 
 struct wire_GreenlightNodeConfig *new_box_autoadd_greenlight_node_config_0(void);
 
+struct wire_HtlcExposureLimits *new_box_autoadd_htlc_exposure_limits_0(void);
+
 int64_t *new_box_autoadd_i64_0(int64_t value);
 
+struct wire_ImportPaymentsRequest *new_box_autoadd_import_payments_request_0(void);
+
+struct wire_ListPaymentGroupsRequest *new_box_autoadd_list_payment_groups_request_0(void);
+
 struct wire_ListPaymentsRequest *new_box_autoadd_list_payments_request_0(void);
 
 struct wire_ListSwapsRequest *new_box_autoadd_list_swaps_request_0(void);
 
+struct wire_LnUrlAuthExport *new_box_autoadd_ln_url_auth_export_0(void);
+
 struct wire_LnUrlAuthRequestData *new_box_autoadd_ln_url_auth_request_data_0(void);
 
 struct wire_LnUrlPayRequest *new_box_autoadd_ln_url_pay_request_0(void);
 
 struct wire_LnUrlWithdrawRequest *new_box_autoadd_ln_url_withdraw_request_0(void);
 
+struct wire_LspPolicy *new_box_autoadd_lsp_policy_0(void);
+
+struct wire_MilliSat *new_box_autoadd_milli_sat_0(void);
+
+struct wire_MockNodeConfig *new_box_autoadd_mock_node_config_0(void);
+
 struct wire_NodeConfig *new_box_autoadd_node_config_0(void);
 
+struct wire_NodeMigrationRequest *new_box_autoadd_node_migration_request_0(void);
+
 struct wire_OpenChannelFeeRequest *new_box_autoadd_open_channel_fee_request_0(void);
 
+struct wire_OpenChannelRequest *new_box_autoadd_open_channel_request_0(void);
+
 struct wire_OpeningFeeParams *new_box_autoadd_opening_fee_params_0(void);
 
+struct wire_PartnerFeeConfig *new_box_autoadd_partner_fee_config_0(void);
+
+struct wire_PayOfferRequest *new_box_autoadd_pay_offer_request_0(void);
+
+struct wire_PayOnchainAddressRequest *new_box_autoadd_pay_onchain_address_request_0(void);
+
 struct wire_PayOnchainRequest *new_box_autoadd_pay_onchain_request_0(void);
 
+struct wire_PaymentRequestBundle *new_box_autoadd_payment_request_bundle_0(void);
+
 struct wire_PrepareOnchainPaymentRequest *new_box_autoadd_prepare_onchain_payment_request_0(void);
 
+struct wire_PrepareReceivePaymentRequest *new_box_autoadd_prepare_receive_payment_request_0(void);
+
 struct wire_PrepareRedeemOnchainFundsRequest *new_box_autoadd_prepare_redeem_onchain_funds_request_0(void);
 
 struct wire_PrepareRefundRequest *new_box_autoadd_prepare_refund_request_0(void);
 
+struct wire_PrepareSendPaymentRequest *new_box_autoadd_prepare_send_payment_request_0(void);
+
+struct wire_ProbePaymentRequest *new_box_autoadd_probe_payment_request_0(void);
+
+struct wire_ProveAddressOwnershipRequest *new_box_autoadd_prove_address_ownership_request_0(void);
+
+struct wire_ProxyConfig *new_box_autoadd_proxy_config_0(void);
+
+struct wire_QueuePaymentRequest *new_box_autoadd_queue_payment_request_0(void);
+
 struct wire_ReceiveOnchainRequest *new_box_autoadd_receive_onchain_request_0(void);
 
 struct wire_ReceivePaymentRequest *new_box_autoadd_receive_payment_request_0(void);
 
+struct wire_ReceiveUnifiedRequest *new_box_autoadd_receive_unified_request_0(void);
+
+struct wire_RedeemLnurlWithdrawVoucherRequest *new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0(void);
+
 struct wire_RedeemOnchainFundsRequest *new_box_autoadd_redeem_onchain_funds_request_0(void);
 
 struct wire_RefundRequest *new_box_autoadd_refund_request_0(void);
@@ -485,30 +1127,70 @@ struct wire_ReportPaymentFailureDetails *new_box_autoadd_report_payment_failure_
 
 struct wire_ReverseSwapFeesRequest *new_box_autoadd_reverse_swap_fees_request_0(void);
 
+struct wire_Sat *new_box_autoadd_sat_0(void);
+
 struct wire_SendPaymentRequest *new_box_autoadd_send_payment_request_0(void);
 
+struct wire_SendPaymentsRequest *new_box_autoadd_send_payments_request_0(void);
+
 struct wire_SendSpontaneousPaymentRequest *new_box_autoadd_send_spontaneous_payment_request_0(void);
 
 struct wire_SignMessageRequest *new_box_autoadd_sign_message_request_0(void);
 
+int32_t *new_box_autoadd_sort_order_0(int32_t value);
+
+struct wire_SpendPolicy *new_box_autoadd_spend_policy_0(void);
+
 struct wire_StaticBackupRequest *new_box_autoadd_static_backup_request_0(void);
 
+struct wire_StaticLnurlPayCallbackRequest *new_box_autoadd_static_lnurl_pay_callback_request_0(void);
+
+uint16_t *new_box_autoadd_u16_0(uint16_t value);
+
 uint32_t *new_box_autoadd_u32_0(uint32_t value);
 
 uint64_t *new_box_autoadd_u64_0(uint64_t value);
 
+struct wire_UserSettings *new_box_autoadd_user_settings_0(void);
+
+struct wire_ValidatePayabilityRequest *new_box_autoadd_validate_payability_request_0(void);
+
+struct wire_list_ln_url_auth_domain *new_list_ln_url_auth_domain_0(int32_t len);
+
 struct wire_list_metadata_filter *new_list_metadata_filter_0(int32_t len);
 
 struct wire_list_payment_type_filter *new_list_payment_type_filter_0(int32_t len);
 
+struct wire_list_route_hint *new_list_route_hint_0(int32_t len);
+
+struct wire_list_route_hint_hop *new_list_route_hint_hop_0(int32_t len);
+
+struct wire_list_send_payment_request *new_list_send_payment_request_0(int32_t len);
+
 struct wire_list_swap_status *new_list_swap_status_0(int32_t len);
 
 struct wire_list_tlv_entry *new_list_tlv_entry_0(int32_t len);
 
 struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
 
+union BackupTransportConfigKind *inflate_BackupTransportConfig_S3(void);
+
+union BackupTransportConfigKind *inflate_BackupTransportConfig_WebDav(void);
+
+union ChainServiceConfigKind *inflate_ChainServiceConfig_Esplora(void);
+
+union ChainServiceConfigKind *inflate_ChainServiceConfig_Electrum(void);
+
+union LspPolicyKind *inflate_LspPolicy_Pinned(void);
+
 union NodeConfigKind *inflate_NodeConfig_Greenlight(void);
 
+union NodeConfigKind *inflate_NodeConfig_Mock(void);
+
+union PartnerFeeDestinationKind *inflate_PartnerFeeDestination_NodeId(void);
+
+union PartnerFeeDestinationKind *inflate_PartnerFeeDestination_LightningAddress(void);
+
 union ReportIssueRequestKind *inflate_ReportIssueRequest_PaymentFailure(void);
 
 void free_WireSyncReturn(WireSyncReturn ptr);
@@ -516,54 +1198,120 @@ void free_WireSyncReturn(WireSyncReturn ptr);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
     dummy_var ^= ((int64_t) (void*) wire_connect);
+    dummy_var ^= ((int64_t) (void*) wire_connect_ephemeral);
     dummy_var ^= ((int64_t) (void*) wire_is_initialized);
     dummy_var ^= ((int64_t) (void*) wire_sync);
+    dummy_var ^= ((int64_t) (void*) wire_cancel_sync);
+    dummy_var ^= ((int64_t) (void*) wire_metrics);
+    dummy_var ^= ((int64_t) (void*) wire_sync_partial);
     dummy_var ^= ((int64_t) (void*) wire_node_credentials);
     dummy_var ^= ((int64_t) (void*) wire_node_info);
+    dummy_var ^= ((int64_t) (void*) wire_balances);
+    dummy_var ^= ((int64_t) (void*) wire_get_balance_breakdown);
     dummy_var ^= ((int64_t) (void*) wire_configure_node);
     dummy_var ^= ((int64_t) (void*) wire_disconnect);
+    dummy_var ^= ((int64_t) (void*) wire_derive_encryption_key);
+    dummy_var ^= ((int64_t) (void*) wire_prove_address_ownership);
+    dummy_var ^= ((int64_t) (void*) wire_encrypt_payment_request);
+    dummy_var ^= ((int64_t) (void*) wire_decrypt_payment_request);
     dummy_var ^= ((int64_t) (void*) wire_sign_message);
     dummy_var ^= ((int64_t) (void*) wire_check_message);
     dummy_var ^= ((int64_t) (void*) wire_mnemonic_to_seed);
     dummy_var ^= ((int64_t) (void*) wire_default_config);
+    dummy_var ^= ((int64_t) (void*) wire_sdk_info);
+    dummy_var ^= ((int64_t) (void*) wire_decrypt_recovery_bundle);
+    dummy_var ^= ((int64_t) (void*) wire_export_recovery_bundle);
+    dummy_var ^= ((int64_t) (void*) wire_stop_recovery_bundle_refresh);
     dummy_var ^= ((int64_t) (void*) wire_static_backup);
     dummy_var ^= ((int64_t) (void*) wire_service_health_check);
     dummy_var ^= ((int64_t) (void*) wire_breez_events_stream);
     dummy_var ^= ((int64_t) (void*) wire_breez_log_stream);
+    dummy_var ^= ((int64_t) (void*) wire_set_log_filter);
     dummy_var ^= ((int64_t) (void*) wire_list_lsps);
     dummy_var ^= ((int64_t) (void*) wire_connect_lsp);
     dummy_var ^= ((int64_t) (void*) wire_lsp_id);
+    dummy_var ^= ((int64_t) (void*) wire_user_settings);
+    dummy_var ^= ((int64_t) (void*) wire_set_user_settings);
     dummy_var ^= ((int64_t) (void*) wire_fetch_lsp_info);
     dummy_var ^= ((int64_t) (void*) wire_lsp_info);
     dummy_var ^= ((int64_t) (void*) wire_close_lsp_channels);
+    dummy_var ^= ((int64_t) (void*) wire_list_channels);
+    dummy_var ^= ((int64_t) (void*) wire_close_channel);
+    dummy_var ^= ((int64_t) (void*) wire_connect_peer);
+    dummy_var ^= ((int64_t) (void*) wire_open_channel);
+    dummy_var ^= ((int64_t) (void*) wire_export_node_migration);
     dummy_var ^= ((int64_t) (void*) wire_register_webhook);
     dummy_var ^= ((int64_t) (void*) wire_unregister_webhook);
+    dummy_var ^= ((int64_t) (void*) wire_register_lightning_address);
+    dummy_var ^= ((int64_t) (void*) wire_lnurl_pay_info);
+    dummy_var ^= ((int64_t) (void*) wire_create_static_lnurl_pay);
+    dummy_var ^= ((int64_t) (void*) wire_static_lnurl_pay_callback);
+    dummy_var ^= ((int64_t) (void*) wire_create_lnurl_withdraw_voucher);
+    dummy_var ^= ((int64_t) (void*) wire_redeem_lnurl_withdraw_voucher);
+    dummy_var ^= ((int64_t) (void*) wire_list_lnurl_withdraw_vouchers);
     dummy_var ^= ((int64_t) (void*) wire_backup);
     dummy_var ^= ((int64_t) (void*) wire_backup_status);
+    dummy_var ^= ((int64_t) (void*) wire_rotate_backup_key);
+    dummy_var ^= ((int64_t) (void*) wire_list_backup_versions);
+    dummy_var ^= ((int64_t) (void*) wire_restore_backup);
     dummy_var ^= ((int64_t) (void*) wire_parse_invoice);
     dummy_var ^= ((int64_t) (void*) wire_parse_input);
     dummy_var ^= ((int64_t) (void*) wire_list_payments);
+    dummy_var ^= ((int64_t) (void*) wire_list_payment_groups);
+    dummy_var ^= ((int64_t) (void*) wire_export_payments);
+    dummy_var ^= ((int64_t) (void*) wire_import_payments);
     dummy_var ^= ((int64_t) (void*) wire_payment_by_hash);
+    dummy_var ^= ((int64_t) (void*) wire_list_channel_fees_paid);
     dummy_var ^= ((int64_t) (void*) wire_set_payment_metadata);
     dummy_var ^= ((int64_t) (void*) wire_send_payment);
+    dummy_var ^= ((int64_t) (void*) wire_send_payments);
+    dummy_var ^= ((int64_t) (void*) wire_verify_invoices);
+    dummy_var ^= ((int64_t) (void*) wire_queue_payment);
+    dummy_var ^= ((int64_t) (void*) wire_list_pending_outbox);
+    dummy_var ^= ((int64_t) (void*) wire_cancel_outbox_payment);
+    dummy_var ^= ((int64_t) (void*) wire_prepare_send_payment);
     dummy_var ^= ((int64_t) (void*) wire_send_spontaneous_payment);
+    dummy_var ^= ((int64_t) (void*) wire_cancel);
+    dummy_var ^= ((int64_t) (void*) wire_probe_payment);
+    dummy_var ^= ((int64_t) (void*) wire_validate_payability);
+    dummy_var ^= ((int64_t) (void*) wire_list_payment_probes);
+    dummy_var ^= ((int64_t) (void*) wire_pay_offer);
+    dummy_var ^= ((int64_t) (void*) wire_prepare_receive_payment);
     dummy_var ^= ((int64_t) (void*) wire_receive_payment);
+    dummy_var ^= ((int64_t) (void*) wire_receive_unified);
+    dummy_var ^= ((int64_t) (void*) wire_amend_invoice);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_pay);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_withdraw);
     dummy_var ^= ((int64_t) (void*) wire_lnurl_auth);
+    dummy_var ^= ((int64_t) (void*) wire_list_lnurl_auth_domains);
+    dummy_var ^= ((int64_t) (void*) wire_lnurl_auth_linking_key);
+    dummy_var ^= ((int64_t) (void*) wire_export_lnurl_auth);
+    dummy_var ^= ((int64_t) (void*) wire_import_lnurl_auth);
     dummy_var ^= ((int64_t) (void*) wire_report_issue);
+    dummy_var ^= ((int64_t) (void*) wire_connection_status);
+    dummy_var ^= ((int64_t) (void*) wire_task_status);
+    dummy_var ^= ((int64_t) (void*) wire_peer_connectivity);
     dummy_var ^= ((int64_t) (void*) wire_fetch_fiat_rates);
+    dummy_var ^= ((int64_t) (void*) wire_fiat_rate_history);
+    dummy_var ^= ((int64_t) (void*) wire_add_fiat_rate_alert);
+    dummy_var ^= ((int64_t) (void*) wire_list_fiat_rate_alerts);
+    dummy_var ^= ((int64_t) (void*) wire_remove_fiat_rate_alert);
     dummy_var ^= ((int64_t) (void*) wire_list_fiat_currencies);
     dummy_var ^= ((int64_t) (void*) wire_pay_onchain);
+    dummy_var ^= ((int64_t) (void*) wire_pay_onchain_address);
     dummy_var ^= ((int64_t) (void*) wire_receive_onchain);
     dummy_var ^= ((int64_t) (void*) wire_buy_bitcoin);
+    dummy_var ^= ((int64_t) (void*) wire_fetch_buy_bitcoin_quote);
     dummy_var ^= ((int64_t) (void*) wire_redeem_onchain_funds);
+    dummy_var ^= ((int64_t) (void*) wire_bump_fee);
     dummy_var ^= ((int64_t) (void*) wire_prepare_redeem_onchain_funds);
     dummy_var ^= ((int64_t) (void*) wire_list_refundables);
+    dummy_var ^= ((int64_t) (void*) wire_export_swap_refund_keys);
     dummy_var ^= ((int64_t) (void*) wire_prepare_refund);
     dummy_var ^= ((int64_t) (void*) wire_refund);
     dummy_var ^= ((int64_t) (void*) wire_rescan_swaps);
     dummy_var ^= ((int64_t) (void*) wire_redeem_swap);
+    dummy_var ^= ((int64_t) (void*) wire_verify_swap_address);
     dummy_var ^= ((int64_t) (void*) wire_in_progress_swap);
     dummy_var ^= ((int64_t) (void*) wire_list_swaps);
     dummy_var ^= ((int64_t) (void*) wire_claim_reverse_swap);
@@ -575,45 +1323,111 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_recommended_fees);
     dummy_var ^= ((int64_t) (void*) wire_execute_command);
     dummy_var ^= ((int64_t) (void*) wire_generate_diagnostic_data);
+    dummy_var ^= ((int64_t) (void*) wire_diagnostic_snapshot);
+    dummy_var ^= ((int64_t) (void*) wire_diff_snapshots);
+    dummy_var ^= ((int64_t) (void*) new_StringList_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_add_fiat_rate_alert_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_amend_invoice_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_auto_refund_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_backup_transport_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_bool_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_bump_fee_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_buy_bitcoin_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_chain_service_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_check_message_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_close_channel_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_configure_node_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_connect_peer_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_connect_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_create_lnurl_withdraw_voucher_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_create_static_lnurl_pay_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_decrypt_recovery_bundle_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_denomination_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_derive_encryption_key_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_encrypted_payment_request_data_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_export_payments_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_export_recovery_bundle_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_export_swap_refund_keys_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_f64_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_feerate_preset_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_fetch_buy_bitcoin_quote_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_fiat_amount_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_greenlight_credentials_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_greenlight_node_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_htlc_exposure_limits_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_i64_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_import_payments_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_list_payment_groups_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_list_payments_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_list_swaps_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_auth_export_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_auth_request_data_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_pay_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_ln_url_withdraw_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_lsp_policy_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_milli_sat_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_mock_node_config_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_node_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_node_migration_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_open_channel_fee_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_open_channel_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_opening_fee_params_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_partner_fee_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_pay_offer_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_pay_onchain_address_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_pay_onchain_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_payment_request_bundle_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_onchain_payment_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_receive_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_redeem_onchain_funds_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_refund_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_prepare_send_payment_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_probe_payment_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_prove_address_ownership_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_proxy_config_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_queue_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_receive_onchain_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_receive_payment_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_receive_unified_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_redeem_onchain_funds_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_refund_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_report_issue_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_report_payment_failure_details_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_reverse_swap_fees_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_sat_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_payment_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_payments_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_send_spontaneous_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_sign_message_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_sort_order_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_spend_policy_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_static_backup_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_static_lnurl_pay_callback_request_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_u16_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u32_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_u64_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_user_settings_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_validate_payability_request_0);
+    dummy_var ^= ((int64_t) (void*) new_list_ln_url_auth_domain_0);
     dummy_var ^= ((int64_t) (void*) new_list_metadata_filter_0);
     dummy_var ^= ((int64_t) (void*) new_list_payment_type_filter_0);
+    dummy_var ^= ((int64_t) (void*) new_list_route_hint_0);
+    dummy_var ^= ((int64_t) (void*) new_list_route_hint_hop_0);
+    dummy_var ^= ((int64_t) (void*) new_list_send_payment_request_0);
     dummy_var ^= ((int64_t) (void*) new_list_swap_status_0);
     dummy_var ^= ((int64_t) (void*) new_list_tlv_entry_0);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list_0);
+    dummy_var ^= ((int64_t) (void*) inflate_BackupTransportConfig_S3);
+    dummy_var ^= ((int64_t) (void*) inflate_BackupTransportConfig_WebDav);
+    dummy_var ^= ((int64_t) (void*) inflate_ChainServiceConfig_Esplora);
+    dummy_var ^= ((int64_t) (void*) inflate_ChainServiceConfig_Electrum);
+    dummy_var ^= ((int64_t) (void*) inflate_LspPolicy_Pinned);
     dummy_var ^= ((int64_t) (void*) inflate_NodeConfig_Greenlight);
+    dummy_var ^= ((int64_t) (void*) inflate_NodeConfig_Mock);
+    dummy_var ^= ((int64_t) (void*) inflate_PartnerFeeDestination_NodeId);
+    dummy_var ^= ((int64_t) (void*) inflate_PartnerFeeDestination_LightningAddress);
     dummy_var ^= ((int64_t) (void*) inflate_ReportIssueRequest_PaymentFailure);
     dummy_var ^= ((int64_t) (void*) free_WireSyncReturn);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
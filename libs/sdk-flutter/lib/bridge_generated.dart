@@ -19,6 +19,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kConnectConstMeta;
 
+  /// See [BreezServices::connect_ephemeral]
+  Future<void> connectEphemeral({required Config config, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConnectEphemeralConstMeta;
+
   /// Check whether node service is initialized or not
   Future<bool> isInitialized({dynamic hint});
 
@@ -29,6 +34,21 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kSyncConstMeta;
 
+  /// See [BreezServices::cancel_sync]
+  Future<bool> cancelSync({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCancelSyncConstMeta;
+
+  /// See [BreezServices::metrics]
+  Future<MetricsSnapshot> metrics({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kMetricsConstMeta;
+
+  /// See [BreezServices::sync_partial]
+  Future<void> syncPartial({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSyncPartialConstMeta;
+
   /// See [BreezServices::node_credentials]
   Future<NodeCredentials?> nodeCredentials({dynamic hint});
 
@@ -39,6 +59,16 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kNodeInfoConstMeta;
 
+  /// See [BreezServices::balances]
+  Future<Balances> balances({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBalancesConstMeta;
+
+  /// See [BreezServices::get_balance_breakdown]
+  Future<BalanceBreakdown> getBalanceBreakdown({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGetBalanceBreakdownConstMeta;
+
   /// See [BreezServices::configure_node]
   Future<void> configureNode({required ConfigureNodeRequest req, dynamic hint});
 
@@ -49,6 +79,29 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kDisconnectConstMeta;
 
+  /// See [BreezServices::derive_encryption_key]
+  Future<DeriveEncryptionKeyResponse> deriveEncryptionKey(
+      {required DeriveEncryptionKeyRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDeriveEncryptionKeyConstMeta;
+
+  /// See [BreezServices::prove_address_ownership]
+  Future<ProveAddressOwnershipResponse> proveAddressOwnership(
+      {required ProveAddressOwnershipRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kProveAddressOwnershipConstMeta;
+
+  /// See [BreezServices::encrypt_payment_request]
+  Future<String> encryptPaymentRequest({required PaymentRequestBundle bundle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kEncryptPaymentRequestConstMeta;
+
+  /// See [BreezServices::decrypt_payment_request]
+  Future<PaymentRequestBundle> decryptPaymentRequest(
+      {required EncryptedPaymentRequestData data, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDecryptPaymentRequestConstMeta;
+
   /// See [BreezServices::sign_message]
   Future<SignMessageResponse> signMessage({required SignMessageRequest req, dynamic hint});
 
@@ -73,6 +126,27 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kDefaultConfigConstMeta;
 
+  /// See [BreezServices::sdk_info]
+  Future<SdkInfo> sdkInfo({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSdkInfoConstMeta;
+
+  /// See [BreezServices::decrypt_recovery_bundle]
+  Future<RecoveryBundle> decryptRecoveryBundle({required DecryptRecoveryBundleRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDecryptRecoveryBundleConstMeta;
+
+  /// See [BreezServices::export_recovery_bundle]
+  Future<ExportRecoveryBundleResponse> exportRecoveryBundle(
+      {required ExportRecoveryBundleRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportRecoveryBundleConstMeta;
+
+  /// See [BreezServices::stop_recovery_bundle_refresh]
+  Future<void> stopRecoveryBundleRefresh({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStopRecoveryBundleRefreshConstMeta;
+
   /// See [BreezServices::static_backup]
   Future<StaticBackupResponse> staticBackup({required StaticBackupRequest req, dynamic hint});
 
@@ -93,6 +167,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kBreezLogStreamConstMeta;
 
+  /// See [BreezServices::set_log_filter]
+  Future<void> setLogFilter({required String filter, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetLogFilterConstMeta;
+
   /// See [BreezServices::list_lsps]
   Future<List<LspInformation>> listLsps({dynamic hint});
 
@@ -108,6 +187,16 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kLspIdConstMeta;
 
+  /// See [BreezServices::user_settings]
+  Future<UserSettings> userSettings({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUserSettingsConstMeta;
+
+  /// See [BreezServices::set_user_settings]
+  Future<void> setUserSettings({required UserSettings settings, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSetUserSettingsConstMeta;
+
   /// See [BreezServices::fetch_lsp_info]
   Future<LspInformation?> fetchLspInfo({required String id, dynamic hint});
 
@@ -123,6 +212,31 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kCloseLspChannelsConstMeta;
 
+  /// See [BreezServices::list_channels]
+  Future<List<ChannelDetails>> listChannels({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListChannelsConstMeta;
+
+  /// See [BreezServices::close_channel]
+  Future<CloseChannelResponse> closeChannel({required CloseChannelRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCloseChannelConstMeta;
+
+  /// See [BreezServices::connect_peer]
+  Future<void> connectPeer({required ConnectPeerRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConnectPeerConstMeta;
+
+  /// See [BreezServices::open_channel]
+  Future<OpenChannelResponse> openChannel({required OpenChannelRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOpenChannelConstMeta;
+
+  /// See [BreezServices::export_node_migration]
+  Future<NodeMigrationState> exportNodeMigration({required NodeMigrationRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportNodeMigrationConstMeta;
+
   Future<void> registerWebhook({required String webhookUrl, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kRegisterWebhookConstMeta;
@@ -131,6 +245,43 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kUnregisterWebhookConstMeta;
 
+  /// See [BreezServices::register_lightning_address]
+  Future<LnurlPayInfo> registerLightningAddress({required String username, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRegisterLightningAddressConstMeta;
+
+  /// See [BreezServices::lnurl_pay_info]
+  Future<LnurlPayInfo?> lnurlPayInfo({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLnurlPayInfoConstMeta;
+
+  /// See [BreezServices::create_static_lnurl_pay]
+  Future<StaticLnurlPay> createStaticLnurlPay({required CreateStaticLnurlPayRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateStaticLnurlPayConstMeta;
+
+  /// See [BreezServices::static_lnurl_pay_callback]
+  Future<String> staticLnurlPayCallback({required StaticLnurlPayCallbackRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kStaticLnurlPayCallbackConstMeta;
+
+  /// See [BreezServices::create_lnurl_withdraw_voucher]
+  Future<LnurlWithdrawVoucher> createLnurlWithdrawVoucher(
+      {required CreateLnurlWithdrawVoucherRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCreateLnurlWithdrawVoucherConstMeta;
+
+  /// See [BreezServices::redeem_lnurl_withdraw_voucher]
+  Future<SendPaymentResponse> redeemLnurlWithdrawVoucher(
+      {required RedeemLnurlWithdrawVoucherRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRedeemLnurlWithdrawVoucherConstMeta;
+
+  /// See [BreezServices::list_lnurl_withdraw_vouchers]
+  Future<List<LnurlWithdrawVoucher>> listLnurlWithdrawVouchers({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListLnurlWithdrawVouchersConstMeta;
+
   /// See [BreezServices::backup]
   Future<void> backup({dynamic hint});
 
@@ -141,6 +292,21 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kBackupStatusConstMeta;
 
+  /// See [BreezServices::rotate_backup_key]
+  Future<void> rotateBackupKey({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRotateBackupKeyConstMeta;
+
+  /// See [BreezServices::list_backup_versions]
+  Future<Uint64List> listBackupVersions({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListBackupVersionsConstMeta;
+
+  /// See [BreezServices::restore_backup]
+  Future<void> restoreBackup({required int version, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRestoreBackupConstMeta;
+
   Future<LNInvoice> parseInvoice({required String invoice, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kParseInvoiceConstMeta;
@@ -154,11 +320,31 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kListPaymentsConstMeta;
 
+  /// See [BreezServices::list_payment_groups]
+  Future<List<PaymentGroup>> listPaymentGroups({required ListPaymentGroupsRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListPaymentGroupsConstMeta;
+
+  /// See [BreezServices::export_payments]
+  Future<String> exportPayments({required ExportPaymentsRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportPaymentsConstMeta;
+
+  /// See [BreezServices::import_payments]
+  Future<ImportPaymentsResponse> importPayments({required ImportPaymentsRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kImportPaymentsConstMeta;
+
   /// See [BreezServices::list_payments]
   Future<Payment?> paymentByHash({required String hash, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kPaymentByHashConstMeta;
 
+  /// See [BreezServices::list_channel_fees_paid]
+  Future<List<ChannelOpeningFee>> listChannelFeesPaid({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListChannelFeesPaidConstMeta;
+
   /// See [BreezServices::set_payment_metadata]
   Future<void> setPaymentMetadata({required String hash, required String metadata, dynamic hint});
 
@@ -169,17 +355,89 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kSendPaymentConstMeta;
 
+  /// See [BreezServices::send_payments]
+  Future<SendPaymentsResponse> sendPayments({required SendPaymentsRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSendPaymentsConstMeta;
+
+  /// See [BreezServices::verify_invoices]
+  Future<List<InvoiceVerificationResult>> verifyInvoices({required List<String> invoices, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kVerifyInvoicesConstMeta;
+
+  /// See [BreezServices::queue_payment]
+  Future<OutboxPayment> queuePayment({required QueuePaymentRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kQueuePaymentConstMeta;
+
+  /// See [BreezServices::list_pending_outbox]
+  Future<List<OutboxPayment>> listPendingOutbox({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListPendingOutboxConstMeta;
+
+  /// See [BreezServices::cancel_outbox_payment]
+  Future<void> cancelOutboxPayment({required int id, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCancelOutboxPaymentConstMeta;
+
+  /// See [BreezServices::prepare_send_payment]
+  Future<PrepareSendPaymentResponse> prepareSendPayment(
+      {required PrepareSendPaymentRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPrepareSendPaymentConstMeta;
+
   /// See [BreezServices::send_spontaneous_payment]
   Future<SendPaymentResponse> sendSpontaneousPayment(
       {required SendSpontaneousPaymentRequest req, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentConstMeta;
 
+  /// See [BreezServices::cancel]
+  Future<bool> cancel({required String cancelToken, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCancelConstMeta;
+
+  /// See [BreezServices::probe_payment]
+  Future<PaymentProbe> probePayment({required ProbePaymentRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kProbePaymentConstMeta;
+
+  /// See [BreezServices::validate_payability]
+  Future<InvoicePayability> validatePayability({required ValidatePayabilityRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kValidatePayabilityConstMeta;
+
+  /// See [BreezServices::list_payment_probes]
+  Future<List<PaymentProbe>> listPaymentProbes({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListPaymentProbesConstMeta;
+
+  /// See [BreezServices::pay_offer]
+  Future<SendPaymentResponse> payOffer({required PayOfferRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPayOfferConstMeta;
+
+  /// See [BreezServices::prepare_receive_payment]
+  Future<PrepareReceivePaymentResponse> prepareReceivePayment(
+      {required PrepareReceivePaymentRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPrepareReceivePaymentConstMeta;
+
   /// See [BreezServices::receive_payment]
   Future<ReceivePaymentResponse> receivePayment({required ReceivePaymentRequest req, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kReceivePaymentConstMeta;
 
+  /// See [BreezServices::receive_unified]
+  Future<ReceiveUnifiedResponse> receiveUnified({required ReceiveUnifiedRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kReceiveUnifiedConstMeta;
+
+  /// See [BreezServices::amend_invoice]
+  Future<LNInvoice> amendInvoice({required AmendInvoiceRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAmendInvoiceConstMeta;
+
   /// See [BreezServices::lnurl_pay]
   Future<LnUrlPayResult> lnurlPay({required LnUrlPayRequest req, dynamic hint});
 
@@ -195,16 +453,72 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kLnurlAuthConstMeta;
 
+  /// See [BreezServices::list_lnurl_auth_domains]
+  Future<List<LnUrlAuthDomain>> listLnurlAuthDomains({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListLnurlAuthDomainsConstMeta;
+
+  /// See [BreezServices::lnurl_auth_linking_key]
+  Future<String> lnurlAuthLinkingKey({required String domain, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLnurlAuthLinkingKeyConstMeta;
+
+  /// See [BreezServices::export_lnurl_auth]
+  Future<LnUrlAuthExport> exportLnurlAuth({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportLnurlAuthConstMeta;
+
+  /// See [BreezServices::import_lnurl_auth]
+  Future<void> importLnurlAuth({required LnUrlAuthExport export, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kImportLnurlAuthConstMeta;
+
   /// See [BreezServices::report_issue]
   Future<void> reportIssue({required ReportIssueRequest req, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kReportIssueConstMeta;
 
+  /// See [BreezServices::connection_status]
+  Future<ConnectionStatus> connectionStatus({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConnectionStatusConstMeta;
+
+  /// See [BreezServices::task_status]
+  Future<List<TaskStatus>> taskStatus({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kTaskStatusConstMeta;
+
+  /// See [BreezServices::peer_connectivity]
+  Future<PeerConnectivity> peerConnectivity({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPeerConnectivityConstMeta;
+
   /// See [BreezServices::fetch_fiat_rates]
   Future<List<Rate>> fetchFiatRates({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kFetchFiatRatesConstMeta;
 
+  /// See [BreezServices::fiat_rate_history]
+  Future<List<HistoricalRate>> fiatRateHistory(
+      {required String currency, int? fromTimestamp, int? toTimestamp, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFiatRateHistoryConstMeta;
+
+  /// See [BreezServices::add_fiat_rate_alert]
+  Future<FiatRateAlert> addFiatRateAlert({required AddFiatRateAlertRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAddFiatRateAlertConstMeta;
+
+  /// See [BreezServices::list_fiat_rate_alerts]
+  Future<List<FiatRateAlert>> listFiatRateAlerts({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kListFiatRateAlertsConstMeta;
+
+  /// See [BreezServices::remove_fiat_rate_alert]
+  Future<void> removeFiatRateAlert({required int id, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kRemoveFiatRateAlertConstMeta;
+
   /// See [BreezServices::list_fiat_currencies]
   Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint});
 
@@ -215,6 +529,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kPayOnchainConstMeta;
 
+  /// See [BreezServices::pay_onchain_address]
+  Future<PayOnchainAddressResponse> payOnchainAddress({required PayOnchainAddressRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kPayOnchainAddressConstMeta;
+
   /// See [BreezServices::receive_onchain]
   Future<SwapInfo> receiveOnchain({required ReceiveOnchainRequest req, dynamic hint});
 
@@ -225,12 +544,22 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kBuyBitcoinConstMeta;
 
+  /// See [BreezServices::fetch_buy_bitcoin_quote]
+  Future<BuyBitcoinQuote> fetchBuyBitcoinQuote({required FetchBuyBitcoinQuoteRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFetchBuyBitcoinQuoteConstMeta;
+
   /// See [BreezServices::redeem_onchain_funds]
   Future<RedeemOnchainFundsResponse> redeemOnchainFunds(
       {required RedeemOnchainFundsRequest req, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kRedeemOnchainFundsConstMeta;
 
+  /// See [BreezServices::bump_fee]
+  Future<BumpFeeResponse> bumpFee({required BumpFeeRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBumpFeeConstMeta;
+
   /// See [BreezServices::prepare_redeem_onchain_funds]
   Future<PrepareRedeemOnchainFundsResponse> prepareRedeemOnchainFunds(
       {required PrepareRedeemOnchainFundsRequest req, dynamic hint});
@@ -242,6 +571,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kListRefundablesConstMeta;
 
+  /// See [BreezServices::export_swap_refund_keys]
+  Future<List<SwapRefundKey>> exportSwapRefundKeys({required ExportSwapRefundKeysRequest req, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kExportSwapRefundKeysConstMeta;
+
   /// See [BreezServices::prepare_refund]
   Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req, dynamic hint});
 
@@ -262,6 +596,11 @@ abstract class BreezSdkCore {
 
   FlutterRustBridgeTaskConstMeta get kRedeemSwapConstMeta;
 
+  /// See [BreezServices::verify_swap_address]
+  Future<bool> verifySwapAddress({required String swapAddress, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kVerifySwapAddressConstMeta;
+
   /// See [BreezServices::in_progress_swap]
   Future<SwapInfo?> inProgressSwap({dynamic hint});
 
@@ -317,6 +656,32 @@ abstract class BreezSdkCore {
   Future<String> generateDiagnosticData({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kGenerateDiagnosticDataConstMeta;
+
+  /// See [BreezServices::diagnostic_snapshot]
+  Future<String> diagnosticSnapshot({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDiagnosticSnapshotConstMeta;
+
+  /// See [crate::diff_snapshots]
+  Future<List<SnapshotChange>> diffSnapshots({required String a, required String b, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kDiffSnapshotsConstMeta;
+}
+
+/// Represents a [crate::BreezServices::add_fiat_rate_alert] request.
+class AddFiatRateAlertRequest {
+  /// The fiat currency code, for example `USD`
+  final String currency;
+  final FiatRateAlertDirection direction;
+
+  /// The exchange rate of one BTC in the fiat currency
+  final double threshold;
+
+  const AddFiatRateAlertRequest({
+    required this.currency,
+    required this.direction,
+    required this.threshold,
+  });
 }
 
 class AesSuccessActionDataDecrypted {
@@ -339,6 +704,59 @@ sealed class AesSuccessActionDataResult with _$AesSuccessActionDataResult {
   }) = AesSuccessActionDataResult_ErrorStatus;
 }
 
+/// Represents a request to amend an unexpired, unpaid invoice of this node.
+///
+/// The payment hash and payment secret of the invoice are preserved, so the node still settles
+/// the amended invoice. Since the node keeps tracking the original invoice, only changes it accepts
+/// are allowed: the amount can only be raised and the expiry can only be shortened.
+class AmendInvoiceRequest {
+  /// The bolt11 invoice to amend
+  final String bolt11;
+
+  /// If specified, sets the new amount of the invoice
+  final MilliSat? amountMsat;
+
+  /// If specified, sets the new time the invoice is valid for, in seconds from its creation
+  final int? expiry;
+
+  /// If specified, replaces the route hints of the invoice
+  final List<RouteHint>? routeHints;
+
+  const AmendInvoiceRequest({
+    required this.bolt11,
+    this.amountMsat,
+    this.expiry,
+    this.routeHints,
+  });
+}
+
+@freezed
+sealed class Amount with _$Amount {
+  const factory Amount.bitcoin({
+    required int amountMsat,
+  }) = Amount_Bitcoin;
+  const factory Amount.currency({
+    required String iso4217Code,
+    required int fractionalAmount,
+  }) = Amount_Currency;
+}
+
+/// How the refundable swaps are refunded, see [Config::swap_auto_refund]
+class AutoRefundConfig {
+  /// The address the swaps are refunded to, on [Config::network]. If not set, they are refunded
+  /// to the on-chain wallet of the node.
+  final String? toAddress;
+
+  /// The fee rate of the refund transactions. If not set, the recommended fee rate for a
+  /// confirmation within an hour is used.
+  final int? satPerVbyte;
+
+  const AutoRefundConfig({
+    this.toAddress,
+    this.satPerVbyte,
+  });
+}
+
 class BackupFailedData {
   final String error;
 
@@ -359,6 +777,136 @@ class BackupStatus {
   });
 }
 
+@freezed
+sealed class BackupTransportConfig with _$BackupTransportConfig {
+  /// An S3 compatible bucket supporting conditional writes. The backup is stored under the
+  /// `breez-sdk/backup` key.
+  const factory BackupTransportConfig.s3({
+    /// For example `https://s3.eu-west-1.amazonaws.com`
+    required String endpoint,
+    required String region,
+    required String bucket,
+    required String accessKeyId,
+    required String secretAccessKey,
+  }) = BackupTransportConfig_S3;
+
+  /// A file on a WebDAV server, for example `https://dav.example.com/breez/backup`
+  const factory BackupTransportConfig.webDav({
+    required String url,
+    String? username,
+    String? password,
+  }) = BackupTransportConfig_WebDav;
+}
+
+/// The funds of the node with the channel reserves and HTLCs that explain the difference between
+/// the balances and what can be sent, see [crate::BreezServices::get_balance_breakdown]
+class BalanceBreakdown {
+  /// What can be sent over Lightning now, the sum of the spendable amounts of the opened
+  /// channels
+  final int spendableMsat;
+
+  /// The channels not yet closed, with their spendable amounts, reserves and HTLCs
+  final List<ChannelDetails> channels;
+
+  /// The reserves of the opened channels, not spendable until they're closed
+  final int channelReservesMsat;
+  final int htlcsOutMsat;
+  final int htlcsInMsat;
+
+  /// The part of the HTLCs below the dust limits of their channels
+  final int dustHtlcsMsat;
+
+  /// The local balance of the channels being opened, see
+  /// [NodeState::pending_channel_opens_msat]
+  final int pendingChannelOpensMsat;
+  final int onchainConfirmedMsat;
+  final int onchainUnconfirmedMsat;
+
+  /// See [Balances::swaps_locked_msat]
+  final int swapsLockedMsat;
+
+  /// See [Balances::reverse_swaps_locked_msat]
+  final int reverseSwapsLockedMsat;
+
+  const BalanceBreakdown({
+    required this.spendableMsat,
+    required this.channels,
+    required this.channelReservesMsat,
+    required this.htlcsOutMsat,
+    required this.htlcsInMsat,
+    required this.dustHtlcsMsat,
+    required this.pendingChannelOpensMsat,
+    required this.onchainConfirmedMsat,
+    required this.onchainUnconfirmedMsat,
+    required this.swapsLockedMsat,
+    required this.reverseSwapsLockedMsat,
+  });
+}
+
+/// The funds of the node, split by state, see [crate::BreezServices::balances]
+class Balances {
+  /// What can be sent over Lightning now, taking the channel reserves into account
+  final int lightningSpendableMsat;
+
+  /// Outgoing Lightning payments in flight, including their fees. The payments of the reverse
+  /// swaps are counted in `reverse_swaps_locked_msat` instead.
+  final int lightningPendingMsat;
+  final int onchainConfirmedMsat;
+  final int onchainUnconfirmedMsat;
+
+  /// Deposited to swap addresses and not yet paid out over Lightning, or waiting to be
+  /// refunded
+  final int swapsLockedMsat;
+
+  /// Paid to the reverse swaps in progress and not yet claimed onchain
+  final int reverseSwapsLockedMsat;
+
+  const Balances({
+    required this.lightningSpendableMsat,
+    required this.lightningPendingMsat,
+    required this.onchainConfirmedMsat,
+    required this.onchainUnconfirmedMsat,
+    required this.swapsLockedMsat,
+    required this.reverseSwapsLockedMsat,
+  });
+}
+
+/// The progress of a batch of payments, included as payload in [BreezEvent::BatchPaymentProgress]
+class BatchPaymentProgressDetails {
+  /// The payment that just finished
+  final BatchPaymentResult result;
+  final int succeeded;
+  final int failed;
+  final int total;
+
+  const BatchPaymentProgressDetails({
+    required this.result,
+    required this.succeeded,
+    required this.failed,
+    required this.total,
+  });
+}
+
+/// The outcome of one payment of a [SendPaymentsRequest]
+class BatchPaymentResult {
+  /// The index of the payment in [SendPaymentsRequest::payments]
+  final int index;
+  final String bolt11;
+
+  /// Set if the payment succeeded
+  final Payment? payment;
+
+  /// Set if the payment failed
+  final String? error;
+
+  const BatchPaymentResult({
+    required this.index,
+    required this.bolt11,
+    this.payment,
+    this.error,
+  });
+}
+
 class BitcoinAddressData {
   final String address;
   final Network network;
@@ -422,11 +970,176 @@ sealed class BreezEvent with _$BreezEvent {
   const factory BreezEvent.swapUpdated({
     required SwapInfo details,
   }) = BreezEvent_SwapUpdated;
+
+  /// Indicates the progress of [BreezServices::connect], emitted once per [ConnectStage] and
+  /// again each time a step of the [ConnectStage::InitialSync] completes
+  const factory BreezEvent.connectProgress({
+    required ConnectProgressDetails details,
+  }) = BreezEvent_ConnectProgress;
+
+  /// Emitted on [BreezServices::connect] when funds are found that need an action from the
+  /// user to be recovered
+  const factory BreezEvent.unredeemedFundsDetected({
+    required UnredeemedFundsDetails details,
+  }) = BreezEvent_UnredeemedFundsDetected;
+
+  /// Indicates that an LNURL payment for which [LnUrlPayResult::Pending] was returned has
+  /// finished
+  const factory BreezEvent.lnUrlPayFinished({
+    required LnUrlPayFinishedDetails details,
+  }) = BreezEvent_LnUrlPayFinished;
+
+  /// Indicates the progress of a payment received through a channel opened by the LSP, for
+  /// example the first payment received by a node
+  const factory BreezEvent.openChannelReceiveUpdated({
+    required OpenChannelReceiveDetails details,
+  }) = BreezEvent_OpenChannelReceiveUpdated;
+
+  /// Emitted after a sync when the amount that can be sent drops below
+  /// [Config::low_outbound_liquidity_threshold_msat], to prompt the user to top up
+  const factory BreezEvent.lowOutboundLiquidity({
+    required LowLiquidityDetails details,
+  }) = BreezEvent_LowOutboundLiquidity;
+
+  /// Emitted after a sync when the amount that can be received through the existing channels
+  /// drops below [Config::low_inbound_liquidity_threshold_msat], to prompt the user to get
+  /// more inbound capacity
+  const factory BreezEvent.lowInboundLiquidity({
+    required LowLiquidityDetails details,
+  }) = BreezEvent_LowInboundLiquidity;
+
+  /// Indicates that the LSP opened a channel to forward a payment, and that `fee_msat`,
+  /// calculated from the `proportional` rate in ppm with a minimum of `min_fee_msat`, is
+  /// deducted from it for the channel opening
+  const factory BreezEvent.channelOpening({
+    required int feeMsat,
+    required int minFeeMsat,
+    required int proportional,
+  }) = BreezEvent_ChannelOpening;
+
+  /// Indicates that the SDK selected another LSP, for example according to the
+  /// [Config::lsp_policy] or because the previous LSP is no longer available
+  const factory BreezEvent.lspChanged({
+    String? previousLspId,
+    required String lspId,
+  }) = BreezEvent_LspChanged;
+
+  /// Indicates that a refundable swap was refunded automatically, see [Config::swap_auto_refund]
+  const factory BreezEvent.swapRefunded({
+    required SwapRefundedDetails details,
+  }) = BreezEvent_SwapRefunded;
+
+  /// Emitted each time a payment of [BreezServices::send_payments] finishes
+  const factory BreezEvent.batchPaymentProgress({
+    required BatchPaymentProgressDetails details,
+  }) = BreezEvent_BatchPaymentProgress;
+
+  /// Indicates that a payment was queued in the outbox, or that one of its attempts finished
+  const factory BreezEvent.outboxPaymentUpdated({
+    required OutboxPaymentDetails details,
+  }) = BreezEvent_OutboxPaymentUpdated;
+
+  /// Indicates that a service accepted the signature of [BreezServices::lnurl_auth]
+  const factory BreezEvent.lnUrlAuthCompleted({
+    required LnUrlAuthDomain details,
+  }) = BreezEvent_LnUrlAuthCompleted;
+
+  /// Emitted after a sync for each channel that was not used and whose peer was not connected
+  /// during [Config::channel_hygiene_window_secs], suggesting to close it with
+  /// [BreezServices::close_channel] before onchain fees rise
+  const factory BreezEvent.channelHygieneSuggestion({
+    required ChannelHygieneSuggestionDetails details,
+  }) = BreezEvent_ChannelHygieneSuggestion;
+
+  /// Indicates that a fetched exchange rate crossed the threshold of a price alert, see
+  /// [BreezServices::add_fiat_rate_alert]
+  const factory BreezEvent.fiatRateAlertTriggered({
+    required FiatRateAlertTriggeredDetails details,
+  }) = BreezEvent_FiatRateAlertTriggered;
+
+  /// Indicates that the node and the LSP peer are reachable again after a
+  /// [BreezEvent::Disconnected]
+  const factory BreezEvent.connected() = BreezEvent_Connected;
+
+  /// Indicates that the node or the LSP peer can't be reached anymore. The SDK reconnects
+  /// automatically, see [BreezServices::connection_status].
+  const factory BreezEvent.disconnected({
+    required String error,
+  }) = BreezEvent_Disconnected;
+
+  /// Indicates that a reconnection is attempted in `delay_ms`, the delay growing exponentially
+  /// with the attempts
+  const factory BreezEvent.reconnecting({
+    required int attempt,
+    required int delayMs,
+  }) = BreezEvent_Reconnecting;
+
+  /// Indicates that the LSP peer couldn't be reached before a payment was sent or an invoice
+  /// was created, for the `reason` given, and that it was reconnected
+  const factory BreezEvent.lspPeerReconnected({
+    required String lspPubkey,
+    required String reason,
+  }) = BreezEvent_LspPeerReconnected;
+
+  /// Indicates that a keysend payment was received, with the sender metadata in
+  /// [LnPaymentDetails::spontaneous_metadata]. It follows the [BreezEvent::InvoicePaid] of the
+  /// invoice the node created for it.
+  const factory BreezEvent.spontaneousPaymentReceived({
+    required Payment details,
+  }) = BreezEvent_SpontaneousPaymentReceived;
+
+  /// Indicates that a service the SDK depends on degraded or recovered, see
+  /// [Config::service_status_check_interval_secs]
+  const factory BreezEvent.serviceStatusChanged({
+    required ServiceComponent component,
+    required ServiceStatus status,
+  }) = BreezEvent_ServiceStatusChanged;
+}
+
+/// Represents a request to replace an unconfirmed transaction by one paying a higher fee rate,
+/// see [crate::BreezServices::bump_fee]
+class BumpFeeRequest {
+  /// The txid of the transaction to replace, in hex
+  final String txid;
+
+  /// The new fee rate, which must be higher than the one of the replaced transaction
+  final int satPerVbyte;
+
+  const BumpFeeRequest({
+    required this.txid,
+    required this.satPerVbyte,
+  });
+}
+
+class BumpFeeResponse {
+  /// The txid of the replacement transaction
+  final String txid;
+
+  const BumpFeeResponse({
+    required this.txid,
+  });
 }
 
 /// Different providers will demand different behaviours when the user is trying to buy bitcoin.
 enum BuyBitcoinProvider {
   Moonpay,
+
+  /// Requires [Config::transak_api_key]
+  Transak,
+}
+
+class BuyBitcoinQuote {
+  final String fiatCurrency;
+  final double fiatAmount;
+  final double feesFiat;
+  final int amountSat;
+
+  const BuyBitcoinQuote({
+    required this.fiatCurrency,
+    required this.fiatAmount,
+    required this.feesFiat,
+    required this.amountSat,
+  });
 }
 
 class BuyBitcoinRequest {
@@ -455,51 +1168,206 @@ class BuyBitcoinResponse {
   });
 }
 
-/// State of a Lightning channel
-enum ChannelState {
-  PendingOpen,
-  Opened,
-  PendingClose,
-  Closed,
-}
-
-/// Request to check a message was signed by a specific node id.
-class CheckMessageRequest {
-  /// The message that was signed.
-  final String message;
-
-  /// The public key of the node that signed the message.
-  final String pubkey;
-
-  /// The zbase encoded signature to verify.
-  final String signature;
+@freezed
+sealed class ChainServiceConfig with _$ChainServiceConfig {
+  /// An Esplora REST API, for example `https://blockstream.info/api`
+  const factory ChainServiceConfig.esplora({
+    required String url,
+  }) = ChainServiceConfig_Esplora;
 
-  const CheckMessageRequest({
-    required this.message,
-    required this.pubkey,
-    required this.signature,
-  });
+  /// An Electrum server, in the format `ssl://host:port` or `tcp://host:port`
+  const factory ChainServiceConfig.electrum({
+    required String url,
+  }) = ChainServiceConfig_Electrum;
 }
 
-/// Response to a [CheckMessageRequest]
-class CheckMessageResponse {
-  /// Boolean value indicating whether the signature covers the message and
-  /// was signed by the given pubkey.
-  final bool isValid;
+/// The details of a channel of the node, as reported by [crate::BreezServices::list_channels]
+class ChannelDetails {
+  /// The hex encoded channel id, used to close the channel
+  final String channelId;
 
-  const CheckMessageResponse({
-    required this.isValid,
+  /// The node id of the channel peer
+  final String peerId;
+  final bool peerConnected;
+  final String? shortChannelId;
+  final String fundingTxid;
+  final ChannelState state;
+  final int capacityMsat;
+  final int localBalanceMsat;
+  final int remoteBalanceMsat;
+  final int spendableMsat;
+  final int receivableMsat;
+
+  /// The reserve the peer requires us to keep in the channel. It can't be spent and is only
+  /// returned when the channel is closed.
+  final int reserveMsat;
+
+  /// The outgoing HTLCs, not yet settled or failed
+  final int htlcsOutMsat;
+
+  /// The incoming HTLCs, not yet settled or failed
+  final int htlcsInMsat;
+
+  /// The part of the HTLCs below the dust limit of the channel. They are not enforceable
+  /// onchain and go to the miners if the channel is force closed.
+  final int dustHtlcsMsat;
+
+  const ChannelDetails({
+    required this.channelId,
+    required this.peerId,
+    required this.peerConnected,
+    this.shortChannelId,
+    required this.fundingTxid,
+    required this.state,
+    required this.capacityMsat,
+    required this.localBalanceMsat,
+    required this.remoteBalanceMsat,
+    required this.spendableMsat,
+    required this.receivableMsat,
+    required this.reserveMsat,
+    required this.htlcsOutMsat,
+    required this.htlcsInMsat,
+    required this.dustHtlcsMsat,
   });
 }
 
-/// Represents the funds that were on the user side of the channel at the time it was closed.
-class ClosedChannelPaymentDetails {
-  final ChannelState state;
+/// A stale channel suggested to be closed, included as payload in
+/// [BreezEvent::ChannelHygieneSuggestion]
+class ChannelHygieneSuggestionDetails {
+  final String channelId;
+  final String peerId;
   final String fundingTxid;
-  final String? shortChannelId;
+  final int localBalanceMsat;
 
-  /// Can be empty for older closed channels.
-  final String? closingTxid;
+  /// The seconds since the channel was last used
+  final int idleSecs;
+
+  /// The seconds since the peer was last seen connected
+  final int peerOfflineSecs;
+
+  /// The current `hourFee` rate the sweep fee is projected with
+  final int satPerVbyte;
+
+  /// The projected fee of a cooperative close of the channel
+  final int projectedSweepFeeSat;
+
+  /// The projected amount returned onchain, the local balance minus the sweep fee
+  final int projectedSweepAmountSat;
+
+  const ChannelHygieneSuggestionDetails({
+    required this.channelId,
+    required this.peerId,
+    required this.fundingTxid,
+    required this.localBalanceMsat,
+    required this.idleSecs,
+    required this.peerOfflineSecs,
+    required this.satPerVbyte,
+    required this.projectedSweepFeeSat,
+    required this.projectedSweepAmountSat,
+  });
+}
+
+/// The fee charged by the LSP to open a channel for a received payment, see
+/// [crate::BreezServices::list_channel_fees_paid]
+class ChannelOpeningFee {
+  /// The hash of the payment received through the opened channel
+  final String paymentHash;
+
+  /// Epoch time, in seconds
+  final int paymentTime;
+
+  /// The amount paid by the sender
+  final int payerAmountMsat;
+
+  /// The amount received, after the fee was deducted
+  final int receivedAmountMsat;
+  final int feeMsat;
+
+  /// The fee params the channel was opened with. Not set for payments received before they
+  /// were recorded.
+  final OpeningFeeParams? openingFeeParams;
+
+  const ChannelOpeningFee({
+    required this.paymentHash,
+    required this.paymentTime,
+    required this.payerAmountMsat,
+    required this.receivedAmountMsat,
+    required this.feeMsat,
+    this.openingFeeParams,
+  });
+}
+
+/// State of a Lightning channel
+enum ChannelState {
+  PendingOpen,
+  Opened,
+  PendingClose,
+  Closed,
+}
+
+/// Request to check a message was signed by a specific node id.
+class CheckMessageRequest {
+  /// The message that was signed.
+  final String message;
+
+  /// The public key of the node that signed the message.
+  final String pubkey;
+
+  /// The zbase encoded signature to verify.
+  final String signature;
+
+  const CheckMessageRequest({
+    required this.message,
+    required this.pubkey,
+    required this.signature,
+  });
+}
+
+/// Response to a [CheckMessageRequest]
+class CheckMessageResponse {
+  /// Boolean value indicating whether the signature covers the message and
+  /// was signed by the given pubkey.
+  final bool isValid;
+
+  const CheckMessageResponse({
+    required this.isValid,
+  });
+}
+
+/// Represents a close channel request.
+class CloseChannelRequest {
+  /// The hex encoded id of the channel to close, see [ChannelDetails::channel_id]
+  final String channelId;
+
+  /// If set, the channel is closed unilaterally when the peer doesn't agree to a mutual close
+  /// right away. Otherwise the node waits up to 48 hours for the peer before closing it
+  /// unilaterally.
+  final bool force;
+
+  const CloseChannelRequest({
+    required this.channelId,
+    required this.force,
+  });
+}
+
+/// Represents a close channel response.
+class CloseChannelResponse {
+  /// The closing tx id. Not set for channels that were closed before their funding tx was broadcast
+  final String? closingTxid;
+
+  const CloseChannelResponse({
+    this.closingTxid,
+  });
+}
+
+/// Represents the funds that were on the user side of the channel at the time it was closed.
+class ClosedChannelPaymentDetails {
+  final ChannelState state;
+  final String fundingTxid;
+  final String? shortChannelId;
+
+  /// Can be empty for older closed channels.
+  final String? closingTxid;
 
   const ClosedChannelPaymentDetails({
     required this.state,
@@ -517,6 +1385,10 @@ class Config {
   final String breezserver;
   final String chainnotifierUrl;
 
+  /// The LNURL-pay server the lightning address is registered with, see
+  /// [crate::BreezServices::register_lightning_address]
+  final String lnurlServerUrl;
+
   /// If set, this is the mempool.space URL that will be used.
   ///
   /// If not set, a list of mempool.space URLs will be used to provide fault-tolerance. If calls
@@ -525,32 +1397,155 @@ class Config {
   /// Note that, if specified, the URL has to be in the format: `https://mempool.space/api`
   final String? mempoolspaceUrl;
 
+  /// If set, this chain service is used to monitor the chain instead of mempool.space, and
+  /// `mempoolspace_url` is ignored.
+  final ChainServiceConfig? chainService;
+
+  /// If set, the SDK state is backed up to this store instead of the Greenlight node.
+  ///
+  /// Ignored if a transport is set with [crate::BreezServicesBuilder::backup_transport].
+  final BackupTransportConfig? backupTransport;
+
+  /// If set, the connections to the Breez server and LSP, the swap service, the fiat rates,
+  /// LNURL and the chain service are made through this SOCKS5 proxy.
+  ///
+  /// The connection to the Greenlight node is not affected.
+  final ProxyConfig? proxy;
+
   /// Directory in which all SDK files (DB, log) are stored. Defaults to ".", otherwise if it's customized,
   /// the folder should exist before starting the SDK.
   final String workingDir;
+
+  /// If set, the SQLite DBs are stored in this directory instead of the `working_dir`. The
+  /// folder should exist before starting the SDK.
+  final String? dbDir;
+
+  /// If set, the encrypted node credentials are stored in a file in this directory instead of
+  /// in the DB, for example to keep them out of the app backups. The folder should exist
+  /// before starting the SDK.
+  final String? credentialsDir;
   final Network network;
+
+  /// How long a payment is attempted for, retrying other routes until it expires. It can be
+  /// overridden per payment with [SendPaymentRequest::payment_timeout_sec].
   final int paymentTimeoutSec;
+
+  /// How many outgoing payments can be in flight at the same time. The payments over the limit
+  /// wait for one of them to complete. The payments to the same destination are always sent
+  /// one after the other.
+  final int maxConcurrentPayments;
   final String? defaultLspId;
+
+  /// If set, the LSP is selected by the SDK according to this policy on startup and on each
+  /// sync, so the fees of the LSPs are re-evaluated. A [crate::BreezEvent::LspChanged] is
+  /// emitted when another LSP is selected.
+  ///
+  /// This overrides the LSP selected with [crate::BreezServices::connect_lsp].
+  final LspPolicy? lspPolicy;
   final String? apiKey;
 
-  /// Maps to the CLN `maxfeepercent` config when paying invoices (`lightning-pay`)
+  /// Maps to the CLN `maxfeepercent` config when paying invoices (`lightning-pay`). It can be
+  /// overridden per payment with [SendPaymentRequest::maxfee_percent].
   final double maxfeePercent;
 
-  /// Maps to the CLN `exemptfee` config when paying invoices (`lightning-pay`)
+  /// Maps to the CLN `exemptfee` config when paying invoices (`lightning-pay`). It can be
+  /// overridden per payment with [SendPaymentRequest::exemptfee_msat].
   final int exemptfeeMsat;
+
+  /// If set, the exchange rate of this fiat currency, for example `USD`, is recorded in
+  /// [Payment::fiat_rate] when a payment settles
+  final String? fiatCurrency;
+
+  /// If set, a [crate::BreezEvent::LowOutboundLiquidity] is emitted when the amount that can be
+  /// sent drops below this threshold
+  final int? lowOutboundLiquidityThresholdMsat;
+
+  /// If set, a [crate::BreezEvent::LowInboundLiquidity] is emitted when the amount that can be
+  /// received without a new channel drops below this threshold
+  final int? lowInboundLiquidityThresholdMsat;
+
+  /// If set, [crate::BreezServices::send_payment] attempts trampoline payments by default,
+  /// unless overridden by [SendPaymentRequest::use_trampoline]
+  final bool useTrampoline;
+
+  /// If set, a partner fee is paid after each outgoing Lightning payment
+  final PartnerFeeConfig? partnerFee;
+
+  /// If set, the refundable swaps are refunded automatically, so the funds are not left in the
+  /// swap addresses when nobody is around to call [crate::BreezServices::refund]. A
+  /// [crate::BreezEvent::SwapRefunded] is emitted for each refund.
+  final AutoRefundConfig? swapAutoRefund;
+
+  /// How the descriptions of the invoices are kept private, see [MemoPrivacy]
+  final MemoPrivacy memoPrivacy;
+
+  /// If set, a [crate::BreezEvent::ChannelHygieneSuggestion] is emitted for the channels that
+  /// were not used and whose peer was not connected during this many seconds, so small or
+  /// unreachable channels can be closed before onchain fees rise
+  final int? channelHygieneWindowSecs;
+
+  /// If set, the log filter applied when connecting, in the `RUST_LOG` syntax, for example
+  /// `info,breez_sdk_core=debug`. See [crate::BreezServices::set_log_filter].
+  final String? logFilter;
+
+  /// If set, a denylist of payment destinations is fetched from this URL and cached, and
+  /// [crate::BreezServices::send_payment] and [crate::BreezServices::lnurl_pay] refuse to pay
+  /// the destinations in it, unless [SendPaymentRequest::skip_denylist] is set.
+  ///
+  /// The URL serves a JSON array of node pubkeys, lightning addresses and LNURL domains. A
+  /// denied domain also denies its subdomains. The list is refreshed daily, and payments are
+  /// not blocked while no list could be fetched yet.
+  final String? denylistUrl;
+
+  /// The API key of a Transak partner account, required to buy Bitcoin with
+  /// [BuyBitcoinProvider::Transak]
+  final String? transakApiKey;
+
+  /// If set, the limits enforced on the outgoing payments, see [SpendPolicy]
+  final SpendPolicy? spendPolicy;
+
+  /// If set, the bounds on the HTLCs of the outgoing payments, see [HtlcExposureLimits]
+  final HtlcExposureLimits? htlcExposureLimits;
+
+  /// If set, the LSP, the Greenlight node and the swapper are checked in the background every
+  /// this many seconds, and a [crate::BreezEvent::ServiceStatusChanged] is emitted when one of
+  /// them degrades or recovers
+  final int? serviceStatusCheckIntervalSecs;
   final NodeConfig nodeConfig;
 
   const Config({
     required this.breezserver,
     required this.chainnotifierUrl,
+    required this.lnurlServerUrl,
     this.mempoolspaceUrl,
+    this.chainService,
+    this.backupTransport,
+    this.proxy,
     required this.workingDir,
+    this.dbDir,
+    this.credentialsDir,
     required this.network,
     required this.paymentTimeoutSec,
+    required this.maxConcurrentPayments,
     this.defaultLspId,
+    this.lspPolicy,
     this.apiKey,
     required this.maxfeePercent,
     required this.exemptfeeMsat,
+    this.fiatCurrency,
+    this.lowOutboundLiquidityThresholdMsat,
+    this.lowInboundLiquidityThresholdMsat,
+    required this.useTrampoline,
+    this.partnerFee,
+    this.swapAutoRefund,
+    required this.memoPrivacy,
+    this.channelHygieneWindowSecs,
+    this.logFilter,
+    this.denylistUrl,
+    this.transakApiKey,
+    this.spendPolicy,
+    this.htlcExposureLimits,
+    this.serviceStatusCheckIntervalSecs,
     required this.nodeConfig,
   });
 }
@@ -564,18 +1559,158 @@ class ConfigureNodeRequest {
   });
 }
 
+/// Represents a connect peer request.
+class ConnectPeerRequest {
+  /// The peer to connect to, as `<node id>@<host>:<port>`
+  final String nodeUri;
+
+  const ConnectPeerRequest({
+    required this.nodeUri,
+  });
+}
+
+/// Details of the connect progress, included as payload in an emitted [BreezEvent]
+class ConnectProgressDetails {
+  final ConnectStage stage;
+
+  /// The overall connect progress, from 0 to 100
+  final int progressPercent;
+
+  /// Whether a new node was registered (`true`) or an existing node was restored or
+  /// reconnected (`false`). It is `None` while this is not yet known.
+  final bool? isNewNode;
+
+  const ConnectProgressDetails({
+    required this.stage,
+    required this.progressPercent,
+    this.isNewNode,
+  });
+}
+
 /// Represents a connect request.
 class ConnectRequest {
   final Config config;
   final Uint8List seed;
 
+  /// Deprecated, use `register_if_missing` instead, which wins when both are set.
+  ///
   /// If true, only restores an existing node and otherwise result in an error
   final bool? restoreOnly;
 
+  /// Whether a new node should be registered when no node exists for the `seed`. Defaults to
+  /// true. Restore wallet flows should set it to false, so that connecting results in a
+  /// [crate::error::ConnectError::RestoreOnly] error instead of registering a new empty node.
+  final bool? registerIfMissing;
+
   const ConnectRequest({
     required this.config,
     required this.seed,
     this.restoreOnly,
+    this.registerIfMissing,
+  });
+}
+
+/// The stages [BreezServices::connect] goes through, in the order they are reported
+enum ConnectStage {
+  /// No stored credentials were found, trying to recover an existing node from the seed
+  NodeRecovery,
+
+  /// No existing node was found for the seed, registering a new one
+  NodeRegistration,
+
+  /// Authenticating the node credentials with the scheduler
+  SchedulerHandshake,
+
+  /// Starting the signer and the node background tasks
+  NodeStartup,
+
+  /// Syncing the node state for the first time
+  InitialSync,
+
+  /// The SDK is connected and ready to use
+  Completed,
+}
+
+/// Whether the node and the LSP peer are reachable, see [ConnectionStatus]
+enum ConnectionState {
+  Connected,
+
+  /// The connection was lost, the first reconnection attempt is not scheduled yet
+  Disconnected,
+
+  /// Waiting for, or running, a reconnection attempt
+  Reconnecting,
+}
+
+/// The connection to the node and the LSP peer, returned by
+/// [crate::BreezServices::connection_status]
+class ConnectionStatus {
+  final ConnectionState state;
+
+  /// Epoch time, in seconds, since which the connection is in this state
+  final int since;
+
+  /// The reconnection attempts since the connection was lost
+  final int reconnectAttempts;
+
+  /// Why the connection was lost, or why the last reconnection attempt failed
+  final String? lastError;
+
+  const ConnectionStatus({
+    required this.state,
+    required this.since,
+    required this.reconnectAttempts,
+    this.lastError,
+  });
+}
+
+/// Represents a request to create an LNURL-withdraw voucher paid from the balance of the node,
+/// see [crate::BreezServices::create_lnurl_withdraw_voucher]
+class CreateLnurlWithdrawVoucherRequest {
+  /// The amount the claimant can withdraw on each use
+  final MilliSat amountMsat;
+
+  /// How many times the voucher can be redeemed
+  final int uses;
+
+  /// How long the voucher can be redeemed for, in seconds
+  final int expirySecs;
+
+  /// The description shown to the claimant
+  final String? description;
+
+  const CreateLnurlWithdrawVoucherRequest({
+    required this.amountMsat,
+    required this.uses,
+    required this.expirySecs,
+    this.description,
+  });
+}
+
+/// Request to [crate::BreezServices::create_static_lnurl_pay]
+class CreateStaticLnurlPayRequest {
+  /// The URL the endpoint is hosted at, which serves [StaticLnurlPay::pay_request]
+  final String url;
+
+  /// The URL the payers request the invoices from, whose requests are answered with
+  /// [crate::BreezServices::static_lnurl_pay_callback]
+  final String callbackUrl;
+
+  /// The description shown to the payers, for example `Donations to Alice`
+  final String description;
+  final MilliSat minSendableMsat;
+  final MilliSat maxSendableMsat;
+
+  /// The max length of the comment the payers can add, or no comments if not set
+  final int? commentAllowed;
+
+  const CreateStaticLnurlPayRequest({
+    required this.url,
+    required this.callbackUrl,
+    required this.description,
+    required this.minSendableMsat,
+    required this.maxSendableMsat,
+    this.commentAllowed,
   });
 }
 
@@ -599,10 +1734,174 @@ class CurrencyInfo {
   });
 }
 
+/// Request to decrypt a bundle of [ExportRecoveryBundleResponse]
+class DecryptRecoveryBundleRequest {
+  final Uint8List bundle;
+
+  /// The private key of [ExportRecoveryBundleRequest::recipient_pubkey]
+  final Uint8List privateKey;
+
+  const DecryptRecoveryBundleRequest({
+    required this.bundle,
+    required this.privateKey,
+  });
+}
+
+/// The unit in which amounts are shown to the user
+enum Denomination {
+  Btc,
+  Sat,
+}
+
+/// Request to derive a key for the app to encrypt its own data, see
+/// [BreezServices::derive_encryption_key]
+class DeriveEncryptionKeyRequest {
+  /// What the key is used for, for example `contacts`. Keys for different purposes are
+  /// unrelated.
+  final String purpose;
+
+  /// Allows to derive multiple keys for the same purpose, for example to rotate them. Must be
+  /// lower than 2^31.
+  final int index;
+
+  const DeriveEncryptionKeyRequest({
+    required this.purpose,
+    required this.index,
+  });
+}
+
+/// Response to a [DeriveEncryptionKeyRequest]
+class DeriveEncryptionKeyResponse {
+  /// The 32 byte key, for example for AES-256
+  final Uint8List key;
+
+  const DeriveEncryptionKeyResponse({
+    required this.key,
+  });
+}
+
+class EncryptedPaymentRequestData {
+  final String blob;
+
+  const EncryptedPaymentRequestData({
+    required this.blob,
+  });
+}
+
 /// Indicates the different kinds of supported environments for [crate::BreezServices].
 enum EnvironmentType {
   Production,
   Staging,
+
+  /// A local regtest stack, see [Config::regtest]
+  Regtest,
+}
+
+/// The file formats supported by [crate::BreezServices::export_payments]
+enum ExportFormat {
+  Csv,
+  Json,
+}
+
+/// Represents an export payments request.
+class ExportPaymentsRequest {
+  final ExportFormat format;
+
+  /// Epoch time, in seconds
+  final int? fromTimestamp;
+
+  /// Epoch time, in seconds
+  final int? toTimestamp;
+
+  /// The id of the fiat currency, for example `USD`, in which the payment amounts are also
+  /// exported. If not set, no fiat values are exported.
+  final String? includeFiat;
+
+  const ExportPaymentsRequest({
+    required this.format,
+    this.fromTimestamp,
+    this.toTimestamp,
+    this.includeFiat,
+  });
+}
+
+/// Request to export a [RecoveryBundle], see [crate::BreezServices::export_recovery_bundle]
+class ExportRecoveryBundleRequest {
+  /// The hex encoded public key the bundle is encrypted to, for example the heir's
+  final String recipientPubkey;
+
+  /// Instructions for the recipient, added to the generic recovery instructions
+  final String? instructions;
+
+  /// If set, the bundle is written to this file, and written again each time the channels
+  /// change, until [crate::BreezServices::stop_recovery_bundle_refresh] is called
+  final String? filePath;
+
+  const ExportRecoveryBundleRequest({
+    required this.recipientPubkey,
+    this.instructions,
+    this.filePath,
+  });
+}
+
+/// Response to an [ExportRecoveryBundleRequest]
+class ExportRecoveryBundleResponse {
+  /// The [RecoveryBundle] as JSON, encrypted to the recipient public key with ECIES
+  final Uint8List bundle;
+
+  const ExportRecoveryBundleResponse({
+    required this.bundle,
+  });
+}
+
+/// Request to export the swap refund keys, see [crate::BreezServices::export_swap_refund_keys]
+class ExportSwapRefundKeysRequest {
+  /// Also export the unused keys following the last used one, to cover the swaps missing from
+  /// the database. Defaults to false: only the keys of the known swaps are exported.
+  final bool? includeGapKeys;
+
+  const ExportSwapRefundKeysRequest({
+    this.includeGapKeys,
+  });
+}
+
+enum FeatureSupport {
+  NotSupported,
+  Optional,
+  Required,
+}
+
+/// Different types of supported feerates
+enum FeeratePreset {
+  Regular,
+  Economy,
+  Priority,
+}
+
+/// Represents a request to compare what buying Bitcoin costs at a provider, see
+/// [crate::BreezServices::fetch_buy_bitcoin_quote]
+class FetchBuyBitcoinQuoteRequest {
+  final BuyBitcoinProvider provider;
+
+  /// The fiat amount to pay, fees included
+  final FiatAmount fiatAmount;
+
+  const FetchBuyBitcoinQuoteRequest({
+    required this.provider,
+    required this.fiatAmount,
+  });
+}
+
+/// An amount in a fiat currency, for example 4.5 `USD`
+class FiatAmount {
+  /// The id of the currency, as in [crate::BreezServices::list_fiat_currencies]
+  final String currency;
+  final double amount;
+
+  const FiatAmount({
+    required this.currency,
+    required this.amount,
+  });
 }
 
 class FiatCurrency {
@@ -615,6 +1914,53 @@ class FiatCurrency {
   });
 }
 
+/// A price alert checked every time the exchange rates are fetched, see
+/// [crate::BreezServices::add_fiat_rate_alert]
+class FiatRateAlert {
+  final int id;
+  final String currency;
+  final FiatRateAlertDirection direction;
+  final double threshold;
+
+  /// Epoch time, in seconds
+  final int createdAt;
+
+  /// Whether the rate is past the threshold since the alert last triggered. The alert
+  /// triggers again only after the rate moved back to the other side of the threshold.
+  final bool triggered;
+
+  /// Epoch time, in seconds
+  final int? lastTriggeredAt;
+
+  const FiatRateAlert({
+    required this.id,
+    required this.currency,
+    required this.direction,
+    required this.threshold,
+    required this.createdAt,
+    required this.triggered,
+    this.lastTriggeredAt,
+  });
+}
+
+/// The side of its threshold the exchange rate has to cross for a [FiatRateAlert] to trigger
+enum FiatRateAlertDirection {
+  Above,
+  Below,
+}
+
+/// A price alert and the exchange rate that triggered it, included as payload in
+/// [BreezEvent::FiatRateAlertTriggered]
+class FiatRateAlertTriggeredDetails {
+  final FiatRateAlert alert;
+  final double rate;
+
+  const FiatRateAlertTriggeredDetails({
+    required this.alert,
+    required this.rate,
+  });
+}
+
 /// Client-specific credentials to connect to and manage a Greenlight node in the cloud
 class GreenlightCredentials {
   final Uint8List developerKey;
@@ -635,7 +1981,11 @@ class GreenlightDeviceCredentials {
   });
 }
 
+/// How a new Greenlight node is registered when connecting with a seed that has none. Exactly
+/// one of the fields has to be set to register a node.
 class GreenlightNodeConfig {
+  /// The partner credentials, which register nodes without an invite code within the quota
+  /// of the partner. See [crate::error::ConnectError::RegistrationQuotaExceeded].
   final GreenlightCredentials? partnerCredentials;
   final String? inviteCode;
 
@@ -652,14 +2002,82 @@ enum HealthCheckStatus {
   ServiceDisruption,
 }
 
-@freezed
-sealed class InputType with _$InputType {
-  const factory InputType.bitcoinAddress({
-    required BitcoinAddressData address,
+/// An exchange rate recorded at `timestamp`, as returned by
+/// [crate::BreezServices::fiat_rate_history]
+class HistoricalRate {
+  final String coin;
+  final double value;
+
+  /// Epoch time, in seconds
+  final int timestamp;
+
+  const HistoricalRate({
+    required this.coin,
+    required this.value,
+    required this.timestamp,
+  });
+}
+
+/// Bounds on the HTLCs of the outgoing payments, see [Config::htlc_exposure_limits], to limit
+/// what can be lost to HTLCs stuck along a route.
+///
+/// They are enforced by [crate::BreezServices::send_payment],
+/// [crate::BreezServices::send_spontaneous_payment] and [crate::BreezServices::lnurl_pay],
+/// which fail with an `HtlcExposureExceeded` error for a payment exceeding them.
+class HtlcExposureLimits {
+  /// The max amount of the outgoing payments in flight, including the payment being sent and
+  /// the fees of the pending ones
+  final int? maxInflightMsat;
+
+  /// The max number of HTLCs of a single payment. A payment needs at least one HTLC per
+  /// channel it's sent through, so a payment only the sum of several channels can cover is
+  /// refused if it needs more channels than this.
+  final int? maxHtlcsPerPayment;
+
+  const HtlcExposureLimits({
+    this.maxInflightMsat,
+    this.maxHtlcsPerPayment,
+  });
+}
+
+/// Represents an import payments request.
+class ImportPaymentsRequest {
+  final ExportFormat format;
+
+  /// The payments, in the format of [crate::BreezServices::export_payments]
+  final String data;
+
+  const ImportPaymentsRequest({
+    required this.format,
+    required this.data,
+  });
+}
+
+/// Represents an import payments response.
+class ImportPaymentsResponse {
+  final int importedCount;
+
+  /// The payments skipped because a payment with the same id already exists
+  final int skippedCount;
+
+  const ImportPaymentsResponse({
+    required this.importedCount,
+    required this.skippedCount,
+  });
+}
+
+@freezed
+sealed class InputType with _$InputType {
+  const factory InputType.bitcoinAddress({
+    required BitcoinAddressData address,
   }) = InputType_BitcoinAddress;
   const factory InputType.bolt11({
     required LNInvoice invoice,
   }) = InputType_Bolt11;
+  const factory InputType.bolt12Offer({
+    required LNOffer offer,
+    String? bip353Address,
+  }) = InputType_Bolt12Offer;
   const factory InputType.nodeId({
     required String nodeId,
   }) = InputType_NodeId;
@@ -669,6 +2087,8 @@ sealed class InputType with _$InputType {
   const factory InputType.lnUrlPay({
     required LnUrlPayRequestData data,
     String? bip353Address,
+    int? amountMsat,
+    String? comment,
   }) = InputType_LnUrlPay;
   const factory InputType.lnUrlWithdraw({
     required LnUrlWithdrawRequestData data,
@@ -679,6 +2099,23 @@ sealed class InputType with _$InputType {
   const factory InputType.lnUrlError({
     required LnUrlErrorData data,
   }) = InputType_LnUrlError;
+  const factory InputType.encryptedPaymentRequest({
+    required EncryptedPaymentRequestData data,
+  }) = InputType_EncryptedPaymentRequest;
+}
+
+class InvoiceFeatures {
+  final FeatureSupport basicMpp;
+  final FeatureSupport paymentSecret;
+  final FeatureSupport trampoline;
+  final bool requiresUnknownFeatures;
+
+  const InvoiceFeatures({
+    required this.basicMpp,
+    required this.paymentSecret,
+    required this.trampoline,
+    required this.requiresUnknownFeatures,
+  });
 }
 
 /// Details of an invoice that has been paid, included as payload in an emitted [BreezEvent]
@@ -694,6 +2131,77 @@ class InvoicePaidDetails {
   });
 }
 
+/// Whether an invoice can be paid by the node, see [crate::BreezServices::validate_payability]
+class InvoicePayability {
+  final LNInvoice invoice;
+
+  /// The amount that would be paid: the invoice amount, or else the requested one
+  final int? amountMsat;
+
+  /// Whether the invoice passed all the checks. The payment can still fail, for example when
+  /// no route is found, see [crate::BreezServices::prepare_send_payment].
+  final bool isPayable;
+
+  /// Why the invoice can't be paid, if it can't
+  final String? error;
+
+  /// The amount the node can send now, see [NodeState::max_payable_msat]
+  final int maxPayableMsat;
+
+  /// The amount the payee can receive through the route hints of the invoice, if all of them
+  /// set their max HTLC amount
+  final int? payeeInboundLiquidityMsat;
+
+  /// The route hint through the LSP of this node, if the invoice has one. Such invoices are
+  /// paid directly instead of with trampoline.
+  final RouteHint? lspRouteHint;
+
+  const InvoicePayability({
+    required this.invoice,
+    this.amountMsat,
+    required this.isPayable,
+    this.error,
+    required this.maxPayableMsat,
+    this.payeeInboundLiquidityMsat,
+    this.lspRouteHint,
+  });
+}
+
+/// The result of the verification of one of the invoices of
+/// [crate::BreezServices::verify_invoices]
+class InvoiceVerificationResult {
+  final String bolt11;
+
+  /// Set if the invoice is valid
+  final LNInvoice? invoice;
+
+  /// Why the invoice is invalid, if it isn't
+  final String? error;
+
+  const InvoiceVerificationResult({
+    required this.bolt11,
+    this.invoice,
+    this.error,
+  });
+}
+
+/// Represents a list payment groups request.
+class ListPaymentGroupsRequest {
+  final PaymentGroupBy groupBy;
+
+  /// The payments to aggregate. Its `offset` and `limit` apply to the payments, before grouping.
+  final ListPaymentsRequest payments;
+
+  /// The maximum number of groups returned, all by default
+  final int? limit;
+
+  const ListPaymentGroupsRequest({
+    required this.groupBy,
+    required this.payments,
+    this.limit,
+  });
+}
+
 /// Represents a list payments request.
 class ListPaymentsRequest {
   final List<PaymentTypeFilter>? filters;
@@ -708,6 +2216,13 @@ class ListPaymentsRequest {
   final int? offset;
   final int? limit;
 
+  /// If set, the raw values of the custom TLV records of received payments are included as
+  /// [TlvRecord::value_hex]
+  final bool? includeTlvHex;
+
+  /// The order of the payments by time, the newest first by default
+  final SortOrder? sortOrder;
+
   const ListPaymentsRequest({
     this.filters,
     this.metadataFilters,
@@ -716,6 +2231,8 @@ class ListPaymentsRequest {
     this.includeFailures,
     this.offset,
     this.limit,
+    this.includeTlvHex,
+    this.sortOrder,
   });
 }
 
@@ -749,9 +2266,12 @@ class LNInvoice {
   final int? amountMsat;
   final int timestamp;
   final int expiry;
+  final int expiresAt;
+  final bool isExpired;
   final List<RouteHint> routingHints;
   final Uint8List paymentSecret;
   final int minFinalCltvExpiryDelta;
+  final InvoiceFeatures features;
 
   const LNInvoice({
     required this.bolt11,
@@ -763,9 +2283,42 @@ class LNInvoice {
     this.amountMsat,
     required this.timestamp,
     required this.expiry,
+    required this.expiresAt,
+    required this.isExpired,
     required this.routingHints,
     required this.paymentSecret,
     required this.minFinalCltvExpiryDelta,
+    required this.features,
+  });
+}
+
+class LNOffer {
+  final String offer;
+  final List<String> chains;
+  final Amount? minAmount;
+  final String? description;
+  final int? absoluteExpiry;
+  final String? issuer;
+  final String? signingPubkey;
+  final List<LnOfferBlindedPath> paths;
+
+  const LNOffer({
+    required this.offer,
+    required this.chains,
+    this.minAmount,
+    this.description,
+    this.absoluteExpiry,
+    this.issuer,
+    this.signingPubkey,
+    required this.paths,
+  });
+}
+
+class LnOfferBlindedPath {
+  final List<String> blindedHops;
+
+  const LnOfferBlindedPath({
+    required this.blindedHops,
   });
 }
 
@@ -782,6 +2335,10 @@ class LnPaymentDetails {
   /// Represents the actual invoice paid by the sender
   final String? openChannelBolt11;
 
+  /// Only set for [PaymentType::Received] payments which required to open a channel.
+  /// The fee charged by the LSP for opening the channel, included in the payment `fee_msat`
+  final int? channelOpeningFeeMsat;
+
   /// Only set for [PaymentType::Sent] payments that are part of a LNURL-pay workflow where
   /// the endpoint returns a success action
   final SuccessActionProcessed? lnurlSuccessAction;
@@ -810,6 +2367,14 @@ class LnPaymentDetails {
   /// Only set for [PaymentStatus::Pending] payments that are inflight.
   final int? pendingExpirationBlock;
 
+  /// Only set for [PaymentType::Received] payments that carried custom TLV records, like
+  /// keysend messages or podcast boostagrams
+  final List<TlvRecord>? tlvs;
+
+  /// Only set for [PaymentType::Received] keysend payments, with the sender metadata found in
+  /// their custom TLV records
+  final SpontaneousPaymentMetadata? spontaneousMetadata;
+
   const LnPaymentDetails({
     required this.paymentHash,
     required this.label,
@@ -818,6 +2383,7 @@ class LnPaymentDetails {
     required this.keysend,
     required this.bolt11,
     this.openChannelBolt11,
+    this.channelOpeningFeeMsat,
     this.lnurlSuccessAction,
     this.lnurlPayDomain,
     this.lnurlPayComment,
@@ -827,6 +2393,43 @@ class LnPaymentDetails {
     this.swapInfo,
     this.reverseSwapInfo,
     this.pendingExpirationBlock,
+    this.tlvs,
+    this.spontaneousMetadata,
+  });
+}
+
+/// A domain the wallet authenticated to with LNURL-auth, see
+/// [crate::BreezServices::list_lnurl_auth_domains]
+class LnUrlAuthDomain {
+  final String domain;
+
+  /// The hex encoded LUD-05 linking public key, the identity of the wallet on the domain
+  final String linkingKey;
+  final int firstAuthenticatedAt;
+  final int lastAuthenticatedAt;
+  final int authCount;
+
+  const LnUrlAuthDomain({
+    required this.domain,
+    required this.linkingKey,
+    required this.firstAuthenticatedAt,
+    required this.lastAuthenticatedAt,
+    required this.authCount,
+  });
+}
+
+/// The LNURL-auth identity of the wallet and its domain history, exported with
+/// [crate::BreezServices::export_lnurl_auth] to be imported on another device restored from the
+/// same seed
+class LnUrlAuthExport {
+  /// The hex encoded public key of the LUD-05 hashing key, from which the linking key of each
+  /// domain is derived. It identifies the seed without revealing it.
+  final String hashingKey;
+  final List<LnUrlAuthDomain> domains;
+
+  const LnUrlAuthExport({
+    required this.hashingKey,
+    required this.domains,
   });
 }
 
@@ -870,6 +2473,29 @@ class LnUrlPayErrorData {
   });
 }
 
+/// Result of an LNURL payment that continued in the background, included as payload in
+/// [BreezEvent::LnUrlPayFinished]
+class LnUrlPayFinishedDetails {
+  final String paymentHash;
+
+  /// The final result. Errors that occurred while paying are reported as
+  /// [LnUrlPayResult::PayError].
+  final LnUrlPayResult result;
+
+  const LnUrlPayFinishedDetails({
+    required this.paymentHash,
+    required this.result,
+  });
+}
+
+class LnUrlPayPendingData {
+  final String paymentHash;
+
+  const LnUrlPayPendingData({
+    required this.paymentHash,
+  });
+}
+
 class LnUrlPayRequest {
   final LnUrlPayRequestData data;
   final int amountMsat;
@@ -877,6 +2503,9 @@ class LnUrlPayRequest {
   final String? comment;
   final String? paymentLabel;
   final bool? validateSuccessActionUrl;
+  final int? pendingTimeoutSec;
+  final String? cancelToken;
+  final bool? skipDenylist;
 
   const LnUrlPayRequest({
     required this.data,
@@ -885,6 +2514,9 @@ class LnUrlPayRequest {
     this.comment,
     this.paymentLabel,
     this.validateSuccessActionUrl,
+    this.pendingTimeoutSec,
+    this.cancelToken,
+    this.skipDenylist,
   });
 }
 
@@ -923,15 +2555,22 @@ sealed class LnUrlPayResult with _$LnUrlPayResult {
   const factory LnUrlPayResult.payError({
     required LnUrlPayErrorData data,
   }) = LnUrlPayResult_PayError;
+  const factory LnUrlPayResult.pending({
+    required LnUrlPayPendingData data,
+  }) = LnUrlPayResult_Pending;
 }
 
 class LnUrlPaySuccessData {
   final Payment payment;
   final SuccessActionProcessed? successAction;
 
+  /// The partner fee paid after the payment, if a [crate::Config::partner_fee] is set
+  final PartnerFeeDetails? partnerFee;
+
   const LnUrlPaySuccessData({
     required this.payment,
     this.successAction,
+    this.partnerFee,
   });
 }
 
@@ -984,6 +2623,52 @@ class LnUrlWithdrawSuccessData {
   });
 }
 
+/// The reusable LNURL-pay code and lightning address of the wallet, see
+/// [crate::BreezServices::register_lightning_address]
+class LnurlPayInfo {
+  /// The bech32 encoded LNURL-pay code, to be shown as a QR code
+  final String lnurl;
+
+  /// The lightning address, for example `alice@breez.fun`
+  final String lightningAddress;
+
+  const LnurlPayInfo({
+    required this.lnurl,
+    required this.lightningAddress,
+  });
+}
+
+/// An LNURL-withdraw voucher hosted by the node, see
+/// [crate::BreezServices::create_lnurl_withdraw_voucher]
+class LnurlWithdrawVoucher {
+  /// The `k1` of the voucher, identifying it when it's redeemed
+  final String id;
+
+  /// The bech32 encoded LNURL-withdraw code, to be handed to the claimant
+  final String lnurl;
+  final int amountMsat;
+  final int uses;
+  final int redeemedUses;
+  final String? description;
+
+  /// Epoch time, in seconds
+  final int createdAt;
+
+  /// Epoch time, in seconds, after which the voucher can't be redeemed
+  final int expiresAt;
+
+  const LnurlWithdrawVoucher({
+    required this.id,
+    required this.lnurl,
+    required this.amountMsat,
+    required this.uses,
+    required this.redeemedUses,
+    this.description,
+    required this.createdAt,
+    required this.expiresAt,
+  });
+}
+
 class LocaleOverrides {
   final String locale;
   final int? spacing;
@@ -1011,9 +2696,25 @@ class LogEntry {
   final String line;
   final String level;
 
+  /// The module the entry was logged from, for example `breez_sdk_core::breez_services`
+  final String module;
+
   const LogEntry({
     required this.line,
     required this.level,
+    required this.module,
+  });
+}
+
+/// The liquidity that dropped below its configured threshold, included as payload in
+/// [BreezEvent::LowOutboundLiquidity] and [BreezEvent::LowInboundLiquidity]
+class LowLiquidityDetails {
+  final int liquidityMsat;
+  final int thresholdMsat;
+
+  const LowLiquidityDetails({
+    required this.liquidityMsat,
+    required this.thresholdMsat,
   });
 }
 
@@ -1062,6 +2763,40 @@ class LspInformation {
   });
 }
 
+@freezed
+sealed class LspPolicy with _$LspPolicy {
+  /// The LSP with the lowest minimum channel opening fee, best for small payments
+  const factory LspPolicy.lowestOpeningFee() = LspPolicy_LowestOpeningFee;
+
+  /// The LSP with the lowest proportional channel opening fee, best for large payments
+  const factory LspPolicy.lowestProportionalFee() = LspPolicy_LowestProportionalFee;
+
+  /// The given LSP. If it isn't available, the selected LSP is kept.
+  const factory LspPolicy.pinned({
+    required String lspId,
+  }) = LspPolicy_Pinned;
+}
+
+/// How the descriptions (memos) of the invoices are kept off the node and the local payments
+/// DB, for users who don't want them stored outside their device, see [Config::memo_privacy]
+///
+/// It applies to the invoices created with [crate::BreezServices::receive_payment], whose
+/// description is given to the Greenlight node, and to the descriptions of the payments
+/// persisted locally, which are also part of the backups. The descriptions are
+/// still in the `bolt11` of the payments, and the invoices with
+/// [ReceivePaymentRequest::use_description_hash] keep theirs, as the hash has to match it.
+enum MemoPrivacy {
+  /// The descriptions are kept as they are
+  Plain,
+
+  /// The descriptions are replaced with `sha256:<hex>` of their SHA-256, so a known memo can
+  /// still be matched. The payer sees the hash as the description of the invoice.
+  Hash,
+
+  /// The descriptions are removed
+  Strip,
+}
+
 class MessageSuccessActionData {
   final String message;
 
@@ -1076,12 +2811,121 @@ class MetadataFilter {
   final String jsonPath;
 
   /// Specifies which JSON value to filter for.
-  /// As such, strings must be wrapped with quotes ("") in order to be properly filtered
-  final String jsonValue;
+  /// As such, strings must be wrapped with quotes ("") in order to be properly filtered.
+  /// When not set, all the payments whose metadata contains the field are returned.
+  final String? jsonValue;
 
   const MetadataFilter({
     required this.jsonPath,
-    required this.jsonValue,
+    this.jsonValue,
+  });
+}
+
+class MetricBucket {
+  final double upperBound;
+
+  /// The number of observed values up to `upper_bound`
+  final int cumulativeCount;
+
+  const MetricBucket({
+    required this.upperBound,
+    required this.cumulativeCount,
+  });
+}
+
+class MetricCounter {
+  /// The Prometheus metric name, for example `breez_payments_succeeded_total`
+  final String name;
+  final String help;
+  final int value;
+
+  const MetricCounter({
+    required this.name,
+    required this.help,
+    required this.value,
+  });
+}
+
+class MetricHistogram {
+  /// The Prometheus metric name, for example `breez_payment_duration_seconds`
+  final String name;
+  final String help;
+  final List<MetricBucket> buckets;
+
+  /// The sum of the observed values
+  final double sum;
+
+  /// The number of observed values, including those above the last bucket
+  final int count;
+
+  const MetricHistogram({
+    required this.name,
+    required this.help,
+    required this.buckets,
+    required this.sum,
+    required this.count,
+  });
+}
+
+/// The counters and histograms of the SDK operations since connect, returned by
+/// [crate::BreezServices::metrics]. See [MetricsSnapshot::to_prometheus] to expose them to
+/// Prometheus.
+class MetricsSnapshot {
+  final List<MetricCounter> counters;
+  final List<MetricHistogram> histograms;
+
+  const MetricsSnapshot({
+    required this.counters,
+    required this.histograms,
+  });
+}
+
+/// A channel included in a [NodeMigrationState].
+class MigrationChannel {
+  final String fundingTxid;
+  final int? fundingOutnum;
+  final String? shortChannelId;
+  final ChannelState state;
+  final int localBalanceMsat;
+
+  /// The node id of the channel peer, if the node still knows the channel
+  final String? peerId;
+
+  /// The capacity of the channel, to reopen one of the same size from the target node
+  final int? capacityMsat;
+
+  const MigrationChannel({
+    required this.fundingTxid,
+    this.fundingOutnum,
+    this.shortChannelId,
+    required this.state,
+    required this.localBalanceMsat,
+    this.peerId,
+    this.capacityMsat,
+  });
+}
+
+class MilliSat {
+  final int field0;
+
+  const MilliSat({
+    required this.field0,
+  });
+}
+
+/// The starting state of a [NodeConfig::Mock] node. A node reconnected in the same process
+/// keeps its state instead.
+class MockNodeConfig {
+  final int channelsBalanceMsat;
+  final int onchainBalanceMsat;
+
+  /// How much the node can receive over Lightning
+  final int inboundLiquidityMsat;
+
+  const MockNodeConfig({
+    required this.channelsBalanceMsat,
+    required this.onchainBalanceMsat,
+    required this.inboundLiquidityMsat,
   });
 }
 
@@ -1097,6 +2941,15 @@ sealed class NodeConfig with _$NodeConfig {
   const factory NodeConfig.greenlight({
     required GreenlightNodeConfig config,
   }) = NodeConfig_Greenlight;
+
+  /// An in-memory node for tests and development, whose balances only change with the
+  /// payments to and from it. Invoices of the other mock nodes of the process are settled on
+  /// them, and payments to any other destination succeed right away.
+  ///
+  /// Requires the `mock-node` feature.
+  const factory NodeConfig.mock({
+    required MockNodeConfig config,
+  }) = NodeConfig_Mock;
 }
 
 @freezed
@@ -1106,6 +2959,54 @@ sealed class NodeCredentials with _$NodeCredentials {
   }) = NodeCredentials_Greenlight;
 }
 
+/// Represents a node migration request.
+class NodeMigrationRequest {
+  /// If true, the channels with the LSP are cooperatively closed as part of the export, so that
+  /// the funds can be moved onchain to the target node.
+  final bool closeChannels;
+
+  const NodeMigrationRequest({
+    required this.closeChannels,
+  });
+}
+
+/// The exported node state, needed to move the wallet from Greenlight to a self-hosted CLN node.
+class NodeMigrationState {
+  final String nodeId;
+  final Network network;
+
+  /// The URI of the LSP node, `pubkey@host`, for the target node to connect to it and reopen
+  /// the [NodeMigrationState::channels]
+  final String? lspNodeUri;
+
+  /// The static channel backup of the node, which can be used to recover the channels on the
+  /// target node as a fallback for channels that could not be closed cooperatively
+  final List<String> staticBackup;
+
+  /// The channels that were open or pending open at the time of the export
+  final List<MigrationChannel> channels;
+
+  /// The txids of the closing transactions, if [NodeMigrationRequest::close_channels] was set
+  final List<String> closingTxids;
+  final int channelsBalanceMsat;
+  final int onchainBalanceMsat;
+
+  /// Epoch time, in seconds
+  final int exportedAt;
+
+  const NodeMigrationState({
+    required this.nodeId,
+    required this.network,
+    this.lspNodeUri,
+    required this.staticBackup,
+    required this.channels,
+    required this.closingTxids,
+    required this.channelsBalanceMsat,
+    required this.onchainBalanceMsat,
+    required this.exportedAt,
+  });
+}
+
 /// The node state of a Greenlight LN node running in the cloud.
 ///
 /// Note: The implementation attempts to provide the most up-to-date values,
@@ -1130,6 +3031,14 @@ class NodeState {
   /// Total receivable on all available channels
   final int totalInboundLiquidityMsats;
 
+  /// The local balance of the channels being opened, not included in `channels_balance_msat`
+  /// until their funding tx confirms. For example, a payment received with a JIT channel is
+  /// only spendable once its channel is opened.
+  final int pendingChannelOpensMsat;
+
+  /// The channels being opened, see `pending_channel_opens_msat`. Set on each full sync.
+  final List<PendingChannelOpen> pendingChannelOpens;
+
   const NodeState({
     required this.id,
     required this.blockHeight,
@@ -1144,18 +3053,20 @@ class NodeState {
     required this.connectedPeers,
     required this.maxReceivableSinglePaymentAmountMsat,
     required this.totalInboundLiquidityMsats,
+    required this.pendingChannelOpensMsat,
+    required this.pendingChannelOpens,
   });
 }
 
 class OnchainPaymentLimitsResponse {
   /// Minimum amount the reverse swap service accepts as a send amount
-  final int minSat;
+  final Sat minSat;
 
   /// Maximum amount the reverse swap service accepts as a send amount
-  final int maxSat;
+  final Sat maxSat;
 
   /// Maximum amount this node can send with the current channels and the current local balance
-  final int maxPayableSat;
+  final Sat maxPayableSat;
 
   const OnchainPaymentLimitsResponse({
     required this.minSat,
@@ -1165,7 +3076,7 @@ class OnchainPaymentLimitsResponse {
 }
 
 class OpenChannelFeeRequest {
-  final int? amountMsat;
+  final MilliSat? amountMsat;
   final int? expiry;
 
   const OpenChannelFeeRequest({
@@ -1177,7 +3088,7 @@ class OpenChannelFeeRequest {
 class OpenChannelFeeResponse {
   /// Opening fee for receiving the amount set in the [OpenChannelFeeRequest], in case it was set.
   /// It may be zero if no new channel needs to be opened.
-  final int? feeMsat;
+  final MilliSat? feeMsat;
 
   /// The fee params for receiving more than the current inbound liquidity.
   final OpeningFeeParams feeParams;
@@ -1188,6 +3099,75 @@ class OpenChannelFeeResponse {
   });
 }
 
+/// The progress of a payment received through a new channel, included as payload in
+/// [BreezEvent::OpenChannelReceiveUpdated]
+class OpenChannelReceiveDetails {
+  final String paymentHash;
+  final OpenChannelReceiveStage stage;
+
+  const OpenChannelReceiveDetails({
+    required this.paymentHash,
+    required this.stage,
+  });
+}
+
+@freezed
+sealed class OpenChannelReceiveStage with _$OpenChannelReceiveStage {
+  /// The invoice was created and the channel opening was negotiated with the LSP, which
+  /// deducts `opening_fee_msat` from the received amount
+  const factory OpenChannelReceiveStage.registered({
+    required int openingFeeMsat,
+  }) = OpenChannelReceiveStage_Registered;
+
+  /// The LSP opened the channel and forwards the payment through it
+  const factory OpenChannelReceiveStage.channelPending({
+    required String fundingTxid,
+  }) = OpenChannelReceiveStage_ChannelPending;
+
+  /// The payment was received and `opening_fee_msat` was charged for the channel
+  const factory OpenChannelReceiveStage.settled({
+    required int openingFeeMsat,
+  }) = OpenChannelReceiveStage_Settled;
+
+  /// The invoice was registered with the LSP again, for example after the channel opening
+  /// timed out, so the payer can retry the payment with the same invoice
+  const factory OpenChannelReceiveStage.reregistered() = OpenChannelReceiveStage_Reregistered;
+}
+
+/// Represents an open channel request.
+class OpenChannelRequest {
+  /// The node id of the peer, which must be connected with [crate::BreezServices::connect_peer]
+  final String nodeId;
+
+  /// The channel capacity, funded from the onchain balance
+  final Sat amountSat;
+
+  /// Whether the channel is announced to the network, to route payments of other nodes
+  final bool announce;
+
+  /// The feerate of the funding tx. If not set, the node estimates one
+  final int? satPerVbyte;
+
+  const OpenChannelRequest({
+    required this.nodeId,
+    required this.amountSat,
+    required this.announce,
+    this.satPerVbyte,
+  });
+}
+
+/// Represents an open channel response.
+class OpenChannelResponse {
+  /// The hex encoded id of the new channel, see [ChannelDetails::channel_id]
+  final String channelId;
+  final String fundingTxid;
+
+  const OpenChannelResponse({
+    required this.channelId,
+    required this.fundingTxid,
+  });
+}
+
 /// Dynamic fee parameters offered by the LSP for opening a new channel.
 ///
 /// After they are received, the client shouldn't change them when calling LSP methods,
@@ -1226,14 +3206,196 @@ class OpeningFeeParamsMenu {
   });
 }
 
-class PayOnchainRequest {
-  final String recipientAddress;
-  final PrepareOnchainPaymentResponse prepareRes;
+/// A payment queued with [crate::BreezServices::queue_payment], retried until it succeeds or
+/// is abandoned
+class OutboxPayment {
+  final int id;
+  final SendPaymentRequest req;
 
-  const PayOnchainRequest({
-    required this.recipientAddress,
-    required this.prepareRes,
-  });
+  /// Epoch time, in seconds
+  final int createdAt;
+
+  /// When the payment is abandoned if it didn't succeed yet, epoch time in seconds
+  final int expiresAt;
+
+  /// The number of attempts so far
+  final int attempts;
+
+  /// When the payment is attempted next, epoch time in seconds
+  final int nextAttemptAt;
+
+  /// The error of the last attempt
+  final String? lastError;
+
+  const OutboxPayment({
+    required this.id,
+    required this.req,
+    required this.createdAt,
+    required this.expiresAt,
+    required this.attempts,
+    required this.nextAttemptAt,
+    this.lastError,
+  });
+}
+
+/// A payment of the outbox and its new state, included as payload in
+/// [BreezEvent::OutboxPaymentUpdated]
+class OutboxPaymentDetails {
+  final OutboxPayment outboxPayment;
+  final OutboxPaymentStatus status;
+
+  const OutboxPaymentDetails({
+    required this.outboxPayment,
+    required this.status,
+  });
+}
+
+@freezed
+sealed class OutboxPaymentStatus with _$OutboxPaymentStatus {
+  /// The payment is waiting for its next attempt
+  const factory OutboxPaymentStatus.queued() = OutboxPaymentStatus_Queued;
+
+  /// The payment succeeded and was removed from the outbox
+  const factory OutboxPaymentStatus.sent({
+    required Payment payment,
+  }) = OutboxPaymentStatus_Sent;
+
+  /// The payment failed with an error that retrying won't fix, expired or failed too many
+  /// times, and was removed from the outbox
+  const factory OutboxPaymentStatus.abandoned() = OutboxPaymentStatus_Abandoned;
+
+  /// The payment was removed from the outbox by
+  /// [crate::BreezServices::cancel_outbox_payment]
+  const factory OutboxPaymentStatus.cancelled() = OutboxPaymentStatus_Cancelled;
+}
+
+/// The fee of a partner, for example the provider of a white-label wallet, see
+/// [Config::partner_fee]
+///
+/// The fee is paid as a separate payment to the `destination` once a payment succeeds, so it
+/// isn't included in the amount or in the routing fees of the payment. The fee payments have the
+/// `partner-fee` label.
+class PartnerFeeConfig {
+  /// The fee in basis points of the payment amount, for example 50 for 0.5%
+  final int feeBps;
+  final PartnerFeeDestination destination;
+
+  const PartnerFeeConfig({
+    required this.feeBps,
+    required this.destination,
+  });
+}
+
+@freezed
+sealed class PartnerFeeDestination with _$PartnerFeeDestination {
+  /// Paid with keysend to the node
+  const factory PartnerFeeDestination.nodeId({
+    required String nodeId,
+  }) = PartnerFeeDestination_NodeId;
+
+  /// Paid with LNURL-pay to the lightning address, requires the `lnurl` feature
+  const factory PartnerFeeDestination.lightningAddress({
+    required String address,
+  }) = PartnerFeeDestination_LightningAddress;
+}
+
+/// The partner fee paid after a payment, see [Config::partner_fee]
+class PartnerFeeDetails {
+  final int amountMsat;
+
+  /// The id of the partner fee [Payment], if it succeeded
+  final String? paymentId;
+
+  /// Why the partner fee payment failed. The payment the fee applies to is not affected.
+  final String? error;
+
+  const PartnerFeeDetails({
+    required this.amountMsat,
+    this.paymentId,
+    this.error,
+  });
+}
+
+/// Represents a request to pay a BOLT12 offer.
+class PayOfferRequest {
+  /// The BOLT12 offer
+  final String offer;
+
+  /// The amount in millisatoshis to pay. Required when the offer doesn't specify an amount, or
+  /// specifies it in a fiat currency.
+  final MilliSat? amountMsat;
+
+  /// An optional note for the payee, included in the invoice request
+  final String? payerNote;
+
+  /// The external label or identifier of the [Payment]
+  final String? label;
+
+  const PayOfferRequest({
+    required this.offer,
+    this.amountMsat,
+    this.payerNote,
+    this.label,
+  });
+}
+
+/// Represents a request to pay an on-chain address in one step, with
+/// [crate::BreezServices::pay_onchain_address].
+///
+/// Exactly one of `amount_sat` and `drain` must be set, unless `address` is a BIP21 URI with an
+/// amount, which is then used when `amount_sat` is not set.
+class PayOnchainAddressRequest {
+  /// The on-chain address or BIP21 URI to pay
+  final String address;
+
+  /// The amount the recipient receives, fees excluded
+  final Sat? amountSat;
+
+  /// Whether to send as much as the channels allow, the recipient receiving what is left
+  /// after the fees
+  final bool drain;
+
+  /// The claim transaction feerate. Defaults to [UserSettings::default_feerate_preset], or to
+  /// [FeeratePreset::Regular] if it is not set.
+  final FeeratePreset? feePreference;
+
+  const PayOnchainAddressRequest({
+    required this.address,
+    this.amountSat,
+    required this.drain,
+    this.feePreference,
+  });
+}
+
+/// Returned by [crate::BreezServices::pay_onchain_address]
+class PayOnchainAddressResponse {
+  /// The amounts and fees of the payment
+  final PrepareOnchainPaymentResponse prepareRes;
+
+  /// The claim transaction feerate (sat/vByte) that was used
+  final int claimTxFeerate;
+  final ReverseSwapInfo reverseSwapInfo;
+
+  const PayOnchainAddressResponse({
+    required this.prepareRes,
+    required this.claimTxFeerate,
+    required this.reverseSwapInfo,
+  });
+}
+
+class PayOnchainRequest {
+  final String recipientAddress;
+  final PrepareOnchainPaymentResponse prepareRes;
+
+  /// A token chosen by the caller to cancel the call with [crate::BreezServices::cancel],
+  /// unique among the calls in progress
+  final String? cancelToken;
+
+  const PayOnchainRequest({
+    required this.recipientAddress,
+    required this.prepareRes,
+    this.cancelToken,
+  });
 }
 
 class PayOnchainResponse {
@@ -1259,6 +3421,13 @@ class Payment {
   final PaymentDetails details;
   final String? metadata;
 
+  /// The exchange rate of the [Config::fiat_currency] when the payment settled, if recorded
+  final Rate? fiatRate;
+
+  /// Whether the payment was made outside of this node and added with
+  /// [crate::BreezServices::import_payments]
+  final bool imported;
+
   const Payment({
     required this.id,
     required this.paymentType,
@@ -1270,6 +3439,8 @@ class Payment {
     this.description,
     required this.details,
     this.metadata,
+    this.fiatRate,
+    required this.imported,
   });
 }
 
@@ -1297,6 +3468,98 @@ class PaymentFailedData {
   });
 }
 
+/// The completed sent payments to a counterparty, as returned by
+/// [crate::BreezServices::list_payment_groups]
+class PaymentGroup {
+  /// The destination pubkey or lightning address, depending on [PaymentGroupBy]
+  final String key;
+  final int paymentsCount;
+  final int totalAmountMsat;
+  final int totalFeeMsat;
+
+  /// Epoch time, in seconds
+  final int firstPaymentTime;
+
+  /// Epoch time, in seconds
+  final int lastPaymentTime;
+
+  const PaymentGroup({
+    required this.key,
+    required this.paymentsCount,
+    required this.totalAmountMsat,
+    required this.totalFeeMsat,
+    required this.firstPaymentTime,
+    required this.lastPaymentTime,
+  });
+}
+
+/// The counterparty by which [crate::BreezServices::list_payment_groups] groups the payments
+enum PaymentGroupBy {
+  /// The [LnPaymentDetails::destination_pubkey] of sent payments
+  DestinationPubkey,
+
+  /// The [LnPaymentDetails::ln_address] of payments to a lightning address
+  LnAddress,
+}
+
+/// The outcome of a [crate::BreezServices::probe_payment]. Probes don't move funds and are not
+/// listed as payments, but with [crate::BreezServices::list_payment_probes].
+class PaymentProbe {
+  final int id;
+  final String nodeId;
+  final String? bolt11;
+  final int amountMsat;
+
+  /// Whether a route to the destination was found
+  final bool routeFound;
+
+  /// Whether the probe reached the destination, in which case a payment of the amount is
+  /// likely to succeed
+  final bool reachable;
+
+  /// The fee of the found route
+  final int? estimatedFeeMsat;
+  final int? hops;
+
+  /// Why no route was found or the probe did not reach the destination
+  final String? error;
+
+  /// Epoch time, in seconds
+  final int createdAt;
+
+  const PaymentProbe({
+    required this.id,
+    required this.nodeId,
+    this.bolt11,
+    required this.amountMsat,
+    required this.routeFound,
+    required this.reachable,
+    this.estimatedFeeMsat,
+    this.hops,
+    this.error,
+    required this.createdAt,
+  });
+}
+
+/// A payment request handed off to another app of the same wallet, for example from a watch to
+/// the phone. See [crate::BreezServices::encrypt_payment_request].
+class PaymentRequestBundle {
+  /// The invoice or other input accepted by [parse]
+  final String invoice;
+
+  /// An on-chain address to pay instead, if the invoice can't be paid
+  final String? fallback;
+
+  /// App specific data, for example the item being paid
+  final String? metadata;
+
+  const PaymentRequestBundle({
+    required this.invoice,
+    this.fallback,
+    this.metadata,
+  });
+}
+
 /// The status of a payment
 enum PaymentStatus {
   Pending,
@@ -1316,12 +3579,75 @@ enum PaymentTypeFilter {
   Sent,
   Received,
   ClosedChannel,
+
+  /// The received keysend payments, which carry no invoice, see
+  /// [LnPaymentDetails::spontaneous_metadata]
+  SpontaneousReceived,
+}
+
+/// The connectivity to the LSP peer, returned by [crate::BreezServices::peer_connectivity]
+class PeerConnectivity {
+  /// The node id of the selected LSP, if any
+  final String? lspPubkey;
+
+  /// Whether the LSP answered the ping
+  final bool lspConnected;
+
+  /// The round trip time of the ping, if it was answered
+  final int? pingRttMs;
+
+  /// Why the ping failed, if it did
+  final String? pingError;
+
+  /// How many times the LSP peer was found disconnected and reconnected since connect
+  final int reconnectionAttempts;
+
+  /// How many of the `reconnection_attempts` failed
+  final int failedReconnectionAttempts;
+
+  /// Epoch time, in seconds, of the last successful reconnection
+  final int? lastReconnectedAt;
+
+  /// The error of the last failed reconnection
+  final String? lastReconnectionError;
+
+  const PeerConnectivity({
+    this.lspPubkey,
+    required this.lspConnected,
+    this.pingRttMs,
+    this.pingError,
+    required this.reconnectionAttempts,
+    required this.failedReconnectionAttempts,
+    this.lastReconnectedAt,
+    this.lastReconnectionError,
+  });
+}
+
+/// A channel whose funding tx is not confirmed yet, see [NodeState::pending_channel_opens]
+class PendingChannelOpen {
+  final String fundingTxid;
+
+  /// The local balance of the channel, available once it's opened
+  final int amountMsat;
+
+  /// Epoch time, in seconds, when the channel was first seen pending
+  final int seenAt;
+
+  /// Estimated epoch time, in seconds, when the funding tx confirms
+  final int expectedConfirmationAt;
+
+  const PendingChannelOpen({
+    required this.fundingTxid,
+    required this.amountMsat,
+    required this.seenAt,
+    required this.expectedConfirmationAt,
+  });
 }
 
 /// See [ReverseSwapFeesRequest]
 class PrepareOnchainPaymentRequest {
   /// Depending on `amount_type`, this may be the desired send amount or the desired receive amount.
-  final int amountSat;
+  final Sat amountSat;
   final SwapAmountType amountType;
 
   /// Feerate (sat / vByte) for the claim transaction
@@ -1341,8 +3667,8 @@ class PrepareOnchainPaymentResponse {
   final double feesPercentage;
   final int feesLockup;
   final int feesClaim;
-  final int senderAmountSat;
-  final int recipientAmountSat;
+  final Sat senderAmountSat;
+  final Sat recipientAmountSat;
   final int totalFees;
 
   const PrepareOnchainPaymentResponse({
@@ -1356,6 +3682,53 @@ class PrepareOnchainPaymentResponse {
   });
 }
 
+/// Represents a request to preview the fees of receiving a payment, see
+/// [crate::BreezServices::prepare_receive_payment]
+class PrepareReceivePaymentRequest {
+  /// The amount the payer will pay
+  final MilliSat amountMsat;
+
+  /// If set and valid, these fee options are used when a new channel is needed.
+  /// Otherwise the cheapest fee options valid for `expiry` will be used.
+  final OpeningFeeParams? openingFeeParams;
+
+  /// The time the invoice will be valid for, in seconds
+  final int? expiry;
+
+  const PrepareReceivePaymentRequest({
+    required this.amountMsat,
+    this.openingFeeParams,
+    this.expiry,
+  });
+}
+
+/// The fees of receiving a payment, returned by [crate::BreezServices::prepare_receive_payment]
+class PrepareReceivePaymentResponse {
+  /// The amount the payer will pay
+  final MilliSat amountMsat;
+
+  /// Whether the LSP has to open a new channel to receive the payment
+  final bool openChannelNeeded;
+
+  /// The channel opening fee that will be deducted from the amount, 0 if no channel is needed
+  final MilliSat openingFeeMsat;
+
+  /// If a channel is needed, the [OpeningFeeParams] the fee was calculated with. Pass them to
+  /// [ReceivePaymentRequest::opening_fee_params] to create the invoice with the same fee.
+  final OpeningFeeParams? openingFeeParams;
+
+  /// The amount that will be received, after deducting the opening fee
+  final MilliSat receivedAmountMsat;
+
+  const PrepareReceivePaymentResponse({
+    required this.amountMsat,
+    required this.openChannelNeeded,
+    required this.openingFeeMsat,
+    this.openingFeeParams,
+    required this.receivedAmountMsat,
+  });
+}
+
 /// We need to prepare a redeem_onchain_funds transaction to know what fee will be charged in satoshis.
 /// This model holds the request data which consists of the address to redeem on-chain funds to and the fee rate in.
 /// satoshis per vbyte which will be converted to absolute satoshis.
@@ -1363,9 +3736,13 @@ class PrepareRedeemOnchainFundsRequest {
   final String toAddress;
   final int satPerVbyte;
 
+  /// The amount to send, see [RedeemOnchainFundsRequest::amount_sat]
+  final Sat? amountSat;
+
   const PrepareRedeemOnchainFundsRequest({
     required this.toAddress,
     required this.satPerVbyte,
+    this.amountSat,
   });
 }
 
@@ -1373,11 +3750,19 @@ class PrepareRedeemOnchainFundsRequest {
 /// this model holds the response data, which consists of the weight and the absolute fee in sats
 class PrepareRedeemOnchainFundsResponse {
   final int txWeight;
-  final int txFeeSat;
+  final Sat txFeeSat;
+
+  /// The amount received at the destination address
+  final Sat amountSat;
+
+  /// The amount going back to the wallet, 0 if there is no change output
+  final Sat changeSat;
 
   const PrepareRedeemOnchainFundsResponse({
     required this.txWeight,
     required this.txFeeSat,
+    required this.amountSat,
+    required this.changeSat,
   });
 }
 
@@ -1387,21 +3772,135 @@ class PrepareRefundRequest {
   final int satPerVbyte;
   final bool? unilateral;
 
+  /// If set to true, the response also previews the fees of the refund at each
+  /// [FeeratePreset], using the recommended fees
+  final bool? includeFeePreviews;
+
   const PrepareRefundRequest({
     required this.swapAddress,
     required this.toAddress,
     required this.satPerVbyte,
     this.unilateral,
+    this.includeFeePreviews,
   });
 }
 
 class PrepareRefundResponse {
   final int refundTxWeight;
-  final int refundTxFeeSat;
+  final Sat refundTxFeeSat;
+
+  /// The fees of the refund at each [FeeratePreset], the cheapest first, if
+  /// [PrepareRefundRequest::include_fee_previews] was set
+  final List<RefundFeePreview> feePreviews;
 
   const PrepareRefundResponse({
     required this.refundTxWeight,
     required this.refundTxFeeSat,
+    required this.feePreviews,
+  });
+}
+
+/// Represents a prepare send payment request.
+class PrepareSendPaymentRequest {
+  /// The bolt11 invoice
+  final String bolt11;
+
+  /// The amount to pay in millisatoshis. Should only be set when `bolt11` is a zero-amount invoice.
+  final MilliSat? amountMsat;
+
+  const PrepareSendPaymentRequest({
+    required this.bolt11,
+    this.amountMsat,
+  });
+}
+
+/// Represents a prepare send payment response.
+class PrepareSendPaymentResponse {
+  final String bolt11;
+
+  /// The amount the recipient receives, in millisatoshis
+  final MilliSat amountMsat;
+
+  /// The expected routing fee, in millisatoshis
+  final MilliSat feeMsat;
+
+  /// The number of hops of the longest route
+  final int routeHops;
+
+  /// The number of parts the payment is expected to be split in
+  final int parts;
+
+  const PrepareSendPaymentResponse({
+    required this.bolt11,
+    required this.amountMsat,
+    required this.feeMsat,
+    required this.routeHops,
+    required this.parts,
+  });
+}
+
+/// Represents a [crate::BreezServices::probe_payment] request.
+class ProbePaymentRequest {
+  /// A BOLT11 invoice or the id of the destination node
+  final String destination;
+
+  /// Required when probing a node id or an invoice without an amount
+  final MilliSat? amountMsat;
+
+  const ProbePaymentRequest({
+    required this.destination,
+    this.amountMsat,
+  });
+}
+
+/// Request to prove the ownership of an onchain address, see
+/// [BreezServices::prove_address_ownership]
+class ProveAddressOwnershipRequest {
+  /// An address of the onchain wallet of the node
+  final String address;
+
+  /// The message to sign, for example the challenge of an exchange
+  final String message;
+
+  const ProveAddressOwnershipRequest({
+    required this.address,
+    required this.message,
+  });
+}
+
+/// Response to a [ProveAddressOwnershipRequest]
+class ProveAddressOwnershipResponse {
+  /// The BIP-322 "simple" signature of the message, base64 encoded
+  final String signature;
+
+  const ProveAddressOwnershipResponse({
+    required this.signature,
+  });
+}
+
+class ProxyConfig {
+  final String address;
+  final String? username;
+  final String? password;
+
+  const ProxyConfig({
+    required this.address,
+    this.username,
+    this.password,
+  });
+}
+
+/// Represents a request to queue a payment in the outbox, see [crate::BreezServices::queue_payment]
+class QueuePaymentRequest {
+  final SendPaymentRequest req;
+
+  /// How long the payment is retried before it is abandoned. Defaults to
+  /// [DEFAULT_OUTBOX_EXPIRY_SECS]. The payment is abandoned anyway once the invoice expires.
+  final int? expirySecs;
+
+  const QueuePaymentRequest({
+    required this.req,
+    this.expirySecs,
   });
 }
 
@@ -1426,7 +3925,7 @@ class ReceiveOnchainRequest {
 /// Represents a receive payment request.
 class ReceivePaymentRequest {
   /// The amount in satoshis for this payment request
-  final int amountMsat;
+  final MilliSat amountMsat;
 
   /// The description for this payment request.
   final String description;
@@ -1448,6 +3947,19 @@ class ReceivePaymentRequest {
   /// if specified, sets the min_final_cltv_expiry for the invoice
   final int? cltv;
 
+  /// If set to true, the address of a new swap is included in the invoice as fallback address,
+  /// for payers that prefer to pay onchain.
+  final bool? includeFallbackAddress;
+
+  /// If specified, sets the maximum number of route hints included in the invoice besides the
+  /// LSP one. The hints of the highest capacity channels are preferred. Fewer hints result in a
+  /// shorter invoice, which is easier to scan as QR code. Defaults to [DEFAULT_MAX_ROUTE_HINTS].
+  final int? maxRouteHints;
+
+  /// If set, the invoice amount is converted from this fiat amount at the current exchange
+  /// rate, instead of using `amount_msat`. The rate is recorded as the [Payment::fiat_rate].
+  final FiatAmount? fiatAmount;
+
   const ReceivePaymentRequest({
     required this.amountMsat,
     required this.description,
@@ -1456,6 +3968,9 @@ class ReceivePaymentRequest {
     this.useDescriptionHash,
     this.expiry,
     this.cltv,
+    this.includeFallbackAddress,
+    this.maxRouteHints,
+    this.fiatAmount,
   });
 }
 
@@ -1472,39 +3987,148 @@ class ReceivePaymentResponse {
   final OpeningFeeParams? openingFeeParams;
 
   /// If set, this is the channel opening fee that will be deduced from the invoice amount.
-  final int? openingFeeMsat;
+  final MilliSat? openingFeeMsat;
+
+  /// The length of the bolt11, which determines how dense its QR code is.
+  final int bolt11Length;
+
+  /// The [ReceivePaymentRequest::fiat_amount] the invoice amount was converted from, if set
+  final FiatAmount? fiatAmount;
+
+  /// The exchange rate the `fiat_amount` was converted with, if set
+  final Rate? fiatRate;
 
   const ReceivePaymentResponse({
     required this.lnInvoice,
     this.openingFeeParams,
     this.openingFeeMsat,
+    required this.bolt11Length,
+    this.fiatAmount,
+    this.fiatRate,
   });
 }
 
-/// Wrapper containing the result of the recommended fees query, in sat/vByte, based on mempool.space data
-class RecommendedFees {
-  final int fastestFee;
-  final int halfHourFee;
-  final int hourFee;
-  final int economyFee;
-  final int minimumFee;
+/// Represents a request to receive a payment either onchain or over lightning, see
+/// [crate::BreezServices::receive_unified]
+class ReceiveUnifiedRequest {
+  final Sat amountSat;
 
-  const RecommendedFees({
-    required this.fastestFee,
-    required this.halfHourFee,
-    required this.hourFee,
-    required this.economyFee,
-    required this.minimumFee,
-  });
-}
+  /// The invoice description, also used as BIP21 label
+  final String description;
+
+  /// If set and valid, these fee options are used when a new channel is needed, whichever way
+  /// the payment is received. Otherwise the default fee options will be used.
+  final OpeningFeeParams? openingFeeParams;
+
+  const ReceiveUnifiedRequest({
+    required this.amountSat,
+    required this.description,
+    this.openingFeeParams,
+  });
+}
+
+/// Represents a unified receive response
+class ReceiveUnifiedResponse {
+  /// The BIP21 URI with the swap address and the invoice in its `lightning` parameter
+  final String bip21;
+
+  /// The swap receiving the payment if it is paid onchain
+  final SwapInfo swapInfo;
+
+  /// The invoice receiving the payment if it is paid over lightning
+  final LNInvoice lnInvoice;
+
+  /// If set, these are the [OpeningFeeParams] used to calculate the channel opening fees.
+  final OpeningFeeParams? openingFeeParams;
+
+  /// If set, this is the channel opening fee that will be deduced from the invoice amount.
+  final MilliSat? openingFeeMsat;
+
+  const ReceiveUnifiedResponse({
+    required this.bip21,
+    required this.swapInfo,
+    required this.lnInvoice,
+    this.openingFeeParams,
+    this.openingFeeMsat,
+  });
+}
+
+/// Wrapper containing the result of the recommended fees query, in sat/vByte, based on mempool.space data
+class RecommendedFees {
+  final int fastestFee;
+  final int halfHourFee;
+  final int hourFee;
+  final int economyFee;
+  final int minimumFee;
+
+  const RecommendedFees({
+    required this.fastestFee,
+    required this.halfHourFee,
+    required this.hourFee,
+    required this.economyFee,
+    required this.minimumFee,
+  });
+}
+
+/// What someone else needs to recover the funds of the wallet, along with its seed, for example
+/// in an inheritance setup. It doesn't contain the seed.
+class RecoveryBundle {
+  final String nodeId;
+  final Network network;
+
+  /// How to recover the funds, readable without the SDK
+  final String instructions;
+
+  /// The static channel backup of the node, to recover the funds of the channels
+  final List<String> staticBackup;
+
+  /// The channels that were open or pending open when the bundle was created
+  final List<MigrationChannel> channels;
+  final int channelsBalanceMsat;
+  final int onchainBalanceMsat;
+
+  /// Epoch time, in seconds
+  final int createdAt;
+
+  const RecoveryBundle({
+    required this.nodeId,
+    required this.network,
+    required this.instructions,
+    required this.staticBackup,
+    required this.channels,
+    required this.channelsBalanceMsat,
+    required this.onchainBalanceMsat,
+    required this.createdAt,
+  });
+}
+
+/// Represents a request to pay the invoice of the claimant of a voucher, see
+/// [crate::BreezServices::redeem_lnurl_withdraw_voucher]
+class RedeemLnurlWithdrawVoucherRequest {
+  /// The [LnurlWithdrawVoucher::id] of the voucher
+  final String id;
+
+  /// The invoice of the claimant
+  final String bolt11;
+
+  const RedeemLnurlWithdrawVoucherRequest({
+    required this.id,
+    required this.bolt11,
+  });
+}
 
 class RedeemOnchainFundsRequest {
   final String toAddress;
   final int satPerVbyte;
 
+  /// The amount to send, the change going back to the wallet. If not set, all the on-chain
+  /// funds are sent.
+  final Sat? amountSat;
+
   const RedeemOnchainFundsRequest({
     required this.toAddress,
     required this.satPerVbyte,
+    this.amountSat,
   });
 }
 
@@ -1516,6 +4140,19 @@ class RedeemOnchainFundsResponse {
   });
 }
 
+/// The fee of a refund at a [FeeratePreset], see [PrepareRefundResponse::fee_previews]
+class RefundFeePreview {
+  final FeeratePreset preset;
+  final int satPerVbyte;
+  final int refundTxFeeSat;
+
+  const RefundFeePreview({
+    required this.preset,
+    required this.satPerVbyte,
+    required this.refundTxFeeSat,
+  });
+}
+
 class RefundRequest {
   final String swapAddress;
   final String toAddress;
@@ -1560,7 +4197,7 @@ class ReportPaymentFailureDetails {
 
 class ReverseSwapFeesRequest {
   /// Amount to be sent
-  final int? sendAmountSat;
+  final Sat? sendAmountSat;
 
   /// Feerate (sat / vByte) for the claim transaction
   final int? claimTxFeerate;
@@ -1583,6 +4220,7 @@ class ReverseSwapInfo {
   final String? claimTxid;
   final int onchainAmountSat;
   final ReverseSwapStatus status;
+  final ReverseSwapProtocol protocol;
 
   const ReverseSwapInfo({
     required this.id,
@@ -1591,6 +4229,7 @@ class ReverseSwapInfo {
     this.claimTxid,
     required this.onchainAmountSat,
     required this.status,
+    required this.protocol,
   });
 }
 
@@ -1633,6 +4272,16 @@ class ReverseSwapPairInfo {
   });
 }
 
+/// The protocol with which the funds of a reverse swap are locked onchain
+enum ReverseSwapProtocol {
+  /// The funds are locked in a P2WSH HTLC, claimed by revealing the preimage
+  Legacy,
+
+  /// The funds are locked in a taproot output, claimed together with Boltz with MuSig2. The
+  /// script path revealing the preimage is kept as fallback.
+  Taproot,
+}
+
 /// The possible statuses of a reverse swap, from the Breez SDK perspective.
 ///
 /// See [BoltzApiReverseSwapStatus] for the reverse swap status from the Breez endpoint point of view.
@@ -1685,6 +4334,50 @@ class RouteHintHop {
   });
 }
 
+class Sat {
+  final int field0;
+
+  const Sat({
+    required this.field0,
+  });
+}
+
+/// The version of the SDK and the protocols it supports, see [crate::BreezServices::sdk_info]
+class SdkInfo {
+  /// The version of the `breez-sdk-core` crate
+  final String version;
+
+  /// The LNURL specs supported, for example `LUD-06`
+  final List<String> supportedLuds;
+
+  /// The script versions of the swap-in addresses that can be used or refunded. New swaps
+  /// always use the latest one.
+  final List<String> swapInScriptVersions;
+
+  /// The protocols of the reverse swaps that can be created or claimed
+  final List<ReverseSwapProtocol> reverseSwapProtocols;
+
+  /// The protocols spoken with the LSP
+  final List<String> lspProtocols;
+
+  /// The schema of the backups this SDK version writes. Older SDK versions may not be able to
+  /// restore a backup with a newer schema than theirs.
+  final int backupSchemaVersion;
+
+  /// The oldest backup schema this SDK version can restore
+  final int minBackupSchemaVersion;
+
+  const SdkInfo({
+    required this.version,
+    required this.supportedLuds,
+    required this.swapInScriptVersions,
+    required this.reverseSwapProtocols,
+    required this.lspProtocols,
+    required this.backupSchemaVersion,
+    required this.minBackupSchemaVersion,
+  });
+}
+
 /// Represents a send payment request.
 class SendPaymentRequest {
   /// The bolt11 invoice
@@ -1693,19 +4386,49 @@ class SendPaymentRequest {
   /// Trampoline payments outsource pathfinding to the LSP. Trampoline payments can improve
   /// payment performance, but are generally more expensive in terms of fees and they
   /// compromise on privacy.
-  final bool useTrampoline;
+  ///
+  /// If not set, [Config::use_trampoline] is used.
+  final bool? useTrampoline;
 
   /// The amount to pay in millisatoshis. Should only be set when `bolt11` is a zero-amount invoice.
-  final int? amountMsat;
+  final MilliSat? amountMsat;
 
   /// The external label or identifier of the [Payment]
   final String? label;
 
+  /// If set, the payment is only routed through the route hint at this index of the invoice
+  /// [LNInvoice::routing_hints], for example when another hint points to an unreachable node.
+  /// Trampoline is not used in that case.
+  final int? routeHintIndex;
+
+  /// If set, overrides [Config::payment_timeout_sec] for this payment
+  final int? paymentTimeoutSec;
+
+  /// If set, overrides [Config::maxfee_percent] for this payment
+  final double? maxfeePercent;
+
+  /// If set, overrides [Config::exemptfee_msat] for this payment
+  final MilliSat? exemptfeeMsat;
+
+  /// A token chosen by the caller to cancel the call with [crate::BreezServices::cancel],
+  /// unique among the calls in progress. Not used by [crate::BreezServices::queue_payment].
+  final String? cancelToken;
+
+  /// If true, the payment is made even if the destination is in the denylist, see
+  /// [Config::denylist_url]
+  final bool? skipDenylist;
+
   const SendPaymentRequest({
     required this.bolt11,
-    required this.useTrampoline,
+    this.useTrampoline,
     this.amountMsat,
     this.label,
+    this.routeHintIndex,
+    this.paymentTimeoutSec,
+    this.maxfeePercent,
+    this.exemptfeeMsat,
+    this.cancelToken,
+    this.skipDenylist,
   });
 }
 
@@ -1713,8 +4436,40 @@ class SendPaymentRequest {
 class SendPaymentResponse {
   final Payment payment;
 
+  /// The partner fee paid after the payment, if a [Config::partner_fee] is set
+  final PartnerFeeDetails? partnerFee;
+
   const SendPaymentResponse({
     required this.payment,
+    this.partnerFee,
+  });
+}
+
+/// Represents a batch of payments for [crate::BreezServices::send_payments]
+class SendPaymentsRequest {
+  final List<SendPaymentRequest> payments;
+
+  /// How many payments are sent at the same time. Defaults to
+  /// [DEFAULT_SEND_PAYMENTS_CONCURRENCY].
+  final int? maxConcurrency;
+
+  const SendPaymentsRequest({
+    required this.payments,
+    this.maxConcurrency,
+  });
+}
+
+/// Returned by [crate::BreezServices::send_payments]
+class SendPaymentsResponse {
+  /// The outcome of each payment, in the order of [SendPaymentsRequest::payments]
+  final List<BatchPaymentResult> results;
+  final int succeeded;
+  final int failed;
+
+  const SendPaymentsResponse({
+    required this.results,
+    required this.succeeded,
+    required this.failed,
   });
 }
 
@@ -1724,7 +4479,7 @@ class SendSpontaneousPaymentRequest {
   final String nodeId;
 
   /// The amount in millisatoshis for this payment
-  final int amountMsat;
+  final MilliSat amountMsat;
   final List<TlvEntry>? extraTlvs;
 
   /// The external label or identifier of the [Payment]
@@ -1738,6 +4493,19 @@ class SendSpontaneousPaymentRequest {
   });
 }
 
+/// The services the SDK depends on, whose status is monitored when
+/// [Config::service_status_check_interval_secs] is set
+enum ServiceComponent {
+  /// The LSP peer of the node
+  Lsp,
+
+  /// The Greenlight node
+  Greenlight,
+
+  /// The swap service, used to receive onchain
+  Swapper,
+}
+
 /// Represents a service health check response.
 class ServiceHealthCheckResponse {
   final HealthCheckStatus status;
@@ -1747,6 +4515,14 @@ class ServiceHealthCheckResponse {
   });
 }
 
+/// The status of a [ServiceComponent], as seen by the SDK
+enum ServiceStatus {
+  Operational,
+
+  /// The component can't be reached or doesn't respond in time
+  Degraded,
+}
+
 /// Request to sign a message with the node's private key.
 class SignMessageRequest {
   /// The message to be signed by the node's private key.
@@ -1768,6 +4544,82 @@ class SignMessageResponse {
   });
 }
 
+/// A value that differs between two snapshots, see [crate::diff_snapshots]
+class SnapshotChange {
+  /// The dot separated path of the value, like `node_state.channels_balance_msat`
+  final String path;
+
+  /// The JSON encoded value in the first snapshot, if it is there
+  final String? before;
+
+  /// The JSON encoded value in the second snapshot, if it is there
+  final String? after;
+
+  const SnapshotChange({
+    required this.path,
+    this.before,
+    this.after,
+  });
+}
+
+/// The order of a list
+enum SortOrder {
+  Descending,
+  Ascending,
+}
+
+/// Limits on the outgoing payments, see [Config::spend_policy]
+///
+/// They are enforced by [crate::BreezServices::send_payment],
+/// [crate::BreezServices::send_spontaneous_payment], [crate::BreezServices::pay_offer],
+/// [crate::BreezServices::lnurl_pay] and [crate::BreezServices::pay_onchain], which fail with a
+/// `SpendPolicyViolation` error for a payment exceeding them.
+///
+/// The routing fees and the [Config::partner_fee] payments count towards the daily limit: a
+/// payment is checked with its max routing fee, then counted with the fee it was sent with. The
+/// partner fee payments are not checked against the allowed destinations.
+class SpendPolicy {
+  /// The max amount sent over the last 24 hours, across restarts
+  final int? dailyLimitMsat;
+
+  /// The max amount of a single payment, without the fees
+  final int? perPaymentLimitMsat;
+
+  /// If set, only these destinations can be paid: node pubkeys, lightning addresses, LNURL
+  /// domains and on-chain addresses. An allowed domain also allows its subdomains.
+  final List<String>? allowedDestinations;
+
+  const SpendPolicy({
+    this.dailyLimitMsat,
+    this.perPaymentLimitMsat,
+    this.allowedDestinations,
+  });
+}
+
+/// The metadata a sender attached to a keysend payment, see
+/// [LnPaymentDetails::spontaneous_metadata]
+class SpontaneousPaymentMetadata {
+  /// The text message of TLV record 34349334, or the message of the boostagram
+  final String? message;
+
+  /// The sender node id of TLV record 34349339, if the sender disclosed it
+  final String? senderPubkey;
+
+  /// The sender name of the boostagram
+  final String? senderName;
+
+  /// The podcasting 2.0 boostagram JSON of TLV record 7629169, with the podcast, the episode
+  /// and the app it was sent from
+  final String? boostagram;
+
+  const SpontaneousPaymentMetadata({
+    this.message,
+    this.senderPubkey,
+    this.senderName,
+    this.boostagram,
+  });
+}
+
 class StaticBackupRequest {
   final String workingDir;
 
@@ -1784,6 +4636,40 @@ class StaticBackupResponse {
   });
 }
 
+/// A static LNURL-pay endpoint backed by the wallet, see
+/// [crate::BreezServices::create_static_lnurl_pay]
+class StaticLnurlPay {
+  /// The bech32 encoded LNURL of [CreateStaticLnurlPayRequest::url], to be printed as a QR code
+  final String lnurl;
+
+  /// The LUD-06 metadata, whose hash is the description hash of the invoices
+  final String metadata;
+
+  /// The JSON response to serve at [CreateStaticLnurlPayRequest::url]
+  final String payRequest;
+
+  const StaticLnurlPay({
+    required this.lnurl,
+    required this.metadata,
+    required this.payRequest,
+  });
+}
+
+/// Request to [crate::BreezServices::static_lnurl_pay_callback], with the query parameters of
+/// the request to the callback URL
+class StaticLnurlPayCallbackRequest {
+  /// The `amount` parameter
+  final MilliSat amountMsat;
+
+  /// The `comment` parameter, if any
+  final String? comment;
+
+  const StaticLnurlPayCallbackRequest({
+    required this.amountMsat,
+    this.comment,
+  });
+}
+
 @freezed
 sealed class SuccessActionProcessed with _$SuccessActionProcessed {
   const factory SuccessActionProcessed.aes({
@@ -1914,6 +4800,40 @@ class SwapInfo {
   });
 }
 
+/// A key refunding the swaps created with it, see [crate::BreezServices::export_swap_refund_keys]
+class SwapRefundKey {
+  final int index;
+
+  /// The hardened path `m/145'/index'` the key is derived at, from the master key of the node
+  final String derivationPath;
+  final Uint8List privateKey;
+
+  /// Matches the [SwapInfo::public_key] of the swaps that can be refunded with the key
+  final Uint8List publicKey;
+
+  const SwapRefundKey({
+    required this.index,
+    required this.derivationPath,
+    required this.privateKey,
+    required this.publicKey,
+  });
+}
+
+/// A swap refunded automatically, included as payload in [BreezEvent::SwapRefunded]
+class SwapRefundedDetails {
+  final String swapAddress;
+  final String refundTxId;
+  final String toAddress;
+  final int satPerVbyte;
+
+  const SwapRefundedDetails({
+    required this.swapAddress,
+    required this.refundTxId,
+    required this.toAddress,
+    required this.satPerVbyte,
+  });
+}
+
 /// The status of a swap
 enum SwapStatus {
   /// The swap address has been created and either there aren't any confirmed transactions associated with it
@@ -1945,6 +4865,41 @@ class Symbol {
   });
 }
 
+/// Whether a background task is running, see [TaskStatus]
+enum TaskState {
+  Running,
+
+  /// Waiting to be restarted after it failed
+  Restarting,
+
+  /// Completed, on disconnect or once its work is done
+  Stopped,
+}
+
+/// The health of a background task of the SDK, like the sync loop or the swap monitor, returned
+/// by [crate::BreezServices::task_status]
+class TaskStatus {
+  final String name;
+  final TaskState state;
+
+  /// How many times the task was restarted after it failed since connect
+  final int restarts;
+
+  /// The error of the last failure, or the message of the panic, if any
+  final String? lastError;
+
+  /// Epoch time, in seconds, of the last failure
+  final int? lastErrorAt;
+
+  const TaskStatus({
+    required this.name,
+    required this.state,
+    required this.restarts,
+    this.lastError,
+    this.lastErrorAt,
+  });
+}
+
 /// Represents a TLV entry for a keysend payment.
 class TlvEntry {
   /// The type field for the TLV
@@ -1959,6 +4914,54 @@ class TlvEntry {
   });
 }
 
+/// A custom TLV record of a received payment, see [LnPaymentDetails::tlvs]
+class TlvRecord {
+  /// The type field of the TLV
+  final int fieldNumber;
+
+  /// The value decoded as UTF-8 text, if it is valid text
+  final String? valueText;
+
+  /// The hex encoded value, only set if [ListPaymentsRequest::include_tlv_hex] is set
+  final String? valueHex;
+
+  const TlvRecord({
+    required this.fieldNumber,
+    this.valueText,
+    this.valueHex,
+  });
+}
+
+/// Funds that are not yet in the user's balance but can be recovered, included as payload in
+/// [BreezEvent::UnredeemedFundsDetected]
+class UnredeemedFundsDetails {
+  /// Swaps that failed or expired and can be refunded with [BreezServices::refund]
+  final List<SwapInfo> refundableSwaps;
+
+  /// Swaps with confirmed funds whose invoice was not paid yet. They can be retried with
+  /// [BreezServices::redeem_swap]
+  final List<SwapInfo> redeemableSwaps;
+
+  /// Swaps with confirmed funds whose invoice expired unpaid after their channel opening fee
+  /// params expired too. [BreezServices::redeem_swap] needs a new opening fee for them, which
+  /// fails if it's higher than the accepted one, so they may have to be refunded instead
+  final List<SwapInfo> expiredInvoiceSwaps;
+
+  /// Reverse swaps whose lockup tx was broadcast but that are not claimed yet
+  final List<ReverseSwapInfo> unclaimedReverseSwaps;
+
+  /// The total amount of the above, in satoshis
+  final int totalAmountSat;
+
+  const UnredeemedFundsDetails({
+    required this.refundableSwaps,
+    required this.redeemableSwaps,
+    required this.expiredInvoiceSwaps,
+    required this.unclaimedReverseSwaps,
+    required this.totalAmountSat,
+  });
+}
+
 /// UTXO known to the LN node
 class UnspentTransactionOutput {
   final Uint8List txid;
@@ -1986,6 +4989,41 @@ class UrlSuccessActionData {
   });
 }
 
+/// User preferences persisted by the SDK. They are included in the backup, so they roam
+/// across devices with the wallet.
+///
+/// The SDK doesn't interpret them beyond:
+/// - `preferred_fiat_currency` is used to record the fiat rate of payments when
+///   [Config::fiat_currency] is not set
+/// - `preferred_lsp_id` is used to select the LSP on startup when none was selected yet, instead
+///   of [Config::default_lsp_id]
+class UserSettings {
+  final String? preferredFiatCurrency;
+  final Denomination? denomination;
+  final FeeratePreset? defaultFeeratePreset;
+  final String? preferredLspId;
+
+  const UserSettings({
+    this.preferredFiatCurrency,
+    this.denomination,
+    this.defaultFeeratePreset,
+    this.preferredLspId,
+  });
+}
+
+/// Represents a [crate::BreezServices::validate_payability] request.
+class ValidatePayabilityRequest {
+  final String bolt11;
+
+  /// The amount to pay, only for invoices without an amount
+  final MilliSat? amountMsat;
+
+  const ValidatePayabilityRequest({
+    required this.bolt11,
+    this.amountMsat,
+  });
+}
+
 class BreezSdkCoreImpl implements BreezSdkCore {
   final BreezSdkCorePlatform _platform;
   factory BreezSdkCoreImpl(ExternalLibrary dylib) => BreezSdkCoreImpl.raw(BreezSdkCorePlatform(dylib));
@@ -2010,6 +5048,23 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["req"],
       );
 
+  Future<void> connectEphemeral({required Config config, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_config(config);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_connect_ephemeral(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kConnectEphemeralConstMeta,
+      argValues: [config],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kConnectEphemeralConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "connect_ephemeral",
+        argNames: ["config"],
+      );
+
   Future<bool> isInitialized({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_is_initialized(port_),
@@ -2042,53 +5097,133 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
-  Future<NodeCredentials?> nodeCredentials({dynamic hint}) {
+  Future<bool> cancelSync({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_node_credentials(port_),
-      parseSuccessData: _wire2api_opt_box_autoadd_node_credentials,
+      callFfi: (port_) => _platform.inner.wire_cancel_sync(port_),
+      parseSuccessData: _wire2api_bool,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kNodeCredentialsConstMeta,
+      constMeta: kCancelSyncConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kNodeCredentialsConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "node_credentials",
+  FlutterRustBridgeTaskConstMeta get kCancelSyncConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "cancel_sync",
         argNames: [],
       );
 
-  Future<NodeState> nodeInfo({dynamic hint}) {
+  Future<MetricsSnapshot> metrics({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_node_info(port_),
-      parseSuccessData: _wire2api_node_state,
+      callFfi: (port_) => _platform.inner.wire_metrics(port_),
+      parseSuccessData: _wire2api_metrics_snapshot,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kNodeInfoConstMeta,
+      constMeta: kMetricsConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kNodeInfoConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "node_info",
+  FlutterRustBridgeTaskConstMeta get kMetricsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "metrics",
         argNames: [],
       );
 
-  Future<void> configureNode({required ConfigureNodeRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_configure_node_request(req);
+  Future<void> syncPartial({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_configure_node(port_, arg0),
+      callFfi: (port_) => _platform.inner.wire_sync_partial(port_),
       parseSuccessData: _wire2api_unit,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kConfigureNodeConstMeta,
-      argValues: [req],
+      constMeta: kSyncPartialConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kConfigureNodeConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "configure_node",
-        argNames: ["req"],
+  FlutterRustBridgeTaskConstMeta get kSyncPartialConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "sync_partial",
+        argNames: [],
+      );
+
+  Future<NodeCredentials?> nodeCredentials({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_node_credentials(port_),
+      parseSuccessData: _wire2api_opt_box_autoadd_node_credentials,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kNodeCredentialsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNodeCredentialsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "node_credentials",
+        argNames: [],
+      );
+
+  Future<NodeState> nodeInfo({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_node_info(port_),
+      parseSuccessData: _wire2api_node_state,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kNodeInfoConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNodeInfoConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "node_info",
+        argNames: [],
+      );
+
+  Future<Balances> balances({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_balances(port_),
+      parseSuccessData: _wire2api_balances,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBalancesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBalancesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "balances",
+        argNames: [],
+      );
+
+  Future<BalanceBreakdown> getBalanceBreakdown({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_get_balance_breakdown(port_),
+      parseSuccessData: _wire2api_balance_breakdown,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kGetBalanceBreakdownConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetBalanceBreakdownConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_balance_breakdown",
+        argNames: [],
+      );
+
+  Future<void> configureNode({required ConfigureNodeRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_configure_node_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_configure_node(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kConfigureNodeConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kConfigureNodeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "configure_node",
+        argNames: ["req"],
       );
 
   Future<void> disconnect({dynamic hint}) {
@@ -2107,6 +5242,77 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<DeriveEncryptionKeyResponse> deriveEncryptionKey(
+      {required DeriveEncryptionKeyRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_derive_encryption_key_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_derive_encryption_key(port_, arg0),
+      parseSuccessData: _wire2api_derive_encryption_key_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDeriveEncryptionKeyConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDeriveEncryptionKeyConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "derive_encryption_key",
+        argNames: ["req"],
+      );
+
+  Future<ProveAddressOwnershipResponse> proveAddressOwnership(
+      {required ProveAddressOwnershipRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prove_address_ownership_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prove_address_ownership(port_, arg0),
+      parseSuccessData: _wire2api_prove_address_ownership_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kProveAddressOwnershipConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kProveAddressOwnershipConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prove_address_ownership",
+        argNames: ["req"],
+      );
+
+  Future<String> encryptPaymentRequest({required PaymentRequestBundle bundle, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_payment_request_bundle(bundle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_encrypt_payment_request(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kEncryptPaymentRequestConstMeta,
+      argValues: [bundle],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kEncryptPaymentRequestConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "encrypt_payment_request",
+        argNames: ["bundle"],
+      );
+
+  Future<PaymentRequestBundle> decryptPaymentRequest(
+      {required EncryptedPaymentRequestData data, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_encrypted_payment_request_data(data);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_decrypt_payment_request(port_, arg0),
+      parseSuccessData: _wire2api_payment_request_bundle,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDecryptPaymentRequestConstMeta,
+      argValues: [data],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDecryptPaymentRequestConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "decrypt_payment_request",
+        argNames: ["data"],
+      );
+
   Future<SignMessageResponse> signMessage({required SignMessageRequest req, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_sign_message_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2181,6 +5387,74 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["envType", "apiKey", "nodeConfig"],
       );
 
+  Future<SdkInfo> sdkInfo({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_sdk_info(port_),
+      parseSuccessData: _wire2api_sdk_info,
+      parseErrorData: null,
+      constMeta: kSdkInfoConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSdkInfoConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "sdk_info",
+        argNames: [],
+      );
+
+  Future<RecoveryBundle> decryptRecoveryBundle({required DecryptRecoveryBundleRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_decrypt_recovery_bundle_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_decrypt_recovery_bundle(port_, arg0),
+      parseSuccessData: _wire2api_recovery_bundle,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDecryptRecoveryBundleConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDecryptRecoveryBundleConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "decrypt_recovery_bundle",
+        argNames: ["req"],
+      );
+
+  Future<ExportRecoveryBundleResponse> exportRecoveryBundle(
+      {required ExportRecoveryBundleRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_export_recovery_bundle_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_recovery_bundle(port_, arg0),
+      parseSuccessData: _wire2api_export_recovery_bundle_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kExportRecoveryBundleConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportRecoveryBundleConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_recovery_bundle",
+        argNames: ["req"],
+      );
+
+  Future<void> stopRecoveryBundleRefresh({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_stop_recovery_bundle_refresh(port_),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStopRecoveryBundleRefreshConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kStopRecoveryBundleRefreshConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "stop_recovery_bundle_refresh",
+        argNames: [],
+      );
+
   Future<StaticBackupResponse> staticBackup({required StaticBackupRequest req, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_static_backup_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2247,6 +5521,23 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<void> setLogFilter({required String filter, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(filter);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_log_filter(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetLogFilterConstMeta,
+      argValues: [filter],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetLogFilterConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_log_filter",
+        argNames: ["filter"],
+      );
+
   Future<List<LspInformation>> listLsps({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_list_lsps(port_),
@@ -2296,6 +5587,39 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<UserSettings> userSettings({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_user_settings(port_),
+      parseSuccessData: _wire2api_user_settings,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kUserSettingsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kUserSettingsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "user_settings",
+        argNames: [],
+      );
+
+  Future<void> setUserSettings({required UserSettings settings, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_user_settings(settings);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_set_user_settings(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kSetUserSettingsConstMeta,
+      argValues: [settings],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kSetUserSettingsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "set_user_settings",
+        argNames: ["settings"],
+      );
+
   Future<LspInformation?> fetchLspInfo({required String id, dynamic hint}) {
     var arg0 = _platform.api2wire_String(id);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2345,6 +5669,90 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: [],
       );
 
+  Future<List<ChannelDetails>> listChannels({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_channels(port_),
+      parseSuccessData: _wire2api_list_channel_details,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListChannelsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListChannelsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_channels",
+        argNames: [],
+      );
+
+  Future<CloseChannelResponse> closeChannel({required CloseChannelRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_close_channel_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_close_channel(port_, arg0),
+      parseSuccessData: _wire2api_close_channel_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCloseChannelConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCloseChannelConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "close_channel",
+        argNames: ["req"],
+      );
+
+  Future<void> connectPeer({required ConnectPeerRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_connect_peer_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_connect_peer(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kConnectPeerConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kConnectPeerConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "connect_peer",
+        argNames: ["req"],
+      );
+
+  Future<OpenChannelResponse> openChannel({required OpenChannelRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_open_channel_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_open_channel(port_, arg0),
+      parseSuccessData: _wire2api_open_channel_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kOpenChannelConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOpenChannelConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "open_channel",
+        argNames: ["req"],
+      );
+
+  Future<NodeMigrationState> exportNodeMigration({required NodeMigrationRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_node_migration_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_node_migration(port_, arg0),
+      parseSuccessData: _wire2api_node_migration_state,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kExportNodeMigrationConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportNodeMigrationConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_node_migration",
+        argNames: ["req"],
+      );
+
   Future<void> registerWebhook({required String webhookUrl, dynamic hint}) {
     var arg0 = _platform.api2wire_String(webhookUrl);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2379,57 +5787,229 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["webhookUrl"],
       );
 
-  Future<void> backup({dynamic hint}) {
+  Future<LnurlPayInfo> registerLightningAddress({required String username, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(username);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_backup(port_),
-      parseSuccessData: _wire2api_unit,
+      callFfi: (port_) => _platform.inner.wire_register_lightning_address(port_, arg0),
+      parseSuccessData: _wire2api_lnurl_pay_info,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kBackupConstMeta,
-      argValues: [],
+      constMeta: kRegisterLightningAddressConstMeta,
+      argValues: [username],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "backup",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kRegisterLightningAddressConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "register_lightning_address",
+        argNames: ["username"],
       );
 
-  Future<BackupStatus> backupStatus({dynamic hint}) {
+  Future<LnurlPayInfo?> lnurlPayInfo({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_backup_status(port_),
-      parseSuccessData: _wire2api_backup_status,
+      callFfi: (port_) => _platform.inner.wire_lnurl_pay_info(port_),
+      parseSuccessData: _wire2api_opt_box_autoadd_lnurl_pay_info,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kBackupStatusConstMeta,
+      constMeta: kLnurlPayInfoConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kBackupStatusConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "backup_status",
+  FlutterRustBridgeTaskConstMeta get kLnurlPayInfoConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lnurl_pay_info",
         argNames: [],
       );
 
-  Future<LNInvoice> parseInvoice({required String invoice, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(invoice);
+  Future<StaticLnurlPay> createStaticLnurlPay({required CreateStaticLnurlPayRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_create_static_lnurl_pay_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_parse_invoice(port_, arg0),
-      parseSuccessData: _wire2api_ln_invoice,
+      callFfi: (port_) => _platform.inner.wire_create_static_lnurl_pay(port_, arg0),
+      parseSuccessData: _wire2api_static_lnurl_pay,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kParseInvoiceConstMeta,
-      argValues: [invoice],
+      constMeta: kCreateStaticLnurlPayConstMeta,
+      argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kParseInvoiceConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "parse_invoice",
-        argNames: ["invoice"],
+  FlutterRustBridgeTaskConstMeta get kCreateStaticLnurlPayConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_static_lnurl_pay",
+        argNames: ["req"],
       );
 
-  Future<InputType> parseInput({required String input, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(input);
+  Future<String> staticLnurlPayCallback({required StaticLnurlPayCallbackRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_static_lnurl_pay_callback_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_static_lnurl_pay_callback(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kStaticLnurlPayCallbackConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kStaticLnurlPayCallbackConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "static_lnurl_pay_callback",
+        argNames: ["req"],
+      );
+
+  Future<LnurlWithdrawVoucher> createLnurlWithdrawVoucher(
+      {required CreateLnurlWithdrawVoucherRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_create_lnurl_withdraw_voucher_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_create_lnurl_withdraw_voucher(port_, arg0),
+      parseSuccessData: _wire2api_lnurl_withdraw_voucher,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kCreateLnurlWithdrawVoucherConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kCreateLnurlWithdrawVoucherConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "create_lnurl_withdraw_voucher",
+        argNames: ["req"],
+      );
+
+  Future<SendPaymentResponse> redeemLnurlWithdrawVoucher(
+      {required RedeemLnurlWithdrawVoucherRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_redeem_lnurl_withdraw_voucher_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_redeem_lnurl_withdraw_voucher(port_, arg0),
+      parseSuccessData: _wire2api_send_payment_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRedeemLnurlWithdrawVoucherConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRedeemLnurlWithdrawVoucherConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "redeem_lnurl_withdraw_voucher",
+        argNames: ["req"],
+      );
+
+  Future<List<LnurlWithdrawVoucher>> listLnurlWithdrawVouchers({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_lnurl_withdraw_vouchers(port_),
+      parseSuccessData: _wire2api_list_lnurl_withdraw_voucher,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListLnurlWithdrawVouchersConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListLnurlWithdrawVouchersConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_lnurl_withdraw_vouchers",
+        argNames: [],
+      );
+
+  Future<void> backup({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_backup(port_),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBackupConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "backup",
+        argNames: [],
+      );
+
+  Future<BackupStatus> backupStatus({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_backup_status(port_),
+      parseSuccessData: _wire2api_backup_status,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBackupStatusConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBackupStatusConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "backup_status",
+        argNames: [],
+      );
+
+  Future<void> rotateBackupKey({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rotate_backup_key(port_),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRotateBackupKeyConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRotateBackupKeyConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "rotate_backup_key",
+        argNames: [],
+      );
+
+  Future<Uint64List> listBackupVersions({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_backup_versions(port_),
+      parseSuccessData: _wire2api_uint_64_list,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListBackupVersionsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListBackupVersionsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_backup_versions",
+        argNames: [],
+      );
+
+  Future<void> restoreBackup({required int version, dynamic hint}) {
+    var arg0 = _platform.api2wire_u64(version);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_restore_backup(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRestoreBackupConstMeta,
+      argValues: [version],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRestoreBackupConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "restore_backup",
+        argNames: ["version"],
+      );
+
+  Future<LNInvoice> parseInvoice({required String invoice, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(invoice);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_parse_invoice(port_, arg0),
+      parseSuccessData: _wire2api_ln_invoice,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kParseInvoiceConstMeta,
+      argValues: [invoice],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseInvoiceConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_invoice",
+        argNames: ["invoice"],
+      );
+
+  Future<InputType> parseInput({required String input, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(input);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner.wire_parse_input(port_, arg0),
       parseSuccessData: _wire2api_input_type,
@@ -2462,6 +6042,57 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["req"],
       );
 
+  Future<List<PaymentGroup>> listPaymentGroups({required ListPaymentGroupsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_list_payment_groups_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_payment_groups(port_, arg0),
+      parseSuccessData: _wire2api_list_payment_group,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListPaymentGroupsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListPaymentGroupsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_payment_groups",
+        argNames: ["req"],
+      );
+
+  Future<String> exportPayments({required ExportPaymentsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_export_payments_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_payments(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kExportPaymentsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportPaymentsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_payments",
+        argNames: ["req"],
+      );
+
+  Future<ImportPaymentsResponse> importPayments({required ImportPaymentsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_import_payments_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_import_payments(port_, arg0),
+      parseSuccessData: _wire2api_import_payments_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kImportPaymentsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kImportPaymentsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "import_payments",
+        argNames: ["req"],
+      );
+
   Future<Payment?> paymentByHash({required String hash, dynamic hint}) {
     var arg0 = _platform.api2wire_String(hash);
     return _platform.executeNormal(FlutterRustBridgeTask(
@@ -2479,6 +6110,22 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["hash"],
       );
 
+  Future<List<ChannelOpeningFee>> listChannelFeesPaid({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_channel_fees_paid(port_),
+      parseSuccessData: _wire2api_list_channel_opening_fee,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListChannelFeesPaidConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListChannelFeesPaidConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_channel_fees_paid",
+        argNames: [],
+      );
+
   Future<void> setPaymentMetadata({required String hash, required String metadata, dynamic hint}) {
     var arg0 = _platform.api2wire_String(hash);
     var arg1 = _platform.api2wire_String(metadata);
@@ -2514,512 +6161,1053 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         argNames: ["req"],
       );
 
-  Future<SendPaymentResponse> sendSpontaneousPayment(
-      {required SendSpontaneousPaymentRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_send_spontaneous_payment_request(req);
+  Future<SendPaymentsResponse> sendPayments({required SendPaymentsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_send_payments_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_send_spontaneous_payment(port_, arg0),
-      parseSuccessData: _wire2api_send_payment_response,
+      callFfi: (port_) => _platform.inner.wire_send_payments(port_, arg0),
+      parseSuccessData: _wire2api_send_payments_response,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kSendSpontaneousPaymentConstMeta,
+      constMeta: kSendPaymentsConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "send_spontaneous_payment",
+  FlutterRustBridgeTaskConstMeta get kSendPaymentsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "send_payments",
         argNames: ["req"],
       );
 
-  Future<ReceivePaymentResponse> receivePayment({required ReceivePaymentRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_receive_payment_request(req);
+  Future<List<InvoiceVerificationResult>> verifyInvoices({required List<String> invoices, dynamic hint}) {
+    var arg0 = _platform.api2wire_StringList(invoices);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_receive_payment(port_, arg0),
-      parseSuccessData: _wire2api_receive_payment_response,
+      callFfi: (port_) => _platform.inner.wire_verify_invoices(port_, arg0),
+      parseSuccessData: _wire2api_list_invoice_verification_result,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kReceivePaymentConstMeta,
-      argValues: [req],
+      constMeta: kVerifyInvoicesConstMeta,
+      argValues: [invoices],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kReceivePaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "receive_payment",
-        argNames: ["req"],
+  FlutterRustBridgeTaskConstMeta get kVerifyInvoicesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "verify_invoices",
+        argNames: ["invoices"],
       );
 
-  Future<LnUrlPayResult> lnurlPay({required LnUrlPayRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_ln_url_pay_request(req);
+  Future<OutboxPayment> queuePayment({required QueuePaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_queue_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_lnurl_pay(port_, arg0),
-      parseSuccessData: _wire2api_ln_url_pay_result,
+      callFfi: (port_) => _platform.inner.wire_queue_payment(port_, arg0),
+      parseSuccessData: _wire2api_outbox_payment,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kLnurlPayConstMeta,
+      constMeta: kQueuePaymentConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kLnurlPayConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "lnurl_pay",
+  FlutterRustBridgeTaskConstMeta get kQueuePaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "queue_payment",
         argNames: ["req"],
       );
 
-  Future<LnUrlWithdrawResult> lnurlWithdraw({required LnUrlWithdrawRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_ln_url_withdraw_request(req);
+  Future<List<OutboxPayment>> listPendingOutbox({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_lnurl_withdraw(port_, arg0),
-      parseSuccessData: _wire2api_ln_url_withdraw_result,
+      callFfi: (port_) => _platform.inner.wire_list_pending_outbox(port_),
+      parseSuccessData: _wire2api_list_outbox_payment,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kLnurlWithdrawConstMeta,
-      argValues: [req],
+      constMeta: kListPendingOutboxConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kLnurlWithdrawConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "lnurl_withdraw",
-        argNames: ["req"],
+  FlutterRustBridgeTaskConstMeta get kListPendingOutboxConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_pending_outbox",
+        argNames: [],
       );
 
-  Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_ln_url_auth_request_data(reqData);
+  Future<void> cancelOutboxPayment({required int id, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(id);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_lnurl_auth(port_, arg0),
-      parseSuccessData: _wire2api_ln_url_callback_status,
+      callFfi: (port_) => _platform.inner.wire_cancel_outbox_payment(port_, arg0),
+      parseSuccessData: _wire2api_unit,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kLnurlAuthConstMeta,
-      argValues: [reqData],
+      constMeta: kCancelOutboxPaymentConstMeta,
+      argValues: [id],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kLnurlAuthConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "lnurl_auth",
-        argNames: ["reqData"],
+  FlutterRustBridgeTaskConstMeta get kCancelOutboxPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "cancel_outbox_payment",
+        argNames: ["id"],
       );
 
-  Future<void> reportIssue({required ReportIssueRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_report_issue_request(req);
+  Future<PrepareSendPaymentResponse> prepareSendPayment(
+      {required PrepareSendPaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prepare_send_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_report_issue(port_, arg0),
-      parseSuccessData: _wire2api_unit,
+      callFfi: (port_) => _platform.inner.wire_prepare_send_payment(port_, arg0),
+      parseSuccessData: _wire2api_prepare_send_payment_response,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kReportIssueConstMeta,
+      constMeta: kPrepareSendPaymentConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kReportIssueConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "report_issue",
+  FlutterRustBridgeTaskConstMeta get kPrepareSendPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_send_payment",
         argNames: ["req"],
       );
 
-  Future<List<Rate>> fetchFiatRates({dynamic hint}) {
+  Future<SendPaymentResponse> sendSpontaneousPayment(
+      {required SendSpontaneousPaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_send_spontaneous_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_fetch_fiat_rates(port_),
-      parseSuccessData: _wire2api_list_rate,
+      callFfi: (port_) => _platform.inner.wire_send_spontaneous_payment(port_, arg0),
+      parseSuccessData: _wire2api_send_payment_response,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kFetchFiatRatesConstMeta,
-      argValues: [],
+      constMeta: kSendSpontaneousPaymentConstMeta,
+      argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kFetchFiatRatesConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "fetch_fiat_rates",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kSendSpontaneousPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "send_spontaneous_payment",
+        argNames: ["req"],
       );
 
-  Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint}) {
+  Future<bool> cancel({required String cancelToken, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(cancelToken);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_list_fiat_currencies(port_),
-      parseSuccessData: _wire2api_list_fiat_currency,
+      callFfi: (port_) => _platform.inner.wire_cancel(port_, arg0),
+      parseSuccessData: _wire2api_bool,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kListFiatCurrenciesConstMeta,
-      argValues: [],
+      constMeta: kCancelConstMeta,
+      argValues: [cancelToken],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kListFiatCurrenciesConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "list_fiat_currencies",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kCancelConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "cancel",
+        argNames: ["cancelToken"],
       );
 
-  Future<PayOnchainResponse> payOnchain({required PayOnchainRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_pay_onchain_request(req);
+  Future<PaymentProbe> probePayment({required ProbePaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_probe_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_pay_onchain(port_, arg0),
-      parseSuccessData: _wire2api_pay_onchain_response,
+      callFfi: (port_) => _platform.inner.wire_probe_payment(port_, arg0),
+      parseSuccessData: _wire2api_payment_probe,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kPayOnchainConstMeta,
+      constMeta: kProbePaymentConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kPayOnchainConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "pay_onchain",
+  FlutterRustBridgeTaskConstMeta get kProbePaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "probe_payment",
         argNames: ["req"],
       );
 
-  Future<SwapInfo> receiveOnchain({required ReceiveOnchainRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_receive_onchain_request(req);
+  Future<InvoicePayability> validatePayability({required ValidatePayabilityRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_validate_payability_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_receive_onchain(port_, arg0),
-      parseSuccessData: _wire2api_swap_info,
+      callFfi: (port_) => _platform.inner.wire_validate_payability(port_, arg0),
+      parseSuccessData: _wire2api_invoice_payability,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kReceiveOnchainConstMeta,
+      constMeta: kValidatePayabilityConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kReceiveOnchainConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "receive_onchain",
+  FlutterRustBridgeTaskConstMeta get kValidatePayabilityConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "validate_payability",
         argNames: ["req"],
       );
 
-  Future<BuyBitcoinResponse> buyBitcoin({required BuyBitcoinRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_buy_bitcoin_request(req);
+  Future<List<PaymentProbe>> listPaymentProbes({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_buy_bitcoin(port_, arg0),
-      parseSuccessData: _wire2api_buy_bitcoin_response,
+      callFfi: (port_) => _platform.inner.wire_list_payment_probes(port_),
+      parseSuccessData: _wire2api_list_payment_probe,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kBuyBitcoinConstMeta,
+      constMeta: kListPaymentProbesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListPaymentProbesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_payment_probes",
+        argNames: [],
+      );
+
+  Future<SendPaymentResponse> payOffer({required PayOfferRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_pay_offer_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_pay_offer(port_, arg0),
+      parseSuccessData: _wire2api_send_payment_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPayOfferConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kBuyBitcoinConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "buy_bitcoin",
+  FlutterRustBridgeTaskConstMeta get kPayOfferConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "pay_offer",
         argNames: ["req"],
       );
 
-  Future<RedeemOnchainFundsResponse> redeemOnchainFunds(
-      {required RedeemOnchainFundsRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_redeem_onchain_funds_request(req);
+  Future<PrepareReceivePaymentResponse> prepareReceivePayment(
+      {required PrepareReceivePaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prepare_receive_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_redeem_onchain_funds(port_, arg0),
-      parseSuccessData: _wire2api_redeem_onchain_funds_response,
+      callFfi: (port_) => _platform.inner.wire_prepare_receive_payment(port_, arg0),
+      parseSuccessData: _wire2api_prepare_receive_payment_response,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRedeemOnchainFundsConstMeta,
+      constMeta: kPrepareReceivePaymentConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRedeemOnchainFundsConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "redeem_onchain_funds",
+  FlutterRustBridgeTaskConstMeta get kPrepareReceivePaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_receive_payment",
         argNames: ["req"],
       );
 
-  Future<PrepareRedeemOnchainFundsResponse> prepareRedeemOnchainFunds(
-      {required PrepareRedeemOnchainFundsRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_prepare_redeem_onchain_funds_request(req);
+  Future<ReceivePaymentResponse> receivePayment({required ReceivePaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_receive_payment_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_prepare_redeem_onchain_funds(port_, arg0),
-      parseSuccessData: _wire2api_prepare_redeem_onchain_funds_response,
+      callFfi: (port_) => _platform.inner.wire_receive_payment(port_, arg0),
+      parseSuccessData: _wire2api_receive_payment_response,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kPrepareRedeemOnchainFundsConstMeta,
+      constMeta: kReceivePaymentConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kPrepareRedeemOnchainFundsConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "prepare_redeem_onchain_funds",
+  FlutterRustBridgeTaskConstMeta get kReceivePaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_payment",
         argNames: ["req"],
       );
 
-  Future<List<SwapInfo>> listRefundables({dynamic hint}) {
+  Future<ReceiveUnifiedResponse> receiveUnified({required ReceiveUnifiedRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_receive_unified_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_list_refundables(port_),
-      parseSuccessData: _wire2api_list_swap_info,
+      callFfi: (port_) => _platform.inner.wire_receive_unified(port_, arg0),
+      parseSuccessData: _wire2api_receive_unified_response,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kListRefundablesConstMeta,
-      argValues: [],
+      constMeta: kReceiveUnifiedConstMeta,
+      argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kListRefundablesConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "list_refundables",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kReceiveUnifiedConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_unified",
+        argNames: ["req"],
       );
 
-  Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_prepare_refund_request(req);
+  Future<LNInvoice> amendInvoice({required AmendInvoiceRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_amend_invoice_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_prepare_refund(port_, arg0),
-      parseSuccessData: _wire2api_prepare_refund_response,
+      callFfi: (port_) => _platform.inner.wire_amend_invoice(port_, arg0),
+      parseSuccessData: _wire2api_ln_invoice,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kPrepareRefundConstMeta,
+      constMeta: kAmendInvoiceConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kPrepareRefundConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "prepare_refund",
+  FlutterRustBridgeTaskConstMeta get kAmendInvoiceConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "amend_invoice",
         argNames: ["req"],
       );
 
-  Future<RefundResponse> refund({required RefundRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_refund_request(req);
+  Future<LnUrlPayResult> lnurlPay({required LnUrlPayRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_ln_url_pay_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_refund(port_, arg0),
-      parseSuccessData: _wire2api_refund_response,
+      callFfi: (port_) => _platform.inner.wire_lnurl_pay(port_, arg0),
+      parseSuccessData: _wire2api_ln_url_pay_result,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRefundConstMeta,
+      constMeta: kLnurlPayConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRefundConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "refund",
+  FlutterRustBridgeTaskConstMeta get kLnurlPayConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lnurl_pay",
         argNames: ["req"],
       );
 
-  Future<void> rescanSwaps({dynamic hint}) {
+  Future<LnUrlWithdrawResult> lnurlWithdraw({required LnUrlWithdrawRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_ln_url_withdraw_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_rescan_swaps(port_),
-      parseSuccessData: _wire2api_unit,
+      callFfi: (port_) => _platform.inner.wire_lnurl_withdraw(port_, arg0),
+      parseSuccessData: _wire2api_ln_url_withdraw_result,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRescanSwapsConstMeta,
-      argValues: [],
+      constMeta: kLnurlWithdrawConstMeta,
+      argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRescanSwapsConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "rescan_swaps",
-        argNames: [],
+  FlutterRustBridgeTaskConstMeta get kLnurlWithdrawConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lnurl_withdraw",
+        argNames: ["req"],
       );
 
-  Future<void> redeemSwap({required String swapAddress, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(swapAddress);
+  Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_ln_url_auth_request_data(reqData);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_redeem_swap(port_, arg0),
-      parseSuccessData: _wire2api_unit,
+      callFfi: (port_) => _platform.inner.wire_lnurl_auth(port_, arg0),
+      parseSuccessData: _wire2api_ln_url_callback_status,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRedeemSwapConstMeta,
-      argValues: [swapAddress],
+      constMeta: kLnurlAuthConstMeta,
+      argValues: [reqData],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRedeemSwapConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "redeem_swap",
-        argNames: ["swapAddress"],
+  FlutterRustBridgeTaskConstMeta get kLnurlAuthConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lnurl_auth",
+        argNames: ["reqData"],
       );
 
-  Future<SwapInfo?> inProgressSwap({dynamic hint}) {
+  Future<List<LnUrlAuthDomain>> listLnurlAuthDomains({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_in_progress_swap(port_),
-      parseSuccessData: _wire2api_opt_box_autoadd_swap_info,
+      callFfi: (port_) => _platform.inner.wire_list_lnurl_auth_domains(port_),
+      parseSuccessData: _wire2api_list_ln_url_auth_domain,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kInProgressSwapConstMeta,
+      constMeta: kListLnurlAuthDomainsConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kInProgressSwapConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "in_progress_swap",
+  FlutterRustBridgeTaskConstMeta get kListLnurlAuthDomainsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_lnurl_auth_domains",
         argNames: [],
       );
 
-  Future<List<SwapInfo>> listSwaps({required ListSwapsRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_list_swaps_request(req);
+  Future<String> lnurlAuthLinkingKey({required String domain, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(domain);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_list_swaps(port_, arg0),
-      parseSuccessData: _wire2api_list_swap_info,
+      callFfi: (port_) => _platform.inner.wire_lnurl_auth_linking_key(port_, arg0),
+      parseSuccessData: _wire2api_String,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kListSwapsConstMeta,
-      argValues: [req],
+      constMeta: kLnurlAuthLinkingKeyConstMeta,
+      argValues: [domain],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kListSwapsConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "list_swaps",
-        argNames: ["req"],
+  FlutterRustBridgeTaskConstMeta get kLnurlAuthLinkingKeyConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "lnurl_auth_linking_key",
+        argNames: ["domain"],
       );
 
-  Future<void> claimReverseSwap({required String lockupAddress, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(lockupAddress);
+  Future<LnUrlAuthExport> exportLnurlAuth({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_claim_reverse_swap(port_, arg0),
-      parseSuccessData: _wire2api_unit,
+      callFfi: (port_) => _platform.inner.wire_export_lnurl_auth(port_),
+      parseSuccessData: _wire2api_ln_url_auth_export,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kClaimReverseSwapConstMeta,
-      argValues: [lockupAddress],
+      constMeta: kExportLnurlAuthConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kClaimReverseSwapConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "claim_reverse_swap",
-        argNames: ["lockupAddress"],
+  FlutterRustBridgeTaskConstMeta get kExportLnurlAuthConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_lnurl_auth",
+        argNames: [],
       );
 
-  Future<OpenChannelFeeResponse> openChannelFee({required OpenChannelFeeRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_open_channel_fee_request(req);
+  Future<void> importLnurlAuth({required LnUrlAuthExport export, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_ln_url_auth_export(export);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_open_channel_fee(port_, arg0),
-      parseSuccessData: _wire2api_open_channel_fee_response,
+      callFfi: (port_) => _platform.inner.wire_import_lnurl_auth(port_, arg0),
+      parseSuccessData: _wire2api_unit,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kOpenChannelFeeConstMeta,
-      argValues: [req],
+      constMeta: kImportLnurlAuthConstMeta,
+      argValues: [export],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kOpenChannelFeeConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "open_channel_fee",
-        argNames: ["req"],
+  FlutterRustBridgeTaskConstMeta get kImportLnurlAuthConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "import_lnurl_auth",
+        argNames: ["export"],
       );
 
-  Future<ReverseSwapPairInfo> fetchReverseSwapFees({required ReverseSwapFeesRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_reverse_swap_fees_request(req);
+  Future<void> reportIssue({required ReportIssueRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_report_issue_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_fetch_reverse_swap_fees(port_, arg0),
-      parseSuccessData: _wire2api_reverse_swap_pair_info,
+      callFfi: (port_) => _platform.inner.wire_report_issue(port_, arg0),
+      parseSuccessData: _wire2api_unit,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kFetchReverseSwapFeesConstMeta,
+      constMeta: kReportIssueConstMeta,
       argValues: [req],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kFetchReverseSwapFeesConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "fetch_reverse_swap_fees",
+  FlutterRustBridgeTaskConstMeta get kReportIssueConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "report_issue",
         argNames: ["req"],
       );
 
-  Future<OnchainPaymentLimitsResponse> onchainPaymentLimits({dynamic hint}) {
+  Future<ConnectionStatus> connectionStatus({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_onchain_payment_limits(port_),
-      parseSuccessData: _wire2api_onchain_payment_limits_response,
+      callFfi: (port_) => _platform.inner.wire_connection_status(port_),
+      parseSuccessData: _wire2api_connection_status,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kOnchainPaymentLimitsConstMeta,
+      constMeta: kConnectionStatusConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kOnchainPaymentLimitsConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "onchain_payment_limits",
+  FlutterRustBridgeTaskConstMeta get kConnectionStatusConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "connection_status",
         argNames: [],
       );
 
-  Future<PrepareOnchainPaymentResponse> prepareOnchainPayment(
-      {required PrepareOnchainPaymentRequest req, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_prepare_onchain_payment_request(req);
+  Future<List<TaskStatus>> taskStatus({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_prepare_onchain_payment(port_, arg0),
-      parseSuccessData: _wire2api_prepare_onchain_payment_response,
+      callFfi: (port_) => _platform.inner.wire_task_status(port_),
+      parseSuccessData: _wire2api_list_task_status,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kPrepareOnchainPaymentConstMeta,
-      argValues: [req],
+      constMeta: kTaskStatusConstMeta,
+      argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kPrepareOnchainPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "prepare_onchain_payment",
-        argNames: ["req"],
+  FlutterRustBridgeTaskConstMeta get kTaskStatusConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "task_status",
+        argNames: [],
       );
 
-  Future<List<ReverseSwapInfo>> inProgressOnchainPayments({dynamic hint}) {
+  Future<PeerConnectivity> peerConnectivity({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_in_progress_onchain_payments(port_),
-      parseSuccessData: _wire2api_list_reverse_swap_info,
+      callFfi: (port_) => _platform.inner.wire_peer_connectivity(port_),
+      parseSuccessData: _wire2api_peer_connectivity,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kInProgressOnchainPaymentsConstMeta,
+      constMeta: kPeerConnectivityConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kInProgressOnchainPaymentsConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "in_progress_onchain_payments",
+  FlutterRustBridgeTaskConstMeta get kPeerConnectivityConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "peer_connectivity",
         argNames: [],
       );
 
-  Future<RecommendedFees> recommendedFees({dynamic hint}) {
+  Future<List<Rate>> fetchFiatRates({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_recommended_fees(port_),
-      parseSuccessData: _wire2api_recommended_fees,
+      callFfi: (port_) => _platform.inner.wire_fetch_fiat_rates(port_),
+      parseSuccessData: _wire2api_list_rate,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kRecommendedFeesConstMeta,
+      constMeta: kFetchFiatRatesConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRecommendedFeesConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "recommended_fees",
+  FlutterRustBridgeTaskConstMeta get kFetchFiatRatesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fetch_fiat_rates",
         argNames: [],
       );
 
-  Future<String> executeCommand({required String command, dynamic hint}) {
-    var arg0 = _platform.api2wire_String(command);
+  Future<List<HistoricalRate>> fiatRateHistory(
+      {required String currency, int? fromTimestamp, int? toTimestamp, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(currency);
+    var arg1 = _platform.api2wire_opt_box_autoadd_i64(fromTimestamp);
+    var arg2 = _platform.api2wire_opt_box_autoadd_i64(toTimestamp);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_execute_command(port_, arg0),
-      parseSuccessData: _wire2api_String,
+      callFfi: (port_) => _platform.inner.wire_fiat_rate_history(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_list_historical_rate,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kExecuteCommandConstMeta,
-      argValues: [command],
+      constMeta: kFiatRateHistoryConstMeta,
+      argValues: [currency, fromTimestamp, toTimestamp],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kExecuteCommandConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "execute_command",
-        argNames: ["command"],
+  FlutterRustBridgeTaskConstMeta get kFiatRateHistoryConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fiat_rate_history",
+        argNames: ["currency", "fromTimestamp", "toTimestamp"],
       );
 
-  Future<String> generateDiagnosticData({dynamic hint}) {
+  Future<FiatRateAlert> addFiatRateAlert({required AddFiatRateAlertRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_add_fiat_rate_alert_request(req);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_generate_diagnostic_data(port_),
-      parseSuccessData: _wire2api_String,
+      callFfi: (port_) => _platform.inner.wire_add_fiat_rate_alert(port_, arg0),
+      parseSuccessData: _wire2api_fiat_rate_alert,
       parseErrorData: _wire2api_FrbAnyhowException,
-      constMeta: kGenerateDiagnosticDataConstMeta,
+      constMeta: kAddFiatRateAlertConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kAddFiatRateAlertConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "add_fiat_rate_alert",
+        argNames: ["req"],
+      );
+
+  Future<List<FiatRateAlert>> listFiatRateAlerts({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_fiat_rate_alerts(port_),
+      parseSuccessData: _wire2api_list_fiat_rate_alert,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListFiatRateAlertsConstMeta,
       argValues: [],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGenerateDiagnosticDataConstMeta => const FlutterRustBridgeTaskConstMeta(
-        debugName: "generate_diagnostic_data",
+  FlutterRustBridgeTaskConstMeta get kListFiatRateAlertsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_fiat_rate_alerts",
         argNames: [],
       );
 
-  void dispose() {
-    _platform.dispose();
+  Future<void> removeFiatRateAlert({required int id, dynamic hint}) {
+    var arg0 = _platform.api2wire_i64(id);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_remove_fiat_rate_alert(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRemoveFiatRateAlertConstMeta,
+      argValues: [id],
+      hint: hint,
+    ));
   }
-// Section: wire2api
 
-  FrbAnyhowException _wire2api_FrbAnyhowException(dynamic raw) {
-    return FrbAnyhowException(raw as String);
-  }
+  FlutterRustBridgeTaskConstMeta get kRemoveFiatRateAlertConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "remove_fiat_rate_alert",
+        argNames: ["id"],
+      );
 
-  String _wire2api_String(dynamic raw) {
-    return raw as String;
+  Future<List<FiatCurrency>> listFiatCurrencies({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_fiat_currencies(port_),
+      parseSuccessData: _wire2api_list_fiat_currency,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListFiatCurrenciesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
   }
 
-  List<String> _wire2api_StringList(dynamic raw) {
-    return (raw as List<dynamic>).cast<String>();
-  }
+  FlutterRustBridgeTaskConstMeta get kListFiatCurrenciesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_fiat_currencies",
+        argNames: [],
+      );
+
+  Future<PayOnchainResponse> payOnchain({required PayOnchainRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_pay_onchain_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_pay_onchain(port_, arg0),
+      parseSuccessData: _wire2api_pay_onchain_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPayOnchainConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPayOnchainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "pay_onchain",
+        argNames: ["req"],
+      );
+
+  Future<PayOnchainAddressResponse> payOnchainAddress({required PayOnchainAddressRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_pay_onchain_address_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_pay_onchain_address(port_, arg0),
+      parseSuccessData: _wire2api_pay_onchain_address_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPayOnchainAddressConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPayOnchainAddressConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "pay_onchain_address",
+        argNames: ["req"],
+      );
+
+  Future<SwapInfo> receiveOnchain({required ReceiveOnchainRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_receive_onchain_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_receive_onchain(port_, arg0),
+      parseSuccessData: _wire2api_swap_info,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kReceiveOnchainConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kReceiveOnchainConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "receive_onchain",
+        argNames: ["req"],
+      );
+
+  Future<BuyBitcoinResponse> buyBitcoin({required BuyBitcoinRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_buy_bitcoin_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_buy_bitcoin(port_, arg0),
+      parseSuccessData: _wire2api_buy_bitcoin_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBuyBitcoinConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBuyBitcoinConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "buy_bitcoin",
+        argNames: ["req"],
+      );
+
+  Future<BuyBitcoinQuote> fetchBuyBitcoinQuote({required FetchBuyBitcoinQuoteRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_fetch_buy_bitcoin_quote_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_fetch_buy_bitcoin_quote(port_, arg0),
+      parseSuccessData: _wire2api_buy_bitcoin_quote,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kFetchBuyBitcoinQuoteConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFetchBuyBitcoinQuoteConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fetch_buy_bitcoin_quote",
+        argNames: ["req"],
+      );
+
+  Future<RedeemOnchainFundsResponse> redeemOnchainFunds(
+      {required RedeemOnchainFundsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_redeem_onchain_funds_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_redeem_onchain_funds(port_, arg0),
+      parseSuccessData: _wire2api_redeem_onchain_funds_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRedeemOnchainFundsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRedeemOnchainFundsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "redeem_onchain_funds",
+        argNames: ["req"],
+      );
+
+  Future<BumpFeeResponse> bumpFee({required BumpFeeRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_bump_fee_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_bump_fee(port_, arg0),
+      parseSuccessData: _wire2api_bump_fee_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kBumpFeeConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBumpFeeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "bump_fee",
+        argNames: ["req"],
+      );
+
+  Future<PrepareRedeemOnchainFundsResponse> prepareRedeemOnchainFunds(
+      {required PrepareRedeemOnchainFundsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prepare_redeem_onchain_funds_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prepare_redeem_onchain_funds(port_, arg0),
+      parseSuccessData: _wire2api_prepare_redeem_onchain_funds_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPrepareRedeemOnchainFundsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrepareRedeemOnchainFundsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_redeem_onchain_funds",
+        argNames: ["req"],
+      );
+
+  Future<List<SwapInfo>> listRefundables({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_refundables(port_),
+      parseSuccessData: _wire2api_list_swap_info,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListRefundablesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListRefundablesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_refundables",
+        argNames: [],
+      );
+
+  Future<List<SwapRefundKey>> exportSwapRefundKeys({required ExportSwapRefundKeysRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_export_swap_refund_keys_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_export_swap_refund_keys(port_, arg0),
+      parseSuccessData: _wire2api_list_swap_refund_key,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kExportSwapRefundKeysConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExportSwapRefundKeysConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "export_swap_refund_keys",
+        argNames: ["req"],
+      );
+
+  Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prepare_refund_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prepare_refund(port_, arg0),
+      parseSuccessData: _wire2api_prepare_refund_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPrepareRefundConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrepareRefundConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_refund",
+        argNames: ["req"],
+      );
+
+  Future<RefundResponse> refund({required RefundRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_refund_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_refund(port_, arg0),
+      parseSuccessData: _wire2api_refund_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRefundConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRefundConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "refund",
+        argNames: ["req"],
+      );
+
+  Future<void> rescanSwaps({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_rescan_swaps(port_),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRescanSwapsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRescanSwapsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "rescan_swaps",
+        argNames: [],
+      );
+
+  Future<void> redeemSwap({required String swapAddress, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(swapAddress);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_redeem_swap(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRedeemSwapConstMeta,
+      argValues: [swapAddress],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRedeemSwapConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "redeem_swap",
+        argNames: ["swapAddress"],
+      );
+
+  Future<bool> verifySwapAddress({required String swapAddress, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(swapAddress);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_verify_swap_address(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kVerifySwapAddressConstMeta,
+      argValues: [swapAddress],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kVerifySwapAddressConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "verify_swap_address",
+        argNames: ["swapAddress"],
+      );
+
+  Future<SwapInfo?> inProgressSwap({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_in_progress_swap(port_),
+      parseSuccessData: _wire2api_opt_box_autoadd_swap_info,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kInProgressSwapConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInProgressSwapConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "in_progress_swap",
+        argNames: [],
+      );
+
+  Future<List<SwapInfo>> listSwaps({required ListSwapsRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_list_swaps_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_list_swaps(port_, arg0),
+      parseSuccessData: _wire2api_list_swap_info,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kListSwapsConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kListSwapsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "list_swaps",
+        argNames: ["req"],
+      );
+
+  Future<void> claimReverseSwap({required String lockupAddress, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(lockupAddress);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_claim_reverse_swap(port_, arg0),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kClaimReverseSwapConstMeta,
+      argValues: [lockupAddress],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kClaimReverseSwapConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "claim_reverse_swap",
+        argNames: ["lockupAddress"],
+      );
+
+  Future<OpenChannelFeeResponse> openChannelFee({required OpenChannelFeeRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_open_channel_fee_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_open_channel_fee(port_, arg0),
+      parseSuccessData: _wire2api_open_channel_fee_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kOpenChannelFeeConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOpenChannelFeeConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "open_channel_fee",
+        argNames: ["req"],
+      );
+
+  Future<ReverseSwapPairInfo> fetchReverseSwapFees({required ReverseSwapFeesRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_reverse_swap_fees_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_fetch_reverse_swap_fees(port_, arg0),
+      parseSuccessData: _wire2api_reverse_swap_pair_info,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kFetchReverseSwapFeesConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kFetchReverseSwapFeesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "fetch_reverse_swap_fees",
+        argNames: ["req"],
+      );
+
+  Future<OnchainPaymentLimitsResponse> onchainPaymentLimits({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_onchain_payment_limits(port_),
+      parseSuccessData: _wire2api_onchain_payment_limits_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kOnchainPaymentLimitsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kOnchainPaymentLimitsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "onchain_payment_limits",
+        argNames: [],
+      );
+
+  Future<PrepareOnchainPaymentResponse> prepareOnchainPayment(
+      {required PrepareOnchainPaymentRequest req, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_prepare_onchain_payment_request(req);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_prepare_onchain_payment(port_, arg0),
+      parseSuccessData: _wire2api_prepare_onchain_payment_response,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kPrepareOnchainPaymentConstMeta,
+      argValues: [req],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kPrepareOnchainPaymentConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "prepare_onchain_payment",
+        argNames: ["req"],
+      );
+
+  Future<List<ReverseSwapInfo>> inProgressOnchainPayments({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_in_progress_onchain_payments(port_),
+      parseSuccessData: _wire2api_list_reverse_swap_info,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kInProgressOnchainPaymentsConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInProgressOnchainPaymentsConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "in_progress_onchain_payments",
+        argNames: [],
+      );
+
+  Future<RecommendedFees> recommendedFees({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_recommended_fees(port_),
+      parseSuccessData: _wire2api_recommended_fees,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kRecommendedFeesConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRecommendedFeesConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "recommended_fees",
+        argNames: [],
+      );
+
+  Future<String> executeCommand({required String command, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(command);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_execute_command(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kExecuteCommandConstMeta,
+      argValues: [command],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kExecuteCommandConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "execute_command",
+        argNames: ["command"],
+      );
+
+  Future<String> generateDiagnosticData({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_generate_diagnostic_data(port_),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kGenerateDiagnosticDataConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGenerateDiagnosticDataConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "generate_diagnostic_data",
+        argNames: [],
+      );
+
+  Future<String> diagnosticSnapshot({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_diagnostic_snapshot(port_),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDiagnosticSnapshotConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDiagnosticSnapshotConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "diagnostic_snapshot",
+        argNames: [],
+      );
+
+  Future<List<SnapshotChange>> diffSnapshots({required String a, required String b, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(a);
+    var arg1 = _platform.api2wire_String(b);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_diff_snapshots(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_snapshot_change,
+      parseErrorData: _wire2api_FrbAnyhowException,
+      constMeta: kDiffSnapshotsConstMeta,
+      argValues: [a, b],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kDiffSnapshotsConstMeta => const FlutterRustBridgeTaskConstMeta(
+        debugName: "diff_snapshots",
+        argNames: ["a", "b"],
+      );
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  FrbAnyhowException _wire2api_FrbAnyhowException(dynamic raw) {
+    return FrbAnyhowException(raw as String);
+  }
+
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
+
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
 
   AesSuccessActionDataDecrypted _wire2api_aes_success_action_data_decrypted(dynamic raw) {
     final arr = raw as List<dynamic>;
@@ -3045,6 +7233,31 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     }
   }
 
+  Amount _wire2api_amount(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return Amount_Bitcoin(
+          amountMsat: _wire2api_u64(raw[1]),
+        );
+      case 1:
+        return Amount_Currency(
+          iso4217Code: _wire2api_String(raw[1]),
+          fractionalAmount: _wire2api_u64(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  AutoRefundConfig _wire2api_auto_refund_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AutoRefundConfig(
+      toAddress: _wire2api_opt_String(arr[0]),
+      satPerVbyte: _wire2api_opt_box_autoadd_u32(arr[1]),
+    );
+  }
+
   BackupFailedData _wire2api_backup_failed_data(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3062,6 +7275,80 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  BackupTransportConfig _wire2api_backup_transport_config(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return BackupTransportConfig_S3(
+          endpoint: _wire2api_String(raw[1]),
+          region: _wire2api_String(raw[2]),
+          bucket: _wire2api_String(raw[3]),
+          accessKeyId: _wire2api_String(raw[4]),
+          secretAccessKey: _wire2api_String(raw[5]),
+        );
+      case 1:
+        return BackupTransportConfig_WebDav(
+          url: _wire2api_String(raw[1]),
+          username: _wire2api_opt_String(raw[2]),
+          password: _wire2api_opt_String(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  BalanceBreakdown _wire2api_balance_breakdown(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return BalanceBreakdown(
+      spendableMsat: _wire2api_u64(arr[0]),
+      channels: _wire2api_list_channel_details(arr[1]),
+      channelReservesMsat: _wire2api_u64(arr[2]),
+      htlcsOutMsat: _wire2api_u64(arr[3]),
+      htlcsInMsat: _wire2api_u64(arr[4]),
+      dustHtlcsMsat: _wire2api_u64(arr[5]),
+      pendingChannelOpensMsat: _wire2api_u64(arr[6]),
+      onchainConfirmedMsat: _wire2api_u64(arr[7]),
+      onchainUnconfirmedMsat: _wire2api_u64(arr[8]),
+      swapsLockedMsat: _wire2api_u64(arr[9]),
+      reverseSwapsLockedMsat: _wire2api_u64(arr[10]),
+    );
+  }
+
+  Balances _wire2api_balances(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Balances(
+      lightningSpendableMsat: _wire2api_u64(arr[0]),
+      lightningPendingMsat: _wire2api_u64(arr[1]),
+      onchainConfirmedMsat: _wire2api_u64(arr[2]),
+      onchainUnconfirmedMsat: _wire2api_u64(arr[3]),
+      swapsLockedMsat: _wire2api_u64(arr[4]),
+      reverseSwapsLockedMsat: _wire2api_u64(arr[5]),
+    );
+  }
+
+  BatchPaymentProgressDetails _wire2api_batch_payment_progress_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BatchPaymentProgressDetails(
+      result: _wire2api_batch_payment_result(arr[0]),
+      succeeded: _wire2api_u32(arr[1]),
+      failed: _wire2api_u32(arr[2]),
+      total: _wire2api_u32(arr[3]),
+    );
+  }
+
+  BatchPaymentResult _wire2api_batch_payment_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BatchPaymentResult(
+      index: _wire2api_u32(arr[0]),
+      bolt11: _wire2api_String(arr[1]),
+      payment: _wire2api_opt_box_autoadd_payment(arr[2]),
+      error: _wire2api_opt_String(arr[3]),
+    );
+  }
+
   BitcoinAddressData _wire2api_bitcoin_address_data(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
@@ -3086,10 +7373,26 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_aes_success_action_data_result(raw);
   }
 
+  Amount _wire2api_box_autoadd_amount(dynamic raw) {
+    return _wire2api_amount(raw);
+  }
+
+  AutoRefundConfig _wire2api_box_autoadd_auto_refund_config(dynamic raw) {
+    return _wire2api_auto_refund_config(raw);
+  }
+
   BackupFailedData _wire2api_box_autoadd_backup_failed_data(dynamic raw) {
     return _wire2api_backup_failed_data(raw);
   }
 
+  BackupTransportConfig _wire2api_box_autoadd_backup_transport_config(dynamic raw) {
+    return _wire2api_backup_transport_config(raw);
+  }
+
+  BatchPaymentProgressDetails _wire2api_box_autoadd_batch_payment_progress_details(dynamic raw) {
+    return _wire2api_batch_payment_progress_details(raw);
+  }
+
   BitcoinAddressData _wire2api_box_autoadd_bitcoin_address_data(dynamic raw) {
     return _wire2api_bitcoin_address_data(raw);
   }
@@ -3098,10 +7401,46 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw as bool;
   }
 
+  ChainServiceConfig _wire2api_box_autoadd_chain_service_config(dynamic raw) {
+    return _wire2api_chain_service_config(raw);
+  }
+
+  ChannelHygieneSuggestionDetails _wire2api_box_autoadd_channel_hygiene_suggestion_details(dynamic raw) {
+    return _wire2api_channel_hygiene_suggestion_details(raw);
+  }
+
   ClosedChannelPaymentDetails _wire2api_box_autoadd_closed_channel_payment_details(dynamic raw) {
     return _wire2api_closed_channel_payment_details(raw);
   }
 
+  ConnectProgressDetails _wire2api_box_autoadd_connect_progress_details(dynamic raw) {
+    return _wire2api_connect_progress_details(raw);
+  }
+
+  Denomination _wire2api_box_autoadd_denomination(dynamic raw) {
+    return _wire2api_denomination(raw);
+  }
+
+  EncryptedPaymentRequestData _wire2api_box_autoadd_encrypted_payment_request_data(dynamic raw) {
+    return _wire2api_encrypted_payment_request_data(raw);
+  }
+
+  double _wire2api_box_autoadd_f64(dynamic raw) {
+    return raw as double;
+  }
+
+  FeeratePreset _wire2api_box_autoadd_feerate_preset(dynamic raw) {
+    return _wire2api_feerate_preset(raw);
+  }
+
+  FiatAmount _wire2api_box_autoadd_fiat_amount(dynamic raw) {
+    return _wire2api_fiat_amount(raw);
+  }
+
+  FiatRateAlertTriggeredDetails _wire2api_box_autoadd_fiat_rate_alert_triggered_details(dynamic raw) {
+    return _wire2api_fiat_rate_alert_triggered_details(raw);
+  }
+
   GreenlightCredentials _wire2api_box_autoadd_greenlight_credentials(dynamic raw) {
     return _wire2api_greenlight_credentials(raw);
   }
@@ -3114,6 +7453,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_greenlight_node_config(raw);
   }
 
+  HtlcExposureLimits _wire2api_box_autoadd_htlc_exposure_limits(dynamic raw) {
+    return _wire2api_htlc_exposure_limits(raw);
+  }
+
+  int _wire2api_box_autoadd_i64(dynamic raw) {
+    return _wire2api_i64(raw);
+  }
+
   InvoicePaidDetails _wire2api_box_autoadd_invoice_paid_details(dynamic raw) {
     return _wire2api_invoice_paid_details(raw);
   }
@@ -3122,10 +7469,18 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_ln_invoice(raw);
   }
 
+  LNOffer _wire2api_box_autoadd_ln_offer(dynamic raw) {
+    return _wire2api_ln_offer(raw);
+  }
+
   LnPaymentDetails _wire2api_box_autoadd_ln_payment_details(dynamic raw) {
     return _wire2api_ln_payment_details(raw);
   }
 
+  LnUrlAuthDomain _wire2api_box_autoadd_ln_url_auth_domain(dynamic raw) {
+    return _wire2api_ln_url_auth_domain(raw);
+  }
+
   LnUrlAuthRequestData _wire2api_box_autoadd_ln_url_auth_request_data(dynamic raw) {
     return _wire2api_ln_url_auth_request_data(raw);
   }
@@ -3138,6 +7493,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_ln_url_pay_error_data(raw);
   }
 
+  LnUrlPayFinishedDetails _wire2api_box_autoadd_ln_url_pay_finished_details(dynamic raw) {
+    return _wire2api_ln_url_pay_finished_details(raw);
+  }
+
+  LnUrlPayPendingData _wire2api_box_autoadd_ln_url_pay_pending_data(dynamic raw) {
+    return _wire2api_ln_url_pay_pending_data(raw);
+  }
+
   LnUrlPayRequestData _wire2api_box_autoadd_ln_url_pay_request_data(dynamic raw) {
     return _wire2api_ln_url_pay_request_data(raw);
   }
@@ -3154,22 +7517,58 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_ln_url_withdraw_success_data(raw);
   }
 
+  LnurlPayInfo _wire2api_box_autoadd_lnurl_pay_info(dynamic raw) {
+    return _wire2api_lnurl_pay_info(raw);
+  }
+
+  LowLiquidityDetails _wire2api_box_autoadd_low_liquidity_details(dynamic raw) {
+    return _wire2api_low_liquidity_details(raw);
+  }
+
   LspInformation _wire2api_box_autoadd_lsp_information(dynamic raw) {
     return _wire2api_lsp_information(raw);
   }
 
+  LspPolicy _wire2api_box_autoadd_lsp_policy(dynamic raw) {
+    return _wire2api_lsp_policy(raw);
+  }
+
   MessageSuccessActionData _wire2api_box_autoadd_message_success_action_data(dynamic raw) {
     return _wire2api_message_success_action_data(raw);
   }
 
+  MilliSat _wire2api_box_autoadd_milli_sat(dynamic raw) {
+    return _wire2api_milli_sat(raw);
+  }
+
+  MockNodeConfig _wire2api_box_autoadd_mock_node_config(dynamic raw) {
+    return _wire2api_mock_node_config(raw);
+  }
+
   NodeCredentials _wire2api_box_autoadd_node_credentials(dynamic raw) {
     return _wire2api_node_credentials(raw);
   }
 
+  OpenChannelReceiveDetails _wire2api_box_autoadd_open_channel_receive_details(dynamic raw) {
+    return _wire2api_open_channel_receive_details(raw);
+  }
+
   OpeningFeeParams _wire2api_box_autoadd_opening_fee_params(dynamic raw) {
     return _wire2api_opening_fee_params(raw);
   }
 
+  OutboxPaymentDetails _wire2api_box_autoadd_outbox_payment_details(dynamic raw) {
+    return _wire2api_outbox_payment_details(raw);
+  }
+
+  PartnerFeeConfig _wire2api_box_autoadd_partner_fee_config(dynamic raw) {
+    return _wire2api_partner_fee_config(raw);
+  }
+
+  PartnerFeeDetails _wire2api_box_autoadd_partner_fee_details(dynamic raw) {
+    return _wire2api_partner_fee_details(raw);
+  }
+
   Payment _wire2api_box_autoadd_payment(dynamic raw) {
     return _wire2api_payment(raw);
   }
@@ -3178,10 +7577,30 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_payment_failed_data(raw);
   }
 
+  ProxyConfig _wire2api_box_autoadd_proxy_config(dynamic raw) {
+    return _wire2api_proxy_config(raw);
+  }
+
+  Rate _wire2api_box_autoadd_rate(dynamic raw) {
+    return _wire2api_rate(raw);
+  }
+
   ReverseSwapInfo _wire2api_box_autoadd_reverse_swap_info(dynamic raw) {
     return _wire2api_reverse_swap_info(raw);
   }
 
+  RouteHint _wire2api_box_autoadd_route_hint(dynamic raw) {
+    return _wire2api_route_hint(raw);
+  }
+
+  SpendPolicy _wire2api_box_autoadd_spend_policy(dynamic raw) {
+    return _wire2api_spend_policy(raw);
+  }
+
+  SpontaneousPaymentMetadata _wire2api_box_autoadd_spontaneous_payment_metadata(dynamic raw) {
+    return _wire2api_spontaneous_payment_metadata(raw);
+  }
+
   SuccessActionProcessed _wire2api_box_autoadd_success_action_processed(dynamic raw) {
     return _wire2api_success_action_processed(raw);
   }
@@ -3190,6 +7609,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_swap_info(raw);
   }
 
+  SwapRefundedDetails _wire2api_box_autoadd_swap_refunded_details(dynamic raw) {
+    return _wire2api_swap_refunded_details(raw);
+  }
+
   Symbol _wire2api_box_autoadd_symbol(dynamic raw) {
     return _wire2api_symbol(raw);
   }
@@ -3202,6 +7625,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return _wire2api_u64(raw);
   }
 
+  UnredeemedFundsDetails _wire2api_box_autoadd_unredeemed_funds_details(dynamic raw) {
+    return _wire2api_unredeemed_funds_details(raw);
+  }
+
   UrlSuccessActionData _wire2api_box_autoadd_url_success_action_data(dynamic raw) {
     return _wire2api_url_success_action_data(raw);
   }
@@ -3242,11 +7669,114 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         return BreezEvent_SwapUpdated(
           details: _wire2api_box_autoadd_swap_info(raw[1]),
         );
+      case 10:
+        return BreezEvent_ConnectProgress(
+          details: _wire2api_box_autoadd_connect_progress_details(raw[1]),
+        );
+      case 11:
+        return BreezEvent_UnredeemedFundsDetected(
+          details: _wire2api_box_autoadd_unredeemed_funds_details(raw[1]),
+        );
+      case 12:
+        return BreezEvent_LnUrlPayFinished(
+          details: _wire2api_box_autoadd_ln_url_pay_finished_details(raw[1]),
+        );
+      case 13:
+        return BreezEvent_OpenChannelReceiveUpdated(
+          details: _wire2api_box_autoadd_open_channel_receive_details(raw[1]),
+        );
+      case 14:
+        return BreezEvent_LowOutboundLiquidity(
+          details: _wire2api_box_autoadd_low_liquidity_details(raw[1]),
+        );
+      case 15:
+        return BreezEvent_LowInboundLiquidity(
+          details: _wire2api_box_autoadd_low_liquidity_details(raw[1]),
+        );
+      case 16:
+        return BreezEvent_ChannelOpening(
+          feeMsat: _wire2api_u64(raw[1]),
+          minFeeMsat: _wire2api_u64(raw[2]),
+          proportional: _wire2api_u32(raw[3]),
+        );
+      case 17:
+        return BreezEvent_LspChanged(
+          previousLspId: _wire2api_opt_String(raw[1]),
+          lspId: _wire2api_String(raw[2]),
+        );
+      case 18:
+        return BreezEvent_SwapRefunded(
+          details: _wire2api_box_autoadd_swap_refunded_details(raw[1]),
+        );
+      case 19:
+        return BreezEvent_BatchPaymentProgress(
+          details: _wire2api_box_autoadd_batch_payment_progress_details(raw[1]),
+        );
+      case 20:
+        return BreezEvent_OutboxPaymentUpdated(
+          details: _wire2api_box_autoadd_outbox_payment_details(raw[1]),
+        );
+      case 21:
+        return BreezEvent_LnUrlAuthCompleted(
+          details: _wire2api_box_autoadd_ln_url_auth_domain(raw[1]),
+        );
+      case 22:
+        return BreezEvent_ChannelHygieneSuggestion(
+          details: _wire2api_box_autoadd_channel_hygiene_suggestion_details(raw[1]),
+        );
+      case 23:
+        return BreezEvent_FiatRateAlertTriggered(
+          details: _wire2api_box_autoadd_fiat_rate_alert_triggered_details(raw[1]),
+        );
+      case 24:
+        return BreezEvent_Connected();
+      case 25:
+        return BreezEvent_Disconnected(
+          error: _wire2api_String(raw[1]),
+        );
+      case 26:
+        return BreezEvent_Reconnecting(
+          attempt: _wire2api_u32(raw[1]),
+          delayMs: _wire2api_u64(raw[2]),
+        );
+      case 27:
+        return BreezEvent_LspPeerReconnected(
+          lspPubkey: _wire2api_String(raw[1]),
+          reason: _wire2api_String(raw[2]),
+        );
+      case 28:
+        return BreezEvent_SpontaneousPaymentReceived(
+          details: _wire2api_box_autoadd_payment(raw[1]),
+        );
+      case 29:
+        return BreezEvent_ServiceStatusChanged(
+          component: _wire2api_service_component(raw[1]),
+          status: _wire2api_service_status(raw[2]),
+        );
       default:
         throw Exception("unreachable");
     }
   }
 
+  BumpFeeResponse _wire2api_bump_fee_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return BumpFeeResponse(
+      txid: _wire2api_String(arr[0]),
+    );
+  }
+
+  BuyBitcoinQuote _wire2api_buy_bitcoin_quote(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BuyBitcoinQuote(
+      fiatCurrency: _wire2api_String(arr[0]),
+      fiatAmount: _wire2api_f64(arr[1]),
+      feesFiat: _wire2api_f64(arr[2]),
+      amountSat: _wire2api_u64(arr[3]),
+    );
+  }
+
   BuyBitcoinResponse _wire2api_buy_bitcoin_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
@@ -3256,6 +7786,72 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  ChainServiceConfig _wire2api_chain_service_config(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return ChainServiceConfig_Esplora(
+          url: _wire2api_String(raw[1]),
+        );
+      case 1:
+        return ChainServiceConfig_Electrum(
+          url: _wire2api_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  ChannelDetails _wire2api_channel_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return ChannelDetails(
+      channelId: _wire2api_String(arr[0]),
+      peerId: _wire2api_String(arr[1]),
+      peerConnected: _wire2api_bool(arr[2]),
+      shortChannelId: _wire2api_opt_String(arr[3]),
+      fundingTxid: _wire2api_String(arr[4]),
+      state: _wire2api_channel_state(arr[5]),
+      capacityMsat: _wire2api_u64(arr[6]),
+      localBalanceMsat: _wire2api_u64(arr[7]),
+      remoteBalanceMsat: _wire2api_u64(arr[8]),
+      spendableMsat: _wire2api_u64(arr[9]),
+      receivableMsat: _wire2api_u64(arr[10]),
+      reserveMsat: _wire2api_u64(arr[11]),
+      htlcsOutMsat: _wire2api_u64(arr[12]),
+      htlcsInMsat: _wire2api_u64(arr[13]),
+      dustHtlcsMsat: _wire2api_u64(arr[14]),
+    );
+  }
+
+  ChannelHygieneSuggestionDetails _wire2api_channel_hygiene_suggestion_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return ChannelHygieneSuggestionDetails(
+      channelId: _wire2api_String(arr[0]),
+      peerId: _wire2api_String(arr[1]),
+      fundingTxid: _wire2api_String(arr[2]),
+      localBalanceMsat: _wire2api_u64(arr[3]),
+      idleSecs: _wire2api_u64(arr[4]),
+      peerOfflineSecs: _wire2api_u64(arr[5]),
+      satPerVbyte: _wire2api_u64(arr[6]),
+      projectedSweepFeeSat: _wire2api_u64(arr[7]),
+      projectedSweepAmountSat: _wire2api_u64(arr[8]),
+    );
+  }
+
+  ChannelOpeningFee _wire2api_channel_opening_fee(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ChannelOpeningFee(
+      paymentHash: _wire2api_String(arr[0]),
+      paymentTime: _wire2api_i64(arr[1]),
+      payerAmountMsat: _wire2api_u64(arr[2]),
+      receivedAmountMsat: _wire2api_u64(arr[3]),
+      feeMsat: _wire2api_u64(arr[4]),
+      openingFeeParams: _wire2api_opt_box_autoadd_opening_fee_params(arr[5]),
+    );
+  }
+
   ChannelState _wire2api_channel_state(dynamic raw) {
     return ChannelState.values[raw as int];
   }
@@ -3268,6 +7864,14 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  CloseChannelResponse _wire2api_close_channel_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return CloseChannelResponse(
+      closingTxid: _wire2api_opt_String(arr[0]),
+    );
+  }
+
   ClosedChannelPaymentDetails _wire2api_closed_channel_payment_details(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
@@ -3279,48 +7883,171 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
-  Config _wire2api_config(dynamic raw) {
+  Config _wire2api_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 33) throw Exception('unexpected arr length: expect 33 but see ${arr.length}');
+    return Config(
+      breezserver: _wire2api_String(arr[0]),
+      chainnotifierUrl: _wire2api_String(arr[1]),
+      lnurlServerUrl: _wire2api_String(arr[2]),
+      mempoolspaceUrl: _wire2api_opt_String(arr[3]),
+      chainService: _wire2api_opt_box_autoadd_chain_service_config(arr[4]),
+      backupTransport: _wire2api_opt_box_autoadd_backup_transport_config(arr[5]),
+      proxy: _wire2api_opt_box_autoadd_proxy_config(arr[6]),
+      workingDir: _wire2api_String(arr[7]),
+      dbDir: _wire2api_opt_String(arr[8]),
+      credentialsDir: _wire2api_opt_String(arr[9]),
+      network: _wire2api_network(arr[10]),
+      paymentTimeoutSec: _wire2api_u32(arr[11]),
+      maxConcurrentPayments: _wire2api_u32(arr[12]),
+      defaultLspId: _wire2api_opt_String(arr[13]),
+      lspPolicy: _wire2api_opt_box_autoadd_lsp_policy(arr[14]),
+      apiKey: _wire2api_opt_String(arr[15]),
+      maxfeePercent: _wire2api_f64(arr[16]),
+      exemptfeeMsat: _wire2api_u64(arr[17]),
+      fiatCurrency: _wire2api_opt_String(arr[18]),
+      lowOutboundLiquidityThresholdMsat: _wire2api_opt_box_autoadd_u64(arr[19]),
+      lowInboundLiquidityThresholdMsat: _wire2api_opt_box_autoadd_u64(arr[20]),
+      useTrampoline: _wire2api_bool(arr[21]),
+      partnerFee: _wire2api_opt_box_autoadd_partner_fee_config(arr[22]),
+      swapAutoRefund: _wire2api_opt_box_autoadd_auto_refund_config(arr[23]),
+      memoPrivacy: _wire2api_memo_privacy(arr[24]),
+      channelHygieneWindowSecs: _wire2api_opt_box_autoadd_u64(arr[25]),
+      logFilter: _wire2api_opt_String(arr[26]),
+      denylistUrl: _wire2api_opt_String(arr[27]),
+      transakApiKey: _wire2api_opt_String(arr[28]),
+      spendPolicy: _wire2api_opt_box_autoadd_spend_policy(arr[29]),
+      htlcExposureLimits: _wire2api_opt_box_autoadd_htlc_exposure_limits(arr[30]),
+      serviceStatusCheckIntervalSecs: _wire2api_opt_box_autoadd_u64(arr[31]),
+      nodeConfig: _wire2api_node_config(arr[32]),
+    );
+  }
+
+  ConnectProgressDetails _wire2api_connect_progress_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ConnectProgressDetails(
+      stage: _wire2api_connect_stage(arr[0]),
+      progressPercent: _wire2api_u8(arr[1]),
+      isNewNode: _wire2api_opt_box_autoadd_bool(arr[2]),
+    );
+  }
+
+  ConnectStage _wire2api_connect_stage(dynamic raw) {
+    return ConnectStage.values[raw as int];
+  }
+
+  ConnectionState _wire2api_connection_state(dynamic raw) {
+    return ConnectionState.values[raw as int];
+  }
+
+  ConnectionStatus _wire2api_connection_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConnectionStatus(
+      state: _wire2api_connection_state(arr[0]),
+      since: _wire2api_i64(arr[1]),
+      reconnectAttempts: _wire2api_u32(arr[2]),
+      lastError: _wire2api_opt_String(arr[3]),
+    );
+  }
+
+  CurrencyInfo _wire2api_currency_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CurrencyInfo(
+      name: _wire2api_String(arr[0]),
+      fractionSize: _wire2api_u32(arr[1]),
+      spacing: _wire2api_opt_box_autoadd_u32(arr[2]),
+      symbol: _wire2api_opt_box_autoadd_symbol(arr[3]),
+      uniqSymbol: _wire2api_opt_box_autoadd_symbol(arr[4]),
+      localizedName: _wire2api_list_localized_name(arr[5]),
+      localeOverrides: _wire2api_list_locale_overrides(arr[6]),
+    );
+  }
+
+  Denomination _wire2api_denomination(dynamic raw) {
+    return Denomination.values[raw as int];
+  }
+
+  DeriveEncryptionKeyResponse _wire2api_derive_encryption_key_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return DeriveEncryptionKeyResponse(
+      key: _wire2api_uint_8_list(arr[0]),
+    );
+  }
+
+  EncryptedPaymentRequestData _wire2api_encrypted_payment_request_data(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return EncryptedPaymentRequestData(
+      blob: _wire2api_String(arr[0]),
+    );
+  }
+
+  ExportRecoveryBundleResponse _wire2api_export_recovery_bundle_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ExportRecoveryBundleResponse(
+      bundle: _wire2api_uint_8_list(arr[0]),
+    );
+  }
+
+  double _wire2api_f64(dynamic raw) {
+    return raw as double;
+  }
+
+  FeatureSupport _wire2api_feature_support(dynamic raw) {
+    return FeatureSupport.values[raw as int];
+  }
+
+  FeeratePreset _wire2api_feerate_preset(dynamic raw) {
+    return FeeratePreset.values[raw as int];
+  }
+
+  FiatAmount _wire2api_fiat_amount(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FiatAmount(
+      currency: _wire2api_String(arr[0]),
+      amount: _wire2api_f64(arr[1]),
+    );
+  }
+
+  FiatCurrency _wire2api_fiat_currency(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return Config(
-      breezserver: _wire2api_String(arr[0]),
-      chainnotifierUrl: _wire2api_String(arr[1]),
-      mempoolspaceUrl: _wire2api_opt_String(arr[2]),
-      workingDir: _wire2api_String(arr[3]),
-      network: _wire2api_network(arr[4]),
-      paymentTimeoutSec: _wire2api_u32(arr[5]),
-      defaultLspId: _wire2api_opt_String(arr[6]),
-      apiKey: _wire2api_opt_String(arr[7]),
-      maxfeePercent: _wire2api_f64(arr[8]),
-      exemptfeeMsat: _wire2api_u64(arr[9]),
-      nodeConfig: _wire2api_node_config(arr[10]),
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FiatCurrency(
+      id: _wire2api_String(arr[0]),
+      info: _wire2api_currency_info(arr[1]),
     );
   }
 
-  CurrencyInfo _wire2api_currency_info(dynamic raw) {
+  FiatRateAlert _wire2api_fiat_rate_alert(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return CurrencyInfo(
-      name: _wire2api_String(arr[0]),
-      fractionSize: _wire2api_u32(arr[1]),
-      spacing: _wire2api_opt_box_autoadd_u32(arr[2]),
-      symbol: _wire2api_opt_box_autoadd_symbol(arr[3]),
-      uniqSymbol: _wire2api_opt_box_autoadd_symbol(arr[4]),
-      localizedName: _wire2api_list_localized_name(arr[5]),
-      localeOverrides: _wire2api_list_locale_overrides(arr[6]),
+    return FiatRateAlert(
+      id: _wire2api_i64(arr[0]),
+      currency: _wire2api_String(arr[1]),
+      direction: _wire2api_fiat_rate_alert_direction(arr[2]),
+      threshold: _wire2api_f64(arr[3]),
+      createdAt: _wire2api_i64(arr[4]),
+      triggered: _wire2api_bool(arr[5]),
+      lastTriggeredAt: _wire2api_opt_box_autoadd_i64(arr[6]),
     );
   }
 
-  double _wire2api_f64(dynamic raw) {
-    return raw as double;
+  FiatRateAlertDirection _wire2api_fiat_rate_alert_direction(dynamic raw) {
+    return FiatRateAlertDirection.values[raw as int];
   }
 
-  FiatCurrency _wire2api_fiat_currency(dynamic raw) {
+  FiatRateAlertTriggeredDetails _wire2api_fiat_rate_alert_triggered_details(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return FiatCurrency(
-      id: _wire2api_String(arr[0]),
-      info: _wire2api_currency_info(arr[1]),
+    return FiatRateAlertTriggeredDetails(
+      alert: _wire2api_fiat_rate_alert(arr[0]),
+      rate: _wire2api_f64(arr[1]),
     );
   }
 
@@ -3354,6 +8081,25 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return HealthCheckStatus.values[raw as int];
   }
 
+  HistoricalRate _wire2api_historical_rate(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HistoricalRate(
+      coin: _wire2api_String(arr[0]),
+      value: _wire2api_f64(arr[1]),
+      timestamp: _wire2api_i64(arr[2]),
+    );
+  }
+
+  HtlcExposureLimits _wire2api_htlc_exposure_limits(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return HtlcExposureLimits(
+      maxInflightMsat: _wire2api_opt_box_autoadd_u64(arr[0]),
+      maxHtlcsPerPayment: _wire2api_opt_box_autoadd_u32(arr[1]),
+    );
+  }
+
   int _wire2api_i32(dynamic raw) {
     return raw as int;
   }
@@ -3362,6 +8108,15 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return castInt(raw);
   }
 
+  ImportPaymentsResponse _wire2api_import_payments_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportPaymentsResponse(
+      importedCount: _wire2api_u32(arr[0]),
+      skippedCount: _wire2api_u32(arr[1]),
+    );
+  }
+
   InputType _wire2api_input_type(dynamic raw) {
     switch (raw[0]) {
       case 0:
@@ -3373,35 +8128,57 @@ class BreezSdkCoreImpl implements BreezSdkCore {
           invoice: _wire2api_box_autoadd_ln_invoice(raw[1]),
         );
       case 2:
+        return InputType_Bolt12Offer(
+          offer: _wire2api_box_autoadd_ln_offer(raw[1]),
+          bip353Address: _wire2api_opt_String(raw[2]),
+        );
+      case 3:
         return InputType_NodeId(
           nodeId: _wire2api_String(raw[1]),
         );
-      case 3:
+      case 4:
         return InputType_Url(
           url: _wire2api_String(raw[1]),
         );
-      case 4:
+      case 5:
         return InputType_LnUrlPay(
           data: _wire2api_box_autoadd_ln_url_pay_request_data(raw[1]),
           bip353Address: _wire2api_opt_String(raw[2]),
+          amountMsat: _wire2api_opt_box_autoadd_u64(raw[3]),
+          comment: _wire2api_opt_String(raw[4]),
         );
-      case 5:
+      case 6:
         return InputType_LnUrlWithdraw(
           data: _wire2api_box_autoadd_ln_url_withdraw_request_data(raw[1]),
         );
-      case 6:
+      case 7:
         return InputType_LnUrlAuth(
           data: _wire2api_box_autoadd_ln_url_auth_request_data(raw[1]),
         );
-      case 7:
+      case 8:
         return InputType_LnUrlError(
           data: _wire2api_box_autoadd_ln_url_error_data(raw[1]),
         );
+      case 9:
+        return InputType_EncryptedPaymentRequest(
+          data: _wire2api_box_autoadd_encrypted_payment_request_data(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
   }
 
+  InvoiceFeatures _wire2api_invoice_features(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return InvoiceFeatures(
+      basicMpp: _wire2api_feature_support(arr[0]),
+      paymentSecret: _wire2api_feature_support(arr[1]),
+      trampoline: _wire2api_feature_support(arr[2]),
+      requiresUnknownFeatures: _wire2api_bool(arr[3]),
+    );
+  }
+
   InvoicePaidDetails _wire2api_invoice_paid_details(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
@@ -3412,10 +8189,70 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  InvoicePayability _wire2api_invoice_payability(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return InvoicePayability(
+      invoice: _wire2api_ln_invoice(arr[0]),
+      amountMsat: _wire2api_opt_box_autoadd_u64(arr[1]),
+      isPayable: _wire2api_bool(arr[2]),
+      error: _wire2api_opt_String(arr[3]),
+      maxPayableMsat: _wire2api_u64(arr[4]),
+      payeeInboundLiquidityMsat: _wire2api_opt_box_autoadd_u64(arr[5]),
+      lspRouteHint: _wire2api_opt_box_autoadd_route_hint(arr[6]),
+    );
+  }
+
+  InvoiceVerificationResult _wire2api_invoice_verification_result(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return InvoiceVerificationResult(
+      bolt11: _wire2api_String(arr[0]),
+      invoice: _wire2api_opt_box_autoadd_ln_invoice(arr[1]),
+      error: _wire2api_opt_String(arr[2]),
+    );
+  }
+
+  List<BatchPaymentResult> _wire2api_list_batch_payment_result(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_batch_payment_result).toList();
+  }
+
+  List<ChannelDetails> _wire2api_list_channel_details(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_channel_details).toList();
+  }
+
+  List<ChannelOpeningFee> _wire2api_list_channel_opening_fee(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_channel_opening_fee).toList();
+  }
+
   List<FiatCurrency> _wire2api_list_fiat_currency(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_fiat_currency).toList();
   }
 
+  List<FiatRateAlert> _wire2api_list_fiat_rate_alert(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_fiat_rate_alert).toList();
+  }
+
+  List<HistoricalRate> _wire2api_list_historical_rate(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_historical_rate).toList();
+  }
+
+  List<InvoiceVerificationResult> _wire2api_list_invoice_verification_result(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_invoice_verification_result).toList();
+  }
+
+  List<LnOfferBlindedPath> _wire2api_list_ln_offer_blinded_path(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_ln_offer_blinded_path).toList();
+  }
+
+  List<LnUrlAuthDomain> _wire2api_list_ln_url_auth_domain(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_ln_url_auth_domain).toList();
+  }
+
+  List<LnurlWithdrawVoucher> _wire2api_list_lnurl_withdraw_voucher(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_lnurl_withdraw_voucher).toList();
+  }
+
   List<LocaleOverrides> _wire2api_list_locale_overrides(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_locale_overrides).toList();
   }
@@ -3428,22 +8265,62 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return (raw as List<dynamic>).map(_wire2api_lsp_information).toList();
   }
 
+  List<MetricBucket> _wire2api_list_metric_bucket(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_metric_bucket).toList();
+  }
+
+  List<MetricCounter> _wire2api_list_metric_counter(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_metric_counter).toList();
+  }
+
+  List<MetricHistogram> _wire2api_list_metric_histogram(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_metric_histogram).toList();
+  }
+
+  List<MigrationChannel> _wire2api_list_migration_channel(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_migration_channel).toList();
+  }
+
   List<OpeningFeeParams> _wire2api_list_opening_fee_params(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_opening_fee_params).toList();
   }
 
+  List<OutboxPayment> _wire2api_list_outbox_payment(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_outbox_payment).toList();
+  }
+
   List<Payment> _wire2api_list_payment(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_payment).toList();
   }
 
+  List<PaymentGroup> _wire2api_list_payment_group(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_payment_group).toList();
+  }
+
+  List<PaymentProbe> _wire2api_list_payment_probe(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_payment_probe).toList();
+  }
+
+  List<PendingChannelOpen> _wire2api_list_pending_channel_open(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_pending_channel_open).toList();
+  }
+
   List<Rate> _wire2api_list_rate(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_rate).toList();
   }
 
+  List<RefundFeePreview> _wire2api_list_refund_fee_preview(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_refund_fee_preview).toList();
+  }
+
   List<ReverseSwapInfo> _wire2api_list_reverse_swap_info(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_reverse_swap_info).toList();
   }
 
+  List<ReverseSwapProtocol> _wire2api_list_reverse_swap_protocol(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_reverse_swap_protocol).toList();
+  }
+
   List<RouteHint> _wire2api_list_route_hint(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_route_hint).toList();
   }
@@ -3452,17 +8329,33 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return (raw as List<dynamic>).map(_wire2api_route_hint_hop).toList();
   }
 
+  List<SnapshotChange> _wire2api_list_snapshot_change(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_snapshot_change).toList();
+  }
+
   List<SwapInfo> _wire2api_list_swap_info(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_swap_info).toList();
   }
 
+  List<SwapRefundKey> _wire2api_list_swap_refund_key(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_swap_refund_key).toList();
+  }
+
+  List<TaskStatus> _wire2api_list_task_status(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_task_status).toList();
+  }
+
+  List<TlvRecord> _wire2api_list_tlv_record(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_tlv_record).toList();
+  }
+
   List<UnspentTransactionOutput> _wire2api_list_unspent_transaction_output(dynamic raw) {
     return (raw as List<dynamic>).map(_wire2api_unspent_transaction_output).toList();
   }
 
   LNInvoice _wire2api_ln_invoice(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
     return LNInvoice(
       bolt11: _wire2api_String(arr[0]),
       network: _wire2api_network(arr[1]),
@@ -3473,15 +8366,41 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       amountMsat: _wire2api_opt_box_autoadd_u64(arr[6]),
       timestamp: _wire2api_u64(arr[7]),
       expiry: _wire2api_u64(arr[8]),
-      routingHints: _wire2api_list_route_hint(arr[9]),
-      paymentSecret: _wire2api_uint_8_list(arr[10]),
-      minFinalCltvExpiryDelta: _wire2api_u64(arr[11]),
+      expiresAt: _wire2api_u64(arr[9]),
+      isExpired: _wire2api_bool(arr[10]),
+      routingHints: _wire2api_list_route_hint(arr[11]),
+      paymentSecret: _wire2api_uint_8_list(arr[12]),
+      minFinalCltvExpiryDelta: _wire2api_u64(arr[13]),
+      features: _wire2api_invoice_features(arr[14]),
+    );
+  }
+
+  LNOffer _wire2api_ln_offer(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return LNOffer(
+      offer: _wire2api_String(arr[0]),
+      chains: _wire2api_StringList(arr[1]),
+      minAmount: _wire2api_opt_box_autoadd_amount(arr[2]),
+      description: _wire2api_opt_String(arr[3]),
+      absoluteExpiry: _wire2api_opt_box_autoadd_u64(arr[4]),
+      issuer: _wire2api_opt_String(arr[5]),
+      signingPubkey: _wire2api_opt_String(arr[6]),
+      paths: _wire2api_list_ln_offer_blinded_path(arr[7]),
+    );
+  }
+
+  LnOfferBlindedPath _wire2api_ln_offer_blinded_path(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return LnOfferBlindedPath(
+      blindedHops: _wire2api_StringList(arr[0]),
     );
   }
 
   LnPaymentDetails _wire2api_ln_payment_details(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    if (arr.length != 19) throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
     return LnPaymentDetails(
       paymentHash: _wire2api_String(arr[0]),
       label: _wire2api_String(arr[1]),
@@ -3490,15 +8409,39 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       keysend: _wire2api_bool(arr[4]),
       bolt11: _wire2api_String(arr[5]),
       openChannelBolt11: _wire2api_opt_String(arr[6]),
-      lnurlSuccessAction: _wire2api_opt_box_autoadd_success_action_processed(arr[7]),
-      lnurlPayDomain: _wire2api_opt_String(arr[8]),
-      lnurlPayComment: _wire2api_opt_String(arr[9]),
-      lnAddress: _wire2api_opt_String(arr[10]),
-      lnurlMetadata: _wire2api_opt_String(arr[11]),
-      lnurlWithdrawEndpoint: _wire2api_opt_String(arr[12]),
-      swapInfo: _wire2api_opt_box_autoadd_swap_info(arr[13]),
-      reverseSwapInfo: _wire2api_opt_box_autoadd_reverse_swap_info(arr[14]),
-      pendingExpirationBlock: _wire2api_opt_box_autoadd_u32(arr[15]),
+      channelOpeningFeeMsat: _wire2api_opt_box_autoadd_u64(arr[7]),
+      lnurlSuccessAction: _wire2api_opt_box_autoadd_success_action_processed(arr[8]),
+      lnurlPayDomain: _wire2api_opt_String(arr[9]),
+      lnurlPayComment: _wire2api_opt_String(arr[10]),
+      lnAddress: _wire2api_opt_String(arr[11]),
+      lnurlMetadata: _wire2api_opt_String(arr[12]),
+      lnurlWithdrawEndpoint: _wire2api_opt_String(arr[13]),
+      swapInfo: _wire2api_opt_box_autoadd_swap_info(arr[14]),
+      reverseSwapInfo: _wire2api_opt_box_autoadd_reverse_swap_info(arr[15]),
+      pendingExpirationBlock: _wire2api_opt_box_autoadd_u32(arr[16]),
+      tlvs: _wire2api_opt_list_tlv_record(arr[17]),
+      spontaneousMetadata: _wire2api_opt_box_autoadd_spontaneous_payment_metadata(arr[18]),
+    );
+  }
+
+  LnUrlAuthDomain _wire2api_ln_url_auth_domain(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LnUrlAuthDomain(
+      domain: _wire2api_String(arr[0]),
+      linkingKey: _wire2api_String(arr[1]),
+      firstAuthenticatedAt: _wire2api_i64(arr[2]),
+      lastAuthenticatedAt: _wire2api_i64(arr[3]),
+      authCount: _wire2api_u32(arr[4]),
+    );
+  }
+
+  LnUrlAuthExport _wire2api_ln_url_auth_export(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LnUrlAuthExport(
+      hashingKey: _wire2api_String(arr[0]),
+      domains: _wire2api_list_ln_url_auth_domain(arr[1]),
     );
   }
 
@@ -3543,6 +8486,23 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  LnUrlPayFinishedDetails _wire2api_ln_url_pay_finished_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LnUrlPayFinishedDetails(
+      paymentHash: _wire2api_String(arr[0]),
+      result: _wire2api_ln_url_pay_result(arr[1]),
+    );
+  }
+
+  LnUrlPayPendingData _wire2api_ln_url_pay_pending_data(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return LnUrlPayPendingData(
+      paymentHash: _wire2api_String(arr[0]),
+    );
+  }
+
   LnUrlPayRequestData _wire2api_ln_url_pay_request_data(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
@@ -3573,6 +8533,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         return LnUrlPayResult_PayError(
           data: _wire2api_box_autoadd_ln_url_pay_error_data(raw[1]),
         );
+      case 3:
+        return LnUrlPayResult_Pending(
+          data: _wire2api_box_autoadd_ln_url_pay_pending_data(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -3580,10 +8544,11 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   LnUrlPaySuccessData _wire2api_ln_url_pay_success_data(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return LnUrlPaySuccessData(
       payment: _wire2api_payment(arr[0]),
       successAction: _wire2api_opt_box_autoadd_success_action_processed(arr[1]),
+      partnerFee: _wire2api_opt_box_autoadd_partner_fee_details(arr[2]),
     );
   }
 
@@ -3626,6 +8591,30 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  LnurlPayInfo _wire2api_lnurl_pay_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LnurlPayInfo(
+      lnurl: _wire2api_String(arr[0]),
+      lightningAddress: _wire2api_String(arr[1]),
+    );
+  }
+
+  LnurlWithdrawVoucher _wire2api_lnurl_withdraw_voucher(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return LnurlWithdrawVoucher(
+      id: _wire2api_String(arr[0]),
+      lnurl: _wire2api_String(arr[1]),
+      amountMsat: _wire2api_u64(arr[2]),
+      uses: _wire2api_u32(arr[3]),
+      redeemedUses: _wire2api_u32(arr[4]),
+      description: _wire2api_opt_String(arr[5]),
+      createdAt: _wire2api_i64(arr[6]),
+      expiresAt: _wire2api_i64(arr[7]),
+    );
+  }
+
   LocaleOverrides _wire2api_locale_overrides(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
@@ -3647,10 +8636,20 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   LogEntry _wire2api_log_entry(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return LogEntry(
       line: _wire2api_String(arr[0]),
       level: _wire2api_String(arr[1]),
+      module: _wire2api_String(arr[2]),
+    );
+  }
+
+  LowLiquidityDetails _wire2api_low_liquidity_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LowLiquidityDetails(
+      liquidityMsat: _wire2api_u64(arr[0]),
+      thresholdMsat: _wire2api_u64(arr[1]),
     );
   }
 
@@ -3672,6 +8671,25 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  LspPolicy _wire2api_lsp_policy(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return LspPolicy_LowestOpeningFee();
+      case 1:
+        return LspPolicy_LowestProportionalFee();
+      case 2:
+        return LspPolicy_Pinned(
+          lspId: _wire2api_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  MemoPrivacy _wire2api_memo_privacy(dynamic raw) {
+    return MemoPrivacy.values[raw as int];
+  }
+
   MessageSuccessActionData _wire2api_message_success_action_data(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3680,6 +8698,78 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  MetricBucket _wire2api_metric_bucket(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MetricBucket(
+      upperBound: _wire2api_f64(arr[0]),
+      cumulativeCount: _wire2api_u64(arr[1]),
+    );
+  }
+
+  MetricCounter _wire2api_metric_counter(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MetricCounter(
+      name: _wire2api_String(arr[0]),
+      help: _wire2api_String(arr[1]),
+      value: _wire2api_u64(arr[2]),
+    );
+  }
+
+  MetricHistogram _wire2api_metric_histogram(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MetricHistogram(
+      name: _wire2api_String(arr[0]),
+      help: _wire2api_String(arr[1]),
+      buckets: _wire2api_list_metric_bucket(arr[2]),
+      sum: _wire2api_f64(arr[3]),
+      count: _wire2api_u64(arr[4]),
+    );
+  }
+
+  MetricsSnapshot _wire2api_metrics_snapshot(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MetricsSnapshot(
+      counters: _wire2api_list_metric_counter(arr[0]),
+      histograms: _wire2api_list_metric_histogram(arr[1]),
+    );
+  }
+
+  MigrationChannel _wire2api_migration_channel(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MigrationChannel(
+      fundingTxid: _wire2api_String(arr[0]),
+      fundingOutnum: _wire2api_opt_box_autoadd_u32(arr[1]),
+      shortChannelId: _wire2api_opt_String(arr[2]),
+      state: _wire2api_channel_state(arr[3]),
+      localBalanceMsat: _wire2api_u64(arr[4]),
+      peerId: _wire2api_opt_String(arr[5]),
+      capacityMsat: _wire2api_opt_box_autoadd_u64(arr[6]),
+    );
+  }
+
+  MilliSat _wire2api_milli_sat(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return MilliSat(
+      field0: _wire2api_u64(arr[0]),
+    );
+  }
+
+  MockNodeConfig _wire2api_mock_node_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MockNodeConfig(
+      channelsBalanceMsat: _wire2api_u64(arr[0]),
+      onchainBalanceMsat: _wire2api_u64(arr[1]),
+      inboundLiquidityMsat: _wire2api_u64(arr[2]),
+    );
+  }
+
   Network _wire2api_network(dynamic raw) {
     return Network.values[raw as int];
   }
@@ -3690,6 +8780,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
         return NodeConfig_Greenlight(
           config: _wire2api_box_autoadd_greenlight_node_config(raw[1]),
         );
+      case 1:
+        return NodeConfig_Mock(
+          config: _wire2api_box_autoadd_mock_node_config(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -3706,9 +8800,25 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     }
   }
 
+  NodeMigrationState _wire2api_node_migration_state(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return NodeMigrationState(
+      nodeId: _wire2api_String(arr[0]),
+      network: _wire2api_network(arr[1]),
+      lspNodeUri: _wire2api_opt_String(arr[2]),
+      staticBackup: _wire2api_StringList(arr[3]),
+      channels: _wire2api_list_migration_channel(arr[4]),
+      closingTxids: _wire2api_StringList(arr[5]),
+      channelsBalanceMsat: _wire2api_u64(arr[6]),
+      onchainBalanceMsat: _wire2api_u64(arr[7]),
+      exportedAt: _wire2api_u64(arr[8]),
+    );
+  }
+
   NodeState _wire2api_node_state(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
     return NodeState(
       id: _wire2api_String(arr[0]),
       blockHeight: _wire2api_u32(arr[1]),
@@ -3723,6 +8833,8 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       connectedPeers: _wire2api_StringList(arr[10]),
       maxReceivableSinglePaymentAmountMsat: _wire2api_u64(arr[11]),
       totalInboundLiquidityMsats: _wire2api_u64(arr[12]),
+      pendingChannelOpensMsat: _wire2api_u64(arr[13]),
+      pendingChannelOpens: _wire2api_list_pending_channel_open(arr[14]),
     );
   }
 
@@ -3730,18 +8842,57 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     final arr = raw as List<dynamic>;
     if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return OnchainPaymentLimitsResponse(
-      minSat: _wire2api_u64(arr[0]),
-      maxSat: _wire2api_u64(arr[1]),
-      maxPayableSat: _wire2api_u64(arr[2]),
+      minSat: _wire2api_sat(arr[0]),
+      maxSat: _wire2api_sat(arr[1]),
+      maxPayableSat: _wire2api_sat(arr[2]),
+    );
+  }
+
+  OpenChannelFeeResponse _wire2api_open_channel_fee_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OpenChannelFeeResponse(
+      feeMsat: _wire2api_opt_box_autoadd_milli_sat(arr[0]),
+      feeParams: _wire2api_opening_fee_params(arr[1]),
+    );
+  }
+
+  OpenChannelReceiveDetails _wire2api_open_channel_receive_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OpenChannelReceiveDetails(
+      paymentHash: _wire2api_String(arr[0]),
+      stage: _wire2api_open_channel_receive_stage(arr[1]),
     );
   }
 
-  OpenChannelFeeResponse _wire2api_open_channel_fee_response(dynamic raw) {
+  OpenChannelReceiveStage _wire2api_open_channel_receive_stage(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return OpenChannelReceiveStage_Registered(
+          openingFeeMsat: _wire2api_u64(raw[1]),
+        );
+      case 1:
+        return OpenChannelReceiveStage_ChannelPending(
+          fundingTxid: _wire2api_String(raw[1]),
+        );
+      case 2:
+        return OpenChannelReceiveStage_Settled(
+          openingFeeMsat: _wire2api_u64(raw[1]),
+        );
+      case 3:
+        return OpenChannelReceiveStage_Reregistered();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  OpenChannelResponse _wire2api_open_channel_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return OpenChannelFeeResponse(
-      feeMsat: _wire2api_opt_box_autoadd_u64(arr[0]),
-      feeParams: _wire2api_opening_fee_params(arr[1]),
+    return OpenChannelResponse(
+      channelId: _wire2api_String(arr[0]),
+      fundingTxid: _wire2api_String(arr[1]),
     );
   }
 
@@ -3774,22 +8925,74 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_StringList(raw);
   }
 
+  Amount? _wire2api_opt_box_autoadd_amount(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_amount(raw);
+  }
+
+  AutoRefundConfig? _wire2api_opt_box_autoadd_auto_refund_config(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_auto_refund_config(raw);
+  }
+
+  BackupTransportConfig? _wire2api_opt_box_autoadd_backup_transport_config(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_backup_transport_config(raw);
+  }
+
   bool? _wire2api_opt_box_autoadd_bool(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_bool(raw);
   }
 
+  ChainServiceConfig? _wire2api_opt_box_autoadd_chain_service_config(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_chain_service_config(raw);
+  }
+
+  Denomination? _wire2api_opt_box_autoadd_denomination(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_denomination(raw);
+  }
+
+  double? _wire2api_opt_box_autoadd_f64(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_f64(raw);
+  }
+
+  FeeratePreset? _wire2api_opt_box_autoadd_feerate_preset(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_feerate_preset(raw);
+  }
+
+  FiatAmount? _wire2api_opt_box_autoadd_fiat_amount(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_fiat_amount(raw);
+  }
+
   GreenlightCredentials? _wire2api_opt_box_autoadd_greenlight_credentials(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_greenlight_credentials(raw);
   }
 
+  HtlcExposureLimits? _wire2api_opt_box_autoadd_htlc_exposure_limits(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_htlc_exposure_limits(raw);
+  }
+
+  int? _wire2api_opt_box_autoadd_i64(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_i64(raw);
+  }
+
   LNInvoice? _wire2api_opt_box_autoadd_ln_invoice(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_ln_invoice(raw);
   }
 
+  LnurlPayInfo? _wire2api_opt_box_autoadd_lnurl_pay_info(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_lnurl_pay_info(raw);
+  }
+
   LspInformation? _wire2api_opt_box_autoadd_lsp_information(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_lsp_information(raw);
   }
 
+  LspPolicy? _wire2api_opt_box_autoadd_lsp_policy(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_lsp_policy(raw);
+  }
+
+  MilliSat? _wire2api_opt_box_autoadd_milli_sat(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_milli_sat(raw);
+  }
+
   NodeCredentials? _wire2api_opt_box_autoadd_node_credentials(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_node_credentials(raw);
   }
@@ -3798,14 +9001,42 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_box_autoadd_opening_fee_params(raw);
   }
 
+  PartnerFeeConfig? _wire2api_opt_box_autoadd_partner_fee_config(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_partner_fee_config(raw);
+  }
+
+  PartnerFeeDetails? _wire2api_opt_box_autoadd_partner_fee_details(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_partner_fee_details(raw);
+  }
+
   Payment? _wire2api_opt_box_autoadd_payment(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_payment(raw);
   }
 
+  ProxyConfig? _wire2api_opt_box_autoadd_proxy_config(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_proxy_config(raw);
+  }
+
+  Rate? _wire2api_opt_box_autoadd_rate(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_rate(raw);
+  }
+
   ReverseSwapInfo? _wire2api_opt_box_autoadd_reverse_swap_info(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_reverse_swap_info(raw);
   }
 
+  RouteHint? _wire2api_opt_box_autoadd_route_hint(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_route_hint(raw);
+  }
+
+  SpendPolicy? _wire2api_opt_box_autoadd_spend_policy(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_spend_policy(raw);
+  }
+
+  SpontaneousPaymentMetadata? _wire2api_opt_box_autoadd_spontaneous_payment_metadata(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_spontaneous_payment_metadata(raw);
+  }
+
   SuccessActionProcessed? _wire2api_opt_box_autoadd_success_action_processed(dynamic raw) {
     return raw == null ? null : _wire2api_box_autoadd_success_action_processed(raw);
   }
@@ -3826,6 +9057,94 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw == null ? null : _wire2api_box_autoadd_u64(raw);
   }
 
+  List<TlvRecord>? _wire2api_opt_list_tlv_record(dynamic raw) {
+    return raw == null ? null : _wire2api_list_tlv_record(raw);
+  }
+
+  OutboxPayment _wire2api_outbox_payment(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return OutboxPayment(
+      id: _wire2api_i64(arr[0]),
+      req: _wire2api_send_payment_request(arr[1]),
+      createdAt: _wire2api_i64(arr[2]),
+      expiresAt: _wire2api_i64(arr[3]),
+      attempts: _wire2api_u32(arr[4]),
+      nextAttemptAt: _wire2api_i64(arr[5]),
+      lastError: _wire2api_opt_String(arr[6]),
+    );
+  }
+
+  OutboxPaymentDetails _wire2api_outbox_payment_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OutboxPaymentDetails(
+      outboxPayment: _wire2api_outbox_payment(arr[0]),
+      status: _wire2api_outbox_payment_status(arr[1]),
+    );
+  }
+
+  OutboxPaymentStatus _wire2api_outbox_payment_status(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return OutboxPaymentStatus_Queued();
+      case 1:
+        return OutboxPaymentStatus_Sent(
+          payment: _wire2api_box_autoadd_payment(raw[1]),
+        );
+      case 2:
+        return OutboxPaymentStatus_Abandoned();
+      case 3:
+        return OutboxPaymentStatus_Cancelled();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  PartnerFeeConfig _wire2api_partner_fee_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PartnerFeeConfig(
+      feeBps: _wire2api_u32(arr[0]),
+      destination: _wire2api_partner_fee_destination(arr[1]),
+    );
+  }
+
+  PartnerFeeDestination _wire2api_partner_fee_destination(dynamic raw) {
+    switch (raw[0]) {
+      case 0:
+        return PartnerFeeDestination_NodeId(
+          nodeId: _wire2api_String(raw[1]),
+        );
+      case 1:
+        return PartnerFeeDestination_LightningAddress(
+          address: _wire2api_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  PartnerFeeDetails _wire2api_partner_fee_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PartnerFeeDetails(
+      amountMsat: _wire2api_u64(arr[0]),
+      paymentId: _wire2api_opt_String(arr[1]),
+      error: _wire2api_opt_String(arr[2]),
+    );
+  }
+
+  PayOnchainAddressResponse _wire2api_pay_onchain_address_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PayOnchainAddressResponse(
+      prepareRes: _wire2api_prepare_onchain_payment_response(arr[0]),
+      claimTxFeerate: _wire2api_u32(arr[1]),
+      reverseSwapInfo: _wire2api_reverse_swap_info(arr[2]),
+    );
+  }
+
   PayOnchainResponse _wire2api_pay_onchain_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3836,7 +9155,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   Payment _wire2api_payment(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
     return Payment(
       id: _wire2api_String(arr[0]),
       paymentType: _wire2api_payment_type(arr[1]),
@@ -3848,6 +9167,8 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       description: _wire2api_opt_String(arr[7]),
       details: _wire2api_payment_details(arr[8]),
       metadata: _wire2api_opt_String(arr[9]),
+      fiatRate: _wire2api_opt_box_autoadd_rate(arr[10]),
+      imported: _wire2api_bool(arr[11]),
     );
   }
 
@@ -3877,6 +9198,46 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  PaymentGroup _wire2api_payment_group(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PaymentGroup(
+      key: _wire2api_String(arr[0]),
+      paymentsCount: _wire2api_u32(arr[1]),
+      totalAmountMsat: _wire2api_u64(arr[2]),
+      totalFeeMsat: _wire2api_u64(arr[3]),
+      firstPaymentTime: _wire2api_i64(arr[4]),
+      lastPaymentTime: _wire2api_i64(arr[5]),
+    );
+  }
+
+  PaymentProbe _wire2api_payment_probe(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return PaymentProbe(
+      id: _wire2api_i64(arr[0]),
+      nodeId: _wire2api_String(arr[1]),
+      bolt11: _wire2api_opt_String(arr[2]),
+      amountMsat: _wire2api_u64(arr[3]),
+      routeFound: _wire2api_bool(arr[4]),
+      reachable: _wire2api_bool(arr[5]),
+      estimatedFeeMsat: _wire2api_opt_box_autoadd_u64(arr[6]),
+      hops: _wire2api_opt_box_autoadd_u32(arr[7]),
+      error: _wire2api_opt_String(arr[8]),
+      createdAt: _wire2api_i64(arr[9]),
+    );
+  }
+
+  PaymentRequestBundle _wire2api_payment_request_bundle(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaymentRequestBundle(
+      invoice: _wire2api_String(arr[0]),
+      fallback: _wire2api_opt_String(arr[1]),
+      metadata: _wire2api_opt_String(arr[2]),
+    );
+  }
+
   PaymentStatus _wire2api_payment_status(dynamic raw) {
     return PaymentStatus.values[raw as int];
   }
@@ -3885,6 +9246,32 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return PaymentType.values[raw as int];
   }
 
+  PeerConnectivity _wire2api_peer_connectivity(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PeerConnectivity(
+      lspPubkey: _wire2api_opt_String(arr[0]),
+      lspConnected: _wire2api_bool(arr[1]),
+      pingRttMs: _wire2api_opt_box_autoadd_u64(arr[2]),
+      pingError: _wire2api_opt_String(arr[3]),
+      reconnectionAttempts: _wire2api_u32(arr[4]),
+      failedReconnectionAttempts: _wire2api_u32(arr[5]),
+      lastReconnectedAt: _wire2api_opt_box_autoadd_i64(arr[6]),
+      lastReconnectionError: _wire2api_opt_String(arr[7]),
+    );
+  }
+
+  PendingChannelOpen _wire2api_pending_channel_open(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PendingChannelOpen(
+      fundingTxid: _wire2api_String(arr[0]),
+      amountMsat: _wire2api_u64(arr[1]),
+      seenAt: _wire2api_u64(arr[2]),
+      expectedConfirmationAt: _wire2api_u64(arr[3]),
+    );
+  }
+
   PrepareOnchainPaymentResponse _wire2api_prepare_onchain_payment_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
@@ -3893,27 +9280,72 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       feesPercentage: _wire2api_f64(arr[1]),
       feesLockup: _wire2api_u64(arr[2]),
       feesClaim: _wire2api_u64(arr[3]),
-      senderAmountSat: _wire2api_u64(arr[4]),
-      recipientAmountSat: _wire2api_u64(arr[5]),
+      senderAmountSat: _wire2api_sat(arr[4]),
+      recipientAmountSat: _wire2api_sat(arr[5]),
       totalFees: _wire2api_u64(arr[6]),
     );
   }
 
+  PrepareReceivePaymentResponse _wire2api_prepare_receive_payment_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PrepareReceivePaymentResponse(
+      amountMsat: _wire2api_milli_sat(arr[0]),
+      openChannelNeeded: _wire2api_bool(arr[1]),
+      openingFeeMsat: _wire2api_milli_sat(arr[2]),
+      openingFeeParams: _wire2api_opt_box_autoadd_opening_fee_params(arr[3]),
+      receivedAmountMsat: _wire2api_milli_sat(arr[4]),
+    );
+  }
+
   PrepareRedeemOnchainFundsResponse _wire2api_prepare_redeem_onchain_funds_response(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PrepareRedeemOnchainFundsResponse(
       txWeight: _wire2api_u64(arr[0]),
-      txFeeSat: _wire2api_u64(arr[1]),
+      txFeeSat: _wire2api_sat(arr[1]),
+      amountSat: _wire2api_sat(arr[2]),
+      changeSat: _wire2api_sat(arr[3]),
     );
   }
 
   PrepareRefundResponse _wire2api_prepare_refund_response(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PrepareRefundResponse(
       refundTxWeight: _wire2api_u32(arr[0]),
-      refundTxFeeSat: _wire2api_u64(arr[1]),
+      refundTxFeeSat: _wire2api_sat(arr[1]),
+      feePreviews: _wire2api_list_refund_fee_preview(arr[2]),
+    );
+  }
+
+  PrepareSendPaymentResponse _wire2api_prepare_send_payment_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PrepareSendPaymentResponse(
+      bolt11: _wire2api_String(arr[0]),
+      amountMsat: _wire2api_milli_sat(arr[1]),
+      feeMsat: _wire2api_milli_sat(arr[2]),
+      routeHops: _wire2api_u32(arr[3]),
+      parts: _wire2api_u32(arr[4]),
+    );
+  }
+
+  ProveAddressOwnershipResponse _wire2api_prove_address_ownership_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ProveAddressOwnershipResponse(
+      signature: _wire2api_String(arr[0]),
+    );
+  }
+
+  ProxyConfig _wire2api_proxy_config(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ProxyConfig(
+      address: _wire2api_String(arr[0]),
+      username: _wire2api_opt_String(arr[1]),
+      password: _wire2api_opt_String(arr[2]),
     );
   }
 
@@ -3928,11 +9360,26 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   ReceivePaymentResponse _wire2api_receive_payment_response(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return ReceivePaymentResponse(
       lnInvoice: _wire2api_ln_invoice(arr[0]),
       openingFeeParams: _wire2api_opt_box_autoadd_opening_fee_params(arr[1]),
-      openingFeeMsat: _wire2api_opt_box_autoadd_u64(arr[2]),
+      openingFeeMsat: _wire2api_opt_box_autoadd_milli_sat(arr[2]),
+      bolt11Length: _wire2api_u32(arr[3]),
+      fiatAmount: _wire2api_opt_box_autoadd_fiat_amount(arr[4]),
+      fiatRate: _wire2api_opt_box_autoadd_rate(arr[5]),
+    );
+  }
+
+  ReceiveUnifiedResponse _wire2api_receive_unified_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReceiveUnifiedResponse(
+      bip21: _wire2api_String(arr[0]),
+      swapInfo: _wire2api_swap_info(arr[1]),
+      lnInvoice: _wire2api_ln_invoice(arr[2]),
+      openingFeeParams: _wire2api_opt_box_autoadd_opening_fee_params(arr[3]),
+      openingFeeMsat: _wire2api_opt_box_autoadd_milli_sat(arr[4]),
     );
   }
 
@@ -3948,6 +9395,21 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  RecoveryBundle _wire2api_recovery_bundle(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return RecoveryBundle(
+      nodeId: _wire2api_String(arr[0]),
+      network: _wire2api_network(arr[1]),
+      instructions: _wire2api_String(arr[2]),
+      staticBackup: _wire2api_StringList(arr[3]),
+      channels: _wire2api_list_migration_channel(arr[4]),
+      channelsBalanceMsat: _wire2api_u64(arr[5]),
+      onchainBalanceMsat: _wire2api_u64(arr[6]),
+      createdAt: _wire2api_u64(arr[7]),
+    );
+  }
+
   RedeemOnchainFundsResponse _wire2api_redeem_onchain_funds_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3956,6 +9418,16 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  RefundFeePreview _wire2api_refund_fee_preview(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RefundFeePreview(
+      preset: _wire2api_feerate_preset(arr[0]),
+      satPerVbyte: _wire2api_u32(arr[1]),
+      refundTxFeeSat: _wire2api_u64(arr[2]),
+    );
+  }
+
   RefundResponse _wire2api_refund_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -3966,7 +9438,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
 
   ReverseSwapInfo _wire2api_reverse_swap_info(dynamic raw) {
     final arr = raw as List<dynamic>;
-    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return ReverseSwapInfo(
       id: _wire2api_String(arr[0]),
       claimPubkey: _wire2api_String(arr[1]),
@@ -3974,6 +9446,7 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       claimTxid: _wire2api_opt_String(arr[3]),
       onchainAmountSat: _wire2api_u64(arr[4]),
       status: _wire2api_reverse_swap_status(arr[5]),
+      protocol: _wire2api_reverse_swap_protocol(arr[6]),
     );
   }
 
@@ -3991,6 +9464,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  ReverseSwapProtocol _wire2api_reverse_swap_protocol(dynamic raw) {
+    return ReverseSwapProtocol.values[raw as int];
+  }
+
   ReverseSwapStatus _wire2api_reverse_swap_status(dynamic raw) {
     return ReverseSwapStatus.values[raw as int];
   }
@@ -4017,14 +9494,68 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
-  SendPaymentResponse _wire2api_send_payment_response(dynamic raw) {
+  Sat _wire2api_sat(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return Sat(
+      field0: _wire2api_u64(arr[0]),
+    );
+  }
+
+  SdkInfo _wire2api_sdk_info(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SdkInfo(
+      version: _wire2api_String(arr[0]),
+      supportedLuds: _wire2api_StringList(arr[1]),
+      swapInScriptVersions: _wire2api_StringList(arr[2]),
+      reverseSwapProtocols: _wire2api_list_reverse_swap_protocol(arr[3]),
+      lspProtocols: _wire2api_StringList(arr[4]),
+      backupSchemaVersion: _wire2api_u32(arr[5]),
+      minBackupSchemaVersion: _wire2api_u32(arr[6]),
+    );
+  }
+
+  SendPaymentRequest _wire2api_send_payment_request(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SendPaymentRequest(
+      bolt11: _wire2api_String(arr[0]),
+      useTrampoline: _wire2api_opt_box_autoadd_bool(arr[1]),
+      amountMsat: _wire2api_opt_box_autoadd_milli_sat(arr[2]),
+      label: _wire2api_opt_String(arr[3]),
+      routeHintIndex: _wire2api_opt_box_autoadd_u32(arr[4]),
+      paymentTimeoutSec: _wire2api_opt_box_autoadd_u32(arr[5]),
+      maxfeePercent: _wire2api_opt_box_autoadd_f64(arr[6]),
+      exemptfeeMsat: _wire2api_opt_box_autoadd_milli_sat(arr[7]),
+      cancelToken: _wire2api_opt_String(arr[8]),
+      skipDenylist: _wire2api_opt_box_autoadd_bool(arr[9]),
+    );
+  }
+
+  SendPaymentResponse _wire2api_send_payment_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
     return SendPaymentResponse(
       payment: _wire2api_payment(arr[0]),
+      partnerFee: _wire2api_opt_box_autoadd_partner_fee_details(arr[1]),
     );
   }
 
+  SendPaymentsResponse _wire2api_send_payments_response(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SendPaymentsResponse(
+      results: _wire2api_list_batch_payment_result(arr[0]),
+      succeeded: _wire2api_u32(arr[1]),
+      failed: _wire2api_u32(arr[2]),
+    );
+  }
+
+  ServiceComponent _wire2api_service_component(dynamic raw) {
+    return ServiceComponent.values[raw as int];
+  }
+
   ServiceHealthCheckResponse _wire2api_service_health_check_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -4033,6 +9564,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  ServiceStatus _wire2api_service_status(dynamic raw) {
+    return ServiceStatus.values[raw as int];
+  }
+
   SignMessageResponse _wire2api_sign_message_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -4041,6 +9576,37 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  SnapshotChange _wire2api_snapshot_change(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SnapshotChange(
+      path: _wire2api_String(arr[0]),
+      before: _wire2api_opt_String(arr[1]),
+      after: _wire2api_opt_String(arr[2]),
+    );
+  }
+
+  SpendPolicy _wire2api_spend_policy(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SpendPolicy(
+      dailyLimitMsat: _wire2api_opt_box_autoadd_u64(arr[0]),
+      perPaymentLimitMsat: _wire2api_opt_box_autoadd_u64(arr[1]),
+      allowedDestinations: _wire2api_opt_StringList(arr[2]),
+    );
+  }
+
+  SpontaneousPaymentMetadata _wire2api_spontaneous_payment_metadata(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SpontaneousPaymentMetadata(
+      message: _wire2api_opt_String(arr[0]),
+      senderPubkey: _wire2api_opt_String(arr[1]),
+      senderName: _wire2api_opt_String(arr[2]),
+      boostagram: _wire2api_opt_String(arr[3]),
+    );
+  }
+
   StaticBackupResponse _wire2api_static_backup_response(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
@@ -4049,6 +9615,16 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  StaticLnurlPay _wire2api_static_lnurl_pay(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StaticLnurlPay(
+      lnurl: _wire2api_String(arr[0]),
+      metadata: _wire2api_String(arr[1]),
+      payRequest: _wire2api_String(arr[2]),
+    );
+  }
+
   SuccessActionProcessed _wire2api_success_action_processed(dynamic raw) {
     switch (raw[0]) {
       case 0:
@@ -4099,6 +9675,28 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  SwapRefundKey _wire2api_swap_refund_key(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SwapRefundKey(
+      index: _wire2api_u32(arr[0]),
+      derivationPath: _wire2api_String(arr[1]),
+      privateKey: _wire2api_uint_8_list(arr[2]),
+      publicKey: _wire2api_uint_8_list(arr[3]),
+    );
+  }
+
+  SwapRefundedDetails _wire2api_swap_refunded_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SwapRefundedDetails(
+      swapAddress: _wire2api_String(arr[0]),
+      refundTxId: _wire2api_String(arr[1]),
+      toAddress: _wire2api_String(arr[2]),
+      satPerVbyte: _wire2api_u32(arr[3]),
+    );
+  }
+
   SwapStatus _wire2api_swap_status(dynamic raw) {
     return SwapStatus.values[raw as int];
   }
@@ -4114,6 +9712,32 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     );
   }
 
+  TaskState _wire2api_task_state(dynamic raw) {
+    return TaskState.values[raw as int];
+  }
+
+  TaskStatus _wire2api_task_status(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TaskStatus(
+      name: _wire2api_String(arr[0]),
+      state: _wire2api_task_state(arr[1]),
+      restarts: _wire2api_u32(arr[2]),
+      lastError: _wire2api_opt_String(arr[3]),
+      lastErrorAt: _wire2api_opt_box_autoadd_i64(arr[4]),
+    );
+  }
+
+  TlvRecord _wire2api_tlv_record(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TlvRecord(
+      fieldNumber: _wire2api_u64(arr[0]),
+      valueText: _wire2api_opt_String(arr[1]),
+      valueHex: _wire2api_opt_String(arr[2]),
+    );
+  }
+
   int _wire2api_u16(dynamic raw) {
     return raw as int;
   }
@@ -4130,6 +9754,10 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return raw as int;
   }
 
+  Uint64List _wire2api_uint_64_list(dynamic raw) {
+    return Uint64List.from(raw);
+  }
+
   Uint8List _wire2api_uint_8_list(dynamic raw) {
     return raw as Uint8List;
   }
@@ -4138,6 +9766,18 @@ class BreezSdkCoreImpl implements BreezSdkCore {
     return;
   }
 
+  UnredeemedFundsDetails _wire2api_unredeemed_funds_details(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return UnredeemedFundsDetails(
+      refundableSwaps: _wire2api_list_swap_info(arr[0]),
+      redeemableSwaps: _wire2api_list_swap_info(arr[1]),
+      expiredInvoiceSwaps: _wire2api_list_swap_info(arr[2]),
+      unclaimedReverseSwaps: _wire2api_list_reverse_swap_info(arr[3]),
+      totalAmountSat: _wire2api_u64(arr[4]),
+    );
+  }
+
   UnspentTransactionOutput _wire2api_unspent_transaction_output(dynamic raw) {
     final arr = raw as List<dynamic>;
     if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
@@ -4158,6 +9798,17 @@ class BreezSdkCoreImpl implements BreezSdkCore {
       url: _wire2api_String(arr[1]),
     );
   }
+
+  UserSettings _wire2api_user_settings(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return UserSettings(
+      preferredFiatCurrency: _wire2api_opt_String(arr[0]),
+      denomination: _wire2api_opt_box_autoadd_denomination(arr[1]),
+      defaultFeeratePreset: _wire2api_opt_box_autoadd_feerate_preset(arr[2]),
+      preferredLspId: _wire2api_opt_String(arr[3]),
+    );
+  }
 }
 
 // Section: api2wire
@@ -4172,31 +9823,66 @@ int api2wire_buy_bitcoin_provider(BuyBitcoinProvider raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_denomination(Denomination raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_environment_type(EnvironmentType raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_export_format(ExportFormat raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 double api2wire_f64(double raw) {
   return raw;
 }
 
+@protected
+int api2wire_feerate_preset(FeeratePreset raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_fiat_rate_alert_direction(FiatRateAlertDirection raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_i32(int raw) {
   return raw;
 }
 
+@protected
+int api2wire_memo_privacy(MemoPrivacy raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_network(Network raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_payment_group_by(PaymentGroupBy raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_payment_type_filter(PaymentTypeFilter raw) {
   return api2wire_i32(raw.index);
 }
 
+@protected
+int api2wire_sort_order(SortOrder raw) {
+  return api2wire_i32(raw.index);
+}
+
 @protected
 int api2wire_swap_amount_type(SwapAmountType raw) {
   return api2wire_i32(raw.index);
@@ -4234,11 +9920,57 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return api2wire_uint_8_list(utf8.encoder.convert(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList_0(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_AddFiatRateAlertRequest> api2wire_box_autoadd_add_fiat_rate_alert_request(
+      AddFiatRateAlertRequest raw) {
+    final ptr = inner.new_box_autoadd_add_fiat_rate_alert_request_0();
+    _api_fill_to_wire_add_fiat_rate_alert_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_AmendInvoiceRequest> api2wire_box_autoadd_amend_invoice_request(AmendInvoiceRequest raw) {
+    final ptr = inner.new_box_autoadd_amend_invoice_request_0();
+    _api_fill_to_wire_amend_invoice_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_AutoRefundConfig> api2wire_box_autoadd_auto_refund_config(AutoRefundConfig raw) {
+    final ptr = inner.new_box_autoadd_auto_refund_config_0();
+    _api_fill_to_wire_auto_refund_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_BackupTransportConfig> api2wire_box_autoadd_backup_transport_config(
+      BackupTransportConfig raw) {
+    final ptr = inner.new_box_autoadd_backup_transport_config_0();
+    _api_fill_to_wire_backup_transport_config(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> api2wire_box_autoadd_bool(bool raw) {
     return inner.new_box_autoadd_bool_0(api2wire_bool(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_BumpFeeRequest> api2wire_box_autoadd_bump_fee_request(BumpFeeRequest raw) {
+    final ptr = inner.new_box_autoadd_bump_fee_request_0();
+    _api_fill_to_wire_bump_fee_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_BuyBitcoinRequest> api2wire_box_autoadd_buy_bitcoin_request(BuyBitcoinRequest raw) {
     final ptr = inner.new_box_autoadd_buy_bitcoin_request_0();
@@ -4246,6 +9978,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ChainServiceConfig> api2wire_box_autoadd_chain_service_config(ChainServiceConfig raw) {
+    final ptr = inner.new_box_autoadd_chain_service_config_0();
+    _api_fill_to_wire_chain_service_config(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_CheckMessageRequest> api2wire_box_autoadd_check_message_request(CheckMessageRequest raw) {
     final ptr = inner.new_box_autoadd_check_message_request_0();
@@ -4253,6 +9992,20 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_CloseChannelRequest> api2wire_box_autoadd_close_channel_request(CloseChannelRequest raw) {
+    final ptr = inner.new_box_autoadd_close_channel_request_0();
+    _api_fill_to_wire_close_channel_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_Config> api2wire_box_autoadd_config(Config raw) {
+    final ptr = inner.new_box_autoadd_config_0();
+    _api_fill_to_wire_config(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ConfigureNodeRequest> api2wire_box_autoadd_configure_node_request(
       ConfigureNodeRequest raw) {
@@ -4261,6 +10014,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ConnectPeerRequest> api2wire_box_autoadd_connect_peer_request(ConnectPeerRequest raw) {
+    final ptr = inner.new_box_autoadd_connect_peer_request_0();
+    _api_fill_to_wire_connect_peer_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ConnectRequest> api2wire_box_autoadd_connect_request(ConnectRequest raw) {
     final ptr = inner.new_box_autoadd_connect_request_0();
@@ -4268,6 +10028,100 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest>
+      api2wire_box_autoadd_create_lnurl_withdraw_voucher_request(CreateLnurlWithdrawVoucherRequest raw) {
+    final ptr = inner.new_box_autoadd_create_lnurl_withdraw_voucher_request_0();
+    _api_fill_to_wire_create_lnurl_withdraw_voucher_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_CreateStaticLnurlPayRequest> api2wire_box_autoadd_create_static_lnurl_pay_request(
+      CreateStaticLnurlPayRequest raw) {
+    final ptr = inner.new_box_autoadd_create_static_lnurl_pay_request_0();
+    _api_fill_to_wire_create_static_lnurl_pay_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_DecryptRecoveryBundleRequest> api2wire_box_autoadd_decrypt_recovery_bundle_request(
+      DecryptRecoveryBundleRequest raw) {
+    final ptr = inner.new_box_autoadd_decrypt_recovery_bundle_request_0();
+    _api_fill_to_wire_decrypt_recovery_bundle_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_denomination(Denomination raw) {
+    return inner.new_box_autoadd_denomination_0(api2wire_denomination(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_DeriveEncryptionKeyRequest> api2wire_box_autoadd_derive_encryption_key_request(
+      DeriveEncryptionKeyRequest raw) {
+    final ptr = inner.new_box_autoadd_derive_encryption_key_request_0();
+    _api_fill_to_wire_derive_encryption_key_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_EncryptedPaymentRequestData> api2wire_box_autoadd_encrypted_payment_request_data(
+      EncryptedPaymentRequestData raw) {
+    final ptr = inner.new_box_autoadd_encrypted_payment_request_data_0();
+    _api_fill_to_wire_encrypted_payment_request_data(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ExportPaymentsRequest> api2wire_box_autoadd_export_payments_request(
+      ExportPaymentsRequest raw) {
+    final ptr = inner.new_box_autoadd_export_payments_request_0();
+    _api_fill_to_wire_export_payments_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ExportRecoveryBundleRequest> api2wire_box_autoadd_export_recovery_bundle_request(
+      ExportRecoveryBundleRequest raw) {
+    final ptr = inner.new_box_autoadd_export_recovery_bundle_request_0();
+    _api_fill_to_wire_export_recovery_bundle_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ExportSwapRefundKeysRequest> api2wire_box_autoadd_export_swap_refund_keys_request(
+      ExportSwapRefundKeysRequest raw) {
+    final ptr = inner.new_box_autoadd_export_swap_refund_keys_request_0();
+    _api_fill_to_wire_export_swap_refund_keys_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Double> api2wire_box_autoadd_f64(double raw) {
+    return inner.new_box_autoadd_f64_0(api2wire_f64(raw));
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_feerate_preset(FeeratePreset raw) {
+    return inner.new_box_autoadd_feerate_preset_0(api2wire_feerate_preset(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest> api2wire_box_autoadd_fetch_buy_bitcoin_quote_request(
+      FetchBuyBitcoinQuoteRequest raw) {
+    final ptr = inner.new_box_autoadd_fetch_buy_bitcoin_quote_request_0();
+    _api_fill_to_wire_fetch_buy_bitcoin_quote_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_FiatAmount> api2wire_box_autoadd_fiat_amount(FiatAmount raw) {
+    final ptr = inner.new_box_autoadd_fiat_amount_0();
+    _api_fill_to_wire_fiat_amount(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_GreenlightCredentials> api2wire_box_autoadd_greenlight_credentials(
       GreenlightCredentials raw) {
@@ -4284,11 +10138,34 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_HtlcExposureLimits> api2wire_box_autoadd_htlc_exposure_limits(HtlcExposureLimits raw) {
+    final ptr = inner.new_box_autoadd_htlc_exposure_limits_0();
+    _api_fill_to_wire_htlc_exposure_limits(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Int64> api2wire_box_autoadd_i64(int raw) {
     return inner.new_box_autoadd_i64_0(api2wire_i64(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_ImportPaymentsRequest> api2wire_box_autoadd_import_payments_request(
+      ImportPaymentsRequest raw) {
+    final ptr = inner.new_box_autoadd_import_payments_request_0();
+    _api_fill_to_wire_import_payments_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ListPaymentGroupsRequest> api2wire_box_autoadd_list_payment_groups_request(
+      ListPaymentGroupsRequest raw) {
+    final ptr = inner.new_box_autoadd_list_payment_groups_request_0();
+    _api_fill_to_wire_list_payment_groups_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ListPaymentsRequest> api2wire_box_autoadd_list_payments_request(ListPaymentsRequest raw) {
     final ptr = inner.new_box_autoadd_list_payments_request_0();
@@ -4303,6 +10180,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_LnUrlAuthExport> api2wire_box_autoadd_ln_url_auth_export(LnUrlAuthExport raw) {
+    final ptr = inner.new_box_autoadd_ln_url_auth_export_0();
+    _api_fill_to_wire_ln_url_auth_export(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_LnUrlAuthRequestData> api2wire_box_autoadd_ln_url_auth_request_data(
       LnUrlAuthRequestData raw) {
@@ -4326,6 +10210,27 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_LspPolicy> api2wire_box_autoadd_lsp_policy(LspPolicy raw) {
+    final ptr = inner.new_box_autoadd_lsp_policy_0();
+    _api_fill_to_wire_lsp_policy(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_MilliSat> api2wire_box_autoadd_milli_sat(MilliSat raw) {
+    final ptr = inner.new_box_autoadd_milli_sat_0();
+    _api_fill_to_wire_milli_sat(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_MockNodeConfig> api2wire_box_autoadd_mock_node_config(MockNodeConfig raw) {
+    final ptr = inner.new_box_autoadd_mock_node_config_0();
+    _api_fill_to_wire_mock_node_config(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_NodeConfig> api2wire_box_autoadd_node_config(NodeConfig raw) {
     final ptr = inner.new_box_autoadd_node_config_0();
@@ -4333,6 +10238,14 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_NodeMigrationRequest> api2wire_box_autoadd_node_migration_request(
+      NodeMigrationRequest raw) {
+    final ptr = inner.new_box_autoadd_node_migration_request_0();
+    _api_fill_to_wire_node_migration_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_OpenChannelFeeRequest> api2wire_box_autoadd_open_channel_fee_request(
       OpenChannelFeeRequest raw) {
@@ -4341,6 +10254,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_OpenChannelRequest> api2wire_box_autoadd_open_channel_request(OpenChannelRequest raw) {
+    final ptr = inner.new_box_autoadd_open_channel_request_0();
+    _api_fill_to_wire_open_channel_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_OpeningFeeParams> api2wire_box_autoadd_opening_fee_params(OpeningFeeParams raw) {
     final ptr = inner.new_box_autoadd_opening_fee_params_0();
@@ -4348,6 +10268,28 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_PartnerFeeConfig> api2wire_box_autoadd_partner_fee_config(PartnerFeeConfig raw) {
+    final ptr = inner.new_box_autoadd_partner_fee_config_0();
+    _api_fill_to_wire_partner_fee_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_PayOfferRequest> api2wire_box_autoadd_pay_offer_request(PayOfferRequest raw) {
+    final ptr = inner.new_box_autoadd_pay_offer_request_0();
+    _api_fill_to_wire_pay_offer_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_PayOnchainAddressRequest> api2wire_box_autoadd_pay_onchain_address_request(
+      PayOnchainAddressRequest raw) {
+    final ptr = inner.new_box_autoadd_pay_onchain_address_request_0();
+    _api_fill_to_wire_pay_onchain_address_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_PayOnchainRequest> api2wire_box_autoadd_pay_onchain_request(PayOnchainRequest raw) {
     final ptr = inner.new_box_autoadd_pay_onchain_request_0();
@@ -4355,6 +10297,14 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_PaymentRequestBundle> api2wire_box_autoadd_payment_request_bundle(
+      PaymentRequestBundle raw) {
+    final ptr = inner.new_box_autoadd_payment_request_bundle_0();
+    _api_fill_to_wire_payment_request_bundle(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_PrepareOnchainPaymentRequest> api2wire_box_autoadd_prepare_onchain_payment_request(
       PrepareOnchainPaymentRequest raw) {
@@ -4363,6 +10313,14 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_PrepareReceivePaymentRequest> api2wire_box_autoadd_prepare_receive_payment_request(
+      PrepareReceivePaymentRequest raw) {
+    final ptr = inner.new_box_autoadd_prepare_receive_payment_request_0();
+    _api_fill_to_wire_prepare_receive_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_PrepareRedeemOnchainFundsRequest>
       api2wire_box_autoadd_prepare_redeem_onchain_funds_request(PrepareRedeemOnchainFundsRequest raw) {
@@ -4379,6 +10337,43 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_PrepareSendPaymentRequest> api2wire_box_autoadd_prepare_send_payment_request(
+      PrepareSendPaymentRequest raw) {
+    final ptr = inner.new_box_autoadd_prepare_send_payment_request_0();
+    _api_fill_to_wire_prepare_send_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ProbePaymentRequest> api2wire_box_autoadd_probe_payment_request(ProbePaymentRequest raw) {
+    final ptr = inner.new_box_autoadd_probe_payment_request_0();
+    _api_fill_to_wire_probe_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ProveAddressOwnershipRequest> api2wire_box_autoadd_prove_address_ownership_request(
+      ProveAddressOwnershipRequest raw) {
+    final ptr = inner.new_box_autoadd_prove_address_ownership_request_0();
+    _api_fill_to_wire_prove_address_ownership_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ProxyConfig> api2wire_box_autoadd_proxy_config(ProxyConfig raw) {
+    final ptr = inner.new_box_autoadd_proxy_config_0();
+    _api_fill_to_wire_proxy_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_QueuePaymentRequest> api2wire_box_autoadd_queue_payment_request(QueuePaymentRequest raw) {
+    final ptr = inner.new_box_autoadd_queue_payment_request_0();
+    _api_fill_to_wire_queue_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_ReceiveOnchainRequest> api2wire_box_autoadd_receive_onchain_request(
       ReceiveOnchainRequest raw) {
@@ -4395,6 +10390,22 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_ReceiveUnifiedRequest> api2wire_box_autoadd_receive_unified_request(
+      ReceiveUnifiedRequest raw) {
+    final ptr = inner.new_box_autoadd_receive_unified_request_0();
+    _api_fill_to_wire_receive_unified_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest>
+      api2wire_box_autoadd_redeem_lnurl_withdraw_voucher_request(RedeemLnurlWithdrawVoucherRequest raw) {
+    final ptr = inner.new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0();
+    _api_fill_to_wire_redeem_lnurl_withdraw_voucher_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_RedeemOnchainFundsRequest> api2wire_box_autoadd_redeem_onchain_funds_request(
       RedeemOnchainFundsRequest raw) {
@@ -4433,6 +10444,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_Sat> api2wire_box_autoadd_sat(Sat raw) {
+    final ptr = inner.new_box_autoadd_sat_0();
+    _api_fill_to_wire_sat(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_SendPaymentRequest> api2wire_box_autoadd_send_payment_request(SendPaymentRequest raw) {
     final ptr = inner.new_box_autoadd_send_payment_request_0();
@@ -4440,6 +10458,13 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_SendPaymentsRequest> api2wire_box_autoadd_send_payments_request(SendPaymentsRequest raw) {
+    final ptr = inner.new_box_autoadd_send_payments_request_0();
+    _api_fill_to_wire_send_payments_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_SendSpontaneousPaymentRequest> api2wire_box_autoadd_send_spontaneous_payment_request(
       SendSpontaneousPaymentRequest raw) {
@@ -4455,6 +10480,18 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_box_autoadd_sort_order(SortOrder raw) {
+    return inner.new_box_autoadd_sort_order_0(api2wire_sort_order(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_SpendPolicy> api2wire_box_autoadd_spend_policy(SpendPolicy raw) {
+    final ptr = inner.new_box_autoadd_spend_policy_0();
+    _api_fill_to_wire_spend_policy(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_StaticBackupRequest> api2wire_box_autoadd_static_backup_request(StaticBackupRequest raw) {
     final ptr = inner.new_box_autoadd_static_backup_request_0();
@@ -4462,6 +10499,19 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_StaticLnurlPayCallbackRequest> api2wire_box_autoadd_static_lnurl_pay_callback_request(
+      StaticLnurlPayCallbackRequest raw) {
+    final ptr = inner.new_box_autoadd_static_lnurl_pay_callback_request_0();
+    _api_fill_to_wire_static_lnurl_pay_callback_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> api2wire_box_autoadd_u16(int raw) {
+    return inner.new_box_autoadd_u16_0(api2wire_u16(raw));
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> api2wire_box_autoadd_u32(int raw) {
     return inner.new_box_autoadd_u32_0(api2wire_u32(raw));
@@ -4472,11 +10522,35 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return inner.new_box_autoadd_u64_0(api2wire_u64(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_UserSettings> api2wire_box_autoadd_user_settings(UserSettings raw) {
+    final ptr = inner.new_box_autoadd_user_settings_0();
+    _api_fill_to_wire_user_settings(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_ValidatePayabilityRequest> api2wire_box_autoadd_validate_payability_request(
+      ValidatePayabilityRequest raw) {
+    final ptr = inner.new_box_autoadd_validate_payability_request_0();
+    _api_fill_to_wire_validate_payability_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   int api2wire_i64(int raw) {
     return raw;
   }
 
+  @protected
+  ffi.Pointer<wire_list_ln_url_auth_domain> api2wire_list_ln_url_auth_domain(List<LnUrlAuthDomain> raw) {
+    final ans = inner.new_list_ln_url_auth_domain_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_ln_url_auth_domain(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_list_metadata_filter> api2wire_list_metadata_filter(List<MetadataFilter> raw) {
     final ans = inner.new_list_metadata_filter_0(raw.length);
@@ -4490,7 +10564,35 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
   ffi.Pointer<wire_list_payment_type_filter> api2wire_list_payment_type_filter(List<PaymentTypeFilter> raw) {
     final ans = inner.new_list_payment_type_filter_0(raw.length);
     for (var i = 0; i < raw.length; ++i) {
-      ans.ref.ptr[i] = api2wire_payment_type_filter(raw[i]);
+      ans.ref.ptr[i] = api2wire_payment_type_filter(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_route_hint> api2wire_list_route_hint(List<RouteHint> raw) {
+    final ans = inner.new_list_route_hint_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_route_hint(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_route_hint_hop> api2wire_list_route_hint_hop(List<RouteHintHop> raw) {
+    final ans = inner.new_list_route_hint_hop_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_route_hint_hop(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_list_send_payment_request> api2wire_list_send_payment_request(
+      List<SendPaymentRequest> raw) {
+    final ans = inner.new_list_send_payment_request_0(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      _api_fill_to_wire_send_payment_request(raw[i], ans.ref.ptr[i]);
     }
     return ans;
   }
@@ -4518,27 +10620,115 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return raw == null ? ffi.nullptr : api2wire_String(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_opt_StringList(List<String>? raw) {
+    return raw == null ? ffi.nullptr : api2wire_StringList(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_AutoRefundConfig> api2wire_opt_box_autoadd_auto_refund_config(AutoRefundConfig? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_auto_refund_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_BackupTransportConfig> api2wire_opt_box_autoadd_backup_transport_config(
+      BackupTransportConfig? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_backup_transport_config(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> api2wire_opt_box_autoadd_bool(bool? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_bool(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_ChainServiceConfig> api2wire_opt_box_autoadd_chain_service_config(
+      ChainServiceConfig? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_chain_service_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_denomination(Denomination? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_denomination(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Double> api2wire_opt_box_autoadd_f64(double? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_f64(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_feerate_preset(FeeratePreset? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_feerate_preset(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_FiatAmount> api2wire_opt_box_autoadd_fiat_amount(FiatAmount? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_fiat_amount(raw);
+  }
+
   @protected
   ffi.Pointer<wire_GreenlightCredentials> api2wire_opt_box_autoadd_greenlight_credentials(
       GreenlightCredentials? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_greenlight_credentials(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_HtlcExposureLimits> api2wire_opt_box_autoadd_htlc_exposure_limits(
+      HtlcExposureLimits? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_htlc_exposure_limits(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Int64> api2wire_opt_box_autoadd_i64(int? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_i64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_LspPolicy> api2wire_opt_box_autoadd_lsp_policy(LspPolicy? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_lsp_policy(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_MilliSat> api2wire_opt_box_autoadd_milli_sat(MilliSat? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_milli_sat(raw);
+  }
+
   @protected
   ffi.Pointer<wire_OpeningFeeParams> api2wire_opt_box_autoadd_opening_fee_params(OpeningFeeParams? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_opening_fee_params(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_PartnerFeeConfig> api2wire_opt_box_autoadd_partner_fee_config(PartnerFeeConfig? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_partner_fee_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_ProxyConfig> api2wire_opt_box_autoadd_proxy_config(ProxyConfig? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_proxy_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_Sat> api2wire_opt_box_autoadd_sat(Sat? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_sat(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> api2wire_opt_box_autoadd_sort_order(SortOrder? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_sort_order(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_SpendPolicy> api2wire_opt_box_autoadd_spend_policy(SpendPolicy? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_spend_policy(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint16> api2wire_opt_box_autoadd_u16(int? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_u16(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> api2wire_opt_box_autoadd_u32(int? raw) {
     return raw == null ? ffi.nullptr : api2wire_box_autoadd_u32(raw);
@@ -4560,6 +10750,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     return raw == null ? ffi.nullptr : api2wire_list_payment_type_filter(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_list_route_hint> api2wire_opt_list_route_hint(List<RouteHint>? raw) {
+    return raw == null ? ffi.nullptr : api2wire_list_route_hint(raw);
+  }
+
   @protected
   ffi.Pointer<wire_list_swap_status> api2wire_opt_list_swap_status(List<SwapStatus>? raw) {
     return raw == null ? ffi.nullptr : api2wire_list_swap_status(raw);
@@ -4590,26 +10785,168 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
 
 // Section: api_fill_to_wire
 
+  void _api_fill_to_wire_add_fiat_rate_alert_request(
+      AddFiatRateAlertRequest apiObj, wire_AddFiatRateAlertRequest wireObj) {
+    wireObj.currency = api2wire_String(apiObj.currency);
+    wireObj.direction = api2wire_fiat_rate_alert_direction(apiObj.direction);
+    wireObj.threshold = api2wire_f64(apiObj.threshold);
+  }
+
+  void _api_fill_to_wire_amend_invoice_request(AmendInvoiceRequest apiObj, wire_AmendInvoiceRequest wireObj) {
+    wireObj.bolt11 = api2wire_String(apiObj.bolt11);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
+    wireObj.expiry = api2wire_opt_box_autoadd_u32(apiObj.expiry);
+    wireObj.route_hints = api2wire_opt_list_route_hint(apiObj.routeHints);
+  }
+
+  void _api_fill_to_wire_auto_refund_config(AutoRefundConfig apiObj, wire_AutoRefundConfig wireObj) {
+    wireObj.to_address = api2wire_opt_String(apiObj.toAddress);
+    wireObj.sat_per_vbyte = api2wire_opt_box_autoadd_u32(apiObj.satPerVbyte);
+  }
+
+  void _api_fill_to_wire_backup_transport_config(
+      BackupTransportConfig apiObj, wire_BackupTransportConfig wireObj) {
+    if (apiObj is BackupTransportConfig_S3) {
+      var pre_endpoint = api2wire_String(apiObj.endpoint);
+      var pre_region = api2wire_String(apiObj.region);
+      var pre_bucket = api2wire_String(apiObj.bucket);
+      var pre_access_key_id = api2wire_String(apiObj.accessKeyId);
+      var pre_secret_access_key = api2wire_String(apiObj.secretAccessKey);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_BackupTransportConfig_S3();
+      wireObj.kind.ref.S3.ref.endpoint = pre_endpoint;
+      wireObj.kind.ref.S3.ref.region = pre_region;
+      wireObj.kind.ref.S3.ref.bucket = pre_bucket;
+      wireObj.kind.ref.S3.ref.access_key_id = pre_access_key_id;
+      wireObj.kind.ref.S3.ref.secret_access_key = pre_secret_access_key;
+      return;
+    }
+    if (apiObj is BackupTransportConfig_WebDav) {
+      var pre_url = api2wire_String(apiObj.url);
+      var pre_username = api2wire_opt_String(apiObj.username);
+      var pre_password = api2wire_opt_String(apiObj.password);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_BackupTransportConfig_WebDav();
+      wireObj.kind.ref.WebDav.ref.url = pre_url;
+      wireObj.kind.ref.WebDav.ref.username = pre_username;
+      wireObj.kind.ref.WebDav.ref.password = pre_password;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_box_autoadd_add_fiat_rate_alert_request(
+      AddFiatRateAlertRequest apiObj, ffi.Pointer<wire_AddFiatRateAlertRequest> wireObj) {
+    _api_fill_to_wire_add_fiat_rate_alert_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_amend_invoice_request(
+      AmendInvoiceRequest apiObj, ffi.Pointer<wire_AmendInvoiceRequest> wireObj) {
+    _api_fill_to_wire_amend_invoice_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_auto_refund_config(
+      AutoRefundConfig apiObj, ffi.Pointer<wire_AutoRefundConfig> wireObj) {
+    _api_fill_to_wire_auto_refund_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_backup_transport_config(
+      BackupTransportConfig apiObj, ffi.Pointer<wire_BackupTransportConfig> wireObj) {
+    _api_fill_to_wire_backup_transport_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_bump_fee_request(
+      BumpFeeRequest apiObj, ffi.Pointer<wire_BumpFeeRequest> wireObj) {
+    _api_fill_to_wire_bump_fee_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_buy_bitcoin_request(
       BuyBitcoinRequest apiObj, ffi.Pointer<wire_BuyBitcoinRequest> wireObj) {
     _api_fill_to_wire_buy_bitcoin_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_chain_service_config(
+      ChainServiceConfig apiObj, ffi.Pointer<wire_ChainServiceConfig> wireObj) {
+    _api_fill_to_wire_chain_service_config(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_check_message_request(
       CheckMessageRequest apiObj, ffi.Pointer<wire_CheckMessageRequest> wireObj) {
     _api_fill_to_wire_check_message_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_close_channel_request(
+      CloseChannelRequest apiObj, ffi.Pointer<wire_CloseChannelRequest> wireObj) {
+    _api_fill_to_wire_close_channel_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_config(Config apiObj, ffi.Pointer<wire_Config> wireObj) {
+    _api_fill_to_wire_config(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_configure_node_request(
       ConfigureNodeRequest apiObj, ffi.Pointer<wire_ConfigureNodeRequest> wireObj) {
     _api_fill_to_wire_configure_node_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_connect_peer_request(
+      ConnectPeerRequest apiObj, ffi.Pointer<wire_ConnectPeerRequest> wireObj) {
+    _api_fill_to_wire_connect_peer_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_connect_request(
       ConnectRequest apiObj, ffi.Pointer<wire_ConnectRequest> wireObj) {
     _api_fill_to_wire_connect_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_create_lnurl_withdraw_voucher_request(
+      CreateLnurlWithdrawVoucherRequest apiObj, ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest> wireObj) {
+    _api_fill_to_wire_create_lnurl_withdraw_voucher_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_create_static_lnurl_pay_request(
+      CreateStaticLnurlPayRequest apiObj, ffi.Pointer<wire_CreateStaticLnurlPayRequest> wireObj) {
+    _api_fill_to_wire_create_static_lnurl_pay_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_decrypt_recovery_bundle_request(
+      DecryptRecoveryBundleRequest apiObj, ffi.Pointer<wire_DecryptRecoveryBundleRequest> wireObj) {
+    _api_fill_to_wire_decrypt_recovery_bundle_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_derive_encryption_key_request(
+      DeriveEncryptionKeyRequest apiObj, ffi.Pointer<wire_DeriveEncryptionKeyRequest> wireObj) {
+    _api_fill_to_wire_derive_encryption_key_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_encrypted_payment_request_data(
+      EncryptedPaymentRequestData apiObj, ffi.Pointer<wire_EncryptedPaymentRequestData> wireObj) {
+    _api_fill_to_wire_encrypted_payment_request_data(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_export_payments_request(
+      ExportPaymentsRequest apiObj, ffi.Pointer<wire_ExportPaymentsRequest> wireObj) {
+    _api_fill_to_wire_export_payments_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_export_recovery_bundle_request(
+      ExportRecoveryBundleRequest apiObj, ffi.Pointer<wire_ExportRecoveryBundleRequest> wireObj) {
+    _api_fill_to_wire_export_recovery_bundle_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_export_swap_refund_keys_request(
+      ExportSwapRefundKeysRequest apiObj, ffi.Pointer<wire_ExportSwapRefundKeysRequest> wireObj) {
+    _api_fill_to_wire_export_swap_refund_keys_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_fetch_buy_bitcoin_quote_request(
+      FetchBuyBitcoinQuoteRequest apiObj, ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest> wireObj) {
+    _api_fill_to_wire_fetch_buy_bitcoin_quote_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_fiat_amount(FiatAmount apiObj, ffi.Pointer<wire_FiatAmount> wireObj) {
+    _api_fill_to_wire_fiat_amount(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_greenlight_credentials(
       GreenlightCredentials apiObj, ffi.Pointer<wire_GreenlightCredentials> wireObj) {
     _api_fill_to_wire_greenlight_credentials(apiObj, wireObj.ref);
@@ -4620,6 +10957,21 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_greenlight_node_config(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_htlc_exposure_limits(
+      HtlcExposureLimits apiObj, ffi.Pointer<wire_HtlcExposureLimits> wireObj) {
+    _api_fill_to_wire_htlc_exposure_limits(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_import_payments_request(
+      ImportPaymentsRequest apiObj, ffi.Pointer<wire_ImportPaymentsRequest> wireObj) {
+    _api_fill_to_wire_import_payments_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_list_payment_groups_request(
+      ListPaymentGroupsRequest apiObj, ffi.Pointer<wire_ListPaymentGroupsRequest> wireObj) {
+    _api_fill_to_wire_list_payment_groups_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_list_payments_request(
       ListPaymentsRequest apiObj, ffi.Pointer<wire_ListPaymentsRequest> wireObj) {
     _api_fill_to_wire_list_payments_request(apiObj, wireObj.ref);
@@ -4630,6 +10982,11 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_list_swaps_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_ln_url_auth_export(
+      LnUrlAuthExport apiObj, ffi.Pointer<wire_LnUrlAuthExport> wireObj) {
+    _api_fill_to_wire_ln_url_auth_export(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_ln_url_auth_request_data(
       LnUrlAuthRequestData apiObj, ffi.Pointer<wire_LnUrlAuthRequestData> wireObj) {
     _api_fill_to_wire_ln_url_auth_request_data(apiObj, wireObj.ref);
@@ -4645,30 +11002,78 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_ln_url_withdraw_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_lsp_policy(LspPolicy apiObj, ffi.Pointer<wire_LspPolicy> wireObj) {
+    _api_fill_to_wire_lsp_policy(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_milli_sat(MilliSat apiObj, ffi.Pointer<wire_MilliSat> wireObj) {
+    _api_fill_to_wire_milli_sat(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_mock_node_config(
+      MockNodeConfig apiObj, ffi.Pointer<wire_MockNodeConfig> wireObj) {
+    _api_fill_to_wire_mock_node_config(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_node_config(NodeConfig apiObj, ffi.Pointer<wire_NodeConfig> wireObj) {
     _api_fill_to_wire_node_config(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_node_migration_request(
+      NodeMigrationRequest apiObj, ffi.Pointer<wire_NodeMigrationRequest> wireObj) {
+    _api_fill_to_wire_node_migration_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_open_channel_fee_request(
       OpenChannelFeeRequest apiObj, ffi.Pointer<wire_OpenChannelFeeRequest> wireObj) {
     _api_fill_to_wire_open_channel_fee_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_open_channel_request(
+      OpenChannelRequest apiObj, ffi.Pointer<wire_OpenChannelRequest> wireObj) {
+    _api_fill_to_wire_open_channel_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_opening_fee_params(
       OpeningFeeParams apiObj, ffi.Pointer<wire_OpeningFeeParams> wireObj) {
     _api_fill_to_wire_opening_fee_params(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_partner_fee_config(
+      PartnerFeeConfig apiObj, ffi.Pointer<wire_PartnerFeeConfig> wireObj) {
+    _api_fill_to_wire_partner_fee_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_pay_offer_request(
+      PayOfferRequest apiObj, ffi.Pointer<wire_PayOfferRequest> wireObj) {
+    _api_fill_to_wire_pay_offer_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_pay_onchain_address_request(
+      PayOnchainAddressRequest apiObj, ffi.Pointer<wire_PayOnchainAddressRequest> wireObj) {
+    _api_fill_to_wire_pay_onchain_address_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_pay_onchain_request(
       PayOnchainRequest apiObj, ffi.Pointer<wire_PayOnchainRequest> wireObj) {
     _api_fill_to_wire_pay_onchain_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_payment_request_bundle(
+      PaymentRequestBundle apiObj, ffi.Pointer<wire_PaymentRequestBundle> wireObj) {
+    _api_fill_to_wire_payment_request_bundle(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_prepare_onchain_payment_request(
       PrepareOnchainPaymentRequest apiObj, ffi.Pointer<wire_PrepareOnchainPaymentRequest> wireObj) {
     _api_fill_to_wire_prepare_onchain_payment_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_prepare_receive_payment_request(
+      PrepareReceivePaymentRequest apiObj, ffi.Pointer<wire_PrepareReceivePaymentRequest> wireObj) {
+    _api_fill_to_wire_prepare_receive_payment_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_prepare_redeem_onchain_funds_request(
       PrepareRedeemOnchainFundsRequest apiObj, ffi.Pointer<wire_PrepareRedeemOnchainFundsRequest> wireObj) {
     _api_fill_to_wire_prepare_redeem_onchain_funds_request(apiObj, wireObj.ref);
@@ -4679,6 +11084,30 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_prepare_refund_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_prepare_send_payment_request(
+      PrepareSendPaymentRequest apiObj, ffi.Pointer<wire_PrepareSendPaymentRequest> wireObj) {
+    _api_fill_to_wire_prepare_send_payment_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_probe_payment_request(
+      ProbePaymentRequest apiObj, ffi.Pointer<wire_ProbePaymentRequest> wireObj) {
+    _api_fill_to_wire_probe_payment_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_prove_address_ownership_request(
+      ProveAddressOwnershipRequest apiObj, ffi.Pointer<wire_ProveAddressOwnershipRequest> wireObj) {
+    _api_fill_to_wire_prove_address_ownership_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_proxy_config(ProxyConfig apiObj, ffi.Pointer<wire_ProxyConfig> wireObj) {
+    _api_fill_to_wire_proxy_config(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_queue_payment_request(
+      QueuePaymentRequest apiObj, ffi.Pointer<wire_QueuePaymentRequest> wireObj) {
+    _api_fill_to_wire_queue_payment_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_receive_onchain_request(
       ReceiveOnchainRequest apiObj, ffi.Pointer<wire_ReceiveOnchainRequest> wireObj) {
     _api_fill_to_wire_receive_onchain_request(apiObj, wireObj.ref);
@@ -4689,6 +11118,16 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_receive_payment_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_receive_unified_request(
+      ReceiveUnifiedRequest apiObj, ffi.Pointer<wire_ReceiveUnifiedRequest> wireObj) {
+    _api_fill_to_wire_receive_unified_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_redeem_lnurl_withdraw_voucher_request(
+      RedeemLnurlWithdrawVoucherRequest apiObj, ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest> wireObj) {
+    _api_fill_to_wire_redeem_lnurl_withdraw_voucher_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_redeem_onchain_funds_request(
       RedeemOnchainFundsRequest apiObj, ffi.Pointer<wire_RedeemOnchainFundsRequest> wireObj) {
     _api_fill_to_wire_redeem_onchain_funds_request(apiObj, wireObj.ref);
@@ -4714,11 +11153,20 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_reverse_swap_fees_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_sat(Sat apiObj, ffi.Pointer<wire_Sat> wireObj) {
+    _api_fill_to_wire_sat(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_send_payment_request(
       SendPaymentRequest apiObj, ffi.Pointer<wire_SendPaymentRequest> wireObj) {
     _api_fill_to_wire_send_payment_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_send_payments_request(
+      SendPaymentsRequest apiObj, ffi.Pointer<wire_SendPaymentsRequest> wireObj) {
+    _api_fill_to_wire_send_payments_request(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_send_spontaneous_payment_request(
       SendSpontaneousPaymentRequest apiObj, ffi.Pointer<wire_SendSpontaneousPaymentRequest> wireObj) {
     _api_fill_to_wire_send_spontaneous_payment_request(apiObj, wireObj.ref);
@@ -4729,34 +11177,105 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     _api_fill_to_wire_sign_message_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_spend_policy(SpendPolicy apiObj, ffi.Pointer<wire_SpendPolicy> wireObj) {
+    _api_fill_to_wire_spend_policy(apiObj, wireObj.ref);
+  }
+
   void _api_fill_to_wire_box_autoadd_static_backup_request(
       StaticBackupRequest apiObj, ffi.Pointer<wire_StaticBackupRequest> wireObj) {
     _api_fill_to_wire_static_backup_request(apiObj, wireObj.ref);
   }
 
+  void _api_fill_to_wire_box_autoadd_static_lnurl_pay_callback_request(
+      StaticLnurlPayCallbackRequest apiObj, ffi.Pointer<wire_StaticLnurlPayCallbackRequest> wireObj) {
+    _api_fill_to_wire_static_lnurl_pay_callback_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_user_settings(
+      UserSettings apiObj, ffi.Pointer<wire_UserSettings> wireObj) {
+    _api_fill_to_wire_user_settings(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_validate_payability_request(
+      ValidatePayabilityRequest apiObj, ffi.Pointer<wire_ValidatePayabilityRequest> wireObj) {
+    _api_fill_to_wire_validate_payability_request(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_bump_fee_request(BumpFeeRequest apiObj, wire_BumpFeeRequest wireObj) {
+    wireObj.txid = api2wire_String(apiObj.txid);
+    wireObj.sat_per_vbyte = api2wire_u32(apiObj.satPerVbyte);
+  }
+
   void _api_fill_to_wire_buy_bitcoin_request(BuyBitcoinRequest apiObj, wire_BuyBitcoinRequest wireObj) {
     wireObj.provider = api2wire_buy_bitcoin_provider(apiObj.provider);
     wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
     wireObj.redirect_url = api2wire_opt_String(apiObj.redirectUrl);
   }
 
+  void _api_fill_to_wire_chain_service_config(ChainServiceConfig apiObj, wire_ChainServiceConfig wireObj) {
+    if (apiObj is ChainServiceConfig_Esplora) {
+      var pre_url = api2wire_String(apiObj.url);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_ChainServiceConfig_Esplora();
+      wireObj.kind.ref.Esplora.ref.url = pre_url;
+      return;
+    }
+    if (apiObj is ChainServiceConfig_Electrum) {
+      var pre_url = api2wire_String(apiObj.url);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_ChainServiceConfig_Electrum();
+      wireObj.kind.ref.Electrum.ref.url = pre_url;
+      return;
+    }
+  }
+
   void _api_fill_to_wire_check_message_request(CheckMessageRequest apiObj, wire_CheckMessageRequest wireObj) {
     wireObj.message = api2wire_String(apiObj.message);
     wireObj.pubkey = api2wire_String(apiObj.pubkey);
     wireObj.signature = api2wire_String(apiObj.signature);
   }
 
+  void _api_fill_to_wire_close_channel_request(CloseChannelRequest apiObj, wire_CloseChannelRequest wireObj) {
+    wireObj.channel_id = api2wire_String(apiObj.channelId);
+    wireObj.force = api2wire_bool(apiObj.force);
+  }
+
   void _api_fill_to_wire_config(Config apiObj, wire_Config wireObj) {
     wireObj.breezserver = api2wire_String(apiObj.breezserver);
     wireObj.chainnotifier_url = api2wire_String(apiObj.chainnotifierUrl);
+    wireObj.lnurl_server_url = api2wire_String(apiObj.lnurlServerUrl);
     wireObj.mempoolspace_url = api2wire_opt_String(apiObj.mempoolspaceUrl);
+    wireObj.chain_service = api2wire_opt_box_autoadd_chain_service_config(apiObj.chainService);
+    wireObj.backup_transport = api2wire_opt_box_autoadd_backup_transport_config(apiObj.backupTransport);
+    wireObj.proxy = api2wire_opt_box_autoadd_proxy_config(apiObj.proxy);
     wireObj.working_dir = api2wire_String(apiObj.workingDir);
+    wireObj.db_dir = api2wire_opt_String(apiObj.dbDir);
+    wireObj.credentials_dir = api2wire_opt_String(apiObj.credentialsDir);
     wireObj.network = api2wire_network(apiObj.network);
     wireObj.payment_timeout_sec = api2wire_u32(apiObj.paymentTimeoutSec);
+    wireObj.max_concurrent_payments = api2wire_u32(apiObj.maxConcurrentPayments);
     wireObj.default_lsp_id = api2wire_opt_String(apiObj.defaultLspId);
+    wireObj.lsp_policy = api2wire_opt_box_autoadd_lsp_policy(apiObj.lspPolicy);
     wireObj.api_key = api2wire_opt_String(apiObj.apiKey);
     wireObj.maxfee_percent = api2wire_f64(apiObj.maxfeePercent);
     wireObj.exemptfee_msat = api2wire_u64(apiObj.exemptfeeMsat);
+    wireObj.fiat_currency = api2wire_opt_String(apiObj.fiatCurrency);
+    wireObj.low_outbound_liquidity_threshold_msat =
+        api2wire_opt_box_autoadd_u64(apiObj.lowOutboundLiquidityThresholdMsat);
+    wireObj.low_inbound_liquidity_threshold_msat =
+        api2wire_opt_box_autoadd_u64(apiObj.lowInboundLiquidityThresholdMsat);
+    wireObj.use_trampoline = api2wire_bool(apiObj.useTrampoline);
+    wireObj.partner_fee = api2wire_opt_box_autoadd_partner_fee_config(apiObj.partnerFee);
+    wireObj.swap_auto_refund = api2wire_opt_box_autoadd_auto_refund_config(apiObj.swapAutoRefund);
+    wireObj.memo_privacy = api2wire_memo_privacy(apiObj.memoPrivacy);
+    wireObj.channel_hygiene_window_secs = api2wire_opt_box_autoadd_u64(apiObj.channelHygieneWindowSecs);
+    wireObj.log_filter = api2wire_opt_String(apiObj.logFilter);
+    wireObj.denylist_url = api2wire_opt_String(apiObj.denylistUrl);
+    wireObj.transak_api_key = api2wire_opt_String(apiObj.transakApiKey);
+    wireObj.spend_policy = api2wire_opt_box_autoadd_spend_policy(apiObj.spendPolicy);
+    wireObj.htlc_exposure_limits = api2wire_opt_box_autoadd_htlc_exposure_limits(apiObj.htlcExposureLimits);
+    wireObj.service_status_check_interval_secs =
+        api2wire_opt_box_autoadd_u64(apiObj.serviceStatusCheckIntervalSecs);
     _api_fill_to_wire_node_config(apiObj.nodeConfig, wireObj.node_config);
   }
 
@@ -4765,10 +11284,81 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.close_to_address = api2wire_opt_String(apiObj.closeToAddress);
   }
 
+  void _api_fill_to_wire_connect_peer_request(ConnectPeerRequest apiObj, wire_ConnectPeerRequest wireObj) {
+    wireObj.node_uri = api2wire_String(apiObj.nodeUri);
+  }
+
   void _api_fill_to_wire_connect_request(ConnectRequest apiObj, wire_ConnectRequest wireObj) {
     _api_fill_to_wire_config(apiObj.config, wireObj.config);
     wireObj.seed = api2wire_uint_8_list(apiObj.seed);
     wireObj.restore_only = api2wire_opt_box_autoadd_bool(apiObj.restoreOnly);
+    wireObj.register_if_missing = api2wire_opt_box_autoadd_bool(apiObj.registerIfMissing);
+  }
+
+  void _api_fill_to_wire_create_lnurl_withdraw_voucher_request(
+      CreateLnurlWithdrawVoucherRequest apiObj, wire_CreateLnurlWithdrawVoucherRequest wireObj) {
+    _api_fill_to_wire_milli_sat(apiObj.amountMsat, wireObj.amount_msat);
+    wireObj.uses = api2wire_u32(apiObj.uses);
+    wireObj.expiry_secs = api2wire_u32(apiObj.expirySecs);
+    wireObj.description = api2wire_opt_String(apiObj.description);
+  }
+
+  void _api_fill_to_wire_create_static_lnurl_pay_request(
+      CreateStaticLnurlPayRequest apiObj, wire_CreateStaticLnurlPayRequest wireObj) {
+    wireObj.url = api2wire_String(apiObj.url);
+    wireObj.callback_url = api2wire_String(apiObj.callbackUrl);
+    wireObj.description = api2wire_String(apiObj.description);
+    _api_fill_to_wire_milli_sat(apiObj.minSendableMsat, wireObj.min_sendable_msat);
+    _api_fill_to_wire_milli_sat(apiObj.maxSendableMsat, wireObj.max_sendable_msat);
+    wireObj.comment_allowed = api2wire_opt_box_autoadd_u16(apiObj.commentAllowed);
+  }
+
+  void _api_fill_to_wire_decrypt_recovery_bundle_request(
+      DecryptRecoveryBundleRequest apiObj, wire_DecryptRecoveryBundleRequest wireObj) {
+    wireObj.bundle = api2wire_uint_8_list(apiObj.bundle);
+    wireObj.private_key = api2wire_uint_8_list(apiObj.privateKey);
+  }
+
+  void _api_fill_to_wire_derive_encryption_key_request(
+      DeriveEncryptionKeyRequest apiObj, wire_DeriveEncryptionKeyRequest wireObj) {
+    wireObj.purpose = api2wire_String(apiObj.purpose);
+    wireObj.index = api2wire_u32(apiObj.index);
+  }
+
+  void _api_fill_to_wire_encrypted_payment_request_data(
+      EncryptedPaymentRequestData apiObj, wire_EncryptedPaymentRequestData wireObj) {
+    wireObj.blob = api2wire_String(apiObj.blob);
+  }
+
+  void _api_fill_to_wire_export_payments_request(
+      ExportPaymentsRequest apiObj, wire_ExportPaymentsRequest wireObj) {
+    wireObj.format = api2wire_export_format(apiObj.format);
+    wireObj.from_timestamp = api2wire_opt_box_autoadd_i64(apiObj.fromTimestamp);
+    wireObj.to_timestamp = api2wire_opt_box_autoadd_i64(apiObj.toTimestamp);
+    wireObj.include_fiat = api2wire_opt_String(apiObj.includeFiat);
+  }
+
+  void _api_fill_to_wire_export_recovery_bundle_request(
+      ExportRecoveryBundleRequest apiObj, wire_ExportRecoveryBundleRequest wireObj) {
+    wireObj.recipient_pubkey = api2wire_String(apiObj.recipientPubkey);
+    wireObj.instructions = api2wire_opt_String(apiObj.instructions);
+    wireObj.file_path = api2wire_opt_String(apiObj.filePath);
+  }
+
+  void _api_fill_to_wire_export_swap_refund_keys_request(
+      ExportSwapRefundKeysRequest apiObj, wire_ExportSwapRefundKeysRequest wireObj) {
+    wireObj.include_gap_keys = api2wire_opt_box_autoadd_bool(apiObj.includeGapKeys);
+  }
+
+  void _api_fill_to_wire_fetch_buy_bitcoin_quote_request(
+      FetchBuyBitcoinQuoteRequest apiObj, wire_FetchBuyBitcoinQuoteRequest wireObj) {
+    wireObj.provider = api2wire_buy_bitcoin_provider(apiObj.provider);
+    _api_fill_to_wire_fiat_amount(apiObj.fiatAmount, wireObj.fiat_amount);
+  }
+
+  void _api_fill_to_wire_fiat_amount(FiatAmount apiObj, wire_FiatAmount wireObj) {
+    wireObj.currency = api2wire_String(apiObj.currency);
+    wireObj.amount = api2wire_f64(apiObj.amount);
   }
 
   void _api_fill_to_wire_greenlight_credentials(
@@ -4783,6 +11373,24 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.invite_code = api2wire_opt_String(apiObj.inviteCode);
   }
 
+  void _api_fill_to_wire_htlc_exposure_limits(HtlcExposureLimits apiObj, wire_HtlcExposureLimits wireObj) {
+    wireObj.max_inflight_msat = api2wire_opt_box_autoadd_u64(apiObj.maxInflightMsat);
+    wireObj.max_htlcs_per_payment = api2wire_opt_box_autoadd_u32(apiObj.maxHtlcsPerPayment);
+  }
+
+  void _api_fill_to_wire_import_payments_request(
+      ImportPaymentsRequest apiObj, wire_ImportPaymentsRequest wireObj) {
+    wireObj.format = api2wire_export_format(apiObj.format);
+    wireObj.data = api2wire_String(apiObj.data);
+  }
+
+  void _api_fill_to_wire_list_payment_groups_request(
+      ListPaymentGroupsRequest apiObj, wire_ListPaymentGroupsRequest wireObj) {
+    wireObj.group_by = api2wire_payment_group_by(apiObj.groupBy);
+    _api_fill_to_wire_list_payments_request(apiObj.payments, wireObj.payments);
+    wireObj.limit = api2wire_opt_box_autoadd_u32(apiObj.limit);
+  }
+
   void _api_fill_to_wire_list_payments_request(ListPaymentsRequest apiObj, wire_ListPaymentsRequest wireObj) {
     wireObj.filters = api2wire_opt_list_payment_type_filter(apiObj.filters);
     wireObj.metadata_filters = api2wire_opt_list_metadata_filter(apiObj.metadataFilters);
@@ -4791,6 +11399,8 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.include_failures = api2wire_opt_box_autoadd_bool(apiObj.includeFailures);
     wireObj.offset = api2wire_opt_box_autoadd_u32(apiObj.offset);
     wireObj.limit = api2wire_opt_box_autoadd_u32(apiObj.limit);
+    wireObj.include_tlv_hex = api2wire_opt_box_autoadd_bool(apiObj.includeTlvHex);
+    wireObj.sort_order = api2wire_opt_box_autoadd_sort_order(apiObj.sortOrder);
   }
 
   void _api_fill_to_wire_list_swaps_request(ListSwapsRequest apiObj, wire_ListSwapsRequest wireObj) {
@@ -4801,6 +11411,19 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.limit = api2wire_opt_box_autoadd_u32(apiObj.limit);
   }
 
+  void _api_fill_to_wire_ln_url_auth_domain(LnUrlAuthDomain apiObj, wire_LnUrlAuthDomain wireObj) {
+    wireObj.domain = api2wire_String(apiObj.domain);
+    wireObj.linking_key = api2wire_String(apiObj.linkingKey);
+    wireObj.first_authenticated_at = api2wire_i64(apiObj.firstAuthenticatedAt);
+    wireObj.last_authenticated_at = api2wire_i64(apiObj.lastAuthenticatedAt);
+    wireObj.auth_count = api2wire_u32(apiObj.authCount);
+  }
+
+  void _api_fill_to_wire_ln_url_auth_export(LnUrlAuthExport apiObj, wire_LnUrlAuthExport wireObj) {
+    wireObj.hashing_key = api2wire_String(apiObj.hashingKey);
+    wireObj.domains = api2wire_list_ln_url_auth_domain(apiObj.domains);
+  }
+
   void _api_fill_to_wire_ln_url_auth_request_data(
       LnUrlAuthRequestData apiObj, wire_LnUrlAuthRequestData wireObj) {
     wireObj.k1 = api2wire_String(apiObj.k1);
@@ -4816,6 +11439,9 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.comment = api2wire_opt_String(apiObj.comment);
     wireObj.payment_label = api2wire_opt_String(apiObj.paymentLabel);
     wireObj.validate_success_action_url = api2wire_opt_box_autoadd_bool(apiObj.validateSuccessActionUrl);
+    wireObj.pending_timeout_sec = api2wire_opt_box_autoadd_u32(apiObj.pendingTimeoutSec);
+    wireObj.cancel_token = api2wire_opt_String(apiObj.cancelToken);
+    wireObj.skip_denylist = api2wire_opt_box_autoadd_bool(apiObj.skipDenylist);
   }
 
   void _api_fill_to_wire_ln_url_pay_request_data(
@@ -4847,9 +11473,37 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.max_withdrawable = api2wire_u64(apiObj.maxWithdrawable);
   }
 
+  void _api_fill_to_wire_lsp_policy(LspPolicy apiObj, wire_LspPolicy wireObj) {
+    if (apiObj is LspPolicy_LowestOpeningFee) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is LspPolicy_LowestProportionalFee) {
+      wireObj.tag = 1;
+      return;
+    }
+    if (apiObj is LspPolicy_Pinned) {
+      var pre_lsp_id = api2wire_String(apiObj.lspId);
+      wireObj.tag = 2;
+      wireObj.kind = inner.inflate_LspPolicy_Pinned();
+      wireObj.kind.ref.Pinned.ref.lsp_id = pre_lsp_id;
+      return;
+    }
+  }
+
   void _api_fill_to_wire_metadata_filter(MetadataFilter apiObj, wire_MetadataFilter wireObj) {
     wireObj.json_path = api2wire_String(apiObj.jsonPath);
-    wireObj.json_value = api2wire_String(apiObj.jsonValue);
+    wireObj.json_value = api2wire_opt_String(apiObj.jsonValue);
+  }
+
+  void _api_fill_to_wire_milli_sat(MilliSat apiObj, wire_MilliSat wireObj) {
+    wireObj.field0 = api2wire_u64(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_mock_node_config(MockNodeConfig apiObj, wire_MockNodeConfig wireObj) {
+    wireObj.channels_balance_msat = api2wire_u64(apiObj.channelsBalanceMsat);
+    wireObj.onchain_balance_msat = api2wire_u64(apiObj.onchainBalanceMsat);
+    wireObj.inbound_liquidity_msat = api2wire_u64(apiObj.inboundLiquidityMsat);
   }
 
   void _api_fill_to_wire_node_config(NodeConfig apiObj, wire_NodeConfig wireObj) {
@@ -4860,14 +11514,33 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
       wireObj.kind.ref.Greenlight.ref.config = pre_config;
       return;
     }
+    if (apiObj is NodeConfig_Mock) {
+      var pre_config = api2wire_box_autoadd_mock_node_config(apiObj.config);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_NodeConfig_Mock();
+      wireObj.kind.ref.Mock.ref.config = pre_config;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_node_migration_request(
+      NodeMigrationRequest apiObj, wire_NodeMigrationRequest wireObj) {
+    wireObj.close_channels = api2wire_bool(apiObj.closeChannels);
   }
 
   void _api_fill_to_wire_open_channel_fee_request(
       OpenChannelFeeRequest apiObj, wire_OpenChannelFeeRequest wireObj) {
-    wireObj.amount_msat = api2wire_opt_box_autoadd_u64(apiObj.amountMsat);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
     wireObj.expiry = api2wire_opt_box_autoadd_u32(apiObj.expiry);
   }
 
+  void _api_fill_to_wire_open_channel_request(OpenChannelRequest apiObj, wire_OpenChannelRequest wireObj) {
+    wireObj.node_id = api2wire_String(apiObj.nodeId);
+    _api_fill_to_wire_sat(apiObj.amountSat, wireObj.amount_sat);
+    wireObj.announce = api2wire_bool(apiObj.announce);
+    wireObj.sat_per_vbyte = api2wire_opt_box_autoadd_u32(apiObj.satPerVbyte);
+  }
+
   void _api_fill_to_wire_opening_fee_params(OpeningFeeParams apiObj, wire_OpeningFeeParams wireObj) {
     wireObj.min_msat = api2wire_u64(apiObj.minMsat);
     wireObj.proportional = api2wire_u32(apiObj.proportional);
@@ -4877,14 +11550,60 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.promise = api2wire_String(apiObj.promise);
   }
 
+  void _api_fill_to_wire_partner_fee_config(PartnerFeeConfig apiObj, wire_PartnerFeeConfig wireObj) {
+    wireObj.fee_bps = api2wire_u32(apiObj.feeBps);
+    _api_fill_to_wire_partner_fee_destination(apiObj.destination, wireObj.destination);
+  }
+
+  void _api_fill_to_wire_partner_fee_destination(
+      PartnerFeeDestination apiObj, wire_PartnerFeeDestination wireObj) {
+    if (apiObj is PartnerFeeDestination_NodeId) {
+      var pre_node_id = api2wire_String(apiObj.nodeId);
+      wireObj.tag = 0;
+      wireObj.kind = inner.inflate_PartnerFeeDestination_NodeId();
+      wireObj.kind.ref.NodeId.ref.node_id = pre_node_id;
+      return;
+    }
+    if (apiObj is PartnerFeeDestination_LightningAddress) {
+      var pre_address = api2wire_String(apiObj.address);
+      wireObj.tag = 1;
+      wireObj.kind = inner.inflate_PartnerFeeDestination_LightningAddress();
+      wireObj.kind.ref.LightningAddress.ref.address = pre_address;
+      return;
+    }
+  }
+
+  void _api_fill_to_wire_pay_offer_request(PayOfferRequest apiObj, wire_PayOfferRequest wireObj) {
+    wireObj.offer = api2wire_String(apiObj.offer);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
+    wireObj.payer_note = api2wire_opt_String(apiObj.payerNote);
+    wireObj.label = api2wire_opt_String(apiObj.label);
+  }
+
+  void _api_fill_to_wire_pay_onchain_address_request(
+      PayOnchainAddressRequest apiObj, wire_PayOnchainAddressRequest wireObj) {
+    wireObj.address = api2wire_String(apiObj.address);
+    wireObj.amount_sat = api2wire_opt_box_autoadd_sat(apiObj.amountSat);
+    wireObj.drain = api2wire_bool(apiObj.drain);
+    wireObj.fee_preference = api2wire_opt_box_autoadd_feerate_preset(apiObj.feePreference);
+  }
+
   void _api_fill_to_wire_pay_onchain_request(PayOnchainRequest apiObj, wire_PayOnchainRequest wireObj) {
     wireObj.recipient_address = api2wire_String(apiObj.recipientAddress);
     _api_fill_to_wire_prepare_onchain_payment_response(apiObj.prepareRes, wireObj.prepare_res);
+    wireObj.cancel_token = api2wire_opt_String(apiObj.cancelToken);
+  }
+
+  void _api_fill_to_wire_payment_request_bundle(
+      PaymentRequestBundle apiObj, wire_PaymentRequestBundle wireObj) {
+    wireObj.invoice = api2wire_String(apiObj.invoice);
+    wireObj.fallback = api2wire_opt_String(apiObj.fallback);
+    wireObj.metadata = api2wire_opt_String(apiObj.metadata);
   }
 
   void _api_fill_to_wire_prepare_onchain_payment_request(
       PrepareOnchainPaymentRequest apiObj, wire_PrepareOnchainPaymentRequest wireObj) {
-    wireObj.amount_sat = api2wire_u64(apiObj.amountSat);
+    _api_fill_to_wire_sat(apiObj.amountSat, wireObj.amount_sat);
     wireObj.amount_type = api2wire_swap_amount_type(apiObj.amountType);
     wireObj.claim_tx_feerate = api2wire_u32(apiObj.claimTxFeerate);
   }
@@ -4895,15 +11614,23 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.fees_percentage = api2wire_f64(apiObj.feesPercentage);
     wireObj.fees_lockup = api2wire_u64(apiObj.feesLockup);
     wireObj.fees_claim = api2wire_u64(apiObj.feesClaim);
-    wireObj.sender_amount_sat = api2wire_u64(apiObj.senderAmountSat);
-    wireObj.recipient_amount_sat = api2wire_u64(apiObj.recipientAmountSat);
+    _api_fill_to_wire_sat(apiObj.senderAmountSat, wireObj.sender_amount_sat);
+    _api_fill_to_wire_sat(apiObj.recipientAmountSat, wireObj.recipient_amount_sat);
     wireObj.total_fees = api2wire_u64(apiObj.totalFees);
   }
 
+  void _api_fill_to_wire_prepare_receive_payment_request(
+      PrepareReceivePaymentRequest apiObj, wire_PrepareReceivePaymentRequest wireObj) {
+    _api_fill_to_wire_milli_sat(apiObj.amountMsat, wireObj.amount_msat);
+    wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
+    wireObj.expiry = api2wire_opt_box_autoadd_u32(apiObj.expiry);
+  }
+
   void _api_fill_to_wire_prepare_redeem_onchain_funds_request(
       PrepareRedeemOnchainFundsRequest apiObj, wire_PrepareRedeemOnchainFundsRequest wireObj) {
     wireObj.to_address = api2wire_String(apiObj.toAddress);
     wireObj.sat_per_vbyte = api2wire_u32(apiObj.satPerVbyte);
+    wireObj.amount_sat = api2wire_opt_box_autoadd_sat(apiObj.amountSat);
   }
 
   void _api_fill_to_wire_prepare_refund_request(
@@ -4912,6 +11639,35 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.to_address = api2wire_String(apiObj.toAddress);
     wireObj.sat_per_vbyte = api2wire_u32(apiObj.satPerVbyte);
     wireObj.unilateral = api2wire_opt_box_autoadd_bool(apiObj.unilateral);
+    wireObj.include_fee_previews = api2wire_opt_box_autoadd_bool(apiObj.includeFeePreviews);
+  }
+
+  void _api_fill_to_wire_prepare_send_payment_request(
+      PrepareSendPaymentRequest apiObj, wire_PrepareSendPaymentRequest wireObj) {
+    wireObj.bolt11 = api2wire_String(apiObj.bolt11);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
+  }
+
+  void _api_fill_to_wire_probe_payment_request(ProbePaymentRequest apiObj, wire_ProbePaymentRequest wireObj) {
+    wireObj.destination = api2wire_String(apiObj.destination);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
+  }
+
+  void _api_fill_to_wire_prove_address_ownership_request(
+      ProveAddressOwnershipRequest apiObj, wire_ProveAddressOwnershipRequest wireObj) {
+    wireObj.address = api2wire_String(apiObj.address);
+    wireObj.message = api2wire_String(apiObj.message);
+  }
+
+  void _api_fill_to_wire_proxy_config(ProxyConfig apiObj, wire_ProxyConfig wireObj) {
+    wireObj.address = api2wire_String(apiObj.address);
+    wireObj.username = api2wire_opt_String(apiObj.username);
+    wireObj.password = api2wire_opt_String(apiObj.password);
+  }
+
+  void _api_fill_to_wire_queue_payment_request(QueuePaymentRequest apiObj, wire_QueuePaymentRequest wireObj) {
+    _api_fill_to_wire_send_payment_request(apiObj.req, wireObj.req);
+    wireObj.expiry_secs = api2wire_opt_box_autoadd_u64(apiObj.expirySecs);
   }
 
   void _api_fill_to_wire_receive_onchain_request(
@@ -4921,19 +11677,36 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
 
   void _api_fill_to_wire_receive_payment_request(
       ReceivePaymentRequest apiObj, wire_ReceivePaymentRequest wireObj) {
-    wireObj.amount_msat = api2wire_u64(apiObj.amountMsat);
+    _api_fill_to_wire_milli_sat(apiObj.amountMsat, wireObj.amount_msat);
     wireObj.description = api2wire_String(apiObj.description);
     wireObj.preimage = api2wire_opt_uint_8_list(apiObj.preimage);
     wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
     wireObj.use_description_hash = api2wire_opt_box_autoadd_bool(apiObj.useDescriptionHash);
     wireObj.expiry = api2wire_opt_box_autoadd_u32(apiObj.expiry);
     wireObj.cltv = api2wire_opt_box_autoadd_u32(apiObj.cltv);
+    wireObj.include_fallback_address = api2wire_opt_box_autoadd_bool(apiObj.includeFallbackAddress);
+    wireObj.max_route_hints = api2wire_opt_box_autoadd_u32(apiObj.maxRouteHints);
+    wireObj.fiat_amount = api2wire_opt_box_autoadd_fiat_amount(apiObj.fiatAmount);
+  }
+
+  void _api_fill_to_wire_receive_unified_request(
+      ReceiveUnifiedRequest apiObj, wire_ReceiveUnifiedRequest wireObj) {
+    _api_fill_to_wire_sat(apiObj.amountSat, wireObj.amount_sat);
+    wireObj.description = api2wire_String(apiObj.description);
+    wireObj.opening_fee_params = api2wire_opt_box_autoadd_opening_fee_params(apiObj.openingFeeParams);
+  }
+
+  void _api_fill_to_wire_redeem_lnurl_withdraw_voucher_request(
+      RedeemLnurlWithdrawVoucherRequest apiObj, wire_RedeemLnurlWithdrawVoucherRequest wireObj) {
+    wireObj.id = api2wire_String(apiObj.id);
+    wireObj.bolt11 = api2wire_String(apiObj.bolt11);
   }
 
   void _api_fill_to_wire_redeem_onchain_funds_request(
       RedeemOnchainFundsRequest apiObj, wire_RedeemOnchainFundsRequest wireObj) {
     wireObj.to_address = api2wire_String(apiObj.toAddress);
     wireObj.sat_per_vbyte = api2wire_u32(apiObj.satPerVbyte);
+    wireObj.amount_sat = api2wire_opt_box_autoadd_sat(apiObj.amountSat);
   }
 
   void _api_fill_to_wire_refund_request(RefundRequest apiObj, wire_RefundRequest wireObj) {
@@ -4961,21 +11734,50 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
 
   void _api_fill_to_wire_reverse_swap_fees_request(
       ReverseSwapFeesRequest apiObj, wire_ReverseSwapFeesRequest wireObj) {
-    wireObj.send_amount_sat = api2wire_opt_box_autoadd_u64(apiObj.sendAmountSat);
+    wireObj.send_amount_sat = api2wire_opt_box_autoadd_sat(apiObj.sendAmountSat);
     wireObj.claim_tx_feerate = api2wire_opt_box_autoadd_u32(apiObj.claimTxFeerate);
   }
 
+  void _api_fill_to_wire_route_hint(RouteHint apiObj, wire_RouteHint wireObj) {
+    wireObj.hops = api2wire_list_route_hint_hop(apiObj.hops);
+  }
+
+  void _api_fill_to_wire_route_hint_hop(RouteHintHop apiObj, wire_RouteHintHop wireObj) {
+    wireObj.src_node_id = api2wire_String(apiObj.srcNodeId);
+    wireObj.short_channel_id = api2wire_String(apiObj.shortChannelId);
+    wireObj.fees_base_msat = api2wire_u32(apiObj.feesBaseMsat);
+    wireObj.fees_proportional_millionths = api2wire_u32(apiObj.feesProportionalMillionths);
+    wireObj.cltv_expiry_delta = api2wire_u64(apiObj.cltvExpiryDelta);
+    wireObj.htlc_minimum_msat = api2wire_opt_box_autoadd_u64(apiObj.htlcMinimumMsat);
+    wireObj.htlc_maximum_msat = api2wire_opt_box_autoadd_u64(apiObj.htlcMaximumMsat);
+  }
+
+  void _api_fill_to_wire_sat(Sat apiObj, wire_Sat wireObj) {
+    wireObj.field0 = api2wire_u64(apiObj.field0);
+  }
+
   void _api_fill_to_wire_send_payment_request(SendPaymentRequest apiObj, wire_SendPaymentRequest wireObj) {
     wireObj.bolt11 = api2wire_String(apiObj.bolt11);
-    wireObj.use_trampoline = api2wire_bool(apiObj.useTrampoline);
-    wireObj.amount_msat = api2wire_opt_box_autoadd_u64(apiObj.amountMsat);
+    wireObj.use_trampoline = api2wire_opt_box_autoadd_bool(apiObj.useTrampoline);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
     wireObj.label = api2wire_opt_String(apiObj.label);
+    wireObj.route_hint_index = api2wire_opt_box_autoadd_u32(apiObj.routeHintIndex);
+    wireObj.payment_timeout_sec = api2wire_opt_box_autoadd_u32(apiObj.paymentTimeoutSec);
+    wireObj.maxfee_percent = api2wire_opt_box_autoadd_f64(apiObj.maxfeePercent);
+    wireObj.exemptfee_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.exemptfeeMsat);
+    wireObj.cancel_token = api2wire_opt_String(apiObj.cancelToken);
+    wireObj.skip_denylist = api2wire_opt_box_autoadd_bool(apiObj.skipDenylist);
+  }
+
+  void _api_fill_to_wire_send_payments_request(SendPaymentsRequest apiObj, wire_SendPaymentsRequest wireObj) {
+    wireObj.payments = api2wire_list_send_payment_request(apiObj.payments);
+    wireObj.max_concurrency = api2wire_opt_box_autoadd_u32(apiObj.maxConcurrency);
   }
 
   void _api_fill_to_wire_send_spontaneous_payment_request(
       SendSpontaneousPaymentRequest apiObj, wire_SendSpontaneousPaymentRequest wireObj) {
     wireObj.node_id = api2wire_String(apiObj.nodeId);
-    wireObj.amount_msat = api2wire_u64(apiObj.amountMsat);
+    _api_fill_to_wire_milli_sat(apiObj.amountMsat, wireObj.amount_msat);
     wireObj.extra_tlvs = api2wire_opt_list_tlv_entry(apiObj.extraTlvs);
     wireObj.label = api2wire_opt_String(apiObj.label);
   }
@@ -4984,14 +11786,39 @@ class BreezSdkCorePlatform extends FlutterRustBridgeBase<BreezSdkCoreWire> {
     wireObj.message = api2wire_String(apiObj.message);
   }
 
+  void _api_fill_to_wire_spend_policy(SpendPolicy apiObj, wire_SpendPolicy wireObj) {
+    wireObj.daily_limit_msat = api2wire_opt_box_autoadd_u64(apiObj.dailyLimitMsat);
+    wireObj.per_payment_limit_msat = api2wire_opt_box_autoadd_u64(apiObj.perPaymentLimitMsat);
+    wireObj.allowed_destinations = api2wire_opt_StringList(apiObj.allowedDestinations);
+  }
+
   void _api_fill_to_wire_static_backup_request(StaticBackupRequest apiObj, wire_StaticBackupRequest wireObj) {
     wireObj.working_dir = api2wire_String(apiObj.workingDir);
   }
 
+  void _api_fill_to_wire_static_lnurl_pay_callback_request(
+      StaticLnurlPayCallbackRequest apiObj, wire_StaticLnurlPayCallbackRequest wireObj) {
+    _api_fill_to_wire_milli_sat(apiObj.amountMsat, wireObj.amount_msat);
+    wireObj.comment = api2wire_opt_String(apiObj.comment);
+  }
+
   void _api_fill_to_wire_tlv_entry(TlvEntry apiObj, wire_TlvEntry wireObj) {
     wireObj.field_number = api2wire_u64(apiObj.fieldNumber);
     wireObj.value = api2wire_uint_8_list(apiObj.value);
   }
+
+  void _api_fill_to_wire_user_settings(UserSettings apiObj, wire_UserSettings wireObj) {
+    wireObj.preferred_fiat_currency = api2wire_opt_String(apiObj.preferredFiatCurrency);
+    wireObj.denomination = api2wire_opt_box_autoadd_denomination(apiObj.denomination);
+    wireObj.default_feerate_preset = api2wire_opt_box_autoadd_feerate_preset(apiObj.defaultFeeratePreset);
+    wireObj.preferred_lsp_id = api2wire_opt_String(apiObj.preferredLspId);
+  }
+
+  void _api_fill_to_wire_validate_payability_request(
+      ValidatePayabilityRequest apiObj, wire_ValidatePayabilityRequest wireObj) {
+    wireObj.bolt11 = api2wire_String(apiObj.bolt11);
+    wireObj.amount_msat = api2wire_opt_box_autoadd_milli_sat(apiObj.amountMsat);
+  }
 }
 
 // ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
@@ -5094,6 +11921,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_connect =
       _wire_connectPtr.asFunction<void Function(int, ffi.Pointer<wire_ConnectRequest>)>();
 
+  void wire_connect_ephemeral(
+    int port_,
+    ffi.Pointer<wire_Config> config,
+  ) {
+    return _wire_connect_ephemeral(
+      port_,
+      config,
+    );
+  }
+
+  late final _wire_connect_ephemeralPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_Config>)>>(
+          'wire_connect_ephemeral');
+  late final _wire_connect_ephemeral =
+      _wire_connect_ephemeralPtr.asFunction<void Function(int, ffi.Pointer<wire_Config>)>();
+
   void wire_is_initialized(
     int port_,
   ) {
@@ -5117,6 +11960,41 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_syncPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_sync');
   late final _wire_sync = _wire_syncPtr.asFunction<void Function(int)>();
 
+  void wire_cancel_sync(
+    int port_,
+  ) {
+    return _wire_cancel_sync(
+      port_,
+    );
+  }
+
+  late final _wire_cancel_syncPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_cancel_sync');
+  late final _wire_cancel_sync = _wire_cancel_syncPtr.asFunction<void Function(int)>();
+
+  void wire_metrics(
+    int port_,
+  ) {
+    return _wire_metrics(
+      port_,
+    );
+  }
+
+  late final _wire_metricsPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_metrics');
+  late final _wire_metrics = _wire_metricsPtr.asFunction<void Function(int)>();
+
+  void wire_sync_partial(
+    int port_,
+  ) {
+    return _wire_sync_partial(
+      port_,
+    );
+  }
+
+  late final _wire_sync_partialPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_sync_partial');
+  late final _wire_sync_partial = _wire_sync_partialPtr.asFunction<void Function(int)>();
+
   void wire_node_credentials(
     int port_,
   ) {
@@ -5140,6 +12018,29 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_node_infoPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_node_info');
   late final _wire_node_info = _wire_node_infoPtr.asFunction<void Function(int)>();
 
+  void wire_balances(
+    int port_,
+  ) {
+    return _wire_balances(
+      port_,
+    );
+  }
+
+  late final _wire_balancesPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_balances');
+  late final _wire_balances = _wire_balancesPtr.asFunction<void Function(int)>();
+
+  void wire_get_balance_breakdown(
+    int port_,
+  ) {
+    return _wire_get_balance_breakdown(
+      port_,
+    );
+  }
+
+  late final _wire_get_balance_breakdownPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_get_balance_breakdown');
+  late final _wire_get_balance_breakdown = _wire_get_balance_breakdownPtr.asFunction<void Function(int)>();
+
   void wire_configure_node(
     int port_,
     ffi.Pointer<wire_ConfigureNodeRequest> req,
@@ -5168,6 +12069,70 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_disconnect');
   late final _wire_disconnect = _wire_disconnectPtr.asFunction<void Function(int)>();
 
+  void wire_derive_encryption_key(
+    int port_,
+    ffi.Pointer<wire_DeriveEncryptionKeyRequest> req,
+  ) {
+    return _wire_derive_encryption_key(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_derive_encryption_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DeriveEncryptionKeyRequest>)>>(
+          'wire_derive_encryption_key');
+  late final _wire_derive_encryption_key = _wire_derive_encryption_keyPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_DeriveEncryptionKeyRequest>)>();
+
+  void wire_prove_address_ownership(
+    int port_,
+    ffi.Pointer<wire_ProveAddressOwnershipRequest> req,
+  ) {
+    return _wire_prove_address_ownership(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_prove_address_ownershipPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ProveAddressOwnershipRequest>)>>(
+      'wire_prove_address_ownership');
+  late final _wire_prove_address_ownership = _wire_prove_address_ownershipPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ProveAddressOwnershipRequest>)>();
+
+  void wire_encrypt_payment_request(
+    int port_,
+    ffi.Pointer<wire_PaymentRequestBundle> bundle,
+  ) {
+    return _wire_encrypt_payment_request(
+      port_,
+      bundle,
+    );
+  }
+
+  late final _wire_encrypt_payment_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_PaymentRequestBundle>)>>(
+          'wire_encrypt_payment_request');
+  late final _wire_encrypt_payment_request = _wire_encrypt_payment_requestPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_PaymentRequestBundle>)>();
+
+  void wire_decrypt_payment_request(
+    int port_,
+    ffi.Pointer<wire_EncryptedPaymentRequestData> data,
+  ) {
+    return _wire_decrypt_payment_request(
+      port_,
+      data,
+    );
+  }
+
+  late final _wire_decrypt_payment_requestPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_EncryptedPaymentRequestData>)>>(
+      'wire_decrypt_payment_request');
+  late final _wire_decrypt_payment_request = _wire_decrypt_payment_requestPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_EncryptedPaymentRequestData>)>();
+
   void wire_sign_message(
     int port_,
     ffi.Pointer<wire_SignMessageRequest> req,
@@ -5237,6 +12202,62 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_default_config = _wire_default_configPtr
       .asFunction<void Function(int, int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_NodeConfig>)>();
 
+  void wire_sdk_info(
+    int port_,
+  ) {
+    return _wire_sdk_info(
+      port_,
+    );
+  }
+
+  late final _wire_sdk_infoPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_sdk_info');
+  late final _wire_sdk_info = _wire_sdk_infoPtr.asFunction<void Function(int)>();
+
+  void wire_decrypt_recovery_bundle(
+    int port_,
+    ffi.Pointer<wire_DecryptRecoveryBundleRequest> req,
+  ) {
+    return _wire_decrypt_recovery_bundle(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_decrypt_recovery_bundlePtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_DecryptRecoveryBundleRequest>)>>(
+      'wire_decrypt_recovery_bundle');
+  late final _wire_decrypt_recovery_bundle = _wire_decrypt_recovery_bundlePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_DecryptRecoveryBundleRequest>)>();
+
+  void wire_export_recovery_bundle(
+    int port_,
+    ffi.Pointer<wire_ExportRecoveryBundleRequest> req,
+  ) {
+    return _wire_export_recovery_bundle(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_export_recovery_bundlePtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExportRecoveryBundleRequest>)>>(
+      'wire_export_recovery_bundle');
+  late final _wire_export_recovery_bundle = _wire_export_recovery_bundlePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ExportRecoveryBundleRequest>)>();
+
+  void wire_stop_recovery_bundle_refresh(
+    int port_,
+  ) {
+    return _wire_stop_recovery_bundle_refresh(
+      port_,
+    );
+  }
+
+  late final _wire_stop_recovery_bundle_refreshPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_stop_recovery_bundle_refresh');
+  late final _wire_stop_recovery_bundle_refresh =
+      _wire_stop_recovery_bundle_refreshPtr.asFunction<void Function(int)>();
+
   void wire_static_backup(
     int port_,
     ffi.Pointer<wire_StaticBackupRequest> req,
@@ -5293,6 +12314,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_breez_log_stream');
   late final _wire_breez_log_stream = _wire_breez_log_streamPtr.asFunction<void Function(int)>();
 
+  void wire_set_log_filter(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> filter,
+  ) {
+    return _wire_set_log_filter(
+      port_,
+      filter,
+    );
+  }
+
+  late final _wire_set_log_filterPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_set_log_filter');
+  late final _wire_set_log_filter =
+      _wire_set_log_filterPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_list_lsps(
     int port_,
   ) {
@@ -5331,6 +12368,34 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_lsp_idPtr = _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_lsp_id');
   late final _wire_lsp_id = _wire_lsp_idPtr.asFunction<void Function(int)>();
 
+  void wire_user_settings(
+    int port_,
+  ) {
+    return _wire_user_settings(
+      port_,
+    );
+  }
+
+  late final _wire_user_settingsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_user_settings');
+  late final _wire_user_settings = _wire_user_settingsPtr.asFunction<void Function(int)>();
+
+  void wire_set_user_settings(
+    int port_,
+    ffi.Pointer<wire_UserSettings> settings,
+  ) {
+    return _wire_set_user_settings(
+      port_,
+      settings,
+    );
+  }
+
+  late final _wire_set_user_settingsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_UserSettings>)>>(
+          'wire_set_user_settings');
+  late final _wire_set_user_settings =
+      _wire_set_user_settingsPtr.asFunction<void Function(int, ffi.Pointer<wire_UserSettings>)>();
+
   void wire_fetch_lsp_info(
     int port_,
     ffi.Pointer<wire_uint_8_list> id,
@@ -5370,6 +12435,82 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_close_lsp_channels');
   late final _wire_close_lsp_channels = _wire_close_lsp_channelsPtr.asFunction<void Function(int)>();
 
+  void wire_list_channels(
+    int port_,
+  ) {
+    return _wire_list_channels(
+      port_,
+    );
+  }
+
+  late final _wire_list_channelsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_channels');
+  late final _wire_list_channels = _wire_list_channelsPtr.asFunction<void Function(int)>();
+
+  void wire_close_channel(
+    int port_,
+    ffi.Pointer<wire_CloseChannelRequest> req,
+  ) {
+    return _wire_close_channel(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_close_channelPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_CloseChannelRequest>)>>(
+          'wire_close_channel');
+  late final _wire_close_channel =
+      _wire_close_channelPtr.asFunction<void Function(int, ffi.Pointer<wire_CloseChannelRequest>)>();
+
+  void wire_connect_peer(
+    int port_,
+    ffi.Pointer<wire_ConnectPeerRequest> req,
+  ) {
+    return _wire_connect_peer(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_connect_peerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ConnectPeerRequest>)>>(
+          'wire_connect_peer');
+  late final _wire_connect_peer =
+      _wire_connect_peerPtr.asFunction<void Function(int, ffi.Pointer<wire_ConnectPeerRequest>)>();
+
+  void wire_open_channel(
+    int port_,
+    ffi.Pointer<wire_OpenChannelRequest> req,
+  ) {
+    return _wire_open_channel(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_open_channelPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OpenChannelRequest>)>>(
+          'wire_open_channel');
+  late final _wire_open_channel =
+      _wire_open_channelPtr.asFunction<void Function(int, ffi.Pointer<wire_OpenChannelRequest>)>();
+
+  void wire_export_node_migration(
+    int port_,
+    ffi.Pointer<wire_NodeMigrationRequest> req,
+  ) {
+    return _wire_export_node_migration(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_export_node_migrationPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_NodeMigrationRequest>)>>(
+          'wire_export_node_migration');
+  late final _wire_export_node_migration =
+      _wire_export_node_migrationPtr.asFunction<void Function(int, ffi.Pointer<wire_NodeMigrationRequest>)>();
+
   void wire_register_webhook(
     int port_,
     ffi.Pointer<wire_uint_8_list> webhook_url,
@@ -5402,6 +12543,113 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_unregister_webhook =
       _wire_unregister_webhookPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_register_lightning_address(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> username,
+  ) {
+    return _wire_register_lightning_address(
+      port_,
+      username,
+    );
+  }
+
+  late final _wire_register_lightning_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_register_lightning_address');
+  late final _wire_register_lightning_address =
+      _wire_register_lightning_addressPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_lnurl_pay_info(
+    int port_,
+  ) {
+    return _wire_lnurl_pay_info(
+      port_,
+    );
+  }
+
+  late final _wire_lnurl_pay_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_lnurl_pay_info');
+  late final _wire_lnurl_pay_info = _wire_lnurl_pay_infoPtr.asFunction<void Function(int)>();
+
+  void wire_create_static_lnurl_pay(
+    int port_,
+    ffi.Pointer<wire_CreateStaticLnurlPayRequest> req,
+  ) {
+    return _wire_create_static_lnurl_pay(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_create_static_lnurl_payPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_CreateStaticLnurlPayRequest>)>>(
+      'wire_create_static_lnurl_pay');
+  late final _wire_create_static_lnurl_pay = _wire_create_static_lnurl_payPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_CreateStaticLnurlPayRequest>)>();
+
+  void wire_static_lnurl_pay_callback(
+    int port_,
+    ffi.Pointer<wire_StaticLnurlPayCallbackRequest> req,
+  ) {
+    return _wire_static_lnurl_pay_callback(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_static_lnurl_pay_callbackPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StaticLnurlPayCallbackRequest>)>>(
+      'wire_static_lnurl_pay_callback');
+  late final _wire_static_lnurl_pay_callback = _wire_static_lnurl_pay_callbackPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_StaticLnurlPayCallbackRequest>)>();
+
+  void wire_create_lnurl_withdraw_voucher(
+    int port_,
+    ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest> req,
+  ) {
+    return _wire_create_lnurl_withdraw_voucher(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_create_lnurl_withdraw_voucherPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest>)>>(
+      'wire_create_lnurl_withdraw_voucher');
+  late final _wire_create_lnurl_withdraw_voucher = _wire_create_lnurl_withdraw_voucherPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest>)>();
+
+  void wire_redeem_lnurl_withdraw_voucher(
+    int port_,
+    ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest> req,
+  ) {
+    return _wire_redeem_lnurl_withdraw_voucher(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_redeem_lnurl_withdraw_voucherPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest>)>>(
+      'wire_redeem_lnurl_withdraw_voucher');
+  late final _wire_redeem_lnurl_withdraw_voucher = _wire_redeem_lnurl_withdraw_voucherPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest>)>();
+
+  void wire_list_lnurl_withdraw_vouchers(
+    int port_,
+  ) {
+    return _wire_list_lnurl_withdraw_vouchers(
+      port_,
+    );
+  }
+
+  late final _wire_list_lnurl_withdraw_vouchersPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_lnurl_withdraw_vouchers');
+  late final _wire_list_lnurl_withdraw_vouchers =
+      _wire_list_lnurl_withdraw_vouchersPtr.asFunction<void Function(int)>();
+
   void wire_backup(
     int port_,
   ) {
@@ -5425,6 +12673,44 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_backup_status');
   late final _wire_backup_status = _wire_backup_statusPtr.asFunction<void Function(int)>();
 
+  void wire_rotate_backup_key(
+    int port_,
+  ) {
+    return _wire_rotate_backup_key(
+      port_,
+    );
+  }
+
+  late final _wire_rotate_backup_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_rotate_backup_key');
+  late final _wire_rotate_backup_key = _wire_rotate_backup_keyPtr.asFunction<void Function(int)>();
+
+  void wire_list_backup_versions(
+    int port_,
+  ) {
+    return _wire_list_backup_versions(
+      port_,
+    );
+  }
+
+  late final _wire_list_backup_versionsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_backup_versions');
+  late final _wire_list_backup_versions = _wire_list_backup_versionsPtr.asFunction<void Function(int)>();
+
+  void wire_restore_backup(
+    int port_,
+    int version,
+  ) {
+    return _wire_restore_backup(
+      port_,
+      version,
+    );
+  }
+
+  late final _wire_restore_backupPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>('wire_restore_backup');
+  late final _wire_restore_backup = _wire_restore_backupPtr.asFunction<void Function(int, int)>();
+
   void wire_parse_invoice(
     int port_,
     ffi.Pointer<wire_uint_8_list> invoice,
@@ -5473,6 +12759,54 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_list_payments =
       _wire_list_paymentsPtr.asFunction<void Function(int, ffi.Pointer<wire_ListPaymentsRequest>)>();
 
+  void wire_list_payment_groups(
+    int port_,
+    ffi.Pointer<wire_ListPaymentGroupsRequest> req,
+  ) {
+    return _wire_list_payment_groups(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_list_payment_groupsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ListPaymentGroupsRequest>)>>(
+          'wire_list_payment_groups');
+  late final _wire_list_payment_groups = _wire_list_payment_groupsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ListPaymentGroupsRequest>)>();
+
+  void wire_export_payments(
+    int port_,
+    ffi.Pointer<wire_ExportPaymentsRequest> req,
+  ) {
+    return _wire_export_payments(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_export_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExportPaymentsRequest>)>>(
+          'wire_export_payments');
+  late final _wire_export_payments =
+      _wire_export_paymentsPtr.asFunction<void Function(int, ffi.Pointer<wire_ExportPaymentsRequest>)>();
+
+  void wire_import_payments(
+    int port_,
+    ffi.Pointer<wire_ImportPaymentsRequest> req,
+  ) {
+    return _wire_import_payments(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_import_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ImportPaymentsRequest>)>>(
+          'wire_import_payments');
+  late final _wire_import_payments =
+      _wire_import_paymentsPtr.asFunction<void Function(int, ffi.Pointer<wire_ImportPaymentsRequest>)>();
+
   void wire_payment_by_hash(
     int port_,
     ffi.Pointer<wire_uint_8_list> hash,
@@ -5489,6 +12823,18 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_payment_by_hash =
       _wire_payment_by_hashPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_list_channel_fees_paid(
+    int port_,
+  ) {
+    return _wire_list_channel_fees_paid(
+      port_,
+    );
+  }
+
+  late final _wire_list_channel_fees_paidPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_channel_fees_paid');
+  late final _wire_list_channel_fees_paid = _wire_list_channel_fees_paidPtr.asFunction<void Function(int)>();
+
   void wire_set_payment_metadata(
     int port_,
     ffi.Pointer<wire_uint_8_list> hash,
@@ -5524,6 +12870,97 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_send_payment =
       _wire_send_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_SendPaymentRequest>)>();
 
+  void wire_send_payments(
+    int port_,
+    ffi.Pointer<wire_SendPaymentsRequest> req,
+  ) {
+    return _wire_send_payments(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_send_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_SendPaymentsRequest>)>>(
+          'wire_send_payments');
+  late final _wire_send_payments =
+      _wire_send_paymentsPtr.asFunction<void Function(int, ffi.Pointer<wire_SendPaymentsRequest>)>();
+
+  void wire_verify_invoices(
+    int port_,
+    ffi.Pointer<wire_StringList> invoices,
+  ) {
+    return _wire_verify_invoices(
+      port_,
+      invoices,
+    );
+  }
+
+  late final _wire_verify_invoicesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_StringList>)>>(
+          'wire_verify_invoices');
+  late final _wire_verify_invoices =
+      _wire_verify_invoicesPtr.asFunction<void Function(int, ffi.Pointer<wire_StringList>)>();
+
+  void wire_queue_payment(
+    int port_,
+    ffi.Pointer<wire_QueuePaymentRequest> req,
+  ) {
+    return _wire_queue_payment(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_queue_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_QueuePaymentRequest>)>>(
+          'wire_queue_payment');
+  late final _wire_queue_payment =
+      _wire_queue_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_QueuePaymentRequest>)>();
+
+  void wire_list_pending_outbox(
+    int port_,
+  ) {
+    return _wire_list_pending_outbox(
+      port_,
+    );
+  }
+
+  late final _wire_list_pending_outboxPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_pending_outbox');
+  late final _wire_list_pending_outbox = _wire_list_pending_outboxPtr.asFunction<void Function(int)>();
+
+  void wire_cancel_outbox_payment(
+    int port_,
+    int id,
+  ) {
+    return _wire_cancel_outbox_payment(
+      port_,
+      id,
+    );
+  }
+
+  late final _wire_cancel_outbox_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_cancel_outbox_payment');
+  late final _wire_cancel_outbox_payment =
+      _wire_cancel_outbox_paymentPtr.asFunction<void Function(int, int)>();
+
+  void wire_prepare_send_payment(
+    int port_,
+    ffi.Pointer<wire_PrepareSendPaymentRequest> req,
+  ) {
+    return _wire_prepare_send_payment(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_prepare_send_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_PrepareSendPaymentRequest>)>>(
+          'wire_prepare_send_payment');
+  late final _wire_prepare_send_payment = _wire_prepare_send_paymentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_PrepareSendPaymentRequest>)>();
+
   void wire_send_spontaneous_payment(
     int port_,
     ffi.Pointer<wire_SendSpontaneousPaymentRequest> req,
@@ -5540,6 +12977,96 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_send_spontaneous_payment = _wire_send_spontaneous_paymentPtr
       .asFunction<void Function(int, ffi.Pointer<wire_SendSpontaneousPaymentRequest>)>();
 
+  void wire_cancel(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> cancel_token,
+  ) {
+    return _wire_cancel(
+      port_,
+      cancel_token,
+    );
+  }
+
+  late final _wire_cancelPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_cancel');
+  late final _wire_cancel = _wire_cancelPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_probe_payment(
+    int port_,
+    ffi.Pointer<wire_ProbePaymentRequest> req,
+  ) {
+    return _wire_probe_payment(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_probe_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ProbePaymentRequest>)>>(
+          'wire_probe_payment');
+  late final _wire_probe_payment =
+      _wire_probe_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_ProbePaymentRequest>)>();
+
+  void wire_validate_payability(
+    int port_,
+    ffi.Pointer<wire_ValidatePayabilityRequest> req,
+  ) {
+    return _wire_validate_payability(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_validate_payabilityPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ValidatePayabilityRequest>)>>(
+          'wire_validate_payability');
+  late final _wire_validate_payability = _wire_validate_payabilityPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ValidatePayabilityRequest>)>();
+
+  void wire_list_payment_probes(
+    int port_,
+  ) {
+    return _wire_list_payment_probes(
+      port_,
+    );
+  }
+
+  late final _wire_list_payment_probesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_payment_probes');
+  late final _wire_list_payment_probes = _wire_list_payment_probesPtr.asFunction<void Function(int)>();
+
+  void wire_pay_offer(
+    int port_,
+    ffi.Pointer<wire_PayOfferRequest> req,
+  ) {
+    return _wire_pay_offer(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_pay_offerPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_PayOfferRequest>)>>(
+          'wire_pay_offer');
+  late final _wire_pay_offer =
+      _wire_pay_offerPtr.asFunction<void Function(int, ffi.Pointer<wire_PayOfferRequest>)>();
+
+  void wire_prepare_receive_payment(
+    int port_,
+    ffi.Pointer<wire_PrepareReceivePaymentRequest> req,
+  ) {
+    return _wire_prepare_receive_payment(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_prepare_receive_paymentPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_PrepareReceivePaymentRequest>)>>(
+      'wire_prepare_receive_payment');
+  late final _wire_prepare_receive_payment = _wire_prepare_receive_paymentPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_PrepareReceivePaymentRequest>)>();
+
   void wire_receive_payment(
     int port_,
     ffi.Pointer<wire_ReceivePaymentRequest> req,
@@ -5556,6 +13083,38 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_receive_payment =
       _wire_receive_paymentPtr.asFunction<void Function(int, ffi.Pointer<wire_ReceivePaymentRequest>)>();
 
+  void wire_receive_unified(
+    int port_,
+    ffi.Pointer<wire_ReceiveUnifiedRequest> req,
+  ) {
+    return _wire_receive_unified(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_receive_unifiedPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ReceiveUnifiedRequest>)>>(
+          'wire_receive_unified');
+  late final _wire_receive_unified =
+      _wire_receive_unifiedPtr.asFunction<void Function(int, ffi.Pointer<wire_ReceiveUnifiedRequest>)>();
+
+  void wire_amend_invoice(
+    int port_,
+    ffi.Pointer<wire_AmendInvoiceRequest> req,
+  ) {
+    return _wire_amend_invoice(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_amend_invoicePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_AmendInvoiceRequest>)>>(
+          'wire_amend_invoice');
+  late final _wire_amend_invoice =
+      _wire_amend_invoicePtr.asFunction<void Function(int, ffi.Pointer<wire_AmendInvoiceRequest>)>();
+
   void wire_lnurl_pay(
     int port_,
     ffi.Pointer<wire_LnUrlPayRequest> req,
@@ -5604,33 +13163,190 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_lnurl_auth =
       _wire_lnurl_authPtr.asFunction<void Function(int, ffi.Pointer<wire_LnUrlAuthRequestData>)>();
 
+  void wire_list_lnurl_auth_domains(
+    int port_,
+  ) {
+    return _wire_list_lnurl_auth_domains(
+      port_,
+    );
+  }
+
+  late final _wire_list_lnurl_auth_domainsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_lnurl_auth_domains');
+  late final _wire_list_lnurl_auth_domains =
+      _wire_list_lnurl_auth_domainsPtr.asFunction<void Function(int)>();
+
+  void wire_lnurl_auth_linking_key(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> domain,
+  ) {
+    return _wire_lnurl_auth_linking_key(
+      port_,
+      domain,
+    );
+  }
+
+  late final _wire_lnurl_auth_linking_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_lnurl_auth_linking_key');
+  late final _wire_lnurl_auth_linking_key =
+      _wire_lnurl_auth_linking_keyPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_export_lnurl_auth(
+    int port_,
+  ) {
+    return _wire_export_lnurl_auth(
+      port_,
+    );
+  }
+
+  late final _wire_export_lnurl_authPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_export_lnurl_auth');
+  late final _wire_export_lnurl_auth = _wire_export_lnurl_authPtr.asFunction<void Function(int)>();
+
+  void wire_import_lnurl_auth(
+    int port_,
+    ffi.Pointer<wire_LnUrlAuthExport> export,
+  ) {
+    return _wire_import_lnurl_auth(
+      port_,
+      export,
+    );
+  }
+
+  late final _wire_import_lnurl_authPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_LnUrlAuthExport>)>>(
+          'wire_import_lnurl_auth');
+  late final _wire_import_lnurl_auth =
+      _wire_import_lnurl_authPtr.asFunction<void Function(int, ffi.Pointer<wire_LnUrlAuthExport>)>();
+
   void wire_report_issue(
     int port_,
-    ffi.Pointer<wire_ReportIssueRequest> req,
+    ffi.Pointer<wire_ReportIssueRequest> req,
+  ) {
+    return _wire_report_issue(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_report_issuePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ReportIssueRequest>)>>(
+          'wire_report_issue');
+  late final _wire_report_issue =
+      _wire_report_issuePtr.asFunction<void Function(int, ffi.Pointer<wire_ReportIssueRequest>)>();
+
+  void wire_connection_status(
+    int port_,
+  ) {
+    return _wire_connection_status(
+      port_,
+    );
+  }
+
+  late final _wire_connection_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_connection_status');
+  late final _wire_connection_status = _wire_connection_statusPtr.asFunction<void Function(int)>();
+
+  void wire_task_status(
+    int port_,
+  ) {
+    return _wire_task_status(
+      port_,
+    );
+  }
+
+  late final _wire_task_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_task_status');
+  late final _wire_task_status = _wire_task_statusPtr.asFunction<void Function(int)>();
+
+  void wire_peer_connectivity(
+    int port_,
+  ) {
+    return _wire_peer_connectivity(
+      port_,
+    );
+  }
+
+  late final _wire_peer_connectivityPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_peer_connectivity');
+  late final _wire_peer_connectivity = _wire_peer_connectivityPtr.asFunction<void Function(int)>();
+
+  void wire_fetch_fiat_rates(
+    int port_,
+  ) {
+    return _wire_fetch_fiat_rates(
+      port_,
+    );
+  }
+
+  late final _wire_fetch_fiat_ratesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_fetch_fiat_rates');
+  late final _wire_fetch_fiat_rates = _wire_fetch_fiat_ratesPtr.asFunction<void Function(int)>();
+
+  void wire_fiat_rate_history(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> currency,
+    ffi.Pointer<ffi.Int64> from_timestamp,
+    ffi.Pointer<ffi.Int64> to_timestamp,
+  ) {
+    return _wire_fiat_rate_history(
+      port_,
+      currency,
+      from_timestamp,
+      to_timestamp,
+    );
+  }
+
+  late final _wire_fiat_rate_historyPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Int64>,
+              ffi.Pointer<ffi.Int64>)>>('wire_fiat_rate_history');
+  late final _wire_fiat_rate_history = _wire_fiat_rate_historyPtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<ffi.Int64>, ffi.Pointer<ffi.Int64>)>();
+
+  void wire_add_fiat_rate_alert(
+    int port_,
+    ffi.Pointer<wire_AddFiatRateAlertRequest> req,
+  ) {
+    return _wire_add_fiat_rate_alert(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_add_fiat_rate_alertPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_AddFiatRateAlertRequest>)>>(
+          'wire_add_fiat_rate_alert');
+  late final _wire_add_fiat_rate_alert = _wire_add_fiat_rate_alertPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_AddFiatRateAlertRequest>)>();
+
+  void wire_list_fiat_rate_alerts(
+    int port_,
   ) {
-    return _wire_report_issue(
+    return _wire_list_fiat_rate_alerts(
       port_,
-      req,
     );
   }
 
-  late final _wire_report_issuePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ReportIssueRequest>)>>(
-          'wire_report_issue');
-  late final _wire_report_issue =
-      _wire_report_issuePtr.asFunction<void Function(int, ffi.Pointer<wire_ReportIssueRequest>)>();
+  late final _wire_list_fiat_rate_alertsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_fiat_rate_alerts');
+  late final _wire_list_fiat_rate_alerts = _wire_list_fiat_rate_alertsPtr.asFunction<void Function(int)>();
 
-  void wire_fetch_fiat_rates(
+  void wire_remove_fiat_rate_alert(
     int port_,
+    int id,
   ) {
-    return _wire_fetch_fiat_rates(
+    return _wire_remove_fiat_rate_alert(
       port_,
+      id,
     );
   }
 
-  late final _wire_fetch_fiat_ratesPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_fetch_fiat_rates');
-  late final _wire_fetch_fiat_rates = _wire_fetch_fiat_ratesPtr.asFunction<void Function(int)>();
+  late final _wire_remove_fiat_rate_alertPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Int64)>>('wire_remove_fiat_rate_alert');
+  late final _wire_remove_fiat_rate_alert =
+      _wire_remove_fiat_rate_alertPtr.asFunction<void Function(int, int)>();
 
   void wire_list_fiat_currencies(
     int port_,
@@ -5660,6 +13376,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_pay_onchain =
       _wire_pay_onchainPtr.asFunction<void Function(int, ffi.Pointer<wire_PayOnchainRequest>)>();
 
+  void wire_pay_onchain_address(
+    int port_,
+    ffi.Pointer<wire_PayOnchainAddressRequest> req,
+  ) {
+    return _wire_pay_onchain_address(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_pay_onchain_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_PayOnchainAddressRequest>)>>(
+          'wire_pay_onchain_address');
+  late final _wire_pay_onchain_address = _wire_pay_onchain_addressPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_PayOnchainAddressRequest>)>();
+
   void wire_receive_onchain(
     int port_,
     ffi.Pointer<wire_ReceiveOnchainRequest> req,
@@ -5692,6 +13424,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_buy_bitcoin =
       _wire_buy_bitcoinPtr.asFunction<void Function(int, ffi.Pointer<wire_BuyBitcoinRequest>)>();
 
+  void wire_fetch_buy_bitcoin_quote(
+    int port_,
+    ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest> req,
+  ) {
+    return _wire_fetch_buy_bitcoin_quote(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_fetch_buy_bitcoin_quotePtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest>)>>(
+      'wire_fetch_buy_bitcoin_quote');
+  late final _wire_fetch_buy_bitcoin_quote = _wire_fetch_buy_bitcoin_quotePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest>)>();
+
   void wire_redeem_onchain_funds(
     int port_,
     ffi.Pointer<wire_RedeemOnchainFundsRequest> req,
@@ -5708,6 +13456,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_redeem_onchain_funds = _wire_redeem_onchain_fundsPtr
       .asFunction<void Function(int, ffi.Pointer<wire_RedeemOnchainFundsRequest>)>();
 
+  void wire_bump_fee(
+    int port_,
+    ffi.Pointer<wire_BumpFeeRequest> req,
+  ) {
+    return _wire_bump_fee(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_bump_feePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_BumpFeeRequest>)>>(
+          'wire_bump_fee');
+  late final _wire_bump_fee =
+      _wire_bump_feePtr.asFunction<void Function(int, ffi.Pointer<wire_BumpFeeRequest>)>();
+
   void wire_prepare_redeem_onchain_funds(
     int port_,
     ffi.Pointer<wire_PrepareRedeemOnchainFundsRequest> req,
@@ -5737,6 +13501,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_list_refundables');
   late final _wire_list_refundables = _wire_list_refundablesPtr.asFunction<void Function(int)>();
 
+  void wire_export_swap_refund_keys(
+    int port_,
+    ffi.Pointer<wire_ExportSwapRefundKeysRequest> req,
+  ) {
+    return _wire_export_swap_refund_keys(
+      port_,
+      req,
+    );
+  }
+
+  late final _wire_export_swap_refund_keysPtr = _lookup<
+          ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_ExportSwapRefundKeysRequest>)>>(
+      'wire_export_swap_refund_keys');
+  late final _wire_export_swap_refund_keys = _wire_export_swap_refund_keysPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_ExportSwapRefundKeysRequest>)>();
+
   void wire_prepare_refund(
     int port_,
     ffi.Pointer<wire_PrepareRefundRequest> req,
@@ -5796,6 +13576,22 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_redeem_swap =
       _wire_redeem_swapPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
 
+  void wire_verify_swap_address(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> swap_address,
+  ) {
+    return _wire_verify_swap_address(
+      port_,
+      swap_address,
+    );
+  }
+
+  late final _wire_verify_swap_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+          'wire_verify_swap_address');
+  late final _wire_verify_swap_address =
+      _wire_verify_swap_addressPtr.asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
   void wire_in_progress_swap(
     int port_,
   ) {
@@ -5954,6 +13750,91 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _wire_generate_diagnostic_data =
       _wire_generate_diagnostic_dataPtr.asFunction<void Function(int)>();
 
+  void wire_diagnostic_snapshot(
+    int port_,
+  ) {
+    return _wire_diagnostic_snapshot(
+      port_,
+    );
+  }
+
+  late final _wire_diagnostic_snapshotPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>('wire_diagnostic_snapshot');
+  late final _wire_diagnostic_snapshot = _wire_diagnostic_snapshotPtr.asFunction<void Function(int)>();
+
+  void wire_diff_snapshots(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> a,
+    ffi.Pointer<wire_uint_8_list> b,
+  ) {
+    return _wire_diff_snapshots(
+      port_,
+      a,
+      b,
+    );
+  }
+
+  late final _wire_diff_snapshotsPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_diff_snapshots');
+  late final _wire_diff_snapshots = _wire_diff_snapshotsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  ffi.Pointer<wire_StringList> new_StringList_0(
+    int len,
+  ) {
+    return _new_StringList_0(
+      len,
+    );
+  }
+
+  late final _new_StringList_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_StringList> Function(ffi.Int32)>>('new_StringList_0');
+  late final _new_StringList_0 =
+      _new_StringList_0Ptr.asFunction<ffi.Pointer<wire_StringList> Function(int)>();
+
+  ffi.Pointer<wire_AddFiatRateAlertRequest> new_box_autoadd_add_fiat_rate_alert_request_0() {
+    return _new_box_autoadd_add_fiat_rate_alert_request_0();
+  }
+
+  late final _new_box_autoadd_add_fiat_rate_alert_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_AddFiatRateAlertRequest> Function()>>(
+          'new_box_autoadd_add_fiat_rate_alert_request_0');
+  late final _new_box_autoadd_add_fiat_rate_alert_request_0 =
+      _new_box_autoadd_add_fiat_rate_alert_request_0Ptr
+          .asFunction<ffi.Pointer<wire_AddFiatRateAlertRequest> Function()>();
+
+  ffi.Pointer<wire_AmendInvoiceRequest> new_box_autoadd_amend_invoice_request_0() {
+    return _new_box_autoadd_amend_invoice_request_0();
+  }
+
+  late final _new_box_autoadd_amend_invoice_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_AmendInvoiceRequest> Function()>>(
+          'new_box_autoadd_amend_invoice_request_0');
+  late final _new_box_autoadd_amend_invoice_request_0 = _new_box_autoadd_amend_invoice_request_0Ptr
+      .asFunction<ffi.Pointer<wire_AmendInvoiceRequest> Function()>();
+
+  ffi.Pointer<wire_AutoRefundConfig> new_box_autoadd_auto_refund_config_0() {
+    return _new_box_autoadd_auto_refund_config_0();
+  }
+
+  late final _new_box_autoadd_auto_refund_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_AutoRefundConfig> Function()>>(
+          'new_box_autoadd_auto_refund_config_0');
+  late final _new_box_autoadd_auto_refund_config_0 =
+      _new_box_autoadd_auto_refund_config_0Ptr.asFunction<ffi.Pointer<wire_AutoRefundConfig> Function()>();
+
+  ffi.Pointer<wire_BackupTransportConfig> new_box_autoadd_backup_transport_config_0() {
+    return _new_box_autoadd_backup_transport_config_0();
+  }
+
+  late final _new_box_autoadd_backup_transport_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_BackupTransportConfig> Function()>>(
+          'new_box_autoadd_backup_transport_config_0');
+  late final _new_box_autoadd_backup_transport_config_0 = _new_box_autoadd_backup_transport_config_0Ptr
+      .asFunction<ffi.Pointer<wire_BackupTransportConfig> Function()>();
+
   ffi.Pointer<ffi.Bool> new_box_autoadd_bool_0(
     bool value,
   ) {
@@ -5967,6 +13848,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_bool_0 =
       _new_box_autoadd_bool_0Ptr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
 
+  ffi.Pointer<wire_BumpFeeRequest> new_box_autoadd_bump_fee_request_0() {
+    return _new_box_autoadd_bump_fee_request_0();
+  }
+
+  late final _new_box_autoadd_bump_fee_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_BumpFeeRequest> Function()>>(
+          'new_box_autoadd_bump_fee_request_0');
+  late final _new_box_autoadd_bump_fee_request_0 =
+      _new_box_autoadd_bump_fee_request_0Ptr.asFunction<ffi.Pointer<wire_BumpFeeRequest> Function()>();
+
   ffi.Pointer<wire_BuyBitcoinRequest> new_box_autoadd_buy_bitcoin_request_0() {
     return _new_box_autoadd_buy_bitcoin_request_0();
   }
@@ -5977,6 +13868,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_buy_bitcoin_request_0 =
       _new_box_autoadd_buy_bitcoin_request_0Ptr.asFunction<ffi.Pointer<wire_BuyBitcoinRequest> Function()>();
 
+  ffi.Pointer<wire_ChainServiceConfig> new_box_autoadd_chain_service_config_0() {
+    return _new_box_autoadd_chain_service_config_0();
+  }
+
+  late final _new_box_autoadd_chain_service_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ChainServiceConfig> Function()>>(
+          'new_box_autoadd_chain_service_config_0');
+  late final _new_box_autoadd_chain_service_config_0 = _new_box_autoadd_chain_service_config_0Ptr
+      .asFunction<ffi.Pointer<wire_ChainServiceConfig> Function()>();
+
   ffi.Pointer<wire_CheckMessageRequest> new_box_autoadd_check_message_request_0() {
     return _new_box_autoadd_check_message_request_0();
   }
@@ -5987,6 +13888,25 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_check_message_request_0 = _new_box_autoadd_check_message_request_0Ptr
       .asFunction<ffi.Pointer<wire_CheckMessageRequest> Function()>();
 
+  ffi.Pointer<wire_CloseChannelRequest> new_box_autoadd_close_channel_request_0() {
+    return _new_box_autoadd_close_channel_request_0();
+  }
+
+  late final _new_box_autoadd_close_channel_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_CloseChannelRequest> Function()>>(
+          'new_box_autoadd_close_channel_request_0');
+  late final _new_box_autoadd_close_channel_request_0 = _new_box_autoadd_close_channel_request_0Ptr
+      .asFunction<ffi.Pointer<wire_CloseChannelRequest> Function()>();
+
+  ffi.Pointer<wire_Config> new_box_autoadd_config_0() {
+    return _new_box_autoadd_config_0();
+  }
+
+  late final _new_box_autoadd_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Config> Function()>>('new_box_autoadd_config_0');
+  late final _new_box_autoadd_config_0 =
+      _new_box_autoadd_config_0Ptr.asFunction<ffi.Pointer<wire_Config> Function()>();
+
   ffi.Pointer<wire_ConfigureNodeRequest> new_box_autoadd_configure_node_request_0() {
     return _new_box_autoadd_configure_node_request_0();
   }
@@ -5997,6 +13917,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_configure_node_request_0 = _new_box_autoadd_configure_node_request_0Ptr
       .asFunction<ffi.Pointer<wire_ConfigureNodeRequest> Function()>();
 
+  ffi.Pointer<wire_ConnectPeerRequest> new_box_autoadd_connect_peer_request_0() {
+    return _new_box_autoadd_connect_peer_request_0();
+  }
+
+  late final _new_box_autoadd_connect_peer_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ConnectPeerRequest> Function()>>(
+          'new_box_autoadd_connect_peer_request_0');
+  late final _new_box_autoadd_connect_peer_request_0 = _new_box_autoadd_connect_peer_request_0Ptr
+      .asFunction<ffi.Pointer<wire_ConnectPeerRequest> Function()>();
+
   ffi.Pointer<wire_ConnectRequest> new_box_autoadd_connect_request_0() {
     return _new_box_autoadd_connect_request_0();
   }
@@ -6007,6 +13937,155 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_connect_request_0 =
       _new_box_autoadd_connect_request_0Ptr.asFunction<ffi.Pointer<wire_ConnectRequest> Function()>();
 
+  ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest>
+      new_box_autoadd_create_lnurl_withdraw_voucher_request_0() {
+    return _new_box_autoadd_create_lnurl_withdraw_voucher_request_0();
+  }
+
+  late final _new_box_autoadd_create_lnurl_withdraw_voucher_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest> Function()>>(
+          'new_box_autoadd_create_lnurl_withdraw_voucher_request_0');
+  late final _new_box_autoadd_create_lnurl_withdraw_voucher_request_0 =
+      _new_box_autoadd_create_lnurl_withdraw_voucher_request_0Ptr
+          .asFunction<ffi.Pointer<wire_CreateLnurlWithdrawVoucherRequest> Function()>();
+
+  ffi.Pointer<wire_CreateStaticLnurlPayRequest> new_box_autoadd_create_static_lnurl_pay_request_0() {
+    return _new_box_autoadd_create_static_lnurl_pay_request_0();
+  }
+
+  late final _new_box_autoadd_create_static_lnurl_pay_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_CreateStaticLnurlPayRequest> Function()>>(
+          'new_box_autoadd_create_static_lnurl_pay_request_0');
+  late final _new_box_autoadd_create_static_lnurl_pay_request_0 =
+      _new_box_autoadd_create_static_lnurl_pay_request_0Ptr
+          .asFunction<ffi.Pointer<wire_CreateStaticLnurlPayRequest> Function()>();
+
+  ffi.Pointer<wire_DecryptRecoveryBundleRequest> new_box_autoadd_decrypt_recovery_bundle_request_0() {
+    return _new_box_autoadd_decrypt_recovery_bundle_request_0();
+  }
+
+  late final _new_box_autoadd_decrypt_recovery_bundle_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_DecryptRecoveryBundleRequest> Function()>>(
+          'new_box_autoadd_decrypt_recovery_bundle_request_0');
+  late final _new_box_autoadd_decrypt_recovery_bundle_request_0 =
+      _new_box_autoadd_decrypt_recovery_bundle_request_0Ptr
+          .asFunction<ffi.Pointer<wire_DecryptRecoveryBundleRequest> Function()>();
+
+  ffi.Pointer<ffi.Int32> new_box_autoadd_denomination_0(
+    int value,
+  ) {
+    return _new_box_autoadd_denomination_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_denomination_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'new_box_autoadd_denomination_0');
+  late final _new_box_autoadd_denomination_0 =
+      _new_box_autoadd_denomination_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_DeriveEncryptionKeyRequest> new_box_autoadd_derive_encryption_key_request_0() {
+    return _new_box_autoadd_derive_encryption_key_request_0();
+  }
+
+  late final _new_box_autoadd_derive_encryption_key_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_DeriveEncryptionKeyRequest> Function()>>(
+          'new_box_autoadd_derive_encryption_key_request_0');
+  late final _new_box_autoadd_derive_encryption_key_request_0 =
+      _new_box_autoadd_derive_encryption_key_request_0Ptr
+          .asFunction<ffi.Pointer<wire_DeriveEncryptionKeyRequest> Function()>();
+
+  ffi.Pointer<wire_EncryptedPaymentRequestData> new_box_autoadd_encrypted_payment_request_data_0() {
+    return _new_box_autoadd_encrypted_payment_request_data_0();
+  }
+
+  late final _new_box_autoadd_encrypted_payment_request_data_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_EncryptedPaymentRequestData> Function()>>(
+          'new_box_autoadd_encrypted_payment_request_data_0');
+  late final _new_box_autoadd_encrypted_payment_request_data_0 =
+      _new_box_autoadd_encrypted_payment_request_data_0Ptr
+          .asFunction<ffi.Pointer<wire_EncryptedPaymentRequestData> Function()>();
+
+  ffi.Pointer<wire_ExportPaymentsRequest> new_box_autoadd_export_payments_request_0() {
+    return _new_box_autoadd_export_payments_request_0();
+  }
+
+  late final _new_box_autoadd_export_payments_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ExportPaymentsRequest> Function()>>(
+          'new_box_autoadd_export_payments_request_0');
+  late final _new_box_autoadd_export_payments_request_0 = _new_box_autoadd_export_payments_request_0Ptr
+      .asFunction<ffi.Pointer<wire_ExportPaymentsRequest> Function()>();
+
+  ffi.Pointer<wire_ExportRecoveryBundleRequest> new_box_autoadd_export_recovery_bundle_request_0() {
+    return _new_box_autoadd_export_recovery_bundle_request_0();
+  }
+
+  late final _new_box_autoadd_export_recovery_bundle_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ExportRecoveryBundleRequest> Function()>>(
+          'new_box_autoadd_export_recovery_bundle_request_0');
+  late final _new_box_autoadd_export_recovery_bundle_request_0 =
+      _new_box_autoadd_export_recovery_bundle_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ExportRecoveryBundleRequest> Function()>();
+
+  ffi.Pointer<wire_ExportSwapRefundKeysRequest> new_box_autoadd_export_swap_refund_keys_request_0() {
+    return _new_box_autoadd_export_swap_refund_keys_request_0();
+  }
+
+  late final _new_box_autoadd_export_swap_refund_keys_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ExportSwapRefundKeysRequest> Function()>>(
+          'new_box_autoadd_export_swap_refund_keys_request_0');
+  late final _new_box_autoadd_export_swap_refund_keys_request_0 =
+      _new_box_autoadd_export_swap_refund_keys_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ExportSwapRefundKeysRequest> Function()>();
+
+  ffi.Pointer<ffi.Double> new_box_autoadd_f64_0(
+    double value,
+  ) {
+    return _new_box_autoadd_f64_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_f64_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Double> Function(ffi.Double)>>('new_box_autoadd_f64_0');
+  late final _new_box_autoadd_f64_0 =
+      _new_box_autoadd_f64_0Ptr.asFunction<ffi.Pointer<ffi.Double> Function(double)>();
+
+  ffi.Pointer<ffi.Int32> new_box_autoadd_feerate_preset_0(
+    int value,
+  ) {
+    return _new_box_autoadd_feerate_preset_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_feerate_preset_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+          'new_box_autoadd_feerate_preset_0');
+  late final _new_box_autoadd_feerate_preset_0 =
+      _new_box_autoadd_feerate_preset_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest> new_box_autoadd_fetch_buy_bitcoin_quote_request_0() {
+    return _new_box_autoadd_fetch_buy_bitcoin_quote_request_0();
+  }
+
+  late final _new_box_autoadd_fetch_buy_bitcoin_quote_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest> Function()>>(
+          'new_box_autoadd_fetch_buy_bitcoin_quote_request_0');
+  late final _new_box_autoadd_fetch_buy_bitcoin_quote_request_0 =
+      _new_box_autoadd_fetch_buy_bitcoin_quote_request_0Ptr
+          .asFunction<ffi.Pointer<wire_FetchBuyBitcoinQuoteRequest> Function()>();
+
+  ffi.Pointer<wire_FiatAmount> new_box_autoadd_fiat_amount_0() {
+    return _new_box_autoadd_fiat_amount_0();
+  }
+
+  late final _new_box_autoadd_fiat_amount_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_FiatAmount> Function()>>('new_box_autoadd_fiat_amount_0');
+  late final _new_box_autoadd_fiat_amount_0 =
+      _new_box_autoadd_fiat_amount_0Ptr.asFunction<ffi.Pointer<wire_FiatAmount> Function()>();
+
   ffi.Pointer<wire_GreenlightCredentials> new_box_autoadd_greenlight_credentials_0() {
     return _new_box_autoadd_greenlight_credentials_0();
   }
@@ -6027,6 +14106,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_greenlight_node_config_0 = _new_box_autoadd_greenlight_node_config_0Ptr
       .asFunction<ffi.Pointer<wire_GreenlightNodeConfig> Function()>();
 
+  ffi.Pointer<wire_HtlcExposureLimits> new_box_autoadd_htlc_exposure_limits_0() {
+    return _new_box_autoadd_htlc_exposure_limits_0();
+  }
+
+  late final _new_box_autoadd_htlc_exposure_limits_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_HtlcExposureLimits> Function()>>(
+          'new_box_autoadd_htlc_exposure_limits_0');
+  late final _new_box_autoadd_htlc_exposure_limits_0 = _new_box_autoadd_htlc_exposure_limits_0Ptr
+      .asFunction<ffi.Pointer<wire_HtlcExposureLimits> Function()>();
+
   ffi.Pointer<ffi.Int64> new_box_autoadd_i64_0(
     int value,
   ) {
@@ -6040,6 +14129,27 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_i64_0 =
       _new_box_autoadd_i64_0Ptr.asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
 
+  ffi.Pointer<wire_ImportPaymentsRequest> new_box_autoadd_import_payments_request_0() {
+    return _new_box_autoadd_import_payments_request_0();
+  }
+
+  late final _new_box_autoadd_import_payments_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ImportPaymentsRequest> Function()>>(
+          'new_box_autoadd_import_payments_request_0');
+  late final _new_box_autoadd_import_payments_request_0 = _new_box_autoadd_import_payments_request_0Ptr
+      .asFunction<ffi.Pointer<wire_ImportPaymentsRequest> Function()>();
+
+  ffi.Pointer<wire_ListPaymentGroupsRequest> new_box_autoadd_list_payment_groups_request_0() {
+    return _new_box_autoadd_list_payment_groups_request_0();
+  }
+
+  late final _new_box_autoadd_list_payment_groups_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ListPaymentGroupsRequest> Function()>>(
+          'new_box_autoadd_list_payment_groups_request_0');
+  late final _new_box_autoadd_list_payment_groups_request_0 =
+      _new_box_autoadd_list_payment_groups_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ListPaymentGroupsRequest> Function()>();
+
   ffi.Pointer<wire_ListPaymentsRequest> new_box_autoadd_list_payments_request_0() {
     return _new_box_autoadd_list_payments_request_0();
   }
@@ -6060,6 +14170,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_list_swaps_request_0 =
       _new_box_autoadd_list_swaps_request_0Ptr.asFunction<ffi.Pointer<wire_ListSwapsRequest> Function()>();
 
+  ffi.Pointer<wire_LnUrlAuthExport> new_box_autoadd_ln_url_auth_export_0() {
+    return _new_box_autoadd_ln_url_auth_export_0();
+  }
+
+  late final _new_box_autoadd_ln_url_auth_export_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_LnUrlAuthExport> Function()>>(
+          'new_box_autoadd_ln_url_auth_export_0');
+  late final _new_box_autoadd_ln_url_auth_export_0 =
+      _new_box_autoadd_ln_url_auth_export_0Ptr.asFunction<ffi.Pointer<wire_LnUrlAuthExport> Function()>();
+
   ffi.Pointer<wire_LnUrlAuthRequestData> new_box_autoadd_ln_url_auth_request_data_0() {
     return _new_box_autoadd_ln_url_auth_request_data_0();
   }
@@ -6090,6 +14210,34 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_ln_url_withdraw_request_0 = _new_box_autoadd_ln_url_withdraw_request_0Ptr
       .asFunction<ffi.Pointer<wire_LnUrlWithdrawRequest> Function()>();
 
+  ffi.Pointer<wire_LspPolicy> new_box_autoadd_lsp_policy_0() {
+    return _new_box_autoadd_lsp_policy_0();
+  }
+
+  late final _new_box_autoadd_lsp_policy_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_LspPolicy> Function()>>('new_box_autoadd_lsp_policy_0');
+  late final _new_box_autoadd_lsp_policy_0 =
+      _new_box_autoadd_lsp_policy_0Ptr.asFunction<ffi.Pointer<wire_LspPolicy> Function()>();
+
+  ffi.Pointer<wire_MilliSat> new_box_autoadd_milli_sat_0() {
+    return _new_box_autoadd_milli_sat_0();
+  }
+
+  late final _new_box_autoadd_milli_sat_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_MilliSat> Function()>>('new_box_autoadd_milli_sat_0');
+  late final _new_box_autoadd_milli_sat_0 =
+      _new_box_autoadd_milli_sat_0Ptr.asFunction<ffi.Pointer<wire_MilliSat> Function()>();
+
+  ffi.Pointer<wire_MockNodeConfig> new_box_autoadd_mock_node_config_0() {
+    return _new_box_autoadd_mock_node_config_0();
+  }
+
+  late final _new_box_autoadd_mock_node_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_MockNodeConfig> Function()>>(
+          'new_box_autoadd_mock_node_config_0');
+  late final _new_box_autoadd_mock_node_config_0 =
+      _new_box_autoadd_mock_node_config_0Ptr.asFunction<ffi.Pointer<wire_MockNodeConfig> Function()>();
+
   ffi.Pointer<wire_NodeConfig> new_box_autoadd_node_config_0() {
     return _new_box_autoadd_node_config_0();
   }
@@ -6099,6 +14247,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_node_config_0 =
       _new_box_autoadd_node_config_0Ptr.asFunction<ffi.Pointer<wire_NodeConfig> Function()>();
 
+  ffi.Pointer<wire_NodeMigrationRequest> new_box_autoadd_node_migration_request_0() {
+    return _new_box_autoadd_node_migration_request_0();
+  }
+
+  late final _new_box_autoadd_node_migration_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_NodeMigrationRequest> Function()>>(
+          'new_box_autoadd_node_migration_request_0');
+  late final _new_box_autoadd_node_migration_request_0 = _new_box_autoadd_node_migration_request_0Ptr
+      .asFunction<ffi.Pointer<wire_NodeMigrationRequest> Function()>();
+
   ffi.Pointer<wire_OpenChannelFeeRequest> new_box_autoadd_open_channel_fee_request_0() {
     return _new_box_autoadd_open_channel_fee_request_0();
   }
@@ -6109,6 +14267,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_open_channel_fee_request_0 = _new_box_autoadd_open_channel_fee_request_0Ptr
       .asFunction<ffi.Pointer<wire_OpenChannelFeeRequest> Function()>();
 
+  ffi.Pointer<wire_OpenChannelRequest> new_box_autoadd_open_channel_request_0() {
+    return _new_box_autoadd_open_channel_request_0();
+  }
+
+  late final _new_box_autoadd_open_channel_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_OpenChannelRequest> Function()>>(
+          'new_box_autoadd_open_channel_request_0');
+  late final _new_box_autoadd_open_channel_request_0 = _new_box_autoadd_open_channel_request_0Ptr
+      .asFunction<ffi.Pointer<wire_OpenChannelRequest> Function()>();
+
   ffi.Pointer<wire_OpeningFeeParams> new_box_autoadd_opening_fee_params_0() {
     return _new_box_autoadd_opening_fee_params_0();
   }
@@ -6119,6 +14287,37 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_opening_fee_params_0 =
       _new_box_autoadd_opening_fee_params_0Ptr.asFunction<ffi.Pointer<wire_OpeningFeeParams> Function()>();
 
+  ffi.Pointer<wire_PartnerFeeConfig> new_box_autoadd_partner_fee_config_0() {
+    return _new_box_autoadd_partner_fee_config_0();
+  }
+
+  late final _new_box_autoadd_partner_fee_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PartnerFeeConfig> Function()>>(
+          'new_box_autoadd_partner_fee_config_0');
+  late final _new_box_autoadd_partner_fee_config_0 =
+      _new_box_autoadd_partner_fee_config_0Ptr.asFunction<ffi.Pointer<wire_PartnerFeeConfig> Function()>();
+
+  ffi.Pointer<wire_PayOfferRequest> new_box_autoadd_pay_offer_request_0() {
+    return _new_box_autoadd_pay_offer_request_0();
+  }
+
+  late final _new_box_autoadd_pay_offer_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PayOfferRequest> Function()>>(
+          'new_box_autoadd_pay_offer_request_0');
+  late final _new_box_autoadd_pay_offer_request_0 =
+      _new_box_autoadd_pay_offer_request_0Ptr.asFunction<ffi.Pointer<wire_PayOfferRequest> Function()>();
+
+  ffi.Pointer<wire_PayOnchainAddressRequest> new_box_autoadd_pay_onchain_address_request_0() {
+    return _new_box_autoadd_pay_onchain_address_request_0();
+  }
+
+  late final _new_box_autoadd_pay_onchain_address_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PayOnchainAddressRequest> Function()>>(
+          'new_box_autoadd_pay_onchain_address_request_0');
+  late final _new_box_autoadd_pay_onchain_address_request_0 =
+      _new_box_autoadd_pay_onchain_address_request_0Ptr
+          .asFunction<ffi.Pointer<wire_PayOnchainAddressRequest> Function()>();
+
   ffi.Pointer<wire_PayOnchainRequest> new_box_autoadd_pay_onchain_request_0() {
     return _new_box_autoadd_pay_onchain_request_0();
   }
@@ -6129,6 +14328,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_pay_onchain_request_0 =
       _new_box_autoadd_pay_onchain_request_0Ptr.asFunction<ffi.Pointer<wire_PayOnchainRequest> Function()>();
 
+  ffi.Pointer<wire_PaymentRequestBundle> new_box_autoadd_payment_request_bundle_0() {
+    return _new_box_autoadd_payment_request_bundle_0();
+  }
+
+  late final _new_box_autoadd_payment_request_bundle_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PaymentRequestBundle> Function()>>(
+          'new_box_autoadd_payment_request_bundle_0');
+  late final _new_box_autoadd_payment_request_bundle_0 = _new_box_autoadd_payment_request_bundle_0Ptr
+      .asFunction<ffi.Pointer<wire_PaymentRequestBundle> Function()>();
+
   ffi.Pointer<wire_PrepareOnchainPaymentRequest> new_box_autoadd_prepare_onchain_payment_request_0() {
     return _new_box_autoadd_prepare_onchain_payment_request_0();
   }
@@ -6140,6 +14349,17 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
       _new_box_autoadd_prepare_onchain_payment_request_0Ptr
           .asFunction<ffi.Pointer<wire_PrepareOnchainPaymentRequest> Function()>();
 
+  ffi.Pointer<wire_PrepareReceivePaymentRequest> new_box_autoadd_prepare_receive_payment_request_0() {
+    return _new_box_autoadd_prepare_receive_payment_request_0();
+  }
+
+  late final _new_box_autoadd_prepare_receive_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PrepareReceivePaymentRequest> Function()>>(
+          'new_box_autoadd_prepare_receive_payment_request_0');
+  late final _new_box_autoadd_prepare_receive_payment_request_0 =
+      _new_box_autoadd_prepare_receive_payment_request_0Ptr
+          .asFunction<ffi.Pointer<wire_PrepareReceivePaymentRequest> Function()>();
+
   ffi.Pointer<wire_PrepareRedeemOnchainFundsRequest>
       new_box_autoadd_prepare_redeem_onchain_funds_request_0() {
     return _new_box_autoadd_prepare_redeem_onchain_funds_request_0();
@@ -6162,6 +14382,57 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_prepare_refund_request_0 = _new_box_autoadd_prepare_refund_request_0Ptr
       .asFunction<ffi.Pointer<wire_PrepareRefundRequest> Function()>();
 
+  ffi.Pointer<wire_PrepareSendPaymentRequest> new_box_autoadd_prepare_send_payment_request_0() {
+    return _new_box_autoadd_prepare_send_payment_request_0();
+  }
+
+  late final _new_box_autoadd_prepare_send_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_PrepareSendPaymentRequest> Function()>>(
+          'new_box_autoadd_prepare_send_payment_request_0');
+  late final _new_box_autoadd_prepare_send_payment_request_0 =
+      _new_box_autoadd_prepare_send_payment_request_0Ptr
+          .asFunction<ffi.Pointer<wire_PrepareSendPaymentRequest> Function()>();
+
+  ffi.Pointer<wire_ProbePaymentRequest> new_box_autoadd_probe_payment_request_0() {
+    return _new_box_autoadd_probe_payment_request_0();
+  }
+
+  late final _new_box_autoadd_probe_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ProbePaymentRequest> Function()>>(
+          'new_box_autoadd_probe_payment_request_0');
+  late final _new_box_autoadd_probe_payment_request_0 = _new_box_autoadd_probe_payment_request_0Ptr
+      .asFunction<ffi.Pointer<wire_ProbePaymentRequest> Function()>();
+
+  ffi.Pointer<wire_ProveAddressOwnershipRequest> new_box_autoadd_prove_address_ownership_request_0() {
+    return _new_box_autoadd_prove_address_ownership_request_0();
+  }
+
+  late final _new_box_autoadd_prove_address_ownership_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ProveAddressOwnershipRequest> Function()>>(
+          'new_box_autoadd_prove_address_ownership_request_0');
+  late final _new_box_autoadd_prove_address_ownership_request_0 =
+      _new_box_autoadd_prove_address_ownership_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ProveAddressOwnershipRequest> Function()>();
+
+  ffi.Pointer<wire_ProxyConfig> new_box_autoadd_proxy_config_0() {
+    return _new_box_autoadd_proxy_config_0();
+  }
+
+  late final _new_box_autoadd_proxy_config_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ProxyConfig> Function()>>('new_box_autoadd_proxy_config_0');
+  late final _new_box_autoadd_proxy_config_0 =
+      _new_box_autoadd_proxy_config_0Ptr.asFunction<ffi.Pointer<wire_ProxyConfig> Function()>();
+
+  ffi.Pointer<wire_QueuePaymentRequest> new_box_autoadd_queue_payment_request_0() {
+    return _new_box_autoadd_queue_payment_request_0();
+  }
+
+  late final _new_box_autoadd_queue_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_QueuePaymentRequest> Function()>>(
+          'new_box_autoadd_queue_payment_request_0');
+  late final _new_box_autoadd_queue_payment_request_0 = _new_box_autoadd_queue_payment_request_0Ptr
+      .asFunction<ffi.Pointer<wire_QueuePaymentRequest> Function()>();
+
   ffi.Pointer<wire_ReceiveOnchainRequest> new_box_autoadd_receive_onchain_request_0() {
     return _new_box_autoadd_receive_onchain_request_0();
   }
@@ -6182,6 +14453,28 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_receive_payment_request_0 = _new_box_autoadd_receive_payment_request_0Ptr
       .asFunction<ffi.Pointer<wire_ReceivePaymentRequest> Function()>();
 
+  ffi.Pointer<wire_ReceiveUnifiedRequest> new_box_autoadd_receive_unified_request_0() {
+    return _new_box_autoadd_receive_unified_request_0();
+  }
+
+  late final _new_box_autoadd_receive_unified_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ReceiveUnifiedRequest> Function()>>(
+          'new_box_autoadd_receive_unified_request_0');
+  late final _new_box_autoadd_receive_unified_request_0 = _new_box_autoadd_receive_unified_request_0Ptr
+      .asFunction<ffi.Pointer<wire_ReceiveUnifiedRequest> Function()>();
+
+  ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest>
+      new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0() {
+    return _new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0();
+  }
+
+  late final _new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest> Function()>>(
+          'new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0');
+  late final _new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0 =
+      _new_box_autoadd_redeem_lnurl_withdraw_voucher_request_0Ptr
+          .asFunction<ffi.Pointer<wire_RedeemLnurlWithdrawVoucherRequest> Function()>();
+
   ffi.Pointer<wire_RedeemOnchainFundsRequest> new_box_autoadd_redeem_onchain_funds_request_0() {
     return _new_box_autoadd_redeem_onchain_funds_request_0();
   }
@@ -6234,6 +14527,15 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_reverse_swap_fees_request_0 = _new_box_autoadd_reverse_swap_fees_request_0Ptr
       .asFunction<ffi.Pointer<wire_ReverseSwapFeesRequest> Function()>();
 
+  ffi.Pointer<wire_Sat> new_box_autoadd_sat_0() {
+    return _new_box_autoadd_sat_0();
+  }
+
+  late final _new_box_autoadd_sat_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_Sat> Function()>>('new_box_autoadd_sat_0');
+  late final _new_box_autoadd_sat_0 =
+      _new_box_autoadd_sat_0Ptr.asFunction<ffi.Pointer<wire_Sat> Function()>();
+
   ffi.Pointer<wire_SendPaymentRequest> new_box_autoadd_send_payment_request_0() {
     return _new_box_autoadd_send_payment_request_0();
   }
@@ -6244,6 +14546,16 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_send_payment_request_0 = _new_box_autoadd_send_payment_request_0Ptr
       .asFunction<ffi.Pointer<wire_SendPaymentRequest> Function()>();
 
+  ffi.Pointer<wire_SendPaymentsRequest> new_box_autoadd_send_payments_request_0() {
+    return _new_box_autoadd_send_payments_request_0();
+  }
+
+  late final _new_box_autoadd_send_payments_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_SendPaymentsRequest> Function()>>(
+          'new_box_autoadd_send_payments_request_0');
+  late final _new_box_autoadd_send_payments_request_0 = _new_box_autoadd_send_payments_request_0Ptr
+      .asFunction<ffi.Pointer<wire_SendPaymentsRequest> Function()>();
+
   ffi.Pointer<wire_SendSpontaneousPaymentRequest> new_box_autoadd_send_spontaneous_payment_request_0() {
     return _new_box_autoadd_send_spontaneous_payment_request_0();
   }
@@ -6265,6 +14577,28 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_sign_message_request_0 = _new_box_autoadd_sign_message_request_0Ptr
       .asFunction<ffi.Pointer<wire_SignMessageRequest> Function()>();
 
+  ffi.Pointer<ffi.Int32> new_box_autoadd_sort_order_0(
+    int value,
+  ) {
+    return _new_box_autoadd_sort_order_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_sort_order_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>('new_box_autoadd_sort_order_0');
+  late final _new_box_autoadd_sort_order_0 =
+      _new_box_autoadd_sort_order_0Ptr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_SpendPolicy> new_box_autoadd_spend_policy_0() {
+    return _new_box_autoadd_spend_policy_0();
+  }
+
+  late final _new_box_autoadd_spend_policy_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_SpendPolicy> Function()>>('new_box_autoadd_spend_policy_0');
+  late final _new_box_autoadd_spend_policy_0 =
+      _new_box_autoadd_spend_policy_0Ptr.asFunction<ffi.Pointer<wire_SpendPolicy> Function()>();
+
   ffi.Pointer<wire_StaticBackupRequest> new_box_autoadd_static_backup_request_0() {
     return _new_box_autoadd_static_backup_request_0();
   }
@@ -6275,6 +14609,30 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_static_backup_request_0 = _new_box_autoadd_static_backup_request_0Ptr
       .asFunction<ffi.Pointer<wire_StaticBackupRequest> Function()>();
 
+  ffi.Pointer<wire_StaticLnurlPayCallbackRequest> new_box_autoadd_static_lnurl_pay_callback_request_0() {
+    return _new_box_autoadd_static_lnurl_pay_callback_request_0();
+  }
+
+  late final _new_box_autoadd_static_lnurl_pay_callback_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_StaticLnurlPayCallbackRequest> Function()>>(
+          'new_box_autoadd_static_lnurl_pay_callback_request_0');
+  late final _new_box_autoadd_static_lnurl_pay_callback_request_0 =
+      _new_box_autoadd_static_lnurl_pay_callback_request_0Ptr
+          .asFunction<ffi.Pointer<wire_StaticLnurlPayCallbackRequest> Function()>();
+
+  ffi.Pointer<ffi.Uint16> new_box_autoadd_u16_0(
+    int value,
+  ) {
+    return _new_box_autoadd_u16_0(
+      value,
+    );
+  }
+
+  late final _new_box_autoadd_u16_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint16> Function(ffi.Uint16)>>('new_box_autoadd_u16_0');
+  late final _new_box_autoadd_u16_0 =
+      _new_box_autoadd_u16_0Ptr.asFunction<ffi.Pointer<ffi.Uint16> Function(int)>();
+
   ffi.Pointer<ffi.Uint32> new_box_autoadd_u32_0(
     int value,
   ) {
@@ -6301,6 +14659,41 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_u64_0 =
       _new_box_autoadd_u64_0Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
 
+  ffi.Pointer<wire_UserSettings> new_box_autoadd_user_settings_0() {
+    return _new_box_autoadd_user_settings_0();
+  }
+
+  late final _new_box_autoadd_user_settings_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_UserSettings> Function()>>(
+          'new_box_autoadd_user_settings_0');
+  late final _new_box_autoadd_user_settings_0 =
+      _new_box_autoadd_user_settings_0Ptr.asFunction<ffi.Pointer<wire_UserSettings> Function()>();
+
+  ffi.Pointer<wire_ValidatePayabilityRequest> new_box_autoadd_validate_payability_request_0() {
+    return _new_box_autoadd_validate_payability_request_0();
+  }
+
+  late final _new_box_autoadd_validate_payability_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_ValidatePayabilityRequest> Function()>>(
+          'new_box_autoadd_validate_payability_request_0');
+  late final _new_box_autoadd_validate_payability_request_0 =
+      _new_box_autoadd_validate_payability_request_0Ptr
+          .asFunction<ffi.Pointer<wire_ValidatePayabilityRequest> Function()>();
+
+  ffi.Pointer<wire_list_ln_url_auth_domain> new_list_ln_url_auth_domain_0(
+    int len,
+  ) {
+    return _new_list_ln_url_auth_domain_0(
+      len,
+    );
+  }
+
+  late final _new_list_ln_url_auth_domain_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_ln_url_auth_domain> Function(ffi.Int32)>>(
+          'new_list_ln_url_auth_domain_0');
+  late final _new_list_ln_url_auth_domain_0 =
+      _new_list_ln_url_auth_domain_0Ptr.asFunction<ffi.Pointer<wire_list_ln_url_auth_domain> Function(int)>();
+
   ffi.Pointer<wire_list_metadata_filter> new_list_metadata_filter_0(
     int len,
   ) {
@@ -6329,6 +14722,48 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_list_payment_type_filter_0 = _new_list_payment_type_filter_0Ptr
       .asFunction<ffi.Pointer<wire_list_payment_type_filter> Function(int)>();
 
+  ffi.Pointer<wire_list_route_hint> new_list_route_hint_0(
+    int len,
+  ) {
+    return _new_list_route_hint_0(
+      len,
+    );
+  }
+
+  late final _new_list_route_hint_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_route_hint> Function(ffi.Int32)>>(
+          'new_list_route_hint_0');
+  late final _new_list_route_hint_0 =
+      _new_list_route_hint_0Ptr.asFunction<ffi.Pointer<wire_list_route_hint> Function(int)>();
+
+  ffi.Pointer<wire_list_route_hint_hop> new_list_route_hint_hop_0(
+    int len,
+  ) {
+    return _new_list_route_hint_hop_0(
+      len,
+    );
+  }
+
+  late final _new_list_route_hint_hop_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_route_hint_hop> Function(ffi.Int32)>>(
+          'new_list_route_hint_hop_0');
+  late final _new_list_route_hint_hop_0 =
+      _new_list_route_hint_hop_0Ptr.asFunction<ffi.Pointer<wire_list_route_hint_hop> Function(int)>();
+
+  ffi.Pointer<wire_list_send_payment_request> new_list_send_payment_request_0(
+    int len,
+  ) {
+    return _new_list_send_payment_request_0(
+      len,
+    );
+  }
+
+  late final _new_list_send_payment_request_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_list_send_payment_request> Function(ffi.Int32)>>(
+          'new_list_send_payment_request_0');
+  late final _new_list_send_payment_request_0 = _new_list_send_payment_request_0Ptr
+      .asFunction<ffi.Pointer<wire_list_send_payment_request> Function(int)>();
+
   ffi.Pointer<wire_list_swap_status> new_list_swap_status_0(
     int len,
   ) {
@@ -6370,6 +14805,55 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _new_uint_8_list_0 =
       _new_uint_8_list_0Ptr.asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
+  ffi.Pointer<BackupTransportConfigKind> inflate_BackupTransportConfig_S3() {
+    return _inflate_BackupTransportConfig_S3();
+  }
+
+  late final _inflate_BackupTransportConfig_S3Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<BackupTransportConfigKind> Function()>>(
+          'inflate_BackupTransportConfig_S3');
+  late final _inflate_BackupTransportConfig_S3 =
+      _inflate_BackupTransportConfig_S3Ptr.asFunction<ffi.Pointer<BackupTransportConfigKind> Function()>();
+
+  ffi.Pointer<BackupTransportConfigKind> inflate_BackupTransportConfig_WebDav() {
+    return _inflate_BackupTransportConfig_WebDav();
+  }
+
+  late final _inflate_BackupTransportConfig_WebDavPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<BackupTransportConfigKind> Function()>>(
+          'inflate_BackupTransportConfig_WebDav');
+  late final _inflate_BackupTransportConfig_WebDav = _inflate_BackupTransportConfig_WebDavPtr
+      .asFunction<ffi.Pointer<BackupTransportConfigKind> Function()>();
+
+  ffi.Pointer<ChainServiceConfigKind> inflate_ChainServiceConfig_Esplora() {
+    return _inflate_ChainServiceConfig_Esplora();
+  }
+
+  late final _inflate_ChainServiceConfig_EsploraPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ChainServiceConfigKind> Function()>>(
+          'inflate_ChainServiceConfig_Esplora');
+  late final _inflate_ChainServiceConfig_Esplora =
+      _inflate_ChainServiceConfig_EsploraPtr.asFunction<ffi.Pointer<ChainServiceConfigKind> Function()>();
+
+  ffi.Pointer<ChainServiceConfigKind> inflate_ChainServiceConfig_Electrum() {
+    return _inflate_ChainServiceConfig_Electrum();
+  }
+
+  late final _inflate_ChainServiceConfig_ElectrumPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ChainServiceConfigKind> Function()>>(
+          'inflate_ChainServiceConfig_Electrum');
+  late final _inflate_ChainServiceConfig_Electrum =
+      _inflate_ChainServiceConfig_ElectrumPtr.asFunction<ffi.Pointer<ChainServiceConfigKind> Function()>();
+
+  ffi.Pointer<LspPolicyKind> inflate_LspPolicy_Pinned() {
+    return _inflate_LspPolicy_Pinned();
+  }
+
+  late final _inflate_LspPolicy_PinnedPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<LspPolicyKind> Function()>>('inflate_LspPolicy_Pinned');
+  late final _inflate_LspPolicy_Pinned =
+      _inflate_LspPolicy_PinnedPtr.asFunction<ffi.Pointer<LspPolicyKind> Function()>();
+
   ffi.Pointer<NodeConfigKind> inflate_NodeConfig_Greenlight() {
     return _inflate_NodeConfig_Greenlight();
   }
@@ -6379,6 +14863,36 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
   late final _inflate_NodeConfig_Greenlight =
       _inflate_NodeConfig_GreenlightPtr.asFunction<ffi.Pointer<NodeConfigKind> Function()>();
 
+  ffi.Pointer<NodeConfigKind> inflate_NodeConfig_Mock() {
+    return _inflate_NodeConfig_Mock();
+  }
+
+  late final _inflate_NodeConfig_MockPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<NodeConfigKind> Function()>>('inflate_NodeConfig_Mock');
+  late final _inflate_NodeConfig_Mock =
+      _inflate_NodeConfig_MockPtr.asFunction<ffi.Pointer<NodeConfigKind> Function()>();
+
+  ffi.Pointer<PartnerFeeDestinationKind> inflate_PartnerFeeDestination_NodeId() {
+    return _inflate_PartnerFeeDestination_NodeId();
+  }
+
+  late final _inflate_PartnerFeeDestination_NodeIdPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<PartnerFeeDestinationKind> Function()>>(
+          'inflate_PartnerFeeDestination_NodeId');
+  late final _inflate_PartnerFeeDestination_NodeId = _inflate_PartnerFeeDestination_NodeIdPtr
+      .asFunction<ffi.Pointer<PartnerFeeDestinationKind> Function()>();
+
+  ffi.Pointer<PartnerFeeDestinationKind> inflate_PartnerFeeDestination_LightningAddress() {
+    return _inflate_PartnerFeeDestination_LightningAddress();
+  }
+
+  late final _inflate_PartnerFeeDestination_LightningAddressPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<PartnerFeeDestinationKind> Function()>>(
+          'inflate_PartnerFeeDestination_LightningAddress');
+  late final _inflate_PartnerFeeDestination_LightningAddress =
+      _inflate_PartnerFeeDestination_LightningAddressPtr
+          .asFunction<ffi.Pointer<PartnerFeeDestinationKind> Function()>();
+
   ffi.Pointer<ReportIssueRequestKind> inflate_ReportIssueRequest_PaymentFailure() {
     return _inflate_ReportIssueRequest_PaymentFailure();
   }
@@ -6397,101 +14911,433 @@ class BreezSdkCoreWire implements FlutterRustBridgeWireBase {
     );
   }
 
-  late final _free_WireSyncReturnPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(WireSyncReturn)>>('free_WireSyncReturn');
-  late final _free_WireSyncReturn = _free_WireSyncReturnPtr.asFunction<void Function(WireSyncReturn)>();
+  late final _free_WireSyncReturnPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(WireSyncReturn)>>('free_WireSyncReturn');
+  late final _free_WireSyncReturn = _free_WireSyncReturnPtr.asFunction<void Function(WireSyncReturn)>();
+}
+
+final class _Dart_Handle extends ffi.Opaque {}
+
+final class wire_uint_8_list extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_ChainServiceConfig_Esplora extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> url;
+}
+
+final class wire_ChainServiceConfig_Electrum extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> url;
+}
+
+final class ChainServiceConfigKind extends ffi.Union {
+  external ffi.Pointer<wire_ChainServiceConfig_Esplora> Esplora;
+
+  external ffi.Pointer<wire_ChainServiceConfig_Electrum> Electrum;
+}
+
+final class wire_ChainServiceConfig extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<ChainServiceConfigKind> kind;
+}
+
+final class wire_BackupTransportConfig_S3 extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> endpoint;
+
+  external ffi.Pointer<wire_uint_8_list> region;
+
+  external ffi.Pointer<wire_uint_8_list> bucket;
+
+  external ffi.Pointer<wire_uint_8_list> access_key_id;
+
+  external ffi.Pointer<wire_uint_8_list> secret_access_key;
+}
+
+final class wire_BackupTransportConfig_WebDav extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> url;
+
+  external ffi.Pointer<wire_uint_8_list> username;
+
+  external ffi.Pointer<wire_uint_8_list> password;
+}
+
+final class BackupTransportConfigKind extends ffi.Union {
+  external ffi.Pointer<wire_BackupTransportConfig_S3> S3;
+
+  external ffi.Pointer<wire_BackupTransportConfig_WebDav> WebDav;
+}
+
+final class wire_BackupTransportConfig extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<BackupTransportConfigKind> kind;
+}
+
+final class wire_ProxyConfig extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> address;
+
+  external ffi.Pointer<wire_uint_8_list> username;
+
+  external ffi.Pointer<wire_uint_8_list> password;
+}
+
+final class wire_LspPolicy_LowestOpeningFee extends ffi.Struct {
+}
+
+final class wire_LspPolicy_LowestProportionalFee extends ffi.Struct {
+}
+
+final class wire_LspPolicy_Pinned extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> lsp_id;
+}
+
+final class LspPolicyKind extends ffi.Union {
+  external ffi.Pointer<wire_LspPolicy_LowestOpeningFee> LowestOpeningFee;
+
+  external ffi.Pointer<wire_LspPolicy_LowestProportionalFee> LowestProportionalFee;
+
+  external ffi.Pointer<wire_LspPolicy_Pinned> Pinned;
+}
+
+final class wire_LspPolicy extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<LspPolicyKind> kind;
+}
+
+final class wire_PartnerFeeDestination_NodeId extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> node_id;
+}
+
+final class wire_PartnerFeeDestination_LightningAddress extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> address;
+}
+
+final class PartnerFeeDestinationKind extends ffi.Union {
+  external ffi.Pointer<wire_PartnerFeeDestination_NodeId> NodeId;
+
+  external ffi.Pointer<wire_PartnerFeeDestination_LightningAddress> LightningAddress;
+}
+
+final class wire_PartnerFeeDestination extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<PartnerFeeDestinationKind> kind;
+}
+
+final class wire_PartnerFeeConfig extends ffi.Struct {
+  @ffi.Uint32()
+  external int fee_bps;
+
+  external wire_PartnerFeeDestination destination;
+}
+
+final class wire_AutoRefundConfig extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> to_address;
+
+  external ffi.Pointer<ffi.Uint32> sat_per_vbyte;
+}
+
+final class wire_StringList extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_uint_8_list>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_SpendPolicy extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> daily_limit_msat;
+
+  external ffi.Pointer<ffi.Uint64> per_payment_limit_msat;
+
+  external ffi.Pointer<wire_StringList> allowed_destinations;
+}
+
+final class wire_HtlcExposureLimits extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> max_inflight_msat;
+
+  external ffi.Pointer<ffi.Uint32> max_htlcs_per_payment;
+}
+
+final class wire_GreenlightCredentials extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> developer_key;
+
+  external ffi.Pointer<wire_uint_8_list> developer_cert;
+}
+
+final class wire_GreenlightNodeConfig extends ffi.Struct {
+  external ffi.Pointer<wire_GreenlightCredentials> partner_credentials;
+
+  external ffi.Pointer<wire_uint_8_list> invite_code;
+}
+
+final class wire_NodeConfig_Greenlight extends ffi.Struct {
+  external ffi.Pointer<wire_GreenlightNodeConfig> config;
+}
+
+final class wire_MockNodeConfig extends ffi.Struct {
+  @ffi.Uint64()
+  external int channels_balance_msat;
+
+  @ffi.Uint64()
+  external int onchain_balance_msat;
+
+  @ffi.Uint64()
+  external int inbound_liquidity_msat;
+}
+
+final class wire_NodeConfig_Mock extends ffi.Struct {
+  external ffi.Pointer<wire_MockNodeConfig> config;
+}
+
+final class NodeConfigKind extends ffi.Union {
+  external ffi.Pointer<wire_NodeConfig_Greenlight> Greenlight;
+
+  external ffi.Pointer<wire_NodeConfig_Mock> Mock;
+}
+
+final class wire_NodeConfig extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external ffi.Pointer<NodeConfigKind> kind;
+}
+
+final class wire_Config extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> breezserver;
+
+  external ffi.Pointer<wire_uint_8_list> chainnotifier_url;
+
+  external ffi.Pointer<wire_uint_8_list> lnurl_server_url;
+
+  external ffi.Pointer<wire_uint_8_list> mempoolspace_url;
+
+  external ffi.Pointer<wire_ChainServiceConfig> chain_service;
+
+  external ffi.Pointer<wire_BackupTransportConfig> backup_transport;
+
+  external ffi.Pointer<wire_ProxyConfig> proxy;
+
+  external ffi.Pointer<wire_uint_8_list> working_dir;
+
+  external ffi.Pointer<wire_uint_8_list> db_dir;
+
+  external ffi.Pointer<wire_uint_8_list> credentials_dir;
+
+  @ffi.Int32()
+  external int network;
+
+  @ffi.Uint32()
+  external int payment_timeout_sec;
+
+  @ffi.Uint32()
+  external int max_concurrent_payments;
+
+  external ffi.Pointer<wire_uint_8_list> default_lsp_id;
+
+  external ffi.Pointer<wire_LspPolicy> lsp_policy;
+
+  external ffi.Pointer<wire_uint_8_list> api_key;
+
+  @ffi.Double()
+  external double maxfee_percent;
+
+  @ffi.Uint64()
+  external int exemptfee_msat;
+
+  external ffi.Pointer<wire_uint_8_list> fiat_currency;
+
+  external ffi.Pointer<ffi.Uint64> low_outbound_liquidity_threshold_msat;
+
+  external ffi.Pointer<ffi.Uint64> low_inbound_liquidity_threshold_msat;
+
+  @ffi.Bool()
+  external bool use_trampoline;
+
+  external ffi.Pointer<wire_PartnerFeeConfig> partner_fee;
+
+  external ffi.Pointer<wire_AutoRefundConfig> swap_auto_refund;
+
+  @ffi.Int32()
+  external int memo_privacy;
+
+  external ffi.Pointer<ffi.Uint64> channel_hygiene_window_secs;
+
+  external ffi.Pointer<wire_uint_8_list> log_filter;
+
+  external ffi.Pointer<wire_uint_8_list> denylist_url;
+
+  external ffi.Pointer<wire_uint_8_list> transak_api_key;
+
+  external ffi.Pointer<wire_SpendPolicy> spend_policy;
+
+  external ffi.Pointer<wire_HtlcExposureLimits> htlc_exposure_limits;
+
+  external ffi.Pointer<ffi.Uint64> service_status_check_interval_secs;
+
+  external wire_NodeConfig node_config;
+}
+
+final class wire_ConnectRequest extends ffi.Struct {
+  external wire_Config config;
+
+  external ffi.Pointer<wire_uint_8_list> seed;
+
+  external ffi.Pointer<ffi.Bool> restore_only;
+
+  external ffi.Pointer<ffi.Bool> register_if_missing;
+}
+
+final class wire_ConfigureNodeRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> close_to_address;
+}
+
+final class wire_DeriveEncryptionKeyRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> purpose;
+
+  @ffi.Uint32()
+  external int index;
+}
+
+final class wire_ProveAddressOwnershipRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> address;
+
+  external ffi.Pointer<wire_uint_8_list> message;
 }
 
-final class _Dart_Handle extends ffi.Opaque {}
+final class wire_PaymentRequestBundle extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> invoice;
 
-final class wire_uint_8_list extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+  external ffi.Pointer<wire_uint_8_list> fallback;
 
-  @ffi.Int32()
-  external int len;
+  external ffi.Pointer<wire_uint_8_list> metadata;
 }
 
-final class wire_GreenlightCredentials extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> developer_key;
+final class wire_EncryptedPaymentRequestData extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> blob;
+}
 
-  external ffi.Pointer<wire_uint_8_list> developer_cert;
+final class wire_SignMessageRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> message;
 }
 
-final class wire_GreenlightNodeConfig extends ffi.Struct {
-  external ffi.Pointer<wire_GreenlightCredentials> partner_credentials;
+final class wire_CheckMessageRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> message;
 
-  external ffi.Pointer<wire_uint_8_list> invite_code;
+  external ffi.Pointer<wire_uint_8_list> pubkey;
+
+  external ffi.Pointer<wire_uint_8_list> signature;
 }
 
-final class wire_NodeConfig_Greenlight extends ffi.Struct {
-  external ffi.Pointer<wire_GreenlightNodeConfig> config;
+final class wire_DecryptRecoveryBundleRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> bundle;
+
+  external ffi.Pointer<wire_uint_8_list> private_key;
 }
 
-final class NodeConfigKind extends ffi.Union {
-  external ffi.Pointer<wire_NodeConfig_Greenlight> Greenlight;
+final class wire_ExportRecoveryBundleRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> recipient_pubkey;
+
+  external ffi.Pointer<wire_uint_8_list> instructions;
+
+  external ffi.Pointer<wire_uint_8_list> file_path;
 }
 
-final class wire_NodeConfig extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+final class wire_StaticBackupRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> working_dir;
+}
 
-  external ffi.Pointer<NodeConfigKind> kind;
+final class wire_UserSettings extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> preferred_fiat_currency;
+
+  external ffi.Pointer<ffi.Int32> denomination;
+
+  external ffi.Pointer<ffi.Int32> default_feerate_preset;
+
+  external ffi.Pointer<wire_uint_8_list> preferred_lsp_id;
 }
 
-final class wire_Config extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> breezserver;
+final class wire_CloseChannelRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> channel_id;
 
-  external ffi.Pointer<wire_uint_8_list> chainnotifier_url;
+  @ffi.Bool()
+  external bool force;
+}
 
-  external ffi.Pointer<wire_uint_8_list> mempoolspace_url;
+final class wire_ConnectPeerRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> node_uri;
+}
 
-  external ffi.Pointer<wire_uint_8_list> working_dir;
+final class wire_Sat extends ffi.Struct {
+  @ffi.Uint64()
+  external int field0;
+}
 
-  @ffi.Int32()
-  external int network;
+final class wire_OpenChannelRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> node_id;
 
-  @ffi.Uint32()
-  external int payment_timeout_sec;
+  external wire_Sat amount_sat;
 
-  external ffi.Pointer<wire_uint_8_list> default_lsp_id;
+  @ffi.Bool()
+  external bool announce;
 
-  external ffi.Pointer<wire_uint_8_list> api_key;
+  external ffi.Pointer<ffi.Uint32> sat_per_vbyte;
+}
 
-  @ffi.Double()
-  external double maxfee_percent;
+final class wire_NodeMigrationRequest extends ffi.Struct {
+  @ffi.Bool()
+  external bool close_channels;
+}
 
+final class wire_MilliSat extends ffi.Struct {
   @ffi.Uint64()
-  external int exemptfee_msat;
-
-  external wire_NodeConfig node_config;
+  external int field0;
 }
 
-final class wire_ConnectRequest extends ffi.Struct {
-  external wire_Config config;
+final class wire_CreateStaticLnurlPayRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> url;
 
-  external ffi.Pointer<wire_uint_8_list> seed;
+  external ffi.Pointer<wire_uint_8_list> callback_url;
 
-  external ffi.Pointer<ffi.Bool> restore_only;
-}
+  external ffi.Pointer<wire_uint_8_list> description;
 
-final class wire_ConfigureNodeRequest extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> close_to_address;
+  external wire_MilliSat min_sendable_msat;
+
+  external wire_MilliSat max_sendable_msat;
+
+  external ffi.Pointer<ffi.Uint16> comment_allowed;
 }
 
-final class wire_SignMessageRequest extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> message;
+final class wire_StaticLnurlPayCallbackRequest extends ffi.Struct {
+  external wire_MilliSat amount_msat;
+
+  external ffi.Pointer<wire_uint_8_list> comment;
 }
 
-final class wire_CheckMessageRequest extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> message;
+final class wire_CreateLnurlWithdrawVoucherRequest extends ffi.Struct {
+  external wire_MilliSat amount_msat;
 
-  external ffi.Pointer<wire_uint_8_list> pubkey;
+  @ffi.Uint32()
+  external int uses;
 
-  external ffi.Pointer<wire_uint_8_list> signature;
+  @ffi.Uint32()
+  external int expiry_secs;
+
+  external ffi.Pointer<wire_uint_8_list> description;
 }
 
-final class wire_StaticBackupRequest extends ffi.Struct {
-  external ffi.Pointer<wire_uint_8_list> working_dir;
+final class wire_RedeemLnurlWithdrawVoucherRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> id;
+
+  external ffi.Pointer<wire_uint_8_list> bolt11;
 }
 
 final class wire_list_payment_type_filter extends ffi.Struct {
@@ -6528,17 +15374,84 @@ final class wire_ListPaymentsRequest extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> offset;
 
   external ffi.Pointer<ffi.Uint32> limit;
+
+  external ffi.Pointer<ffi.Bool> include_tlv_hex;
+
+  external ffi.Pointer<ffi.Int32> sort_order;
+}
+
+final class wire_ListPaymentGroupsRequest extends ffi.Struct {
+  @ffi.Int32()
+  external int group_by;
+
+  external wire_ListPaymentsRequest payments;
+
+  external ffi.Pointer<ffi.Uint32> limit;
+}
+
+final class wire_ExportPaymentsRequest extends ffi.Struct {
+  @ffi.Int32()
+  external int format;
+
+  external ffi.Pointer<ffi.Int64> from_timestamp;
+
+  external ffi.Pointer<ffi.Int64> to_timestamp;
+
+  external ffi.Pointer<wire_uint_8_list> include_fiat;
+}
+
+final class wire_ImportPaymentsRequest extends ffi.Struct {
+  @ffi.Int32()
+  external int format;
+
+  external ffi.Pointer<wire_uint_8_list> data;
 }
 
 final class wire_SendPaymentRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> bolt11;
 
-  @ffi.Bool()
-  external bool use_trampoline;
+  external ffi.Pointer<ffi.Bool> use_trampoline;
 
-  external ffi.Pointer<ffi.Uint64> amount_msat;
+  external ffi.Pointer<wire_MilliSat> amount_msat;
 
   external ffi.Pointer<wire_uint_8_list> label;
+
+  external ffi.Pointer<ffi.Uint32> route_hint_index;
+
+  external ffi.Pointer<ffi.Uint32> payment_timeout_sec;
+
+  external ffi.Pointer<ffi.Double> maxfee_percent;
+
+  external ffi.Pointer<wire_MilliSat> exemptfee_msat;
+
+  external ffi.Pointer<wire_uint_8_list> cancel_token;
+
+  external ffi.Pointer<ffi.Bool> skip_denylist;
+}
+
+final class wire_list_send_payment_request extends ffi.Struct {
+  external ffi.Pointer<wire_SendPaymentRequest> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_SendPaymentsRequest extends ffi.Struct {
+  external ffi.Pointer<wire_list_send_payment_request> payments;
+
+  external ffi.Pointer<ffi.Uint32> max_concurrency;
+}
+
+final class wire_QueuePaymentRequest extends ffi.Struct {
+  external wire_SendPaymentRequest req;
+
+  external ffi.Pointer<ffi.Uint64> expiry_secs;
+}
+
+final class wire_PrepareSendPaymentRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> bolt11;
+
+  external ffi.Pointer<wire_MilliSat> amount_msat;
 }
 
 final class wire_TlvEntry extends ffi.Struct {
@@ -6558,14 +15471,35 @@ final class wire_list_tlv_entry extends ffi.Struct {
 final class wire_SendSpontaneousPaymentRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> node_id;
 
-  @ffi.Uint64()
-  external int amount_msat;
+  external wire_MilliSat amount_msat;
 
   external ffi.Pointer<wire_list_tlv_entry> extra_tlvs;
 
   external ffi.Pointer<wire_uint_8_list> label;
 }
 
+final class wire_ProbePaymentRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> destination;
+
+  external ffi.Pointer<wire_MilliSat> amount_msat;
+}
+
+final class wire_ValidatePayabilityRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> bolt11;
+
+  external ffi.Pointer<wire_MilliSat> amount_msat;
+}
+
+final class wire_PayOfferRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> offer;
+
+  external ffi.Pointer<wire_MilliSat> amount_msat;
+
+  external ffi.Pointer<wire_uint_8_list> payer_note;
+
+  external ffi.Pointer<wire_uint_8_list> label;
+}
+
 final class wire_OpeningFeeParams extends ffi.Struct {
   @ffi.Uint64()
   external int min_msat;
@@ -6584,9 +15518,23 @@ final class wire_OpeningFeeParams extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> promise;
 }
 
+final class wire_PrepareReceivePaymentRequest extends ffi.Struct {
+  external wire_MilliSat amount_msat;
+
+  external ffi.Pointer<wire_OpeningFeeParams> opening_fee_params;
+
+  external ffi.Pointer<ffi.Uint32> expiry;
+}
+
+final class wire_FiatAmount extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> currency;
+
+  @ffi.Double()
+  external double amount;
+}
+
 final class wire_ReceivePaymentRequest extends ffi.Struct {
-  @ffi.Uint64()
-  external int amount_msat;
+  external wire_MilliSat amount_msat;
 
   external ffi.Pointer<wire_uint_8_list> description;
 
@@ -6599,6 +15547,67 @@ final class wire_ReceivePaymentRequest extends ffi.Struct {
   external ffi.Pointer<ffi.Uint32> expiry;
 
   external ffi.Pointer<ffi.Uint32> cltv;
+
+  external ffi.Pointer<ffi.Bool> include_fallback_address;
+
+  external ffi.Pointer<ffi.Uint32> max_route_hints;
+
+  external ffi.Pointer<wire_FiatAmount> fiat_amount;
+}
+
+final class wire_ReceiveUnifiedRequest extends ffi.Struct {
+  external wire_Sat amount_sat;
+
+  external ffi.Pointer<wire_uint_8_list> description;
+
+  external ffi.Pointer<wire_OpeningFeeParams> opening_fee_params;
+}
+
+final class wire_RouteHintHop extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> src_node_id;
+
+  external ffi.Pointer<wire_uint_8_list> short_channel_id;
+
+  @ffi.Uint32()
+  external int fees_base_msat;
+
+  @ffi.Uint32()
+  external int fees_proportional_millionths;
+
+  @ffi.Uint64()
+  external int cltv_expiry_delta;
+
+  external ffi.Pointer<ffi.Uint64> htlc_minimum_msat;
+
+  external ffi.Pointer<ffi.Uint64> htlc_maximum_msat;
+}
+
+final class wire_list_route_hint_hop extends ffi.Struct {
+  external ffi.Pointer<wire_RouteHintHop> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_RouteHint extends ffi.Struct {
+  external ffi.Pointer<wire_list_route_hint_hop> hops;
+}
+
+final class wire_list_route_hint extends ffi.Struct {
+  external ffi.Pointer<wire_RouteHint> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_AmendInvoiceRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> bolt11;
+
+  external ffi.Pointer<wire_MilliSat> amount_msat;
+
+  external ffi.Pointer<ffi.Uint32> expiry;
+
+  external ffi.Pointer<wire_list_route_hint> route_hints;
 }
 
 final class wire_LnUrlPayRequestData extends ffi.Struct {
@@ -6639,6 +15648,12 @@ final class wire_LnUrlPayRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> payment_label;
 
   external ffi.Pointer<ffi.Bool> validate_success_action_url;
+
+  external ffi.Pointer<ffi.Uint32> pending_timeout_sec;
+
+  external ffi.Pointer<wire_uint_8_list> cancel_token;
+
+  external ffi.Pointer<ffi.Bool> skip_denylist;
 }
 
 final class wire_LnUrlWithdrawRequestData extends ffi.Struct {
@@ -6674,6 +15689,34 @@ final class wire_LnUrlAuthRequestData extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> url;
 }
 
+final class wire_LnUrlAuthDomain extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> domain;
+
+  external ffi.Pointer<wire_uint_8_list> linking_key;
+
+  @ffi.Int64()
+  external int first_authenticated_at;
+
+  @ffi.Int64()
+  external int last_authenticated_at;
+
+  @ffi.Uint32()
+  external int auth_count;
+}
+
+final class wire_list_ln_url_auth_domain extends ffi.Struct {
+  external ffi.Pointer<wire_LnUrlAuthDomain> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_LnUrlAuthExport extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> hashing_key;
+
+  external ffi.Pointer<wire_list_ln_url_auth_domain> domains;
+}
+
 final class wire_ReportPaymentFailureDetails extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> payment_hash;
 
@@ -6695,6 +15738,16 @@ final class wire_ReportIssueRequest extends ffi.Struct {
   external ffi.Pointer<ReportIssueRequestKind> kind;
 }
 
+final class wire_AddFiatRateAlertRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> currency;
+
+  @ffi.Int32()
+  external int direction;
+
+  @ffi.Double()
+  external double threshold;
+}
+
 final class wire_PrepareOnchainPaymentResponse extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> fees_hash;
 
@@ -6707,11 +15760,9 @@ final class wire_PrepareOnchainPaymentResponse extends ffi.Struct {
   @ffi.Uint64()
   external int fees_claim;
 
-  @ffi.Uint64()
-  external int sender_amount_sat;
+  external wire_Sat sender_amount_sat;
 
-  @ffi.Uint64()
-  external int recipient_amount_sat;
+  external wire_Sat recipient_amount_sat;
 
   @ffi.Uint64()
   external int total_fees;
@@ -6721,6 +15772,19 @@ final class wire_PayOnchainRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> recipient_address;
 
   external wire_PrepareOnchainPaymentResponse prepare_res;
+
+  external ffi.Pointer<wire_uint_8_list> cancel_token;
+}
+
+final class wire_PayOnchainAddressRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> address;
+
+  external ffi.Pointer<wire_Sat> amount_sat;
+
+  @ffi.Bool()
+  external bool drain;
+
+  external ffi.Pointer<ffi.Int32> fee_preference;
 }
 
 final class wire_ReceiveOnchainRequest extends ffi.Struct {
@@ -6736,11 +15800,27 @@ final class wire_BuyBitcoinRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> redirect_url;
 }
 
+final class wire_FetchBuyBitcoinQuoteRequest extends ffi.Struct {
+  @ffi.Int32()
+  external int provider;
+
+  external wire_FiatAmount fiat_amount;
+}
+
 final class wire_RedeemOnchainFundsRequest extends ffi.Struct {
   external ffi.Pointer<wire_uint_8_list> to_address;
 
   @ffi.Uint32()
   external int sat_per_vbyte;
+
+  external ffi.Pointer<wire_Sat> amount_sat;
+}
+
+final class wire_BumpFeeRequest extends ffi.Struct {
+  external ffi.Pointer<wire_uint_8_list> txid;
+
+  @ffi.Uint32()
+  external int sat_per_vbyte;
 }
 
 final class wire_PrepareRedeemOnchainFundsRequest extends ffi.Struct {
@@ -6748,6 +15828,12 @@ final class wire_PrepareRedeemOnchainFundsRequest extends ffi.Struct {
 
   @ffi.Uint32()
   external int sat_per_vbyte;
+
+  external ffi.Pointer<wire_Sat> amount_sat;
+}
+
+final class wire_ExportSwapRefundKeysRequest extends ffi.Struct {
+  external ffi.Pointer<ffi.Bool> include_gap_keys;
 }
 
 final class wire_PrepareRefundRequest extends ffi.Struct {
@@ -6759,6 +15845,8 @@ final class wire_PrepareRefundRequest extends ffi.Struct {
   external int sat_per_vbyte;
 
   external ffi.Pointer<ffi.Bool> unilateral;
+
+  external ffi.Pointer<ffi.Bool> include_fee_previews;
 }
 
 final class wire_RefundRequest extends ffi.Struct {
@@ -6792,20 +15880,19 @@ final class wire_ListSwapsRequest extends ffi.Struct {
 }
 
 final class wire_OpenChannelFeeRequest extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint64> amount_msat;
+  external ffi.Pointer<wire_MilliSat> amount_msat;
 
   external ffi.Pointer<ffi.Uint32> expiry;
 }
 
 final class wire_ReverseSwapFeesRequest extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint64> send_amount_sat;
+  external ffi.Pointer<wire_Sat> send_amount_sat;
 
   external ffi.Pointer<ffi.Uint32> claim_tx_feerate;
 }
 
 final class wire_PrepareOnchainPaymentRequest extends ffi.Struct {
-  @ffi.Uint64()
-  external int amount_sat;
+  external wire_Sat amount_sat;
 
   @ffi.Int32()
   external int amount_type;
@@ -6822,6 +15909,14 @@ const int SWAP_PAYMENT_FEE_EXPIRY_SECONDS = 172800;
 
 const int INVOICE_PAYMENT_FEE_EXPIRY_SECONDS = 3600;
 
+const int DEFAULT_MAX_ROUTE_HINTS = 3;
+
+const int DEFAULT_OUTBOX_EXPIRY_SECS = 86400;
+
+const int DEFAULT_SEND_PAYMENTS_CONCURRENCY = 4;
+
+const int MAX_PAYMENT_AMOUNT_MSAT = 4294967000;
+
 const int ESTIMATED_CLAIM_TX_VSIZE = 138;
 
 const int ESTIMATED_LOCKUP_TX_VSIZE = 153;
@@ -348,104 +348,55 @@ abstract class AesSuccessActionDataResult_ErrorStatus implements AesSuccessActio
 }
 
 /// @nodoc
-mixin _$BreezEvent {
+mixin _$Amount {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
-    required TResult Function() backupStarted,
-    required TResult Function() backupSucceeded,
-    required TResult Function(BackupFailedData details) backupFailed,
-    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
-    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(int amountMsat) bitcoin,
+    required TResult Function(String iso4217Code, int fractionalAmount) currency,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
-    TResult? Function()? backupStarted,
-    TResult? Function()? backupSucceeded,
-    TResult? Function(BackupFailedData details)? backupFailed,
-    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(int amountMsat)? bitcoin,
+    TResult? Function(String iso4217Code, int fractionalAmount)? currency,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
-    TResult Function()? backupStarted,
-    TResult Function()? backupSucceeded,
-    TResult Function(BackupFailedData details)? backupFailed,
-    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(int amountMsat)? bitcoin,
+    TResult Function(String iso4217Code, int fractionalAmount)? currency,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
-    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
-    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
-    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
-    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
-    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(Amount_Bitcoin value) bitcoin,
+    required TResult Function(Amount_Currency value) currency,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(Amount_Bitcoin value)? bitcoin,
+    TResult? Function(Amount_Currency value)? currency,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(Amount_Bitcoin value)? bitcoin,
+    TResult Function(Amount_Currency value)? currency,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $BreezEventCopyWith<$Res> {
-  factory $BreezEventCopyWith(BreezEvent value, $Res Function(BreezEvent) then) =
-      _$BreezEventCopyWithImpl<$Res, BreezEvent>;
+abstract class $AmountCopyWith<$Res> {
+  factory $AmountCopyWith(Amount value, $Res Function(Amount) then) = _$AmountCopyWithImpl<$Res, Amount>;
 }
 
 /// @nodoc
-class _$BreezEventCopyWithImpl<$Res, $Val extends BreezEvent> implements $BreezEventCopyWith<$Res> {
-  _$BreezEventCopyWithImpl(this._value, this._then);
+class _$AmountCopyWithImpl<$Res, $Val extends Amount> implements $AmountCopyWith<$Res> {
+  _$AmountCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -454,31 +405,29 @@ class _$BreezEventCopyWithImpl<$Res, $Val extends BreezEvent> implements $BreezE
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_NewBlockImplCopyWith<$Res> {
-  factory _$$BreezEvent_NewBlockImplCopyWith(
-          _$BreezEvent_NewBlockImpl value, $Res Function(_$BreezEvent_NewBlockImpl) then) =
-      __$$BreezEvent_NewBlockImplCopyWithImpl<$Res>;
+abstract class _$$Amount_BitcoinImplCopyWith<$Res> {
+  factory _$$Amount_BitcoinImplCopyWith(
+          _$Amount_BitcoinImpl value, $Res Function(_$Amount_BitcoinImpl) then) =
+      __$$Amount_BitcoinImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({int block});
+  $Res call({int amountMsat});
 }
 
 /// @nodoc
-class __$$BreezEvent_NewBlockImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_NewBlockImpl>
-    implements _$$BreezEvent_NewBlockImplCopyWith<$Res> {
-  __$$BreezEvent_NewBlockImplCopyWithImpl(
-      _$BreezEvent_NewBlockImpl _value, $Res Function(_$BreezEvent_NewBlockImpl) _then)
+class __$$Amount_BitcoinImplCopyWithImpl<$Res> extends _$AmountCopyWithImpl<$Res, _$Amount_BitcoinImpl>
+    implements _$$Amount_BitcoinImplCopyWith<$Res> {
+  __$$Amount_BitcoinImplCopyWithImpl(_$Amount_BitcoinImpl _value, $Res Function(_$Amount_BitcoinImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? block = null,
+    Object? amountMsat = null,
   }) {
-    return _then(_$BreezEvent_NewBlockImpl(
-      block: null == block
-          ? _value.block
-          : block // ignore: cast_nullable_to_non_nullable
+    return _then(_$Amount_BitcoinImpl(
+      amountMsat: null == amountMsat
+          ? _value.amountMsat
+          : amountMsat // ignore: cast_nullable_to_non_nullable
               as int,
     ));
   }
@@ -486,85 +435,61 @@ class __$$BreezEvent_NewBlockImplCopyWithImpl<$Res>
 
 /// @nodoc
 
-class _$BreezEvent_NewBlockImpl implements BreezEvent_NewBlock {
-  const _$BreezEvent_NewBlockImpl({required this.block});
+class _$Amount_BitcoinImpl implements Amount_Bitcoin {
+  const _$Amount_BitcoinImpl({required this.amountMsat});
 
   @override
-  final int block;
+  final int amountMsat;
 
   @override
   String toString() {
-    return 'BreezEvent.newBlock(block: $block)';
+    return 'Amount.bitcoin(amountMsat: $amountMsat)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_NewBlockImpl &&
-            (identical(other.block, block) || other.block == block));
+            other is _$Amount_BitcoinImpl &&
+            (identical(other.amountMsat, amountMsat) || other.amountMsat == amountMsat));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, block);
+  int get hashCode => Object.hash(runtimeType, amountMsat);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_NewBlockImplCopyWith<_$BreezEvent_NewBlockImpl> get copyWith =>
-      __$$BreezEvent_NewBlockImplCopyWithImpl<_$BreezEvent_NewBlockImpl>(this, _$identity);
+  _$$Amount_BitcoinImplCopyWith<_$Amount_BitcoinImpl> get copyWith =>
+      __$$Amount_BitcoinImplCopyWithImpl<_$Amount_BitcoinImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
-    required TResult Function() backupStarted,
-    required TResult Function() backupSucceeded,
-    required TResult Function(BackupFailedData details) backupFailed,
-    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
-    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(int amountMsat) bitcoin,
+    required TResult Function(String iso4217Code, int fractionalAmount) currency,
   }) {
-    return newBlock(block);
+    return bitcoin(amountMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
-    TResult? Function()? backupStarted,
-    TResult? Function()? backupSucceeded,
-    TResult? Function(BackupFailedData details)? backupFailed,
-    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(int amountMsat)? bitcoin,
+    TResult? Function(String iso4217Code, int fractionalAmount)? currency,
   }) {
-    return newBlock?.call(block);
+    return bitcoin?.call(amountMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
-    TResult Function()? backupStarted,
-    TResult Function()? backupSucceeded,
-    TResult Function(BackupFailedData details)? backupFailed,
-    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(int amountMsat)? bitcoin,
+    TResult Function(String iso4217Code, int fractionalAmount)? currency,
     required TResult orElse(),
   }) {
-    if (newBlock != null) {
-      return newBlock(block);
+    if (bitcoin != null) {
+      return bitcoin(amountMsat);
     }
     return orElse();
   }
@@ -572,180 +497,139 @@ class _$BreezEvent_NewBlockImpl implements BreezEvent_NewBlock {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
-    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
-    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
-    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
-    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
-    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(Amount_Bitcoin value) bitcoin,
+    required TResult Function(Amount_Currency value) currency,
   }) {
-    return newBlock(this);
+    return bitcoin(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(Amount_Bitcoin value)? bitcoin,
+    TResult? Function(Amount_Currency value)? currency,
   }) {
-    return newBlock?.call(this);
+    return bitcoin?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(Amount_Bitcoin value)? bitcoin,
+    TResult Function(Amount_Currency value)? currency,
     required TResult orElse(),
   }) {
-    if (newBlock != null) {
-      return newBlock(this);
+    if (bitcoin != null) {
+      return bitcoin(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_NewBlock implements BreezEvent {
-  const factory BreezEvent_NewBlock({required final int block}) = _$BreezEvent_NewBlockImpl;
+abstract class Amount_Bitcoin implements Amount {
+  const factory Amount_Bitcoin({required final int amountMsat}) = _$Amount_BitcoinImpl;
 
-  int get block;
+  int get amountMsat;
   @JsonKey(ignore: true)
-  _$$BreezEvent_NewBlockImplCopyWith<_$BreezEvent_NewBlockImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$Amount_BitcoinImplCopyWith<_$Amount_BitcoinImpl> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_InvoicePaidImplCopyWith<$Res> {
-  factory _$$BreezEvent_InvoicePaidImplCopyWith(
-          _$BreezEvent_InvoicePaidImpl value, $Res Function(_$BreezEvent_InvoicePaidImpl) then) =
-      __$$BreezEvent_InvoicePaidImplCopyWithImpl<$Res>;
+abstract class _$$Amount_CurrencyImplCopyWith<$Res> {
+  factory _$$Amount_CurrencyImplCopyWith(
+          _$Amount_CurrencyImpl value, $Res Function(_$Amount_CurrencyImpl) then) =
+      __$$Amount_CurrencyImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({InvoicePaidDetails details});
+  $Res call({String iso4217Code, int fractionalAmount});
 }
 
 /// @nodoc
-class __$$BreezEvent_InvoicePaidImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_InvoicePaidImpl>
-    implements _$$BreezEvent_InvoicePaidImplCopyWith<$Res> {
-  __$$BreezEvent_InvoicePaidImplCopyWithImpl(
-      _$BreezEvent_InvoicePaidImpl _value, $Res Function(_$BreezEvent_InvoicePaidImpl) _then)
+class __$$Amount_CurrencyImplCopyWithImpl<$Res> extends _$AmountCopyWithImpl<$Res, _$Amount_CurrencyImpl>
+    implements _$$Amount_CurrencyImplCopyWith<$Res> {
+  __$$Amount_CurrencyImplCopyWithImpl(
+      _$Amount_CurrencyImpl _value, $Res Function(_$Amount_CurrencyImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? iso4217Code = null,
+    Object? fractionalAmount = null,
   }) {
-    return _then(_$BreezEvent_InvoicePaidImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as InvoicePaidDetails,
+    return _then(_$Amount_CurrencyImpl(
+      iso4217Code: null == iso4217Code
+          ? _value.iso4217Code
+          : iso4217Code // ignore: cast_nullable_to_non_nullable
+              as String,
+      fractionalAmount: null == fractionalAmount
+          ? _value.fractionalAmount
+          : fractionalAmount // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_InvoicePaidImpl implements BreezEvent_InvoicePaid {
-  const _$BreezEvent_InvoicePaidImpl({required this.details});
+class _$Amount_CurrencyImpl implements Amount_Currency {
+  const _$Amount_CurrencyImpl({required this.iso4217Code, required this.fractionalAmount});
 
   @override
-  final InvoicePaidDetails details;
+  final String iso4217Code;
+  @override
+  final int fractionalAmount;
 
   @override
   String toString() {
-    return 'BreezEvent.invoicePaid(details: $details)';
+    return 'Amount.currency(iso4217Code: $iso4217Code, fractionalAmount: $fractionalAmount)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_InvoicePaidImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$Amount_CurrencyImpl &&
+            (identical(other.iso4217Code, iso4217Code) || other.iso4217Code == iso4217Code) &&
+            (identical(other.fractionalAmount, fractionalAmount) ||
+                other.fractionalAmount == fractionalAmount));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, iso4217Code, fractionalAmount);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_InvoicePaidImplCopyWith<_$BreezEvent_InvoicePaidImpl> get copyWith =>
-      __$$BreezEvent_InvoicePaidImplCopyWithImpl<_$BreezEvent_InvoicePaidImpl>(this, _$identity);
+  _$$Amount_CurrencyImplCopyWith<_$Amount_CurrencyImpl> get copyWith =>
+      __$$Amount_CurrencyImplCopyWithImpl<_$Amount_CurrencyImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
-    required TResult Function() backupStarted,
-    required TResult Function() backupSucceeded,
-    required TResult Function(BackupFailedData details) backupFailed,
-    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
-    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(int amountMsat) bitcoin,
+    required TResult Function(String iso4217Code, int fractionalAmount) currency,
   }) {
-    return invoicePaid(details);
+    return currency(iso4217Code, fractionalAmount);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
-    TResult? Function()? backupStarted,
-    TResult? Function()? backupSucceeded,
-    TResult? Function(BackupFailedData details)? backupFailed,
-    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(int amountMsat)? bitcoin,
+    TResult? Function(String iso4217Code, int fractionalAmount)? currency,
   }) {
-    return invoicePaid?.call(details);
+    return currency?.call(iso4217Code, fractionalAmount);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
-    TResult Function()? backupStarted,
-    TResult Function()? backupSucceeded,
-    TResult Function(BackupFailedData details)? backupFailed,
-    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(int amountMsat)? bitcoin,
+    TResult Function(String iso4217Code, int fractionalAmount)? currency,
     required TResult orElse(),
   }) {
-    if (invoicePaid != null) {
-      return invoicePaid(details);
+    if (currency != null) {
+      return currency(iso4217Code, fractionalAmount);
     }
     return orElse();
   }
@@ -753,154 +637,243 @@ class _$BreezEvent_InvoicePaidImpl implements BreezEvent_InvoicePaid {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
-    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
-    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
-    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
-    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
-    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(Amount_Bitcoin value) bitcoin,
+    required TResult Function(Amount_Currency value) currency,
   }) {
-    return invoicePaid(this);
+    return currency(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(Amount_Bitcoin value)? bitcoin,
+    TResult? Function(Amount_Currency value)? currency,
   }) {
-    return invoicePaid?.call(this);
+    return currency?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(Amount_Bitcoin value)? bitcoin,
+    TResult Function(Amount_Currency value)? currency,
     required TResult orElse(),
   }) {
-    if (invoicePaid != null) {
-      return invoicePaid(this);
+    if (currency != null) {
+      return currency(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_InvoicePaid implements BreezEvent {
-  const factory BreezEvent_InvoicePaid({required final InvoicePaidDetails details}) =
-      _$BreezEvent_InvoicePaidImpl;
+abstract class Amount_Currency implements Amount {
+  const factory Amount_Currency({required final String iso4217Code, required final int fractionalAmount}) =
+      _$Amount_CurrencyImpl;
 
-  InvoicePaidDetails get details;
+  String get iso4217Code;
+  int get fractionalAmount;
   @JsonKey(ignore: true)
-  _$$BreezEvent_InvoicePaidImplCopyWith<_$BreezEvent_InvoicePaidImpl> get copyWith =>
+  _$$Amount_CurrencyImplCopyWith<_$Amount_CurrencyImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$BackupTransportConfig {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)
+        s3,
+    required TResult Function(String url, String? username, String? password) webDav,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)?
+        s3,
+    TResult? Function(String url, String? username, String? password)? webDav,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)?
+        s3,
+    TResult Function(String url, String? username, String? password)? webDav,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BackupTransportConfig_S3 value) s3,
+    required TResult Function(BackupTransportConfig_WebDav value) webDav,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BackupTransportConfig_S3 value)? s3,
+    TResult? Function(BackupTransportConfig_WebDav value)? webDav,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BackupTransportConfig_S3 value)? s3,
+    TResult Function(BackupTransportConfig_WebDav value)? webDav,
+    required TResult orElse(),
+  }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_SyncedImplCopyWith<$Res> {
-  factory _$$BreezEvent_SyncedImplCopyWith(
-          _$BreezEvent_SyncedImpl value, $Res Function(_$BreezEvent_SyncedImpl) then) =
-      __$$BreezEvent_SyncedImplCopyWithImpl<$Res>;
+abstract class $BackupTransportConfigCopyWith<$Res> {
+  factory $BackupTransportConfigCopyWith(
+          BackupTransportConfig value, $Res Function(BackupTransportConfig) then) =
+      _$BackupTransportConfigCopyWithImpl<$Res, BackupTransportConfig>;
 }
 
 /// @nodoc
-class __$$BreezEvent_SyncedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SyncedImpl>
-    implements _$$BreezEvent_SyncedImplCopyWith<$Res> {
-  __$$BreezEvent_SyncedImplCopyWithImpl(
-      _$BreezEvent_SyncedImpl _value, $Res Function(_$BreezEvent_SyncedImpl) _then)
+class _$BackupTransportConfigCopyWithImpl<$Res, $Val extends BackupTransportConfig>
+    implements $BackupTransportConfigCopyWith<$Res> {
+  _$BackupTransportConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$BackupTransportConfig_S3ImplCopyWith<$Res> {
+  factory _$$BackupTransportConfig_S3ImplCopyWith(
+          _$BackupTransportConfig_S3Impl value, $Res Function(_$BackupTransportConfig_S3Impl) then) =
+      __$$BackupTransportConfig_S3ImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey});
+}
+
+/// @nodoc
+class __$$BackupTransportConfig_S3ImplCopyWithImpl<$Res>
+    extends _$BackupTransportConfigCopyWithImpl<$Res, _$BackupTransportConfig_S3Impl>
+    implements _$$BackupTransportConfig_S3ImplCopyWith<$Res> {
+  __$$BackupTransportConfig_S3ImplCopyWithImpl(
+      _$BackupTransportConfig_S3Impl _value, $Res Function(_$BackupTransportConfig_S3Impl) _then)
       : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? endpoint = null,
+    Object? region = null,
+    Object? bucket = null,
+    Object? accessKeyId = null,
+    Object? secretAccessKey = null,
+  }) {
+    return _then(_$BackupTransportConfig_S3Impl(
+      endpoint: null == endpoint
+          ? _value.endpoint
+          : endpoint // ignore: cast_nullable_to_non_nullable
+              as String,
+      region: null == region
+          ? _value.region
+          : region // ignore: cast_nullable_to_non_nullable
+              as String,
+      bucket: null == bucket
+          ? _value.bucket
+          : bucket // ignore: cast_nullable_to_non_nullable
+              as String,
+      accessKeyId: null == accessKeyId
+          ? _value.accessKeyId
+          : accessKeyId // ignore: cast_nullable_to_non_nullable
+              as String,
+      secretAccessKey: null == secretAccessKey
+          ? _value.secretAccessKey
+          : secretAccessKey // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$BreezEvent_SyncedImpl implements BreezEvent_Synced {
-  const _$BreezEvent_SyncedImpl();
+class _$BackupTransportConfig_S3Impl implements BackupTransportConfig_S3 {
+  const _$BackupTransportConfig_S3Impl(
+      {required this.endpoint,
+      required this.region,
+      required this.bucket,
+      required this.accessKeyId,
+      required this.secretAccessKey});
+
+  /// For example `https://s3.eu-west-1.amazonaws.com`
+  @override
+  final String endpoint;
+  @override
+  final String region;
+  @override
+  final String bucket;
+  @override
+  final String accessKeyId;
+  @override
+  final String secretAccessKey;
 
   @override
   String toString() {
-    return 'BreezEvent.synced()';
+    return 'BackupTransportConfig.s3(endpoint: $endpoint, region: $region, bucket: $bucket, accessKeyId: $accessKeyId, secretAccessKey: $secretAccessKey)';
   }
 
   @override
   bool operator ==(Object other) {
-    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BreezEvent_SyncedImpl);
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BackupTransportConfig_S3Impl &&
+            (identical(other.endpoint, endpoint) || other.endpoint == endpoint) &&
+            (identical(other.region, region) || other.region == region) &&
+            (identical(other.bucket, bucket) || other.bucket == bucket) &&
+            (identical(other.accessKeyId, accessKeyId) || other.accessKeyId == accessKeyId) &&
+            (identical(other.secretAccessKey, secretAccessKey) || other.secretAccessKey == secretAccessKey));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, endpoint, region, bucket, accessKeyId, secretAccessKey);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BackupTransportConfig_S3ImplCopyWith<_$BackupTransportConfig_S3Impl> get copyWith =>
+      __$$BackupTransportConfig_S3ImplCopyWithImpl<_$BackupTransportConfig_S3Impl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
-    required TResult Function() backupStarted,
-    required TResult Function() backupSucceeded,
-    required TResult Function(BackupFailedData details) backupFailed,
-    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
-    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)
+        s3,
+    required TResult Function(String url, String? username, String? password) webDav,
   }) {
-    return synced();
+    return s3(endpoint, region, bucket, accessKeyId, secretAccessKey);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
-    TResult? Function()? backupStarted,
-    TResult? Function()? backupSucceeded,
-    TResult? Function(BackupFailedData details)? backupFailed,
-    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)?
+        s3,
+    TResult? Function(String url, String? username, String? password)? webDav,
   }) {
-    return synced?.call();
+    return s3?.call(endpoint, region, bucket, accessKeyId, secretAccessKey);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
-    TResult Function()? backupStarted,
-    TResult Function()? backupSucceeded,
-    TResult Function(BackupFailedData details)? backupFailed,
-    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)?
+        s3,
+    TResult Function(String url, String? username, String? password)? webDav,
     required TResult orElse(),
   }) {
-    if (synced != null) {
-      return synced();
+    if (s3 != null) {
+      return s3(endpoint, region, bucket, accessKeyId, secretAccessKey);
     }
     return orElse();
   }
@@ -908,175 +881,165 @@ class _$BreezEvent_SyncedImpl implements BreezEvent_Synced {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
-    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
-    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
-    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
-    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
-    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BackupTransportConfig_S3 value) s3,
+    required TResult Function(BackupTransportConfig_WebDav value) webDav,
   }) {
-    return synced(this);
+    return s3(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BackupTransportConfig_S3 value)? s3,
+    TResult? Function(BackupTransportConfig_WebDav value)? webDav,
   }) {
-    return synced?.call(this);
+    return s3?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BackupTransportConfig_S3 value)? s3,
+    TResult Function(BackupTransportConfig_WebDav value)? webDav,
     required TResult orElse(),
   }) {
-    if (synced != null) {
-      return synced(this);
+    if (s3 != null) {
+      return s3(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_Synced implements BreezEvent {
-  const factory BreezEvent_Synced() = _$BreezEvent_SyncedImpl;
+abstract class BackupTransportConfig_S3 implements BackupTransportConfig {
+  const factory BackupTransportConfig_S3(
+          {required final String endpoint,
+          required final String region,
+          required final String bucket,
+          required final String accessKeyId,
+          required final String secretAccessKey}) =
+      _$BackupTransportConfig_S3Impl;
+
+  /// For example `https://s3.eu-west-1.amazonaws.com`
+  String get endpoint;
+  String get region;
+  String get bucket;
+  String get accessKeyId;
+  String get secretAccessKey;
+  @JsonKey(ignore: true)
+  _$$BackupTransportConfig_S3ImplCopyWith<_$BackupTransportConfig_S3Impl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_PaymentSucceedImplCopyWith<$Res> {
-  factory _$$BreezEvent_PaymentSucceedImplCopyWith(
-          _$BreezEvent_PaymentSucceedImpl value, $Res Function(_$BreezEvent_PaymentSucceedImpl) then) =
-      __$$BreezEvent_PaymentSucceedImplCopyWithImpl<$Res>;
+abstract class _$$BackupTransportConfig_WebDavImplCopyWith<$Res> {
+  factory _$$BackupTransportConfig_WebDavImplCopyWith(
+          _$BackupTransportConfig_WebDavImpl value, $Res Function(_$BackupTransportConfig_WebDavImpl) then) =
+      __$$BackupTransportConfig_WebDavImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String url, String? username, String? password});
 }
 
 /// @nodoc
-class __$$BreezEvent_PaymentSucceedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentSucceedImpl>
-    implements _$$BreezEvent_PaymentSucceedImplCopyWith<$Res> {
-  __$$BreezEvent_PaymentSucceedImplCopyWithImpl(
-      _$BreezEvent_PaymentSucceedImpl _value, $Res Function(_$BreezEvent_PaymentSucceedImpl) _then)
+class __$$BackupTransportConfig_WebDavImplCopyWithImpl<$Res>
+    extends _$BackupTransportConfigCopyWithImpl<$Res, _$BackupTransportConfig_WebDavImpl>
+    implements _$$BackupTransportConfig_WebDavImplCopyWith<$Res> {
+  __$$BackupTransportConfig_WebDavImplCopyWithImpl(
+      _$BackupTransportConfig_WebDavImpl _value, $Res Function(_$BackupTransportConfig_WebDavImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? url = null,
+    Object? username = freezed,
+    Object? password = freezed,
   }) {
-    return _then(_$BreezEvent_PaymentSucceedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$BackupTransportConfig_WebDavImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+      username: freezed == username
+          ? _value.username
+          : username // ignore: cast_nullable_to_non_nullable
+              as String?,
+      password: freezed == password
+          ? _value.password
+          : password // ignore: cast_nullable_to_non_nullable
+              as String?,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_PaymentSucceedImpl implements BreezEvent_PaymentSucceed {
-  const _$BreezEvent_PaymentSucceedImpl({required this.details});
+class _$BackupTransportConfig_WebDavImpl implements BackupTransportConfig_WebDav {
+  const _$BackupTransportConfig_WebDavImpl({required this.url, this.username, this.password});
 
   @override
-  final Payment details;
+  final String url;
+  @override
+  final String? username;
+  @override
+  final String? password;
 
   @override
   String toString() {
-    return 'BreezEvent.paymentSucceed(details: $details)';
+    return 'BackupTransportConfig.webDav(url: $url, username: $username, password: $password)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_PaymentSucceedImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$BackupTransportConfig_WebDavImpl &&
+            (identical(other.url, url) || other.url == url) &&
+            (identical(other.username, username) || other.username == username) &&
+            (identical(other.password, password) || other.password == password));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, url, username, password);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_PaymentSucceedImplCopyWith<_$BreezEvent_PaymentSucceedImpl> get copyWith =>
-      __$$BreezEvent_PaymentSucceedImplCopyWithImpl<_$BreezEvent_PaymentSucceedImpl>(this, _$identity);
+  _$$BackupTransportConfig_WebDavImplCopyWith<_$BackupTransportConfig_WebDavImpl> get copyWith =>
+      __$$BackupTransportConfig_WebDavImplCopyWithImpl<_$BackupTransportConfig_WebDavImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(int block) newBlock,
-    required TResult Function(InvoicePaidDetails details) invoicePaid,
-    required TResult Function() synced,
-    required TResult Function(Payment details) paymentSucceed,
-    required TResult Function(PaymentFailedData details) paymentFailed,
-    required TResult Function() backupStarted,
-    required TResult Function() backupSucceeded,
-    required TResult Function(BackupFailedData details) backupFailed,
-    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
-    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)
+        s3,
+    required TResult Function(String url, String? username, String? password) webDav,
   }) {
-    return paymentSucceed(details);
+    return webDav(url, username, password);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(int block)? newBlock,
-    TResult? Function(InvoicePaidDetails details)? invoicePaid,
-    TResult? Function()? synced,
-    TResult? Function(Payment details)? paymentSucceed,
-    TResult? Function(PaymentFailedData details)? paymentFailed,
-    TResult? Function()? backupStarted,
-    TResult? Function()? backupSucceeded,
-    TResult? Function(BackupFailedData details)? backupFailed,
-    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)?
+        s3,
+    TResult? Function(String url, String? username, String? password)? webDav,
   }) {
-    return paymentSucceed?.call(details);
+    return webDav?.call(url, username, password);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(int block)? newBlock,
-    TResult Function(InvoicePaidDetails details)? invoicePaid,
-    TResult Function()? synced,
-    TResult Function(Payment details)? paymentSucceed,
-    TResult Function(PaymentFailedData details)? paymentFailed,
-    TResult Function()? backupStarted,
-    TResult Function()? backupSucceeded,
-    TResult Function(BackupFailedData details)? backupFailed,
-    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
-    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(
+            String endpoint, String region, String bucket, String accessKeyId, String secretAccessKey)?
+        s3,
+    TResult Function(String url, String? username, String? password)? webDav,
     required TResult orElse(),
   }) {
-    if (paymentSucceed != null) {
-      return paymentSucceed(details);
+    if (webDav != null) {
+      return webDav(url, username, password);
     }
     return orElse();
   }
@@ -1084,128 +1047,334 @@ class _$BreezEvent_PaymentSucceedImpl implements BreezEvent_PaymentSucceed {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(BreezEvent_NewBlock value) newBlock,
-    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
-    required TResult Function(BreezEvent_Synced value) synced,
-    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
-    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
-    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
-    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
-    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
-    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
-    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BackupTransportConfig_S3 value) s3,
+    required TResult Function(BackupTransportConfig_WebDav value) webDav,
   }) {
-    return paymentSucceed(this);
+    return webDav(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(BreezEvent_NewBlock value)? newBlock,
-    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult? Function(BreezEvent_Synced value)? synced,
-    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BackupTransportConfig_S3 value)? s3,
+    TResult? Function(BackupTransportConfig_WebDav value)? webDav,
   }) {
-    return paymentSucceed?.call(this);
+    return webDav?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(BreezEvent_NewBlock value)? newBlock,
-    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
-    TResult Function(BreezEvent_Synced value)? synced,
-    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
-    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
-    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
-    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
-    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
-    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
-    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BackupTransportConfig_S3 value)? s3,
+    TResult Function(BackupTransportConfig_WebDav value)? webDav,
     required TResult orElse(),
   }) {
-    if (paymentSucceed != null) {
-      return paymentSucceed(this);
+    if (webDav != null) {
+      return webDav(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_PaymentSucceed implements BreezEvent {
-  const factory BreezEvent_PaymentSucceed({required final Payment details}) = _$BreezEvent_PaymentSucceedImpl;
+abstract class BackupTransportConfig_WebDav implements BackupTransportConfig {
+  const factory BackupTransportConfig_WebDav(
+          {required final String url, final String? username, final String? password}) =
+      _$BackupTransportConfig_WebDavImpl;
 
-  Payment get details;
+  String get url;
+  String? get username;
+  String? get password;
   @JsonKey(ignore: true)
-  _$$BreezEvent_PaymentSucceedImplCopyWith<_$BreezEvent_PaymentSucceedImpl> get copyWith =>
+  _$$BackupTransportConfig_WebDavImplCopyWith<_$BackupTransportConfig_WebDavImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_PaymentFailedImplCopyWith<$Res> {
-  factory _$$BreezEvent_PaymentFailedImplCopyWith(
-          _$BreezEvent_PaymentFailedImpl value, $Res Function(_$BreezEvent_PaymentFailedImpl) then) =
-      __$$BreezEvent_PaymentFailedImplCopyWithImpl<$Res>;
+mixin _$BreezEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $BreezEventCopyWith<$Res> {
+  factory $BreezEventCopyWith(BreezEvent value, $Res Function(BreezEvent) then) =
+      _$BreezEventCopyWithImpl<$Res, BreezEvent>;
+}
+
+/// @nodoc
+class _$BreezEventCopyWithImpl<$Res, $Val extends BreezEvent> implements $BreezEventCopyWith<$Res> {
+  _$BreezEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_NewBlockImplCopyWith<$Res> {
+  factory _$$BreezEvent_NewBlockImplCopyWith(
+          _$BreezEvent_NewBlockImpl value, $Res Function(_$BreezEvent_NewBlockImpl) then) =
+      __$$BreezEvent_NewBlockImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({PaymentFailedData details});
+  $Res call({int block});
 }
 
 /// @nodoc
-class __$$BreezEvent_PaymentFailedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentFailedImpl>
-    implements _$$BreezEvent_PaymentFailedImplCopyWith<$Res> {
-  __$$BreezEvent_PaymentFailedImplCopyWithImpl(
-      _$BreezEvent_PaymentFailedImpl _value, $Res Function(_$BreezEvent_PaymentFailedImpl) _then)
+class __$$BreezEvent_NewBlockImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_NewBlockImpl>
+    implements _$$BreezEvent_NewBlockImplCopyWith<$Res> {
+  __$$BreezEvent_NewBlockImplCopyWithImpl(
+      _$BreezEvent_NewBlockImpl _value, $Res Function(_$BreezEvent_NewBlockImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? block = null,
   }) {
-    return _then(_$BreezEvent_PaymentFailedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as PaymentFailedData,
+    return _then(_$BreezEvent_NewBlockImpl(
+      block: null == block
+          ? _value.block
+          : block // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
-  const _$BreezEvent_PaymentFailedImpl({required this.details});
+class _$BreezEvent_NewBlockImpl implements BreezEvent_NewBlock {
+  const _$BreezEvent_NewBlockImpl({required this.block});
 
   @override
-  final PaymentFailedData details;
+  final int block;
 
   @override
   String toString() {
-    return 'BreezEvent.paymentFailed(details: $details)';
+    return 'BreezEvent.newBlock(block: $block)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_PaymentFailedImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$BreezEvent_NewBlockImpl &&
+            (identical(other.block, block) || other.block == block));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, block);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_PaymentFailedImplCopyWith<_$BreezEvent_PaymentFailedImpl> get copyWith =>
-      __$$BreezEvent_PaymentFailedImplCopyWithImpl<_$BreezEvent_PaymentFailedImpl>(this, _$identity);
+  _$$BreezEvent_NewBlockImplCopyWith<_$BreezEvent_NewBlockImpl> get copyWith =>
+      __$$BreezEvent_NewBlockImplCopyWithImpl<_$BreezEvent_NewBlockImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1220,8 +1389,28 @@ class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
     required TResult Function(BackupFailedData details) backupFailed,
     required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
     required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
   }) {
-    return paymentFailed(details);
+    return newBlock(block);
   }
 
   @override
@@ -1237,8 +1426,28 @@ class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
     TResult? Function(BackupFailedData details)? backupFailed,
     TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
   }) {
-    return paymentFailed?.call(details);
+    return newBlock?.call(block);
   }
 
   @override
@@ -1254,10 +1463,30 @@ class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
     TResult Function(BackupFailedData details)? backupFailed,
     TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (paymentFailed != null) {
-      return paymentFailed(details);
+    if (newBlock != null) {
+      return newBlock(block);
     }
     return orElse();
   }
@@ -1275,8 +1504,28 @@ class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
     required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
     required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
   }) {
-    return paymentFailed(this);
+    return newBlock(this);
   }
 
   @override
@@ -1292,8 +1541,28 @@ class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
   }) {
-    return paymentFailed?.call(this);
+    return newBlock?.call(this);
   }
 
   @override
@@ -1309,59 +1578,104 @@ class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (paymentFailed != null) {
-      return paymentFailed(this);
+    if (newBlock != null) {
+      return newBlock(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_PaymentFailed implements BreezEvent {
-  const factory BreezEvent_PaymentFailed({required final PaymentFailedData details}) =
-      _$BreezEvent_PaymentFailedImpl;
+abstract class BreezEvent_NewBlock implements BreezEvent {
+  const factory BreezEvent_NewBlock({required final int block}) = _$BreezEvent_NewBlockImpl;
 
-  PaymentFailedData get details;
+  int get block;
   @JsonKey(ignore: true)
-  _$$BreezEvent_PaymentFailedImplCopyWith<_$BreezEvent_PaymentFailedImpl> get copyWith =>
+  _$$BreezEvent_NewBlockImplCopyWith<_$BreezEvent_NewBlockImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_BackupStartedImplCopyWith<$Res> {
-  factory _$$BreezEvent_BackupStartedImplCopyWith(
-          _$BreezEvent_BackupStartedImpl value, $Res Function(_$BreezEvent_BackupStartedImpl) then) =
-      __$$BreezEvent_BackupStartedImplCopyWithImpl<$Res>;
+abstract class _$$BreezEvent_InvoicePaidImplCopyWith<$Res> {
+  factory _$$BreezEvent_InvoicePaidImplCopyWith(
+          _$BreezEvent_InvoicePaidImpl value, $Res Function(_$BreezEvent_InvoicePaidImpl) then) =
+      __$$BreezEvent_InvoicePaidImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({InvoicePaidDetails details});
 }
 
 /// @nodoc
-class __$$BreezEvent_BackupStartedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupStartedImpl>
-    implements _$$BreezEvent_BackupStartedImplCopyWith<$Res> {
-  __$$BreezEvent_BackupStartedImplCopyWithImpl(
-      _$BreezEvent_BackupStartedImpl _value, $Res Function(_$BreezEvent_BackupStartedImpl) _then)
+class __$$BreezEvent_InvoicePaidImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_InvoicePaidImpl>
+    implements _$$BreezEvent_InvoicePaidImplCopyWith<$Res> {
+  __$$BreezEvent_InvoicePaidImplCopyWithImpl(
+      _$BreezEvent_InvoicePaidImpl _value, $Res Function(_$BreezEvent_InvoicePaidImpl) _then)
       : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_InvoicePaidImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as InvoicePaidDetails,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
-  const _$BreezEvent_BackupStartedImpl();
+class _$BreezEvent_InvoicePaidImpl implements BreezEvent_InvoicePaid {
+  const _$BreezEvent_InvoicePaidImpl({required this.details});
+
+  @override
+  final InvoicePaidDetails details;
 
   @override
   String toString() {
-    return 'BreezEvent.backupStarted()';
+    return 'BreezEvent.invoicePaid(details: $details)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$BreezEvent_BackupStartedImpl);
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_InvoicePaidImpl &&
+            (identical(other.details, details) || other.details == details));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_InvoicePaidImplCopyWith<_$BreezEvent_InvoicePaidImpl> get copyWith =>
+      __$$BreezEvent_InvoicePaidImplCopyWithImpl<_$BreezEvent_InvoicePaidImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1376,8 +1690,28 @@ class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
     required TResult Function(BackupFailedData details) backupFailed,
     required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
     required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
   }) {
-    return backupStarted();
+    return invoicePaid(details);
   }
 
   @override
@@ -1393,8 +1727,28 @@ class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
     TResult? Function(BackupFailedData details)? backupFailed,
     TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
   }) {
-    return backupStarted?.call();
+    return invoicePaid?.call(details);
   }
 
   @override
@@ -1410,10 +1764,30 @@ class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
     TResult Function(BackupFailedData details)? backupFailed,
     TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (backupStarted != null) {
-      return backupStarted();
+    if (invoicePaid != null) {
+      return invoicePaid(details);
     }
     return orElse();
   }
@@ -1431,8 +1805,28 @@ class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
     required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
     required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
   }) {
-    return backupStarted(this);
+    return invoicePaid(this);
   }
 
   @override
@@ -1448,8 +1842,28 @@ class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
   }) {
-    return backupStarted?.call(this);
+    return invoicePaid?.call(this);
   }
 
   @override
@@ -1465,49 +1879,74 @@ class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (backupStarted != null) {
-      return backupStarted(this);
+    if (invoicePaid != null) {
+      return invoicePaid(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_BackupStarted implements BreezEvent {
-  const factory BreezEvent_BackupStarted() = _$BreezEvent_BackupStartedImpl;
+abstract class BreezEvent_InvoicePaid implements BreezEvent {
+  const factory BreezEvent_InvoicePaid({required final InvoicePaidDetails details}) =
+      _$BreezEvent_InvoicePaidImpl;
+
+  InvoicePaidDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_InvoicePaidImplCopyWith<_$BreezEvent_InvoicePaidImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_BackupSucceededImplCopyWith<$Res> {
-  factory _$$BreezEvent_BackupSucceededImplCopyWith(
-          _$BreezEvent_BackupSucceededImpl value, $Res Function(_$BreezEvent_BackupSucceededImpl) then) =
-      __$$BreezEvent_BackupSucceededImplCopyWithImpl<$Res>;
+abstract class _$$BreezEvent_SyncedImplCopyWith<$Res> {
+  factory _$$BreezEvent_SyncedImplCopyWith(
+          _$BreezEvent_SyncedImpl value, $Res Function(_$BreezEvent_SyncedImpl) then) =
+      __$$BreezEvent_SyncedImplCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$BreezEvent_BackupSucceededImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupSucceededImpl>
-    implements _$$BreezEvent_BackupSucceededImplCopyWith<$Res> {
-  __$$BreezEvent_BackupSucceededImplCopyWithImpl(
-      _$BreezEvent_BackupSucceededImpl _value, $Res Function(_$BreezEvent_BackupSucceededImpl) _then)
+class __$$BreezEvent_SyncedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SyncedImpl>
+    implements _$$BreezEvent_SyncedImplCopyWith<$Res> {
+  __$$BreezEvent_SyncedImplCopyWithImpl(
+      _$BreezEvent_SyncedImpl _value, $Res Function(_$BreezEvent_SyncedImpl) _then)
       : super(_value, _then);
 }
 
 /// @nodoc
 
-class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
-  const _$BreezEvent_BackupSucceededImpl();
+class _$BreezEvent_SyncedImpl implements BreezEvent_Synced {
+  const _$BreezEvent_SyncedImpl();
 
   @override
   String toString() {
-    return 'BreezEvent.backupSucceeded()';
+    return 'BreezEvent.synced()';
   }
 
   @override
   bool operator ==(Object other) {
-    return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$BreezEvent_BackupSucceededImpl);
+    return identical(this, other) || (other.runtimeType == runtimeType && other is _$BreezEvent_SyncedImpl);
   }
 
   @override
@@ -1526,8 +1965,28 @@ class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
     required TResult Function(BackupFailedData details) backupFailed,
     required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
     required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
   }) {
-    return backupSucceeded();
+    return synced();
   }
 
   @override
@@ -1543,8 +2002,28 @@ class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
     TResult? Function(BackupFailedData details)? backupFailed,
     TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
   }) {
-    return backupSucceeded?.call();
+    return synced?.call();
   }
 
   @override
@@ -1560,10 +2039,30 @@ class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
     TResult Function(BackupFailedData details)? backupFailed,
     TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (backupSucceeded != null) {
-      return backupSucceeded();
+    if (synced != null) {
+      return synced();
     }
     return orElse();
   }
@@ -1581,8 +2080,28 @@ class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
     required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
     required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
   }) {
-    return backupSucceeded(this);
+    return synced(this);
   }
 
   @override
@@ -1598,8 +2117,28 @@ class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
   }) {
-    return backupSucceeded?.call(this);
+    return synced?.call(this);
   }
 
   @override
@@ -1615,34 +2154,54 @@ class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (backupSucceeded != null) {
-      return backupSucceeded(this);
+    if (synced != null) {
+      return synced(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_BackupSucceeded implements BreezEvent {
-  const factory BreezEvent_BackupSucceeded() = _$BreezEvent_BackupSucceededImpl;
+abstract class BreezEvent_Synced implements BreezEvent {
+  const factory BreezEvent_Synced() = _$BreezEvent_SyncedImpl;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_BackupFailedImplCopyWith<$Res> {
-  factory _$$BreezEvent_BackupFailedImplCopyWith(
-          _$BreezEvent_BackupFailedImpl value, $Res Function(_$BreezEvent_BackupFailedImpl) then) =
-      __$$BreezEvent_BackupFailedImplCopyWithImpl<$Res>;
+abstract class _$$BreezEvent_PaymentSucceedImplCopyWith<$Res> {
+  factory _$$BreezEvent_PaymentSucceedImplCopyWith(
+          _$BreezEvent_PaymentSucceedImpl value, $Res Function(_$BreezEvent_PaymentSucceedImpl) then) =
+      __$$BreezEvent_PaymentSucceedImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({BackupFailedData details});
+  $Res call({Payment details});
 }
 
 /// @nodoc
-class __$$BreezEvent_BackupFailedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupFailedImpl>
-    implements _$$BreezEvent_BackupFailedImplCopyWith<$Res> {
-  __$$BreezEvent_BackupFailedImplCopyWithImpl(
-      _$BreezEvent_BackupFailedImpl _value, $Res Function(_$BreezEvent_BackupFailedImpl) _then)
+class __$$BreezEvent_PaymentSucceedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentSucceedImpl>
+    implements _$$BreezEvent_PaymentSucceedImplCopyWith<$Res> {
+  __$$BreezEvent_PaymentSucceedImplCopyWithImpl(
+      _$BreezEvent_PaymentSucceedImpl _value, $Res Function(_$BreezEvent_PaymentSucceedImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
@@ -1650,33 +2209,33 @@ class __$$BreezEvent_BackupFailedImplCopyWithImpl<$Res>
   $Res call({
     Object? details = null,
   }) {
-    return _then(_$BreezEvent_BackupFailedImpl(
+    return _then(_$BreezEvent_PaymentSucceedImpl(
       details: null == details
           ? _value.details
           : details // ignore: cast_nullable_to_non_nullable
-              as BackupFailedData,
+              as Payment,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
-  const _$BreezEvent_BackupFailedImpl({required this.details});
+class _$BreezEvent_PaymentSucceedImpl implements BreezEvent_PaymentSucceed {
+  const _$BreezEvent_PaymentSucceedImpl({required this.details});
 
   @override
-  final BackupFailedData details;
+  final Payment details;
 
   @override
   String toString() {
-    return 'BreezEvent.backupFailed(details: $details)';
+    return 'BreezEvent.paymentSucceed(details: $details)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_BackupFailedImpl &&
+            other is _$BreezEvent_PaymentSucceedImpl &&
             (identical(other.details, details) || other.details == details));
   }
 
@@ -1686,8 +2245,8 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_BackupFailedImplCopyWith<_$BreezEvent_BackupFailedImpl> get copyWith =>
-      __$$BreezEvent_BackupFailedImplCopyWithImpl<_$BreezEvent_BackupFailedImpl>(this, _$identity);
+  _$$BreezEvent_PaymentSucceedImplCopyWith<_$BreezEvent_PaymentSucceedImpl> get copyWith =>
+      __$$BreezEvent_PaymentSucceedImplCopyWithImpl<_$BreezEvent_PaymentSucceedImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1702,8 +2261,28 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
     required TResult Function(BackupFailedData details) backupFailed,
     required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
     required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
   }) {
-    return backupFailed(details);
+    return paymentSucceed(details);
   }
 
   @override
@@ -1719,8 +2298,28 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
     TResult? Function(BackupFailedData details)? backupFailed,
     TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
   }) {
-    return backupFailed?.call(details);
+    return paymentSucceed?.call(details);
   }
 
   @override
@@ -1736,10 +2335,30 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
     TResult Function(BackupFailedData details)? backupFailed,
     TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (backupFailed != null) {
-      return backupFailed(details);
+    if (paymentSucceed != null) {
+      return paymentSucceed(details);
     }
     return orElse();
   }
@@ -1757,8 +2376,28 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
     required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
     required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
   }) {
-    return backupFailed(this);
+    return paymentSucceed(this);
   }
 
   @override
@@ -1774,8 +2413,28 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
   }) {
-    return backupFailed?.call(this);
+    return paymentSucceed?.call(this);
   }
 
   @override
@@ -1791,40 +2450,59 @@ class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (backupFailed != null) {
-      return backupFailed(this);
+    if (paymentSucceed != null) {
+      return paymentSucceed(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_BackupFailed implements BreezEvent {
-  const factory BreezEvent_BackupFailed({required final BackupFailedData details}) =
-      _$BreezEvent_BackupFailedImpl;
+abstract class BreezEvent_PaymentSucceed implements BreezEvent {
+  const factory BreezEvent_PaymentSucceed({required final Payment details}) = _$BreezEvent_PaymentSucceedImpl;
 
-  BackupFailedData get details;
+  Payment get details;
   @JsonKey(ignore: true)
-  _$$BreezEvent_BackupFailedImplCopyWith<_$BreezEvent_BackupFailedImpl> get copyWith =>
+  _$$BreezEvent_PaymentSucceedImplCopyWith<_$BreezEvent_PaymentSucceedImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<$Res> {
-  factory _$$BreezEvent_ReverseSwapUpdatedImplCopyWith(_$BreezEvent_ReverseSwapUpdatedImpl value,
-          $Res Function(_$BreezEvent_ReverseSwapUpdatedImpl) then) =
-      __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<$Res>;
+abstract class _$$BreezEvent_PaymentFailedImplCopyWith<$Res> {
+  factory _$$BreezEvent_PaymentFailedImplCopyWith(
+          _$BreezEvent_PaymentFailedImpl value, $Res Function(_$BreezEvent_PaymentFailedImpl) then) =
+      __$$BreezEvent_PaymentFailedImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({ReverseSwapInfo details});
+  $Res call({PaymentFailedData details});
 }
 
 /// @nodoc
-class __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ReverseSwapUpdatedImpl>
-    implements _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<$Res> {
-  __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl(
-      _$BreezEvent_ReverseSwapUpdatedImpl _value, $Res Function(_$BreezEvent_ReverseSwapUpdatedImpl) _then)
+class __$$BreezEvent_PaymentFailedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_PaymentFailedImpl>
+    implements _$$BreezEvent_PaymentFailedImplCopyWith<$Res> {
+  __$$BreezEvent_PaymentFailedImplCopyWithImpl(
+      _$BreezEvent_PaymentFailedImpl _value, $Res Function(_$BreezEvent_PaymentFailedImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
@@ -1832,33 +2510,33 @@ class __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<$Res>
   $Res call({
     Object? details = null,
   }) {
-    return _then(_$BreezEvent_ReverseSwapUpdatedImpl(
+    return _then(_$BreezEvent_PaymentFailedImpl(
       details: null == details
           ? _value.details
           : details // ignore: cast_nullable_to_non_nullable
-              as ReverseSwapInfo,
+              as PaymentFailedData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdated {
-  const _$BreezEvent_ReverseSwapUpdatedImpl({required this.details});
+class _$BreezEvent_PaymentFailedImpl implements BreezEvent_PaymentFailed {
+  const _$BreezEvent_PaymentFailedImpl({required this.details});
 
   @override
-  final ReverseSwapInfo details;
+  final PaymentFailedData details;
 
   @override
   String toString() {
-    return 'BreezEvent.reverseSwapUpdated(details: $details)';
+    return 'BreezEvent.paymentFailed(details: $details)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_ReverseSwapUpdatedImpl &&
+            other is _$BreezEvent_PaymentFailedImpl &&
             (identical(other.details, details) || other.details == details));
   }
 
@@ -1868,9 +2546,8 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<_$BreezEvent_ReverseSwapUpdatedImpl> get copyWith =>
-      __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<_$BreezEvent_ReverseSwapUpdatedImpl>(
-          this, _$identity);
+  _$$BreezEvent_PaymentFailedImplCopyWith<_$BreezEvent_PaymentFailedImpl> get copyWith =>
+      __$$BreezEvent_PaymentFailedImplCopyWithImpl<_$BreezEvent_PaymentFailedImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
@@ -1885,8 +2562,28 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
     required TResult Function(BackupFailedData details) backupFailed,
     required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
     required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
   }) {
-    return reverseSwapUpdated(details);
+    return paymentFailed(details);
   }
 
   @override
@@ -1902,8 +2599,28 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
     TResult? Function(BackupFailedData details)? backupFailed,
     TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
   }) {
-    return reverseSwapUpdated?.call(details);
+    return paymentFailed?.call(details);
   }
 
   @override
@@ -1919,10 +2636,30 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
     TResult Function(BackupFailedData details)? backupFailed,
     TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (reverseSwapUpdated != null) {
-      return reverseSwapUpdated(details);
+    if (paymentFailed != null) {
+      return paymentFailed(details);
     }
     return orElse();
   }
@@ -1940,8 +2677,28 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
     required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
     required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
   }) {
-    return reverseSwapUpdated(this);
+    return paymentFailed(this);
   }
 
   @override
@@ -1957,8 +2714,28 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
   }) {
-    return reverseSwapUpdated?.call(this);
+    return paymentFailed?.call(this);
   }
 
   @override
@@ -1974,85 +2751,79 @@ class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdat
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (reverseSwapUpdated != null) {
-      return reverseSwapUpdated(this);
+    if (paymentFailed != null) {
+      return paymentFailed(this);
     }
     return orElse();
   }
 }
 
-abstract class BreezEvent_ReverseSwapUpdated implements BreezEvent {
-  const factory BreezEvent_ReverseSwapUpdated({required final ReverseSwapInfo details}) =
-      _$BreezEvent_ReverseSwapUpdatedImpl;
+abstract class BreezEvent_PaymentFailed implements BreezEvent {
+  const factory BreezEvent_PaymentFailed({required final PaymentFailedData details}) =
+      _$BreezEvent_PaymentFailedImpl;
 
-  ReverseSwapInfo get details;
+  PaymentFailedData get details;
   @JsonKey(ignore: true)
-  _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<_$BreezEvent_ReverseSwapUpdatedImpl> get copyWith =>
+  _$$BreezEvent_PaymentFailedImplCopyWith<_$BreezEvent_PaymentFailedImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$BreezEvent_SwapUpdatedImplCopyWith<$Res> {
-  factory _$$BreezEvent_SwapUpdatedImplCopyWith(
-          _$BreezEvent_SwapUpdatedImpl value, $Res Function(_$BreezEvent_SwapUpdatedImpl) then) =
-      __$$BreezEvent_SwapUpdatedImplCopyWithImpl<$Res>;
-  @useResult
-  $Res call({SwapInfo details});
+abstract class _$$BreezEvent_BackupStartedImplCopyWith<$Res> {
+  factory _$$BreezEvent_BackupStartedImplCopyWith(
+          _$BreezEvent_BackupStartedImpl value, $Res Function(_$BreezEvent_BackupStartedImpl) then) =
+      __$$BreezEvent_BackupStartedImplCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$BreezEvent_SwapUpdatedImplCopyWithImpl<$Res>
-    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SwapUpdatedImpl>
-    implements _$$BreezEvent_SwapUpdatedImplCopyWith<$Res> {
-  __$$BreezEvent_SwapUpdatedImplCopyWithImpl(
-      _$BreezEvent_SwapUpdatedImpl _value, $Res Function(_$BreezEvent_SwapUpdatedImpl) _then)
+class __$$BreezEvent_BackupStartedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupStartedImpl>
+    implements _$$BreezEvent_BackupStartedImplCopyWith<$Res> {
+  __$$BreezEvent_BackupStartedImplCopyWithImpl(
+      _$BreezEvent_BackupStartedImpl _value, $Res Function(_$BreezEvent_BackupStartedImpl) _then)
       : super(_value, _then);
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? details = null,
-  }) {
-    return _then(_$BreezEvent_SwapUpdatedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as SwapInfo,
-    ));
-  }
 }
 
 /// @nodoc
 
-class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
-  const _$BreezEvent_SwapUpdatedImpl({required this.details});
-
-  @override
-  final SwapInfo details;
+class _$BreezEvent_BackupStartedImpl implements BreezEvent_BackupStarted {
+  const _$BreezEvent_BackupStartedImpl();
 
   @override
   String toString() {
-    return 'BreezEvent.swapUpdated(details: $details)';
+    return 'BreezEvent.backupStarted()';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$BreezEvent_SwapUpdatedImpl &&
-            (identical(other.details, details) || other.details == details));
+        (other.runtimeType == runtimeType && other is _$BreezEvent_BackupStartedImpl);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
-
-  @JsonKey(ignore: true)
-  @override
-  @pragma('vm:prefer-inline')
-  _$$BreezEvent_SwapUpdatedImplCopyWith<_$BreezEvent_SwapUpdatedImpl> get copyWith =>
-      __$$BreezEvent_SwapUpdatedImplCopyWithImpl<_$BreezEvent_SwapUpdatedImpl>(this, _$identity);
+  int get hashCode => runtimeType.hashCode;
 
   @override
   @optionalTypeArgs
@@ -2067,8 +2838,28 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
     required TResult Function(BackupFailedData details) backupFailed,
     required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
     required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
   }) {
-    return swapUpdated(details);
+    return backupStarted();
   }
 
   @override
@@ -2084,8 +2875,28 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
     TResult? Function(BackupFailedData details)? backupFailed,
     TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
   }) {
-    return swapUpdated?.call(details);
+    return backupStarted?.call();
   }
 
   @override
@@ -2101,10 +2912,30 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
     TResult Function(BackupFailedData details)? backupFailed,
     TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
     TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
     required TResult orElse(),
   }) {
-    if (swapUpdated != null) {
-      return swapUpdated(details);
+    if (backupStarted != null) {
+      return backupStarted();
     }
     return orElse();
   }
@@ -2122,8 +2953,28 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
     required TResult Function(BreezEvent_BackupFailed value) backupFailed,
     required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
     required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
   }) {
-    return swapUpdated(this);
+    return backupStarted(this);
   }
 
   @override
@@ -2139,8 +2990,28 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
     TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
   }) {
-    return swapUpdated?.call(this);
+    return backupStarted?.call(this);
   }
 
   @override
@@ -2156,6 +3027,1197 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
     TResult Function(BreezEvent_BackupFailed value)? backupFailed,
     TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
     TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (backupStarted != null) {
+      return backupStarted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_BackupStarted implements BreezEvent {
+  const factory BreezEvent_BackupStarted() = _$BreezEvent_BackupStartedImpl;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_BackupSucceededImplCopyWith<$Res> {
+  factory _$$BreezEvent_BackupSucceededImplCopyWith(
+          _$BreezEvent_BackupSucceededImpl value, $Res Function(_$BreezEvent_BackupSucceededImpl) then) =
+      __$$BreezEvent_BackupSucceededImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BreezEvent_BackupSucceededImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupSucceededImpl>
+    implements _$$BreezEvent_BackupSucceededImplCopyWith<$Res> {
+  __$$BreezEvent_BackupSucceededImplCopyWithImpl(
+      _$BreezEvent_BackupSucceededImpl _value, $Res Function(_$BreezEvent_BackupSucceededImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$BreezEvent_BackupSucceededImpl implements BreezEvent_BackupSucceeded {
+  const _$BreezEvent_BackupSucceededImpl();
+
+  @override
+  String toString() {
+    return 'BreezEvent.backupSucceeded()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$BreezEvent_BackupSucceededImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return backupSucceeded();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return backupSucceeded?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (backupSucceeded != null) {
+      return backupSucceeded();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return backupSucceeded(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return backupSucceeded?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (backupSucceeded != null) {
+      return backupSucceeded(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_BackupSucceeded implements BreezEvent {
+  const factory BreezEvent_BackupSucceeded() = _$BreezEvent_BackupSucceededImpl;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_BackupFailedImplCopyWith<$Res> {
+  factory _$$BreezEvent_BackupFailedImplCopyWith(
+          _$BreezEvent_BackupFailedImpl value, $Res Function(_$BreezEvent_BackupFailedImpl) then) =
+      __$$BreezEvent_BackupFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BackupFailedData details});
+}
+
+/// @nodoc
+class __$$BreezEvent_BackupFailedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BackupFailedImpl>
+    implements _$$BreezEvent_BackupFailedImplCopyWith<$Res> {
+  __$$BreezEvent_BackupFailedImplCopyWithImpl(
+      _$BreezEvent_BackupFailedImpl _value, $Res Function(_$BreezEvent_BackupFailedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_BackupFailedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as BackupFailedData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_BackupFailedImpl implements BreezEvent_BackupFailed {
+  const _$BreezEvent_BackupFailedImpl({required this.details});
+
+  @override
+  final BackupFailedData details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.backupFailed(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_BackupFailedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_BackupFailedImplCopyWith<_$BreezEvent_BackupFailedImpl> get copyWith =>
+      __$$BreezEvent_BackupFailedImplCopyWithImpl<_$BreezEvent_BackupFailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return backupFailed(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return backupFailed?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (backupFailed != null) {
+      return backupFailed(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return backupFailed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return backupFailed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (backupFailed != null) {
+      return backupFailed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_BackupFailed implements BreezEvent {
+  const factory BreezEvent_BackupFailed({required final BackupFailedData details}) =
+      _$BreezEvent_BackupFailedImpl;
+
+  BackupFailedData get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_BackupFailedImplCopyWith<_$BreezEvent_BackupFailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<$Res> {
+  factory _$$BreezEvent_ReverseSwapUpdatedImplCopyWith(_$BreezEvent_ReverseSwapUpdatedImpl value,
+          $Res Function(_$BreezEvent_ReverseSwapUpdatedImpl) then) =
+      __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ReverseSwapInfo details});
+}
+
+/// @nodoc
+class __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ReverseSwapUpdatedImpl>
+    implements _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<$Res> {
+  __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl(
+      _$BreezEvent_ReverseSwapUpdatedImpl _value, $Res Function(_$BreezEvent_ReverseSwapUpdatedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_ReverseSwapUpdatedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as ReverseSwapInfo,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ReverseSwapUpdatedImpl implements BreezEvent_ReverseSwapUpdated {
+  const _$BreezEvent_ReverseSwapUpdatedImpl({required this.details});
+
+  @override
+  final ReverseSwapInfo details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.reverseSwapUpdated(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ReverseSwapUpdatedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<_$BreezEvent_ReverseSwapUpdatedImpl> get copyWith =>
+      __$$BreezEvent_ReverseSwapUpdatedImplCopyWithImpl<_$BreezEvent_ReverseSwapUpdatedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return reverseSwapUpdated(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return reverseSwapUpdated?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (reverseSwapUpdated != null) {
+      return reverseSwapUpdated(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return reverseSwapUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return reverseSwapUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (reverseSwapUpdated != null) {
+      return reverseSwapUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ReverseSwapUpdated implements BreezEvent {
+  const factory BreezEvent_ReverseSwapUpdated({required final ReverseSwapInfo details}) =
+      _$BreezEvent_ReverseSwapUpdatedImpl;
+
+  ReverseSwapInfo get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ReverseSwapUpdatedImplCopyWith<_$BreezEvent_ReverseSwapUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SwapUpdatedImplCopyWith<$Res> {
+  factory _$$BreezEvent_SwapUpdatedImplCopyWith(
+          _$BreezEvent_SwapUpdatedImpl value, $Res Function(_$BreezEvent_SwapUpdatedImpl) then) =
+      __$$BreezEvent_SwapUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({SwapInfo details});
+}
+
+/// @nodoc
+class __$$BreezEvent_SwapUpdatedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SwapUpdatedImpl>
+    implements _$$BreezEvent_SwapUpdatedImplCopyWith<$Res> {
+  __$$BreezEvent_SwapUpdatedImplCopyWithImpl(
+      _$BreezEvent_SwapUpdatedImpl _value, $Res Function(_$BreezEvent_SwapUpdatedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_SwapUpdatedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as SwapInfo,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
+  const _$BreezEvent_SwapUpdatedImpl({required this.details});
+
+  @override
+  final SwapInfo details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.swapUpdated(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SwapUpdatedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SwapUpdatedImplCopyWith<_$BreezEvent_SwapUpdatedImpl> get copyWith =>
+      __$$BreezEvent_SwapUpdatedImplCopyWithImpl<_$BreezEvent_SwapUpdatedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return swapUpdated(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return swapUpdated?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (swapUpdated != null) {
+      return swapUpdated(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return swapUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return swapUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
     required TResult orElse(),
   }) {
     if (swapUpdated != null) {
@@ -2165,215 +4227,9201 @@ class _$BreezEvent_SwapUpdatedImpl implements BreezEvent_SwapUpdated {
   }
 }
 
-abstract class BreezEvent_SwapUpdated implements BreezEvent {
-  const factory BreezEvent_SwapUpdated({required final SwapInfo details}) = _$BreezEvent_SwapUpdatedImpl;
+abstract class BreezEvent_SwapUpdated implements BreezEvent {
+  const factory BreezEvent_SwapUpdated({required final SwapInfo details}) = _$BreezEvent_SwapUpdatedImpl;
+
+  SwapInfo get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SwapUpdatedImplCopyWith<_$BreezEvent_SwapUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ConnectProgressImplCopyWith<$Res> {
+  factory _$$BreezEvent_ConnectProgressImplCopyWith(
+          _$BreezEvent_ConnectProgressImpl value, $Res Function(_$BreezEvent_ConnectProgressImpl) then) =
+      __$$BreezEvent_ConnectProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ConnectProgressDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_ConnectProgressImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ConnectProgressImpl>
+    implements _$$BreezEvent_ConnectProgressImplCopyWith<$Res> {
+  __$$BreezEvent_ConnectProgressImplCopyWithImpl(
+      _$BreezEvent_ConnectProgressImpl _value, $Res Function(_$BreezEvent_ConnectProgressImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_ConnectProgressImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as ConnectProgressDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ConnectProgressImpl implements BreezEvent_ConnectProgress {
+  const _$BreezEvent_ConnectProgressImpl({required this.details});
+
+  @override
+  final ConnectProgressDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.connectProgress(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ConnectProgressImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ConnectProgressImplCopyWith<_$BreezEvent_ConnectProgressImpl> get copyWith =>
+      __$$BreezEvent_ConnectProgressImplCopyWithImpl<_$BreezEvent_ConnectProgressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return connectProgress(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return connectProgress?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (connectProgress != null) {
+      return connectProgress(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return connectProgress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return connectProgress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (connectProgress != null) {
+      return connectProgress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ConnectProgress implements BreezEvent {
+  const factory BreezEvent_ConnectProgress({required final ConnectProgressDetails details}) =
+      _$BreezEvent_ConnectProgressImpl;
+
+  ConnectProgressDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ConnectProgressImplCopyWith<_$BreezEvent_ConnectProgressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_UnredeemedFundsDetectedImplCopyWith<$Res> {
+  factory _$$BreezEvent_UnredeemedFundsDetectedImplCopyWith(_$BreezEvent_UnredeemedFundsDetectedImpl value,
+          $Res Function(_$BreezEvent_UnredeemedFundsDetectedImpl) then) =
+      __$$BreezEvent_UnredeemedFundsDetectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({UnredeemedFundsDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_UnredeemedFundsDetectedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_UnredeemedFundsDetectedImpl>
+    implements _$$BreezEvent_UnredeemedFundsDetectedImplCopyWith<$Res> {
+  __$$BreezEvent_UnredeemedFundsDetectedImplCopyWithImpl(_$BreezEvent_UnredeemedFundsDetectedImpl _value,
+      $Res Function(_$BreezEvent_UnredeemedFundsDetectedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_UnredeemedFundsDetectedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as UnredeemedFundsDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_UnredeemedFundsDetectedImpl implements BreezEvent_UnredeemedFundsDetected {
+  const _$BreezEvent_UnredeemedFundsDetectedImpl({required this.details});
+
+  @override
+  final UnredeemedFundsDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.unredeemedFundsDetected(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_UnredeemedFundsDetectedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_UnredeemedFundsDetectedImplCopyWith<_$BreezEvent_UnredeemedFundsDetectedImpl> get copyWith =>
+      __$$BreezEvent_UnredeemedFundsDetectedImplCopyWithImpl<_$BreezEvent_UnredeemedFundsDetectedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return unredeemedFundsDetected(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return unredeemedFundsDetected?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (unredeemedFundsDetected != null) {
+      return unredeemedFundsDetected(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return unredeemedFundsDetected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return unredeemedFundsDetected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (unredeemedFundsDetected != null) {
+      return unredeemedFundsDetected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_UnredeemedFundsDetected implements BreezEvent {
+  const factory BreezEvent_UnredeemedFundsDetected({required final UnredeemedFundsDetails details}) =
+      _$BreezEvent_UnredeemedFundsDetectedImpl;
+
+  UnredeemedFundsDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_UnredeemedFundsDetectedImplCopyWith<_$BreezEvent_UnredeemedFundsDetectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LnUrlPayFinishedImplCopyWith<$Res> {
+  factory _$$BreezEvent_LnUrlPayFinishedImplCopyWith(
+          _$BreezEvent_LnUrlPayFinishedImpl value, $Res Function(_$BreezEvent_LnUrlPayFinishedImpl) then) =
+      __$$BreezEvent_LnUrlPayFinishedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlPayFinishedDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_LnUrlPayFinishedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LnUrlPayFinishedImpl>
+    implements _$$BreezEvent_LnUrlPayFinishedImplCopyWith<$Res> {
+  __$$BreezEvent_LnUrlPayFinishedImplCopyWithImpl(
+      _$BreezEvent_LnUrlPayFinishedImpl _value, $Res Function(_$BreezEvent_LnUrlPayFinishedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_LnUrlPayFinishedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as LnUrlPayFinishedDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LnUrlPayFinishedImpl implements BreezEvent_LnUrlPayFinished {
+  const _$BreezEvent_LnUrlPayFinishedImpl({required this.details});
+
+  @override
+  final LnUrlPayFinishedDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lnUrlPayFinished(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LnUrlPayFinishedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LnUrlPayFinishedImplCopyWith<_$BreezEvent_LnUrlPayFinishedImpl> get copyWith =>
+      __$$BreezEvent_LnUrlPayFinishedImplCopyWithImpl<_$BreezEvent_LnUrlPayFinishedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return lnUrlPayFinished(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return lnUrlPayFinished?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lnUrlPayFinished != null) {
+      return lnUrlPayFinished(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return lnUrlPayFinished(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return lnUrlPayFinished?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lnUrlPayFinished != null) {
+      return lnUrlPayFinished(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LnUrlPayFinished implements BreezEvent {
+  const factory BreezEvent_LnUrlPayFinished({required final LnUrlPayFinishedDetails details}) =
+      _$BreezEvent_LnUrlPayFinishedImpl;
+
+  LnUrlPayFinishedDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LnUrlPayFinishedImplCopyWith<_$BreezEvent_LnUrlPayFinishedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWith<$Res> {
+  factory _$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWith(
+          _$BreezEvent_OpenChannelReceiveUpdatedImpl value,
+          $Res Function(_$BreezEvent_OpenChannelReceiveUpdatedImpl) then) =
+      __$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({OpenChannelReceiveDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_OpenChannelReceiveUpdatedImpl>
+    implements _$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWith<$Res> {
+  __$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWithImpl(_$BreezEvent_OpenChannelReceiveUpdatedImpl _value,
+      $Res Function(_$BreezEvent_OpenChannelReceiveUpdatedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_OpenChannelReceiveUpdatedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as OpenChannelReceiveDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_OpenChannelReceiveUpdatedImpl implements BreezEvent_OpenChannelReceiveUpdated {
+  const _$BreezEvent_OpenChannelReceiveUpdatedImpl({required this.details});
+
+  @override
+  final OpenChannelReceiveDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.openChannelReceiveUpdated(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_OpenChannelReceiveUpdatedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWith<_$BreezEvent_OpenChannelReceiveUpdatedImpl>
+      get copyWith => __$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWithImpl<
+          _$BreezEvent_OpenChannelReceiveUpdatedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return openChannelReceiveUpdated(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return openChannelReceiveUpdated?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (openChannelReceiveUpdated != null) {
+      return openChannelReceiveUpdated(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return openChannelReceiveUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return openChannelReceiveUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (openChannelReceiveUpdated != null) {
+      return openChannelReceiveUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_OpenChannelReceiveUpdated implements BreezEvent {
+  const factory BreezEvent_OpenChannelReceiveUpdated({required final OpenChannelReceiveDetails details}) =
+      _$BreezEvent_OpenChannelReceiveUpdatedImpl;
+
+  OpenChannelReceiveDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_OpenChannelReceiveUpdatedImplCopyWith<_$BreezEvent_OpenChannelReceiveUpdatedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LowOutboundLiquidityImplCopyWith<$Res> {
+  factory _$$BreezEvent_LowOutboundLiquidityImplCopyWith(_$BreezEvent_LowOutboundLiquidityImpl value,
+          $Res Function(_$BreezEvent_LowOutboundLiquidityImpl) then) =
+      __$$BreezEvent_LowOutboundLiquidityImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LowLiquidityDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_LowOutboundLiquidityImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LowOutboundLiquidityImpl>
+    implements _$$BreezEvent_LowOutboundLiquidityImplCopyWith<$Res> {
+  __$$BreezEvent_LowOutboundLiquidityImplCopyWithImpl(_$BreezEvent_LowOutboundLiquidityImpl _value,
+      $Res Function(_$BreezEvent_LowOutboundLiquidityImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_LowOutboundLiquidityImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as LowLiquidityDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LowOutboundLiquidityImpl implements BreezEvent_LowOutboundLiquidity {
+  const _$BreezEvent_LowOutboundLiquidityImpl({required this.details});
+
+  @override
+  final LowLiquidityDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lowOutboundLiquidity(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LowOutboundLiquidityImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LowOutboundLiquidityImplCopyWith<_$BreezEvent_LowOutboundLiquidityImpl> get copyWith =>
+      __$$BreezEvent_LowOutboundLiquidityImplCopyWithImpl<_$BreezEvent_LowOutboundLiquidityImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return lowOutboundLiquidity(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return lowOutboundLiquidity?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lowOutboundLiquidity != null) {
+      return lowOutboundLiquidity(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return lowOutboundLiquidity(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return lowOutboundLiquidity?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lowOutboundLiquidity != null) {
+      return lowOutboundLiquidity(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LowOutboundLiquidity implements BreezEvent {
+  const factory BreezEvent_LowOutboundLiquidity({required final LowLiquidityDetails details}) =
+      _$BreezEvent_LowOutboundLiquidityImpl;
+
+  LowLiquidityDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LowOutboundLiquidityImplCopyWith<_$BreezEvent_LowOutboundLiquidityImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LowInboundLiquidityImplCopyWith<$Res> {
+  factory _$$BreezEvent_LowInboundLiquidityImplCopyWith(_$BreezEvent_LowInboundLiquidityImpl value,
+          $Res Function(_$BreezEvent_LowInboundLiquidityImpl) then) =
+      __$$BreezEvent_LowInboundLiquidityImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LowLiquidityDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_LowInboundLiquidityImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LowInboundLiquidityImpl>
+    implements _$$BreezEvent_LowInboundLiquidityImplCopyWith<$Res> {
+  __$$BreezEvent_LowInboundLiquidityImplCopyWithImpl(
+      _$BreezEvent_LowInboundLiquidityImpl _value, $Res Function(_$BreezEvent_LowInboundLiquidityImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_LowInboundLiquidityImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as LowLiquidityDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LowInboundLiquidityImpl implements BreezEvent_LowInboundLiquidity {
+  const _$BreezEvent_LowInboundLiquidityImpl({required this.details});
+
+  @override
+  final LowLiquidityDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lowInboundLiquidity(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LowInboundLiquidityImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LowInboundLiquidityImplCopyWith<_$BreezEvent_LowInboundLiquidityImpl> get copyWith =>
+      __$$BreezEvent_LowInboundLiquidityImplCopyWithImpl<_$BreezEvent_LowInboundLiquidityImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return lowInboundLiquidity(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return lowInboundLiquidity?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lowInboundLiquidity != null) {
+      return lowInboundLiquidity(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return lowInboundLiquidity(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return lowInboundLiquidity?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lowInboundLiquidity != null) {
+      return lowInboundLiquidity(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LowInboundLiquidity implements BreezEvent {
+  const factory BreezEvent_LowInboundLiquidity({required final LowLiquidityDetails details}) =
+      _$BreezEvent_LowInboundLiquidityImpl;
+
+  LowLiquidityDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LowInboundLiquidityImplCopyWith<_$BreezEvent_LowInboundLiquidityImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ChannelOpeningImplCopyWith<$Res> {
+  factory _$$BreezEvent_ChannelOpeningImplCopyWith(
+          _$BreezEvent_ChannelOpeningImpl value, $Res Function(_$BreezEvent_ChannelOpeningImpl) then) =
+      __$$BreezEvent_ChannelOpeningImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int feeMsat, int minFeeMsat, int proportional});
+}
+
+/// @nodoc
+class __$$BreezEvent_ChannelOpeningImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ChannelOpeningImpl>
+    implements _$$BreezEvent_ChannelOpeningImplCopyWith<$Res> {
+  __$$BreezEvent_ChannelOpeningImplCopyWithImpl(
+      _$BreezEvent_ChannelOpeningImpl _value, $Res Function(_$BreezEvent_ChannelOpeningImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? feeMsat = null,
+    Object? minFeeMsat = null,
+    Object? proportional = null,
+  }) {
+    return _then(_$BreezEvent_ChannelOpeningImpl(
+      feeMsat: null == feeMsat
+          ? _value.feeMsat
+          : feeMsat // ignore: cast_nullable_to_non_nullable
+              as int,
+      minFeeMsat: null == minFeeMsat
+          ? _value.minFeeMsat
+          : minFeeMsat // ignore: cast_nullable_to_non_nullable
+              as int,
+      proportional: null == proportional
+          ? _value.proportional
+          : proportional // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ChannelOpeningImpl implements BreezEvent_ChannelOpening {
+  const _$BreezEvent_ChannelOpeningImpl(
+      {required this.feeMsat, required this.minFeeMsat, required this.proportional});
+
+  @override
+  final int feeMsat;
+  @override
+  final int minFeeMsat;
+  @override
+  final int proportional;
+
+  @override
+  String toString() {
+    return 'BreezEvent.channelOpening(feeMsat: $feeMsat, minFeeMsat: $minFeeMsat, proportional: $proportional)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ChannelOpeningImpl &&
+            (identical(other.feeMsat, feeMsat) || other.feeMsat == feeMsat) &&
+            (identical(other.minFeeMsat, minFeeMsat) || other.minFeeMsat == minFeeMsat) &&
+            (identical(other.proportional, proportional) || other.proportional == proportional));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, feeMsat, minFeeMsat, proportional);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ChannelOpeningImplCopyWith<_$BreezEvent_ChannelOpeningImpl> get copyWith =>
+      __$$BreezEvent_ChannelOpeningImplCopyWithImpl<_$BreezEvent_ChannelOpeningImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return channelOpening(feeMsat, minFeeMsat, proportional);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return channelOpening?.call(feeMsat, minFeeMsat, proportional);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (channelOpening != null) {
+      return channelOpening(feeMsat, minFeeMsat, proportional);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return channelOpening(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return channelOpening?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (channelOpening != null) {
+      return channelOpening(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ChannelOpening implements BreezEvent {
+  const factory BreezEvent_ChannelOpening(
+          {required final int feeMsat, required final int minFeeMsat, required final int proportional}) =
+      _$BreezEvent_ChannelOpeningImpl;
+
+  int get feeMsat;
+  int get minFeeMsat;
+  int get proportional;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ChannelOpeningImplCopyWith<_$BreezEvent_ChannelOpeningImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LspChangedImplCopyWith<$Res> {
+  factory _$$BreezEvent_LspChangedImplCopyWith(
+          _$BreezEvent_LspChangedImpl value, $Res Function(_$BreezEvent_LspChangedImpl) then) =
+      __$$BreezEvent_LspChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String? previousLspId, String lspId});
+}
+
+/// @nodoc
+class __$$BreezEvent_LspChangedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LspChangedImpl>
+    implements _$$BreezEvent_LspChangedImplCopyWith<$Res> {
+  __$$BreezEvent_LspChangedImplCopyWithImpl(
+      _$BreezEvent_LspChangedImpl _value, $Res Function(_$BreezEvent_LspChangedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? previousLspId = freezed,
+    Object? lspId = null,
+  }) {
+    return _then(_$BreezEvent_LspChangedImpl(
+      previousLspId: freezed == previousLspId
+          ? _value.previousLspId
+          : previousLspId // ignore: cast_nullable_to_non_nullable
+              as String?,
+      lspId: null == lspId
+          ? _value.lspId
+          : lspId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LspChangedImpl implements BreezEvent_LspChanged {
+  const _$BreezEvent_LspChangedImpl({this.previousLspId, required this.lspId});
+
+  @override
+  final String? previousLspId;
+  @override
+  final String lspId;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lspChanged(previousLspId: $previousLspId, lspId: $lspId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LspChangedImpl &&
+            (identical(other.previousLspId, previousLspId) || other.previousLspId == previousLspId) &&
+            (identical(other.lspId, lspId) || other.lspId == lspId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, previousLspId, lspId);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LspChangedImplCopyWith<_$BreezEvent_LspChangedImpl> get copyWith =>
+      __$$BreezEvent_LspChangedImplCopyWithImpl<_$BreezEvent_LspChangedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return lspChanged(previousLspId, lspId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return lspChanged?.call(previousLspId, lspId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lspChanged != null) {
+      return lspChanged(previousLspId, lspId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return lspChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return lspChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lspChanged != null) {
+      return lspChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LspChanged implements BreezEvent {
+  const factory BreezEvent_LspChanged({final String? previousLspId, required final String lspId}) =
+      _$BreezEvent_LspChangedImpl;
+
+  String? get previousLspId;
+  String get lspId;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LspChangedImplCopyWith<_$BreezEvent_LspChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SwapRefundedImplCopyWith<$Res> {
+  factory _$$BreezEvent_SwapRefundedImplCopyWith(
+          _$BreezEvent_SwapRefundedImpl value, $Res Function(_$BreezEvent_SwapRefundedImpl) then) =
+      __$$BreezEvent_SwapRefundedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({SwapRefundedDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_SwapRefundedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SwapRefundedImpl>
+    implements _$$BreezEvent_SwapRefundedImplCopyWith<$Res> {
+  __$$BreezEvent_SwapRefundedImplCopyWithImpl(
+      _$BreezEvent_SwapRefundedImpl _value, $Res Function(_$BreezEvent_SwapRefundedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_SwapRefundedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as SwapRefundedDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SwapRefundedImpl implements BreezEvent_SwapRefunded {
+  const _$BreezEvent_SwapRefundedImpl({required this.details});
+
+  @override
+  final SwapRefundedDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.swapRefunded(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SwapRefundedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SwapRefundedImplCopyWith<_$BreezEvent_SwapRefundedImpl> get copyWith =>
+      __$$BreezEvent_SwapRefundedImplCopyWithImpl<_$BreezEvent_SwapRefundedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return swapRefunded(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return swapRefunded?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (swapRefunded != null) {
+      return swapRefunded(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return swapRefunded(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return swapRefunded?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (swapRefunded != null) {
+      return swapRefunded(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_SwapRefunded implements BreezEvent {
+  const factory BreezEvent_SwapRefunded({required final SwapRefundedDetails details}) =
+      _$BreezEvent_SwapRefundedImpl;
+
+  SwapRefundedDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SwapRefundedImplCopyWith<_$BreezEvent_SwapRefundedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_BatchPaymentProgressImplCopyWith<$Res> {
+  factory _$$BreezEvent_BatchPaymentProgressImplCopyWith(_$BreezEvent_BatchPaymentProgressImpl value,
+          $Res Function(_$BreezEvent_BatchPaymentProgressImpl) then) =
+      __$$BreezEvent_BatchPaymentProgressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BatchPaymentProgressDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_BatchPaymentProgressImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_BatchPaymentProgressImpl>
+    implements _$$BreezEvent_BatchPaymentProgressImplCopyWith<$Res> {
+  __$$BreezEvent_BatchPaymentProgressImplCopyWithImpl(_$BreezEvent_BatchPaymentProgressImpl _value,
+      $Res Function(_$BreezEvent_BatchPaymentProgressImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_BatchPaymentProgressImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as BatchPaymentProgressDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_BatchPaymentProgressImpl implements BreezEvent_BatchPaymentProgress {
+  const _$BreezEvent_BatchPaymentProgressImpl({required this.details});
+
+  @override
+  final BatchPaymentProgressDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.batchPaymentProgress(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_BatchPaymentProgressImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_BatchPaymentProgressImplCopyWith<_$BreezEvent_BatchPaymentProgressImpl> get copyWith =>
+      __$$BreezEvent_BatchPaymentProgressImplCopyWithImpl<_$BreezEvent_BatchPaymentProgressImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return batchPaymentProgress(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return batchPaymentProgress?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (batchPaymentProgress != null) {
+      return batchPaymentProgress(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return batchPaymentProgress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return batchPaymentProgress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (batchPaymentProgress != null) {
+      return batchPaymentProgress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_BatchPaymentProgress implements BreezEvent {
+  const factory BreezEvent_BatchPaymentProgress({required final BatchPaymentProgressDetails details}) =
+      _$BreezEvent_BatchPaymentProgressImpl;
+
+  BatchPaymentProgressDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_BatchPaymentProgressImplCopyWith<_$BreezEvent_BatchPaymentProgressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_OutboxPaymentUpdatedImplCopyWith<$Res> {
+  factory _$$BreezEvent_OutboxPaymentUpdatedImplCopyWith(_$BreezEvent_OutboxPaymentUpdatedImpl value,
+          $Res Function(_$BreezEvent_OutboxPaymentUpdatedImpl) then) =
+      __$$BreezEvent_OutboxPaymentUpdatedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({OutboxPaymentDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_OutboxPaymentUpdatedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_OutboxPaymentUpdatedImpl>
+    implements _$$BreezEvent_OutboxPaymentUpdatedImplCopyWith<$Res> {
+  __$$BreezEvent_OutboxPaymentUpdatedImplCopyWithImpl(_$BreezEvent_OutboxPaymentUpdatedImpl _value,
+      $Res Function(_$BreezEvent_OutboxPaymentUpdatedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_OutboxPaymentUpdatedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as OutboxPaymentDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_OutboxPaymentUpdatedImpl implements BreezEvent_OutboxPaymentUpdated {
+  const _$BreezEvent_OutboxPaymentUpdatedImpl({required this.details});
+
+  @override
+  final OutboxPaymentDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.outboxPaymentUpdated(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_OutboxPaymentUpdatedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_OutboxPaymentUpdatedImplCopyWith<_$BreezEvent_OutboxPaymentUpdatedImpl> get copyWith =>
+      __$$BreezEvent_OutboxPaymentUpdatedImplCopyWithImpl<_$BreezEvent_OutboxPaymentUpdatedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return outboxPaymentUpdated(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return outboxPaymentUpdated?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (outboxPaymentUpdated != null) {
+      return outboxPaymentUpdated(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return outboxPaymentUpdated(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return outboxPaymentUpdated?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (outboxPaymentUpdated != null) {
+      return outboxPaymentUpdated(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_OutboxPaymentUpdated implements BreezEvent {
+  const factory BreezEvent_OutboxPaymentUpdated({required final OutboxPaymentDetails details}) =
+      _$BreezEvent_OutboxPaymentUpdatedImpl;
+
+  OutboxPaymentDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_OutboxPaymentUpdatedImplCopyWith<_$BreezEvent_OutboxPaymentUpdatedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LnUrlAuthCompletedImplCopyWith<$Res> {
+  factory _$$BreezEvent_LnUrlAuthCompletedImplCopyWith(_$BreezEvent_LnUrlAuthCompletedImpl value,
+          $Res Function(_$BreezEvent_LnUrlAuthCompletedImpl) then) =
+      __$$BreezEvent_LnUrlAuthCompletedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlAuthDomain details});
+}
+
+/// @nodoc
+class __$$BreezEvent_LnUrlAuthCompletedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LnUrlAuthCompletedImpl>
+    implements _$$BreezEvent_LnUrlAuthCompletedImplCopyWith<$Res> {
+  __$$BreezEvent_LnUrlAuthCompletedImplCopyWithImpl(
+      _$BreezEvent_LnUrlAuthCompletedImpl _value, $Res Function(_$BreezEvent_LnUrlAuthCompletedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_LnUrlAuthCompletedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as LnUrlAuthDomain,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LnUrlAuthCompletedImpl implements BreezEvent_LnUrlAuthCompleted {
+  const _$BreezEvent_LnUrlAuthCompletedImpl({required this.details});
+
+  @override
+  final LnUrlAuthDomain details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lnUrlAuthCompleted(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LnUrlAuthCompletedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LnUrlAuthCompletedImplCopyWith<_$BreezEvent_LnUrlAuthCompletedImpl> get copyWith =>
+      __$$BreezEvent_LnUrlAuthCompletedImplCopyWithImpl<_$BreezEvent_LnUrlAuthCompletedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return lnUrlAuthCompleted(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return lnUrlAuthCompleted?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lnUrlAuthCompleted != null) {
+      return lnUrlAuthCompleted(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return lnUrlAuthCompleted(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return lnUrlAuthCompleted?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lnUrlAuthCompleted != null) {
+      return lnUrlAuthCompleted(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LnUrlAuthCompleted implements BreezEvent {
+  const factory BreezEvent_LnUrlAuthCompleted({required final LnUrlAuthDomain details}) =
+      _$BreezEvent_LnUrlAuthCompletedImpl;
+
+  LnUrlAuthDomain get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LnUrlAuthCompletedImplCopyWith<_$BreezEvent_LnUrlAuthCompletedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ChannelHygieneSuggestionImplCopyWith<$Res> {
+  factory _$$BreezEvent_ChannelHygieneSuggestionImplCopyWith(_$BreezEvent_ChannelHygieneSuggestionImpl value,
+          $Res Function(_$BreezEvent_ChannelHygieneSuggestionImpl) then) =
+      __$$BreezEvent_ChannelHygieneSuggestionImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ChannelHygieneSuggestionDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_ChannelHygieneSuggestionImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ChannelHygieneSuggestionImpl>
+    implements _$$BreezEvent_ChannelHygieneSuggestionImplCopyWith<$Res> {
+  __$$BreezEvent_ChannelHygieneSuggestionImplCopyWithImpl(_$BreezEvent_ChannelHygieneSuggestionImpl _value,
+      $Res Function(_$BreezEvent_ChannelHygieneSuggestionImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_ChannelHygieneSuggestionImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as ChannelHygieneSuggestionDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ChannelHygieneSuggestionImpl implements BreezEvent_ChannelHygieneSuggestion {
+  const _$BreezEvent_ChannelHygieneSuggestionImpl({required this.details});
+
+  @override
+  final ChannelHygieneSuggestionDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.channelHygieneSuggestion(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ChannelHygieneSuggestionImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ChannelHygieneSuggestionImplCopyWith<_$BreezEvent_ChannelHygieneSuggestionImpl>
+      get copyWith => __$$BreezEvent_ChannelHygieneSuggestionImplCopyWithImpl<
+          _$BreezEvent_ChannelHygieneSuggestionImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return channelHygieneSuggestion(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return channelHygieneSuggestion?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (channelHygieneSuggestion != null) {
+      return channelHygieneSuggestion(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return channelHygieneSuggestion(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return channelHygieneSuggestion?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (channelHygieneSuggestion != null) {
+      return channelHygieneSuggestion(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ChannelHygieneSuggestion implements BreezEvent {
+  const factory BreezEvent_ChannelHygieneSuggestion(
+          {required final ChannelHygieneSuggestionDetails details}) =
+      _$BreezEvent_ChannelHygieneSuggestionImpl;
+
+  ChannelHygieneSuggestionDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ChannelHygieneSuggestionImplCopyWith<_$BreezEvent_ChannelHygieneSuggestionImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_FiatRateAlertTriggeredImplCopyWith<$Res> {
+  factory _$$BreezEvent_FiatRateAlertTriggeredImplCopyWith(_$BreezEvent_FiatRateAlertTriggeredImpl value,
+          $Res Function(_$BreezEvent_FiatRateAlertTriggeredImpl) then) =
+      __$$BreezEvent_FiatRateAlertTriggeredImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({FiatRateAlertTriggeredDetails details});
+}
+
+/// @nodoc
+class __$$BreezEvent_FiatRateAlertTriggeredImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_FiatRateAlertTriggeredImpl>
+    implements _$$BreezEvent_FiatRateAlertTriggeredImplCopyWith<$Res> {
+  __$$BreezEvent_FiatRateAlertTriggeredImplCopyWithImpl(_$BreezEvent_FiatRateAlertTriggeredImpl _value,
+      $Res Function(_$BreezEvent_FiatRateAlertTriggeredImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_FiatRateAlertTriggeredImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as FiatRateAlertTriggeredDetails,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_FiatRateAlertTriggeredImpl implements BreezEvent_FiatRateAlertTriggered {
+  const _$BreezEvent_FiatRateAlertTriggeredImpl({required this.details});
+
+  @override
+  final FiatRateAlertTriggeredDetails details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.fiatRateAlertTriggered(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_FiatRateAlertTriggeredImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_FiatRateAlertTriggeredImplCopyWith<_$BreezEvent_FiatRateAlertTriggeredImpl> get copyWith =>
+      __$$BreezEvent_FiatRateAlertTriggeredImplCopyWithImpl<_$BreezEvent_FiatRateAlertTriggeredImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return fiatRateAlertTriggered(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return fiatRateAlertTriggered?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (fiatRateAlertTriggered != null) {
+      return fiatRateAlertTriggered(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return fiatRateAlertTriggered(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return fiatRateAlertTriggered?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (fiatRateAlertTriggered != null) {
+      return fiatRateAlertTriggered(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_FiatRateAlertTriggered implements BreezEvent {
+  const factory BreezEvent_FiatRateAlertTriggered({required final FiatRateAlertTriggeredDetails details}) =
+      _$BreezEvent_FiatRateAlertTriggeredImpl;
+
+  FiatRateAlertTriggeredDetails get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_FiatRateAlertTriggeredImplCopyWith<_$BreezEvent_FiatRateAlertTriggeredImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ConnectedImplCopyWith<$Res> {
+  factory _$$BreezEvent_ConnectedImplCopyWith(
+          _$BreezEvent_ConnectedImpl value, $Res Function(_$BreezEvent_ConnectedImpl) then) =
+      __$$BreezEvent_ConnectedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$BreezEvent_ConnectedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ConnectedImpl>
+    implements _$$BreezEvent_ConnectedImplCopyWith<$Res> {
+  __$$BreezEvent_ConnectedImplCopyWithImpl(
+      _$BreezEvent_ConnectedImpl _value, $Res Function(_$BreezEvent_ConnectedImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$BreezEvent_ConnectedImpl implements BreezEvent_Connected {
+  const _$BreezEvent_ConnectedImpl();
+
+  @override
+  String toString() {
+    return 'BreezEvent.connected()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$BreezEvent_ConnectedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return connected();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return connected?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (connected != null) {
+      return connected();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return connected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return connected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (connected != null) {
+      return connected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_Connected implements BreezEvent {
+  const factory BreezEvent_Connected() = _$BreezEvent_ConnectedImpl;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_DisconnectedImplCopyWith<$Res> {
+  factory _$$BreezEvent_DisconnectedImplCopyWith(
+          _$BreezEvent_DisconnectedImpl value, $Res Function(_$BreezEvent_DisconnectedImpl) then) =
+      __$$BreezEvent_DisconnectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String error});
+}
+
+/// @nodoc
+class __$$BreezEvent_DisconnectedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_DisconnectedImpl>
+    implements _$$BreezEvent_DisconnectedImplCopyWith<$Res> {
+  __$$BreezEvent_DisconnectedImplCopyWithImpl(
+      _$BreezEvent_DisconnectedImpl _value, $Res Function(_$BreezEvent_DisconnectedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? error = null,
+  }) {
+    return _then(_$BreezEvent_DisconnectedImpl(
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_DisconnectedImpl implements BreezEvent_Disconnected {
+  const _$BreezEvent_DisconnectedImpl({required this.error});
+
+  @override
+  final String error;
+
+  @override
+  String toString() {
+    return 'BreezEvent.disconnected(error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_DisconnectedImpl &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, error);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_DisconnectedImplCopyWith<_$BreezEvent_DisconnectedImpl> get copyWith =>
+      __$$BreezEvent_DisconnectedImplCopyWithImpl<_$BreezEvent_DisconnectedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return disconnected(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return disconnected?.call(error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (disconnected != null) {
+      return disconnected(error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return disconnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return disconnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (disconnected != null) {
+      return disconnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_Disconnected implements BreezEvent {
+  const factory BreezEvent_Disconnected({required final String error}) = _$BreezEvent_DisconnectedImpl;
+
+  String get error;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_DisconnectedImplCopyWith<_$BreezEvent_DisconnectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ReconnectingImplCopyWith<$Res> {
+  factory _$$BreezEvent_ReconnectingImplCopyWith(
+          _$BreezEvent_ReconnectingImpl value, $Res Function(_$BreezEvent_ReconnectingImpl) then) =
+      __$$BreezEvent_ReconnectingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int attempt, int delayMs});
+}
+
+/// @nodoc
+class __$$BreezEvent_ReconnectingImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ReconnectingImpl>
+    implements _$$BreezEvent_ReconnectingImplCopyWith<$Res> {
+  __$$BreezEvent_ReconnectingImplCopyWithImpl(
+      _$BreezEvent_ReconnectingImpl _value, $Res Function(_$BreezEvent_ReconnectingImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? attempt = null,
+    Object? delayMs = null,
+  }) {
+    return _then(_$BreezEvent_ReconnectingImpl(
+      attempt: null == attempt
+          ? _value.attempt
+          : attempt // ignore: cast_nullable_to_non_nullable
+              as int,
+      delayMs: null == delayMs
+          ? _value.delayMs
+          : delayMs // ignore: cast_nullable_to_non_nullable
+              as int,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ReconnectingImpl implements BreezEvent_Reconnecting {
+  const _$BreezEvent_ReconnectingImpl({required this.attempt, required this.delayMs});
+
+  @override
+  final int attempt;
+  @override
+  final int delayMs;
+
+  @override
+  String toString() {
+    return 'BreezEvent.reconnecting(attempt: $attempt, delayMs: $delayMs)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ReconnectingImpl &&
+            (identical(other.attempt, attempt) || other.attempt == attempt) &&
+            (identical(other.delayMs, delayMs) || other.delayMs == delayMs));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, attempt, delayMs);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ReconnectingImplCopyWith<_$BreezEvent_ReconnectingImpl> get copyWith =>
+      __$$BreezEvent_ReconnectingImplCopyWithImpl<_$BreezEvent_ReconnectingImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return reconnecting(attempt, delayMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return reconnecting?.call(attempt, delayMs);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (reconnecting != null) {
+      return reconnecting(attempt, delayMs);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return reconnecting(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return reconnecting?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (reconnecting != null) {
+      return reconnecting(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_Reconnecting implements BreezEvent {
+  const factory BreezEvent_Reconnecting({required final int attempt, required final int delayMs}) =
+      _$BreezEvent_ReconnectingImpl;
+
+  int get attempt;
+  int get delayMs;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ReconnectingImplCopyWith<_$BreezEvent_ReconnectingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_LspPeerReconnectedImplCopyWith<$Res> {
+  factory _$$BreezEvent_LspPeerReconnectedImplCopyWith(_$BreezEvent_LspPeerReconnectedImpl value,
+          $Res Function(_$BreezEvent_LspPeerReconnectedImpl) then) =
+      __$$BreezEvent_LspPeerReconnectedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String lspPubkey, String reason});
+}
+
+/// @nodoc
+class __$$BreezEvent_LspPeerReconnectedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_LspPeerReconnectedImpl>
+    implements _$$BreezEvent_LspPeerReconnectedImplCopyWith<$Res> {
+  __$$BreezEvent_LspPeerReconnectedImplCopyWithImpl(
+      _$BreezEvent_LspPeerReconnectedImpl _value, $Res Function(_$BreezEvent_LspPeerReconnectedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? lspPubkey = null,
+    Object? reason = null,
+  }) {
+    return _then(_$BreezEvent_LspPeerReconnectedImpl(
+      lspPubkey: null == lspPubkey
+          ? _value.lspPubkey
+          : lspPubkey // ignore: cast_nullable_to_non_nullable
+              as String,
+      reason: null == reason
+          ? _value.reason
+          : reason // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_LspPeerReconnectedImpl implements BreezEvent_LspPeerReconnected {
+  const _$BreezEvent_LspPeerReconnectedImpl({required this.lspPubkey, required this.reason});
+
+  @override
+  final String lspPubkey;
+  @override
+  final String reason;
+
+  @override
+  String toString() {
+    return 'BreezEvent.lspPeerReconnected(lspPubkey: $lspPubkey, reason: $reason)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_LspPeerReconnectedImpl &&
+            (identical(other.lspPubkey, lspPubkey) || other.lspPubkey == lspPubkey) &&
+            (identical(other.reason, reason) || other.reason == reason));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, lspPubkey, reason);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_LspPeerReconnectedImplCopyWith<_$BreezEvent_LspPeerReconnectedImpl> get copyWith =>
+      __$$BreezEvent_LspPeerReconnectedImplCopyWithImpl<_$BreezEvent_LspPeerReconnectedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return lspPeerReconnected(lspPubkey, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return lspPeerReconnected?.call(lspPubkey, reason);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lspPeerReconnected != null) {
+      return lspPeerReconnected(lspPubkey, reason);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return lspPeerReconnected(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return lspPeerReconnected?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (lspPeerReconnected != null) {
+      return lspPeerReconnected(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_LspPeerReconnected implements BreezEvent {
+  const factory BreezEvent_LspPeerReconnected(
+          {required final String lspPubkey, required final String reason}) =
+      _$BreezEvent_LspPeerReconnectedImpl;
+
+  String get lspPubkey;
+  String get reason;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_LspPeerReconnectedImplCopyWith<_$BreezEvent_LspPeerReconnectedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_SpontaneousPaymentReceivedImplCopyWith<$Res> {
+  factory _$$BreezEvent_SpontaneousPaymentReceivedImplCopyWith(
+          _$BreezEvent_SpontaneousPaymentReceivedImpl value,
+          $Res Function(_$BreezEvent_SpontaneousPaymentReceivedImpl) then) =
+      __$$BreezEvent_SpontaneousPaymentReceivedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$BreezEvent_SpontaneousPaymentReceivedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_SpontaneousPaymentReceivedImpl>
+    implements _$$BreezEvent_SpontaneousPaymentReceivedImplCopyWith<$Res> {
+  __$$BreezEvent_SpontaneousPaymentReceivedImplCopyWithImpl(
+      _$BreezEvent_SpontaneousPaymentReceivedImpl _value,
+      $Res Function(_$BreezEvent_SpontaneousPaymentReceivedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$BreezEvent_SpontaneousPaymentReceivedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_SpontaneousPaymentReceivedImpl implements BreezEvent_SpontaneousPaymentReceived {
+  const _$BreezEvent_SpontaneousPaymentReceivedImpl({required this.details});
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'BreezEvent.spontaneousPaymentReceived(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_SpontaneousPaymentReceivedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_SpontaneousPaymentReceivedImplCopyWith<_$BreezEvent_SpontaneousPaymentReceivedImpl>
+      get copyWith => __$$BreezEvent_SpontaneousPaymentReceivedImplCopyWithImpl<
+          _$BreezEvent_SpontaneousPaymentReceivedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return spontaneousPaymentReceived(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return spontaneousPaymentReceived?.call(details);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (spontaneousPaymentReceived != null) {
+      return spontaneousPaymentReceived(details);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return spontaneousPaymentReceived(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return spontaneousPaymentReceived?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (spontaneousPaymentReceived != null) {
+      return spontaneousPaymentReceived(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_SpontaneousPaymentReceived implements BreezEvent {
+  const factory BreezEvent_SpontaneousPaymentReceived({required final Payment details}) =
+      _$BreezEvent_SpontaneousPaymentReceivedImpl;
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_SpontaneousPaymentReceivedImplCopyWith<_$BreezEvent_SpontaneousPaymentReceivedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$BreezEvent_ServiceStatusChangedImplCopyWith<$Res> {
+  factory _$$BreezEvent_ServiceStatusChangedImplCopyWith(_$BreezEvent_ServiceStatusChangedImpl value,
+          $Res Function(_$BreezEvent_ServiceStatusChangedImpl) then) =
+      __$$BreezEvent_ServiceStatusChangedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({ServiceComponent component, ServiceStatus status});
+}
+
+/// @nodoc
+class __$$BreezEvent_ServiceStatusChangedImplCopyWithImpl<$Res>
+    extends _$BreezEventCopyWithImpl<$Res, _$BreezEvent_ServiceStatusChangedImpl>
+    implements _$$BreezEvent_ServiceStatusChangedImplCopyWith<$Res> {
+  __$$BreezEvent_ServiceStatusChangedImplCopyWithImpl(_$BreezEvent_ServiceStatusChangedImpl _value,
+      $Res Function(_$BreezEvent_ServiceStatusChangedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? component = null,
+    Object? status = null,
+  }) {
+    return _then(_$BreezEvent_ServiceStatusChangedImpl(
+      component: null == component
+          ? _value.component
+          : component // ignore: cast_nullable_to_non_nullable
+              as ServiceComponent,
+      status: null == status
+          ? _value.status
+          : status // ignore: cast_nullable_to_non_nullable
+              as ServiceStatus,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$BreezEvent_ServiceStatusChangedImpl implements BreezEvent_ServiceStatusChanged {
+  const _$BreezEvent_ServiceStatusChangedImpl({required this.component, required this.status});
+
+  @override
+  final ServiceComponent component;
+  @override
+  final ServiceStatus status;
+
+  @override
+  String toString() {
+    return 'BreezEvent.serviceStatusChanged(component: $component, status: $status)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$BreezEvent_ServiceStatusChangedImpl &&
+            (identical(other.component, component) || other.component == component) &&
+            (identical(other.status, status) || other.status == status));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, component, status);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$BreezEvent_ServiceStatusChangedImplCopyWith<_$BreezEvent_ServiceStatusChangedImpl> get copyWith =>
+      __$$BreezEvent_ServiceStatusChangedImplCopyWithImpl<_$BreezEvent_ServiceStatusChangedImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int block) newBlock,
+    required TResult Function(InvoicePaidDetails details) invoicePaid,
+    required TResult Function() synced,
+    required TResult Function(Payment details) paymentSucceed,
+    required TResult Function(PaymentFailedData details) paymentFailed,
+    required TResult Function() backupStarted,
+    required TResult Function() backupSucceeded,
+    required TResult Function(BackupFailedData details) backupFailed,
+    required TResult Function(ReverseSwapInfo details) reverseSwapUpdated,
+    required TResult Function(SwapInfo details) swapUpdated,
+    required TResult Function(ConnectProgressDetails details) connectProgress,
+    required TResult Function(UnredeemedFundsDetails details) unredeemedFundsDetected,
+    required TResult Function(LnUrlPayFinishedDetails details) lnUrlPayFinished,
+    required TResult Function(OpenChannelReceiveDetails details) openChannelReceiveUpdated,
+    required TResult Function(LowLiquidityDetails details) lowOutboundLiquidity,
+    required TResult Function(LowLiquidityDetails details) lowInboundLiquidity,
+    required TResult Function(int feeMsat, int minFeeMsat, int proportional) channelOpening,
+    required TResult Function(String? previousLspId, String lspId) lspChanged,
+    required TResult Function(SwapRefundedDetails details) swapRefunded,
+    required TResult Function(BatchPaymentProgressDetails details) batchPaymentProgress,
+    required TResult Function(OutboxPaymentDetails details) outboxPaymentUpdated,
+    required TResult Function(LnUrlAuthDomain details) lnUrlAuthCompleted,
+    required TResult Function(ChannelHygieneSuggestionDetails details) channelHygieneSuggestion,
+    required TResult Function(FiatRateAlertTriggeredDetails details) fiatRateAlertTriggered,
+    required TResult Function() connected,
+    required TResult Function(String error) disconnected,
+    required TResult Function(int attempt, int delayMs) reconnecting,
+    required TResult Function(String lspPubkey, String reason) lspPeerReconnected,
+    required TResult Function(Payment details) spontaneousPaymentReceived,
+    required TResult Function(ServiceComponent component, ServiceStatus status) serviceStatusChanged,
+  }) {
+    return serviceStatusChanged(component, status);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int block)? newBlock,
+    TResult? Function(InvoicePaidDetails details)? invoicePaid,
+    TResult? Function()? synced,
+    TResult? Function(Payment details)? paymentSucceed,
+    TResult? Function(PaymentFailedData details)? paymentFailed,
+    TResult? Function()? backupStarted,
+    TResult? Function()? backupSucceeded,
+    TResult? Function(BackupFailedData details)? backupFailed,
+    TResult? Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult? Function(SwapInfo details)? swapUpdated,
+    TResult? Function(ConnectProgressDetails details)? connectProgress,
+    TResult? Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult? Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult? Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult? Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult? Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult? Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult? Function(String? previousLspId, String lspId)? lspChanged,
+    TResult? Function(SwapRefundedDetails details)? swapRefunded,
+    TResult? Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult? Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult? Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult? Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult? Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult? Function()? connected,
+    TResult? Function(String error)? disconnected,
+    TResult? Function(int attempt, int delayMs)? reconnecting,
+    TResult? Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult? Function(Payment details)? spontaneousPaymentReceived,
+    TResult? Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+  }) {
+    return serviceStatusChanged?.call(component, status);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int block)? newBlock,
+    TResult Function(InvoicePaidDetails details)? invoicePaid,
+    TResult Function()? synced,
+    TResult Function(Payment details)? paymentSucceed,
+    TResult Function(PaymentFailedData details)? paymentFailed,
+    TResult Function()? backupStarted,
+    TResult Function()? backupSucceeded,
+    TResult Function(BackupFailedData details)? backupFailed,
+    TResult Function(ReverseSwapInfo details)? reverseSwapUpdated,
+    TResult Function(SwapInfo details)? swapUpdated,
+    TResult Function(ConnectProgressDetails details)? connectProgress,
+    TResult Function(UnredeemedFundsDetails details)? unredeemedFundsDetected,
+    TResult Function(LnUrlPayFinishedDetails details)? lnUrlPayFinished,
+    TResult Function(OpenChannelReceiveDetails details)? openChannelReceiveUpdated,
+    TResult Function(LowLiquidityDetails details)? lowOutboundLiquidity,
+    TResult Function(LowLiquidityDetails details)? lowInboundLiquidity,
+    TResult Function(int feeMsat, int minFeeMsat, int proportional)? channelOpening,
+    TResult Function(String? previousLspId, String lspId)? lspChanged,
+    TResult Function(SwapRefundedDetails details)? swapRefunded,
+    TResult Function(BatchPaymentProgressDetails details)? batchPaymentProgress,
+    TResult Function(OutboxPaymentDetails details)? outboxPaymentUpdated,
+    TResult Function(LnUrlAuthDomain details)? lnUrlAuthCompleted,
+    TResult Function(ChannelHygieneSuggestionDetails details)? channelHygieneSuggestion,
+    TResult Function(FiatRateAlertTriggeredDetails details)? fiatRateAlertTriggered,
+    TResult Function()? connected,
+    TResult Function(String error)? disconnected,
+    TResult Function(int attempt, int delayMs)? reconnecting,
+    TResult Function(String lspPubkey, String reason)? lspPeerReconnected,
+    TResult Function(Payment details)? spontaneousPaymentReceived,
+    TResult Function(ServiceComponent component, ServiceStatus status)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (serviceStatusChanged != null) {
+      return serviceStatusChanged(component, status);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(BreezEvent_NewBlock value) newBlock,
+    required TResult Function(BreezEvent_InvoicePaid value) invoicePaid,
+    required TResult Function(BreezEvent_Synced value) synced,
+    required TResult Function(BreezEvent_PaymentSucceed value) paymentSucceed,
+    required TResult Function(BreezEvent_PaymentFailed value) paymentFailed,
+    required TResult Function(BreezEvent_BackupStarted value) backupStarted,
+    required TResult Function(BreezEvent_BackupSucceeded value) backupSucceeded,
+    required TResult Function(BreezEvent_BackupFailed value) backupFailed,
+    required TResult Function(BreezEvent_ReverseSwapUpdated value) reverseSwapUpdated,
+    required TResult Function(BreezEvent_SwapUpdated value) swapUpdated,
+    required TResult Function(BreezEvent_ConnectProgress value) connectProgress,
+    required TResult Function(BreezEvent_UnredeemedFundsDetected value) unredeemedFundsDetected,
+    required TResult Function(BreezEvent_LnUrlPayFinished value) lnUrlPayFinished,
+    required TResult Function(BreezEvent_OpenChannelReceiveUpdated value) openChannelReceiveUpdated,
+    required TResult Function(BreezEvent_LowOutboundLiquidity value) lowOutboundLiquidity,
+    required TResult Function(BreezEvent_LowInboundLiquidity value) lowInboundLiquidity,
+    required TResult Function(BreezEvent_ChannelOpening value) channelOpening,
+    required TResult Function(BreezEvent_LspChanged value) lspChanged,
+    required TResult Function(BreezEvent_SwapRefunded value) swapRefunded,
+    required TResult Function(BreezEvent_BatchPaymentProgress value) batchPaymentProgress,
+    required TResult Function(BreezEvent_OutboxPaymentUpdated value) outboxPaymentUpdated,
+    required TResult Function(BreezEvent_LnUrlAuthCompleted value) lnUrlAuthCompleted,
+    required TResult Function(BreezEvent_ChannelHygieneSuggestion value) channelHygieneSuggestion,
+    required TResult Function(BreezEvent_FiatRateAlertTriggered value) fiatRateAlertTriggered,
+    required TResult Function(BreezEvent_Connected value) connected,
+    required TResult Function(BreezEvent_Disconnected value) disconnected,
+    required TResult Function(BreezEvent_Reconnecting value) reconnecting,
+    required TResult Function(BreezEvent_LspPeerReconnected value) lspPeerReconnected,
+    required TResult Function(BreezEvent_SpontaneousPaymentReceived value) spontaneousPaymentReceived,
+    required TResult Function(BreezEvent_ServiceStatusChanged value) serviceStatusChanged,
+  }) {
+    return serviceStatusChanged(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(BreezEvent_NewBlock value)? newBlock,
+    TResult? Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult? Function(BreezEvent_Synced value)? synced,
+    TResult? Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult? Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult? Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult? Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult? Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult? Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult? Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult? Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult? Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult? Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult? Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult? Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult? Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult? Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult? Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult? Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult? Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult? Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult? Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult? Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult? Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult? Function(BreezEvent_Connected value)? connected,
+    TResult? Function(BreezEvent_Disconnected value)? disconnected,
+    TResult? Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult? Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult? Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult? Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+  }) {
+    return serviceStatusChanged?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(BreezEvent_NewBlock value)? newBlock,
+    TResult Function(BreezEvent_InvoicePaid value)? invoicePaid,
+    TResult Function(BreezEvent_Synced value)? synced,
+    TResult Function(BreezEvent_PaymentSucceed value)? paymentSucceed,
+    TResult Function(BreezEvent_PaymentFailed value)? paymentFailed,
+    TResult Function(BreezEvent_BackupStarted value)? backupStarted,
+    TResult Function(BreezEvent_BackupSucceeded value)? backupSucceeded,
+    TResult Function(BreezEvent_BackupFailed value)? backupFailed,
+    TResult Function(BreezEvent_ReverseSwapUpdated value)? reverseSwapUpdated,
+    TResult Function(BreezEvent_SwapUpdated value)? swapUpdated,
+    TResult Function(BreezEvent_ConnectProgress value)? connectProgress,
+    TResult Function(BreezEvent_UnredeemedFundsDetected value)? unredeemedFundsDetected,
+    TResult Function(BreezEvent_LnUrlPayFinished value)? lnUrlPayFinished,
+    TResult Function(BreezEvent_OpenChannelReceiveUpdated value)? openChannelReceiveUpdated,
+    TResult Function(BreezEvent_LowOutboundLiquidity value)? lowOutboundLiquidity,
+    TResult Function(BreezEvent_LowInboundLiquidity value)? lowInboundLiquidity,
+    TResult Function(BreezEvent_ChannelOpening value)? channelOpening,
+    TResult Function(BreezEvent_LspChanged value)? lspChanged,
+    TResult Function(BreezEvent_SwapRefunded value)? swapRefunded,
+    TResult Function(BreezEvent_BatchPaymentProgress value)? batchPaymentProgress,
+    TResult Function(BreezEvent_OutboxPaymentUpdated value)? outboxPaymentUpdated,
+    TResult Function(BreezEvent_LnUrlAuthCompleted value)? lnUrlAuthCompleted,
+    TResult Function(BreezEvent_ChannelHygieneSuggestion value)? channelHygieneSuggestion,
+    TResult Function(BreezEvent_FiatRateAlertTriggered value)? fiatRateAlertTriggered,
+    TResult Function(BreezEvent_Connected value)? connected,
+    TResult Function(BreezEvent_Disconnected value)? disconnected,
+    TResult Function(BreezEvent_Reconnecting value)? reconnecting,
+    TResult Function(BreezEvent_LspPeerReconnected value)? lspPeerReconnected,
+    TResult Function(BreezEvent_SpontaneousPaymentReceived value)? spontaneousPaymentReceived,
+    TResult Function(BreezEvent_ServiceStatusChanged value)? serviceStatusChanged,
+    required TResult orElse(),
+  }) {
+    if (serviceStatusChanged != null) {
+      return serviceStatusChanged(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class BreezEvent_ServiceStatusChanged implements BreezEvent {
+  const factory BreezEvent_ServiceStatusChanged(
+          {required final ServiceComponent component, required final ServiceStatus status}) =
+      _$BreezEvent_ServiceStatusChangedImpl;
+
+  ServiceComponent get component;
+  ServiceStatus get status;
+  @JsonKey(ignore: true)
+  _$$BreezEvent_ServiceStatusChangedImplCopyWith<_$BreezEvent_ServiceStatusChangedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$ChainServiceConfig {
+  String get url => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String url) esplora,
+    required TResult Function(String url) electrum,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String url)? esplora,
+    TResult? Function(String url)? electrum,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String url)? esplora,
+    TResult Function(String url)? electrum,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ChainServiceConfig_Esplora value) esplora,
+    required TResult Function(ChainServiceConfig_Electrum value) electrum,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ChainServiceConfig_Esplora value)? esplora,
+    TResult? Function(ChainServiceConfig_Electrum value)? electrum,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ChainServiceConfig_Esplora value)? esplora,
+    TResult Function(ChainServiceConfig_Electrum value)? electrum,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @JsonKey(ignore: true)
+  $ChainServiceConfigCopyWith<ChainServiceConfig> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $ChainServiceConfigCopyWith<$Res> {
+  factory $ChainServiceConfigCopyWith(ChainServiceConfig value, $Res Function(ChainServiceConfig) then) =
+      _$ChainServiceConfigCopyWithImpl<$Res, ChainServiceConfig>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class _$ChainServiceConfigCopyWithImpl<$Res, $Val extends ChainServiceConfig>
+    implements $ChainServiceConfigCopyWith<$Res> {
+  _$ChainServiceConfigCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_value.copyWith(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$ChainServiceConfig_EsploraImplCopyWith<$Res> implements $ChainServiceConfigCopyWith<$Res> {
+  factory _$$ChainServiceConfig_EsploraImplCopyWith(
+          _$ChainServiceConfig_EsploraImpl value, $Res Function(_$ChainServiceConfig_EsploraImpl) then) =
+      __$$ChainServiceConfig_EsploraImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$ChainServiceConfig_EsploraImplCopyWithImpl<$Res>
+    extends _$ChainServiceConfigCopyWithImpl<$Res, _$ChainServiceConfig_EsploraImpl>
+    implements _$$ChainServiceConfig_EsploraImplCopyWith<$Res> {
+  __$$ChainServiceConfig_EsploraImplCopyWithImpl(
+      _$ChainServiceConfig_EsploraImpl _value, $Res Function(_$ChainServiceConfig_EsploraImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$ChainServiceConfig_EsploraImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChainServiceConfig_EsploraImpl implements ChainServiceConfig_Esplora {
+  const _$ChainServiceConfig_EsploraImpl({required this.url});
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'ChainServiceConfig.esplora(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChainServiceConfig_EsploraImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChainServiceConfig_EsploraImplCopyWith<_$ChainServiceConfig_EsploraImpl> get copyWith =>
+      __$$ChainServiceConfig_EsploraImplCopyWithImpl<_$ChainServiceConfig_EsploraImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String url) esplora,
+    required TResult Function(String url) electrum,
+  }) {
+    return esplora(url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String url)? esplora,
+    TResult? Function(String url)? electrum,
+  }) {
+    return esplora?.call(url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String url)? esplora,
+    TResult Function(String url)? electrum,
+    required TResult orElse(),
+  }) {
+    if (esplora != null) {
+      return esplora(url);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ChainServiceConfig_Esplora value) esplora,
+    required TResult Function(ChainServiceConfig_Electrum value) electrum,
+  }) {
+    return esplora(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ChainServiceConfig_Esplora value)? esplora,
+    TResult? Function(ChainServiceConfig_Electrum value)? electrum,
+  }) {
+    return esplora?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ChainServiceConfig_Esplora value)? esplora,
+    TResult Function(ChainServiceConfig_Electrum value)? electrum,
+    required TResult orElse(),
+  }) {
+    if (esplora != null) {
+      return esplora(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ChainServiceConfig_Esplora implements ChainServiceConfig {
+  const factory ChainServiceConfig_Esplora({required final String url}) = _$ChainServiceConfig_EsploraImpl;
+
+  @override
+  String get url;
+  @override
+  @JsonKey(ignore: true)
+  _$$ChainServiceConfig_EsploraImplCopyWith<_$ChainServiceConfig_EsploraImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$ChainServiceConfig_ElectrumImplCopyWith<$Res> implements $ChainServiceConfigCopyWith<$Res> {
+  factory _$$ChainServiceConfig_ElectrumImplCopyWith(
+          _$ChainServiceConfig_ElectrumImpl value, $Res Function(_$ChainServiceConfig_ElectrumImpl) then) =
+      __$$ChainServiceConfig_ElectrumImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$ChainServiceConfig_ElectrumImplCopyWithImpl<$Res>
+    extends _$ChainServiceConfigCopyWithImpl<$Res, _$ChainServiceConfig_ElectrumImpl>
+    implements _$$ChainServiceConfig_ElectrumImplCopyWith<$Res> {
+  __$$ChainServiceConfig_ElectrumImplCopyWithImpl(
+      _$ChainServiceConfig_ElectrumImpl _value, $Res Function(_$ChainServiceConfig_ElectrumImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$ChainServiceConfig_ElectrumImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$ChainServiceConfig_ElectrumImpl implements ChainServiceConfig_Electrum {
+  const _$ChainServiceConfig_ElectrumImpl({required this.url});
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'ChainServiceConfig.electrum(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$ChainServiceConfig_ElectrumImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$ChainServiceConfig_ElectrumImplCopyWith<_$ChainServiceConfig_ElectrumImpl> get copyWith =>
+      __$$ChainServiceConfig_ElectrumImplCopyWithImpl<_$ChainServiceConfig_ElectrumImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String url) esplora,
+    required TResult Function(String url) electrum,
+  }) {
+    return electrum(url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String url)? esplora,
+    TResult? Function(String url)? electrum,
+  }) {
+    return electrum?.call(url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String url)? esplora,
+    TResult Function(String url)? electrum,
+    required TResult orElse(),
+  }) {
+    if (electrum != null) {
+      return electrum(url);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(ChainServiceConfig_Esplora value) esplora,
+    required TResult Function(ChainServiceConfig_Electrum value) electrum,
+  }) {
+    return electrum(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(ChainServiceConfig_Esplora value)? esplora,
+    TResult? Function(ChainServiceConfig_Electrum value)? electrum,
+  }) {
+    return electrum?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(ChainServiceConfig_Esplora value)? esplora,
+    TResult Function(ChainServiceConfig_Electrum value)? electrum,
+    required TResult orElse(),
+  }) {
+    if (electrum != null) {
+      return electrum(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class ChainServiceConfig_Electrum implements ChainServiceConfig {
+  const factory ChainServiceConfig_Electrum({required final String url}) = _$ChainServiceConfig_ElectrumImpl;
+
+  @override
+  String get url;
+  @override
+  @JsonKey(ignore: true)
+  _$$ChainServiceConfig_ElectrumImplCopyWith<_$ChainServiceConfig_ElectrumImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$InputType {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $InputTypeCopyWith<$Res> {
+  factory $InputTypeCopyWith(InputType value, $Res Function(InputType) then) =
+      _$InputTypeCopyWithImpl<$Res, InputType>;
+}
+
+/// @nodoc
+class _$InputTypeCopyWithImpl<$Res, $Val extends InputType> implements $InputTypeCopyWith<$Res> {
+  _$InputTypeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$InputType_BitcoinAddressImplCopyWith<$Res> {
+  factory _$$InputType_BitcoinAddressImplCopyWith(
+          _$InputType_BitcoinAddressImpl value, $Res Function(_$InputType_BitcoinAddressImpl) then) =
+      __$$InputType_BitcoinAddressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BitcoinAddressData address});
+}
+
+/// @nodoc
+class __$$InputType_BitcoinAddressImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_BitcoinAddressImpl>
+    implements _$$InputType_BitcoinAddressImplCopyWith<$Res> {
+  __$$InputType_BitcoinAddressImplCopyWithImpl(
+      _$InputType_BitcoinAddressImpl _value, $Res Function(_$InputType_BitcoinAddressImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? address = null,
+  }) {
+    return _then(_$InputType_BitcoinAddressImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as BitcoinAddressData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_BitcoinAddressImpl implements InputType_BitcoinAddress {
+  const _$InputType_BitcoinAddressImpl({required this.address});
+
+  @override
+  final BitcoinAddressData address;
+
+  @override
+  String toString() {
+    return 'InputType.bitcoinAddress(address: $address)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_BitcoinAddressImpl &&
+            (identical(other.address, address) || other.address == address));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, address);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_BitcoinAddressImplCopyWith<_$InputType_BitcoinAddressImpl> get copyWith =>
+      __$$InputType_BitcoinAddressImplCopyWithImpl<_$InputType_BitcoinAddressImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return bitcoinAddress(address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return bitcoinAddress?.call(address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (bitcoinAddress != null) {
+      return bitcoinAddress(address);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return bitcoinAddress(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return bitcoinAddress?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (bitcoinAddress != null) {
+      return bitcoinAddress(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_BitcoinAddress implements InputType {
+  const factory InputType_BitcoinAddress({required final BitcoinAddressData address}) =
+      _$InputType_BitcoinAddressImpl;
+
+  BitcoinAddressData get address;
+  @JsonKey(ignore: true)
+  _$$InputType_BitcoinAddressImplCopyWith<_$InputType_BitcoinAddressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_Bolt11ImplCopyWith<$Res> {
+  factory _$$InputType_Bolt11ImplCopyWith(
+          _$InputType_Bolt11Impl value, $Res Function(_$InputType_Bolt11Impl) then) =
+      __$$InputType_Bolt11ImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LNInvoice invoice});
+}
+
+/// @nodoc
+class __$$InputType_Bolt11ImplCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt11Impl>
+    implements _$$InputType_Bolt11ImplCopyWith<$Res> {
+  __$$InputType_Bolt11ImplCopyWithImpl(
+      _$InputType_Bolt11Impl _value, $Res Function(_$InputType_Bolt11Impl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? invoice = null,
+  }) {
+    return _then(_$InputType_Bolt11Impl(
+      invoice: null == invoice
+          ? _value.invoice
+          : invoice // ignore: cast_nullable_to_non_nullable
+              as LNInvoice,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_Bolt11Impl implements InputType_Bolt11 {
+  const _$InputType_Bolt11Impl({required this.invoice});
+
+  @override
+  final LNInvoice invoice;
+
+  @override
+  String toString() {
+    return 'InputType.bolt11(invoice: $invoice)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_Bolt11Impl &&
+            (identical(other.invoice, invoice) || other.invoice == invoice));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, invoice);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_Bolt11ImplCopyWith<_$InputType_Bolt11Impl> get copyWith =>
+      __$$InputType_Bolt11ImplCopyWithImpl<_$InputType_Bolt11Impl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return bolt11(invoice);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return bolt11?.call(invoice);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (bolt11 != null) {
+      return bolt11(invoice);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return bolt11(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return bolt11?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (bolt11 != null) {
+      return bolt11(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_Bolt11 implements InputType {
+  const factory InputType_Bolt11({required final LNInvoice invoice}) = _$InputType_Bolt11Impl;
+
+  LNInvoice get invoice;
+  @JsonKey(ignore: true)
+  _$$InputType_Bolt11ImplCopyWith<_$InputType_Bolt11Impl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_Bolt12OfferImplCopyWith<$Res> {
+  factory _$$InputType_Bolt12OfferImplCopyWith(
+          _$InputType_Bolt12OfferImpl value, $Res Function(_$InputType_Bolt12OfferImpl) then) =
+      __$$InputType_Bolt12OfferImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LNOffer offer, String? bip353Address});
+}
+
+/// @nodoc
+class __$$InputType_Bolt12OfferImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt12OfferImpl>
+    implements _$$InputType_Bolt12OfferImplCopyWith<$Res> {
+  __$$InputType_Bolt12OfferImplCopyWithImpl(
+      _$InputType_Bolt12OfferImpl _value, $Res Function(_$InputType_Bolt12OfferImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? offer = null,
+    Object? bip353Address = freezed,
+  }) {
+    return _then(_$InputType_Bolt12OfferImpl(
+      offer: null == offer
+          ? _value.offer
+          : offer // ignore: cast_nullable_to_non_nullable
+              as LNOffer,
+      bip353Address: freezed == bip353Address
+          ? _value.bip353Address
+          : bip353Address // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_Bolt12OfferImpl implements InputType_Bolt12Offer {
+  const _$InputType_Bolt12OfferImpl({required this.offer, this.bip353Address});
+
+  @override
+  final LNOffer offer;
+  @override
+  final String? bip353Address;
+
+  @override
+  String toString() {
+    return 'InputType.bolt12Offer(offer: $offer, bip353Address: $bip353Address)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_Bolt12OfferImpl &&
+            (identical(other.offer, offer) || other.offer == offer) &&
+            (identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, offer, bip353Address);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_Bolt12OfferImplCopyWith<_$InputType_Bolt12OfferImpl> get copyWith =>
+      __$$InputType_Bolt12OfferImplCopyWithImpl<_$InputType_Bolt12OfferImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return bolt12Offer(offer, bip353Address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return bolt12Offer?.call(offer, bip353Address);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (bolt12Offer != null) {
+      return bolt12Offer(offer, bip353Address);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return bolt12Offer(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return bolt12Offer?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (bolt12Offer != null) {
+      return bolt12Offer(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_Bolt12Offer implements InputType {
+  const factory InputType_Bolt12Offer({required final LNOffer offer, final String? bip353Address}) =
+      _$InputType_Bolt12OfferImpl;
+
+  LNOffer get offer;
+  String? get bip353Address;
+  @JsonKey(ignore: true)
+  _$$InputType_Bolt12OfferImplCopyWith<_$InputType_Bolt12OfferImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_NodeIdImplCopyWith<$Res> {
+  factory _$$InputType_NodeIdImplCopyWith(
+          _$InputType_NodeIdImpl value, $Res Function(_$InputType_NodeIdImpl) then) =
+      __$$InputType_NodeIdImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String nodeId});
+}
+
+/// @nodoc
+class __$$InputType_NodeIdImplCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_NodeIdImpl>
+    implements _$$InputType_NodeIdImplCopyWith<$Res> {
+  __$$InputType_NodeIdImplCopyWithImpl(
+      _$InputType_NodeIdImpl _value, $Res Function(_$InputType_NodeIdImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? nodeId = null,
+  }) {
+    return _then(_$InputType_NodeIdImpl(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_NodeIdImpl implements InputType_NodeId {
+  const _$InputType_NodeIdImpl({required this.nodeId});
+
+  @override
+  final String nodeId;
+
+  @override
+  String toString() {
+    return 'InputType.nodeId(nodeId: $nodeId)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_NodeIdImpl &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, nodeId);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_NodeIdImplCopyWith<_$InputType_NodeIdImpl> get copyWith =>
+      __$$InputType_NodeIdImplCopyWithImpl<_$InputType_NodeIdImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return nodeId(this.nodeId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return nodeId?.call(this.nodeId);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (nodeId != null) {
+      return nodeId(this.nodeId);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return nodeId(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return nodeId?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (nodeId != null) {
+      return nodeId(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_NodeId implements InputType {
+  const factory InputType_NodeId({required final String nodeId}) = _$InputType_NodeIdImpl;
+
+  String get nodeId;
+  @JsonKey(ignore: true)
+  _$$InputType_NodeIdImplCopyWith<_$InputType_NodeIdImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_UrlImplCopyWith<$Res> {
+  factory _$$InputType_UrlImplCopyWith(_$InputType_UrlImpl value, $Res Function(_$InputType_UrlImpl) then) =
+      __$$InputType_UrlImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$InputType_UrlImplCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_UrlImpl>
+    implements _$$InputType_UrlImplCopyWith<$Res> {
+  __$$InputType_UrlImplCopyWithImpl(_$InputType_UrlImpl _value, $Res Function(_$InputType_UrlImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$InputType_UrlImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_UrlImpl implements InputType_Url {
+  const _$InputType_UrlImpl({required this.url});
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'InputType.url(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_UrlImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_UrlImplCopyWith<_$InputType_UrlImpl> get copyWith =>
+      __$$InputType_UrlImplCopyWithImpl<_$InputType_UrlImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return url(this.url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return url?.call(this.url);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (url != null) {
+      return url(this.url);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return url(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return url?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (url != null) {
+      return url(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_Url implements InputType {
+  const factory InputType_Url({required final String url}) = _$InputType_UrlImpl;
+
+  String get url;
+  @JsonKey(ignore: true)
+  _$$InputType_UrlImplCopyWith<_$InputType_UrlImpl> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_LnUrlPayImplCopyWith<$Res> {
+  factory _$$InputType_LnUrlPayImplCopyWith(
+          _$InputType_LnUrlPayImpl value, $Res Function(_$InputType_LnUrlPayImpl) then) =
+      __$$InputType_LnUrlPayImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment});
+}
+
+/// @nodoc
+class __$$InputType_LnUrlPayImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlPayImpl>
+    implements _$$InputType_LnUrlPayImplCopyWith<$Res> {
+  __$$InputType_LnUrlPayImplCopyWithImpl(
+      _$InputType_LnUrlPayImpl _value, $Res Function(_$InputType_LnUrlPayImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+    Object? bip353Address = freezed,
+    Object? amountMsat = freezed,
+    Object? comment = freezed,
+  }) {
+    return _then(_$InputType_LnUrlPayImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlPayRequestData,
+      bip353Address: freezed == bip353Address
+          ? _value.bip353Address
+          : bip353Address // ignore: cast_nullable_to_non_nullable
+              as String?,
+      amountMsat: freezed == amountMsat
+          ? _value.amountMsat
+          : amountMsat // ignore: cast_nullable_to_non_nullable
+              as int?,
+      comment: freezed == comment
+          ? _value.comment
+          : comment // ignore: cast_nullable_to_non_nullable
+              as String?,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_LnUrlPayImpl implements InputType_LnUrlPay {
+  const _$InputType_LnUrlPayImpl({required this.data, this.bip353Address, this.amountMsat, this.comment});
+
+  @override
+  final LnUrlPayRequestData data;
+  @override
+  final String? bip353Address;
+  @override
+  final int? amountMsat;
+  @override
+  final String? comment;
+
+  @override
+  String toString() {
+    return 'InputType.lnUrlPay(data: $data, bip353Address: $bip353Address, amountMsat: $amountMsat, comment: $comment)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_LnUrlPayImpl &&
+            (identical(other.data, data) || other.data == data) &&
+            (identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address) &&
+            (identical(other.amountMsat, amountMsat) || other.amountMsat == amountMsat) &&
+            (identical(other.comment, comment) || other.comment == comment));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data, bip353Address, amountMsat, comment);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_LnUrlPayImplCopyWith<_$InputType_LnUrlPayImpl> get copyWith =>
+      __$$InputType_LnUrlPayImplCopyWithImpl<_$InputType_LnUrlPayImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return lnUrlPay(data, bip353Address, amountMsat, comment);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return lnUrlPay?.call(data, bip353Address, amountMsat, comment);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlPay != null) {
+      return lnUrlPay(data, bip353Address, amountMsat, comment);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return lnUrlPay(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return lnUrlPay?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlPay != null) {
+      return lnUrlPay(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_LnUrlPay implements InputType {
+  const factory InputType_LnUrlPay(
+          {required final LnUrlPayRequestData data,
+          final String? bip353Address,
+          final int? amountMsat,
+          final String? comment}) =
+      _$InputType_LnUrlPayImpl;
+
+  LnUrlPayRequestData get data;
+  String? get bip353Address;
+  int? get amountMsat;
+  String? get comment;
+  @JsonKey(ignore: true)
+  _$$InputType_LnUrlPayImplCopyWith<_$InputType_LnUrlPayImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_LnUrlWithdrawImplCopyWith<$Res> {
+  factory _$$InputType_LnUrlWithdrawImplCopyWith(
+          _$InputType_LnUrlWithdrawImpl value, $Res Function(_$InputType_LnUrlWithdrawImpl) then) =
+      __$$InputType_LnUrlWithdrawImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlWithdrawRequestData data});
+}
+
+/// @nodoc
+class __$$InputType_LnUrlWithdrawImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlWithdrawImpl>
+    implements _$$InputType_LnUrlWithdrawImplCopyWith<$Res> {
+  __$$InputType_LnUrlWithdrawImplCopyWithImpl(
+      _$InputType_LnUrlWithdrawImpl _value, $Res Function(_$InputType_LnUrlWithdrawImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$InputType_LnUrlWithdrawImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlWithdrawRequestData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_LnUrlWithdrawImpl implements InputType_LnUrlWithdraw {
+  const _$InputType_LnUrlWithdrawImpl({required this.data});
+
+  @override
+  final LnUrlWithdrawRequestData data;
+
+  @override
+  String toString() {
+    return 'InputType.lnUrlWithdraw(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_LnUrlWithdrawImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_LnUrlWithdrawImplCopyWith<_$InputType_LnUrlWithdrawImpl> get copyWith =>
+      __$$InputType_LnUrlWithdrawImplCopyWithImpl<_$InputType_LnUrlWithdrawImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return lnUrlWithdraw(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return lnUrlWithdraw?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlWithdraw != null) {
+      return lnUrlWithdraw(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return lnUrlWithdraw(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return lnUrlWithdraw?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlWithdraw != null) {
+      return lnUrlWithdraw(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_LnUrlWithdraw implements InputType {
+  const factory InputType_LnUrlWithdraw({required final LnUrlWithdrawRequestData data}) =
+      _$InputType_LnUrlWithdrawImpl;
+
+  LnUrlWithdrawRequestData get data;
+  @JsonKey(ignore: true)
+  _$$InputType_LnUrlWithdrawImplCopyWith<_$InputType_LnUrlWithdrawImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_LnUrlAuthImplCopyWith<$Res> {
+  factory _$$InputType_LnUrlAuthImplCopyWith(
+          _$InputType_LnUrlAuthImpl value, $Res Function(_$InputType_LnUrlAuthImpl) then) =
+      __$$InputType_LnUrlAuthImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlAuthRequestData data});
+}
+
+/// @nodoc
+class __$$InputType_LnUrlAuthImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlAuthImpl>
+    implements _$$InputType_LnUrlAuthImplCopyWith<$Res> {
+  __$$InputType_LnUrlAuthImplCopyWithImpl(
+      _$InputType_LnUrlAuthImpl _value, $Res Function(_$InputType_LnUrlAuthImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$InputType_LnUrlAuthImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlAuthRequestData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_LnUrlAuthImpl implements InputType_LnUrlAuth {
+  const _$InputType_LnUrlAuthImpl({required this.data});
+
+  @override
+  final LnUrlAuthRequestData data;
+
+  @override
+  String toString() {
+    return 'InputType.lnUrlAuth(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_LnUrlAuthImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_LnUrlAuthImplCopyWith<_$InputType_LnUrlAuthImpl> get copyWith =>
+      __$$InputType_LnUrlAuthImplCopyWithImpl<_$InputType_LnUrlAuthImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return lnUrlAuth(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return lnUrlAuth?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlAuth != null) {
+      return lnUrlAuth(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return lnUrlAuth(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return lnUrlAuth?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlAuth != null) {
+      return lnUrlAuth(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_LnUrlAuth implements InputType {
+  const factory InputType_LnUrlAuth({required final LnUrlAuthRequestData data}) = _$InputType_LnUrlAuthImpl;
+
+  LnUrlAuthRequestData get data;
+  @JsonKey(ignore: true)
+  _$$InputType_LnUrlAuthImplCopyWith<_$InputType_LnUrlAuthImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_LnUrlErrorImplCopyWith<$Res> {
+  factory _$$InputType_LnUrlErrorImplCopyWith(
+          _$InputType_LnUrlErrorImpl value, $Res Function(_$InputType_LnUrlErrorImpl) then) =
+      __$$InputType_LnUrlErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlErrorData data});
+}
+
+/// @nodoc
+class __$$InputType_LnUrlErrorImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlErrorImpl>
+    implements _$$InputType_LnUrlErrorImplCopyWith<$Res> {
+  __$$InputType_LnUrlErrorImplCopyWithImpl(
+      _$InputType_LnUrlErrorImpl _value, $Res Function(_$InputType_LnUrlErrorImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$InputType_LnUrlErrorImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlErrorData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_LnUrlErrorImpl implements InputType_LnUrlError {
+  const _$InputType_LnUrlErrorImpl({required this.data});
+
+  @override
+  final LnUrlErrorData data;
+
+  @override
+  String toString() {
+    return 'InputType.lnUrlError(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_LnUrlErrorImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_LnUrlErrorImplCopyWith<_$InputType_LnUrlErrorImpl> get copyWith =>
+      __$$InputType_LnUrlErrorImplCopyWithImpl<_$InputType_LnUrlErrorImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return lnUrlError(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return lnUrlError?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlError != null) {
+      return lnUrlError(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return lnUrlError(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return lnUrlError?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (lnUrlError != null) {
+      return lnUrlError(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_LnUrlError implements InputType {
+  const factory InputType_LnUrlError({required final LnUrlErrorData data}) = _$InputType_LnUrlErrorImpl;
+
+  LnUrlErrorData get data;
+  @JsonKey(ignore: true)
+  _$$InputType_LnUrlErrorImplCopyWith<_$InputType_LnUrlErrorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_EncryptedPaymentRequestImplCopyWith<$Res> {
+  factory _$$InputType_EncryptedPaymentRequestImplCopyWith(_$InputType_EncryptedPaymentRequestImpl value,
+          $Res Function(_$InputType_EncryptedPaymentRequestImpl) then) =
+      __$$InputType_EncryptedPaymentRequestImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({EncryptedPaymentRequestData data});
+}
+
+/// @nodoc
+class __$$InputType_EncryptedPaymentRequestImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_EncryptedPaymentRequestImpl>
+    implements _$$InputType_EncryptedPaymentRequestImplCopyWith<$Res> {
+  __$$InputType_EncryptedPaymentRequestImplCopyWithImpl(_$InputType_EncryptedPaymentRequestImpl _value,
+      $Res Function(_$InputType_EncryptedPaymentRequestImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$InputType_EncryptedPaymentRequestImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as EncryptedPaymentRequestData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_EncryptedPaymentRequestImpl implements InputType_EncryptedPaymentRequest {
+  const _$InputType_EncryptedPaymentRequestImpl({required this.data});
+
+  @override
+  final EncryptedPaymentRequestData data;
+
+  @override
+  String toString() {
+    return 'InputType.encryptedPaymentRequest(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_EncryptedPaymentRequestImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_EncryptedPaymentRequestImplCopyWith<_$InputType_EncryptedPaymentRequestImpl> get copyWith =>
+      __$$InputType_EncryptedPaymentRequestImplCopyWithImpl<_$InputType_EncryptedPaymentRequestImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(BitcoinAddressData address) bitcoinAddress,
+    required TResult Function(LNInvoice invoice) bolt11,
+    required TResult Function(LNOffer offer, String? bip353Address) bolt12Offer,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String url) url,
+    required TResult Function(
+            LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)
+        lnUrlPay,
+    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
+    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
+    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(EncryptedPaymentRequestData data) encryptedPaymentRequest,
+  }) {
+    return encryptedPaymentRequest(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult? Function(LNInvoice invoice)? bolt11,
+    TResult? Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String url)? url,
+    TResult? Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+  }) {
+    return encryptedPaymentRequest?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(BitcoinAddressData address)? bitcoinAddress,
+    TResult Function(LNInvoice invoice)? bolt11,
+    TResult Function(LNOffer offer, String? bip353Address)? bolt12Offer,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String url)? url,
+    TResult Function(LnUrlPayRequestData data, String? bip353Address, int? amountMsat, String? comment)?
+        lnUrlPay,
+    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
+    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
+    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(EncryptedPaymentRequestData data)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (encryptedPaymentRequest != null) {
+      return encryptedPaymentRequest(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
+    required TResult Function(InputType_Bolt11 value) bolt11,
+    required TResult Function(InputType_Bolt12Offer value) bolt12Offer,
+    required TResult Function(InputType_NodeId value) nodeId,
+    required TResult Function(InputType_Url value) url,
+    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
+    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
+    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
+    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(InputType_EncryptedPaymentRequest value) encryptedPaymentRequest,
+  }) {
+    return encryptedPaymentRequest(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult? Function(InputType_Bolt11 value)? bolt11,
+    TResult? Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult? Function(InputType_NodeId value)? nodeId,
+    TResult? Function(InputType_Url value)? url,
+    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+  }) {
+    return encryptedPaymentRequest?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
+    TResult Function(InputType_Bolt11 value)? bolt11,
+    TResult Function(InputType_Bolt12Offer value)? bolt12Offer,
+    TResult Function(InputType_NodeId value)? nodeId,
+    TResult Function(InputType_Url value)? url,
+    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
+    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
+    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
+    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(InputType_EncryptedPaymentRequest value)? encryptedPaymentRequest,
+    required TResult orElse(),
+  }) {
+    if (encryptedPaymentRequest != null) {
+      return encryptedPaymentRequest(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class InputType_EncryptedPaymentRequest implements InputType {
+  const factory InputType_EncryptedPaymentRequest({required final EncryptedPaymentRequestData data}) =
+      _$InputType_EncryptedPaymentRequestImpl;
+
+  EncryptedPaymentRequestData get data;
+  @JsonKey(ignore: true)
+  _$$InputType_EncryptedPaymentRequestImplCopyWith<_$InputType_EncryptedPaymentRequestImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$LnUrlCallbackStatus {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() ok,
+    required TResult Function(LnUrlErrorData data) errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? ok,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? ok,
+    TResult Function(LnUrlErrorData data)? errorStatus,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlCallbackStatus_Ok value) ok,
+    required TResult Function(LnUrlCallbackStatus_ErrorStatus value) errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlCallbackStatus_Ok value)? ok,
+    TResult? Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlCallbackStatus_Ok value)? ok,
+    TResult Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $LnUrlCallbackStatusCopyWith<$Res> {
+  factory $LnUrlCallbackStatusCopyWith(LnUrlCallbackStatus value, $Res Function(LnUrlCallbackStatus) then) =
+      _$LnUrlCallbackStatusCopyWithImpl<$Res, LnUrlCallbackStatus>;
+}
+
+/// @nodoc
+class _$LnUrlCallbackStatusCopyWithImpl<$Res, $Val extends LnUrlCallbackStatus>
+    implements $LnUrlCallbackStatusCopyWith<$Res> {
+  _$LnUrlCallbackStatusCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$LnUrlCallbackStatus_OkImplCopyWith<$Res> {
+  factory _$$LnUrlCallbackStatus_OkImplCopyWith(
+          _$LnUrlCallbackStatus_OkImpl value, $Res Function(_$LnUrlCallbackStatus_OkImpl) then) =
+      __$$LnUrlCallbackStatus_OkImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$LnUrlCallbackStatus_OkImplCopyWithImpl<$Res>
+    extends _$LnUrlCallbackStatusCopyWithImpl<$Res, _$LnUrlCallbackStatus_OkImpl>
+    implements _$$LnUrlCallbackStatus_OkImplCopyWith<$Res> {
+  __$$LnUrlCallbackStatus_OkImplCopyWithImpl(
+      _$LnUrlCallbackStatus_OkImpl _value, $Res Function(_$LnUrlCallbackStatus_OkImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$LnUrlCallbackStatus_OkImpl implements LnUrlCallbackStatus_Ok {
+  const _$LnUrlCallbackStatus_OkImpl();
+
+  @override
+  String toString() {
+    return 'LnUrlCallbackStatus.ok()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$LnUrlCallbackStatus_OkImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() ok,
+    required TResult Function(LnUrlErrorData data) errorStatus,
+  }) {
+    return ok();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? ok,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
+  }) {
+    return ok?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? ok,
+    TResult Function(LnUrlErrorData data)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (ok != null) {
+      return ok();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlCallbackStatus_Ok value) ok,
+    required TResult Function(LnUrlCallbackStatus_ErrorStatus value) errorStatus,
+  }) {
+    return ok(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlCallbackStatus_Ok value)? ok,
+    TResult? Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+  }) {
+    return ok?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlCallbackStatus_Ok value)? ok,
+    TResult Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (ok != null) {
+      return ok(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LnUrlCallbackStatus_Ok implements LnUrlCallbackStatus {
+  const factory LnUrlCallbackStatus_Ok() = _$LnUrlCallbackStatus_OkImpl;
+}
+
+/// @nodoc
+abstract class _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<$Res> {
+  factory _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith(_$LnUrlCallbackStatus_ErrorStatusImpl value,
+          $Res Function(_$LnUrlCallbackStatus_ErrorStatusImpl) then) =
+      __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlErrorData data});
+}
+
+/// @nodoc
+class __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl<$Res>
+    extends _$LnUrlCallbackStatusCopyWithImpl<$Res, _$LnUrlCallbackStatus_ErrorStatusImpl>
+    implements _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<$Res> {
+  __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl(_$LnUrlCallbackStatus_ErrorStatusImpl _value,
+      $Res Function(_$LnUrlCallbackStatus_ErrorStatusImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$LnUrlCallbackStatus_ErrorStatusImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlErrorData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LnUrlCallbackStatus_ErrorStatusImpl implements LnUrlCallbackStatus_ErrorStatus {
+  const _$LnUrlCallbackStatus_ErrorStatusImpl({required this.data});
+
+  @override
+  final LnUrlErrorData data;
+
+  @override
+  String toString() {
+    return 'LnUrlCallbackStatus.errorStatus(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LnUrlCallbackStatus_ErrorStatusImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<_$LnUrlCallbackStatus_ErrorStatusImpl> get copyWith =>
+      __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl<_$LnUrlCallbackStatus_ErrorStatusImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() ok,
+    required TResult Function(LnUrlErrorData data) errorStatus,
+  }) {
+    return errorStatus(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? ok,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
+  }) {
+    return errorStatus?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? ok,
+    TResult Function(LnUrlErrorData data)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (errorStatus != null) {
+      return errorStatus(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlCallbackStatus_Ok value) ok,
+    required TResult Function(LnUrlCallbackStatus_ErrorStatus value) errorStatus,
+  }) {
+    return errorStatus(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlCallbackStatus_Ok value)? ok,
+    TResult? Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+  }) {
+    return errorStatus?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlCallbackStatus_Ok value)? ok,
+    TResult Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (errorStatus != null) {
+      return errorStatus(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LnUrlCallbackStatus_ErrorStatus implements LnUrlCallbackStatus {
+  const factory LnUrlCallbackStatus_ErrorStatus({required final LnUrlErrorData data}) =
+      _$LnUrlCallbackStatus_ErrorStatusImpl;
+
+  LnUrlErrorData get data;
+  @JsonKey(ignore: true)
+  _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<_$LnUrlCallbackStatus_ErrorStatusImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$LnUrlPayResult {
+  Object get data => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
+    required TResult Function(LnUrlErrorData data) endpointError,
+    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(LnUrlPayPendingData data) pending,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult? Function(LnUrlErrorData data)? endpointError,
+    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(LnUrlPayPendingData data)? pending,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult Function(LnUrlErrorData data)? endpointError,
+    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(LnUrlPayPendingData data)? pending,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
+    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
+    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(LnUrlPayResult_Pending value) pending,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(LnUrlPayResult_Pending value)? pending,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(LnUrlPayResult_Pending value)? pending,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $LnUrlPayResultCopyWith<$Res> {
+  factory $LnUrlPayResultCopyWith(LnUrlPayResult value, $Res Function(LnUrlPayResult) then) =
+      _$LnUrlPayResultCopyWithImpl<$Res, LnUrlPayResult>;
+}
+
+/// @nodoc
+class _$LnUrlPayResultCopyWithImpl<$Res, $Val extends LnUrlPayResult>
+    implements $LnUrlPayResultCopyWith<$Res> {
+  _$LnUrlPayResultCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$LnUrlPayResult_EndpointSuccessImplCopyWith<$Res> {
+  factory _$$LnUrlPayResult_EndpointSuccessImplCopyWith(_$LnUrlPayResult_EndpointSuccessImpl value,
+          $Res Function(_$LnUrlPayResult_EndpointSuccessImpl) then) =
+      __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlPaySuccessData data});
+}
+
+/// @nodoc
+class __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl<$Res>
+    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_EndpointSuccessImpl>
+    implements _$$LnUrlPayResult_EndpointSuccessImplCopyWith<$Res> {
+  __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl(
+      _$LnUrlPayResult_EndpointSuccessImpl _value, $Res Function(_$LnUrlPayResult_EndpointSuccessImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$LnUrlPayResult_EndpointSuccessImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlPaySuccessData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LnUrlPayResult_EndpointSuccessImpl implements LnUrlPayResult_EndpointSuccess {
+  const _$LnUrlPayResult_EndpointSuccessImpl({required this.data});
+
+  @override
+  final LnUrlPaySuccessData data;
+
+  @override
+  String toString() {
+    return 'LnUrlPayResult.endpointSuccess(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LnUrlPayResult_EndpointSuccessImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LnUrlPayResult_EndpointSuccessImplCopyWith<_$LnUrlPayResult_EndpointSuccessImpl> get copyWith =>
+      __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl<_$LnUrlPayResult_EndpointSuccessImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
+    required TResult Function(LnUrlErrorData data) endpointError,
+    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(LnUrlPayPendingData data) pending,
+  }) {
+    return endpointSuccess(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult? Function(LnUrlErrorData data)? endpointError,
+    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(LnUrlPayPendingData data)? pending,
+  }) {
+    return endpointSuccess?.call(data);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult Function(LnUrlErrorData data)? endpointError,
+    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(LnUrlPayPendingData data)? pending,
+    required TResult orElse(),
+  }) {
+    if (endpointSuccess != null) {
+      return endpointSuccess(data);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
+    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
+    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(LnUrlPayResult_Pending value) pending,
+  }) {
+    return endpointSuccess(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(LnUrlPayResult_Pending value)? pending,
+  }) {
+    return endpointSuccess?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(LnUrlPayResult_Pending value)? pending,
+    required TResult orElse(),
+  }) {
+    if (endpointSuccess != null) {
+      return endpointSuccess(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LnUrlPayResult_EndpointSuccess implements LnUrlPayResult {
+  const factory LnUrlPayResult_EndpointSuccess({required final LnUrlPaySuccessData data}) =
+      _$LnUrlPayResult_EndpointSuccessImpl;
 
-  SwapInfo get details;
+  @override
+  LnUrlPaySuccessData get data;
   @JsonKey(ignore: true)
-  _$$BreezEvent_SwapUpdatedImplCopyWith<_$BreezEvent_SwapUpdatedImpl> get copyWith =>
+  _$$LnUrlPayResult_EndpointSuccessImplCopyWith<_$LnUrlPayResult_EndpointSuccessImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$InputType {
+abstract class _$$LnUrlPayResult_EndpointErrorImplCopyWith<$Res> {
+  factory _$$LnUrlPayResult_EndpointErrorImplCopyWith(
+          _$LnUrlPayResult_EndpointErrorImpl value, $Res Function(_$LnUrlPayResult_EndpointErrorImpl) then) =
+      __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LnUrlErrorData data});
+}
+
+/// @nodoc
+class __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl<$Res>
+    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_EndpointErrorImpl>
+    implements _$$LnUrlPayResult_EndpointErrorImplCopyWith<$Res> {
+  __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl(
+      _$LnUrlPayResult_EndpointErrorImpl _value, $Res Function(_$LnUrlPayResult_EndpointErrorImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? data = null,
+  }) {
+    return _then(_$LnUrlPayResult_EndpointErrorImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlErrorData,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LnUrlPayResult_EndpointErrorImpl implements LnUrlPayResult_EndpointError {
+  const _$LnUrlPayResult_EndpointErrorImpl({required this.data});
+
+  @override
+  final LnUrlErrorData data;
+
+  @override
+  String toString() {
+    return 'LnUrlPayResult.endpointError(data: $data)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LnUrlPayResult_EndpointErrorImpl &&
+            (identical(other.data, data) || other.data == data));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, data);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LnUrlPayResult_EndpointErrorImplCopyWith<_$LnUrlPayResult_EndpointErrorImpl> get copyWith =>
+      __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl<_$LnUrlPayResult_EndpointErrorImpl>(this, _$identity);
+
+  @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
+    required TResult Function(LnUrlErrorData data) endpointError,
+    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(LnUrlPayPendingData data) pending,
+  }) {
+    return endpointError(data);
+  }
+
+  @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult? Function(LnUrlErrorData data)? endpointError,
+    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(LnUrlPayPendingData data)? pending,
+  }) {
+    return endpointError?.call(data);
+  }
+
+  @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult Function(LnUrlErrorData data)? endpointError,
+    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(LnUrlPayPendingData data)? pending,
     required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
+  }) {
+    if (endpointError != null) {
+      return endpointError(data);
+    }
+    return orElse();
+  }
+
+  @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
+    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
+    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(LnUrlPayResult_Pending value) pending,
+  }) {
+    return endpointError(this);
+  }
+
+  @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
-  }) =>
-      throw _privateConstructorUsedError;
+    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(LnUrlPayResult_Pending value)? pending,
+  }) {
+    return endpointError?.call(this);
+  }
+
+  @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(LnUrlPayResult_Pending value)? pending,
     required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
-}
-
-/// @nodoc
-abstract class $InputTypeCopyWith<$Res> {
-  factory $InputTypeCopyWith(InputType value, $Res Function(InputType) then) =
-      _$InputTypeCopyWithImpl<$Res, InputType>;
+  }) {
+    if (endpointError != null) {
+      return endpointError(this);
+    }
+    return orElse();
+  }
 }
 
-/// @nodoc
-class _$InputTypeCopyWithImpl<$Res, $Val extends InputType> implements $InputTypeCopyWith<$Res> {
-  _$InputTypeCopyWithImpl(this._value, this._then);
+abstract class LnUrlPayResult_EndpointError implements LnUrlPayResult {
+  const factory LnUrlPayResult_EndpointError({required final LnUrlErrorData data}) =
+      _$LnUrlPayResult_EndpointErrorImpl;
 
-  // ignore: unused_field
-  final $Val _value;
-  // ignore: unused_field
-  final $Res Function($Val) _then;
+  @override
+  LnUrlErrorData get data;
+  @JsonKey(ignore: true)
+  _$$LnUrlPayResult_EndpointErrorImplCopyWith<_$LnUrlPayResult_EndpointErrorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_BitcoinAddressImplCopyWith<$Res> {
-  factory _$$InputType_BitcoinAddressImplCopyWith(
-          _$InputType_BitcoinAddressImpl value, $Res Function(_$InputType_BitcoinAddressImpl) then) =
-      __$$InputType_BitcoinAddressImplCopyWithImpl<$Res>;
+abstract class _$$LnUrlPayResult_PayErrorImplCopyWith<$Res> {
+  factory _$$LnUrlPayResult_PayErrorImplCopyWith(
+          _$LnUrlPayResult_PayErrorImpl value, $Res Function(_$LnUrlPayResult_PayErrorImpl) then) =
+      __$$LnUrlPayResult_PayErrorImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({BitcoinAddressData address});
+  $Res call({LnUrlPayErrorData data});
 }
 
 /// @nodoc
-class __$$InputType_BitcoinAddressImplCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_BitcoinAddressImpl>
-    implements _$$InputType_BitcoinAddressImplCopyWith<$Res> {
-  __$$InputType_BitcoinAddressImplCopyWithImpl(
-      _$InputType_BitcoinAddressImpl _value, $Res Function(_$InputType_BitcoinAddressImpl) _then)
+class __$$LnUrlPayResult_PayErrorImplCopyWithImpl<$Res>
+    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_PayErrorImpl>
+    implements _$$LnUrlPayResult_PayErrorImplCopyWith<$Res> {
+  __$$LnUrlPayResult_PayErrorImplCopyWithImpl(
+      _$LnUrlPayResult_PayErrorImpl _value, $Res Function(_$LnUrlPayResult_PayErrorImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? address = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_BitcoinAddressImpl(
-      address: null == address
-          ? _value.address
-          : address // ignore: cast_nullable_to_non_nullable
-              as BitcoinAddressData,
+    return _then(_$LnUrlPayResult_PayErrorImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlPayErrorData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_BitcoinAddressImpl implements InputType_BitcoinAddress {
-  const _$InputType_BitcoinAddressImpl({required this.address});
+class _$LnUrlPayResult_PayErrorImpl implements LnUrlPayResult_PayError {
+  const _$LnUrlPayResult_PayErrorImpl({required this.data});
 
   @override
-  final BitcoinAddressData address;
+  final LnUrlPayErrorData data;
 
   @override
   String toString() {
-    return 'InputType.bitcoinAddress(address: $address)';
+    return 'LnUrlPayResult.payError(data: $data)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_BitcoinAddressImpl &&
-            (identical(other.address, address) || other.address == address));
+            other is _$LnUrlPayResult_PayErrorImpl &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, address);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_BitcoinAddressImplCopyWith<_$InputType_BitcoinAddressImpl> get copyWith =>
-      __$$InputType_BitcoinAddressImplCopyWithImpl<_$InputType_BitcoinAddressImpl>(this, _$identity);
+  _$$LnUrlPayResult_PayErrorImplCopyWith<_$LnUrlPayResult_PayErrorImpl> get copyWith =>
+      __$$LnUrlPayResult_PayErrorImplCopyWithImpl<_$LnUrlPayResult_PayErrorImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
+    required TResult Function(LnUrlErrorData data) endpointError,
+    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(LnUrlPayPendingData data) pending,
   }) {
-    return bitcoinAddress(address);
+    return payError(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult? Function(LnUrlErrorData data)? endpointError,
+    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(LnUrlPayPendingData data)? pending,
   }) {
-    return bitcoinAddress?.call(address);
+    return payError?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult Function(LnUrlErrorData data)? endpointError,
+    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(LnUrlPayPendingData data)? pending,
     required TResult orElse(),
   }) {
-    if (bitcoinAddress != null) {
-      return bitcoinAddress(address);
+    if (payError != null) {
+      return payError(data);
     }
     return orElse();
   }
@@ -2381,168 +13429,146 @@ class _$InputType_BitcoinAddressImpl implements InputType_BitcoinAddress {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
+    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
+    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(LnUrlPayResult_Pending value) pending,
   }) {
-    return bitcoinAddress(this);
+    return payError(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(LnUrlPayResult_Pending value)? pending,
   }) {
-    return bitcoinAddress?.call(this);
+    return payError?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(LnUrlPayResult_Pending value)? pending,
     required TResult orElse(),
   }) {
-    if (bitcoinAddress != null) {
-      return bitcoinAddress(this);
+    if (payError != null) {
+      return payError(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_BitcoinAddress implements InputType {
-  const factory InputType_BitcoinAddress({required final BitcoinAddressData address}) =
-      _$InputType_BitcoinAddressImpl;
+abstract class LnUrlPayResult_PayError implements LnUrlPayResult {
+  const factory LnUrlPayResult_PayError({required final LnUrlPayErrorData data}) =
+      _$LnUrlPayResult_PayErrorImpl;
 
-  BitcoinAddressData get address;
+  @override
+  LnUrlPayErrorData get data;
   @JsonKey(ignore: true)
-  _$$InputType_BitcoinAddressImplCopyWith<_$InputType_BitcoinAddressImpl> get copyWith =>
+  _$$LnUrlPayResult_PayErrorImplCopyWith<_$LnUrlPayResult_PayErrorImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_Bolt11ImplCopyWith<$Res> {
-  factory _$$InputType_Bolt11ImplCopyWith(
-          _$InputType_Bolt11Impl value, $Res Function(_$InputType_Bolt11Impl) then) =
-      __$$InputType_Bolt11ImplCopyWithImpl<$Res>;
+abstract class _$$LnUrlPayResult_PendingImplCopyWith<$Res> {
+  factory _$$LnUrlPayResult_PendingImplCopyWith(
+          _$LnUrlPayResult_PendingImpl value, $Res Function(_$LnUrlPayResult_PendingImpl) then) =
+      __$$LnUrlPayResult_PendingImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({LNInvoice invoice});
+  $Res call({LnUrlPayPendingData data});
 }
 
 /// @nodoc
-class __$$InputType_Bolt11ImplCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt11Impl>
-    implements _$$InputType_Bolt11ImplCopyWith<$Res> {
-  __$$InputType_Bolt11ImplCopyWithImpl(
-      _$InputType_Bolt11Impl _value, $Res Function(_$InputType_Bolt11Impl) _then)
+class __$$LnUrlPayResult_PendingImplCopyWithImpl<$Res>
+    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_PendingImpl>
+    implements _$$LnUrlPayResult_PendingImplCopyWith<$Res> {
+  __$$LnUrlPayResult_PendingImplCopyWithImpl(
+      _$LnUrlPayResult_PendingImpl _value, $Res Function(_$LnUrlPayResult_PendingImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? invoice = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_Bolt11Impl(
-      invoice: null == invoice
-          ? _value.invoice
-          : invoice // ignore: cast_nullable_to_non_nullable
-              as LNInvoice,
+    return _then(_$LnUrlPayResult_PendingImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlPayPendingData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_Bolt11Impl implements InputType_Bolt11 {
-  const _$InputType_Bolt11Impl({required this.invoice});
+class _$LnUrlPayResult_PendingImpl implements LnUrlPayResult_Pending {
+  const _$LnUrlPayResult_PendingImpl({required this.data});
 
   @override
-  final LNInvoice invoice;
+  final LnUrlPayPendingData data;
 
   @override
   String toString() {
-    return 'InputType.bolt11(invoice: $invoice)';
+    return 'LnUrlPayResult.pending(data: $data)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_Bolt11Impl &&
-            (identical(other.invoice, invoice) || other.invoice == invoice));
+            other is _$LnUrlPayResult_PendingImpl &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, invoice);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_Bolt11ImplCopyWith<_$InputType_Bolt11Impl> get copyWith =>
-      __$$InputType_Bolt11ImplCopyWithImpl<_$InputType_Bolt11Impl>(this, _$identity);
+  _$$LnUrlPayResult_PendingImplCopyWith<_$LnUrlPayResult_PendingImpl> get copyWith =>
+      __$$LnUrlPayResult_PendingImplCopyWithImpl<_$LnUrlPayResult_PendingImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
+    required TResult Function(LnUrlErrorData data) endpointError,
+    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(LnUrlPayPendingData data) pending,
   }) {
-    return bolt11(invoice);
+    return pending(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult? Function(LnUrlErrorData data)? endpointError,
+    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(LnUrlPayPendingData data)? pending,
   }) {
-    return bolt11?.call(invoice);
+    return pending?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
+    TResult Function(LnUrlErrorData data)? endpointError,
+    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(LnUrlPayPendingData data)? pending,
     required TResult orElse(),
   }) {
-    if (bolt11 != null) {
-      return bolt11(invoice);
+    if (pending != null) {
+      return pending(data);
     }
     return orElse();
   }
@@ -2550,166 +13576,209 @@ class _$InputType_Bolt11Impl implements InputType_Bolt11 {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
+    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
+    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(LnUrlPayResult_Pending value) pending,
   }) {
-    return bolt11(this);
+    return pending(this);
   }
 
   @override
   @optionalTypeArgs
-  TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(LnUrlPayResult_Pending value)? pending,
   }) {
-    return bolt11?.call(this);
+    return pending?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
+    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
+    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(LnUrlPayResult_Pending value)? pending,
     required TResult orElse(),
   }) {
-    if (bolt11 != null) {
-      return bolt11(this);
+    if (pending != null) {
+      return pending(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_Bolt11 implements InputType {
-  const factory InputType_Bolt11({required final LNInvoice invoice}) = _$InputType_Bolt11Impl;
+abstract class LnUrlPayResult_Pending implements LnUrlPayResult {
+  const factory LnUrlPayResult_Pending({required final LnUrlPayPendingData data}) =
+      _$LnUrlPayResult_PendingImpl;
 
-  LNInvoice get invoice;
+  @override
+  LnUrlPayPendingData get data;
   @JsonKey(ignore: true)
-  _$$InputType_Bolt11ImplCopyWith<_$InputType_Bolt11Impl> get copyWith => throw _privateConstructorUsedError;
+  _$$LnUrlPayResult_PendingImplCopyWith<_$LnUrlPayResult_PendingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_NodeIdImplCopyWith<$Res> {
-  factory _$$InputType_NodeIdImplCopyWith(
-          _$InputType_NodeIdImpl value, $Res Function(_$InputType_NodeIdImpl) then) =
-      __$$InputType_NodeIdImplCopyWithImpl<$Res>;
+mixin _$LnUrlWithdrawResult {
+  Object get data => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LnUrlWithdrawSuccessData data) ok,
+    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
+    required TResult Function(LnUrlErrorData data) errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult Function(LnUrlErrorData data)? errorStatus,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
+    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
+    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $LnUrlWithdrawResultCopyWith<$Res> {
+  factory $LnUrlWithdrawResultCopyWith(LnUrlWithdrawResult value, $Res Function(LnUrlWithdrawResult) then) =
+      _$LnUrlWithdrawResultCopyWithImpl<$Res, LnUrlWithdrawResult>;
+}
+
+/// @nodoc
+class _$LnUrlWithdrawResultCopyWithImpl<$Res, $Val extends LnUrlWithdrawResult>
+    implements $LnUrlWithdrawResultCopyWith<$Res> {
+  _$LnUrlWithdrawResultCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$LnUrlWithdrawResult_OkImplCopyWith<$Res> {
+  factory _$$LnUrlWithdrawResult_OkImplCopyWith(
+          _$LnUrlWithdrawResult_OkImpl value, $Res Function(_$LnUrlWithdrawResult_OkImpl) then) =
+      __$$LnUrlWithdrawResult_OkImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({String nodeId});
+  $Res call({LnUrlWithdrawSuccessData data});
 }
 
 /// @nodoc
-class __$$InputType_NodeIdImplCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_NodeIdImpl>
-    implements _$$InputType_NodeIdImplCopyWith<$Res> {
-  __$$InputType_NodeIdImplCopyWithImpl(
-      _$InputType_NodeIdImpl _value, $Res Function(_$InputType_NodeIdImpl) _then)
+class __$$LnUrlWithdrawResult_OkImplCopyWithImpl<$Res>
+    extends _$LnUrlWithdrawResultCopyWithImpl<$Res, _$LnUrlWithdrawResult_OkImpl>
+    implements _$$LnUrlWithdrawResult_OkImplCopyWith<$Res> {
+  __$$LnUrlWithdrawResult_OkImplCopyWithImpl(
+      _$LnUrlWithdrawResult_OkImpl _value, $Res Function(_$LnUrlWithdrawResult_OkImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? nodeId = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_NodeIdImpl(
-      nodeId: null == nodeId
-          ? _value.nodeId
-          : nodeId // ignore: cast_nullable_to_non_nullable
-              as String,
+    return _then(_$LnUrlWithdrawResult_OkImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlWithdrawSuccessData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_NodeIdImpl implements InputType_NodeId {
-  const _$InputType_NodeIdImpl({required this.nodeId});
+class _$LnUrlWithdrawResult_OkImpl implements LnUrlWithdrawResult_Ok {
+  const _$LnUrlWithdrawResult_OkImpl({required this.data});
 
   @override
-  final String nodeId;
+  final LnUrlWithdrawSuccessData data;
 
   @override
   String toString() {
-    return 'InputType.nodeId(nodeId: $nodeId)';
+    return 'LnUrlWithdrawResult.ok(data: $data)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_NodeIdImpl &&
-            (identical(other.nodeId, nodeId) || other.nodeId == nodeId));
+            other is _$LnUrlWithdrawResult_OkImpl &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, nodeId);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_NodeIdImplCopyWith<_$InputType_NodeIdImpl> get copyWith =>
-      __$$InputType_NodeIdImplCopyWithImpl<_$InputType_NodeIdImpl>(this, _$identity);
+  _$$LnUrlWithdrawResult_OkImplCopyWith<_$LnUrlWithdrawResult_OkImpl> get copyWith =>
+      __$$LnUrlWithdrawResult_OkImplCopyWithImpl<_$LnUrlWithdrawResult_OkImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlWithdrawSuccessData data) ok,
+    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
+    required TResult Function(LnUrlErrorData data) errorStatus,
   }) {
-    return nodeId(this.nodeId);
+    return ok(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
   }) {
-    return nodeId?.call(this.nodeId);
+    return ok?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult Function(LnUrlErrorData data)? errorStatus,
     required TResult orElse(),
   }) {
-    if (nodeId != null) {
-      return nodeId(this.nodeId);
+    if (ok != null) {
+      return ok(data);
     }
     return orElse();
   }
@@ -2717,164 +13786,140 @@ class _$InputType_NodeIdImpl implements InputType_NodeId {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
+    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
+    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
   }) {
-    return nodeId(this);
+    return ok(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
   }) {
-    return nodeId?.call(this);
+    return ok?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
     required TResult orElse(),
   }) {
-    if (nodeId != null) {
-      return nodeId(this);
+    if (ok != null) {
+      return ok(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_NodeId implements InputType {
-  const factory InputType_NodeId({required final String nodeId}) = _$InputType_NodeIdImpl;
+abstract class LnUrlWithdrawResult_Ok implements LnUrlWithdrawResult {
+  const factory LnUrlWithdrawResult_Ok({required final LnUrlWithdrawSuccessData data}) =
+      _$LnUrlWithdrawResult_OkImpl;
 
-  String get nodeId;
+  @override
+  LnUrlWithdrawSuccessData get data;
   @JsonKey(ignore: true)
-  _$$InputType_NodeIdImplCopyWith<_$InputType_NodeIdImpl> get copyWith => throw _privateConstructorUsedError;
+  _$$LnUrlWithdrawResult_OkImplCopyWith<_$LnUrlWithdrawResult_OkImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_UrlImplCopyWith<$Res> {
-  factory _$$InputType_UrlImplCopyWith(_$InputType_UrlImpl value, $Res Function(_$InputType_UrlImpl) then) =
-      __$$InputType_UrlImplCopyWithImpl<$Res>;
+abstract class _$$LnUrlWithdrawResult_TimeoutImplCopyWith<$Res> {
+  factory _$$LnUrlWithdrawResult_TimeoutImplCopyWith(
+          _$LnUrlWithdrawResult_TimeoutImpl value, $Res Function(_$LnUrlWithdrawResult_TimeoutImpl) then) =
+      __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({String url});
+  $Res call({LnUrlWithdrawSuccessData data});
 }
 
 /// @nodoc
-class __$$InputType_UrlImplCopyWithImpl<$Res> extends _$InputTypeCopyWithImpl<$Res, _$InputType_UrlImpl>
-    implements _$$InputType_UrlImplCopyWith<$Res> {
-  __$$InputType_UrlImplCopyWithImpl(_$InputType_UrlImpl _value, $Res Function(_$InputType_UrlImpl) _then)
+class __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl<$Res>
+    extends _$LnUrlWithdrawResultCopyWithImpl<$Res, _$LnUrlWithdrawResult_TimeoutImpl>
+    implements _$$LnUrlWithdrawResult_TimeoutImplCopyWith<$Res> {
+  __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl(
+      _$LnUrlWithdrawResult_TimeoutImpl _value, $Res Function(_$LnUrlWithdrawResult_TimeoutImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? url = null,
+    Object? data = null,
   }) {
-    return _then(_$InputType_UrlImpl(
-      url: null == url
-          ? _value.url
-          : url // ignore: cast_nullable_to_non_nullable
-              as String,
+    return _then(_$LnUrlWithdrawResult_TimeoutImpl(
+      data: null == data
+          ? _value.data
+          : data // ignore: cast_nullable_to_non_nullable
+              as LnUrlWithdrawSuccessData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_UrlImpl implements InputType_Url {
-  const _$InputType_UrlImpl({required this.url});
+class _$LnUrlWithdrawResult_TimeoutImpl implements LnUrlWithdrawResult_Timeout {
+  const _$LnUrlWithdrawResult_TimeoutImpl({required this.data});
 
   @override
-  final String url;
+  final LnUrlWithdrawSuccessData data;
 
   @override
   String toString() {
-    return 'InputType.url(url: $url)';
+    return 'LnUrlWithdrawResult.timeout(data: $data)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_UrlImpl &&
-            (identical(other.url, url) || other.url == url));
+            other is _$LnUrlWithdrawResult_TimeoutImpl &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, url);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_UrlImplCopyWith<_$InputType_UrlImpl> get copyWith =>
-      __$$InputType_UrlImplCopyWithImpl<_$InputType_UrlImpl>(this, _$identity);
+  _$$LnUrlWithdrawResult_TimeoutImplCopyWith<_$LnUrlWithdrawResult_TimeoutImpl> get copyWith =>
+      __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl<_$LnUrlWithdrawResult_TimeoutImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlWithdrawSuccessData data) ok,
+    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
+    required TResult Function(LnUrlErrorData data) errorStatus,
   }) {
-    return url(this.url);
+    return timeout(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
   }) {
-    return url?.call(this.url);
+    return timeout?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult Function(LnUrlErrorData data)? errorStatus,
     required TResult orElse(),
   }) {
-    if (url != null) {
-      return url(this.url);
+    if (timeout != null) {
+      return timeout(data);
     }
     return orElse();
   }
@@ -2882,175 +13927,141 @@ class _$InputType_UrlImpl implements InputType_Url {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
+    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
+    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
   }) {
-    return url(this);
+    return timeout(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
   }) {
-    return url?.call(this);
+    return timeout?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
     required TResult orElse(),
   }) {
-    if (url != null) {
-      return url(this);
+    if (timeout != null) {
+      return timeout(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_Url implements InputType {
-  const factory InputType_Url({required final String url}) = _$InputType_UrlImpl;
+abstract class LnUrlWithdrawResult_Timeout implements LnUrlWithdrawResult {
+  const factory LnUrlWithdrawResult_Timeout({required final LnUrlWithdrawSuccessData data}) =
+      _$LnUrlWithdrawResult_TimeoutImpl;
 
-  String get url;
+  @override
+  LnUrlWithdrawSuccessData get data;
   @JsonKey(ignore: true)
-  _$$InputType_UrlImplCopyWith<_$InputType_UrlImpl> get copyWith => throw _privateConstructorUsedError;
+  _$$LnUrlWithdrawResult_TimeoutImplCopyWith<_$LnUrlWithdrawResult_TimeoutImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlPayImplCopyWith<$Res> {
-  factory _$$InputType_LnUrlPayImplCopyWith(
-          _$InputType_LnUrlPayImpl value, $Res Function(_$InputType_LnUrlPayImpl) then) =
-      __$$InputType_LnUrlPayImplCopyWithImpl<$Res>;
+abstract class _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<$Res> {
+  factory _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith(_$LnUrlWithdrawResult_ErrorStatusImpl value,
+          $Res Function(_$LnUrlWithdrawResult_ErrorStatusImpl) then) =
+      __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlPayRequestData data, String? bip353Address});
+  $Res call({LnUrlErrorData data});
 }
 
 /// @nodoc
-class __$$InputType_LnUrlPayImplCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlPayImpl>
-    implements _$$InputType_LnUrlPayImplCopyWith<$Res> {
-  __$$InputType_LnUrlPayImplCopyWithImpl(
-      _$InputType_LnUrlPayImpl _value, $Res Function(_$InputType_LnUrlPayImpl) _then)
+class __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl<$Res>
+    extends _$LnUrlWithdrawResultCopyWithImpl<$Res, _$LnUrlWithdrawResult_ErrorStatusImpl>
+    implements _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<$Res> {
+  __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl(_$LnUrlWithdrawResult_ErrorStatusImpl _value,
+      $Res Function(_$LnUrlWithdrawResult_ErrorStatusImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
     Object? data = null,
-    Object? bip353Address = freezed,
   }) {
-    return _then(_$InputType_LnUrlPayImpl(
+    return _then(_$LnUrlWithdrawResult_ErrorStatusImpl(
       data: null == data
           ? _value.data
           : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlPayRequestData,
-      bip353Address: freezed == bip353Address
-          ? _value.bip353Address
-          : bip353Address // ignore: cast_nullable_to_non_nullable
-              as String?,
+              as LnUrlErrorData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlPayImpl implements InputType_LnUrlPay {
-  const _$InputType_LnUrlPayImpl({required this.data, this.bip353Address});
+class _$LnUrlWithdrawResult_ErrorStatusImpl implements LnUrlWithdrawResult_ErrorStatus {
+  const _$LnUrlWithdrawResult_ErrorStatusImpl({required this.data});
 
   @override
-  final LnUrlPayRequestData data;
-  @override
-  final String? bip353Address;
+  final LnUrlErrorData data;
 
   @override
   String toString() {
-    return 'InputType.lnUrlPay(data: $data, bip353Address: $bip353Address)';
+    return 'LnUrlWithdrawResult.errorStatus(data: $data)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlPayImpl &&
-            (identical(other.data, data) || other.data == data) &&
-            (identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+            other is _$LnUrlWithdrawResult_ErrorStatusImpl &&
+            (identical(other.data, data) || other.data == data));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data, bip353Address);
+  int get hashCode => Object.hash(runtimeType, data);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlPayImplCopyWith<_$InputType_LnUrlPayImpl> get copyWith =>
-      __$$InputType_LnUrlPayImplCopyWithImpl<_$InputType_LnUrlPayImpl>(this, _$identity);
+  _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<_$LnUrlWithdrawResult_ErrorStatusImpl> get copyWith =>
+      __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl<_$LnUrlWithdrawResult_ErrorStatusImpl>(
+          this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function(LnUrlWithdrawSuccessData data) ok,
+    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
+    required TResult Function(LnUrlErrorData data) errorStatus,
   }) {
-    return lnUrlPay(data, bip353Address);
+    return errorStatus(data);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult? Function(LnUrlErrorData data)? errorStatus,
   }) {
-    return lnUrlPay?.call(data, bip353Address);
+    return errorStatus?.call(data);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function(LnUrlWithdrawSuccessData data)? ok,
+    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
+    TResult Function(LnUrlErrorData data)? errorStatus,
     required TResult orElse(),
   }) {
-    if (lnUrlPay != null) {
-      return lnUrlPay(data, bip353Address);
+    if (errorStatus != null) {
+      return errorStatus(data);
     }
     return orElse();
   }
@@ -3058,170 +14069,178 @@ class _$InputType_LnUrlPayImpl implements InputType_LnUrlPay {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
+    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
+    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
   }) {
-    return lnUrlPay(this);
+    return errorStatus(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
   }) {
-    return lnUrlPay?.call(this);
+    return errorStatus?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
+    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
+    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    required TResult orElse(),
+  }) {
+    if (errorStatus != null) {
+      return errorStatus(this);
+    }
+    return orElse();
   }
+}
+
+abstract class LnUrlWithdrawResult_ErrorStatus implements LnUrlWithdrawResult {
+  const factory LnUrlWithdrawResult_ErrorStatus({required final LnUrlErrorData data}) =
+      _$LnUrlWithdrawResult_ErrorStatusImpl;
 
   @override
+  LnUrlErrorData get data;
+  @JsonKey(ignore: true)
+  _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<_$LnUrlWithdrawResult_ErrorStatusImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$LspPolicy {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() lowestOpeningFee,
+    required TResult Function() lowestProportionalFee,
+    required TResult Function(String lspId) pinned,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? lowestOpeningFee,
+    TResult? Function()? lowestProportionalFee,
+    TResult? Function(String lspId)? pinned,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? lowestOpeningFee,
+    TResult Function()? lowestProportionalFee,
+    TResult Function(String lspId)? pinned,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LspPolicy_LowestOpeningFee value) lowestOpeningFee,
+    required TResult Function(LspPolicy_LowestProportionalFee value) lowestProportionalFee,
+    required TResult Function(LspPolicy_Pinned value) pinned,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult? Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult? Function(LspPolicy_Pinned value)? pinned,
+  }) =>
+      throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult Function(LspPolicy_Pinned value)? pinned,
     required TResult orElse(),
-  }) {
-    if (lnUrlPay != null) {
-      return lnUrlPay(this);
-    }
-    return orElse();
-  }
+  }) =>
+      throw _privateConstructorUsedError;
 }
 
-abstract class InputType_LnUrlPay implements InputType {
-  const factory InputType_LnUrlPay({required final LnUrlPayRequestData data, final String? bip353Address}) =
-      _$InputType_LnUrlPayImpl;
+/// @nodoc
+abstract class $LspPolicyCopyWith<$Res> {
+  factory $LspPolicyCopyWith(LspPolicy value, $Res Function(LspPolicy) then) =
+      _$LspPolicyCopyWithImpl<$Res, LspPolicy>;
+}
 
-  LnUrlPayRequestData get data;
-  String? get bip353Address;
-  @JsonKey(ignore: true)
-  _$$InputType_LnUrlPayImplCopyWith<_$InputType_LnUrlPayImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+/// @nodoc
+class _$LspPolicyCopyWithImpl<$Res, $Val extends LspPolicy> implements $LspPolicyCopyWith<$Res> {
+  _$LspPolicyCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlWithdrawImplCopyWith<$Res> {
-  factory _$$InputType_LnUrlWithdrawImplCopyWith(
-          _$InputType_LnUrlWithdrawImpl value, $Res Function(_$InputType_LnUrlWithdrawImpl) then) =
-      __$$InputType_LnUrlWithdrawImplCopyWithImpl<$Res>;
-  @useResult
-  $Res call({LnUrlWithdrawRequestData data});
+abstract class _$$LspPolicy_LowestOpeningFeeImplCopyWith<$Res> {
+  factory _$$LspPolicy_LowestOpeningFeeImplCopyWith(
+          _$LspPolicy_LowestOpeningFeeImpl value, $Res Function(_$LspPolicy_LowestOpeningFeeImpl) then) =
+      __$$LspPolicy_LowestOpeningFeeImplCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$InputType_LnUrlWithdrawImplCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlWithdrawImpl>
-    implements _$$InputType_LnUrlWithdrawImplCopyWith<$Res> {
-  __$$InputType_LnUrlWithdrawImplCopyWithImpl(
-      _$InputType_LnUrlWithdrawImpl _value, $Res Function(_$InputType_LnUrlWithdrawImpl) _then)
+class __$$LspPolicy_LowestOpeningFeeImplCopyWithImpl<$Res>
+    extends _$LspPolicyCopyWithImpl<$Res, _$LspPolicy_LowestOpeningFeeImpl>
+    implements _$$LspPolicy_LowestOpeningFeeImplCopyWith<$Res> {
+  __$$LspPolicy_LowestOpeningFeeImplCopyWithImpl(
+      _$LspPolicy_LowestOpeningFeeImpl _value, $Res Function(_$LspPolicy_LowestOpeningFeeImpl) _then)
       : super(_value, _then);
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? data = null,
-  }) {
-    return _then(_$InputType_LnUrlWithdrawImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlWithdrawRequestData,
-    ));
-  }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlWithdrawImpl implements InputType_LnUrlWithdraw {
-  const _$InputType_LnUrlWithdrawImpl({required this.data});
-
-  @override
-  final LnUrlWithdrawRequestData data;
+class _$LspPolicy_LowestOpeningFeeImpl implements LspPolicy_LowestOpeningFee {
+  const _$LspPolicy_LowestOpeningFeeImpl();
 
   @override
   String toString() {
-    return 'InputType.lnUrlWithdraw(data: $data)';
+    return 'LspPolicy.lowestOpeningFee()';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlWithdrawImpl &&
-            (identical(other.data, data) || other.data == data));
+        (other.runtimeType == runtimeType && other is _$LspPolicy_LowestOpeningFeeImpl);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
-
-  @JsonKey(ignore: true)
-  @override
-  @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlWithdrawImplCopyWith<_$InputType_LnUrlWithdrawImpl> get copyWith =>
-      __$$InputType_LnUrlWithdrawImplCopyWithImpl<_$InputType_LnUrlWithdrawImpl>(this, _$identity);
+  int get hashCode => runtimeType.hashCode;
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function() lowestOpeningFee,
+    required TResult Function() lowestProportionalFee,
+    required TResult Function(String lspId) pinned,
   }) {
-    return lnUrlWithdraw(data);
+    return lowestOpeningFee();
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function()? lowestOpeningFee,
+    TResult? Function()? lowestProportionalFee,
+    TResult? Function(String lspId)? pinned,
   }) {
-    return lnUrlWithdraw?.call(data);
+    return lowestOpeningFee?.call();
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function()? lowestOpeningFee,
+    TResult Function()? lowestProportionalFee,
+    TResult Function(String lspId)? pinned,
     required TResult orElse(),
   }) {
-    if (lnUrlWithdraw != null) {
-      return lnUrlWithdraw(data);
+    if (lowestOpeningFee != null) {
+      return lowestOpeningFee();
     }
     return orElse();
   }
@@ -3229,169 +14248,107 @@ class _$InputType_LnUrlWithdrawImpl implements InputType_LnUrlWithdraw {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LspPolicy_LowestOpeningFee value) lowestOpeningFee,
+    required TResult Function(LspPolicy_LowestProportionalFee value) lowestProportionalFee,
+    required TResult Function(LspPolicy_Pinned value) pinned,
   }) {
-    return lnUrlWithdraw(this);
+    return lowestOpeningFee(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult? Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult? Function(LspPolicy_Pinned value)? pinned,
   }) {
-    return lnUrlWithdraw?.call(this);
+    return lowestOpeningFee?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult Function(LspPolicy_Pinned value)? pinned,
     required TResult orElse(),
   }) {
-    if (lnUrlWithdraw != null) {
-      return lnUrlWithdraw(this);
+    if (lowestOpeningFee != null) {
+      return lowestOpeningFee(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlWithdraw implements InputType {
-  const factory InputType_LnUrlWithdraw({required final LnUrlWithdrawRequestData data}) =
-      _$InputType_LnUrlWithdrawImpl;
-
-  LnUrlWithdrawRequestData get data;
-  @JsonKey(ignore: true)
-  _$$InputType_LnUrlWithdrawImplCopyWith<_$InputType_LnUrlWithdrawImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+abstract class LspPolicy_LowestOpeningFee implements LspPolicy {
+  const factory LspPolicy_LowestOpeningFee() = _$LspPolicy_LowestOpeningFeeImpl;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlAuthImplCopyWith<$Res> {
-  factory _$$InputType_LnUrlAuthImplCopyWith(
-          _$InputType_LnUrlAuthImpl value, $Res Function(_$InputType_LnUrlAuthImpl) then) =
-      __$$InputType_LnUrlAuthImplCopyWithImpl<$Res>;
-  @useResult
-  $Res call({LnUrlAuthRequestData data});
+abstract class _$$LspPolicy_LowestProportionalFeeImplCopyWith<$Res> {
+  factory _$$LspPolicy_LowestProportionalFeeImplCopyWith(_$LspPolicy_LowestProportionalFeeImpl value,
+          $Res Function(_$LspPolicy_LowestProportionalFeeImpl) then) =
+      __$$LspPolicy_LowestProportionalFeeImplCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$InputType_LnUrlAuthImplCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlAuthImpl>
-    implements _$$InputType_LnUrlAuthImplCopyWith<$Res> {
-  __$$InputType_LnUrlAuthImplCopyWithImpl(
-      _$InputType_LnUrlAuthImpl _value, $Res Function(_$InputType_LnUrlAuthImpl) _then)
+class __$$LspPolicy_LowestProportionalFeeImplCopyWithImpl<$Res>
+    extends _$LspPolicyCopyWithImpl<$Res, _$LspPolicy_LowestProportionalFeeImpl>
+    implements _$$LspPolicy_LowestProportionalFeeImplCopyWith<$Res> {
+  __$$LspPolicy_LowestProportionalFeeImplCopyWithImpl(_$LspPolicy_LowestProportionalFeeImpl _value,
+      $Res Function(_$LspPolicy_LowestProportionalFeeImpl) _then)
       : super(_value, _then);
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? data = null,
-  }) {
-    return _then(_$InputType_LnUrlAuthImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlAuthRequestData,
-    ));
-  }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlAuthImpl implements InputType_LnUrlAuth {
-  const _$InputType_LnUrlAuthImpl({required this.data});
-
-  @override
-  final LnUrlAuthRequestData data;
+class _$LspPolicy_LowestProportionalFeeImpl implements LspPolicy_LowestProportionalFee {
+  const _$LspPolicy_LowestProportionalFeeImpl();
 
   @override
   String toString() {
-    return 'InputType.lnUrlAuth(data: $data)';
+    return 'LspPolicy.lowestProportionalFee()';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlAuthImpl &&
-            (identical(other.data, data) || other.data == data));
+        (other.runtimeType == runtimeType && other is _$LspPolicy_LowestProportionalFeeImpl);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
-
-  @JsonKey(ignore: true)
-  @override
-  @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlAuthImplCopyWith<_$InputType_LnUrlAuthImpl> get copyWith =>
-      __$$InputType_LnUrlAuthImplCopyWithImpl<_$InputType_LnUrlAuthImpl>(this, _$identity);
+  int get hashCode => runtimeType.hashCode;
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function() lowestOpeningFee,
+    required TResult Function() lowestProportionalFee,
+    required TResult Function(String lspId) pinned,
   }) {
-    return lnUrlAuth(data);
+    return lowestProportionalFee();
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function()? lowestOpeningFee,
+    TResult? Function()? lowestProportionalFee,
+    TResult? Function(String lspId)? pinned,
   }) {
-    return lnUrlAuth?.call(data);
+    return lowestProportionalFee?.call();
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function()? lowestOpeningFee,
+    TResult Function()? lowestProportionalFee,
+    TResult Function(String lspId)? pinned,
     required TResult orElse(),
   }) {
-    if (lnUrlAuth != null) {
-      return lnUrlAuth(data);
+    if (lowestProportionalFee != null) {
+      return lowestProportionalFee();
     }
     return orElse();
   }
@@ -3399,168 +14356,132 @@ class _$InputType_LnUrlAuthImpl implements InputType_LnUrlAuth {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LspPolicy_LowestOpeningFee value) lowestOpeningFee,
+    required TResult Function(LspPolicy_LowestProportionalFee value) lowestProportionalFee,
+    required TResult Function(LspPolicy_Pinned value) pinned,
   }) {
-    return lnUrlAuth(this);
+    return lowestProportionalFee(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult? Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult? Function(LspPolicy_Pinned value)? pinned,
   }) {
-    return lnUrlAuth?.call(this);
+    return lowestProportionalFee?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult Function(LspPolicy_Pinned value)? pinned,
     required TResult orElse(),
   }) {
-    if (lnUrlAuth != null) {
-      return lnUrlAuth(this);
+    if (lowestProportionalFee != null) {
+      return lowestProportionalFee(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlAuth implements InputType {
-  const factory InputType_LnUrlAuth({required final LnUrlAuthRequestData data}) = _$InputType_LnUrlAuthImpl;
-
-  LnUrlAuthRequestData get data;
-  @JsonKey(ignore: true)
-  _$$InputType_LnUrlAuthImplCopyWith<_$InputType_LnUrlAuthImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+abstract class LspPolicy_LowestProportionalFee implements LspPolicy {
+  const factory LspPolicy_LowestProportionalFee() = _$LspPolicy_LowestProportionalFeeImpl;
 }
 
 /// @nodoc
-abstract class _$$InputType_LnUrlErrorImplCopyWith<$Res> {
-  factory _$$InputType_LnUrlErrorImplCopyWith(
-          _$InputType_LnUrlErrorImpl value, $Res Function(_$InputType_LnUrlErrorImpl) then) =
-      __$$InputType_LnUrlErrorImplCopyWithImpl<$Res>;
+abstract class _$$LspPolicy_PinnedImplCopyWith<$Res> {
+  factory _$$LspPolicy_PinnedImplCopyWith(
+          _$LspPolicy_PinnedImpl value, $Res Function(_$LspPolicy_PinnedImpl) then) =
+      __$$LspPolicy_PinnedImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlErrorData data});
-}
-
-/// @nodoc
-class __$$InputType_LnUrlErrorImplCopyWithImpl<$Res>
-    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlErrorImpl>
-    implements _$$InputType_LnUrlErrorImplCopyWith<$Res> {
-  __$$InputType_LnUrlErrorImplCopyWithImpl(
-      _$InputType_LnUrlErrorImpl _value, $Res Function(_$InputType_LnUrlErrorImpl) _then)
+  $Res call({String lspId});
+}
+
+/// @nodoc
+class __$$LspPolicy_PinnedImplCopyWithImpl<$Res> extends _$LspPolicyCopyWithImpl<$Res, _$LspPolicy_PinnedImpl>
+    implements _$$LspPolicy_PinnedImplCopyWith<$Res> {
+  __$$LspPolicy_PinnedImplCopyWithImpl(
+      _$LspPolicy_PinnedImpl _value, $Res Function(_$LspPolicy_PinnedImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? lspId = null,
   }) {
-    return _then(_$InputType_LnUrlErrorImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlErrorData,
+    return _then(_$LspPolicy_PinnedImpl(
+      lspId: null == lspId
+          ? _value.lspId
+          : lspId // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$InputType_LnUrlErrorImpl implements InputType_LnUrlError {
-  const _$InputType_LnUrlErrorImpl({required this.data});
+class _$LspPolicy_PinnedImpl implements LspPolicy_Pinned {
+  const _$LspPolicy_PinnedImpl({required this.lspId});
 
   @override
-  final LnUrlErrorData data;
+  final String lspId;
 
   @override
   String toString() {
-    return 'InputType.lnUrlError(data: $data)';
+    return 'LspPolicy.pinned(lspId: $lspId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$InputType_LnUrlErrorImpl &&
-            (identical(other.data, data) || other.data == data));
+            other is _$LspPolicy_PinnedImpl &&
+            (identical(other.lspId, lspId) || other.lspId == lspId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, lspId);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$InputType_LnUrlErrorImplCopyWith<_$InputType_LnUrlErrorImpl> get copyWith =>
-      __$$InputType_LnUrlErrorImplCopyWithImpl<_$InputType_LnUrlErrorImpl>(this, _$identity);
+  _$$LspPolicy_PinnedImplCopyWith<_$LspPolicy_PinnedImpl> get copyWith =>
+      __$$LspPolicy_PinnedImplCopyWithImpl<_$LspPolicy_PinnedImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(BitcoinAddressData address) bitcoinAddress,
-    required TResult Function(LNInvoice invoice) bolt11,
-    required TResult Function(String nodeId) nodeId,
-    required TResult Function(String url) url,
-    required TResult Function(LnUrlPayRequestData data, String? bip353Address) lnUrlPay,
-    required TResult Function(LnUrlWithdrawRequestData data) lnUrlWithdraw,
-    required TResult Function(LnUrlAuthRequestData data) lnUrlAuth,
-    required TResult Function(LnUrlErrorData data) lnUrlError,
+    required TResult Function() lowestOpeningFee,
+    required TResult Function() lowestProportionalFee,
+    required TResult Function(String lspId) pinned,
   }) {
-    return lnUrlError(data);
+    return pinned(lspId);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult? Function(LNInvoice invoice)? bolt11,
-    TResult? Function(String nodeId)? nodeId,
-    TResult? Function(String url)? url,
-    TResult? Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult? Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult? Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult? Function(LnUrlErrorData data)? lnUrlError,
+    TResult? Function()? lowestOpeningFee,
+    TResult? Function()? lowestProportionalFee,
+    TResult? Function(String lspId)? pinned,
   }) {
-    return lnUrlError?.call(data);
+    return pinned?.call(lspId);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(BitcoinAddressData address)? bitcoinAddress,
-    TResult Function(LNInvoice invoice)? bolt11,
-    TResult Function(String nodeId)? nodeId,
-    TResult Function(String url)? url,
-    TResult Function(LnUrlPayRequestData data, String? bip353Address)? lnUrlPay,
-    TResult Function(LnUrlWithdrawRequestData data)? lnUrlWithdraw,
-    TResult Function(LnUrlAuthRequestData data)? lnUrlAuth,
-    TResult Function(LnUrlErrorData data)? lnUrlError,
+    TResult Function()? lowestOpeningFee,
+    TResult Function()? lowestProportionalFee,
+    TResult Function(String lspId)? pinned,
     required TResult orElse(),
   }) {
-    if (lnUrlError != null) {
-      return lnUrlError(data);
+    if (pinned != null) {
+      return pinned(lspId);
     }
     return orElse();
   }
@@ -3568,114 +14489,98 @@ class _$InputType_LnUrlErrorImpl implements InputType_LnUrlError {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(InputType_BitcoinAddress value) bitcoinAddress,
-    required TResult Function(InputType_Bolt11 value) bolt11,
-    required TResult Function(InputType_NodeId value) nodeId,
-    required TResult Function(InputType_Url value) url,
-    required TResult Function(InputType_LnUrlPay value) lnUrlPay,
-    required TResult Function(InputType_LnUrlWithdraw value) lnUrlWithdraw,
-    required TResult Function(InputType_LnUrlAuth value) lnUrlAuth,
-    required TResult Function(InputType_LnUrlError value) lnUrlError,
+    required TResult Function(LspPolicy_LowestOpeningFee value) lowestOpeningFee,
+    required TResult Function(LspPolicy_LowestProportionalFee value) lowestProportionalFee,
+    required TResult Function(LspPolicy_Pinned value) pinned,
   }) {
-    return lnUrlError(this);
+    return pinned(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult? Function(InputType_Bolt11 value)? bolt11,
-    TResult? Function(InputType_NodeId value)? nodeId,
-    TResult? Function(InputType_Url value)? url,
-    TResult? Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult? Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult? Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult? Function(InputType_LnUrlError value)? lnUrlError,
+    TResult? Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult? Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult? Function(LspPolicy_Pinned value)? pinned,
   }) {
-    return lnUrlError?.call(this);
+    return pinned?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(InputType_BitcoinAddress value)? bitcoinAddress,
-    TResult Function(InputType_Bolt11 value)? bolt11,
-    TResult Function(InputType_NodeId value)? nodeId,
-    TResult Function(InputType_Url value)? url,
-    TResult Function(InputType_LnUrlPay value)? lnUrlPay,
-    TResult Function(InputType_LnUrlWithdraw value)? lnUrlWithdraw,
-    TResult Function(InputType_LnUrlAuth value)? lnUrlAuth,
-    TResult Function(InputType_LnUrlError value)? lnUrlError,
+    TResult Function(LspPolicy_LowestOpeningFee value)? lowestOpeningFee,
+    TResult Function(LspPolicy_LowestProportionalFee value)? lowestProportionalFee,
+    TResult Function(LspPolicy_Pinned value)? pinned,
     required TResult orElse(),
   }) {
-    if (lnUrlError != null) {
-      return lnUrlError(this);
+    if (pinned != null) {
+      return pinned(this);
     }
     return orElse();
   }
 }
 
-abstract class InputType_LnUrlError implements InputType {
-  const factory InputType_LnUrlError({required final LnUrlErrorData data}) = _$InputType_LnUrlErrorImpl;
+abstract class LspPolicy_Pinned implements LspPolicy {
+  const factory LspPolicy_Pinned({required final String lspId}) = _$LspPolicy_PinnedImpl;
 
-  LnUrlErrorData get data;
+  String get lspId;
   @JsonKey(ignore: true)
-  _$$InputType_LnUrlErrorImplCopyWith<_$InputType_LnUrlErrorImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$LspPolicy_PinnedImplCopyWith<_$LspPolicy_PinnedImpl> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$LnUrlCallbackStatus {
+mixin _$NodeConfig {
+  Object get config => throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() ok,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(MockNodeConfig config) mock,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? ok,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(MockNodeConfig config)? mock,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? ok,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(MockNodeConfig config)? mock,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlCallbackStatus_Ok value) ok,
-    required TResult Function(LnUrlCallbackStatus_ErrorStatus value) errorStatus,
+    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(NodeConfig_Mock value) mock,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlCallbackStatus_Ok value)? ok,
-    TResult? Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(NodeConfig_Mock value)? mock,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlCallbackStatus_Ok value)? ok,
-    TResult Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(NodeConfig_Mock value)? mock,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $LnUrlCallbackStatusCopyWith<$Res> {
-  factory $LnUrlCallbackStatusCopyWith(LnUrlCallbackStatus value, $Res Function(LnUrlCallbackStatus) then) =
-      _$LnUrlCallbackStatusCopyWithImpl<$Res, LnUrlCallbackStatus>;
+abstract class $NodeConfigCopyWith<$Res> {
+  factory $NodeConfigCopyWith(NodeConfig value, $Res Function(NodeConfig) then) =
+      _$NodeConfigCopyWithImpl<$Res, NodeConfig>;
 }
 
 /// @nodoc
-class _$LnUrlCallbackStatusCopyWithImpl<$Res, $Val extends LnUrlCallbackStatus>
-    implements $LnUrlCallbackStatusCopyWith<$Res> {
-  _$LnUrlCallbackStatusCopyWithImpl(this._value, this._then);
+class _$NodeConfigCopyWithImpl<$Res, $Val extends NodeConfig> implements $NodeConfigCopyWith<$Res> {
+  _$NodeConfigCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -3684,67 +14589,227 @@ class _$LnUrlCallbackStatusCopyWithImpl<$Res, $Val extends LnUrlCallbackStatus>
 }
 
 /// @nodoc
-abstract class _$$LnUrlCallbackStatus_OkImplCopyWith<$Res> {
-  factory _$$LnUrlCallbackStatus_OkImplCopyWith(
-          _$LnUrlCallbackStatus_OkImpl value, $Res Function(_$LnUrlCallbackStatus_OkImpl) then) =
-      __$$LnUrlCallbackStatus_OkImplCopyWithImpl<$Res>;
+abstract class _$$NodeConfig_GreenlightImplCopyWith<$Res> {
+  factory _$$NodeConfig_GreenlightImplCopyWith(
+          _$NodeConfig_GreenlightImpl value, $Res Function(_$NodeConfig_GreenlightImpl) then) =
+      __$$NodeConfig_GreenlightImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({GreenlightNodeConfig config});
 }
 
 /// @nodoc
-class __$$LnUrlCallbackStatus_OkImplCopyWithImpl<$Res>
-    extends _$LnUrlCallbackStatusCopyWithImpl<$Res, _$LnUrlCallbackStatus_OkImpl>
-    implements _$$LnUrlCallbackStatus_OkImplCopyWith<$Res> {
-  __$$LnUrlCallbackStatus_OkImplCopyWithImpl(
-      _$LnUrlCallbackStatus_OkImpl _value, $Res Function(_$LnUrlCallbackStatus_OkImpl) _then)
+class __$$NodeConfig_GreenlightImplCopyWithImpl<$Res>
+    extends _$NodeConfigCopyWithImpl<$Res, _$NodeConfig_GreenlightImpl>
+    implements _$$NodeConfig_GreenlightImplCopyWith<$Res> {
+  __$$NodeConfig_GreenlightImplCopyWithImpl(
+      _$NodeConfig_GreenlightImpl _value, $Res Function(_$NodeConfig_GreenlightImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? config = null,
+  }) {
+    return _then(_$NodeConfig_GreenlightImpl(
+      config: null == config
+          ? _value.config
+          : config // ignore: cast_nullable_to_non_nullable
+              as GreenlightNodeConfig,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$NodeConfig_GreenlightImpl implements NodeConfig_Greenlight {
+  const _$NodeConfig_GreenlightImpl({required this.config});
+
+  @override
+  final GreenlightNodeConfig config;
+
+  @override
+  String toString() {
+    return 'NodeConfig.greenlight(config: $config)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$NodeConfig_GreenlightImpl &&
+            (identical(other.config, config) || other.config == config));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, config);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NodeConfig_GreenlightImplCopyWith<_$NodeConfig_GreenlightImpl> get copyWith =>
+      __$$NodeConfig_GreenlightImplCopyWithImpl<_$NodeConfig_GreenlightImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(MockNodeConfig config) mock,
+  }) {
+    return greenlight(config);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(MockNodeConfig config)? mock,
+  }) {
+    return greenlight?.call(config);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(MockNodeConfig config)? mock,
+    required TResult orElse(),
+  }) {
+    if (greenlight != null) {
+      return greenlight(config);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(NodeConfig_Mock value) mock,
+  }) {
+    return greenlight(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(NodeConfig_Mock value)? mock,
+  }) {
+    return greenlight?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(NodeConfig_Mock value)? mock,
+    required TResult orElse(),
+  }) {
+    if (greenlight != null) {
+      return greenlight(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class NodeConfig_Greenlight implements NodeConfig {
+  const factory NodeConfig_Greenlight({required final GreenlightNodeConfig config}) =
+      _$NodeConfig_GreenlightImpl;
+
+  @override
+  GreenlightNodeConfig get config;
+  @JsonKey(ignore: true)
+  _$$NodeConfig_GreenlightImplCopyWith<_$NodeConfig_GreenlightImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$NodeConfig_MockImplCopyWith<$Res> {
+  factory _$$NodeConfig_MockImplCopyWith(
+          _$NodeConfig_MockImpl value, $Res Function(_$NodeConfig_MockImpl) then) =
+      __$$NodeConfig_MockImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({MockNodeConfig config});
+}
+
+/// @nodoc
+class __$$NodeConfig_MockImplCopyWithImpl<$Res> extends _$NodeConfigCopyWithImpl<$Res, _$NodeConfig_MockImpl>
+    implements _$$NodeConfig_MockImplCopyWith<$Res> {
+  __$$NodeConfig_MockImplCopyWithImpl(
+      _$NodeConfig_MockImpl _value, $Res Function(_$NodeConfig_MockImpl) _then)
       : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? config = null,
+  }) {
+    return _then(_$NodeConfig_MockImpl(
+      config: null == config
+          ? _value.config
+          : config // ignore: cast_nullable_to_non_nullable
+              as MockNodeConfig,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$LnUrlCallbackStatus_OkImpl implements LnUrlCallbackStatus_Ok {
-  const _$LnUrlCallbackStatus_OkImpl();
+class _$NodeConfig_MockImpl implements NodeConfig_Mock {
+  const _$NodeConfig_MockImpl({required this.config});
+
+  @override
+  final MockNodeConfig config;
 
   @override
   String toString() {
-    return 'LnUrlCallbackStatus.ok()';
+    return 'NodeConfig.mock(config: $config)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$LnUrlCallbackStatus_OkImpl);
+        (other.runtimeType == runtimeType &&
+            other is _$NodeConfig_MockImpl &&
+            (identical(other.config, config) || other.config == config));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, config);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$NodeConfig_MockImplCopyWith<_$NodeConfig_MockImpl> get copyWith =>
+      __$$NodeConfig_MockImplCopyWithImpl<_$NodeConfig_MockImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() ok,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(MockNodeConfig config) mock,
   }) {
-    return ok();
+    return mock(config);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? ok,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(MockNodeConfig config)? mock,
   }) {
-    return ok?.call();
+    return mock?.call(config);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? ok,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(MockNodeConfig config)? mock,
     required TResult orElse(),
   }) {
-    if (ok != null) {
-      return ok();
+    if (mock != null) {
+      return mock(config);
     }
     return orElse();
   }
@@ -3752,128 +14817,202 @@ class _$LnUrlCallbackStatus_OkImpl implements LnUrlCallbackStatus_Ok {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlCallbackStatus_Ok value) ok,
-    required TResult Function(LnUrlCallbackStatus_ErrorStatus value) errorStatus,
+    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(NodeConfig_Mock value) mock,
   }) {
-    return ok(this);
+    return mock(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlCallbackStatus_Ok value)? ok,
-    TResult? Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(NodeConfig_Mock value)? mock,
   }) {
-    return ok?.call(this);
+    return mock?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlCallbackStatus_Ok value)? ok,
-    TResult Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(NodeConfig_Mock value)? mock,
     required TResult orElse(),
   }) {
-    if (ok != null) {
-      return ok(this);
+    if (mock != null) {
+      return mock(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlCallbackStatus_Ok implements LnUrlCallbackStatus {
-  const factory LnUrlCallbackStatus_Ok() = _$LnUrlCallbackStatus_OkImpl;
+abstract class NodeConfig_Mock implements NodeConfig {
+  const factory NodeConfig_Mock({required final MockNodeConfig config}) = _$NodeConfig_MockImpl;
+
+  @override
+  MockNodeConfig get config;
+  @JsonKey(ignore: true)
+  _$$NodeConfig_MockImplCopyWith<_$NodeConfig_MockImpl> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<$Res> {
-  factory _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith(_$LnUrlCallbackStatus_ErrorStatusImpl value,
-          $Res Function(_$LnUrlCallbackStatus_ErrorStatusImpl) then) =
-      __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl<$Res>;
+mixin _$NodeCredentials {
+  GreenlightDeviceCredentials get credentials => throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(GreenlightDeviceCredentials credentials) greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(GreenlightDeviceCredentials credentials)? greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(GreenlightDeviceCredentials credentials)? greenlight,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(NodeCredentials_Greenlight value) greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(NodeCredentials_Greenlight value)? greenlight,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(NodeCredentials_Greenlight value)? greenlight,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+
+  @JsonKey(ignore: true)
+  $NodeCredentialsCopyWith<NodeCredentials> get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $NodeCredentialsCopyWith<$Res> {
+  factory $NodeCredentialsCopyWith(NodeCredentials value, $Res Function(NodeCredentials) then) =
+      _$NodeCredentialsCopyWithImpl<$Res, NodeCredentials>;
   @useResult
-  $Res call({LnUrlErrorData data});
+  $Res call({GreenlightDeviceCredentials credentials});
 }
 
 /// @nodoc
-class __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl<$Res>
-    extends _$LnUrlCallbackStatusCopyWithImpl<$Res, _$LnUrlCallbackStatus_ErrorStatusImpl>
-    implements _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<$Res> {
-  __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl(_$LnUrlCallbackStatus_ErrorStatusImpl _value,
-      $Res Function(_$LnUrlCallbackStatus_ErrorStatusImpl) _then)
+class _$NodeCredentialsCopyWithImpl<$Res, $Val extends NodeCredentials>
+    implements $NodeCredentialsCopyWith<$Res> {
+  _$NodeCredentialsCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? credentials = null,
+  }) {
+    return _then(_value.copyWith(
+      credentials: null == credentials
+          ? _value.credentials
+          : credentials // ignore: cast_nullable_to_non_nullable
+              as GreenlightDeviceCredentials,
+    ) as $Val);
+  }
+}
+
+/// @nodoc
+abstract class _$$NodeCredentials_GreenlightImplCopyWith<$Res> implements $NodeCredentialsCopyWith<$Res> {
+  factory _$$NodeCredentials_GreenlightImplCopyWith(
+          _$NodeCredentials_GreenlightImpl value, $Res Function(_$NodeCredentials_GreenlightImpl) then) =
+      __$$NodeCredentials_GreenlightImplCopyWithImpl<$Res>;
+  @override
+  @useResult
+  $Res call({GreenlightDeviceCredentials credentials});
+}
+
+/// @nodoc
+class __$$NodeCredentials_GreenlightImplCopyWithImpl<$Res>
+    extends _$NodeCredentialsCopyWithImpl<$Res, _$NodeCredentials_GreenlightImpl>
+    implements _$$NodeCredentials_GreenlightImplCopyWith<$Res> {
+  __$$NodeCredentials_GreenlightImplCopyWithImpl(
+      _$NodeCredentials_GreenlightImpl _value, $Res Function(_$NodeCredentials_GreenlightImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? credentials = null,
   }) {
-    return _then(_$LnUrlCallbackStatus_ErrorStatusImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlErrorData,
+    return _then(_$NodeCredentials_GreenlightImpl(
+      credentials: null == credentials
+          ? _value.credentials
+          : credentials // ignore: cast_nullable_to_non_nullable
+              as GreenlightDeviceCredentials,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LnUrlCallbackStatus_ErrorStatusImpl implements LnUrlCallbackStatus_ErrorStatus {
-  const _$LnUrlCallbackStatus_ErrorStatusImpl({required this.data});
+class _$NodeCredentials_GreenlightImpl implements NodeCredentials_Greenlight {
+  const _$NodeCredentials_GreenlightImpl({required this.credentials});
 
   @override
-  final LnUrlErrorData data;
+  final GreenlightDeviceCredentials credentials;
 
   @override
   String toString() {
-    return 'LnUrlCallbackStatus.errorStatus(data: $data)';
+    return 'NodeCredentials.greenlight(credentials: $credentials)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LnUrlCallbackStatus_ErrorStatusImpl &&
-            (identical(other.data, data) || other.data == data));
+            other is _$NodeCredentials_GreenlightImpl &&
+            (identical(other.credentials, credentials) || other.credentials == credentials));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, credentials);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<_$LnUrlCallbackStatus_ErrorStatusImpl> get copyWith =>
-      __$$LnUrlCallbackStatus_ErrorStatusImplCopyWithImpl<_$LnUrlCallbackStatus_ErrorStatusImpl>(
-          this, _$identity);
+  _$$NodeCredentials_GreenlightImplCopyWith<_$NodeCredentials_GreenlightImpl> get copyWith =>
+      __$$NodeCredentials_GreenlightImplCopyWithImpl<_$NodeCredentials_GreenlightImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function() ok,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function(GreenlightDeviceCredentials credentials) greenlight,
   }) {
-    return errorStatus(data);
+    return greenlight(credentials);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function()? ok,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function(GreenlightDeviceCredentials credentials)? greenlight,
   }) {
-    return errorStatus?.call(data);
+    return greenlight?.call(credentials);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function()? ok,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function(GreenlightDeviceCredentials credentials)? greenlight,
     required TResult orElse(),
   }) {
-    if (errorStatus != null) {
-      return errorStatus(data);
+    if (greenlight != null) {
+      return greenlight(credentials);
     }
     return orElse();
   }
@@ -3881,104 +15020,109 @@ class _$LnUrlCallbackStatus_ErrorStatusImpl implements LnUrlCallbackStatus_Error
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlCallbackStatus_Ok value) ok,
-    required TResult Function(LnUrlCallbackStatus_ErrorStatus value) errorStatus,
+    required TResult Function(NodeCredentials_Greenlight value) greenlight,
   }) {
-    return errorStatus(this);
+    return greenlight(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlCallbackStatus_Ok value)? ok,
-    TResult? Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    TResult? Function(NodeCredentials_Greenlight value)? greenlight,
   }) {
-    return errorStatus?.call(this);
+    return greenlight?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlCallbackStatus_Ok value)? ok,
-    TResult Function(LnUrlCallbackStatus_ErrorStatus value)? errorStatus,
+    TResult Function(NodeCredentials_Greenlight value)? greenlight,
     required TResult orElse(),
   }) {
-    if (errorStatus != null) {
-      return errorStatus(this);
+    if (greenlight != null) {
+      return greenlight(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlCallbackStatus_ErrorStatus implements LnUrlCallbackStatus {
-  const factory LnUrlCallbackStatus_ErrorStatus({required final LnUrlErrorData data}) =
-      _$LnUrlCallbackStatus_ErrorStatusImpl;
+abstract class NodeCredentials_Greenlight implements NodeCredentials {
+  const factory NodeCredentials_Greenlight({required final GreenlightDeviceCredentials credentials}) =
+      _$NodeCredentials_GreenlightImpl;
 
-  LnUrlErrorData get data;
+  @override
+  GreenlightDeviceCredentials get credentials;
+  @override
   @JsonKey(ignore: true)
-  _$$LnUrlCallbackStatus_ErrorStatusImplCopyWith<_$LnUrlCallbackStatus_ErrorStatusImpl> get copyWith =>
+  _$$NodeCredentials_GreenlightImplCopyWith<_$NodeCredentials_GreenlightImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$LnUrlPayResult {
-  Object get data => throw _privateConstructorUsedError;
+mixin _$OpenChannelReceiveStage {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
-    required TResult Function(LnUrlErrorData data) endpointError,
-    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(int openingFeeMsat) registered,
+    required TResult Function(String fundingTxid) channelPending,
+    required TResult Function(int openingFeeMsat) settled,
+    required TResult Function() reregistered,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult? Function(LnUrlErrorData data)? endpointError,
-    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(int openingFeeMsat)? registered,
+    TResult? Function(String fundingTxid)? channelPending,
+    TResult? Function(int openingFeeMsat)? settled,
+    TResult? Function()? reregistered,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult Function(LnUrlErrorData data)? endpointError,
-    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(int openingFeeMsat)? registered,
+    TResult Function(String fundingTxid)? channelPending,
+    TResult Function(int openingFeeMsat)? settled,
+    TResult Function()? reregistered,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
-    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
-    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(OpenChannelReceiveStage_Registered value) registered,
+    required TResult Function(OpenChannelReceiveStage_ChannelPending value) channelPending,
+    required TResult Function(OpenChannelReceiveStage_Settled value) settled,
+    required TResult Function(OpenChannelReceiveStage_Reregistered value) reregistered,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult? Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult? Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult? Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $LnUrlPayResultCopyWith<$Res> {
-  factory $LnUrlPayResultCopyWith(LnUrlPayResult value, $Res Function(LnUrlPayResult) then) =
-      _$LnUrlPayResultCopyWithImpl<$Res, LnUrlPayResult>;
+abstract class $OpenChannelReceiveStageCopyWith<$Res> {
+  factory $OpenChannelReceiveStageCopyWith(
+          OpenChannelReceiveStage value, $Res Function(OpenChannelReceiveStage) then) =
+      _$OpenChannelReceiveStageCopyWithImpl<$Res, OpenChannelReceiveStage>;
 }
 
 /// @nodoc
-class _$LnUrlPayResultCopyWithImpl<$Res, $Val extends LnUrlPayResult>
-    implements $LnUrlPayResultCopyWith<$Res> {
-  _$LnUrlPayResultCopyWithImpl(this._value, this._then);
+class _$OpenChannelReceiveStageCopyWithImpl<$Res, $Val extends OpenChannelReceiveStage>
+    implements $OpenChannelReceiveStageCopyWith<$Res> {
+  _$OpenChannelReceiveStageCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -3987,97 +15131,100 @@ class _$LnUrlPayResultCopyWithImpl<$Res, $Val extends LnUrlPayResult>
 }
 
 /// @nodoc
-abstract class _$$LnUrlPayResult_EndpointSuccessImplCopyWith<$Res> {
-  factory _$$LnUrlPayResult_EndpointSuccessImplCopyWith(_$LnUrlPayResult_EndpointSuccessImpl value,
-          $Res Function(_$LnUrlPayResult_EndpointSuccessImpl) then) =
-      __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl<$Res>;
-  @useResult
-  $Res call({LnUrlPaySuccessData data});
-}
-
-/// @nodoc
-class __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl<$Res>
-    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_EndpointSuccessImpl>
-    implements _$$LnUrlPayResult_EndpointSuccessImplCopyWith<$Res> {
-  __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl(
-      _$LnUrlPayResult_EndpointSuccessImpl _value, $Res Function(_$LnUrlPayResult_EndpointSuccessImpl) _then)
+abstract class _$$OpenChannelReceiveStage_RegisteredImplCopyWith<$Res> {
+  factory _$$OpenChannelReceiveStage_RegisteredImplCopyWith(_$OpenChannelReceiveStage_RegisteredImpl value,
+          $Res Function(_$OpenChannelReceiveStage_RegisteredImpl) then) =
+      __$$OpenChannelReceiveStage_RegisteredImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({int openingFeeMsat});
+}
+
+/// @nodoc
+class __$$OpenChannelReceiveStage_RegisteredImplCopyWithImpl<$Res>
+    extends _$OpenChannelReceiveStageCopyWithImpl<$Res, _$OpenChannelReceiveStage_RegisteredImpl>
+    implements _$$OpenChannelReceiveStage_RegisteredImplCopyWith<$Res> {
+  __$$OpenChannelReceiveStage_RegisteredImplCopyWithImpl(_$OpenChannelReceiveStage_RegisteredImpl _value,
+      $Res Function(_$OpenChannelReceiveStage_RegisteredImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? openingFeeMsat = null,
   }) {
-    return _then(_$LnUrlPayResult_EndpointSuccessImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlPaySuccessData,
+    return _then(_$OpenChannelReceiveStage_RegisteredImpl(
+      openingFeeMsat: null == openingFeeMsat
+          ? _value.openingFeeMsat
+          : openingFeeMsat // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LnUrlPayResult_EndpointSuccessImpl implements LnUrlPayResult_EndpointSuccess {
-  const _$LnUrlPayResult_EndpointSuccessImpl({required this.data});
+class _$OpenChannelReceiveStage_RegisteredImpl implements OpenChannelReceiveStage_Registered {
+  const _$OpenChannelReceiveStage_RegisteredImpl({required this.openingFeeMsat});
 
   @override
-  final LnUrlPaySuccessData data;
+  final int openingFeeMsat;
 
   @override
   String toString() {
-    return 'LnUrlPayResult.endpointSuccess(data: $data)';
+    return 'OpenChannelReceiveStage.registered(openingFeeMsat: $openingFeeMsat)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LnUrlPayResult_EndpointSuccessImpl &&
-            (identical(other.data, data) || other.data == data));
+            other is _$OpenChannelReceiveStage_RegisteredImpl &&
+            (identical(other.openingFeeMsat, openingFeeMsat) || other.openingFeeMsat == openingFeeMsat));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, openingFeeMsat);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LnUrlPayResult_EndpointSuccessImplCopyWith<_$LnUrlPayResult_EndpointSuccessImpl> get copyWith =>
-      __$$LnUrlPayResult_EndpointSuccessImplCopyWithImpl<_$LnUrlPayResult_EndpointSuccessImpl>(
+  _$$OpenChannelReceiveStage_RegisteredImplCopyWith<_$OpenChannelReceiveStage_RegisteredImpl> get copyWith =>
+      __$$OpenChannelReceiveStage_RegisteredImplCopyWithImpl<_$OpenChannelReceiveStage_RegisteredImpl>(
           this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
-    required TResult Function(LnUrlErrorData data) endpointError,
-    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(int openingFeeMsat) registered,
+    required TResult Function(String fundingTxid) channelPending,
+    required TResult Function(int openingFeeMsat) settled,
+    required TResult Function() reregistered,
   }) {
-    return endpointSuccess(data);
+    return registered(openingFeeMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult? Function(LnUrlErrorData data)? endpointError,
-    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(int openingFeeMsat)? registered,
+    TResult? Function(String fundingTxid)? channelPending,
+    TResult? Function(int openingFeeMsat)? settled,
+    TResult? Function()? reregistered,
   }) {
-    return endpointSuccess?.call(data);
+    return registered?.call(openingFeeMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult Function(LnUrlErrorData data)? endpointError,
-    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(int openingFeeMsat)? registered,
+    TResult Function(String fundingTxid)? channelPending,
+    TResult Function(int openingFeeMsat)? settled,
+    TResult Function()? reregistered,
     required TResult orElse(),
   }) {
-    if (endpointSuccess != null) {
-      return endpointSuccess(data);
+    if (registered != null) {
+      return registered(openingFeeMsat);
     }
     return orElse();
   }
@@ -4085,140 +15232,148 @@ class _$LnUrlPayResult_EndpointSuccessImpl implements LnUrlPayResult_EndpointSuc
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
-    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
-    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(OpenChannelReceiveStage_Registered value) registered,
+    required TResult Function(OpenChannelReceiveStage_ChannelPending value) channelPending,
+    required TResult Function(OpenChannelReceiveStage_Settled value) settled,
+    required TResult Function(OpenChannelReceiveStage_Reregistered value) reregistered,
   }) {
-    return endpointSuccess(this);
+    return registered(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult? Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult? Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult? Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
   }) {
-    return endpointSuccess?.call(this);
+    return registered?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
     required TResult orElse(),
   }) {
-    if (endpointSuccess != null) {
-      return endpointSuccess(this);
+    if (registered != null) {
+      return registered(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlPayResult_EndpointSuccess implements LnUrlPayResult {
-  const factory LnUrlPayResult_EndpointSuccess({required final LnUrlPaySuccessData data}) =
-      _$LnUrlPayResult_EndpointSuccessImpl;
+abstract class OpenChannelReceiveStage_Registered implements OpenChannelReceiveStage {
+  const factory OpenChannelReceiveStage_Registered({required final int openingFeeMsat}) =
+      _$OpenChannelReceiveStage_RegisteredImpl;
 
-  @override
-  LnUrlPaySuccessData get data;
+  int get openingFeeMsat;
   @JsonKey(ignore: true)
-  _$$LnUrlPayResult_EndpointSuccessImplCopyWith<_$LnUrlPayResult_EndpointSuccessImpl> get copyWith =>
+  _$$OpenChannelReceiveStage_RegisteredImplCopyWith<_$OpenChannelReceiveStage_RegisteredImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LnUrlPayResult_EndpointErrorImplCopyWith<$Res> {
-  factory _$$LnUrlPayResult_EndpointErrorImplCopyWith(
-          _$LnUrlPayResult_EndpointErrorImpl value, $Res Function(_$LnUrlPayResult_EndpointErrorImpl) then) =
-      __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl<$Res>;
+abstract class _$$OpenChannelReceiveStage_ChannelPendingImplCopyWith<$Res> {
+  factory _$$OpenChannelReceiveStage_ChannelPendingImplCopyWith(
+          _$OpenChannelReceiveStage_ChannelPendingImpl value,
+          $Res Function(_$OpenChannelReceiveStage_ChannelPendingImpl) then) =
+      __$$OpenChannelReceiveStage_ChannelPendingImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlErrorData data});
+  $Res call({String fundingTxid});
 }
 
 /// @nodoc
-class __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl<$Res>
-    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_EndpointErrorImpl>
-    implements _$$LnUrlPayResult_EndpointErrorImplCopyWith<$Res> {
-  __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl(
-      _$LnUrlPayResult_EndpointErrorImpl _value, $Res Function(_$LnUrlPayResult_EndpointErrorImpl) _then)
+class __$$OpenChannelReceiveStage_ChannelPendingImplCopyWithImpl<$Res>
+    extends _$OpenChannelReceiveStageCopyWithImpl<$Res, _$OpenChannelReceiveStage_ChannelPendingImpl>
+    implements _$$OpenChannelReceiveStage_ChannelPendingImplCopyWith<$Res> {
+  __$$OpenChannelReceiveStage_ChannelPendingImplCopyWithImpl(
+      _$OpenChannelReceiveStage_ChannelPendingImpl _value,
+      $Res Function(_$OpenChannelReceiveStage_ChannelPendingImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? fundingTxid = null,
   }) {
-    return _then(_$LnUrlPayResult_EndpointErrorImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlErrorData,
+    return _then(_$OpenChannelReceiveStage_ChannelPendingImpl(
+      fundingTxid: null == fundingTxid
+          ? _value.fundingTxid
+          : fundingTxid // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LnUrlPayResult_EndpointErrorImpl implements LnUrlPayResult_EndpointError {
-  const _$LnUrlPayResult_EndpointErrorImpl({required this.data});
+class _$OpenChannelReceiveStage_ChannelPendingImpl implements OpenChannelReceiveStage_ChannelPending {
+  const _$OpenChannelReceiveStage_ChannelPendingImpl({required this.fundingTxid});
 
   @override
-  final LnUrlErrorData data;
+  final String fundingTxid;
 
   @override
   String toString() {
-    return 'LnUrlPayResult.endpointError(data: $data)';
+    return 'OpenChannelReceiveStage.channelPending(fundingTxid: $fundingTxid)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LnUrlPayResult_EndpointErrorImpl &&
-            (identical(other.data, data) || other.data == data));
+            other is _$OpenChannelReceiveStage_ChannelPendingImpl &&
+            (identical(other.fundingTxid, fundingTxid) || other.fundingTxid == fundingTxid));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, fundingTxid);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LnUrlPayResult_EndpointErrorImplCopyWith<_$LnUrlPayResult_EndpointErrorImpl> get copyWith =>
-      __$$LnUrlPayResult_EndpointErrorImplCopyWithImpl<_$LnUrlPayResult_EndpointErrorImpl>(this, _$identity);
+  _$$OpenChannelReceiveStage_ChannelPendingImplCopyWith<_$OpenChannelReceiveStage_ChannelPendingImpl>
+      get copyWith => __$$OpenChannelReceiveStage_ChannelPendingImplCopyWithImpl<
+          _$OpenChannelReceiveStage_ChannelPendingImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
-    required TResult Function(LnUrlErrorData data) endpointError,
-    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(int openingFeeMsat) registered,
+    required TResult Function(String fundingTxid) channelPending,
+    required TResult Function(int openingFeeMsat) settled,
+    required TResult Function() reregistered,
   }) {
-    return endpointError(data);
+    return channelPending(fundingTxid);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult? Function(LnUrlErrorData data)? endpointError,
-    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(int openingFeeMsat)? registered,
+    TResult? Function(String fundingTxid)? channelPending,
+    TResult? Function(int openingFeeMsat)? settled,
+    TResult? Function()? reregistered,
   }) {
-    return endpointError?.call(data);
+    return channelPending?.call(fundingTxid);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult Function(LnUrlErrorData data)? endpointError,
-    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(int openingFeeMsat)? registered,
+    TResult Function(String fundingTxid)? channelPending,
+    TResult Function(int openingFeeMsat)? settled,
+    TResult Function()? reregistered,
     required TResult orElse(),
   }) {
-    if (endpointError != null) {
-      return endpointError(data);
+    if (channelPending != null) {
+      return channelPending(fundingTxid);
     }
     return orElse();
   }
@@ -4226,140 +15381,146 @@ class _$LnUrlPayResult_EndpointErrorImpl implements LnUrlPayResult_EndpointError
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
-    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
-    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(OpenChannelReceiveStage_Registered value) registered,
+    required TResult Function(OpenChannelReceiveStage_ChannelPending value) channelPending,
+    required TResult Function(OpenChannelReceiveStage_Settled value) settled,
+    required TResult Function(OpenChannelReceiveStage_Reregistered value) reregistered,
   }) {
-    return endpointError(this);
+    return channelPending(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult? Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult? Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult? Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
   }) {
-    return endpointError?.call(this);
+    return channelPending?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
     required TResult orElse(),
   }) {
-    if (endpointError != null) {
-      return endpointError(this);
+    if (channelPending != null) {
+      return channelPending(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlPayResult_EndpointError implements LnUrlPayResult {
-  const factory LnUrlPayResult_EndpointError({required final LnUrlErrorData data}) =
-      _$LnUrlPayResult_EndpointErrorImpl;
+abstract class OpenChannelReceiveStage_ChannelPending implements OpenChannelReceiveStage {
+  const factory OpenChannelReceiveStage_ChannelPending({required final String fundingTxid}) =
+      _$OpenChannelReceiveStage_ChannelPendingImpl;
 
-  @override
-  LnUrlErrorData get data;
+  String get fundingTxid;
   @JsonKey(ignore: true)
-  _$$LnUrlPayResult_EndpointErrorImplCopyWith<_$LnUrlPayResult_EndpointErrorImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$OpenChannelReceiveStage_ChannelPendingImplCopyWith<_$OpenChannelReceiveStage_ChannelPendingImpl>
+      get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LnUrlPayResult_PayErrorImplCopyWith<$Res> {
-  factory _$$LnUrlPayResult_PayErrorImplCopyWith(
-          _$LnUrlPayResult_PayErrorImpl value, $Res Function(_$LnUrlPayResult_PayErrorImpl) then) =
-      __$$LnUrlPayResult_PayErrorImplCopyWithImpl<$Res>;
+abstract class _$$OpenChannelReceiveStage_SettledImplCopyWith<$Res> {
+  factory _$$OpenChannelReceiveStage_SettledImplCopyWith(_$OpenChannelReceiveStage_SettledImpl value,
+          $Res Function(_$OpenChannelReceiveStage_SettledImpl) then) =
+      __$$OpenChannelReceiveStage_SettledImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlPayErrorData data});
+  $Res call({int openingFeeMsat});
 }
 
 /// @nodoc
-class __$$LnUrlPayResult_PayErrorImplCopyWithImpl<$Res>
-    extends _$LnUrlPayResultCopyWithImpl<$Res, _$LnUrlPayResult_PayErrorImpl>
-    implements _$$LnUrlPayResult_PayErrorImplCopyWith<$Res> {
-  __$$LnUrlPayResult_PayErrorImplCopyWithImpl(
-      _$LnUrlPayResult_PayErrorImpl _value, $Res Function(_$LnUrlPayResult_PayErrorImpl) _then)
+class __$$OpenChannelReceiveStage_SettledImplCopyWithImpl<$Res>
+    extends _$OpenChannelReceiveStageCopyWithImpl<$Res, _$OpenChannelReceiveStage_SettledImpl>
+    implements _$$OpenChannelReceiveStage_SettledImplCopyWith<$Res> {
+  __$$OpenChannelReceiveStage_SettledImplCopyWithImpl(_$OpenChannelReceiveStage_SettledImpl _value,
+      $Res Function(_$OpenChannelReceiveStage_SettledImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? openingFeeMsat = null,
   }) {
-    return _then(_$LnUrlPayResult_PayErrorImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlPayErrorData,
+    return _then(_$OpenChannelReceiveStage_SettledImpl(
+      openingFeeMsat: null == openingFeeMsat
+          ? _value.openingFeeMsat
+          : openingFeeMsat // ignore: cast_nullable_to_non_nullable
+              as int,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LnUrlPayResult_PayErrorImpl implements LnUrlPayResult_PayError {
-  const _$LnUrlPayResult_PayErrorImpl({required this.data});
+class _$OpenChannelReceiveStage_SettledImpl implements OpenChannelReceiveStage_Settled {
+  const _$OpenChannelReceiveStage_SettledImpl({required this.openingFeeMsat});
 
   @override
-  final LnUrlPayErrorData data;
+  final int openingFeeMsat;
 
   @override
   String toString() {
-    return 'LnUrlPayResult.payError(data: $data)';
+    return 'OpenChannelReceiveStage.settled(openingFeeMsat: $openingFeeMsat)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LnUrlPayResult_PayErrorImpl &&
-            (identical(other.data, data) || other.data == data));
+            other is _$OpenChannelReceiveStage_SettledImpl &&
+            (identical(other.openingFeeMsat, openingFeeMsat) || other.openingFeeMsat == openingFeeMsat));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, openingFeeMsat);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LnUrlPayResult_PayErrorImplCopyWith<_$LnUrlPayResult_PayErrorImpl> get copyWith =>
-      __$$LnUrlPayResult_PayErrorImplCopyWithImpl<_$LnUrlPayResult_PayErrorImpl>(this, _$identity);
+  _$$OpenChannelReceiveStage_SettledImplCopyWith<_$OpenChannelReceiveStage_SettledImpl> get copyWith =>
+      __$$OpenChannelReceiveStage_SettledImplCopyWithImpl<_$OpenChannelReceiveStage_SettledImpl>(
+          this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlPaySuccessData data) endpointSuccess,
-    required TResult Function(LnUrlErrorData data) endpointError,
-    required TResult Function(LnUrlPayErrorData data) payError,
+    required TResult Function(int openingFeeMsat) registered,
+    required TResult Function(String fundingTxid) channelPending,
+    required TResult Function(int openingFeeMsat) settled,
+    required TResult Function() reregistered,
   }) {
-    return payError(data);
+    return settled(openingFeeMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult? Function(LnUrlErrorData data)? endpointError,
-    TResult? Function(LnUrlPayErrorData data)? payError,
+    TResult? Function(int openingFeeMsat)? registered,
+    TResult? Function(String fundingTxid)? channelPending,
+    TResult? Function(int openingFeeMsat)? settled,
+    TResult? Function()? reregistered,
   }) {
-    return payError?.call(data);
+    return settled?.call(openingFeeMsat);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlPaySuccessData data)? endpointSuccess,
-    TResult Function(LnUrlErrorData data)? endpointError,
-    TResult Function(LnUrlPayErrorData data)? payError,
+    TResult Function(int openingFeeMsat)? registered,
+    TResult Function(String fundingTxid)? channelPending,
+    TResult Function(int openingFeeMsat)? settled,
+    TResult Function()? reregistered,
     required TResult orElse(),
   }) {
-    if (payError != null) {
-      return payError(data);
+    if (settled != null) {
+      return settled(openingFeeMsat);
     }
     return orElse();
   }
@@ -4367,108 +15528,230 @@ class _$LnUrlPayResult_PayErrorImpl implements LnUrlPayResult_PayError {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlPayResult_EndpointSuccess value) endpointSuccess,
-    required TResult Function(LnUrlPayResult_EndpointError value) endpointError,
-    required TResult Function(LnUrlPayResult_PayError value) payError,
+    required TResult Function(OpenChannelReceiveStage_Registered value) registered,
+    required TResult Function(OpenChannelReceiveStage_ChannelPending value) channelPending,
+    required TResult Function(OpenChannelReceiveStage_Settled value) settled,
+    required TResult Function(OpenChannelReceiveStage_Reregistered value) reregistered,
   }) {
-    return payError(this);
+    return settled(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult? Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult? Function(LnUrlPayResult_PayError value)? payError,
+    TResult? Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult? Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult? Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult? Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
   }) {
-    return payError?.call(this);
+    return settled?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlPayResult_EndpointSuccess value)? endpointSuccess,
-    TResult Function(LnUrlPayResult_EndpointError value)? endpointError,
-    TResult Function(LnUrlPayResult_PayError value)? payError,
+    TResult Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
     required TResult orElse(),
   }) {
-    if (payError != null) {
-      return payError(this);
+    if (settled != null) {
+      return settled(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlPayResult_PayError implements LnUrlPayResult {
-  const factory LnUrlPayResult_PayError({required final LnUrlPayErrorData data}) =
-      _$LnUrlPayResult_PayErrorImpl;
+abstract class OpenChannelReceiveStage_Settled implements OpenChannelReceiveStage {
+  const factory OpenChannelReceiveStage_Settled({required final int openingFeeMsat}) =
+      _$OpenChannelReceiveStage_SettledImpl;
 
-  @override
-  LnUrlPayErrorData get data;
+  int get openingFeeMsat;
   @JsonKey(ignore: true)
-  _$$LnUrlPayResult_PayErrorImplCopyWith<_$LnUrlPayResult_PayErrorImpl> get copyWith =>
+  _$$OpenChannelReceiveStage_SettledImplCopyWith<_$OpenChannelReceiveStage_SettledImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-mixin _$LnUrlWithdrawResult {
-  Object get data => throw _privateConstructorUsedError;
+abstract class _$$OpenChannelReceiveStage_ReregisteredImplCopyWith<$Res> {
+  factory _$$OpenChannelReceiveStage_ReregisteredImplCopyWith(
+          _$OpenChannelReceiveStage_ReregisteredImpl value,
+          $Res Function(_$OpenChannelReceiveStage_ReregisteredImpl) then) =
+      __$$OpenChannelReceiveStage_ReregisteredImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$OpenChannelReceiveStage_ReregisteredImplCopyWithImpl<$Res>
+    extends _$OpenChannelReceiveStageCopyWithImpl<$Res, _$OpenChannelReceiveStage_ReregisteredImpl>
+    implements _$$OpenChannelReceiveStage_ReregisteredImplCopyWith<$Res> {
+  __$$OpenChannelReceiveStage_ReregisteredImplCopyWithImpl(_$OpenChannelReceiveStage_ReregisteredImpl _value,
+      $Res Function(_$OpenChannelReceiveStage_ReregisteredImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$OpenChannelReceiveStage_ReregisteredImpl implements OpenChannelReceiveStage_Reregistered {
+  const _$OpenChannelReceiveStage_ReregisteredImpl();
+
+  @override
+  String toString() {
+    return 'OpenChannelReceiveStage.reregistered()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$OpenChannelReceiveStage_ReregisteredImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawSuccessData data) ok,
-    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function(int openingFeeMsat) registered,
+    required TResult Function(String fundingTxid) channelPending,
+    required TResult Function(int openingFeeMsat) settled,
+    required TResult Function() reregistered,
+  }) {
+    return reregistered();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int openingFeeMsat)? registered,
+    TResult? Function(String fundingTxid)? channelPending,
+    TResult? Function(int openingFeeMsat)? settled,
+    TResult? Function()? reregistered,
+  }) {
+    return reregistered?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int openingFeeMsat)? registered,
+    TResult Function(String fundingTxid)? channelPending,
+    TResult Function(int openingFeeMsat)? settled,
+    TResult Function()? reregistered,
+    required TResult orElse(),
+  }) {
+    if (reregistered != null) {
+      return reregistered();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(OpenChannelReceiveStage_Registered value) registered,
+    required TResult Function(OpenChannelReceiveStage_ChannelPending value) channelPending,
+    required TResult Function(OpenChannelReceiveStage_Settled value) settled,
+    required TResult Function(OpenChannelReceiveStage_Reregistered value) reregistered,
+  }) {
+    return reregistered(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult? Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult? Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult? Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
+  }) {
+    return reregistered?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(OpenChannelReceiveStage_Registered value)? registered,
+    TResult Function(OpenChannelReceiveStage_ChannelPending value)? channelPending,
+    TResult Function(OpenChannelReceiveStage_Settled value)? settled,
+    TResult Function(OpenChannelReceiveStage_Reregistered value)? reregistered,
+    required TResult orElse(),
+  }) {
+    if (reregistered != null) {
+      return reregistered(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class OpenChannelReceiveStage_Reregistered implements OpenChannelReceiveStage {
+  const factory OpenChannelReceiveStage_Reregistered() = _$OpenChannelReceiveStage_ReregisteredImpl;
+}
+
+/// @nodoc
+mixin _$OutboxPaymentStatus {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() queued,
+    required TResult Function(Payment payment) sent,
+    required TResult Function() abandoned,
+    required TResult Function() cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function()? queued,
+    TResult? Function(Payment payment)? sent,
+    TResult? Function()? abandoned,
+    TResult? Function()? cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function()? queued,
+    TResult Function(Payment payment)? sent,
+    TResult Function()? abandoned,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
-    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
-    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+    required TResult Function(OutboxPaymentStatus_Queued value) queued,
+    required TResult Function(OutboxPaymentStatus_Sent value) sent,
+    required TResult Function(OutboxPaymentStatus_Abandoned value) abandoned,
+    required TResult Function(OutboxPaymentStatus_Cancelled value) cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
-  TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult? Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult? Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult? Function(OutboxPaymentStatus_Cancelled value)? cancelled,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult Function(OutboxPaymentStatus_Cancelled value)? cancelled,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $LnUrlWithdrawResultCopyWith<$Res> {
-  factory $LnUrlWithdrawResultCopyWith(LnUrlWithdrawResult value, $Res Function(LnUrlWithdrawResult) then) =
-      _$LnUrlWithdrawResultCopyWithImpl<$Res, LnUrlWithdrawResult>;
+abstract class $OutboxPaymentStatusCopyWith<$Res> {
+  factory $OutboxPaymentStatusCopyWith(OutboxPaymentStatus value, $Res Function(OutboxPaymentStatus) then) =
+      _$OutboxPaymentStatusCopyWithImpl<$Res, OutboxPaymentStatus>;
 }
 
 /// @nodoc
-class _$LnUrlWithdrawResultCopyWithImpl<$Res, $Val extends LnUrlWithdrawResult>
-    implements $LnUrlWithdrawResultCopyWith<$Res> {
-  _$LnUrlWithdrawResultCopyWithImpl(this._value, this._then);
+class _$OutboxPaymentStatusCopyWithImpl<$Res, $Val extends OutboxPaymentStatus>
+    implements $OutboxPaymentStatusCopyWith<$Res> {
+  _$OutboxPaymentStatusCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -4477,96 +15760,73 @@ class _$LnUrlWithdrawResultCopyWithImpl<$Res, $Val extends LnUrlWithdrawResult>
 }
 
 /// @nodoc
-abstract class _$$LnUrlWithdrawResult_OkImplCopyWith<$Res> {
-  factory _$$LnUrlWithdrawResult_OkImplCopyWith(
-          _$LnUrlWithdrawResult_OkImpl value, $Res Function(_$LnUrlWithdrawResult_OkImpl) then) =
-      __$$LnUrlWithdrawResult_OkImplCopyWithImpl<$Res>;
-  @useResult
-  $Res call({LnUrlWithdrawSuccessData data});
+abstract class _$$OutboxPaymentStatus_QueuedImplCopyWith<$Res> {
+  factory _$$OutboxPaymentStatus_QueuedImplCopyWith(
+          _$OutboxPaymentStatus_QueuedImpl value, $Res Function(_$OutboxPaymentStatus_QueuedImpl) then) =
+      __$$OutboxPaymentStatus_QueuedImplCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$LnUrlWithdrawResult_OkImplCopyWithImpl<$Res>
-    extends _$LnUrlWithdrawResultCopyWithImpl<$Res, _$LnUrlWithdrawResult_OkImpl>
-    implements _$$LnUrlWithdrawResult_OkImplCopyWith<$Res> {
-  __$$LnUrlWithdrawResult_OkImplCopyWithImpl(
-      _$LnUrlWithdrawResult_OkImpl _value, $Res Function(_$LnUrlWithdrawResult_OkImpl) _then)
+class __$$OutboxPaymentStatus_QueuedImplCopyWithImpl<$Res>
+    extends _$OutboxPaymentStatusCopyWithImpl<$Res, _$OutboxPaymentStatus_QueuedImpl>
+    implements _$$OutboxPaymentStatus_QueuedImplCopyWith<$Res> {
+  __$$OutboxPaymentStatus_QueuedImplCopyWithImpl(
+      _$OutboxPaymentStatus_QueuedImpl _value, $Res Function(_$OutboxPaymentStatus_QueuedImpl) _then)
       : super(_value, _then);
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? data = null,
-  }) {
-    return _then(_$LnUrlWithdrawResult_OkImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlWithdrawSuccessData,
-    ));
-  }
 }
 
 /// @nodoc
 
-class _$LnUrlWithdrawResult_OkImpl implements LnUrlWithdrawResult_Ok {
-  const _$LnUrlWithdrawResult_OkImpl({required this.data});
-
-  @override
-  final LnUrlWithdrawSuccessData data;
+class _$OutboxPaymentStatus_QueuedImpl implements OutboxPaymentStatus_Queued {
+  const _$OutboxPaymentStatus_QueuedImpl();
 
   @override
   String toString() {
-    return 'LnUrlWithdrawResult.ok(data: $data)';
+    return 'OutboxPaymentStatus.queued()';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$LnUrlWithdrawResult_OkImpl &&
-            (identical(other.data, data) || other.data == data));
+        (other.runtimeType == runtimeType && other is _$OutboxPaymentStatus_QueuedImpl);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
-
-  @JsonKey(ignore: true)
-  @override
-  @pragma('vm:prefer-inline')
-  _$$LnUrlWithdrawResult_OkImplCopyWith<_$LnUrlWithdrawResult_OkImpl> get copyWith =>
-      __$$LnUrlWithdrawResult_OkImplCopyWithImpl<_$LnUrlWithdrawResult_OkImpl>(this, _$identity);
+  int get hashCode => runtimeType.hashCode;
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawSuccessData data) ok,
-    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function() queued,
+    required TResult Function(Payment payment) sent,
+    required TResult Function() abandoned,
+    required TResult Function() cancelled,
   }) {
-    return ok(data);
+    return queued();
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function()? queued,
+    TResult? Function(Payment payment)? sent,
+    TResult? Function()? abandoned,
+    TResult? Function()? cancelled,
   }) {
-    return ok?.call(data);
+    return queued?.call();
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function()? queued,
+    TResult Function(Payment payment)? sent,
+    TResult Function()? abandoned,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) {
-    if (ok != null) {
-      return ok(data);
+    if (queued != null) {
+      return queued();
     }
     return orElse();
   }
@@ -4574,140 +15834,139 @@ class _$LnUrlWithdrawResult_OkImpl implements LnUrlWithdrawResult_Ok {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
-    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
-    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+    required TResult Function(OutboxPaymentStatus_Queued value) queued,
+    required TResult Function(OutboxPaymentStatus_Sent value) sent,
+    required TResult Function(OutboxPaymentStatus_Abandoned value) abandoned,
+    required TResult Function(OutboxPaymentStatus_Cancelled value) cancelled,
   }) {
-    return ok(this);
+    return queued(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult? Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult? Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult? Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult? Function(OutboxPaymentStatus_Cancelled value)? cancelled,
   }) {
-    return ok?.call(this);
+    return queued?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult Function(OutboxPaymentStatus_Cancelled value)? cancelled,
     required TResult orElse(),
   }) {
-    if (ok != null) {
-      return ok(this);
+    if (queued != null) {
+      return queued(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlWithdrawResult_Ok implements LnUrlWithdrawResult {
-  const factory LnUrlWithdrawResult_Ok({required final LnUrlWithdrawSuccessData data}) =
-      _$LnUrlWithdrawResult_OkImpl;
-
-  @override
-  LnUrlWithdrawSuccessData get data;
-  @JsonKey(ignore: true)
-  _$$LnUrlWithdrawResult_OkImplCopyWith<_$LnUrlWithdrawResult_OkImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+abstract class OutboxPaymentStatus_Queued implements OutboxPaymentStatus {
+  const factory OutboxPaymentStatus_Queued() = _$OutboxPaymentStatus_QueuedImpl;
 }
 
 /// @nodoc
-abstract class _$$LnUrlWithdrawResult_TimeoutImplCopyWith<$Res> {
-  factory _$$LnUrlWithdrawResult_TimeoutImplCopyWith(
-          _$LnUrlWithdrawResult_TimeoutImpl value, $Res Function(_$LnUrlWithdrawResult_TimeoutImpl) then) =
-      __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl<$Res>;
+abstract class _$$OutboxPaymentStatus_SentImplCopyWith<$Res> {
+  factory _$$OutboxPaymentStatus_SentImplCopyWith(
+          _$OutboxPaymentStatus_SentImpl value, $Res Function(_$OutboxPaymentStatus_SentImpl) then) =
+      __$$OutboxPaymentStatus_SentImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({LnUrlWithdrawSuccessData data});
+  $Res call({Payment payment});
 }
 
 /// @nodoc
-class __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl<$Res>
-    extends _$LnUrlWithdrawResultCopyWithImpl<$Res, _$LnUrlWithdrawResult_TimeoutImpl>
-    implements _$$LnUrlWithdrawResult_TimeoutImplCopyWith<$Res> {
-  __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl(
-      _$LnUrlWithdrawResult_TimeoutImpl _value, $Res Function(_$LnUrlWithdrawResult_TimeoutImpl) _then)
+class __$$OutboxPaymentStatus_SentImplCopyWithImpl<$Res>
+    extends _$OutboxPaymentStatusCopyWithImpl<$Res, _$OutboxPaymentStatus_SentImpl>
+    implements _$$OutboxPaymentStatus_SentImplCopyWith<$Res> {
+  __$$OutboxPaymentStatus_SentImplCopyWithImpl(
+      _$OutboxPaymentStatus_SentImpl _value, $Res Function(_$OutboxPaymentStatus_SentImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? data = null,
+    Object? payment = null,
   }) {
-    return _then(_$LnUrlWithdrawResult_TimeoutImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlWithdrawSuccessData,
+    return _then(_$OutboxPaymentStatus_SentImpl(
+      payment: null == payment
+          ? _value.payment
+          : payment // ignore: cast_nullable_to_non_nullable
+              as Payment,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LnUrlWithdrawResult_TimeoutImpl implements LnUrlWithdrawResult_Timeout {
-  const _$LnUrlWithdrawResult_TimeoutImpl({required this.data});
+class _$OutboxPaymentStatus_SentImpl implements OutboxPaymentStatus_Sent {
+  const _$OutboxPaymentStatus_SentImpl({required this.payment});
 
   @override
-  final LnUrlWithdrawSuccessData data;
+  final Payment payment;
 
   @override
   String toString() {
-    return 'LnUrlWithdrawResult.timeout(data: $data)';
+    return 'OutboxPaymentStatus.sent(payment: $payment)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LnUrlWithdrawResult_TimeoutImpl &&
-            (identical(other.data, data) || other.data == data));
+            other is _$OutboxPaymentStatus_SentImpl &&
+            (identical(other.payment, payment) || other.payment == payment));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
+  int get hashCode => Object.hash(runtimeType, payment);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LnUrlWithdrawResult_TimeoutImplCopyWith<_$LnUrlWithdrawResult_TimeoutImpl> get copyWith =>
-      __$$LnUrlWithdrawResult_TimeoutImplCopyWithImpl<_$LnUrlWithdrawResult_TimeoutImpl>(this, _$identity);
+  _$$OutboxPaymentStatus_SentImplCopyWith<_$OutboxPaymentStatus_SentImpl> get copyWith =>
+      __$$OutboxPaymentStatus_SentImplCopyWithImpl<_$OutboxPaymentStatus_SentImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawSuccessData data) ok,
-    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function() queued,
+    required TResult Function(Payment payment) sent,
+    required TResult Function() abandoned,
+    required TResult Function() cancelled,
   }) {
-    return timeout(data);
+    return sent(payment);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function()? queued,
+    TResult? Function(Payment payment)? sent,
+    TResult? Function()? abandoned,
+    TResult? Function()? cancelled,
   }) {
-    return timeout?.call(data);
+    return sent?.call(payment);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+    TResult Function()? queued,
+    TResult Function(Payment payment)? sent,
+    TResult Function()? abandoned,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) {
-    if (timeout != null) {
-      return timeout(data);
+    if (sent != null) {
+      return sent(payment);
     }
     return orElse();
   }
@@ -4715,141 +15974,232 @@ class _$LnUrlWithdrawResult_TimeoutImpl implements LnUrlWithdrawResult_Timeout {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
-    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
-    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+    required TResult Function(OutboxPaymentStatus_Queued value) queued,
+    required TResult Function(OutboxPaymentStatus_Sent value) sent,
+    required TResult Function(OutboxPaymentStatus_Abandoned value) abandoned,
+    required TResult Function(OutboxPaymentStatus_Cancelled value) cancelled,
   }) {
-    return timeout(this);
+    return sent(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult? Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult? Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult? Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult? Function(OutboxPaymentStatus_Cancelled value)? cancelled,
   }) {
-    return timeout?.call(this);
+    return sent?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult Function(OutboxPaymentStatus_Cancelled value)? cancelled,
     required TResult orElse(),
   }) {
-    if (timeout != null) {
-      return timeout(this);
+    if (sent != null) {
+      return sent(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlWithdrawResult_Timeout implements LnUrlWithdrawResult {
-  const factory LnUrlWithdrawResult_Timeout({required final LnUrlWithdrawSuccessData data}) =
-      _$LnUrlWithdrawResult_TimeoutImpl;
+abstract class OutboxPaymentStatus_Sent implements OutboxPaymentStatus {
+  const factory OutboxPaymentStatus_Sent({required final Payment payment}) = _$OutboxPaymentStatus_SentImpl;
 
-  @override
-  LnUrlWithdrawSuccessData get data;
+  Payment get payment;
   @JsonKey(ignore: true)
-  _$$LnUrlWithdrawResult_TimeoutImplCopyWith<_$LnUrlWithdrawResult_TimeoutImpl> get copyWith =>
+  _$$OutboxPaymentStatus_SentImplCopyWith<_$OutboxPaymentStatus_SentImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<$Res> {
-  factory _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith(_$LnUrlWithdrawResult_ErrorStatusImpl value,
-          $Res Function(_$LnUrlWithdrawResult_ErrorStatusImpl) then) =
-      __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl<$Res>;
-  @useResult
-  $Res call({LnUrlErrorData data});
+abstract class _$$OutboxPaymentStatus_AbandonedImplCopyWith<$Res> {
+  factory _$$OutboxPaymentStatus_AbandonedImplCopyWith(_$OutboxPaymentStatus_AbandonedImpl value,
+          $Res Function(_$OutboxPaymentStatus_AbandonedImpl) then) =
+      __$$OutboxPaymentStatus_AbandonedImplCopyWithImpl<$Res>;
 }
 
 /// @nodoc
-class __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl<$Res>
-    extends _$LnUrlWithdrawResultCopyWithImpl<$Res, _$LnUrlWithdrawResult_ErrorStatusImpl>
-    implements _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<$Res> {
-  __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl(_$LnUrlWithdrawResult_ErrorStatusImpl _value,
-      $Res Function(_$LnUrlWithdrawResult_ErrorStatusImpl) _then)
+class __$$OutboxPaymentStatus_AbandonedImplCopyWithImpl<$Res>
+    extends _$OutboxPaymentStatusCopyWithImpl<$Res, _$OutboxPaymentStatus_AbandonedImpl>
+    implements _$$OutboxPaymentStatus_AbandonedImplCopyWith<$Res> {
+  __$$OutboxPaymentStatus_AbandonedImplCopyWithImpl(
+      _$OutboxPaymentStatus_AbandonedImpl _value, $Res Function(_$OutboxPaymentStatus_AbandonedImpl) _then)
       : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$OutboxPaymentStatus_AbandonedImpl implements OutboxPaymentStatus_Abandoned {
+  const _$OutboxPaymentStatus_AbandonedImpl();
 
-  @pragma('vm:prefer-inline')
   @override
-  $Res call({
-    Object? data = null,
+  String toString() {
+    return 'OutboxPaymentStatus.abandoned()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$OutboxPaymentStatus_AbandonedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() queued,
+    required TResult Function(Payment payment) sent,
+    required TResult Function() abandoned,
+    required TResult Function() cancelled,
   }) {
-    return _then(_$LnUrlWithdrawResult_ErrorStatusImpl(
-      data: null == data
-          ? _value.data
-          : data // ignore: cast_nullable_to_non_nullable
-              as LnUrlErrorData,
-    ));
+    return abandoned();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? queued,
+    TResult? Function(Payment payment)? sent,
+    TResult? Function()? abandoned,
+    TResult? Function()? cancelled,
+  }) {
+    return abandoned?.call();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? queued,
+    TResult Function(Payment payment)? sent,
+    TResult Function()? abandoned,
+    TResult Function()? cancelled,
+    required TResult orElse(),
+  }) {
+    if (abandoned != null) {
+      return abandoned();
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(OutboxPaymentStatus_Queued value) queued,
+    required TResult Function(OutboxPaymentStatus_Sent value) sent,
+    required TResult Function(OutboxPaymentStatus_Abandoned value) abandoned,
+    required TResult Function(OutboxPaymentStatus_Cancelled value) cancelled,
+  }) {
+    return abandoned(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult? Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult? Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult? Function(OutboxPaymentStatus_Cancelled value)? cancelled,
+  }) {
+    return abandoned?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult Function(OutboxPaymentStatus_Cancelled value)? cancelled,
+    required TResult orElse(),
+  }) {
+    if (abandoned != null) {
+      return abandoned(this);
+    }
+    return orElse();
   }
 }
 
+abstract class OutboxPaymentStatus_Abandoned implements OutboxPaymentStatus {
+  const factory OutboxPaymentStatus_Abandoned() = _$OutboxPaymentStatus_AbandonedImpl;
+}
+
 /// @nodoc
+abstract class _$$OutboxPaymentStatus_CancelledImplCopyWith<$Res> {
+  factory _$$OutboxPaymentStatus_CancelledImplCopyWith(_$OutboxPaymentStatus_CancelledImpl value,
+          $Res Function(_$OutboxPaymentStatus_CancelledImpl) then) =
+      __$$OutboxPaymentStatus_CancelledImplCopyWithImpl<$Res>;
+}
 
-class _$LnUrlWithdrawResult_ErrorStatusImpl implements LnUrlWithdrawResult_ErrorStatus {
-  const _$LnUrlWithdrawResult_ErrorStatusImpl({required this.data});
+/// @nodoc
+class __$$OutboxPaymentStatus_CancelledImplCopyWithImpl<$Res>
+    extends _$OutboxPaymentStatusCopyWithImpl<$Res, _$OutboxPaymentStatus_CancelledImpl>
+    implements _$$OutboxPaymentStatus_CancelledImplCopyWith<$Res> {
+  __$$OutboxPaymentStatus_CancelledImplCopyWithImpl(
+      _$OutboxPaymentStatus_CancelledImpl _value, $Res Function(_$OutboxPaymentStatus_CancelledImpl) _then)
+      : super(_value, _then);
+}
 
-  @override
-  final LnUrlErrorData data;
+/// @nodoc
+
+class _$OutboxPaymentStatus_CancelledImpl implements OutboxPaymentStatus_Cancelled {
+  const _$OutboxPaymentStatus_CancelledImpl();
 
   @override
   String toString() {
-    return 'LnUrlWithdrawResult.errorStatus(data: $data)';
+    return 'OutboxPaymentStatus.cancelled()';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType &&
-            other is _$LnUrlWithdrawResult_ErrorStatusImpl &&
-            (identical(other.data, data) || other.data == data));
+        (other.runtimeType == runtimeType && other is _$OutboxPaymentStatus_CancelledImpl);
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, data);
-
-  @JsonKey(ignore: true)
-  @override
-  @pragma('vm:prefer-inline')
-  _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<_$LnUrlWithdrawResult_ErrorStatusImpl> get copyWith =>
-      __$$LnUrlWithdrawResult_ErrorStatusImplCopyWithImpl<_$LnUrlWithdrawResult_ErrorStatusImpl>(
-          this, _$identity);
+  int get hashCode => runtimeType.hashCode;
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawSuccessData data) ok,
-    required TResult Function(LnUrlWithdrawSuccessData data) timeout,
-    required TResult Function(LnUrlErrorData data) errorStatus,
+    required TResult Function() queued,
+    required TResult Function(Payment payment) sent,
+    required TResult Function() abandoned,
+    required TResult Function() cancelled,
   }) {
-    return errorStatus(data);
+    return cancelled();
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult? Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult? Function(LnUrlErrorData data)? errorStatus,
+    TResult? Function()? queued,
+    TResult? Function(Payment payment)? sent,
+    TResult? Function()? abandoned,
+    TResult? Function()? cancelled,
   }) {
-    return errorStatus?.call(data);
+    return cancelled?.call();
   }
 
   @override
-  @optionalTypeArgs
-  TResult maybeWhen<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawSuccessData data)? ok,
-    TResult Function(LnUrlWithdrawSuccessData data)? timeout,
-    TResult Function(LnUrlErrorData data)? errorStatus,
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? queued,
+    TResult Function(Payment payment)? sent,
+    TResult Function()? abandoned,
+    TResult Function()? cancelled,
     required TResult orElse(),
   }) {
-    if (errorStatus != null) {
-      return errorStatus(data);
+    if (cancelled != null) {
+      return cancelled();
     }
     return orElse();
   }
@@ -4857,206 +16207,193 @@ class _$LnUrlWithdrawResult_ErrorStatusImpl implements LnUrlWithdrawResult_Error
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(LnUrlWithdrawResult_Ok value) ok,
-    required TResult Function(LnUrlWithdrawResult_Timeout value) timeout,
-    required TResult Function(LnUrlWithdrawResult_ErrorStatus value) errorStatus,
+    required TResult Function(OutboxPaymentStatus_Queued value) queued,
+    required TResult Function(OutboxPaymentStatus_Sent value) sent,
+    required TResult Function(OutboxPaymentStatus_Abandoned value) abandoned,
+    required TResult Function(OutboxPaymentStatus_Cancelled value) cancelled,
   }) {
-    return errorStatus(this);
+    return cancelled(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult? Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult? Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult? Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult? Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult? Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult? Function(OutboxPaymentStatus_Cancelled value)? cancelled,
   }) {
-    return errorStatus?.call(this);
+    return cancelled?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(LnUrlWithdrawResult_Ok value)? ok,
-    TResult Function(LnUrlWithdrawResult_Timeout value)? timeout,
-    TResult Function(LnUrlWithdrawResult_ErrorStatus value)? errorStatus,
+    TResult Function(OutboxPaymentStatus_Queued value)? queued,
+    TResult Function(OutboxPaymentStatus_Sent value)? sent,
+    TResult Function(OutboxPaymentStatus_Abandoned value)? abandoned,
+    TResult Function(OutboxPaymentStatus_Cancelled value)? cancelled,
     required TResult orElse(),
   }) {
-    if (errorStatus != null) {
-      return errorStatus(this);
+    if (cancelled != null) {
+      return cancelled(this);
     }
     return orElse();
   }
 }
 
-abstract class LnUrlWithdrawResult_ErrorStatus implements LnUrlWithdrawResult {
-  const factory LnUrlWithdrawResult_ErrorStatus({required final LnUrlErrorData data}) =
-      _$LnUrlWithdrawResult_ErrorStatusImpl;
-
-  @override
-  LnUrlErrorData get data;
-  @JsonKey(ignore: true)
-  _$$LnUrlWithdrawResult_ErrorStatusImplCopyWith<_$LnUrlWithdrawResult_ErrorStatusImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+abstract class OutboxPaymentStatus_Cancelled implements OutboxPaymentStatus {
+  const factory OutboxPaymentStatus_Cancelled() = _$OutboxPaymentStatus_CancelledImpl;
 }
 
 /// @nodoc
-mixin _$NodeConfig {
-  GreenlightNodeConfig get config => throw _privateConstructorUsedError;
+mixin _$PartnerFeeDestination {
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String address) lightningAddress,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String address)? lightningAddress,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String address)? lightningAddress,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(PartnerFeeDestination_NodeId value) nodeId,
+    required TResult Function(PartnerFeeDestination_LightningAddress value) lightningAddress,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(PartnerFeeDestination_NodeId value)? nodeId,
+    TResult? Function(PartnerFeeDestination_LightningAddress value)? lightningAddress,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(PartnerFeeDestination_NodeId value)? nodeId,
+    TResult Function(PartnerFeeDestination_LightningAddress value)? lightningAddress,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
-
-  @JsonKey(ignore: true)
-  $NodeConfigCopyWith<NodeConfig> get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class $NodeConfigCopyWith<$Res> {
-  factory $NodeConfigCopyWith(NodeConfig value, $Res Function(NodeConfig) then) =
-      _$NodeConfigCopyWithImpl<$Res, NodeConfig>;
-  @useResult
-  $Res call({GreenlightNodeConfig config});
+abstract class $PartnerFeeDestinationCopyWith<$Res> {
+  factory $PartnerFeeDestinationCopyWith(
+          PartnerFeeDestination value, $Res Function(PartnerFeeDestination) then) =
+      _$PartnerFeeDestinationCopyWithImpl<$Res, PartnerFeeDestination>;
 }
 
 /// @nodoc
-class _$NodeConfigCopyWithImpl<$Res, $Val extends NodeConfig> implements $NodeConfigCopyWith<$Res> {
-  _$NodeConfigCopyWithImpl(this._value, this._then);
+class _$PartnerFeeDestinationCopyWithImpl<$Res, $Val extends PartnerFeeDestination>
+    implements $PartnerFeeDestinationCopyWith<$Res> {
+  _$PartnerFeeDestinationCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
   // ignore: unused_field
   final $Res Function($Val) _then;
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? config = null,
-  }) {
-    return _then(_value.copyWith(
-      config: null == config
-          ? _value.config
-          : config // ignore: cast_nullable_to_non_nullable
-              as GreenlightNodeConfig,
-    ) as $Val);
-  }
 }
 
 /// @nodoc
-abstract class _$$NodeConfig_GreenlightImplCopyWith<$Res> implements $NodeConfigCopyWith<$Res> {
-  factory _$$NodeConfig_GreenlightImplCopyWith(
-          _$NodeConfig_GreenlightImpl value, $Res Function(_$NodeConfig_GreenlightImpl) then) =
-      __$$NodeConfig_GreenlightImplCopyWithImpl<$Res>;
-  @override
+abstract class _$$PartnerFeeDestination_NodeIdImplCopyWith<$Res> {
+  factory _$$PartnerFeeDestination_NodeIdImplCopyWith(
+          _$PartnerFeeDestination_NodeIdImpl value, $Res Function(_$PartnerFeeDestination_NodeIdImpl) then) =
+      __$$PartnerFeeDestination_NodeIdImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({GreenlightNodeConfig config});
+  $Res call({String nodeId});
 }
 
 /// @nodoc
-class __$$NodeConfig_GreenlightImplCopyWithImpl<$Res>
-    extends _$NodeConfigCopyWithImpl<$Res, _$NodeConfig_GreenlightImpl>
-    implements _$$NodeConfig_GreenlightImplCopyWith<$Res> {
-  __$$NodeConfig_GreenlightImplCopyWithImpl(
-      _$NodeConfig_GreenlightImpl _value, $Res Function(_$NodeConfig_GreenlightImpl) _then)
+class __$$PartnerFeeDestination_NodeIdImplCopyWithImpl<$Res>
+    extends _$PartnerFeeDestinationCopyWithImpl<$Res, _$PartnerFeeDestination_NodeIdImpl>
+    implements _$$PartnerFeeDestination_NodeIdImplCopyWith<$Res> {
+  __$$PartnerFeeDestination_NodeIdImplCopyWithImpl(
+      _$PartnerFeeDestination_NodeIdImpl _value, $Res Function(_$PartnerFeeDestination_NodeIdImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? config = null,
+    Object? nodeId = null,
   }) {
-    return _then(_$NodeConfig_GreenlightImpl(
-      config: null == config
-          ? _value.config
-          : config // ignore: cast_nullable_to_non_nullable
-              as GreenlightNodeConfig,
+    return _then(_$PartnerFeeDestination_NodeIdImpl(
+      nodeId: null == nodeId
+          ? _value.nodeId
+          : nodeId // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$NodeConfig_GreenlightImpl implements NodeConfig_Greenlight {
-  const _$NodeConfig_GreenlightImpl({required this.config});
+class _$PartnerFeeDestination_NodeIdImpl implements PartnerFeeDestination_NodeId {
+  const _$PartnerFeeDestination_NodeIdImpl({required this.nodeId});
 
   @override
-  final GreenlightNodeConfig config;
+  final String nodeId;
 
   @override
   String toString() {
-    return 'NodeConfig.greenlight(config: $config)';
+    return 'PartnerFeeDestination.nodeId(nodeId: $nodeId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$NodeConfig_GreenlightImpl &&
-            (identical(other.config, config) || other.config == config));
+            other is _$PartnerFeeDestination_NodeIdImpl &&
+            (identical(other.nodeId, nodeId) || other.nodeId == nodeId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, config);
+  int get hashCode => Object.hash(runtimeType, nodeId);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$NodeConfig_GreenlightImplCopyWith<_$NodeConfig_GreenlightImpl> get copyWith =>
-      __$$NodeConfig_GreenlightImplCopyWithImpl<_$NodeConfig_GreenlightImpl>(this, _$identity);
+  _$$PartnerFeeDestination_NodeIdImplCopyWith<_$PartnerFeeDestination_NodeIdImpl> get copyWith =>
+      __$$PartnerFeeDestination_NodeIdImplCopyWithImpl<_$PartnerFeeDestination_NodeIdImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(GreenlightNodeConfig config) greenlight,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String address) lightningAddress,
   }) {
-    return greenlight(config);
+    return nodeId(this.nodeId);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(GreenlightNodeConfig config)? greenlight,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String address)? lightningAddress,
   }) {
-    return greenlight?.call(config);
+    return nodeId?.call(this.nodeId);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(GreenlightNodeConfig config)? greenlight,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String address)? lightningAddress,
     required TResult orElse(),
   }) {
-    if (greenlight != null) {
-      return greenlight(config);
+    if (nodeId != null) {
+      return nodeId(this.nodeId);
     }
     return orElse();
   }
@@ -5064,202 +16401,136 @@ class _$NodeConfig_GreenlightImpl implements NodeConfig_Greenlight {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NodeConfig_Greenlight value) greenlight,
+    required TResult Function(PartnerFeeDestination_NodeId value) nodeId,
+    required TResult Function(PartnerFeeDestination_LightningAddress value) lightningAddress,
   }) {
-    return greenlight(this);
+    return nodeId(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NodeConfig_Greenlight value)? greenlight,
+    TResult? Function(PartnerFeeDestination_NodeId value)? nodeId,
+    TResult? Function(PartnerFeeDestination_LightningAddress value)? lightningAddress,
   }) {
-    return greenlight?.call(this);
+    return nodeId?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NodeConfig_Greenlight value)? greenlight,
+    TResult Function(PartnerFeeDestination_NodeId value)? nodeId,
+    TResult Function(PartnerFeeDestination_LightningAddress value)? lightningAddress,
     required TResult orElse(),
   }) {
-    if (greenlight != null) {
-      return greenlight(this);
+    if (nodeId != null) {
+      return nodeId(this);
     }
     return orElse();
   }
 }
 
-abstract class NodeConfig_Greenlight implements NodeConfig {
-  const factory NodeConfig_Greenlight({required final GreenlightNodeConfig config}) =
-      _$NodeConfig_GreenlightImpl;
+abstract class PartnerFeeDestination_NodeId implements PartnerFeeDestination {
+  const factory PartnerFeeDestination_NodeId({required final String nodeId}) =
+      _$PartnerFeeDestination_NodeIdImpl;
 
-  @override
-  GreenlightNodeConfig get config;
-  @override
+  String get nodeId;
   @JsonKey(ignore: true)
-  _$$NodeConfig_GreenlightImplCopyWith<_$NodeConfig_GreenlightImpl> get copyWith =>
-      throw _privateConstructorUsedError;
-}
-
-/// @nodoc
-mixin _$NodeCredentials {
-  GreenlightDeviceCredentials get credentials => throw _privateConstructorUsedError;
-  @optionalTypeArgs
-  TResult when<TResult extends Object?>({
-    required TResult Function(GreenlightDeviceCredentials credentials) greenlight,
-  }) =>
-      throw _privateConstructorUsedError;
-  @optionalTypeArgs
-  TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(GreenlightDeviceCredentials credentials)? greenlight,
-  }) =>
-      throw _privateConstructorUsedError;
-  @optionalTypeArgs
-  TResult maybeWhen<TResult extends Object?>({
-    TResult Function(GreenlightDeviceCredentials credentials)? greenlight,
-    required TResult orElse(),
-  }) =>
-      throw _privateConstructorUsedError;
-  @optionalTypeArgs
-  TResult map<TResult extends Object?>({
-    required TResult Function(NodeCredentials_Greenlight value) greenlight,
-  }) =>
-      throw _privateConstructorUsedError;
-  @optionalTypeArgs
-  TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NodeCredentials_Greenlight value)? greenlight,
-  }) =>
-      throw _privateConstructorUsedError;
-  @optionalTypeArgs
-  TResult maybeMap<TResult extends Object?>({
-    TResult Function(NodeCredentials_Greenlight value)? greenlight,
-    required TResult orElse(),
-  }) =>
+  _$$PartnerFeeDestination_NodeIdImplCopyWith<_$PartnerFeeDestination_NodeIdImpl> get copyWith =>
       throw _privateConstructorUsedError;
-
-  @JsonKey(ignore: true)
-  $NodeCredentialsCopyWith<NodeCredentials> get copyWith => throw _privateConstructorUsedError;
-}
-
-/// @nodoc
-abstract class $NodeCredentialsCopyWith<$Res> {
-  factory $NodeCredentialsCopyWith(NodeCredentials value, $Res Function(NodeCredentials) then) =
-      _$NodeCredentialsCopyWithImpl<$Res, NodeCredentials>;
-  @useResult
-  $Res call({GreenlightDeviceCredentials credentials});
-}
-
-/// @nodoc
-class _$NodeCredentialsCopyWithImpl<$Res, $Val extends NodeCredentials>
-    implements $NodeCredentialsCopyWith<$Res> {
-  _$NodeCredentialsCopyWithImpl(this._value, this._then);
-
-  // ignore: unused_field
-  final $Val _value;
-  // ignore: unused_field
-  final $Res Function($Val) _then;
-
-  @pragma('vm:prefer-inline')
-  @override
-  $Res call({
-    Object? credentials = null,
-  }) {
-    return _then(_value.copyWith(
-      credentials: null == credentials
-          ? _value.credentials
-          : credentials // ignore: cast_nullable_to_non_nullable
-              as GreenlightDeviceCredentials,
-    ) as $Val);
-  }
 }
 
 /// @nodoc
-abstract class _$$NodeCredentials_GreenlightImplCopyWith<$Res> implements $NodeCredentialsCopyWith<$Res> {
-  factory _$$NodeCredentials_GreenlightImplCopyWith(
-          _$NodeCredentials_GreenlightImpl value, $Res Function(_$NodeCredentials_GreenlightImpl) then) =
-      __$$NodeCredentials_GreenlightImplCopyWithImpl<$Res>;
-  @override
+abstract class _$$PartnerFeeDestination_LightningAddressImplCopyWith<$Res> {
+  factory _$$PartnerFeeDestination_LightningAddressImplCopyWith(
+          _$PartnerFeeDestination_LightningAddressImpl value,
+          $Res Function(_$PartnerFeeDestination_LightningAddressImpl) then) =
+      __$$PartnerFeeDestination_LightningAddressImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({GreenlightDeviceCredentials credentials});
+  $Res call({String address});
 }
 
 /// @nodoc
-class __$$NodeCredentials_GreenlightImplCopyWithImpl<$Res>
-    extends _$NodeCredentialsCopyWithImpl<$Res, _$NodeCredentials_GreenlightImpl>
-    implements _$$NodeCredentials_GreenlightImplCopyWith<$Res> {
-  __$$NodeCredentials_GreenlightImplCopyWithImpl(
-      _$NodeCredentials_GreenlightImpl _value, $Res Function(_$NodeCredentials_GreenlightImpl) _then)
+class __$$PartnerFeeDestination_LightningAddressImplCopyWithImpl<$Res>
+    extends _$PartnerFeeDestinationCopyWithImpl<$Res, _$PartnerFeeDestination_LightningAddressImpl>
+    implements _$$PartnerFeeDestination_LightningAddressImplCopyWith<$Res> {
+  __$$PartnerFeeDestination_LightningAddressImplCopyWithImpl(
+      _$PartnerFeeDestination_LightningAddressImpl _value,
+      $Res Function(_$PartnerFeeDestination_LightningAddressImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? credentials = null,
+    Object? address = null,
   }) {
-    return _then(_$NodeCredentials_GreenlightImpl(
-      credentials: null == credentials
-          ? _value.credentials
-          : credentials // ignore: cast_nullable_to_non_nullable
-              as GreenlightDeviceCredentials,
+    return _then(_$PartnerFeeDestination_LightningAddressImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$NodeCredentials_GreenlightImpl implements NodeCredentials_Greenlight {
-  const _$NodeCredentials_GreenlightImpl({required this.credentials});
+class _$PartnerFeeDestination_LightningAddressImpl implements PartnerFeeDestination_LightningAddress {
+  const _$PartnerFeeDestination_LightningAddressImpl({required this.address});
 
   @override
-  final GreenlightDeviceCredentials credentials;
+  final String address;
 
   @override
   String toString() {
-    return 'NodeCredentials.greenlight(credentials: $credentials)';
+    return 'PartnerFeeDestination.lightningAddress(address: $address)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$NodeCredentials_GreenlightImpl &&
-            (identical(other.credentials, credentials) || other.credentials == credentials));
+            other is _$PartnerFeeDestination_LightningAddressImpl &&
+            (identical(other.address, address) || other.address == address));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, credentials);
+  int get hashCode => Object.hash(runtimeType, address);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$NodeCredentials_GreenlightImplCopyWith<_$NodeCredentials_GreenlightImpl> get copyWith =>
-      __$$NodeCredentials_GreenlightImplCopyWithImpl<_$NodeCredentials_GreenlightImpl>(this, _$identity);
+  _$$PartnerFeeDestination_LightningAddressImplCopyWith<_$PartnerFeeDestination_LightningAddressImpl>
+      get copyWith => __$$PartnerFeeDestination_LightningAddressImplCopyWithImpl<
+          _$PartnerFeeDestination_LightningAddressImpl>(this, _$identity);
 
   @override
   @optionalTypeArgs
   TResult when<TResult extends Object?>({
-    required TResult Function(GreenlightDeviceCredentials credentials) greenlight,
+    required TResult Function(String nodeId) nodeId,
+    required TResult Function(String address) lightningAddress,
   }) {
-    return greenlight(credentials);
+    return lightningAddress(address);
   }
 
   @override
   @optionalTypeArgs
   TResult? whenOrNull<TResult extends Object?>({
-    TResult? Function(GreenlightDeviceCredentials credentials)? greenlight,
+    TResult? Function(String nodeId)? nodeId,
+    TResult? Function(String address)? lightningAddress,
   }) {
-    return greenlight?.call(credentials);
+    return lightningAddress?.call(address);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeWhen<TResult extends Object?>({
-    TResult Function(GreenlightDeviceCredentials credentials)? greenlight,
+    TResult Function(String nodeId)? nodeId,
+    TResult Function(String address)? lightningAddress,
     required TResult orElse(),
   }) {
-    if (greenlight != null) {
-      return greenlight(credentials);
+    if (lightningAddress != null) {
+      return lightningAddress(address);
     }
     return orElse();
   }
@@ -5267,42 +16538,43 @@ class _$NodeCredentials_GreenlightImpl implements NodeCredentials_Greenlight {
   @override
   @optionalTypeArgs
   TResult map<TResult extends Object?>({
-    required TResult Function(NodeCredentials_Greenlight value) greenlight,
+    required TResult Function(PartnerFeeDestination_NodeId value) nodeId,
+    required TResult Function(PartnerFeeDestination_LightningAddress value) lightningAddress,
   }) {
-    return greenlight(this);
+    return lightningAddress(this);
   }
 
   @override
   @optionalTypeArgs
   TResult? mapOrNull<TResult extends Object?>({
-    TResult? Function(NodeCredentials_Greenlight value)? greenlight,
+    TResult? Function(PartnerFeeDestination_NodeId value)? nodeId,
+    TResult? Function(PartnerFeeDestination_LightningAddress value)? lightningAddress,
   }) {
-    return greenlight?.call(this);
+    return lightningAddress?.call(this);
   }
 
   @override
   @optionalTypeArgs
   TResult maybeMap<TResult extends Object?>({
-    TResult Function(NodeCredentials_Greenlight value)? greenlight,
+    TResult Function(PartnerFeeDestination_NodeId value)? nodeId,
+    TResult Function(PartnerFeeDestination_LightningAddress value)? lightningAddress,
     required TResult orElse(),
   }) {
-    if (greenlight != null) {
-      return greenlight(this);
+    if (lightningAddress != null) {
+      return lightningAddress(this);
     }
     return orElse();
   }
 }
 
-abstract class NodeCredentials_Greenlight implements NodeCredentials {
-  const factory NodeCredentials_Greenlight({required final GreenlightDeviceCredentials credentials}) =
-      _$NodeCredentials_GreenlightImpl;
+abstract class PartnerFeeDestination_LightningAddress implements PartnerFeeDestination {
+  const factory PartnerFeeDestination_LightningAddress({required final String address}) =
+      _$PartnerFeeDestination_LightningAddressImpl;
 
-  @override
-  GreenlightDeviceCredentials get credentials;
-  @override
+  String get address;
   @JsonKey(ignore: true)
-  _$$NodeCredentials_GreenlightImplCopyWith<_$NodeCredentials_GreenlightImpl> get copyWith =>
-      throw _privateConstructorUsedError;
+  _$$PartnerFeeDestination_LightningAddressImplCopyWith<_$PartnerFeeDestination_LightningAddressImpl>
+      get copyWith => throw _privateConstructorUsedError;
 }
 
 /// @nodoc
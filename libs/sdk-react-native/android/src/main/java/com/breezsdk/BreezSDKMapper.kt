@@ -3,6 +3,42 @@ import breez_sdk.*
 import com.facebook.react.bridge.*
 import java.util.*
 
+fun asAddFiatRateAlertRequest(addFiatRateAlertRequest: ReadableMap): AddFiatRateAlertRequest? {
+    if (!validateMandatoryFields(
+            addFiatRateAlertRequest,
+            arrayOf(
+                "currency",
+                "direction",
+                "threshold",
+            ),
+        )
+    ) {
+        return null
+    }
+    val currency = addFiatRateAlertRequest.getString("currency")!!
+    val direction = addFiatRateAlertRequest.getString("direction")?.let { asFiatRateAlertDirection(it) }!!
+    val threshold = addFiatRateAlertRequest.getDouble("threshold")
+    return AddFiatRateAlertRequest(currency, direction, threshold)
+}
+
+fun readableMapOf(addFiatRateAlertRequest: AddFiatRateAlertRequest): ReadableMap =
+    readableMapOf(
+        "currency" to addFiatRateAlertRequest.currency,
+        "direction" to addFiatRateAlertRequest.direction.name.lowercase(),
+        "threshold" to addFiatRateAlertRequest.threshold,
+    )
+
+fun asAddFiatRateAlertRequestList(arr: ReadableArray): List<AddFiatRateAlertRequest> {
+    val list = ArrayList<AddFiatRateAlertRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asAddFiatRateAlertRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asAesSuccessActionDataDecrypted(aesSuccessActionDataDecrypted: ReadableMap): AesSuccessActionDataDecrypted? {
     if (!validateMandatoryFields(
             aesSuccessActionDataDecrypted,
@@ -36,6 +72,79 @@ fun asAesSuccessActionDataDecryptedList(arr: ReadableArray): List<AesSuccessActi
     return list
 }
 
+fun asAmendInvoiceRequest(amendInvoiceRequest: ReadableMap): AmendInvoiceRequest? {
+    if (!validateMandatoryFields(
+            amendInvoiceRequest,
+            arrayOf(
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val bolt11 = amendInvoiceRequest.getString("bolt11")!!
+    val amountMsat = if (hasNonNullKey(amendInvoiceRequest, "amountMsat")) amendInvoiceRequest.getDouble("amountMsat").toULong() else null
+    val expiry = if (hasNonNullKey(amendInvoiceRequest, "expiry")) amendInvoiceRequest.getInt("expiry").toUInt() else null
+    val routeHints =
+        if (hasNonNullKey(amendInvoiceRequest, "routeHints")) {
+            amendInvoiceRequest.getArray("routeHints")?.let {
+                asRouteHintList(it)
+            }
+        } else {
+            null
+        }
+    return AmendInvoiceRequest(bolt11, amountMsat, expiry, routeHints)
+}
+
+fun readableMapOf(amendInvoiceRequest: AmendInvoiceRequest): ReadableMap =
+    readableMapOf(
+        "bolt11" to amendInvoiceRequest.bolt11,
+        "amountMsat" to amendInvoiceRequest.amountMsat,
+        "expiry" to amendInvoiceRequest.expiry,
+        "routeHints" to amendInvoiceRequest.routeHints?.let { readableArrayOf(it) },
+    )
+
+fun asAmendInvoiceRequestList(arr: ReadableArray): List<AmendInvoiceRequest> {
+    val list = ArrayList<AmendInvoiceRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asAmendInvoiceRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asAutoRefundConfig(autoRefundConfig: ReadableMap): AutoRefundConfig? {
+    if (!validateMandatoryFields(
+            autoRefundConfig,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val toAddress = if (hasNonNullKey(autoRefundConfig, "toAddress")) autoRefundConfig.getString("toAddress") else null
+    val satPerVbyte = if (hasNonNullKey(autoRefundConfig, "satPerVbyte")) autoRefundConfig.getInt("satPerVbyte").toUInt() else null
+    return AutoRefundConfig(toAddress, satPerVbyte)
+}
+
+fun readableMapOf(autoRefundConfig: AutoRefundConfig): ReadableMap =
+    readableMapOf(
+        "toAddress" to autoRefundConfig.toAddress,
+        "satPerVbyte" to autoRefundConfig.satPerVbyte,
+    )
+
+fun asAutoRefundConfigList(arr: ReadableArray): List<AutoRefundConfig> {
+    val list = ArrayList<AutoRefundConfig>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asAutoRefundConfig(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asBackupFailedData(backupFailedData: ReadableMap): BackupFailedData? {
     if (!validateMandatoryFields(
             backupFailedData,
@@ -98,6 +207,206 @@ fun asBackupStatusList(arr: ReadableArray): List<BackupStatus> {
     return list
 }
 
+fun asBalanceBreakdown(balanceBreakdown: ReadableMap): BalanceBreakdown? {
+    if (!validateMandatoryFields(
+            balanceBreakdown,
+            arrayOf(
+                "spendableMsat",
+                "channels",
+                "channelReservesMsat",
+                "htlcsOutMsat",
+                "htlcsInMsat",
+                "dustHtlcsMsat",
+                "pendingChannelOpensMsat",
+                "onchainConfirmedMsat",
+                "onchainUnconfirmedMsat",
+                "swapsLockedMsat",
+                "reverseSwapsLockedMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val spendableMsat = balanceBreakdown.getDouble("spendableMsat").toULong()
+    val channels = balanceBreakdown.getArray("channels")?.let { asChannelDetailsList(it) }!!
+    val channelReservesMsat = balanceBreakdown.getDouble("channelReservesMsat").toULong()
+    val htlcsOutMsat = balanceBreakdown.getDouble("htlcsOutMsat").toULong()
+    val htlcsInMsat = balanceBreakdown.getDouble("htlcsInMsat").toULong()
+    val dustHtlcsMsat = balanceBreakdown.getDouble("dustHtlcsMsat").toULong()
+    val pendingChannelOpensMsat = balanceBreakdown.getDouble("pendingChannelOpensMsat").toULong()
+    val onchainConfirmedMsat = balanceBreakdown.getDouble("onchainConfirmedMsat").toULong()
+    val onchainUnconfirmedMsat = balanceBreakdown.getDouble("onchainUnconfirmedMsat").toULong()
+    val swapsLockedMsat = balanceBreakdown.getDouble("swapsLockedMsat").toULong()
+    val reverseSwapsLockedMsat = balanceBreakdown.getDouble("reverseSwapsLockedMsat").toULong()
+    return BalanceBreakdown(
+        spendableMsat,
+        channels,
+        channelReservesMsat,
+        htlcsOutMsat,
+        htlcsInMsat,
+        dustHtlcsMsat,
+        pendingChannelOpensMsat,
+        onchainConfirmedMsat,
+        onchainUnconfirmedMsat,
+        swapsLockedMsat,
+        reverseSwapsLockedMsat,
+    )
+}
+
+fun readableMapOf(balanceBreakdown: BalanceBreakdown): ReadableMap =
+    readableMapOf(
+        "spendableMsat" to balanceBreakdown.spendableMsat,
+        "channels" to readableArrayOf(balanceBreakdown.channels),
+        "channelReservesMsat" to balanceBreakdown.channelReservesMsat,
+        "htlcsOutMsat" to balanceBreakdown.htlcsOutMsat,
+        "htlcsInMsat" to balanceBreakdown.htlcsInMsat,
+        "dustHtlcsMsat" to balanceBreakdown.dustHtlcsMsat,
+        "pendingChannelOpensMsat" to balanceBreakdown.pendingChannelOpensMsat,
+        "onchainConfirmedMsat" to balanceBreakdown.onchainConfirmedMsat,
+        "onchainUnconfirmedMsat" to balanceBreakdown.onchainUnconfirmedMsat,
+        "swapsLockedMsat" to balanceBreakdown.swapsLockedMsat,
+        "reverseSwapsLockedMsat" to balanceBreakdown.reverseSwapsLockedMsat,
+    )
+
+fun asBalanceBreakdownList(arr: ReadableArray): List<BalanceBreakdown> {
+    val list = ArrayList<BalanceBreakdown>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBalanceBreakdown(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asBalances(balances: ReadableMap): Balances? {
+    if (!validateMandatoryFields(
+            balances,
+            arrayOf(
+                "lightningSpendableMsat",
+                "lightningPendingMsat",
+                "onchainConfirmedMsat",
+                "onchainUnconfirmedMsat",
+                "swapsLockedMsat",
+                "reverseSwapsLockedMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val lightningSpendableMsat = balances.getDouble("lightningSpendableMsat").toULong()
+    val lightningPendingMsat = balances.getDouble("lightningPendingMsat").toULong()
+    val onchainConfirmedMsat = balances.getDouble("onchainConfirmedMsat").toULong()
+    val onchainUnconfirmedMsat = balances.getDouble("onchainUnconfirmedMsat").toULong()
+    val swapsLockedMsat = balances.getDouble("swapsLockedMsat").toULong()
+    val reverseSwapsLockedMsat = balances.getDouble("reverseSwapsLockedMsat").toULong()
+    return Balances(
+        lightningSpendableMsat,
+        lightningPendingMsat,
+        onchainConfirmedMsat,
+        onchainUnconfirmedMsat,
+        swapsLockedMsat,
+        reverseSwapsLockedMsat,
+    )
+}
+
+fun readableMapOf(balances: Balances): ReadableMap =
+    readableMapOf(
+        "lightningSpendableMsat" to balances.lightningSpendableMsat,
+        "lightningPendingMsat" to balances.lightningPendingMsat,
+        "onchainConfirmedMsat" to balances.onchainConfirmedMsat,
+        "onchainUnconfirmedMsat" to balances.onchainUnconfirmedMsat,
+        "swapsLockedMsat" to balances.swapsLockedMsat,
+        "reverseSwapsLockedMsat" to balances.reverseSwapsLockedMsat,
+    )
+
+fun asBalancesList(arr: ReadableArray): List<Balances> {
+    val list = ArrayList<Balances>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBalances(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asBatchPaymentProgressDetails(batchPaymentProgressDetails: ReadableMap): BatchPaymentProgressDetails? {
+    if (!validateMandatoryFields(
+            batchPaymentProgressDetails,
+            arrayOf(
+                "result",
+                "succeeded",
+                "failed",
+                "total",
+            ),
+        )
+    ) {
+        return null
+    }
+    val result = batchPaymentProgressDetails.getMap("result")?.let { asBatchPaymentResult(it) }!!
+    val succeeded = batchPaymentProgressDetails.getInt("succeeded").toUInt()
+    val failed = batchPaymentProgressDetails.getInt("failed").toUInt()
+    val total = batchPaymentProgressDetails.getInt("total").toUInt()
+    return BatchPaymentProgressDetails(result, succeeded, failed, total)
+}
+
+fun readableMapOf(batchPaymentProgressDetails: BatchPaymentProgressDetails): ReadableMap =
+    readableMapOf(
+        "result" to readableMapOf(batchPaymentProgressDetails.result),
+        "succeeded" to batchPaymentProgressDetails.succeeded,
+        "failed" to batchPaymentProgressDetails.failed,
+        "total" to batchPaymentProgressDetails.total,
+    )
+
+fun asBatchPaymentProgressDetailsList(arr: ReadableArray): List<BatchPaymentProgressDetails> {
+    val list = ArrayList<BatchPaymentProgressDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBatchPaymentProgressDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asBatchPaymentResult(batchPaymentResult: ReadableMap): BatchPaymentResult? {
+    if (!validateMandatoryFields(
+            batchPaymentResult,
+            arrayOf(
+                "index",
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val index = batchPaymentResult.getInt("index").toUInt()
+    val bolt11 = batchPaymentResult.getString("bolt11")!!
+    val payment = if (hasNonNullKey(batchPaymentResult, "payment")) batchPaymentResult.getMap("payment")?.let { asPayment(it) } else null
+    val error = if (hasNonNullKey(batchPaymentResult, "error")) batchPaymentResult.getString("error") else null
+    return BatchPaymentResult(index, bolt11, payment, error)
+}
+
+fun readableMapOf(batchPaymentResult: BatchPaymentResult): ReadableMap =
+    readableMapOf(
+        "index" to batchPaymentResult.index,
+        "bolt11" to batchPaymentResult.bolt11,
+        "payment" to batchPaymentResult.payment?.let { readableMapOf(it) },
+        "error" to batchPaymentResult.error,
+    )
+
+fun asBatchPaymentResultList(arr: ReadableArray): List<BatchPaymentResult> {
+    val list = ArrayList<BatchPaymentResult>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBatchPaymentResult(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asBitcoinAddressData(bitcoinAddressData: ReadableMap): BitcoinAddressData? {
     if (!validateMandatoryFields(
             bitcoinAddressData,
@@ -137,6 +446,108 @@ fun asBitcoinAddressDataList(arr: ReadableArray): List<BitcoinAddressData> {
     return list
 }
 
+fun asBumpFeeRequest(bumpFeeRequest: ReadableMap): BumpFeeRequest? {
+    if (!validateMandatoryFields(
+            bumpFeeRequest,
+            arrayOf(
+                "txid",
+                "satPerVbyte",
+            ),
+        )
+    ) {
+        return null
+    }
+    val txid = bumpFeeRequest.getString("txid")!!
+    val satPerVbyte = bumpFeeRequest.getInt("satPerVbyte").toUInt()
+    return BumpFeeRequest(txid, satPerVbyte)
+}
+
+fun readableMapOf(bumpFeeRequest: BumpFeeRequest): ReadableMap =
+    readableMapOf(
+        "txid" to bumpFeeRequest.txid,
+        "satPerVbyte" to bumpFeeRequest.satPerVbyte,
+    )
+
+fun asBumpFeeRequestList(arr: ReadableArray): List<BumpFeeRequest> {
+    val list = ArrayList<BumpFeeRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBumpFeeRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asBumpFeeResponse(bumpFeeResponse: ReadableMap): BumpFeeResponse? {
+    if (!validateMandatoryFields(
+            bumpFeeResponse,
+            arrayOf(
+                "txid",
+            ),
+        )
+    ) {
+        return null
+    }
+    val txid = bumpFeeResponse.getString("txid")!!
+    return BumpFeeResponse(txid)
+}
+
+fun readableMapOf(bumpFeeResponse: BumpFeeResponse): ReadableMap =
+    readableMapOf(
+        "txid" to bumpFeeResponse.txid,
+    )
+
+fun asBumpFeeResponseList(arr: ReadableArray): List<BumpFeeResponse> {
+    val list = ArrayList<BumpFeeResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBumpFeeResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asBuyBitcoinQuote(buyBitcoinQuote: ReadableMap): BuyBitcoinQuote? {
+    if (!validateMandatoryFields(
+            buyBitcoinQuote,
+            arrayOf(
+                "fiatCurrency",
+                "fiatAmount",
+                "feesFiat",
+                "amountSat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val fiatCurrency = buyBitcoinQuote.getString("fiatCurrency")!!
+    val fiatAmount = buyBitcoinQuote.getDouble("fiatAmount")
+    val feesFiat = buyBitcoinQuote.getDouble("feesFiat")
+    val amountSat = buyBitcoinQuote.getDouble("amountSat").toULong()
+    return BuyBitcoinQuote(fiatCurrency, fiatAmount, feesFiat, amountSat)
+}
+
+fun readableMapOf(buyBitcoinQuote: BuyBitcoinQuote): ReadableMap =
+    readableMapOf(
+        "fiatCurrency" to buyBitcoinQuote.fiatCurrency,
+        "fiatAmount" to buyBitcoinQuote.fiatAmount,
+        "feesFiat" to buyBitcoinQuote.feesFiat,
+        "amountSat" to buyBitcoinQuote.amountSat,
+    )
+
+fun asBuyBitcoinQuoteList(arr: ReadableArray): List<BuyBitcoinQuote> {
+    val list = ArrayList<BuyBitcoinQuote>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBuyBitcoinQuote(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asBuyBitcoinRequest(buyBitcoinRequest: ReadableMap): BuyBitcoinRequest? {
     if (!validateMandatoryFields(
             buyBitcoinRequest,
@@ -217,88 +628,351 @@ fun asBuyBitcoinResponseList(arr: ReadableArray): List<BuyBitcoinResponse> {
     return list
 }
 
-fun asCheckMessageRequest(checkMessageRequest: ReadableMap): CheckMessageRequest? {
+fun asChannelDetails(channelDetails: ReadableMap): ChannelDetails? {
     if (!validateMandatoryFields(
-            checkMessageRequest,
+            channelDetails,
             arrayOf(
-                "message",
-                "pubkey",
-                "signature",
+                "channelId",
+                "peerId",
+                "peerConnected",
+                "fundingTxid",
+                "state",
+                "capacityMsat",
+                "localBalanceMsat",
+                "remoteBalanceMsat",
+                "spendableMsat",
+                "receivableMsat",
+                "reserveMsat",
+                "htlcsOutMsat",
+                "htlcsInMsat",
+                "dustHtlcsMsat",
             ),
         )
     ) {
         return null
     }
-    val message = checkMessageRequest.getString("message")!!
-    val pubkey = checkMessageRequest.getString("pubkey")!!
-    val signature = checkMessageRequest.getString("signature")!!
-    return CheckMessageRequest(message, pubkey, signature)
+    val channelId = channelDetails.getString("channelId")!!
+    val peerId = channelDetails.getString("peerId")!!
+    val peerConnected = channelDetails.getBoolean("peerConnected")
+    val shortChannelId = if (hasNonNullKey(channelDetails, "shortChannelId")) channelDetails.getString("shortChannelId") else null
+    val fundingTxid = channelDetails.getString("fundingTxid")!!
+    val state = channelDetails.getString("state")?.let { asChannelState(it) }!!
+    val capacityMsat = channelDetails.getDouble("capacityMsat").toULong()
+    val localBalanceMsat = channelDetails.getDouble("localBalanceMsat").toULong()
+    val remoteBalanceMsat = channelDetails.getDouble("remoteBalanceMsat").toULong()
+    val spendableMsat = channelDetails.getDouble("spendableMsat").toULong()
+    val receivableMsat = channelDetails.getDouble("receivableMsat").toULong()
+    val reserveMsat = channelDetails.getDouble("reserveMsat").toULong()
+    val htlcsOutMsat = channelDetails.getDouble("htlcsOutMsat").toULong()
+    val htlcsInMsat = channelDetails.getDouble("htlcsInMsat").toULong()
+    val dustHtlcsMsat = channelDetails.getDouble("dustHtlcsMsat").toULong()
+    return ChannelDetails(
+        channelId,
+        peerId,
+        peerConnected,
+        shortChannelId,
+        fundingTxid,
+        state,
+        capacityMsat,
+        localBalanceMsat,
+        remoteBalanceMsat,
+        spendableMsat,
+        receivableMsat,
+        reserveMsat,
+        htlcsOutMsat,
+        htlcsInMsat,
+        dustHtlcsMsat,
+    )
 }
 
-fun readableMapOf(checkMessageRequest: CheckMessageRequest): ReadableMap =
+fun readableMapOf(channelDetails: ChannelDetails): ReadableMap =
     readableMapOf(
-        "message" to checkMessageRequest.message,
-        "pubkey" to checkMessageRequest.pubkey,
-        "signature" to checkMessageRequest.signature,
+        "channelId" to channelDetails.channelId,
+        "peerId" to channelDetails.peerId,
+        "peerConnected" to channelDetails.peerConnected,
+        "shortChannelId" to channelDetails.shortChannelId,
+        "fundingTxid" to channelDetails.fundingTxid,
+        "state" to channelDetails.state.name.lowercase(),
+        "capacityMsat" to channelDetails.capacityMsat,
+        "localBalanceMsat" to channelDetails.localBalanceMsat,
+        "remoteBalanceMsat" to channelDetails.remoteBalanceMsat,
+        "spendableMsat" to channelDetails.spendableMsat,
+        "receivableMsat" to channelDetails.receivableMsat,
+        "reserveMsat" to channelDetails.reserveMsat,
+        "htlcsOutMsat" to channelDetails.htlcsOutMsat,
+        "htlcsInMsat" to channelDetails.htlcsInMsat,
+        "dustHtlcsMsat" to channelDetails.dustHtlcsMsat,
     )
 
-fun asCheckMessageRequestList(arr: ReadableArray): List<CheckMessageRequest> {
-    val list = ArrayList<CheckMessageRequest>()
+fun asChannelDetailsList(arr: ReadableArray): List<ChannelDetails> {
+    val list = ArrayList<ChannelDetails>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asCheckMessageRequest(value)!!)
+            is ReadableMap -> list.add(asChannelDetails(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asCheckMessageResponse(checkMessageResponse: ReadableMap): CheckMessageResponse? {
+fun asChannelHygieneSuggestionDetails(channelHygieneSuggestionDetails: ReadableMap): ChannelHygieneSuggestionDetails? {
     if (!validateMandatoryFields(
-            checkMessageResponse,
+            channelHygieneSuggestionDetails,
             arrayOf(
-                "isValid",
+                "channelId",
+                "peerId",
+                "fundingTxid",
+                "localBalanceMsat",
+                "idleSecs",
+                "peerOfflineSecs",
+                "satPerVbyte",
+                "projectedSweepFeeSat",
+                "projectedSweepAmountSat",
             ),
         )
     ) {
         return null
     }
-    val isValid = checkMessageResponse.getBoolean("isValid")
-    return CheckMessageResponse(isValid)
+    val channelId = channelHygieneSuggestionDetails.getString("channelId")!!
+    val peerId = channelHygieneSuggestionDetails.getString("peerId")!!
+    val fundingTxid = channelHygieneSuggestionDetails.getString("fundingTxid")!!
+    val localBalanceMsat = channelHygieneSuggestionDetails.getDouble("localBalanceMsat").toULong()
+    val idleSecs = channelHygieneSuggestionDetails.getDouble("idleSecs").toULong()
+    val peerOfflineSecs = channelHygieneSuggestionDetails.getDouble("peerOfflineSecs").toULong()
+    val satPerVbyte = channelHygieneSuggestionDetails.getDouble("satPerVbyte").toULong()
+    val projectedSweepFeeSat = channelHygieneSuggestionDetails.getDouble("projectedSweepFeeSat").toULong()
+    val projectedSweepAmountSat = channelHygieneSuggestionDetails.getDouble("projectedSweepAmountSat").toULong()
+    return ChannelHygieneSuggestionDetails(
+        channelId,
+        peerId,
+        fundingTxid,
+        localBalanceMsat,
+        idleSecs,
+        peerOfflineSecs,
+        satPerVbyte,
+        projectedSweepFeeSat,
+        projectedSweepAmountSat,
+    )
 }
 
-fun readableMapOf(checkMessageResponse: CheckMessageResponse): ReadableMap =
+fun readableMapOf(channelHygieneSuggestionDetails: ChannelHygieneSuggestionDetails): ReadableMap =
     readableMapOf(
-        "isValid" to checkMessageResponse.isValid,
+        "channelId" to channelHygieneSuggestionDetails.channelId,
+        "peerId" to channelHygieneSuggestionDetails.peerId,
+        "fundingTxid" to channelHygieneSuggestionDetails.fundingTxid,
+        "localBalanceMsat" to channelHygieneSuggestionDetails.localBalanceMsat,
+        "idleSecs" to channelHygieneSuggestionDetails.idleSecs,
+        "peerOfflineSecs" to channelHygieneSuggestionDetails.peerOfflineSecs,
+        "satPerVbyte" to channelHygieneSuggestionDetails.satPerVbyte,
+        "projectedSweepFeeSat" to channelHygieneSuggestionDetails.projectedSweepFeeSat,
+        "projectedSweepAmountSat" to channelHygieneSuggestionDetails.projectedSweepAmountSat,
     )
 
-fun asCheckMessageResponseList(arr: ReadableArray): List<CheckMessageResponse> {
-    val list = ArrayList<CheckMessageResponse>()
+fun asChannelHygieneSuggestionDetailsList(arr: ReadableArray): List<ChannelHygieneSuggestionDetails> {
+    val list = ArrayList<ChannelHygieneSuggestionDetails>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asCheckMessageResponse(value)!!)
+            is ReadableMap -> list.add(asChannelHygieneSuggestionDetails(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asClosedChannelPaymentDetails(closedChannelPaymentDetails: ReadableMap): ClosedChannelPaymentDetails? {
+fun asChannelOpeningFee(channelOpeningFee: ReadableMap): ChannelOpeningFee? {
     if (!validateMandatoryFields(
-            closedChannelPaymentDetails,
+            channelOpeningFee,
             arrayOf(
-                "state",
-                "fundingTxid",
+                "paymentHash",
+                "paymentTime",
+                "payerAmountMsat",
+                "receivedAmountMsat",
+                "feeMsat",
             ),
         )
     ) {
         return null
     }
-    val state = closedChannelPaymentDetails.getString("state")?.let { asChannelState(it) }!!
-    val fundingTxid = closedChannelPaymentDetails.getString("fundingTxid")!!
-    val shortChannelId =
-        if (hasNonNullKey(
-                closedChannelPaymentDetails,
+    val paymentHash = channelOpeningFee.getString("paymentHash")!!
+    val paymentTime = channelOpeningFee.getDouble("paymentTime").toLong()
+    val payerAmountMsat = channelOpeningFee.getDouble("payerAmountMsat").toULong()
+    val receivedAmountMsat = channelOpeningFee.getDouble("receivedAmountMsat").toULong()
+    val feeMsat = channelOpeningFee.getDouble("feeMsat").toULong()
+    val openingFeeParams =
+        if (hasNonNullKey(channelOpeningFee, "openingFeeParams")) {
+            channelOpeningFee.getMap("openingFeeParams")?.let {
+                asOpeningFeeParams(it)
+            }
+        } else {
+            null
+        }
+    return ChannelOpeningFee(paymentHash, paymentTime, payerAmountMsat, receivedAmountMsat, feeMsat, openingFeeParams)
+}
+
+fun readableMapOf(channelOpeningFee: ChannelOpeningFee): ReadableMap =
+    readableMapOf(
+        "paymentHash" to channelOpeningFee.paymentHash,
+        "paymentTime" to channelOpeningFee.paymentTime,
+        "payerAmountMsat" to channelOpeningFee.payerAmountMsat,
+        "receivedAmountMsat" to channelOpeningFee.receivedAmountMsat,
+        "feeMsat" to channelOpeningFee.feeMsat,
+        "openingFeeParams" to channelOpeningFee.openingFeeParams?.let { readableMapOf(it) },
+    )
+
+fun asChannelOpeningFeeList(arr: ReadableArray): List<ChannelOpeningFee> {
+    val list = ArrayList<ChannelOpeningFee>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asChannelOpeningFee(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asCheckMessageRequest(checkMessageRequest: ReadableMap): CheckMessageRequest? {
+    if (!validateMandatoryFields(
+            checkMessageRequest,
+            arrayOf(
+                "message",
+                "pubkey",
+                "signature",
+            ),
+        )
+    ) {
+        return null
+    }
+    val message = checkMessageRequest.getString("message")!!
+    val pubkey = checkMessageRequest.getString("pubkey")!!
+    val signature = checkMessageRequest.getString("signature")!!
+    return CheckMessageRequest(message, pubkey, signature)
+}
+
+fun readableMapOf(checkMessageRequest: CheckMessageRequest): ReadableMap =
+    readableMapOf(
+        "message" to checkMessageRequest.message,
+        "pubkey" to checkMessageRequest.pubkey,
+        "signature" to checkMessageRequest.signature,
+    )
+
+fun asCheckMessageRequestList(arr: ReadableArray): List<CheckMessageRequest> {
+    val list = ArrayList<CheckMessageRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asCheckMessageRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asCheckMessageResponse(checkMessageResponse: ReadableMap): CheckMessageResponse? {
+    if (!validateMandatoryFields(
+            checkMessageResponse,
+            arrayOf(
+                "isValid",
+            ),
+        )
+    ) {
+        return null
+    }
+    val isValid = checkMessageResponse.getBoolean("isValid")
+    return CheckMessageResponse(isValid)
+}
+
+fun readableMapOf(checkMessageResponse: CheckMessageResponse): ReadableMap =
+    readableMapOf(
+        "isValid" to checkMessageResponse.isValid,
+    )
+
+fun asCheckMessageResponseList(arr: ReadableArray): List<CheckMessageResponse> {
+    val list = ArrayList<CheckMessageResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asCheckMessageResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asCloseChannelRequest(closeChannelRequest: ReadableMap): CloseChannelRequest? {
+    if (!validateMandatoryFields(
+            closeChannelRequest,
+            arrayOf(
+                "channelId",
+                "force",
+            ),
+        )
+    ) {
+        return null
+    }
+    val channelId = closeChannelRequest.getString("channelId")!!
+    val force = closeChannelRequest.getBoolean("force")
+    return CloseChannelRequest(channelId, force)
+}
+
+fun readableMapOf(closeChannelRequest: CloseChannelRequest): ReadableMap =
+    readableMapOf(
+        "channelId" to closeChannelRequest.channelId,
+        "force" to closeChannelRequest.force,
+    )
+
+fun asCloseChannelRequestList(arr: ReadableArray): List<CloseChannelRequest> {
+    val list = ArrayList<CloseChannelRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asCloseChannelRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asCloseChannelResponse(closeChannelResponse: ReadableMap): CloseChannelResponse? {
+    if (!validateMandatoryFields(
+            closeChannelResponse,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val closingTxid = if (hasNonNullKey(closeChannelResponse, "closingTxid")) closeChannelResponse.getString("closingTxid") else null
+    return CloseChannelResponse(closingTxid)
+}
+
+fun readableMapOf(closeChannelResponse: CloseChannelResponse): ReadableMap =
+    readableMapOf(
+        "closingTxid" to closeChannelResponse.closingTxid,
+    )
+
+fun asCloseChannelResponseList(arr: ReadableArray): List<CloseChannelResponse> {
+    val list = ArrayList<CloseChannelResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asCloseChannelResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asClosedChannelPaymentDetails(closedChannelPaymentDetails: ReadableMap): ClosedChannelPaymentDetails? {
+    if (!validateMandatoryFields(
+            closedChannelPaymentDetails,
+            arrayOf(
+                "state",
+                "fundingTxid",
+            ),
+        )
+    ) {
+        return null
+    }
+    val state = closedChannelPaymentDetails.getString("state")?.let { asChannelState(it) }!!
+    val fundingTxid = closedChannelPaymentDetails.getString("fundingTxid")!!
+    val shortChannelId =
+        if (hasNonNullKey(
+                closedChannelPaymentDetails,
                 "shortChannelId",
             )
         ) {
@@ -344,11 +1018,15 @@ fun asConfig(config: ReadableMap): Config? {
             arrayOf(
                 "breezserver",
                 "chainnotifierUrl",
+                "lnurlServerUrl",
                 "workingDir",
                 "network",
                 "paymentTimeoutSec",
+                "maxConcurrentPayments",
                 "maxfeePercent",
                 "exemptfeeMsat",
+                "useTrampoline",
+                "memoPrivacy",
                 "nodeConfig",
             ),
         )
@@ -357,26 +1035,129 @@ fun asConfig(config: ReadableMap): Config? {
     }
     val breezserver = config.getString("breezserver")!!
     val chainnotifierUrl = config.getString("chainnotifierUrl")!!
+    val lnurlServerUrl = config.getString("lnurlServerUrl")!!
     val mempoolspaceUrl = if (hasNonNullKey(config, "mempoolspaceUrl")) config.getString("mempoolspaceUrl") else null
+    val chainService = if (hasNonNullKey(config, "chainService")) config.getMap("chainService")?.let { asChainServiceConfig(it) } else null
+    val backupTransport =
+        if (hasNonNullKey(config, "backupTransport")) {
+            config.getMap("backupTransport")?.let {
+                asBackupTransportConfig(it)
+            }
+        } else {
+            null
+        }
+    val proxy = if (hasNonNullKey(config, "proxy")) config.getMap("proxy")?.let { asProxyConfig(it) } else null
     val workingDir = config.getString("workingDir")!!
+    val dbDir = if (hasNonNullKey(config, "dbDir")) config.getString("dbDir") else null
+    val credentialsDir = if (hasNonNullKey(config, "credentialsDir")) config.getString("credentialsDir") else null
     val network = config.getString("network")?.let { asNetwork(it) }!!
     val paymentTimeoutSec = config.getInt("paymentTimeoutSec").toUInt()
+    val maxConcurrentPayments = config.getInt("maxConcurrentPayments").toUInt()
     val defaultLspId = if (hasNonNullKey(config, "defaultLspId")) config.getString("defaultLspId") else null
+    val lspPolicy = if (hasNonNullKey(config, "lspPolicy")) config.getMap("lspPolicy")?.let { asLspPolicy(it) } else null
     val apiKey = if (hasNonNullKey(config, "apiKey")) config.getString("apiKey") else null
     val maxfeePercent = config.getDouble("maxfeePercent")
     val exemptfeeMsat = config.getDouble("exemptfeeMsat").toULong()
+    val fiatCurrency = if (hasNonNullKey(config, "fiatCurrency")) config.getString("fiatCurrency") else null
+    val lowOutboundLiquidityThresholdMsat =
+        if (hasNonNullKey(
+                config,
+                "lowOutboundLiquidityThresholdMsat",
+            )
+        ) {
+            config.getDouble("lowOutboundLiquidityThresholdMsat").toULong()
+        } else {
+            null
+        }
+    val lowInboundLiquidityThresholdMsat =
+        if (hasNonNullKey(
+                config,
+                "lowInboundLiquidityThresholdMsat",
+            )
+        ) {
+            config.getDouble("lowInboundLiquidityThresholdMsat").toULong()
+        } else {
+            null
+        }
+    val useTrampoline = config.getBoolean("useTrampoline")
+    val partnerFee = if (hasNonNullKey(config, "partnerFee")) config.getMap("partnerFee")?.let { asPartnerFeeConfig(it) } else null
+    val swapAutoRefund =
+        if (hasNonNullKey(
+                config,
+                "swapAutoRefund",
+            )
+        ) {
+            config.getMap("swapAutoRefund")?.let { asAutoRefundConfig(it) }
+        } else {
+            null
+        }
+    val memoPrivacy = config.getString("memoPrivacy")?.let { asMemoPrivacy(it) }!!
+    val channelHygieneWindowSecs =
+        if (hasNonNullKey(
+                config,
+                "channelHygieneWindowSecs",
+            )
+        ) {
+            config.getDouble("channelHygieneWindowSecs").toULong()
+        } else {
+            null
+        }
+    val logFilter = if (hasNonNullKey(config, "logFilter")) config.getString("logFilter") else null
+    val denylistUrl = if (hasNonNullKey(config, "denylistUrl")) config.getString("denylistUrl") else null
+    val transakApiKey = if (hasNonNullKey(config, "transakApiKey")) config.getString("transakApiKey") else null
+    val spendPolicy = if (hasNonNullKey(config, "spendPolicy")) config.getMap("spendPolicy")?.let { asSpendPolicy(it) } else null
+    val htlcExposureLimits =
+        if (hasNonNullKey(config, "htlcExposureLimits")) {
+            config.getMap("htlcExposureLimits")?.let {
+                asHtlcExposureLimits(it)
+            }
+        } else {
+            null
+        }
+    val serviceStatusCheckIntervalSecs =
+        if (hasNonNullKey(
+                config,
+                "serviceStatusCheckIntervalSecs",
+            )
+        ) {
+            config.getDouble("serviceStatusCheckIntervalSecs").toULong()
+        } else {
+            null
+        }
     val nodeConfig = config.getMap("nodeConfig")?.let { asNodeConfig(it) }!!
     return Config(
         breezserver,
         chainnotifierUrl,
+        lnurlServerUrl,
         mempoolspaceUrl,
+        chainService,
+        backupTransport,
+        proxy,
         workingDir,
+        dbDir,
+        credentialsDir,
         network,
         paymentTimeoutSec,
+        maxConcurrentPayments,
         defaultLspId,
+        lspPolicy,
         apiKey,
         maxfeePercent,
         exemptfeeMsat,
+        fiatCurrency,
+        lowOutboundLiquidityThresholdMsat,
+        lowInboundLiquidityThresholdMsat,
+        useTrampoline,
+        partnerFee,
+        swapAutoRefund,
+        memoPrivacy,
+        channelHygieneWindowSecs,
+        logFilter,
+        denylistUrl,
+        transakApiKey,
+        spendPolicy,
+        htlcExposureLimits,
+        serviceStatusCheckIntervalSecs,
         nodeConfig,
     )
 }
@@ -385,14 +1166,36 @@ fun readableMapOf(config: Config): ReadableMap =
     readableMapOf(
         "breezserver" to config.breezserver,
         "chainnotifierUrl" to config.chainnotifierUrl,
+        "lnurlServerUrl" to config.lnurlServerUrl,
         "mempoolspaceUrl" to config.mempoolspaceUrl,
+        "chainService" to config.chainService?.let { readableMapOf(it) },
+        "backupTransport" to config.backupTransport?.let { readableMapOf(it) },
+        "proxy" to config.proxy?.let { readableMapOf(it) },
         "workingDir" to config.workingDir,
+        "dbDir" to config.dbDir,
+        "credentialsDir" to config.credentialsDir,
         "network" to config.network.name.lowercase(),
         "paymentTimeoutSec" to config.paymentTimeoutSec,
+        "maxConcurrentPayments" to config.maxConcurrentPayments,
         "defaultLspId" to config.defaultLspId,
+        "lspPolicy" to config.lspPolicy?.let { readableMapOf(it) },
         "apiKey" to config.apiKey,
         "maxfeePercent" to config.maxfeePercent,
         "exemptfeeMsat" to config.exemptfeeMsat,
+        "fiatCurrency" to config.fiatCurrency,
+        "lowOutboundLiquidityThresholdMsat" to config.lowOutboundLiquidityThresholdMsat,
+        "lowInboundLiquidityThresholdMsat" to config.lowInboundLiquidityThresholdMsat,
+        "useTrampoline" to config.useTrampoline,
+        "partnerFee" to config.partnerFee?.let { readableMapOf(it) },
+        "swapAutoRefund" to config.swapAutoRefund?.let { readableMapOf(it) },
+        "memoPrivacy" to config.memoPrivacy.name.lowercase(),
+        "channelHygieneWindowSecs" to config.channelHygieneWindowSecs,
+        "logFilter" to config.logFilter,
+        "denylistUrl" to config.denylistUrl,
+        "transakApiKey" to config.transakApiKey,
+        "spendPolicy" to config.spendPolicy?.let { readableMapOf(it) },
+        "htlcExposureLimits" to config.htlcExposureLimits?.let { readableMapOf(it) },
+        "serviceStatusCheckIntervalSecs" to config.serviceStatusCheckIntervalSecs,
         "nodeConfig" to readableMapOf(config.nodeConfig),
     )
 
@@ -444,1822 +1247,4553 @@ fun asConfigureNodeRequestList(arr: ReadableArray): List<ConfigureNodeRequest> {
     return list
 }
 
-fun asConnectRequest(connectRequest: ReadableMap): ConnectRequest? {
+fun asConnectPeerRequest(connectPeerRequest: ReadableMap): ConnectPeerRequest? {
     if (!validateMandatoryFields(
-            connectRequest,
+            connectPeerRequest,
             arrayOf(
-                "config",
-                "seed",
+                "nodeUri",
             ),
         )
     ) {
         return null
     }
-    val config = connectRequest.getMap("config")?.let { asConfig(it) }!!
-    val seed = connectRequest.getArray("seed")?.let { asUByteList(it) }!!
-    val restoreOnly = if (hasNonNullKey(connectRequest, "restoreOnly")) connectRequest.getBoolean("restoreOnly") else null
-    return ConnectRequest(config, seed, restoreOnly)
+    val nodeUri = connectPeerRequest.getString("nodeUri")!!
+    return ConnectPeerRequest(nodeUri)
 }
 
-fun readableMapOf(connectRequest: ConnectRequest): ReadableMap =
+fun readableMapOf(connectPeerRequest: ConnectPeerRequest): ReadableMap =
     readableMapOf(
-        "config" to readableMapOf(connectRequest.config),
-        "seed" to readableArrayOf(connectRequest.seed),
-        "restoreOnly" to connectRequest.restoreOnly,
+        "nodeUri" to connectPeerRequest.nodeUri,
     )
 
-fun asConnectRequestList(arr: ReadableArray): List<ConnectRequest> {
-    val list = ArrayList<ConnectRequest>()
+fun asConnectPeerRequestList(arr: ReadableArray): List<ConnectPeerRequest> {
+    val list = ArrayList<ConnectPeerRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asConnectRequest(value)!!)
+            is ReadableMap -> list.add(asConnectPeerRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asCurrencyInfo(currencyInfo: ReadableMap): CurrencyInfo? {
+fun asConnectProgressDetails(connectProgressDetails: ReadableMap): ConnectProgressDetails? {
     if (!validateMandatoryFields(
-            currencyInfo,
+            connectProgressDetails,
             arrayOf(
-                "name",
-                "fractionSize",
-                "localizedName",
-                "localeOverrides",
+                "stage",
+                "progressPercent",
             ),
         )
     ) {
         return null
     }
-    val name = currencyInfo.getString("name")!!
-    val fractionSize = currencyInfo.getInt("fractionSize").toUInt()
-    val spacing = if (hasNonNullKey(currencyInfo, "spacing")) currencyInfo.getInt("spacing").toUInt() else null
-    val symbol = if (hasNonNullKey(currencyInfo, "symbol")) currencyInfo.getMap("symbol")?.let { asSymbol(it) } else null
-    val uniqSymbol = if (hasNonNullKey(currencyInfo, "uniqSymbol")) currencyInfo.getMap("uniqSymbol")?.let { asSymbol(it) } else null
-    val localizedName = currencyInfo.getArray("localizedName")?.let { asLocalizedNameList(it) }!!
-    val localeOverrides = currencyInfo.getArray("localeOverrides")?.let { asLocaleOverridesList(it) }!!
-    return CurrencyInfo(name, fractionSize, spacing, symbol, uniqSymbol, localizedName, localeOverrides)
+    val stage = connectProgressDetails.getString("stage")?.let { asConnectStage(it) }!!
+    val progressPercent = connectProgressDetails.getInt("progressPercent").toUByte()
+    val isNewNode = if (hasNonNullKey(connectProgressDetails, "isNewNode")) connectProgressDetails.getBoolean("isNewNode") else null
+    return ConnectProgressDetails(stage, progressPercent, isNewNode)
 }
 
-fun readableMapOf(currencyInfo: CurrencyInfo): ReadableMap =
+fun readableMapOf(connectProgressDetails: ConnectProgressDetails): ReadableMap =
     readableMapOf(
-        "name" to currencyInfo.name,
-        "fractionSize" to currencyInfo.fractionSize,
-        "spacing" to currencyInfo.spacing,
-        "symbol" to currencyInfo.symbol?.let { readableMapOf(it) },
-        "uniqSymbol" to currencyInfo.uniqSymbol?.let { readableMapOf(it) },
-        "localizedName" to readableArrayOf(currencyInfo.localizedName),
-        "localeOverrides" to readableArrayOf(currencyInfo.localeOverrides),
+        "stage" to connectProgressDetails.stage.name.lowercase(),
+        "progressPercent" to connectProgressDetails.progressPercent,
+        "isNewNode" to connectProgressDetails.isNewNode,
     )
 
-fun asCurrencyInfoList(arr: ReadableArray): List<CurrencyInfo> {
-    val list = ArrayList<CurrencyInfo>()
+fun asConnectProgressDetailsList(arr: ReadableArray): List<ConnectProgressDetails> {
+    val list = ArrayList<ConnectProgressDetails>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asCurrencyInfo(value)!!)
+            is ReadableMap -> list.add(asConnectProgressDetails(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asFiatCurrency(fiatCurrency: ReadableMap): FiatCurrency? {
+fun asConnectRequest(connectRequest: ReadableMap): ConnectRequest? {
     if (!validateMandatoryFields(
-            fiatCurrency,
+            connectRequest,
             arrayOf(
-                "id",
-                "info",
+                "config",
+                "seed",
             ),
         )
     ) {
         return null
     }
-    val id = fiatCurrency.getString("id")!!
-    val info = fiatCurrency.getMap("info")?.let { asCurrencyInfo(it) }!!
-    return FiatCurrency(id, info)
+    val config = connectRequest.getMap("config")?.let { asConfig(it) }!!
+    val seed = connectRequest.getArray("seed")?.let { asUByteList(it) }!!
+    val restoreOnly = if (hasNonNullKey(connectRequest, "restoreOnly")) connectRequest.getBoolean("restoreOnly") else null
+    val registerIfMissing = if (hasNonNullKey(connectRequest, "registerIfMissing")) connectRequest.getBoolean("registerIfMissing") else null
+    return ConnectRequest(config, seed, restoreOnly, registerIfMissing)
 }
 
-fun readableMapOf(fiatCurrency: FiatCurrency): ReadableMap =
+fun readableMapOf(connectRequest: ConnectRequest): ReadableMap =
     readableMapOf(
-        "id" to fiatCurrency.id,
-        "info" to readableMapOf(fiatCurrency.info),
+        "config" to readableMapOf(connectRequest.config),
+        "seed" to readableArrayOf(connectRequest.seed),
+        "restoreOnly" to connectRequest.restoreOnly,
+        "registerIfMissing" to connectRequest.registerIfMissing,
     )
 
-fun asFiatCurrencyList(arr: ReadableArray): List<FiatCurrency> {
-    val list = ArrayList<FiatCurrency>()
+fun asConnectRequestList(arr: ReadableArray): List<ConnectRequest> {
+    val list = ArrayList<ConnectRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asFiatCurrency(value)!!)
+            is ReadableMap -> list.add(asConnectRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asGreenlightCredentials(greenlightCredentials: ReadableMap): GreenlightCredentials? {
+fun asConnectionStatus(connectionStatus: ReadableMap): ConnectionStatus? {
     if (!validateMandatoryFields(
-            greenlightCredentials,
+            connectionStatus,
             arrayOf(
-                "developerKey",
-                "developerCert",
+                "state",
+                "since",
+                "reconnectAttempts",
             ),
         )
     ) {
         return null
     }
-    val developerKey = greenlightCredentials.getArray("developerKey")?.let { asUByteList(it) }!!
-    val developerCert = greenlightCredentials.getArray("developerCert")?.let { asUByteList(it) }!!
-    return GreenlightCredentials(developerKey, developerCert)
+    val state = connectionStatus.getString("state")?.let { asConnectionState(it) }!!
+    val since = connectionStatus.getDouble("since").toLong()
+    val reconnectAttempts = connectionStatus.getInt("reconnectAttempts").toUInt()
+    val lastError = if (hasNonNullKey(connectionStatus, "lastError")) connectionStatus.getString("lastError") else null
+    return ConnectionStatus(state, since, reconnectAttempts, lastError)
 }
 
-fun readableMapOf(greenlightCredentials: GreenlightCredentials): ReadableMap =
+fun readableMapOf(connectionStatus: ConnectionStatus): ReadableMap =
     readableMapOf(
-        "developerKey" to readableArrayOf(greenlightCredentials.developerKey),
-        "developerCert" to readableArrayOf(greenlightCredentials.developerCert),
+        "state" to connectionStatus.state.name.lowercase(),
+        "since" to connectionStatus.since,
+        "reconnectAttempts" to connectionStatus.reconnectAttempts,
+        "lastError" to connectionStatus.lastError,
     )
 
-fun asGreenlightCredentialsList(arr: ReadableArray): List<GreenlightCredentials> {
-    val list = ArrayList<GreenlightCredentials>()
+fun asConnectionStatusList(arr: ReadableArray): List<ConnectionStatus> {
+    val list = ArrayList<ConnectionStatus>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asGreenlightCredentials(value)!!)
+            is ReadableMap -> list.add(asConnectionStatus(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asGreenlightDeviceCredentials(greenlightDeviceCredentials: ReadableMap): GreenlightDeviceCredentials? {
+fun asCreateLnurlWithdrawVoucherRequest(createLnurlWithdrawVoucherRequest: ReadableMap): CreateLnurlWithdrawVoucherRequest? {
     if (!validateMandatoryFields(
-            greenlightDeviceCredentials,
+            createLnurlWithdrawVoucherRequest,
             arrayOf(
-                "device",
+                "amountMsat",
+                "uses",
+                "expirySecs",
             ),
         )
     ) {
         return null
     }
-    val device = greenlightDeviceCredentials.getArray("device")?.let { asUByteList(it) }!!
-    return GreenlightDeviceCredentials(device)
+    val amountMsat = createLnurlWithdrawVoucherRequest.getDouble("amountMsat").toULong()
+    val uses = createLnurlWithdrawVoucherRequest.getInt("uses").toUInt()
+    val expirySecs = createLnurlWithdrawVoucherRequest.getInt("expirySecs").toUInt()
+    val description =
+        if (hasNonNullKey(
+                createLnurlWithdrawVoucherRequest,
+                "description",
+            )
+        ) {
+            createLnurlWithdrawVoucherRequest.getString("description")
+        } else {
+            null
+        }
+    return CreateLnurlWithdrawVoucherRequest(amountMsat, uses, expirySecs, description)
 }
 
-fun readableMapOf(greenlightDeviceCredentials: GreenlightDeviceCredentials): ReadableMap =
+fun readableMapOf(createLnurlWithdrawVoucherRequest: CreateLnurlWithdrawVoucherRequest): ReadableMap =
     readableMapOf(
-        "device" to readableArrayOf(greenlightDeviceCredentials.device),
+        "amountMsat" to createLnurlWithdrawVoucherRequest.amountMsat,
+        "uses" to createLnurlWithdrawVoucherRequest.uses,
+        "expirySecs" to createLnurlWithdrawVoucherRequest.expirySecs,
+        "description" to createLnurlWithdrawVoucherRequest.description,
     )
 
-fun asGreenlightDeviceCredentialsList(arr: ReadableArray): List<GreenlightDeviceCredentials> {
-    val list = ArrayList<GreenlightDeviceCredentials>()
+fun asCreateLnurlWithdrawVoucherRequestList(arr: ReadableArray): List<CreateLnurlWithdrawVoucherRequest> {
+    val list = ArrayList<CreateLnurlWithdrawVoucherRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asGreenlightDeviceCredentials(value)!!)
+            is ReadableMap -> list.add(asCreateLnurlWithdrawVoucherRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asGreenlightNodeConfig(greenlightNodeConfig: ReadableMap): GreenlightNodeConfig? {
+fun asCreateStaticLnurlPayRequest(createStaticLnurlPayRequest: ReadableMap): CreateStaticLnurlPayRequest? {
     if (!validateMandatoryFields(
-            greenlightNodeConfig,
-            arrayOf(),
+            createStaticLnurlPayRequest,
+            arrayOf(
+                "url",
+                "callbackUrl",
+                "description",
+                "minSendableMsat",
+                "maxSendableMsat",
+            ),
         )
     ) {
         return null
     }
-    val partnerCredentials =
+    val url = createStaticLnurlPayRequest.getString("url")!!
+    val callbackUrl = createStaticLnurlPayRequest.getString("callbackUrl")!!
+    val description = createStaticLnurlPayRequest.getString("description")!!
+    val minSendableMsat = createStaticLnurlPayRequest.getDouble("minSendableMsat").toULong()
+    val maxSendableMsat = createStaticLnurlPayRequest.getDouble("maxSendableMsat").toULong()
+    val commentAllowed =
         if (hasNonNullKey(
-                greenlightNodeConfig,
-                "partnerCredentials",
+                createStaticLnurlPayRequest,
+                "commentAllowed",
             )
         ) {
-            greenlightNodeConfig.getMap("partnerCredentials")?.let {
-                asGreenlightCredentials(it)
-            }
+            createStaticLnurlPayRequest.getInt("commentAllowed").toUShort()
         } else {
             null
         }
-    val inviteCode = if (hasNonNullKey(greenlightNodeConfig, "inviteCode")) greenlightNodeConfig.getString("inviteCode") else null
-    return GreenlightNodeConfig(partnerCredentials, inviteCode)
+    return CreateStaticLnurlPayRequest(url, callbackUrl, description, minSendableMsat, maxSendableMsat, commentAllowed)
 }
 
-fun readableMapOf(greenlightNodeConfig: GreenlightNodeConfig): ReadableMap =
+fun readableMapOf(createStaticLnurlPayRequest: CreateStaticLnurlPayRequest): ReadableMap =
     readableMapOf(
-        "partnerCredentials" to greenlightNodeConfig.partnerCredentials?.let { readableMapOf(it) },
-        "inviteCode" to greenlightNodeConfig.inviteCode,
+        "url" to createStaticLnurlPayRequest.url,
+        "callbackUrl" to createStaticLnurlPayRequest.callbackUrl,
+        "description" to createStaticLnurlPayRequest.description,
+        "minSendableMsat" to createStaticLnurlPayRequest.minSendableMsat,
+        "maxSendableMsat" to createStaticLnurlPayRequest.maxSendableMsat,
+        "commentAllowed" to createStaticLnurlPayRequest.commentAllowed,
     )
 
-fun asGreenlightNodeConfigList(arr: ReadableArray): List<GreenlightNodeConfig> {
-    val list = ArrayList<GreenlightNodeConfig>()
+fun asCreateStaticLnurlPayRequestList(arr: ReadableArray): List<CreateStaticLnurlPayRequest> {
+    val list = ArrayList<CreateStaticLnurlPayRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asGreenlightNodeConfig(value)!!)
+            is ReadableMap -> list.add(asCreateStaticLnurlPayRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asInvoicePaidDetails(invoicePaidDetails: ReadableMap): InvoicePaidDetails? {
+fun asCurrencyInfo(currencyInfo: ReadableMap): CurrencyInfo? {
     if (!validateMandatoryFields(
-            invoicePaidDetails,
+            currencyInfo,
             arrayOf(
-                "paymentHash",
-                "bolt11",
+                "name",
+                "fractionSize",
+                "localizedName",
+                "localeOverrides",
             ),
         )
     ) {
         return null
     }
-    val paymentHash = invoicePaidDetails.getString("paymentHash")!!
-    val bolt11 = invoicePaidDetails.getString("bolt11")!!
-    val payment = if (hasNonNullKey(invoicePaidDetails, "payment")) invoicePaidDetails.getMap("payment")?.let { asPayment(it) } else null
-    return InvoicePaidDetails(paymentHash, bolt11, payment)
+    val name = currencyInfo.getString("name")!!
+    val fractionSize = currencyInfo.getInt("fractionSize").toUInt()
+    val spacing = if (hasNonNullKey(currencyInfo, "spacing")) currencyInfo.getInt("spacing").toUInt() else null
+    val symbol = if (hasNonNullKey(currencyInfo, "symbol")) currencyInfo.getMap("symbol")?.let { asSymbol(it) } else null
+    val uniqSymbol = if (hasNonNullKey(currencyInfo, "uniqSymbol")) currencyInfo.getMap("uniqSymbol")?.let { asSymbol(it) } else null
+    val localizedName = currencyInfo.getArray("localizedName")?.let { asLocalizedNameList(it) }!!
+    val localeOverrides = currencyInfo.getArray("localeOverrides")?.let { asLocaleOverridesList(it) }!!
+    return CurrencyInfo(name, fractionSize, spacing, symbol, uniqSymbol, localizedName, localeOverrides)
 }
 
-fun readableMapOf(invoicePaidDetails: InvoicePaidDetails): ReadableMap =
+fun readableMapOf(currencyInfo: CurrencyInfo): ReadableMap =
     readableMapOf(
-        "paymentHash" to invoicePaidDetails.paymentHash,
-        "bolt11" to invoicePaidDetails.bolt11,
-        "payment" to invoicePaidDetails.payment?.let { readableMapOf(it) },
+        "name" to currencyInfo.name,
+        "fractionSize" to currencyInfo.fractionSize,
+        "spacing" to currencyInfo.spacing,
+        "symbol" to currencyInfo.symbol?.let { readableMapOf(it) },
+        "uniqSymbol" to currencyInfo.uniqSymbol?.let { readableMapOf(it) },
+        "localizedName" to readableArrayOf(currencyInfo.localizedName),
+        "localeOverrides" to readableArrayOf(currencyInfo.localeOverrides),
     )
 
-fun asInvoicePaidDetailsList(arr: ReadableArray): List<InvoicePaidDetails> {
-    val list = ArrayList<InvoicePaidDetails>()
+fun asCurrencyInfoList(arr: ReadableArray): List<CurrencyInfo> {
+    val list = ArrayList<CurrencyInfo>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asInvoicePaidDetails(value)!!)
+            is ReadableMap -> list.add(asCurrencyInfo(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnInvoice(lnInvoice: ReadableMap): LnInvoice? {
+fun asDecryptRecoveryBundleRequest(decryptRecoveryBundleRequest: ReadableMap): DecryptRecoveryBundleRequest? {
     if (!validateMandatoryFields(
-            lnInvoice,
+            decryptRecoveryBundleRequest,
             arrayOf(
-                "bolt11",
-                "network",
-                "payeePubkey",
-                "paymentHash",
-                "timestamp",
-                "expiry",
-                "routingHints",
-                "paymentSecret",
-                "minFinalCltvExpiryDelta",
+                "bundle",
+                "privateKey",
             ),
         )
     ) {
         return null
     }
-    val bolt11 = lnInvoice.getString("bolt11")!!
-    val network = lnInvoice.getString("network")?.let { asNetwork(it) }!!
-    val payeePubkey = lnInvoice.getString("payeePubkey")!!
-    val paymentHash = lnInvoice.getString("paymentHash")!!
-    val description = if (hasNonNullKey(lnInvoice, "description")) lnInvoice.getString("description") else null
-    val descriptionHash = if (hasNonNullKey(lnInvoice, "descriptionHash")) lnInvoice.getString("descriptionHash") else null
-    val amountMsat = if (hasNonNullKey(lnInvoice, "amountMsat")) lnInvoice.getDouble("amountMsat").toULong() else null
-    val timestamp = lnInvoice.getDouble("timestamp").toULong()
-    val expiry = lnInvoice.getDouble("expiry").toULong()
-    val routingHints = lnInvoice.getArray("routingHints")?.let { asRouteHintList(it) }!!
-    val paymentSecret = lnInvoice.getArray("paymentSecret")?.let { asUByteList(it) }!!
-    val minFinalCltvExpiryDelta = lnInvoice.getDouble("minFinalCltvExpiryDelta").toULong()
-    return LnInvoice(
-        bolt11,
-        network,
-        payeePubkey,
-        paymentHash,
-        description,
-        descriptionHash,
-        amountMsat,
-        timestamp,
-        expiry,
-        routingHints,
-        paymentSecret,
-        minFinalCltvExpiryDelta,
-    )
+    val bundle = decryptRecoveryBundleRequest.getArray("bundle")?.let { asUByteList(it) }!!
+    val privateKey = decryptRecoveryBundleRequest.getArray("privateKey")?.let { asUByteList(it) }!!
+    return DecryptRecoveryBundleRequest(bundle, privateKey)
 }
 
-fun readableMapOf(lnInvoice: LnInvoice): ReadableMap =
+fun readableMapOf(decryptRecoveryBundleRequest: DecryptRecoveryBundleRequest): ReadableMap =
     readableMapOf(
-        "bolt11" to lnInvoice.bolt11,
-        "network" to lnInvoice.network.name.lowercase(),
-        "payeePubkey" to lnInvoice.payeePubkey,
-        "paymentHash" to lnInvoice.paymentHash,
-        "description" to lnInvoice.description,
-        "descriptionHash" to lnInvoice.descriptionHash,
-        "amountMsat" to lnInvoice.amountMsat,
-        "timestamp" to lnInvoice.timestamp,
-        "expiry" to lnInvoice.expiry,
-        "routingHints" to readableArrayOf(lnInvoice.routingHints),
-        "paymentSecret" to readableArrayOf(lnInvoice.paymentSecret),
-        "minFinalCltvExpiryDelta" to lnInvoice.minFinalCltvExpiryDelta,
+        "bundle" to readableArrayOf(decryptRecoveryBundleRequest.bundle),
+        "privateKey" to readableArrayOf(decryptRecoveryBundleRequest.privateKey),
     )
 
-fun asLnInvoiceList(arr: ReadableArray): List<LnInvoice> {
-    val list = ArrayList<LnInvoice>()
+fun asDecryptRecoveryBundleRequestList(arr: ReadableArray): List<DecryptRecoveryBundleRequest> {
+    val list = ArrayList<DecryptRecoveryBundleRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnInvoice(value)!!)
+            is ReadableMap -> list.add(asDecryptRecoveryBundleRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asListPaymentsRequest(listPaymentsRequest: ReadableMap): ListPaymentsRequest? {
+fun asDeriveEncryptionKeyRequest(deriveEncryptionKeyRequest: ReadableMap): DeriveEncryptionKeyRequest? {
     if (!validateMandatoryFields(
-            listPaymentsRequest,
-            arrayOf(),
+            deriveEncryptionKeyRequest,
+            arrayOf(
+                "purpose",
+                "index",
+            ),
         )
     ) {
         return null
     }
-    val filters =
-        if (hasNonNullKey(listPaymentsRequest, "filters")) {
-            listPaymentsRequest.getArray("filters")?.let {
-                asPaymentTypeFilterList(it)
-            }
-        } else {
-            null
-        }
-    val metadataFilters =
-        if (hasNonNullKey(listPaymentsRequest, "metadataFilters")) {
-            listPaymentsRequest.getArray("metadataFilters")?.let {
-                asMetadataFilterList(it)
-            }
-        } else {
-            null
-        }
-    val fromTimestamp =
-        if (hasNonNullKey(
-                listPaymentsRequest,
-                "fromTimestamp",
-            )
-        ) {
-            listPaymentsRequest.getDouble("fromTimestamp").toLong()
-        } else {
-            null
-        }
-    val toTimestamp = if (hasNonNullKey(listPaymentsRequest, "toTimestamp")) listPaymentsRequest.getDouble("toTimestamp").toLong() else null
-    val includeFailures =
-        if (hasNonNullKey(
-                listPaymentsRequest,
-                "includeFailures",
-            )
-        ) {
-            listPaymentsRequest.getBoolean("includeFailures")
-        } else {
-            null
+    val purpose = deriveEncryptionKeyRequest.getString("purpose")!!
+    val index = deriveEncryptionKeyRequest.getInt("index").toUInt()
+    return DeriveEncryptionKeyRequest(purpose, index)
+}
+
+fun readableMapOf(deriveEncryptionKeyRequest: DeriveEncryptionKeyRequest): ReadableMap =
+    readableMapOf(
+        "purpose" to deriveEncryptionKeyRequest.purpose,
+        "index" to deriveEncryptionKeyRequest.index,
+    )
+
+fun asDeriveEncryptionKeyRequestList(arr: ReadableArray): List<DeriveEncryptionKeyRequest> {
+    val list = ArrayList<DeriveEncryptionKeyRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asDeriveEncryptionKeyRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
-    val offset = if (hasNonNullKey(listPaymentsRequest, "offset")) listPaymentsRequest.getInt("offset").toUInt() else null
-    val limit = if (hasNonNullKey(listPaymentsRequest, "limit")) listPaymentsRequest.getInt("limit").toUInt() else null
-    return ListPaymentsRequest(filters, metadataFilters, fromTimestamp, toTimestamp, includeFailures, offset, limit)
+    }
+    return list
 }
 
-fun readableMapOf(listPaymentsRequest: ListPaymentsRequest): ReadableMap =
+fun asDeriveEncryptionKeyResponse(deriveEncryptionKeyResponse: ReadableMap): DeriveEncryptionKeyResponse? {
+    if (!validateMandatoryFields(
+            deriveEncryptionKeyResponse,
+            arrayOf(
+                "key",
+            ),
+        )
+    ) {
+        return null
+    }
+    val key = deriveEncryptionKeyResponse.getArray("key")?.let { asUByteList(it) }!!
+    return DeriveEncryptionKeyResponse(key)
+}
+
+fun readableMapOf(deriveEncryptionKeyResponse: DeriveEncryptionKeyResponse): ReadableMap =
     readableMapOf(
-        "filters" to listPaymentsRequest.filters?.let { readableArrayOf(it) },
-        "metadataFilters" to listPaymentsRequest.metadataFilters?.let { readableArrayOf(it) },
-        "fromTimestamp" to listPaymentsRequest.fromTimestamp,
-        "toTimestamp" to listPaymentsRequest.toTimestamp,
-        "includeFailures" to listPaymentsRequest.includeFailures,
-        "offset" to listPaymentsRequest.offset,
-        "limit" to listPaymentsRequest.limit,
+        "key" to readableArrayOf(deriveEncryptionKeyResponse.key),
     )
 
-fun asListPaymentsRequestList(arr: ReadableArray): List<ListPaymentsRequest> {
-    val list = ArrayList<ListPaymentsRequest>()
+fun asDeriveEncryptionKeyResponseList(arr: ReadableArray): List<DeriveEncryptionKeyResponse> {
+    val list = ArrayList<DeriveEncryptionKeyResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asListPaymentsRequest(value)!!)
+            is ReadableMap -> list.add(asDeriveEncryptionKeyResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asListSwapsRequest(listSwapsRequest: ReadableMap): ListSwapsRequest? {
+fun asEncryptedPaymentRequestData(encryptedPaymentRequestData: ReadableMap): EncryptedPaymentRequestData? {
     if (!validateMandatoryFields(
-            listSwapsRequest,
-            arrayOf(),
+            encryptedPaymentRequestData,
+            arrayOf(
+                "blob",
+            ),
         )
     ) {
         return null
     }
-    val status = if (hasNonNullKey(listSwapsRequest, "status")) listSwapsRequest.getArray("status")?.let { asSwapStatusList(it) } else null
-    val fromTimestamp = if (hasNonNullKey(listSwapsRequest, "fromTimestamp")) listSwapsRequest.getDouble("fromTimestamp").toLong() else null
-    val toTimestamp = if (hasNonNullKey(listSwapsRequest, "toTimestamp")) listSwapsRequest.getDouble("toTimestamp").toLong() else null
-    val offset = if (hasNonNullKey(listSwapsRequest, "offset")) listSwapsRequest.getInt("offset").toUInt() else null
-    val limit = if (hasNonNullKey(listSwapsRequest, "limit")) listSwapsRequest.getInt("limit").toUInt() else null
-    return ListSwapsRequest(status, fromTimestamp, toTimestamp, offset, limit)
+    val blob = encryptedPaymentRequestData.getString("blob")!!
+    return EncryptedPaymentRequestData(blob)
 }
 
-fun readableMapOf(listSwapsRequest: ListSwapsRequest): ReadableMap =
+fun readableMapOf(encryptedPaymentRequestData: EncryptedPaymentRequestData): ReadableMap =
     readableMapOf(
-        "status" to listSwapsRequest.status?.let { readableArrayOf(it) },
-        "fromTimestamp" to listSwapsRequest.fromTimestamp,
-        "toTimestamp" to listSwapsRequest.toTimestamp,
-        "offset" to listSwapsRequest.offset,
-        "limit" to listSwapsRequest.limit,
+        "blob" to encryptedPaymentRequestData.blob,
     )
 
-fun asListSwapsRequestList(arr: ReadableArray): List<ListSwapsRequest> {
-    val list = ArrayList<ListSwapsRequest>()
+fun asEncryptedPaymentRequestDataList(arr: ReadableArray): List<EncryptedPaymentRequestData> {
+    val list = ArrayList<EncryptedPaymentRequestData>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asListSwapsRequest(value)!!)
+            is ReadableMap -> list.add(asEncryptedPaymentRequestData(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnPaymentDetails(lnPaymentDetails: ReadableMap): LnPaymentDetails? {
+fun asExportPaymentsRequest(exportPaymentsRequest: ReadableMap): ExportPaymentsRequest? {
     if (!validateMandatoryFields(
-            lnPaymentDetails,
+            exportPaymentsRequest,
             arrayOf(
-                "paymentHash",
-                "label",
-                "destinationPubkey",
-                "paymentPreimage",
-                "keysend",
-                "bolt11",
+                "format",
             ),
         )
     ) {
         return null
     }
-    val paymentHash = lnPaymentDetails.getString("paymentHash")!!
-    val label = lnPaymentDetails.getString("label")!!
-    val destinationPubkey = lnPaymentDetails.getString("destinationPubkey")!!
-    val paymentPreimage = lnPaymentDetails.getString("paymentPreimage")!!
-    val keysend = lnPaymentDetails.getBoolean("keysend")
-    val bolt11 = lnPaymentDetails.getString("bolt11")!!
-    val openChannelBolt11 =
+    val format = exportPaymentsRequest.getString("format")?.let { asExportFormat(it) }!!
+    val fromTimestamp =
         if (hasNonNullKey(
-                lnPaymentDetails,
-                "openChannelBolt11",
+                exportPaymentsRequest,
+                "fromTimestamp",
             )
         ) {
-            lnPaymentDetails.getString("openChannelBolt11")
+            exportPaymentsRequest.getDouble("fromTimestamp").toLong()
         } else {
             null
         }
-    val lnurlSuccessAction =
-        if (hasNonNullKey(lnPaymentDetails, "lnurlSuccessAction")) {
-            lnPaymentDetails.getMap("lnurlSuccessAction")?.let {
-                asSuccessActionProcessed(it)
-            }
-        } else {
-            null
-        }
-    val lnurlPayDomain = if (hasNonNullKey(lnPaymentDetails, "lnurlPayDomain")) lnPaymentDetails.getString("lnurlPayDomain") else null
-    val lnurlPayComment = if (hasNonNullKey(lnPaymentDetails, "lnurlPayComment")) lnPaymentDetails.getString("lnurlPayComment") else null
-    val lnurlMetadata = if (hasNonNullKey(lnPaymentDetails, "lnurlMetadata")) lnPaymentDetails.getString("lnurlMetadata") else null
-    val lnAddress = if (hasNonNullKey(lnPaymentDetails, "lnAddress")) lnPaymentDetails.getString("lnAddress") else null
-    val lnurlWithdrawEndpoint =
+    val toTimestamp =
         if (hasNonNullKey(
-                lnPaymentDetails,
-                "lnurlWithdrawEndpoint",
+                exportPaymentsRequest,
+                "toTimestamp",
             )
         ) {
-            lnPaymentDetails.getString("lnurlWithdrawEndpoint")
+            exportPaymentsRequest.getDouble("toTimestamp").toLong()
         } else {
             null
         }
-    val swapInfo = if (hasNonNullKey(lnPaymentDetails, "swapInfo")) lnPaymentDetails.getMap("swapInfo")?.let { asSwapInfo(it) } else null
-    val reverseSwapInfo =
-        if (hasNonNullKey(lnPaymentDetails, "reverseSwapInfo")) {
-            lnPaymentDetails.getMap("reverseSwapInfo")?.let {
-                asReverseSwapInfo(it)
-            }
-        } else {
-            null
+    val includeFiat = if (hasNonNullKey(exportPaymentsRequest, "includeFiat")) exportPaymentsRequest.getString("includeFiat") else null
+    return ExportPaymentsRequest(format, fromTimestamp, toTimestamp, includeFiat)
+}
+
+fun readableMapOf(exportPaymentsRequest: ExportPaymentsRequest): ReadableMap =
+    readableMapOf(
+        "format" to exportPaymentsRequest.format.name.lowercase(),
+        "fromTimestamp" to exportPaymentsRequest.fromTimestamp,
+        "toTimestamp" to exportPaymentsRequest.toTimestamp,
+        "includeFiat" to exportPaymentsRequest.includeFiat,
+    )
+
+fun asExportPaymentsRequestList(arr: ReadableArray): List<ExportPaymentsRequest> {
+    val list = ArrayList<ExportPaymentsRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asExportPaymentsRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
-    val pendingExpirationBlock =
+    }
+    return list
+}
+
+fun asExportRecoveryBundleRequest(exportRecoveryBundleRequest: ReadableMap): ExportRecoveryBundleRequest? {
+    if (!validateMandatoryFields(
+            exportRecoveryBundleRequest,
+            arrayOf(
+                "recipientPubkey",
+            ),
+        )
+    ) {
+        return null
+    }
+    val recipientPubkey = exportRecoveryBundleRequest.getString("recipientPubkey")!!
+    val instructions =
         if (hasNonNullKey(
-                lnPaymentDetails,
-                "pendingExpirationBlock",
+                exportRecoveryBundleRequest,
+                "instructions",
             )
         ) {
-            lnPaymentDetails.getInt("pendingExpirationBlock").toUInt()
+            exportRecoveryBundleRequest.getString("instructions")
         } else {
             null
         }
-    return LnPaymentDetails(
-        paymentHash,
-        label,
-        destinationPubkey,
-        paymentPreimage,
-        keysend,
-        bolt11,
-        openChannelBolt11,
-        lnurlSuccessAction,
-        lnurlPayDomain,
-        lnurlPayComment,
-        lnurlMetadata,
-        lnAddress,
-        lnurlWithdrawEndpoint,
-        swapInfo,
-        reverseSwapInfo,
-        pendingExpirationBlock,
-    )
+    val filePath = if (hasNonNullKey(exportRecoveryBundleRequest, "filePath")) exportRecoveryBundleRequest.getString("filePath") else null
+    return ExportRecoveryBundleRequest(recipientPubkey, instructions, filePath)
 }
 
-fun readableMapOf(lnPaymentDetails: LnPaymentDetails): ReadableMap =
+fun readableMapOf(exportRecoveryBundleRequest: ExportRecoveryBundleRequest): ReadableMap =
     readableMapOf(
-        "paymentHash" to lnPaymentDetails.paymentHash,
-        "label" to lnPaymentDetails.label,
-        "destinationPubkey" to lnPaymentDetails.destinationPubkey,
-        "paymentPreimage" to lnPaymentDetails.paymentPreimage,
-        "keysend" to lnPaymentDetails.keysend,
-        "bolt11" to lnPaymentDetails.bolt11,
-        "openChannelBolt11" to lnPaymentDetails.openChannelBolt11,
-        "lnurlSuccessAction" to lnPaymentDetails.lnurlSuccessAction?.let { readableMapOf(it) },
-        "lnurlPayDomain" to lnPaymentDetails.lnurlPayDomain,
-        "lnurlPayComment" to lnPaymentDetails.lnurlPayComment,
-        "lnurlMetadata" to lnPaymentDetails.lnurlMetadata,
-        "lnAddress" to lnPaymentDetails.lnAddress,
-        "lnurlWithdrawEndpoint" to lnPaymentDetails.lnurlWithdrawEndpoint,
-        "swapInfo" to lnPaymentDetails.swapInfo?.let { readableMapOf(it) },
-        "reverseSwapInfo" to lnPaymentDetails.reverseSwapInfo?.let { readableMapOf(it) },
-        "pendingExpirationBlock" to lnPaymentDetails.pendingExpirationBlock,
+        "recipientPubkey" to exportRecoveryBundleRequest.recipientPubkey,
+        "instructions" to exportRecoveryBundleRequest.instructions,
+        "filePath" to exportRecoveryBundleRequest.filePath,
     )
 
-fun asLnPaymentDetailsList(arr: ReadableArray): List<LnPaymentDetails> {
-    val list = ArrayList<LnPaymentDetails>()
+fun asExportRecoveryBundleRequestList(arr: ReadableArray): List<ExportRecoveryBundleRequest> {
+    val list = ArrayList<ExportRecoveryBundleRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnPaymentDetails(value)!!)
+            is ReadableMap -> list.add(asExportRecoveryBundleRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlAuthRequestData(lnUrlAuthRequestData: ReadableMap): LnUrlAuthRequestData? {
+fun asExportRecoveryBundleResponse(exportRecoveryBundleResponse: ReadableMap): ExportRecoveryBundleResponse? {
     if (!validateMandatoryFields(
-            lnUrlAuthRequestData,
+            exportRecoveryBundleResponse,
             arrayOf(
-                "k1",
-                "domain",
-                "url",
+                "bundle",
             ),
         )
     ) {
         return null
     }
-    val k1 = lnUrlAuthRequestData.getString("k1")!!
-    val domain = lnUrlAuthRequestData.getString("domain")!!
-    val url = lnUrlAuthRequestData.getString("url")!!
-    val action = if (hasNonNullKey(lnUrlAuthRequestData, "action")) lnUrlAuthRequestData.getString("action") else null
-    return LnUrlAuthRequestData(k1, domain, url, action)
+    val bundle = exportRecoveryBundleResponse.getArray("bundle")?.let { asUByteList(it) }!!
+    return ExportRecoveryBundleResponse(bundle)
 }
 
-fun readableMapOf(lnUrlAuthRequestData: LnUrlAuthRequestData): ReadableMap =
+fun readableMapOf(exportRecoveryBundleResponse: ExportRecoveryBundleResponse): ReadableMap =
     readableMapOf(
-        "k1" to lnUrlAuthRequestData.k1,
-        "domain" to lnUrlAuthRequestData.domain,
-        "url" to lnUrlAuthRequestData.url,
-        "action" to lnUrlAuthRequestData.action,
+        "bundle" to readableArrayOf(exportRecoveryBundleResponse.bundle),
     )
 
-fun asLnUrlAuthRequestDataList(arr: ReadableArray): List<LnUrlAuthRequestData> {
-    val list = ArrayList<LnUrlAuthRequestData>()
+fun asExportRecoveryBundleResponseList(arr: ReadableArray): List<ExportRecoveryBundleResponse> {
+    val list = ArrayList<ExportRecoveryBundleResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlAuthRequestData(value)!!)
+            is ReadableMap -> list.add(asExportRecoveryBundleResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlErrorData(lnUrlErrorData: ReadableMap): LnUrlErrorData? {
+fun asExportSwapRefundKeysRequest(exportSwapRefundKeysRequest: ReadableMap): ExportSwapRefundKeysRequest? {
     if (!validateMandatoryFields(
-            lnUrlErrorData,
-            arrayOf(
-                "reason",
-            ),
+            exportSwapRefundKeysRequest,
+            arrayOf(),
         )
     ) {
         return null
     }
-    val reason = lnUrlErrorData.getString("reason")!!
-    return LnUrlErrorData(reason)
+    val includeGapKeys =
+        if (hasNonNullKey(
+                exportSwapRefundKeysRequest,
+                "includeGapKeys",
+            )
+        ) {
+            exportSwapRefundKeysRequest.getBoolean("includeGapKeys")
+        } else {
+            null
+        }
+    return ExportSwapRefundKeysRequest(includeGapKeys)
 }
 
-fun readableMapOf(lnUrlErrorData: LnUrlErrorData): ReadableMap =
+fun readableMapOf(exportSwapRefundKeysRequest: ExportSwapRefundKeysRequest): ReadableMap =
     readableMapOf(
-        "reason" to lnUrlErrorData.reason,
+        "includeGapKeys" to exportSwapRefundKeysRequest.includeGapKeys,
     )
 
-fun asLnUrlErrorDataList(arr: ReadableArray): List<LnUrlErrorData> {
-    val list = ArrayList<LnUrlErrorData>()
+fun asExportSwapRefundKeysRequestList(arr: ReadableArray): List<ExportSwapRefundKeysRequest> {
+    val list = ArrayList<ExportSwapRefundKeysRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlErrorData(value)!!)
+            is ReadableMap -> list.add(asExportSwapRefundKeysRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlPayErrorData(lnUrlPayErrorData: ReadableMap): LnUrlPayErrorData? {
+fun asFetchBuyBitcoinQuoteRequest(fetchBuyBitcoinQuoteRequest: ReadableMap): FetchBuyBitcoinQuoteRequest? {
     if (!validateMandatoryFields(
-            lnUrlPayErrorData,
+            fetchBuyBitcoinQuoteRequest,
             arrayOf(
-                "paymentHash",
-                "reason",
+                "provider",
+                "fiatAmount",
             ),
         )
     ) {
         return null
     }
-    val paymentHash = lnUrlPayErrorData.getString("paymentHash")!!
-    val reason = lnUrlPayErrorData.getString("reason")!!
-    return LnUrlPayErrorData(paymentHash, reason)
+    val provider = fetchBuyBitcoinQuoteRequest.getString("provider")?.let { asBuyBitcoinProvider(it) }!!
+    val fiatAmount = fetchBuyBitcoinQuoteRequest.getMap("fiatAmount")?.let { asFiatAmount(it) }!!
+    return FetchBuyBitcoinQuoteRequest(provider, fiatAmount)
 }
 
-fun readableMapOf(lnUrlPayErrorData: LnUrlPayErrorData): ReadableMap =
+fun readableMapOf(fetchBuyBitcoinQuoteRequest: FetchBuyBitcoinQuoteRequest): ReadableMap =
     readableMapOf(
-        "paymentHash" to lnUrlPayErrorData.paymentHash,
-        "reason" to lnUrlPayErrorData.reason,
+        "provider" to fetchBuyBitcoinQuoteRequest.provider.name.lowercase(),
+        "fiatAmount" to readableMapOf(fetchBuyBitcoinQuoteRequest.fiatAmount),
     )
 
-fun asLnUrlPayErrorDataList(arr: ReadableArray): List<LnUrlPayErrorData> {
-    val list = ArrayList<LnUrlPayErrorData>()
+fun asFetchBuyBitcoinQuoteRequestList(arr: ReadableArray): List<FetchBuyBitcoinQuoteRequest> {
+    val list = ArrayList<FetchBuyBitcoinQuoteRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlPayErrorData(value)!!)
+            is ReadableMap -> list.add(asFetchBuyBitcoinQuoteRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlPayRequest(lnUrlPayRequest: ReadableMap): LnUrlPayRequest? {
+fun asFiatAmount(fiatAmount: ReadableMap): FiatAmount? {
     if (!validateMandatoryFields(
-            lnUrlPayRequest,
+            fiatAmount,
             arrayOf(
-                "data",
-                "amountMsat",
-                "useTrampoline",
+                "currency",
+                "amount",
             ),
         )
     ) {
         return null
     }
-    val data = lnUrlPayRequest.getMap("data")?.let { asLnUrlPayRequestData(it) }!!
-    val amountMsat = lnUrlPayRequest.getDouble("amountMsat").toULong()
-    val useTrampoline = lnUrlPayRequest.getBoolean("useTrampoline")
-    val comment = if (hasNonNullKey(lnUrlPayRequest, "comment")) lnUrlPayRequest.getString("comment") else null
-    val paymentLabel = if (hasNonNullKey(lnUrlPayRequest, "paymentLabel")) lnUrlPayRequest.getString("paymentLabel") else null
-    val validateSuccessActionUrl =
-        if (hasNonNullKey(
-                lnUrlPayRequest,
-                "validateSuccessActionUrl",
-            )
-        ) {
-            lnUrlPayRequest.getBoolean("validateSuccessActionUrl")
-        } else {
-            null
-        }
-    return LnUrlPayRequest(data, amountMsat, useTrampoline, comment, paymentLabel, validateSuccessActionUrl)
+    val currency = fiatAmount.getString("currency")!!
+    val amount = fiatAmount.getDouble("amount")
+    return FiatAmount(currency, amount)
 }
 
-fun readableMapOf(lnUrlPayRequest: LnUrlPayRequest): ReadableMap =
+fun readableMapOf(fiatAmount: FiatAmount): ReadableMap =
     readableMapOf(
-        "data" to readableMapOf(lnUrlPayRequest.data),
-        "amountMsat" to lnUrlPayRequest.amountMsat,
-        "useTrampoline" to lnUrlPayRequest.useTrampoline,
-        "comment" to lnUrlPayRequest.comment,
-        "paymentLabel" to lnUrlPayRequest.paymentLabel,
-        "validateSuccessActionUrl" to lnUrlPayRequest.validateSuccessActionUrl,
+        "currency" to fiatAmount.currency,
+        "amount" to fiatAmount.amount,
     )
 
-fun asLnUrlPayRequestList(arr: ReadableArray): List<LnUrlPayRequest> {
-    val list = ArrayList<LnUrlPayRequest>()
+fun asFiatAmountList(arr: ReadableArray): List<FiatAmount> {
+    val list = ArrayList<FiatAmount>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlPayRequest(value)!!)
+            is ReadableMap -> list.add(asFiatAmount(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlPayRequestData(lnUrlPayRequestData: ReadableMap): LnUrlPayRequestData? {
+fun asFiatCurrency(fiatCurrency: ReadableMap): FiatCurrency? {
     if (!validateMandatoryFields(
-            lnUrlPayRequestData,
+            fiatCurrency,
             arrayOf(
-                "callback",
-                "minSendable",
-                "maxSendable",
-                "metadataStr",
-                "commentAllowed",
-                "domain",
-                "allowsNostr",
+                "id",
+                "info",
             ),
         )
     ) {
         return null
     }
-    val callback = lnUrlPayRequestData.getString("callback")!!
-    val minSendable = lnUrlPayRequestData.getDouble("minSendable").toULong()
-    val maxSendable = lnUrlPayRequestData.getDouble("maxSendable").toULong()
-    val metadataStr = lnUrlPayRequestData.getString("metadataStr")!!
-    val commentAllowed = lnUrlPayRequestData.getInt("commentAllowed").toUShort()
-    val domain = lnUrlPayRequestData.getString("domain")!!
-    val allowsNostr = lnUrlPayRequestData.getBoolean("allowsNostr")
-    val nostrPubkey = if (hasNonNullKey(lnUrlPayRequestData, "nostrPubkey")) lnUrlPayRequestData.getString("nostrPubkey") else null
-    val lnAddress = if (hasNonNullKey(lnUrlPayRequestData, "lnAddress")) lnUrlPayRequestData.getString("lnAddress") else null
-    return LnUrlPayRequestData(
-        callback,
-        minSendable,
-        maxSendable,
-        metadataStr,
-        commentAllowed,
-        domain,
-        allowsNostr,
-        nostrPubkey,
-        lnAddress,
-    )
+    val id = fiatCurrency.getString("id")!!
+    val info = fiatCurrency.getMap("info")?.let { asCurrencyInfo(it) }!!
+    return FiatCurrency(id, info)
 }
 
-fun readableMapOf(lnUrlPayRequestData: LnUrlPayRequestData): ReadableMap =
+fun readableMapOf(fiatCurrency: FiatCurrency): ReadableMap =
     readableMapOf(
-        "callback" to lnUrlPayRequestData.callback,
-        "minSendable" to lnUrlPayRequestData.minSendable,
-        "maxSendable" to lnUrlPayRequestData.maxSendable,
-        "metadataStr" to lnUrlPayRequestData.metadataStr,
-        "commentAllowed" to lnUrlPayRequestData.commentAllowed,
-        "domain" to lnUrlPayRequestData.domain,
-        "allowsNostr" to lnUrlPayRequestData.allowsNostr,
-        "nostrPubkey" to lnUrlPayRequestData.nostrPubkey,
-        "lnAddress" to lnUrlPayRequestData.lnAddress,
+        "id" to fiatCurrency.id,
+        "info" to readableMapOf(fiatCurrency.info),
     )
 
-fun asLnUrlPayRequestDataList(arr: ReadableArray): List<LnUrlPayRequestData> {
-    val list = ArrayList<LnUrlPayRequestData>()
+fun asFiatCurrencyList(arr: ReadableArray): List<FiatCurrency> {
+    val list = ArrayList<FiatCurrency>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlPayRequestData(value)!!)
+            is ReadableMap -> list.add(asFiatCurrency(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlPaySuccessData(lnUrlPaySuccessData: ReadableMap): LnUrlPaySuccessData? {
+fun asFiatRateAlert(fiatRateAlert: ReadableMap): FiatRateAlert? {
     if (!validateMandatoryFields(
-            lnUrlPaySuccessData,
+            fiatRateAlert,
             arrayOf(
-                "payment",
+                "id",
+                "currency",
+                "direction",
+                "threshold",
+                "createdAt",
+                "triggered",
             ),
         )
     ) {
         return null
     }
-    val successAction =
-        if (hasNonNullKey(lnUrlPaySuccessData, "successAction")) {
-            lnUrlPaySuccessData.getMap("successAction")?.let {
-                asSuccessActionProcessed(it)
-            }
-        } else {
-            null
-        }
-    val payment = lnUrlPaySuccessData.getMap("payment")?.let { asPayment(it) }!!
-    return LnUrlPaySuccessData(successAction, payment)
+    val id = fiatRateAlert.getDouble("id").toLong()
+    val currency = fiatRateAlert.getString("currency")!!
+    val direction = fiatRateAlert.getString("direction")?.let { asFiatRateAlertDirection(it) }!!
+    val threshold = fiatRateAlert.getDouble("threshold")
+    val createdAt = fiatRateAlert.getDouble("createdAt").toLong()
+    val triggered = fiatRateAlert.getBoolean("triggered")
+    val lastTriggeredAt = if (hasNonNullKey(fiatRateAlert, "lastTriggeredAt")) fiatRateAlert.getDouble("lastTriggeredAt").toLong() else null
+    return FiatRateAlert(id, currency, direction, threshold, createdAt, triggered, lastTriggeredAt)
 }
 
-fun readableMapOf(lnUrlPaySuccessData: LnUrlPaySuccessData): ReadableMap =
+fun readableMapOf(fiatRateAlert: FiatRateAlert): ReadableMap =
     readableMapOf(
-        "successAction" to lnUrlPaySuccessData.successAction?.let { readableMapOf(it) },
-        "payment" to readableMapOf(lnUrlPaySuccessData.payment),
+        "id" to fiatRateAlert.id,
+        "currency" to fiatRateAlert.currency,
+        "direction" to fiatRateAlert.direction.name.lowercase(),
+        "threshold" to fiatRateAlert.threshold,
+        "createdAt" to fiatRateAlert.createdAt,
+        "triggered" to fiatRateAlert.triggered,
+        "lastTriggeredAt" to fiatRateAlert.lastTriggeredAt,
     )
 
-fun asLnUrlPaySuccessDataList(arr: ReadableArray): List<LnUrlPaySuccessData> {
-    val list = ArrayList<LnUrlPaySuccessData>()
+fun asFiatRateAlertList(arr: ReadableArray): List<FiatRateAlert> {
+    val list = ArrayList<FiatRateAlert>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlPaySuccessData(value)!!)
+            is ReadableMap -> list.add(asFiatRateAlert(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlWithdrawRequest(lnUrlWithdrawRequest: ReadableMap): LnUrlWithdrawRequest? {
+fun asFiatRateAlertTriggeredDetails(fiatRateAlertTriggeredDetails: ReadableMap): FiatRateAlertTriggeredDetails? {
     if (!validateMandatoryFields(
-            lnUrlWithdrawRequest,
+            fiatRateAlertTriggeredDetails,
             arrayOf(
-                "data",
-                "amountMsat",
+                "alert",
+                "rate",
             ),
         )
     ) {
         return null
     }
-    val data = lnUrlWithdrawRequest.getMap("data")?.let { asLnUrlWithdrawRequestData(it) }!!
-    val amountMsat = lnUrlWithdrawRequest.getDouble("amountMsat").toULong()
-    val description = if (hasNonNullKey(lnUrlWithdrawRequest, "description")) lnUrlWithdrawRequest.getString("description") else null
-    return LnUrlWithdrawRequest(data, amountMsat, description)
+    val alert = fiatRateAlertTriggeredDetails.getMap("alert")?.let { asFiatRateAlert(it) }!!
+    val rate = fiatRateAlertTriggeredDetails.getDouble("rate")
+    return FiatRateAlertTriggeredDetails(alert, rate)
 }
 
-fun readableMapOf(lnUrlWithdrawRequest: LnUrlWithdrawRequest): ReadableMap =
+fun readableMapOf(fiatRateAlertTriggeredDetails: FiatRateAlertTriggeredDetails): ReadableMap =
     readableMapOf(
-        "data" to readableMapOf(lnUrlWithdrawRequest.data),
-        "amountMsat" to lnUrlWithdrawRequest.amountMsat,
-        "description" to lnUrlWithdrawRequest.description,
+        "alert" to readableMapOf(fiatRateAlertTriggeredDetails.alert),
+        "rate" to fiatRateAlertTriggeredDetails.rate,
     )
 
-fun asLnUrlWithdrawRequestList(arr: ReadableArray): List<LnUrlWithdrawRequest> {
-    val list = ArrayList<LnUrlWithdrawRequest>()
+fun asFiatRateAlertTriggeredDetailsList(arr: ReadableArray): List<FiatRateAlertTriggeredDetails> {
+    val list = ArrayList<FiatRateAlertTriggeredDetails>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlWithdrawRequest(value)!!)
+            is ReadableMap -> list.add(asFiatRateAlertTriggeredDetails(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlWithdrawRequestData(lnUrlWithdrawRequestData: ReadableMap): LnUrlWithdrawRequestData? {
+fun asGreenlightCredentials(greenlightCredentials: ReadableMap): GreenlightCredentials? {
     if (!validateMandatoryFields(
-            lnUrlWithdrawRequestData,
+            greenlightCredentials,
             arrayOf(
-                "callback",
-                "k1",
-                "defaultDescription",
-                "minWithdrawable",
-                "maxWithdrawable",
+                "developerKey",
+                "developerCert",
             ),
         )
     ) {
         return null
     }
-    val callback = lnUrlWithdrawRequestData.getString("callback")!!
-    val k1 = lnUrlWithdrawRequestData.getString("k1")!!
-    val defaultDescription = lnUrlWithdrawRequestData.getString("defaultDescription")!!
-    val minWithdrawable = lnUrlWithdrawRequestData.getDouble("minWithdrawable").toULong()
-    val maxWithdrawable = lnUrlWithdrawRequestData.getDouble("maxWithdrawable").toULong()
-    return LnUrlWithdrawRequestData(callback, k1, defaultDescription, minWithdrawable, maxWithdrawable)
+    val developerKey = greenlightCredentials.getArray("developerKey")?.let { asUByteList(it) }!!
+    val developerCert = greenlightCredentials.getArray("developerCert")?.let { asUByteList(it) }!!
+    return GreenlightCredentials(developerKey, developerCert)
 }
 
-fun readableMapOf(lnUrlWithdrawRequestData: LnUrlWithdrawRequestData): ReadableMap =
+fun readableMapOf(greenlightCredentials: GreenlightCredentials): ReadableMap =
     readableMapOf(
-        "callback" to lnUrlWithdrawRequestData.callback,
-        "k1" to lnUrlWithdrawRequestData.k1,
-        "defaultDescription" to lnUrlWithdrawRequestData.defaultDescription,
-        "minWithdrawable" to lnUrlWithdrawRequestData.minWithdrawable,
-        "maxWithdrawable" to lnUrlWithdrawRequestData.maxWithdrawable,
+        "developerKey" to readableArrayOf(greenlightCredentials.developerKey),
+        "developerCert" to readableArrayOf(greenlightCredentials.developerCert),
     )
 
-fun asLnUrlWithdrawRequestDataList(arr: ReadableArray): List<LnUrlWithdrawRequestData> {
-    val list = ArrayList<LnUrlWithdrawRequestData>()
+fun asGreenlightCredentialsList(arr: ReadableArray): List<GreenlightCredentials> {
+    val list = ArrayList<GreenlightCredentials>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlWithdrawRequestData(value)!!)
+            is ReadableMap -> list.add(asGreenlightCredentials(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLnUrlWithdrawSuccessData(lnUrlWithdrawSuccessData: ReadableMap): LnUrlWithdrawSuccessData? {
+fun asGreenlightDeviceCredentials(greenlightDeviceCredentials: ReadableMap): GreenlightDeviceCredentials? {
     if (!validateMandatoryFields(
-            lnUrlWithdrawSuccessData,
+            greenlightDeviceCredentials,
             arrayOf(
-                "invoice",
+                "device",
             ),
         )
     ) {
         return null
     }
-    val invoice = lnUrlWithdrawSuccessData.getMap("invoice")?.let { asLnInvoice(it) }!!
-    return LnUrlWithdrawSuccessData(invoice)
+    val device = greenlightDeviceCredentials.getArray("device")?.let { asUByteList(it) }!!
+    return GreenlightDeviceCredentials(device)
 }
 
-fun readableMapOf(lnUrlWithdrawSuccessData: LnUrlWithdrawSuccessData): ReadableMap =
+fun readableMapOf(greenlightDeviceCredentials: GreenlightDeviceCredentials): ReadableMap =
     readableMapOf(
-        "invoice" to readableMapOf(lnUrlWithdrawSuccessData.invoice),
+        "device" to readableArrayOf(greenlightDeviceCredentials.device),
     )
 
-fun asLnUrlWithdrawSuccessDataList(arr: ReadableArray): List<LnUrlWithdrawSuccessData> {
-    val list = ArrayList<LnUrlWithdrawSuccessData>()
+fun asGreenlightDeviceCredentialsList(arr: ReadableArray): List<GreenlightDeviceCredentials> {
+    val list = ArrayList<GreenlightDeviceCredentials>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLnUrlWithdrawSuccessData(value)!!)
+            is ReadableMap -> list.add(asGreenlightDeviceCredentials(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLocaleOverrides(localeOverrides: ReadableMap): LocaleOverrides? {
+fun asGreenlightNodeConfig(greenlightNodeConfig: ReadableMap): GreenlightNodeConfig? {
     if (!validateMandatoryFields(
-            localeOverrides,
-            arrayOf(
-                "locale",
-                "symbol",
-            ),
+            greenlightNodeConfig,
+            arrayOf(),
         )
     ) {
         return null
     }
-    val locale = localeOverrides.getString("locale")!!
-    val spacing = if (hasNonNullKey(localeOverrides, "spacing")) localeOverrides.getInt("spacing").toUInt() else null
-    val symbol = localeOverrides.getMap("symbol")?.let { asSymbol(it) }!!
-    return LocaleOverrides(locale, spacing, symbol)
+    val partnerCredentials =
+        if (hasNonNullKey(
+                greenlightNodeConfig,
+                "partnerCredentials",
+            )
+        ) {
+            greenlightNodeConfig.getMap("partnerCredentials")?.let {
+                asGreenlightCredentials(it)
+            }
+        } else {
+            null
+        }
+    val inviteCode = if (hasNonNullKey(greenlightNodeConfig, "inviteCode")) greenlightNodeConfig.getString("inviteCode") else null
+    return GreenlightNodeConfig(partnerCredentials, inviteCode)
 }
 
-fun readableMapOf(localeOverrides: LocaleOverrides): ReadableMap =
+fun readableMapOf(greenlightNodeConfig: GreenlightNodeConfig): ReadableMap =
     readableMapOf(
-        "locale" to localeOverrides.locale,
-        "spacing" to localeOverrides.spacing,
-        "symbol" to readableMapOf(localeOverrides.symbol),
+        "partnerCredentials" to greenlightNodeConfig.partnerCredentials?.let { readableMapOf(it) },
+        "inviteCode" to greenlightNodeConfig.inviteCode,
     )
 
-fun asLocaleOverridesList(arr: ReadableArray): List<LocaleOverrides> {
-    val list = ArrayList<LocaleOverrides>()
+fun asGreenlightNodeConfigList(arr: ReadableArray): List<GreenlightNodeConfig> {
+    val list = ArrayList<GreenlightNodeConfig>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLocaleOverrides(value)!!)
+            is ReadableMap -> list.add(asGreenlightNodeConfig(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLocalizedName(localizedName: ReadableMap): LocalizedName? {
+fun asHistoricalRate(historicalRate: ReadableMap): HistoricalRate? {
     if (!validateMandatoryFields(
-            localizedName,
+            historicalRate,
             arrayOf(
-                "locale",
-                "name",
+                "coin",
+                "value",
+                "timestamp",
             ),
         )
     ) {
         return null
     }
-    val locale = localizedName.getString("locale")!!
-    val name = localizedName.getString("name")!!
-    return LocalizedName(locale, name)
+    val coin = historicalRate.getString("coin")!!
+    val value = historicalRate.getDouble("value")
+    val timestamp = historicalRate.getDouble("timestamp").toLong()
+    return HistoricalRate(coin, value, timestamp)
 }
 
-fun readableMapOf(localizedName: LocalizedName): ReadableMap =
+fun readableMapOf(historicalRate: HistoricalRate): ReadableMap =
     readableMapOf(
-        "locale" to localizedName.locale,
-        "name" to localizedName.name,
+        "coin" to historicalRate.coin,
+        "value" to historicalRate.value,
+        "timestamp" to historicalRate.timestamp,
     )
 
-fun asLocalizedNameList(arr: ReadableArray): List<LocalizedName> {
-    val list = ArrayList<LocalizedName>()
+fun asHistoricalRateList(arr: ReadableArray): List<HistoricalRate> {
+    val list = ArrayList<HistoricalRate>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLocalizedName(value)!!)
+            is ReadableMap -> list.add(asHistoricalRate(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLogEntry(logEntry: ReadableMap): LogEntry? {
+fun asHtlcExposureLimits(htlcExposureLimits: ReadableMap): HtlcExposureLimits? {
     if (!validateMandatoryFields(
-            logEntry,
-            arrayOf(
-                "line",
-                "level",
-            ),
+            htlcExposureLimits,
+            arrayOf(),
         )
     ) {
         return null
     }
-    val line = logEntry.getString("line")!!
-    val level = logEntry.getString("level")!!
-    return LogEntry(line, level)
+    val maxInflightMsat =
+        if (hasNonNullKey(
+                htlcExposureLimits,
+                "maxInflightMsat",
+            )
+        ) {
+            htlcExposureLimits.getDouble("maxInflightMsat").toULong()
+        } else {
+            null
+        }
+    val maxHtlcsPerPayment =
+        if (hasNonNullKey(
+                htlcExposureLimits,
+                "maxHtlcsPerPayment",
+            )
+        ) {
+            htlcExposureLimits.getInt("maxHtlcsPerPayment").toUInt()
+        } else {
+            null
+        }
+    return HtlcExposureLimits(maxInflightMsat, maxHtlcsPerPayment)
 }
 
-fun readableMapOf(logEntry: LogEntry): ReadableMap =
+fun readableMapOf(htlcExposureLimits: HtlcExposureLimits): ReadableMap =
     readableMapOf(
-        "line" to logEntry.line,
-        "level" to logEntry.level,
+        "maxInflightMsat" to htlcExposureLimits.maxInflightMsat,
+        "maxHtlcsPerPayment" to htlcExposureLimits.maxHtlcsPerPayment,
     )
 
-fun asLogEntryList(arr: ReadableArray): List<LogEntry> {
-    val list = ArrayList<LogEntry>()
+fun asHtlcExposureLimitsList(arr: ReadableArray): List<HtlcExposureLimits> {
+    val list = ArrayList<HtlcExposureLimits>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLogEntry(value)!!)
+            is ReadableMap -> list.add(asHtlcExposureLimits(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asLspInformation(lspInformation: ReadableMap): LspInformation? {
+fun asImportPaymentsRequest(importPaymentsRequest: ReadableMap): ImportPaymentsRequest? {
     if (!validateMandatoryFields(
-            lspInformation,
+            importPaymentsRequest,
             arrayOf(
-                "id",
-                "name",
-                "widgetUrl",
-                "pubkey",
-                "host",
-                "baseFeeMsat",
-                "feeRate",
-                "timeLockDelta",
-                "minHtlcMsat",
-                "lspPubkey",
-                "openingFeeParamsList",
+                "format",
+                "data",
             ),
         )
     ) {
         return null
     }
-    val id = lspInformation.getString("id")!!
-    val name = lspInformation.getString("name")!!
-    val widgetUrl = lspInformation.getString("widgetUrl")!!
-    val pubkey = lspInformation.getString("pubkey")!!
-    val host = lspInformation.getString("host")!!
-    val baseFeeMsat = lspInformation.getDouble("baseFeeMsat").toLong()
-    val feeRate = lspInformation.getDouble("feeRate")
-    val timeLockDelta = lspInformation.getInt("timeLockDelta").toUInt()
-    val minHtlcMsat = lspInformation.getDouble("minHtlcMsat").toLong()
-    val lspPubkey = lspInformation.getArray("lspPubkey")?.let { asUByteList(it) }!!
-    val openingFeeParamsList = lspInformation.getMap("openingFeeParamsList")?.let { asOpeningFeeParamsMenu(it) }!!
-    return LspInformation(
-        id,
-        name,
-        widgetUrl,
-        pubkey,
-        host,
-        baseFeeMsat,
-        feeRate,
-        timeLockDelta,
-        minHtlcMsat,
-        lspPubkey,
-        openingFeeParamsList,
-    )
+    val format = importPaymentsRequest.getString("format")?.let { asExportFormat(it) }!!
+    val data = importPaymentsRequest.getString("data")!!
+    return ImportPaymentsRequest(format, data)
 }
 
-fun readableMapOf(lspInformation: LspInformation): ReadableMap =
+fun readableMapOf(importPaymentsRequest: ImportPaymentsRequest): ReadableMap =
     readableMapOf(
-        "id" to lspInformation.id,
-        "name" to lspInformation.name,
-        "widgetUrl" to lspInformation.widgetUrl,
-        "pubkey" to lspInformation.pubkey,
-        "host" to lspInformation.host,
-        "baseFeeMsat" to lspInformation.baseFeeMsat,
-        "feeRate" to lspInformation.feeRate,
-        "timeLockDelta" to lspInformation.timeLockDelta,
-        "minHtlcMsat" to lspInformation.minHtlcMsat,
-        "lspPubkey" to readableArrayOf(lspInformation.lspPubkey),
-        "openingFeeParamsList" to readableMapOf(lspInformation.openingFeeParamsList),
+        "format" to importPaymentsRequest.format.name.lowercase(),
+        "data" to importPaymentsRequest.data,
     )
 
-fun asLspInformationList(arr: ReadableArray): List<LspInformation> {
-    val list = ArrayList<LspInformation>()
+fun asImportPaymentsRequestList(arr: ReadableArray): List<ImportPaymentsRequest> {
+    val list = ArrayList<ImportPaymentsRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asLspInformation(value)!!)
+            is ReadableMap -> list.add(asImportPaymentsRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asMessageSuccessActionData(messageSuccessActionData: ReadableMap): MessageSuccessActionData? {
+fun asImportPaymentsResponse(importPaymentsResponse: ReadableMap): ImportPaymentsResponse? {
     if (!validateMandatoryFields(
-            messageSuccessActionData,
+            importPaymentsResponse,
             arrayOf(
-                "message",
+                "importedCount",
+                "skippedCount",
             ),
         )
     ) {
         return null
     }
-    val message = messageSuccessActionData.getString("message")!!
-    return MessageSuccessActionData(message)
+    val importedCount = importPaymentsResponse.getInt("importedCount").toUInt()
+    val skippedCount = importPaymentsResponse.getInt("skippedCount").toUInt()
+    return ImportPaymentsResponse(importedCount, skippedCount)
 }
 
-fun readableMapOf(messageSuccessActionData: MessageSuccessActionData): ReadableMap =
+fun readableMapOf(importPaymentsResponse: ImportPaymentsResponse): ReadableMap =
     readableMapOf(
-        "message" to messageSuccessActionData.message,
+        "importedCount" to importPaymentsResponse.importedCount,
+        "skippedCount" to importPaymentsResponse.skippedCount,
     )
 
-fun asMessageSuccessActionDataList(arr: ReadableArray): List<MessageSuccessActionData> {
-    val list = ArrayList<MessageSuccessActionData>()
+fun asImportPaymentsResponseList(arr: ReadableArray): List<ImportPaymentsResponse> {
+    val list = ArrayList<ImportPaymentsResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asMessageSuccessActionData(value)!!)
+            is ReadableMap -> list.add(asImportPaymentsResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asMetadataFilter(metadataFilter: ReadableMap): MetadataFilter? {
+fun asInvoiceFeatures(invoiceFeatures: ReadableMap): InvoiceFeatures? {
     if (!validateMandatoryFields(
-            metadataFilter,
+            invoiceFeatures,
             arrayOf(
-                "jsonPath",
-                "jsonValue",
+                "basicMpp",
+                "paymentSecret",
+                "trampoline",
+                "requiresUnknownFeatures",
             ),
         )
     ) {
         return null
     }
-    val jsonPath = metadataFilter.getString("jsonPath")!!
-    val jsonValue = metadataFilter.getString("jsonValue")!!
-    return MetadataFilter(jsonPath, jsonValue)
+    val basicMpp = invoiceFeatures.getString("basicMpp")?.let { asFeatureSupport(it) }!!
+    val paymentSecret = invoiceFeatures.getString("paymentSecret")?.let { asFeatureSupport(it) }!!
+    val trampoline = invoiceFeatures.getString("trampoline")?.let { asFeatureSupport(it) }!!
+    val requiresUnknownFeatures = invoiceFeatures.getBoolean("requiresUnknownFeatures")
+    return InvoiceFeatures(basicMpp, paymentSecret, trampoline, requiresUnknownFeatures)
 }
 
-fun readableMapOf(metadataFilter: MetadataFilter): ReadableMap =
+fun readableMapOf(invoiceFeatures: InvoiceFeatures): ReadableMap =
     readableMapOf(
-        "jsonPath" to metadataFilter.jsonPath,
-        "jsonValue" to metadataFilter.jsonValue,
+        "basicMpp" to invoiceFeatures.basicMpp.name.lowercase(),
+        "paymentSecret" to invoiceFeatures.paymentSecret.name.lowercase(),
+        "trampoline" to invoiceFeatures.trampoline.name.lowercase(),
+        "requiresUnknownFeatures" to invoiceFeatures.requiresUnknownFeatures,
     )
 
-fun asMetadataFilterList(arr: ReadableArray): List<MetadataFilter> {
-    val list = ArrayList<MetadataFilter>()
+fun asInvoiceFeaturesList(arr: ReadableArray): List<InvoiceFeatures> {
+    val list = ArrayList<InvoiceFeatures>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asMetadataFilter(value)!!)
+            is ReadableMap -> list.add(asInvoiceFeatures(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asMetadataItem(metadataItem: ReadableMap): MetadataItem? {
+fun asInvoicePaidDetails(invoicePaidDetails: ReadableMap): InvoicePaidDetails? {
     if (!validateMandatoryFields(
-            metadataItem,
+            invoicePaidDetails,
             arrayOf(
-                "key",
-                "value",
+                "paymentHash",
+                "bolt11",
             ),
         )
     ) {
         return null
     }
-    val key = metadataItem.getString("key")!!
-    val value = metadataItem.getString("value")!!
-    return MetadataItem(key, value)
+    val paymentHash = invoicePaidDetails.getString("paymentHash")!!
+    val bolt11 = invoicePaidDetails.getString("bolt11")!!
+    val payment = if (hasNonNullKey(invoicePaidDetails, "payment")) invoicePaidDetails.getMap("payment")?.let { asPayment(it) } else null
+    return InvoicePaidDetails(paymentHash, bolt11, payment)
 }
 
-fun readableMapOf(metadataItem: MetadataItem): ReadableMap =
+fun readableMapOf(invoicePaidDetails: InvoicePaidDetails): ReadableMap =
     readableMapOf(
-        "key" to metadataItem.key,
-        "value" to metadataItem.value,
+        "paymentHash" to invoicePaidDetails.paymentHash,
+        "bolt11" to invoicePaidDetails.bolt11,
+        "payment" to invoicePaidDetails.payment?.let { readableMapOf(it) },
     )
 
-fun asMetadataItemList(arr: ReadableArray): List<MetadataItem> {
-    val list = ArrayList<MetadataItem>()
+fun asInvoicePaidDetailsList(arr: ReadableArray): List<InvoicePaidDetails> {
+    val list = ArrayList<InvoicePaidDetails>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asMetadataItem(value)!!)
+            is ReadableMap -> list.add(asInvoicePaidDetails(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asNodeState(nodeState: ReadableMap): NodeState? {
+fun asInvoicePayability(invoicePayability: ReadableMap): InvoicePayability? {
     if (!validateMandatoryFields(
-            nodeState,
+            invoicePayability,
             arrayOf(
-                "id",
-                "blockHeight",
-                "channelsBalanceMsat",
-                "onchainBalanceMsat",
-                "pendingOnchainBalanceMsat",
-                "utxos",
+                "invoice",
+                "isPayable",
                 "maxPayableMsat",
-                "maxReceivableMsat",
-                "maxSinglePaymentAmountMsat",
-                "maxChanReserveMsats",
-                "connectedPeers",
-                "maxReceivableSinglePaymentAmountMsat",
-                "totalInboundLiquidityMsats",
             ),
         )
     ) {
         return null
     }
-    val id = nodeState.getString("id")!!
-    val blockHeight = nodeState.getInt("blockHeight").toUInt()
-    val channelsBalanceMsat = nodeState.getDouble("channelsBalanceMsat").toULong()
-    val onchainBalanceMsat = nodeState.getDouble("onchainBalanceMsat").toULong()
-    val pendingOnchainBalanceMsat = nodeState.getDouble("pendingOnchainBalanceMsat").toULong()
-    val utxos = nodeState.getArray("utxos")?.let { asUnspentTransactionOutputList(it) }!!
-    val maxPayableMsat = nodeState.getDouble("maxPayableMsat").toULong()
-    val maxReceivableMsat = nodeState.getDouble("maxReceivableMsat").toULong()
-    val maxSinglePaymentAmountMsat = nodeState.getDouble("maxSinglePaymentAmountMsat").toULong()
-    val maxChanReserveMsats = nodeState.getDouble("maxChanReserveMsats").toULong()
-    val connectedPeers = nodeState.getArray("connectedPeers")?.let { asStringList(it) }!!
-    val maxReceivableSinglePaymentAmountMsat = nodeState.getDouble("maxReceivableSinglePaymentAmountMsat").toULong()
-    val totalInboundLiquidityMsats = nodeState.getDouble("totalInboundLiquidityMsats").toULong()
-    return NodeState(
-        id,
-        blockHeight,
-        channelsBalanceMsat,
-        onchainBalanceMsat,
-        pendingOnchainBalanceMsat,
-        utxos,
-        maxPayableMsat,
-        maxReceivableMsat,
-        maxSinglePaymentAmountMsat,
-        maxChanReserveMsats,
-        connectedPeers,
-        maxReceivableSinglePaymentAmountMsat,
-        totalInboundLiquidityMsats,
+    val invoice = invoicePayability.getMap("invoice")?.let { asLnInvoice(it) }!!
+    val amountMsat = if (hasNonNullKey(invoicePayability, "amountMsat")) invoicePayability.getDouble("amountMsat").toULong() else null
+    val isPayable = invoicePayability.getBoolean("isPayable")
+    val error = if (hasNonNullKey(invoicePayability, "error")) invoicePayability.getString("error") else null
+    val maxPayableMsat = invoicePayability.getDouble("maxPayableMsat").toULong()
+    val payeeInboundLiquidityMsat =
+        if (hasNonNullKey(
+                invoicePayability,
+                "payeeInboundLiquidityMsat",
+            )
+        ) {
+            invoicePayability.getDouble("payeeInboundLiquidityMsat").toULong()
+        } else {
+            null
+        }
+    val lspRouteHint =
+        if (hasNonNullKey(invoicePayability, "lspRouteHint")) {
+            invoicePayability.getMap("lspRouteHint")?.let {
+                asRouteHint(it)
+            }
+        } else {
+            null
+        }
+    return InvoicePayability(invoice, amountMsat, isPayable, error, maxPayableMsat, payeeInboundLiquidityMsat, lspRouteHint)
+}
+
+fun readableMapOf(invoicePayability: InvoicePayability): ReadableMap =
+    readableMapOf(
+        "invoice" to readableMapOf(invoicePayability.invoice),
+        "amountMsat" to invoicePayability.amountMsat,
+        "isPayable" to invoicePayability.isPayable,
+        "error" to invoicePayability.error,
+        "maxPayableMsat" to invoicePayability.maxPayableMsat,
+        "payeeInboundLiquidityMsat" to invoicePayability.payeeInboundLiquidityMsat,
+        "lspRouteHint" to invoicePayability.lspRouteHint?.let { readableMapOf(it) },
     )
+
+fun asInvoicePayabilityList(arr: ReadableArray): List<InvoicePayability> {
+    val list = ArrayList<InvoicePayability>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asInvoicePayability(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
 }
 
-fun readableMapOf(nodeState: NodeState): ReadableMap =
+fun asInvoiceVerificationResult(invoiceVerificationResult: ReadableMap): InvoiceVerificationResult? {
+    if (!validateMandatoryFields(
+            invoiceVerificationResult,
+            arrayOf(
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val bolt11 = invoiceVerificationResult.getString("bolt11")!!
+    val invoice =
+        if (hasNonNullKey(invoiceVerificationResult, "invoice")) {
+            invoiceVerificationResult.getMap("invoice")?.let {
+                asLnInvoice(it)
+            }
+        } else {
+            null
+        }
+    val error = if (hasNonNullKey(invoiceVerificationResult, "error")) invoiceVerificationResult.getString("error") else null
+    return InvoiceVerificationResult(bolt11, invoice, error)
+}
+
+fun readableMapOf(invoiceVerificationResult: InvoiceVerificationResult): ReadableMap =
     readableMapOf(
-        "id" to nodeState.id,
-        "blockHeight" to nodeState.blockHeight,
-        "channelsBalanceMsat" to nodeState.channelsBalanceMsat,
-        "onchainBalanceMsat" to nodeState.onchainBalanceMsat,
-        "pendingOnchainBalanceMsat" to nodeState.pendingOnchainBalanceMsat,
-        "utxos" to readableArrayOf(nodeState.utxos),
-        "maxPayableMsat" to nodeState.maxPayableMsat,
-        "maxReceivableMsat" to nodeState.maxReceivableMsat,
-        "maxSinglePaymentAmountMsat" to nodeState.maxSinglePaymentAmountMsat,
-        "maxChanReserveMsats" to nodeState.maxChanReserveMsats,
-        "connectedPeers" to readableArrayOf(nodeState.connectedPeers),
-        "maxReceivableSinglePaymentAmountMsat" to nodeState.maxReceivableSinglePaymentAmountMsat,
-        "totalInboundLiquidityMsats" to nodeState.totalInboundLiquidityMsats,
+        "bolt11" to invoiceVerificationResult.bolt11,
+        "invoice" to invoiceVerificationResult.invoice?.let { readableMapOf(it) },
+        "error" to invoiceVerificationResult.error,
     )
 
-fun asNodeStateList(arr: ReadableArray): List<NodeState> {
-    val list = ArrayList<NodeState>()
+fun asInvoiceVerificationResultList(arr: ReadableArray): List<InvoiceVerificationResult> {
+    val list = ArrayList<InvoiceVerificationResult>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asNodeState(value)!!)
+            is ReadableMap -> list.add(asInvoiceVerificationResult(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asOnchainPaymentLimitsResponse(onchainPaymentLimitsResponse: ReadableMap): OnchainPaymentLimitsResponse? {
+fun asLnInvoice(lnInvoice: ReadableMap): LnInvoice? {
     if (!validateMandatoryFields(
-            onchainPaymentLimitsResponse,
+            lnInvoice,
             arrayOf(
-                "minSat",
-                "maxSat",
-                "maxPayableSat",
+                "bolt11",
+                "network",
+                "payeePubkey",
+                "paymentHash",
+                "timestamp",
+                "expiry",
+                "expiresAt",
+                "isExpired",
+                "routingHints",
+                "paymentSecret",
+                "minFinalCltvExpiryDelta",
+                "features",
             ),
         )
     ) {
         return null
     }
-    val minSat = onchainPaymentLimitsResponse.getDouble("minSat").toULong()
-    val maxSat = onchainPaymentLimitsResponse.getDouble("maxSat").toULong()
-    val maxPayableSat = onchainPaymentLimitsResponse.getDouble("maxPayableSat").toULong()
-    return OnchainPaymentLimitsResponse(minSat, maxSat, maxPayableSat)
+    val bolt11 = lnInvoice.getString("bolt11")!!
+    val network = lnInvoice.getString("network")?.let { asNetwork(it) }!!
+    val payeePubkey = lnInvoice.getString("payeePubkey")!!
+    val paymentHash = lnInvoice.getString("paymentHash")!!
+    val description = if (hasNonNullKey(lnInvoice, "description")) lnInvoice.getString("description") else null
+    val descriptionHash = if (hasNonNullKey(lnInvoice, "descriptionHash")) lnInvoice.getString("descriptionHash") else null
+    val amountMsat = if (hasNonNullKey(lnInvoice, "amountMsat")) lnInvoice.getDouble("amountMsat").toULong() else null
+    val timestamp = lnInvoice.getDouble("timestamp").toULong()
+    val expiry = lnInvoice.getDouble("expiry").toULong()
+    val expiresAt = lnInvoice.getDouble("expiresAt").toULong()
+    val isExpired = lnInvoice.getBoolean("isExpired")
+    val routingHints = lnInvoice.getArray("routingHints")?.let { asRouteHintList(it) }!!
+    val paymentSecret = lnInvoice.getArray("paymentSecret")?.let { asUByteList(it) }!!
+    val minFinalCltvExpiryDelta = lnInvoice.getDouble("minFinalCltvExpiryDelta").toULong()
+    val features = lnInvoice.getMap("features")?.let { asInvoiceFeatures(it) }!!
+    return LnInvoice(
+        bolt11,
+        network,
+        payeePubkey,
+        paymentHash,
+        description,
+        descriptionHash,
+        amountMsat,
+        timestamp,
+        expiry,
+        expiresAt,
+        isExpired,
+        routingHints,
+        paymentSecret,
+        minFinalCltvExpiryDelta,
+        features,
+    )
 }
 
-fun readableMapOf(onchainPaymentLimitsResponse: OnchainPaymentLimitsResponse): ReadableMap =
+fun readableMapOf(lnInvoice: LnInvoice): ReadableMap =
     readableMapOf(
-        "minSat" to onchainPaymentLimitsResponse.minSat,
-        "maxSat" to onchainPaymentLimitsResponse.maxSat,
-        "maxPayableSat" to onchainPaymentLimitsResponse.maxPayableSat,
+        "bolt11" to lnInvoice.bolt11,
+        "network" to lnInvoice.network.name.lowercase(),
+        "payeePubkey" to lnInvoice.payeePubkey,
+        "paymentHash" to lnInvoice.paymentHash,
+        "description" to lnInvoice.description,
+        "descriptionHash" to lnInvoice.descriptionHash,
+        "amountMsat" to lnInvoice.amountMsat,
+        "timestamp" to lnInvoice.timestamp,
+        "expiry" to lnInvoice.expiry,
+        "expiresAt" to lnInvoice.expiresAt,
+        "isExpired" to lnInvoice.isExpired,
+        "routingHints" to readableArrayOf(lnInvoice.routingHints),
+        "paymentSecret" to readableArrayOf(lnInvoice.paymentSecret),
+        "minFinalCltvExpiryDelta" to lnInvoice.minFinalCltvExpiryDelta,
+        "features" to readableMapOf(lnInvoice.features),
     )
 
-fun asOnchainPaymentLimitsResponseList(arr: ReadableArray): List<OnchainPaymentLimitsResponse> {
-    val list = ArrayList<OnchainPaymentLimitsResponse>()
+fun asLnInvoiceList(arr: ReadableArray): List<LnInvoice> {
+    val list = ArrayList<LnInvoice>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asOnchainPaymentLimitsResponse(value)!!)
+            is ReadableMap -> list.add(asLnInvoice(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asOpenChannelFeeRequest(openChannelFeeRequest: ReadableMap): OpenChannelFeeRequest? {
+fun asLnOffer(lnOffer: ReadableMap): LnOffer? {
     if (!validateMandatoryFields(
-            openChannelFeeRequest,
+            lnOffer,
+            arrayOf(
+                "offer",
+                "chains",
+                "paths",
+            ),
+        )
+    ) {
+        return null
+    }
+    val offer = lnOffer.getString("offer")!!
+    val chains = lnOffer.getArray("chains")?.let { asStringList(it) }!!
+    val minAmount = if (hasNonNullKey(lnOffer, "minAmount")) lnOffer.getMap("minAmount")?.let { asAmount(it) } else null
+    val description = if (hasNonNullKey(lnOffer, "description")) lnOffer.getString("description") else null
+    val absoluteExpiry = if (hasNonNullKey(lnOffer, "absoluteExpiry")) lnOffer.getDouble("absoluteExpiry").toULong() else null
+    val issuer = if (hasNonNullKey(lnOffer, "issuer")) lnOffer.getString("issuer") else null
+    val signingPubkey = if (hasNonNullKey(lnOffer, "signingPubkey")) lnOffer.getString("signingPubkey") else null
+    val paths = lnOffer.getArray("paths")?.let { asLnOfferBlindedPathList(it) }!!
+    return LnOffer(offer, chains, minAmount, description, absoluteExpiry, issuer, signingPubkey, paths)
+}
+
+fun readableMapOf(lnOffer: LnOffer): ReadableMap =
+    readableMapOf(
+        "offer" to lnOffer.offer,
+        "chains" to readableArrayOf(lnOffer.chains),
+        "minAmount" to lnOffer.minAmount?.let { readableMapOf(it) },
+        "description" to lnOffer.description,
+        "absoluteExpiry" to lnOffer.absoluteExpiry,
+        "issuer" to lnOffer.issuer,
+        "signingPubkey" to lnOffer.signingPubkey,
+        "paths" to readableArrayOf(lnOffer.paths),
+    )
+
+fun asLnOfferList(arr: ReadableArray): List<LnOffer> {
+    val list = ArrayList<LnOffer>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnOffer(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asListPaymentGroupsRequest(listPaymentGroupsRequest: ReadableMap): ListPaymentGroupsRequest? {
+    if (!validateMandatoryFields(
+            listPaymentGroupsRequest,
+            arrayOf(
+                "groupBy",
+                "payments",
+            ),
+        )
+    ) {
+        return null
+    }
+    val groupBy = listPaymentGroupsRequest.getString("groupBy")?.let { asPaymentGroupBy(it) }!!
+    val payments = listPaymentGroupsRequest.getMap("payments")?.let { asListPaymentsRequest(it) }!!
+    val limit = if (hasNonNullKey(listPaymentGroupsRequest, "limit")) listPaymentGroupsRequest.getInt("limit").toUInt() else null
+    return ListPaymentGroupsRequest(groupBy, payments, limit)
+}
+
+fun readableMapOf(listPaymentGroupsRequest: ListPaymentGroupsRequest): ReadableMap =
+    readableMapOf(
+        "groupBy" to listPaymentGroupsRequest.groupBy.name.lowercase(),
+        "payments" to readableMapOf(listPaymentGroupsRequest.payments),
+        "limit" to listPaymentGroupsRequest.limit,
+    )
+
+fun asListPaymentGroupsRequestList(arr: ReadableArray): List<ListPaymentGroupsRequest> {
+    val list = ArrayList<ListPaymentGroupsRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asListPaymentGroupsRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asListPaymentsRequest(listPaymentsRequest: ReadableMap): ListPaymentsRequest? {
+    if (!validateMandatoryFields(
+            listPaymentsRequest,
             arrayOf(),
         )
     ) {
         return null
     }
-    val amountMsat =
+    val filters =
+        if (hasNonNullKey(listPaymentsRequest, "filters")) {
+            listPaymentsRequest.getArray("filters")?.let {
+                asPaymentTypeFilterList(it)
+            }
+        } else {
+            null
+        }
+    val metadataFilters =
+        if (hasNonNullKey(listPaymentsRequest, "metadataFilters")) {
+            listPaymentsRequest.getArray("metadataFilters")?.let {
+                asMetadataFilterList(it)
+            }
+        } else {
+            null
+        }
+    val fromTimestamp =
         if (hasNonNullKey(
-                openChannelFeeRequest,
-                "amountMsat",
+                listPaymentsRequest,
+                "fromTimestamp",
             )
         ) {
-            openChannelFeeRequest.getDouble("amountMsat").toULong()
+            listPaymentsRequest.getDouble("fromTimestamp").toLong()
         } else {
             null
         }
-    val expiry = if (hasNonNullKey(openChannelFeeRequest, "expiry")) openChannelFeeRequest.getInt("expiry").toUInt() else null
-    return OpenChannelFeeRequest(amountMsat, expiry)
+    val toTimestamp = if (hasNonNullKey(listPaymentsRequest, "toTimestamp")) listPaymentsRequest.getDouble("toTimestamp").toLong() else null
+    val includeFailures =
+        if (hasNonNullKey(
+                listPaymentsRequest,
+                "includeFailures",
+            )
+        ) {
+            listPaymentsRequest.getBoolean("includeFailures")
+        } else {
+            null
+        }
+    val offset = if (hasNonNullKey(listPaymentsRequest, "offset")) listPaymentsRequest.getInt("offset").toUInt() else null
+    val limit = if (hasNonNullKey(listPaymentsRequest, "limit")) listPaymentsRequest.getInt("limit").toUInt() else null
+    val includeTlvHex = if (hasNonNullKey(listPaymentsRequest, "includeTlvHex")) listPaymentsRequest.getBoolean("includeTlvHex") else null
+    val sortOrder =
+        if (hasNonNullKey(listPaymentsRequest, "sortOrder")) {
+            listPaymentsRequest.getString("sortOrder")?.let {
+                asSortOrder(it)
+            }
+        } else {
+            null
+        }
+    return ListPaymentsRequest(
+        filters,
+        metadataFilters,
+        fromTimestamp,
+        toTimestamp,
+        includeFailures,
+        offset,
+        limit,
+        includeTlvHex,
+        sortOrder,
+    )
+}
+
+fun readableMapOf(listPaymentsRequest: ListPaymentsRequest): ReadableMap =
+    readableMapOf(
+        "filters" to listPaymentsRequest.filters?.let { readableArrayOf(it) },
+        "metadataFilters" to listPaymentsRequest.metadataFilters?.let { readableArrayOf(it) },
+        "fromTimestamp" to listPaymentsRequest.fromTimestamp,
+        "toTimestamp" to listPaymentsRequest.toTimestamp,
+        "includeFailures" to listPaymentsRequest.includeFailures,
+        "offset" to listPaymentsRequest.offset,
+        "limit" to listPaymentsRequest.limit,
+        "includeTlvHex" to listPaymentsRequest.includeTlvHex,
+        "sortOrder" to listPaymentsRequest.sortOrder?.let { it.name.lowercase() },
+    )
+
+fun asListPaymentsRequestList(arr: ReadableArray): List<ListPaymentsRequest> {
+    val list = ArrayList<ListPaymentsRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asListPaymentsRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asListSwapsRequest(listSwapsRequest: ReadableMap): ListSwapsRequest? {
+    if (!validateMandatoryFields(
+            listSwapsRequest,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val status = if (hasNonNullKey(listSwapsRequest, "status")) listSwapsRequest.getArray("status")?.let { asSwapStatusList(it) } else null
+    val fromTimestamp = if (hasNonNullKey(listSwapsRequest, "fromTimestamp")) listSwapsRequest.getDouble("fromTimestamp").toLong() else null
+    val toTimestamp = if (hasNonNullKey(listSwapsRequest, "toTimestamp")) listSwapsRequest.getDouble("toTimestamp").toLong() else null
+    val offset = if (hasNonNullKey(listSwapsRequest, "offset")) listSwapsRequest.getInt("offset").toUInt() else null
+    val limit = if (hasNonNullKey(listSwapsRequest, "limit")) listSwapsRequest.getInt("limit").toUInt() else null
+    return ListSwapsRequest(status, fromTimestamp, toTimestamp, offset, limit)
+}
+
+fun readableMapOf(listSwapsRequest: ListSwapsRequest): ReadableMap =
+    readableMapOf(
+        "status" to listSwapsRequest.status?.let { readableArrayOf(it) },
+        "fromTimestamp" to listSwapsRequest.fromTimestamp,
+        "toTimestamp" to listSwapsRequest.toTimestamp,
+        "offset" to listSwapsRequest.offset,
+        "limit" to listSwapsRequest.limit,
+    )
+
+fun asListSwapsRequestList(arr: ReadableArray): List<ListSwapsRequest> {
+    val list = ArrayList<ListSwapsRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asListSwapsRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnOfferBlindedPath(lnOfferBlindedPath: ReadableMap): LnOfferBlindedPath? {
+    if (!validateMandatoryFields(
+            lnOfferBlindedPath,
+            arrayOf(
+                "blindedHops",
+            ),
+        )
+    ) {
+        return null
+    }
+    val blindedHops = lnOfferBlindedPath.getArray("blindedHops")?.let { asStringList(it) }!!
+    return LnOfferBlindedPath(blindedHops)
+}
+
+fun readableMapOf(lnOfferBlindedPath: LnOfferBlindedPath): ReadableMap =
+    readableMapOf(
+        "blindedHops" to readableArrayOf(lnOfferBlindedPath.blindedHops),
+    )
+
+fun asLnOfferBlindedPathList(arr: ReadableArray): List<LnOfferBlindedPath> {
+    val list = ArrayList<LnOfferBlindedPath>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnOfferBlindedPath(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnPaymentDetails(lnPaymentDetails: ReadableMap): LnPaymentDetails? {
+    if (!validateMandatoryFields(
+            lnPaymentDetails,
+            arrayOf(
+                "paymentHash",
+                "label",
+                "destinationPubkey",
+                "paymentPreimage",
+                "keysend",
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val paymentHash = lnPaymentDetails.getString("paymentHash")!!
+    val label = lnPaymentDetails.getString("label")!!
+    val destinationPubkey = lnPaymentDetails.getString("destinationPubkey")!!
+    val paymentPreimage = lnPaymentDetails.getString("paymentPreimage")!!
+    val keysend = lnPaymentDetails.getBoolean("keysend")
+    val bolt11 = lnPaymentDetails.getString("bolt11")!!
+    val openChannelBolt11 =
+        if (hasNonNullKey(
+                lnPaymentDetails,
+                "openChannelBolt11",
+            )
+        ) {
+            lnPaymentDetails.getString("openChannelBolt11")
+        } else {
+            null
+        }
+    val channelOpeningFeeMsat =
+        if (hasNonNullKey(
+                lnPaymentDetails,
+                "channelOpeningFeeMsat",
+            )
+        ) {
+            lnPaymentDetails.getDouble("channelOpeningFeeMsat").toULong()
+        } else {
+            null
+        }
+    val lnurlSuccessAction =
+        if (hasNonNullKey(lnPaymentDetails, "lnurlSuccessAction")) {
+            lnPaymentDetails.getMap("lnurlSuccessAction")?.let {
+                asSuccessActionProcessed(it)
+            }
+        } else {
+            null
+        }
+    val lnurlPayDomain = if (hasNonNullKey(lnPaymentDetails, "lnurlPayDomain")) lnPaymentDetails.getString("lnurlPayDomain") else null
+    val lnurlPayComment = if (hasNonNullKey(lnPaymentDetails, "lnurlPayComment")) lnPaymentDetails.getString("lnurlPayComment") else null
+    val lnurlMetadata = if (hasNonNullKey(lnPaymentDetails, "lnurlMetadata")) lnPaymentDetails.getString("lnurlMetadata") else null
+    val lnAddress = if (hasNonNullKey(lnPaymentDetails, "lnAddress")) lnPaymentDetails.getString("lnAddress") else null
+    val lnurlWithdrawEndpoint =
+        if (hasNonNullKey(
+                lnPaymentDetails,
+                "lnurlWithdrawEndpoint",
+            )
+        ) {
+            lnPaymentDetails.getString("lnurlWithdrawEndpoint")
+        } else {
+            null
+        }
+    val swapInfo = if (hasNonNullKey(lnPaymentDetails, "swapInfo")) lnPaymentDetails.getMap("swapInfo")?.let { asSwapInfo(it) } else null
+    val reverseSwapInfo =
+        if (hasNonNullKey(lnPaymentDetails, "reverseSwapInfo")) {
+            lnPaymentDetails.getMap("reverseSwapInfo")?.let {
+                asReverseSwapInfo(it)
+            }
+        } else {
+            null
+        }
+    val pendingExpirationBlock =
+        if (hasNonNullKey(
+                lnPaymentDetails,
+                "pendingExpirationBlock",
+            )
+        ) {
+            lnPaymentDetails.getInt("pendingExpirationBlock").toUInt()
+        } else {
+            null
+        }
+    val tlvs = if (hasNonNullKey(lnPaymentDetails, "tlvs")) lnPaymentDetails.getArray("tlvs")?.let { asTlvRecordList(it) } else null
+    val spontaneousMetadata =
+        if (hasNonNullKey(
+                lnPaymentDetails,
+                "spontaneousMetadata",
+            )
+        ) {
+            lnPaymentDetails.getMap("spontaneousMetadata")?.let {
+                asSpontaneousPaymentMetadata(it)
+            }
+        } else {
+            null
+        }
+    return LnPaymentDetails(
+        paymentHash,
+        label,
+        destinationPubkey,
+        paymentPreimage,
+        keysend,
+        bolt11,
+        openChannelBolt11,
+        channelOpeningFeeMsat,
+        lnurlSuccessAction,
+        lnurlPayDomain,
+        lnurlPayComment,
+        lnurlMetadata,
+        lnAddress,
+        lnurlWithdrawEndpoint,
+        swapInfo,
+        reverseSwapInfo,
+        pendingExpirationBlock,
+        tlvs,
+        spontaneousMetadata,
+    )
+}
+
+fun readableMapOf(lnPaymentDetails: LnPaymentDetails): ReadableMap =
+    readableMapOf(
+        "paymentHash" to lnPaymentDetails.paymentHash,
+        "label" to lnPaymentDetails.label,
+        "destinationPubkey" to lnPaymentDetails.destinationPubkey,
+        "paymentPreimage" to lnPaymentDetails.paymentPreimage,
+        "keysend" to lnPaymentDetails.keysend,
+        "bolt11" to lnPaymentDetails.bolt11,
+        "openChannelBolt11" to lnPaymentDetails.openChannelBolt11,
+        "channelOpeningFeeMsat" to lnPaymentDetails.channelOpeningFeeMsat,
+        "lnurlSuccessAction" to lnPaymentDetails.lnurlSuccessAction?.let { readableMapOf(it) },
+        "lnurlPayDomain" to lnPaymentDetails.lnurlPayDomain,
+        "lnurlPayComment" to lnPaymentDetails.lnurlPayComment,
+        "lnurlMetadata" to lnPaymentDetails.lnurlMetadata,
+        "lnAddress" to lnPaymentDetails.lnAddress,
+        "lnurlWithdrawEndpoint" to lnPaymentDetails.lnurlWithdrawEndpoint,
+        "swapInfo" to lnPaymentDetails.swapInfo?.let { readableMapOf(it) },
+        "reverseSwapInfo" to lnPaymentDetails.reverseSwapInfo?.let { readableMapOf(it) },
+        "pendingExpirationBlock" to lnPaymentDetails.pendingExpirationBlock,
+        "tlvs" to lnPaymentDetails.tlvs?.let { readableArrayOf(it) },
+        "spontaneousMetadata" to lnPaymentDetails.spontaneousMetadata?.let { readableMapOf(it) },
+    )
+
+fun asLnPaymentDetailsList(arr: ReadableArray): List<LnPaymentDetails> {
+    val list = ArrayList<LnPaymentDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnPaymentDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlAuthDomain(lnUrlAuthDomain: ReadableMap): LnUrlAuthDomain? {
+    if (!validateMandatoryFields(
+            lnUrlAuthDomain,
+            arrayOf(
+                "domain",
+                "linkingKey",
+                "firstAuthenticatedAt",
+                "lastAuthenticatedAt",
+                "authCount",
+            ),
+        )
+    ) {
+        return null
+    }
+    val domain = lnUrlAuthDomain.getString("domain")!!
+    val linkingKey = lnUrlAuthDomain.getString("linkingKey")!!
+    val firstAuthenticatedAt = lnUrlAuthDomain.getDouble("firstAuthenticatedAt").toLong()
+    val lastAuthenticatedAt = lnUrlAuthDomain.getDouble("lastAuthenticatedAt").toLong()
+    val authCount = lnUrlAuthDomain.getInt("authCount").toUInt()
+    return LnUrlAuthDomain(domain, linkingKey, firstAuthenticatedAt, lastAuthenticatedAt, authCount)
+}
+
+fun readableMapOf(lnUrlAuthDomain: LnUrlAuthDomain): ReadableMap =
+    readableMapOf(
+        "domain" to lnUrlAuthDomain.domain,
+        "linkingKey" to lnUrlAuthDomain.linkingKey,
+        "firstAuthenticatedAt" to lnUrlAuthDomain.firstAuthenticatedAt,
+        "lastAuthenticatedAt" to lnUrlAuthDomain.lastAuthenticatedAt,
+        "authCount" to lnUrlAuthDomain.authCount,
+    )
+
+fun asLnUrlAuthDomainList(arr: ReadableArray): List<LnUrlAuthDomain> {
+    val list = ArrayList<LnUrlAuthDomain>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlAuthDomain(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlAuthExport(lnUrlAuthExport: ReadableMap): LnUrlAuthExport? {
+    if (!validateMandatoryFields(
+            lnUrlAuthExport,
+            arrayOf(
+                "hashingKey",
+                "domains",
+            ),
+        )
+    ) {
+        return null
+    }
+    val hashingKey = lnUrlAuthExport.getString("hashingKey")!!
+    val domains = lnUrlAuthExport.getArray("domains")?.let { asLnUrlAuthDomainList(it) }!!
+    return LnUrlAuthExport(hashingKey, domains)
+}
+
+fun readableMapOf(lnUrlAuthExport: LnUrlAuthExport): ReadableMap =
+    readableMapOf(
+        "hashingKey" to lnUrlAuthExport.hashingKey,
+        "domains" to readableArrayOf(lnUrlAuthExport.domains),
+    )
+
+fun asLnUrlAuthExportList(arr: ReadableArray): List<LnUrlAuthExport> {
+    val list = ArrayList<LnUrlAuthExport>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlAuthExport(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlAuthRequestData(lnUrlAuthRequestData: ReadableMap): LnUrlAuthRequestData? {
+    if (!validateMandatoryFields(
+            lnUrlAuthRequestData,
+            arrayOf(
+                "k1",
+                "domain",
+                "url",
+            ),
+        )
+    ) {
+        return null
+    }
+    val k1 = lnUrlAuthRequestData.getString("k1")!!
+    val domain = lnUrlAuthRequestData.getString("domain")!!
+    val url = lnUrlAuthRequestData.getString("url")!!
+    val action = if (hasNonNullKey(lnUrlAuthRequestData, "action")) lnUrlAuthRequestData.getString("action") else null
+    return LnUrlAuthRequestData(k1, domain, url, action)
+}
+
+fun readableMapOf(lnUrlAuthRequestData: LnUrlAuthRequestData): ReadableMap =
+    readableMapOf(
+        "k1" to lnUrlAuthRequestData.k1,
+        "domain" to lnUrlAuthRequestData.domain,
+        "url" to lnUrlAuthRequestData.url,
+        "action" to lnUrlAuthRequestData.action,
+    )
+
+fun asLnUrlAuthRequestDataList(arr: ReadableArray): List<LnUrlAuthRequestData> {
+    val list = ArrayList<LnUrlAuthRequestData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlAuthRequestData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlErrorData(lnUrlErrorData: ReadableMap): LnUrlErrorData? {
+    if (!validateMandatoryFields(
+            lnUrlErrorData,
+            arrayOf(
+                "reason",
+            ),
+        )
+    ) {
+        return null
+    }
+    val reason = lnUrlErrorData.getString("reason")!!
+    return LnUrlErrorData(reason)
+}
+
+fun readableMapOf(lnUrlErrorData: LnUrlErrorData): ReadableMap =
+    readableMapOf(
+        "reason" to lnUrlErrorData.reason,
+    )
+
+fun asLnUrlErrorDataList(arr: ReadableArray): List<LnUrlErrorData> {
+    val list = ArrayList<LnUrlErrorData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlErrorData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlPayErrorData(lnUrlPayErrorData: ReadableMap): LnUrlPayErrorData? {
+    if (!validateMandatoryFields(
+            lnUrlPayErrorData,
+            arrayOf(
+                "paymentHash",
+                "reason",
+            ),
+        )
+    ) {
+        return null
+    }
+    val paymentHash = lnUrlPayErrorData.getString("paymentHash")!!
+    val reason = lnUrlPayErrorData.getString("reason")!!
+    return LnUrlPayErrorData(paymentHash, reason)
+}
+
+fun readableMapOf(lnUrlPayErrorData: LnUrlPayErrorData): ReadableMap =
+    readableMapOf(
+        "paymentHash" to lnUrlPayErrorData.paymentHash,
+        "reason" to lnUrlPayErrorData.reason,
+    )
+
+fun asLnUrlPayErrorDataList(arr: ReadableArray): List<LnUrlPayErrorData> {
+    val list = ArrayList<LnUrlPayErrorData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlPayErrorData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlPayFinishedDetails(lnUrlPayFinishedDetails: ReadableMap): LnUrlPayFinishedDetails? {
+    if (!validateMandatoryFields(
+            lnUrlPayFinishedDetails,
+            arrayOf(
+                "paymentHash",
+                "result",
+            ),
+        )
+    ) {
+        return null
+    }
+    val paymentHash = lnUrlPayFinishedDetails.getString("paymentHash")!!
+    val result = lnUrlPayFinishedDetails.getMap("result")?.let { asLnUrlPayResult(it) }!!
+    return LnUrlPayFinishedDetails(paymentHash, result)
+}
+
+fun readableMapOf(lnUrlPayFinishedDetails: LnUrlPayFinishedDetails): ReadableMap =
+    readableMapOf(
+        "paymentHash" to lnUrlPayFinishedDetails.paymentHash,
+        "result" to readableMapOf(lnUrlPayFinishedDetails.result),
+    )
+
+fun asLnUrlPayFinishedDetailsList(arr: ReadableArray): List<LnUrlPayFinishedDetails> {
+    val list = ArrayList<LnUrlPayFinishedDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlPayFinishedDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlPayPendingData(lnUrlPayPendingData: ReadableMap): LnUrlPayPendingData? {
+    if (!validateMandatoryFields(
+            lnUrlPayPendingData,
+            arrayOf(
+                "paymentHash",
+            ),
+        )
+    ) {
+        return null
+    }
+    val paymentHash = lnUrlPayPendingData.getString("paymentHash")!!
+    return LnUrlPayPendingData(paymentHash)
+}
+
+fun readableMapOf(lnUrlPayPendingData: LnUrlPayPendingData): ReadableMap =
+    readableMapOf(
+        "paymentHash" to lnUrlPayPendingData.paymentHash,
+    )
+
+fun asLnUrlPayPendingDataList(arr: ReadableArray): List<LnUrlPayPendingData> {
+    val list = ArrayList<LnUrlPayPendingData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlPayPendingData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlPayRequest(lnUrlPayRequest: ReadableMap): LnUrlPayRequest? {
+    if (!validateMandatoryFields(
+            lnUrlPayRequest,
+            arrayOf(
+                "data",
+                "amountMsat",
+                "useTrampoline",
+            ),
+        )
+    ) {
+        return null
+    }
+    val data = lnUrlPayRequest.getMap("data")?.let { asLnUrlPayRequestData(it) }!!
+    val amountMsat = lnUrlPayRequest.getDouble("amountMsat").toULong()
+    val useTrampoline = lnUrlPayRequest.getBoolean("useTrampoline")
+    val comment = if (hasNonNullKey(lnUrlPayRequest, "comment")) lnUrlPayRequest.getString("comment") else null
+    val paymentLabel = if (hasNonNullKey(lnUrlPayRequest, "paymentLabel")) lnUrlPayRequest.getString("paymentLabel") else null
+    val validateSuccessActionUrl =
+        if (hasNonNullKey(
+                lnUrlPayRequest,
+                "validateSuccessActionUrl",
+            )
+        ) {
+            lnUrlPayRequest.getBoolean("validateSuccessActionUrl")
+        } else {
+            null
+        }
+    val pendingTimeoutSec =
+        if (hasNonNullKey(
+                lnUrlPayRequest,
+                "pendingTimeoutSec",
+            )
+        ) {
+            lnUrlPayRequest.getInt("pendingTimeoutSec").toUInt()
+        } else {
+            null
+        }
+    val cancelToken = if (hasNonNullKey(lnUrlPayRequest, "cancelToken")) lnUrlPayRequest.getString("cancelToken") else null
+    val skipDenylist = if (hasNonNullKey(lnUrlPayRequest, "skipDenylist")) lnUrlPayRequest.getBoolean("skipDenylist") else null
+    return LnUrlPayRequest(
+        data,
+        amountMsat,
+        useTrampoline,
+        comment,
+        paymentLabel,
+        validateSuccessActionUrl,
+        pendingTimeoutSec,
+        cancelToken,
+        skipDenylist,
+    )
+}
+
+fun readableMapOf(lnUrlPayRequest: LnUrlPayRequest): ReadableMap =
+    readableMapOf(
+        "data" to readableMapOf(lnUrlPayRequest.data),
+        "amountMsat" to lnUrlPayRequest.amountMsat,
+        "useTrampoline" to lnUrlPayRequest.useTrampoline,
+        "comment" to lnUrlPayRequest.comment,
+        "paymentLabel" to lnUrlPayRequest.paymentLabel,
+        "validateSuccessActionUrl" to lnUrlPayRequest.validateSuccessActionUrl,
+        "pendingTimeoutSec" to lnUrlPayRequest.pendingTimeoutSec,
+        "cancelToken" to lnUrlPayRequest.cancelToken,
+        "skipDenylist" to lnUrlPayRequest.skipDenylist,
+    )
+
+fun asLnUrlPayRequestList(arr: ReadableArray): List<LnUrlPayRequest> {
+    val list = ArrayList<LnUrlPayRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlPayRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlPayRequestData(lnUrlPayRequestData: ReadableMap): LnUrlPayRequestData? {
+    if (!validateMandatoryFields(
+            lnUrlPayRequestData,
+            arrayOf(
+                "callback",
+                "minSendable",
+                "maxSendable",
+                "metadataStr",
+                "commentAllowed",
+                "domain",
+                "allowsNostr",
+            ),
+        )
+    ) {
+        return null
+    }
+    val callback = lnUrlPayRequestData.getString("callback")!!
+    val minSendable = lnUrlPayRequestData.getDouble("minSendable").toULong()
+    val maxSendable = lnUrlPayRequestData.getDouble("maxSendable").toULong()
+    val metadataStr = lnUrlPayRequestData.getString("metadataStr")!!
+    val commentAllowed = lnUrlPayRequestData.getInt("commentAllowed").toUShort()
+    val domain = lnUrlPayRequestData.getString("domain")!!
+    val allowsNostr = lnUrlPayRequestData.getBoolean("allowsNostr")
+    val nostrPubkey = if (hasNonNullKey(lnUrlPayRequestData, "nostrPubkey")) lnUrlPayRequestData.getString("nostrPubkey") else null
+    val lnAddress = if (hasNonNullKey(lnUrlPayRequestData, "lnAddress")) lnUrlPayRequestData.getString("lnAddress") else null
+    return LnUrlPayRequestData(
+        callback,
+        minSendable,
+        maxSendable,
+        metadataStr,
+        commentAllowed,
+        domain,
+        allowsNostr,
+        nostrPubkey,
+        lnAddress,
+    )
+}
+
+fun readableMapOf(lnUrlPayRequestData: LnUrlPayRequestData): ReadableMap =
+    readableMapOf(
+        "callback" to lnUrlPayRequestData.callback,
+        "minSendable" to lnUrlPayRequestData.minSendable,
+        "maxSendable" to lnUrlPayRequestData.maxSendable,
+        "metadataStr" to lnUrlPayRequestData.metadataStr,
+        "commentAllowed" to lnUrlPayRequestData.commentAllowed,
+        "domain" to lnUrlPayRequestData.domain,
+        "allowsNostr" to lnUrlPayRequestData.allowsNostr,
+        "nostrPubkey" to lnUrlPayRequestData.nostrPubkey,
+        "lnAddress" to lnUrlPayRequestData.lnAddress,
+    )
+
+fun asLnUrlPayRequestDataList(arr: ReadableArray): List<LnUrlPayRequestData> {
+    val list = ArrayList<LnUrlPayRequestData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlPayRequestData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlPaySuccessData(lnUrlPaySuccessData: ReadableMap): LnUrlPaySuccessData? {
+    if (!validateMandatoryFields(
+            lnUrlPaySuccessData,
+            arrayOf(
+                "payment",
+            ),
+        )
+    ) {
+        return null
+    }
+    val successAction =
+        if (hasNonNullKey(lnUrlPaySuccessData, "successAction")) {
+            lnUrlPaySuccessData.getMap("successAction")?.let {
+                asSuccessActionProcessed(it)
+            }
+        } else {
+            null
+        }
+    val payment = lnUrlPaySuccessData.getMap("payment")?.let { asPayment(it) }!!
+    val partnerFee =
+        if (hasNonNullKey(lnUrlPaySuccessData, "partnerFee")) {
+            lnUrlPaySuccessData.getMap("partnerFee")?.let {
+                asPartnerFeeDetails(it)
+            }
+        } else {
+            null
+        }
+    return LnUrlPaySuccessData(successAction, payment, partnerFee)
+}
+
+fun readableMapOf(lnUrlPaySuccessData: LnUrlPaySuccessData): ReadableMap =
+    readableMapOf(
+        "successAction" to lnUrlPaySuccessData.successAction?.let { readableMapOf(it) },
+        "payment" to readableMapOf(lnUrlPaySuccessData.payment),
+        "partnerFee" to lnUrlPaySuccessData.partnerFee?.let { readableMapOf(it) },
+    )
+
+fun asLnUrlPaySuccessDataList(arr: ReadableArray): List<LnUrlPaySuccessData> {
+    val list = ArrayList<LnUrlPaySuccessData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlPaySuccessData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlWithdrawRequest(lnUrlWithdrawRequest: ReadableMap): LnUrlWithdrawRequest? {
+    if (!validateMandatoryFields(
+            lnUrlWithdrawRequest,
+            arrayOf(
+                "data",
+                "amountMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val data = lnUrlWithdrawRequest.getMap("data")?.let { asLnUrlWithdrawRequestData(it) }!!
+    val amountMsat = lnUrlWithdrawRequest.getDouble("amountMsat").toULong()
+    val description = if (hasNonNullKey(lnUrlWithdrawRequest, "description")) lnUrlWithdrawRequest.getString("description") else null
+    return LnUrlWithdrawRequest(data, amountMsat, description)
+}
+
+fun readableMapOf(lnUrlWithdrawRequest: LnUrlWithdrawRequest): ReadableMap =
+    readableMapOf(
+        "data" to readableMapOf(lnUrlWithdrawRequest.data),
+        "amountMsat" to lnUrlWithdrawRequest.amountMsat,
+        "description" to lnUrlWithdrawRequest.description,
+    )
+
+fun asLnUrlWithdrawRequestList(arr: ReadableArray): List<LnUrlWithdrawRequest> {
+    val list = ArrayList<LnUrlWithdrawRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlWithdrawRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlWithdrawRequestData(lnUrlWithdrawRequestData: ReadableMap): LnUrlWithdrawRequestData? {
+    if (!validateMandatoryFields(
+            lnUrlWithdrawRequestData,
+            arrayOf(
+                "callback",
+                "k1",
+                "defaultDescription",
+                "minWithdrawable",
+                "maxWithdrawable",
+            ),
+        )
+    ) {
+        return null
+    }
+    val callback = lnUrlWithdrawRequestData.getString("callback")!!
+    val k1 = lnUrlWithdrawRequestData.getString("k1")!!
+    val defaultDescription = lnUrlWithdrawRequestData.getString("defaultDescription")!!
+    val minWithdrawable = lnUrlWithdrawRequestData.getDouble("minWithdrawable").toULong()
+    val maxWithdrawable = lnUrlWithdrawRequestData.getDouble("maxWithdrawable").toULong()
+    return LnUrlWithdrawRequestData(callback, k1, defaultDescription, minWithdrawable, maxWithdrawable)
+}
+
+fun readableMapOf(lnUrlWithdrawRequestData: LnUrlWithdrawRequestData): ReadableMap =
+    readableMapOf(
+        "callback" to lnUrlWithdrawRequestData.callback,
+        "k1" to lnUrlWithdrawRequestData.k1,
+        "defaultDescription" to lnUrlWithdrawRequestData.defaultDescription,
+        "minWithdrawable" to lnUrlWithdrawRequestData.minWithdrawable,
+        "maxWithdrawable" to lnUrlWithdrawRequestData.maxWithdrawable,
+    )
+
+fun asLnUrlWithdrawRequestDataList(arr: ReadableArray): List<LnUrlWithdrawRequestData> {
+    val list = ArrayList<LnUrlWithdrawRequestData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlWithdrawRequestData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnUrlWithdrawSuccessData(lnUrlWithdrawSuccessData: ReadableMap): LnUrlWithdrawSuccessData? {
+    if (!validateMandatoryFields(
+            lnUrlWithdrawSuccessData,
+            arrayOf(
+                "invoice",
+            ),
+        )
+    ) {
+        return null
+    }
+    val invoice = lnUrlWithdrawSuccessData.getMap("invoice")?.let { asLnInvoice(it) }!!
+    return LnUrlWithdrawSuccessData(invoice)
+}
+
+fun readableMapOf(lnUrlWithdrawSuccessData: LnUrlWithdrawSuccessData): ReadableMap =
+    readableMapOf(
+        "invoice" to readableMapOf(lnUrlWithdrawSuccessData.invoice),
+    )
+
+fun asLnUrlWithdrawSuccessDataList(arr: ReadableArray): List<LnUrlWithdrawSuccessData> {
+    val list = ArrayList<LnUrlWithdrawSuccessData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnUrlWithdrawSuccessData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnurlPayInfo(lnurlPayInfo: ReadableMap): LnurlPayInfo? {
+    if (!validateMandatoryFields(
+            lnurlPayInfo,
+            arrayOf(
+                "lnurl",
+                "lightningAddress",
+            ),
+        )
+    ) {
+        return null
+    }
+    val lnurl = lnurlPayInfo.getString("lnurl")!!
+    val lightningAddress = lnurlPayInfo.getString("lightningAddress")!!
+    return LnurlPayInfo(lnurl, lightningAddress)
+}
+
+fun readableMapOf(lnurlPayInfo: LnurlPayInfo): ReadableMap =
+    readableMapOf(
+        "lnurl" to lnurlPayInfo.lnurl,
+        "lightningAddress" to lnurlPayInfo.lightningAddress,
+    )
+
+fun asLnurlPayInfoList(arr: ReadableArray): List<LnurlPayInfo> {
+    val list = ArrayList<LnurlPayInfo>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnurlPayInfo(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLnurlWithdrawVoucher(lnurlWithdrawVoucher: ReadableMap): LnurlWithdrawVoucher? {
+    if (!validateMandatoryFields(
+            lnurlWithdrawVoucher,
+            arrayOf(
+                "id",
+                "lnurl",
+                "amountMsat",
+                "uses",
+                "redeemedUses",
+                "createdAt",
+                "expiresAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = lnurlWithdrawVoucher.getString("id")!!
+    val lnurl = lnurlWithdrawVoucher.getString("lnurl")!!
+    val amountMsat = lnurlWithdrawVoucher.getDouble("amountMsat").toULong()
+    val uses = lnurlWithdrawVoucher.getInt("uses").toUInt()
+    val redeemedUses = lnurlWithdrawVoucher.getInt("redeemedUses").toUInt()
+    val description = if (hasNonNullKey(lnurlWithdrawVoucher, "description")) lnurlWithdrawVoucher.getString("description") else null
+    val createdAt = lnurlWithdrawVoucher.getDouble("createdAt").toLong()
+    val expiresAt = lnurlWithdrawVoucher.getDouble("expiresAt").toLong()
+    return LnurlWithdrawVoucher(id, lnurl, amountMsat, uses, redeemedUses, description, createdAt, expiresAt)
+}
+
+fun readableMapOf(lnurlWithdrawVoucher: LnurlWithdrawVoucher): ReadableMap =
+    readableMapOf(
+        "id" to lnurlWithdrawVoucher.id,
+        "lnurl" to lnurlWithdrawVoucher.lnurl,
+        "amountMsat" to lnurlWithdrawVoucher.amountMsat,
+        "uses" to lnurlWithdrawVoucher.uses,
+        "redeemedUses" to lnurlWithdrawVoucher.redeemedUses,
+        "description" to lnurlWithdrawVoucher.description,
+        "createdAt" to lnurlWithdrawVoucher.createdAt,
+        "expiresAt" to lnurlWithdrawVoucher.expiresAt,
+    )
+
+fun asLnurlWithdrawVoucherList(arr: ReadableArray): List<LnurlWithdrawVoucher> {
+    val list = ArrayList<LnurlWithdrawVoucher>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLnurlWithdrawVoucher(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLocaleOverrides(localeOverrides: ReadableMap): LocaleOverrides? {
+    if (!validateMandatoryFields(
+            localeOverrides,
+            arrayOf(
+                "locale",
+                "symbol",
+            ),
+        )
+    ) {
+        return null
+    }
+    val locale = localeOverrides.getString("locale")!!
+    val spacing = if (hasNonNullKey(localeOverrides, "spacing")) localeOverrides.getInt("spacing").toUInt() else null
+    val symbol = localeOverrides.getMap("symbol")?.let { asSymbol(it) }!!
+    return LocaleOverrides(locale, spacing, symbol)
+}
+
+fun readableMapOf(localeOverrides: LocaleOverrides): ReadableMap =
+    readableMapOf(
+        "locale" to localeOverrides.locale,
+        "spacing" to localeOverrides.spacing,
+        "symbol" to readableMapOf(localeOverrides.symbol),
+    )
+
+fun asLocaleOverridesList(arr: ReadableArray): List<LocaleOverrides> {
+    val list = ArrayList<LocaleOverrides>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLocaleOverrides(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLocalizedName(localizedName: ReadableMap): LocalizedName? {
+    if (!validateMandatoryFields(
+            localizedName,
+            arrayOf(
+                "locale",
+                "name",
+            ),
+        )
+    ) {
+        return null
+    }
+    val locale = localizedName.getString("locale")!!
+    val name = localizedName.getString("name")!!
+    return LocalizedName(locale, name)
+}
+
+fun readableMapOf(localizedName: LocalizedName): ReadableMap =
+    readableMapOf(
+        "locale" to localizedName.locale,
+        "name" to localizedName.name,
+    )
+
+fun asLocalizedNameList(arr: ReadableArray): List<LocalizedName> {
+    val list = ArrayList<LocalizedName>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLocalizedName(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLogEntry(logEntry: ReadableMap): LogEntry? {
+    if (!validateMandatoryFields(
+            logEntry,
+            arrayOf(
+                "line",
+                "level",
+                "module",
+            ),
+        )
+    ) {
+        return null
+    }
+    val line = logEntry.getString("line")!!
+    val level = logEntry.getString("level")!!
+    val module = logEntry.getString("module")!!
+    return LogEntry(line, level, module)
+}
+
+fun readableMapOf(logEntry: LogEntry): ReadableMap =
+    readableMapOf(
+        "line" to logEntry.line,
+        "level" to logEntry.level,
+        "module" to logEntry.module,
+    )
+
+fun asLogEntryList(arr: ReadableArray): List<LogEntry> {
+    val list = ArrayList<LogEntry>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLogEntry(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLowLiquidityDetails(lowLiquidityDetails: ReadableMap): LowLiquidityDetails? {
+    if (!validateMandatoryFields(
+            lowLiquidityDetails,
+            arrayOf(
+                "liquidityMsat",
+                "thresholdMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val liquidityMsat = lowLiquidityDetails.getDouble("liquidityMsat").toULong()
+    val thresholdMsat = lowLiquidityDetails.getDouble("thresholdMsat").toULong()
+    return LowLiquidityDetails(liquidityMsat, thresholdMsat)
+}
+
+fun readableMapOf(lowLiquidityDetails: LowLiquidityDetails): ReadableMap =
+    readableMapOf(
+        "liquidityMsat" to lowLiquidityDetails.liquidityMsat,
+        "thresholdMsat" to lowLiquidityDetails.thresholdMsat,
+    )
+
+fun asLowLiquidityDetailsList(arr: ReadableArray): List<LowLiquidityDetails> {
+    val list = ArrayList<LowLiquidityDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLowLiquidityDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asLspInformation(lspInformation: ReadableMap): LspInformation? {
+    if (!validateMandatoryFields(
+            lspInformation,
+            arrayOf(
+                "id",
+                "name",
+                "widgetUrl",
+                "pubkey",
+                "host",
+                "baseFeeMsat",
+                "feeRate",
+                "timeLockDelta",
+                "minHtlcMsat",
+                "lspPubkey",
+                "openingFeeParamsList",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = lspInformation.getString("id")!!
+    val name = lspInformation.getString("name")!!
+    val widgetUrl = lspInformation.getString("widgetUrl")!!
+    val pubkey = lspInformation.getString("pubkey")!!
+    val host = lspInformation.getString("host")!!
+    val baseFeeMsat = lspInformation.getDouble("baseFeeMsat").toLong()
+    val feeRate = lspInformation.getDouble("feeRate")
+    val timeLockDelta = lspInformation.getInt("timeLockDelta").toUInt()
+    val minHtlcMsat = lspInformation.getDouble("minHtlcMsat").toLong()
+    val lspPubkey = lspInformation.getArray("lspPubkey")?.let { asUByteList(it) }!!
+    val openingFeeParamsList = lspInformation.getMap("openingFeeParamsList")?.let { asOpeningFeeParamsMenu(it) }!!
+    return LspInformation(
+        id,
+        name,
+        widgetUrl,
+        pubkey,
+        host,
+        baseFeeMsat,
+        feeRate,
+        timeLockDelta,
+        minHtlcMsat,
+        lspPubkey,
+        openingFeeParamsList,
+    )
+}
+
+fun readableMapOf(lspInformation: LspInformation): ReadableMap =
+    readableMapOf(
+        "id" to lspInformation.id,
+        "name" to lspInformation.name,
+        "widgetUrl" to lspInformation.widgetUrl,
+        "pubkey" to lspInformation.pubkey,
+        "host" to lspInformation.host,
+        "baseFeeMsat" to lspInformation.baseFeeMsat,
+        "feeRate" to lspInformation.feeRate,
+        "timeLockDelta" to lspInformation.timeLockDelta,
+        "minHtlcMsat" to lspInformation.minHtlcMsat,
+        "lspPubkey" to readableArrayOf(lspInformation.lspPubkey),
+        "openingFeeParamsList" to readableMapOf(lspInformation.openingFeeParamsList),
+    )
+
+fun asLspInformationList(arr: ReadableArray): List<LspInformation> {
+    val list = ArrayList<LspInformation>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLspInformation(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMessageSuccessActionData(messageSuccessActionData: ReadableMap): MessageSuccessActionData? {
+    if (!validateMandatoryFields(
+            messageSuccessActionData,
+            arrayOf(
+                "message",
+            ),
+        )
+    ) {
+        return null
+    }
+    val message = messageSuccessActionData.getString("message")!!
+    return MessageSuccessActionData(message)
+}
+
+fun readableMapOf(messageSuccessActionData: MessageSuccessActionData): ReadableMap =
+    readableMapOf(
+        "message" to messageSuccessActionData.message,
+    )
+
+fun asMessageSuccessActionDataList(arr: ReadableArray): List<MessageSuccessActionData> {
+    val list = ArrayList<MessageSuccessActionData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMessageSuccessActionData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMetadataFilter(metadataFilter: ReadableMap): MetadataFilter? {
+    if (!validateMandatoryFields(
+            metadataFilter,
+            arrayOf(
+                "jsonPath",
+            ),
+        )
+    ) {
+        return null
+    }
+    val jsonPath = metadataFilter.getString("jsonPath")!!
+    val jsonValue = if (hasNonNullKey(metadataFilter, "jsonValue")) metadataFilter.getString("jsonValue") else null
+    return MetadataFilter(jsonPath, jsonValue)
+}
+
+fun readableMapOf(metadataFilter: MetadataFilter): ReadableMap =
+    readableMapOf(
+        "jsonPath" to metadataFilter.jsonPath,
+        "jsonValue" to metadataFilter.jsonValue,
+    )
+
+fun asMetadataFilterList(arr: ReadableArray): List<MetadataFilter> {
+    val list = ArrayList<MetadataFilter>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMetadataFilter(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMetadataItem(metadataItem: ReadableMap): MetadataItem? {
+    if (!validateMandatoryFields(
+            metadataItem,
+            arrayOf(
+                "key",
+                "value",
+            ),
+        )
+    ) {
+        return null
+    }
+    val key = metadataItem.getString("key")!!
+    val value = metadataItem.getString("value")!!
+    return MetadataItem(key, value)
+}
+
+fun readableMapOf(metadataItem: MetadataItem): ReadableMap =
+    readableMapOf(
+        "key" to metadataItem.key,
+        "value" to metadataItem.value,
+    )
+
+fun asMetadataItemList(arr: ReadableArray): List<MetadataItem> {
+    val list = ArrayList<MetadataItem>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMetadataItem(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMetricBucket(metricBucket: ReadableMap): MetricBucket? {
+    if (!validateMandatoryFields(
+            metricBucket,
+            arrayOf(
+                "upperBound",
+                "cumulativeCount",
+            ),
+        )
+    ) {
+        return null
+    }
+    val upperBound = metricBucket.getDouble("upperBound")
+    val cumulativeCount = metricBucket.getDouble("cumulativeCount").toULong()
+    return MetricBucket(upperBound, cumulativeCount)
+}
+
+fun readableMapOf(metricBucket: MetricBucket): ReadableMap =
+    readableMapOf(
+        "upperBound" to metricBucket.upperBound,
+        "cumulativeCount" to metricBucket.cumulativeCount,
+    )
+
+fun asMetricBucketList(arr: ReadableArray): List<MetricBucket> {
+    val list = ArrayList<MetricBucket>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMetricBucket(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMetricCounter(metricCounter: ReadableMap): MetricCounter? {
+    if (!validateMandatoryFields(
+            metricCounter,
+            arrayOf(
+                "name",
+                "help",
+                "value",
+            ),
+        )
+    ) {
+        return null
+    }
+    val name = metricCounter.getString("name")!!
+    val help = metricCounter.getString("help")!!
+    val value = metricCounter.getDouble("value").toULong()
+    return MetricCounter(name, help, value)
+}
+
+fun readableMapOf(metricCounter: MetricCounter): ReadableMap =
+    readableMapOf(
+        "name" to metricCounter.name,
+        "help" to metricCounter.help,
+        "value" to metricCounter.value,
+    )
+
+fun asMetricCounterList(arr: ReadableArray): List<MetricCounter> {
+    val list = ArrayList<MetricCounter>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMetricCounter(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMetricHistogram(metricHistogram: ReadableMap): MetricHistogram? {
+    if (!validateMandatoryFields(
+            metricHistogram,
+            arrayOf(
+                "name",
+                "help",
+                "buckets",
+                "sum",
+                "count",
+            ),
+        )
+    ) {
+        return null
+    }
+    val name = metricHistogram.getString("name")!!
+    val help = metricHistogram.getString("help")!!
+    val buckets = metricHistogram.getArray("buckets")?.let { asMetricBucketList(it) }!!
+    val sum = metricHistogram.getDouble("sum")
+    val count = metricHistogram.getDouble("count").toULong()
+    return MetricHistogram(name, help, buckets, sum, count)
+}
+
+fun readableMapOf(metricHistogram: MetricHistogram): ReadableMap =
+    readableMapOf(
+        "name" to metricHistogram.name,
+        "help" to metricHistogram.help,
+        "buckets" to readableArrayOf(metricHistogram.buckets),
+        "sum" to metricHistogram.sum,
+        "count" to metricHistogram.count,
+    )
+
+fun asMetricHistogramList(arr: ReadableArray): List<MetricHistogram> {
+    val list = ArrayList<MetricHistogram>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMetricHistogram(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMetricsSnapshot(metricsSnapshot: ReadableMap): MetricsSnapshot? {
+    if (!validateMandatoryFields(
+            metricsSnapshot,
+            arrayOf(
+                "counters",
+                "histograms",
+            ),
+        )
+    ) {
+        return null
+    }
+    val counters = metricsSnapshot.getArray("counters")?.let { asMetricCounterList(it) }!!
+    val histograms = metricsSnapshot.getArray("histograms")?.let { asMetricHistogramList(it) }!!
+    return MetricsSnapshot(counters, histograms)
+}
+
+fun readableMapOf(metricsSnapshot: MetricsSnapshot): ReadableMap =
+    readableMapOf(
+        "counters" to readableArrayOf(metricsSnapshot.counters),
+        "histograms" to readableArrayOf(metricsSnapshot.histograms),
+    )
+
+fun asMetricsSnapshotList(arr: ReadableArray): List<MetricsSnapshot> {
+    val list = ArrayList<MetricsSnapshot>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMetricsSnapshot(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMigrationChannel(migrationChannel: ReadableMap): MigrationChannel? {
+    if (!validateMandatoryFields(
+            migrationChannel,
+            arrayOf(
+                "fundingTxid",
+                "state",
+                "localBalanceMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val fundingTxid = migrationChannel.getString("fundingTxid")!!
+    val fundingOutnum = if (hasNonNullKey(migrationChannel, "fundingOutnum")) migrationChannel.getInt("fundingOutnum").toUInt() else null
+    val shortChannelId = if (hasNonNullKey(migrationChannel, "shortChannelId")) migrationChannel.getString("shortChannelId") else null
+    val state = migrationChannel.getString("state")?.let { asChannelState(it) }!!
+    val localBalanceMsat = migrationChannel.getDouble("localBalanceMsat").toULong()
+    val peerId = if (hasNonNullKey(migrationChannel, "peerId")) migrationChannel.getString("peerId") else null
+    val capacityMsat = if (hasNonNullKey(migrationChannel, "capacityMsat")) migrationChannel.getDouble("capacityMsat").toULong() else null
+    return MigrationChannel(fundingTxid, fundingOutnum, shortChannelId, state, localBalanceMsat, peerId, capacityMsat)
+}
+
+fun readableMapOf(migrationChannel: MigrationChannel): ReadableMap =
+    readableMapOf(
+        "fundingTxid" to migrationChannel.fundingTxid,
+        "fundingOutnum" to migrationChannel.fundingOutnum,
+        "shortChannelId" to migrationChannel.shortChannelId,
+        "state" to migrationChannel.state.name.lowercase(),
+        "localBalanceMsat" to migrationChannel.localBalanceMsat,
+        "peerId" to migrationChannel.peerId,
+        "capacityMsat" to migrationChannel.capacityMsat,
+    )
+
+fun asMigrationChannelList(arr: ReadableArray): List<MigrationChannel> {
+    val list = ArrayList<MigrationChannel>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMigrationChannel(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMockNodeConfig(mockNodeConfig: ReadableMap): MockNodeConfig? {
+    if (!validateMandatoryFields(
+            mockNodeConfig,
+            arrayOf(
+                "channelsBalanceMsat",
+                "onchainBalanceMsat",
+                "inboundLiquidityMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val channelsBalanceMsat = mockNodeConfig.getDouble("channelsBalanceMsat").toULong()
+    val onchainBalanceMsat = mockNodeConfig.getDouble("onchainBalanceMsat").toULong()
+    val inboundLiquidityMsat = mockNodeConfig.getDouble("inboundLiquidityMsat").toULong()
+    return MockNodeConfig(channelsBalanceMsat, onchainBalanceMsat, inboundLiquidityMsat)
+}
+
+fun readableMapOf(mockNodeConfig: MockNodeConfig): ReadableMap =
+    readableMapOf(
+        "channelsBalanceMsat" to mockNodeConfig.channelsBalanceMsat,
+        "onchainBalanceMsat" to mockNodeConfig.onchainBalanceMsat,
+        "inboundLiquidityMsat" to mockNodeConfig.inboundLiquidityMsat,
+    )
+
+fun asMockNodeConfigList(arr: ReadableArray): List<MockNodeConfig> {
+    val list = ArrayList<MockNodeConfig>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asMockNodeConfig(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asNodeMigrationRequest(nodeMigrationRequest: ReadableMap): NodeMigrationRequest? {
+    if (!validateMandatoryFields(
+            nodeMigrationRequest,
+            arrayOf(
+                "closeChannels",
+            ),
+        )
+    ) {
+        return null
+    }
+    val closeChannels = nodeMigrationRequest.getBoolean("closeChannels")
+    return NodeMigrationRequest(closeChannels)
+}
+
+fun readableMapOf(nodeMigrationRequest: NodeMigrationRequest): ReadableMap =
+    readableMapOf(
+        "closeChannels" to nodeMigrationRequest.closeChannels,
+    )
+
+fun asNodeMigrationRequestList(arr: ReadableArray): List<NodeMigrationRequest> {
+    val list = ArrayList<NodeMigrationRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asNodeMigrationRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asNodeMigrationState(nodeMigrationState: ReadableMap): NodeMigrationState? {
+    if (!validateMandatoryFields(
+            nodeMigrationState,
+            arrayOf(
+                "nodeId",
+                "network",
+                "staticBackup",
+                "channels",
+                "closingTxids",
+                "channelsBalanceMsat",
+                "onchainBalanceMsat",
+                "exportedAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val nodeId = nodeMigrationState.getString("nodeId")!!
+    val network = nodeMigrationState.getString("network")?.let { asNetwork(it) }!!
+    val lspNodeUri = if (hasNonNullKey(nodeMigrationState, "lspNodeUri")) nodeMigrationState.getString("lspNodeUri") else null
+    val staticBackup = nodeMigrationState.getArray("staticBackup")?.let { asStringList(it) }!!
+    val channels = nodeMigrationState.getArray("channels")?.let { asMigrationChannelList(it) }!!
+    val closingTxids = nodeMigrationState.getArray("closingTxids")?.let { asStringList(it) }!!
+    val channelsBalanceMsat = nodeMigrationState.getDouble("channelsBalanceMsat").toULong()
+    val onchainBalanceMsat = nodeMigrationState.getDouble("onchainBalanceMsat").toULong()
+    val exportedAt = nodeMigrationState.getDouble("exportedAt").toULong()
+    return NodeMigrationState(
+        nodeId,
+        network,
+        lspNodeUri,
+        staticBackup,
+        channels,
+        closingTxids,
+        channelsBalanceMsat,
+        onchainBalanceMsat,
+        exportedAt,
+    )
+}
+
+fun readableMapOf(nodeMigrationState: NodeMigrationState): ReadableMap =
+    readableMapOf(
+        "nodeId" to nodeMigrationState.nodeId,
+        "network" to nodeMigrationState.network.name.lowercase(),
+        "lspNodeUri" to nodeMigrationState.lspNodeUri,
+        "staticBackup" to readableArrayOf(nodeMigrationState.staticBackup),
+        "channels" to readableArrayOf(nodeMigrationState.channels),
+        "closingTxids" to readableArrayOf(nodeMigrationState.closingTxids),
+        "channelsBalanceMsat" to nodeMigrationState.channelsBalanceMsat,
+        "onchainBalanceMsat" to nodeMigrationState.onchainBalanceMsat,
+        "exportedAt" to nodeMigrationState.exportedAt,
+    )
+
+fun asNodeMigrationStateList(arr: ReadableArray): List<NodeMigrationState> {
+    val list = ArrayList<NodeMigrationState>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asNodeMigrationState(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asNodeState(nodeState: ReadableMap): NodeState? {
+    if (!validateMandatoryFields(
+            nodeState,
+            arrayOf(
+                "id",
+                "blockHeight",
+                "channelsBalanceMsat",
+                "onchainBalanceMsat",
+                "pendingOnchainBalanceMsat",
+                "utxos",
+                "maxPayableMsat",
+                "maxReceivableMsat",
+                "maxSinglePaymentAmountMsat",
+                "maxChanReserveMsats",
+                "connectedPeers",
+                "maxReceivableSinglePaymentAmountMsat",
+                "totalInboundLiquidityMsats",
+                "pendingChannelOpensMsat",
+                "pendingChannelOpens",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = nodeState.getString("id")!!
+    val blockHeight = nodeState.getInt("blockHeight").toUInt()
+    val channelsBalanceMsat = nodeState.getDouble("channelsBalanceMsat").toULong()
+    val onchainBalanceMsat = nodeState.getDouble("onchainBalanceMsat").toULong()
+    val pendingOnchainBalanceMsat = nodeState.getDouble("pendingOnchainBalanceMsat").toULong()
+    val utxos = nodeState.getArray("utxos")?.let { asUnspentTransactionOutputList(it) }!!
+    val maxPayableMsat = nodeState.getDouble("maxPayableMsat").toULong()
+    val maxReceivableMsat = nodeState.getDouble("maxReceivableMsat").toULong()
+    val maxSinglePaymentAmountMsat = nodeState.getDouble("maxSinglePaymentAmountMsat").toULong()
+    val maxChanReserveMsats = nodeState.getDouble("maxChanReserveMsats").toULong()
+    val connectedPeers = nodeState.getArray("connectedPeers")?.let { asStringList(it) }!!
+    val maxReceivableSinglePaymentAmountMsat = nodeState.getDouble("maxReceivableSinglePaymentAmountMsat").toULong()
+    val totalInboundLiquidityMsats = nodeState.getDouble("totalInboundLiquidityMsats").toULong()
+    val pendingChannelOpensMsat = nodeState.getDouble("pendingChannelOpensMsat").toULong()
+    val pendingChannelOpens = nodeState.getArray("pendingChannelOpens")?.let { asPendingChannelOpenList(it) }!!
+    return NodeState(
+        id,
+        blockHeight,
+        channelsBalanceMsat,
+        onchainBalanceMsat,
+        pendingOnchainBalanceMsat,
+        utxos,
+        maxPayableMsat,
+        maxReceivableMsat,
+        maxSinglePaymentAmountMsat,
+        maxChanReserveMsats,
+        connectedPeers,
+        maxReceivableSinglePaymentAmountMsat,
+        totalInboundLiquidityMsats,
+        pendingChannelOpensMsat,
+        pendingChannelOpens,
+    )
+}
+
+fun readableMapOf(nodeState: NodeState): ReadableMap =
+    readableMapOf(
+        "id" to nodeState.id,
+        "blockHeight" to nodeState.blockHeight,
+        "channelsBalanceMsat" to nodeState.channelsBalanceMsat,
+        "onchainBalanceMsat" to nodeState.onchainBalanceMsat,
+        "pendingOnchainBalanceMsat" to nodeState.pendingOnchainBalanceMsat,
+        "utxos" to readableArrayOf(nodeState.utxos),
+        "maxPayableMsat" to nodeState.maxPayableMsat,
+        "maxReceivableMsat" to nodeState.maxReceivableMsat,
+        "maxSinglePaymentAmountMsat" to nodeState.maxSinglePaymentAmountMsat,
+        "maxChanReserveMsats" to nodeState.maxChanReserveMsats,
+        "connectedPeers" to readableArrayOf(nodeState.connectedPeers),
+        "maxReceivableSinglePaymentAmountMsat" to nodeState.maxReceivableSinglePaymentAmountMsat,
+        "totalInboundLiquidityMsats" to nodeState.totalInboundLiquidityMsats,
+        "pendingChannelOpensMsat" to nodeState.pendingChannelOpensMsat,
+        "pendingChannelOpens" to readableArrayOf(nodeState.pendingChannelOpens),
+    )
+
+fun asNodeStateList(arr: ReadableArray): List<NodeState> {
+    val list = ArrayList<NodeState>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asNodeState(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOnchainPaymentLimitsResponse(onchainPaymentLimitsResponse: ReadableMap): OnchainPaymentLimitsResponse? {
+    if (!validateMandatoryFields(
+            onchainPaymentLimitsResponse,
+            arrayOf(
+                "minSat",
+                "maxSat",
+                "maxPayableSat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val minSat = onchainPaymentLimitsResponse.getDouble("minSat").toULong()
+    val maxSat = onchainPaymentLimitsResponse.getDouble("maxSat").toULong()
+    val maxPayableSat = onchainPaymentLimitsResponse.getDouble("maxPayableSat").toULong()
+    return OnchainPaymentLimitsResponse(minSat, maxSat, maxPayableSat)
+}
+
+fun readableMapOf(onchainPaymentLimitsResponse: OnchainPaymentLimitsResponse): ReadableMap =
+    readableMapOf(
+        "minSat" to onchainPaymentLimitsResponse.minSat,
+        "maxSat" to onchainPaymentLimitsResponse.maxSat,
+        "maxPayableSat" to onchainPaymentLimitsResponse.maxPayableSat,
+    )
+
+fun asOnchainPaymentLimitsResponseList(arr: ReadableArray): List<OnchainPaymentLimitsResponse> {
+    val list = ArrayList<OnchainPaymentLimitsResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOnchainPaymentLimitsResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpenChannelFeeRequest(openChannelFeeRequest: ReadableMap): OpenChannelFeeRequest? {
+    if (!validateMandatoryFields(
+            openChannelFeeRequest,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val amountMsat =
+        if (hasNonNullKey(
+                openChannelFeeRequest,
+                "amountMsat",
+            )
+        ) {
+            openChannelFeeRequest.getDouble("amountMsat").toULong()
+        } else {
+            null
+        }
+    val expiry = if (hasNonNullKey(openChannelFeeRequest, "expiry")) openChannelFeeRequest.getInt("expiry").toUInt() else null
+    return OpenChannelFeeRequest(amountMsat, expiry)
+}
+
+fun readableMapOf(openChannelFeeRequest: OpenChannelFeeRequest): ReadableMap =
+    readableMapOf(
+        "amountMsat" to openChannelFeeRequest.amountMsat,
+        "expiry" to openChannelFeeRequest.expiry,
+    )
+
+fun asOpenChannelFeeRequestList(arr: ReadableArray): List<OpenChannelFeeRequest> {
+    val list = ArrayList<OpenChannelFeeRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpenChannelFeeRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpenChannelFeeResponse(openChannelFeeResponse: ReadableMap): OpenChannelFeeResponse? {
+    if (!validateMandatoryFields(
+            openChannelFeeResponse,
+            arrayOf(
+                "feeParams",
+            ),
+        )
+    ) {
+        return null
+    }
+    val feeMsat = if (hasNonNullKey(openChannelFeeResponse, "feeMsat")) openChannelFeeResponse.getDouble("feeMsat").toULong() else null
+    val feeParams = openChannelFeeResponse.getMap("feeParams")?.let { asOpeningFeeParams(it) }!!
+    return OpenChannelFeeResponse(feeMsat, feeParams)
+}
+
+fun readableMapOf(openChannelFeeResponse: OpenChannelFeeResponse): ReadableMap =
+    readableMapOf(
+        "feeMsat" to openChannelFeeResponse.feeMsat,
+        "feeParams" to readableMapOf(openChannelFeeResponse.feeParams),
+    )
+
+fun asOpenChannelFeeResponseList(arr: ReadableArray): List<OpenChannelFeeResponse> {
+    val list = ArrayList<OpenChannelFeeResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpenChannelFeeResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpenChannelReceiveDetails(openChannelReceiveDetails: ReadableMap): OpenChannelReceiveDetails? {
+    if (!validateMandatoryFields(
+            openChannelReceiveDetails,
+            arrayOf(
+                "paymentHash",
+                "stage",
+            ),
+        )
+    ) {
+        return null
+    }
+    val paymentHash = openChannelReceiveDetails.getString("paymentHash")!!
+    val stage = openChannelReceiveDetails.getMap("stage")?.let { asOpenChannelReceiveStage(it) }!!
+    return OpenChannelReceiveDetails(paymentHash, stage)
+}
+
+fun readableMapOf(openChannelReceiveDetails: OpenChannelReceiveDetails): ReadableMap =
+    readableMapOf(
+        "paymentHash" to openChannelReceiveDetails.paymentHash,
+        "stage" to readableMapOf(openChannelReceiveDetails.stage),
+    )
+
+fun asOpenChannelReceiveDetailsList(arr: ReadableArray): List<OpenChannelReceiveDetails> {
+    val list = ArrayList<OpenChannelReceiveDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpenChannelReceiveDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpenChannelRequest(openChannelRequest: ReadableMap): OpenChannelRequest? {
+    if (!validateMandatoryFields(
+            openChannelRequest,
+            arrayOf(
+                "nodeId",
+                "amountSat",
+                "announce",
+            ),
+        )
+    ) {
+        return null
+    }
+    val nodeId = openChannelRequest.getString("nodeId")!!
+    val amountSat = openChannelRequest.getDouble("amountSat").toULong()
+    val announce = openChannelRequest.getBoolean("announce")
+    val satPerVbyte = if (hasNonNullKey(openChannelRequest, "satPerVbyte")) openChannelRequest.getInt("satPerVbyte").toUInt() else null
+    return OpenChannelRequest(nodeId, amountSat, announce, satPerVbyte)
+}
+
+fun readableMapOf(openChannelRequest: OpenChannelRequest): ReadableMap =
+    readableMapOf(
+        "nodeId" to openChannelRequest.nodeId,
+        "amountSat" to openChannelRequest.amountSat,
+        "announce" to openChannelRequest.announce,
+        "satPerVbyte" to openChannelRequest.satPerVbyte,
+    )
+
+fun asOpenChannelRequestList(arr: ReadableArray): List<OpenChannelRequest> {
+    val list = ArrayList<OpenChannelRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpenChannelRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpenChannelResponse(openChannelResponse: ReadableMap): OpenChannelResponse? {
+    if (!validateMandatoryFields(
+            openChannelResponse,
+            arrayOf(
+                "channelId",
+                "fundingTxid",
+            ),
+        )
+    ) {
+        return null
+    }
+    val channelId = openChannelResponse.getString("channelId")!!
+    val fundingTxid = openChannelResponse.getString("fundingTxid")!!
+    return OpenChannelResponse(channelId, fundingTxid)
+}
+
+fun readableMapOf(openChannelResponse: OpenChannelResponse): ReadableMap =
+    readableMapOf(
+        "channelId" to openChannelResponse.channelId,
+        "fundingTxid" to openChannelResponse.fundingTxid,
+    )
+
+fun asOpenChannelResponseList(arr: ReadableArray): List<OpenChannelResponse> {
+    val list = ArrayList<OpenChannelResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpenChannelResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpeningFeeParams(openingFeeParams: ReadableMap): OpeningFeeParams? {
+    if (!validateMandatoryFields(
+            openingFeeParams,
+            arrayOf(
+                "minMsat",
+                "proportional",
+                "validUntil",
+                "maxIdleTime",
+                "maxClientToSelfDelay",
+                "promise",
+            ),
+        )
+    ) {
+        return null
+    }
+    val minMsat = openingFeeParams.getDouble("minMsat").toULong()
+    val proportional = openingFeeParams.getInt("proportional").toUInt()
+    val validUntil = openingFeeParams.getString("validUntil")!!
+    val maxIdleTime = openingFeeParams.getInt("maxIdleTime").toUInt()
+    val maxClientToSelfDelay = openingFeeParams.getInt("maxClientToSelfDelay").toUInt()
+    val promise = openingFeeParams.getString("promise")!!
+    return OpeningFeeParams(minMsat, proportional, validUntil, maxIdleTime, maxClientToSelfDelay, promise)
+}
+
+fun readableMapOf(openingFeeParams: OpeningFeeParams): ReadableMap =
+    readableMapOf(
+        "minMsat" to openingFeeParams.minMsat,
+        "proportional" to openingFeeParams.proportional,
+        "validUntil" to openingFeeParams.validUntil,
+        "maxIdleTime" to openingFeeParams.maxIdleTime,
+        "maxClientToSelfDelay" to openingFeeParams.maxClientToSelfDelay,
+        "promise" to openingFeeParams.promise,
+    )
+
+fun asOpeningFeeParamsList(arr: ReadableArray): List<OpeningFeeParams> {
+    val list = ArrayList<OpeningFeeParams>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpeningFeeParams(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOpeningFeeParamsMenu(openingFeeParamsMenu: ReadableMap): OpeningFeeParamsMenu? {
+    if (!validateMandatoryFields(
+            openingFeeParamsMenu,
+            arrayOf(
+                "values",
+            ),
+        )
+    ) {
+        return null
+    }
+    val values = openingFeeParamsMenu.getArray("values")?.let { asOpeningFeeParamsList(it) }!!
+    return OpeningFeeParamsMenu(values)
+}
+
+fun readableMapOf(openingFeeParamsMenu: OpeningFeeParamsMenu): ReadableMap =
+    readableMapOf(
+        "values" to readableArrayOf(openingFeeParamsMenu.values),
+    )
+
+fun asOpeningFeeParamsMenuList(arr: ReadableArray): List<OpeningFeeParamsMenu> {
+    val list = ArrayList<OpeningFeeParamsMenu>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpeningFeeParamsMenu(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOutboxPayment(outboxPayment: ReadableMap): OutboxPayment? {
+    if (!validateMandatoryFields(
+            outboxPayment,
+            arrayOf(
+                "id",
+                "req",
+                "createdAt",
+                "expiresAt",
+                "attempts",
+                "nextAttemptAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = outboxPayment.getDouble("id").toLong()
+    val req = outboxPayment.getMap("req")?.let { asSendPaymentRequest(it) }!!
+    val createdAt = outboxPayment.getDouble("createdAt").toLong()
+    val expiresAt = outboxPayment.getDouble("expiresAt").toLong()
+    val attempts = outboxPayment.getInt("attempts").toUInt()
+    val nextAttemptAt = outboxPayment.getDouble("nextAttemptAt").toLong()
+    val lastError = if (hasNonNullKey(outboxPayment, "lastError")) outboxPayment.getString("lastError") else null
+    return OutboxPayment(id, req, createdAt, expiresAt, attempts, nextAttemptAt, lastError)
+}
+
+fun readableMapOf(outboxPayment: OutboxPayment): ReadableMap =
+    readableMapOf(
+        "id" to outboxPayment.id,
+        "req" to readableMapOf(outboxPayment.req),
+        "createdAt" to outboxPayment.createdAt,
+        "expiresAt" to outboxPayment.expiresAt,
+        "attempts" to outboxPayment.attempts,
+        "nextAttemptAt" to outboxPayment.nextAttemptAt,
+        "lastError" to outboxPayment.lastError,
+    )
+
+fun asOutboxPaymentList(arr: ReadableArray): List<OutboxPayment> {
+    val list = ArrayList<OutboxPayment>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOutboxPayment(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOutboxPaymentDetails(outboxPaymentDetails: ReadableMap): OutboxPaymentDetails? {
+    if (!validateMandatoryFields(
+            outboxPaymentDetails,
+            arrayOf(
+                "outboxPayment",
+                "status",
+            ),
+        )
+    ) {
+        return null
+    }
+    val outboxPayment = outboxPaymentDetails.getMap("outboxPayment")?.let { asOutboxPayment(it) }!!
+    val status = outboxPaymentDetails.getMap("status")?.let { asOutboxPaymentStatus(it) }!!
+    return OutboxPaymentDetails(outboxPayment, status)
+}
+
+fun readableMapOf(outboxPaymentDetails: OutboxPaymentDetails): ReadableMap =
+    readableMapOf(
+        "outboxPayment" to readableMapOf(outboxPaymentDetails.outboxPayment),
+        "status" to readableMapOf(outboxPaymentDetails.status),
+    )
+
+fun asOutboxPaymentDetailsList(arr: ReadableArray): List<OutboxPaymentDetails> {
+    val list = ArrayList<OutboxPaymentDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOutboxPaymentDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPartnerFeeConfig(partnerFeeConfig: ReadableMap): PartnerFeeConfig? {
+    if (!validateMandatoryFields(
+            partnerFeeConfig,
+            arrayOf(
+                "feeBps",
+                "destination",
+            ),
+        )
+    ) {
+        return null
+    }
+    val feeBps = partnerFeeConfig.getInt("feeBps").toUInt()
+    val destination = partnerFeeConfig.getMap("destination")?.let { asPartnerFeeDestination(it) }!!
+    return PartnerFeeConfig(feeBps, destination)
+}
+
+fun readableMapOf(partnerFeeConfig: PartnerFeeConfig): ReadableMap =
+    readableMapOf(
+        "feeBps" to partnerFeeConfig.feeBps,
+        "destination" to readableMapOf(partnerFeeConfig.destination),
+    )
+
+fun asPartnerFeeConfigList(arr: ReadableArray): List<PartnerFeeConfig> {
+    val list = ArrayList<PartnerFeeConfig>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPartnerFeeConfig(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPartnerFeeDetails(partnerFeeDetails: ReadableMap): PartnerFeeDetails? {
+    if (!validateMandatoryFields(
+            partnerFeeDetails,
+            arrayOf(
+                "amountMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val amountMsat = partnerFeeDetails.getDouble("amountMsat").toULong()
+    val paymentId = if (hasNonNullKey(partnerFeeDetails, "paymentId")) partnerFeeDetails.getString("paymentId") else null
+    val error = if (hasNonNullKey(partnerFeeDetails, "error")) partnerFeeDetails.getString("error") else null
+    return PartnerFeeDetails(amountMsat, paymentId, error)
+}
+
+fun readableMapOf(partnerFeeDetails: PartnerFeeDetails): ReadableMap =
+    readableMapOf(
+        "amountMsat" to partnerFeeDetails.amountMsat,
+        "paymentId" to partnerFeeDetails.paymentId,
+        "error" to partnerFeeDetails.error,
+    )
+
+fun asPartnerFeeDetailsList(arr: ReadableArray): List<PartnerFeeDetails> {
+    val list = ArrayList<PartnerFeeDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPartnerFeeDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPayOfferRequest(payOfferRequest: ReadableMap): PayOfferRequest? {
+    if (!validateMandatoryFields(
+            payOfferRequest,
+            arrayOf(
+                "offer",
+            ),
+        )
+    ) {
+        return null
+    }
+    val offer = payOfferRequest.getString("offer")!!
+    val amountMsat = if (hasNonNullKey(payOfferRequest, "amountMsat")) payOfferRequest.getDouble("amountMsat").toULong() else null
+    val payerNote = if (hasNonNullKey(payOfferRequest, "payerNote")) payOfferRequest.getString("payerNote") else null
+    val label = if (hasNonNullKey(payOfferRequest, "label")) payOfferRequest.getString("label") else null
+    return PayOfferRequest(offer, amountMsat, payerNote, label)
+}
+
+fun readableMapOf(payOfferRequest: PayOfferRequest): ReadableMap =
+    readableMapOf(
+        "offer" to payOfferRequest.offer,
+        "amountMsat" to payOfferRequest.amountMsat,
+        "payerNote" to payOfferRequest.payerNote,
+        "label" to payOfferRequest.label,
+    )
+
+fun asPayOfferRequestList(arr: ReadableArray): List<PayOfferRequest> {
+    val list = ArrayList<PayOfferRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPayOfferRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPayOnchainAddressRequest(payOnchainAddressRequest: ReadableMap): PayOnchainAddressRequest? {
+    if (!validateMandatoryFields(
+            payOnchainAddressRequest,
+            arrayOf(
+                "address",
+                "drain",
+            ),
+        )
+    ) {
+        return null
+    }
+    val address = payOnchainAddressRequest.getString("address")!!
+    val amountSat =
+        if (hasNonNullKey(
+                payOnchainAddressRequest,
+                "amountSat",
+            )
+        ) {
+            payOnchainAddressRequest.getDouble("amountSat").toULong()
+        } else {
+            null
+        }
+    val drain = payOnchainAddressRequest.getBoolean("drain")
+    val feePreference =
+        if (hasNonNullKey(
+                payOnchainAddressRequest,
+                "feePreference",
+            )
+        ) {
+            payOnchainAddressRequest.getString("feePreference")?.let {
+                asFeeratePreset(it)
+            }
+        } else {
+            null
+        }
+    return PayOnchainAddressRequest(address, amountSat, drain, feePreference)
+}
+
+fun readableMapOf(payOnchainAddressRequest: PayOnchainAddressRequest): ReadableMap =
+    readableMapOf(
+        "address" to payOnchainAddressRequest.address,
+        "amountSat" to payOnchainAddressRequest.amountSat,
+        "drain" to payOnchainAddressRequest.drain,
+        "feePreference" to payOnchainAddressRequest.feePreference?.let { it.name.lowercase() },
+    )
+
+fun asPayOnchainAddressRequestList(arr: ReadableArray): List<PayOnchainAddressRequest> {
+    val list = ArrayList<PayOnchainAddressRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPayOnchainAddressRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPayOnchainAddressResponse(payOnchainAddressResponse: ReadableMap): PayOnchainAddressResponse? {
+    if (!validateMandatoryFields(
+            payOnchainAddressResponse,
+            arrayOf(
+                "prepareRes",
+                "claimTxFeerate",
+                "reverseSwapInfo",
+            ),
+        )
+    ) {
+        return null
+    }
+    val prepareRes = payOnchainAddressResponse.getMap("prepareRes")?.let { asPrepareOnchainPaymentResponse(it) }!!
+    val claimTxFeerate = payOnchainAddressResponse.getInt("claimTxFeerate").toUInt()
+    val reverseSwapInfo = payOnchainAddressResponse.getMap("reverseSwapInfo")?.let { asReverseSwapInfo(it) }!!
+    return PayOnchainAddressResponse(prepareRes, claimTxFeerate, reverseSwapInfo)
+}
+
+fun readableMapOf(payOnchainAddressResponse: PayOnchainAddressResponse): ReadableMap =
+    readableMapOf(
+        "prepareRes" to readableMapOf(payOnchainAddressResponse.prepareRes),
+        "claimTxFeerate" to payOnchainAddressResponse.claimTxFeerate,
+        "reverseSwapInfo" to readableMapOf(payOnchainAddressResponse.reverseSwapInfo),
+    )
+
+fun asPayOnchainAddressResponseList(arr: ReadableArray): List<PayOnchainAddressResponse> {
+    val list = ArrayList<PayOnchainAddressResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPayOnchainAddressResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPayOnchainRequest(payOnchainRequest: ReadableMap): PayOnchainRequest? {
+    if (!validateMandatoryFields(
+            payOnchainRequest,
+            arrayOf(
+                "recipientAddress",
+                "prepareRes",
+            ),
+        )
+    ) {
+        return null
+    }
+    val recipientAddress = payOnchainRequest.getString("recipientAddress")!!
+    val prepareRes = payOnchainRequest.getMap("prepareRes")?.let { asPrepareOnchainPaymentResponse(it) }!!
+    val cancelToken = if (hasNonNullKey(payOnchainRequest, "cancelToken")) payOnchainRequest.getString("cancelToken") else null
+    return PayOnchainRequest(recipientAddress, prepareRes, cancelToken)
+}
+
+fun readableMapOf(payOnchainRequest: PayOnchainRequest): ReadableMap =
+    readableMapOf(
+        "recipientAddress" to payOnchainRequest.recipientAddress,
+        "prepareRes" to readableMapOf(payOnchainRequest.prepareRes),
+        "cancelToken" to payOnchainRequest.cancelToken,
+    )
+
+fun asPayOnchainRequestList(arr: ReadableArray): List<PayOnchainRequest> {
+    val list = ArrayList<PayOnchainRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPayOnchainRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPayOnchainResponse(payOnchainResponse: ReadableMap): PayOnchainResponse? {
+    if (!validateMandatoryFields(
+            payOnchainResponse,
+            arrayOf(
+                "reverseSwapInfo",
+            ),
+        )
+    ) {
+        return null
+    }
+    val reverseSwapInfo = payOnchainResponse.getMap("reverseSwapInfo")?.let { asReverseSwapInfo(it) }!!
+    return PayOnchainResponse(reverseSwapInfo)
+}
+
+fun readableMapOf(payOnchainResponse: PayOnchainResponse): ReadableMap =
+    readableMapOf(
+        "reverseSwapInfo" to readableMapOf(payOnchainResponse.reverseSwapInfo),
+    )
+
+fun asPayOnchainResponseList(arr: ReadableArray): List<PayOnchainResponse> {
+    val list = ArrayList<PayOnchainResponse>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPayOnchainResponse(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPayment(payment: ReadableMap): Payment? {
+    if (!validateMandatoryFields(
+            payment,
+            arrayOf(
+                "id",
+                "paymentType",
+                "paymentTime",
+                "amountMsat",
+                "feeMsat",
+                "status",
+                "details",
+                "imported",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = payment.getString("id")!!
+    val paymentType = payment.getString("paymentType")?.let { asPaymentType(it) }!!
+    val paymentTime = payment.getDouble("paymentTime").toLong()
+    val amountMsat = payment.getDouble("amountMsat").toULong()
+    val feeMsat = payment.getDouble("feeMsat").toULong()
+    val status = payment.getString("status")?.let { asPaymentStatus(it) }!!
+    val error = if (hasNonNullKey(payment, "error")) payment.getString("error") else null
+    val description = if (hasNonNullKey(payment, "description")) payment.getString("description") else null
+    val details = payment.getMap("details")?.let { asPaymentDetails(it) }!!
+    val metadata = if (hasNonNullKey(payment, "metadata")) payment.getString("metadata") else null
+    val fiatRate = if (hasNonNullKey(payment, "fiatRate")) payment.getMap("fiatRate")?.let { asRate(it) } else null
+    val imported = payment.getBoolean("imported")
+    return Payment(id, paymentType, paymentTime, amountMsat, feeMsat, status, error, description, details, metadata, fiatRate, imported)
+}
+
+fun readableMapOf(payment: Payment): ReadableMap =
+    readableMapOf(
+        "id" to payment.id,
+        "paymentType" to payment.paymentType.name.lowercase(),
+        "paymentTime" to payment.paymentTime,
+        "amountMsat" to payment.amountMsat,
+        "feeMsat" to payment.feeMsat,
+        "status" to payment.status.name.lowercase(),
+        "error" to payment.error,
+        "description" to payment.description,
+        "details" to readableMapOf(payment.details),
+        "metadata" to payment.metadata,
+        "fiatRate" to payment.fiatRate?.let { readableMapOf(it) },
+        "imported" to payment.imported,
+    )
+
+fun asPaymentList(arr: ReadableArray): List<Payment> {
+    val list = ArrayList<Payment>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPayment(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPaymentFailedData(paymentFailedData: ReadableMap): PaymentFailedData? {
+    if (!validateMandatoryFields(
+            paymentFailedData,
+            arrayOf(
+                "error",
+                "nodeId",
+            ),
+        )
+    ) {
+        return null
+    }
+    val error = paymentFailedData.getString("error")!!
+    val nodeId = paymentFailedData.getString("nodeId")!!
+    val invoice = if (hasNonNullKey(paymentFailedData, "invoice")) paymentFailedData.getMap("invoice")?.let { asLnInvoice(it) } else null
+    val label = if (hasNonNullKey(paymentFailedData, "label")) paymentFailedData.getString("label") else null
+    return PaymentFailedData(error, nodeId, invoice, label)
+}
+
+fun readableMapOf(paymentFailedData: PaymentFailedData): ReadableMap =
+    readableMapOf(
+        "error" to paymentFailedData.error,
+        "nodeId" to paymentFailedData.nodeId,
+        "invoice" to paymentFailedData.invoice?.let { readableMapOf(it) },
+        "label" to paymentFailedData.label,
+    )
+
+fun asPaymentFailedDataList(arr: ReadableArray): List<PaymentFailedData> {
+    val list = ArrayList<PaymentFailedData>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPaymentFailedData(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPaymentGroup(paymentGroup: ReadableMap): PaymentGroup? {
+    if (!validateMandatoryFields(
+            paymentGroup,
+            arrayOf(
+                "key",
+                "paymentsCount",
+                "totalAmountMsat",
+                "totalFeeMsat",
+                "firstPaymentTime",
+                "lastPaymentTime",
+            ),
+        )
+    ) {
+        return null
+    }
+    val key = paymentGroup.getString("key")!!
+    val paymentsCount = paymentGroup.getInt("paymentsCount").toUInt()
+    val totalAmountMsat = paymentGroup.getDouble("totalAmountMsat").toULong()
+    val totalFeeMsat = paymentGroup.getDouble("totalFeeMsat").toULong()
+    val firstPaymentTime = paymentGroup.getDouble("firstPaymentTime").toLong()
+    val lastPaymentTime = paymentGroup.getDouble("lastPaymentTime").toLong()
+    return PaymentGroup(key, paymentsCount, totalAmountMsat, totalFeeMsat, firstPaymentTime, lastPaymentTime)
+}
+
+fun readableMapOf(paymentGroup: PaymentGroup): ReadableMap =
+    readableMapOf(
+        "key" to paymentGroup.key,
+        "paymentsCount" to paymentGroup.paymentsCount,
+        "totalAmountMsat" to paymentGroup.totalAmountMsat,
+        "totalFeeMsat" to paymentGroup.totalFeeMsat,
+        "firstPaymentTime" to paymentGroup.firstPaymentTime,
+        "lastPaymentTime" to paymentGroup.lastPaymentTime,
+    )
+
+fun asPaymentGroupList(arr: ReadableArray): List<PaymentGroup> {
+    val list = ArrayList<PaymentGroup>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPaymentGroup(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPaymentProbe(paymentProbe: ReadableMap): PaymentProbe? {
+    if (!validateMandatoryFields(
+            paymentProbe,
+            arrayOf(
+                "id",
+                "nodeId",
+                "amountMsat",
+                "routeFound",
+                "reachable",
+                "createdAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = paymentProbe.getDouble("id").toLong()
+    val nodeId = paymentProbe.getString("nodeId")!!
+    val bolt11 = if (hasNonNullKey(paymentProbe, "bolt11")) paymentProbe.getString("bolt11") else null
+    val amountMsat = paymentProbe.getDouble("amountMsat").toULong()
+    val routeFound = paymentProbe.getBoolean("routeFound")
+    val reachable = paymentProbe.getBoolean("reachable")
+    val estimatedFeeMsat =
+        if (hasNonNullKey(
+                paymentProbe,
+                "estimatedFeeMsat",
+            )
+        ) {
+            paymentProbe.getDouble("estimatedFeeMsat").toULong()
+        } else {
+            null
+        }
+    val hops = if (hasNonNullKey(paymentProbe, "hops")) paymentProbe.getInt("hops").toUInt() else null
+    val error = if (hasNonNullKey(paymentProbe, "error")) paymentProbe.getString("error") else null
+    val createdAt = paymentProbe.getDouble("createdAt").toLong()
+    return PaymentProbe(id, nodeId, bolt11, amountMsat, routeFound, reachable, estimatedFeeMsat, hops, error, createdAt)
+}
+
+fun readableMapOf(paymentProbe: PaymentProbe): ReadableMap =
+    readableMapOf(
+        "id" to paymentProbe.id,
+        "nodeId" to paymentProbe.nodeId,
+        "bolt11" to paymentProbe.bolt11,
+        "amountMsat" to paymentProbe.amountMsat,
+        "routeFound" to paymentProbe.routeFound,
+        "reachable" to paymentProbe.reachable,
+        "estimatedFeeMsat" to paymentProbe.estimatedFeeMsat,
+        "hops" to paymentProbe.hops,
+        "error" to paymentProbe.error,
+        "createdAt" to paymentProbe.createdAt,
+    )
+
+fun asPaymentProbeList(arr: ReadableArray): List<PaymentProbe> {
+    val list = ArrayList<PaymentProbe>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPaymentProbe(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPaymentRequestBundle(paymentRequestBundle: ReadableMap): PaymentRequestBundle? {
+    if (!validateMandatoryFields(
+            paymentRequestBundle,
+            arrayOf(
+                "invoice",
+            ),
+        )
+    ) {
+        return null
+    }
+    val invoice = paymentRequestBundle.getString("invoice")!!
+    val fallback = if (hasNonNullKey(paymentRequestBundle, "fallback")) paymentRequestBundle.getString("fallback") else null
+    val metadata = if (hasNonNullKey(paymentRequestBundle, "metadata")) paymentRequestBundle.getString("metadata") else null
+    return PaymentRequestBundle(invoice, fallback, metadata)
+}
+
+fun readableMapOf(paymentRequestBundle: PaymentRequestBundle): ReadableMap =
+    readableMapOf(
+        "invoice" to paymentRequestBundle.invoice,
+        "fallback" to paymentRequestBundle.fallback,
+        "metadata" to paymentRequestBundle.metadata,
+    )
+
+fun asPaymentRequestBundleList(arr: ReadableArray): List<PaymentRequestBundle> {
+    val list = ArrayList<PaymentRequestBundle>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPaymentRequestBundle(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPeerConnectivity(peerConnectivity: ReadableMap): PeerConnectivity? {
+    if (!validateMandatoryFields(
+            peerConnectivity,
+            arrayOf(
+                "lspConnected",
+                "reconnectionAttempts",
+                "failedReconnectionAttempts",
+            ),
+        )
+    ) {
+        return null
+    }
+    val lspPubkey = if (hasNonNullKey(peerConnectivity, "lspPubkey")) peerConnectivity.getString("lspPubkey") else null
+    val lspConnected = peerConnectivity.getBoolean("lspConnected")
+    val pingRttMs = if (hasNonNullKey(peerConnectivity, "pingRttMs")) peerConnectivity.getDouble("pingRttMs").toULong() else null
+    val pingError = if (hasNonNullKey(peerConnectivity, "pingError")) peerConnectivity.getString("pingError") else null
+    val reconnectionAttempts = peerConnectivity.getInt("reconnectionAttempts").toUInt()
+    val failedReconnectionAttempts = peerConnectivity.getInt("failedReconnectionAttempts").toUInt()
+    val lastReconnectedAt =
+        if (hasNonNullKey(
+                peerConnectivity,
+                "lastReconnectedAt",
+            )
+        ) {
+            peerConnectivity.getDouble("lastReconnectedAt").toLong()
+        } else {
+            null
+        }
+    val lastReconnectionError =
+        if (hasNonNullKey(
+                peerConnectivity,
+                "lastReconnectionError",
+            )
+        ) {
+            peerConnectivity.getString("lastReconnectionError")
+        } else {
+            null
+        }
+    return PeerConnectivity(
+        lspPubkey,
+        lspConnected,
+        pingRttMs,
+        pingError,
+        reconnectionAttempts,
+        failedReconnectionAttempts,
+        lastReconnectedAt,
+        lastReconnectionError,
+    )
+}
+
+fun readableMapOf(peerConnectivity: PeerConnectivity): ReadableMap =
+    readableMapOf(
+        "lspPubkey" to peerConnectivity.lspPubkey,
+        "lspConnected" to peerConnectivity.lspConnected,
+        "pingRttMs" to peerConnectivity.pingRttMs,
+        "pingError" to peerConnectivity.pingError,
+        "reconnectionAttempts" to peerConnectivity.reconnectionAttempts,
+        "failedReconnectionAttempts" to peerConnectivity.failedReconnectionAttempts,
+        "lastReconnectedAt" to peerConnectivity.lastReconnectedAt,
+        "lastReconnectionError" to peerConnectivity.lastReconnectionError,
+    )
+
+fun asPeerConnectivityList(arr: ReadableArray): List<PeerConnectivity> {
+    val list = ArrayList<PeerConnectivity>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPeerConnectivity(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPendingChannelOpen(pendingChannelOpen: ReadableMap): PendingChannelOpen? {
+    if (!validateMandatoryFields(
+            pendingChannelOpen,
+            arrayOf(
+                "fundingTxid",
+                "amountMsat",
+                "seenAt",
+                "expectedConfirmationAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val fundingTxid = pendingChannelOpen.getString("fundingTxid")!!
+    val amountMsat = pendingChannelOpen.getDouble("amountMsat").toULong()
+    val seenAt = pendingChannelOpen.getDouble("seenAt").toULong()
+    val expectedConfirmationAt = pendingChannelOpen.getDouble("expectedConfirmationAt").toULong()
+    return PendingChannelOpen(fundingTxid, amountMsat, seenAt, expectedConfirmationAt)
+}
+
+fun readableMapOf(pendingChannelOpen: PendingChannelOpen): ReadableMap =
+    readableMapOf(
+        "fundingTxid" to pendingChannelOpen.fundingTxid,
+        "amountMsat" to pendingChannelOpen.amountMsat,
+        "seenAt" to pendingChannelOpen.seenAt,
+        "expectedConfirmationAt" to pendingChannelOpen.expectedConfirmationAt,
+    )
+
+fun asPendingChannelOpenList(arr: ReadableArray): List<PendingChannelOpen> {
+    val list = ArrayList<PendingChannelOpen>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPendingChannelOpen(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPrepareOnchainPaymentRequest(prepareOnchainPaymentRequest: ReadableMap): PrepareOnchainPaymentRequest? {
+    if (!validateMandatoryFields(
+            prepareOnchainPaymentRequest,
+            arrayOf(
+                "amountSat",
+                "amountType",
+                "claimTxFeerate",
+            ),
+        )
+    ) {
+        return null
+    }
+    val amountSat = prepareOnchainPaymentRequest.getDouble("amountSat").toULong()
+    val amountType = prepareOnchainPaymentRequest.getString("amountType")?.let { asSwapAmountType(it) }!!
+    val claimTxFeerate = prepareOnchainPaymentRequest.getInt("claimTxFeerate").toUInt()
+    return PrepareOnchainPaymentRequest(amountSat, amountType, claimTxFeerate)
+}
+
+fun readableMapOf(prepareOnchainPaymentRequest: PrepareOnchainPaymentRequest): ReadableMap =
+    readableMapOf(
+        "amountSat" to prepareOnchainPaymentRequest.amountSat,
+        "amountType" to prepareOnchainPaymentRequest.amountType.name.lowercase(),
+        "claimTxFeerate" to prepareOnchainPaymentRequest.claimTxFeerate,
+    )
+
+fun asPrepareOnchainPaymentRequestList(arr: ReadableArray): List<PrepareOnchainPaymentRequest> {
+    val list = ArrayList<PrepareOnchainPaymentRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPrepareOnchainPaymentRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPrepareOnchainPaymentResponse(prepareOnchainPaymentResponse: ReadableMap): PrepareOnchainPaymentResponse? {
+    if (!validateMandatoryFields(
+            prepareOnchainPaymentResponse,
+            arrayOf(
+                "feesHash",
+                "feesPercentage",
+                "feesLockup",
+                "feesClaim",
+                "senderAmountSat",
+                "recipientAmountSat",
+                "totalFees",
+            ),
+        )
+    ) {
+        return null
+    }
+    val feesHash = prepareOnchainPaymentResponse.getString("feesHash")!!
+    val feesPercentage = prepareOnchainPaymentResponse.getDouble("feesPercentage")
+    val feesLockup = prepareOnchainPaymentResponse.getDouble("feesLockup").toULong()
+    val feesClaim = prepareOnchainPaymentResponse.getDouble("feesClaim").toULong()
+    val senderAmountSat = prepareOnchainPaymentResponse.getDouble("senderAmountSat").toULong()
+    val recipientAmountSat = prepareOnchainPaymentResponse.getDouble("recipientAmountSat").toULong()
+    val totalFees = prepareOnchainPaymentResponse.getDouble("totalFees").toULong()
+    return PrepareOnchainPaymentResponse(feesHash, feesPercentage, feesLockup, feesClaim, senderAmountSat, recipientAmountSat, totalFees)
 }
 
-fun readableMapOf(openChannelFeeRequest: OpenChannelFeeRequest): ReadableMap =
+fun readableMapOf(prepareOnchainPaymentResponse: PrepareOnchainPaymentResponse): ReadableMap =
     readableMapOf(
-        "amountMsat" to openChannelFeeRequest.amountMsat,
-        "expiry" to openChannelFeeRequest.expiry,
+        "feesHash" to prepareOnchainPaymentResponse.feesHash,
+        "feesPercentage" to prepareOnchainPaymentResponse.feesPercentage,
+        "feesLockup" to prepareOnchainPaymentResponse.feesLockup,
+        "feesClaim" to prepareOnchainPaymentResponse.feesClaim,
+        "senderAmountSat" to prepareOnchainPaymentResponse.senderAmountSat,
+        "recipientAmountSat" to prepareOnchainPaymentResponse.recipientAmountSat,
+        "totalFees" to prepareOnchainPaymentResponse.totalFees,
     )
 
-fun asOpenChannelFeeRequestList(arr: ReadableArray): List<OpenChannelFeeRequest> {
-    val list = ArrayList<OpenChannelFeeRequest>()
+fun asPrepareOnchainPaymentResponseList(arr: ReadableArray): List<PrepareOnchainPaymentResponse> {
+    val list = ArrayList<PrepareOnchainPaymentResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asOpenChannelFeeRequest(value)!!)
+            is ReadableMap -> list.add(asPrepareOnchainPaymentResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asOpenChannelFeeResponse(openChannelFeeResponse: ReadableMap): OpenChannelFeeResponse? {
+fun asPrepareReceivePaymentRequest(prepareReceivePaymentRequest: ReadableMap): PrepareReceivePaymentRequest? {
     if (!validateMandatoryFields(
-            openChannelFeeResponse,
+            prepareReceivePaymentRequest,
             arrayOf(
-                "feeParams",
+                "amountMsat",
             ),
         )
     ) {
         return null
     }
-    val feeMsat = if (hasNonNullKey(openChannelFeeResponse, "feeMsat")) openChannelFeeResponse.getDouble("feeMsat").toULong() else null
-    val feeParams = openChannelFeeResponse.getMap("feeParams")?.let { asOpeningFeeParams(it) }!!
-    return OpenChannelFeeResponse(feeMsat, feeParams)
+    val amountMsat = prepareReceivePaymentRequest.getDouble("amountMsat").toULong()
+    val openingFeeParams =
+        if (hasNonNullKey(
+                prepareReceivePaymentRequest,
+                "openingFeeParams",
+            )
+        ) {
+            prepareReceivePaymentRequest.getMap("openingFeeParams")?.let {
+                asOpeningFeeParams(it)
+            }
+        } else {
+            null
+        }
+    val expiry = if (hasNonNullKey(prepareReceivePaymentRequest, "expiry")) prepareReceivePaymentRequest.getInt("expiry").toUInt() else null
+    return PrepareReceivePaymentRequest(amountMsat, openingFeeParams, expiry)
 }
 
-fun readableMapOf(openChannelFeeResponse: OpenChannelFeeResponse): ReadableMap =
+fun readableMapOf(prepareReceivePaymentRequest: PrepareReceivePaymentRequest): ReadableMap =
     readableMapOf(
-        "feeMsat" to openChannelFeeResponse.feeMsat,
-        "feeParams" to readableMapOf(openChannelFeeResponse.feeParams),
+        "amountMsat" to prepareReceivePaymentRequest.amountMsat,
+        "openingFeeParams" to prepareReceivePaymentRequest.openingFeeParams?.let { readableMapOf(it) },
+        "expiry" to prepareReceivePaymentRequest.expiry,
     )
 
-fun asOpenChannelFeeResponseList(arr: ReadableArray): List<OpenChannelFeeResponse> {
-    val list = ArrayList<OpenChannelFeeResponse>()
+fun asPrepareReceivePaymentRequestList(arr: ReadableArray): List<PrepareReceivePaymentRequest> {
+    val list = ArrayList<PrepareReceivePaymentRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asOpenChannelFeeResponse(value)!!)
+            is ReadableMap -> list.add(asPrepareReceivePaymentRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asOpeningFeeParams(openingFeeParams: ReadableMap): OpeningFeeParams? {
+fun asPrepareReceivePaymentResponse(prepareReceivePaymentResponse: ReadableMap): PrepareReceivePaymentResponse? {
     if (!validateMandatoryFields(
-            openingFeeParams,
+            prepareReceivePaymentResponse,
             arrayOf(
-                "minMsat",
-                "proportional",
-                "validUntil",
-                "maxIdleTime",
-                "maxClientToSelfDelay",
-                "promise",
+                "amountMsat",
+                "openChannelNeeded",
+                "openingFeeMsat",
+                "receivedAmountMsat",
             ),
         )
     ) {
         return null
     }
-    val minMsat = openingFeeParams.getDouble("minMsat").toULong()
-    val proportional = openingFeeParams.getInt("proportional").toUInt()
-    val validUntil = openingFeeParams.getString("validUntil")!!
-    val maxIdleTime = openingFeeParams.getInt("maxIdleTime").toUInt()
-    val maxClientToSelfDelay = openingFeeParams.getInt("maxClientToSelfDelay").toUInt()
-    val promise = openingFeeParams.getString("promise")!!
-    return OpeningFeeParams(minMsat, proportional, validUntil, maxIdleTime, maxClientToSelfDelay, promise)
+    val amountMsat = prepareReceivePaymentResponse.getDouble("amountMsat").toULong()
+    val openChannelNeeded = prepareReceivePaymentResponse.getBoolean("openChannelNeeded")
+    val openingFeeMsat = prepareReceivePaymentResponse.getDouble("openingFeeMsat").toULong()
+    val openingFeeParams =
+        if (hasNonNullKey(
+                prepareReceivePaymentResponse,
+                "openingFeeParams",
+            )
+        ) {
+            prepareReceivePaymentResponse.getMap("openingFeeParams")?.let {
+                asOpeningFeeParams(it)
+            }
+        } else {
+            null
+        }
+    val receivedAmountMsat = prepareReceivePaymentResponse.getDouble("receivedAmountMsat").toULong()
+    return PrepareReceivePaymentResponse(amountMsat, openChannelNeeded, openingFeeMsat, openingFeeParams, receivedAmountMsat)
 }
 
-fun readableMapOf(openingFeeParams: OpeningFeeParams): ReadableMap =
+fun readableMapOf(prepareReceivePaymentResponse: PrepareReceivePaymentResponse): ReadableMap =
     readableMapOf(
-        "minMsat" to openingFeeParams.minMsat,
-        "proportional" to openingFeeParams.proportional,
-        "validUntil" to openingFeeParams.validUntil,
-        "maxIdleTime" to openingFeeParams.maxIdleTime,
-        "maxClientToSelfDelay" to openingFeeParams.maxClientToSelfDelay,
-        "promise" to openingFeeParams.promise,
+        "amountMsat" to prepareReceivePaymentResponse.amountMsat,
+        "openChannelNeeded" to prepareReceivePaymentResponse.openChannelNeeded,
+        "openingFeeMsat" to prepareReceivePaymentResponse.openingFeeMsat,
+        "openingFeeParams" to prepareReceivePaymentResponse.openingFeeParams?.let { readableMapOf(it) },
+        "receivedAmountMsat" to prepareReceivePaymentResponse.receivedAmountMsat,
     )
 
-fun asOpeningFeeParamsList(arr: ReadableArray): List<OpeningFeeParams> {
-    val list = ArrayList<OpeningFeeParams>()
+fun asPrepareReceivePaymentResponseList(arr: ReadableArray): List<PrepareReceivePaymentResponse> {
+    val list = ArrayList<PrepareReceivePaymentResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asOpeningFeeParams(value)!!)
+            is ReadableMap -> list.add(asPrepareReceivePaymentResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asOpeningFeeParamsMenu(openingFeeParamsMenu: ReadableMap): OpeningFeeParamsMenu? {
+fun asPrepareRedeemOnchainFundsRequest(prepareRedeemOnchainFundsRequest: ReadableMap): PrepareRedeemOnchainFundsRequest? {
     if (!validateMandatoryFields(
-            openingFeeParamsMenu,
+            prepareRedeemOnchainFundsRequest,
             arrayOf(
-                "values",
+                "toAddress",
+                "satPerVbyte",
             ),
         )
     ) {
         return null
     }
-    val values = openingFeeParamsMenu.getArray("values")?.let { asOpeningFeeParamsList(it) }!!
-    return OpeningFeeParamsMenu(values)
+    val toAddress = prepareRedeemOnchainFundsRequest.getString("toAddress")!!
+    val satPerVbyte = prepareRedeemOnchainFundsRequest.getInt("satPerVbyte").toUInt()
+    val amountSat =
+        if (hasNonNullKey(
+                prepareRedeemOnchainFundsRequest,
+                "amountSat",
+            )
+        ) {
+            prepareRedeemOnchainFundsRequest.getDouble("amountSat").toULong()
+        } else {
+            null
+        }
+    return PrepareRedeemOnchainFundsRequest(toAddress, satPerVbyte, amountSat)
 }
 
-fun readableMapOf(openingFeeParamsMenu: OpeningFeeParamsMenu): ReadableMap =
+fun readableMapOf(prepareRedeemOnchainFundsRequest: PrepareRedeemOnchainFundsRequest): ReadableMap =
     readableMapOf(
-        "values" to readableArrayOf(openingFeeParamsMenu.values),
+        "toAddress" to prepareRedeemOnchainFundsRequest.toAddress,
+        "satPerVbyte" to prepareRedeemOnchainFundsRequest.satPerVbyte,
+        "amountSat" to prepareRedeemOnchainFundsRequest.amountSat,
     )
 
-fun asOpeningFeeParamsMenuList(arr: ReadableArray): List<OpeningFeeParamsMenu> {
-    val list = ArrayList<OpeningFeeParamsMenu>()
+fun asPrepareRedeemOnchainFundsRequestList(arr: ReadableArray): List<PrepareRedeemOnchainFundsRequest> {
+    val list = ArrayList<PrepareRedeemOnchainFundsRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asOpeningFeeParamsMenu(value)!!)
+            is ReadableMap -> list.add(asPrepareRedeemOnchainFundsRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPayOnchainRequest(payOnchainRequest: ReadableMap): PayOnchainRequest? {
+fun asPrepareRedeemOnchainFundsResponse(prepareRedeemOnchainFundsResponse: ReadableMap): PrepareRedeemOnchainFundsResponse? {
     if (!validateMandatoryFields(
-            payOnchainRequest,
+            prepareRedeemOnchainFundsResponse,
             arrayOf(
-                "recipientAddress",
-                "prepareRes",
+                "txWeight",
+                "txFeeSat",
+                "amountSat",
+                "changeSat",
             ),
         )
     ) {
         return null
     }
-    val recipientAddress = payOnchainRequest.getString("recipientAddress")!!
-    val prepareRes = payOnchainRequest.getMap("prepareRes")?.let { asPrepareOnchainPaymentResponse(it) }!!
-    return PayOnchainRequest(recipientAddress, prepareRes)
+    val txWeight = prepareRedeemOnchainFundsResponse.getDouble("txWeight").toULong()
+    val txFeeSat = prepareRedeemOnchainFundsResponse.getDouble("txFeeSat").toULong()
+    val amountSat = prepareRedeemOnchainFundsResponse.getDouble("amountSat").toULong()
+    val changeSat = prepareRedeemOnchainFundsResponse.getDouble("changeSat").toULong()
+    return PrepareRedeemOnchainFundsResponse(txWeight, txFeeSat, amountSat, changeSat)
 }
 
-fun readableMapOf(payOnchainRequest: PayOnchainRequest): ReadableMap =
+fun readableMapOf(prepareRedeemOnchainFundsResponse: PrepareRedeemOnchainFundsResponse): ReadableMap =
     readableMapOf(
-        "recipientAddress" to payOnchainRequest.recipientAddress,
-        "prepareRes" to readableMapOf(payOnchainRequest.prepareRes),
+        "txWeight" to prepareRedeemOnchainFundsResponse.txWeight,
+        "txFeeSat" to prepareRedeemOnchainFundsResponse.txFeeSat,
+        "amountSat" to prepareRedeemOnchainFundsResponse.amountSat,
+        "changeSat" to prepareRedeemOnchainFundsResponse.changeSat,
     )
 
-fun asPayOnchainRequestList(arr: ReadableArray): List<PayOnchainRequest> {
-    val list = ArrayList<PayOnchainRequest>()
+fun asPrepareRedeemOnchainFundsResponseList(arr: ReadableArray): List<PrepareRedeemOnchainFundsResponse> {
+    val list = ArrayList<PrepareRedeemOnchainFundsResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPayOnchainRequest(value)!!)
+            is ReadableMap -> list.add(asPrepareRedeemOnchainFundsResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPayOnchainResponse(payOnchainResponse: ReadableMap): PayOnchainResponse? {
+fun asPrepareRefundRequest(prepareRefundRequest: ReadableMap): PrepareRefundRequest? {
     if (!validateMandatoryFields(
-            payOnchainResponse,
+            prepareRefundRequest,
             arrayOf(
-                "reverseSwapInfo",
+                "swapAddress",
+                "toAddress",
+                "satPerVbyte",
             ),
         )
     ) {
         return null
     }
-    val reverseSwapInfo = payOnchainResponse.getMap("reverseSwapInfo")?.let { asReverseSwapInfo(it) }!!
-    return PayOnchainResponse(reverseSwapInfo)
+    val swapAddress = prepareRefundRequest.getString("swapAddress")!!
+    val toAddress = prepareRefundRequest.getString("toAddress")!!
+    val satPerVbyte = prepareRefundRequest.getInt("satPerVbyte").toUInt()
+    val unilateral = if (hasNonNullKey(prepareRefundRequest, "unilateral")) prepareRefundRequest.getBoolean("unilateral") else null
+    val includeFeePreviews =
+        if (hasNonNullKey(
+                prepareRefundRequest,
+                "includeFeePreviews",
+            )
+        ) {
+            prepareRefundRequest.getBoolean("includeFeePreviews")
+        } else {
+            null
+        }
+    return PrepareRefundRequest(swapAddress, toAddress, satPerVbyte, unilateral, includeFeePreviews)
 }
 
-fun readableMapOf(payOnchainResponse: PayOnchainResponse): ReadableMap =
+fun readableMapOf(prepareRefundRequest: PrepareRefundRequest): ReadableMap =
     readableMapOf(
-        "reverseSwapInfo" to readableMapOf(payOnchainResponse.reverseSwapInfo),
+        "swapAddress" to prepareRefundRequest.swapAddress,
+        "toAddress" to prepareRefundRequest.toAddress,
+        "satPerVbyte" to prepareRefundRequest.satPerVbyte,
+        "unilateral" to prepareRefundRequest.unilateral,
+        "includeFeePreviews" to prepareRefundRequest.includeFeePreviews,
     )
 
-fun asPayOnchainResponseList(arr: ReadableArray): List<PayOnchainResponse> {
-    val list = ArrayList<PayOnchainResponse>()
+fun asPrepareRefundRequestList(arr: ReadableArray): List<PrepareRefundRequest> {
+    val list = ArrayList<PrepareRefundRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPayOnchainResponse(value)!!)
+            is ReadableMap -> list.add(asPrepareRefundRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPayment(payment: ReadableMap): Payment? {
+fun asPrepareRefundResponse(prepareRefundResponse: ReadableMap): PrepareRefundResponse? {
     if (!validateMandatoryFields(
-            payment,
+            prepareRefundResponse,
             arrayOf(
-                "id",
-                "paymentType",
-                "paymentTime",
-                "amountMsat",
-                "feeMsat",
-                "status",
-                "details",
+                "refundTxWeight",
+                "refundTxFeeSat",
+                "feePreviews",
             ),
         )
     ) {
         return null
     }
-    val id = payment.getString("id")!!
-    val paymentType = payment.getString("paymentType")?.let { asPaymentType(it) }!!
-    val paymentTime = payment.getDouble("paymentTime").toLong()
-    val amountMsat = payment.getDouble("amountMsat").toULong()
-    val feeMsat = payment.getDouble("feeMsat").toULong()
-    val status = payment.getString("status")?.let { asPaymentStatus(it) }!!
-    val error = if (hasNonNullKey(payment, "error")) payment.getString("error") else null
-    val description = if (hasNonNullKey(payment, "description")) payment.getString("description") else null
-    val details = payment.getMap("details")?.let { asPaymentDetails(it) }!!
-    val metadata = if (hasNonNullKey(payment, "metadata")) payment.getString("metadata") else null
-    return Payment(id, paymentType, paymentTime, amountMsat, feeMsat, status, error, description, details, metadata)
+    val refundTxWeight = prepareRefundResponse.getInt("refundTxWeight").toUInt()
+    val refundTxFeeSat = prepareRefundResponse.getDouble("refundTxFeeSat").toULong()
+    val feePreviews = prepareRefundResponse.getArray("feePreviews")?.let { asRefundFeePreviewList(it) }!!
+    return PrepareRefundResponse(refundTxWeight, refundTxFeeSat, feePreviews)
 }
 
-fun readableMapOf(payment: Payment): ReadableMap =
+fun readableMapOf(prepareRefundResponse: PrepareRefundResponse): ReadableMap =
     readableMapOf(
-        "id" to payment.id,
-        "paymentType" to payment.paymentType.name.lowercase(),
-        "paymentTime" to payment.paymentTime,
-        "amountMsat" to payment.amountMsat,
-        "feeMsat" to payment.feeMsat,
-        "status" to payment.status.name.lowercase(),
-        "error" to payment.error,
-        "description" to payment.description,
-        "details" to readableMapOf(payment.details),
-        "metadata" to payment.metadata,
+        "refundTxWeight" to prepareRefundResponse.refundTxWeight,
+        "refundTxFeeSat" to prepareRefundResponse.refundTxFeeSat,
+        "feePreviews" to readableArrayOf(prepareRefundResponse.feePreviews),
     )
 
-fun asPaymentList(arr: ReadableArray): List<Payment> {
-    val list = ArrayList<Payment>()
+fun asPrepareRefundResponseList(arr: ReadableArray): List<PrepareRefundResponse> {
+    val list = ArrayList<PrepareRefundResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPayment(value)!!)
+            is ReadableMap -> list.add(asPrepareRefundResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPaymentFailedData(paymentFailedData: ReadableMap): PaymentFailedData? {
+fun asPrepareSendPaymentRequest(prepareSendPaymentRequest: ReadableMap): PrepareSendPaymentRequest? {
     if (!validateMandatoryFields(
-            paymentFailedData,
+            prepareSendPaymentRequest,
             arrayOf(
-                "error",
-                "nodeId",
+                "bolt11",
             ),
         )
     ) {
         return null
     }
-    val error = paymentFailedData.getString("error")!!
-    val nodeId = paymentFailedData.getString("nodeId")!!
-    val invoice = if (hasNonNullKey(paymentFailedData, "invoice")) paymentFailedData.getMap("invoice")?.let { asLnInvoice(it) } else null
-    val label = if (hasNonNullKey(paymentFailedData, "label")) paymentFailedData.getString("label") else null
-    return PaymentFailedData(error, nodeId, invoice, label)
+    val bolt11 = prepareSendPaymentRequest.getString("bolt11")!!
+    val amountMsat =
+        if (hasNonNullKey(
+                prepareSendPaymentRequest,
+                "amountMsat",
+            )
+        ) {
+            prepareSendPaymentRequest.getDouble("amountMsat").toULong()
+        } else {
+            null
+        }
+    return PrepareSendPaymentRequest(bolt11, amountMsat)
 }
 
-fun readableMapOf(paymentFailedData: PaymentFailedData): ReadableMap =
+fun readableMapOf(prepareSendPaymentRequest: PrepareSendPaymentRequest): ReadableMap =
     readableMapOf(
-        "error" to paymentFailedData.error,
-        "nodeId" to paymentFailedData.nodeId,
-        "invoice" to paymentFailedData.invoice?.let { readableMapOf(it) },
-        "label" to paymentFailedData.label,
+        "bolt11" to prepareSendPaymentRequest.bolt11,
+        "amountMsat" to prepareSendPaymentRequest.amountMsat,
     )
 
-fun asPaymentFailedDataList(arr: ReadableArray): List<PaymentFailedData> {
-    val list = ArrayList<PaymentFailedData>()
+fun asPrepareSendPaymentRequestList(arr: ReadableArray): List<PrepareSendPaymentRequest> {
+    val list = ArrayList<PrepareSendPaymentRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPaymentFailedData(value)!!)
+            is ReadableMap -> list.add(asPrepareSendPaymentRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPrepareOnchainPaymentRequest(prepareOnchainPaymentRequest: ReadableMap): PrepareOnchainPaymentRequest? {
+fun asPrepareSendPaymentResponse(prepareSendPaymentResponse: ReadableMap): PrepareSendPaymentResponse? {
     if (!validateMandatoryFields(
-            prepareOnchainPaymentRequest,
-            arrayOf(
-                "amountSat",
-                "amountType",
-                "claimTxFeerate",
+            prepareSendPaymentResponse,
+            arrayOf(
+                "bolt11",
+                "amountMsat",
+                "feeMsat",
+                "routeHops",
+                "parts",
             ),
         )
     ) {
         return null
     }
-    val amountSat = prepareOnchainPaymentRequest.getDouble("amountSat").toULong()
-    val amountType = prepareOnchainPaymentRequest.getString("amountType")?.let { asSwapAmountType(it) }!!
-    val claimTxFeerate = prepareOnchainPaymentRequest.getInt("claimTxFeerate").toUInt()
-    return PrepareOnchainPaymentRequest(amountSat, amountType, claimTxFeerate)
+    val bolt11 = prepareSendPaymentResponse.getString("bolt11")!!
+    val amountMsat = prepareSendPaymentResponse.getDouble("amountMsat").toULong()
+    val feeMsat = prepareSendPaymentResponse.getDouble("feeMsat").toULong()
+    val routeHops = prepareSendPaymentResponse.getInt("routeHops").toUInt()
+    val parts = prepareSendPaymentResponse.getInt("parts").toUInt()
+    return PrepareSendPaymentResponse(bolt11, amountMsat, feeMsat, routeHops, parts)
 }
 
-fun readableMapOf(prepareOnchainPaymentRequest: PrepareOnchainPaymentRequest): ReadableMap =
+fun readableMapOf(prepareSendPaymentResponse: PrepareSendPaymentResponse): ReadableMap =
     readableMapOf(
-        "amountSat" to prepareOnchainPaymentRequest.amountSat,
-        "amountType" to prepareOnchainPaymentRequest.amountType.name.lowercase(),
-        "claimTxFeerate" to prepareOnchainPaymentRequest.claimTxFeerate,
+        "bolt11" to prepareSendPaymentResponse.bolt11,
+        "amountMsat" to prepareSendPaymentResponse.amountMsat,
+        "feeMsat" to prepareSendPaymentResponse.feeMsat,
+        "routeHops" to prepareSendPaymentResponse.routeHops,
+        "parts" to prepareSendPaymentResponse.parts,
     )
 
-fun asPrepareOnchainPaymentRequestList(arr: ReadableArray): List<PrepareOnchainPaymentRequest> {
-    val list = ArrayList<PrepareOnchainPaymentRequest>()
+fun asPrepareSendPaymentResponseList(arr: ReadableArray): List<PrepareSendPaymentResponse> {
+    val list = ArrayList<PrepareSendPaymentResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPrepareOnchainPaymentRequest(value)!!)
+            is ReadableMap -> list.add(asPrepareSendPaymentResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPrepareOnchainPaymentResponse(prepareOnchainPaymentResponse: ReadableMap): PrepareOnchainPaymentResponse? {
+fun asProbePaymentRequest(probePaymentRequest: ReadableMap): ProbePaymentRequest? {
     if (!validateMandatoryFields(
-            prepareOnchainPaymentResponse,
+            probePaymentRequest,
             arrayOf(
-                "feesHash",
-                "feesPercentage",
-                "feesLockup",
-                "feesClaim",
-                "senderAmountSat",
-                "recipientAmountSat",
-                "totalFees",
+                "destination",
             ),
         )
     ) {
         return null
     }
-    val feesHash = prepareOnchainPaymentResponse.getString("feesHash")!!
-    val feesPercentage = prepareOnchainPaymentResponse.getDouble("feesPercentage")
-    val feesLockup = prepareOnchainPaymentResponse.getDouble("feesLockup").toULong()
-    val feesClaim = prepareOnchainPaymentResponse.getDouble("feesClaim").toULong()
-    val senderAmountSat = prepareOnchainPaymentResponse.getDouble("senderAmountSat").toULong()
-    val recipientAmountSat = prepareOnchainPaymentResponse.getDouble("recipientAmountSat").toULong()
-    val totalFees = prepareOnchainPaymentResponse.getDouble("totalFees").toULong()
-    return PrepareOnchainPaymentResponse(feesHash, feesPercentage, feesLockup, feesClaim, senderAmountSat, recipientAmountSat, totalFees)
+    val destination = probePaymentRequest.getString("destination")!!
+    val amountMsat = if (hasNonNullKey(probePaymentRequest, "amountMsat")) probePaymentRequest.getDouble("amountMsat").toULong() else null
+    return ProbePaymentRequest(destination, amountMsat)
 }
 
-fun readableMapOf(prepareOnchainPaymentResponse: PrepareOnchainPaymentResponse): ReadableMap =
+fun readableMapOf(probePaymentRequest: ProbePaymentRequest): ReadableMap =
     readableMapOf(
-        "feesHash" to prepareOnchainPaymentResponse.feesHash,
-        "feesPercentage" to prepareOnchainPaymentResponse.feesPercentage,
-        "feesLockup" to prepareOnchainPaymentResponse.feesLockup,
-        "feesClaim" to prepareOnchainPaymentResponse.feesClaim,
-        "senderAmountSat" to prepareOnchainPaymentResponse.senderAmountSat,
-        "recipientAmountSat" to prepareOnchainPaymentResponse.recipientAmountSat,
-        "totalFees" to prepareOnchainPaymentResponse.totalFees,
+        "destination" to probePaymentRequest.destination,
+        "amountMsat" to probePaymentRequest.amountMsat,
     )
 
-fun asPrepareOnchainPaymentResponseList(arr: ReadableArray): List<PrepareOnchainPaymentResponse> {
-    val list = ArrayList<PrepareOnchainPaymentResponse>()
+fun asProbePaymentRequestList(arr: ReadableArray): List<ProbePaymentRequest> {
+    val list = ArrayList<ProbePaymentRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPrepareOnchainPaymentResponse(value)!!)
+            is ReadableMap -> list.add(asProbePaymentRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPrepareRedeemOnchainFundsRequest(prepareRedeemOnchainFundsRequest: ReadableMap): PrepareRedeemOnchainFundsRequest? {
+fun asProveAddressOwnershipRequest(proveAddressOwnershipRequest: ReadableMap): ProveAddressOwnershipRequest? {
     if (!validateMandatoryFields(
-            prepareRedeemOnchainFundsRequest,
+            proveAddressOwnershipRequest,
             arrayOf(
-                "toAddress",
-                "satPerVbyte",
+                "address",
+                "message",
             ),
         )
     ) {
         return null
     }
-    val toAddress = prepareRedeemOnchainFundsRequest.getString("toAddress")!!
-    val satPerVbyte = prepareRedeemOnchainFundsRequest.getInt("satPerVbyte").toUInt()
-    return PrepareRedeemOnchainFundsRequest(toAddress, satPerVbyte)
+    val address = proveAddressOwnershipRequest.getString("address")!!
+    val message = proveAddressOwnershipRequest.getString("message")!!
+    return ProveAddressOwnershipRequest(address, message)
 }
 
-fun readableMapOf(prepareRedeemOnchainFundsRequest: PrepareRedeemOnchainFundsRequest): ReadableMap =
+fun readableMapOf(proveAddressOwnershipRequest: ProveAddressOwnershipRequest): ReadableMap =
     readableMapOf(
-        "toAddress" to prepareRedeemOnchainFundsRequest.toAddress,
-        "satPerVbyte" to prepareRedeemOnchainFundsRequest.satPerVbyte,
+        "address" to proveAddressOwnershipRequest.address,
+        "message" to proveAddressOwnershipRequest.message,
     )
 
-fun asPrepareRedeemOnchainFundsRequestList(arr: ReadableArray): List<PrepareRedeemOnchainFundsRequest> {
-    val list = ArrayList<PrepareRedeemOnchainFundsRequest>()
+fun asProveAddressOwnershipRequestList(arr: ReadableArray): List<ProveAddressOwnershipRequest> {
+    val list = ArrayList<ProveAddressOwnershipRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPrepareRedeemOnchainFundsRequest(value)!!)
+            is ReadableMap -> list.add(asProveAddressOwnershipRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPrepareRedeemOnchainFundsResponse(prepareRedeemOnchainFundsResponse: ReadableMap): PrepareRedeemOnchainFundsResponse? {
+fun asProveAddressOwnershipResponse(proveAddressOwnershipResponse: ReadableMap): ProveAddressOwnershipResponse? {
     if (!validateMandatoryFields(
-            prepareRedeemOnchainFundsResponse,
+            proveAddressOwnershipResponse,
             arrayOf(
-                "txWeight",
-                "txFeeSat",
+                "signature",
             ),
         )
     ) {
         return null
     }
-    val txWeight = prepareRedeemOnchainFundsResponse.getDouble("txWeight").toULong()
-    val txFeeSat = prepareRedeemOnchainFundsResponse.getDouble("txFeeSat").toULong()
-    return PrepareRedeemOnchainFundsResponse(txWeight, txFeeSat)
+    val signature = proveAddressOwnershipResponse.getString("signature")!!
+    return ProveAddressOwnershipResponse(signature)
 }
 
-fun readableMapOf(prepareRedeemOnchainFundsResponse: PrepareRedeemOnchainFundsResponse): ReadableMap =
+fun readableMapOf(proveAddressOwnershipResponse: ProveAddressOwnershipResponse): ReadableMap =
     readableMapOf(
-        "txWeight" to prepareRedeemOnchainFundsResponse.txWeight,
-        "txFeeSat" to prepareRedeemOnchainFundsResponse.txFeeSat,
+        "signature" to proveAddressOwnershipResponse.signature,
     )
 
-fun asPrepareRedeemOnchainFundsResponseList(arr: ReadableArray): List<PrepareRedeemOnchainFundsResponse> {
-    val list = ArrayList<PrepareRedeemOnchainFundsResponse>()
+fun asProveAddressOwnershipResponseList(arr: ReadableArray): List<ProveAddressOwnershipResponse> {
+    val list = ArrayList<ProveAddressOwnershipResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPrepareRedeemOnchainFundsResponse(value)!!)
+            is ReadableMap -> list.add(asProveAddressOwnershipResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPrepareRefundRequest(prepareRefundRequest: ReadableMap): PrepareRefundRequest? {
+fun asProxyConfig(proxyConfig: ReadableMap): ProxyConfig? {
     if (!validateMandatoryFields(
-            prepareRefundRequest,
+            proxyConfig,
             arrayOf(
-                "swapAddress",
-                "toAddress",
-                "satPerVbyte",
+                "address",
             ),
         )
     ) {
         return null
     }
-    val swapAddress = prepareRefundRequest.getString("swapAddress")!!
-    val toAddress = prepareRefundRequest.getString("toAddress")!!
-    val satPerVbyte = prepareRefundRequest.getInt("satPerVbyte").toUInt()
-    val unilateral = if (hasNonNullKey(prepareRefundRequest, "unilateral")) prepareRefundRequest.getBoolean("unilateral") else null
-    return PrepareRefundRequest(swapAddress, toAddress, satPerVbyte, unilateral)
+    val address = proxyConfig.getString("address")!!
+    val username = if (hasNonNullKey(proxyConfig, "username")) proxyConfig.getString("username") else null
+    val password = if (hasNonNullKey(proxyConfig, "password")) proxyConfig.getString("password") else null
+    return ProxyConfig(address, username, password)
 }
 
-fun readableMapOf(prepareRefundRequest: PrepareRefundRequest): ReadableMap =
+fun readableMapOf(proxyConfig: ProxyConfig): ReadableMap =
     readableMapOf(
-        "swapAddress" to prepareRefundRequest.swapAddress,
-        "toAddress" to prepareRefundRequest.toAddress,
-        "satPerVbyte" to prepareRefundRequest.satPerVbyte,
-        "unilateral" to prepareRefundRequest.unilateral,
+        "address" to proxyConfig.address,
+        "username" to proxyConfig.username,
+        "password" to proxyConfig.password,
     )
 
-fun asPrepareRefundRequestList(arr: ReadableArray): List<PrepareRefundRequest> {
-    val list = ArrayList<PrepareRefundRequest>()
+fun asProxyConfigList(arr: ReadableArray): List<ProxyConfig> {
+    val list = ArrayList<ProxyConfig>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPrepareRefundRequest(value)!!)
+            is ReadableMap -> list.add(asProxyConfig(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asPrepareRefundResponse(prepareRefundResponse: ReadableMap): PrepareRefundResponse? {
+fun asQueuePaymentRequest(queuePaymentRequest: ReadableMap): QueuePaymentRequest? {
     if (!validateMandatoryFields(
-            prepareRefundResponse,
+            queuePaymentRequest,
             arrayOf(
-                "refundTxWeight",
-                "refundTxFeeSat",
+                "req",
             ),
         )
     ) {
         return null
     }
-    val refundTxWeight = prepareRefundResponse.getInt("refundTxWeight").toUInt()
-    val refundTxFeeSat = prepareRefundResponse.getDouble("refundTxFeeSat").toULong()
-    return PrepareRefundResponse(refundTxWeight, refundTxFeeSat)
+    val req = queuePaymentRequest.getMap("req")?.let { asSendPaymentRequest(it) }!!
+    val expirySecs = if (hasNonNullKey(queuePaymentRequest, "expirySecs")) queuePaymentRequest.getDouble("expirySecs").toULong() else null
+    return QueuePaymentRequest(req, expirySecs)
 }
 
-fun readableMapOf(prepareRefundResponse: PrepareRefundResponse): ReadableMap =
+fun readableMapOf(queuePaymentRequest: QueuePaymentRequest): ReadableMap =
     readableMapOf(
-        "refundTxWeight" to prepareRefundResponse.refundTxWeight,
-        "refundTxFeeSat" to prepareRefundResponse.refundTxFeeSat,
+        "req" to readableMapOf(queuePaymentRequest.req),
+        "expirySecs" to queuePaymentRequest.expirySecs,
     )
 
-fun asPrepareRefundResponseList(arr: ReadableArray): List<PrepareRefundResponse> {
-    val list = ArrayList<PrepareRefundResponse>()
+fun asQueuePaymentRequestList(arr: ReadableArray): List<QueuePaymentRequest> {
+    val list = ArrayList<QueuePaymentRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asPrepareRefundResponse(value)!!)
+            is ReadableMap -> list.add(asQueuePaymentRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
@@ -2313,119 +5847,288 @@ fun asReceiveOnchainRequest(receiveOnchainRequest: ReadableMap): ReceiveOnchainR
                 "openingFeeParams",
             )
         ) {
-            receiveOnchainRequest.getMap("openingFeeParams")?.let {
-                asOpeningFeeParams(it)
-            }
+            receiveOnchainRequest.getMap("openingFeeParams")?.let {
+                asOpeningFeeParams(it)
+            }
+        } else {
+            null
+        }
+    return ReceiveOnchainRequest(openingFeeParams)
+}
+
+fun readableMapOf(receiveOnchainRequest: ReceiveOnchainRequest): ReadableMap =
+    readableMapOf(
+        "openingFeeParams" to receiveOnchainRequest.openingFeeParams?.let { readableMapOf(it) },
+    )
+
+fun asReceiveOnchainRequestList(arr: ReadableArray): List<ReceiveOnchainRequest> {
+    val list = ArrayList<ReceiveOnchainRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asReceiveOnchainRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asReceivePaymentRequest(receivePaymentRequest: ReadableMap): ReceivePaymentRequest? {
+    if (!validateMandatoryFields(
+            receivePaymentRequest,
+            arrayOf(
+                "amountMsat",
+                "description",
+            ),
+        )
+    ) {
+        return null
+    }
+    val amountMsat = receivePaymentRequest.getDouble("amountMsat").toULong()
+    val description = receivePaymentRequest.getString("description")!!
+    val preimage =
+        if (hasNonNullKey(receivePaymentRequest, "preimage")) {
+            receivePaymentRequest.getArray("preimage")?.let {
+                asUByteList(it)
+            }
+        } else {
+            null
+        }
+    val openingFeeParams =
+        if (hasNonNullKey(
+                receivePaymentRequest,
+                "openingFeeParams",
+            )
+        ) {
+            receivePaymentRequest.getMap("openingFeeParams")?.let {
+                asOpeningFeeParams(it)
+            }
+        } else {
+            null
+        }
+    val useDescriptionHash =
+        if (hasNonNullKey(
+                receivePaymentRequest,
+                "useDescriptionHash",
+            )
+        ) {
+            receivePaymentRequest.getBoolean("useDescriptionHash")
+        } else {
+            null
+        }
+    val expiry = if (hasNonNullKey(receivePaymentRequest, "expiry")) receivePaymentRequest.getInt("expiry").toUInt() else null
+    val cltv = if (hasNonNullKey(receivePaymentRequest, "cltv")) receivePaymentRequest.getInt("cltv").toUInt() else null
+    val includeFallbackAddress =
+        if (hasNonNullKey(
+                receivePaymentRequest,
+                "includeFallbackAddress",
+            )
+        ) {
+            receivePaymentRequest.getBoolean("includeFallbackAddress")
+        } else {
+            null
+        }
+    val maxRouteHints =
+        if (hasNonNullKey(
+                receivePaymentRequest,
+                "maxRouteHints",
+            )
+        ) {
+            receivePaymentRequest.getInt("maxRouteHints").toUInt()
+        } else {
+            null
+        }
+    val fiatAmount =
+        if (hasNonNullKey(receivePaymentRequest, "fiatAmount")) {
+            receivePaymentRequest.getMap("fiatAmount")?.let {
+                asFiatAmount(it)
+            }
+        } else {
+            null
+        }
+    return ReceivePaymentRequest(
+        amountMsat,
+        description,
+        preimage,
+        openingFeeParams,
+        useDescriptionHash,
+        expiry,
+        cltv,
+        includeFallbackAddress,
+        maxRouteHints,
+        fiatAmount,
+    )
+}
+
+fun readableMapOf(receivePaymentRequest: ReceivePaymentRequest): ReadableMap =
+    readableMapOf(
+        "amountMsat" to receivePaymentRequest.amountMsat,
+        "description" to receivePaymentRequest.description,
+        "preimage" to receivePaymentRequest.preimage?.let { readableArrayOf(it) },
+        "openingFeeParams" to receivePaymentRequest.openingFeeParams?.let { readableMapOf(it) },
+        "useDescriptionHash" to receivePaymentRequest.useDescriptionHash,
+        "expiry" to receivePaymentRequest.expiry,
+        "cltv" to receivePaymentRequest.cltv,
+        "includeFallbackAddress" to receivePaymentRequest.includeFallbackAddress,
+        "maxRouteHints" to receivePaymentRequest.maxRouteHints,
+        "fiatAmount" to receivePaymentRequest.fiatAmount?.let { readableMapOf(it) },
+    )
+
+fun asReceivePaymentRequestList(arr: ReadableArray): List<ReceivePaymentRequest> {
+    val list = ArrayList<ReceivePaymentRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asReceivePaymentRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asReceivePaymentResponse(receivePaymentResponse: ReadableMap): ReceivePaymentResponse? {
+    if (!validateMandatoryFields(
+            receivePaymentResponse,
+            arrayOf(
+                "lnInvoice",
+                "bolt11Length",
+            ),
+        )
+    ) {
+        return null
+    }
+    val lnInvoice = receivePaymentResponse.getMap("lnInvoice")?.let { asLnInvoice(it) }!!
+    val openingFeeParams =
+        if (hasNonNullKey(
+                receivePaymentResponse,
+                "openingFeeParams",
+            )
+        ) {
+            receivePaymentResponse.getMap("openingFeeParams")?.let {
+                asOpeningFeeParams(it)
+            }
+        } else {
+            null
+        }
+    val openingFeeMsat =
+        if (hasNonNullKey(
+                receivePaymentResponse,
+                "openingFeeMsat",
+            )
+        ) {
+            receivePaymentResponse.getDouble("openingFeeMsat").toULong()
+        } else {
+            null
+        }
+    val bolt11Length = receivePaymentResponse.getInt("bolt11Length").toUInt()
+    val fiatAmount =
+        if (hasNonNullKey(receivePaymentResponse, "fiatAmount")) {
+            receivePaymentResponse.getMap("fiatAmount")?.let {
+                asFiatAmount(it)
+            }
+        } else {
+            null
+        }
+    val fiatRate =
+        if (hasNonNullKey(
+                receivePaymentResponse,
+                "fiatRate",
+            )
+        ) {
+            receivePaymentResponse.getMap("fiatRate")?.let { asRate(it) }
         } else {
             null
         }
-    return ReceiveOnchainRequest(openingFeeParams)
+    return ReceivePaymentResponse(lnInvoice, openingFeeParams, openingFeeMsat, bolt11Length, fiatAmount, fiatRate)
 }
 
-fun readableMapOf(receiveOnchainRequest: ReceiveOnchainRequest): ReadableMap =
+fun readableMapOf(receivePaymentResponse: ReceivePaymentResponse): ReadableMap =
     readableMapOf(
-        "openingFeeParams" to receiveOnchainRequest.openingFeeParams?.let { readableMapOf(it) },
+        "lnInvoice" to readableMapOf(receivePaymentResponse.lnInvoice),
+        "openingFeeParams" to receivePaymentResponse.openingFeeParams?.let { readableMapOf(it) },
+        "openingFeeMsat" to receivePaymentResponse.openingFeeMsat,
+        "bolt11Length" to receivePaymentResponse.bolt11Length,
+        "fiatAmount" to receivePaymentResponse.fiatAmount?.let { readableMapOf(it) },
+        "fiatRate" to receivePaymentResponse.fiatRate?.let { readableMapOf(it) },
     )
 
-fun asReceiveOnchainRequestList(arr: ReadableArray): List<ReceiveOnchainRequest> {
-    val list = ArrayList<ReceiveOnchainRequest>()
+fun asReceivePaymentResponseList(arr: ReadableArray): List<ReceivePaymentResponse> {
+    val list = ArrayList<ReceivePaymentResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asReceiveOnchainRequest(value)!!)
+            is ReadableMap -> list.add(asReceivePaymentResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asReceivePaymentRequest(receivePaymentRequest: ReadableMap): ReceivePaymentRequest? {
+fun asReceiveUnifiedRequest(receiveUnifiedRequest: ReadableMap): ReceiveUnifiedRequest? {
     if (!validateMandatoryFields(
-            receivePaymentRequest,
+            receiveUnifiedRequest,
             arrayOf(
-                "amountMsat",
+                "amountSat",
                 "description",
             ),
         )
     ) {
         return null
     }
-    val amountMsat = receivePaymentRequest.getDouble("amountMsat").toULong()
-    val description = receivePaymentRequest.getString("description")!!
-    val preimage =
-        if (hasNonNullKey(receivePaymentRequest, "preimage")) {
-            receivePaymentRequest.getArray("preimage")?.let {
-                asUByteList(it)
-            }
-        } else {
-            null
-        }
+    val amountSat = receiveUnifiedRequest.getDouble("amountSat").toULong()
+    val description = receiveUnifiedRequest.getString("description")!!
     val openingFeeParams =
         if (hasNonNullKey(
-                receivePaymentRequest,
+                receiveUnifiedRequest,
                 "openingFeeParams",
             )
         ) {
-            receivePaymentRequest.getMap("openingFeeParams")?.let {
+            receiveUnifiedRequest.getMap("openingFeeParams")?.let {
                 asOpeningFeeParams(it)
             }
         } else {
             null
         }
-    val useDescriptionHash =
-        if (hasNonNullKey(
-                receivePaymentRequest,
-                "useDescriptionHash",
-            )
-        ) {
-            receivePaymentRequest.getBoolean("useDescriptionHash")
-        } else {
-            null
-        }
-    val expiry = if (hasNonNullKey(receivePaymentRequest, "expiry")) receivePaymentRequest.getInt("expiry").toUInt() else null
-    val cltv = if (hasNonNullKey(receivePaymentRequest, "cltv")) receivePaymentRequest.getInt("cltv").toUInt() else null
-    return ReceivePaymentRequest(amountMsat, description, preimage, openingFeeParams, useDescriptionHash, expiry, cltv)
+    return ReceiveUnifiedRequest(amountSat, description, openingFeeParams)
 }
 
-fun readableMapOf(receivePaymentRequest: ReceivePaymentRequest): ReadableMap =
+fun readableMapOf(receiveUnifiedRequest: ReceiveUnifiedRequest): ReadableMap =
     readableMapOf(
-        "amountMsat" to receivePaymentRequest.amountMsat,
-        "description" to receivePaymentRequest.description,
-        "preimage" to receivePaymentRequest.preimage?.let { readableArrayOf(it) },
-        "openingFeeParams" to receivePaymentRequest.openingFeeParams?.let { readableMapOf(it) },
-        "useDescriptionHash" to receivePaymentRequest.useDescriptionHash,
-        "expiry" to receivePaymentRequest.expiry,
-        "cltv" to receivePaymentRequest.cltv,
+        "amountSat" to receiveUnifiedRequest.amountSat,
+        "description" to receiveUnifiedRequest.description,
+        "openingFeeParams" to receiveUnifiedRequest.openingFeeParams?.let { readableMapOf(it) },
     )
 
-fun asReceivePaymentRequestList(arr: ReadableArray): List<ReceivePaymentRequest> {
-    val list = ArrayList<ReceivePaymentRequest>()
+fun asReceiveUnifiedRequestList(arr: ReadableArray): List<ReceiveUnifiedRequest> {
+    val list = ArrayList<ReceiveUnifiedRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asReceivePaymentRequest(value)!!)
+            is ReadableMap -> list.add(asReceiveUnifiedRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asReceivePaymentResponse(receivePaymentResponse: ReadableMap): ReceivePaymentResponse? {
+fun asReceiveUnifiedResponse(receiveUnifiedResponse: ReadableMap): ReceiveUnifiedResponse? {
     if (!validateMandatoryFields(
-            receivePaymentResponse,
+            receiveUnifiedResponse,
             arrayOf(
+                "bip21",
+                "swapInfo",
                 "lnInvoice",
             ),
         )
     ) {
         return null
     }
-    val lnInvoice = receivePaymentResponse.getMap("lnInvoice")?.let { asLnInvoice(it) }!!
+    val bip21 = receiveUnifiedResponse.getString("bip21")!!
+    val swapInfo = receiveUnifiedResponse.getMap("swapInfo")?.let { asSwapInfo(it) }!!
+    val lnInvoice = receiveUnifiedResponse.getMap("lnInvoice")?.let { asLnInvoice(it) }!!
     val openingFeeParams =
         if (hasNonNullKey(
-                receivePaymentResponse,
+                receiveUnifiedResponse,
                 "openingFeeParams",
             )
         ) {
-            receivePaymentResponse.getMap("openingFeeParams")?.let {
+            receiveUnifiedResponse.getMap("openingFeeParams")?.let {
                 asOpeningFeeParams(it)
             }
         } else {
@@ -2433,29 +6136,31 @@ fun asReceivePaymentResponse(receivePaymentResponse: ReadableMap): ReceivePaymen
         }
     val openingFeeMsat =
         if (hasNonNullKey(
-                receivePaymentResponse,
+                receiveUnifiedResponse,
                 "openingFeeMsat",
             )
         ) {
-            receivePaymentResponse.getDouble("openingFeeMsat").toULong()
+            receiveUnifiedResponse.getDouble("openingFeeMsat").toULong()
         } else {
             null
         }
-    return ReceivePaymentResponse(lnInvoice, openingFeeParams, openingFeeMsat)
+    return ReceiveUnifiedResponse(bip21, swapInfo, lnInvoice, openingFeeParams, openingFeeMsat)
 }
 
-fun readableMapOf(receivePaymentResponse: ReceivePaymentResponse): ReadableMap =
+fun readableMapOf(receiveUnifiedResponse: ReceiveUnifiedResponse): ReadableMap =
     readableMapOf(
-        "lnInvoice" to readableMapOf(receivePaymentResponse.lnInvoice),
-        "openingFeeParams" to receivePaymentResponse.openingFeeParams?.let { readableMapOf(it) },
-        "openingFeeMsat" to receivePaymentResponse.openingFeeMsat,
+        "bip21" to receiveUnifiedResponse.bip21,
+        "swapInfo" to readableMapOf(receiveUnifiedResponse.swapInfo),
+        "lnInvoice" to readableMapOf(receiveUnifiedResponse.lnInvoice),
+        "openingFeeParams" to receiveUnifiedResponse.openingFeeParams?.let { readableMapOf(it) },
+        "openingFeeMsat" to receiveUnifiedResponse.openingFeeMsat,
     )
 
-fun asReceivePaymentResponseList(arr: ReadableArray): List<ReceivePaymentResponse> {
-    val list = ArrayList<ReceivePaymentResponse>()
+fun asReceiveUnifiedResponseList(arr: ReadableArray): List<ReceiveUnifiedResponse> {
+    val list = ArrayList<ReceiveUnifiedResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asReceivePaymentResponse(value)!!)
+            is ReadableMap -> list.add(asReceiveUnifiedResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
@@ -2504,6 +6209,90 @@ fun asRecommendedFeesList(arr: ReadableArray): List<RecommendedFees> {
     return list
 }
 
+fun asRecoveryBundle(recoveryBundle: ReadableMap): RecoveryBundle? {
+    if (!validateMandatoryFields(
+            recoveryBundle,
+            arrayOf(
+                "nodeId",
+                "network",
+                "instructions",
+                "staticBackup",
+                "channels",
+                "channelsBalanceMsat",
+                "onchainBalanceMsat",
+                "createdAt",
+            ),
+        )
+    ) {
+        return null
+    }
+    val nodeId = recoveryBundle.getString("nodeId")!!
+    val network = recoveryBundle.getString("network")?.let { asNetwork(it) }!!
+    val instructions = recoveryBundle.getString("instructions")!!
+    val staticBackup = recoveryBundle.getArray("staticBackup")?.let { asStringList(it) }!!
+    val channels = recoveryBundle.getArray("channels")?.let { asMigrationChannelList(it) }!!
+    val channelsBalanceMsat = recoveryBundle.getDouble("channelsBalanceMsat").toULong()
+    val onchainBalanceMsat = recoveryBundle.getDouble("onchainBalanceMsat").toULong()
+    val createdAt = recoveryBundle.getDouble("createdAt").toULong()
+    return RecoveryBundle(nodeId, network, instructions, staticBackup, channels, channelsBalanceMsat, onchainBalanceMsat, createdAt)
+}
+
+fun readableMapOf(recoveryBundle: RecoveryBundle): ReadableMap =
+    readableMapOf(
+        "nodeId" to recoveryBundle.nodeId,
+        "network" to recoveryBundle.network.name.lowercase(),
+        "instructions" to recoveryBundle.instructions,
+        "staticBackup" to readableArrayOf(recoveryBundle.staticBackup),
+        "channels" to readableArrayOf(recoveryBundle.channels),
+        "channelsBalanceMsat" to recoveryBundle.channelsBalanceMsat,
+        "onchainBalanceMsat" to recoveryBundle.onchainBalanceMsat,
+        "createdAt" to recoveryBundle.createdAt,
+    )
+
+fun asRecoveryBundleList(arr: ReadableArray): List<RecoveryBundle> {
+    val list = ArrayList<RecoveryBundle>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asRecoveryBundle(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asRedeemLnurlWithdrawVoucherRequest(redeemLnurlWithdrawVoucherRequest: ReadableMap): RedeemLnurlWithdrawVoucherRequest? {
+    if (!validateMandatoryFields(
+            redeemLnurlWithdrawVoucherRequest,
+            arrayOf(
+                "id",
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val id = redeemLnurlWithdrawVoucherRequest.getString("id")!!
+    val bolt11 = redeemLnurlWithdrawVoucherRequest.getString("bolt11")!!
+    return RedeemLnurlWithdrawVoucherRequest(id, bolt11)
+}
+
+fun readableMapOf(redeemLnurlWithdrawVoucherRequest: RedeemLnurlWithdrawVoucherRequest): ReadableMap =
+    readableMapOf(
+        "id" to redeemLnurlWithdrawVoucherRequest.id,
+        "bolt11" to redeemLnurlWithdrawVoucherRequest.bolt11,
+    )
+
+fun asRedeemLnurlWithdrawVoucherRequestList(arr: ReadableArray): List<RedeemLnurlWithdrawVoucherRequest> {
+    val list = ArrayList<RedeemLnurlWithdrawVoucherRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asRedeemLnurlWithdrawVoucherRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asRedeemOnchainFundsRequest(redeemOnchainFundsRequest: ReadableMap): RedeemOnchainFundsRequest? {
     if (!validateMandatoryFields(
             redeemOnchainFundsRequest,
@@ -2517,13 +6306,24 @@ fun asRedeemOnchainFundsRequest(redeemOnchainFundsRequest: ReadableMap): RedeemO
     }
     val toAddress = redeemOnchainFundsRequest.getString("toAddress")!!
     val satPerVbyte = redeemOnchainFundsRequest.getInt("satPerVbyte").toUInt()
-    return RedeemOnchainFundsRequest(toAddress, satPerVbyte)
+    val amountSat =
+        if (hasNonNullKey(
+                redeemOnchainFundsRequest,
+                "amountSat",
+            )
+        ) {
+            redeemOnchainFundsRequest.getDouble("amountSat").toULong()
+        } else {
+            null
+        }
+    return RedeemOnchainFundsRequest(toAddress, satPerVbyte, amountSat)
 }
 
 fun readableMapOf(redeemOnchainFundsRequest: RedeemOnchainFundsRequest): ReadableMap =
     readableMapOf(
         "toAddress" to redeemOnchainFundsRequest.toAddress,
         "satPerVbyte" to redeemOnchainFundsRequest.satPerVbyte,
+        "amountSat" to redeemOnchainFundsRequest.amountSat,
     )
 
 fun asRedeemOnchainFundsRequestList(arr: ReadableArray): List<RedeemOnchainFundsRequest> {
@@ -2567,6 +6367,42 @@ fun asRedeemOnchainFundsResponseList(arr: ReadableArray): List<RedeemOnchainFund
     return list
 }
 
+fun asRefundFeePreview(refundFeePreview: ReadableMap): RefundFeePreview? {
+    if (!validateMandatoryFields(
+            refundFeePreview,
+            arrayOf(
+                "preset",
+                "satPerVbyte",
+                "refundTxFeeSat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val preset = refundFeePreview.getString("preset")?.let { asFeeratePreset(it) }!!
+    val satPerVbyte = refundFeePreview.getInt("satPerVbyte").toUInt()
+    val refundTxFeeSat = refundFeePreview.getDouble("refundTxFeeSat").toULong()
+    return RefundFeePreview(preset, satPerVbyte, refundTxFeeSat)
+}
+
+fun readableMapOf(refundFeePreview: RefundFeePreview): ReadableMap =
+    readableMapOf(
+        "preset" to refundFeePreview.preset.name.lowercase(),
+        "satPerVbyte" to refundFeePreview.satPerVbyte,
+        "refundTxFeeSat" to refundFeePreview.refundTxFeeSat,
+    )
+
+fun asRefundFeePreviewList(arr: ReadableArray): List<RefundFeePreview> {
+    val list = ArrayList<RefundFeePreview>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asRefundFeePreview(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asRefundRequest(refundRequest: ReadableMap): RefundRequest? {
     if (!validateMandatoryFields(
             refundRequest,
@@ -2723,6 +6559,7 @@ fun asReverseSwapInfo(reverseSwapInfo: ReadableMap): ReverseSwapInfo? {
                 "claimPubkey",
                 "onchainAmountSat",
                 "status",
+                "protocol",
             ),
         )
     ) {
@@ -2734,7 +6571,8 @@ fun asReverseSwapInfo(reverseSwapInfo: ReadableMap): ReverseSwapInfo? {
     val claimTxid = if (hasNonNullKey(reverseSwapInfo, "claimTxid")) reverseSwapInfo.getString("claimTxid") else null
     val onchainAmountSat = reverseSwapInfo.getDouble("onchainAmountSat").toULong()
     val status = reverseSwapInfo.getString("status")?.let { asReverseSwapStatus(it) }!!
-    return ReverseSwapInfo(id, claimPubkey, lockupTxid, claimTxid, onchainAmountSat, status)
+    val protocol = reverseSwapInfo.getString("protocol")?.let { asReverseSwapProtocol(it) }!!
+    return ReverseSwapInfo(id, claimPubkey, lockupTxid, claimTxid, onchainAmountSat, status, protocol)
 }
 
 fun readableMapOf(reverseSwapInfo: ReverseSwapInfo): ReadableMap =
@@ -2745,6 +6583,7 @@ fun readableMapOf(reverseSwapInfo: ReverseSwapInfo): ReadableMap =
         "claimTxid" to reverseSwapInfo.claimTxid,
         "onchainAmountSat" to reverseSwapInfo.onchainAmountSat,
         "status" to reverseSwapInfo.status.name.lowercase(),
+        "protocol" to reverseSwapInfo.protocol.name.lowercase(),
     )
 
 fun asReverseSwapInfoList(arr: ReadableArray): List<ReverseSwapInfo> {
@@ -2867,89 +6706,280 @@ fun asRouteHintHop(routeHintHop: ReadableMap): RouteHintHop? {
     )
 }
 
-fun readableMapOf(routeHintHop: RouteHintHop): ReadableMap =
+fun readableMapOf(routeHintHop: RouteHintHop): ReadableMap =
+    readableMapOf(
+        "srcNodeId" to routeHintHop.srcNodeId,
+        "shortChannelId" to routeHintHop.shortChannelId,
+        "feesBaseMsat" to routeHintHop.feesBaseMsat,
+        "feesProportionalMillionths" to routeHintHop.feesProportionalMillionths,
+        "cltvExpiryDelta" to routeHintHop.cltvExpiryDelta,
+        "htlcMinimumMsat" to routeHintHop.htlcMinimumMsat,
+        "htlcMaximumMsat" to routeHintHop.htlcMaximumMsat,
+    )
+
+fun asRouteHintHopList(arr: ReadableArray): List<RouteHintHop> {
+    val list = ArrayList<RouteHintHop>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asRouteHintHop(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSdkInfo(sdkInfo: ReadableMap): SdkInfo? {
+    if (!validateMandatoryFields(
+            sdkInfo,
+            arrayOf(
+                "version",
+                "supportedLuds",
+                "swapInScriptVersions",
+                "reverseSwapProtocols",
+                "lspProtocols",
+                "backupSchemaVersion",
+                "minBackupSchemaVersion",
+            ),
+        )
+    ) {
+        return null
+    }
+    val version = sdkInfo.getString("version")!!
+    val supportedLuds = sdkInfo.getArray("supportedLuds")?.let { asStringList(it) }!!
+    val swapInScriptVersions = sdkInfo.getArray("swapInScriptVersions")?.let { asStringList(it) }!!
+    val reverseSwapProtocols = sdkInfo.getArray("reverseSwapProtocols")?.let { asReverseSwapProtocolList(it) }!!
+    val lspProtocols = sdkInfo.getArray("lspProtocols")?.let { asStringList(it) }!!
+    val backupSchemaVersion = sdkInfo.getInt("backupSchemaVersion").toUInt()
+    val minBackupSchemaVersion = sdkInfo.getInt("minBackupSchemaVersion").toUInt()
+    return SdkInfo(
+        version,
+        supportedLuds,
+        swapInScriptVersions,
+        reverseSwapProtocols,
+        lspProtocols,
+        backupSchemaVersion,
+        minBackupSchemaVersion,
+    )
+}
+
+fun readableMapOf(sdkInfo: SdkInfo): ReadableMap =
+    readableMapOf(
+        "version" to sdkInfo.version,
+        "supportedLuds" to readableArrayOf(sdkInfo.supportedLuds),
+        "swapInScriptVersions" to readableArrayOf(sdkInfo.swapInScriptVersions),
+        "reverseSwapProtocols" to readableArrayOf(sdkInfo.reverseSwapProtocols),
+        "lspProtocols" to readableArrayOf(sdkInfo.lspProtocols),
+        "backupSchemaVersion" to sdkInfo.backupSchemaVersion,
+        "minBackupSchemaVersion" to sdkInfo.minBackupSchemaVersion,
+    )
+
+fun asSdkInfoList(arr: ReadableArray): List<SdkInfo> {
+    val list = ArrayList<SdkInfo>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSdkInfo(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSendPaymentRequest(sendPaymentRequest: ReadableMap): SendPaymentRequest? {
+    if (!validateMandatoryFields(
+            sendPaymentRequest,
+            arrayOf(
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val bolt11 = sendPaymentRequest.getString("bolt11")!!
+    val useTrampoline = if (hasNonNullKey(sendPaymentRequest, "useTrampoline")) sendPaymentRequest.getBoolean("useTrampoline") else null
+    val amountMsat = if (hasNonNullKey(sendPaymentRequest, "amountMsat")) sendPaymentRequest.getDouble("amountMsat").toULong() else null
+    val label = if (hasNonNullKey(sendPaymentRequest, "label")) sendPaymentRequest.getString("label") else null
+    val routeHintIndex =
+        if (hasNonNullKey(
+                sendPaymentRequest,
+                "routeHintIndex",
+            )
+        ) {
+            sendPaymentRequest.getInt("routeHintIndex").toUInt()
+        } else {
+            null
+        }
+    val paymentTimeoutSec =
+        if (hasNonNullKey(
+                sendPaymentRequest,
+                "paymentTimeoutSec",
+            )
+        ) {
+            sendPaymentRequest.getInt("paymentTimeoutSec").toUInt()
+        } else {
+            null
+        }
+    val maxfeePercent = if (hasNonNullKey(sendPaymentRequest, "maxfeePercent")) sendPaymentRequest.getDouble("maxfeePercent") else null
+    val exemptfeeMsat =
+        if (hasNonNullKey(
+                sendPaymentRequest,
+                "exemptfeeMsat",
+            )
+        ) {
+            sendPaymentRequest.getDouble("exemptfeeMsat").toULong()
+        } else {
+            null
+        }
+    val cancelToken = if (hasNonNullKey(sendPaymentRequest, "cancelToken")) sendPaymentRequest.getString("cancelToken") else null
+    val skipDenylist = if (hasNonNullKey(sendPaymentRequest, "skipDenylist")) sendPaymentRequest.getBoolean("skipDenylist") else null
+    return SendPaymentRequest(
+        bolt11,
+        useTrampoline,
+        amountMsat,
+        label,
+        routeHintIndex,
+        paymentTimeoutSec,
+        maxfeePercent,
+        exemptfeeMsat,
+        cancelToken,
+        skipDenylist,
+    )
+}
+
+fun readableMapOf(sendPaymentRequest: SendPaymentRequest): ReadableMap =
+    readableMapOf(
+        "bolt11" to sendPaymentRequest.bolt11,
+        "useTrampoline" to sendPaymentRequest.useTrampoline,
+        "amountMsat" to sendPaymentRequest.amountMsat,
+        "label" to sendPaymentRequest.label,
+        "routeHintIndex" to sendPaymentRequest.routeHintIndex,
+        "paymentTimeoutSec" to sendPaymentRequest.paymentTimeoutSec,
+        "maxfeePercent" to sendPaymentRequest.maxfeePercent,
+        "exemptfeeMsat" to sendPaymentRequest.exemptfeeMsat,
+        "cancelToken" to sendPaymentRequest.cancelToken,
+        "skipDenylist" to sendPaymentRequest.skipDenylist,
+    )
+
+fun asSendPaymentRequestList(arr: ReadableArray): List<SendPaymentRequest> {
+    val list = ArrayList<SendPaymentRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSendPaymentRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSendPaymentResponse(sendPaymentResponse: ReadableMap): SendPaymentResponse? {
+    if (!validateMandatoryFields(
+            sendPaymentResponse,
+            arrayOf(
+                "payment",
+            ),
+        )
+    ) {
+        return null
+    }
+    val payment = sendPaymentResponse.getMap("payment")?.let { asPayment(it) }!!
+    val partnerFee =
+        if (hasNonNullKey(sendPaymentResponse, "partnerFee")) {
+            sendPaymentResponse.getMap("partnerFee")?.let {
+                asPartnerFeeDetails(it)
+            }
+        } else {
+            null
+        }
+    return SendPaymentResponse(payment, partnerFee)
+}
+
+fun readableMapOf(sendPaymentResponse: SendPaymentResponse): ReadableMap =
     readableMapOf(
-        "srcNodeId" to routeHintHop.srcNodeId,
-        "shortChannelId" to routeHintHop.shortChannelId,
-        "feesBaseMsat" to routeHintHop.feesBaseMsat,
-        "feesProportionalMillionths" to routeHintHop.feesProportionalMillionths,
-        "cltvExpiryDelta" to routeHintHop.cltvExpiryDelta,
-        "htlcMinimumMsat" to routeHintHop.htlcMinimumMsat,
-        "htlcMaximumMsat" to routeHintHop.htlcMaximumMsat,
+        "payment" to readableMapOf(sendPaymentResponse.payment),
+        "partnerFee" to sendPaymentResponse.partnerFee?.let { readableMapOf(it) },
     )
 
-fun asRouteHintHopList(arr: ReadableArray): List<RouteHintHop> {
-    val list = ArrayList<RouteHintHop>()
+fun asSendPaymentResponseList(arr: ReadableArray): List<SendPaymentResponse> {
+    val list = ArrayList<SendPaymentResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asRouteHintHop(value)!!)
+            is ReadableMap -> list.add(asSendPaymentResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asSendPaymentRequest(sendPaymentRequest: ReadableMap): SendPaymentRequest? {
+fun asSendPaymentsRequest(sendPaymentsRequest: ReadableMap): SendPaymentsRequest? {
     if (!validateMandatoryFields(
-            sendPaymentRequest,
+            sendPaymentsRequest,
             arrayOf(
-                "bolt11",
-                "useTrampoline",
+                "payments",
             ),
         )
     ) {
         return null
     }
-    val bolt11 = sendPaymentRequest.getString("bolt11")!!
-    val useTrampoline = sendPaymentRequest.getBoolean("useTrampoline")
-    val amountMsat = if (hasNonNullKey(sendPaymentRequest, "amountMsat")) sendPaymentRequest.getDouble("amountMsat").toULong() else null
-    val label = if (hasNonNullKey(sendPaymentRequest, "label")) sendPaymentRequest.getString("label") else null
-    return SendPaymentRequest(bolt11, useTrampoline, amountMsat, label)
+    val payments = sendPaymentsRequest.getArray("payments")?.let { asSendPaymentRequestList(it) }!!
+    val maxConcurrency =
+        if (hasNonNullKey(
+                sendPaymentsRequest,
+                "maxConcurrency",
+            )
+        ) {
+            sendPaymentsRequest.getInt("maxConcurrency").toUInt()
+        } else {
+            null
+        }
+    return SendPaymentsRequest(payments, maxConcurrency)
 }
 
-fun readableMapOf(sendPaymentRequest: SendPaymentRequest): ReadableMap =
+fun readableMapOf(sendPaymentsRequest: SendPaymentsRequest): ReadableMap =
     readableMapOf(
-        "bolt11" to sendPaymentRequest.bolt11,
-        "useTrampoline" to sendPaymentRequest.useTrampoline,
-        "amountMsat" to sendPaymentRequest.amountMsat,
-        "label" to sendPaymentRequest.label,
+        "payments" to readableArrayOf(sendPaymentsRequest.payments),
+        "maxConcurrency" to sendPaymentsRequest.maxConcurrency,
     )
 
-fun asSendPaymentRequestList(arr: ReadableArray): List<SendPaymentRequest> {
-    val list = ArrayList<SendPaymentRequest>()
+fun asSendPaymentsRequestList(arr: ReadableArray): List<SendPaymentsRequest> {
+    val list = ArrayList<SendPaymentsRequest>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asSendPaymentRequest(value)!!)
+            is ReadableMap -> list.add(asSendPaymentsRequest(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
     return list
 }
 
-fun asSendPaymentResponse(sendPaymentResponse: ReadableMap): SendPaymentResponse? {
+fun asSendPaymentsResponse(sendPaymentsResponse: ReadableMap): SendPaymentsResponse? {
     if (!validateMandatoryFields(
-            sendPaymentResponse,
+            sendPaymentsResponse,
             arrayOf(
-                "payment",
+                "results",
+                "succeeded",
+                "failed",
             ),
         )
     ) {
         return null
     }
-    val payment = sendPaymentResponse.getMap("payment")?.let { asPayment(it) }!!
-    return SendPaymentResponse(payment)
+    val results = sendPaymentsResponse.getArray("results")?.let { asBatchPaymentResultList(it) }!!
+    val succeeded = sendPaymentsResponse.getInt("succeeded").toUInt()
+    val failed = sendPaymentsResponse.getInt("failed").toUInt()
+    return SendPaymentsResponse(results, succeeded, failed)
 }
 
-fun readableMapOf(sendPaymentResponse: SendPaymentResponse): ReadableMap =
+fun readableMapOf(sendPaymentsResponse: SendPaymentsResponse): ReadableMap =
     readableMapOf(
-        "payment" to readableMapOf(sendPaymentResponse.payment),
+        "results" to readableArrayOf(sendPaymentsResponse.results),
+        "succeeded" to sendPaymentsResponse.succeeded,
+        "failed" to sendPaymentsResponse.failed,
     )
 
-fun asSendPaymentResponseList(arr: ReadableArray): List<SendPaymentResponse> {
-    val list = ArrayList<SendPaymentResponse>()
+fun asSendPaymentsResponseList(arr: ReadableArray): List<SendPaymentsResponse> {
+    val list = ArrayList<SendPaymentsResponse>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asSendPaymentResponse(value)!!)
+            is ReadableMap -> list.add(asSendPaymentsResponse(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
@@ -3094,6 +7124,149 @@ fun asSignMessageResponseList(arr: ReadableArray): List<SignMessageResponse> {
     return list
 }
 
+fun asSnapshotChange(snapshotChange: ReadableMap): SnapshotChange? {
+    if (!validateMandatoryFields(
+            snapshotChange,
+            arrayOf(
+                "path",
+            ),
+        )
+    ) {
+        return null
+    }
+    val path = snapshotChange.getString("path")!!
+    val before = if (hasNonNullKey(snapshotChange, "before")) snapshotChange.getString("before") else null
+    val after = if (hasNonNullKey(snapshotChange, "after")) snapshotChange.getString("after") else null
+    return SnapshotChange(path, before, after)
+}
+
+fun readableMapOf(snapshotChange: SnapshotChange): ReadableMap =
+    readableMapOf(
+        "path" to snapshotChange.path,
+        "before" to snapshotChange.before,
+        "after" to snapshotChange.after,
+    )
+
+fun asSnapshotChangeList(arr: ReadableArray): List<SnapshotChange> {
+    val list = ArrayList<SnapshotChange>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSnapshotChange(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSpendPolicy(spendPolicy: ReadableMap): SpendPolicy? {
+    if (!validateMandatoryFields(
+            spendPolicy,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val dailyLimitMsat = if (hasNonNullKey(spendPolicy, "dailyLimitMsat")) spendPolicy.getDouble("dailyLimitMsat").toULong() else null
+    val perPaymentLimitMsat =
+        if (hasNonNullKey(
+                spendPolicy,
+                "perPaymentLimitMsat",
+            )
+        ) {
+            spendPolicy.getDouble("perPaymentLimitMsat").toULong()
+        } else {
+            null
+        }
+    val allowedDestinations =
+        if (hasNonNullKey(spendPolicy, "allowedDestinations")) {
+            spendPolicy.getArray("allowedDestinations")?.let {
+                asStringList(it)
+            }
+        } else {
+            null
+        }
+    return SpendPolicy(dailyLimitMsat, perPaymentLimitMsat, allowedDestinations)
+}
+
+fun readableMapOf(spendPolicy: SpendPolicy): ReadableMap =
+    readableMapOf(
+        "dailyLimitMsat" to spendPolicy.dailyLimitMsat,
+        "perPaymentLimitMsat" to spendPolicy.perPaymentLimitMsat,
+        "allowedDestinations" to spendPolicy.allowedDestinations?.let { readableArrayOf(it) },
+    )
+
+fun asSpendPolicyList(arr: ReadableArray): List<SpendPolicy> {
+    val list = ArrayList<SpendPolicy>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSpendPolicy(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSpontaneousPaymentMetadata(spontaneousPaymentMetadata: ReadableMap): SpontaneousPaymentMetadata? {
+    if (!validateMandatoryFields(
+            spontaneousPaymentMetadata,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val message = if (hasNonNullKey(spontaneousPaymentMetadata, "message")) spontaneousPaymentMetadata.getString("message") else null
+    val senderPubkey =
+        if (hasNonNullKey(
+                spontaneousPaymentMetadata,
+                "senderPubkey",
+            )
+        ) {
+            spontaneousPaymentMetadata.getString("senderPubkey")
+        } else {
+            null
+        }
+    val senderName =
+        if (hasNonNullKey(
+                spontaneousPaymentMetadata,
+                "senderName",
+            )
+        ) {
+            spontaneousPaymentMetadata.getString("senderName")
+        } else {
+            null
+        }
+    val boostagram =
+        if (hasNonNullKey(
+                spontaneousPaymentMetadata,
+                "boostagram",
+            )
+        ) {
+            spontaneousPaymentMetadata.getString("boostagram")
+        } else {
+            null
+        }
+    return SpontaneousPaymentMetadata(message, senderPubkey, senderName, boostagram)
+}
+
+fun readableMapOf(spontaneousPaymentMetadata: SpontaneousPaymentMetadata): ReadableMap =
+    readableMapOf(
+        "message" to spontaneousPaymentMetadata.message,
+        "senderPubkey" to spontaneousPaymentMetadata.senderPubkey,
+        "senderName" to spontaneousPaymentMetadata.senderName,
+        "boostagram" to spontaneousPaymentMetadata.boostagram,
+    )
+
+fun asSpontaneousPaymentMetadataList(arr: ReadableArray): List<SpontaneousPaymentMetadata> {
+    val list = ArrayList<SpontaneousPaymentMetadata>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSpontaneousPaymentMetadata(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asStaticBackupRequest(staticBackupRequest: ReadableMap): StaticBackupRequest? {
     if (!validateMandatoryFields(
             staticBackupRequest,
@@ -3161,6 +7334,74 @@ fun asStaticBackupResponseList(arr: ReadableArray): List<StaticBackupResponse> {
     return list
 }
 
+fun asStaticLnurlPay(staticLnurlPay: ReadableMap): StaticLnurlPay? {
+    if (!validateMandatoryFields(
+            staticLnurlPay,
+            arrayOf(
+                "lnurl",
+                "metadata",
+                "payRequest",
+            ),
+        )
+    ) {
+        return null
+    }
+    val lnurl = staticLnurlPay.getString("lnurl")!!
+    val metadata = staticLnurlPay.getString("metadata")!!
+    val payRequest = staticLnurlPay.getString("payRequest")!!
+    return StaticLnurlPay(lnurl, metadata, payRequest)
+}
+
+fun readableMapOf(staticLnurlPay: StaticLnurlPay): ReadableMap =
+    readableMapOf(
+        "lnurl" to staticLnurlPay.lnurl,
+        "metadata" to staticLnurlPay.metadata,
+        "payRequest" to staticLnurlPay.payRequest,
+    )
+
+fun asStaticLnurlPayList(arr: ReadableArray): List<StaticLnurlPay> {
+    val list = ArrayList<StaticLnurlPay>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asStaticLnurlPay(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asStaticLnurlPayCallbackRequest(staticLnurlPayCallbackRequest: ReadableMap): StaticLnurlPayCallbackRequest? {
+    if (!validateMandatoryFields(
+            staticLnurlPayCallbackRequest,
+            arrayOf(
+                "amountMsat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val amountMsat = staticLnurlPayCallbackRequest.getDouble("amountMsat").toULong()
+    val comment = if (hasNonNullKey(staticLnurlPayCallbackRequest, "comment")) staticLnurlPayCallbackRequest.getString("comment") else null
+    return StaticLnurlPayCallbackRequest(amountMsat, comment)
+}
+
+fun readableMapOf(staticLnurlPayCallbackRequest: StaticLnurlPayCallbackRequest): ReadableMap =
+    readableMapOf(
+        "amountMsat" to staticLnurlPayCallbackRequest.amountMsat,
+        "comment" to staticLnurlPayCallbackRequest.comment,
+    )
+
+fun asStaticLnurlPayCallbackRequestList(arr: ReadableArray): List<StaticLnurlPayCallbackRequest> {
+    val list = ArrayList<StaticLnurlPayCallbackRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asStaticLnurlPayCallbackRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asSwapInfo(swapInfo: ReadableMap): SwapInfo? {
     if (!validateMandatoryFields(
             swapInfo,
@@ -3249,39 +7490,117 @@ fun asSwapInfo(swapInfo: ReadableMap): SwapInfo? {
     )
 }
 
-fun readableMapOf(swapInfo: SwapInfo): ReadableMap =
+fun readableMapOf(swapInfo: SwapInfo): ReadableMap =
+    readableMapOf(
+        "bitcoinAddress" to swapInfo.bitcoinAddress,
+        "createdAt" to swapInfo.createdAt,
+        "lockHeight" to swapInfo.lockHeight,
+        "paymentHash" to readableArrayOf(swapInfo.paymentHash),
+        "preimage" to readableArrayOf(swapInfo.preimage),
+        "privateKey" to readableArrayOf(swapInfo.privateKey),
+        "publicKey" to readableArrayOf(swapInfo.publicKey),
+        "swapperPublicKey" to readableArrayOf(swapInfo.swapperPublicKey),
+        "script" to readableArrayOf(swapInfo.script),
+        "bolt11" to swapInfo.bolt11,
+        "paidMsat" to swapInfo.paidMsat,
+        "unconfirmedSats" to swapInfo.unconfirmedSats,
+        "confirmedSats" to swapInfo.confirmedSats,
+        "totalIncomingTxs" to swapInfo.totalIncomingTxs,
+        "status" to swapInfo.status.name.lowercase(),
+        "refundTxIds" to readableArrayOf(swapInfo.refundTxIds),
+        "unconfirmedTxIds" to readableArrayOf(swapInfo.unconfirmedTxIds),
+        "confirmedTxIds" to readableArrayOf(swapInfo.confirmedTxIds),
+        "minAllowedDeposit" to swapInfo.minAllowedDeposit,
+        "maxAllowedDeposit" to swapInfo.maxAllowedDeposit,
+        "maxSwapperPayable" to swapInfo.maxSwapperPayable,
+        "lastRedeemError" to swapInfo.lastRedeemError,
+        "channelOpeningFees" to swapInfo.channelOpeningFees?.let { readableMapOf(it) },
+        "confirmedAt" to swapInfo.confirmedAt,
+    )
+
+fun asSwapInfoList(arr: ReadableArray): List<SwapInfo> {
+    val list = ArrayList<SwapInfo>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSwapInfo(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSwapRefundKey(swapRefundKey: ReadableMap): SwapRefundKey? {
+    if (!validateMandatoryFields(
+            swapRefundKey,
+            arrayOf(
+                "index",
+                "derivationPath",
+                "privateKey",
+                "publicKey",
+            ),
+        )
+    ) {
+        return null
+    }
+    val index = swapRefundKey.getInt("index").toUInt()
+    val derivationPath = swapRefundKey.getString("derivationPath")!!
+    val privateKey = swapRefundKey.getArray("privateKey")?.let { asUByteList(it) }!!
+    val publicKey = swapRefundKey.getArray("publicKey")?.let { asUByteList(it) }!!
+    return SwapRefundKey(index, derivationPath, privateKey, publicKey)
+}
+
+fun readableMapOf(swapRefundKey: SwapRefundKey): ReadableMap =
+    readableMapOf(
+        "index" to swapRefundKey.index,
+        "derivationPath" to swapRefundKey.derivationPath,
+        "privateKey" to readableArrayOf(swapRefundKey.privateKey),
+        "publicKey" to readableArrayOf(swapRefundKey.publicKey),
+    )
+
+fun asSwapRefundKeyList(arr: ReadableArray): List<SwapRefundKey> {
+    val list = ArrayList<SwapRefundKey>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asSwapRefundKey(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSwapRefundedDetails(swapRefundedDetails: ReadableMap): SwapRefundedDetails? {
+    if (!validateMandatoryFields(
+            swapRefundedDetails,
+            arrayOf(
+                "swapAddress",
+                "refundTxId",
+                "toAddress",
+                "satPerVbyte",
+            ),
+        )
+    ) {
+        return null
+    }
+    val swapAddress = swapRefundedDetails.getString("swapAddress")!!
+    val refundTxId = swapRefundedDetails.getString("refundTxId")!!
+    val toAddress = swapRefundedDetails.getString("toAddress")!!
+    val satPerVbyte = swapRefundedDetails.getInt("satPerVbyte").toUInt()
+    return SwapRefundedDetails(swapAddress, refundTxId, toAddress, satPerVbyte)
+}
+
+fun readableMapOf(swapRefundedDetails: SwapRefundedDetails): ReadableMap =
     readableMapOf(
-        "bitcoinAddress" to swapInfo.bitcoinAddress,
-        "createdAt" to swapInfo.createdAt,
-        "lockHeight" to swapInfo.lockHeight,
-        "paymentHash" to readableArrayOf(swapInfo.paymentHash),
-        "preimage" to readableArrayOf(swapInfo.preimage),
-        "privateKey" to readableArrayOf(swapInfo.privateKey),
-        "publicKey" to readableArrayOf(swapInfo.publicKey),
-        "swapperPublicKey" to readableArrayOf(swapInfo.swapperPublicKey),
-        "script" to readableArrayOf(swapInfo.script),
-        "bolt11" to swapInfo.bolt11,
-        "paidMsat" to swapInfo.paidMsat,
-        "unconfirmedSats" to swapInfo.unconfirmedSats,
-        "confirmedSats" to swapInfo.confirmedSats,
-        "totalIncomingTxs" to swapInfo.totalIncomingTxs,
-        "status" to swapInfo.status.name.lowercase(),
-        "refundTxIds" to readableArrayOf(swapInfo.refundTxIds),
-        "unconfirmedTxIds" to readableArrayOf(swapInfo.unconfirmedTxIds),
-        "confirmedTxIds" to readableArrayOf(swapInfo.confirmedTxIds),
-        "minAllowedDeposit" to swapInfo.minAllowedDeposit,
-        "maxAllowedDeposit" to swapInfo.maxAllowedDeposit,
-        "maxSwapperPayable" to swapInfo.maxSwapperPayable,
-        "lastRedeemError" to swapInfo.lastRedeemError,
-        "channelOpeningFees" to swapInfo.channelOpeningFees?.let { readableMapOf(it) },
-        "confirmedAt" to swapInfo.confirmedAt,
+        "swapAddress" to swapRefundedDetails.swapAddress,
+        "refundTxId" to swapRefundedDetails.refundTxId,
+        "toAddress" to swapRefundedDetails.toAddress,
+        "satPerVbyte" to swapRefundedDetails.satPerVbyte,
     )
 
-fun asSwapInfoList(arr: ReadableArray): List<SwapInfo> {
-    val list = ArrayList<SwapInfo>()
+fun asSwapRefundedDetailsList(arr: ReadableArray): List<SwapRefundedDetails> {
+    val list = ArrayList<SwapRefundedDetails>()
     for (value in arr.toArrayList()) {
         when (value) {
-            is ReadableMap -> list.add(asSwapInfo(value)!!)
+            is ReadableMap -> list.add(asSwapRefundedDetails(value)!!)
             else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
         }
     }
@@ -3322,6 +7641,46 @@ fun asSymbolList(arr: ReadableArray): List<Symbol> {
     return list
 }
 
+fun asTaskStatus(taskStatus: ReadableMap): TaskStatus? {
+    if (!validateMandatoryFields(
+            taskStatus,
+            arrayOf(
+                "name",
+                "state",
+                "restarts",
+            ),
+        )
+    ) {
+        return null
+    }
+    val name = taskStatus.getString("name")!!
+    val state = taskStatus.getString("state")?.let { asTaskState(it) }!!
+    val restarts = taskStatus.getInt("restarts").toUInt()
+    val lastError = if (hasNonNullKey(taskStatus, "lastError")) taskStatus.getString("lastError") else null
+    val lastErrorAt = if (hasNonNullKey(taskStatus, "lastErrorAt")) taskStatus.getDouble("lastErrorAt").toLong() else null
+    return TaskStatus(name, state, restarts, lastError, lastErrorAt)
+}
+
+fun readableMapOf(taskStatus: TaskStatus): ReadableMap =
+    readableMapOf(
+        "name" to taskStatus.name,
+        "state" to taskStatus.state.name.lowercase(),
+        "restarts" to taskStatus.restarts,
+        "lastError" to taskStatus.lastError,
+        "lastErrorAt" to taskStatus.lastErrorAt,
+    )
+
+fun asTaskStatusList(arr: ReadableArray): List<TaskStatus> {
+    val list = ArrayList<TaskStatus>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asTaskStatus(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asTlvEntry(tlvEntry: ReadableMap): TlvEntry? {
     if (!validateMandatoryFields(
             tlvEntry,
@@ -3355,6 +7714,82 @@ fun asTlvEntryList(arr: ReadableArray): List<TlvEntry> {
     return list
 }
 
+fun asTlvRecord(tlvRecord: ReadableMap): TlvRecord? {
+    if (!validateMandatoryFields(
+            tlvRecord,
+            arrayOf(
+                "fieldNumber",
+            ),
+        )
+    ) {
+        return null
+    }
+    val fieldNumber = tlvRecord.getDouble("fieldNumber").toULong()
+    val valueText = if (hasNonNullKey(tlvRecord, "valueText")) tlvRecord.getString("valueText") else null
+    val valueHex = if (hasNonNullKey(tlvRecord, "valueHex")) tlvRecord.getString("valueHex") else null
+    return TlvRecord(fieldNumber, valueText, valueHex)
+}
+
+fun readableMapOf(tlvRecord: TlvRecord): ReadableMap =
+    readableMapOf(
+        "fieldNumber" to tlvRecord.fieldNumber,
+        "valueText" to tlvRecord.valueText,
+        "valueHex" to tlvRecord.valueHex,
+    )
+
+fun asTlvRecordList(arr: ReadableArray): List<TlvRecord> {
+    val list = ArrayList<TlvRecord>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asTlvRecord(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asUnredeemedFundsDetails(unredeemedFundsDetails: ReadableMap): UnredeemedFundsDetails? {
+    if (!validateMandatoryFields(
+            unredeemedFundsDetails,
+            arrayOf(
+                "refundableSwaps",
+                "redeemableSwaps",
+                "expiredInvoiceSwaps",
+                "unclaimedReverseSwaps",
+                "totalAmountSat",
+            ),
+        )
+    ) {
+        return null
+    }
+    val refundableSwaps = unredeemedFundsDetails.getArray("refundableSwaps")?.let { asSwapInfoList(it) }!!
+    val redeemableSwaps = unredeemedFundsDetails.getArray("redeemableSwaps")?.let { asSwapInfoList(it) }!!
+    val expiredInvoiceSwaps = unredeemedFundsDetails.getArray("expiredInvoiceSwaps")?.let { asSwapInfoList(it) }!!
+    val unclaimedReverseSwaps = unredeemedFundsDetails.getArray("unclaimedReverseSwaps")?.let { asReverseSwapInfoList(it) }!!
+    val totalAmountSat = unredeemedFundsDetails.getDouble("totalAmountSat").toULong()
+    return UnredeemedFundsDetails(refundableSwaps, redeemableSwaps, expiredInvoiceSwaps, unclaimedReverseSwaps, totalAmountSat)
+}
+
+fun readableMapOf(unredeemedFundsDetails: UnredeemedFundsDetails): ReadableMap =
+    readableMapOf(
+        "refundableSwaps" to readableArrayOf(unredeemedFundsDetails.refundableSwaps),
+        "redeemableSwaps" to readableArrayOf(unredeemedFundsDetails.redeemableSwaps),
+        "expiredInvoiceSwaps" to readableArrayOf(unredeemedFundsDetails.expiredInvoiceSwaps),
+        "unclaimedReverseSwaps" to readableArrayOf(unredeemedFundsDetails.unclaimedReverseSwaps),
+        "totalAmountSat" to unredeemedFundsDetails.totalAmountSat,
+    )
+
+fun asUnredeemedFundsDetailsList(arr: ReadableArray): List<UnredeemedFundsDetails> {
+    val list = ArrayList<UnredeemedFundsDetails>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asUnredeemedFundsDetails(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asUnspentTransactionOutput(unspentTransactionOutput: ReadableMap): UnspentTransactionOutput? {
     if (!validateMandatoryFields(
             unspentTransactionOutput,
@@ -3433,6 +7868,110 @@ fun asUrlSuccessActionDataList(arr: ReadableArray): List<UrlSuccessActionData> {
     return list
 }
 
+fun asUserSettings(userSettings: ReadableMap): UserSettings? {
+    if (!validateMandatoryFields(
+            userSettings,
+            arrayOf(),
+        )
+    ) {
+        return null
+    }
+    val preferredFiatCurrency =
+        if (hasNonNullKey(
+                userSettings,
+                "preferredFiatCurrency",
+            )
+        ) {
+            userSettings.getString("preferredFiatCurrency")
+        } else {
+            null
+        }
+    val denomination =
+        if (hasNonNullKey(
+                userSettings,
+                "denomination",
+            )
+        ) {
+            userSettings.getString("denomination")?.let { asDenomination(it) }
+        } else {
+            null
+        }
+    val defaultFeeratePreset =
+        if (hasNonNullKey(
+                userSettings,
+                "defaultFeeratePreset",
+            )
+        ) {
+            userSettings.getString("defaultFeeratePreset")?.let {
+                asFeeratePreset(it)
+            }
+        } else {
+            null
+        }
+    val preferredLspId = if (hasNonNullKey(userSettings, "preferredLspId")) userSettings.getString("preferredLspId") else null
+    return UserSettings(preferredFiatCurrency, denomination, defaultFeeratePreset, preferredLspId)
+}
+
+fun readableMapOf(userSettings: UserSettings): ReadableMap =
+    readableMapOf(
+        "preferredFiatCurrency" to userSettings.preferredFiatCurrency,
+        "denomination" to userSettings.denomination?.let { it.name.lowercase() },
+        "defaultFeeratePreset" to userSettings.defaultFeeratePreset?.let { it.name.lowercase() },
+        "preferredLspId" to userSettings.preferredLspId,
+    )
+
+fun asUserSettingsList(arr: ReadableArray): List<UserSettings> {
+    val list = ArrayList<UserSettings>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asUserSettings(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asValidatePayabilityRequest(validatePayabilityRequest: ReadableMap): ValidatePayabilityRequest? {
+    if (!validateMandatoryFields(
+            validatePayabilityRequest,
+            arrayOf(
+                "bolt11",
+            ),
+        )
+    ) {
+        return null
+    }
+    val bolt11 = validatePayabilityRequest.getString("bolt11")!!
+    val amountMsat =
+        if (hasNonNullKey(
+                validatePayabilityRequest,
+                "amountMsat",
+            )
+        ) {
+            validatePayabilityRequest.getDouble("amountMsat").toULong()
+        } else {
+            null
+        }
+    return ValidatePayabilityRequest(bolt11, amountMsat)
+}
+
+fun readableMapOf(validatePayabilityRequest: ValidatePayabilityRequest): ReadableMap =
+    readableMapOf(
+        "bolt11" to validatePayabilityRequest.bolt11,
+        "amountMsat" to validatePayabilityRequest.amountMsat,
+    )
+
+fun asValidatePayabilityRequestList(arr: ReadableArray): List<ValidatePayabilityRequest> {
+    val list = ArrayList<ValidatePayabilityRequest>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asValidatePayabilityRequest(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asAesSuccessActionDataResult(aesSuccessActionDataResult: ReadableMap): AesSuccessActionDataResult? {
     val type = aesSuccessActionDataResult.getString("type")
 
@@ -3473,6 +8012,100 @@ fun asAesSuccessActionDataResultList(arr: ReadableArray): List<AesSuccessActionD
     return list
 }
 
+fun asAmount(amount: ReadableMap): Amount? {
+    val type = amount.getString("type")
+
+    if (type == "bitcoin") {
+        val amountMsat = amount.getDouble("amountMsat").toULong()
+        return Amount.Bitcoin(amountMsat)
+    }
+    if (type == "currency") {
+        val iso4217Code = amount.getString("iso4217Code")!!
+        val fractionalAmount = amount.getDouble("fractionalAmount").toULong()
+        return Amount.Currency(iso4217Code, fractionalAmount)
+    }
+    return null
+}
+
+fun readableMapOf(amount: Amount): ReadableMap? {
+    val map = Arguments.createMap()
+    when (amount) {
+        is Amount.Bitcoin -> {
+            pushToMap(map, "type", "bitcoin")
+            pushToMap(map, "amountMsat", amount.amountMsat)
+        }
+        is Amount.Currency -> {
+            pushToMap(map, "type", "currency")
+            pushToMap(map, "iso4217Code", amount.iso4217Code)
+            pushToMap(map, "fractionalAmount", amount.fractionalAmount)
+        }
+    }
+    return map
+}
+
+fun asAmountList(arr: ReadableArray): List<Amount> {
+    val list = ArrayList<Amount>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asAmount(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asBackupTransportConfig(backupTransportConfig: ReadableMap): BackupTransportConfig? {
+    val type = backupTransportConfig.getString("type")
+
+    if (type == "s3") {
+        val endpoint = backupTransportConfig.getString("endpoint")!!
+        val region = backupTransportConfig.getString("region")!!
+        val bucket = backupTransportConfig.getString("bucket")!!
+        val accessKeyId = backupTransportConfig.getString("accessKeyId")!!
+        val secretAccessKey = backupTransportConfig.getString("secretAccessKey")!!
+        return BackupTransportConfig.S3(endpoint, region, bucket, accessKeyId, secretAccessKey)
+    }
+    if (type == "webDav") {
+        val url = backupTransportConfig.getString("url")!!
+        val username = if (hasNonNullKey(backupTransportConfig, "username")) backupTransportConfig.getString("username") else null
+        val password = if (hasNonNullKey(backupTransportConfig, "password")) backupTransportConfig.getString("password") else null
+        return BackupTransportConfig.WebDav(url, username, password)
+    }
+    return null
+}
+
+fun readableMapOf(backupTransportConfig: BackupTransportConfig): ReadableMap? {
+    val map = Arguments.createMap()
+    when (backupTransportConfig) {
+        is BackupTransportConfig.S3 -> {
+            pushToMap(map, "type", "s3")
+            pushToMap(map, "endpoint", backupTransportConfig.endpoint)
+            pushToMap(map, "region", backupTransportConfig.region)
+            pushToMap(map, "bucket", backupTransportConfig.bucket)
+            pushToMap(map, "accessKeyId", backupTransportConfig.accessKeyId)
+            pushToMap(map, "secretAccessKey", backupTransportConfig.secretAccessKey)
+        }
+        is BackupTransportConfig.WebDav -> {
+            pushToMap(map, "type", "webDav")
+            pushToMap(map, "url", backupTransportConfig.url)
+            pushToMap(map, "username", backupTransportConfig.username)
+            pushToMap(map, "password", backupTransportConfig.password)
+        }
+    }
+    return map
+}
+
+fun asBackupTransportConfigList(arr: ReadableArray): List<BackupTransportConfig> {
+    val list = ArrayList<BackupTransportConfig>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asBackupTransportConfig(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asBreezEvent(breezEvent: ReadableMap): BreezEvent? {
     val type = breezEvent.getString("type")
 
@@ -3513,6 +8146,91 @@ fun asBreezEvent(breezEvent: ReadableMap): BreezEvent? {
         val details = breezEvent.getMap("details")?.let { asSwapInfo(it) }!!
         return BreezEvent.SwapUpdated(details)
     }
+    if (type == "connectProgress") {
+        val details = breezEvent.getMap("details")?.let { asConnectProgressDetails(it) }!!
+        return BreezEvent.ConnectProgress(details)
+    }
+    if (type == "unredeemedFundsDetected") {
+        val details = breezEvent.getMap("details")?.let { asUnredeemedFundsDetails(it) }!!
+        return BreezEvent.UnredeemedFundsDetected(details)
+    }
+    if (type == "lnUrlPayFinished") {
+        val details = breezEvent.getMap("details")?.let { asLnUrlPayFinishedDetails(it) }!!
+        return BreezEvent.LnUrlPayFinished(details)
+    }
+    if (type == "openChannelReceiveUpdated") {
+        val details = breezEvent.getMap("details")?.let { asOpenChannelReceiveDetails(it) }!!
+        return BreezEvent.OpenChannelReceiveUpdated(details)
+    }
+    if (type == "lowOutboundLiquidity") {
+        val details = breezEvent.getMap("details")?.let { asLowLiquidityDetails(it) }!!
+        return BreezEvent.LowOutboundLiquidity(details)
+    }
+    if (type == "lowInboundLiquidity") {
+        val details = breezEvent.getMap("details")?.let { asLowLiquidityDetails(it) }!!
+        return BreezEvent.LowInboundLiquidity(details)
+    }
+    if (type == "channelOpening") {
+        val feeMsat = breezEvent.getDouble("feeMsat").toULong()
+        val minFeeMsat = breezEvent.getDouble("minFeeMsat").toULong()
+        val proportional = breezEvent.getInt("proportional").toUInt()
+        return BreezEvent.ChannelOpening(feeMsat, minFeeMsat, proportional)
+    }
+    if (type == "lspChanged") {
+        val previousLspId = if (hasNonNullKey(breezEvent, "previousLspId")) breezEvent.getString("previousLspId") else null
+        val lspId = breezEvent.getString("lspId")!!
+        return BreezEvent.LspChanged(previousLspId, lspId)
+    }
+    if (type == "swapRefunded") {
+        val details = breezEvent.getMap("details")?.let { asSwapRefundedDetails(it) }!!
+        return BreezEvent.SwapRefunded(details)
+    }
+    if (type == "batchPaymentProgress") {
+        val details = breezEvent.getMap("details")?.let { asBatchPaymentProgressDetails(it) }!!
+        return BreezEvent.BatchPaymentProgress(details)
+    }
+    if (type == "outboxPaymentUpdated") {
+        val details = breezEvent.getMap("details")?.let { asOutboxPaymentDetails(it) }!!
+        return BreezEvent.OutboxPaymentUpdated(details)
+    }
+    if (type == "lnUrlAuthCompleted") {
+        val details = breezEvent.getMap("details")?.let { asLnUrlAuthDomain(it) }!!
+        return BreezEvent.LnUrlAuthCompleted(details)
+    }
+    if (type == "channelHygieneSuggestion") {
+        val details = breezEvent.getMap("details")?.let { asChannelHygieneSuggestionDetails(it) }!!
+        return BreezEvent.ChannelHygieneSuggestion(details)
+    }
+    if (type == "fiatRateAlertTriggered") {
+        val details = breezEvent.getMap("details")?.let { asFiatRateAlertTriggeredDetails(it) }!!
+        return BreezEvent.FiatRateAlertTriggered(details)
+    }
+    if (type == "connected") {
+        return BreezEvent.Connected
+    }
+    if (type == "disconnected") {
+        val error = breezEvent.getString("error")!!
+        return BreezEvent.Disconnected(error)
+    }
+    if (type == "reconnecting") {
+        val attempt = breezEvent.getInt("attempt").toUInt()
+        val delayMs = breezEvent.getDouble("delayMs").toULong()
+        return BreezEvent.Reconnecting(attempt, delayMs)
+    }
+    if (type == "lspPeerReconnected") {
+        val lspPubkey = breezEvent.getString("lspPubkey")!!
+        val reason = breezEvent.getString("reason")!!
+        return BreezEvent.LspPeerReconnected(lspPubkey, reason)
+    }
+    if (type == "spontaneousPaymentReceived") {
+        val details = breezEvent.getMap("details")?.let { asPayment(it) }!!
+        return BreezEvent.SpontaneousPaymentReceived(details)
+    }
+    if (type == "serviceStatusChanged") {
+        val component = breezEvent.getString("component")?.let { asServiceComponent(it) }!!
+        val status = breezEvent.getString("status")?.let { asServiceStatus(it) }!!
+        return BreezEvent.ServiceStatusChanged(component, status)
+    }
     return null
 }
 
@@ -3556,6 +8274,91 @@ fun readableMapOf(breezEvent: BreezEvent): ReadableMap? {
             pushToMap(map, "type", "swapUpdated")
             pushToMap(map, "details", readableMapOf(breezEvent.details))
         }
+        is BreezEvent.ConnectProgress -> {
+            pushToMap(map, "type", "connectProgress")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.UnredeemedFundsDetected -> {
+            pushToMap(map, "type", "unredeemedFundsDetected")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.LnUrlPayFinished -> {
+            pushToMap(map, "type", "lnUrlPayFinished")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.OpenChannelReceiveUpdated -> {
+            pushToMap(map, "type", "openChannelReceiveUpdated")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.LowOutboundLiquidity -> {
+            pushToMap(map, "type", "lowOutboundLiquidity")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.LowInboundLiquidity -> {
+            pushToMap(map, "type", "lowInboundLiquidity")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.ChannelOpening -> {
+            pushToMap(map, "type", "channelOpening")
+            pushToMap(map, "feeMsat", breezEvent.feeMsat)
+            pushToMap(map, "minFeeMsat", breezEvent.minFeeMsat)
+            pushToMap(map, "proportional", breezEvent.proportional)
+        }
+        is BreezEvent.LspChanged -> {
+            pushToMap(map, "type", "lspChanged")
+            pushToMap(map, "previousLspId", breezEvent.previousLspId)
+            pushToMap(map, "lspId", breezEvent.lspId)
+        }
+        is BreezEvent.SwapRefunded -> {
+            pushToMap(map, "type", "swapRefunded")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.BatchPaymentProgress -> {
+            pushToMap(map, "type", "batchPaymentProgress")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.OutboxPaymentUpdated -> {
+            pushToMap(map, "type", "outboxPaymentUpdated")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.LnUrlAuthCompleted -> {
+            pushToMap(map, "type", "lnUrlAuthCompleted")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.ChannelHygieneSuggestion -> {
+            pushToMap(map, "type", "channelHygieneSuggestion")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.FiatRateAlertTriggered -> {
+            pushToMap(map, "type", "fiatRateAlertTriggered")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.Connected -> {
+            pushToMap(map, "type", "connected")
+        }
+        is BreezEvent.Disconnected -> {
+            pushToMap(map, "type", "disconnected")
+            pushToMap(map, "error", breezEvent.error)
+        }
+        is BreezEvent.Reconnecting -> {
+            pushToMap(map, "type", "reconnecting")
+            pushToMap(map, "attempt", breezEvent.attempt)
+            pushToMap(map, "delayMs", breezEvent.delayMs)
+        }
+        is BreezEvent.LspPeerReconnected -> {
+            pushToMap(map, "type", "lspPeerReconnected")
+            pushToMap(map, "lspPubkey", breezEvent.lspPubkey)
+            pushToMap(map, "reason", breezEvent.reason)
+        }
+        is BreezEvent.SpontaneousPaymentReceived -> {
+            pushToMap(map, "type", "spontaneousPaymentReceived")
+            pushToMap(map, "details", readableMapOf(breezEvent.details))
+        }
+        is BreezEvent.ServiceStatusChanged -> {
+            pushToMap(map, "type", "serviceStatusChanged")
+            pushToMap(map, "component", breezEvent.component.name.lowercase())
+            pushToMap(map, "status", breezEvent.status.name.lowercase())
+        }
     }
     return map
 }
@@ -3584,6 +8387,46 @@ fun asBuyBitcoinProviderList(arr: ReadableArray): List<BuyBitcoinProvider> {
     return list
 }
 
+fun asChainServiceConfig(chainServiceConfig: ReadableMap): ChainServiceConfig? {
+    val type = chainServiceConfig.getString("type")
+
+    if (type == "esplora") {
+        val url = chainServiceConfig.getString("url")!!
+        return ChainServiceConfig.Esplora(url)
+    }
+    if (type == "electrum") {
+        val url = chainServiceConfig.getString("url")!!
+        return ChainServiceConfig.Electrum(url)
+    }
+    return null
+}
+
+fun readableMapOf(chainServiceConfig: ChainServiceConfig): ReadableMap? {
+    val map = Arguments.createMap()
+    when (chainServiceConfig) {
+        is ChainServiceConfig.Esplora -> {
+            pushToMap(map, "type", "esplora")
+            pushToMap(map, "url", chainServiceConfig.url)
+        }
+        is ChainServiceConfig.Electrum -> {
+            pushToMap(map, "type", "electrum")
+            pushToMap(map, "url", chainServiceConfig.url)
+        }
+    }
+    return map
+}
+
+fun asChainServiceConfigList(arr: ReadableArray): List<ChainServiceConfig> {
+    val list = ArrayList<ChainServiceConfig>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asChainServiceConfig(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asChannelState(type: String): ChannelState = ChannelState.valueOf(camelToUpperSnakeCase(type))
 
 fun asChannelStateList(arr: ReadableArray): List<ChannelState> {
@@ -3597,6 +8440,45 @@ fun asChannelStateList(arr: ReadableArray): List<ChannelState> {
     return list
 }
 
+fun asConnectStage(type: String): ConnectStage = ConnectStage.valueOf(camelToUpperSnakeCase(type))
+
+fun asConnectStageList(arr: ReadableArray): List<ConnectStage> {
+    val list = ArrayList<ConnectStage>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asConnectStage(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asConnectionState(type: String): ConnectionState = ConnectionState.valueOf(camelToUpperSnakeCase(type))
+
+fun asConnectionStateList(arr: ReadableArray): List<ConnectionState> {
+    val list = ArrayList<ConnectionState>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asConnectionState(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asDenomination(type: String): Denomination = Denomination.valueOf(camelToUpperSnakeCase(type))
+
+fun asDenominationList(arr: ReadableArray): List<Denomination> {
+    val list = ArrayList<Denomination>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asDenomination(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asEnvironmentType(type: String): EnvironmentType = EnvironmentType.valueOf(camelToUpperSnakeCase(type))
 
 fun asEnvironmentTypeList(arr: ReadableArray): List<EnvironmentType> {
@@ -3610,6 +8492,32 @@ fun asEnvironmentTypeList(arr: ReadableArray): List<EnvironmentType> {
     return list
 }
 
+fun asExportFormat(type: String): ExportFormat = ExportFormat.valueOf(camelToUpperSnakeCase(type))
+
+fun asExportFormatList(arr: ReadableArray): List<ExportFormat> {
+    val list = ArrayList<ExportFormat>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asExportFormat(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asFeatureSupport(type: String): FeatureSupport = FeatureSupport.valueOf(camelToUpperSnakeCase(type))
+
+fun asFeatureSupportList(arr: ReadableArray): List<FeatureSupport> {
+    val list = ArrayList<FeatureSupport>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asFeatureSupport(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asFeeratePreset(type: String): FeeratePreset = FeeratePreset.valueOf(camelToUpperSnakeCase(type))
 
 fun asFeeratePresetList(arr: ReadableArray): List<FeeratePreset> {
@@ -3623,6 +8531,19 @@ fun asFeeratePresetList(arr: ReadableArray): List<FeeratePreset> {
     return list
 }
 
+fun asFiatRateAlertDirection(type: String): FiatRateAlertDirection = FiatRateAlertDirection.valueOf(camelToUpperSnakeCase(type))
+
+fun asFiatRateAlertDirectionList(arr: ReadableArray): List<FiatRateAlertDirection> {
+    val list = ArrayList<FiatRateAlertDirection>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asFiatRateAlertDirection(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asHealthCheckStatus(type: String): HealthCheckStatus = HealthCheckStatus.valueOf(camelToUpperSnakeCase(type))
 
 fun asHealthCheckStatusList(arr: ReadableArray): List<HealthCheckStatus> {
@@ -3647,6 +8568,11 @@ fun asInputType(inputType: ReadableMap): InputType? {
         val invoice = inputType.getMap("invoice")?.let { asLnInvoice(it) }!!
         return InputType.Bolt11(invoice)
     }
+    if (type == "bolt12Offer") {
+        val offer = inputType.getMap("offer")?.let { asLnOffer(it) }!!
+        val bip353Address = if (hasNonNullKey(inputType, "bip353Address")) inputType.getString("bip353Address") else null
+        return InputType.Bolt12Offer(offer, bip353Address)
+    }
     if (type == "nodeId") {
         val nodeId = inputType.getString("nodeId")!!
         return InputType.NodeId(nodeId)
@@ -3658,7 +8584,9 @@ fun asInputType(inputType: ReadableMap): InputType? {
     if (type == "lnUrlPay") {
         val data = inputType.getMap("data")?.let { asLnUrlPayRequestData(it) }!!
         val bip353Address = if (hasNonNullKey(inputType, "bip353Address")) inputType.getString("bip353Address") else null
-        return InputType.LnUrlPay(data, bip353Address)
+        val amountMsat = if (hasNonNullKey(inputType, "amountMsat")) inputType.getDouble("amountMsat").toULong() else null
+        val comment = if (hasNonNullKey(inputType, "comment")) inputType.getString("comment") else null
+        return InputType.LnUrlPay(data, bip353Address, amountMsat, comment)
     }
     if (type == "lnUrlWithdraw") {
         val data = inputType.getMap("data")?.let { asLnUrlWithdrawRequestData(it) }!!
@@ -3672,6 +8600,10 @@ fun asInputType(inputType: ReadableMap): InputType? {
         val data = inputType.getMap("data")?.let { asLnUrlErrorData(it) }!!
         return InputType.LnUrlError(data)
     }
+    if (type == "encryptedPaymentRequest") {
+        val data = inputType.getMap("data")?.let { asEncryptedPaymentRequestData(it) }!!
+        return InputType.EncryptedPaymentRequest(data)
+    }
     return null
 }
 
@@ -3686,6 +8618,11 @@ fun readableMapOf(inputType: InputType): ReadableMap? {
             pushToMap(map, "type", "bolt11")
             pushToMap(map, "invoice", readableMapOf(inputType.invoice))
         }
+        is InputType.Bolt12Offer -> {
+            pushToMap(map, "type", "bolt12Offer")
+            pushToMap(map, "offer", readableMapOf(inputType.offer))
+            pushToMap(map, "bip353Address", inputType.bip353Address)
+        }
         is InputType.NodeId -> {
             pushToMap(map, "type", "nodeId")
             pushToMap(map, "nodeId", inputType.nodeId)
@@ -3698,6 +8635,8 @@ fun readableMapOf(inputType: InputType): ReadableMap? {
             pushToMap(map, "type", "lnUrlPay")
             pushToMap(map, "data", readableMapOf(inputType.data))
             pushToMap(map, "bip353Address", inputType.bip353Address)
+            pushToMap(map, "amountMsat", inputType.amountMsat)
+            pushToMap(map, "comment", inputType.comment)
         }
         is InputType.LnUrlWithdraw -> {
             pushToMap(map, "type", "lnUrlWithdraw")
@@ -3711,6 +8650,10 @@ fun readableMapOf(inputType: InputType): ReadableMap? {
             pushToMap(map, "type", "lnUrlError")
             pushToMap(map, "data", readableMapOf(inputType.data))
         }
+        is InputType.EncryptedPaymentRequest -> {
+            pushToMap(map, "type", "encryptedPaymentRequest")
+            pushToMap(map, "data", readableMapOf(inputType.data))
+        }
     }
     return map
 }
@@ -3779,6 +8722,10 @@ fun asLnUrlPayResult(lnUrlPayResult: ReadableMap): LnUrlPayResult? {
         val data = lnUrlPayResult.getMap("data")?.let { asLnUrlPayErrorData(it) }!!
         return LnUrlPayResult.PayError(data)
     }
+    if (type == "pending") {
+        val data = lnUrlPayResult.getMap("data")?.let { asLnUrlPayPendingData(it) }!!
+        return LnUrlPayResult.Pending(data)
+    }
     return null
 }
 
@@ -3797,6 +8744,10 @@ fun readableMapOf(lnUrlPayResult: LnUrlPayResult): ReadableMap? {
             pushToMap(map, "type", "payError")
             pushToMap(map, "data", readableMapOf(lnUrlPayResult.data))
         }
+        is LnUrlPayResult.Pending -> {
+            pushToMap(map, "type", "pending")
+            pushToMap(map, "data", readableMapOf(lnUrlPayResult.data))
+        }
     }
     return map
 }
@@ -3860,6 +8811,63 @@ fun asLnUrlWithdrawResultList(arr: ReadableArray): List<LnUrlWithdrawResult> {
     return list
 }
 
+fun asLspPolicy(lspPolicy: ReadableMap): LspPolicy? {
+    val type = lspPolicy.getString("type")
+
+    if (type == "lowestOpeningFee") {
+        return LspPolicy.LowestOpeningFee
+    }
+    if (type == "lowestProportionalFee") {
+        return LspPolicy.LowestProportionalFee
+    }
+    if (type == "pinned") {
+        val lspId = lspPolicy.getString("lspId")!!
+        return LspPolicy.Pinned(lspId)
+    }
+    return null
+}
+
+fun readableMapOf(lspPolicy: LspPolicy): ReadableMap? {
+    val map = Arguments.createMap()
+    when (lspPolicy) {
+        is LspPolicy.LowestOpeningFee -> {
+            pushToMap(map, "type", "lowestOpeningFee")
+        }
+        is LspPolicy.LowestProportionalFee -> {
+            pushToMap(map, "type", "lowestProportionalFee")
+        }
+        is LspPolicy.Pinned -> {
+            pushToMap(map, "type", "pinned")
+            pushToMap(map, "lspId", lspPolicy.lspId)
+        }
+    }
+    return map
+}
+
+fun asLspPolicyList(arr: ReadableArray): List<LspPolicy> {
+    val list = ArrayList<LspPolicy>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asLspPolicy(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asMemoPrivacy(type: String): MemoPrivacy = MemoPrivacy.valueOf(camelToUpperSnakeCase(type))
+
+fun asMemoPrivacyList(arr: ReadableArray): List<MemoPrivacy> {
+    val list = ArrayList<MemoPrivacy>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asMemoPrivacy(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asNetwork(type: String): Network = Network.valueOf(camelToUpperSnakeCase(type))
 
 fun asNetworkList(arr: ReadableArray): List<Network> {
@@ -3880,6 +8888,10 @@ fun asNodeConfig(nodeConfig: ReadableMap): NodeConfig? {
         val config = nodeConfig.getMap("config")?.let { asGreenlightNodeConfig(it) }!!
         return NodeConfig.Greenlight(config)
     }
+    if (type == "mock") {
+        val config = nodeConfig.getMap("config")?.let { asMockNodeConfig(it) }!!
+        return NodeConfig.Mock(config)
+    }
     return null
 }
 
@@ -3890,6 +8902,10 @@ fun readableMapOf(nodeConfig: NodeConfig): ReadableMap? {
             pushToMap(map, "type", "greenlight")
             pushToMap(map, "config", readableMapOf(nodeConfig.config))
         }
+        is NodeConfig.Mock -> {
+            pushToMap(map, "type", "mock")
+            pushToMap(map, "config", readableMapOf(nodeConfig.config))
+        }
     }
     return map
 }
@@ -3937,6 +8953,150 @@ fun asNodeCredentialsList(arr: ReadableArray): List<NodeCredentials> {
     return list
 }
 
+fun asOpenChannelReceiveStage(openChannelReceiveStage: ReadableMap): OpenChannelReceiveStage? {
+    val type = openChannelReceiveStage.getString("type")
+
+    if (type == "registered") {
+        val openingFeeMsat = openChannelReceiveStage.getDouble("openingFeeMsat").toULong()
+        return OpenChannelReceiveStage.Registered(openingFeeMsat)
+    }
+    if (type == "channelPending") {
+        val fundingTxid = openChannelReceiveStage.getString("fundingTxid")!!
+        return OpenChannelReceiveStage.ChannelPending(fundingTxid)
+    }
+    if (type == "settled") {
+        val openingFeeMsat = openChannelReceiveStage.getDouble("openingFeeMsat").toULong()
+        return OpenChannelReceiveStage.Settled(openingFeeMsat)
+    }
+    if (type == "reregistered") {
+        return OpenChannelReceiveStage.Reregistered
+    }
+    return null
+}
+
+fun readableMapOf(openChannelReceiveStage: OpenChannelReceiveStage): ReadableMap? {
+    val map = Arguments.createMap()
+    when (openChannelReceiveStage) {
+        is OpenChannelReceiveStage.Registered -> {
+            pushToMap(map, "type", "registered")
+            pushToMap(map, "openingFeeMsat", openChannelReceiveStage.openingFeeMsat)
+        }
+        is OpenChannelReceiveStage.ChannelPending -> {
+            pushToMap(map, "type", "channelPending")
+            pushToMap(map, "fundingTxid", openChannelReceiveStage.fundingTxid)
+        }
+        is OpenChannelReceiveStage.Settled -> {
+            pushToMap(map, "type", "settled")
+            pushToMap(map, "openingFeeMsat", openChannelReceiveStage.openingFeeMsat)
+        }
+        is OpenChannelReceiveStage.Reregistered -> {
+            pushToMap(map, "type", "reregistered")
+        }
+    }
+    return map
+}
+
+fun asOpenChannelReceiveStageList(arr: ReadableArray): List<OpenChannelReceiveStage> {
+    val list = ArrayList<OpenChannelReceiveStage>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOpenChannelReceiveStage(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asOutboxPaymentStatus(outboxPaymentStatus: ReadableMap): OutboxPaymentStatus? {
+    val type = outboxPaymentStatus.getString("type")
+
+    if (type == "queued") {
+        return OutboxPaymentStatus.Queued
+    }
+    if (type == "sent") {
+        val payment = outboxPaymentStatus.getMap("payment")?.let { asPayment(it) }!!
+        return OutboxPaymentStatus.Sent(payment)
+    }
+    if (type == "abandoned") {
+        return OutboxPaymentStatus.Abandoned
+    }
+    if (type == "cancelled") {
+        return OutboxPaymentStatus.Cancelled
+    }
+    return null
+}
+
+fun readableMapOf(outboxPaymentStatus: OutboxPaymentStatus): ReadableMap? {
+    val map = Arguments.createMap()
+    when (outboxPaymentStatus) {
+        is OutboxPaymentStatus.Queued -> {
+            pushToMap(map, "type", "queued")
+        }
+        is OutboxPaymentStatus.Sent -> {
+            pushToMap(map, "type", "sent")
+            pushToMap(map, "payment", readableMapOf(outboxPaymentStatus.payment))
+        }
+        is OutboxPaymentStatus.Abandoned -> {
+            pushToMap(map, "type", "abandoned")
+        }
+        is OutboxPaymentStatus.Cancelled -> {
+            pushToMap(map, "type", "cancelled")
+        }
+    }
+    return map
+}
+
+fun asOutboxPaymentStatusList(arr: ReadableArray): List<OutboxPaymentStatus> {
+    val list = ArrayList<OutboxPaymentStatus>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asOutboxPaymentStatus(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asPartnerFeeDestination(partnerFeeDestination: ReadableMap): PartnerFeeDestination? {
+    val type = partnerFeeDestination.getString("type")
+
+    if (type == "nodeId") {
+        val nodeId = partnerFeeDestination.getString("nodeId")!!
+        return PartnerFeeDestination.NodeId(nodeId)
+    }
+    if (type == "lightningAddress") {
+        val address = partnerFeeDestination.getString("address")!!
+        return PartnerFeeDestination.LightningAddress(address)
+    }
+    return null
+}
+
+fun readableMapOf(partnerFeeDestination: PartnerFeeDestination): ReadableMap? {
+    val map = Arguments.createMap()
+    when (partnerFeeDestination) {
+        is PartnerFeeDestination.NodeId -> {
+            pushToMap(map, "type", "nodeId")
+            pushToMap(map, "nodeId", partnerFeeDestination.nodeId)
+        }
+        is PartnerFeeDestination.LightningAddress -> {
+            pushToMap(map, "type", "lightningAddress")
+            pushToMap(map, "address", partnerFeeDestination.address)
+        }
+    }
+    return map
+}
+
+fun asPartnerFeeDestinationList(arr: ReadableArray): List<PartnerFeeDestination> {
+    val list = ArrayList<PartnerFeeDestination>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is ReadableMap -> list.add(asPartnerFeeDestination(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asPaymentDetails(paymentDetails: ReadableMap): PaymentDetails? {
     val type = paymentDetails.getString("type")
 
@@ -3977,6 +9137,19 @@ fun asPaymentDetailsList(arr: ReadableArray): List<PaymentDetails> {
     return list
 }
 
+fun asPaymentGroupBy(type: String): PaymentGroupBy = PaymentGroupBy.valueOf(camelToUpperSnakeCase(type))
+
+fun asPaymentGroupByList(arr: ReadableArray): List<PaymentGroupBy> {
+    val list = ArrayList<PaymentGroupBy>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asPaymentGroupBy(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asPaymentStatus(type: String): PaymentStatus = PaymentStatus.valueOf(camelToUpperSnakeCase(type))
 
 fun asPaymentStatusList(arr: ReadableArray): List<PaymentStatus> {
@@ -4016,6 +9189,19 @@ fun asPaymentTypeFilterList(arr: ReadableArray): List<PaymentTypeFilter> {
     return list
 }
 
+fun asReceiveLimitReason(type: String): ReceiveLimitReason = ReceiveLimitReason.valueOf(camelToUpperSnakeCase(type))
+
+fun asReceiveLimitReasonList(arr: ReadableArray): List<ReceiveLimitReason> {
+    val list = ArrayList<ReceiveLimitReason>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asReceiveLimitReason(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asReportIssueRequest(reportIssueRequest: ReadableMap): ReportIssueRequest? {
     val type = reportIssueRequest.getString("type")
 
@@ -4048,6 +9234,19 @@ fun asReportIssueRequestList(arr: ReadableArray): List<ReportIssueRequest> {
     return list
 }
 
+fun asReverseSwapProtocol(type: String): ReverseSwapProtocol = ReverseSwapProtocol.valueOf(camelToUpperSnakeCase(type))
+
+fun asReverseSwapProtocolList(arr: ReadableArray): List<ReverseSwapProtocol> {
+    val list = ArrayList<ReverseSwapProtocol>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asReverseSwapProtocol(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asReverseSwapStatus(type: String): ReverseSwapStatus = ReverseSwapStatus.valueOf(camelToUpperSnakeCase(type))
 
 fun asReverseSwapStatusList(arr: ReadableArray): List<ReverseSwapStatus> {
@@ -4061,6 +9260,45 @@ fun asReverseSwapStatusList(arr: ReadableArray): List<ReverseSwapStatus> {
     return list
 }
 
+fun asServiceComponent(type: String): ServiceComponent = ServiceComponent.valueOf(camelToUpperSnakeCase(type))
+
+fun asServiceComponentList(arr: ReadableArray): List<ServiceComponent> {
+    val list = ArrayList<ServiceComponent>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asServiceComponent(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asServiceStatus(type: String): ServiceStatus = ServiceStatus.valueOf(camelToUpperSnakeCase(type))
+
+fun asServiceStatusList(arr: ReadableArray): List<ServiceStatus> {
+    val list = ArrayList<ServiceStatus>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asServiceStatus(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
+fun asSortOrder(type: String): SortOrder = SortOrder.valueOf(camelToUpperSnakeCase(type))
+
+fun asSortOrderList(arr: ReadableArray): List<SortOrder> {
+    val list = ArrayList<SortOrder>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asSortOrder(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun asSuccessActionProcessed(successActionProcessed: ReadableMap): SuccessActionProcessed? {
     val type = successActionProcessed.getString("type")
 
@@ -4135,6 +9373,19 @@ fun asSwapStatusList(arr: ReadableArray): List<SwapStatus> {
     return list
 }
 
+fun asTaskState(type: String): TaskState = TaskState.valueOf(camelToUpperSnakeCase(type))
+
+fun asTaskStateList(arr: ReadableArray): List<TaskState> {
+    val list = ArrayList<TaskState>()
+    for (value in arr.toArrayList()) {
+        when (value) {
+            is String -> list.add(asTaskState(value)!!)
+            else -> throw SdkException.Generic(errUnexpectedType("${value::class.java.name}"))
+        }
+    }
+    return list
+}
+
 fun readableMapOf(vararg values: Pair<String, *>): ReadableMap {
     val map = Arguments.createMap()
     for ((key, value) in values) {
@@ -4165,23 +9416,47 @@ fun pushToArray(
 ) {
     when (value) {
         null -> array.pushNull()
+        is BatchPaymentResult -> array.pushMap(readableMapOf(value))
+        is ChannelDetails -> array.pushMap(readableMapOf(value))
+        is ChannelOpeningFee -> array.pushMap(readableMapOf(value))
         is FiatCurrency -> array.pushMap(readableMapOf(value))
+        is FiatRateAlert -> array.pushMap(readableMapOf(value))
+        is HistoricalRate -> array.pushMap(readableMapOf(value))
+        is InvoiceVerificationResult -> array.pushMap(readableMapOf(value))
+        is LnOfferBlindedPath -> array.pushMap(readableMapOf(value))
+        is LnUrlAuthDomain -> array.pushMap(readableMapOf(value))
+        is LnurlWithdrawVoucher -> array.pushMap(readableMapOf(value))
         is LocaleOverrides -> array.pushMap(readableMapOf(value))
         is LocalizedName -> array.pushMap(readableMapOf(value))
         is LspInformation -> array.pushMap(readableMapOf(value))
         is MetadataFilter -> array.pushMap(readableMapOf(value))
+        is MetricBucket -> array.pushMap(readableMapOf(value))
+        is MetricCounter -> array.pushMap(readableMapOf(value))
+        is MetricHistogram -> array.pushMap(readableMapOf(value))
+        is MigrationChannel -> array.pushMap(readableMapOf(value))
         is OpeningFeeParams -> array.pushMap(readableMapOf(value))
+        is OutboxPayment -> array.pushMap(readableMapOf(value))
         is Payment -> array.pushMap(readableMapOf(value))
+        is PaymentGroup -> array.pushMap(readableMapOf(value))
+        is PaymentProbe -> array.pushMap(readableMapOf(value))
         is PaymentTypeFilter -> array.pushString(value.name.lowercase())
+        is PendingChannelOpen -> array.pushMap(readableMapOf(value))
         is Rate -> array.pushMap(readableMapOf(value))
+        is RefundFeePreview -> array.pushMap(readableMapOf(value))
         is ReverseSwapInfo -> array.pushMap(readableMapOf(value))
+        is ReverseSwapProtocol -> array.pushString(value.name.lowercase())
         is RouteHint -> array.pushMap(readableMapOf(value))
         is RouteHintHop -> array.pushMap(readableMapOf(value))
+        is SendPaymentRequest -> array.pushMap(readableMapOf(value))
         is String -> array.pushString(value)
         is SwapInfo -> array.pushMap(readableMapOf(value))
+        is SwapRefundKey -> array.pushMap(readableMapOf(value))
         is SwapStatus -> array.pushString(value.name.lowercase())
+        is TaskStatus -> array.pushMap(readableMapOf(value))
         is TlvEntry -> array.pushMap(readableMapOf(value))
+        is TlvRecord -> array.pushMap(readableMapOf(value))
         is UByte -> array.pushInt(value.toInt())
+        is ULong -> array.pushDouble(value.toDouble())
         is UnspentTransactionOutput -> array.pushMap(readableMapOf(value))
         is Array<*> -> array.pushArray(readableArrayOf(value.asIterable()))
         is List<*> -> array.pushArray(readableArrayOf(value))
@@ -54,6 +54,38 @@ class BreezSDKModule(
     @ReactMethod
     fun removeListeners(count: Int) {}
 
+    @ReactMethod
+    fun connectEphemeral(
+        config: ReadableMap,
+        listener:,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val config = asConfig(config) ?: run { throw SdkException.Generic(errMissingMandatoryField("config", "Config")) }
+                val res = connectEphemeral(config, listener)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun setLogFilter(
+        filter: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                setLogFilter(filter)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun parseInvoice(
         invoice: String,
@@ -122,6 +154,18 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun sdkInfo(promise: Promise) {
+        executor.execute {
+            try {
+                val res = sdkInfo()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun staticBackup(
         req: ReadableMap,
@@ -139,6 +183,24 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun decryptRecoveryBundle(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val decryptRecoveryBundleRequest =
+                    asDecryptRecoveryBundleRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "DecryptRecoveryBundleRequest")) }
+                val res = decryptRecoveryBundle(decryptRecoveryBundleRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun serviceHealthCheck(
         apiKey: String,
@@ -154,6 +216,22 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun diffSnapshots(
+        a: String,
+        b: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = diffSnapshots(a, b)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun setLogStream(promise: Promise) {
         executor.execute {
@@ -244,6 +322,82 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun sendPayments(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val sendPaymentsRequest =
+                    asSendPaymentsRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "SendPaymentsRequest")) }
+                val res = getBreezServices().sendPayments(sendPaymentsRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun verifyInvoices(
+        invoices: ReadableArray,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBreezServices().verifyInvoices(invoices)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun queuePayment(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val queuePaymentRequest =
+                    asQueuePaymentRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "QueuePaymentRequest")) }
+                val res = getBreezServices().queuePayment(queuePaymentRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listPendingOutbox(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listPendingOutbox()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun cancelOutboxPayment(
+        id: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBreezServices().cancelOutboxPayment(id.toLong())
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun sendSpontaneousPayment(
         req: ReadableMap,
@@ -262,6 +416,121 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun cancel(
+        cancelToken: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBreezServices().cancel(cancelToken)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun probePayment(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val probePaymentRequest =
+                    asProbePaymentRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ProbePaymentRequest")) }
+                val res = getBreezServices().probePayment(probePaymentRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listPaymentProbes(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listPaymentProbes()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun validatePayability(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val validatePayabilityRequest =
+                    asValidatePayabilityRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ValidatePayabilityRequest")) }
+                val res = getBreezServices().validatePayability(validatePayabilityRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun payOffer(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val payOfferRequest =
+                    asPayOfferRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PayOfferRequest")) }
+                val res = getBreezServices().payOffer(payOfferRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun prepareSendPayment(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val prepareSendPaymentRequest =
+                    asPrepareSendPaymentRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareSendPaymentRequest")) }
+                val res = getBreezServices().prepareSendPayment(prepareSendPaymentRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun prepareReceivePayment(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val prepareReceivePaymentRequest =
+                    asPrepareReceivePaymentRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PrepareReceivePaymentRequest")) }
+                val res = getBreezServices().prepareReceivePayment(prepareReceivePaymentRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun receivePayment(
         req: ReadableMap,
@@ -280,6 +549,41 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun receiveUnified(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val receiveUnifiedRequest =
+                    asReceiveUnifiedRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ReceiveUnifiedRequest")) }
+                val res = getBreezServices().receiveUnified(receiveUnifiedRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun amendInvoice(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val amendInvoiceRequest =
+                    asAmendInvoiceRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "AmendInvoiceRequest")) }
+                val res = getBreezServices().amendInvoice(amendInvoiceRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun payLnurl(
         req: ReadableMap,
@@ -316,17 +620,607 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun lnurlAuth(
-        reqData: ReadableMap,
+    fun lnurlAuth(
+        reqData: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val lnUrlAuthRequestData =
+                    asLnUrlAuthRequestData(reqData)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("reqData", "LnUrlAuthRequestData")) }
+                val res = getBreezServices().lnurlAuth(lnUrlAuthRequestData)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listLnurlAuthDomains(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listLnurlAuthDomains()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun lnurlAuthLinkingKey(
+        domain: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBreezServices().lnurlAuthLinkingKey(domain)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun exportLnurlAuth(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().exportLnurlAuth()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun importLnurlAuth(
+        export: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val lnUrlAuthExport =
+                    asLnUrlAuthExport(export) ?: run { throw SdkException.Generic(errMissingMandatoryField("export", "LnUrlAuthExport")) }
+                getBreezServices().importLnurlAuth(lnUrlAuthExport)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun reportIssue(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val reqTmp =
+                    asReportIssueRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ReportIssueRequest")) }
+                getBreezServices().reportIssue(reqTmp)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun peerConnectivity(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().peerConnectivity()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun connectionStatus(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().connectionStatus()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun taskStatus(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().taskStatus()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun nodeCredentials(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().nodeCredentials()
+                promise.resolve(res?.let { readableMapOf(res) })
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun nodeInfo(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().nodeInfo()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun balances(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().balances()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun getBalanceBreakdown(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().getBalanceBreakdown()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun signMessage(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val signMessageRequest =
+                    asSignMessageRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "SignMessageRequest")) }
+                val res = getBreezServices().signMessage(signMessageRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun deriveEncryptionKey(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val deriveEncryptionKeyRequest =
+                    asDeriveEncryptionKeyRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "DeriveEncryptionKeyRequest")) }
+                val res = getBreezServices().deriveEncryptionKey(deriveEncryptionKeyRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun proveAddressOwnership(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val proveAddressOwnershipRequest =
+                    asProveAddressOwnershipRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ProveAddressOwnershipRequest")) }
+                val res = getBreezServices().proveAddressOwnership(proveAddressOwnershipRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun encryptPaymentRequest(
+        bundle: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val paymentRequestBundle =
+                    asPaymentRequestBundle(bundle)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("bundle", "PaymentRequestBundle")) }
+                val res = getBreezServices().encryptPaymentRequest(paymentRequestBundle)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun decryptPaymentRequest(
+        data: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val encryptedPaymentRequestData =
+                    asEncryptedPaymentRequestData(data)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("data", "EncryptedPaymentRequestData")) }
+                val res = getBreezServices().decryptPaymentRequest(encryptedPaymentRequestData)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun checkMessage(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val checkMessageRequest =
+                    asCheckMessageRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "CheckMessageRequest")) }
+                val res = getBreezServices().checkMessage(checkMessageRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun backupStatus(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().backupStatus()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun backup(promise: Promise) {
+        executor.execute {
+            try {
+                getBreezServices().backup()
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun rotateBackupKey(promise: Promise) {
+        executor.execute {
+            try {
+                getBreezServices().rotateBackupKey()
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listBackupVersions(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listBackupVersions()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun restoreBackup(
+        version: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBreezServices().restoreBackup(version.toULong())
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listPayments(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val listPaymentsRequest =
+                    asListPaymentsRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ListPaymentsRequest")) }
+                val res = getBreezServices().listPayments(listPaymentsRequest)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listPaymentGroups(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val listPaymentGroupsRequest =
+                    asListPaymentGroupsRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ListPaymentGroupsRequest")) }
+                val res = getBreezServices().listPaymentGroups(listPaymentGroupsRequest)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun exportPayments(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val exportPaymentsRequest =
+                    asExportPaymentsRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ExportPaymentsRequest")) }
+                val res = getBreezServices().exportPayments(exportPaymentsRequest)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun importPayments(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val importPaymentsRequest =
+                    asImportPaymentsRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ImportPaymentsRequest")) }
+                val res = getBreezServices().importPayments(importPaymentsRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun paymentByHash(
+        hash: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBreezServices().paymentByHash(hash)
+                promise.resolve(res?.let { readableMapOf(res) })
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listChannelFeesPaid(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listChannelFeesPaid()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun setPaymentMetadata(
+        hash: String,
+        metadata: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBreezServices().setPaymentMetadata(hash, metadata)
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun redeemOnchainFunds(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val redeemOnchainFundsRequest =
+                    asRedeemOnchainFundsRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "RedeemOnchainFundsRequest")) }
+                val res = getBreezServices().redeemOnchainFunds(redeemOnchainFundsRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun bumpFee(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val bumpFeeRequest =
+                    asBumpFeeRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "BumpFeeRequest")) }
+                val res = getBreezServices().bumpFee(bumpFeeRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun fetchFiatRates(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().fetchFiatRates()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun fiatRateHistory(
+        currency: String,
+        fromTimestamp: Double,
+        toTimestamp: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val fromTimestampTmp = fromTimestamp.toLong().takeUnless { it == 0L }
+                val toTimestampTmp = toTimestamp.toLong().takeUnless { it == 0L }
+                val res = getBreezServices().fiatRateHistory(currency, fromTimestampTmp, toTimestampTmp)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun addFiatRateAlert(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val addFiatRateAlertRequest =
+                    asAddFiatRateAlertRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "AddFiatRateAlertRequest")) }
+                val res = getBreezServices().addFiatRateAlert(addFiatRateAlertRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listFiatRateAlerts(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listFiatRateAlerts()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun removeFiatRateAlert(
+        id: Double,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                getBreezServices().removeFiatRateAlert(id.toLong())
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listFiatCurrencies(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listFiatCurrencies()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listLsps(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listLsps()
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun connectLsp(
+        lspId: String,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val lnUrlAuthRequestData =
-                    asLnUrlAuthRequestData(reqData)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("reqData", "LnUrlAuthRequestData")) }
-                val res = getBreezServices().lnurlAuth(lnUrlAuthRequestData)
-                promise.resolve(readableMapOf(res))
+                getBreezServices().connectLsp(lspId)
+                promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -334,16 +1228,14 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun reportIssue(
-        req: ReadableMap,
+    fun fetchLspInfo(
+        lspId: String,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val reqTmp =
-                    asReportIssueRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ReportIssueRequest")) }
-                getBreezServices().reportIssue(reqTmp)
-                promise.resolve(readableMapOf("status" to "ok"))
+                val res = getBreezServices().fetchLspInfo(lspId)
+                promise.resolve(res?.let { readableMapOf(res) })
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -351,11 +1243,17 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun nodeCredentials(promise: Promise) {
+    fun openChannelFee(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
         executor.execute {
             try {
-                val res = getBreezServices().nodeCredentials()
-                promise.resolve(res?.let { readableMapOf(res) })
+                val openChannelFeeRequest =
+                    asOpenChannelFeeRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "OpenChannelFeeRequest")) }
+                val res = getBreezServices().openChannelFee(openChannelFeeRequest)
+                promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -363,11 +1261,11 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun nodeInfo(promise: Promise) {
+    fun lspId(promise: Promise) {
         executor.execute {
             try {
-                val res = getBreezServices().nodeInfo()
-                promise.resolve(readableMapOf(res))
+                val res = getBreezServices().lspId()
+                promise.resolve(res?.let { res })
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -375,15 +1273,10 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun signMessage(
-        req: ReadableMap,
-        promise: Promise,
-    ) {
+    fun userSettings(promise: Promise) {
         executor.execute {
             try {
-                val signMessageRequest =
-                    asSignMessageRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "SignMessageRequest")) }
-                val res = getBreezServices().signMessage(signMessageRequest)
+                val res = getBreezServices().userSettings()
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -392,16 +1285,16 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun checkMessage(
-        req: ReadableMap,
+    fun setUserSettings(
+        settings: ReadableMap,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val checkMessageRequest =
-                    asCheckMessageRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "CheckMessageRequest")) }
-                val res = getBreezServices().checkMessage(checkMessageRequest)
-                promise.resolve(readableMapOf(res))
+                val userSettings =
+                    asUserSettings(settings) ?: run { throw SdkException.Generic(errMissingMandatoryField("settings", "UserSettings")) }
+                getBreezServices().setUserSettings(userSettings)
+                promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -409,10 +1302,10 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun backupStatus(promise: Promise) {
+    fun lspInfo(promise: Promise) {
         executor.execute {
             try {
-                val res = getBreezServices().backupStatus()
+                val res = getBreezServices().lspInfo()
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -421,10 +1314,10 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun backup(promise: Promise) {
+    fun closeLspChannels(promise: Promise) {
         executor.execute {
             try {
-                getBreezServices().backup()
+                getBreezServices().closeLspChannels()
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -433,15 +1326,10 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun listPayments(
-        req: ReadableMap,
-        promise: Promise,
-    ) {
+    fun listChannels(promise: Promise) {
         executor.execute {
             try {
-                val listPaymentsRequest =
-                    asListPaymentsRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ListPaymentsRequest")) }
-                val res = getBreezServices().listPayments(listPaymentsRequest)
+                val res = getBreezServices().listChannels()
                 promise.resolve(readableArrayOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -450,14 +1338,16 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun paymentByHash(
-        hash: String,
+    fun closeChannel(
+        req: ReadableMap,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val res = getBreezServices().paymentByHash(hash)
-                promise.resolve(res?.let { readableMapOf(res) })
+                val closeChannelRequest =
+                    asCloseChannelRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "CloseChannelRequest")) }
+                val res = getBreezServices().closeChannel(closeChannelRequest)
+                promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -465,14 +1355,15 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun setPaymentMetadata(
-        hash: String,
-        metadata: String,
+    fun connectPeer(
+        req: ReadableMap,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                getBreezServices().setPaymentMetadata(hash, metadata)
+                val connectPeerRequest =
+                    asConnectPeerRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ConnectPeerRequest")) }
+                getBreezServices().connectPeer(connectPeerRequest)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -481,16 +1372,15 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun redeemOnchainFunds(
+    fun openChannel(
         req: ReadableMap,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val redeemOnchainFundsRequest =
-                    asRedeemOnchainFundsRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "RedeemOnchainFundsRequest")) }
-                val res = getBreezServices().redeemOnchainFunds(redeemOnchainFundsRequest)
+                val openChannelRequest =
+                    asOpenChannelRequest(req) ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "OpenChannelRequest")) }
+                val res = getBreezServices().openChannel(openChannelRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -499,11 +1389,17 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun fetchFiatRates(promise: Promise) {
+    fun exportNodeMigration(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
         executor.execute {
             try {
-                val res = getBreezServices().fetchFiatRates()
-                promise.resolve(readableArrayOf(res))
+                val nodeMigrationRequest =
+                    asNodeMigrationRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "NodeMigrationRequest")) }
+                val res = getBreezServices().exportNodeMigration(nodeMigrationRequest)
+                promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -511,11 +1407,17 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun listFiatCurrencies(promise: Promise) {
+    fun exportRecoveryBundle(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
         executor.execute {
             try {
-                val res = getBreezServices().listFiatCurrencies()
-                promise.resolve(readableArrayOf(res))
+                val exportRecoveryBundleRequest =
+                    asExportRecoveryBundleRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ExportRecoveryBundleRequest")) }
+                val res = getBreezServices().exportRecoveryBundle(exportRecoveryBundleRequest)
+                promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -523,11 +1425,11 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun listLsps(promise: Promise) {
+    fun stopRecoveryBundleRefresh(promise: Promise) {
         executor.execute {
             try {
-                val res = getBreezServices().listLsps()
-                promise.resolve(readableArrayOf(res))
+                getBreezServices().stopRecoveryBundleRefresh()
+                promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -535,13 +1437,13 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun connectLsp(
-        lspId: String,
+    fun registerWebhook(
+        webhookUrl: String,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                getBreezServices().connectLsp(lspId)
+                getBreezServices().registerWebhook(webhookUrl)
                 promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -550,14 +1452,14 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun fetchLspInfo(
-        lspId: String,
+    fun unregisterWebhook(
+        webhookUrl: String,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val res = getBreezServices().fetchLspInfo(lspId)
-                promise.resolve(res?.let { readableMapOf(res) })
+                getBreezServices().unregisterWebhook(webhookUrl)
+                promise.resolve(readableMapOf("status" to "ok"))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -565,16 +1467,13 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun openChannelFee(
-        req: ReadableMap,
+    fun registerLightningAddress(
+        username: String,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                val openChannelFeeRequest =
-                    asOpenChannelFeeRequest(req)
-                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "OpenChannelFeeRequest")) }
-                val res = getBreezServices().openChannelFee(openChannelFeeRequest)
+                val res = getBreezServices().registerLightningAddress(username)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -583,11 +1482,11 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun lspId(promise: Promise) {
+    fun lnurlPayInfo(promise: Promise) {
         executor.execute {
             try {
-                val res = getBreezServices().lspId()
-                promise.resolve(res?.let { res })
+                val res = getBreezServices().lnurlPayInfo()
+                promise.resolve(res?.let { readableMapOf(res) })
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -595,10 +1494,16 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun lspInfo(promise: Promise) {
+    fun createStaticLnurlPay(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
         executor.execute {
             try {
-                val res = getBreezServices().lspInfo()
+                val createStaticLnurlPayRequest =
+                    asCreateStaticLnurlPayRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "CreateStaticLnurlPayRequest")) }
+                val res = getBreezServices().createStaticLnurlPay(createStaticLnurlPayRequest)
                 promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
@@ -607,11 +1512,17 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun closeLspChannels(promise: Promise) {
+    fun staticLnurlPayCallback(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
         executor.execute {
             try {
-                getBreezServices().closeLspChannels()
-                promise.resolve(readableMapOf("status" to "ok"))
+                val staticLnurlPayCallbackRequest =
+                    asStaticLnurlPayCallbackRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "StaticLnurlPayCallbackRequest")) }
+                val res = getBreezServices().staticLnurlPayCallback(staticLnurlPayCallbackRequest)
+                promise.resolve(res)
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -619,14 +1530,17 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun registerWebhook(
-        webhookUrl: String,
+    fun createLnurlWithdrawVoucher(
+        req: ReadableMap,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                getBreezServices().registerWebhook(webhookUrl)
-                promise.resolve(readableMapOf("status" to "ok"))
+                val createLnurlWithdrawVoucherRequest =
+                    asCreateLnurlWithdrawVoucherRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "CreateLnurlWithdrawVoucherRequest")) }
+                val res = getBreezServices().createLnurlWithdrawVoucher(createLnurlWithdrawVoucherRequest)
+                promise.resolve(readableMapOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -634,14 +1548,29 @@ class BreezSDKModule(
     }
 
     @ReactMethod
-    fun unregisterWebhook(
-        webhookUrl: String,
+    fun redeemLnurlWithdrawVoucher(
+        req: ReadableMap,
         promise: Promise,
     ) {
         executor.execute {
             try {
-                getBreezServices().unregisterWebhook(webhookUrl)
-                promise.resolve(readableMapOf("status" to "ok"))
+                val redeemLnurlWithdrawVoucherRequest =
+                    asRedeemLnurlWithdrawVoucherRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "RedeemLnurlWithdrawVoucherRequest")) }
+                val res = getBreezServices().redeemLnurlWithdrawVoucher(redeemLnurlWithdrawVoucherRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun listLnurlWithdrawVouchers(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().listLnurlWithdrawVouchers()
+                promise.resolve(readableArrayOf(res))
             } catch (e: Exception) {
                 promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
             }
@@ -705,6 +1634,21 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun verifySwapAddress(
+        swapAddress: String,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val res = getBreezServices().verifySwapAddress(swapAddress)
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun listRefundables(promise: Promise) {
         executor.execute {
@@ -717,6 +1661,24 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun exportSwapRefundKeys(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val exportSwapRefundKeysRequest =
+                    asExportSwapRefundKeysRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "ExportSwapRefundKeysRequest")) }
+                val res = getBreezServices().exportSwapRefundKeys(exportSwapRefundKeysRequest)
+                promise.resolve(readableArrayOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun prepareRefund(
         req: ReadableMap,
@@ -861,6 +1823,24 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun payOnchainAddress(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val payOnchainAddressRequest =
+                    asPayOnchainAddressRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "PayOnchainAddressRequest")) }
+                val res = getBreezServices().payOnchainAddress(payOnchainAddressRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun executeDevCommand(
         command: String,
@@ -888,6 +1868,18 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun diagnosticSnapshot(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().diagnosticSnapshot()
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun sync(promise: Promise) {
         executor.execute {
@@ -900,6 +1892,42 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun cancelSync(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().cancelSync()
+                promise.resolve(res)
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun metrics(promise: Promise) {
+        executor.execute {
+            try {
+                val res = getBreezServices().metrics()
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
+    @ReactMethod
+    fun syncPartial(promise: Promise) {
+        executor.execute {
+            try {
+                getBreezServices().syncPartial()
+                promise.resolve(readableMapOf("status" to "ok"))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun recommendedFees(promise: Promise) {
         executor.execute {
@@ -929,6 +1957,24 @@ class BreezSDKModule(
         }
     }
 
+    @ReactMethod
+    fun fetchBuyBitcoinQuote(
+        req: ReadableMap,
+        promise: Promise,
+    ) {
+        executor.execute {
+            try {
+                val fetchBuyBitcoinQuoteRequest =
+                    asFetchBuyBitcoinQuoteRequest(req)
+                        ?: run { throw SdkException.Generic(errMissingMandatoryField("req", "FetchBuyBitcoinQuoteRequest")) }
+                val res = getBreezServices().fetchBuyBitcoinQuote(fetchBuyBitcoinQuoteRequest)
+                promise.resolve(readableMapOf(res))
+            } catch (e: Exception) {
+                promise.reject(e.javaClass.simpleName.replace("Exception", "Error"), e.message, e)
+            }
+        }
+    }
+
     @ReactMethod
     fun prepareRedeemOnchainFunds(
         req: ReadableMap,
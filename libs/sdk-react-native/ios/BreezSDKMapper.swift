@@ -2,6 +2,47 @@ import BreezSDK
 import Foundation
 
 enum BreezSDKMapper {
+    static func asAddFiatRateAlertRequest(addFiatRateAlertRequest: [String: Any?]) throws -> AddFiatRateAlertRequest {
+        guard let currency = addFiatRateAlertRequest["currency"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "currency", typeName: "AddFiatRateAlertRequest"))
+        }
+        guard let directionTmp = addFiatRateAlertRequest["direction"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "direction", typeName: "AddFiatRateAlertRequest"))
+        }
+        let direction = try asFiatRateAlertDirection(fiatRateAlertDirection: directionTmp)
+
+        guard let threshold = addFiatRateAlertRequest["threshold"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "threshold", typeName: "AddFiatRateAlertRequest"))
+        }
+
+        return AddFiatRateAlertRequest(currency: currency, direction: direction, threshold: threshold)
+    }
+
+    static func dictionaryOf(addFiatRateAlertRequest: AddFiatRateAlertRequest) -> [String: Any?] {
+        return [
+            "currency": addFiatRateAlertRequest.currency,
+            "direction": valueOf(fiatRateAlertDirection: addFiatRateAlertRequest.direction),
+            "threshold": addFiatRateAlertRequest.threshold,
+        ]
+    }
+
+    static func asAddFiatRateAlertRequestList(arr: [Any]) throws -> [AddFiatRateAlertRequest] {
+        var list = [AddFiatRateAlertRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var addFiatRateAlertRequest = try asAddFiatRateAlertRequest(addFiatRateAlertRequest: val)
+                list.append(addFiatRateAlertRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "AddFiatRateAlertRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(addFiatRateAlertRequestList: [AddFiatRateAlertRequest]) -> [Any] {
+        return addFiatRateAlertRequestList.map { v -> [String: Any?] in return dictionaryOf(addFiatRateAlertRequest: v) }
+    }
+
     static func asAesSuccessActionDataDecrypted(aesSuccessActionDataDecrypted: [String: Any?]) throws -> AesSuccessActionDataDecrypted {
         guard let description = aesSuccessActionDataDecrypted["description"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "AesSuccessActionDataDecrypted"))
@@ -37,6 +78,101 @@ enum BreezSDKMapper {
         return aesSuccessActionDataDecryptedList.map { v -> [String: Any?] in return dictionaryOf(aesSuccessActionDataDecrypted: v) }
     }
 
+    static func asAmendInvoiceRequest(amendInvoiceRequest: [String: Any?]) throws -> AmendInvoiceRequest {
+        guard let bolt11 = amendInvoiceRequest["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "AmendInvoiceRequest"))
+        }
+        var amountMsat: MilliSat?
+        if hasNonNilKey(data: amendInvoiceRequest, key: "amountMsat") {
+            guard let amountMsatTmp = amendInvoiceRequest["amountMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+            }
+            amountMsat = amountMsatTmp
+        }
+        var expiry: UInt32?
+        if hasNonNilKey(data: amendInvoiceRequest, key: "expiry") {
+            guard let expiryTmp = amendInvoiceRequest["expiry"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "expiry"))
+            }
+            expiry = expiryTmp
+        }
+        var routeHints: [RouteHint]?
+        if let routeHintsTmp = amendInvoiceRequest["routeHints"] as? [[String: Any?]] {
+            routeHints = try asRouteHintList(arr: routeHintsTmp)
+        }
+
+        return AmendInvoiceRequest(bolt11: bolt11, amountMsat: amountMsat, expiry: expiry, routeHints: routeHints)
+    }
+
+    static func dictionaryOf(amendInvoiceRequest: AmendInvoiceRequest) -> [String: Any?] {
+        return [
+            "bolt11": amendInvoiceRequest.bolt11,
+            "amountMsat": amendInvoiceRequest.amountMsat == nil ? nil : amendInvoiceRequest.amountMsat,
+            "expiry": amendInvoiceRequest.expiry == nil ? nil : amendInvoiceRequest.expiry,
+            "routeHints": amendInvoiceRequest.routeHints == nil ? nil : arrayOf(routeHintList: amendInvoiceRequest.routeHints!),
+        ]
+    }
+
+    static func asAmendInvoiceRequestList(arr: [Any]) throws -> [AmendInvoiceRequest] {
+        var list = [AmendInvoiceRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var amendInvoiceRequest = try asAmendInvoiceRequest(amendInvoiceRequest: val)
+                list.append(amendInvoiceRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "AmendInvoiceRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(amendInvoiceRequestList: [AmendInvoiceRequest]) -> [Any] {
+        return amendInvoiceRequestList.map { v -> [String: Any?] in return dictionaryOf(amendInvoiceRequest: v) }
+    }
+
+    static func asAutoRefundConfig(autoRefundConfig: [String: Any?]) throws -> AutoRefundConfig {
+        var toAddress: String?
+        if hasNonNilKey(data: autoRefundConfig, key: "toAddress") {
+            guard let toAddressTmp = autoRefundConfig["toAddress"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "toAddress"))
+            }
+            toAddress = toAddressTmp
+        }
+        var satPerVbyte: UInt32?
+        if hasNonNilKey(data: autoRefundConfig, key: "satPerVbyte") {
+            guard let satPerVbyteTmp = autoRefundConfig["satPerVbyte"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "satPerVbyte"))
+            }
+            satPerVbyte = satPerVbyteTmp
+        }
+
+        return AutoRefundConfig(toAddress: toAddress, satPerVbyte: satPerVbyte)
+    }
+
+    static func dictionaryOf(autoRefundConfig: AutoRefundConfig) -> [String: Any?] {
+        return [
+            "toAddress": autoRefundConfig.toAddress == nil ? nil : autoRefundConfig.toAddress,
+            "satPerVbyte": autoRefundConfig.satPerVbyte == nil ? nil : autoRefundConfig.satPerVbyte,
+        ]
+    }
+
+    static func asAutoRefundConfigList(arr: [Any]) throws -> [AutoRefundConfig] {
+        var list = [AutoRefundConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var autoRefundConfig = try asAutoRefundConfig(autoRefundConfig: val)
+                list.append(autoRefundConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "AutoRefundConfig"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(autoRefundConfigList: [AutoRefundConfig]) -> [Any] {
+        return autoRefundConfigList.map { v -> [String: Any?] in return dictionaryOf(autoRefundConfig: v) }
+    }
+
     static func asBackupFailedData(backupFailedData: [String: Any?]) throws -> BackupFailedData {
         guard let error = backupFailedData["error"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "error", typeName: "BackupFailedData"))
@@ -107,6 +243,224 @@ enum BreezSDKMapper {
         return backupStatusList.map { v -> [String: Any?] in return dictionaryOf(backupStatus: v) }
     }
 
+    static func asBalanceBreakdown(balanceBreakdown: [String: Any?]) throws -> BalanceBreakdown {
+        guard let spendableMsat = balanceBreakdown["spendableMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "spendableMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let channelsTmp = balanceBreakdown["channels"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channels", typeName: "BalanceBreakdown"))
+        }
+        let channels = try asChannelDetailsList(arr: channelsTmp)
+
+        guard let channelReservesMsat = balanceBreakdown["channelReservesMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelReservesMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let htlcsOutMsat = balanceBreakdown["htlcsOutMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "htlcsOutMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let htlcsInMsat = balanceBreakdown["htlcsInMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "htlcsInMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let dustHtlcsMsat = balanceBreakdown["dustHtlcsMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "dustHtlcsMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let pendingChannelOpensMsat = balanceBreakdown["pendingChannelOpensMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pendingChannelOpensMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let onchainConfirmedMsat = balanceBreakdown["onchainConfirmedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainConfirmedMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let onchainUnconfirmedMsat = balanceBreakdown["onchainUnconfirmedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainUnconfirmedMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let swapsLockedMsat = balanceBreakdown["swapsLockedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapsLockedMsat", typeName: "BalanceBreakdown"))
+        }
+        guard let reverseSwapsLockedMsat = balanceBreakdown["reverseSwapsLockedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reverseSwapsLockedMsat", typeName: "BalanceBreakdown"))
+        }
+
+        return BalanceBreakdown(spendableMsat: spendableMsat, channels: channels, channelReservesMsat: channelReservesMsat, htlcsOutMsat: htlcsOutMsat, htlcsInMsat: htlcsInMsat, dustHtlcsMsat: dustHtlcsMsat, pendingChannelOpensMsat: pendingChannelOpensMsat, onchainConfirmedMsat: onchainConfirmedMsat, onchainUnconfirmedMsat: onchainUnconfirmedMsat, swapsLockedMsat: swapsLockedMsat, reverseSwapsLockedMsat: reverseSwapsLockedMsat)
+    }
+
+    static func dictionaryOf(balanceBreakdown: BalanceBreakdown) -> [String: Any?] {
+        return [
+            "spendableMsat": balanceBreakdown.spendableMsat,
+            "channels": arrayOf(channelDetailsList: balanceBreakdown.channels),
+            "channelReservesMsat": balanceBreakdown.channelReservesMsat,
+            "htlcsOutMsat": balanceBreakdown.htlcsOutMsat,
+            "htlcsInMsat": balanceBreakdown.htlcsInMsat,
+            "dustHtlcsMsat": balanceBreakdown.dustHtlcsMsat,
+            "pendingChannelOpensMsat": balanceBreakdown.pendingChannelOpensMsat,
+            "onchainConfirmedMsat": balanceBreakdown.onchainConfirmedMsat,
+            "onchainUnconfirmedMsat": balanceBreakdown.onchainUnconfirmedMsat,
+            "swapsLockedMsat": balanceBreakdown.swapsLockedMsat,
+            "reverseSwapsLockedMsat": balanceBreakdown.reverseSwapsLockedMsat,
+        ]
+    }
+
+    static func asBalanceBreakdownList(arr: [Any]) throws -> [BalanceBreakdown] {
+        var list = [BalanceBreakdown]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var balanceBreakdown = try asBalanceBreakdown(balanceBreakdown: val)
+                list.append(balanceBreakdown)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BalanceBreakdown"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(balanceBreakdownList: [BalanceBreakdown]) -> [Any] {
+        return balanceBreakdownList.map { v -> [String: Any?] in return dictionaryOf(balanceBreakdown: v) }
+    }
+
+    static func asBalances(balances: [String: Any?]) throws -> Balances {
+        guard let lightningSpendableMsat = balances["lightningSpendableMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lightningSpendableMsat", typeName: "Balances"))
+        }
+        guard let lightningPendingMsat = balances["lightningPendingMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lightningPendingMsat", typeName: "Balances"))
+        }
+        guard let onchainConfirmedMsat = balances["onchainConfirmedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainConfirmedMsat", typeName: "Balances"))
+        }
+        guard let onchainUnconfirmedMsat = balances["onchainUnconfirmedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainUnconfirmedMsat", typeName: "Balances"))
+        }
+        guard let swapsLockedMsat = balances["swapsLockedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapsLockedMsat", typeName: "Balances"))
+        }
+        guard let reverseSwapsLockedMsat = balances["reverseSwapsLockedMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reverseSwapsLockedMsat", typeName: "Balances"))
+        }
+
+        return Balances(lightningSpendableMsat: lightningSpendableMsat, lightningPendingMsat: lightningPendingMsat, onchainConfirmedMsat: onchainConfirmedMsat, onchainUnconfirmedMsat: onchainUnconfirmedMsat, swapsLockedMsat: swapsLockedMsat, reverseSwapsLockedMsat: reverseSwapsLockedMsat)
+    }
+
+    static func dictionaryOf(balances: Balances) -> [String: Any?] {
+        return [
+            "lightningSpendableMsat": balances.lightningSpendableMsat,
+            "lightningPendingMsat": balances.lightningPendingMsat,
+            "onchainConfirmedMsat": balances.onchainConfirmedMsat,
+            "onchainUnconfirmedMsat": balances.onchainUnconfirmedMsat,
+            "swapsLockedMsat": balances.swapsLockedMsat,
+            "reverseSwapsLockedMsat": balances.reverseSwapsLockedMsat,
+        ]
+    }
+
+    static func asBalancesList(arr: [Any]) throws -> [Balances] {
+        var list = [Balances]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var balances = try asBalances(balances: val)
+                list.append(balances)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "Balances"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(balancesList: [Balances]) -> [Any] {
+        return balancesList.map { v -> [String: Any?] in return dictionaryOf(balances: v) }
+    }
+
+    static func asBatchPaymentProgressDetails(batchPaymentProgressDetails: [String: Any?]) throws -> BatchPaymentProgressDetails {
+        guard let resultTmp = batchPaymentProgressDetails["result"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "result", typeName: "BatchPaymentProgressDetails"))
+        }
+        let result = try asBatchPaymentResult(batchPaymentResult: resultTmp)
+
+        guard let succeeded = batchPaymentProgressDetails["succeeded"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "succeeded", typeName: "BatchPaymentProgressDetails"))
+        }
+        guard let failed = batchPaymentProgressDetails["failed"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "failed", typeName: "BatchPaymentProgressDetails"))
+        }
+        guard let total = batchPaymentProgressDetails["total"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "total", typeName: "BatchPaymentProgressDetails"))
+        }
+
+        return BatchPaymentProgressDetails(result: result, succeeded: succeeded, failed: failed, total: total)
+    }
+
+    static func dictionaryOf(batchPaymentProgressDetails: BatchPaymentProgressDetails) -> [String: Any?] {
+        return [
+            "result": dictionaryOf(batchPaymentResult: batchPaymentProgressDetails.result),
+            "succeeded": batchPaymentProgressDetails.succeeded,
+            "failed": batchPaymentProgressDetails.failed,
+            "total": batchPaymentProgressDetails.total,
+        ]
+    }
+
+    static func asBatchPaymentProgressDetailsList(arr: [Any]) throws -> [BatchPaymentProgressDetails] {
+        var list = [BatchPaymentProgressDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var batchPaymentProgressDetails = try asBatchPaymentProgressDetails(batchPaymentProgressDetails: val)
+                list.append(batchPaymentProgressDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BatchPaymentProgressDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(batchPaymentProgressDetailsList: [BatchPaymentProgressDetails]) -> [Any] {
+        return batchPaymentProgressDetailsList.map { v -> [String: Any?] in return dictionaryOf(batchPaymentProgressDetails: v) }
+    }
+
+    static func asBatchPaymentResult(batchPaymentResult: [String: Any?]) throws -> BatchPaymentResult {
+        guard let index = batchPaymentResult["index"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "index", typeName: "BatchPaymentResult"))
+        }
+        guard let bolt11 = batchPaymentResult["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "BatchPaymentResult"))
+        }
+        var payment: Payment?
+        if let paymentTmp = batchPaymentResult["payment"] as? [String: Any?] {
+            payment = try asPayment(payment: paymentTmp)
+        }
+
+        var error: String?
+        if hasNonNilKey(data: batchPaymentResult, key: "error") {
+            guard let errorTmp = batchPaymentResult["error"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "error"))
+            }
+            error = errorTmp
+        }
+
+        return BatchPaymentResult(index: index, bolt11: bolt11, payment: payment, error: error)
+    }
+
+    static func dictionaryOf(batchPaymentResult: BatchPaymentResult) -> [String: Any?] {
+        return [
+            "index": batchPaymentResult.index,
+            "bolt11": batchPaymentResult.bolt11,
+            "payment": batchPaymentResult.payment == nil ? nil : dictionaryOf(payment: batchPaymentResult.payment!),
+            "error": batchPaymentResult.error == nil ? nil : batchPaymentResult.error,
+        ]
+    }
+
+    static func asBatchPaymentResultList(arr: [Any]) throws -> [BatchPaymentResult] {
+        var list = [BatchPaymentResult]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var batchPaymentResult = try asBatchPaymentResult(batchPaymentResult: val)
+                list.append(batchPaymentResult)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BatchPaymentResult"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(batchPaymentResultList: [BatchPaymentResult]) -> [Any] {
+        return batchPaymentResultList.map { v -> [String: Any?] in return dictionaryOf(batchPaymentResult: v) }
+    }
+
     static func asBitcoinAddressData(bitcoinAddressData: [String: Any?]) throws -> BitcoinAddressData {
         guard let address = bitcoinAddressData["address"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "BitcoinAddressData"))
@@ -168,6 +522,115 @@ enum BreezSDKMapper {
         return bitcoinAddressDataList.map { v -> [String: Any?] in return dictionaryOf(bitcoinAddressData: v) }
     }
 
+    static func asBumpFeeRequest(bumpFeeRequest: [String: Any?]) throws -> BumpFeeRequest {
+        guard let txid = bumpFeeRequest["txid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txid", typeName: "BumpFeeRequest"))
+        }
+        guard let satPerVbyte = bumpFeeRequest["satPerVbyte"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "satPerVbyte", typeName: "BumpFeeRequest"))
+        }
+
+        return BumpFeeRequest(txid: txid, satPerVbyte: satPerVbyte)
+    }
+
+    static func dictionaryOf(bumpFeeRequest: BumpFeeRequest) -> [String: Any?] {
+        return [
+            "txid": bumpFeeRequest.txid,
+            "satPerVbyte": bumpFeeRequest.satPerVbyte,
+        ]
+    }
+
+    static func asBumpFeeRequestList(arr: [Any]) throws -> [BumpFeeRequest] {
+        var list = [BumpFeeRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var bumpFeeRequest = try asBumpFeeRequest(bumpFeeRequest: val)
+                list.append(bumpFeeRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BumpFeeRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(bumpFeeRequestList: [BumpFeeRequest]) -> [Any] {
+        return bumpFeeRequestList.map { v -> [String: Any?] in return dictionaryOf(bumpFeeRequest: v) }
+    }
+
+    static func asBumpFeeResponse(bumpFeeResponse: [String: Any?]) throws -> BumpFeeResponse {
+        guard let txid = bumpFeeResponse["txid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txid", typeName: "BumpFeeResponse"))
+        }
+
+        return BumpFeeResponse(txid: txid)
+    }
+
+    static func dictionaryOf(bumpFeeResponse: BumpFeeResponse) -> [String: Any?] {
+        return [
+            "txid": bumpFeeResponse.txid,
+        ]
+    }
+
+    static func asBumpFeeResponseList(arr: [Any]) throws -> [BumpFeeResponse] {
+        var list = [BumpFeeResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var bumpFeeResponse = try asBumpFeeResponse(bumpFeeResponse: val)
+                list.append(bumpFeeResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BumpFeeResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(bumpFeeResponseList: [BumpFeeResponse]) -> [Any] {
+        return bumpFeeResponseList.map { v -> [String: Any?] in return dictionaryOf(bumpFeeResponse: v) }
+    }
+
+    static func asBuyBitcoinQuote(buyBitcoinQuote: [String: Any?]) throws -> BuyBitcoinQuote {
+        guard let fiatCurrency = buyBitcoinQuote["fiatCurrency"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fiatCurrency", typeName: "BuyBitcoinQuote"))
+        }
+        guard let fiatAmount = buyBitcoinQuote["fiatAmount"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fiatAmount", typeName: "BuyBitcoinQuote"))
+        }
+        guard let feesFiat = buyBitcoinQuote["feesFiat"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesFiat", typeName: "BuyBitcoinQuote"))
+        }
+        guard let amountSat = buyBitcoinQuote["amountSat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "BuyBitcoinQuote"))
+        }
+
+        return BuyBitcoinQuote(fiatCurrency: fiatCurrency, fiatAmount: fiatAmount, feesFiat: feesFiat, amountSat: amountSat)
+    }
+
+    static func dictionaryOf(buyBitcoinQuote: BuyBitcoinQuote) -> [String: Any?] {
+        return [
+            "fiatCurrency": buyBitcoinQuote.fiatCurrency,
+            "fiatAmount": buyBitcoinQuote.fiatAmount,
+            "feesFiat": buyBitcoinQuote.feesFiat,
+            "amountSat": buyBitcoinQuote.amountSat,
+        ]
+    }
+
+    static func asBuyBitcoinQuoteList(arr: [Any]) throws -> [BuyBitcoinQuote] {
+        var list = [BuyBitcoinQuote]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var buyBitcoinQuote = try asBuyBitcoinQuote(buyBitcoinQuote: val)
+                list.append(buyBitcoinQuote)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BuyBitcoinQuote"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(buyBitcoinQuoteList: [BuyBitcoinQuote]) -> [Any] {
+        return buyBitcoinQuoteList.map { v -> [String: Any?] in return dictionaryOf(buyBitcoinQuote: v) }
+    }
+
     static func asBuyBitcoinRequest(buyBitcoinRequest: [String: Any?]) throws -> BuyBitcoinRequest {
         guard let providerTmp = buyBitcoinRequest["provider"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "provider", typeName: "BuyBitcoinRequest"))
@@ -251,18 +714,226 @@ enum BreezSDKMapper {
         return buyBitcoinResponseList.map { v -> [String: Any?] in return dictionaryOf(buyBitcoinResponse: v) }
     }
 
-    static func asCheckMessageRequest(checkMessageRequest: [String: Any?]) throws -> CheckMessageRequest {
-        guard let message = checkMessageRequest["message"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "CheckMessageRequest"))
+    static func asChannelDetails(channelDetails: [String: Any?]) throws -> ChannelDetails {
+        guard let channelId = channelDetails["channelId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelId", typeName: "ChannelDetails"))
         }
-        guard let pubkey = checkMessageRequest["pubkey"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pubkey", typeName: "CheckMessageRequest"))
+        guard let peerId = channelDetails["peerId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "peerId", typeName: "ChannelDetails"))
         }
-        guard let signature = checkMessageRequest["signature"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "CheckMessageRequest"))
+        guard let peerConnected = channelDetails["peerConnected"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "peerConnected", typeName: "ChannelDetails"))
+        }
+        var shortChannelId: String?
+        if hasNonNilKey(data: channelDetails, key: "shortChannelId") {
+            guard let shortChannelIdTmp = channelDetails["shortChannelId"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "shortChannelId"))
+            }
+            shortChannelId = shortChannelIdTmp
+        }
+        guard let fundingTxid = channelDetails["fundingTxid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fundingTxid", typeName: "ChannelDetails"))
+        }
+        guard let stateTmp = channelDetails["state"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "state", typeName: "ChannelDetails"))
         }
+        let state = try asChannelState(channelState: stateTmp)
 
-        return CheckMessageRequest(message: message, pubkey: pubkey, signature: signature)
+        guard let capacityMsat = channelDetails["capacityMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "capacityMsat", typeName: "ChannelDetails"))
+        }
+        guard let localBalanceMsat = channelDetails["localBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localBalanceMsat", typeName: "ChannelDetails"))
+        }
+        guard let remoteBalanceMsat = channelDetails["remoteBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "remoteBalanceMsat", typeName: "ChannelDetails"))
+        }
+        guard let spendableMsat = channelDetails["spendableMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "spendableMsat", typeName: "ChannelDetails"))
+        }
+        guard let receivableMsat = channelDetails["receivableMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receivableMsat", typeName: "ChannelDetails"))
+        }
+        guard let reserveMsat = channelDetails["reserveMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reserveMsat", typeName: "ChannelDetails"))
+        }
+        guard let htlcsOutMsat = channelDetails["htlcsOutMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "htlcsOutMsat", typeName: "ChannelDetails"))
+        }
+        guard let htlcsInMsat = channelDetails["htlcsInMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "htlcsInMsat", typeName: "ChannelDetails"))
+        }
+        guard let dustHtlcsMsat = channelDetails["dustHtlcsMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "dustHtlcsMsat", typeName: "ChannelDetails"))
+        }
+
+        return ChannelDetails(channelId: channelId, peerId: peerId, peerConnected: peerConnected, shortChannelId: shortChannelId, fundingTxid: fundingTxid, state: state, capacityMsat: capacityMsat, localBalanceMsat: localBalanceMsat, remoteBalanceMsat: remoteBalanceMsat, spendableMsat: spendableMsat, receivableMsat: receivableMsat, reserveMsat: reserveMsat, htlcsOutMsat: htlcsOutMsat, htlcsInMsat: htlcsInMsat, dustHtlcsMsat: dustHtlcsMsat)
+    }
+
+    static func dictionaryOf(channelDetails: ChannelDetails) -> [String: Any?] {
+        return [
+            "channelId": channelDetails.channelId,
+            "peerId": channelDetails.peerId,
+            "peerConnected": channelDetails.peerConnected,
+            "shortChannelId": channelDetails.shortChannelId == nil ? nil : channelDetails.shortChannelId,
+            "fundingTxid": channelDetails.fundingTxid,
+            "state": valueOf(channelState: channelDetails.state),
+            "capacityMsat": channelDetails.capacityMsat,
+            "localBalanceMsat": channelDetails.localBalanceMsat,
+            "remoteBalanceMsat": channelDetails.remoteBalanceMsat,
+            "spendableMsat": channelDetails.spendableMsat,
+            "receivableMsat": channelDetails.receivableMsat,
+            "reserveMsat": channelDetails.reserveMsat,
+            "htlcsOutMsat": channelDetails.htlcsOutMsat,
+            "htlcsInMsat": channelDetails.htlcsInMsat,
+            "dustHtlcsMsat": channelDetails.dustHtlcsMsat,
+        ]
+    }
+
+    static func asChannelDetailsList(arr: [Any]) throws -> [ChannelDetails] {
+        var list = [ChannelDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var channelDetails = try asChannelDetails(channelDetails: val)
+                list.append(channelDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ChannelDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(channelDetailsList: [ChannelDetails]) -> [Any] {
+        return channelDetailsList.map { v -> [String: Any?] in return dictionaryOf(channelDetails: v) }
+    }
+
+    static func asChannelHygieneSuggestionDetails(channelHygieneSuggestionDetails: [String: Any?]) throws -> ChannelHygieneSuggestionDetails {
+        guard let channelId = channelHygieneSuggestionDetails["channelId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelId", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let peerId = channelHygieneSuggestionDetails["peerId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "peerId", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let fundingTxid = channelHygieneSuggestionDetails["fundingTxid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fundingTxid", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let localBalanceMsat = channelHygieneSuggestionDetails["localBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localBalanceMsat", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let idleSecs = channelHygieneSuggestionDetails["idleSecs"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "idleSecs", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let peerOfflineSecs = channelHygieneSuggestionDetails["peerOfflineSecs"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "peerOfflineSecs", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let satPerVbyte = channelHygieneSuggestionDetails["satPerVbyte"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "satPerVbyte", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let projectedSweepFeeSat = channelHygieneSuggestionDetails["projectedSweepFeeSat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "projectedSweepFeeSat", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+        guard let projectedSweepAmountSat = channelHygieneSuggestionDetails["projectedSweepAmountSat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "projectedSweepAmountSat", typeName: "ChannelHygieneSuggestionDetails"))
+        }
+
+        return ChannelHygieneSuggestionDetails(channelId: channelId, peerId: peerId, fundingTxid: fundingTxid, localBalanceMsat: localBalanceMsat, idleSecs: idleSecs, peerOfflineSecs: peerOfflineSecs, satPerVbyte: satPerVbyte, projectedSweepFeeSat: projectedSweepFeeSat, projectedSweepAmountSat: projectedSweepAmountSat)
+    }
+
+    static func dictionaryOf(channelHygieneSuggestionDetails: ChannelHygieneSuggestionDetails) -> [String: Any?] {
+        return [
+            "channelId": channelHygieneSuggestionDetails.channelId,
+            "peerId": channelHygieneSuggestionDetails.peerId,
+            "fundingTxid": channelHygieneSuggestionDetails.fundingTxid,
+            "localBalanceMsat": channelHygieneSuggestionDetails.localBalanceMsat,
+            "idleSecs": channelHygieneSuggestionDetails.idleSecs,
+            "peerOfflineSecs": channelHygieneSuggestionDetails.peerOfflineSecs,
+            "satPerVbyte": channelHygieneSuggestionDetails.satPerVbyte,
+            "projectedSweepFeeSat": channelHygieneSuggestionDetails.projectedSweepFeeSat,
+            "projectedSweepAmountSat": channelHygieneSuggestionDetails.projectedSweepAmountSat,
+        ]
+    }
+
+    static func asChannelHygieneSuggestionDetailsList(arr: [Any]) throws -> [ChannelHygieneSuggestionDetails] {
+        var list = [ChannelHygieneSuggestionDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var channelHygieneSuggestionDetails = try asChannelHygieneSuggestionDetails(channelHygieneSuggestionDetails: val)
+                list.append(channelHygieneSuggestionDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ChannelHygieneSuggestionDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(channelHygieneSuggestionDetailsList: [ChannelHygieneSuggestionDetails]) -> [Any] {
+        return channelHygieneSuggestionDetailsList.map { v -> [String: Any?] in return dictionaryOf(channelHygieneSuggestionDetails: v) }
+    }
+
+    static func asChannelOpeningFee(channelOpeningFee: [String: Any?]) throws -> ChannelOpeningFee {
+        guard let paymentHash = channelOpeningFee["paymentHash"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "ChannelOpeningFee"))
+        }
+        guard let paymentTime = channelOpeningFee["paymentTime"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentTime", typeName: "ChannelOpeningFee"))
+        }
+        guard let payerAmountMsat = channelOpeningFee["payerAmountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payerAmountMsat", typeName: "ChannelOpeningFee"))
+        }
+        guard let receivedAmountMsat = channelOpeningFee["receivedAmountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receivedAmountMsat", typeName: "ChannelOpeningFee"))
+        }
+        guard let feeMsat = channelOpeningFee["feeMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feeMsat", typeName: "ChannelOpeningFee"))
+        }
+        var openingFeeParams: OpeningFeeParams?
+        if let openingFeeParamsTmp = channelOpeningFee["openingFeeParams"] as? [String: Any?] {
+            openingFeeParams = try asOpeningFeeParams(openingFeeParams: openingFeeParamsTmp)
+        }
+
+        return ChannelOpeningFee(paymentHash: paymentHash, paymentTime: paymentTime, payerAmountMsat: payerAmountMsat, receivedAmountMsat: receivedAmountMsat, feeMsat: feeMsat, openingFeeParams: openingFeeParams)
+    }
+
+    static func dictionaryOf(channelOpeningFee: ChannelOpeningFee) -> [String: Any?] {
+        return [
+            "paymentHash": channelOpeningFee.paymentHash,
+            "paymentTime": channelOpeningFee.paymentTime,
+            "payerAmountMsat": channelOpeningFee.payerAmountMsat,
+            "receivedAmountMsat": channelOpeningFee.receivedAmountMsat,
+            "feeMsat": channelOpeningFee.feeMsat,
+            "openingFeeParams": channelOpeningFee.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: channelOpeningFee.openingFeeParams!),
+        ]
+    }
+
+    static func asChannelOpeningFeeList(arr: [Any]) throws -> [ChannelOpeningFee] {
+        var list = [ChannelOpeningFee]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var channelOpeningFee = try asChannelOpeningFee(channelOpeningFee: val)
+                list.append(channelOpeningFee)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ChannelOpeningFee"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(channelOpeningFeeList: [ChannelOpeningFee]) -> [Any] {
+        return channelOpeningFeeList.map { v -> [String: Any?] in return dictionaryOf(channelOpeningFee: v) }
+    }
+
+    static func asCheckMessageRequest(checkMessageRequest: [String: Any?]) throws -> CheckMessageRequest {
+        guard let message = checkMessageRequest["message"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "CheckMessageRequest"))
+        }
+        guard let pubkey = checkMessageRequest["pubkey"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pubkey", typeName: "CheckMessageRequest"))
+        }
+        guard let signature = checkMessageRequest["signature"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "CheckMessageRequest"))
+        }
+
+        return CheckMessageRequest(message: message, pubkey: pubkey, signature: signature)
     }
 
     static func dictionaryOf(checkMessageRequest: CheckMessageRequest) -> [String: Any?] {
@@ -321,6 +992,76 @@ enum BreezSDKMapper {
         return checkMessageResponseList.map { v -> [String: Any?] in return dictionaryOf(checkMessageResponse: v) }
     }
 
+    static func asCloseChannelRequest(closeChannelRequest: [String: Any?]) throws -> CloseChannelRequest {
+        guard let channelId = closeChannelRequest["channelId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelId", typeName: "CloseChannelRequest"))
+        }
+        guard let force = closeChannelRequest["force"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "force", typeName: "CloseChannelRequest"))
+        }
+
+        return CloseChannelRequest(channelId: channelId, force: force)
+    }
+
+    static func dictionaryOf(closeChannelRequest: CloseChannelRequest) -> [String: Any?] {
+        return [
+            "channelId": closeChannelRequest.channelId,
+            "force": closeChannelRequest.force,
+        ]
+    }
+
+    static func asCloseChannelRequestList(arr: [Any]) throws -> [CloseChannelRequest] {
+        var list = [CloseChannelRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var closeChannelRequest = try asCloseChannelRequest(closeChannelRequest: val)
+                list.append(closeChannelRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "CloseChannelRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(closeChannelRequestList: [CloseChannelRequest]) -> [Any] {
+        return closeChannelRequestList.map { v -> [String: Any?] in return dictionaryOf(closeChannelRequest: v) }
+    }
+
+    static func asCloseChannelResponse(closeChannelResponse: [String: Any?]) throws -> CloseChannelResponse {
+        var closingTxid: String?
+        if hasNonNilKey(data: closeChannelResponse, key: "closingTxid") {
+            guard let closingTxidTmp = closeChannelResponse["closingTxid"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "closingTxid"))
+            }
+            closingTxid = closingTxidTmp
+        }
+
+        return CloseChannelResponse(closingTxid: closingTxid)
+    }
+
+    static func dictionaryOf(closeChannelResponse: CloseChannelResponse) -> [String: Any?] {
+        return [
+            "closingTxid": closeChannelResponse.closingTxid == nil ? nil : closeChannelResponse.closingTxid,
+        ]
+    }
+
+    static func asCloseChannelResponseList(arr: [Any]) throws -> [CloseChannelResponse] {
+        var list = [CloseChannelResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var closeChannelResponse = try asCloseChannelResponse(closeChannelResponse: val)
+                list.append(closeChannelResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "CloseChannelResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(closeChannelResponseList: [CloseChannelResponse]) -> [Any] {
+        return closeChannelResponseList.map { v -> [String: Any?] in return dictionaryOf(closeChannelResponse: v) }
+    }
+
     static func asClosedChannelPaymentDetails(closedChannelPaymentDetails: [String: Any?]) throws -> ClosedChannelPaymentDetails {
         guard let stateTmp = closedChannelPaymentDetails["state"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "state", typeName: "ClosedChannelPaymentDetails"))
@@ -381,6 +1122,9 @@ enum BreezSDKMapper {
         guard let chainnotifierUrl = config["chainnotifierUrl"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "chainnotifierUrl", typeName: "Config"))
         }
+        guard let lnurlServerUrl = config["lnurlServerUrl"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnurlServerUrl", typeName: "Config"))
+        }
         var mempoolspaceUrl: String?
         if hasNonNilKey(data: config, key: "mempoolspaceUrl") {
             guard let mempoolspaceUrlTmp = config["mempoolspaceUrl"] as? String else {
@@ -388,9 +1132,38 @@ enum BreezSDKMapper {
             }
             mempoolspaceUrl = mempoolspaceUrlTmp
         }
+        var chainService: ChainServiceConfig?
+        if let chainServiceTmp = config["chainService"] as? [String: Any?] {
+            chainService = try asChainServiceConfig(chainServiceConfig: chainServiceTmp)
+        }
+
+        var backupTransport: BackupTransportConfig?
+        if let backupTransportTmp = config["backupTransport"] as? [String: Any?] {
+            backupTransport = try asBackupTransportConfig(backupTransportConfig: backupTransportTmp)
+        }
+
+        var proxy: ProxyConfig?
+        if let proxyTmp = config["proxy"] as? [String: Any?] {
+            proxy = try asProxyConfig(proxyConfig: proxyTmp)
+        }
+
         guard let workingDir = config["workingDir"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "workingDir", typeName: "Config"))
         }
+        var dbDir: String?
+        if hasNonNilKey(data: config, key: "dbDir") {
+            guard let dbDirTmp = config["dbDir"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "dbDir"))
+            }
+            dbDir = dbDirTmp
+        }
+        var credentialsDir: String?
+        if hasNonNilKey(data: config, key: "credentialsDir") {
+            guard let credentialsDirTmp = config["credentialsDir"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "credentialsDir"))
+            }
+            credentialsDir = credentialsDirTmp
+        }
         guard let networkTmp = config["network"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "Config"))
         }
@@ -399,6 +1172,9 @@ enum BreezSDKMapper {
         guard let paymentTimeoutSec = config["paymentTimeoutSec"] as? UInt32 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentTimeoutSec", typeName: "Config"))
         }
+        guard let maxConcurrentPayments = config["maxConcurrentPayments"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxConcurrentPayments", typeName: "Config"))
+        }
         var defaultLspId: String?
         if hasNonNilKey(data: config, key: "defaultLspId") {
             guard let defaultLspIdTmp = config["defaultLspId"] as? String else {
@@ -406,6 +1182,11 @@ enum BreezSDKMapper {
             }
             defaultLspId = defaultLspIdTmp
         }
+        var lspPolicy: LspPolicy?
+        if let lspPolicyTmp = config["lspPolicy"] as? [String: Any?] {
+            lspPolicy = try asLspPolicy(lspPolicy: lspPolicyTmp)
+        }
+
         var apiKey: String?
         if hasNonNilKey(data: config, key: "apiKey") {
             guard let apiKeyTmp = config["apiKey"] as? String else {
@@ -419,26 +1200,132 @@ enum BreezSDKMapper {
         guard let exemptfeeMsat = config["exemptfeeMsat"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "exemptfeeMsat", typeName: "Config"))
         }
+        var fiatCurrency: String?
+        if hasNonNilKey(data: config, key: "fiatCurrency") {
+            guard let fiatCurrencyTmp = config["fiatCurrency"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "fiatCurrency"))
+            }
+            fiatCurrency = fiatCurrencyTmp
+        }
+        var lowOutboundLiquidityThresholdMsat: UInt64?
+        if hasNonNilKey(data: config, key: "lowOutboundLiquidityThresholdMsat") {
+            guard let lowOutboundLiquidityThresholdMsatTmp = config["lowOutboundLiquidityThresholdMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lowOutboundLiquidityThresholdMsat"))
+            }
+            lowOutboundLiquidityThresholdMsat = lowOutboundLiquidityThresholdMsatTmp
+        }
+        var lowInboundLiquidityThresholdMsat: UInt64?
+        if hasNonNilKey(data: config, key: "lowInboundLiquidityThresholdMsat") {
+            guard let lowInboundLiquidityThresholdMsatTmp = config["lowInboundLiquidityThresholdMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lowInboundLiquidityThresholdMsat"))
+            }
+            lowInboundLiquidityThresholdMsat = lowInboundLiquidityThresholdMsatTmp
+        }
+        guard let useTrampoline = config["useTrampoline"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "useTrampoline", typeName: "Config"))
+        }
+        var partnerFee: PartnerFeeConfig?
+        if let partnerFeeTmp = config["partnerFee"] as? [String: Any?] {
+            partnerFee = try asPartnerFeeConfig(partnerFeeConfig: partnerFeeTmp)
+        }
+
+        var swapAutoRefund: AutoRefundConfig?
+        if let swapAutoRefundTmp = config["swapAutoRefund"] as? [String: Any?] {
+            swapAutoRefund = try asAutoRefundConfig(autoRefundConfig: swapAutoRefundTmp)
+        }
+
+        guard let memoPrivacyTmp = config["memoPrivacy"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "memoPrivacy", typeName: "Config"))
+        }
+        let memoPrivacy = try asMemoPrivacy(memoPrivacy: memoPrivacyTmp)
+
+        var channelHygieneWindowSecs: UInt64?
+        if hasNonNilKey(data: config, key: "channelHygieneWindowSecs") {
+            guard let channelHygieneWindowSecsTmp = config["channelHygieneWindowSecs"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "channelHygieneWindowSecs"))
+            }
+            channelHygieneWindowSecs = channelHygieneWindowSecsTmp
+        }
+        var logFilter: String?
+        if hasNonNilKey(data: config, key: "logFilter") {
+            guard let logFilterTmp = config["logFilter"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "logFilter"))
+            }
+            logFilter = logFilterTmp
+        }
+        var denylistUrl: String?
+        if hasNonNilKey(data: config, key: "denylistUrl") {
+            guard let denylistUrlTmp = config["denylistUrl"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "denylistUrl"))
+            }
+            denylistUrl = denylistUrlTmp
+        }
+        var transakApiKey: String?
+        if hasNonNilKey(data: config, key: "transakApiKey") {
+            guard let transakApiKeyTmp = config["transakApiKey"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "transakApiKey"))
+            }
+            transakApiKey = transakApiKeyTmp
+        }
+        var spendPolicy: SpendPolicy?
+        if let spendPolicyTmp = config["spendPolicy"] as? [String: Any?] {
+            spendPolicy = try asSpendPolicy(spendPolicy: spendPolicyTmp)
+        }
+
+        var htlcExposureLimits: HtlcExposureLimits?
+        if let htlcExposureLimitsTmp = config["htlcExposureLimits"] as? [String: Any?] {
+            htlcExposureLimits = try asHtlcExposureLimits(htlcExposureLimits: htlcExposureLimitsTmp)
+        }
+
+        var serviceStatusCheckIntervalSecs: UInt64?
+        if hasNonNilKey(data: config, key: "serviceStatusCheckIntervalSecs") {
+            guard let serviceStatusCheckIntervalSecsTmp = config["serviceStatusCheckIntervalSecs"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "serviceStatusCheckIntervalSecs"))
+            }
+            serviceStatusCheckIntervalSecs = serviceStatusCheckIntervalSecsTmp
+        }
         guard let nodeConfigTmp = config["nodeConfig"] as? [String: Any?] else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeConfig", typeName: "Config"))
         }
         let nodeConfig = try asNodeConfig(nodeConfig: nodeConfigTmp)
 
-        return Config(breezserver: breezserver, chainnotifierUrl: chainnotifierUrl, mempoolspaceUrl: mempoolspaceUrl, workingDir: workingDir, network: network, paymentTimeoutSec: paymentTimeoutSec, defaultLspId: defaultLspId, apiKey: apiKey, maxfeePercent: maxfeePercent, exemptfeeMsat: exemptfeeMsat, nodeConfig: nodeConfig)
+        return Config(breezserver: breezserver, chainnotifierUrl: chainnotifierUrl, lnurlServerUrl: lnurlServerUrl, mempoolspaceUrl: mempoolspaceUrl, chainService: chainService, backupTransport: backupTransport, proxy: proxy, workingDir: workingDir, dbDir: dbDir, credentialsDir: credentialsDir, network: network, paymentTimeoutSec: paymentTimeoutSec, maxConcurrentPayments: maxConcurrentPayments, defaultLspId: defaultLspId, lspPolicy: lspPolicy, apiKey: apiKey, maxfeePercent: maxfeePercent, exemptfeeMsat: exemptfeeMsat, fiatCurrency: fiatCurrency, lowOutboundLiquidityThresholdMsat: lowOutboundLiquidityThresholdMsat, lowInboundLiquidityThresholdMsat: lowInboundLiquidityThresholdMsat, useTrampoline: useTrampoline, partnerFee: partnerFee, swapAutoRefund: swapAutoRefund, memoPrivacy: memoPrivacy, channelHygieneWindowSecs: channelHygieneWindowSecs, logFilter: logFilter, denylistUrl: denylistUrl, transakApiKey: transakApiKey, spendPolicy: spendPolicy, htlcExposureLimits: htlcExposureLimits, serviceStatusCheckIntervalSecs: serviceStatusCheckIntervalSecs, nodeConfig: nodeConfig)
     }
 
     static func dictionaryOf(config: Config) -> [String: Any?] {
         return [
             "breezserver": config.breezserver,
             "chainnotifierUrl": config.chainnotifierUrl,
+            "lnurlServerUrl": config.lnurlServerUrl,
             "mempoolspaceUrl": config.mempoolspaceUrl == nil ? nil : config.mempoolspaceUrl,
+            "chainService": config.chainService == nil ? nil : dictionaryOf(chainServiceConfig: config.chainService!),
+            "backupTransport": config.backupTransport == nil ? nil : dictionaryOf(backupTransportConfig: config.backupTransport!),
+            "proxy": config.proxy == nil ? nil : dictionaryOf(proxyConfig: config.proxy!),
             "workingDir": config.workingDir,
+            "dbDir": config.dbDir == nil ? nil : config.dbDir,
+            "credentialsDir": config.credentialsDir == nil ? nil : config.credentialsDir,
             "network": valueOf(network: config.network),
             "paymentTimeoutSec": config.paymentTimeoutSec,
+            "maxConcurrentPayments": config.maxConcurrentPayments,
             "defaultLspId": config.defaultLspId == nil ? nil : config.defaultLspId,
+            "lspPolicy": config.lspPolicy == nil ? nil : dictionaryOf(lspPolicy: config.lspPolicy!),
             "apiKey": config.apiKey == nil ? nil : config.apiKey,
             "maxfeePercent": config.maxfeePercent,
             "exemptfeeMsat": config.exemptfeeMsat,
+            "fiatCurrency": config.fiatCurrency == nil ? nil : config.fiatCurrency,
+            "lowOutboundLiquidityThresholdMsat": config.lowOutboundLiquidityThresholdMsat == nil ? nil : config.lowOutboundLiquidityThresholdMsat,
+            "lowInboundLiquidityThresholdMsat": config.lowInboundLiquidityThresholdMsat == nil ? nil : config.lowInboundLiquidityThresholdMsat,
+            "useTrampoline": config.useTrampoline,
+            "partnerFee": config.partnerFee == nil ? nil : dictionaryOf(partnerFeeConfig: config.partnerFee!),
+            "swapAutoRefund": config.swapAutoRefund == nil ? nil : dictionaryOf(autoRefundConfig: config.swapAutoRefund!),
+            "memoPrivacy": valueOf(memoPrivacy: config.memoPrivacy),
+            "channelHygieneWindowSecs": config.channelHygieneWindowSecs == nil ? nil : config.channelHygieneWindowSecs,
+            "logFilter": config.logFilter == nil ? nil : config.logFilter,
+            "denylistUrl": config.denylistUrl == nil ? nil : config.denylistUrl,
+            "transakApiKey": config.transakApiKey == nil ? nil : config.transakApiKey,
+            "spendPolicy": config.spendPolicy == nil ? nil : dictionaryOf(spendPolicy: config.spendPolicy!),
+            "htlcExposureLimits": config.htlcExposureLimits == nil ? nil : dictionaryOf(htlcExposureLimits: config.htlcExposureLimits!),
+            "serviceStatusCheckIntervalSecs": config.serviceStatusCheckIntervalSecs == nil ? nil : config.serviceStatusCheckIntervalSecs,
             "nodeConfig": dictionaryOf(nodeConfig: config.nodeConfig),
         ]
     }
@@ -495,6 +1382,82 @@ enum BreezSDKMapper {
         return configureNodeRequestList.map { v -> [String: Any?] in return dictionaryOf(configureNodeRequest: v) }
     }
 
+    static func asConnectPeerRequest(connectPeerRequest: [String: Any?]) throws -> ConnectPeerRequest {
+        guard let nodeUri = connectPeerRequest["nodeUri"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeUri", typeName: "ConnectPeerRequest"))
+        }
+
+        return ConnectPeerRequest(nodeUri: nodeUri)
+    }
+
+    static func dictionaryOf(connectPeerRequest: ConnectPeerRequest) -> [String: Any?] {
+        return [
+            "nodeUri": connectPeerRequest.nodeUri,
+        ]
+    }
+
+    static func asConnectPeerRequestList(arr: [Any]) throws -> [ConnectPeerRequest] {
+        var list = [ConnectPeerRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var connectPeerRequest = try asConnectPeerRequest(connectPeerRequest: val)
+                list.append(connectPeerRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectPeerRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(connectPeerRequestList: [ConnectPeerRequest]) -> [Any] {
+        return connectPeerRequestList.map { v -> [String: Any?] in return dictionaryOf(connectPeerRequest: v) }
+    }
+
+    static func asConnectProgressDetails(connectProgressDetails: [String: Any?]) throws -> ConnectProgressDetails {
+        guard let stageTmp = connectProgressDetails["stage"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "stage", typeName: "ConnectProgressDetails"))
+        }
+        let stage = try asConnectStage(connectStage: stageTmp)
+
+        guard let progressPercent = connectProgressDetails["progressPercent"] as? UInt8 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "progressPercent", typeName: "ConnectProgressDetails"))
+        }
+        var isNewNode: Bool?
+        if hasNonNilKey(data: connectProgressDetails, key: "isNewNode") {
+            guard let isNewNodeTmp = connectProgressDetails["isNewNode"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "isNewNode"))
+            }
+            isNewNode = isNewNodeTmp
+        }
+
+        return ConnectProgressDetails(stage: stage, progressPercent: progressPercent, isNewNode: isNewNode)
+    }
+
+    static func dictionaryOf(connectProgressDetails: ConnectProgressDetails) -> [String: Any?] {
+        return [
+            "stage": valueOf(connectStage: connectProgressDetails.stage),
+            "progressPercent": connectProgressDetails.progressPercent,
+            "isNewNode": connectProgressDetails.isNewNode == nil ? nil : connectProgressDetails.isNewNode,
+        ]
+    }
+
+    static func asConnectProgressDetailsList(arr: [Any]) throws -> [ConnectProgressDetails] {
+        var list = [ConnectProgressDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var connectProgressDetails = try asConnectProgressDetails(connectProgressDetails: val)
+                list.append(connectProgressDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectProgressDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(connectProgressDetailsList: [ConnectProgressDetails]) -> [Any] {
+        return connectProgressDetailsList.map { v -> [String: Any?] in return dictionaryOf(connectProgressDetails: v) }
+    }
+
     static func asConnectRequest(connectRequest: [String: Any?]) throws -> ConnectRequest {
         guard let configTmp = connectRequest["config"] as? [String: Any?] else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "config", typeName: "ConnectRequest"))
@@ -511,8 +1474,15 @@ enum BreezSDKMapper {
             }
             restoreOnly = restoreOnlyTmp
         }
+        var registerIfMissing: Bool?
+        if hasNonNilKey(data: connectRequest, key: "registerIfMissing") {
+            guard let registerIfMissingTmp = connectRequest["registerIfMissing"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "registerIfMissing"))
+            }
+            registerIfMissing = registerIfMissingTmp
+        }
 
-        return ConnectRequest(config: config, seed: seed, restoreOnly: restoreOnly)
+        return ConnectRequest(config: config, seed: seed, restoreOnly: restoreOnly, registerIfMissing: registerIfMissing)
     }
 
     static func dictionaryOf(connectRequest: ConnectRequest) -> [String: Any?] {
@@ -520,6 +1490,7 @@ enum BreezSDKMapper {
             "config": dictionaryOf(config: connectRequest.config),
             "seed": connectRequest.seed,
             "restoreOnly": connectRequest.restoreOnly == nil ? nil : connectRequest.restoreOnly,
+            "registerIfMissing": connectRequest.registerIfMissing == nil ? nil : connectRequest.registerIfMissing,
         ]
     }
 
@@ -540,82 +1511,608 @@ enum BreezSDKMapper {
         return connectRequestList.map { v -> [String: Any?] in return dictionaryOf(connectRequest: v) }
     }
 
-    static func asCurrencyInfo(currencyInfo: [String: Any?]) throws -> CurrencyInfo {
-        guard let name = currencyInfo["name"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "CurrencyInfo"))
-        }
-        guard let fractionSize = currencyInfo["fractionSize"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fractionSize", typeName: "CurrencyInfo"))
-        }
-        var spacing: UInt32?
-        if hasNonNilKey(data: currencyInfo, key: "spacing") {
-            guard let spacingTmp = currencyInfo["spacing"] as? UInt32 else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "spacing"))
-            }
-            spacing = spacingTmp
-        }
-        var symbol: Symbol?
-        if let symbolTmp = currencyInfo["symbol"] as? [String: Any?] {
-            symbol = try asSymbol(symbol: symbolTmp)
+    static func asConnectionStatus(connectionStatus: [String: Any?]) throws -> ConnectionStatus {
+        guard let stateTmp = connectionStatus["state"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "state", typeName: "ConnectionStatus"))
         }
+        let state = try asConnectionState(connectionState: stateTmp)
 
-        var uniqSymbol: Symbol?
-        if let uniqSymbolTmp = currencyInfo["uniqSymbol"] as? [String: Any?] {
-            uniqSymbol = try asSymbol(symbol: uniqSymbolTmp)
+        guard let since = connectionStatus["since"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "since", typeName: "ConnectionStatus"))
         }
-
-        guard let localizedNameTmp = currencyInfo["localizedName"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localizedName", typeName: "CurrencyInfo"))
+        guard let reconnectAttempts = connectionStatus["reconnectAttempts"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reconnectAttempts", typeName: "ConnectionStatus"))
         }
-        let localizedName = try asLocalizedNameList(arr: localizedNameTmp)
-
-        guard let localeOverridesTmp = currencyInfo["localeOverrides"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localeOverrides", typeName: "CurrencyInfo"))
+        var lastError: String?
+        if hasNonNilKey(data: connectionStatus, key: "lastError") {
+            guard let lastErrorTmp = connectionStatus["lastError"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastError"))
+            }
+            lastError = lastErrorTmp
         }
-        let localeOverrides = try asLocaleOverridesList(arr: localeOverridesTmp)
 
-        return CurrencyInfo(name: name, fractionSize: fractionSize, spacing: spacing, symbol: symbol, uniqSymbol: uniqSymbol, localizedName: localizedName, localeOverrides: localeOverrides)
+        return ConnectionStatus(state: state, since: since, reconnectAttempts: reconnectAttempts, lastError: lastError)
     }
 
-    static func dictionaryOf(currencyInfo: CurrencyInfo) -> [String: Any?] {
+    static func dictionaryOf(connectionStatus: ConnectionStatus) -> [String: Any?] {
         return [
-            "name": currencyInfo.name,
-            "fractionSize": currencyInfo.fractionSize,
-            "spacing": currencyInfo.spacing == nil ? nil : currencyInfo.spacing,
-            "symbol": currencyInfo.symbol == nil ? nil : dictionaryOf(symbol: currencyInfo.symbol!),
-            "uniqSymbol": currencyInfo.uniqSymbol == nil ? nil : dictionaryOf(symbol: currencyInfo.uniqSymbol!),
-            "localizedName": arrayOf(localizedNameList: currencyInfo.localizedName),
-            "localeOverrides": arrayOf(localeOverridesList: currencyInfo.localeOverrides),
+            "state": valueOf(connectionState: connectionStatus.state),
+            "since": connectionStatus.since,
+            "reconnectAttempts": connectionStatus.reconnectAttempts,
+            "lastError": connectionStatus.lastError == nil ? nil : connectionStatus.lastError,
         ]
     }
 
-    static func asCurrencyInfoList(arr: [Any]) throws -> [CurrencyInfo] {
-        var list = [CurrencyInfo]()
+    static func asConnectionStatusList(arr: [Any]) throws -> [ConnectionStatus] {
+        var list = [ConnectionStatus]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var currencyInfo = try asCurrencyInfo(currencyInfo: val)
-                list.append(currencyInfo)
+                var connectionStatus = try asConnectionStatus(connectionStatus: val)
+                list.append(connectionStatus)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "CurrencyInfo"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectionStatus"))
             }
         }
         return list
     }
 
-    static func arrayOf(currencyInfoList: [CurrencyInfo]) -> [Any] {
-        return currencyInfoList.map { v -> [String: Any?] in return dictionaryOf(currencyInfo: v) }
+    static func arrayOf(connectionStatusList: [ConnectionStatus]) -> [Any] {
+        return connectionStatusList.map { v -> [String: Any?] in return dictionaryOf(connectionStatus: v) }
     }
 
-    static func asFiatCurrency(fiatCurrency: [String: Any?]) throws -> FiatCurrency {
-        guard let id = fiatCurrency["id"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "FiatCurrency"))
+    static func asCreateLnurlWithdrawVoucherRequest(createLnurlWithdrawVoucherRequest: [String: Any?]) throws -> CreateLnurlWithdrawVoucherRequest {
+        guard let amountMsat = createLnurlWithdrawVoucherRequest["amountMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "CreateLnurlWithdrawVoucherRequest"))
         }
-        guard let infoTmp = fiatCurrency["info"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "info", typeName: "FiatCurrency"))
+        guard let uses = createLnurlWithdrawVoucherRequest["uses"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "uses", typeName: "CreateLnurlWithdrawVoucherRequest"))
         }
-        let info = try asCurrencyInfo(currencyInfo: infoTmp)
-
-        return FiatCurrency(id: id, info: info)
+        guard let expirySecs = createLnurlWithdrawVoucherRequest["expirySecs"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expirySecs", typeName: "CreateLnurlWithdrawVoucherRequest"))
+        }
+        var description: String?
+        if hasNonNilKey(data: createLnurlWithdrawVoucherRequest, key: "description") {
+            guard let descriptionTmp = createLnurlWithdrawVoucherRequest["description"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+            }
+            description = descriptionTmp
+        }
+
+        return CreateLnurlWithdrawVoucherRequest(amountMsat: amountMsat, uses: uses, expirySecs: expirySecs, description: description)
+    }
+
+    static func dictionaryOf(createLnurlWithdrawVoucherRequest: CreateLnurlWithdrawVoucherRequest) -> [String: Any?] {
+        return [
+            "amountMsat": createLnurlWithdrawVoucherRequest.amountMsat,
+            "uses": createLnurlWithdrawVoucherRequest.uses,
+            "expirySecs": createLnurlWithdrawVoucherRequest.expirySecs,
+            "description": createLnurlWithdrawVoucherRequest.description == nil ? nil : createLnurlWithdrawVoucherRequest.description,
+        ]
+    }
+
+    static func asCreateLnurlWithdrawVoucherRequestList(arr: [Any]) throws -> [CreateLnurlWithdrawVoucherRequest] {
+        var list = [CreateLnurlWithdrawVoucherRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var createLnurlWithdrawVoucherRequest = try asCreateLnurlWithdrawVoucherRequest(createLnurlWithdrawVoucherRequest: val)
+                list.append(createLnurlWithdrawVoucherRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "CreateLnurlWithdrawVoucherRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(createLnurlWithdrawVoucherRequestList: [CreateLnurlWithdrawVoucherRequest]) -> [Any] {
+        return createLnurlWithdrawVoucherRequestList.map { v -> [String: Any?] in return dictionaryOf(createLnurlWithdrawVoucherRequest: v) }
+    }
+
+    static func asCreateStaticLnurlPayRequest(createStaticLnurlPayRequest: [String: Any?]) throws -> CreateStaticLnurlPayRequest {
+        guard let url = createStaticLnurlPayRequest["url"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "CreateStaticLnurlPayRequest"))
+        }
+        guard let callbackUrl = createStaticLnurlPayRequest["callbackUrl"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "callbackUrl", typeName: "CreateStaticLnurlPayRequest"))
+        }
+        guard let description = createStaticLnurlPayRequest["description"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "CreateStaticLnurlPayRequest"))
+        }
+        guard let minSendableMsat = createStaticLnurlPayRequest["minSendableMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minSendableMsat", typeName: "CreateStaticLnurlPayRequest"))
+        }
+        guard let maxSendableMsat = createStaticLnurlPayRequest["maxSendableMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxSendableMsat", typeName: "CreateStaticLnurlPayRequest"))
+        }
+        var commentAllowed: UInt16?
+        if hasNonNilKey(data: createStaticLnurlPayRequest, key: "commentAllowed") {
+            guard let commentAllowedTmp = createStaticLnurlPayRequest["commentAllowed"] as? UInt16 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "commentAllowed"))
+            }
+            commentAllowed = commentAllowedTmp
+        }
+
+        return CreateStaticLnurlPayRequest(url: url, callbackUrl: callbackUrl, description: description, minSendableMsat: minSendableMsat, maxSendableMsat: maxSendableMsat, commentAllowed: commentAllowed)
+    }
+
+    static func dictionaryOf(createStaticLnurlPayRequest: CreateStaticLnurlPayRequest) -> [String: Any?] {
+        return [
+            "url": createStaticLnurlPayRequest.url,
+            "callbackUrl": createStaticLnurlPayRequest.callbackUrl,
+            "description": createStaticLnurlPayRequest.description,
+            "minSendableMsat": createStaticLnurlPayRequest.minSendableMsat,
+            "maxSendableMsat": createStaticLnurlPayRequest.maxSendableMsat,
+            "commentAllowed": createStaticLnurlPayRequest.commentAllowed == nil ? nil : createStaticLnurlPayRequest.commentAllowed,
+        ]
+    }
+
+    static func asCreateStaticLnurlPayRequestList(arr: [Any]) throws -> [CreateStaticLnurlPayRequest] {
+        var list = [CreateStaticLnurlPayRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var createStaticLnurlPayRequest = try asCreateStaticLnurlPayRequest(createStaticLnurlPayRequest: val)
+                list.append(createStaticLnurlPayRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "CreateStaticLnurlPayRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(createStaticLnurlPayRequestList: [CreateStaticLnurlPayRequest]) -> [Any] {
+        return createStaticLnurlPayRequestList.map { v -> [String: Any?] in return dictionaryOf(createStaticLnurlPayRequest: v) }
+    }
+
+    static func asCurrencyInfo(currencyInfo: [String: Any?]) throws -> CurrencyInfo {
+        guard let name = currencyInfo["name"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "CurrencyInfo"))
+        }
+        guard let fractionSize = currencyInfo["fractionSize"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fractionSize", typeName: "CurrencyInfo"))
+        }
+        var spacing: UInt32?
+        if hasNonNilKey(data: currencyInfo, key: "spacing") {
+            guard let spacingTmp = currencyInfo["spacing"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "spacing"))
+            }
+            spacing = spacingTmp
+        }
+        var symbol: Symbol?
+        if let symbolTmp = currencyInfo["symbol"] as? [String: Any?] {
+            symbol = try asSymbol(symbol: symbolTmp)
+        }
+
+        var uniqSymbol: Symbol?
+        if let uniqSymbolTmp = currencyInfo["uniqSymbol"] as? [String: Any?] {
+            uniqSymbol = try asSymbol(symbol: uniqSymbolTmp)
+        }
+
+        guard let localizedNameTmp = currencyInfo["localizedName"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localizedName", typeName: "CurrencyInfo"))
+        }
+        let localizedName = try asLocalizedNameList(arr: localizedNameTmp)
+
+        guard let localeOverridesTmp = currencyInfo["localeOverrides"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localeOverrides", typeName: "CurrencyInfo"))
+        }
+        let localeOverrides = try asLocaleOverridesList(arr: localeOverridesTmp)
+
+        return CurrencyInfo(name: name, fractionSize: fractionSize, spacing: spacing, symbol: symbol, uniqSymbol: uniqSymbol, localizedName: localizedName, localeOverrides: localeOverrides)
+    }
+
+    static func dictionaryOf(currencyInfo: CurrencyInfo) -> [String: Any?] {
+        return [
+            "name": currencyInfo.name,
+            "fractionSize": currencyInfo.fractionSize,
+            "spacing": currencyInfo.spacing == nil ? nil : currencyInfo.spacing,
+            "symbol": currencyInfo.symbol == nil ? nil : dictionaryOf(symbol: currencyInfo.symbol!),
+            "uniqSymbol": currencyInfo.uniqSymbol == nil ? nil : dictionaryOf(symbol: currencyInfo.uniqSymbol!),
+            "localizedName": arrayOf(localizedNameList: currencyInfo.localizedName),
+            "localeOverrides": arrayOf(localeOverridesList: currencyInfo.localeOverrides),
+        ]
+    }
+
+    static func asCurrencyInfoList(arr: [Any]) throws -> [CurrencyInfo] {
+        var list = [CurrencyInfo]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var currencyInfo = try asCurrencyInfo(currencyInfo: val)
+                list.append(currencyInfo)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "CurrencyInfo"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(currencyInfoList: [CurrencyInfo]) -> [Any] {
+        return currencyInfoList.map { v -> [String: Any?] in return dictionaryOf(currencyInfo: v) }
+    }
+
+    static func asDecryptRecoveryBundleRequest(decryptRecoveryBundleRequest: [String: Any?]) throws -> DecryptRecoveryBundleRequest {
+        guard let bundle = decryptRecoveryBundleRequest["bundle"] as? [UInt8] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bundle", typeName: "DecryptRecoveryBundleRequest"))
+        }
+        guard let privateKey = decryptRecoveryBundleRequest["privateKey"] as? [UInt8] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "privateKey", typeName: "DecryptRecoveryBundleRequest"))
+        }
+
+        return DecryptRecoveryBundleRequest(bundle: bundle, privateKey: privateKey)
+    }
+
+    static func dictionaryOf(decryptRecoveryBundleRequest: DecryptRecoveryBundleRequest) -> [String: Any?] {
+        return [
+            "bundle": decryptRecoveryBundleRequest.bundle,
+            "privateKey": decryptRecoveryBundleRequest.privateKey,
+        ]
+    }
+
+    static func asDecryptRecoveryBundleRequestList(arr: [Any]) throws -> [DecryptRecoveryBundleRequest] {
+        var list = [DecryptRecoveryBundleRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var decryptRecoveryBundleRequest = try asDecryptRecoveryBundleRequest(decryptRecoveryBundleRequest: val)
+                list.append(decryptRecoveryBundleRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "DecryptRecoveryBundleRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(decryptRecoveryBundleRequestList: [DecryptRecoveryBundleRequest]) -> [Any] {
+        return decryptRecoveryBundleRequestList.map { v -> [String: Any?] in return dictionaryOf(decryptRecoveryBundleRequest: v) }
+    }
+
+    static func asDeriveEncryptionKeyRequest(deriveEncryptionKeyRequest: [String: Any?]) throws -> DeriveEncryptionKeyRequest {
+        guard let purpose = deriveEncryptionKeyRequest["purpose"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "purpose", typeName: "DeriveEncryptionKeyRequest"))
+        }
+        guard let index = deriveEncryptionKeyRequest["index"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "index", typeName: "DeriveEncryptionKeyRequest"))
+        }
+
+        return DeriveEncryptionKeyRequest(purpose: purpose, index: index)
+    }
+
+    static func dictionaryOf(deriveEncryptionKeyRequest: DeriveEncryptionKeyRequest) -> [String: Any?] {
+        return [
+            "purpose": deriveEncryptionKeyRequest.purpose,
+            "index": deriveEncryptionKeyRequest.index,
+        ]
+    }
+
+    static func asDeriveEncryptionKeyRequestList(arr: [Any]) throws -> [DeriveEncryptionKeyRequest] {
+        var list = [DeriveEncryptionKeyRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var deriveEncryptionKeyRequest = try asDeriveEncryptionKeyRequest(deriveEncryptionKeyRequest: val)
+                list.append(deriveEncryptionKeyRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "DeriveEncryptionKeyRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(deriveEncryptionKeyRequestList: [DeriveEncryptionKeyRequest]) -> [Any] {
+        return deriveEncryptionKeyRequestList.map { v -> [String: Any?] in return dictionaryOf(deriveEncryptionKeyRequest: v) }
+    }
+
+    static func asDeriveEncryptionKeyResponse(deriveEncryptionKeyResponse: [String: Any?]) throws -> DeriveEncryptionKeyResponse {
+        guard let key = deriveEncryptionKeyResponse["key"] as? [UInt8] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "key", typeName: "DeriveEncryptionKeyResponse"))
+        }
+
+        return DeriveEncryptionKeyResponse(key: key)
+    }
+
+    static func dictionaryOf(deriveEncryptionKeyResponse: DeriveEncryptionKeyResponse) -> [String: Any?] {
+        return [
+            "key": deriveEncryptionKeyResponse.key,
+        ]
+    }
+
+    static func asDeriveEncryptionKeyResponseList(arr: [Any]) throws -> [DeriveEncryptionKeyResponse] {
+        var list = [DeriveEncryptionKeyResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var deriveEncryptionKeyResponse = try asDeriveEncryptionKeyResponse(deriveEncryptionKeyResponse: val)
+                list.append(deriveEncryptionKeyResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "DeriveEncryptionKeyResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(deriveEncryptionKeyResponseList: [DeriveEncryptionKeyResponse]) -> [Any] {
+        return deriveEncryptionKeyResponseList.map { v -> [String: Any?] in return dictionaryOf(deriveEncryptionKeyResponse: v) }
+    }
+
+    static func asEncryptedPaymentRequestData(encryptedPaymentRequestData: [String: Any?]) throws -> EncryptedPaymentRequestData {
+        guard let blob = encryptedPaymentRequestData["blob"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "blob", typeName: "EncryptedPaymentRequestData"))
+        }
+
+        return EncryptedPaymentRequestData(blob: blob)
+    }
+
+    static func dictionaryOf(encryptedPaymentRequestData: EncryptedPaymentRequestData) -> [String: Any?] {
+        return [
+            "blob": encryptedPaymentRequestData.blob,
+        ]
+    }
+
+    static func asEncryptedPaymentRequestDataList(arr: [Any]) throws -> [EncryptedPaymentRequestData] {
+        var list = [EncryptedPaymentRequestData]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var encryptedPaymentRequestData = try asEncryptedPaymentRequestData(encryptedPaymentRequestData: val)
+                list.append(encryptedPaymentRequestData)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "EncryptedPaymentRequestData"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(encryptedPaymentRequestDataList: [EncryptedPaymentRequestData]) -> [Any] {
+        return encryptedPaymentRequestDataList.map { v -> [String: Any?] in return dictionaryOf(encryptedPaymentRequestData: v) }
+    }
+
+    static func asExportPaymentsRequest(exportPaymentsRequest: [String: Any?]) throws -> ExportPaymentsRequest {
+        guard let formatTmp = exportPaymentsRequest["format"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "format", typeName: "ExportPaymentsRequest"))
+        }
+        let format = try asExportFormat(exportFormat: formatTmp)
+
+        var fromTimestamp: Int64?
+        if hasNonNilKey(data: exportPaymentsRequest, key: "fromTimestamp") {
+            guard let fromTimestampTmp = exportPaymentsRequest["fromTimestamp"] as? Int64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "fromTimestamp"))
+            }
+            fromTimestamp = fromTimestampTmp
+        }
+        var toTimestamp: Int64?
+        if hasNonNilKey(data: exportPaymentsRequest, key: "toTimestamp") {
+            guard let toTimestampTmp = exportPaymentsRequest["toTimestamp"] as? Int64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "toTimestamp"))
+            }
+            toTimestamp = toTimestampTmp
+        }
+        var includeFiat: String?
+        if hasNonNilKey(data: exportPaymentsRequest, key: "includeFiat") {
+            guard let includeFiatTmp = exportPaymentsRequest["includeFiat"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "includeFiat"))
+            }
+            includeFiat = includeFiatTmp
+        }
+
+        return ExportPaymentsRequest(format: format, fromTimestamp: fromTimestamp, toTimestamp: toTimestamp, includeFiat: includeFiat)
+    }
+
+    static func dictionaryOf(exportPaymentsRequest: ExportPaymentsRequest) -> [String: Any?] {
+        return [
+            "format": valueOf(exportFormat: exportPaymentsRequest.format),
+            "fromTimestamp": exportPaymentsRequest.fromTimestamp == nil ? nil : exportPaymentsRequest.fromTimestamp,
+            "toTimestamp": exportPaymentsRequest.toTimestamp == nil ? nil : exportPaymentsRequest.toTimestamp,
+            "includeFiat": exportPaymentsRequest.includeFiat == nil ? nil : exportPaymentsRequest.includeFiat,
+        ]
+    }
+
+    static func asExportPaymentsRequestList(arr: [Any]) throws -> [ExportPaymentsRequest] {
+        var list = [ExportPaymentsRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var exportPaymentsRequest = try asExportPaymentsRequest(exportPaymentsRequest: val)
+                list.append(exportPaymentsRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ExportPaymentsRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(exportPaymentsRequestList: [ExportPaymentsRequest]) -> [Any] {
+        return exportPaymentsRequestList.map { v -> [String: Any?] in return dictionaryOf(exportPaymentsRequest: v) }
+    }
+
+    static func asExportRecoveryBundleRequest(exportRecoveryBundleRequest: [String: Any?]) throws -> ExportRecoveryBundleRequest {
+        guard let recipientPubkey = exportRecoveryBundleRequest["recipientPubkey"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "recipientPubkey", typeName: "ExportRecoveryBundleRequest"))
+        }
+        var instructions: String?
+        if hasNonNilKey(data: exportRecoveryBundleRequest, key: "instructions") {
+            guard let instructionsTmp = exportRecoveryBundleRequest["instructions"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "instructions"))
+            }
+            instructions = instructionsTmp
+        }
+        var filePath: String?
+        if hasNonNilKey(data: exportRecoveryBundleRequest, key: "filePath") {
+            guard let filePathTmp = exportRecoveryBundleRequest["filePath"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "filePath"))
+            }
+            filePath = filePathTmp
+        }
+
+        return ExportRecoveryBundleRequest(recipientPubkey: recipientPubkey, instructions: instructions, filePath: filePath)
+    }
+
+    static func dictionaryOf(exportRecoveryBundleRequest: ExportRecoveryBundleRequest) -> [String: Any?] {
+        return [
+            "recipientPubkey": exportRecoveryBundleRequest.recipientPubkey,
+            "instructions": exportRecoveryBundleRequest.instructions == nil ? nil : exportRecoveryBundleRequest.instructions,
+            "filePath": exportRecoveryBundleRequest.filePath == nil ? nil : exportRecoveryBundleRequest.filePath,
+        ]
+    }
+
+    static func asExportRecoveryBundleRequestList(arr: [Any]) throws -> [ExportRecoveryBundleRequest] {
+        var list = [ExportRecoveryBundleRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var exportRecoveryBundleRequest = try asExportRecoveryBundleRequest(exportRecoveryBundleRequest: val)
+                list.append(exportRecoveryBundleRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ExportRecoveryBundleRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(exportRecoveryBundleRequestList: [ExportRecoveryBundleRequest]) -> [Any] {
+        return exportRecoveryBundleRequestList.map { v -> [String: Any?] in return dictionaryOf(exportRecoveryBundleRequest: v) }
+    }
+
+    static func asExportRecoveryBundleResponse(exportRecoveryBundleResponse: [String: Any?]) throws -> ExportRecoveryBundleResponse {
+        guard let bundle = exportRecoveryBundleResponse["bundle"] as? [UInt8] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bundle", typeName: "ExportRecoveryBundleResponse"))
+        }
+
+        return ExportRecoveryBundleResponse(bundle: bundle)
+    }
+
+    static func dictionaryOf(exportRecoveryBundleResponse: ExportRecoveryBundleResponse) -> [String: Any?] {
+        return [
+            "bundle": exportRecoveryBundleResponse.bundle,
+        ]
+    }
+
+    static func asExportRecoveryBundleResponseList(arr: [Any]) throws -> [ExportRecoveryBundleResponse] {
+        var list = [ExportRecoveryBundleResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var exportRecoveryBundleResponse = try asExportRecoveryBundleResponse(exportRecoveryBundleResponse: val)
+                list.append(exportRecoveryBundleResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ExportRecoveryBundleResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(exportRecoveryBundleResponseList: [ExportRecoveryBundleResponse]) -> [Any] {
+        return exportRecoveryBundleResponseList.map { v -> [String: Any?] in return dictionaryOf(exportRecoveryBundleResponse: v) }
+    }
+
+    static func asExportSwapRefundKeysRequest(exportSwapRefundKeysRequest: [String: Any?]) throws -> ExportSwapRefundKeysRequest {
+        var includeGapKeys: Bool?
+        if hasNonNilKey(data: exportSwapRefundKeysRequest, key: "includeGapKeys") {
+            guard let includeGapKeysTmp = exportSwapRefundKeysRequest["includeGapKeys"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "includeGapKeys"))
+            }
+            includeGapKeys = includeGapKeysTmp
+        }
+
+        return ExportSwapRefundKeysRequest(includeGapKeys: includeGapKeys)
+    }
+
+    static func dictionaryOf(exportSwapRefundKeysRequest: ExportSwapRefundKeysRequest) -> [String: Any?] {
+        return [
+            "includeGapKeys": exportSwapRefundKeysRequest.includeGapKeys == nil ? nil : exportSwapRefundKeysRequest.includeGapKeys,
+        ]
+    }
+
+    static func asExportSwapRefundKeysRequestList(arr: [Any]) throws -> [ExportSwapRefundKeysRequest] {
+        var list = [ExportSwapRefundKeysRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var exportSwapRefundKeysRequest = try asExportSwapRefundKeysRequest(exportSwapRefundKeysRequest: val)
+                list.append(exportSwapRefundKeysRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ExportSwapRefundKeysRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(exportSwapRefundKeysRequestList: [ExportSwapRefundKeysRequest]) -> [Any] {
+        return exportSwapRefundKeysRequestList.map { v -> [String: Any?] in return dictionaryOf(exportSwapRefundKeysRequest: v) }
+    }
+
+    static func asFetchBuyBitcoinQuoteRequest(fetchBuyBitcoinQuoteRequest: [String: Any?]) throws -> FetchBuyBitcoinQuoteRequest {
+        guard let providerTmp = fetchBuyBitcoinQuoteRequest["provider"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "provider", typeName: "FetchBuyBitcoinQuoteRequest"))
+        }
+        let provider = try asBuyBitcoinProvider(buyBitcoinProvider: providerTmp)
+
+        guard let fiatAmountTmp = fetchBuyBitcoinQuoteRequest["fiatAmount"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fiatAmount", typeName: "FetchBuyBitcoinQuoteRequest"))
+        }
+        let fiatAmount = try asFiatAmount(fiatAmount: fiatAmountTmp)
+
+        return FetchBuyBitcoinQuoteRequest(provider: provider, fiatAmount: fiatAmount)
+    }
+
+    static func dictionaryOf(fetchBuyBitcoinQuoteRequest: FetchBuyBitcoinQuoteRequest) -> [String: Any?] {
+        return [
+            "provider": valueOf(buyBitcoinProvider: fetchBuyBitcoinQuoteRequest.provider),
+            "fiatAmount": dictionaryOf(fiatAmount: fetchBuyBitcoinQuoteRequest.fiatAmount),
+        ]
+    }
+
+    static func asFetchBuyBitcoinQuoteRequestList(arr: [Any]) throws -> [FetchBuyBitcoinQuoteRequest] {
+        var list = [FetchBuyBitcoinQuoteRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var fetchBuyBitcoinQuoteRequest = try asFetchBuyBitcoinQuoteRequest(fetchBuyBitcoinQuoteRequest: val)
+                list.append(fetchBuyBitcoinQuoteRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "FetchBuyBitcoinQuoteRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(fetchBuyBitcoinQuoteRequestList: [FetchBuyBitcoinQuoteRequest]) -> [Any] {
+        return fetchBuyBitcoinQuoteRequestList.map { v -> [String: Any?] in return dictionaryOf(fetchBuyBitcoinQuoteRequest: v) }
+    }
+
+    static func asFiatAmount(fiatAmount: [String: Any?]) throws -> FiatAmount {
+        guard let currency = fiatAmount["currency"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "currency", typeName: "FiatAmount"))
+        }
+        guard let amount = fiatAmount["amount"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amount", typeName: "FiatAmount"))
+        }
+
+        return FiatAmount(currency: currency, amount: amount)
+    }
+
+    static func dictionaryOf(fiatAmount: FiatAmount) -> [String: Any?] {
+        return [
+            "currency": fiatAmount.currency,
+            "amount": fiatAmount.amount,
+        ]
+    }
+
+    static func asFiatAmountList(arr: [Any]) throws -> [FiatAmount] {
+        var list = [FiatAmount]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var fiatAmount = try asFiatAmount(fiatAmount: val)
+                list.append(fiatAmount)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "FiatAmount"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(fiatAmountList: [FiatAmount]) -> [Any] {
+        return fiatAmountList.map { v -> [String: Any?] in return dictionaryOf(fiatAmount: v) }
+    }
+
+    static func asFiatCurrency(fiatCurrency: [String: Any?]) throws -> FiatCurrency {
+        guard let id = fiatCurrency["id"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "FiatCurrency"))
+        }
+        guard let infoTmp = fiatCurrency["info"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "info", typeName: "FiatCurrency"))
+        }
+        let info = try asCurrencyInfo(currencyInfo: infoTmp)
+
+        return FiatCurrency(id: id, info: info)
     }
 
     static func dictionaryOf(fiatCurrency: FiatCurrency) -> [String: Any?] {
@@ -642,6 +2139,104 @@ enum BreezSDKMapper {
         return fiatCurrencyList.map { v -> [String: Any?] in return dictionaryOf(fiatCurrency: v) }
     }
 
+    static func asFiatRateAlert(fiatRateAlert: [String: Any?]) throws -> FiatRateAlert {
+        guard let id = fiatRateAlert["id"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "FiatRateAlert"))
+        }
+        guard let currency = fiatRateAlert["currency"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "currency", typeName: "FiatRateAlert"))
+        }
+        guard let directionTmp = fiatRateAlert["direction"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "direction", typeName: "FiatRateAlert"))
+        }
+        let direction = try asFiatRateAlertDirection(fiatRateAlertDirection: directionTmp)
+
+        guard let threshold = fiatRateAlert["threshold"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "threshold", typeName: "FiatRateAlert"))
+        }
+        guard let createdAt = fiatRateAlert["createdAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "createdAt", typeName: "FiatRateAlert"))
+        }
+        guard let triggered = fiatRateAlert["triggered"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "triggered", typeName: "FiatRateAlert"))
+        }
+        var lastTriggeredAt: Int64?
+        if hasNonNilKey(data: fiatRateAlert, key: "lastTriggeredAt") {
+            guard let lastTriggeredAtTmp = fiatRateAlert["lastTriggeredAt"] as? Int64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastTriggeredAt"))
+            }
+            lastTriggeredAt = lastTriggeredAtTmp
+        }
+
+        return FiatRateAlert(id: id, currency: currency, direction: direction, threshold: threshold, createdAt: createdAt, triggered: triggered, lastTriggeredAt: lastTriggeredAt)
+    }
+
+    static func dictionaryOf(fiatRateAlert: FiatRateAlert) -> [String: Any?] {
+        return [
+            "id": fiatRateAlert.id,
+            "currency": fiatRateAlert.currency,
+            "direction": valueOf(fiatRateAlertDirection: fiatRateAlert.direction),
+            "threshold": fiatRateAlert.threshold,
+            "createdAt": fiatRateAlert.createdAt,
+            "triggered": fiatRateAlert.triggered,
+            "lastTriggeredAt": fiatRateAlert.lastTriggeredAt == nil ? nil : fiatRateAlert.lastTriggeredAt,
+        ]
+    }
+
+    static func asFiatRateAlertList(arr: [Any]) throws -> [FiatRateAlert] {
+        var list = [FiatRateAlert]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var fiatRateAlert = try asFiatRateAlert(fiatRateAlert: val)
+                list.append(fiatRateAlert)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "FiatRateAlert"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(fiatRateAlertList: [FiatRateAlert]) -> [Any] {
+        return fiatRateAlertList.map { v -> [String: Any?] in return dictionaryOf(fiatRateAlert: v) }
+    }
+
+    static func asFiatRateAlertTriggeredDetails(fiatRateAlertTriggeredDetails: [String: Any?]) throws -> FiatRateAlertTriggeredDetails {
+        guard let alertTmp = fiatRateAlertTriggeredDetails["alert"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "alert", typeName: "FiatRateAlertTriggeredDetails"))
+        }
+        let alert = try asFiatRateAlert(fiatRateAlert: alertTmp)
+
+        guard let rate = fiatRateAlertTriggeredDetails["rate"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "rate", typeName: "FiatRateAlertTriggeredDetails"))
+        }
+
+        return FiatRateAlertTriggeredDetails(alert: alert, rate: rate)
+    }
+
+    static func dictionaryOf(fiatRateAlertTriggeredDetails: FiatRateAlertTriggeredDetails) -> [String: Any?] {
+        return [
+            "alert": dictionaryOf(fiatRateAlert: fiatRateAlertTriggeredDetails.alert),
+            "rate": fiatRateAlertTriggeredDetails.rate,
+        ]
+    }
+
+    static func asFiatRateAlertTriggeredDetailsList(arr: [Any]) throws -> [FiatRateAlertTriggeredDetails] {
+        var list = [FiatRateAlertTriggeredDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var fiatRateAlertTriggeredDetails = try asFiatRateAlertTriggeredDetails(fiatRateAlertTriggeredDetails: val)
+                list.append(fiatRateAlertTriggeredDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "FiatRateAlertTriggeredDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(fiatRateAlertTriggeredDetailsList: [FiatRateAlertTriggeredDetails]) -> [Any] {
+        return fiatRateAlertTriggeredDetailsList.map { v -> [String: Any?] in return dictionaryOf(fiatRateAlertTriggeredDetails: v) }
+    }
+
     static func asGreenlightCredentials(greenlightCredentials: [String: Any?]) throws -> GreenlightCredentials {
         guard let developerKey = greenlightCredentials["developerKey"] as? [UInt8] else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "developerKey", typeName: "GreenlightCredentials"))
@@ -708,45 +2303,248 @@ enum BreezSDKMapper {
         return greenlightDeviceCredentialsList.map { v -> [String: Any?] in return dictionaryOf(greenlightDeviceCredentials: v) }
     }
 
-    static func asGreenlightNodeConfig(greenlightNodeConfig: [String: Any?]) throws -> GreenlightNodeConfig {
-        var partnerCredentials: GreenlightCredentials?
-        if let partnerCredentialsTmp = greenlightNodeConfig["partnerCredentials"] as? [String: Any?] {
-            partnerCredentials = try asGreenlightCredentials(greenlightCredentials: partnerCredentialsTmp)
+    static func asGreenlightNodeConfig(greenlightNodeConfig: [String: Any?]) throws -> GreenlightNodeConfig {
+        var partnerCredentials: GreenlightCredentials?
+        if let partnerCredentialsTmp = greenlightNodeConfig["partnerCredentials"] as? [String: Any?] {
+            partnerCredentials = try asGreenlightCredentials(greenlightCredentials: partnerCredentialsTmp)
+        }
+
+        var inviteCode: String?
+        if hasNonNilKey(data: greenlightNodeConfig, key: "inviteCode") {
+            guard let inviteCodeTmp = greenlightNodeConfig["inviteCode"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "inviteCode"))
+            }
+            inviteCode = inviteCodeTmp
+        }
+
+        return GreenlightNodeConfig(partnerCredentials: partnerCredentials, inviteCode: inviteCode)
+    }
+
+    static func dictionaryOf(greenlightNodeConfig: GreenlightNodeConfig) -> [String: Any?] {
+        return [
+            "partnerCredentials": greenlightNodeConfig.partnerCredentials == nil ? nil : dictionaryOf(greenlightCredentials: greenlightNodeConfig.partnerCredentials!),
+            "inviteCode": greenlightNodeConfig.inviteCode == nil ? nil : greenlightNodeConfig.inviteCode,
+        ]
+    }
+
+    static func asGreenlightNodeConfigList(arr: [Any]) throws -> [GreenlightNodeConfig] {
+        var list = [GreenlightNodeConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var greenlightNodeConfig = try asGreenlightNodeConfig(greenlightNodeConfig: val)
+                list.append(greenlightNodeConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "GreenlightNodeConfig"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(greenlightNodeConfigList: [GreenlightNodeConfig]) -> [Any] {
+        return greenlightNodeConfigList.map { v -> [String: Any?] in return dictionaryOf(greenlightNodeConfig: v) }
+    }
+
+    static func asHistoricalRate(historicalRate: [String: Any?]) throws -> HistoricalRate {
+        guard let coin = historicalRate["coin"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "coin", typeName: "HistoricalRate"))
+        }
+        guard let value = historicalRate["value"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "value", typeName: "HistoricalRate"))
+        }
+        guard let timestamp = historicalRate["timestamp"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "timestamp", typeName: "HistoricalRate"))
+        }
+
+        return HistoricalRate(coin: coin, value: value, timestamp: timestamp)
+    }
+
+    static func dictionaryOf(historicalRate: HistoricalRate) -> [String: Any?] {
+        return [
+            "coin": historicalRate.coin,
+            "value": historicalRate.value,
+            "timestamp": historicalRate.timestamp,
+        ]
+    }
+
+    static func asHistoricalRateList(arr: [Any]) throws -> [HistoricalRate] {
+        var list = [HistoricalRate]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var historicalRate = try asHistoricalRate(historicalRate: val)
+                list.append(historicalRate)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "HistoricalRate"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(historicalRateList: [HistoricalRate]) -> [Any] {
+        return historicalRateList.map { v -> [String: Any?] in return dictionaryOf(historicalRate: v) }
+    }
+
+    static func asHtlcExposureLimits(htlcExposureLimits: [String: Any?]) throws -> HtlcExposureLimits {
+        var maxInflightMsat: UInt64?
+        if hasNonNilKey(data: htlcExposureLimits, key: "maxInflightMsat") {
+            guard let maxInflightMsatTmp = htlcExposureLimits["maxInflightMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "maxInflightMsat"))
+            }
+            maxInflightMsat = maxInflightMsatTmp
+        }
+        var maxHtlcsPerPayment: UInt32?
+        if hasNonNilKey(data: htlcExposureLimits, key: "maxHtlcsPerPayment") {
+            guard let maxHtlcsPerPaymentTmp = htlcExposureLimits["maxHtlcsPerPayment"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "maxHtlcsPerPayment"))
+            }
+            maxHtlcsPerPayment = maxHtlcsPerPaymentTmp
+        }
+
+        return HtlcExposureLimits(maxInflightMsat: maxInflightMsat, maxHtlcsPerPayment: maxHtlcsPerPayment)
+    }
+
+    static func dictionaryOf(htlcExposureLimits: HtlcExposureLimits) -> [String: Any?] {
+        return [
+            "maxInflightMsat": htlcExposureLimits.maxInflightMsat == nil ? nil : htlcExposureLimits.maxInflightMsat,
+            "maxHtlcsPerPayment": htlcExposureLimits.maxHtlcsPerPayment == nil ? nil : htlcExposureLimits.maxHtlcsPerPayment,
+        ]
+    }
+
+    static func asHtlcExposureLimitsList(arr: [Any]) throws -> [HtlcExposureLimits] {
+        var list = [HtlcExposureLimits]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var htlcExposureLimits = try asHtlcExposureLimits(htlcExposureLimits: val)
+                list.append(htlcExposureLimits)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "HtlcExposureLimits"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(htlcExposureLimitsList: [HtlcExposureLimits]) -> [Any] {
+        return htlcExposureLimitsList.map { v -> [String: Any?] in return dictionaryOf(htlcExposureLimits: v) }
+    }
+
+    static func asImportPaymentsRequest(importPaymentsRequest: [String: Any?]) throws -> ImportPaymentsRequest {
+        guard let formatTmp = importPaymentsRequest["format"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "format", typeName: "ImportPaymentsRequest"))
+        }
+        let format = try asExportFormat(exportFormat: formatTmp)
+
+        guard let data = importPaymentsRequest["data"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "ImportPaymentsRequest"))
+        }
+
+        return ImportPaymentsRequest(format: format, data: data)
+    }
+
+    static func dictionaryOf(importPaymentsRequest: ImportPaymentsRequest) -> [String: Any?] {
+        return [
+            "format": valueOf(exportFormat: importPaymentsRequest.format),
+            "data": importPaymentsRequest.data,
+        ]
+    }
+
+    static func asImportPaymentsRequestList(arr: [Any]) throws -> [ImportPaymentsRequest] {
+        var list = [ImportPaymentsRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var importPaymentsRequest = try asImportPaymentsRequest(importPaymentsRequest: val)
+                list.append(importPaymentsRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ImportPaymentsRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(importPaymentsRequestList: [ImportPaymentsRequest]) -> [Any] {
+        return importPaymentsRequestList.map { v -> [String: Any?] in return dictionaryOf(importPaymentsRequest: v) }
+    }
+
+    static func asImportPaymentsResponse(importPaymentsResponse: [String: Any?]) throws -> ImportPaymentsResponse {
+        guard let importedCount = importPaymentsResponse["importedCount"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "importedCount", typeName: "ImportPaymentsResponse"))
+        }
+        guard let skippedCount = importPaymentsResponse["skippedCount"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "skippedCount", typeName: "ImportPaymentsResponse"))
+        }
+
+        return ImportPaymentsResponse(importedCount: importedCount, skippedCount: skippedCount)
+    }
+
+    static func dictionaryOf(importPaymentsResponse: ImportPaymentsResponse) -> [String: Any?] {
+        return [
+            "importedCount": importPaymentsResponse.importedCount,
+            "skippedCount": importPaymentsResponse.skippedCount,
+        ]
+    }
+
+    static func asImportPaymentsResponseList(arr: [Any]) throws -> [ImportPaymentsResponse] {
+        var list = [ImportPaymentsResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var importPaymentsResponse = try asImportPaymentsResponse(importPaymentsResponse: val)
+                list.append(importPaymentsResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ImportPaymentsResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(importPaymentsResponseList: [ImportPaymentsResponse]) -> [Any] {
+        return importPaymentsResponseList.map { v -> [String: Any?] in return dictionaryOf(importPaymentsResponse: v) }
+    }
+
+    static func asInvoiceFeatures(invoiceFeatures: [String: Any?]) throws -> InvoiceFeatures {
+        guard let basicMppTmp = invoiceFeatures["basicMpp"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "basicMpp", typeName: "InvoiceFeatures"))
+        }
+        let basicMpp = try asFeatureSupport(featureSupport: basicMppTmp)
+
+        guard let paymentSecretTmp = invoiceFeatures["paymentSecret"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentSecret", typeName: "InvoiceFeatures"))
+        }
+        let paymentSecret = try asFeatureSupport(featureSupport: paymentSecretTmp)
+
+        guard let trampolineTmp = invoiceFeatures["trampoline"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "trampoline", typeName: "InvoiceFeatures"))
         }
+        let trampoline = try asFeatureSupport(featureSupport: trampolineTmp)
 
-        var inviteCode: String?
-        if hasNonNilKey(data: greenlightNodeConfig, key: "inviteCode") {
-            guard let inviteCodeTmp = greenlightNodeConfig["inviteCode"] as? String else {
-                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "inviteCode"))
-            }
-            inviteCode = inviteCodeTmp
+        guard let requiresUnknownFeatures = invoiceFeatures["requiresUnknownFeatures"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "requiresUnknownFeatures", typeName: "InvoiceFeatures"))
         }
 
-        return GreenlightNodeConfig(partnerCredentials: partnerCredentials, inviteCode: inviteCode)
+        return InvoiceFeatures(basicMpp: basicMpp, paymentSecret: paymentSecret, trampoline: trampoline, requiresUnknownFeatures: requiresUnknownFeatures)
     }
 
-    static func dictionaryOf(greenlightNodeConfig: GreenlightNodeConfig) -> [String: Any?] {
+    static func dictionaryOf(invoiceFeatures: InvoiceFeatures) -> [String: Any?] {
         return [
-            "partnerCredentials": greenlightNodeConfig.partnerCredentials == nil ? nil : dictionaryOf(greenlightCredentials: greenlightNodeConfig.partnerCredentials!),
-            "inviteCode": greenlightNodeConfig.inviteCode == nil ? nil : greenlightNodeConfig.inviteCode,
+            "basicMpp": valueOf(featureSupport: invoiceFeatures.basicMpp),
+            "paymentSecret": valueOf(featureSupport: invoiceFeatures.paymentSecret),
+            "trampoline": valueOf(featureSupport: invoiceFeatures.trampoline),
+            "requiresUnknownFeatures": invoiceFeatures.requiresUnknownFeatures,
         ]
     }
 
-    static func asGreenlightNodeConfigList(arr: [Any]) throws -> [GreenlightNodeConfig] {
-        var list = [GreenlightNodeConfig]()
+    static func asInvoiceFeaturesList(arr: [Any]) throws -> [InvoiceFeatures] {
+        var list = [InvoiceFeatures]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var greenlightNodeConfig = try asGreenlightNodeConfig(greenlightNodeConfig: val)
-                list.append(greenlightNodeConfig)
+                var invoiceFeatures = try asInvoiceFeatures(invoiceFeatures: val)
+                list.append(invoiceFeatures)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "GreenlightNodeConfig"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "InvoiceFeatures"))
             }
         }
         return list
     }
 
-    static func arrayOf(greenlightNodeConfigList: [GreenlightNodeConfig]) -> [Any] {
-        return greenlightNodeConfigList.map { v -> [String: Any?] in return dictionaryOf(greenlightNodeConfig: v) }
+    static func arrayOf(invoiceFeaturesList: [InvoiceFeatures]) -> [Any] {
+        return invoiceFeaturesList.map { v -> [String: Any?] in return dictionaryOf(invoiceFeatures: v) }
     }
 
     static func asInvoicePaidDetails(invoicePaidDetails: [String: Any?]) throws -> InvoicePaidDetails {
@@ -789,6 +2587,121 @@ enum BreezSDKMapper {
         return invoicePaidDetailsList.map { v -> [String: Any?] in return dictionaryOf(invoicePaidDetails: v) }
     }
 
+    static func asInvoicePayability(invoicePayability: [String: Any?]) throws -> InvoicePayability {
+        guard let invoiceTmp = invoicePayability["invoice"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "invoice", typeName: "InvoicePayability"))
+        }
+        let invoice = try asLnInvoice(lnInvoice: invoiceTmp)
+
+        var amountMsat: UInt64?
+        if hasNonNilKey(data: invoicePayability, key: "amountMsat") {
+            guard let amountMsatTmp = invoicePayability["amountMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+            }
+            amountMsat = amountMsatTmp
+        }
+        guard let isPayable = invoicePayability["isPayable"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "isPayable", typeName: "InvoicePayability"))
+        }
+        var error: String?
+        if hasNonNilKey(data: invoicePayability, key: "error") {
+            guard let errorTmp = invoicePayability["error"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "error"))
+            }
+            error = errorTmp
+        }
+        guard let maxPayableMsat = invoicePayability["maxPayableMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxPayableMsat", typeName: "InvoicePayability"))
+        }
+        var payeeInboundLiquidityMsat: UInt64?
+        if hasNonNilKey(data: invoicePayability, key: "payeeInboundLiquidityMsat") {
+            guard let payeeInboundLiquidityMsatTmp = invoicePayability["payeeInboundLiquidityMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "payeeInboundLiquidityMsat"))
+            }
+            payeeInboundLiquidityMsat = payeeInboundLiquidityMsatTmp
+        }
+        var lspRouteHint: RouteHint?
+        if let lspRouteHintTmp = invoicePayability["lspRouteHint"] as? [String: Any?] {
+            lspRouteHint = try asRouteHint(routeHint: lspRouteHintTmp)
+        }
+
+        return InvoicePayability(invoice: invoice, amountMsat: amountMsat, isPayable: isPayable, error: error, maxPayableMsat: maxPayableMsat, payeeInboundLiquidityMsat: payeeInboundLiquidityMsat, lspRouteHint: lspRouteHint)
+    }
+
+    static func dictionaryOf(invoicePayability: InvoicePayability) -> [String: Any?] {
+        return [
+            "invoice": dictionaryOf(lnInvoice: invoicePayability.invoice),
+            "amountMsat": invoicePayability.amountMsat == nil ? nil : invoicePayability.amountMsat,
+            "isPayable": invoicePayability.isPayable,
+            "error": invoicePayability.error == nil ? nil : invoicePayability.error,
+            "maxPayableMsat": invoicePayability.maxPayableMsat,
+            "payeeInboundLiquidityMsat": invoicePayability.payeeInboundLiquidityMsat == nil ? nil : invoicePayability.payeeInboundLiquidityMsat,
+            "lspRouteHint": invoicePayability.lspRouteHint == nil ? nil : dictionaryOf(routeHint: invoicePayability.lspRouteHint!),
+        ]
+    }
+
+    static func asInvoicePayabilityList(arr: [Any]) throws -> [InvoicePayability] {
+        var list = [InvoicePayability]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var invoicePayability = try asInvoicePayability(invoicePayability: val)
+                list.append(invoicePayability)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "InvoicePayability"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(invoicePayabilityList: [InvoicePayability]) -> [Any] {
+        return invoicePayabilityList.map { v -> [String: Any?] in return dictionaryOf(invoicePayability: v) }
+    }
+
+    static func asInvoiceVerificationResult(invoiceVerificationResult: [String: Any?]) throws -> InvoiceVerificationResult {
+        guard let bolt11 = invoiceVerificationResult["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "InvoiceVerificationResult"))
+        }
+        var invoice: LnInvoice?
+        if let invoiceTmp = invoiceVerificationResult["invoice"] as? [String: Any?] {
+            invoice = try asLnInvoice(lnInvoice: invoiceTmp)
+        }
+
+        var error: String?
+        if hasNonNilKey(data: invoiceVerificationResult, key: "error") {
+            guard let errorTmp = invoiceVerificationResult["error"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "error"))
+            }
+            error = errorTmp
+        }
+
+        return InvoiceVerificationResult(bolt11: bolt11, invoice: invoice, error: error)
+    }
+
+    static func dictionaryOf(invoiceVerificationResult: InvoiceVerificationResult) -> [String: Any?] {
+        return [
+            "bolt11": invoiceVerificationResult.bolt11,
+            "invoice": invoiceVerificationResult.invoice == nil ? nil : dictionaryOf(lnInvoice: invoiceVerificationResult.invoice!),
+            "error": invoiceVerificationResult.error == nil ? nil : invoiceVerificationResult.error,
+        ]
+    }
+
+    static func asInvoiceVerificationResultList(arr: [Any]) throws -> [InvoiceVerificationResult] {
+        var list = [InvoiceVerificationResult]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var invoiceVerificationResult = try asInvoiceVerificationResult(invoiceVerificationResult: val)
+                list.append(invoiceVerificationResult)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "InvoiceVerificationResult"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(invoiceVerificationResultList: [InvoiceVerificationResult]) -> [Any] {
+        return invoiceVerificationResultList.map { v -> [String: Any?] in return dictionaryOf(invoiceVerificationResult: v) }
+    }
+
     static func asLnInvoice(lnInvoice: [String: Any?]) throws -> LnInvoice {
         guard let bolt11 = lnInvoice["bolt11"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "LnInvoice"))
@@ -831,6 +2744,12 @@ enum BreezSDKMapper {
         guard let expiry = lnInvoice["expiry"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expiry", typeName: "LnInvoice"))
         }
+        guard let expiresAt = lnInvoice["expiresAt"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expiresAt", typeName: "LnInvoice"))
+        }
+        guard let isExpired = lnInvoice["isExpired"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "isExpired", typeName: "LnInvoice"))
+        }
         guard let routingHintsTmp = lnInvoice["routingHints"] as? [[String: Any?]] else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "routingHints", typeName: "LnInvoice"))
         }
@@ -842,8 +2761,12 @@ enum BreezSDKMapper {
         guard let minFinalCltvExpiryDelta = lnInvoice["minFinalCltvExpiryDelta"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minFinalCltvExpiryDelta", typeName: "LnInvoice"))
         }
+        guard let featuresTmp = lnInvoice["features"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "features", typeName: "LnInvoice"))
+        }
+        let features = try asInvoiceFeatures(invoiceFeatures: featuresTmp)
 
-        return LnInvoice(bolt11: bolt11, network: network, payeePubkey: payeePubkey, paymentHash: paymentHash, description: description, descriptionHash: descriptionHash, amountMsat: amountMsat, timestamp: timestamp, expiry: expiry, routingHints: routingHints, paymentSecret: paymentSecret, minFinalCltvExpiryDelta: minFinalCltvExpiryDelta)
+        return LnInvoice(bolt11: bolt11, network: network, payeePubkey: payeePubkey, paymentHash: paymentHash, description: description, descriptionHash: descriptionHash, amountMsat: amountMsat, timestamp: timestamp, expiry: expiry, expiresAt: expiresAt, isExpired: isExpired, routingHints: routingHints, paymentSecret: paymentSecret, minFinalCltvExpiryDelta: minFinalCltvExpiryDelta, features: features)
     }
 
     static func dictionaryOf(lnInvoice: LnInvoice) -> [String: Any?] {
@@ -857,9 +2780,12 @@ enum BreezSDKMapper {
             "amountMsat": lnInvoice.amountMsat == nil ? nil : lnInvoice.amountMsat,
             "timestamp": lnInvoice.timestamp,
             "expiry": lnInvoice.expiry,
+            "expiresAt": lnInvoice.expiresAt,
+            "isExpired": lnInvoice.isExpired,
             "routingHints": arrayOf(routeHintList: lnInvoice.routingHints),
             "paymentSecret": lnInvoice.paymentSecret,
             "minFinalCltvExpiryDelta": lnInvoice.minFinalCltvExpiryDelta,
+            "features": dictionaryOf(invoiceFeatures: lnInvoice.features),
         ]
     }
 
@@ -880,6 +2806,131 @@ enum BreezSDKMapper {
         return lnInvoiceList.map { v -> [String: Any?] in return dictionaryOf(lnInvoice: v) }
     }
 
+    static func asLnOffer(lnOffer: [String: Any?]) throws -> LnOffer {
+        guard let offer = lnOffer["offer"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "offer", typeName: "LnOffer"))
+        }
+        guard let chains = lnOffer["chains"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "chains", typeName: "LnOffer"))
+        }
+        var minAmount: Amount?
+        if let minAmountTmp = lnOffer["minAmount"] as? [String: Any?] {
+            minAmount = try asAmount(amount: minAmountTmp)
+        }
+
+        var description: String?
+        if hasNonNilKey(data: lnOffer, key: "description") {
+            guard let descriptionTmp = lnOffer["description"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+            }
+            description = descriptionTmp
+        }
+        var absoluteExpiry: UInt64?
+        if hasNonNilKey(data: lnOffer, key: "absoluteExpiry") {
+            guard let absoluteExpiryTmp = lnOffer["absoluteExpiry"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "absoluteExpiry"))
+            }
+            absoluteExpiry = absoluteExpiryTmp
+        }
+        var issuer: String?
+        if hasNonNilKey(data: lnOffer, key: "issuer") {
+            guard let issuerTmp = lnOffer["issuer"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "issuer"))
+            }
+            issuer = issuerTmp
+        }
+        var signingPubkey: String?
+        if hasNonNilKey(data: lnOffer, key: "signingPubkey") {
+            guard let signingPubkeyTmp = lnOffer["signingPubkey"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "signingPubkey"))
+            }
+            signingPubkey = signingPubkeyTmp
+        }
+        guard let pathsTmp = lnOffer["paths"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paths", typeName: "LnOffer"))
+        }
+        let paths = try asLnOfferBlindedPathList(arr: pathsTmp)
+
+        return LnOffer(offer: offer, chains: chains, minAmount: minAmount, description: description, absoluteExpiry: absoluteExpiry, issuer: issuer, signingPubkey: signingPubkey, paths: paths)
+    }
+
+    static func dictionaryOf(lnOffer: LnOffer) -> [String: Any?] {
+        return [
+            "offer": lnOffer.offer,
+            "chains": lnOffer.chains,
+            "minAmount": lnOffer.minAmount == nil ? nil : dictionaryOf(amount: lnOffer.minAmount!),
+            "description": lnOffer.description == nil ? nil : lnOffer.description,
+            "absoluteExpiry": lnOffer.absoluteExpiry == nil ? nil : lnOffer.absoluteExpiry,
+            "issuer": lnOffer.issuer == nil ? nil : lnOffer.issuer,
+            "signingPubkey": lnOffer.signingPubkey == nil ? nil : lnOffer.signingPubkey,
+            "paths": arrayOf(lnOfferBlindedPathList: lnOffer.paths),
+        ]
+    }
+
+    static func asLnOfferList(arr: [Any]) throws -> [LnOffer] {
+        var list = [LnOffer]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnOffer = try asLnOffer(lnOffer: val)
+                list.append(lnOffer)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnOffer"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnOfferList: [LnOffer]) -> [Any] {
+        return lnOfferList.map { v -> [String: Any?] in return dictionaryOf(lnOffer: v) }
+    }
+
+    static func asListPaymentGroupsRequest(listPaymentGroupsRequest: [String: Any?]) throws -> ListPaymentGroupsRequest {
+        guard let groupByTmp = listPaymentGroupsRequest["groupBy"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "groupBy", typeName: "ListPaymentGroupsRequest"))
+        }
+        let groupBy = try asPaymentGroupBy(paymentGroupBy: groupByTmp)
+
+        guard let paymentsTmp = listPaymentGroupsRequest["payments"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payments", typeName: "ListPaymentGroupsRequest"))
+        }
+        let payments = try asListPaymentsRequest(listPaymentsRequest: paymentsTmp)
+
+        var limit: UInt32?
+        if hasNonNilKey(data: listPaymentGroupsRequest, key: "limit") {
+            guard let limitTmp = listPaymentGroupsRequest["limit"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "limit"))
+            }
+            limit = limitTmp
+        }
+
+        return ListPaymentGroupsRequest(groupBy: groupBy, payments: payments, limit: limit)
+    }
+
+    static func dictionaryOf(listPaymentGroupsRequest: ListPaymentGroupsRequest) -> [String: Any?] {
+        return [
+            "groupBy": valueOf(paymentGroupBy: listPaymentGroupsRequest.groupBy),
+            "payments": dictionaryOf(listPaymentsRequest: listPaymentGroupsRequest.payments),
+            "limit": listPaymentGroupsRequest.limit == nil ? nil : listPaymentGroupsRequest.limit,
+        ]
+    }
+
+    static func asListPaymentGroupsRequestList(arr: [Any]) throws -> [ListPaymentGroupsRequest] {
+        var list = [ListPaymentGroupsRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var listPaymentGroupsRequest = try asListPaymentGroupsRequest(listPaymentGroupsRequest: val)
+                list.append(listPaymentGroupsRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ListPaymentGroupsRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(listPaymentGroupsRequestList: [ListPaymentGroupsRequest]) -> [Any] {
+        return listPaymentGroupsRequestList.map { v -> [String: Any?] in return dictionaryOf(listPaymentGroupsRequest: v) }
+    }
+
     static func asListPaymentsRequest(listPaymentsRequest: [String: Any?]) throws -> ListPaymentsRequest {
         var filters: [PaymentTypeFilter]?
         if let filtersTmp = listPaymentsRequest["filters"] as? [String] {
@@ -926,8 +2977,19 @@ enum BreezSDKMapper {
             }
             limit = limitTmp
         }
+        var includeTlvHex: Bool?
+        if hasNonNilKey(data: listPaymentsRequest, key: "includeTlvHex") {
+            guard let includeTlvHexTmp = listPaymentsRequest["includeTlvHex"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "includeTlvHex"))
+            }
+            includeTlvHex = includeTlvHexTmp
+        }
+        var sortOrder: SortOrder?
+        if let sortOrderTmp = listPaymentsRequest["sortOrder"] as? String {
+            sortOrder = try asSortOrder(sortOrder: sortOrderTmp)
+        }
 
-        return ListPaymentsRequest(filters: filters, metadataFilters: metadataFilters, fromTimestamp: fromTimestamp, toTimestamp: toTimestamp, includeFailures: includeFailures, offset: offset, limit: limit)
+        return ListPaymentsRequest(filters: filters, metadataFilters: metadataFilters, fromTimestamp: fromTimestamp, toTimestamp: toTimestamp, includeFailures: includeFailures, offset: offset, limit: limit, includeTlvHex: includeTlvHex, sortOrder: sortOrder)
     }
 
     static func dictionaryOf(listPaymentsRequest: ListPaymentsRequest) -> [String: Any?] {
@@ -939,6 +3001,8 @@ enum BreezSDKMapper {
             "includeFailures": listPaymentsRequest.includeFailures == nil ? nil : listPaymentsRequest.includeFailures,
             "offset": listPaymentsRequest.offset == nil ? nil : listPaymentsRequest.offset,
             "limit": listPaymentsRequest.limit == nil ? nil : listPaymentsRequest.limit,
+            "includeTlvHex": listPaymentsRequest.includeTlvHex == nil ? nil : listPaymentsRequest.includeTlvHex,
+            "sortOrder": listPaymentsRequest.sortOrder == nil ? nil : valueOf(sortOrder: listPaymentsRequest.sortOrder!),
         ]
     }
 
@@ -1024,6 +3088,37 @@ enum BreezSDKMapper {
         return listSwapsRequestList.map { v -> [String: Any?] in return dictionaryOf(listSwapsRequest: v) }
     }
 
+    static func asLnOfferBlindedPath(lnOfferBlindedPath: [String: Any?]) throws -> LnOfferBlindedPath {
+        guard let blindedHops = lnOfferBlindedPath["blindedHops"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "blindedHops", typeName: "LnOfferBlindedPath"))
+        }
+
+        return LnOfferBlindedPath(blindedHops: blindedHops)
+    }
+
+    static func dictionaryOf(lnOfferBlindedPath: LnOfferBlindedPath) -> [String: Any?] {
+        return [
+            "blindedHops": lnOfferBlindedPath.blindedHops,
+        ]
+    }
+
+    static func asLnOfferBlindedPathList(arr: [Any]) throws -> [LnOfferBlindedPath] {
+        var list = [LnOfferBlindedPath]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnOfferBlindedPath = try asLnOfferBlindedPath(lnOfferBlindedPath: val)
+                list.append(lnOfferBlindedPath)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnOfferBlindedPath"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnOfferBlindedPathList: [LnOfferBlindedPath]) -> [Any] {
+        return lnOfferBlindedPathList.map { v -> [String: Any?] in return dictionaryOf(lnOfferBlindedPath: v) }
+    }
+
     static func asLnPaymentDetails(lnPaymentDetails: [String: Any?]) throws -> LnPaymentDetails {
         guard let paymentHash = lnPaymentDetails["paymentHash"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnPaymentDetails"))
@@ -1050,6 +3145,13 @@ enum BreezSDKMapper {
             }
             openChannelBolt11 = openChannelBolt11Tmp
         }
+        var channelOpeningFeeMsat: UInt64?
+        if hasNonNilKey(data: lnPaymentDetails, key: "channelOpeningFeeMsat") {
+            guard let channelOpeningFeeMsatTmp = lnPaymentDetails["channelOpeningFeeMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "channelOpeningFeeMsat"))
+            }
+            channelOpeningFeeMsat = channelOpeningFeeMsatTmp
+        }
         var lnurlSuccessAction: SuccessActionProcessed?
         if let lnurlSuccessActionTmp = lnPaymentDetails["lnurlSuccessAction"] as? [String: Any?] {
             lnurlSuccessAction = try asSuccessActionProcessed(successActionProcessed: lnurlSuccessActionTmp)
@@ -1107,8 +3209,17 @@ enum BreezSDKMapper {
             }
             pendingExpirationBlock = pendingExpirationBlockTmp
         }
+        var tlvs: [TlvRecord]?
+        if let tlvsTmp = lnPaymentDetails["tlvs"] as? [[String: Any?]] {
+            tlvs = try asTlvRecordList(arr: tlvsTmp)
+        }
+
+        var spontaneousMetadata: SpontaneousPaymentMetadata?
+        if let spontaneousMetadataTmp = lnPaymentDetails["spontaneousMetadata"] as? [String: Any?] {
+            spontaneousMetadata = try asSpontaneousPaymentMetadata(spontaneousPaymentMetadata: spontaneousMetadataTmp)
+        }
 
-        return LnPaymentDetails(paymentHash: paymentHash, label: label, destinationPubkey: destinationPubkey, paymentPreimage: paymentPreimage, keysend: keysend, bolt11: bolt11, openChannelBolt11: openChannelBolt11, lnurlSuccessAction: lnurlSuccessAction, lnurlPayDomain: lnurlPayDomain, lnurlPayComment: lnurlPayComment, lnurlMetadata: lnurlMetadata, lnAddress: lnAddress, lnurlWithdrawEndpoint: lnurlWithdrawEndpoint, swapInfo: swapInfo, reverseSwapInfo: reverseSwapInfo, pendingExpirationBlock: pendingExpirationBlock)
+        return LnPaymentDetails(paymentHash: paymentHash, label: label, destinationPubkey: destinationPubkey, paymentPreimage: paymentPreimage, keysend: keysend, bolt11: bolt11, openChannelBolt11: openChannelBolt11, channelOpeningFeeMsat: channelOpeningFeeMsat, lnurlSuccessAction: lnurlSuccessAction, lnurlPayDomain: lnurlPayDomain, lnurlPayComment: lnurlPayComment, lnurlMetadata: lnurlMetadata, lnAddress: lnAddress, lnurlWithdrawEndpoint: lnurlWithdrawEndpoint, swapInfo: swapInfo, reverseSwapInfo: reverseSwapInfo, pendingExpirationBlock: pendingExpirationBlock, tlvs: tlvs, spontaneousMetadata: spontaneousMetadata)
     }
 
     static func dictionaryOf(lnPaymentDetails: LnPaymentDetails) -> [String: Any?] {
@@ -1120,6 +3231,7 @@ enum BreezSDKMapper {
             "keysend": lnPaymentDetails.keysend,
             "bolt11": lnPaymentDetails.bolt11,
             "openChannelBolt11": lnPaymentDetails.openChannelBolt11 == nil ? nil : lnPaymentDetails.openChannelBolt11,
+            "channelOpeningFeeMsat": lnPaymentDetails.channelOpeningFeeMsat == nil ? nil : lnPaymentDetails.channelOpeningFeeMsat,
             "lnurlSuccessAction": lnPaymentDetails.lnurlSuccessAction == nil ? nil : dictionaryOf(successActionProcessed: lnPaymentDetails.lnurlSuccessAction!),
             "lnurlPayDomain": lnPaymentDetails.lnurlPayDomain == nil ? nil : lnPaymentDetails.lnurlPayDomain,
             "lnurlPayComment": lnPaymentDetails.lnurlPayComment == nil ? nil : lnPaymentDetails.lnurlPayComment,
@@ -1129,6 +3241,8 @@ enum BreezSDKMapper {
             "swapInfo": lnPaymentDetails.swapInfo == nil ? nil : dictionaryOf(swapInfo: lnPaymentDetails.swapInfo!),
             "reverseSwapInfo": lnPaymentDetails.reverseSwapInfo == nil ? nil : dictionaryOf(reverseSwapInfo: lnPaymentDetails.reverseSwapInfo!),
             "pendingExpirationBlock": lnPaymentDetails.pendingExpirationBlock == nil ? nil : lnPaymentDetails.pendingExpirationBlock,
+            "tlvs": lnPaymentDetails.tlvs == nil ? nil : arrayOf(tlvRecordList: lnPaymentDetails.tlvs!),
+            "spontaneousMetadata": lnPaymentDetails.spontaneousMetadata == nil ? nil : dictionaryOf(spontaneousPaymentMetadata: lnPaymentDetails.spontaneousMetadata!),
         ]
     }
 
@@ -1149,6 +3263,89 @@ enum BreezSDKMapper {
         return lnPaymentDetailsList.map { v -> [String: Any?] in return dictionaryOf(lnPaymentDetails: v) }
     }
 
+    static func asLnUrlAuthDomain(lnUrlAuthDomain: [String: Any?]) throws -> LnUrlAuthDomain {
+        guard let domain = lnUrlAuthDomain["domain"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "domain", typeName: "LnUrlAuthDomain"))
+        }
+        guard let linkingKey = lnUrlAuthDomain["linkingKey"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "linkingKey", typeName: "LnUrlAuthDomain"))
+        }
+        guard let firstAuthenticatedAt = lnUrlAuthDomain["firstAuthenticatedAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "firstAuthenticatedAt", typeName: "LnUrlAuthDomain"))
+        }
+        guard let lastAuthenticatedAt = lnUrlAuthDomain["lastAuthenticatedAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lastAuthenticatedAt", typeName: "LnUrlAuthDomain"))
+        }
+        guard let authCount = lnUrlAuthDomain["authCount"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "authCount", typeName: "LnUrlAuthDomain"))
+        }
+
+        return LnUrlAuthDomain(domain: domain, linkingKey: linkingKey, firstAuthenticatedAt: firstAuthenticatedAt, lastAuthenticatedAt: lastAuthenticatedAt, authCount: authCount)
+    }
+
+    static func dictionaryOf(lnUrlAuthDomain: LnUrlAuthDomain) -> [String: Any?] {
+        return [
+            "domain": lnUrlAuthDomain.domain,
+            "linkingKey": lnUrlAuthDomain.linkingKey,
+            "firstAuthenticatedAt": lnUrlAuthDomain.firstAuthenticatedAt,
+            "lastAuthenticatedAt": lnUrlAuthDomain.lastAuthenticatedAt,
+            "authCount": lnUrlAuthDomain.authCount,
+        ]
+    }
+
+    static func asLnUrlAuthDomainList(arr: [Any]) throws -> [LnUrlAuthDomain] {
+        var list = [LnUrlAuthDomain]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnUrlAuthDomain = try asLnUrlAuthDomain(lnUrlAuthDomain: val)
+                list.append(lnUrlAuthDomain)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlAuthDomain"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnUrlAuthDomainList: [LnUrlAuthDomain]) -> [Any] {
+        return lnUrlAuthDomainList.map { v -> [String: Any?] in return dictionaryOf(lnUrlAuthDomain: v) }
+    }
+
+    static func asLnUrlAuthExport(lnUrlAuthExport: [String: Any?]) throws -> LnUrlAuthExport {
+        guard let hashingKey = lnUrlAuthExport["hashingKey"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "hashingKey", typeName: "LnUrlAuthExport"))
+        }
+        guard let domainsTmp = lnUrlAuthExport["domains"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "domains", typeName: "LnUrlAuthExport"))
+        }
+        let domains = try asLnUrlAuthDomainList(arr: domainsTmp)
+
+        return LnUrlAuthExport(hashingKey: hashingKey, domains: domains)
+    }
+
+    static func dictionaryOf(lnUrlAuthExport: LnUrlAuthExport) -> [String: Any?] {
+        return [
+            "hashingKey": lnUrlAuthExport.hashingKey,
+            "domains": arrayOf(lnUrlAuthDomainList: lnUrlAuthExport.domains),
+        ]
+    }
+
+    static func asLnUrlAuthExportList(arr: [Any]) throws -> [LnUrlAuthExport] {
+        var list = [LnUrlAuthExport]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnUrlAuthExport = try asLnUrlAuthExport(lnUrlAuthExport: val)
+                list.append(lnUrlAuthExport)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlAuthExport"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnUrlAuthExportList: [LnUrlAuthExport]) -> [Any] {
+        return lnUrlAuthExportList.map { v -> [String: Any?] in return dictionaryOf(lnUrlAuthExport: v) }
+    }
+
     static func asLnUrlAuthRequestData(lnUrlAuthRequestData: [String: Any?]) throws -> LnUrlAuthRequestData {
         guard let k1 = lnUrlAuthRequestData["k1"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "k1", typeName: "LnUrlAuthRequestData"))
@@ -1262,6 +3459,73 @@ enum BreezSDKMapper {
         return lnUrlPayErrorDataList.map { v -> [String: Any?] in return dictionaryOf(lnUrlPayErrorData: v) }
     }
 
+    static func asLnUrlPayFinishedDetails(lnUrlPayFinishedDetails: [String: Any?]) throws -> LnUrlPayFinishedDetails {
+        guard let paymentHash = lnUrlPayFinishedDetails["paymentHash"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnUrlPayFinishedDetails"))
+        }
+        guard let resultTmp = lnUrlPayFinishedDetails["result"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "result", typeName: "LnUrlPayFinishedDetails"))
+        }
+        let result = try asLnUrlPayResult(lnUrlPayResult: resultTmp)
+
+        return LnUrlPayFinishedDetails(paymentHash: paymentHash, result: result)
+    }
+
+    static func dictionaryOf(lnUrlPayFinishedDetails: LnUrlPayFinishedDetails) -> [String: Any?] {
+        return [
+            "paymentHash": lnUrlPayFinishedDetails.paymentHash,
+            "result": dictionaryOf(lnUrlPayResult: lnUrlPayFinishedDetails.result),
+        ]
+    }
+
+    static func asLnUrlPayFinishedDetailsList(arr: [Any]) throws -> [LnUrlPayFinishedDetails] {
+        var list = [LnUrlPayFinishedDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnUrlPayFinishedDetails = try asLnUrlPayFinishedDetails(lnUrlPayFinishedDetails: val)
+                list.append(lnUrlPayFinishedDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPayFinishedDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnUrlPayFinishedDetailsList: [LnUrlPayFinishedDetails]) -> [Any] {
+        return lnUrlPayFinishedDetailsList.map { v -> [String: Any?] in return dictionaryOf(lnUrlPayFinishedDetails: v) }
+    }
+
+    static func asLnUrlPayPendingData(lnUrlPayPendingData: [String: Any?]) throws -> LnUrlPayPendingData {
+        guard let paymentHash = lnUrlPayPendingData["paymentHash"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "LnUrlPayPendingData"))
+        }
+
+        return LnUrlPayPendingData(paymentHash: paymentHash)
+    }
+
+    static func dictionaryOf(lnUrlPayPendingData: LnUrlPayPendingData) -> [String: Any?] {
+        return [
+            "paymentHash": lnUrlPayPendingData.paymentHash,
+        ]
+    }
+
+    static func asLnUrlPayPendingDataList(arr: [Any]) throws -> [LnUrlPayPendingData] {
+        var list = [LnUrlPayPendingData]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnUrlPayPendingData = try asLnUrlPayPendingData(lnUrlPayPendingData: val)
+                list.append(lnUrlPayPendingData)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnUrlPayPendingData"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnUrlPayPendingDataList: [LnUrlPayPendingData]) -> [Any] {
+        return lnUrlPayPendingDataList.map { v -> [String: Any?] in return dictionaryOf(lnUrlPayPendingData: v) }
+    }
+
     static func asLnUrlPayRequest(lnUrlPayRequest: [String: Any?]) throws -> LnUrlPayRequest {
         guard let dataTmp = lnUrlPayRequest["data"] as? [String: Any?] else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayRequest"))
@@ -1295,8 +3559,29 @@ enum BreezSDKMapper {
             }
             validateSuccessActionUrl = validateSuccessActionUrlTmp
         }
+        var pendingTimeoutSec: UInt32?
+        if hasNonNilKey(data: lnUrlPayRequest, key: "pendingTimeoutSec") {
+            guard let pendingTimeoutSecTmp = lnUrlPayRequest["pendingTimeoutSec"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "pendingTimeoutSec"))
+            }
+            pendingTimeoutSec = pendingTimeoutSecTmp
+        }
+        var cancelToken: String?
+        if hasNonNilKey(data: lnUrlPayRequest, key: "cancelToken") {
+            guard let cancelTokenTmp = lnUrlPayRequest["cancelToken"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "cancelToken"))
+            }
+            cancelToken = cancelTokenTmp
+        }
+        var skipDenylist: Bool?
+        if hasNonNilKey(data: lnUrlPayRequest, key: "skipDenylist") {
+            guard let skipDenylistTmp = lnUrlPayRequest["skipDenylist"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "skipDenylist"))
+            }
+            skipDenylist = skipDenylistTmp
+        }
 
-        return LnUrlPayRequest(data: data, amountMsat: amountMsat, useTrampoline: useTrampoline, comment: comment, paymentLabel: paymentLabel, validateSuccessActionUrl: validateSuccessActionUrl)
+        return LnUrlPayRequest(data: data, amountMsat: amountMsat, useTrampoline: useTrampoline, comment: comment, paymentLabel: paymentLabel, validateSuccessActionUrl: validateSuccessActionUrl, pendingTimeoutSec: pendingTimeoutSec, cancelToken: cancelToken, skipDenylist: skipDenylist)
     }
 
     static func dictionaryOf(lnUrlPayRequest: LnUrlPayRequest) -> [String: Any?] {
@@ -1307,6 +3592,9 @@ enum BreezSDKMapper {
             "comment": lnUrlPayRequest.comment == nil ? nil : lnUrlPayRequest.comment,
             "paymentLabel": lnUrlPayRequest.paymentLabel == nil ? nil : lnUrlPayRequest.paymentLabel,
             "validateSuccessActionUrl": lnUrlPayRequest.validateSuccessActionUrl == nil ? nil : lnUrlPayRequest.validateSuccessActionUrl,
+            "pendingTimeoutSec": lnUrlPayRequest.pendingTimeoutSec == nil ? nil : lnUrlPayRequest.pendingTimeoutSec,
+            "cancelToken": lnUrlPayRequest.cancelToken == nil ? nil : lnUrlPayRequest.cancelToken,
+            "skipDenylist": lnUrlPayRequest.skipDenylist == nil ? nil : lnUrlPayRequest.skipDenylist,
         ]
     }
 
@@ -1409,13 +3697,19 @@ enum BreezSDKMapper {
         }
         let payment = try asPayment(payment: paymentTmp)
 
-        return LnUrlPaySuccessData(successAction: successAction, payment: payment)
+        var partnerFee: PartnerFeeDetails?
+        if let partnerFeeTmp = lnUrlPaySuccessData["partnerFee"] as? [String: Any?] {
+            partnerFee = try asPartnerFeeDetails(partnerFeeDetails: partnerFeeTmp)
+        }
+
+        return LnUrlPaySuccessData(successAction: successAction, payment: payment, partnerFee: partnerFee)
     }
 
     static func dictionaryOf(lnUrlPaySuccessData: LnUrlPaySuccessData) -> [String: Any?] {
         return [
             "successAction": lnUrlPaySuccessData.successAction == nil ? nil : dictionaryOf(successActionProcessed: lnUrlPaySuccessData.successAction!),
             "payment": dictionaryOf(payment: lnUrlPaySuccessData.payment),
+            "partnerFee": lnUrlPaySuccessData.partnerFee == nil ? nil : dictionaryOf(partnerFeeDetails: lnUrlPaySuccessData.partnerFee!),
         ]
     }
 
@@ -1560,6 +3854,104 @@ enum BreezSDKMapper {
         return lnUrlWithdrawSuccessDataList.map { v -> [String: Any?] in return dictionaryOf(lnUrlWithdrawSuccessData: v) }
     }
 
+    static func asLnurlPayInfo(lnurlPayInfo: [String: Any?]) throws -> LnurlPayInfo {
+        guard let lnurl = lnurlPayInfo["lnurl"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnurl", typeName: "LnurlPayInfo"))
+        }
+        guard let lightningAddress = lnurlPayInfo["lightningAddress"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lightningAddress", typeName: "LnurlPayInfo"))
+        }
+
+        return LnurlPayInfo(lnurl: lnurl, lightningAddress: lightningAddress)
+    }
+
+    static func dictionaryOf(lnurlPayInfo: LnurlPayInfo) -> [String: Any?] {
+        return [
+            "lnurl": lnurlPayInfo.lnurl,
+            "lightningAddress": lnurlPayInfo.lightningAddress,
+        ]
+    }
+
+    static func asLnurlPayInfoList(arr: [Any]) throws -> [LnurlPayInfo] {
+        var list = [LnurlPayInfo]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnurlPayInfo = try asLnurlPayInfo(lnurlPayInfo: val)
+                list.append(lnurlPayInfo)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnurlPayInfo"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnurlPayInfoList: [LnurlPayInfo]) -> [Any] {
+        return lnurlPayInfoList.map { v -> [String: Any?] in return dictionaryOf(lnurlPayInfo: v) }
+    }
+
+    static func asLnurlWithdrawVoucher(lnurlWithdrawVoucher: [String: Any?]) throws -> LnurlWithdrawVoucher {
+        guard let id = lnurlWithdrawVoucher["id"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "LnurlWithdrawVoucher"))
+        }
+        guard let lnurl = lnurlWithdrawVoucher["lnurl"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnurl", typeName: "LnurlWithdrawVoucher"))
+        }
+        guard let amountMsat = lnurlWithdrawVoucher["amountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "LnurlWithdrawVoucher"))
+        }
+        guard let uses = lnurlWithdrawVoucher["uses"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "uses", typeName: "LnurlWithdrawVoucher"))
+        }
+        guard let redeemedUses = lnurlWithdrawVoucher["redeemedUses"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "redeemedUses", typeName: "LnurlWithdrawVoucher"))
+        }
+        var description: String?
+        if hasNonNilKey(data: lnurlWithdrawVoucher, key: "description") {
+            guard let descriptionTmp = lnurlWithdrawVoucher["description"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "description"))
+            }
+            description = descriptionTmp
+        }
+        guard let createdAt = lnurlWithdrawVoucher["createdAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "createdAt", typeName: "LnurlWithdrawVoucher"))
+        }
+        guard let expiresAt = lnurlWithdrawVoucher["expiresAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expiresAt", typeName: "LnurlWithdrawVoucher"))
+        }
+
+        return LnurlWithdrawVoucher(id: id, lnurl: lnurl, amountMsat: amountMsat, uses: uses, redeemedUses: redeemedUses, description: description, createdAt: createdAt, expiresAt: expiresAt)
+    }
+
+    static func dictionaryOf(lnurlWithdrawVoucher: LnurlWithdrawVoucher) -> [String: Any?] {
+        return [
+            "id": lnurlWithdrawVoucher.id,
+            "lnurl": lnurlWithdrawVoucher.lnurl,
+            "amountMsat": lnurlWithdrawVoucher.amountMsat,
+            "uses": lnurlWithdrawVoucher.uses,
+            "redeemedUses": lnurlWithdrawVoucher.redeemedUses,
+            "description": lnurlWithdrawVoucher.description == nil ? nil : lnurlWithdrawVoucher.description,
+            "createdAt": lnurlWithdrawVoucher.createdAt,
+            "expiresAt": lnurlWithdrawVoucher.expiresAt,
+        ]
+    }
+
+    static func asLnurlWithdrawVoucherList(arr: [Any]) throws -> [LnurlWithdrawVoucher] {
+        var list = [LnurlWithdrawVoucher]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lnurlWithdrawVoucher = try asLnurlWithdrawVoucher(lnurlWithdrawVoucher: val)
+                list.append(lnurlWithdrawVoucher)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LnurlWithdrawVoucher"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lnurlWithdrawVoucherList: [LnurlWithdrawVoucher]) -> [Any] {
+        return lnurlWithdrawVoucherList.map { v -> [String: Any?] in return dictionaryOf(lnurlWithdrawVoucher: v) }
+    }
+
     static func asLocaleOverrides(localeOverrides: [String: Any?]) throws -> LocaleOverrides {
         guard let locale = localeOverrides["locale"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "locale", typeName: "LocaleOverrides"))
@@ -1646,14 +4038,18 @@ enum BreezSDKMapper {
         guard let level = logEntry["level"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "level", typeName: "LogEntry"))
         }
+        guard let module = logEntry["module"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "module", typeName: "LogEntry"))
+        }
 
-        return LogEntry(line: line, level: level)
+        return LogEntry(line: line, level: level, module: module)
     }
 
     static func dictionaryOf(logEntry: LogEntry) -> [String: Any?] {
         return [
             "line": logEntry.line,
             "level": logEntry.level,
+            "module": logEntry.module,
         ]
     }
 
@@ -1674,6 +4070,41 @@ enum BreezSDKMapper {
         return logEntryList.map { v -> [String: Any?] in return dictionaryOf(logEntry: v) }
     }
 
+    static func asLowLiquidityDetails(lowLiquidityDetails: [String: Any?]) throws -> LowLiquidityDetails {
+        guard let liquidityMsat = lowLiquidityDetails["liquidityMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "liquidityMsat", typeName: "LowLiquidityDetails"))
+        }
+        guard let thresholdMsat = lowLiquidityDetails["thresholdMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "thresholdMsat", typeName: "LowLiquidityDetails"))
+        }
+
+        return LowLiquidityDetails(liquidityMsat: liquidityMsat, thresholdMsat: thresholdMsat)
+    }
+
+    static func dictionaryOf(lowLiquidityDetails: LowLiquidityDetails) -> [String: Any?] {
+        return [
+            "liquidityMsat": lowLiquidityDetails.liquidityMsat,
+            "thresholdMsat": lowLiquidityDetails.thresholdMsat,
+        ]
+    }
+
+    static func asLowLiquidityDetailsList(arr: [Any]) throws -> [LowLiquidityDetails] {
+        var list = [LowLiquidityDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lowLiquidityDetails = try asLowLiquidityDetails(lowLiquidityDetails: val)
+                list.append(lowLiquidityDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LowLiquidityDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(lowLiquidityDetailsList: [LowLiquidityDetails]) -> [Any] {
+        return lowLiquidityDetailsList.map { v -> [String: Any?] in return dictionaryOf(lowLiquidityDetails: v) }
+    }
+
     static func asLspInformation(lspInformation: [String: Any?]) throws -> LspInformation {
         guard let id = lspInformation["id"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "LspInformation"))
@@ -1781,8 +4212,12 @@ enum BreezSDKMapper {
         guard let jsonPath = metadataFilter["jsonPath"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "jsonPath", typeName: "MetadataFilter"))
         }
-        guard let jsonValue = metadataFilter["jsonValue"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "jsonValue", typeName: "MetadataFilter"))
+        var jsonValue: String?
+        if hasNonNilKey(data: metadataFilter, key: "jsonValue") {
+            guard let jsonValueTmp = metadataFilter["jsonValue"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "jsonValue"))
+            }
+            jsonValue = jsonValueTmp
         }
 
         return MetadataFilter(jsonPath: jsonPath, jsonValue: jsonValue)
@@ -1791,7 +4226,7 @@ enum BreezSDKMapper {
     static func dictionaryOf(metadataFilter: MetadataFilter) -> [String: Any?] {
         return [
             "jsonPath": metadataFilter.jsonPath,
-            "jsonValue": metadataFilter.jsonValue,
+            "jsonValue": metadataFilter.jsonValue == nil ? nil : metadataFilter.jsonValue,
         ]
     }
 
@@ -1843,8 +4278,383 @@ enum BreezSDKMapper {
         return list
     }
 
-    static func arrayOf(metadataItemList: [MetadataItem]) -> [Any] {
-        return metadataItemList.map { v -> [String: Any?] in return dictionaryOf(metadataItem: v) }
+    static func arrayOf(metadataItemList: [MetadataItem]) -> [Any] {
+        return metadataItemList.map { v -> [String: Any?] in return dictionaryOf(metadataItem: v) }
+    }
+
+    static func asMetricBucket(metricBucket: [String: Any?]) throws -> MetricBucket {
+        guard let upperBound = metricBucket["upperBound"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "upperBound", typeName: "MetricBucket"))
+        }
+        guard let cumulativeCount = metricBucket["cumulativeCount"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "cumulativeCount", typeName: "MetricBucket"))
+        }
+
+        return MetricBucket(upperBound: upperBound, cumulativeCount: cumulativeCount)
+    }
+
+    static func dictionaryOf(metricBucket: MetricBucket) -> [String: Any?] {
+        return [
+            "upperBound": metricBucket.upperBound,
+            "cumulativeCount": metricBucket.cumulativeCount,
+        ]
+    }
+
+    static func asMetricBucketList(arr: [Any]) throws -> [MetricBucket] {
+        var list = [MetricBucket]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var metricBucket = try asMetricBucket(metricBucket: val)
+                list.append(metricBucket)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MetricBucket"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(metricBucketList: [MetricBucket]) -> [Any] {
+        return metricBucketList.map { v -> [String: Any?] in return dictionaryOf(metricBucket: v) }
+    }
+
+    static func asMetricCounter(metricCounter: [String: Any?]) throws -> MetricCounter {
+        guard let name = metricCounter["name"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "MetricCounter"))
+        }
+        guard let help = metricCounter["help"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "help", typeName: "MetricCounter"))
+        }
+        guard let value = metricCounter["value"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "value", typeName: "MetricCounter"))
+        }
+
+        return MetricCounter(name: name, help: help, value: value)
+    }
+
+    static func dictionaryOf(metricCounter: MetricCounter) -> [String: Any?] {
+        return [
+            "name": metricCounter.name,
+            "help": metricCounter.help,
+            "value": metricCounter.value,
+        ]
+    }
+
+    static func asMetricCounterList(arr: [Any]) throws -> [MetricCounter] {
+        var list = [MetricCounter]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var metricCounter = try asMetricCounter(metricCounter: val)
+                list.append(metricCounter)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MetricCounter"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(metricCounterList: [MetricCounter]) -> [Any] {
+        return metricCounterList.map { v -> [String: Any?] in return dictionaryOf(metricCounter: v) }
+    }
+
+    static func asMetricHistogram(metricHistogram: [String: Any?]) throws -> MetricHistogram {
+        guard let name = metricHistogram["name"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "MetricHistogram"))
+        }
+        guard let help = metricHistogram["help"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "help", typeName: "MetricHistogram"))
+        }
+        guard let bucketsTmp = metricHistogram["buckets"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "buckets", typeName: "MetricHistogram"))
+        }
+        let buckets = try asMetricBucketList(arr: bucketsTmp)
+
+        guard let sum = metricHistogram["sum"] as? Double else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "sum", typeName: "MetricHistogram"))
+        }
+        guard let count = metricHistogram["count"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "count", typeName: "MetricHistogram"))
+        }
+
+        return MetricHistogram(name: name, help: help, buckets: buckets, sum: sum, count: count)
+    }
+
+    static func dictionaryOf(metricHistogram: MetricHistogram) -> [String: Any?] {
+        return [
+            "name": metricHistogram.name,
+            "help": metricHistogram.help,
+            "buckets": arrayOf(metricBucketList: metricHistogram.buckets),
+            "sum": metricHistogram.sum,
+            "count": metricHistogram.count,
+        ]
+    }
+
+    static func asMetricHistogramList(arr: [Any]) throws -> [MetricHistogram] {
+        var list = [MetricHistogram]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var metricHistogram = try asMetricHistogram(metricHistogram: val)
+                list.append(metricHistogram)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MetricHistogram"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(metricHistogramList: [MetricHistogram]) -> [Any] {
+        return metricHistogramList.map { v -> [String: Any?] in return dictionaryOf(metricHistogram: v) }
+    }
+
+    static func asMetricsSnapshot(metricsSnapshot: [String: Any?]) throws -> MetricsSnapshot {
+        guard let countersTmp = metricsSnapshot["counters"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "counters", typeName: "MetricsSnapshot"))
+        }
+        let counters = try asMetricCounterList(arr: countersTmp)
+
+        guard let histogramsTmp = metricsSnapshot["histograms"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "histograms", typeName: "MetricsSnapshot"))
+        }
+        let histograms = try asMetricHistogramList(arr: histogramsTmp)
+
+        return MetricsSnapshot(counters: counters, histograms: histograms)
+    }
+
+    static func dictionaryOf(metricsSnapshot: MetricsSnapshot) -> [String: Any?] {
+        return [
+            "counters": arrayOf(metricCounterList: metricsSnapshot.counters),
+            "histograms": arrayOf(metricHistogramList: metricsSnapshot.histograms),
+        ]
+    }
+
+    static func asMetricsSnapshotList(arr: [Any]) throws -> [MetricsSnapshot] {
+        var list = [MetricsSnapshot]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var metricsSnapshot = try asMetricsSnapshot(metricsSnapshot: val)
+                list.append(metricsSnapshot)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MetricsSnapshot"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(metricsSnapshotList: [MetricsSnapshot]) -> [Any] {
+        return metricsSnapshotList.map { v -> [String: Any?] in return dictionaryOf(metricsSnapshot: v) }
+    }
+
+    static func asMigrationChannel(migrationChannel: [String: Any?]) throws -> MigrationChannel {
+        guard let fundingTxid = migrationChannel["fundingTxid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fundingTxid", typeName: "MigrationChannel"))
+        }
+        var fundingOutnum: UInt32?
+        if hasNonNilKey(data: migrationChannel, key: "fundingOutnum") {
+            guard let fundingOutnumTmp = migrationChannel["fundingOutnum"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "fundingOutnum"))
+            }
+            fundingOutnum = fundingOutnumTmp
+        }
+        var shortChannelId: String?
+        if hasNonNilKey(data: migrationChannel, key: "shortChannelId") {
+            guard let shortChannelIdTmp = migrationChannel["shortChannelId"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "shortChannelId"))
+            }
+            shortChannelId = shortChannelIdTmp
+        }
+        guard let stateTmp = migrationChannel["state"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "state", typeName: "MigrationChannel"))
+        }
+        let state = try asChannelState(channelState: stateTmp)
+
+        guard let localBalanceMsat = migrationChannel["localBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "localBalanceMsat", typeName: "MigrationChannel"))
+        }
+        var peerId: String?
+        if hasNonNilKey(data: migrationChannel, key: "peerId") {
+            guard let peerIdTmp = migrationChannel["peerId"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "peerId"))
+            }
+            peerId = peerIdTmp
+        }
+        var capacityMsat: UInt64?
+        if hasNonNilKey(data: migrationChannel, key: "capacityMsat") {
+            guard let capacityMsatTmp = migrationChannel["capacityMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "capacityMsat"))
+            }
+            capacityMsat = capacityMsatTmp
+        }
+
+        return MigrationChannel(fundingTxid: fundingTxid, fundingOutnum: fundingOutnum, shortChannelId: shortChannelId, state: state, localBalanceMsat: localBalanceMsat, peerId: peerId, capacityMsat: capacityMsat)
+    }
+
+    static func dictionaryOf(migrationChannel: MigrationChannel) -> [String: Any?] {
+        return [
+            "fundingTxid": migrationChannel.fundingTxid,
+            "fundingOutnum": migrationChannel.fundingOutnum == nil ? nil : migrationChannel.fundingOutnum,
+            "shortChannelId": migrationChannel.shortChannelId == nil ? nil : migrationChannel.shortChannelId,
+            "state": valueOf(channelState: migrationChannel.state),
+            "localBalanceMsat": migrationChannel.localBalanceMsat,
+            "peerId": migrationChannel.peerId == nil ? nil : migrationChannel.peerId,
+            "capacityMsat": migrationChannel.capacityMsat == nil ? nil : migrationChannel.capacityMsat,
+        ]
+    }
+
+    static func asMigrationChannelList(arr: [Any]) throws -> [MigrationChannel] {
+        var list = [MigrationChannel]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var migrationChannel = try asMigrationChannel(migrationChannel: val)
+                list.append(migrationChannel)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MigrationChannel"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(migrationChannelList: [MigrationChannel]) -> [Any] {
+        return migrationChannelList.map { v -> [String: Any?] in return dictionaryOf(migrationChannel: v) }
+    }
+
+    static func asMockNodeConfig(mockNodeConfig: [String: Any?]) throws -> MockNodeConfig {
+        guard let channelsBalanceMsat = mockNodeConfig["channelsBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelsBalanceMsat", typeName: "MockNodeConfig"))
+        }
+        guard let onchainBalanceMsat = mockNodeConfig["onchainBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainBalanceMsat", typeName: "MockNodeConfig"))
+        }
+        guard let inboundLiquidityMsat = mockNodeConfig["inboundLiquidityMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "inboundLiquidityMsat", typeName: "MockNodeConfig"))
+        }
+
+        return MockNodeConfig(channelsBalanceMsat: channelsBalanceMsat, onchainBalanceMsat: onchainBalanceMsat, inboundLiquidityMsat: inboundLiquidityMsat)
+    }
+
+    static func dictionaryOf(mockNodeConfig: MockNodeConfig) -> [String: Any?] {
+        return [
+            "channelsBalanceMsat": mockNodeConfig.channelsBalanceMsat,
+            "onchainBalanceMsat": mockNodeConfig.onchainBalanceMsat,
+            "inboundLiquidityMsat": mockNodeConfig.inboundLiquidityMsat,
+        ]
+    }
+
+    static func asMockNodeConfigList(arr: [Any]) throws -> [MockNodeConfig] {
+        var list = [MockNodeConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var mockNodeConfig = try asMockNodeConfig(mockNodeConfig: val)
+                list.append(mockNodeConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MockNodeConfig"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(mockNodeConfigList: [MockNodeConfig]) -> [Any] {
+        return mockNodeConfigList.map { v -> [String: Any?] in return dictionaryOf(mockNodeConfig: v) }
+    }
+
+    static func asNodeMigrationRequest(nodeMigrationRequest: [String: Any?]) throws -> NodeMigrationRequest {
+        guard let closeChannels = nodeMigrationRequest["closeChannels"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "closeChannels", typeName: "NodeMigrationRequest"))
+        }
+
+        return NodeMigrationRequest(closeChannels: closeChannels)
+    }
+
+    static func dictionaryOf(nodeMigrationRequest: NodeMigrationRequest) -> [String: Any?] {
+        return [
+            "closeChannels": nodeMigrationRequest.closeChannels,
+        ]
+    }
+
+    static func asNodeMigrationRequestList(arr: [Any]) throws -> [NodeMigrationRequest] {
+        var list = [NodeMigrationRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var nodeMigrationRequest = try asNodeMigrationRequest(nodeMigrationRequest: val)
+                list.append(nodeMigrationRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "NodeMigrationRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(nodeMigrationRequestList: [NodeMigrationRequest]) -> [Any] {
+        return nodeMigrationRequestList.map { v -> [String: Any?] in return dictionaryOf(nodeMigrationRequest: v) }
+    }
+
+    static func asNodeMigrationState(nodeMigrationState: [String: Any?]) throws -> NodeMigrationState {
+        guard let nodeId = nodeMigrationState["nodeId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "NodeMigrationState"))
+        }
+        guard let networkTmp = nodeMigrationState["network"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "NodeMigrationState"))
+        }
+        let network = try asNetwork(network: networkTmp)
+
+        var lspNodeUri: String?
+        if hasNonNilKey(data: nodeMigrationState, key: "lspNodeUri") {
+            guard let lspNodeUriTmp = nodeMigrationState["lspNodeUri"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lspNodeUri"))
+            }
+            lspNodeUri = lspNodeUriTmp
+        }
+        guard let staticBackup = nodeMigrationState["staticBackup"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "staticBackup", typeName: "NodeMigrationState"))
+        }
+        guard let channelsTmp = nodeMigrationState["channels"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channels", typeName: "NodeMigrationState"))
+        }
+        let channels = try asMigrationChannelList(arr: channelsTmp)
+
+        guard let closingTxids = nodeMigrationState["closingTxids"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "closingTxids", typeName: "NodeMigrationState"))
+        }
+        guard let channelsBalanceMsat = nodeMigrationState["channelsBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelsBalanceMsat", typeName: "NodeMigrationState"))
+        }
+        guard let onchainBalanceMsat = nodeMigrationState["onchainBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainBalanceMsat", typeName: "NodeMigrationState"))
+        }
+        guard let exportedAt = nodeMigrationState["exportedAt"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "exportedAt", typeName: "NodeMigrationState"))
+        }
+
+        return NodeMigrationState(nodeId: nodeId, network: network, lspNodeUri: lspNodeUri, staticBackup: staticBackup, channels: channels, closingTxids: closingTxids, channelsBalanceMsat: channelsBalanceMsat, onchainBalanceMsat: onchainBalanceMsat, exportedAt: exportedAt)
+    }
+
+    static func dictionaryOf(nodeMigrationState: NodeMigrationState) -> [String: Any?] {
+        return [
+            "nodeId": nodeMigrationState.nodeId,
+            "network": valueOf(network: nodeMigrationState.network),
+            "lspNodeUri": nodeMigrationState.lspNodeUri == nil ? nil : nodeMigrationState.lspNodeUri,
+            "staticBackup": nodeMigrationState.staticBackup,
+            "channels": arrayOf(migrationChannelList: nodeMigrationState.channels),
+            "closingTxids": nodeMigrationState.closingTxids,
+            "channelsBalanceMsat": nodeMigrationState.channelsBalanceMsat,
+            "onchainBalanceMsat": nodeMigrationState.onchainBalanceMsat,
+            "exportedAt": nodeMigrationState.exportedAt,
+        ]
+    }
+
+    static func asNodeMigrationStateList(arr: [Any]) throws -> [NodeMigrationState] {
+        var list = [NodeMigrationState]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var nodeMigrationState = try asNodeMigrationState(nodeMigrationState: val)
+                list.append(nodeMigrationState)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "NodeMigrationState"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(nodeMigrationStateList: [NodeMigrationState]) -> [Any] {
+        return nodeMigrationStateList.map { v -> [String: Any?] in return dictionaryOf(nodeMigrationState: v) }
     }
 
     static func asNodeState(nodeState: [String: Any?]) throws -> NodeState {
@@ -1889,8 +4699,15 @@ enum BreezSDKMapper {
         guard let totalInboundLiquidityMsats = nodeState["totalInboundLiquidityMsats"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalInboundLiquidityMsats", typeName: "NodeState"))
         }
+        guard let pendingChannelOpensMsat = nodeState["pendingChannelOpensMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pendingChannelOpensMsat", typeName: "NodeState"))
+        }
+        guard let pendingChannelOpensTmp = nodeState["pendingChannelOpens"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "pendingChannelOpens", typeName: "NodeState"))
+        }
+        let pendingChannelOpens = try asPendingChannelOpenList(arr: pendingChannelOpensTmp)
 
-        return NodeState(id: id, blockHeight: blockHeight, channelsBalanceMsat: channelsBalanceMsat, onchainBalanceMsat: onchainBalanceMsat, pendingOnchainBalanceMsat: pendingOnchainBalanceMsat, utxos: utxos, maxPayableMsat: maxPayableMsat, maxReceivableMsat: maxReceivableMsat, maxSinglePaymentAmountMsat: maxSinglePaymentAmountMsat, maxChanReserveMsats: maxChanReserveMsats, connectedPeers: connectedPeers, maxReceivableSinglePaymentAmountMsat: maxReceivableSinglePaymentAmountMsat, totalInboundLiquidityMsats: totalInboundLiquidityMsats)
+        return NodeState(id: id, blockHeight: blockHeight, channelsBalanceMsat: channelsBalanceMsat, onchainBalanceMsat: onchainBalanceMsat, pendingOnchainBalanceMsat: pendingOnchainBalanceMsat, utxos: utxos, maxPayableMsat: maxPayableMsat, maxReceivableMsat: maxReceivableMsat, maxSinglePaymentAmountMsat: maxSinglePaymentAmountMsat, maxChanReserveMsats: maxChanReserveMsats, connectedPeers: connectedPeers, maxReceivableSinglePaymentAmountMsat: maxReceivableSinglePaymentAmountMsat, totalInboundLiquidityMsats: totalInboundLiquidityMsats, pendingChannelOpensMsat: pendingChannelOpensMsat, pendingChannelOpens: pendingChannelOpens)
     }
 
     static func dictionaryOf(nodeState: NodeState) -> [String: Any?] {
@@ -1908,6 +4725,8 @@ enum BreezSDKMapper {
             "connectedPeers": nodeState.connectedPeers,
             "maxReceivableSinglePaymentAmountMsat": nodeState.maxReceivableSinglePaymentAmountMsat,
             "totalInboundLiquidityMsats": nodeState.totalInboundLiquidityMsats,
+            "pendingChannelOpensMsat": nodeState.pendingChannelOpensMsat,
+            "pendingChannelOpens": arrayOf(pendingChannelOpenList: nodeState.pendingChannelOpens),
         ]
     }
 
@@ -1929,13 +4748,13 @@ enum BreezSDKMapper {
     }
 
     static func asOnchainPaymentLimitsResponse(onchainPaymentLimitsResponse: [String: Any?]) throws -> OnchainPaymentLimitsResponse {
-        guard let minSat = onchainPaymentLimitsResponse["minSat"] as? UInt64 else {
+        guard let minSat = onchainPaymentLimitsResponse["minSat"] as? Sat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minSat", typeName: "OnchainPaymentLimitsResponse"))
         }
-        guard let maxSat = onchainPaymentLimitsResponse["maxSat"] as? UInt64 else {
+        guard let maxSat = onchainPaymentLimitsResponse["maxSat"] as? Sat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxSat", typeName: "OnchainPaymentLimitsResponse"))
         }
-        guard let maxPayableSat = onchainPaymentLimitsResponse["maxPayableSat"] as? UInt64 else {
+        guard let maxPayableSat = onchainPaymentLimitsResponse["maxPayableSat"] as? Sat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxPayableSat", typeName: "OnchainPaymentLimitsResponse"))
         }
 
@@ -1968,9 +4787,9 @@ enum BreezSDKMapper {
     }
 
     static func asOpenChannelFeeRequest(openChannelFeeRequest: [String: Any?]) throws -> OpenChannelFeeRequest {
-        var amountMsat: UInt64?
+        var amountMsat: MilliSat?
         if hasNonNilKey(data: openChannelFeeRequest, key: "amountMsat") {
-            guard let amountMsatTmp = openChannelFeeRequest["amountMsat"] as? UInt64 else {
+            guard let amountMsatTmp = openChannelFeeRequest["amountMsat"] as? MilliSat else {
                 throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
             }
             amountMsat = amountMsatTmp
@@ -2011,9 +4830,9 @@ enum BreezSDKMapper {
     }
 
     static func asOpenChannelFeeResponse(openChannelFeeResponse: [String: Any?]) throws -> OpenChannelFeeResponse {
-        var feeMsat: UInt64?
+        var feeMsat: MilliSat?
         if hasNonNilKey(data: openChannelFeeResponse, key: "feeMsat") {
-            guard let feeMsatTmp = openChannelFeeResponse["feeMsat"] as? UInt64 else {
+            guard let feeMsatTmp = openChannelFeeResponse["feeMsat"] as? MilliSat else {
                 throw SdkError.Generic(message: errUnexpectedValue(fieldName: "feeMsat"))
             }
             feeMsat = feeMsatTmp
@@ -2050,6 +4869,124 @@ enum BreezSDKMapper {
         return openChannelFeeResponseList.map { v -> [String: Any?] in return dictionaryOf(openChannelFeeResponse: v) }
     }
 
+    static func asOpenChannelReceiveDetails(openChannelReceiveDetails: [String: Any?]) throws -> OpenChannelReceiveDetails {
+        guard let paymentHash = openChannelReceiveDetails["paymentHash"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentHash", typeName: "OpenChannelReceiveDetails"))
+        }
+        guard let stageTmp = openChannelReceiveDetails["stage"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "stage", typeName: "OpenChannelReceiveDetails"))
+        }
+        let stage = try asOpenChannelReceiveStage(openChannelReceiveStage: stageTmp)
+
+        return OpenChannelReceiveDetails(paymentHash: paymentHash, stage: stage)
+    }
+
+    static func dictionaryOf(openChannelReceiveDetails: OpenChannelReceiveDetails) -> [String: Any?] {
+        return [
+            "paymentHash": openChannelReceiveDetails.paymentHash,
+            "stage": dictionaryOf(openChannelReceiveStage: openChannelReceiveDetails.stage),
+        ]
+    }
+
+    static func asOpenChannelReceiveDetailsList(arr: [Any]) throws -> [OpenChannelReceiveDetails] {
+        var list = [OpenChannelReceiveDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var openChannelReceiveDetails = try asOpenChannelReceiveDetails(openChannelReceiveDetails: val)
+                list.append(openChannelReceiveDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpenChannelReceiveDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(openChannelReceiveDetailsList: [OpenChannelReceiveDetails]) -> [Any] {
+        return openChannelReceiveDetailsList.map { v -> [String: Any?] in return dictionaryOf(openChannelReceiveDetails: v) }
+    }
+
+    static func asOpenChannelRequest(openChannelRequest: [String: Any?]) throws -> OpenChannelRequest {
+        guard let nodeId = openChannelRequest["nodeId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "OpenChannelRequest"))
+        }
+        guard let amountSat = openChannelRequest["amountSat"] as? Sat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "OpenChannelRequest"))
+        }
+        guard let announce = openChannelRequest["announce"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "announce", typeName: "OpenChannelRequest"))
+        }
+        var satPerVbyte: UInt32?
+        if hasNonNilKey(data: openChannelRequest, key: "satPerVbyte") {
+            guard let satPerVbyteTmp = openChannelRequest["satPerVbyte"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "satPerVbyte"))
+            }
+            satPerVbyte = satPerVbyteTmp
+        }
+
+        return OpenChannelRequest(nodeId: nodeId, amountSat: amountSat, announce: announce, satPerVbyte: satPerVbyte)
+    }
+
+    static func dictionaryOf(openChannelRequest: OpenChannelRequest) -> [String: Any?] {
+        return [
+            "nodeId": openChannelRequest.nodeId,
+            "amountSat": openChannelRequest.amountSat,
+            "announce": openChannelRequest.announce,
+            "satPerVbyte": openChannelRequest.satPerVbyte == nil ? nil : openChannelRequest.satPerVbyte,
+        ]
+    }
+
+    static func asOpenChannelRequestList(arr: [Any]) throws -> [OpenChannelRequest] {
+        var list = [OpenChannelRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var openChannelRequest = try asOpenChannelRequest(openChannelRequest: val)
+                list.append(openChannelRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpenChannelRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(openChannelRequestList: [OpenChannelRequest]) -> [Any] {
+        return openChannelRequestList.map { v -> [String: Any?] in return dictionaryOf(openChannelRequest: v) }
+    }
+
+    static func asOpenChannelResponse(openChannelResponse: [String: Any?]) throws -> OpenChannelResponse {
+        guard let channelId = openChannelResponse["channelId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelId", typeName: "OpenChannelResponse"))
+        }
+        guard let fundingTxid = openChannelResponse["fundingTxid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fundingTxid", typeName: "OpenChannelResponse"))
+        }
+
+        return OpenChannelResponse(channelId: channelId, fundingTxid: fundingTxid)
+    }
+
+    static func dictionaryOf(openChannelResponse: OpenChannelResponse) -> [String: Any?] {
+        return [
+            "channelId": openChannelResponse.channelId,
+            "fundingTxid": openChannelResponse.fundingTxid,
+        ]
+    }
+
+    static func asOpenChannelResponseList(arr: [Any]) throws -> [OpenChannelResponse] {
+        var list = [OpenChannelResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var openChannelResponse = try asOpenChannelResponse(openChannelResponse: val)
+                list.append(openChannelResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpenChannelResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(openChannelResponseList: [OpenChannelResponse]) -> [Any] {
+        return openChannelResponseList.map { v -> [String: Any?] in return dictionaryOf(openChannelResponse: v) }
+    }
+
     static func asOpeningFeeParams(openingFeeParams: [String: Any?]) throws -> OpeningFeeParams {
         guard let minMsat = openingFeeParams["minMsat"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minMsat", typeName: "OpeningFeeParams"))
@@ -2060,77 +4997,404 @@ enum BreezSDKMapper {
         guard let validUntil = openingFeeParams["validUntil"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "validUntil", typeName: "OpeningFeeParams"))
         }
-        guard let maxIdleTime = openingFeeParams["maxIdleTime"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxIdleTime", typeName: "OpeningFeeParams"))
+        guard let maxIdleTime = openingFeeParams["maxIdleTime"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxIdleTime", typeName: "OpeningFeeParams"))
+        }
+        guard let maxClientToSelfDelay = openingFeeParams["maxClientToSelfDelay"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxClientToSelfDelay", typeName: "OpeningFeeParams"))
+        }
+        guard let promise = openingFeeParams["promise"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "promise", typeName: "OpeningFeeParams"))
+        }
+
+        return OpeningFeeParams(minMsat: minMsat, proportional: proportional, validUntil: validUntil, maxIdleTime: maxIdleTime, maxClientToSelfDelay: maxClientToSelfDelay, promise: promise)
+    }
+
+    static func dictionaryOf(openingFeeParams: OpeningFeeParams) -> [String: Any?] {
+        return [
+            "minMsat": openingFeeParams.minMsat,
+            "proportional": openingFeeParams.proportional,
+            "validUntil": openingFeeParams.validUntil,
+            "maxIdleTime": openingFeeParams.maxIdleTime,
+            "maxClientToSelfDelay": openingFeeParams.maxClientToSelfDelay,
+            "promise": openingFeeParams.promise,
+        ]
+    }
+
+    static func asOpeningFeeParamsList(arr: [Any]) throws -> [OpeningFeeParams] {
+        var list = [OpeningFeeParams]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var openingFeeParams = try asOpeningFeeParams(openingFeeParams: val)
+                list.append(openingFeeParams)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpeningFeeParams"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(openingFeeParamsList: [OpeningFeeParams]) -> [Any] {
+        return openingFeeParamsList.map { v -> [String: Any?] in return dictionaryOf(openingFeeParams: v) }
+    }
+
+    static func asOpeningFeeParamsMenu(openingFeeParamsMenu: [String: Any?]) throws -> OpeningFeeParamsMenu {
+        guard let valuesTmp = openingFeeParamsMenu["values"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "values", typeName: "OpeningFeeParamsMenu"))
+        }
+        let values = try asOpeningFeeParamsList(arr: valuesTmp)
+
+        return OpeningFeeParamsMenu(values: values)
+    }
+
+    static func dictionaryOf(openingFeeParamsMenu: OpeningFeeParamsMenu) -> [String: Any?] {
+        return [
+            "values": arrayOf(openingFeeParamsList: openingFeeParamsMenu.values),
+        ]
+    }
+
+    static func asOpeningFeeParamsMenuList(arr: [Any]) throws -> [OpeningFeeParamsMenu] {
+        var list = [OpeningFeeParamsMenu]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var openingFeeParamsMenu = try asOpeningFeeParamsMenu(openingFeeParamsMenu: val)
+                list.append(openingFeeParamsMenu)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpeningFeeParamsMenu"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(openingFeeParamsMenuList: [OpeningFeeParamsMenu]) -> [Any] {
+        return openingFeeParamsMenuList.map { v -> [String: Any?] in return dictionaryOf(openingFeeParamsMenu: v) }
+    }
+
+    static func asOutboxPayment(outboxPayment: [String: Any?]) throws -> OutboxPayment {
+        guard let id = outboxPayment["id"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "OutboxPayment"))
+        }
+        guard let reqTmp = outboxPayment["req"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "req", typeName: "OutboxPayment"))
+        }
+        let req = try asSendPaymentRequest(sendPaymentRequest: reqTmp)
+
+        guard let createdAt = outboxPayment["createdAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "createdAt", typeName: "OutboxPayment"))
+        }
+        guard let expiresAt = outboxPayment["expiresAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expiresAt", typeName: "OutboxPayment"))
+        }
+        guard let attempts = outboxPayment["attempts"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "attempts", typeName: "OutboxPayment"))
+        }
+        guard let nextAttemptAt = outboxPayment["nextAttemptAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nextAttemptAt", typeName: "OutboxPayment"))
+        }
+        var lastError: String?
+        if hasNonNilKey(data: outboxPayment, key: "lastError") {
+            guard let lastErrorTmp = outboxPayment["lastError"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastError"))
+            }
+            lastError = lastErrorTmp
+        }
+
+        return OutboxPayment(id: id, req: req, createdAt: createdAt, expiresAt: expiresAt, attempts: attempts, nextAttemptAt: nextAttemptAt, lastError: lastError)
+    }
+
+    static func dictionaryOf(outboxPayment: OutboxPayment) -> [String: Any?] {
+        return [
+            "id": outboxPayment.id,
+            "req": dictionaryOf(sendPaymentRequest: outboxPayment.req),
+            "createdAt": outboxPayment.createdAt,
+            "expiresAt": outboxPayment.expiresAt,
+            "attempts": outboxPayment.attempts,
+            "nextAttemptAt": outboxPayment.nextAttemptAt,
+            "lastError": outboxPayment.lastError == nil ? nil : outboxPayment.lastError,
+        ]
+    }
+
+    static func asOutboxPaymentList(arr: [Any]) throws -> [OutboxPayment] {
+        var list = [OutboxPayment]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var outboxPayment = try asOutboxPayment(outboxPayment: val)
+                list.append(outboxPayment)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OutboxPayment"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(outboxPaymentList: [OutboxPayment]) -> [Any] {
+        return outboxPaymentList.map { v -> [String: Any?] in return dictionaryOf(outboxPayment: v) }
+    }
+
+    static func asOutboxPaymentDetails(outboxPaymentDetails: [String: Any?]) throws -> OutboxPaymentDetails {
+        guard let outboxPaymentTmp = outboxPaymentDetails["outboxPayment"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "outboxPayment", typeName: "OutboxPaymentDetails"))
+        }
+        let outboxPayment = try asOutboxPayment(outboxPayment: outboxPaymentTmp)
+
+        guard let statusTmp = outboxPaymentDetails["status"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "status", typeName: "OutboxPaymentDetails"))
+        }
+        let status = try asOutboxPaymentStatus(outboxPaymentStatus: statusTmp)
+
+        return OutboxPaymentDetails(outboxPayment: outboxPayment, status: status)
+    }
+
+    static func dictionaryOf(outboxPaymentDetails: OutboxPaymentDetails) -> [String: Any?] {
+        return [
+            "outboxPayment": dictionaryOf(outboxPayment: outboxPaymentDetails.outboxPayment),
+            "status": dictionaryOf(outboxPaymentStatus: outboxPaymentDetails.status),
+        ]
+    }
+
+    static func asOutboxPaymentDetailsList(arr: [Any]) throws -> [OutboxPaymentDetails] {
+        var list = [OutboxPaymentDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var outboxPaymentDetails = try asOutboxPaymentDetails(outboxPaymentDetails: val)
+                list.append(outboxPaymentDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OutboxPaymentDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(outboxPaymentDetailsList: [OutboxPaymentDetails]) -> [Any] {
+        return outboxPaymentDetailsList.map { v -> [String: Any?] in return dictionaryOf(outboxPaymentDetails: v) }
+    }
+
+    static func asPartnerFeeConfig(partnerFeeConfig: [String: Any?]) throws -> PartnerFeeConfig {
+        guard let feeBps = partnerFeeConfig["feeBps"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feeBps", typeName: "PartnerFeeConfig"))
+        }
+        guard let destinationTmp = partnerFeeConfig["destination"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "PartnerFeeConfig"))
+        }
+        let destination = try asPartnerFeeDestination(partnerFeeDestination: destinationTmp)
+
+        return PartnerFeeConfig(feeBps: feeBps, destination: destination)
+    }
+
+    static func dictionaryOf(partnerFeeConfig: PartnerFeeConfig) -> [String: Any?] {
+        return [
+            "feeBps": partnerFeeConfig.feeBps,
+            "destination": dictionaryOf(partnerFeeDestination: partnerFeeConfig.destination),
+        ]
+    }
+
+    static func asPartnerFeeConfigList(arr: [Any]) throws -> [PartnerFeeConfig] {
+        var list = [PartnerFeeConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var partnerFeeConfig = try asPartnerFeeConfig(partnerFeeConfig: val)
+                list.append(partnerFeeConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PartnerFeeConfig"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(partnerFeeConfigList: [PartnerFeeConfig]) -> [Any] {
+        return partnerFeeConfigList.map { v -> [String: Any?] in return dictionaryOf(partnerFeeConfig: v) }
+    }
+
+    static func asPartnerFeeDetails(partnerFeeDetails: [String: Any?]) throws -> PartnerFeeDetails {
+        guard let amountMsat = partnerFeeDetails["amountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PartnerFeeDetails"))
+        }
+        var paymentId: String?
+        if hasNonNilKey(data: partnerFeeDetails, key: "paymentId") {
+            guard let paymentIdTmp = partnerFeeDetails["paymentId"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "paymentId"))
+            }
+            paymentId = paymentIdTmp
+        }
+        var error: String?
+        if hasNonNilKey(data: partnerFeeDetails, key: "error") {
+            guard let errorTmp = partnerFeeDetails["error"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "error"))
+            }
+            error = errorTmp
+        }
+
+        return PartnerFeeDetails(amountMsat: amountMsat, paymentId: paymentId, error: error)
+    }
+
+    static func dictionaryOf(partnerFeeDetails: PartnerFeeDetails) -> [String: Any?] {
+        return [
+            "amountMsat": partnerFeeDetails.amountMsat,
+            "paymentId": partnerFeeDetails.paymentId == nil ? nil : partnerFeeDetails.paymentId,
+            "error": partnerFeeDetails.error == nil ? nil : partnerFeeDetails.error,
+        ]
+    }
+
+    static func asPartnerFeeDetailsList(arr: [Any]) throws -> [PartnerFeeDetails] {
+        var list = [PartnerFeeDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var partnerFeeDetails = try asPartnerFeeDetails(partnerFeeDetails: val)
+                list.append(partnerFeeDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PartnerFeeDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(partnerFeeDetailsList: [PartnerFeeDetails]) -> [Any] {
+        return partnerFeeDetailsList.map { v -> [String: Any?] in return dictionaryOf(partnerFeeDetails: v) }
+    }
+
+    static func asPayOfferRequest(payOfferRequest: [String: Any?]) throws -> PayOfferRequest {
+        guard let offer = payOfferRequest["offer"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "offer", typeName: "PayOfferRequest"))
+        }
+        var amountMsat: MilliSat?
+        if hasNonNilKey(data: payOfferRequest, key: "amountMsat") {
+            guard let amountMsatTmp = payOfferRequest["amountMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+            }
+            amountMsat = amountMsatTmp
+        }
+        var payerNote: String?
+        if hasNonNilKey(data: payOfferRequest, key: "payerNote") {
+            guard let payerNoteTmp = payOfferRequest["payerNote"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "payerNote"))
+            }
+            payerNote = payerNoteTmp
+        }
+        var label: String?
+        if hasNonNilKey(data: payOfferRequest, key: "label") {
+            guard let labelTmp = payOfferRequest["label"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "label"))
+            }
+            label = labelTmp
+        }
+
+        return PayOfferRequest(offer: offer, amountMsat: amountMsat, payerNote: payerNote, label: label)
+    }
+
+    static func dictionaryOf(payOfferRequest: PayOfferRequest) -> [String: Any?] {
+        return [
+            "offer": payOfferRequest.offer,
+            "amountMsat": payOfferRequest.amountMsat == nil ? nil : payOfferRequest.amountMsat,
+            "payerNote": payOfferRequest.payerNote == nil ? nil : payOfferRequest.payerNote,
+            "label": payOfferRequest.label == nil ? nil : payOfferRequest.label,
+        ]
+    }
+
+    static func asPayOfferRequestList(arr: [Any]) throws -> [PayOfferRequest] {
+        var list = [PayOfferRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var payOfferRequest = try asPayOfferRequest(payOfferRequest: val)
+                list.append(payOfferRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PayOfferRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(payOfferRequestList: [PayOfferRequest]) -> [Any] {
+        return payOfferRequestList.map { v -> [String: Any?] in return dictionaryOf(payOfferRequest: v) }
+    }
+
+    static func asPayOnchainAddressRequest(payOnchainAddressRequest: [String: Any?]) throws -> PayOnchainAddressRequest {
+        guard let address = payOnchainAddressRequest["address"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "PayOnchainAddressRequest"))
+        }
+        var amountSat: Sat?
+        if hasNonNilKey(data: payOnchainAddressRequest, key: "amountSat") {
+            guard let amountSatTmp = payOnchainAddressRequest["amountSat"] as? Sat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountSat"))
+            }
+            amountSat = amountSatTmp
         }
-        guard let maxClientToSelfDelay = openingFeeParams["maxClientToSelfDelay"] as? UInt32 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "maxClientToSelfDelay", typeName: "OpeningFeeParams"))
+        guard let drain = payOnchainAddressRequest["drain"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "drain", typeName: "PayOnchainAddressRequest"))
         }
-        guard let promise = openingFeeParams["promise"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "promise", typeName: "OpeningFeeParams"))
+        var feePreference: FeeratePreset?
+        if let feePreferenceTmp = payOnchainAddressRequest["feePreference"] as? String {
+            feePreference = try asFeeratePreset(feeratePreset: feePreferenceTmp)
         }
 
-        return OpeningFeeParams(minMsat: minMsat, proportional: proportional, validUntil: validUntil, maxIdleTime: maxIdleTime, maxClientToSelfDelay: maxClientToSelfDelay, promise: promise)
+        return PayOnchainAddressRequest(address: address, amountSat: amountSat, drain: drain, feePreference: feePreference)
     }
 
-    static func dictionaryOf(openingFeeParams: OpeningFeeParams) -> [String: Any?] {
+    static func dictionaryOf(payOnchainAddressRequest: PayOnchainAddressRequest) -> [String: Any?] {
         return [
-            "minMsat": openingFeeParams.minMsat,
-            "proportional": openingFeeParams.proportional,
-            "validUntil": openingFeeParams.validUntil,
-            "maxIdleTime": openingFeeParams.maxIdleTime,
-            "maxClientToSelfDelay": openingFeeParams.maxClientToSelfDelay,
-            "promise": openingFeeParams.promise,
+            "address": payOnchainAddressRequest.address,
+            "amountSat": payOnchainAddressRequest.amountSat == nil ? nil : payOnchainAddressRequest.amountSat,
+            "drain": payOnchainAddressRequest.drain,
+            "feePreference": payOnchainAddressRequest.feePreference == nil ? nil : valueOf(feeratePreset: payOnchainAddressRequest.feePreference!),
         ]
     }
 
-    static func asOpeningFeeParamsList(arr: [Any]) throws -> [OpeningFeeParams] {
-        var list = [OpeningFeeParams]()
+    static func asPayOnchainAddressRequestList(arr: [Any]) throws -> [PayOnchainAddressRequest] {
+        var list = [PayOnchainAddressRequest]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var openingFeeParams = try asOpeningFeeParams(openingFeeParams: val)
-                list.append(openingFeeParams)
+                var payOnchainAddressRequest = try asPayOnchainAddressRequest(payOnchainAddressRequest: val)
+                list.append(payOnchainAddressRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpeningFeeParams"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PayOnchainAddressRequest"))
             }
         }
         return list
     }
 
-    static func arrayOf(openingFeeParamsList: [OpeningFeeParams]) -> [Any] {
-        return openingFeeParamsList.map { v -> [String: Any?] in return dictionaryOf(openingFeeParams: v) }
+    static func arrayOf(payOnchainAddressRequestList: [PayOnchainAddressRequest]) -> [Any] {
+        return payOnchainAddressRequestList.map { v -> [String: Any?] in return dictionaryOf(payOnchainAddressRequest: v) }
     }
 
-    static func asOpeningFeeParamsMenu(openingFeeParamsMenu: [String: Any?]) throws -> OpeningFeeParamsMenu {
-        guard let valuesTmp = openingFeeParamsMenu["values"] as? [[String: Any?]] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "values", typeName: "OpeningFeeParamsMenu"))
+    static func asPayOnchainAddressResponse(payOnchainAddressResponse: [String: Any?]) throws -> PayOnchainAddressResponse {
+        guard let prepareResTmp = payOnchainAddressResponse["prepareRes"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "prepareRes", typeName: "PayOnchainAddressResponse"))
         }
-        let values = try asOpeningFeeParamsList(arr: valuesTmp)
+        let prepareRes = try asPrepareOnchainPaymentResponse(prepareOnchainPaymentResponse: prepareResTmp)
 
-        return OpeningFeeParamsMenu(values: values)
+        guard let claimTxFeerate = payOnchainAddressResponse["claimTxFeerate"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "claimTxFeerate", typeName: "PayOnchainAddressResponse"))
+        }
+        guard let reverseSwapInfoTmp = payOnchainAddressResponse["reverseSwapInfo"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reverseSwapInfo", typeName: "PayOnchainAddressResponse"))
+        }
+        let reverseSwapInfo = try asReverseSwapInfo(reverseSwapInfo: reverseSwapInfoTmp)
+
+        return PayOnchainAddressResponse(prepareRes: prepareRes, claimTxFeerate: claimTxFeerate, reverseSwapInfo: reverseSwapInfo)
     }
 
-    static func dictionaryOf(openingFeeParamsMenu: OpeningFeeParamsMenu) -> [String: Any?] {
+    static func dictionaryOf(payOnchainAddressResponse: PayOnchainAddressResponse) -> [String: Any?] {
         return [
-            "values": arrayOf(openingFeeParamsList: openingFeeParamsMenu.values),
+            "prepareRes": dictionaryOf(prepareOnchainPaymentResponse: payOnchainAddressResponse.prepareRes),
+            "claimTxFeerate": payOnchainAddressResponse.claimTxFeerate,
+            "reverseSwapInfo": dictionaryOf(reverseSwapInfo: payOnchainAddressResponse.reverseSwapInfo),
         ]
     }
 
-    static func asOpeningFeeParamsMenuList(arr: [Any]) throws -> [OpeningFeeParamsMenu] {
-        var list = [OpeningFeeParamsMenu]()
+    static func asPayOnchainAddressResponseList(arr: [Any]) throws -> [PayOnchainAddressResponse] {
+        var list = [PayOnchainAddressResponse]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var openingFeeParamsMenu = try asOpeningFeeParamsMenu(openingFeeParamsMenu: val)
-                list.append(openingFeeParamsMenu)
+                var payOnchainAddressResponse = try asPayOnchainAddressResponse(payOnchainAddressResponse: val)
+                list.append(payOnchainAddressResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpeningFeeParamsMenu"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PayOnchainAddressResponse"))
             }
         }
         return list
     }
 
-    static func arrayOf(openingFeeParamsMenuList: [OpeningFeeParamsMenu]) -> [Any] {
-        return openingFeeParamsMenuList.map { v -> [String: Any?] in return dictionaryOf(openingFeeParamsMenu: v) }
+    static func arrayOf(payOnchainAddressResponseList: [PayOnchainAddressResponse]) -> [Any] {
+        return payOnchainAddressResponseList.map { v -> [String: Any?] in return dictionaryOf(payOnchainAddressResponse: v) }
     }
 
     static func asPayOnchainRequest(payOnchainRequest: [String: Any?]) throws -> PayOnchainRequest {
@@ -2142,13 +5406,22 @@ enum BreezSDKMapper {
         }
         let prepareRes = try asPrepareOnchainPaymentResponse(prepareOnchainPaymentResponse: prepareResTmp)
 
-        return PayOnchainRequest(recipientAddress: recipientAddress, prepareRes: prepareRes)
+        var cancelToken: String?
+        if hasNonNilKey(data: payOnchainRequest, key: "cancelToken") {
+            guard let cancelTokenTmp = payOnchainRequest["cancelToken"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "cancelToken"))
+            }
+            cancelToken = cancelTokenTmp
+        }
+
+        return PayOnchainRequest(recipientAddress: recipientAddress, prepareRes: prepareRes, cancelToken: cancelToken)
     }
 
     static func dictionaryOf(payOnchainRequest: PayOnchainRequest) -> [String: Any?] {
         return [
             "recipientAddress": payOnchainRequest.recipientAddress,
             "prepareRes": dictionaryOf(prepareOnchainPaymentResponse: payOnchainRequest.prepareRes),
+            "cancelToken": payOnchainRequest.cancelToken == nil ? nil : payOnchainRequest.cancelToken,
         ]
     }
 
@@ -2250,8 +5523,16 @@ enum BreezSDKMapper {
             }
             metadata = metadataTmp
         }
+        var fiatRate: Rate?
+        if let fiatRateTmp = payment["fiatRate"] as? [String: Any?] {
+            fiatRate = try asRate(rate: fiatRateTmp)
+        }
+
+        guard let imported = payment["imported"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "imported", typeName: "Payment"))
+        }
 
-        return Payment(id: id, paymentType: paymentType, paymentTime: paymentTime, amountMsat: amountMsat, feeMsat: feeMsat, status: status, error: error, description: description, details: details, metadata: metadata)
+        return Payment(id: id, paymentType: paymentType, paymentTime: paymentTime, amountMsat: amountMsat, feeMsat: feeMsat, status: status, error: error, description: description, details: details, metadata: metadata, fiatRate: fiatRate, imported: imported)
     }
 
     static func dictionaryOf(payment: Payment) -> [String: Any?] {
@@ -2266,6 +5547,8 @@ enum BreezSDKMapper {
             "description": payment.description == nil ? nil : payment.description,
             "details": dictionaryOf(paymentDetails: payment.details),
             "metadata": payment.metadata == nil ? nil : payment.metadata,
+            "fiatRate": payment.fiatRate == nil ? nil : dictionaryOf(rate: payment.fiatRate!),
+            "imported": payment.imported,
         ]
     }
 
@@ -2335,8 +5618,311 @@ enum BreezSDKMapper {
         return paymentFailedDataList.map { v -> [String: Any?] in return dictionaryOf(paymentFailedData: v) }
     }
 
+    static func asPaymentGroup(paymentGroup: [String: Any?]) throws -> PaymentGroup {
+        guard let key = paymentGroup["key"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "key", typeName: "PaymentGroup"))
+        }
+        guard let paymentsCount = paymentGroup["paymentsCount"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "paymentsCount", typeName: "PaymentGroup"))
+        }
+        guard let totalAmountMsat = paymentGroup["totalAmountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalAmountMsat", typeName: "PaymentGroup"))
+        }
+        guard let totalFeeMsat = paymentGroup["totalFeeMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalFeeMsat", typeName: "PaymentGroup"))
+        }
+        guard let firstPaymentTime = paymentGroup["firstPaymentTime"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "firstPaymentTime", typeName: "PaymentGroup"))
+        }
+        guard let lastPaymentTime = paymentGroup["lastPaymentTime"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lastPaymentTime", typeName: "PaymentGroup"))
+        }
+
+        return PaymentGroup(key: key, paymentsCount: paymentsCount, totalAmountMsat: totalAmountMsat, totalFeeMsat: totalFeeMsat, firstPaymentTime: firstPaymentTime, lastPaymentTime: lastPaymentTime)
+    }
+
+    static func dictionaryOf(paymentGroup: PaymentGroup) -> [String: Any?] {
+        return [
+            "key": paymentGroup.key,
+            "paymentsCount": paymentGroup.paymentsCount,
+            "totalAmountMsat": paymentGroup.totalAmountMsat,
+            "totalFeeMsat": paymentGroup.totalFeeMsat,
+            "firstPaymentTime": paymentGroup.firstPaymentTime,
+            "lastPaymentTime": paymentGroup.lastPaymentTime,
+        ]
+    }
+
+    static func asPaymentGroupList(arr: [Any]) throws -> [PaymentGroup] {
+        var list = [PaymentGroup]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var paymentGroup = try asPaymentGroup(paymentGroup: val)
+                list.append(paymentGroup)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentGroup"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(paymentGroupList: [PaymentGroup]) -> [Any] {
+        return paymentGroupList.map { v -> [String: Any?] in return dictionaryOf(paymentGroup: v) }
+    }
+
+    static func asPaymentProbe(paymentProbe: [String: Any?]) throws -> PaymentProbe {
+        guard let id = paymentProbe["id"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "PaymentProbe"))
+        }
+        guard let nodeId = paymentProbe["nodeId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "PaymentProbe"))
+        }
+        var bolt11: String?
+        if hasNonNilKey(data: paymentProbe, key: "bolt11") {
+            guard let bolt11Tmp = paymentProbe["bolt11"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "bolt11"))
+            }
+            bolt11 = bolt11Tmp
+        }
+        guard let amountMsat = paymentProbe["amountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PaymentProbe"))
+        }
+        guard let routeFound = paymentProbe["routeFound"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "routeFound", typeName: "PaymentProbe"))
+        }
+        guard let reachable = paymentProbe["reachable"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reachable", typeName: "PaymentProbe"))
+        }
+        var estimatedFeeMsat: UInt64?
+        if hasNonNilKey(data: paymentProbe, key: "estimatedFeeMsat") {
+            guard let estimatedFeeMsatTmp = paymentProbe["estimatedFeeMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "estimatedFeeMsat"))
+            }
+            estimatedFeeMsat = estimatedFeeMsatTmp
+        }
+        var hops: UInt32?
+        if hasNonNilKey(data: paymentProbe, key: "hops") {
+            guard let hopsTmp = paymentProbe["hops"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "hops"))
+            }
+            hops = hopsTmp
+        }
+        var error: String?
+        if hasNonNilKey(data: paymentProbe, key: "error") {
+            guard let errorTmp = paymentProbe["error"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "error"))
+            }
+            error = errorTmp
+        }
+        guard let createdAt = paymentProbe["createdAt"] as? Int64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "createdAt", typeName: "PaymentProbe"))
+        }
+
+        return PaymentProbe(id: id, nodeId: nodeId, bolt11: bolt11, amountMsat: amountMsat, routeFound: routeFound, reachable: reachable, estimatedFeeMsat: estimatedFeeMsat, hops: hops, error: error, createdAt: createdAt)
+    }
+
+    static func dictionaryOf(paymentProbe: PaymentProbe) -> [String: Any?] {
+        return [
+            "id": paymentProbe.id,
+            "nodeId": paymentProbe.nodeId,
+            "bolt11": paymentProbe.bolt11 == nil ? nil : paymentProbe.bolt11,
+            "amountMsat": paymentProbe.amountMsat,
+            "routeFound": paymentProbe.routeFound,
+            "reachable": paymentProbe.reachable,
+            "estimatedFeeMsat": paymentProbe.estimatedFeeMsat == nil ? nil : paymentProbe.estimatedFeeMsat,
+            "hops": paymentProbe.hops == nil ? nil : paymentProbe.hops,
+            "error": paymentProbe.error == nil ? nil : paymentProbe.error,
+            "createdAt": paymentProbe.createdAt,
+        ]
+    }
+
+    static func asPaymentProbeList(arr: [Any]) throws -> [PaymentProbe] {
+        var list = [PaymentProbe]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var paymentProbe = try asPaymentProbe(paymentProbe: val)
+                list.append(paymentProbe)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentProbe"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(paymentProbeList: [PaymentProbe]) -> [Any] {
+        return paymentProbeList.map { v -> [String: Any?] in return dictionaryOf(paymentProbe: v) }
+    }
+
+    static func asPaymentRequestBundle(paymentRequestBundle: [String: Any?]) throws -> PaymentRequestBundle {
+        guard let invoice = paymentRequestBundle["invoice"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "invoice", typeName: "PaymentRequestBundle"))
+        }
+        var fallback: String?
+        if hasNonNilKey(data: paymentRequestBundle, key: "fallback") {
+            guard let fallbackTmp = paymentRequestBundle["fallback"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "fallback"))
+            }
+            fallback = fallbackTmp
+        }
+        var metadata: String?
+        if hasNonNilKey(data: paymentRequestBundle, key: "metadata") {
+            guard let metadataTmp = paymentRequestBundle["metadata"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "metadata"))
+            }
+            metadata = metadataTmp
+        }
+
+        return PaymentRequestBundle(invoice: invoice, fallback: fallback, metadata: metadata)
+    }
+
+    static func dictionaryOf(paymentRequestBundle: PaymentRequestBundle) -> [String: Any?] {
+        return [
+            "invoice": paymentRequestBundle.invoice,
+            "fallback": paymentRequestBundle.fallback == nil ? nil : paymentRequestBundle.fallback,
+            "metadata": paymentRequestBundle.metadata == nil ? nil : paymentRequestBundle.metadata,
+        ]
+    }
+
+    static func asPaymentRequestBundleList(arr: [Any]) throws -> [PaymentRequestBundle] {
+        var list = [PaymentRequestBundle]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var paymentRequestBundle = try asPaymentRequestBundle(paymentRequestBundle: val)
+                list.append(paymentRequestBundle)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentRequestBundle"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(paymentRequestBundleList: [PaymentRequestBundle]) -> [Any] {
+        return paymentRequestBundleList.map { v -> [String: Any?] in return dictionaryOf(paymentRequestBundle: v) }
+    }
+
+    static func asPeerConnectivity(peerConnectivity: [String: Any?]) throws -> PeerConnectivity {
+        var lspPubkey: String?
+        if hasNonNilKey(data: peerConnectivity, key: "lspPubkey") {
+            guard let lspPubkeyTmp = peerConnectivity["lspPubkey"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lspPubkey"))
+            }
+            lspPubkey = lspPubkeyTmp
+        }
+        guard let lspConnected = peerConnectivity["lspConnected"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lspConnected", typeName: "PeerConnectivity"))
+        }
+        var pingRttMs: UInt64?
+        if hasNonNilKey(data: peerConnectivity, key: "pingRttMs") {
+            guard let pingRttMsTmp = peerConnectivity["pingRttMs"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "pingRttMs"))
+            }
+            pingRttMs = pingRttMsTmp
+        }
+        var pingError: String?
+        if hasNonNilKey(data: peerConnectivity, key: "pingError") {
+            guard let pingErrorTmp = peerConnectivity["pingError"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "pingError"))
+            }
+            pingError = pingErrorTmp
+        }
+        guard let reconnectionAttempts = peerConnectivity["reconnectionAttempts"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reconnectionAttempts", typeName: "PeerConnectivity"))
+        }
+        guard let failedReconnectionAttempts = peerConnectivity["failedReconnectionAttempts"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "failedReconnectionAttempts", typeName: "PeerConnectivity"))
+        }
+        var lastReconnectedAt: Int64?
+        if hasNonNilKey(data: peerConnectivity, key: "lastReconnectedAt") {
+            guard let lastReconnectedAtTmp = peerConnectivity["lastReconnectedAt"] as? Int64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastReconnectedAt"))
+            }
+            lastReconnectedAt = lastReconnectedAtTmp
+        }
+        var lastReconnectionError: String?
+        if hasNonNilKey(data: peerConnectivity, key: "lastReconnectionError") {
+            guard let lastReconnectionErrorTmp = peerConnectivity["lastReconnectionError"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastReconnectionError"))
+            }
+            lastReconnectionError = lastReconnectionErrorTmp
+        }
+
+        return PeerConnectivity(lspPubkey: lspPubkey, lspConnected: lspConnected, pingRttMs: pingRttMs, pingError: pingError, reconnectionAttempts: reconnectionAttempts, failedReconnectionAttempts: failedReconnectionAttempts, lastReconnectedAt: lastReconnectedAt, lastReconnectionError: lastReconnectionError)
+    }
+
+    static func dictionaryOf(peerConnectivity: PeerConnectivity) -> [String: Any?] {
+        return [
+            "lspPubkey": peerConnectivity.lspPubkey == nil ? nil : peerConnectivity.lspPubkey,
+            "lspConnected": peerConnectivity.lspConnected,
+            "pingRttMs": peerConnectivity.pingRttMs == nil ? nil : peerConnectivity.pingRttMs,
+            "pingError": peerConnectivity.pingError == nil ? nil : peerConnectivity.pingError,
+            "reconnectionAttempts": peerConnectivity.reconnectionAttempts,
+            "failedReconnectionAttempts": peerConnectivity.failedReconnectionAttempts,
+            "lastReconnectedAt": peerConnectivity.lastReconnectedAt == nil ? nil : peerConnectivity.lastReconnectedAt,
+            "lastReconnectionError": peerConnectivity.lastReconnectionError == nil ? nil : peerConnectivity.lastReconnectionError,
+        ]
+    }
+
+    static func asPeerConnectivityList(arr: [Any]) throws -> [PeerConnectivity] {
+        var list = [PeerConnectivity]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var peerConnectivity = try asPeerConnectivity(peerConnectivity: val)
+                list.append(peerConnectivity)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PeerConnectivity"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(peerConnectivityList: [PeerConnectivity]) -> [Any] {
+        return peerConnectivityList.map { v -> [String: Any?] in return dictionaryOf(peerConnectivity: v) }
+    }
+
+    static func asPendingChannelOpen(pendingChannelOpen: [String: Any?]) throws -> PendingChannelOpen {
+        guard let fundingTxid = pendingChannelOpen["fundingTxid"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fundingTxid", typeName: "PendingChannelOpen"))
+        }
+        guard let amountMsat = pendingChannelOpen["amountMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PendingChannelOpen"))
+        }
+        guard let seenAt = pendingChannelOpen["seenAt"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "seenAt", typeName: "PendingChannelOpen"))
+        }
+        guard let expectedConfirmationAt = pendingChannelOpen["expectedConfirmationAt"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expectedConfirmationAt", typeName: "PendingChannelOpen"))
+        }
+
+        return PendingChannelOpen(fundingTxid: fundingTxid, amountMsat: amountMsat, seenAt: seenAt, expectedConfirmationAt: expectedConfirmationAt)
+    }
+
+    static func dictionaryOf(pendingChannelOpen: PendingChannelOpen) -> [String: Any?] {
+        return [
+            "fundingTxid": pendingChannelOpen.fundingTxid,
+            "amountMsat": pendingChannelOpen.amountMsat,
+            "seenAt": pendingChannelOpen.seenAt,
+            "expectedConfirmationAt": pendingChannelOpen.expectedConfirmationAt,
+        ]
+    }
+
+    static func asPendingChannelOpenList(arr: [Any]) throws -> [PendingChannelOpen] {
+        var list = [PendingChannelOpen]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var pendingChannelOpen = try asPendingChannelOpen(pendingChannelOpen: val)
+                list.append(pendingChannelOpen)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PendingChannelOpen"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(pendingChannelOpenList: [PendingChannelOpen]) -> [Any] {
+        return pendingChannelOpenList.map { v -> [String: Any?] in return dictionaryOf(pendingChannelOpen: v) }
+    }
+
     static func asPrepareOnchainPaymentRequest(prepareOnchainPaymentRequest: [String: Any?]) throws -> PrepareOnchainPaymentRequest {
-        guard let amountSat = prepareOnchainPaymentRequest["amountSat"] as? UInt64 else {
+        guard let amountSat = prepareOnchainPaymentRequest["amountSat"] as? Sat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "PrepareOnchainPaymentRequest"))
         }
         guard let amountTypeTmp = prepareOnchainPaymentRequest["amountType"] as? String else {
@@ -2386,49 +5972,143 @@ enum BreezSDKMapper {
         guard let feesLockup = prepareOnchainPaymentResponse["feesLockup"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesLockup", typeName: "PrepareOnchainPaymentResponse"))
         }
-        guard let feesClaim = prepareOnchainPaymentResponse["feesClaim"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesClaim", typeName: "PrepareOnchainPaymentResponse"))
+        guard let feesClaim = prepareOnchainPaymentResponse["feesClaim"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feesClaim", typeName: "PrepareOnchainPaymentResponse"))
+        }
+        guard let senderAmountSat = prepareOnchainPaymentResponse["senderAmountSat"] as? Sat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "senderAmountSat", typeName: "PrepareOnchainPaymentResponse"))
+        }
+        guard let recipientAmountSat = prepareOnchainPaymentResponse["recipientAmountSat"] as? Sat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "recipientAmountSat", typeName: "PrepareOnchainPaymentResponse"))
+        }
+        guard let totalFees = prepareOnchainPaymentResponse["totalFees"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalFees", typeName: "PrepareOnchainPaymentResponse"))
+        }
+
+        return PrepareOnchainPaymentResponse(feesHash: feesHash, feesPercentage: feesPercentage, feesLockup: feesLockup, feesClaim: feesClaim, senderAmountSat: senderAmountSat, recipientAmountSat: recipientAmountSat, totalFees: totalFees)
+    }
+
+    static func dictionaryOf(prepareOnchainPaymentResponse: PrepareOnchainPaymentResponse) -> [String: Any?] {
+        return [
+            "feesHash": prepareOnchainPaymentResponse.feesHash,
+            "feesPercentage": prepareOnchainPaymentResponse.feesPercentage,
+            "feesLockup": prepareOnchainPaymentResponse.feesLockup,
+            "feesClaim": prepareOnchainPaymentResponse.feesClaim,
+            "senderAmountSat": prepareOnchainPaymentResponse.senderAmountSat,
+            "recipientAmountSat": prepareOnchainPaymentResponse.recipientAmountSat,
+            "totalFees": prepareOnchainPaymentResponse.totalFees,
+        ]
+    }
+
+    static func asPrepareOnchainPaymentResponseList(arr: [Any]) throws -> [PrepareOnchainPaymentResponse] {
+        var list = [PrepareOnchainPaymentResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var prepareOnchainPaymentResponse = try asPrepareOnchainPaymentResponse(prepareOnchainPaymentResponse: val)
+                list.append(prepareOnchainPaymentResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareOnchainPaymentResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(prepareOnchainPaymentResponseList: [PrepareOnchainPaymentResponse]) -> [Any] {
+        return prepareOnchainPaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(prepareOnchainPaymentResponse: v) }
+    }
+
+    static func asPrepareReceivePaymentRequest(prepareReceivePaymentRequest: [String: Any?]) throws -> PrepareReceivePaymentRequest {
+        guard let amountMsat = prepareReceivePaymentRequest["amountMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PrepareReceivePaymentRequest"))
+        }
+        var openingFeeParams: OpeningFeeParams?
+        if let openingFeeParamsTmp = prepareReceivePaymentRequest["openingFeeParams"] as? [String: Any?] {
+            openingFeeParams = try asOpeningFeeParams(openingFeeParams: openingFeeParamsTmp)
+        }
+
+        var expiry: UInt32?
+        if hasNonNilKey(data: prepareReceivePaymentRequest, key: "expiry") {
+            guard let expiryTmp = prepareReceivePaymentRequest["expiry"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "expiry"))
+            }
+            expiry = expiryTmp
+        }
+
+        return PrepareReceivePaymentRequest(amountMsat: amountMsat, openingFeeParams: openingFeeParams, expiry: expiry)
+    }
+
+    static func dictionaryOf(prepareReceivePaymentRequest: PrepareReceivePaymentRequest) -> [String: Any?] {
+        return [
+            "amountMsat": prepareReceivePaymentRequest.amountMsat,
+            "openingFeeParams": prepareReceivePaymentRequest.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: prepareReceivePaymentRequest.openingFeeParams!),
+            "expiry": prepareReceivePaymentRequest.expiry == nil ? nil : prepareReceivePaymentRequest.expiry,
+        ]
+    }
+
+    static func asPrepareReceivePaymentRequestList(arr: [Any]) throws -> [PrepareReceivePaymentRequest] {
+        var list = [PrepareReceivePaymentRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var prepareReceivePaymentRequest = try asPrepareReceivePaymentRequest(prepareReceivePaymentRequest: val)
+                list.append(prepareReceivePaymentRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareReceivePaymentRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(prepareReceivePaymentRequestList: [PrepareReceivePaymentRequest]) -> [Any] {
+        return prepareReceivePaymentRequestList.map { v -> [String: Any?] in return dictionaryOf(prepareReceivePaymentRequest: v) }
+    }
+
+    static func asPrepareReceivePaymentResponse(prepareReceivePaymentResponse: [String: Any?]) throws -> PrepareReceivePaymentResponse {
+        guard let amountMsat = prepareReceivePaymentResponse["amountMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PrepareReceivePaymentResponse"))
+        }
+        guard let openChannelNeeded = prepareReceivePaymentResponse["openChannelNeeded"] as? Bool else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "openChannelNeeded", typeName: "PrepareReceivePaymentResponse"))
         }
-        guard let senderAmountSat = prepareOnchainPaymentResponse["senderAmountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "senderAmountSat", typeName: "PrepareOnchainPaymentResponse"))
+        guard let openingFeeMsat = prepareReceivePaymentResponse["openingFeeMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "openingFeeMsat", typeName: "PrepareReceivePaymentResponse"))
         }
-        guard let recipientAmountSat = prepareOnchainPaymentResponse["recipientAmountSat"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "recipientAmountSat", typeName: "PrepareOnchainPaymentResponse"))
+        var openingFeeParams: OpeningFeeParams?
+        if let openingFeeParamsTmp = prepareReceivePaymentResponse["openingFeeParams"] as? [String: Any?] {
+            openingFeeParams = try asOpeningFeeParams(openingFeeParams: openingFeeParamsTmp)
         }
-        guard let totalFees = prepareOnchainPaymentResponse["totalFees"] as? UInt64 else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalFees", typeName: "PrepareOnchainPaymentResponse"))
+
+        guard let receivedAmountMsat = prepareReceivePaymentResponse["receivedAmountMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "receivedAmountMsat", typeName: "PrepareReceivePaymentResponse"))
         }
 
-        return PrepareOnchainPaymentResponse(feesHash: feesHash, feesPercentage: feesPercentage, feesLockup: feesLockup, feesClaim: feesClaim, senderAmountSat: senderAmountSat, recipientAmountSat: recipientAmountSat, totalFees: totalFees)
+        return PrepareReceivePaymentResponse(amountMsat: amountMsat, openChannelNeeded: openChannelNeeded, openingFeeMsat: openingFeeMsat, openingFeeParams: openingFeeParams, receivedAmountMsat: receivedAmountMsat)
     }
 
-    static func dictionaryOf(prepareOnchainPaymentResponse: PrepareOnchainPaymentResponse) -> [String: Any?] {
+    static func dictionaryOf(prepareReceivePaymentResponse: PrepareReceivePaymentResponse) -> [String: Any?] {
         return [
-            "feesHash": prepareOnchainPaymentResponse.feesHash,
-            "feesPercentage": prepareOnchainPaymentResponse.feesPercentage,
-            "feesLockup": prepareOnchainPaymentResponse.feesLockup,
-            "feesClaim": prepareOnchainPaymentResponse.feesClaim,
-            "senderAmountSat": prepareOnchainPaymentResponse.senderAmountSat,
-            "recipientAmountSat": prepareOnchainPaymentResponse.recipientAmountSat,
-            "totalFees": prepareOnchainPaymentResponse.totalFees,
+            "amountMsat": prepareReceivePaymentResponse.amountMsat,
+            "openChannelNeeded": prepareReceivePaymentResponse.openChannelNeeded,
+            "openingFeeMsat": prepareReceivePaymentResponse.openingFeeMsat,
+            "openingFeeParams": prepareReceivePaymentResponse.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: prepareReceivePaymentResponse.openingFeeParams!),
+            "receivedAmountMsat": prepareReceivePaymentResponse.receivedAmountMsat,
         ]
     }
 
-    static func asPrepareOnchainPaymentResponseList(arr: [Any]) throws -> [PrepareOnchainPaymentResponse] {
-        var list = [PrepareOnchainPaymentResponse]()
+    static func asPrepareReceivePaymentResponseList(arr: [Any]) throws -> [PrepareReceivePaymentResponse] {
+        var list = [PrepareReceivePaymentResponse]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var prepareOnchainPaymentResponse = try asPrepareOnchainPaymentResponse(prepareOnchainPaymentResponse: val)
-                list.append(prepareOnchainPaymentResponse)
+                var prepareReceivePaymentResponse = try asPrepareReceivePaymentResponse(prepareReceivePaymentResponse: val)
+                list.append(prepareReceivePaymentResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareOnchainPaymentResponse"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareReceivePaymentResponse"))
             }
         }
         return list
     }
 
-    static func arrayOf(prepareOnchainPaymentResponseList: [PrepareOnchainPaymentResponse]) -> [Any] {
-        return prepareOnchainPaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(prepareOnchainPaymentResponse: v) }
+    static func arrayOf(prepareReceivePaymentResponseList: [PrepareReceivePaymentResponse]) -> [Any] {
+        return prepareReceivePaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(prepareReceivePaymentResponse: v) }
     }
 
     static func asPrepareRedeemOnchainFundsRequest(prepareRedeemOnchainFundsRequest: [String: Any?]) throws -> PrepareRedeemOnchainFundsRequest {
@@ -2438,14 +6118,22 @@ enum BreezSDKMapper {
         guard let satPerVbyte = prepareRedeemOnchainFundsRequest["satPerVbyte"] as? UInt32 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "satPerVbyte", typeName: "PrepareRedeemOnchainFundsRequest"))
         }
+        var amountSat: Sat?
+        if hasNonNilKey(data: prepareRedeemOnchainFundsRequest, key: "amountSat") {
+            guard let amountSatTmp = prepareRedeemOnchainFundsRequest["amountSat"] as? Sat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountSat"))
+            }
+            amountSat = amountSatTmp
+        }
 
-        return PrepareRedeemOnchainFundsRequest(toAddress: toAddress, satPerVbyte: satPerVbyte)
+        return PrepareRedeemOnchainFundsRequest(toAddress: toAddress, satPerVbyte: satPerVbyte, amountSat: amountSat)
     }
 
     static func dictionaryOf(prepareRedeemOnchainFundsRequest: PrepareRedeemOnchainFundsRequest) -> [String: Any?] {
         return [
             "toAddress": prepareRedeemOnchainFundsRequest.toAddress,
             "satPerVbyte": prepareRedeemOnchainFundsRequest.satPerVbyte,
+            "amountSat": prepareRedeemOnchainFundsRequest.amountSat == nil ? nil : prepareRedeemOnchainFundsRequest.amountSat,
         ]
     }
 
@@ -2470,17 +6158,25 @@ enum BreezSDKMapper {
         guard let txWeight = prepareRedeemOnchainFundsResponse["txWeight"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txWeight", typeName: "PrepareRedeemOnchainFundsResponse"))
         }
-        guard let txFeeSat = prepareRedeemOnchainFundsResponse["txFeeSat"] as? UInt64 else {
+        guard let txFeeSat = prepareRedeemOnchainFundsResponse["txFeeSat"] as? Sat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txFeeSat", typeName: "PrepareRedeemOnchainFundsResponse"))
         }
+        guard let amountSat = prepareRedeemOnchainFundsResponse["amountSat"] as? Sat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "PrepareRedeemOnchainFundsResponse"))
+        }
+        guard let changeSat = prepareRedeemOnchainFundsResponse["changeSat"] as? Sat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "changeSat", typeName: "PrepareRedeemOnchainFundsResponse"))
+        }
 
-        return PrepareRedeemOnchainFundsResponse(txWeight: txWeight, txFeeSat: txFeeSat)
+        return PrepareRedeemOnchainFundsResponse(txWeight: txWeight, txFeeSat: txFeeSat, amountSat: amountSat, changeSat: changeSat)
     }
 
     static func dictionaryOf(prepareRedeemOnchainFundsResponse: PrepareRedeemOnchainFundsResponse) -> [String: Any?] {
         return [
             "txWeight": prepareRedeemOnchainFundsResponse.txWeight,
             "txFeeSat": prepareRedeemOnchainFundsResponse.txFeeSat,
+            "amountSat": prepareRedeemOnchainFundsResponse.amountSat,
+            "changeSat": prepareRedeemOnchainFundsResponse.changeSat,
         ]
     }
 
@@ -2518,8 +6214,15 @@ enum BreezSDKMapper {
             }
             unilateral = unilateralTmp
         }
+        var includeFeePreviews: Bool?
+        if hasNonNilKey(data: prepareRefundRequest, key: "includeFeePreviews") {
+            guard let includeFeePreviewsTmp = prepareRefundRequest["includeFeePreviews"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "includeFeePreviews"))
+            }
+            includeFeePreviews = includeFeePreviewsTmp
+        }
 
-        return PrepareRefundRequest(swapAddress: swapAddress, toAddress: toAddress, satPerVbyte: satPerVbyte, unilateral: unilateral)
+        return PrepareRefundRequest(swapAddress: swapAddress, toAddress: toAddress, satPerVbyte: satPerVbyte, unilateral: unilateral, includeFeePreviews: includeFeePreviews)
     }
 
     static func dictionaryOf(prepareRefundRequest: PrepareRefundRequest) -> [String: Any?] {
@@ -2528,6 +6231,7 @@ enum BreezSDKMapper {
             "toAddress": prepareRefundRequest.toAddress,
             "satPerVbyte": prepareRefundRequest.satPerVbyte,
             "unilateral": prepareRefundRequest.unilateral == nil ? nil : prepareRefundRequest.unilateral,
+            "includeFeePreviews": prepareRefundRequest.includeFeePreviews == nil ? nil : prepareRefundRequest.includeFeePreviews,
         ]
     }
 
@@ -2552,17 +6256,22 @@ enum BreezSDKMapper {
         guard let refundTxWeight = prepareRefundResponse["refundTxWeight"] as? UInt32 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundTxWeight", typeName: "PrepareRefundResponse"))
         }
-        guard let refundTxFeeSat = prepareRefundResponse["refundTxFeeSat"] as? UInt64 else {
+        guard let refundTxFeeSat = prepareRefundResponse["refundTxFeeSat"] as? Sat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundTxFeeSat", typeName: "PrepareRefundResponse"))
         }
+        guard let feePreviewsTmp = prepareRefundResponse["feePreviews"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feePreviews", typeName: "PrepareRefundResponse"))
+        }
+        let feePreviews = try asRefundFeePreviewList(arr: feePreviewsTmp)
 
-        return PrepareRefundResponse(refundTxWeight: refundTxWeight, refundTxFeeSat: refundTxFeeSat)
+        return PrepareRefundResponse(refundTxWeight: refundTxWeight, refundTxFeeSat: refundTxFeeSat, feePreviews: feePreviews)
     }
 
     static func dictionaryOf(prepareRefundResponse: PrepareRefundResponse) -> [String: Any?] {
         return [
             "refundTxWeight": prepareRefundResponse.refundTxWeight,
             "refundTxFeeSat": prepareRefundResponse.refundTxFeeSat,
+            "feePreviews": arrayOf(refundFeePreviewList: prepareRefundResponse.feePreviews),
         ]
     }
 
@@ -2583,6 +6292,285 @@ enum BreezSDKMapper {
         return prepareRefundResponseList.map { v -> [String: Any?] in return dictionaryOf(prepareRefundResponse: v) }
     }
 
+    static func asPrepareSendPaymentRequest(prepareSendPaymentRequest: [String: Any?]) throws -> PrepareSendPaymentRequest {
+        guard let bolt11 = prepareSendPaymentRequest["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "PrepareSendPaymentRequest"))
+        }
+        var amountMsat: MilliSat?
+        if hasNonNilKey(data: prepareSendPaymentRequest, key: "amountMsat") {
+            guard let amountMsatTmp = prepareSendPaymentRequest["amountMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+            }
+            amountMsat = amountMsatTmp
+        }
+
+        return PrepareSendPaymentRequest(bolt11: bolt11, amountMsat: amountMsat)
+    }
+
+    static func dictionaryOf(prepareSendPaymentRequest: PrepareSendPaymentRequest) -> [String: Any?] {
+        return [
+            "bolt11": prepareSendPaymentRequest.bolt11,
+            "amountMsat": prepareSendPaymentRequest.amountMsat == nil ? nil : prepareSendPaymentRequest.amountMsat,
+        ]
+    }
+
+    static func asPrepareSendPaymentRequestList(arr: [Any]) throws -> [PrepareSendPaymentRequest] {
+        var list = [PrepareSendPaymentRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var prepareSendPaymentRequest = try asPrepareSendPaymentRequest(prepareSendPaymentRequest: val)
+                list.append(prepareSendPaymentRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareSendPaymentRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(prepareSendPaymentRequestList: [PrepareSendPaymentRequest]) -> [Any] {
+        return prepareSendPaymentRequestList.map { v -> [String: Any?] in return dictionaryOf(prepareSendPaymentRequest: v) }
+    }
+
+    static func asPrepareSendPaymentResponse(prepareSendPaymentResponse: [String: Any?]) throws -> PrepareSendPaymentResponse {
+        guard let bolt11 = prepareSendPaymentResponse["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "PrepareSendPaymentResponse"))
+        }
+        guard let amountMsat = prepareSendPaymentResponse["amountMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "PrepareSendPaymentResponse"))
+        }
+        guard let feeMsat = prepareSendPaymentResponse["feeMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feeMsat", typeName: "PrepareSendPaymentResponse"))
+        }
+        guard let routeHops = prepareSendPaymentResponse["routeHops"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "routeHops", typeName: "PrepareSendPaymentResponse"))
+        }
+        guard let parts = prepareSendPaymentResponse["parts"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "parts", typeName: "PrepareSendPaymentResponse"))
+        }
+
+        return PrepareSendPaymentResponse(bolt11: bolt11, amountMsat: amountMsat, feeMsat: feeMsat, routeHops: routeHops, parts: parts)
+    }
+
+    static func dictionaryOf(prepareSendPaymentResponse: PrepareSendPaymentResponse) -> [String: Any?] {
+        return [
+            "bolt11": prepareSendPaymentResponse.bolt11,
+            "amountMsat": prepareSendPaymentResponse.amountMsat,
+            "feeMsat": prepareSendPaymentResponse.feeMsat,
+            "routeHops": prepareSendPaymentResponse.routeHops,
+            "parts": prepareSendPaymentResponse.parts,
+        ]
+    }
+
+    static func asPrepareSendPaymentResponseList(arr: [Any]) throws -> [PrepareSendPaymentResponse] {
+        var list = [PrepareSendPaymentResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var prepareSendPaymentResponse = try asPrepareSendPaymentResponse(prepareSendPaymentResponse: val)
+                list.append(prepareSendPaymentResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PrepareSendPaymentResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(prepareSendPaymentResponseList: [PrepareSendPaymentResponse]) -> [Any] {
+        return prepareSendPaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(prepareSendPaymentResponse: v) }
+    }
+
+    static func asProbePaymentRequest(probePaymentRequest: [String: Any?]) throws -> ProbePaymentRequest {
+        guard let destination = probePaymentRequest["destination"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "destination", typeName: "ProbePaymentRequest"))
+        }
+        var amountMsat: MilliSat?
+        if hasNonNilKey(data: probePaymentRequest, key: "amountMsat") {
+            guard let amountMsatTmp = probePaymentRequest["amountMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+            }
+            amountMsat = amountMsatTmp
+        }
+
+        return ProbePaymentRequest(destination: destination, amountMsat: amountMsat)
+    }
+
+    static func dictionaryOf(probePaymentRequest: ProbePaymentRequest) -> [String: Any?] {
+        return [
+            "destination": probePaymentRequest.destination,
+            "amountMsat": probePaymentRequest.amountMsat == nil ? nil : probePaymentRequest.amountMsat,
+        ]
+    }
+
+    static func asProbePaymentRequestList(arr: [Any]) throws -> [ProbePaymentRequest] {
+        var list = [ProbePaymentRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var probePaymentRequest = try asProbePaymentRequest(probePaymentRequest: val)
+                list.append(probePaymentRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ProbePaymentRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(probePaymentRequestList: [ProbePaymentRequest]) -> [Any] {
+        return probePaymentRequestList.map { v -> [String: Any?] in return dictionaryOf(probePaymentRequest: v) }
+    }
+
+    static func asProveAddressOwnershipRequest(proveAddressOwnershipRequest: [String: Any?]) throws -> ProveAddressOwnershipRequest {
+        guard let address = proveAddressOwnershipRequest["address"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "ProveAddressOwnershipRequest"))
+        }
+        guard let message = proveAddressOwnershipRequest["message"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "ProveAddressOwnershipRequest"))
+        }
+
+        return ProveAddressOwnershipRequest(address: address, message: message)
+    }
+
+    static func dictionaryOf(proveAddressOwnershipRequest: ProveAddressOwnershipRequest) -> [String: Any?] {
+        return [
+            "address": proveAddressOwnershipRequest.address,
+            "message": proveAddressOwnershipRequest.message,
+        ]
+    }
+
+    static func asProveAddressOwnershipRequestList(arr: [Any]) throws -> [ProveAddressOwnershipRequest] {
+        var list = [ProveAddressOwnershipRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var proveAddressOwnershipRequest = try asProveAddressOwnershipRequest(proveAddressOwnershipRequest: val)
+                list.append(proveAddressOwnershipRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ProveAddressOwnershipRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(proveAddressOwnershipRequestList: [ProveAddressOwnershipRequest]) -> [Any] {
+        return proveAddressOwnershipRequestList.map { v -> [String: Any?] in return dictionaryOf(proveAddressOwnershipRequest: v) }
+    }
+
+    static func asProveAddressOwnershipResponse(proveAddressOwnershipResponse: [String: Any?]) throws -> ProveAddressOwnershipResponse {
+        guard let signature = proveAddressOwnershipResponse["signature"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "ProveAddressOwnershipResponse"))
+        }
+
+        return ProveAddressOwnershipResponse(signature: signature)
+    }
+
+    static func dictionaryOf(proveAddressOwnershipResponse: ProveAddressOwnershipResponse) -> [String: Any?] {
+        return [
+            "signature": proveAddressOwnershipResponse.signature,
+        ]
+    }
+
+    static func asProveAddressOwnershipResponseList(arr: [Any]) throws -> [ProveAddressOwnershipResponse] {
+        var list = [ProveAddressOwnershipResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var proveAddressOwnershipResponse = try asProveAddressOwnershipResponse(proveAddressOwnershipResponse: val)
+                list.append(proveAddressOwnershipResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ProveAddressOwnershipResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(proveAddressOwnershipResponseList: [ProveAddressOwnershipResponse]) -> [Any] {
+        return proveAddressOwnershipResponseList.map { v -> [String: Any?] in return dictionaryOf(proveAddressOwnershipResponse: v) }
+    }
+
+    static func asProxyConfig(proxyConfig: [String: Any?]) throws -> ProxyConfig {
+        guard let address = proxyConfig["address"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "ProxyConfig"))
+        }
+        var username: String?
+        if hasNonNilKey(data: proxyConfig, key: "username") {
+            guard let usernameTmp = proxyConfig["username"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "username"))
+            }
+            username = usernameTmp
+        }
+        var password: String?
+        if hasNonNilKey(data: proxyConfig, key: "password") {
+            guard let passwordTmp = proxyConfig["password"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "password"))
+            }
+            password = passwordTmp
+        }
+
+        return ProxyConfig(address: address, username: username, password: password)
+    }
+
+    static func dictionaryOf(proxyConfig: ProxyConfig) -> [String: Any?] {
+        return [
+            "address": proxyConfig.address,
+            "username": proxyConfig.username == nil ? nil : proxyConfig.username,
+            "password": proxyConfig.password == nil ? nil : proxyConfig.password,
+        ]
+    }
+
+    static func asProxyConfigList(arr: [Any]) throws -> [ProxyConfig] {
+        var list = [ProxyConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var proxyConfig = try asProxyConfig(proxyConfig: val)
+                list.append(proxyConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ProxyConfig"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(proxyConfigList: [ProxyConfig]) -> [Any] {
+        return proxyConfigList.map { v -> [String: Any?] in return dictionaryOf(proxyConfig: v) }
+    }
+
+    static func asQueuePaymentRequest(queuePaymentRequest: [String: Any?]) throws -> QueuePaymentRequest {
+        guard let reqTmp = queuePaymentRequest["req"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "req", typeName: "QueuePaymentRequest"))
+        }
+        let req = try asSendPaymentRequest(sendPaymentRequest: reqTmp)
+
+        var expirySecs: UInt64?
+        if hasNonNilKey(data: queuePaymentRequest, key: "expirySecs") {
+            guard let expirySecsTmp = queuePaymentRequest["expirySecs"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "expirySecs"))
+            }
+            expirySecs = expirySecsTmp
+        }
+
+        return QueuePaymentRequest(req: req, expirySecs: expirySecs)
+    }
+
+    static func dictionaryOf(queuePaymentRequest: QueuePaymentRequest) -> [String: Any?] {
+        return [
+            "req": dictionaryOf(sendPaymentRequest: queuePaymentRequest.req),
+            "expirySecs": queuePaymentRequest.expirySecs == nil ? nil : queuePaymentRequest.expirySecs,
+        ]
+    }
+
+    static func asQueuePaymentRequestList(arr: [Any]) throws -> [QueuePaymentRequest] {
+        var list = [QueuePaymentRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var queuePaymentRequest = try asQueuePaymentRequest(queuePaymentRequest: val)
+                list.append(queuePaymentRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "QueuePaymentRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(queuePaymentRequestList: [QueuePaymentRequest]) -> [Any] {
+        return queuePaymentRequestList.map { v -> [String: Any?] in return dictionaryOf(queuePaymentRequest: v) }
+    }
+
     static func asRate(rate: [String: Any?]) throws -> Rate {
         guard let coin = rate["coin"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "coin", typeName: "Rate"))
@@ -2651,7 +6639,7 @@ enum BreezSDKMapper {
     }
 
     static func asReceivePaymentRequest(receivePaymentRequest: [String: Any?]) throws -> ReceivePaymentRequest {
-        guard let amountMsat = receivePaymentRequest["amountMsat"] as? UInt64 else {
+        guard let amountMsat = receivePaymentRequest["amountMsat"] as? MilliSat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "ReceivePaymentRequest"))
         }
         guard let description = receivePaymentRequest["description"] as? String else {
@@ -2690,8 +6678,26 @@ enum BreezSDKMapper {
             }
             cltv = cltvTmp
         }
+        var includeFallbackAddress: Bool?
+        if hasNonNilKey(data: receivePaymentRequest, key: "includeFallbackAddress") {
+            guard let includeFallbackAddressTmp = receivePaymentRequest["includeFallbackAddress"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "includeFallbackAddress"))
+            }
+            includeFallbackAddress = includeFallbackAddressTmp
+        }
+        var maxRouteHints: UInt32?
+        if hasNonNilKey(data: receivePaymentRequest, key: "maxRouteHints") {
+            guard let maxRouteHintsTmp = receivePaymentRequest["maxRouteHints"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "maxRouteHints"))
+            }
+            maxRouteHints = maxRouteHintsTmp
+        }
+        var fiatAmount: FiatAmount?
+        if let fiatAmountTmp = receivePaymentRequest["fiatAmount"] as? [String: Any?] {
+            fiatAmount = try asFiatAmount(fiatAmount: fiatAmountTmp)
+        }
 
-        return ReceivePaymentRequest(amountMsat: amountMsat, description: description, preimage: preimage, openingFeeParams: openingFeeParams, useDescriptionHash: useDescriptionHash, expiry: expiry, cltv: cltv)
+        return ReceivePaymentRequest(amountMsat: amountMsat, description: description, preimage: preimage, openingFeeParams: openingFeeParams, useDescriptionHash: useDescriptionHash, expiry: expiry, cltv: cltv, includeFallbackAddress: includeFallbackAddress, maxRouteHints: maxRouteHints, fiatAmount: fiatAmount)
     }
 
     static func dictionaryOf(receivePaymentRequest: ReceivePaymentRequest) -> [String: Any?] {
@@ -2703,6 +6709,9 @@ enum BreezSDKMapper {
             "useDescriptionHash": receivePaymentRequest.useDescriptionHash == nil ? nil : receivePaymentRequest.useDescriptionHash,
             "expiry": receivePaymentRequest.expiry == nil ? nil : receivePaymentRequest.expiry,
             "cltv": receivePaymentRequest.cltv == nil ? nil : receivePaymentRequest.cltv,
+            "includeFallbackAddress": receivePaymentRequest.includeFallbackAddress == nil ? nil : receivePaymentRequest.includeFallbackAddress,
+            "maxRouteHints": receivePaymentRequest.maxRouteHints == nil ? nil : receivePaymentRequest.maxRouteHints,
+            "fiatAmount": receivePaymentRequest.fiatAmount == nil ? nil : dictionaryOf(fiatAmount: receivePaymentRequest.fiatAmount!),
         ]
     }
 
@@ -2723,51 +6732,163 @@ enum BreezSDKMapper {
         return receivePaymentRequestList.map { v -> [String: Any?] in return dictionaryOf(receivePaymentRequest: v) }
     }
 
-    static func asReceivePaymentResponse(receivePaymentResponse: [String: Any?]) throws -> ReceivePaymentResponse {
-        guard let lnInvoiceTmp = receivePaymentResponse["lnInvoice"] as? [String: Any?] else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnInvoice", typeName: "ReceivePaymentResponse"))
+    static func asReceivePaymentResponse(receivePaymentResponse: [String: Any?]) throws -> ReceivePaymentResponse {
+        guard let lnInvoiceTmp = receivePaymentResponse["lnInvoice"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnInvoice", typeName: "ReceivePaymentResponse"))
+        }
+        let lnInvoice = try asLnInvoice(lnInvoice: lnInvoiceTmp)
+
+        var openingFeeParams: OpeningFeeParams?
+        if let openingFeeParamsTmp = receivePaymentResponse["openingFeeParams"] as? [String: Any?] {
+            openingFeeParams = try asOpeningFeeParams(openingFeeParams: openingFeeParamsTmp)
+        }
+
+        var openingFeeMsat: MilliSat?
+        if hasNonNilKey(data: receivePaymentResponse, key: "openingFeeMsat") {
+            guard let openingFeeMsatTmp = receivePaymentResponse["openingFeeMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "openingFeeMsat"))
+            }
+            openingFeeMsat = openingFeeMsatTmp
+        }
+        guard let bolt11Length = receivePaymentResponse["bolt11Length"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11Length", typeName: "ReceivePaymentResponse"))
+        }
+        var fiatAmount: FiatAmount?
+        if let fiatAmountTmp = receivePaymentResponse["fiatAmount"] as? [String: Any?] {
+            fiatAmount = try asFiatAmount(fiatAmount: fiatAmountTmp)
+        }
+
+        var fiatRate: Rate?
+        if let fiatRateTmp = receivePaymentResponse["fiatRate"] as? [String: Any?] {
+            fiatRate = try asRate(rate: fiatRateTmp)
+        }
+
+        return ReceivePaymentResponse(lnInvoice: lnInvoice, openingFeeParams: openingFeeParams, openingFeeMsat: openingFeeMsat, bolt11Length: bolt11Length, fiatAmount: fiatAmount, fiatRate: fiatRate)
+    }
+
+    static func dictionaryOf(receivePaymentResponse: ReceivePaymentResponse) -> [String: Any?] {
+        return [
+            "lnInvoice": dictionaryOf(lnInvoice: receivePaymentResponse.lnInvoice),
+            "openingFeeParams": receivePaymentResponse.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: receivePaymentResponse.openingFeeParams!),
+            "openingFeeMsat": receivePaymentResponse.openingFeeMsat == nil ? nil : receivePaymentResponse.openingFeeMsat,
+            "bolt11Length": receivePaymentResponse.bolt11Length,
+            "fiatAmount": receivePaymentResponse.fiatAmount == nil ? nil : dictionaryOf(fiatAmount: receivePaymentResponse.fiatAmount!),
+            "fiatRate": receivePaymentResponse.fiatRate == nil ? nil : dictionaryOf(rate: receivePaymentResponse.fiatRate!),
+        ]
+    }
+
+    static func asReceivePaymentResponseList(arr: [Any]) throws -> [ReceivePaymentResponse] {
+        var list = [ReceivePaymentResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var receivePaymentResponse = try asReceivePaymentResponse(receivePaymentResponse: val)
+                list.append(receivePaymentResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceivePaymentResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(receivePaymentResponseList: [ReceivePaymentResponse]) -> [Any] {
+        return receivePaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(receivePaymentResponse: v) }
+    }
+
+    static func asReceiveUnifiedRequest(receiveUnifiedRequest: [String: Any?]) throws -> ReceiveUnifiedRequest {
+        guard let amountSat = receiveUnifiedRequest["amountSat"] as? Sat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountSat", typeName: "ReceiveUnifiedRequest"))
+        }
+        guard let description = receiveUnifiedRequest["description"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "description", typeName: "ReceiveUnifiedRequest"))
+        }
+        var openingFeeParams: OpeningFeeParams?
+        if let openingFeeParamsTmp = receiveUnifiedRequest["openingFeeParams"] as? [String: Any?] {
+            openingFeeParams = try asOpeningFeeParams(openingFeeParams: openingFeeParamsTmp)
+        }
+
+        return ReceiveUnifiedRequest(amountSat: amountSat, description: description, openingFeeParams: openingFeeParams)
+    }
+
+    static func dictionaryOf(receiveUnifiedRequest: ReceiveUnifiedRequest) -> [String: Any?] {
+        return [
+            "amountSat": receiveUnifiedRequest.amountSat,
+            "description": receiveUnifiedRequest.description,
+            "openingFeeParams": receiveUnifiedRequest.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: receiveUnifiedRequest.openingFeeParams!),
+        ]
+    }
+
+    static func asReceiveUnifiedRequestList(arr: [Any]) throws -> [ReceiveUnifiedRequest] {
+        var list = [ReceiveUnifiedRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var receiveUnifiedRequest = try asReceiveUnifiedRequest(receiveUnifiedRequest: val)
+                list.append(receiveUnifiedRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceiveUnifiedRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(receiveUnifiedRequestList: [ReceiveUnifiedRequest]) -> [Any] {
+        return receiveUnifiedRequestList.map { v -> [String: Any?] in return dictionaryOf(receiveUnifiedRequest: v) }
+    }
+
+    static func asReceiveUnifiedResponse(receiveUnifiedResponse: [String: Any?]) throws -> ReceiveUnifiedResponse {
+        guard let bip21 = receiveUnifiedResponse["bip21"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bip21", typeName: "ReceiveUnifiedResponse"))
+        }
+        guard let swapInfoTmp = receiveUnifiedResponse["swapInfo"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapInfo", typeName: "ReceiveUnifiedResponse"))
+        }
+        let swapInfo = try asSwapInfo(swapInfo: swapInfoTmp)
+
+        guard let lnInvoiceTmp = receiveUnifiedResponse["lnInvoice"] as? [String: Any?] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnInvoice", typeName: "ReceiveUnifiedResponse"))
         }
         let lnInvoice = try asLnInvoice(lnInvoice: lnInvoiceTmp)
 
         var openingFeeParams: OpeningFeeParams?
-        if let openingFeeParamsTmp = receivePaymentResponse["openingFeeParams"] as? [String: Any?] {
+        if let openingFeeParamsTmp = receiveUnifiedResponse["openingFeeParams"] as? [String: Any?] {
             openingFeeParams = try asOpeningFeeParams(openingFeeParams: openingFeeParamsTmp)
         }
 
-        var openingFeeMsat: UInt64?
-        if hasNonNilKey(data: receivePaymentResponse, key: "openingFeeMsat") {
-            guard let openingFeeMsatTmp = receivePaymentResponse["openingFeeMsat"] as? UInt64 else {
+        var openingFeeMsat: MilliSat?
+        if hasNonNilKey(data: receiveUnifiedResponse, key: "openingFeeMsat") {
+            guard let openingFeeMsatTmp = receiveUnifiedResponse["openingFeeMsat"] as? MilliSat else {
                 throw SdkError.Generic(message: errUnexpectedValue(fieldName: "openingFeeMsat"))
             }
             openingFeeMsat = openingFeeMsatTmp
         }
 
-        return ReceivePaymentResponse(lnInvoice: lnInvoice, openingFeeParams: openingFeeParams, openingFeeMsat: openingFeeMsat)
+        return ReceiveUnifiedResponse(bip21: bip21, swapInfo: swapInfo, lnInvoice: lnInvoice, openingFeeParams: openingFeeParams, openingFeeMsat: openingFeeMsat)
     }
 
-    static func dictionaryOf(receivePaymentResponse: ReceivePaymentResponse) -> [String: Any?] {
+    static func dictionaryOf(receiveUnifiedResponse: ReceiveUnifiedResponse) -> [String: Any?] {
         return [
-            "lnInvoice": dictionaryOf(lnInvoice: receivePaymentResponse.lnInvoice),
-            "openingFeeParams": receivePaymentResponse.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: receivePaymentResponse.openingFeeParams!),
-            "openingFeeMsat": receivePaymentResponse.openingFeeMsat == nil ? nil : receivePaymentResponse.openingFeeMsat,
+            "bip21": receiveUnifiedResponse.bip21,
+            "swapInfo": dictionaryOf(swapInfo: receiveUnifiedResponse.swapInfo),
+            "lnInvoice": dictionaryOf(lnInvoice: receiveUnifiedResponse.lnInvoice),
+            "openingFeeParams": receiveUnifiedResponse.openingFeeParams == nil ? nil : dictionaryOf(openingFeeParams: receiveUnifiedResponse.openingFeeParams!),
+            "openingFeeMsat": receiveUnifiedResponse.openingFeeMsat == nil ? nil : receiveUnifiedResponse.openingFeeMsat,
         ]
     }
 
-    static func asReceivePaymentResponseList(arr: [Any]) throws -> [ReceivePaymentResponse] {
-        var list = [ReceivePaymentResponse]()
+    static func asReceiveUnifiedResponseList(arr: [Any]) throws -> [ReceiveUnifiedResponse] {
+        var list = [ReceiveUnifiedResponse]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var receivePaymentResponse = try asReceivePaymentResponse(receivePaymentResponse: val)
-                list.append(receivePaymentResponse)
+                var receiveUnifiedResponse = try asReceiveUnifiedResponse(receiveUnifiedResponse: val)
+                list.append(receiveUnifiedResponse)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceivePaymentResponse"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceiveUnifiedResponse"))
             }
         }
         return list
     }
 
-    static func arrayOf(receivePaymentResponseList: [ReceivePaymentResponse]) -> [Any] {
-        return receivePaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(receivePaymentResponse: v) }
+    static func arrayOf(receiveUnifiedResponseList: [ReceiveUnifiedResponse]) -> [Any] {
+        return receiveUnifiedResponseList.map { v -> [String: Any?] in return dictionaryOf(receiveUnifiedResponse: v) }
     }
 
     static func asRecommendedFees(recommendedFees: [String: Any?]) throws -> RecommendedFees {
@@ -2817,6 +6938,104 @@ enum BreezSDKMapper {
         return recommendedFeesList.map { v -> [String: Any?] in return dictionaryOf(recommendedFees: v) }
     }
 
+    static func asRecoveryBundle(recoveryBundle: [String: Any?]) throws -> RecoveryBundle {
+        guard let nodeId = recoveryBundle["nodeId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "RecoveryBundle"))
+        }
+        guard let networkTmp = recoveryBundle["network"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "network", typeName: "RecoveryBundle"))
+        }
+        let network = try asNetwork(network: networkTmp)
+
+        guard let instructions = recoveryBundle["instructions"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "instructions", typeName: "RecoveryBundle"))
+        }
+        guard let staticBackup = recoveryBundle["staticBackup"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "staticBackup", typeName: "RecoveryBundle"))
+        }
+        guard let channelsTmp = recoveryBundle["channels"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channels", typeName: "RecoveryBundle"))
+        }
+        let channels = try asMigrationChannelList(arr: channelsTmp)
+
+        guard let channelsBalanceMsat = recoveryBundle["channelsBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "channelsBalanceMsat", typeName: "RecoveryBundle"))
+        }
+        guard let onchainBalanceMsat = recoveryBundle["onchainBalanceMsat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "onchainBalanceMsat", typeName: "RecoveryBundle"))
+        }
+        guard let createdAt = recoveryBundle["createdAt"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "createdAt", typeName: "RecoveryBundle"))
+        }
+
+        return RecoveryBundle(nodeId: nodeId, network: network, instructions: instructions, staticBackup: staticBackup, channels: channels, channelsBalanceMsat: channelsBalanceMsat, onchainBalanceMsat: onchainBalanceMsat, createdAt: createdAt)
+    }
+
+    static func dictionaryOf(recoveryBundle: RecoveryBundle) -> [String: Any?] {
+        return [
+            "nodeId": recoveryBundle.nodeId,
+            "network": valueOf(network: recoveryBundle.network),
+            "instructions": recoveryBundle.instructions,
+            "staticBackup": recoveryBundle.staticBackup,
+            "channels": arrayOf(migrationChannelList: recoveryBundle.channels),
+            "channelsBalanceMsat": recoveryBundle.channelsBalanceMsat,
+            "onchainBalanceMsat": recoveryBundle.onchainBalanceMsat,
+            "createdAt": recoveryBundle.createdAt,
+        ]
+    }
+
+    static func asRecoveryBundleList(arr: [Any]) throws -> [RecoveryBundle] {
+        var list = [RecoveryBundle]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var recoveryBundle = try asRecoveryBundle(recoveryBundle: val)
+                list.append(recoveryBundle)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "RecoveryBundle"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(recoveryBundleList: [RecoveryBundle]) -> [Any] {
+        return recoveryBundleList.map { v -> [String: Any?] in return dictionaryOf(recoveryBundle: v) }
+    }
+
+    static func asRedeemLnurlWithdrawVoucherRequest(redeemLnurlWithdrawVoucherRequest: [String: Any?]) throws -> RedeemLnurlWithdrawVoucherRequest {
+        guard let id = redeemLnurlWithdrawVoucherRequest["id"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "id", typeName: "RedeemLnurlWithdrawVoucherRequest"))
+        }
+        guard let bolt11 = redeemLnurlWithdrawVoucherRequest["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "RedeemLnurlWithdrawVoucherRequest"))
+        }
+
+        return RedeemLnurlWithdrawVoucherRequest(id: id, bolt11: bolt11)
+    }
+
+    static func dictionaryOf(redeemLnurlWithdrawVoucherRequest: RedeemLnurlWithdrawVoucherRequest) -> [String: Any?] {
+        return [
+            "id": redeemLnurlWithdrawVoucherRequest.id,
+            "bolt11": redeemLnurlWithdrawVoucherRequest.bolt11,
+        ]
+    }
+
+    static func asRedeemLnurlWithdrawVoucherRequestList(arr: [Any]) throws -> [RedeemLnurlWithdrawVoucherRequest] {
+        var list = [RedeemLnurlWithdrawVoucherRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var redeemLnurlWithdrawVoucherRequest = try asRedeemLnurlWithdrawVoucherRequest(redeemLnurlWithdrawVoucherRequest: val)
+                list.append(redeemLnurlWithdrawVoucherRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "RedeemLnurlWithdrawVoucherRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(redeemLnurlWithdrawVoucherRequestList: [RedeemLnurlWithdrawVoucherRequest]) -> [Any] {
+        return redeemLnurlWithdrawVoucherRequestList.map { v -> [String: Any?] in return dictionaryOf(redeemLnurlWithdrawVoucherRequest: v) }
+    }
+
     static func asRedeemOnchainFundsRequest(redeemOnchainFundsRequest: [String: Any?]) throws -> RedeemOnchainFundsRequest {
         guard let toAddress = redeemOnchainFundsRequest["toAddress"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "toAddress", typeName: "RedeemOnchainFundsRequest"))
@@ -2824,14 +7043,22 @@ enum BreezSDKMapper {
         guard let satPerVbyte = redeemOnchainFundsRequest["satPerVbyte"] as? UInt32 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "satPerVbyte", typeName: "RedeemOnchainFundsRequest"))
         }
+        var amountSat: Sat?
+        if hasNonNilKey(data: redeemOnchainFundsRequest, key: "amountSat") {
+            guard let amountSatTmp = redeemOnchainFundsRequest["amountSat"] as? Sat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountSat"))
+            }
+            amountSat = amountSatTmp
+        }
 
-        return RedeemOnchainFundsRequest(toAddress: toAddress, satPerVbyte: satPerVbyte)
+        return RedeemOnchainFundsRequest(toAddress: toAddress, satPerVbyte: satPerVbyte, amountSat: amountSat)
     }
 
     static func dictionaryOf(redeemOnchainFundsRequest: RedeemOnchainFundsRequest) -> [String: Any?] {
         return [
             "toAddress": redeemOnchainFundsRequest.toAddress,
             "satPerVbyte": redeemOnchainFundsRequest.satPerVbyte,
+            "amountSat": redeemOnchainFundsRequest.amountSat == nil ? nil : redeemOnchainFundsRequest.amountSat,
         ]
     }
 
@@ -2883,6 +7110,47 @@ enum BreezSDKMapper {
         return redeemOnchainFundsResponseList.map { v -> [String: Any?] in return dictionaryOf(redeemOnchainFundsResponse: v) }
     }
 
+    static func asRefundFeePreview(refundFeePreview: [String: Any?]) throws -> RefundFeePreview {
+        guard let presetTmp = refundFeePreview["preset"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "preset", typeName: "RefundFeePreview"))
+        }
+        let preset = try asFeeratePreset(feeratePreset: presetTmp)
+
+        guard let satPerVbyte = refundFeePreview["satPerVbyte"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "satPerVbyte", typeName: "RefundFeePreview"))
+        }
+        guard let refundTxFeeSat = refundFeePreview["refundTxFeeSat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundTxFeeSat", typeName: "RefundFeePreview"))
+        }
+
+        return RefundFeePreview(preset: preset, satPerVbyte: satPerVbyte, refundTxFeeSat: refundTxFeeSat)
+    }
+
+    static func dictionaryOf(refundFeePreview: RefundFeePreview) -> [String: Any?] {
+        return [
+            "preset": valueOf(feeratePreset: refundFeePreview.preset),
+            "satPerVbyte": refundFeePreview.satPerVbyte,
+            "refundTxFeeSat": refundFeePreview.refundTxFeeSat,
+        ]
+    }
+
+    static func asRefundFeePreviewList(arr: [Any]) throws -> [RefundFeePreview] {
+        var list = [RefundFeePreview]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var refundFeePreview = try asRefundFeePreview(refundFeePreview: val)
+                list.append(refundFeePreview)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "RefundFeePreview"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(refundFeePreviewList: [RefundFeePreview]) -> [Any] {
+        return refundFeePreviewList.map { v -> [String: Any?] in return dictionaryOf(refundFeePreview: v) }
+    }
+
     static func asRefundRequest(refundRequest: [String: Any?]) throws -> RefundRequest {
         guard let swapAddress = refundRequest["swapAddress"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapAddress", typeName: "RefundRequest"))
@@ -3001,9 +7269,9 @@ enum BreezSDKMapper {
     }
 
     static func asReverseSwapFeesRequest(reverseSwapFeesRequest: [String: Any?]) throws -> ReverseSwapFeesRequest {
-        var sendAmountSat: UInt64?
+        var sendAmountSat: Sat?
         if hasNonNilKey(data: reverseSwapFeesRequest, key: "sendAmountSat") {
-            guard let sendAmountSatTmp = reverseSwapFeesRequest["sendAmountSat"] as? UInt64 else {
+            guard let sendAmountSatTmp = reverseSwapFeesRequest["sendAmountSat"] as? Sat else {
                 throw SdkError.Generic(message: errUnexpectedValue(fieldName: "sendAmountSat"))
             }
             sendAmountSat = sendAmountSatTmp
@@ -3072,7 +7340,12 @@ enum BreezSDKMapper {
         }
         let status = try asReverseSwapStatus(reverseSwapStatus: statusTmp)
 
-        return ReverseSwapInfo(id: id, claimPubkey: claimPubkey, lockupTxid: lockupTxid, claimTxid: claimTxid, onchainAmountSat: onchainAmountSat, status: status)
+        guard let protocolTmp = reverseSwapInfo["protocol"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "protocol", typeName: "ReverseSwapInfo"))
+        }
+        let protocol = try asReverseSwapProtocol(reverseSwapProtocol: protocolTmp)
+
+        return ReverseSwapInfo(id: id, claimPubkey: claimPubkey, lockupTxid: lockupTxid, claimTxid: claimTxid, onchainAmountSat: onchainAmountSat, status: status, protocol: protocol)
     }
 
     static func dictionaryOf(reverseSwapInfo: ReverseSwapInfo) -> [String: Any?] {
@@ -3083,6 +7356,7 @@ enum BreezSDKMapper {
             "claimTxid": reverseSwapInfo.claimTxid == nil ? nil : reverseSwapInfo.claimTxid,
             "onchainAmountSat": reverseSwapInfo.onchainAmountSat,
             "status": valueOf(reverseSwapStatus: reverseSwapInfo.status),
+            "protocol": valueOf(reverseSwapProtocol: reverseSwapInfo.protocol),
         ]
     }
 
@@ -3257,16 +7531,77 @@ enum BreezSDKMapper {
         return routeHintHopList.map { v -> [String: Any?] in return dictionaryOf(routeHintHop: v) }
     }
 
+    static func asSdkInfo(sdkInfo: [String: Any?]) throws -> SdkInfo {
+        guard let version = sdkInfo["version"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "version", typeName: "SdkInfo"))
+        }
+        guard let supportedLuds = sdkInfo["supportedLuds"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "supportedLuds", typeName: "SdkInfo"))
+        }
+        guard let swapInScriptVersions = sdkInfo["swapInScriptVersions"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapInScriptVersions", typeName: "SdkInfo"))
+        }
+        guard let reverseSwapProtocolsTmp = sdkInfo["reverseSwapProtocols"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reverseSwapProtocols", typeName: "SdkInfo"))
+        }
+        let reverseSwapProtocols = try asReverseSwapProtocolList(arr: reverseSwapProtocolsTmp)
+
+        guard let lspProtocols = sdkInfo["lspProtocols"] as? [String] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lspProtocols", typeName: "SdkInfo"))
+        }
+        guard let backupSchemaVersion = sdkInfo["backupSchemaVersion"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "backupSchemaVersion", typeName: "SdkInfo"))
+        }
+        guard let minBackupSchemaVersion = sdkInfo["minBackupSchemaVersion"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minBackupSchemaVersion", typeName: "SdkInfo"))
+        }
+
+        return SdkInfo(version: version, supportedLuds: supportedLuds, swapInScriptVersions: swapInScriptVersions, reverseSwapProtocols: reverseSwapProtocols, lspProtocols: lspProtocols, backupSchemaVersion: backupSchemaVersion, minBackupSchemaVersion: minBackupSchemaVersion)
+    }
+
+    static func dictionaryOf(sdkInfo: SdkInfo) -> [String: Any?] {
+        return [
+            "version": sdkInfo.version,
+            "supportedLuds": sdkInfo.supportedLuds,
+            "swapInScriptVersions": sdkInfo.swapInScriptVersions,
+            "reverseSwapProtocols": arrayOf(reverseSwapProtocolList: sdkInfo.reverseSwapProtocols),
+            "lspProtocols": sdkInfo.lspProtocols,
+            "backupSchemaVersion": sdkInfo.backupSchemaVersion,
+            "minBackupSchemaVersion": sdkInfo.minBackupSchemaVersion,
+        ]
+    }
+
+    static func asSdkInfoList(arr: [Any]) throws -> [SdkInfo] {
+        var list = [SdkInfo]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var sdkInfo = try asSdkInfo(sdkInfo: val)
+                list.append(sdkInfo)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SdkInfo"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(sdkInfoList: [SdkInfo]) -> [Any] {
+        return sdkInfoList.map { v -> [String: Any?] in return dictionaryOf(sdkInfo: v) }
+    }
+
     static func asSendPaymentRequest(sendPaymentRequest: [String: Any?]) throws -> SendPaymentRequest {
         guard let bolt11 = sendPaymentRequest["bolt11"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "SendPaymentRequest"))
         }
-        guard let useTrampoline = sendPaymentRequest["useTrampoline"] as? Bool else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "useTrampoline", typeName: "SendPaymentRequest"))
+        var useTrampoline: Bool?
+        if hasNonNilKey(data: sendPaymentRequest, key: "useTrampoline") {
+            guard let useTrampolineTmp = sendPaymentRequest["useTrampoline"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "useTrampoline"))
+            }
+            useTrampoline = useTrampolineTmp
         }
-        var amountMsat: UInt64?
+        var amountMsat: MilliSat?
         if hasNonNilKey(data: sendPaymentRequest, key: "amountMsat") {
-            guard let amountMsatTmp = sendPaymentRequest["amountMsat"] as? UInt64 else {
+            guard let amountMsatTmp = sendPaymentRequest["amountMsat"] as? MilliSat else {
                 throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
             }
             amountMsat = amountMsatTmp
@@ -3278,16 +7613,64 @@ enum BreezSDKMapper {
             }
             label = labelTmp
         }
+        var routeHintIndex: UInt32?
+        if hasNonNilKey(data: sendPaymentRequest, key: "routeHintIndex") {
+            guard let routeHintIndexTmp = sendPaymentRequest["routeHintIndex"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "routeHintIndex"))
+            }
+            routeHintIndex = routeHintIndexTmp
+        }
+        var paymentTimeoutSec: UInt32?
+        if hasNonNilKey(data: sendPaymentRequest, key: "paymentTimeoutSec") {
+            guard let paymentTimeoutSecTmp = sendPaymentRequest["paymentTimeoutSec"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "paymentTimeoutSec"))
+            }
+            paymentTimeoutSec = paymentTimeoutSecTmp
+        }
+        var maxfeePercent: Double?
+        if hasNonNilKey(data: sendPaymentRequest, key: "maxfeePercent") {
+            guard let maxfeePercentTmp = sendPaymentRequest["maxfeePercent"] as? Double else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "maxfeePercent"))
+            }
+            maxfeePercent = maxfeePercentTmp
+        }
+        var exemptfeeMsat: MilliSat?
+        if hasNonNilKey(data: sendPaymentRequest, key: "exemptfeeMsat") {
+            guard let exemptfeeMsatTmp = sendPaymentRequest["exemptfeeMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "exemptfeeMsat"))
+            }
+            exemptfeeMsat = exemptfeeMsatTmp
+        }
+        var cancelToken: String?
+        if hasNonNilKey(data: sendPaymentRequest, key: "cancelToken") {
+            guard let cancelTokenTmp = sendPaymentRequest["cancelToken"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "cancelToken"))
+            }
+            cancelToken = cancelTokenTmp
+        }
+        var skipDenylist: Bool?
+        if hasNonNilKey(data: sendPaymentRequest, key: "skipDenylist") {
+            guard let skipDenylistTmp = sendPaymentRequest["skipDenylist"] as? Bool else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "skipDenylist"))
+            }
+            skipDenylist = skipDenylistTmp
+        }
 
-        return SendPaymentRequest(bolt11: bolt11, useTrampoline: useTrampoline, amountMsat: amountMsat, label: label)
+        return SendPaymentRequest(bolt11: bolt11, useTrampoline: useTrampoline, amountMsat: amountMsat, label: label, routeHintIndex: routeHintIndex, paymentTimeoutSec: paymentTimeoutSec, maxfeePercent: maxfeePercent, exemptfeeMsat: exemptfeeMsat, cancelToken: cancelToken, skipDenylist: skipDenylist)
     }
 
     static func dictionaryOf(sendPaymentRequest: SendPaymentRequest) -> [String: Any?] {
         return [
             "bolt11": sendPaymentRequest.bolt11,
-            "useTrampoline": sendPaymentRequest.useTrampoline,
+            "useTrampoline": sendPaymentRequest.useTrampoline == nil ? nil : sendPaymentRequest.useTrampoline,
             "amountMsat": sendPaymentRequest.amountMsat == nil ? nil : sendPaymentRequest.amountMsat,
             "label": sendPaymentRequest.label == nil ? nil : sendPaymentRequest.label,
+            "routeHintIndex": sendPaymentRequest.routeHintIndex == nil ? nil : sendPaymentRequest.routeHintIndex,
+            "paymentTimeoutSec": sendPaymentRequest.paymentTimeoutSec == nil ? nil : sendPaymentRequest.paymentTimeoutSec,
+            "maxfeePercent": sendPaymentRequest.maxfeePercent == nil ? nil : sendPaymentRequest.maxfeePercent,
+            "exemptfeeMsat": sendPaymentRequest.exemptfeeMsat == nil ? nil : sendPaymentRequest.exemptfeeMsat,
+            "cancelToken": sendPaymentRequest.cancelToken == nil ? nil : sendPaymentRequest.cancelToken,
+            "skipDenylist": sendPaymentRequest.skipDenylist == nil ? nil : sendPaymentRequest.skipDenylist,
         ]
     }
 
@@ -3314,12 +7697,18 @@ enum BreezSDKMapper {
         }
         let payment = try asPayment(payment: paymentTmp)
 
-        return SendPaymentResponse(payment: payment)
+        var partnerFee: PartnerFeeDetails?
+        if let partnerFeeTmp = sendPaymentResponse["partnerFee"] as? [String: Any?] {
+            partnerFee = try asPartnerFeeDetails(partnerFeeDetails: partnerFeeTmp)
+        }
+
+        return SendPaymentResponse(payment: payment, partnerFee: partnerFee)
     }
 
     static func dictionaryOf(sendPaymentResponse: SendPaymentResponse) -> [String: Any?] {
         return [
             "payment": dictionaryOf(payment: sendPaymentResponse.payment),
+            "partnerFee": sendPaymentResponse.partnerFee == nil ? nil : dictionaryOf(partnerFeeDetails: sendPaymentResponse.partnerFee!),
         ]
     }
 
@@ -3340,11 +7729,93 @@ enum BreezSDKMapper {
         return sendPaymentResponseList.map { v -> [String: Any?] in return dictionaryOf(sendPaymentResponse: v) }
     }
 
+    static func asSendPaymentsRequest(sendPaymentsRequest: [String: Any?]) throws -> SendPaymentsRequest {
+        guard let paymentsTmp = sendPaymentsRequest["payments"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payments", typeName: "SendPaymentsRequest"))
+        }
+        let payments = try asSendPaymentRequestList(arr: paymentsTmp)
+
+        var maxConcurrency: UInt32?
+        if hasNonNilKey(data: sendPaymentsRequest, key: "maxConcurrency") {
+            guard let maxConcurrencyTmp = sendPaymentsRequest["maxConcurrency"] as? UInt32 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "maxConcurrency"))
+            }
+            maxConcurrency = maxConcurrencyTmp
+        }
+
+        return SendPaymentsRequest(payments: payments, maxConcurrency: maxConcurrency)
+    }
+
+    static func dictionaryOf(sendPaymentsRequest: SendPaymentsRequest) -> [String: Any?] {
+        return [
+            "payments": arrayOf(sendPaymentRequestList: sendPaymentsRequest.payments),
+            "maxConcurrency": sendPaymentsRequest.maxConcurrency == nil ? nil : sendPaymentsRequest.maxConcurrency,
+        ]
+    }
+
+    static func asSendPaymentsRequestList(arr: [Any]) throws -> [SendPaymentsRequest] {
+        var list = [SendPaymentsRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var sendPaymentsRequest = try asSendPaymentsRequest(sendPaymentsRequest: val)
+                list.append(sendPaymentsRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SendPaymentsRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(sendPaymentsRequestList: [SendPaymentsRequest]) -> [Any] {
+        return sendPaymentsRequestList.map { v -> [String: Any?] in return dictionaryOf(sendPaymentsRequest: v) }
+    }
+
+    static func asSendPaymentsResponse(sendPaymentsResponse: [String: Any?]) throws -> SendPaymentsResponse {
+        guard let resultsTmp = sendPaymentsResponse["results"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "results", typeName: "SendPaymentsResponse"))
+        }
+        let results = try asBatchPaymentResultList(arr: resultsTmp)
+
+        guard let succeeded = sendPaymentsResponse["succeeded"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "succeeded", typeName: "SendPaymentsResponse"))
+        }
+        guard let failed = sendPaymentsResponse["failed"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "failed", typeName: "SendPaymentsResponse"))
+        }
+
+        return SendPaymentsResponse(results: results, succeeded: succeeded, failed: failed)
+    }
+
+    static func dictionaryOf(sendPaymentsResponse: SendPaymentsResponse) -> [String: Any?] {
+        return [
+            "results": arrayOf(batchPaymentResultList: sendPaymentsResponse.results),
+            "succeeded": sendPaymentsResponse.succeeded,
+            "failed": sendPaymentsResponse.failed,
+        ]
+    }
+
+    static func asSendPaymentsResponseList(arr: [Any]) throws -> [SendPaymentsResponse] {
+        var list = [SendPaymentsResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var sendPaymentsResponse = try asSendPaymentsResponse(sendPaymentsResponse: val)
+                list.append(sendPaymentsResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SendPaymentsResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(sendPaymentsResponseList: [SendPaymentsResponse]) -> [Any] {
+        return sendPaymentsResponseList.map { v -> [String: Any?] in return dictionaryOf(sendPaymentsResponse: v) }
+    }
+
     static func asSendSpontaneousPaymentRequest(sendSpontaneousPaymentRequest: [String: Any?]) throws -> SendSpontaneousPaymentRequest {
         guard let nodeId = sendSpontaneousPaymentRequest["nodeId"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "SendSpontaneousPaymentRequest"))
         }
-        guard let amountMsat = sendSpontaneousPaymentRequest["amountMsat"] as? UInt64 else {
+        guard let amountMsat = sendSpontaneousPaymentRequest["amountMsat"] as? MilliSat else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "SendSpontaneousPaymentRequest"))
         }
         var extraTlvs: [TlvEntry]?
@@ -3426,61 +7897,218 @@ enum BreezSDKMapper {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "message", typeName: "SignMessageRequest"))
         }
 
-        return SignMessageRequest(message: message)
+        return SignMessageRequest(message: message)
+    }
+
+    static func dictionaryOf(signMessageRequest: SignMessageRequest) -> [String: Any?] {
+        return [
+            "message": signMessageRequest.message,
+        ]
+    }
+
+    static func asSignMessageRequestList(arr: [Any]) throws -> [SignMessageRequest] {
+        var list = [SignMessageRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var signMessageRequest = try asSignMessageRequest(signMessageRequest: val)
+                list.append(signMessageRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SignMessageRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(signMessageRequestList: [SignMessageRequest]) -> [Any] {
+        return signMessageRequestList.map { v -> [String: Any?] in return dictionaryOf(signMessageRequest: v) }
+    }
+
+    static func asSignMessageResponse(signMessageResponse: [String: Any?]) throws -> SignMessageResponse {
+        guard let signature = signMessageResponse["signature"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "SignMessageResponse"))
+        }
+
+        return SignMessageResponse(signature: signature)
+    }
+
+    static func dictionaryOf(signMessageResponse: SignMessageResponse) -> [String: Any?] {
+        return [
+            "signature": signMessageResponse.signature,
+        ]
+    }
+
+    static func asSignMessageResponseList(arr: [Any]) throws -> [SignMessageResponse] {
+        var list = [SignMessageResponse]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var signMessageResponse = try asSignMessageResponse(signMessageResponse: val)
+                list.append(signMessageResponse)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SignMessageResponse"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(signMessageResponseList: [SignMessageResponse]) -> [Any] {
+        return signMessageResponseList.map { v -> [String: Any?] in return dictionaryOf(signMessageResponse: v) }
+    }
+
+    static func asSnapshotChange(snapshotChange: [String: Any?]) throws -> SnapshotChange {
+        guard let path = snapshotChange["path"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "path", typeName: "SnapshotChange"))
+        }
+        var before: String?
+        if hasNonNilKey(data: snapshotChange, key: "before") {
+            guard let beforeTmp = snapshotChange["before"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "before"))
+            }
+            before = beforeTmp
+        }
+        var after: String?
+        if hasNonNilKey(data: snapshotChange, key: "after") {
+            guard let afterTmp = snapshotChange["after"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "after"))
+            }
+            after = afterTmp
+        }
+
+        return SnapshotChange(path: path, before: before, after: after)
+    }
+
+    static func dictionaryOf(snapshotChange: SnapshotChange) -> [String: Any?] {
+        return [
+            "path": snapshotChange.path,
+            "before": snapshotChange.before == nil ? nil : snapshotChange.before,
+            "after": snapshotChange.after == nil ? nil : snapshotChange.after,
+        ]
+    }
+
+    static func asSnapshotChangeList(arr: [Any]) throws -> [SnapshotChange] {
+        var list = [SnapshotChange]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var snapshotChange = try asSnapshotChange(snapshotChange: val)
+                list.append(snapshotChange)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SnapshotChange"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(snapshotChangeList: [SnapshotChange]) -> [Any] {
+        return snapshotChangeList.map { v -> [String: Any?] in return dictionaryOf(snapshotChange: v) }
+    }
+
+    static func asSpendPolicy(spendPolicy: [String: Any?]) throws -> SpendPolicy {
+        var dailyLimitMsat: UInt64?
+        if hasNonNilKey(data: spendPolicy, key: "dailyLimitMsat") {
+            guard let dailyLimitMsatTmp = spendPolicy["dailyLimitMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "dailyLimitMsat"))
+            }
+            dailyLimitMsat = dailyLimitMsatTmp
+        }
+        var perPaymentLimitMsat: UInt64?
+        if hasNonNilKey(data: spendPolicy, key: "perPaymentLimitMsat") {
+            guard let perPaymentLimitMsatTmp = spendPolicy["perPaymentLimitMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "perPaymentLimitMsat"))
+            }
+            perPaymentLimitMsat = perPaymentLimitMsatTmp
+        }
+        var allowedDestinations: [String]?
+        if hasNonNilKey(data: spendPolicy, key: "allowedDestinations") {
+            guard let allowedDestinationsTmp = spendPolicy["allowedDestinations"] as? [String] else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "allowedDestinations"))
+            }
+            allowedDestinations = allowedDestinationsTmp
+        }
+
+        return SpendPolicy(dailyLimitMsat: dailyLimitMsat, perPaymentLimitMsat: perPaymentLimitMsat, allowedDestinations: allowedDestinations)
     }
 
-    static func dictionaryOf(signMessageRequest: SignMessageRequest) -> [String: Any?] {
+    static func dictionaryOf(spendPolicy: SpendPolicy) -> [String: Any?] {
         return [
-            "message": signMessageRequest.message,
+            "dailyLimitMsat": spendPolicy.dailyLimitMsat == nil ? nil : spendPolicy.dailyLimitMsat,
+            "perPaymentLimitMsat": spendPolicy.perPaymentLimitMsat == nil ? nil : spendPolicy.perPaymentLimitMsat,
+            "allowedDestinations": spendPolicy.allowedDestinations == nil ? nil : spendPolicy.allowedDestinations,
         ]
     }
 
-    static func asSignMessageRequestList(arr: [Any]) throws -> [SignMessageRequest] {
-        var list = [SignMessageRequest]()
+    static func asSpendPolicyList(arr: [Any]) throws -> [SpendPolicy] {
+        var list = [SpendPolicy]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var signMessageRequest = try asSignMessageRequest(signMessageRequest: val)
-                list.append(signMessageRequest)
+                var spendPolicy = try asSpendPolicy(spendPolicy: val)
+                list.append(spendPolicy)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SignMessageRequest"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SpendPolicy"))
             }
         }
         return list
     }
 
-    static func arrayOf(signMessageRequestList: [SignMessageRequest]) -> [Any] {
-        return signMessageRequestList.map { v -> [String: Any?] in return dictionaryOf(signMessageRequest: v) }
+    static func arrayOf(spendPolicyList: [SpendPolicy]) -> [Any] {
+        return spendPolicyList.map { v -> [String: Any?] in return dictionaryOf(spendPolicy: v) }
     }
 
-    static func asSignMessageResponse(signMessageResponse: [String: Any?]) throws -> SignMessageResponse {
-        guard let signature = signMessageResponse["signature"] as? String else {
-            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "signature", typeName: "SignMessageResponse"))
+    static func asSpontaneousPaymentMetadata(spontaneousPaymentMetadata: [String: Any?]) throws -> SpontaneousPaymentMetadata {
+        var message: String?
+        if hasNonNilKey(data: spontaneousPaymentMetadata, key: "message") {
+            guard let messageTmp = spontaneousPaymentMetadata["message"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "message"))
+            }
+            message = messageTmp
+        }
+        var senderPubkey: String?
+        if hasNonNilKey(data: spontaneousPaymentMetadata, key: "senderPubkey") {
+            guard let senderPubkeyTmp = spontaneousPaymentMetadata["senderPubkey"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "senderPubkey"))
+            }
+            senderPubkey = senderPubkeyTmp
+        }
+        var senderName: String?
+        if hasNonNilKey(data: spontaneousPaymentMetadata, key: "senderName") {
+            guard let senderNameTmp = spontaneousPaymentMetadata["senderName"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "senderName"))
+            }
+            senderName = senderNameTmp
+        }
+        var boostagram: String?
+        if hasNonNilKey(data: spontaneousPaymentMetadata, key: "boostagram") {
+            guard let boostagramTmp = spontaneousPaymentMetadata["boostagram"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "boostagram"))
+            }
+            boostagram = boostagramTmp
         }
 
-        return SignMessageResponse(signature: signature)
+        return SpontaneousPaymentMetadata(message: message, senderPubkey: senderPubkey, senderName: senderName, boostagram: boostagram)
     }
 
-    static func dictionaryOf(signMessageResponse: SignMessageResponse) -> [String: Any?] {
+    static func dictionaryOf(spontaneousPaymentMetadata: SpontaneousPaymentMetadata) -> [String: Any?] {
         return [
-            "signature": signMessageResponse.signature,
+            "message": spontaneousPaymentMetadata.message == nil ? nil : spontaneousPaymentMetadata.message,
+            "senderPubkey": spontaneousPaymentMetadata.senderPubkey == nil ? nil : spontaneousPaymentMetadata.senderPubkey,
+            "senderName": spontaneousPaymentMetadata.senderName == nil ? nil : spontaneousPaymentMetadata.senderName,
+            "boostagram": spontaneousPaymentMetadata.boostagram == nil ? nil : spontaneousPaymentMetadata.boostagram,
         ]
     }
 
-    static func asSignMessageResponseList(arr: [Any]) throws -> [SignMessageResponse] {
-        var list = [SignMessageResponse]()
+    static func asSpontaneousPaymentMetadataList(arr: [Any]) throws -> [SpontaneousPaymentMetadata] {
+        var list = [SpontaneousPaymentMetadata]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var signMessageResponse = try asSignMessageResponse(signMessageResponse: val)
-                list.append(signMessageResponse)
+                var spontaneousPaymentMetadata = try asSpontaneousPaymentMetadata(spontaneousPaymentMetadata: val)
+                list.append(spontaneousPaymentMetadata)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "SignMessageResponse"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SpontaneousPaymentMetadata"))
             }
         }
         return list
     }
 
-    static func arrayOf(signMessageResponseList: [SignMessageResponse]) -> [Any] {
-        return signMessageResponseList.map { v -> [String: Any?] in return dictionaryOf(signMessageResponse: v) }
+    static func arrayOf(spontaneousPaymentMetadataList: [SpontaneousPaymentMetadata]) -> [Any] {
+        return spontaneousPaymentMetadataList.map { v -> [String: Any?] in return dictionaryOf(spontaneousPaymentMetadata: v) }
     }
 
     static func asStaticBackupRequest(staticBackupRequest: [String: Any?]) throws -> StaticBackupRequest {
@@ -3549,6 +8177,84 @@ enum BreezSDKMapper {
         return staticBackupResponseList.map { v -> [String: Any?] in return dictionaryOf(staticBackupResponse: v) }
     }
 
+    static func asStaticLnurlPay(staticLnurlPay: [String: Any?]) throws -> StaticLnurlPay {
+        guard let lnurl = staticLnurlPay["lnurl"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lnurl", typeName: "StaticLnurlPay"))
+        }
+        guard let metadata = staticLnurlPay["metadata"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "metadata", typeName: "StaticLnurlPay"))
+        }
+        guard let payRequest = staticLnurlPay["payRequest"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payRequest", typeName: "StaticLnurlPay"))
+        }
+
+        return StaticLnurlPay(lnurl: lnurl, metadata: metadata, payRequest: payRequest)
+    }
+
+    static func dictionaryOf(staticLnurlPay: StaticLnurlPay) -> [String: Any?] {
+        return [
+            "lnurl": staticLnurlPay.lnurl,
+            "metadata": staticLnurlPay.metadata,
+            "payRequest": staticLnurlPay.payRequest,
+        ]
+    }
+
+    static func asStaticLnurlPayList(arr: [Any]) throws -> [StaticLnurlPay] {
+        var list = [StaticLnurlPay]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var staticLnurlPay = try asStaticLnurlPay(staticLnurlPay: val)
+                list.append(staticLnurlPay)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "StaticLnurlPay"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(staticLnurlPayList: [StaticLnurlPay]) -> [Any] {
+        return staticLnurlPayList.map { v -> [String: Any?] in return dictionaryOf(staticLnurlPay: v) }
+    }
+
+    static func asStaticLnurlPayCallbackRequest(staticLnurlPayCallbackRequest: [String: Any?]) throws -> StaticLnurlPayCallbackRequest {
+        guard let amountMsat = staticLnurlPayCallbackRequest["amountMsat"] as? MilliSat else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "StaticLnurlPayCallbackRequest"))
+        }
+        var comment: String?
+        if hasNonNilKey(data: staticLnurlPayCallbackRequest, key: "comment") {
+            guard let commentTmp = staticLnurlPayCallbackRequest["comment"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "comment"))
+            }
+            comment = commentTmp
+        }
+
+        return StaticLnurlPayCallbackRequest(amountMsat: amountMsat, comment: comment)
+    }
+
+    static func dictionaryOf(staticLnurlPayCallbackRequest: StaticLnurlPayCallbackRequest) -> [String: Any?] {
+        return [
+            "amountMsat": staticLnurlPayCallbackRequest.amountMsat,
+            "comment": staticLnurlPayCallbackRequest.comment == nil ? nil : staticLnurlPayCallbackRequest.comment,
+        ]
+    }
+
+    static func asStaticLnurlPayCallbackRequestList(arr: [Any]) throws -> [StaticLnurlPayCallbackRequest] {
+        var list = [StaticLnurlPayCallbackRequest]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var staticLnurlPayCallbackRequest = try asStaticLnurlPayCallbackRequest(staticLnurlPayCallbackRequest: val)
+                list.append(staticLnurlPayCallbackRequest)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "StaticLnurlPayCallbackRequest"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(staticLnurlPayCallbackRequestList: [StaticLnurlPayCallbackRequest]) -> [Any] {
+        return staticLnurlPayCallbackRequestList.map { v -> [String: Any?] in return dictionaryOf(staticLnurlPayCallbackRequest: v) }
+    }
+
     static func asSwapInfo(swapInfo: [String: Any?]) throws -> SwapInfo {
         guard let bitcoinAddress = swapInfo["bitcoinAddress"] as? String else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bitcoinAddress", typeName: "SwapInfo"))
@@ -3688,6 +8394,92 @@ enum BreezSDKMapper {
         return swapInfoList.map { v -> [String: Any?] in return dictionaryOf(swapInfo: v) }
     }
 
+    static func asSwapRefundKey(swapRefundKey: [String: Any?]) throws -> SwapRefundKey {
+        guard let index = swapRefundKey["index"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "index", typeName: "SwapRefundKey"))
+        }
+        guard let derivationPath = swapRefundKey["derivationPath"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "derivationPath", typeName: "SwapRefundKey"))
+        }
+        guard let privateKey = swapRefundKey["privateKey"] as? [UInt8] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "privateKey", typeName: "SwapRefundKey"))
+        }
+        guard let publicKey = swapRefundKey["publicKey"] as? [UInt8] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "publicKey", typeName: "SwapRefundKey"))
+        }
+
+        return SwapRefundKey(index: index, derivationPath: derivationPath, privateKey: privateKey, publicKey: publicKey)
+    }
+
+    static func dictionaryOf(swapRefundKey: SwapRefundKey) -> [String: Any?] {
+        return [
+            "index": swapRefundKey.index,
+            "derivationPath": swapRefundKey.derivationPath,
+            "privateKey": swapRefundKey.privateKey,
+            "publicKey": swapRefundKey.publicKey,
+        ]
+    }
+
+    static func asSwapRefundKeyList(arr: [Any]) throws -> [SwapRefundKey] {
+        var list = [SwapRefundKey]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var swapRefundKey = try asSwapRefundKey(swapRefundKey: val)
+                list.append(swapRefundKey)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SwapRefundKey"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(swapRefundKeyList: [SwapRefundKey]) -> [Any] {
+        return swapRefundKeyList.map { v -> [String: Any?] in return dictionaryOf(swapRefundKey: v) }
+    }
+
+    static func asSwapRefundedDetails(swapRefundedDetails: [String: Any?]) throws -> SwapRefundedDetails {
+        guard let swapAddress = swapRefundedDetails["swapAddress"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "swapAddress", typeName: "SwapRefundedDetails"))
+        }
+        guard let refundTxId = swapRefundedDetails["refundTxId"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundTxId", typeName: "SwapRefundedDetails"))
+        }
+        guard let toAddress = swapRefundedDetails["toAddress"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "toAddress", typeName: "SwapRefundedDetails"))
+        }
+        guard let satPerVbyte = swapRefundedDetails["satPerVbyte"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "satPerVbyte", typeName: "SwapRefundedDetails"))
+        }
+
+        return SwapRefundedDetails(swapAddress: swapAddress, refundTxId: refundTxId, toAddress: toAddress, satPerVbyte: satPerVbyte)
+    }
+
+    static func dictionaryOf(swapRefundedDetails: SwapRefundedDetails) -> [String: Any?] {
+        return [
+            "swapAddress": swapRefundedDetails.swapAddress,
+            "refundTxId": swapRefundedDetails.refundTxId,
+            "toAddress": swapRefundedDetails.toAddress,
+            "satPerVbyte": swapRefundedDetails.satPerVbyte,
+        ]
+    }
+
+    static func asSwapRefundedDetailsList(arr: [Any]) throws -> [SwapRefundedDetails] {
+        var list = [SwapRefundedDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var swapRefundedDetails = try asSwapRefundedDetails(swapRefundedDetails: val)
+                list.append(swapRefundedDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SwapRefundedDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(swapRefundedDetailsList: [SwapRefundedDetails]) -> [Any] {
+        return swapRefundedDetailsList.map { v -> [String: Any?] in return dictionaryOf(swapRefundedDetails: v) }
+    }
+
     static func asSymbol(symbol: [String: Any?]) throws -> Symbol {
         var grapheme: String?
         if hasNonNilKey(data: symbol, key: "grapheme") {
@@ -3747,6 +8539,63 @@ enum BreezSDKMapper {
         return symbolList.map { v -> [String: Any?] in return dictionaryOf(symbol: v) }
     }
 
+    static func asTaskStatus(taskStatus: [String: Any?]) throws -> TaskStatus {
+        guard let name = taskStatus["name"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "name", typeName: "TaskStatus"))
+        }
+        guard let stateTmp = taskStatus["state"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "state", typeName: "TaskStatus"))
+        }
+        let state = try asTaskState(taskState: stateTmp)
+
+        guard let restarts = taskStatus["restarts"] as? UInt32 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "restarts", typeName: "TaskStatus"))
+        }
+        var lastError: String?
+        if hasNonNilKey(data: taskStatus, key: "lastError") {
+            guard let lastErrorTmp = taskStatus["lastError"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastError"))
+            }
+            lastError = lastErrorTmp
+        }
+        var lastErrorAt: Int64?
+        if hasNonNilKey(data: taskStatus, key: "lastErrorAt") {
+            guard let lastErrorAtTmp = taskStatus["lastErrorAt"] as? Int64 else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "lastErrorAt"))
+            }
+            lastErrorAt = lastErrorAtTmp
+        }
+
+        return TaskStatus(name: name, state: state, restarts: restarts, lastError: lastError, lastErrorAt: lastErrorAt)
+    }
+
+    static func dictionaryOf(taskStatus: TaskStatus) -> [String: Any?] {
+        return [
+            "name": taskStatus.name,
+            "state": valueOf(taskState: taskStatus.state),
+            "restarts": taskStatus.restarts,
+            "lastError": taskStatus.lastError == nil ? nil : taskStatus.lastError,
+            "lastErrorAt": taskStatus.lastErrorAt == nil ? nil : taskStatus.lastErrorAt,
+        ]
+    }
+
+    static func asTaskStatusList(arr: [Any]) throws -> [TaskStatus] {
+        var list = [TaskStatus]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var taskStatus = try asTaskStatus(taskStatus: val)
+                list.append(taskStatus)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "TaskStatus"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(taskStatusList: [TaskStatus]) -> [Any] {
+        return taskStatusList.map { v -> [String: Any?] in return dictionaryOf(taskStatus: v) }
+    }
+
     static func asTlvEntry(tlvEntry: [String: Any?]) throws -> TlvEntry {
         guard let fieldNumber = tlvEntry["fieldNumber"] as? UInt64 else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fieldNumber", typeName: "TlvEntry"))
@@ -3782,6 +8631,108 @@ enum BreezSDKMapper {
         return tlvEntryList.map { v -> [String: Any?] in return dictionaryOf(tlvEntry: v) }
     }
 
+    static func asTlvRecord(tlvRecord: [String: Any?]) throws -> TlvRecord {
+        guard let fieldNumber = tlvRecord["fieldNumber"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fieldNumber", typeName: "TlvRecord"))
+        }
+        var valueText: String?
+        if hasNonNilKey(data: tlvRecord, key: "valueText") {
+            guard let valueTextTmp = tlvRecord["valueText"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "valueText"))
+            }
+            valueText = valueTextTmp
+        }
+        var valueHex: String?
+        if hasNonNilKey(data: tlvRecord, key: "valueHex") {
+            guard let valueHexTmp = tlvRecord["valueHex"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "valueHex"))
+            }
+            valueHex = valueHexTmp
+        }
+
+        return TlvRecord(fieldNumber: fieldNumber, valueText: valueText, valueHex: valueHex)
+    }
+
+    static func dictionaryOf(tlvRecord: TlvRecord) -> [String: Any?] {
+        return [
+            "fieldNumber": tlvRecord.fieldNumber,
+            "valueText": tlvRecord.valueText == nil ? nil : tlvRecord.valueText,
+            "valueHex": tlvRecord.valueHex == nil ? nil : tlvRecord.valueHex,
+        ]
+    }
+
+    static func asTlvRecordList(arr: [Any]) throws -> [TlvRecord] {
+        var list = [TlvRecord]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var tlvRecord = try asTlvRecord(tlvRecord: val)
+                list.append(tlvRecord)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "TlvRecord"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(tlvRecordList: [TlvRecord]) -> [Any] {
+        return tlvRecordList.map { v -> [String: Any?] in return dictionaryOf(tlvRecord: v) }
+    }
+
+    static func asUnredeemedFundsDetails(unredeemedFundsDetails: [String: Any?]) throws -> UnredeemedFundsDetails {
+        guard let refundableSwapsTmp = unredeemedFundsDetails["refundableSwaps"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "refundableSwaps", typeName: "UnredeemedFundsDetails"))
+        }
+        let refundableSwaps = try asSwapInfoList(arr: refundableSwapsTmp)
+
+        guard let redeemableSwapsTmp = unredeemedFundsDetails["redeemableSwaps"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "redeemableSwaps", typeName: "UnredeemedFundsDetails"))
+        }
+        let redeemableSwaps = try asSwapInfoList(arr: redeemableSwapsTmp)
+
+        guard let expiredInvoiceSwapsTmp = unredeemedFundsDetails["expiredInvoiceSwaps"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "expiredInvoiceSwaps", typeName: "UnredeemedFundsDetails"))
+        }
+        let expiredInvoiceSwaps = try asSwapInfoList(arr: expiredInvoiceSwapsTmp)
+
+        guard let unclaimedReverseSwapsTmp = unredeemedFundsDetails["unclaimedReverseSwaps"] as? [[String: Any?]] else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "unclaimedReverseSwaps", typeName: "UnredeemedFundsDetails"))
+        }
+        let unclaimedReverseSwaps = try asReverseSwapInfoList(arr: unclaimedReverseSwapsTmp)
+
+        guard let totalAmountSat = unredeemedFundsDetails["totalAmountSat"] as? UInt64 else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "totalAmountSat", typeName: "UnredeemedFundsDetails"))
+        }
+
+        return UnredeemedFundsDetails(refundableSwaps: refundableSwaps, redeemableSwaps: redeemableSwaps, expiredInvoiceSwaps: expiredInvoiceSwaps, unclaimedReverseSwaps: unclaimedReverseSwaps, totalAmountSat: totalAmountSat)
+    }
+
+    static func dictionaryOf(unredeemedFundsDetails: UnredeemedFundsDetails) -> [String: Any?] {
+        return [
+            "refundableSwaps": arrayOf(swapInfoList: unredeemedFundsDetails.refundableSwaps),
+            "redeemableSwaps": arrayOf(swapInfoList: unredeemedFundsDetails.redeemableSwaps),
+            "expiredInvoiceSwaps": arrayOf(swapInfoList: unredeemedFundsDetails.expiredInvoiceSwaps),
+            "unclaimedReverseSwaps": arrayOf(reverseSwapInfoList: unredeemedFundsDetails.unclaimedReverseSwaps),
+            "totalAmountSat": unredeemedFundsDetails.totalAmountSat,
+        ]
+    }
+
+    static func asUnredeemedFundsDetailsList(arr: [Any]) throws -> [UnredeemedFundsDetails] {
+        var list = [UnredeemedFundsDetails]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var unredeemedFundsDetails = try asUnredeemedFundsDetails(unredeemedFundsDetails: val)
+                list.append(unredeemedFundsDetails)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "UnredeemedFundsDetails"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(unredeemedFundsDetailsList: [UnredeemedFundsDetails]) -> [Any] {
+        return unredeemedFundsDetailsList.map { v -> [String: Any?] in return dictionaryOf(unredeemedFundsDetails: v) }
+    }
+
     static func asUnspentTransactionOutput(unspentTransactionOutput: [String: Any?]) throws -> UnspentTransactionOutput {
         guard let txid = unspentTransactionOutput["txid"] as? [UInt8] else {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "txid", typeName: "UnspentTransactionOutput"))
@@ -3840,32 +8791,126 @@ enum BreezSDKMapper {
             throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "matchesCallbackDomain", typeName: "UrlSuccessActionData"))
         }
 
-        return UrlSuccessActionData(description: description, url: url, matchesCallbackDomain: matchesCallbackDomain)
+        return UrlSuccessActionData(description: description, url: url, matchesCallbackDomain: matchesCallbackDomain)
+    }
+
+    static func dictionaryOf(urlSuccessActionData: UrlSuccessActionData) -> [String: Any?] {
+        return [
+            "description": urlSuccessActionData.description,
+            "url": urlSuccessActionData.url,
+            "matchesCallbackDomain": urlSuccessActionData.matchesCallbackDomain,
+        ]
+    }
+
+    static func asUrlSuccessActionDataList(arr: [Any]) throws -> [UrlSuccessActionData] {
+        var list = [UrlSuccessActionData]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var urlSuccessActionData = try asUrlSuccessActionData(urlSuccessActionData: val)
+                list.append(urlSuccessActionData)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "UrlSuccessActionData"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(urlSuccessActionDataList: [UrlSuccessActionData]) -> [Any] {
+        return urlSuccessActionDataList.map { v -> [String: Any?] in return dictionaryOf(urlSuccessActionData: v) }
+    }
+
+    static func asUserSettings(userSettings: [String: Any?]) throws -> UserSettings {
+        var preferredFiatCurrency: String?
+        if hasNonNilKey(data: userSettings, key: "preferredFiatCurrency") {
+            guard let preferredFiatCurrencyTmp = userSettings["preferredFiatCurrency"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "preferredFiatCurrency"))
+            }
+            preferredFiatCurrency = preferredFiatCurrencyTmp
+        }
+        var denomination: Denomination?
+        if let denominationTmp = userSettings["denomination"] as? String {
+            denomination = try asDenomination(denomination: denominationTmp)
+        }
+
+        var defaultFeeratePreset: FeeratePreset?
+        if let defaultFeeratePresetTmp = userSettings["defaultFeeratePreset"] as? String {
+            defaultFeeratePreset = try asFeeratePreset(feeratePreset: defaultFeeratePresetTmp)
+        }
+
+        var preferredLspId: String?
+        if hasNonNilKey(data: userSettings, key: "preferredLspId") {
+            guard let preferredLspIdTmp = userSettings["preferredLspId"] as? String else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "preferredLspId"))
+            }
+            preferredLspId = preferredLspIdTmp
+        }
+
+        return UserSettings(preferredFiatCurrency: preferredFiatCurrency, denomination: denomination, defaultFeeratePreset: defaultFeeratePreset, preferredLspId: preferredLspId)
+    }
+
+    static func dictionaryOf(userSettings: UserSettings) -> [String: Any?] {
+        return [
+            "preferredFiatCurrency": userSettings.preferredFiatCurrency == nil ? nil : userSettings.preferredFiatCurrency,
+            "denomination": userSettings.denomination == nil ? nil : valueOf(denomination: userSettings.denomination!),
+            "defaultFeeratePreset": userSettings.defaultFeeratePreset == nil ? nil : valueOf(feeratePreset: userSettings.defaultFeeratePreset!),
+            "preferredLspId": userSettings.preferredLspId == nil ? nil : userSettings.preferredLspId,
+        ]
+    }
+
+    static func asUserSettingsList(arr: [Any]) throws -> [UserSettings] {
+        var list = [UserSettings]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var userSettings = try asUserSettings(userSettings: val)
+                list.append(userSettings)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "UserSettings"))
+            }
+        }
+        return list
+    }
+
+    static func arrayOf(userSettingsList: [UserSettings]) -> [Any] {
+        return userSettingsList.map { v -> [String: Any?] in return dictionaryOf(userSettings: v) }
+    }
+
+    static func asValidatePayabilityRequest(validatePayabilityRequest: [String: Any?]) throws -> ValidatePayabilityRequest {
+        guard let bolt11 = validatePayabilityRequest["bolt11"] as? String else {
+            throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bolt11", typeName: "ValidatePayabilityRequest"))
+        }
+        var amountMsat: MilliSat?
+        if hasNonNilKey(data: validatePayabilityRequest, key: "amountMsat") {
+            guard let amountMsatTmp = validatePayabilityRequest["amountMsat"] as? MilliSat else {
+                throw SdkError.Generic(message: errUnexpectedValue(fieldName: "amountMsat"))
+            }
+            amountMsat = amountMsatTmp
+        }
+
+        return ValidatePayabilityRequest(bolt11: bolt11, amountMsat: amountMsat)
     }
 
-    static func dictionaryOf(urlSuccessActionData: UrlSuccessActionData) -> [String: Any?] {
+    static func dictionaryOf(validatePayabilityRequest: ValidatePayabilityRequest) -> [String: Any?] {
         return [
-            "description": urlSuccessActionData.description,
-            "url": urlSuccessActionData.url,
-            "matchesCallbackDomain": urlSuccessActionData.matchesCallbackDomain,
+            "bolt11": validatePayabilityRequest.bolt11,
+            "amountMsat": validatePayabilityRequest.amountMsat == nil ? nil : validatePayabilityRequest.amountMsat,
         ]
     }
 
-    static func asUrlSuccessActionDataList(arr: [Any]) throws -> [UrlSuccessActionData] {
-        var list = [UrlSuccessActionData]()
+    static func asValidatePayabilityRequestList(arr: [Any]) throws -> [ValidatePayabilityRequest] {
+        var list = [ValidatePayabilityRequest]()
         for value in arr {
             if let val = value as? [String: Any?] {
-                var urlSuccessActionData = try asUrlSuccessActionData(urlSuccessActionData: val)
-                list.append(urlSuccessActionData)
+                var validatePayabilityRequest = try asValidatePayabilityRequest(validatePayabilityRequest: val)
+                list.append(validatePayabilityRequest)
             } else {
-                throw SdkError.Generic(message: errUnexpectedType(typeName: "UrlSuccessActionData"))
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ValidatePayabilityRequest"))
             }
         }
         return list
     }
 
-    static func arrayOf(urlSuccessActionDataList: [UrlSuccessActionData]) -> [Any] {
-        return urlSuccessActionDataList.map { v -> [String: Any?] in return dictionaryOf(urlSuccessActionData: v) }
+    static func arrayOf(validatePayabilityRequestList: [ValidatePayabilityRequest]) -> [Any] {
+        return validatePayabilityRequestList.map { v -> [String: Any?] in return dictionaryOf(validatePayabilityRequest: v) }
     }
 
     static func asAesSuccessActionDataResult(aesSuccessActionDataResult: [String: Any?]) throws -> AesSuccessActionDataResult {
@@ -3925,6 +8970,142 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asAmount(amount: [String: Any?]) throws -> Amount {
+        let type = amount["type"] as! String
+        if type == "bitcoin" {
+            guard let _amountMsat = amount["amountMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "amountMsat", typeName: "Amount"))
+            }
+            return Amount.bitcoin(amountMsat: _amountMsat)
+        }
+        if type == "currency" {
+            guard let _iso4217Code = amount["iso4217Code"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "iso4217Code", typeName: "Amount"))
+            }
+            guard let _fractionalAmount = amount["fractionalAmount"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fractionalAmount", typeName: "Amount"))
+            }
+            return Amount.currency(iso4217Code: _iso4217Code, fractionalAmount: _fractionalAmount)
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum Amount")
+    }
+
+    static func dictionaryOf(amount: Amount) -> [String: Any?] {
+        switch amount {
+        case let .bitcoin(
+            amountMsat
+        ):
+            return [
+                "type": "bitcoin",
+                "amountMsat": amountMsat,
+            ]
+
+        case let .currency(
+            iso4217Code, fractionalAmount
+        ):
+            return [
+                "type": "currency",
+                "iso4217Code": iso4217Code,
+                "fractionalAmount": fractionalAmount,
+            ]
+        }
+    }
+
+    static func arrayOf(amountList: [Amount]) -> [Any] {
+        return amountList.map { v -> [String: Any?] in return dictionaryOf(amount: v) }
+    }
+
+    static func asAmountList(arr: [Any]) throws -> [Amount] {
+        var list = [Amount]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var amount = try asAmount(amount: val)
+                list.append(amount)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "Amount"))
+            }
+        }
+        return list
+    }
+
+    static func asBackupTransportConfig(backupTransportConfig: [String: Any?]) throws -> BackupTransportConfig {
+        let type = backupTransportConfig["type"] as! String
+        if type == "s3" {
+            guard let _endpoint = backupTransportConfig["endpoint"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "endpoint", typeName: "BackupTransportConfig"))
+            }
+            guard let _region = backupTransportConfig["region"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "region", typeName: "BackupTransportConfig"))
+            }
+            guard let _bucket = backupTransportConfig["bucket"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "bucket", typeName: "BackupTransportConfig"))
+            }
+            guard let _accessKeyId = backupTransportConfig["accessKeyId"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "accessKeyId", typeName: "BackupTransportConfig"))
+            }
+            guard let _secretAccessKey = backupTransportConfig["secretAccessKey"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "secretAccessKey", typeName: "BackupTransportConfig"))
+            }
+            return BackupTransportConfig.s3(endpoint: _endpoint, region: _region, bucket: _bucket, accessKeyId: _accessKeyId, secretAccessKey: _secretAccessKey)
+        }
+        if type == "webDav" {
+            guard let _url = backupTransportConfig["url"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "BackupTransportConfig"))
+            }
+            let _username = backupTransportConfig["username"] as? String
+
+            let _password = backupTransportConfig["password"] as? String
+
+            return BackupTransportConfig.webDav(url: _url, username: _username, password: _password)
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum BackupTransportConfig")
+    }
+
+    static func dictionaryOf(backupTransportConfig: BackupTransportConfig) -> [String: Any?] {
+        switch backupTransportConfig {
+        case let .s3(
+            endpoint, region, bucket, accessKeyId, secretAccessKey
+        ):
+            return [
+                "type": "s3",
+                "endpoint": endpoint,
+                "region": region,
+                "bucket": bucket,
+                "accessKeyId": accessKeyId,
+                "secretAccessKey": secretAccessKey,
+            ]
+
+        case let .webDav(
+            url, username, password
+        ):
+            return [
+                "type": "webDav",
+                "url": url,
+                "username": username == nil ? nil : username,
+                "password": password == nil ? nil : password,
+            ]
+        }
+    }
+
+    static func arrayOf(backupTransportConfigList: [BackupTransportConfig]) -> [Any] {
+        return backupTransportConfigList.map { v -> [String: Any?] in return dictionaryOf(backupTransportConfig: v) }
+    }
+
+    static func asBackupTransportConfigList(arr: [Any]) throws -> [BackupTransportConfig] {
+        var list = [BackupTransportConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var backupTransportConfig = try asBackupTransportConfig(backupTransportConfig: val)
+                list.append(backupTransportConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "BackupTransportConfig"))
+            }
+        }
+        return list
+    }
+
     static func asBreezEvent(breezEvent: [String: Any?]) throws -> BreezEvent {
         let type = breezEvent["type"] as! String
         if type == "newBlock" {
@@ -3990,6 +9171,170 @@ enum BreezSDKMapper {
 
             return BreezEvent.swapUpdated(details: _details)
         }
+        if type == "connectProgress" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asConnectProgressDetails(connectProgressDetails: detailsTmp)
+
+            return BreezEvent.connectProgress(details: _details)
+        }
+        if type == "unredeemedFundsDetected" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asUnredeemedFundsDetails(unredeemedFundsDetails: detailsTmp)
+
+            return BreezEvent.unredeemedFundsDetected(details: _details)
+        }
+        if type == "lnUrlPayFinished" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asLnUrlPayFinishedDetails(lnUrlPayFinishedDetails: detailsTmp)
+
+            return BreezEvent.lnUrlPayFinished(details: _details)
+        }
+        if type == "openChannelReceiveUpdated" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asOpenChannelReceiveDetails(openChannelReceiveDetails: detailsTmp)
+
+            return BreezEvent.openChannelReceiveUpdated(details: _details)
+        }
+        if type == "lowOutboundLiquidity" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asLowLiquidityDetails(lowLiquidityDetails: detailsTmp)
+
+            return BreezEvent.lowOutboundLiquidity(details: _details)
+        }
+        if type == "lowInboundLiquidity" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asLowLiquidityDetails(lowLiquidityDetails: detailsTmp)
+
+            return BreezEvent.lowInboundLiquidity(details: _details)
+        }
+        if type == "channelOpening" {
+            guard let _feeMsat = breezEvent["feeMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "feeMsat", typeName: "BreezEvent"))
+            }
+            guard let _minFeeMsat = breezEvent["minFeeMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "minFeeMsat", typeName: "BreezEvent"))
+            }
+            guard let _proportional = breezEvent["proportional"] as? UInt32 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "proportional", typeName: "BreezEvent"))
+            }
+            return BreezEvent.channelOpening(feeMsat: _feeMsat, minFeeMsat: _minFeeMsat, proportional: _proportional)
+        }
+        if type == "lspChanged" {
+            let _previousLspId = breezEvent["previousLspId"] as? String
+
+            guard let _lspId = breezEvent["lspId"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lspId", typeName: "BreezEvent"))
+            }
+            return BreezEvent.lspChanged(previousLspId: _previousLspId, lspId: _lspId)
+        }
+        if type == "swapRefunded" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asSwapRefundedDetails(swapRefundedDetails: detailsTmp)
+
+            return BreezEvent.swapRefunded(details: _details)
+        }
+        if type == "batchPaymentProgress" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asBatchPaymentProgressDetails(batchPaymentProgressDetails: detailsTmp)
+
+            return BreezEvent.batchPaymentProgress(details: _details)
+        }
+        if type == "outboxPaymentUpdated" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asOutboxPaymentDetails(outboxPaymentDetails: detailsTmp)
+
+            return BreezEvent.outboxPaymentUpdated(details: _details)
+        }
+        if type == "lnUrlAuthCompleted" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asLnUrlAuthDomain(lnUrlAuthDomain: detailsTmp)
+
+            return BreezEvent.lnUrlAuthCompleted(details: _details)
+        }
+        if type == "channelHygieneSuggestion" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asChannelHygieneSuggestionDetails(channelHygieneSuggestionDetails: detailsTmp)
+
+            return BreezEvent.channelHygieneSuggestion(details: _details)
+        }
+        if type == "fiatRateAlertTriggered" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asFiatRateAlertTriggeredDetails(fiatRateAlertTriggeredDetails: detailsTmp)
+
+            return BreezEvent.fiatRateAlertTriggered(details: _details)
+        }
+        if type == "connected" {
+            return BreezEvent.connected
+        }
+        if type == "disconnected" {
+            guard let _error = breezEvent["error"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "error", typeName: "BreezEvent"))
+            }
+            return BreezEvent.disconnected(error: _error)
+        }
+        if type == "reconnecting" {
+            guard let _attempt = breezEvent["attempt"] as? UInt32 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "attempt", typeName: "BreezEvent"))
+            }
+            guard let _delayMs = breezEvent["delayMs"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "delayMs", typeName: "BreezEvent"))
+            }
+            return BreezEvent.reconnecting(attempt: _attempt, delayMs: _delayMs)
+        }
+        if type == "lspPeerReconnected" {
+            guard let _lspPubkey = breezEvent["lspPubkey"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lspPubkey", typeName: "BreezEvent"))
+            }
+            guard let _reason = breezEvent["reason"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "reason", typeName: "BreezEvent"))
+            }
+            return BreezEvent.lspPeerReconnected(lspPubkey: _lspPubkey, reason: _reason)
+        }
+        if type == "spontaneousPaymentReceived" {
+            guard let detailsTmp = breezEvent["details"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "details", typeName: "BreezEvent"))
+            }
+            let _details = try asPayment(payment: detailsTmp)
+
+            return BreezEvent.spontaneousPaymentReceived(details: _details)
+        }
+        if type == "serviceStatusChanged" {
+            guard let componentTmp = breezEvent["component"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "component", typeName: "BreezEvent"))
+            }
+            let _component = try asServiceComponent(serviceComponent: componentTmp)
+
+            guard let statusTmp = breezEvent["status"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "status", typeName: "BreezEvent"))
+            }
+            let _status = try asServiceStatus(serviceStatus: statusTmp)
+
+            return BreezEvent.serviceStatusChanged(component: _component, status: _status)
+        }
 
         throw SdkError.Generic(message: "Unexpected type \(type) for enum BreezEvent")
     }
@@ -4066,6 +9411,169 @@ enum BreezSDKMapper {
                 "type": "swapUpdated",
                 "details": dictionaryOf(swapInfo: details),
             ]
+
+        case let .connectProgress(
+            details
+        ):
+            return [
+                "type": "connectProgress",
+                "details": dictionaryOf(connectProgressDetails: details),
+            ]
+
+        case let .unredeemedFundsDetected(
+            details
+        ):
+            return [
+                "type": "unredeemedFundsDetected",
+                "details": dictionaryOf(unredeemedFundsDetails: details),
+            ]
+
+        case let .lnUrlPayFinished(
+            details
+        ):
+            return [
+                "type": "lnUrlPayFinished",
+                "details": dictionaryOf(lnUrlPayFinishedDetails: details),
+            ]
+
+        case let .openChannelReceiveUpdated(
+            details
+        ):
+            return [
+                "type": "openChannelReceiveUpdated",
+                "details": dictionaryOf(openChannelReceiveDetails: details),
+            ]
+
+        case let .lowOutboundLiquidity(
+            details
+        ):
+            return [
+                "type": "lowOutboundLiquidity",
+                "details": dictionaryOf(lowLiquidityDetails: details),
+            ]
+
+        case let .lowInboundLiquidity(
+            details
+        ):
+            return [
+                "type": "lowInboundLiquidity",
+                "details": dictionaryOf(lowLiquidityDetails: details),
+            ]
+
+        case let .channelOpening(
+            feeMsat, minFeeMsat, proportional
+        ):
+            return [
+                "type": "channelOpening",
+                "feeMsat": feeMsat,
+                "minFeeMsat": minFeeMsat,
+                "proportional": proportional,
+            ]
+
+        case let .lspChanged(
+            previousLspId, lspId
+        ):
+            return [
+                "type": "lspChanged",
+                "previousLspId": previousLspId == nil ? nil : previousLspId,
+                "lspId": lspId,
+            ]
+
+        case let .swapRefunded(
+            details
+        ):
+            return [
+                "type": "swapRefunded",
+                "details": dictionaryOf(swapRefundedDetails: details),
+            ]
+
+        case let .batchPaymentProgress(
+            details
+        ):
+            return [
+                "type": "batchPaymentProgress",
+                "details": dictionaryOf(batchPaymentProgressDetails: details),
+            ]
+
+        case let .outboxPaymentUpdated(
+            details
+        ):
+            return [
+                "type": "outboxPaymentUpdated",
+                "details": dictionaryOf(outboxPaymentDetails: details),
+            ]
+
+        case let .lnUrlAuthCompleted(
+            details
+        ):
+            return [
+                "type": "lnUrlAuthCompleted",
+                "details": dictionaryOf(lnUrlAuthDomain: details),
+            ]
+
+        case let .channelHygieneSuggestion(
+            details
+        ):
+            return [
+                "type": "channelHygieneSuggestion",
+                "details": dictionaryOf(channelHygieneSuggestionDetails: details),
+            ]
+
+        case let .fiatRateAlertTriggered(
+            details
+        ):
+            return [
+                "type": "fiatRateAlertTriggered",
+                "details": dictionaryOf(fiatRateAlertTriggeredDetails: details),
+            ]
+
+        case .connected:
+            return [
+                "type": "connected",
+            ]
+
+        case let .disconnected(
+            error
+        ):
+            return [
+                "type": "disconnected",
+                "error": error,
+            ]
+
+        case let .reconnecting(
+            attempt, delayMs
+        ):
+            return [
+                "type": "reconnecting",
+                "attempt": attempt,
+                "delayMs": delayMs,
+            ]
+
+        case let .lspPeerReconnected(
+            lspPubkey, reason
+        ):
+            return [
+                "type": "lspPeerReconnected",
+                "lspPubkey": lspPubkey,
+                "reason": reason,
+            ]
+
+        case let .spontaneousPaymentReceived(
+            details
+        ):
+            return [
+                "type": "spontaneousPaymentReceived",
+                "details": dictionaryOf(payment: details),
+            ]
+
+        case let .serviceStatusChanged(
+            component, status
+        ):
+            return [
+                "type": "serviceStatusChanged",
+                "component": valueOf(serviceComponent: component),
+                "status": valueOf(serviceStatus: status),
+            ]
         }
     }
 
@@ -4091,6 +9599,9 @@ enum BreezSDKMapper {
         case "moonpay":
             return BuyBitcoinProvider.moonpay
 
+        case "transak":
+            return BuyBitcoinProvider.transak
+
         default: throw SdkError.Generic(message: "Invalid variant \(buyBitcoinProvider) for enum BuyBitcoinProvider")
         }
     }
@@ -4099,6 +9610,9 @@ enum BreezSDKMapper {
         switch buyBitcoinProvider {
         case .moonpay:
             return "moonpay"
+
+        case .transak:
+            return "transak"
         }
     }
 
@@ -4119,6 +9633,61 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asChainServiceConfig(chainServiceConfig: [String: Any?]) throws -> ChainServiceConfig {
+        let type = chainServiceConfig["type"] as! String
+        if type == "esplora" {
+            guard let _url = chainServiceConfig["url"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "ChainServiceConfig"))
+            }
+            return ChainServiceConfig.esplora(url: _url)
+        }
+        if type == "electrum" {
+            guard let _url = chainServiceConfig["url"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "url", typeName: "ChainServiceConfig"))
+            }
+            return ChainServiceConfig.electrum(url: _url)
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum ChainServiceConfig")
+    }
+
+    static func dictionaryOf(chainServiceConfig: ChainServiceConfig) -> [String: Any?] {
+        switch chainServiceConfig {
+        case let .esplora(
+            url
+        ):
+            return [
+                "type": "esplora",
+                "url": url,
+            ]
+
+        case let .electrum(
+            url
+        ):
+            return [
+                "type": "electrum",
+                "url": url,
+            ]
+        }
+    }
+
+    static func arrayOf(chainServiceConfigList: [ChainServiceConfig]) -> [Any] {
+        return chainServiceConfigList.map { v -> [String: Any?] in return dictionaryOf(chainServiceConfig: v) }
+    }
+
+    static func asChainServiceConfigList(arr: [Any]) throws -> [ChainServiceConfig] {
+        var list = [ChainServiceConfig]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var chainServiceConfig = try asChainServiceConfig(chainServiceConfig: val)
+                list.append(chainServiceConfig)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ChainServiceConfig"))
+            }
+        }
+        return list
+    }
+
     static func asChannelState(channelState: String) throws -> ChannelState {
         switch channelState {
         case "pendingOpen":
@@ -4170,6 +9739,153 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asConnectStage(connectStage: String) throws -> ConnectStage {
+        switch connectStage {
+        case "nodeRecovery":
+            return ConnectStage.nodeRecovery
+
+        case "nodeRegistration":
+            return ConnectStage.nodeRegistration
+
+        case "schedulerHandshake":
+            return ConnectStage.schedulerHandshake
+
+        case "nodeStartup":
+            return ConnectStage.nodeStartup
+
+        case "initialSync":
+            return ConnectStage.initialSync
+
+        case "completed":
+            return ConnectStage.completed
+
+        default: throw SdkError.Generic(message: "Invalid variant \(connectStage) for enum ConnectStage")
+        }
+    }
+
+    static func valueOf(connectStage: ConnectStage) -> String {
+        switch connectStage {
+        case .nodeRecovery:
+            return "nodeRecovery"
+
+        case .nodeRegistration:
+            return "nodeRegistration"
+
+        case .schedulerHandshake:
+            return "schedulerHandshake"
+
+        case .nodeStartup:
+            return "nodeStartup"
+
+        case .initialSync:
+            return "initialSync"
+
+        case .completed:
+            return "completed"
+        }
+    }
+
+    static func arrayOf(connectStageList: [ConnectStage]) -> [String] {
+        return connectStageList.map { v -> String in return valueOf(connectStage: v) }
+    }
+
+    static func asConnectStageList(arr: [Any]) throws -> [ConnectStage] {
+        var list = [ConnectStage]()
+        for value in arr {
+            if let val = value as? String {
+                var connectStage = try asConnectStage(connectStage: val)
+                list.append(connectStage)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectStage"))
+            }
+        }
+        return list
+    }
+
+    static func asConnectionState(connectionState: String) throws -> ConnectionState {
+        switch connectionState {
+        case "connected":
+            return ConnectionState.connected
+
+        case "disconnected":
+            return ConnectionState.disconnected
+
+        case "reconnecting":
+            return ConnectionState.reconnecting
+
+        default: throw SdkError.Generic(message: "Invalid variant \(connectionState) for enum ConnectionState")
+        }
+    }
+
+    static func valueOf(connectionState: ConnectionState) -> String {
+        switch connectionState {
+        case .connected:
+            return "connected"
+
+        case .disconnected:
+            return "disconnected"
+
+        case .reconnecting:
+            return "reconnecting"
+        }
+    }
+
+    static func arrayOf(connectionStateList: [ConnectionState]) -> [String] {
+        return connectionStateList.map { v -> String in return valueOf(connectionState: v) }
+    }
+
+    static func asConnectionStateList(arr: [Any]) throws -> [ConnectionState] {
+        var list = [ConnectionState]()
+        for value in arr {
+            if let val = value as? String {
+                var connectionState = try asConnectionState(connectionState: val)
+                list.append(connectionState)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ConnectionState"))
+            }
+        }
+        return list
+    }
+
+    static func asDenomination(denomination: String) throws -> Denomination {
+        switch denomination {
+        case "btc":
+            return Denomination.btc
+
+        case "sat":
+            return Denomination.sat
+
+        default: throw SdkError.Generic(message: "Invalid variant \(denomination) for enum Denomination")
+        }
+    }
+
+    static func valueOf(denomination: Denomination) -> String {
+        switch denomination {
+        case .btc:
+            return "btc"
+
+        case .sat:
+            return "sat"
+        }
+    }
+
+    static func arrayOf(denominationList: [Denomination]) -> [String] {
+        return denominationList.map { v -> String in return valueOf(denomination: v) }
+    }
+
+    static func asDenominationList(arr: [Any]) throws -> [Denomination] {
+        var list = [Denomination]()
+        for value in arr {
+            if let val = value as? String {
+                var denomination = try asDenomination(denomination: val)
+                list.append(denomination)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "Denomination"))
+            }
+        }
+        return list
+    }
+
     static func asEnvironmentType(environmentType: String) throws -> EnvironmentType {
         switch environmentType {
         case "production":
@@ -4178,6 +9894,9 @@ enum BreezSDKMapper {
         case "staging":
             return EnvironmentType.staging
 
+        case "regtest":
+            return EnvironmentType.regtest
+
         default: throw SdkError.Generic(message: "Invalid variant \(environmentType) for enum EnvironmentType")
         }
     }
@@ -4189,6 +9908,9 @@ enum BreezSDKMapper {
 
         case .staging:
             return "staging"
+
+        case .regtest:
+            return "regtest"
         }
     }
 
@@ -4209,6 +9931,90 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asExportFormat(exportFormat: String) throws -> ExportFormat {
+        switch exportFormat {
+        case "csv":
+            return ExportFormat.csv
+
+        case "json":
+            return ExportFormat.json
+
+        default: throw SdkError.Generic(message: "Invalid variant \(exportFormat) for enum ExportFormat")
+        }
+    }
+
+    static func valueOf(exportFormat: ExportFormat) -> String {
+        switch exportFormat {
+        case .csv:
+            return "csv"
+
+        case .json:
+            return "json"
+        }
+    }
+
+    static func arrayOf(exportFormatList: [ExportFormat]) -> [String] {
+        return exportFormatList.map { v -> String in return valueOf(exportFormat: v) }
+    }
+
+    static func asExportFormatList(arr: [Any]) throws -> [ExportFormat] {
+        var list = [ExportFormat]()
+        for value in arr {
+            if let val = value as? String {
+                var exportFormat = try asExportFormat(exportFormat: val)
+                list.append(exportFormat)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ExportFormat"))
+            }
+        }
+        return list
+    }
+
+    static func asFeatureSupport(featureSupport: String) throws -> FeatureSupport {
+        switch featureSupport {
+        case "notSupported":
+            return FeatureSupport.notSupported
+
+        case "optional":
+            return FeatureSupport.optional
+
+        case "required":
+            return FeatureSupport.required
+
+        default: throw SdkError.Generic(message: "Invalid variant \(featureSupport) for enum FeatureSupport")
+        }
+    }
+
+    static func valueOf(featureSupport: FeatureSupport) -> String {
+        switch featureSupport {
+        case .notSupported:
+            return "notSupported"
+
+        case .optional:
+            return "optional"
+
+        case .required:
+            return "required"
+        }
+    }
+
+    static func arrayOf(featureSupportList: [FeatureSupport]) -> [String] {
+        return featureSupportList.map { v -> String in return valueOf(featureSupport: v) }
+    }
+
+    static func asFeatureSupportList(arr: [Any]) throws -> [FeatureSupport] {
+        var list = [FeatureSupport]()
+        for value in arr {
+            if let val = value as? String {
+                var featureSupport = try asFeatureSupport(featureSupport: val)
+                list.append(featureSupport)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "FeatureSupport"))
+            }
+        }
+        return list
+    }
+
     static func asFeeratePreset(feeratePreset: String) throws -> FeeratePreset {
         switch feeratePreset {
         case "regular":
@@ -4254,6 +10060,45 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asFiatRateAlertDirection(fiatRateAlertDirection: String) throws -> FiatRateAlertDirection {
+        switch fiatRateAlertDirection {
+        case "above":
+            return FiatRateAlertDirection.above
+
+        case "below":
+            return FiatRateAlertDirection.below
+
+        default: throw SdkError.Generic(message: "Invalid variant \(fiatRateAlertDirection) for enum FiatRateAlertDirection")
+        }
+    }
+
+    static func valueOf(fiatRateAlertDirection: FiatRateAlertDirection) -> String {
+        switch fiatRateAlertDirection {
+        case .above:
+            return "above"
+
+        case .below:
+            return "below"
+        }
+    }
+
+    static func arrayOf(fiatRateAlertDirectionList: [FiatRateAlertDirection]) -> [String] {
+        return fiatRateAlertDirectionList.map { v -> String in return valueOf(fiatRateAlertDirection: v) }
+    }
+
+    static func asFiatRateAlertDirectionList(arr: [Any]) throws -> [FiatRateAlertDirection] {
+        var list = [FiatRateAlertDirection]()
+        for value in arr {
+            if let val = value as? String {
+                var fiatRateAlertDirection = try asFiatRateAlertDirection(fiatRateAlertDirection: val)
+                list.append(fiatRateAlertDirection)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "FiatRateAlertDirection"))
+            }
+        }
+        return list
+    }
+
     static func asHealthCheckStatus(healthCheckStatus: String) throws -> HealthCheckStatus {
         switch healthCheckStatus {
         case "operational":
@@ -4317,6 +10162,16 @@ enum BreezSDKMapper {
 
             return InputType.bolt11(invoice: _invoice)
         }
+        if type == "bolt12Offer" {
+            guard let offerTmp = inputType["offer"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "offer", typeName: "InputType"))
+            }
+            let _offer = try asLnOffer(lnOffer: offerTmp)
+
+            let _bip353Address = inputType["bip353Address"] as? String
+
+            return InputType.bolt12Offer(offer: _offer, bip353Address: _bip353Address)
+        }
         if type == "nodeId" {
             guard let _nodeId = inputType["nodeId"] as? String else {
                 throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "InputType"))
@@ -4337,7 +10192,11 @@ enum BreezSDKMapper {
 
             let _bip353Address = inputType["bip353Address"] as? String
 
-            return InputType.lnUrlPay(data: _data, bip353Address: _bip353Address)
+            let _amountMsat = inputType["amountMsat"] as? UInt64
+
+            let _comment = inputType["comment"] as? String
+
+            return InputType.lnUrlPay(data: _data, bip353Address: _bip353Address, amountMsat: _amountMsat, comment: _comment)
         }
         if type == "lnUrlWithdraw" {
             guard let dataTmp = inputType["data"] as? [String: Any?] else {
@@ -4363,6 +10222,14 @@ enum BreezSDKMapper {
 
             return InputType.lnUrlError(data: _data)
         }
+        if type == "encryptedPaymentRequest" {
+            guard let dataTmp = inputType["data"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "InputType"))
+            }
+            let _data = try asEncryptedPaymentRequestData(encryptedPaymentRequestData: dataTmp)
+
+            return InputType.encryptedPaymentRequest(data: _data)
+        }
 
         throw SdkError.Generic(message: "Unexpected type \(type) for enum InputType")
     }
@@ -4385,6 +10252,15 @@ enum BreezSDKMapper {
                 "invoice": dictionaryOf(lnInvoice: invoice),
             ]
 
+        case let .bolt12Offer(
+            offer, bip353Address
+        ):
+            return [
+                "type": "bolt12Offer",
+                "offer": dictionaryOf(lnOffer: offer),
+                "bip353Address": bip353Address == nil ? nil : bip353Address,
+            ]
+
         case let .nodeId(
             nodeId
         ):
@@ -4402,12 +10278,14 @@ enum BreezSDKMapper {
             ]
 
         case let .lnUrlPay(
-            data, bip353Address
+            data, bip353Address, amountMsat, comment
         ):
             return [
                 "type": "lnUrlPay",
                 "data": dictionaryOf(lnUrlPayRequestData: data),
                 "bip353Address": bip353Address == nil ? nil : bip353Address,
+                "amountMsat": amountMsat == nil ? nil : amountMsat,
+                "comment": comment == nil ? nil : comment,
             ]
 
         case let .lnUrlWithdraw(
@@ -4433,6 +10311,14 @@ enum BreezSDKMapper {
                 "type": "lnUrlError",
                 "data": dictionaryOf(lnUrlErrorData: data),
             ]
+
+        case let .encryptedPaymentRequest(
+            data
+        ):
+            return [
+                "type": "encryptedPaymentRequest",
+                "data": dictionaryOf(encryptedPaymentRequestData: data),
+            ]
         }
     }
 
@@ -4530,6 +10416,14 @@ enum BreezSDKMapper {
 
             return LnUrlPayResult.payError(data: _data)
         }
+        if type == "pending" {
+            guard let dataTmp = lnUrlPayResult["data"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "data", typeName: "LnUrlPayResult"))
+            }
+            let _data = try asLnUrlPayPendingData(lnUrlPayPendingData: dataTmp)
+
+            return LnUrlPayResult.pending(data: _data)
+        }
 
         throw SdkError.Generic(message: "Unexpected type \(type) for enum LnUrlPayResult")
     }
@@ -4559,6 +10453,14 @@ enum BreezSDKMapper {
                 "type": "payError",
                 "data": dictionaryOf(lnUrlPayErrorData: data),
             ]
+
+        case let .pending(
+            data
+        ):
+            return [
+                "type": "pending",
+                "data": dictionaryOf(lnUrlPayPendingData: data),
+            ]
         }
     }
 
@@ -4654,6 +10556,108 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asLspPolicy(lspPolicy: [String: Any?]) throws -> LspPolicy {
+        let type = lspPolicy["type"] as! String
+        if type == "lowestOpeningFee" {
+            return LspPolicy.lowestOpeningFee
+        }
+        if type == "lowestProportionalFee" {
+            return LspPolicy.lowestProportionalFee
+        }
+        if type == "pinned" {
+            guard let _lspId = lspPolicy["lspId"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "lspId", typeName: "LspPolicy"))
+            }
+            return LspPolicy.pinned(lspId: _lspId)
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum LspPolicy")
+    }
+
+    static func dictionaryOf(lspPolicy: LspPolicy) -> [String: Any?] {
+        switch lspPolicy {
+        case .lowestOpeningFee:
+            return [
+                "type": "lowestOpeningFee",
+            ]
+
+        case .lowestProportionalFee:
+            return [
+                "type": "lowestProportionalFee",
+            ]
+
+        case let .pinned(
+            lspId
+        ):
+            return [
+                "type": "pinned",
+                "lspId": lspId,
+            ]
+        }
+    }
+
+    static func arrayOf(lspPolicyList: [LspPolicy]) -> [Any] {
+        return lspPolicyList.map { v -> [String: Any?] in return dictionaryOf(lspPolicy: v) }
+    }
+
+    static func asLspPolicyList(arr: [Any]) throws -> [LspPolicy] {
+        var list = [LspPolicy]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var lspPolicy = try asLspPolicy(lspPolicy: val)
+                list.append(lspPolicy)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "LspPolicy"))
+            }
+        }
+        return list
+    }
+
+    static func asMemoPrivacy(memoPrivacy: String) throws -> MemoPrivacy {
+        switch memoPrivacy {
+        case "plain":
+            return MemoPrivacy.plain
+
+        case "hash":
+            return MemoPrivacy.hash
+
+        case "strip":
+            return MemoPrivacy.strip
+
+        default: throw SdkError.Generic(message: "Invalid variant \(memoPrivacy) for enum MemoPrivacy")
+        }
+    }
+
+    static func valueOf(memoPrivacy: MemoPrivacy) -> String {
+        switch memoPrivacy {
+        case .plain:
+            return "plain"
+
+        case .hash:
+            return "hash"
+
+        case .strip:
+            return "strip"
+        }
+    }
+
+    static func arrayOf(memoPrivacyList: [MemoPrivacy]) -> [String] {
+        return memoPrivacyList.map { v -> String in return valueOf(memoPrivacy: v) }
+    }
+
+    static func asMemoPrivacyList(arr: [Any]) throws -> [MemoPrivacy] {
+        var list = [MemoPrivacy]()
+        for value in arr {
+            if let val = value as? String {
+                var memoPrivacy = try asMemoPrivacy(memoPrivacy: val)
+                list.append(memoPrivacy)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "MemoPrivacy"))
+            }
+        }
+        return list
+    }
+
     static func asNetwork(network: String) throws -> Network {
         switch network {
         case "bitcoin":
@@ -4711,9 +10715,17 @@ enum BreezSDKMapper {
             guard let configTmp = nodeConfig["config"] as? [String: Any?] else {
                 throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "config", typeName: "NodeConfig"))
             }
-            let _config = try asGreenlightNodeConfig(greenlightNodeConfig: configTmp)
+            let _config = try asGreenlightNodeConfig(greenlightNodeConfig: configTmp)
+
+            return NodeConfig.greenlight(config: _config)
+        }
+        if type == "mock" {
+            guard let configTmp = nodeConfig["config"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "config", typeName: "NodeConfig"))
+            }
+            let _config = try asMockNodeConfig(mockNodeConfig: configTmp)
 
-            return NodeConfig.greenlight(config: _config)
+            return NodeConfig.mock(config: _config)
         }
 
         throw SdkError.Generic(message: "Unexpected type \(type) for enum NodeConfig")
@@ -4728,6 +10740,14 @@ enum BreezSDKMapper {
                 "type": "greenlight",
                 "config": dictionaryOf(greenlightNodeConfig: config),
             ]
+
+        case let .mock(
+            config
+        ):
+            return [
+                "type": "mock",
+                "config": dictionaryOf(mockNodeConfig: config),
+            ]
         }
     }
 
@@ -4791,6 +10811,205 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asOpenChannelReceiveStage(openChannelReceiveStage: [String: Any?]) throws -> OpenChannelReceiveStage {
+        let type = openChannelReceiveStage["type"] as! String
+        if type == "registered" {
+            guard let _openingFeeMsat = openChannelReceiveStage["openingFeeMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "openingFeeMsat", typeName: "OpenChannelReceiveStage"))
+            }
+            return OpenChannelReceiveStage.registered(openingFeeMsat: _openingFeeMsat)
+        }
+        if type == "channelPending" {
+            guard let _fundingTxid = openChannelReceiveStage["fundingTxid"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "fundingTxid", typeName: "OpenChannelReceiveStage"))
+            }
+            return OpenChannelReceiveStage.channelPending(fundingTxid: _fundingTxid)
+        }
+        if type == "settled" {
+            guard let _openingFeeMsat = openChannelReceiveStage["openingFeeMsat"] as? UInt64 else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "openingFeeMsat", typeName: "OpenChannelReceiveStage"))
+            }
+            return OpenChannelReceiveStage.settled(openingFeeMsat: _openingFeeMsat)
+        }
+        if type == "reregistered" {
+            return OpenChannelReceiveStage.reregistered
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum OpenChannelReceiveStage")
+    }
+
+    static func dictionaryOf(openChannelReceiveStage: OpenChannelReceiveStage) -> [String: Any?] {
+        switch openChannelReceiveStage {
+        case let .registered(
+            openingFeeMsat
+        ):
+            return [
+                "type": "registered",
+                "openingFeeMsat": openingFeeMsat,
+            ]
+
+        case let .channelPending(
+            fundingTxid
+        ):
+            return [
+                "type": "channelPending",
+                "fundingTxid": fundingTxid,
+            ]
+
+        case let .settled(
+            openingFeeMsat
+        ):
+            return [
+                "type": "settled",
+                "openingFeeMsat": openingFeeMsat,
+            ]
+
+        case .reregistered:
+            return [
+                "type": "reregistered",
+            ]
+        }
+    }
+
+    static func arrayOf(openChannelReceiveStageList: [OpenChannelReceiveStage]) -> [Any] {
+        return openChannelReceiveStageList.map { v -> [String: Any?] in return dictionaryOf(openChannelReceiveStage: v) }
+    }
+
+    static func asOpenChannelReceiveStageList(arr: [Any]) throws -> [OpenChannelReceiveStage] {
+        var list = [OpenChannelReceiveStage]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var openChannelReceiveStage = try asOpenChannelReceiveStage(openChannelReceiveStage: val)
+                list.append(openChannelReceiveStage)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OpenChannelReceiveStage"))
+            }
+        }
+        return list
+    }
+
+    static func asOutboxPaymentStatus(outboxPaymentStatus: [String: Any?]) throws -> OutboxPaymentStatus {
+        let type = outboxPaymentStatus["type"] as! String
+        if type == "queued" {
+            return OutboxPaymentStatus.queued
+        }
+        if type == "sent" {
+            guard let paymentTmp = outboxPaymentStatus["payment"] as? [String: Any?] else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "payment", typeName: "OutboxPaymentStatus"))
+            }
+            let _payment = try asPayment(payment: paymentTmp)
+
+            return OutboxPaymentStatus.sent(payment: _payment)
+        }
+        if type == "abandoned" {
+            return OutboxPaymentStatus.abandoned
+        }
+        if type == "cancelled" {
+            return OutboxPaymentStatus.cancelled
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum OutboxPaymentStatus")
+    }
+
+    static func dictionaryOf(outboxPaymentStatus: OutboxPaymentStatus) -> [String: Any?] {
+        switch outboxPaymentStatus {
+        case .queued:
+            return [
+                "type": "queued",
+            ]
+
+        case let .sent(
+            payment
+        ):
+            return [
+                "type": "sent",
+                "payment": dictionaryOf(payment: payment),
+            ]
+
+        case .abandoned:
+            return [
+                "type": "abandoned",
+            ]
+
+        case .cancelled:
+            return [
+                "type": "cancelled",
+            ]
+        }
+    }
+
+    static func arrayOf(outboxPaymentStatusList: [OutboxPaymentStatus]) -> [Any] {
+        return outboxPaymentStatusList.map { v -> [String: Any?] in return dictionaryOf(outboxPaymentStatus: v) }
+    }
+
+    static func asOutboxPaymentStatusList(arr: [Any]) throws -> [OutboxPaymentStatus] {
+        var list = [OutboxPaymentStatus]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var outboxPaymentStatus = try asOutboxPaymentStatus(outboxPaymentStatus: val)
+                list.append(outboxPaymentStatus)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "OutboxPaymentStatus"))
+            }
+        }
+        return list
+    }
+
+    static func asPartnerFeeDestination(partnerFeeDestination: [String: Any?]) throws -> PartnerFeeDestination {
+        let type = partnerFeeDestination["type"] as! String
+        if type == "nodeId" {
+            guard let _nodeId = partnerFeeDestination["nodeId"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "nodeId", typeName: "PartnerFeeDestination"))
+            }
+            return PartnerFeeDestination.nodeId(nodeId: _nodeId)
+        }
+        if type == "lightningAddress" {
+            guard let _address = partnerFeeDestination["address"] as? String else {
+                throw SdkError.Generic(message: errMissingMandatoryField(fieldName: "address", typeName: "PartnerFeeDestination"))
+            }
+            return PartnerFeeDestination.lightningAddress(address: _address)
+        }
+
+        throw SdkError.Generic(message: "Unexpected type \(type) for enum PartnerFeeDestination")
+    }
+
+    static func dictionaryOf(partnerFeeDestination: PartnerFeeDestination) -> [String: Any?] {
+        switch partnerFeeDestination {
+        case let .nodeId(
+            nodeId
+        ):
+            return [
+                "type": "nodeId",
+                "nodeId": nodeId,
+            ]
+
+        case let .lightningAddress(
+            address
+        ):
+            return [
+                "type": "lightningAddress",
+                "address": address,
+            ]
+        }
+    }
+
+    static func arrayOf(partnerFeeDestinationList: [PartnerFeeDestination]) -> [Any] {
+        return partnerFeeDestinationList.map { v -> [String: Any?] in return dictionaryOf(partnerFeeDestination: v) }
+    }
+
+    static func asPartnerFeeDestinationList(arr: [Any]) throws -> [PartnerFeeDestination] {
+        var list = [PartnerFeeDestination]()
+        for value in arr {
+            if let val = value as? [String: Any?] {
+                var partnerFeeDestination = try asPartnerFeeDestination(partnerFeeDestination: val)
+                list.append(partnerFeeDestination)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PartnerFeeDestination"))
+            }
+        }
+        return list
+    }
+
     static func asPaymentDetails(paymentDetails: [String: Any?]) throws -> PaymentDetails {
         let type = paymentDetails["type"] as! String
         if type == "ln" {
@@ -4850,6 +11069,45 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asPaymentGroupBy(paymentGroupBy: String) throws -> PaymentGroupBy {
+        switch paymentGroupBy {
+        case "destinationPubkey":
+            return PaymentGroupBy.destinationPubkey
+
+        case "lnAddress":
+            return PaymentGroupBy.lnAddress
+
+        default: throw SdkError.Generic(message: "Invalid variant \(paymentGroupBy) for enum PaymentGroupBy")
+        }
+    }
+
+    static func valueOf(paymentGroupBy: PaymentGroupBy) -> String {
+        switch paymentGroupBy {
+        case .destinationPubkey:
+            return "destinationPubkey"
+
+        case .lnAddress:
+            return "lnAddress"
+        }
+    }
+
+    static func arrayOf(paymentGroupByList: [PaymentGroupBy]) -> [String] {
+        return paymentGroupByList.map { v -> String in return valueOf(paymentGroupBy: v) }
+    }
+
+    static func asPaymentGroupByList(arr: [Any]) throws -> [PaymentGroupBy] {
+        var list = [PaymentGroupBy]()
+        for value in arr {
+            if let val = value as? String {
+                var paymentGroupBy = try asPaymentGroupBy(paymentGroupBy: val)
+                list.append(paymentGroupBy)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "PaymentGroupBy"))
+            }
+        }
+        return list
+    }
+
     static func asPaymentStatus(paymentStatus: String) throws -> PaymentStatus {
         switch paymentStatus {
         case "pending":
@@ -4951,6 +11209,9 @@ enum BreezSDKMapper {
         case "closedChannel":
             return PaymentTypeFilter.closedChannel
 
+        case "spontaneousReceived":
+            return PaymentTypeFilter.spontaneousReceived
+
         default: throw SdkError.Generic(message: "Invalid variant \(paymentTypeFilter) for enum PaymentTypeFilter")
         }
     }
@@ -4965,6 +11226,9 @@ enum BreezSDKMapper {
 
         case .closedChannel:
             return "closedChannel"
+
+        case .spontaneousReceived:
+            return "spontaneousReceived"
         }
     }
 
@@ -4985,6 +11249,51 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asReceiveLimitReason(receiveLimitReason: String) throws -> ReceiveLimitReason {
+        switch receiveLimitReason {
+        case "maxInboundLiquidity":
+            return ReceiveLimitReason.maxInboundLiquidity
+
+        case "maxPaymentAmount":
+            return ReceiveLimitReason.maxPaymentAmount
+
+        case "maxSwapDeposit":
+            return ReceiveLimitReason.maxSwapDeposit
+
+        default: throw SdkError.Generic(message: "Invalid variant \(receiveLimitReason) for enum ReceiveLimitReason")
+        }
+    }
+
+    static func valueOf(receiveLimitReason: ReceiveLimitReason) -> String {
+        switch receiveLimitReason {
+        case .maxInboundLiquidity:
+            return "maxInboundLiquidity"
+
+        case .maxPaymentAmount:
+            return "maxPaymentAmount"
+
+        case .maxSwapDeposit:
+            return "maxSwapDeposit"
+        }
+    }
+
+    static func arrayOf(receiveLimitReasonList: [ReceiveLimitReason]) -> [String] {
+        return receiveLimitReasonList.map { v -> String in return valueOf(receiveLimitReason: v) }
+    }
+
+    static func asReceiveLimitReasonList(arr: [Any]) throws -> [ReceiveLimitReason] {
+        var list = [ReceiveLimitReason]()
+        for value in arr {
+            if let val = value as? String {
+                var receiveLimitReason = try asReceiveLimitReason(receiveLimitReason: val)
+                list.append(receiveLimitReason)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReceiveLimitReason"))
+            }
+        }
+        return list
+    }
+
     static func asReportIssueRequest(reportIssueRequest: [String: Any?]) throws -> ReportIssueRequest {
         let type = reportIssueRequest["type"] as! String
         if type == "paymentFailure" {
@@ -5028,6 +11337,45 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asReverseSwapProtocol(reverseSwapProtocol: String) throws -> ReverseSwapProtocol {
+        switch reverseSwapProtocol {
+        case "legacy":
+            return ReverseSwapProtocol.legacy
+
+        case "taproot":
+            return ReverseSwapProtocol.taproot
+
+        default: throw SdkError.Generic(message: "Invalid variant \(reverseSwapProtocol) for enum ReverseSwapProtocol")
+        }
+    }
+
+    static func valueOf(reverseSwapProtocol: ReverseSwapProtocol) -> String {
+        switch reverseSwapProtocol {
+        case .legacy:
+            return "legacy"
+
+        case .taproot:
+            return "taproot"
+        }
+    }
+
+    static func arrayOf(reverseSwapProtocolList: [ReverseSwapProtocol]) -> [String] {
+        return reverseSwapProtocolList.map { v -> String in return valueOf(reverseSwapProtocol: v) }
+    }
+
+    static func asReverseSwapProtocolList(arr: [Any]) throws -> [ReverseSwapProtocol] {
+        var list = [ReverseSwapProtocol]()
+        for value in arr {
+            if let val = value as? String {
+                var reverseSwapProtocol = try asReverseSwapProtocol(reverseSwapProtocol: val)
+                list.append(reverseSwapProtocol)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ReverseSwapProtocol"))
+            }
+        }
+        return list
+    }
+
     static func asReverseSwapStatus(reverseSwapStatus: String) throws -> ReverseSwapStatus {
         switch reverseSwapStatus {
         case "initial":
@@ -5085,6 +11433,129 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asServiceComponent(serviceComponent: String) throws -> ServiceComponent {
+        switch serviceComponent {
+        case "lsp":
+            return ServiceComponent.lsp
+
+        case "greenlight":
+            return ServiceComponent.greenlight
+
+        case "swapper":
+            return ServiceComponent.swapper
+
+        default: throw SdkError.Generic(message: "Invalid variant \(serviceComponent) for enum ServiceComponent")
+        }
+    }
+
+    static func valueOf(serviceComponent: ServiceComponent) -> String {
+        switch serviceComponent {
+        case .lsp:
+            return "lsp"
+
+        case .greenlight:
+            return "greenlight"
+
+        case .swapper:
+            return "swapper"
+        }
+    }
+
+    static func arrayOf(serviceComponentList: [ServiceComponent]) -> [String] {
+        return serviceComponentList.map { v -> String in return valueOf(serviceComponent: v) }
+    }
+
+    static func asServiceComponentList(arr: [Any]) throws -> [ServiceComponent] {
+        var list = [ServiceComponent]()
+        for value in arr {
+            if let val = value as? String {
+                var serviceComponent = try asServiceComponent(serviceComponent: val)
+                list.append(serviceComponent)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ServiceComponent"))
+            }
+        }
+        return list
+    }
+
+    static func asServiceStatus(serviceStatus: String) throws -> ServiceStatus {
+        switch serviceStatus {
+        case "operational":
+            return ServiceStatus.operational
+
+        case "degraded":
+            return ServiceStatus.degraded
+
+        default: throw SdkError.Generic(message: "Invalid variant \(serviceStatus) for enum ServiceStatus")
+        }
+    }
+
+    static func valueOf(serviceStatus: ServiceStatus) -> String {
+        switch serviceStatus {
+        case .operational:
+            return "operational"
+
+        case .degraded:
+            return "degraded"
+        }
+    }
+
+    static func arrayOf(serviceStatusList: [ServiceStatus]) -> [String] {
+        return serviceStatusList.map { v -> String in return valueOf(serviceStatus: v) }
+    }
+
+    static func asServiceStatusList(arr: [Any]) throws -> [ServiceStatus] {
+        var list = [ServiceStatus]()
+        for value in arr {
+            if let val = value as? String {
+                var serviceStatus = try asServiceStatus(serviceStatus: val)
+                list.append(serviceStatus)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "ServiceStatus"))
+            }
+        }
+        return list
+    }
+
+    static func asSortOrder(sortOrder: String) throws -> SortOrder {
+        switch sortOrder {
+        case "descending":
+            return SortOrder.descending
+
+        case "ascending":
+            return SortOrder.ascending
+
+        default: throw SdkError.Generic(message: "Invalid variant \(sortOrder) for enum SortOrder")
+        }
+    }
+
+    static func valueOf(sortOrder: SortOrder) -> String {
+        switch sortOrder {
+        case .descending:
+            return "descending"
+
+        case .ascending:
+            return "ascending"
+        }
+    }
+
+    static func arrayOf(sortOrderList: [SortOrder]) -> [String] {
+        return sortOrderList.map { v -> String in return valueOf(sortOrder: v) }
+    }
+
+    static func asSortOrderList(arr: [Any]) throws -> [SortOrder] {
+        var list = [SortOrder]()
+        for value in arr {
+            if let val = value as? String {
+                var sortOrder = try asSortOrder(sortOrder: val)
+                list.append(sortOrder)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "SortOrder"))
+            }
+        }
+        return list
+    }
+
     static func asSuccessActionProcessed(successActionProcessed: [String: Any?]) throws -> SuccessActionProcessed {
         let type = successActionProcessed["type"] as! String
         if type == "aes" {
@@ -5262,6 +11733,51 @@ enum BreezSDKMapper {
         return list
     }
 
+    static func asTaskState(taskState: String) throws -> TaskState {
+        switch taskState {
+        case "running":
+            return TaskState.running
+
+        case "restarting":
+            return TaskState.restarting
+
+        case "stopped":
+            return TaskState.stopped
+
+        default: throw SdkError.Generic(message: "Invalid variant \(taskState) for enum TaskState")
+        }
+    }
+
+    static func valueOf(taskState: TaskState) -> String {
+        switch taskState {
+        case .running:
+            return "running"
+
+        case .restarting:
+            return "restarting"
+
+        case .stopped:
+            return "stopped"
+        }
+    }
+
+    static func arrayOf(taskStateList: [TaskState]) -> [String] {
+        return taskStateList.map { v -> String in return valueOf(taskState: v) }
+    }
+
+    static func asTaskStateList(arr: [Any]) throws -> [TaskState] {
+        var list = [TaskState]()
+        for value in arr {
+            if let val = value as? String {
+                var taskState = try asTaskState(taskState: val)
+                list.append(taskState)
+            } else {
+                throw SdkError.Generic(message: errUnexpectedType(typeName: "TaskState"))
+            }
+        }
+        return list
+    }
+
     static func hasNonNilKey(data: [String: Any?], key: String) -> Bool {
         if let val = data[key] {
             return !(val == nil || val is NSNull)
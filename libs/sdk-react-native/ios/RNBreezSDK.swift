@@ -66,6 +66,27 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(connectEphemeral:listener:resolve:reject:)
+    func connectEphemeral(_ config: [String: Any], listener: EventListener, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let config = try BreezSDKMapper.asConfig(config: config)
+            var res = try BreezSDK.connectEphemeral(config: config, listener: listener)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(setLogFilter:resolve:reject:)
+    func setLogFilter(_ filter: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try BreezSDK.setLogFilter(filter: filter)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(parseInvoice:resolve:reject:)
     func parseInvoice(_ invoice: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -109,6 +130,16 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(sdkInfo:reject:)
+    func sdkInfo(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = BreezSDK.sdkInfo()
+            resolve(BreezSDKMapper.dictionaryOf(sdkInfo: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(staticBackup:resolve:reject:)
     func staticBackup(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -120,6 +151,17 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(decryptRecoveryBundle:resolve:reject:)
+    func decryptRecoveryBundle(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let decryptRecoveryBundleRequest = try BreezSDKMapper.asDecryptRecoveryBundleRequest(decryptRecoveryBundleRequest: req)
+            var res = try BreezSDK.decryptRecoveryBundle(req: decryptRecoveryBundleRequest)
+            resolve(BreezSDKMapper.dictionaryOf(recoveryBundle: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(serviceHealthCheck:resolve:reject:)
     func serviceHealthCheck(_ apiKey: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -130,6 +172,16 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(diffSnapshots:b:resolve:reject:)
+    func diffSnapshots(_ a: String, b: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try BreezSDK.diffSnapshots(a: a, b: b)
+            resolve(BreezSDKMapper.arrayOf(snapshotChangeList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(setLogStream:reject:)
     func setLogStream(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -191,6 +243,58 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(sendPayments:resolve:reject:)
+    func sendPayments(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let sendPaymentsRequest = try BreezSDKMapper.asSendPaymentsRequest(sendPaymentsRequest: req)
+            var res = try getBreezServices().sendPayments(req: sendPaymentsRequest)
+            resolve(BreezSDKMapper.dictionaryOf(sendPaymentsResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(verifyInvoices:resolve:reject:)
+    func verifyInvoices(_ invoices: [String], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = getBreezServices().verifyInvoices(invoices: invoices)
+            resolve(BreezSDKMapper.arrayOf(invoiceVerificationResultList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(queuePayment:resolve:reject:)
+    func queuePayment(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let queuePaymentRequest = try BreezSDKMapper.asQueuePaymentRequest(queuePaymentRequest: req)
+            var res = try getBreezServices().queuePayment(req: queuePaymentRequest)
+            resolve(BreezSDKMapper.dictionaryOf(outboxPayment: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(listPendingOutbox:reject:)
+    func listPendingOutbox(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listPendingOutbox()
+            resolve(BreezSDKMapper.arrayOf(outboxPaymentList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(cancelOutboxPayment:resolve:reject:)
+    func cancelOutboxPayment(_ id: Int64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBreezServices().cancelOutboxPayment(id: id)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(sendSpontaneousPayment:resolve:reject:)
     func sendSpontaneousPayment(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -202,6 +306,81 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(cancel:resolve:reject:)
+    func cancel(_ cancelToken: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().cancel(cancelToken: cancelToken)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(probePayment:resolve:reject:)
+    func probePayment(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let probePaymentRequest = try BreezSDKMapper.asProbePaymentRequest(probePaymentRequest: req)
+            var res = try getBreezServices().probePayment(req: probePaymentRequest)
+            resolve(BreezSDKMapper.dictionaryOf(paymentProbe: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(listPaymentProbes:reject:)
+    func listPaymentProbes(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listPaymentProbes()
+            resolve(BreezSDKMapper.arrayOf(paymentProbeList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(validatePayability:resolve:reject:)
+    func validatePayability(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let validatePayabilityRequest = try BreezSDKMapper.asValidatePayabilityRequest(validatePayabilityRequest: req)
+            var res = try getBreezServices().validatePayability(req: validatePayabilityRequest)
+            resolve(BreezSDKMapper.dictionaryOf(invoicePayability: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(payOffer:resolve:reject:)
+    func payOffer(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let payOfferRequest = try BreezSDKMapper.asPayOfferRequest(payOfferRequest: req)
+            var res = try getBreezServices().payOffer(req: payOfferRequest)
+            resolve(BreezSDKMapper.dictionaryOf(sendPaymentResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(prepareSendPayment:resolve:reject:)
+    func prepareSendPayment(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let prepareSendPaymentRequest = try BreezSDKMapper.asPrepareSendPaymentRequest(prepareSendPaymentRequest: req)
+            var res = try getBreezServices().prepareSendPayment(req: prepareSendPaymentRequest)
+            resolve(BreezSDKMapper.dictionaryOf(prepareSendPaymentResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(prepareReceivePayment:resolve:reject:)
+    func prepareReceivePayment(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let prepareReceivePaymentRequest = try BreezSDKMapper.asPrepareReceivePaymentRequest(prepareReceivePaymentRequest: req)
+            var res = try getBreezServices().prepareReceivePayment(req: prepareReceivePaymentRequest)
+            resolve(BreezSDKMapper.dictionaryOf(prepareReceivePaymentResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(receivePayment:resolve:reject:)
     func receivePayment(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -213,6 +392,28 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(receiveUnified:resolve:reject:)
+    func receiveUnified(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let receiveUnifiedRequest = try BreezSDKMapper.asReceiveUnifiedRequest(receiveUnifiedRequest: req)
+            var res = try getBreezServices().receiveUnified(req: receiveUnifiedRequest)
+            resolve(BreezSDKMapper.dictionaryOf(receiveUnifiedResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(amendInvoice:resolve:reject:)
+    func amendInvoice(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let amendInvoiceRequest = try BreezSDKMapper.asAmendInvoiceRequest(amendInvoiceRequest: req)
+            var res = try getBreezServices().amendInvoice(req: amendInvoiceRequest)
+            resolve(BreezSDKMapper.dictionaryOf(lnInvoice: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(payLnurl:resolve:reject:)
     func payLnurl(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -246,6 +447,47 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(listLnurlAuthDomains:reject:)
+    func listLnurlAuthDomains(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listLnurlAuthDomains()
+            resolve(BreezSDKMapper.arrayOf(lnUrlAuthDomainList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(lnurlAuthLinkingKey:resolve:reject:)
+    func lnurlAuthLinkingKey(_ domain: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().lnurlAuthLinkingKey(domain: domain)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(exportLnurlAuth:reject:)
+    func exportLnurlAuth(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().exportLnurlAuth()
+            resolve(BreezSDKMapper.dictionaryOf(lnUrlAuthExport: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(importLnurlAuth:resolve:reject:)
+    func importLnurlAuth(_ export: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let lnUrlAuthExport = try BreezSDKMapper.asLnUrlAuthExport(lnUrlAuthExport: export)
+            try getBreezServices().importLnurlAuth(export: lnUrlAuthExport)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(reportIssue:resolve:reject:)
     func reportIssue(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -257,6 +499,36 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(peerConnectivity:reject:)
+    func peerConnectivity(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().peerConnectivity()
+            resolve(BreezSDKMapper.dictionaryOf(peerConnectivity: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(connectionStatus:reject:)
+    func connectionStatus(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().connectionStatus()
+            resolve(BreezSDKMapper.dictionaryOf(connectionStatus: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(taskStatus:reject:)
+    func taskStatus(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().taskStatus()
+            resolve(BreezSDKMapper.arrayOf(taskStatusList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(nodeCredentials:reject:)
     func nodeCredentials(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -281,6 +553,26 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(balances:reject:)
+    func balances(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().balances()
+            resolve(BreezSDKMapper.dictionaryOf(balances: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(getBalanceBreakdown:reject:)
+    func getBalanceBreakdown(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().getBalanceBreakdown()
+            resolve(BreezSDKMapper.dictionaryOf(balanceBreakdown: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(signMessage:resolve:reject:)
     func signMessage(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -292,6 +584,50 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(deriveEncryptionKey:resolve:reject:)
+    func deriveEncryptionKey(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let deriveEncryptionKeyRequest = try BreezSDKMapper.asDeriveEncryptionKeyRequest(deriveEncryptionKeyRequest: req)
+            var res = try getBreezServices().deriveEncryptionKey(req: deriveEncryptionKeyRequest)
+            resolve(BreezSDKMapper.dictionaryOf(deriveEncryptionKeyResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(proveAddressOwnership:resolve:reject:)
+    func proveAddressOwnership(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let proveAddressOwnershipRequest = try BreezSDKMapper.asProveAddressOwnershipRequest(proveAddressOwnershipRequest: req)
+            var res = try getBreezServices().proveAddressOwnership(req: proveAddressOwnershipRequest)
+            resolve(BreezSDKMapper.dictionaryOf(proveAddressOwnershipResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(encryptPaymentRequest:resolve:reject:)
+    func encryptPaymentRequest(_ bundle: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let paymentRequestBundle = try BreezSDKMapper.asPaymentRequestBundle(paymentRequestBundle: bundle)
+            var res = try getBreezServices().encryptPaymentRequest(bundle: paymentRequestBundle)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(decryptPaymentRequest:resolve:reject:)
+    func decryptPaymentRequest(_ data: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let encryptedPaymentRequestData = try BreezSDKMapper.asEncryptedPaymentRequestData(encryptedPaymentRequestData: data)
+            var res = try getBreezServices().decryptPaymentRequest(data: encryptedPaymentRequestData)
+            resolve(BreezSDKMapper.dictionaryOf(paymentRequestBundle: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(checkMessage:resolve:reject:)
     func checkMessage(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -323,6 +659,36 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(rotateBackupKey:reject:)
+    func rotateBackupKey(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBreezServices().rotateBackupKey()
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(listBackupVersions:reject:)
+    func listBackupVersions(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listBackupVersions()
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(restoreBackup:resolve:reject:)
+    func restoreBackup(_ version: UInt64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBreezServices().restoreBackup(version: version)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(listPayments:resolve:reject:)
     func listPayments(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -334,6 +700,39 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(listPaymentGroups:resolve:reject:)
+    func listPaymentGroups(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let listPaymentGroupsRequest = try BreezSDKMapper.asListPaymentGroupsRequest(listPaymentGroupsRequest: req)
+            var res = try getBreezServices().listPaymentGroups(req: listPaymentGroupsRequest)
+            resolve(BreezSDKMapper.arrayOf(paymentGroupList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(exportPayments:resolve:reject:)
+    func exportPayments(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let exportPaymentsRequest = try BreezSDKMapper.asExportPaymentsRequest(exportPaymentsRequest: req)
+            var res = try getBreezServices().exportPayments(req: exportPaymentsRequest)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(importPayments:resolve:reject:)
+    func importPayments(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let importPaymentsRequest = try BreezSDKMapper.asImportPaymentsRequest(importPaymentsRequest: req)
+            var res = try getBreezServices().importPayments(req: importPaymentsRequest)
+            resolve(BreezSDKMapper.dictionaryOf(importPaymentsResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(paymentByHash:resolve:reject:)
     func paymentByHash(_ hash: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -348,6 +747,16 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(listChannelFeesPaid:reject:)
+    func listChannelFeesPaid(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listChannelFeesPaid()
+            resolve(BreezSDKMapper.arrayOf(channelOpeningFeeList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(setPaymentMetadata:metadata:resolve:reject:)
     func setPaymentMetadata(_ hash: String, metadata: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -369,6 +778,17 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(bumpFee:resolve:reject:)
+    func bumpFee(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let bumpFeeRequest = try BreezSDKMapper.asBumpFeeRequest(bumpFeeRequest: req)
+            var res = try getBreezServices().bumpFee(req: bumpFeeRequest)
+            resolve(BreezSDKMapper.dictionaryOf(bumpFeeResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(fetchFiatRates:reject:)
     func fetchFiatRates(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -379,6 +799,49 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(fiatRateHistory:fromTimestamp:toTimestamp:resolve:reject:)
+    func fiatRateHistory(_ currency: String, fromTimestamp: Int64, toTimestamp: Int64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let fromTimestampTmp = fromTimestamp == 0 ? nil : fromTimestamp
+            let toTimestampTmp = toTimestamp == 0 ? nil : toTimestamp
+            var res = try getBreezServices().fiatRateHistory(currency: currency, fromTimestamp: fromTimestampTmp, toTimestamp: toTimestampTmp)
+            resolve(BreezSDKMapper.arrayOf(historicalRateList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(addFiatRateAlert:resolve:reject:)
+    func addFiatRateAlert(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let addFiatRateAlertRequest = try BreezSDKMapper.asAddFiatRateAlertRequest(addFiatRateAlertRequest: req)
+            var res = try getBreezServices().addFiatRateAlert(req: addFiatRateAlertRequest)
+            resolve(BreezSDKMapper.dictionaryOf(fiatRateAlert: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(listFiatRateAlerts:reject:)
+    func listFiatRateAlerts(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listFiatRateAlerts()
+            resolve(BreezSDKMapper.arrayOf(fiatRateAlertList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(removeFiatRateAlert:resolve:reject:)
+    func removeFiatRateAlert(_ id: Int64, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBreezServices().removeFiatRateAlert(id: id)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(listFiatCurrencies:reject:)
     func listFiatCurrencies(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -448,6 +911,27 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(userSettings:reject:)
+    func userSettings(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().userSettings()
+            resolve(BreezSDKMapper.dictionaryOf(userSettings: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(setUserSettings:resolve:reject:)
+    func setUserSettings(_ settings: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let userSettings = try BreezSDKMapper.asUserSettings(userSettings: settings)
+            try getBreezServices().setUserSettings(settings: userSettings)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(lspInfo:reject:)
     func lspInfo(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -468,6 +952,81 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(listChannels:reject:)
+    func listChannels(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listChannels()
+            resolve(BreezSDKMapper.arrayOf(channelDetailsList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(closeChannel:resolve:reject:)
+    func closeChannel(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let closeChannelRequest = try BreezSDKMapper.asCloseChannelRequest(closeChannelRequest: req)
+            var res = try getBreezServices().closeChannel(req: closeChannelRequest)
+            resolve(BreezSDKMapper.dictionaryOf(closeChannelResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(connectPeer:resolve:reject:)
+    func connectPeer(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let connectPeerRequest = try BreezSDKMapper.asConnectPeerRequest(connectPeerRequest: req)
+            try getBreezServices().connectPeer(req: connectPeerRequest)
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(openChannel:resolve:reject:)
+    func openChannel(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let openChannelRequest = try BreezSDKMapper.asOpenChannelRequest(openChannelRequest: req)
+            var res = try getBreezServices().openChannel(req: openChannelRequest)
+            resolve(BreezSDKMapper.dictionaryOf(openChannelResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(exportNodeMigration:resolve:reject:)
+    func exportNodeMigration(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let nodeMigrationRequest = try BreezSDKMapper.asNodeMigrationRequest(nodeMigrationRequest: req)
+            var res = try getBreezServices().exportNodeMigration(req: nodeMigrationRequest)
+            resolve(BreezSDKMapper.dictionaryOf(nodeMigrationState: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(exportRecoveryBundle:resolve:reject:)
+    func exportRecoveryBundle(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let exportRecoveryBundleRequest = try BreezSDKMapper.asExportRecoveryBundleRequest(exportRecoveryBundleRequest: req)
+            var res = try getBreezServices().exportRecoveryBundle(req: exportRecoveryBundleRequest)
+            resolve(BreezSDKMapper.dictionaryOf(exportRecoveryBundleResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(stopRecoveryBundleRefresh:reject:)
+    func stopRecoveryBundleRefresh(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBreezServices().stopRecoveryBundleRefresh()
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(registerWebhook:resolve:reject:)
     func registerWebhook(_ webhookUrl: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -488,6 +1047,84 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(registerLightningAddress:resolve:reject:)
+    func registerLightningAddress(_ username: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().registerLightningAddress(username: username)
+            resolve(BreezSDKMapper.dictionaryOf(lnurlPayInfo: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(lnurlPayInfo:reject:)
+    func lnurlPayInfo(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().lnurlPayInfo()
+            if res != nil {
+                resolve(BreezSDKMapper.dictionaryOf(lnurlPayInfo: res!))
+            } else {
+                resolve(nil)
+            }
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(createStaticLnurlPay:resolve:reject:)
+    func createStaticLnurlPay(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let createStaticLnurlPayRequest = try BreezSDKMapper.asCreateStaticLnurlPayRequest(createStaticLnurlPayRequest: req)
+            var res = try getBreezServices().createStaticLnurlPay(req: createStaticLnurlPayRequest)
+            resolve(BreezSDKMapper.dictionaryOf(staticLnurlPay: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(staticLnurlPayCallback:resolve:reject:)
+    func staticLnurlPayCallback(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let staticLnurlPayCallbackRequest = try BreezSDKMapper.asStaticLnurlPayCallbackRequest(staticLnurlPayCallbackRequest: req)
+            var res = try getBreezServices().staticLnurlPayCallback(req: staticLnurlPayCallbackRequest)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(createLnurlWithdrawVoucher:resolve:reject:)
+    func createLnurlWithdrawVoucher(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let createLnurlWithdrawVoucherRequest = try BreezSDKMapper.asCreateLnurlWithdrawVoucherRequest(createLnurlWithdrawVoucherRequest: req)
+            var res = try getBreezServices().createLnurlWithdrawVoucher(req: createLnurlWithdrawVoucherRequest)
+            resolve(BreezSDKMapper.dictionaryOf(lnurlWithdrawVoucher: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(redeemLnurlWithdrawVoucher:resolve:reject:)
+    func redeemLnurlWithdrawVoucher(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let redeemLnurlWithdrawVoucherRequest = try BreezSDKMapper.asRedeemLnurlWithdrawVoucherRequest(redeemLnurlWithdrawVoucherRequest: req)
+            var res = try getBreezServices().redeemLnurlWithdrawVoucher(req: redeemLnurlWithdrawVoucherRequest)
+            resolve(BreezSDKMapper.dictionaryOf(sendPaymentResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(listLnurlWithdrawVouchers:reject:)
+    func listLnurlWithdrawVouchers(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().listLnurlWithdrawVouchers()
+            resolve(BreezSDKMapper.arrayOf(lnurlWithdrawVoucherList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(receiveOnchain:resolve:reject:)
     func receiveOnchain(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -533,6 +1170,16 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(verifySwapAddress:resolve:reject:)
+    func verifySwapAddress(_ swapAddress: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().verifySwapAddress(swapAddress: swapAddress)
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(listRefundables:reject:)
     func listRefundables(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -543,6 +1190,17 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(exportSwapRefundKeys:resolve:reject:)
+    func exportSwapRefundKeys(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let exportSwapRefundKeysRequest = try BreezSDKMapper.asExportSwapRefundKeysRequest(exportSwapRefundKeysRequest: req)
+            var res = try getBreezServices().exportSwapRefundKeys(req: exportSwapRefundKeysRequest)
+            resolve(BreezSDKMapper.arrayOf(swapRefundKeyList: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(prepareRefund:resolve:reject:)
     func prepareRefund(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -639,6 +1297,17 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(payOnchainAddress:resolve:reject:)
+    func payOnchainAddress(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let payOnchainAddressRequest = try BreezSDKMapper.asPayOnchainAddressRequest(payOnchainAddressRequest: req)
+            var res = try getBreezServices().payOnchainAddress(req: payOnchainAddressRequest)
+            resolve(BreezSDKMapper.dictionaryOf(payOnchainAddressResponse: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(executeDevCommand:resolve:reject:)
     func executeDevCommand(_ command: String, resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -659,6 +1328,16 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(diagnosticSnapshot:reject:)
+    func diagnosticSnapshot(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().diagnosticSnapshot()
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(sync:reject:)
     func sync(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -669,6 +1348,36 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(cancelSync:reject:)
+    func cancelSync(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().cancelSync()
+            resolve(res)
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(metrics:reject:)
+    func metrics(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            var res = try getBreezServices().metrics()
+            resolve(BreezSDKMapper.dictionaryOf(metricsSnapshot: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
+    @objc(syncPartial:reject:)
+    func syncPartial(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            try getBreezServices().syncPartial()
+            resolve(["status": "ok"])
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(recommendedFees:reject:)
     func recommendedFees(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
@@ -690,6 +1399,17 @@ class RNBreezSDK: RCTEventEmitter {
         }
     }
 
+    @objc(fetchBuyBitcoinQuote:resolve:reject:)
+    func fetchBuyBitcoinQuote(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
+        do {
+            let fetchBuyBitcoinQuoteRequest = try BreezSDKMapper.asFetchBuyBitcoinQuoteRequest(fetchBuyBitcoinQuoteRequest: req)
+            var res = try getBreezServices().fetchBuyBitcoinQuote(req: fetchBuyBitcoinQuoteRequest)
+            resolve(BreezSDKMapper.dictionaryOf(buyBitcoinQuote: res))
+        } catch let err {
+            rejectErr(err: err, reject: reject)
+        }
+    }
+
     @objc(prepareRedeemOnchainFunds:resolve:reject:)
     func prepareRedeemOnchainFunds(_ req: [String: Any], resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) {
         do {
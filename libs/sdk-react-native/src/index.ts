@@ -19,11 +19,29 @@ const BreezSDK = NativeModules.RNBreezSDK
 
 const BreezSDKEmitter = new NativeEventEmitter(BreezSDK)
 
+export interface AddFiatRateAlertRequest {
+    currency: string
+    direction: FiatRateAlertDirection
+    threshold: number
+}
+
 export interface AesSuccessActionDataDecrypted {
     description: string
     plaintext: string
 }
 
+export interface AmendInvoiceRequest {
+    bolt11: string
+    amountMsat?: number
+    expiry?: number
+    routeHints?: RouteHint[]
+}
+
+export interface AutoRefundConfig {
+    toAddress?: string
+    satPerVbyte?: number
+}
+
 export interface BackupFailedData {
     error: string
 }
@@ -33,6 +51,43 @@ export interface BackupStatus {
     lastBackupTime?: number
 }
 
+export interface BalanceBreakdown {
+    spendableMsat: number
+    channels: ChannelDetails[]
+    channelReservesMsat: number
+    htlcsOutMsat: number
+    htlcsInMsat: number
+    dustHtlcsMsat: number
+    pendingChannelOpensMsat: number
+    onchainConfirmedMsat: number
+    onchainUnconfirmedMsat: number
+    swapsLockedMsat: number
+    reverseSwapsLockedMsat: number
+}
+
+export interface Balances {
+    lightningSpendableMsat: number
+    lightningPendingMsat: number
+    onchainConfirmedMsat: number
+    onchainUnconfirmedMsat: number
+    swapsLockedMsat: number
+    reverseSwapsLockedMsat: number
+}
+
+export interface BatchPaymentProgressDetails {
+    result: BatchPaymentResult
+    succeeded: number
+    failed: number
+    total: number
+}
+
+export interface BatchPaymentResult {
+    index: number
+    bolt11: string
+    payment?: Payment
+    error?: string
+}
+
 export interface BitcoinAddressData {
     address: string
     network: Network
@@ -41,6 +96,22 @@ export interface BitcoinAddressData {
     message?: string
 }
 
+export interface BumpFeeRequest {
+    txid: string
+    satPerVbyte: number
+}
+
+export interface BumpFeeResponse {
+    txid: string
+}
+
+export interface BuyBitcoinQuote {
+    fiatCurrency: string
+    fiatAmount: number
+    feesFiat: number
+    amountSat: number
+}
+
 export interface BuyBitcoinRequest {
     provider: BuyBitcoinProvider
     openingFeeParams?: OpeningFeeParams
@@ -52,6 +123,45 @@ export interface BuyBitcoinResponse {
     openingFeeParams?: OpeningFeeParams
 }
 
+export interface ChannelDetails {
+    channelId: string
+    peerId: string
+    peerConnected: boolean
+    shortChannelId?: string
+    fundingTxid: string
+    state: ChannelState
+    capacityMsat: number
+    localBalanceMsat: number
+    remoteBalanceMsat: number
+    spendableMsat: number
+    receivableMsat: number
+    reserveMsat: number
+    htlcsOutMsat: number
+    htlcsInMsat: number
+    dustHtlcsMsat: number
+}
+
+export interface ChannelHygieneSuggestionDetails {
+    channelId: string
+    peerId: string
+    fundingTxid: string
+    localBalanceMsat: number
+    idleSecs: number
+    peerOfflineSecs: number
+    satPerVbyte: number
+    projectedSweepFeeSat: number
+    projectedSweepAmountSat: number
+}
+
+export interface ChannelOpeningFee {
+    paymentHash: string
+    paymentTime: number
+    payerAmountMsat: number
+    receivedAmountMsat: number
+    feeMsat: number
+    openingFeeParams?: OpeningFeeParams
+}
+
 export interface CheckMessageRequest {
     message: string
     pubkey: string
@@ -62,6 +172,15 @@ export interface CheckMessageResponse {
     isValid: boolean
 }
 
+export interface CloseChannelRequest {
+    channelId: string
+    force: boolean
+}
+
+export interface CloseChannelResponse {
+    closingTxid?: string
+}
+
 export interface ClosedChannelPaymentDetails {
     state: ChannelState
     fundingTxid: string
@@ -72,14 +191,36 @@ export interface ClosedChannelPaymentDetails {
 export interface Config {
     breezserver: string
     chainnotifierUrl: string
+    lnurlServerUrl: string
     mempoolspaceUrl?: string
+    chainService?: ChainServiceConfig
+    backupTransport?: BackupTransportConfig
+    proxy?: ProxyConfig
     workingDir: string
+    dbDir?: string
+    credentialsDir?: string
     network: Network
     paymentTimeoutSec: number
+    maxConcurrentPayments: number
     defaultLspId?: string
+    lspPolicy?: LspPolicy
     apiKey?: string
     maxfeePercent: number
     exemptfeeMsat: number
+    fiatCurrency?: string
+    lowOutboundLiquidityThresholdMsat?: number
+    lowInboundLiquidityThresholdMsat?: number
+    useTrampoline: boolean
+    partnerFee?: PartnerFeeConfig
+    swapAutoRefund?: AutoRefundConfig
+    memoPrivacy: MemoPrivacy
+    channelHygieneWindowSecs?: number
+    logFilter?: string
+    denylistUrl?: string
+    transakApiKey?: string
+    spendPolicy?: SpendPolicy
+    htlcExposureLimits?: HtlcExposureLimits
+    serviceStatusCheckIntervalSecs?: number
     nodeConfig: NodeConfig
 }
 
@@ -87,10 +228,44 @@ export interface ConfigureNodeRequest {
     closeToAddress?: string
 }
 
+export interface ConnectPeerRequest {
+    nodeUri: string
+}
+
+export interface ConnectProgressDetails {
+    stage: ConnectStage
+    progressPercent: number
+    isNewNode?: boolean
+}
+
 export interface ConnectRequest {
     config: Config
     seed: number[]
     restoreOnly?: boolean
+    registerIfMissing?: boolean
+}
+
+export interface ConnectionStatus {
+    state: ConnectionState
+    since: number
+    reconnectAttempts: number
+    lastError?: string
+}
+
+export interface CreateLnurlWithdrawVoucherRequest {
+    amountMsat: number
+    uses: number
+    expirySecs: number
+    description?: string
+}
+
+export interface CreateStaticLnurlPayRequest {
+    url: string
+    callbackUrl: string
+    description: string
+    minSendableMsat: number
+    maxSendableMsat: number
+    commentAllowed?: number
 }
 
 export interface CurrencyInfo {
@@ -103,11 +278,75 @@ export interface CurrencyInfo {
     localeOverrides: LocaleOverrides[]
 }
 
+export interface DecryptRecoveryBundleRequest {
+    bundle: number[]
+    privateKey: number[]
+}
+
+export interface DeriveEncryptionKeyRequest {
+    purpose: string
+    index: number
+}
+
+export interface DeriveEncryptionKeyResponse {
+    key: number[]
+}
+
+export interface EncryptedPaymentRequestData {
+    blob: string
+}
+
+export interface ExportPaymentsRequest {
+    format: ExportFormat
+    fromTimestamp?: number
+    toTimestamp?: number
+    includeFiat?: string
+}
+
+export interface ExportRecoveryBundleRequest {
+    recipientPubkey: string
+    instructions?: string
+    filePath?: string
+}
+
+export interface ExportRecoveryBundleResponse {
+    bundle: number[]
+}
+
+export interface ExportSwapRefundKeysRequest {
+    includeGapKeys?: boolean
+}
+
+export interface FetchBuyBitcoinQuoteRequest {
+    provider: BuyBitcoinProvider
+    fiatAmount: FiatAmount
+}
+
+export interface FiatAmount {
+    currency: string
+    amount: number
+}
+
 export interface FiatCurrency {
     id: string
     info: CurrencyInfo
 }
 
+export interface FiatRateAlert {
+    id: number
+    currency: string
+    direction: FiatRateAlertDirection
+    threshold: number
+    createdAt: number
+    triggered: boolean
+    lastTriggeredAt?: number
+}
+
+export interface FiatRateAlertTriggeredDetails {
+    alert: FiatRateAlert
+    rate: number
+}
+
 export interface GreenlightCredentials {
     developerKey: number[]
     developerCert: number[]
@@ -122,12 +361,56 @@ export interface GreenlightNodeConfig {
     inviteCode?: string
 }
 
+export interface HistoricalRate {
+    coin: string
+    value: number
+    timestamp: number
+}
+
+export interface HtlcExposureLimits {
+    maxInflightMsat?: number
+    maxHtlcsPerPayment?: number
+}
+
+export interface ImportPaymentsRequest {
+    format: ExportFormat
+    data: string
+}
+
+export interface ImportPaymentsResponse {
+    importedCount: number
+    skippedCount: number
+}
+
+export interface InvoiceFeatures {
+    basicMpp: FeatureSupport
+    paymentSecret: FeatureSupport
+    trampoline: FeatureSupport
+    requiresUnknownFeatures: boolean
+}
+
 export interface InvoicePaidDetails {
     paymentHash: string
     bolt11: string
     payment?: Payment
 }
 
+export interface InvoicePayability {
+    invoice: LnInvoice
+    amountMsat?: number
+    isPayable: boolean
+    error?: string
+    maxPayableMsat: number
+    payeeInboundLiquidityMsat?: number
+    lspRouteHint?: RouteHint
+}
+
+export interface InvoiceVerificationResult {
+    bolt11: string
+    invoice?: LnInvoice
+    error?: string
+}
+
 export interface LnInvoice {
     bolt11: string
     network: Network
@@ -138,9 +421,29 @@ export interface LnInvoice {
     amountMsat?: number
     timestamp: number
     expiry: number
+    expiresAt: number
+    isExpired: boolean
     routingHints: RouteHint[]
     paymentSecret: number[]
     minFinalCltvExpiryDelta: number
+    features: InvoiceFeatures
+}
+
+export interface LnOffer {
+    offer: string
+    chains: string[]
+    minAmount?: Amount
+    description?: string
+    absoluteExpiry?: number
+    issuer?: string
+    signingPubkey?: string
+    paths: LnOfferBlindedPath[]
+}
+
+export interface ListPaymentGroupsRequest {
+    groupBy: PaymentGroupBy
+    payments: ListPaymentsRequest
+    limit?: number
 }
 
 export interface ListPaymentsRequest {
@@ -151,6 +454,8 @@ export interface ListPaymentsRequest {
     includeFailures?: boolean
     offset?: number
     limit?: number
+    includeTlvHex?: boolean
+    sortOrder?: SortOrder
 }
 
 export interface ListSwapsRequest {
@@ -161,6 +466,10 @@ export interface ListSwapsRequest {
     limit?: number
 }
 
+export interface LnOfferBlindedPath {
+    blindedHops: string[]
+}
+
 export interface LnPaymentDetails {
     paymentHash: string
     label: string
@@ -169,6 +478,7 @@ export interface LnPaymentDetails {
     keysend: boolean
     bolt11: string
     openChannelBolt11?: string
+    channelOpeningFeeMsat?: number
     lnurlSuccessAction?: SuccessActionProcessed
     lnurlPayDomain?: string
     lnurlPayComment?: string
@@ -178,6 +488,21 @@ export interface LnPaymentDetails {
     swapInfo?: SwapInfo
     reverseSwapInfo?: ReverseSwapInfo
     pendingExpirationBlock?: number
+    tlvs?: TlvRecord[]
+    spontaneousMetadata?: SpontaneousPaymentMetadata
+}
+
+export interface LnUrlAuthDomain {
+    domain: string
+    linkingKey: string
+    firstAuthenticatedAt: number
+    lastAuthenticatedAt: number
+    authCount: number
+}
+
+export interface LnUrlAuthExport {
+    hashingKey: string
+    domains: LnUrlAuthDomain[]
 }
 
 export interface LnUrlAuthRequestData {
@@ -196,6 +521,15 @@ export interface LnUrlPayErrorData {
     reason: string
 }
 
+export interface LnUrlPayFinishedDetails {
+    paymentHash: string
+    result: LnUrlPayResult
+}
+
+export interface LnUrlPayPendingData {
+    paymentHash: string
+}
+
 export interface LnUrlPayRequest {
     data: LnUrlPayRequestData
     amountMsat: number
@@ -203,6 +537,9 @@ export interface LnUrlPayRequest {
     comment?: string
     paymentLabel?: string
     validateSuccessActionUrl?: boolean
+    pendingTimeoutSec?: number
+    cancelToken?: string
+    skipDenylist?: boolean
 }
 
 export interface LnUrlPayRequestData {
@@ -220,6 +557,7 @@ export interface LnUrlPayRequestData {
 export interface LnUrlPaySuccessData {
     successAction?: SuccessActionProcessed
     payment: Payment
+    partnerFee?: PartnerFeeDetails
 }
 
 export interface LnUrlWithdrawRequest {
@@ -240,6 +578,22 @@ export interface LnUrlWithdrawSuccessData {
     invoice: LnInvoice
 }
 
+export interface LnurlPayInfo {
+    lnurl: string
+    lightningAddress: string
+}
+
+export interface LnurlWithdrawVoucher {
+    id: string
+    lnurl: string
+    amountMsat: number
+    uses: number
+    redeemedUses: number
+    description?: string
+    createdAt: number
+    expiresAt: number
+}
+
 export interface LocaleOverrides {
     locale: string
     spacing?: number
@@ -254,6 +608,12 @@ export interface LocalizedName {
 export interface LogEntry {
     line: string
     level: string
+    module: string
+}
+
+export interface LowLiquidityDetails {
+    liquidityMsat: number
+    thresholdMsat: number
 }
 
 export interface LspInformation {
@@ -276,7 +636,7 @@ export interface MessageSuccessActionData {
 
 export interface MetadataFilter {
     jsonPath: string
-    jsonValue: string
+    jsonValue?: string
 }
 
 export interface MetadataItem {
@@ -284,6 +644,62 @@ export interface MetadataItem {
     value: string
 }
 
+export interface MetricBucket {
+    upperBound: number
+    cumulativeCount: number
+}
+
+export interface MetricCounter {
+    name: string
+    help: string
+    value: number
+}
+
+export interface MetricHistogram {
+    name: string
+    help: string
+    buckets: MetricBucket[]
+    sum: number
+    count: number
+}
+
+export interface MetricsSnapshot {
+    counters: MetricCounter[]
+    histograms: MetricHistogram[]
+}
+
+export interface MigrationChannel {
+    fundingTxid: string
+    fundingOutnum?: number
+    shortChannelId?: string
+    state: ChannelState
+    localBalanceMsat: number
+    peerId?: string
+    capacityMsat?: number
+}
+
+export interface MockNodeConfig {
+    channelsBalanceMsat: number
+    onchainBalanceMsat: number
+    inboundLiquidityMsat: number
+}
+
+export interface NodeMigrationRequest {
+    closeChannels: boolean
+}
+
+export interface NodeMigrationState {
+    nodeId: string
+    network: Network
+    lspNodeUri?: string
+    staticBackup: string[]
+    channels: MigrationChannel[]
+    closingTxids: string[]
+    channelsBalanceMsat: number
+    onchainBalanceMsat: number
+    exportedAt: number
+}
+
 export interface NodeState {
     id: string
     blockHeight: number
@@ -298,6 +714,8 @@ export interface NodeState {
     connectedPeers: string[]
     maxReceivableSinglePaymentAmountMsat: number
     totalInboundLiquidityMsats: number
+    pendingChannelOpensMsat: number
+    pendingChannelOpens: PendingChannelOpen[]
 }
 
 export interface OnchainPaymentLimitsResponse {
@@ -316,6 +734,23 @@ export interface OpenChannelFeeResponse {
     feeParams: OpeningFeeParams
 }
 
+export interface OpenChannelReceiveDetails {
+    paymentHash: string
+    stage: OpenChannelReceiveStage
+}
+
+export interface OpenChannelRequest {
+    nodeId: string
+    amountSat: number
+    announce: boolean
+    satPerVbyte?: number
+}
+
+export interface OpenChannelResponse {
+    channelId: string
+    fundingTxid: string
+}
+
 export interface OpeningFeeParams {
     minMsat: number
     proportional: number
@@ -329,9 +764,56 @@ export interface OpeningFeeParamsMenu {
     values: OpeningFeeParams[]
 }
 
+export interface OutboxPayment {
+    id: number
+    req: SendPaymentRequest
+    createdAt: number
+    expiresAt: number
+    attempts: number
+    nextAttemptAt: number
+    lastError?: string
+}
+
+export interface OutboxPaymentDetails {
+    outboxPayment: OutboxPayment
+    status: OutboxPaymentStatus
+}
+
+export interface PartnerFeeConfig {
+    feeBps: number
+    destination: PartnerFeeDestination
+}
+
+export interface PartnerFeeDetails {
+    amountMsat: number
+    paymentId?: string
+    error?: string
+}
+
+export interface PayOfferRequest {
+    offer: string
+    amountMsat?: number
+    payerNote?: string
+    label?: string
+}
+
+export interface PayOnchainAddressRequest {
+    address: string
+    amountSat?: number
+    drain: boolean
+    feePreference?: FeeratePreset
+}
+
+export interface PayOnchainAddressResponse {
+    prepareRes: PrepareOnchainPaymentResponse
+    claimTxFeerate: number
+    reverseSwapInfo: ReverseSwapInfo
+}
+
 export interface PayOnchainRequest {
     recipientAddress: string
     prepareRes: PrepareOnchainPaymentResponse
+    cancelToken?: string
 }
 
 export interface PayOnchainResponse {
@@ -349,6 +831,8 @@ export interface Payment {
     description?: string
     details: PaymentDetails
     metadata?: string
+    fiatRate?: Rate
+    imported: boolean
 }
 
 export interface PaymentFailedData {
@@ -358,6 +842,52 @@ export interface PaymentFailedData {
     label?: string
 }
 
+export interface PaymentGroup {
+    key: string
+    paymentsCount: number
+    totalAmountMsat: number
+    totalFeeMsat: number
+    firstPaymentTime: number
+    lastPaymentTime: number
+}
+
+export interface PaymentProbe {
+    id: number
+    nodeId: string
+    bolt11?: string
+    amountMsat: number
+    routeFound: boolean
+    reachable: boolean
+    estimatedFeeMsat?: number
+    hops?: number
+    error?: string
+    createdAt: number
+}
+
+export interface PaymentRequestBundle {
+    invoice: string
+    fallback?: string
+    metadata?: string
+}
+
+export interface PeerConnectivity {
+    lspPubkey?: string
+    lspConnected: boolean
+    pingRttMs?: number
+    pingError?: string
+    reconnectionAttempts: number
+    failedReconnectionAttempts: number
+    lastReconnectedAt?: number
+    lastReconnectionError?: string
+}
+
+export interface PendingChannelOpen {
+    fundingTxid: string
+    amountMsat: number
+    seenAt: number
+    expectedConfirmationAt: number
+}
+
 export interface PrepareOnchainPaymentRequest {
     amountSat: number
     amountType: SwapAmountType
@@ -374,14 +904,31 @@ export interface PrepareOnchainPaymentResponse {
     totalFees: number
 }
 
+export interface PrepareReceivePaymentRequest {
+    amountMsat: number
+    openingFeeParams?: OpeningFeeParams
+    expiry?: number
+}
+
+export interface PrepareReceivePaymentResponse {
+    amountMsat: number
+    openChannelNeeded: boolean
+    openingFeeMsat: number
+    openingFeeParams?: OpeningFeeParams
+    receivedAmountMsat: number
+}
+
 export interface PrepareRedeemOnchainFundsRequest {
     toAddress: string
     satPerVbyte: number
+    amountSat?: number
 }
 
 export interface PrepareRedeemOnchainFundsResponse {
     txWeight: number
     txFeeSat: number
+    amountSat: number
+    changeSat: number
 }
 
 export interface PrepareRefundRequest {
@@ -389,11 +936,51 @@ export interface PrepareRefundRequest {
     toAddress: string
     satPerVbyte: number
     unilateral?: boolean
+    includeFeePreviews?: boolean
 }
 
 export interface PrepareRefundResponse {
     refundTxWeight: number
     refundTxFeeSat: number
+    feePreviews: RefundFeePreview[]
+}
+
+export interface PrepareSendPaymentRequest {
+    bolt11: string
+    amountMsat?: number
+}
+
+export interface PrepareSendPaymentResponse {
+    bolt11: string
+    amountMsat: number
+    feeMsat: number
+    routeHops: number
+    parts: number
+}
+
+export interface ProbePaymentRequest {
+    destination: string
+    amountMsat?: number
+}
+
+export interface ProveAddressOwnershipRequest {
+    address: string
+    message: string
+}
+
+export interface ProveAddressOwnershipResponse {
+    signature: string
+}
+
+export interface ProxyConfig {
+    address: string
+    username?: string
+    password?: string
+}
+
+export interface QueuePaymentRequest {
+    req: SendPaymentRequest
+    expirySecs?: number
 }
 
 export interface Rate {
@@ -413,12 +1000,32 @@ export interface ReceivePaymentRequest {
     useDescriptionHash?: boolean
     expiry?: number
     cltv?: number
+    includeFallbackAddress?: boolean
+    maxRouteHints?: number
+    fiatAmount?: FiatAmount
 }
 
 export interface ReceivePaymentResponse {
     lnInvoice: LnInvoice
     openingFeeParams?: OpeningFeeParams
     openingFeeMsat?: number
+    bolt11Length: number
+    fiatAmount?: FiatAmount
+    fiatRate?: Rate
+}
+
+export interface ReceiveUnifiedRequest {
+    amountSat: number
+    description: string
+    openingFeeParams?: OpeningFeeParams
+}
+
+export interface ReceiveUnifiedResponse {
+    bip21: string
+    swapInfo: SwapInfo
+    lnInvoice: LnInvoice
+    openingFeeParams?: OpeningFeeParams
+    openingFeeMsat?: number
 }
 
 export interface RecommendedFees {
@@ -429,15 +1036,38 @@ export interface RecommendedFees {
     minimumFee: number
 }
 
+export interface RecoveryBundle {
+    nodeId: string
+    network: Network
+    instructions: string
+    staticBackup: string[]
+    channels: MigrationChannel[]
+    channelsBalanceMsat: number
+    onchainBalanceMsat: number
+    createdAt: number
+}
+
+export interface RedeemLnurlWithdrawVoucherRequest {
+    id: string
+    bolt11: string
+}
+
 export interface RedeemOnchainFundsRequest {
     toAddress: string
     satPerVbyte: number
+    amountSat?: number
 }
 
 export interface RedeemOnchainFundsResponse {
     txid: number[]
 }
 
+export interface RefundFeePreview {
+    preset: FeeratePreset
+    satPerVbyte: number
+    refundTxFeeSat: number
+}
+
 export interface RefundRequest {
     swapAddress: string
     toAddress: string
@@ -466,6 +1096,7 @@ export interface ReverseSwapInfo {
     claimTxid?: string
     onchainAmountSat: number
     status: ReverseSwapStatus
+    protocol: ReverseSwapProtocol
 }
 
 export interface ReverseSwapPairInfo {
@@ -492,15 +1123,43 @@ export interface RouteHintHop {
     htlcMaximumMsat?: number
 }
 
+export interface SdkInfo {
+    version: string
+    supportedLuds: string[]
+    swapInScriptVersions: string[]
+    reverseSwapProtocols: ReverseSwapProtocol[]
+    lspProtocols: string[]
+    backupSchemaVersion: number
+    minBackupSchemaVersion: number
+}
+
 export interface SendPaymentRequest {
     bolt11: string
-    useTrampoline: boolean
+    useTrampoline?: boolean
     amountMsat?: number
     label?: string
+    routeHintIndex?: number
+    paymentTimeoutSec?: number
+    maxfeePercent?: number
+    exemptfeeMsat?: number
+    cancelToken?: string
+    skipDenylist?: boolean
 }
 
 export interface SendPaymentResponse {
     payment: Payment
+    partnerFee?: PartnerFeeDetails
+}
+
+export interface SendPaymentsRequest {
+    payments: SendPaymentRequest[]
+    maxConcurrency?: number
+}
+
+export interface SendPaymentsResponse {
+    results: BatchPaymentResult[]
+    succeeded: number
+    failed: number
 }
 
 export interface SendSpontaneousPaymentRequest {
@@ -522,6 +1181,25 @@ export interface SignMessageResponse {
     signature: string
 }
 
+export interface SnapshotChange {
+    path: string
+    before?: string
+    after?: string
+}
+
+export interface SpendPolicy {
+    dailyLimitMsat?: number
+    perPaymentLimitMsat?: number
+    allowedDestinations?: string[]
+}
+
+export interface SpontaneousPaymentMetadata {
+    message?: string
+    senderPubkey?: string
+    senderName?: string
+    boostagram?: string
+}
+
 export interface StaticBackupRequest {
     workingDir: string
 }
@@ -530,6 +1208,17 @@ export interface StaticBackupResponse {
     backup?: string[]
 }
 
+export interface StaticLnurlPay {
+    lnurl: string
+    metadata: string
+    payRequest: string
+}
+
+export interface StaticLnurlPayCallbackRequest {
+    amountMsat: number
+    comment?: string
+}
+
 export interface SwapInfo {
     bitcoinAddress: string
     createdAt: number
@@ -557,6 +1246,20 @@ export interface SwapInfo {
     confirmedAt?: number
 }
 
+export interface SwapRefundKey {
+    index: number
+    derivationPath: string
+    privateKey: number[]
+    publicKey: number[]
+}
+
+export interface SwapRefundedDetails {
+    swapAddress: string
+    refundTxId: string
+    toAddress: string
+    satPerVbyte: number
+}
+
 export interface SymbolType {
     grapheme?: string
     template?: string
@@ -564,11 +1267,33 @@ export interface SymbolType {
     position?: number
 }
 
+export interface TaskStatus {
+    name: string
+    state: TaskState
+    restarts: number
+    lastError?: string
+    lastErrorAt?: number
+}
+
 export interface TlvEntry {
     fieldNumber: number
     value: number[]
 }
 
+export interface TlvRecord {
+    fieldNumber: number
+    valueText?: string
+    valueHex?: string
+}
+
+export interface UnredeemedFundsDetails {
+    refundableSwaps: SwapInfo[]
+    redeemableSwaps: SwapInfo[]
+    expiredInvoiceSwaps: SwapInfo[]
+    unclaimedReverseSwaps: ReverseSwapInfo[]
+    totalAmountSat: number
+}
+
 export interface UnspentTransactionOutput {
     txid: number[]
     outnum: number
@@ -583,6 +1308,18 @@ export interface UrlSuccessActionData {
     matchesCallbackDomain: boolean
 }
 
+export interface UserSettings {
+    preferredFiatCurrency?: string
+    denomination?: Denomination
+    defaultFeeratePreset?: FeeratePreset
+    preferredLspId?: string
+}
+
+export interface ValidatePayabilityRequest {
+    bolt11: string
+    amountMsat?: number
+}
+
 export enum AesSuccessActionDataResultVariant {
     DECRYPTED = "decrypted",
     ERROR_STATUS = "errorStatus"
@@ -596,6 +1333,39 @@ export type AesSuccessActionDataResult = {
     reason: string
 }
 
+export enum AmountVariant {
+    BITCOIN = "bitcoin",
+    CURRENCY = "currency"
+}
+
+export type Amount = {
+    type: AmountVariant.BITCOIN,
+    amountMsat: number
+} | {
+    type: AmountVariant.CURRENCY,
+    iso4217Code: string
+    fractionalAmount: number
+}
+
+export enum BackupTransportConfigVariant {
+    S3 = "s3",
+    WEB_DAV = "webDav"
+}
+
+export type BackupTransportConfig = {
+    type: BackupTransportConfigVariant.S3,
+    endpoint: string
+    region: string
+    bucket: string
+    accessKeyId: string
+    secretAccessKey: string
+} | {
+    type: BackupTransportConfigVariant.WEB_DAV,
+    url: string
+    username?: string
+    password?: string
+}
+
 export enum BreezEventVariant {
     NEW_BLOCK = "newBlock",
     INVOICE_PAID = "invoicePaid",
@@ -606,7 +1376,27 @@ export enum BreezEventVariant {
     BACKUP_SUCCEEDED = "backupSucceeded",
     BACKUP_FAILED = "backupFailed",
     REVERSE_SWAP_UPDATED = "reverseSwapUpdated",
-    SWAP_UPDATED = "swapUpdated"
+    SWAP_UPDATED = "swapUpdated",
+    CONNECT_PROGRESS = "connectProgress",
+    UNREDEEMED_FUNDS_DETECTED = "unredeemedFundsDetected",
+    LN_URL_PAY_FINISHED = "lnUrlPayFinished",
+    OPEN_CHANNEL_RECEIVE_UPDATED = "openChannelReceiveUpdated",
+    LOW_OUTBOUND_LIQUIDITY = "lowOutboundLiquidity",
+    LOW_INBOUND_LIQUIDITY = "lowInboundLiquidity",
+    CHANNEL_OPENING = "channelOpening",
+    LSP_CHANGED = "lspChanged",
+    SWAP_REFUNDED = "swapRefunded",
+    BATCH_PAYMENT_PROGRESS = "batchPaymentProgress",
+    OUTBOX_PAYMENT_UPDATED = "outboxPaymentUpdated",
+    LN_URL_AUTH_COMPLETED = "lnUrlAuthCompleted",
+    CHANNEL_HYGIENE_SUGGESTION = "channelHygieneSuggestion",
+    FIAT_RATE_ALERT_TRIGGERED = "fiatRateAlertTriggered",
+    CONNECTED = "connected",
+    DISCONNECTED = "disconnected",
+    RECONNECTING = "reconnecting",
+    LSP_PEER_RECONNECTED = "lspPeerReconnected",
+    SPONTANEOUS_PAYMENT_RECEIVED = "spontaneousPaymentReceived",
+    SERVICE_STATUS_CHANGED = "serviceStatusChanged"
 }
 
 export type BreezEvent = {
@@ -636,10 +1426,89 @@ export type BreezEvent = {
 } | {
     type: BreezEventVariant.SWAP_UPDATED,
     details: SwapInfo
+} | {
+    type: BreezEventVariant.CONNECT_PROGRESS,
+    details: ConnectProgressDetails
+} | {
+    type: BreezEventVariant.UNREDEEMED_FUNDS_DETECTED,
+    details: UnredeemedFundsDetails
+} | {
+    type: BreezEventVariant.LN_URL_PAY_FINISHED,
+    details: LnUrlPayFinishedDetails
+} | {
+    type: BreezEventVariant.OPEN_CHANNEL_RECEIVE_UPDATED,
+    details: OpenChannelReceiveDetails
+} | {
+    type: BreezEventVariant.LOW_OUTBOUND_LIQUIDITY,
+    details: LowLiquidityDetails
+} | {
+    type: BreezEventVariant.LOW_INBOUND_LIQUIDITY,
+    details: LowLiquidityDetails
+} | {
+    type: BreezEventVariant.CHANNEL_OPENING,
+    feeMsat: number
+    minFeeMsat: number
+    proportional: number
+} | {
+    type: BreezEventVariant.LSP_CHANGED,
+    previousLspId?: string
+    lspId: string
+} | {
+    type: BreezEventVariant.SWAP_REFUNDED,
+    details: SwapRefundedDetails
+} | {
+    type: BreezEventVariant.BATCH_PAYMENT_PROGRESS,
+    details: BatchPaymentProgressDetails
+} | {
+    type: BreezEventVariant.OUTBOX_PAYMENT_UPDATED,
+    details: OutboxPaymentDetails
+} | {
+    type: BreezEventVariant.LN_URL_AUTH_COMPLETED,
+    details: LnUrlAuthDomain
+} | {
+    type: BreezEventVariant.CHANNEL_HYGIENE_SUGGESTION,
+    details: ChannelHygieneSuggestionDetails
+} | {
+    type: BreezEventVariant.FIAT_RATE_ALERT_TRIGGERED,
+    details: FiatRateAlertTriggeredDetails
+} | {
+    type: BreezEventVariant.CONNECTED
+} | {
+    type: BreezEventVariant.DISCONNECTED,
+    error: string
+} | {
+    type: BreezEventVariant.RECONNECTING,
+    attempt: number
+    delayMs: number
+} | {
+    type: BreezEventVariant.LSP_PEER_RECONNECTED,
+    lspPubkey: string
+    reason: string
+} | {
+    type: BreezEventVariant.SPONTANEOUS_PAYMENT_RECEIVED,
+    details: Payment
+} | {
+    type: BreezEventVariant.SERVICE_STATUS_CHANGED,
+    component: ServiceComponent
+    status: ServiceStatus
 }
 
 export enum BuyBitcoinProvider {
-    MOONPAY = "moonpay"
+    MOONPAY = "moonpay",
+    TRANSAK = "transak"
+}
+
+export enum ChainServiceConfigVariant {
+    ESPLORA = "esplora",
+    ELECTRUM = "electrum"
+}
+
+export type ChainServiceConfig = {
+    type: ChainServiceConfigVariant.ESPLORA,
+    url: string
+} | {
+    type: ChainServiceConfigVariant.ELECTRUM,
+    url: string
 }
 
 export enum ChannelState {
@@ -649,9 +1518,41 @@ export enum ChannelState {
     CLOSED = "closed"
 }
 
-export enum EnvironmentType {
-    PRODUCTION = "production",
-    STAGING = "staging"
+export enum ConnectStage {
+    NODE_RECOVERY = "nodeRecovery",
+    NODE_REGISTRATION = "nodeRegistration",
+    SCHEDULER_HANDSHAKE = "schedulerHandshake",
+    NODE_STARTUP = "nodeStartup",
+    INITIAL_SYNC = "initialSync",
+    COMPLETED = "completed"
+}
+
+export enum ConnectionState {
+    CONNECTED = "connected",
+    DISCONNECTED = "disconnected",
+    RECONNECTING = "reconnecting"
+}
+
+export enum Denomination {
+    BTC = "btc",
+    SAT = "sat"
+}
+
+export enum EnvironmentType {
+    PRODUCTION = "production",
+    STAGING = "staging",
+    REGTEST = "regtest"
+}
+
+export enum ExportFormat {
+    CSV = "csv",
+    JSON = "json"
+}
+
+export enum FeatureSupport {
+    NOT_SUPPORTED = "notSupported",
+    OPTIONAL = "optional",
+    REQUIRED = "required"
 }
 
 export enum FeeratePreset {
@@ -660,6 +1561,11 @@ export enum FeeratePreset {
     PRIORITY = "priority"
 }
 
+export enum FiatRateAlertDirection {
+    ABOVE = "above",
+    BELOW = "below"
+}
+
 export enum HealthCheckStatus {
     OPERATIONAL = "operational",
     MAINTENANCE = "maintenance",
@@ -669,12 +1575,14 @@ export enum HealthCheckStatus {
 export enum InputTypeVariant {
     BITCOIN_ADDRESS = "bitcoinAddress",
     BOLT11 = "bolt11",
+    BOLT12_OFFER = "bolt12Offer",
     NODE_ID = "nodeId",
     URL = "url",
     LN_URL_PAY = "lnUrlPay",
     LN_URL_WITHDRAW = "lnUrlWithdraw",
     LN_URL_AUTH = "lnUrlAuth",
-    LN_URL_ERROR = "lnUrlError"
+    LN_URL_ERROR = "lnUrlError",
+    ENCRYPTED_PAYMENT_REQUEST = "encryptedPaymentRequest"
 }
 
 export type InputType = {
@@ -683,6 +1591,10 @@ export type InputType = {
 } | {
     type: InputTypeVariant.BOLT11,
     invoice: LnInvoice
+} | {
+    type: InputTypeVariant.BOLT12_OFFER,
+    offer: LnOffer
+    bip353Address?: string
 } | {
     type: InputTypeVariant.NODE_ID,
     nodeId: string
@@ -693,6 +1605,8 @@ export type InputType = {
     type: InputTypeVariant.LN_URL_PAY,
     data: LnUrlPayRequestData
     bip353Address?: string
+    amountMsat?: number
+    comment?: string
 } | {
     type: InputTypeVariant.LN_URL_WITHDRAW,
     data: LnUrlWithdrawRequestData
@@ -702,6 +1616,9 @@ export type InputType = {
 } | {
     type: InputTypeVariant.LN_URL_ERROR,
     data: LnUrlErrorData
+} | {
+    type: InputTypeVariant.ENCRYPTED_PAYMENT_REQUEST,
+    data: EncryptedPaymentRequestData
 }
 
 export enum LnUrlCallbackStatusVariant {
@@ -719,7 +1636,8 @@ export type LnUrlCallbackStatus = {
 export enum LnUrlPayResultVariant {
     ENDPOINT_SUCCESS = "endpointSuccess",
     ENDPOINT_ERROR = "endpointError",
-    PAY_ERROR = "payError"
+    PAY_ERROR = "payError",
+    PENDING = "pending"
 }
 
 export type LnUrlPayResult = {
@@ -731,6 +1649,9 @@ export type LnUrlPayResult = {
 } | {
     type: LnUrlPayResultVariant.PAY_ERROR,
     data: LnUrlPayErrorData
+} | {
+    type: LnUrlPayResultVariant.PENDING,
+    data: LnUrlPayPendingData
 }
 
 export enum LnUrlWithdrawResultVariant {
@@ -750,6 +1671,27 @@ export type LnUrlWithdrawResult = {
     data: LnUrlErrorData
 }
 
+export enum LspPolicyVariant {
+    LOWEST_OPENING_FEE = "lowestOpeningFee",
+    LOWEST_PROPORTIONAL_FEE = "lowestProportionalFee",
+    PINNED = "pinned"
+}
+
+export type LspPolicy = {
+    type: LspPolicyVariant.LOWEST_OPENING_FEE
+} | {
+    type: LspPolicyVariant.LOWEST_PROPORTIONAL_FEE
+} | {
+    type: LspPolicyVariant.PINNED,
+    lspId: string
+}
+
+export enum MemoPrivacy {
+    PLAIN = "plain",
+    HASH = "hash",
+    STRIP = "strip"
+}
+
 export enum Network {
     BITCOIN = "bitcoin",
     TESTNET = "testnet",
@@ -758,12 +1700,16 @@ export enum Network {
 }
 
 export enum NodeConfigVariant {
-    GREENLIGHT = "greenlight"
+    GREENLIGHT = "greenlight",
+    MOCK = "mock"
 }
 
-export interface NodeConfig {
+export type NodeConfig = {
     type: NodeConfigVariant.GREENLIGHT,
     config: GreenlightNodeConfig
+} | {
+    type: NodeConfigVariant.MOCK,
+    config: MockNodeConfig
 }
 
 export enum NodeCredentialsVariant {
@@ -775,6 +1721,57 @@ export interface NodeCredentials {
     credentials: GreenlightDeviceCredentials
 }
 
+export enum OpenChannelReceiveStageVariant {
+    REGISTERED = "registered",
+    CHANNEL_PENDING = "channelPending",
+    SETTLED = "settled",
+    REREGISTERED = "reregistered"
+}
+
+export type OpenChannelReceiveStage = {
+    type: OpenChannelReceiveStageVariant.REGISTERED,
+    openingFeeMsat: number
+} | {
+    type: OpenChannelReceiveStageVariant.CHANNEL_PENDING,
+    fundingTxid: string
+} | {
+    type: OpenChannelReceiveStageVariant.SETTLED,
+    openingFeeMsat: number
+} | {
+    type: OpenChannelReceiveStageVariant.REREGISTERED
+}
+
+export enum OutboxPaymentStatusVariant {
+    QUEUED = "queued",
+    SENT = "sent",
+    ABANDONED = "abandoned",
+    CANCELLED = "cancelled"
+}
+
+export type OutboxPaymentStatus = {
+    type: OutboxPaymentStatusVariant.QUEUED
+} | {
+    type: OutboxPaymentStatusVariant.SENT,
+    payment: Payment
+} | {
+    type: OutboxPaymentStatusVariant.ABANDONED
+} | {
+    type: OutboxPaymentStatusVariant.CANCELLED
+}
+
+export enum PartnerFeeDestinationVariant {
+    NODE_ID = "nodeId",
+    LIGHTNING_ADDRESS = "lightningAddress"
+}
+
+export type PartnerFeeDestination = {
+    type: PartnerFeeDestinationVariant.NODE_ID,
+    nodeId: string
+} | {
+    type: PartnerFeeDestinationVariant.LIGHTNING_ADDRESS,
+    address: string
+}
+
 export enum PaymentDetailsVariant {
     LN = "ln",
     CLOSED_CHANNEL = "closedChannel"
@@ -788,6 +1785,11 @@ export type PaymentDetails = {
     data: ClosedChannelPaymentDetails
 }
 
+export enum PaymentGroupBy {
+    DESTINATION_PUBKEY = "destinationPubkey",
+    LN_ADDRESS = "lnAddress"
+}
+
 export enum PaymentStatus {
     PENDING = "pending",
     COMPLETE = "complete",
@@ -803,7 +1805,14 @@ export enum PaymentType {
 export enum PaymentTypeFilter {
     SENT = "sent",
     RECEIVED = "received",
-    CLOSED_CHANNEL = "closedChannel"
+    CLOSED_CHANNEL = "closedChannel",
+    SPONTANEOUS_RECEIVED = "spontaneousReceived"
+}
+
+export enum ReceiveLimitReason {
+    MAX_INBOUND_LIQUIDITY = "maxInboundLiquidity",
+    MAX_PAYMENT_AMOUNT = "maxPaymentAmount",
+    MAX_SWAP_DEPOSIT = "maxSwapDeposit"
 }
 
 export enum ReportIssueRequestVariant {
@@ -815,6 +1824,11 @@ export interface ReportIssueRequest {
     data: ReportPaymentFailureDetails
 }
 
+export enum ReverseSwapProtocol {
+    LEGACY = "legacy",
+    TAPROOT = "taproot"
+}
+
 export enum ReverseSwapStatus {
     INITIAL = "initial",
     IN_PROGRESS = "inProgress",
@@ -823,6 +1837,22 @@ export enum ReverseSwapStatus {
     COMPLETED_CONFIRMED = "completedConfirmed"
 }
 
+export enum ServiceComponent {
+    LSP = "lsp",
+    GREENLIGHT = "greenlight",
+    SWAPPER = "swapper"
+}
+
+export enum ServiceStatus {
+    OPERATIONAL = "operational",
+    DEGRADED = "degraded"
+}
+
+export enum SortOrder {
+    DESCENDING = "descending",
+    ASCENDING = "ascending"
+}
+
 export enum SuccessActionProcessedVariant {
     AES = "aes",
     MESSAGE = "message",
@@ -853,6 +1883,12 @@ export enum SwapStatus {
     REFUNDABLE = "refundable",
     COMPLETED = "completed"
 }
+
+export enum TaskState {
+    RUNNING = "running",
+    RESTARTING = "restarting",
+    STOPPED = "stopped"
+}
 export type EventListener = (breezEvent: BreezEvent) => void
 
 export type LogStream = (logEntry: LogEntry) => void
@@ -875,6 +1911,15 @@ export const setLogStream = async (logStream: LogStream): Promise<EmitterSubscri
     return subscription
 }
 
+export const connectEphemeral = async (config: Config, listener: EventListener): Promise<BlockingBreezServices> => {
+    const response = await BreezSDK.connectEphemeral(config, listener)
+    return response
+}
+
+export const setLogFilter = async (filter: string): Promise<void> => {
+    await BreezSDK.setLogFilter(filter)
+}
+
 export const parseInvoice = async (invoice: string): Promise<LnInvoice> => {
     const response = await BreezSDK.parseInvoice(invoice)
     return response
@@ -895,16 +1940,31 @@ export const defaultConfig = async (envType: EnvironmentType, apiKey: string, no
     return response
 }
 
+export const sdkInfo = async (): Promise<SdkInfo> => {
+    const response = await BreezSDK.sdkInfo()
+    return response
+}
+
 export const staticBackup = async (req: StaticBackupRequest): Promise<StaticBackupResponse> => {
     const response = await BreezSDK.staticBackup(req)
     return response
 }
 
+export const decryptRecoveryBundle = async (req: DecryptRecoveryBundleRequest): Promise<RecoveryBundle> => {
+    const response = await BreezSDK.decryptRecoveryBundle(req)
+    return response
+}
+
 export const serviceHealthCheck = async (apiKey: string): Promise<ServiceHealthCheckResponse> => {
     const response = await BreezSDK.serviceHealthCheck(apiKey)
     return response
 }
 
+export const diffSnapshots = async (a: string, b: string): Promise<SnapshotChange[]> => {
+    const response = await BreezSDK.diffSnapshots(a, b)
+    return response
+}
+
 
 export const disconnect = async (): Promise<void> => {
     await BreezSDK.disconnect()
@@ -919,16 +1979,85 @@ export const sendPayment = async (req: SendPaymentRequest): Promise<SendPaymentR
     return response
 }
 
+export const sendPayments = async (req: SendPaymentsRequest): Promise<SendPaymentsResponse> => {
+    const response = await BreezSDK.sendPayments(req)
+    return response
+}
+
+export const verifyInvoices = async (invoices: string[]): Promise<InvoiceVerificationResult[]> => {
+    const response = await BreezSDK.verifyInvoices(invoices)
+    return response
+}
+
+export const queuePayment = async (req: QueuePaymentRequest): Promise<OutboxPayment> => {
+    const response = await BreezSDK.queuePayment(req)
+    return response
+}
+
+export const listPendingOutbox = async (): Promise<OutboxPayment[]> => {
+    const response = await BreezSDK.listPendingOutbox()
+    return response
+}
+
+export const cancelOutboxPayment = async (id: number): Promise<void> => {
+    await BreezSDK.cancelOutboxPayment(id)
+}
+
 export const sendSpontaneousPayment = async (req: SendSpontaneousPaymentRequest): Promise<SendPaymentResponse> => {
     const response = await BreezSDK.sendSpontaneousPayment(req)
     return response
 }
 
+export const cancel = async (cancelToken: string): Promise<boolean> => {
+    const response = await BreezSDK.cancel(cancelToken)
+    return response
+}
+
+export const probePayment = async (req: ProbePaymentRequest): Promise<PaymentProbe> => {
+    const response = await BreezSDK.probePayment(req)
+    return response
+}
+
+export const listPaymentProbes = async (): Promise<PaymentProbe[]> => {
+    const response = await BreezSDK.listPaymentProbes()
+    return response
+}
+
+export const validatePayability = async (req: ValidatePayabilityRequest): Promise<InvoicePayability> => {
+    const response = await BreezSDK.validatePayability(req)
+    return response
+}
+
+export const payOffer = async (req: PayOfferRequest): Promise<SendPaymentResponse> => {
+    const response = await BreezSDK.payOffer(req)
+    return response
+}
+
+export const prepareSendPayment = async (req: PrepareSendPaymentRequest): Promise<PrepareSendPaymentResponse> => {
+    const response = await BreezSDK.prepareSendPayment(req)
+    return response
+}
+
+export const prepareReceivePayment = async (req: PrepareReceivePaymentRequest): Promise<PrepareReceivePaymentResponse> => {
+    const response = await BreezSDK.prepareReceivePayment(req)
+    return response
+}
+
 export const receivePayment = async (req: ReceivePaymentRequest): Promise<ReceivePaymentResponse> => {
     const response = await BreezSDK.receivePayment(req)
     return response
 }
 
+export const receiveUnified = async (req: ReceiveUnifiedRequest): Promise<ReceiveUnifiedResponse> => {
+    const response = await BreezSDK.receiveUnified(req)
+    return response
+}
+
+export const amendInvoice = async (req: AmendInvoiceRequest): Promise<LnInvoice> => {
+    const response = await BreezSDK.amendInvoice(req)
+    return response
+}
+
 export const payLnurl = async (req: LnUrlPayRequest): Promise<LnUrlPayResult> => {
     const response = await BreezSDK.payLnurl(req)
     return response
@@ -944,10 +2073,44 @@ export const lnurlAuth = async (reqData: LnUrlAuthRequestData): Promise<LnUrlCal
     return response
 }
 
+export const listLnurlAuthDomains = async (): Promise<LnUrlAuthDomain[]> => {
+    const response = await BreezSDK.listLnurlAuthDomains()
+    return response
+}
+
+export const lnurlAuthLinkingKey = async (domain: string): Promise<string> => {
+    const response = await BreezSDK.lnurlAuthLinkingKey(domain)
+    return response
+}
+
+export const exportLnurlAuth = async (): Promise<LnUrlAuthExport> => {
+    const response = await BreezSDK.exportLnurlAuth()
+    return response
+}
+
+export const importLnurlAuth = async (export: LnUrlAuthExport): Promise<void> => {
+    await BreezSDK.importLnurlAuth(export)
+}
+
 export const reportIssue = async (req: ReportIssueRequest): Promise<void> => {
     await BreezSDK.reportIssue(req)
 }
 
+export const peerConnectivity = async (): Promise<PeerConnectivity> => {
+    const response = await BreezSDK.peerConnectivity()
+    return response
+}
+
+export const connectionStatus = async (): Promise<ConnectionStatus> => {
+    const response = await BreezSDK.connectionStatus()
+    return response
+}
+
+export const taskStatus = async (): Promise<TaskStatus[]> => {
+    const response = await BreezSDK.taskStatus()
+    return response
+}
+
 export const nodeCredentials = async (): Promise<NodeCredentials | null> => {
     const response = await BreezSDK.nodeCredentials()
     return response
@@ -958,11 +2121,41 @@ export const nodeInfo = async (): Promise<NodeState> => {
     return response
 }
 
+export const balances = async (): Promise<Balances> => {
+    const response = await BreezSDK.balances()
+    return response
+}
+
+export const getBalanceBreakdown = async (): Promise<BalanceBreakdown> => {
+    const response = await BreezSDK.getBalanceBreakdown()
+    return response
+}
+
 export const signMessage = async (req: SignMessageRequest): Promise<SignMessageResponse> => {
     const response = await BreezSDK.signMessage(req)
     return response
 }
 
+export const deriveEncryptionKey = async (req: DeriveEncryptionKeyRequest): Promise<DeriveEncryptionKeyResponse> => {
+    const response = await BreezSDK.deriveEncryptionKey(req)
+    return response
+}
+
+export const proveAddressOwnership = async (req: ProveAddressOwnershipRequest): Promise<ProveAddressOwnershipResponse> => {
+    const response = await BreezSDK.proveAddressOwnership(req)
+    return response
+}
+
+export const encryptPaymentRequest = async (bundle: PaymentRequestBundle): Promise<string> => {
+    const response = await BreezSDK.encryptPaymentRequest(bundle)
+    return response
+}
+
+export const decryptPaymentRequest = async (data: EncryptedPaymentRequestData): Promise<PaymentRequestBundle> => {
+    const response = await BreezSDK.decryptPaymentRequest(data)
+    return response
+}
+
 export const checkMessage = async (req: CheckMessageRequest): Promise<CheckMessageResponse> => {
     const response = await BreezSDK.checkMessage(req)
     return response
@@ -977,16 +2170,49 @@ export const backup = async (): Promise<void> => {
     await BreezSDK.backup()
 }
 
+export const rotateBackupKey = async (): Promise<void> => {
+    await BreezSDK.rotateBackupKey()
+}
+
+export const listBackupVersions = async (): Promise<number[]> => {
+    const response = await BreezSDK.listBackupVersions()
+    return response
+}
+
+export const restoreBackup = async (version: number): Promise<void> => {
+    await BreezSDK.restoreBackup(version)
+}
+
 export const listPayments = async (req: ListPaymentsRequest): Promise<Payment[]> => {
     const response = await BreezSDK.listPayments(req)
     return response
 }
 
+export const listPaymentGroups = async (req: ListPaymentGroupsRequest): Promise<PaymentGroup[]> => {
+    const response = await BreezSDK.listPaymentGroups(req)
+    return response
+}
+
+export const exportPayments = async (req: ExportPaymentsRequest): Promise<string> => {
+    const response = await BreezSDK.exportPayments(req)
+    return response
+}
+
+export const importPayments = async (req: ImportPaymentsRequest): Promise<ImportPaymentsResponse> => {
+    const response = await BreezSDK.importPayments(req)
+    return response
+}
+
 export const paymentByHash = async (hash: string): Promise<Payment | null> => {
     const response = await BreezSDK.paymentByHash(hash)
     return response
 }
 
+export const listChannelFeesPaid = async (): Promise<ChannelOpeningFee[]> => {
+    const response = await BreezSDK.listChannelFeesPaid()
+    return response
+}
+
 export const setPaymentMetadata = async (hash: string, metadata: string): Promise<void> => {
     await BreezSDK.setPaymentMetadata(hash, metadata)
 }
@@ -996,11 +2222,35 @@ export const redeemOnchainFunds = async (req: RedeemOnchainFundsRequest): Promis
     return response
 }
 
+export const bumpFee = async (req: BumpFeeRequest): Promise<BumpFeeResponse> => {
+    const response = await BreezSDK.bumpFee(req)
+    return response
+}
+
 export const fetchFiatRates = async (): Promise<Rate[]> => {
     const response = await BreezSDK.fetchFiatRates()
     return response
 }
 
+export const fiatRateHistory = async (currency: string, fromTimestamp: number = 0, toTimestamp: number = 0): Promise<HistoricalRate[]> => {
+    const response = await BreezSDK.fiatRateHistory(currency, fromTimestamp, toTimestamp)
+    return response
+}
+
+export const addFiatRateAlert = async (req: AddFiatRateAlertRequest): Promise<FiatRateAlert> => {
+    const response = await BreezSDK.addFiatRateAlert(req)
+    return response
+}
+
+export const listFiatRateAlerts = async (): Promise<FiatRateAlert[]> => {
+    const response = await BreezSDK.listFiatRateAlerts()
+    return response
+}
+
+export const removeFiatRateAlert = async (id: number): Promise<void> => {
+    await BreezSDK.removeFiatRateAlert(id)
+}
+
 export const listFiatCurrencies = async (): Promise<FiatCurrency[]> => {
     const response = await BreezSDK.listFiatCurrencies()
     return response
@@ -1030,6 +2280,15 @@ export const lspId = async (): Promise<string | null> => {
     return response
 }
 
+export const userSettings = async (): Promise<UserSettings> => {
+    const response = await BreezSDK.userSettings()
+    return response
+}
+
+export const setUserSettings = async (settings: UserSettings): Promise<void> => {
+    await BreezSDK.setUserSettings(settings)
+}
+
 export const lspInfo = async (): Promise<LspInformation> => {
     const response = await BreezSDK.lspInfo()
     return response
@@ -1039,6 +2298,39 @@ export const closeLspChannels = async (): Promise<void> => {
     await BreezSDK.closeLspChannels()
 }
 
+export const listChannels = async (): Promise<ChannelDetails[]> => {
+    const response = await BreezSDK.listChannels()
+    return response
+}
+
+export const closeChannel = async (req: CloseChannelRequest): Promise<CloseChannelResponse> => {
+    const response = await BreezSDK.closeChannel(req)
+    return response
+}
+
+export const connectPeer = async (req: ConnectPeerRequest): Promise<void> => {
+    await BreezSDK.connectPeer(req)
+}
+
+export const openChannel = async (req: OpenChannelRequest): Promise<OpenChannelResponse> => {
+    const response = await BreezSDK.openChannel(req)
+    return response
+}
+
+export const exportNodeMigration = async (req: NodeMigrationRequest): Promise<NodeMigrationState> => {
+    const response = await BreezSDK.exportNodeMigration(req)
+    return response
+}
+
+export const exportRecoveryBundle = async (req: ExportRecoveryBundleRequest): Promise<ExportRecoveryBundleResponse> => {
+    const response = await BreezSDK.exportRecoveryBundle(req)
+    return response
+}
+
+export const stopRecoveryBundleRefresh = async (): Promise<void> => {
+    await BreezSDK.stopRecoveryBundleRefresh()
+}
+
 export const registerWebhook = async (webhookUrl: string): Promise<void> => {
     await BreezSDK.registerWebhook(webhookUrl)
 }
@@ -1047,6 +2339,41 @@ export const unregisterWebhook = async (webhookUrl: string): Promise<void> => {
     await BreezSDK.unregisterWebhook(webhookUrl)
 }
 
+export const registerLightningAddress = async (username: string): Promise<LnurlPayInfo> => {
+    const response = await BreezSDK.registerLightningAddress(username)
+    return response
+}
+
+export const lnurlPayInfo = async (): Promise<LnurlPayInfo | null> => {
+    const response = await BreezSDK.lnurlPayInfo()
+    return response
+}
+
+export const createStaticLnurlPay = async (req: CreateStaticLnurlPayRequest): Promise<StaticLnurlPay> => {
+    const response = await BreezSDK.createStaticLnurlPay(req)
+    return response
+}
+
+export const staticLnurlPayCallback = async (req: StaticLnurlPayCallbackRequest): Promise<string> => {
+    const response = await BreezSDK.staticLnurlPayCallback(req)
+    return response
+}
+
+export const createLnurlWithdrawVoucher = async (req: CreateLnurlWithdrawVoucherRequest): Promise<LnurlWithdrawVoucher> => {
+    const response = await BreezSDK.createLnurlWithdrawVoucher(req)
+    return response
+}
+
+export const redeemLnurlWithdrawVoucher = async (req: RedeemLnurlWithdrawVoucherRequest): Promise<SendPaymentResponse> => {
+    const response = await BreezSDK.redeemLnurlWithdrawVoucher(req)
+    return response
+}
+
+export const listLnurlWithdrawVouchers = async (): Promise<LnurlWithdrawVoucher[]> => {
+    const response = await BreezSDK.listLnurlWithdrawVouchers()
+    return response
+}
+
 export const receiveOnchain = async (req: ReceiveOnchainRequest): Promise<SwapInfo> => {
     const response = await BreezSDK.receiveOnchain(req)
     return response
@@ -1065,11 +2392,21 @@ export const redeemSwap = async (swapAddress: string): Promise<void> => {
     await BreezSDK.redeemSwap(swapAddress)
 }
 
+export const verifySwapAddress = async (swapAddress: string): Promise<boolean> => {
+    const response = await BreezSDK.verifySwapAddress(swapAddress)
+    return response
+}
+
 export const listRefundables = async (): Promise<SwapInfo[]> => {
     const response = await BreezSDK.listRefundables()
     return response
 }
 
+export const exportSwapRefundKeys = async (req: ExportSwapRefundKeysRequest): Promise<SwapRefundKey[]> => {
+    const response = await BreezSDK.exportSwapRefundKeys(req)
+    return response
+}
+
 export const prepareRefund = async (req: PrepareRefundRequest): Promise<PrepareRefundResponse> => {
     const response = await BreezSDK.prepareRefund(req)
     return response
@@ -1114,6 +2451,11 @@ export const payOnchain = async (req: PayOnchainRequest): Promise<PayOnchainResp
     return response
 }
 
+export const payOnchainAddress = async (req: PayOnchainAddressRequest): Promise<PayOnchainAddressResponse> => {
+    const response = await BreezSDK.payOnchainAddress(req)
+    return response
+}
+
 export const executeDevCommand = async (command: string): Promise<string> => {
     const response = await BreezSDK.executeDevCommand(command)
     return response
@@ -1124,10 +2466,29 @@ export const generateDiagnosticData = async (): Promise<string> => {
     return response
 }
 
+export const diagnosticSnapshot = async (): Promise<string> => {
+    const response = await BreezSDK.diagnosticSnapshot()
+    return response
+}
+
 export const sync = async (): Promise<void> => {
     await BreezSDK.sync()
 }
 
+export const cancelSync = async (): Promise<boolean> => {
+    const response = await BreezSDK.cancelSync()
+    return response
+}
+
+export const metrics = async (): Promise<MetricsSnapshot> => {
+    const response = await BreezSDK.metrics()
+    return response
+}
+
+export const syncPartial = async (): Promise<void> => {
+    await BreezSDK.syncPartial()
+}
+
 export const recommendedFees = async (): Promise<RecommendedFees> => {
     const response = await BreezSDK.recommendedFees()
     return response
@@ -1138,6 +2499,11 @@ export const buyBitcoin = async (req: BuyBitcoinRequest): Promise<BuyBitcoinResp
     return response
 }
 
+export const fetchBuyBitcoinQuote = async (req: FetchBuyBitcoinQuoteRequest): Promise<BuyBitcoinQuote> => {
+    const response = await BreezSDK.fetchBuyBitcoinQuote(req)
+    return response
+}
+
 export const prepareRedeemOnchainFunds = async (req: PrepareRedeemOnchainFundsRequest): Promise<PrepareRedeemOnchainFundsResponse> => {
     const response = await BreezSDK.prepareRedeemOnchainFunds(req)
     return response
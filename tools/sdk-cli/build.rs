@@ -0,0 +1,6 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tonic_build::configure()
+        .build_client(false)
+        .compile(&["src/daemon/proto/breez_sdk.proto"], &["src/daemon/proto"])?;
+    Ok(())
+}
@@ -1,25 +1,41 @@
 use std::borrow::Cow::{self, Owned};
 use std::fs;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
-use anyhow::{anyhow, ensure, Context, Error, Result};
-use breez_sdk_core::InputType::{LnUrlAuth, LnUrlPay, LnUrlWithdraw};
+use anyhow::{anyhow, ensure, Error, Result};
+use bip39::{Language, Mnemonic};
+use breez_sdk_core::InputType::{EncryptedPaymentRequest, LnUrlAuth, LnUrlPay, LnUrlWithdraw};
 use breez_sdk_core::{
-    parse, BreezEvent, BreezServices, BuyBitcoinRequest, CheckMessageRequest, ConnectRequest,
-    EventListener, GreenlightCredentials, ListPaymentsRequest, ListSwapsRequest, LnUrlPayRequest,
-    LnUrlWithdrawRequest, MetadataFilter, PayOnchainRequest, PrepareOnchainPaymentRequest,
-    PrepareRedeemOnchainFundsRequest, PrepareRefundRequest, ReceiveOnchainRequest,
-    ReceivePaymentRequest, RedeemOnchainFundsRequest, RefundRequest, ReportIssueRequest,
-    ReportPaymentFailureDetails, ReverseSwapFeesRequest, SendPaymentRequest,
-    SendSpontaneousPaymentRequest, SignMessageRequest, StaticBackupRequest, SwapAmountType,
+    diff_snapshots, GreenlightNodeConfig, MockNodeConfig, Network, NodeConfig,
+    PaymentRequestBundle, SwapInfo,
+};
+use breez_sdk_core::{
+    parse, AddFiatRateAlertRequest, BreezEvent, BreezServices, BumpFeeRequest, BuyBitcoinRequest,
+    CheckMessageRequest, CloseChannelRequest, ConnectPeerRequest, ConnectRequest,
+    CreateLnurlWithdrawVoucherRequest, CreateStaticLnurlPayRequest, EventListener,
+    ExportPaymentsRequest, ExportRecoveryBundleRequest, ExportSwapRefundKeysRequest,
+    FetchBuyBitcoinQuoteRequest, FiatAmount, GreenlightCredentials, ImportPaymentsRequest,
+    ListPaymentGroupsRequest, ListPaymentsRequest, ListSwapsRequest, LnUrlPayRequest,
+    LnUrlWithdrawRequest, MetadataFilter, MilliSat, NodeMigrationRequest, OpenChannelRequest,
+    PayOfferRequest, PayOnchainAddressRequest, PayOnchainRequest, PaymentTypeFilter,
+    PrepareOnchainPaymentRequest, PrepareReceivePaymentRequest, PrepareRedeemOnchainFundsRequest,
+    PrepareRefundRequest, ProbePaymentRequest, ProveAddressOwnershipRequest, QueuePaymentRequest,
+    ReceiveOnchainRequest, ReceivePaymentRequest, ReceiveUnifiedRequest,
+    RedeemLnurlWithdrawVoucherRequest, RedeemOnchainFundsRequest, RefundRequest,
+    ReportIssueRequest, ReportPaymentFailureDetails, ReverseSwapFeesRequest, Sat,
+    SendPaymentRequest, SendPaymentsRequest, SendSpontaneousPaymentRequest, SignMessageRequest,
+    SortOrder, StaticBackupRequest, StaticLnurlPayCallbackRequest, SwapAmountType,
+    ValidatePayabilityRequest,
 };
-use breez_sdk_core::{GreenlightNodeConfig, NodeConfig};
 use qrcode_rs::render::unicode;
 use qrcode_rs::{EcLevel, QrCode};
 use rustyline::history::DefaultHistory;
+use serde_json::json;
 
-use crate::persist::CliPersistence;
+use crate::event_server::EventServer;
+use crate::persist::{CliPersistence, RestoredSeed};
+use crate::qr;
 use crate::Commands;
 
 use rustyline::highlight::Highlighter;
@@ -27,10 +43,26 @@ use rustyline::hint::HistoryHinter;
 use rustyline::Editor;
 use rustyline::{Completer, Helper, Hinter, Validator};
 
-struct CliEventListener {}
+/// How often the faucet command checks if the coins were confirmed
+const FAUCET_POLL_INTERVAL_SECS: u64 = 30;
+/// How long the faucet command waits for the coins to be confirmed
+const FAUCET_TIMEOUT_SECS: u64 = 3600;
+/// What a mock node connected with `connect --mock_balance_sat` can receive
+const MOCK_INBOUND_LIQUIDITY_MSAT: u64 = 10_000_000_000;
+
+struct CliEventListener {
+    /// Whether the connect progress is printed, while restoring a node interactively
+    show_connect_progress: bool,
+}
 
 impl EventListener for CliEventListener {
     fn on_event(&self, e: BreezEvent) {
+        if let (true, BreezEvent::ConnectProgress { details }) = (self.show_connect_progress, &e) {
+            println!(
+                "Recovery progress: {:?} ({}%)",
+                details.stage, details.progress_percent
+            );
+        }
         info!("Received Breez event: {e:?}");
     }
 }
@@ -50,13 +82,19 @@ impl Highlighter for CliHelper {
 pub struct CommandHandler {
     persistence: CliPersistence,
     sdk: Option<Arc<BreezServices>>,
+    /// Streams the SDK events to websocket clients, see [Commands::ServeEvents]
+    event_server: Option<EventServer>,
+    /// Whether the user can be prompted and human readable output, like QR codes, is shown
+    interactive: bool,
 }
 
 impl CommandHandler {
-    pub fn new(persistence: CliPersistence) -> Self {
+    pub fn new(persistence: CliPersistence, interactive: bool) -> Self {
         Self {
             persistence,
             sdk: None,
+            event_server: None,
+            interactive,
         }
     }
 
@@ -78,16 +116,46 @@ impl CommandHandler {
                 self.persistence.save_config(config)?;
                 Ok(format!("Environment was set to {env:?}"))
             }
+            Commands::SetNetwork { network } => {
+                let mut config = self.persistence.get_or_create_config()?;
+                config.network = Some(network);
+                self.persistence.save_config(config)?;
+                Ok(format!("Network was set to {network}"))
+            }
+            Commands::SetFaucetUrl { url } => {
+                let mut config = self.persistence.get_or_create_config()?;
+                config.faucet_url = Some(url);
+                self.persistence.save_config(config)?;
+                Ok("Faucet URL was set".to_string())
+            }
+            Commands::CreateProfile { name } => {
+                self.persistence.create_profile(&name)?;
+                Ok(format!("Profile {name} was created"))
+            }
+            Commands::ListProfiles {} => serde_json::to_string_pretty(&json!({
+                "active": self.persistence.profile,
+                "profiles": self.persistence.list_profiles()?,
+            }))
+            .map_err(|e| e.into()),
+            Commands::SwitchProfile { name } => {
+                self.disconnect().await?;
+                self.persistence.set_profile(name.clone())?;
+                Ok(match name {
+                    Some(name) => format!("Switched to profile {name}"),
+                    None => "Switched to the default profile".to_string(),
+                })
+            }
             Commands::Connect {
                 partner_cert,
                 partner_key,
                 invite_code,
                 restore_only,
+                mock_balance_sat,
             } => {
                 let mut config = self
                     .persistence
                     .get_or_create_config()?
-                    .to_sdk_config(&self.persistence.data_dir);
+                    .to_sdk_config(&self.persistence.profile_dir());
                 let mut partner_credentials: Option<GreenlightCredentials> = None;
                 if partner_cert.is_some() && partner_key.is_some() {
                     let cert = fs::read(partner_cert.unwrap())?;
@@ -98,52 +166,233 @@ impl CommandHandler {
                     })
                 }
 
+                config.node_config = match mock_balance_sat {
+                    Some(balance_sat) => NodeConfig::Mock {
+                        config: MockNodeConfig {
+                            channels_balance_msat: balance_sat * 1000,
+                            onchain_balance_msat: 0,
+                            inbound_liquidity_msat: MOCK_INBOUND_LIQUIDITY_MSAT,
+                        },
+                    },
+                    None => NodeConfig::Greenlight {
+                        config: GreenlightNodeConfig {
+                            partner_credentials,
+                            invite_code,
+                        },
+                    },
+                };
+
+                self.connect(
+                    ConnectRequest {
+                        config,
+                        seed: self.persistence.get_or_create_seed(),
+                        restore_only: None,
+                        register_if_missing: Some(!restore_only),
+                    },
+                    false,
+                )
+                .await?;
+                Ok("Node was connected successfully".to_string())
+            }
+            Commands::ConnectEphemeral { invite_code } => {
+                ensure!(self.sdk.is_none(), "Breez Services already initialized");
+                let mut config = self
+                    .persistence
+                    .get_or_create_config()?
+                    .to_sdk_config(&self.persistence.profile_dir());
                 config.node_config = NodeConfig::Greenlight {
                     config: GreenlightNodeConfig {
-                        partner_credentials,
+                        partner_credentials: None,
                         invite_code,
                     },
                 };
+                let listener = CliEventListener {
+                    show_connect_progress: false,
+                };
+                let service = BreezServices::connect_ephemeral(config, Box::new(listener)).await?;
+                self.sdk = Some(service);
+                Ok("Ephemeral wallet was connected successfully".to_string())
+            }
+            Commands::RestoreNode { mnemonic, seed_hex } => {
+                ensure!(self.sdk.is_none(), "Breez Services already initialized");
+                let restored = match (mnemonic, seed_hex) {
+                    (Some(words), None) => {
+                        // The words may be quoted, as a single argument
+                        let phrase = words.join(" ").replace(['"', '\''], "");
+                        RestoredSeed::Mnemonic(
+                            Mnemonic::from_phrase(
+                                &phrase.split_whitespace().collect::<Vec<_>>().join(" "),
+                                Language::English,
+                            )
+                            .map_err(|e| anyhow!("Invalid mnemonic: {e}"))?,
+                        )
+                    }
+                    (None, Some(seed_hex)) => {
+                        let seed = hex::decode(seed_hex.trim())
+                            .map_err(|e| anyhow!("Invalid seed hex: {e}"))?;
+                        ensure!(
+                            (16..=64).contains(&seed.len()),
+                            "The seed must be between 16 and 64 bytes long"
+                        );
+                        RestoredSeed::Raw(seed)
+                    }
+                    _ => return Err(anyhow!("Either a mnemonic or a seed hex is required")),
+                };
+                let saved = self.persistence.save_restored_seed(&restored)?;
 
-                self.connect(ConnectRequest {
+                let config = self
+                    .persistence
+                    .get_or_create_config()?
+                    .to_sdk_config(&self.persistence.profile_dir());
+                let req = ConnectRequest {
                     config,
                     seed: self.persistence.get_or_create_seed(),
-                    restore_only: Some(restore_only),
-                })
-                .await?;
-                Ok("Node was connected successfully".to_string())
+                    restore_only: None,
+                    register_if_missing: Some(false),
+                };
+                if let Err(e) = self.connect(req, self.interactive).await {
+                    // Another seed can be tried if no node was found for this one
+                    if saved {
+                        self.persistence.delete_seed()?;
+                    }
+                    return Err(e);
+                }
+                let sdk = self.sdk()?;
+                sdk.backup().await?;
+                sdk.sync().await?;
+                serde_json::to_string_pretty(&json!({
+                    "node_id": sdk.node_info()?.id,
+                    "backup_versions": sdk.list_backup_versions().await?,
+                }))
+                .map_err(|e| e.into())
             }
-            Commands::Sync {} => {
-                self.sdk()?.sync().await?;
+            Commands::ShowMnemonic { yes } => {
+                let phrase = self.persistence.get_mnemonic()?.ok_or(anyhow!(
+                    "The wallet has no mnemonic, it was restored from a raw seed or not created yet"
+                ))?;
+                if !yes {
+                    ensure!(
+                        self.interactive,
+                        "The mnemonic gives full control of the funds, pass --yes to show it"
+                    );
+                    let answer = rl.readline(
+                        "The mnemonic gives full control of the funds. Type 'yes' to show it: ",
+                    )?;
+                    ensure!(answer.trim() == "yes", "The mnemonic was not shown");
+                }
+                Ok(phrase)
+            }
+            Commands::Sync { partial } => {
+                match partial {
+                    true => self.sdk()?.sync_partial().await?,
+                    false => self.sdk()?.sync().await?,
+                }
                 Ok("Sync finished successfully".to_string())
             }
             Commands::Parse { input } => parse(&input, None)
                 .await
                 .map(|res| serde_json::to_string_pretty(&res))?
                 .map_err(|e| e.into()),
+            Commands::ScanQr { paths, parts } => {
+                let mut contents = qr::scan_images(&paths)?;
+                contents.extend(parts);
+                let input = qr::join_parts(&contents)?;
+                parse(&input, None)
+                    .await
+                    .map(|res| serde_json::to_string_pretty(&res))?
+                    .map_err(|e| e.into())
+            }
+            Commands::VerifyInvoices { bolt11 } => {
+                let results = self.sdk()?.verify_invoices(bolt11).await;
+                serde_json::to_string_pretty(&results).map_err(|e| e.into())
+            }
+            Commands::EncryptPaymentRequest {
+                invoice,
+                fallback,
+                metadata,
+            } => Ok(self
+                .sdk()?
+                .encrypt_payment_request(PaymentRequestBundle {
+                    invoice,
+                    fallback,
+                    metadata,
+                })
+                .await?),
+            Commands::DecryptPaymentRequest { input } => match parse(&input, None).await? {
+                EncryptedPaymentRequest { data } => {
+                    serde_json::to_string_pretty(&self.sdk()?.decrypt_payment_request(data).await?)
+                        .map_err(|e| e.into())
+                }
+                _ => Err(anyhow!("Not an encrypted payment request")),
+            },
+            Commands::PrepareReceivePayment {
+                amount_msat,
+                expiry,
+            } => {
+                let res = self
+                    .sdk()?
+                    .prepare_receive_payment(PrepareReceivePaymentRequest {
+                        amount_msat: MilliSat(amount_msat),
+                        expiry,
+                        ..Default::default()
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&res).map_err(|e| e.into())
+            }
             Commands::ReceivePayment {
                 amount_msat,
                 description,
                 use_description_hash,
                 expiry,
                 cltv,
+                include_fallback_address,
+                fiat_amount,
+                fiat_currency,
             } => {
                 let recv_payment_response = self
                     .sdk()?
                     .receive_payment(ReceivePaymentRequest {
-                        amount_msat,
+                        amount_msat: MilliSat(amount_msat),
                         description,
                         use_description_hash,
                         expiry,
                         cltv,
+                        include_fallback_address: Some(include_fallback_address),
+                        fiat_amount: fiat_amount.map(|amount| FiatAmount {
+                            currency: fiat_currency,
+                            amount,
+                        }),
                         ..Default::default()
                     })
                     .await?;
+                if !self.interactive {
+                    return serde_json::to_string_pretty(&recv_payment_response)
+                        .map_err(|e| e.into());
+                }
                 let mut result = serde_json::to_string(&recv_payment_response)?;
                 result.push('\n');
                 result.push_str(&build_qr_text(&recv_payment_response.ln_invoice.bolt11));
                 Ok(result)
             }
+            Commands::ReceiveUnified {
+                amount_sat,
+                description,
+            } => {
+                let res = self
+                    .sdk()?
+                    .receive_unified(ReceiveUnifiedRequest {
+                        amount_sat: Sat(amount_sat),
+                        description,
+                        opening_fee_params: None,
+                    })
+                    .await?;
+                let mut result = serde_json::to_string_pretty(&res)?;
+                if self.interactive {
+                    result.push('\n');
+                    result.push_str(&build_qr_text(&res.bip21));
+                }
+                Ok(result)
+            }
             Commands::OnchainPaymentLimits {} => {
                 let response = self.sdk()?.onchain_payment_limits().await?;
                 serde_json::to_string_pretty(&response).map_err(|e| e.into())
@@ -154,7 +403,7 @@ impl CommandHandler {
                 claim_tx_feerate,
             } => {
                 let req = PrepareOnchainPaymentRequest {
-                    amount_sat,
+                    amount_sat: Sat(amount_sat),
                     amount_type: match is_send {
                         true => SwapAmountType::Send,
                         false => SwapAmountType::Receive,
@@ -171,7 +420,7 @@ impl CommandHandler {
                 recipient_address,
             } => {
                 let req_prepare = PrepareOnchainPaymentRequest {
-                    amount_sat,
+                    amount_sat: Sat(amount_sat),
                     amount_type: match is_send {
                         true => SwapAmountType::Send,
                         false => SwapAmountType::Receive,
@@ -183,10 +432,28 @@ impl CommandHandler {
                 let req = PayOnchainRequest {
                     recipient_address,
                     prepare_res: res_prepare,
+                    cancel_token: None,
                 };
                 let response = self.sdk()?.pay_onchain(req).await?;
                 serde_json::to_string_pretty(&response).map_err(|e| e.into())
             }
+            Commands::SendOnchain {
+                amount_sat,
+                drain,
+                fee_preference,
+                address,
+            } => {
+                let response = self
+                    .sdk()?
+                    .pay_onchain_address(PayOnchainAddressRequest {
+                        address,
+                        amount_sat: amount_sat.map(Sat),
+                        drain,
+                        fee_preference,
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&response).map_err(|e| e.into())
+            }
             Commands::FetchOnchainFees {
                 send_amount_sat,
                 claim_tx_feerate,
@@ -194,7 +461,7 @@ impl CommandHandler {
                 let pair_info = self
                     .sdk()?
                     .fetch_reverse_swap_fees(ReverseSwapFeesRequest {
-                        send_amount_sat,
+                        send_amount_sat: send_amount_sat.map(Sat),
                         claim_tx_feerate,
                     })
                     .await
@@ -216,22 +483,95 @@ impl CommandHandler {
                 amount_msat,
                 label,
                 use_trampoline,
+                route_hint_index,
+                payment_timeout_sec,
+                maxfee_percent,
+                exemptfee_msat,
+                skip_denylist,
             } => {
                 let start = SystemTime::now();
                 let payment = self
                     .sdk()?
                     .send_payment(SendPaymentRequest {
                         bolt11,
-                        amount_msat,
+                        amount_msat: amount_msat.map(MilliSat),
                         label,
-                        use_trampoline,
+                        use_trampoline: use_trampoline.then_some(true),
+                        route_hint_index,
+                        payment_timeout_sec,
+                        maxfee_percent,
+                        exemptfee_msat: exemptfee_msat.map(MilliSat),
+                        cancel_token: None,
+                        skip_denylist: skip_denylist.then_some(true),
                     })
                     .await?;
-                let end = SystemTime::now();
-                let diff = end.duration_since(start)?;
-                println!("payment took {}s", diff.as_secs_f32());
+                self.show_duration(start)?;
                 serde_json::to_string_pretty(&payment).map_err(|e| e.into())
             }
+            Commands::SendPayments {
+                max_concurrency,
+                bolt11,
+            } => {
+                let start = SystemTime::now();
+                let payments = bolt11
+                    .into_iter()
+                    .map(|bolt11| SendPaymentRequest {
+                        bolt11,
+                        amount_msat: None,
+                        label: None,
+                        use_trampoline: None,
+                        route_hint_index: None,
+                        payment_timeout_sec: None,
+                        maxfee_percent: None,
+                        exemptfee_msat: None,
+                        cancel_token: None,
+                        skip_denylist: None,
+                    })
+                    .collect();
+                let response = self
+                    .sdk()?
+                    .send_payments(SendPaymentsRequest {
+                        payments,
+                        max_concurrency,
+                    })
+                    .await?;
+                self.show_duration(start)?;
+                serde_json::to_string_pretty(&response).map_err(|e| e.into())
+            }
+            Commands::QueuePayment {
+                bolt11,
+                amount_msat,
+                label,
+                expiry_secs,
+            } => {
+                let outbox_payment = self
+                    .sdk()?
+                    .queue_payment(QueuePaymentRequest {
+                        req: SendPaymentRequest {
+                            bolt11,
+                            amount_msat: amount_msat.map(MilliSat),
+                            label,
+                            use_trampoline: None,
+                            route_hint_index: None,
+                            payment_timeout_sec: None,
+                            maxfee_percent: None,
+                            exemptfee_msat: None,
+                            cancel_token: None,
+                            skip_denylist: None,
+                        },
+                        expiry_secs,
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&outbox_payment).map_err(|e| e.into())
+            }
+            Commands::ListOutbox {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_pending_outbox().await?)
+                    .map_err(|e| e.into())
+            }
+            Commands::CancelOutboxPayment { id } => {
+                self.sdk()?.cancel_outbox_payment(id).await?;
+                Ok("Payment removed from the queue".to_string())
+            }
             Commands::SendSpontaneousPayment {
                 node_id,
                 amount_msat,
@@ -242,14 +582,59 @@ impl CommandHandler {
                     .sdk()?
                     .send_spontaneous_payment(SendSpontaneousPaymentRequest {
                         node_id,
-                        amount_msat,
+                        amount_msat: MilliSat(amount_msat),
                         extra_tlvs: None,
                         label,
                     })
                     .await?;
-                let end = SystemTime::now();
-                let diff = end.duration_since(start)?;
-                println!("payment took {}s", diff.as_secs_f32());
+                self.show_duration(start)?;
+                serde_json::to_string_pretty(&response.payment).map_err(|e| e.into())
+            }
+            Commands::ProbePayment {
+                destination,
+                amount_msat,
+            } => {
+                let probe = self
+                    .sdk()?
+                    .probe_payment(ProbePaymentRequest {
+                        destination,
+                        amount_msat: amount_msat.map(MilliSat),
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&probe).map_err(|e| e.into())
+            }
+            Commands::ListPaymentProbes {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_payment_probes().await?)
+                    .map_err(|e| e.into())
+            }
+            Commands::ValidatePayability {
+                bolt11,
+                amount_msat,
+            } => {
+                let payability = self
+                    .sdk()?
+                    .validate_payability(ValidatePayabilityRequest {
+                        bolt11,
+                        amount_msat: amount_msat.map(MilliSat),
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&payability).map_err(|e| e.into())
+            }
+            Commands::PayOffer {
+                offer,
+                amount_msat,
+                payer_note,
+                label,
+            } => {
+                let response = self
+                    .sdk()?
+                    .pay_offer(PayOfferRequest {
+                        offer,
+                        amount_msat: amount_msat.map(MilliSat),
+                        payer_note,
+                        label,
+                    })
+                    .await?;
                 serde_json::to_string_pretty(&response.payment).map_err(|e| e.into())
             }
             Commands::ListPayments {
@@ -259,18 +644,26 @@ impl CommandHandler {
                 limit,
                 offset,
                 metadata_filters: metadata_filters_raw,
+                include_tlv_hex,
+                spontaneous,
+                ascending,
+                group_by,
             } => {
                 let metadata_filters = match metadata_filters_raw {
                     Some(raw_filters) => {
                         let mut filters = vec![];
 
                         for filter in raw_filters.iter() {
-                            let (json_path, json_value) =
-                                filter.split_once(':').context("Invalid metadata filter")?;
+                            let (json_path, json_value) = match filter.split_once(':') {
+                                Some((json_path, json_value)) => {
+                                    (json_path, Some(json_value.to_string()))
+                                }
+                                None => (filter.as_str(), None),
+                            };
 
                             filters.push(MetadataFilter {
                                 json_path: json_path.to_string(),
-                                json_value: json_value.to_string(),
+                                json_value,
                             });
                         }
 
@@ -279,19 +672,64 @@ impl CommandHandler {
                     None => None,
                 };
 
-                let payments = self
+                let req = ListPaymentsRequest {
+                    filters: spontaneous.then(|| vec![PaymentTypeFilter::SpontaneousReceived]),
+                    metadata_filters,
+                    from_timestamp,
+                    to_timestamp,
+                    include_failures: Some(include_failures),
+                    limit,
+                    offset,
+                    include_tlv_hex: Some(include_tlv_hex),
+                    sort_order: ascending.then_some(SortOrder::Ascending),
+                };
+                if let Some(group_by) = group_by {
+                    let groups = self
+                        .sdk()?
+                        .list_payment_groups(ListPaymentGroupsRequest {
+                            group_by,
+                            payments: ListPaymentsRequest {
+                                limit: None,
+                                offset: None,
+                                ..req
+                            },
+                            limit,
+                        })
+                        .await?;
+                    return serde_json::to_string_pretty(&groups).map_err(|e| e.into());
+                }
+
+                let payments = self.sdk()?.list_payments(req).await?;
+                serde_json::to_string_pretty(&payments).map_err(|e| e.into())
+            }
+            Commands::ExportPayments {
+                path,
+                format,
+                from_timestamp,
+                to_timestamp,
+                fiat,
+            } => {
+                let export = self
                     .sdk()?
-                    .list_payments(ListPaymentsRequest {
-                        filters: None,
-                        metadata_filters,
+                    .export_payments(ExportPaymentsRequest {
+                        format,
                         from_timestamp,
                         to_timestamp,
-                        include_failures: Some(include_failures),
-                        limit,
-                        offset,
+                        include_fiat: fiat,
                     })
                     .await?;
-                serde_json::to_string_pretty(&payments).map_err(|e| e.into())
+                fs::write(&path, export)?;
+                Ok(format!("Payments were exported to {}", path.display()))
+            }
+            Commands::ImportPayments { path, format } => {
+                let res = self
+                    .sdk()?
+                    .import_payments(ImportPaymentsRequest {
+                        format,
+                        data: fs::read_to_string(path)?,
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&res).map_err(|e| e.into())
             }
             Commands::SetPaymentMetadata {
                 payment_hash,
@@ -307,15 +745,34 @@ impl CommandHandler {
                 let payment = self.sdk()?.payment_by_hash(hash).await?;
                 serde_json::to_string_pretty(&payment).map_err(|e| e.into())
             }
+            Commands::ListChannelFees {} => {
+                let fees = self.sdk()?.list_channel_fees_paid().await?;
+                serde_json::to_string_pretty(&fees).map_err(|e| e.into())
+            }
             Commands::RedeemOnchainFunds {
                 to_address,
                 sat_per_vbyte,
+                amount_sat,
             } => {
                 let resp = self
                     .sdk()?
                     .redeem_onchain_funds(RedeemOnchainFundsRequest {
                         to_address,
                         sat_per_vbyte,
+                        amount_sat: amount_sat.map(Sat),
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&resp).map_err(|e| e.into())
+            }
+            Commands::BumpFee {
+                txid,
+                sat_per_vbyte,
+            } => {
+                let resp = self
+                    .sdk()?
+                    .bump_fee(BumpFeeRequest {
+                        txid,
+                        sat_per_vbyte,
                     })
                     .await?;
                 serde_json::to_string_pretty(&resp).map_err(|e| e.into())
@@ -323,12 +780,14 @@ impl CommandHandler {
             Commands::PrepareRedeemOnchainFunds {
                 to_address,
                 sat_per_vbyte,
+                amount_sat,
             } => {
                 let resp = self
                     .sdk()?
                     .prepare_redeem_onchain_funds(PrepareRedeemOnchainFundsRequest {
                         to_address,
                         sat_per_vbyte,
+                        amount_sat: amount_sat.map(Sat),
                     })
                     .await?;
                 serde_json::to_string_pretty(&resp).map_err(|e| e.into())
@@ -352,7 +811,7 @@ impl CommandHandler {
                 let res = self
                     .sdk()?
                     .open_channel_fee(breez_sdk_core::OpenChannelFeeRequest {
-                        amount_msat,
+                        amount_msat: amount_msat.map(MilliSat),
                         expiry,
                     })
                     .await?;
@@ -367,6 +826,13 @@ impl CommandHandler {
             Commands::NodeInfo {} => {
                 serde_json::to_string_pretty(&self.sdk()?.node_info()?).map_err(|e| e.into())
             }
+            Commands::Balances {} => {
+                serde_json::to_string_pretty(&self.sdk()?.balances()?).map_err(|e| e.into())
+            }
+            Commands::BalanceBreakdown {} => {
+                serde_json::to_string_pretty(&self.sdk()?.get_balance_breakdown().await?)
+                    .map_err(|e| e.into())
+            }
             Commands::ConfigureNode { close_to_address } => {
                 self.sdk()?
                     .configure_node(breez_sdk_core::ConfigureNodeRequest { close_to_address })
@@ -381,15 +847,81 @@ impl CommandHandler {
                 serde_json::to_string_pretty(&self.sdk()?.fetch_fiat_rates().await?)
                     .map_err(|e| e.into())
             }
+            Commands::AddFiatRateAlert {
+                currency,
+                direction,
+                threshold,
+            } => {
+                let alert = self
+                    .sdk()?
+                    .add_fiat_rate_alert(AddFiatRateAlertRequest {
+                        currency,
+                        direction,
+                        threshold,
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&alert).map_err(|e| e.into())
+            }
+            Commands::ListFiatRateAlerts {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_fiat_rate_alerts().await?)
+                    .map_err(|e| e.into())
+            }
+            Commands::RemoveFiatRateAlert { id } => {
+                self.sdk()?.remove_fiat_rate_alert(id).await?;
+                Ok(format!("Fiat rate alert {id} removed"))
+            }
             Commands::CloseLSPChannels {} => {
                 let tx_ids = self.sdk()?.close_lsp_channels().await?;
                 Ok(format!("Closing transaction ids:\n{tx_ids:?}"))
             }
+            Commands::ListChannels {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_channels().await?)
+                    .map_err(|e| e.into())
+            }
+            Commands::ConnectPeer { node_uri } => {
+                self.sdk()?
+                    .connect_peer(ConnectPeerRequest { node_uri })
+                    .await?;
+                Ok("Peer was connected successfully".to_string())
+            }
+            Commands::OpenChannel {
+                node_id,
+                amount_sat,
+                announce,
+                sat_per_vbyte,
+            } => {
+                let res = self
+                    .sdk()?
+                    .open_channel(OpenChannelRequest {
+                        node_id,
+                        amount_sat: Sat(amount_sat),
+                        announce,
+                        sat_per_vbyte,
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&res).map_err(|e| e.into())
+            }
+            Commands::CloseChannel { channel_id, force } => {
+                let res = self
+                    .sdk()?
+                    .close_channel(CloseChannelRequest { channel_id, force })
+                    .await?;
+                serde_json::to_string_pretty(&res).map_err(|e| e.into())
+            }
             Commands::Disconnect {} => {
                 self.sdk()?.disconnect().await?;
                 self.sdk = None;
+                self.event_server = None;
                 Ok("Node was stopped successfully".to_string())
             }
+            Commands::ServeEvents { address } => {
+                // Stop the previous server first, in case it listens on the same address
+                self.event_server = None;
+                let server = EventServer::start(self.sdk()?, &address).await?;
+                let local_addr = server.local_addr;
+                self.event_server = Some(server);
+                Ok(format!("Streaming events to ws://{local_addr}"))
+            }
             Commands::RecommendedFees {} => {
                 serde_json::to_string_pretty(&self.sdk()?.recommended_fees().await?)
                     .map_err(|e| e.into())
@@ -405,10 +937,29 @@ impl CommandHandler {
                 serde_json::to_string_pretty(&self.sdk()?.in_progress_swap().await?)
                     .map_err(|e| e.into())
             }
+            Commands::Faucet { amount_sat } => {
+                let swap = self.request_faucet_coins(amount_sat).await?;
+                serde_json::to_string_pretty(&swap).map_err(|e| e.into())
+            }
+            Commands::VerifySwapAddress { swap_address } => {
+                match self.sdk()?.verify_swap_address(swap_address).await? {
+                    true => Ok("Swap address verified".to_string()),
+                    false => Err(anyhow!("Swap address doesn't match the swap keys")),
+                }
+            }
             Commands::ListRefundables {} => {
                 serde_json::to_string_pretty(&self.sdk()?.list_refundables().await?)
                     .map_err(|e| e.into())
             }
+            Commands::ExportSwapRefundKeys { include_gap_keys } => {
+                let keys = self
+                    .sdk()?
+                    .export_swap_refund_keys(ExportSwapRefundKeysRequest {
+                        include_gap_keys: Some(include_gap_keys),
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&keys).map_err(|e| e.into())
+            }
             Commands::RescanSwaps {} => {
                 self.sdk()?.rescan_swaps().await?;
                 Ok("Rescan completed successfully".to_string())
@@ -418,6 +969,7 @@ impl CommandHandler {
                 to_address,
                 sat_per_vbyte,
                 unilateral,
+                fee_previews,
             } => {
                 let res = self
                     .sdk()?
@@ -426,12 +978,20 @@ impl CommandHandler {
                         to_address,
                         sat_per_vbyte,
                         unilateral,
+                        include_fee_previews: Some(fee_previews),
                     })
                     .await?;
-                Ok(format!(
-                    "Prepared refund tx - weight: {} - fees: {} sat",
+                let mut result = format!(
+                    "Prepared refund tx - weight: {} - fees: {}",
                     res.refund_tx_weight, res.refund_tx_fee_sat
-                ))
+                );
+                for preview in res.fee_previews {
+                    result.push_str(&format!(
+                        "\n{:?}: {} sat/vbyte - fees: {} sat",
+                        preview.preset, preview.sat_per_vbyte, preview.refund_tx_fee_sat
+                    ));
+                }
+                Ok(result)
             }
             Commands::Refund {
                 swap_address,
@@ -466,6 +1026,11 @@ impl CommandHandler {
                 let res = self.sdk()?.sign_message(req).await?;
                 Ok(format!("Message signature: {}", res.signature))
             }
+            Commands::ProveAddressOwnership { address, message } => {
+                let req = ProveAddressOwnershipRequest { address, message };
+                let res = self.sdk()?.prove_address_ownership(req).await?;
+                Ok(format!("Address ownership signature: {}", res.signature))
+            }
             Commands::CheckMessage {
                 message,
                 pubkey,
@@ -484,35 +1049,44 @@ impl CommandHandler {
                 label,
                 validate_success_url,
                 use_trampoline,
+                amount_msat,
+                skip_denylist,
             } => match parse(&lnurl, None).await? {
-                LnUrlPay { data: pd, .. } => {
+                LnUrlPay {
+                    data: pd,
+                    amount_msat: deep_link_amount_msat,
+                    comment,
+                    ..
+                } => {
                     let prompt = format!(
                         "Amount to pay in millisatoshi (min {} msat, max {} msat: ",
                         pd.min_sendable, pd.max_sendable
                     );
 
-                    let amount_msat = rl.readline(&prompt)?;
+                    // The amount of a deep link saves prompting for it
+                    let amount_msat =
+                        self.amount_msat(rl, &prompt, amount_msat.or(deep_link_amount_msat))?;
                     let start = SystemTime::now();
                     let pay_res = self
                         .sdk()?
                         .lnurl_pay(LnUrlPayRequest {
                             data: pd,
-                            amount_msat: amount_msat.parse::<u64>()?,
+                            amount_msat,
                             use_trampoline,
-                            comment: None,
+                            comment,
                             payment_label: label,
                             validate_success_action_url: validate_success_url,
+                            pending_timeout_sec: None,
+                            cancel_token: None,
+                            skip_denylist: skip_denylist.then_some(true),
                         })
                         .await?;
-                    let end = SystemTime::now();
-                    let diff = end.duration_since(start)?;
-                    println!("payment took {}s", diff.as_secs_f32());
-                    //show_results(pay_res);
+                    self.show_duration(start)?;
                     serde_json::to_string_pretty(&pay_res).map_err(|e| e.into())
                 }
                 _ => Err(anyhow!("Invalid input")),
             },
-            Commands::LnurlWithdraw { lnurl } => {
+            Commands::LnurlWithdraw { lnurl, amount_msat } => {
                 match parse(&lnurl, None).await? {
                     LnUrlWithdraw { data: wd } => {
                         info!("Endpoint description: {}", wd.default_description);
@@ -535,9 +1109,7 @@ impl CommandHandler {
                         let prompt = format!(
                         "Amount to withdraw in msat (min {user_input_min_msat} msat, max {user_input_max_msat} msat: "
                     );
-                        let user_input_withdraw_amount_msat = rl.readline(&prompt)?;
-
-                        let amount_msat: u64 = user_input_withdraw_amount_msat.parse()?;
+                        let amount_msat = self.amount_msat(rl, &prompt, amount_msat)?;
                         let description = "LNURL-withdraw";
 
                         let withdraw_res = self
@@ -564,6 +1136,29 @@ impl CommandHandler {
                     _ => Err(anyhow!("Unexpected result type")),
                 }
             }
+            Commands::ListLnurlAuthDomains {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_lnurl_auth_domains()?)
+                    .map_err(Into::into)
+            }
+            Commands::LnurlAuthLinkingKey { domain } => {
+                Ok(self.sdk()?.lnurl_auth_linking_key(domain).await?)
+            }
+            Commands::ExportLnurlAuth { path } => {
+                let export = self.sdk()?.export_lnurl_auth().await?;
+                fs::write(&path, serde_json::to_vec_pretty(&export)?)?;
+                Ok(format!(
+                    "Exported {} domains to {path}",
+                    export.domains.len()
+                ))
+            }
+            Commands::ImportLnurlAuth { path } => {
+                let export = serde_json::from_slice(&fs::read(&path)?)?;
+                self.sdk()?.import_lnurl_auth(export).await?;
+                Ok(format!("Imported the lnurl auth history of {path}"))
+            }
+            Commands::SdkInfo {} => {
+                serde_json::to_string_pretty(&BreezServices::sdk_info()).map_err(Into::into)
+            }
             Commands::ServiceHealthCheck {} => {
                 let config: crate::config::CliConfig = self.persistence.get_or_create_config()?;
                 match config.api_key {
@@ -588,9 +1183,40 @@ impl CommandHandler {
                     .await?;
                 Ok("Report sent".into())
             }
+            Commands::PeerConnectivity {} => {
+                serde_json::to_string_pretty(&self.sdk()?.peer_connectivity().await?)
+                    .map_err(Into::into)
+            }
+            Commands::ConnectionStatus {} => {
+                serde_json::to_string_pretty(&self.sdk()?.connection_status().await?)
+                    .map_err(Into::into)
+            }
+            Commands::TaskStatus {} => {
+                serde_json::to_string_pretty(&self.sdk()?.task_status()?).map_err(Into::into)
+            }
+            Commands::Metrics { prometheus } => {
+                let metrics = self.sdk()?.metrics().await?;
+                match prometheus {
+                    true => Ok(metrics.to_prometheus()),
+                    false => serde_json::to_string_pretty(&metrics).map_err(Into::into),
+                }
+            }
+            Commands::DiagnosticSnapshot { path } => {
+                fs::write(&path, self.sdk()?.diagnostic_snapshot().await?)?;
+                Ok(format!("Snapshot written to {path}"))
+            }
+            Commands::DiffSnapshots { before, after } => {
+                let changes =
+                    diff_snapshots(fs::read_to_string(before)?, fs::read_to_string(after)?)?;
+                serde_json::to_string_pretty(&changes).map_err(Into::into)
+            }
             Commands::ExecuteDevCommand { command } => {
                 Ok(self.sdk()?.execute_dev_command(command).await?)
             }
+            Commands::SetLogFilter { filter } => {
+                BreezServices::set_log_filter(&filter)?;
+                Ok("Log filter was set".into())
+            }
             Commands::GenerateDiagnosticData {} => {
                 Ok(self.sdk()?.generate_diagnostic_data().await?)
             }
@@ -605,15 +1231,49 @@ impl CommandHandler {
                     .await?;
                 Ok(format!("Here your {provider:?} url: {}", res.url))
             }
+            Commands::FetchBuyBitcoinQuote {
+                provider,
+                currency,
+                amount,
+            } => {
+                let quote = self
+                    .sdk()?
+                    .fetch_buy_bitcoin_quote(FetchBuyBitcoinQuoteRequest {
+                        provider,
+                        fiat_amount: FiatAmount { currency, amount },
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&quote).map_err(|e| e.into())
+            }
             Commands::Backup {} => {
                 self.sdk()?.backup().await?;
                 Ok("Backup completed successfully".into())
             }
+            Commands::RotateBackupKey {} => {
+                self.sdk()?.rotate_backup_key().await?;
+                Ok("Backup key rotated successfully".into())
+            }
+            Commands::ListBackupVersions {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_backup_versions().await?)
+                    .map_err(|e| e.into())
+            }
+            Commands::RestoreBackup { version } => {
+                self.sdk()?.restore_backup(version).await?;
+                Ok(format!("Backup version {version} restored successfully"))
+            }
+            Commands::UserSettings {} => {
+                serde_json::to_string_pretty(&self.sdk()?.user_settings()?).map_err(|e| e.into())
+            }
+            Commands::SetUserSettings { settings } => {
+                self.sdk()?
+                    .set_user_settings(serde_json::from_str(&settings)?)?;
+                Ok("User settings updated successfully".into())
+            }
             Commands::StaticBackup {} => {
                 let config = self
                     .persistence
                     .get_or_create_config()?
-                    .to_sdk_config(&self.persistence.data_dir);
+                    .to_sdk_config(&self.persistence.profile_dir());
                 let backup_data = BreezServices::static_backup(StaticBackupRequest {
                     working_dir: config.working_dir,
                 })?;
@@ -625,6 +1285,31 @@ impl CommandHandler {
                     None => Ok("No static backup data".into()),
                 }
             }
+            Commands::ExportNodeMigration { close_channels } => {
+                let state = self
+                    .sdk()?
+                    .export_node_migration(NodeMigrationRequest { close_channels })
+                    .await?;
+                serde_json::to_string_pretty(&state).map_err(|e| e.into())
+            }
+            Commands::ExportRecoveryBundle {
+                recipient_pubkey,
+                path,
+                instructions,
+            } => {
+                self.sdk()?
+                    .export_recovery_bundle(ExportRecoveryBundleRequest {
+                        recipient_pubkey,
+                        instructions,
+                        file_path: Some(path.clone()),
+                    })
+                    .await?;
+                Ok(format!("Recovery bundle written to {path}"))
+            }
+            Commands::StopRecoveryBundleRefresh {} => {
+                self.sdk()?.stop_recovery_bundle_refresh()?;
+                Ok("The recovery bundle is not refreshed anymore".into())
+            }
             Commands::RegisterWebhook { url } => {
                 self.sdk()?.register_webhook(url).await?;
                 Ok("Url registered successfully".into())
@@ -633,25 +1318,202 @@ impl CommandHandler {
                 self.sdk()?.unregister_webhook(url).await?;
                 Ok("Url unregistered successfully".into())
             }
+            Commands::RegisterLightningAddress { username } => {
+                let info = self.sdk()?.register_lightning_address(username).await?;
+                serde_json::to_string_pretty(&info).map_err(Into::into)
+            }
+            Commands::LnurlPayInfo {} => {
+                serde_json::to_string_pretty(&self.sdk()?.lnurl_pay_info()?).map_err(Into::into)
+            }
+            Commands::CreateStaticLnurlPay {
+                url,
+                callback_url,
+                description,
+                max_sendable_msat,
+                min_sendable_msat,
+                comment_allowed,
+            } => {
+                let res = self
+                    .sdk()?
+                    .create_static_lnurl_pay(CreateStaticLnurlPayRequest {
+                        url,
+                        callback_url,
+                        description,
+                        min_sendable_msat: MilliSat(min_sendable_msat),
+                        max_sendable_msat: MilliSat(max_sendable_msat),
+                        comment_allowed,
+                    })
+                    .await?;
+                let mut result = serde_json::to_string_pretty(&res)?;
+                if self.interactive {
+                    result.push('\n');
+                    result.push_str(&build_qr_text(&res.lnurl));
+                }
+                Ok(result)
+            }
+            Commands::StaticLnurlPayCallback {
+                amount_msat,
+                comment,
+            } => Ok(self
+                .sdk()?
+                .static_lnurl_pay_callback(StaticLnurlPayCallbackRequest {
+                    amount_msat: MilliSat(amount_msat),
+                    comment,
+                })
+                .await?),
+            Commands::CreateLnurlWithdrawVoucher {
+                amount_msat,
+                uses,
+                expiry_secs,
+                description,
+            } => {
+                let voucher = self
+                    .sdk()?
+                    .create_lnurl_withdraw_voucher(CreateLnurlWithdrawVoucherRequest {
+                        amount_msat: MilliSat(amount_msat),
+                        uses,
+                        expiry_secs,
+                        description,
+                    })
+                    .await?;
+                serde_json::to_string_pretty(&voucher).map_err(Into::into)
+            }
+            Commands::RedeemLnurlWithdrawVoucher { id, bolt11 } => {
+                let res = self
+                    .sdk()?
+                    .redeem_lnurl_withdraw_voucher(RedeemLnurlWithdrawVoucherRequest { id, bolt11 })
+                    .await?;
+                serde_json::to_string_pretty(&res.payment).map_err(Into::into)
+            }
+            Commands::ListLnurlWithdrawVouchers {} => {
+                serde_json::to_string_pretty(&self.sdk()?.list_lnurl_withdraw_vouchers()?)
+                    .map_err(Into::into)
+            }
             Commands::Abort {} => {
                 std::process::exit(0);
             }
         }
     }
 
-    async fn connect(&mut self, req: ConnectRequest) -> Result<()> {
+    async fn connect(&mut self, req: ConnectRequest, show_connect_progress: bool) -> Result<()> {
         ensure!(self.sdk.is_none(), "Breez Services already initialized");
-        let service = BreezServices::connect(req, Box::new(CliEventListener {})).await?;
+        let listener = CliEventListener {
+            show_connect_progress,
+        };
+        let service = BreezServices::connect(req, Box::new(listener)).await?;
         self.sdk = Some(service);
         Ok(())
     }
 
-    fn sdk(&self) -> Result<Arc<BreezServices>> {
+    /// Connects to the node with the saved config, without registering a new node
+    pub(crate) async fn connect_saved(&mut self) -> Result<()> {
+        let config = self
+            .persistence
+            .get_or_create_config()?
+            .to_sdk_config(&self.persistence.profile_dir());
+        self.connect(
+            ConnectRequest {
+                config,
+                seed: self.persistence.get_or_create_seed(),
+                restore_only: None,
+                register_if_missing: Some(false),
+            },
+            false,
+        )
+        .await
+    }
+
+    pub(crate) async fn disconnect(&mut self) -> Result<()> {
+        self.event_server = None;
+        if let Some(sdk) = self.sdk.take() {
+            sdk.disconnect().await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sdk(&self) -> Result<Arc<BreezServices>> {
         self.sdk
             .as_ref()
             .ok_or(anyhow!("Breez Services not initialized"))
             .cloned()
     }
+
+    /// The given amount, or else the amount entered by the user
+    fn amount_msat(
+        &self,
+        rl: &mut Editor<CliHelper, DefaultHistory>,
+        prompt: &str,
+        amount_msat: Option<u64>,
+    ) -> Result<u64> {
+        match (amount_msat, self.interactive) {
+            (Some(amount_msat), _) => Ok(amount_msat),
+            (None, true) => Ok(rl.readline(prompt)?.parse()?),
+            (None, false) => Err(anyhow!("The amount_msat argument is required")),
+        }
+    }
+
+    /// Requests coins from the faucet to the swap address, and waits until they are confirmed
+    async fn request_faucet_coins(&self, amount_sat: Option<u64>) -> Result<SwapInfo> {
+        let config = self.persistence.get_or_create_config()?;
+        let faucet_url = config
+            .faucet_url
+            .clone()
+            .ok_or(anyhow!("No faucet URL, set it with set_faucet_url"))?;
+        let network = config
+            .to_sdk_config(&self.persistence.profile_dir())
+            .network;
+        ensure!(
+            matches!(network, Network::Testnet | Network::Signet),
+            "The faucet is only available on testnet and signet, not {network}"
+        );
+
+        let sdk = self.sdk()?;
+        let address = sdk
+            .receive_onchain(ReceiveOnchainRequest::default())
+            .await?
+            .bitcoin_address;
+        let response = reqwest::Client::new()
+            .post(faucet_url)
+            .json(&json!({ "address": address, "amount_sat": amount_sat }))
+            .send()
+            .await?;
+        let status = response.status();
+        ensure!(
+            status.is_success(),
+            "The faucet request failed with {status}: {}",
+            response.text().await?
+        );
+        if self.interactive {
+            println!("Coins requested to {address}, waiting for them to be confirmed");
+        }
+
+        let start = SystemTime::now();
+        loop {
+            tokio::time::sleep(Duration::from_secs(FAUCET_POLL_INTERVAL_SECS)).await;
+            // Refreshes the in-progress swaps from the chain
+            sdk.in_progress_swap().await?;
+            let swap = sdk
+                .list_swaps(ListSwapsRequest::default())
+                .await?
+                .into_iter()
+                .find(|s| s.bitcoin_address == address);
+            if let Some(swap) = swap.filter(|s| s.confirmed_sats > 0) {
+                return Ok(swap);
+            }
+            ensure!(
+                SystemTime::now().duration_since(start)?.as_secs() < FAUCET_TIMEOUT_SECS,
+                "The faucet coins to {address} were not confirmed in time"
+            );
+        }
+    }
+
+    fn show_duration(&self, start: SystemTime) -> Result<()> {
+        if self.interactive {
+            let diff = SystemTime::now().duration_since(start)?;
+            println!("payment took {}s", diff.as_secs_f32());
+        }
+        Ok(())
+    }
 }
 
 fn build_qr_text(text: &str) -> String {
@@ -1,11 +1,55 @@
-use breez_sdk_core::{BuyBitcoinProvider, EnvironmentType};
-use clap::Parser;
+use breez_sdk_core::{
+    BuyBitcoinProvider, EnvironmentType, ExportFormat, FeeratePreset, FiatRateAlertDirection,
+    Network, PaymentGroupBy,
+};
+use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 pub(crate) struct SdkCli {
     /// Optional data dir, default to current directory
     #[clap(name = "data_dir", short = 'd', long = "data_dir")]
     pub(crate) data_dir: Option<String>,
+
+    /// Optional profile to start with, default to the profile stored in the data dir
+    #[clap(name = "profile", short = 'p', long = "profile")]
+    pub(crate) profile: Option<String>,
+
+    /// Runs the interactive mode if not set
+    #[clap(subcommand)]
+    pub(crate) mode: Option<Mode>,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum Mode {
+    /// Run a single command, print its result as JSON to stdout and exit.
+    ///
+    /// The node is connected first if the command needs it. The exit code is 0 on success,
+    /// 1 if the command failed, 2 if it is not a valid command and 3 if the node could not be
+    /// connected.
+    Exec {
+        /// The command and its arguments, as typed in the interactive mode
+        command: String,
+    },
+    /// Connect the node and serve a core subset of its API over gRPC until interrupted.
+    ///
+    /// The service is defined in `src/daemon/proto/breez_sdk.proto`, from which clients can be
+    /// generated for other languages. It covers the node info, sync, payments, message signing
+    /// and fees, the rest of the API is only available through the other modes.
+    Daemon {
+        /// The address to listen on
+        #[clap(default_value = "127.0.0.1:50051")]
+        address: String,
+
+        /// Require this token on each call, as an `authorization: Bearer <token>` header.
+        /// Required when the address is not a loopback one, as anyone reaching it could spend
+        /// the node funds.
+        #[clap(long)]
+        token: Option<String>,
+
+        /// Also serve the SDK metrics over HTTP at `/metrics` on this address, for Prometheus
+        #[clap(long)]
+        metrics_address: Option<String>,
+    },
 }
 
 #[derive(Parser, Debug, Clone, PartialEq)]
@@ -18,9 +62,25 @@ pub(crate) enum Commands {
     },
     /// [config] Set the Environment type
     SetEnv {
-        /// The environment to use (staging|production)        
+        /// The environment to use (staging|production|regtest)
         env: EnvironmentType,
     },
+    /// [config] Set the network, overriding the network of the environment
+    SetNetwork {
+        /// The network to use (bitcoin|testnet|signet|regtest)
+        #[clap(value_parser = parse_network)]
+        network: Network,
+    },
+    /// [config] Set the URL of the faucet used by the faucet command
+    SetFaucetUrl { url: String },
+    /// [config] Create a profile with its own seed, config and working dir, starting with a
+    /// copy of the current config
+    CreateProfile { name: String },
+    /// [config] List the created profiles
+    ListProfiles {},
+    /// [config] Switch to a profile, disconnecting the current node. Switches to the default
+    /// profile if no name is given
+    SwitchProfile { name: Option<String> },
     /// [init] Connect to the sdk services, make it operational
     Connect {
         /// The optional file location containing the greenlight partner certificate
@@ -38,6 +98,40 @@ pub(crate) enum Commands {
         /// Only restore existing nodes
         #[clap(short = 'r', long = "restore_only")]
         restore_only: bool,
+
+        /// Connect an in-memory mock node with this channels balance instead of a Greenlight
+        /// node, for example on regtest
+        #[clap(name = "mock_balance_sat", long = "mock_balance_sat")]
+        mock_balance_sat: Option<u64>,
+    },
+    /// [init] Connect a throwaway wallet with a random seed on a test network, deleted on
+    /// disconnect
+    ConnectEphemeral {
+        /// The optional greenlight invite code
+        #[clap(name = "invite_code", short = 'i', long = "invite_code")]
+        invite_code: Option<String>,
+    },
+    /// [init] Restore an existing node from its seed, and its local data from the backup. The
+    /// seed is saved in the current profile, which can't have another seed already
+    RestoreNode {
+        /// The words of the BIP39 mnemonic of the node
+        #[clap(
+            long,
+            num_args = 1..,
+            conflicts_with = "seed_hex",
+            required_unless_present = "seed_hex"
+        )]
+        mnemonic: Option<Vec<String>>,
+
+        /// The raw seed of the node, in hex, instead of its mnemonic
+        #[clap(long)]
+        seed_hex: Option<String>,
+    },
+    /// [init] Show the mnemonic of the wallet, after a confirmation
+    ShowMnemonic {
+        /// Skip the confirmation, required outside of the interactive mode
+        #[clap(long)]
+        yes: bool,
     },
 
     /// [pay] Send a lightning payment
@@ -51,11 +145,65 @@ pub(crate) enum Commands {
         #[clap(name = "label", short = 'l', long = "label")]
         label: Option<String>,
 
-        /// If use_trampoline is set, trampoline payments will be attempted.
+        /// If use_trampoline is set, trampoline payments will be attempted. Otherwise the
+        /// configured default is used.
         #[clap(long, action)]
         use_trampoline: bool,
+
+        /// The optional index of the invoice route hint to route the payment through
+        #[clap(name = "route_hint_index", short = 'r', long = "route_hint")]
+        route_hint_index: Option<u32>,
+
+        /// How long the payment is attempted for, instead of the configured timeout
+        #[clap(name = "payment_timeout_sec", short = 't', long = "timeout")]
+        payment_timeout_sec: Option<u32>,
+
+        /// The max fee, as a percentage of the amount, instead of the configured one
+        #[clap(name = "maxfee_percent", long = "maxfee_percent")]
+        maxfee_percent: Option<f64>,
+
+        /// The fee below which the max fee percentage is not enforced
+        #[clap(name = "exemptfee_msat", long = "exemptfee_msat")]
+        exemptfee_msat: Option<u64>,
+
+        /// Pays even if the destination is in the denylist
+        #[clap(long, action)]
+        skip_denylist: bool,
+    },
+
+    /// [pay] Pay several invoices, a few at a time
+    SendPayments {
+        /// How many payments are sent at the same time
+        #[clap(name = "max_concurrency", short = 'c', long = "concurrency")]
+        max_concurrency: Option<u32>,
+
+        #[clap(required = true)]
+        bolt11: Vec<String>,
+    },
+
+    /// [pay] Queue a payment, retried in the background until it succeeds or expires
+    QueuePayment {
+        bolt11: String,
+
+        /// The amount to pay, required for zero-amount invoices
+        #[clap(name = "amount_msat", short = 'a', long = "amount_msat")]
+        amount_msat: Option<u64>,
+
+        /// The external label or identifier of the payment
+        #[clap(name = "label", short = 'l', long = "label")]
+        label: Option<String>,
+
+        /// How long the payment is retried before it is abandoned
+        #[clap(name = "expiry_secs", short = 'e', long = "expiry")]
+        expiry_secs: Option<u64>,
     },
 
+    /// [pay] List the queued payments
+    ListOutbox {},
+
+    /// [pay] Remove a payment from the queue
+    CancelOutboxPayment { id: i64 },
+
     /// [pay] Send a spontaneous (keysend) payment
     SendSpontaneousPayment {
         node_id: String,
@@ -66,6 +214,52 @@ pub(crate) enum Commands {
         label: Option<String>,
     },
 
+    /// [pay] Check whether a payment to an invoice or a node id would succeed, without paying
+    ProbePayment {
+        /// A bolt11 invoice or a node id
+        destination: String,
+
+        /// The amount to probe, required for node ids and invoices without an amount
+        #[clap(name = "amount_msat", short = 'a', long = "amt")]
+        amount_msat: Option<u64>,
+    },
+
+    /// [pay] List the payment probes
+    ListPaymentProbes {},
+
+    /// [pay] Check whether an invoice can be paid, without probing the routes
+    ValidatePayability {
+        bolt11: String,
+
+        /// The amount to pay, only for invoices without an amount
+        #[clap(name = "amount_msat", short = 'a', long = "amt")]
+        amount_msat: Option<u64>,
+    },
+
+    /// [pay] Pay a BOLT12 offer
+    PayOffer {
+        offer: String,
+
+        /// The amount to pay, required when the offer has no amount
+        #[clap(name = "amount_msat", short = 'a', long = "amt")]
+        amount_msat: Option<u64>,
+
+        /// A note for the payee, included in the invoice request
+        #[clap(name = "payer_note", short = 'n', long = "note")]
+        payer_note: Option<String>,
+
+        /// The external label or identifier of the payment
+        #[clap(name = "label", short = 'l', long = "label")]
+        label: Option<String>,
+    },
+
+    /// [pay] Show the channel opening fee deducted when receiving the amount
+    PrepareReceivePayment {
+        amount_msat: u64,
+        #[clap(name = "expiry", short = 'e', long = "expiry")]
+        expiry: Option<u32>,
+    },
+
     /// [pay] Generate a bolt11 invoice
     ReceivePayment {
         amount_msat: u64,
@@ -76,6 +270,22 @@ pub(crate) enum Commands {
         expiry: Option<u32>,
         #[clap(name = "cltv", short = 'c', long = "cltv")]
         cltv: Option<u32>,
+        /// Include a new onchain address of the node as fallback in the invoice
+        #[clap(name = "fallback_address", short = 'f', long = "fallback_address")]
+        include_fallback_address: bool,
+        /// Convert the amount from this fiat amount instead, ignoring amount_msat
+        #[clap(name = "fiat_amount", long = "fiat_amount")]
+        fiat_amount: Option<f64>,
+        /// The currency of the fiat amount
+        #[clap(name = "fiat_currency", long = "fiat_currency", default_value = "USD")]
+        fiat_currency: String,
+    },
+
+    /// [pay] Generate a BIP21 URI that can be paid either onchain or over lightning
+    ReceiveUnified {
+        amount_sat: u64,
+        /// The invoice description, also used as label
+        description: String,
     },
 
     /// [pay] List recommended fees based on the mempool
@@ -105,23 +315,67 @@ pub(crate) enum Commands {
         /// If use_trampoline is set, trampoline payments will be attempted.
         #[clap(long, action)]
         use_trampoline: bool,
+
+        /// The amount to pay, prompted for if not set
+        #[clap(name = "amount_msat", short = 'a', long = "amount_msat")]
+        amount_msat: Option<u64>,
+
+        /// Pays even if the destination is in the denylist
+        #[clap(long, action)]
+        skip_denylist: bool,
     },
 
     /// [lnurl] Withdraw using lnurl withdraw
-    LnurlWithdraw { lnurl: String },
+    LnurlWithdraw {
+        lnurl: String,
+
+        /// The amount to withdraw, prompted for if not set
+        #[clap(name = "amount_msat", short = 'a', long = "amount_msat")]
+        amount_msat: Option<u64>,
+    },
 
     /// [lnurl] Authenticate using lnurl auth
     LnurlAuth { lnurl: String },
 
+    /// [lnurl] List the domains authenticated to with lnurl auth
+    ListLnurlAuthDomains {},
+
+    /// [lnurl] Show the lnurl auth linking key of a domain
+    LnurlAuthLinkingKey { domain: String },
+
+    /// [lnurl] Export the lnurl auth identity and domain history to a file
+    ExportLnurlAuth { path: String },
+
+    /// [lnurl] Import an lnurl auth domain history exported from the same seed
+    ImportLnurlAuth { path: String },
+
     /// [swap-in] Generate address to receive onchain
     ReceiveOnchain {},
 
     /// [swap-in] Get the current in-progress swap if exists
     InProgressSwap {},
 
+    /// [swap-in] Request coins from the configured faucet to the swap address and wait for
+    /// them to confirm. Only available on testnet and signet.
+    Faucet {
+        /// The amount to request, default to the faucet amount
+        #[clap(name = "amount_sat", short = 'a', long = "amount_sat")]
+        amount_sat: Option<u64>,
+    },
+
+    /// [swap-in] Verify that a swap address was derived from the swap keys
+    VerifySwapAddress { swap_address: String },
+
     /// [swap-in] List refundable swap addresses
     ListRefundables {},
 
+    /// [swap-in] Export the swap refund keys derived from the seed, for recovery tools
+    ExportSwapRefundKeys {
+        /// Also export the unused keys following the last used one
+        #[clap(long, action)]
+        include_gap_keys: bool,
+    },
+
     /// [swap-in] Rescan all swaps
     RescanSwaps {},
 
@@ -131,6 +385,9 @@ pub(crate) enum Commands {
         to_address: String,
         sat_per_vbyte: u32,
         unilateral: Option<bool>,
+        /// Also show the fees at the economy, regular and priority feerates
+        #[clap(long = "fee_previews")]
+        fee_previews: bool,
     },
 
     /// [swap-in] Broadcast a refund transaction for an incomplete swap
@@ -196,9 +453,29 @@ pub(crate) enum Commands {
         recipient_address: String,
     },
 
+    /// [swap-out-v2] Pays an on-chain address or BIP21 URI in one step
+    SendOnchain {
+        /// The amount the recipient receives. Can be omitted if the BIP21 URI has one.
+        #[clap(name = "amount_sat", short = 'a', long = "amt")]
+        amount_sat: Option<u64>,
+
+        /// Send as much as the channels allow
+        #[clap(name = "drain", long = "drain")]
+        drain: bool,
+
+        /// The claim tx feerate preset: economy, regular or priority
+        #[clap(name = "fee", short = 'f', long = "fee", value_parser = parse_feerate_preset)]
+        fee_preference: Option<FeeratePreset>,
+
+        address: String,
+    },
+
     /// [sign] Sign a message with the node's private key
     SignMessage { message: String },
 
+    /// [sign] Sign a message with the key of an onchain wallet address (BIP-322)
+    ProveAddressOwnership { address: String, message: String },
+
     /// [sign] Verify a message with a node's public key
     CheckMessage {
         message: String,
@@ -213,6 +490,19 @@ pub(crate) enum Commands {
 
         /// The fee rate for the redeem_onchain_funds transaction
         sat_per_vbyte: u32,
+
+        /// The amount to send, all the on-chain funds if not set
+        #[clap(name = "amount_sat", short = 'a', long = "amount_sat")]
+        amount_sat: Option<u64>,
+    },
+
+    /// [redeem] Replace a stuck sweep or refund transaction by one paying a higher fee rate
+    BumpFee {
+        /// The txid of the transaction to replace
+        txid: String,
+
+        /// The new fee rate in sat/vbyte
+        sat_per_vbyte: u32,
     },
 
     /// [redeem] Calculate the fee (in sats) for a potential transaction
@@ -222,6 +512,10 @@ pub(crate) enum Commands {
 
         /// The fee rate for the transaction in vbyte/sats
         sat_per_vbyte: u32,
+
+        /// The amount to send, all the on-chain funds if not set
+        #[clap(name = "amount_sat", short = 'a', long = "amount_sat")]
+        amount_sat: Option<u64>,
     },
 
     /// [lsp] The up to date lsp information
@@ -242,27 +536,139 @@ pub(crate) enum Commands {
     /// [support] Fetches the service health check
     ServiceHealthCheck {},
 
+    /// [support] Show the SDK version and the protocols it supports
+    SdkInfo {},
+
     /// [support] Send a payment failure report
     ReportPaymentFailure {
         payment_hash: String,
         comment: Option<String>,
     },
 
+    /// [support] Ping the LSP peer and show the reconnections to it
+    PeerConnectivity {},
+
+    /// [support] Show whether the node and the LSP peer are reachable
+    ConnectionStatus {},
+
+    /// [support] Show the health of the background tasks and their restarts after a failure
+    TaskStatus {},
+
+    /// [support] Write a redacted snapshot of the wallet state to a file
+    DiagnosticSnapshot { path: String },
+
+    /// [support] Show the differences between two snapshot files
+    DiffSnapshots { before: String, after: String },
+
+    /// [support] Show the counters and histograms of the SDK operations
+    Metrics {
+        /// Print them in the Prometheus text format instead of JSON
+        #[clap(long, action)]
+        prometheus: bool,
+    },
+
     /// [node-mgmt] Sync local data with remote node
-    Sync {},
+    Sync {
+        /// Only refresh the balance and the recent payments
+        #[clap(long, action)]
+        partial: bool,
+    },
 
     /// [node-mgmt] Triggers a backup of the local data
     Backup {},
 
+    /// [node-mgmt] Encrypts the backups with the next key derived from the seed from now on
+    RotateBackupKey {},
+
+    /// [node-mgmt] Lists the versions of the stored backup snapshots
+    ListBackupVersions {},
+
+    /// [node-mgmt] Merges a backup snapshot into the local data
+    RestoreBackup {
+        /// The version to restore, as listed by list-backup-versions
+        version: u64,
+    },
+
+    /// [node-mgmt] Show the user settings
+    UserSettings {},
+
+    /// [node-mgmt] Replace the user settings
+    SetUserSettings {
+        /// The settings as JSON, for example '{"preferred_fiat_currency":"EUR","denomination":"Sat"}'.
+        /// Omitted settings are cleared.
+        settings: String,
+    },
+
     /// [node-mgmt] Fetch the static backup data
     StaticBackup {},
 
+    /// [node-mgmt] Export the node state needed to migrate from Greenlight to a self-hosted CLN node
+    ExportNodeMigration {
+        /// Cooperatively close the LSP channels as part of the export
+        #[clap(short = 'c', long = "close_channels")]
+        close_channels: bool,
+    },
+
+    /// [node-mgmt] Export a recovery bundle encrypted to someone else's key, for example an heir
+    ExportRecoveryBundle {
+        /// The hex encoded public key of the recipient
+        recipient_pubkey: String,
+
+        /// The file the bundle is written to, and written again after channel changes
+        path: String,
+
+        /// Instructions for the recipient
+        #[clap(short = 'i', long = "instructions")]
+        instructions: Option<String>,
+    },
+
+    /// [node-mgmt] Stop refreshing the recovery bundle file
+    StopRecoveryBundleRefresh {},
+
     /// [node-mgmt] Parse a generic string to get its type and relevant metadata
     Parse {
         /// Generic input (URL, LNURL, BIP-21 BTC Address, LN invoice, etc)
         input: String,
     },
 
+    /// [node-mgmt] Decode QR codes and parse their content, as [Commands::Parse] does
+    ScanQr {
+        /// Images of the QR codes, decoded with `zbarimg`. The frames of an animated UR or
+        /// BBQr code can be given as several images.
+        #[clap(name = "path", short = 'p', long = "path")]
+        paths: Vec<String>,
+
+        /// The scanned content of a QR code, such as a part of an animated UR or BBQr code
+        #[clap(name = "part", long = "part")]
+        parts: Vec<String>,
+    },
+
+    /// [node-mgmt] Verify the signature and network of several invoices
+    VerifyInvoices {
+        #[clap(required = true)]
+        bolt11: Vec<String>,
+    },
+
+    /// [node-mgmt] Encrypt a payment request for the other apps of this wallet
+    EncryptPaymentRequest {
+        /// The invoice to pay
+        invoice: String,
+
+        /// Optional on-chain address to pay if the invoice can't be paid
+        #[clap(name = "fallback", short = 'f', long = "fallback")]
+        fallback: Option<String>,
+
+        /// Optional app specific data
+        #[clap(name = "metadata", short = 'm', long = "metadata")]
+        metadata: Option<String>,
+    },
+
+    /// [node-mgmt] Decrypt a payment request encrypted by another app of this wallet
+    DecryptPaymentRequest {
+        /// The encrypted payment request, as returned by encrypt_payment_request
+        input: String,
+    },
+
     /// [node-mgmt] List all payments
     ListPayments {
         /// The optional from unix timestamp
@@ -285,9 +691,59 @@ pub(crate) enum Commands {
         #[clap(short = 'o', long = "offset")]
         offset: Option<u32>,
 
-        /// Optional metadata filter, in the form of json_path:json_value
+        /// Optional metadata filter, in the form of json_path:json_value, or json_path to
+        /// only require the field to be present
         #[clap(short = 'm', long = "metadata", num_args = 1..)]
         metadata_filters: Option<Vec<String>>,
+
+        /// Include the raw values of the custom TLV records of received payments
+        #[clap(long = "include_tlv_hex")]
+        include_tlv_hex: bool,
+
+        /// Only list the received keysend payments
+        #[clap(long = "spontaneous")]
+        spontaneous: bool,
+
+        /// List the oldest payments first
+        #[clap(long = "ascending")]
+        ascending: bool,
+
+        /// Instead of the payments, list the totals of the completed sent payments per
+        /// counterparty (destination_pubkey|ln_address), the largest first
+        #[clap(long = "group_by")]
+        group_by: Option<PaymentGroupBy>,
+    },
+
+    /// [node-mgmt] Export the completed payments to a file
+    ExportPayments {
+        /// The file to write the payments to
+        path: std::path::PathBuf,
+
+        /// The file format (csv|json)
+        #[clap(short = 'f', long = "format", default_value = "csv")]
+        format: ExportFormat,
+
+        /// The optional from unix timestamp
+        #[clap(name = "from_timestamp", long = "from")]
+        from_timestamp: Option<i64>,
+
+        /// The optional to unix timestamp
+        #[clap(name = "to_timestamp", long = "to")]
+        to_timestamp: Option<i64>,
+
+        /// The optional fiat currency, for example USD, to also export the amounts in
+        #[clap(long = "fiat")]
+        fiat: Option<String>,
+    },
+
+    /// [node-mgmt] Import payments made outside of this node from a file in the export format
+    ImportPayments {
+        /// The file to read the payments from
+        path: std::path::PathBuf,
+
+        /// The file format (csv|json)
+        #[clap(short = 'f', long = "format", default_value = "csv")]
+        format: ExportFormat,
     },
 
     /// [node-mgmt] Set the metadata for a given payment
@@ -299,12 +755,57 @@ pub(crate) enum Commands {
     /// [node-mgmt] Retrieve a payment by its hash
     PaymentByHash { hash: String },
 
+    /// [node-mgmt] List the channel opening fees charged by the LSP for received payments
+    ListChannelFees {},
+
     /// [node-mgmt] The node credentials
     NodeCredentials {},
 
     /// [node-mgmt] The up to date node information
     NodeInfo {},
 
+    /// [node-mgmt] The funds of the node, split by state
+    Balances {},
+
+    /// [node-mgmt] The spendable amounts, reserves and HTLCs of the channels, and the pending funds
+    BalanceBreakdown {},
+
+    /// [node-mgmt] List the channels that are not yet closed
+    ListChannels {},
+
+    /// [node-mgmt] Connect to a peer other than the LSP
+    ConnectPeer {
+        /// The peer, as <node id>@<host>:<port>
+        node_uri: String,
+    },
+
+    /// [node-mgmt] Open a channel to a connected peer with onchain funds
+    OpenChannel {
+        /// The node id of the peer
+        node_id: String,
+
+        /// The channel capacity
+        amount_sat: u64,
+
+        /// Announce the channel to the network
+        #[clap(long, action)]
+        announce: bool,
+
+        /// The feerate of the funding tx, estimated by the node if not set
+        #[clap(name = "sat_per_vbyte", short = 'f', long = "fee_rate")]
+        sat_per_vbyte: Option<u32>,
+    },
+
+    /// [node-mgmt] Close a single channel
+    CloseChannel {
+        /// The id of the channel to close
+        channel_id: String,
+
+        /// Close the channel unilaterally if the peer doesn't agree to a mutual close
+        #[clap(long, action)]
+        force: bool,
+    },
+
     /// [node-mgmt] Configure the node
     ConfigureNode {
         // Optional address to send funds to during a mutual channel close
@@ -315,27 +816,180 @@ pub(crate) enum Commands {
     /// [node-mgmt] Stop the node and disconnect from the sdk services
     Disconnect {},
 
+    /// [node-mgmt] Stream the SDK events as JSON to the websocket clients connecting to the
+    /// address, until disconnected
+    ServeEvents {
+        #[clap(default_value = "127.0.0.1:8765")]
+        address: String,
+    },
+
     /// [node-mgmt] Register a webhook URL, where the SDK will trigger a callback on specific events.
     RegisterWebhook { url: String },
 
     /// [node-mgmt] Unregister a webhook URL.
     UnregisterWebhook { url: String },
 
+    /// [lnurl] Register the lightning address <username>@domain, served through the webhook
+    RegisterLightningAddress { username: String },
+
+    /// [lnurl] Show the registered lightning address and LNURL-pay code
+    LnurlPayInfo {},
+
+    /// [lnurl] Set up a static LNURL-pay endpoint hosted at <url>, for a printed QR code
+    CreateStaticLnurlPay {
+        /// The URL the endpoint is hosted at
+        url: String,
+
+        /// The URL the payers request the invoices from
+        callback_url: String,
+
+        /// The description shown to the payers
+        description: String,
+
+        /// The max amount a payer can send
+        max_sendable_msat: u64,
+
+        /// The min amount a payer can send
+        #[clap(long, default_value = "1000")]
+        min_sendable_msat: u64,
+
+        /// The max length of the comment of the payers, no comments if not set
+        #[clap(long)]
+        comment_allowed: Option<u16>,
+    },
+
+    /// [lnurl] Answer a request to the callback URL of the static LNURL-pay endpoint
+    StaticLnurlPayCallback {
+        amount_msat: u64,
+
+        #[clap(long)]
+        comment: Option<String>,
+    },
+
+    /// [lnurl] Create an LNURL-withdraw voucher paid from the balance, redeemed through the webhook
+    CreateLnurlWithdrawVoucher {
+        /// The amount that can be withdrawn on each use
+        amount_msat: u64,
+
+        /// How many times the voucher can be redeemed
+        #[clap(long, default_value = "1")]
+        uses: u32,
+
+        /// How long the voucher can be redeemed for, in seconds
+        #[clap(long, default_value = "86400")]
+        expiry_secs: u32,
+
+        #[clap(long)]
+        description: Option<String>,
+    },
+
+    /// [lnurl] Pay the invoice of the claimant of a voucher
+    RedeemLnurlWithdrawVoucher { id: String, bolt11: String },
+
+    /// [lnurl] List the created LNURL-withdraw vouchers
+    ListLnurlWithdrawVouchers {},
+
     /// [buy] Generates an URL to buy bitcoin from a 3rd party provider
     BuyBitcoin { provider: BuyBitcoinProvider },
 
+    /// [buy] Fetch how many sats a fiat amount buys at a 3rd party provider, fees included
+    FetchBuyBitcoinQuote {
+        provider: BuyBitcoinProvider,
+
+        /// The fiat currency code, for example USD
+        currency: String,
+
+        /// The fiat amount to pay
+        amount: f64,
+    },
+
     /// [fiat] List fiat currencies
     ListFiat {},
 
     /// [fiat] Fetch available fiat rates
     FetchFiatRates {},
 
+    /// [fiat] Add a price alert triggered when the rate of one BTC crosses the threshold
+    AddFiatRateAlert {
+        /// The fiat currency code, for example USD
+        currency: String,
+
+        /// The direction the rate crosses the threshold: above or below
+        #[clap(value_parser = parse_fiat_rate_alert_direction)]
+        direction: FiatRateAlertDirection,
+
+        threshold: f64,
+    },
+
+    /// [fiat] List the price alerts
+    ListFiatRateAlerts {},
+
+    /// [fiat] Remove a price alert
+    RemoveFiatRateAlert { id: i64 },
+
     /// [dev] Execute a low level node command (used for debugging)
     ExecuteDevCommand { command: String },
 
     /// [dev] Generates and retrieves a diagnostic data report from the sdk services (used for debugging)
     GenerateDiagnosticData {},
 
+    /// [dev] Change the log filter, for example "breez_sdk_core=trace,gl_client=debug"
+    SetLogFilter { filter: String },
+
     /// [dev] Immediately abort the program
     Abort {},
 }
+
+impl Commands {
+    /// Whether the command needs the node to be connected
+    pub(crate) fn requires_connection(&self) -> bool {
+        !matches!(
+            self,
+            Commands::SetAPIKey { .. }
+                | Commands::SetEnv { .. }
+                | Commands::SetNetwork { .. }
+                | Commands::SetFaucetUrl { .. }
+                | Commands::CreateProfile { .. }
+                | Commands::ListProfiles {}
+                | Commands::SwitchProfile { .. }
+                | Commands::Connect { .. }
+                | Commands::ConnectEphemeral { .. }
+                | Commands::RestoreNode { .. }
+                | Commands::ShowMnemonic { .. }
+                | Commands::Parse { .. }
+                | Commands::ScanQr { .. }
+                | Commands::ServiceHealthCheck {}
+                | Commands::SdkInfo {}
+                | Commands::StaticBackup {}
+                | Commands::SetLogFilter { .. }
+                | Commands::Abort {}
+        )
+    }
+}
+
+fn parse_network(network: &str) -> Result<Network, String> {
+    match network {
+        "bitcoin" => Ok(Network::Bitcoin),
+        "testnet" => Ok(Network::Testnet),
+        "signet" => Ok(Network::Signet),
+        "regtest" => Ok(Network::Regtest),
+        _ => Err(format!("Unknown network: {network}")),
+    }
+}
+
+fn parse_feerate_preset(preset: &str) -> Result<FeeratePreset, String> {
+    match preset {
+        "economy" => Ok(FeeratePreset::Economy),
+        "regular" => Ok(FeeratePreset::Regular),
+        "priority" => Ok(FeeratePreset::Priority),
+        _ => Err(format!("Unknown feerate preset: {preset}")),
+    }
+}
+
+fn parse_fiat_rate_alert_direction(direction: &str) -> Result<FiatRateAlertDirection, String> {
+    match direction {
+        "above" => Ok(FiatRateAlertDirection::Above),
+        "below" => Ok(FiatRateAlertDirection::Below),
+        _ => Err(format!("Unknown direction: {direction}")),
+    }
+}
@@ -1,10 +1,16 @@
-use breez_sdk_core::{BreezServices, Config, EnvironmentType, GreenlightNodeConfig};
+use breez_sdk_core::{BreezServices, Config, EnvironmentType, GreenlightNodeConfig, Network};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct CliConfig {
     pub(crate) api_key: Option<String>,
     pub(crate) env: EnvironmentType,
+    /// Overrides the network of the environment
+    #[serde(default)]
+    pub(crate) network: Option<Network>,
+    /// The faucet the `faucet` command requests coins from
+    #[serde(default)]
+    pub(crate) faucet_url: Option<String>,
 }
 
 impl Default for CliConfig {
@@ -12,6 +18,8 @@ impl Default for CliConfig {
         CliConfig {
             api_key: None,
             env: EnvironmentType::Production,
+            network: None,
+            faucet_url: None,
         }
     }
 }
@@ -29,6 +37,9 @@ impl CliConfig {
             },
         );
         config.working_dir = data_dir.to_string();
+        if let Some(network) = self.network {
+            config.network = network;
+        }
         config
     }
 }
@@ -0,0 +1,303 @@
+//! Conversions between the SDK structs and the messages of the daemon API
+
+use breez_sdk_core::error::{ReceivePaymentError, SdkError, SendPaymentError};
+use breez_sdk_core::MilliSat;
+use tonic::Status;
+
+use super::proto;
+use super::proto::breez_event::Event;
+
+impl From<breez_sdk_core::NodeState> for proto::NodeState {
+    fn from(state: breez_sdk_core::NodeState) -> Self {
+        Self {
+            id: state.id,
+            block_height: state.block_height,
+            channels_balance_msat: state.channels_balance_msat,
+            onchain_balance_msat: state.onchain_balance_msat,
+            pending_onchain_balance_msat: state.pending_onchain_balance_msat,
+            max_payable_msat: state.max_payable_msat,
+            max_receivable_msat: state.max_receivable_msat,
+            max_single_payment_amount_msat: state.max_single_payment_amount_msat,
+            max_chan_reserve_msats: state.max_chan_reserve_msats,
+            connected_peers: state.connected_peers,
+            max_receivable_single_payment_amount_msat: state
+                .max_receivable_single_payment_amount_msat,
+            total_inbound_liquidity_msats: state.total_inbound_liquidity_msats,
+        }
+    }
+}
+
+impl From<breez_sdk_core::Payment> for proto::Payment {
+    fn from(payment: breez_sdk_core::Payment) -> Self {
+        let payment_type = match payment.payment_type {
+            breez_sdk_core::PaymentType::Sent => proto::PaymentType::Sent,
+            breez_sdk_core::PaymentType::Received => proto::PaymentType::Received,
+            breez_sdk_core::PaymentType::ClosedChannel => proto::PaymentType::ClosedChannel,
+        };
+        let status = match payment.status {
+            breez_sdk_core::PaymentStatus::Pending => proto::PaymentStatus::Pending,
+            breez_sdk_core::PaymentStatus::Complete => proto::PaymentStatus::Complete,
+            breez_sdk_core::PaymentStatus::Failed => proto::PaymentStatus::Failed,
+        };
+        let details = match payment.details {
+            breez_sdk_core::PaymentDetails::Ln { data } => {
+                proto::payment::Details::Ln(proto::LnPaymentDetails {
+                    payment_hash: data.payment_hash,
+                    label: data.label,
+                    destination_pubkey: data.destination_pubkey,
+                    payment_preimage: data.payment_preimage,
+                    keysend: data.keysend,
+                    bolt11: data.bolt11,
+                    open_channel_bolt11: data.open_channel_bolt11,
+                    channel_opening_fee_msat: data.channel_opening_fee_msat,
+                    lnurl_pay_domain: data.lnurl_pay_domain,
+                    lnurl_pay_comment: data.lnurl_pay_comment,
+                    ln_address: data.ln_address,
+                    lnurl_metadata: data.lnurl_metadata,
+                    lnurl_withdraw_endpoint: data.lnurl_withdraw_endpoint,
+                    pending_expiration_block: data.pending_expiration_block,
+                })
+            }
+            breez_sdk_core::PaymentDetails::ClosedChannel { data } => {
+                let state = match data.state {
+                    breez_sdk_core::ChannelState::PendingOpen => proto::ChannelState::PendingOpen,
+                    breez_sdk_core::ChannelState::Opened => proto::ChannelState::Opened,
+                    breez_sdk_core::ChannelState::PendingClose => proto::ChannelState::PendingClose,
+                    breez_sdk_core::ChannelState::Closed => proto::ChannelState::Closed,
+                };
+                proto::payment::Details::ClosedChannel(proto::ClosedChannelPaymentDetails {
+                    state: state as i32,
+                    funding_txid: data.funding_txid,
+                    short_channel_id: data.short_channel_id,
+                    closing_txid: data.closing_txid,
+                })
+            }
+        };
+        Self {
+            id: payment.id,
+            payment_type: payment_type as i32,
+            payment_time: payment.payment_time,
+            amount_msat: payment.amount_msat,
+            fee_msat: payment.fee_msat,
+            status: status as i32,
+            error: payment.error,
+            description: payment.description,
+            details: Some(details),
+            metadata: payment.metadata,
+            fiat_rate: payment.fiat_rate.map(|rate| proto::Rate {
+                coin: rate.coin,
+                value: rate.value,
+            }),
+            imported: payment.imported,
+        }
+    }
+}
+
+impl From<breez_sdk_core::LNInvoice> for proto::LnInvoice {
+    fn from(invoice: breez_sdk_core::LNInvoice) -> Self {
+        Self {
+            bolt11: invoice.bolt11,
+            payee_pubkey: invoice.payee_pubkey,
+            payment_hash: invoice.payment_hash,
+            description: invoice.description,
+            description_hash: invoice.description_hash,
+            amount_msat: invoice.amount_msat,
+            timestamp: invoice.timestamp,
+            expiry: invoice.expiry,
+            min_final_cltv_expiry_delta: invoice.min_final_cltv_expiry_delta,
+        }
+    }
+}
+
+impl From<proto::ListPaymentsRequest> for breez_sdk_core::ListPaymentsRequest {
+    fn from(req: proto::ListPaymentsRequest) -> Self {
+        let filters: Vec<breez_sdk_core::PaymentTypeFilter> = req
+            .filters()
+            .map(|filter| match filter {
+                proto::PaymentTypeFilter::Sent => breez_sdk_core::PaymentTypeFilter::Sent,
+                proto::PaymentTypeFilter::Received => breez_sdk_core::PaymentTypeFilter::Received,
+                proto::PaymentTypeFilter::ClosedChannel => {
+                    breez_sdk_core::PaymentTypeFilter::ClosedChannel
+                }
+                proto::PaymentTypeFilter::SpontaneousReceived => {
+                    breez_sdk_core::PaymentTypeFilter::SpontaneousReceived
+                }
+            })
+            .collect();
+        let sort_order = match req.sort_order() {
+            proto::SortOrder::Descending => breez_sdk_core::SortOrder::Descending,
+            proto::SortOrder::Ascending => breez_sdk_core::SortOrder::Ascending,
+        };
+        Self {
+            filters: (!filters.is_empty()).then_some(filters),
+            from_timestamp: req.from_timestamp,
+            to_timestamp: req.to_timestamp,
+            include_failures: req.include_failures,
+            offset: req.offset,
+            limit: req.limit,
+            sort_order: Some(sort_order),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<proto::ReceivePaymentRequest> for breez_sdk_core::ReceivePaymentRequest {
+    fn from(req: proto::ReceivePaymentRequest) -> Self {
+        Self {
+            amount_msat: MilliSat(req.amount_msat),
+            description: req.description,
+            preimage: req.preimage,
+            opening_fee_params: None,
+            use_description_hash: req.use_description_hash,
+            expiry: req.expiry,
+            cltv: req.cltv,
+            include_fallback_address: req.include_fallback_address,
+            max_route_hints: req.max_route_hints,
+            fiat_amount: None,
+        }
+    }
+}
+
+impl From<breez_sdk_core::ReceivePaymentResponse> for proto::ReceivePaymentResponse {
+    fn from(res: breez_sdk_core::ReceivePaymentResponse) -> Self {
+        Self {
+            ln_invoice: Some(res.ln_invoice.into()),
+            opening_fee_msat: res.opening_fee_msat.map(MilliSat::msat),
+            bolt11_length: res.bolt11_length,
+        }
+    }
+}
+
+impl From<proto::SendPaymentRequest> for breez_sdk_core::SendPaymentRequest {
+    fn from(req: proto::SendPaymentRequest) -> Self {
+        Self {
+            bolt11: req.bolt11,
+            use_trampoline: req.use_trampoline,
+            amount_msat: req.amount_msat.map(MilliSat),
+            label: req.label,
+            route_hint_index: req.route_hint_index,
+            payment_timeout_sec: req.payment_timeout_sec,
+            maxfee_percent: req.maxfee_percent,
+            exemptfee_msat: req.exemptfee_msat.map(MilliSat),
+            cancel_token: None,
+            skip_denylist: None,
+        }
+    }
+}
+
+impl From<proto::SendSpontaneousPaymentRequest> for breez_sdk_core::SendSpontaneousPaymentRequest {
+    fn from(req: proto::SendSpontaneousPaymentRequest) -> Self {
+        let extra_tlvs: Vec<breez_sdk_core::TlvEntry> = req
+            .extra_tlvs
+            .into_iter()
+            .map(|tlv| breez_sdk_core::TlvEntry {
+                field_number: tlv.field_number,
+                value: tlv.value,
+            })
+            .collect();
+        Self {
+            node_id: req.node_id,
+            amount_msat: MilliSat(req.amount_msat),
+            extra_tlvs: (!extra_tlvs.is_empty()).then_some(extra_tlvs),
+            label: req.label,
+        }
+    }
+}
+
+impl From<breez_sdk_core::SendPaymentResponse> for proto::SendPaymentResponse {
+    fn from(res: breez_sdk_core::SendPaymentResponse) -> Self {
+        Self {
+            payment: Some(res.payment.into()),
+        }
+    }
+}
+
+impl From<breez_sdk_core::RecommendedFees> for proto::RecommendedFees {
+    fn from(fees: breez_sdk_core::RecommendedFees) -> Self {
+        Self {
+            fastest_fee: fees.fastest_fee,
+            half_hour_fee: fees.half_hour_fee,
+            hour_fee: fees.hour_fee,
+            economy_fee: fees.economy_fee,
+            minimum_fee: fees.minimum_fee,
+        }
+    }
+}
+
+impl From<breez_sdk_core::BreezEvent> for proto::BreezEvent {
+    fn from(event: breez_sdk_core::BreezEvent) -> Self {
+        let event = match event {
+            breez_sdk_core::BreezEvent::NewBlock { block } => {
+                Event::NewBlock(proto::NewBlock { block })
+            }
+            breez_sdk_core::BreezEvent::InvoicePaid { details } => {
+                Event::InvoicePaid(proto::InvoicePaid {
+                    payment_hash: details.payment_hash,
+                    bolt11: details.bolt11,
+                    payment: details.payment.map(Into::into),
+                })
+            }
+            breez_sdk_core::BreezEvent::Synced => Event::Synced(proto::Synced {}),
+            breez_sdk_core::BreezEvent::PaymentSucceed { details } => {
+                Event::PaymentSucceed(proto::PaymentSucceed {
+                    details: Some(details.into()),
+                })
+            }
+            breez_sdk_core::BreezEvent::PaymentFailed { details } => {
+                Event::PaymentFailed(proto::PaymentFailed {
+                    error: details.error,
+                    node_id: details.node_id,
+                    invoice: details.invoice.map(Into::into),
+                    label: details.label,
+                })
+            }
+            event => {
+                let json = serde_json::to_value(&event).unwrap_or_default();
+                Event::Other(proto::OtherEvent {
+                    r#type: json["type"].as_str().unwrap_or_default().to_string(),
+                    json: json.to_string(),
+                })
+            }
+        };
+        Self { event: Some(event) }
+    }
+}
+
+pub(super) fn sdk_error_status(err: SdkError) -> Status {
+    match err {
+        SdkError::ServiceConnectivity { err } => Status::unavailable(err),
+        SdkError::Generic { err } => Status::internal(err),
+    }
+}
+
+pub(super) fn send_payment_error_status(err: SendPaymentError) -> Status {
+    let message = err.to_string();
+    match err {
+        SendPaymentError::AlreadyPaid | SendPaymentError::AlreadyInProgress => {
+            Status::already_exists(message)
+        }
+        SendPaymentError::InvalidAmount { .. }
+        | SendPaymentError::InvalidInvoice { .. }
+        | SendPaymentError::InvalidNetwork { .. } => Status::invalid_argument(message),
+        SendPaymentError::InvoiceExpired { .. } | SendPaymentError::InsufficientBalance { .. } => {
+            Status::failed_precondition(message)
+        }
+        SendPaymentError::PaymentTimeout { .. } => Status::deadline_exceeded(message),
+        SendPaymentError::ServiceConnectivity { .. } => Status::unavailable(message),
+        SendPaymentError::Cancelled => Status::cancelled(message),
+        _ => Status::internal(message),
+    }
+}
+
+pub(super) fn receive_payment_error_status(err: ReceivePaymentError) -> Status {
+    let message = err.to_string();
+    match err {
+        ReceivePaymentError::InvalidAmount { .. }
+        | ReceivePaymentError::InvalidInvoice { .. }
+        | ReceivePaymentError::InvoiceNoDescription { .. } => Status::invalid_argument(message),
+        ReceivePaymentError::AmountExceedsMaximum { .. } => Status::failed_precondition(message),
+        ReceivePaymentError::InvoicePreimageAlreadyExists { .. } => Status::already_exists(message),
+        ReceivePaymentError::ServiceConnectivity { .. } => Status::unavailable(message),
+        _ => Status::internal(message),
+    }
+}
@@ -0,0 +1,342 @@
+mod convert;
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use breez_sdk_core::{BreezServices, CheckMessageRequest, SignMessageRequest};
+use futures::{Stream, StreamExt};
+use tonic::metadata::MetadataValue;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+use crate::command_handlers::CommandHandler;
+use crate::metrics_server::MetricsServer;
+use crate::persist::CliPersistence;
+use convert::{receive_payment_error_status, sdk_error_status, send_payment_error_status};
+use proto::breez_sdk_server::{BreezSdk, BreezSdkServer};
+
+pub(crate) mod proto {
+    tonic::include_proto!("breez_sdk");
+}
+
+/// Connects the saved node and serves its API over gRPC on `address`, until interrupted.
+/// The calls must carry `token` as a bearer token if set, which is required off loopback.
+/// The metrics are served over HTTP on `metrics_address` if set.
+pub(crate) async fn run(
+    persistence: CliPersistence,
+    address: &str,
+    token: Option<String>,
+    metrics_address: Option<&str>,
+) -> Result<()> {
+    let address: SocketAddr = address.parse()?;
+    let expected_authorization = expected_authorization(&address, token)?;
+    let mut command_handler = CommandHandler::new(persistence, false);
+    command_handler.connect_saved().await?;
+    let service = BreezSdkService {
+        sdk: command_handler.sdk()?,
+    };
+    let _metrics_server = match metrics_address {
+        Some(metrics_address) => {
+            let server = MetricsServer::start(command_handler.sdk()?, metrics_address).await?;
+            info!(
+                "Serving the SDK metrics on http://{}/metrics",
+                server.local_addr
+            );
+            Some(server)
+        }
+        None => None,
+    };
+
+    info!("Serving the SDK API over gRPC on {address}");
+    let res = Server::builder()
+        .add_service(BreezSdkServer::with_interceptor(
+            service,
+            move |request: Request<()>| check_authorization(request, &expected_authorization),
+        ))
+        .serve_with_shutdown(address, async {
+            if let Err(e) = tokio::signal::ctrl_c().await {
+                error!("Failed to listen for the interrupt signal: {e}");
+            }
+        })
+        .await;
+    command_handler.disconnect().await?;
+    Ok(res?)
+}
+
+/// The authorization header the calls must carry for `token`. A token is required off loopback.
+fn expected_authorization(
+    address: &SocketAddr,
+    token: Option<String>,
+) -> Result<Option<MetadataValue<tonic::metadata::Ascii>>> {
+    ensure!(
+        token.is_some() || address.ip().is_loopback(),
+        "A --token is required to serve the daemon on a non-loopback address"
+    );
+    Ok(match token {
+        Some(token) => Some(MetadataValue::try_from(format!("Bearer {token}"))?),
+        None => None,
+    })
+}
+
+/// Rejects the calls without the `expected` authorization header, if any
+fn check_authorization(
+    request: Request<()>,
+    expected: &Option<MetadataValue<tonic::metadata::Ascii>>,
+) -> Result<Request<()>, Status> {
+    let Some(expected) = expected else {
+        return Ok(request);
+    };
+    match request.metadata().get("authorization") {
+        Some(authorization) if constant_time_eq(authorization.as_bytes(), expected.as_bytes()) => {
+            Ok(request)
+        }
+        _ => Err(Status::unauthenticated("Invalid or missing token")),
+    }
+}
+
+/// Compares without leaking where the first difference is through the timing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+struct BreezSdkService {
+    sdk: Arc<BreezServices>,
+}
+
+#[tonic::async_trait]
+impl BreezSdk for BreezSdkService {
+    type SubscribeEventsStream =
+        Pin<Box<dyn Stream<Item = Result<proto::BreezEvent, Status>> + Send>>;
+
+    async fn node_info(
+        &self,
+        _request: Request<proto::NodeInfoRequest>,
+    ) -> Result<Response<proto::NodeState>, Status> {
+        let node_state = self.sdk.node_info().map_err(sdk_error_status)?;
+        Ok(Response::new(node_state.into()))
+    }
+
+    async fn sync(
+        &self,
+        _request: Request<proto::SyncRequest>,
+    ) -> Result<Response<proto::SyncResponse>, Status> {
+        self.sdk.sync().await.map_err(sdk_error_status)?;
+        Ok(Response::new(proto::SyncResponse {}))
+    }
+
+    async fn list_payments(
+        &self,
+        request: Request<proto::ListPaymentsRequest>,
+    ) -> Result<Response<proto::ListPaymentsResponse>, Status> {
+        let payments = self
+            .sdk
+            .list_payments(request.into_inner().into())
+            .await
+            .map_err(sdk_error_status)?;
+        Ok(Response::new(proto::ListPaymentsResponse {
+            payments: payments.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn payment_by_hash(
+        &self,
+        request: Request<proto::PaymentByHashRequest>,
+    ) -> Result<Response<proto::PaymentByHashResponse>, Status> {
+        let payment = self
+            .sdk
+            .payment_by_hash(request.into_inner().hash)
+            .await
+            .map_err(sdk_error_status)?;
+        Ok(Response::new(proto::PaymentByHashResponse {
+            payment: payment.map(Into::into),
+        }))
+    }
+
+    async fn receive_payment(
+        &self,
+        request: Request<proto::ReceivePaymentRequest>,
+    ) -> Result<Response<proto::ReceivePaymentResponse>, Status> {
+        let res = self
+            .sdk
+            .receive_payment(request.into_inner().into())
+            .await
+            .map_err(receive_payment_error_status)?;
+        Ok(Response::new(res.into()))
+    }
+
+    async fn send_payment(
+        &self,
+        request: Request<proto::SendPaymentRequest>,
+    ) -> Result<Response<proto::SendPaymentResponse>, Status> {
+        let res = self
+            .sdk
+            .send_payment(request.into_inner().into())
+            .await
+            .map_err(send_payment_error_status)?;
+        Ok(Response::new(res.into()))
+    }
+
+    async fn send_spontaneous_payment(
+        &self,
+        request: Request<proto::SendSpontaneousPaymentRequest>,
+    ) -> Result<Response<proto::SendPaymentResponse>, Status> {
+        let res = self
+            .sdk
+            .send_spontaneous_payment(request.into_inner().into())
+            .await
+            .map_err(send_payment_error_status)?;
+        Ok(Response::new(res.into()))
+    }
+
+    async fn sign_message(
+        &self,
+        request: Request<proto::SignMessageRequest>,
+    ) -> Result<Response<proto::SignMessageResponse>, Status> {
+        let res = self
+            .sdk
+            .sign_message(SignMessageRequest {
+                message: request.into_inner().message,
+            })
+            .await
+            .map_err(sdk_error_status)?;
+        Ok(Response::new(proto::SignMessageResponse {
+            signature: res.signature,
+        }))
+    }
+
+    async fn check_message(
+        &self,
+        request: Request<proto::CheckMessageRequest>,
+    ) -> Result<Response<proto::CheckMessageResponse>, Status> {
+        let req = request.into_inner();
+        let res = self
+            .sdk
+            .check_message(CheckMessageRequest {
+                message: req.message,
+                pubkey: req.pubkey,
+                signature: req.signature,
+            })
+            .await
+            .map_err(sdk_error_status)?;
+        Ok(Response::new(proto::CheckMessageResponse {
+            is_valid: res.is_valid,
+        }))
+    }
+
+    async fn recommended_fees(
+        &self,
+        _request: Request<proto::RecommendedFeesRequest>,
+    ) -> Result<Response<proto::RecommendedFees>, Status> {
+        let fees = self
+            .sdk
+            .recommended_fees()
+            .await
+            .map_err(sdk_error_status)?;
+        Ok(Response::new(fees.into()))
+    }
+
+    async fn subscribe_events(
+        &self,
+        _request: Request<proto::SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        let events = self.sdk.subscribe().map(|event| Ok(event.into()));
+        Ok(Response::new(Box::pin(events)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use breez_sdk_core::error::SendPaymentError;
+    use tonic::{Code, Request};
+
+    use super::convert::send_payment_error_status;
+    use super::{check_authorization, constant_time_eq, expected_authorization};
+
+    fn request(authorization: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        if let Some(authorization) = authorization {
+            request
+                .metadata_mut()
+                .insert("authorization", authorization.parse().unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn test_expected_authorization() {
+        let loopback = "127.0.0.1:50051".parse().unwrap();
+        let loopback_v6 = "[::1]:50051".parse().unwrap();
+        let public = "0.0.0.0:50051".parse().unwrap();
+
+        // A token is optional on loopback only
+        assert!(expected_authorization(&loopback, None).unwrap().is_none());
+        assert!(expected_authorization(&loopback_v6, None)
+            .unwrap()
+            .is_none());
+        assert!(expected_authorization(&public, None).is_err());
+        assert_eq!(
+            expected_authorization(&public, Some("secret".to_string()))
+                .unwrap()
+                .unwrap(),
+            "Bearer secret"
+        );
+        assert!(
+            expected_authorization(&loopback, Some("secret".to_string()))
+                .unwrap()
+                .is_some()
+        );
+        // The token has to fit in a header
+        assert!(expected_authorization(&public, Some("new\nline".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_check_authorization() {
+        let expected =
+            expected_authorization(&"0.0.0.0:50051".parse().unwrap(), Some("secret".into()))
+                .unwrap();
+
+        assert!(check_authorization(request(Some("Bearer secret")), &expected).is_ok());
+        for authorization in [
+            None,
+            Some("Bearer other"),
+            Some("secret"),
+            Some("Bearer secret2"),
+        ] {
+            let status = check_authorization(request(authorization), &expected).unwrap_err();
+            assert_eq!(status.code(), Code::Unauthenticated);
+        }
+
+        // Without a token every call is accepted
+        assert!(check_authorization(request(None), &None).is_ok());
+        assert!(check_authorization(request(Some("Bearer other")), &None).is_ok());
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+        assert!(constant_time_eq(b"", b""));
+    }
+
+    #[test]
+    fn test_send_payment_error_status() {
+        let code = |err| send_payment_error_status(err).code();
+        assert_eq!(code(SendPaymentError::AlreadyPaid), Code::AlreadyExists);
+        assert_eq!(
+            code(SendPaymentError::InvalidInvoice {
+                err: "invalid".into()
+            }),
+            Code::InvalidArgument
+        );
+        assert_eq!(code(SendPaymentError::Cancelled), Code::Cancelled);
+        assert_eq!(
+            code(SendPaymentError::Generic {
+                err: "failed".into()
+            }),
+            Code::Internal
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::Result;
+use breez_sdk_core::BreezServices;
+use futures::{SinkExt, StreamExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Websocket endpoint streaming the SDK events as JSON text messages, one per event, for
+/// dashboards and other integrations that would otherwise poll the CLI.
+///
+/// Each client receives the events emitted after it connected. The server stops when dropped.
+pub(crate) struct EventServer {
+    pub(crate) local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl EventServer {
+    pub(crate) async fn start(sdk: Arc<BreezServices>, addr: &str) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        let sdk = sdk.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = stream_events(sdk, stream).await {
+                                debug!("Event stream to {peer} stopped: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => warn!("Failed to accept an event stream client: {e}"),
+                }
+            }
+        });
+        Ok(Self { local_addr, task })
+    }
+}
+
+impl Drop for EventServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn stream_events(sdk: Arc<BreezServices>, stream: TcpStream) -> Result<()> {
+    let (mut sink, mut incoming) = tokio_tungstenite::accept_async(stream).await?.split();
+    let mut events = Box::pin(sdk.subscribe());
+    loop {
+        tokio::select! {
+            event = events.next() => match event {
+                Some(event) => sink.send(Message::Text(serde_json::to_string(&event)?)).await?,
+                // The SDK was dropped
+                None => break,
+            },
+            message = incoming.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by tungstenite, other messages are ignored
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+    sink.close().await?;
+    Ok(())
+}
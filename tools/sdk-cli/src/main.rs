@@ -3,20 +3,32 @@ extern crate log;
 mod command_handlers;
 mod commands;
 mod config;
+mod daemon;
+mod event_server;
+mod metrics_server;
 mod persist;
+mod qr;
 
 use crate::command_handlers::CliHelper;
 use anyhow::{anyhow, ensure, Result};
 use breez_sdk_core::BreezServices;
 use clap::Parser;
 use command_handlers::CommandHandler;
-use commands::{Commands, SdkCli};
+use commands::{Commands, Mode, SdkCli};
 use persist::CliPersistence;
 use rustyline::error::ReadlineError;
 use rustyline::hint::HistoryHinter;
 use rustyline::Editor;
+use serde_json::{json, Value};
 use std::path::Path;
 
+/// Exit code of [Mode::Exec] when the command failed
+const EXIT_COMMAND_FAILED: i32 = 1;
+/// Exit code of [Mode::Exec] when the command could not be parsed
+const EXIT_INVALID_COMMAND: i32 = 2;
+/// Exit code of [Mode::Exec] when the node could not be connected
+const EXIT_CONNECT_FAILED: i32 = 3;
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = SdkCli::parse();
@@ -29,7 +41,20 @@ async fn main() -> Result<()> {
 
     BreezServices::init_logging(&data_dir, None)?;
 
-    let persistence = CliPersistence { data_dir };
+    let mut persistence = CliPersistence {
+        data_dir,
+        profile: None,
+    };
+    persistence.set_profile(cli.profile)?;
+    match cli.mode {
+        Some(Mode::Exec { command }) => std::process::exit(exec(persistence, &command).await),
+        Some(Mode::Daemon {
+            address,
+            token,
+            metrics_address,
+        }) => return daemon::run(persistence, &address, token, metrics_address.as_deref()).await,
+        None => {}
+    }
     let history_file = &persistence.history_file();
 
     let rl = &mut Editor::new()?;
@@ -40,16 +65,14 @@ async fn main() -> Result<()> {
         info!("No previous history.");
     }
 
-    let mut command_handler = CommandHandler::new(persistence);
+    let mut command_handler = CommandHandler::new(persistence, true);
 
     loop {
         let readline = rl.readline("sdk> ");
         match readline {
             Ok(line) => {
                 rl.add_history_entry(line.as_str())?;
-                let mut vec: Vec<&str> = line.as_str().split_whitespace().collect();
-                vec.insert(0, " ");
-                let cli_res = Commands::try_parse_from(vec);
+                let cli_res = parse_command(&line);
                 if cli_res.is_err() {
                     println!("{}", cli_res.unwrap_err());
                     continue;
@@ -75,9 +98,133 @@ async fn main() -> Result<()> {
     rl.save_history(history_file).map_err(|e| anyhow!(e))
 }
 
+fn parse_command(line: &str) -> Result<Commands, clap::Error> {
+    let mut vec: Vec<&str> = line.split_whitespace().collect();
+    vec.insert(0, " ");
+    Commands::try_parse_from(vec)
+}
+
+/// Runs a single command, prints its result or error as JSON and returns the exit code
+async fn exec(persistence: CliPersistence, line: &str) -> i32 {
+    let command = match parse_command(line) {
+        Ok(command) => command,
+        Err(err) => return print_error(err.to_string(), EXIT_INVALID_COMMAND),
+    };
+    let rl = &mut match Editor::new() {
+        Ok(rl) => rl,
+        Err(err) => return print_error(err.to_string(), EXIT_COMMAND_FAILED),
+    };
+
+    let mut command_handler = CommandHandler::new(persistence, false);
+    if command.requires_connection() {
+        if let Err(err) = command_handler.connect_saved().await {
+            return print_error(err.to_string(), EXIT_CONNECT_FAILED);
+        }
+    }
+    let res = command_handler.handle_command(rl, command).await;
+    if let Err(err) = command_handler.disconnect().await {
+        warn!("Failed to disconnect: {err}");
+    }
+    match res {
+        Ok(output) => {
+            println!("{:#}", json_output(output));
+            0
+        }
+        Err(err) => print_error(err.to_string(), EXIT_COMMAND_FAILED),
+    }
+}
+
+/// The result of a command as JSON. Plain text results are wrapped so stdout is always JSON.
+fn json_output(output: String) -> Value {
+    serde_json::from_str::<Value>(&output).unwrap_or_else(|_| json!({ "message": output }))
+}
+
+fn print_error(err: String, exit_code: i32) -> i32 {
+    println!("{:#}", json!({ "error": err }));
+    exit_code
+}
+
 fn show_results(res: Result<String>) {
     match res {
         Ok(inner) => println!("{inner}"),
         Err(err) => eprintln!("Error: {err}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::commands::Commands;
+    use crate::persist::test_persistence;
+    use crate::{exec, json_output, parse_command, EXIT_INVALID_COMMAND};
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("node_info").unwrap(), Commands::NodeInfo {});
+        assert!(matches!(
+            parse_command("lnurl_pay lnurl1dp68gurn --amount_msat 1000").unwrap(),
+            Commands::LnurlPay {
+                amount_msat: Some(1000),
+                ..
+            }
+        ));
+        let words = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon about";
+        assert!(matches!(
+            parse_command(&format!("restore_node --mnemonic {words}")).unwrap(),
+            Commands::RestoreNode {
+                mnemonic: Some(mnemonic),
+                seed_hex: None,
+            } if mnemonic.join(" ") == words
+        ));
+        assert!(parse_command("restore_node").is_err());
+        assert!(parse_command("restore_node --mnemonic abandon --seed-hex 00").is_err());
+        assert!(parse_command("not_a_command").is_err());
+        assert!(parse_command("lnurl_pay lnurl1dp68gurn --amount_msat abc").is_err());
+    }
+
+    #[test]
+    fn test_requires_connection() {
+        assert!(parse_command("node_info").unwrap().requires_connection());
+        assert!(!parse_command("set_api_key abc")
+            .unwrap()
+            .requires_connection());
+        assert!(!parse_command("list_profiles")
+            .unwrap()
+            .requires_connection());
+    }
+
+    #[test]
+    fn test_json_output() {
+        assert_eq!(
+            json_output(r#"{"id": "abc"}"#.to_string()),
+            json!({"id": "abc"})
+        );
+        assert_eq!(
+            json_output("API key was set".to_string()),
+            json!({"message": "API key was set"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exec() {
+        let persistence = test_persistence();
+        assert_eq!(
+            exec(test_persistence(), "not_a_command").await,
+            EXIT_INVALID_COMMAND
+        );
+
+        // A command that doesn't need the node runs without connecting
+        let data_dir = persistence.data_dir.clone();
+        assert_eq!(exec(persistence, "set_api_key abc").await, 0);
+        let persistence = crate::persist::CliPersistence {
+            data_dir,
+            profile: None,
+        };
+        assert_eq!(
+            persistence.get_or_create_config().unwrap().api_key,
+            Some("abc".to_string())
+        );
+    }
+}
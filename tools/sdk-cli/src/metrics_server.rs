@@ -0,0 +1,71 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::Result;
+use breez_sdk_core::BreezServices;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+/// HTTP endpoint serving the SDK metrics at `GET /metrics`, in the Prometheus text format, so
+/// they can be scraped while the daemon runs.
+///
+/// The server stops when dropped.
+pub(crate) struct MetricsServer {
+    pub(crate) local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl MetricsServer {
+    pub(crate) async fn start(sdk: Arc<BreezServices>, addr: &str) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, peer)) => {
+                        let sdk = sdk.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = serve_metrics(sdk, stream).await {
+                                debug!("Failed to serve the metrics to {peer}: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => warn!("Failed to accept a metrics client: {e}"),
+                }
+            }
+        });
+        Ok(Self { local_addr, task })
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve_metrics(sdk: Arc<BreezServices>, mut stream: TcpStream) -> Result<()> {
+    // Only the request line matters, the headers and body are ignored
+    let mut buf = [0; 1024];
+    let len = stream.read(&mut buf).await?;
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let response = match (request_line.next(), request_line.next()) {
+        (Some("GET"), Some("/metrics")) => {
+            let body = sdk.metrics().await?.to_prometheus();
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+        }
+        _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+    };
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use std::{fs, io, path::Path};
 
@@ -6,15 +6,111 @@ use crate::config::CliConfig;
 
 const CONFIG_FILE_NAME: &str = "config.json";
 const PHRASE_FILE_NAME: &str = "phrase";
+/// The raw seed, in hex, of a wallet restored without its mnemonic
+const SEED_FILE_NAME: &str = "seed";
 const HISTORY_FILE_NAME: &str = "history.txt";
+const PROFILES_DIR_NAME: &str = "profiles";
+
+/// The seed a wallet is restored from
+pub(crate) enum RestoredSeed {
+    Mnemonic(Mnemonic),
+    /// A raw seed, without the mnemonic it may be derived from
+    Raw(Vec<u8>),
+}
+
+impl RestoredSeed {
+    fn seed(&self) -> Vec<u8> {
+        match self {
+            RestoredSeed::Mnemonic(mnemonic) => Seed::new(mnemonic, "").as_bytes().to_vec(),
+            RestoredSeed::Raw(seed) => seed.clone(),
+        }
+    }
+}
 
 pub(crate) struct CliPersistence {
     pub(crate) data_dir: String,
+    /// The active profile, or `None` for the default profile, which is stored in the data dir
+    /// itself. Each profile has its own seed, config and SDK working dir.
+    pub(crate) profile: Option<String>,
 }
 
 impl CliPersistence {
+    /// The directory of the active profile
+    pub(crate) fn profile_dir(&self) -> String {
+        match &self.profile {
+            Some(name) => self.named_profile_dir(name),
+            None => self.data_dir.clone(),
+        }
+    }
+
+    fn named_profile_dir(&self, name: &str) -> String {
+        let path = Path::new(&self.data_dir).join(PROFILES_DIR_NAME).join(name);
+        path.to_str().unwrap().to_string()
+    }
+
+    /// Creates a profile, starting with a copy of the config of the active profile
+    pub(crate) fn create_profile(&self, name: &str) -> Result<()> {
+        ensure!(
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+            "Profile names can only contain letters, digits, '-' and '_'"
+        );
+        let profile_dir = self.named_profile_dir(name);
+        ensure!(
+            !Path::new(&profile_dir).exists(),
+            "Profile {name} already exists"
+        );
+        let config = self.get_or_create_config()?;
+        fs::create_dir_all(&profile_dir)?;
+        CliPersistence {
+            data_dir: self.data_dir.clone(),
+            profile: Some(name.to_string()),
+        }
+        .save_config(config)
+    }
+
+    /// The names of the created profiles, sorted
+    pub(crate) fn list_profiles(&self) -> Result<Vec<String>> {
+        let profiles_dir = Path::new(&self.data_dir).join(PROFILES_DIR_NAME);
+        if !profiles_dir.exists() {
+            return Ok(vec![]);
+        }
+        let mut profiles = vec![];
+        for entry in fs::read_dir(profiles_dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                profiles.push(
+                    entry
+                        .file_name()
+                        .into_string()
+                        .map_err(|name| anyhow!("Invalid profile name: {name:?}"))?,
+                );
+            }
+        }
+        profiles.sort();
+        Ok(profiles)
+    }
+
+    /// Switches to the given profile, or to the default profile if `None`
+    pub(crate) fn set_profile(&mut self, profile: Option<String>) -> Result<()> {
+        if let Some(name) = &profile {
+            ensure!(
+                Path::new(&self.named_profile_dir(name)).is_dir(),
+                "Profile {name} doesn't exist"
+            );
+        }
+        self.profile = profile;
+        Ok(())
+    }
+
     pub(crate) fn get_or_create_seed(&self) -> Vec<u8> {
-        let filename = Path::new(&self.data_dir).join(PHRASE_FILE_NAME);
+        let seed_filename = Path::new(&self.profile_dir()).join(SEED_FILE_NAME);
+        if let Ok(seed) = fs::read_to_string(&seed_filename) {
+            return hex::decode(seed.trim()).unwrap();
+        }
+        let filename = Path::new(&self.profile_dir()).join(PHRASE_FILE_NAME);
         let mnemonic = match fs::read_to_string(filename.clone()) {
             Ok(phrase) => Mnemonic::from_phrase(phrase.as_str(), Language::English).unwrap(),
             Err(e) => {
@@ -33,8 +129,54 @@ impl CliPersistence {
         seed.as_bytes().to_vec()
     }
 
+    /// The mnemonic of the wallet, or `None` if it was restored from a raw seed or no seed was
+    /// created yet
+    pub(crate) fn get_mnemonic(&self) -> Result<Option<String>> {
+        let filename = Path::new(&self.profile_dir()).join(PHRASE_FILE_NAME);
+        match fs::read_to_string(filename) {
+            Ok(phrase) => Ok(Some(phrase)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Saves the seed to restore the wallet from. Fails if the profile already has another seed.
+    ///
+    /// Returns whether the seed was saved, as opposed to being the seed of the profile already.
+    pub(crate) fn save_restored_seed(&self, restored: &RestoredSeed) -> Result<bool> {
+        let profile_dir = Path::new(&self.profile_dir()).to_path_buf();
+        if profile_dir.join(PHRASE_FILE_NAME).exists() || profile_dir.join(SEED_FILE_NAME).exists()
+        {
+            ensure!(
+                self.get_or_create_seed() == restored.seed(),
+                "The profile already has another seed, create a new profile to restore this one"
+            );
+            return Ok(false);
+        }
+        match restored {
+            RestoredSeed::Mnemonic(mnemonic) => {
+                fs::write(profile_dir.join(PHRASE_FILE_NAME), mnemonic.phrase())?
+            }
+            RestoredSeed::Raw(seed) => {
+                fs::write(profile_dir.join(SEED_FILE_NAME), hex::encode(seed))?
+            }
+        }
+        Ok(true)
+    }
+
+    /// Deletes the seed of the profile, after restoring a wallet from it failed
+    pub(crate) fn delete_seed(&self) -> Result<()> {
+        for name in [PHRASE_FILE_NAME, SEED_FILE_NAME] {
+            let filename = Path::new(&self.profile_dir()).join(name);
+            if filename.exists() {
+                fs::remove_file(filename)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn get_or_create_config(&self) -> Result<CliConfig> {
-        let filename = Path::new(&self.data_dir).join(CONFIG_FILE_NAME);
+        let filename = Path::new(&self.profile_dir()).join(CONFIG_FILE_NAME);
         let config: CliConfig = match fs::read(filename) {
             Ok(raw) => serde_json::from_slice(raw.as_slice()).unwrap(),
             Err(_) => {
@@ -47,7 +189,7 @@ impl CliPersistence {
     }
 
     pub(crate) fn save_config(&self, config: CliConfig) -> Result<()> {
-        let filename = Path::new(&self.data_dir).join(CONFIG_FILE_NAME);
+        let filename = Path::new(&self.profile_dir()).join(CONFIG_FILE_NAME);
         fs::write(filename, serde_json::to_vec(&config)?)?;
         Ok(())
     }
@@ -57,3 +199,128 @@ impl CliPersistence {
         path.to_str().unwrap().to_string()
     }
 }
+
+/// A persistence in a new empty data dir under the temp dir
+#[cfg(test)]
+pub(crate) fn test_persistence() -> CliPersistence {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let data_dir = std::env::temp_dir().join(format!(
+        "sdk-cli-test-{}-{nanos}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&data_dir).unwrap();
+    CliPersistence {
+        data_dir: data_dir.to_str().unwrap().to_string(),
+        profile: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use bip39::{Language, Mnemonic, Seed};
+
+    use crate::persist::{test_persistence, RestoredSeed};
+
+    #[test]
+    fn test_profiles() {
+        let mut persistence = test_persistence();
+        assert!(persistence.list_profiles().unwrap().is_empty());
+        let mut config = persistence.get_or_create_config().unwrap();
+        config.api_key = Some("default key".to_string());
+        persistence.save_config(config).unwrap();
+        let default_seed = persistence.get_or_create_seed();
+
+        persistence.create_profile("bob").unwrap();
+        persistence.create_profile("alice").unwrap();
+        assert_eq!(persistence.list_profiles().unwrap(), vec!["alice", "bob"]);
+        assert!(persistence.create_profile("alice").is_err());
+        assert!(persistence.create_profile("../alice").is_err());
+        assert!(persistence.create_profile("").is_err());
+
+        // A new profile starts with the config of the active one and its own seed
+        persistence.set_profile(Some("alice".to_string())).unwrap();
+        assert_eq!(
+            persistence.profile_dir(),
+            Path::new(&persistence.data_dir)
+                .join("profiles")
+                .join("alice")
+                .to_str()
+                .unwrap()
+        );
+        let mut config = persistence.get_or_create_config().unwrap();
+        assert_eq!(config.api_key, Some("default key".to_string()));
+        let alice_seed = persistence.get_or_create_seed();
+        assert_ne!(alice_seed, default_seed);
+        config.api_key = Some("alice key".to_string());
+        persistence.save_config(config).unwrap();
+
+        persistence.set_profile(Some("bob".to_string())).unwrap();
+        assert_ne!(persistence.get_or_create_seed(), alice_seed);
+        assert_eq!(
+            persistence.get_or_create_config().unwrap().api_key,
+            Some("default key".to_string())
+        );
+
+        // Switching back keeps the state of each profile
+        persistence.set_profile(Some("alice".to_string())).unwrap();
+        assert_eq!(persistence.get_or_create_seed(), alice_seed);
+        assert_eq!(
+            persistence.get_or_create_config().unwrap().api_key,
+            Some("alice key".to_string())
+        );
+        persistence.set_profile(None).unwrap();
+        assert_eq!(persistence.profile_dir(), persistence.data_dir);
+        assert_eq!(persistence.get_or_create_seed(), default_seed);
+
+        // An unknown profile can't be switched to
+        assert!(persistence.set_profile(Some("carol".to_string())).is_err());
+        assert_eq!(persistence.profile, None);
+    }
+
+    #[test]
+    fn test_restored_seed() {
+        let persistence = test_persistence();
+        assert_eq!(persistence.get_mnemonic().unwrap(), None);
+
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon about";
+        let mnemonic =
+            || RestoredSeed::Mnemonic(Mnemonic::from_phrase(phrase, Language::English).unwrap());
+        assert!(persistence.save_restored_seed(&mnemonic()).unwrap());
+        assert_eq!(
+            persistence.get_mnemonic().unwrap(),
+            Some(phrase.to_string())
+        );
+        let seed = Seed::new(
+            &Mnemonic::from_phrase(phrase, Language::English).unwrap(),
+            "",
+        );
+        assert_eq!(persistence.get_or_create_seed(), seed.as_bytes());
+
+        // The same seed can be restored again, but not another one
+        assert!(!persistence.save_restored_seed(&mnemonic()).unwrap());
+        assert!(persistence
+            .save_restored_seed(&RestoredSeed::Raw(vec![7; 32]))
+            .is_err());
+
+        // Once deleted, a raw seed can be restored instead, without a mnemonic
+        persistence.delete_seed().unwrap();
+        assert_eq!(persistence.get_mnemonic().unwrap(), None);
+        assert!(persistence
+            .save_restored_seed(&RestoredSeed::Raw(vec![7; 32]))
+            .unwrap());
+        assert_eq!(persistence.get_or_create_seed(), vec![7; 32]);
+        assert_eq!(persistence.get_mnemonic().unwrap(), None);
+        assert!(persistence.save_restored_seed(&mnemonic()).is_err());
+    }
+}
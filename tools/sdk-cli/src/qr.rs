@@ -0,0 +1,396 @@
+//! Decoding of scanned QR codes, including the animated UR and BBQr formats wallets use for
+//! payloads too large for a single QR code.
+
+use std::process::Command;
+
+use anyhow::{anyhow, ensure, Result};
+
+/// The bytewords of the UR encoding, indexed by the byte they encode. Only the first and last
+/// letters of each word are used, as in the minimal encoding of UR bodies.
+const BYTEWORDS: &str = "able acid also apex aqua arch atom aunt away axis back bald barn belt \
+    beta bias blue body brag brew bulb buzz calm cash cats chef city claw code cola cook cost \
+    crux curl cusp cyan dark data days deli dice diet door down draw drop drum dull duty each \
+    easy echo edge epic even exam exit eyes fact fair fern figs film fish fizz flap flew flux \
+    foxy free frog fuel fund gala game gear gems gift girl glow good gray grim guru gush gyro \
+    half hang hard hawk heat help high hill holy hope horn huts iced idea idle inch inky into \
+    iris iron item jade jazz join jolt jowl judo jugs jump junk jury keep keno kept keys kick \
+    kiln king kite kiwi knob lamb lava lazy leaf legs liar limp lion list logo loud love luau \
+    luck lung main many math maze memo menu meow mild mint miss monk nail navy need news next \
+    noon note numb obey oboe omit onyx open oval owls paid part peck play plus poem pool pose \
+    puff puma purr quad quiz race ramp real redo rich road rock roof ruby ruin runs rust safe \
+    saga scar sets silk skew slot soap solo song stub surf swan taco task taxi tent tied time \
+    tiny toil tomb toys trip tuna twin ugly undo unit urge user vast very veto vial vibe view \
+    visa void vows wall wand warm wasp wave waxy webs what when whiz wolf work yank yawn yell \
+    yoga yurt zaps zero zest zinc zone zoom";
+
+/// Decodes the QR codes in the images at `paths` with `zbarimg`, one content per QR code found.
+pub(crate) fn scan_images(paths: &[String]) -> Result<Vec<String>> {
+    let mut contents = vec![];
+    for path in paths {
+        let output = Command::new("zbarimg")
+            .args(["--raw", "-q", "-Sdisable", "-Sqrcode.enable", path])
+            .output()
+            .map_err(|e| anyhow!("Failed to run zbarimg, is it installed? {e}"))?;
+        ensure!(
+            output.status.success(),
+            "No QR code found in {path}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        contents.extend(
+            String::from_utf8(output.stdout)?
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string),
+        );
+    }
+    Ok(contents)
+}
+
+/// Joins the contents of scanned QR codes into the payload they encode.
+///
+/// Several contents must be the parts of a single animated UR or BBQr code, in any order and
+/// possibly repeated. A single content that is neither is returned as is.
+pub(crate) fn join_parts(parts: &[String]) -> Result<String> {
+    let first = parts.first().ok_or_else(|| anyhow!("No QR code content"))?;
+    if first.to_lowercase().starts_with("ur:") {
+        return join_ur(parts);
+    }
+    if first.starts_with("B$") {
+        return join_bbqr(parts);
+    }
+    ensure!(
+        parts.len() == 1,
+        "Several QR codes were given, but they are not the parts of a UR or BBQr code"
+    );
+    Ok(first.clone())
+}
+
+fn join_bbqr(parts: &[String]) -> Result<String> {
+    let mut encoding = None;
+    let mut chunks: Vec<Option<&str>> = vec![];
+    for part in parts {
+        ensure!(
+            part.is_ascii() && part.len() >= 8 && part.starts_with("B$"),
+            "Not a BBQr part: {part}"
+        );
+        let part_encoding = part.as_bytes()[2] as char;
+        let file_type = part.as_bytes()[3] as char;
+        ensure!(
+            file_type == 'U',
+            "BBQr file type {file_type} is not text and can't be parsed"
+        );
+        let total = usize::from_str_radix(&part[4..6], 36)?;
+        let index = usize::from_str_radix(&part[6..8], 36)?;
+        ensure!(
+            total > 0 && index < total,
+            "Invalid BBQr part {index} of {total}"
+        );
+        if chunks.is_empty() {
+            chunks = vec![None; total];
+            encoding = Some(part_encoding);
+        }
+        ensure!(
+            chunks.len() == total && encoding == Some(part_encoding),
+            "The BBQr parts belong to different payloads"
+        );
+        chunks[index] = Some(&part[8..]);
+    }
+
+    let missing: Vec<String> = chunks
+        .iter()
+        .enumerate()
+        .filter(|(_, chunk)| chunk.is_none())
+        .map(|(index, _)| index.to_string())
+        .collect();
+    ensure!(
+        missing.is_empty(),
+        "Missing BBQr parts {} of {}",
+        missing.join(", "),
+        chunks.len()
+    );
+    let data: String = chunks.into_iter().flatten().collect();
+    let bytes = match encoding {
+        Some('H') => hex::decode(data)?,
+        Some('2') => decode_base32(&data)?,
+        Some('Z') => return Err(anyhow!("Zlib compressed BBQr codes are not supported")),
+        Some(other) => return Err(anyhow!("Unknown BBQr encoding {other}")),
+        None => unreachable!("at least one part was joined"),
+    };
+    Ok(String::from_utf8(bytes)?)
+}
+
+/// Decodes unpadded RFC 4648 base32, as used by BBQr
+fn decode_base32(data: &str) -> Result<Vec<u8>> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut bytes = vec![];
+    let (mut buffer, mut bits) = (0u32, 0);
+    for c in data.trim_end_matches('=').bytes() {
+        let value = ALPHABET
+            .iter()
+            .position(|a| *a == c)
+            .ok_or_else(|| anyhow!("Invalid base32 character {}", c as char))?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
+
+fn join_ur(parts: &[String]) -> Result<String> {
+    let mut ur_type = None;
+    let mut single = None;
+    let mut fragments: Vec<Option<Vec<u8>>> = vec![];
+    let mut message_info = None;
+    for part in parts {
+        let lower = part.to_lowercase();
+        let path: Vec<&str> = lower
+            .strip_prefix("ur:")
+            .ok_or_else(|| anyhow!("Not a UR part: {part}"))?
+            .split('/')
+            .collect();
+        ensure!(
+            ur_type.is_none() || ur_type == Some(path[0].to_string()),
+            "The UR parts belong to different payloads"
+        );
+        ur_type = Some(path[0].to_string());
+
+        match path[..] {
+            [_, body] => single = Some(decode_bytewords(body)?),
+            [_, _, body] => {
+                let decoded = decode_bytewords(body)?;
+                let mut cbor = Cbor(&decoded);
+                let (seq_num, seq_len) = match cbor.item()? {
+                    CborItem::Array(5) => (cbor.uint()?, cbor.uint()? as usize),
+                    _ => return Err(anyhow!("Invalid UR part: {part}")),
+                };
+                let info = (cbor.uint()? as usize, cbor.uint()? as u32);
+                let fragment = cbor.bytes()?;
+                if fragments.is_empty() {
+                    fragments = vec![None; seq_len];
+                    message_info = Some(info);
+                }
+                ensure!(
+                    fragments.len() == seq_len && message_info == Some(info),
+                    "The UR parts belong to different payloads"
+                );
+                // Parts beyond the sequence length are fountain-coded mixes of fragments,
+                // which are not needed when all the pure fragments were scanned
+                if seq_num >= 1 && seq_num as usize <= seq_len {
+                    fragments[seq_num as usize - 1] = Some(fragment.to_vec());
+                }
+            }
+            _ => return Err(anyhow!("Invalid UR part: {part}")),
+        }
+    }
+
+    let message = match (single, message_info) {
+        (Some(message), None) => message,
+        (None, Some((message_len, checksum))) => {
+            let missing = fragments.iter().filter(|f| f.is_none()).count();
+            ensure!(
+                missing == 0,
+                "Missing {missing} of the {} UR parts",
+                fragments.len()
+            );
+            let mut message: Vec<u8> = fragments.into_iter().flatten().flatten().collect();
+            ensure!(message.len() >= message_len, "Invalid UR message length");
+            message.truncate(message_len);
+            ensure!(crc32(&message) == checksum, "Invalid UR message checksum");
+            message
+        }
+        _ => return Err(anyhow!("Single and multi-part UR codes can't be mixed")),
+    };
+
+    let mut cbor = Cbor(&message);
+    let bytes = match cbor.item()? {
+        CborItem::Bytes(bytes) | CborItem::Text(bytes) => bytes,
+        _ => {
+            return Err(anyhow!(
+                "UR type {} is not text and can't be parsed",
+                ur_type.unwrap_or_default()
+            ))
+        }
+    };
+    Ok(String::from_utf8(bytes.to_vec())?)
+}
+
+/// Decodes minimal bytewords and checks the CRC32 checksum in their last 4 bytes
+fn decode_bytewords(body: &str) -> Result<Vec<u8>> {
+    ensure!(
+        body.is_ascii() && body.len() % 2 == 0,
+        "Invalid bytewords: {body}"
+    );
+    let mut bytes = body
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            BYTEWORDS
+                .split_whitespace()
+                .position(|word| {
+                    let word = word.as_bytes();
+                    word[0] == pair[0] && word[3] == pair[1]
+                })
+                .map(|index| index as u8)
+                .ok_or_else(|| anyhow!("Invalid byteword {}", String::from_utf8_lossy(pair)))
+        })
+        .collect::<Result<Vec<u8>>>()?;
+    ensure!(bytes.len() > 4, "Invalid bytewords: {body}");
+    let checksum = bytes.split_off(bytes.len() - 4);
+    ensure!(
+        crc32(&bytes).to_be_bytes() == checksum[..],
+        "Invalid bytewords checksum"
+    );
+    Ok(bytes)
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+enum CborItem<'a> {
+    Uint(u64),
+    Bytes(&'a [u8]),
+    Text(&'a [u8]),
+    Array(u64),
+}
+
+/// A reader of the few CBOR items used by UR parts
+struct Cbor<'a>(&'a [u8]);
+
+impl<'a> Cbor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        ensure!(self.0.len() >= len, "Truncated CBOR");
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn item(&mut self) -> Result<CborItem<'a>> {
+        let initial = self.take(1)?[0];
+        let value = match initial & 0x1f {
+            info @ 0..=23 => info as u64,
+            info @ 24..=27 => self
+                .take(1 << (info - 24))?
+                .iter()
+                .fold(0, |value, byte| (value << 8) | *byte as u64),
+            _ => return Err(anyhow!("Unsupported CBOR item")),
+        };
+        Ok(match initial >> 5 {
+            0 => CborItem::Uint(value),
+            2 => CborItem::Bytes(self.take(value as usize)?),
+            3 => CborItem::Text(self.take(value as usize)?),
+            4 => CborItem::Array(value),
+            _ => return Err(anyhow!("Unsupported CBOR item")),
+        })
+    }
+
+    fn uint(&mut self) -> Result<u64> {
+        match self.item()? {
+            CborItem::Uint(value) => Ok(value),
+            _ => Err(anyhow!("Expected a CBOR unsigned integer")),
+        }
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        match self.item()? {
+            CborItem::Bytes(bytes) => Ok(bytes),
+            _ => Err(anyhow!("Expected a CBOR byte string")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc32, decode_base32, join_parts};
+
+    const BIP21: &str = "bitcoin:bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq?amount=0.0001";
+    const LNURL: &str = "lnurl1dp68gurn8ghj7um9wfmxjcm99e3k7mf0v9cxj0m385ekvcenxc6r2c35xvukxefcv5mkvv34x5ekzd3ev56nyd3hxqurzepexejxxepnxscrvwfnv9nxzcn9xq6xyefhvgcxxcmyxymnserxfq5fns";
+    const UR_PARTS: [&str; 3] = [
+        "ur:bytes/1-3/lpadaxcsfwcyhdrfmogohfhdfzidinjyiajlinjtftidiaehjshsjpdyjkjpjpjpembtdrtpnd",
+        "ur:bytes/2-3/lpaoaxcsfwcyhdrfmogohfksiyjekokkecjzeneeeojzkkiejtktesjpihecesiojyhnjtlpce",
+        "ur:bytes/3-3/lpaxaxcsfwcyhdrfmogohfknknktiyecjniejsfhhsjnjlkpjtjyfsdydmdydydyehdkktwsnn",
+    ];
+    const BBQR_PARTS: [&str; 2] = [
+        "B$2U0200NRXHK4TMGFSHANRYM52XE3RYM5UGUN3VNU4XOZTNPBVGG3JZHFSTG2ZXNVTDA5RZMN4GUMDNGM4DKZLLOZRWK3TYMM3HEMTDGM2XQ5TVNN4GKZTDOY2W223W",
+        "B$2U0201OYZTI6BVMVVXUZBTMV3DKNTOPFSDG2DYOF2XE6TFOBSXQZLKPB4GK4DOPBZWG4TWO5TG45RZNZ4HUY3OHF4HCNTYPFSWM2DWM5RXQ6DDNV4XQ6LNNZZWK4TYMZYTKZTOOM",
+    ];
+
+    fn parts(parts: &[&str]) -> Vec<String> {
+        parts.iter().map(|part| part.to_string()).collect()
+    }
+
+    #[test]
+    fn test_join_plain() {
+        assert_eq!(join_parts(&parts(&[LNURL])).unwrap(), LNURL);
+        assert!(join_parts(&[]).is_err());
+        assert!(join_parts(&parts(&[LNURL, BIP21])).is_err());
+    }
+
+    #[test]
+    fn test_join_ur() {
+        let single = "ur:bytes/hdfzidinjyiajlinjtftidiaehjshsjpdyjkjpjpjpemksiyjekokkecjzeneeeojzkkiejtktesjpihecesiojyknknktiyecjniejsfhhsjnjlkpjtjyfsdydmdydydyehhdrfmogo";
+        assert_eq!(join_parts(&parts(&[single])).unwrap(), BIP21);
+        assert_eq!(
+            join_parts(&parts(&[single.to_uppercase().as_str()])).unwrap(),
+            BIP21
+        );
+
+        // The parts can be scanned in any order, and repeated
+        let [first, second, third] = UR_PARTS;
+        assert_eq!(
+            join_parts(&parts(&[third, first, third, second])).unwrap(),
+            BIP21
+        );
+        assert!(join_parts(&parts(&[first, third])).is_err());
+        assert!(join_parts(&parts(&[single, first])).is_err());
+
+        // A corrupted part fails its checksum
+        let corrupted = second.replace("/lpao", "/lpae");
+        assert!(join_parts(&parts(&[first, &corrupted, third])).is_err());
+    }
+
+    #[test]
+    fn test_join_bbqr() {
+        let [first, second] = BBQR_PARTS;
+        assert_eq!(join_parts(&parts(&[second, first])).unwrap(), LNURL);
+        assert!(join_parts(&parts(&[first])).is_err());
+        assert_eq!(
+            join_parts(&parts(&["B$HU01006C6E75726C31647036386775726E"])).unwrap(),
+            "lnurl1dp68gurn"
+        );
+
+        // Parts of other payloads, binary files and compressed data are rejected
+        assert!(join_parts(&parts(&[first, "B$2U0301MZXW6YTBOI"])).is_err());
+        assert!(join_parts(&parts(&["B$HP01006C6E"])).is_err());
+        assert!(join_parts(&parts(&["B$ZU01006C6E"])).is_err());
+    }
+
+    #[test]
+    fn test_decode_base32() {
+        assert_eq!(decode_base32("MZXW6YTBOI").unwrap(), b"foobar");
+        assert_eq!(decode_base32("MZXW6YTBOI======").unwrap(), b"foobar");
+        assert!(decode_base32("mzxw6ytboi").is_err());
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+}